@@ -1,5 +1,6 @@
 /// The exponent in `Sbox(x) = x^\alpha`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Alpha {
     /// A positive exponent $x^{alpha}$.
     Exponent(u32),
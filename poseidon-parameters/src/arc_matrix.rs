@@ -6,6 +6,8 @@ use decaf377::Fq;
 /// Arc stands for `AddRoundConstant` which is the
 /// step in the permutation where this matrix is used.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct ArcMatrix<const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize>(
     pub Matrix<N_ROWS, N_COLS, N_ELEMENTS>,
 );
@@ -30,8 +32,8 @@ impl<const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize>
 impl<const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize> MatrixOperations
     for ArcMatrix<N_ROWS, N_COLS, N_ELEMENTS>
 {
-    fn new(elements: &[Fq]) -> Self {
-        Self(Matrix::new(elements))
+    fn new(elements: &[Fq]) -> Result<Self, PoseidonParameterError> {
+        Ok(Self(Matrix::new(elements)?))
     }
 
     fn elements(&self) -> &[Fq] {
@@ -71,6 +73,8 @@ impl<const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize> MatrixOp
 /// This method follows `calc_equivalent_constants` from Appendix B's
 /// `poseidonperm_x3_64_24_optimized.sage`.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct OptimizedArcMatrix<const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize>(
     pub ArcMatrix<N_ROWS, N_COLS, N_ELEMENTS>,
 );
@@ -92,8 +96,8 @@ impl<const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize> MatrixOp
     for OptimizedArcMatrix<N_ROWS, N_COLS, N_ELEMENTS>
 {
     /// Create a `OptimizedArcMatrix` from its elements.
-    fn new(elements: &[Fq]) -> Self {
-        Self(ArcMatrix::new(elements))
+    fn new(elements: &[Fq]) -> Result<Self, PoseidonParameterError> {
+        Ok(Self(ArcMatrix::new(elements)?))
     }
 
     fn elements(&self) -> &[Fq] {
@@ -0,0 +1,325 @@
+//! Rust source for parameters, in the layout of the parameters vendored by
+//! `poseidon377`, see [`DynPoseidonParameters::to_rust_source`] for v1 and
+//! [`PoseidonParameters::to_rust_source`](crate::v2::PoseidonParameters::to_rust_source)
+//! for v2.
+
+use alloc::{format, string::String};
+use core::fmt::Write;
+
+use decaf377::Fq;
+
+use crate::{
+    alpha::Alpha, dynamic::DynPoseidonParameters, error::PoseidonParameterError,
+    matrix_ops::MatrixOperations, v2,
+};
+
+/// The maximum width of a line, as in `rustfmt`.
+const MAX_WIDTH: usize = 100;
+
+/// The maximum width of the arguments of a call kept on one line, as in `rustfmt`.
+const FN_CALL_WIDTH: usize = 60;
+
+/// Indentation of one block.
+const INDENT: usize = 4;
+
+impl DynPoseidonParameters {
+    /// Render these parameters as a Rust module defining `pub const fn name()`, which
+    /// returns them as [`PoseidonParameters`](crate::v1::PoseidonParameters) without any
+    /// computation at runtime.
+    ///
+    /// The function is documented with `doc`. The source is formatted as `rustfmt`
+    /// would, so that it can be vendored next to the parameters in
+    /// `poseidon377/src/params`.
+    pub fn to_rust_source(&self, name: &str, doc: &str) -> Result<String, PoseidonParameterError> {
+        self.check_dimensions()?;
+        let t = self.state_size();
+        let rounds = self.rounds.total();
+        let optimized = &self.optimized_mds;
+
+        let mut source = String::from(
+            "use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+",
+        );
+        push_doc(&mut source, doc);
+        let _ = writeln!(
+            source,
+            "pub const fn {}() -> PoseidonParameters<{}, {}, {}, {}, {}, {}, {}, {}> {{",
+            name,
+            t,
+            t - 1,
+            t * t,
+            (t - 1) * (t - 1),
+            rounds,
+            t,
+            rounds * t,
+            self.rounds.partial(),
+        );
+
+        let mut out = Writer { source };
+        out.line(INDENT, "PoseidonParameters {");
+        let indent = 2 * INDENT;
+        out.line(indent, &format!("M: {},", self.M));
+        out.matrix(
+            indent,
+            &format!("arc: ArcMatrix::<{}, {}, {}>", rounds, t, rounds * t),
+            self.arc.elements(),
+        );
+        out.matrix(
+            indent,
+            &format!(
+                "mds: MdsMatrix::<{}, {}, {}, {}>",
+                t,
+                t - 1,
+                t * t,
+                (t - 1) * (t - 1)
+            ),
+            self.mds.elements(),
+        );
+        out.line(indent, &format!("alpha: {},", alpha_source(self.alpha)));
+        out.line(
+            indent,
+            &format!(
+                "rounds: RoundNumbers {{ r_P: {}, r_F: {} }},",
+                self.rounds.partial(),
+                self.rounds.full()
+            ),
+        );
+
+        out.line(indent, "optimized_mds: OptimizedMdsMatrices {");
+        let inner = indent + INDENT;
+        let square = |n: usize| format!("SquareMatrix::<{}, {}>", n, n * n);
+        let row = format!("Matrix::<1, {}, {}>", t - 1, t - 1);
+        let column = format!("Matrix::<{}, 1, {}>", t - 1, t - 1);
+        out.matrix(
+            inner,
+            &format!("M_hat: {}", square(t - 1)),
+            optimized.M_hat.elements(),
+        );
+        out.matrix(inner, &format!("v: {}", row), optimized.v.elements());
+        out.matrix(inner, &format!("w: {}", column), optimized.w.elements());
+        out.matrix(
+            inner,
+            &format!("M_prime: {}", square(t)),
+            optimized.M_prime.elements(),
+        );
+        out.matrix(
+            inner,
+            &format!("M_doubleprime: {}", square(t)),
+            optimized.M_doubleprime.elements(),
+        );
+        out.matrix(
+            inner,
+            &format!("M_inverse: {}", square(t)),
+            optimized.M_inverse.elements(),
+        );
+        out.matrix(
+            inner,
+            &format!("M_hat_inverse: {}", square(t - 1)),
+            optimized.M_hat_inverse.elements(),
+        );
+        out.element(inner, "M_00: ", optimized.M_00);
+        out.matrix(
+            inner,
+            &format!("M_i: Matrix::<{}, {}, {}>", t, t, t * t),
+            optimized.M_i.elements(),
+        );
+        out.line(inner, "v_collection: [");
+        for v in optimized.v_collection.iter() {
+            out.matrix(inner + INDENT, &row, v.elements());
+        }
+        out.line(inner, "],");
+        out.line(inner, "w_hat_collection: [");
+        for w_hat in optimized.w_hat_collection.iter() {
+            out.matrix(inner + INDENT, &column, w_hat.elements());
+        }
+        out.line(inner, "],");
+        out.line(indent, "},");
+
+        out.matrix(
+            indent,
+            &format!(
+                "optimized_arc: OptimizedArcMatrix::<{}, {}, {}>",
+                rounds,
+                t,
+                rounds * t
+            ),
+            self.optimized_arc.elements(),
+        );
+        out.line(INDENT, "}");
+        out.line(0, "}");
+
+        Ok(out.source)
+    }
+}
+
+impl<
+        const STATE_SIZE: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+    >
+    v2::PoseidonParameters<
+        STATE_SIZE,
+        NUM_MDS_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+    >
+{
+    /// Render these parameters as a Rust module defining `pub const fn name()`, which
+    /// returns them without any computation at runtime.
+    ///
+    /// The function is documented with `doc`. As for v1, the source is formatted as
+    /// `rustfmt` would, so that it can be vendored in `poseidon377/src/params/v2`.
+    pub fn to_rust_source(&self, name: &str, doc: &str) -> String {
+        let mut source = String::from(
+            "use decaf377::Fq;
+use poseidon_parameters::v2::{Alpha, ArcMatrix, PoseidonParameters, RoundNumbers, SquareMatrix};
+
+",
+        );
+        push_doc(&mut source, doc);
+        let _ = writeln!(
+            source,
+            "pub const fn {}() -> PoseidonParameters<{}, {}, {}, {}, {}> {{",
+            name, STATE_SIZE, NUM_MDS_ELEMENTS, NUM_ROUND_ROWS, NUM_ROUND_COLS, NUM_ROUND_ELEMENTS,
+        );
+
+        let mut out = Writer { source };
+        out.line(INDENT, "PoseidonParameters {");
+        let indent = 2 * INDENT;
+        out.line(indent, &format!("M: {},", self.M));
+        out.line(indent, &format!("alpha: {},", alpha_source(self.alpha)));
+        out.line(
+            indent,
+            &format!(
+                "rounds: RoundNumbers {{ r_P: {}, r_F: {} }},",
+                self.rounds.partial(),
+                self.rounds.full()
+            ),
+        );
+        let square = format!("SquareMatrix::<{}, {}>", STATE_SIZE, NUM_MDS_ELEMENTS);
+        out.matrix(indent, &format!("m_e: {}", square), self.m_e.elements());
+        out.matrix(indent, &format!("m_i: {}", square), self.m_i.elements());
+        out.matrix(
+            indent,
+            &format!(
+                "arc: ArcMatrix::<{}, {}, {}>",
+                NUM_ROUND_ROWS, NUM_ROUND_COLS, NUM_ROUND_ELEMENTS
+            ),
+            self.arc.elements(),
+        );
+        out.line(INDENT, "}");
+        out.line(0, "}");
+
+        out.source
+    }
+}
+
+/// Write `doc` as the doc comment of the item that follows.
+fn push_doc(source: &mut String, doc: &str) {
+    for line in doc.lines() {
+        source.push_str("///");
+        if !line.is_empty() {
+            source.push(' ');
+            source.push_str(line);
+        }
+        source.push('\n');
+    }
+}
+
+fn alpha_source(alpha: Alpha) -> String {
+    match alpha {
+        Alpha::Exponent(exp) => format!("Alpha::Exponent({})", exp),
+        Alpha::Inverse => String::from("Alpha::Inverse"),
+    }
+}
+
+/// The Montgomery limbs of `x`, as expected by [`Fq::from_montgomery_limbs`].
+fn montgomery_limbs(x: Fq) -> [u64; 4] {
+    // The Montgomery form of `x` is `x * R` with `R = 2^256`, reduced.
+    let mut r = Fq::from(2u64);
+    for _ in 0..8 {
+        r = r * r;
+    }
+    let bytes = (x * r).to_bytes();
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+        let mut le = [0u8; 8];
+        le.copy_from_slice(chunk);
+        *limb = u64::from_le_bytes(le);
+    }
+    limbs
+}
+
+/// Accumulates the lines of the generated source.
+struct Writer {
+    source: String,
+}
+
+impl Writer {
+    fn line(&mut self, indent: usize, line: &str) {
+        let _ = writeln!(self.source, "{:indent$}{}", "", line, indent = indent);
+    }
+
+    /// Write the construction of a matrix of `elements` with the type path `ty`, which
+    /// may start with a field name, and a trailing comma.
+    fn matrix(&mut self, indent: usize, ty: &str, elements: &[Fq]) {
+        let head = format!("{}::new_from_known(", ty);
+        if let [element] = elements {
+            let limbs = montgomery_limbs(*element);
+            let argument = format!("[Fq::from_montgomery_limbs({})]", limbs_on_one_line(&limbs));
+            if fits(indent, &head, &argument, "),") {
+                self.line(indent, &format!("{}{}),", head, argument));
+            } else {
+                // A single element overflows into the call.
+                self.line(indent, &format!("{}[Fq::from_montgomery_limbs([", head));
+                self.limbs(indent + INDENT, &limbs);
+                self.line(indent, "])]),");
+            }
+            return;
+        }
+
+        self.line(indent, &format!("{}[", head));
+        for element in elements {
+            self.element(indent + INDENT, "", *element);
+        }
+        self.line(indent, "]),");
+    }
+
+    /// Write `prefix` followed by the construction of `element`, and a trailing comma.
+    fn element(&mut self, indent: usize, prefix: &str, element: Fq) {
+        let limbs = montgomery_limbs(element);
+        let head = format!("{}Fq::from_montgomery_limbs(", prefix);
+        let argument = limbs_on_one_line(&limbs);
+        if fits(indent, &head, &argument, "),") {
+            self.line(indent, &format!("{}{}),", head, argument));
+        } else {
+            self.line(indent, &format!("{}[", head));
+            self.limbs(indent + INDENT, &limbs);
+            self.line(indent, "]),");
+        }
+    }
+
+    fn limbs(&mut self, indent: usize, limbs: &[u64; 4]) {
+        for limb in limbs.iter() {
+            self.line(indent, &format!("{},", limb));
+        }
+    }
+}
+
+fn limbs_on_one_line(limbs: &[u64; 4]) -> String {
+    format!("[{}, {}, {}, {}]", limbs[0], limbs[1], limbs[2], limbs[3])
+}
+
+/// Whether `rustfmt` keeps the call `head` with `argument` on one line.
+fn fits(indent: usize, head: &str, argument: &str, tail: &str) -> bool {
+    argument.len() <= FN_CALL_WIDTH
+        && indent + head.len() + argument.len() + tail.len() <= MAX_WIDTH
+}
@@ -0,0 +1,472 @@
+use alloc::vec::Vec;
+use core::{
+    convert::{TryFrom, TryInto},
+    slice::Chunks,
+};
+
+use decaf377::Fq;
+
+use crate::{
+    alpha::Alpha,
+    error::PoseidonParameterError,
+    matrix::mul_elements_into,
+    matrix_ops::MatrixOperations,
+    round_numbers::RoundNumbers,
+    sparse_matrix::SparseMatrix,
+    v1::{
+        ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices, PoseidonParameters,
+        SquareMatrix,
+    },
+};
+
+/// A matrix whose dimensions are chosen at runtime.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynMatrix {
+    n_rows: usize,
+    n_cols: usize,
+    elements: Vec<Fq>,
+}
+
+impl DynMatrix {
+    /// Create an `n_rows x n_cols` matrix from its elements in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are not `n_rows * n_cols` elements, see [`DynMatrix::try_new`].
+    pub fn new(n_rows: usize, n_cols: usize, elements: Vec<Fq>) -> Self {
+        Self::try_new(n_rows, n_cols, elements)
+            .expect("Matrix has an insufficient number of elements")
+    }
+
+    /// Create an `n_rows x n_cols` matrix from its elements in row-major order, or return
+    /// [`PoseidonParameterError::InvalidMatrixDimensions`] if there are not
+    /// `n_rows * n_cols` elements.
+    pub fn try_new(
+        n_rows: usize,
+        n_cols: usize,
+        elements: Vec<Fq>,
+    ) -> Result<Self, PoseidonParameterError> {
+        if Some(elements.len()) != n_rows.checked_mul(n_cols) {
+            return Err(PoseidonParameterError::InvalidMatrixDimensions);
+        }
+        Ok(Self {
+            n_rows,
+            n_cols,
+            elements,
+        })
+    }
+
+    /// Elements in row-major order.
+    pub fn elements(&self) -> &[Fq] {
+        &self.elements
+    }
+
+    /// Get the element in row `i` and column `j`.
+    pub fn get_element(&self, i: usize, j: usize) -> Fq {
+        self.elements[i * self.n_cols + j]
+    }
+
+    /// Get rows
+    pub fn iter_rows(&self) -> Chunks<'_, Fq> {
+        self.elements.chunks(self.n_cols)
+    }
+
+    /// Number of rows
+    pub fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    /// Number of columns
+    pub fn n_cols(&self) -> usize {
+        self.n_cols
+    }
+
+    /// Multiply this matrix by `rhs`.
+    ///
+    /// Returns [`PoseidonParameterError::InvalidMatrixDimensions`] if the number of
+    /// columns of this matrix is not the number of rows of `rhs`.
+    pub fn mat_mul(&self, rhs: &DynMatrix) -> Result<DynMatrix, PoseidonParameterError> {
+        let mut result = DynMatrix {
+            n_rows: 0,
+            n_cols: 0,
+            elements: Vec::new(),
+        };
+        self.mat_mul_into(rhs, &mut result)?;
+        Ok(result)
+    }
+
+    /// Multiply this matrix by `rhs`, writing the product to `out` and reusing its
+    /// allocation.
+    ///
+    /// Returns [`PoseidonParameterError::InvalidMatrixDimensions`] under the same
+    /// conditions as [`DynMatrix::mat_mul`], in which case `out` is left unchanged.
+    pub fn mat_mul_into(
+        &self,
+        rhs: &DynMatrix,
+        out: &mut DynMatrix,
+    ) -> Result<(), PoseidonParameterError> {
+        if self.n_cols != rhs.n_rows {
+            return Err(PoseidonParameterError::InvalidMatrixDimensions);
+        }
+
+        out.n_rows = self.n_rows;
+        out.n_cols = rhs.n_cols;
+        out.elements.clear();
+        out.elements.resize(self.n_rows * rhs.n_cols, Fq::default());
+        mul_elements_into(
+            &self.elements,
+            &rhs.elements,
+            self.n_cols,
+            rhs.n_cols,
+            &mut out.elements,
+        );
+        Ok(())
+    }
+
+    fn has_dimensions(&self, n_rows: usize, n_cols: usize) -> bool {
+        self.n_rows == n_rows && self.n_cols == n_cols
+    }
+}
+
+/// A set of Poseidon1 parameters whose width is chosen at runtime.
+///
+/// This mirrors [`PoseidonParameters`](crate::v1::PoseidonParameters), with every
+/// matrix stored on the heap, so that parameters for any rate can be selected or
+/// loaded at runtime. The dimensions of the matrices are checked by
+/// [`DynPoseidonParameters::check_dimensions`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynPoseidonParameters {
+    // Input parameters.
+    /// Security level.
+    pub M: usize,
+
+    // Generated parameters.
+    /// Exponent of the Sbox, i.e. S-box(x) = x^{\alpha} used in the `SubWords` step
+    pub alpha: Alpha,
+
+    /// Round numbers
+    pub rounds: RoundNumbers,
+
+    /// `t x t` MDS matrix used in the `MixLayer` step
+    pub mds: DynMatrix,
+
+    /// `num_total_rounds x t` matrix of constants used in the `AddRoundConstant` step
+    pub arc: DynMatrix,
+
+    /// Optimized round constants.
+    pub optimized_arc: DynMatrix,
+
+    /// Optimized MDS matrices.
+    pub optimized_mds: DynOptimizedMdsMatrices,
+}
+
+/// Optimized MDS matrices whose dimensions are chosen at runtime, mirroring
+/// [`OptimizedMdsMatrices`](crate::v1::OptimizedMdsMatrices).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynOptimizedMdsMatrices {
+    /// A (t - 1) x (t - 1) MDS submatrix derived from the MDS matrix.
+    pub M_hat: DynMatrix,
+    /// A 1 x (t - 1) (row) vector derived from the MDS matrix.
+    pub v: DynMatrix,
+    /// A (t - 1) x 1 (column) vector derived from the MDS matrix.
+    pub w: DynMatrix,
+    /// A matrix formed from Mhat (an MDS submatrix of the MDS matrix).
+    pub M_prime: DynMatrix,
+    /// A sparse matrix formed from M,
+    pub M_doubleprime: DynMatrix,
+    /// The inverse of the t x t MDS matrix (needed to compute round constants).
+    pub M_inverse: DynMatrix,
+    /// The inverse of the (t - 1) x (t - 1) Mhat matrix.
+    pub M_hat_inverse: DynMatrix,
+    /// Element at M00
+    pub M_00: Fq,
+    /// M_i
+    pub M_i: DynMatrix,
+    /// v_collection: one per partial round.
+    pub v_collection: Vec<DynMatrix>,
+    /// w_hat_collection: one per round
+    pub w_hat_collection: Vec<DynMatrix>,
+}
+
+impl DynPoseidonParameters {
+    /// The width `t` of the permutation.
+    pub fn state_size(&self) -> usize {
+        self.mds.n_rows()
+    }
+
+    /// Check that every matrix has the dimensions implied by the state size and the
+    /// round numbers.
+    pub fn check_dimensions(&self) -> Result<(), PoseidonParameterError> {
+        let t = self.state_size();
+        let rounds = self.rounds.total();
+        let optimized = &self.optimized_mds;
+
+        let valid = t >= 2
+            && self.rounds.full().is_multiple_of(2)
+            && self.rounds.partial() >= 1
+            && self.mds.has_dimensions(t, t)
+            && self.arc.has_dimensions(rounds, t)
+            && self.optimized_arc.has_dimensions(rounds, t)
+            && optimized.M_hat.has_dimensions(t - 1, t - 1)
+            && optimized.v.has_dimensions(1, t - 1)
+            && optimized.w.has_dimensions(t - 1, 1)
+            && optimized.M_prime.has_dimensions(t, t)
+            && optimized.M_doubleprime.has_dimensions(t, t)
+            && optimized.M_inverse.has_dimensions(t, t)
+            && optimized.M_hat_inverse.has_dimensions(t - 1, t - 1)
+            && optimized.M_i.has_dimensions(t, t)
+            && optimized.v_collection.len() == self.rounds.partial()
+            && optimized
+                .v_collection
+                .iter()
+                .all(|v| v.has_dimensions(1, t - 1))
+            && optimized.w_hat_collection.len() == self.rounds.partial()
+            && optimized
+                .w_hat_collection
+                .iter()
+                .all(|w_hat| w_hat.has_dimensions(t - 1, 1));
+
+        if valid {
+            Ok(())
+        } else {
+            Err(PoseidonParameterError::InvalidMatrixDimensions)
+        }
+    }
+}
+
+impl DynOptimizedMdsMatrices {
+    /// The sparse matrix at `index` in `v_collection` and `w_hat_collection`, which are
+    /// ordered from the last partial round backwards.
+    pub fn sparse_matrix(&self, index: usize) -> SparseMatrix<'_> {
+        SparseMatrix {
+            M_00: self.M_00,
+            v: self.v_collection[index].elements(),
+            w_hat: self.w_hat_collection[index].elements(),
+        }
+    }
+}
+
+impl DynMatrix {
+    fn from_matrix<M: MatrixOperations>(matrix: &M) -> Self {
+        Self::new(matrix.n_rows(), matrix.n_cols(), matrix.elements().to_vec())
+    }
+
+    fn to_array<const N: usize>(&self) -> Result<[Fq; N], PoseidonParameterError> {
+        self.elements
+            .as_slice()
+            .try_into()
+            .map_err(|_| PoseidonParameterError::DimensionMismatch)
+    }
+}
+
+impl<
+        const STATE_SIZE: usize,
+        const STATE_SIZE_MINUS_1: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+        const NUM_PARTIAL_ROUNDS: usize,
+    >
+    From<
+        &PoseidonParameters<
+            STATE_SIZE,
+            STATE_SIZE_MINUS_1,
+            NUM_MDS_ELEMENTS,
+            NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+            NUM_ROUND_ROWS,
+            NUM_ROUND_COLS,
+            NUM_ROUND_ELEMENTS,
+            NUM_PARTIAL_ROUNDS,
+        >,
+    > for DynPoseidonParameters
+{
+    fn from(
+        parameters: &PoseidonParameters<
+            STATE_SIZE,
+            STATE_SIZE_MINUS_1,
+            NUM_MDS_ELEMENTS,
+            NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+            NUM_ROUND_ROWS,
+            NUM_ROUND_COLS,
+            NUM_ROUND_ELEMENTS,
+            NUM_PARTIAL_ROUNDS,
+        >,
+    ) -> Self {
+        let optimized = &parameters.optimized_mds;
+        Self {
+            M: parameters.M,
+            alpha: parameters.alpha,
+            rounds: parameters.rounds,
+            mds: DynMatrix::from_matrix(&parameters.mds),
+            arc: DynMatrix::from_matrix(&parameters.arc),
+            optimized_arc: DynMatrix::from_matrix(&parameters.optimized_arc),
+            optimized_mds: DynOptimizedMdsMatrices {
+                M_hat: DynMatrix::from_matrix(&optimized.M_hat),
+                v: DynMatrix::from_matrix(&optimized.v),
+                w: DynMatrix::from_matrix(&optimized.w),
+                M_prime: DynMatrix::from_matrix(&optimized.M_prime),
+                M_doubleprime: DynMatrix::from_matrix(&optimized.M_doubleprime),
+                M_inverse: DynMatrix::from_matrix(&optimized.M_inverse),
+                M_hat_inverse: DynMatrix::from_matrix(&optimized.M_hat_inverse),
+                M_00: optimized.M_00,
+                M_i: DynMatrix::from_matrix(&optimized.M_i),
+                v_collection: optimized
+                    .v_collection
+                    .iter()
+                    .map(DynMatrix::from_matrix)
+                    .collect(),
+                w_hat_collection: optimized
+                    .w_hat_collection
+                    .iter()
+                    .map(DynMatrix::from_matrix)
+                    .collect(),
+            },
+        }
+    }
+}
+
+impl<
+        const STATE_SIZE: usize,
+        const STATE_SIZE_MINUS_1: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+        const NUM_PARTIAL_ROUNDS: usize,
+    >
+    From<
+        PoseidonParameters<
+            STATE_SIZE,
+            STATE_SIZE_MINUS_1,
+            NUM_MDS_ELEMENTS,
+            NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+            NUM_ROUND_ROWS,
+            NUM_ROUND_COLS,
+            NUM_ROUND_ELEMENTS,
+            NUM_PARTIAL_ROUNDS,
+        >,
+    > for DynPoseidonParameters
+{
+    fn from(
+        parameters: PoseidonParameters<
+            STATE_SIZE,
+            STATE_SIZE_MINUS_1,
+            NUM_MDS_ELEMENTS,
+            NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+            NUM_ROUND_ROWS,
+            NUM_ROUND_COLS,
+            NUM_ROUND_ELEMENTS,
+            NUM_PARTIAL_ROUNDS,
+        >,
+    ) -> Self {
+        Self::from(&parameters)
+    }
+}
+
+/// Convert runtime parameters to the const-generic type with the same dimensions.
+///
+/// Fails with [`PoseidonParameterError::InvalidMatrixDimensions`] if the matrices do
+/// not pass [`DynPoseidonParameters::check_dimensions`], and with
+/// [`PoseidonParameterError::DimensionMismatch`] if their dimensions are not those of
+/// the target type.
+impl<
+        const STATE_SIZE: usize,
+        const STATE_SIZE_MINUS_1: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+        const NUM_PARTIAL_ROUNDS: usize,
+    > TryFrom<&DynPoseidonParameters>
+    for PoseidonParameters<
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >
+{
+    type Error = PoseidonParameterError;
+
+    fn try_from(parameters: &DynPoseidonParameters) -> Result<Self, Self::Error> {
+        parameters.check_dimensions()?;
+        let t = parameters.state_size();
+        let dimensions_match = t == STATE_SIZE
+            && t == STATE_SIZE_MINUS_1 + 1
+            && parameters.rounds.total() == NUM_ROUND_ROWS
+            && parameters.rounds.partial() == NUM_PARTIAL_ROUNDS
+            && NUM_ROUND_COLS == t;
+        if !dimensions_match {
+            return Err(PoseidonParameterError::DimensionMismatch);
+        }
+
+        let optimized = &parameters.optimized_mds;
+        let mut v_collection = [(); NUM_PARTIAL_ROUNDS]
+            .map(|()| Matrix::new_from_known([Fq::default(); STATE_SIZE_MINUS_1]));
+        for (v, dyn_v) in v_collection.iter_mut().zip(&optimized.v_collection) {
+            *v = Matrix::new_from_known(dyn_v.to_array()?);
+        }
+        let mut w_hat_collection = [(); NUM_PARTIAL_ROUNDS]
+            .map(|()| Matrix::new_from_known([Fq::default(); STATE_SIZE_MINUS_1]));
+        for (w_hat, dyn_w_hat) in w_hat_collection.iter_mut().zip(&optimized.w_hat_collection) {
+            *w_hat = Matrix::new_from_known(dyn_w_hat.to_array()?);
+        }
+
+        Ok(Self {
+            M: parameters.M,
+            alpha: parameters.alpha,
+            rounds: parameters.rounds,
+            mds: MdsMatrix::new_from_known(parameters.mds.to_array()?),
+            arc: ArcMatrix::new_from_known(parameters.arc.to_array()?),
+            optimized_arc: OptimizedArcMatrix::new_from_known(parameters.optimized_arc.to_array()?),
+            optimized_mds: OptimizedMdsMatrices {
+                M_hat: SquareMatrix::new_from_known(optimized.M_hat.to_array()?),
+                v: Matrix::new_from_known(optimized.v.to_array()?),
+                w: Matrix::new_from_known(optimized.w.to_array()?),
+                M_prime: SquareMatrix::new_from_known(optimized.M_prime.to_array()?),
+                M_doubleprime: SquareMatrix::new_from_known(optimized.M_doubleprime.to_array()?),
+                M_inverse: SquareMatrix::new_from_known(optimized.M_inverse.to_array()?),
+                M_hat_inverse: SquareMatrix::new_from_known(optimized.M_hat_inverse.to_array()?),
+                M_00: optimized.M_00,
+                M_i: Matrix::new_from_known(optimized.M_i.to_array()?),
+                v_collection,
+                w_hat_collection,
+            },
+        })
+    }
+}
+
+impl<
+        const STATE_SIZE: usize,
+        const STATE_SIZE_MINUS_1: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+        const NUM_PARTIAL_ROUNDS: usize,
+    > TryFrom<DynPoseidonParameters>
+    for PoseidonParameters<
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >
+{
+    type Error = PoseidonParameterError;
+
+    fn try_from(parameters: DynPoseidonParameters) -> Result<Self, Self::Error> {
+        Self::try_from(&parameters)
+    }
+}
@@ -0,0 +1,493 @@
+//! A canonical, versioned binary encoding of v1 parameters, see
+//! [`PoseidonParameters::to_bytes`] and [`PoseidonParameters::from_bytes`].
+//!
+//! [`DynPoseidonParameters`] use the same encoding, so parameters can be written
+//! with either type and read with the other.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::convert::TryFrom;
+use core::convert::TryInto;
+
+use decaf377::Fq;
+
+#[cfg(feature = "alloc")]
+use crate::dynamic::{DynMatrix, DynOptimizedMdsMatrices, DynPoseidonParameters};
+use crate::{
+    error::PoseidonParameterError,
+    matrix_ops::{MatrixOperations, SquareMatrixOperations},
+    v1::{
+        Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+        PoseidonParameters, RoundNumbers, SquareMatrix,
+    },
+};
+
+/// Magic bytes starting every encoding.
+const MAGIC: [u8; 4] = *b"PSDN";
+
+/// Version of the encoding.
+const VERSION: u8 = 1;
+
+/// Length of the header preceding the field elements.
+const HEADER_LEN: usize = MAGIC.len() + 1 + FQ_LEN + 5 * 4;
+
+/// Length of an encoded field element.
+const FQ_LEN: usize = 32;
+
+/// The modulus of [`Fq`] in little-endian order.
+fn modulus() -> [u8; FQ_LEN] {
+    // The modulus is not an element of the field, but its predecessor is.
+    let mut modulus = (-Fq::from(1u64)).to_bytes();
+    for byte in modulus.iter_mut() {
+        let (sum, carry) = byte.overflowing_add(1);
+        *byte = sum;
+        if !carry {
+            break;
+        }
+    }
+    modulus
+}
+
+impl<
+        const STATE_SIZE: usize,
+        const STATE_SIZE_MINUS_1: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+        const NUM_PARTIAL_ROUNDS: usize,
+    >
+    PoseidonParameters<
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >
+{
+    /// Length in bytes of the encoding of these parameters.
+    pub const ENCODED_LEN: usize = HEADER_LEN
+        + FQ_LEN
+            * (5 * NUM_MDS_ELEMENTS
+                + 2 * NUM_ROUND_ELEMENTS
+                + 2 * NUM_STATE_SIZE_MINUS_1_ELEMENTS
+                + 2 * STATE_SIZE_MINUS_1
+                + 2 * NUM_PARTIAL_ROUNDS * STATE_SIZE_MINUS_1
+                + 1);
+
+    /// Encode the parameters in their canonical binary encoding.
+    ///
+    /// The encoding is a fixed 57-byte header followed by every field element of the
+    /// parameters, each as its canonical 32-byte little-endian encoding. Integers are
+    /// 32-bit little-endian.
+    ///
+    /// | bytes  | contents                                                       |
+    /// |--------|----------------------------------------------------------------|
+    /// | 4      | magic bytes, `PSDN`                                            |
+    /// | 1      | format version, currently 1                                    |
+    /// | 32     | field modulus, little-endian                                   |
+    /// | 4      | security level `M`                                             |
+    /// | 4      | state size `t`                                                 |
+    /// | 4      | full rounds `r_F`                                              |
+    /// | 4      | partial rounds `r_P`                                           |
+    /// | 4      | `alpha`, as in [`Alpha::to_bytes_le`]                          |
+    /// | 32 * n | the MDS matrix, round constants, optimized round constants and |
+    /// |        | optimized MDS matrices, in declaration order and row-major     |
+    #[cfg(feature = "alloc")]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(Self::ENCODED_LEN);
+        Header {
+            M: self.M,
+            t: STATE_SIZE,
+            rounds: self.rounds,
+            alpha: self.alpha,
+        }
+        .write(&mut out);
+
+        let mds = &self.optimized_mds;
+        for elements in [
+            self.mds.elements(),
+            self.arc.elements(),
+            self.optimized_arc.elements(),
+            mds.M_hat.elements(),
+            mds.v.elements(),
+            mds.w.elements(),
+            mds.M_prime.elements(),
+            mds.M_doubleprime.elements(),
+            mds.M_inverse.elements(),
+            mds.M_hat_inverse.elements(),
+            core::slice::from_ref(&mds.M_00),
+            mds.M_i.elements(),
+        ]
+        .iter()
+        .copied()
+        .chain(mds.v_collection.iter().map(|v| v.elements()))
+        .chain(mds.w_hat_collection.iter().map(|w_hat| w_hat.elements()))
+        {
+            write_elements(&mut out, elements);
+        }
+
+        debug_assert_eq!(out.len(), Self::ENCODED_LEN);
+        out
+    }
+
+    /// Decode parameters encoded with [`PoseidonParameters::to_bytes`].
+    ///
+    /// Decoding checks every header field against the parameter type and the field,
+    /// rejects non-canonical field elements, truncated input and trailing bytes, and
+    /// checks that the matrices derived from the MDS matrix are consistent with it.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PoseidonParameterError> {
+        let (header, mut reader) = Header::read(bytes)?;
+        let Header {
+            M,
+            t,
+            rounds,
+            alpha,
+        } = header;
+        let (r_F, r_P) = (rounds.full(), rounds.partial());
+
+        let dimensions_match = t == STATE_SIZE
+            && t == STATE_SIZE_MINUS_1 + 1
+            && NUM_MDS_ELEMENTS == t * t
+            && NUM_STATE_SIZE_MINUS_1_ELEMENTS == (t - 1) * (t - 1)
+            && r_P == NUM_PARTIAL_ROUNDS
+            && NUM_ROUND_ROWS == r_F + r_P
+            && NUM_ROUND_COLS == t
+            && NUM_ROUND_ELEMENTS == NUM_ROUND_ROWS * NUM_ROUND_COLS;
+        if !dimensions_match {
+            return Err(PoseidonParameterError::DimensionMismatch);
+        }
+        if bytes.len() != Self::ENCODED_LEN {
+            return Err(PoseidonParameterError::InvalidEncoding);
+        }
+
+        let mds = MdsMatrix::new_from_known(reader.elements()?);
+        let arc = ArcMatrix::new_from_known(reader.elements()?);
+        let optimized_arc = OptimizedArcMatrix::new_from_known(reader.elements()?);
+        let M_hat = SquareMatrix::new_from_known(reader.elements()?);
+        let v = Matrix::new_from_known(reader.elements()?);
+        let w = Matrix::new_from_known(reader.elements()?);
+        let M_prime = SquareMatrix::new_from_known(reader.elements()?);
+        let M_doubleprime = SquareMatrix::new_from_known(reader.elements()?);
+        let M_inverse = SquareMatrix::new_from_known(reader.elements()?);
+        let M_hat_inverse = SquareMatrix::new_from_known(reader.elements()?);
+        let [M_00] = reader.elements()?;
+        let M_i = Matrix::new_from_known(reader.elements()?);
+        let v_collection = reader.matrices()?;
+        let w_hat_collection = reader.matrices()?;
+        debug_assert!(reader.0.is_empty());
+
+        let consistent = M_00 == mds.get_element(0, 0)
+            && M_hat == mds.hat()
+            && v == mds.v()
+            && w == mds.w()
+            && mds.0.inverse().ok().as_ref() == Some(&M_inverse)
+            && M_hat.inverse().ok().as_ref() == Some(&M_hat_inverse);
+        if !consistent {
+            return Err(PoseidonParameterError::InconsistentParameters);
+        }
+
+        Ok(Self {
+            M,
+            alpha,
+            rounds,
+            mds,
+            arc,
+            optimized_arc,
+            optimized_mds: OptimizedMdsMatrices {
+                M_hat,
+                v,
+                w,
+                M_prime,
+                M_doubleprime,
+                M_inverse,
+                M_hat_inverse,
+                M_00,
+                M_i,
+                v_collection,
+                w_hat_collection,
+            },
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl DynPoseidonParameters {
+    /// Encode the parameters, as with [`PoseidonParameters::to_bytes`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters fail [`DynPoseidonParameters::check_dimensions`].
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.check_dimensions()
+            .expect("parameters have consistent dimensions");
+
+        let mut out = Vec::new();
+        Header {
+            M: self.M,
+            t: self.state_size(),
+            rounds: self.rounds,
+            alpha: self.alpha,
+        }
+        .write(&mut out);
+
+        let mds = &self.optimized_mds;
+        for elements in [
+            self.mds.elements(),
+            self.arc.elements(),
+            self.optimized_arc.elements(),
+            mds.M_hat.elements(),
+            mds.v.elements(),
+            mds.w.elements(),
+            mds.M_prime.elements(),
+            mds.M_doubleprime.elements(),
+            mds.M_inverse.elements(),
+            mds.M_hat_inverse.elements(),
+            core::slice::from_ref(&mds.M_00),
+            mds.M_i.elements(),
+        ]
+        .iter()
+        .copied()
+        .chain(mds.v_collection.iter().map(|v| v.elements()))
+        .chain(mds.w_hat_collection.iter().map(|w_hat| w_hat.elements()))
+        {
+            write_elements(&mut out, elements);
+        }
+        out
+    }
+
+    /// Decode parameters of any width, as with [`PoseidonParameters::from_bytes`].
+    ///
+    /// The dimensions of the matrices are taken from the header, and the encoding is
+    /// validated as strictly as for the const-generic parameters.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PoseidonParameterError> {
+        let (header, mut reader) = Header::read(bytes)?;
+        if header.encoded_len() != Some(bytes.len()) {
+            return Err(PoseidonParameterError::InvalidEncoding);
+        }
+
+        let t = header.t;
+        let rounds = header.rounds.total();
+        let mds = reader.dyn_matrix(t, t)?;
+        let arc = reader.dyn_matrix(rounds, t)?;
+        let optimized_arc = reader.dyn_matrix(rounds, t)?;
+        let M_hat = reader.dyn_matrix(t - 1, t - 1)?;
+        let v = reader.dyn_matrix(1, t - 1)?;
+        let w = reader.dyn_matrix(t - 1, 1)?;
+        let M_prime = reader.dyn_matrix(t, t)?;
+        let M_doubleprime = reader.dyn_matrix(t, t)?;
+        let M_inverse = reader.dyn_matrix(t, t)?;
+        let M_hat_inverse = reader.dyn_matrix(t - 1, t - 1)?;
+        let M_00 = reader.element()?;
+        let M_i = reader.dyn_matrix(t, t)?;
+        let v_collection = (0..header.rounds.partial())
+            .map(|_| reader.dyn_matrix(1, t - 1))
+            .collect::<Result<Vec<_>, _>>()?;
+        let w_hat_collection = (0..header.rounds.partial())
+            .map(|_| reader.dyn_matrix(t - 1, 1))
+            .collect::<Result<Vec<_>, _>>()?;
+        debug_assert!(reader.0.is_empty());
+
+        let minor = |i: usize, j: usize| mds.get_element(i + 1, j + 1);
+        let consistent = M_00 == mds.get_element(0, 0)
+            && (0..t - 1).all(|i| {
+                v.get_element(0, i) == mds.get_element(0, i + 1)
+                    && w.get_element(i, 0) == mds.get_element(i + 1, 0)
+                    && (0..t - 1).all(|j| M_hat.get_element(i, j) == minor(i, j))
+            })
+            && is_inverse(t, |i, j| mds.get_element(i, j), &M_inverse)
+            && is_inverse(t - 1, minor, &M_hat_inverse);
+        if !consistent {
+            return Err(PoseidonParameterError::InconsistentParameters);
+        }
+
+        Ok(Self {
+            M: header.M,
+            alpha: header.alpha,
+            rounds: header.rounds,
+            mds,
+            arc,
+            optimized_arc,
+            optimized_mds: DynOptimizedMdsMatrices {
+                M_hat,
+                v,
+                w,
+                M_prime,
+                M_doubleprime,
+                M_inverse,
+                M_hat_inverse,
+                M_00,
+                M_i,
+                v_collection,
+                w_hat_collection,
+            },
+        })
+    }
+}
+
+/// Whether `inverse` is the inverse of the `n x n` matrix with elements `matrix(i, j)`.
+#[cfg(feature = "alloc")]
+pub(crate) fn is_inverse(
+    n: usize,
+    matrix: impl Fn(usize, usize) -> Fq,
+    inverse: &DynMatrix,
+) -> bool {
+    (0..n).all(|i| {
+        (0..n).all(|j| {
+            let product: Fq = (0..n)
+                .map(|k| matrix(i, k) * inverse.get_element(k, j))
+                .sum();
+            product == Fq::from((i == j) as u64)
+        })
+    })
+}
+
+/// The header of an encoding.
+struct Header {
+    M: usize,
+    t: usize,
+    rounds: RoundNumbers,
+    alpha: Alpha,
+}
+
+impl Header {
+    #[cfg(feature = "alloc")]
+    fn write(&self, out: &mut Vec<u8>) {
+        let to_u32 = |n: usize| -> [u8; 4] {
+            u32::try_from(n)
+                .expect("parameters fit in 32 bits")
+                .to_le_bytes()
+        };
+
+        out.extend_from_slice(&MAGIC);
+        out.push(VERSION);
+        out.extend_from_slice(&modulus());
+        out.extend_from_slice(&to_u32(self.M));
+        out.extend_from_slice(&to_u32(self.t));
+        out.extend_from_slice(&to_u32(self.rounds.full()));
+        out.extend_from_slice(&to_u32(self.rounds.partial()));
+        out.extend_from_slice(&self.alpha.to_bytes_le());
+    }
+
+    /// Read and check the header, returning a reader over the field elements.
+    fn read(bytes: &[u8]) -> Result<(Self, Reader<'_>), PoseidonParameterError> {
+        if bytes.len() < HEADER_LEN || bytes[..MAGIC.len()] != MAGIC {
+            return Err(PoseidonParameterError::InvalidEncoding);
+        }
+        let mut reader = Reader(&bytes[MAGIC.len()..]);
+
+        let version = reader.take(1)[0];
+        if version != VERSION {
+            return Err(PoseidonParameterError::UnsupportedVersion(version));
+        }
+        if reader.take(FQ_LEN) != modulus() {
+            return Err(PoseidonParameterError::FieldMismatch);
+        }
+
+        let M = reader.u32() as usize;
+        let t = reader.u32() as usize;
+        let r_F = reader.u32() as usize;
+        let r_P = reader.u32() as usize;
+        let alpha = match reader.u32() {
+            u32::MAX => Alpha::Inverse,
+            exp if exp > 1 => Alpha::Exponent(exp),
+            _ => return Err(PoseidonParameterError::InvalidAlpha),
+        };
+        if t < 2 || !r_F.is_multiple_of(2) || r_P == 0 {
+            return Err(PoseidonParameterError::DimensionMismatch);
+        }
+
+        let header = Self {
+            M,
+            t,
+            rounds: RoundNumbers { r_P, r_F },
+            alpha,
+        };
+        Ok((header, reader))
+    }
+
+    /// Length of the whole encoding, or `None` if it does not fit in a `usize`.
+    #[cfg(feature = "alloc")]
+    fn encoded_len(&self) -> Option<usize> {
+        let t = self.t;
+        let rounds = self.rounds.full().checked_add(self.rounds.partial())?;
+        let square = t.checked_mul(t)?;
+        let round_elements = rounds.checked_mul(t)?;
+        let collections = self.rounds.partial().checked_mul(t - 1)?;
+        let elements = square
+            .checked_mul(5)?
+            .checked_add(round_elements.checked_mul(2)?)?
+            .checked_add((t - 1) * (t - 1) * 2)?
+            .checked_add((t - 1) * 2)?
+            .checked_add(collections.checked_mul(2)?)?
+            .checked_add(1)?;
+        elements.checked_mul(FQ_LEN)?.checked_add(HEADER_LEN)
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn write_elements(out: &mut Vec<u8>, elements: &[Fq]) {
+    for element in elements {
+        out.extend_from_slice(&element.to_bytes());
+    }
+}
+
+/// A cursor over an encoding whose length has already been checked.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> &'a [u8] {
+        let (head, tail) = self.0.split_at(n);
+        self.0 = tail;
+        head
+    }
+
+    fn u32(&mut self) -> u32 {
+        u32::from_le_bytes(self.take(4).try_into().expect("took 4 bytes"))
+    }
+
+    fn element(&mut self) -> Result<Fq, PoseidonParameterError> {
+        let bytes = self.take(FQ_LEN).try_into().expect("took 32 bytes");
+        Fq::from_bytes_checked(bytes).map_err(|_| PoseidonParameterError::NonCanonicalElement)
+    }
+
+    fn elements<const N: usize>(&mut self) -> Result<[Fq; N], PoseidonParameterError> {
+        let mut elements = [Fq::default(); N];
+        for element in elements.iter_mut() {
+            *element = self.element()?;
+        }
+        Ok(elements)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn dyn_matrix(
+        &mut self,
+        n_rows: usize,
+        n_cols: usize,
+    ) -> Result<DynMatrix, PoseidonParameterError> {
+        let elements = (0..n_rows * n_cols)
+            .map(|_| self.element())
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(DynMatrix::new(n_rows, n_cols, elements))
+    }
+
+    fn matrices<
+        const N_ROWS: usize,
+        const N_COLS: usize,
+        const N_ELEMENTS: usize,
+        const N: usize,
+    >(
+        &mut self,
+    ) -> Result<[Matrix<N_ROWS, N_COLS, N_ELEMENTS>; N], PoseidonParameterError> {
+        let mut matrices = [(); N].map(|()| Matrix::new_from_known([Fq::default(); N_ELEMENTS]));
+        for matrix in matrices.iter_mut() {
+            *matrix = Matrix::new_from_known(self.elements()?);
+        }
+        Ok(matrices)
+    }
+}
@@ -1,7 +1,33 @@
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum PoseidonParameterError {
     InvalidMatrixDimensions,
     NoMatrixInverse,
+    /// The encoding has the wrong magic bytes or the wrong length.
+    InvalidEncoding,
+    /// The encoding has a format version this crate does not support.
+    UnsupportedVersion(u8),
+    /// The encoded parameters are for a different field.
+    FieldMismatch,
+    /// The encoded dimensions are invalid, or are not those of the parameter type.
+    DimensionMismatch,
+    /// The encoded S-box exponent is not valid.
+    InvalidAlpha,
+    /// The encoding contains a non-canonical field element.
+    NonCanonicalElement,
+    /// The encoded matrices derived from the MDS matrix do not match it.
+    InconsistentParameters,
+    /// The MDS matrix has a singular square submatrix.
+    NotMds,
+    /// The MDS matrix admits infinitely long invariant subspace trails.
+    InvariantSubspaceTrail,
+    /// The round numbers are below the minimum for their security level.
+    InsecureRoundNumbers,
+    /// The security level is beyond what the round number bounds support.
+    UnsupportedSecurityLevel,
+    /// The modulus is not a prime large enough for parameter generation.
+    InvalidModulus,
+    /// The security margin would remove rounds, or add an odd number of full rounds.
+    InvalidSecurityMargin,
 }
 
 impl core::fmt::Display for PoseidonParameterError {
@@ -9,8 +35,26 @@ impl core::fmt::Display for PoseidonParameterError {
         let msg = match self {
             Self::InvalidMatrixDimensions => "Invalid matrix dimensions",
             Self::NoMatrixInverse => "No matrix inverse",
+            Self::InvalidEncoding => "Invalid parameter encoding",
+            Self::UnsupportedVersion(version) => {
+                return write!(f, "Unsupported parameter encoding version {}", version)
+            }
+            Self::FieldMismatch => "Parameters are for a different field",
+            Self::DimensionMismatch => "Parameters have the wrong dimensions",
+            Self::InvalidAlpha => "Invalid S-box exponent",
+            Self::NonCanonicalElement => "Non-canonical field element in parameters",
+            Self::InconsistentParameters => "Inconsistent MDS matrices in parameters",
+            Self::NotMds => "MDS matrix has a singular submatrix",
+            Self::InvariantSubspaceTrail => "MDS matrix admits invariant subspace trails",
+            Self::InsecureRoundNumbers => "Round numbers are insecure",
+            Self::UnsupportedSecurityLevel => "Unsupported security level",
+            Self::InvalidModulus => "Invalid prime modulus",
+            Self::InvalidSecurityMargin => "Invalid security margin",
         };
 
         msg.fmt(f)
     }
 }
+
+#[cfg(feature = "std")]
+impl std::error::Error for PoseidonParameterError {}
@@ -5,13 +5,33 @@
 //! The API here is split into [`v1`] and [`v2`] to avoid confusion
 //! between the two versions.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 mod alpha;
 mod arc_matrix;
+#[cfg(feature = "alloc")]
+mod codegen;
+#[cfg(feature = "alloc")]
+mod dynamic;
+mod encoding;
 mod error;
 mod matrix;
 mod matrix_ops;
 mod mds_matrix;
+#[cfg(feature = "alloc")]
+mod optimize;
 mod round_numbers;
+#[cfg(feature = "std")]
+mod security;
+#[cfg(feature = "serde")]
+mod serialize;
+mod sparse_matrix;
+#[cfg(feature = "alloc")]
+mod validate;
+
+#[cfg(feature = "alloc")]
+pub use validate::validate;
 
 /// Structures related to Poseidon version 1 parameters.
 pub mod v1;
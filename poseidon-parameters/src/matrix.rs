@@ -1,8 +1,11 @@
 use core::convert::TryInto;
 use core::ops::Mul;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 use crate::error::PoseidonParameterError;
-use crate::matrix_ops::{dot_product, MatrixOperations, SquareMatrixOperations};
+use crate::matrix_ops::{MatrixOperations, SquareMatrixOperations};
 use decaf377::Fq;
 
 /// Represents a matrix over `PrimeField` elements.
@@ -29,7 +32,7 @@ impl<const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize>
                 index += 1;
             }
         }
-        Matrix::<N_COLS, N_ROWS, N_ELEMENTS>::new(&transposed_elements)
+        Matrix::<N_COLS, N_ROWS, N_ELEMENTS>::new_from_known(transposed_elements)
     }
 
     /// Create a new matrix from a slice of elements.
@@ -45,19 +48,19 @@ impl<const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize>
 impl<const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize> MatrixOperations
     for Matrix<N_ROWS, N_COLS, N_ELEMENTS>
 {
-    fn new(elements: &[Fq]) -> Self {
+    fn new(elements: &[Fq]) -> Result<Self, PoseidonParameterError> {
         // Note: We use a third const generic to denote the number of elements in the
         // matrix here due to `generic_const_exprs` being an unstable Rust feature at
         // the time of writing.
         if N_ELEMENTS != N_ROWS * N_COLS {
-            panic!("Matrix has an insufficient number of elements")
+            return Err(PoseidonParameterError::InvalidMatrixDimensions);
         }
 
         let elements: [Fq; N_ELEMENTS] = elements
             .try_into()
-            .expect("Matrix has the correct number of elements");
+            .map_err(|_| PoseidonParameterError::InvalidMatrixDimensions)?;
 
-        Self { elements }
+        Ok(Self { elements })
     }
 
     fn elements(&self) -> &[Fq] {
@@ -93,11 +96,15 @@ impl<const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize> MatrixOp
             }
         }
 
-        Ok(Self::new(&new_elements))
+        Self::new(&new_elements)
     }
 }
 
 /// Multiply two `Matrix`
+///
+/// Returns [`PoseidonParameterError::InvalidMatrixDimensions`] if the number of columns
+/// of `lhs` is not the number of rows of `rhs`, or if `RESULT_N_ELEMENTS` is not the
+/// number of elements of the product.
 pub fn mat_mul<
     const LHS_N_ROWS: usize,
     const LHS_N_COLS: usize,
@@ -109,21 +116,47 @@ pub fn mat_mul<
 >(
     lhs: &Matrix<LHS_N_ROWS, LHS_N_COLS, LHS_N_ELEMENTS>,
     rhs: &Matrix<RHS_N_ROWS, RHS_N_COLS, RHS_N_ELEMENTS>,
-) -> Matrix<LHS_N_ROWS, RHS_N_COLS, RESULT_N_ELEMENTS> {
-    let rhs_T = rhs.transpose();
-
-    let mut new_elements = [Fq::default(); RESULT_N_ELEMENTS];
+) -> Result<Matrix<LHS_N_ROWS, RHS_N_COLS, RESULT_N_ELEMENTS>, PoseidonParameterError> {
+    let mut result = Matrix {
+        elements: [Fq::default(); RESULT_N_ELEMENTS],
+    };
+    mat_mul_into(lhs, rhs, &mut result)?;
+    Ok(result)
+}
 
-    let mut index = 0;
-    for row in lhs.iter_rows() {
-        // Rows of the transposed matrix are the columns of the original matrix
-        for column in rhs_T.iter_rows() {
-            new_elements[index] = dot_product(row, column);
-            index += 1;
-        }
+/// Multiply two `Matrix`, writing the product to `out` instead of returning a new matrix.
+///
+/// Returns [`PoseidonParameterError::InvalidMatrixDimensions`] under the same conditions
+/// as [`mat_mul`], in which case `out` is left unchanged.
+pub fn mat_mul_into<
+    const LHS_N_ROWS: usize,
+    const LHS_N_COLS: usize,
+    const LHS_N_ELEMENTS: usize,
+    const RHS_N_ROWS: usize,
+    const RHS_N_COLS: usize,
+    const RHS_N_ELEMENTS: usize,
+    const RESULT_N_ELEMENTS: usize,
+>(
+    lhs: &Matrix<LHS_N_ROWS, LHS_N_COLS, LHS_N_ELEMENTS>,
+    rhs: &Matrix<RHS_N_ROWS, RHS_N_COLS, RHS_N_ELEMENTS>,
+    out: &mut Matrix<LHS_N_ROWS, RHS_N_COLS, RESULT_N_ELEMENTS>,
+) -> Result<(), PoseidonParameterError> {
+    if LHS_N_COLS != RHS_N_ROWS
+        || LHS_N_ELEMENTS != LHS_N_ROWS * LHS_N_COLS
+        || RHS_N_ELEMENTS != RHS_N_ROWS * RHS_N_COLS
+        || RESULT_N_ELEMENTS != LHS_N_ROWS * RHS_N_COLS
+    {
+        return Err(PoseidonParameterError::InvalidMatrixDimensions);
     }
 
-    Matrix::<LHS_N_ROWS, RHS_N_COLS, RESULT_N_ELEMENTS>::new(&new_elements)
+    mul_elements_into(
+        &lhs.elements,
+        &rhs.elements,
+        LHS_N_COLS,
+        RHS_N_COLS,
+        &mut out.elements,
+    );
+    Ok(())
 }
 
 /// Multiply scalar by Matrix
@@ -133,12 +166,20 @@ impl<const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize> Mul<Fq>
     type Output = Matrix<N_ROWS, N_COLS, N_ELEMENTS>;
 
     fn mul(self, rhs: Fq) -> Self::Output {
-        let elements = self.elements();
-        let mut new_elements = [Fq::default(); N_ELEMENTS];
-        for (i, &element) in elements.iter().enumerate() {
-            new_elements[i] = element * rhs;
+        &self * rhs
+    }
+}
+
+/// Multiply scalar by a borrowed Matrix
+impl<const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize> Mul<Fq>
+    for &Matrix<N_ROWS, N_COLS, N_ELEMENTS>
+{
+    type Output = Matrix<N_ROWS, N_COLS, N_ELEMENTS>;
+
+    fn mul(self, rhs: Fq) -> Self::Output {
+        Matrix {
+            elements: self.elements.map(|element| element * rhs),
         }
-        Self::new(&new_elements)
     }
 }
 
@@ -146,12 +187,12 @@ impl<const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize>
     Matrix<N_ROWS, N_COLS, N_ELEMENTS>
 {
     /// Get row vector at a specified row index
-    pub fn row_vector(&self, i: usize) -> Matrix<1, N_COLS, N_ELEMENTS> {
+    pub fn row_vector(&self, i: usize) -> Matrix<1, N_COLS, N_COLS> {
         let mut row_elements = [Fq::default(); N_COLS];
-        for j in 0..N_COLS {
-            row_elements[j] = self.get_element(i, j);
+        for (j, element) in row_elements.iter_mut().enumerate() {
+            *element = self.get_element(i, j);
         }
-        Matrix::new(&row_elements)
+        Matrix::new_from_known(row_elements)
     }
 }
 
@@ -163,6 +204,8 @@ impl<const N_ROWS: usize, const N_ELEMENTS: usize> SquareMatrix<N_ROWS, N_ELEMEN
 
 /// Represents a square matrix over `PrimeField` elements
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct SquareMatrix<const N_ROWS: usize, const N_ELEMENTS: usize>(
     pub Matrix<N_ROWS, N_ROWS, N_ELEMENTS>,
 );
@@ -170,8 +213,8 @@ pub struct SquareMatrix<const N_ROWS: usize, const N_ELEMENTS: usize>(
 impl<const N_ROWS: usize, const N_ELEMENTS: usize> MatrixOperations
     for SquareMatrix<N_ROWS, N_ELEMENTS>
 {
-    fn new(elements: &[Fq]) -> Self {
-        Self(Matrix::new(elements))
+    fn new(elements: &[Fq]) -> Result<Self, PoseidonParameterError> {
+        Ok(Self(Matrix::new(elements)?))
     }
 
     fn elements(&self) -> &[Fq] {
@@ -206,39 +249,24 @@ impl<const N_ROWS: usize, const N_ELEMENTS: usize> MatrixOperations
 impl<const N_ROWS: usize, const N_ELEMENTS: usize> SquareMatrixOperations
     for SquareMatrix<N_ROWS, N_ELEMENTS>
 {
-    /// Compute the inverse of the matrix
+    /// Compute the inverse of the matrix by Gauss-Jordan elimination, in $O(n^3)$ field
+    /// operations.
+    ///
+    /// Returns [`PoseidonParameterError::NoMatrixInverse`] if the matrix is singular.
     fn inverse(&self) -> Result<Self, PoseidonParameterError> {
-        let identity = Self::identity();
-
-        if self.n_rows() == 1 {
-            let elements = [self
-                .get_element(0, 0)
-                .inverse()
-                .expect("inverse of single element must exist for 1x1 matrix")];
-            return Ok(Self::new(&elements));
-        }
-
-        let determinant = self.determinant();
-        if determinant == Fq::from(0u64) {
-            return Err(PoseidonParameterError::NoMatrixInverse);
-        }
+        let mut elements = self.0.elements;
+        let mut inverse = Self::identity().0.elements;
+        gauss_jordan(&mut elements, &mut inverse, N_ROWS)?;
 
-        let minors = self.minors();
-        let cofactor_matrix = self.cofactors();
-        let signed_minors = minors
-            .hadamard_product(&cofactor_matrix)
-            .expect("minor and cofactor matrix have correct dimensions");
-        let adj = signed_minors.transpose();
-        let matrix_inverse = adj * (Fq::from(1u64) / determinant);
-
-        debug_assert_eq!(square_mat_mul(self, &matrix_inverse), identity);
+        let matrix_inverse = Self::new(&inverse)?;
+        debug_assert_eq!(square_mat_mul(self, &matrix_inverse), Ok(Self::identity()));
         Ok(matrix_inverse)
     }
 
     /// Construct an identity matrix
     fn identity() -> Self {
         let elements = [Fq::from(0u64); N_ELEMENTS];
-        let mut m = Self::new(&elements);
+        let mut m = Self::new_from_known(elements);
 
         // Set diagonals to 1
         for i in 0..N_ROWS {
@@ -248,28 +276,33 @@ impl<const N_ROWS: usize, const N_ELEMENTS: usize> SquareMatrixOperations
         m
     }
 
-    /// Compute the (unsigned) minors of this matrix
-    fn minors(&self) -> Self {
-        match N_ROWS {
-            0 => panic!("matrix has no elements!"),
-            1 => Self::new(&[self.get_element(0, 0)]),
-            2 => {
-                let a = self.get_element(0, 0);
-                let b = self.get_element(0, 1);
-                let c = self.get_element(1, 0);
-                let d = self.get_element(1, 1);
-                Self::new(&[d, c, b, a])
-            }
-            3 => minor_matrix::<N_ROWS, 2, N_ELEMENTS, 4>(self),
-            4 => minor_matrix::<N_ROWS, 3, N_ELEMENTS, 9>(self),
-            5 => minor_matrix::<N_ROWS, 4, N_ELEMENTS, 16>(self),
-            6 => minor_matrix::<N_ROWS, 5, N_ELEMENTS, 25>(self),
-            7 => minor_matrix::<N_ROWS, 6, N_ELEMENTS, 36>(self),
-            8 => minor_matrix::<N_ROWS, 7, N_ELEMENTS, 49>(self),
-            _ => {
-                unimplemented!("poseidon-parameters only supports square matrices up to 8")
+    /// Compute the (unsigned) minors of this matrix, each by Gaussian elimination.
+    ///
+    /// Returns [`PoseidonParameterError::InvalidMatrixDimensions`] if the matrix has no
+    /// elements.
+    fn minors(&self) -> Result<Self, PoseidonParameterError> {
+        check_square::<N_ROWS, N_ELEMENTS>()?;
+        if N_ROWS == 1 {
+            return Ok(self.clone());
+        }
+
+        let dim_minus_1 = N_ROWS - 1;
+        let mut minors = [Fq::default(); N_ELEMENTS];
+        for i in 0..N_ROWS {
+            for j in 0..N_ROWS {
+                // The submatrix without row `i` and column `j`, in row-major order.
+                let mut elements = [Fq::default(); N_ELEMENTS];
+                let submatrix = (0..N_ROWS)
+                    .filter(|&k| k != i)
+                    .flat_map(|k| (0..N_ROWS).filter(move |&l| l != j).map(move |l| (k, l)));
+                for (element, (k, l)) in elements.iter_mut().zip(submatrix) {
+                    *element = self.get_element(k, l);
+                }
+                minors[i * N_ROWS + j] =
+                    determinant(&mut elements[..dim_minus_1 * dim_minus_1], dim_minus_1);
             }
         }
+        Ok(Self::new_from_known(minors))
     }
 
     /// Compute the cofactor matrix, i.e. $C_{ij} = (-1)^{i+j}$
@@ -284,25 +317,18 @@ impl<const N_ROWS: usize, const N_ELEMENTS: usize> SquareMatrixOperations
                 index += 1;
             }
         }
-        Self::new(&elements)
-    }
-
-    /// Compute the matrix determinant
-    fn determinant(&self) -> Fq {
-        match N_ROWS {
-            0 => panic!("matrix has no elements!"),
-            1 => self.get_element(0, 0),
-            2 => determinant::<N_ROWS, 1, N_ELEMENTS, 1>(self),
-            3 => determinant::<N_ROWS, 2, N_ELEMENTS, 4>(self),
-            4 => determinant::<N_ROWS, 3, N_ELEMENTS, 9>(self),
-            5 => determinant::<N_ROWS, 4, N_ELEMENTS, 16>(self),
-            6 => determinant::<N_ROWS, 5, N_ELEMENTS, 25>(self),
-            7 => determinant::<N_ROWS, 6, N_ELEMENTS, 36>(self),
-            8 => determinant::<N_ROWS, 7, N_ELEMENTS, 49>(self),
-            _ => {
-                unimplemented!("poseidon-parameters only supports square matrices up to 8")
-            }
-        }
+        Self::new_from_known(elements)
+    }
+
+    /// Compute the matrix determinant by Gaussian elimination, in $O(n^3)$ field
+    /// operations.
+    ///
+    /// Returns [`PoseidonParameterError::InvalidMatrixDimensions`] if the matrix has no
+    /// elements.
+    fn determinant(&self) -> Result<Fq, PoseidonParameterError> {
+        check_square::<N_ROWS, N_ELEMENTS>()?;
+        let mut elements = self.0.elements;
+        Ok(determinant(&mut elements, N_ROWS))
     }
 }
 
@@ -311,24 +337,28 @@ impl<const N_ROWS: usize, const N_ELEMENTS: usize> Mul<Fq> for SquareMatrix<N_RO
     type Output = SquareMatrix<N_ROWS, N_ELEMENTS>;
 
     fn mul(self, rhs: Fq) -> Self::Output {
-        let elements = self.elements();
-        let mut new_elements = [Fq::default(); N_ELEMENTS];
-        for (i, &element) in elements.iter().enumerate() {
-            new_elements[i] = element * rhs;
-        }
-        Self::new(&new_elements)
+        SquareMatrix(&self.0 * rhs)
+    }
+}
+
+/// Multiply scalar by a borrowed SquareMatrix
+impl<const N_ROWS: usize, const N_ELEMENTS: usize> Mul<Fq> for &SquareMatrix<N_ROWS, N_ELEMENTS> {
+    type Output = SquareMatrix<N_ROWS, N_ELEMENTS>;
+
+    fn mul(self, rhs: Fq) -> Self::Output {
+        SquareMatrix(&self.0 * rhs)
     }
 }
 
 impl<const N_ROWS: usize, const N_ELEMENTS: usize> SquareMatrix<N_ROWS, N_ELEMENTS> {
     /// Get row vector at a specified row index.
-    pub fn row_vector(&self, i: usize) -> Matrix<1, N_ROWS, N_ELEMENTS> {
+    pub fn row_vector(&self, i: usize) -> Matrix<1, N_ROWS, N_ROWS> {
         self.0.row_vector(i)
     }
 
     /// Create a 2x2 `SquareMatrix` from four elements.
     pub fn new_2x2(a: Fq, b: Fq, c: Fq, d: Fq) -> SquareMatrix<2, 4> {
-        SquareMatrix::<2, 4>::new(&[a, b, c, d])
+        SquareMatrix::<2, 4>::new_from_known([a, b, c, d])
     }
 
     /// Create a new matrix from a slice of elements.
@@ -338,6 +368,10 @@ impl<const N_ROWS: usize, const N_ELEMENTS: usize> SquareMatrix<N_ROWS, N_ELEMEN
 }
 
 /// Multiply two matrices
+///
+/// Returns [`PoseidonParameterError::InvalidMatrixDimensions`] if the matrices do not
+/// have the same dimensions, or if `RESULT_N_ELEMENTS` is not the number of elements of
+/// the product.
 pub fn square_mat_mul<
     const LHS_N_ROWS: usize,
     const LHS_N_ELEMENTS: usize,
@@ -347,100 +381,199 @@ pub fn square_mat_mul<
 >(
     lhs: &SquareMatrix<LHS_N_ROWS, LHS_N_ELEMENTS>,
     rhs: &SquareMatrix<RHS_N_ROWS, RHS_N_ELEMENTS>,
-) -> SquareMatrix<LHS_N_ROWS, RESULT_N_ELEMENTS> {
-    let rhs_T = rhs.transpose();
-
-    let mut new_elements = [Fq::default(); RESULT_N_ELEMENTS];
+) -> Result<SquareMatrix<LHS_N_ROWS, RESULT_N_ELEMENTS>, PoseidonParameterError> {
+    let mut result = SquareMatrix(Matrix {
+        elements: [Fq::default(); RESULT_N_ELEMENTS],
+    });
+    square_mat_mul_into(lhs, rhs, &mut result)?;
+    Ok(result)
+}
 
-    let mut index = 0;
-    for row in lhs.iter_rows() {
-        // Rows of the transposed matrix are the columns of the original matrix
-        for column in rhs_T.iter_rows() {
-            new_elements[index] = dot_product(row, column);
-            index += 1;
-        }
+/// Multiply two matrices, writing the product to `out` instead of returning a new matrix.
+///
+/// Returns [`PoseidonParameterError::InvalidMatrixDimensions`] under the same conditions
+/// as [`square_mat_mul`], in which case `out` is left unchanged.
+pub fn square_mat_mul_into<
+    const LHS_N_ROWS: usize,
+    const LHS_N_ELEMENTS: usize,
+    const RHS_N_ROWS: usize,
+    const RHS_N_ELEMENTS: usize,
+    const RESULT_N_ELEMENTS: usize,
+>(
+    lhs: &SquareMatrix<LHS_N_ROWS, LHS_N_ELEMENTS>,
+    rhs: &SquareMatrix<RHS_N_ROWS, RHS_N_ELEMENTS>,
+    out: &mut SquareMatrix<LHS_N_ROWS, RESULT_N_ELEMENTS>,
+) -> Result<(), PoseidonParameterError> {
+    if LHS_N_ROWS != RHS_N_ROWS
+        || LHS_N_ELEMENTS != LHS_N_ROWS * LHS_N_ROWS
+        || RHS_N_ELEMENTS != RHS_N_ROWS * RHS_N_ROWS
+        || RESULT_N_ELEMENTS != LHS_N_ROWS * LHS_N_ROWS
+    {
+        return Err(PoseidonParameterError::InvalidMatrixDimensions);
     }
 
-    SquareMatrix::<LHS_N_ROWS, RESULT_N_ELEMENTS>::new(&new_elements)
+    mul_elements_into(
+        &lhs.0.elements,
+        &rhs.0.elements,
+        LHS_N_ROWS,
+        LHS_N_ROWS,
+        &mut out.0.elements,
+    );
+    Ok(())
 }
 
-/// Helper function for computing matrix minors
-fn minor_matrix<
-    const DIM: usize,
-    const DIM_MINUS_1: usize,
-    const N_ELEMENTS: usize,
-    const N_ELEMENTS_DIM_MINUS_1: usize,
->(
-    matrix: &SquareMatrix<DIM, N_ELEMENTS>,
-) -> SquareMatrix<DIM, N_ELEMENTS> {
-    let mut minor_matrix_elements = [Fq::default(); N_ELEMENTS];
-    let mut outer_index = 0;
-    for i in 0..DIM {
-        for j in 0..DIM {
-            let mut elements = [Fq::default(); N_ELEMENTS_DIM_MINUS_1];
-            let mut index = 0;
-            for k in 0..i {
-                for l in 0..j {
-                    elements[index] = matrix.get_element(k, l);
-                    index += 1;
-                }
-                for l in (j + 1)..DIM {
-                    elements[index] = matrix.get_element(k, l);
-                    index += 1;
-                }
-            }
-            for k in i + 1..DIM {
-                for l in 0..j {
-                    elements[index] = matrix.get_element(k, l);
-                    index += 1;
-                }
-                for l in (j + 1)..DIM {
-                    elements[index] = matrix.get_element(k, l);
-                    index += 1;
-                }
+/// Multiply the row-major `lhs` with `n_inner` columns by the row-major `rhs` with
+/// `n_cols` columns into `out`, whose dimensions the caller has checked.
+///
+/// With the `parallel` feature, the rows of the product are computed in parallel.
+pub(crate) fn mul_elements_into(
+    lhs: &[Fq],
+    rhs: &[Fq],
+    n_inner: usize,
+    n_cols: usize,
+    out: &mut [Fq],
+) {
+    let row = |(i, out_row): (usize, &mut [Fq])| {
+        for (j, element) in out_row.iter_mut().enumerate() {
+            *element = (0..n_inner)
+                .map(|k| lhs[i * n_inner + k] * rhs[k * n_cols + j])
+                .sum();
+        }
+    };
+
+    #[cfg(not(feature = "parallel"))]
+    out.chunks_exact_mut(n_cols.max(1))
+        .enumerate()
+        .for_each(row);
+    #[cfg(feature = "parallel")]
+    out.par_chunks_exact_mut(n_cols.max(1))
+        .enumerate()
+        .for_each(row);
+}
+
+/// Reduce the row-major `n x n` matrix `elements` to the identity by Gauss-Jordan
+/// elimination, applying the same row operations to `inverse`.
+///
+/// Starting from the identity, `inverse` ends up as the inverse of `elements`. Returns
+/// [`PoseidonParameterError::NoMatrixInverse`] if the matrix is singular.
+pub(crate) fn gauss_jordan(
+    elements: &mut [Fq],
+    inverse: &mut [Fq],
+    n: usize,
+) -> Result<(), PoseidonParameterError> {
+    let zero = Fq::from(0u64);
+    for col in 0..n {
+        let pivot = (col..n)
+            .find(|&row| elements[row * n + col] != zero)
+            .ok_or(PoseidonParameterError::NoMatrixInverse)?;
+        if pivot != col {
+            for j in 0..n {
+                elements.swap(pivot * n + j, col * n + j);
+                inverse.swap(pivot * n + j, col * n + j);
             }
-            let minor = SquareMatrix::<DIM_MINUS_1, N_ELEMENTS_DIM_MINUS_1>::new(&elements);
-            minor_matrix_elements[outer_index] = minor.determinant();
-            outer_index += 1;
         }
+
+        let scale = elements[col * n + col]
+            .inverse()
+            .ok_or(PoseidonParameterError::NoMatrixInverse)?;
+        for j in 0..n {
+            elements[col * n + j] *= scale;
+            inverse[col * n + j] *= scale;
+        }
+
+        eliminate(elements, inverse, n, col);
     }
-    SquareMatrix::<DIM, N_ELEMENTS>::new(&minor_matrix_elements)
+    Ok(())
 }
 
-/// Helper function for computing matrix determinant
-fn determinant<
-    const DIM: usize,
-    const DIM_MINUS_1: usize,
-    const N_ELEMENTS: usize,
-    const N_ELEMENTS_DIM_MINUS_1: usize,
->(
-    matrix: &SquareMatrix<DIM, N_ELEMENTS>,
-) -> Fq {
-    let mut det = Fq::from(0u64);
-    let mut levi_civita = true;
+/// Subtract multiples of the normalized pivot row `col` from every other row, to clear
+/// column `col`.
+///
+/// With the `parallel` feature, the rows are updated in parallel.
+fn eliminate(elements: &mut [Fq], inverse: &mut [Fq], n: usize, col: usize) {
+    let (elements_before, elements_rest) = elements.split_at_mut(col * n);
+    let (pivot_elements, elements_after) = elements_rest.split_at_mut(n);
+    let (inverse_before, inverse_rest) = inverse.split_at_mut(col * n);
+    let (pivot_inverse, inverse_after) = inverse_rest.split_at_mut(n);
+    let (pivot_elements, pivot_inverse) = (&*pivot_elements, &*pivot_inverse);
+
+    let row = |(row_elements, row_inverse): (&mut [Fq], &mut [Fq])| {
+        let factor = row_elements[col];
+        if factor == Fq::from(0u64) {
+            return;
+        }
+        for (element, pivot_element) in row_elements.iter_mut().zip(pivot_elements) {
+            *element -= factor * pivot_element;
+        }
+        for (element, pivot_element) in row_inverse.iter_mut().zip(pivot_inverse) {
+            *element -= factor * pivot_element;
+        }
+    };
+
+    #[cfg(not(feature = "parallel"))]
+    elements_before
+        .chunks_exact_mut(n)
+        .zip(inverse_before.chunks_exact_mut(n))
+        .chain(
+            elements_after
+                .chunks_exact_mut(n)
+                .zip(inverse_after.chunks_exact_mut(n)),
+        )
+        .for_each(row);
+    #[cfg(feature = "parallel")]
+    elements_before
+        .par_chunks_exact_mut(n)
+        .zip(inverse_before.par_chunks_exact_mut(n))
+        .chain(
+            elements_after
+                .par_chunks_exact_mut(n)
+                .zip(inverse_after.par_chunks_exact_mut(n)),
+        )
+        .for_each(row);
+}
 
-    for i in 0..DIM {
-        let mut elements = [Fq::default(); N_ELEMENTS_DIM_MINUS_1];
-        let mut index = 0;
-        for k in 0..i {
-            for l in 1..DIM {
-                elements[index] = matrix.get_element(k, l);
-                index += 1;
+/// Return [`PoseidonParameterError::InvalidMatrixDimensions`] unless a `DIM x DIM`
+/// matrix has `N_ELEMENTS` elements and at least one row.
+fn check_square<const DIM: usize, const N_ELEMENTS: usize>() -> Result<(), PoseidonParameterError> {
+    if DIM == 0 || N_ELEMENTS != DIM * DIM {
+        return Err(PoseidonParameterError::InvalidMatrixDimensions);
+    }
+    Ok(())
+}
+
+/// Compute the determinant of the row-major `n x n` matrix `elements` by Gaussian
+/// elimination, overwriting it.
+fn determinant(elements: &mut [Fq], n: usize) -> Fq {
+    let zero = Fq::from(0u64);
+    let mut det = Fq::from(1u64);
+    for col in 0..n {
+        let pivot = match (col..n).find(|&row| elements[row * n + col] != zero) {
+            Some(pivot) => pivot,
+            None => return zero,
+        };
+        if pivot != col {
+            for j in col..n {
+                elements.swap(pivot * n + j, col * n + j);
             }
+            det = -det;
         }
-        for k in i + 1..DIM {
-            for l in 1..DIM {
-                elements[index] = matrix.get_element(k, l);
-                index += 1;
+
+        let pivot_element = elements[col * n + col];
+        det *= pivot_element;
+        let pivot_inverse = match pivot_element.inverse() {
+            Some(pivot_inverse) => pivot_inverse,
+            None => return zero,
+        };
+        for row in (col + 1)..n {
+            let factor = elements[row * n + col] * pivot_inverse;
+            if factor == zero {
+                continue;
+            }
+            for j in col..n {
+                let pivot_row_element = elements[col * n + j];
+                elements[row * n + j] -= factor * pivot_row_element;
             }
         }
-        let minor = SquareMatrix::<DIM_MINUS_1, N_ELEMENTS_DIM_MINUS_1>::new(&elements);
-        if levi_civita {
-            det += matrix.get_element(i, 0) * minor.determinant();
-        } else {
-            det -= matrix.get_element(i, 0) * minor.determinant();
-        }
-        levi_civita = !levi_civita;
     }
     det
 }
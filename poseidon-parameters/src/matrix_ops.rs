@@ -4,8 +4,11 @@ use crate::error::PoseidonParameterError;
 use decaf377::Fq;
 
 pub trait MatrixOperations {
-    /// Create a new matrix
-    fn new(elements: &[Fq]) -> Self;
+    /// Create a new matrix, or return [`PoseidonParameterError::InvalidMatrixDimensions`]
+    /// if the number of elements does not match the dimensions.
+    fn new(elements: &[Fq]) -> Result<Self, PoseidonParameterError>
+    where
+        Self: Sized;
     /// Access elements as an array of arrays
     fn elements(&self) -> &[Fq];
     /// Get element[i,j]
@@ -13,7 +16,7 @@ pub trait MatrixOperations {
     /// Set element[i,j]
     fn set_element(&mut self, i: usize, j: usize, val: Fq);
     /// Get rows
-    fn iter_rows(&self) -> Chunks<Fq> {
+    fn iter_rows(&self) -> Chunks<'_, Fq> {
         self.elements().chunks(self.n_cols())
     }
     /// Number of rows
@@ -26,15 +29,6 @@ pub trait MatrixOperations {
         Self: Sized;
 }
 
-/// Compute vector dot product
-pub fn dot_product(a: &[Fq], b: &[Fq]) -> Fq {
-    if a.len() != b.len() {
-        panic!("vecs not same len")
-    }
-
-    a.iter().zip(b.iter()).map(|(x, y)| *x * *y).sum()
-}
-
 /// Matrix operations that are defined on square matrices.
 pub trait SquareMatrixOperations {
     /// Compute the matrix inverse, if it exists
@@ -44,9 +38,11 @@ pub trait SquareMatrixOperations {
     /// Construct an identity matrix
     fn identity() -> Self;
     /// Compute the matrix of minors
-    fn minors(&self) -> Self;
+    fn minors(&self) -> Result<Self, PoseidonParameterError>
+    where
+        Self: Sized;
     /// Compute the matrix of cofactors
     fn cofactors(&self) -> Self;
     /// Compute the matrix determinant
-    fn determinant(&self) -> Fq;
+    fn determinant(&self) -> Result<Fq, PoseidonParameterError>;
 }
@@ -7,6 +7,8 @@ use decaf377::Fq;
 
 /// Represents an MDS (maximum distance separable) matrix.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct MdsMatrix<
     const STATE_SIZE: usize,
     const STATE_SIZE_MINUS_1: usize,
@@ -22,11 +24,13 @@ impl<
     > MatrixOperations
     for MdsMatrix<STATE_SIZE, STATE_SIZE_MINUS_1, NUM_ELEMENTS, NUM_ELEMENTS_STATE_SIZE_MINUS_1_2>
 {
-    fn new(elements: &[Fq]) -> Self {
-        assert!(STATE_SIZE == STATE_SIZE_MINUS_1 + 1);
-        assert!(STATE_SIZE * STATE_SIZE == NUM_ELEMENTS);
-        assert!(STATE_SIZE_MINUS_1 * STATE_SIZE_MINUS_1 == NUM_ELEMENTS_STATE_SIZE_MINUS_1_2);
-        Self(SquareMatrix::new(elements))
+    fn new(elements: &[Fq]) -> Result<Self, PoseidonParameterError> {
+        if STATE_SIZE != STATE_SIZE_MINUS_1 + 1
+            || STATE_SIZE_MINUS_1 * STATE_SIZE_MINUS_1 != NUM_ELEMENTS_STATE_SIZE_MINUS_1_2
+        {
+            return Err(PoseidonParameterError::InvalidMatrixDimensions);
+        }
+        Ok(Self(SquareMatrix::new(elements)?))
     }
 
     fn elements(&self) -> &[Fq] {
@@ -66,14 +70,17 @@ impl<
 {
     /// Instantiate an MDS matrix from a list of elements.
     ///
+    /// Returns [`PoseidonParameterError::InvalidMatrixDimensions`] if the number of
+    /// elements does not match the dimensions.
+    ///
     /// # Security
     ///
     /// You must ensure this matrix was generated securely,
     /// using the Cauchy method in `fixed_cauchy_matrix` or
     /// using the random subsampling method described in the original
     /// paper.
-    pub fn from_elements(elements: &[Fq]) -> Self {
-        Self(SquareMatrix::new(elements))
+    pub fn from_elements(elements: &[Fq]) -> Result<Self, PoseidonParameterError> {
+        <Self as MatrixOperations>::new(elements)
     }
 
     pub fn transpose(&self) -> Self {
@@ -91,8 +98,11 @@ impl<
     ///
     /// Ref: p.20 of the Poseidon paper
     pub fn v(&self) -> Matrix<1, STATE_SIZE_MINUS_1, STATE_SIZE_MINUS_1> {
-        let elements = &self.0 .0.elements()[1..self.0 .0.n_rows()];
-        Matrix::new(elements)
+        let mut elements = [Fq::from(0u64); STATE_SIZE_MINUS_1];
+        for j in 1..self.n_cols() {
+            elements[j - 1] = self.get_element(0, j);
+        }
+        Matrix::new_from_known(elements)
     }
 
     /// Return the elements M_{1,0} .. M_{t,0} from the first column
@@ -103,7 +113,7 @@ impl<
         for i in 1..self.n_rows() {
             elements[i - 1] = self.get_element(i, 0);
         }
-        Matrix::new(&elements)
+        Matrix::new_from_known(elements)
     }
 
     /// Compute the (t - 1) x (t - 1) Mhat matrix from the MDS matrix
@@ -121,7 +131,7 @@ impl<
                 index += 1;
             }
         }
-        SquareMatrix::new(&mhat_elements)
+        SquareMatrix::new_from_known(mhat_elements)
     }
 
     /// Create a new matrix from a slice of elements.
@@ -139,6 +149,7 @@ impl<
 
 /// Represents an optimized MDS (maximum distance separable) matrix.
 #[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OptimizedMdsMatrices<
     const N_ROUNDS: usize,
     const N_PARTIAL_ROUNDS: usize,
@@ -162,11 +173,14 @@ pub struct OptimizedMdsMatrices<
     /// The inverse of the (t - 1) x (t - 1) Mhat matrix.
     pub M_hat_inverse: SquareMatrix<STATE_SIZE_MINUS_1, NUM_STATE_SIZE_MINUS_1_ELEMENTS>,
     /// Element at M00
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::fq"))]
     pub M_00: Fq,
     /// M_i
     pub M_i: Matrix<STATE_SIZE, STATE_SIZE, NUM_MDS_ELEMENTS>,
     /// v_collection: one per partial round.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::array"))]
     pub v_collection: [Matrix<1, STATE_SIZE_MINUS_1, STATE_SIZE_MINUS_1>; N_PARTIAL_ROUNDS],
     /// w_hat_collection: one per round
+    #[cfg_attr(feature = "serde", serde(with = "crate::serialize::array"))]
     pub w_hat_collection: [Matrix<STATE_SIZE_MINUS_1, 1, STATE_SIZE_MINUS_1>; N_PARTIAL_ROUNDS],
 }
@@ -0,0 +1,219 @@
+//! Precomputation of the optimized form of Poseidon1 parameters described in Appendix B
+//! of the Poseidon paper, for any MDS matrix and round constants.
+
+use alloc::vec::Vec;
+
+use decaf377::Fq;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+use crate::{
+    alpha::Alpha,
+    dynamic::{DynMatrix, DynOptimizedMdsMatrices, DynPoseidonParameters},
+    error::PoseidonParameterError,
+    matrix::gauss_jordan,
+    round_numbers::RoundNumbers,
+};
+
+impl DynPoseidonParameters {
+    /// Create parameters from the MDS matrix and round constants, precomputing the
+    /// optimized matrices and round constants with [`optimized_mds`] and
+    /// [`optimized_arc`].
+    pub fn from_unoptimized(
+        M: usize,
+        alpha: Alpha,
+        rounds: RoundNumbers,
+        mds: DynMatrix,
+        arc: DynMatrix,
+    ) -> Result<Self, PoseidonParameterError> {
+        let optimized_mds = optimized_mds(&mds, rounds)?;
+        let optimized_arc = optimized_arc(&arc, &mds, rounds)?;
+        let parameters = Self {
+            M,
+            alpha,
+            rounds,
+            mds,
+            arc,
+            optimized_arc,
+            optimized_mds,
+        };
+        parameters.check_dimensions()?;
+        Ok(parameters)
+    }
+}
+
+/// Compute the matrices used by the optimized permutation from the `t x t` MDS matrix.
+///
+/// This is the decomposition $M = M' M''$ of Eqn. 7 in Appendix B, and the sparse
+/// matrices of the `r_P` partial rounds, as `calc_equivalent_matrices` in
+/// `poseidonperm_x3_64_24_optimized.sage` of the reference implementation computes them.
+pub fn optimized_mds(
+    mds: &DynMatrix,
+    rounds: RoundNumbers,
+) -> Result<DynOptimizedMdsMatrices, PoseidonParameterError> {
+    let t = mds.n_rows();
+    if t < 2 || mds.n_cols() != t {
+        return Err(PoseidonParameterError::InvalidMatrixDimensions);
+    }
+
+    let M_hat = hat(mds);
+    let M_hat_inverse = inverse(&M_hat)?;
+    let v = v(mds);
+    let w = w(mds);
+    let M_00 = mds.get_element(0, 0);
+    let M_prime = prime(&M_hat);
+    let M_doubleprime = doubleprime(&M_hat_inverse.mat_mul(&w)?, &v, M_00);
+
+    // The sparse matrices are computed from the last partial round backwards.
+    let M_T = transpose(mds);
+    let mut M_mul = M_T.clone();
+    let mut M_i = identity(t);
+    let mut v_collection = Vec::with_capacity(rounds.partial());
+    let mut hat_and_w = Vec::with_capacity(rounds.partial());
+    for _ in 0..rounds.partial() {
+        let M_hat = hat(&M_mul);
+        v_collection.push(self::v(&M_mul));
+
+        // Now we compute M' and M * M' for the previous round
+        M_i = prime(&M_hat);
+        hat_and_w.push((M_hat, self::w(&M_mul)));
+        M_T.mat_mul_into(&M_i, &mut M_mul)?;
+    }
+
+    // Only the chain of products depends on the previous round, so the inverses are
+    // computed afterwards, in parallel with the `parallel` feature.
+    let w_hat = |(M_hat, w): &(DynMatrix, DynMatrix)| inverse(M_hat)?.mat_mul(w);
+    #[cfg(not(feature = "parallel"))]
+    let w_hat_collection = hat_and_w.iter().map(w_hat).collect::<Result<_, _>>()?;
+    #[cfg(feature = "parallel")]
+    let w_hat_collection = hat_and_w.par_iter().map(w_hat).collect::<Result<_, _>>()?;
+
+    Ok(DynOptimizedMdsMatrices {
+        M_hat,
+        v,
+        w,
+        M_prime,
+        M_doubleprime,
+        M_inverse: inverse(mds)?,
+        M_hat_inverse,
+        M_00,
+        M_i: transpose(&M_i),
+        v_collection,
+        w_hat_collection,
+    })
+}
+
+/// Compute the round constants used by the optimized permutation from the
+/// `num_total_rounds x t` round constants and the MDS matrix.
+///
+/// The constants of each partial round but the first are moved through the linear layer
+/// of the previous round, so that only the first element of the state has a nonzero
+/// constant added in those rounds.
+pub fn optimized_arc(
+    arc: &DynMatrix,
+    mds: &DynMatrix,
+    rounds: RoundNumbers,
+) -> Result<DynMatrix, PoseidonParameterError> {
+    let t = mds.n_rows();
+    let r_f = rounds.full() / 2;
+    let r_T = rounds.total();
+    if t < 2 || mds.n_cols() != t || arc.n_rows() != r_T || arc.n_cols() != t || r_T < 1 {
+        return Err(PoseidonParameterError::InvalidMatrixDimensions);
+    }
+
+    let mds_inv = inverse(&transpose(mds))?;
+    let mut constants = arc.elements().to_vec();
+    let mut inv_cip1 = DynMatrix::new(1, t, constants[..t].to_vec());
+    // C_i = M^-1 * C_(i+1)
+    for r in (r_f..(r_T - 1).saturating_sub(r_f)).rev() {
+        let next = DynMatrix::new(1, t, constants[(r + 1) * t..(r + 2) * t].to_vec());
+        next.mat_mul_into(&mds_inv, &mut inv_cip1)?;
+
+        for j in 1..t {
+            constants[r * t + j] += inv_cip1.get_element(0, j);
+        }
+        constants[(r + 1) * t] = inv_cip1.get_element(0, 0);
+        for j in 1..t {
+            constants[(r + 1) * t + j] = Fq::from(0u64);
+        }
+    }
+
+    Ok(DynMatrix::new(r_T, t, constants))
+}
+
+/// The `(t - 1) x (t - 1)` matrix with the first row and column removed.
+fn hat(matrix: &DynMatrix) -> DynMatrix {
+    let dim = matrix.n_rows();
+    let elements = (1..dim)
+        .flat_map(|i| (1..dim).map(move |j| matrix.get_element(i, j)))
+        .collect();
+    DynMatrix::new(dim - 1, dim - 1, elements)
+}
+
+/// The elements $M_{0,1} .. M_{0,t-1}$ of the first row.
+fn v(matrix: &DynMatrix) -> DynMatrix {
+    let dim = matrix.n_rows();
+    DynMatrix::new(1, dim - 1, matrix.elements()[1..dim].to_vec())
+}
+
+/// The elements $M_{1,0} .. M_{t-1,0}$ of the first column.
+fn w(matrix: &DynMatrix) -> DynMatrix {
+    let dim = matrix.n_rows();
+    let elements = (1..dim).map(|i| matrix.get_element(i, 0)).collect();
+    DynMatrix::new(dim - 1, 1, elements)
+}
+
+/// The matrix with $\hat{M}$ in the lower right corner and 1 in the upper left corner.
+fn prime(M_hat: &DynMatrix) -> DynMatrix {
+    let dim = M_hat.n_rows() + 1;
+    let elements = (0..dim)
+        .flat_map(|i| {
+            (0..dim).map(move |j| match (i, j) {
+                (0, 0) => Fq::from(1u64),
+                (0, _) | (_, 0) => Fq::from(0u64),
+                _ => M_hat.get_element(i - 1, j - 1),
+            })
+        })
+        .collect();
+    DynMatrix::new(dim, dim, elements)
+}
+
+/// The sparse matrix with $M_{0,0}$, $v$ and $\hat{w} = \hat{M}^{-1} w$ in the first
+/// row and column, and the identity in the lower right corner.
+fn doubleprime(w_hat: &DynMatrix, v: &DynMatrix, M_00: Fq) -> DynMatrix {
+    let dim = w_hat.n_rows() + 1;
+    let elements = (0..dim)
+        .flat_map(|i| {
+            (0..dim).map(move |j| match (i, j) {
+                (0, 0) => M_00,
+                (0, _) => v.get_element(0, j - 1),
+                (_, 0) => w_hat.get_element(i - 1, 0),
+                _ => Fq::from((i == j) as u64),
+            })
+        })
+        .collect();
+    DynMatrix::new(dim, dim, elements)
+}
+
+fn identity(dim: usize) -> DynMatrix {
+    let elements = (0..dim * dim)
+        .map(|index| Fq::from((index / dim == index % dim) as u64))
+        .collect();
+    DynMatrix::new(dim, dim, elements)
+}
+
+fn transpose(matrix: &DynMatrix) -> DynMatrix {
+    let elements = (0..matrix.n_cols())
+        .flat_map(|j| (0..matrix.n_rows()).map(move |i| matrix.get_element(i, j)))
+        .collect();
+    DynMatrix::new(matrix.n_cols(), matrix.n_rows(), elements)
+}
+
+/// Invert the square `matrix` by Gauss-Jordan elimination.
+fn inverse(matrix: &DynMatrix) -> Result<DynMatrix, PoseidonParameterError> {
+    let n = matrix.n_rows();
+    let mut elements = matrix.elements().to_vec();
+    let mut inverse = identity(n).elements().to_vec();
+    gauss_jordan(&mut elements, &mut inverse, n)?;
+    Ok(DynMatrix::new(n, n, inverse))
+}
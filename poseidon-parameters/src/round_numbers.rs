@@ -1,5 +1,6 @@
 /// `RoundNumbers` required for security based on known attacks.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RoundNumbers {
     /// Number of partial rounds.
     pub r_P: usize,
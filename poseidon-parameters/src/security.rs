@@ -0,0 +1,439 @@
+//! Round numbers required for security, following Section 5.5 and Appendix C of the
+//! Poseidon paper.
+
+use decaf377::Fq;
+
+use crate::{
+    alpha::Alpha, dynamic::DynPoseidonParameters, error::PoseidonParameterError,
+    round_numbers::RoundNumbers, v1::PoseidonParameters,
+};
+
+/// The margins of [`RoundNumbers`] above the fewest rounds secure against the
+/// statistical and algebraic attacks of the Poseidon paper.
+///
+/// The paper recommends a margin of 2 full rounds and 7.5% of the partial rounds,
+/// which the parameters generated by `poseidon-paramgen` include.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SecurityMargins {
+    /// The fewest full rounds, and the fewest partial rounds given the provided number
+    /// of full rounds, that are secure.
+    pub minimum: RoundNumbers,
+    /// Number of full rounds above the minimum.
+    pub full: usize,
+    /// Number of partial rounds above the minimum.
+    pub partial: usize,
+}
+
+/// The margins of [`RoundNumbers`] against each attack of the Poseidon paper, for
+/// security reviews.
+///
+/// The [`Display`](core::fmt::Display) implementation renders a report of the margins.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AttackMargins {
+    /// Security level in bits.
+    pub M: usize,
+    /// Width of the permutation.
+    pub t: usize,
+    /// Exponent of the S-box.
+    pub alpha: Alpha,
+    /// The round numbers the margins are for.
+    pub rounds: RoundNumbers,
+    /// Full rounds against the statistical attacks of Section 5.5.1.
+    pub statistical: AttackMargin,
+    /// Rounds against the interpolation attacks of Section 5.5.2.
+    pub interpolation: AttackMargin,
+    /// Rounds against the Grobner basis attacks of Section 5.5.2 and Appendix C.2.2.
+    pub grobner_basis: AttackMargin,
+}
+
+/// The number of rounds against an attack, and the fewest that are secure.
+///
+/// For the algebraic attacks, the rounds are counted as $R_F + R_P$ for positive alpha
+/// and $\floor{R_F \log_2(t)} + R_P$ for inverse alpha, see Eqn 4.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AttackMargin {
+    /// The number of rounds against the attack.
+    pub rounds: usize,
+    /// The fewest rounds that are secure against the attack.
+    pub required: usize,
+}
+
+impl AttackMargin {
+    /// The number of rounds above the fewest that are secure, negative if insecure.
+    pub fn margin(&self) -> isize {
+        self.rounds as isize - self.required as isize
+    }
+
+    /// Whether there are enough rounds against the attack.
+    pub fn is_secure(&self) -> bool {
+        self.rounds >= self.required
+    }
+}
+
+impl AttackMargins {
+    /// Whether there are enough rounds against every attack.
+    pub fn is_secure(&self) -> bool {
+        self.statistical.is_secure()
+            && self.interpolation.is_secure()
+            && self.grobner_basis.is_secure()
+    }
+}
+
+impl core::fmt::Display for AttackMargins {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let alpha = match self.alpha {
+            Alpha::Exponent(exp) => format!("x^{}", exp),
+            Alpha::Inverse => String::from("x^-1"),
+        };
+        writeln!(
+            f,
+            "Poseidon1 attack margins for M = {}, t = {}, alpha = {}, r_F = {}, r_P = {}",
+            self.M,
+            self.t,
+            alpha,
+            self.rounds.full(),
+            self.rounds.partial()
+        )?;
+        let algebraic_rounds = match self.alpha {
+            Alpha::Exponent(_) => "r_F + r_P",
+            Alpha::Inverse => "floor(r_F log2(t)) + r_P",
+        };
+        let attacks = [
+            ("statistical", "r_F", self.statistical),
+            ("interpolation", algebraic_rounds, self.interpolation),
+            ("Grobner basis", algebraic_rounds, self.grobner_basis),
+        ];
+        for (attack, rounds, margin) in attacks.iter() {
+            writeln!(
+                f,
+                "  {:<14} {} = {}, required {}, margin {}{}",
+                attack,
+                rounds,
+                margin.rounds,
+                margin.required,
+                margin.margin(),
+                if margin.is_secure() {
+                    ""
+                } else {
+                    " (INSECURE)"
+                }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl RoundNumbers {
+    /// The margins of these round numbers against each attack at the security level of
+    /// `M` bits, for a permutation of width `t` with S-box `alpha` over a field with a
+    /// modulus of `log_2_p` bits.
+    ///
+    /// Unlike [`RoundNumbers::security_margins`], insecure round numbers are not an
+    /// error, but have a negative margin.
+    pub fn attack_margins(
+        &self,
+        M: usize,
+        log_2_p: f64,
+        t: usize,
+        alpha: Alpha,
+    ) -> Result<AttackMargins, PoseidonParameterError> {
+        if M > 256 {
+            return Err(PoseidonParameterError::UnsupportedSecurityLevel);
+        }
+        if let Alpha::Exponent(exp) = alpha {
+            if exp < 2 {
+                return Err(PoseidonParameterError::InvalidAlpha);
+            }
+        }
+        let input = Input { M, log_2_p, t };
+        let algebraic_rounds = match alpha {
+            Alpha::Exponent(_) => self.full(),
+            Alpha::Inverse => (self.full() as f64 * (t as f64).log2()).floor() as usize,
+        } + self.partial();
+
+        Ok(AttackMargins {
+            M,
+            t,
+            alpha,
+            rounds: *self,
+            statistical: AttackMargin {
+                rounds: self.full(),
+                required: input.statistical_attack_full_rounds(alpha),
+            },
+            // The algebraic bounds must be exceeded.
+            interpolation: AttackMargin {
+                rounds: algebraic_rounds,
+                required: input.algebraic_attack_interpolation(alpha) + 1,
+            },
+            grobner_basis: AttackMargin {
+                rounds: algebraic_rounds,
+                required: input.algebraic_attack_grobner_basis(alpha) + 1,
+            },
+        })
+    }
+
+    /// Check that these round numbers are secure at the security level of `M` bits, for
+    /// a permutation of width `t` with S-box `alpha` over a field with a modulus of
+    /// `log_2_p` bits, and return their margins.
+    ///
+    /// The minimum round numbers are recomputed as in `poseidon-paramgen`, which does
+    /// not support security levels beyond 256 bits for Poseidon1.
+    pub fn security_margins(
+        &self,
+        M: usize,
+        log_2_p: f64,
+        t: usize,
+        alpha: Alpha,
+    ) -> Result<SecurityMargins, PoseidonParameterError> {
+        if M > 256 {
+            return Err(PoseidonParameterError::UnsupportedSecurityLevel);
+        }
+        if let Alpha::Exponent(exp) = alpha {
+            if exp < 2 {
+                return Err(PoseidonParameterError::InvalidAlpha);
+            }
+        }
+        let input = Input { M, log_2_p, t };
+
+        let min_full = input.statistical_attack_full_rounds(alpha);
+        if self.full() < min_full {
+            return Err(PoseidonParameterError::InsecureRoundNumbers);
+        }
+
+        // The algebraic attacks bound the total number of rounds for positive alpha,
+        // and $\floor{R_F \log_2(t)} + R_P$ for inverse alpha. See Eqn 4.
+        let bound = input
+            .algebraic_attack_interpolation(alpha)
+            .max(input.algebraic_attack_grobner_basis(alpha));
+        let full_rounds_weight = match alpha {
+            Alpha::Exponent(_) => self.full(),
+            Alpha::Inverse => (self.full() as f64 * (t as f64).log2()).floor() as usize,
+        };
+        let min_partial = (bound + 1).saturating_sub(full_rounds_weight).max(1);
+        if self.partial() < min_partial {
+            return Err(PoseidonParameterError::InsecureRoundNumbers);
+        }
+
+        Ok(SecurityMargins {
+            minimum: RoundNumbers {
+                r_F: min_full,
+                r_P: min_partial,
+            },
+            full: self.full() - min_full,
+            partial: self.partial() - min_partial,
+        })
+    }
+}
+
+impl RoundNumbers {
+    /// The round numbers with the fewest S-boxes that are secure at the security level of
+    /// `M` bits, for a permutation of width `t` with S-box `alpha` over a field with a
+    /// modulus of `log_2_p` bits, including the margin recommended by the paper of 2 full
+    /// rounds and 7.5% of the partial rounds, rounded up.
+    ///
+    /// These are the round numbers `poseidon-paramgen` chooses with its default margin,
+    /// e.g. for `t = 12` and `alpha = 17` over decaf377 at the 128-bit level:
+    ///
+    /// ```
+    /// use poseidon_parameters::v1::{Alpha, RoundNumbers};
+    ///
+    /// let rounds = RoundNumbers::generate_for_fq(128, 12, Alpha::Exponent(17)).unwrap();
+    /// assert_eq!(rounds, RoundNumbers { r_F: 8, r_P: 31 });
+    /// ```
+    pub fn generate(
+        M: usize,
+        log_2_p: f64,
+        t: usize,
+        alpha: Alpha,
+    ) -> Result<Self, PoseidonParameterError> {
+        if M > 256 {
+            return Err(PoseidonParameterError::UnsupportedSecurityLevel);
+        }
+        if let Alpha::Exponent(exp) = alpha {
+            if exp < 2 {
+                return Err(PoseidonParameterError::InvalidAlpha);
+            }
+        }
+        let input = Input { M, log_2_p, t };
+        let min_full = input.statistical_attack_full_rounds(alpha);
+        let bound = input
+            .algebraic_attack_interpolation(alpha)
+            .max(input.algebraic_attack_grobner_basis(alpha));
+
+        // Loop through choices of r_F, r_P as `poseidon-paramgen` does, picking the
+        // cheapest secure candidate after the margin is added, and the one with the fewer
+        // full rounds if the cost is tied.
+        let mut choice: Option<(RoundNumbers, usize)> = None;
+        for r_P in 1..400 {
+            for r_F in min_full.max(4)..100 {
+                let full_rounds_weight = match alpha {
+                    Alpha::Exponent(_) => r_F,
+                    Alpha::Inverse => (r_F as f64 * (t as f64).log2()).floor() as usize,
+                };
+                if full_rounds_weight + r_P <= bound {
+                    continue;
+                }
+
+                let candidate = RoundNumbers {
+                    r_F: r_F + 2,
+                    r_P: (1.075 * (r_P as f64)).ceil() as usize,
+                };
+                let cost = t * candidate.full() + candidate.partial();
+                match choice {
+                    Some((chosen, chosen_cost))
+                        if chosen_cost < cost
+                            || (chosen_cost == cost && chosen.full() <= candidate.full()) => {}
+                    _ => choice = Some((candidate, cost)),
+                }
+            }
+        }
+
+        choice
+            .map(|(rounds, _)| rounds)
+            .ok_or(PoseidonParameterError::InsecureRoundNumbers)
+    }
+
+    /// The round numbers chosen by [`RoundNumbers::generate`] for a permutation over
+    /// [`Fq`].
+    pub fn generate_for_fq(
+        M: usize,
+        t: usize,
+        alpha: Alpha,
+    ) -> Result<Self, PoseidonParameterError> {
+        Self::generate(M, log_2_modulus(), t, alpha)
+    }
+}
+
+/// The binary logarithm of the modulus of [`Fq`].
+fn log_2_modulus() -> f64 {
+    // The modulus is odd, so its predecessor has the same logarithm to well within
+    // the precision of an `f64`.
+    let bytes = (-Fq::from(1u64)).to_bytes();
+    let top = bytes
+        .iter()
+        .rposition(|&byte| byte != 0)
+        .expect("the modulus is not 1");
+    let start = top.saturating_sub(7);
+    let mut leading = [0u8; 8];
+    leading[..=top - start].copy_from_slice(&bytes[start..=top]);
+    (u64::from_le_bytes(leading) as f64).log2() + 8.0 * start as f64
+}
+
+impl<
+        const STATE_SIZE: usize,
+        const STATE_SIZE_MINUS_1: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+        const NUM_PARTIAL_ROUNDS: usize,
+    >
+    PoseidonParameters<
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >
+{
+    /// The margins of the round numbers against each attack, at the security level `M`
+    /// of these parameters. See [`RoundNumbers::attack_margins`].
+    pub fn attack_margins(&self) -> Result<AttackMargins, PoseidonParameterError> {
+        self.rounds
+            .attack_margins(self.M, log_2_modulus(), STATE_SIZE, self.alpha)
+    }
+
+    /// Check that the round numbers are secure at the security level `M` of these
+    /// parameters, and return their margins. See [`RoundNumbers::security_margins`].
+    pub fn security_margins(&self) -> Result<SecurityMargins, PoseidonParameterError> {
+        self.rounds
+            .security_margins(self.M, log_2_modulus(), STATE_SIZE, self.alpha)
+    }
+}
+
+impl DynPoseidonParameters {
+    /// The margins of the round numbers against each attack, at the security level `M`
+    /// of these parameters. See [`RoundNumbers::attack_margins`].
+    pub fn attack_margins(&self) -> Result<AttackMargins, PoseidonParameterError> {
+        self.rounds
+            .attack_margins(self.M, log_2_modulus(), self.state_size(), self.alpha)
+    }
+
+    /// Check that the round numbers are secure at the security level `M` of these
+    /// parameters, and return their margins. See [`RoundNumbers::security_margins`].
+    pub fn security_margins(&self) -> Result<SecurityMargins, PoseidonParameterError> {
+        self.rounds
+            .security_margins(self.M, log_2_modulus(), self.state_size(), self.alpha)
+    }
+}
+
+/// The inputs of the bounds below.
+struct Input {
+    M: usize,
+    log_2_p: f64,
+    t: usize,
+}
+
+impl Input {
+    /// Number of full rounds required to defend against statistical attacks.
+    ///
+    /// These are the differential/linear distinguisher attacks described
+    /// in Section 5.5.1 of the paper.
+    fn statistical_attack_full_rounds(&self, alpha: Alpha) -> usize {
+        // C is defined in Section 5.5.1, p.10.
+        let C = match alpha {
+            Alpha::Inverse => 2.0,
+            Alpha::Exponent(exp) => (exp as f64 - 1.0).log2(),
+        };
+
+        if self.M as f64 <= (self.log_2_p.floor() - C) * (self.t as f64 + 1.0) {
+            6
+        } else {
+            10
+        }
+    }
+
+    /// Number of rounds to defend against interpolation attacks, as in Eqn 3 for
+    /// positive alpha and Eqn 4 for inverse alpha.
+    ///
+    /// These attacks are described in Section 5.5.2 of the paper.
+    fn algebraic_attack_interpolation(&self, alpha: Alpha) -> usize {
+        let min = (self.M as f64).min(self.log_2_p);
+        match alpha {
+            Alpha::Inverse => ((self.t as f64).log2().ceil() + (0.5 * min).ceil()) as usize,
+            Alpha::Exponent(exp) => {
+                let exp = exp as f64;
+                ((2f64.log(exp) * min).ceil() + (self.t as f64).log(exp)).ceil() as usize
+            }
+        }
+    }
+
+    /// Number of rounds to defend against Grobner basis attacks.
+    ///
+    /// These are described in Section 5.5.2 of the paper. We use the first two
+    /// conditions described in Section C.2.2, eliding the third since if the first
+    /// condition is satisfied, then the third will be also.
+    fn algebraic_attack_grobner_basis(&self, alpha: Alpha) -> usize {
+        let (M, log_2_p, t) = (self.M as f64, self.log_2_p, self.t as f64);
+        let (grobner_1, grobner_2) = match alpha {
+            Alpha::Inverse => (
+                (0.5 * M.min(log_2_p)).ceil() + t.log2().ceil(),
+                (t - 1.0) + t.log2().ceil() + (M / (t + 1.0)).ceil().min((0.5 * log_2_p).ceil()),
+            ),
+            Alpha::Exponent(exp) => {
+                let log_alpha_2 = 2f64.log(exp as f64);
+                (
+                    log_alpha_2 * (M / 3.0).min(log_2_p / 2.0),
+                    (t - 1.0) + (log_alpha_2 * M / (t + 1.0)).min(log_alpha_2 * log_2_p / 2.0),
+                )
+            }
+        };
+
+        // The most strict Grobner basis constraint.
+        grobner_1.max(grobner_2).floor() as usize
+    }
+}
@@ -0,0 +1,193 @@
+//! `serde` support for parameters.
+//!
+//! Field elements are serialized as the lowercase hex of their 32-byte little-endian
+//! encoding in human-readable formats, and as those bytes otherwise. Matrices are
+//! serialized as the tuple of their elements in row-major order; their dimensions are
+//! part of the type, so decoding a matrix with the wrong number of elements fails.
+//! Non-canonical field elements are rejected.
+
+use core::{convert::TryInto, fmt, marker::PhantomData};
+
+use decaf377::Fq;
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::matrix::Matrix;
+
+const FQ_BYTES: usize = 32;
+
+/// A field element with a `serde` implementation.
+struct SerdeFq(Fq);
+
+impl Serialize for SerdeFq {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let bytes = self.0.to_bytes();
+        if serializer.is_human_readable() {
+            const DIGITS: &[u8; 16] = b"0123456789abcdef";
+            let mut hex = [0u8; 2 * FQ_BYTES];
+            for (byte, pair) in bytes.iter().zip(hex.chunks_mut(2)) {
+                pair[0] = DIGITS[(byte >> 4) as usize];
+                pair[1] = DIGITS[(byte & 0xf) as usize];
+            }
+            serializer.serialize_str(core::str::from_utf8(&hex).expect("hex digits are ASCII"))
+        } else {
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for SerdeFq {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(FqVisitor)
+        } else {
+            deserializer.deserialize_bytes(FqVisitor)
+        }
+    }
+}
+
+struct FqVisitor;
+
+impl<'de> Visitor<'de> for FqVisitor {
+    type Value = SerdeFq;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "a canonical field element as {} little-endian bytes or their hex",
+            FQ_BYTES
+        )
+    }
+
+    fn visit_str<E: de::Error>(self, hex: &str) -> Result<SerdeFq, E> {
+        let hex = hex.as_bytes();
+        if hex.len() != 2 * FQ_BYTES {
+            return Err(E::invalid_length(hex.len(), &self));
+        }
+
+        let nibble = |c: u8| match c {
+            b'0'..=b'9' => Ok(c - b'0'),
+            b'a'..=b'f' => Ok(c - b'a' + 10),
+            b'A'..=b'F' => Ok(c - b'A' + 10),
+            _ => Err(E::invalid_value(de::Unexpected::Char(c as char), &self)),
+        };
+        let mut bytes = [0u8; FQ_BYTES];
+        for (byte, pair) in bytes.iter_mut().zip(hex.chunks(2)) {
+            *byte = (nibble(pair[0])? << 4) | nibble(pair[1])?;
+        }
+        self.visit_bytes(&bytes)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<SerdeFq, E> {
+        let bytes: &[u8; FQ_BYTES] = bytes
+            .try_into()
+            .map_err(|_| E::invalid_length(bytes.len(), &self))?;
+        Fq::from_bytes_checked(bytes)
+            .map(SerdeFq)
+            .map_err(|_| E::custom("non-canonical field element"))
+    }
+
+    // Formats without a bytes type, such as JSON, serialize bytes as a sequence.
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<SerdeFq, A::Error> {
+        let mut bytes = [0u8; FQ_BYTES];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(de::Error::invalid_length(FQ_BYTES + 1, &self));
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+/// `serde` helpers for a single field element, for use with `#[serde(with)]`.
+pub(crate) mod fq {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(element: &Fq, serializer: S) -> Result<S::Ok, S::Error> {
+        SerdeFq(*element).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Fq, D::Error> {
+        SerdeFq::deserialize(deserializer).map(|element| element.0)
+    }
+}
+
+/// `serde` helpers for arrays of any length, serialized as tuples, for use with
+/// `#[serde(with)]`.
+pub(crate) mod array {
+    use super::*;
+
+    pub fn serialize<S, T, const N: usize>(array: &[T; N], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        let mut tuple = serializer.serialize_tuple(N)?;
+        for item in array {
+            tuple.serialize_element(item)?;
+        }
+        tuple.end()
+    }
+
+    pub fn deserialize<'de, D, T, const N: usize>(deserializer: D) -> Result<[T; N], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_tuple(N, ArrayVisitor(PhantomData))
+    }
+
+    struct ArrayVisitor<T, const N: usize>(PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<T, N> {
+        type Value = [T; N];
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a tuple of {} elements", N)
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<[T; N], A::Error> {
+            let mut items: [Option<T>; N] = [(); N].map(|()| None);
+            for (i, item) in items.iter_mut().enumerate() {
+                *item = Some(
+                    seq.next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &self))?,
+                );
+            }
+            if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                return Err(de::Error::invalid_length(N + 1, &self));
+            }
+            Ok(items.map(|item| item.expect("every item was deserialized")))
+        }
+    }
+}
+
+impl<const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize> Serialize
+    for Matrix<N_ROWS, N_COLS, N_ELEMENTS>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        array::serialize(&self.elements.map(SerdeFq), serializer)
+    }
+}
+
+impl<'de, const N_ROWS: usize, const N_COLS: usize, const N_ELEMENTS: usize> Deserialize<'de>
+    for Matrix<N_ROWS, N_COLS, N_ELEMENTS>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if N_ELEMENTS != N_ROWS * N_COLS {
+            return Err(de::Error::custom(
+                "Matrix has an insufficient number of elements",
+            ));
+        }
+        let elements: [SerdeFq; N_ELEMENTS] = array::deserialize(deserializer)?;
+        Ok(Self {
+            elements: elements.map(|element| element.0),
+        })
+    }
+}
@@ -0,0 +1,95 @@
+use decaf377::Fq;
+
+use crate::mds_matrix::OptimizedMdsMatrices;
+
+/// The sparse `t x t` matrix of the linear layer of a partial round in the optimized
+/// permutation, see Appendix B of the Poseidon paper.
+///
+/// Only the first row and the first column are stored, the rest of the matrix is the
+/// identity:
+///
+/// ```text
+/// M_00  w_hat
+/// v     I
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SparseMatrix<'a> {
+    /// The element in the upper left corner.
+    pub M_00: Fq,
+    /// The rest of the first column, with `t - 1` elements.
+    pub v: &'a [Fq],
+    /// The rest of the first row, with `t - 1` elements.
+    pub w_hat: &'a [Fq],
+}
+
+impl SparseMatrix<'_> {
+    /// Width of the matrix.
+    pub fn n_rows(&self) -> usize {
+        self.v.len() + 1
+    }
+
+    /// Get the element in row `i` and column `j`.
+    pub fn get_element(&self, i: usize, j: usize) -> Fq {
+        match (i, j) {
+            (0, 0) => self.M_00,
+            (0, _) => self.w_hat[j - 1],
+            (_, 0) => self.v[i - 1],
+            _ => Fq::from((i == j) as u64),
+        }
+    }
+
+    /// Multiply the column vector `state` by this matrix in place, in `4(t - 1) + 1`
+    /// multiplications and additions instead of the `t^2` of a dense matrix.
+    ///
+    /// This is `cheap_matrix_mul` in the Sage spec.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `state` is empty.
+    pub fn mul_in_place(&self, state: &mut [Fq]) {
+        let (first, rest) = state.split_first_mut().expect("state is not empty");
+        let old_first = *first;
+
+        // state_words_new[0] = M_0_0 * state_words[0] + w_hat . state_words[1..]
+        *first = self.M_00 * old_first
+            + self
+                .w_hat
+                .iter()
+                .zip(rest.iter())
+                .map(|(x, y)| *x * *y)
+                .sum::<Fq>();
+
+        // state_words_new[i + 1] = v[i] * state_words[0] + state_words[i + 1]
+        for (word, v) in rest.iter_mut().zip(self.v) {
+            *word += *v * old_first;
+        }
+    }
+}
+
+impl<
+        const N_ROUNDS: usize,
+        const N_PARTIAL_ROUNDS: usize,
+        const STATE_SIZE: usize,
+        const STATE_SIZE_MINUS_1: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+    >
+    OptimizedMdsMatrices<
+        N_ROUNDS,
+        N_PARTIAL_ROUNDS,
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+    >
+{
+    /// The sparse matrix at `index` in `v_collection` and `w_hat_collection`, which are
+    /// ordered from the last partial round backwards.
+    pub fn sparse_matrix(&self, index: usize) -> SparseMatrix<'_> {
+        SparseMatrix {
+            M_00: self.M_00,
+            v: &self.v_collection[index].elements,
+            w_hat: &self.w_hat_collection[index].elements,
+        }
+    }
+}
@@ -1,7 +1,17 @@
 pub use crate::alpha::Alpha;
+#[cfg(feature = "alloc")]
+pub use crate::dynamic::{DynMatrix, DynOptimizedMdsMatrices, DynPoseidonParameters};
+pub use crate::error::PoseidonParameterError;
+#[cfg(feature = "alloc")]
+pub use crate::optimize::{optimized_arc, optimized_mds};
 pub use crate::round_numbers::RoundNumbers;
+#[cfg(feature = "std")]
+pub use crate::security::{AttackMargin, AttackMargins, SecurityMargins};
+pub use crate::sparse_matrix::SparseMatrix;
 
-pub use crate::matrix::{mat_mul, square_mat_mul, Matrix, SquareMatrix};
+pub use crate::matrix::{
+    mat_mul, mat_mul_into, square_mat_mul, square_mat_mul_into, Matrix, SquareMatrix,
+};
 pub use crate::{
     arc_matrix::ArcMatrix, arc_matrix::OptimizedArcMatrix, matrix_ops::MatrixOperations,
     matrix_ops::SquareMatrixOperations, mds_matrix::MdsMatrix, mds_matrix::OptimizedMdsMatrices,
@@ -11,7 +21,8 @@ pub use crate::{
 ///
 /// The const `STATE_SIZE` corresponds to $t$ in the paper, the width of the hash function,
 /// e.g. $t=3$ corresponds to a 2-to-1 hash.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PoseidonParameters<
     const STATE_SIZE: usize,
     const STATE_SIZE_MINUS_1: usize,
@@ -16,6 +16,7 @@ pub use crate::{matrix_ops::MatrixOperations, matrix_ops::SquareMatrixOperations
 /// The const `NUM_ROUND_ROWS`, `NUM_ROUND_COLS`, and `NUM_ROUND_ELEMENTS` correspond to the number
 /// of rows in the round constants matrix.
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PoseidonParameters<
     const STATE_SIZE: usize,
     const NUM_MDS_ELEMENTS: usize,
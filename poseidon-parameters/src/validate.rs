@@ -0,0 +1,152 @@
+//! Security checks for Poseidon1 parameters obtained from an untrusted source.
+
+use alloc::vec::Vec;
+
+use decaf377::Fq;
+
+use crate::{
+    dynamic::{DynMatrix, DynPoseidonParameters},
+    encoding::is_inverse,
+    error::PoseidonParameterError,
+};
+
+/// Check that `parameters` use a secure linear layer.
+///
+/// This checks that:
+///
+/// * the dimensions of the matrices are consistent, as in
+///   [`DynPoseidonParameters::check_dimensions`],
+/// * the MDS matrix is actually MDS, i.e. every square submatrix of it is
+///   nonsingular, which is checked exhaustively,
+/// * the MDS matrix admits no infinitely long subspace trails in which the S-boxes of
+///   the partial rounds stay inactive, as in Algorithm 1 of
+///   [Grassi, Rechberger, Schofnegger 2020](https://eprint.iacr.org/2020/500),
+/// * the stored inverses of the MDS matrix and of $\hat{M}$ are correct, and
+///   $M'$ and $M''$ are invertible.
+///
+/// The round numbers and round constants are not checked.
+///
+/// Trails with active S-boxes, covered by Algorithms 2 and 3 of that paper, are not
+/// checked. The number of submatrices grows exponentially with $t$, so this is only
+/// practical for the widths used in practice.
+pub fn validate(parameters: &DynPoseidonParameters) -> Result<(), PoseidonParameterError> {
+    parameters.check_dimensions()?;
+    let t = parameters.state_size();
+    let mds = &parameters.mds;
+
+    if !is_mds(mds) {
+        return Err(PoseidonParameterError::NotMds);
+    }
+    if !has_no_invariant_subspace_trails(mds) {
+        return Err(PoseidonParameterError::InvariantSubspaceTrail);
+    }
+
+    let optimized = &parameters.optimized_mds;
+    let inverses_match = is_inverse(t, |i, j| mds.get_element(i, j), &optimized.M_inverse)
+        && is_inverse(
+            t - 1,
+            |i, j| mds.get_element(i + 1, j + 1),
+            &optimized.M_hat_inverse,
+        );
+    if !inverses_match {
+        return Err(PoseidonParameterError::InconsistentParameters);
+    }
+    for matrix in [&optimized.M_prime, &optimized.M_doubleprime].iter() {
+        if is_singular(t, matrix.elements().to_vec()) {
+            return Err(PoseidonParameterError::NoMatrixInverse);
+        }
+    }
+
+    Ok(())
+}
+
+/// Whether every square submatrix of the square `matrix` is nonsingular.
+fn is_mds(matrix: &DynMatrix) -> bool {
+    let t = matrix.n_rows();
+    (1..=t).all(|k| {
+        let mut rows: Vec<usize> = (0..k).collect();
+        loop {
+            let mut cols: Vec<usize> = (0..k).collect();
+            loop {
+                let elements = rows
+                    .iter()
+                    .flat_map(|&i| cols.iter().map(move |&j| matrix.get_element(i, j)))
+                    .collect();
+                if is_singular(k, elements) {
+                    return false;
+                }
+                if !next_combination(&mut cols, t) {
+                    break;
+                }
+            }
+            if !next_combination(&mut rows, t) {
+                return true;
+            }
+        }
+    })
+}
+
+/// Advance `indices`, a strictly increasing sequence of indices below `n`, to the next
+/// such sequence in lexicographic order. Returns `false` once all sequences were seen.
+fn next_combination(indices: &mut [usize], n: usize) -> bool {
+    let k = indices.len();
+    for i in (0..k).rev() {
+        if indices[i] < n - k + i {
+            indices[i] += 1;
+            for j in i + 1..k {
+                indices[j] = indices[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+    false
+}
+
+/// Whether the `n x n` matrix with row-major `elements` is singular.
+///
+/// This uses Gaussian elimination, scaling rows instead of dividing by the pivot, as
+/// only whether the determinant is zero matters.
+fn is_singular(n: usize, mut elements: Vec<Fq>) -> bool {
+    let zero = Fq::from(0u64);
+    for col in 0..n {
+        let pivot = match (col..n).find(|&row| elements[row * n + col] != zero) {
+            Some(pivot) => pivot,
+            None => return true,
+        };
+        for j in col..n {
+            elements.swap(pivot * n + j, col * n + j);
+        }
+        let pivot_element = elements[col * n + col];
+        for row in col + 1..n {
+            let factor = elements[row * n + col];
+            if factor == zero {
+                continue;
+            }
+            for j in col..n {
+                let subtrahend = factor * elements[col * n + j];
+                elements[row * n + j] = pivot_element * elements[row * n + j] - subtrahend;
+            }
+        }
+    }
+    false
+}
+
+/// Whether no nonzero subspace of the states with an inactive S-box is invariant
+/// under the square `matrix`.
+///
+/// In the partial rounds only the first word goes through the S-box, so a difference
+/// $\delta$ keeps the S-box inactive for all rounds iff $e_0 M^i \delta = 0$ for all
+/// $i$. By Cayley-Hamilton it suffices to take $i < t$, so such a difference exists
+/// iff the rows $e_0 M^i$, $0 \leq i < t$, are linearly dependent.
+fn has_no_invariant_subspace_trails(matrix: &DynMatrix) -> bool {
+    let t = matrix.n_rows();
+    let mut row: Vec<Fq> = (0..t).map(|j| Fq::from((j == 0) as u64)).collect();
+    let mut rows = Vec::with_capacity(t * t);
+    for _ in 0..t {
+        rows.extend_from_slice(&row);
+        row = (0..t)
+            .map(|j| (0..t).map(|k| row[k] * matrix.get_element(k, j)).sum())
+            .collect();
+    }
+    !is_singular(t, rows)
+}
@@ -1,8 +1,9 @@
 use ark_ff::PrimeField;
 use ark_std::vec::Vec;
 use num::integer::gcd;
+use num::One;
 use num_bigint::BigUint;
-use poseidon_parameters::v1::Alpha;
+use poseidon_parameters::v1::{Alpha, PoseidonParameterError};
 
 /// Shortest addition chains for small numbers.
 ///
@@ -44,29 +45,38 @@ impl ShortestAdditionChains {
     }
 }
 
-/// Select the best choice of `Alpha` given the parameters.
-pub fn generate<F: PrimeField>(p: F::BigInt, allow_inverse: bool) -> Alpha {
+/// Select the best choice of `Alpha` given the parameters, or return
+/// [`PoseidonParameterError::InvalidAlpha`] if there is no small positive exponent and
+/// `allow_inverse` is not enabled.
+pub fn generate<F: PrimeField>(
+    p: F::BigInt,
+    allow_inverse: bool,
+) -> Result<Alpha, PoseidonParameterError> {
+    generate_for_modulus(&p.into(), allow_inverse).ok_or(PoseidonParameterError::InvalidAlpha)
+}
+
+/// Select the best choice of `Alpha` for the modulus `p`, or `None` if there is no
+/// small positive exponent and `allow_inverse` is not enabled.
+pub(crate) fn generate_for_modulus(p: &BigUint, allow_inverse: bool) -> Option<Alpha> {
     // Move through the addition chains in increasing depth,
     // picking the leftmost choice that meets the coprime requirement.
     for candidate in SHORTEST_ADDITION_CHAINS.depths_in_order() {
-        if candidate % 2 != 0 && alpha_coprime_to_p_minus_one::<F>(*candidate, p) {
-            return Alpha::Exponent(*candidate);
+        if candidate % 2 != 0 && alpha_coprime_to_p_minus_one(*candidate, p) {
+            return Some(Alpha::Exponent(*candidate));
         }
     }
 
     if allow_inverse {
-        Alpha::Inverse
+        Some(Alpha::Inverse)
     } else {
-        panic!("could not find a small positive exponent and allow_inverse was not enabled")
+        None
     }
 }
 
-fn alpha_coprime_to_p_minus_one<F: PrimeField>(alpha: u32, p: F::BigInt) -> bool {
-    let one: BigUint = F::one().into();
-    let p_minus_one: BigUint = p.into() - one;
-    let alpha_bigint: BigUint = F::from(alpha).into();
-    let computed_gcd = gcd(alpha_bigint, p_minus_one);
-    F::from(computed_gcd) == F::one()
+/// Whether `x^alpha` is a permutation of the field of modulus `p`.
+pub(crate) fn alpha_coprime_to_p_minus_one(alpha: u32, p: &BigUint) -> bool {
+    let p_minus_one = p - BigUint::one();
+    gcd(BigUint::from(alpha), p_minus_one).is_one()
 }
 
 #[cfg(test)]
@@ -99,16 +109,29 @@ mod tests {
         // We know from the Poseidon paper that we should get an alpha of 5 for
         // BLS12-381 and BN254 (see Table 2)
         let p = Fq381::MODULUS;
-        assert_eq!(generate::<Fq381>(p, true), Alpha::Exponent(5));
+        assert_eq!(generate::<Fq381>(p, true), Ok(Alpha::Exponent(5)));
 
         let p = Fq254::MODULUS;
-        assert_eq!(generate::<Fq254>(p, true), Alpha::Exponent(5));
+        assert_eq!(generate::<Fq254>(p, true), Ok(Alpha::Exponent(5)));
+    }
+
+    #[test]
+    fn check_alpha_3() {
+        // The largest prime below 2^255 that is 2 mod 3, so 3 is coprime to p - 1.
+        let p = (BigUint::one() << 255) - BigUint::from(735u32);
+        assert_eq!(generate_for_modulus(&p, false), Some(Alpha::Exponent(3)));
+
+        // 3 divides p - 1 for the base field of BN254 and the scalar field of BLS12-381.
+        let p: BigUint = Fq254::MODULUS.into();
+        assert!(!alpha_coprime_to_p_minus_one(3, &p));
+        let p: BigUint = Fq381::MODULUS.into();
+        assert!(!alpha_coprime_to_p_minus_one(3, &p));
     }
 
     #[test]
     fn check_alpha_17() {
         // For Poseidon377, we should get an alpha of 17 (from our own work).
         let p = Fq377::MODULUS;
-        assert_eq!(generate::<Fq377>(p, true), Alpha::Exponent(17));
+        assert_eq!(generate::<Fq377>(p, true), Ok(Alpha::Exponent(17)));
     }
 }
@@ -6,7 +6,7 @@ mod tests {
     use ark_ed_on_bls12_377::Fq;
     use ark_ff::{BigInteger768, PrimeField};
     use num_bigint::BigUint;
-    use poseidon_parameters::v1::{Alpha, PoseidonParameters};
+    use poseidon_parameters::v1::Alpha;
 
     use crate::{input::InputParameters, rounds, v1::generate};
 
@@ -81,7 +81,7 @@ mod tests {
                 cost: row[6],
             };
             let input = InputParameters::generate(table_row.M, table_row.t, table_row.p, true);
-            let rounds = rounds::v1_generate(&input, &alpha);
+            let rounds = rounds::v1_generate(&input, &alpha).unwrap();
 
             // For bits of security of 256 bits or less, v2 parameter generation should be unchanged.
             if table_row.M <= 256 {
@@ -142,7 +142,7 @@ mod tests {
 
             let input: InputParameters<ark_ff::BigInt<12>> =
                 InputParameters::generate(table_row.M, table_row.t, table_row.p, true);
-            let rounds = rounds::v1_generate(&input, &alpha);
+            let rounds = rounds::v1_generate(&input, &alpha).unwrap();
 
             // For bits of security of 256 bits or less, v2 parameter generation should be unchanged.
             if table_row.M <= 256 {
@@ -201,7 +201,7 @@ mod tests {
                 cost: row[6],
             };
             let input = InputParameters::generate(table_row.M, table_row.t, table_row.p, true);
-            let rounds = rounds::v1_generate(&input, &alpha);
+            let rounds = rounds::v1_generate(&input, &alpha).unwrap();
             assert_eq!(rounds.full(), table_row.r_F);
             assert_eq!(rounds.partial(), table_row.r_P);
         }
@@ -213,37 +213,42 @@ mod tests {
 
         // $t=2$ corresponds to a 1:1 hash
         let input = InputParameters::generate(128, 2, Fq::MODULUS, true);
-        let _rounds = rounds::v1_generate(&input, &alpha);
-        // Calling PoseidonParameters::new runs a bunch of assertions to ensure the optimized matrices
-        // have been property constructed.
-        let _params_1_to_11: PoseidonParameters<Fq> = generate(128, 2, Fq::MODULUS, true);
+        let _rounds = rounds::v1_generate(&input, &alpha).unwrap();
+        // Generating the parameters checks that the optimized matrices can be computed
+        // and have the dimensions of the type.
+        let _params_1_to_11 =
+            generate::<Fq, 2, 1, 4, 1, 39, 2, 78, 31>(128, 2, Fq::MODULUS, true).unwrap();
 
         // $t=3$ corresponds to a 2:1 hash
         let input = InputParameters::generate(128, 3, Fq::MODULUS, true);
-        let rounds = rounds::v1_generate(&input, &alpha);
+        let rounds = rounds::v1_generate(&input, &alpha).unwrap();
         assert_eq!(rounds.full(), 8);
         assert_eq!(rounds.partial(), 31);
-        let _params_2_to_1: PoseidonParameters<Fq> = generate(128, 3, Fq::MODULUS, true);
+        let _params_2_to_1 =
+            generate::<Fq, 3, 2, 9, 4, 39, 3, 117, 31>(128, 3, Fq::MODULUS, true).unwrap();
 
         // $t=4$ corresponds to a 3:1 hash
         let input = InputParameters::generate(128, 4, Fq::MODULUS, true);
-        let rounds = rounds::v1_generate(&input, &alpha);
+        let rounds = rounds::v1_generate(&input, &alpha).unwrap();
         assert_eq!(rounds.full(), 8);
         assert_eq!(rounds.partial(), 31);
-        let _params_3_to_1: PoseidonParameters<Fq> = generate(128, 4, Fq::MODULUS, true);
+        let _params_3_to_1 =
+            generate::<Fq, 4, 3, 16, 9, 39, 4, 156, 31>(128, 4, Fq::MODULUS, true).unwrap();
 
         // $t=5$ corresponds to a 4:1 hash
         let input = InputParameters::generate(128, 5, Fq::MODULUS, true);
-        let rounds = rounds::v1_generate(&input, &alpha);
+        let rounds = rounds::v1_generate(&input, &alpha).unwrap();
         assert_eq!(rounds.full(), 8);
         assert_eq!(rounds.partial(), 31);
-        let _params_4_to_1: PoseidonParameters<Fq> = generate(128, 5, Fq::MODULUS, true);
+        let _params_4_to_1 =
+            generate::<Fq, 5, 4, 25, 16, 39, 5, 195, 31>(128, 5, Fq::MODULUS, true).unwrap();
 
         // $t=6$ corresponds to a 5:1 hash
         let input = InputParameters::generate(128, 6, Fq::MODULUS, true);
-        let rounds = rounds::v1_generate(&input, &alpha);
+        let rounds = rounds::v1_generate(&input, &alpha).unwrap();
         assert_eq!(rounds.full(), 8);
         assert_eq!(rounds.partial(), 31);
-        let _params_5_to_1: PoseidonParameters<Fq> = generate(128, 6, Fq::MODULUS, true);
+        let _params_5_to_1 =
+            generate::<Fq, 6, 5, 36, 25, 39, 6, 234, 31>(128, 6, Fq::MODULUS, true).unwrap();
     }
 }
@@ -0,0 +1,220 @@
+use std::fmt::Write;
+
+use ark_ff::PrimeField;
+use ark_std::vec::Vec;
+
+use poseidon_parameters::v1::{Alpha, PoseidonParameterError};
+
+use crate::{field, input::InputParameters, utils::serialize_rows, v1::Derivation};
+
+/// Create a Circom library implementing the v1 permutation for each width in `t_values`.
+///
+/// For each rate the library contains a `Poseidon377Permutation{rate}` template over the
+/// full state, and a `Poseidon377Hash{rate}` template which takes a domain separator and
+/// `rate` inputs and outputs the same element as the native fixed-width hash. The
+/// permutation is the unoptimized one, using the round constants and MDS matrix exactly
+/// as generated. The circuit must be compiled for the field `p` was chosen from, e.g.
+/// with `circom --prime bls12377` for decaf377.
+///
+/// This fails as [`field::generate`](crate::v1::field::generate) does.
+pub fn v1_compile<F: PrimeField>(
+    M: usize,
+    t_values: Vec<usize>,
+    p: F::BigInt,
+    allow_inverse: bool,
+) -> Result<String, PoseidonParameterError> {
+    let mut circom_code = "pragma circom 2.1.0;\n".to_string();
+
+    for t in t_values {
+        let input = InputParameters::generate(M, t, p, allow_inverse);
+        let instance = field::v1_generate_from_input::<F>(&input, Derivation::Transcript)?;
+
+        write_instance(
+            &mut circom_code,
+            t,
+            instance.rounds.full(),
+            instance.rounds.partial(),
+            instance.alpha,
+            &instance.arc,
+            &instance.mds,
+        )
+        .expect("writing to a string cannot fail");
+    }
+
+    Ok(circom_code)
+}
+
+fn write_instance<F: PrimeField>(
+    out: &mut String,
+    t: usize,
+    r_F: usize,
+    r_P: usize,
+    alpha: Alpha,
+    round_constants: &[Vec<F>],
+    mds: &[Vec<F>],
+) -> std::fmt::Result {
+    let capacity = 1;
+    let rate = t - capacity;
+    let num_rounds = r_F + r_P;
+    let num_sboxes = r_F * t + r_P;
+
+    write!(
+        out,
+        r"
+// Parameters for the rate-{rate} instance of Poseidon.
+function POSEIDON377_C_{rate}() {{
+    return {};
+}}
+
+function POSEIDON377_M_{rate}() {{
+    return {};
+}}
+",
+        serialize_rows(round_constants),
+        serialize_rows(mds),
+    )?;
+
+    write_sbox(out, rate, alpha)?;
+
+    write!(
+        out,
+        r"
+template Poseidon377Permutation{rate}() {{
+    signal input in[{t}];
+    signal output out[{t}];
+
+    var C[{num_rounds}][{t}] = POSEIDON377_C_{rate}();
+    var M[{t}][{t}] = POSEIDON377_M_{rate}();
+
+    component sbox[{num_sboxes}];
+    var state[{t}];
+    for (var i = 0; i < {t}; i++) {{
+        state[i] = in[i];
+    }}
+
+    var k = 0;
+    for (var r = 0; r < {num_rounds}; r++) {{
+        for (var i = 0; i < {t}; i++) {{
+            state[i] += C[r][i];
+        }}
+
+        // Full rounds apply the S-box to every element, partial rounds to the first.
+        var width = 1;
+        if (r < {half_F} || r >= {half_F} + {r_P}) {{
+            width = {t};
+        }}
+        for (var i = 0; i < width; i++) {{
+            sbox[k] = Poseidon377Sbox{rate}();
+            sbox[k].in <== state[i];
+            state[i] = sbox[k].out;
+            k++;
+        }}
+
+        var mixed[{t}];
+        for (var i = 0; i < {t}; i++) {{
+            mixed[i] = 0;
+            for (var j = 0; j < {t}; j++) {{
+                mixed[i] += M[i][j] * state[j];
+            }}
+        }}
+        for (var i = 0; i < {t}; i++) {{
+            state[i] = mixed[i];
+        }}
+    }}
+
+    for (var i = 0; i < {t}; i++) {{
+        out[i] <== state[i];
+    }}
+}}
+
+template Poseidon377Hash{rate}() {{
+    signal input domain_separator;
+    signal input in[{rate}];
+    signal output out;
+
+    component permutation = Poseidon377Permutation{rate}();
+    permutation.in[0] <== domain_separator;
+    for (var i = 0; i < {rate}; i++) {{
+        permutation.in[i + 1] <== in[i];
+    }}
+    out <== permutation.out[1];
+}}
+",
+        half_F = r_F / 2,
+    )
+}
+
+/// Emit the S-box template, evaluating `x^alpha` with one multiplication per signal,
+/// or `1/x` (mapping zero to zero) for the inverse S-box.
+fn write_sbox(out: &mut String, rate: usize, alpha: Alpha) -> std::fmt::Result {
+    write!(
+        out,
+        r"
+template Poseidon377Sbox{rate}() {{
+    signal input in;
+    signal output out;
+"
+    )?;
+
+    match alpha {
+        Alpha::Exponent(exp) => {
+            // Left-to-right binary exponentiation: square, then multiply by `in`
+            // for each set bit below the leading one.
+            let bits = 32 - exp.leading_zeros();
+            let mut acc = "in".to_string();
+            let mut step = 0;
+            for bit in (0..bits.saturating_sub(1)).rev() {
+                writeln!(out, "    signal s{step} <== {acc} * {acc};")?;
+                acc = format!("s{step}");
+                step += 1;
+                if (exp >> bit) & 1 == 1 {
+                    writeln!(out, "    signal s{step} <== {acc} * in;")?;
+                    acc = format!("s{step}");
+                    step += 1;
+                }
+            }
+            writeln!(out, "    out <== {acc};")?;
+        }
+        Alpha::Inverse => {
+            // `product` is 1 when `in` is nonzero, which forces `out = 1/in`, and 0 when
+            // `in` is zero, which forces `out = 0`.
+            out.push_str(
+                r"    out <-- in != 0 ? 1 / in : 0;
+    signal product <== in * out;
+    in * (product - 1) === 0;
+    out * (product - 1) === 0;
+",
+            );
+        }
+    }
+
+    out.push_str("}\n");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_ed_on_bls12_377::Fq;
+
+    #[test]
+    fn circom_templates() {
+        let code = v1_compile::<Fq>(128, vec![3, 5], Fq::MODULUS, false).unwrap();
+        assert!(code.starts_with("pragma circom"));
+        for rate in [2, 4] {
+            assert!(code.contains(&format!("template Poseidon377Permutation{rate}()")));
+            assert!(code.contains(&format!("template Poseidon377Hash{rate}()")));
+        }
+
+        // The constants are those of the native instances, in decimal.
+        assert!(code.contains(
+            "308026635595114235070436728341841505234226384644787941764356225291780075012"
+        ));
+
+        // x^17 is four squarings and one multiplication.
+        assert!(
+            code.contains("signal s3 <== s2 * s2;\n    signal s4 <== s3 * in;\n    out <== s4;")
+        );
+    }
+}
@@ -0,0 +1,300 @@
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::vec::Vec;
+use decaf377::Fq;
+use num_bigint::BigUint;
+use poseidon_parameters::v1::{
+    Alpha, DynMatrix, DynPoseidonParameters, PoseidonParameterError, RoundNumbers,
+};
+
+use crate::{
+    alpha, grain, input::InputParameters, mds, round_constants, rounds, utils::fq_modulus,
+    v1::Derivation,
+};
+
+/// The constants of a Poseidon instance over the prime field `F`, as packaged by the
+/// field modules of `poseidon377`.
+///
+/// These are the unoptimized constants: one row of `t` round constants per round, and
+/// the MDS matrix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldInstance<F: PrimeField> {
+    /// Security level in bits.
+    pub M: usize,
+    /// Width of the hash function.
+    pub t: usize,
+    /// The S-box of the instance.
+    pub alpha: Alpha,
+    /// Number of full and partial rounds.
+    pub rounds: RoundNumbers,
+    /// Round constants, one row per round.
+    pub arc: Vec<Vec<F>>,
+    /// MDS matrix, in rows.
+    pub mds: Vec<Vec<F>>,
+}
+
+/// Generate the constants of the instance of width `t` over `F` for a security level
+/// of `M` bits.
+///
+/// The S-box, round numbers, round constants and MDS matrix are chosen and derived
+/// as for the instances over decaf377's `Fq`, with [`Derivation::Transcript`], so the
+/// constants over `Fq` are the same as those of [`generate`](crate::v1::generate).
+///
+/// This fails with [`PoseidonParameterError::InvalidAlpha`] if there is no S-box for
+/// `F`, or with [`PoseidonParameterError::UnsupportedSecurityLevel`] if `M` is beyond
+/// 256 bits.
+///
+/// [`Derivation::Transcript`]: crate::v1::Derivation::Transcript
+pub fn v1_generate<F: PrimeField>(
+    M: usize,
+    t: usize,
+    allow_inverse: bool,
+) -> Result<FieldInstance<F>, PoseidonParameterError> {
+    let input = InputParameters::generate(M, t, F::MODULUS, allow_inverse);
+    v1_generate_from_input(&input, Derivation::Transcript)
+}
+
+/// Generate the constants of the instance for `input` over `F`, deriving the round
+/// constants and the MDS matrix with `derivation`. This fails as [`v1_generate`] does.
+pub(crate) fn v1_generate_from_input<F: PrimeField>(
+    input: &InputParameters<F::BigInt>,
+    derivation: Derivation,
+) -> Result<FieldInstance<F>, PoseidonParameterError> {
+    let alpha = alpha::generate::<F>(input.p, input.allow_inverse)?;
+    let rounds = rounds::v1_generate(input, &alpha)?;
+    let (arc, mds) = match derivation {
+        Derivation::Transcript => (
+            round_constants::v1_generate(input, rounds, alpha),
+            mds::v1_generate(input),
+        ),
+        Derivation::GrainLfsr => grain::v1_generate(input, rounds, alpha),
+    };
+
+    Ok(FieldInstance {
+        M: input.M,
+        t: input.t,
+        alpha,
+        rounds,
+        arc,
+        mds,
+    })
+}
+
+impl<F: PrimeField> FieldInstance<F> {
+    /// Convert the instance to [`DynPoseidonParameters`], precomputing its optimized
+    /// constants, or return [`PoseidonParameterError::FieldMismatch`] if `F` is not
+    /// decaf377's `Fq`, the field of the parameters of `poseidon-parameters`.
+    pub fn to_dyn_parameters(&self) -> Result<DynPoseidonParameters, PoseidonParameterError> {
+        let to_matrix =
+            |rows: &[Vec<F>]| DynMatrix::try_new(rows.len(), self.t, to_fq(rows.iter().flatten())?);
+        DynPoseidonParameters::from_unoptimized(
+            self.M,
+            self.alpha,
+            self.rounds,
+            to_matrix(&self.mds)?,
+            to_matrix(&self.arc)?,
+        )
+    }
+}
+
+/// Convert `elements` of `F` to decaf377's `Fq`, or return
+/// [`PoseidonParameterError::FieldMismatch`] if `F` is a different field.
+pub(crate) fn to_fq<'a, F: PrimeField>(
+    elements: impl IntoIterator<Item = &'a F>,
+) -> Result<Vec<Fq>, PoseidonParameterError> {
+    let modulus: BigUint = F::MODULUS.into();
+    if modulus != fq_modulus() {
+        return Err(PoseidonParameterError::FieldMismatch);
+    }
+
+    Ok(elements
+        .into_iter()
+        .map(|x| Fq::from_le_bytes_mod_order(&x.into_bigint().to_bytes_le()))
+        .collect())
+}
+
+/// Generate the constants of the instances of widths `t_values` over `F`, as Rust
+/// source for the field modules of `poseidon377`.
+///
+/// `field` is the name of the type of `F` in the generated source, which declares one
+/// `RATE_{t - 1}_PARAMS` constant of type `FieldParameters<field, t>` per width. This
+/// fails as [`v1_generate`] does.
+#[cfg(feature = "std")]
+pub fn v1_compile<F: PrimeField>(
+    M: usize,
+    t_values: Vec<usize>,
+    field: &str,
+    allow_inverse: bool,
+) -> Result<String, PoseidonParameterError> {
+    let mut code = format!(
+        "use ark_ff::MontFp;
+use poseidon_parameters::v1::{{Alpha, RoundNumbers}};
+
+use super::{field};
+use crate::field::FieldParameters;
+"
+    );
+
+    for t in t_values {
+        let instance = v1_generate::<F>(M, t, allow_inverse)?;
+        code.push_str(&display_instance(&instance, field));
+    }
+
+    Ok(code)
+}
+
+#[cfg(feature = "std")]
+fn display_instance<F: PrimeField>(instance: &FieldInstance<F>, field: &str) -> String {
+    let rate = instance.t - 1;
+    let t = instance.t;
+    let alpha = match instance.alpha {
+        Alpha::Exponent(exp) => format!("Alpha::Exponent({exp})"),
+        Alpha::Inverse => "Alpha::Inverse".to_string(),
+    };
+    let r_P = instance.rounds.partial();
+    let r_F = instance.rounds.full();
+
+    format!(
+        "
+/// Parameters for the rate-{rate} instance of Poseidon over [`{field}`].
+pub const RATE_{rate}_PARAMS: FieldParameters<{field}, {t}> = FieldParameters {{
+    M: {},
+    alpha: {alpha},
+    rounds: RoundNumbers {{ r_P: {r_P}, r_F: {r_F} }},
+    arc: &[{}],
+    mds: [{}],
+}};
+",
+        instance.M,
+        display_rows(&instance.arc),
+        display_rows(&instance.mds),
+    )
+}
+
+#[cfg(feature = "std")]
+fn display_rows<F: PrimeField>(rows: &[Vec<F>]) -> String {
+    let rows: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let elements: Vec<String> = row
+                .iter()
+                .map(|x| format!("MontFp!(\"{}\")", x.into_bigint()))
+                .collect();
+            format!("[{}]", elements.join(", "))
+        })
+        .collect();
+    rows.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use ark_bls12_377::Fq as Fp377;
+    use ark_bn254::Fr as Fr254;
+    use ark_ed_on_bls12_377::Fq as Fq377;
+    use ark_ed_on_bls12_381::Fq as Fr381;
+    use ark_pallas::{Fq as VestaFr, Fr as PallasFr};
+
+    use super::*;
+
+    #[test]
+    fn field_instance_over_fq_matches_v1() {
+        let instance = v1_generate::<Fq377>(128, 3, true).unwrap();
+        assert_eq!(instance.alpha, Alpha::Exponent(17));
+        assert_eq!(instance.rounds, RoundNumbers { r_P: 31, r_F: 8 });
+        assert_eq!(instance.arc.len(), 39);
+
+        // The first round constant of the rate-2 instance of `poseidon377`.
+        assert_eq!(
+            instance.arc[0][0],
+            Fq377::from_str(
+                "308026635595114235070436728341841505234226384644787941764356225291780075012"
+            )
+            .unwrap()
+        );
+        assert_eq!(instance.mds[0][0] * Fq377::from(3u64), Fq377::from(1u64));
+
+        let parameters = instance.to_dyn_parameters().unwrap();
+        assert_eq!(parameters.state_size(), 3);
+        assert_eq!(
+            parameters.arc.get_element(0, 0),
+            Fq::from_le_bytes_mod_order(&instance.arc[0][0].into_bigint().to_bytes_le())
+        );
+
+        assert_eq!(
+            v1_generate::<Fr254>(128, 3, true)
+                .unwrap()
+                .to_dyn_parameters(),
+            Err(PoseidonParameterError::FieldMismatch)
+        );
+        assert_eq!(
+            v1_generate::<Fq377>(512, 3, true),
+            Err(PoseidonParameterError::UnsupportedSecurityLevel)
+        );
+    }
+
+    #[test]
+    fn field_instance_over_bls12_377_base_field() {
+        let instance = v1_generate::<Fp377>(128, 2, true).unwrap();
+        assert_eq!(instance.alpha, Alpha::Exponent(5));
+        assert_eq!(instance.rounds, RoundNumbers { r_P: 56, r_F: 8 });
+        assert_eq!(
+            instance.arc[0][0],
+            Fp377::from_str("175705853156336778222642836125549430739280332316420110315608764160897834774585796527467528298984693495197737431588")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn field_instance_over_bn254_scalar_field() {
+        let instance = v1_generate::<Fr254>(128, 2, true).unwrap();
+        assert_eq!(instance.alpha, Alpha::Exponent(5));
+        assert_eq!(instance.rounds, RoundNumbers { r_P: 56, r_F: 8 });
+        assert_eq!(
+            instance.arc[0][0],
+            Fr254::from_str(
+                "1994900499411599099483382816386402366159714072789165987080698883950479295903"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn field_instance_over_bls12_381_scalar_field() {
+        let instance = v1_generate::<Fr381>(128, 2, true).unwrap();
+        assert_eq!(instance.alpha, Alpha::Exponent(5));
+        assert_eq!(instance.rounds, RoundNumbers { r_P: 56, r_F: 8 });
+        assert_eq!(
+            instance.arc[0][0],
+            Fr381::from_str(
+                "35174739893055911104493616029378130908017657834702731071195911003169112450229"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn field_instance_over_pasta_scalar_fields() {
+        let pallas = v1_generate::<PallasFr>(128, 2, true).unwrap();
+        assert_eq!(pallas.alpha, Alpha::Exponent(5));
+        assert_eq!(pallas.rounds, RoundNumbers { r_P: 56, r_F: 8 });
+        assert_eq!(
+            pallas.arc[0][0],
+            PallasFr::from_str(
+                "9194680729814864118993736450219925798428105912757975172379913547880479024951"
+            )
+            .unwrap()
+        );
+
+        let vesta = v1_generate::<VestaFr>(128, 2, true).unwrap();
+        assert_eq!(vesta.alpha, Alpha::Exponent(5));
+        assert_eq!(vesta.rounds, RoundNumbers { r_P: 56, r_F: 8 });
+        assert_eq!(
+            vesta.arc[0][0],
+            VestaFr::from_str(
+                "10492696468594742196941772386373219328717797116158260902961845027358696139313"
+            )
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,285 @@
+use ark_ff::PrimeField;
+use ark_std::{vec, vec::Vec};
+use num::Zero;
+use num_bigint::BigUint;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use crate::{input::InputParameters, utils::inverse};
+
+/// The Grain LFSR used as a pseudorandom bit source in Appendix F of the Poseidon paper.
+///
+/// This follows `generate_parameters_grain.sage` of the reference implementation, which
+/// is also used by circomlib and neptune.
+struct GrainLfsr {
+    state: [bool; 80],
+}
+
+/// The number of output bits discarded after initialization.
+pub(crate) const DISCARDED_BITS: usize = 160;
+
+/// The initial state of the LFSR for an instance, i.e. the field type (2 bits), the S-box
+/// (4 bits), the field size in bits (12 bits), t (12 bits), r_F (10 bits) and r_P (10
+/// bits), each most significant bit first, padded with ones.
+pub(crate) fn initial_state(
+    field_size: u32,
+    t: usize,
+    rounds: RoundNumbers,
+    alpha: Alpha,
+) -> [bool; 80] {
+    // The field is 1 for prime fields and the S-box is 0 for x^alpha, 1 for x^{-1}.
+    let sbox = match alpha {
+        Alpha::Exponent(_) => 0,
+        Alpha::Inverse => 1,
+    };
+    let fields = [
+        (1, 2),
+        (sbox, 4),
+        (field_size as usize, 12),
+        (t, 12),
+        (rounds.full(), 10),
+        (rounds.partial(), 10),
+    ];
+
+    let mut state = [true; 80];
+    let mut position = 0;
+    for (value, width) in fields.iter() {
+        for bit in (0..*width).rev() {
+            state[position] = (value >> bit) & 1 == 1;
+            position += 1;
+        }
+    }
+    state
+}
+
+impl GrainLfsr {
+    /// Initialize the LFSR with the description of the instance, and discard the first
+    /// [`DISCARDED_BITS`] bits.
+    fn new(field_size: u32, t: usize, rounds: RoundNumbers, alpha: Alpha) -> Self {
+        let mut lfsr = Self {
+            state: initial_state(field_size, t, rounds, alpha),
+        };
+        for _ in 0..DISCARDED_BITS {
+            lfsr.clock();
+        }
+        lfsr
+    }
+
+    /// Shift the register by one bit, returning the new bit.
+    fn clock(&mut self) -> bool {
+        let s = &self.state;
+        let new_bit = s[62] ^ s[51] ^ s[38] ^ s[23] ^ s[13] ^ s[0];
+        self.state.copy_within(1.., 0);
+        self.state[79] = new_bit;
+        new_bit
+    }
+
+    /// The next output bit, with the bits of the register taken in pairs and the second
+    /// bit output only if the first one is set.
+    fn next_bit(&mut self) -> bool {
+        loop {
+            let keep = self.clock();
+            let bit = self.clock();
+            if keep {
+                return bit;
+            }
+        }
+    }
+
+    /// The next `num_bits` output bits as a big-endian integer.
+    fn random_bits(&mut self, num_bits: usize) -> BigUint {
+        let mut bytes = vec![0u8; (num_bits + 7) / 8];
+        // Pad with leading zeros to a whole number of bytes.
+        for i in (bytes.len() * 8 - num_bits)..(bytes.len() * 8) {
+            if self.next_bit() {
+                bytes[i / 8] |= 0x80 >> (i % 8);
+            }
+        }
+        BigUint::from_bytes_be(&bytes)
+    }
+}
+
+/// Generate the round constants and the MDS matrix from the Grain LFSR, as the
+/// reference implementation of the Poseidon paper does.
+///
+/// Each round constant is sampled by rejection from the LFSR output. The MDS matrix is
+/// then the Cauchy matrix $1/(x_i + y_j)$ for the next $2t$ elements output, resampled
+/// until they are distinct and every $x_i + y_j$ is nonzero. Unlike the reference
+/// implementation, the matrix is not checked with Algorithms 1-3 of
+/// [Grassi, Rechberger, Schofnegger 2020](https://eprint.iacr.org/2020/500), so
+/// instances where the reference implementation resamples the matrix differ.
+pub fn v1_generate<F: PrimeField>(
+    input: &InputParameters<F::BigInt>,
+    round_numbers: RoundNumbers,
+    alpha: Alpha,
+) -> (Vec<Vec<F>>, Vec<Vec<F>>) {
+    let (arc, mds) = v1_generate_for_modulus(&input.p.into(), input.t, round_numbers, alpha);
+    let to_field = |rows: Vec<Vec<BigUint>>| -> Vec<Vec<F>> {
+        rows.into_iter()
+            .map(|row| row.into_iter().map(F::from).collect())
+            .collect()
+    };
+    (to_field(arc), to_field(mds))
+}
+
+/// Generate the round constants and the MDS matrix of width `t` from the Grain LFSR
+/// over the prime field of modulus `p`, as [`v1_generate`] does, as integers reduced
+/// modulo `p`.
+pub(crate) fn v1_generate_for_modulus(
+    p: &BigUint,
+    t: usize,
+    round_numbers: RoundNumbers,
+    alpha: Alpha,
+) -> (Vec<Vec<BigUint>>, Vec<Vec<BigUint>>) {
+    let field_size = p.bits() as u32;
+    let num_bits = field_size as usize;
+    let mut lfsr = GrainLfsr::new(field_size, t, round_numbers, alpha);
+
+    let arc = (0..round_numbers.total())
+        .map(|_| {
+            (0..t)
+                .map(|_| loop {
+                    let candidate = lfsr.random_bits(num_bits);
+                    if &candidate < p {
+                        break candidate;
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    let mds = loop {
+        // The Cauchy points are reduced rather than sampled by rejection.
+        let points: Vec<BigUint> = (0..2 * t).map(|_| lfsr.random_bits(num_bits) % p).collect();
+        let distinct = points
+            .iter()
+            .enumerate()
+            .all(|(i, x)| points[..i].iter().all(|y| x != y));
+        if !distinct {
+            continue;
+        }
+
+        let (xs, ys) = points.split_at(t);
+        let rows: Vec<Vec<BigUint>> = xs
+            .iter()
+            .map(|x| ys.iter().map(|y| (x + y) % p).collect())
+            .collect();
+        if rows.iter().flatten().any(|sum| sum.is_zero()) {
+            continue;
+        }
+        break rows
+            .into_iter()
+            .map(|row| row.iter().map(|sum| inverse(sum, p)).collect())
+            .collect();
+    };
+
+    (arc, mds)
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::Fr;
+    use ark_ff::MontFp;
+
+    use super::*;
+
+    #[test]
+    fn circomlib_bn254_constants() {
+        // circomlib uses the reference implementation for the x^5 instance with t = 3,
+        // r_F = 8 and r_P = 57 over the scalar field of BN254. The expected constants
+        // are those of `poseidon_constants.js` in circomlib, `C[1]` and `M[1]`.
+        let input = InputParameters::generate(128, 3, Fr::MODULUS, false);
+        let (arc, mds) =
+            v1_generate::<Fr>(&input, RoundNumbers { r_F: 8, r_P: 57 }, Alpha::Exponent(5));
+
+        assert_eq!(arc.len(), 65);
+        assert!(arc.iter().all(|row| row.len() == 3));
+        let expected_arc: [Fr; 3] = [
+            MontFp!("6745197990210204598374042828761989596302876299545964402857411729872131034734"),
+            MontFp!("426281677759936592021316809065178817848084678679510574715894138690250139748"),
+            MontFp!("4014188762916583598888942667424965430287497824629657219807941460227372577781"),
+        ];
+        assert_eq!(arc[0][..], expected_arc);
+        let expected_last: Fr = MontFp!(
+            "13409242754315411433193860530743374419854094495153957441316635981078068351329"
+        );
+        assert_eq!(arc[64][2], expected_last);
+
+        let expected_mds: [[Fr; 3]; 3] = [
+            [
+                MontFp!(
+                    "7511745149465107256748700652201246547602992235352608707588321460060273774987"
+                ),
+                MontFp!(
+                    "10370080108974718697676803824769673834027675643658433702224577712625900127200"
+                ),
+                MontFp!(
+                    "19705173408229649878903981084052839426532978878058043055305024233888854471533"
+                ),
+            ],
+            [
+                MontFp!(
+                    "18732019378264290557468133440468564866454307626475683536618613112504878618481"
+                ),
+                MontFp!(
+                    "20870176810702568768751421378473869562658540583882454726129544628203806653987"
+                ),
+                MontFp!(
+                    "7266061498423634438633389053804536045105766754026813321943009179476902321146"
+                ),
+            ],
+            [
+                MontFp!(
+                    "9131299761947733513298312097611845208338517739621853568979632113419485819303"
+                ),
+                MontFp!(
+                    "10595341252162738537912664445405114076324478519622938027420701542910180337937"
+                ),
+                MontFp!(
+                    "11597556804922396090267472882856054602429588299176362916247939723151043581408"
+                ),
+            ],
+        ];
+        for (row, expected_row) in mds.iter().zip(expected_mds.iter()) {
+            assert_eq!(row[..], expected_row[..]);
+        }
+    }
+
+    #[test]
+    fn circomlib_bn254_constants_t_equals_2() {
+        // The instance with t = 2 has r_P = 56, `C[0]` and `M[0]` in circomlib.
+        let input = InputParameters::generate(128, 2, Fr::MODULUS, false);
+        let (arc, mds) =
+            v1_generate::<Fr>(&input, RoundNumbers { r_F: 8, r_P: 56 }, Alpha::Exponent(5));
+
+        assert_eq!(arc.len(), 64);
+        let expected_first: Fr =
+            MontFp!("4417881134626180770308697923359573201005643519861877412381846989312604493735");
+        let expected_last: Fr = MontFp!(
+            "17467570179597572575614276429760169990940929887711661192333523245667228809456"
+        );
+        assert_eq!(arc[0][0], expected_first);
+        assert_eq!(arc[63][1], expected_last);
+
+        let expected_mds: [[Fr; 2]; 2] = [
+            [
+                MontFp!(
+                    "2910766817845651019878574839501801340070030115151021261302834310722729507541"
+                ),
+                MontFp!(
+                    "19727366863391167538122140361473584127147630672623100827934084310230022599144"
+                ),
+            ],
+            [
+                MontFp!(
+                    "5776684794125549462448597414050232243778680302179439492664047328281728356345"
+                ),
+                MontFp!(
+                    "8348174920934122550483593999453880006756108121341067172388445916328941978568"
+                ),
+            ],
+        ];
+        for (row, expected_row) in mds.iter().zip(expected_mds.iter()) {
+            assert_eq!(row[..], expected_row[..]);
+        }
+    }
+}
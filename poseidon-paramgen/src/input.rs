@@ -1,10 +1,12 @@
 use ark_ff::BigInteger;
+use num_bigint::BigUint;
+use poseidon_parameters::v1::PoseidonParameterError;
 
-use crate::log2;
+use crate::{log2, utils::log2_biguint};
 
 /// Input parameters that are used to generate Poseidon parameters.
 #[derive(Clone, Debug)]
-pub struct InputParameters<T: BigInteger> {
+pub struct InputParameters<T> {
     /// Whether or not to allow inverse alpha.
     pub allow_inverse: bool,
 
@@ -17,6 +19,9 @@ pub struct InputParameters<T: BigInteger> {
     /// Modulus of the prime field.
     pub p: T, // let modulus = <F as PrimeField>::Params::MODULUS;
 
+    /// Security margin added to the round numbers required by known attacks.
+    pub security_margin: SecurityMargin,
+
     // The below are derived values, stored for convenience.
     /// log_2(p)
     pub log_2_p: f64,
@@ -32,6 +37,124 @@ impl<T: BigInteger> InputParameters<T> {
             p,
             log_2_p,
             allow_inverse,
+            security_margin: SecurityMargin::default(),
+        }
+    }
+}
+
+impl InputParameters<BigUint> {
+    /// Create a new set of input parameters for a Poseidon instance over the prime
+    /// field of the modulus `p`, which is chosen at runtime.
+    pub fn for_modulus(M: usize, t: usize, p: BigUint, allow_inverse: bool) -> Self {
+        let log_2_p = log2_biguint(p.clone());
+        InputParameters {
+            M,
+            t,
+            p,
+            log_2_p,
+            allow_inverse,
+            security_margin: SecurityMargin::default(),
+        }
+    }
+}
+
+impl<T> InputParameters<T> {
+    /// Use `security_margin` instead of the margin suggested by the Poseidon paper.
+    ///
+    /// Fails with [`PoseidonParameterError::InvalidSecurityMargin`] if the margin is not
+    /// one [`SecurityMargin::new`] accepts.
+    pub fn with_security_margin(
+        mut self,
+        security_margin: SecurityMargin,
+    ) -> Result<Self, PoseidonParameterError> {
+        self.security_margin = SecurityMargin::new(
+            security_margin.full_rounds,
+            security_margin.partial_rounds_factor,
+        )?;
+        Ok(self)
+    }
+}
+
+/// The rounds added to the minimum number of rounds that defend against known attacks.
+///
+/// The default is the margin suggested in Section 5.4 of the Poseidon paper, i.e. two
+/// more full rounds and 7.5% more partial rounds. Conservative deployments can add more
+/// rounds, at the cost of a slower permutation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SecurityMargin {
+    /// Number of full rounds added.
+    full_rounds: usize,
+
+    /// Factor the number of partial rounds is multiplied by, rounding up.
+    partial_rounds_factor: f64,
+}
+
+impl SecurityMargin {
+    /// A margin of `full_rounds` more full rounds, and the partial rounds multiplied by
+    /// `partial_rounds_factor`, rounding up.
+    ///
+    /// A margin only adds rounds, and the full rounds are split evenly between the start
+    /// and the end of the permutation, so this fails with
+    /// [`PoseidonParameterError::InvalidSecurityMargin`] if `full_rounds` is odd or
+    /// `partial_rounds_factor` is not a finite number of at least 1.
+    pub fn new(
+        full_rounds: usize,
+        partial_rounds_factor: f64,
+    ) -> Result<Self, PoseidonParameterError> {
+        if full_rounds % 2 != 0 || !partial_rounds_factor.is_finite() || partial_rounds_factor < 1.0
+        {
+            return Err(PoseidonParameterError::InvalidSecurityMargin);
+        }
+
+        Ok(Self {
+            full_rounds,
+            partial_rounds_factor,
+        })
+    }
+
+    /// Number of full rounds added.
+    pub fn full_rounds(&self) -> usize {
+        self.full_rounds
+    }
+
+    /// Factor the number of partial rounds is multiplied by, rounding up.
+    pub fn partial_rounds_factor(&self) -> f64 {
+        self.partial_rounds_factor
+    }
+}
+
+impl Default for SecurityMargin {
+    fn default() -> Self {
+        Self {
+            full_rounds: 2,
+            partial_rounds_factor: 1.075,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn security_margin_only_adds_rounds() {
+        let margin = SecurityMargin::new(4, 1.5).unwrap();
+        assert_eq!(margin.full_rounds(), 4);
+        assert_eq!(margin.partial_rounds_factor(), 1.5);
+        assert_eq!(SecurityMargin::new(2, 1.075), Ok(SecurityMargin::default()));
+        assert!(SecurityMargin::new(0, 1.0).is_ok());
+
+        for (full_rounds, partial_rounds_factor) in [
+            (3, 1.075),
+            (2, 0.9),
+            (2, f64::NAN),
+            (2, f64::INFINITY),
+            (2, -1.0),
+        ] {
+            assert_eq!(
+                SecurityMargin::new(full_rounds, partial_rounds_factor),
+                Err(PoseidonParameterError::InvalidSecurityMargin)
+            );
         }
     }
 }
@@ -27,10 +27,22 @@ pub mod v2;
 
 mod alpha;
 mod appendix_g;
+#[cfg(feature = "std")]
+mod circom;
+mod field;
+mod grain;
 mod input;
 mod mds;
+#[cfg(feature = "std")]
+mod modulus;
+#[cfg(feature = "std")]
+mod noir;
+#[cfg(feature = "std")]
+mod provenance;
 mod round_constants;
 mod rounds;
+#[cfg(feature = "std")]
+mod sage;
 mod transcript;
 mod utils;
 
@@ -38,4 +50,5 @@ mod utils;
 #[cfg(feature = "std")]
 mod poseidon_build;
 
+pub use input::{InputParameters, SecurityMargin};
 use utils::log2;
@@ -0,0 +1,576 @@
+#![allow(non_snake_case)]
+//! Command-line interface for generating and checking Poseidon parameters.
+//!
+//! * `generate` derives the v1 instance over the field of a prime modulus and writes it
+//!   as JSON, optionally with a record of how it was derived,
+//! * `inspect` summarizes a JSON parameter file,
+//! * `validate` re-derives the instance a JSON parameter file claims to be, with the
+//!   security margin and derivation recorded in the file, and checks that its constants
+//!   are the ones it contains.
+
+use std::{
+    convert::TryFrom,
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use anyhow::{anyhow, bail, Context};
+use clap::{Parser, Subcommand};
+use num_bigint::BigUint;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+use poseidon_paramgen::{
+    v1::{
+        modulus::{self, ModulusInstance},
+        provenance, Derivation,
+    },
+    InputParameters, SecurityMargin,
+};
+use serde_json::{json, Value};
+
+/// The version of the format of the parameter files.
+const FORMAT_VERSION: &str = "poseidon-paramgen/v1";
+
+#[derive(Parser)]
+#[command(name = "poseidon-paramgen", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate the v1 instance over the field of a prime modulus.
+    Generate {
+        /// The prime modulus, in decimal or as hex prefixed with `0x`.
+        #[arg(long)]
+        modulus: String,
+        /// The width of the permutation, e.g. 3 for a 2:1 hash.
+        #[arg(long)]
+        t: usize,
+        /// The S-box exponent, or `inverse`. Defaults to the smallest valid exponent.
+        #[arg(long)]
+        alpha: Option<String>,
+        /// Whether to choose the inverse S-box if no exponent is valid.
+        #[arg(long)]
+        allow_inverse: bool,
+        /// The security level, in bits.
+        #[arg(long, default_value_t = 128)]
+        security: usize,
+        /// The number of full rounds added to the minimum, which must be even.
+        #[arg(long, default_value_t = SecurityMargin::default().full_rounds())]
+        margin_full_rounds: usize,
+        /// The factor the minimum number of partial rounds is multiplied by, at least 1.
+        #[arg(long, default_value_t = SecurityMargin::default().partial_rounds_factor())]
+        margin_partial_rounds_factor: f64,
+        /// How the constants are derived: `transcript`, or `grain-lfsr` for the constants
+        /// of the reference implementation of the Poseidon paper.
+        #[arg(long, default_value = "transcript")]
+        derivation: String,
+        /// The file to write the parameters to, instead of standard output.
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// The file to write the record of how the parameters were derived to.
+        #[arg(long)]
+        provenance: Option<PathBuf>,
+    },
+    /// Summarize a parameter file.
+    Inspect {
+        /// The parameter file.
+        file: PathBuf,
+    },
+    /// Check that a parameter file contains the constants of the instance it describes.
+    Validate {
+        /// The parameter file.
+        file: PathBuf,
+    },
+}
+
+fn main() -> anyhow::Result<ExitCode> {
+    match Cli::parse().command {
+        Command::Generate {
+            modulus,
+            t,
+            alpha,
+            allow_inverse,
+            security,
+            margin_full_rounds,
+            margin_partial_rounds_factor,
+            derivation,
+            out,
+            provenance: provenance_out,
+        } => {
+            let p = parse_modulus(&modulus)?;
+            let derivation = parse_derivation(&derivation)?;
+            let alpha = match alpha {
+                Some(alpha) => parse_alpha(&alpha)?,
+                None => modulus::alpha(&p, allow_inverse)?,
+            };
+            let security_margin =
+                SecurityMargin::new(margin_full_rounds, margin_partial_rounds_factor)?;
+            let input = InputParameters::for_modulus(security, t, p, alpha == Alpha::Inverse)
+                .with_security_margin(security_margin)?;
+            let instance = modulus::generate_from_input(&input, alpha, derivation)?;
+            if let Some(path) = provenance_out {
+                let record = provenance::record_for_input(&input, alpha, derivation)?;
+                fs::write(&path, record.to_json())
+                    .with_context(|| format!("writing {}", path.display()))?;
+            }
+            let file = ParameterFile {
+                instance,
+                security_margin,
+                derivation,
+            };
+            let json = serde_json::to_string_pretty(&to_json(&file))?;
+            match out {
+                Some(path) => fs::write(&path, json + "\n")
+                    .with_context(|| format!("writing {}", path.display()))?,
+                None => println!("{json}"),
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Inspect { file } => {
+            print!("{}", summary(&read(&file)?));
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Validate { file } => {
+            let parameters = read(&file)?;
+            let expected = regenerate(&parameters)?;
+            match mismatch(&parameters.instance, &expected) {
+                None => {
+                    println!("{}: ok", file.display());
+                    Ok(ExitCode::SUCCESS)
+                }
+                Some(mismatch) => {
+                    println!("{}: {mismatch}", file.display());
+                    Ok(ExitCode::FAILURE)
+                }
+            }
+        }
+    }
+}
+
+/// Parse a modulus in decimal, or in hex prefixed with `0x`.
+fn parse_modulus(s: &str) -> anyhow::Result<BigUint> {
+    let s = s.trim();
+    let parsed = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => BigUint::parse_bytes(hex.as_bytes(), 16),
+        None => BigUint::parse_bytes(s.as_bytes(), 10),
+    };
+    parsed.ok_or_else(|| anyhow!("invalid modulus {s:?}"))
+}
+
+/// Parse an S-box exponent, or `inverse`.
+fn parse_alpha(s: &str) -> anyhow::Result<Alpha> {
+    if s == "inverse" {
+        return Ok(Alpha::Inverse);
+    }
+    s.parse()
+        .map(Alpha::Exponent)
+        .map_err(|_| anyhow!("invalid alpha {s:?}, expected an exponent or `inverse`"))
+}
+
+/// The name of a derivation, on the command line and in parameter files.
+fn derivation_name(derivation: Derivation) -> &'static str {
+    match derivation {
+        Derivation::Transcript => "transcript",
+        Derivation::GrainLfsr => "grain-lfsr",
+    }
+}
+
+/// Parse a derivation by its name.
+fn parse_derivation(s: &str) -> anyhow::Result<Derivation> {
+    [Derivation::Transcript, Derivation::GrainLfsr]
+        .iter()
+        .copied()
+        .find(|derivation| derivation_name(*derivation) == s)
+        .ok_or_else(|| anyhow!("invalid derivation {s:?}, expected `transcript` or `grain-lfsr`"))
+}
+
+fn alpha_to_json(alpha: Alpha) -> Value {
+    match alpha {
+        Alpha::Exponent(exp) => json!(exp),
+        Alpha::Inverse => json!("inverse"),
+    }
+}
+
+fn rows_to_json(rows: &[Vec<BigUint>]) -> Value {
+    rows.iter()
+        .map(|row| row.iter().map(|x| x.to_string()).collect::<Vec<_>>())
+        .collect()
+}
+
+/// The contents of a parameter file: the instance, and the options it was generated
+/// with, which are needed to re-derive it.
+#[derive(Clone, Debug, PartialEq)]
+struct ParameterFile {
+    instance: ModulusInstance,
+    security_margin: SecurityMargin,
+    derivation: Derivation,
+}
+
+/// Re-derive the instance `parameters` describes, with the options it records.
+fn regenerate(parameters: &ParameterFile) -> anyhow::Result<ModulusInstance> {
+    let instance = &parameters.instance;
+    let input = InputParameters::for_modulus(
+        instance.M,
+        instance.t,
+        instance.p.clone(),
+        instance.alpha == Alpha::Inverse,
+    )
+    .with_security_margin(parameters.security_margin)?;
+    Ok(modulus::generate_from_input(
+        &input,
+        instance.alpha,
+        parameters.derivation,
+    )?)
+}
+
+/// The JSON parameter file of `parameters`, with field elements as decimal strings.
+fn to_json(parameters: &ParameterFile) -> Value {
+    let instance = &parameters.instance;
+    let margin = parameters.security_margin;
+    json!({
+        "format": FORMAT_VERSION,
+        "modulus": instance.p.to_string(),
+        "security": instance.M,
+        "t": instance.t,
+        "alpha": alpha_to_json(instance.alpha),
+        "rounds": {
+            "full": instance.rounds.full(),
+            "partial": instance.rounds.partial(),
+        },
+        "security_margin": {
+            "full_rounds": margin.full_rounds(),
+            "partial_rounds_factor": margin.partial_rounds_factor(),
+        },
+        "derivation": derivation_name(parameters.derivation),
+        "arc": rows_to_json(&instance.arc),
+        "mds": rows_to_json(&instance.mds),
+    })
+}
+
+fn field<'a>(json: &'a Value, name: &str) -> anyhow::Result<&'a Value> {
+    json.get(name)
+        .ok_or_else(|| anyhow!("missing field {name:?}"))
+}
+
+fn usize_field(json: &Value, name: &str) -> anyhow::Result<usize> {
+    field(json, name)?
+        .as_u64()
+        .map(|x| x as usize)
+        .ok_or_else(|| anyhow!("field {name:?} is not an integer"))
+}
+
+fn element(json: &Value) -> anyhow::Result<BigUint> {
+    json.as_str()
+        .and_then(|s| BigUint::parse_bytes(s.as_bytes(), 10))
+        .ok_or_else(|| anyhow!("invalid field element {json}"))
+}
+
+fn rows_field(json: &Value, name: &str) -> anyhow::Result<Vec<Vec<BigUint>>> {
+    let rows = field(json, name)?
+        .as_array()
+        .ok_or_else(|| anyhow!("field {name:?} is not an array of rows"))?;
+    rows.iter()
+        .map(|row| {
+            row.as_array()
+                .ok_or_else(|| anyhow!("field {name:?} is not an array of rows"))?
+                .iter()
+                .map(element)
+                .collect()
+        })
+        .collect()
+}
+
+/// Parse the security margin of a parameter file. Files written before the margin was
+/// recorded were generated with the default margin.
+fn security_margin(json: &Value) -> anyhow::Result<SecurityMargin> {
+    let margin = match json.get("security_margin") {
+        Some(margin) => margin,
+        None => return Ok(SecurityMargin::default()),
+    };
+    let partial_rounds_factor = field(margin, "partial_rounds_factor")?
+        .as_f64()
+        .ok_or_else(|| anyhow!("field \"partial_rounds_factor\" is not a number"))?;
+    SecurityMargin::new(usize_field(margin, "full_rounds")?, partial_rounds_factor)
+        .context("invalid security margin")
+}
+
+/// Parse the derivation of a parameter file. Files written before the derivation was
+/// recorded were derived from a transcript.
+fn derivation(json: &Value) -> anyhow::Result<Derivation> {
+    match json.get("derivation") {
+        Some(derivation) => parse_derivation(
+            derivation
+                .as_str()
+                .ok_or_else(|| anyhow!("field \"derivation\" is not a string"))?,
+        ),
+        None => Ok(Derivation::Transcript),
+    }
+}
+
+/// Parse a JSON parameter file, as written by [`to_json`].
+fn from_json(json: &Value) -> anyhow::Result<ParameterFile> {
+    let format = field(json, "format")?;
+    if format != FORMAT_VERSION {
+        bail!("unsupported format {format}, expected {FORMAT_VERSION:?}");
+    }
+
+    let alpha = match field(json, "alpha")? {
+        Value::String(s) if s == "inverse" => Alpha::Inverse,
+        alpha => alpha
+            .as_u64()
+            .and_then(|exp| u32::try_from(exp).ok())
+            .map(Alpha::Exponent)
+            .ok_or_else(|| anyhow!("invalid alpha {alpha}"))?,
+    };
+    let rounds = field(json, "rounds")?;
+    let r_F = usize_field(rounds, "full")?;
+    let r_P = usize_field(rounds, "partial")?;
+
+    let instance = ModulusInstance {
+        p: element(field(json, "modulus")?)?,
+        M: usize_field(json, "security")?,
+        t: usize_field(json, "t")?,
+        alpha,
+        rounds: RoundNumbers { r_P, r_F },
+        arc: rows_field(json, "arc")?,
+        mds: rows_field(json, "mds")?,
+    };
+
+    Ok(ParameterFile {
+        instance,
+        security_margin: security_margin(json)?,
+        derivation: derivation(json)?,
+    })
+}
+
+fn read(path: &Path) -> anyhow::Result<ParameterFile> {
+    let contents =
+        fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let json: Value =
+        serde_json::from_str(&contents).with_context(|| format!("parsing {}", path.display()))?;
+    from_json(&json).with_context(|| format!("reading parameters from {}", path.display()))
+}
+
+/// A human-readable summary of `parameters`.
+fn summary(parameters: &ParameterFile) -> String {
+    let instance = &parameters.instance;
+    let margin = parameters.security_margin;
+    let alpha = match instance.alpha {
+        Alpha::Exponent(exp) => format!("x^{exp}"),
+        Alpha::Inverse => "x^-1".to_string(),
+    };
+    let sboxes = instance.t * instance.rounds.full() + instance.rounds.partial();
+    format!(
+        "modulus:        {} ({} bits)
+security level: {} bits
+width:          t = {} (rate {}, capacity 1)
+S-box:          {alpha}
+rounds:         {} full, {} partial, {} total
+margin:         +{} full rounds, x{} partial rounds
+derivation:     {}
+S-boxes:        {sboxes} per permutation
+constants:      {} x {} round constants, {} x {} MDS matrix
+",
+        instance.p,
+        instance.p.bits(),
+        instance.M,
+        instance.t,
+        instance.t - 1,
+        instance.rounds.full(),
+        instance.rounds.partial(),
+        instance.rounds.total(),
+        margin.full_rounds(),
+        margin.partial_rounds_factor(),
+        derivation_name(parameters.derivation),
+        instance.arc.len(),
+        instance.arc.first().map_or(0, Vec::len),
+        instance.mds.len(),
+        instance.mds.first().map_or(0, Vec::len),
+    )
+}
+
+/// The first difference between `instance` and the `expected` instance, if any.
+fn mismatch(instance: &ModulusInstance, expected: &ModulusInstance) -> Option<String> {
+    if instance.rounds != expected.rounds {
+        return Some(format!(
+            "rounds are {} full and {} partial, expected {} full and {} partial",
+            instance.rounds.full(),
+            instance.rounds.partial(),
+            expected.rounds.full(),
+            expected.rounds.partial(),
+        ));
+    }
+    for (name, rows, expected_rows) in [
+        ("round constant", &instance.arc, &expected.arc),
+        ("MDS element", &instance.mds, &expected.mds),
+    ] {
+        if rows.len() != expected_rows.len() || rows.iter().any(|row| row.len() != instance.t) {
+            return Some(format!("the {name}s have the wrong dimensions"));
+        }
+        for (i, (row, expected_row)) in rows.iter().zip(expected_rows).enumerate() {
+            for (j, (x, y)) in row.iter().zip(expected_row).enumerate() {
+                if x != y {
+                    return Some(format!("{name} ({i}, {j}) is {x}, expected {y}"));
+                }
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The scalar field modulus of Pallas.
+    const PALLAS: &str = "0x40000000000000000000000000000000224698fc0994a8dd8c46eb2100000001";
+
+    #[test]
+    fn parameter_files_round_trip() {
+        let p = parse_modulus(PALLAS).unwrap();
+        let alpha = modulus::alpha(&p, false).unwrap();
+        assert_eq!(alpha, Alpha::Exponent(5));
+
+        let parameters = ParameterFile {
+            instance: modulus::generate(&p, 128, 3, alpha).unwrap(),
+            security_margin: SecurityMargin::default(),
+            derivation: Derivation::Transcript,
+        };
+        let json: Value = serde_json::from_str(&to_json(&parameters).to_string()).unwrap();
+        let parsed = from_json(&json).unwrap();
+        assert_eq!(parsed, parameters);
+        assert_eq!(
+            mismatch(&parsed.instance, &regenerate(&parsed).unwrap()),
+            None
+        );
+
+        // Files without a security margin or a derivation were generated with the
+        // default margin, from a transcript.
+        let mut json = json;
+        json.as_object_mut().unwrap().remove("security_margin");
+        json.as_object_mut().unwrap().remove("derivation");
+        assert_eq!(from_json(&json).unwrap(), parameters);
+    }
+
+    #[test]
+    fn validate_uses_the_recorded_security_margin() {
+        let p = parse_modulus(PALLAS).unwrap();
+        let security_margin = SecurityMargin::new(4, 1.5).unwrap();
+        let input = InputParameters::for_modulus(128, 3, p, false)
+            .with_security_margin(security_margin)
+            .unwrap();
+        let parameters = ParameterFile {
+            instance: modulus::generate_from_input(
+                &input,
+                Alpha::Exponent(5),
+                Derivation::Transcript,
+            )
+            .unwrap(),
+            security_margin,
+            derivation: Derivation::Transcript,
+        };
+
+        let json: Value = serde_json::from_str(&to_json(&parameters).to_string()).unwrap();
+        let parsed = from_json(&json).unwrap();
+        assert_eq!(parsed.security_margin, security_margin);
+        assert_eq!(
+            mismatch(&parsed.instance, &regenerate(&parsed).unwrap()),
+            None
+        );
+
+        // A margin that removes rounds is rejected.
+        let mut json = json;
+        json["security_margin"]["partial_rounds_factor"] = json!(0.5);
+        assert!(from_json(&json).is_err());
+    }
+
+    #[test]
+    fn validate_uses_the_recorded_derivation() {
+        let p = parse_modulus(PALLAS).unwrap();
+        let input = InputParameters::for_modulus(128, 3, p, false);
+        let parameters = ParameterFile {
+            instance: modulus::generate_from_input(
+                &input,
+                Alpha::Exponent(5),
+                Derivation::GrainLfsr,
+            )
+            .unwrap(),
+            security_margin: SecurityMargin::default(),
+            derivation: Derivation::GrainLfsr,
+        };
+
+        let json: Value = serde_json::from_str(&to_json(&parameters).to_string()).unwrap();
+        assert_eq!(json["derivation"], "grain-lfsr");
+        let parsed = from_json(&json).unwrap();
+        assert_eq!(parsed, parameters);
+        assert_eq!(
+            mismatch(&parsed.instance, &regenerate(&parsed).unwrap()),
+            None
+        );
+
+        // The constants are not those of a transcript.
+        let mut json = json;
+        json["derivation"] = json!("transcript");
+        let parsed = from_json(&json).unwrap();
+        assert!(mismatch(&parsed.instance, &regenerate(&parsed).unwrap()).is_some());
+
+        json["derivation"] = json!("grain");
+        assert!(from_json(&json).is_err());
+    }
+
+    #[test]
+    fn validate_reports_tampered_constants() {
+        let p = parse_modulus(PALLAS).unwrap();
+        let instance = modulus::generate(&p, 128, 3, Alpha::Exponent(5)).unwrap();
+
+        let mut tampered = instance.clone();
+        tampered.arc[2][1] += 1u32;
+        assert_eq!(
+            mismatch(&tampered, &instance),
+            Some(format!(
+                "round constant (2, 1) is {}, expected {}",
+                tampered.arc[2][1], instance.arc[2][1]
+            ))
+        );
+
+        let mut tampered = instance.clone();
+        tampered.rounds = RoundNumbers { r_P: 50, r_F: 8 };
+        assert!(mismatch(&tampered, &instance).is_some());
+    }
+
+    #[test]
+    fn provenance_of_generated_parameters() {
+        let p = parse_modulus(PALLAS).unwrap();
+        let instance = modulus::generate(&p, 128, 3, Alpha::Exponent(5)).unwrap();
+        let record = provenance::record_for_modulus(&p, 128, 3, Alpha::Exponent(5)).unwrap();
+        assert_eq!(record.rounds, instance.rounds);
+        assert_eq!(record.p, p.to_string());
+
+        // The record is valid JSON.
+        let json: Value = serde_json::from_str(&record.to_json()).unwrap();
+        assert_eq!(json["derivation"]["method"], "transcript");
+        assert_eq!(json["rounds"]["r_P"], instance.rounds.partial());
+    }
+
+    #[test]
+    fn parse_inputs() {
+        assert_eq!(parse_alpha("17").unwrap(), Alpha::Exponent(17));
+        assert_eq!(parse_alpha("inverse").unwrap(), Alpha::Inverse);
+        assert!(parse_alpha("-1").is_err());
+        assert_eq!(
+            parse_derivation("transcript").unwrap(),
+            Derivation::Transcript
+        );
+        assert_eq!(
+            parse_derivation("grain-lfsr").unwrap(),
+            Derivation::GrainLfsr
+        );
+        assert!(parse_derivation("grain").is_err());
+        assert_eq!(parse_modulus("0x11").unwrap(), BigUint::from(17u32));
+        assert_eq!(parse_modulus("17").unwrap(), BigUint::from(17u32));
+        assert!(parse_modulus("0xg").is_err());
+    }
+}
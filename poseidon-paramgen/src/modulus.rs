@@ -0,0 +1,469 @@
+use ark_std::{vec, vec::Vec};
+use decaf377::Fq;
+use merlin::Transcript;
+use num::{One, Zero};
+use num_bigint::BigUint;
+use poseidon_parameters::v1::{
+    Alpha, DynMatrix, DynPoseidonParameters, PoseidonParameterError, RoundNumbers,
+};
+
+use crate::{
+    alpha, grain,
+    input::InputParameters,
+    rounds,
+    transcript::{instance_messages, ROUND_CONSTANTS_LABEL, ROUND_CONSTANT_CHALLENGE_LABEL},
+    utils::{fq_modulus, inverse, limb_bytes},
+    v1::Derivation,
+};
+
+/// The smallest modulus, in bits, for which the fixed Cauchy MDS matrix is used.
+const MIN_MODULUS_BITS: u64 = 128;
+
+/// The largest modulus, in bits, whose size fits the 12 bits the Grain LFSR is
+/// initialized with.
+const MAX_GRAIN_MODULUS_BITS: u64 = 4095;
+
+/// The bases of the Miller-Rabin test of the modulus.
+const MILLER_RABIN_BASES: [u32; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// The constants of a Poseidon instance over the prime field of a modulus chosen at
+/// runtime.
+///
+/// The field elements are integers reduced modulo [`ModulusInstance::p`], so this
+/// describes instances over any prime field, without a Rust type for the field. The
+/// constants are the unoptimized ones: one row of `t` round constants per round, and
+/// the MDS matrix.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ModulusInstance {
+    /// The prime modulus.
+    pub p: BigUint,
+    /// Security level in bits.
+    pub M: usize,
+    /// Width of the hash function.
+    pub t: usize,
+    /// The S-box of the instance.
+    pub alpha: Alpha,
+    /// Number of full and partial rounds.
+    pub rounds: RoundNumbers,
+    /// Round constants, one row per round.
+    pub arc: Vec<Vec<BigUint>>,
+    /// MDS matrix, in rows.
+    pub mds: Vec<Vec<BigUint>>,
+}
+
+/// The S-box [`generate`](crate::v1::generate) chooses for the field of modulus `p`:
+/// the smallest exponent `alpha` such that `x^alpha` is a permutation, or the inverse
+/// S-box if there is none and `allow_inverse` is set.
+///
+/// Returns [`PoseidonParameterError::InvalidAlpha`] if there is no suitable S-box.
+pub fn v1_alpha(p: &BigUint, allow_inverse: bool) -> Result<Alpha, PoseidonParameterError> {
+    alpha::generate_for_modulus(p, allow_inverse).ok_or(PoseidonParameterError::InvalidAlpha)
+}
+
+/// Generate the constants of the instance of width `t` with S-box `alpha` over the
+/// prime field of modulus `p`, for a security level of `M` bits.
+///
+/// A modulus given as bytes can be read with [`BigUint::from_bytes_le`] or
+/// [`BigUint::from_bytes_be`]. The round numbers, round constants and MDS matrix are
+/// chosen and derived as for the instances over the fields of arkworks, so for those
+/// fields and the `alpha` of [`v1_alpha`] the constants are those of
+/// [`field::generate`](crate::v1::field::generate).
+///
+/// This fails as [`v1_generate_from_input`] does.
+pub fn v1_generate(
+    p: &BigUint,
+    M: usize,
+    t: usize,
+    alpha: Alpha,
+) -> Result<ModulusInstance, PoseidonParameterError> {
+    let input = InputParameters::for_modulus(M, t, p.clone(), alpha == Alpha::Inverse);
+    v1_generate_from_input(&input, alpha, Derivation::Transcript)
+}
+
+/// Generate the constants of the instance for `input` with S-box `alpha`, as
+/// [`v1_generate`] does, with the [`SecurityMargin`](crate::SecurityMargin) of `input`,
+/// deriving the round constants and the MDS matrix with `derivation`.
+///
+/// This fails with
+///
+/// * [`PoseidonParameterError::InvalidModulus`] if `p` is not prime or is smaller than
+///   128 bits, which the MDS matrix generation requires, as checked by a Miller-Rabin
+///   test, or if `derivation` is [`Derivation::GrainLfsr`] and `p` is larger than
+///   4095 bits,
+/// * [`PoseidonParameterError::InvalidMatrixDimensions`] if `t` is smaller than 2,
+/// * [`PoseidonParameterError::InvalidAlpha`] if `x^alpha` is not a permutation of the
+///   field, or `alpha` is smaller than 3,
+/// * [`PoseidonParameterError::UnsupportedSecurityLevel`] if `M` is beyond 256 bits.
+pub fn v1_generate_from_input(
+    input: &InputParameters<BigUint>,
+    alpha: Alpha,
+    derivation: Derivation,
+) -> Result<ModulusInstance, PoseidonParameterError> {
+    let (p, M, t) = (&input.p, input.M, input.t);
+    if p.bits() < MIN_MODULUS_BITS || !is_probable_prime(p) {
+        return Err(PoseidonParameterError::InvalidModulus);
+    }
+    if derivation == Derivation::GrainLfsr && p.bits() > MAX_GRAIN_MODULUS_BITS {
+        return Err(PoseidonParameterError::InvalidModulus);
+    }
+    if t < 2 {
+        return Err(PoseidonParameterError::InvalidMatrixDimensions);
+    }
+    if let Alpha::Exponent(exp) = alpha {
+        if exp < 3 || !alpha::alpha_coprime_to_p_minus_one(exp, p) {
+            return Err(PoseidonParameterError::InvalidAlpha);
+        }
+    }
+    let rounds = rounds::v1_generate(input, &alpha)?;
+    let (arc, mds) = match derivation {
+        Derivation::Transcript => transcript_constants(p, M, t, rounds, alpha),
+        Derivation::GrainLfsr => grain::v1_generate_for_modulus(p, t, rounds, alpha),
+    };
+
+    Ok(ModulusInstance {
+        p: p.clone(),
+        M,
+        t,
+        alpha,
+        rounds,
+        arc,
+        mds,
+    })
+}
+
+/// Squeeze the round constants of the instance from a Merlin transcript bound to it,
+/// and build the fixed Cauchy MDS matrix, as for the instances over `Fq`.
+fn transcript_constants(
+    p: &BigUint,
+    M: usize,
+    t: usize,
+    rounds: RoundNumbers,
+    alpha: Alpha,
+) -> (Vec<Vec<BigUint>>, Vec<Vec<BigUint>>) {
+    let mut transcript = Transcript::new(ROUND_CONSTANTS_LABEL);
+    for (label, message) in instance_messages(t, M, limb_bytes(p), rounds, alpha) {
+        transcript.append_message(label, &message);
+    }
+    let constant_len = (p.bits() as usize + 135) / 8;
+    let arc = (0..rounds.total())
+        .map(|_| {
+            (0..t)
+                .map(|_| {
+                    let mut dest = vec![0u8; constant_len];
+                    transcript.challenge_bytes(ROUND_CONSTANT_CHALLENGE_LABEL, &mut dest);
+                    BigUint::from_bytes_le(&dest) % p
+                })
+                .collect()
+        })
+        .collect();
+
+    // The fixed Cauchy matrix `1 / (x_i + y_j)` with `x_i = i` and `y_j = t + j`.
+    let mds = (0..t)
+        .map(|i| {
+            (0..t)
+                .map(|j| inverse(&BigUint::from(i + t + j), p))
+                .collect()
+        })
+        .collect();
+
+    (arc, mds)
+}
+
+/// Generate the instance of [`v1_generate`] as [`DynPoseidonParameters`], which are
+/// over decaf377's `Fq`.
+///
+/// This fails as [`v1_generate`] does, and with
+/// [`PoseidonParameterError::FieldMismatch`] if `p` is not the modulus of `Fq`.
+pub fn v1_generate_dyn(
+    p: &BigUint,
+    M: usize,
+    t: usize,
+    alpha: Alpha,
+) -> Result<DynPoseidonParameters, PoseidonParameterError> {
+    v1_generate(p, M, t, alpha)?.to_dyn_parameters()
+}
+
+impl ModulusInstance {
+    /// Apply the Poseidon permutation to `state`, which has `t` elements reduced
+    /// modulo `p`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `state` does not have `t` elements.
+    pub fn permute(&self, state: &mut [BigUint]) {
+        assert_eq!(state.len(), self.t, "the state has t elements");
+
+        let half_full_rounds = self.rounds.full() / 2;
+        for (r, constants) in self.arc.iter().enumerate() {
+            for (word, constant) in state.iter_mut().zip(constants) {
+                *word = (&*word + constant) % &self.p;
+            }
+
+            let full = r < half_full_rounds || r >= half_full_rounds + self.rounds.partial();
+            let sboxes = if full { self.t } else { 1 };
+            for word in state.iter_mut().take(sboxes) {
+                *word = match self.alpha {
+                    Alpha::Exponent(exp) => word.modpow(&BigUint::from(exp), &self.p),
+                    Alpha::Inverse => inverse(word, &self.p),
+                };
+            }
+
+            let mixed: Vec<BigUint> = self
+                .mds
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .zip(state.iter())
+                        .fold(BigUint::zero(), |acc, (m, word)| (acc + m * word) % &self.p)
+                })
+                .collect();
+            state.clone_from_slice(&mixed);
+        }
+    }
+
+    /// Convert the instance to [`DynPoseidonParameters`], precomputing its optimized
+    /// constants, or return [`PoseidonParameterError::FieldMismatch`] if it is not
+    /// over decaf377's `Fq`.
+    pub fn to_dyn_parameters(&self) -> Result<DynPoseidonParameters, PoseidonParameterError> {
+        if self.p != fq_modulus() {
+            return Err(PoseidonParameterError::FieldMismatch);
+        }
+
+        let to_matrix = |rows: &[Vec<BigUint>]| {
+            let elements = rows
+                .iter()
+                .flatten()
+                .map(|x| Fq::from_le_bytes_mod_order(&x.to_bytes_le()))
+                .collect();
+            DynMatrix::try_new(rows.len(), self.t, elements)
+        };
+        DynPoseidonParameters::from_unoptimized(
+            self.M,
+            self.alpha,
+            self.rounds,
+            to_matrix(&self.mds)?,
+            to_matrix(&self.arc)?,
+        )
+    }
+}
+
+/// A Miller-Rabin test of `n` with the bases [`MILLER_RABIN_BASES`], which is exact
+/// for `n` below `3.3 * 10^24` and probabilistic beyond.
+fn is_probable_prime(n: &BigUint) -> bool {
+    let one = BigUint::one();
+    let n_minus_one = n - &one;
+    let s = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> s;
+
+    MILLER_RABIN_BASES.iter().all(|base| {
+        let base = BigUint::from(*base);
+        if n == &base {
+            return true;
+        }
+        if (n % &base).is_zero() {
+            return false;
+        }
+
+        let mut x = base.modpow(&d, n);
+        if x == one || x == n_minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x = x.modpow(&BigUint::from(2u32), n);
+            if x == n_minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr;
+
+    use ark_bn254::Fr as Fr254;
+    use ark_ff::{BigInteger, PrimeField};
+    use ark_pallas::Fr as PallasFr;
+
+    use super::*;
+
+    fn modulus<F: PrimeField>() -> BigUint {
+        F::MODULUS.into()
+    }
+
+    #[test]
+    fn modulus_instance_matches_field_instance() {
+        let p = modulus::<PallasFr>();
+        let alpha = v1_alpha(&p, false).unwrap();
+        let instance = v1_generate(&p, 128, 3, alpha).unwrap();
+        let field_instance = crate::field::v1_generate::<PallasFr>(128, 3, false).unwrap();
+
+        assert_same_instance(&instance, &field_instance);
+        assert_eq!(limb_bytes(&p), PallasFr::MODULUS.to_bytes_le());
+    }
+
+    #[test]
+    fn modulus_instance_from_the_grain_lfsr() {
+        let p = modulus::<Fr254>();
+        let input = InputParameters::for_modulus(128, 3, p.clone(), false);
+        let instance =
+            v1_generate_from_input(&input, Alpha::Exponent(5), Derivation::GrainLfsr).unwrap();
+        let field_instance = crate::field::v1_generate_from_input::<Fr254>(
+            &InputParameters::generate(128, 3, Fr254::MODULUS, false),
+            Derivation::GrainLfsr,
+        )
+        .unwrap();
+
+        assert_same_instance(&instance, &field_instance);
+        assert_ne!(
+            instance.arc,
+            v1_generate(&p, 128, 3, Alpha::Exponent(5)).unwrap().arc
+        );
+    }
+
+    fn assert_same_instance<F: PrimeField>(
+        instance: &ModulusInstance,
+        field_instance: &crate::field::FieldInstance<F>,
+    ) {
+        assert_eq!(instance.alpha, field_instance.alpha);
+        assert_eq!(instance.rounds, field_instance.rounds);
+        for (rows, field_rows) in [
+            (&instance.arc, &field_instance.arc),
+            (&instance.mds, &field_instance.mds),
+        ] {
+            assert_eq!(rows.len(), field_rows.len());
+            for (row, field_row) in rows.iter().zip(field_rows) {
+                assert_eq!(row.len(), field_row.len());
+                for (x, y) in row.iter().zip(field_row) {
+                    assert_eq!(*x, y.into_bigint().into());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn modulus_instance_over_fq_converts_to_dyn_parameters() {
+        let p = fq_modulus();
+        let instance = v1_generate(&p, 128, 3, Alpha::Exponent(17)).unwrap();
+        assert_eq!(instance.rounds, RoundNumbers { r_P: 31, r_F: 8 });
+
+        // The test vector of `hash_2` of `poseidon377`, which is the second word of the
+        // permutation of the domain separator and the two inputs.
+        let mut state = vec![
+            BigUint::from_bytes_le(b"Penumbra_TestVec") % &p,
+            BigUint::from_str(
+                "7553885614632219548127688026174585776320152166623257619763178041781456016062",
+            )
+            .unwrap(),
+            BigUint::from_str(
+                "2337838243217876174544784248400816541933405738836087430664765452605435675740",
+            )
+            .unwrap(),
+        ];
+        instance.permute(&mut state);
+        assert_eq!(
+            state[1],
+            BigUint::from_str(
+                "4318449279293553393006719276941638490334729643330833590842693275258805886300"
+            )
+            .unwrap()
+        );
+
+        let parameters = v1_generate_dyn(&p, 128, 3, Alpha::Exponent(17)).unwrap();
+        assert_eq!(parameters.state_size(), 3);
+        assert_eq!(
+            parameters.arc.get_element(0, 0),
+            Fq::from_le_bytes_mod_order(&instance.arc[0][0].to_bytes_le())
+        );
+        // The optimized parameters are those vendored by `poseidon377`.
+        assert_eq!(
+            parameters
+                .to_rust_source("rate_2", "Parameters for the rate-2 instance of Poseidon.")
+                .unwrap(),
+            include_str!("../../poseidon377/src/params/rate_2.rs")
+        );
+
+        let pallas = v1_generate(&modulus::<PallasFr>(), 128, 3, Alpha::Exponent(5)).unwrap();
+        assert_eq!(
+            pallas.to_dyn_parameters(),
+            Err(PoseidonParameterError::FieldMismatch)
+        );
+    }
+
+    #[test]
+    fn modulus_instance_with_alpha_3() {
+        // The largest prime below 2^255 that is 2 mod 3, over which `x^3` is a
+        // permutation.
+        let p = (BigUint::one() << 255) - BigUint::from(735u32);
+        let alpha = v1_alpha(&p, false).unwrap();
+        assert_eq!(alpha, Alpha::Exponent(3));
+
+        let instance = v1_generate(&p, 128, 3, alpha).unwrap();
+        assert_eq!(instance.rounds, RoundNumbers { r_P: 83, r_F: 8 });
+        assert_eq!(
+            instance.arc[0][0],
+            BigUint::from_str(
+                "43266612485354401473045802794738964545025122047701956605175774283459217465956"
+            )
+            .unwrap()
+        );
+
+        let mut state = vec![
+            BigUint::from(0u32),
+            BigUint::from(1u32),
+            BigUint::from(2u32),
+        ];
+        instance.permute(&mut state);
+        assert_eq!(
+            state[1],
+            BigUint::from_str(
+                "2680225160359434238467508867883766655815387045361127036666722479291521718407"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn modulus_instance_with_security_margin() {
+        let p = fq_modulus();
+        let margin = crate::SecurityMargin::new(4, 1.5).unwrap();
+        let input = InputParameters::for_modulus(128, 3, p.clone(), false)
+            .with_security_margin(margin)
+            .unwrap();
+        let instance =
+            v1_generate_from_input(&input, Alpha::Exponent(17), Derivation::Transcript).unwrap();
+        assert_eq!(instance.rounds, RoundNumbers { r_P: 42, r_F: 10 });
+        assert_ne!(
+            instance.arc[0],
+            v1_generate(&p, 128, 3, Alpha::Exponent(17)).unwrap().arc[0]
+        );
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        let p = modulus::<PallasFr>();
+        assert_eq!(
+            v1_generate(&(&p + 2u32), 128, 3, Alpha::Exponent(5)),
+            Err(PoseidonParameterError::InvalidModulus)
+        );
+        assert_eq!(
+            v1_generate(&BigUint::from(65537u32), 128, 3, Alpha::Exponent(5)),
+            Err(PoseidonParameterError::InvalidModulus)
+        );
+        assert_eq!(
+            v1_generate(&p, 128, 1, Alpha::Exponent(5)),
+            Err(PoseidonParameterError::InvalidMatrixDimensions)
+        );
+        assert_eq!(
+            v1_generate(&p, 128, 3, Alpha::Exponent(3)),
+            Err(PoseidonParameterError::InvalidAlpha)
+        );
+        assert_eq!(
+            v1_generate(&p, 512, 3, Alpha::Exponent(5)),
+            Err(PoseidonParameterError::UnsupportedSecurityLevel)
+        );
+        assert_eq!(v1_alpha(&p, false), Ok(Alpha::Exponent(5)));
+        assert!(is_probable_prime(&BigUint::from_str("65537").unwrap()));
+    }
+}
@@ -0,0 +1,246 @@
+use std::{fmt::Write, fs, io, path::Path};
+
+use ark_ff::PrimeField;
+use ark_std::vec::Vec;
+use num::BigUint;
+
+use poseidon_parameters::v1::{Alpha, PoseidonParameterError};
+
+use crate::{field, input::InputParameters, utils::serialize_rows, v1::Derivation};
+
+/// The files making up a generated Noir library package.
+pub struct NoirPackage {
+    /// Contents of `Nargo.toml`.
+    pub manifest: String,
+    /// Contents of `src/lib.nr`.
+    pub lib: String,
+}
+
+impl NoirPackage {
+    /// Write the package to the directory `path`, creating it if needed.
+    pub fn write_to<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let src = path.as_ref().join("src");
+        fs::create_dir_all(&src)?;
+        fs::write(path.as_ref().join("Nargo.toml"), &self.manifest)?;
+        fs::write(src.join("lib.nr"), &self.lib)
+    }
+}
+
+/// Create a Noir library package named `name` implementing the v1 permutation for each
+/// width in `t_values`.
+///
+/// For each rate the library exports `permute_{rate}` over the full state, and
+/// `hash_{rate}` which takes a domain separator and `rate` inputs and returns the same
+/// element as the native fixed-width hash. The permutation is the unoptimized one, using
+/// the round constants and MDS matrix exactly as generated.
+///
+/// Noir's `Field` is the scalar field of the proving backend, so the hashes only match
+/// the native implementation on a backend whose field modulus is `p`. Every generated
+/// function asserts this, which fails at compile time on any other backend.
+///
+/// This fails as [`field::generate`](crate::v1::field::generate) does.
+pub fn v1_compile<F: PrimeField>(
+    name: &str,
+    M: usize,
+    t_values: Vec<usize>,
+    p: F::BigInt,
+    allow_inverse: bool,
+) -> Result<NoirPackage, PoseidonParameterError> {
+    let manifest = format!(
+        r#"[package]
+name = "{name}"
+type = "lib"
+authors = [""]
+
+[dependencies]
+"#
+    );
+
+    let modulus: BigUint = p.into();
+    let modulus_bytes: Vec<String> = modulus
+        .to_bytes_be()
+        .iter()
+        .map(|byte| byte.to_string())
+        .collect();
+    let mut lib = format!(
+        r"// Generated by poseidon-paramgen.
+
+global MODULUS_BE: [u8; {}] = [{}];
+
+// The generated constants are only meaningful in the field they were generated for.
+fn assert_modulus() {{
+    let modulus = std::field::modulus_be_bytes();
+    assert(modulus.len() == MODULUS_BE.len());
+    for i in 0..MODULUS_BE.len() {{
+        assert(modulus[i] == MODULUS_BE[i]);
+    }}
+}}
+",
+        modulus_bytes.len(),
+        modulus_bytes.join(", "),
+    );
+
+    for t in t_values {
+        let input = InputParameters::generate(M, t, p, allow_inverse);
+        let instance = field::v1_generate_from_input::<F>(&input, Derivation::Transcript)?;
+
+        write_instance(
+            &mut lib,
+            t,
+            instance.rounds.full(),
+            instance.rounds.partial(),
+            instance.alpha,
+            &instance.arc,
+            &instance.mds,
+        )
+        .expect("writing to a string cannot fail");
+    }
+
+    Ok(NoirPackage { manifest, lib })
+}
+
+fn write_instance<F: PrimeField>(
+    out: &mut String,
+    t: usize,
+    r_F: usize,
+    r_P: usize,
+    alpha: Alpha,
+    round_constants: &[Vec<F>],
+    mds: &[Vec<F>],
+) -> std::fmt::Result {
+    let capacity = 1;
+    let rate = t - capacity;
+    let num_rounds = r_F + r_P;
+
+    write!(
+        out,
+        r"
+// Parameters for the rate-{rate} instance of Poseidon.
+global C_{rate}: [[Field; {t}]; {num_rounds}] = {};
+
+global M_{rate}: [[Field; {t}]; {t}] = {};
+",
+        serialize_rows(round_constants),
+        serialize_rows(mds),
+    )?;
+
+    write_sbox(out, rate, alpha)?;
+
+    write!(
+        out,
+        r"
+pub fn permute_{rate}(input: [Field; {t}]) -> [Field; {t}] {{
+    assert_modulus();
+
+    let mut state = input;
+    for r in 0..{num_rounds} {{
+        for i in 0..{t} {{
+            state[i] += C_{rate}[r][i];
+        }}
+
+        // Full rounds apply the S-box to every element, partial rounds to the first.
+        if (r < {half_F}) | (r >= {half_F} + {r_P}) {{
+            for i in 0..{t} {{
+                state[i] = sbox_{rate}(state[i]);
+            }}
+        }} else {{
+            state[0] = sbox_{rate}(state[0]);
+        }}
+
+        let mut mixed = [0; {t}];
+        for i in 0..{t} {{
+            for j in 0..{t} {{
+                mixed[i] += M_{rate}[i][j] * state[j];
+            }}
+        }}
+        state = mixed;
+    }}
+    state
+}}
+
+pub fn hash_{rate}(domain_separator: Field, input: [Field; {rate}]) -> Field {{
+    let mut state = [0; {t}];
+    state[0] = domain_separator;
+    for i in 0..{rate} {{
+        state[i + 1] = input[i];
+    }}
+    permute_{rate}(state)[1]
+}}
+",
+        half_F = r_F / 2,
+    )
+}
+
+/// Emit the S-box function, evaluating `x^alpha` by square-and-multiply, or `1/x`
+/// (mapping zero to zero) for the inverse S-box.
+fn write_sbox(out: &mut String, rate: usize, alpha: Alpha) -> std::fmt::Result {
+    writeln!(out, "\nfn sbox_{rate}(x: Field) -> Field {{")?;
+
+    match alpha {
+        Alpha::Exponent(exp) => {
+            let bits = 32 - exp.leading_zeros();
+            let mut acc = "x".to_string();
+            let mut step = 0;
+            for bit in (0..bits.saturating_sub(1)).rev() {
+                writeln!(out, "    let s{step} = {acc} * {acc};")?;
+                acc = format!("s{step}");
+                step += 1;
+                if (exp >> bit) & 1 == 1 {
+                    writeln!(out, "    let s{step} = {acc} * x;")?;
+                    acc = format!("s{step}");
+                    step += 1;
+                }
+            }
+            writeln!(out, "    {acc}")?;
+        }
+        Alpha::Inverse => {
+            out.push_str("    if x == 0 { 0 } else { 1 / x }\n");
+        }
+    }
+
+    out.push_str("}\n");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_ed_on_bls12_377::Fq;
+
+    #[test]
+    fn noir_package() {
+        let package = v1_compile::<Fq>("poseidon377", 128, vec![3, 5], Fq::MODULUS, false).unwrap();
+        assert!(package.manifest.contains("name = \"poseidon377\""));
+        for rate in [2, 4] {
+            assert!(package.lib.contains(&format!("pub fn permute_{rate}(")));
+            assert!(package.lib.contains(&format!("pub fn hash_{rate}(")));
+        }
+
+        // The constants are those of the native instances, in decimal.
+        assert!(package.lib.contains(
+            "308026635595114235070436728341841505234226384644787941764356225291780075012"
+        ));
+        // The modulus is checked against the backend's, in big-endian bytes.
+        assert!(package
+            .lib
+            .contains("global MODULUS_BE: [u8; 32] = [18, 171, 101, 94,"));
+
+        // x^17 is four squarings and one multiplication.
+        assert!(package
+            .lib
+            .contains("let s3 = s2 * s2;\n    let s4 = s3 * x;\n    s4\n}"));
+
+        let dir = std::env::temp_dir().join(format!("noir-package-{}", std::process::id()));
+        package.write_to(&dir).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("Nargo.toml")).unwrap(),
+            package.manifest
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("src").join("lib.nr")).unwrap(),
+            package.lib
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,336 @@
+use std::fmt::Write;
+
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::vec::Vec;
+use num_bigint::BigUint;
+use poseidon_parameters::v1::{Alpha, PoseidonParameterError, RoundNumbers};
+
+use crate::{
+    alpha, grain,
+    input::{InputParameters, SecurityMargin},
+    rounds, transcript,
+    utils::limb_bytes,
+    v1::Derivation,
+};
+
+/// A record of how a v1 parameter set was generated, with everything needed to re-derive
+/// it exactly: the input parameters, the intermediate bounds the round numbers were chosen
+/// from, the labels and messages the constants were derived from, and the version of this
+/// crate.
+#[derive(Clone, Debug)]
+pub struct Provenance {
+    /// The name of the crate that generated the parameters.
+    pub generator: &'static str,
+    /// The version of the crate that generated the parameters.
+    pub version: &'static str,
+    /// Security level in bits.
+    pub M: usize,
+    /// Width of the hash function.
+    pub t: usize,
+    /// The prime modulus, in decimal.
+    pub p: String,
+    /// log_2(p)
+    pub log_2_p: f64,
+    /// Whether or not inverse alpha was allowed.
+    pub allow_inverse: bool,
+    /// The chosen S-box exponent.
+    pub alpha: Alpha,
+    /// The number of full rounds required against statistical attacks.
+    pub statistical_full_rounds: usize,
+    /// The bound against interpolation attacks, on the total number of rounds for positive
+    /// alpha and on $\floor{R_F \log_2(t)} + R_P$ for inverse alpha.
+    pub interpolation_rounds: usize,
+    /// The bound against Grobner basis attacks, on the same number of rounds as
+    /// `interpolation_rounds`.
+    pub grobner_rounds: usize,
+    /// The security margin added to the round numbers.
+    pub security_margin: SecurityMargin,
+    /// The chosen round numbers, including the security margin.
+    pub rounds: RoundNumbers,
+    /// How the round constants and the MDS matrix were derived.
+    pub derivation: DerivationRecord,
+}
+
+/// The inputs of the derivation of the round constants and the MDS matrix.
+#[derive(Clone, Debug)]
+pub enum DerivationRecord {
+    /// The round constants were squeezed from a Merlin transcript.
+    Transcript {
+        /// The label the transcript was created with.
+        label: String,
+        /// The labelled messages appended to the transcript, in order, as hex.
+        messages: Vec<(String, String)>,
+        /// The label of each round constant challenge.
+        challenge_label: String,
+        /// The number of bytes of each challenge, reduced modulo p.
+        challenge_bytes: usize,
+    },
+    /// The round constants and the MDS matrix were sampled from the Grain LFSR.
+    GrainLfsr {
+        /// The 80 bits the LFSR was initialized with.
+        initial_state: String,
+        /// The number of output bits discarded after initialization.
+        discarded_bits: usize,
+    },
+}
+
+/// Record how [`generate_from_input`](crate::v1::generate_from_input) derives the v1
+/// instance for `input` with `derivation`.
+///
+/// This fails as generating the S-box and the round numbers of the instance does.
+pub fn v1_record<F: PrimeField>(
+    input: &InputParameters<F::BigInt>,
+    derivation: Derivation,
+) -> Result<Provenance, PoseidonParameterError> {
+    let alpha = alpha::generate::<F>(input.p, input.allow_inverse)?;
+    record(
+        input,
+        &input.p.into(),
+        input.p.to_bytes_le(),
+        alpha,
+        derivation,
+    )
+}
+
+/// Record how [`modulus::generate`](crate::v1::modulus::generate) derives the v1
+/// instance of width `t` with S-box `alpha` over the prime field of modulus `p`, for a
+/// security level of `M` bits.
+///
+/// For the fields of arkworks, this is the record of [`v1_record`] with
+/// [`Derivation::Transcript`].
+pub fn v1_record_for_modulus(
+    p: &BigUint,
+    M: usize,
+    t: usize,
+    alpha: Alpha,
+) -> Result<Provenance, PoseidonParameterError> {
+    let input = InputParameters::for_modulus(M, t, p.clone(), alpha == Alpha::Inverse);
+    v1_record_for_input(&input, alpha, Derivation::Transcript)
+}
+
+/// Record how [`modulus::generate_from_input`](crate::v1::modulus::generate_from_input)
+/// derives the v1 instance for `input` with S-box `alpha` and `derivation`.
+pub fn v1_record_for_input(
+    input: &InputParameters<BigUint>,
+    alpha: Alpha,
+    derivation: Derivation,
+) -> Result<Provenance, PoseidonParameterError> {
+    record(input, &input.p, limb_bytes(&input.p), alpha, derivation)
+}
+
+/// Record the derivation of the instance for `input` with S-box `alpha`, over the field of
+/// modulus `p`, which is bound to the transcript as `p_bytes`.
+fn record<T>(
+    input: &InputParameters<T>,
+    p: &BigUint,
+    p_bytes: Vec<u8>,
+    alpha: Alpha,
+    derivation: Derivation,
+) -> Result<Provenance, PoseidonParameterError> {
+    let round_numbers = rounds::v1_generate(input, &alpha)?;
+    let modulus_bits = p.bits() as usize;
+
+    let derivation = match derivation {
+        Derivation::Transcript => DerivationRecord::Transcript {
+            label: label(transcript::ROUND_CONSTANTS_LABEL),
+            messages: transcript::instance_messages(
+                input.t,
+                input.M,
+                p_bytes,
+                round_numbers,
+                alpha,
+            )
+            .into_iter()
+            .map(|(name, message)| (label(name), hex(&message)))
+            .collect(),
+            challenge_label: label(transcript::ROUND_CONSTANT_CHALLENGE_LABEL),
+            // As many bytes as the transcript squeezes for each round constant.
+            challenge_bytes: (modulus_bits + 135) / 8,
+        },
+        Derivation::GrainLfsr => DerivationRecord::GrainLfsr {
+            initial_state: grain::initial_state(modulus_bits as u32, input.t, round_numbers, alpha)
+                .iter()
+                .map(|bit| if *bit { '1' } else { '0' })
+                .collect(),
+            discarded_bits: grain::DISCARDED_BITS,
+        },
+    };
+
+    Ok(Provenance {
+        generator: env!("CARGO_PKG_NAME"),
+        version: env!("CARGO_PKG_VERSION"),
+        M: input.M,
+        t: input.t,
+        p: p.to_string(),
+        log_2_p: input.log_2_p,
+        allow_inverse: input.allow_inverse,
+        alpha,
+        statistical_full_rounds: rounds::statistical_attack_full_rounds(input, &alpha),
+        interpolation_rounds: rounds::algebraic_attack_interpolation(input, &alpha),
+        grobner_rounds: rounds::algebraic_attack_grobner_basis_v1(input, &alpha),
+        security_margin: input.security_margin,
+        rounds: round_numbers,
+        derivation,
+    })
+}
+
+impl Provenance {
+    /// Serialize the record as a JSON object, to be stored next to the parameters.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json)
+            .expect("writing to a string cannot fail");
+        json
+    }
+
+    fn write_json(&self, out: &mut String) -> std::fmt::Result {
+        writeln!(out, "{{")?;
+        writeln!(
+            out,
+            "  \"generator\": {{ \"name\": \"{}\", \"version\": \"{}\" }},",
+            self.generator, self.version
+        )?;
+        writeln!(out, "  \"input\": {{")?;
+        writeln!(out, "    \"M\": {},", self.M)?;
+        writeln!(out, "    \"t\": {},", self.t)?;
+        writeln!(out, "    \"p\": \"{}\",", self.p)?;
+        writeln!(out, "    \"log_2_p\": {},", self.log_2_p)?;
+        writeln!(out, "    \"allow_inverse\": {}", self.allow_inverse)?;
+        writeln!(out, "  }},")?;
+        match self.alpha {
+            Alpha::Exponent(exp) => writeln!(out, "  \"alpha\": {{ \"exponent\": {} }},", exp)?,
+            Alpha::Inverse => writeln!(out, "  \"alpha\": \"inverse\",")?,
+        }
+        writeln!(out, "  \"rounds\": {{")?;
+        writeln!(
+            out,
+            "    \"statistical_full_rounds\": {},",
+            self.statistical_full_rounds
+        )?;
+        writeln!(
+            out,
+            "    \"interpolation_rounds\": {},",
+            self.interpolation_rounds
+        )?;
+        writeln!(out, "    \"grobner_rounds\": {},", self.grobner_rounds)?;
+        writeln!(
+            out,
+            "    \"security_margin\": {{ \"full_rounds\": {}, \"partial_rounds_factor\": {} }},",
+            self.security_margin.full_rounds(),
+            self.security_margin.partial_rounds_factor()
+        )?;
+        writeln!(out, "    \"r_F\": {},", self.rounds.full())?;
+        writeln!(out, "    \"r_P\": {}", self.rounds.partial())?;
+        writeln!(out, "  }},")?;
+        writeln!(out, "  \"derivation\": {{")?;
+        match &self.derivation {
+            DerivationRecord::Transcript {
+                label,
+                messages,
+                challenge_label,
+                challenge_bytes,
+            } => {
+                writeln!(out, "    \"method\": \"transcript\",")?;
+                writeln!(out, "    \"label\": \"{}\",", label)?;
+                writeln!(out, "    \"messages\": [")?;
+                for (i, (name, message)) in messages.iter().enumerate() {
+                    let separator = if i + 1 < messages.len() { "," } else { "" };
+                    writeln!(
+                        out,
+                        "      {{ \"label\": \"{}\", \"hex\": \"{}\" }}{}",
+                        name, message, separator
+                    )?;
+                }
+                writeln!(out, "    ],")?;
+                writeln!(out, "    \"challenge_label\": \"{}\",", challenge_label)?;
+                writeln!(out, "    \"challenge_bytes\": {}", challenge_bytes)?;
+            }
+            DerivationRecord::GrainLfsr {
+                initial_state,
+                discarded_bits,
+            } => {
+                writeln!(out, "    \"method\": \"grain-lfsr\",")?;
+                writeln!(out, "    \"initial_state\": \"{}\",", initial_state)?;
+                writeln!(out, "    \"discarded_bits\": {}", discarded_bits)?;
+            }
+        }
+        writeln!(out, "  }}")?;
+        writeln!(out, "}}")
+    }
+}
+
+/// The transcript labels are ASCII.
+fn label(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_ed_on_bls12_377::Fq;
+
+    #[test]
+    fn provenance_record() {
+        let input = InputParameters::generate(128, 3, Fq::MODULUS, false);
+        let record = v1_record::<Fq>(&input, Derivation::Transcript).unwrap();
+        let params =
+            crate::v1::generate::<Fq, 3, 2, 9, 4, 39, 3, 117, 31>(128, 3, Fq::MODULUS, false)
+                .unwrap();
+        assert_eq!(record.alpha, params.alpha);
+        assert_eq!(record.rounds, params.rounds);
+        assert_eq!(record.rounds.full(), record.statistical_full_rounds + 2);
+
+        let json = record.to_json();
+        assert!(json.contains("\"method\": \"transcript\""));
+        assert!(json.contains("{ \"label\": \"t\", \"hex\": \"0300000000000000\" }"));
+        assert!(json.contains(&format!("\"version\": \"{}\"", env!("CARGO_PKG_VERSION"))));
+        assert!(json.contains("\"challenge_bytes\": 48"));
+
+        // The same instance, over the modulus given at runtime.
+        let modulus: BigUint = Fq::MODULUS.into();
+        let for_modulus = v1_record_for_modulus(&modulus, 128, 3, Alpha::Exponent(17)).unwrap();
+        assert_eq!(for_modulus.to_json(), json);
+
+        let record = v1_record::<Fq>(&input, Derivation::GrainLfsr).unwrap();
+        let for_input = v1_record_for_input(
+            &InputParameters::for_modulus(128, 3, modulus, false),
+            Alpha::Exponent(17),
+            Derivation::GrainLfsr,
+        )
+        .unwrap();
+        assert_eq!(for_input.to_json(), record.to_json());
+        match record.derivation {
+            DerivationRecord::GrainLfsr { initial_state, .. } => {
+                // A prime field and x^alpha.
+                assert!(initial_state.starts_with("010000"));
+                assert_eq!(initial_state.len(), 80);
+            }
+            _ => panic!("expected the Grain LFSR"),
+        }
+    }
+
+    #[test]
+    fn security_margin_recorded() {
+        let margin = SecurityMargin::new(4, 1.5).unwrap();
+        let input = InputParameters::generate(128, 3, Fq::MODULUS, false)
+            .with_security_margin(margin)
+            .unwrap();
+        let record = v1_record::<Fq>(&input, Derivation::Transcript).unwrap();
+        let default = v1_record::<Fq>(
+            &InputParameters::generate(128, 3, Fq::MODULUS, false),
+            Derivation::Transcript,
+        )
+        .unwrap();
+        assert_eq!(record.security_margin, margin);
+        assert_eq!(record.rounds.full(), record.statistical_full_rounds + 4);
+        assert!(record.rounds.partial() > default.rounds.partial());
+        assert!(record.to_json().contains(
+            "\"security_margin\": { \"full_rounds\": 4, \"partial_rounds_factor\": 1.5 }"
+        ));
+    }
+}
@@ -1,139 +1,72 @@
 use crate::input::InputParameters;
 use ark_ff::PrimeField;
-use ark_std::{vec, vec::Vec};
+use ark_std::vec::Vec;
 use merlin::Transcript;
-use poseidon_parameters::v1::{
-    mat_mul, Alpha, ArcMatrix, Matrix, MatrixOperations, MdsMatrix, OptimizedArcMatrix,
-    RoundNumbers,
-};
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
 
-use crate::transcript::TranscriptProtocol;
+use crate::transcript::{TranscriptProtocol, ROUND_CONSTANTS_LABEL};
 
-// TODO:
-// Problem: We don't know NUM_ROUNDS off the bat, so can't be a compile-time constant.
-
-/// Generate round constants.
+/// Generate round constants, one row of `t` constants per round.
 pub fn v1_generate<F: PrimeField>(
     input: &InputParameters<F::BigInt>,
     round_numbers: RoundNumbers,
     alpha: Alpha,
-) -> ArcMatrix<F> {
-    let mut transcript = Transcript::new(b"round-constants");
+) -> Vec<Vec<F>> {
+    let mut transcript = Transcript::new(ROUND_CONSTANTS_LABEL);
     transcript.domain_sep::<F>(input, round_numbers, alpha);
 
-    let num_total_rounds = round_numbers.total();
-    let elements = (0..num_total_rounds * input.t)
-        .map(|_| transcript.round_constant())
-        .collect();
-    ArcMatrix(Matrix::new(num_total_rounds, input.t, elements))
+    (0..round_numbers.total())
+        .map(|_| (0..input.t).map(|_| transcript.round_constant()).collect())
+        .collect()
 }
 
-/// Generate round constants.
+/// Generate round constants for Poseidon2: `t` constants per full round, and a single
+/// constant per partial round.
 pub fn v2_generate<F: PrimeField>(
     input: &InputParameters<F::BigInt>,
     round_numbers: RoundNumbers,
     alpha: Alpha,
-) -> ArcMatrix<F> {
-    let mut transcript = Transcript::new(b"round-constants");
+) -> Vec<F> {
+    let mut transcript = Transcript::new(ROUND_CONSTANTS_LABEL);
     transcript.domain_sep::<F>(input, round_numbers, alpha);
 
     let full_rounds = round_numbers.full();
     let partial_rounds = round_numbers.partial();
     let num_round_constants = full_rounds * input.t + partial_rounds;
-    let elements = (0..num_round_constants)
+    (0..num_round_constants)
         .map(|_| transcript.round_constant())
-        .collect();
-    ArcMatrix(Matrix::new(num_round_constants, 1, elements))
-}
-
-/// Get row vector of constants by round
-fn constants_by_round<F: PrimeField>(arc_matrix: &ArcMatrix<F>, r: usize) -> Matrix<F> {
-    arc_matrix.0.row_vector(r)
-}
-
-/// Set row vector of constants by round
-fn set_constants_by_round<F: PrimeField>(
-    arc_matrix: &mut ArcMatrix<F>,
-    r: usize,
-    constants: Vec<F>,
-) {
-    assert_eq!(constants.len(), arc_matrix.0.n_cols());
-    for (j, value) in constants.into_iter().enumerate() {
-        arc_matrix.0.set_element(r, j, value);
-    }
-}
-
-/// Generate the optimized round constants.
-pub fn generate_optimized<F: PrimeField>(
-    arc: &ArcMatrix<F>,
-    mds: &MdsMatrix<F>,
-    rounds: &RoundNumbers,
-) -> OptimizedArcMatrix<F> {
-    let n_cols = arc.n_cols();
-    let mut constants_temp = arc.clone();
-
-    let r_f = rounds.full() / 2;
-    let r_T = rounds.total();
-    let mds_T = mds.transpose();
-    let mds_inv = mds_T.inverse();
-
-    // C_i = M^-1 * C_(i+1)
-    for r in ((r_f)..(r_T - 1 - r_f)).rev() {
-        // inv_cip1 = list(vector(constants_temp[i+1]) * MDS_matrix_field_transpose.inverse())
-
-        let inv_cip1 = mat_mul(&constants_by_round(&constants_temp, r + 1), &mds_inv.0).expect(
-            "matrix multiplication of row of ARC matrix and MDS must have correct dimensions",
-        );
-        assert_eq!(inv_cip1.n_cols(), n_cols);
-        assert_eq!(inv_cip1.n_rows(), 1);
-
-        // constants_temp[i] = list(vector(constants_temp[i]) + vector([0] + inv_cip1[1:]))
-        let mut delta_new_constants_r = Vec::with_capacity(n_cols);
-        delta_new_constants_r.push(F::zero());
-        for j in 1..n_cols {
-            delta_new_constants_r.push(inv_cip1.get_element(0, j));
-        }
-        (0..n_cols).for_each(|j| {
-            let curr_element = constants_temp.get_element(r, j);
-            constants_temp.set_element(r, j, curr_element + delta_new_constants_r[j]);
-        });
-
-        // constants_temp[i+1] = [inv_cip1[0]] + [0] * (t-1)
-        let mut new_constants_row_i_plus_1 = Vec::with_capacity(n_cols);
-        new_constants_row_i_plus_1.push(inv_cip1.get_element(0, 0));
-        new_constants_row_i_plus_1.extend(vec![F::zero(); n_cols - 1]);
-        set_constants_by_round(&mut constants_temp, r + 1, new_constants_row_i_plus_1);
-    }
-
-    OptimizedArcMatrix(constants_temp)
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
+    use core::str::FromStr;
+
     use ark_ed_on_bls12_377::Fq;
 
     use super::*;
 
     #[test]
-    fn convert_from_arc_to_vec_of_vecs() {
-        let arc_matrix = ArcMatrix(Matrix::new(
-            2,
-            3,
-            vec![
-                Fq::from(1u32),
-                Fq::from(2u32),
-                Fq::from(0u32),
-                Fq::from(4u32),
-                Fq::from(5u32),
-                Fq::from(6u32),
-            ],
-        ));
-        let vec_of_vecs: Vec<Vec<Fq>> = arc_matrix.into();
-        assert_eq!(vec_of_vecs[0][0], Fq::from(1u32));
-        assert_eq!(vec_of_vecs[0][1], Fq::from(2u32));
-        assert_eq!(vec_of_vecs[0][2], Fq::from(0u32));
-        assert_eq!(vec_of_vecs[1][0], Fq::from(4u32));
-        assert_eq!(vec_of_vecs[1][1], Fq::from(5u32));
-        assert_eq!(vec_of_vecs[1][2], Fq::from(6u32));
+    fn round_constants_are_squeezed_in_order() {
+        let input = InputParameters::generate(128, 3, Fq::MODULUS, false);
+        let rounds = RoundNumbers { r_P: 31, r_F: 8 };
+        let arc: Vec<Vec<Fq>> = v1_generate(&input, rounds, Alpha::Exponent(17));
+        assert_eq!(arc.len(), 39);
+        assert!(arc.iter().all(|row| row.len() == 3));
+
+        // The first round constant of the rate-2 instance of `poseidon377`.
+        assert_eq!(
+            arc[0][0],
+            Fq::from_str(
+                "308026635595114235070436728341841505234226384644787941764356225291780075012"
+            )
+            .unwrap()
+        );
+
+        // The transcript is the same for Poseidon2, which squeezes fewer constants.
+        let v2_arc: Vec<Fq> = v2_generate(&input, rounds, Alpha::Exponent(17));
+        assert_eq!(v2_arc.len(), 8 * 3 + 31);
+        let v1_arc: Vec<Fq> = arc.into_iter().flatten().collect();
+        assert_eq!(v2_arc[..], v1_arc[..v2_arc.len()]);
     }
 }
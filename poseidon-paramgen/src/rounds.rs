@@ -1,27 +1,31 @@
-use crate::input::InputParameters;
-use ark_ff::BigInteger;
+use crate::input::{InputParameters, SecurityMargin};
 use ark_std::cmp::{Ordering, PartialOrd};
 use num_bigint::{BigInt, ToBigInt};
-use poseidon_parameters::v1::{Alpha, RoundNumbers};
+use poseidon_parameters::v1::{Alpha, PoseidonParameterError, RoundNumbers};
 
 /// Generate round numbers.
 ///
-/// For Poseidon 1, we currently panic if the security level is not at
-/// the 128-bit or 256-bit level. This is because in the work by Ashur,
+/// For Poseidon 1, we currently return
+/// [`PoseidonParameterError::UnsupportedSecurityLevel`] if the security level is
+/// beyond the 256-bit level. This is because in the work by Ashur,
 /// Buschman, and Mahzoun 2023, they find the Grobner basis attacks are
 /// stronger than described in the original Poseidon paper, however they
 /// only find partial and full attacks beyond the 256-bit level [0]. The
 /// parameter generation logic for 128-bit and 256-bit security levels
-/// is unchanged.
+/// is unchanged. It also returns this error if no round numbers in the
+/// search range are secure.
 ///
 /// [0]: https://eprint.iacr.org/2023/537
-pub fn v1_generate<T: BigInteger>(input: &InputParameters<T>, alpha: &Alpha) -> RoundNumbers {
+pub fn v1_generate<T>(
+    input: &InputParameters<T>,
+    alpha: &Alpha,
+) -> Result<RoundNumbers, PoseidonParameterError> {
     let mut choice: Option<RoundNumbers> = None;
     let mut cost = usize::MAX;
     let mut cost_rf = usize::MAX;
 
     if input.M > 256 {
-        panic!("This crate does not support security levels beyond 256 bits, use Poseidon2");
+        return Err(PoseidonParameterError::UnsupportedSecurityLevel);
     }
 
     // Loop through choices of r_F, r_P
@@ -32,7 +36,7 @@ pub fn v1_generate<T: BigInteger>(input: &InputParameters<T>, alpha: &Alpha) ->
                 continue;
             }
 
-            candidate.apply_security_margin();
+            candidate.apply_security_margin(&input.security_margin);
             let candidate_cost = candidate.sbox_count(input.t);
             // Pick the minimum cost Candidate, and if the cost is tied with another
             // candidate, we switch to the new candidate if the total number of full rounds is lower.
@@ -44,11 +48,11 @@ pub fn v1_generate<T: BigInteger>(input: &InputParameters<T>, alpha: &Alpha) ->
         }
     }
 
-    choice.unwrap()
+    choice.ok_or(PoseidonParameterError::UnsupportedSecurityLevel)
 }
 
 /// Generate round numbers for Poseidon2.
-pub fn v2_generate<T: BigInteger>(input: &InputParameters<T>, alpha: &Alpha) -> RoundNumbers {
+pub fn v2_generate<T>(input: &InputParameters<T>, alpha: &Alpha) -> RoundNumbers {
     let mut choice: Option<RoundNumbers> = None;
     let mut cost = usize::MAX;
     let mut cost_rf = usize::MAX;
@@ -61,7 +65,7 @@ pub fn v2_generate<T: BigInteger>(input: &InputParameters<T>, alpha: &Alpha) ->
                 continue;
             }
 
-            candidate.apply_security_margin();
+            candidate.apply_security_margin(&input.security_margin);
             let candidate_cost = candidate.sbox_count(input.t);
             // Pick the minimum cost Candidate, and if the cost is tied with another
             // candidate, we switch to the new candidate if the total number of full rounds is lower.
@@ -80,7 +84,7 @@ struct RoundNumbersBuilder(pub RoundNumbers);
 
 impl RoundNumbersBuilder {
     /// Check if this `RoundNumbers` choice is secure given all known attacks.
-    fn is_secure_v1<T: BigInteger>(&self, input: &InputParameters<T>, alpha: &Alpha) -> bool {
+    fn is_secure_v1<T>(&self, input: &InputParameters<T>, alpha: &Alpha) -> bool {
         // Check if the number of full rounds are sufficient.
         if self.0.full() < statistical_attack_full_rounds(input, alpha) {
             return false;
@@ -119,7 +123,7 @@ impl RoundNumbersBuilder {
     }
 
     /// Check if this `RoundNumbers` choice is secure given all known attacks.
-    fn is_secure_v2<T: BigInteger>(&self, input: &InputParameters<T>, alpha: &Alpha) -> bool {
+    fn is_secure_v2<T>(&self, input: &InputParameters<T>, alpha: &Alpha) -> bool {
         // Check if the number of full rounds are sufficient.
         if self.0.full() < statistical_attack_full_rounds(input, alpha) {
             return false;
@@ -150,11 +154,12 @@ impl RoundNumbersBuilder {
         t * self.0.full() + self.0.partial()
     }
 
-    /// Add suggested security margin of +2 R_F and +7.5% R_P.
+    /// Add the security margin, by default the suggested +2 R_F and +7.5% R_P.
     /// Ref: Section 5.4.
-    fn apply_security_margin(&mut self) {
-        *self.0.full_mut() += 2;
-        *self.0.partial_mut() = (1.075 * (self.0.partial() as f64)).ceil() as usize;
+    fn apply_security_margin(&mut self, margin: &SecurityMargin) {
+        *self.0.full_mut() += margin.full_rounds();
+        *self.0.partial_mut() =
+            (margin.partial_rounds_factor() * (self.0.partial() as f64)).ceil() as usize;
     }
 }
 
@@ -162,7 +167,7 @@ impl RoundNumbersBuilder {
 ///
 /// These are the differential/linear distinguisher attacks described
 /// in Section 5.5.1 of the paper.
-fn statistical_attack_full_rounds<T: BigInteger>(
+pub(crate) fn statistical_attack_full_rounds<T>(
     input: &InputParameters<T>,
     alpha: &Alpha,
 ) -> usize {
@@ -189,7 +194,7 @@ fn statistical_attack_full_rounds<T: BigInteger>(
 /// These attacks are described in Section 5.5.2 of the paper.
 /// For positive alpha, we use Eqn 3.
 /// For negative alpha, we use Eqn 4.
-fn algebraic_attack_interpolation<T: BigInteger>(
+pub(crate) fn algebraic_attack_interpolation<T>(
     input: &InputParameters<T>,
     alpha: &Alpha,
 ) -> usize {
@@ -212,7 +217,7 @@ fn algebraic_attack_interpolation<T: BigInteger>(
 /// If the improved Grobner basis attacks from https://eprint.iacr.org/2023/537 are possible.
 ///
 /// See: https://github.com/HorizenLabs/poseidon2/commit/44bdcbc37887390442c7e743bad655a7ab8a7b7d
-fn algebraic_attack_grobner_basis_v2_possible<T: BigInteger>(
+fn algebraic_attack_grobner_basis_v2_possible<T>(
     input: &InputParameters<T>,
     alpha: &Alpha,
     choice: &RoundNumbers,
@@ -267,7 +272,7 @@ fn algebraic_attack_grobner_basis_v2_possible<T: BigInteger>(
 /// We use the first two conditions described in Section C.2.2,
 /// eliding the third since if the first condition is satisfied, then
 /// the third will be also.
-fn algebraic_attack_grobner_basis_v1<T: BigInteger>(
+pub(crate) fn algebraic_attack_grobner_basis_v1<T>(
     input: &InputParameters<T>,
     alpha: &Alpha,
 ) -> usize {
@@ -317,3 +322,63 @@ fn algebraic_attack_grobner_basis_v1<T: BigInteger>(
 fn cmp_f64(lhs: &&f64, rhs: &&f64) -> Ordering {
     lhs.partial_cmp(rhs).unwrap()
 }
+
+#[cfg(test)]
+mod tests {
+    use ark_ed_on_bls12_377::Fq;
+    use ark_ff::PrimeField;
+    use ark_pallas::Fr as PallasFr;
+
+    use super::*;
+
+    fn input(M: usize, margin: SecurityMargin) -> InputParameters<<Fq as PrimeField>::BigInt> {
+        InputParameters::generate(M, 3, Fq::MODULUS, false)
+            .with_security_margin(margin)
+            .unwrap()
+    }
+
+    #[test]
+    fn round_numbers_with_security_margin() {
+        let alpha = Alpha::Exponent(17);
+
+        // The margin of the Poseidon paper gives the rate-2 instance of `poseidon377`.
+        let default = input(128, SecurityMargin::default());
+        assert_eq!(
+            v1_generate(&default, &alpha),
+            Ok(RoundNumbers { r_P: 31, r_F: 8 })
+        );
+        assert_eq!(statistical_attack_full_rounds(&default, &alpha), 6);
+
+        // Without a margin, the round numbers are the minimum secure ones.
+        let none = SecurityMargin::new(0, 1.0).unwrap();
+        assert_eq!(
+            v1_generate(&input(128, none), &alpha),
+            Ok(RoundNumbers { r_P: 28, r_F: 6 })
+        );
+
+        let conservative = SecurityMargin::new(4, 1.5).unwrap();
+        assert_eq!(
+            v1_generate(&input(128, conservative), &alpha),
+            Ok(RoundNumbers { r_P: 42, r_F: 10 })
+        );
+
+        // The instances of `poseidon377::security_256`.
+        assert_eq!(
+            v1_generate(&input(256, SecurityMargin::default()), &alpha),
+            Ok(RoundNumbers { r_P: 63, r_F: 8 })
+        );
+        assert_eq!(
+            v1_generate(&input(512, SecurityMargin::default()), &alpha),
+            Err(PoseidonParameterError::UnsupportedSecurityLevel)
+        );
+    }
+
+    #[test]
+    fn round_numbers_over_pallas() {
+        let input = InputParameters::generate(128, 3, PallasFr::MODULUS, false);
+        assert_eq!(
+            v1_generate(&input, &Alpha::Exponent(5)),
+            Ok(RoundNumbers { r_P: 56, r_F: 8 })
+        );
+    }
+}
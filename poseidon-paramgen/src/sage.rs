@@ -0,0 +1,351 @@
+use std::fmt::Write;
+
+use ark_ff::PrimeField;
+use ark_std::vec::Vec;
+use num_bigint::BigUint;
+
+use decaf377::Fq;
+use poseidon_parameters::v1::{Alpha, DynMatrix, PoseidonParameterError};
+
+use crate::{
+    input::InputParameters,
+    utils::serialize_rows,
+    v1::{self, Derivation},
+};
+
+/// Create a self-contained Sage script cross-checking the v1 parameters generated for
+/// each width in `t_values`.
+///
+/// The script regenerates the S-box exponent, the round numbers, the MDS matrix, and the
+/// optimized MDS matrices and round constants with the algorithms of the Poseidon paper
+/// and its reference implementation, and asserts that they equal the generated ones.
+/// The round constants are squeezed from a Merlin transcript instead of the Grain LFSR
+/// of the reference implementation, so they are embedded as generated and only
+/// checked through the optimized round constants derived from them.
+///
+/// Run the script with `sage -python`, which exits with an error on the first mismatch.
+///
+/// The optimized constants are those of decaf377's `Fq`, so this fails with
+/// [`PoseidonParameterError::FieldMismatch`] for any other field.
+pub fn v1_compile<F: PrimeField>(
+    M: usize,
+    t_values: Vec<usize>,
+    p: F::BigInt,
+    allow_inverse: bool,
+) -> Result<String, PoseidonParameterError> {
+    let modulus: BigUint = p.into();
+    let mut sage_code = format!(
+        "{}\np = {modulus}\nM = {M}\nallow_inverse = {}\n\n{}",
+        HEADER,
+        if allow_inverse { "True" } else { "False" },
+        REFERENCE,
+    );
+
+    for t in t_values {
+        let input = InputParameters::generate(M, t, p, allow_inverse);
+        let params = v1::generate_dyn_from_input::<F>(&input, Derivation::Transcript)?;
+        let optimized = &params.optimized_mds;
+
+        let alpha = match params.alpha {
+            Alpha::Exponent(exp) => exp as i64,
+            Alpha::Inverse => -1,
+        };
+        let M_00 = BigUint::from_bytes_le(&optimized.M_00.to_bytes());
+
+        write!(
+            &mut sage_code,
+            r"
+# Parameters for the rate-{rate} instance of Poseidon.
+check_instance(
+    t={t},
+    alpha={alpha},
+    r_F={r_F},
+    r_P={r_P},
+    arc={arc},
+    mds={mds},
+    optimized_arc={optimized_arc},
+    M_hat={M_hat},
+    v={v},
+    w={w},
+    M_prime={M_prime},
+    M_doubleprime={M_doubleprime},
+    M_inverse={M_inverse},
+    M_hat_inverse={M_hat_inverse},
+    M_00={M_00},
+    M_i={M_i},
+    v_collection={v_collection},
+    w_hat_collection={w_hat_collection},
+)
+",
+            rate = t - 1,
+            r_F = params.rounds.full(),
+            r_P = params.rounds.partial(),
+            arc = serialize_rows(&rows::<F>(&params.arc)),
+            mds = serialize_rows(&rows::<F>(&params.mds)),
+            optimized_arc = serialize_rows(&rows::<F>(&params.optimized_arc)),
+            M_hat = serialize_rows(&rows::<F>(&optimized.M_hat)),
+            v = serialize_rows(&rows::<F>(&optimized.v)),
+            w = serialize_rows(&rows::<F>(&optimized.w)),
+            M_prime = serialize_rows(&rows::<F>(&optimized.M_prime)),
+            M_doubleprime = serialize_rows(&rows::<F>(&optimized.M_doubleprime)),
+            M_inverse = serialize_rows(&rows::<F>(&optimized.M_inverse)),
+            M_hat_inverse = serialize_rows(&rows::<F>(&optimized.M_hat_inverse)),
+            M_i = serialize_rows(&rows::<F>(&optimized.M_i)),
+            // Each vector is flattened to one row.
+            v_collection = serialize_rows(&flatten::<F>(&optimized.v_collection)),
+            w_hat_collection = serialize_rows(&flatten::<F>(&optimized.w_hat_collection)),
+        )
+        .expect("writing to a string cannot fail");
+    }
+
+    Ok(sage_code)
+}
+
+/// Map an element of `Fq` to `F`, which has the same modulus.
+fn from_fq<F: PrimeField>(x: &Fq) -> F {
+    F::from_le_bytes_mod_order(&x.to_bytes())
+}
+
+fn rows<F: PrimeField>(matrix: &DynMatrix) -> Vec<Vec<F>> {
+    matrix
+        .iter_rows()
+        .map(|row| row.iter().map(from_fq).collect())
+        .collect()
+}
+
+fn flatten<F: PrimeField>(matrices: &[DynMatrix]) -> Vec<Vec<F>> {
+    matrices
+        .iter()
+        .map(|matrix| matrix.elements().iter().map(from_fq).collect())
+        .collect()
+}
+
+const HEADER: &str = r"# Cross-check of Poseidon parameters generated by poseidon-paramgen.
+#
+# Every constant is regenerated with the algorithms of the Poseidon paper and its
+# reference implementation, and compared with the generated one. The round constants
+# are squeezed from a Merlin transcript instead of the Grain LFSR, so they are only
+# checked through the optimized round constants derived from them.
+#
+# Run with `sage -python`.
+
+from math import ceil, floor, gcd, log, log2
+
+from sage.all import GF, identity_matrix, matrix
+";
+
+/// The reference algorithms, parameterized by the globals `p`, `M` and
+/// `allow_inverse`.
+const REFERENCE: &str = r"F = GF(p)
+log_2_p = log2(p)
+
+# Candidate exponents by increasing length of their shortest addition chain.
+ADDITION_CHAINS = [
+    4, 3,
+    8, 6, 5,
+    16, 12, 10, 9, 7,
+    32, 24, 20, 18, 17, 15, 14, 13, 11,
+    64, 48, 40, 36, 34, 33, 30, 28, 27, 26, 25, 23, 22, 21, 19,
+    128, 96, 80, 72, 68, 66, 65, 60, 56, 54, 52, 51, 50, 49, 46, 45, 44, 43, 42, 41, 39,
+    38, 37, 35, 31, 29,
+]
+
+
+def generate_alpha():
+    '''The cheapest S-box exponent, with -1 standing for the inverse S-box.'''
+    for exp in ADDITION_CHAINS:
+        if exp % 2 == 1 and gcd(exp, p - 1) == 1:
+            return exp
+    return -1
+
+
+def statistical_full_rounds(t, alpha):
+    '''Section 5.5.1 of the paper.'''
+    C = 2.0 if alpha == -1 else log2(alpha - 1)
+    return 6 if M <= (floor(log_2_p) - C) * (t + 1.0) else 10
+
+
+def interpolation_rounds(t, alpha):
+    '''Eqn 3 for positive alpha and Eqn 4 for inverse alpha.'''
+    if alpha == -1:
+        return ceil(log2(t)) + ceil(0.5 * min(M, log_2_p))
+    return ceil(ceil(log(2.0, alpha) * min(M, log_2_p)) + log(t, alpha))
+
+
+def grobner_rounds(t, alpha):
+    '''The first two conditions of Section C.2.2 of the paper.'''
+    if alpha == -1:
+        grobner_1 = ceil(0.5 * min(M, log_2_p)) + ceil(log2(t))
+        grobner_2 = (t - 1) + ceil(log2(t)) + min(ceil(M / (t + 1.0)), ceil(0.5 * log_2_p))
+    else:
+        log_alpha_2 = log(2.0, alpha)
+        grobner_1 = log_alpha_2 * min(M / 3.0, log_2_p / 2.0)
+        grobner_2 = (t - 1) + min(log_alpha_2 * M / (t + 1.0), log_alpha_2 * log_2_p / 2.0)
+    return floor(max(grobner_1, grobner_2))
+
+
+def is_secure(t, alpha, r_F, r_P):
+    if r_F < statistical_full_rounds(t, alpha):
+        return False
+    weight = r_F + r_P if alpha != -1 else floor(r_F * log2(t)) + r_P
+    return weight > interpolation_rounds(t, alpha) and weight > grobner_rounds(t, alpha)
+
+
+def generate_rounds(t, alpha):
+    '''The secure round numbers with the fewest S-boxes, with the margin of Section 5.4.'''
+    choice, cost, cost_r_F = None, None, None
+    for r_P in range(1, 400):
+        for r_F in range(4, 100):
+            if not is_secure(t, alpha, r_F, r_P):
+                continue
+            candidate = (r_F + 2, ceil(1.075 * r_P))
+            candidate_cost = t * candidate[0] + candidate[1]
+            if cost is None or candidate_cost < cost or (candidate_cost == cost and r_F < cost_r_F):
+                choice, cost, cost_r_F = candidate, candidate_cost, r_F
+    return choice
+
+
+def generate_mds(t):
+    '''The Cauchy matrix with x_i = i and y_j = t + j.'''
+    return matrix(F, [[1 / F(i + t + j) for j in range(t)] for i in range(t)])
+
+
+def submatrix(A, rows, cols):
+    return matrix(F, [[A[i, j] for j in cols] for i in rows])
+
+
+def prime(M_hat):
+    '''The matrix M' of Appendix B, embedding M_hat below the identity.'''
+    n = M_hat.nrows()
+    return matrix(F, [[1] + [0] * n] + [[0] + [M_hat[i, j] for j in range(n)] for i in range(n)])
+
+
+def doubleprime(M_00, v, w_hat):
+    '''The sparse matrix M'' of Appendix B.'''
+    n = len(v)
+    identity = identity_matrix(F, n)
+    return matrix(F, [[M_00] + v] + [[w_hat[i]] + [identity[i, j] for j in range(n)] for i in range(n)])
+
+
+def calc_equivalent_matrices(mds, t, r_P):
+    '''From `calc_equivalent_matrices` in `poseidonperm_x3_64_24_optimized.sage`.'''
+    rest = range(1, t)
+    mds_transpose = mds.transpose()
+    M_mul = mds_transpose
+    M_i = identity_matrix(F, t)
+    v_collection, w_hat_collection = [], []
+    for _ in range(r_P):
+        M_hat = submatrix(M_mul, rest, rest)
+        w = submatrix(M_mul, rest, [0])
+        v_collection.append(submatrix(M_mul, [0], rest).list())
+        w_hat_collection.append((M_hat.inverse() * w).list())
+        M_i = prime(M_hat)
+        M_mul = mds_transpose * M_i
+    return M_i.transpose(), v_collection, w_hat_collection
+
+
+def calc_equivalent_constants(arc, mds, r_F, r_P):
+    '''From `calc_equivalent_constants` in `poseidonperm_x3_64_24_optimized.sage`.'''
+    t = mds.nrows()
+    constants = [list(row) for row in arc.rows()]
+    mds_transpose_inverse = mds.transpose().inverse()
+    for i in range(r_F // 2 + r_P - 2, r_F // 2 - 1, -1):
+        inv_cip1 = (matrix(F, [constants[i + 1]]) * mds_transpose_inverse).list()
+        constants[i] = [c + d for c, d in zip(constants[i], [0] + inv_cip1[1:])]
+        constants[i + 1] = [inv_cip1[0]] + [0] * (t - 1)
+    return matrix(F, constants)
+
+
+def check_instance(t, alpha, r_F, r_P, arc, mds, optimized_arc, M_hat, v, w, M_prime,
+                   M_doubleprime, M_inverse, M_hat_inverse, M_00, M_i, v_collection,
+                   w_hat_collection):
+    def check(name, expected, actual):
+        assert expected == actual, f'rate-{t - 1}: {name} does not match'
+
+    # The inverse S-box may be chosen over an exponent when it is allowed.
+    check('alpha', generate_alpha() if alpha != -1 or not allow_inverse else -1, alpha)
+    check('round numbers', generate_rounds(t, alpha), (r_F, r_P))
+
+    expected_mds = generate_mds(t)
+    check('MDS matrix', expected_mds, matrix(F, mds))
+
+    rest = range(1, t)
+    expected_M_hat = submatrix(expected_mds, rest, rest)
+    expected_v = submatrix(expected_mds, [0], rest)
+    expected_w = submatrix(expected_mds, rest, [0])
+    expected_w_hat = (expected_M_hat.inverse() * expected_w).list()
+    check('M_hat', expected_M_hat, matrix(F, M_hat))
+    check('v', expected_v, matrix(F, v))
+    check('w', expected_w, matrix(F, w))
+    check('M_prime', prime(expected_M_hat), matrix(F, M_prime))
+    check(
+        'M_doubleprime',
+        doubleprime(expected_mds[0, 0], expected_v.list(), expected_w_hat),
+        matrix(F, M_doubleprime),
+    )
+    check('M_prime * M_doubleprime', expected_mds, matrix(F, M_prime) * matrix(F, M_doubleprime))
+    check('M_inverse', expected_mds.inverse(), matrix(F, M_inverse))
+    check('M_hat_inverse', expected_M_hat.inverse(), matrix(F, M_hat_inverse))
+    check('M_00', expected_mds[0, 0], F(M_00))
+
+    expected_M_i, expected_v_collection, expected_w_hat_collection = calc_equivalent_matrices(
+        expected_mds, t, r_P
+    )
+    check('M_i', expected_M_i, matrix(F, M_i))
+    check('v_collection', [[F(x) for x in row] for row in v_collection], expected_v_collection)
+    check(
+        'w_hat_collection',
+        [[F(x) for x in row] for row in w_hat_collection],
+        expected_w_hat_collection,
+    )
+
+    check(
+        'optimized round constants',
+        calc_equivalent_constants(matrix(F, arc), expected_mds, r_F, r_P),
+        matrix(F, optimized_arc),
+    )
+    print(f'rate-{t - 1}: ok')
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ark_ed_on_bls12_377::Fq;
+    use ark_ff::Field;
+    use ark_pallas::Fr as PallasFr;
+
+    #[test]
+    fn sage_script() {
+        let code = v1_compile::<Fq>(128, vec![2, 3], Fq::MODULUS, false).unwrap();
+        assert!(code.contains("from sage.all import GF, identity_matrix, matrix"));
+        assert!(code.contains("allow_inverse = False"));
+        for rate in [1, 2] {
+            assert!(code.contains(&format!(
+                "# Parameters for the rate-{rate} instance of Poseidon."
+            )));
+        }
+        assert!(code.contains("    alpha=17,\n    r_F=8,\n    r_P=31,\n"));
+
+        // The round constants are embedded as generated, in decimal.
+        assert!(code.contains(
+            "    arc=[\n        [308026635595114235070436728341841505234226384644787941764356225291780075012, "
+        ));
+        // The first row of the Cauchy MDS matrix is 1 / (t + j).
+        let inverse = |x: u64| BigUint::from(Fq::from(x).inverse().unwrap());
+        assert!(code.contains(&format!(
+            "    mds=[\n        [{}, {}, {}],",
+            inverse(3),
+            inverse(4),
+            inverse(5)
+        )));
+    }
+
+    #[test]
+    fn sage_script_requires_fq() {
+        assert_eq!(
+            v1_compile::<PallasFr>(128, vec![3], PallasFr::MODULUS, false),
+            Err(PoseidonParameterError::FieldMismatch)
+        );
+    }
+}
@@ -1,9 +1,61 @@
 use crate::input::InputParameters;
 use ark_ff::{BigInteger, PrimeField};
-use ark_std::vec;
+use ark_std::{vec, vec::Vec};
 use merlin::Transcript;
 use poseidon_parameters::v1::{Alpha, RoundNumbers};
 
+/// The label of the transcript the round constants are squeezed from.
+pub(crate) const ROUND_CONSTANTS_LABEL: &[u8] = b"round-constants";
+
+/// The label of each round constant challenge.
+pub(crate) const ROUND_CONSTANT_CHALLENGE_LABEL: &[u8] = b"round-constant";
+
+/// The labelled messages binding the transcript to an instance, in order.
+pub(crate) fn domain_sep_messages<F: PrimeField>(
+    input: &InputParameters<F::BigInt>,
+    round_numbers: RoundNumbers,
+    alpha: Alpha,
+) -> Vec<(&'static [u8], Vec<u8>)> {
+    instance_messages(
+        input.t,
+        input.M,
+        input.p.to_bytes_le(),
+        round_numbers,
+        alpha,
+    )
+}
+
+/// The labelled messages binding the transcript to the instance of width `t` and
+/// security level `M` over the field whose modulus has the little-endian encoding `p`.
+///
+/// For the fields of arkworks, `p` is encoded with all the 64-bit limbs of the
+/// modulus, as `BigInteger::to_bytes_le` does.
+pub(crate) fn instance_messages(
+    t: usize,
+    M: usize,
+    p: Vec<u8>,
+    round_numbers: RoundNumbers,
+    alpha: Alpha,
+) -> Vec<(&'static [u8], Vec<u8>)> {
+    vec![
+        (b"dom-sep", b"poseidon-paramgen".to_vec()),
+        // Bind transcript to input parameter choices
+        (b"t", t.to_le_bytes().to_vec()),
+        (b"M", M.to_le_bytes().to_vec()),
+        (b"p", p),
+        // Bind transcript also to specific instance as done with the Grain LFSR
+        // in Appendix F of the Poseidon paper.
+        (b"r_F", vec![round_numbers.full() as u8]),
+        (b"r_P", vec![round_numbers.partial() as u8]),
+        (b"alpha", alpha.to_bytes_le().to_vec()),
+    ]
+}
+
+/// The number of bytes squeezed for each round constant, which is reduced modulo p.
+pub(crate) fn round_constant_len<F: PrimeField>() -> usize {
+    (F::MODULUS_BIT_SIZE as usize + 135) / 8
+}
+
 pub(crate) trait TranscriptProtocol {
     fn domain_sep<F: PrimeField>(
         &mut self,
@@ -12,6 +64,8 @@ pub(crate) trait TranscriptProtocol {
         alpha: Alpha,
     );
     fn round_constant<F: PrimeField>(&mut self) -> F;
+    fn internal_matrix_domain_sep<F: PrimeField>(&mut self, input: &InputParameters<F::BigInt>);
+    fn internal_matrix_diagonal<F: PrimeField>(&mut self) -> F;
 }
 
 impl TranscriptProtocol for Transcript {
@@ -21,23 +75,30 @@ impl TranscriptProtocol for Transcript {
         round_numbers: RoundNumbers,
         alpha: Alpha,
     ) {
+        for (label, message) in domain_sep_messages::<F>(input, round_numbers, alpha) {
+            self.append_message(label, &message);
+        }
+    }
+
+    fn round_constant<F: PrimeField>(&mut self) -> F {
+        let mut dest = vec![0u8; round_constant_len::<F>()];
+        self.challenge_bytes(ROUND_CONSTANT_CHALLENGE_LABEL, &mut dest);
+        F::from_le_bytes_mod_order(&dest)
+    }
+
+    fn internal_matrix_domain_sep<F: PrimeField>(&mut self, input: &InputParameters<F::BigInt>) {
         self.append_message(b"dom-sep", b"poseidon-paramgen");
-        // Bind transcript to input parameter choices
+        // The internal matrix does not depend on the round numbers or alpha, so
+        // only the input parameters are bound.
         self.append_message(b"t", &input.t.to_le_bytes());
         self.append_message(b"M", &input.M.to_le_bytes());
         self.append_message(b"p", &input.p.to_bytes_le());
-
-        // Bind transcript also to specific instance as done with the Grain LFSR
-        // in Appendix F of the Poseidon paper.
-        self.append_message(b"r_F", &[round_numbers.full() as u8]);
-        self.append_message(b"r_P", &[round_numbers.partial() as u8]);
-        self.append_message(b"alpha", &alpha.to_bytes_le());
     }
 
-    fn round_constant<F: PrimeField>(&mut self) -> F {
+    fn internal_matrix_diagonal<F: PrimeField>(&mut self) -> F {
         let size_in_bytes = (F::MODULUS_BIT_SIZE as usize + 135) / 8;
         let mut dest = vec![0u8; size_in_bytes];
-        self.challenge_bytes(b"round-constant", &mut dest);
+        self.challenge_bytes(b"internal-matrix-diagonal", &mut dest);
         F::from_le_bytes_mod_order(&dest)
     }
 }
@@ -1,13 +1,22 @@
 use ark_ff::BigInteger;
-use ark_std::convert::TryInto;
+use ark_std::{convert::TryInto, vec::Vec};
+use decaf377::Fq;
+use num::One;
 use num_bigint::BigUint;
 
+#[cfg(feature = "std")]
+use ark_ff::PrimeField;
+
 /// Computes the binary log of a `BigInteger`
 pub fn log2<P>(x: P) -> f64
 where
     P: BigInteger,
 {
-    let mut p_biguint: BigUint = x.into();
+    log2_biguint(x.into())
+}
+
+/// Computes the binary log of a `BigUint`, as [`log2`] does.
+pub fn log2_biguint(mut p_biguint: BigUint) -> f64 {
     let two_to_50: BigUint = 1125899906842624u64.into(); // 2**50
     let mut log_bit_boundaries = 0;
     while p_biguint > two_to_50 {
@@ -29,6 +38,45 @@ where
     log_bit_boundaries as f64 + ((x_u64) as f64).log2()
 }
 
+/// The modulus of decaf377's `Fq`, the field of the parameters of `poseidon-parameters`.
+pub(crate) fn fq_modulus() -> BigUint {
+    BigUint::from_bytes_le(&(-Fq::from(1u64)).to_bytes()) + BigUint::one()
+}
+
+/// The inverse of `x` modulo the prime `p`, or `0` if `x` is `0`.
+pub(crate) fn inverse(x: &BigUint, p: &BigUint) -> BigUint {
+    x.modpow(&(p - 2u32), p)
+}
+
+/// The little-endian encoding of `p` with all of its 64-bit limbs, as the modulus of
+/// an arkworks field of the same size is encoded.
+pub(crate) fn limb_bytes(p: &BigUint) -> Vec<u8> {
+    let mut bytes = p.to_bytes_le();
+    bytes.resize(((p.bits() as usize + 63) / 64) * 8, 0);
+    bytes
+}
+
+/// Serialize a matrix as a nested array literal of decimal field elements, as accepted
+/// by both Circom and Noir.
+#[cfg(feature = "std")]
+pub fn serialize_rows<F: PrimeField>(rows: &[Vec<F>]) -> String {
+    let rows: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let elements: Vec<String> = row
+                .iter()
+                .map(|elem| {
+                    // Go through BigUint to get a decimal representation.
+                    let elem_bigint: BigUint = (*elem).into();
+                    elem_bigint.to_string()
+                })
+                .collect();
+            format!("[{}]", elements.join(", "))
+        })
+        .collect();
+    format!("[\n        {}\n    ]", rows.join(",\n        "))
+}
+
 #[cfg(test)]
 mod tests {
     use ark_ed_on_bls12_381::Fq;
@@ -1,19 +1,97 @@
-use ark_ff::PrimeField;
+use ark_ff::{BigInteger, PrimeField};
+use ark_std::convert::TryFrom;
 
-use crate::{alpha, input::InputParameters, mds, round_constants, rounds};
-use poseidon_parameters::v1::PoseidonParameters;
+use crate::{input::InputParameters, rounds};
+use poseidon_parameters::v1::{
+    Alpha, DynPoseidonParameters, PoseidonParameterError, PoseidonParameters, RoundNumbers,
+};
 
 /// For generating parameters at build time.
 pub mod poseidon_build {
     pub use crate::poseidon_build::v1_compile as compile;
 }
 
+/// For generating Circom templates implementing the permutation.
+#[cfg(feature = "std")]
+pub mod circom {
+    pub use crate::circom::v1_compile as compile;
+}
+
+/// For generating Noir packages implementing the permutation.
+#[cfg(feature = "std")]
+pub mod noir {
+    pub use crate::noir::{v1_compile as compile, NoirPackage};
+}
+
+/// For generating Sage scripts cross-checking the parameters.
+#[cfg(feature = "std")]
+pub mod sage {
+    pub use crate::sage::v1_compile as compile;
+}
+
+/// For recording how parameters were generated, so that they can be re-derived.
+#[cfg(feature = "std")]
+pub mod provenance {
+    pub use crate::provenance::{
+        v1_record as record, v1_record_for_input as record_for_input,
+        v1_record_for_modulus as record_for_modulus, DerivationRecord, Provenance,
+    };
+}
+
+/// How the round constants and the MDS matrix are derived.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Derivation {
+    /// Squeeze the round constants from a Merlin transcript bound to the instance, and
+    /// use a fixed Cauchy matrix. This is how the `poseidon377` parameters are derived.
+    #[default]
+    Transcript,
+    /// Reproduce the round constants and MDS matrix of the reference implementation of
+    /// the Poseidon paper, derived from the Grain LFSR as used by circomlib and neptune.
+    GrainLfsr,
+}
+
+/// The round numbers of the instance [`generate_from_input`] generates for `input` with
+/// S-box `alpha`, without generating its constants.
+///
+/// These are the round numbers with the fewest S-boxes that are secure against the
+/// attacks of the Poseidon paper, plus the [`SecurityMargin`](crate::SecurityMargin) of
+/// `input`.
+///
+/// This fails with [`PoseidonParameterError::UnsupportedSecurityLevel`] if the security
+/// level is beyond 256 bits, see Poseidon2 instead.
+pub fn round_numbers<T: BigInteger>(
+    input: &InputParameters<T>,
+    alpha: Alpha,
+) -> Result<RoundNumbers, PoseidonParameterError> {
+    rounds::v1_generate(input, &alpha)
+}
+
+/// For generating instances over prime fields other than decaf377's Fq, as packaged by
+/// the field modules of `poseidon377`, such as `poseidon377::fp`.
+pub mod field {
+    #[cfg(feature = "std")]
+    pub use crate::field::v1_compile as compile;
+    pub use crate::field::{v1_generate as generate, FieldInstance};
+}
+
+/// For generating instances over the prime field of a modulus chosen at runtime.
+#[cfg(feature = "std")]
+pub mod modulus {
+    pub use crate::modulus::{
+        v1_alpha as alpha, v1_generate as generate, v1_generate_dyn as generate_dyn,
+        v1_generate_from_input as generate_from_input, ModulusInstance,
+    };
+}
+
 /// Generate a Poseidon instance mapped over Fp given a choice of:
 ///
 /// * M, the desired security level (in bits),
 /// * t, the width of the desired hash function, e.g. $t=3$ corresponds to 2-to-1 hash.
 /// * p, the prime modulus,
 /// * `allow_inverse`, whether or not to allow an inverse alpha.
+///
+/// The constants are derived with [`Derivation::Transcript`]. This fails as
+/// [`generate_from_input`] does.
 pub fn generate<
     F: PrimeField,
     const STATE_SIZE: usize,
@@ -23,35 +101,185 @@ pub fn generate<
     const NUM_ROUND_ROWS: usize,
     const NUM_ROUND_COLS: usize,
     const NUM_ROUND_ELEMENTS: usize,
+    const NUM_PARTIAL_ROUNDS: usize,
+>(
+    M: usize,
+    t: usize,
+    p: F::BigInt,
+    allow_inverse: bool,
+) -> Result<
+    PoseidonParameters<
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >,
+    PoseidonParameterError,
+> {
+    generate_with::<
+        F,
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >(M, t, p, allow_inverse, Derivation::Transcript)
+}
+
+/// Generate a Poseidon instance as [`generate`] does, deriving the round constants and
+/// the MDS matrix with `derivation`.
+pub fn generate_with<
+    F: PrimeField,
+    const STATE_SIZE: usize,
+    const STATE_SIZE_MINUS_1: usize,
+    const NUM_MDS_ELEMENTS: usize,
+    const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+    const NUM_ROUND_ROWS: usize,
+    const NUM_ROUND_COLS: usize,
+    const NUM_ROUND_ELEMENTS: usize,
+    const NUM_PARTIAL_ROUNDS: usize,
 >(
     M: usize,
     t: usize,
     p: F::BigInt,
     allow_inverse: bool,
-) -> PoseidonParameters<
-    STATE_SIZE,
-    STATE_SIZE_MINUS_1,
-    NUM_MDS_ELEMENTS,
-    NUM_STATE_SIZE_MINUS_1_ELEMENTS,
-    NUM_ROUND_ROWS,
-    NUM_ROUND_COLS,
-    NUM_ROUND_ELEMENTS,
+    derivation: Derivation,
+) -> Result<
+    PoseidonParameters<
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >,
+    PoseidonParameterError,
+> {
+    generate_from_input::<
+        F,
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >(
+        &InputParameters::generate(M, t, p, allow_inverse),
+        derivation,
+    )
+}
+
+/// Generate a Poseidon instance for `input`, deriving the round constants and the MDS
+/// matrix with `derivation`.
+///
+/// The round numbers include the [`SecurityMargin`](crate::SecurityMargin) of `input`,
+/// where [`generate_with`] always uses the margin suggested by the Poseidon paper.
+///
+/// The parameters are over decaf377's `Fq`, so this fails with
+/// [`PoseidonParameterError::FieldMismatch`] if `F` is another field, see
+/// [`field::generate`] instead. It fails with
+/// [`PoseidonParameterError::DimensionMismatch`] if the dimensions of the instance are
+/// not those of the requested type: for `t = STATE_SIZE`, the round numbers must be
+/// `NUM_ROUND_ROWS` in total, of which `NUM_PARTIAL_ROUNDS` are partial.
+pub fn generate_from_input<
+    F: PrimeField,
+    const STATE_SIZE: usize,
+    const STATE_SIZE_MINUS_1: usize,
+    const NUM_MDS_ELEMENTS: usize,
+    const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+    const NUM_ROUND_ROWS: usize,
+    const NUM_ROUND_COLS: usize,
+    const NUM_ROUND_ELEMENTS: usize,
+    const NUM_PARTIAL_ROUNDS: usize,
+>(
+    input: &InputParameters<F::BigInt>,
+    derivation: Derivation,
+) -> Result<
+    PoseidonParameters<
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >,
+    PoseidonParameterError,
 > {
-    let input = InputParameters::generate(M, t, p, allow_inverse);
-    let alpha = alpha::generate::<F>(p, allow_inverse);
-    let rounds = rounds::v1_generate(&input, &alpha);
-    let mds = mds::v1_generate(&input);
-    let arc = round_constants::v1_generate(&input, rounds, alpha);
-    let optimized_mds = mds::generate_optimized(&mds, t, &rounds);
-    let optimized_arc = round_constants::generate_optimized(&arc, &mds, &rounds);
-
-    PoseidonParameters::<F> {
-        M: input.M,
-        alpha,
-        rounds,
-        mds,
-        arc,
-        optimized_mds,
-        optimized_arc,
+    PoseidonParameters::try_from(&generate_dyn_from_input::<F>(input, derivation)?)
+}
+
+/// Generate the Poseidon instance of [`generate_from_input`] as
+/// [`DynPoseidonParameters`], whose dimensions are chosen at runtime.
+///
+/// This fails with [`PoseidonParameterError::FieldMismatch`] if `F` is not decaf377's
+/// `Fq`, and as [`field::generate`] does.
+pub fn generate_dyn_from_input<F: PrimeField>(
+    input: &InputParameters<F::BigInt>,
+    derivation: Derivation,
+) -> Result<DynPoseidonParameters, PoseidonParameterError> {
+    crate::field::v1_generate_from_input::<F>(input, derivation)?.to_dyn_parameters()
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ed_on_bls12_377::Fq;
+    use ark_pallas::Fr as PallasFr;
+    use poseidon_parameters::v1::MatrixOperations;
+
+    use super::*;
+
+    #[test]
+    fn generate_const_parameters() {
+        let params =
+            generate::<Fq, 3, 2, 9, 4, 39, 3, 117, 31>(128, 3, Fq::MODULUS, false).unwrap();
+        assert_eq!(params.alpha, Alpha::Exponent(17));
+        assert_eq!(params.rounds, RoundNumbers { r_P: 31, r_F: 8 });
+        assert_eq!(params.arc.n_rows(), 39);
+        assert_eq!(params.optimized_mds.v_collection.len(), 31);
+
+        // The dimensions of the type must be those of the instance.
+        assert_eq!(
+            generate::<Fq, 3, 2, 9, 4, 40, 3, 120, 32>(128, 3, Fq::MODULUS, false).err(),
+            Some(PoseidonParameterError::DimensionMismatch)
+        );
+        // The parameters are over decaf377's Fq.
+        assert_eq!(
+            generate::<PallasFr, 3, 2, 9, 4, 64, 3, 192, 56>(128, 3, PallasFr::MODULUS, false)
+                .err(),
+            Some(PoseidonParameterError::FieldMismatch)
+        );
+        // Security levels beyond 256 bits are not supported.
+        assert_eq!(
+            generate::<Fq, 3, 2, 9, 4, 39, 3, 117, 31>(512, 3, Fq::MODULUS, false).err(),
+            Some(PoseidonParameterError::UnsupportedSecurityLevel)
+        );
+    }
+
+    #[test]
+    fn round_numbers_include_security_margin() {
+        let margin = crate::SecurityMargin::new(4, 1.5).unwrap();
+        let input = InputParameters::generate(128, 3, Fq::MODULUS, false)
+            .with_security_margin(margin)
+            .unwrap();
+        let rounds = round_numbers(&input, Alpha::Exponent(17)).unwrap();
+        assert_eq!(rounds, RoundNumbers { r_P: 42, r_F: 10 });
+
+        let params =
+            generate_from_input::<Fq, 3, 2, 9, 4, 52, 3, 156, 42>(&input, Derivation::Transcript)
+                .unwrap();
+        assert_eq!(params.rounds, rounds);
     }
 }
@@ -1,10 +1,15 @@
 use ark_ff::PrimeField;
+use ark_std::{convert::TryInto, vec::Vec};
 
 mod external;
 mod internal;
+mod polynomial;
 
-use crate::{alpha, input::InputParameters, round_constants, rounds};
-use poseidon_parameters::v2::{PoseidonParameters, SquareMatrix};
+use crate::{alpha, field::to_fq, input::InputParameters, round_constants, rounds};
+use poseidon_parameters::{
+    v1::PoseidonParameterError,
+    v2::{ArcMatrix, PoseidonParameters, SquareMatrix},
+};
 
 /// For generating parameters at build time.
 pub mod poseidon_build {
@@ -17,6 +22,15 @@ pub mod poseidon_build {
 /// * t, the width of the desired hash function, e.g. $t=3$ corresponds to 2-to-1 hash.
 /// * p, the prime modulus,
 /// * `allow_inverse`, whether or not to allow an inverse alpha.
+///
+/// The external matrix of the Poseidon2 paper is only defined for $t \in \{2, 3\}$
+/// and for multiples of 4, so other widths are rejected. For $t \geq 4$, the
+/// diagonal of the internal matrix is sampled deterministically from a transcript,
+/// like the round constants.
+///
+/// Returns [`PoseidonParameterError::FieldMismatch`] if `F` is not decaf377's `Fq`,
+/// and [`PoseidonParameterError::DimensionMismatch`] if the const generics are not
+/// the dimensions of the instance.
 pub fn generate<
     F: PrimeField,
     const STATE_SIZE: usize,
@@ -29,38 +43,49 @@ pub fn generate<
     t: usize,
     p: F::BigInt,
     allow_inverse: bool,
-) -> PoseidonParameters<
-    STATE_SIZE,
-    NUM_MDS_ELEMENTS,
-    NUM_ROUND_ROWS,
-    NUM_ROUND_COLS,
-    NUM_ROUND_ELEMENTS,
+) -> Result<
+    PoseidonParameters<
+        STATE_SIZE,
+        NUM_MDS_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+    >,
+    PoseidonParameterError,
 > {
     let input = InputParameters::generate(M, t, p, allow_inverse);
-    let alpha = alpha::generate::<F>(p, allow_inverse);
+    let alpha = alpha::generate::<F>(p, allow_inverse)?;
     let rounds = rounds::v2_generate(&input, &alpha);
-    let arc = round_constants::v2_generate(&input, rounds, alpha);
-    let m_i: SquareMatrix<F> = internal::generate(t);
+    let arc: Vec<F> = round_constants::v2_generate(&input, rounds, alpha);
+    let m_i: Vec<Vec<F>> = internal::generate(&input);
 
     // We use the internal matrix also for the external rounds if t < 4.
-    if t < 4 {
-        PoseidonParameters::<F> {
-            M: input.M,
-            alpha,
-            rounds,
-            arc,
-            m_i: m_i.clone(),
-            m_e: m_i,
-        }
+    let m_e = if t < 4 {
+        m_i.clone()
     } else {
-        let m_e = external::generate(t);
-        PoseidonParameters::<F> {
-            M: input.M,
-            alpha,
-            rounds,
-            arc,
-            m_e,
-            m_i,
-        }
+        external::generate(t)
+    };
+
+    // The round constants are a single column, one row per constant.
+    if t != STATE_SIZE || NUM_ROUND_ROWS != arc.len() || NUM_ROUND_COLS != 1 {
+        return Err(PoseidonParameterError::DimensionMismatch);
     }
+    let square = |rows: &[Vec<F>]| -> Result<_, PoseidonParameterError> {
+        let elements = to_fq(rows.iter().flatten())?
+            .try_into()
+            .map_err(|_| PoseidonParameterError::DimensionMismatch)?;
+        Ok(SquareMatrix::<STATE_SIZE, NUM_MDS_ELEMENTS>::new_from_known(elements))
+    };
+    let arc = to_fq(&arc)?
+        .try_into()
+        .map_err(|_| PoseidonParameterError::DimensionMismatch)?;
+
+    Ok(PoseidonParameters {
+        M: input.M,
+        alpha,
+        rounds,
+        m_e: square(&m_e)?,
+        m_i: square(&m_i)?,
+        arc: ArcMatrix::new_from_known(arc),
+    })
 }
@@ -1,13 +1,8 @@
 use ark_ff::PrimeField;
-use poseidon_parameters::{
-    v2::MatrixOperations,
-    v2::{SquareMatrix, SquareMatrixOperations},
-};
-
-/// Generate external matrix
-pub fn generate<F: PrimeField, const STATE_SIZE: usize, const NUM_MDS_ELEMENTS: usize>(
-    t: usize,
-) -> SquareMatrix<STATE_SIZE, NUM_MDS_ELEMENTS> {
+use ark_std::{vec, vec::Vec};
+
+/// Generate external matrix, in rows
+pub fn generate<F: PrimeField>(t: usize) -> Vec<Vec<F>> {
     if t < 4 {
         // For t=[2, 3], we don't need to generate an external matrix
         // because we also use the internal matrix in the full rounds.
@@ -18,51 +13,24 @@ pub fn generate<F: PrimeField, const STATE_SIZE: usize, const NUM_MDS_ELEMENTS:
     }
 
     // For t>= 4, we use the following fixed matrix (Section 5.1, Poseidon2 paper).
-    let M4 = SquareMatrix::<F>::from_vec(vec![
-        F::from(5u64),
-        F::from(7u64),
-        F::one(),
-        F::from(3u64),
-        F::from(4u64),
-        F::from(6u64),
-        F::one(),
-        F::one(),
-        F::one(),
-        F::from(3u64),
-        F::from(5u64),
-        F::from(7u64),
-        F::one(),
-        F::one(),
-        F::from(4u64),
-        F::from(6u64),
-    ]);
+    let M4: Vec<Vec<F>> = [[5u64, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]
+        .iter()
+        .map(|row| row.iter().map(|&x| F::from(x)).collect())
+        .collect();
 
     if t == 4 {
         M4
     } else {
-        let mut matrix = SquareMatrix::<STATE_SIZE, NUM_MDS_ELEMENTS>::identity();
+        // The blocks on the diagonal are 2 * M4, and the others are M4.
+        let mut matrix = vec![vec![F::zero(); t]; t];
         let d = t / 4;
         for i in 0..d {
             for j in 0..d {
-                if i == j {
-                    for inner_row in 0..4 {
-                        for inner_col in 0..4 {
-                            matrix.set_element(
-                                i * 4 + inner_row,
-                                j * 4 + inner_col,
-                                F::from(2u64) * M4.get_element(inner_row, inner_col),
-                            )
-                        }
-                    }
-                } else {
-                    for inner_row in 0..4 {
-                        for inner_col in 0..4 {
-                            matrix.set_element(
-                                i * 4 + inner_row,
-                                j * 4 + inner_col,
-                                M4.get_element(inner_row, inner_col),
-                            )
-                        }
+                let scale = if i == j { F::from(2u64) } else { F::one() };
+                for inner_row in 0..4 {
+                    for inner_col in 0..4 {
+                        matrix[i * 4 + inner_row][j * 4 + inner_col] =
+                            scale * M4[inner_row][inner_col];
                     }
                 }
             }
@@ -73,122 +41,122 @@ pub fn generate<F: PrimeField, const STATE_SIZE: usize, const NUM_MDS_ELEMENTS:
 
 #[cfg(test)]
 mod tests {
-    use decaf377::Fq;
+    use ark_ed_on_bls12_377::Fq;
 
     use super::*;
 
     #[test]
     fn external_matrix_t_equals_4() {
-        let matrix: SquareMatrix<Fq> = generate(4);
+        let matrix: Vec<Vec<Fq>> = generate(4);
         // If t=4, the matrix should simply be the fixed M4, unmodified.
 
         // Row 0
-        assert_eq!(Fq::from(5u64), matrix.get_element(0, 0));
-        assert_eq!(Fq::from(7u64), matrix.get_element(0, 1));
-        assert_eq!(Fq::from(1u64), matrix.get_element(0, 2));
-        assert_eq!(Fq::from(3u64), matrix.get_element(0, 3));
+        assert_eq!(Fq::from(5u64), matrix[0][0]);
+        assert_eq!(Fq::from(7u64), matrix[0][1]);
+        assert_eq!(Fq::from(1u64), matrix[0][2]);
+        assert_eq!(Fq::from(3u64), matrix[0][3]);
 
         // Row 1
-        assert_eq!(Fq::from(4u64), matrix.get_element(1, 0));
-        assert_eq!(Fq::from(6u64), matrix.get_element(1, 1));
-        assert_eq!(Fq::from(1u64), matrix.get_element(1, 2));
-        assert_eq!(Fq::from(1u64), matrix.get_element(1, 3));
+        assert_eq!(Fq::from(4u64), matrix[1][0]);
+        assert_eq!(Fq::from(6u64), matrix[1][1]);
+        assert_eq!(Fq::from(1u64), matrix[1][2]);
+        assert_eq!(Fq::from(1u64), matrix[1][3]);
 
         // Row 2
-        assert_eq!(Fq::from(1u64), matrix.get_element(2, 0));
-        assert_eq!(Fq::from(3u64), matrix.get_element(2, 1));
-        assert_eq!(Fq::from(5u64), matrix.get_element(2, 2));
-        assert_eq!(Fq::from(7u64), matrix.get_element(2, 3));
+        assert_eq!(Fq::from(1u64), matrix[2][0]);
+        assert_eq!(Fq::from(3u64), matrix[2][1]);
+        assert_eq!(Fq::from(5u64), matrix[2][2]);
+        assert_eq!(Fq::from(7u64), matrix[2][3]);
 
         // Row 3
-        assert_eq!(Fq::from(1u64), matrix.get_element(3, 0));
-        assert_eq!(Fq::from(1u64), matrix.get_element(3, 1));
-        assert_eq!(Fq::from(4u64), matrix.get_element(3, 2));
-        assert_eq!(Fq::from(6u64), matrix.get_element(3, 3));
+        assert_eq!(Fq::from(1u64), matrix[3][0]);
+        assert_eq!(Fq::from(1u64), matrix[3][1]);
+        assert_eq!(Fq::from(4u64), matrix[3][2]);
+        assert_eq!(Fq::from(6u64), matrix[3][3]);
     }
 
     #[test]
     fn external_matrix_t_equals_8() {
-        let matrix: SquareMatrix<Fq> = generate(8);
+        let matrix: Vec<Vec<Fq>> = generate(8);
 
         // Row 0
-        assert_eq!(Fq::from(10u64), matrix.get_element(0, 0));
-        assert_eq!(Fq::from(14u64), matrix.get_element(0, 1));
-        assert_eq!(Fq::from(2u64), matrix.get_element(0, 2));
-        assert_eq!(Fq::from(6u64), matrix.get_element(0, 3));
-        assert_eq!(Fq::from(5u64), matrix.get_element(0, 4));
-        assert_eq!(Fq::from(7u64), matrix.get_element(0, 5));
-        assert_eq!(Fq::from(1u64), matrix.get_element(0, 6));
-        assert_eq!(Fq::from(3u64), matrix.get_element(0, 7));
+        assert_eq!(Fq::from(10u64), matrix[0][0]);
+        assert_eq!(Fq::from(14u64), matrix[0][1]);
+        assert_eq!(Fq::from(2u64), matrix[0][2]);
+        assert_eq!(Fq::from(6u64), matrix[0][3]);
+        assert_eq!(Fq::from(5u64), matrix[0][4]);
+        assert_eq!(Fq::from(7u64), matrix[0][5]);
+        assert_eq!(Fq::from(1u64), matrix[0][6]);
+        assert_eq!(Fq::from(3u64), matrix[0][7]);
 
         // Row 1
-        assert_eq!(Fq::from(8u64), matrix.get_element(1, 0));
-        assert_eq!(Fq::from(12u64), matrix.get_element(1, 1));
-        assert_eq!(Fq::from(2u64), matrix.get_element(1, 2));
-        assert_eq!(Fq::from(2u64), matrix.get_element(1, 3));
-        assert_eq!(Fq::from(4u64), matrix.get_element(1, 4));
-        assert_eq!(Fq::from(6u64), matrix.get_element(1, 5));
-        assert_eq!(Fq::from(1u64), matrix.get_element(1, 6));
-        assert_eq!(Fq::from(1u64), matrix.get_element(1, 7));
+        assert_eq!(Fq::from(8u64), matrix[1][0]);
+        assert_eq!(Fq::from(12u64), matrix[1][1]);
+        assert_eq!(Fq::from(2u64), matrix[1][2]);
+        assert_eq!(Fq::from(2u64), matrix[1][3]);
+        assert_eq!(Fq::from(4u64), matrix[1][4]);
+        assert_eq!(Fq::from(6u64), matrix[1][5]);
+        assert_eq!(Fq::from(1u64), matrix[1][6]);
+        assert_eq!(Fq::from(1u64), matrix[1][7]);
 
         // Row 2
-        assert_eq!(Fq::from(2u64), matrix.get_element(2, 0));
-        assert_eq!(Fq::from(6u64), matrix.get_element(2, 1));
-        assert_eq!(Fq::from(10u64), matrix.get_element(2, 2));
-        assert_eq!(Fq::from(14u64), matrix.get_element(2, 3));
-        assert_eq!(Fq::from(1u64), matrix.get_element(2, 4));
-        assert_eq!(Fq::from(3u64), matrix.get_element(2, 5));
-        assert_eq!(Fq::from(5u64), matrix.get_element(2, 6));
-        assert_eq!(Fq::from(7u64), matrix.get_element(2, 7));
+        assert_eq!(Fq::from(2u64), matrix[2][0]);
+        assert_eq!(Fq::from(6u64), matrix[2][1]);
+        assert_eq!(Fq::from(10u64), matrix[2][2]);
+        assert_eq!(Fq::from(14u64), matrix[2][3]);
+        assert_eq!(Fq::from(1u64), matrix[2][4]);
+        assert_eq!(Fq::from(3u64), matrix[2][5]);
+        assert_eq!(Fq::from(5u64), matrix[2][6]);
+        assert_eq!(Fq::from(7u64), matrix[2][7]);
 
         // Row 3
-        assert_eq!(Fq::from(2u64), matrix.get_element(3, 0));
-        assert_eq!(Fq::from(2u64), matrix.get_element(3, 1));
-        assert_eq!(Fq::from(8u64), matrix.get_element(3, 2));
-        assert_eq!(Fq::from(12u64), matrix.get_element(3, 3));
-        assert_eq!(Fq::from(1u64), matrix.get_element(3, 4));
-        assert_eq!(Fq::from(1u64), matrix.get_element(3, 5));
-        assert_eq!(Fq::from(4u64), matrix.get_element(3, 6));
-        assert_eq!(Fq::from(6u64), matrix.get_element(3, 7));
+        assert_eq!(Fq::from(2u64), matrix[3][0]);
+        assert_eq!(Fq::from(2u64), matrix[3][1]);
+        assert_eq!(Fq::from(8u64), matrix[3][2]);
+        assert_eq!(Fq::from(12u64), matrix[3][3]);
+        assert_eq!(Fq::from(1u64), matrix[3][4]);
+        assert_eq!(Fq::from(1u64), matrix[3][5]);
+        assert_eq!(Fq::from(4u64), matrix[3][6]);
+        assert_eq!(Fq::from(6u64), matrix[3][7]);
 
         // Row 4
-        assert_eq!(Fq::from(5u64), matrix.get_element(4, 0));
-        assert_eq!(Fq::from(7u64), matrix.get_element(4, 1));
-        assert_eq!(Fq::from(1u64), matrix.get_element(4, 2));
-        assert_eq!(Fq::from(3u64), matrix.get_element(4, 3));
-        assert_eq!(Fq::from(10u64), matrix.get_element(4, 4));
-        assert_eq!(Fq::from(14u64), matrix.get_element(4, 5));
-        assert_eq!(Fq::from(2u64), matrix.get_element(4, 6));
-        assert_eq!(Fq::from(6u64), matrix.get_element(4, 7));
+        assert_eq!(Fq::from(5u64), matrix[4][0]);
+        assert_eq!(Fq::from(7u64), matrix[4][1]);
+        assert_eq!(Fq::from(1u64), matrix[4][2]);
+        assert_eq!(Fq::from(3u64), matrix[4][3]);
+        assert_eq!(Fq::from(10u64), matrix[4][4]);
+        assert_eq!(Fq::from(14u64), matrix[4][5]);
+        assert_eq!(Fq::from(2u64), matrix[4][6]);
+        assert_eq!(Fq::from(6u64), matrix[4][7]);
 
         // Row 5
-        assert_eq!(Fq::from(4u64), matrix.get_element(5, 0));
-        assert_eq!(Fq::from(6u64), matrix.get_element(5, 1));
-        assert_eq!(Fq::from(1u64), matrix.get_element(5, 2));
-        assert_eq!(Fq::from(1u64), matrix.get_element(5, 3));
-        assert_eq!(Fq::from(8u64), matrix.get_element(5, 4));
-        assert_eq!(Fq::from(12u64), matrix.get_element(5, 5));
-        assert_eq!(Fq::from(2u64), matrix.get_element(5, 6));
-        assert_eq!(Fq::from(2u64), matrix.get_element(5, 7));
+        assert_eq!(Fq::from(4u64), matrix[5][0]);
+        assert_eq!(Fq::from(6u64), matrix[5][1]);
+        assert_eq!(Fq::from(1u64), matrix[5][2]);
+        assert_eq!(Fq::from(1u64), matrix[5][3]);
+        assert_eq!(Fq::from(8u64), matrix[5][4]);
+        assert_eq!(Fq::from(12u64), matrix[5][5]);
+        assert_eq!(Fq::from(2u64), matrix[5][6]);
+        assert_eq!(Fq::from(2u64), matrix[5][7]);
 
         // Row 6
-        assert_eq!(Fq::from(1u64), matrix.get_element(6, 0));
-        assert_eq!(Fq::from(3u64), matrix.get_element(6, 1));
-        assert_eq!(Fq::from(5u64), matrix.get_element(6, 2));
-        assert_eq!(Fq::from(7u64), matrix.get_element(6, 3));
-        assert_eq!(Fq::from(2u64), matrix.get_element(6, 4));
-        assert_eq!(Fq::from(6u64), matrix.get_element(6, 5));
-        assert_eq!(Fq::from(10u64), matrix.get_element(6, 6));
-        assert_eq!(Fq::from(14u64), matrix.get_element(6, 7));
+        assert_eq!(Fq::from(1u64), matrix[6][0]);
+        assert_eq!(Fq::from(3u64), matrix[6][1]);
+        assert_eq!(Fq::from(5u64), matrix[6][2]);
+        assert_eq!(Fq::from(7u64), matrix[6][3]);
+        assert_eq!(Fq::from(2u64), matrix[6][4]);
+        assert_eq!(Fq::from(6u64), matrix[6][5]);
+        assert_eq!(Fq::from(10u64), matrix[6][6]);
+        assert_eq!(Fq::from(14u64), matrix[6][7]);
 
         // Row 7
-        assert_eq!(Fq::from(1u64), matrix.get_element(7, 0));
-        assert_eq!(Fq::from(1u64), matrix.get_element(7, 1));
-        assert_eq!(Fq::from(4u64), matrix.get_element(7, 2));
-        assert_eq!(Fq::from(6u64), matrix.get_element(7, 3));
-        assert_eq!(Fq::from(2u64), matrix.get_element(7, 4));
-        assert_eq!(Fq::from(2u64), matrix.get_element(7, 5));
-        assert_eq!(Fq::from(8u64), matrix.get_element(7, 6));
-        assert_eq!(Fq::from(12u64), matrix.get_element(7, 7));
+        assert_eq!(Fq::from(1u64), matrix[7][0]);
+        assert_eq!(Fq::from(1u64), matrix[7][1]);
+        assert_eq!(Fq::from(4u64), matrix[7][2]);
+        assert_eq!(Fq::from(6u64), matrix[7][3]);
+        assert_eq!(Fq::from(2u64), matrix[7][4]);
+        assert_eq!(Fq::from(2u64), matrix[7][5]);
+        assert_eq!(Fq::from(8u64), matrix[7][6]);
+        assert_eq!(Fq::from(12u64), matrix[7][7]);
     }
 }
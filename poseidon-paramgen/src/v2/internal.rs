@@ -1,86 +1,142 @@
 use ark_ff::PrimeField;
-use poseidon_parameters::v2::{SquareMatrix, SquareMatrixOperations};
+use ark_std::{vec, vec::Vec};
+use merlin::Transcript;
 
-/// Generate internal matrix
+use super::polynomial::{characteristic_polynomial, is_invertible, mat_mul};
+use crate::{input::InputParameters, transcript::TranscriptProtocol};
+
+/// Generate internal matrix, in rows
 ///
 /// This matrix needs to be invertible, and no arbitrarily long
 /// subspace trails should exist.
-pub fn generate<F: PrimeField, const STATE_SIZE: usize, const NUM_MDS_ELEMENTS: usize>(
-    t: usize,
-) -> SquareMatrix<STATE_SIZE, NUM_MDS_ELEMENTS> {
-    let M_i: SquareMatrix<F>;
+pub fn generate<F: PrimeField>(input: &InputParameters<F::BigInt>) -> Vec<Vec<F>> {
+    let t = input.t;
+    let M_i: Vec<Vec<F>>;
 
     if t == 2 {
-        M_i = SquareMatrix::<F>::from_vec(vec![F::from(2u64), F::one(), F::one(), F::from(3u64)]);
+        M_i = vec![vec![F::from(2u64), F::one()], vec![F::one(), F::from(3u64)]];
     } else if t == 3 {
-        M_i = SquareMatrix::<F>::from_vec(vec![
-            F::from(2u64),
-            F::one(),
-            F::one(),
-            F::one(),
-            F::from(2u64),
-            F::one(),
-            F::one(),
-            F::one(),
-            F::from(3u64),
-        ]);
+        M_i = vec![
+            vec![F::from(2u64), F::one(), F::one()],
+            vec![F::one(), F::from(2u64), F::one()],
+            vec![F::one(), F::one(), F::from(3u64)],
+        ];
     } else {
-        // From Section 5.3 of the Poseidon2 paper, in lieu of implementing
-        // the three algorithms defined in Grassi et al. 2020 [0] to check
-        // for arbitrarily long subspace trails, we can instead check that the
-        // minimal polynomials of the matrices M_i, M_i^2, ..., are irreducible
-        // and of maximum degree. If that is true, then no arbitrarily long
-        // subspace trails exist. See Proposition 12 and its proof in [0].
-        //
-        // [0] https://eprint.iacr.org/2020/500
-        unimplemented!("internal matrix for t >= 4 not yet implemented")
+        // For t >= 4, the internal matrix is 1 off the diagonal, with diagonal
+        // entries sampled from a transcript bound to the input parameters, so the
+        // choice is deterministic. Candidates are rejected until one is found for
+        // which no arbitrarily long subspace trails exist.
+        let mut transcript = Transcript::new(b"internal-matrix");
+        transcript.internal_matrix_domain_sep::<F>(input);
+
+        M_i = loop {
+            let diagonal: Vec<F> = (0..t)
+                .map(|_| transcript.internal_matrix_diagonal())
+                .collect();
+            let rows: Vec<Vec<F>> = (0..t)
+                .map(|i| {
+                    (0..t)
+                        .map(|j| if i == j { diagonal[i] } else { F::one() })
+                        .collect()
+                })
+                .collect();
+            if has_no_invariant_subspace_trails(&rows) {
+                break rows;
+            }
+        };
     }
 
     // Check the matrix is invertible.
-    assert!(M_i.inverse().is_ok());
+    assert!(is_invertible(&M_i));
 
     M_i
 }
 
+/// Check that no arbitrarily long subspace trails exist for the internal rounds.
+///
+/// From Section 5.3 of the Poseidon2 paper, in lieu of implementing the three
+/// algorithms defined in Grassi et al. 2020 [0] to check for arbitrarily long
+/// subspace trails, we can instead check that the minimal polynomials of the
+/// matrices M_i, M_i^2, ..., M_i^{2t} are irreducible and of maximum degree. If
+/// that is true, then no arbitrarily long subspace trails exist. See Proposition 12
+/// and its proof in [0].
+///
+/// A minimal polynomial of maximum degree is the characteristic polynomial, so it
+/// suffices to check that the characteristic polynomials are irreducible.
+///
+/// [0] https://eprint.iacr.org/2020/500
+fn has_no_invariant_subspace_trails<F: PrimeField>(matrix: &[Vec<F>]) -> bool {
+    let t = matrix.len();
+    let mut power = matrix.to_vec();
+    for _ in 0..(2 * t) {
+        if !characteristic_polynomial(&power).is_irreducible() {
+            return false;
+        }
+        power = mat_mul(matrix, &power);
+    }
+    true
+}
+
 #[cfg(test)]
 mod tests {
     use ark_ed_on_bls12_377::Fq;
-    use poseidon_parameters::v2::MatrixOperations;
 
     use super::*;
 
+    fn input<F: PrimeField>(t: usize) -> InputParameters<F::BigInt> {
+        InputParameters::generate(128, t, F::MODULUS, false)
+    }
+
     #[test]
     fn internal_matrix_t_equals_2() {
-        let matrix: SquareMatrix<Fq> = generate(2);
+        let matrix: Vec<Vec<Fq>> = generate(&input::<Fq>(2));
         // The off-diagonal elements should be 1. The diagonals are non-zero.
 
         // Row 0
-        assert_eq!(Fq::from(2u64), matrix.get_element(0, 0));
-        assert_eq!(Fq::from(1u64), matrix.get_element(0, 1));
+        assert_eq!(Fq::from(2u64), matrix[0][0]);
+        assert_eq!(Fq::from(1u64), matrix[0][1]);
 
         // Row 1
-        assert_eq!(Fq::from(1u64), matrix.get_element(1, 0));
-        assert_eq!(Fq::from(3u64), matrix.get_element(1, 1));
+        assert_eq!(Fq::from(1u64), matrix[1][0]);
+        assert_eq!(Fq::from(3u64), matrix[1][1]);
     }
 
     #[test]
     fn internal_matrix_t_equals_3() {
-        let matrix: SquareMatrix<Fq> = generate(3);
+        let matrix: Vec<Vec<Fq>> = generate(&input::<Fq>(3));
         // The off-diagonal elements should be 1. The diagonals are non-zero.
 
         // Row 0
-        assert_eq!(Fq::from(2u64), matrix.get_element(0, 0));
-        assert_eq!(Fq::from(1u64), matrix.get_element(0, 1));
-        assert_eq!(Fq::from(1u64), matrix.get_element(0, 2));
+        assert_eq!(Fq::from(2u64), matrix[0][0]);
+        assert_eq!(Fq::from(1u64), matrix[0][1]);
+        assert_eq!(Fq::from(1u64), matrix[0][2]);
 
         // Row 1
-        assert_eq!(Fq::from(1u64), matrix.get_element(1, 0));
-        assert_eq!(Fq::from(2u64), matrix.get_element(1, 1));
-        assert_eq!(Fq::from(1u64), matrix.get_element(1, 2));
+        assert_eq!(Fq::from(1u64), matrix[1][0]);
+        assert_eq!(Fq::from(2u64), matrix[1][1]);
+        assert_eq!(Fq::from(1u64), matrix[1][2]);
 
         // Row 2
-        assert_eq!(Fq::from(1u64), matrix.get_element(2, 0));
-        assert_eq!(Fq::from(1u64), matrix.get_element(2, 1));
-        assert_eq!(Fq::from(3u64), matrix.get_element(2, 2));
+        assert_eq!(Fq::from(1u64), matrix[2][0]);
+        assert_eq!(Fq::from(1u64), matrix[2][1]);
+        assert_eq!(Fq::from(3u64), matrix[2][2]);
+    }
+
+    #[test]
+    fn internal_matrix_t_equals_4() {
+        let rows: Vec<Vec<Fq>> = generate(&input::<Fq>(4));
+
+        // The off-diagonal elements should be 1.
+        for (i, row) in rows.iter().enumerate() {
+            for (j, element) in row.iter().enumerate() {
+                if i != j {
+                    assert_eq!(Fq::from(1u64), *element);
+                }
+            }
+        }
+        assert!(has_no_invariant_subspace_trails(&rows));
+
+        // Generation is deterministic.
+        assert_eq!(rows, generate(&input::<Fq>(4)));
     }
 }
@@ -0,0 +1,231 @@
+use ark_ff::{BitIteratorBE, PrimeField};
+use ark_std::{vec, vec::Vec};
+
+/// A dense univariate polynomial, with coefficients in increasing order of degree.
+///
+/// The representation is kept normalized: there are no trailing zero coefficients,
+/// so the zero polynomial is the empty vector.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Polynomial<F: PrimeField>(Vec<F>);
+
+impl<F: PrimeField> Polynomial<F> {
+    /// Create a polynomial from its coefficients, in increasing order of degree.
+    pub(crate) fn new(mut coeffs: Vec<F>) -> Self {
+        while coeffs.last() == Some(&F::zero()) {
+            coeffs.pop();
+        }
+        Self(coeffs)
+    }
+
+    /// The polynomial $x$.
+    fn x() -> Self {
+        Self(vec![F::zero(), F::one()])
+    }
+
+    /// Degree of the polynomial, or `None` for the zero polynomial.
+    pub(crate) fn degree(&self) -> Option<usize> {
+        self.0.len().checked_sub(1)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        let len = self.0.len().max(rhs.0.len());
+        let coeffs = (0..len)
+            .map(|i| {
+                self.0.get(i).copied().unwrap_or_else(F::zero)
+                    - rhs.0.get(i).copied().unwrap_or_else(F::zero)
+            })
+            .collect();
+        Self::new(coeffs)
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Self(Vec::new());
+        }
+        let mut coeffs = vec![F::zero(); self.0.len() + rhs.0.len() - 1];
+        for (i, a) in self.0.iter().enumerate() {
+            for (j, b) in rhs.0.iter().enumerate() {
+                coeffs[i + j] += *a * b;
+            }
+        }
+        Self::new(coeffs)
+    }
+
+    /// Remainder of the division by the nonzero polynomial `modulus`.
+    fn rem(&self, modulus: &Self) -> Self {
+        let n = modulus.degree().expect("division by the zero polynomial");
+        let lead_inv = modulus.0[n]
+            .inverse()
+            .expect("leading coefficient is nonzero");
+        let mut coeffs = self.0.clone();
+        while coeffs.len() > n {
+            let top = coeffs.len() - 1;
+            let q = coeffs[top] * lead_inv;
+            for (i, m) in modulus.0.iter().enumerate() {
+                coeffs[top - n + i] -= q * m;
+            }
+            coeffs.pop();
+            while coeffs.last() == Some(&F::zero()) {
+                coeffs.pop();
+            }
+        }
+        Self::new(coeffs)
+    }
+
+    /// Compute `self^p mod modulus`, where `p` is the characteristic of the field.
+    fn pow_characteristic(&self, modulus: &Self) -> Self {
+        let mut result = Self(vec![F::one()]);
+        for bit in BitIteratorBE::without_leading_zeros(F::characteristic()) {
+            result = result.mul(&result).rem(modulus);
+            if bit {
+                result = result.mul(self).rem(modulus);
+            }
+        }
+        result
+    }
+
+    fn gcd(&self, rhs: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), rhs.clone());
+        while !b.is_zero() {
+            let r = a.rem(&b);
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    /// Check whether this polynomial is irreducible, using Rabin's test.
+    ///
+    /// A polynomial `f` of degree `n` is irreducible if and only if $f$ divides
+    /// $x^{p^n} - x$, and $\gcd(f, x^{p^{n/q}} - x) = 1$ for every prime $q$
+    /// dividing `n`.
+    pub(crate) fn is_irreducible(&self) -> bool {
+        let n = match self.degree() {
+            None | Some(0) => return false,
+            Some(n) => n,
+        };
+
+        // x_powers[k] = x^(p^k) mod f
+        let mut x_powers = vec![Self::x().rem(self)];
+        for k in 1..=n {
+            let next = x_powers[k - 1].pow_characteristic(self);
+            x_powers.push(next);
+        }
+
+        if !x_powers[n].sub(&Self::x()).rem(self).is_zero() {
+            return false;
+        }
+
+        prime_divisors(n).into_iter().all(|q| {
+            let g = self.gcd(&x_powers[n / q].sub(&Self::x()));
+            g.degree() == Some(0)
+        })
+    }
+}
+
+/// Distinct prime divisors of `n`.
+fn prime_divisors(mut n: usize) -> Vec<usize> {
+    let mut divisors = Vec::new();
+    let mut q = 2;
+    while q * q <= n {
+        if n % q == 0 {
+            divisors.push(q);
+            while n % q == 0 {
+                n /= q;
+            }
+        }
+        q += 1;
+    }
+    if n > 1 {
+        divisors.push(n);
+    }
+    divisors
+}
+
+/// Compute the characteristic polynomial of a square matrix given by its rows.
+///
+/// This uses the Faddeev-LeVerrier algorithm, which divides by `1..=t` and so
+/// requires the characteristic of the field to be larger than the matrix size.
+pub(crate) fn characteristic_polynomial<F: PrimeField>(matrix: &[Vec<F>]) -> Polynomial<F> {
+    let t = matrix.len();
+    let mut coeffs = vec![F::zero(); t + 1];
+    coeffs[t] = F::one();
+
+    // M_0 = 0, M_k = A M_{k-1} + c_{t-k+1} I, c_{t-k} = -tr(A M_k) / k
+    let mut m_k = vec![vec![F::zero(); t]; t];
+    for k in 1..=t {
+        let mut next = mat_mul(matrix, &m_k);
+        for (i, row) in next.iter_mut().enumerate() {
+            row[i] += coeffs[t - k + 1];
+        }
+        m_k = next;
+
+        let a_m_k = mat_mul(matrix, &m_k);
+        let trace: F = (0..t).map(|i| a_m_k[i][i]).sum();
+        coeffs[t - k] = -trace / F::from(k as u64);
+    }
+
+    Polynomial::new(coeffs)
+}
+
+/// Whether a square matrix given by its rows is invertible, i.e. whether the constant
+/// coefficient of its characteristic polynomial, which is its determinant up to sign, is
+/// non-zero.
+pub(crate) fn is_invertible<F: PrimeField>(matrix: &[Vec<F>]) -> bool {
+    characteristic_polynomial(matrix)
+        .0
+        .first()
+        .map_or(false, |det| *det != F::zero())
+}
+
+/// Multiply two square matrices given by their rows.
+pub(crate) fn mat_mul<F: PrimeField>(a: &[Vec<F>], b: &[Vec<F>]) -> Vec<Vec<F>> {
+    let t = a.len();
+    (0..t)
+        .map(|i| {
+            (0..t)
+                .map(|j| (0..t).map(|l| a[i][l] * b[l][j]).sum())
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ed_on_bls12_377::Fq;
+
+    use super::*;
+
+    #[test]
+    fn irreducibility() {
+        // (x - 1)(x - 2)
+        let reducible = Polynomial::new(vec![Fq::from(2u64), -Fq::from(3u64), Fq::one()]);
+        assert!(!reducible.is_irreducible());
+
+        // x^2 - n is irreducible if and only if n is a quadratic non-residue.
+        let non_residue = (2u64..)
+            .map(Fq::from)
+            .find(|n| n.legendre().is_qnr())
+            .unwrap();
+        let irreducible = Polynomial::new(vec![-non_residue, Fq::zero(), Fq::one()]);
+        assert!(irreducible.is_irreducible());
+        assert!(!irreducible.mul(&irreducible).is_irreducible());
+    }
+
+    #[test]
+    fn characteristic_polynomial_2x2() {
+        // [[2, 1], [1, 3]] has characteristic polynomial x^2 - 5x + 5.
+        let matrix = vec![
+            vec![Fq::from(2u64), Fq::one()],
+            vec![Fq::one(), Fq::from(3u64)],
+        ];
+        assert_eq!(
+            characteristic_polynomial(&matrix),
+            Polynomial::new(vec![Fq::from(5u64), -Fq::from(5u64), Fq::one()])
+        );
+    }
+}
@@ -0,0 +1,237 @@
+#![allow(non_snake_case)]
+
+use alloc::{vec, vec::Vec};
+
+use decaf377::Fq;
+use poseidon_parameters::v1::{Alpha, DynPoseidonParameters, PoseidonParameterError};
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::permutation::inverse_or_zero;
+
+/// Represents an instance of `Poseidon` whose width is chosen at runtime.
+///
+/// This computes the same permutation as [`Instance`](crate::Instance), over
+/// [`DynPoseidonParameters`], so that applications can select a rate or load
+/// parameters at runtime.
+///
+/// With the `zeroize` feature, the state is wiped when the instance is dropped, as are
+/// the temporary buffers used during the permutation.
+#[derive(Clone)]
+pub struct DynInstance<'a> {
+    /// Parameters for this instance of Poseidon.
+    parameters: &'a DynPoseidonParameters,
+
+    /// Inner state.
+    state_words: Vec<Fq>,
+}
+
+impl<'a> DynInstance<'a> {
+    /// Instantiate a new hash function over Fq given `parameters`.
+    ///
+    /// Returns an error if the dimensions of the parameters are inconsistent, as
+    /// checked by [`DynPoseidonParameters::check_dimensions`].
+    pub fn new(parameters: &'a DynPoseidonParameters) -> Result<Self, PoseidonParameterError> {
+        parameters.check_dimensions()?;
+        Ok(Self {
+            parameters,
+            state_words: vec![Fq::from(0u64); parameters.state_size()],
+        })
+    }
+
+    /// The width `t` of the permutation.
+    pub fn state_size(&self) -> usize {
+        self.state_words.len()
+    }
+
+    /// Fixed width hash from n:1. Outputs a F given `t` input words.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input_words` does not have [`DynInstance::state_size`] elements.
+    pub fn n_to_1_fixed_hash(&mut self, input_words: &[Fq]) -> Fq {
+        // Set internal state words.
+        self.state_words.copy_from_slice(input_words);
+
+        // Apply Poseidon permutation.
+        self.permute();
+
+        // Emit a single element since this is a n:1 hash.
+        self.state_words[1]
+    }
+
+    /// Reset the internal state to all zeros, so the instance can be reused for a new hash.
+    pub fn reset(&mut self) {
+        self.state_words.fill(Fq::from(0u64));
+    }
+
+    /// Print out internal state.
+    pub fn output_words(&self) -> &[Fq] {
+        &self.state_words
+    }
+
+    /// Mutable access to the internal state, e.g. for absorbing words in a sponge.
+    pub fn state_words_mut(&mut self) -> &mut [Fq] {
+        &mut self.state_words
+    }
+
+    /// Permutes the internal state.
+    ///
+    /// This follows [`Instance::permute`](crate::Instance::permute).
+    pub fn permute(&mut self) {
+        let parameters = self.parameters;
+        let R_f = parameters.rounds.full() / 2;
+
+        // First chunk of full rounds
+        for r in 0..R_f {
+            // Apply `AddRoundConstants` layer
+            for (word, constant) in self
+                .state_words
+                .iter_mut()
+                .zip(parameters.optimized_arc.iter_rows().nth(r).expect("round"))
+            {
+                *word += constant;
+            }
+            self.full_sub_words();
+            self.mix_layer(parameters.mds.elements());
+        }
+        let mut round_constants_counter = R_f;
+
+        // Partial rounds
+        // First part of `AddRoundConstants` layer
+        for (i, word) in self.state_words.iter_mut().enumerate() {
+            *word += parameters
+                .optimized_arc
+                .get_element(round_constants_counter, i);
+        }
+        // First full matrix multiplication.
+        self.mix_layer(parameters.optimized_mds.M_i.elements());
+
+        for r in 0..parameters.rounds.partial() - 1 {
+            self.partial_sub_words();
+            // Rest of `AddRoundConstants` layer, moved to after the S-box layer
+            round_constants_counter += 1;
+            self.state_words[0] += parameters
+                .optimized_arc
+                .get_element(round_constants_counter, 0);
+            self.sparse_mat_mul(parameters.rounds.partial() - r - 1);
+        }
+
+        // Last partial round
+        self.partial_sub_words();
+        self.sparse_mat_mul(0);
+        round_constants_counter += 1;
+
+        // Final full rounds
+        for _ in 0..R_f {
+            // Apply `AddRoundConstants` layer
+            for (i, word) in self.state_words.iter_mut().enumerate() {
+                *word += parameters
+                    .optimized_arc
+                    .get_element(round_constants_counter, i);
+            }
+            self.full_sub_words();
+            self.mix_layer(parameters.mds.elements());
+            round_constants_counter += 1;
+        }
+    }
+
+    /// Fixed width hash from n:1. Outputs a F given `t` input words. Unoptimized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input_words` does not have [`DynInstance::state_size`] elements.
+    pub fn unoptimized_n_to_1_fixed_hash(&mut self, input_words: &[Fq]) -> Fq {
+        // Set internal state words.
+        self.state_words.copy_from_slice(input_words);
+
+        // Apply Poseidon permutation.
+        self.unoptimized_permute();
+
+        // Emit a single element since this is a n:1 hash.
+        self.state_words[1]
+    }
+
+    /// Permutes the internal state, following `Instance::unoptimized_permute`.
+    fn unoptimized_permute(&mut self) {
+        let parameters = self.parameters;
+        let R_f = parameters.rounds.full() / 2;
+
+        for (r, round_constants) in parameters.arc.iter_rows().enumerate() {
+            // Apply `AddRoundConstants` layer
+            for (word, constant) in self.state_words.iter_mut().zip(round_constants) {
+                *word += constant;
+            }
+            if r < R_f || r >= R_f + parameters.rounds.partial() {
+                self.full_sub_words();
+            } else {
+                self.partial_sub_words();
+            }
+            self.mix_layer(parameters.mds.elements());
+        }
+    }
+
+    /// Applies the partial `SubWords` layer.
+    fn partial_sub_words(&mut self) {
+        self.state_words[0] = sbox(self.parameters.alpha, self.state_words[0]);
+    }
+
+    /// Applies the full `SubWords` layer.
+    fn full_sub_words(&mut self) {
+        let alpha = self.parameters.alpha;
+        for word in self.state_words.iter_mut() {
+            *word = sbox(alpha, *word);
+        }
+    }
+
+    /// Applies the `MixLayer` using the square `matrix`, in row-major order.
+    fn mix_layer(&mut self, matrix: &[Fq]) {
+        let new_state_words: Vec<Fq> = matrix
+            .chunks(self.state_words.len())
+            .map(|row| {
+                row.iter()
+                    .zip(&self.state_words)
+                    .map(|(x, y)| *x * *y)
+                    .sum()
+            })
+            .collect();
+        self.state_words.copy_from_slice(&new_state_words);
+        #[cfg(feature = "zeroize")]
+        {
+            let mut new_state_words = new_state_words;
+            new_state_words.iter_mut().for_each(Zeroize::zeroize);
+        }
+    }
+
+    /// This is `cheap_matrix_mul` in the Sage spec
+    fn sparse_mat_mul(&mut self, round_number: usize) {
+        self.parameters
+            .optimized_mds
+            .sparse_matrix(round_number)
+            .mul_in_place(&mut self.state_words);
+    }
+}
+
+fn sbox(alpha: Alpha, x: Fq) -> Fq {
+    match alpha {
+        Alpha::Exponent(exp) => x.power([exp as u64]),
+        Alpha::Inverse => inverse_or_zero(x),
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<'a> Zeroize for DynInstance<'a> {
+    fn zeroize(&mut self) {
+        self.state_words.iter_mut().for_each(Zeroize::zeroize);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<'a> Drop for DynInstance<'a> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<'a> ZeroizeOnDrop for DynInstance<'a> {}
@@ -2,9 +2,19 @@
 //! hashing.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+mod dynamic;
 mod permutation;
 
+#[cfg(feature = "alloc")]
+pub use dynamic::DynInstance;
 pub use permutation::Instance;
 
+/// The Poseidon2 permutation.
+pub mod v2;
+
 #[cfg(feature = "r1cs")]
 pub mod r1cs;
@@ -2,10 +2,16 @@
 
 use decaf377::Fq;
 use poseidon_parameters::v1::{Alpha, MatrixOperations, PoseidonParameters};
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Represents a generic instance of `Poseidon`.
 ///
 /// Intended for generic fixed-width hashing.
+///
+/// With the `zeroize` feature, the state is wiped when the instance is dropped, as are
+/// the temporary buffers used during the permutation.
+#[derive(Clone)]
 pub struct Instance<
     'a,
     const STATE_SIZE: usize,
@@ -57,6 +63,9 @@ impl<
     >
 {
     /// Instantiate a new hash function over Fq given `Parameters`.
+    ///
+    /// This is cheap: the optimized round constants and matrices are precomputed in
+    /// the `Parameters`, which are only borrowed here.
     pub fn new(
         parameters: &'a PoseidonParameters<
             STATE_SIZE,
@@ -89,16 +98,26 @@ impl<
         self.state_words[1]
     }
 
+    /// Reset the internal state to all zeros, so the instance can be reused for a new hash.
+    pub fn reset(&mut self) {
+        self.state_words = [Fq::from(0u64); STATE_SIZE];
+    }
+
     /// Print out internal state.
     pub fn output_words(&self) -> [Fq; STATE_SIZE] {
         self.state_words
     }
 
+    /// Mutable access to the internal state, e.g. for absorbing words in a sponge.
+    pub fn state_words_mut(&mut self) -> &mut [Fq; STATE_SIZE] {
+        &mut self.state_words
+    }
+
     /// Permutes the internal state.
     ///
     /// This implementation is based on the optimized Sage implementation
     /// `poseidonperm_x3_64_optimized.sage` provided in Appendix B of the Poseidon paper.
-    fn permute(&mut self) {
+    pub fn permute(&mut self) {
         let R_f = self.parameters.rounds.full() / 2;
 
         // First chunk of full rounds
@@ -219,7 +238,7 @@ impl<
     fn partial_sub_words(&mut self) {
         match self.parameters.alpha {
             Alpha::Exponent(exp) => self.state_words[0] = (self.state_words[0]).power([exp as u64]),
-            Alpha::Inverse => self.state_words[0] = Fq::from(1u64) / self.state_words[0],
+            Alpha::Inverse => self.state_words[0] = inverse_or_zero(self.state_words[0]),
         }
     }
 
@@ -233,7 +252,7 @@ impl<
             }
             Alpha::Inverse => {
                 for i in 0..STATE_SIZE {
-                    self.state_words[i] = Fq::from(1u64) / self.state_words[i];
+                    self.state_words[i] = inverse_or_zero(self.state_words[i]);
                 }
             }
         }
@@ -251,6 +270,8 @@ impl<
             new_state_words[i] = sum;
         }
         self.state_words = new_state_words;
+        #[cfg(feature = "zeroize")]
+        new_state_words.zeroize();
     }
 
     /// Applies the `MixLayer` using the MDS matrix.
@@ -266,32 +287,117 @@ impl<
             new_state_words[i] = sum;
         }
         self.state_words = new_state_words;
+        #[cfg(feature = "zeroize")]
+        new_state_words.zeroize();
     }
 
     /// This is `cheap_matrix_mul` in the Sage spec
     fn sparse_mat_mul(&mut self, round_number: usize) {
-        // mul_row = [(state_words[0] * v[i]) for i in range(0, t-1)]
-        // add_row = [(mul_row[i] + state_words[i+1]) for i in range(0, t-1)]
-        let mut add_row = [Fq::from(0u64); STATE_SIZE_MINUS_1];
-        for (i, x) in self.parameters.optimized_mds.v_collection[round_number]
-            .elements
-            .iter()
-            .enumerate()
-        {
-            add_row[i] = *x * self.state_words[0] + self.state_words[i + 1];
-        }
+        self.parameters
+            .optimized_mds
+            .sparse_matrix(round_number)
+            .mul_in_place(&mut self.state_words);
+    }
+}
 
-        // column_1 = [M_0_0] + w_hat
-        // state_words_new[0] = sum([column_1[i] * state_words[i] for i in range(0, t)])
-        // state_words_new = [state_words_new[0]] + add_row
-        self.state_words[0] = self.parameters.optimized_mds.M_00 * self.state_words[0]
-            + self.parameters.optimized_mds.w_hat_collection[round_number]
-                .elements
-                .iter()
-                .zip(self.state_words[1..STATE_SIZE].iter())
-                .map(|(x, y)| *x * *y)
-                .sum::<Fq>();
+/// The exponent `p - 2` of the inverse S-box, as little-endian limbs.
+const P_MINUS_2: [u64; 4] = [
+    0x0a117fffffffffff,
+    0x59aa76fed0000001,
+    0x60b44d1e5c37b001,
+    0x12ab655e9a2ca556,
+];
 
-        self.state_words[1..STATE_SIZE].copy_from_slice(&add_row[..(STATE_SIZE - 1)]);
+/// The inverse S-box, mapping zero to zero as in the Poseidon paper.
+///
+/// By Fermat's little theorem this is $x^{p - 2}$, computed by square-and-multiply
+/// over the bits of the fixed exponent `p - 2`, so the sequence of field operations
+/// depends only on the exponent and never on `x`. `Fq::inverse` is not used, as it
+/// returns early on zero and makes no constant-time guarantee.
+pub(crate) fn inverse_or_zero(x: Fq) -> Fq {
+    x.power(P_MINUS_2)
+}
+
+#[cfg(feature = "zeroize")]
+impl<
+        'a,
+        const STATE_SIZE: usize,
+        const STATE_SIZE_MINUS_1: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+        const NUM_PARTIAL_ROUNDS: usize,
+    > Zeroize
+    for Instance<
+        'a,
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >
+{
+    fn zeroize(&mut self) {
+        self.state_words.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<
+        'a,
+        const STATE_SIZE: usize,
+        const STATE_SIZE_MINUS_1: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+        const NUM_PARTIAL_ROUNDS: usize,
+    > Drop
+    for Instance<
+        'a,
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >
+{
+    fn drop(&mut self) {
+        self.zeroize();
     }
 }
+
+#[cfg(feature = "zeroize")]
+impl<
+        'a,
+        const STATE_SIZE: usize,
+        const STATE_SIZE_MINUS_1: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+        const NUM_PARTIAL_ROUNDS: usize,
+    > ZeroizeOnDrop
+    for Instance<
+        'a,
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >
+{
+}
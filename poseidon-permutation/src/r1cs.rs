@@ -1,12 +1,37 @@
 #![allow(non_snake_case)]
-use ark_std::vec::Vec;
+use ark_std::{convert::Infallible, vec::Vec};
 
+use ark_ff::One;
 use ark_r1cs_std::{fields::fp::FpVar, prelude::*};
-use ark_relations::r1cs::ConstraintSystemRef;
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
 use decaf377::Fq;
 use poseidon_parameters::v1::{Alpha, MatrixOperations, PoseidonParameters};
 
+/// Number of partial rounds between two allocations of the state with
+/// [`Strategy::Weight`].
+const WEIGHT_ALLOCATION_PERIOD: usize = 4;
+
+/// How the permutation gadget trades the number of constraints against the weight,
+/// the number of non-zero entries, of the constraint matrices.
+///
+/// Different proving systems have different cost models: the proving time of
+/// Groth16 is driven by the number of constraints, while systems such as Marlin
+/// are driven by the weight of the matrices.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Strategy {
+    /// Only constrain the S-boxes, merging every linear layer into the following
+    /// S-box constraints. This gives the fewest constraints and witnesses, but the
+    /// linear combinations of the partial rounds grow long.
+    #[default]
+    Constraints,
+    /// Also allocate the state every few partial rounds, keeping the linear
+    /// combinations short. For rate 4 this takes 385 rather than 350 constraints,
+    /// and 2319 rather than 3897 non-zero entries.
+    Weight,
+}
+
 /// Represents a Poseidon permutation instance.
+#[derive(Clone)]
 pub struct InstanceVar<
     const STATE_SIZE: usize,
     const STATE_SIZE_MINUS_1: usize,
@@ -87,8 +112,151 @@ impl<
         instance.state_words[1].clone()
     }
 
+    /// Fixed width hash from n:1, laying out the permutation with `strategy`.
+    pub fn n_to_1_fixed_hash_with_strategy(
+        parameters: PoseidonParameters<
+            STATE_SIZE,
+            STATE_SIZE_MINUS_1,
+            NUM_MDS_ELEMENTS,
+            NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+            NUM_ROUND_ROWS,
+            NUM_ROUND_COLS,
+            NUM_ROUND_ELEMENTS,
+            NUM_PARTIAL_ROUNDS,
+        >,
+        cs: ConstraintSystemRef<Fq>,
+        input_words: [FpVar<Fq>; STATE_SIZE],
+        strategy: Strategy,
+    ) -> Result<FpVar<Fq>, SynthesisError> {
+        let mut instance = InstanceVar {
+            parameters,
+            cs,
+            state_words: input_words.to_vec(),
+        };
+
+        instance.permute_with_strategy(strategy)?;
+
+        Ok(instance.state_words[1].clone())
+    }
+
+    /// Fixed width hash from n:1. Outputs a Fq given `t` input words. Unoptimized.
+    pub fn unoptimized_n_to_1_fixed_hash(
+        parameters: PoseidonParameters<
+            STATE_SIZE,
+            STATE_SIZE_MINUS_1,
+            NUM_MDS_ELEMENTS,
+            NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+            NUM_ROUND_ROWS,
+            NUM_ROUND_COLS,
+            NUM_ROUND_ELEMENTS,
+            NUM_PARTIAL_ROUNDS,
+        >,
+        cs: ConstraintSystemRef<Fq>,
+        input_words: [FpVar<Fq>; STATE_SIZE],
+    ) -> FpVar<Fq> {
+        let mut instance = InstanceVar {
+            parameters,
+            cs,
+            state_words: input_words.to_vec(),
+        };
+
+        // Apply Poseidon permutation.
+        instance.unoptimized_permute();
+
+        // Emit a single element since this is a n:1 hash.
+        instance.state_words[1].clone()
+    }
+
     /// Poseidon permutation.
+    ///
+    /// This follows the optimized native permutation: the round constants of the
+    /// partial rounds are moved to the first state word, and the MDS matrix of each
+    /// partial round is replaced by a sparse matrix, so each partial round only
+    /// takes `O(t)` linear combinations instead of `O(t^2)`. The number of
+    /// constraints is the same as for the unoptimized permutation, since the only
+    /// constraints are in the S-boxes, but fewer linear combinations are needed.
+    ///
+    /// Words of the state that only depend on constants stay constants, so the
+    /// S-boxes applied to them cost no constraints.
+    ///
+    /// This uses [`Strategy::Constraints`].
     pub fn permute(&mut self) {
+        // No witnesses are allocated between the partial rounds, so this cannot fail.
+        match self.optimized_permute(|_, _| Ok::<(), Infallible>(())) {
+            Ok(()) => {}
+            Err(never) => match never {},
+        }
+    }
+
+    /// Poseidon permutation, laid out with `strategy`.
+    ///
+    /// See [`InstanceVar::permute`]; with [`Strategy::Weight`] the state is also
+    /// allocated and constrained to its value every few partial rounds, which fails
+    /// if the state cannot be allocated.
+    pub fn permute_with_strategy(&mut self, strategy: Strategy) -> Result<(), SynthesisError> {
+        self.optimized_permute(|instance, r| {
+            if strategy == Strategy::Weight && (r + 1) % WEIGHT_ALLOCATION_PERIOD == 0 {
+                instance.allocate_state()?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Optimized Poseidon permutation, calling `after_partial_round` with the index
+    /// of each partial round but the last after it is applied.
+    fn optimized_permute<E>(
+        &mut self,
+        mut after_partial_round: impl FnMut(&mut Self, usize) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let R_f = self.parameters.rounds.full() / 2;
+        let R_P = self.parameters.rounds.partial();
+
+        // First chunk of full rounds
+        for r in 0..R_f {
+            self.add_round_constants(r);
+            self.full_sub_words();
+            self.mix_layer_mds();
+        }
+        let mut round_constants_counter = R_f;
+
+        // Partial rounds
+        // First part of `AddRoundConstants` layer
+        self.add_round_constants(round_constants_counter);
+        // First full matrix multiplication.
+        self.mix_layer_mi();
+
+        for r in 0..R_P - 1 {
+            self.partial_sub_words();
+            // Rest of `AddRoundConstants` layer, moved to after the S-box layer
+            round_constants_counter += 1;
+            self.state_words[0] += self
+                .parameters
+                .optimized_arc
+                .0
+                .get_element(round_constants_counter, 0);
+            self.sparse_mat_mul(R_P - r - 1);
+
+            after_partial_round(self, r)?;
+        }
+
+        // Last partial round
+        self.partial_sub_words();
+        self.sparse_mat_mul(0);
+        round_constants_counter += 1;
+
+        // Final full rounds
+        for _ in 0..R_f {
+            self.add_round_constants(round_constants_counter);
+            self.full_sub_words();
+            self.mix_layer_mds();
+            round_constants_counter += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Poseidon permutation, following the unoptimized specification.
+    fn unoptimized_permute(&mut self) {
         let R_f = self.parameters.rounds.full() / 2;
         let R_P = self.parameters.rounds.partial();
         let mut round_constants_counter = 0;
@@ -128,6 +296,28 @@ impl<
         }
     }
 
+    /// Replaces each word of the state which is not a constant by a new witness,
+    /// constrained to be equal to it.
+    fn allocate_state(&mut self) -> Result<(), SynthesisError> {
+        for word in self.state_words.iter_mut() {
+            if !word.is_constant() {
+                let value = word.value();
+                let allocated = FpVar::new_witness(self.cs.clone(), || value)?;
+                allocated.enforce_equal(word)?;
+                *word = allocated;
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies the `AddRoundConstants` layer with the optimized round constants of
+    /// round `round`.
+    fn add_round_constants(&mut self, round: usize) {
+        for i in 0..STATE_SIZE {
+            self.state_words[i] += self.parameters.optimized_arc.0.get_element(round, i);
+        }
+    }
+
     /// Applies the partial `SubWords` layer.
     fn partial_sub_words(&mut self) {
         match self.parameters.alpha {
@@ -156,6 +346,17 @@ impl<
         }
     }
 
+    /// Applies the `MixLayer` using the M_i matrix.
+    fn mix_layer_mi(&mut self) {
+        self.state_words = self
+            .parameters
+            .optimized_mds
+            .M_i
+            .iter_rows()
+            .map(|row| linear_combination(row.iter().copied(), &self.state_words))
+            .collect();
+    }
+
     /// Applies the `MixLayer` using the MDS matrix.
     fn mix_layer_mds(&mut self) {
         self.state_words = self
@@ -164,18 +365,55 @@ impl<
             .0
              .0
             .iter_rows()
-            .map(|row| {
-                let temp_vec: Vec<FpVar<Fq>> = row
-                    .iter()
-                    .zip(&self.state_words)
-                    .map(|(x, y)| {
-                        FpVar::<Fq>::new_constant(self.cs.clone(), x).expect("can create constant")
-                            * y
-                    })
-                    .collect();
-                let result = temp_vec.iter().sum();
-                result
+            .map(|row| linear_combination(row.iter().copied(), &self.state_words))
+            .collect();
+    }
+
+    /// This is `cheap_matrix_mul` in the Sage spec
+    fn sparse_mat_mul(&mut self, round_number: usize) {
+        let sparse_matrix = self.parameters.optimized_mds.sparse_matrix(round_number);
+
+        // add_row = [(state_words[0] * v[i] + state_words[i+1]) for i in range(0, t-1)]
+        let add_row: Vec<FpVar<Fq>> = sparse_matrix
+            .v
+            .iter()
+            .zip(&self.state_words[1..])
+            .map(|(v, word)| {
+                linear_combination(
+                    [*v, Fq::one()],
+                    &[self.state_words[0].clone(), word.clone()],
+                )
             })
             .collect();
+
+        // state_words_new[0] = M_0_0 * state_words[0] + w_hat . state_words[1..]
+        let first = linear_combination(
+            core::iter::once(sparse_matrix.M_00).chain(sparse_matrix.w_hat.iter().copied()),
+            &self.state_words,
+        );
+
+        self.state_words[0] = first;
+        for (word, new_word) in self.state_words[1..].iter_mut().zip(add_row) {
+            *word = new_word;
+        }
+    }
+}
+
+/// Compute `sum(coefficients[i] * words[i])` without allocating any constraint.
+fn linear_combination(
+    coefficients: impl IntoIterator<Item = Fq>,
+    words: &[FpVar<Fq>],
+) -> FpVar<Fq> {
+    let terms: Vec<FpVar<Fq>> = coefficients
+        .into_iter()
+        .zip(words)
+        .map(|(coefficient, word)| word * coefficient)
+        .collect();
+    // Summing `FpVar`s panics if they are all constants, which is the case as long
+    // as the state only depends on constants.
+    if terms.iter().all(|x| x.is_constant()) {
+        FpVar::constant(terms.iter().map(|x| x.value().expect("constant")).sum())
+    } else {
+        terms.iter().sum()
     }
 }
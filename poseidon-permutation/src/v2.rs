@@ -0,0 +1,225 @@
+#![allow(non_snake_case)]
+
+use decaf377::Fq;
+use poseidon_parameters::v2::{Alpha, MatrixOperations, PoseidonParameters};
+#[cfg(feature = "zeroize")]
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::permutation::inverse_or_zero;
+
+/// Represents a generic instance of `Poseidon2`.
+///
+/// Intended for generic fixed-width hashing.
+///
+/// With the `zeroize` feature, the state is wiped when the instance is dropped.
+#[derive(Clone)]
+pub struct Instance<
+    'a,
+    const STATE_SIZE: usize,
+    const NUM_MDS_ELEMENTS: usize,
+    const NUM_ROUND_ROWS: usize,
+    const NUM_ROUND_COLS: usize,
+    const NUM_ROUND_ELEMENTS: usize,
+> {
+    /// Parameters for this instance of Poseidon2.
+    parameters: &'a PoseidonParameters<
+        STATE_SIZE,
+        NUM_MDS_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+    >,
+
+    /// Inner state.
+    state_words: [Fq; STATE_SIZE],
+}
+
+impl<
+        'a,
+        const STATE_SIZE: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+    >
+    Instance<'a, STATE_SIZE, NUM_MDS_ELEMENTS, NUM_ROUND_ROWS, NUM_ROUND_COLS, NUM_ROUND_ELEMENTS>
+{
+    /// Instantiate a new hash function over Fq given `Parameters`.
+    pub fn new(
+        parameters: &'a PoseidonParameters<
+            STATE_SIZE,
+            NUM_MDS_ELEMENTS,
+            NUM_ROUND_ROWS,
+            NUM_ROUND_COLS,
+            NUM_ROUND_ELEMENTS,
+        >,
+    ) -> Self {
+        Self {
+            parameters,
+            state_words: [Fq::from(0u64); STATE_SIZE],
+        }
+    }
+
+    /// Fixed width hash from n:1. Outputs a F given `t` input words.
+    pub fn n_to_1_fixed_hash(&mut self, input_words: &[Fq; STATE_SIZE]) -> Fq {
+        self.state_words = *input_words;
+        self.permute();
+        self.state_words[1]
+    }
+
+    /// Print out internal state.
+    pub fn output_words(&self) -> [Fq; STATE_SIZE] {
+        self.state_words
+    }
+
+    /// Mutable access to the internal state, e.g. for absorbing words in a sponge.
+    pub fn state_words_mut(&mut self) -> &mut [Fq; STATE_SIZE] {
+        &mut self.state_words
+    }
+
+    /// Reset the internal state to all zeros, so the instance can be reused for a new hash.
+    pub fn reset(&mut self) {
+        self.state_words = [Fq::from(0u64); STATE_SIZE];
+    }
+
+    /// Permutes the internal state.
+    ///
+    /// The initial external linear layer is followed by `R_F / 2` external rounds,
+    /// `R_P` internal rounds and another `R_F / 2` external rounds, as in Section 5 of
+    /// the Poseidon2 paper. External rounds add `t` round constants, apply the S-box
+    /// to every word and multiply by `M_E`; internal rounds add a single round
+    /// constant to the first word, apply the S-box to it and multiply by `M_I`.
+    pub fn permute(&mut self) {
+        let R_f = self.parameters.rounds.full() / 2;
+        let mut round_constants_counter = 0;
+
+        self.external_linear_layer();
+
+        for _ in 0..R_f {
+            self.external_round(&mut round_constants_counter);
+        }
+
+        for _ in 0..self.parameters.rounds.partial() {
+            self.state_words[0] += self.parameters.arc.get_element(round_constants_counter, 0);
+            round_constants_counter += 1;
+            self.state_words[0] = self.sbox(self.state_words[0]);
+            self.internal_linear_layer();
+        }
+
+        for _ in 0..R_f {
+            self.external_round(&mut round_constants_counter);
+        }
+    }
+
+    /// Applies a single external round, consuming `t` round constants.
+    fn external_round(&mut self, round_constants_counter: &mut usize) {
+        for i in 0..STATE_SIZE {
+            self.state_words[i] += self.parameters.arc.get_element(*round_constants_counter, 0);
+            *round_constants_counter += 1;
+            self.state_words[i] = self.sbox(self.state_words[i]);
+        }
+        self.external_linear_layer();
+    }
+
+    /// Applies the S-box to a single word.
+    fn sbox(&self, x: Fq) -> Fq {
+        match self.parameters.alpha {
+            Alpha::Exponent(exp) => x.power([exp as u64]),
+            Alpha::Inverse => inverse_or_zero(x),
+        }
+    }
+
+    /// Multiplies the state by the external matrix `M_E`.
+    fn external_linear_layer(&mut self) {
+        let mut new_state_words = [Fq::from(0u64); STATE_SIZE];
+        for (i, row) in self.parameters.m_e.iter_rows().enumerate() {
+            new_state_words[i] = row
+                .iter()
+                .zip(&self.state_words)
+                .map(|(x, y)| *x * *y)
+                .sum();
+        }
+        self.state_words = new_state_words;
+        #[cfg(feature = "zeroize")]
+        new_state_words.zeroize();
+    }
+
+    /// Multiplies the state by the internal matrix `M_I`.
+    ///
+    /// The internal matrix has all off-diagonal entries equal to `1`, so this only
+    /// needs the sum of the state words and one multiplication per word by the
+    /// diagonal entry minus one.
+    fn internal_linear_layer(&mut self) {
+        let sum: Fq = self.state_words.iter().sum();
+        for i in 0..STATE_SIZE {
+            let diagonal = self.parameters.m_i.get_element(i, i) - Fq::from(1u64);
+            self.state_words[i] = sum + diagonal * self.state_words[i];
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<
+        'a,
+        const STATE_SIZE: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+    > Zeroize
+    for Instance<
+        'a,
+        STATE_SIZE,
+        NUM_MDS_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+    >
+{
+    fn zeroize(&mut self) {
+        self.state_words.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<
+        'a,
+        const STATE_SIZE: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+    > Drop
+    for Instance<
+        'a,
+        STATE_SIZE,
+        NUM_MDS_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+    >
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<
+        'a,
+        const STATE_SIZE: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+    > ZeroizeOnDrop
+    for Instance<
+        'a,
+        STATE_SIZE,
+        NUM_MDS_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+    >
+{
+}
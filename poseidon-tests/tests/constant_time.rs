@@ -0,0 +1,142 @@
+//! A dudect-style harness checking that hashing time does not depend on the input.
+//!
+//! Each test times a hash function on two classes of inputs, a fixed input chosen to
+//! hit special cases and uniformly random inputs, interleaved in random order, and
+//! applies Welch's t-test to the two timing distributions (see "Dude, is my code
+//! constant time?", Reparaz, Balasch and Verbauwhede, 2017). As in dudect, the test
+//! is repeated on the measurements below several percentiles to discard noise from
+//! interrupts and context switches, and fails if any `|t|` exceeds [`T_THRESHOLD`].
+//!
+//! Timing measurements are only meaningful in optimized builds on an otherwise idle
+//! machine, so these tests are ignored by default. CI runs them in a dedicated job, as
+//!
+//! ```text
+//! cargo test --release -p poseidon-tests --test constant_time -- --ignored --test-threads 1
+//! ```
+
+use std::time::Instant;
+
+use decaf377::Fq;
+use poseidon_parameters::v1::MatrixOperations;
+use rand_chacha::ChaCha20Rng;
+use rand_core::{RngCore, SeedableRng};
+
+/// Number of timed calls per test.
+const NUM_MEASUREMENTS: usize = 100_000;
+
+/// The `|t|` value above which dudect considers an implementation definitely not constant time.
+const T_THRESHOLD: f64 = 10.0;
+
+/// Percentiles at which the measurements are cropped before applying the t-test.
+const CROP_PERCENTILES: [f64; 5] = [1.0, 0.99, 0.95, 0.9, 0.8];
+
+fn random_fq(rng: &mut ChaCha20Rng) -> Fq {
+    let mut bytes = [0u8; 64];
+    rng.fill_bytes(&mut bytes);
+    Fq::from_le_bytes_mod_order(&bytes)
+}
+
+/// Welch's t statistic for two samples.
+fn welch_t(a: &[f64], b: &[f64]) -> f64 {
+    fn mean_var(x: &[f64]) -> (f64, f64) {
+        let n = x.len() as f64;
+        let mean = x.iter().sum::<f64>() / n;
+        let var = x.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / (n - 1.0);
+        (mean, var)
+    }
+    let (mean_a, var_a) = mean_var(a);
+    let (mean_b, var_b) = mean_var(b);
+    (mean_a - mean_b) / (var_a / a.len() as f64 + var_b / b.len() as f64).sqrt()
+}
+
+/// Time `f` on `fixed` and on random inputs and return the largest `|t|` over all crops.
+fn max_t<I: Copy, O>(
+    fixed: I,
+    mut random: impl FnMut(&mut ChaCha20Rng) -> I,
+    mut f: impl FnMut(I) -> O,
+) -> f64 {
+    let mut rng = ChaCha20Rng::seed_from_u64(0);
+
+    // Generate all inputs up front so only `f` is timed.
+    let inputs: Vec<(bool, I)> = (0..NUM_MEASUREMENTS)
+        .map(|_| {
+            if rng.next_u32() & 1 == 0 {
+                (false, fixed)
+            } else {
+                (true, random(&mut rng))
+            }
+        })
+        .collect();
+
+    let timings: Vec<(bool, f64)> = inputs
+        .iter()
+        .map(|(class, input)| {
+            let start = Instant::now();
+            std::hint::black_box(f(std::hint::black_box(*input)));
+            (*class, start.elapsed().as_nanos() as f64)
+        })
+        .collect();
+
+    let mut sorted: Vec<f64> = timings.iter().map(|(_, t)| *t).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    CROP_PERCENTILES
+        .iter()
+        .map(|percentile| {
+            let index = ((sorted.len() - 1) as f64 * percentile) as usize;
+            let cutoff = sorted[index];
+            let class = |c: bool| -> Vec<f64> {
+                timings
+                    .iter()
+                    .filter(|(class, t)| *class == c && *t <= cutoff)
+                    .map(|(_, t)| *t)
+                    .collect()
+            };
+            welch_t(&class(false), &class(true)).abs()
+        })
+        .fold(0.0, f64::max)
+}
+
+#[test]
+#[ignore]
+fn constant_time_hash_2() {
+    let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+    let zero = Fq::from(0u64);
+    let t = max_t(
+        (zero, zero),
+        |rng| (random_fq(rng), random_fq(rng)),
+        |input| poseidon377::hash_2(&domain_sep, input),
+    );
+    assert!(t < T_THRESHOLD, "|t| = {}", t);
+}
+
+#[test]
+#[ignore]
+fn constant_time_hash_varlen() {
+    let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+    let t = max_t(
+        [Fq::from(0u64); 9],
+        |rng| {
+            let mut input = [Fq::from(0u64); 9];
+            input.iter_mut().for_each(|x| *x = random_fq(rng));
+            input
+        },
+        |input| poseidon377::hash_varlen(&domain_sep, &input),
+    );
+    assert!(t < T_THRESHOLD, "|t| = {}", t);
+}
+
+#[test]
+#[ignore]
+fn constant_time_inverse_sbox() {
+    // Cancel out the round constants of the first round, so the first layer of
+    // S-boxes inverts zero.
+    let params = &poseidon377::inverse_sbox::RATE_2_PARAMS;
+    let arc = |i| -params.optimized_arc.0.get_element(0, i);
+    let t = max_t(
+        (arc(0), (arc(1), arc(2))),
+        |rng| (random_fq(rng), (random_fq(rng), random_fq(rng))),
+        |(domain_sep, input)| poseidon377::inverse_sbox::hash_2(&domain_sep, input),
+    );
+    assert!(t < T_THRESHOLD, "|t| = {}", t);
+}
@@ -0,0 +1,48 @@
+use poseidon377::{hash_varlen, Fq, PoseidonHash, ToFieldElements};
+
+#[derive(PoseidonHash)]
+struct Note {
+    amount: u64,
+    asset_id: Fq,
+    memo: [u8; 4],
+}
+
+#[derive(PoseidonHash)]
+struct Wrapper<T>(T, bool);
+
+#[derive(PoseidonHash)]
+struct Empty;
+
+#[test]
+fn derived_encoding_commits_to_fields() {
+    let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+    let note = Note {
+        amount: 7,
+        asset_id: Fq::from(9u64),
+        memo: *b"memo",
+    };
+
+    assert_eq!(
+        note.poseidon_hash(&domain_sep),
+        hash_varlen(
+            &domain_sep,
+            &[
+                Fq::from(3u64),
+                Fq::from(7u64),
+                Fq::from(9u64),
+                Fq::from_le_bytes_mod_order(b"memo"),
+            ]
+        )
+    );
+
+    let mut elements = Vec::new();
+    Wrapper(Fq::from(1u64), true).append_field_elements(&mut elements);
+    assert_eq!(
+        elements,
+        vec![Fq::from(2u64), Fq::from(1u64), Fq::from(1u64)]
+    );
+
+    let mut elements = Vec::new();
+    Empty.append_field_elements(&mut elements);
+    assert_eq!(elements, vec![Fq::from(0u64)]);
+}
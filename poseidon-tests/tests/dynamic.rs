@@ -0,0 +1,127 @@
+use core::convert::TryFrom;
+
+use decaf377::Fq;
+use poseidon_parameters::v1::{DynPoseidonParameters, PoseidonParameterError, PoseidonParameters};
+use poseidon_permutation::{DynInstance, Instance};
+use proptest::prelude::*;
+
+fn fq_strategy() -> BoxedStrategy<Fq> {
+    any::<[u8; 32]>()
+        .prop_map(|bytes| Fq::from_le_bytes_mod_order(&bytes[..]))
+        .boxed()
+}
+
+/// Check the runtime instance, loaded from the encoding of `parameters`, against
+/// the const-generic instance on the first `STATE_SIZE` of `words`.
+fn check_dyn_instance<
+    const STATE_SIZE: usize,
+    const STATE_SIZE_MINUS_1: usize,
+    const NUM_MDS_ELEMENTS: usize,
+    const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+    const NUM_ROUND_ROWS: usize,
+    const NUM_ROUND_COLS: usize,
+    const NUM_ROUND_ELEMENTS: usize,
+    const NUM_PARTIAL_ROUNDS: usize,
+>(
+    parameters: PoseidonParameters<
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >,
+    words: &[Fq],
+) {
+    let state: [Fq; STATE_SIZE] = core::array::from_fn(|i| words[i]);
+    let expected = Instance::new(&parameters).n_to_1_fixed_hash(&state);
+
+    let bytes = parameters.to_bytes();
+    let dyn_parameters = DynPoseidonParameters::from_bytes(&bytes).unwrap();
+    assert_eq!(dyn_parameters.state_size(), STATE_SIZE);
+    assert_eq!(dyn_parameters.to_bytes(), bytes);
+
+    // Conversions round-trip with the compiled-in parameters.
+    assert_eq!(DynPoseidonParameters::from(&parameters), dyn_parameters);
+    assert_eq!(
+        PoseidonParameters::try_from(&dyn_parameters).as_ref(),
+        Ok(&parameters)
+    );
+
+    let mut instance = DynInstance::new(&dyn_parameters).unwrap();
+    assert_eq!(instance.n_to_1_fixed_hash(&state), expected);
+    let output = instance.output_words().to_vec();
+    assert_eq!(instance.unoptimized_n_to_1_fixed_hash(&state), expected);
+    assert_eq!(instance.output_words(), &output[..]);
+}
+
+proptest! {
+#![proptest_config(ProptestConfig::with_cases(10))]
+#[test]
+fn dyn_instance_matches_instance(words in prop::collection::vec(fq_strategy(), 8)) {
+    check_dyn_instance(poseidon377::RATE_1_PARAMS, &words);
+    check_dyn_instance(poseidon377::RATE_2_PARAMS, &words);
+    check_dyn_instance(poseidon377::RATE_3_PARAMS, &words);
+    check_dyn_instance(poseidon377::RATE_4_PARAMS, &words);
+    check_dyn_instance(poseidon377::RATE_5_PARAMS, &words);
+    check_dyn_instance(poseidon377::RATE_6_PARAMS, &words);
+    check_dyn_instance(poseidon377::RATE_7_PARAMS, &words);
+    check_dyn_instance(poseidon377::inverse_sbox::RATE_2_PARAMS, &words);
+}
+}
+
+#[test]
+fn dyn_parameters_are_validated() {
+    let bytes = poseidon377::RATE_2_PARAMS.to_bytes();
+    let parameters = DynPoseidonParameters::from_bytes(&bytes).unwrap();
+
+    // The same checks as for the const-generic parameters.
+    assert_eq!(
+        DynPoseidonParameters::from_bytes(&bytes[..bytes.len() - 32]).unwrap_err(),
+        PoseidonParameterError::InvalidEncoding
+    );
+    let mut inconsistent = bytes.clone();
+    let m_00_offset = bytes.len() - 32 * (1 + 9 + 2 * 31 * 2);
+    inconsistent[m_00_offset] ^= 1;
+    assert_eq!(
+        DynPoseidonParameters::from_bytes(&inconsistent).unwrap_err(),
+        PoseidonParameterError::InconsistentParameters
+    );
+
+    // Matrices with the wrong dimensions are rejected by the instance.
+    let mut truncated = parameters.clone();
+    truncated.optimized_mds.v_collection.pop();
+    assert_eq!(
+        DynInstance::new(&truncated).err(),
+        Some(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+}
+
+#[test]
+fn dyn_parameters_convert_only_to_matching_dimensions() {
+    let parameters = DynPoseidonParameters::from(poseidon377::RATE_2_PARAMS);
+    assert_eq!(
+        PoseidonParameters::<3, 2, 9, 4, 39, 3, 117, 31>::try_from(parameters.clone()),
+        Ok(poseidon377::RATE_2_PARAMS)
+    );
+
+    // The same width with a different number of partial rounds.
+    assert_eq!(
+        PoseidonParameters::<3, 2, 9, 4, 68, 3, 204, 60>::try_from(&parameters),
+        Err(PoseidonParameterError::DimensionMismatch)
+    );
+    // A different width.
+    assert_eq!(
+        PoseidonParameters::<5, 4, 25, 16, 39, 5, 195, 31>::try_from(&parameters),
+        Err(PoseidonParameterError::DimensionMismatch)
+    );
+
+    let mut truncated = parameters;
+    truncated.optimized_mds.w_hat_collection.pop();
+    assert_eq!(
+        PoseidonParameters::<3, 2, 9, 4, 39, 3, 117, 31>::try_from(truncated),
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+}
@@ -0,0 +1,115 @@
+use ark_r1cs_std::{prelude::AllocVar, R1CSVar};
+use ark_relations::r1cs::ConstraintSystem;
+use decaf377::{r1cs::FqVar, Fq};
+use poseidon377::r1cs::{FixedHashGadget, HashGadget};
+use poseidon_parameters::v1::PoseidonParameters;
+use poseidon_permutation::{r1cs::InstanceVar, Instance};
+use proptest::prelude::*;
+
+fn fq_strategy() -> BoxedStrategy<Fq> {
+    any::<[u8; 32]>()
+        .prop_map(|bytes| Fq::from_le_bytes_mod_order(&bytes[..]))
+        .boxed()
+}
+
+/// Check both the optimized and unoptimized permutation gadgets against the
+/// native permutation on the first `STATE_SIZE` of `words`.
+fn check_instance<
+    const STATE_SIZE: usize,
+    const STATE_SIZE_MINUS_1: usize,
+    const NUM_MDS_ELEMENTS: usize,
+    const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+    const NUM_ROUND_ROWS: usize,
+    const NUM_ROUND_COLS: usize,
+    const NUM_ROUND_ELEMENTS: usize,
+    const NUM_PARTIAL_ROUNDS: usize,
+>(
+    parameters: PoseidonParameters<
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >,
+    words: &[Fq],
+) {
+    let state: [Fq; STATE_SIZE] = core::array::from_fn(|i| words[i]);
+    let expected = Instance::new(&parameters).n_to_1_fixed_hash(&state);
+
+    for optimized in [true, false] {
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let vars = state.map(|word| FqVar::new_witness(cs.clone(), || Ok(word)).unwrap());
+        let output = if optimized {
+            InstanceVar::n_to_1_fixed_hash(parameters.clone(), cs.clone(), vars)
+        } else {
+            InstanceVar::unoptimized_n_to_1_fixed_hash(parameters.clone(), cs.clone(), vars)
+        };
+
+        assert_eq!(
+            output.value().unwrap(),
+            expected,
+            "rate {} (optimized: {})",
+            STATE_SIZE - 1,
+            optimized
+        );
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
+
+/// Check the rate-`N` hash gadget against [`poseidon377::hash_fixed`], using the
+/// first word as the domain separator.
+fn check_gadget<const N: usize>(words: &[Fq])
+where
+    FixedHashGadget: HashGadget<N>,
+{
+    let input: [Fq; N] = core::array::from_fn(|i| words[i + 1]);
+    let expected = poseidon377::hash_fixed(&words[0], input);
+
+    let cs = ConstraintSystem::<Fq>::new_ref();
+    let domain_separator = FqVar::new_witness(cs.clone(), || Ok(words[0])).unwrap();
+    let input = input.map(|word| FqVar::new_witness(cs.clone(), || Ok(word)).unwrap());
+    let output = FixedHashGadget::hash(cs.clone(), &domain_separator, input).unwrap();
+
+    assert_eq!(output.value().unwrap(), expected, "rate {}", N);
+    assert!(cs.is_satisfied().unwrap());
+}
+
+proptest! {
+#![proptest_config(ProptestConfig::with_cases(10))]
+#[test]
+fn permutation_gadgets_match_native(words in prop::collection::vec(fq_strategy(), 17)) {
+    check_instance(poseidon377::RATE_1_PARAMS, &words);
+    check_instance(poseidon377::RATE_2_PARAMS, &words);
+    check_instance(poseidon377::RATE_3_PARAMS, &words);
+    check_instance(poseidon377::RATE_4_PARAMS, &words);
+    check_instance(poseidon377::RATE_5_PARAMS, &words);
+    check_instance(poseidon377::RATE_6_PARAMS, &words);
+    check_instance(poseidon377::RATE_7_PARAMS, &words);
+    check_instance(poseidon377::RATE_8_PARAMS, &words);
+    check_instance(poseidon377::RATE_9_PARAMS, &words);
+    check_instance(poseidon377::RATE_10_PARAMS, &words);
+    check_instance(poseidon377::RATE_11_PARAMS, &words);
+    check_instance(poseidon377::RATE_12_PARAMS, &words);
+    check_instance(poseidon377::RATE_13_PARAMS, &words);
+    check_instance(poseidon377::RATE_14_PARAMS, &words);
+    check_instance(poseidon377::RATE_15_PARAMS, &words);
+    check_instance(poseidon377::RATE_16_PARAMS, &words);
+}
+}
+
+proptest! {
+#![proptest_config(ProptestConfig::with_cases(10))]
+#[test]
+fn hash_gadgets_match_native(words in prop::collection::vec(fq_strategy(), 8)) {
+    check_gadget::<1>(&words);
+    check_gadget::<2>(&words);
+    check_gadget::<3>(&words);
+    check_gadget::<4>(&words);
+    check_gadget::<5>(&words);
+    check_gadget::<6>(&words);
+    check_gadget::<7>(&words);
+}
+}
@@ -1,9 +1,14 @@
 use decaf377::Fq;
 use proptest::prelude::*;
 
-use poseidon_parameters::v1::{mat_mul, square_mat_mul};
+use poseidon_parameters::v1::{mat_mul, mat_mul_into, square_mat_mul, square_mat_mul_into};
+use poseidon_parameters::v1::{optimized_arc, optimized_mds};
+use poseidon_parameters::v1::{Alpha, AttackMargin, DynMatrix, DynPoseidonParameters};
 use poseidon_parameters::v1::{Matrix, MatrixOperations};
+use poseidon_parameters::v1::{PoseidonParameterError, PoseidonParameters};
+use poseidon_parameters::v1::{RoundNumbers, SecurityMargins};
 use poseidon_parameters::v1::{SquareMatrix, SquareMatrixOperations};
+use poseidon_parameters::validate;
 
 #[test]
 fn identity_matrix() {
@@ -24,9 +29,9 @@ fn square_matmul() {
         Fq::from(3u64),
         Fq::from(4u64),
     ];
-    let matrix_2x2 = SquareMatrix::<2, 4>::new(elements);
+    let matrix_2x2 = SquareMatrix::<2, 4>::new(elements).unwrap();
 
-    let res: SquareMatrix<2, 4> = square_mat_mul(&matrix_2x2, &identity);
+    let res: SquareMatrix<2, 4> = square_mat_mul(&matrix_2x2, &identity).unwrap();
     assert_eq!(res.get_element(0, 0), Fq::from(1u64));
     assert_eq!(res.get_element(0, 1), Fq::from(2u64));
     assert_eq!(res.get_element(1, 0), Fq::from(3u64));
@@ -43,10 +48,10 @@ fn nonsquare_matmul_happy() {
         Fq::from(5u64),
         Fq::from(6u64),
     ];
-    let matrix_2x3 = Matrix::<3, 2, 6>::new(test_elements);
+    let matrix_2x3 = Matrix::<3, 2, 6>::new(test_elements).unwrap();
 
     let matrix_3x2 = matrix_2x3.transpose();
-    let res: Matrix<3, 3, 9> = mat_mul(&matrix_2x3, &matrix_3x2);
+    let res: Matrix<3, 3, 9> = mat_mul(&matrix_2x3, &matrix_3x2).unwrap();
     assert_eq!(res.get_element(0, 0), Fq::from(5u64));
     assert_eq!(res.get_element(0, 1), Fq::from(11u64));
     assert_eq!(res.get_element(0, 2), Fq::from(17u64));
@@ -58,6 +63,102 @@ fn nonsquare_matmul_happy() {
     assert_eq!(res.get_element(2, 2), Fq::from(61u64));
 }
 
+#[test]
+fn invalid_matrix_dimensions() {
+    let elements = [Fq::from(1u64); 6];
+    assert!(Matrix::<3, 2, 6>::new(&elements).is_ok());
+    assert_eq!(
+        Matrix::<3, 2, 6>::new(&elements[..5]),
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+    assert_eq!(
+        Matrix::<3, 2, 5>::new(&elements[..5]),
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+    assert_eq!(
+        SquareMatrix::<2, 4>::new(&elements),
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+
+    let matrix_3x2 = Matrix::<3, 2, 6>::new(&elements).unwrap();
+    let product: Result<Matrix<3, 2, 6>, _> = mat_mul(&matrix_3x2, &matrix_3x2);
+    assert_eq!(
+        product,
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+    let product: Result<Matrix<3, 3, 6>, _> = mat_mul(&matrix_3x2, &matrix_3x2.transpose());
+    assert_eq!(
+        product,
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+
+    let identity_2x2 = SquareMatrix::<2, 4>::identity();
+    let identity_3x3 = SquareMatrix::<3, 9>::identity();
+    let product: Result<SquareMatrix<2, 4>, _> = square_mat_mul(&identity_2x2, &identity_3x3);
+    assert_eq!(
+        product,
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+
+    assert!(DynMatrix::try_new(3, 2, elements.to_vec()).is_ok());
+    assert_eq!(
+        DynMatrix::try_new(2, 2, elements.to_vec()),
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+    assert_eq!(
+        DynMatrix::try_new(usize::MAX, 2, elements.to_vec()),
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+}
+
+#[test]
+fn mat_mul_into_output() {
+    let elements: Vec<Fq> = (1..=6u64).map(Fq::from).collect();
+    let matrix_3x2 = Matrix::<3, 2, 6>::new(&elements).unwrap();
+    let matrix_2x3 = matrix_3x2.transpose();
+
+    let mut out = Matrix::<3, 3, 9>::new(&[Fq::from(7u64); 9]).unwrap();
+    mat_mul_into(&matrix_3x2, &matrix_2x3, &mut out).unwrap();
+    assert_eq!(out, mat_mul(&matrix_3x2, &matrix_2x3).unwrap());
+    assert_eq!(out.get_element(2, 2), Fq::from(61u64));
+
+    // The output is left unchanged on error.
+    let mut out = Matrix::<3, 2, 6>::new(&elements).unwrap();
+    assert_eq!(
+        mat_mul_into(&matrix_3x2, &matrix_3x2, &mut out),
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+    assert_eq!(out, matrix_3x2);
+
+    let square = SquareMatrix::<2, 4>::new(&elements[..4]).unwrap();
+    let mut out = SquareMatrix::<2, 4>::identity();
+    square_mat_mul_into(&square, &square, &mut out).unwrap();
+    assert_eq!(out, square_mat_mul(&square, &square).unwrap());
+    assert_eq!(&square * Fq::from(2u64), square.clone() * Fq::from(2u64));
+    assert_eq!(
+        (&matrix_3x2 * Fq::from(2u64)).get_element(2, 1),
+        Fq::from(12u64)
+    );
+
+    let lhs = DynMatrix::new(3, 2, elements.clone());
+    let rhs = DynMatrix::new(2, 3, matrix_2x3.elements().to_vec());
+    let product = lhs.mat_mul(&rhs).unwrap();
+    let expected: Matrix<3, 3, 9> = mat_mul(&matrix_3x2, &matrix_2x3).unwrap();
+    assert_eq!(product.elements(), expected.elements());
+
+    // The output may have any dimensions beforehand.
+    let mut out = DynMatrix::new(1, 1, vec![Fq::from(1u64)]);
+    lhs.mat_mul_into(&rhs, &mut out).unwrap();
+    assert_eq!(out, product);
+    rhs.mat_mul_into(&lhs, &mut out).unwrap();
+    assert_eq!((out.n_rows(), out.n_cols()), (2, 2));
+    assert_eq!(
+        lhs.mat_mul_into(&lhs, &mut out),
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+    assert_eq!((out.n_rows(), out.n_cols()), (2, 2));
+}
+
 #[test]
 fn hadamard_product() {
     let test_elements: [Fq; 6] = [
@@ -68,7 +169,7 @@ fn hadamard_product() {
         Fq::from(5u64),
         Fq::from(6u64),
     ];
-    let matrix_3x2 = Matrix::<3, 2, 6>::new(&test_elements);
+    let matrix_3x2 = Matrix::<3, 2, 6>::new(&test_elements).unwrap();
 
     let res = matrix_3x2.hadamard_product(&matrix_3x2).expect("is ok");
     assert_eq!(res.get_element(0, 0), Fq::from(1u64));
@@ -89,7 +190,7 @@ fn transpose() {
         Fq::from(5u64),
         Fq::from(6u64),
     ];
-    let matrix_2x3 = Matrix::<3, 2, 6>::new(test_elements);
+    let matrix_2x3 = Matrix::<3, 2, 6>::new(test_elements).unwrap();
     assert_eq!(matrix_2x3.get_element(0, 1), Fq::from(2u64));
     assert_eq!(matrix_2x3.get_element(1, 0), Fq::from(3u64));
     assert_eq!(matrix_2x3.get_element(1, 1), Fq::from(4u64));
@@ -108,7 +209,7 @@ fn transpose() {
         Fq::from(3u64),
         Fq::from(4u64),
     ];
-    let matrix_2x2 = SquareMatrix::<2, 4>::new(test_elements);
+    let matrix_2x2 = SquareMatrix::<2, 4>::new(test_elements).unwrap();
 
     let res = matrix_2x2.transpose();
     assert_eq!(res.get_element(0, 0), Fq::from(1u64));
@@ -121,7 +222,7 @@ fn transpose() {
 fn cofactors() {
     let identity_1x1 = SquareMatrix::<1, 1>::identity();
     let test_elements = &[Fq::from(1u64)];
-    let expected_res = SquareMatrix::new(test_elements);
+    let expected_res = SquareMatrix::new(test_elements).unwrap();
     assert_eq!(identity_1x1.cofactors(), expected_res);
 
     let identity_2x2 = SquareMatrix::<2, 4>::identity();
@@ -131,7 +232,7 @@ fn cofactors() {
         -Fq::from(1u64),
         Fq::from(1u64),
     ];
-    let expected_res = SquareMatrix::new(test_elements);
+    let expected_res = SquareMatrix::new(test_elements).unwrap();
     assert_eq!(identity_2x2.cofactors(), expected_res);
 }
 
@@ -144,19 +245,19 @@ fn fq_strategy() -> BoxedStrategy<Fq> {
 proptest! {
     #[test]
     fn inverse_2x2(a in fq_strategy(), b in fq_strategy(), c in fq_strategy(), d in fq_strategy()) {
-        let matrix_2x2 = SquareMatrix::<2, 4>::new(&[a, b, c, d]);
+        let matrix_2x2 = SquareMatrix::<2, 4>::new(&[a, b, c, d]).unwrap();
 
         let res = matrix_2x2.inverse().unwrap();
-        assert_eq!(square_mat_mul(&matrix_2x2, &res), SquareMatrix::<2, 4>::identity());
+        assert_eq!(square_mat_mul(&matrix_2x2, &res).unwrap(), SquareMatrix::<2, 4>::identity());
     }
 }
 
 #[test]
 fn inverse() {
-    let matrix_1x1 = SquareMatrix::<1, 1>::new(&[Fq::from(2u64)]);
+    let matrix_1x1 = SquareMatrix::<1, 1>::new(&[Fq::from(2u64)]).unwrap();
     let res = matrix_1x1.inverse().unwrap();
     assert_eq!(
-        square_mat_mul(&matrix_1x1, &res),
+        square_mat_mul(&matrix_1x1, &res).unwrap(),
         SquareMatrix::<1, 1>::identity()
     );
 
@@ -165,11 +266,12 @@ fn inverse() {
         Fq::from(2u64),
         Fq::from(3u64),
         Fq::from(4u64),
-    ]);
+    ])
+    .unwrap();
 
     let res = matrix_2x2.inverse().unwrap();
     assert_eq!(
-        square_mat_mul(&matrix_2x2, &res),
+        square_mat_mul(&matrix_2x2, &res).unwrap(),
         SquareMatrix::<2, 4>::identity()
     );
 
@@ -186,10 +288,11 @@ fn inverse() {
         Fq::from(0u64),
         Fq::from(1u64),
         Fq::from(1u64),
-    ]);
+    ])
+    .unwrap();
     let res = matrix_3x3.inverse().unwrap();
     assert_eq!(
-        square_mat_mul(&matrix_3x3, &res),
+        square_mat_mul(&matrix_3x3, &res).unwrap(),
         SquareMatrix::<3, 9>::identity()
     );
 
@@ -203,7 +306,9 @@ fn inverse() {
         Fq::from(2u64),
         -Fq::from(3u64),
         Fq::from(0u64),
-    ]) * (Fq::from(1u64) / Fq::from(10u64));
+    ])
+    .unwrap()
+        * (Fq::from(1u64) / Fq::from(10u64));
     assert_eq!(res, expected_res);
 }
 
@@ -214,7 +319,8 @@ fn create_matrix_from_array() {
         Fq::from(2u64),
         Fq::from(3u64),
         Fq::from(4u64),
-    ]);
+    ])
+    .unwrap();
     assert_eq!(matrix_2x2.get_element(0, 0), Fq::from(1u64));
     assert_eq!(matrix_2x2.get_element(0, 1), Fq::from(2u64));
     assert_eq!(matrix_2x2.get_element(1, 0), Fq::from(3u64));
@@ -227,7 +333,8 @@ fn create_matrix_from_array() {
         Fq::from(4u64),
         Fq::from(5u64),
         Fq::from(6u64),
-    ]);
+    ])
+    .unwrap();
     assert_eq!(matrix_2x3.get_element(0, 0), Fq::from(1u64));
     assert_eq!(matrix_2x3.get_element(0, 1), Fq::from(2u64));
     assert_eq!(matrix_2x3.get_element(0, 2), Fq::from(3u64));
@@ -238,48 +345,620 @@ fn create_matrix_from_array() {
 
 #[test]
 fn determinant() {
-    let matrix_1x1 = SquareMatrix::<1, 1>::new(&[Fq::from(1u64)]);
-    assert_eq!(matrix_1x1.determinant(), Fq::from(1u64));
+    let matrix_1x1 = SquareMatrix::<1, 1>::new(&[Fq::from(1u64)]).unwrap();
+    assert_eq!(matrix_1x1.determinant().unwrap(), Fq::from(1u64));
 
     let a = Fq::from(1u64);
     let b = Fq::from(1u64) + Fq::from(1u64);
     let c = Fq::from(3u64);
     let d = Fq::from(4u64);
-    let matrix_2x2 = SquareMatrix::<2, 4>::new(&[a, b, c, d]);
-    assert_eq!(matrix_2x2.determinant(), -Fq::from(2u64));
+    let matrix_2x2 = SquareMatrix::<2, 4>::new(&[a, b, c, d]).unwrap();
+    assert_eq!(matrix_2x2.determinant().unwrap(), -Fq::from(2u64));
 
     let e = Fq::from(5u64);
     let f = Fq::from(6u64);
     let g = Fq::from(7u64);
     let h = Fq::from(8u64);
     let i = Fq::from(9u64);
-    let matrix_3x3 = SquareMatrix::<3, 9>::new(&[a, b, c, d, e, f, g, h, i]);
-    assert_eq!(matrix_3x3.determinant(), Fq::from(0u64));
+    let matrix_3x3 = SquareMatrix::<3, 9>::new(&[a, b, c, d, e, f, g, h, i]).unwrap();
+    assert_eq!(matrix_3x3.determinant().unwrap(), Fq::from(0u64));
 
     let elem = Fq::from(10u64);
     let matrix_4x4 = SquareMatrix::<4, 16>::new(&[
         a, b, c, d, e, f, g, h, i, elem, elem, elem, elem, elem, elem, elem,
-    ]);
-    assert_eq!(matrix_4x4.determinant(), Fq::from(0u64));
+    ])
+    .unwrap();
+    assert_eq!(matrix_4x4.determinant().unwrap(), Fq::from(0u64));
 
     let matrix_5x5 = SquareMatrix::<5, 25>::new(&[
         a, b, c, d, e, f, g, h, i, elem, elem, elem, elem, elem, elem, elem, elem, elem, elem,
         elem, elem, elem, elem, elem, elem,
-    ]);
-    assert_eq!(matrix_5x5.determinant(), Fq::from(0u64));
+    ])
+    .unwrap();
+    assert_eq!(matrix_5x5.determinant().unwrap(), Fq::from(0u64));
 
     let mut elements = vec![a, b, c, d, e, f, g, h, i];
     elements.extend_from_slice(&[elem; 27]);
-    let matrix_6x6 = SquareMatrix::<6, 36>::new(&elements[..]);
-    assert_eq!(matrix_6x6.determinant(), Fq::from(0u64));
+    let matrix_6x6 = SquareMatrix::<6, 36>::new(&elements[..]).unwrap();
+    assert_eq!(matrix_6x6.determinant().unwrap(), Fq::from(0u64));
 
     let mut elements = vec![a, b, c, d, e, f, g, h, i];
     elements.extend_from_slice(&[elem; 40]);
-    let matrix_7x7 = SquareMatrix::<7, 49>::new(&elements[..]);
-    assert_eq!(matrix_7x7.determinant(), Fq::from(0u64));
+    let matrix_7x7 = SquareMatrix::<7, 49>::new(&elements[..]).unwrap();
+    assert_eq!(matrix_7x7.determinant().unwrap(), Fq::from(0u64));
 
     let mut elements = vec![a, b, c, d, e, f, g, h, i];
     elements.extend_from_slice(&[elem; 55]);
-    let matrix_8x8 = SquareMatrix::<8, 64>::new(&elements[..]);
-    assert_eq!(matrix_8x8.determinant(), Fq::from(0u64));
+    let matrix_8x8 = SquareMatrix::<8, 64>::new(&elements[..]).unwrap();
+    assert_eq!(matrix_8x8.determinant().unwrap(), Fq::from(0u64));
+}
+
+#[test]
+fn minors() {
+    let elements = [1u64, 2, 3, 4].map(Fq::from);
+    let matrix_2x2 = SquareMatrix::<2, 4>::new(&elements).unwrap();
+    assert_eq!(
+        matrix_2x2.minors().unwrap(),
+        SquareMatrix::<2, 4>::new(&[4u64, 3, 2, 1].map(Fq::from)).unwrap()
+    );
+
+    // Minors are defined for matrices of any size, and expand to the determinant.
+    let identity = SquareMatrix::<9, 81>::identity();
+    assert_eq!(identity.minors().unwrap(), identity);
+    let elements: Vec<Fq> = (0..81u64).map(|k| Fq::from(k * k + 1)).collect();
+    let matrix_9x9 = SquareMatrix::<9, 81>::new(&elements).unwrap();
+    let minors = matrix_9x9.minors().unwrap();
+    let cofactors = matrix_9x9.cofactors();
+    let expansion: Fq = (0..9)
+        .map(|j| {
+            matrix_9x9.get_element(0, j) * cofactors.get_element(0, j) * minors.get_element(0, j)
+        })
+        .sum();
+    assert_eq!(expansion, matrix_9x9.determinant().unwrap());
+
+    // A matrix without elements has neither.
+    let empty = SquareMatrix::<0, 0>::identity();
+    assert_eq!(
+        empty.minors(),
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+    assert_eq!(
+        empty.determinant(),
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+}
+
+#[test]
+fn determinant_large_matrices() {
+    // Upper triangular, with the product of the diagonal as determinant.
+    let mut elements = [Fq::from(0u64); 256];
+    let mut expected = Fq::from(1u64);
+    for i in 0..16 {
+        for j in i..16 {
+            elements[i * 16 + j] = Fq::from((i * 16 + j + 1) as u64);
+        }
+        expected *= elements[i * 16 + i];
+    }
+    let triangular = SquareMatrix::<16, 256>::new(&elements).unwrap();
+    assert_eq!(triangular.determinant().unwrap(), expected);
+    assert_eq!(triangular.transpose().determinant().unwrap(), expected);
+
+    // Swapping two rows flips the sign, which also needs pivoting.
+    for j in 0..16 {
+        elements.swap(j, 16 + j);
+    }
+    assert_eq!(
+        SquareMatrix::<16, 256>::new(&elements)
+            .unwrap()
+            .determinant()
+            .unwrap(),
+        -expected
+    );
+
+    // The determinant is multiplicative.
+    let cauchy = SquareMatrix::<12, 144>::new(
+        &(0..144u64)
+            .map(|index| {
+                Fq::from(index / 12 + index % 12 + 12 + 1)
+                    .inverse()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>(),
+    )
+    .unwrap();
+    let product: SquareMatrix<12, 144> = square_mat_mul(&cauchy, &cauchy.transpose()).unwrap();
+    assert_ne!(cauchy.determinant().unwrap(), Fq::from(0u64));
+    assert_eq!(
+        product.determinant().unwrap(),
+        cauchy.determinant().unwrap() * cauchy.determinant().unwrap()
+    );
+
+    // Equal rows make the matrix singular.
+    let mut elements = cauchy.elements().to_vec();
+    elements.copy_within(0..12, 12 * 11);
+    assert_eq!(
+        SquareMatrix::<12, 144>::new(&elements)
+            .unwrap()
+            .determinant()
+            .unwrap(),
+        Fq::from(0u64)
+    );
+}
+
+#[test]
+fn inverse_large_matrices() {
+    let cauchy = SquareMatrix::<16, 256>::new(
+        &(0..256u64)
+            .map(|index| {
+                Fq::from(index / 16 + index % 16 + 16 + 1)
+                    .inverse()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>(),
+    )
+    .unwrap();
+    let inverse = cauchy.inverse().unwrap();
+    assert_eq!(
+        square_mat_mul(&cauchy, &inverse).unwrap(),
+        SquareMatrix::<16, 256>::identity()
+    );
+    assert_eq!(inverse.inverse().unwrap(), cauchy);
+
+    // A zero in the upper left corner needs pivoting.
+    let mut elements = SquareMatrix::<9, 81>::identity().elements().to_vec();
+    elements.swap(0, 9);
+    elements.swap(1, 10);
+    let permutation = SquareMatrix::<9, 81>::new(&elements).unwrap();
+    assert_eq!(permutation.inverse().unwrap(), permutation);
+
+    // Singular matrices have no inverse.
+    let mut elements = cauchy.elements().to_vec();
+    elements.copy_within(0..16, 16 * 15);
+    assert_eq!(
+        SquareMatrix::<16, 256>::new(&elements).unwrap().inverse(),
+        Err(PoseidonParameterError::NoMatrixInverse)
+    );
+    assert_eq!(
+        SquareMatrix::<1, 1>::new(&[Fq::from(0u64)])
+            .unwrap()
+            .inverse(),
+        Err(PoseidonParameterError::NoMatrixInverse)
+    );
+}
+
+#[test]
+fn deserialize_matrix() {
+    use poseidon_parameters::v1::ArcMatrix;
+    use serde::de::{value::Error, value::SeqDeserializer, Deserialize};
+
+    fn decode<'a, T: Deserialize<'a>>(hex: &[&'a str]) -> Result<T, Error> {
+        T::deserialize(SeqDeserializer::<_, Error>::new(hex.iter().copied()))
+    }
+
+    // Elements are the hex of their little-endian encoding, in row-major order.
+    let one = "0100000000000000000000000000000000000000000000000000000000000000";
+    let x = "0201000000000000000000000000000000000000000000000000000000000000";
+    let matrix: ArcMatrix<1, 2, 2> = decode(&[one, x]).unwrap();
+    assert_eq!(matrix.get_element(0, 0), Fq::from(1u64));
+    assert_eq!(matrix.get_element(0, 1), Fq::from(0x0102u64));
+
+    // The dimensions are part of the type.
+    assert!(decode::<ArcMatrix<1, 2, 2>>(&[one]).is_err());
+    assert!(decode::<ArcMatrix<1, 2, 2>>(&[one, one, one]).is_err());
+
+    // Non-canonical and malformed elements are rejected.
+    let too_large = "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+    assert!(decode::<SquareMatrix<1, 1>>(&[too_large]).is_err());
+    assert!(decode::<SquareMatrix<1, 1>>(&["01"]).is_err());
+    assert!(decode::<SquareMatrix<1, 1>>(&[&x.replace('2', "g")]).is_err());
+}
+
+/// Check that `parameters` survive an encoding round trip.
+fn roundtrip<
+    const STATE_SIZE: usize,
+    const STATE_SIZE_MINUS_1: usize,
+    const NUM_MDS_ELEMENTS: usize,
+    const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+    const NUM_ROUND_ROWS: usize,
+    const NUM_ROUND_COLS: usize,
+    const NUM_ROUND_ELEMENTS: usize,
+    const NUM_PARTIAL_ROUNDS: usize,
+>(
+    parameters: PoseidonParameters<
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >,
+) {
+    let bytes = parameters.to_bytes();
+    let decoded = PoseidonParameters::<
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >::from_bytes(&bytes)
+    .unwrap();
+    assert_eq!(decoded.to_bytes(), bytes);
+    assert_eq!(decoded.alpha, parameters.alpha);
+    assert_eq!(decoded.rounds, parameters.rounds);
+    assert_eq!(decoded.mds, parameters.mds);
+    assert_eq!(decoded.optimized_mds, parameters.optimized_mds);
+}
+
+#[test]
+fn binary_encoding() {
+    roundtrip(poseidon377::RATE_1_PARAMS);
+    roundtrip(poseidon377::RATE_2_PARAMS);
+    roundtrip(poseidon377::RATE_4_PARAMS);
+    roundtrip(poseidon377::RATE_7_PARAMS);
+    roundtrip(poseidon377::inverse_sbox::RATE_4_PARAMS);
+
+    type Rate4 = PoseidonParameters<5, 4, 25, 16, 39, 5, 195, 31>;
+    let bytes = poseidon377::RATE_4_PARAMS.to_bytes();
+    assert_eq!(bytes.len(), 57 + 32 * 804);
+    assert_eq!(bytes.len(), Rate4::ENCODED_LEN);
+    let corrupt = |offset: usize, value: &[u8]| {
+        let mut bytes = bytes.clone();
+        bytes[offset..offset + value.len()].copy_from_slice(value);
+        Rate4::from_bytes(&bytes).unwrap_err()
+    };
+
+    // Header.
+    assert_eq!(corrupt(0, b"X"), PoseidonParameterError::InvalidEncoding);
+    assert_eq!(
+        corrupt(4, &[2]),
+        PoseidonParameterError::UnsupportedVersion(2)
+    );
+    assert_eq!(corrupt(5, &[0]), PoseidonParameterError::FieldMismatch);
+    assert_eq!(corrupt(53, &[0; 4]), PoseidonParameterError::InvalidAlpha);
+    assert_eq!(
+        PoseidonParameters::<3, 2, 9, 4, 39, 3, 117, 31>::from_bytes(&bytes).unwrap_err(),
+        PoseidonParameterError::DimensionMismatch
+    );
+    assert_eq!(
+        PoseidonParameters::<5, 4, 25, 16, 68, 5, 340, 60>::from_bytes(&bytes).unwrap_err(),
+        PoseidonParameterError::DimensionMismatch
+    );
+
+    // Length.
+    assert_eq!(
+        Rate4::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err(),
+        PoseidonParameterError::InvalidEncoding
+    );
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(
+        Rate4::from_bytes(&trailing).unwrap_err(),
+        PoseidonParameterError::InvalidEncoding
+    );
+
+    // Elements: the first round constant, then the first element of `M_hat`.
+    assert_eq!(
+        corrupt(57 + 32 * 25, &[0xff; 32]),
+        PoseidonParameterError::NonCanonicalElement
+    );
+    assert_eq!(
+        corrupt(57 + 32 * (25 + 2 * 195), &Fq::from(1u64).to_bytes()),
+        PoseidonParameterError::InconsistentParameters
+    );
+}
+
+#[test]
+fn validate_parameters() {
+    let builtin = [
+        DynPoseidonParameters::from(poseidon377::RATE_1_PARAMS),
+        DynPoseidonParameters::from(poseidon377::RATE_2_PARAMS),
+        DynPoseidonParameters::from(poseidon377::RATE_3_PARAMS),
+        DynPoseidonParameters::from(poseidon377::RATE_4_PARAMS),
+        DynPoseidonParameters::from(poseidon377::RATE_5_PARAMS),
+        DynPoseidonParameters::from(poseidon377::RATE_6_PARAMS),
+        DynPoseidonParameters::from(poseidon377::RATE_7_PARAMS),
+        DynPoseidonParameters::from(poseidon377::inverse_sbox::RATE_2_PARAMS),
+    ];
+    for parameters in builtin.iter() {
+        assert_eq!(validate(parameters), Ok(()));
+    }
+
+    let parameters = DynPoseidonParameters::from(poseidon377::RATE_1_PARAMS);
+    let with_mds = |elements: [u64; 4]| {
+        let mut parameters = parameters.clone();
+        parameters.mds = DynMatrix::new(2, 2, elements.iter().map(|&x| Fq::from(x)).collect());
+        validate(&parameters)
+    };
+    // A zero entry is a singular 1x1 submatrix.
+    assert_eq!(with_mds([1, 0, 1, 1]), Err(PoseidonParameterError::NotMds));
+    assert_eq!(with_mds([1, 2, 2, 4]), Err(PoseidonParameterError::NotMds));
+
+    // MDS, but (0, 1, -1) is an eigenvector, so the S-box stays inactive for the
+    // differences it spans.
+    let mut invariant = DynPoseidonParameters::from(poseidon377::RATE_2_PARAMS);
+    invariant.mds = DynMatrix::new(
+        3,
+        3,
+        [7, 2, 2, 2, 3, 5, 2, 5, 3]
+            .iter()
+            .map(|&x| Fq::from(x as u64))
+            .collect(),
+    );
+    assert_eq!(
+        validate(&invariant),
+        Err(PoseidonParameterError::InvariantSubspaceTrail)
+    );
+
+    let mut wrong_inverse = parameters;
+    wrong_inverse.optimized_mds.M_inverse = wrong_inverse.mds.clone();
+    assert_eq!(
+        validate(&wrong_inverse),
+        Err(PoseidonParameterError::InconsistentParameters)
+    );
+}
+
+#[test]
+fn security_margins() {
+    let expected = SecurityMargins {
+        minimum: RoundNumbers { r_F: 6, r_P: 26 },
+        full: 2,
+        partial: 5,
+    };
+    assert_eq!(poseidon377::RATE_1_PARAMS.security_margins(), Ok(expected));
+    assert_eq!(poseidon377::RATE_4_PARAMS.security_margins(), Ok(expected));
+    assert_eq!(
+        DynPoseidonParameters::from(poseidon377::RATE_7_PARAMS).security_margins(),
+        Ok(expected)
+    );
+    assert_eq!(
+        poseidon377::inverse_sbox::RATE_2_PARAMS.security_margins(),
+        Ok(SecurityMargins {
+            minimum: RoundNumbers { r_F: 6, r_P: 55 },
+            full: 2,
+            partial: 8,
+        })
+    );
+
+    let log_2_p = 252.0;
+    let alpha = Alpha::Exponent(17);
+    let margins = |full, partial, security_level| {
+        RoundNumbers {
+            r_F: full,
+            r_P: partial,
+        }
+        .security_margins(security_level, log_2_p, 3, alpha)
+    };
+    assert_eq!(
+        margins(6, 28, 128).map(|margins| (margins.full, margins.partial)),
+        Ok((0, 0))
+    );
+    assert_eq!(
+        margins(6, 27, 128),
+        Err(PoseidonParameterError::InsecureRoundNumbers)
+    );
+    assert_eq!(
+        margins(4, 100, 128),
+        Err(PoseidonParameterError::InsecureRoundNumbers)
+    );
+    assert_eq!(
+        margins(8, 31, 512),
+        Err(PoseidonParameterError::UnsupportedSecurityLevel)
+    );
+}
+
+#[test]
+fn goldilocks_round_numbers() {
+    // The round numbers of plonky2's Poseidon over Goldilocks, p = 2^64 - 2^32 + 1,
+    // with t = 12 and alpha = 7.
+    let log_2_p = 63.999_999_999_66;
+    let rounds = RoundNumbers { r_F: 8, r_P: 22 };
+    assert_eq!(
+        rounds.security_margins(128, log_2_p, 12, Alpha::Exponent(7)),
+        Ok(SecurityMargins {
+            minimum: RoundNumbers { r_F: 6, r_P: 18 },
+            full: 2,
+            partial: 4,
+        })
+    );
+}
+
+#[test]
+fn rust_source_matches_vendored_parameters() {
+    let check = |parameters: DynPoseidonParameters, name: &str, doc: &str, vendored: &str| {
+        let source = parameters.to_rust_source(name, doc).unwrap();
+        assert!(
+            source == vendored,
+            "{} differs from the vendored source",
+            name
+        );
+    };
+    check(
+        DynPoseidonParameters::from(poseidon377::RATE_2_PARAMS),
+        "rate_2",
+        "Parameters for the rate-2 instance of Poseidon.",
+        include_str!("../../poseidon377/src/params/rate_2.rs"),
+    );
+    check(
+        DynPoseidonParameters::from(poseidon377::RATE_4_PARAMS),
+        "rate_4",
+        "Parameters for the rate-4 instance of Poseidon.",
+        include_str!("../../poseidon377/src/params/rate_4.rs"),
+    );
+    check(
+        DynPoseidonParameters::from(poseidon377::RATE_15_PARAMS),
+        "rate_15",
+        "Parameters for the rate-15 instance of Poseidon.",
+        include_str!("../../poseidon377/src/params/rate_15.rs"),
+    );
+    check(
+        DynPoseidonParameters::from(poseidon377::inverse_sbox::RATE_1_PARAMS),
+        "rate_1",
+        "Parameters for the rate-1 instance of Poseidon with the inverse S-box.",
+        include_str!("../../poseidon377/src/params/inverse/rate_1.rs"),
+    );
+    check(
+        DynPoseidonParameters::from(poseidon377::inverse_sbox::RATE_7_PARAMS),
+        "rate_7",
+        "Parameters for the rate-7 instance of Poseidon with the inverse S-box.",
+        include_str!("../../poseidon377/src/params/inverse/rate_7.rs"),
+    );
+
+    let mut parameters = DynPoseidonParameters::from(poseidon377::RATE_1_PARAMS);
+    parameters.optimized_mds.v_collection.pop();
+    assert_eq!(
+        parameters.to_rust_source("rate_1", ""),
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+}
+
+#[test]
+fn optimized_parameters_match_vendored_parameters() {
+    let builtin = [
+        DynPoseidonParameters::from(poseidon377::RATE_1_PARAMS),
+        DynPoseidonParameters::from(poseidon377::RATE_2_PARAMS),
+        DynPoseidonParameters::from(poseidon377::RATE_4_PARAMS),
+        DynPoseidonParameters::from(poseidon377::RATE_7_PARAMS),
+        DynPoseidonParameters::from(poseidon377::RATE_15_PARAMS),
+        DynPoseidonParameters::from(poseidon377::inverse_sbox::RATE_2_PARAMS),
+    ];
+    for parameters in builtin.iter() {
+        let recomputed = DynPoseidonParameters::from_unoptimized(
+            parameters.M,
+            parameters.alpha,
+            parameters.rounds,
+            parameters.mds.clone(),
+            parameters.arc.clone(),
+        );
+        assert_eq!(recomputed.as_ref(), Ok(parameters));
+    }
+
+    let parameters = DynPoseidonParameters::from(poseidon377::RATE_1_PARAMS);
+    let singular = DynMatrix::new(2, 2, [1u64, 2, 2, 4].iter().map(|&x| Fq::from(x)).collect());
+    assert_eq!(
+        optimized_mds(&singular, parameters.rounds),
+        Err(PoseidonParameterError::NoMatrixInverse)
+    );
+    assert_eq!(
+        optimized_arc(&parameters.mds, &parameters.mds, parameters.rounds),
+        Err(PoseidonParameterError::InvalidMatrixDimensions)
+    );
+}
+
+#[test]
+fn sparse_matrix() {
+    let parameters = poseidon377::RATE_4_PARAMS;
+    let t = 5;
+    let state: Vec<Fq> = (1..=t as u64).map(|x| Fq::from(x * x + 7)).collect();
+    for index in [0, 1, parameters.rounds.partial() - 1].iter() {
+        let sparse = parameters.optimized_mds.sparse_matrix(*index);
+        assert_eq!(sparse.n_rows(), t);
+        assert_eq!(
+            sparse,
+            DynPoseidonParameters::from(&parameters)
+                .optimized_mds
+                .sparse_matrix(*index)
+        );
+
+        let dense: Vec<Fq> = (0..t)
+            .map(|i| (0..t).map(|j| sparse.get_element(i, j) * state[j]).sum())
+            .collect();
+        let mut product = state.clone();
+        sparse.mul_in_place(&mut product);
+        assert_eq!(product, dense);
+    }
+}
+
+#[test]
+fn generate_round_numbers() {
+    let exponent = RoundNumbers { r_F: 8, r_P: 31 };
+    for t in 2..=17 {
+        assert_eq!(
+            RoundNumbers::generate_for_fq(128, t, Alpha::Exponent(17)),
+            Ok(exponent)
+        );
+    }
+    let inverse = [
+        poseidon377::inverse_sbox::RATE_1_PARAMS.rounds,
+        poseidon377::inverse_sbox::RATE_2_PARAMS.rounds,
+        poseidon377::inverse_sbox::RATE_3_PARAMS.rounds,
+        poseidon377::inverse_sbox::RATE_4_PARAMS.rounds,
+        poseidon377::inverse_sbox::RATE_5_PARAMS.rounds,
+        poseidon377::inverse_sbox::RATE_6_PARAMS.rounds,
+        poseidon377::inverse_sbox::RATE_7_PARAMS.rounds,
+    ];
+    for (rate, rounds) in inverse.iter().enumerate() {
+        assert_eq!(
+            RoundNumbers::generate_for_fq(128, rate + 2, Alpha::Inverse),
+            Ok(*rounds)
+        );
+    }
+
+    // plonky2's round numbers over Goldilocks, see `goldilocks_round_numbers`.
+    assert_eq!(
+        RoundNumbers::generate(128, 63.999_999_999_66, 12, Alpha::Exponent(7)),
+        Ok(RoundNumbers { r_F: 8, r_P: 22 })
+    );
+    assert_eq!(
+        RoundNumbers::generate_for_fq(512, 3, Alpha::Exponent(17)),
+        Err(PoseidonParameterError::UnsupportedSecurityLevel)
+    );
+}
+
+#[test]
+fn attack_margins() {
+    let margins = poseidon377::RATE_2_PARAMS.attack_margins().unwrap();
+    assert!(margins.is_secure());
+    assert_eq!(
+        (
+            margins.statistical,
+            margins.interpolation,
+            margins.grobner_basis
+        ),
+        (
+            AttackMargin {
+                rounds: 8,
+                required: 6
+            },
+            AttackMargin {
+                rounds: 39,
+                required: 34
+            },
+            AttackMargin {
+                rounds: 39,
+                required: 11
+            },
+        )
+    );
+    assert_eq!(
+        margins.to_string(),
+        "Poseidon1 attack margins for M = 128, t = 3, alpha = x^17, r_F = 8, r_P = 31
+  statistical    r_F = 8, required 6, margin 2
+  interpolation  r_F + r_P = 39, required 34, margin 5
+  Grobner basis  r_F + r_P = 39, required 11, margin 28
+"
+    );
+    assert_eq!(
+        DynPoseidonParameters::from(poseidon377::RATE_2_PARAMS).attack_margins(),
+        Ok(margins)
+    );
+
+    let margins = poseidon377::inverse_sbox::RATE_2_PARAMS
+        .attack_margins()
+        .unwrap();
+    assert_eq!(margins.interpolation.rounds, 75);
+    assert_eq!(margins.interpolation.margin(), 8);
+
+    let margins = RoundNumbers { r_F: 4, r_P: 20 }
+        .attack_margins(128, 252.0, 3, Alpha::Exponent(17))
+        .unwrap();
+    assert!(!margins.is_secure());
+    assert_eq!(margins.statistical.margin(), -2);
+    assert!(margins
+        .to_string()
+        .contains("interpolation  r_F + r_P = 24, required 34, margin -10 (INSECURE)"));
 }
@@ -225,3 +225,32 @@ fn groth16_hash_proof_unhappy_path(v1 in fq_strategy(), v2 in fq_strategy(), v3
         assert!(!proof_result);
     }
 }
+
+proptest! {
+#![proptest_config(ProptestConfig::with_cases(20))]
+#[test]
+fn optimized_gadget_matches_unoptimized(v1 in fq_strategy(), v2 in fq_strategy(), v3 in fq_strategy(), v4 in fq_strategy()) {
+    use ark_r1cs_std::R1CSVar;
+    use ark_relations::r1cs::ConstraintSystem;
+    use poseidon_permutation::r1cs::InstanceVar;
+
+    let cs = ConstraintSystem::<Fq>::new_ref();
+    let words = [DOMAIN_SEP, v1, v2, v3, v4]
+        .map(|word| FqVar::new_witness(cs.clone(), || Ok(word)).unwrap());
+    let optimized = InstanceVar::n_to_1_fixed_hash(
+        poseidon377::RATE_4_PARAMS,
+        cs.clone(),
+        words.clone(),
+    );
+    let unoptimized = InstanceVar::unoptimized_n_to_1_fixed_hash(
+        poseidon377::RATE_4_PARAMS,
+        cs.clone(),
+        words,
+    );
+
+    let expected = poseidon377::hash_4(&DOMAIN_SEP, (v1, v2, v3, v4));
+    assert_eq!(optimized.value().unwrap(), expected);
+    assert_eq!(unoptimized.value().unwrap(), expected);
+    assert!(cs.is_satisfied().unwrap());
+}
+}
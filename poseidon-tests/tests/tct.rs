@@ -0,0 +1,57 @@
+//! Fixtures for the tiered commitment tree helpers.
+//!
+//! The expected values were computed with an independent implementation of the
+//! hashing conventions of Penumbra's tiered commitment tree.
+
+use core::str::FromStr;
+
+use blake2::{Blake2b512, Digest};
+use poseidon377::{merkle::tct, Fq};
+
+fn fq(s: &str) -> Fq {
+    Fq::from_str(s).unwrap()
+}
+
+#[test]
+fn tct_domain_separator() {
+    let digest = Blake2b512::digest(b"penumbra.tct");
+    assert_eq!(
+        tct::DOMAIN_SEPARATOR,
+        Fq::from_le_bytes_mod_order(digest.as_slice())
+    );
+}
+
+#[test]
+fn tct_hashes() {
+    assert_eq!(
+        tct::hash_leaf(Fq::from(1u64)),
+        fq("1906763079343300024415645699073579500030144671442992054391123841606661561917")
+    );
+    assert_eq!(
+        tct::hash_node(1, [1u64, 2, 3, 4].map(Fq::from)),
+        fq("4159266677712373036766142329159510047127121095756363260316310010507872845073")
+    );
+}
+
+#[test]
+fn tct_roots() {
+    let block_0 = tct::block_root(&[(0, Fq::from(1u64)), (1, Fq::from(2u64))]);
+    assert_eq!(
+        block_0,
+        fq("6811024675151312256360482747165263267777588018435023082392093742801803945227")
+    );
+
+    // The second block of the first epoch was finalized without commitments.
+    let epoch_0 = tct::epoch_root(&[(0, block_0), (1, tct::block_root(&[]))]);
+    assert_eq!(
+        epoch_0,
+        fq("8368966448807665005850608701280168816655212225402108962074734762768119772056")
+    );
+
+    // The second epoch was finalized without blocks, and the third one is missing.
+    let epoch_3 = tct::epoch_root(&[(2, tct::block_root(&[(7, Fq::from(3u64))]))]);
+    assert_eq!(
+        tct::root(&[(0, epoch_0), (1, tct::epoch_root(&[])), (3, epoch_3)]),
+        fq("2378780221157071290118110929588736806268313517504264665467217220315130395524")
+    );
+}
@@ -0,0 +1,63 @@
+//! Derive macros for hashing structs with `poseidon377`.
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Fields, Index};
+
+/// Derive `ToFieldElements` and `PoseidonHash` for a struct.
+///
+/// The generated encoding is the number of fields followed by the encoding of
+/// each field in declaration order, so the hash commits both to the number of
+/// fields and to their order. All fields must implement `ToFieldElements`.
+#[proc_macro_derive(PoseidonHash)]
+pub fn derive_poseidon_hash(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Error::new_spanned(&input.ident, "PoseidonHash can only be derived for structs")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let accessors: Vec<_> = match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|field| {
+                let ident = &field.ident;
+                quote!(#ident)
+            })
+            .collect(),
+        Fields::Unnamed(fields) => (0..fields.unnamed.len())
+            .map(|i| {
+                let index = Index::from(i);
+                quote!(#index)
+            })
+            .collect(),
+        Fields::Unit => Vec::new(),
+    };
+    let num_fields = accessors.len() as u64;
+
+    for param in input.generics.type_params_mut() {
+        param
+            .bounds
+            .push(parse_quote!(::poseidon377::ToFieldElements));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::poseidon377::ToFieldElements for #name #ty_generics #where_clause {
+            fn append_field_elements<E: ::core::iter::Extend<::poseidon377::Fq>>(&self, output: &mut E) {
+                ::poseidon377::ToFieldElements::append_field_elements(&#num_fields, output);
+                #(::poseidon377::ToFieldElements::append_field_elements(&self.#accessors, output);)*
+            }
+        }
+
+        impl #impl_generics ::poseidon377::PoseidonHash for #name #ty_generics #where_clause {}
+    }
+    .into()
+}
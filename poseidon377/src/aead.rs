@@ -0,0 +1,247 @@
+//! Authenticated encryption with associated data over [`Fq`] elements, using the
+//! [`DuplexSponge`] in the SpongeWrap mode.
+//!
+//! The sponge absorbs the `key`, the `nonce`, the length of the associated data,
+//! the associated data itself and the length of the plaintext. The plaintext is
+//! then processed in blocks of four elements: the next block of the keystream is
+//! squeezed and added to the plaintext block to produce the ciphertext block, and
+//! the plaintext block is absorbed, so the rate portion of the state ends up equal
+//! to the ciphertext block. Finally the tag is a single squeezed element.
+//!
+//! A `(key, nonce)` pair must never be used to seal two different messages: doing
+//! so reveals the difference of the first blocks of the plaintexts. The key should
+//! be uniformly random, and nonces may be a counter.
+//!
+//! [`seal`] and [`open`] work in place on [`Fq`] elements and need no allocation.
+//! With the `alloc` feature, [`seal_bytes`] and [`open_bytes`] encrypt byte strings,
+//! encoded with the [`ToFieldElements`](crate::ToFieldElements) encoding of byte
+//! slices, and produce ciphertexts made of the 32-byte encodings of the ciphertext
+//! elements followed by the tag.
+
+use crate::{error::AeadError, sponge::SPONGE_RATE, DuplexSponge, Fq};
+
+/// Domain separator used for authenticated encryption,
+/// `from_le_bytes_mod_order(b"poseidon377.aead")`.
+const AEAD_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    18310090040774316865,
+    16300198392777336151,
+    15551548348278761336,
+    850603953808161176,
+]);
+
+/// Create a sponge with the `key`, `nonce`, `associated_data` and the length of
+/// the message absorbed.
+fn init(key: &Fq, nonce: &Fq, associated_data: &[Fq], message_len: usize) -> DuplexSponge {
+    let mut sponge = DuplexSponge::new(&AEAD_DOMAIN_SEP);
+    sponge.absorb(&[*key, *nonce, Fq::from(associated_data.len() as u64)]);
+    sponge.absorb(associated_data);
+    sponge.absorb(&[Fq::from(message_len as u64)]);
+    sponge
+}
+
+/// Encrypt `plaintext` in place with the provided `key` and `nonce`, authenticating
+/// it together with the `associated_data`, and return the tag.
+pub fn seal(key: &Fq, nonce: &Fq, associated_data: &[Fq], plaintext: &mut [Fq]) -> Fq {
+    let mut sponge = init(key, nonce, associated_data, plaintext.len());
+    for block in plaintext.chunks_mut(SPONGE_RATE) {
+        let mut keystream = [Fq::from(0u64); SPONGE_RATE];
+        sponge.squeeze_into(&mut keystream[..block.len()]);
+        sponge.absorb(block);
+        for (word, key_word) in block.iter_mut().zip(keystream.iter()) {
+            *word += key_word;
+        }
+    }
+    sponge.squeeze()
+}
+
+/// Check the `tag` of `ciphertext` and decrypt it in place, with the provided
+/// `key`, `nonce` and `associated_data`.
+///
+/// If the tag is invalid, `ciphertext` is overwritten with zeros rather than with
+/// the unauthenticated plaintext.
+pub fn open(
+    key: &Fq,
+    nonce: &Fq,
+    associated_data: &[Fq],
+    ciphertext: &mut [Fq],
+    tag: &Fq,
+) -> Result<(), AeadError> {
+    let mut sponge = init(key, nonce, associated_data, ciphertext.len());
+    for block in ciphertext.chunks_mut(SPONGE_RATE) {
+        let mut keystream = [Fq::from(0u64); SPONGE_RATE];
+        sponge.squeeze_into(&mut keystream[..block.len()]);
+        for (word, key_word) in block.iter_mut().zip(keystream.iter()) {
+            *word -= key_word;
+        }
+        sponge.absorb(block);
+    }
+
+    if ct_eq(&sponge.squeeze(), tag) {
+        Ok(())
+    } else {
+        ciphertext
+            .iter_mut()
+            .for_each(|word| *word = Fq::from(0u64));
+        Err(AeadError::InvalidTag)
+    }
+}
+
+/// Compare two elements in constant time.
+fn ct_eq(a: &Fq, b: &Fq) -> bool {
+    a.to_bytes()
+        .iter()
+        .zip(b.to_bytes().iter())
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+        == 0
+}
+
+/// Encrypt the `plaintext` bytes with the provided `key` and `nonce`, authenticating
+/// them together with the `associated_data` bytes.
+#[cfg(feature = "alloc")]
+pub fn seal_bytes(
+    key: &Fq,
+    nonce: &Fq,
+    associated_data: &[u8],
+    plaintext: &[u8],
+) -> alloc::vec::Vec<u8> {
+    use crate::ToFieldElements;
+    use alloc::vec::Vec;
+
+    let mut associated_elements = Vec::new();
+    associated_data.append_field_elements(&mut associated_elements);
+    let mut elements = Vec::new();
+    plaintext.append_field_elements(&mut elements);
+
+    let tag = seal(key, nonce, &associated_elements, &mut elements);
+    elements
+        .iter()
+        .chain(Some(&tag))
+        .flat_map(|element| element.to_bytes())
+        .collect()
+}
+
+/// Check and decrypt a `ciphertext` produced by [`seal_bytes`], with the provided
+/// `key`, `nonce` and `associated_data` bytes.
+#[cfg(feature = "alloc")]
+pub fn open_bytes(
+    key: &Fq,
+    nonce: &Fq,
+    associated_data: &[u8],
+    ciphertext: &[u8],
+) -> Result<alloc::vec::Vec<u8>, AeadError> {
+    use crate::{encoding::BYTES_PER_ELEMENT, ToFieldElements};
+    use alloc::vec::Vec;
+    use core::convert::TryFrom;
+
+    // The ciphertext contains at least the encrypted length and the tag.
+    if !ciphertext.len().is_multiple_of(32) || ciphertext.len() < 64 {
+        return Err(AeadError::InvalidCiphertext);
+    }
+    let mut elements = ciphertext
+        .chunks(32)
+        .map(|chunk| {
+            let bytes = <&[u8; 32]>::try_from(chunk).expect("chunk of 32 bytes");
+            Fq::from_bytes_checked(bytes).map_err(|_| AeadError::InvalidCiphertext)
+        })
+        .collect::<Result<Vec<Fq>, AeadError>>()?;
+    let tag = elements.pop().expect("at least two elements");
+
+    let mut associated_elements = Vec::new();
+    associated_data.append_field_elements(&mut associated_elements);
+    open(key, nonce, &associated_elements, &mut elements, &tag)?;
+
+    // The plaintext is authentic, but check that it is a valid encoding anyway.
+    let len_bytes = elements[0].to_bytes();
+    if len_bytes[8..].iter().any(|byte| *byte != 0) {
+        return Err(AeadError::InvalidCiphertext);
+    }
+    let len = u64::from_le_bytes(<[u8; 8]>::try_from(&len_bytes[..8]).expect("8 bytes")) as usize;
+    if len.div_ceil(BYTES_PER_ELEMENT) != elements.len() - 1 {
+        return Err(AeadError::InvalidCiphertext);
+    }
+
+    let mut plaintext = Vec::with_capacity(len);
+    for element in &elements[1..] {
+        let chunk_len = core::cmp::min(BYTES_PER_ELEMENT, len - plaintext.len());
+        let bytes = element.to_bytes();
+        if bytes[chunk_len..].iter().any(|byte| *byte != 0) {
+            return Err(AeadError::InvalidCiphertext);
+        }
+        plaintext.extend_from_slice(&bytes[..chunk_len]);
+    }
+    Ok(plaintext)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn seal_open_roundtrip() {
+        assert_eq!(
+            AEAD_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.aead")
+        );
+
+        let key = Fq::from(1234u64);
+        let nonce = Fq::from(1u64);
+        let associated_data = [Fq::from(42u64)];
+
+        for len in 0..10u64 {
+            let plaintext: Vec<Fq> = (0..len).map(Fq::from).collect();
+            let mut buffer = plaintext.clone();
+            let tag = seal(&key, &nonce, &associated_data, &mut buffer);
+            if len > 0 {
+                assert_ne!(buffer, plaintext);
+            }
+
+            let mut decrypted = buffer.clone();
+            open(&key, &nonce, &associated_data, &mut decrypted, &tag).unwrap();
+            assert_eq!(decrypted, plaintext);
+
+            // Any change to the inputs is detected.
+            let other = Fq::from(99u64);
+            let mut decrypted = buffer.clone();
+            assert_eq!(
+                open(&other, &nonce, &associated_data, &mut decrypted, &tag),
+                Err(AeadError::InvalidTag)
+            );
+            assert!(decrypted.iter().all(|word| *word == Fq::from(0u64)));
+            let mut decrypted = buffer.clone();
+            assert!(open(&key, &other, &associated_data, &mut decrypted, &tag).is_err());
+            let mut decrypted = buffer.clone();
+            assert!(open(&key, &nonce, &[], &mut decrypted, &tag).is_err());
+            let mut decrypted = buffer.clone();
+            assert!(open(&key, &nonce, &associated_data, &mut decrypted, &other).is_err());
+            if len > 0 {
+                let mut decrypted = buffer.clone();
+                decrypted[0] += Fq::from(1u64);
+                assert!(open(&key, &nonce, &associated_data, &mut decrypted, &tag).is_err());
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn seal_open_bytes_roundtrip() {
+        let key = Fq::from(1234u64);
+        let nonce = Fq::from(1u64);
+
+        for len in [0, 1, 30, 31, 32, 100] {
+            let plaintext: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let ciphertext = seal_bytes(&key, &nonce, b"header", &plaintext);
+            assert_eq!(
+                open_bytes(&key, &nonce, b"header", &ciphertext).unwrap(),
+                plaintext
+            );
+            assert_eq!(
+                open_bytes(&key, &nonce, b"other header", &ciphertext),
+                Err(AeadError::InvalidTag)
+            );
+            assert_eq!(
+                open_bytes(&key, &nonce, b"header", &ciphertext[1..]),
+                Err(AeadError::InvalidCiphertext)
+            );
+        }
+    }
+}
@@ -0,0 +1,311 @@
+//! Descriptions of the permutation as an AIR, for STARK backends.
+//!
+//! An [`Air`] lays the permutation out as an execution trace with one row per
+//! round and one column per state element. Row `r + 1` is obtained from row `r`
+//! by the transition constraint
+//!
+//! ```text
+//! active[r] * (next[i] - sum_j mds[i][j] * sbox_j(current[j] + rc[r][j])) = 0
+//! ```
+//!
+//! where `sbox_j` is the S-box in full rounds, and in partial rounds is the S-box
+//! for `j = 0` and the identity otherwise. The round constants, a full-round
+//! selector and the active-round selector are periodic columns, padded with zeros
+//! to the trace length, so the same constraints apply on every row and vanish on
+//! the padding rows. The boundary constraints fix the first row to the permutation input and
+//! the row after the last round to its output.
+//!
+//! The transition constraints have degree `alpha` in the trace columns, which
+//! is why only instances with an `x^alpha` S-box can be described this way.
+
+use alloc::{string::String, vec, vec::Vec};
+use core::fmt::Write;
+
+use poseidon_parameters::v1::Alpha;
+
+use crate::{
+    plonkish::{write_rows, Descriptor},
+    Fq,
+};
+
+/// A boundary constraint, fixing the value of one trace cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BoundaryConstraint {
+    /// The trace column.
+    pub column: usize,
+    /// The trace row.
+    pub row: usize,
+    /// The value of the cell.
+    pub value: Fq,
+}
+
+/// An AIR for a Poseidon permutation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Air {
+    descriptor: Descriptor,
+    rounds: usize,
+    trace_length: usize,
+}
+
+impl Air {
+    /// Describe the permutation of `descriptor` as an AIR.
+    ///
+    /// Returns `None` for the inverse S-box, which is not a low-degree polynomial.
+    pub fn new(descriptor: &Descriptor) -> Option<Self> {
+        if let Alpha::Inverse = descriptor.alpha {
+            return None;
+        }
+
+        let rounds = descriptor.full_rounds + descriptor.partial_rounds;
+        Some(Self {
+            descriptor: descriptor.clone(),
+            rounds,
+            trace_length: (rounds + 1).next_power_of_two(),
+        })
+    }
+
+    /// Number of columns in the trace, one per state element.
+    pub fn trace_width(&self) -> usize {
+        self.descriptor.state_size
+    }
+
+    /// Number of rows in the trace, the smallest power of two covering every round.
+    pub fn trace_length(&self) -> usize {
+        self.trace_length
+    }
+
+    /// Row holding the output of the permutation.
+    pub fn output_row(&self) -> usize {
+        self.rounds
+    }
+
+    /// Degree of the transition constraints in the trace columns.
+    pub fn constraint_degree(&self) -> usize {
+        match self.descriptor.alpha {
+            Alpha::Exponent(exp) => exp as usize,
+            Alpha::Inverse => unreachable!("rejected in Air::new"),
+        }
+    }
+
+    /// The periodic columns, each of length [`Air::trace_length`].
+    ///
+    /// The first `trace_width` columns hold the round constants for each state
+    /// element, followed by the full-round selector and the active-round selector.
+    pub fn periodic_columns(&self) -> Vec<Vec<Fq>> {
+        let width = self.trace_width();
+        let mut columns = vec![vec![Fq::from(0u64); self.trace_length]; width + 2];
+        for row in 0..self.rounds {
+            for (column, constant) in columns
+                .iter_mut()
+                .zip(&self.descriptor.round_constants[row])
+            {
+                column[row] = *constant;
+            }
+            if self.is_full_round(row) {
+                columns[width][row] = Fq::from(1u64);
+            }
+            columns[width + 1][row] = Fq::from(1u64);
+        }
+        columns
+    }
+
+    /// Build the execution trace of the permutation on `input`, one row per
+    /// round. Rows after [`Air::output_row`] repeat the output.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` does not have `trace_width` elements.
+    pub fn trace(&self, input: &[Fq]) -> Vec<Vec<Fq>> {
+        assert_eq!(input.len(), self.trace_width(), "input has the wrong width");
+
+        let mut trace = Vec::with_capacity(self.trace_length);
+        trace.push(input.to_vec());
+        for row in 0..self.trace_length - 1 {
+            let next = if row < self.rounds {
+                self.round(row, &trace[row])
+            } else {
+                trace[row].clone()
+            };
+            trace.push(next);
+        }
+        trace
+    }
+
+    /// Evaluate the transition constraints between `current` at `row` and `next`.
+    ///
+    /// Every evaluation is zero exactly when the transition is valid.
+    pub fn evaluate_transition(&self, row: usize, current: &[Fq], next: &[Fq]) -> Vec<Fq> {
+        if row >= self.rounds {
+            // The active-round selector disables the constraints on padding rows.
+            return vec![Fq::from(0u64); next.len()];
+        }
+
+        next.iter()
+            .zip(self.round(row, current))
+            .map(|(next, expected)| *next - expected)
+            .collect()
+    }
+
+    /// The boundary constraints for a permutation of `input` producing `output`.
+    pub fn boundary_constraints(&self, input: &[Fq], output: &[Fq]) -> Vec<BoundaryConstraint> {
+        let first = input
+            .iter()
+            .enumerate()
+            .map(|(column, &value)| BoundaryConstraint {
+                column,
+                row: 0,
+                value,
+            });
+        let last = output
+            .iter()
+            .enumerate()
+            .map(|(column, &value)| BoundaryConstraint {
+                column,
+                row: self.output_row(),
+                value,
+            });
+        first.chain(last).collect()
+    }
+
+    /// Encode the AIR as a JSON object.
+    ///
+    /// Field elements use the same encoding as [`Descriptor::to_json`].
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json)
+            .expect("writing to a string cannot fail");
+        json
+    }
+
+    fn write_json(&self, out: &mut String) -> core::fmt::Result {
+        write!(
+            out,
+            "{{\"field\":\"decaf377-fq\",\"encoding\":\"le-hex\",\"trace_width\":{},\"trace_length\":{},\"output_row\":{},\"constraint_degree\":{}",
+            self.trace_width(),
+            self.trace_length,
+            self.output_row(),
+            self.constraint_degree(),
+        )?;
+        out.push_str(",\"transition\":\"next[i] = active * sum_j mds[i][j] * sbox_j(current[j] + round_constants[j]) + (1 - active) * current[i]\"");
+        out.push_str(",\"sbox\":\"full_round * x^alpha + (1 - full_round) * x, except column 0 which is always x^alpha\"");
+        out.push_str(",\"boundary\":[{\"row\":0,\"columns\":\"input\"},{\"row\":\"output_row\",\"columns\":\"output\"}]");
+        out.push_str(",\"mds\":");
+        write_rows(out, &self.descriptor.mds)?;
+        out.push_str(",\"periodic_columns\":{\"round_constants\":");
+        let columns = self.periodic_columns();
+        let width = self.trace_width();
+        write_rows(out, &columns[..width])?;
+        out.push_str(",\"full_round\":");
+        write_rows(out, &columns[width..width + 1])?;
+        out.push_str(",\"active\":");
+        write_rows(out, &columns[width + 1..])?;
+        out.push_str("}}");
+        Ok(())
+    }
+
+    fn is_full_round(&self, round: usize) -> bool {
+        let half = self.descriptor.full_rounds / 2;
+        round < half || round >= half + self.descriptor.partial_rounds
+    }
+
+    fn round(&self, round: usize, current: &[Fq]) -> Vec<Fq> {
+        let sboxes = if self.is_full_round(round) {
+            self.trace_width()
+        } else {
+            1
+        };
+        let state: Vec<Fq> = current
+            .iter()
+            .zip(&self.descriptor.round_constants[round])
+            .enumerate()
+            .map(|(j, (x, c))| {
+                let x = *x + c;
+                if j < sboxes {
+                    self.descriptor.apply_sbox(x)
+                } else {
+                    x
+                }
+            })
+            .collect();
+
+        self.descriptor
+            .mds
+            .iter()
+            .map(|row| row.iter().zip(&state).map(|(m, x)| *m * x).sum())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{inverse_sbox, RATE_2_PARAMS, RATE_4_PARAMS};
+
+    #[test]
+    fn trace_satisfies_constraints() {
+        let descriptor = Descriptor::new(&RATE_4_PARAMS);
+        let air = Air::new(&descriptor).unwrap();
+        assert_eq!(air.trace_width(), 5);
+        assert_eq!(air.trace_length(), 64);
+        assert_eq!(air.output_row(), 39);
+        assert_eq!(air.constraint_degree(), 17);
+
+        let input = [0u64, 1, 2, 3, 4].map(Fq::from);
+        let mut output = input;
+        descriptor.permute(&mut output);
+
+        let mut trace = air.trace(&input);
+        assert_eq!(trace.len(), air.trace_length());
+        for constraint in air.boundary_constraints(&input, &output) {
+            assert_eq!(trace[constraint.row][constraint.column], constraint.value);
+        }
+        assert_eq!(
+            trace[air.output_row()][1],
+            crate::hash_4(&input[0], (input[1], input[2], input[3], input[4]))
+        );
+
+        let zero = Fq::from(0u64);
+        for row in 0..air.trace_length() - 1 {
+            let evaluations = air.evaluate_transition(row, &trace[row], &trace[row + 1]);
+            assert!(evaluations.iter().all(|e| *e == zero));
+        }
+
+        // Tampering with any round is caught by the transition into the next row.
+        trace[20][3] += Fq::from(1u64);
+        assert!(air
+            .evaluate_transition(19, &trace[19], &trace[20])
+            .iter()
+            .any(|e| *e != zero));
+        assert!(air
+            .evaluate_transition(20, &trace[20], &trace[21])
+            .iter()
+            .any(|e| *e != zero));
+    }
+
+    #[test]
+    fn periodic_columns() {
+        let descriptor = Descriptor::new(&RATE_2_PARAMS);
+        let air = Air::new(&descriptor).unwrap();
+        let columns = air.periodic_columns();
+        assert_eq!(columns.len(), 5);
+        assert!(columns.iter().all(|c| c.len() == air.trace_length()));
+        assert_eq!(columns[1][38], descriptor.round_constants[38][1]);
+        assert_eq!(columns[1][39], Fq::from(0u64));
+
+        let one = Fq::from(1u64);
+        let full = columns[3].iter().filter(|x| **x == one).count();
+        let active = columns[4].iter().filter(|x| **x == one).count();
+        assert_eq!(full, descriptor.full_rounds);
+        assert_eq!(active, descriptor.full_rounds + descriptor.partial_rounds);
+
+        let json = air.to_json();
+        assert!(json.starts_with("{\"field\":\"decaf377-fq\""));
+        assert!(json.contains("\"trace_width\":3,\"trace_length\":64"));
+        assert!(json.ends_with("}}"));
+    }
+
+    #[test]
+    fn inverse_sbox_has_no_air() {
+        assert!(Air::new(&Descriptor::new(&inverse_sbox::RATE_2_PARAMS)).is_none());
+    }
+}
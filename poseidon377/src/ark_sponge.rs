@@ -0,0 +1,221 @@
+//! Implementations of the arkworks sponge traits.
+//!
+//! [`DuplexSponge`] implements [`CryptographicSponge`], with the domain separator as
+//! its configuration, so the Poseidon instances of this crate can be used wherever
+//! `ark-crypto-primitives` expects a sponge. With the `r1cs` feature,
+//! `DuplexSpongeVar` implements `CryptographicSpongeVar`, so the same sponge can be
+//! used by gadgets such as Fiat-Shamir transforms in recursive circuits.
+//!
+//! Arkworks sponges absorb values implementing [`Absorb`], which `decaf377` field
+//! elements do not implement, so [`Elements`] wraps [`Fq`] elements to absorb them
+//! as they are. In circuits, `FqVar` elements can be absorbed directly.
+//!
+//! Bytes and bits are squeezed as by the Poseidon sponge of `ark-crypto-primitives`:
+//! each squeezed element provides its low 252 bits, or its low 31 bytes.
+
+use ark_crypto_primitives::sponge::{Absorb, CryptographicSponge, FieldBasedCryptographicSponge};
+use ark_ff::PrimeField;
+use ark_std::vec::Vec;
+
+use crate::{DuplexSponge, Fq};
+
+/// The number of bits of each squeezed element used by [`CryptographicSponge::squeeze_bits`].
+const USABLE_BITS: usize = (Fq::MODULUS_BIT_SIZE - 1) as usize;
+
+/// The number of bytes of each squeezed element used by [`CryptographicSponge::squeeze_bytes`].
+const USABLE_BYTES: usize = USABLE_BITS / 8;
+
+/// A slice of [`Fq`] elements that are absorbed as they are.
+#[derive(Clone, Copy, Debug)]
+pub struct Elements<'a>(pub &'a [Fq]);
+
+impl Absorb for Elements<'_> {
+    fn to_sponge_bytes(&self, dest: &mut Vec<u8>) {
+        for element in self.0 {
+            dest.extend_from_slice(&element.to_bytes());
+        }
+    }
+
+    fn to_sponge_field_elements<F: PrimeField>(&self, dest: &mut Vec<F>) {
+        dest.extend(
+            self.0
+                .iter()
+                .map(|element| F::from_le_bytes_mod_order(&element.to_bytes())),
+        );
+    }
+}
+
+impl CryptographicSponge for DuplexSponge {
+    type Config = Fq;
+
+    fn new(domain_separator: &Fq) -> Self {
+        DuplexSponge::new(domain_separator)
+    }
+
+    fn absorb(&mut self, input: &impl Absorb) {
+        DuplexSponge::absorb(self, &input.to_sponge_field_elements_as_vec::<Fq>());
+    }
+
+    fn squeeze_bytes(&mut self, num_bytes: usize) -> Vec<u8> {
+        let num_elements = num_bytes.div_ceil(USABLE_BYTES);
+        let mut bytes = Vec::with_capacity(num_elements * USABLE_BYTES);
+        for _ in 0..num_elements {
+            bytes.extend_from_slice(&self.squeeze().to_bytes()[..USABLE_BYTES]);
+        }
+        bytes.truncate(num_bytes);
+        bytes
+    }
+
+    fn squeeze_bits(&mut self, num_bits: usize) -> Vec<bool> {
+        let num_elements = num_bits.div_ceil(USABLE_BITS);
+        let mut bits = Vec::with_capacity(num_elements * USABLE_BITS);
+        for _ in 0..num_elements {
+            let bytes = self.squeeze().to_bytes();
+            bits.extend((0..USABLE_BITS).map(|i| (bytes[i / 8] >> (i % 8)) & 1 == 1));
+        }
+        bits.truncate(num_bits);
+        bits
+    }
+}
+
+impl FieldBasedCryptographicSponge<Fq> for DuplexSponge {
+    fn squeeze_native_field_elements(&mut self, num_elements: usize) -> Vec<Fq> {
+        (0..num_elements).map(|_| self.squeeze()).collect()
+    }
+}
+
+#[cfg(feature = "r1cs")]
+mod r1cs {
+    use ark_crypto_primitives::sponge::constraints::{
+        AbsorbGadget, CryptographicSpongeVar, SpongeWithGadget,
+    };
+    use ark_r1cs_std::{
+        bits::{boolean::Boolean, uint8::UInt8, ToBitsGadget, ToBytesGadget},
+        fields::FieldVar,
+    };
+    use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+    use ark_std::vec::Vec;
+    use decaf377::r1cs::FqVar;
+
+    use super::{USABLE_BITS, USABLE_BYTES};
+    use crate::{r1cs::DuplexSpongeVar, DuplexSponge, Fq};
+
+    impl SpongeWithGadget<Fq> for DuplexSponge {
+        type Var = DuplexSpongeVar;
+    }
+
+    impl CryptographicSpongeVar<Fq, DuplexSponge> for DuplexSpongeVar {
+        type Parameters = Fq;
+
+        fn new(cs: ConstraintSystemRef<Fq>, domain_separator: &Fq) -> Self {
+            DuplexSpongeVar::new(cs, &FqVar::constant(*domain_separator))
+        }
+
+        fn cs(&self) -> ConstraintSystemRef<Fq> {
+            DuplexSpongeVar::cs(self)
+        }
+
+        fn absorb(&mut self, input: &impl AbsorbGadget<Fq>) -> Result<(), SynthesisError> {
+            DuplexSpongeVar::absorb(self, &input.to_sponge_field_elements()?);
+            Ok(())
+        }
+
+        fn squeeze_bytes(&mut self, num_bytes: usize) -> Result<Vec<UInt8<Fq>>, SynthesisError> {
+            let num_elements = num_bytes.div_ceil(USABLE_BYTES);
+            let mut bytes = Vec::with_capacity(num_elements * USABLE_BYTES);
+            for element in self.squeeze_n(num_elements) {
+                bytes.extend_from_slice(&element.to_bytes()?[..USABLE_BYTES]);
+            }
+            bytes.truncate(num_bytes);
+            Ok(bytes)
+        }
+
+        fn squeeze_bits(&mut self, num_bits: usize) -> Result<Vec<Boolean<Fq>>, SynthesisError> {
+            let num_elements = num_bits.div_ceil(USABLE_BITS);
+            let mut bits = Vec::with_capacity(num_elements * USABLE_BITS);
+            for element in self.squeeze_n(num_elements) {
+                bits.extend_from_slice(&element.to_bits_le()?[..USABLE_BITS]);
+            }
+            bits.truncate(num_bits);
+            Ok(bits)
+        }
+
+        fn squeeze_field_elements(
+            &mut self,
+            num_elements: usize,
+        ) -> Result<Vec<FqVar>, SynthesisError> {
+            Ok(self.squeeze_n(num_elements))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ark_sponge_matches_duplex() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input = [Fq::from(1u64), Fq::from(2u64), Fq::from(3u64)];
+
+        let mut expected = DuplexSponge::new(&domain_sep);
+        expected.absorb(&input);
+        let expected = [expected.squeeze(), expected.squeeze()];
+
+        let mut sponge = <DuplexSponge as CryptographicSponge>::new(&domain_sep);
+        CryptographicSponge::absorb(&mut sponge, &Elements(&input));
+        assert_eq!(sponge.squeeze_native_field_elements(2), expected);
+
+        // Bytes and bits are taken from the low end of each element.
+        let mut sponge = <DuplexSponge as CryptographicSponge>::new(&domain_sep);
+        CryptographicSponge::absorb(&mut sponge, &Elements(&input));
+        let bytes = sponge.squeeze_bytes(40);
+        assert_eq!(bytes[..31], expected[0].to_bytes()[..31]);
+        assert_eq!(bytes[31..], expected[1].to_bytes()[..9]);
+
+        let mut sponge = <DuplexSponge as CryptographicSponge>::new(&domain_sep);
+        CryptographicSponge::absorb(&mut sponge, &Elements(&input));
+        let bits = sponge.squeeze_bits(260);
+        assert_eq!(bits.len(), 260);
+        assert_eq!(bits[252], expected[1].to_bytes()[0] & 1 == 1);
+    }
+
+    #[cfg(feature = "r1cs")]
+    #[test]
+    fn ark_sponge_gadget_matches_native() {
+        use ark_crypto_primitives::sponge::constraints::CryptographicSpongeVar;
+        use ark_r1cs_std::{alloc::AllocVar, R1CSVar};
+        use ark_relations::r1cs::ConstraintSystem;
+        use decaf377::r1cs::FqVar;
+
+        use crate::r1cs::DuplexSpongeVar;
+
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input = [Fq::from(1u64), Fq::from(2u64), Fq::from(3u64)];
+
+        let mut native = <DuplexSponge as CryptographicSponge>::new(&domain_sep);
+        CryptographicSponge::absorb(&mut native, &Elements(&input));
+        let native_elements = native.squeeze_native_field_elements(2);
+        let native_bytes = native.squeeze_bytes(40);
+        let native_bits = native.squeeze_bits(20);
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let input_vars: Vec<FqVar> = input
+            .iter()
+            .map(|x| FqVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+            .collect();
+        let mut sponge = <DuplexSpongeVar as CryptographicSpongeVar<Fq, DuplexSponge>>::new(
+            cs.clone(),
+            &domain_sep,
+        );
+        CryptographicSpongeVar::absorb(&mut sponge, &input_vars).unwrap();
+        let elements = sponge.squeeze_field_elements(2).unwrap();
+        let bytes = CryptographicSpongeVar::squeeze_bytes(&mut sponge, 40).unwrap();
+        let bits = CryptographicSpongeVar::squeeze_bits(&mut sponge, 20).unwrap();
+
+        assert_eq!(elements.value().unwrap(), native_elements);
+        assert_eq!(bytes.value().unwrap(), native_bytes);
+        assert_eq!(bits.value().unwrap(), native_bits);
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
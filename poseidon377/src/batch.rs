@@ -0,0 +1,242 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(any(
+    feature = "rate-2",
+    feature = "rate-3",
+    feature = "rate-4",
+    feature = "rate-5",
+    feature = "rate-6",
+    feature = "rate-7",
+))]
+use crate::PoseidonParameters;
+use crate::{Fq, Instance};
+
+/// Number of inputs hashed by each parallel task, each of which reuses a single instance.
+#[cfg(feature = "parallel")]
+const PAR_CHUNK_SIZE: usize = 1024;
+
+/// Hash many single [`Fq`] elements with the provided `domain_separator`.
+///
+/// Equivalent to calling [`hash_1`](crate::hash_1) on each input, but reuses a single instance.
+#[cfg(feature = "rate-1")]
+pub fn batch_hash_1(domain_separator: &Fq, inputs: &[Fq]) -> Vec<Fq> {
+    let mut state = Instance::new(&crate::RATE_1_PARAMS);
+    inputs
+        .iter()
+        .map(|value| state.n_to_1_fixed_hash(&[*domain_separator, *value]))
+        .collect()
+}
+
+/// Hash many pairs of [`Fq`] elements with the provided `domain_separator`.
+///
+/// Equivalent to calling [`hash_2`](crate::hash_2) on each input, but reuses a single instance.
+#[cfg(feature = "rate-2")]
+pub fn batch_hash_2(domain_separator: &Fq, inputs: &[[Fq; 2]]) -> Vec<Fq> {
+    batch_fixed(&crate::RATE_2_PARAMS, domain_separator, inputs)
+}
+
+/// Hash many triples of [`Fq`] elements with the provided `domain_separator`.
+///
+/// Equivalent to calling [`hash_3`](crate::hash_3) on each input, but reuses a single instance.
+#[cfg(feature = "rate-3")]
+pub fn batch_hash_3(domain_separator: &Fq, inputs: &[[Fq; 3]]) -> Vec<Fq> {
+    batch_fixed(&crate::RATE_3_PARAMS, domain_separator, inputs)
+}
+
+/// Hash many groups of four [`Fq`] elements with the provided `domain_separator`.
+///
+/// Equivalent to calling [`hash_4`](crate::hash_4) on each input, but reuses a single instance.
+#[cfg(feature = "rate-4")]
+pub fn batch_hash_4(domain_separator: &Fq, inputs: &[[Fq; 4]]) -> Vec<Fq> {
+    batch_fixed(&crate::RATE_4_PARAMS, domain_separator, inputs)
+}
+
+/// Hash many groups of five [`Fq`] elements with the provided `domain_separator`.
+///
+/// Equivalent to calling [`hash_5`](crate::hash_5) on each input, but reuses a single instance.
+#[cfg(feature = "rate-5")]
+pub fn batch_hash_5(domain_separator: &Fq, inputs: &[[Fq; 5]]) -> Vec<Fq> {
+    batch_fixed(&crate::RATE_5_PARAMS, domain_separator, inputs)
+}
+
+/// Hash many groups of six [`Fq`] elements with the provided `domain_separator`.
+///
+/// Equivalent to calling [`hash_6`](crate::hash_6) on each input, but reuses a single instance.
+#[cfg(feature = "rate-6")]
+pub fn batch_hash_6(domain_separator: &Fq, inputs: &[[Fq; 6]]) -> Vec<Fq> {
+    batch_fixed(&crate::RATE_6_PARAMS, domain_separator, inputs)
+}
+
+/// Hash many groups of seven [`Fq`] elements with the provided `domain_separator`.
+///
+/// Equivalent to calling [`hash_7`](crate::hash_7) on each input, but reuses a single instance.
+#[cfg(feature = "rate-7")]
+pub fn batch_hash_7(domain_separator: &Fq, inputs: &[[Fq; 7]]) -> Vec<Fq> {
+    batch_fixed(&crate::RATE_7_PARAMS, domain_separator, inputs)
+}
+
+/// Hash many single [`Fq`] elements with the provided `domain_separator` in parallel.
+///
+/// Produces the same output as [`batch_hash_1`], splitting the inputs across threads with rayon.
+#[cfg(all(feature = "parallel", feature = "rate-1"))]
+pub fn par_batch_hash_1(domain_separator: &Fq, inputs: &[Fq]) -> Vec<Fq> {
+    par_batch(domain_separator, inputs, batch_hash_1)
+}
+
+/// Hash many pairs of [`Fq`] elements with the provided `domain_separator` in parallel.
+///
+/// Produces the same output as [`batch_hash_2`], splitting the inputs across threads with rayon.
+#[cfg(all(feature = "parallel", feature = "rate-2"))]
+pub fn par_batch_hash_2(domain_separator: &Fq, inputs: &[[Fq; 2]]) -> Vec<Fq> {
+    par_batch(domain_separator, inputs, batch_hash_2)
+}
+
+/// Hash many triples of [`Fq`] elements with the provided `domain_separator` in parallel.
+///
+/// Produces the same output as [`batch_hash_3`], splitting the inputs across threads with rayon.
+#[cfg(all(feature = "parallel", feature = "rate-3"))]
+pub fn par_batch_hash_3(domain_separator: &Fq, inputs: &[[Fq; 3]]) -> Vec<Fq> {
+    par_batch(domain_separator, inputs, batch_hash_3)
+}
+
+/// Hash many groups of four [`Fq`] elements with the provided `domain_separator` in parallel.
+///
+/// Produces the same output as [`batch_hash_4`], splitting the inputs across threads with rayon.
+#[cfg(all(feature = "parallel", feature = "rate-4"))]
+pub fn par_batch_hash_4(domain_separator: &Fq, inputs: &[[Fq; 4]]) -> Vec<Fq> {
+    par_batch(domain_separator, inputs, batch_hash_4)
+}
+
+/// Hash many groups of five [`Fq`] elements with the provided `domain_separator` in parallel.
+///
+/// Produces the same output as [`batch_hash_5`], splitting the inputs across threads with rayon.
+#[cfg(all(feature = "parallel", feature = "rate-5"))]
+pub fn par_batch_hash_5(domain_separator: &Fq, inputs: &[[Fq; 5]]) -> Vec<Fq> {
+    par_batch(domain_separator, inputs, batch_hash_5)
+}
+
+/// Hash many groups of six [`Fq`] elements with the provided `domain_separator` in parallel.
+///
+/// Produces the same output as [`batch_hash_6`], splitting the inputs across threads with rayon.
+#[cfg(all(feature = "parallel", feature = "rate-6"))]
+pub fn par_batch_hash_6(domain_separator: &Fq, inputs: &[[Fq; 6]]) -> Vec<Fq> {
+    par_batch(domain_separator, inputs, batch_hash_6)
+}
+
+/// Hash many groups of seven [`Fq`] elements with the provided `domain_separator` in parallel.
+///
+/// Produces the same output as [`batch_hash_7`], splitting the inputs across threads with rayon.
+#[cfg(all(feature = "parallel", feature = "rate-7"))]
+pub fn par_batch_hash_7(domain_separator: &Fq, inputs: &[[Fq; 7]]) -> Vec<Fq> {
+    par_batch(domain_separator, inputs, batch_hash_7)
+}
+
+/// Split `inputs` into chunks and hash each chunk with `batch` in parallel.
+#[cfg(feature = "parallel")]
+fn par_batch<T: Sync>(
+    domain_separator: &Fq,
+    inputs: &[T],
+    batch: fn(&Fq, &[T]) -> Vec<Fq>,
+) -> Vec<Fq> {
+    inputs
+        .par_chunks(PAR_CHUNK_SIZE)
+        .flat_map_iter(|chunk| batch(domain_separator, chunk))
+        .collect()
+}
+
+/// Hash each of `inputs` with a single instance over `parameters`.
+#[cfg(any(
+    feature = "rate-2",
+    feature = "rate-3",
+    feature = "rate-4",
+    feature = "rate-5",
+    feature = "rate-6",
+    feature = "rate-7",
+))]
+fn batch_fixed<
+    const STATE_SIZE: usize,
+    const STATE_SIZE_MINUS_1: usize,
+    const NUM_MDS_ELEMENTS: usize,
+    const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+    const NUM_ROUND_ROWS: usize,
+    const NUM_ROUND_COLS: usize,
+    const NUM_ROUND_ELEMENTS: usize,
+    const NUM_PARTIAL_ROUNDS: usize,
+>(
+    parameters: &PoseidonParameters<
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >,
+    domain_separator: &Fq,
+    inputs: &[[Fq; STATE_SIZE_MINUS_1]],
+) -> Vec<Fq> {
+    let mut state = Instance::new(parameters);
+    inputs
+        .iter()
+        .map(|input| {
+            let words = core::array::from_fn(|i| {
+                if i == 0 {
+                    *domain_separator
+                } else {
+                    input[i - 1]
+                }
+            });
+            state.n_to_1_fixed_hash(&words)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{hash_1, hash_4, hash_7};
+
+    #[test]
+    fn batch_matches_single() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let inputs: [[Fq; 7]; 5] =
+            core::array::from_fn(|i| core::array::from_fn(|j| Fq::from((7 * i + j) as u64)));
+
+        let outputs = batch_hash_7(&domain_sep, &inputs);
+        for (input, output) in inputs.iter().zip(outputs.iter()) {
+            let [a, b, c, d, e, f, g] = *input;
+            assert_eq!(*output, hash_7(&domain_sep, (a, b, c, d, e, f, g)));
+        }
+
+        let inputs: [[Fq; 4]; 3] =
+            core::array::from_fn(|i| core::array::from_fn(|j| Fq::from((4 * i + j) as u64)));
+        let outputs = batch_hash_4(&domain_sep, &inputs);
+        for (input, output) in inputs.iter().zip(outputs.iter()) {
+            let [a, b, c, d] = *input;
+            assert_eq!(*output, hash_4(&domain_sep, (a, b, c, d)));
+        }
+
+        let inputs = [Fq::from(1u64), Fq::from(2u64)];
+        let outputs = batch_hash_1(&domain_sep, &inputs);
+        assert_eq!(outputs[1], hash_1(&domain_sep, inputs[1]));
+        assert!(batch_hash_2(&domain_sep, &[]).is_empty());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn par_batch_matches_batch() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let inputs: Vec<[Fq; 2]> = (0..3 * PAR_CHUNK_SIZE as u64 + 5)
+            .map(|i| [Fq::from(i), Fq::from(i + 1)])
+            .collect();
+
+        assert_eq!(
+            par_batch_hash_2(&domain_sep, &inputs),
+            batch_hash_2(&domain_sep, &inputs)
+        );
+    }
+}
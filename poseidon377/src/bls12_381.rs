@@ -0,0 +1,106 @@
+//! Poseidon over the BLS12-381 scalar field.
+//!
+//! This module provides the rate-1 to rate-4 instances of Poseidon over the scalar
+//! field [`Fr`] of BLS12-381, which is also the base field of Jubjub and Bandersnatch,
+//! with the same API as the instances over [`Fq`](crate::Fq), and R1CS gadgets for
+//! them with the `r1cs` feature.
+//!
+//! The parameters are generated by `poseidon-paramgen` with the same transcript
+//! procedure as those over [`Fq`](crate::Fq), for a security level of 128 bits, and
+//! the S-box is `x^5`.
+
+use crate::field::FieldParameters;
+
+#[cfg(feature = "r1cs")]
+pub mod r1cs;
+mod rate_1;
+mod rate_2;
+mod rate_3;
+mod rate_4;
+
+/// The BLS12-381 scalar field.
+pub use ark_bls12_381::Fr;
+
+/// Parameters for the rate-1 instance of Poseidon over [`Fr`].
+pub const RATE_1_PARAMS: FieldParameters<Fr, 2> = rate_1::RATE_1_PARAMS;
+
+/// Parameters for the rate-2 instance of Poseidon over [`Fr`].
+pub const RATE_2_PARAMS: FieldParameters<Fr, 3> = rate_2::RATE_2_PARAMS;
+
+/// Parameters for the rate-3 instance of Poseidon over [`Fr`].
+pub const RATE_3_PARAMS: FieldParameters<Fr, 4> = rate_3::RATE_3_PARAMS;
+
+/// Parameters for the rate-4 instance of Poseidon over [`Fr`].
+pub const RATE_4_PARAMS: FieldParameters<Fr, 5> = rate_4::RATE_4_PARAMS;
+
+/// Hash a single [`Fr`] element with the provided `domain_separator`.
+pub fn hash_1(domain_separator: &Fr, value: Fr) -> Fr {
+    RATE_1_PARAMS.n_to_1_fixed_hash([*domain_separator, value])
+}
+
+/// Hash two [`Fr`] elements with the provided `domain_separator`.
+pub fn hash_2(domain_separator: &Fr, value: (Fr, Fr)) -> Fr {
+    RATE_2_PARAMS.n_to_1_fixed_hash([*domain_separator, value.0, value.1])
+}
+
+/// Hash three [`Fr`] elements with the provided `domain_separator`.
+pub fn hash_3(domain_separator: &Fr, value: (Fr, Fr, Fr)) -> Fr {
+    RATE_3_PARAMS.n_to_1_fixed_hash([*domain_separator, value.0, value.1, value.2])
+}
+
+/// Hash four [`Fr`] elements with the provided `domain_separator`.
+pub fn hash_4(domain_separator: &Fr, value: (Fr, Fr, Fr, Fr)) -> Fr {
+    RATE_4_PARAMS.n_to_1_fixed_hash([*domain_separator, value.0, value.1, value.2, value.3])
+}
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use ark_ff::PrimeField;
+
+    use super::*;
+
+    fn fr(s: &str) -> Fr {
+        Fr::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn bls12_381_params_are_consistent() {
+        assert_eq!(RATE_1_PARAMS.arc.len(), RATE_1_PARAMS.rounds.total());
+        assert_eq!(RATE_2_PARAMS.arc.len(), RATE_2_PARAMS.rounds.total());
+        assert_eq!(RATE_3_PARAMS.arc.len(), RATE_3_PARAMS.rounds.total());
+        assert_eq!(RATE_4_PARAMS.arc.len(), RATE_4_PARAMS.rounds.total());
+
+        // The MDS matrix is the Cauchy matrix `1 / (i + t + j)`.
+        let mds = RATE_2_PARAMS.mds;
+        for (i, row) in mds.iter().enumerate() {
+            for (j, m) in row.iter().enumerate() {
+                assert_eq!(*m * Fr::from((i + 3 + j) as u64), Fr::from(1u64));
+            }
+        }
+    }
+
+    #[test]
+    fn bls12_381_hashes() {
+        let domain_sep = Fr::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let [a, b, c, d] = [1u64, 2, 3, 4].map(Fr::from);
+
+        assert_eq!(
+            hash_1(&domain_sep, a),
+            fr("35304054799632152034688222412211714813726379224497915540416203225234783256299")
+        );
+        assert_eq!(
+            hash_2(&domain_sep, (a, b)),
+            fr("35625373216413008838659114457231324299181905820466975862043902396939227967760")
+        );
+        assert_eq!(
+            hash_3(&domain_sep, (a, b, c)),
+            fr("34207874719770339340729325879838697048542381652280227195346800545630116085994")
+        );
+        assert_eq!(
+            hash_4(&domain_sep, (a, b, c, d)),
+            fr("4458742396028818111321800819237776736548536545720589400287820492651959776974")
+        );
+    }
+}
@@ -0,0 +1,512 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-1 instance of Poseidon over [`Fr`].
+pub const RATE_1_PARAMS: FieldParameters<Fr, 2> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!(
+                "35174739893055911104493616029378130908017657834702731071195911003169112450229"
+            ),
+            MontFp!(
+                "40230473166484073181383530626136429631051240172158259323832118663695222064618"
+            ),
+        ],
+        [
+            MontFp!(
+                "44839971797550275719608927493602345619671796219131839128512847210863471539893"
+            ),
+            MontFp!(
+                "48611564661854252146943435349048894917868766542696555144699929229862960415826"
+            ),
+        ],
+        [
+            MontFp!(
+                "40685922923883326639435460208454299274336241847181916712959630054792010757353"
+            ),
+            MontFp!(
+                "15664326207051802832937062272185115402684860397390155009854115660846132411502"
+            ),
+        ],
+        [
+            MontFp!(
+                "20394588293088308460232807257084465383971903994422965392216628021702933756783"
+            ),
+            MontFp!(
+                "38072899122370719412288433912125900148799524273752876684473712699262778897654"
+            ),
+        ],
+        [
+            MontFp!(
+                "49322204581160802920728528441668898478821361914594574984125707955891751339030"
+            ),
+            MontFp!(
+                "50347305345520436535964839331484028706021264874956603553067798037836173651466"
+            ),
+        ],
+        [
+            MontFp!(
+                "40078972002964969619442410831168416589272403782380052533737439660325916494912"
+            ),
+            MontFp!(
+                "16924481893633890556036089675091788745304335123156116162143840931183063196275"
+            ),
+        ],
+        [
+            MontFp!(
+                "15950945481350597658451757244282476321967934435937826665895833437077276040754"
+            ),
+            MontFp!(
+                "45461282993637819573288749652453263783235945959670912694991729940283965985733"
+            ),
+        ],
+        [
+            MontFp!(
+                "28038644046234298370628400737789907143817173874662134250914598498795544431933"
+            ),
+            MontFp!(
+                "11068658063110546729562922745621277134925014371838931507832785600289614789661"
+            ),
+        ],
+        [
+            MontFp!(
+                "52268179647931159024439236070026950641875512180000134500458099574638256905396"
+            ),
+            MontFp!(
+                "20086107503624605748636970696926057193402564249603453473832198318657465264844"
+            ),
+        ],
+        [
+            MontFp!(
+                "43167135986193729211374724389523185950396183466709666189982348666853925219274"
+            ),
+            MontFp!(
+                "17447884039561508187156464597183128324504945258768782636135974167343387840370"
+            ),
+        ],
+        [
+            MontFp!(
+                "38143921110340159188228723493420793611575037275078276538202087394517162729554"
+            ),
+            MontFp!(
+                "16552274712964434889263219772539968802934308159216951923060313539515542031786"
+            ),
+        ],
+        [
+            MontFp!(
+                "21533690197859269486445042768281999749203728740955226853531960445800814390872"
+            ),
+            MontFp!(
+                "21880324978302337713295482389160476412101864070449056420604485964938425631874"
+            ),
+        ],
+        [
+            MontFp!(
+                "21087213863282472584378891060158648179244240590829455065271622458534461188836"
+            ),
+            MontFp!(
+                "45441431536584624993024537766051601076632677727170866925607963222280486713049"
+            ),
+        ],
+        [
+            MontFp!(
+                "30366589203056029534723552859270912699187398046791858542022387243548834105416"
+            ),
+            MontFp!(
+                "16733270300941292512227942400499003353179417300568446496228472021958136869720"
+            ),
+        ],
+        [
+            MontFp!(
+                "47689344912249729511524931558254404476237521452234110431416696572010445584377"
+            ),
+            MontFp!(
+                "51082398733030351856006960204664584892075227924560364715540908659573989781750"
+            ),
+        ],
+        [
+            MontFp!(
+                "43167631539924493632724725819354890041217481730115306669306358893967667398874"
+            ),
+            MontFp!(
+                "26279180116818040201102703456273647693759458841914982758720296510206405428021"
+            ),
+        ],
+        [
+            MontFp!(
+                "46216367353381802394196440660508655919466089439065744128194487821032228754652"
+            ),
+            MontFp!("7697386669681950056820540614309128742475760814776187777185453179049760288818"),
+        ],
+        [
+            MontFp!(
+                "21817782321868614563882198267973607652180292878221625474094565055913249527776"
+            ),
+            MontFp!(
+                "32913105459022632076500291209667002113801444807962815598956143828295422498435"
+            ),
+        ],
+        [
+            MontFp!("8502502120080628515364514243099640525167810254369523636419786457595198827016"),
+            MontFp!(
+                "30648576830725883184207011877575066156796295678736109722327320104219004516016"
+            ),
+        ],
+        [
+            MontFp!(
+                "45671339585608301452963125193632555070872004563546575584248952737167410364255"
+            ),
+            MontFp!("2451562695720526255410159479987351458212942773001104345088347268337017907959"),
+        ],
+        [
+            MontFp!("7231969043003068614870036828648884326376597612869950504826116686399812612615"),
+            MontFp!(
+                "47273307399297813289148635743141600935869642174900722409535446469272321692250"
+            ),
+        ],
+        [
+            MontFp!(
+                "27329929942264081140373255677952719498722443205980316837000158653641048193545"
+            ),
+            MontFp!(
+                "34885886867965298185213628215610826501969641592969627478599392659366672373049"
+            ),
+        ],
+        [
+            MontFp!(
+                "25721535552417582691507498115295027536875255063383254204046680789623453672738"
+            ),
+            MontFp!(
+                "33423368799112636093951957186824418831319616314165017049161108153912788138739"
+            ),
+        ],
+        [
+            MontFp!(
+                "25189833401427047672367010746537756277335149535290712725530069868987176912248"
+            ),
+            MontFp!(
+                "47958321445457642291354706357838648199395839243428939094705320859005837779683"
+            ),
+        ],
+        [
+            MontFp!(
+                "31774563161791760397918769926476984438505538732495587514473389056037994152917"
+            ),
+            MontFp!(
+                "47850858374935538132163048932011117808042772861034650362901884832239133015769"
+            ),
+        ],
+        [
+            MontFp!(
+                "12199128582337530870446335645513552144450426981759317374083089598580379977396"
+            ),
+            MontFp!(
+                "47383053307574057321551022954108360871160286114060097754143644961632203800586"
+            ),
+        ],
+        [
+            MontFp!(
+                "13803873123545025453120373087635256640332504728831881021333511396025254579362"
+            ),
+            MontFp!(
+                "23837178543685639949465643421241187863068824855019641916163779619876342616994"
+            ),
+        ],
+        [
+            MontFp!(
+                "31189587782086980076115943766865860302487147542493494232203123657276644861038"
+            ),
+            MontFp!(
+                "46499582074722273407549664254855207560785631035023694347608062453482015876732"
+            ),
+        ],
+        [
+            MontFp!(
+                "26662253988013080389152043229142922449414668235746968994211290964651963062577"
+            ),
+            MontFp!("7640762545216115926527336572554187073301504782874830986070234716899751469743"),
+        ],
+        [
+            MontFp!(
+                "34498963117721665288552413822781095260286143856801028464976598055529802364121"
+            ),
+            MontFp!(
+                "36341838182182173408654982018067474537982027919615372559077851240339996099909"
+            ),
+        ],
+        [
+            MontFp!(
+                "48300815548868869899847665404889851174998730453507260888165473018017728774364"
+            ),
+            MontFp!(
+                "32747300835280625438818285868260843662581362309780466961516941514877133787742"
+            ),
+        ],
+        [
+            MontFp!(
+                "45303055704904829718227319208556302736092241137651995389242696979297340573498"
+            ),
+            MontFp!("4026222424264187292280393735543795340594832329968061359731987906507864963350"),
+        ],
+        [
+            MontFp!(
+                "31228142597140675203545792153829318135052985461159014806691885654670181871080"
+            ),
+            MontFp!(
+                "16750970802520682106296025351756788120283612961882144708385294780405339907653"
+            ),
+        ],
+        [
+            MontFp!(
+                "20590763839978944194181943371062517541785283010773701162056751611994399836929"
+            ),
+            MontFp!(
+                "37594126822410828138420253267022641722217448081718524151329800547846230255554"
+            ),
+        ],
+        [
+            MontFp!("2394917091132363277251637490654694317375647018617394697153359156851524846663"),
+            MontFp!(
+                "19737567843628339977867209886872321409023634418218069475717765230353858108711"
+            ),
+        ],
+        [
+            MontFp!(
+                "42204066697221303743688912704913307336835691602781490921152392223146514657878"
+            ),
+            MontFp!(
+                "38876322214460807353380405022935203128264986058834077654885314090533204762902"
+            ),
+        ],
+        [
+            MontFp!(
+                "40247991658021514679970964566577204929744204347802761928836742882323686245178"
+            ),
+            MontFp!(
+                "39561906161264753200012194261000443020947453081833838411624410505039816555019"
+            ),
+        ],
+        [
+            MontFp!(
+                "11166460550589161376470931941636300531100498757195643048019370497750388675069"
+            ),
+            MontFp!(
+                "24790565769125580275808568537357949817511203246184311136698467928472412457388"
+            ),
+        ],
+        [
+            MontFp!(
+                "11554811916664964398531930051533768691042015283409338870777253388332997487366"
+            ),
+            MontFp!(
+                "29075039934313065791873216420662295250443756892492849722546053940305352208592"
+            ),
+        ],
+        [
+            MontFp!(
+                "39006987007077517708714677464659567763889315959219170058379373515871687446558"
+            ),
+            MontFp!(
+                "10061028159773233365380812282944217591122833800994251428395880422048305719205"
+            ),
+        ],
+        [
+            MontFp!(
+                "25031744215108526550081827540392247511207429766528399786265447570055547629537"
+            ),
+            MontFp!(
+                "10547501786444119997366494351393198125463151669258024373934193854191592243660"
+            ),
+        ],
+        [
+            MontFp!(
+                "41670235909630407351693051359364867863450981844765151303749913478896054145311"
+            ),
+            MontFp!("1550363730816174450383908114106000535080436631518311072819950124158318191772"),
+        ],
+        [
+            MontFp!(
+                "45637974422753200101239746614094187617113326582060147125507840703682309645118"
+            ),
+            MontFp!("6370060789642013553710100441744454816236009933679880713369392969145674474801"),
+        ],
+        [
+            MontFp!(
+                "35620803994636505121581975698616513950631274399330479421894994077745439358719"
+            ),
+            MontFp!(
+                "16067112068611935345405777398473590697557771847332060708439576094222338198471"
+            ),
+        ],
+        [
+            MontFp!(
+                "30951410852793823879089954146089097398960021512400590702302072057244085482851"
+            ),
+            MontFp!(
+                "12276812530571706718883327429408937606433993990196477479137283768845651925632"
+            ),
+        ],
+        [
+            MontFp!("1005720997907928078270582364714493795153874104882338052955430808063076949858"),
+            MontFp!("9432539242737555038548151091729061683667954775317685872580932218706555557193"),
+        ],
+        [
+            MontFp!(
+                "48144091886915459055979584468520005030090312584666359911350135788203438616374"
+            ),
+            MontFp!(
+                "22450925011805294935671299553107487783565275020715623196582033415826118494250"
+            ),
+        ],
+        [
+            MontFp!(
+                "28149684753511832845415363706857286714650553416094912809755106801999479476946"
+            ),
+            MontFp!(
+                "18984416380626314263255929984317834766173910658882331424593963038930830788043"
+            ),
+        ],
+        [
+            MontFp!(
+                "48109900230700383470215980955557162221434150244334962594952526792484549956555"
+            ),
+            MontFp!(
+                "40552374456316069085238865117122876015343906448762540220517521948829052005624"
+            ),
+        ],
+        [
+            MontFp!(
+                "11526656751459710456216830286109707102065783384712439837497260759522383867216"
+            ),
+            MontFp!(
+                "49248089956591852389575948260598336493822205882486787578128339029114849462975"
+            ),
+        ],
+        [
+            MontFp!(
+                "39372448145844604168126556278211607335704246721513006643620494230495045549807"
+            ),
+            MontFp!(
+                "47966243235447948688248399775419397863458889989786324048551282844713998917659"
+            ),
+        ],
+        [
+            MontFp!(
+                "10668975765352037241237726978611677229760273562923261307501184329169652545894"
+            ),
+            MontFp!(
+                "39507142063581063677057438880300974612179697912640172055190488555355094497813"
+            ),
+        ],
+        [
+            MontFp!(
+                "15638468735129173771932513149618051284064233358516491068231561062569214055646"
+            ),
+            MontFp!(
+                "17353431924571233181583986362517131255570540171642397316569828436646526591477"
+            ),
+        ],
+        [
+            MontFp!("1113151614126806805734456303419241558692549524321573289125509096780876830226"),
+            MontFp!("9984420714488068655842637440690086256585448839019918893900233218326556751996"),
+        ],
+        [
+            MontFp!(
+                "35272971102390074745676114574216945810721613586667868556864593470832260078394"
+            ),
+            MontFp!(
+                "41799703040810741398331928206395151226132289978178889483708245720764196041488"
+            ),
+        ],
+        [
+            MontFp!(
+                "23792232833587856422363054609833448590404370086820676679702830143363402049151"
+            ),
+            MontFp!(
+                "23400519399894650768950735628474893615670522380725820954092838104223338024750"
+            ),
+        ],
+        [
+            MontFp!(
+                "27249609507238299376315178191811683563055616067419555246827701486258799476504"
+            ),
+            MontFp!(
+                "31460888976162844111664970745263960397533358231300142520392677905396078538854"
+            ),
+        ],
+        [
+            MontFp!(
+                "50541111392701415266654707208995846306449617779331389560072929586596066181460"
+            ),
+            MontFp!(
+                "16849402175596403120072436766532798469469816167994479896578935023255475833387"
+            ),
+        ],
+        [
+            MontFp!(
+                "42001541432800637111202252596131403836308927391584450209899896684682298556999"
+            ),
+            MontFp!("7555678132801484327842862804202549162661136010229184979905673538160644182471"),
+        ],
+        [
+            MontFp!(
+                "16973896084242861113146860522670036493260039473958217656803484585799718732593"
+            ),
+            MontFp!(
+                "42770539705351951841624093062193706601998280964803890045059967451602239435405"
+            ),
+        ],
+        [
+            MontFp!(
+                "32967596628774896802370445267419978233370846153035552840999780942126744328522"
+            ),
+            MontFp!(
+                "29885680756072762644563275495535410002799284565481770605563275267677242393534"
+            ),
+        ],
+        [
+            MontFp!("6767565503770123230340610856538459014642791435134695578527084133222911388089"),
+            MontFp!(
+                "38034555907959016273994483610140537209819290169171432036495630086575144181148"
+            ),
+        ],
+        [
+            MontFp!(
+                "35085508904879414050180652811899618801153816228166027426392174635054369363148"
+            ),
+            MontFp!(
+                "36657441819968029240636827111992013950645228690585008802597958876784513528202"
+            ),
+        ],
+        [
+            MontFp!("1322579115549556048043817488502303168290944210869541203532619083870097554885"),
+            MontFp!(
+                "16882844793625225132625646093399829180269843611668936510323341639265456546672"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "26217937587563095239723870254092982918845276250263818911301829349969290592257"
+            ),
+            MontFp!(
+                "34957250116750793652965160338790643891793701667018425215069105799959054123009"
+            ),
+        ],
+        [
+            MontFp!(
+                "34957250116750793652965160338790643891793701667018425215069105799959054123009"
+            ),
+            MontFp!(
+                "39326906381344642859585805381139474378267914375395728366952744024953935888385"
+            ),
+        ],
+    ],
+};
@@ -0,0 +1,689 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-2 instance of Poseidon over [`Fr`].
+pub const RATE_2_PARAMS: FieldParameters<Fr, 3> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!(
+                "22845428001683035001757566886883286202794971213257405898653937619342210809967"
+            ),
+            MontFp!("9417748754940633550207934065289371845381781919953741119572192314859326756938"),
+            MontFp!(
+                "26689524918965143061772476650114353366218076390810271464955542964810918690462"
+            ),
+        ],
+        [
+            MontFp!(
+                "23667059179080355346057115978894489941696461722584637607820962465827837825273"
+            ),
+            MontFp!(
+                "46040731510256722444481170188451497748934948859158655271594666645009954130420"
+            ),
+            MontFp!(
+                "38113870242218339764104342092524703512067945072549749734867314604234805824264"
+            ),
+        ],
+        [
+            MontFp!(
+                "32611384898299796592751439904458085938192833715280734492851042712787358716294"
+            ),
+            MontFp!(
+                "33324362596726879582132023009108655879122993156535518227166123425490127359165"
+            ),
+            MontFp!(
+                "31233555617069547075173148270170739854954869805099073326535758453459148394049"
+            ),
+        ],
+        [
+            MontFp!(
+                "49586904012565111968216908241229472951281535032044747164567307267295875715026"
+            ),
+            MontFp!("2127315429566462795785821689020248617345433219758728404787310279338768885895"),
+            MontFp!("6529018108583238830114188423456393413649137858781161494022316162010318640950"),
+        ],
+        [
+            MontFp!(
+                "23649163789043242678776939693475922815241122333107994583151017148206730700267"
+            ),
+            MontFp!(
+                "23982229504468820398618649637438150346315963308296786781329337326290795690104"
+            ),
+            MontFp!("591156623969703606931660477957796564969105236556604782683460292150992424027"),
+        ],
+        [
+            MontFp!(
+                "30084821438110117176679719381277976601442389474785273476376177505312413978818"
+            ),
+            MontFp!("8191445513196243205449886391313722723661952074636811757767516173019594024447"),
+            MontFp!("7395469702730664138851034971541308544559423038002849895231878040178489451243"),
+        ],
+        [
+            MontFp!("6341026417807630730297613183042003952592308254703287399431399858814665410034"),
+            MontFp!(
+                "20652945640892549037566441607019982429364028401857035181703584312655393699470"
+            ),
+            MontFp!(
+                "34824482815556921067370021614278815836412588641486739976853046658332582052879"
+            ),
+        ],
+        [
+            MontFp!(
+                "48976251116034708464354192855627142326430307437063612591022448910603652475844"
+            ),
+            MontFp!(
+                "33973886383203962364416371082456886713311059913098568681589079598311290341730"
+            ),
+            MontFp!(
+                "23178695901093722025363352164825380417033560143406423973483056057902806658538"
+            ),
+        ],
+        [
+            MontFp!(
+                "37639985941451408425998780104098889280755425755378537999685550579014673118383"
+            ),
+            MontFp!(
+                "26775243484593836205445491219466322530651352314052529708867746286247232484014"
+            ),
+            MontFp!(
+                "45192808452249683828194325900235208250060616352273105247249793679536128618560"
+            ),
+        ],
+        [
+            MontFp!(
+                "20071905668670782245953495435146197909174054068927952772615658727538296929513"
+            ),
+            MontFp!(
+                "29406444079001843296331241892729732794993064809431324386692390118024299913033"
+            ),
+            MontFp!(
+                "27838323777556259931728341767911143308623943462495399158698183979133847887998"
+            ),
+        ],
+        [
+            MontFp!(
+                "37966516969555117666235778718758211780248673468521118136255691264235287331101"
+            ),
+            MontFp!(
+                "38967514381541394472121804114513934243293763636056980334619885239009397430989"
+            ),
+            MontFp!(
+                "40028341421872977135388961875125188765067392991342155013375654821131464031936"
+            ),
+        ],
+        [
+            MontFp!("1001613543114792173050023076800478067685833458234763459693802494435332553026"),
+            MontFp!(
+                "35311269311571980681347601071503185346173815932615188417499353359668184483756"
+            ),
+            MontFp!(
+                "34462018568703773567209528024867849348825919032173701573186920278058007201948"
+            ),
+        ],
+        [
+            MontFp!(
+                "10262313581383491791096554537171596499065114438052122965745631817632928267916"
+            ),
+            MontFp!(
+                "27992105705964906687339251318982892339131668293855140653976808119275969963868"
+            ),
+            MontFp!(
+                "42462965018262714629670524480631638355909533906921865587801092326698629415353"
+            ),
+        ],
+        [
+            MontFp!("731862653799218940119653655008077067672754736101148769466023549852112342198"),
+            MontFp!("4990007337841733858417996170512765349002981269633789630458334995176400108943"),
+            MontFp!(
+                "40084733361186863392667730682383810731143560279010011121513673913355226479536"
+            ),
+        ],
+        [
+            MontFp!(
+                "13472436169748051397435195704947919623141237809210825830289534653879222770162"
+            ),
+            MontFp!(
+                "50265394623175139483913971841917882707100028857396846929106208750084545654680"
+            ),
+            MontFp!(
+                "30949636244369656784898042441618620789717441754208684137133141585230651202314"
+            ),
+        ],
+        [
+            MontFp!(
+                "39349527001767045643298791216991158309532217712981217526969063621356866755244"
+            ),
+            MontFp!(
+                "10201123598983809630230278570888438897128042351935922235849565841871811914458"
+            ),
+            MontFp!(
+                "23172475118582244440190512123295348171804989798503475437607943201606731741328"
+            ),
+        ],
+        [
+            MontFp!(
+                "14539407523908390695568439980336876821578107531746451022879738674020080696447"
+            ),
+            MontFp!(
+                "40663157753728471683236602461375503173626639949161575886034585669523632164258"
+            ),
+            MontFp!(
+                "41478194862632007866456615972070216026646814855913562507497145373244784230963"
+            ),
+        ],
+        [
+            MontFp!("8622071781902469999213511033556562060366946974247448298500382057461783727407"),
+            MontFp!(
+                "10830466122360977082234691006699380271235478376684567138914651532981394980126"
+            ),
+            MontFp!("7232867760693339528219049901552217783200496118772426979320627096878605711592"),
+        ],
+        [
+            MontFp!(
+                "35079031576108189052612014501875323358302221902936394805957983077486222384523"
+            ),
+            MontFp!("9167584557822184508030612984628817005319158702740560176792366793373157325196"),
+            MontFp!(
+                "18074610613332847447681900241221546049326446100125268088064210259992236150854"
+            ),
+        ],
+        [
+            MontFp!(
+                "16517331293849824763364413300554893951203379531056781643365275271457171439509"
+            ),
+            MontFp!("1089195116745468118265857623837494042159140798941646088987006397242383772274"),
+            MontFp!(
+                "17610774827548103443018122640740452953487672960426564938071840133447084592273"
+            ),
+        ],
+        [
+            MontFp!(
+                "16560559120122945159055643110747776066049028486182219761206720512074050208959"
+            ),
+            MontFp!(
+                "27307859881734247797242923704101058112359845830743800995003842091962029823992"
+            ),
+            MontFp!(
+                "12574849717458401184175214146191580664064712008876201865632459615475659992210"
+            ),
+        ],
+        [
+            MontFp!(
+                "41543024786871098911868759261087187686116402204621994511525841195606772065593"
+            ),
+            MontFp!(
+                "32756776979566599709291671539531904499663794908005108228877494335137125655012"
+            ),
+            MontFp!(
+                "13532075261688893387142549255064455454261052957758568678924958037395894977520"
+            ),
+        ],
+        [
+            MontFp!(
+                "27998635034936574887381376947571205212530065197443449727964597035616638471847"
+            ),
+            MontFp!(
+                "46844950058534215635661018375636671603134206482544045614009211691474640545183"
+            ),
+            MontFp!(
+                "45526729914336495893163690991383148465568398910057609608759474521512027209057"
+            ),
+        ],
+        [
+            MontFp!(
+                "33872763155222545113791047234953158863665411185433215920322248059422557784040"
+            ),
+            MontFp!(
+                "29460460133995562592349301799618260583016525430602287818866026649784680483523"
+            ),
+            MontFp!(
+                "20989735902099832593496529105916714634367556088781730822973410813751407497879"
+            ),
+        ],
+        [
+            MontFp!(
+                "45520909994106166183648093095035256424865964915398087578040671480508057709894"
+            ),
+            MontFp!(
+                "51725468395149610367573509613712677267589764836449813856852867186555661819360"
+            ),
+            MontFp!(
+                "15651425738690741503176348763612585457187791238335345329322432112360560461704"
+            ),
+        ],
+        [
+            MontFp!(
+                "24945611636441666054318407598715861234462523499499595448517505049488110615214"
+            ),
+            MontFp!(
+                "16301539278215369687299504108802130276435745980769657328958446248303035955176"
+            ),
+            MontFp!(
+                "44301912086845175690540373083352281317454905399747377547078556917865150832989"
+            ),
+        ],
+        [
+            MontFp!(
+                "49778396049625358995132756791358478390102266869360717476676437985801446091889"
+            ),
+            MontFp!(
+                "39153290849454135141271361494475662688916570729187492943387960313673041446148"
+            ),
+            MontFp!(
+                "26991647174508986366084735074783734164113574105838673377039994017089952182486"
+            ),
+        ],
+        [
+            MontFp!("2403186673978540311545640594843853543643227950077142548745613485294780855141"),
+            MontFp!(
+                "10329119407814891230092277094164085309383530588695756714355495404374556885786"
+            ),
+            MontFp!("702874774050464869184650423477652304207160836554060769812462487419268335441"),
+        ],
+        [
+            MontFp!(
+                "50074752288575507441174064290633923705846137616935811121806601719875594753019"
+            ),
+            MontFp!(
+                "29342013954649824046585779860716199589577998264314693554503526645430239741709"
+            ),
+            MontFp!(
+                "30361262010467679813283989386832242969994684207282862420161955708780601098704"
+            ),
+        ],
+        [
+            MontFp!(
+                "30351766991733436072768691856661481615952834268179175645404913610213825680401"
+            ),
+            MontFp!("1664790785025469129434526735675056241660553790656417175792384823268029808499"),
+            MontFp!(
+                "46322613396380948619628922350351042103212945902543833810047505753710422764619"
+            ),
+        ],
+        [
+            MontFp!(
+                "19201041989805354003325674917539369487037360530331018121215975522940072895718"
+            ),
+            MontFp!(
+                "40713015437441300486859919171751834181664365406786230895978883504408178991598"
+            ),
+            MontFp!(
+                "33165732938688300275094516949956529791738898002532702022139088595193131778075"
+            ),
+        ],
+        [
+            MontFp!(
+                "14061271190311903391893296597687971683409755901603758686994336529066151361543"
+            ),
+            MontFp!(
+                "30017115602362133544428282226988281665898062715638283950670461587514995528337"
+            ),
+            MontFp!(
+                "51753869079004912869790737342975337426219894984640345689956073613279132042928"
+            ),
+        ],
+        [
+            MontFp!(
+                "33726414746454922641356272061208629386217736066083215066025761465516292002524"
+            ),
+            MontFp!(
+                "35210911960966295213959369947626866692609088740419141627680657893370017484508"
+            ),
+            MontFp!("4141660360379187486674487904202902153994931348181391058833499399706861611970"),
+        ],
+        [
+            MontFp!(
+                "40875507602384303877352581938122059128419080856403401999804217089730126842394"
+            ),
+            MontFp!(
+                "40087000777143021841754444382736050591105162668380193612093257295149366899295"
+            ),
+            MontFp!("360624956582718333774625848521820613496494801229735030900568999941102114884"),
+        ],
+        [
+            MontFp!(
+                "11477457618572262344538985253904058228123098918969349239608807377007383139313"
+            ),
+            MontFp!(
+                "45254065959701514055715124638217794757142405925270580133395538824914155859795"
+            ),
+            MontFp!("7358301974444199529119918346570045958313961795808719967710329208237031192970"),
+        ],
+        [
+            MontFp!(
+                "12699242192147355636134649376980967434530726534868950427468890401069053166328"
+            ),
+            MontFp!(
+                "21574666344082745249505505003959311248488352938383685650327775178001000819481"
+            ),
+            MontFp!(
+                "26884614465157200359785958766234836206045908165626281395333341075981676550453"
+            ),
+        ],
+        [
+            MontFp!(
+                "24852179396523634535353419259325074241075443941784111871159513114358632326939"
+            ),
+            MontFp!(
+                "18211374049926652874841112832356976374552310648534087414193782959262371639828"
+            ),
+            MontFp!(
+                "35375087468679890118148541757069905069645283903997802919905971779989387935312"
+            ),
+        ],
+        [
+            MontFp!(
+                "10990565882246234777729800926231517373372767769410158316318619229150139473531"
+            ),
+            MontFp!(
+                "24032051352715262242401866721229748797811123047937790057119251127169973152881"
+            ),
+            MontFp!(
+                "28438207938166443253621581664511800652351092231061919031828588532652227156596"
+            ),
+        ],
+        [
+            MontFp!(
+                "45687415910148401846425146916404001259507215012315057946595070426660110660811"
+            ),
+            MontFp!(
+                "45110274946146660516282617054500711927725036481516113151944675392925576760894"
+            ),
+            MontFp!(
+                "34137223191115457752400978731943408868071059969283171338528389319055436889307"
+            ),
+        ],
+        [
+            MontFp!(
+                "32713597945226548966370311170658233211393249637894135199446742658128239554646"
+            ),
+            MontFp!(
+                "33192735799627684320353855891675583234013662286026882194028603892523478740123"
+            ),
+            MontFp!("3776516710765546122697723171053195964096694338618710275820928005602928573368"),
+        ],
+        [
+            MontFp!("7944265310164359722118605517046022331921673922658843007239349330762185482392"),
+            MontFp!(
+                "31715470560273718011671907461103911969141721217361313081393607676239225390364"
+            ),
+            MontFp!(
+                "42597200531215255918594108726444759128389740499081765757576512781903070927748"
+            ),
+        ],
+        [
+            MontFp!(
+                "38622022004327168167649998385610411001269295886008008390410138141853113112315"
+            ),
+            MontFp!(
+                "41639697610221403852836714442228562101456287247472949217445027773398868160711"
+            ),
+            MontFp!(
+                "13150428932552364822098333559189689694536801235647059449631307475679127673867"
+            ),
+        ],
+        [
+            MontFp!(
+                "34373201154776600875146892219437926734069726168942010866640582754750620448511"
+            ),
+            MontFp!(
+                "35279388813336423141682178743103346803927226873412975071332881859187650721328"
+            ),
+            MontFp!(
+                "27774333846963600481511257662914321673673373955022726430096988470105398209714"
+            ),
+        ],
+        [
+            MontFp!(
+                "35483098310244161758040046647575507323602964525852265859901382236130852700062"
+            ),
+            MontFp!(
+                "29940267014590605840700764600461238078000060065790052366740674868616463896153"
+            ),
+            MontFp!(
+                "25739729541991097592540752609074245193891720936529356517885412505653561292306"
+            ),
+        ],
+        [
+            MontFp!(
+                "48592083802088089137279496160575479196512409111032554810647441613023130859714"
+            ),
+            MontFp!(
+                "11785199761165312817707782850381637428548166564182162215870947169062065050309"
+            ),
+            MontFp!(
+                "37737752799213701220107891469991549645342013287264825965243870418885646558575"
+            ),
+        ],
+        [
+            MontFp!(
+                "42185188402563931515779080170268528213759004550497190339895558255998756484305"
+            ),
+            MontFp!(
+                "29762891826290037177744093996573528328489719757759674546874936845480876153625"
+            ),
+            MontFp!(
+                "41048820922442053658536094708659956071999957589299249055158529124710069314949"
+            ),
+        ],
+        [
+            MontFp!(
+                "23522761739531401519638478389533837643267162088884579912861987842834590059205"
+            ),
+            MontFp!("1577795469784700584387088254598570414875555853652586256618632377936727476028"),
+            MontFp!(
+                "44736559789102463530519848387460288056502543753479209858246163243414900222987"
+            ),
+        ],
+        [
+            MontFp!(
+                "26124083702829644397351772362411115259535338884150178345524352910165101961197"
+            ),
+            MontFp!(
+                "44068951341630081435585895581791390859877371169408252908545483578489725374863"
+            ),
+            MontFp!(
+                "17361638690026756605596598636834592222533091153902430487355356691164428488413"
+            ),
+        ],
+        [
+            MontFp!(
+                "11647749325942062475038392890134560282358764855423971504181109950516197620555"
+            ),
+            MontFp!(
+                "40500824589487208102914420357982839590679741935577095819591537825040671597495"
+            ),
+            MontFp!(
+                "40785454914612292708630091724060381548396170012626005122221974905540756399207"
+            ),
+        ],
+        [
+            MontFp!(
+                "20356565648078510551533920457384489131486812605779651499082832980671484016522"
+            ),
+            MontFp!(
+                "29112015816272783569379820912763801267400393662021206741257278682896986065788"
+            ),
+            MontFp!(
+                "44733525682678568645165250261910618247576685489408569315352106466147253727345"
+            ),
+        ],
+        [
+            MontFp!(
+                "10565089313326829099491655443012602039407136842910355472790748905051752334527"
+            ),
+            MontFp!(
+                "17636725232687840901682379138469361259504197357613401374231593442081994684058"
+            ),
+            MontFp!(
+                "49455315856290883709107366844714705621304302274078267246639667945964681049589"
+            ),
+        ],
+        [
+            MontFp!("9394008262967145993303699385193109865242274266934320660589155431516724290078"),
+            MontFp!(
+                "43900030624416944756689868922581338041945556634263488704748136303189975871142"
+            ),
+            MontFp!(
+                "48191060987937123985909229203502109068831786295580349499493566153386798933792"
+            ),
+        ],
+        [
+            MontFp!(
+                "43361003342366732200083697919780436439559769824804240685090356123985970020322"
+            ),
+            MontFp!(
+                "51781017630032437459807770951906705163397801283934820333816755077832398052644"
+            ),
+            MontFp!(
+                "48709885480080800062931271139518814968675476404080001820094094562394087333146"
+            ),
+        ],
+        [
+            MontFp!("517790256320125875817609575412307930532409010548566690252780460066482113077"),
+            MontFp!(
+                "44162810324319332227049701762799226779884107012295764844978771561514939267347"
+            ),
+            MontFp!(
+                "18329654636987314320181933412859769661035966797908420315450106106967427683830"
+            ),
+        ],
+        [
+            MontFp!(
+                "19615817294297319883822402917727657184715513281307347906216027590033902491654"
+            ),
+            MontFp!(
+                "41253529578447529272966952303200110041594696386420191733502268974386805490133"
+            ),
+            MontFp!(
+                "44551831777999888557799092846226179853573739860014862778154450411343402505480"
+            ),
+        ],
+        [
+            MontFp!("7559320320412538022925238701423240040386861903490421501028835005166431445991"),
+            MontFp!(
+                "37703931107359229792370407986681862000136875665994615587307408387674509893202"
+            ),
+            MontFp!(
+                "36981740507092948379101268207755436295900732477477035639426425826451873208221"
+            ),
+        ],
+        [
+            MontFp!(
+                "32220980229047213196553181881899738269582355015949570895340236724112466067644"
+            ),
+            MontFp!(
+                "50293059130849255052300726917626827619838259588242288225714397253126361265854"
+            ),
+            MontFp!(
+                "11461804647277285059017568254865894963059032745983570268950239637841369012659"
+            ),
+        ],
+        [
+            MontFp!(
+                "18013977259539479149208518875602054515978941464362999137382072720864300137360"
+            ),
+            MontFp!(
+                "24262624423049731894299705457348106409867388962133042917690439456364005006686"
+            ),
+            MontFp!(
+                "52144014954916185528618956479620036725707233503135302723071827906176892132960"
+            ),
+        ],
+        [
+            MontFp!("1184581588830389264718635894468521582491343657766431963764898469843010069554"),
+            MontFp!(
+                "40610686652907253784724857559452211268043806809440823202590722974294091011876"
+            ),
+            MontFp!("914146278018379374974950713477496078415253310809588124235733809681562817725"),
+        ],
+        [
+            MontFp!(
+                "25097904996332603354919889504928778253424502922841324137944667824221109148627"
+            ),
+            MontFp!(
+                "17243512742922204136836426230051681478081152509764057560629791259889066934988"
+            ),
+            MontFp!("4081759897772210684578749284259535944860111230995927338347978237888465764094"),
+        ],
+        [
+            MontFp!(
+                "36765065937060082811140519464443523737174704483573873520674596392742252800514"
+            ),
+            MontFp!(
+                "24321691704536604443532250865415818572294386696143821782936373725880267878369"
+            ),
+            MontFp!("2395267786872226534851212329566983083100169355414401082132646655651751509972"),
+        ],
+        [
+            MontFp!("3950196104600672123800974772107750971315804388021439464175836038546084353494"),
+            MontFp!(
+                "25263626252513390011901163434994070547778323834423264534286975919312362778567"
+            ),
+            MontFp!(
+                "29896651629929954914209712564022862418500548724265113861327208457712342872872"
+            ),
+        ],
+        [
+            MontFp!("9539335037415025952662358277279804491504129471492873246424615506400771650468"),
+            MontFp!(
+                "27193798077315487970531850693078048672125444244557439962378947858653645205697"
+            ),
+            MontFp!(
+                "21395546066883486929277886107956208113265529474198702733513032754482401541525"
+            ),
+        ],
+        [
+            MontFp!(
+                "30032609953800770008376919053783279995466549980411699863594692563988339280340"
+            ),
+            MontFp!(
+                "42581228581701308758620568691477079823478678724481681987270661843757181526448"
+            ),
+            MontFp!(
+                "48272706010725470294995350043440475847552808087172142257372185646688911955162"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "34957250116750793652965160338790643891793701667018425215069105799959054123009"
+            ),
+            MontFp!(
+                "39326906381344642859585805381139474378267914375395728366952744024953935888385"
+            ),
+            MontFp!(
+                "31461525105075714287668644304911579502614331500316582693562195219963148710708"
+            ),
+        ],
+        [
+            MontFp!(
+                "39326906381344642859585805381139474378267914375395728366952744024953935888385"
+            ),
+            MontFp!(
+                "31461525105075714287668644304911579502614331500316582693562195219963148710708"
+            ),
+            MontFp!(
+                "43696562645938492066206450423488304864742127083773031518836382249948817653761"
+            ),
+        ],
+        [
+            MontFp!(
+                "31461525105075714287668644304911579502614331500316582693562195219963148710708"
+            ),
+            MontFp!(
+                "43696562645938492066206450423488304864742127083773031518836382249948817653761"
+            ),
+            MontFp!(
+                "14981678621464625851270783002338847382197300714436467949315331057125308909861"
+            ),
+        ],
+    ],
+};
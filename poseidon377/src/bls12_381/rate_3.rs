@@ -0,0 +1,870 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-3 instance of Poseidon over [`Fr`].
+pub const RATE_3_PARAMS: FieldParameters<Fr, 4> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!(
+                "51224678911162681061039682631750937273835564066950223832355911142511454151981"
+            ),
+            MontFp!(
+                "39837585688895373840291445627535765305939272624677070068196425498624961721658"
+            ),
+            MontFp!(
+                "47460721781806397777951710430593486908360577168954956741924437101309624417792"
+            ),
+            MontFp!(
+                "17259272199883821326076750214445706970475246021361939581796302773131203777652"
+            ),
+        ],
+        [
+            MontFp!(
+                "23896675405843999799053898278835222588729342954112090077541106073385729576508"
+            ),
+            MontFp!(
+                "36838769127012992672428703332040407098630117596251624066119970041181700174728"
+            ),
+            MontFp!(
+                "19595757675319108133364970905333514351611045732259974871885896059144209951510"
+            ),
+            MontFp!(
+                "47108231520891819882510519562133748522102537682088007385404511530110618938993"
+            ),
+        ],
+        [
+            MontFp!(
+                "31210907566457797728825539514730024979990779414728066440892860375725532976962"
+            ),
+            MontFp!(
+                "48415086254475130714127814072765652666675145233930684514454537087977600708150"
+            ),
+            MontFp!(
+                "35162450306635767369059303254505973209667614400228044708658839078153658907124"
+            ),
+            MontFp!(
+                "32898223201563657707557704049980743818191801342586941043298513876876216768240"
+            ),
+        ],
+        [
+            MontFp!(
+                "32803675198132816446445974454150934956779543039802051712263012280227967988468"
+            ),
+            MontFp!("6312494213773194422795610719000375622095292078190058715316987125677650208768"),
+            MontFp!("9630469376416323935045709375168574508480934685544919155614945891018139944632"),
+            MontFp!("5010797553660710365797602277519469458121262208072495792666075341859434617043"),
+        ],
+        [
+            MontFp!(
+                "51715849689063340404677345545815998618517449320192673529118926497568554184033"
+            ),
+            MontFp!("1544411030531512792285741940003107855255395739103706654539056447861975434541"),
+            MontFp!(
+                "36053792868805433480899393746207494168458046826665571369029040515793998681740"
+            ),
+            MontFp!("8085937328223372039237979218009753405134415853608990122162333611322680740296"),
+        ],
+        [
+            MontFp!(
+                "22763786289350418106083724577808732998038960052355200759533929150163643402883"
+            ),
+            MontFp!("8069640696914068961112715843209900432348389562598548104518392433103655482551"),
+            MontFp!("1782712239890906435923301315700515636989212168803532956307163510555614604677"),
+            MontFp!("7474484220167263563686975168022142380643407423414677360501334672187540312919"),
+        ],
+        [
+            MontFp!(
+                "12963851422948085089795407635253491275856913644452432805086450920172693167599"
+            ),
+            MontFp!(
+                "51554204906081284658532138112728391763084334993280390895046794509635693166203"
+            ),
+            MontFp!(
+                "27104506325569448788824363306813084401546778610631615560990920735068222060630"
+            ),
+            MontFp!(
+                "12671396443860961303957644310016394810510216575883263998894654432305938547078"
+            ),
+        ],
+        [
+            MontFp!(
+                "33944455943922764161167721751820025062294904803153271552980805752634110240491"
+            ),
+            MontFp!(
+                "16834384416238469661639820046581189553546589325879870523089171696194981124836"
+            ),
+            MontFp!(
+                "30494489648659977332305918166922154371520697334743909492156844937602358982450"
+            ),
+            MontFp!(
+                "33893205504869056610803610010589442252058449555097876588569470813221505300845"
+            ),
+        ],
+        [
+            MontFp!(
+                "43273317422094858285764918382555381161268197155930584030840463902265295085208"
+            ),
+            MontFp!(
+                "29318500392088584648891561260057291784199878543665305514045885281427673886078"
+            ),
+            MontFp!(
+                "36474311757887052355992696464229983372620080766133842884728393026025157242887"
+            ),
+            MontFp!(
+                "42417253566166796991952925266030163395688798545650059008380704768717531566852"
+            ),
+        ],
+        [
+            MontFp!(
+                "30507749731248599903381149659258082690670726229933185849656346836519088529709"
+            ),
+            MontFp!(
+                "38218232375618220357352867459171051875485569650231352797804151555288496913076"
+            ),
+            MontFp!(
+                "48996315360367171780926711279569189644893555718592023649984254679989869469733"
+            ),
+            MontFp!(
+                "25860731039457798633883579851756109341784836102653954563418533385210050156595"
+            ),
+        ],
+        [
+            MontFp!(
+                "48716132337305679290264172950759316735043017901687317114216677606402116639263"
+            ),
+            MontFp!(
+                "17392002118343560181105280132030718298391476428556867308828113367709327427712"
+            ),
+            MontFp!(
+                "45002131386227380306387921698169901124533461163382140773299754461623594756252"
+            ),
+            MontFp!(
+                "15092363976470656085162248006636923012833461866658913847430958588775522667336"
+            ),
+        ],
+        [
+            MontFp!(
+                "29278628774705525510842909583839598315230250165407086305986888460893675354441"
+            ),
+            MontFp!("9905477049490337604958476980130149786261642753062722556965255527853595786847"),
+            MontFp!(
+                "22796046122531360604283863492459975373838233659804919437899440253233911732618"
+            ),
+            MontFp!(
+                "40948304432411889166209109680557771757000873649070061804416262477758272710994"
+            ),
+        ],
+        [
+            MontFp!(
+                "49689897438552646624470689595257989891005904668495294586389537295646271993375"
+            ),
+            MontFp!(
+                "27771748086268841219694982040138174408025278517285302042711486487045505380767"
+            ),
+            MontFp!(
+                "19607724368297525590567951566007931333163961915902186892795130558486434996410"
+            ),
+            MontFp!("2908448756519967328302197120249437149092856859019642496830402495304142636728"),
+        ],
+        [
+            MontFp!(
+                "31825938489535701784182774036497392404476901714800829757154420993288927889421"
+            ),
+            MontFp!(
+                "47609414990345740295881480649978954489594843962514466835501742919410845610194"
+            ),
+            MontFp!(
+                "22391316870668938047875997092565304870275458208722805102000730932099150581274"
+            ),
+            MontFp!(
+                "12894583918304806940336518260625243325461092491401468667836178926230216873552"
+            ),
+        ],
+        [
+            MontFp!(
+                "33327174392447967928447492298407812005856984377506934565243692952121199034025"
+            ),
+            MontFp!(
+                "23346142063096737237008845712063939668552304756094133358234450814637572241790"
+            ),
+            MontFp!(
+                "49081925754806820625906809562245749994525516159401520635427929377770459133243"
+            ),
+            MontFp!(
+                "22089577933620897391512101737633456626487254433195444117190915209876586493571"
+            ),
+        ],
+        [
+            MontFp!(
+                "42633207097650760932993093512667533488126647694181271084065348515351834560581"
+            ),
+            MontFp!(
+                "50108332398110441395623368830188327354297038308915384481840699935252088094679"
+            ),
+            MontFp!(
+                "41407135853587910492500534218551637077446090131458685241387383605873599734569"
+            ),
+            MontFp!(
+                "51112670166656947440598087833213810731477517654395322873459724774981983393763"
+            ),
+        ],
+        [
+            MontFp!(
+                "21012864521547103412831184521462102123767434797813370338471939260240108400502"
+            ),
+            MontFp!(
+                "22043931404701836582727112900707324636686833935311070419188144718993971017582"
+            ),
+            MontFp!(
+                "27075183549530214276467616500756643355601365227128765865744555534757941783349"
+            ),
+            MontFp!(
+                "40037606024925010515615847583622745451189502915252199316047595372323921216781"
+            ),
+        ],
+        [
+            MontFp!(
+                "21621346807728311752620948125029343898951695880952393271668891950646147101311"
+            ),
+            MontFp!(
+                "26096019477368517518900338166397242987315174511749192559303864735971678298441"
+            ),
+            MontFp!(
+                "29187100509049277136441725332701044677389227058775705584347474331675219292231"
+            ),
+            MontFp!(
+                "45606994185082016761259849458269822540534465056175106187162499073855759378528"
+            ),
+        ],
+        [
+            MontFp!(
+                "13835253890071851179982932853638476767409659356872020650230439771195016130313"
+            ),
+            MontFp!(
+                "40245630439118037273516878878498533049209812754941120432661806900641949655312"
+            ),
+            MontFp!(
+                "25009762192016448129323458644625746976940917942800873144565450164995073297348"
+            ),
+            MontFp!(
+                "24478650747493808565239868067833090217339251421640305113474170976941127754994"
+            ),
+        ],
+        [
+            MontFp!(
+                "43290705682243061199174880823407240579771701315960703950713978046754738915194"
+            ),
+            MontFp!(
+                "20666908667563388690216068460118379906240111488387613750831847705240889309328"
+            ),
+            MontFp!(
+                "29689567960111684599063718330759674856043616613643715021475215918124714775736"
+            ),
+            MontFp!(
+                "32722346880273386977327950026525473818976319605179592310863193054975504105147"
+            ),
+        ],
+        [
+            MontFp!(
+                "44089555432536247270927874422561348759784726699975485331418293931736361090448"
+            ),
+            MontFp!(
+                "49497970020084659212823938809823005520405655388686420164688865115240807038637"
+            ),
+            MontFp!(
+                "14479170743694396774368291870814246106676364341316754213111659753016153902180"
+            ),
+            MontFp!(
+                "11203390137815179858796201014321449704073348523894300209977406560163900111558"
+            ),
+        ],
+        [
+            MontFp!(
+                "33287930886531925692229727444836952680479275253438678441457951847600407122199"
+            ),
+            MontFp!(
+                "16003536963559739240446538325568128384668329472689781833835286902967008768131"
+            ),
+            MontFp!(
+                "43040350834787683795631801917902767640861142569319730362408443450327919712119"
+            ),
+            MontFp!("3531937735089203331054942998639074999947175691169747716469449270272874816794"),
+        ],
+        [
+            MontFp!("9498929221730888125752665125640808131292771774022089363496374520521550992866"),
+            MontFp!(
+                "13540521314528532626882536147830727864622265918336942406454714444349100463518"
+            ),
+            MontFp!(
+                "38427336564979280635524579582847562886519374646633253998847659762281507341760"
+            ),
+            MontFp!(
+                "33584095412193414131843213388986056024704845118905162130545235208957721058503"
+            ),
+        ],
+        [
+            MontFp!("9802212093440385806218834673865235682836290036891389252642358748819947963851"),
+            MontFp!(
+                "27027494588750672667549583325871984541918765702930567201393240360255100659998"
+            ),
+            MontFp!(
+                "20810741448496777927589520205549212294183875349758677088333772433704314420166"
+            ),
+            MontFp!(
+                "46492116931975206744730808076280632158600397467655681126660756843216818360323"
+            ),
+        ],
+        [
+            MontFp!(
+                "51128642741778067383317256621058616787054233257715379239372587888101272082921"
+            ),
+            MontFp!(
+                "39656911603330113299384045723202748917314552427380345383897915838958644946356"
+            ),
+            MontFp!(
+                "40145868530726256801026789858784292224515324044881063066389017238934704382961"
+            ),
+            MontFp!(
+                "37850425042655155916907612008166811989060192458396487534660160948992558875635"
+            ),
+        ],
+        [
+            MontFp!(
+                "36913190692945001518173531443490018488483555789655907031764653443043381561559"
+            ),
+            MontFp!("2751290615421710010117479274394789242571856556315900063594357865570070281783"),
+            MontFp!(
+                "37325930156371774413338142022593360308451995377924644814955358523331406882069"
+            ),
+            MontFp!(
+                "50268437674394011998750563109395598739338452272748932083838315353605427529859"
+            ),
+        ],
+        [
+            MontFp!("6115329609452231763919063626941615282017454415078820503306200314788040384235"),
+            MontFp!("1315134324297401576438624054926949959506077689405611873320513350704218779474"),
+            MontFp!(
+                "33578556399802220531782073312315451075248948108175404633763490741159185841925"
+            ),
+            MontFp!(
+                "19951637098732958027954931189075514280994315267674897793468660835475928976518"
+            ),
+        ],
+        [
+            MontFp!("4356667742959103798433459086821151899922295181632364689946539306886436544972"),
+            MontFp!(
+                "21288642227914676799442242494496731136276494427146050449885733530066751713946"
+            ),
+            MontFp!("7260587067513661007205937560923276221647994828064985487007843406915468744302"),
+            MontFp!("1003411199296175244455874773245325940712389822750146305398994939550162836405"),
+        ],
+        [
+            MontFp!("891426979856526908276607654738442197919123597246496612330906177171396420978"),
+            MontFp!(
+                "31667536933497349664718688350944946997645999480101532361478064765571337896820"
+            ),
+            MontFp!(
+                "30643655906137091890860159330150573031675683184068878015375675550723409130225"
+            ),
+            MontFp!("3182393181022526141548572698807659657031430856552213346146655485994842138549"),
+        ],
+        [
+            MontFp!("470176141884552820941059987419071551879611299084468983282626305495925226386"),
+            MontFp!(
+                "40424537971871938944555064783697251529348318720829038390822747730836069124202"
+            ),
+            MontFp!(
+                "35391385334779735039047182319541083881411693117272102672567069925315828306521"
+            ),
+            MontFp!(
+                "20969160273171286405614352642163982654236366511675284308855027652266662460412"
+            ),
+        ],
+        [
+            MontFp!(
+                "41398951675163939825245317116202322640035465447227947051376472020485680288127"
+            ),
+            MontFp!(
+                "37504081250669133128703103476680402267980921784861093060086564150785132096870"
+            ),
+            MontFp!(
+                "29281582031910975357943274701364178935321125226882646229907099695579966815277"
+            ),
+            MontFp!(
+                "48135231271276964996766893088299471306233727500491438756586467248168413528406"
+            ),
+        ],
+        [
+            MontFp!(
+                "39813196361047381153254228034724310304313123540115951005171919128227471219261"
+            ),
+            MontFp!("8744167774028500593193345113367035802865454787459364048040210758617909568934"),
+            MontFp!(
+                "26298976562067391837475769501072358738119392032723874726244608543065975149339"
+            ),
+            MontFp!("8544864806610984631040366248812622998366217652119200629303479231553537925438"),
+        ],
+        [
+            MontFp!(
+                "33401885092841459389905169575431022938538755478377930933012434308776841658566"
+            ),
+            MontFp!(
+                "37813770312932155570801753373676594384925313661085612593616075678899612040806"
+            ),
+            MontFp!(
+                "49881079659295098841571943996545261747314058290776503478220396417459724181617"
+            ),
+            MontFp!(
+                "47900536078545977826191557004616226947563734684950243880480408868403583102098"
+            ),
+        ],
+        [
+            MontFp!("930916432929125928590890230883632575743096081853260963413072732519893423672"),
+            MontFp!(
+                "44600249811329208325287879202443902677773376680739459643431708639864774656343"
+            ),
+            MontFp!(
+                "16845400328563820311148247173146417532102518171456182693572824508751542342632"
+            ),
+            MontFp!(
+                "32561894585842314922548201332983433324584772609134939590624397863350699890246"
+            ),
+        ],
+        [
+            MontFp!(
+                "32379728546330122290902269432031132640834210378376001822468164020641836206553"
+            ),
+            MontFp!(
+                "32589886874219885428218674665890587860471374038354429306235459891941000234998"
+            ),
+            MontFp!(
+                "43514729289262063652829189362927857677151007894459269209281295159070981665696"
+            ),
+            MontFp!(
+                "24469202793645129312821701616648205420473603871219813682079925015932167328442"
+            ),
+        ],
+        [
+            MontFp!(
+                "24278142934044242881527890551630800787249788848702213537282917655234431688269"
+            ),
+            MontFp!(
+                "30732493489280069554525807723338588684261111507053371464557320713616740233699"
+            ),
+            MontFp!(
+                "17985185811209421037120579723064375893639784568315123877932802792306819678858"
+            ),
+            MontFp!(
+                "47092836551665872556547176190057254324153756852984359295107315136638532836263"
+            ),
+        ],
+        [
+            MontFp!("524200041198069756771387473374338971952644380859330043334700892157325348122"),
+            MontFp!(
+                "22645711591751828654489798172498393076221885563329082946675517613316789385081"
+            ),
+            MontFp!(
+                "34967656993677345047126755015602757263460365217322329970632735607287153720959"
+            ),
+            MontFp!(
+                "47716164504928491714847455244438106868023197304205697617311532947388065657666"
+            ),
+        ],
+        [
+            MontFp!(
+                "19666423674779042132087243949558682077387643537572724432862519417967092770820"
+            ),
+            MontFp!(
+                "31011765360339397135284515650686506140831653715519304876923179558650715670712"
+            ),
+            MontFp!(
+                "11426338555382805983941505535044154062769930479995016461317237243414439494536"
+            ),
+            MontFp!(
+                "37929691966351728804115915928055869223156552806799587225864677162925891398186"
+            ),
+        ],
+        [
+            MontFp!("8987759189534570042356911451809807094994953299217551992583723618877727019148"),
+            MontFp!(
+                "27257357651442681665165971570722457823983655766457843063157166228152703641219"
+            ),
+            MontFp!("3449412913958379121666471192179460185956082821935743903057392513255678327625"),
+            MontFp!(
+                "14850531223929725403857418545539207975284091359871842137701785722691779394496"
+            ),
+        ],
+        [
+            MontFp!(
+                "29817045459280575427204024759933188867898368998202984428772539983880946908916"
+            ),
+            MontFp!(
+                "20560460577804860845326959825866154854097136904743120983640858825755112533636"
+            ),
+            MontFp!("2404217620318822149036472903884670345263349918009485371642889624533800662082"),
+            MontFp!(
+                "32557350888647796155154412151299686036280728489369981902302962408174839497820"
+            ),
+        ],
+        [
+            MontFp!("9593897050811677391739861893135571282354118830204076825322631989468311710743"),
+            MontFp!(
+                "38816789262031698589467344072736268636577168489772791096864052184176045597363"
+            ),
+            MontFp!(
+                "44915254961379140222917743154134749740347225829905898292337598019207262259606"
+            ),
+            MontFp!(
+                "11661395116200953660278332035360876689715424358282798270799773975744044565494"
+            ),
+        ],
+        [
+            MontFp!("4482575775972336446450106608413134357680770996721565346449713700054481534641"),
+            MontFp!("6797843681556253842474495774006419946651765305531164375510495601973071223674"),
+            MontFp!("3076566499728249839297865278071457081118252994061397569219557348839538250884"),
+            MontFp!(
+                "11948134602210058279734596536685036490249152520735667598515919309389923034237"
+            ),
+        ],
+        [
+            MontFp!(
+                "39598113788526120640963324420696080257516104895411793324485613320873832026220"
+            ),
+            MontFp!(
+                "20515439916996933289943552066902933839615499776546463100854501718278084900355"
+            ),
+            MontFp!(
+                "42187121585812047860771015548446803298084825767030385610912804639616462561495"
+            ),
+            MontFp!(
+                "26777412901554251520853562048492073183259106609478294681209764579250487729277"
+            ),
+        ],
+        [
+            MontFp!(
+                "24474492752157118052944025884397243538342068083494220530310002864975916272857"
+            ),
+            MontFp!(
+                "43380508821428388277984429771625954069472384045376977257746355556832704741771"
+            ),
+            MontFp!("9996798309045151437100028129168284026230031303631103483121891785315551024512"),
+            MontFp!("1716639528139598723289351624900142766300480260080182638173761032082275976604"),
+        ],
+        [
+            MontFp!(
+                "38852603530243871777525718263699259765766655659322067640211424502655576817139"
+            ),
+            MontFp!(
+                "29929582651368647234480378327646273768080544011287014350021254573566913288803"
+            ),
+            MontFp!(
+                "12438079186328904753281936856384365300877266774533746770950311399372303667948"
+            ),
+            MontFp!("5684829383994046713549763894724145110965208222476907405769328573572244515962"),
+        ],
+        [
+            MontFp!(
+                "22540055812976480117687294616127941583363602554566031798818635590337787394342"
+            ),
+            MontFp!(
+                "49486831752504265246325983550216634281206798526440582173989114801804610282334"
+            ),
+            MontFp!(
+                "45486505013541101643650789482688335039353672652699953766559167840751442269710"
+            ),
+            MontFp!(
+                "23706152196045119443346426397546719807973005238829628224164621054053899055835"
+            ),
+        ],
+        [
+            MontFp!(
+                "14296546052628862510326514696511121482355347447247896841443997354285431840050"
+            ),
+            MontFp!("7590394267167243471350554380096196940660953219709545508736837993285673416803"),
+            MontFp!(
+                "51762623985506760225887903130080625881135638471699085817056145840372340687610"
+            ),
+            MontFp!(
+                "38868360230494053264819938352010127269748746122429988257971056651189530596691"
+            ),
+        ],
+        [
+            MontFp!(
+                "36286095885230162059094856675737344482382092922425238841782770427473811463020"
+            ),
+            MontFp!(
+                "22651482461700118502409958399170197122554892112726742475091773455930553101572"
+            ),
+            MontFp!("3812702406198632110288046230290559496214537630604718994299352654010404328716"),
+            MontFp!(
+                "43027858872749450178431948746449445373835904059504244787860486845289981473463"
+            ),
+        ],
+        [
+            MontFp!(
+                "36664664203838374742880848229424871897633570821072483190695660080411434799483"
+            ),
+            MontFp!(
+                "10725517250309659653389927159598441314913728088581661806598921062489791797605"
+            ),
+            MontFp!(
+                "44825937442920596244777427494798641907226772043780822828160304900105474996033"
+            ),
+            MontFp!(
+                "31157513779207058612012279388487407991859657772701221014437281102984347497679"
+            ),
+        ],
+        [
+            MontFp!(
+                "26069611505763321167828881609808015932796879856137123818516465973932090609405"
+            ),
+            MontFp!(
+                "31525435341137301609340910638662282211274150379260065436263778963754239680036"
+            ),
+            MontFp!(
+                "26316931912914453873774955953911216563827208573792751269846265586217816145788"
+            ),
+            MontFp!(
+                "37436143825017956241719958110634128546689748404151978916562803805972682067985"
+            ),
+        ],
+        [
+            MontFp!(
+                "30654395912070375405363629107963151716824305555690834549707976630153827565360"
+            ),
+            MontFp!(
+                "37175448828503659833442666181159824323239380961448793116775713846319350919563"
+            ),
+            MontFp!(
+                "12133055396613748340824041184576063319813532811013480054588567446405323926381"
+            ),
+            MontFp!(
+                "16116861146405130585013935393301363615695691822033745186079798729189368741551"
+            ),
+        ],
+        [
+            MontFp!(
+                "47663588394012416399142632689891748350514921264571612543975527039377612117566"
+            ),
+            MontFp!(
+                "37128685363621512772897798359515923488753545593425002767863140981935407310822"
+            ),
+            MontFp!(
+                "14545791766291151638768874929119753703514058630517814032243468288733812307630"
+            ),
+            MontFp!("3335016481849945548223107640521619237198697948889476873063589137872062214565"),
+        ],
+        [
+            MontFp!(
+                "32756920390439772337378997348325703769030376568430475619341748253110827309280"
+            ),
+            MontFp!("3289281564635899589647059831516224941687713498317857568850903266674596784765"),
+            MontFp!(
+                "31142442815466021031962678539844756321200125703489288549015294756164763265662"
+            ),
+            MontFp!(
+                "35974512463759725798235912521137863290953950655201947005056016819591503473546"
+            ),
+        ],
+        [
+            MontFp!("1744771273010367549231009523131533093183562821258641735096389677795463083653"),
+            MontFp!(
+                "48396109490852699281823446502176956775930471551623002870354442631183349276432"
+            ),
+            MontFp!(
+                "45122321186669367576871209931245028553792010994009568414443354272509294807403"
+            ),
+            MontFp!(
+                "39925568594958891766395867259163834850804198633883921948634076031425969760545"
+            ),
+        ],
+        [
+            MontFp!(
+                "15987834152400047096555928399223171287798124152080450912575121488651220550596"
+            ),
+            MontFp!("3362419142911355963831554225395915708890041753411554534695415103124946011438"),
+            MontFp!(
+                "13248955859733947310426814688025482159731358932485412465993026913811570884529"
+            ),
+            MontFp!(
+                "27710661826738045636791476037962435380383291450835057069711742810157911391574"
+            ),
+        ],
+        [
+            MontFp!(
+                "15756465495940618607797322303821736658582690699256124950846548055596202989646"
+            ),
+            MontFp!(
+                "35919805172743080213216170200726254943059962892224837095907547409541590288174"
+            ),
+            MontFp!(
+                "18425079533138985547083473203640896450136238012677924009354112773890467417395"
+            ),
+            MontFp!(
+                "39129152186542261371182129092894283133085495910950155830586951401018162695157"
+            ),
+        ],
+        [
+            MontFp!(
+                "17700416282928102820552738256743913352309984045095704244903886013700774751310"
+            ),
+            MontFp!(
+                "14403334110282769117408190887946811603142332644596739831416801529876946696125"
+            ),
+            MontFp!(
+                "33468896595329992524377930507726134175965658138008373671075617710250166399212"
+            ),
+            MontFp!(
+                "51862422043534844850779879453128275587335245724997127620512380671698169960108"
+            ),
+        ],
+        [
+            MontFp!(
+                "35826629644805549016371555836939210945051777655953951382577275964740346260288"
+            ),
+            MontFp!(
+                "29715080979086455701089781933291179647595686975961236244977159170100890239912"
+            ),
+            MontFp!(
+                "30408593242396003036020898412448425656352898239139795648530574773674293953120"
+            ),
+            MontFp!("2467457140536936895651893160448112171740068863128554174065613202079704720792"),
+        ],
+        [
+            MontFp!(
+                "11852873551093310949195545643061697308484451766363740111464322857772584025156"
+            ),
+            MontFp!("1115946630398019275664583947583534300142892913749385388381126237252964362082"),
+            MontFp!("1681646025356624101676958004097628834513701740800503264563532220504878638683"),
+            MontFp!(
+                "20503434172178074463846261985701635533821990902884742555568969450558831964851"
+            ),
+        ],
+        [
+            MontFp!("1661002186677083968352896451997434772210377725044581879589957139580631657113"),
+            MontFp!(
+                "41004111282611764324152421899310416613578967477196389750624291996582532298190"
+            ),
+            MontFp!(
+                "42292899126270775480723852214357527415187469012477815897675677223797288750062"
+            ),
+            MontFp!(
+                "35511559843259295949615609144221213271950799107673045592644731080561659954212"
+            ),
+        ],
+        [
+            MontFp!(
+                "29453426834202625414592001373876422408246172426541309589075683841760519883829"
+            ),
+            MontFp!(
+                "41647353269036552085426946791284757593425861824369120849286276846401278474506"
+            ),
+            MontFp!(
+                "29669331484933565459607361881976832143466549694131625332410234632632370993187"
+            ),
+            MontFp!(
+                "26829527299892099177123774133075536507202515400352569191513317912120034320160"
+            ),
+        ],
+        [
+            MontFp!(
+                "14708170276385985642592100927559106743640033688551934264737206955957552624555"
+            ),
+            MontFp!(
+                "32456874199976580031560313772300008978102988092664163540124643399884196166787"
+            ),
+            MontFp!("4769786457138907721357917436422187158222786407162633637780110958801830471551"),
+            MontFp!("1341634014200114919052384914086328582897180015450791070005238571947590063083"),
+        ],
+        [
+            MontFp!(
+                "49841422359593421905870377127558310307549994412191557962074766491936472366935"
+            ),
+            MontFp!("4467171677206712824641477800910245711071395352985372287179249441072245990562"),
+            MontFp!(
+                "35163703940907033873531213272687884039681126034213558256683033425479983314922"
+            ),
+            MontFp!(
+                "11115971798638785255543067474957417738020476445425466654888071659494430062538"
+            ),
+        ],
+        [
+            MontFp!(
+                "33916865435569467452976366760650816523661391272868858817218621769970866307950"
+            ),
+            MontFp!(
+                "32043364359415303079412305043414341932086644266002211199360563107178261493684"
+            ),
+            MontFp!(
+                "36460548121265525620221512660448272125656298458551703339857609206862843708432"
+            ),
+            MontFp!(
+                "50014226311811968232429928853102687935598209991106671590378599539579574508987"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "39326906381344642859585805381139474378267914375395728366952744024953935888385"
+            ),
+            MontFp!(
+                "31461525105075714287668644304911579502614331500316582693562195219963148710708"
+            ),
+            MontFp!(
+                "43696562645938492066206450423488304864742127083773031518836382249948817653761"
+            ),
+            MontFp!(
+                "14981678621464625851270783002338847382197300714436467949315331057125308909861"
+            ),
+        ],
+        [
+            MontFp!(
+                "31461525105075714287668644304911579502614331500316582693562195219963148710708"
+            ),
+            MontFp!(
+                "43696562645938492066206450423488304864742127083773031518836382249948817653761"
+            ),
+            MontFp!(
+                "14981678621464625851270783002338847382197300714436467949315331057125308909861"
+            ),
+            MontFp!(
+                "45881390778235416669516772944662720107979233437961683094778201362446258536449"
+            ),
+        ],
+        [
+            MontFp!(
+                "43696562645938492066206450423488304864742127083773031518836382249948817653761"
+            ),
+            MontFp!(
+                "14981678621464625851270783002338847382197300714436467949315331057125308909861"
+            ),
+            MontFp!(
+                "45881390778235416669516772944662720107979233437961683094778201362446258536449"
+            ),
+            MontFp!(
+                "11652416705583597884321720112930214630597900555672808405023035266653018041003"
+            ),
+        ],
+        [
+            MontFp!(
+                "14981678621464625851270783002338847382197300714436467949315331057125308909861"
+            ),
+            MontFp!(
+                "45881390778235416669516772944662720107979233437961683094778201362446258536449"
+            ),
+            MontFp!(
+                "11652416705583597884321720112930214630597900555672808405023035266653018041003"
+            ),
+            MontFp!(
+                "15730762552537857143834322152455789751307165750158291346781097609981574355354"
+            ),
+        ],
+    ],
+};
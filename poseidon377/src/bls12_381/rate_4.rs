@@ -0,0 +1,1071 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-4 instance of Poseidon over [`Fr`].
+pub const RATE_4_PARAMS: FieldParameters<Fr, 5> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!(
+                "37679052194837531472016270169565219416387501111427473068758636033914544266479"
+            ),
+            MontFp!(
+                "17358817669155831442311279233102347541097120520591368801950510010043397736297"
+            ),
+            MontFp!(
+                "38505894054527628952492460319827584056684715621743535690323697120081706727178"
+            ),
+            MontFp!(
+                "21761007004682803231765326203979733649664220117813915470472644616355573853667"
+            ),
+            MontFp!("8753869049510618789736289895373973834875137762564395197911602862872112715233"),
+        ],
+        [
+            MontFp!(
+                "19987933731823876302775206149786392986845148693447433480641172442307726300860"
+            ),
+            MontFp!(
+                "47280370591088604528787365120437442206763695047008581192050505756840305547314"
+            ),
+            MontFp!("3059763098804347883378187472516106263432306197734995478008257406675497341420"),
+            MontFp!(
+                "25458184106317185354400077619327991050836757448362205197207672802646281226733"
+            ),
+            MontFp!("3175660759697597924577855925264092988731029771249477946092698163415438649937"),
+        ],
+        [
+            MontFp!(
+                "42669051531385980189759866988026749977416020042713822387987801184764349457377"
+            ),
+            MontFp!(
+                "28152494054008986388456165963998301878535945510499758293208970781478775488397"
+            ),
+            MontFp!(
+                "13778181943390328203244861279320270914434588612282862409362242266228081498256"
+            ),
+            MontFp!(
+                "38064182742444000897529314860472608592058009701746660763045065006742624108326"
+            ),
+            MontFp!(
+                "13335850239037945104270592524438821162515362244823351042907514827664359190781"
+            ),
+        ],
+        [
+            MontFp!("3873401384979169098140203422525668439377070034848496606372905443152248791809"),
+            MontFp!(
+                "28081528909234823337008886090432875606748734389787857372574772585861216051763"
+            ),
+            MontFp!(
+                "34041505377924241960282155024624396427535044679794587105671001200844651988420"
+            ),
+            MontFp!("6984088112387805914714473555277704049636275791624060750086103876625722271018"),
+            MontFp!(
+                "12998315216605282237027898122689292917053304830416303490807691721290045791876"
+            ),
+        ],
+        [
+            MontFp!(
+                "28727562078406107470114940837420733851385006727725078606837701621454831368687"
+            ),
+            MontFp!(
+                "13554202960231604565524217705666944636880321244954164910697812576917597884558"
+            ),
+            MontFp!(
+                "17708922999682279620684618629319290959238157499162769909041378549938357418155"
+            ),
+            MontFp!(
+                "12506847566578054268750177077520033808092741707219194048253747780650404609726"
+            ),
+            MontFp!(
+                "46454121854970829331423145575922453396717872380121685327180658564857536772520"
+            ),
+        ],
+        [
+            MontFp!(
+                "17387662519483330535518415383347113301749060641284886720568407956611692185342"
+            ),
+            MontFp!("4400090962523266744688300770256086479131607341501636716065123543832865275339"),
+            MontFp!("4971079177650895204445476352814244457142214961386474164106927146903942523781"),
+            MontFp!(
+                "33810204588209694168296233826345997910041345232725420362824278546641939023174"
+            ),
+            MontFp!(
+                "39368524179898426248482407394396692280418516579835407998501220120902045307647"
+            ),
+        ],
+        [
+            MontFp!(
+                "26416074874015657669193236087281956674199088798065813345104352264019542828009"
+            ),
+            MontFp!(
+                "12235823731305118301620854586463925010977691660458502579533468316615452110579"
+            ),
+            MontFp!(
+                "46473634142926767735589316772867812329978258511269244571213123472773503272945"
+            ),
+            MontFp!(
+                "29474799678194488908903967365397057007267416245015650107620797874955991018793"
+            ),
+            MontFp!(
+                "36396282893313598677597899371245255173050816559438842082124349479865915213480"
+            ),
+        ],
+        [
+            MontFp!(
+                "18536359198515068966298481472065160697741886877914182811752662521927750515073"
+            ),
+            MontFp!("1541517052671715187314549955698311718263991254796716856965776691957522386765"),
+            MontFp!(
+                "25528027002689289036314058066257132565403683933011905967924061873229752249868"
+            ),
+            MontFp!(
+                "50914277065105252741112143521579658391208824303476824183675846240445139521389"
+            ),
+            MontFp!(
+                "12669255784666339545214328515021672753662644508205693263431948615041346900009"
+            ),
+        ],
+        [
+            MontFp!(
+                "10196556501961424195718094774721167529554163051912988053023936849062634869370"
+            ),
+            MontFp!(
+                "38862727424831766595648837592892462756975525533793995859989021589709529231794"
+            ),
+            MontFp!(
+                "17807865079437029577366703221361941259494918666445926266882024148849786204046"
+            ),
+            MontFp!(
+                "22943998821829450941770294274861301827337544018723748518329505316620902887890"
+            ),
+            MontFp!(
+                "13499410230890345167876141293588692261092470434074419811214538817636907561654"
+            ),
+        ],
+        [
+            MontFp!(
+                "34092003638908236951973076150513861900860223103673446477096609135650997275236"
+            ),
+            MontFp!("7147800470284773367656816297369104452939255825670136964978876724508880809121"),
+            MontFp!(
+                "45845481175573746306277233357170749292793338843052523378555502851361670875257"
+            ),
+            MontFp!("999559447179756490079331871639199331509772973858695477451870660034161178515"),
+            MontFp!(
+                "49685466365770491878942709941360130668690250354616281783651161590593922394362"
+            ),
+        ],
+        [
+            MontFp!(
+                "24326706553522468381511079245787022643838153883763439831542200332500980034275"
+            ),
+            MontFp!(
+                "11875926229492837061511919620585274300297603596802609324542629618761483693072"
+            ),
+            MontFp!(
+                "42023057294698627537337927872743462180683346832304172410212479916070189247494"
+            ),
+            MontFp!("8354156675065329913107160116212065722757487634203707412728567141600427091965"),
+            MontFp!("4033380577158671659313581691420771024974747735694728439842929397313347025203"),
+        ],
+        [
+            MontFp!(
+                "41614881711039953865875987146754251246111769953862583833425422289686850997807"
+            ),
+            MontFp!(
+                "24303195248637307337913536470533839413045125699454108502779191703375065253293"
+            ),
+            MontFp!(
+                "43718459685968986531678315580782571359868338060941924739460453762142263906211"
+            ),
+            MontFp!(
+                "38187979821015486385002505400644180428838572606752385136364012258413653896788"
+            ),
+            MontFp!(
+                "16783476248066408718001173607823236895802863181817613355787226069838698199369"
+            ),
+        ],
+        [
+            MontFp!(
+                "38592098257846100783148851755695975303424675922860285584603051038575851720418"
+            ),
+            MontFp!(
+                "36693201191628556827609856796308533324137019414295263784588075087111494904437"
+            ),
+            MontFp!(
+                "36444721185226691785880248336664087648547017069464640118458663329564931916508"
+            ),
+            MontFp!(
+                "26459424029478496088654066357340498394141800555329494646938903375847705692407"
+            ),
+            MontFp!(
+                "12499713786364883638316793027483067907426961172858374116999955086741018057730"
+            ),
+        ],
+        [
+            MontFp!(
+                "35926131207759561876557839914247702662745661876220308014652450535951908285580"
+            ),
+            MontFp!(
+                "23776261266956329194665711430059675081734464713550201621035954039163096880295"
+            ),
+            MontFp!(
+                "11844127997051516084212487774730307105341451594778395361992549960166623455662"
+            ),
+            MontFp!(
+                "37474924926769403744967030820595694796930120688352786777972440309240606820619"
+            ),
+            MontFp!(
+                "11630663418569497814375700969478544566033617388277148713247266988300722303814"
+            ),
+        ],
+        [
+            MontFp!(
+                "46441485797202556471860394354909433113964136319996976925770353056788895336340"
+            ),
+            MontFp!(
+                "19975824190235569705296171773473953812733410019338228084281133468032344310917"
+            ),
+            MontFp!(
+                "23637407986796978790731458126723293133143910162867849796177952975377654741827"
+            ),
+            MontFp!(
+                "16675596343356387260062592099076748756971845980636038780854984818665414717015"
+            ),
+            MontFp!(
+                "28757755616699644456329788620458203574399762217350399398630529504265710915323"
+            ),
+        ],
+        [
+            MontFp!(
+                "33807825413222987169428144700777998811898457202006722554361142045624061640371"
+            ),
+            MontFp!("7174182829465860203599766783502951677979942608131753074029850899830069509160"),
+            MontFp!(
+                "44410566812709374913651573303193170917467973547160312620367372786757260879426"
+            ),
+            MontFp!(
+                "30597267398246202646766740923295145236487719600495097385029340894625456808414"
+            ),
+            MontFp!("1283745280655284044836125197943623229378665779998568136477404403109358277683"),
+        ],
+        [
+            MontFp!(
+                "15000474148785848454537059660142034457769458993495304219972369620268793591721"
+            ),
+            MontFp!(
+                "18727168592976171182849169742319868884969829431184590711672124557020381291963"
+            ),
+            MontFp!(
+                "28929903323393302334203128804235871045351332235559731409069082830833831603042"
+            ),
+            MontFp!(
+                "48188362397132657546906617429152604706167745876296259310525907314719321884693"
+            ),
+            MontFp!(
+                "28754728379475769256273895114536635966524846586575841651317119174053561393881"
+            ),
+        ],
+        [
+            MontFp!("3846407618116213162762424719892737525161623898147468540979954274418605616159"),
+            MontFp!(
+                "27438181760221665412789775940245484704024101191957615350782638440006958183412"
+            ),
+            MontFp!(
+                "10515149705209682040698331485344896119362513920731453349325320214289775189377"
+            ),
+            MontFp!("2116555774726537303259876896252092100199545091302004054736054787845196624081"),
+            MontFp!(
+                "47734363724259421731106402729419041298350314936395882552900299240536580846296"
+            ),
+        ],
+        [
+            MontFp!(
+                "31846592060887942915937968134462848240565559035651663182008090986205796385291"
+            ),
+            MontFp!("3969027073636289793787619950666007963698405939200885064646065940075562565366"),
+            MontFp!(
+                "19759899061473418072050313037987124433405535012781662318119812132647019741278"
+            ),
+            MontFp!(
+                "49964895127218356895292858716178054528093844078989035684027371012487679107997"
+            ),
+            MontFp!("1927670657186319128695590724554916379389212876096026640236582718759993694008"),
+        ],
+        [
+            MontFp!(
+                "17367736365620455050431479091292375364669525113625849318894786145565524874022"
+            ),
+            MontFp!(
+                "38226198078256175949279586664009764115593206365901755201903368307598229033908"
+            ),
+            MontFp!(
+                "17441725458113105597509450373972224372318320550015613837006038557752601781939"
+            ),
+            MontFp!(
+                "42837907278542777683572043293807354897612064990939194188333870265445420219919"
+            ),
+            MontFp!(
+                "33419907390905747487865856221034472974672744259825851305879263571234126055402"
+            ),
+        ],
+        [
+            MontFp!(
+                "50728920623607461986141775686931929985062208819744138511820882674022699070230"
+            ),
+            MontFp!(
+                "24120063576518064189113611342807489639257820739623061408153882486984248382240"
+            ),
+            MontFp!(
+                "15680534214842264152653396602290338371550164086503256504178321821633361803389"
+            ),
+            MontFp!(
+                "20384256248115158155434818258709400328687324891105359937842852300097428012589"
+            ),
+            MontFp!(
+                "48433431991287042064925097922913531695642095177407432345787475000391645265929"
+            ),
+        ],
+        [
+            MontFp!(
+                "35920958387091813480700282134839366594682599657866879399213840372241313160046"
+            ),
+            MontFp!(
+                "46723480880158305346760910063933734343146565065560058245763150629044644179203"
+            ),
+            MontFp!("5018984934499987858196609131904956697454818253711715769710222417299598467614"),
+            MontFp!(
+                "28847718972098749205838953150672382399965128202279418750012883916446150265084"
+            ),
+            MontFp!(
+                "42870020950375674062250464665078779613659640404475537335399460256899466715067"
+            ),
+        ],
+        [
+            MontFp!(
+                "32558505304395718047081425996585272141384941464002783105424246503317076581282"
+            ),
+            MontFp!(
+                "29739218513630250085184799269740977586853161410635355656050383525417665446207"
+            ),
+            MontFp!(
+                "37015238547710459187999417030328986490170039914608574435100889947359841450211"
+            ),
+            MontFp!(
+                "11681393129061801989616632296642541367280911344194776842890112900012024388796"
+            ),
+            MontFp!(
+                "40187066593268902988394727707246085197469311127765207272704480168855966078445"
+            ),
+        ],
+        [
+            MontFp!(
+                "25839857054832270566544608820512641761074333749382673711402947284287326131871"
+            ),
+            MontFp!("7589018131417524907040335315714708145124031962352407145401907991508596826308"),
+            MontFp!(
+                "42043420529703197942165192395540920447740162169124465996057256624005105610758"
+            ),
+            MontFp!(
+                "15938386154517943855031931480693632574075296528796290551711473474318943860075"
+            ),
+            MontFp!(
+                "12588804373430932976513513377451650383640616484471605671652016065268336941312"
+            ),
+        ],
+        [
+            MontFp!(
+                "33624509145062439187245550362267034963024564045563173369483000037242516817923"
+            ),
+            MontFp!(
+                "24635416319735446617105876636580886579230080016094051926334801882500874481723"
+            ),
+            MontFp!(
+                "22696818081821065327297348729384225605292717524399440220994081416242921980781"
+            ),
+            MontFp!(
+                "41597845977021283515141842616456699085633573049972309302164329138802562120597"
+            ),
+            MontFp!(
+                "10835456539700519819694995566040257103588254544838898413162006973775423648535"
+            ),
+        ],
+        [
+            MontFp!("869607072617425630583256169301370713874911681313747344329658097829167284327"),
+            MontFp!(
+                "21033502935551816065488293708560138796923985114607737558737769383647786030287"
+            ),
+            MontFp!(
+                "27647952743589419020331823588324762214786404308716185984148176948575135606342"
+            ),
+            MontFp!(
+                "38518742310672236492989641881905903120587142923444497187827574274453315543545"
+            ),
+            MontFp!("4531071024850222539250369473086750090449448599437449446442788371005060684937"),
+        ],
+        [
+            MontFp!(
+                "35491081210784801952289786572600175709671381921081046973560399852949571810199"
+            ),
+            MontFp!(
+                "48658769242191841799798671732468072705591851650641902890145556406380841315696"
+            ),
+            MontFp!(
+                "12820502794969811839681835095003978062312966711792994035170864470094577124272"
+            ),
+            MontFp!(
+                "47613134517083177423814057036763246488981570177374680633858568311689152591641"
+            ),
+            MontFp!(
+                "12838292032285679517406017231821500554137457516769661430282205658031655170019"
+            ),
+        ],
+        [
+            MontFp!(
+                "22396663666673503690119106785468653056616779596534305929786594273032399557197"
+            ),
+            MontFp!(
+                "20546032986497052581540128864815896475683353512973150654602301633831877765555"
+            ),
+            MontFp!(
+                "38048101856563966155672224667893065905693708840881557010189944088556615176084"
+            ),
+            MontFp!(
+                "35364285829774161550793069957364283692902646764201196735328823101260681986287"
+            ),
+            MontFp!(
+                "10905585706171946952427982077489937314096607272419866478708312545111889753089"
+            ),
+        ],
+        [
+            MontFp!("4583684024581088955168192816084269037013266426481281689046134674380801148862"),
+            MontFp!("3307687500364211704062998932145271884840309546096449338516587054114728430755"),
+            MontFp!(
+                "52371375988028349500026253321346241850849459314791745188142574255173849549821"
+            ),
+            MontFp!(
+                "28319035102827205384219649624392277062846417852111863790406027604448685102973"
+            ),
+            MontFp!(
+                "34304506269152100302302929514946316284564531757655140477853804157259126916938"
+            ),
+        ],
+        [
+            MontFp!(
+                "44488961075117540406192166373037720223898691502148149135945609974961009737582"
+            ),
+            MontFp!("1774169086926078822667406719155602759291633151558034847231443279958014510824"),
+            MontFp!(
+                "39278378010753737815028836153312713748065140395764519784954120993351391790788"
+            ),
+            MontFp!(
+                "26627867983167900213440810447499042499899165534466852536593632514056039786543"
+            ),
+            MontFp!(
+                "43126807404370735288090675615731308324287161193604241406535828309026198875753"
+            ),
+        ],
+        [
+            MontFp!(
+                "43348987163294543247563406334523337019322765037007488427909081755982898203157"
+            ),
+            MontFp!(
+                "29115022243347760457852928081244573963991839366052762176565682557571261259492"
+            ),
+            MontFp!(
+                "28089883970898921507026757088696204835963298364547689315419248903264641650981"
+            ),
+            MontFp!(
+                "44599763126512550426766690309373905878327473014021387053329174443339002469603"
+            ),
+            MontFp!(
+                "29621537583500881448950659763639755107207403224381288648393454435988283305146"
+            ),
+        ],
+        [
+            MontFp!("7194278207938417688788603915309404233322497157184130825761772960944257979283"),
+            MontFp!(
+                "39803314695802554077841799831550178949906299308166545970116309227334884889388"
+            ),
+            MontFp!(
+                "30797780990442393116597607971963438620093994859523757021841430146497981673558"
+            ),
+            MontFp!(
+                "39241902163045255429081142654176110886967662529497126587279196064414462797205"
+            ),
+            MontFp!(
+                "23825786549524610265616261557363837329114672367950478819699942087629234386851"
+            ),
+        ],
+        [
+            MontFp!(
+                "33237417963493883852239230203235085592063646532552861207993711949152030277406"
+            ),
+            MontFp!(
+                "12125814877578216709834823019685023801696942269844770971585519987510996222761"
+            ),
+            MontFp!("9883323458082738936874073005536242223864413511537734931404285362207559923701"),
+            MontFp!(
+                "23667358195695181149916734767044791081547242188357869486433144691217159275916"
+            ),
+            MontFp!("2103809394540429747882863649885267843855476167184675354147717319676325688657"),
+        ],
+        [
+            MontFp!(
+                "43533640248437967387924378122954484869084379074382026266165184605822384356249"
+            ),
+            MontFp!(
+                "23064372275635221977720968117128888726980812326309464855996068896297677276576"
+            ),
+            MontFp!(
+                "10915452092464337592615035548389385569117695023235082183431643179215165422591"
+            ),
+            MontFp!(
+                "22616309273823226474781109622317351092436593278301405990827287901622716477962"
+            ),
+            MontFp!(
+                "25962390428229879789234420288041246367419068753673853906425730989527826775629"
+            ),
+        ],
+        [
+            MontFp!("4496882622884389908736629085608021557602189311353106883382031948417550502789"),
+            MontFp!(
+                "23071338796470338216603112212612095562293275534684751667995696307146168404057"
+            ),
+            MontFp!(
+                "14224424932168213496536777666618065315069904406333475627947768518479866310242"
+            ),
+            MontFp!(
+                "40907000649402938324500449984959420709146442099804025772053332446526267478151"
+            ),
+            MontFp!(
+                "13453108856285324847358406165402753702042724251185256465584993995695371681260"
+            ),
+        ],
+        [
+            MontFp!(
+                "19951602527990217760741549537667338194282697203002553716967067651845693653932"
+            ),
+            MontFp!(
+                "38316823637926348537974095103816311211802049209145764893016322319864903011725"
+            ),
+            MontFp!(
+                "28490335090324092935997332283405896171611456752496969834725573106227407262431"
+            ),
+            MontFp!("2242086311047166080760715745048448387002486732620066182209816983289051787678"),
+            MontFp!(
+                "27566536226220187444994375648747726314588201106593640401311329627662860704300"
+            ),
+        ],
+        [
+            MontFp!(
+                "43156126148039523025011202299443409535930145548814037486495745476548869432627"
+            ),
+            MontFp!(
+                "35907468854791708401883299459646885281574642865218436554956518356315683959614"
+            ),
+            MontFp!(
+                "10865230392327907986290879678051972471070278884309166188877363259145066259639"
+            ),
+            MontFp!(
+                "34552251443029593271820414125328559400294180025565777849964308660801941426770"
+            ),
+            MontFp!(
+                "16200299091330935152223273386908099775723148411980424099302506052695738935117"
+            ),
+        ],
+        [
+            MontFp!(
+                "49157667145559600528695433309641384970463822444547620898261758991686633096833"
+            ),
+            MontFp!(
+                "41259794186963981321021589191362430884121645241396699247607765126938764571355"
+            ),
+            MontFp!(
+                "11800328043760013440734386209983011730946771727574235097830966525201352914741"
+            ),
+            MontFp!(
+                "48905181336024409727898442265754288395658847545971491904479221328838830698396"
+            ),
+            MontFp!("7617498442791271637273080099153012071683075531053592846105175715717981246413"),
+        ],
+        [
+            MontFp!(
+                "51016520866544723521642914756352166716288571977342208698888053327080737176960"
+            ),
+            MontFp!("7461329961185784616581931157715924661292190955568315102114747970625506742665"),
+            MontFp!(
+                "10399327006107433178483984009496369794370678608764815332764690019795726874738"
+            ),
+            MontFp!("4036278073511297455516959321017396623608288572069681288633929825318440139954"),
+            MontFp!(
+                "48160547729664996726192296237611807878122822142772995923024373993220783732815"
+            ),
+        ],
+        [
+            MontFp!(
+                "11191935303927600037154979890636508641961935039711090109749203883230331697520"
+            ),
+            MontFp!(
+                "14762711541110656926914387458537689214088320262207878877628413534013560378538"
+            ),
+            MontFp!(
+                "33192196643970403082725565882276310966965943744309788186609538932424713386386"
+            ),
+            MontFp!(
+                "38860419396025284639797194088144221063427196356594600305092361901494074344198"
+            ),
+            MontFp!("4795190020568070018131065644952508817073791836038218744498298918796790397901"),
+        ],
+        [
+            MontFp!(
+                "43165876016931363872911683739738624173082623374059029422736446361842017339841"
+            ),
+            MontFp!("5687828461043595898457129641574977482004124352044376030421182683897327491805"),
+            MontFp!("7192282788031413675475098778627489563583015700062312447501248147217265336481"),
+            MontFp!(
+                "21186200901228778490700079606507801893903968196077188119618830368263435793067"
+            ),
+            MontFp!("8550716283956365903006800507958016288246142326167343259233772509629163040225"),
+        ],
+        [
+            MontFp!(
+                "11180847804988960064991516857737985188956138425622782842157347289093078702597"
+            ),
+            MontFp!(
+                "43435752324588441615642695818377500304436021944583464855191712759009451041251"
+            ),
+            MontFp!(
+                "12278262031016453868427677113692936050977078687221344941334082001313056979644"
+            ),
+            MontFp!(
+                "22451109288343797520636567456426730033748927713244660571223190794632576862619"
+            ),
+            MontFp!(
+                "45652565786637681973149199675923827734753686910679393088339961606820197029220"
+            ),
+        ],
+        [
+            MontFp!(
+                "20843277809449535407971376252433933995054790809847145391826694531890370112790"
+            ),
+            MontFp!(
+                "25435902603606300955094661093787110223476211065053466948976831665885034421523"
+            ),
+            MontFp!(
+                "12587462391800033127867243084119049938523132413268589228885934553304251353157"
+            ),
+            MontFp!("6806880557066440017056417655777533959837499351609559156108033231157264514419"),
+            MontFp!(
+                "34291104507405952677828916922726904089719019036444688021786185640822399985152"
+            ),
+        ],
+        [
+            MontFp!(
+                "41706520274464120325132686992414426895166782403376324981407486301091454547212"
+            ),
+            MontFp!(
+                "39906505295493025425336111559428640378746833084573882934814238861697651618039"
+            ),
+            MontFp!(
+                "28988632628830432543436439390668176259149392184988285894663414919323167912479"
+            ),
+            MontFp!(
+                "42518184630413109333989386821814432612825414618652848669742957837888366722465"
+            ),
+            MontFp!("2585556993539605186190068078830476668377521606351123775077937735185462914809"),
+        ],
+        [
+            MontFp!(
+                "15647351476356581886671635892618261507432325620577467149760277617270648526450"
+            ),
+            MontFp!(
+                "49960290874836829892066577642284297541544010413669343272797010787386986813789"
+            ),
+            MontFp!(
+                "40591154205746746706465247433129501688700391061613925921546714779749645726059"
+            ),
+            MontFp!(
+                "27221219480891153346399693487478869849170594995649768309811315331645163648037"
+            ),
+            MontFp!(
+                "35627419059885980609994265159822107471834473523334061252666778471987351790113"
+            ),
+        ],
+        [
+            MontFp!("6133507071481096789713278740759855124587014509454399644865557738563699351674"),
+            MontFp!(
+                "37282626525015210048713833887131947199631070267134252471569978211754969096983"
+            ),
+            MontFp!(
+                "38632952057258318013509909407827872779146138944926108766635533690684210889560"
+            ),
+            MontFp!(
+                "23398349301233968535733292475713923150860499133525348800847484357511842629224"
+            ),
+            MontFp!(
+                "16503461243526462388049309986602343236378007909111284761924001195358498340965"
+            ),
+        ],
+        [
+            MontFp!(
+                "21185190689519287192694720516666079084751642484186369020465858084104486251164"
+            ),
+            MontFp!(
+                "18426481733425961499146211416024835395751505410345536100389857686231784450071"
+            ),
+            MontFp!("6001102686734796126213311788241203673344327464941758686761480140271185255143"),
+            MontFp!(
+                "29899404828646086160710613574420829957790890516902607461968775560842273691520"
+            ),
+            MontFp!(
+                "44937983925509040799211822188828913201732031115000611837359323844559304283026"
+            ),
+        ],
+        [
+            MontFp!(
+                "19384502544531780334415575775862287754751785913745700359817710641757950667264"
+            ),
+            MontFp!(
+                "21903817949955993434526504885284335828238898854706763168935988842086056898006"
+            ),
+            MontFp!(
+                "10887345712590292720481113782792984907263646250815660129091820061647044918703"
+            ),
+            MontFp!(
+                "33810228400760521469845999662141943304254424560384623713188464888040052258882"
+            ),
+            MontFp!(
+                "23599561654314103764631945204853468266220976216865716712087297561966487491213"
+            ),
+        ],
+        [
+            MontFp!(
+                "51665243774510441587779856863093099866203459246751705676551224837972062712559"
+            ),
+            MontFp!("5428902421828104599676046606038114067495317156987540253376690173453599041901"),
+            MontFp!(
+                "24525636629838659244066695364862589176019065199174312026123332238322337577135"
+            ),
+            MontFp!("6402909782605136997322139633185934713372192502509970550071969188531131377288"),
+            MontFp!(
+                "24615672769194038372005940078613830802903829711100791640556147316977170340862"
+            ),
+        ],
+        [
+            MontFp!(
+                "47069063381733437320552936849086484741405407716986810171219233796444315506009"
+            ),
+            MontFp!(
+                "46293543155615698398200937527416817081285757196590112560649141402478792794272"
+            ),
+            MontFp!(
+                "44356021981447503932029001142992476420841428007695507936935968422995327952841"
+            ),
+            MontFp!(
+                "41610839009487213276930145108109926808865544973579887828814419918928410290417"
+            ),
+            MontFp!("6812817863022395583889343002392115326626317215589950828003887175452342222783"),
+        ],
+        [
+            MontFp!(
+                "39009959460922106868241758553765901811943566486940681218536134037306470627445"
+            ),
+            MontFp!(
+                "23908620497010908974982690327117381875006382133771456572614578676337725630280"
+            ),
+            MontFp!(
+                "27775659430970654963452457728583216908551656989778833866103121420299486538943"
+            ),
+            MontFp!(
+                "49772989813536929797113349577991600664798260456841518390049429467578719309864"
+            ),
+            MontFp!("3266405300389933300827683986363752473177514591021900881754486422747698249471"),
+        ],
+        [
+            MontFp!(
+                "17444111937329416584822267450480047629264495632182742121370754897356510956397"
+            ),
+            MontFp!("5247352605375272409165364240826501925650169677835319393400915410351143290308"),
+            MontFp!(
+                "49417384560293130093644275174387396044694860385217264027700738080767922369886"
+            ),
+            MontFp!(
+                "42172199361121396607794757547701086621542004079361657138587538337958094985349"
+            ),
+            MontFp!(
+                "30501851327592114920508795308634516960542069584200165295083221434010186114552"
+            ),
+        ],
+        [
+            MontFp!(
+                "32340332870888022969457066661235161215694610358558765276850183887698260856285"
+            ),
+            MontFp!(
+                "44104927227667583101463896986855602777178764835570190728746829702860312302738"
+            ),
+            MontFp!("5816414118224128301530569546999075310640202680687314133544183510204417255072"),
+            MontFp!(
+                "29561363266359460587942473079585715006149250326935674324839592506574125163890"
+            ),
+            MontFp!(
+                "18433786308899414444571229669939214215714926678542219782005779769729939903389"
+            ),
+        ],
+        [
+            MontFp!(
+                "33585393730882809319852882197413616979752049491962215049889725504609014064434"
+            ),
+            MontFp!(
+                "17681657896749758666241194211836551530444207343826347266484830607024521512996"
+            ),
+            MontFp!(
+                "16004252298149626486910858227424678441955588867215137941969125526801952088794"
+            ),
+            MontFp!(
+                "33060408558768674283322271779802016724961181457003337132528342465855087083496"
+            ),
+            MontFp!(
+                "47247850553198548052611450333311454963835109323507023770393896680171888169738"
+            ),
+        ],
+        [
+            MontFp!(
+                "25720330099212276688093695057477928501445775591274234410284893209403148678341"
+            ),
+            MontFp!(
+                "21810909699600627289003031504901192798941856966698469064755005839854482536446"
+            ),
+            MontFp!("2636921781005222587627935002526773285066001482411745865198821763041100275770"),
+            MontFp!(
+                "10903577470552408166764170124564840755987790798531368931554195829243816956125"
+            ),
+            MontFp!("6674096421044256460683237108966329762906267369229470850710455524260723677123"),
+        ],
+        [
+            MontFp!(
+                "43153319247416307130408162211903101924701179089489599616319496949287252616221"
+            ),
+            MontFp!(
+                "13578338848263669633860987441273136782285405624861324983501026544081778018160"
+            ),
+            MontFp!("836716256255211181484526770429719486366586447890511270311733224168442840532"),
+            MontFp!("6811685226783826520785473382919214142776688673337852297836965878423876166041"),
+            MontFp!(
+                "39608176693398928274032060336962091549052280248510727986445919980507815351331"
+            ),
+        ],
+        [
+            MontFp!(
+                "12383545025838170865107792312666524725314613158654903657449927633054071351846"
+            ),
+            MontFp!("9910824665298277168093320973475527650086426573325860425446289994225726883293"),
+            MontFp!(
+                "50386619651857104971500870008627905004729365081501890406855038875627873174152"
+            ),
+            MontFp!("2871899086726006293101826686785141855671789457173452628915312696822181871144"),
+            MontFp!(
+                "48694602500523715352531493684012287176692998983834059061406146219598613889265"
+            ),
+        ],
+        [
+            MontFp!("969216282333307096807467204172181065402764678288651916605689207371272936146"),
+            MontFp!(
+                "47149918628883642029495298436228728733483831608303548747854523815748875442162"
+            ),
+            MontFp!(
+                "50275128957026546470499452410768640845489119819799444551334609033722700450574"
+            ),
+            MontFp!(
+                "13268762726605460281898516941692824740745750640708199783718192098100664913943"
+            ),
+            MontFp!("1969290093182067719658043564096634644037133615921329799668199574380108190216"),
+        ],
+        [
+            MontFp!(
+                "12608907561047785509650788190244729617707430058360499089349184944990118931924"
+            ),
+            MontFp!(
+                "45461174176026718868376919008723922548232459072348693797708111262194807030678"
+            ),
+            MontFp!(
+                "25766506519692708015511957406596839497779018170573393686888572686606928282183"
+            ),
+            MontFp!(
+                "39833144178460825210101068642275627335225323185718284715499769919816155809752"
+            ),
+            MontFp!(
+                "30056908061992601662269582344566856619599673830629269958741964313258559470743"
+            ),
+        ],
+        [
+            MontFp!(
+                "31727444246456988786933551214733898957961176356901118820078939764536723060306"
+            ),
+            MontFp!(
+                "30275330289318824034296957317866878263149191185287150962890311926181049597255"
+            ),
+            MontFp!("7410389755844552578646513576361329606222824979741272879457013646921487095504"),
+            MontFp!(
+                "26534480884398988086419884786050246206409529783471575453387948028898420152999"
+            ),
+            MontFp!(
+                "52202646950452813785288495526663475377895462221477530270492329128118288784822"
+            ),
+        ],
+        [
+            MontFp!(
+                "44193973484191525988837153550191664940867684113974075667410428439326700223817"
+            ),
+            MontFp!(
+                "27668929324841375213849382886601185600010833715295639580376570849444228459174"
+            ),
+            MontFp!(
+                "25370072614404137813311976650200358809993557360090848969306130354539406948012"
+            ),
+            MontFp!(
+                "10486761831077879478414810647021596736703831577597525176983135492128139232518"
+            ),
+            MontFp!(
+                "50480202770193420944827959911779569309067864765168840034692962616935010498687"
+            ),
+        ],
+        [
+            MontFp!(
+                "21088611258980169715483272186318177590712170441549469558529712036708931360815"
+            ),
+            MontFp!(
+                "47181986549731176635441931338426333554035867550740064286972653790168752331617"
+            ),
+            MontFp!(
+                "47432939185014971605498923189554103720161643820288348370204996182785047214462"
+            ),
+            MontFp!("8841018451562759950530940427813578275644705571635459825009186897203142954365"),
+            MontFp!("3928705257164142270342755168858752457590613769216361974174887572099085233662"),
+        ],
+        [
+            MontFp!(
+                "27510175094511688045950992368532035091571816336049381045105051204659097206432"
+            ),
+            MontFp!(
+                "51837458785793898941596403071931935938331097333264604343631647908673678882892"
+            ),
+            MontFp!(
+                "23389660966963456899692923031642817484944915693477219469729970658845407592355"
+            ),
+            MontFp!(
+                "29560583826958098541883197003544411151875334058080741689574364782259065634475"
+            ),
+            MontFp!("8131146427909845706826496547789342804068046477822690250521483514747362734470"),
+        ],
+        [
+            MontFp!(
+                "19720506494363039437207040970321613993053962208806797849378146519451757302856"
+            ),
+            MontFp!(
+                "16506005685162161466724008068185243816817502765166908912262084982261980504087"
+            ),
+            MontFp!(
+                "10710835599492630979090381502448060726586218601076725173497652091248354428702"
+            ),
+            MontFp!(
+                "11161364644590334291277680782449791811994905323066876376212175938699016337143"
+            ),
+            MontFp!(
+                "43929312973861741722064374721538899139577044772784758489780761063158023900663"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "31461525105075714287668644304911579502614331500316582693562195219963148710708"
+            ),
+            MontFp!(
+                "43696562645938492066206450423488304864742127083773031518836382249948817653761"
+            ),
+            MontFp!(
+                "14981678621464625851270783002338847382197300714436467949315331057125308909861"
+            ),
+            MontFp!(
+                "45881390778235416669516772944662720107979233437961683094778201362446258536449"
+            ),
+            MontFp!(
+                "11652416705583597884321720112930214630597900555672808405023035266653018041003"
+            ),
+        ],
+        [
+            MontFp!(
+                "43696562645938492066206450423488304864742127083773031518836382249948817653761"
+            ),
+            MontFp!(
+                "14981678621464625851270783002338847382197300714436467949315331057125308909861"
+            ),
+            MontFp!(
+                "45881390778235416669516772944662720107979233437961683094778201362446258536449"
+            ),
+            MontFp!(
+                "11652416705583597884321720112930214630597900555672808405023035266653018041003"
+            ),
+            MontFp!(
+                "15730762552537857143834322152455789751307165750158291346781097609981574355354"
+            ),
+        ],
+        [
+            MontFp!(
+                "14981678621464625851270783002338847382197300714436467949315331057125308909861"
+            ),
+            MontFp!(
+                "45881390778235416669516772944662720107979233437961683094778201362446258536449"
+            ),
+            MontFp!(
+                "11652416705583597884321720112930214630597900555672808405023035266653018041003"
+            ),
+            MontFp!(
+                "15730762552537857143834322152455789751307165750158291346781097609981574355354"
+            ),
+            MontFp!(
+                "47668977431932900435861582280169059852445956818661488929639689727216891985921"
+            ),
+        ],
+        [
+            MontFp!(
+                "45881390778235416669516772944662720107979233437961683094778201362446258536449"
+            ),
+            MontFp!(
+                "11652416705583597884321720112930214630597900555672808405023035266653018041003"
+            ),
+            MontFp!(
+                "15730762552537857143834322152455789751307165750158291346781097609981574355354"
+            ),
+            MontFp!(
+                "47668977431932900435861582280169059852445956818661488929639689727216891985921"
+            ),
+            MontFp!(
+                "48066218910532341272827095465837135351216339792150334670720020474943699419137"
+            ),
+        ],
+        [
+            MontFp!(
+                "11652416705583597884321720112930214630597900555672808405023035266653018041003"
+            ),
+            MontFp!(
+                "15730762552537857143834322152455789751307165750158291346781097609981574355354"
+            ),
+            MontFp!(
+                "47668977431932900435861582280169059852445956818661488929639689727216891985921"
+            ),
+            MontFp!(
+                "48066218910532341272827095465837135351216339792150334670720020474943699419137"
+            ),
+            MontFp!(
+                "44368817455876007328763472737695817247276621346600308926818480438409568694588"
+            ),
+        ],
+    ],
+};
@@ -0,0 +1,426 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-1 instance of Poseidon over [`Fr`].
+pub const RATE_1_PARAMS: FieldParameters<Fr, 2> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!("1994900499411599099483382816386402366159714072789165987080698883950479295903"),
+            MontFp!(
+                "16397423844052573347842713262382067046511037705922274726920083222870792352943"
+            ),
+        ],
+        [
+            MontFp!(
+                "15475738260826905615975077431840791053379982311366514206415798911218745340088"
+            ),
+            MontFp!("6421893642834570895108992571221632032650158762106056833307301165049262024028"),
+        ],
+        [
+            MontFp!("1266485394544528387855312666495930829951865235347868817762097959858471109381"),
+            MontFp!("1042394888491048212154599179797450566782171918963907681154814527784795067277"),
+        ],
+        [
+            MontFp!(
+                "18873052230218551435941789116211754570883183211663487700396863325483386856538"
+            ),
+            MontFp!(
+                "12885605770267990853901019155997617454631692791661369229896470340802370985883"
+            ),
+        ],
+        [
+            MontFp!(
+                "21550980885021455015441197583068079409542487980244478330266369813293109700470"
+            ),
+            MontFp!(
+                "20217134908013197874214833579989874870691678581157943324580411580587610306213"
+            ),
+        ],
+        [
+            MontFp!("6535559180230918470158252369510698049277902658654440761074654870299905515080"),
+            MontFp!("7863651453350983380348374501020827750582775243962162981806231184911345724837"),
+        ],
+        [
+            MontFp!(
+                "12333446049150168884890380239213204831669803981722315639378865627039058240890"
+            ),
+            MontFp!("9840183669489347828125125642033036161179614467767969463241344832196533911896"),
+        ],
+        [
+            MontFp!("3167531345474375381177450613366357466527231577842238832171459242763178257395"),
+            MontFp!("1465149965838967049280538876194406257030227103231733980296148644027155398679"),
+        ],
+        [
+            MontFp!(
+                "13176253469548083322194884016905515813979317598104671203620004175837816770792"
+            ),
+            MontFp!(
+                "14519064763234462231343021642488918106775543684288170618462668887082779043895"
+            ),
+        ],
+        [
+            MontFp!(
+                "18795401444925488336207831758206047831621943249211295053500885829329003000269"
+            ),
+            MontFp!("4913373188489987344495531181130940376734865272340225423990609150740065589655"),
+        ],
+        [
+            MontFp!(
+                "17733532791898314502737823712563966763202446461341288116981064291570118267829"
+            ),
+            MontFp!(
+                "15935248512053426706475992363063254580029154652812673655327110160606890935726"
+            ),
+        ],
+        [
+            MontFp!("4899138664054041881265291936274535437938168624978018662517006908280704001183"),
+            MontFp!(
+                "20362989624868950116144653853341436975505104303027744456804414397054964603487"
+            ),
+        ],
+        [
+            MontFp!(
+                "15192678926113438504424026420396069922293276859632279183516004409477459130740"
+            ),
+            MontFp!(
+                "14222700010377423657278048569194678009472618705573982630892126108111228204791"
+            ),
+        ],
+        [
+            MontFp!(
+                "13039472518407084864755613646215798744513721465223662275973451218814395699351"
+            ),
+            MontFp!(
+                "14467974322653891722969840368199147385607848547358720254589320739263618363792"
+            ),
+        ],
+        [
+            MontFp!(
+                "14053302657472190574658212357572591545706138774040051902589622956535453227385"
+            ),
+            MontFp!(
+                "12678782625800687803551695863972538719876723266716873652986645567072532648410"
+            ),
+        ],
+        [
+            MontFp!(
+                "18796352784974731232821910228440645879134272231239796338257626828637846218307"
+            ),
+            MontFp!(
+                "16535049599826393824945847644568253695379817587645870125069784830499415124423"
+            ),
+        ],
+        [
+            MontFp!("7119159776523800225800194502248254007374664973227488591976211865348115450570"),
+            MontFp!(
+                "20929529796198296727254526485493066046235425613391226263072098158134243223873"
+            ),
+        ],
+        [
+            MontFp!(
+                "18452180632248733241046852369826827197773572115483447206671942362878951351595"
+            ),
+            MontFp!(
+                "13954279926003129321848329472058524410960435438259782439375447814141149558564"
+            ),
+        ],
+        [
+            MontFp!("9765959445120009622919422682164906805551161511654559296998810181745931397717"),
+            MontFp!(
+                "12581809288154131228931666149869522795051098378467962622474981791339378938362"
+            ),
+        ],
+        [
+            MontFp!(
+                "11053051340130440403164891076175119018094948521360216766037964166041281554901"
+            ),
+            MontFp!("2465504072137068455192783272443407312893943616368243083381200886491921439324"),
+        ],
+        [
+            MontFp!("5325924803435036569481235679997862969102878279845729340048651515935625422373"),
+            MontFp!("6902689314291189978928687187254608246735850943845850939153076785999801843674"),
+        ],
+        [
+            MontFp!("6518276441425489302743756762048216801362156879812047109117696294628350039419"),
+            MontFp!("1840488911781069930230672807313104736898183345008996980056236470733892758192"),
+        ],
+        [
+            MontFp!(
+                "11362748316011044408778248506965741120112563565536202702918742381898047277196"
+            ),
+            MontFp!(
+                "14500190499932046372859685428328568966541776057701240828688311299929097557321"
+            ),
+        ],
+        [
+            MontFp!(
+                "13776704698659053010828955354261429479751856734830709093339154096997757184395"
+            ),
+            MontFp!("1304341909447573553986604444483105532988707028495369870941075000464942389374"),
+        ],
+        [
+            MontFp!("835658717093223026740737788818679933285683756259576432778607209530129065469"),
+            MontFp!("2604444872394176633328970364366525596179851557143962779309433822021942326620"),
+        ],
+        [
+            MontFp!("8493176233122362193892905237322825572823131415928313730257967372932635260937"),
+            MontFp!(
+                "13435325840622403356317200955309883215426271401464788406340138362168891328206"
+            ),
+        ],
+        [
+            MontFp!(
+                "15999859241034991083579371978791737512505374973001189363064541801928587533507"
+            ),
+            MontFp!("5863500949826374993363792974698743696653510579585272957366645939388319650323"),
+        ],
+        [
+            MontFp!("4569477935419424771011564626357658398046894940860730829269261817232153716152"),
+            MontFp!("2170901967348706904485020601844246721631834833551902355039805881001068826049"),
+        ],
+        [
+            MontFp!(
+                "16677067693582090319510041079372106147309407841057526469263764274407585280895"
+            ),
+            MontFp!("8353221686156078025145488701965474555078598701334281754730969925197657508073"),
+        ],
+        [
+            MontFp!(
+                "12572926154650525226762349707079817085030419647311436023107201436086496861119"
+            ),
+            MontFp!("8887394405340241324410382768456164299063665556947866537023606996836099609879"),
+        ],
+        [
+            MontFp!("3382325176864023218938583456643830241317569987766623477657410175294661655223"),
+            MontFp!("5333399235203976990161432120757242224506583560101846261898469726628462848893"),
+        ],
+        [
+            MontFp!("6565459852761945298187576365019520120624911382929741021880219523662974818151"),
+            MontFp!("8262895332681115250898384153766586802342505057393508907695240660619711799203"),
+        ],
+        [
+            MontFp!("2040640427094294044388941973444624095353181397718807608649744910039778117044"),
+            MontFp!("5659882748569519849686371718709631158990395929114031700498289260166120271374"),
+        ],
+        [
+            MontFp!(
+                "18173885543323796560415070822703053580088816928149095852340745076874951369899"
+            ),
+            MontFp!(
+                "16790592436752198628167844685827261546495195267293812700905071871292750034858"
+            ),
+        ],
+        [
+            MontFp!("7128649354861633049563607584420492410060871562371156089612480670954907151492"),
+            MontFp!("6311502072451309326851662509715707719288684566699065304956520585902840265014"),
+        ],
+        [
+            MontFp!(
+                "11827610549551501200125760127454829689517706970191370450546080188353591624774"
+            ),
+            MontFp!(
+                "14845426145322782838258947303989574178443658992795077026935952519131626372466"
+            ),
+        ],
+        [
+            MontFp!("8596122468324630349930659180491672993438048352516150959823207097825689839982"),
+            MontFp!("4317803363057806421505652809272259203417567738040689346526984493740165542079"),
+        ],
+        [
+            MontFp!("3206288315139072878576391049683893558337397988987329563824832454259308308868"),
+            MontFp!(
+                "21011352109799033549421788813453815890030840888851954350358741930459033207693"
+            ),
+        ],
+        [
+            MontFp!(
+                "21884699946676510291596752917983882764735038678260605425299487127946823890675"
+            ),
+            MontFp!("6259123560855560948882321328062388898597379842378584814090926584496360264837"),
+        ],
+        [
+            MontFp!(
+                "11371041760121839600191917836974468909448813082748303225021187530130372014321"
+            ),
+            MontFp!(
+                "10128188275275849954277996075961995177645917905446133805872221964895309288472"
+            ),
+        ],
+        [
+            MontFp!(
+                "12694314406418071234747602333123793420568445152908159391073844723545049884895"
+            ),
+            MontFp!("4450420228785885678999848149428540502147881259037446373366861323639409383937"),
+        ],
+        [
+            MontFp!(
+                "18394526278158126224504342758608487732104281687520539071477307906007608765457"
+            ),
+            MontFp!(
+                "15329702554147142273282821585644689345293617700295126753266558790124453601303"
+            ),
+        ],
+        [
+            MontFp!("1685702719454200538204219080969857046423332638545508227825311266283474786175"),
+            MontFp!(
+                "11757211728376606774534808029525460954235535597228945831111197103269308613601"
+            ),
+        ],
+        [
+            MontFp!("7014092375971515399619569809125939611467028830612336247143279409045995867057"),
+            MontFp!("9877900922563924129674365002346760662979471745338964509929488281109646950564"),
+        ],
+        [
+            MontFp!("6967070882620338827750684237022732517775728823940825917248564522029788697666"),
+            MontFp!(
+                "20853226526460747786742383245211926894182308431169292151305461371464128103734"
+            ),
+        ],
+        [
+            MontFp!(
+                "20439928402547879969517306862692457585484554437518455910873476926975479397737"
+            ),
+            MontFp!(
+                "17492155096378293585684152020669138265226191334968293066933161472313199403986"
+            ),
+        ],
+        [
+            MontFp!(
+                "20091029224829397415838088293072022331129188452344136132553351873837918599936"
+            ),
+            MontFp!("2912041711572930061178930547370758252590336610801812475751722083635847275551"),
+        ],
+        [
+            MontFp!("6300458147673739738109125436436035495970985600672423767419115561986231462032"),
+            MontFp!(
+                "12029879410369415995094335805328113690614474336081421222079371814289950483113"
+            ),
+        ],
+        [
+            MontFp!(
+                "11605424019868086455969426500258169359676049939646598603366163855437974873620"
+            ),
+            MontFp!(
+                "21042876150377579970785861114914276018519033788024715798371448251974258989416"
+            ),
+        ],
+        [
+            MontFp!(
+                "11090241973257399681566893808186225629315292529625955133453725999333061663605"
+            ),
+            MontFp!("7215085570992110612941959425935772627816623377092212246199899694681558349868"),
+        ],
+        [
+            MontFp!("1550847157744579358984678397236042818632311079832518359105261512503527712486"),
+            MontFp!(
+                "20904369007291989635779249522519960384622285109669801148662409914626880604468"
+            ),
+        ],
+        [
+            MontFp!(
+                "14430396508364430005159930619172044169859302639450816770174380469072129920785"
+            ),
+            MontFp!(
+                "18512810209509243823721763815550175951565510508996026885465597476789055873793"
+            ),
+        ],
+        [
+            MontFp!(
+                "13746653381664259884998856425267264780186156878693730263813014232476638094366"
+            ),
+            MontFp!(
+                "19102407739763900873545258567734097446540669273111560585717868721834396998559"
+            ),
+        ],
+        [
+            MontFp!("6989389609813475954332088555389596987180504907646102264653257216409030839193"),
+            MontFp!("8620348917918929302926978173356430946715028065879334784742762670974467986197"),
+        ],
+        [
+            MontFp!("4658715109183923383976666317817798661341074884472450724078845236990608958023"),
+            MontFp!("1684463988701857617789224856849521099978090551790898762162996625284365100888"),
+        ],
+        [
+            MontFp!("5602082890557587616639890209342323487694753890747397469781600573183379259784"),
+            MontFp!(
+                "11313168341033455065965833227292599400660617344667523254292091736165338268237"
+            ),
+        ],
+        [
+            MontFp!(
+                "12898632832712508835144835756477259693903327375276177488611504910595907553769"
+            ),
+            MontFp!("4212600298369837693740522436431511086820089585271837735216013965567355658514"),
+        ],
+        [
+            MontFp!("3938906289343588316976478559749974123282883451527487501995611682208755211376"),
+            MontFp!(
+                "16551193326286259743149764497544621826656488227399963060120761236125382561104"
+            ),
+        ],
+        [
+            MontFp!("4657645611597328828888253860495917873011802248076049610052300188004258916122"),
+            MontFp!(
+                "16138103529500416668149417750176187127950051863850194348912092362591121409121"
+            ),
+        ],
+        [
+            MontFp!("7303338736800381716961425448917571272098133593907074685762568164985304954925"),
+            MontFp!(
+                "11531100788937044596042688112839576462148334790971121365738157297435643238166"
+            ),
+        ],
+        [
+            MontFp!(
+                "21623708747453252688969722715940298610191881147554025085519123552693907899094"
+            ),
+            MontFp!(
+                "16885215709211685830165959934368345350567998365966532299433246447435257953227"
+            ),
+        ],
+        [
+            MontFp!(
+                "14558329494269377622208861921348890457222118213637308340659128124438501669507"
+            ),
+            MontFp!(
+                "12619967900466407967749357943668927081723591892499573266648969369369763989506"
+            ),
+        ],
+        [
+            MontFp!(
+                "19861601462065584882985090065454496695579943525939576630620758918304643608343"
+            ),
+            MontFp!(
+                "11968508451364876690841812759042156699846207579632170874049277723480777574690"
+            ),
+        ],
+        [
+            MontFp!("4795233874025647477949140044133169599509248170705224335766206100340627981138"),
+            MontFp!("3094885842569213053391428902147869219867606003057727210719764814598408185135"),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "10944121435919637611123202872628637544274182200208017171849102093287904247809"
+            ),
+            MontFp!(
+                "14592161914559516814830937163504850059032242933610689562465469457717205663745"
+            ),
+        ],
+        [
+            MontFp!(
+                "14592161914559516814830937163504850059032242933610689562465469457717205663745"
+            ),
+            MontFp!(
+                "16416182153879456416684804308942956316411273300312025757773653139931856371713"
+            ),
+        ],
+    ],
+};
@@ -0,0 +1,577 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-2 instance of Poseidon over [`Fr`].
+pub const RATE_2_PARAMS: FieldParameters<Fr, 3> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!(
+                "12502394742535973444795760632318383767339342181470228329155508395201188460930"
+            ),
+            MontFp!("5481416226612959370567475794433709282933806432499450580019195451102956268841"),
+            MontFp!(
+                "19319463828961040780579405315833190438927328892510528434920210122629727699801"
+            ),
+        ],
+        [
+            MontFp!(
+                "11532452692464603280621307419798575386733690138220328206391714116820004806755"
+            ),
+            MontFp!(
+                "15918083279452184950925445757547746509984981112237222060675244828774006161771"
+            ),
+            MontFp!(
+                "18433528399643084261978155487485298493607339896518011647746472928492191276786"
+            ),
+        ],
+        [
+            MontFp!(
+                "17529136536224955440094555164425021301962381684397628610659486868961115898256"
+            ),
+            MontFp!("2510143748523718457296596479856299004608793634661339338517362849092495122980"),
+            MontFp!("5792319842304691427207352512839355462005298299991614226141212528081277126334"),
+        ],
+        [
+            MontFp!("7430929066745878655806907221734407776957928361313136301655022548921786449890"),
+            MontFp!(
+                "19491955241112376921366382751485384191411795075487961637207578696290847935734"
+            ),
+            MontFp!(
+                "16982297366033445593653321549893394626164797151762660801702830403244113375552"
+            ),
+        ],
+        [
+            MontFp!(
+                "20833664911411919678000039673732439453321789343279114333211306453112933523929"
+            ),
+            MontFp!("1898886346478024352526569089397893718006009613230968580819573824419002860320"),
+            MontFp!(
+                "15550045984569335813835080083566866315887444879342856115109715911286414790885"
+            ),
+        ],
+        [
+            MontFp!("2426519839287278079489057559225593660579169738258973818210828220155035170809"),
+            MontFp!("1315360185792171597028198482500861978173239254160913032589792706989598210075"),
+            MontFp!("7554906721008587236496159399296311771755058400336500368804426811814930949583"),
+        ],
+        [
+            MontFp!(
+                "15772391609860897228644273287122712042151210716918705654866328878784502752175"
+            ),
+            MontFp!(
+                "10413865199637949876941154864178368653546899334715209295785227741245982024478"
+            ),
+            MontFp!("3500833962290292265410191401236312262723631400497895514230176349770670067893"),
+        ],
+        [
+            MontFp!(
+                "20342866415142973023288055624441616473810291297052066233277735808118289207989"
+            ),
+            MontFp!("6839231384149946978517975225484762511863688348710282212003457717345475064633"),
+            MontFp!(
+                "21121156613420658152943280386740849280685045853012304776360872808182471310682"
+            ),
+        ],
+        [
+            MontFp!(
+                "11418668251008093610222864896976619231360580410378380409189092987861815081065"
+            ),
+            MontFp!("4371658334797197139297375495916298984210394309526095070141167240580324578151"),
+            MontFp!(
+                "14379507636998326476647912951212759913096204714620911828181701253777166080156"
+            ),
+        ],
+        [
+            MontFp!(
+                "14135569584709656197051013300087390437471606315494359329076176613500713660984"
+            ),
+            MontFp!(
+                "20310346602754870678095182726181427842522037917356659433446982752533998844108"
+            ),
+            MontFp!(
+                "19037984183132569664457415521156968402892684624902182232842908599039992097991"
+            ),
+        ],
+        [
+            MontFp!(
+                "18440177821471324603770414486363732810921534945398526212501230791685254841638"
+            ),
+            MontFp!(
+                "11191929065401444978596944676766102636325052361925940946707504323462502760551"
+            ),
+            MontFp!("9715487020122649822461649300677848423183313555933216020434521400367279064601"),
+        ],
+        [
+            MontFp!("6255570901517861572695229086294811540351695066044833538002756529094295858464"),
+            MontFp!(
+                "13619811080440238784601255463091292984771526671564563462341922966314267239501"
+            ),
+            MontFp!(
+                "10423371285087415032930489905061134168418356178189868040943430727833916645044"
+            ),
+        ],
+        [
+            MontFp!("8713483808954849022300850997222946754740062619381591882264486019322118920359"),
+            MontFp!("7407988580157960616374349776464067103927327020984121613319312696187093144247"),
+            MontFp!("181920664862604486579885622594382626232020724001514804073226777964339347688"),
+        ],
+        [
+            MontFp!("1416970717541717687401780339222525421630764344204382989165363784293412848625"),
+            MontFp!("7777441722809692327655025344209772664364987508016535974103010706449035162001"),
+            MontFp!("1112541176791938343533446758828319213997435873543817541550793331042192360855"),
+        ],
+        [
+            MontFp!(
+                "19357191793484129566300629101349170611252835793194588750419126207025789976727"
+            ),
+            MontFp!(
+                "15290871126548915259447662083308637973192533118231310115402475496343108116331"
+            ),
+            MontFp!("8524355387355339860171746286556090331566710275993717793314011527984203553029"),
+        ],
+        [
+            MontFp!(
+                "11069060553295738367641575575396689254344234867571121461568199406357081611886"
+            ),
+            MontFp!("1880793571986012449317976621257202014801179001244000539450415883353965098614"),
+            MontFp!(
+                "18348375312252560179068665882968348513412313973652962452254243706332944534791"
+            ),
+        ],
+        [
+            MontFp!(
+                "11848455035073572240520122872373358273624635155417802529471714185772923477951"
+            ),
+            MontFp!("4821402339217554215826870882264808369099830531267246397201635665743159215686"),
+            MontFp!("5921691030478568643987616838398541215475056546296514184257147678978792104048"),
+        ],
+        [
+            MontFp!("9192571332640773316025689330719054821377303981623474151818228512224113240171"),
+            MontFp!("9175442740542471974045212939649480325655707786620793977112174421869083272472"),
+            MontFp!("3534276246221715997200882154142690573231743541823093418010606016455803874241"),
+        ],
+        [
+            MontFp!(
+                "16789982233705906485521903525512139238589260118805777553109528422488750941469"
+            ),
+            MontFp!("6520794681370508019433600691431033648009591505693874022158717188981189788962"),
+            MontFp!(
+                "17590219917143718391813785613808346652684277398338092670155639052398799361977"
+            ),
+        ],
+        [
+            MontFp!(
+                "18043299553523296209745378507627643769017247931677968040400156214724581802879"
+            ),
+            MontFp!("8315508607082204167931233498673148457266920435504453110354440614091440565576"),
+            MontFp!(
+                "15353836631846932321891844078304090774575355048768337459936414880323495492380"
+            ),
+        ],
+        [
+            MontFp!("4452915677577039208434127319141632189027007984622085619780383093954725477336"),
+            MontFp!(
+                "18524180948458233399244913507871551211441063529705115821571185526738712438491"
+            ),
+            MontFp!(
+                "12048612043349734713539832696184046785821973431161006007912975452491588500403"
+            ),
+        ],
+        [
+            MontFp!("2326871788816371253498574386575373010879978604797654491890096334029601979129"),
+            MontFp!(
+                "10251431108500631282622289451864832252525811760175282949910967532267684962934"
+            ),
+            MontFp!(
+                "20226083376866794960595051572569848757579493948106644142660669805490336804982"
+            ),
+        ],
+        [
+            MontFp!(
+                "15133486445789813351051521170734797472015960749826152308539482572946414888131"
+            ),
+            MontFp!("5522787558718674668802607400384656228772146204419049230217052208222353773815"),
+            MontFp!("2793336118749064578278328655486988204806424252938672536693386395145346271437"),
+        ],
+        [
+            MontFp!("5628577245688354308122728937986993904367921134614651610475652948188460273326"),
+            MontFp!(
+                "14811724204520140103307208626638918741524410956272416224480977573532436565058"
+            ),
+            MontFp!("9941823410928081268900795935172685684670476319891523305703973404188618523560"),
+        ],
+        [
+            MontFp!(
+                "18557248805047936697951175324498726405356901633670563721003844336056772930563"
+            ),
+            MontFp!(
+                "12027402055277113176841024630273163213421132370827269339617744939123603028516"
+            ),
+            MontFp!("1519725175564548542532307808802044324838530205773081116902405174421921280129"),
+        ],
+        [
+            MontFp!("2294529525161192305719889814808107341009294597715358825607482310928314913353"),
+            MontFp!(
+                "17309934918157089805484897504612704962678635821999159691534932572316463737638"
+            ),
+            MontFp!(
+                "11672829325422317984913122153981471722843062345564328471851376542068490644562"
+            ),
+        ],
+        [
+            MontFp!(
+                "18783476013425975012397534546624946095347638369215273114445093133307477065871"
+            ),
+            MontFp!("9412456844950242030050038374871202441685417489431139654965135866128213426014"),
+            MontFp!("5194298742878575548142360339212456732597741453341165459055584144287475019563"),
+        ],
+        [
+            MontFp!(
+                "11726076047284616869153392449246274456563796755381258773073648439721522847347"
+            ),
+            MontFp!("4626540001082052822558674958987108522303758380657632455898625031056879639079"),
+            MontFp!(
+                "12324910915164650260808135220271063609703454762717538698946423100914501372086"
+            ),
+        ],
+        [
+            MontFp!("4846172096913949711355879593273499148624658300803061983182243347089587665674"),
+            MontFp!("3359398489448475642296068625802228762641150335613420585337092727551637000338"),
+            MontFp!(
+                "17283720950721235963150839139766011532303062654550941522876964744444825885820"
+            ),
+        ],
+        [
+            MontFp!("2921749738575024367555219385254751096606901973129162206644008238998362040002"),
+            MontFp!(
+                "21815106507547316504565508987621440452196700561122941505116273710422742256600"
+            ),
+            MontFp!(
+                "15633846084045958795280709093904810906152850091795269725462005459678594965227"
+            ),
+        ],
+        [
+            MontFp!(
+                "10243994926082784692031612594728351969788874715347984555411591038701620134823"
+            ),
+            MontFp!(
+                "14220021601282242113295167591829837479042845866280649053797289758262311224307"
+            ),
+            MontFp!(
+                "20905040245116049400596384200541411983275285883570986741570908849280970085670"
+            ),
+        ],
+        [
+            MontFp!("1029711921329574618305693003464114475958778427069202076953159688443330760523"),
+            MontFp!("9209747730203230846386458498910836245997623027101072515590905174122741788859"),
+            MontFp!("9384518633785458913795613473485363774825758250810301791096165181217073600699"),
+        ],
+        [
+            MontFp!("1988678186133955354216459657134212884559735260370794146878324449037216966073"),
+            MontFp!(
+                "15308476710344117123228666618977401662733996652652000944904875658701088249251"
+            ),
+            MontFp!(
+                "21393129197372020818630894031645843602447417019855309118352302839477810705280"
+            ),
+        ],
+        [
+            MontFp!(
+                "21856025072590119560594944430217761607930148569258698011463840655439841661822"
+            ),
+            MontFp!(
+                "20211000732594770223600091833269952000696045944896385200115861710673773672871"
+            ),
+            MontFp!("3612102082853709684088890985892462687902203277814107225112943594724907765126"),
+        ],
+        [
+            MontFp!(
+                "20810496943532327711493946155160830331816156355693248220827779056039324370716"
+            ),
+            MontFp!("7710998811066464637888400818838889998700617525186459635208054220303166742641"),
+            MontFp!(
+                "19207439433746246927422464146433443359530319855711855691735040137907849976562"
+            ),
+        ],
+        [
+            MontFp!(
+                "20945788016618297893835242321450170525400759321362974247954653042707840478588"
+            ),
+            MontFp!(
+                "21184309592159748990938945606418332315520525330793897573378774623866231764946"
+            ),
+            MontFp!("6125844154060688296672814666766827000216989374061231522616714890425457637158"),
+        ],
+        [
+            MontFp!(
+                "12894605829163636324473542877267366865157886297964292133802378456178180799333"
+            ),
+            MontFp!("3510456899172506003415862972276424932951583100121777213856102902794994991647"),
+            MontFp!(
+                "15661897952151742451634565024427639011828333551759433182169995250026241832377"
+            ),
+        ],
+        [
+            MontFp!("29140497121375015370311604797762999975943653322555384348030079506348759723"),
+            MontFp!(
+                "17594536559611373947517762220156353344325502678604792276802724286755542676705"
+            ),
+            MontFp!("4016736233416327349582440015093877465656657309655105697844586853792569666144"),
+        ],
+        [
+            MontFp!(
+                "15919207095304034301052681569506106385261537475093459789074696249753483254780"
+            ),
+            MontFp!(
+                "19182842418928195718930912860730416919240306463364950531097085946068327346971"
+            ),
+            MontFp!("3400794967110228560668374987583490752194760224800416046699215116075815480104"),
+        ],
+        [
+            MontFp!(
+                "14304414616556692986866619145688844859959473696808814864141072437804328192663"
+            ),
+            MontFp!("7232152651395813053850716289250760135036258586489053832409079351866408520431"),
+            MontFp!("1384501373301621647036781737553434699627382378942654981559011346445975550224"),
+        ],
+        [
+            MontFp!("8629541381442200399227540151064873769764903619922139853928228754119564272376"),
+            MontFp!("9748778018012415711564684754559557736628533351928088805168725048287242902584"),
+            MontFp!(
+                "17290122548961830656605985348337804083789697216937970732515894625825416956920"
+            ),
+        ],
+        [
+            MontFp!(
+                "14694557528047225323761580570719189805365359941527075901335419913937768903233"
+            ),
+            MontFp!("2429839514951510165237569836360793835617803808655190427617678801579750923636"),
+            MontFp!(
+                "17451767287514675970948506008602372705434628347551535859848836302474935219258"
+            ),
+        ],
+        [
+            MontFp!("3928463620202156528223158386702472515600212092987574202524380417941586100969"),
+            MontFp!(
+                "12326995243882726396359722839254764124625691435666702623943303572431073178995"
+            ),
+            MontFp!("3983766252217755647264348570935965284993357811590055908074985990463865078217"),
+        ],
+        [
+            MontFp!("7929692777236641166213412442113546926594743693777881995396496710443464407189"),
+            MontFp!(
+                "11096147922491058636298218177044562626611192730150686024486588601191563774398"
+            ),
+            MontFp!(
+                "10783082926971498145975947419999906142310735562597538741387194159436934088989"
+            ),
+        ],
+        [
+            MontFp!(
+                "11700579797764694130526199690291159433979704690648064067535100065558903580029"
+            ),
+            MontFp!(
+                "16369186579549465997960799989729387720744841946585934981870541524481405436110"
+            ),
+            MontFp!("6113253736100187800243660367999994795177435990036720243925165272537102809900"),
+        ],
+        [
+            MontFp!(
+                "15337835608348598904130670513332304414912202536258888428371332775755725967018"
+            ),
+            MontFp!(
+                "20881578796031857434417791579229908900587507135765681036204896641976516768733"
+            ),
+            MontFp!(
+                "11907089662598435152944590301386588638121045015106008919032453044345916155581"
+            ),
+        ],
+        [
+            MontFp!("5961472459104855530699963168176663598347909579058633276616112111720922721731"),
+            MontFp!(
+                "18625061324544394375115395547521875462219409987089357069711611394694724821314"
+            ),
+            MontFp!(
+                "12618588947984173717386092977194610505907558672098024101931280480257521365773"
+            ),
+        ],
+        [
+            MontFp!(
+                "19258463032802676108273259900589785733306876118120951285036373987353737301418"
+            ),
+            MontFp!(
+                "18082390467754333552241326197155496866782900889848536694158109796356757521119"
+            ),
+            MontFp!(
+                "10268449095772897457547001572330968229999124222222255722116703307826393394580"
+            ),
+        ],
+        [
+            MontFp!(
+                "20080984403348357280546458919794552282967411710044455590491861707831168023609"
+            ),
+            MontFp!("8191618407316288317440334960348186210458763299700010837462691653840623870201"),
+            MontFp!(
+                "17960937232194663537696447722722621164348139390674496106167299553190641500825"
+            ),
+        ],
+        [
+            MontFp!(
+                "15539905794239332239542781347738457876121494177201952534805595805290163645104"
+            ),
+            MontFp!(
+                "13577837548242278319907396833103575613018749724054140977589487243280275752736"
+            ),
+            MontFp!("3047402093663605036846761632699309853671305297373290962900051472268537821186"),
+        ],
+        [
+            MontFp!(
+                "21395534162255367765176302672725608294848042143440575656965114987228763394692"
+            ),
+            MontFp!(
+                "18621567903450991221942756249851154313846267615052676741538964864997838492028"
+            ),
+            MontFp!(
+                "14867093283540939505626120866911424397071653730612801919919519877455095664598"
+            ),
+        ],
+        [
+            MontFp!("2577225620373269470546891824588608858827198988776192646961147753656821276990"),
+            MontFp!("7716599103660064973275830099832084012237307946337621019128491116070633641042"),
+            MontFp!("6211982986578405600483453025425368227146491832463953086697949232749487841510"),
+        ],
+        [
+            MontFp!("7590358953279279930364423913453168856824651554555580775929976402444962493181"),
+            MontFp!(
+                "19092502753864384725822000366407749621873398083113879301903088258017302901870"
+            ),
+            MontFp!(
+                "13191061624738344668234875613401004350664492991479826667739598725923321080180"
+            ),
+        ],
+        [
+            MontFp!("2061330304308492937759442043226427304654813006532087200601764668875219741472"),
+            MontFp!(
+                "13887437217115272034162153018922736223884421153940055758935814686187958397314"
+            ),
+            MontFp!("2319085048726846382181216060644338043092917560313464647004611771869641267427"),
+        ],
+        [
+            MontFp!("874721879121861619492745408252390052701147687316194089005090704847349296292"),
+            MontFp!(
+                "11650668137174285614134432903838633239089653837039980586441948104282070762898"
+            ),
+            MontFp!("5852237246242943006050612002231680038463333296944901002364974514588667533185"),
+        ],
+        [
+            MontFp!("7612310716709139479767796718381232036931691272383089649676203802474664461059"),
+            MontFp!("576267801697700732266640569933719779974975494192581048525300106295962817980"),
+            MontFp!("5927065176901761749495295377402028043396104464935678163727576654021537817693"),
+        ],
+        [
+            MontFp!(
+                "21249871788720296200703325798646380382788257664240815435444013903458583029728"
+            ),
+            MontFp!(
+                "19299870395561703030478035334876376115295192145298721678593915588622569164462"
+            ),
+            MontFp!(
+                "19569725530544582676777532695846342207058453801969626417733949600933479509073"
+            ),
+        ],
+        [
+            MontFp!(
+                "16929326315132778037499745376532341758628460135583369052387401764759301676684"
+            ),
+            MontFp!(
+                "11931122245594467014600929641504819874263663022430762286489211522733996609703"
+            ),
+            MontFp!(
+                "13051871355983093906784965981325065831801849255966504985772280247367211045265"
+            ),
+        ],
+        [
+            MontFp!("1327176713323412952014937644731992388824196147527181912774175186923332979402"),
+            MontFp!("6525586334594603518224013201264739628046898989572680100667822763848948860395"),
+            MontFp!("4887458781655017679178993728726679623888917026290101567483655998108114790462"),
+        ],
+        [
+            MontFp!(
+                "16849544954132845101403053912856555876679768883969282491910761481053321056346"
+            ),
+            MontFp!("5281862752013618048386712317977693668302581362790693303743726333157849379191"),
+            MontFp!(
+                "11603109702346555031959097432687452593839986676464583452778924234986805288187"
+            ),
+        ],
+        [
+            MontFp!(
+                "19886998221412451651050899120566617347942181734800917748308734719737542287963"
+            ),
+            MontFp!(
+                "21124757030728938835828950299422959779583965622437222594853879197257001840132"
+            ),
+            MontFp!("478884963299088302590807943390653928387318816201018160124547872348298725062"),
+        ],
+        [
+            MontFp!(
+                "20327770065897661957062082832832229824307965288502339472866243175801137501417"
+            ),
+            MontFp!(
+                "19666534927545988854295167263479154391611709637406416373613722530800598284066"
+            ),
+            MontFp!("9959144872096608523596872867243015749128530157148975418275614549846515116309"),
+        ],
+        [
+            MontFp!(
+                "10749787749012126319116475580201091744896330331149819657235990014794674937435"
+            ),
+            MontFp!(
+                "17999072083507358123845938501046581586443974514296194701759574388611188928996"
+            ),
+            MontFp!("3597362052046912974136916113184973732648069537464541133442299867375610240748"),
+        ],
+        [
+            MontFp!(
+                "18494479843914007511401665319331022996485977429038784400250699974478545989124"
+            ),
+            MontFp!(
+                "11966763811052525928909829295801185573156882751864115445845798150116316013561"
+            ),
+            MontFp!("9148244827909621392110679934733593143796101380643351570919001858032034677387"),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "14592161914559516814830937163504850059032242933610689562465469457717205663745"
+            ),
+            MontFp!(
+                "16416182153879456416684804308942956316411273300312025757773653139931856371713"
+            ),
+            MontFp!("8755297148735710088898562298102910035419345760166413737479281674630323398247"),
+        ],
+        [
+            MontFp!(
+                "16416182153879456416684804308942956316411273300312025757773653139931856371713"
+            ),
+            MontFp!("8755297148735710088898562298102910035419345760166413737479281674630323398247"),
+            MontFp!(
+                "18240202393199396018538671454381062573790303667013361953081836822146507079681"
+            ),
+        ],
+        [
+            MontFp!("8755297148735710088898562298102910035419345760166413737479281674630323398247"),
+            MontFp!(
+                "18240202393199396018538671454381062573790303667013361953081836822146507079681"
+            ),
+            MontFp!("3126891838834182174606629392179610726935480628630862049099743455225115499374"),
+        ],
+    ],
+};
@@ -0,0 +1,704 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-3 instance of Poseidon over [`Fr`].
+pub const RATE_3_PARAMS: FieldParameters<Fr, 4> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!("6989206064138186724576560065838025150983232360672096953206968039263150982299"),
+            MontFp!(
+                "12385331421222792962647215682398155030159735771393170722416573201263207516591"
+            ),
+            MontFp!(
+                "12610767348965574746057159234619619709575703545291955258995009186700857395697"
+            ),
+            MontFp!("7729661182287063096677693873818076760911862409961532260712052723637358928470"),
+        ],
+        [
+            MontFp!(
+                "14858079947535007600258812361217807915444644603508336307821063029635114254606"
+            ),
+            MontFp!(
+                "19030719415304151048389023779214990207277173706184140997599225928504264115693"
+            ),
+            MontFp!("2863024648811861533738267863842887515531601059145255285202137185088254338314"),
+            MontFp!("4460350423464018106309445766249032561365646869717076015396075673823906757900"),
+        ],
+        [
+            MontFp!("2589295257913333379205605752797198807212884998666546305800989972810389155640"),
+            MontFp!("619195231620576565858985836415250331036684692481766169036436392534786914451"),
+            MontFp!("8169152646159785175486346835613787549457261351482755936854526137300965043188"),
+            MontFp!(
+                "15167474372335876070629576662104636269857640787343808477531322798417199873416"
+            ),
+        ],
+        [
+            MontFp!(
+                "18090155888472215518687387175631324186833045067799118408658536209742439966950"
+            ),
+            MontFp!(
+                "16650061350542203705904123310281783436433478010598227738776622695651033477178"
+            ),
+            MontFp!(
+                "11511444856540710342976165282211926527363752365661095568426866527515044913497"
+            ),
+            MontFp!(
+                "16146838856747464770741788052163520418546249132957692916702255902034049482638"
+            ),
+        ],
+        [
+            MontFp!("8898542226110947938957894779963864289951609977145665859086861195644425342435"),
+            MontFp!("5422089789579956111430955948025517839063428548895424176533111716375116479527"),
+            MontFp!("5876201609727069933066575169943970124439843188547468290051967652391374901770"),
+            MontFp!(
+                "16650479683364596660725739686678581317877455186643021212875798260544577378802"
+            ),
+        ],
+        [
+            MontFp!("7719035201584188087787756320441809862975963489005364698248992666907883665756"),
+            MontFp!(
+                "19123287519694075029614999441067035768366715697251091727009288098859063774422"
+            ),
+            MontFp!("7048522819278604189468765308662627939571384327452854020829276908175323970090"),
+            MontFp!("9705177147834454777455803355314608054376334286302174677900279786731518144718"),
+        ],
+        [
+            MontFp!("9269762924078465009053516638845465404699295762392869333985357385832659788854"),
+            MontFp!(
+                "20754858006222249353729062654914623593680914411331168493310490285778988760930"
+            ),
+            MontFp!("3715751209678458932812296041493259694663236472083913633041078307156433400169"),
+            MontFp!(
+                "17076375076943493649246931750083907774814981377931828864234613360581881393187"
+            ),
+        ],
+        [
+            MontFp!(
+                "19190480170096554849560941881825726856251635648773866873666965720308823331711"
+            ),
+            MontFp!(
+                "15137845889843508737551845869954221312726379131663109206166387322621947969575"
+            ),
+            MontFp!(
+                "17584285767496273270906609788370963013384007994008672430070783712179214876107"
+            ),
+            MontFp!("3418066602582604727934130229291409208762466223953501283799662084475609561703"),
+        ],
+        [
+            MontFp!(
+                "20929566517675812125625379485679124819338276935595300962617020679552098498939"
+            ),
+            MontFp!("3213242245586172654460114546545512349720408730107513851135490151383869813390"),
+            MontFp!("6460770075289473881577047251327831880515064613076076356452047677073493900156"),
+            MontFp!(
+                "12727394631101019438548653148087253273399394981030320236791441667233852490435"
+            ),
+        ],
+        [
+            MontFp!("209172859221986440081565780822020424876308930895844127594058747803854122530"),
+            MontFp!(
+                "21529540648426409593084406349759682583432808850292768355544661930127265189681"
+            ),
+            MontFp!(
+                "16754480846667767430443023031487310513302961070333887086063942881724160886718"
+            ),
+            MontFp!(
+                "17044440668065499744687705922828475911077202339718931744586231380122558523654"
+            ),
+        ],
+        [
+            MontFp!("9272460664349150683990900741321979890625837882267396076373242286437922623998"),
+            MontFp!("5105524688727706685210417792745577646482741749682136766140022089972929352304"),
+            MontFp!("3171977529895672480931194971623016137439289794463254381856744385624241580738"),
+            MontFp!("2244716226147645216278499899330372827278358290259067987938668226129675595615"),
+        ],
+        [
+            MontFp!(
+                "19767533256236627567774670822505047534145803118639356214136111572440891338248"
+            ),
+            MontFp!("1547802471702923926185371431708160749021346810005520304168768459202880151272"),
+            MontFp!(
+                "11668461804456491287282024809433644392729148893324122894729514850704740655064"
+            ),
+            MontFp!("2169031068089914913585395284163116029544320497897223440754045403942904432159"),
+        ],
+        [
+            MontFp!(
+                "15462113823071033240228937787673652698053363835458408674186356609291725594812"
+            ),
+            MontFp!("4726828957398747939524900935622966176299947950444317497422923855928897261119"),
+            MontFp!("6906226831246218898469768359198030912655334942902716486482998053744766321375"),
+            MontFp!("3272200200706745594399589218043220058631502718774942237364087262952447615701"),
+        ],
+        [
+            MontFp!(
+                "15938466434850025657817606306545187233284776511046427667187849411548711956567"
+            ),
+            MontFp!(
+                "17958728471884557675922040447393312921383624090315403110645052786783514882561"
+            ),
+            MontFp!("9988301159270440983070374102579041479386276659553609147319570508003732902516"),
+            MontFp!(
+                "20588522535327432452962244447080167731968115236922026548121805583448558319452"
+            ),
+        ],
+        [
+            MontFp!(
+                "16446351995802015551815966652397724839602771979224355709682227296442729101757"
+            ),
+            MontFp!("2170997410809089166448796883733385897466832506585011785460119246600172976582"),
+            MontFp!(
+                "16799683325691093839043195795569235145405506480557193539788802458631370690518"
+            ),
+            MontFp!("2114123628621787803383998014093621842204492234248145429841498704254177878901"),
+        ],
+        [
+            MontFp!("1217919625296682412710412041638589400042375071846322165933735480589710153319"),
+            MontFp!("5518926696469079767472730262289269192766839641798100154338110120780515957463"),
+            MontFp!(
+                "17438784066305681698627489973538167386290388531779193872800943276079355908527"
+            ),
+            MontFp!("3957680471887368121567776448636692893506676566228164433338965188133658949612"),
+        ],
+        [
+            MontFp!(
+                "12128086335709920054553709311492701373724650313060873510720657580493564021565"
+            ),
+            MontFp!("4970935013556369473037931430939482554422358268511031385458661132372992351999"),
+            MontFp!(
+                "12951239474072496063357117558012516217890269626129386226829352017556967359424"
+            ),
+            MontFp!("8394554453099399939958600437533685912895369613901334927452425770496855065866"),
+        ],
+        [
+            MontFp!("7208453440577052592688017811921469405912481834041041960686523028092587231956"),
+            MontFp!("4924269322088307501168723877643792985198390694139861991704085635975109391890"),
+            MontFp!(
+                "14393411073940396934450794292648453494253585810085005132240287588173817832054"
+            ),
+            MontFp!("5661206671820297848097531440053849260918111123324715967921747808418982136671"),
+        ],
+        [
+            MontFp!("5167668152059344563900027509159452998247805463966820182044931575593804440768"),
+            MontFp!("3821303710935942401121558848245460438924436605938423645271596931016202633649"),
+            MontFp!("9884634428867475459847494545667840663343098524902456140792679878179215623366"),
+            MontFp!("9620016547634997193837649193969704590902093351564378054259162228412391952552"),
+        ],
+        [
+            MontFp!("5239869387582173377958930201653351426374360692224129591255390133527606739377"),
+            MontFp!(
+                "18210285633468281743424662483699854503274156223474139524179282746525997321366"
+            ),
+            MontFp!("9217047217162667396948577516457367053495709283761996051898920501813992608429"),
+            MontFp!("163045523907316039709350289864669426102835736635750178358816882094817086471"),
+        ],
+        [
+            MontFp!(
+                "18157123947242504502679448835138702295728653861578577913473822693411028051637"
+            ),
+            MontFp!(
+                "15331481885249785244365385153513568577332639030991843188708905825858642505156"
+            ),
+            MontFp!("8989137552182637228841786968873885866630683032214920882568564526836094054690"),
+            MontFp!("7967307514039968318766549216020995678227246059080365092586288685642895852451"),
+        ],
+        [
+            MontFp!(
+                "20904911176374245469981680253685303791492346027277487957195055211728964055723"
+            ),
+            MontFp!("5746193731983865114974486989024958718375745185421213362614149722927339594073"),
+            MontFp!(
+                "10017920915918817431820645138672900496010982612879988028541803910302033777266"
+            ),
+            MontFp!("6308049759641611643455153015245298996307683726473918447121913048129423702087"),
+        ],
+        [
+            MontFp!(
+                "21852602680866557816441408909664143792925527766815199540888415354729548701250"
+            ),
+            MontFp!("5327891129412966148701859067892754527211683945830712306253155230432200076553"),
+            MontFp!("5548882580684885798079987054483098994836736009960503628831432871213085404968"),
+            MontFp!(
+                "15706098279068891619209860411667210176714318219278884769470022566834708638598"
+            ),
+        ],
+        [
+            MontFp!(
+                "10632613091962979790571439605635949840782414170039643099225404830593554542469"
+            ),
+            MontFp!(
+                "11344270092816945021453847754497856069884142522377731202679340259584285096856"
+            ),
+            MontFp!(
+                "15969420417909840653569234798537436281866179039950297553062995817298258462837"
+            ),
+            MontFp!(
+                "18884518559512811806978765422215745564232422964715221721291387623931136259008"
+            ),
+        ],
+        [
+            MontFp!(
+                "21333388212216722831031479398620586630126307770168422004406451887151256568587"
+            ),
+            MontFp!("5898220963269381696565194841440082487599819286959915624770445528172529916425"),
+            MontFp!(
+                "14737119037292836216249592944086118547974622061858780043409064086730585232818"
+            ),
+            MontFp!(
+                "21649684079871980197908906952046314983097593028925911786441456960156296604733"
+            ),
+        ],
+        [
+            MontFp!(
+                "15358739255898771397608408373663238270297608401910985005708075128353544291435"
+            ),
+            MontFp!(
+                "17910248562250368935458826550810997127113864734152887292945502897393371880297"
+            ),
+            MontFp!("1896133661037106561110769212421003315662505684090169021836951265331587654333"),
+            MontFp!("933999185216362994185875510717173722699770845401946177962262945385300269259"),
+        ],
+        [
+            MontFp!("1287187097366663675509871467487826898073454419075056878074186044561362716900"),
+            MontFp!(
+                "15485037395745924460381621473087091774104467228951687001258543292871811542791"
+            ),
+            MontFp!("8838365642250481715742575485256540430068381596949802630623830979064804856341"),
+            MontFp!(
+                "19799107995858749283115115015138502625512947656878721492406484194846971040792"
+            ),
+        ],
+        [
+            MontFp!(
+                "18308067253220992282065090453136527824786408725182428555499807825704772542718"
+            ),
+            MontFp!("7329765169793203494058506676109479508074760457912363368121255879983319252802"),
+            MontFp!("6595213130647646229635889017348808778048013136691756409116971059792893944973"),
+            MontFp!("4010960263133169778199895299184229007076541269544441475297851766557708934769"),
+        ],
+        [
+            MontFp!("9574545661001527057160273967178115896998352128399710673833420598172388664735"),
+            MontFp!("6324548441308408610005618220833232738257052466831028589646777729332659810157"),
+            MontFp!(
+                "10288611120491131118438315367654829790684622449584042054490835165703989657898"
+            ),
+            MontFp!(
+                "14301823335383336419239834592397948303315869193351552691391291878312891956176"
+            ),
+        ],
+        [
+            MontFp!("7242511317799095275360664799301790000023472248345329124399331072716598853450"),
+            MontFp!("3679599636894113169588445125374868034257916056420206597112255728428693907717"),
+            MontFp!("2044172733839254081970698835842807545917629124545023158716360565299697504090"),
+            MontFp!(
+                "10729371536621015095881301133425220408458262730438882640369744284547738264434"
+            ),
+        ],
+        [
+            MontFp!("2942946682204200678224802974496372210092574226660851637682994495641158734175"),
+            MontFp!("2952222945742259260426060333998351362639902946874621803569447835669067043444"),
+            MontFp!("9813867621925555814563627066568363773790479773684962699077815028103840583786"),
+            MontFp!(
+                "21381938969946825374287486266562931083824050006960710834048580867960910828473"
+            ),
+        ],
+        [
+            MontFp!("4864314676416059139017019327515484137621717971769423850336910081427614036087"),
+            MontFp!(
+                "17114123352035167063914989294351646163163798805912875522675902798330799661815"
+            ),
+            MontFp!(
+                "14823787472287400915541509645880756378974045810584832720594246639394577767606"
+            ),
+            MontFp!(
+                "17428198595463151377834430909211915762190022256165840103093237676101496749039"
+            ),
+        ],
+        [
+            MontFp!(
+                "12587223000930200926007914780062017188056000202086188070485427974030108519803"
+            ),
+            MontFp!("5373831060984943640536838470209997840624751604165617192105563928606772343975"),
+            MontFp!(
+                "17006367024979646973345571352359735572931737268383549130424689425654005855366"
+            ),
+            MontFp!(
+                "19985783594404858184536814527958789551613010925652047437222211657065040454669"
+            ),
+        ],
+        [
+            MontFp!(
+                "17502424457087200196778611688265898905899628894105984584234402682803469473014"
+            ),
+            MontFp!(
+                "13534411768188726235770234692632194078315954875155879489860658815825048857830"
+            ),
+            MontFp!(
+                "21650186521551902429264841380423919120819590635854907391914311255968816949017"
+            ),
+            MontFp!(
+                "11205113204957829820301259490242665741693936035540155949143482413701932582594"
+            ),
+        ],
+        [
+            MontFp!(
+                "20572281815235537473238995711575870198882820800808893548923008660671735098129"
+            ),
+            MontFp!(
+                "21643981998194682255330097256188813439321238811723737559330059782167478247525"
+            ),
+            MontFp!("372079280198664046813545263465387908375406601152436588832747043521380012165"),
+            MontFp!(
+                "16373328986886918337440681837556948383626177661446503933378247943824886908547"
+            ),
+        ],
+        [
+            MontFp!(
+                "13986376885237030304475563051049356393449444461439714590281312204491648586746"
+            ),
+            MontFp!("5753044900450621125094565103209948046116074349062136966669956889958532101227"),
+            MontFp!("1592372229945751054411957966942238297764031253118258764275288375304479753317"),
+            MontFp!(
+                "13985782557210646726466547416608596260375163861185748095841110905263297240076"
+            ),
+        ],
+        [
+            MontFp!("8107640966748741829746834885163455673015446222409468193729245965004023310620"),
+            MontFp!(
+                "15357859787568318242021974667989336098265933350507336503651027583606643088948"
+            ),
+            MontFp!("8591787009670118088093541282160383799713385499582932267100142950867885233445"),
+            MontFp!(
+                "20243390286027956887977894693473941574714993540291902850218869893440150615866"
+            ),
+        ],
+        [
+            MontFp!(
+                "19466155137509913378983284354622016235733359250004590759232430603655243292409"
+            ),
+            MontFp!(
+                "13330353708692660920692082868197091763875603573502663990293800317981007421354"
+            ),
+            MontFp!("5215680543423055748378157349150091831620976666577097607970181004288830668172"),
+            MontFp!(
+                "13668612804662994726567399350479872031415775759827744698098266872784422626287"
+            ),
+        ],
+        [
+            MontFp!(
+                "21515402372503272589731853559278808105056809144501438978074479805228553700973"
+            ),
+            MontFp!(
+                "18973899810160424190113386213549747020179234352779113925206176762835622759881"
+            ),
+            MontFp!("2483586135128833812203527484953342705877869133450128418619504738290920617894"),
+            MontFp!(
+                "14169641311576621979272176467579848609348707079052789005670343442728182849628"
+            ),
+        ],
+        [
+            MontFp!(
+                "16360678729212221306062965043332229408591576179610636519759912628996086558681"
+            ),
+            MontFp!(
+                "16046642236925217350285912252551718586863882139431692091467934487857869770613"
+            ),
+            MontFp!("7366436579985715837416195920104160920732060635812950291438573683666893207971"),
+            MontFp!(
+                "10168278556406058883281653455934504279441360517202724761611794418069512871737"
+            ),
+        ],
+        [
+            MontFp!("7813953352124819681543371180286970969215044525822975128616264853090427607164"),
+            MontFp!("2689454365445506077306113001675255297334293526827915442668827589735195961385"),
+            MontFp!(
+                "13286131097014686293258109569680048650937079140250863440045832964863966717344"
+            ),
+            MontFp!("1304146450993302787914629327166164802648295645967089914031067577430177512980"),
+        ],
+        [
+            MontFp!(
+                "18470781061814218179336743464842337352226867238878309153518028217089807398279"
+            ),
+            MontFp!("5809406621726018078215071641641016620804171935357138346101774168747144711966"),
+            MontFp!("9099013363448943957707104171290323720255828773921142504055420108602161982644"),
+            MontFp!(
+                "17942594256279930890720053748093930600328195508811207794542727367652761416285"
+            ),
+        ],
+        [
+            MontFp!(
+                "17543333741531764149705430380339488027304430630796376820640605251203888132087"
+            ),
+            MontFp!(
+                "20125179821241575339943090360080811557860296469989280962601092166035192862598"
+            ),
+            MontFp!("6178434141478611277513280958372848953006564276948274878625823198436529625685"),
+            MontFp!("2055869582629112299749229117910697947739024674655577464444931669688576040796"),
+        ],
+        [
+            MontFp!(
+                "19600156529228527143621576392622093270964686730585793976872817588936540478429"
+            ),
+            MontFp!(
+                "19841538120660162372598241865443821941068108593929169978876899582537570661548"
+            ),
+            MontFp!(
+                "10590789500360682886461300533605432413155351619041235748410486333131311111676"
+            ),
+            MontFp!(
+                "21074078177098513999990196887378727778215973682656538161237254252224138917585"
+            ),
+        ],
+        [
+            MontFp!(
+                "16707524739800821613442063341807495847342159053535697348613795745115841799087"
+            ),
+            MontFp!(
+                "21122397322029528007358918505080986003494347692836753643832029938405762062496"
+            ),
+            MontFp!(
+                "19414758703514944278040747049327750814503601835123394647494815537273896168915"
+            ),
+            MontFp!(
+                "14236698874128870412858295672191991899955744119748452744939537944776731951778"
+            ),
+        ],
+        [
+            MontFp!("8572821953649236178764103009462854354139673669229129196069678337489181528748"),
+            MontFp!("6012484387220542386055631381920654865277341567329663533761461354604628396853"),
+            MontFp!(
+                "17290007038359819369507584201446314796044514740125922675790050655413875633866"
+            ),
+            MontFp!("1360874508705092628016952473736941236094665104534752215487460574539468152481"),
+        ],
+        [
+            MontFp!("7147748206041111142642516763384857982193016371341588652286701910843079952064"),
+            MontFp!(
+                "12376034800855524210896931716839858782714394849813365207729827834676933048614"
+            ),
+            MontFp!(
+                "13768312547206758611418539611911676340668642218505321379597189116785776764491"
+            ),
+            MontFp!("6604288383876907827333151660555456264522967694973500913327396686499823451787"),
+        ],
+        [
+            MontFp!(
+                "19770249280988352884561326290910039344939384499039190165799515921891794127591"
+            ),
+            MontFp!("3910731467912299413668906826446326067942722063375962122782984300808018272612"),
+            MontFp!("8189311519143411609527940091397501611838423923441936422140229839183559603354"),
+            MontFp!(
+                "21778584846290884648776809336639447936030995300156277619923721392235240609008"
+            ),
+        ],
+        [
+            MontFp!(
+                "10064026013572763033973798631362782702097690147016255740083400682324901508394"
+            ),
+            MontFp!(
+                "16246631715066470414819939659231789077729927108649817039056407021953320465520"
+            ),
+            MontFp!(
+                "17234172394273360162356207059502586464031450391286445937604849298803224881102"
+            ),
+            MontFp!(
+                "20166956970990218442246228081727338774997639974566110681286087832732393123673"
+            ),
+        ],
+        [
+            MontFp!(
+                "16204748361525788495656382429191959318116423614300214709389063400293876289745"
+            ),
+            MontFp!(
+                "17117956294937757438168853809864328551978948988107011359767174933748664943929"
+            ),
+            MontFp!("8295519064282648117681193343912151081069164977439809047855271230044069856042"),
+            MontFp!("5969205563714710208674329496010617535844190311778735104924871034156449565750"),
+        ],
+        [
+            MontFp!(
+                "19356790249172979122598693488628148924271383338059955765453155012074154565656"
+            ),
+            MontFp!(
+                "20065390946393288528182450430223368285470960711495445599791900192456024975335"
+            ),
+            MontFp!("1828858482396007153894287041648197085895848434274172520786231284158684977840"),
+            MontFp!("5578355494330845209451762366091880041869491168601864777070234445661509022238"),
+        ],
+        [
+            MontFp!(
+                "12563340074031362531200111389327038459812465742237083588464650126236512453180"
+            ),
+            MontFp!("7818408491267122551804564890962902501313106965795951879865107150474482020787"),
+            MontFp!(
+                "19222433365990150747229746212633421717252657364309607954916534823766618705006"
+            ),
+            MontFp!("9598594435391940335351990672059270588046491821489973515499302727980192615743"),
+        ],
+        [
+            MontFp!(
+                "11390537314888586581125660294504027778717592709512093819650252920491015365170"
+            ),
+            MontFp!("2601244631213725438752202123585591022870860150099573935430865482644459232164"),
+            MontFp!(
+                "11274760887091492081547504529596992345809501939029390951955781437347670808520"
+            ),
+            MontFp!("3254076850930880591400410012127162610283399513004730594285756491675434404232"),
+        ],
+        [
+            MontFp!("5163434405163430236451450762167773440175832240868053399330925846712416726480"),
+            MontFp!("5979842213409160644798204566196818040115598377991272695979346094732424136858"),
+            MontFp!("855240295383081722067586322130008401891642321337165265961894093857512356856"),
+            MontFp!(
+                "19613906437500920071048651890341806238728378058802751546748140333074414314248"
+            ),
+        ],
+        [
+            MontFp!(
+                "10140887927051769195318192624030997640090551867896914179415923222537116298911"
+            ),
+            MontFp!("6272322499446157292593614382804618133547066215793419588657637343859858004692"),
+            MontFp!("1619580592918012061657331658516353621319662967264360582822410070082111014972"),
+            MontFp!("6995933294690789116815526034433128490160077348633872052409734294317659618825"),
+        ],
+        [
+            MontFp!(
+                "16876312640459521562829308379828809709511351073126101520771880522749017485186"
+            ),
+            MontFp!("8137615035641490994546792817073754375968609457474649942992680163761113676429"),
+            MontFp!("9467799665864316084970776494247345926639597520115528603263758038712716214719"),
+            MontFp!("5488561348646412135205604128238919204968518386073828489866959852991489041103"),
+        ],
+        [
+            MontFp!(
+                "14099846517565924438057068947724631941101622846753659886211353866362469890606"
+            ),
+            MontFp!(
+                "17241496704755351249112730370350206711784712500026727846734077578726248039937"
+            ),
+            MontFp!("9567474098117553880325785548570548049562093186667756417353301228364268132427"),
+            MontFp!(
+                "20228869593535217022932853893719933964281160485894540162837624603326272182680"
+            ),
+        ],
+        [
+            MontFp!(
+                "12091321037748437029336236146179001585089935207966053501146301969905166366809"
+            ),
+            MontFp!("86583281298099615098107309960484008974316514812329386687404414121006926579"),
+            MontFp!(
+                "20874225276341116312149279790952641589522110446059534057071846273750099496203"
+            ),
+            MontFp!(
+                "19315729677898331851901761568279482086122157618313351267100044182979418953609"
+            ),
+        ],
+        [
+            MontFp!("5031610249430730585969693102327428615122555586579675602932288560863721160813"),
+            MontFp!("5527486609826907524930314073557147352607204507332216170010057595598774652509"),
+            MontFp!(
+                "14739493921902650734522307134392655225710460660277486700655354296301884871849"
+            ),
+            MontFp!("9326308118237913374156264672447642077170075433350315267288572415837394685838"),
+        ],
+        [
+            MontFp!(
+                "17608208155090277746826993444287998693051521913111888004210030436209374333281"
+            ),
+            MontFp!("4024274153438537588423794099159788122148902819871082470796307194511649778006"),
+            MontFp!("1898085335241632040823181491239671490034736160795797163299537937496032894280"),
+            MontFp!("9304331932994261695472885348011540339446687471408893930031381890687028328539"),
+        ],
+        [
+            MontFp!("8517491409647105821066318155581761711379549354023054150557386087160386423627"),
+            MontFp!(
+                "20062522456999544946730623002913454655012179203057073191244461008988840840289"
+            ),
+            MontFp!("8619012404196080882891275218988676731927052064454887569094117076287764780633"),
+            MontFp!("326012097671362024052868321833247799444082307460654973989262615203824158474"),
+        ],
+        [
+            MontFp!(
+                "12413882898716512183502883559641952170682793144020563441993455773157311323364"
+            ),
+            MontFp!(
+                "13079590973381736196594181569259979006062045459136740081555493922506206297277"
+            ),
+            MontFp!(
+                "21790082368310385839137063445654835397421151333411214229501451322486587968704"
+            ),
+            MontFp!("5883796438424833752759679882964067911422889360643276518565797132737887406056"),
+        ],
+        [
+            MontFp!("9677024440231148734668913681362281091647726983907451998829898171064511837974"),
+            MontFp!(
+                "10603357832346039860034718409322905120954223823158864594039479564399737407799"
+            ),
+            MontFp!("4056073609856865355474760409362591947419627701585441557971089737095781181218"),
+            MontFp!("9773002359623017569353472248098935145737781181679591906108111558414350781157"),
+        ],
+        [
+            MontFp!(
+                "16512754032607564610675471138903230294130890138814822793886415502033153736759"
+            ),
+            MontFp!(
+                "18627016696759865514829897024490129061118689169034571968572462678839951177043"
+            ),
+            MontFp!("3112348107370301894706418184835707093010032916829351806207061365318331797108"),
+            MontFp!("7064377249665734988148595324917312727514362768638147890362896014652541891770"),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "16416182153879456416684804308942956316411273300312025757773653139931856371713"
+            ),
+            MontFp!("8755297148735710088898562298102910035419345760166413737479281674630323398247"),
+            MontFp!(
+                "18240202393199396018538671454381062573790303667013361953081836822146507079681"
+            ),
+            MontFp!("3126891838834182174606629392179610726935480628630862049099743455225115499374"),
+        ],
+        [
+            MontFp!("8755297148735710088898562298102910035419345760166413737479281674630323398247"),
+            MontFp!(
+                "18240202393199396018538671454381062573790303667013361953081836822146507079681"
+            ),
+            MontFp!("3126891838834182174606629392179610726935480628630862049099743455225115499374"),
+            MontFp!(
+                "19152212512859365819465605027100115702479818850364030050735928663253832433665"
+            ),
+        ],
+        [
+            MontFp!(
+                "18240202393199396018538671454381062573790303667013361953081836822146507079681"
+            ),
+            MontFp!("3126891838834182174606629392179610726935480628630862049099743455225115499374"),
+            MontFp!(
+                "19152212512859365819465605027100115702479818850364030050735928663253832433665"
+            ),
+            MontFp!(
+                "19456215886079355753107916218006466745376323911480919416620625943622940884993"
+            ),
+        ],
+        [
+            MontFp!("3126891838834182174606629392179610726935480628630862049099743455225115499374"),
+            MontFp!(
+                "19152212512859365819465605027100115702479818850364030050735928663253832433665"
+            ),
+            MontFp!(
+                "19456215886079355753107916218006466745376323911480919416620625943622940884993"
+            ),
+            MontFp!(
+                "15321770010287492655572484021680092561983855080291224040588742930603065946932"
+            ),
+        ],
+    ],
+};
@@ -0,0 +1,895 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-4 instance of Poseidon over [`Fr`].
+pub const RATE_4_PARAMS: FieldParameters<Fr, 5> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!("8598786632164628421211451620378536648622742002058751601487020070519777865137"),
+            MontFp!(
+                "18816410289425108348744704391196347624853841129456765019281535423981224246050"
+            ),
+            MontFp!(
+                "14662220377083068772070792727394423613132403467294031026314933191186750033625"
+            ),
+            MontFp!("1922620738626103530483564310372217341843412952699067846006292804813402862046"),
+            MontFp!(
+                "18125505943658057225416023911796129551711378234251990133228783064414682999007"
+            ),
+        ],
+        [
+            MontFp!(
+                "21667938765325976282111599428381436645287668251203183516770712453811458030497"
+            ),
+            MontFp!(
+                "12351570464122703405887148975135810333521018653953912278240737374268711625514"
+            ),
+            MontFp!(
+                "14192237527425899191744210240681463814285094261698903854496279538886598068742"
+            ),
+            MontFp!(
+                "14760251359710246541096194235311467562355650452700805381581182015739021645913"
+            ),
+            MontFp!(
+                "15522873692086034275695684295639823619149543568547676658418416596357058892973"
+            ),
+        ],
+        [
+            MontFp!(
+                "14615811426017725793005154412637188943209690364107877093205442452413224624456"
+            ),
+            MontFp!("4512033740911328975100011704395067832893746916084116380835940392918320558402"),
+            MontFp!(
+                "18095287592703470147492079949328781094888582122167269028387679707306924786469"
+            ),
+            MontFp!(
+                "19535236679628431138529998635383262724595004663554457219387588534311591720942"
+            ),
+            MontFp!("2616745712458294843219355922525728600101666797458136403695950140713767547985"),
+        ],
+        [
+            MontFp!(
+                "12070517756160589308069685650303672447270345965289258164954738466378525361715"
+            ),
+            MontFp!(
+                "10068996470562503742660150973219620299444968338674632428870274753829218313604"
+            ),
+            MontFp!(
+                "17264354659914997872111416617226413661049316717835833367814885756838492849232"
+            ),
+            MontFp!(
+                "18422262218524610525088535605531346741582379690423894766615951256957312696936"
+            ),
+            MontFp!(
+                "14463629723316181397239911962954372942297417992613711763000530225651876496995"
+            ),
+        ],
+        [
+            MontFp!(
+                "10539102988155932071726230119100619562733007967751884817308793257025977794749"
+            ),
+            MontFp!("8031385072009664183675810024027356921847415175659754616438146871770101541215"),
+            MontFp!("4531383281807232500842577862722093951496066648893692176450508280085879293528"),
+            MontFp!(
+                "10978425411089752128568876103462602197115346767251595909422596331928416910120"
+            ),
+            MontFp!(
+                "12408724184262338738886764350690843845106430762067943581995069066679130171112"
+            ),
+        ],
+        [
+            MontFp!("707299673394049776649525828278314785393390695246183305870445451789307132233"),
+            MontFp!("1916748597261053639361200445628917740306907461832379728700634324087300053417"),
+            MontFp!("4666588121602409843979088066192003822553560823723513259218432197014772872153"),
+            MontFp!("1026188522176634376608758961736204269692970573025103057721048554999423966188"),
+            MontFp!("1606771623117413799787566414215324950512188622492249559796191619407609222364"),
+        ],
+        [
+            MontFp!(
+                "17551378745170181720900776940539163556615466348005671392823499036769323990607"
+            ),
+            MontFp!(
+                "18541060186122982274709186487065407782238972925464420753910373495675936221624"
+            ),
+            MontFp!(
+                "11001867365254668374499122371984249935822245347653348115412308486314881248538"
+            ),
+            MontFp!("6977503962572468485144047718367498394804250457648398616048940372607374662645"),
+            MontFp!("7152849086766406394817506711929802969869428855824383308904936376788787524605"),
+        ],
+        [
+            MontFp!("4525995536522164850308947577610994838925822160090943737459830551880528335092"),
+            MontFp!(
+                "15036314673706864144301416858945409375467438912773677100863616422863810866721"
+            ),
+            MontFp!(
+                "18532522675651017608430042972942018341946110085794667964915235619410975907103"
+            ),
+            MontFp!(
+                "15386605578801357874297185527828511199715089824353162325185222365215807258493"
+            ),
+            MontFp!("869838865335373735789078070262927704083405347366655751189377432509331683882"),
+        ],
+        [
+            MontFp!("1298543665749531914066797303049160679363665890308265693537964913488947019795"),
+            MontFp!(
+                "14255598692137896614133941686520302565877754533594113715034878048162623355049"
+            ),
+            MontFp!(
+                "10340886913891600031525541022432486803743988627356837331253403437449541560962"
+            ),
+            MontFp!("1079967250671531017495416462892288194334842053711272839548631057730681587160"),
+            MontFp!(
+                "18011859701710527853370203379923710153979912319399376919345487551204977347909"
+            ),
+        ],
+        [
+            MontFp!(
+                "19749055133524666463044708587690139759155344578663042041928856363979967422705"
+            ),
+            MontFp!(
+                "13176952717435497844827767097227972984514223074939422628683782050519281167751"
+            ),
+            MontFp!("3359156141254861891389180345967041804882904031269632882837923539556234198247"),
+            MontFp!(
+                "10989231205417105674482697073702412800929326508021243096744962611079446022963"
+            ),
+            MontFp!("2133438334139769006637923484569644351377661092143578329426637428637557194542"),
+        ],
+        [
+            MontFp!(
+                "20406869188256560532545651140096761655308683829962886665983287704810073781782"
+            ),
+            MontFp!("8990031979668620761882700979367727909271191409517061233607820035688936256645"),
+            MontFp!(
+                "15882653237938797207157243948664628757292026278664946783571344981558198974381"
+            ),
+            MontFp!("3619874971126647353914838696387707457745140886725730674009867244308078673578"),
+            MontFp!(
+                "19004376870084374846064312295352395877235288942627259125444151515107416529329"
+            ),
+        ],
+        [
+            MontFp!("1659497621303313737462114299677721702626206479206196610918059332153256780534"),
+            MontFp!(
+                "11256187893829133220936124792930012578250928208620478072088774558408099067990"
+            ),
+            MontFp!("5566467911950483522905770643185386976292339081144699047166865983678949703275"),
+            MontFp!("6496483995104244295772836479423976479499115404816652017746564787373945608315"),
+            MontFp!("678657374344083064865177173668067482905512509595315996115878216256600179339"),
+        ],
+        [
+            MontFp!(
+                "19067841193940319098556976258074193449926606986817262321957484703214691470602"
+            ),
+            MontFp!(
+                "14675546672893114524842152434123587794661156520660462320839476683330050716589"
+            ),
+            MontFp!("4375724570791740314892458549663394177557420933200832926783661941398166824126"),
+            MontFp!(
+                "19118139928106450265805552799670148855882196692683750411967405221852039306360"
+            ),
+            MontFp!(
+                "19952515115153594506496871625396960728795864204916485575979838796246045886138"
+            ),
+        ],
+        [
+            MontFp!(
+                "21204836912260997261713497587067713961400900377085936520078803317336330046895"
+            ),
+            MontFp!(
+                "12679053226597024667776629310354383778463881895734212289815134455400296528913"
+            ),
+            MontFp!(
+                "14146484595025116162909727694363030749365638695729352881185012122243277038643"
+            ),
+            MontFp!(
+                "12478616021577903690109603139964135985029367612379322467260808367577555930171"
+            ),
+            MontFp!("6758759744372138408924018686696131751376632359857336105608095784400824072339"),
+        ],
+        [
+            MontFp!(
+                "17443313435400855812163078232091519172355662114876082339270970858297618439487"
+            ),
+            MontFp!("4104737864122723632016317052237003597582157001188838546282726314670524497147"),
+            MontFp!(
+                "20923976044150085781847766733128288387465192155866189403983551517470545146355"
+            ),
+            MontFp!(
+                "20699851284647403448276469629553887894983999929411267059790193795961586675990"
+            ),
+            MontFp!("2710086277321867337676430613887861410411809777241678291258823957961276776592"),
+        ],
+        [
+            MontFp!(
+                "10178072673113866048244163893475496833747768336347788655156867074411961306043"
+            ),
+            MontFp!(
+                "18986200311221963951920469853758418152861411374633993772419704254398775686616"
+            ),
+            MontFp!(
+                "10648425369800101366756496076701661663728111590838070593456576864203809822568"
+            ),
+            MontFp!(
+                "21083052704583476899973093328055964282913610641889751393773184901638143815847"
+            ),
+            MontFp!(
+                "19195369100635697276530287816081033419124466383035722175739366149396976429120"
+            ),
+        ],
+        [
+            MontFp!("3018108283050928719616136611102373693349609593257676534892515332935420246901"),
+            MontFp!("4137322756219195676210575198343991091425129177518697490599546597486915324810"),
+            MontFp!("6968873779969789090632231888360141423066177656886580090020542131389995278147"),
+            MontFp!(
+                "13556077607817782517063976397709248181353234307085453394012922372068316360676"
+            ),
+            MontFp!(
+                "16971652136547727033487176507228504705234917338322907128837361075629280989096"
+            ),
+        ],
+        [
+            MontFp!("9093170636202391441121709024862797876476284578228833937961846261131414568195"),
+            MontFp!("992971786155154421799960895517699439421661636568638252094004896766758114511"),
+            MontFp!("3016904460998386296386798553609353350201111392879398355829571947946431964088"),
+            MontFp!("5878563016308021028470613928536831149396949004706022654558981363353091769937"),
+            MontFp!(
+                "21883973200752510782476241026054653419868826447093827227526090032653503880820"
+            ),
+        ],
+        [
+            MontFp!(
+                "12799268326402942647564785467537008395125044454915805470338658521876629713847"
+            ),
+            MontFp!("6192449206528699140311820464141851236200510732865553967032656503255500720579"),
+            MontFp!(
+                "12031257962968340817795020523499171137701676309594257892661960322599525417578"
+            ),
+            MontFp!("8601027687136103328914118627336210226547786783516160461925648426105631114888"),
+            MontFp!("6575334302232265379682403637410316779108131372770126005485198821489330800275"),
+        ],
+        [
+            MontFp!(
+                "14314884633942800878336786957171282773748946421487467162068581408404813101718"
+            ),
+            MontFp!("8723478833154654625187087834650534491678411707902496046185836457490667161709"),
+            MontFp!("6735262834509845805568378119699906079526151105177718507296540141906176458334"),
+            MontFp!("5612324802679514351769568605127332368552019748101202922019276641450851076434"),
+            MontFp!("3717926995626642754395559230921719540173698789414234375048574158210658067763"),
+        ],
+        [
+            MontFp!(
+                "15599120490107267801470449267172072986213604527387917014837057463443372520938"
+            ),
+            MontFp!("1386395923726852792011021530880930176184116936024491925547649516614992181438"),
+            MontFp!("6538044683356865872451397762994295701307540561441231836123866276916278537305"),
+            MontFp!("3019820100972112574720523244125324573590114632803023588785989938408295554456"),
+            MontFp!("4675650619724356762458500625431620337466472804336297985978795890881828210040"),
+        ],
+        [
+            MontFp!(
+                "11263482342815801408604026172311086168256426262237655705583885451660697089071"
+            ),
+            MontFp!(
+                "18749791545908034976458706910783512669552007602491548571321017424342871558525"
+            ),
+            MontFp!(
+                "10944813243599773703269832871781682276279098806460809909109213001669590018689"
+            ),
+            MontFp!("2586690534834169784287726935549921466616370080021050078163939031341284344726"),
+            MontFp!("2171230182537683344587773995458340159674499454366750433278965561971995318519"),
+        ],
+        [
+            MontFp!(
+                "10307969394268991138667845869342250966575625117933778059261550677270884158008"
+            ),
+            MontFp!(
+                "19559177136671582058155526509576791224009291924313767942457079610037379754301"
+            ),
+            MontFp!(
+                "21520344182562975130064319362513092855165337181059199877754790545586913744258"
+            ),
+            MontFp!("8849405405397290084167116387035998999991111125772333232341292368671025829953"),
+            MontFp!(
+                "12430377622401976507069735865832237574532162408907844532712186771510966142277"
+            ),
+        ],
+        [
+            MontFp!(
+                "14529460361642030011015537548836716741983148192371887162429920540839044217410"
+            ),
+            MontFp!(
+                "17235413149417146442388071440945387684558506620203984218700569768787990877127"
+            ),
+            MontFp!(
+                "19273566330201992597305691708906294451193761727389830851627507594570703426011"
+            ),
+            MontFp!(
+                "11418689560469199630812786848708912246674568775896035578862466720737082911417"
+            ),
+            MontFp!(
+                "13663224273550812935019098790986204314535918775563683447237545705035009118030"
+            ),
+        ],
+        [
+            MontFp!(
+                "15733605312714987887133695995197323438942114141141352693262021584518912540161"
+            ),
+            MontFp!(
+                "15430926040626593324517344719767379958111035840728200011661832062800204682408"
+            ),
+            MontFp!(
+                "10308806019711464018362516361222725036316621595371155453692718080579842841466"
+            ),
+            MontFp!(
+                "11395562143898290361875020206101882937854563964440597132292327034386286575551"
+            ),
+            MontFp!("5444369917730609419963300018263391095411160241287745862954204012609329126466"),
+        ],
+        [
+            MontFp!(
+                "18047989797181156549018603007073055575075671143032794115317152785370368049578"
+            ),
+            MontFp!("4188020575633502096835595948048488332855974168518837385598196478103946331936"),
+            MontFp!(
+                "17969692185669130721863274632415514637662238747742191481764236583900299896656"
+            ),
+            MontFp!(
+                "12660223934202747996388982126966728467648934667740288178036035133700626666155"
+            ),
+            MontFp!("7079977454776280545989624009542211585513638465339584048679449131088546439582"),
+        ],
+        [
+            MontFp!(
+                "19351927933208880207496217496300431481469039468231869121368890939997950053210"
+            ),
+            MontFp!(
+                "16264878967338582718431583167593636825093292059150766937522872856825122464794"
+            ),
+            MontFp!("4885855566256511237793524065491361814358891564606397226200319865424296012897"),
+            MontFp!(
+                "12765502117237287370931842273960073081683946760150516451080381971185747155731"
+            ),
+            MontFp!("3066830902780584599011399285971330195749041774205188883508190527282994506679"),
+        ],
+        [
+            MontFp!("9295469581684507574399395092397130092585121722987032875354264734459773363995"),
+            MontFp!("334353094113495187066778075208341151744100811864935299861007334497663570804"),
+            MontFp!(
+                "12073396326626754085724777473620983026964961065824879544293908404528275023882"
+            ),
+            MontFp!("9617786824615324687621951687790190954239603934607648481350536440082926967947"),
+            MontFp!("4040667197024401802661787259347967321615369438786577315631997663883248334116"),
+        ],
+        [
+            MontFp!("1265154413505398467786394840247750978797974974654625706254356090613862556612"),
+            MontFp!("4934198340170991535549627007589840644229580422186163935275034037629786994242"),
+            MontFp!(
+                "15938049935176892465821402757136458857700375394249581689798844302181307340646"
+            ),
+            MontFp!(
+                "13026614052175258908407397876605181673140434204450751899728748000073003933019"
+            ),
+            MontFp!(
+                "15186409427725306048619445509100864729273879973069901426255451535193075791735"
+            ),
+        ],
+        [
+            MontFp!(
+                "11691652879230495966412520700398948280893013771621920409063661349858099047444"
+            ),
+            MontFp!("1631855987634340631402312121769529437138328278924508867450087271840843403409"),
+            MontFp!(
+                "17165946087758221343371276516200442928594589875421074884683762106537651470547"
+            ),
+            MontFp!(
+                "13510555978290961609572953344280776869863392170284865090846616958951401062429"
+            ),
+            MontFp!(
+                "11824526160974448991879892255112179998667684998720033986158392997658894380092"
+            ),
+        ],
+        [
+            MontFp!("9521490651080852539066303225346749087773622151079626072933758234320693405489"),
+            MontFp!(
+                "16455187722407504553512226051302020507982467297743402007643991930852319332830"
+            ),
+            MontFp!("5742682151710442965534315112604998536003159536349778848657933122726171407941"),
+            MontFp!(
+                "19701217633963028849863105710125971502443206571992952176576643074611718536857"
+            ),
+            MontFp!("5974109471361749141431104987628821247386819131841287715345276215429863136108"),
+        ],
+        [
+            MontFp!("9215984158693902793028255018473077413553435783514801204376778169946608911045"),
+            MontFp!(
+                "18817186437032627582271863757014874482734423390895394665410385725057214147472"
+            ),
+            MontFp!("1866962200362691258700139267816340698090339502741430358256824640844861783310"),
+            MontFp!("9638332100128203624588175000271729199286819655418516578892443272624598100281"),
+            MontFp!("2144646278993203964295549845000228787432893290796872504520585739476676771850"),
+        ],
+        [
+            MontFp!(
+                "13768953020389374239533109837571366480371968805492699357082924116907982966873"
+            ),
+            MontFp!("2928416518427415468207505334435366743880899670445356195545471822685306495024"),
+            MontFp!(
+                "14092525701623685950441143107968894338496424717342222885846637023317088578264"
+            ),
+            MontFp!(
+                "12066892076928389207242528794405859790973616131556411228582628103996760315722"
+            ),
+            MontFp!(
+                "10538880299510097919772652331271532653974042073781971862375419768000925471372"
+            ),
+        ],
+        [
+            MontFp!("6198586845653382454490419371822337804084509619166956184419558755283805736142"),
+            MontFp!("3023265532598729376821936713331859933483380841738372877526408159960044746105"),
+            MontFp!(
+                "15157782783255423354715795718540062819408021538466352887917352862428785545532"
+            ),
+            MontFp!(
+                "19778607554342597617919549994103945838465384136377534899724506482574813805643"
+            ),
+            MontFp!(
+                "13247668020418935473776061724210325794948290938573458912234077634414021030953"
+            ),
+        ],
+        [
+            MontFp!(
+                "14109528056208126024236191300636900072738679852951755733906119040463567098518"
+            ),
+            MontFp!("6991161445985296100133732536180090445737286940847655685462625337035697848821"),
+            MontFp!("152052171776248509704686253482007013473630920276384957624884474568238342334"),
+            MontFp!(
+                "16268605508222757576723988371619204407086716579784246164590829801261044029691"
+            ),
+            MontFp!("848835780422277601707730881451660539053207410526420719742093901863373292824"),
+        ],
+        [
+            MontFp!(
+                "12945868370568960855788148015736332595570568227569460645256141548118757326330"
+            ),
+            MontFp!(
+                "16316302504490815943708822557021305044392464642254098775298611521582036969002"
+            ),
+            MontFp!(
+                "10390225667040937881788074400727322886329228309406795810415443903377920575575"
+            ),
+            MontFp!(
+                "20899167149846019862851283725004350868550678800324697251002180344155154573298"
+            ),
+            MontFp!("5501491130673209547979703336016636137962555564762866652285068634202349201958"),
+        ],
+        [
+            MontFp!(
+                "21584240055791709605691336751200542525424239543286490095858064758936243344131"
+            ),
+            MontFp!(
+                "11170577007723576514758739996049768302566303615259760403522445108271794551663"
+            ),
+            MontFp!(
+                "21179087581213421402249212070877650137493750952522816731635420700896642524036"
+            ),
+            MontFp!(
+                "19305964405180133661346069606971659736146500224756367973236566622112603409601"
+            ),
+            MontFp!(
+                "13437535817178573572626629716991358389697345057137663410303405407378666527490"
+            ),
+        ],
+        [
+            MontFp!(
+                "17040481547164873436337138071439435313357665416826305938445739587363236973629"
+            ),
+            MontFp!(
+                "11343692592165184113381612931291618299160327811822012703980150669262163200572"
+            ),
+            MontFp!(
+                "20832456596175623157653459610472101653892987513654527794129743846652085502232"
+            ),
+            MontFp!("8068357146698294038166447199685964538426826574998071692110735472772947603318"),
+            MontFp!("9410014195245078597374021652148083789127058040071473817490180034528557528387"),
+        ],
+        [
+            MontFp!("5347377483799084957230203525710834967698951473015358483614480363163348613033"),
+            MontFp!(
+                "17634177507115287739307624097408633258433310251457919066106334693723520126028"
+            ),
+            MontFp!("8556518589196831030406008190590821584160922533984647524529435749501742188212"),
+            MontFp!(
+                "17934932744754988575142079880067387063710259192412879799435114348698879073006"
+            ),
+            MontFp!(
+                "13619232348875797544057314514281963048077254331227331948178032434510708860557"
+            ),
+        ],
+        [
+            MontFp!("9776428626888758629654429516803939615737682043450642366132819167230180812724"),
+            MontFp!(
+                "19078872245880190108838378601769993766362252386711627640751623953233412860121"
+            ),
+            MontFp!(
+                "10570537844204732246569553240100215734595764574132104673549516989827421898917"
+            ),
+            MontFp!(
+                "18664493618286599579761831194784141036740741302204081527298726268226130752309"
+            ),
+            MontFp!("3526586433204920225908283732700163933464317125758859305654576432147188360181"),
+        ],
+        [
+            MontFp!("9349766889645484961346087396330362694637546172007729148872203059369866289953"),
+            MontFp!("7841838753336026369956237950907407106037680093942404186364912253672424948909"),
+            MontFp!(
+                "18828035206692850796983260459259799006628381632485259804680705977973884091123"
+            ),
+            MontFp!("8924391991421013649756662262490987204952112527760103898113759462684939109569"),
+            MontFp!("9010685041655975863898977941339139128215410470912627634172415403255176847059"),
+        ],
+        [
+            MontFp!(
+                "13090235740543265756433057218450227939381774166774897550820226237833191452168"
+            ),
+            MontFp!(
+                "17054188041770863344596067716751040111656854045383635044293489087097193170099"
+            ),
+            MontFp!(
+                "18118465586704616411086730694923071735399389501916075261279764709030399251451"
+            ),
+            MontFp!(
+                "17656238134454935524708982854081632221140357905140023546569692991496342117652"
+            ),
+            MontFp!("7146981540731086297675846397388819193873924395855683178747545415562492151778"),
+        ],
+        [
+            MontFp!(
+                "14134322731311739748723508431553587999504231398016732302338676692324552583628"
+            ),
+            MontFp!("5159253799661657292369717160265679839084047177650725609350208197050145371645"),
+            MontFp!(
+                "15642104843167241021031387660397023261037688660029011794831593026929739103971"
+            ),
+            MontFp!("830428822713308836796182634913442002269682241735518032752012876808621909263"),
+            MontFp!("1998152302482992984111898483560064339399698928942639138284602251521997825977"),
+        ],
+        [
+            MontFp!(
+                "15898763199858098568691431394419918574861961971507542111143492705812390541917"
+            ),
+            MontFp!(
+                "19816356197194993892845792447534142901799224993371934867752810831532875639530"
+            ),
+            MontFp!(
+                "21411039331311309332490627966455736865184066829036766284773318827318767380717"
+            ),
+            MontFp!("5722372744909557906649571682993107048069723405581477083514151819251571887014"),
+            MontFp!(
+                "18677128191874623908772938494093957450204505096754323802736478532039600874667"
+            ),
+        ],
+        [
+            MontFp!("387439319788392029780345615795272369735672613573734572334540549245203505477"),
+            MontFp!("921581170226558595642303117001650473134593494138422085001106022725415870297"),
+            MontFp!(
+                "13385032304735329239531079386026778414180291425202963083230185187061972409690"
+            ),
+            MontFp!("1434255012016787207308355008245519026646102502334714916286740231384533827149"),
+            MontFp!(
+                "16929118988335993565822729017922050073081154920590035178260367149352089219380"
+            ),
+        ],
+        [
+            MontFp!("6039050069857583662671745565476994149450288068968738103401467749314645266197"),
+            MontFp!(
+                "20721971773095807565124673082725041033873077329266241188160065902190372159118"
+            ),
+            MontFp!(
+                "17634731339306339970096828331929471709696292788418580130899744185434435814794"
+            ),
+            MontFp!(
+                "16883188173911898621126962641442052218809713647943632810915757395850791705815"
+            ),
+            MontFp!(
+                "10508270296657120245580840557889251564421795712399450856469152827573051185567"
+            ),
+        ],
+        [
+            MontFp!(
+                "16257551788554089205568505236191197008540889176411769443644196029634317837897"
+            ),
+            MontFp!("328202797159938342211115045135454833158105031709853940465240404537545535203"),
+            MontFp!(
+                "21618814416448225644234080651074779864705767796605588058201268400139270741709"
+            ),
+            MontFp!(
+                "15977363783786306532870957624049702710767588229237633405250107933692143546235"
+            ),
+            MontFp!(
+                "19539599904956747486118076740734079630394163294635733217428699688562188632135"
+            ),
+        ],
+        [
+            MontFp!(
+                "18282201560529141207179999572373080606367575555819297892597039218972716258709"
+            ),
+            MontFp!("2450577673930967077806253397989105531259661084393548519564239807841743370518"),
+            MontFp!("7604698702972824474812890329933660859161741335767723955430127799287959361779"),
+            MontFp!("5374674023727758188648681291060909355948693809818760273065657301647651024586"),
+            MontFp!(
+                "15326628016369426477612115277234509589000085353682052094152955681847137878484"
+            ),
+        ],
+        [
+            MontFp!("4024781330651271864785050855131749250869673831676939890257254661650587408168"),
+            MontFp!(
+                "11662869625945351972806217744010044509417904913438407460128715670712542741525"
+            ),
+            MontFp!(
+                "10995552417713174435772385081824643088293154994005040006039044075524857067416"
+            ),
+            MontFp!("6987218854720030881498384006760752396093254577693866796269595796247398931897"),
+            MontFp!(
+                "12973936548417491718818049306328999557038729616100211605543285185918379443933"
+            ),
+        ],
+        [
+            MontFp!("1522890564826500579590759751671821445979367996019789420029493699340349161388"),
+            MontFp!(
+                "20172453381006993687041475585476350126266484538865101688497394770608861407851"
+            ),
+            MontFp!("9352402703789183776246499681697223602658170660939618034822173467903311753555"),
+            MontFp!("1663989254995869517314780609025709168445959629105615744824989881828565951867"),
+            MontFp!("865703964216047966440453009631578558571626893712453342846680232302758880808"),
+        ],
+        [
+            MontFp!("1943511741250561045978887420681954886761747458282187358688287087567249513831"),
+            MontFp!("9567377331031219779464116154519457665327071786423915273102945622383363331118"),
+            MontFp!(
+                "12645230798018301309420727385654036236548087963074959835781576786441702386135"
+            ),
+            MontFp!("4661275209863095944730120031907499260478718263364850929910170592938492946802"),
+            MontFp!(
+                "11540884652920947262437060632191848287066869791004648754205016529218993505524"
+            ),
+        ],
+        [
+            MontFp!(
+                "21604842875201170732653934092364840644492595382499613256783886900815525787917"
+            ),
+            MontFp!("166646788984221744747123159251267757162011891662133965937362732395306614240"),
+            MontFp!("4501403450531960660831349445283633949298226537157186590711626951026651001613"),
+            MontFp!(
+                "13637237920190734605197679465575972373572356815828005415076724743476404447130"
+            ),
+            MontFp!("7409702775580632557041241300371968166455976305708314437207398512421569393942"),
+        ],
+        [
+            MontFp!(
+                "14470058286455518038604703763719635225171546825228913451016404757603607838402"
+            ),
+            MontFp!(
+                "10356961241279204742266297435376594463155877749116016561480317708103625793545"
+            ),
+            MontFp!("56137801124163733254662704476837399414165890796848579542919337103301488161"),
+            MontFp!(
+                "10781918639339372338412366087072754383141135251480022944533205370955386545233"
+            ),
+            MontFp!(
+                "12688965137609573179708768972218806529969230687397738324620751914007457112972"
+            ),
+        ],
+        [
+            MontFp!("2800552716106685014818629588968136938321037016527964039668062682988254893287"),
+            MontFp!("7021282451058125545866332712299985107654350710869227968541011670074229770284"),
+            MontFp!(
+                "19561774842651934295094293507989494576103842612928515105626078482350123086326"
+            ),
+            MontFp!("3317842325497950429207010201732699002718827965189221395621487794332600050995"),
+            MontFp!("6765728020052648691475822139911507493023416346713956996336236813873839145260"),
+        ],
+        [
+            MontFp!(
+                "20841833493719413290759280455340104045868891980538263708748575648650134866635"
+            ),
+            MontFp!("536905112518843720819274837056180107771214620479509006679088737001107772110"),
+            MontFp!(
+                "15955124467421242190498400121702619995209633263196026918394503400002418506744"
+            ),
+            MontFp!("2711908182888147643765417915372753478460965230260006467193731705212717876930"),
+            MontFp!(
+                "21863403440394087862210042451862801347993964749186771965577319641518763925510"
+            ),
+        ],
+        [
+            MontFp!(
+                "15448869854079102245139858696221414024646265508661222208641299207026411713974"
+            ),
+            MontFp!(
+                "17427390955450752640592667380300834696253688526549522594248490615310749245324"
+            ),
+            MontFp!("6711805678441384130309984179597533806209348255970611850938031573494009552434"),
+            MontFp!("7885902711087600539034422099098714566922516897760834614931711986387416741945"),
+            MontFp!("2566589889163543848365588286818925498677187822052589443262435247465155020253"),
+        ],
+        [
+            MontFp!(
+                "21344278117827079905931907012639339732900406354859987354428528808994683664252"
+            ),
+            MontFp!(
+                "10187314721853268966797450811383899653711881411297682740313285293820695841166"
+            ),
+            MontFp!("5247065116607583694930664239659463649894883945140244008486784016182560831094"),
+            MontFp!(
+                "16401154565207500320746275700209841129083732549613819020770516143419836129196"
+            ),
+            MontFp!("8302456653228409345673441821693234953306838353927939692084547685234824760405"),
+        ],
+        [
+            MontFp!("7346272335883983475643524278773047759897325596302391508187058007167449451375"),
+            MontFp!(
+                "13692388285782313151056241715700798509191230565917741754551519012937240978834"
+            ),
+            MontFp!(
+                "10046440703458713099030488202372351088283138278315810259131480802842891233447"
+            ),
+            MontFp!(
+                "21844477634808029824089227680903943997881138816104574229932726488487265746940"
+            ),
+            MontFp!("1797139481622560050267070777718580520350556497900722457717328913256175183193"),
+        ],
+        [
+            MontFp!(
+                "20632419596788689389037200796300202273773372978060673711027930581453076466300"
+            ),
+            MontFp!("5926673641520353094284220299699222202438432969572579666286824341976240793225"),
+            MontFp!(
+                "19816571886884611625670766405545136334366870302936420094731524023344739478022"
+            ),
+            MontFp!("7098809554006376741545495027197419783892258254109351784281223539534495864411"),
+            MontFp!("5993978498547737544861536429524509974127011290013446853819510917330732445536"),
+        ],
+        [
+            MontFp!("3651884220682200188434499718287355594691258022493239286902076607507767217545"),
+            MontFp!("7701031809654098198485752371929362223478301296630536519007951703000860856331"),
+            MontFp!(
+                "10746456244704973517659602111830498819196260373394897640057832538660050070821"
+            ),
+            MontFp!(
+                "15976809296878055269624478984609339133022473826839586348607509738062559768432"
+            ),
+            MontFp!("8489278156644961764910559800650499256846930137330429169797443396862077830699"),
+        ],
+        [
+            MontFp!(
+                "21325436661344885129404810295796247843237757214005879707139610695358904316824"
+            ),
+            MontFp!("8097340509617839249694630209789790542307271541434810275431206554229846090915"),
+            MontFp!(
+                "14814034068762867373521846263918589712551006954943866393903075553895551995121"
+            ),
+            MontFp!(
+                "12983718912937593966936224031564757925741067436861940800928455479497588997301"
+            ),
+            MontFp!("5842708670483998577973454268320147122920860343832943164813167606696485374988"),
+        ],
+        [
+            MontFp!("8905717278519742475185856664433818126395806585354358506532344824083042437275"),
+            MontFp!(
+                "11276356379269402405637335215129490975900508368411164098190366694890296997065"
+            ),
+            MontFp!("1896062319949048337113800892167236140763024398277936385378583249790337072956"),
+            MontFp!("8961851056721830835887748932502838647123303743597936342034803298169191794796"),
+            MontFp!(
+                "20512263169612271537541788303170482904879362686083538117355311056881859648696"
+            ),
+        ],
+        [
+            MontFp!(
+                "20355533441732334739531277647314457275398994182217446762704382473042966550850"
+            ),
+            MontFp!("2950304409268326634854262121946579792709385508134232658550227161756196380376"),
+            MontFp!("9416016478278004931597292558588322494934305504896363497395512600132830148377"),
+            MontFp!(
+                "17799879685095943374764503770035939441690511803371664014825462730022069557299"
+            ),
+            MontFp!("9536094335212655823715379468337038676131042453824854479475698509737174283340"),
+        ],
+        [
+            MontFp!(
+                "14292774683868329298552699294475321809174910281919362992291352478798142692671"
+            ),
+            MontFp!(
+                "21166647504099339752506011060935790320129441393855231757832059333094674583633"
+            ),
+            MontFp!(
+                "13385174119545667656275667288215234059683122780003489987000055680934942195299"
+            ),
+            MontFp!("7854778404638308070903456020568138577856488959599374505379533264072649740388"),
+            MontFp!(
+                "13836995965224818360123212256428086746150854656270376404502980885087601922007"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!("8755297148735710088898562298102910035419345760166413737479281674630323398247"),
+            MontFp!(
+                "18240202393199396018538671454381062573790303667013361953081836822146507079681"
+            ),
+            MontFp!("3126891838834182174606629392179610726935480628630862049099743455225115499374"),
+            MontFp!(
+                "19152212512859365819465605027100115702479818850364030050735928663253832433665"
+            ),
+            MontFp!(
+                "19456215886079355753107916218006466745376323911480919416620625943622940884993"
+            ),
+        ],
+        [
+            MontFp!(
+                "18240202393199396018538671454381062573790303667013361953081836822146507079681"
+            ),
+            MontFp!("3126891838834182174606629392179610726935480628630862049099743455225115499374"),
+            MontFp!(
+                "19152212512859365819465605027100115702479818850364030050735928663253832433665"
+            ),
+            MontFp!(
+                "19456215886079355753107916218006466745376323911480919416620625943622940884993"
+            ),
+            MontFp!(
+                "15321770010287492655572484021680092561983855080291224040588742930603065946932"
+            ),
+        ],
+        [
+            MontFp!("3126891838834182174606629392179610726935480628630862049099743455225115499374"),
+            MontFp!(
+                "19152212512859365819465605027100115702479818850364030050735928663253832433665"
+            ),
+            MontFp!(
+                "19456215886079355753107916218006466745376323911480919416620625943622940884993"
+            ),
+            MontFp!(
+                "15321770010287492655572484021680092561983855080291224040588742930603065946932"
+            ),
+            MontFp!("7959361044305190989907783907366281850381223418333103397708437886027566725679"),
+        ],
+        [
+            MontFp!(
+                "19152212512859365819465605027100115702479818850364030050735928663253832433665"
+            ),
+            MontFp!(
+                "19456215886079355753107916218006466745376323911480919416620625943622940884993"
+            ),
+            MontFp!(
+                "15321770010287492655572484021680092561983855080291224040588742930603065946932"
+            ),
+            MontFp!("7959361044305190989907783907366281850381223418333103397708437886027566725679"),
+            MontFp!(
+                "20064222632519335620392538599819168831169334033714698148390020504361157787649"
+            ),
+        ],
+        [
+            MontFp!(
+                "19456215886079355753107916218006466745376323911480919416620625943622940884993"
+            ),
+            MontFp!(
+                "15321770010287492655572484021680092561983855080291224040588742930603065946932"
+            ),
+            MontFp!("7959361044305190989907783907366281850381223418333103397708437886027566725679"),
+            MontFp!(
+                "20064222632519335620392538599819168831169334033714698148390020504361157787649"
+            ),
+            MontFp!(
+                "20204531881697792512842836072545177004813874831153262471106034633762284765185"
+            ),
+        ],
+    ],
+};
@@ -0,0 +1,204 @@
+//! A hiding commitment scheme over [`Fq`] elements.
+//!
+//! A commitment to a `message` under a `domain_separator` with a `blinding` factor
+//! is [`hash_varlen`](crate::hash_varlen) of the `blinding` followed by the
+//! `message`, where the domain separator in the capacity is [`hash_1`] of the
+//! `domain_separator` under a domain separator reserved for commitments, so
+//! commitments never collide with plain hashes under the same domain separator.
+//!
+//! **Binding.** Opening a commitment to two different `(blinding, message)` pairs,
+//! including messages of different lengths, is a collision of the sponge under the
+//! same domain separator, since the sponge padding is injective. Binding is
+//! therefore computational, and relies on the collision resistance of Poseidon.
+//!
+//! **Hiding.** The `blinding` factor is absorbed in the same block as the start of
+//! the message. Modeling the permutation as a random permutation, if the `blinding`
+//! factor is sampled uniformly at random and kept secret, the commitment is
+//! indistinguishable from a uniformly random element for any `message`. Hiding is
+//! therefore computational, and fails completely if a blinding factor is reused or
+//! guessable.
+//!
+//! With the `r1cs` feature, `r1cs::commit` and `r1cs::verify_opening` are the
+//! in-circuit counterparts of [`commit`] and [`verify_opening`].
+
+use crate::{hash_1, sponge::Sponge, Fq};
+
+/// Domain separator used to derive the domain separators of commitments,
+/// `from_le_bytes_mod_order(b"poseidon377.commit")`.
+const COMMITMENT_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    11327898541499863444,
+    10771850159030388265,
+    16775391188876038313,
+    134879886734399332,
+]);
+
+/// A commitment to a message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Commitment(pub Fq);
+
+impl From<Commitment> for Fq {
+    fn from(commitment: Commitment) -> Self {
+        commitment.0
+    }
+}
+
+/// Commit to `message` with the provided `domain_separator` and `blinding` factor.
+///
+/// The `blinding` factor must be sampled uniformly at random for each commitment.
+pub fn commit(domain_separator: &Fq, blinding: Fq, message: &[Fq]) -> Commitment {
+    let mut sponge = Sponge::new(&hash_1(&COMMITMENT_DOMAIN_SEP, *domain_separator));
+    sponge.absorb(&[blinding]);
+    sponge.absorb(message);
+    Commitment(sponge.finalize())
+}
+
+/// Check that `commitment` opens to `message` with the provided `domain_separator`
+/// and `blinding` factor.
+pub fn verify_opening(
+    domain_separator: &Fq,
+    commitment: &Commitment,
+    blinding: Fq,
+    message: &[Fq],
+) -> bool {
+    commit(domain_separator, blinding, message) == *commitment
+}
+
+#[cfg(feature = "r1cs")]
+pub mod r1cs {
+    //! The in-circuit counterparts of [`commit`](super::commit) and
+    //! [`verify_opening`](super::verify_opening).
+
+    use ark_r1cs_std::{boolean::Boolean, eq::EqGadget};
+    use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+    use ark_std::vec::Vec;
+    use decaf377::r1cs::FqVar;
+
+    use super::COMMITMENT_DOMAIN_SEP;
+    use crate::Fq;
+
+    /// Commit to `message` with the provided `domain_separator` and `blinding` factor.
+    pub fn commit(
+        cs: ConstraintSystemRef<Fq>,
+        domain_separator: &FqVar,
+        blinding: FqVar,
+        message: &[FqVar],
+    ) -> Result<FqVar, SynthesisError> {
+        let commitment_domain_sep = crate::r1cs::hash_1(
+            cs.clone(),
+            &FqVar::Constant(COMMITMENT_DOMAIN_SEP),
+            domain_separator.clone(),
+        )?;
+        let mut input = Vec::with_capacity(1 + message.len());
+        input.push(blinding);
+        input.extend_from_slice(message);
+        crate::r1cs::hash_varlen(cs, &commitment_domain_sep, &input)
+    }
+
+    /// Check that `commitment` opens to `message` with the provided `domain_separator`
+    /// and `blinding` factor.
+    ///
+    /// Use [`EqGadget::enforce_equal`] on the result of [`commit`] instead to
+    /// require a valid opening.
+    pub fn verify_opening(
+        cs: ConstraintSystemRef<Fq>,
+        domain_separator: &FqVar,
+        commitment: &FqVar,
+        blinding: FqVar,
+        message: &[FqVar],
+    ) -> Result<Boolean<Fq>, SynthesisError> {
+        commit(cs, domain_separator, blinding, message)?.is_eq(commitment)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hash_varlen;
+
+    #[test]
+    fn commitment_opening() {
+        assert_eq!(
+            COMMITMENT_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.commit")
+        );
+
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let blinding = Fq::from(7u64);
+        let message = [Fq::from(1u64), Fq::from(2u64)];
+        let commitment = commit(&domain_sep, blinding, &message);
+
+        assert_eq!(
+            commitment.0,
+            hash_varlen(
+                &hash_1(&COMMITMENT_DOMAIN_SEP, domain_sep),
+                &[blinding, message[0], message[1]]
+            )
+        );
+        assert_ne!(
+            commitment.0,
+            hash_varlen(&domain_sep, &[blinding, message[0], message[1]])
+        );
+
+        assert!(verify_opening(&domain_sep, &commitment, blinding, &message));
+        assert!(!verify_opening(
+            &domain_sep,
+            &commitment,
+            Fq::from(8u64),
+            &message
+        ));
+        assert!(!verify_opening(
+            &domain_sep,
+            &commitment,
+            blinding,
+            &message[..1]
+        ));
+        assert!(!verify_opening(
+            &Fq::from(0u64),
+            &commitment,
+            blinding,
+            &message
+        ));
+    }
+
+    #[cfg(feature = "r1cs")]
+    #[test]
+    fn commitment_opening_gadget() {
+        use ark_r1cs_std::{alloc::AllocVar, R1CSVar};
+        use ark_relations::r1cs::ConstraintSystem;
+        use decaf377::r1cs::FqVar;
+
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let blinding = Fq::from(7u64);
+        let message = [Fq::from(1u64), Fq::from(2u64)];
+        let commitment = commit(&domain_sep, blinding, &message);
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let domain_sep_var = FqVar::new_constant(cs.clone(), domain_sep).unwrap();
+        let commitment_var = FqVar::new_input(cs.clone(), || Ok(commitment.0)).unwrap();
+        let blinding_var = FqVar::new_witness(cs.clone(), || Ok(blinding)).unwrap();
+        let message_vars: Vec<FqVar> = message
+            .iter()
+            .map(|x| FqVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+            .collect();
+
+        let valid = r1cs::verify_opening(
+            cs.clone(),
+            &domain_sep_var,
+            &commitment_var,
+            blinding_var.clone(),
+            &message_vars,
+        )
+        .unwrap();
+        assert!(valid.value().unwrap());
+        let invalid = r1cs::verify_opening(
+            cs.clone(),
+            &domain_sep_var,
+            &commitment_var,
+            blinding_var,
+            &message_vars[..1],
+        )
+        .unwrap();
+        assert!(!invalid.value().unwrap());
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
@@ -0,0 +1,409 @@
+//! Poseidon hashing in `const` contexts.
+//!
+//! The arithmetic of [`Fq`] is not available in `const fn`s, so protocol constants
+//! such as the empty nodes of a Merkle tree would otherwise have to be computed at
+//! runtime. [`ConstFq`] is an element of [`Fq`] in Montgomery form with `const`
+//! addition and multiplication, and [`const_hash_1`] and [`const_hash_2`] evaluate
+//! the unoptimized permutation of the rate-1 and rate-2 instances with it, giving the
+//! same outputs as [`hash_1`](crate::hash_1) and [`hash_2`](crate::hash_2):
+//!
+//! ```
+//! use poseidon377::{const_hash_2, hash_2, ConstFq, Fq};
+//!
+//! const DOMAIN_SEP: ConstFq = ConstFq::from_u64(7);
+//! const EMPTY_1: ConstFq = const_hash_2(&DOMAIN_SEP, (ConstFq::ZERO, ConstFq::ZERO));
+//! const EMPTY_2: ConstFq = const_hash_2(&DOMAIN_SEP, (EMPTY_1, EMPTY_1));
+//!
+//! let empty_1 = hash_2(&Fq::from(7u64), (Fq::from(0u64), Fq::from(0u64)));
+//! assert_eq!(EMPTY_1.to_fq(), empty_1);
+//! assert_eq!(EMPTY_2.to_fq(), hash_2(&Fq::from(7u64), (empty_1, empty_1)));
+//! ```
+//!
+//! Evaluating a permutation this way is much slower than with [`Instance`](crate::Instance),
+//! so these functions are meant for constants only. They are not constant time.
+
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use crate::Fq;
+
+#[cfg(feature = "rate-1")]
+mod rate_1;
+#[cfg(feature = "rate-2")]
+mod rate_2;
+
+/// The modulus of [`Fq`], as little-endian limbs.
+const MODULUS: [u64; 4] = [
+    725501752471715841,
+    6461107452199829505,
+    6968279316240510977,
+    1345280370688173398,
+];
+
+/// The modulus minus two, the exponent of the inverse S-box.
+const MODULUS_MINUS_TWO: [u64; 4] = [
+    725501752471715839,
+    6461107452199829505,
+    6968279316240510977,
+    1345280370688173398,
+];
+
+/// `-1 / MODULUS mod 2^64`, for Montgomery reduction.
+const INV: u64 = 725501752471715839;
+
+/// `R^2 mod MODULUS` with `R = 2^256`, the Montgomery form of `R`.
+const R2: [u64; 4] = [
+    2726216793283724667,
+    14712177743343147295,
+    12091039717619697043,
+    81024008013859129,
+];
+
+/// An element of [`Fq`] in Montgomery form, with `const` arithmetic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConstFq([u64; 4]);
+
+impl ConstFq {
+    /// The additive identity.
+    pub const ZERO: Self = Self([0; 4]);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Self([
+        9015221291577245683,
+        8239323489949974514,
+        1646089257421115374,
+        958099254763297437,
+    ]);
+
+    /// Construct an element from its Montgomery limbs, as used by
+    /// [`Fq::from_montgomery_limbs`].
+    ///
+    /// The limbs must be those of a reduced element.
+    pub const fn from_montgomery_limbs(limbs: [u64; 4]) -> Self {
+        Self(limbs)
+    }
+
+    /// Construct the element equal to `value`.
+    pub const fn from_u64(value: u64) -> Self {
+        Self(mont_mul(&[value, 0, 0, 0], &R2))
+    }
+
+    /// The Montgomery limbs of this element.
+    pub const fn to_montgomery_limbs(&self) -> [u64; 4] {
+        self.0
+    }
+
+    /// This element as an [`Fq`].
+    pub const fn to_fq(&self) -> Fq {
+        Fq::from_montgomery_limbs(self.0)
+    }
+
+    /// The sum of `self` and `other`.
+    pub const fn add(&self, other: &Self) -> Self {
+        let mut sum = [0u64; 4];
+        let mut carry = 0u64;
+        let mut i = 0;
+        while i < 4 {
+            let s = self.0[i] as u128 + other.0[i] as u128 + carry as u128;
+            sum[i] = s as u64;
+            carry = (s >> 64) as u64;
+            i += 1;
+        }
+        // Both summands are below the modulus, which is below `2^255`, so there is no
+        // carry out of the last limb.
+        Self(reduce(sum))
+    }
+
+    /// The product of `self` and `other`.
+    pub const fn mul(&self, other: &Self) -> Self {
+        Self(mont_mul(&self.0, &other.0))
+    }
+
+    /// `self` raised to the power `exp`, given as little-endian limbs.
+    const fn pow(&self, exp: &[u64; 4]) -> Self {
+        // Leading zero bits are skipped, which keeps `x^alpha` to a handful of
+        // multiplications when evaluated at compile time.
+        let mut result = Self::ONE;
+        let mut started = false;
+        let mut i = 4;
+        while i > 0 {
+            i -= 1;
+            let mut bit = 64;
+            while bit > 0 {
+                bit -= 1;
+                if started {
+                    result = result.mul(&result);
+                }
+                if (exp[i] >> bit) & 1 == 1 {
+                    result = if started { result.mul(self) } else { *self };
+                    started = true;
+                }
+            }
+        }
+        result
+    }
+}
+
+impl From<Fq> for ConstFq {
+    fn from(value: Fq) -> Self {
+        // The Montgomery form of `x` is `x * R` with `R = 2^256`, reduced.
+        let bytes = (value * ConstFq::from_montgomery_limbs(R2).to_fq()).to_bytes();
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            let mut le = [0u8; 8];
+            le.copy_from_slice(chunk);
+            *limb = u64::from_le_bytes(le);
+        }
+        Self(limbs)
+    }
+}
+
+impl From<ConstFq> for Fq {
+    fn from(value: ConstFq) -> Self {
+        value.to_fq()
+    }
+}
+
+/// Subtract the modulus from `x` if `x` is not below it.
+const fn reduce(x: [u64; 4]) -> [u64; 4] {
+    let mut i = 4;
+    while i > 0 {
+        i -= 1;
+        if x[i] != MODULUS[i] {
+            if x[i] < MODULUS[i] {
+                return x;
+            }
+            break;
+        }
+    }
+
+    let mut diff = [0u64; 4];
+    let mut borrow = 0u64;
+    let mut i = 0;
+    while i < 4 {
+        let (d, b1) = x[i].overflowing_sub(MODULUS[i]);
+        let (d, b2) = d.overflowing_sub(borrow);
+        diff[i] = d;
+        borrow = (b1 | b2) as u64;
+        i += 1;
+    }
+    diff
+}
+
+/// The Montgomery product `a * b / R mod MODULUS`, with the CIOS method.
+const fn mont_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut t = [0u64; 6];
+    let mut i = 0;
+    while i < 4 {
+        let mut carry = 0u64;
+        let mut j = 0;
+        while j < 4 {
+            let s = t[j] as u128 + a[j] as u128 * b[i] as u128 + carry as u128;
+            t[j] = s as u64;
+            carry = (s >> 64) as u64;
+            j += 1;
+        }
+        let s = t[4] as u128 + carry as u128;
+        t[4] = s as u64;
+        t[5] = (s >> 64) as u64;
+
+        let m = t[0].wrapping_mul(INV);
+        let s = t[0] as u128 + m as u128 * MODULUS[0] as u128;
+        let mut carry = (s >> 64) as u64;
+        let mut j = 1;
+        while j < 4 {
+            let s = t[j] as u128 + m as u128 * MODULUS[j] as u128 + carry as u128;
+            t[j - 1] = s as u64;
+            carry = (s >> 64) as u64;
+            j += 1;
+        }
+        let s = t[4] as u128 + carry as u128;
+        t[3] = s as u64;
+        t[4] = t[5] + (s >> 64) as u64;
+        i += 1;
+    }
+    // The result is below twice the modulus, which is below `2^256`, so `t[4]` is zero.
+    reduce([t[0], t[1], t[2], t[3]])
+}
+
+/// Parameters of a Poseidon instance of width `STATE_SIZE` over [`Fq`], for evaluating
+/// the permutation in `const` contexts.
+pub(crate) struct ConstParameters<const STATE_SIZE: usize> {
+    /// The S-box of the instance.
+    pub alpha: Alpha,
+
+    /// Number of full and partial rounds.
+    pub rounds: RoundNumbers,
+
+    /// Round constants, with one row of `STATE_SIZE` constants per round.
+    pub arc: &'static [[ConstFq; STATE_SIZE]],
+
+    /// MDS matrix.
+    pub mds: [[ConstFq; STATE_SIZE]; STATE_SIZE],
+}
+
+impl<const STATE_SIZE: usize> ConstParameters<STATE_SIZE> {
+    /// Apply the Poseidon permutation to `state`.
+    pub const fn permute(&self, mut state: [ConstFq; STATE_SIZE]) -> [ConstFq; STATE_SIZE] {
+        let half_full_rounds = self.rounds.r_F / 2;
+        let mut r = 0;
+        while r < self.arc.len() {
+            let mut i = 0;
+            while i < STATE_SIZE {
+                state[i] = state[i].add(&self.arc[r][i]);
+                i += 1;
+            }
+
+            if r < half_full_rounds || r >= half_full_rounds + self.rounds.r_P {
+                let mut i = 0;
+                while i < STATE_SIZE {
+                    state[i] = self.sbox(&state[i]);
+                    i += 1;
+                }
+            } else {
+                state[0] = self.sbox(&state[0]);
+            }
+
+            let mut mixed = [ConstFq::ZERO; STATE_SIZE];
+            let mut i = 0;
+            while i < STATE_SIZE {
+                let mut j = 0;
+                while j < STATE_SIZE {
+                    mixed[i] = mixed[i].add(&self.mds[i][j].mul(&state[j]));
+                    j += 1;
+                }
+                i += 1;
+            }
+            state = mixed;
+            r += 1;
+        }
+        state
+    }
+
+    /// Fixed width hash from n:1, permuting `state` and returning its first rate word.
+    pub const fn n_to_1_fixed_hash(&self, state: [ConstFq; STATE_SIZE]) -> ConstFq {
+        self.permute(state)[1]
+    }
+
+    const fn sbox(&self, word: &ConstFq) -> ConstFq {
+        match self.alpha {
+            Alpha::Exponent(exp) => word.pow(&[exp as u64, 0, 0, 0]),
+            // `x^(p - 2)` is the inverse of `x`, and zero for zero.
+            Alpha::Inverse => word.pow(&MODULUS_MINUS_TWO),
+        }
+    }
+}
+
+/// Hash a single [`ConstFq`] element with the provided `domain_separator` in a `const`
+/// context, as with [`hash_1`](crate::hash_1).
+#[cfg(feature = "rate-1")]
+pub const fn const_hash_1(domain_separator: &ConstFq, value: ConstFq) -> ConstFq {
+    rate_1::RATE_1_PARAMS.n_to_1_fixed_hash([*domain_separator, value])
+}
+
+/// Hash two [`ConstFq`] elements with the provided `domain_separator` in a `const`
+/// context, as with [`hash_2`](crate::hash_2).
+#[cfg(feature = "rate-2")]
+pub const fn const_hash_2(domain_separator: &ConstFq, value: (ConstFq, ConstFq)) -> ConstFq {
+    rate_2::RATE_2_PARAMS.n_to_1_fixed_hash([*domain_separator, value.0, value.1])
+}
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn const_arithmetic() {
+        let a = Fq::from_str(
+            "7553885614632219548127688026174585776320152166623257619763178041781456016062",
+        )
+        .unwrap();
+        let b = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let (const_a, const_b) = (ConstFq::from(a), ConstFq::from(b));
+
+        assert_eq!(const_a.to_fq(), a);
+        assert_eq!(const_a.add(&const_b).to_fq(), a + b);
+        assert_eq!(const_a.mul(&const_b).to_fq(), a * b);
+        assert_eq!(const_a.pow(&[17, 0, 0, 0]).to_fq(), a.power([17u64]));
+        assert_eq!(ConstFq::from_u64(u64::MAX).to_fq(), Fq::from(u64::MAX));
+        assert_eq!(ConstFq::ONE.to_fq(), Fq::from(1u64));
+        assert_eq!(ConstFq::ZERO.pow(&MODULUS_MINUS_TWO), ConstFq::ZERO);
+        assert_eq!(const_a.mul(&const_a.pow(&MODULUS_MINUS_TWO)), ConstFq::ONE);
+
+        // `p - 1` plus one wraps around to zero.
+        let minus_one = ConstFq::from(-Fq::from(1u64));
+        assert_eq!(minus_one.add(&ConstFq::ONE), ConstFq::ZERO);
+    }
+
+    #[cfg(all(feature = "rate-1", feature = "rate-2"))]
+    #[test]
+    fn const_params_match_vendored_params() {
+        use poseidon_parameters::v1::MatrixOperations;
+
+        for (row, constants) in rate_1::RATE_1_PARAMS.arc.iter().enumerate() {
+            for (col, constant) in constants.iter().enumerate() {
+                let expected = crate::RATE_1_PARAMS.arc.get_element(row, col);
+                assert_eq!(constant.to_fq(), expected);
+            }
+        }
+        for (row, constants) in rate_2::RATE_2_PARAMS.arc.iter().enumerate() {
+            for (col, constant) in constants.iter().enumerate() {
+                let expected = crate::RATE_2_PARAMS.arc.get_element(row, col);
+                assert_eq!(constant.to_fq(), expected);
+            }
+        }
+        for (row, entries) in rate_2::RATE_2_PARAMS.mds.iter().enumerate() {
+            for (col, entry) in entries.iter().enumerate() {
+                let expected = crate::RATE_2_PARAMS.mds.0.get_element(row, col);
+                assert_eq!(entry.to_fq(), expected);
+            }
+        }
+        assert_eq!(rate_1::RATE_1_PARAMS.rounds, crate::RATE_1_PARAMS.rounds);
+        assert_eq!(rate_2::RATE_2_PARAMS.alpha, crate::RATE_2_PARAMS.alpha);
+    }
+
+    #[cfg(all(feature = "rate-1", feature = "rate-2"))]
+    #[test]
+    fn const_hash_matches_hash() {
+        const DOMAIN_SEP: ConstFq = ConstFq::from_u64(1);
+        const HASH_1: ConstFq = const_hash_1(&DOMAIN_SEP, ConstFq::from_u64(2));
+        const HASH_2: ConstFq = const_hash_2(&DOMAIN_SEP, (ConstFq::from_u64(2), HASH_1));
+
+        let domain_sep = Fq::from(1u64);
+        let hash_1 = crate::hash_1(&domain_sep, Fq::from(2u64));
+        assert_eq!(HASH_1.to_fq(), hash_1);
+        assert_eq!(
+            HASH_2.to_fq(),
+            crate::hash_2(&domain_sep, (Fq::from(2u64), hash_1))
+        );
+
+        // The test vectors of `hash_1` and `hash_2`.
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input = Fq::from_str(
+            "7553885614632219548127688026174585776320152166623257619763178041781456016062",
+        )
+        .unwrap();
+        assert_eq!(
+            const_hash_1(&domain_sep.into(), input.into()).to_fq(),
+            Fq::from_str(
+                "2337838243217876174544784248400816541933405738836087430664765452605435675740"
+            )
+            .unwrap()
+        );
+        let input = (
+            input,
+            Fq::from_str(
+                "2337838243217876174544784248400816541933405738836087430664765452605435675740",
+            )
+            .unwrap(),
+        );
+        assert_eq!(
+            const_hash_2(&domain_sep.into(), (input.0.into(), input.1.into())).to_fq(),
+            Fq::from_str(
+                "4318449279293553393006719276941638490334729643330833590842693275258805886300"
+            )
+            .unwrap()
+        );
+    }
+}
@@ -0,0 +1,588 @@
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::{ConstFq, ConstParameters};
+
+/// Parameters for the rate-1 instance of Poseidon, with the constants of
+/// [`RATE_1_PARAMS`](crate::RATE_1_PARAMS).
+pub const RATE_1_PARAMS: ConstParameters<2> = ConstParameters {
+    alpha: Alpha::Exponent(17),
+    rounds: RoundNumbers { r_P: 31, r_F: 8 },
+    arc: &[
+        [
+            ConstFq::from_montgomery_limbs([
+                6258896775460530561,
+                1267570262938364784,
+                10583705394133388288,
+                4331270346116315,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                15454694353288852986,
+                2205227775135851582,
+                9104115866970654747,
+                48952601862872692,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                17632203450119298224,
+                13599315412875479343,
+                7140007131719627884,
+                569020719923681609,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                6608939517720255573,
+                15066841911042588370,
+                8648475674864948310,
+                578035154261448463,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                1055805092356900670,
+                5486646384412869322,
+                9705528431443784365,
+                307684188417815151,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                11248650571348093756,
+                1514639161444600232,
+                968234586550369174,
+                1313541476846290422,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                5189078861895589451,
+                9996051726892023924,
+                14955307722807799098,
+                1320126769029056157,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                14397975440942704641,
+                14269464930912859892,
+                9230505175050478925,
+                1093012507774165575,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                6847445113090943697,
+                17673603016395516883,
+                15250705377106188486,
+                539035154593930175,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                6947720323756162913,
+                3313209312340777357,
+                18395293995391548637,
+                209157278787801614,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                3458319354278701363,
+                11429938079178703613,
+                7005241729732086579,
+                150425083810087548,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                2543869222269883848,
+                6129645233495109995,
+                1076912762679804840,
+                1127382646597654684,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                3802085031173312140,
+                1153736051705836195,
+                34368926314299214,
+                870379362515076367,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                5488449230911278270,
+                2470593653263895214,
+                2746242821352744093,
+                1170504430408456781,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                3039943962034370722,
+                16476527388322339887,
+                5748836754427063321,
+                874163389084517420,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                11160556015001057055,
+                18242508582059343688,
+                1914918650959602739,
+                1060251542697903110,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                1250158851421170863,
+                3598229175113435790,
+                11440021114985999339,
+                539355236403595924,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                17381864786167270102,
+                5920159615239164628,
+                7621039653090189177,
+                80820461513544845,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                9427313983461117140,
+                12722081891364237901,
+                2526344363227396036,
+                169367802375106360,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                18279043286872423918,
+                11282585104763746254,
+                6663752460065940976,
+                1313306297247703395,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                11348011840530639501,
+                13585724393536580138,
+                16173408530293170097,
+                237306649524404549,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                9105438583131143101,
+                484614180087189633,
+                11007155874658626631,
+                1105934356159118197,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                17692463671930692407,
+                8848595903938472796,
+                11444065729641962767,
+                856337008348787181,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                11475710285348090819,
+                12082432292625800602,
+                4973698077631026180,
+                250886293876957218,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                3831119291993789483,
+                11955292354948817093,
+                10578474534116863906,
+                292270082742245378,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                11074518745033881448,
+                14103711443902779277,
+                9703092681767716480,
+                1027943651677607178,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                14255504761835093988,
+                12836619582277229653,
+                16470467141944117103,
+                216605519265731395,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                17412479146814766387,
+                7550994961529157056,
+                16948400072627593287,
+                686398674032066558,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                14019001188641598557,
+                17711295492607709098,
+                17317648113970387478,
+                1005852188387049762,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                2757938402041260135,
+                10858479133892891281,
+                6226991255650955925,
+                113236437448370768,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                2864831845918016166,
+                11222314241298259255,
+                6809710896423414618,
+                391420167412878448,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                15295022328131697798,
+                14180180046854426704,
+                5969598612677890929,
+                422063763314834782,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                9004302479558436050,
+                9395856654729450347,
+                1876513748778519470,
+                1078019802682326971,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                17483474807509017611,
+                1860495490123329066,
+                10427704098322365889,
+                168618466562963955,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                3325616628101926400,
+                6318651352098105269,
+                10561353542035867513,
+                347869863814864598,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                10608180854761738188,
+                7227409243651228657,
+                10245865730256963695,
+                33366710867335616,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                6377899455837337983,
+                6460750903815498030,
+                14658523922399852198,
+                850391300192126368,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                9949314602248501685,
+                9592125889973713334,
+                12540141676151911968,
+                902630930644097978,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                9874569741817026646,
+                8597014183735249181,
+                14218226934847278615,
+                990148636388408942,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                9660554731559651732,
+                4712943300030820950,
+                8606150340609690108,
+                160577077343575019,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                1527563399863479849,
+                15731827835699836031,
+                7229971325383988685,
+                826813386663462338,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                18280004775370667641,
+                10717313307037013407,
+                3515684275541234253,
+                1288829721588576759,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                9831584723932855792,
+                1077247411674292976,
+                4470807593665567057,
+                629496612576050491,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                18018152400203412106,
+                16658517283861939563,
+                15927825308647499591,
+                1046841820836193453,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                424287065221489244,
+                17051291629546655826,
+                4983999217110075951,
+                1278494067020803559,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                13081137544640017921,
+                1196650010435295235,
+                15746798075854654528,
+                1203678627802318991,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                939677723946433505,
+                11567589096003441629,
+                12722931402128711345,
+                720362007908860959,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                14103532265560668917,
+                10923867233350724517,
+                18049017711808115649,
+                375405789021031150,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                497571197013480246,
+                10623440047065153586,
+                4886324577963912147,
+                1126015905264708236,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                8235696195804714347,
+                8423059580415141744,
+                17356311622946336763,
+                215719902708291122,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                13167547990249432274,
+                13822834764858661811,
+                1681839468362828968,
+                1102696208723885390,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                14358625551422878077,
+                17488520719897236232,
+                10532471740075617546,
+                780155994080253829,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                17574454647216920383,
+                13786111586753929723,
+                11740472500664499721,
+                34830030445253818,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                9209614961980615136,
+                9937652035573086410,
+                1218815569248823646,
+                236722195254239968,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                3620849600049355235,
+                2774041983584442196,
+                6713450660153581499,
+                1022079506649410443,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                15478035690602744699,
+                17214923924946245378,
+                13206453575278417593,
+                842388521454715001,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                7853082889027573297,
+                5688955180632238595,
+                4814575662942306312,
+                837428779080817192,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                6927274665365457380,
+                4891595237470578015,
+                9799180733026983523,
+                417480065074766363,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                9633744074825535160,
+                13348096458316776884,
+                10200632920038276183,
+                1241967248070647028,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                7315634111998276785,
+                14989100225520468879,
+                2450102833924358633,
+                264607654026641770,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                7717087918080228437,
+                10060974775377729782,
+                15332244215838611263,
+                711171330417334270,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                2324103748366230971,
+                10995578112328024729,
+                15408740786099239677,
+                1268694519894110373,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                11893493435520502207,
+                3766971087184646941,
+                15414311780153824548,
+                275458309578547698,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                3031245967343841039,
+                4502052608729245908,
+                4341196184555983012,
+                1220348581034324635,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                14560505796665136876,
+                9750262619582169702,
+                11805062379512156262,
+                947525094566692079,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                7374167934946983346,
+                10320986442790179232,
+                1316321051690183387,
+                672142052910646114,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                6678718452413104008,
+                3653041364903437992,
+                3698421704565957570,
+                448468818117066870,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                12909750526124334775,
+                6274889465513641781,
+                11804747737295382226,
+                507133470520718817,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                9667929895833397580,
+                11082007517281041627,
+                10049496943206931153,
+                128401977346054829,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                12310961684508931943,
+                17018879353361910672,
+                9780939516032057631,
+                874860903480789609,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                1042224041096057459,
+                15183806776241160696,
+                2334295613683866263,
+                49327571912713520,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                17515802687784336662,
+                10591106814883990536,
+                4193417463306063615,
+                23930315094714182,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                15007113684212052093,
+                95900555196155559,
+                9807646841884583683,
+                251554444378507936,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                8094314597037982537,
+                326195237272228919,
+                9972406889128755860,
+                563568079596439234,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                12074057828954296990,
+                17459062068677478655,
+                2059156604363327430,
+                860353551420485928,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                13609943108532429528,
+                16610915446730445558,
+                9788093117338911219,
+                1175759231734449357,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                7485446014387007697,
+                13102110362887743132,
+                11648775823210990955,
+                43349719308454144,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                11902878575783530648,
+                16441587368398080183,
+                9321126611136841229,
+                579191052426365547,
+            ]),
+        ],
+    ],
+    mds: [
+        [
+            ConstFq::from_montgomery_limbs([
+                14093733558879256570,
+                16573587507929677817,
+                13530556323685588983,
+                1151689812725735417,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                15302903146332116305,
+                8895355854553175376,
+                548696419140371791,
+                319366418254432479,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                15302903146332116305,
+                8895355854553175376,
+                548696419140371791,
+                319366418254432479,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                16270238816294404093,
+                17510165790819614716,
+                15988650198697570299,
+                575844906362867708,
+            ]),
+        ],
+    ],
+};
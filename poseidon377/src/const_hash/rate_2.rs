@@ -0,0 +1,854 @@
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::{ConstFq, ConstParameters};
+
+/// Parameters for the rate-2 instance of Poseidon, with the constants of
+/// [`RATE_2_PARAMS`](crate::RATE_2_PARAMS).
+pub const RATE_2_PARAMS: ConstParameters<3> = ConstParameters {
+    alpha: Alpha::Exponent(17),
+    rounds: RoundNumbers { r_P: 31, r_F: 8 },
+    arc: &[
+        [
+            ConstFq::from_montgomery_limbs([
+                13532052565085671856,
+                13175575834742130482,
+                14571043277169067394,
+                1128324334676141805,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                10586567975029405298,
+                5987505274649066590,
+                9663655893165983133,
+                381788629769180835,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                7404335995308317606,
+                10342209374602516054,
+                14679697400509748167,
+                1259991645283011647,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                12711294721892819516,
+                8412935995118692797,
+                7069737649526067295,
+                549549236990677487,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                128694122049037398,
+                15825223235256271389,
+                74647683628497806,
+                403759781237802093,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                3501552524290051296,
+                10688664658423588201,
+                8345653809557910120,
+                766292783896459938,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                13998055823902745235,
+                15535161481341849823,
+                9848874496394823215,
+                579121379437172744,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                16363263235454987811,
+                9117252686821547535,
+                4794867261658414781,
+                418411818796950741,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                3710344964675021293,
+                13957371261080223237,
+                15195218532629186765,
+                760708816844813378,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                16135673103471103205,
+                13943139040297964408,
+                12037506718934223099,
+                1187380734246932890,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                11326210002632211732,
+                2893415499386035614,
+                2949171194607212014,
+                568849018592141776,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                13925494990768706056,
+                13969996543019858204,
+                13457764640058086886,
+                114396439087701688,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                16351586607272228519,
+                6269966282891300082,
+                12490381894697744781,
+                834648274293416263,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                1437979443977545600,
+                14767193465362841311,
+                11074881595123675812,
+                1204201379421269428,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                5230240271406271908,
+                16847766581152362930,
+                1712847198165617791,
+                311937423231086981,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                3645836740283016323,
+                15685198898945208452,
+                1980971647688859029,
+                176967089015698342,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                7550143813701248311,
+                18291556615524985846,
+                8534400014086995148,
+                500430429779017952,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                596957473479108084,
+                6324092840533510915,
+                4811101589352947098,
+                76518932650696039,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                336812235439984799,
+                13522610888312181094,
+                10501405696980879642,
+                449669351326561391,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                12592523411743912820,
+                3924222765120111350,
+                5699458771760954386,
+                892267873608702453,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                12993017322389395630,
+                5952902593915102432,
+                5247111021983199097,
+                470467609253978097,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                7244663031809848522,
+                3071554415549245091,
+                2773295222867337766,
+                1090905641165090834,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                9981097535495148290,
+                12930981823992014491,
+                9629464214154256600,
+                308026108875661910,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                12850815434424207307,
+                8847171669624931211,
+                16288364336002865240,
+                954936779594025677,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                11236881576270168641,
+                15414742267201110956,
+                7825767860878753872,
+                721136380245447153,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                2832704701681877466,
+                7235621408572302496,
+                11812570847329545593,
+                792455748001887640,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                3432713492718273410,
+                6465506654245539380,
+                8827139003294513485,
+                992550877185604089,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                9728786934890323565,
+                10584002864733096791,
+                2293345482796697755,
+                180937419315601034,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                13822678097673121251,
+                4948809415032161320,
+                15691917123613077102,
+                683890210553461269,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                9002390647260096239,
+                10448673368926307125,
+                17815937281103619895,
+                1097505426950771788,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                13908365267431289868,
+                3370079770970658040,
+                15308027316557057295,
+                1308145822882463267,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                7831189882886479452,
+                4689238810753296883,
+                13159502922430301346,
+                27611585919074368,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                14912709676120752020,
+                10115204646384083757,
+                10138309295115926582,
+                223210301540489796,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                12627294687244820632,
+                2059848305109767326,
+                1888484742856066068,
+                1272834063475208138,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                6115210324513801758,
+                10929870666119062300,
+                11707806468269326656,
+                949025326858793021,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                7061826933729287205,
+                1126603583829494325,
+                5469143638052028670,
+                1033665004945948695,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                11008930476894918520,
+                11381807375074895164,
+                2844592577512486485,
+                1128817316316311928,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                5300558244079945539,
+                8283037415518590201,
+                11038807277807240648,
+                1178232107582202545,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                11862022433031293870,
+                8913994378642882666,
+                9450763413034135432,
+                77958396662445698,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                17688402066322086519,
+                524036851070457942,
+                14909117109897886121,
+                557290224371677333,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                1875815915909501089,
+                10678692213853973640,
+                15989623299920327105,
+                428879321377057641,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                1082841901890716129,
+                12068516826131602351,
+                7102481379375591007,
+                906616535867466859,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                17613918888580330287,
+                6204465427334198417,
+                9436344365449852604,
+                655489186886697,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                17585284967658841164,
+                80944379680805043,
+                2698615756683481750,
+                351188921018806563,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                15687615037958784071,
+                2186404284346347770,
+                5652967689160162635,
+                1056583988063876017,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                13107987562409563672,
+                3871322351409280978,
+                8803804680465229381,
+                1030040159656924298,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                16684869482081657339,
+                10281204171444660632,
+                1624958597982547757,
+                386745834466917047,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                6970896123209894127,
+                7830160530923913939,
+                17422861911349199859,
+                623374110284783712,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                4331844870617545742,
+                520046082233107649,
+                5594606953430693091,
+                249698853373994073,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                6680837442175120485,
+                6814766854525276712,
+                6556825746309967179,
+                570042050188335199,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                15436728868072851979,
+                10638387680273826288,
+                16379320772832534606,
+                822954407147775124,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                11837214986206518455,
+                596921394620411645,
+                13159256680099246054,
+                1277661308547494330,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                13679203667889812546,
+                11676386286470050129,
+                8357354920215222534,
+                456950010799870038,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                2229876009761017392,
+                13089694557765213339,
+                16529665410348556997,
+                1200161010593736391,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                16005321486742773520,
+                8650710416750881700,
+                12927174790925866955,
+                1318630719916273524,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                11180409359961118236,
+                7275623093671008389,
+                11439214005189014436,
+                975533040864102009,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                1493684412606064445,
+                1600586062110688441,
+                6392169949497859284,
+                320645276436957806,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                18076284763174743111,
+                15528272241931714086,
+                1987806618344857317,
+                81963683463344143,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                2380106604330211947,
+                13999809284604519871,
+                17117752833631344354,
+                928049684624129249,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                16709180677553116618,
+                15078892089982505559,
+                12839669848886637777,
+                1151522444646802538,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                1024922389916170464,
+                4633605473275241115,
+                8890730306179887903,
+                1145679956560153579,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                2689026397641652069,
+                13435392347048733438,
+                4613843849026450873,
+                644769297929746792,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                1477469845453362358,
+                8875949790662885201,
+                7478581831156986579,
+                67083263015332207,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                1506147123099314810,
+                6546355754774050650,
+                13235191534515389566,
+                573446483563428911,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                11592075759296992527,
+                11495396924055153811,
+                17020953547937729115,
+                326913902434578022,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                10523148363065643749,
+                13492540919508068783,
+                17386067260199896807,
+                528704601527288393,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                9127950768740932108,
+                15914409702651500773,
+                11784570023787002367,
+                340904789729663175,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                6245027322171754000,
+                15663478768566472378,
+                11402185558701422250,
+                36675236290199327,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                6063749964881818939,
+                10257461935793181106,
+                942936442940111744,
+                540037121922249544,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                12968659316148036261,
+                14899520595018177284,
+                3836336888070673001,
+                941781069635905693,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                126228530830236165,
+                5499407626081829044,
+                14133761497400979158,
+                1184006332035149871,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                5031808939874245057,
+                12620452215680194384,
+                1600289362926216464,
+                1200752349590914751,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                13105110393340809165,
+                7101636223400183860,
+                1455202496382111435,
+                1040366751489349639,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                2247493102747455874,
+                3444719793324110884,
+                17343476723736941016,
+                460423680466113922,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                18230610362649795022,
+                4850929477447852354,
+                2999970116929109994,
+                374491266534684371,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                205446930692894616,
+                10625340386042781881,
+                2267554848789579110,
+                805571592523062224,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                9554436490539537540,
+                3781982688039156286,
+                12732806211783679988,
+                207650560800307204,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                14608069410708161069,
+                15150367565152858757,
+                7976963546254869682,
+                1302080053778539832,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                5668581368285301758,
+                1481024316238442280,
+                14073619068562930813,
+                157011518856032932,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                10782685697581511660,
+                656395425526716484,
+                12429361742269361127,
+                1218629948585201369,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                9945724486634605933,
+                5559580130460001707,
+                4958363795583827901,
+                1086630389709013647,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                6214153618072836629,
+                6297284130475637236,
+                3341157682715609955,
+                687805559853576127,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                17561648631049166744,
+                4193461398377217711,
+                17499970945040917083,
+                352506169960283993,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                1122765646603573063,
+                1498611916106521677,
+                10924305234288949443,
+                728326887801130515,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                5800850628186966207,
+                4398317517906769115,
+                9597227115480831972,
+                526197039749009419,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                5159263173914965708,
+                8792786795115987428,
+                3124972989790777106,
+                1052873249848825660,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                1668691385020369393,
+                4311283499155522888,
+                13095876963782085960,
+                334904475460146335,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                14551884987824763154,
+                5459630410931408158,
+                838248742615457530,
+                355270342663742218,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                13811707083670026157,
+                17980420357872171335,
+                15680078942835361627,
+                508494405154027190,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                259245969673242010,
+                12143818002017916372,
+                9104957694955299615,
+                588078921942677607,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                1402823264425646775,
+                17394328405754534008,
+                8594536777296298084,
+                589743092826427286,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                17525191514318164285,
+                3032947818017798651,
+                8010733640330629183,
+                18263345896298111,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                2324591806430845335,
+                3802314283763375525,
+                5802980770825972296,
+                1172121386066784655,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                3481957081013606129,
+                7174433466077666272,
+                18298661318322644558,
+                779600890592950522,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                12191117132876429501,
+                13984916474435046850,
+                15993612081836470001,
+                982264150805006675,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                5832216401942411966,
+                14563514500329138886,
+                4063839581877579762,
+                988127094162723498,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                9980296771901734820,
+                8406919274419375318,
+                7382848308758481422,
+                288106649508594049,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                15311094169366877824,
+                8845543297515843004,
+                5111676196784685495,
+                854309633222289538,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                11805299538225612753,
+                2464747910811709980,
+                7821113581135412494,
+                698716086285897944,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                14453145658534747007,
+                17272254080461340124,
+                10230451546974640772,
+                626243697059407772,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                11287978532447653494,
+                18279346120194159177,
+                2333031913403775389,
+                200338723139384832,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                12366653650419860061,
+                16274230817774725784,
+                4516721510516055669,
+                75055982532212210,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                388555674032981681,
+                6567318722554709379,
+                2086741448191262667,
+                908777123569794972,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                9649589442582163437,
+                13541178269507352071,
+                14258536536571968121,
+                575215969686158075,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                597836451158842199,
+                1858657668022201800,
+                504852646431126025,
+                351889211223645499,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                8710335429210318089,
+                15939765460477572548,
+                13493874982974354172,
+                1195299757590572308,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                16720044796621311460,
+                6297107607479107816,
+                15666086360782481298,
+                946070823657072210,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                5216764590966106189,
+                18330556132248987975,
+                14778046174865524185,
+                601592383999400705,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                12284358746189003172,
+                15796822047553990474,
+                10249807758448272935,
+                1260540691182942177,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                14671741496053748797,
+                15956144823383552989,
+                8978105115308257315,
+                1129739098292337641,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                16528885559305010985,
+                14885472285663731653,
+                8692450371018254777,
+                875854298006252122,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                11216935928075429441,
+                7088545516130524118,
+                3510979311098449529,
+                980345967679074250,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                8480849792218315917,
+                11146529356407982510,
+                4204845623809735650,
+                554809226012522197,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                9465397404983425896,
+                1339596460298740670,
+                2695000812316794677,
+                164270174202044235,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                12807958353199166386,
+                2607509355362673632,
+                9510828759520576266,
+                306232379852697998,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                14216260065142834520,
+                8830790711865643815,
+                7264415235010871052,
+                905551093713836953,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                1392129315389772974,
+                3163495863255052347,
+                5961497987857813198,
+                47707649459794917,
+            ]),
+        ],
+    ],
+    mds: [
+        [
+            ConstFq::from_montgomery_limbs([
+                15302903146332116305,
+                8895355854553175376,
+                548696419140371791,
+                319366418254432479,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                16270238816294404093,
+                17510165790819614716,
+                15988650198697570299,
+                575844906362867708,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                16270238816294404093,
+                17510165790819614716,
+                15988650198697570299,
+                575844906362867708,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+        ],
+        [
+            ConstFq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            ConstFq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+        ],
+    ],
+};
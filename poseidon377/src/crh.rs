@@ -0,0 +1,172 @@
+//! Implementations of the arkworks collision-resistant hash traits.
+//!
+//! [`Crh`] and [`TwoToOneCrh`] implement [`CRHScheme`] and [`TwoToOneCRHScheme`],
+//! so the Poseidon instances of this crate can be used directly with the Merkle
+//! tree and signature constructions of `ark-crypto-primitives`. The parameters of
+//! both schemes are the domain separator. With the `r1cs` feature, `CrhGadget`
+//! and `TwoToOneCrhGadget` are the corresponding gadgets.
+
+use ark_crypto_primitives::{
+    crh::{CRHScheme, TwoToOneCRHScheme},
+    Error,
+};
+use ark_std::{borrow::Borrow, rand::Rng, UniformRand};
+
+use crate::Fq;
+
+/// A CRH hashing a slice of [`Fq`] elements with [`hash_varlen`](crate::hash_varlen).
+pub struct Crh;
+
+impl CRHScheme for Crh {
+    type Input = [Fq];
+    type Output = Fq;
+    type Parameters = Fq;
+
+    /// Sample a uniformly random domain separator.
+    fn setup<R: Rng>(rng: &mut R) -> Result<Fq, Error> {
+        Ok(<Fq as UniformRand>::rand(rng))
+    }
+
+    fn evaluate<T: Borrow<[Fq]>>(domain_separator: &Fq, input: T) -> Result<Fq, Error> {
+        Ok(crate::hash_varlen(domain_separator, input.borrow()))
+    }
+}
+
+/// A two-to-one CRH hashing a pair of [`Fq`] elements with [`hash_2`](crate::hash_2).
+///
+/// Leaves and inner nodes are both hashed with [`hash_2`](crate::hash_2), so callers
+/// should use different domain separators for the two if they need to tell them apart.
+pub struct TwoToOneCrh;
+
+impl TwoToOneCRHScheme for TwoToOneCrh {
+    type Input = Fq;
+    type Output = Fq;
+    type Parameters = Fq;
+
+    /// Sample a uniformly random domain separator.
+    fn setup<R: Rng>(rng: &mut R) -> Result<Fq, Error> {
+        Ok(<Fq as UniformRand>::rand(rng))
+    }
+
+    fn evaluate<T: Borrow<Fq>>(domain_separator: &Fq, left: T, right: T) -> Result<Fq, Error> {
+        Self::compress(domain_separator, left, right)
+    }
+
+    fn compress<T: Borrow<Fq>>(domain_separator: &Fq, left: T, right: T) -> Result<Fq, Error> {
+        Ok(crate::hash_2(
+            domain_separator,
+            (*left.borrow(), *right.borrow()),
+        ))
+    }
+}
+
+#[cfg(feature = "r1cs")]
+pub use self::r1cs::{CrhGadget, TwoToOneCrhGadget};
+
+#[cfg(feature = "r1cs")]
+mod r1cs {
+    use ark_crypto_primitives::crh::{CRHSchemeGadget, TwoToOneCRHSchemeGadget};
+    use ark_r1cs_std::R1CSVar;
+    use ark_relations::r1cs::SynthesisError;
+    use decaf377::r1cs::FqVar;
+
+    use super::{Crh, TwoToOneCrh};
+    use crate::Fq;
+
+    /// The gadget for [`Crh`].
+    pub struct CrhGadget;
+
+    impl CRHSchemeGadget<Crh, Fq> for CrhGadget {
+        type InputVar = [FqVar];
+        type OutputVar = FqVar;
+        type ParametersVar = FqVar;
+
+        fn evaluate(domain_separator: &FqVar, input: &[FqVar]) -> Result<FqVar, SynthesisError> {
+            let cs = input
+                .iter()
+                .fold(domain_separator.cs(), |cs, word| cs.or(word.cs()));
+            crate::r1cs::hash_varlen(cs, domain_separator, input)
+        }
+    }
+
+    /// The gadget for [`TwoToOneCrh`].
+    pub struct TwoToOneCrhGadget;
+
+    impl TwoToOneCRHSchemeGadget<TwoToOneCrh, Fq> for TwoToOneCrhGadget {
+        type InputVar = FqVar;
+        type OutputVar = FqVar;
+        type ParametersVar = FqVar;
+
+        fn evaluate(
+            domain_separator: &FqVar,
+            left: &FqVar,
+            right: &FqVar,
+        ) -> Result<FqVar, SynthesisError> {
+            Self::compress(domain_separator, left, right)
+        }
+
+        fn compress(
+            domain_separator: &FqVar,
+            left: &FqVar,
+            right: &FqVar,
+        ) -> Result<FqVar, SynthesisError> {
+            let cs = domain_separator.cs().or(left.cs()).or(right.cs());
+            crate::r1cs::hash_2(cs, domain_separator, (left.clone(), right.clone()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn crh_matches_hash_functions() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input = [Fq::from(1u64), Fq::from(2u64), Fq::from(3u64)];
+
+        assert_eq!(
+            Crh::evaluate(&domain_sep, &input[..]).unwrap(),
+            crate::hash_varlen(&domain_sep, &input)
+        );
+        assert_eq!(
+            TwoToOneCrh::evaluate(&domain_sep, input[0], input[1]).unwrap(),
+            crate::hash_2(&domain_sep, (input[0], input[1]))
+        );
+    }
+
+    #[cfg(feature = "r1cs")]
+    #[test]
+    fn crh_gadgets_match_native() {
+        use ark_crypto_primitives::crh::{CRHSchemeGadget, TwoToOneCRHSchemeGadget};
+        use ark_r1cs_std::{alloc::AllocVar, R1CSVar};
+        use ark_relations::r1cs::ConstraintSystem;
+        use decaf377::r1cs::FqVar;
+
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let domain_sep_var = FqVar::new_constant(cs.clone(), domain_sep).unwrap();
+
+        for len in 0..10u64 {
+            let input: Vec<Fq> = (0..len).map(Fq::from).collect();
+            let input_vars: Vec<FqVar> = input
+                .iter()
+                .map(|x| FqVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+                .collect();
+            let output = CrhGadget::evaluate(&domain_sep_var, &input_vars).unwrap();
+            assert_eq!(
+                output.value().unwrap(),
+                Crh::evaluate(&domain_sep, &input[..]).unwrap()
+            );
+        }
+
+        let left = FqVar::new_witness(cs.clone(), || Ok(Fq::from(1u64))).unwrap();
+        let right = FqVar::new_witness(cs.clone(), || Ok(Fq::from(2u64))).unwrap();
+        let output = TwoToOneCrhGadget::compress(&domain_sep_var, &left, &right).unwrap();
+        assert_eq!(
+            output.value().unwrap(),
+            TwoToOneCrh::compress(&domain_sep, Fq::from(1u64), Fq::from(2u64)).unwrap()
+        );
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
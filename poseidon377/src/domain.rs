@@ -0,0 +1,244 @@
+use core::ops::Deref;
+
+use crate::Fq;
+#[cfg(feature = "all-rates")]
+use crate::{hash_2, hash_bytes};
+
+/// Domain separator used to derive [`DomainSeparator`]s from labels,
+/// `from_le_bytes_mod_order(b"poseidon377.domain")`.
+#[cfg(feature = "all-rates")]
+const LABEL_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    12874088008208468608,
+    4617439129004181534,
+    12019303909695135746,
+    885394901342392976,
+]);
+
+/// Domain separator used to derive [`Personalization`]s and the domain separators
+/// they personalize, `from_le_bytes_mod_order(b"poseidon377.personal")`.
+#[cfg(feature = "all-rates")]
+const PERSONAL_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    9540527840559221226,
+    9344199303632685852,
+    15598741341138355351,
+    266440437289278193,
+]);
+
+/// A domain separator for the hash functions in this crate.
+///
+/// Constructing domain separators from labels with [`DomainSeparator::new`] hashes
+/// the label to a field element, so two protocols only collide if they pick the
+/// same label, rather than if they pick the same small integer.
+///
+/// `DomainSeparator` dereferences to [`Fq`], so it can be passed wherever a
+/// `&Fq` domain separator is expected:
+///
+/// ```
+/// use poseidon377::{hash_1, DomainSeparator, Fq};
+///
+/// let domain_sep = DomainSeparator::new(b"my.protocol.v1");
+/// let output = hash_1(&domain_sep, Fq::from(1u64));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DomainSeparator(Fq);
+
+impl DomainSeparator {
+    /// Derive a domain separator from a `label` by hashing it to a field element.
+    ///
+    /// This is done with [`hash_bytes`] under a domain separator reserved for this
+    /// purpose, so labels of any length are supported. Since hashing is not available
+    /// in `const` contexts, this should be called once at startup and the result
+    /// reused, or the result embedded as a constant with
+    /// [`DomainSeparator::from_montgomery_limbs`].
+    #[cfg(feature = "all-rates")]
+    pub fn new(label: &[u8]) -> Self {
+        Self(hash_bytes(&LABEL_DOMAIN_SEP, label))
+    }
+
+    /// Construct a domain separator from the Montgomery limbs of its field element, as
+    /// returned by [`DomainSeparator::to_montgomery_limbs`].
+    ///
+    /// This is a `const fn`, so a domain separator derived once with
+    /// [`DomainSeparator::new`] can be declared as a constant:
+    ///
+    /// ```
+    /// use poseidon377::{DomainSeparator, Fq};
+    ///
+    /// // The limbs printed once by, e.g.,
+    /// // `DomainSeparator::new(b"my.protocol.v1").to_montgomery_limbs()`.
+    /// const DOMAIN_SEP: DomainSeparator = DomainSeparator::from_montgomery_limbs([
+    ///     9015221291577245683,
+    ///     8239323489949974514,
+    ///     1646089257421115374,
+    ///     958099254763297437,
+    /// ]);
+    ///
+    /// // These limbs are those of one.
+    /// assert_eq!(DOMAIN_SEP, DomainSeparator::from_fq(Fq::from(1u64)));
+    /// ```
+    pub const fn from_montgomery_limbs(limbs: [u64; 4]) -> Self {
+        Self(Fq::from_montgomery_limbs(limbs))
+    }
+
+    /// The Montgomery limbs of this domain separator, see
+    /// [`DomainSeparator::from_montgomery_limbs`].
+    pub fn to_montgomery_limbs(&self) -> [u64; 4] {
+        // The Montgomery form of `x` is `x * R` with `R = 2^256`, reduced.
+        let mut r = Fq::from(2u64);
+        for _ in 0..8 {
+            r = r * r;
+        }
+        let bytes = (self.0 * r).to_bytes();
+        let mut limbs = [0u64; 4];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks_exact(8)) {
+            let mut le = [0u8; 8];
+            le.copy_from_slice(chunk);
+            *limb = u64::from_le_bytes(le);
+        }
+        limbs
+    }
+
+    /// Use an existing field element as a domain separator, e.g. for
+    /// compatibility with domain separators chosen before this type existed.
+    pub const fn from_fq(domain_separator: Fq) -> Self {
+        Self(domain_separator)
+    }
+}
+
+#[cfg(feature = "all-rates")]
+impl From<&str> for DomainSeparator {
+    fn from(label: &str) -> Self {
+        Self::new(label.as_bytes())
+    }
+}
+
+impl From<DomainSeparator> for Fq {
+    fn from(domain_separator: DomainSeparator) -> Self {
+        domain_separator.0
+    }
+}
+
+impl AsRef<Fq> for DomainSeparator {
+    fn as_ref(&self) -> &Fq {
+        &self.0
+    }
+}
+
+impl Deref for DomainSeparator {
+    type Target = Fq;
+
+    fn deref(&self) -> &Fq {
+        &self.0
+    }
+}
+
+/// A personalization string, namespacing all hashes of an application.
+///
+/// Similar to personalization in BLAKE2, this is independent of the per-call
+/// domain separators: an application picks one personalization and keeps its
+/// existing domain separators, which are combined with the personalization into
+/// the value placed in the capacity. Two applications with different
+/// personalizations therefore never share a capacity value, even if they use the
+/// same domain separators.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg(feature = "all-rates")]
+pub struct Personalization(Fq);
+
+#[cfg(feature = "all-rates")]
+impl Personalization {
+    /// Derive a personalization from `personalization` bytes by hashing them to a field element.
+    pub fn new(personalization: &[u8]) -> Self {
+        Self(hash_bytes(&PERSONAL_DOMAIN_SEP, personalization))
+    }
+
+    /// Combine this personalization with a per-call `domain_separator`.
+    ///
+    /// The combined domain separator is [`hash_2`] of the personalization and the
+    /// `domain_separator`, so this costs one permutation; since both are usually
+    /// fixed for a call site, the result can be computed once and reused.
+    pub fn domain_separator(&self, domain_separator: &Fq) -> DomainSeparator {
+        DomainSeparator(hash_2(&PERSONAL_DOMAIN_SEP, (self.0, *domain_separator)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn label_derivation() {
+        assert_eq!(
+            LABEL_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.domain")
+        );
+
+        let domain_sep = DomainSeparator::new(b"my.protocol.v1");
+        assert_eq!(domain_sep, DomainSeparator::from("my.protocol.v1"));
+        assert_ne!(domain_sep, DomainSeparator::new(b"my.protocol.v2"));
+        assert_ne!(*domain_sep, Fq::from(1u64));
+        assert_eq!(*DomainSeparator::from_fq(Fq::from(1u64)), Fq::from(1u64));
+    }
+
+    #[test]
+    fn montgomery_limbs_round_trip() {
+        const LIMBS: [u64; 4] = [
+            12874088008208468608,
+            4617439129004181534,
+            12019303909695135746,
+            885394901342392976,
+        ];
+        const LABEL: DomainSeparator = DomainSeparator::from_montgomery_limbs(LIMBS);
+        assert_eq!(*LABEL, LABEL_DOMAIN_SEP);
+        assert_eq!(
+            DomainSeparator::from_fq(LABEL_DOMAIN_SEP).to_montgomery_limbs(),
+            LIMBS
+        );
+
+        let domain_sep = DomainSeparator::new(b"my.protocol.v1");
+        assert_eq!(
+            DomainSeparator::from_montgomery_limbs(domain_sep.to_montgomery_limbs()),
+            domain_sep
+        );
+    }
+
+    #[test]
+    fn personalization_derivation() {
+        assert_eq!(
+            PERSONAL_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.personal")
+        );
+
+        let personalization = Personalization::new(b"my.application");
+        assert_eq!(
+            personalization.0,
+            hash_bytes(&PERSONAL_DOMAIN_SEP, b"my.application")
+        );
+
+        let domain_sep = Fq::from(7u64);
+        let personalized = personalization.domain_separator(&domain_sep);
+        assert_eq!(
+            *personalized,
+            hash_2(&PERSONAL_DOMAIN_SEP, (personalization.0, domain_sep))
+        );
+    }
+
+    #[test]
+    fn personalization_changes_output() {
+        let domain_sep = Fq::from(7u64);
+        let input = (Fq::from(1u64), Fq::from(2u64));
+
+        let first = Personalization::new(b"first.application").domain_separator(&domain_sep);
+        let second = Personalization::new(b"second.application").domain_separator(&domain_sep);
+        assert_ne!(first, second);
+        assert_ne!(*first, domain_sep);
+
+        let unpersonalized = hash_2(&domain_sep, input);
+        assert_ne!(hash_2(&first, input), unpersonalized);
+        assert_ne!(hash_2(&second, input), unpersonalized);
+        assert_ne!(hash_2(&first, input), hash_2(&second, input));
+
+        // The same personalization and domain separator always give the same hash.
+        let again = Personalization::new(b"first.application").domain_separator(&domain_sep);
+        assert_eq!(hash_2(&first, input), hash_2(&again, input));
+    }
+}
@@ -0,0 +1,174 @@
+use decaf377::{Element, Encoding};
+
+#[cfg(feature = "all-rates")]
+use crate::hash_object;
+use crate::Fq;
+
+/// Number of bytes packed into each [`Fq`] element when encoding byte strings.
+///
+/// 31 bytes is 248 bits, which is always less than the modulus, so the packing is injective.
+pub(crate) const BYTES_PER_ELEMENT: usize = 31;
+
+/// A value with a canonical encoding as a sequence of [`Fq`] elements.
+///
+/// Encodings are injective and self-delimiting: types with a fixed size are
+/// encoded as a fixed number of elements, and variable-length types such as
+/// slices are prefixed with their length. This makes the encoding of a tuple or
+/// array, which is the concatenation of the encodings of its components,
+/// injective as well.
+///
+/// Integers are encoded as a single element. Byte strings are packed 31 bytes
+/// per element in little-endian order, with the length prefix for byte slices
+/// and strings committing to the number of bytes, not elements. `decaf377`
+/// elements are encoded as their compressed field element, and [`Encoding`]s as
+/// their 32 bytes.
+pub trait ToFieldElements {
+    /// Append the encoding of `self` to `output`.
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E);
+}
+
+/// A value that can be hashed directly.
+///
+/// With the `derive` feature enabled, this can be derived for structs whose
+/// fields implement [`ToFieldElements`] using `#[derive(PoseidonHash)]`, which
+/// also derives a [`ToFieldElements`] encoding committing to the number and
+/// order of the fields.
+#[cfg(feature = "all-rates")]
+pub trait PoseidonHash: ToFieldElements {
+    /// Hash `self` with the provided `domain_separator`, as with [`hash_object`].
+    fn poseidon_hash(&self, domain_separator: &Fq) -> Fq {
+        hash_object(domain_separator, self)
+    }
+}
+
+impl ToFieldElements for Fq {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        output.extend(Some(*self));
+    }
+}
+
+impl ToFieldElements for bool {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        output.extend(Some(Fq::from(*self as u64)));
+    }
+}
+
+impl ToFieldElements for u16 {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        output.extend(Some(Fq::from(*self as u64)));
+    }
+}
+
+impl ToFieldElements for u32 {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        output.extend(Some(Fq::from(*self as u64)));
+    }
+}
+
+impl ToFieldElements for u64 {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        output.extend(Some(Fq::from(*self)));
+    }
+}
+
+impl ToFieldElements for u128 {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        output.extend(Some(Fq::from_le_bytes_mod_order(&self.to_le_bytes())));
+    }
+}
+
+impl ToFieldElements for usize {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        (*self as u64).append_field_elements(output);
+    }
+}
+
+impl<const N: usize> ToFieldElements for [u8; N] {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        append_bytes(self, output);
+    }
+}
+
+impl ToFieldElements for [u8] {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        self.len().append_field_elements(output);
+        append_bytes(self, output);
+    }
+}
+
+impl ToFieldElements for str {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        self.as_bytes().append_field_elements(output);
+    }
+}
+
+impl<T: ToFieldElements, const N: usize> ToFieldElements for [T; N] {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        for item in self {
+            item.append_field_elements(output);
+        }
+    }
+}
+
+impl<T: ToFieldElements> ToFieldElements for [T] {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        self.len().append_field_elements(output);
+        for item in self {
+            item.append_field_elements(output);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ToFieldElements> ToFieldElements for alloc::vec::Vec<T> {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        self.as_slice().append_field_elements(output);
+    }
+}
+
+impl<T: ToFieldElements + ?Sized> ToFieldElements for &T {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        (**self).append_field_elements(output);
+    }
+}
+
+impl ToFieldElements for Element {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        output.extend(Some(self.vartime_compress_to_field()));
+    }
+}
+
+impl ToFieldElements for Encoding {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        self.0.append_field_elements(output);
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($name:ident)+) => {
+        impl<$($name: ToFieldElements),+> ToFieldElements for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+                let ($($name,)+) = self;
+                $($name.append_field_elements(output);)+
+            }
+        }
+    };
+}
+
+impl_tuple!(A);
+impl_tuple!(A B);
+impl_tuple!(A B C);
+impl_tuple!(A B C D);
+impl_tuple!(A B C D F);
+impl_tuple!(A B C D F G);
+impl_tuple!(A B C D F G H);
+
+/// Append `bytes` packed into elements of [`BYTES_PER_ELEMENT`] bytes each.
+fn append_bytes<E: Extend<Fq>>(bytes: &[u8], output: &mut E) {
+    output.extend(
+        bytes
+            .chunks(BYTES_PER_ELEMENT)
+            .map(Fq::from_le_bytes_mod_order),
+    );
+}
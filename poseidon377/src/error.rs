@@ -0,0 +1,133 @@
+/// Errors arising from misuse of the [`SafeSponge`](crate::SafeSponge) API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SafeError {
+    /// The IO pattern is empty or contains an operation of length zero or more than `2^31 - 1`.
+    InvalidIoPattern,
+    /// A call did not match the next operation declared in the IO pattern.
+    IoPatternMismatch,
+    /// The sponge was finished before all operations in the IO pattern were performed.
+    IoPatternIncomplete,
+}
+
+impl core::fmt::Display for SafeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            Self::InvalidIoPattern => "Invalid IO pattern",
+            Self::IoPatternMismatch => "Call does not match the IO pattern",
+            Self::IoPatternIncomplete => "IO pattern not completed",
+        };
+
+        msg.fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SafeError {}
+
+/// Errors arising from decoding a [`HashOutput`](crate::HashOutput).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputError {
+    /// The encoding is not 32 bytes, or 64 hex digits, long.
+    InvalidLength,
+    /// The encoding contains a character that is not a hex digit.
+    InvalidHex,
+    /// The encoding is not the canonical encoding of an element of [`Fq`](crate::Fq).
+    NonCanonical,
+}
+
+impl core::fmt::Display for OutputError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            Self::InvalidLength => "Invalid hash output length",
+            Self::InvalidHex => "Invalid hex digit in hash output",
+            Self::NonCanonical => "Non-canonical hash output encoding",
+        };
+
+        msg.fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutputError {}
+
+/// Errors arising from opening a ciphertext with the [`aead`](crate::aead) functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AeadError {
+    /// The tag does not match the key, nonce, associated data and ciphertext.
+    InvalidTag,
+    /// The ciphertext is not a valid encoding of elements of [`Fq`](crate::Fq).
+    InvalidCiphertext,
+}
+
+impl core::fmt::Display for AeadError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            Self::InvalidTag => "Invalid authentication tag",
+            Self::InvalidCiphertext => "Invalid ciphertext encoding",
+        };
+
+        msg.fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AeadError {}
+
+/// Errors arising from building or querying a [`merkle`](crate::merkle) tree.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MerkleError {
+    /// The depth of the tree is larger than [`MAX_DEPTH`](crate::merkle::MAX_DEPTH).
+    InvalidDepth,
+    /// There are more leaves than positions in the tree.
+    TooManyLeaves,
+    /// The index is not a leaf position of the tree.
+    IndexOutOfRange,
+    /// The index is too far past the last filled leaf of a [`Tree`](crate::merkle::Tree).
+    IndexPastEnd,
+    /// The bytes are not the wire encoding of a proof.
+    InvalidEncoding,
+}
+
+impl core::fmt::Display for MerkleError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            Self::InvalidDepth => "Invalid Merkle tree depth",
+            Self::TooManyLeaves => "Too many leaves for the Merkle tree depth",
+            Self::IndexOutOfRange => "Leaf index out of range",
+            Self::IndexPastEnd => "Leaf index too far past the last filled leaf",
+            Self::InvalidEncoding => "Invalid Merkle proof encoding",
+        };
+
+        msg.fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MerkleError {}
+
+/// Errors arising from a [`StoredTree`](crate::merkle::StoredTree).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StoreError<E> {
+    /// The tree could not be built or queried.
+    Merkle(MerkleError),
+    /// The store failed.
+    Store(E),
+}
+
+impl<E> From<MerkleError> for StoreError<E> {
+    fn from(error: MerkleError) -> Self {
+        Self::Merkle(error)
+    }
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for StoreError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Merkle(error) => error.fmt(f),
+            Self::Store(error) => write!(f, "Node store error: {}", error),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error for StoreError<E> {}
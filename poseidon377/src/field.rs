@@ -0,0 +1,92 @@
+//! Poseidon instances over prime fields other than [`Fq`](crate::Fq).
+//!
+//! The parameters of the instances of this crate are typed with [`Fq`](crate::Fq), the
+//! BLS12-377 scalar field. Instances over other fields, such as those of the
+//! [`fp`](crate::fp) module, are described by [`FieldParameters`] instead, which is
+//! generic over the arkworks type of the field. Their constants are derived by
+//! `poseidon-paramgen` with the same transcript procedure, and the permutation is the
+//! unoptimized Poseidon permutation, which computes the same function as the optimized
+//! permutation of [`Instance`](crate::Instance).
+//!
+//! The S-box of an instance is `x^alpha` for the smallest `alpha` coprime to `p - 1`,
+//! so that it is a permutation of the field. Small exponents are cheaper: in R1CS,
+//! `x^3` costs 2 constraints, `x^5` costs 3 and the `x^17` of the instances over
+//! [`Fq`](crate::Fq) costs 5. The instances over the BLS12-377 base field, BN254,
+//! BLS12-381 and the Pasta fields use `x^5`. `x^3` is not a permutation of any of
+//! these fields, since `3` divides `p - 1`; the [`fp255`](crate::fp255) module provides
+//! instances with `x^3` and with `x^5` over a field where both are.
+
+use ark_ff::PrimeField;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+#[cfg(feature = "r1cs")]
+mod r1cs;
+
+/// Parameters of a Poseidon instance of width `STATE_SIZE` over the field `F`.
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug)]
+pub struct FieldParameters<F: 'static, const STATE_SIZE: usize> {
+    /// Security level in bits.
+    pub M: usize,
+
+    /// The S-box of the instance.
+    pub alpha: Alpha,
+
+    /// Number of full and partial rounds.
+    pub rounds: RoundNumbers,
+
+    /// Round constants, with one row of `STATE_SIZE` constants per round.
+    pub arc: &'static [[F; STATE_SIZE]],
+
+    /// MDS matrix.
+    pub mds: [[F; STATE_SIZE]; STATE_SIZE],
+}
+
+impl<F: PrimeField, const STATE_SIZE: usize> FieldParameters<F, STATE_SIZE> {
+    /// Apply the Poseidon permutation to `state`.
+    pub fn permute(&self, state: &mut [F; STATE_SIZE]) {
+        for (r, constants) in self.arc.iter().enumerate() {
+            for (word, constant) in state.iter_mut().zip(constants) {
+                *word += constant;
+            }
+
+            if self.is_full_round(r) {
+                for word in state.iter_mut() {
+                    *word = self.sbox(*word);
+                }
+            } else {
+                state[0] = self.sbox(state[0]);
+            }
+
+            *state = core::array::from_fn(|i| {
+                self.mds[i]
+                    .iter()
+                    .zip(state.iter())
+                    .map(|(m, word)| *m * word)
+                    .sum()
+            });
+        }
+    }
+
+    /// Fixed width hash from n:1, permuting `state` and returning its first rate word.
+    ///
+    /// As for the instances over [`Fq`](crate::Fq), the first word of `state` is the
+    /// domain separator, and the others are the input.
+    pub fn n_to_1_fixed_hash(&self, mut state: [F; STATE_SIZE]) -> F {
+        self.permute(&mut state);
+        state[1]
+    }
+
+    /// Whether round `r` is a full round, as the first and last `r_F / 2` rounds are.
+    fn is_full_round(&self, r: usize) -> bool {
+        let half_full_rounds = self.rounds.full() / 2;
+        r < half_full_rounds || r >= half_full_rounds + self.rounds.partial()
+    }
+
+    fn sbox(&self, word: F) -> F {
+        match self.alpha {
+            Alpha::Exponent(exp) => word.pow([exp as u64]),
+            Alpha::Inverse => word.inverse().unwrap_or_else(F::zero),
+        }
+    }
+}
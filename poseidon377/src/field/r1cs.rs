@@ -0,0 +1,54 @@
+use ark_ff::PrimeField;
+use ark_r1cs_std::{fields::fp::FpVar, fields::FieldVar};
+use ark_relations::r1cs::SynthesisError;
+use poseidon_parameters::v1::Alpha;
+
+use super::FieldParameters;
+
+impl<F: PrimeField, const STATE_SIZE: usize> FieldParameters<F, STATE_SIZE> {
+    /// Apply the Poseidon permutation to `state` in R1CS, as
+    /// [`FieldParameters::permute`] does.
+    pub fn permute_var(&self, state: &mut [FpVar<F>; STATE_SIZE]) -> Result<(), SynthesisError> {
+        for (r, constants) in self.arc.iter().enumerate() {
+            for (word, constant) in state.iter_mut().zip(constants) {
+                *word += *constant;
+            }
+
+            if self.is_full_round(r) {
+                for word in state.iter_mut() {
+                    *word = self.sbox_var(word)?;
+                }
+            } else {
+                state[0] = self.sbox_var(&state[0])?;
+            }
+
+            // The MDS matrix is constant, so mixing only builds linear combinations.
+            let mixed: [FpVar<F>; STATE_SIZE] = core::array::from_fn(|i| {
+                self.mds[i]
+                    .iter()
+                    .zip(state.iter())
+                    .fold(FpVar::zero(), |acc, (m, word)| acc + word * *m)
+            });
+            *state = mixed;
+        }
+
+        Ok(())
+    }
+
+    /// Fixed width hash from n:1 in R1CS, as [`FieldParameters::n_to_1_fixed_hash`]
+    /// does.
+    pub fn n_to_1_fixed_hash_var(
+        &self,
+        mut state: [FpVar<F>; STATE_SIZE],
+    ) -> Result<FpVar<F>, SynthesisError> {
+        self.permute_var(&mut state)?;
+        Ok(state[1].clone())
+    }
+
+    fn sbox_var(&self, word: &FpVar<F>) -> Result<FpVar<F>, SynthesisError> {
+        match self.alpha {
+            Alpha::Exponent(exp) => word.pow_by_constant([exp as u64]),
+            Alpha::Inverse => unimplemented!("err: inverse alpha not implemented"),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Poseidon over the BLS12-377 base field.
+//!
+//! Recursive proof systems verify BLS12-377 proofs in circuits over its base field
+//! [`Fp`], which is the scalar field of BW6-761. This module provides the rate-1 to
+//! rate-4 instances of Poseidon over that field, with the same API as the instances
+//! over [`Fq`](crate::Fq), and R1CS gadgets for them with the `r1cs` feature.
+//!
+//! The parameters are generated by `poseidon-paramgen` with the same transcript
+//! procedure as those over [`Fq`](crate::Fq), for a security level of 128 bits. Since
+//! `5` is coprime to `p - 1`, the S-box is `x^5`, which is cheaper than the `x^17` of
+//! the instances over [`Fq`](crate::Fq).
+
+use crate::field::FieldParameters;
+
+#[cfg(feature = "r1cs")]
+pub mod r1cs;
+mod rate_1;
+mod rate_2;
+mod rate_3;
+mod rate_4;
+
+/// The BLS12-377 base field.
+pub use ark_bls12_377::Fq as Fp;
+
+/// Parameters for the rate-1 instance of Poseidon over [`Fp`].
+pub const RATE_1_PARAMS: FieldParameters<Fp, 2> = rate_1::RATE_1_PARAMS;
+
+/// Parameters for the rate-2 instance of Poseidon over [`Fp`].
+pub const RATE_2_PARAMS: FieldParameters<Fp, 3> = rate_2::RATE_2_PARAMS;
+
+/// Parameters for the rate-3 instance of Poseidon over [`Fp`].
+pub const RATE_3_PARAMS: FieldParameters<Fp, 4> = rate_3::RATE_3_PARAMS;
+
+/// Parameters for the rate-4 instance of Poseidon over [`Fp`].
+pub const RATE_4_PARAMS: FieldParameters<Fp, 5> = rate_4::RATE_4_PARAMS;
+
+/// Hash a single [`Fp`] element with the provided `domain_separator`.
+pub fn hash_1(domain_separator: &Fp, value: Fp) -> Fp {
+    RATE_1_PARAMS.n_to_1_fixed_hash([*domain_separator, value])
+}
+
+/// Hash two [`Fp`] elements with the provided `domain_separator`.
+pub fn hash_2(domain_separator: &Fp, value: (Fp, Fp)) -> Fp {
+    RATE_2_PARAMS.n_to_1_fixed_hash([*domain_separator, value.0, value.1])
+}
+
+/// Hash three [`Fp`] elements with the provided `domain_separator`.
+pub fn hash_3(domain_separator: &Fp, value: (Fp, Fp, Fp)) -> Fp {
+    RATE_3_PARAMS.n_to_1_fixed_hash([*domain_separator, value.0, value.1, value.2])
+}
+
+/// Hash four [`Fp`] elements with the provided `domain_separator`.
+pub fn hash_4(domain_separator: &Fp, value: (Fp, Fp, Fp, Fp)) -> Fp {
+    RATE_4_PARAMS.n_to_1_fixed_hash([*domain_separator, value.0, value.1, value.2, value.3])
+}
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use ark_ff::PrimeField;
+
+    use super::*;
+
+    fn fp(s: &str) -> Fp {
+        Fp::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn fp_params_are_consistent() {
+        assert_eq!(RATE_1_PARAMS.arc.len(), RATE_1_PARAMS.rounds.total());
+        assert_eq!(RATE_2_PARAMS.arc.len(), RATE_2_PARAMS.rounds.total());
+        assert_eq!(RATE_3_PARAMS.arc.len(), RATE_3_PARAMS.rounds.total());
+        assert_eq!(RATE_4_PARAMS.arc.len(), RATE_4_PARAMS.rounds.total());
+
+        // The MDS matrix is the Cauchy matrix `1 / (i + t + j)`.
+        let mds = RATE_2_PARAMS.mds;
+        for (i, row) in mds.iter().enumerate() {
+            for (j, m) in row.iter().enumerate() {
+                assert_eq!(*m * Fp::from((i + 3 + j) as u64), Fp::from(1u64));
+            }
+        }
+    }
+
+    #[test]
+    fn fp_hashes() {
+        let domain_sep = Fp::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let [a, b, c, d] = [1u64, 2, 3, 4].map(Fp::from);
+
+        assert_eq!(
+            hash_1(&domain_sep, a),
+            fp("29633041120596010194818009084360680535403545717916459282639511089612657405391274112057462481843324299654785702395")
+        );
+        assert_eq!(
+            hash_2(&domain_sep, (a, b)),
+            fp("38092458164043883782344569705758772998999074435931908928054971559137458692154785512352971306841941484612903656013")
+        );
+        assert_eq!(
+            hash_3(&domain_sep, (a, b, c)),
+            fp("151470475178200805081325502900209159197168427505214496868756716103448373459770898835101432649574387414531361510645")
+        );
+        assert_eq!(
+            hash_4(&domain_sep, (a, b, c, d)),
+            fp("172675468631869545565277046189066432991698135116326409755016706044447830927041702778746936596875585625857152162513")
+        );
+    }
+}
@@ -0,0 +1,280 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fp;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-1 instance of Poseidon over [`Fp`].
+pub const RATE_1_PARAMS: FieldParameters<Fp, 2> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!("175705853156336778222642836125549430739280332316420110315608764160897834774585796527467528298984693495197737431588"),
+            MontFp!("174370849577975762282036307111213621119555757398940067702345834624094576888288551456773999505188010765182490405469"),
+        ],
+        [
+            MontFp!("138917503524168472164034053315243671765429109708230276928778751996131038590267814084958144820463842420691232909177"),
+            MontFp!("52867089521603480424690108256487686564114818363119905031546618491758989562741754902566726162594106865576740936812"),
+        ],
+        [
+            MontFp!("51591494066997845044093259219927318976026996222376514784667650446129419452723764565525879448952652216382538790539"),
+            MontFp!("258528949069060983696192699886289490941391408836721563788773850682656344068571380613717288316916551700475978499130"),
+        ],
+        [
+            MontFp!("207458799911979631020941545801254867495195615439046462093226942519880869448371450102578482667663471488724990437988"),
+            MontFp!("229624618273122293351656608167487160133293648812966269279210346065610024768744764148105658871438578443601671197122"),
+        ],
+        [
+            MontFp!("171260978814035977146799242404953888548582347270339030653508333692512784737980871455389870276880395531274115303001"),
+            MontFp!("49540635562508183284001428054449566681397549655310288817949189006599474386683746933191916925367861061548408461192"),
+        ],
+        [
+            MontFp!("139662264817337272842606114359900655294027927034873579507443019540679081563812337344082507723625634752789095691122"),
+            MontFp!("166272124543582490297889345333307908136573008603156031690933055074412009900844354678275279139105972534744951060563"),
+        ],
+        [
+            MontFp!("153632806409436000110566802994281380912209545664679166208016002462840177784700084107748341687076320943195673947089"),
+            MontFp!("241855771496074895611534498877597864652000482079041881575077802891530591832459234984149064293757734606487437442026"),
+        ],
+        [
+            MontFp!("235047087877963495064687367499787420585385198166363314114361141717622875829913714240179509789411026936288380193228"),
+            MontFp!("84960413525066072990603264059932533932925342926021802895767775801634529214844636291359963807003845074277210255453"),
+        ],
+        [
+            MontFp!("3081409138617368103556180108549990580445534805004321167370921958662213948467420713855592615182134232276373151035"),
+            MontFp!("181007356439779729537509573764355228461069713861005177901516733465886857265031036564668921973050586261490973889099"),
+        ],
+        [
+            MontFp!("258238495394940794028948170463609445802425666645977806591794861923361075604272163451483384992064985443018690623195"),
+            MontFp!("45316526403280410582891359376602414043974642678257663363006516620055113062784874380584243320684622029960761817780"),
+        ],
+        [
+            MontFp!("92470307405878953297181263611965302558675892755382408029827543920240551882621712671537492337043047128104893466091"),
+            MontFp!("255978884624492284373957680709017506692560072860295198665937748292020673404723260308388793419784333132509606001445"),
+        ],
+        [
+            MontFp!("131547559437105575634913649359671322271261381092516319102756123845498185185895704235381300031146633413798994360263"),
+            MontFp!("154831909133737613820888879869679444020605011166000173968506682886160346728044677995887115810382776241143644282558"),
+        ],
+        [
+            MontFp!("126185275414190066957930416784662651525845077602790547154607397255868611220311035101467105498663203019490251415142"),
+            MontFp!("41920400156832396987295540511625727166835968816438258698356409075478527261146814984104905630392332594890004969361"),
+        ],
+        [
+            MontFp!("224150182559777179012171845070572666701744087902941948045355502527346753432807872713711778422340547091478172600817"),
+            MontFp!("158107972361419723352525784474017156466876872617659550853283433513575041056667270514290253311298519063830163469998"),
+        ],
+        [
+            MontFp!("152459717550389950858288364756306627654957249045992457222678453437437724767353887530217986040705743907086829112328"),
+            MontFp!("127192167553352556718950216459453077309858887417038821667458467833987242180576704543395776430951910629968868655683"),
+        ],
+        [
+            MontFp!("86575303818678990989422843240399302294030424472561406164627121514943110102664672190649554028932628764516419990345"),
+            MontFp!("10899780025152396303200375074419012058758892406185815339624762066561786430962360360765457757283410863862859193994"),
+        ],
+        [
+            MontFp!("64686977566947010082716354073070291857424352138942959020877358965186044155070269744732967707838776237799462793545"),
+            MontFp!("11217631908128706837334404836605735796306200158409409520334163666717231893556068317967866028541316119905598428474"),
+        ],
+        [
+            MontFp!("146013452857239845178930924465659868161916951609238366556940557204296221387323586377713625290169621207773756670922"),
+            MontFp!("196749864113077474042445713849735068333127417020607861984050275346946080541817562404608110953044875380158244869865"),
+        ],
+        [
+            MontFp!("211153282413175250216919900887186150924869152748377130081465120441493089144704834963144822072813102356795238364938"),
+            MontFp!("133594401016085901683066906860733094380504910033722492441069279257417214174690892641190995578308493719138965809602"),
+        ],
+        [
+            MontFp!("164832696921326919597178726250287673367008737255505293821023119784743092748691113930719901120990893490210582731773"),
+            MontFp!("207427864978755507639650646583570998364238930492990144860859983072153896078090097711931507025080420902068150547520"),
+        ],
+        [
+            MontFp!("118678921009584204403945974235275925432000410322066321198892415168324186314280966989890262136444768593795139640774"),
+            MontFp!("93145914635750362180567410399542457169793676422863351431429308267970421559076798452772343208772796995907714521164"),
+        ],
+        [
+            MontFp!("90120652245049672657055530866113592952445893975675779139653561148067736403573546153959298755877234847956612037764"),
+            MontFp!("201380805769553587822152203440825450487373495294232765270685658354667411434536846391500891051748780070089068381982"),
+        ],
+        [
+            MontFp!("249215251470251929229840114438796580394329960462767130415794683872871189747589787705958806108574977751595120346994"),
+            MontFp!("104291486476353930460365848794163707015365308486720223879218400038081462911273813644007184692125650372222625774559"),
+        ],
+        [
+            MontFp!("103406437708691301917804565321762307805195142103053355614285932222145601718438618774214298539854017179039820232948"),
+            MontFp!("91928426785041652607773562364903885655817874230587452286918596295184843236052341228321862432200247671789614290900"),
+        ],
+        [
+            MontFp!("212907950417394640651399077325866474380854725795291999104648322894930208150874805497650556379138005814582825582605"),
+            MontFp!("98954817521742817699674534564766840158555904755416769479096473981335608510882104053956894057870709987805320710332"),
+        ],
+        [
+            MontFp!("255544350740599499602729969110102570985544977576080208833790681852862019085869383950760723076377439328934427485862"),
+            MontFp!("104762409942690690117823477223372655981970365593252100689059556594626081291813972189152333260701620396471080483962"),
+        ],
+        [
+            MontFp!("33477365811622225929812467683651127460558226844747937828534909137991469612601570585695216358375945916152577217716"),
+            MontFp!("16278471143916937029567616841188156046568700764075249640303309045624834215200673224592122205484554266949251849405"),
+        ],
+        [
+            MontFp!("55273730401291827387154473898054172436569237859117462839656032793138968187683372952666728637116570359571976074701"),
+            MontFp!("26634557353395648800743177040087899970432068758156386291055853186044572832080104151309787913232434013649366009099"),
+        ],
+        [
+            MontFp!("81601855360775778367584945074115907083541288823538733701732179333827598124840050227585525772319311766623777529354"),
+            MontFp!("26959783585619616772645099382456717375253821931462472786146361021304252036610813853073878958543294969401751112233"),
+        ],
+        [
+            MontFp!("220483173993833519073513393034120245748308151466954358690378069961119859668057496656518708619414981683468552648177"),
+            MontFp!("166753498047474487253070309113857615279640024965881077472586921711144274162662815778894144285543824840933450998032"),
+        ],
+        [
+            MontFp!("48195068006825794777297256491311210446455132775519811799159052009672071553179503170539481120950027425137297379342"),
+            MontFp!("237673331128624147045030622350787880140876997496439187422360073972595770937744624673580550506533694130053111925022"),
+        ],
+        [
+            MontFp!("43518536564303377629302526071979428721089537209838944626126778327183825499487665519755962670596996516395921218981"),
+            MontFp!("137490961656703367474849205854888052865984677736519853683287140353558774281018353800015223358696754532098839397475"),
+        ],
+        [
+            MontFp!("112839698768314261935856088861991944817876565177325518322718759982483239955196484572524054338041007906774626995276"),
+            MontFp!("119489807909053040535184544041537226402196777556216690886646240957091046349839026406999185182282909045908819947931"),
+        ],
+        [
+            MontFp!("22237254363687172349616533909272666217799240512182285796622964673874289532135972752970123155222782752910586314161"),
+            MontFp!("53037026335179693413580556077315967153584758304620967090226251447769393105257145410764088475772111334146820220114"),
+        ],
+        [
+            MontFp!("1085094670130032345001573646067462760971908025336874222685555807371268542661163051988939193420572871504304956895"),
+            MontFp!("222911665412599578911091892108392086007371493672156114515068502440339390324292509461431250413912256406057153261224"),
+        ],
+        [
+            MontFp!("177893311854221290273332491425927301003715217622796816618651150946085964527386117711733056859984014624322356389554"),
+            MontFp!("13438092232782813270491426259283985108522124079670511482807559947142565830112546733517100952314984170823810187385"),
+        ],
+        [
+            MontFp!("133111048534245003966868432147094292319073241731297353536442039137128226512557008872805503237836745681953157499632"),
+            MontFp!("90022504645833210416123196918036218429937912409711483340710366248069295952209137092894439433486837411537475744722"),
+        ],
+        [
+            MontFp!("227579071312882255673586653725843543801373702781725152929822782675692288695210472431859932232897918067817712082094"),
+            MontFp!("120890574665469762510662560381429427707845107856452748170199947041726008983122689983411077661748017935350340132735"),
+        ],
+        [
+            MontFp!("14938197543120837996189356119319497029934241446448150790357204731243165716878203552570022718493974791134267207331"),
+            MontFp!("229423939641939227735934822821958687549761004261100872941738491913525952836793201713481693805822834118256510071135"),
+        ],
+        [
+            MontFp!("139579101541619415940259153898151025195592777766152555987097743454997133406731419027806935674968583046153092880854"),
+            MontFp!("172121360443719575573637188706809703478391417852479146975254724233367025629278417606145413698828869858814065719470"),
+        ],
+        [
+            MontFp!("205502250403422758615325687711509856433656774471981841775894729181418086128260977120596580709658627576031912047961"),
+            MontFp!("186954286229365143304398740713505454498476762903225413379387504232480193136709489025855185855208390355473742537444"),
+        ],
+        [
+            MontFp!("27911545925604625422103370109240773592302993472817126342852663899833026563447634528015640308802793798311351856936"),
+            MontFp!("43434684181697304422779487368473310135737126222379611883664379010668886875948580971046479212839824197878388391331"),
+        ],
+        [
+            MontFp!("71171912906232361964332088887557056413655117279001781181161193627771085057123567148997789960195328234768847014107"),
+            MontFp!("109676435830086722160141621344577902256363188395497487626834356005905518132361248347549657303778838773766182121534"),
+        ],
+        [
+            MontFp!("229298160549701890141391946024341219299018667359094309241155298394034552117308193186535194408052561331564368396831"),
+            MontFp!("13456299277545015597238053650392911126649272820948647386539875573460118776144407519593232329124952716323259244340"),
+        ],
+        [
+            MontFp!("14460778349803277563100751061701672080443246878893331178865156218332397301054376880998036712861966174621731737342"),
+            MontFp!("44427485512166928378612332009778505148307413430331338155693039362042619661484749744524196775485231959662856551319"),
+        ],
+        [
+            MontFp!("160954143325909012631616387798156901149354401926574403255156935889400243636062712580351141569352787907502540597375"),
+            MontFp!("191117393618525519692478779738316818612333324461202523142398127460528059866483700173215857867359668278491357779753"),
+        ],
+        [
+            MontFp!("185343003185820157050604696967914161327770251955959954393248212558419573638316590288870929096174608533454634290719"),
+            MontFp!("106320588408745651888158186745107243044547396968517097944020637596327175291445950356787281615969739891248264982079"),
+        ],
+        [
+            MontFp!("104637207154357460139278205757500456758387861765742626515441372878613309528666912045726137950104316138549532472776"),
+            MontFp!("127484633747991673971617896067122220778690388563633039855314747549676641227130026805161447943759944742170510504042"),
+        ],
+        [
+            MontFp!("257773868310804188038769966682830833625335852341896270268105613739181813448193560253836230804006170770814209445545"),
+            MontFp!("67955935108177321068108200565470172808077497879865060785846495503322444583678914567763486585895191048255803655157"),
+        ],
+        [
+            MontFp!("92507606798722958618882276515321238570440415409916865374357649306689942964689598388031296243145816702884816403385"),
+            MontFp!("42718561021176788777643440409439089806898073355218048030733936683547458104466844055313093941227067155616399578168"),
+        ],
+        [
+            MontFp!("155148405612167560276467620896587501568167856130853043961948427011291257042672841264382173830529655877726335754832"),
+            MontFp!("37695397191847951414100280766625743296974702371988061056334658924211196087311321603043194316722020543910469956710"),
+        ],
+        [
+            MontFp!("237837815316848998183177309881703711758319313016334410998744379504638234079384481759253954029659842812650196686198"),
+            MontFp!("97653968868191263769290890642088862273549258650734282801000044086126022431323371987373453569235326495392206327983"),
+        ],
+        [
+            MontFp!("125794747377359042136733092676503135237025577454649027310459191807470614805931950318001925071438982863710738929440"),
+            MontFp!("84593349618848821524365043901919868114391772660366855659803107149978839842708789580518513327063287601875107180412"),
+        ],
+        [
+            MontFp!("79548661466729225192359872245328217622499645833923965745634596091432252034891615718057490227430582676579189272652"),
+            MontFp!("67199339462315987446129248437013336905139309163822698393623654848354334890331971895648381523997732943197251625591"),
+        ],
+        [
+            MontFp!("108036712595573870084839684740072550373591305342024245462095668823670834882962551429580215453803847360077359632463"),
+            MontFp!("215448332250578859350835181788803995561762486810461931356245275834491598867069483716549350204768700073456668911599"),
+        ],
+        [
+            MontFp!("167017831575578205687654288861734382072895685382905035451746304122160773264252521118486210422076642008271994533203"),
+            MontFp!("33190970661750253821385038560570577107602051742649695836884392383167638400324417226101620525446077320086990057027"),
+        ],
+        [
+            MontFp!("238481274353929296038420865443879564531999727462017337401794788397898128980671283317507582420746874974246219354961"),
+            MontFp!("178930367712934865857707184238233773648540916266903679670021930308506211893128090449083252889488522735575671834200"),
+        ],
+        [
+            MontFp!("78985136652292829194565947979029106844542171401990480221557340789487513559109859026373390630547294316821547408434"),
+            MontFp!("125801165974568096837250529907182314224827941524618383839806134822582554201881719274250166902940988034731739319021"),
+        ],
+        [
+            MontFp!("51128226110817362834958741975065901890240893806037506664142691732063067081856896694850493727270718736429025954000"),
+            MontFp!("19845794383585537319538403075384509095806447955332217405014197649217361509961302415714292999407936581982849561628"),
+        ],
+        [
+            MontFp!("108606226410896214774314598672991133424585079038655869157772222233693503110444323069795462239572378590095954899211"),
+            MontFp!("148162938022281356131337749426003587932046396745669189995224892048168548523897946687985663164532945247990575293206"),
+        ],
+        [
+            MontFp!("156855290557216832029146063454698575173530009474959818890123538746024589528756697928399810099998202874900429871544"),
+            MontFp!("156005380420388812042188443146994499153793252487884705400936631459735307252844574715392144000310888794354346993403"),
+        ],
+        [
+            MontFp!("125953438435665905526926719294120570204364012448516052951924678109967254860895787787969255809608987399102261736240"),
+            MontFp!("157200799131094132074241970945981678330523278892289407175410335679977685314655901845836196881179788172151694788700"),
+        ],
+        [
+            MontFp!("39293371923920145392289680729131121587637571347030170321513858609811507297681004309648946411977814448433388090919"),
+            MontFp!("178027418018313256083663037155233152037708226916544231880805199154476310271594723521952711861112213696847137041987"),
+        ],
+        [
+            MontFp!("198906337982049842990792346623580512872282179649968386881254947669130349900367546984038215280848300564686720270013"),
+            MontFp!("11076639547386092150910221703498619551060519912221926523770636402646611840263601446286304824657259889172072007086"),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!("129332213006484547005326366847446766768196756377457330269942131333360234174170411387484444069786680062220160729089"),
+            MontFp!("172442950675312729340435155796595689024262341836609773693256175111146978898893881849979258759715573416293547638785"),
+        ],
+        [
+            MontFp!("172442950675312729340435155796595689024262341836609773693256175111146978898893881849979258759715573416293547638785"),
+            MontFp!("193998319509726820507989550271170150152295134566185995404913197000040351261255617081226666104680020093330241093633"),
+        ],
+    ],
+};
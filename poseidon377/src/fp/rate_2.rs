@@ -0,0 +1,351 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fp;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-2 instance of Poseidon over [`Fp`].
+pub const RATE_2_PARAMS: FieldParameters<Fp, 3> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!("92925693594495927315641497602388827717667275975650801515294180210120198719497839677660542275191365638355760333480"),
+            MontFp!("76010249320388427600601630648584709407425288897777678862278404770209333581275950202720643406833863855693568966305"),
+            MontFp!("166676867262752032121509545918553502004469087061780093069754104159019917675216041700158305163021445195720330126297"),
+        ],
+        [
+            MontFp!("236145110862606559369493665120792868931236727793397370352103831938213524016919663955330091156609111618822583838504"),
+            MontFp!("179506842304301657338929897154999378426774916834911764150226973014685833201243225298476858278841344047776311379962"),
+            MontFp!("216213338744697500871091848740240338623729147779621699478615745430767276310367389174210752820455891207429109755124"),
+        ],
+        [
+            MontFp!("93466742416006806867307900201250858609026914229684902112821153314947538100593656998437675928450414237720868059189"),
+            MontFp!("110418148290909364158550259827502312118779589596553518903018964283493396188124205649244125326876867630232667362248"),
+            MontFp!("77911004814924969073316398031508718708874010938235190384955710477343628084316826837972431909330494666086730168297"),
+        ],
+        [
+            MontFp!("156583238822705780255178137924041137833200472534116898003631465305569901253399062852967992460824323829032494443047"),
+            MontFp!("144372753289735405043577530112398063024512212683987907543686769187043505084732474237679907496523174722010184352142"),
+            MontFp!("192091754393975628791101564769168707941353155649828064825484992963189900775576767478122457693388324144436057664931"),
+        ],
+        [
+            MontFp!("75026113953370294780029345700906181944909140784604234501172197625077102706613934151003495625898011587146480424976"),
+            MontFp!("51642249254865333458775081357626072881757574136033263299121398064984929528131806650748485329246399430630837291073"),
+            MontFp!("206614364870574842337890266643807052137243626305399369504092345985635692044473396679705621046716077038734164272536"),
+        ],
+        [
+            MontFp!("60082883765513160525164561451093869459023143722067221914579589221902692329972198150870556385652284848426061768471"),
+            MontFp!("176999997866956618551110271109591976283835361880100579449678071027922935274884554910461605252935436169323268267264"),
+            MontFp!("252579131579495805131755366833392082155899503263962814116745069624448546273400833484901632213918068255112264381093"),
+        ],
+        [
+            MontFp!("80539712390991493453585654112064522116329844473580186574975952922700608049775648083474360641700861466875485218400"),
+            MontFp!("212206427791802374465313858249107794904016855180486854701204123717384466193672204588377934892569560805718511964730"),
+            MontFp!("25412012967901695452450657133419260629699479202484217869527021679497581586509720448567675233960751945420679079658"),
+        ],
+        [
+            MontFp!("124314304632282168096592537164466432166693829042495045252016598180802538753651574904065780400523695783967198447491"),
+            MontFp!("161567571076640700524954058173399118751038387500657646570723357717836733604474114470108095329591415472979924630480"),
+            MontFp!("9880268169387931647799971579407005361114410663802529632328106804997833179187217021836784009378543978618833927344"),
+        ],
+        [
+            MontFp!("3779726557553135637978132529668881629910170785017673965026778692010771972227670243042295603610348681894952872136"),
+            MontFp!("238530097796244970751774470285069745812301731213660273114722972953053137196489795075197626412602390632224514967879"),
+            MontFp!("203080938232496318395097786344945027507402215395590424551692304824005682739755304905960253358225865299050647111122"),
+        ],
+        [
+            MontFp!("51510488624137139776951722267653307222711063933666094520381033065473767083358500601508157970597889617513345986416"),
+            MontFp!("196653203037165309555170181333093934778824090095885083146161822441168664534742768656597882006795971830056552346213"),
+            MontFp!("75471311837046178680757774194430263696828789993580407934996759372567892972715914261881049584071512571665769261454"),
+        ],
+        [
+            MontFp!("87084223872183377355677042298043918723363147302469994582525528630265169523119299256009844447962932996894662198779"),
+            MontFp!("96551772524435823261668286780635092307515720578150968925997179969243939463927949796389846876124117177953299156699"),
+            MontFp!("191958069149087449574825616828909603005230332765877199718798728825459931880925917363273587766906614777961866024656"),
+        ],
+        [
+            MontFp!("65806788023271635451772326140694800037367467060237095654366530880164544351696673776447946537568072791891792321425"),
+            MontFp!("152576575136930931336132820008261751239508691700233788685007912603413684460436245846481466112594537878391242860547"),
+            MontFp!("185004182687629816497146097560797190303507076629100316184865120476056683555985131596079073379541485385340473058933"),
+        ],
+        [
+            MontFp!("141180767900078254093837563419837452283819405948410158016248169704969573883937477307608813288851645543285436895040"),
+            MontFp!("203929260516304355055113364717218509124656100837354056560560447700652636443292649552022300286120401998144712524512"),
+            MontFp!("199579121343403409985671393524845802877475301932096402388376098011813835083364674085473249995504172746185363862817"),
+        ],
+        [
+            MontFp!("104321068312248015049494706144882379756942092787725587700838765381413646218551778128778706083817830745202222230775"),
+            MontFp!("35133652622005933878217152820100022429095021619957231893795078002730575442902906734498740812149865955485613978541"),
+            MontFp!("161970267780202499303819435695334536888637877680670459756791979023831662216261452371234626905898271046784429653725"),
+        ],
+        [
+            MontFp!("139447312529056790140485480575230475739895165211295726838312495557775461049477196909943872061921385919620744464223"),
+            MontFp!("110421278990116709535311868908919830521968333886390935196728463452351262666987986626657997470031518045802739730245"),
+            MontFp!("34948898448529176277489235267531474629407417805809335592912989093171581011284825015471110172712675160933134015312"),
+        ],
+        [
+            MontFp!("166386689366199609647422810169276969582216968254107510297200376925073299042405463881806503845978083366966648792943"),
+            MontFp!("41545172055355743873859814713391355437057947897740094691521995532323106579917232341867393796315318112511927402811"),
+            MontFp!("1005948544169785278814693143192497841270546421069027555544928780862501923887533524265082218472524590713706612466"),
+        ],
+        [
+            MontFp!("183202781125364073316808733340702337786679040202514223065450042132404028631954144056428685265053174886096518013098"),
+            MontFp!("185414745022391144125792778390534076253693438675602019965597090270325101844754076322378845168270872190119391375362"),
+            MontFp!("217160223756990418670367884769466033645723444543479533353716130234576495645738569200158174723415947368645542873932"),
+        ],
+        [
+            MontFp!("243041099417541185978452307058096087071266180906719755572805341484800822473878174803725507946509650534210492380500"),
+            MontFp!("171409076965393933439867345545002152585052507046413861589848441236834242610245340889427854797289344969789419024857"),
+            MontFp!("41293007301451697789405257650821486877224304063508874680301178750130502130364458492790244202407529072903989886836"),
+        ],
+        [
+            MontFp!("184567230448230611620190005232256853900789318568446060258973363605516107417740323328648713322114937320957980553145"),
+            MontFp!("213215173490999722111364228920182538041614537160765380442128795167778942458734542542052094247348679057703941515379"),
+            MontFp!("186797382769936573372966540407124131443830733056710397927084172390032289850101958089788904917309599067260604728440"),
+        ],
+        [
+            MontFp!("30634792663583588433556523936502396591948600455730790802243931859303760087910404102522848601385633536983217386205"),
+            MontFp!("112614877757292567873646280082762038746376521542289829497895057417856881193428202592324329923568257072230017350093"),
+            MontFp!("229981896934740336343246416684597819687930851120573847434226124555287065427451877198076851766084250981860054820588"),
+        ],
+        [
+            MontFp!("241200010028474474437400554444091334196164466274876833117456941629861482012757067011968209517333749592893234892778"),
+            MontFp!("55390236792499378337742807075218869015311555644414287683277849302798277511778866196595449611995094839096515017407"),
+            MontFp!("102735513905891314281877659276003161689027998179656700952742250284493000526312868568709235929528709176211867459592"),
+        ],
+        [
+            MontFp!("50568795642656442086291956216560803057264462778037708814024229047330506739956384307178270435135380972271899242614"),
+            MontFp!("180236343171674025402708109743179607728981429297443577408234519145361505678181390683979578330627588625165642348663"),
+            MontFp!("72410788060935816749606730163327441577672233501224434761739292392842159482426326299658600542707269440871074783888"),
+        ],
+        [
+            MontFp!("214434216890905955399930371045173596687808855000627675492421153213109904107505600012059031511757713669403207448964"),
+            MontFp!("76613983836171200796071262282441531430799168312010340753360428936358941610083511678667030902722155763029243701704"),
+            MontFp!("41723317503474946136604170095151125814953089659341473770509991108389295859912662335710293894140833751700024931805"),
+        ],
+        [
+            MontFp!("43822809075264044996453622460513963434340672733677872758885705774858832265676150057748654644154544568250181259584"),
+            MontFp!("46043099210875115411976528219045947656622415744774829419618115257157238943913506631745141242580662657672438221407"),
+            MontFp!("158108374243111513621203523861498282802624263479444665373110788559753128827514659848407307776469656047285130144866"),
+        ],
+        [
+            MontFp!("243676110113571087003942626660991554770969356309410578917569641822372861774325095445925678267671598603592155799983"),
+            MontFp!("177607766912085414283449923434322367676942168331430563478568716938505809937403928372285196724621456956019618318926"),
+            MontFp!("118029904116115737649707856778214273287234799077799093766749250483836198462118538532137879937467661913084900244054"),
+        ],
+        [
+            MontFp!("88825495080305331957888150180867671153850965756743578721847633799542376716882471335815950973754427836539626077664"),
+            MontFp!("214918207772075621730457077057593624304149979679433341119914778007412593353277747996089093491850091505663260942868"),
+            MontFp!("151260986872657170263149400562714732020806585414966882036594957256334173596751802996628926376864771060001995527791"),
+        ],
+        [
+            MontFp!("167549813352922038244952426403728185112764545011244392881603102981679421164132741131559028805418224787903667971897"),
+            MontFp!("188976438013862284420705099518067360821172407570120362260195897501481909274258568480317738539913528335635492347589"),
+            MontFp!("118643986251605017484377239886828662047682752545358828550824669933737608141305494452971417256021918812151576733479"),
+        ],
+        [
+            MontFp!("226838310299758202996449090680177295735800278385905326819055140249672585945619622915446299700992155198439560891147"),
+            MontFp!("80408154879642126948179112582218830037656339080651044210443156503937127301135488466524150584146995837207868890634"),
+            MontFp!("99546780560033508526226797782398215914479077746830660716202599152774887660262968880469330790409509076052512949392"),
+        ],
+        [
+            MontFp!("38814144092904920680481755309399231277979944070414777815380226014920300420115716604378584473348763706663675083122"),
+            MontFp!("188999131828331685077027419021445463946543280659902484310899822874225368470705798734324249550909452655372736658240"),
+            MontFp!("76718053074608476217236089752155340359221897598585355287595177838287164649002200449446627411705119847000106056008"),
+        ],
+        [
+            MontFp!("178490694383308412778587633199460243226229806245001665599880350052399001084332606185786223257138545035601688924159"),
+            MontFp!("183643105652633234876284767022228768089805577841889703300812259393667411105708912630229318963174048806678369284768"),
+            MontFp!("62645328356245764740898650518225065913666222949878900528889932394407049747836010608752766457983316945634219584269"),
+        ],
+        [
+            MontFp!("89479975555097121298727797403717834821774334632706298402425044678010137810829367618836203513287182519269999276741"),
+            MontFp!("229046658612502577003868258456251059325229610485655530289230950337339171669643715274164164929722700858507261104885"),
+            MontFp!("202829519748989981451466284787328426036125738431536728440165386465210743899917140477978051477589894941042250314815"),
+        ],
+        [
+            MontFp!("48303212766108930168767438364711049004239885026783268746250554552305800047693850962175836813904116199841770894997"),
+            MontFp!("15912580555788486216713168938598696779029595019068757897184546898032234133427301540161679877293117923617456970333"),
+            MontFp!("16228024195678820370235790901272954071103880118266636887517869497926813447807866261225901391876444367633407532234"),
+        ],
+        [
+            MontFp!("235534901156913263640247785220997110497049251882839193962069824097690730438306962030037617800249733597327171342158"),
+            MontFp!("222485841810635819978336026530952521482537134477330513050272043609365374501433921027223639702137831713567548124929"),
+            MontFp!("199484202583882309022088532392434805269336610354935223070480698440508722725965220995353221291731657354782527938040"),
+        ],
+        [
+            MontFp!("179669105317802240223282411636167887079919576177798251943022659137353849018268691637166854145751436679904795219034"),
+            MontFp!("101899495493750640458266740626809857479462009327153710040054655465071014114421214149447908508893572371473559426225"),
+            MontFp!("182394463594740164848929137164497593863308732689012371193217586534129787041466461997056992565153024901726158522682"),
+        ],
+        [
+            MontFp!("56197636761684677467820417298134844955390518055539364203158587597365059255793068855685527421337605647579454794049"),
+            MontFp!("109987670295391319643565224561382093142159875506483951643964807337052678550909233199900586773767994344689467039184"),
+            MontFp!("28644341383235046529656977480666862107853280137548962523305299448353140145272026854799668439077023482588591159124"),
+        ],
+        [
+            MontFp!("8859907505201765569244317570739289793702875629229074095109025472529175201602636481876497008381753886121336294108"),
+            MontFp!("201863171240921235681724004576230995806961384898735107584416512956244238245994682784735769296238504491299284382871"),
+            MontFp!("134809665099686730221194357931424074089412767518831303089682436061925906291824157727198229232015126361719104820603"),
+        ],
+        [
+            MontFp!("210601929516736461537853036110414119222382506367173981974064977172769694098568364336231031775307295577039040607065"),
+            MontFp!("229153599835757922431966700764392217137092956747534633058215153639701543055484058309604140842538415264335473523014"),
+            MontFp!("238201653218113321993897340163592612045806538300616801147557592765319845962255291374169548028280463103011224148753"),
+        ],
+        [
+            MontFp!("256062803070681554687671458376653819659313517366346794410167801050293599199416160335218397054316759177367941280014"),
+            MontFp!("87224280624394924292493913421415143698302418949246946014064443656575926412852176428936923784941883733336350162548"),
+            MontFp!("79528804979150917409681916747149020005166871144054636946056905132999363342136909215393112082130174875731040593454"),
+        ],
+        [
+            MontFp!("20926607590689351374038588401076226407346723879928598669471532877358324232853220155076140119399540640052675823630"),
+            MontFp!("242777754436299362256604359067272398821231301814191535434437789376189691929265998883178068923205313169264094844665"),
+            MontFp!("258467417672829705577109796071910165947129060674405678807401953174490602844778619888491395983567594276087305731760"),
+        ],
+        [
+            MontFp!("2094555457206214178826687550204195074066308085683932128552253563758127601444766997558710670341500413553739592142"),
+            MontFp!("44968403003468470712715066215297589210971409209672035832622475484855078471099877292297042202238960202931930459150"),
+            MontFp!("13254293492483307396746838364968576730077569757269111419602168749356432962952097410919084003269497615534868238797"),
+        ],
+        [
+            MontFp!("212278352068555373891805504536895401902217892316323799577034643261643183102363882860952205208479642346317118280010"),
+            MontFp!("150537673210537597105365894934961124702044310739726461332716727970261370774564178122361111861884016517320559404613"),
+            MontFp!("17867579654745452930128090550476312326148049094678949997643714870020863375993900171008826157646602355034218561897"),
+        ],
+        [
+            MontFp!("112533342939239405594882309566092424886332560268437971512911834975678213651675493894269623045157149350140092479932"),
+            MontFp!("76045594190044098785976355320991533727003138614203148836295578177528926652414940837459410244360191502026348083100"),
+            MontFp!("90612970924035915350313439148523695069970531832323423385625626466953830384211182770343936083552505589285443306408"),
+        ],
+        [
+            MontFp!("244188176381984363347141008697129967686643456009105394506325466887113877695474514526680248530456840959951151639495"),
+            MontFp!("213846214973650604301085426959945874536453365075405170774808103495177909650826793568105738830969889389283019336712"),
+            MontFp!("234676084565834387787781546314886557701754644100620077400619557415113578748926130213581685878623356906367347811962"),
+        ],
+        [
+            MontFp!("161378521155200118303795822228072816396727533359196315785839399402907926900263447280904366710359453985135851348346"),
+            MontFp!("57014108950445911055532853618664516127984093278354069173492671476946632128750422963354352249447531036285682115064"),
+            MontFp!("248842822776381841321070160066469501749158548182954526307228358321322306799121266966506550253118691564809856681870"),
+        ],
+        [
+            MontFp!("140352351820043340026095736578656583619624351644281054579822598600311125282607012033180735049062243755916983861019"),
+            MontFp!("253556589981981371423676937358583365391657841828637021399280476282644685576948770672046063185916060708009823414313"),
+            MontFp!("77498923003537047562586680462348654564291436669814766359797350805327180538333756264237687671234641424951187687332"),
+        ],
+        [
+            MontFp!("251194586866809027878641541744163564341426161707117585830263742658666261690187476073307399678849977985808280464516"),
+            MontFp!("196156893737460122637185475950985131506299525759963533396720904044041835244582116900019749743848190155477723469932"),
+            MontFp!("201029955487470519573830459124063350644679882050555159773873144519680399292672244552498900599687605358289521073089"),
+        ],
+        [
+            MontFp!("55291037090680966107309915537188575435026051488235637996492375948482003135903567537247067291647242398860875689518"),
+            MontFp!("13363199555863606869657830211989103094711513275263841388856836571060499514277415773296070181386491227385636200500"),
+            MontFp!("210439286347525258441668082799862497773124342902422805069836939069341441533706623890612468526062719569847444791892"),
+        ],
+        [
+            MontFp!("72019355225942343886714516553921909929910815369936609328637894084937480318981131980482964542524045350987214603649"),
+            MontFp!("186155910282813044762120101410265500397965861755603512116314606065559694651844138853274314749976524989323751548959"),
+            MontFp!("66676636555783872499002532122832392224752479277025705962713619714395122134385484191551230642466785714063846630984"),
+        ],
+        [
+            MontFp!("239667786607060869108876295204051820632817252684417602229131871294303303380008037093343116352856724300290399853356"),
+            MontFp!("188109222405739271628538547490096629192924923996702227295462522560184446712693921352781540289919774675978627570663"),
+            MontFp!("154566935292268939823293195970161212551007506453219533114859911517345186130390066597025561175116028153307340526233"),
+        ],
+        [
+            MontFp!("13044031664695667071589470565473555976700902301289366880232101281887572312779278498881428881727025366648652126106"),
+            MontFp!("110674773918400882846544389110004858562671448463386013600939216570565299188992202479546454737575698277469098399508"),
+            MontFp!("55141559922639915292318376237670824894230212692280380502250153449102503945343922658111088228925852747524092211301"),
+        ],
+        [
+            MontFp!("192843142304959368185198800776533288080623080306062255943412774017566843006591138147643221602489387498232282466564"),
+            MontFp!("135619182600374127036877755352956556897997888546093665467676489336033605038639719664833284012131327184315615592588"),
+            MontFp!("75717608485883650182370431657799664765307359755208112877854439948248855214384580726345572554722987964590141865142"),
+        ],
+        [
+            MontFp!("94501598904954849143327740244924902161034218992715251386218364902602837285296262267414182302239898809323278496139"),
+            MontFp!("205644146986489152251855266219673791923117373153529648712749922002168257730938390558008009325076077991061984658921"),
+            MontFp!("82549471878565080468552181073956046351560107925864192588471237799397669522572801641796890595318895258500486223195"),
+        ],
+        [
+            MontFp!("43076868202772871607588331032187419868266955061632414975681447341396139403012688555132661465348141689572236465344"),
+            MontFp!("19510238535694288389838484508581570409112888073806090922198337393701215376340323393631104063821617427937990422285"),
+            MontFp!("10398509184835920401720329587509564450477245393226393383863791249684751586779138578236130561928498659421427791362"),
+        ],
+        [
+            MontFp!("66121767817916729398336775090209840272625321701155692572888813205611167158330477126165076345227408934032020656201"),
+            MontFp!("115769307748411153512296643902385568576162078398080607917988025383554029642560104951797514620318835144804722506544"),
+            MontFp!("249687774732116129531975906451758853850698007330637688278943051696451469814531562431775034804747911912979138562922"),
+        ],
+        [
+            MontFp!("95251797859433607218976947561932994621646000101429795153225466587440332289312252818391664118201071431298108699793"),
+            MontFp!("142314280153639123927499964849729358155121355190035193205623092521827851705771781412436269732662357271022628731085"),
+            MontFp!("105728727860077136284689544378790211011422138657058171342551112968626051076756902719618674917159213310503450092377"),
+        ],
+        [
+            MontFp!("127094095299431932190779001425711516348133726733645069444811220605182471074212257068597843161022875894682836017483"),
+            MontFp!("7450472678441905420447921127668791902737063131492214927919492496266555295205053717617888128012232489622058932535"),
+            MontFp!("236119730153172080912120262863199372109654334385811758195097050469919018480998590786354507080176438761521434920565"),
+        ],
+        [
+            MontFp!("165980169278559499027671547987644236115628564975841786942065113088111389796973737786747055714687227588539565705266"),
+            MontFp!("214982520550144423719638058965999497229791886278551274524433715249010610325243702089842796135900363017208954023429"),
+            MontFp!("156633217875060189057923692539646451346805927115057092278328562336556889081363634863281617030525576504641295087630"),
+        ],
+        [
+            MontFp!("194187473376603575527627198568568450336704286768637894351857246252106045874621811159833551302938024554814401778507"),
+            MontFp!("83770377792126839608026621451803319287647073637915760161383671726522425948444105245058881908340812042996096551329"),
+            MontFp!("141650664114253460357093178742010676571604214137138741013307002748344280734800090788205728164881426165205971367463"),
+        ],
+        [
+            MontFp!("172238089168842281248875237703894113484657508351772750091303159586442239377592676021514547605456040705461428013998"),
+            MontFp!("30418633095368756163358839934402710177263945385544640561422874156755326708579226387009186241690539776084915483823"),
+            MontFp!("187983068396464004821567476959136920255583062882477002054443123690736291439307344329088849013621977031728952845556"),
+        ],
+        [
+            MontFp!("231730089326150053228591667831037607612873557921784490898845755564957249927445529240454106827347617520430644637230"),
+            MontFp!("57520947625274626248178264697804148062184264264662738845647079702668975385693565831890133497943251550923258285132"),
+            MontFp!("55695483310204076383901602287425161879894810507935312518239169837812967962940325716403692751426874369766537712740"),
+        ],
+        [
+            MontFp!("153607734744034348518288666081679710303621404632993753741826163441900932149032224266986217004052959485425278168922"),
+            MontFp!("106591067605888047883236620062824499045452815126652037018123362973029090080103436389812850812502535956325805172880"),
+            MontFp!("156877941103869601196217634201721410316881066770598003602291966077851354820657946098562257717516418673501422625925"),
+        ],
+        [
+            MontFp!("235118314326117349002684140196959941015327438879454403302286125971576765405256512382147397221581449719096431355207"),
+            MontFp!("24593226785717966110896768916982226528376640354230450005606793417283592905674056293281966388081480690796159804413"),
+            MontFp!("93250007847299121626015757252265033226761762946566890942325225151404177937369365051755913628291070696884685749232"),
+        ],
+        [
+            MontFp!("236808375696033996786917953685743336071617642122547507930369223018643811166625988970629043059588239999387578937800"),
+            MontFp!("41917505314713519601765367231382765526822251539403659019546200410034171524116777024976103352254867235999487390300"),
+            MontFp!("174951137424523898088757396378574618291470587445085230811062259050389506787428495143348854667180956379325139715254"),
+        ],
+        [
+            MontFp!("57962540236909903626820212548035701329575563426466721608957267148583921593198031726823380213096228390908274533117"),
+            MontFp!("143450560200638792400811685075702347764633214375550755995291391814652762721869423281930221707631370171408648449165"),
+            MontFp!("186617053509437384102401264544618470318246436080963181716562475107296196628125006859463953055409530792833846332999"),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!("172442950675312729340435155796595689024262341836609773693256175111146978898893881849979258759715573416293547638785"),
+            MontFp!("193998319509726820507989550271170150152295134566185995404913197000040351261255617081226666104680020093330241093633"),
+            MontFp!("103465770405187637604261093477957413414557405101965864215953705066688187339336329109987555255829344049776128583271"),
+        ],
+        [
+            MontFp!("193998319509726820507989550271170150152295134566185995404913197000040351261255617081226666104680020093330241093633"),
+            MontFp!("103465770405187637604261093477957413414557405101965864215953705066688187339336329109987555255829344049776128583271"),
+            MontFp!("215553688344140911675543944745744611280327927295762217116570218888933723623617352312474073449644466770366934548481"),
+        ],
+        [
+            MontFp!("103465770405187637604261093477957413414557405101965864215953705066688187339336329109987555255829344049776128583271"),
+            MontFp!("215553688344140911675543944745744611280327927295762217116570218888933723623617352312474073449644466770366934548481"),
+            MontFp!("221712365153973509151988057452765885888337296647069709034186510857188972870006419521401904119634308678091704107009"),
+        ],
+    ],
+};
@@ -0,0 +1,424 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fp;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-3 instance of Poseidon over [`Fp`].
+pub const RATE_3_PARAMS: FieldParameters<Fp, 4> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!("84408986580208394843446223095922520356581979303255390529289840327495811398754719854155629179229656010596469166108"),
+            MontFp!("79759775797109966713088674687235288307289276901807483477416670046082219572322606270184696394283707379092369905883"),
+            MontFp!("171737043735708824490665464152020660139984286519570039840090774913355878475772815782459719515396763562863396931687"),
+            MontFp!("38449612538629629315919365764588158747179932812874901269579623891450569989576647354112087778410186813750655880900"),
+        ],
+        [
+            MontFp!("156362229347597041048073023908360574811144819876954761248076049387989518413119910737983914325480726534088988077152"),
+            MontFp!("251121299727751286397537605959631300566323379567721770045144475149367028745366331526772171450030582758943663646319"),
+            MontFp!("88457862826949591825066815223286001363767700110961846443072320303053450431313054700490509644574814900549562179540"),
+            MontFp!("28638013035239369158023139847189490875350209046288003555862692009057390050057941166736702599223385019211278829220"),
+        ],
+        [
+            MontFp!("82291391061073017436143965457958581445410405416615549421257553033098106849981078258849368768657488982428603677246"),
+            MontFp!("41052671675056615557002103009324649870296143121779830227222656681756743670547716219890743454329430569876489385091"),
+            MontFp!("199906199851342278114110471257331175660303207990297148621736471765776932479900957099261659029368309278147125144211"),
+            MontFp!("41121397682312539021557085087580647794638405993436155914228898795254219701767180761605966244819525451611011289216"),
+        ],
+        [
+            MontFp!("91402186544670919507807713450306860468153528540686037511085078833439788052414940204373084824609074962145777574442"),
+            MontFp!("240927979192832356860015879484047836392223846606032018376338183458324989368421291749102294624760248421261522312163"),
+            MontFp!("14540959834581229422865743170807098146999539764467401605359132732973355639965672953836208529185649419470321502182"),
+            MontFp!("127452468884593693115752992970175592941173735244948271746826637820429470958373403648518691092241672119826117188160"),
+        ],
+        [
+            MontFp!("46347415284457887640390301476006014925447975358691992875356499016307354602137050463306843547006495994892879012429"),
+            MontFp!("207067957655073694367694241368944490101603285553235788973457462750776370658994711432166628090770839345676599249204"),
+            MontFp!("34179286687201588682828669861902361743572163509666646166468750469755808143047838292663024786139670474060312750678"),
+            MontFp!("130468409039852915918226591410231349016901343550665940344545855633277944365128001568188460245728313852857562588552"),
+        ],
+        [
+            MontFp!("48739278081736198737464642120776030274368074999849333444878297429588525252882250019598181646722716387220432707244"),
+            MontFp!("139750423728283443961854911408634463728782325220847663899109050676372546000903821779327706911310662628459858641056"),
+            MontFp!("63470644943623927532926976727628043409176903012662249209671533576618203338873510147234482707202758683983883385346"),
+            MontFp!("225234044537273552678032752026579236069081344383531364745419044338939604997715835254390786410726374335298203100478"),
+        ],
+        [
+            MontFp!("164623759594152122966691434262585152489857676869676747773594376966510876330097021760066889963025932029752986006549"),
+            MontFp!("15397110666676676561465296586012928044885061974438982306537528152493117279244272395679764321622237691180706083653"),
+            MontFp!("226707030284382242993788702153196325321910326828567909330764474267922369384944332177380119942352993014749000183690"),
+            MontFp!("195055886101059621600768497315169390004540174102386741682781275192518345214742827329247185975715090102341852257093"),
+        ],
+        [
+            MontFp!("103557634913562726865454595755866087314569219017743912572975193652429379687358767060490783251702078886495163714049"),
+            MontFp!("67185356346179003881599989452349926792353395098432328950420898526610112590633526506011822486145070471573689816263"),
+            MontFp!("60801410246761627328105850942492701133282684121844646559846384888136930750112390881852096908461133090590781601911"),
+            MontFp!("111806287879821870088941628842003314563260397046040917851107135300163237875418808030193760491851136315259275417786"),
+        ],
+        [
+            MontFp!("106639196071031025848293155273688494877468788494554303283080842637121312110501733432637661114816865666717532018576"),
+            MontFp!("59002070580698404255606190665309843528102227931797657246300785411231864454799128979378616422503250406750980553398"),
+            MontFp!("164657068916363968193227963271238574299903393607670599679884431249973462594893867267048289925509204872607748521455"),
+            MontFp!("41301303508752330830829630006218042944307874606387401227707951884620340862122243904211213210320320004170264624555"),
+        ],
+        [
+            MontFp!("56231589200709555526983756158525177356009346834571356343690256788462026499656035805955384932530125273178797737932"),
+            MontFp!("138720179761666731127284376830500310135649444125514530771443012542074064087317330315272336477848992665812720308926"),
+            MontFp!("4564099065433141454047459741772733032563470953867746995758534984775635379508409252165732324439615054526606628176"),
+            MontFp!("63952770719533942557959774639291048331050181199507813899437711610409789653470131611628610678292836775680248864701"),
+        ],
+        [
+            MontFp!("123927948227135709604286133253290494272269569098606838674053439913080750875247021033562571977010504090494191031775"),
+            MontFp!("183860874062046166381206832455180113256708786502269477026876882539910609447144363813162521113894892010292872732908"),
+            MontFp!("44664568313983022173278889112549796561558825360360973538617837793742712315288721315361270996336731979724185974259"),
+            MontFp!("190325651174110620862140230019936179804961976968172715361648871359239572431416518928895411168725634113923048092343"),
+        ],
+        [
+            MontFp!("104237824250766228416516324653405964603555409999761104968195587050904546694511617727500562416137876093102482410828"),
+            MontFp!("115916553106915617735371210098017076524033029841151218650694770664112535869553974612990958377853037255943564417192"),
+            MontFp!("122653278430892017094212451217951720079451361875427068719288488358947793886252831431203971352407379123787550138893"),
+            MontFp!("136239052453842407682925713275361592518241692351388393305876285354694939207859266005853235076100169836524760439402"),
+        ],
+        [
+            MontFp!("131130200443439966116831048293682413476345255916777328265341861988509035413753975571943941253204247904622838840162"),
+            MontFp!("185917951371052345680832112262757091785644519739177160131900230839749833791921547822170741391342996515713181511890"),
+            MontFp!("139185033890431718879303914020095509672732270963105881919294727244139998758137931112302292575013026500221403749079"),
+            MontFp!("7822481840517610941387532847821722166162531622773874353707281047361524148606815067407189722303548834291014727446"),
+        ],
+        [
+            MontFp!("40121243045388365697188120694043541780363242931045219347784881979887003127067358078322735078457575348318874263791"),
+            MontFp!("254709656836688843403632835522936163046766904960309524313937442226698018784279142531970364303724183315049699865091"),
+            MontFp!("93038826546856879108411104223293572543593501108130809851195716582134273015650512610865206016208371464856257871119"),
+            MontFp!("167635559908650340427168529373402685960662038999686135210834366425346889136546228321535640174118337037164025894974"),
+        ],
+        [
+            MontFp!("31249760931454382172577675653865456690741654835434502225427782152782670517296885973772831555296187404568444688830"),
+            MontFp!("67832616914361297337724676405984367716362270132415840418867776195104245790018486675209258517167618508027099377537"),
+            MontFp!("255855182562960889293206682435808618884750818549998726011676726620138851392544296552633600071035861019040999476007"),
+            MontFp!("244310885969639240370884586563825377640843954215151750205134043839236896232176175234313531664855878861338670608624"),
+        ],
+        [
+            MontFp!("26930089727236843527917904510717881220829757451041974203366381330681793730170699211007978679566073249105047808924"),
+            MontFp!("231688973531862770215243315794114457622129229145495781567028951785014450996716082343524064730815696115044288098936"),
+            MontFp!("162662632385883669711485296151415311736422779541018446625933796373802430214731807779502643683484679970836750414901"),
+            MontFp!("214880173785373039065234690084872023883568430194939478137302616993463191050484536477493208123016301364573987250509"),
+        ],
+        [
+            MontFp!("155049252135330185149332889597582188706182475028361455310253405210528598944761578523961070165888751813555610001548"),
+            MontFp!("158293754252555467366022437417985317486625682213456239813962287055412408860987224321441349986795568653020896601708"),
+            MontFp!("191434713851280576244103909337237987016701755940227828130586664794363327687416387055807254929294333986835331927959"),
+            MontFp!("16002536044169625490500268147864504785673499059635518733712981709945244632959722783236961842927239960751583360697"),
+        ],
+        [
+            MontFp!("111761774705224806671761501109545087900812146747311345377922396948979640788014728718969571401067733318808080911384"),
+            MontFp!("33767294151772236915599133911366844611903697172828440790019563397797909494494504967406436108029971827291103784103"),
+            MontFp!("236242092564832238535016318792989730036864916302366167412405232270848042135015706282505497752255569070320490740132"),
+            MontFp!("213095075297989296366922755458337036691931191472125324365572462756619456594721307971844855930588254233762030347767"),
+        ],
+        [
+            MontFp!("9936631400839523497854988032148333677245441016569861290721515831806078993192700189419649071000818375263249991367"),
+            MontFp!("88683840693620210946198039660612722713754306611544964420267272572384434455342082050468314412732013311297015052191"),
+            MontFp!("30837364972698377105709457355818705118502255809927500240627443297746615742627181717678290139705787220593734966150"),
+            MontFp!("248822350962998373868264914508669516410081863770234180637499698774941189046292472056911285929347631282060990223481"),
+        ],
+        [
+            MontFp!("22585518513572931805397047375198508009439453638264813865155011925036206043939691515481849728738925788073422347330"),
+            MontFp!("97464819368988608584050732307140490421342013794591042722072276949194432010208728429443866739773156663517064327291"),
+            MontFp!("17379748639864341428334952671072263510759023191353752157488482808683878345586287079724056601341496187477834386141"),
+            MontFp!("254146798685704184577713828719040116001416638724001116946644066691096806893636159608313813664437044121270159957687"),
+        ],
+        [
+            MontFp!("180227137951361417998149909177423426950342696801257190635270899902329975282764026853346051086471166907107196817287"),
+            MontFp!("123319484753973615000676979065267451480377946715315324799560466696275486116514627978800163506700172284620674646073"),
+            MontFp!("172344230700226515672468972647556347074812679785407372117462822521814474863919205222586940066472561154527945083158"),
+            MontFp!("153033162048494158985274130093323687076064725385185262086935889189102678298544963354645301107076401238024468962533"),
+        ],
+        [
+            MontFp!("13962345047633625222316583074007762784841535377172425397941993595308277843483842003602203911738249013342850070648"),
+            MontFp!("206265273082640588150743091046866212318064740606938824489743830435598897350436533258163772060577381034540491141175"),
+            MontFp!("60869043001007026880393885912667629723043145080241812491616210713685186497246260121268639427381171999564042408077"),
+            MontFp!("182922527780136489988155202004583070747666661381389598549493374757651372297539104190683444483398031179006750927097"),
+        ],
+        [
+            MontFp!("189543602747307431071656264942321504591656265051454633507514080120940487671273216957738254390786546494726449549570"),
+            MontFp!("13374213762209386212516519608822127207633165813983833646901100399616466149321152909122020873327206081977898573258"),
+            MontFp!("112913722761169842433902218580605205007378209984577063594177997661929052966796106394324595624986690391947326376313"),
+            MontFp!("69631240491585794132895460075286514725654111011285862823301507336176936704229789449541219168042853285112235136966"),
+        ],
+        [
+            MontFp!("72852452910148785572946347617892179969905612752393380821430358172607763308157271646181669388089534768843568869416"),
+            MontFp!("188143436919618961375047045329031272893604510376114860852237673142947254353884680960401156436707416996181046832405"),
+            MontFp!("146286805763989790692984433059989826055351403559972738491531980550122411842047133347116617363920992058371007595243"),
+            MontFp!("103406374811159986665262937773960720703635495925454574119511502788304348874043819443440151481831867598067293090223"),
+        ],
+        [
+            MontFp!("17960225849165062729499830223377647619456870587395100151224682369560009422269995624880594541031406120044193876222"),
+            MontFp!("49794547741298925658533573629780060039677756862840798079692975320567876561358523032353763454231360112293074796399"),
+            MontFp!("213509262755071229239730733220965642544274939688179952996204296880223193690353836619269880525656079849459595224680"),
+            MontFp!("171260033248728120410153786060156797908592176214774961733404694642836493579493470043539660633644618582416363492770"),
+        ],
+        [
+            MontFp!("223836513729074036084739928970419928128712252971585555516659861129771731654430499396955056923339526249457030952023"),
+            MontFp!("141806335804588137301726153651860192515088365047728982114142325315386307913336085951828693100686302834052972403681"),
+            MontFp!("60367786552351135631415836689190561039158226049243981208127781335395749009797264979587075794492898997094184575957"),
+            MontFp!("50027359659674150519034860387820353407236639619315250921233650108329132506591627112224371556442132132583799813251"),
+        ],
+        [
+            MontFp!("11744182411739024504732532697063125602073077114589775317888931052041800868274335482758736587127499028338113293864"),
+            MontFp!("107747696089079862694117541956079663950126743043363300899153057913241870747839047254262872598262019098104481794825"),
+            MontFp!("29821375009692111327176038058385920267127825703505053632191950670097495198527685593686116085835167027484643204463"),
+            MontFp!("239404637691125722773782150911660980323972484613892391859163690334439821242791003594897010385826716268032444743223"),
+        ],
+        [
+            MontFp!("173133934321073136322736169859236392387290697098664455067078384823650007372482946357904153345656041028406892472786"),
+            MontFp!("1192227611573168765580466540198546738257580316582920837559469024334570849255439718904846300110310423266896467824"),
+            MontFp!("256484776365199338598762156013045153019763975134816031131929224122626254049233146248959098091321691217775975365531"),
+            MontFp!("231772155496905392443753347697439925271165013154441176921828981598795237161646414901270936232835383436477893924523"),
+        ],
+        [
+            MontFp!("94659296653080884531421739828814098280221956459012148687020591450639496804872032799906017806849036278731812201060"),
+            MontFp!("52850760401106240036313793131614885029974245338861064905881329699534148615317138107989523383524868380835061132471"),
+            MontFp!("160887334721714330360865134432928252801218627274078069957733254878479084758827614677326112778477330267789590982583"),
+            MontFp!("78774908610864802901711642666798647981219347826257624487743198639738305195960407071644360867809098637208369729002"),
+        ],
+        [
+            MontFp!("243598887090996487850754209218008816170300287338100067896075936861502408037174613227590444057705332681906607027195"),
+            MontFp!("164633740887598002883152999019019680086763031583237282072517459781979463429329261600436074894423518878784291597905"),
+            MontFp!("130511922556383356064179754594425310554165235633120162571409416351817606435393019645179976200097658644503298085995"),
+            MontFp!("112535918083695616078514805648093557634160530028708998681420635935667674385830605110749563697243488199173079652589"),
+        ],
+        [
+            MontFp!("196625356943551192153978023708607982463175789540262022315417901930912455775744632551496934538515931385708425672968"),
+            MontFp!("74564528949543505137049924597046799970456726086767714522381261288519320428493928297341103154021732557222480602227"),
+            MontFp!("84821012983219000997606824098436935310618118147158070858480656519484971486549441548487504550255181666031808538344"),
+            MontFp!("37682127287666784487540397682326512573140302474741379668652567519786920422316241017073231238785487428125780857844"),
+        ],
+        [
+            MontFp!("32599478697081307959577251454801847407540035941844418384406661760399747289367150810341161669514703033451221783683"),
+            MontFp!("255809221291504500220916483253004769283035423377257226964418732930591959284090734507921270174332146527266210222255"),
+            MontFp!("62693227610309003226297256229135823488338041195829042267322636863530554318165612360242592559323612179752964655648"),
+            MontFp!("136095125775986705435358761117187110161950848404494679600770724978505152163595613444122347083071609115230650360130"),
+        ],
+        [
+            MontFp!("84567506892150395447773193581317559531840064236155322488847845210975606818404379251164210488780689941665516483551"),
+            MontFp!("208692327342773969066731035663731211880074418090924802345955570341556458114716483290530822029902363990156166474244"),
+            MontFp!("115633913937691130933894459417960557117616831566145764503895655354064242274154358069151324911949712033311275011103"),
+            MontFp!("42340638131179355564334591686714875070908246011790545935154151702499102409531174173435347025934206002759877366699"),
+        ],
+        [
+            MontFp!("166887041783553687000656552451531238735090799170772576018858184462811265602802480147034928210740691002113622183232"),
+            MontFp!("20804587212128242732609105487975791444576413640882038149206426099811090102511568018199588758063716535036215871489"),
+            MontFp!("243976814745531698366370760192413081018866718845407019113769476337108036054396253066710450477663460877478362806987"),
+            MontFp!("28387603927398508063524699379575228671166849273676846055786991107047007333970224555431594935945121909445458119160"),
+        ],
+        [
+            MontFp!("34114955918881767889461368911971708635725578027694004099026613552957550812146569017357845717690678363777982137182"),
+            MontFp!("61402531615170497593593532058657332422918911164780005900744357141836196080305768741665971372059773360597254781941"),
+            MontFp!("113638835768037130684466299711810454899486265608107980621674158390859285496955444317860624265761795277017468855327"),
+            MontFp!("53583745699277644898468084832838698804662323028108386595442629953308062254931148017569701526615693888538417777649"),
+        ],
+        [
+            MontFp!("219403934595936376347906944701260953934340502039938997350327943731088555367426872741356736109274349454950230720506"),
+            MontFp!("98505843214843606526574747597032053315251675358420738678862224942041225878155484432831159152771504643213976471926"),
+            MontFp!("240468494279222142846255049377627956901568127340325832797323525801194491967884522202180777569913449845404805444160"),
+            MontFp!("203834850184142837911101803232028956948674602181092190331022166640809238552158724952140709257103650203134285126211"),
+        ],
+        [
+            MontFp!("11322312319190671557257311786161828700826856260333668384712273499088068947648785999800863691900056461044981889233"),
+            MontFp!("39740049300753909534302750187250658034680085015366158603741029035669532221402941232244892639048173982452097892374"),
+            MontFp!("162343498064952574033912993414141002502360596289046543069184689494428821158097604273406863994449572352741900113449"),
+            MontFp!("251713612069209140536735147031984738232981029024963129938316719067983327752763373168043476259232592342591187907582"),
+        ],
+        [
+            MontFp!("137426744479846327930436785694033423296659813500630773880147976577603202496465778707853868486662395533362167074547"),
+            MontFp!("178351891821907961595513645958040004814101168867006796608133698736817372405990385169040614521783529397859916819791"),
+            MontFp!("183592986929849220242234189246187452368769024190801191685595317534485021210327217817682085440815949923426105543237"),
+            MontFp!("62223051246687050482116550828260436265750425841964021625444661076193897764560339453914805157822011612757976676095"),
+        ],
+        [
+            MontFp!("95460101488082643154700831437948141203527734785383207540580588864955208438458069033901687370519988915986446284676"),
+            MontFp!("256618233647160396006272425387028414897419908133972275774585695527579107821270749946320845482366007601974737081842"),
+            MontFp!("65423262858290107485233994970031573905116778653051337713738506578390168718382633183876592236406275546403674888655"),
+            MontFp!("168872467624898878504959519866398485183172574875447288856472964856379699231287943172559991252559208671143651125139"),
+        ],
+        [
+            MontFp!("107772021570751612441914735920084673034606875381883904282013129190773209450741143231328162162787994852084200748969"),
+            MontFp!("214429103935844340305578193671324051653762929251025679784254347061291241592222027411765889237589636510018287725218"),
+            MontFp!("191302667471179247196045951432827478997822468557301605280502218247151340021007697783177140555853326704146204014209"),
+            MontFp!("230690275392367511408299001377558630078896814390088341389253557875174307488308243547752216319262000378512133191902"),
+        ],
+        [
+            MontFp!("91222199168670905079844249079594606405005647449631636967595047217397640380378021066662592231590206993469700762838"),
+            MontFp!("118055292063458196939710308755665201232769332942293403039808035084159614359740529628515520640043089514476053313467"),
+            MontFp!("12360329787380663159491765984687407350536461544306072060748337384837370932079714132863867553736716936045710477511"),
+            MontFp!("5449932493001867816440772656854353204869928245443243158154948976232530418182667814563920278104544294727230859194"),
+        ],
+        [
+            MontFp!("108882782126413129014971833563220127586041485023855710797423733658375391871300675028218280518104820850478706188125"),
+            MontFp!("92464098232725668714210056547893488938242446435715549670078929059662781959449891355298445929246285121487426588852"),
+            MontFp!("118798478355341636530392693896557239245564228503565839820097427793818220040593099403093842682521921965527125381430"),
+            MontFp!("186210226855609781605075349865963032575997941235755969241504246455621319485687088033115592959506964168712892411522"),
+        ],
+        [
+            MontFp!("106402604523912490708890256727074363338835749172642814486986044848766270458914473246022426202196910813699004021970"),
+            MontFp!("99239917968492782226161454307971810739015451751184524620561275817382404552606293844467283767903385735120432836870"),
+            MontFp!("228765732806400080019639564701979252345869534171632524313686276410050834062739302035372682483732466376225299882576"),
+            MontFp!("195897728563254015528804687461968212461021772851994091464983182857430043248299589908637448938135770631263742904884"),
+        ],
+        [
+            MontFp!("48702911959848427521493036564252481331903199552404766357208611149204568170261660277564311482497912668898627726295"),
+            MontFp!("141513730193056059669053993595574297673086029321306969841370350655044598889962715358984898338058708000427839056964"),
+            MontFp!("61479417256970051695315652586522032819626862486850437018859938249786015183197731926695879999074332192583794319412"),
+            MontFp!("217927492841380542725516047121018605328520252970352545824738277368125985914438378176501743383471956811632952039806"),
+        ],
+        [
+            MontFp!("199928782577208789457688240387191151067668330553018957723791544383366960923319480449494254318910104298220971973080"),
+            MontFp!("167001775501268094809229940035748569793097844446049468974579384318114281327896310282406981263794429805925745349483"),
+            MontFp!("122919580887168032929035025397044876933419983497247042247583145024209043834974285599326235252016288110604822054876"),
+            MontFp!("220147013551001576487509132918423773637953687641981776765767810299505604030821916127816561403418869810513713008172"),
+        ],
+        [
+            MontFp!("167960091512326138299346159852384605809159746131117501756066689504640984934464294927434370948039422686341638029258"),
+            MontFp!("102310235458323811916861453617834541123276334330931679143562951920484050049762391892126530544025759762071740497733"),
+            MontFp!("32113014130637151812423583595580930457767443293257256142689281810840078397212925499286933225752079876050237495632"),
+            MontFp!("120141909613381208944278913822552500810249662089243624257710047326329987368591177831913727766736189669655353413830"),
+        ],
+        [
+            MontFp!("131337256471191769383835286814544943323657094869446622875707723526204428953102211228587747013375195643719442252227"),
+            MontFp!("9837113160950692912365046973118004327986951835768832372317315636563920386073026985214390590412672571014211395995"),
+            MontFp!("46719823117673223870649259909704888527549003339690304597797255014505681815440651060146249007564392598800571824323"),
+            MontFp!("157013667039618932454338137576761070191058134776164273791930853692530798311743287264402350060299518156410616130561"),
+        ],
+        [
+            MontFp!("4872504313200527359624359876051127680730980096129121781458594959305992724592841679190332740251137237247264178868"),
+            MontFp!("155948970931051257027285323220346420047914587794294866617662526899362098546814970459889545195185291169483045017744"),
+            MontFp!("149710849953240119110897953288421026924879708725506608297161417347915944537398975715595702629107949402221320088262"),
+            MontFp!("147127640164510440863979500763806601024858557893538870762354521560716692656876313117394244262013800553303108550622"),
+        ],
+        [
+            MontFp!("83659236512078868199419700368661207784477112238657509926984908220515176998794419111497231997074518654717417517749"),
+            MontFp!("2920375844612862351224545424799281361712498764635719071773957933230657830223370098649763449167004429627763037724"),
+            MontFp!("198038642738505337541576022179705005884016449689043685490051436753278672192288872000316549038447608051806420078625"),
+            MontFp!("30378835786958478372422222137953088894638764449092329682739422704539314501047769996725972157581320748791240624623"),
+        ],
+        [
+            MontFp!("68659158232169751530667170810553213732577356164123025813391259500737611591398023598022753219853803302757610131781"),
+            MontFp!("38930800076595473536390147539950356781989345498162986056299418272074349782949580420717262743436752179405663955179"),
+            MontFp!("176757360562405680189401060431936519425912116404620257096450808614547868760689045521833357492008509035741276318475"),
+            MontFp!("201770251849892369596868857346896985236671755220785460073272249758098467199845899639460011832385719997512840415060"),
+        ],
+        [
+            MontFp!("43599961658083486220976553486981659843098524472206576954145019569161762829567044395698580737851893398056754116135"),
+            MontFp!("208987765203350673351847965539118129846314621199374509358910575016694428192266515961120244151552686658472966230911"),
+            MontFp!("34713320468536317977326816639953089317680683718719373946980182181945426317111584406888274974401402607852915397000"),
+            MontFp!("143847793809707600768341325724282657710711367909403777513098653617035434918060440841848898624384927581478212903349"),
+        ],
+        [
+            MontFp!("94618694302393070937845230799640723144108750161544151033358308140814139613968534956984524168255548650035134201239"),
+            MontFp!("163647523924698057688818024185861859379557225479740780598542953916821803007017340998906757093928076972180796450401"),
+            MontFp!("76423256662739113776346654616295151442997623056968544647326734969722858644052646455365434321928309544157085984372"),
+            MontFp!("428674269880591203825046645785357288256696259338838845504829337400358143498018497576289535754203452482401783722"),
+        ],
+        [
+            MontFp!("12824971076177588464536264525807934715802019326112479789120424820913028256407300792911431585755948454561306135627"),
+            MontFp!("235303679004119866264070606485484981853335457221552587448954648637490607107817037467093271240097915611391498378007"),
+            MontFp!("164782528594688840039064308717322936244626011714478700891429185973245814852985493205204427637891392973401357801078"),
+            MontFp!("35664042042505853316478317795789814397579030186159317666214932486369015549163850185385074395968715762647728435536"),
+        ],
+        [
+            MontFp!("99633247130340315065523399151800763355771230732559543219309482995318641711940774095611446231790384892336135970006"),
+            MontFp!("8734518969548118186702339348528087120100073775900058428231929708393786640726082952486677924949179395353419640523"),
+            MontFp!("61971185534215051838138024447133467603104695467787208454245291476909669500247016600100395935951010648325417131533"),
+            MontFp!("58676710522442469180089139312180343522706532239714271348242094199140987345662541151930076015509318929815571380291"),
+        ],
+        [
+            MontFp!("41102292306025102842005185351818616500513010834025652647296762073195462407732394229591197975585096382664403131692"),
+            MontFp!("242078172206119639865564723099024452274635090736161636004526466474685284759370838407712201670856014208652439547329"),
+            MontFp!("133882737691947393890908774173050235919666682036293353011980877639968619011006052071023254102659066860640468870677"),
+            MontFp!("233782669241075809306063019753933306026922586373976294018557806939452132194052913334841376719207283142920646264247"),
+        ],
+        [
+            MontFp!("143435339582455454269013592535319195211153971743034497505603306415092710045646813362902652502560108380535933525460"),
+            MontFp!("48730462152273550084290835969034480835778469851735592416857221325696814249673617513228120551480399776143126760350"),
+            MontFp!("39603901212539119705684913913451669162224309959558331064958898382970955074540355974770865505127099253216561415584"),
+            MontFp!("132751064912967166965493230776160128561772026383556294712484879399618712727402997772895459797367216261785955240205"),
+        ],
+        [
+            MontFp!("106565017752274782152172774919789957300467707767184794563680953834068628481955982773130974461787824859535364799154"),
+            MontFp!("210314642951780843595294882538624236716651943381871842078835382896313727404595396146548221816021420323166387955610"),
+            MontFp!("213395775029851855470809515921941358333171126314245636663021652902042748489314881517941051524633223897015918718326"),
+            MontFp!("102069636026839023805018321851323243118942901057523141193643975024473380665436259084380333137577891768676954704814"),
+        ],
+        [
+            MontFp!("6047685977190646359749754474923704419679699758820802276832769351287994626343151164105188660296799204720257319005"),
+            MontFp!("127102026029573194619829260832259593345675763894145251546826862925358812525656987258891086529008031764580725985387"),
+            MontFp!("98606749269237413431830109536124736792849740588491070964911424558312791692010642603408179990118902642102395205134"),
+            MontFp!("38741261169090982072755619629501289620061650151927418672157576393279619677362485143867718533199845229428266082599"),
+        ],
+        [
+            MontFp!("138307283928500842228413981218278708326807100474100868420027252331637960139847814286643912226905206251196551788047"),
+            MontFp!("186730826267343664226210835288776427332515003537550746078441905392354917843168736021666594835526226662953618607752"),
+            MontFp!("176845298571833153685813101087225853048981911413391956645200319774835675231689938965309611849714379213157843210442"),
+            MontFp!("141973960024599880206245472867589016407951147688015333784362219828003143558832717181247835385974299508794310372744"),
+        ],
+        [
+            MontFp!("247311196749684426881372734551318274007168871557019788880573452665878701900320210163192232047625654136808072782593"),
+            MontFp!("89144486766022605705266909684068484418422372034560558475353528469641473566933275345362391253087891990532919613653"),
+            MontFp!("199729781378535174384565881057587866117897265172141104257149348221846324848884907663909971998693803308114400844754"),
+            MontFp!("51336397176194083762979620040074079640073263272421919608477684747165397914614649581250004580695879464355395224542"),
+        ],
+        [
+            MontFp!("187814080844314417535830340333847210904720727265335435517511088856365541046943341953864040230799591831293300070454"),
+            MontFp!("225241439677038476553537371964944928574561067890637684575143227503273732077772327373283395314996220208257789415730"),
+            MontFp!("53427199251254300153178795081152078037113301046305244029838297481064865023494950644643858761690995054190008937112"),
+            MontFp!("90698268946783154939003105691628514857143166477467164940270252606769025934541506732117017302196295679519917501847"),
+        ],
+        [
+            MontFp!("2722226858106714238220599948677436630213648554369195073769804066643705093525336644300389152064355338322516418751"),
+            MontFp!("123000667482831078969471355281675202196563241597636350089637591803893203745097031106120858352071083124269591731296"),
+            MontFp!("42718308497674973973282556591005414525972051234280176555216968767733511885666474314101533331831408906078271782428"),
+            MontFp!("138101077071379007107865103945495120115731257148145126116518782838689965806823462063149156655922780315302885906177"),
+        ],
+        [
+            MontFp!("226630080586797671857584537852066438965451294387154638439813387952310567717830320174977075247485332577189636890429"),
+            MontFp!("195466374318810434325257962287428765644298681321433387202867388653645551563345228114755595447830913163020609207355"),
+            MontFp!("132678605067585741865457305996531354197081409446795666257418243373505733768500808401177333225063631832409415905416"),
+            MontFp!("178973702558000379738968340190908235269545461512242209251651675793872559815167683442120262548681475633086011153982"),
+        ],
+        [
+            MontFp!("63467210746104005600829433747592125831923688110402230677623641916235836854924910406588061890063619746607255931893"),
+            MontFp!("170688564439862629490770494016971684447554958871047089253295947670061716874585599590224104155978095502746075902572"),
+            MontFp!("106861427084332639341370646558876048134904040078634544256749829525322859963042593474884794525420983931010698120319"),
+            MontFp!("258381558809253208031193183631384537955774994913192398817107440162668120357779071256995320752950128888664626858262"),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!("193998319509726820507989550271170150152295134566185995404913197000040351261255617081226666104680020093330241093633"),
+            MontFp!("103465770405187637604261093477957413414557405101965864215953705066688187339336329109987555255829344049776128583271"),
+            MontFp!("215553688344140911675543944745744611280327927295762217116570218888933723623617352312474073449644466770366934548481"),
+            MontFp!("221712365153973509151988057452765885888337296647069709034186510857188972870006419521401904119634308678091704107009"),
+        ],
+        [
+            MontFp!("103465770405187637604261093477957413414557405101965864215953705066688187339336329109987555255829344049776128583271"),
+            MontFp!("215553688344140911675543944745744611280327927295762217116570218888933723623617352312474073449644466770366934548481"),
+            MontFp!("221712365153973509151988057452765885888337296647069709034186510857188972870006419521401904119634308678091704107009"),
+            MontFp!("226331372761347957259321141983031841844344323660550327972398729833380409804798219928097777122126690108885281275905"),
+        ],
+        [
+            MontFp!("215553688344140911675543944745744611280327927295762217116570218888933723623617352312474073449644466770366934548481"),
+            MontFp!("221712365153973509151988057452765885888337296647069709034186510857188972870006419521401904119634308678091704107009"),
+            MontFp!("226331372761347957259321141983031841844344323660550327972398729833380409804798219928097777122126690108885281275905"),
+            MontFp!("143702458896093941117029296497163074186885284863841478077713479259289149082411568208316048966429644513577956365654"),
+        ],
+        [
+            MontFp!("221712365153973509151988057452765885888337296647069709034186510857188972870006419521401904119634308678091704107009"),
+            MontFp!("226331372761347957259321141983031841844344323660550327972398729833380409804798219928097777122126690108885281275905"),
+            MontFp!("143702458896093941117029296497163074186885284863841478077713479259289149082411568208316048966429644513577956365654"),
+            MontFp!("181065098209078365807456913586425473475475458928440262377918983866704327843838575942478221697701352087108225020724"),
+        ],
+    ],
+};
@@ -0,0 +1,499 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fp;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-4 instance of Poseidon over [`Fp`].
+pub const RATE_4_PARAMS: FieldParameters<Fp, 5> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!("131838898298691501470471362264969351624525017362998895582633073202319358986365386999730217588989493406206990344779"),
+            MontFp!("205647172679919188011536241686354669758674819150811375862645310666040598667135373360260113652758403414228919626054"),
+            MontFp!("5380445511795534147951693994229987806325512406234684866980473580699357548608222819080975143230840896898509848324"),
+            MontFp!("256156933046160042854332766126648920233949126828416866393727257136504871632365654408737017124503929588727826416362"),
+            MontFp!("196172656299711519343816595974291395266944383737359377173923423423046650655432014495114205311320008563378261279738"),
+        ],
+        [
+            MontFp!("177946393963927526882598315406558162763526667582787662229290909827371504437824603474794429521944272626250477025613"),
+            MontFp!("121129419856421194106395151037272964044511448139369987794830054616551711542607388649898454208365525978112378239111"),
+            MontFp!("4205033049337806961203104389903703711686175123973409407193777720247968231271955155141698536831780825753467986594"),
+            MontFp!("207171452643809564774901542889509404800805971035689505061105107394029407155535035461197912177031439965056733815648"),
+            MontFp!("219720823536462336617911755415704092084648765704704184720350400023481497487054472366669710335611981325435125508858"),
+        ],
+        [
+            MontFp!("167147399420013155449677093597650356057844958708819077088072207849873680832270572775057991641326322719160639327838"),
+            MontFp!("1537264676497566488156627431518447722551692573624700898884689293111875046256481397236854990080841994900760450756"),
+            MontFp!("73059556004573653837641323357933363796967545251873534108961933464212689441003994659045851005684528187277581567547"),
+            MontFp!("78486915378425328488497039391489906921872264714105043087952199905855242569449131180468576260928124472694144714133"),
+            MontFp!("75493610144099271047264369555137555541296613450088585149388489536850804045256206729780292241291493752175926662887"),
+        ],
+        [
+            MontFp!("44173269243270022458348276170504487322314112821177454479950127373451975919001782697782293196291172512868065506560"),
+            MontFp!("167478429384147551734965584729580396280018408351024904028057183668945032237923375749560983386881993728211335249447"),
+            MontFp!("12720509736864685386770041517211198718488715026836595088184133654266024906178876028074771473072804233972336682629"),
+            MontFp!("217570526427529674975000638923506565640419935071915043967164547529419929333450788682606927562186752477382496716504"),
+            MontFp!("30436699103327150760532932726850282357901834351005871227232576843428960183499668443359564143628567769494224394835"),
+        ],
+        [
+            MontFp!("111185864803368447581349009542167317728405314958197846211439756380959962066449978523304228789255897532061990280152"),
+            MontFp!("46346338131799584367807100258898292093259162643905377515997303784260124959579025430341520832650901757435052884291"),
+            MontFp!("23763928411756119882233653949758875874900515337083712574684099740395926370928221638946423922602611119564663088209"),
+            MontFp!("95172234354282074204006677953826105167662506331708645592806001604882191165654440617302901433412686236292931586482"),
+            MontFp!("204988406972444944791157848410559520177430932854453193006563324455213693607428975984897898070999513975404681533760"),
+        ],
+        [
+            MontFp!("254697126654987681406610948565500414626731732853900206178601688143828834493590427003167582561018941503862387470184"),
+            MontFp!("142850826313356605376541219245728500169329627830039306231086040769029589616603135807420498231703056587760170684895"),
+            MontFp!("54047537138186824907177857630089533887249641310314904583013349482342140422996479455331941083263545169690411678116"),
+            MontFp!("119624022277613031233408197468888254986575900938652323521154920381585891276003750547461102339997772890347932928181"),
+            MontFp!("129442162921511513507544073141511141188453703391518656890290999158707001481917720430242761271650298747916194938758"),
+        ],
+        [
+            MontFp!("162715823536111888330729536034628571658953688624108013631594671279368399264735678034600536805817939865217411509801"),
+            MontFp!("210610859273502209917419346084689107382002259784187048772730332498391271500337592365725628721138952183014362007774"),
+            MontFp!("124182639550732848102202642169909233823526476169427043269863656376901912025985785527648194892515006852419490924945"),
+            MontFp!("116364766699693463003409840599727319693099614710874928927341381328586348789297749151410063937776536295922324499751"),
+            MontFp!("224957328584479452787856309068288354402665115850836453965154489285643622336982963726855694414001035336717332811596"),
+        ],
+        [
+            MontFp!("226959825503759466996186554140255782315387519440507182138201023144235924993487120974135914078470736994926985479291"),
+            MontFp!("183843744382897654326837488081913388330522116057442817018335122531187981785815268204115752568544391981066690761863"),
+            MontFp!("79918353593942192073649023304415841726447975167660675611282808662502172328225235874841529524166128892918018684996"),
+            MontFp!("86446451401311515879077013261994805633538478226073678944399923128992555995010405014560286097716363068945345055798"),
+            MontFp!("149411951999571379349065817158569113179611400345747207810594770190136393455824030525122863608815394552918297509422"),
+        ],
+        [
+            MontFp!("181120486288808067089335425981902593793902122394688099985442846019815940813145821834632286531601492933966684781151"),
+            MontFp!("615021915237885415484837411359663774146726633600621136458938610292991341903534389411487693387191686972698707072"),
+            MontFp!("133418242780168997426439349061796715745296378632035731090667906483179364232942935733719749218542174899139719779761"),
+            MontFp!("106459707292511486207162162355190835145083444469831122032347388876426368423209359670168653320496068088693771344005"),
+            MontFp!("215656624315171166496351986652814941466601885240668364029077527182165993005332614172898723503475247272333198714946"),
+        ],
+        [
+            MontFp!("254071743080024036129228460030955740575566991936513065936815322759718304352519750500813739718662303007280587219754"),
+            MontFp!("1061354771841757087236966477329812302385123829244231725853412864280020411410654268014946766322126725662582275589"),
+            MontFp!("188479057680165991728810745797451121454371471493318674679165144821413980618115014219246284124661153815368329104692"),
+            MontFp!("138584014749097504989685638574140090629967290033291435077321368425414801938150228102520020579355305497242411311866"),
+            MontFp!("42517945619522663888346564065637294480966114742979314145888571026433427790745552062425758621494366743386488301246"),
+        ],
+        [
+            MontFp!("38925079436644042611725364190230434243658562191361589292564739674226208844046750722432582821015923992984720413876"),
+            MontFp!("97953551583694985965174636679870143506326779006399778769463780204494716372870599017215985240332268554636661819388"),
+            MontFp!("135815027808660434522446447547330406291620756994065946796833481029254102929255567659711041293748903159245421475711"),
+            MontFp!("130473719957683790803579589915404559412375819362502070518649455390365796455751895085750901214509978757125123895568"),
+            MontFp!("23835415172533395951906835169614892748809128580424837685264401112496121784876961068012998854144711338500367259140"),
+        ],
+        [
+            MontFp!("4141548529485400362742697695789238069568870837279638334564567606422082674161410041413855021134856818177312561501"),
+            MontFp!("23483581890826729213985952585241747221869187875780352913847130965884013341247710433661407467472525891263007664774"),
+            MontFp!("67330050676881402915538657935427956262110408572939702151184822313626324763429248876569238781855846754657377676850"),
+            MontFp!("28940875565788296369065202775897718869308173142755082068267401454944756590524211729545805860869771086014023848236"),
+            MontFp!("147390220819936690292200825250123883399872572638459102403674093790711940898683762307566383254408776109754659871125"),
+        ],
+        [
+            MontFp!("251559134898508529471616511114478654449905955325420007688701792565510815880720359284519192286939929045250897784745"),
+            MontFp!("222736260267501678915004353225999383994647188939076426198113227155743302085925859154366398374443728390893432687025"),
+            MontFp!("159526952763187141594365656378696450348432228023790413094745110282984825449912034165588971467731358985885916505808"),
+            MontFp!("126377796880700268906290605205873704296940978390956125826173916869841604385106298196233843963411019456476660104330"),
+            MontFp!("158642143441990720264679561556504817112540691397351425000301790433114245239036468110649328201434468826949865574278"),
+        ],
+        [
+            MontFp!("58449484039226902472953833945499842780823083527307753127795024214689215049548853781488230869400185738657766095054"),
+            MontFp!("17870710408560934953683926035011252107131120057229745980150819028326610398924694835384762992128018693998832323584"),
+            MontFp!("53683287921892020837016266475928894791458098283518957960958048933306285873796429675660246250450532529570949753542"),
+            MontFp!("81497913887573363361397837167474849660345922505341680256370369573496937383173022115571341208818094130617733182663"),
+            MontFp!("252494442766154629095911116284622362855072369027930290891551872782045169670077974237223162954232056101765653970048"),
+        ],
+        [
+            MontFp!("110034203151581738856084316016151798912704167219394670597445939567730337038073302499829673176431939431737398246239"),
+            MontFp!("199165065087757644556989562261052272164656823050834140678670095306778502836241787506882847739695651697747126597151"),
+            MontFp!("188829251287662176463393200833760385189351429209352748079628608609722919857028219930109095471262343364179653383554"),
+            MontFp!("8874140708655212255677123345828331626783493408804030585576001166452431531568236974025561800378301936478025251836"),
+            MontFp!("31904089429124471009337172290377846804808336328620579393924991222029970467008911852267823810712460779043807910034"),
+        ],
+        [
+            MontFp!("167639143913590497410192477322938347138062040495236376146375167904976029263486954957652192463956307141454903987929"),
+            MontFp!("256503236038704281926478319811667868169776203419990431772185529245897378720434269956573149478506889508337882526353"),
+            MontFp!("190171765891637518400411458252013584777609947694878485410046870460810400947522203128806022315637791644173857824395"),
+            MontFp!("14326687179648925611248571459249565601668757643594608969615733801131553870366873202608261866967881113521116234827"),
+            MontFp!("139825590696265825194332747536333300838583760682936325045256498166379997558430738940557955513549126238529168702645"),
+        ],
+        [
+            MontFp!("148007321428584773642703497617869727835930396393170408422437315992306522947434554491599485981611933216927634946232"),
+            MontFp!("240104156198264632878866573317848180042533448702112776104581370892244125240929091006491159138032427625572684262373"),
+            MontFp!("136490076742764458489931143303003794067739757962161750002876370686189434713230796877312009129392800829618989981514"),
+            MontFp!("206540144840700274699472362381391539174149661054268382486407510892532201371032642495317869097971724144735828665296"),
+            MontFp!("237979248921889228642404470680975741960353951855716854520130713926373125417996765599133204620665344674500050929533"),
+        ],
+        [
+            MontFp!("209840993249994443743960523268185754064053094953863629403101888155247610261912959757589914633581124643109473123669"),
+            MontFp!("241819673007325674724485901851366483976174129222902983658662558123668498258133449395604360333186843077358637056762"),
+            MontFp!("104625945697544747080735678639185078444520581907324310927531933209919829135639795407014476035709178870751141747294"),
+            MontFp!("149034664659321616714914959623023584424215313984506767858774886124464710558813072605523525192367668237993732317985"),
+            MontFp!("140655091798753090025899189978963319162712590471272084230273913366897428024973113535773140975391173382362021872297"),
+        ],
+        [
+            MontFp!("138602051515234425881309556735491582001088131180534348880607201295772629958812485598093776832671355036858077553209"),
+            MontFp!("29382377191855729642670568754640094228116898733754894798024672800898037634417524065872644004633482681863644603983"),
+            MontFp!("69317685038677965631835932902105849946922547364467218608834086092896625252294184872415571163414731101326742175575"),
+            MontFp!("150624395889903409969367647483329081057951318238693620953383537112606395438370376992838626506333664210238221646468"),
+            MontFp!("137281045355460247697736782806346938254796900735314262751478463979919681348323870105644620735106028491348942951531"),
+        ],
+        [
+            MontFp!("31148207124328481921113052851969682817772246589676778137813580338270838141815914804274728791759818500923511137606"),
+            MontFp!("152716446915064950993967152636863866520744780600859169752744031148112452947731996931605032597188506238383619874550"),
+            MontFp!("21131632042866824014795174759751526959121549444341033434126882119140348909710418366634919863998795765624972226885"),
+            MontFp!("150388266580511871446249430943048909769796852619863247621337892249986130772467889616231965695278140112870319912051"),
+            MontFp!("72151821023174489646285401518569304419487363447937532886290514704313892305409281951575846113679890331412719947827"),
+        ],
+        [
+            MontFp!("186239970921328335431077203410421524129046846407787700683243931647247590310274847552512912030497705892005025695722"),
+            MontFp!("13053172858412929424538712431208870363118138704740117292174072900787737059795376510122228886250308008777632112812"),
+            MontFp!("198693532658162307877473710212124303721533642281936997041502257234735376331731877628978936909216062342365291384027"),
+            MontFp!("243702633559866698551191869536994035034652616124633293730346403008072599003451075354429912759285892113394933718360"),
+            MontFp!("85886127579306230550958850251482808995540648713961859407582456639616187849638233859918770456036129842882353175344"),
+        ],
+        [
+            MontFp!("214474111226420140021042506623583061097713377497453549726048637951502572492239975688378579925751446679288814759678"),
+            MontFp!("25028081673635816220578650087278369737819587528871671152810426527188875160805642283373999464224568979479746721920"),
+            MontFp!("19590160673461574153597795940967604509299263471036222936721126614225184990691141992741615819897057144622333443391"),
+            MontFp!("119626377794067763051140381670713760106638249822898478991971189382837434995718587199861415150216615674371084303619"),
+            MontFp!("214927557067460140125159118459260443894744530654140882755138620067349163184372063185472882594005779369951044778105"),
+        ],
+        [
+            MontFp!("43111496376476502612073583069270499470614901988133586444743166031756935820590672600299974697423080609192651418979"),
+            MontFp!("179192430754914532770547957542603596460797483931090140423276662005084670247394691677352448833223300670987187877167"),
+            MontFp!("123295261235321311673256077692833566455742213883723918575828067525533021580395513446542528335973269903566146626438"),
+            MontFp!("241815310225852628434743506877383488816779308703180577550321447053157728848622176053663711856202466821826492093064"),
+            MontFp!("198437683369107250961804246565741178964064457795999778884539528358948279751846893173496862950506177811396305266873"),
+        ],
+        [
+            MontFp!("78737038257373845318886757003907345420922308509270021267803387835510171697737280603336047310731776097912973799117"),
+            MontFp!("54422325087065913437732748521286794387917897092623596100311842986376061196413338114172379985571095736284970520776"),
+            MontFp!("245115772442983797597686353919904067587436831750124648901548414945662723615289494369191432224267543991878839282704"),
+            MontFp!("229543690766726012048419101636946442471139149270269820465585568505799981269408079151086670842277849907293448486403"),
+            MontFp!("249237451981954575545390687545649721477838165736874062936612979025593860321078961272074648303620402483106457237959"),
+        ],
+        [
+            MontFp!("59910587547408990397985556715232226808554543670306794697926040132288166357252533575895015185391458917481350006246"),
+            MontFp!("85381519291065895401616903659574509728557678227908711249279990177183791228419898416294963323153569465408909302393"),
+            MontFp!("153323069731702449998381170544658679629639431888063552556737853113810873244672785602563946574281585555693658189453"),
+            MontFp!("72695729448404164184382096430683410755734706897249329843665792303221345473180176329226221309588603437591969688278"),
+            MontFp!("86280357067914624038648198744018621358784085750355532277088925452770627997760204119202282667902124429362596166028"),
+        ],
+        [
+            MontFp!("9223832455128117677031718452435442544709329739818924212162993647594707659368114585672183253721023847824734013893"),
+            MontFp!("254049328744878616595216594985944371837372948202406308023772467028375847116960084508236457715749188945333382356914"),
+            MontFp!("150297762288345451921604148499427351989495827080986613841298778356401200987738343239089767707048293556701397441583"),
+            MontFp!("223984530861016795353464720746385004186630421391171486951413095166212554454932719151421057072445458020300752433499"),
+            MontFp!("168533179164347463665808735533841464286195610267814941368876833425726750673985747598235050954487034991336990313761"),
+        ],
+        [
+            MontFp!("1131502606258122540674179609520815915811397407872551436360239176519634893411246228790644034594370178464804414603"),
+            MontFp!("75925507778443893802803403394959567889343136342249180430223705279410378103656846774332420460577680911891342795742"),
+            MontFp!("46073527919917261529727716446652311749864556717107779866312644998367156840989516774597791654758849561776286678094"),
+            MontFp!("138997129154488141678206780937028999477421304346106889732808512896165469272719851734083280639130060674321029473806"),
+            MontFp!("255672229507965740482516760763995536120328061550580937708663733055066027259412392500967300773630771340628308453045"),
+        ],
+        [
+            MontFp!("240528056368308071472331735210635540918606601650957811241298426872053212419519824943545207358671944874839556368814"),
+            MontFp!("76932343084655567712171781774217377068232718177421152905922507808636670714233024171852436717818468143599294209053"),
+            MontFp!("21142539261881082235035497173206810660323645926776683877086448125357249955509310497101526884171534118004365302666"),
+            MontFp!("22979029649431241902699252554116540903317895035497633458527304229122825038195170599765106664179426544818520234971"),
+            MontFp!("253310278140932669982447623149763247880595461073533058399441438009482609177457199203825736841665547371301715020793"),
+        ],
+        [
+            MontFp!("79196887431715354215221885973360775385661652060662979551498367180974780504537408289753306821217442460838494522101"),
+            MontFp!("117341769519676278696116241584578161158143153302850486918015009099708926129498670099791387651621956274301046161953"),
+            MontFp!("18229232353710124224706997703937922751828194329411548940001181667645100308579678287735499820629869243599279058499"),
+            MontFp!("63454540278090902669433796550542360198133029800884848967125343796270701679559236142847976406320103266422462770379"),
+            MontFp!("6963797945014372051144980578167529156704316450161608635361309218305039947995261398578512035503472181155814091769"),
+        ],
+        [
+            MontFp!("67341421085233961714281203729985176052579884798536101233344542935449702198377691019838851186955867484385540398279"),
+            MontFp!("65985344835022746369777121082944174742792062936865806524868009973312773708723784167467905621134299602543205482395"),
+            MontFp!("17785768158805533483248757605949717492801522620352725062422119583931923039094466021422846095752055741750499781533"),
+            MontFp!("176023790845562766565510271773985768495843597016742829478783220243588377038059794325920441431929147035652900385815"),
+            MontFp!("12391926731112629363832529323901699614025629940322266258191175235622521512908871313033214039636882853015486539329"),
+        ],
+        [
+            MontFp!("170877855780677873464741950656814793924661896492641100750386459933789195302733708678892916291886464303730335139292"),
+            MontFp!("188432771704895651434630462336769384844007319535918975804707511674139864128654643442942675582941336038586302446060"),
+            MontFp!("235918955418933766428690218961279055932683431750677929780041605979998343814744803216402417690891358948403085182745"),
+            MontFp!("57640021018105830231944847102304460675512459918425166370985762341185018513475127782837328538853464896618146430316"),
+            MontFp!("109827178229049911479254022516559048827056959754083768662123676054908702489237664410652542245579512724206458841663"),
+        ],
+        [
+            MontFp!("241662223737256954553286669649335694949789087254406437494635170991316877198219958063729460581112201352594328355479"),
+            MontFp!("43548950925188344998806539379292814108913968372129858897328109082354623289312752254263901272012998365624449290818"),
+            MontFp!("82769764623971922798796084206529932534406459489305721321252193973589298951406067043999464637193056099595475245593"),
+            MontFp!("24223551125401958906704464767145288595900423406612663461697990243221939745127177146343360969773708458109477210933"),
+            MontFp!("82657888915227673877328660299727937411894745941221881215235183029490365299294540831475615395861462875316709254762"),
+        ],
+        [
+            MontFp!("93852550015850666519310147700614509393202216734856214337978832120060629172102839398444085454022088282620596037058"),
+            MontFp!("174450475423741605457800812669542500293883933676513868577708333232956012694966074317757996937878013991044933555140"),
+            MontFp!("165401483102852340798187844052881596255118052889496851153373877748457367544657672764754835293398256375890848606720"),
+            MontFp!("55532960324137958307555766304156670553120549893279294239966596908053497231203742703050751156516031230159155418528"),
+            MontFp!("229084459620049561378487364965438352259288223131555456867071002076236975356675867490727740473864933075080677944103"),
+        ],
+        [
+            MontFp!("59329961977156823962136014206539776250767443569261192531441828246770200031449715327861660497804512754402700611501"),
+            MontFp!("28875097695164200463047360771083578676472871200943668178247188957430884598213895239081944822100885769261924444247"),
+            MontFp!("182304352245836371478762003729372254187654212795309470521219923063279573206948996701498959032342575171414466376841"),
+            MontFp!("165483813236591698185939966085166027821667423087282770070692486823130029356272733403474186155601752734189703445656"),
+            MontFp!("242050842562901056708762334786753605038190842347655173192453199612022915150036914219236799204178880782923066518217"),
+        ],
+        [
+            MontFp!("46326627333056490265190862561487939744928600722852901577381774894525751905172815553531305232917571768631474331309"),
+            MontFp!("62859964255893722942859624403322065793958728969894385714361630174796435121408954837798337448797289821776637661763"),
+            MontFp!("252432372726037899230781748584079064197871097196055441926123467275765970451029286316935736007543197676782455775986"),
+            MontFp!("231522513862008272242019703655670548581714508707849522828986650820723987076940032746278434619094684133040515644596"),
+            MontFp!("106757483948863389626086182491062416360155373452886245692794866716396194298402296167507369938900631271825204902336"),
+        ],
+        [
+            MontFp!("93180132595694072151888269917485955492667876978385795079517331675941734085705395851839563559371861222741626083282"),
+            MontFp!("89694891113615516383424841525975613342561779743083791283314927269101525133321763369234576674228036152721953519516"),
+            MontFp!("6591008840002440845402281243092360221905564599165231098198008778277618238616011324600748512264547479050148233419"),
+            MontFp!("51440685225188257431088469509878387703906877301410265399152107268359571424815208663566619094297744488294525892000"),
+            MontFp!("103785034078512690884511557020896134905358729778242622473904982622232614082052592948146676120813172569541965617485"),
+        ],
+        [
+            MontFp!("219738938813596051999785804843598546550519919037556109193261843709396776755381702360107616501011259521441701843585"),
+            MontFp!("233547630996082396983766381169079750089654755153426220667913051646315707674437779027885609374929213725304973571117"),
+            MontFp!("67352120579774837756259989080559817038932132422795683961544953269382844683685962536090732121027479145940375291465"),
+            MontFp!("135294393404750028039396665281646976224523872419864143076120479981737620339057639486313257777904146981078121550254"),
+            MontFp!("217956553837404560283257986329165230510029630506796460974687534795526343150856476027524608663910306747018952965682"),
+        ],
+        [
+            MontFp!("231662970996050999509184586448023601662701934689099694206211929220120637351459115707684151179432798359001999865125"),
+            MontFp!("42402474573860493189056041931762476188946557142944424988497819279712245227041696337598000844755337178057181960488"),
+            MontFp!("4499567748189421994497442241553616794789373773729672983917837559864163602957449325841611924222187598849933989016"),
+            MontFp!("75631922339124605212230865065111455885977603267604293146227882952280130348136881385839061588909218802768060742785"),
+            MontFp!("82524863784769634692483229668399338490351720221616712064933650965355528307015680824807593447310451680931795586904"),
+        ],
+        [
+            MontFp!("170197429758629948517353465637509189734918695076402094291697838035067207092181026240934143819849762634573241102508"),
+            MontFp!("100610007322408992362881863949878444794873018192417919050112884533877982884054512801735236978081927203861643321163"),
+            MontFp!("234859819302406896555592908644588779918255976774959475567407726079598239180936386862014109212845893528649957916877"),
+            MontFp!("73764455216322334651524413220785247518098580834860703343007439094316679801497595646225612013747983216678939335462"),
+            MontFp!("137082360270941678291335639184796446641492400659259709460126011516058125128271249673618784284592937923424127182412"),
+        ],
+        [
+            MontFp!("178473922640706753980302105023855349113293722762603933381581798825448442541061808272541866095937797746346222972600"),
+            MontFp!("203507107520698544389407136791455625794209735497288884103071989075949771592513404628431596576326452608565535466955"),
+            MontFp!("182011587315009586976190094835521878674326790019119830102411489143622025303978781398557605804452704154234132618699"),
+            MontFp!("209198581710380060656256864001273263447521556452392345538804223685425487298363188017585242913548255041870044326394"),
+            MontFp!("147070169300897122249672828940296420376716826860602745645683260935440890650670882143891074173828984533847965175005"),
+        ],
+        [
+            MontFp!("27605245589407959880272027845795990753809914880600212465628662721347805136303835607898245756572050741611658829153"),
+            MontFp!("252067071020622129537374898845283460595508441022615398591787438880294232094583764058362598381997907943378939383843"),
+            MontFp!("86740210341019698607208284533972892921864759345175027685830724150406947613048726190120289770350443220458105062206"),
+            MontFp!("201208636106218024126728636072046371986698973884409238518137368123619189278984732864602736343845228084798229993556"),
+            MontFp!("12213248524481826098809425368137856429582473780409527636803393013048330840474180613368016076614366632172177185413"),
+        ],
+        [
+            MontFp!("238207469331951126834786061242784426555932047261879978478089858320575670252489877590242193230561164825258625079517"),
+            MontFp!("156705724299909452336618759935246108323901521573855252072650234244378525517989714546206997672026992827147163796499"),
+            MontFp!("243122645796306159755953102138120422833506301680851618172694011058205535670056590329621112619479085323719376073859"),
+            MontFp!("186888861808359019777831951941069009754936253842425212985881747163562794752801626702274955395950123374389380243677"),
+            MontFp!("219366900326178556642281514045040458644843512449859342838388380108555425150223751632629286095421242405372662646616"),
+        ],
+        [
+            MontFp!("16214252112084780023357559733623390188081512979852781828321325763147727452028815293613552101023658803718681951328"),
+            MontFp!("109001210067686913774733996756530600071844931760331388334833781385989453413803230073466330437329473096194364811105"),
+            MontFp!("220445548172186606459235154801547060595139260716771664887917044709310680146549606354356704406276984891992463370319"),
+            MontFp!("27898683403825171016442185773273147921284180116875480832164061966622483351159926982360541819716770215969007537426"),
+            MontFp!("114131603148923213666942789124439288969383934909159181032264867187714406127483748516355870322967712359871840633572"),
+        ],
+        [
+            MontFp!("4001052912369904911271224308874027692960631436723573024514485704715661948077857657858834967808600369761790266165"),
+            MontFp!("106350998854650449096929919987689590932648757237622250681558347030660643703640477860231087923659208494721118274964"),
+            MontFp!("189839447115612080251768086623533967625949527394819293351367470439390117636215435440050562301212119618910279275069"),
+            MontFp!("55658518680432445944430395829454927467917587154995395107096256953752415121869948663365903727252178439425389943293"),
+            MontFp!("252814837570053606502894795821148441627745353984034901820650201037149707139095331366553190292984641587391200728573"),
+        ],
+        [
+            MontFp!("114501383309220243004872433731094946373506620876349111667157456131877285267270242048459629249728999977053733911469"),
+            MontFp!("166949764446015512765938876861834062734359671838112228951091637996821115460418169179339586928861234315001350103082"),
+            MontFp!("171616737185977590073687655997530387091964170790102037616735926136975910704107456563322961190244684137050787482706"),
+            MontFp!("235033100566742861930624426877262002688214135347456767509846866823594140243542880071656013675699696031110124340334"),
+            MontFp!("207928553123759023084248404177893364637300241428735482666068650477883070790445144060613739459141762033625749622936"),
+        ],
+        [
+            MontFp!("171596724601872354240702581800850630152712129511606964774267278238778509382215075389855934163896497862463052483886"),
+            MontFp!("107927488174317563422787450455420803343920325550469315655178662444507387918531862597618943142469023816249497878711"),
+            MontFp!("25429344235200778051704219311718094846691811324952060000295123936769848619597727608698254410076665497666699020430"),
+            MontFp!("221571902855092858886037414751648141750889429459449754883526354448070243702185837943978456158872289173618828724023"),
+            MontFp!("16128257271679694925078556006134859244129060751417194518619475152588646328460616480892984608856166783863101442651"),
+        ],
+        [
+            MontFp!("11081524269748333336726171511386351922169029473582190703930965816659118794233484607135399088278862505707381206346"),
+            MontFp!("29580689588781429623428393067881334353307105806752562681786637222481289805387097149001223748585632901350557908653"),
+            MontFp!("22779646599542364798744273431219173181527731846174284658485803146510739284574116193962912181410676725757727710113"),
+            MontFp!("100695936792681480319849085360880101458133293516750943669375509300754640843571455161394300195934140013405733206459"),
+            MontFp!("133415795151463486516514302327710116546627259341673831573937904047226687048419114998927468761794756826673349887282"),
+        ],
+        [
+            MontFp!("1486238720474804299902331986101931832822256333033789726980470978923895673614534431873629186650957524024226772012"),
+            MontFp!("162318899207803213079400029357398187122342384432584444386345414697199343867778887820434825079236789386687714060316"),
+            MontFp!("217929878107651495587573255153226505513635506703598247897146233829035541916070096747829658200664256251826504724564"),
+            MontFp!("126672361612244367543175882572829861438814940703155207191563639791257166417483678429322613668771049303649623868166"),
+            MontFp!("235510313626857218551406844737029524933345706023822852737416567548737086695586603769230529295805002990096450001832"),
+        ],
+        [
+            MontFp!("228442579862407903254769153186646839794930600156149413570113245925624781466569750154927224181924427213262430409641"),
+            MontFp!("197327529274433466025735762609352474935650061203778916339496307605520084862341295728091632810881846607730772766400"),
+            MontFp!("98136300887393951480943118707606705465366795917030779443258314714867483032640773611920074948314284841822908460891"),
+            MontFp!("84463517177019790406690251417739268303965300446298762098214272278644742347024264048595714826209342487833107831564"),
+            MontFp!("37480414101797231481177835676988317676926262213758445793367847637964232018931050720663700478477858454707076570992"),
+        ],
+        [
+            MontFp!("151501623074054053277256001725866432979636706278457874090105618640912762628192463675026677142902198565251655835243"),
+            MontFp!("52878938008379963880330568167860442161818017156772629845724238550874884962797633540910609802277743081867945109201"),
+            MontFp!("127783995071983367182034216450117250721210084817686573003278458415125378465451459883015147179720488421196571414853"),
+            MontFp!("253690456041603077887833378930042639236965231626087495588495530078300780426613763722075975696073921649081303884305"),
+            MontFp!("238666609222245962577651814734726878096976631344507228888819612314433877767036959132138900574716523655627885385574"),
+        ],
+        [
+            MontFp!("55957602393915945633177494292881518845083008259747275798751274247092896044024438256252186413717706102193542319125"),
+            MontFp!("51740365532243883580326687407492659102360781091029137807810085157013525657880861894066814652074298208639752757028"),
+            MontFp!("136582519427414985333080796908975327082534407322807660340803662976375604639666785819215277196902196465284652694834"),
+            MontFp!("198585072818433919938567340028321228172467441393136269240961964983616192260403611959179192659378210552032704679260"),
+            MontFp!("60800316472915457271187517877647231294596502183690696871934101966385514925843872456066840872815996011849905185801"),
+        ],
+        [
+            MontFp!("102217312844232098472114659936031509202523790802624997538622456678368451853712073175377578861133228118365283340159"),
+            MontFp!("1750427234983498224430968707139492147542199038487038577016117612140193887329438469875339248649284255956865799209"),
+            MontFp!("162493029803515119121480909246215644566983224018137369477947472435460808182209198103965132112284451587721124615040"),
+            MontFp!("131829701900555600028466333364372046627927012504269963921170775753905402439652384219362487328059933778645379060497"),
+            MontFp!("64300048193888890750861551400222748636621625508546847310273140318722400684412221545279292573619637272913537694588"),
+        ],
+        [
+            MontFp!("9408577077374924076078041329482170770558398286852236283553881454672635910983106946523676967015819004058216754834"),
+            MontFp!("123167096523693724889634714969105903997956667935957986986449996422429471921613946948885991249345575267047665852665"),
+            MontFp!("249238413000327104026966101388661410639782087174565549822600947182927818693783825506566911660991315890877622819947"),
+            MontFp!("227397130487573094338258163853013112728675880573554449630941800142554157871053388435448860727362642287024558304721"),
+            MontFp!("5779401022948776722760565912772857371703540814738418508228187615298066059048992057295668250335410941154581728531"),
+        ],
+        [
+            MontFp!("76132516141259793248662041004894338932132252820807326744568411575204098181281777917482341224037892182959340781046"),
+            MontFp!("1654465773826294320955075537143779591978599012958048205257579697452535322300122848535576889242278739581991823897"),
+            MontFp!("30184733980971544012408644820624386471220489687911436601187136281434559694724254096948687885257729950335670445477"),
+            MontFp!("57356041994784867890317590877432812858724273553381390217075853479699114179325783622492911734728464563678929205157"),
+            MontFp!("74334223067611980160469499961755917435749264666923825330154976137785836119395336802872396499128866297543556256306"),
+        ],
+        [
+            MontFp!("102581532081222984048872072607225098291534127333427474811895238775468006931103604107932002863488782086145235231607"),
+            MontFp!("22578515630915860728774831655393274864837662563874530577529355850557740475310123440513756472543630590208879154633"),
+            MontFp!("90264698328466481405408240468021329237854442394549262383090744164819974577780984214741855213252137814250040001579"),
+            MontFp!("109255329464433130067659776798708629276771292174275043784204662923791488780867194775288044306582161077665178976944"),
+            MontFp!("148996413286244171091972355174971994261250090281763553157916473525064175826142656304709319927502560563894945482211"),
+        ],
+        [
+            MontFp!("113289347479512091523425890527271218857008894691332974187050850012089644882529446115348277076455097308420727457216"),
+            MontFp!("70320388295515770708930854323307467242282021647440091404719857955958643648166914376268449499746749317520172564578"),
+            MontFp!("20050581046612365662677963614081099468879404881351336205969838103736920938828008221310772883202043497992248722891"),
+            MontFp!("116037135357639082642918768953898237966431730959731416804884929899757530623975838156248387607745853603264869156016"),
+            MontFp!("41019245390891544823391621398237587404579752303594130051524824129037382230819446340779205815295483399755687833943"),
+        ],
+        [
+            MontFp!("165263449016223960883792508445731696303952610357031171213815546831852693831341412075991536504023315843915201677736"),
+            MontFp!("115846918932858252116893132625254643937028920026529688417987615594177049409942787785423764150825043317737323355717"),
+            MontFp!("126898503692267272298179803334913667472446308356587810984655445525613631081998003595427924690913088868799868369278"),
+            MontFp!("26842365543318895187358505126194738299919015312441261091779586595815434507148068797464834338106444934094294147228"),
+            MontFp!("167219678261554877694819790493796907014127258515584197899020026597528417504482516656301378094040108187855247309487"),
+        ],
+        [
+            MontFp!("132240656220098946574851082269269833644533299300779615363006304406470630703883001251070573488158064474684012834868"),
+            MontFp!("73248682009712532992056659514195916695969333091441033706997057525311984026014770053707973787031626737210275421698"),
+            MontFp!("222652697999535790738868598452870192341980813706861968053680668560769877152444865225819064080516588852894241524846"),
+            MontFp!("232676358340341660742098343761643042972773144963240655218482879727620237381881771693005788434955184458421236471976"),
+            MontFp!("125134293729019004198658096436599609574668971943215814939460717003873516369820466656527934652910625504175418588862"),
+        ],
+        [
+            MontFp!("185537259080038032352321706780399426376402627569232822989371506561373603990095428936493735664545210934762741580091"),
+            MontFp!("27986958583896681370558365600972334313722681625130100142736448896790473716265742068231922504718946752297898446900"),
+            MontFp!("242973804098543808550579750649238847536278615781626743281529123299076397569250171475433708729630372099860943154256"),
+            MontFp!("70543292657218249503276702202432240536199860726437607513059911035033877626649497915370050332457572116554166061241"),
+            MontFp!("179752209924830212958123930703364231072614591421975492773974744449059146165817893909598518268305270530860397185971"),
+        ],
+        [
+            MontFp!("163805222411411257323034985489119768342780220901585162648658758126507459991861738532851547373650691191368950251606"),
+            MontFp!("251965462884320723716826708617986719943402501138965081806588286256653234375552231907990064535314575123659366833949"),
+            MontFp!("106901764106794845377173284663519557999331143378686595430226924287723335934292067162600751833051755000218780923432"),
+            MontFp!("23298278473813345372578340333779697572447866534523828967897928998249133742551559308161404839586701986435548004796"),
+            MontFp!("233687248410501896724004933998166529839430106621664664608640142828133787745511934737576724241803509257996058823133"),
+        ],
+        [
+            MontFp!("214882402798966998644345820629826172463683861131125926609180881189479963595122026383521717686494455988111200682975"),
+            MontFp!("144620639105547008482541983948548005276905684244931704376968141983876531521019827532016211041956499627035337781655"),
+            MontFp!("64866733850187777903458954945056595777189929031856533081090435730209095057906370059290134656483343842662687130173"),
+            MontFp!("2792553524746404328888222237269271278476909036485965067308987965526379748243113142141817534559816278550482553284"),
+            MontFp!("57318761615889635876227813794190829814443898162187826599558704984888924212550801300712785820454748211807134423083"),
+        ],
+        [
+            MontFp!("208517632963895185837608168464902389800180493568869381774343700049095838255406374229681995632968535310139339068191"),
+            MontFp!("21277157392165979048378990383413573842757922302585381489287468723231112166050389485327011128164570231766049439627"),
+            MontFp!("239681163484373954567438235515599035323438721069471812670977624060783157914956722552399496024422753892876464112451"),
+            MontFp!("27278773881086059964862306745236081545010909377060106261620277727666052889937275660647698277213574898822216131924"),
+            MontFp!("184187358140165501645284979321785265290307436898172768391074581115709419818430774012566941650036858983772381695987"),
+        ],
+        [
+            MontFp!("83369328511223958060699121389708711860369640523789825202145073349111545066309804489806895833335486672093191635458"),
+            MontFp!("207052754486530930942242542388072295211482214469444478712759667667048367721188550489893771544377767718187707673095"),
+            MontFp!("200294218502525688402824766541747408764058911917543232036348602374567955129381293584498929911973528912074190690467"),
+            MontFp!("7432578026670746463406411819334400062802546346012469208895007056081647871850475690321285003460174020025501943458"),
+            MontFp!("140810796154530801674341268867937480984876985210741314537331853048100159687472798500337335824430802993498692866795"),
+        ],
+        [
+            MontFp!("149790344186327156798105919147374911397787625290407186292025486047371054757400187508968237758512455807900576112517"),
+            MontFp!("80225769820962146745283098308424805193737692884397729423765333560538771573518460538847934766052289174892646549983"),
+            MontFp!("114118009356651624443009992227721391447118918950883309463994050754905827950049166102165466044361522007613712213070"),
+            MontFp!("95674847189573491312197245835868172351870032692441445551206936069798693749083213657490502482141894260886973402105"),
+            MontFp!("35322502794581296192739807118237969652587322261393488375870029797436922291814473690228334476797942607065613251396"),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!("103465770405187637604261093477957413414557405101965864215953705066688187339336329109987555255829344049776128583271"),
+            MontFp!("215553688344140911675543944745744611280327927295762217116570218888933723623617352312474073449644466770366934548481"),
+            MontFp!("221712365153973509151988057452765885888337296647069709034186510857188972870006419521401904119634308678091704107009"),
+            MontFp!("226331372761347957259321141983031841844344323660550327972398729833380409804798219928097777122126690108885281275905"),
+            MontFp!("143702458896093941117029296497163074186885284863841478077713479259289149082411568208316048966429644513577956365654"),
+        ],
+        [
+            MontFp!("215553688344140911675543944745744611280327927295762217116570218888933723623617352312474073449644466770366934548481"),
+            MontFp!("221712365153973509151988057452765885888337296647069709034186510857188972870006419521401904119634308678091704107009"),
+            MontFp!("226331372761347957259321141983031841844344323660550327972398729833380409804798219928097777122126690108885281275905"),
+            MontFp!("143702458896093941117029296497163074186885284863841478077713479259289149082411568208316048966429644513577956365654"),
+            MontFp!("181065098209078365807456913586425473475475458928440262377918983866704327843838575942478221697701352087108225020724"),
+        ],
+        [
+            MontFp!("221712365153973509151988057452765885888337296647069709034186510857188972870006419521401904119634308678091704107009"),
+            MontFp!("226331372761347957259321141983031841844344323660550327972398729833380409804798219928097777122126690108885281275905"),
+            MontFp!("143702458896093941117029296497163074186885284863841478077713479259289149082411568208316048966429644513577956365654"),
+            MontFp!("181065098209078365807456913586425473475475458928440262377918983866704327843838575942478221697701352087108225020724"),
+            MontFp!("117574739096804133641205788043133424334724323979506663881765573939418394703791283079531312790715163692927418844626"),
+        ],
+        [
+            MontFp!("226331372761347957259321141983031841844344323660550327972398729833380409804798219928097777122126690108885281275905"),
+            MontFp!("143702458896093941117029296497163074186885284863841478077713479259289149082411568208316048966429644513577956365654"),
+            MontFp!("181065098209078365807456913586425473475475458928440262377918983866704327843838575942478221697701352087108225020724"),
+            MontFp!("117574739096804133641205788043133424334724323979506663881765573939418394703791283079531312790715163692927418844626"),
+            MontFp!("237109057178555002843098339220319072408360720025338438828227240777827095985979087543721480794608913447403628003329"),
+        ],
+        [
+            MontFp!("143702458896093941117029296497163074186885284863841478077713479259289149082411568208316048966429644513577956365654"),
+            MontFp!("181065098209078365807456913586425473475475458928440262377918983866704327843838575942478221697701352087108225020724"),
+            MontFp!("117574739096804133641205788043133424334724323979506663881765573939418394703791283079531312790715163692927418844626"),
+            MontFp!("237109057178555002843098339220319072408360720025338438828227240777827095985979087543721480794608913447403628003329"),
+            MontFp!("238767162473509932932910215718363261725901704081459686652200857846203509244622297946125127513452332422560296730625"),
+        ],
+    ],
+};
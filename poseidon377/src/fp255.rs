@@ -0,0 +1,27 @@
+//! Poseidon with the `x^3` and `x^5` S-boxes over the prime field of order `2^255 - 735`.
+//!
+//! The S-box `x^alpha` is a permutation of a field only if `alpha` is coprime to
+//! `p - 1`. `3` divides `p - 1` for the fields of BLS12-377, BN254, BLS12-381 and the
+//! Pasta curves, so their instances use `x^5` or `x^17`. [`Fp255`], the field of the
+//! largest prime below `2^255` that is `2` mod `3`, admits both `x^3` and `x^5`: the
+//! [`alpha_3`] and [`alpha_5`] modules provide the rate-1 and rate-2 instances of
+//! Poseidon over it with each S-box, and R1CS gadgets for them with the `r1cs` feature.
+//!
+//! The parameters are generated by `poseidon-paramgen` with the same transcript
+//! procedure as those over [`Fq`](crate::Fq), for a security level of 128 bits. The
+//! `x^3` instances need 83 partial rounds where the `x^5` instances need 56, but each
+//! `x^3` S-box costs 2 constraints in R1CS where `x^5` costs 3.
+
+use ark_ff::fields::{Fp256, MontBackend, MontConfig};
+
+pub mod alpha_3;
+pub mod alpha_5;
+
+/// The Montgomery configuration of [`Fp255`].
+#[derive(MontConfig)]
+#[modulus = "57896044618658097711785492504343953926634992332820282019728792003956564819233"]
+#[generator = "3"]
+pub struct Fp255Config;
+
+/// The prime field of order `2^255 - 735`.
+pub type Fp255 = Fp256<MontBackend<Fp255Config, 4>>;
@@ -0,0 +1,73 @@
+//! R1CS gadgets for the instances of Poseidon over [`Fp255`] with the `x^3` S-box.
+
+use ark_r1cs_std::fields::fp::FpVar;
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+
+use super::{Fp255, RATE_1_PARAMS, RATE_2_PARAMS};
+
+pub fn hash_1(
+    _cs: ConstraintSystemRef<Fp255>,
+    domain_separator: &FpVar<Fp255>,
+    value: FpVar<Fp255>,
+) -> Result<FpVar<Fp255>, SynthesisError> {
+    RATE_1_PARAMS.n_to_1_fixed_hash_var([domain_separator.clone(), value])
+}
+
+pub fn hash_2(
+    _cs: ConstraintSystemRef<Fp255>,
+    domain_separator: &FpVar<Fp255>,
+    value: (FpVar<Fp255>, FpVar<Fp255>),
+) -> Result<FpVar<Fp255>, SynthesisError> {
+    RATE_2_PARAMS.n_to_1_fixed_hash_var([domain_separator.clone(), value.0, value.1])
+}
+
+#[cfg(test)]
+mod test {
+    use ark_ff::PrimeField;
+    use ark_r1cs_std::{alloc::AllocVar, R1CSVar};
+    use ark_relations::r1cs::ConstraintSystem;
+
+    use super::*;
+    use crate::fp255::alpha_3;
+
+    #[test]
+    fn alpha_3_hash_gadgets_match_native() {
+        let domain_separator = Fp255::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let [a, b] = [1u64, 2].map(Fp255::from);
+
+        let cs = ConstraintSystem::<Fp255>::new_ref();
+        let ds = FpVar::new_constant(cs.clone(), domain_separator).unwrap();
+        let [a_var, b_var] = [a, b].map(|x| FpVar::new_witness(cs.clone(), || Ok(x)).unwrap());
+
+        let output = hash_1(cs.clone(), &ds, a_var.clone()).unwrap();
+        assert_eq!(
+            output.value().unwrap(),
+            alpha_3::hash_1(&domain_separator, a)
+        );
+
+        let output = hash_2(cs.clone(), &ds, (a_var, b_var)).unwrap();
+        assert_eq!(
+            output.value().unwrap(),
+            alpha_3::hash_2(&domain_separator, (a, b))
+        );
+
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn alpha_3_sbox_cost() {
+        let mut state = [1u64, 2, 3].map(Fp255::from);
+
+        let cs = ConstraintSystem::<Fp255>::new_ref();
+        let mut state_var = state.map(|x| FpVar::new_witness(cs.clone(), || Ok(x)).unwrap());
+        RATE_2_PARAMS.permute_var(&mut state_var).unwrap();
+        RATE_2_PARAMS.permute(&mut state);
+        assert_eq!(state_var.map(|x| x.value().unwrap()), state);
+        assert!(cs.is_satisfied().unwrap());
+
+        // The rate-2 permutation has 8 full and 83 partial rounds, and `x^3` costs 2
+        // constraints.
+        let sboxes = 3 * RATE_2_PARAMS.rounds.full() + RATE_2_PARAMS.rounds.partial();
+        assert_eq!(cs.num_constraints(), 2 * sboxes);
+    }
+}
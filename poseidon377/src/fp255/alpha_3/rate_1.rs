@@ -0,0 +1,700 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fp255;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-1 instance of Poseidon over [`Fp255`] with the `x^3` S-box.
+pub const RATE_1_PARAMS: FieldParameters<Fp255, 2> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(3),
+    rounds: RoundNumbers { r_P: 83, r_F: 8 },
+    arc: &[
+        [
+            MontFp!(
+                "36061651454296896849173267305498508654045415338143599381915773767514754861985"
+            ),
+            MontFp!(
+                "19392194934427299082801048216193744907221130994843086445714931004863023609066"
+            ),
+        ],
+        [
+            MontFp!(
+                "18353407361862225332478365374220457006192662606801820171913129546013736114060"
+            ),
+            MontFp!(
+                "52560864532193473022471585621490478266341021759413116412103124924571768010919"
+            ),
+        ],
+        [
+            MontFp!(
+                "25183059018179635344660795989203794218563619927066084093130070179860741083583"
+            ),
+            MontFp!(
+                "40889662704384458281216353211360334307377153814621820959909035667671227260520"
+            ),
+        ],
+        [
+            MontFp!("9492870359260354314238471079849048753160270398240741186120020487076969633936"),
+            MontFp!(
+                "53837942941650147817230977229916805887265437499803541253432171211378131038343"
+            ),
+        ],
+        [
+            MontFp!(
+                "34883634002228000376862485520207345132228143996707830684800252364107373315033"
+            ),
+            MontFp!(
+                "10069353831533177200308501958472678078817238494142102277440412716892461718991"
+            ),
+        ],
+        [
+            MontFp!(
+                "29068398130586930571307791512626278879487328535320709492156624316329653856718"
+            ),
+            MontFp!(
+                "23964499159708760384467844780524914567337272971159717310508337360526824551515"
+            ),
+        ],
+        [
+            MontFp!(
+                "29166881890820605373684123351191700160027797821885185611759086330499033733402"
+            ),
+            MontFp!(
+                "18782767343398453831258192011652154317489852807822506052415038247124270789236"
+            ),
+        ],
+        [
+            MontFp!(
+                "51465325213730431349756228908022922009479147102032947425335641354291986551115"
+            ),
+            MontFp!(
+                "56201678768714169068244811703236636652814309911894513974601121051285278005679"
+            ),
+        ],
+        [
+            MontFp!(
+                "56618469497274228179297100524591188338126922821404370852626256307865200955221"
+            ),
+            MontFp!(
+                "44221524287528473834351298077785730888608087150877176946031759474984412987063"
+            ),
+        ],
+        [
+            MontFp!(
+                "28683068564086082877334189757942548276572385956307532291893236090758286885134"
+            ),
+            MontFp!(
+                "26477360635961774834900023476547299035560172033976396194194109787050545097694"
+            ),
+        ],
+        [
+            MontFp!(
+                "24101791717977465584368286434471912162038880962170861333506434327370826892057"
+            ),
+            MontFp!(
+                "38449364335725588181811805800870992864487769278975627561390297495568711038807"
+            ),
+        ],
+        [
+            MontFp!(
+                "56248872014089832633811138419924192505763254561968627542895118852688543059424"
+            ),
+            MontFp!(
+                "42164795668085667151578513521256897513864349019787262659883216692098402251128"
+            ),
+        ],
+        [
+            MontFp!(
+                "52256508361351406609022095018201564194208367825519161756232047262872165253363"
+            ),
+            MontFp!(
+                "34382552234555004821742500193463853380070054598028137733010229733330579446848"
+            ),
+        ],
+        [
+            MontFp!(
+                "10005739722515950673182543577750733209777164063018139311409938107649748050761"
+            ),
+            MontFp!(
+                "39049472600406324809392217425369738775333901504721681332050882805817975532276"
+            ),
+        ],
+        [
+            MontFp!(
+                "55689299688923930251769563288577124438354505653955710026104783125466853220115"
+            ),
+            MontFp!("3628436527762885528657730550761924906748224789098397789868920629135746487902"),
+        ],
+        [
+            MontFp!(
+                "11388508995293663291763074443833506139936224314335008034671291097666992364590"
+            ),
+            MontFp!("322640681887281861169241402157292616814101669183001716276108576475605143901"),
+        ],
+        [
+            MontFp!(
+                "47417264294228144919877957855260643811827148220070137415974866991628863593291"
+            ),
+            MontFp!(
+                "24633433790277162029909295407384673156787242310043379226431833863209179265976"
+            ),
+        ],
+        [
+            MontFp!(
+                "57418856371580268425262958644245329158545530779593895959689076250294932821334"
+            ),
+            MontFp!(
+                "19594231760238879212872559183081261965670380687135112154736814555365944835405"
+            ),
+        ],
+        [
+            MontFp!("4912443854218236672180735659972356377362272733975492468995593767428322815723"),
+            MontFp!(
+                "47335664261985198862481947708193781684494718206255853016371001225793303800398"
+            ),
+        ],
+        [
+            MontFp!("2578932593786139488296007378480651384759110627855022435545992203436533622829"),
+            MontFp!(
+                "46756011776826097860541881103930132582971167717290408445165770488795822820600"
+            ),
+        ],
+        [
+            MontFp!(
+                "48199706560824027038119762169928868530623878459965319557746120947693224062482"
+            ),
+            MontFp!(
+                "16550608375413128000840563998690443945159737203740515012116788998837595036908"
+            ),
+        ],
+        [
+            MontFp!(
+                "50485145012163268294206335801781899319503067850487101544956200330308493470929"
+            ),
+            MontFp!("4117816157651133857820943032253585773156144459005133507362432609559300047262"),
+        ],
+        [
+            MontFp!("7390422879124228043530044176817903494532351215149820420945222952138927941525"),
+            MontFp!(
+                "26179396767492876093567554887549370658132092481778396707922606388319173927534"
+            ),
+        ],
+        [
+            MontFp!(
+                "55497738733808831589016122820522363546772394474975704264277939970426423315152"
+            ),
+            MontFp!(
+                "26463651933244186203584755918088787345276055174106979501242767944470294202033"
+            ),
+        ],
+        [
+            MontFp!(
+                "24778248188760687836613908878316849906943684182635612622537819594235905132135"
+            ),
+            MontFp!(
+                "29988437018272262638387526127170286901947026392739379482418068053802157478339"
+            ),
+        ],
+        [
+            MontFp!(
+                "36836423375542725037816339890485587828047439850708634917794860449434427182707"
+            ),
+            MontFp!(
+                "10993588422860089276478441907864105499083013265580416986818037901718694136488"
+            ),
+        ],
+        [
+            MontFp!(
+                "46004910435544439532003754200309656866875135136387646302539119655367911228093"
+            ),
+            MontFp!(
+                "33903046206522180625108050038197816346795127078223901947569171411218546047578"
+            ),
+        ],
+        [
+            MontFp!(
+                "36742373599291130807609173620955557236046912546668055874230100493748553413551"
+            ),
+            MontFp!(
+                "29177762658326456429665552748259843429119610882090291302675676603092778577763"
+            ),
+        ],
+        [
+            MontFp!(
+                "34241389883777756884871696543022127574668014867023088561409347394288834400317"
+            ),
+            MontFp!(
+                "21301258761861436421752414750646363944350156987623860759280756008669771991407"
+            ),
+        ],
+        [
+            MontFp!(
+                "25418538573069030959034465924303449967103208425648871454884169577113287031927"
+            ),
+            MontFp!(
+                "27235182577716381605934787113680818823304013868573392940966151823039708478911"
+            ),
+        ],
+        [
+            MontFp!(
+                "54943126979783998105819950655201863655035383182381300378086049431896851585344"
+            ),
+            MontFp!(
+                "14505487858442946027584166187183558208278552877071182659205388589421230775180"
+            ),
+        ],
+        [
+            MontFp!(
+                "14517601151132934699996389762102600515054346826087141636499632002288012486093"
+            ),
+            MontFp!("3604973971799964969417912764181101658799179609033332225784429695929641464899"),
+        ],
+        [
+            MontFp!(
+                "33942866638700218147202608022795656257372208782991549971487351612319450299723"
+            ),
+            MontFp!(
+                "50427368596790441135926178895476266444592418326445075081079529665556820572450"
+            ),
+        ],
+        [
+            MontFp!("6011964059030012559555815772607030734741396016045511355493130463246835069433"),
+            MontFp!("9562316763315024404937591930032532940251435777227781243923306358573026508619"),
+        ],
+        [
+            MontFp!(
+                "31942995906623719911699502194954897042268569452587860951341377700466553142676"
+            ),
+            MontFp!(
+                "23022956771205255699288622050943811855717939498223524327094820308202417045496"
+            ),
+        ],
+        [
+            MontFp!(
+                "55716707024696945911610133378209518817522370428405067391881715095997394185816"
+            ),
+            MontFp!(
+                "49385580361504992432754953126427617889242167697460941642458501480137089236563"
+            ),
+        ],
+        [
+            MontFp!(
+                "10929667003620154006207550035875954076365611165254605606468706734797159901316"
+            ),
+            MontFp!(
+                "50599949623249078208359503622162715781207716751238567155463530791139942426547"
+            ),
+        ],
+        [
+            MontFp!(
+                "25706778767419114631326241682352881085893014072936525095422347448101886222411"
+            ),
+            MontFp!(
+                "50907254482406882078769047527752184992927064072716752952701968385962859793674"
+            ),
+        ],
+        [
+            MontFp!(
+                "33795496869707558427642398566482667334633014504907382715721541305083211083375"
+            ),
+            MontFp!(
+                "19128902475320166687461882526127106923972748405717302299675309335624217224655"
+            ),
+        ],
+        [
+            MontFp!(
+                "29488217009032360014556903923733089677592892770316544216571639733802028559376"
+            ),
+            MontFp!(
+                "47118647477613427581250673973806365544220078797977236957349744665868249820605"
+            ),
+        ],
+        [
+            MontFp!(
+                "35691803714123877524984957605526025842702585357933711141498025229454511198110"
+            ),
+            MontFp!(
+                "52754662292472163922762681366546922310044228766373146242734961262325277529761"
+            ),
+        ],
+        [
+            MontFp!("559339489068394349500310702754657670010711680768060218592378547170459373886"),
+            MontFp!(
+                "33744352418990861640289696148091233057552961841200312809266420761908694985526"
+            ),
+        ],
+        [
+            MontFp!(
+                "21883866534325135064370640132959967411384269877662282584441038005440744242567"
+            ),
+            MontFp!(
+                "41104967781645358392972959899226958501226653096916431041604853317516173929837"
+            ),
+        ],
+        [
+            MontFp!(
+                "46793063501511401966977283752593330219034048094670313211466392451757039725862"
+            ),
+            MontFp!(
+                "16789536772764778270797457714608321693341603596608300437643488082448401190596"
+            ),
+        ],
+        [
+            MontFp!(
+                "53859868638545053778793399134385571357276532093511387377702161805132607513614"
+            ),
+            MontFp!(
+                "42638335929031377706588069054737791208980053529573483259659672988620710017214"
+            ),
+        ],
+        [
+            MontFp!("9418845592624730667082272425350670039858964078745415389647445049667727830267"),
+            MontFp!(
+                "18112155239845151873368234727062348342355287548474620048440768368106790622001"
+            ),
+        ],
+        [
+            MontFp!(
+                "13765979557458418274557008756008344600633380256393028472523935824989557097530"
+            ),
+            MontFp!("3891918336890512962872614092331523688776524825704733516671338450798246092095"),
+        ],
+        [
+            MontFp!(
+                "50944971991680178202307713553183485508759045044582293127327086222558968687671"
+            ),
+            MontFp!(
+                "38112054952039948344677766680349353255253222652306378904673540639930089686723"
+            ),
+        ],
+        [
+            MontFp!("5555074244054694497363608343146957166398101178836446555084443092786867923124"),
+            MontFp!(
+                "54768849915315377850780727284569319737736122426816710607098529757484930521770"
+            ),
+        ],
+        [
+            MontFp!(
+                "12010516854813028593574682773036914436183277186649368163955857339627831920630"
+            ),
+            MontFp!(
+                "45133156358412040179322009265068870976732545089233810458916837427770702223139"
+            ),
+        ],
+        [
+            MontFp!(
+                "52679619853910278384790754934967211370071414869463931602875429364633560710247"
+            ),
+            MontFp!(
+                "21729729790708489270851824687765695857451368936800841131153171961301850968880"
+            ),
+        ],
+        [
+            MontFp!(
+                "31915805649396612257971202367074517665095457768539606291603391000307337277190"
+            ),
+            MontFp!(
+                "10778641626561638165627315401965262329287526802565442482308408215608865338032"
+            ),
+        ],
+        [
+            MontFp!("8010615492089067711103458721301322077318269541562577492146706571709563935098"),
+            MontFp!(
+                "52940571264578619761598841402544630214745981864512457965309483013738013977647"
+            ),
+        ],
+        [
+            MontFp!(
+                "27899864105688912812799743143395505957080138790022931466169083029094611540814"
+            ),
+            MontFp!(
+                "43321106541253440602933213544895654676956090565004948533179503761960618625478"
+            ),
+        ],
+        [
+            MontFp!(
+                "52976077661684816684955619701316232495034987016751488149215023178118800833916"
+            ),
+            MontFp!(
+                "10321323458549607972348747103714961833651740974543074080775814180032616264025"
+            ),
+        ],
+        [
+            MontFp!("7498108550481989446379135982198473985134385049722888295160534443462611153428"),
+            MontFp!(
+                "28706605103289730037646974309491765867378736272036239003656750926474122778307"
+            ),
+        ],
+        [
+            MontFp!("2828705201531135621728031347661077678377187020096280000050235699474999001680"),
+            MontFp!(
+                "23278963794720551494355511881029236046558680207952857311160065643472739809138"
+            ),
+        ],
+        [
+            MontFp!(
+                "18200513631748434647412316103643109148071700097260666484485566808984956512255"
+            ),
+            MontFp!(
+                "10978468385107165400036437661309465871641176189970822831446726008368766462525"
+            ),
+        ],
+        [
+            MontFp!("2704323422025960862596078674754109070161428477959319573987436463918530643768"),
+            MontFp!(
+                "42184101748622420706819249296597543782973910734487473004141058828957350221012"
+            ),
+        ],
+        [
+            MontFp!("5262627629207811664838245106420241152257996092985619270475381206770762327463"),
+            MontFp!(
+                "37819142138795723672676264734539179009853190365011556895652283274643508127904"
+            ),
+        ],
+        [
+            MontFp!(
+                "19622668702417692854386194991044973459396568378619033325956586534231247283714"
+            ),
+            MontFp!(
+                "57442809629533447896892429006335432081405021913027650316853545514336090501663"
+            ),
+        ],
+        [
+            MontFp!("2239593945314462486418492336271663014339117912142705503360150595698072540184"),
+            MontFp!(
+                "12678000855782287697853477688118147996104674491499060997996896440025012472667"
+            ),
+        ],
+        [
+            MontFp!(
+                "20660128726102615381190830026816395256733054274715393776374769507881491535228"
+            ),
+            MontFp!("6066993080365927916582494930800330544353528827847576138432361974484752071858"),
+        ],
+        [
+            MontFp!(
+                "49705471671379899573092246046055147665244286287462833372128263398731308317141"
+            ),
+            MontFp!(
+                "31924540604092583484404916888334045459751393360913615438086218148808063885816"
+            ),
+        ],
+        [
+            MontFp!(
+                "31188868819747233011781793711396438664736426311528666370672298253300811486468"
+            ),
+            MontFp!(
+                "31981550659017801252292049599056715571196430602307525668838738402899323246190"
+            ),
+        ],
+        [
+            MontFp!(
+                "33422061903487255589285583958649780919088709259036387398328047229527399476715"
+            ),
+            MontFp!(
+                "47845654173438968480026007785048714139007522191746724649793055470455059378952"
+            ),
+        ],
+        [
+            MontFp!(
+                "31142979247675406905996764831416322452812094019810976554331938489439806926970"
+            ),
+            MontFp!(
+                "21892410124536729574957164679647309834885026024617727053089670294859747541569"
+            ),
+        ],
+        [
+            MontFp!(
+                "49251618153128123696867539282233206576962932808373990819212702952018594833070"
+            ),
+            MontFp!(
+                "12282086074251808202729683022851098589902710696743239229140903818792258551929"
+            ),
+        ],
+        [
+            MontFp!(
+                "51663433998490506218185347656372774703906263986063617931248270437055973124556"
+            ),
+            MontFp!(
+                "11956295589260000338039423884285077279690178628520894011789676101559792540375"
+            ),
+        ],
+        [
+            MontFp!(
+                "40018487834097210310142954212363099410843622131837726363145335272798904554648"
+            ),
+            MontFp!(
+                "35358049876714866374364434719582237117547702685578835972488141758919099049300"
+            ),
+        ],
+        [
+            MontFp!(
+                "46417664011311543074472330304410440034878431347600650022719180124256841561414"
+            ),
+            MontFp!(
+                "54941891357699629427992194481984678386156141055139840697182894813783474822363"
+            ),
+        ],
+        [
+            MontFp!(
+                "21954388007372432600024186214901717898989928604400120060097975379872448771128"
+            ),
+            MontFp!(
+                "37124817600844752203287128584482057235151110559696773147203099977025274508869"
+            ),
+        ],
+        [
+            MontFp!("2249813416836795837167846338129104482270133647137862193301488555221711651052"),
+            MontFp!("8034644015479502397257251677552330248642600513881121491733746939844278600156"),
+        ],
+        [
+            MontFp!(
+                "32242255252360849218182753325422333287456802440533773172505436353636774716065"
+            ),
+            MontFp!("491652476892092639253512353546370417430537022074455300527587613631387514685"),
+        ],
+        [
+            MontFp!(
+                "36395147772931238481349219365024977870026895999130812044292325184336101907731"
+            ),
+            MontFp!(
+                "33450839815320755332014852108307416264425451337814702077975751652485486374134"
+            ),
+        ],
+        [
+            MontFp!("5038751992869753904833286358836336066536667074678417091928928073913878424195"),
+            MontFp!(
+                "23956815601115944184479259716898815243525284642397727417775053973856146622886"
+            ),
+        ],
+        [
+            MontFp!(
+                "47929874897968180646193439495095675102204426497074544428883659282135810711162"
+            ),
+            MontFp!(
+                "33168514836523515356909122629526358827760845149409080946591800082631913235489"
+            ),
+        ],
+        [
+            MontFp!(
+                "33712622875742317835332348340590437622958302671346806948634195195244891639046"
+            ),
+            MontFp!(
+                "23973186123433586335083801475826445941611216043470604409529494225907546626474"
+            ),
+        ],
+        [
+            MontFp!(
+                "26586468898431711475247754963818854250038818095107993587084320566151730813977"
+            ),
+            MontFp!(
+                "18018828246507087303438755709167694365868079107634406683771362378251974824994"
+            ),
+        ],
+        [
+            MontFp!(
+                "50489643597300615791280823270399204491664219686602808591361424581147286387249"
+            ),
+            MontFp!(
+                "20457383200132459012999221788176649414765634379799812597705892519016778025716"
+            ),
+        ],
+        [
+            MontFp!("4798131745577077032430275017524402244749684209351137670271374803527046182475"),
+            MontFp!("3925185314263514459304707237052872471132928892729315046432615096643044225367"),
+        ],
+        [
+            MontFp!(
+                "22430702019846549276613827405737588489912773596282328391070218779715841779077"
+            ),
+            MontFp!(
+                "31194964014580801169039917272524928132946844683839828764483855416176581752103"
+            ),
+        ],
+        [
+            MontFp!(
+                "45665019256688102608149670458841160355274692040515082854148227018533791155739"
+            ),
+            MontFp!("9889179274775725854080292620669130557408468053821149108985443397271672713640"),
+        ],
+        [
+            MontFp!(
+                "27671924248109993665801537323089369449841367688761631871194483992976489700255"
+            ),
+            MontFp!(
+                "37559084671352962926856873048007632469141572801107287571325821091316016711475"
+            ),
+        ],
+        [
+            MontFp!("2745115471480153882841825172457961323485962592875234791101750298186919156087"),
+            MontFp!(
+                "54016619438179815651746989931685620805130532310527967540386670694350651902604"
+            ),
+        ],
+        [
+            MontFp!(
+                "52772321720968707368424998714435941131492475810470806364216659394261770166456"
+            ),
+            MontFp!(
+                "46282142441359295229367804539189939130194945907741778834449386368813826166028"
+            ),
+        ],
+        [
+            MontFp!(
+                "16404814887119590615638373728068112550394439681132989504528001037529660452029"
+            ),
+            MontFp!(
+                "10414815521440743024660995450674485841440976203060148788633490233012436711596"
+            ),
+        ],
+        [
+            MontFp!(
+                "34255945114074658868155578392398337350116772921980136976917019427700617045331"
+            ),
+            MontFp!(
+                "46507798440659537176201732511010001699083434100448518691713956339297580827788"
+            ),
+        ],
+        [
+            MontFp!(
+                "48400497276548131104875758771475464563840042948214509980979805926942983697328"
+            ),
+            MontFp!(
+                "54263419260135041415508742164294167629135902252453434018327803185770059223607"
+            ),
+        ],
+        [
+            MontFp!(
+                "34116008220746214755456324605894825449948340630690836995353764257392821920153"
+            ),
+            MontFp!("8284522708658522812913180588198421887635416314767384940156278740464316389814"),
+        ],
+        [
+            MontFp!(
+                "31205788410933964017989225644346855310687252273426413936116343489042485701303"
+            ),
+            MontFp!(
+                "30930007161817162123405121616756474036703230511691937637051521244195348071315"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "28948022309329048855892746252171976963317496166410141009864396001978282409617"
+            ),
+            MontFp!(
+                "19298681539552699237261830834781317975544997444273427339909597334652188273078"
+            ),
+        ],
+        [
+            MontFp!(
+                "19298681539552699237261830834781317975544997444273427339909597334652188273078"
+            ),
+            MontFp!(
+                "43422033463993573283839119378257965444976244249615211514796594002967423614425"
+            ),
+        ],
+    ],
+};
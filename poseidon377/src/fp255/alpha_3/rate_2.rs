@@ -0,0 +1,954 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fp255;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-2 instance of Poseidon over [`Fp255`] with the `x^3` S-box.
+pub const RATE_2_PARAMS: FieldParameters<Fp255, 3> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(3),
+    rounds: RoundNumbers { r_P: 83, r_F: 8 },
+    arc: &[
+        [
+            MontFp!(
+                "43266612485354401473045802794738964545025122047701956605175774283459217465956"
+            ),
+            MontFp!(
+                "26803386967279189981461892361699970428019009456953831063926464474122763561518"
+            ),
+            MontFp!(
+                "24233584703385416860542135699070558678312435034818674709005302797525754079683"
+            ),
+        ],
+        [
+            MontFp!(
+                "29677266918616797511067971192974297719520488968585113048855228346211294237697"
+            ),
+            MontFp!(
+                "14229456322444760682549834640569821348381937295976812350484584885729296153807"
+            ),
+            MontFp!(
+                "20169549446839547336395244817400227379272649650361321101673048815818336783947"
+            ),
+        ],
+        [
+            MontFp!(
+                "40591037592269453626009048590836908185610948108518821907285102049608144424230"
+            ),
+            MontFp!("3074147197346716080967100721054620643321880279423701165358063906374916225139"),
+            MontFp!(
+                "43949248585398031984443538941123666549031745293186447709830866084091585362852"
+            ),
+        ],
+        [
+            MontFp!("3797063281876328732016495393495645273335085523819144337725195489681342387842"),
+            MontFp!(
+                "29509538576420667275352934699535469920017233591191978401743458062054094220889"
+            ),
+            MontFp!(
+                "45362005265815729981564906726518946591068014921414454245408119134183440840754"
+            ),
+        ],
+        [
+            MontFp!(
+                "53163821144961942441050075515309811089414143813791581633060458877281622466921"
+            ),
+            MontFp!(
+                "31947663854211115568057192457375042152209691522137776507318490900741126050819"
+            ),
+            MontFp!("7950844454826571106071973675903479854651077112711486401983609827209687248508"),
+        ],
+        [
+            MontFp!("9666540405688613433478449455860907769489492489630841149910599971527409635381"),
+            MontFp!("6474168574965513820428492262020349661004679020823067549836485213239159056271"),
+            MontFp!(
+                "29860786971086614254060968941932480364808485009961908954604539674715217288848"
+            ),
+        ],
+        [
+            MontFp!(
+                "54099971394898516247397345744215584442222354372872498037321900298364657995767"
+            ),
+            MontFp!(
+                "21047168062200164962311670813284329261826226412546331603525767487440863106006"
+            ),
+            MontFp!(
+                "53519967785373355760926595330418538796646056417433819295160854545416786044478"
+            ),
+        ],
+        [
+            MontFp!(
+                "47327632733745944703527351367705953727181430809630566672655300057829111232544"
+            ),
+            MontFp!(
+                "33120535629364274757500225606046829298454954338537430868623937110528751718411"
+            ),
+            MontFp!(
+                "40985398468810226159942803811773973551913486651006652719712437019275913840744"
+            ),
+        ],
+        [
+            MontFp!("6315643240467798504828020849011248527997375935315078497618360459286472148868"),
+            MontFp!(
+                "37710626022535750099517852453763551511278817643078620533391792778302780916851"
+            ),
+            MontFp!(
+                "29152912674201612277093568537886431455001810235176100819218832033253649023121"
+            ),
+        ],
+        [
+            MontFp!("156146460716402056969211103793762657625066724573968859993322103106356230679"),
+            MontFp!(
+                "32176709009833863121834014607455216543387142226232305369351684037295684634144"
+            ),
+            MontFp!(
+                "52923498638993452324463472228347877280741159261903035310830422565481600145995"
+            ),
+        ],
+        [
+            MontFp!(
+                "28969874164542399157634686084082560768785636798932340357297465071263150586037"
+            ),
+            MontFp!(
+                "20034330271531338208098757403009277659626202492113856023382943655706124842747"
+            ),
+            MontFp!("738404019911074360323967538666522835957131263777047345506416082476105317290"),
+        ],
+        [
+            MontFp!(
+                "16644379286448792987794314439562850546774505402174120226454639894104344183020"
+            ),
+            MontFp!(
+                "43312658534287417004805892875140781083845490500421959150554165524450760437769"
+            ),
+            MontFp!(
+                "46779082409418434827490687496638261776189433078155781111083300030310605340804"
+            ),
+        ],
+        [
+            MontFp!(
+                "19137195894166375036296880086895649214503628713199991847263255919900782616832"
+            ),
+            MontFp!(
+                "20322569586986822151424705574417179041362802738449930865466764635340834603212"
+            ),
+            MontFp!(
+                "11388291973388145399767638163656267803598744937913065082437227077340234442384"
+            ),
+        ],
+        [
+            MontFp!(
+                "14613760180437783559526749075549782557875326350797604192443257273364310385338"
+            ),
+            MontFp!("9350515140816684092669986614274256075272651709597208787054542891229250598052"),
+            MontFp!("7484385819815954179848545652313171970023883323503176911854984265213568283315"),
+        ],
+        [
+            MontFp!(
+                "12746371351870466020304996472577877806269445696333453469872258368795048430573"
+            ),
+            MontFp!(
+                "25105398233395238674934166814239725527891866755762746854990676235493189499068"
+            ),
+            MontFp!(
+                "38128204777260899476921331075797957516008234702897224381627244178868991078181"
+            ),
+        ],
+        [
+            MontFp!(
+                "39252373163091318781505701491848877176835548062135750753512009409789961960840"
+            ),
+            MontFp!(
+                "51009715560909264044129383666135865126838906845754385884322210712906911973671"
+            ),
+            MontFp!(
+                "42855852536209258870017396439401053934682251254897821130970092788359520655606"
+            ),
+        ],
+        [
+            MontFp!("6994054142474909872085972001552630038732809143849104400826109272059864847159"),
+            MontFp!(
+                "45195059398792437229446469859303090789178853467182930271097555262351233475677"
+            ),
+            MontFp!(
+                "25167198670613328340891322533772728213634713215315618559303046728021176109476"
+            ),
+        ],
+        [
+            MontFp!(
+                "43409167625600712582071990293423877861219588336759025732686026834732924112918"
+            ),
+            MontFp!(
+                "45126870229573357645457892472381875776942258010572520069677344277167905985176"
+            ),
+            MontFp!(
+                "44232364112033064955752067719230948885285237806050761719431593037253815203593"
+            ),
+        ],
+        [
+            MontFp!(
+                "36070401788602874988218970867487177968497773743390601993032770569004946563597"
+            ),
+            MontFp!(
+                "57343064112625829458132578163063129519302939404487235408952249788417477122145"
+            ),
+            MontFp!(
+                "30473313311381112606013112125762412329359818344827326000902595116469384512512"
+            ),
+        ],
+        [
+            MontFp!("1469261021177126654078484355886316189234111709887811785648192654419513427846"),
+            MontFp!(
+                "38236229877470723521494707882474351917077768310812063934620069968365746007218"
+            ),
+            MontFp!(
+                "57405419361031938606409348582115701804901649609536059037903194522708787098378"
+            ),
+        ],
+        [
+            MontFp!("7815736175281591460604685094163585958267844485826030432055035882241602332356"),
+            MontFp!(
+                "46981542763735711900760691008495367458545715376150448426350009414748313566559"
+            ),
+            MontFp!("8920693562030742984363353754320190893609269008041114836025913328000447886521"),
+        ],
+        [
+            MontFp!(
+                "36969376650531431514619316183328362734157400626211689366341283658521229687552"
+            ),
+            MontFp!(
+                "11938624546399830365428902546378054388107915272515488985939062624611766194915"
+            ),
+            MontFp!(
+                "19102053476564519993166181143293728294637669616512423610267876521353211304671"
+            ),
+        ],
+        [
+            MontFp!(
+                "26961032799705086461874335028706699675233707475914661669943059959891435819809"
+            ),
+            MontFp!("7849227621537743389592313995456011751694787046751161057283437707713493301683"),
+            MontFp!(
+                "28276503910627183583594751025142945707170437708132093962703174189548737179181"
+            ),
+        ],
+        [
+            MontFp!(
+                "48319057053637998932654100305863818836497618421425339075077929048440008556815"
+            ),
+            MontFp!(
+                "24697198274953576528317675451911477058569826821019672374070182090856247284935"
+            ),
+            MontFp!("167666502940517004207088135215836935953783728428797728855700300360478426134"),
+        ],
+        [
+            MontFp!(
+                "29240784309820954410035758437736158234558137818685615327381755128079892154819"
+            ),
+            MontFp!(
+                "51237803889131672095750583612809768524870124081215662034714422568006358432548"
+            ),
+            MontFp!(
+                "34000062116281975720923400201549460533679977255590707132581559261841710951917"
+            ),
+        ],
+        [
+            MontFp!(
+                "14913507684110639577632573394657895163974954835233369919232929307468407597976"
+            ),
+            MontFp!(
+                "57718527110400734926527528870218271732081134288163911544980365155265809082575"
+            ),
+            MontFp!(
+                "38091212509039938035877585725963825242453829696686861630773124832194396527065"
+            ),
+        ],
+        [
+            MontFp!(
+                "30760768406595899042559224690290775369025704451586827581467329957269157641080"
+            ),
+            MontFp!(
+                "18308634849156433949950484980015576683266374539784109191456183957837867546239"
+            ),
+            MontFp!(
+                "19030800353706308865180271857442467073284592701956305974886217441144695322007"
+            ),
+        ],
+        [
+            MontFp!(
+                "13618122127023110905400271933961174182019768155621621397473527938115825777637"
+            ),
+            MontFp!(
+                "45326355903299742688248322165474497000613946799225482823886929693247898519375"
+            ),
+            MontFp!(
+                "24535378354600928526409536093802158765522704067742253320575632693940716009198"
+            ),
+        ],
+        [
+            MontFp!(
+                "25696783188512226973349064725541423274113149886160534990618261344197235145608"
+            ),
+            MontFp!("1707290927838978058532784443911604437645741017344801618914207007384056851006"),
+            MontFp!("4896647622664672804976517773309645812582874357956149049220406197459289145468"),
+        ],
+        [
+            MontFp!(
+                "57345374347286018036858096522150933253675298500674457232276477834813938406428"
+            ),
+            MontFp!(
+                "42712279805796082314132009968052442393614146880777148018715979717579669425049"
+            ),
+            MontFp!(
+                "33891304795076844389555258031513126947731297377389003531491248814795543666435"
+            ),
+        ],
+        [
+            MontFp!("3934222057928524936641236987528512672557397082196195959015959171816174994757"),
+            MontFp!("1597722312182578923718609110038942520344392785525218964400854417518416427479"),
+            MontFp!(
+                "26441368924140318462236120494899080140656040690721493164316674804335536789793"
+            ),
+        ],
+        [
+            MontFp!(
+                "10603388651725257344082402920618245422695279538857139358439914801858223477467"
+            ),
+            MontFp!("7884644107365928418313108090354290171329256021659022394919789217320220170087"),
+            MontFp!(
+                "27624153543281464667771616900751711807777284635894986921045406911812398083908"
+            ),
+        ],
+        [
+            MontFp!(
+                "54743969842601069069347322877849403596920117220340321855103260747511020728503"
+            ),
+            MontFp!(
+                "48086317962882711025483510747985064246655287913243907857599379243074372028290"
+            ),
+            MontFp!("2324859728379968746406579681327758102312840904566842340689871024687594627519"),
+        ],
+        [
+            MontFp!(
+                "44591904222192165688963862232689773866524213680562390187812042081401654711113"
+            ),
+            MontFp!(
+                "13385876946725438396419470609188748798749935957557776498213315588501254691386"
+            ),
+            MontFp!("5665242808547517211558037466488456279296471938330879106347370297147632763081"),
+        ],
+        [
+            MontFp!(
+                "42273694189572152285385348860545344674945031248353647825378578603033176611632"
+            ),
+            MontFp!(
+                "16552532930343851414388102684382148285932500824456814824411361216082580723406"
+            ),
+            MontFp!("9847593738733384023654016442798752347889647692160012230650530796720763094297"),
+        ],
+        [
+            MontFp!(
+                "19034098062370236234363996672258101587604174559342220979263174321060869437286"
+            ),
+            MontFp!(
+                "24848679266323406830317118528344776868034392975252289395175799240426704326358"
+            ),
+            MontFp!(
+                "30131837539034582435693307433949852833734694775271106937856888233220872997232"
+            ),
+        ],
+        [
+            MontFp!(
+                "38658781532424246777028231118347425846350135436014295601519741708607182740777"
+            ),
+            MontFp!(
+                "49280212700265228095582637543917615632250867794774166602357485279076125415140"
+            ),
+            MontFp!(
+                "53849217571061728037499565172346390985552725722246366290010136268024829376179"
+            ),
+        ],
+        [
+            MontFp!(
+                "35050531298253337209624876332292493630936130281714513735802441372726260203560"
+            ),
+            MontFp!(
+                "32859169668009226712565139986988003560820381224052473086534629677791892483147"
+            ),
+            MontFp!(
+                "18924389002530368446975963440608145504729125126727458509594193149735356232613"
+            ),
+        ],
+        [
+            MontFp!(
+                "23719017267636047630128589739825157496822752430273330492723872357600537249053"
+            ),
+            MontFp!(
+                "33773294935230513713425547864299839323080638606434661795090694119536320842567"
+            ),
+            MontFp!(
+                "20273878644609030078498840024669423664297947280781244410441511084696117921392"
+            ),
+        ],
+        [
+            MontFp!(
+                "32335700421778987867262427449949652693952354624068272209795426603327181005138"
+            ),
+            MontFp!(
+                "36749624928300801372695215590027830181150856025494576946585059985140644707788"
+            ),
+            MontFp!("8087232711370629432485883612867360774963984851780549953758283609435769917697"),
+        ],
+        [
+            MontFp!(
+                "31879689785578907871591539405780400587946688969770711552945606179043797824630"
+            ),
+            MontFp!(
+                "31202110961528838892935800779875589122050351845943786006129235566147784851178"
+            ),
+            MontFp!(
+                "15871414587499913174171127156054451313697272921762232874871559833870390140529"
+            ),
+        ],
+        [
+            MontFp!("4287866988103886972755240056313810120098624480019495123557060058286979789349"),
+            MontFp!(
+                "44543394037373691191214999436949711960859965234662906857693783944435123168801"
+            ),
+            MontFp!(
+                "54453603348528329352760722460801014113640658327399294358794139156397577552167"
+            ),
+        ],
+        [
+            MontFp!(
+                "53808439732881537237246158587831476022567015633427561743741217251688401792763"
+            ),
+            MontFp!(
+                "47105163260261799763983359640763222251945742776990292689497926370914588858241"
+            ),
+            MontFp!(
+                "15664741619499923881579677102300978191991401789703812060067958386185541116202"
+            ),
+        ],
+        [
+            MontFp!(
+                "41087687137446177897612338604277254435839866726500603498380506594286398872236"
+            ),
+            MontFp!(
+                "49943543439728295671838195594873184932048948877641137594622764968733850096317"
+            ),
+            MontFp!(
+                "44842999935738914840387813136280796968298916880872205808246902061824465063587"
+            ),
+        ],
+        [
+            MontFp!(
+                "29740150237181231811035785215210987057887092035850412158491858028600326302565"
+            ),
+            MontFp!(
+                "20089655803013401633028203171433903817212002287476608257520546933925102387160"
+            ),
+            MontFp!(
+                "46921862101817551700242595351646353219619428783732034243388886183826844076197"
+            ),
+        ],
+        [
+            MontFp!(
+                "43592814817697225243818202009399729870211106676824220806719572704051337168664"
+            ),
+            MontFp!("600717596495082860948710749066702785125133892289167857687885499314912422735"),
+            MontFp!(
+                "21104423600872723219575403457623124773296840840841887665576456439391379369883"
+            ),
+        ],
+        [
+            MontFp!(
+                "32051594421992921562735005697338889441524227464470137605232006299770959588967"
+            ),
+            MontFp!("3617112040120476783180384933574316074670212868049678675018766402753380410319"),
+            MontFp!(
+                "37216964126639455389078039172923743463390089892468156702400164187256939888654"
+            ),
+        ],
+        [
+            MontFp!("2581971829671622277177024686738637196063355444664551277893009098296926342488"),
+            MontFp!("5907830626068405496106935828918954754631060789313181983323133075075008612069"),
+            MontFp!(
+                "51380129835856705706854489057578312652246239933364364573502281056151283339139"
+            ),
+        ],
+        [
+            MontFp!(
+                "40741484852598991245273369566974010206488893825945769216476151016948084175405"
+            ),
+            MontFp!(
+                "27399017424204676027501120042327475883231095440603723345535510747542897665107"
+            ),
+            MontFp!("2872190637676141869627101883284510036068881407344885242838878004301233749167"),
+        ],
+        [
+            MontFp!(
+                "33042610561586251055484067185023423612809178985918487671566576535448944569774"
+            ),
+            MontFp!(
+                "35931557954982981555306598699533700299838705158874669094538477809722957811179"
+            ),
+            MontFp!(
+                "56507639798842780755224058861760891846849265719542534762608921333224178193626"
+            ),
+        ],
+        [
+            MontFp!(
+                "39464005326590867626513383207948599547345176148868999557801370767257283162698"
+            ),
+            MontFp!(
+                "37679635178063294760614938649537633395584669043623921090698188270466394425003"
+            ),
+            MontFp!(
+                "33996007606151610437065622726933825615338546676332749853006287326921260252304"
+            ),
+        ],
+        [
+            MontFp!(
+                "54671040984765538992391138655141911836274209199084115477469620815947343764622"
+            ),
+            MontFp!(
+                "51822397356293981661165014707259044715936474498183099485675258228053606181000"
+            ),
+            MontFp!(
+                "47834119825557363517462017046751250392084978035895320807081438774520095846788"
+            ),
+        ],
+        [
+            MontFp!(
+                "44225639155617345504553025585965745002710204692149717559234481557947366820241"
+            ),
+            MontFp!(
+                "11982746798043942681504178125274528541129210003085292453294099801832742851365"
+            ),
+            MontFp!("8288322509537891408151680791520825803124363757965062911279669755112080810697"),
+        ],
+        [
+            MontFp!(
+                "53538859112034328743720079185810607757734401835477828913153780875278055305898"
+            ),
+            MontFp!(
+                "57579027042790612408893157339166116487138504149423828898744213823107368427336"
+            ),
+            MontFp!(
+                "44337795275044677467842642650657896948148045416706384742737349104081449120951"
+            ),
+        ],
+        [
+            MontFp!("9644669421397650911063513373813885323421594416423276118788466601313910027161"),
+            MontFp!(
+                "54680291614848409645493916218523209146934536556204043964347463903538159385703"
+            ),
+            MontFp!(
+                "42406749337489970012772747069200706608115930589821421937530604694325370643027"
+            ),
+        ],
+        [
+            MontFp!(
+                "43267962765475152806746220031338144087388848934115528837928600370277838390079"
+            ),
+            MontFp!(
+                "41207910612377004288527444913242057532485795370514033917823268578100326883452"
+            ),
+            MontFp!(
+                "26365509508760629903807930134140539608817533289175279869958021999072425104937"
+            ),
+        ],
+        [
+            MontFp!(
+                "42797076529785833663611125153667299874292159596761718058403168967115387913740"
+            ),
+            MontFp!(
+                "27625938636688872327471086181816164663153825869203972773955439744620736441204"
+            ),
+            MontFp!(
+                "31180124164389570435569814192445940315227054608337362383374076608876161332834"
+            ),
+        ],
+        [
+            MontFp!(
+                "11662629993848063016165720301505079114182591090336072900269333191943076379595"
+            ),
+            MontFp!(
+                "37407296616066627854377005354271060084276932454430899880196718393777982911469"
+            ),
+            MontFp!("4491616277146248297141325436033644872179112752493191488306595699781496535921"),
+        ],
+        [
+            MontFp!(
+                "20010372268189983219212775451053419433632493564420915505971351337631957901000"
+            ),
+            MontFp!(
+                "54798717120525379133086262858892082387983438212989697862220166206166110697801"
+            ),
+            MontFp!(
+                "10978808478257953205861382747345961631772609354780915018042457545093008411396"
+            ),
+        ],
+        [
+            MontFp!(
+                "43244387841404569287485696439602134071320892360057313292163885394904272267027"
+            ),
+            MontFp!(
+                "51401213852299916767321276761177587515975244921866170071530755842382899118011"
+            ),
+            MontFp!(
+                "28792455625413712606358505465073743831297021945197935212498050060201128628355"
+            ),
+        ],
+        [
+            MontFp!(
+                "29985165798939805637861174077811446939240709723984134013045959610137140965910"
+            ),
+            MontFp!(
+                "37591547266864131630651031591474201191783196357715833011333755229683896808362"
+            ),
+            MontFp!(
+                "31531124463870226134491235008549594346402101327242288930777450246412645847801"
+            ),
+        ],
+        [
+            MontFp!(
+                "37074022819025198482781342905334560569072764537226641836948125510892083924766"
+            ),
+            MontFp!(
+                "25447859281438887430246353244876356468403269627350391477355630386808919723482"
+            ),
+            MontFp!(
+                "49366414366587345977932538186115785557240590855234507916101705124365940809323"
+            ),
+        ],
+        [
+            MontFp!(
+                "56262880785504529724364689650870027094263506703976445825610803417728375905591"
+            ),
+            MontFp!("826057203547849494431865662918864890104236990164636784217431066091921537050"),
+            MontFp!("2542709578101410302228591816055616473895283068487409265040939194042203754586"),
+        ],
+        [
+            MontFp!(
+                "50657550840617928943444823005311716176014785211295227308724254225018893336008"
+            ),
+            MontFp!("1197641108166619430542428381795681109810123965791697080978565163148870420236"),
+            MontFp!(
+                "26619692863774273454875340226668348004781403956619368064805630005604495961310"
+            ),
+        ],
+        [
+            MontFp!(
+                "49160902112658841969608607629445900839425235862139448476510595385359423153462"
+            ),
+            MontFp!(
+                "17133269544540957820162674976253021890829029878975059005134155755254843149049"
+            ),
+            MontFp!(
+                "51668379296728665956717506545001781991222781005317248855469072887203782461313"
+            ),
+        ],
+        [
+            MontFp!(
+                "57109516426171583126693798083610721868417790725244616261869655435928131846126"
+            ),
+            MontFp!(
+                "35434885286934967168051071937236845027592782569664223989775804331851132460882"
+            ),
+            MontFp!(
+                "37722751287177282964399728641854988678883356800051457880050392960116185571483"
+            ),
+        ],
+        [
+            MontFp!(
+                "31156795382795628913000073517571758862171531235640811621640215418812364939618"
+            ),
+            MontFp!(
+                "12774167366222549164640775308755121689219946584652782069712895117377247531435"
+            ),
+            MontFp!(
+                "29039478828471010298919616743665195110384423233032364843562639722455770105207"
+            ),
+        ],
+        [
+            MontFp!(
+                "49823413986576353815191707028273853427544416232458649316987294019053107133305"
+            ),
+            MontFp!(
+                "22920771088921731242329975362399779007775496429411687119486282256841757870277"
+            ),
+            MontFp!(
+                "29450470327548103194201810108809116292029014264405344820130540009765234851166"
+            ),
+        ],
+        [
+            MontFp!(
+                "55530274155419132521130794385276304180300810253393529474550181636529966739157"
+            ),
+            MontFp!(
+                "52123797582005106460937967067689547581174587652528913985923316632484177221450"
+            ),
+            MontFp!(
+                "40570568601704763594354721082752597730362086232000740901104854226226236473935"
+            ),
+        ],
+        [
+            MontFp!(
+                "55604461450350552772920847598456800147637135251932820010182907123377533732404"
+            ),
+            MontFp!(
+                "44022197631701309109984978960013273708834125532142346142344773652591825896316"
+            ),
+            MontFp!(
+                "28668395425242517927906030212692024543856615227489957899491281067317186238507"
+            ),
+        ],
+        [
+            MontFp!(
+                "53467714128051638616729790103238884614885620379349525810364678950370117811564"
+            ),
+            MontFp!(
+                "13122418833399715538055330575448394496382152474120151351133687470473242209129"
+            ),
+            MontFp!(
+                "44514344115876316038201271747757517572598108451445339875749781614585488202700"
+            ),
+        ],
+        [
+            MontFp!("1850150316370974323922447251725697087365351132537766183006605824822081077388"),
+            MontFp!("4978490733807166962619056429629296173426258022465226767423442459294996447195"),
+            MontFp!(
+                "32243998722700426123947169265204250628231221242328473885307644524225262303414"
+            ),
+        ],
+        [
+            MontFp!(
+                "36875950640591360414177852483187337801819077572871788141449500725290774096184"
+            ),
+            MontFp!(
+                "57535625163135858770673530512900937805557913683945464241425584091613245093528"
+            ),
+            MontFp!(
+                "31646933677330055598794554664439463931638776281753664428926978227279588031171"
+            ),
+        ],
+        [
+            MontFp!(
+                "48769165354152198519153548529114877646250649395042348492503407290229959260641"
+            ),
+            MontFp!(
+                "49998650160264808739287857026170465193962953559732455564133598482186801284415"
+            ),
+            MontFp!(
+                "49619048985201662124651192023754253685928004706105138869040152041127734867927"
+            ),
+        ],
+        [
+            MontFp!(
+                "38659279525094232372717514449287422965042263285782177261726698905134162258433"
+            ),
+            MontFp!(
+                "43004025272889607895450358960038891706016201325466993220607439150039910650033"
+            ),
+            MontFp!(
+                "57444133555801092657644646933610865685649010180178839188041486451870383266624"
+            ),
+        ],
+        [
+            MontFp!("2595254334966190945939018373598394159569967776109641950096759787214034643242"),
+            MontFp!(
+                "46984892278058147922822886686428042694931907837357261594103071714206306022939"
+            ),
+            MontFp!(
+                "43008269336836575201567503601359597868055873460748257561784002538486645487363"
+            ),
+        ],
+        [
+            MontFp!(
+                "42654827500664590075498520334321651607969681731214607968232868037373461776388"
+            ),
+            MontFp!(
+                "28174510803958207476474521534372524101259841109403300537665082448863254480941"
+            ),
+            MontFp!(
+                "52489572814708879305620239149758524486077386920493431771388027022329794703098"
+            ),
+        ],
+        [
+            MontFp!(
+                "52942599525102492615404745834977401075984875837210161769494120079852087925088"
+            ),
+            MontFp!(
+                "50710956098639545042408470085903370580483837620166040885787533266006234178738"
+            ),
+            MontFp!(
+                "36348138998810346850801715220502475908773974658003736379205616048535573364714"
+            ),
+        ],
+        [
+            MontFp!(
+                "26560857810587203521497213934150148152203253935461796740821668490595976883125"
+            ),
+            MontFp!(
+                "30212623876572486214529001714466248064995982505891780149527032696384963770450"
+            ),
+            MontFp!(
+                "20637055854555490222616471572704587192517018803098465847624047096974720039721"
+            ),
+        ],
+        [
+            MontFp!(
+                "48934127866013873463213250824205826980394631579651060031385054997002266881490"
+            ),
+            MontFp!(
+                "53108681817915749131873245741880160510281221687743995095476776216692992766834"
+            ),
+            MontFp!("9539221625871995826326811589927410710014271484077055349621217952030539262016"),
+        ],
+        [
+            MontFp!(
+                "47893903180471944372865496991674143736913398511031982599436855844199930440476"
+            ),
+            MontFp!(
+                "19992657045398792811217196401809536157896075148905010888918082560842201090894"
+            ),
+            MontFp!(
+                "32155756995659794358726099970911126030685051605062481337977001586382522210357"
+            ),
+        ],
+        [
+            MontFp!(
+                "48270207790292303735150501108052436262771725865623879236665973602241188517565"
+            ),
+            MontFp!(
+                "18611537610461590989656972528464681692246808779156394698437109437307061961162"
+            ),
+            MontFp!(
+                "46854806160033446563133452900699141798242224499071638115899864797902702827992"
+            ),
+        ],
+        [
+            MontFp!("6776237201982462136004850084415823079394074396497406748754921511264001752486"),
+            MontFp!(
+                "15768600032688675917680550678710628889624074062361581796122065361737430177406"
+            ),
+            MontFp!(
+                "28525012044326805313965994480742961912860585861987149937596883668387044936199"
+            ),
+        ],
+        [
+            MontFp!("6454564018426258995568837101535850894961485634930968355094307486277015994836"),
+            MontFp!(
+                "39097269546844582154693338074848130244779615526461465373207217472626080486619"
+            ),
+            MontFp!(
+                "10777114957950012457784982948390867841104593300492604933595610492500464211401"
+            ),
+        ],
+        [
+            MontFp!(
+                "21800613120622040545888763692661297796169984959418368672904781096974086516743"
+            ),
+            MontFp!(
+                "47116285511151690797162942348041168905744591350402375931869842726170132515357"
+            ),
+            MontFp!("8175815071782734632373668084672621347053504570000725273033002727404852629717"),
+        ],
+        [
+            MontFp!(
+                "13750612786386876062475808356649162739697411068939860221158562909022688734264"
+            ),
+            MontFp!("6724008606255077524781611821679232438447743360322583859572135440224257137588"),
+            MontFp!(
+                "30072170874711613669380720425005455831551026344215888503451044507789643451402"
+            ),
+        ],
+        [
+            MontFp!(
+                "10649198016316735593583871713205782457401271051528820949315672918186124436679"
+            ),
+            MontFp!(
+                "54652553079876222962257841265371566587749691892817272412097228959130096414631"
+            ),
+            MontFp!("8428512827117483958460381696066800963612492360214849978012204059064439880961"),
+        ],
+        [
+            MontFp!(
+                "56163481259115497067524996002433123190526209729922535019322933464256584531776"
+            ),
+            MontFp!(
+                "49244182410782495757365195804851810505450147895249253278839277953927330449317"
+            ),
+            MontFp!(
+                "16332722423672962542458023749527910343774781113052967793357473881399130631187"
+            ),
+        ],
+        [
+            MontFp!(
+                "11181440460855741189252551215305831814563231522618075283535053648244347094087"
+            ),
+            MontFp!(
+                "37702116056545645968596577605703259745297261448722876464453780284490513788166"
+            ),
+            MontFp!(
+                "49876646164815014554960898500605879733110542921356997881583907422505232869945"
+            ),
+        ],
+        [
+            MontFp!(
+                "32754180248326657965225578415924259588702314333144431655533240785304056431886"
+            ),
+            MontFp!(
+                "21557704609745711144377671783633947304559973613145269642313955365116709849725"
+            ),
+            MontFp!(
+                "37873630996848088167454863524281563021729753561953850540342920102933293494898"
+            ),
+        ],
+        [
+            MontFp!(
+                "54606123705552152543156683679675621147868554393054497109555671228399784993230"
+            ),
+            MontFp!(
+                "54824944333782584094117826544774618970080221230550267390546721284722302783428"
+            ),
+            MontFp!(
+                "14630404541985761056317642014281328925921966928216913667606686248282953854547"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "19298681539552699237261830834781317975544997444273427339909597334652188273078"
+            ),
+            MontFp!(
+                "43422033463993573283839119378257965444976244249615211514796594002967423614425"
+            ),
+            MontFp!(
+                "34737626771194858627071295502606372355980995399692169211837275202373938891540"
+            ),
+        ],
+        [
+            MontFp!(
+                "43422033463993573283839119378257965444976244249615211514796594002967423614425"
+            ),
+            MontFp!(
+                "34737626771194858627071295502606372355980995399692169211837275202373938891540"
+            ),
+            MontFp!("9649340769776349618630915417390658987772498722136713669954798667326094136539"),
+        ],
+        [
+            MontFp!(
+                "34737626771194858627071295502606372355980995399692169211837275202373938891540"
+            ),
+            MontFp!("9649340769776349618630915417390658987772498722136713669954798667326094136539"),
+            MontFp!(
+                "49625181101706940895816136432294817651401421999560241731196107431962769845057"
+            ),
+        ],
+    ],
+};
@@ -0,0 +1,82 @@
+//! Poseidon over [`Fp255`] with the `x^5` S-box.
+//!
+//! This module provides the rate-1 and rate-2 instances of Poseidon over [`Fp255`]
+//! with the `x^5` S-box, with the same API as the instances over [`Fq`](crate::Fq),
+//! and R1CS gadgets for them with the `r1cs` feature. These instances are entirely
+//! separate from those of [`alpha_3`](super::alpha_3): the same inputs hash to unrelated
+//! outputs.
+
+use crate::field::FieldParameters;
+
+pub use super::Fp255;
+
+#[cfg(feature = "r1cs")]
+pub mod r1cs;
+mod rate_1;
+mod rate_2;
+
+/// Parameters for the rate-1 instance of Poseidon over [`Fp255`] with the `x^5` S-box.
+pub const RATE_1_PARAMS: FieldParameters<Fp255, 2> = rate_1::RATE_1_PARAMS;
+
+/// Parameters for the rate-2 instance of Poseidon over [`Fp255`] with the `x^5` S-box.
+pub const RATE_2_PARAMS: FieldParameters<Fp255, 3> = rate_2::RATE_2_PARAMS;
+
+/// Hash a single [`Fp255`] element with the provided `domain_separator`.
+pub fn hash_1(domain_separator: &Fp255, value: Fp255) -> Fp255 {
+    RATE_1_PARAMS.n_to_1_fixed_hash([*domain_separator, value])
+}
+
+/// Hash two [`Fp255`] elements with the provided `domain_separator`.
+pub fn hash_2(domain_separator: &Fp255, value: (Fp255, Fp255)) -> Fp255 {
+    RATE_2_PARAMS.n_to_1_fixed_hash([*domain_separator, value.0, value.1])
+}
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use ark_ff::PrimeField;
+    use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+    use super::*;
+
+    fn fp(s: &str) -> Fp255 {
+        Fp255::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn alpha_5_params_are_consistent() {
+        for (alpha, rounds) in [
+            (RATE_1_PARAMS.alpha, RATE_1_PARAMS.rounds),
+            (RATE_2_PARAMS.alpha, RATE_2_PARAMS.rounds),
+        ] {
+            assert_eq!(alpha, Alpha::Exponent(5));
+            assert_eq!(rounds, RoundNumbers { r_P: 56, r_F: 8 });
+        }
+        assert_eq!(RATE_1_PARAMS.arc.len(), RATE_1_PARAMS.rounds.total());
+        assert_eq!(RATE_2_PARAMS.arc.len(), RATE_2_PARAMS.rounds.total());
+
+        // The MDS matrix is the Cauchy matrix `1 / (i + t + j)`.
+        let mds = RATE_2_PARAMS.mds;
+        for (i, row) in mds.iter().enumerate() {
+            for (j, m) in row.iter().enumerate() {
+                assert_eq!(*m * Fp255::from((i + 3 + j) as u64), Fp255::from(1u64));
+            }
+        }
+    }
+
+    #[test]
+    fn alpha_5_hashes() {
+        let domain_sep = Fp255::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let [a, b] = [1u64, 2].map(Fp255::from);
+
+        assert_eq!(
+            hash_1(&domain_sep, a),
+            fp("21797193498573924591058141805115021795975916023291495140830655530332144093661")
+        );
+        assert_eq!(
+            hash_2(&domain_sep, (a, b)),
+            fp("47976733830284987796187957359468953607530381994142004907797355158937642523251")
+        );
+    }
+}
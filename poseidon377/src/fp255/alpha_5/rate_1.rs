@@ -0,0 +1,506 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fp255;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-1 instance of Poseidon over [`Fp255`] with the `x^5` S-box.
+pub const RATE_1_PARAMS: FieldParameters<Fp255, 2> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!(
+                "47956159340059737273236505701656439699920989906677006063652044821557518321446"
+            ),
+            MontFp!(
+                "38046388304527005287463372260624924943294436589787675765821019171676375322333"
+            ),
+        ],
+        [
+            MontFp!("7744036028858944768595424970122741399635198781909860460984263690258386151840"),
+            MontFp!(
+                "39861427413084477075702616807182702870149182017798475753685886900367014673338"
+            ),
+        ],
+        [
+            MontFp!("2501756948983272363558397028159506490986719874321567188600701298897443975840"),
+            MontFp!(
+                "34397649532649348839606034235507353839862783926713234198346870067814139153798"
+            ),
+        ],
+        [
+            MontFp!(
+                "26940157294168237449279177342941480452235765387106397703444172674345318206932"
+            ),
+            MontFp!(
+                "57279783520250945770315734892431487563007553341930797488633610139117360254663"
+            ),
+        ],
+        [
+            MontFp!(
+                "18221375945627451740032915302934126569711462872501787064517892537388119200526"
+            ),
+            MontFp!(
+                "12137133727842763488151958073111987497920102217554442041181534907321654704987"
+            ),
+        ],
+        [
+            MontFp!(
+                "36469002720969933237044687698142398229796054985889787143024072354085579927627"
+            ),
+            MontFp!(
+                "14681291489776837839888892981908656841652083806909518470139004797706899805434"
+            ),
+        ],
+        [
+            MontFp!(
+                "33948817783592813976481600955195809206662828118792049249418979889337533044399"
+            ),
+            MontFp!(
+                "42323636866170137208023390601992219666850365492304895403040660020416911779161"
+            ),
+        ],
+        [
+            MontFp!(
+                "16090221132773590194024305369801782992434785885627085966995862466287706760604"
+            ),
+            MontFp!("2209110329917523764216951938823636902752090400179771649350118452199951290461"),
+        ],
+        [
+            MontFp!(
+                "22574309500194464861623525689350920079085794894875323654612089927802596533501"
+            ),
+            MontFp!(
+                "19343618804887465245530914202004948804866544235101973070623004007124545498239"
+            ),
+        ],
+        [
+            MontFp!(
+                "37562853484999405248812908937296294623953318905936137288545181234525070805603"
+            ),
+            MontFp!("5553567506183707951292182143354298068864399824771803900132676696327785031797"),
+        ],
+        [
+            MontFp!(
+                "11774177755939276850833162496124780190835437808056830753917276580990944962597"
+            ),
+            MontFp!(
+                "17986491839115610363744382426692832525764821948359492352616557241356606896717"
+            ),
+        ],
+        [
+            MontFp!(
+                "35551494751100654338120896416791594515702005164124754816043857638310816251894"
+            ),
+            MontFp!(
+                "42063191721259534344897828371038104359359607841694622154362377927182601919669"
+            ),
+        ],
+        [
+            MontFp!(
+                "33947419344991938841055625725677987999032178360544482801403736470358824133593"
+            ),
+            MontFp!(
+                "25305893541164422253552586402225700096005247207058163145828423232684770008576"
+            ),
+        ],
+        [
+            MontFp!(
+                "54783091340923253853665502649568792289548239742860365810176294515114476166093"
+            ),
+            MontFp!(
+                "48567285113215232494639911121278644054582928134831804411255103319137050177789"
+            ),
+        ],
+        [
+            MontFp!(
+                "29626639044384520363030837250959765984964847925242419938686419534343053698442"
+            ),
+            MontFp!(
+                "29705662838789079858150750728851958052886333560213358663144230739414678801240"
+            ),
+        ],
+        [
+            MontFp!(
+                "12954164382300826075378369636045460375853141067047933608513745359699037217103"
+            ),
+            MontFp!(
+                "29270562836517950917215145358958616758687663354231376754981481661482811889262"
+            ),
+        ],
+        [
+            MontFp!(
+                "26467292619470896059249319474970538284623932527612419993293737260870116171677"
+            ),
+            MontFp!(
+                "26212014768072312783858095135599602777692378621080927752125045551421683447906"
+            ),
+        ],
+        [
+            MontFp!("5429707756901743970579765418770120756291219846825985761358970385760532117308"),
+            MontFp!("243157936409345281369531931742196328166797400600669923420136091000104132773"),
+        ],
+        [
+            MontFp!(
+                "44637656258212736424040021566126440277740713226425172358603320424453374431723"
+            ),
+            MontFp!(
+                "18192771692256770317844705949870388757533492374863840865017219521819214425905"
+            ),
+        ],
+        [
+            MontFp!(
+                "51293444962105309363692035506693721185625860519643490550291946674255226130330"
+            ),
+            MontFp!("333644600945895085130541838109313344629933295860775257040158822951601152575"),
+        ],
+        [
+            MontFp!(
+                "20207106138137859142116826610565922363025647649294053880946094641764078080259"
+            ),
+            MontFp!(
+                "44651520569009950048171789881280321607230306036492023359495283807693488288899"
+            ),
+        ],
+        [
+            MontFp!(
+                "41818072680156026553360104876354038871599098925217716042523831088720497993358"
+            ),
+            MontFp!(
+                "40999238475801192810097255382490684784422381723002098400603521884174026269490"
+            ),
+        ],
+        [
+            MontFp!(
+                "13557451327973362678178457200397097184189105273637471035137404344967367240294"
+            ),
+            MontFp!("301147395155981240210559375742489481752419172551978228381586760123447253197"),
+        ],
+        [
+            MontFp!(
+                "15044447142202506430505803561798588865491350384490146974865438380036486576274"
+            ),
+            MontFp!(
+                "41744216238045358295746231331422309746382081178498385499171608794721726040850"
+            ),
+        ],
+        [
+            MontFp!(
+                "30609464672780715442117145720139039723065881080733804595939225544998885254363"
+            ),
+            MontFp!(
+                "47591685792088935637325656197647841549638466155694491688856901438787025840268"
+            ),
+        ],
+        [
+            MontFp!(
+                "21167413540947722113954641837950736557128957564585109888322375924121683965981"
+            ),
+            MontFp!(
+                "33390616793322528938293161630310017368118090391557307715763080082850834080650"
+            ),
+        ],
+        [
+            MontFp!(
+                "39749846941655022850900487660224784406950181220221965478431155490060372762694"
+            ),
+            MontFp!(
+                "12713221874301957259609020370827210340210036667116721359427951150522744880080"
+            ),
+        ],
+        [
+            MontFp!(
+                "54207268140389434319798466537723983643032873779328368674394490387288653701273"
+            ),
+            MontFp!(
+                "30975700211647245508374450383744177498040849986196393285548361373535268960930"
+            ),
+        ],
+        [
+            MontFp!(
+                "26825934274898722522886028617720143463385531778598049630744723141978440664739"
+            ),
+            MontFp!(
+                "20589728487427280839150421962239513271464166018935670433157960354684332158587"
+            ),
+        ],
+        [
+            MontFp!(
+                "28952458624935335926570007224032877597367866025385632935373549803851847644254"
+            ),
+            MontFp!(
+                "29544081116589714831581318371190390466554445329411571106264520002452853680675"
+            ),
+        ],
+        [
+            MontFp!(
+                "53772839066627463581968344593538347052809300614903291149265866810742924156426"
+            ),
+            MontFp!("6732768242534983416150288571230353920037256744730184077278594994980364998145"),
+        ],
+        [
+            MontFp!(
+                "51695993525200949020920801044071741534533566689300008204755515716483568569988"
+            ),
+            MontFp!(
+                "49841515112811610644715547851687080521454152148671926051266173277282348358263"
+            ),
+        ],
+        [
+            MontFp!(
+                "29396053803609837499835620919083590131884533250532279000648042980661886420128"
+            ),
+            MontFp!(
+                "26922789202615763129889297478839836110823410040354654635218665163878936274467"
+            ),
+        ],
+        [
+            MontFp!("1368361944332209042681560470163572550350426648799600828163898984550252249574"),
+            MontFp!(
+                "30092750315347230922976896380667330874231016877240911371790901464954936572540"
+            ),
+        ],
+        [
+            MontFp!("7510551453043407981674721056603148174220190969118822927481648771495780054053"),
+            MontFp!(
+                "23284493795933529516823259550403880090370874447151596209353891105442647204027"
+            ),
+        ],
+        [
+            MontFp!(
+                "54867702096825763173210932398847948575304558671047367692106973767956316816842"
+            ),
+            MontFp!("1673408282470989285408613841583937113776137303759480057934772203441744534741"),
+        ],
+        [
+            MontFp!("6287497829446024423976988370167234441229012953543925374663497391373584976818"),
+            MontFp!(
+                "12751715659868034204662835579310137730743014352025673855420335114196290791768"
+            ),
+        ],
+        [
+            MontFp!(
+                "52157835463215840897569413512036961706741174230941255107600650898711524116721"
+            ),
+            MontFp!(
+                "11793283993787802162723766665274953448077809243346947103527037153140895703919"
+            ),
+        ],
+        [
+            MontFp!(
+                "44853197477914315724500360084453236502167369585565074769534786703632959233452"
+            ),
+            MontFp!(
+                "36397109509016878760711151797219681324240387386092144681704341566157433977042"
+            ),
+        ],
+        [
+            MontFp!(
+                "26320563004977422823873729280604292775003459275883012141725808349483220054995"
+            ),
+            MontFp!(
+                "51327148064753828457202866999672766073300734621788751084880127506108297639503"
+            ),
+        ],
+        [
+            MontFp!(
+                "23055714241025016016248593599064420729946947507806613222090814548896893157845"
+            ),
+            MontFp!(
+                "46431286772611021606409313935360467411695764203047207679940344860391217103516"
+            ),
+        ],
+        [
+            MontFp!(
+                "32370041911564139949227010057885424880002220561239387690530157070797819894498"
+            ),
+            MontFp!(
+                "24850386103160815423369297014636790210969026240141353079276115079168787955873"
+            ),
+        ],
+        [
+            MontFp!(
+                "41274728956640132385300678504842747883476277000041201247360039363066070129303"
+            ),
+            MontFp!(
+                "28628116148893648679851043146390024249107893264901402479156939033377762384016"
+            ),
+        ],
+        [
+            MontFp!(
+                "31743503210683944358971725103213275426124059318002380753446026447311362976809"
+            ),
+            MontFp!(
+                "20601626132715312813027682380901242004091255345870113479854393128654708761778"
+            ),
+        ],
+        [
+            MontFp!(
+                "39131174476605527904233206139538594880708050851880954958927346738960372512952"
+            ),
+            MontFp!("3054930502820741399097851197373233254308946901446647773732604050053580761738"),
+        ],
+        [
+            MontFp!("1915203731749871291527638704586505597476451268529571495355189941848150472411"),
+            MontFp!(
+                "10252160151767104058481523657722774948639745703712503234628773491953146740251"
+            ),
+        ],
+        [
+            MontFp!(
+                "52306115614176978318915089008276645380972025101796721844038494779675289271565"
+            ),
+            MontFp!(
+                "57317340193312117732787579984911216471815377586766036195184123908167322615806"
+            ),
+        ],
+        [
+            MontFp!("3570107964415354254410626614856677169418629036103607333104299874541897389267"),
+            MontFp!(
+                "17253245050651702477823973256241834512289003626775891790603646987460550104918"
+            ),
+        ],
+        [
+            MontFp!(
+                "16466266013429056164825230222783692494807286225975122388665860249615612219831"
+            ),
+            MontFp!(
+                "40796105385478496096089074195490925152347211496215511436739582727540410197212"
+            ),
+        ],
+        [
+            MontFp!("3755250110355046402937604134227134549191660573616564099322235154117988692668"),
+            MontFp!(
+                "55032368290704672600332732848316200847593235391465759340770797539837282211671"
+            ),
+        ],
+        [
+            MontFp!(
+                "13831606593954588768025089982440525700262844498806745002937546818153655820856"
+            ),
+            MontFp!(
+                "34616858222575264953751110380280157083135561997455207799679221790854192418433"
+            ),
+        ],
+        [
+            MontFp!("8037250033242990973372090900258252904532195724130366675919599311011191885404"),
+            MontFp!(
+                "41632818110308713508538367320975583633677358604640910913576986355286595793276"
+            ),
+        ],
+        [
+            MontFp!(
+                "23577611101438890242882486005535252096134898805365492835529376575555562600494"
+            ),
+            MontFp!(
+                "18560893756623472827058660782823188896945065791750491852432403658623791955816"
+            ),
+        ],
+        [
+            MontFp!(
+                "37467534841652968413745079345264056176370804730829122831967667232909508503516"
+            ),
+            MontFp!(
+                "21243544753818823520863639564761638484945643475673790302295153309720370142446"
+            ),
+        ],
+        [
+            MontFp!(
+                "34192532801389498665069259304851054590910080843071901138800235553929002250316"
+            ),
+            MontFp!(
+                "41951501727108550911638958933760271507427477595834445353944768932802934032394"
+            ),
+        ],
+        [
+            MontFp!(
+                "30289989210879085106634758022274006021581390953458229399105587873868823961456"
+            ),
+            MontFp!(
+                "49251335681925860495252492959439267398492328037791775609013376458420930659615"
+            ),
+        ],
+        [
+            MontFp!("311281162781512296369431713988883547417525479426907474297175622402785911707"),
+            MontFp!(
+                "39046745825829201896664250193623829023648564963854605158763654160649879341721"
+            ),
+        ],
+        [
+            MontFp!(
+                "37043376167700001093165893799763283738749457371963288348444892652762954481397"
+            ),
+            MontFp!(
+                "41282251293325117899807615004076540833669360192426941298571844814574815669516"
+            ),
+        ],
+        [
+            MontFp!(
+                "25899876319728769845936398633896112143454539671992687229487908082146324503617"
+            ),
+            MontFp!(
+                "27727783254143817680461052553306177725292728236900967634304408589409605881914"
+            ),
+        ],
+        [
+            MontFp!(
+                "17257000480305082887998947777860224561392117351397558659591546508126647755813"
+            ),
+            MontFp!(
+                "37653878324762482178545500984990261762280730163717666608719283980405290345021"
+            ),
+        ],
+        [
+            MontFp!(
+                "48570056482391817313348271101995791479966440866016165082073572972822240293095"
+            ),
+            MontFp!(
+                "17924114455227387974665832663530797122221520975175436228461658877123581810147"
+            ),
+        ],
+        [
+            MontFp!(
+                "18368863758950077417380986916238535316125096899258501637523199755210727278917"
+            ),
+            MontFp!(
+                "31659901691827716869542193425037546483260577898445898749117578040547319200212"
+            ),
+        ],
+        [
+            MontFp!(
+                "33397500248583776725743883024999168853634619736394443176714266652175728863573"
+            ),
+            MontFp!(
+                "45076967813858467186307915799806501732484721326329610146757129097835939909326"
+            ),
+        ],
+        [
+            MontFp!(
+                "26784978292033420007446999775980207275399024995988436048575020213930228830144"
+            ),
+            MontFp!(
+                "14072194493759531044037500822474326845092359408665153763092609755974910403336"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "28948022309329048855892746252171976963317496166410141009864396001978282409617"
+            ),
+            MontFp!(
+                "19298681539552699237261830834781317975544997444273427339909597334652188273078"
+            ),
+        ],
+        [
+            MontFp!(
+                "19298681539552699237261830834781317975544997444273427339909597334652188273078"
+            ),
+            MontFp!(
+                "43422033463993573283839119378257965444976244249615211514796594002967423614425"
+            ),
+        ],
+    ],
+};
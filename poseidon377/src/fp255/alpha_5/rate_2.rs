@@ -0,0 +1,677 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fp255;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-2 instance of Poseidon over [`Fp255`] with the `x^5` S-box.
+pub const RATE_2_PARAMS: FieldParameters<Fp255, 3> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!(
+                "34668910467531436748576063603703834421119150212654059156175230761453393473118"
+            ),
+            MontFp!("761697010307941974957014448122276146463753739633479037471133238576391445322"),
+            MontFp!(
+                "27332118059951645348890107477665944865518905209828653878980949174224319834771"
+            ),
+        ],
+        [
+            MontFp!("5858635057855961123531409479037139897948694514242558059064071871260292847143"),
+            MontFp!(
+                "22209892103929935504977234040420358567831314367203845432729041953086821161436"
+            ),
+            MontFp!(
+                "55961125371077223270489702978911334159120976733439668192359161094234362835622"
+            ),
+        ],
+        [
+            MontFp!(
+                "30379677214243809720273077642037303253548423329639323868068419893988857946028"
+            ),
+            MontFp!(
+                "14795955986983105416636805420676637535414623531095114491058522101527422438777"
+            ),
+            MontFp!("8134637435888357247219308296235475615173067886792782130842524379371115961003"),
+        ],
+        [
+            MontFp!(
+                "35306092005666790377841474670201105380004287773120251875952301010583626024076"
+            ),
+            MontFp!("6531905914155895558925982717546172925370888342797293659658106085084097160835"),
+            MontFp!(
+                "14589155999479811963417779307293898746942465757945587272323220326423933490929"
+            ),
+        ],
+        [
+            MontFp!(
+                "56777925826338771800452723632918859797402220789129578804618042418703748760174"
+            ),
+            MontFp!(
+                "19698941480830604248845562777751509477302830246036675895613870472487545827056"
+            ),
+            MontFp!(
+                "20431654581398336918344340886529866158495865290274473706910480700763909085668"
+            ),
+        ],
+        [
+            MontFp!(
+                "11286358617479756590252765254782528896819875617923494627847926821959292503004"
+            ),
+            MontFp!(
+                "35326093030618242731619439043658563295125594886700148228818365886776667683263"
+            ),
+            MontFp!(
+                "20288644183022433981368296258728689869434130139797224328908462589763743949178"
+            ),
+        ],
+        [
+            MontFp!(
+                "44869594322410348370006050902044969710166417946746645768170241493314213552683"
+            ),
+            MontFp!(
+                "22474482322587332139686260345733856682559342818763290896127454367614267678271"
+            ),
+            MontFp!(
+                "50332847795828226940812868729520359999479389535158774023067390177593049682908"
+            ),
+        ],
+        [
+            MontFp!(
+                "24157286806060727620457612800732402180313231322819959124419035499991892957948"
+            ),
+            MontFp!(
+                "15483433993497359287080077631932268349023320748385891824282494619154606061116"
+            ),
+            MontFp!(
+                "12413527660307785784538720961715393750601396483889231220859802529285762110467"
+            ),
+        ],
+        [
+            MontFp!("6756918722827597919947048068533618982122410648527103585314184516876022586798"),
+            MontFp!(
+                "47908729564060099633302806367396748560690056160523332524294772098937513667075"
+            ),
+            MontFp!(
+                "56382293043981918770909604898424412837895130450028084562620664377889920800745"
+            ),
+        ],
+        [
+            MontFp!(
+                "46041810892260199352657907100224689190685962986723318863171006418811767301006"
+            ),
+            MontFp!(
+                "19501085648070711034185888823630362849890362785753544798954527926951302455549"
+            ),
+            MontFp!(
+                "34458663697182448816058702426650719071566835666637017671860624591040707972440"
+            ),
+        ],
+        [
+            MontFp!(
+                "12160032491294723052205765600102478233565309314146350296539380368154531542785"
+            ),
+            MontFp!(
+                "27824917497242248453265181727885438091259202776755483924582689394265005164660"
+            ),
+            MontFp!("82366464148804622338664721930844532261210792766521032039887480367629934781"),
+        ],
+        [
+            MontFp!(
+                "13617490149923236851328878453256373416721256826698841803635674551551319971865"
+            ),
+            MontFp!(
+                "34559449180414413191223404317838058885965601034193949715839357541750318495489"
+            ),
+            MontFp!(
+                "16252339187091420782507111079965007950863404781533847947188299115132991669428"
+            ),
+        ],
+        [
+            MontFp!(
+                "56384479831523036511910820269625381784583977813349301729548004575174900495366"
+            ),
+            MontFp!(
+                "23486140155541512692955069612104713212005639104229349893525730422452965697259"
+            ),
+            MontFp!(
+                "47820917245541216210280402179580878212782026310945262804160364515953474735075"
+            ),
+        ],
+        [
+            MontFp!(
+                "33880920058964593372178821792229116227356101967600369489937196360575633331441"
+            ),
+            MontFp!(
+                "54387786634802704538300328750933193782918770157991676242253826172070662704288"
+            ),
+            MontFp!(
+                "55570855216670577280080097884986625378393054272635839374758963326798050368129"
+            ),
+        ],
+        [
+            MontFp!(
+                "35580373468329727796976346080262155090588932387813689854234869025338051637520"
+            ),
+            MontFp!(
+                "18481926248601207411191184689899842008902328454752667234786383303408127721461"
+            ),
+            MontFp!(
+                "16144141846730455381039924417236080823593034248551004124458214464308996610397"
+            ),
+        ],
+        [
+            MontFp!(
+                "15550322531346145723483975080949634404515283296483512875733214032164641304107"
+            ),
+            MontFp!(
+                "20362428321052075425934879617160204667423741579302310915229109409725538212897"
+            ),
+            MontFp!(
+                "31417226133307304981005154937016710808210739701902155017960364458754473398043"
+            ),
+        ],
+        [
+            MontFp!(
+                "25077432924759595416326819547363269782154004105913648665104607474528528317585"
+            ),
+            MontFp!(
+                "21163811818586252488376732599861819017741749136845079430368328489102632176708"
+            ),
+            MontFp!(
+                "25072375319336527983273468443125851825552610170364150794088930297790626647926"
+            ),
+        ],
+        [
+            MontFp!(
+                "31365840097053289816857674673955478873165704099912161241991331433834849990927"
+            ),
+            MontFp!(
+                "43685554900602054155450304181223757317378840836906896744383522594063242513154"
+            ),
+            MontFp!("4643294186953103620854029105932586418250125139930150968455632529507416844281"),
+        ],
+        [
+            MontFp!(
+                "44981038782462934326060250679343831432701249665042654165745200651731866321297"
+            ),
+            MontFp!(
+                "31121328382563637127850647494150420607432957139554496900723708555151423888611"
+            ),
+            MontFp!(
+                "17548121151186223368237041950513453866765677152347020369921330815801341369974"
+            ),
+        ],
+        [
+            MontFp!(
+                "10484436280019589061855681348041200985763132461349560153610829400602347440007"
+            ),
+            MontFp!(
+                "10727842287067462426745393944095606292878697103392361658101312563336790792414"
+            ),
+            MontFp!(
+                "53189837575230667291191836926430882041983343526454788631643361705190061006487"
+            ),
+        ],
+        [
+            MontFp!(
+                "20113676857686698700574883807815549064045647083474650159343164430875100008805"
+            ),
+            MontFp!("4507842384245338362794908309855388919988168628487290528393945537127514436681"),
+            MontFp!(
+                "44094720395182982865868967326038650858790641206812795053103567606933630452153"
+            ),
+        ],
+        [
+            MontFp!(
+                "22407890299475927837523658494403331616525561823520553499455857309557660408218"
+            ),
+            MontFp!("1991268586151605648470262045713017354694310047829821102200255004986063767083"),
+            MontFp!(
+                "45336083251777929480989806965594857735297326833241662085731769399968226054622"
+            ),
+        ],
+        [
+            MontFp!(
+                "38667197709022900587961313831607230001323692336931775728296081184002599386158"
+            ),
+            MontFp!(
+                "12955359697648481764177596749451809591654117296069163889898687395076436992176"
+            ),
+            MontFp!(
+                "19175169792379625380694392640298987960065615424778301404162851705522036103636"
+            ),
+        ],
+        [
+            MontFp!(
+                "50281190681048276547091723347313136929574155669743345494544666657644292792952"
+            ),
+            MontFp!(
+                "49930094896478319049536519434858095044227114060247329311101640932161341791441"
+            ),
+            MontFp!(
+                "18362347455217537854756005564695244321835352775659232913835166442481028911998"
+            ),
+        ],
+        [
+            MontFp!("6542033225363877900483018134220277751394801176659291717197819579303546483899"),
+            MontFp!(
+                "47113342295334118074340947899032099707684580646140498669562890765334417635403"
+            ),
+            MontFp!(
+                "57558376451565643983044013575042112335761935940073167129402890831184232881036"
+            ),
+        ],
+        [
+            MontFp!(
+                "23682331986871637989607731502268444551373824720625124142099308135475030719960"
+            ),
+            MontFp!("5263545095263835843607000503164182476686967630435277358900452214415835878806"),
+            MontFp!(
+                "48516426391759072439567843850368806376394134629167254684861933474420562921624"
+            ),
+        ],
+        [
+            MontFp!(
+                "31282634334164509173027836863629246071859594084684685754436411712286122210010"
+            ),
+            MontFp!(
+                "24155233427168103619322585112296941605218606939751024015597112730273839604718"
+            ),
+            MontFp!(
+                "50362557926318846238727094177067096541421988083238432136910955460980214658884"
+            ),
+        ],
+        [
+            MontFp!(
+                "12264614907815110288855034111971857225948079703621078327173679570267368583899"
+            ),
+            MontFp!("9021798563117474247533999623399552025914679218343112965472237536379530442376"),
+            MontFp!(
+                "52722370869883028685684042895739942620408808229475243018911788599508366610560"
+            ),
+        ],
+        [
+            MontFp!(
+                "30468069750269992024198121775370320348877350357251649155352495525876207104198"
+            ),
+            MontFp!("8721860431437719746789217323439512026519977325541734320306316867979922895436"),
+            MontFp!(
+                "49663523864231387106347747275444053256206886682789356569373611145712196938376"
+            ),
+        ],
+        [
+            MontFp!(
+                "46963924209075085305228834957134938207004309951475916723546503543835190246251"
+            ),
+            MontFp!(
+                "17589917222083218957431949602545698746186535081391445923146892407300417210835"
+            ),
+            MontFp!("3661577833473151105405516950962329455731649450056436791508339383163771054192"),
+        ],
+        [
+            MontFp!(
+                "18612505560755038539064867272960786907730940080308310948232321941044804587993"
+            ),
+            MontFp!(
+                "57423861775022619354243582791395615668328966890485833147344617076104962909202"
+            ),
+            MontFp!(
+                "51294196798851340085718704003126304362312030881604767812461606435517917364067"
+            ),
+        ],
+        [
+            MontFp!(
+                "54352056397007180338538747507103254770134161731484440612323302890942719821684"
+            ),
+            MontFp!(
+                "28604904507810111524660025980741985328307642163062062216643872674721587175340"
+            ),
+            MontFp!(
+                "28156721536490137375410390829658850427369711278222490623773652609228396685430"
+            ),
+        ],
+        [
+            MontFp!(
+                "34884110277955760916840572233821110182222479426457577643211712873717456140372"
+            ),
+            MontFp!(
+                "27181265297842121975008027048411684346583804793992399784076150838290618474493"
+            ),
+            MontFp!(
+                "13839352907729226572832269008789637833563581943782646779530631241295803394170"
+            ),
+        ],
+        [
+            MontFp!(
+                "30313947012478263572259679085006038840744080781833124241160900483119630262587"
+            ),
+            MontFp!("2554565779627433225476689339568192826102565491239290645245822255093341350449"),
+            MontFp!("5093831213814825325766709410629555776296744051955924964106706551420764559848"),
+        ],
+        [
+            MontFp!(
+                "37215477817552724854380404553429137514471079057449892359558024988202156197194"
+            ),
+            MontFp!(
+                "10205604821897448718872900796397388447593825788542958617004400267178034752971"
+            ),
+            MontFp!(
+                "34372987312631695895564426122938828307102992153476918058302218103146688669432"
+            ),
+        ],
+        [
+            MontFp!(
+                "48065063970355454781102331410438072394667927855273045680523743229365482452837"
+            ),
+            MontFp!(
+                "38983696714575910852606778233083081064773150635686679228551249191398172569800"
+            ),
+            MontFp!(
+                "35319617514410426577677520827828233630128106975895852441532804625261759144410"
+            ),
+        ],
+        [
+            MontFp!(
+                "15316420591279543094086534611715307649106114697209572209018655448798394753686"
+            ),
+            MontFp!("8990737094629493772782489338639638132268649445308144919306476658084523983229"),
+            MontFp!(
+                "25770247178559053703086822365924749000094491019409789509782920499165800183936"
+            ),
+        ],
+        [
+            MontFp!(
+                "24049650694263633530997075998202000107557479264049875791669907843874604147927"
+            ),
+            MontFp!("8621956380626817560280787246098120232803676226881106800638238415912995413007"),
+            MontFp!(
+                "36382284593765611936141638798090036843027762859342797681243839617085919500265"
+            ),
+        ],
+        [
+            MontFp!(
+                "13379594844860670146373465951382732708621730113716761407750889894227768608270"
+            ),
+            MontFp!(
+                "33450110468522257207236040213880920020715280316092901447836549343167632059493"
+            ),
+            MontFp!(
+                "34785702781493829532129137109157527963739874939200946645788987707120888589107"
+            ),
+        ],
+        [
+            MontFp!(
+                "25923389521627707522814136243095754660815699279385265219307363052920444526663"
+            ),
+            MontFp!("5806238442362055922776035483453008617421667719331154130114426911122899502217"),
+            MontFp!(
+                "21279733373649442916725875886398911052106799572660416136579639866116142986641"
+            ),
+        ],
+        [
+            MontFp!(
+                "56690185835683284509910609477639906552074542445102410030784185213513422719051"
+            ),
+            MontFp!(
+                "46274019423280874058222231147963461306321752441392816861225494479725561457284"
+            ),
+            MontFp!(
+                "37968405969822937717339347301175024693933994179814324170399744557412377745096"
+            ),
+        ],
+        [
+            MontFp!("7014702814691650291559417276683002708913272039074445866803571006991412301678"),
+            MontFp!(
+                "46503479117714626891366268729833769514838893536359289388926389648582941560826"
+            ),
+            MontFp!("4321362985526989399230996885720397456176750614022262737384341638134118519363"),
+        ],
+        [
+            MontFp!(
+                "43129343658019276127856812700338348750083732411424168460956789742037659782340"
+            ),
+            MontFp!(
+                "13455067801870762118600767252837472334650270863808807677849640328881860419538"
+            ),
+            MontFp!(
+                "37152001164683671002510380800693799760004949898828788560736290377768375509297"
+            ),
+        ],
+        [
+            MontFp!(
+                "26861925319224341697919949261665905938697668112080041642099574832695270996912"
+            ),
+            MontFp!("4044851679483798623346202181568598138697575991151819713979134534548544600522"),
+            MontFp!("779861479611825596895309031318652533711950760774114404132676259869318895703"),
+        ],
+        [
+            MontFp!(
+                "16364879424828822966891573988225128322525159231174389053124193577257687459804"
+            ),
+            MontFp!("8053330430672736826133821537575452808356982290633317968161503034261177796256"),
+            MontFp!(
+                "44672512254633557959856633512434355459760799258651919890661531918251810218033"
+            ),
+        ],
+        [
+            MontFp!(
+                "39018438014331970795581991276092170967318129515747145098665104728819879011437"
+            ),
+            MontFp!(
+                "13678634677063099478282809355849794709783772224148879113725849957477398007003"
+            ),
+            MontFp!(
+                "45767049592860575592584020716882042934379308120538912927136949471503301247987"
+            ),
+        ],
+        [
+            MontFp!(
+                "21818832483442326541342828200546958555412727786005504026355575875528165171126"
+            ),
+            MontFp!(
+                "38157575434915916502035729059422861268992672880504717756476464415392420074098"
+            ),
+            MontFp!(
+                "56278845572742598147543233638588584663442919237594660024976423512262943024797"
+            ),
+        ],
+        [
+            MontFp!(
+                "24398387593010404227673556164966925514540007263057361852213334943235377728864"
+            ),
+            MontFp!(
+                "14970443078062981295159528609258472495913936318559006698951840574240132259425"
+            ),
+            MontFp!(
+                "19786170801293140451294267656480290086442869042632945523463835176130446747701"
+            ),
+        ],
+        [
+            MontFp!(
+                "53735291745778099094458657551108682380217374239006138476630931298111983720652"
+            ),
+            MontFp!(
+                "54283529429495869998056708344193557262775647907418170230690227672889921053721"
+            ),
+            MontFp!(
+                "41717069031897410110578972894391926226475912490867189423662053007764027927896"
+            ),
+        ],
+        [
+            MontFp!("3885604173178756598839169969134699001915067021801024873003312449808391750823"),
+            MontFp!("7566363097658528486981284365759135823461654675114917159513017312735885877708"),
+            MontFp!("5521494124887064340583140226196446210526318108629560899044365601244023696381"),
+        ],
+        [
+            MontFp!("6960133446158713327111964497430679607273837488277036324793934312077779502807"),
+            MontFp!(
+                "14339951616864488794399091548684535237285747538444610343373156478317353339338"
+            ),
+            MontFp!(
+                "33192548473780351733381633443592125528050710715311244640696435353069745333156"
+            ),
+        ],
+        [
+            MontFp!("2086223892785485873342007967231228715653682593430481734537070875486987565000"),
+            MontFp!("7975557074410354994020631375077401444132940701562838470122357944518082823978"),
+            MontFp!(
+                "41959972845472318398561167105763576713872003388180538939506186975383421542774"
+            ),
+        ],
+        [
+            MontFp!(
+                "52058419662501216012852188846079633066801296276696680602581088083747207589028"
+            ),
+            MontFp!(
+                "48389095161438105992295227858864083440765787453910949786704729403999312146552"
+            ),
+            MontFp!(
+                "50965598129339315629541459952195848409656013327746825399835601509065795512424"
+            ),
+        ],
+        [
+            MontFp!("4225487098492356011558162184528211068655023522750604306898848073000297027327"),
+            MontFp!(
+                "38859131751561910183609384626247215364080907426253529497109698623046742703397"
+            ),
+            MontFp!(
+                "49409759505978148455847393622843994447769592649720807346034642701940843282135"
+            ),
+        ],
+        [
+            MontFp!(
+                "27926189906496632753630932925601499044424724012542361392311267336410892577509"
+            ),
+            MontFp!(
+                "30317752456762559817985452357211277254157636758164935776113927278408795384821"
+            ),
+            MontFp!(
+                "46476237757793439818346245128787006785121904902246051656160642877759010160990"
+            ),
+        ],
+        [
+            MontFp!(
+                "56876943894321906122666751430277743503784188393378054481685937394248404574996"
+            ),
+            MontFp!(
+                "13731911515581294224161141525144602385399876370398663627352587240853092150349"
+            ),
+            MontFp!(
+                "45412739054897240799128506946136496656153929093933916336908316140426141271273"
+            ),
+        ],
+        [
+            MontFp!("7693327585094134911309429653576961816893413556192995904450944397521778178983"),
+            MontFp!("9840166760680065528209346623738999380424529028669212235538989889502059551034"),
+            MontFp!(
+                "33472607729039497322509674058814516060848545384850217781697017581010939742219"
+            ),
+        ],
+        [
+            MontFp!(
+                "19151526182484980628082445671300621738969966941183643410994056208604405169485"
+            ),
+            MontFp!(
+                "23158120482590372285166702187080380338881180688902833801276524635302039075162"
+            ),
+            MontFp!(
+                "28769393840454398849984247767758083548388307507226655739945213890957557491055"
+            ),
+        ],
+        [
+            MontFp!(
+                "27295724166960011276983095185775565014737850138195961970400013537418641246885"
+            ),
+            MontFp!(
+                "20367144870375328866406320177865969675527527343657485270697913364568179873153"
+            ),
+            MontFp!(
+                "20353634575202132158021476888219913937656264135384779721791033591854049634325"
+            ),
+        ],
+        [
+            MontFp!(
+                "35446566043746589346664497595462046501075933342846865681517678579017270065348"
+            ),
+            MontFp!(
+                "46524926471758461018159196869483750827137763907686800847688088683586709273779"
+            ),
+            MontFp!(
+                "53731005066483661931266440123176733346538428943393103612163032992827338278381"
+            ),
+        ],
+        [
+            MontFp!(
+                "27857866242247365123269888606601033543853914064462035182292720640137857117521"
+            ),
+            MontFp!(
+                "14699796480224667758151525479396162554268534259358549419167475934719681763032"
+            ),
+            MontFp!(
+                "30173510669206820355134846565004602001154154887028647535789702958110008885656"
+            ),
+        ],
+        [
+            MontFp!(
+                "43579029216905413775708185421722141825743393863285516070157544247259073651728"
+            ),
+            MontFp!(
+                "57234328228238861278725933833172133216426348202408021837106412955910165093801"
+            ),
+            MontFp!(
+                "22809493384446779771037833668153418833201069853097550594739481337923156393454"
+            ),
+        ],
+        [
+            MontFp!("4644053735756985814244241099752535095360543393870471722278166031969851882362"),
+            MontFp!("2227068951186598403999540316420318219657338820902237986069128354633626480409"),
+            MontFp!(
+                "57225759378246134234470158112932642315091767880736629274473113002192464897925"
+            ),
+        ],
+        [
+            MontFp!("1740930163060859031544443583139988286158555241972509913927185461315644800069"),
+            MontFp!(
+                "29704530210388567976374845382529269208140937201425109891578453814244482998074"
+            ),
+            MontFp!(
+                "47227252428306516268255474386729645985499002992377078118466740685371132259103"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "19298681539552699237261830834781317975544997444273427339909597334652188273078"
+            ),
+            MontFp!(
+                "43422033463993573283839119378257965444976244249615211514796594002967423614425"
+            ),
+            MontFp!(
+                "34737626771194858627071295502606372355980995399692169211837275202373938891540"
+            ),
+        ],
+        [
+            MontFp!(
+                "43422033463993573283839119378257965444976244249615211514796594002967423614425"
+            ),
+            MontFp!(
+                "34737626771194858627071295502606372355980995399692169211837275202373938891540"
+            ),
+            MontFp!("9649340769776349618630915417390658987772498722136713669954798667326094136539"),
+        ],
+        [
+            MontFp!(
+                "34737626771194858627071295502606372355980995399692169211837275202373938891540"
+            ),
+            MontFp!("9649340769776349618630915417390658987772498722136713669954798667326094136539"),
+            MontFp!(
+                "49625181101706940895816136432294817651401421999560241731196107431962769845057"
+            ),
+        ],
+    ],
+};
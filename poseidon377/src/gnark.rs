@@ -0,0 +1,359 @@
+//! Export of the permutation as Go source for [gnark].
+//!
+//! [`export`] renders one or more [`Descriptor`]s as a Go package built on
+//! `gnark-crypto`'s BLS12-377 scalar field. For each rate `r` the package contains
+//! the round numbers, round constants and MDS matrix, a native `PermuteRateR` and
+//! `HashRateR` over `fr.Element`, and `PermuteRateRCircuit` and
+//! `HashRateRCircuit` for use inside a `frontend.API` circuit. The accompanying
+//! test file checks a golden vector computed by this crate against both.
+//!
+//! Field elements are written as big-endian hex strings, which `fr.Element`'s
+//! `SetString` accepts directly.
+//!
+//! [gnark]: https://github.com/Consensys/gnark
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+use crate::{
+    plonkish::{Descriptor, SboxStep},
+    Fq,
+};
+
+/// The files making up an exported Go package.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GoPackage {
+    /// Contents of the package source file, e.g. `poseidon377.go`.
+    pub source: String,
+    /// Contents of the package test file, e.g. `poseidon377_test.go`.
+    pub test: String,
+}
+
+/// Render `descriptors` as the Go package `package`.
+///
+/// The golden vector for each rate hashes the inputs `1, ..., rate` with a domain
+/// separator of zero.
+///
+/// # Panics
+///
+/// Panics if two descriptors have the same rate.
+pub fn export(package: &str, descriptors: &[Descriptor]) -> GoPackage {
+    let mut source = format!("{}{}", HEADER, SOURCE_PRELUDE.replace("$PACKAGE", package));
+    let mut test = format!("{}{}", HEADER, TEST_PRELUDE.replace("$PACKAGE", package));
+
+    let mut rates = Vec::new();
+    for descriptor in descriptors {
+        assert!(
+            !rates.contains(&descriptor.rate),
+            "duplicate descriptor for rate {}",
+            descriptor.rate
+        );
+        rates.push(descriptor.rate);
+
+        write_source(&mut source, descriptor);
+        write_test(&mut test, descriptor);
+    }
+
+    GoPackage { source, test }
+}
+
+const HEADER: &str = "// Code generated by poseidon377. DO NOT EDIT.\n\n";
+
+const SOURCE_PRELUDE: &str = r#"package $PACKAGE
+
+import (
+	"math/big"
+
+	"github.com/consensys/gnark-crypto/ecc/bls12-377/fr"
+	"github.com/consensys/gnark/frontend"
+)
+
+func element(s string) fr.Element {
+	var e fr.Element
+	if _, err := e.SetString(s); err != nil {
+		panic(err)
+	}
+	return e
+}
+
+func elements(rows [][]string) [][]fr.Element {
+	out := make([][]fr.Element, len(rows))
+	for i, row := range rows {
+		out[i] = make([]fr.Element, len(row))
+		for j, s := range row {
+			out[i][j] = element(s)
+		}
+	}
+	return out
+}
+
+func bigInt(e *fr.Element) *big.Int {
+	return e.BigInt(new(big.Int))
+}
+"#;
+
+const TEST_PRELUDE: &str = r#"package $PACKAGE
+
+import (
+	"testing"
+
+	"github.com/consensys/gnark-crypto/ecc"
+	"github.com/consensys/gnark-crypto/ecc/bls12-377/fr"
+	"github.com/consensys/gnark/frontend"
+	"github.com/consensys/gnark/test"
+)
+"#;
+
+const SOURCE_RATE: &str = r#"
+// Parameters for the rate-$RATE instance of Poseidon.
+const (
+	Rate$RATEFullRounds    = $FULL
+	Rate$RATEPartialRounds = $PARTIAL
+)
+
+var Rate$RATERoundConstants = elements($ROUND_CONSTANTS)
+
+var Rate$RATEMDS = elements($MDS)
+
+func sboxRate$RATE(x fr.Element) fr.Element {
+	acc := x
+$NATIVE_SBOX	return acc
+}
+
+// PermuteRate$RATE applies the rate-$RATE permutation to a state of $WIDTH elements.
+func PermuteRate$RATE(state []fr.Element) {
+	for r := 0; r < $ROUNDS; r++ {
+		for i := range state {
+			state[i].Add(&state[i], &Rate$RATERoundConstants[r][i])
+		}
+		if r < $HALF || r >= $HALF+$PARTIAL {
+			for i := range state {
+				state[i] = sboxRate$RATE(state[i])
+			}
+		} else {
+			state[0] = sboxRate$RATE(state[0])
+		}
+		mixed := make([]fr.Element, $WIDTH)
+		for i := range mixed {
+			for j := range state {
+				var t fr.Element
+				t.Mul(&Rate$RATEMDS[i][j], &state[j])
+				mixed[i].Add(&mixed[i], &t)
+			}
+		}
+		copy(state, mixed)
+	}
+}
+
+// HashRate$RATE hashes $RATE elements with the given domain separator.
+func HashRate$RATE(domainSeparator fr.Element, input []fr.Element) fr.Element {
+	state := make([]fr.Element, $WIDTH)
+	state[0] = domainSeparator
+	copy(state[1:], input)
+	PermuteRate$RATE(state)
+	return state[1]
+}
+
+func sboxRate$RATECircuit(api frontend.API, x frontend.Variable) frontend.Variable {
+	acc := x
+$CIRCUIT_SBOX	return acc
+}
+
+// PermuteRate$RATECircuit applies the rate-$RATE permutation to a state of $WIDTH variables.
+func PermuteRate$RATECircuit(api frontend.API, state []frontend.Variable) {
+	for r := 0; r < $ROUNDS; r++ {
+		for i := range state {
+			state[i] = api.Add(state[i], bigInt(&Rate$RATERoundConstants[r][i]))
+		}
+		if r < $HALF || r >= $HALF+$PARTIAL {
+			for i := range state {
+				state[i] = sboxRate$RATECircuit(api, state[i])
+			}
+		} else {
+			state[0] = sboxRate$RATECircuit(api, state[0])
+		}
+		mixed := make([]frontend.Variable, $WIDTH)
+		for i := range mixed {
+			mixed[i] = 0
+			for j := range state {
+				mixed[i] = api.Add(mixed[i], api.Mul(state[j], bigInt(&Rate$RATEMDS[i][j])))
+			}
+		}
+		copy(state, mixed)
+	}
+}
+
+// HashRate$RATECircuit hashes $RATE variables with the given domain separator.
+func HashRate$RATECircuit(api frontend.API, domainSeparator frontend.Variable, input []frontend.Variable) frontend.Variable {
+	state := make([]frontend.Variable, $WIDTH)
+	state[0] = domainSeparator
+	copy(state[1:], input)
+	PermuteRate$RATECircuit(api, state)
+	return state[1]
+}
+"#;
+
+const TEST_RATE: &str = r#"
+var rate$RATEGolden = []string{$GOLDEN_INPUT}
+
+const rate$RATEGoldenOutput = "$GOLDEN_OUTPUT"
+
+func TestHashRate$RATE(t *testing.T) {
+	input := make([]fr.Element, $WIDTH)
+	for i, s := range rate$RATEGolden {
+		input[i] = element(s)
+	}
+	got := HashRate$RATE(input[0], input[1:])
+	want := element(rate$RATEGoldenOutput)
+	if !got.Equal(&want) {
+		t.Fatalf("got %s, want %s", got.String(), want.String())
+	}
+}
+
+type hashRate$RATECircuit struct {
+	In  [$WIDTH]frontend.Variable
+	Out frontend.Variable `gnark:",public"`
+}
+
+func (c *hashRate$RATECircuit) Define(api frontend.API) error {
+	api.AssertIsEqual(HashRate$RATECircuit(api, c.In[0], c.In[1:]), c.Out)
+	return nil
+}
+
+func TestHashRate$RATECircuit(t *testing.T) {
+	var assignment hashRate$RATECircuit
+	for i, s := range rate$RATEGolden {
+		assignment.In[i] = element(s)
+	}
+	assignment.Out = element(rate$RATEGoldenOutput)
+	if err := test.IsSolved(&hashRate$RATECircuit{}, &assignment, ecc.BLS12_377.ScalarField()); err != nil {
+		t.Fatal(err)
+	}
+}
+"#;
+
+fn write_source(out: &mut String, descriptor: &Descriptor) {
+    let mut native_sbox = String::new();
+    let mut circuit_sbox = String::new();
+    for step in &descriptor.sbox {
+        match step {
+            SboxStep::Square => {
+                native_sbox.push_str("\tacc.Square(&acc)\n");
+                circuit_sbox.push_str("\tacc = api.Mul(acc, acc)\n");
+            }
+            SboxStep::Multiply => {
+                native_sbox.push_str("\tacc.Mul(&acc, &x)\n");
+                circuit_sbox.push_str("\tacc = api.Mul(acc, x)\n");
+            }
+            SboxStep::Inverse => {
+                // gnark-crypto's Inverse already maps zero to zero.
+                native_sbox.push_str("\tacc.Inverse(&acc)\n");
+                circuit_sbox.push_str(
+                    "\tisZero := api.IsZero(acc)\n\
+                     \tacc = api.Select(isZero, 0, api.Inverse(api.Select(isZero, 1, acc)))\n",
+                );
+            }
+        }
+    }
+
+    let rate = SOURCE_RATE
+        .replace("$ROUND_CONSTANTS", &rows(&descriptor.round_constants))
+        .replace("$MDS", &rows(&descriptor.mds))
+        .replace("$NATIVE_SBOX", &native_sbox)
+        .replace("$CIRCUIT_SBOX", &circuit_sbox);
+    out.push_str(&substitute(&rate, descriptor));
+}
+
+fn write_test(out: &mut String, descriptor: &Descriptor) {
+    let mut state: Vec<Fq> = (0..descriptor.state_size as u64).map(Fq::from).collect();
+    let input: Vec<String> = state.iter().map(|x| format!("\"{}\"", hex(x))).collect();
+    descriptor.permute(&mut state);
+
+    let rate = TEST_RATE
+        .replace("$GOLDEN_INPUT", &input.join(", "))
+        .replace("$GOLDEN_OUTPUT", &hex(&state[1]));
+    out.push_str(&substitute(&rate, descriptor));
+}
+
+/// Substitute the per-rate placeholders shared by the source and test templates.
+fn substitute(template: &str, descriptor: &Descriptor) -> String {
+    template
+        .replace(
+            "$ROUNDS",
+            &(descriptor.full_rounds + descriptor.partial_rounds).to_string(),
+        )
+        .replace("$HALF", &(descriptor.full_rounds / 2).to_string())
+        .replace("$FULL", &descriptor.full_rounds.to_string())
+        .replace("$PARTIAL", &descriptor.partial_rounds.to_string())
+        .replace("$WIDTH", &descriptor.state_size.to_string())
+        .replace("$RATE", &descriptor.rate.to_string())
+}
+
+/// Encode a matrix as a Go `[][]string` literal of hex strings.
+fn rows(rows: &[Vec<Fq>]) -> String {
+    let mut out = String::from("[][]string{\n");
+    for row in rows {
+        out.push_str("\t{");
+        for (j, element) in row.iter().enumerate() {
+            if j > 0 {
+                out.push_str(", ");
+            }
+            write!(out, "\"{}\"", hex(element)).expect("writing to a string cannot fail");
+        }
+        out.push_str("},\n");
+    }
+    out.push('}');
+    out
+}
+
+/// Encode a field element as `0x`-prefixed big-endian hex.
+fn hex(element: &Fq) -> String {
+    let mut out = String::from("0x");
+    for byte in element.to_bytes().iter().rev() {
+        write!(out, "{:02x}", byte).expect("writing to a string cannot fail");
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{RATE_2_PARAMS, RATE_4_PARAMS};
+
+    #[test]
+    fn golden_vectors() {
+        let package = export(
+            "poseidon377",
+            &[
+                Descriptor::new(&RATE_2_PARAMS),
+                Descriptor::new(&RATE_4_PARAMS),
+            ],
+        );
+        assert!(package.source.contains("package poseidon377\n"));
+        assert!(!package.source.contains('$'));
+        assert!(!package.test.contains('$'));
+
+        let expected = crate::hash_2(&Fq::from(0u64), (Fq::from(1u64), Fq::from(2u64)));
+        assert!(package
+            .test
+            .contains(&format!("const rate2GoldenOutput = \"{}\"", hex(&expected))));
+
+        let input = [1u64, 2, 3, 4].map(Fq::from);
+        let expected = crate::hash_4(&Fq::from(0u64), (input[0], input[1], input[2], input[3]));
+        assert!(package
+            .test
+            .contains(&format!("const rate4GoldenOutput = \"{}\"", hex(&expected))));
+    }
+
+    #[test]
+    fn hex_is_big_endian() {
+        assert_eq!(
+            hex(&Fq::from(0x0102u64)),
+            "0x0000000000000000000000000000000000000000000000000000000000000102"
+        );
+    }
+}
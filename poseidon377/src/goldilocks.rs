@@ -0,0 +1,94 @@
+//! Poseidon over the Goldilocks field.
+//!
+//! Proof systems such as plonky2 and plonky3 work over [`Goldilocks`], the 64-bit
+//! field of order `2^64 - 2^32 + 1`. This module provides a width-12 instance of
+//! Poseidon over it, the width of the Poseidon of plonky2, for a security level of 128
+//! bits.
+//!
+//! A single element of a 64-bit field cannot hold 128 bits of security, so unlike the
+//! instances over [`Fq`](crate::Fq), the capacity is [`CAPACITY`] elements, the domain
+//! separator and three zero words, and the output is four elements, squeezed from the
+//! rate. The instance therefore has rate `12 - 4 = 8`.
+//!
+//! The round numbers and round constants are derived with the transcript procedure of
+//! `poseidon-paramgen`, and the S-box is `x^7`, as `3` and `5` divide `p - 1`. The
+//! MDS matrix is the Cauchy matrix `1 / (i + t + j)` of the other instances.
+//! `poseidon-paramgen` does not generate it for fields below 128 bits, where a Cauchy
+//! matrix may admit infinitely long invariant subspace trails; for this matrix, the
+//! characteristic polynomials of its first 24 powers are irreducible, which rules them
+//! out (Grassi, Rechberger and Schofnegger, 2020). The constants differ from those of
+//! plonky2, which are derived with the Grain LFSR, so the outputs are not compatible.
+
+use ark_ff::fields::{Fp64, MontBackend, MontConfig};
+
+use crate::field::FieldParameters;
+
+mod rate_8;
+
+/// The Montgomery configuration of [`Goldilocks`].
+#[derive(MontConfig)]
+#[modulus = "18446744069414584321"]
+#[generator = "7"]
+pub struct GoldilocksConfig;
+
+/// The Goldilocks field, of order `2^64 - 2^32 + 1`.
+pub type Goldilocks = Fp64<MontBackend<GoldilocksConfig, 1>>;
+
+/// The number of capacity elements of the instance of this module.
+pub const CAPACITY: usize = 4;
+
+/// Parameters for the rate-8 instance of Poseidon over [`Goldilocks`].
+pub const RATE_8_PARAMS: FieldParameters<Goldilocks, 12> = rate_8::RATE_8_PARAMS;
+
+/// Hash eight [`Goldilocks`] elements with the provided `domain_separator`, producing
+/// four outputs.
+pub fn hash_8(domain_separator: &Goldilocks, value: [Goldilocks; 8]) -> [Goldilocks; 4] {
+    let mut state = [Goldilocks::from(0u64); 12];
+    state[0] = *domain_separator;
+    state[CAPACITY..].copy_from_slice(&value);
+    RATE_8_PARAMS.permute(&mut state);
+    [state[4], state[5], state[6], state[7]]
+}
+
+#[cfg(test)]
+mod test {
+    use ark_ff::PrimeField;
+    use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+    use super::*;
+
+    #[test]
+    fn goldilocks_params_are_consistent() {
+        assert_eq!(RATE_8_PARAMS.alpha, Alpha::Exponent(7));
+        assert_eq!(RATE_8_PARAMS.rounds, RoundNumbers { r_P: 22, r_F: 8 });
+        assert_eq!(RATE_8_PARAMS.arc.len(), RATE_8_PARAMS.rounds.total());
+
+        // The MDS matrix is the Cauchy matrix `1 / (i + t + j)`.
+        let mds = RATE_8_PARAMS.mds;
+        for (i, row) in mds.iter().enumerate() {
+            for (j, m) in row.iter().enumerate() {
+                assert_eq!(
+                    *m * Goldilocks::from((i + 12 + j) as u64),
+                    Goldilocks::from(1u64)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn goldilocks_hashes() {
+        let domain_sep = Goldilocks::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let value = [1u64, 2, 3, 4, 5, 6, 7, 8].map(Goldilocks::from);
+
+        assert_eq!(
+            hash_8(&domain_sep, value),
+            [
+                9659533874614903766u64,
+                14458584352082985335,
+                6338570112116164897,
+                1909495135857848955,
+            ]
+            .map(Goldilocks::from)
+        );
+    }
+}
@@ -0,0 +1,604 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Goldilocks;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-8 instance of Poseidon over [`Goldilocks`].
+pub const RATE_8_PARAMS: FieldParameters<Goldilocks, 12> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(7),
+    rounds: RoundNumbers { r_P: 22, r_F: 8 },
+    arc: &[
+        [
+            MontFp!("16033922385447811372"),
+            MontFp!("5421071271590838268"),
+            MontFp!("10333013596477060166"),
+            MontFp!("1964414535098002978"),
+            MontFp!("6603641215499322144"),
+            MontFp!("1583351396865532508"),
+            MontFp!("8753552017478424097"),
+            MontFp!("4838892377226679833"),
+            MontFp!("8306399905991816261"),
+            MontFp!("3634600109383992145"),
+            MontFp!("10799975640380195123"),
+            MontFp!("11120656500783529287"),
+        ],
+        [
+            MontFp!("13672267915882399957"),
+            MontFp!("383242510580690734"),
+            MontFp!("9397271384484521213"),
+            MontFp!("9987383739785626815"),
+            MontFp!("11177975700327112327"),
+            MontFp!("12363480409320811828"),
+            MontFp!("5840832346561565127"),
+            MontFp!("6360016711722117847"),
+            MontFp!("14069043830029952729"),
+            MontFp!("6134387651307190551"),
+            MontFp!("8092685463387878101"),
+            MontFp!("2572900033024953631"),
+        ],
+        [
+            MontFp!("8996402475395960992"),
+            MontFp!("18209195445511926629"),
+            MontFp!("8252759716507114207"),
+            MontFp!("18088766514942926388"),
+            MontFp!("13536554889047088975"),
+            MontFp!("3927116275467338149"),
+            MontFp!("9166386135654644183"),
+            MontFp!("875934899960244059"),
+            MontFp!("14726557486887958280"),
+            MontFp!("13687257285357507704"),
+            MontFp!("531391129861623916"),
+            MontFp!("9565938172999471673"),
+        ],
+        [
+            MontFp!("14205562686641127053"),
+            MontFp!("3342833333084392899"),
+            MontFp!("14636123581085549099"),
+            MontFp!("13932085652322925893"),
+            MontFp!("16324354208555174369"),
+            MontFp!("12064055273024850990"),
+            MontFp!("1828375566497682886"),
+            MontFp!("11103131411614833493"),
+            MontFp!("8734780174900757121"),
+            MontFp!("15068418287575480630"),
+            MontFp!("16411471505245259243"),
+            MontFp!("15549125676789079541"),
+        ],
+        [
+            MontFp!("381244467478850244"),
+            MontFp!("17790325732595989605"),
+            MontFp!("15155751458401463574"),
+            MontFp!("2071549426243919311"),
+            MontFp!("3097347973054455093"),
+            MontFp!("15379298976503586479"),
+            MontFp!("17953284713682448381"),
+            MontFp!("14203830483523317802"),
+            MontFp!("9964574015045962662"),
+            MontFp!("765040369092525383"),
+            MontFp!("14150846029815007654"),
+            MontFp!("18160659170998678551"),
+        ],
+        [
+            MontFp!("13888543818121593023"),
+            MontFp!("12475586712326680125"),
+            MontFp!("4339844885829627902"),
+            MontFp!("12929076839595030048"),
+            MontFp!("195749383712616652"),
+            MontFp!("11435018500775338911"),
+            MontFp!("5088898783498554419"),
+            MontFp!("7652356349720221183"),
+            MontFp!("12580914916249433063"),
+            MontFp!("15787408900716501019"),
+            MontFp!("31995923108391979"),
+            MontFp!("12758994179516469382"),
+        ],
+        [
+            MontFp!("16681673666757050872"),
+            MontFp!("6429127207391729147"),
+            MontFp!("5585874150508685822"),
+            MontFp!("15890544993031825069"),
+            MontFp!("10836413619934155587"),
+            MontFp!("7580480293814290110"),
+            MontFp!("6328393835048922547"),
+            MontFp!("4201901597242044303"),
+            MontFp!("17053824630495059507"),
+            MontFp!("16642254328579001388"),
+            MontFp!("6211517154207019077"),
+            MontFp!("10444500236741074408"),
+        ],
+        [
+            MontFp!("10865333334789428408"),
+            MontFp!("13026274800553087474"),
+            MontFp!("2008021003420738107"),
+            MontFp!("10156056571251565958"),
+            MontFp!("16198563057730028918"),
+            MontFp!("9886291916653805433"),
+            MontFp!("12745050148155298250"),
+            MontFp!("12753057842103864672"),
+            MontFp!("7300072752957955562"),
+            MontFp!("13241920317248938281"),
+            MontFp!("8621446511107749061"),
+            MontFp!("8060897283479684531"),
+        ],
+        [
+            MontFp!("870794623831551412"),
+            MontFp!("13597488047104582833"),
+            MontFp!("18379789303173708649"),
+            MontFp!("13160018445463192953"),
+            MontFp!("2402092296942102048"),
+            MontFp!("7928654618585533205"),
+            MontFp!("481824786408309895"),
+            MontFp!("8004748238290354064"),
+            MontFp!("13879807419420767764"),
+            MontFp!("120127857390048032"),
+            MontFp!("7246499394909918845"),
+            MontFp!("1145704437072377366"),
+        ],
+        [
+            MontFp!("3312886827406063180"),
+            MontFp!("13719679882010105511"),
+            MontFp!("1495760953026040207"),
+            MontFp!("17106608317482980051"),
+            MontFp!("7034297179046222447"),
+            MontFp!("11558530617759236604"),
+            MontFp!("8246957472382720583"),
+            MontFp!("9561048812840816751"),
+            MontFp!("15177891626648524670"),
+            MontFp!("7587146053333710476"),
+            MontFp!("340269243894345112"),
+            MontFp!("491288476929854004"),
+        ],
+        [
+            MontFp!("4474564482966231291"),
+            MontFp!("11182641534913397688"),
+            MontFp!("11204751924315558734"),
+            MontFp!("12877353974312913889"),
+            MontFp!("18183303631707484510"),
+            MontFp!("1344992824627186680"),
+            MontFp!("14769523064531803826"),
+            MontFp!("13146636018800246247"),
+            MontFp!("17421891950779288902"),
+            MontFp!("3201091527047254603"),
+            MontFp!("13805101716875317397"),
+            MontFp!("9672519333978002084"),
+        ],
+        [
+            MontFp!("10122324285451852777"),
+            MontFp!("17069383683644507549"),
+            MontFp!("10711525319639805298"),
+            MontFp!("10387652890899906867"),
+            MontFp!("14531798453482248349"),
+            MontFp!("9109062321434310359"),
+            MontFp!("10265564241787471351"),
+            MontFp!("4285211200213645060"),
+            MontFp!("6394063049677972305"),
+            MontFp!("1637693813188559786"),
+            MontFp!("16597607645323982771"),
+            MontFp!("16047600116540040573"),
+        ],
+        [
+            MontFp!("7539852668235078856"),
+            MontFp!("7512823208279894785"),
+            MontFp!("852896488456159379"),
+            MontFp!("2236024869644720336"),
+            MontFp!("1179885997299079576"),
+            MontFp!("17484952735974320483"),
+            MontFp!("5319230549058589621"),
+            MontFp!("12254869255672819745"),
+            MontFp!("14700210944422847088"),
+            MontFp!("4270991234318682531"),
+            MontFp!("15756810613482896940"),
+            MontFp!("33568893409226798"),
+        ],
+        [
+            MontFp!("16221138223114567874"),
+            MontFp!("15232501719694157844"),
+            MontFp!("17845179704457812025"),
+            MontFp!("7789862087684271719"),
+            MontFp!("7483312759490384032"),
+            MontFp!("11008854464631090636"),
+            MontFp!("8774550242128378951"),
+            MontFp!("14370666004139147378"),
+            MontFp!("17853222618550829784"),
+            MontFp!("5004348471736572497"),
+            MontFp!("4232636838478058340"),
+            MontFp!("5605426798774603239"),
+        ],
+        [
+            MontFp!("7657657736661897638"),
+            MontFp!("13110341814317057332"),
+            MontFp!("5863547118125223020"),
+            MontFp!("13830607212820575390"),
+            MontFp!("8556436538921578299"),
+            MontFp!("8600603138342978698"),
+            MontFp!("3135662886476419308"),
+            MontFp!("3329858137384594440"),
+            MontFp!("4732191337264502114"),
+            MontFp!("8898277318629989789"),
+            MontFp!("13779593918483003866"),
+            MontFp!("2022946472046883521"),
+        ],
+        [
+            MontFp!("7784383096674221973"),
+            MontFp!("17305739823479639648"),
+            MontFp!("1249144057848553509"),
+            MontFp!("197663701682192902"),
+            MontFp!("12104908577912739853"),
+            MontFp!("11539085885954474647"),
+            MontFp!("16888210593899344341"),
+            MontFp!("14551245337986137100"),
+            MontFp!("15980896900028891428"),
+            MontFp!("11647395739241558961"),
+            MontFp!("10258574677874285196"),
+            MontFp!("12250927254976122506"),
+        ],
+        [
+            MontFp!("11077664464164432669"),
+            MontFp!("13361430258279268743"),
+            MontFp!("12737866340166920031"),
+            MontFp!("1295422858063613063"),
+            MontFp!("1950377943384990821"),
+            MontFp!("9900944736779616994"),
+            MontFp!("434579190406043320"),
+            MontFp!("11723301623786928191"),
+            MontFp!("13747243927054969621"),
+            MontFp!("1141835611923047782"),
+            MontFp!("1373684425407344812"),
+            MontFp!("5096262182110846963"),
+        ],
+        [
+            MontFp!("1299585010952128211"),
+            MontFp!("10441742575103365165"),
+            MontFp!("6669564740137542417"),
+            MontFp!("11848307792280174166"),
+            MontFp!("7558701078822582501"),
+            MontFp!("17995333503833429616"),
+            MontFp!("18275185064451243562"),
+            MontFp!("12425107734964141344"),
+            MontFp!("16475361079981280993"),
+            MontFp!("4305150094455573586"),
+            MontFp!("4034480432133843347"),
+            MontFp!("5724844583898454891"),
+        ],
+        [
+            MontFp!("3981239185798356511"),
+            MontFp!("8367900191131932361"),
+            MontFp!("15205067193903612049"),
+            MontFp!("14001430411666127410"),
+            MontFp!("13001735555981964327"),
+            MontFp!("17973641489331626774"),
+            MontFp!("802849395683580020"),
+            MontFp!("1838996435853687476"),
+            MontFp!("3952758512789403281"),
+            MontFp!("5849514456001040044"),
+            MontFp!("6435672313587972860"),
+            MontFp!("10842223897033879528"),
+        ],
+        [
+            MontFp!("4526558087482797856"),
+            MontFp!("5077394770460134800"),
+            MontFp!("10189200138612435366"),
+            MontFp!("16706277264297913993"),
+            MontFp!("2289632122388500765"),
+            MontFp!("7368419357286875743"),
+            MontFp!("17794087959737157757"),
+            MontFp!("14509268092505264713"),
+            MontFp!("7442559023574800930"),
+            MontFp!("8658134716036757686"),
+            MontFp!("11031050878406591359"),
+            MontFp!("4101499219892496972"),
+        ],
+        [
+            MontFp!("10022904019292085721"),
+            MontFp!("7708968279470594645"),
+            MontFp!("13643301888879244425"),
+            MontFp!("750219645034124571"),
+            MontFp!("6297083888972993028"),
+            MontFp!("8499419847925685121"),
+            MontFp!("7917005464646464340"),
+            MontFp!("246885727834278769"),
+            MontFp!("11347328698335521684"),
+            MontFp!("11945875610952005657"),
+            MontFp!("1285831235738897485"),
+            MontFp!("1140486996086731383"),
+        ],
+        [
+            MontFp!("5011233598214459319"),
+            MontFp!("4191838029063484589"),
+            MontFp!("12048647953195535084"),
+            MontFp!("12087550298767953236"),
+            MontFp!("10895242989849703870"),
+            MontFp!("8718339583184282832"),
+            MontFp!("3573975951556316600"),
+            MontFp!("17530500710633193369"),
+            MontFp!("13460004461822464161"),
+            MontFp!("9151416802668870473"),
+            MontFp!("6718253825952869827"),
+            MontFp!("812378826001702955"),
+        ],
+        [
+            MontFp!("17788664859914960202"),
+            MontFp!("5684280214100932547"),
+            MontFp!("5794090157770429777"),
+            MontFp!("17967831351655994785"),
+            MontFp!("8414723787377315266"),
+            MontFp!("13421776665615452442"),
+            MontFp!("18242293112021653361"),
+            MontFp!("9400813369312109076"),
+            MontFp!("8194682304545277119"),
+            MontFp!("16311006052925632528"),
+            MontFp!("10704561374429927967"),
+            MontFp!("13585377168726540087"),
+        ],
+        [
+            MontFp!("9352957742296665030"),
+            MontFp!("14810953884562423373"),
+            MontFp!("4032169338312667582"),
+            MontFp!("3283810580793660806"),
+            MontFp!("15140619431779979984"),
+            MontFp!("3580994075885034754"),
+            MontFp!("9271621092005102923"),
+            MontFp!("5814243377784242252"),
+            MontFp!("5351995440233397080"),
+            MontFp!("12892931327596453101"),
+            MontFp!("12875028949628303748"),
+            MontFp!("15071816167409664185"),
+        ],
+        [
+            MontFp!("4502846738605280542"),
+            MontFp!("1030152285975448621"),
+            MontFp!("4930435884624096788"),
+            MontFp!("9563159595119360072"),
+            MontFp!("3420888555021442622"),
+            MontFp!("8086990751938221548"),
+            MontFp!("14628029923304429198"),
+            MontFp!("4724388127793396595"),
+            MontFp!("1096931638778617310"),
+            MontFp!("7301119093247701330"),
+            MontFp!("12792852589330646722"),
+            MontFp!("9828944109408388798"),
+        ],
+        [
+            MontFp!("630894833970510012"),
+            MontFp!("16494048552087364094"),
+            MontFp!("568987559967552525"),
+            MontFp!("12776520950660925528"),
+            MontFp!("16153382633792251076"),
+            MontFp!("12784926413170311916"),
+            MontFp!("5377035240697291170"),
+            MontFp!("13973596702790519140"),
+            MontFp!("5859446868606623348"),
+            MontFp!("13144620698878736782"),
+            MontFp!("17284482070115222425"),
+            MontFp!("9343685596643482083"),
+        ],
+        [
+            MontFp!("13907560135804442480"),
+            MontFp!("12205644855621959288"),
+            MontFp!("2297712393167129824"),
+            MontFp!("5385887433352225733"),
+            MontFp!("10381171463233416388"),
+            MontFp!("4012346490489605497"),
+            MontFp!("45917735815765597"),
+            MontFp!("3370061533433675140"),
+            MontFp!("74581063529820388"),
+            MontFp!("17931464063892554382"),
+            MontFp!("2442795367244948780"),
+            MontFp!("6556468727575388366"),
+        ],
+        [
+            MontFp!("13833304728357240592"),
+            MontFp!("1460647723715342793"),
+            MontFp!("16274321045897943730"),
+            MontFp!("16210536763872970635"),
+            MontFp!("14378626198039419912"),
+            MontFp!("3935237783874959319"),
+            MontFp!("7861519534822508510"),
+            MontFp!("2110554293983584952"),
+            MontFp!("10894928972806581397"),
+            MontFp!("18019801410925882461"),
+            MontFp!("9675678938217871710"),
+            MontFp!("12577162642590090880"),
+        ],
+        [
+            MontFp!("1496377628589819477"),
+            MontFp!("3937155235596828992"),
+            MontFp!("2977177536939153325"),
+            MontFp!("7110936647001471259"),
+            MontFp!("12586940052244320610"),
+            MontFp!("6062506638574033985"),
+            MontFp!("18121405524145114753"),
+            MontFp!("6257544177085732248"),
+            MontFp!("18191005696444614097"),
+            MontFp!("11499669148112065988"),
+            MontFp!("9130063028288968472"),
+            MontFp!("1657013329411259643"),
+        ],
+        [
+            MontFp!("11264890524229993732"),
+            MontFp!("9691952747467919396"),
+            MontFp!("298910134170318183"),
+            MontFp!("2657571378548019858"),
+            MontFp!("6922641602565495591"),
+            MontFp!("2711395428368101056"),
+            MontFp!("10099276771787341320"),
+            MontFp!("8036856041671320299"),
+            MontFp!("7618268445230245267"),
+            MontFp!("15068944357667107601"),
+            MontFp!("15111361208441926105"),
+            MontFp!("6309800982284609924"),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!("16909515396963368961"),
+            MontFp!("11351842504255128813"),
+            MontFp!("1317624576386756023"),
+            MontFp!("17216961131453612033"),
+            MontFp!("17293822565076172801"),
+            MontFp!("17361641477096079361"),
+            MontFp!("11273010264642245974"),
+            MontFp!("10679693934924233028"),
+            MontFp!("17524406865943855105"),
+            MontFp!("7027331074062698789"),
+            MontFp!("4192441833957860073"),
+            MontFp!("11228452911817573065"),
+        ],
+        [
+            MontFp!("11351842504255128813"),
+            MontFp!("1317624576386756023"),
+            MontFp!("17216961131453612033"),
+            MontFp!("17293822565076172801"),
+            MontFp!("17361641477096079361"),
+            MontFp!("11273010264642245974"),
+            MontFp!("10679693934924233028"),
+            MontFp!("17524406865943855105"),
+            MontFp!("7027331074062698789"),
+            MontFp!("4192441833957860073"),
+            MontFp!("11228452911817573065"),
+            MontFp!("17678129733188976641"),
+        ],
+        [
+            MontFp!("1317624576386756023"),
+            MontFp!("17216961131453612033"),
+            MontFp!("17293822565076172801"),
+            MontFp!("17361641477096079361"),
+            MontFp!("11273010264642245974"),
+            MontFp!("10679693934924233028"),
+            MontFp!("17524406865943855105"),
+            MontFp!("7027331074062698789"),
+            MontFp!("4192441833957860073"),
+            MontFp!("11228452911817573065"),
+            MontFp!("17678129733188976641"),
+            MontFp!("14019525492755084084"),
+        ],
+        [
+            MontFp!("17216961131453612033"),
+            MontFp!("17293822565076172801"),
+            MontFp!("17361641477096079361"),
+            MontFp!("11273010264642245974"),
+            MontFp!("10679693934924233028"),
+            MontFp!("17524406865943855105"),
+            MontFp!("7027331074062698789"),
+            MontFp!("4192441833957860073"),
+            MontFp!("11228452911817573065"),
+            MontFp!("17678129733188976641"),
+            MontFp!("14019525492755084084"),
+            MontFp!("14899293286834856567"),
+        ],
+        [
+            MontFp!("17293822565076172801"),
+            MontFp!("17361641477096079361"),
+            MontFp!("11273010264642245974"),
+            MontFp!("10679693934924233028"),
+            MontFp!("17524406865943855105"),
+            MontFp!("7027331074062698789"),
+            MontFp!("4192441833957860073"),
+            MontFp!("11228452911817573065"),
+            MontFp!("17678129733188976641"),
+            MontFp!("14019525492755084084"),
+            MontFp!("14899293286834856567"),
+            MontFp!("13664254866233025423"),
+        ],
+        [
+            MontFp!("17361641477096079361"),
+            MontFp!("11273010264642245974"),
+            MontFp!("10679693934924233028"),
+            MontFp!("17524406865943855105"),
+            MontFp!("7027331074062698789"),
+            MontFp!("4192441833957860073"),
+            MontFp!("11228452911817573065"),
+            MontFp!("17678129733188976641"),
+            MontFp!("14019525492755084084"),
+            MontFp!("14899293286834856567"),
+            MontFp!("13664254866233025423"),
+            MontFp!("9882184322900670172"),
+        ],
+        [
+            MontFp!("11273010264642245974"),
+            MontFp!("10679693934924233028"),
+            MontFp!("17524406865943855105"),
+            MontFp!("7027331074062698789"),
+            MontFp!("4192441833957860073"),
+            MontFp!("11228452911817573065"),
+            MontFp!("17678129733188976641"),
+            MontFp!("14019525492755084084"),
+            MontFp!("14899293286834856567"),
+            MontFp!("13664254866233025423"),
+            MontFp!("9882184322900670172"),
+            MontFp!("10177513969332184453"),
+        ],
+        [
+            MontFp!("10679693934924233028"),
+            MontFp!("17524406865943855105"),
+            MontFp!("7027331074062698789"),
+            MontFp!("4192441833957860073"),
+            MontFp!("11228452911817573065"),
+            MontFp!("17678129733188976641"),
+            MontFp!("14019525492755084084"),
+            MontFp!("14899293286834856567"),
+            MontFp!("13664254866233025423"),
+            MontFp!("9882184322900670172"),
+            MontFp!("10177513969332184453"),
+            MontFp!("17831852600434098177"),
+        ],
+        [
+            MontFp!("17524406865943855105"),
+            MontFp!("7027331074062698789"),
+            MontFp!("4192441833957860073"),
+            MontFp!("11228452911817573065"),
+            MontFp!("17678129733188976641"),
+            MontFp!("14019525492755084084"),
+            MontFp!("14899293286834856567"),
+            MontFp!("13664254866233025423"),
+            MontFp!("9882184322900670172"),
+            MontFp!("10177513969332184453"),
+            MontFp!("17831852600434098177"),
+            MontFp!("11306068945770229100"),
+        ],
+        [
+            MontFp!("7027331074062698789"),
+            MontFp!("4192441833957860073"),
+            MontFp!("11228452911817573065"),
+            MontFp!("17678129733188976641"),
+            MontFp!("14019525492755084084"),
+            MontFp!("14899293286834856567"),
+            MontFp!("13664254866233025423"),
+            MontFp!("9882184322900670172"),
+            MontFp!("10177513969332184453"),
+            MontFp!("17831852600434098177"),
+            MontFp!("11306068945770229100"),
+            MontFp!("17870283317245378561"),
+        ],
+        [
+            MontFp!("4192441833957860073"),
+            MontFp!("11228452911817573065"),
+            MontFp!("17678129733188976641"),
+            MontFp!("14019525492755084084"),
+            MontFp!("14899293286834856567"),
+            MontFp!("13664254866233025423"),
+            MontFp!("9882184322900670172"),
+            MontFp!("10177513969332184453"),
+            MontFp!("17831852600434098177"),
+            MontFp!("11306068945770229100"),
+            MontFp!("17870283317245378561"),
+            MontFp!("2794961222638573382"),
+        ],
+        [
+            MontFp!("11228452911817573065"),
+            MontFp!("17678129733188976641"),
+            MontFp!("14019525492755084084"),
+            MontFp!("14899293286834856567"),
+            MontFp!("13664254866233025423"),
+            MontFp!("9882184322900670172"),
+            MontFp!("10177513969332184453"),
+            MontFp!("17831852600434098177"),
+            MontFp!("11306068945770229100"),
+            MontFp!("17870283317245378561"),
+            MontFp!("2794961222638573382"),
+            MontFp!("17904192773255331841"),
+        ],
+    ],
+};
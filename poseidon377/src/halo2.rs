@@ -0,0 +1,402 @@
+//! A halo2 chip for the Poseidon permutation.
+//!
+//! The [`PoseidonChip`] lays out the permutation described by a plonkish
+//! [`Descriptor`] with one row per round: the state before the round is in `t`
+//! advice columns, its round constants in `t` fixed columns, and the state after the
+//! round is in the same advice columns on the next row. Full and partial rounds each
+//! have their own gate.
+//!
+//! The S-box `x^alpha` is split so that the degree of the gates stays low: for each
+//! S-box input `x`, an auxiliary advice cell holds `x^(alpha / 2)`, rounded down,
+//! and the S-box output is the square of that cell, times `x` if `alpha` is odd. For
+//! the `alpha = 17` instances of this crate, this is a degree-8 constraint on the
+//! auxiliary cell and a degree-3 expression for the output.
+//!
+//! halo2 works over fields implementing the `ff` traits, so the chip is defined over
+//! [`Halo2Fq`], a wrapper around [`Fq`](crate::Fq).
+
+use alloc::{vec, vec::Vec};
+
+use halo2_proofs::{
+    arithmetic::Field,
+    circuit::{AssignedCell, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector},
+    poly::Rotation,
+};
+use poseidon_parameters::v1::Alpha;
+
+use crate::plonkish::Descriptor;
+
+mod field;
+
+pub use field::Halo2Fq;
+
+/// A cell holding an element of the state.
+pub type StateCell = AssignedCell<Halo2Fq, Halo2Fq>;
+
+/// The columns and gates of a [`PoseidonChip`].
+#[derive(Clone, Debug)]
+pub struct PoseidonConfig {
+    state: Vec<Column<Advice>>,
+    aux: Vec<Column<Advice>>,
+    round_constants: Vec<Column<Fixed>>,
+    full_round: Selector,
+    partial_round: Selector,
+    /// Rounds of the permutation, `true` for full rounds.
+    rounds: Vec<bool>,
+    round_constant_values: Vec<Vec<Halo2Fq>>,
+    mds: Vec<Vec<Halo2Fq>>,
+    alpha: u32,
+}
+
+/// A chip evaluating a Poseidon permutation over [`Halo2Fq`].
+#[derive(Clone, Debug)]
+pub struct PoseidonChip {
+    config: PoseidonConfig,
+}
+
+impl PoseidonChip {
+    /// Configure the columns and gates of the permutation described by `descriptor`.
+    ///
+    /// The state columns have equality enabled, so inputs can be copied in and outputs
+    /// copied out, and so does `constants`, a fixed column for the constant domain
+    /// separator of [`PoseidonChip::hash`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the S-box of `descriptor` is the inverse S-box, which is not supported.
+    pub fn configure(
+        meta: &mut ConstraintSystem<Halo2Fq>,
+        descriptor: &Descriptor,
+        constants: Column<Fixed>,
+    ) -> PoseidonConfig {
+        let alpha = match descriptor.alpha {
+            Alpha::Exponent(alpha) => alpha,
+            Alpha::Inverse => panic!("the halo2 chip does not support the inverse S-box"),
+        };
+        let t = descriptor.state_size;
+
+        let state: Vec<_> = (0..t).map(|_| meta.advice_column()).collect();
+        let aux: Vec<_> = (0..t).map(|_| meta.advice_column()).collect();
+        let round_constants: Vec<_> = (0..t).map(|_| meta.fixed_column()).collect();
+        for column in &state {
+            meta.enable_equality(*column);
+        }
+        meta.enable_constant(constants);
+
+        let mds: Vec<Vec<Halo2Fq>> = descriptor
+            .mds
+            .iter()
+            .map(|row| row.iter().map(|m| Halo2Fq(*m)).collect())
+            .collect();
+        let rounds = descriptor
+            .gates
+            .iter()
+            .flat_map(|gate| vec![gate.sboxes == t; gate.rounds])
+            .collect();
+        let round_constant_values = descriptor
+            .round_constants
+            .iter()
+            .map(|row| row.iter().map(|c| Halo2Fq(*c)).collect())
+            .collect();
+
+        let full_round = meta.selector();
+        let partial_round = meta.selector();
+        for (selector, sboxes, name) in [
+            (full_round, t, "full_round"),
+            (partial_round, 1, "partial_round"),
+        ] {
+            meta.create_gate(name, |meta| {
+                let s = meta.query_selector(selector);
+                // The S-box inputs, and the S-box outputs or the inputs themselves.
+                let mut words = Vec::with_capacity(t);
+                let mut constraints = Vec::with_capacity(sboxes + t);
+                for i in 0..t {
+                    let x = meta.query_advice(state[i], Rotation::cur())
+                        + meta.query_fixed(round_constants[i], Rotation::cur());
+                    if i < sboxes {
+                        let half = meta.query_advice(aux[i], Rotation::cur());
+                        constraints.push(s.clone() * (half.clone() - pow(x.clone(), alpha / 2)));
+                        words.push(half.clone() * half * pow(x, alpha % 2));
+                    } else {
+                        words.push(x);
+                    }
+                }
+                for (i, row) in mds.iter().enumerate() {
+                    let next = meta.query_advice(state[i], Rotation::next());
+                    let mixed = row
+                        .iter()
+                        .zip(&words)
+                        .map(|(m, word)| Expression::Constant(*m) * word.clone())
+                        .reduce(|acc, term| acc + term)
+                        .expect("the state is not empty");
+                    constraints.push(s.clone() * (next - mixed));
+                }
+                constraints
+            });
+        }
+
+        PoseidonConfig {
+            state,
+            aux,
+            round_constants,
+            full_round,
+            partial_round,
+            rounds,
+            round_constant_values,
+            mds,
+            alpha,
+        }
+    }
+
+    /// Construct a chip from its `config`.
+    pub fn construct(config: PoseidonConfig) -> Self {
+        Self { config }
+    }
+
+    /// Apply the permutation to the `input` cells, returning the cells of the output
+    /// state.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` does not have as many cells as the state.
+    pub fn permute(
+        &self,
+        mut layouter: impl Layouter<Halo2Fq>,
+        input: &[StateCell],
+    ) -> Result<Vec<StateCell>, Error> {
+        let config = &self.config;
+        assert_eq!(input.len(), config.state.len(), "input has the wrong width");
+
+        layouter.assign_region(
+            || "poseidon",
+            |mut region| {
+                let mut state = input
+                    .iter()
+                    .zip(&config.state)
+                    .map(|(cell, column)| cell.copy_advice(|| "input", &mut region, *column, 0))
+                    .collect::<Result<Vec<_>, _>>()?;
+                for (round, &full) in config.rounds.iter().enumerate() {
+                    state = self.assign_round(&mut region, round, full, &state)?;
+                }
+                Ok(state)
+            },
+        )
+    }
+
+    /// Hash the `input` cells with the constant `domain_separator`, as with
+    /// [`hash_1`](crate::hash_1) through [`hash_7`](crate::hash_7) for a chip
+    /// configured for the instance of the matching rate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `input` does not have one cell fewer than the state.
+    pub fn hash(
+        &self,
+        mut layouter: impl Layouter<Halo2Fq>,
+        domain_separator: Halo2Fq,
+        input: &[StateCell],
+    ) -> Result<StateCell, Error> {
+        let domain_separator = layouter.assign_region(
+            || "domain separator",
+            |mut region| {
+                region.assign_advice_from_constant(
+                    || "domain separator",
+                    self.config.state[0],
+                    0,
+                    domain_separator,
+                )
+            },
+        )?;
+        let mut state = vec![domain_separator];
+        state.extend_from_slice(input);
+        let output = self.permute(layouter.namespace(|| "permute"), &state)?;
+        Ok(output[1].clone())
+    }
+
+    /// Assign the round at row `round` to the `state` cells on that row, returning the
+    /// cells of the state on the next row.
+    fn assign_round(
+        &self,
+        region: &mut Region<'_, Halo2Fq>,
+        round: usize,
+        full: bool,
+        state: &[StateCell],
+    ) -> Result<Vec<StateCell>, Error> {
+        let config = &self.config;
+        let t = config.state.len();
+        if full {
+            config.full_round.enable(region, round)?;
+        } else {
+            config.partial_round.enable(region, round)?;
+        }
+
+        let constants = &config.round_constant_values[round];
+        for (i, constant) in constants.iter().enumerate() {
+            region.assign_fixed(
+                || "round constant",
+                config.round_constants[i],
+                round,
+                || Ok(*constant),
+            )?;
+        }
+
+        // The S-box inputs, or `None` if the input cells have no values yet.
+        let inputs: Option<Vec<Halo2Fq>> = state
+            .iter()
+            .zip(constants)
+            .map(|(cell, constant)| cell.value().map(|value| *value + constant))
+            .collect();
+        let sboxes = if full { t } else { 1 };
+        let mut words = inputs.clone();
+        for i in 0..sboxes {
+            let half = inputs
+                .as_ref()
+                .map(|x| x[i].pow_vartime([(config.alpha / 2) as u64]));
+            region.assign_advice(
+                || "sbox",
+                config.aux[i],
+                round,
+                || half.ok_or(Error::Synthesis),
+            )?;
+            if let (Some(words), Some(half), Some(x)) = (words.as_mut(), half, inputs.as_ref()) {
+                words[i] = half.square() * x[i].pow_vartime([(config.alpha % 2) as u64]);
+            }
+        }
+
+        config
+            .mds
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let next = words.as_ref().map(|words| {
+                    row.iter()
+                        .zip(words)
+                        .map(|(m, word)| *m * word)
+                        .sum::<Halo2Fq>()
+                });
+                region.assign_advice(
+                    || "state",
+                    config.state[i],
+                    round + 1,
+                    || next.ok_or(Error::Synthesis),
+                )
+            })
+            .collect()
+    }
+}
+
+/// `x^exponent` as a product of `exponent` copies of `x`, or `1` if `exponent` is zero.
+fn pow(x: Expression<Halo2Fq>, exponent: u32) -> Expression<Halo2Fq> {
+    (1..exponent).fold(
+        if exponent == 0 {
+            Expression::Constant(Halo2Fq::one())
+        } else {
+            x.clone()
+        },
+        |acc, _| acc * x.clone(),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use halo2_proofs::{
+        circuit::SimpleFloorPlanner,
+        dev::MockProver,
+        plonk::{Circuit, Instance},
+    };
+
+    use super::*;
+    use crate::Fq;
+
+    /// A circuit hashing `N` private inputs with each rate's instance, exposing the
+    /// output as a public input.
+    #[derive(Clone, Default)]
+    struct HashCircuit<const N: usize> {
+        input: Option<[Fq; N]>,
+    }
+
+    fn descriptor<const N: usize>() -> Descriptor {
+        match N {
+            1 => Descriptor::new(&crate::RATE_1_PARAMS),
+            2 => Descriptor::new(&crate::RATE_2_PARAMS),
+            3 => Descriptor::new(&crate::RATE_3_PARAMS),
+            4 => Descriptor::new(&crate::RATE_4_PARAMS),
+            _ => unreachable!("only rates 1 to 4 are tested"),
+        }
+    }
+
+    fn domain_separator() -> Fq {
+        Fq::from_le_bytes_mod_order(b"Penumbra_TestVec")
+    }
+
+    impl<const N: usize> Circuit<Halo2Fq> for HashCircuit<N> {
+        type Config = (PoseidonConfig, Column<Advice>, Column<Instance>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Halo2Fq>) -> Self::Config {
+            let constants = meta.fixed_column();
+            let config = PoseidonChip::configure(meta, &descriptor::<N>(), constants);
+            let input = meta.advice_column();
+            meta.enable_equality(input);
+            let output = meta.instance_column();
+            meta.enable_equality(output);
+            (config, input, output)
+        }
+
+        fn synthesize(
+            &self,
+            (config, input, output): Self::Config,
+            mut layouter: impl Layouter<Halo2Fq>,
+        ) -> Result<(), Error> {
+            let cells = layouter.assign_region(
+                || "input",
+                |mut region| {
+                    (0..N)
+                        .map(|i| {
+                            region.assign_advice(
+                                || "input",
+                                input,
+                                i,
+                                || self.input.map(|x| Halo2Fq(x[i])).ok_or(Error::Synthesis),
+                            )
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                },
+            )?;
+            let chip = PoseidonChip::construct(config);
+            let hash = chip.hash(
+                layouter.namespace(|| "hash"),
+                Halo2Fq(domain_separator()),
+                &cells,
+            )?;
+            layouter.constrain_instance(hash.cell(), output, 0)
+        }
+    }
+
+    fn check<const N: usize>(expected: Fq) {
+        let input: [Fq; N] = core::array::from_fn(|i| Fq::from(i as u64 + 3));
+        let circuit = HashCircuit { input: Some(input) };
+
+        let prover = MockProver::run(7, &circuit, vec![vec![Halo2Fq(expected)]]).unwrap();
+        assert_eq!(prover.verify(), Ok(()));
+
+        let wrong = expected + Fq::from(1u64);
+        let prover = MockProver::run(7, &circuit, vec![vec![Halo2Fq(wrong)]]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn halo2_hash_matches_native() {
+        let ds = domain_separator();
+        let [a, b, c, d] = [3u64, 4, 5, 6].map(Fq::from);
+        check::<1>(crate::hash_1(&ds, a));
+        check::<2>(crate::hash_2(&ds, (a, b)));
+        check::<3>(crate::hash_3(&ds, (a, b, c)));
+        check::<4>(crate::hash_4(&ds, (a, b, c, d)));
+    }
+}
@@ -0,0 +1,349 @@
+use core::{
+    cmp::Ordering,
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use halo2_proofs::{
+    arithmetic::{Field, FieldExt, Group, SqrtRatio},
+    pasta::group::ff::PrimeField,
+};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use crate::Fq;
+
+/// An element of [`Fq`] implementing the field traits used by halo2.
+///
+/// halo2 is generic over fields implementing the traits of the `ff` crate and its
+/// own [`FieldExt`], which the arkworks-based [`Fq`] does not implement. This is a
+/// transparent wrapper that does, converting to and from [`Fq`] with [`From`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Halo2Fq(pub Fq);
+
+impl Halo2Fq {
+    const fn from_montgomery_limbs(limbs: [u64; 4]) -> Self {
+        Self(Fq::from_montgomery_limbs(limbs))
+    }
+
+    /// The canonical little-endian encoding of the element.
+    fn to_bytes(self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+}
+
+impl From<Fq> for Halo2Fq {
+    fn from(value: Fq) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Halo2Fq> for Fq {
+    fn from(value: Halo2Fq) -> Self {
+        value.0
+    }
+}
+
+impl From<u64> for Halo2Fq {
+    fn from(value: u64) -> Self {
+        Self(Fq::from(value))
+    }
+}
+
+impl From<bool> for Halo2Fq {
+    fn from(value: bool) -> Self {
+        Self(Fq::from(value as u64))
+    }
+}
+
+impl Ord for Halo2Fq {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (self.to_bytes(), other.to_bytes());
+        a.iter().rev().cmp(b.iter().rev())
+    }
+}
+
+impl PartialOrd for Halo2Fq {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ConstantTimeEq for Halo2Fq {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.to_bytes().ct_eq(&other.to_bytes())
+    }
+}
+
+impl ConditionallySelectable for Halo2Fq {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let (a, b) = (a.to_bytes(), b.to_bytes());
+        let bytes = core::array::from_fn(|i| u8::conditional_select(&a[i], &b[i], choice));
+        Self(Fq::from_bytes_checked(&bytes).expect("both inputs are canonical"))
+    }
+}
+
+macro_rules! impl_binop {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:tt) => {
+        impl $trait for Halo2Fq {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                Self(self.0 $op rhs.0)
+            }
+        }
+
+        impl<'a> $trait<&'a Halo2Fq> for Halo2Fq {
+            type Output = Self;
+
+            fn $method(self, rhs: &'a Self) -> Self {
+                Self(self.0 $op rhs.0)
+            }
+        }
+
+        impl $assign_trait for Halo2Fq {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+
+        impl<'a> $assign_trait<&'a Halo2Fq> for Halo2Fq {
+            fn $assign_method(&mut self, rhs: &'a Self) {
+                *self = *self $op *rhs;
+            }
+        }
+    };
+}
+
+impl_binop!(Add, add, AddAssign, add_assign, +);
+impl_binop!(Sub, sub, SubAssign, sub_assign, -);
+impl_binop!(Mul, mul, MulAssign, mul_assign, *);
+
+impl Neg for Halo2Fq {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Sum for Halo2Fq {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl Product for Halo2Fq {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+
+impl Field for Halo2Fq {
+    fn random(mut rng: impl RngCore) -> Self {
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+        Self(Fq::from_le_bytes_mod_order(&bytes))
+    }
+
+    fn zero() -> Self {
+        Self(Fq::from(0u64))
+    }
+
+    fn one() -> Self {
+        Self(Fq::from(1u64))
+    }
+
+    fn square(&self) -> Self {
+        Self(self.0 * self.0)
+    }
+
+    fn double(&self) -> Self {
+        Self(self.0 + self.0)
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        match self.0.inverse() {
+            Some(inverse) => CtOption::new(Self(inverse), Choice::from(1)),
+            None => CtOption::new(Self::zero(), Choice::from(0)),
+        }
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        match ark_ff::Field::sqrt(&self.0) {
+            Some(root) => CtOption::new(Self(root), Choice::from(1)),
+            None => CtOption::new(Self::zero(), Choice::from(0)),
+        }
+    }
+}
+
+impl PrimeField for Halo2Fq {
+    type Repr = [u8; 32];
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        match Fq::from_bytes_checked(&repr) {
+            Ok(value) => CtOption::new(Self(value), Choice::from(1)),
+            Err(_) => CtOption::new(Self::zero(), Choice::from(0)),
+        }
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        self.to_bytes()
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from(self.to_bytes()[0] & 1)
+    }
+
+    const NUM_BITS: u32 = 253;
+    const CAPACITY: u32 = 252;
+    const S: u32 = 47;
+
+    fn multiplicative_generator() -> Self {
+        GENERATOR
+    }
+
+    fn root_of_unity() -> Self {
+        ROOT_OF_UNITY
+    }
+}
+
+impl SqrtRatio for Halo2Fq {
+    /// `(t - 1) / 2`, where `t` is the odd factor of `q - 1`.
+    const T_MINUS1_OVER2: [u64; 4] = [
+        8574519438978648593,
+        5556980384467605930,
+        7304445162184663220,
+        4779,
+    ];
+
+    fn get_lower_32(&self) -> u32 {
+        let bytes = self.to_bytes();
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+}
+
+impl Group for Halo2Fq {
+    type Scalar = Halo2Fq;
+
+    fn group_zero() -> Self {
+        Self::zero()
+    }
+
+    fn group_add(&mut self, rhs: &Self) {
+        *self += rhs;
+    }
+
+    fn group_sub(&mut self, rhs: &Self) {
+        *self -= rhs;
+    }
+
+    fn group_scale(&mut self, by: &Self::Scalar) {
+        *self *= by;
+    }
+}
+
+impl FieldExt for Halo2Fq {
+    const MODULUS: &'static str =
+        "0x12ab655e9a2ca55660b44d1e5c37b00159aa76fed00000010a11800000000001";
+
+    const ROOT_OF_UNITY_INV: Self = Self::from_montgomery_limbs([
+        947038602043848125,
+        18046490140900491084,
+        9135522784075507087,
+        1308249991387705127,
+    ]);
+
+    /// `GENERATOR^(2^S)`.
+    const DELTA: Self = Self::from_montgomery_limbs([
+        14419373999556438596,
+        12299319794984807954,
+        6552798995449976073,
+        1095758772548496917,
+    ]);
+
+    const TWO_INV: Self = Self::from_montgomery_limbs([
+        14093733558879256570,
+        16573587507929677817,
+        13530556323685588983,
+        1151689812725735417,
+    ]);
+
+    /// `GENERATOR^((q - 1) / 3)`, a primitive cube root of unity.
+    const ZETA: Self = Self::from_montgomery_limbs([
+        16755199528139757613,
+        13123939783501294296,
+        10725926023147515130,
+        1075161258170100669,
+    ]);
+
+    fn from_u128(v: u128) -> Self {
+        Self(Fq::from_le_bytes_mod_order(&v.to_le_bytes()))
+    }
+
+    fn from_bytes_wide(bytes: &[u8; 64]) -> Self {
+        Self(Fq::from_le_bytes_mod_order(bytes))
+    }
+
+    fn get_lower_128(&self) -> u128 {
+        let bytes = self.to_bytes();
+        u128::from_le_bytes(bytes[..16].try_into().expect("slice is 16 bytes"))
+    }
+}
+
+/// `22`, the multiplicative generator of [`Fq`].
+const GENERATOR: Halo2Fq = Halo2Fq::from_montgomery_limbs([
+    2984901390528151251,
+    10561528701063790279,
+    5476750214495080041,
+    898978044469942640,
+]);
+
+/// `GENERATOR^t`, a primitive `2^S`-th root of unity, where `t` is the odd factor of
+/// `q - 1`.
+const ROOT_OF_UNITY: Halo2Fq = Halo2Fq::from_montgomery_limbs([
+    12646347781564978760,
+    6783048705277173164,
+    268534165941069093,
+    1121515446318641358,
+]);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn halo2_field_constants() {
+        let two = Halo2Fq::from(2u64);
+        assert_eq!(Halo2Fq::TWO_INV * two, Halo2Fq::one());
+        assert_eq!(
+            Halo2Fq::ROOT_OF_UNITY_INV * Halo2Fq::root_of_unity(),
+            Halo2Fq::one()
+        );
+        assert_eq!(
+            Halo2Fq::root_of_unity().pow_vartime([1u64 << (Halo2Fq::S - 1)]),
+            -Halo2Fq::one()
+        );
+        assert_eq!(
+            Halo2Fq::multiplicative_generator().pow_vartime([1u64 << 47]),
+            Halo2Fq::DELTA
+        );
+        assert_ne!(Halo2Fq::ZETA, Halo2Fq::one());
+        assert_eq!(Halo2Fq::ZETA.cube(), Halo2Fq::one());
+        assert_eq!(Halo2Fq::multiplicative_generator(), Halo2Fq::from(22u64));
+    }
+
+    #[test]
+    fn halo2_field_encoding() {
+        let x = Halo2Fq::from_u128(u128::MAX);
+        assert_eq!(x.get_lower_128(), u128::MAX);
+        assert_eq!(Halo2Fq::from_repr(x.to_repr()).unwrap(), x);
+        assert_eq!(x.get_lower_32(), u32::MAX);
+        assert!(bool::from(Halo2Fq::from(3u64).is_odd()));
+        assert!(Halo2Fq::from(3u64) < Halo2Fq::from(1u64 << 40));
+
+        let root = Halo2Fq::from(9u64).sqrt().unwrap();
+        assert_eq!(root.square(), Halo2Fq::from(9u64));
+        assert!(bool::from(Halo2Fq::zero().invert().is_none()));
+    }
+}
@@ -1,13 +1,19 @@
+#[cfg(feature = "all-rates")]
+use crate::{
+    encoding::BYTES_PER_ELEMENT, sponge::Sponge, Hasher, PoseidonParameters, ToFieldElements,
+};
 use crate::{Fq, Instance};
 
 /// Hash a single [`Fq`] element with the provided `domain_separator`.
+#[cfg(feature = "rate-1")]
 pub fn hash_1(domain_separator: &Fq, value: Fq) -> Fq {
     let params = &crate::RATE_1_PARAMS;
-    let mut state = Instance::new(&params);
+    let mut state = Instance::new(params);
     state.n_to_1_fixed_hash(&[*domain_separator, value])
 }
 
 /// Hash two [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-2")]
 pub fn hash_2(domain_separator: &Fq, value: (Fq, Fq)) -> Fq {
     let params = &crate::RATE_2_PARAMS;
     let mut state = Instance::new(params);
@@ -15,6 +21,7 @@ pub fn hash_2(domain_separator: &Fq, value: (Fq, Fq)) -> Fq {
 }
 
 /// Hash three [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-3")]
 pub fn hash_3(domain_separator: &Fq, value: (Fq, Fq, Fq)) -> Fq {
     let params = &crate::RATE_3_PARAMS;
     let mut state = Instance::new(params);
@@ -22,6 +29,7 @@ pub fn hash_3(domain_separator: &Fq, value: (Fq, Fq, Fq)) -> Fq {
 }
 
 /// Hash four [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-4")]
 pub fn hash_4(domain_separator: &Fq, value: (Fq, Fq, Fq, Fq)) -> Fq {
     let params = &crate::RATE_4_PARAMS;
     let mut state = Instance::new(params);
@@ -29,6 +37,7 @@ pub fn hash_4(domain_separator: &Fq, value: (Fq, Fq, Fq, Fq)) -> Fq {
 }
 
 /// Hash five [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-5")]
 pub fn hash_5(domain_separator: &Fq, value: (Fq, Fq, Fq, Fq, Fq)) -> Fq {
     let params = &crate::RATE_5_PARAMS;
     let mut state = Instance::new(params);
@@ -43,6 +52,7 @@ pub fn hash_5(domain_separator: &Fq, value: (Fq, Fq, Fq, Fq, Fq)) -> Fq {
 }
 
 /// Hash six [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-6")]
 pub fn hash_6(domain_separator: &Fq, value: (Fq, Fq, Fq, Fq, Fq, Fq)) -> Fq {
     let params = &crate::RATE_6_PARAMS;
     let mut state = Instance::new(params);
@@ -58,6 +68,7 @@ pub fn hash_6(domain_separator: &Fq, value: (Fq, Fq, Fq, Fq, Fq, Fq)) -> Fq {
 }
 
 /// Hash seven [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-7")]
 pub fn hash_7(domain_separator: &Fq, value: (Fq, Fq, Fq, Fq, Fq, Fq, Fq)) -> Fq {
     let params = &crate::RATE_7_PARAMS;
     let mut state = Instance::new(params);
@@ -73,6 +84,537 @@ pub fn hash_7(domain_separator: &Fq, value: (Fq, Fq, Fq, Fq, Fq, Fq, Fq)) -> Fq
     ])
 }
 
+/// Hash `N` [`Fq`] elements with the provided `domain_separator`, for `N` from 1 to 16.
+///
+/// This is equivalent to calling the matching function out of [`hash_1`] through
+/// [`hash_16`], which allows code that is generic over the arity to avoid its own
+/// dispatch. Using any other `N` is a compile-time error.
+#[cfg(feature = "all-rates")]
+pub fn hash_fixed<const N: usize>(domain_separator: &Fq, input: [Fq; N]) -> Fq {
+    let () = FixedArity::<N>::CHECK;
+    match N {
+        8 => hash_8(domain_separator, core::array::from_fn(|i| input[i])),
+        9 => hash_9(domain_separator, core::array::from_fn(|i| input[i])),
+        10 => hash_10(domain_separator, core::array::from_fn(|i| input[i])),
+        11 => hash_11(domain_separator, core::array::from_fn(|i| input[i])),
+        12 => hash_12(domain_separator, core::array::from_fn(|i| input[i])),
+        13 => hash_13(domain_separator, core::array::from_fn(|i| input[i])),
+        14 => hash_14(domain_separator, core::array::from_fn(|i| input[i])),
+        15 => hash_15(domain_separator, core::array::from_fn(|i| input[i])),
+        16 => hash_16(domain_separator, core::array::from_fn(|i| input[i])),
+        _ => hash(domain_separator, &input),
+    }
+}
+
+/// Compile-time check that [`hash_fixed`] is only used with a supported arity.
+#[cfg(feature = "all-rates")]
+struct FixedArity<const N: usize>;
+
+#[cfg(feature = "all-rates")]
+impl<const N: usize> FixedArity<N> {
+    const CHECK: () = assert!(N >= 1 && N <= 16, "hash_fixed supports 1 to 16 elements");
+}
+
+/// Domain separator used by [`compress_2`], `from_le_bytes_mod_order(b"poseidon377.merkle")`.
+#[cfg(feature = "all-rates")]
+const MERKLE_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    6240190207071189516,
+    5065170324423135658,
+    3248759447936774709,
+    980703291565357946,
+]);
+
+/// Compress two [`Fq`] elements into one, for combining nodes of a binary Merkle tree.
+///
+/// This is a single permutation of the rate-2 instance with the state
+/// `[MERKLE_DOMAIN_SEP, left, right]`, where the domain separator is
+/// `from_le_bytes_mod_order(b"poseidon377.merkle")`, and the output is the first rate
+/// word. There is no padding, since the input length is fixed. It is the same as
+/// [`hash_2`] with that domain separator, which is reserved for this function.
+#[cfg(feature = "all-rates")]
+pub fn compress_2(left: Fq, right: Fq) -> Fq {
+    hash_2(&MERKLE_DOMAIN_SEP, (left, right))
+}
+
+/// Domain separator used by [`compress_8`], `from_le_bytes_mod_order(b"poseidon377.merkle8")`.
+#[cfg(feature = "all-rates")]
+const MERKLE_8_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    8636474326947916987,
+    15447220152059494657,
+    12309329210874718622,
+    485332996046158243,
+]);
+
+/// Compress eight [`Fq`] elements into one, for combining nodes of an octary Merkle tree.
+///
+/// This is a single permutation of the rate-8 instance, the same as [`hash_8`] with
+/// the domain separator `from_le_bytes_mod_order(b"poseidon377.merkle8")`, which is
+/// reserved for this function.
+#[cfg(feature = "all-rates")]
+pub fn compress_8(children: [Fq; 8]) -> Fq {
+    hash_8(&MERKLE_8_DOMAIN_SEP, children)
+}
+
+/// Domain separator used to bind the domain separator and lengths of [`hash_n_to_m`]
+/// into its capacity, `from_le_bytes_mod_order(b"poseidon377.hash_n_to_m")`.
+#[cfg(feature = "all-rates")]
+const N_TO_M_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    11133245068177838457,
+    18092304119078780764,
+    10665612293719624005,
+    454930686092450130,
+]);
+
+/// Hash a single [`Fq`] element with the provided `domain_separator`, producing two outputs.
+///
+/// This is [`hash_n_to_m`] with one input and two outputs.
+#[cfg(feature = "all-rates")]
+pub fn hash_1_to_2(domain_separator: &Fq, value: Fq) -> (Fq, Fq) {
+    let [a, b] = hash_n_to_m(domain_separator, [value]);
+    (a, b)
+}
+
+/// Hash two [`Fq`] elements with the provided `domain_separator`, producing two outputs.
+///
+/// This is [`hash_n_to_m`] with two inputs and two outputs.
+#[cfg(feature = "all-rates")]
+pub fn hash_2_to_2(domain_separator: &Fq, value: (Fq, Fq)) -> (Fq, Fq) {
+    let [a, b] = hash_n_to_m(domain_separator, [value.0, value.1]);
+    (a, b)
+}
+
+/// Hash `N` [`Fq`] elements with the provided `domain_separator`, producing `M` outputs.
+///
+/// The fixed-width instance with rate `max(N, M)` is used: the input is placed in
+/// the rate portion of the state, padded with `0`s if it is shorter than `M`, the
+/// state is permuted once and the first `M` rate words are returned. The domain
+/// separator in the capacity is not `domain_separator` itself but
+/// `hash_3(from_le_bytes_mod_order(b"poseidon377.hash_n_to_m"), (domain_separator, N, M))`,
+/// so the outputs are unrelated to those of the single-output hash functions, and
+/// to those of `hash_n_to_m` with other lengths, for the same `domain_separator`.
+///
+/// Both `N` and `M` must be from 1 to 7; using any other length is a compile-time
+/// error:
+///
+/// ```compile_fail
+/// use poseidon377::{hash_n_to_m, Fq};
+///
+/// let _: [Fq; 8] = hash_n_to_m(&Fq::from(0u64), [Fq::from(1u64)]);
+/// ```
+///
+/// # Security
+///
+/// Squeezing more words from the same permutation does not add to the cost of
+/// attacks on the capacity, which remains a single element, so each output on its
+/// own as well as the outputs taken together provide the same ~126-bit collision
+/// resistance as the single-output hash functions.
+#[cfg(feature = "all-rates")]
+pub fn hash_n_to_m<const N: usize, const M: usize>(
+    domain_separator: &Fq,
+    input: [Fq; N],
+) -> [Fq; M] {
+    let () = NToMArity::<N, M>::CHECK;
+    let domain_separator = &hash_3(
+        &N_TO_M_DOMAIN_SEP,
+        (*domain_separator, Fq::from(N as u64), Fq::from(M as u64)),
+    );
+    match N.max(M) {
+        1 => permute_fixed(&crate::RATE_1_PARAMS, domain_separator, &input),
+        2 => permute_fixed(&crate::RATE_2_PARAMS, domain_separator, &input),
+        3 => permute_fixed(&crate::RATE_3_PARAMS, domain_separator, &input),
+        4 => permute_fixed(&crate::RATE_4_PARAMS, domain_separator, &input),
+        5 => permute_fixed(&crate::RATE_5_PARAMS, domain_separator, &input),
+        6 => permute_fixed(&crate::RATE_6_PARAMS, domain_separator, &input),
+        _ => permute_fixed(&crate::RATE_7_PARAMS, domain_separator, &input),
+    }
+}
+
+/// Compile-time check that [`hash_n_to_m`] is only used with supported lengths.
+#[cfg(feature = "all-rates")]
+struct NToMArity<const N: usize, const M: usize>;
+
+#[cfg(feature = "all-rates")]
+impl<const N: usize, const M: usize> NToMArity<N, M> {
+    const CHECK: () = assert!(
+        N >= 1 && N <= 7 && M >= 1 && M <= 7,
+        "hash_n_to_m supports 1 to 7 inputs and outputs"
+    );
+}
+
+/// Permute `domain_separator` followed by the zero-padded `input` once and return the first `M` rate words.
+#[cfg(feature = "all-rates")]
+fn permute_fixed<
+    const STATE_SIZE: usize,
+    const STATE_SIZE_MINUS_1: usize,
+    const NUM_MDS_ELEMENTS: usize,
+    const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+    const NUM_ROUND_ROWS: usize,
+    const NUM_ROUND_COLS: usize,
+    const NUM_ROUND_ELEMENTS: usize,
+    const NUM_PARTIAL_ROUNDS: usize,
+    const M: usize,
+>(
+    parameters: &PoseidonParameters<
+        STATE_SIZE,
+        STATE_SIZE_MINUS_1,
+        NUM_MDS_ELEMENTS,
+        NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+        NUM_ROUND_ROWS,
+        NUM_ROUND_COLS,
+        NUM_ROUND_ELEMENTS,
+        NUM_PARTIAL_ROUNDS,
+    >,
+    domain_separator: &Fq,
+    input: &[Fq],
+) -> [Fq; M] {
+    let mut state = Instance::new(parameters);
+    state.state_words_mut()[0] = *domain_separator;
+    state.state_words_mut()[1..=input.len()].copy_from_slice(input);
+    state.permute();
+
+    let output = state.output_words();
+    core::array::from_fn(|i| output[1 + i])
+}
+
+/// Hash eight [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-8")]
+pub fn hash_8(domain_separator: &Fq, value: [Fq; 8]) -> Fq {
+    let params = &crate::RATE_8_PARAMS;
+    let mut state = Instance::new(params);
+    state.n_to_1_fixed_hash(&fixed_input(domain_separator, value))
+}
+
+/// Hash nine [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-9")]
+pub fn hash_9(domain_separator: &Fq, value: [Fq; 9]) -> Fq {
+    let params = &crate::RATE_9_PARAMS;
+    let mut state = Instance::new(params);
+    state.n_to_1_fixed_hash(&fixed_input(domain_separator, value))
+}
+
+/// Hash ten [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-10")]
+pub fn hash_10(domain_separator: &Fq, value: [Fq; 10]) -> Fq {
+    let params = &crate::RATE_10_PARAMS;
+    let mut state = Instance::new(params);
+    state.n_to_1_fixed_hash(&fixed_input(domain_separator, value))
+}
+
+/// Hash eleven [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-11")]
+pub fn hash_11(domain_separator: &Fq, value: [Fq; 11]) -> Fq {
+    let params = &crate::RATE_11_PARAMS;
+    let mut state = Instance::new(params);
+    state.n_to_1_fixed_hash(&fixed_input(domain_separator, value))
+}
+
+/// Hash twelve [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-12")]
+pub fn hash_12(domain_separator: &Fq, value: [Fq; 12]) -> Fq {
+    let params = &crate::RATE_12_PARAMS;
+    let mut state = Instance::new(params);
+    state.n_to_1_fixed_hash(&fixed_input(domain_separator, value))
+}
+
+/// Hash thirteen [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-13")]
+pub fn hash_13(domain_separator: &Fq, value: [Fq; 13]) -> Fq {
+    let params = &crate::RATE_13_PARAMS;
+    let mut state = Instance::new(params);
+    state.n_to_1_fixed_hash(&fixed_input(domain_separator, value))
+}
+
+/// Hash fourteen [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-14")]
+pub fn hash_14(domain_separator: &Fq, value: [Fq; 14]) -> Fq {
+    let params = &crate::RATE_14_PARAMS;
+    let mut state = Instance::new(params);
+    state.n_to_1_fixed_hash(&fixed_input(domain_separator, value))
+}
+
+/// Hash fifteen [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-15")]
+pub fn hash_15(domain_separator: &Fq, value: [Fq; 15]) -> Fq {
+    let params = &crate::RATE_15_PARAMS;
+    let mut state = Instance::new(params);
+    state.n_to_1_fixed_hash(&fixed_input(domain_separator, value))
+}
+
+/// Hash sixteen [`Fq`] elements with the provided `domain_separator`.
+#[cfg(feature = "rate-16")]
+pub fn hash_16(domain_separator: &Fq, value: [Fq; 16]) -> Fq {
+    let params = &crate::RATE_16_PARAMS;
+    let mut state = Instance::new(params);
+    state.n_to_1_fixed_hash(&fixed_input(domain_separator, value))
+}
+
+/// Prepend the `domain_separator` to `value` to form the input to a fixed-width hash.
+#[cfg(any(
+    feature = "rate-8",
+    feature = "rate-9",
+    feature = "rate-10",
+    feature = "rate-11",
+    feature = "rate-12",
+    feature = "rate-13",
+    feature = "rate-14",
+    feature = "rate-15",
+    feature = "rate-16",
+))]
+fn fixed_input<const N: usize, const STATE_SIZE: usize>(
+    domain_separator: &Fq,
+    value: [Fq; N],
+) -> [Fq; STATE_SIZE] {
+    core::array::from_fn(|i| {
+        if i == 0 {
+            *domain_separator
+        } else {
+            value[i - 1]
+        }
+    })
+}
+
+/// Hash an arbitrary number of [`Fq`] elements with the provided `domain_separator`.
+///
+/// The input is absorbed in chunks of four elements into a sponge over the rate-4
+/// instance of Poseidon. The input is padded with a `1` followed by `0`s up to the
+/// next chunk boundary. The capacity is [`hash_1`] of the `domain_separator` under
+/// a domain separator reserved for the sponge, rather than the `domain_separator`
+/// itself, so that the padded input never matches the input of [`hash_4`]: without
+/// it, hashing no elements would give the same output as [`hash_4`] of `(1, 0, 0, 0)`.
+#[cfg(feature = "all-rates")]
+pub fn hash_varlen(domain_separator: &Fq, input: &[Fq]) -> Fq {
+    let mut sponge = Sponge::new(domain_separator);
+    sponge.absorb(input);
+    sponge.finalize()
+}
+
+/// Hash a slice of [`Fq`] elements with the provided `domain_separator`.
+///
+/// Inputs of one to seven elements are hashed with the matching fixed-width
+/// instance, i.e. this is equivalent to calling [`hash_1`] through [`hash_7`].
+/// Empty and longer inputs are hashed with [`hash_varlen`].
+#[cfg(feature = "all-rates")]
+pub fn hash(domain_separator: &Fq, input: &[Fq]) -> Fq {
+    match *input {
+        [a] => hash_1(domain_separator, a),
+        [a, b] => hash_2(domain_separator, (a, b)),
+        [a, b, c] => hash_3(domain_separator, (a, b, c)),
+        [a, b, c, d] => hash_4(domain_separator, (a, b, c, d)),
+        [a, b, c, d, e] => hash_5(domain_separator, (a, b, c, d, e)),
+        [a, b, c, d, e, f] => hash_6(domain_separator, (a, b, c, d, e, f)),
+        [a, b, c, d, e, f, g] => hash_7(domain_separator, (a, b, c, d, e, f, g)),
+        _ => hash_varlen(domain_separator, input),
+    }
+}
+
+/// Hash an arbitrary byte string with the provided `domain_separator`.
+///
+/// The bytes are encoded injectively into [`Fq`] elements before hashing: the first
+/// element is the length of `bytes`, followed by the bytes split into chunks of 31
+/// bytes, each interpreted as a little-endian integer. The resulting elements are
+/// hashed as with [`hash_varlen`].
+#[cfg(feature = "all-rates")]
+pub fn hash_bytes(domain_separator: &Fq, bytes: &[u8]) -> Fq {
+    let mut sponge = Sponge::new(domain_separator);
+    sponge.absorb(&[Fq::from(bytes.len() as u64)]);
+    for chunk in bytes.chunks(BYTES_PER_ELEMENT) {
+        sponge.absorb(&[Fq::from_le_bytes_mod_order(chunk)]);
+    }
+    sponge.finalize()
+}
+
+/// Hash any value with a canonical field encoding with the provided `domain_separator`.
+///
+/// The value is encoded with [`ToFieldElements`] and the resulting elements are hashed
+/// as with [`hash_varlen`]. Hashing a byte slice produces the same output as [`hash_bytes`].
+#[cfg(feature = "all-rates")]
+pub fn hash_object<T: ToFieldElements + ?Sized>(domain_separator: &Fq, value: &T) -> Fq {
+    let mut hasher = Hasher::new(domain_separator);
+    value.append_field_elements(&mut hasher);
+    hasher.finalize()
+}
+
+/// Domain separator used to derive the domain separators of [`hash_to_group`],
+/// `from_le_bytes_mod_order(b"poseidon377.group")`.
+#[cfg(feature = "all-rates")]
+pub(crate) const GROUP_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    6727306449855977329,
+    10064464286182359853,
+    11654491650530174688,
+    654669188798416973,
+]);
+
+/// Hash an arbitrary number of [`Fq`] elements to a `decaf377` group element with
+/// the provided `domain_separator`.
+///
+/// Two elements are squeezed from a sponge that has absorbed `input`, as with
+/// [`hash_varlen`], and mapped to the group with
+/// [`Element::hash_to_curve`](decaf377::Element::hash_to_curve), which adds the
+/// images of the two elements under the Elligator map so the output is uniformly
+/// distributed. The domain separator in the capacity is [`hash_1`] of the
+/// `domain_separator` under a domain separator reserved for this function, so the
+/// squeezed elements are unrelated to the outputs of the other hash functions.
+#[cfg(feature = "all-rates")]
+pub fn hash_to_group(domain_separator: &Fq, input: &[Fq]) -> decaf377::Element {
+    let mut sponge = Sponge::new(&hash_1(&GROUP_DOMAIN_SEP, *domain_separator));
+    sponge.absorb(input);
+    let mut r = [Fq::from(0u64); 2];
+    sponge.finalize_into(&mut r);
+    decaf377::Element::hash_to_curve(&r[0], &r[1])
+}
+
+/// Domain separator used to bind the key and domain separator of [`mac`] into its
+/// capacity, `from_le_bytes_mod_order(b"poseidon377.mac")`.
+#[cfg(feature = "all-rates")]
+const MAC_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    12736756724898177047,
+    9958279178073596679,
+    4367940495950836851,
+    689198194821717485,
+]);
+
+/// Compute a message authentication code over `input` with the provided `key` and `domain_separator`.
+///
+/// This is an inner-keyed sponge: the capacity word of the sponge used by
+/// [`hash_varlen`] is set to [`hash_2`] of the `key` and the `domain_separator`
+/// under a domain separator reserved for this function, and then `input` is absorbed
+/// and padded as usual. Since the key never leaves the capacity, it is never exposed
+/// in the squeezed output.
+///
+/// # Security
+///
+/// The key must be sampled uniformly at random from [`Fq`]. It may also be used with
+/// [`prf`], [`keystream_into`] and [`Kdf`](crate::Kdf): each of these binds the key
+/// to its own reserved domain separator in the capacity, so their outputs are
+/// unrelated to the tags of this function. The `domain_separator` is remapped along
+/// with the key rather than absorbed as is, so this holds for every
+/// `domain_separator`, including those reserved for the other functions, and tags
+/// under different domain separators are unrelated to each other.
+#[cfg(feature = "all-rates")]
+pub fn mac(key: &Fq, domain_separator: &Fq, input: &[Fq]) -> Fq {
+    let mut sponge = Sponge::from_capacity(&hash_2(&MAC_DOMAIN_SEP, (*key, *domain_separator)));
+    sponge.absorb(input);
+    sponge.finalize()
+}
+
+/// Domain separator used by [`prf`] and [`prf_n`], `from_le_bytes_mod_order(b"poseidon377.prf")`.
+#[cfg(feature = "all-rates")]
+const PRF_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    3801773599423103309,
+    10311670283420969344,
+    6142001728435643484,
+    748151598024683295,
+]);
+
+/// Evaluate a pseudorandom function keyed with `key` on `input`.
+///
+/// The capacity word of the sponge used by [`hash_varlen`] is set to [`hash_1`] of
+/// the `key` under a domain separator reserved for this function, and then `input`
+/// is absorbed and padded as usual. Since the key is bound to this function in the
+/// capacity, the outputs are unrelated to those of [`mac`] with the same key, for
+/// any domain separator, and to those of the unkeyed hash functions.
+#[cfg(feature = "all-rates")]
+pub fn prf(key: &Fq, input: &[Fq]) -> Fq {
+    prf_sponge(key, input).finalize()
+}
+
+/// Evaluate a pseudorandom function keyed with `key` on `input`, producing `N` outputs.
+///
+/// The first output is equal to [`prf`] on the same key and input.
+#[cfg(feature = "all-rates")]
+pub fn prf_n<const N: usize>(key: &Fq, input: &[Fq]) -> [Fq; N] {
+    let mut output = [Fq::from(0u64); N];
+    prf_sponge(key, input).finalize_into(&mut output);
+    output
+}
+
+/// Create the sponge of [`prf`] that has absorbed `input`.
+#[cfg(feature = "all-rates")]
+fn prf_sponge(key: &Fq, input: &[Fq]) -> Sponge {
+    let mut sponge = keyed_sponge(key, &PRF_DOMAIN_SEP);
+    sponge.absorb(input);
+    sponge
+}
+
+/// Domain separator used to generate keystreams,
+/// `from_le_bytes_mod_order(b"poseidon377.keystream")`.
+#[cfg(feature = "all-rates")]
+pub(crate) const KEYSTREAM_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    5455974204512986446,
+    15452898894687853645,
+    17449510080998614076,
+    426061571668639819,
+]);
+
+/// Fill `output` with the keystream for `key` and `nonce`.
+///
+/// The keystream is squeezed from a sponge built as for [`prf`], with the `key`
+/// bound to a domain separator reserved for keystreams in the capacity and the
+/// `nonce` as the input, so it is unrelated to the outputs of [`prf`] and [`mac`]
+/// with the same key. Adding the
+/// keystream to a vector of elements masks it as a one-time pad over [`Fq`], which
+/// unlike XOR costs nothing in a circuit. A `(key, nonce)` pair must never be used
+/// to mask two different vectors.
+#[cfg(feature = "all-rates")]
+pub fn keystream_into(key: &Fq, nonce: &Fq, output: &mut [Fq]) {
+    keystream_sponge(key, nonce).finalize_into(output);
+}
+
+/// Generate `len` elements of the keystream for `key` and `nonce`, as with [`keystream_into`].
+#[cfg(all(feature = "alloc", feature = "all-rates"))]
+pub fn keystream(key: &Fq, nonce: &Fq, len: usize) -> alloc::vec::Vec<Fq> {
+    let mut output = alloc::vec![Fq::from(0u64); len];
+    keystream_into(key, nonce, &mut output);
+    output
+}
+
+/// Generate `len` bytes of keystream for `key` and `nonce`.
+///
+/// The bytes are extracted from the elements of [`keystream`] as with
+/// [`Xof::squeeze_bytes`](crate::Xof::squeeze_bytes), 16 bytes per element.
+#[cfg(all(feature = "alloc", feature = "all-rates"))]
+pub fn keystream_bytes(key: &Fq, nonce: &Fq, len: usize) -> alloc::vec::Vec<u8> {
+    crate::Xof::new(keystream_sponge(key, nonce)).squeeze_bytes(len)
+}
+
+/// Create the sponge that the keystream for `key` and `nonce` is squeezed from.
+#[cfg(feature = "all-rates")]
+fn keystream_sponge(key: &Fq, nonce: &Fq) -> Sponge {
+    let mut sponge = keyed_sponge(key, &KEYSTREAM_DOMAIN_SEP);
+    sponge.absorb(&[*nonce]);
+    sponge
+}
+
+/// Domain separator used by [`hash_salted`], `from_le_bytes_mod_order(b"poseidon377.salt")`.
+#[cfg(feature = "all-rates")]
+const SALT_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    5371154457196600782,
+    6023470139274485758,
+    15852582248986134838,
+    71201613347729388,
+]);
+
+/// Hash an arbitrary number of [`Fq`] elements with the provided `domain_separator` and `salt`.
+///
+/// The salt is bound into the capacity rather than absorbed as a message word: the
+/// capacity word of the sponge used by [`hash_varlen`] is set to [`hash_2`] of the
+/// `domain_separator` and the `salt` under a domain separator reserved for this
+/// function, and then `input` is absorbed and padded as usual. Unlike prepending the
+/// salt to the input, this keeps the salt from being confused with a message word.
+#[cfg(feature = "all-rates")]
+pub fn hash_salted(domain_separator: &Fq, salt: &Fq, input: &[Fq]) -> Fq {
+    let mut sponge = Sponge::new(&hash_2(&SALT_DOMAIN_SEP, (*domain_separator, *salt)));
+    sponge.absorb(input);
+    sponge.finalize()
+}
+
+/// Create a sponge for a keyed function, with [`hash_1`] of `key` under the
+/// `domain_separator` reserved for that function as its capacity word.
+#[cfg(feature = "all-rates")]
+pub(crate) fn keyed_sponge(key: &Fq, domain_separator: &Fq) -> Sponge {
+    Sponge::from_capacity(&hash_1(domain_separator, *key))
+}
+
 #[cfg(test)]
 mod test {
     use core::str::FromStr;
@@ -261,4 +803,531 @@ mod test {
 
         assert_eq!(output, expected_output);
     }
+
+    #[test]
+    fn varlen_padding_separates_lengths() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let zero = Fq::from(0u64);
+        let one = Fq::from(1u64);
+
+        assert_ne!(
+            hash_varlen(&domain_sep, &[]),
+            hash_varlen(&domain_sep, &[zero])
+        );
+        assert_ne!(
+            hash_varlen(&domain_sep, &[one]),
+            hash_varlen(&domain_sep, &[one, zero])
+        );
+        assert_ne!(
+            hash_varlen(&domain_sep, &[one; 4]),
+            hash_varlen(&domain_sep, &[one, one, one, one, one])
+        );
+    }
+
+    #[test]
+    fn varlen_separated_from_fixed_width() {
+        assert_eq!(
+            crate::sponge::SPONGE_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.sponge")
+        );
+
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let a = Fq::from(3u64);
+        let zero = Fq::from(0u64);
+        let one = Fq::from(1u64);
+
+        // The padded inputs of the sponge are valid inputs of `hash_4`.
+        assert_ne!(
+            hash(&domain_sep, &[]),
+            hash(&domain_sep, &[one, zero, zero, zero])
+        );
+        assert_ne!(
+            hash_varlen(&domain_sep, &[]),
+            hash_4(&domain_sep, (one, zero, zero, zero))
+        );
+        assert_ne!(
+            hash_varlen(&domain_sep, &[a]),
+            hash_4(&domain_sep, (a, one, zero, zero))
+        );
+        assert_ne!(
+            hash_varlen(&domain_sep, &[a, a, a]),
+            hash_4(&domain_sep, (a, a, a, one))
+        );
+    }
+
+    #[test]
+    fn varlen_matches_manual_sponge() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input: [Fq; 5] = core::array::from_fn(|i| Fq::from(i as u64 + 7));
+
+        // The first four elements fill one block, the fifth is padded with [1, 0, 0].
+        let capacity = hash_1(&crate::sponge::SPONGE_DOMAIN_SEP, domain_sep);
+        let mut instance = Instance::new(&crate::RATE_4_PARAMS);
+        *instance.state_words_mut() = [capacity, input[0], input[1], input[2], input[3]];
+        instance.permute();
+        instance.state_words_mut()[1] += input[4];
+        instance.state_words_mut()[2] += Fq::from(1u64);
+        instance.permute();
+
+        assert_eq!(hash_varlen(&domain_sep, &input), instance.output_words()[1]);
+    }
+
+    #[test]
+    fn hash_fixed_matches_named() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let [a, b, c, d, e, f, g]: [Fq; 7] = core::array::from_fn(|i| Fq::from(i as u64 + 1));
+
+        assert_eq!(hash_fixed(&domain_sep, [a]), hash_1(&domain_sep, a));
+        assert_eq!(
+            hash_fixed(&domain_sep, [a, b, c]),
+            hash_3(&domain_sep, (a, b, c))
+        );
+        assert_eq!(
+            hash_fixed(&domain_sep, [a, b, c, d, e, f, g]),
+            hash_7(&domain_sep, (a, b, c, d, e, f, g))
+        );
+
+        let wide: [Fq; 16] = core::array::from_fn(|i| Fq::from(i as u64 + 1));
+        assert_eq!(hash_fixed(&domain_sep, wide), hash_16(&domain_sep, wide));
+    }
+
+    #[test]
+    fn wide_rates() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input: [Fq; 16] = core::array::from_fn(|i| Fq::from(i as u64 + 1));
+
+        // The optimized permutation agrees with the unoptimized one from the paper.
+        let mut state = Instance::new(&crate::RATE_16_PARAMS);
+        let mut words = [domain_sep; 17];
+        words[1..].copy_from_slice(&input);
+        assert_eq!(
+            hash_16(&domain_sep, input),
+            state.unoptimized_n_to_1_fixed_hash(words)
+        );
+
+        let mut state = Instance::new(&crate::RATE_8_PARAMS);
+        let words: [Fq; 9] =
+            core::array::from_fn(|i| if i == 0 { domain_sep } else { input[i - 1] });
+        assert_eq!(
+            hash_8(&domain_sep, core::array::from_fn(|i| input[i])),
+            state.unoptimized_n_to_1_fixed_hash(words)
+        );
+        assert_ne!(
+            hash_8(&domain_sep, core::array::from_fn(|i| input[i])),
+            hash_9(&domain_sep, core::array::from_fn(|i| input[i]))
+        );
+    }
+
+    #[test]
+    fn salted_binds_salt_in_capacity() {
+        assert_eq!(
+            SALT_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.salt")
+        );
+
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let salt = Fq::from(7u64);
+        let input = [Fq::from(1u64), Fq::from(2u64)];
+
+        let salted = hash_salted(&domain_sep, &salt, &input);
+        assert_eq!(
+            salted,
+            hash_varlen(&hash_2(&SALT_DOMAIN_SEP, (domain_sep, salt)), &input)
+        );
+        assert_ne!(salted, hash_salted(&domain_sep, &Fq::from(8u64), &input));
+        assert_ne!(
+            salted,
+            hash_varlen(&domain_sep, &[salt, input[0], input[1]])
+        );
+        assert_ne!(
+            hash_salted(&domain_sep, &salt, &input[..1]),
+            hash_salted(&domain_sep, &salt, &input)
+        );
+    }
+
+    #[test]
+    fn instance_reset() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let (a, b) = (Fq::from(1u64), Fq::from(2u64));
+
+        let mut state = Instance::new(&crate::RATE_2_PARAMS);
+        state.state_words_mut()[1] = a;
+        state.permute();
+        state.reset();
+        assert_eq!(state.output_words(), [Fq::from(0u64); 3]);
+
+        state.state_words_mut().copy_from_slice(&[domain_sep, a, b]);
+        state.permute();
+        assert_eq!(state.output_words()[1], hash_2(&domain_sep, (a, b)));
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn instance_zeroize() {
+        use zeroize::Zeroize;
+
+        let mut state = Instance::new(&crate::RATE_2_PARAMS);
+        state
+            .state_words_mut()
+            .copy_from_slice(&[Fq::from(1u64), Fq::from(2u64), Fq::from(3u64)]);
+        state.permute();
+        state.zeroize();
+        assert_eq!(state.output_words(), [Fq::from(0u64); 3]);
+    }
+
+    #[test]
+    fn compress_2_is_ordered() {
+        assert_eq!(
+            MERKLE_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.merkle")
+        );
+
+        let (left, right) = (Fq::from(1u64), Fq::from(2u64));
+        assert_eq!(
+            compress_2(left, right),
+            hash_2(&MERKLE_DOMAIN_SEP, (left, right))
+        );
+        assert_ne!(compress_2(left, right), compress_2(right, left));
+    }
+
+    #[test]
+    fn compress_8_is_ordered() {
+        assert_eq!(
+            MERKLE_8_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.merkle8")
+        );
+
+        let children: [Fq; 8] = core::array::from_fn(|i| Fq::from(i as u64));
+        assert_eq!(compress_8(children), hash_8(&MERKLE_8_DOMAIN_SEP, children));
+        let mut swapped = children;
+        swapped.swap(0, 7);
+        assert_ne!(compress_8(children), compress_8(swapped));
+    }
+
+    #[test]
+    fn multi_output() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let a = Fq::from(3u64);
+        let b = Fq::from(4u64);
+        let zero = Fq::from(0u64);
+
+        let n_to_m_sep =
+            |n: u64, m: u64| hash_3(&N_TO_M_DOMAIN_SEP, (domain_sep, Fq::from(n), Fq::from(m)));
+
+        let (out_0, out_1) = hash_1_to_2(&domain_sep, a);
+        assert_eq!(out_0, hash_2(&n_to_m_sep(1, 2), (a, zero)));
+        assert_ne!(out_0, out_1);
+
+        let (out_0, out_1) = hash_2_to_2(&domain_sep, (a, b));
+        assert_eq!(out_0, hash_2(&n_to_m_sep(2, 2), (a, b)));
+        assert_ne!(out_0, out_1);
+
+        let outputs: [Fq; 5] = hash_n_to_m(&domain_sep, [a, b]);
+        assert_eq!(
+            outputs[0],
+            hash_5(&n_to_m_sep(2, 5), (a, b, zero, zero, zero))
+        );
+        let outputs: [Fq; 1] = hash_n_to_m(&domain_sep, [a, b, a, b]);
+        assert_eq!(outputs[0], hash_4(&n_to_m_sep(4, 1), (a, b, a, b)));
+
+        // A single output does not collide with the single-output hash functions, and
+        // the lengths are bound into the domain separator.
+        let [out]: [Fq; 1] = hash_n_to_m(&domain_sep, [a, b]);
+        assert_ne!(out, hash_2(&domain_sep, (a, b)));
+        let [out]: [Fq; 1] = hash_n_to_m(&domain_sep, [a]);
+        assert_ne!(out, hash_1(&domain_sep, a));
+        let [padded, _]: [Fq; 2] = hash_n_to_m(&domain_sep, [a]);
+        let [unpadded]: [Fq; 1] = hash_n_to_m(&domain_sep, [a, zero]);
+        assert_ne!(padded, unpadded);
+    }
+
+    #[test]
+    fn hash_object_encoding() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let bytes = [7u8; 40];
+
+        assert_eq!(
+            hash_object(&domain_sep, &bytes[..]),
+            hash_bytes(&domain_sep, &bytes)
+        );
+        assert_eq!(
+            hash_object(&domain_sep, "abc"),
+            hash_bytes(&domain_sep, b"abc")
+        );
+
+        // Fixed-size values are not length-prefixed.
+        let (a, b) = (Fq::from(1u64), Fq::from(2u64));
+        assert_eq!(
+            hash_object(&domain_sep, &(a, 2u64)),
+            hash_varlen(&domain_sep, &[a, b])
+        );
+        assert_eq!(
+            hash_object(&domain_sep, &bytes),
+            hash_varlen(
+                &domain_sep,
+                &[
+                    Fq::from_le_bytes_mod_order(&bytes[..31]),
+                    Fq::from_le_bytes_mod_order(&bytes[31..])
+                ]
+            )
+        );
+
+        // Length prefixes keep nested slices unambiguous.
+        let nested: [&[u64]; 2] = [&[1, 2], &[3]];
+        let regrouped: [&[u64]; 2] = [&[1], &[2, 3]];
+        assert_ne!(
+            hash_object(&domain_sep, &nested),
+            hash_object(&domain_sep, &regrouped)
+        );
+    }
+
+    #[test]
+    fn hash_dispatches_on_length() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let x: [Fq; 8] = core::array::from_fn(|i| Fq::from(i as u64 + 1));
+
+        assert_eq!(hash(&domain_sep, &x[..1]), hash_1(&domain_sep, x[0]));
+        assert_eq!(
+            hash(&domain_sep, &x[..2]),
+            hash_2(&domain_sep, (x[0], x[1]))
+        );
+        assert_eq!(
+            hash(&domain_sep, &x[..3]),
+            hash_3(&domain_sep, (x[0], x[1], x[2]))
+        );
+        assert_eq!(
+            hash(&domain_sep, &x[..4]),
+            hash_4(&domain_sep, (x[0], x[1], x[2], x[3]))
+        );
+        assert_eq!(
+            hash(&domain_sep, &x[..5]),
+            hash_5(&domain_sep, (x[0], x[1], x[2], x[3], x[4]))
+        );
+        assert_eq!(
+            hash(&domain_sep, &x[..6]),
+            hash_6(&domain_sep, (x[0], x[1], x[2], x[3], x[4], x[5]))
+        );
+        assert_eq!(
+            hash(&domain_sep, &x[..7]),
+            hash_7(&domain_sep, (x[0], x[1], x[2], x[3], x[4], x[5], x[6]))
+        );
+        assert_eq!(hash(&domain_sep, &[]), hash_varlen(&domain_sep, &[]));
+        assert_eq!(hash(&domain_sep, &x), hash_varlen(&domain_sep, &x));
+    }
+
+    #[test]
+    fn hash_bytes_encoding() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let bytes = [0xffu8; 40];
+
+        let expected = hash_varlen(
+            &domain_sep,
+            &[
+                Fq::from(40u64),
+                Fq::from_le_bytes_mod_order(&bytes[..31]),
+                Fq::from_le_bytes_mod_order(&bytes[31..]),
+            ],
+        );
+        assert_eq!(hash_bytes(&domain_sep, &bytes), expected);
+
+        // Trailing zero bytes must change the output.
+        assert_ne!(hash_bytes(&domain_sep, b""), hash_bytes(&domain_sep, b"\0"));
+        assert_ne!(
+            hash_bytes(&domain_sep, b"abc"),
+            hash_bytes(&domain_sep, b"abc\0")
+        );
+    }
+
+    #[test]
+    fn mac_depends_on_key() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input = [Fq::from(1u64), Fq::from(2u64), Fq::from(3u64)];
+        let key_1 = Fq::from(1234u64);
+        let key_2 = Fq::from(1235u64);
+
+        let tag = mac(&key_1, &domain_sep, &input);
+        assert_ne!(tag, mac(&key_2, &domain_sep, &input));
+        assert_ne!(tag, hash_varlen(&domain_sep, &input));
+
+        let capacity = hash_2(&MAC_DOMAIN_SEP, (key_1, domain_sep));
+        let mut instance = Instance::new(&crate::RATE_4_PARAMS);
+        *instance.state_words_mut() = [capacity, input[0], input[1], input[2], Fq::from(1u64)];
+        instance.permute();
+        assert_eq!(tag, instance.output_words()[1]);
+    }
+
+    #[test]
+    fn mac_is_separated_from_other_keyed_functions() {
+        assert_eq!(
+            MAC_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.mac")
+        );
+
+        let key = Fq::from(1234u64);
+        let input = [Fq::from(1u64), Fq::from(2u64)];
+
+        // Domain separators reserved for the other keyed functions are remapped.
+        assert_ne!(mac(&key, &PRF_DOMAIN_SEP, &input), prf(&key, &input));
+        let mut stream = [Fq::from(0u64)];
+        keystream_into(&key, &input[0], &mut stream);
+        assert_ne!(mac(&key, &KEYSTREAM_DOMAIN_SEP, &input[..1]), stream[0]);
+        assert_ne!(
+            mac(&key, &Fq::from(0u64), &input),
+            mac(&key, &Fq::from(1u64), &input)
+        );
+    }
+
+    #[test]
+    fn prf_outputs() {
+        assert_eq!(
+            PRF_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.prf")
+        );
+
+        let key = Fq::from(1234u64);
+        let input = [Fq::from(1u64), Fq::from(2u64)];
+
+        let output = prf(&key, &input);
+        let mut sponge = Sponge::from_capacity(&hash_1(&PRF_DOMAIN_SEP, key));
+        sponge.absorb(&input);
+        assert_eq!(output, sponge.finalize());
+        assert_ne!(output, mac(&key, &PRF_DOMAIN_SEP, &input));
+        assert_ne!(output, prf(&Fq::from(1235u64), &input));
+
+        // Squeezing more than a single block of outputs.
+        let outputs = prf_n::<6>(&key, &input);
+        assert_eq!(outputs[0], output);
+        assert_eq!(prf_n::<2>(&key, &input), [outputs[0], outputs[1]]);
+        for i in 0..outputs.len() {
+            for j in i + 1..outputs.len() {
+                assert_ne!(outputs[i], outputs[j]);
+            }
+        }
+    }
+
+    #[test]
+    fn keystream_outputs() {
+        assert_eq!(
+            KEYSTREAM_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.keystream")
+        );
+
+        let key = Fq::from(1234u64);
+        let nonce = Fq::from(1u64);
+
+        let stream = keystream(&key, &nonce, 6);
+        let mut sponge = Sponge::from_capacity(&hash_1(&KEYSTREAM_DOMAIN_SEP, key));
+        sponge.absorb(&[nonce]);
+        assert_eq!(stream[0], sponge.finalize());
+        assert_ne!(stream[0], mac(&key, &KEYSTREAM_DOMAIN_SEP, &[nonce]));
+        assert_eq!(keystream(&key, &nonce, 2), stream[..2]);
+        assert_ne!(keystream(&key, &Fq::from(2u64), 6), stream);
+        assert_ne!(keystream(&Fq::from(1235u64), &nonce, 6), stream);
+
+        let bytes = keystream_bytes(&key, &nonce, 40);
+        assert_eq!(bytes[..16], stream[0].to_bytes()[..16]);
+        assert_eq!(bytes[16..32], stream[1].to_bytes()[..16]);
+    }
+
+    #[cfg(feature = "r1cs")]
+    #[test]
+    fn hash_varlen_gadget() {
+        use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, R1CSVar};
+        use ark_relations::r1cs::ConstraintSystem;
+        use decaf377::r1cs::FqVar;
+
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input: Vec<Fq> = (1..=9u64).map(Fq::from).collect();
+
+        // Cover empty inputs and lengths on both sides of each multiple of the rate.
+        for len in 0..=input.len() {
+            let cs = ConstraintSystem::<Fq>::new_ref();
+            let domain_sep_var = FqVar::new_constant(cs.clone(), domain_sep).unwrap();
+            let input_vars: Vec<FqVar> = input[..len]
+                .iter()
+                .map(|x| FqVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+                .collect();
+            let output =
+                crate::r1cs::hash_varlen(cs.clone(), &domain_sep_var, &input_vars).unwrap();
+            let expected = hash_varlen(&domain_sep, &input[..len]);
+            assert_eq!(output.value().unwrap(), expected);
+
+            let expected_var = FqVar::new_input(cs.clone(), || Ok(expected)).unwrap();
+            output.enforce_equal(&expected_var).unwrap();
+            assert!(cs.is_satisfied().unwrap());
+        }
+
+        // A wrong public output is rejected.
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let domain_sep_var = FqVar::new_constant(cs.clone(), domain_sep).unwrap();
+        let input_vars: Vec<FqVar> = input
+            .iter()
+            .map(|x| FqVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+            .collect();
+        let output = crate::r1cs::hash_varlen(cs.clone(), &domain_sep_var, &input_vars).unwrap();
+        let wrong = hash_varlen(&domain_sep, &input[..8]);
+        let wrong_var = FqVar::new_input(cs.clone(), || Ok(wrong)).unwrap();
+        output.enforce_equal(&wrong_var).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[cfg(feature = "r1cs")]
+    #[test]
+    fn keystream_gadget() {
+        use ark_r1cs_std::{alloc::AllocVar, R1CSVar};
+        use ark_relations::r1cs::ConstraintSystem;
+        use decaf377::r1cs::FqVar;
+
+        let key = Fq::from(1234u64);
+        let nonce = Fq::from(1u64);
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let key_var = FqVar::new_witness(cs.clone(), || Ok(key)).unwrap();
+        let nonce_var = FqVar::new_input(cs.clone(), || Ok(nonce)).unwrap();
+        let stream = crate::r1cs::keystream(cs.clone(), &key_var, &nonce_var, 6).unwrap();
+        assert_eq!(stream.value().unwrap(), keystream(&key, &nonce, 6));
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn hash_to_group_outputs() {
+        assert_eq!(
+            GROUP_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.group")
+        );
+
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input = [Fq::from(1u64), Fq::from(2u64)];
+
+        let mut r = [Fq::from(0u64); 2];
+        let mut sponge = Sponge::new(&hash_1(&GROUP_DOMAIN_SEP, domain_sep));
+        sponge.absorb(&input);
+        sponge.finalize_into(&mut r);
+        let element = hash_to_group(&domain_sep, &input);
+        assert_eq!(element, decaf377::Element::hash_to_curve(&r[0], &r[1]));
+
+        assert_ne!(hash_to_group(&domain_sep, &input[..1]), element);
+        assert_ne!(hash_to_group(&Fq::from(0u64), &input), element);
+    }
+
+    #[cfg(feature = "r1cs")]
+    #[test]
+    fn hash_to_group_gadget() {
+        use ark_r1cs_std::{alloc::AllocVar, R1CSVar};
+        use ark_relations::r1cs::ConstraintSystem;
+        use decaf377::r1cs::FqVar;
+
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input = [Fq::from(1u64), Fq::from(2u64)];
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let domain_sep_var = FqVar::new_constant(cs.clone(), domain_sep).unwrap();
+        let input_vars: Vec<FqVar> = input
+            .iter()
+            .map(|x| FqVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+            .collect();
+        let element = crate::r1cs::hash_to_group(cs.clone(), &domain_sep_var, &input_vars).unwrap();
+        assert_eq!(element.value().unwrap(), hash_to_group(&domain_sep, &input));
+        assert!(cs.is_satisfied().unwrap());
+    }
 }
@@ -0,0 +1,91 @@
+use crate::{sponge::Sponge, Fq, Personalization, Xof};
+
+/// An incremental hasher for an arbitrary number of [`Fq`] elements.
+///
+/// Feeding elements in with any number of calls to [`Hasher::update`] and then
+/// calling [`Hasher::finalize`] produces the same output as [`hash_varlen`](crate::hash_varlen)
+/// over the concatenation of all the inputs.
+///
+/// With the `zeroize` feature, the sponge state is wiped when the hasher is dropped.
+pub struct Hasher {
+    sponge: Sponge,
+}
+
+impl Hasher {
+    /// Create a new hasher with the provided `domain_separator`.
+    pub fn new(domain_separator: &Fq) -> Self {
+        Self {
+            sponge: Sponge::new(domain_separator),
+        }
+    }
+
+    /// Create a new hasher with the provided `domain_separator` under `personalization`.
+    ///
+    /// This is the same as [`Hasher::new`] with
+    /// [`Personalization::domain_separator`] as the domain separator.
+    pub fn new_personalized(personalization: &Personalization, domain_separator: &Fq) -> Self {
+        Self::new(&personalization.domain_separator(domain_separator))
+    }
+
+    /// Absorb more `input` elements into the hasher.
+    pub fn update(&mut self, input: &[Fq]) {
+        self.sponge.absorb(input);
+    }
+
+    /// Absorb `input` elements from an iterator, without buffering them.
+    pub fn absorb_iter<I: IntoIterator<Item = Fq>>(&mut self, input: I) {
+        for element in input {
+            self.sponge.absorb(&[element]);
+        }
+    }
+
+    /// Consume the hasher and return the hash of all elements absorbed so far.
+    pub fn finalize(self) -> Fq {
+        self.sponge.finalize()
+    }
+
+    /// Consume the hasher and return an [`Xof`] to squeeze any amount of output from.
+    pub fn finalize_xof(self) -> Xof {
+        Xof::new(self.sponge)
+    }
+}
+
+impl Extend<Fq> for Hasher {
+    fn extend<I: IntoIterator<Item = Fq>>(&mut self, iter: I) {
+        self.absorb_iter(iter);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Hasher {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hash_varlen;
+
+    #[test]
+    fn incremental_matches_varlen() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input: [Fq; 11] = core::array::from_fn(|i| Fq::from(i as u64));
+        let expected = hash_varlen(&domain_sep, &input);
+
+        for split in 0..=input.len() {
+            let mut hasher = Hasher::new(&domain_sep);
+            hasher.update(&input[..split]);
+            hasher.update(&input[split..]);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        let mut hasher = Hasher::new(&domain_sep);
+        for element in input.iter() {
+            hasher.update(&[*element]);
+        }
+        assert_eq!(hasher.finalize(), expected);
+
+        let mut hasher = Hasher::new(&domain_sep);
+        hasher.update(&input[..2]);
+        hasher.absorb_iter((2..input.len()).map(|i| Fq::from(i as u64)));
+        assert_eq!(hasher.finalize(), expected);
+    }
+}
@@ -0,0 +1,160 @@
+use core::hash::{BuildHasher, Hash};
+
+use crate::{encoding::BYTES_PER_ELEMENT, sponge::Sponge, Fq, ToFieldElements};
+
+/// Domain separator used by [`IndexHasher`], `from_le_bytes_mod_order(b"poseidon377.index")`.
+const INDEX_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    14498954494858870356,
+    8473457268820228455,
+    16760813417508792234,
+    518234371116782736,
+]);
+
+/// An adapter implementing [`core::hash::Hasher`] with the rate-4 sponge, for use
+/// as the hasher of `HashMap`s and `HashSet`s.
+///
+/// This is a non-cryptographic index: the output is truncated to 64 bits, so it
+/// must never be used where collision or preimage resistance is needed. It is also
+/// much slower than the hashers usually used for maps.
+///
+/// Written bytes are absorbed in chunks of 31 bytes, each interpreted as a
+/// little-endian integer, and [`finish`](core::hash::Hasher::finish) absorbs the
+/// last partial chunk and the total number of bytes written, squeezes a single
+/// element and returns its low 8 bytes as a little-endian integer. Together with
+/// [`CanonicalKey`], keys are indexed by their canonical field encoding.
+#[derive(Clone)]
+pub struct IndexHasher {
+    sponge: Sponge,
+    /// Bytes written since the last absorbed chunk.
+    chunk: [u8; BYTES_PER_ELEMENT],
+    chunk_len: usize,
+    len: u64,
+}
+
+impl Default for IndexHasher {
+    fn default() -> Self {
+        Self {
+            sponge: Sponge::new(&INDEX_DOMAIN_SEP),
+            chunk: [0u8; BYTES_PER_ELEMENT],
+            chunk_len: 0,
+            len: 0,
+        }
+    }
+}
+
+impl core::hash::Hasher for IndexHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.chunk[self.chunk_len] = *byte;
+            self.chunk_len += 1;
+            if self.chunk_len == BYTES_PER_ELEMENT {
+                self.sponge
+                    .absorb(&[Fq::from_le_bytes_mod_order(&self.chunk)]);
+                self.chunk_len = 0;
+            }
+        }
+        self.len += bytes.len() as u64;
+    }
+
+    fn finish(&self) -> u64 {
+        let mut sponge = self.sponge.clone();
+        if self.chunk_len > 0 {
+            sponge.absorb(&[Fq::from_le_bytes_mod_order(&self.chunk[..self.chunk_len])]);
+        }
+        sponge.absorb(&[Fq::from(self.len)]);
+        let mut low_bytes = [0u8; 8];
+        low_bytes.copy_from_slice(&sponge.finalize().to_bytes()[..8]);
+        u64::from_le_bytes(low_bytes)
+    }
+}
+
+/// A [`BuildHasher`] creating [`IndexHasher`]s.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BuildIndexHasher;
+
+impl BuildHasher for BuildIndexHasher {
+    type Hasher = IndexHasher;
+
+    fn build_hasher(&self) -> IndexHasher {
+        IndexHasher::default()
+    }
+}
+
+/// A map key that is hashed through its canonical [`ToFieldElements`] encoding.
+///
+/// Each element of the encoding is written to the hasher as its canonical 32-byte
+/// encoding, so two keys with the same field encoding always land in the same
+/// bucket, whatever their [`Hash`] implementation would do.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct CanonicalKey<T>(pub T);
+
+impl<T: ToFieldElements> Hash for CanonicalKey<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.append_field_elements(&mut WriteElements(state));
+    }
+}
+
+/// Writes field elements to a [`core::hash::Hasher`] as their canonical encoding.
+struct WriteElements<'a, H>(&'a mut H);
+
+impl<H: core::hash::Hasher> Extend<Fq> for WriteElements<'_, H> {
+    fn extend<I: IntoIterator<Item = Fq>>(&mut self, iter: I) {
+        for element in iter {
+            self.0.write(&element.to_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::hash::Hasher;
+
+    use super::*;
+
+    fn index<T: Hash>(value: &T) -> u64 {
+        BuildIndexHasher.hash_one(value)
+    }
+
+    #[test]
+    fn index_hasher() {
+        assert_eq!(
+            INDEX_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.index")
+        );
+
+        // Splitting the writes does not change the output.
+        let bytes = [3u8; 70];
+        let mut hasher = IndexHasher::default();
+        hasher.write(&bytes);
+        let expected = hasher.finish();
+        for split in [0, 1, 31, 40, 70] {
+            let mut hasher = IndexHasher::default();
+            hasher.write(&bytes[..split]);
+            hasher.write(&bytes[split..]);
+            assert_eq!(hasher.finish(), expected);
+        }
+
+        // Trailing zero bytes are distinguished by the length.
+        let mut hasher = IndexHasher::default();
+        hasher.write(&bytes);
+        hasher.write(&[0]);
+        assert_ne!(hasher.finish(), expected);
+
+        // Keys are indexed by their canonical encoding.
+        assert_eq!(
+            index(&CanonicalKey(42u64)),
+            index(&CanonicalKey(Fq::from(42u64)))
+        );
+        assert_ne!(index(&CanonicalKey(1u64)), index(&CanonicalKey(2u64)));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn index_hash_map() {
+        let mut map = std::collections::HashMap::with_hasher(BuildIndexHasher);
+        map.insert(CanonicalKey(1u64), "one");
+        map.insert(CanonicalKey(2u64), "two");
+        assert_eq!(map.get(&CanonicalKey(1u64)), Some(&"one"));
+        assert_eq!(map.get(&CanonicalKey(3u64)), None);
+    }
+}
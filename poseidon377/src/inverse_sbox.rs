@@ -0,0 +1,161 @@
+//! Poseidon instances using the inverse S-box, `x^(-1)`.
+//!
+//! These instances are generated by `poseidon-paramgen` with `Alpha::Inverse` and
+//! are entirely separate from the `x^17` instances at the crate root: the same
+//! inputs hash to unrelated outputs. Following the Poseidon paper, the S-box maps
+//! zero to zero.
+//!
+//! Each inversion is much slower natively than `x^17`, and the round numbers
+//! selected for this S-box use more partial rounds. No R1CS gadget is provided for
+//! these instances.
+
+use crate::{params, Fq, Instance, PoseidonParameters};
+
+/// Parameters for the rate-1 instance of Poseidon with the inverse S-box.
+pub const RATE_1_PARAMS: PoseidonParameters<2, 1, 4, 1, 73, 2, 146, 65> =
+    params::inverse::rate_1::rate_1();
+
+/// Parameters for the rate-2 instance of Poseidon with the inverse S-box.
+pub const RATE_2_PARAMS: PoseidonParameters<3, 2, 9, 4, 71, 3, 213, 63> =
+    params::inverse::rate_2::rate_2();
+
+/// Parameters for the rate-3 instance of Poseidon with the inverse S-box.
+pub const RATE_3_PARAMS: PoseidonParameters<4, 3, 16, 9, 68, 4, 272, 60> =
+    params::inverse::rate_3::rate_3();
+
+/// Parameters for the rate-4 instance of Poseidon with the inverse S-box.
+pub const RATE_4_PARAMS: PoseidonParameters<5, 4, 25, 16, 68, 5, 340, 60> =
+    params::inverse::rate_4::rate_4();
+
+/// Parameters for the rate-5 instance of Poseidon with the inverse S-box.
+pub const RATE_5_PARAMS: PoseidonParameters<6, 5, 36, 25, 65, 6, 390, 57> =
+    params::inverse::rate_5::rate_5();
+
+/// Parameters for the rate-6 instance of Poseidon with the inverse S-box.
+pub const RATE_6_PARAMS: PoseidonParameters<7, 6, 49, 36, 64, 7, 448, 56> =
+    params::inverse::rate_6::rate_6();
+
+/// Parameters for the rate-7 instance of Poseidon with the inverse S-box.
+pub const RATE_7_PARAMS: PoseidonParameters<8, 7, 64, 49, 62, 8, 496, 54> =
+    params::inverse::rate_7::rate_7();
+
+/// Hash a single [`Fq`] element with the provided `domain_separator`.
+pub fn hash_1(domain_separator: &Fq, value: Fq) -> Fq {
+    let mut state = Instance::new(&RATE_1_PARAMS);
+    state.n_to_1_fixed_hash(&[*domain_separator, value])
+}
+
+/// Hash two [`Fq`] elements with the provided `domain_separator`.
+pub fn hash_2(domain_separator: &Fq, value: (Fq, Fq)) -> Fq {
+    let mut state = Instance::new(&RATE_2_PARAMS);
+    state.n_to_1_fixed_hash(&[*domain_separator, value.0, value.1])
+}
+
+/// Hash three [`Fq`] elements with the provided `domain_separator`.
+pub fn hash_3(domain_separator: &Fq, value: (Fq, Fq, Fq)) -> Fq {
+    let mut state = Instance::new(&RATE_3_PARAMS);
+    state.n_to_1_fixed_hash(&[*domain_separator, value.0, value.1, value.2])
+}
+
+/// Hash four [`Fq`] elements with the provided `domain_separator`.
+pub fn hash_4(domain_separator: &Fq, value: (Fq, Fq, Fq, Fq)) -> Fq {
+    let mut state = Instance::new(&RATE_4_PARAMS);
+    state.n_to_1_fixed_hash(&[*domain_separator, value.0, value.1, value.2, value.3])
+}
+
+/// Hash five [`Fq`] elements with the provided `domain_separator`.
+pub fn hash_5(domain_separator: &Fq, value: (Fq, Fq, Fq, Fq, Fq)) -> Fq {
+    let mut state = Instance::new(&RATE_5_PARAMS);
+    state.n_to_1_fixed_hash(&[
+        *domain_separator,
+        value.0,
+        value.1,
+        value.2,
+        value.3,
+        value.4,
+    ])
+}
+
+/// Hash six [`Fq`] elements with the provided `domain_separator`.
+pub fn hash_6(domain_separator: &Fq, value: (Fq, Fq, Fq, Fq, Fq, Fq)) -> Fq {
+    let mut state = Instance::new(&RATE_6_PARAMS);
+    state.n_to_1_fixed_hash(&[
+        *domain_separator,
+        value.0,
+        value.1,
+        value.2,
+        value.3,
+        value.4,
+        value.5,
+    ])
+}
+
+/// Hash seven [`Fq`] elements with the provided `domain_separator`.
+pub fn hash_7(domain_separator: &Fq, value: (Fq, Fq, Fq, Fq, Fq, Fq, Fq)) -> Fq {
+    let mut state = Instance::new(&RATE_7_PARAMS);
+    state.n_to_1_fixed_hash(&[
+        *domain_separator,
+        value.0,
+        value.1,
+        value.2,
+        value.3,
+        value.4,
+        value.5,
+        value.6,
+    ])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn inverse_sbox_matches_unoptimized() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input: [Fq; 7] = core::array::from_fn(|i| Fq::from(i as u64 + 1));
+
+        let mut state = Instance::new(&RATE_1_PARAMS);
+        assert_eq!(
+            hash_1(&domain_sep, input[0]),
+            state.unoptimized_n_to_1_fixed_hash([domain_sep, input[0]])
+        );
+
+        let mut state = Instance::new(&RATE_4_PARAMS);
+        assert_eq!(
+            hash_4(&domain_sep, (input[0], input[1], input[2], input[3])),
+            state.unoptimized_n_to_1_fixed_hash([
+                domain_sep, input[0], input[1], input[2], input[3]
+            ])
+        );
+
+        let mut state = Instance::new(&RATE_7_PARAMS);
+        let expected = state.unoptimized_n_to_1_fixed_hash([
+            domain_sep, input[0], input[1], input[2], input[3], input[4], input[5], input[6],
+        ]);
+        assert_eq!(
+            hash_7(
+                &domain_sep,
+                (input[0], input[1], input[2], input[3], input[4], input[5], input[6])
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn inverse_sbox_differs_from_exponent() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let a = Fq::from(1u64);
+        let b = Fq::from(2u64);
+        assert_ne!(
+            hash_2(&domain_sep, (a, b)),
+            crate::hash_2(&domain_sep, (a, b))
+        );
+
+        // Zero inputs are mapped to zero by the S-box rather than panicking.
+        let zero = Fq::from(0u64);
+        assert_eq!(
+            hash_2(&zero, (zero, zero)),
+            Instance::new(&RATE_2_PARAMS).unoptimized_n_to_1_fixed_hash([zero, zero, zero])
+        );
+    }
+}
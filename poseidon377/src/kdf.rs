@@ -0,0 +1,152 @@
+use crate::{hash::keyed_sponge, sponge::Sponge, Fq, ToFieldElements};
+
+/// Domain separator used by [`Kdf::extract`], `from_le_bytes_mod_order(b"poseidon377.kdf.extract")`.
+const EXTRACT_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    17134674930993471326,
+    8004117683578693634,
+    14054240358401106310,
+    183356013415005106,
+]);
+
+/// Domain separator used by [`Kdf::expand`], `from_le_bytes_mod_order(b"poseidon377.kdf.expand")`.
+const EXPAND_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    14691321207534650726,
+    2861437210994296072,
+    12427699935695951808,
+    1320443669098325618,
+]);
+
+/// A key derivation function following the extract-then-expand design of HKDF.
+///
+/// [`Kdf::extract`] condenses input keying material, such as a wallet seed, into a
+/// single pseudorandom key, and [`Kdf::expand_into`] derives any number of
+/// [`Fq`] secrets from it for a given purpose. Both steps use a sponge built as for
+/// [`prf`](crate::prf), with the key bound to a domain separator reserved for each
+/// step in the capacity, so a key used here may also be used with the other keyed
+/// functions, such as [`mac`](crate::mac). The `info` label of each expansion is
+/// absorbed with its length, so secrets derived for different labels are independent.
+///
+/// ```
+/// use poseidon377::{Fq, Kdf};
+///
+/// let kdf = Kdf::extract(&Fq::from(0u64), b"wallet seed");
+/// let mut spend_key = [Fq::from(0u64)];
+/// kdf.expand_into(b"spend key", &mut spend_key);
+/// ```
+///
+/// With the `zeroize` feature, the pseudorandom key is wiped when the `Kdf` is dropped.
+#[derive(Clone)]
+pub struct Kdf {
+    prk: Fq,
+}
+
+impl Kdf {
+    /// Extract a pseudorandom key from the input keying material `ikm` with `salt`.
+    ///
+    /// The salt should be a fixed, application-specific value if no random salt is
+    /// available, and may be zero.
+    pub fn extract(salt: &Fq, ikm: &[u8]) -> Self {
+        let mut sponge = keyed_sponge(salt, &EXTRACT_DOMAIN_SEP);
+        ikm.append_field_elements(&mut SpongeInput(&mut sponge));
+        Self {
+            prk: sponge.finalize(),
+        }
+    }
+
+    /// Use an existing uniformly random element as the pseudorandom key.
+    pub fn from_prk(prk: Fq) -> Self {
+        Self { prk }
+    }
+
+    /// Derive enough secrets to fill `output` for the purpose identified by `info`.
+    pub fn expand_into(&self, info: &[u8], output: &mut [Fq]) {
+        let mut sponge = keyed_sponge(&self.prk, &EXPAND_DOMAIN_SEP);
+        info.append_field_elements(&mut SpongeInput(&mut sponge));
+        sponge.finalize_into(output);
+    }
+
+    /// Derive `n` secrets for the purpose identified by `info`, as with [`Kdf::expand_into`].
+    #[cfg(feature = "alloc")]
+    pub fn expand(&self, info: &[u8], n: usize) -> alloc::vec::Vec<Fq> {
+        let mut output = alloc::vec![Fq::from(0u64); n];
+        self.expand_into(info, &mut output);
+        output
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Kdf {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.prk);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Kdf {}
+
+/// Derive a single secret for the purpose identified by `label` from a `seed`.
+///
+/// This is [`Kdf::extract`] with a zero salt followed by [`Kdf::expand_into`] with
+/// `label` as the `info`.
+pub fn derive_key(seed: &[u8], label: &[u8]) -> Fq {
+    let mut key = [Fq::from(0u64)];
+    Kdf::extract(&Fq::from(0u64), seed).expand_into(label, &mut key);
+    key[0]
+}
+
+/// Absorbs field elements into a sponge.
+struct SpongeInput<'a>(&'a mut Sponge);
+
+impl Extend<Fq> for SpongeInput<'_> {
+    fn extend<I: IntoIterator<Item = Fq>>(&mut self, iter: I) {
+        for element in iter {
+            self.0.absorb(&[element]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn kdf_outputs() {
+        assert_eq!(
+            EXTRACT_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.kdf.extract")
+        );
+        assert_eq!(
+            EXPAND_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.kdf.expand")
+        );
+
+        let salt = Fq::from(7u64);
+        let kdf = Kdf::extract(&salt, b"seed");
+
+        // Extraction is the keyed sponge over the encoding of the seed.
+        let mut seed_elements = Vec::new();
+        b"seed"[..].append_field_elements(&mut seed_elements);
+        let mut sponge = keyed_sponge(&salt, &EXTRACT_DOMAIN_SEP);
+        sponge.absorb(&seed_elements);
+        assert_eq!(kdf.prk, sponge.finalize());
+
+        // Derived secrets depend on the salt, the seed and the label.
+        let key = kdf.expand(b"spend key", 3);
+        assert_eq!(kdf.expand(b"spend key", 1), key[..1]);
+        assert_ne!(kdf.expand(b"view key", 3), key);
+        assert_ne!(
+            Kdf::extract(&salt, b"other seed").expand(b"spend key", 3),
+            key
+        );
+        assert_ne!(
+            Kdf::extract(&Fq::from(8u64), b"seed").expand(b"spend key", 3),
+            key
+        );
+        assert_ne!(key[0], key[1]);
+
+        assert_eq!(
+            derive_key(b"seed", b"spend key"),
+            Kdf::extract(&Fq::from(0u64), b"seed").expand(b"spend key", 1)[0]
+        );
+    }
+}
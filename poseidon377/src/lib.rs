@@ -1,37 +1,287 @@
 //! An instantiation of Poseidon for the BLS12-377 scalar field.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "all-rates")]
+pub mod aead;
+#[cfg(feature = "alloc")]
+pub mod air;
+#[cfg(feature = "sponge")]
+pub mod ark_sponge;
+#[cfg(all(
+    feature = "alloc",
+    any(
+        feature = "rate-1",
+        feature = "rate-2",
+        feature = "rate-3",
+        feature = "rate-4",
+        feature = "rate-5",
+        feature = "rate-6",
+        feature = "rate-7",
+    )
+))]
+mod batch;
+#[cfg(feature = "bls12_381")]
+pub mod bls12_381;
+#[cfg(feature = "bn254")]
+pub mod bn254;
+#[cfg(feature = "all-rates")]
+pub mod commitment;
+#[cfg(any(feature = "rate-1", feature = "rate-2"))]
+mod const_hash;
+#[cfg(feature = "crh")]
+pub mod crh;
+mod domain;
+mod encoding;
+mod error;
+#[cfg(feature = "arkworks")]
+pub mod field;
+#[cfg(feature = "fp")]
+pub mod fp;
+#[cfg(feature = "fp255")]
+pub mod fp255;
+#[cfg(feature = "alloc")]
+pub mod gnark;
+#[cfg(feature = "goldilocks")]
+pub mod goldilocks;
+#[cfg(feature = "halo2")]
+pub mod halo2;
+#[cfg(any(
+    feature = "rate-1",
+    feature = "rate-2",
+    feature = "rate-3",
+    feature = "rate-4",
+    feature = "rate-5",
+    feature = "rate-6",
+    feature = "rate-7",
+    feature = "rate-8",
+    feature = "rate-9",
+    feature = "rate-10",
+    feature = "rate-11",
+    feature = "rate-12",
+    feature = "rate-13",
+    feature = "rate-14",
+    feature = "rate-15",
+    feature = "rate-16",
+))]
 mod hash;
+#[cfg(feature = "all-rates")]
+mod hasher;
+#[cfg(feature = "all-rates")]
+mod index;
+pub mod inverse_sbox;
+#[cfg(feature = "all-rates")]
+mod kdf;
+#[cfg(all(feature = "alloc", feature = "all-rates"))]
+pub mod merkle;
+mod output;
 mod params;
+#[cfg(feature = "pasta")]
+pub mod pasta;
+#[cfg(feature = "alloc")]
+pub mod plonkish;
+pub mod registry;
+#[cfg(feature = "rand_core")]
+mod rng;
+#[cfg(feature = "digest")]
+mod rust_crypto;
+#[cfg(feature = "all-rates")]
+mod safe;
+pub mod security_256;
+#[cfg(feature = "all-rates")]
+mod sponge;
+#[cfg(feature = "all-rates")]
+mod transcript;
+pub mod v2;
+#[cfg(feature = "all-rates")]
+mod value;
+#[cfg(feature = "all-rates")]
+mod xof;
 
-pub use hash::{hash_1, hash_2, hash_3, hash_4, hash_5, hash_6, hash_7};
+#[cfg(all(feature = "alloc", feature = "rate-1"))]
+pub use batch::batch_hash_1;
+#[cfg(all(feature = "alloc", feature = "rate-2"))]
+pub use batch::batch_hash_2;
+#[cfg(all(feature = "alloc", feature = "rate-3"))]
+pub use batch::batch_hash_3;
+#[cfg(all(feature = "alloc", feature = "rate-4"))]
+pub use batch::batch_hash_4;
+#[cfg(all(feature = "alloc", feature = "rate-5"))]
+pub use batch::batch_hash_5;
+#[cfg(all(feature = "alloc", feature = "rate-6"))]
+pub use batch::batch_hash_6;
+#[cfg(all(feature = "alloc", feature = "rate-7"))]
+pub use batch::batch_hash_7;
+#[cfg(all(feature = "parallel", feature = "rate-1"))]
+pub use batch::par_batch_hash_1;
+#[cfg(all(feature = "parallel", feature = "rate-2"))]
+pub use batch::par_batch_hash_2;
+#[cfg(all(feature = "parallel", feature = "rate-3"))]
+pub use batch::par_batch_hash_3;
+#[cfg(all(feature = "parallel", feature = "rate-4"))]
+pub use batch::par_batch_hash_4;
+#[cfg(all(feature = "parallel", feature = "rate-5"))]
+pub use batch::par_batch_hash_5;
+#[cfg(all(feature = "parallel", feature = "rate-6"))]
+pub use batch::par_batch_hash_6;
+#[cfg(all(feature = "parallel", feature = "rate-7"))]
+pub use batch::par_batch_hash_7;
+#[cfg(feature = "rate-1")]
+pub use const_hash::const_hash_1;
+#[cfg(feature = "rate-2")]
+pub use const_hash::const_hash_2;
+#[cfg(any(feature = "rate-1", feature = "rate-2"))]
+pub use const_hash::ConstFq;
+pub use domain::DomainSeparator;
+#[cfg(feature = "all-rates")]
+pub use domain::Personalization;
+#[cfg(feature = "all-rates")]
+pub use encoding::PoseidonHash;
+pub use encoding::ToFieldElements;
+pub use error::{AeadError, MerkleError, OutputError, SafeError, StoreError};
+#[cfg(feature = "rate-1")]
+pub use hash::hash_1;
+#[cfg(feature = "rate-10")]
+pub use hash::hash_10;
+#[cfg(feature = "rate-11")]
+pub use hash::hash_11;
+#[cfg(feature = "rate-12")]
+pub use hash::hash_12;
+#[cfg(feature = "rate-13")]
+pub use hash::hash_13;
+#[cfg(feature = "rate-14")]
+pub use hash::hash_14;
+#[cfg(feature = "rate-15")]
+pub use hash::hash_15;
+#[cfg(feature = "rate-16")]
+pub use hash::hash_16;
+#[cfg(feature = "rate-2")]
+pub use hash::hash_2;
+#[cfg(feature = "rate-3")]
+pub use hash::hash_3;
+#[cfg(feature = "rate-4")]
+pub use hash::hash_4;
+#[cfg(feature = "rate-5")]
+pub use hash::hash_5;
+#[cfg(feature = "rate-6")]
+pub use hash::hash_6;
+#[cfg(feature = "rate-7")]
+pub use hash::hash_7;
+#[cfg(feature = "rate-8")]
+pub use hash::hash_8;
+#[cfg(feature = "rate-9")]
+pub use hash::hash_9;
+#[cfg(feature = "all-rates")]
+pub use hash::{
+    compress_2, compress_8, hash, hash_1_to_2, hash_2_to_2, hash_bytes, hash_fixed, hash_n_to_m,
+    hash_object, hash_salted, hash_to_group, hash_varlen, keystream_into, mac, prf, prf_n,
+};
+#[cfg(all(feature = "alloc", feature = "all-rates"))]
+pub use hash::{keystream, keystream_bytes};
+#[cfg(feature = "all-rates")]
+pub use hasher::Hasher;
+#[cfg(feature = "all-rates")]
+pub use index::{BuildIndexHasher, CanonicalKey, IndexHasher};
+#[cfg(feature = "all-rates")]
+pub use kdf::{derive_key, Kdf};
+pub use output::HashOutput;
+#[cfg(feature = "rand_core")]
+pub use rng::PoseidonRng;
+#[cfg(feature = "digest")]
+pub use rust_crypto::PoseidonDigest;
+#[cfg(feature = "all-rates")]
+pub use safe::{SafeSponge, SpongeOp};
+#[cfg(feature = "all-rates")]
+pub use sponge::DuplexSponge;
+#[cfg(feature = "all-rates")]
+pub use transcript::Transcript;
+#[cfg(feature = "all-rates")]
+pub use value::{hash_values, Value};
+#[cfg(feature = "all-rates")]
+pub use xof::Xof;
 
 /// Parameters for the rate-1 instance of Poseidon.
+#[cfg(feature = "rate-1")]
 pub const RATE_1_PARAMS: PoseidonParameters<2, 1, 4, 1, 39, 2, 78, 31> = params::rate_1::rate_1();
 
 /// Parameters for the rate-2 instance of Poseidon.
+#[cfg(feature = "rate-2")]
 pub const RATE_2_PARAMS: PoseidonParameters<3, 2, 9, 4, 39, 3, 117, 31> = params::rate_2::rate_2();
 
 /// Parameters for the rate-3 instance of Poseidon.
+#[cfg(feature = "rate-3")]
 pub const RATE_3_PARAMS: PoseidonParameters<4, 3, 16, 9, 39, 4, 156, 31> = params::rate_3::rate_3();
 
 /// Parameters for the rate-4 instance of Poseidon.
+#[cfg(feature = "rate-4")]
 pub const RATE_4_PARAMS: PoseidonParameters<5, 4, 25, 16, 39, 5, 195, 31> =
     params::rate_4::rate_4();
 
 /// Parameters for the rate-5 instance of Poseidon.
+#[cfg(feature = "rate-5")]
 pub const RATE_5_PARAMS: PoseidonParameters<6, 5, 36, 25, 39, 6, 234, 31> =
     params::rate_5::rate_5();
 
 /// Parameters for the rate-6 instance of Poseidon.
+#[cfg(feature = "rate-6")]
 pub const RATE_6_PARAMS: PoseidonParameters<7, 6, 49, 36, 39, 7, 273, 31> =
     params::rate_6::rate_6();
 
 /// Parameters for the rate-7 instance of Poseidon.
+#[cfg(feature = "rate-7")]
 pub const RATE_7_PARAMS: PoseidonParameters<8, 7, 64, 49, 39, 8, 312, 31> =
     params::rate_7::rate_7();
 
+/// Parameters for the rate-8 instance of Poseidon.
+#[cfg(feature = "rate-8")]
+pub const RATE_8_PARAMS: PoseidonParameters<9, 8, 81, 64, 39, 9, 351, 31> =
+    params::rate_8::rate_8();
+
+/// Parameters for the rate-9 instance of Poseidon.
+#[cfg(feature = "rate-9")]
+pub const RATE_9_PARAMS: PoseidonParameters<10, 9, 100, 81, 39, 10, 390, 31> =
+    params::rate_9::rate_9();
+
+/// Parameters for the rate-10 instance of Poseidon.
+#[cfg(feature = "rate-10")]
+pub const RATE_10_PARAMS: PoseidonParameters<11, 10, 121, 100, 39, 11, 429, 31> =
+    params::rate_10::rate_10();
+
+/// Parameters for the rate-11 instance of Poseidon.
+#[cfg(feature = "rate-11")]
+pub const RATE_11_PARAMS: PoseidonParameters<12, 11, 144, 121, 39, 12, 468, 31> =
+    params::rate_11::rate_11();
+
+/// Parameters for the rate-12 instance of Poseidon.
+#[cfg(feature = "rate-12")]
+pub const RATE_12_PARAMS: PoseidonParameters<13, 12, 169, 144, 39, 13, 507, 31> =
+    params::rate_12::rate_12();
+
+/// Parameters for the rate-13 instance of Poseidon.
+#[cfg(feature = "rate-13")]
+pub const RATE_13_PARAMS: PoseidonParameters<14, 13, 196, 169, 39, 14, 546, 31> =
+    params::rate_13::rate_13();
+
+/// Parameters for the rate-14 instance of Poseidon.
+#[cfg(feature = "rate-14")]
+pub const RATE_14_PARAMS: PoseidonParameters<15, 14, 225, 196, 39, 15, 585, 31> =
+    params::rate_14::rate_14();
+
+/// Parameters for the rate-15 instance of Poseidon.
+#[cfg(feature = "rate-15")]
+pub const RATE_15_PARAMS: PoseidonParameters<16, 15, 256, 225, 39, 16, 624, 31> =
+    params::rate_15::rate_15();
+
+/// Parameters for the rate-16 instance of Poseidon.
+#[cfg(feature = "rate-16")]
+pub const RATE_16_PARAMS: PoseidonParameters<17, 16, 289, 256, 39, 17, 663, 31> =
+    params::rate_16::rate_16();
+
 pub use decaf377::Fq;
+#[cfg(feature = "derive")]
+pub use poseidon377_derive::PoseidonHash;
 pub use poseidon_parameters::v1::PoseidonParameters;
 pub use poseidon_permutation::Instance;
 
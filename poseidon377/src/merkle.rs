@@ -0,0 +1,71 @@
+//! Binary Merkle trees over [`Fq`] leaves.
+//!
+//! A tree of depth `d` has `2^d` leaf positions, indexed from `0` on the left, and
+//! the node at height `h` and index `i` has the nodes at height `h - 1` and indices
+//! `2i` and `2i + 1` as children. Leaves are the [`Fq`] values themselves, such as
+//! commitments, and the two children of each internal node are combined with
+//! [`compress_2`].
+//!
+//! Positions that were never filled hold [`EMPTY_LEAF`], and an internal node all of
+//! whose leaves are empty is the empty node of its height, [`empty_node`]:
+//!
+//! ```text
+//! empty_node(0) = EMPTY_LEAF = 0
+//! empty_node(h) = compress_2(empty_node(h - 1), empty_node(h - 1))
+//! ```
+//!
+//! A [`Tree`] stores the nodes to the left of the last non-empty leaf, so the memory
+//! it uses depends on the number of leaves, not on its depth. An [`IncrementalTree`]
+//! only supports appending leaves, and stores a single path of the tree. A
+//! [`SparseTree`] has a leaf for every 256-bit key and only stores non-empty nodes.
+//! A [`StoredTree`] keeps its nodes in a [`NodeStore`], such as a database.
+//!
+//! The [`tct`] module has helpers for Penumbra's tiered commitment tree, which has
+//! its own hashing conventions.
+//!
+//! An [`OctTree`] has eight children per node instead, combined with
+//! [`compress_8`](crate::compress_8), and its empty nodes are defined the same way.
+
+use alloc::vec::Vec;
+
+use crate::{compress_2, Fq};
+
+mod frontier;
+mod multiproof;
+mod octree;
+mod proof;
+#[cfg(feature = "serde")]
+mod serialize;
+mod sparse;
+mod store;
+pub mod tct;
+mod tree;
+
+pub use frontier::{IncrementalTree, Witness};
+pub use multiproof::MultiProof;
+pub use octree::{OctProof, OctTree, MAX_OCT_DEPTH};
+pub use proof::MerkleProof;
+pub use sparse::{SparseKey, SparseMerkleProof, SparseTree, SPARSE_DEPTH};
+pub use store::{MemoryStore, NodeStore, Position, StoredTree};
+pub use tree::Tree;
+
+/// The maximum depth of a tree, for which leaf indices fill a `u64`.
+pub const MAX_DEPTH: usize = 64;
+
+/// The value of a leaf that was never filled.
+pub const EMPTY_LEAF: Fq = Fq::from_montgomery_limbs([0, 0, 0, 0]);
+
+/// The root of a subtree of height `height` all of whose leaves are [`EMPTY_LEAF`].
+pub fn empty_node(height: usize) -> Fq {
+    (0..height).fold(EMPTY_LEAF, |node, _| compress_2(node, node))
+}
+
+/// The empty nodes of all heights from `0` to `depth`, inclusive.
+fn empty_nodes(depth: usize) -> Vec<Fq> {
+    let mut nodes = Vec::with_capacity(depth + 1);
+    nodes.push(EMPTY_LEAF);
+    for height in 0..depth {
+        nodes.push(compress_2(nodes[height], nodes[height]));
+    }
+    nodes
+}
@@ -0,0 +1,286 @@
+use alloc::vec::Vec;
+
+use super::{empty_nodes, MerkleProof, MAX_DEPTH};
+use crate::{compress_2, error::MerkleError, Fq};
+
+/// An append-only Merkle tree that only stores its frontier.
+///
+/// Leaves are appended from left to right, and the tree only keeps the last leaf
+/// and, for each height, the last complete left child on the path to the next
+/// position, so appending costs at most `depth` hashes and memory is `O(depth)`
+/// regardless of the number of leaves. The root is the same as the root of a
+/// [`Tree`](super::Tree) with the same leaves.
+///
+/// Proofs for individual leaves are maintained with a [`Witness`], created with
+/// [`IncrementalTree::witness`] right after appending the leaf, to which every
+/// leaf appended to the tree afterwards must also be appended.
+#[derive(Clone, Debug)]
+pub struct IncrementalTree {
+    depth: usize,
+    len: u64,
+    last_leaf: Fq,
+    /// The last left child stored at each height. The entry at height `h` is the
+    /// left sibling of the ancestor of the last leaf if bit `h` of its position is set.
+    frontier: Vec<Fq>,
+    /// The empty node of each height.
+    empty: Vec<Fq>,
+}
+
+impl IncrementalTree {
+    /// Create an empty tree of the given `depth`.
+    pub fn new(depth: usize) -> Result<Self, MerkleError> {
+        if depth > MAX_DEPTH {
+            return Err(MerkleError::InvalidDepth);
+        }
+        let empty = empty_nodes(depth);
+        Ok(Self {
+            depth,
+            len: 0,
+            last_leaf: empty[0],
+            frontier: empty[..depth].to_vec(),
+            empty,
+        })
+    }
+
+    /// Create a tree of the given `depth` by appending all of `leaves` in order.
+    ///
+    /// The leaves are consumed one at a time, so they can be produced lazily, for
+    /// instance by a decoder, and the memory used does not depend on their number.
+    /// The root of the returned tree is the root of a [`Tree`](super::Tree) with the
+    /// same leaves.
+    pub fn from_leaves(
+        depth: usize,
+        leaves: impl IntoIterator<Item = Fq>,
+    ) -> Result<Self, MerkleError> {
+        let mut tree = Self::new(depth)?;
+        for leaf in leaves {
+            tree.append(leaf)?;
+        }
+        Ok(tree)
+    }
+
+    /// The depth of the tree.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The number of leaves appended to the tree.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Whether no leaf was appended to the tree.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Append `leaf` to the tree, returning its position.
+    pub fn append(&mut self, leaf: Fq) -> Result<u64, MerkleError> {
+        let position = self.len;
+        let full = if self.depth >= MAX_DEPTH {
+            position == u64::MAX
+        } else {
+            position >> self.depth != 0
+        };
+        if full {
+            return Err(MerkleError::TooManyLeaves);
+        }
+
+        let mut node = leaf;
+        for height in 0..self.depth {
+            if (position >> height) & 1 == 1 {
+                node = compress_2(self.frontier[height], node);
+            } else {
+                self.frontier[height] = node;
+                break;
+            }
+        }
+        self.last_leaf = leaf;
+        self.len += 1;
+        Ok(position)
+    }
+
+    /// The root of the tree.
+    pub fn root(&self) -> Fq {
+        match self.len.checked_sub(1) {
+            None => self.empty[self.depth],
+            Some(position) => self.path_root(position, self.last_leaf),
+        }
+    }
+
+    /// Start witnessing the last leaf appended to the tree, if any.
+    pub fn witness(&self) -> Option<Witness> {
+        let position = self.len.checked_sub(1)?;
+        let siblings = (0..self.depth)
+            .map(|height| {
+                if (position >> height) & 1 == 1 {
+                    self.frontier[height]
+                } else {
+                    self.empty[height]
+                }
+            })
+            .collect();
+        let mut witness = Witness {
+            position,
+            leaf: self.last_leaf,
+            siblings,
+            cursor: None,
+        };
+        witness.next_cursor(0);
+        Some(witness)
+    }
+
+    /// The root of the tree with `leaf` at `position` and only empty leaves after it.
+    fn path_root(&self, position: u64, leaf: Fq) -> Fq {
+        (0..self.depth).fold(leaf, |node, height| {
+            if (position >> height) & 1 == 1 {
+                compress_2(self.frontier[height], node)
+            } else {
+                compress_2(node, self.empty[height])
+            }
+        })
+    }
+}
+
+/// A proof for a leaf of an [`IncrementalTree`] that is kept up to date as leaves
+/// are appended to the tree.
+///
+/// The siblings of the path that are left of the leaf never change. The siblings
+/// that are right of the leaf cover consecutive ranges of the positions after the
+/// leaf, in order of increasing height, so they are filled one at a time by a
+/// cursor tree that the appended leaves go into.
+#[derive(Clone, Debug)]
+pub struct Witness {
+    position: u64,
+    leaf: Fq,
+    /// The siblings of the path, with the right siblings not filled yet empty.
+    siblings: Vec<Fq>,
+    /// The height of the right sibling being filled and the tree of its leaves.
+    cursor: Option<(usize, IncrementalTree)>,
+}
+
+impl Witness {
+    /// The position of the witnessed leaf.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// The witnessed leaf.
+    pub fn leaf(&self) -> Fq {
+        self.leaf
+    }
+
+    /// Append `leaf` to the witness, which must also have been appended to the tree.
+    pub fn append(&mut self, leaf: Fq) -> Result<(), MerkleError> {
+        let (height, cursor) = self.cursor.as_mut().ok_or(MerkleError::TooManyLeaves)?;
+        let height = *height;
+        cursor.append(leaf)?;
+        if cursor.len() == 1 << height {
+            self.siblings[height] = cursor.root();
+            self.next_cursor(height + 1);
+        }
+        Ok(())
+    }
+
+    /// The proof for the witnessed leaf in the current tree.
+    pub fn proof(&self) -> MerkleProof {
+        let mut siblings = self.siblings.clone();
+        if let Some((height, cursor)) = &self.cursor {
+            siblings[*height] = cursor.root();
+        }
+        MerkleProof {
+            index: self.position,
+            siblings,
+        }
+    }
+
+    /// The root of the current tree.
+    pub fn root(&self) -> Fq {
+        self.proof().root(self.leaf)
+    }
+
+    /// Start filling the lowest right sibling at a height of at least `min_height`.
+    fn next_cursor(&mut self, min_height: usize) {
+        self.cursor = (min_height..self.siblings.len())
+            .find(|height| (self.position >> height) & 1 == 0)
+            .map(|height| {
+                let cursor = IncrementalTree::new(height).expect("height is below the depth");
+                (height, cursor)
+            });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::merkle::Tree;
+
+    #[test]
+    fn incremental_root_matches_tree() {
+        let leaves: Vec<Fq> = (1..=16u64).map(Fq::from).collect();
+        let mut tree = IncrementalTree::new(4).unwrap();
+        assert_eq!(tree.root(), Tree::new(4).unwrap().root());
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert_eq!(tree.append(*leaf).unwrap(), i as u64);
+            assert_eq!(
+                tree.root(),
+                Tree::from_leaves(4, &leaves[..=i]).unwrap().root()
+            );
+        }
+        assert_eq!(tree.append(Fq::from(0u64)), Err(MerkleError::TooManyLeaves));
+
+        let mut tree = IncrementalTree::new(0).unwrap();
+        tree.append(leaves[0]).unwrap();
+        assert_eq!(tree.root(), leaves[0]);
+    }
+
+    #[test]
+    fn incremental_tree_from_leaves() {
+        // A lazily produced sequence of leaves, never collected.
+        let leaves = (1..=1000u64).map(|i| Fq::from(i * i));
+        let tree = IncrementalTree::from_leaves(10, leaves.clone()).unwrap();
+        assert_eq!(tree.len(), 1000);
+
+        let collected: Vec<Fq> = leaves.collect();
+        assert_eq!(
+            tree.root(),
+            Tree::from_leaves(10, &collected).unwrap().root()
+        );
+
+        assert_eq!(
+            IncrementalTree::from_leaves(3, collected).unwrap_err(),
+            MerkleError::TooManyLeaves
+        );
+    }
+
+    #[test]
+    fn witnesses_follow_appends() {
+        let leaves: Vec<Fq> = (1..=16u64).map(Fq::from).collect();
+        let mut tree = IncrementalTree::new(4).unwrap();
+        let mut witnesses: Vec<Witness> = Vec::new();
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            tree.append(*leaf).unwrap();
+            for witness in witnesses.iter_mut() {
+                witness.append(*leaf).unwrap();
+            }
+            witnesses.push(tree.witness().unwrap());
+
+            let full_tree = Tree::from_leaves(4, &leaves[..=i]).unwrap();
+            for witness in &witnesses {
+                assert_eq!(
+                    witness.proof(),
+                    full_tree.proof(witness.position()).unwrap()
+                );
+                assert_eq!(witness.root(), tree.root());
+            }
+        }
+
+        // The tree is full, so no witness can be extended.
+        assert_eq!(
+            witnesses[0].append(Fq::from(0u64)),
+            Err(MerkleError::TooManyLeaves)
+        );
+    }
+}
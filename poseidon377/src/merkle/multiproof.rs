@@ -0,0 +1,72 @@
+use alloc::vec::Vec;
+
+use super::MAX_DEPTH;
+use crate::{compress_2, Fq};
+
+/// A proof that several leaves are at given positions of a [`Tree`](super::Tree).
+///
+/// Rather than one path per leaf, a multiproof contains each node needed to
+/// recompute the root only once, and omits the nodes that can be computed from the
+/// opened leaves. Opening `k` leaves of a tree of depth `d` takes at most `k * d`
+/// nodes, and far fewer when the leaves are close to each other.
+///
+/// The nodes are ordered by increasing height, and by increasing index within each
+/// height. The leaves opened by the proof must be given in the order of `indices`,
+/// which is sorted.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultiProof {
+    /// The depth of the tree.
+    pub depth: usize,
+    /// The indices of the opened leaves, in increasing order.
+    pub indices: Vec<u64>,
+    /// The nodes needed to recompute the root from the opened leaves.
+    pub nodes: Vec<Fq>,
+}
+
+impl MultiProof {
+    /// Compute the root of the tree with `leaves` at the positions of this proof, or
+    /// `None` if the proof or the number of leaves is malformed.
+    pub fn root(&self, leaves: &[Fq]) -> Option<Fq> {
+        let in_range = |index: &u64| self.depth >= MAX_DEPTH || index >> self.depth == 0;
+        let sorted = self.indices.windows(2).all(|pair| pair[0] < pair[1]);
+        if self.depth > MAX_DEPTH
+            || self.indices.is_empty()
+            || leaves.len() != self.indices.len()
+            || !sorted
+            || !self.indices.iter().all(in_range)
+        {
+            return None;
+        }
+
+        let mut layer: Vec<(u64, Fq)> = self
+            .indices
+            .iter()
+            .copied()
+            .zip(leaves.iter().copied())
+            .collect();
+        let mut nodes = self.nodes.iter();
+        for _ in 0..self.depth {
+            let mut parents = Vec::with_capacity(layer.len());
+            let mut known = layer.iter().peekable();
+            while let Some(&(index, node)) = known.next() {
+                let parent = match known.peek() {
+                    Some(&&(next, sibling)) if index & 1 == 0 && next == index + 1 => {
+                        known.next();
+                        compress_2(node, sibling)
+                    }
+                    _ if index & 1 == 0 => compress_2(node, *nodes.next()?),
+                    _ => compress_2(*nodes.next()?, node),
+                };
+                parents.push((index >> 1, parent));
+            }
+            layer = parents;
+        }
+
+        nodes.next().is_none().then_some(layer[0].1)
+    }
+
+    /// Check that `leaves` are at the positions of this proof in the tree with `root`.
+    pub fn verify(&self, root: &Fq, leaves: &[Fq]) -> bool {
+        self.root(leaves) == Some(*root)
+    }
+}
@@ -0,0 +1,242 @@
+use core::convert::TryFrom;
+
+use alloc::vec::Vec;
+
+use super::EMPTY_LEAF;
+use crate::{compress_8, error::MerkleError, Fq};
+
+/// The maximum depth of an [`OctTree`], for which leaf indices fill a `u64`.
+pub const MAX_OCT_DEPTH: usize = 21;
+
+/// A fixed-depth Merkle tree with eight children per node, combined with
+/// [`compress_8`], and all of its nodes in memory.
+///
+/// A tree of depth `d` has `8^d` leaf positions, so it needs a third of the depth of
+/// a binary [`Tree`](super::Tree) with the same capacity, and proofs take `d`
+/// permutations instead of `3d`, at the cost of seven siblings per level. The node
+/// at height `h` and index `i` has the nodes at height `h - 1` and indices `8i` to
+/// `8i + 7` as children, and the empty node of each height is the compression of
+/// eight empty nodes of the height below.
+#[derive(Clone, Debug)]
+pub struct OctTree {
+    depth: usize,
+    /// The nodes of each height up to the last non-empty one, from the leaves at
+    /// height `0` to the root at height `depth`.
+    levels: Vec<Vec<Fq>>,
+    /// The empty node of each height.
+    empty: Vec<Fq>,
+}
+
+impl OctTree {
+    /// Create a tree of the given `depth` with all leaves empty.
+    pub fn new(depth: usize) -> Result<Self, MerkleError> {
+        Self::from_leaves(depth, &[])
+    }
+
+    /// Create a tree of the given `depth` with `leaves` in the leftmost positions
+    /// and all other leaves empty.
+    pub fn from_leaves(depth: usize, leaves: &[Fq]) -> Result<Self, MerkleError> {
+        if depth > MAX_OCT_DEPTH {
+            return Err(MerkleError::InvalidDepth);
+        }
+        if leaves.len() as u128 > 1u128 << (3 * depth) {
+            return Err(MerkleError::TooManyLeaves);
+        }
+
+        let empty = empty_oct_nodes(depth);
+        let mut levels = Vec::with_capacity(depth + 1);
+        levels.push(leaves.to_vec());
+        for height in 0..depth {
+            let children = &levels[height];
+            let parents = children
+                .chunks(8)
+                .map(|chunk| {
+                    compress_8(core::array::from_fn(|i| {
+                        chunk.get(i).copied().unwrap_or(empty[height])
+                    }))
+                })
+                .collect();
+            levels.push(parents);
+        }
+
+        Ok(Self {
+            depth,
+            levels,
+            empty,
+        })
+    }
+
+    /// The depth of the tree.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The number of leaves up to the last one that was filled.
+    pub fn len(&self) -> u64 {
+        self.levels[0].len() as u64
+    }
+
+    /// Whether no leaf was filled.
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// The root of the tree.
+    pub fn root(&self) -> Fq {
+        self.node(self.depth, 0)
+    }
+
+    /// The leaf at `index`, or `None` if `index` is not a position of the tree.
+    pub fn leaf(&self, index: u64) -> Option<Fq> {
+        in_range(self.depth, index).then(|| self.node(0, index))
+    }
+
+    /// Generate a proof that the leaf at `index` is in the tree.
+    pub fn proof(&self, index: u64) -> Result<OctProof, MerkleError> {
+        if !in_range(self.depth, index) {
+            return Err(MerkleError::IndexOutOfRange);
+        }
+        let siblings = (0..self.depth)
+            .map(|height| {
+                let node = index >> (3 * height);
+                let first = node & !7;
+                let mut siblings = (0..8)
+                    .filter(|i| first + i != node)
+                    .map(|i| self.node(height, first + i));
+                core::array::from_fn(|_| siblings.next().expect("seven siblings"))
+            })
+            .collect();
+        Ok(OctProof { index, siblings })
+    }
+
+    /// The node at `height` and `index`, which is empty if it was not stored.
+    fn node(&self, height: usize, index: u64) -> Fq {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.levels[height].get(index))
+            .copied()
+            .unwrap_or(self.empty[height])
+    }
+}
+
+/// A proof that a leaf is at a given position of an [`OctTree`].
+///
+/// For each height, starting from the leaves, the proof contains the seven siblings
+/// of the node on the path from the leaf to the root, in order of index.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OctProof {
+    /// The index of the leaf.
+    pub index: u64,
+    /// The siblings of the nodes on the path from the leaf to the root.
+    pub siblings: Vec<[Fq; 7]>,
+}
+
+impl OctProof {
+    /// The depth of the tree this proof is for.
+    pub fn depth(&self) -> usize {
+        self.siblings.len()
+    }
+
+    /// Compute the root of the tree with `leaf` at the position of this proof.
+    pub fn root(&self, leaf: Fq) -> Fq {
+        self.siblings
+            .iter()
+            .enumerate()
+            .fold(leaf, |node, (height, siblings)| {
+                let position = ((self.index >> (3 * height)) & 7) as usize;
+                compress_8(core::array::from_fn(|i| match i.cmp(&position) {
+                    core::cmp::Ordering::Less => siblings[i],
+                    core::cmp::Ordering::Equal => node,
+                    core::cmp::Ordering::Greater => siblings[i - 1],
+                }))
+            })
+    }
+
+    /// Check that `leaf` is at the position of this proof in the tree with `root`.
+    pub fn verify(&self, root: &Fq, leaf: Fq) -> bool {
+        self.depth() <= MAX_OCT_DEPTH
+            && in_range(self.depth(), self.index)
+            && self.root(leaf) == *root
+    }
+}
+
+fn in_range(depth: usize, index: u64) -> bool {
+    // 3 * MAX_OCT_DEPTH is smaller than 64, so the shift does not overflow.
+    depth <= MAX_OCT_DEPTH && index >> (3 * depth) == 0
+}
+
+/// The empty nodes of all heights from `0` to `depth`, inclusive.
+fn empty_oct_nodes(depth: usize) -> Vec<Fq> {
+    let mut nodes = Vec::with_capacity(depth + 1);
+    nodes.push(EMPTY_LEAF);
+    for height in 0..depth {
+        nodes.push(compress_8([nodes[height]; 8]));
+    }
+    nodes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn leaves(n: u64) -> Vec<Fq> {
+        (0..n).map(|i| Fq::from(i + 1)).collect()
+    }
+
+    #[test]
+    fn oct_tree_root() {
+        let leaves = leaves(10);
+        let tree = OctTree::from_leaves(2, &leaves).unwrap();
+
+        let mut children = [EMPTY_LEAF; 8];
+        children.copy_from_slice(&leaves[..8]);
+        let first = compress_8(children);
+        let mut children = [EMPTY_LEAF; 8];
+        children[..2].copy_from_slice(&leaves[8..]);
+        let second = compress_8(children);
+        let empty = compress_8([EMPTY_LEAF; 8]);
+        assert_eq!(
+            tree.root(),
+            compress_8([first, second, empty, empty, empty, empty, empty, empty])
+        );
+
+        assert_eq!(OctTree::new(2).unwrap().root(), compress_8([empty; 8]));
+        assert_eq!(
+            OctTree::from_leaves(1, &leaves).unwrap_err(),
+            MerkleError::TooManyLeaves
+        );
+        assert_eq!(
+            OctTree::new(MAX_OCT_DEPTH + 1).unwrap_err(),
+            MerkleError::InvalidDepth
+        );
+    }
+
+    #[test]
+    fn oct_tree_proofs() {
+        let leaves = leaves(70);
+        let tree = OctTree::from_leaves(3, &leaves).unwrap();
+        let root = tree.root();
+
+        for index in [0, 5, 7, 8, 63, 69, 70, 511] {
+            let leaf = tree.leaf(index).unwrap();
+            let proof = tree.proof(index).unwrap();
+            assert_eq!(proof.depth(), 3);
+            assert!(proof.verify(&root, leaf));
+            assert!(!proof.verify(&root, leaf + Fq::from(1u64)));
+
+            // Moving a leaf among empty leaves does not change the root.
+            if index < 70 {
+                let mut wrong_index = proof.clone();
+                wrong_index.index ^= 1;
+                assert!(!wrong_index.verify(&root, leaf));
+            }
+        }
+
+        assert_eq!(tree.leaf(512), None);
+        assert_eq!(tree.proof(512).unwrap_err(), MerkleError::IndexOutOfRange);
+
+        let deep = OctTree::from_leaves(MAX_OCT_DEPTH, &leaves).unwrap();
+        let proof = deep.proof(u64::MAX >> 1).unwrap();
+        assert!(proof.verify(&deep.root(), EMPTY_LEAF));
+    }
+}
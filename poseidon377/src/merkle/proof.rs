@@ -0,0 +1,87 @@
+use core::convert::TryInto;
+
+use alloc::vec::Vec;
+
+use crate::{compress_2, Fq, MerkleError};
+
+/// A proof that a leaf is at a given position of a [`Tree`](super::Tree).
+///
+/// The proof contains the siblings of the nodes on the path from the leaf to the
+/// root, starting with the sibling of the leaf, so the depth of the tree is the
+/// number of siblings.
+///
+/// The wire encoding of a proof, produced by [`MerkleProof::to_bytes`], is the
+/// index as 8 little-endian bytes followed by the 32-byte encodings of the siblings,
+/// in the same order. With the `serde` feature, proofs are serialized as the bytes
+/// of this encoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerkleProof {
+    /// The index of the leaf.
+    pub index: u64,
+    /// The siblings of the nodes on the path from the leaf to the root.
+    pub siblings: Vec<Fq>,
+}
+
+impl MerkleProof {
+    /// The depth of the tree this proof is for.
+    pub fn depth(&self) -> usize {
+        self.siblings.len()
+    }
+
+    /// Compute the root of the tree with `leaf` at the position of this proof.
+    pub fn root(&self, leaf: Fq) -> Fq {
+        self.siblings
+            .iter()
+            .enumerate()
+            .fold(leaf, |node, (height, sibling)| {
+                if (self.index >> height) & 1 == 0 {
+                    compress_2(node, *sibling)
+                } else {
+                    compress_2(*sibling, node)
+                }
+            })
+    }
+
+    /// Check that `leaf` is at the position of this proof in the tree with `root`.
+    pub fn verify(&self, root: &Fq, leaf: Fq) -> bool {
+        let in_range = self.depth() >= super::MAX_DEPTH || self.index >> self.depth() == 0;
+        self.depth() <= super::MAX_DEPTH && in_range && self.root(leaf) == *root
+    }
+
+    /// The wire encoding of this proof.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8 + 32 * self.siblings.len());
+        bytes.extend_from_slice(&self.index.to_le_bytes());
+        for sibling in &self.siblings {
+            bytes.extend_from_slice(&sibling.to_bytes());
+        }
+        bytes
+    }
+
+    /// Decode a proof from its wire encoding.
+    ///
+    /// This fails if the encoding is malformed, if a sibling is not a canonical
+    /// encoding of an [`Fq`] element, if the depth is larger than
+    /// [`MAX_DEPTH`](super::MAX_DEPTH), or if the index is out of range.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MerkleError> {
+        if bytes.len() < 8 || !(bytes.len() - 8).is_multiple_of(32) {
+            return Err(MerkleError::InvalidEncoding);
+        }
+        let (index, siblings) = bytes.split_at(8);
+        let index = u64::from_le_bytes(index.try_into().expect("index is 8 bytes"));
+        let siblings = siblings
+            .chunks(32)
+            .map(|chunk| Fq::from_bytes_checked(chunk.try_into().expect("chunk is 32 bytes")))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| MerkleError::InvalidEncoding)?;
+
+        let depth = siblings.len();
+        if depth > super::MAX_DEPTH {
+            return Err(MerkleError::InvalidDepth);
+        }
+        if depth < super::MAX_DEPTH && index >> depth != 0 {
+            return Err(MerkleError::IndexOutOfRange);
+        }
+        Ok(Self { index, siblings })
+    }
+}
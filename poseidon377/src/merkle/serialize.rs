@@ -0,0 +1,110 @@
+//! `serde` support for proofs, which are serialized as the bytes of their wire encoding.
+
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::{MerkleProof, SparseMerkleProof};
+use crate::MerkleError;
+
+/// A proof type that can be decoded from its wire encoding.
+trait WireEncoding: Sized {
+    const NAME: &'static str;
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, MerkleError>;
+}
+
+impl WireEncoding for MerkleProof {
+    const NAME: &'static str = "a Merkle proof";
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, MerkleError> {
+        MerkleProof::from_bytes(bytes)
+    }
+}
+
+impl WireEncoding for SparseMerkleProof {
+    const NAME: &'static str = "a sparse Merkle proof";
+
+    fn from_bytes(bytes: &[u8]) -> Result<Self, MerkleError> {
+        SparseMerkleProof::from_bytes(bytes)
+    }
+}
+
+struct WireVisitor<T>(PhantomData<T>);
+
+impl<'de, T: WireEncoding> Visitor<'de> for WireVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the wire encoding of {}", T::NAME)
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<T, E> {
+        T::from_bytes(bytes).map_err(E::custom)
+    }
+
+    // Formats without a bytes type, such as JSON, serialize bytes as a sequence.
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
+
+impl Serialize for MerkleProof {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+impl<'de> Deserialize<'de> for MerkleProof {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(WireVisitor(PhantomData))
+    }
+}
+
+impl Serialize for SparseMerkleProof {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.to_bytes())
+    }
+}
+
+impl<'de> Deserialize<'de> for SparseMerkleProof {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_bytes(WireVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::de::{value::BytesDeserializer, value::Error};
+
+    use super::*;
+    use crate::merkle::{SparseTree, Tree};
+    use crate::Fq;
+
+    #[test]
+    fn deserialize_proofs() {
+        let leaves: Vec<Fq> = (0..5u64).map(Fq::from).collect();
+        let tree = Tree::from_leaves(4, &leaves).unwrap();
+        let proof = tree.proof(3).unwrap();
+        let bytes = proof.to_bytes();
+        let decoded = MerkleProof::deserialize(BytesDeserializer::<Error>::new(&bytes)).unwrap();
+        assert_eq!(decoded, proof);
+        assert!(MerkleProof::deserialize(BytesDeserializer::<Error>::new(&bytes[1..])).is_err());
+
+        let mut tree = SparseTree::new();
+        tree.insert([7; 32], Fq::from(7u64));
+        let proof = tree.proof(&[7; 32]);
+        let bytes = proof.to_bytes();
+        let decoded =
+            SparseMerkleProof::deserialize(BytesDeserializer::<Error>::new(&bytes)).unwrap();
+        assert_eq!(decoded, proof);
+    }
+}
@@ -0,0 +1,342 @@
+use core::convert::TryInto;
+
+use alloc::{collections::BTreeMap, collections::BTreeSet, vec::Vec};
+
+use super::{empty_nodes, EMPTY_LEAF};
+use crate::{compress_2, Fq, MerkleError};
+
+/// The depth of a [`SparseTree`], with one leaf position for every 256-bit key.
+pub const SPARSE_DEPTH: usize = 256;
+
+/// A 256-bit key, identifying a leaf position of a [`SparseTree`].
+///
+/// Bit `h` of the key, counting from the least significant bit of the first byte,
+/// selects the right child at height `h + 1` of the path to the leaf, as with the
+/// bits of leaf indices in a [`Tree`](super::Tree). The canonical encoding of an
+/// [`Fq`] element, such as a nullifier, can be used as a key.
+pub type SparseKey = [u8; 32];
+
+/// A Merkle tree of depth [`SPARSE_DEPTH`] in which most leaves are empty.
+///
+/// Only non-empty nodes are stored, and the empty nodes of each height are
+/// precomputed, so the memory used is proportional to the number of non-empty
+/// leaves. Setting a leaf to [`EMPTY_LEAF`] removes it.
+///
+/// Updating a leaf costs [`SPARSE_DEPTH`] hashes. [`SparseTree::update_batch`]
+/// recomputes each ancestor shared by several updated leaves only once.
+#[derive(Clone, Debug)]
+pub struct SparseTree {
+    /// The non-empty nodes, keyed by height and by the key of their leftmost leaf.
+    nodes: BTreeMap<(usize, SparseKey), Fq>,
+    /// The empty node of each height.
+    empty: Vec<Fq>,
+}
+
+impl Default for SparseTree {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparseTree {
+    /// Create a tree with all leaves empty.
+    pub fn new() -> Self {
+        Self {
+            nodes: BTreeMap::new(),
+            empty: sparse_empty_nodes().to_vec(),
+        }
+    }
+
+    /// The root of the tree.
+    pub fn root(&self) -> Fq {
+        self.node(SPARSE_DEPTH, &[0u8; 32])
+    }
+
+    /// The leaf at `key`, or `None` if it is empty.
+    pub fn get(&self, key: &SparseKey) -> Option<Fq> {
+        self.nodes.get(&(0, *key)).copied()
+    }
+
+    /// Set the leaf at `key` to `value`.
+    pub fn insert(&mut self, key: SparseKey, value: Fq) {
+        self.update_batch(&[(key, value)]);
+    }
+
+    /// Empty the leaf at `key`.
+    pub fn remove(&mut self, key: &SparseKey) {
+        self.update_batch(&[(*key, EMPTY_LEAF)]);
+    }
+
+    /// Set the leaves at the keys of `updates` to the corresponding values.
+    ///
+    /// If a key appears more than once, the last value is used.
+    pub fn update_batch(&mut self, updates: &[(SparseKey, Fq)]) {
+        let mut dirty = BTreeSet::new();
+        for (key, value) in updates {
+            self.set_node(0, *key, *value);
+            dirty.insert(*key);
+        }
+
+        for height in 0..SPARSE_DEPTH {
+            let parents: BTreeSet<SparseKey> =
+                dirty.iter().map(|key| prefix(key, height + 1)).collect();
+            for parent in &parents {
+                let left = self.node(height, parent);
+                let right = self.node(height, &with_bit(parent, height));
+                self.set_node(height + 1, *parent, compress_2(left, right));
+            }
+            dirty = parents;
+        }
+    }
+
+    /// Generate a proof of the value of the leaf at `key`, which is a proof of
+    /// non-membership if the leaf is empty.
+    pub fn proof(&self, key: &SparseKey) -> SparseMerkleProof {
+        let mut non_empty = [0u8; 32];
+        let mut siblings = Vec::new();
+        for height in 0..SPARSE_DEPTH {
+            let sibling = flip_bit(&prefix(key, height), height);
+            if let Some(node) = self.nodes.get(&(height, sibling)) {
+                non_empty[height / 8] |= 1 << (height % 8);
+                siblings.push(*node);
+            }
+        }
+        SparseMerkleProof {
+            key: *key,
+            non_empty,
+            siblings,
+        }
+    }
+
+    /// The node at `height` whose leftmost leaf is at `key`.
+    fn node(&self, height: usize, key: &SparseKey) -> Fq {
+        self.nodes
+            .get(&(height, *key))
+            .copied()
+            .unwrap_or(self.empty[height])
+    }
+
+    fn set_node(&mut self, height: usize, key: SparseKey, node: Fq) {
+        if node == self.empty[height] {
+            self.nodes.remove(&(height, key));
+        } else {
+            self.nodes.insert((height, key), node);
+        }
+    }
+}
+
+/// The empty nodes of all heights of a [`SparseTree`].
+///
+/// With the `std` feature they are computed once and shared, so that verifying a
+/// [`SparseMerkleProof`] does not first take [`SPARSE_DEPTH`] hashes.
+#[cfg(feature = "std")]
+fn sparse_empty_nodes() -> &'static [Fq] {
+    static EMPTY: std::sync::OnceLock<Vec<Fq>> = std::sync::OnceLock::new();
+    EMPTY.get_or_init(|| empty_nodes(SPARSE_DEPTH))
+}
+
+/// The empty nodes of all heights of a [`SparseTree`].
+#[cfg(not(feature = "std"))]
+fn sparse_empty_nodes() -> Vec<Fq> {
+    empty_nodes(SPARSE_DEPTH)
+}
+
+/// A proof of the value of a leaf of a [`SparseTree`].
+///
+/// Only the siblings that are not empty nodes are included, in order of increasing
+/// height, and the bits of `non_empty` indicate at which heights they are.
+///
+/// The wire encoding of a proof, produced by [`SparseMerkleProof::to_bytes`], is
+/// the key, then `non_empty`, then the 32-byte encodings of the siblings. With the
+/// `serde` feature, proofs are serialized as the bytes of this encoding.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseMerkleProof {
+    /// The key of the leaf.
+    pub key: SparseKey,
+    /// Bit `h` is set if the sibling at height `h` is included in `siblings`.
+    pub non_empty: [u8; 32],
+    /// The siblings that are not empty nodes.
+    pub siblings: Vec<Fq>,
+}
+
+impl SparseMerkleProof {
+    /// Compute the root of the tree with `leaf` at the key of this proof, or `None`
+    /// if the number of siblings does not match `non_empty`.
+    pub fn root(&self, leaf: Fq) -> Option<Fq> {
+        let empty = sparse_empty_nodes();
+        let mut siblings = self.siblings.iter();
+        let mut node = leaf;
+        for (height, empty_node) in empty.iter().enumerate().take(SPARSE_DEPTH) {
+            let sibling = if bit(&self.non_empty, height) {
+                *siblings.next()?
+            } else {
+                *empty_node
+            };
+            node = if bit(&self.key, height) {
+                compress_2(sibling, node)
+            } else {
+                compress_2(node, sibling)
+            };
+        }
+        siblings.next().is_none().then_some(node)
+    }
+
+    /// Check that the leaf at the key of this proof in the tree with `root` is
+    /// `value`, or is empty if `value` is `None`.
+    pub fn verify(&self, root: &Fq, value: Option<Fq>) -> bool {
+        self.root(value.unwrap_or(EMPTY_LEAF)) == Some(*root)
+    }
+
+    /// The wire encoding of this proof.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(64 + 32 * self.siblings.len());
+        bytes.extend_from_slice(&self.key);
+        bytes.extend_from_slice(&self.non_empty);
+        for sibling in &self.siblings {
+            bytes.extend_from_slice(&sibling.to_bytes());
+        }
+        bytes
+    }
+
+    /// Decode a proof from its wire encoding.
+    ///
+    /// This fails if the encoding is malformed, if a sibling is not a canonical
+    /// encoding of an [`Fq`] element, or if the number of siblings does not match
+    /// `non_empty`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, MerkleError> {
+        if bytes.len() < 64 || !(bytes.len() - 64).is_multiple_of(32) {
+            return Err(MerkleError::InvalidEncoding);
+        }
+        let key: SparseKey = bytes[..32].try_into().expect("key is 32 bytes");
+        let non_empty: [u8; 32] = bytes[32..64].try_into().expect("bitmap is 32 bytes");
+        let siblings = bytes[64..]
+            .chunks(32)
+            .map(|chunk| Fq::from_bytes_checked(chunk.try_into().expect("chunk is 32 bytes")))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| MerkleError::InvalidEncoding)?;
+
+        let count: u32 = non_empty.iter().map(|byte| byte.count_ones()).sum();
+        if siblings.len() != count as usize {
+            return Err(MerkleError::InvalidEncoding);
+        }
+        Ok(Self {
+            key,
+            non_empty,
+            siblings,
+        })
+    }
+}
+
+fn bit(key: &SparseKey, height: usize) -> bool {
+    (key[height / 8] >> (height % 8)) & 1 == 1
+}
+
+fn with_bit(key: &SparseKey, height: usize) -> SparseKey {
+    let mut key = *key;
+    key[height / 8] |= 1 << (height % 8);
+    key
+}
+
+fn flip_bit(key: &SparseKey, height: usize) -> SparseKey {
+    let mut key = *key;
+    key[height / 8] ^= 1 << (height % 8);
+    key
+}
+
+/// The key of the leftmost leaf below the node at `height` on the path to `key`.
+fn prefix(key: &SparseKey, height: usize) -> SparseKey {
+    let mut key = *key;
+    for (i, byte) in key.iter_mut().enumerate() {
+        let low_bits = height.saturating_sub(8 * i).min(8);
+        *byte &= !((1u16 << low_bits) - 1) as u8;
+    }
+    key
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::merkle::empty_node;
+
+    fn key(n: u8) -> SparseKey {
+        let mut key = [0u8; 32];
+        key[0] = n;
+        key[31] = n.rotate_left(4);
+        key
+    }
+
+    #[test]
+    fn sparse_tree_updates() {
+        assert_eq!(sparse_empty_nodes().len(), SPARSE_DEPTH + 1);
+        assert_eq!(sparse_empty_nodes()[17], empty_node(17));
+
+        let mut tree = SparseTree::new();
+        assert_eq!(tree.root(), empty_node(SPARSE_DEPTH));
+
+        let updates: Vec<(SparseKey, Fq)> =
+            (1..20u8).map(|n| (key(n), Fq::from(n as u64))).collect();
+        for (key, value) in &updates {
+            tree.insert(*key, *value);
+        }
+        let mut batched = SparseTree::new();
+        batched.update_batch(&updates);
+        assert_eq!(batched.root(), tree.root());
+        assert_eq!(tree.get(&key(3)), Some(Fq::from(3u64)));
+        assert_eq!(tree.get(&key(30)), None);
+
+        // Removing every leaf gives back the empty tree, with no stored nodes.
+        for (key, _) in &updates {
+            tree.remove(key);
+        }
+        assert_eq!(tree.root(), empty_node(SPARSE_DEPTH));
+        assert!(tree.nodes.is_empty());
+    }
+
+    #[test]
+    fn sparse_tree_proofs() {
+        let mut tree = SparseTree::new();
+        tree.update_batch(&[
+            (key(1), Fq::from(1u64)),
+            (key(2), Fq::from(2u64)),
+            ([0xff; 32], Fq::from(3u64)),
+        ]);
+        let root = tree.root();
+
+        let proof = tree.proof(&key(1));
+        assert!(proof.verify(&root, Some(Fq::from(1u64))));
+        assert!(!proof.verify(&root, Some(Fq::from(2u64))));
+        assert!(!proof.verify(&root, None));
+
+        let proof = tree.proof(&[0xff; 32]);
+        assert!(proof.verify(&root, Some(Fq::from(3u64))));
+
+        // Non-membership.
+        let proof = tree.proof(&key(5));
+        assert!(proof.verify(&root, None));
+        assert!(!proof.verify(&root, Some(Fq::from(5u64))));
+
+        // Proofs with missing or extra siblings are rejected.
+        let mut truncated = tree.proof(&key(1));
+        truncated.siblings.pop();
+        assert_eq!(truncated.root(Fq::from(1u64)), None);
+        let mut extended = tree.proof(&key(1));
+        extended.siblings.push(Fq::from(0u64));
+        assert!(!extended.verify(&root, Some(Fq::from(1u64))));
+    }
+
+    #[test]
+    fn sparse_proof_encoding() {
+        let mut tree = SparseTree::new();
+        tree.update_batch(&[(key(1), Fq::from(1u64)), (key(2), Fq::from(2u64))]);
+        let proof = tree.proof(&key(1));
+        let bytes = proof.to_bytes();
+        assert_eq!(bytes.len(), 64 + 32 * proof.siblings.len());
+        assert_eq!(SparseMerkleProof::from_bytes(&bytes).unwrap(), proof);
+
+        // The number of siblings must match the bitmap.
+        assert_eq!(
+            SparseMerkleProof::from_bytes(&bytes[..bytes.len() - 32]).unwrap_err(),
+            MerkleError::InvalidEncoding
+        );
+    }
+}
@@ -0,0 +1,288 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+use core::convert::Infallible;
+
+use super::{empty_nodes, MerkleProof, MAX_DEPTH};
+use crate::{
+    compress_2,
+    error::{MerkleError, StoreError},
+    Fq,
+};
+
+/// The position of a node in a binary Merkle tree, as described in the
+/// [module documentation](super).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    /// The height of the node, `0` for leaves.
+    pub height: u8,
+    /// The index of the node among the nodes of its height, from the left.
+    pub index: u64,
+}
+
+impl Position {
+    /// An encoding of the position as a key for byte-oriented databases: the height
+    /// followed by the index as 8 big-endian bytes, so that keys sort like positions.
+    pub fn to_bytes(&self) -> [u8; 9] {
+        let mut bytes = [0u8; 9];
+        bytes[0] = self.height;
+        bytes[1..].copy_from_slice(&self.index.to_be_bytes());
+        bytes
+    }
+}
+
+/// Storage for the nodes of a [`StoredTree`].
+///
+/// A store only needs to hold the nodes that were put into it: nodes that were
+/// never put are read as `None` and treated as empty. Implementations can keep the
+/// nodes in memory, like [`MemoryStore`], or in a database, so that the tree does not
+/// need to fit in memory.
+pub trait NodeStore {
+    /// The error returned by the store.
+    type Error;
+
+    /// The node at `position`, or `None` if it was never put into the store.
+    fn get(&self, position: Position) -> Result<Option<Fq>, Self::Error>;
+
+    /// Store `node` at `position`, replacing any previous node.
+    fn put(&mut self, position: Position, node: Fq) -> Result<(), Self::Error>;
+}
+
+/// A [`NodeStore`] keeping the nodes in memory.
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStore {
+    nodes: BTreeMap<Position, Fq>,
+}
+
+impl MemoryStore {
+    /// Create an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of nodes in the store.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Whether the store holds no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+impl NodeStore for MemoryStore {
+    type Error = Infallible;
+
+    fn get(&self, position: Position) -> Result<Option<Fq>, Infallible> {
+        Ok(self.nodes.get(&position).copied())
+    }
+
+    fn put(&mut self, position: Position, node: Fq) -> Result<(), Infallible> {
+        self.nodes.insert(position, node);
+        Ok(())
+    }
+}
+
+/// A fixed-depth binary Merkle tree with its nodes in a [`NodeStore`].
+///
+/// The tree has the same nodes and proofs as a [`Tree`](super::Tree) with the same
+/// leaves, but only reads and writes the nodes on the paths it needs, so its size
+/// is limited by the store rather than by memory.
+#[derive(Clone, Debug)]
+pub struct StoredTree<S> {
+    depth: usize,
+    store: S,
+    /// The empty node of each height.
+    empty: Vec<Fq>,
+}
+
+impl<S: NodeStore> StoredTree<S> {
+    /// Open a tree of the given `depth` with its nodes in `store`.
+    ///
+    /// The store must be empty, or hold the nodes of a tree of the same depth.
+    pub fn new(depth: usize, store: S) -> Result<Self, MerkleError> {
+        if depth > MAX_DEPTH {
+            return Err(MerkleError::InvalidDepth);
+        }
+        Ok(Self {
+            depth,
+            store,
+            empty: empty_nodes(depth),
+        })
+    }
+
+    /// The depth of the tree.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The store holding the nodes of the tree.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Close the tree and return its store.
+    pub fn into_store(self) -> S {
+        self.store
+    }
+
+    /// The root of the tree.
+    pub fn root(&self) -> Result<Fq, S::Error> {
+        self.node(self.depth, 0)
+    }
+
+    /// The leaf at `index`.
+    pub fn leaf(&self, index: u64) -> Result<Fq, StoreError<S::Error>> {
+        self.check_index(index)?;
+        self.node(0, index).map_err(StoreError::Store)
+    }
+
+    /// Set the leaves at the indices of `leaves` to the corresponding values, and
+    /// return the new root.
+    ///
+    /// Each internal node above the updated leaves is recomputed and put into the
+    /// store once. If an index appears more than once, the last value is used. If
+    /// any index is out of range, the tree is left unchanged, but if the store fails,
+    /// some of the updates may already have been written to it.
+    pub fn update_batch(&mut self, leaves: &[(u64, Fq)]) -> Result<Fq, StoreError<S::Error>> {
+        for (index, _) in leaves {
+            self.check_index(*index)?;
+        }
+
+        let mut dirty = Vec::with_capacity(leaves.len());
+        for (index, leaf) in leaves {
+            self.put(0, *index, *leaf)?;
+            dirty.push(*index);
+        }
+        dirty.sort_unstable();
+        dirty.dedup();
+
+        for height in 0..self.depth {
+            dirty = dirty.iter().map(|index| index >> 1).collect();
+            dirty.dedup();
+            for parent in &dirty {
+                let left = self.node(height, parent << 1).map_err(StoreError::Store)?;
+                let right = self
+                    .node(height, (parent << 1) | 1)
+                    .map_err(StoreError::Store)?;
+                self.put(height + 1, *parent, compress_2(left, right))?;
+            }
+        }
+
+        self.root().map_err(StoreError::Store)
+    }
+
+    /// Generate a proof that the leaf at `index` is in the tree.
+    pub fn proof(&self, index: u64) -> Result<MerkleProof, StoreError<S::Error>> {
+        self.check_index(index)?;
+        let siblings = (0..self.depth)
+            .map(|height| self.node(height, (index >> height) ^ 1))
+            .collect::<Result<_, _>>()
+            .map_err(StoreError::Store)?;
+        Ok(MerkleProof { index, siblings })
+    }
+
+    fn check_index(&self, index: u64) -> Result<(), MerkleError> {
+        if self.depth >= MAX_DEPTH || index >> self.depth == 0 {
+            Ok(())
+        } else {
+            Err(MerkleError::IndexOutOfRange)
+        }
+    }
+
+    /// The node at `height` and `index`, which is empty if it is not in the store.
+    fn node(&self, height: usize, index: u64) -> Result<Fq, S::Error> {
+        let position = Position {
+            height: height as u8,
+            index,
+        };
+        Ok(self.store.get(position)?.unwrap_or(self.empty[height]))
+    }
+
+    fn put(&mut self, height: usize, index: u64, node: Fq) -> Result<(), StoreError<S::Error>> {
+        let position = Position {
+            height: height as u8,
+            index,
+        };
+        self.store.put(position, node).map_err(StoreError::Store)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::merkle::{Tree, EMPTY_LEAF};
+
+    /// A store that fails on reads of one position.
+    struct FailingStore(MemoryStore, Position);
+
+    impl NodeStore for FailingStore {
+        type Error = &'static str;
+
+        fn get(&self, position: Position) -> Result<Option<Fq>, Self::Error> {
+            if position == self.1 {
+                return Err("unavailable");
+            }
+            Ok(self.0.get(position).unwrap())
+        }
+
+        fn put(&mut self, position: Position, node: Fq) -> Result<(), Self::Error> {
+            self.0.put(position, node).unwrap();
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn stored_tree_matches_tree() {
+        let leaves: Vec<Fq> = (1..=11u64).map(Fq::from).collect();
+        let tree = Tree::from_leaves(5, &leaves).unwrap();
+
+        let mut stored = StoredTree::new(5, MemoryStore::new()).unwrap();
+        assert_eq!(stored.root().unwrap(), Tree::new(5).unwrap().root());
+        let updates: Vec<(u64, Fq)> = (0..).zip(leaves.iter().copied()).collect();
+        let root = stored.update_batch(&updates).unwrap();
+        assert_eq!(root, tree.root());
+
+        for index in [0, 4, 10, 11, 31] {
+            assert_eq!(stored.leaf(index).unwrap(), tree.leaf(index).unwrap());
+            assert_eq!(stored.proof(index).unwrap(), tree.proof(index).unwrap());
+        }
+        assert_eq!(
+            stored.proof(32).unwrap_err(),
+            StoreError::Merkle(MerkleError::IndexOutOfRange)
+        );
+
+        // Reopening the store gives back the same tree.
+        let reopened = StoredTree::new(5, stored.into_store()).unwrap();
+        assert_eq!(reopened.root().unwrap(), root);
+        assert_eq!(reopened.leaf(31).unwrap(), EMPTY_LEAF);
+    }
+
+    #[test]
+    fn stored_tree_store_errors() {
+        let position = Position {
+            height: 0,
+            index: 3,
+        };
+        let mut stored = StoredTree::new(3, FailingStore(MemoryStore::new(), position)).unwrap();
+        assert_eq!(
+            stored.update_batch(&[(2, Fq::from(1u64))]).unwrap_err(),
+            StoreError::Store("unavailable")
+        );
+        assert!(stored.proof(2).is_err());
+        assert!(stored.proof(4).is_ok());
+    }
+
+    #[test]
+    fn position_keys_sort_like_positions() {
+        let a = Position {
+            height: 0,
+            index: u64::MAX,
+        };
+        let b = Position {
+            height: 1,
+            index: 0,
+        };
+        assert!(a < b);
+        assert!(a.to_bytes() < b.to_bytes());
+    }
+}
@@ -0,0 +1,223 @@
+//! Hashing conventions of Penumbra's tiered commitment tree.
+//!
+//! The tiered commitment tree is a quaternary tree of height [`HEIGHT`], made of
+//! three tiers of height [`TIER_HEIGHT`]: the top tier holds the epochs, the middle
+//! tier holds the blocks of an epoch, and the bottom tier holds the commitments of a
+//! block. A position in the tree packs the three indices in 16 bits each, as built
+//! by [`position`].
+//!
+//! A commitment is hashed into a leaf with [`hash_leaf`], and internal nodes are
+//! hashed with [`hash_node`], whose domain separator depends on the height of the
+//! node. All domain separation derives from [`DOMAIN_SEPARATOR`]. Children of a node
+//! that have no commitments below them are hashed as [`EMPTY`], except for blocks
+//! and epochs that were finalized without any commitments, whose roots are
+//! [`FINALIZED_EMPTY`].
+//!
+//! The roots of the three tiers are computed with [`block_root`], [`epoch_root`] and
+//! [`root`], each from the roots of the tier below, and [`root_of_commitments`]
+//! computes the root of a whole tree from its commitments.
+//!
+//! Unlike the other trees of this module, this tree is not built with
+//! [`compress_2`](crate::compress_2), so it is only meant for computing roots and
+//! nodes that match those of Penumbra.
+
+use alloc::collections::BTreeMap;
+
+use crate::{error::MerkleError, hash_1, hash_4, Fq};
+
+/// The domain separator of the tree, `BLAKE2b-512(b"penumbra.tct")` reduced with
+/// `from_le_bytes_mod_order`.
+pub const DOMAIN_SEPARATOR: Fq = Fq::from_montgomery_limbs([
+    7616116016068517846,
+    17221394395931803839,
+    663290215102590298,
+    1129953258897505427,
+]);
+
+/// The height of each of the three tiers.
+pub const TIER_HEIGHT: u8 = 8;
+
+/// The height of the tree, from the leaves to the root.
+pub const HEIGHT: u8 = 3 * TIER_HEIGHT;
+
+/// The hash of a node with no commitments below it, `0`.
+pub const EMPTY: Fq = Fq::from_montgomery_limbs([0, 0, 0, 0]);
+
+/// The root of a block or epoch that was finalized without any commitments, `1`.
+pub const FINALIZED_EMPTY: Fq = Fq::from_montgomery_limbs([
+    9015221291577245683,
+    8239323489949974514,
+    1646089257421115374,
+    958099254763297437,
+]);
+
+/// Hash a commitment into a leaf of the tree.
+pub fn hash_leaf(commitment: Fq) -> Fq {
+    hash_1(&DOMAIN_SEPARATOR, commitment)
+}
+
+/// Hash the four children of the node at `height`, which is between `1` and
+/// [`HEIGHT`], with the domain separator `DOMAIN_SEPARATOR + height`.
+pub fn hash_node(height: u8, children: [Fq; 4]) -> Fq {
+    let domain_separator = DOMAIN_SEPARATOR + Fq::from(height as u64);
+    let [a, b, c, d] = children;
+    hash_4(&domain_separator, (a, b, c, d))
+}
+
+/// The position of the commitment at index `commitment` of block `block` of epoch
+/// `epoch`.
+pub fn position(epoch: u16, block: u16, commitment: u16) -> u64 {
+    ((epoch as u64) << 32) | ((block as u64) << 16) | commitment as u64
+}
+
+/// The root of a block with the `commitments` at the given indices, or
+/// [`FINALIZED_EMPTY`] if there are none.
+///
+/// If an index appears more than once, the last commitment is used.
+pub fn block_root(commitments: &[(u16, Fq)]) -> Fq {
+    let leaves = commitments
+        .iter()
+        .map(|(index, commitment)| (*index as u64, hash_leaf(*commitment)));
+    tier_root(0, leaves).unwrap_or(FINALIZED_EMPTY)
+}
+
+/// The root of an epoch with the blocks of the given `block_roots` at the given
+/// indices, or [`FINALIZED_EMPTY`] if there are none.
+///
+/// The roots of empty blocks are [`FINALIZED_EMPTY`], as returned by [`block_root`].
+pub fn epoch_root(block_roots: &[(u16, Fq)]) -> Fq {
+    let leaves = block_roots
+        .iter()
+        .map(|(index, root)| (*index as u64, *root));
+    tier_root(TIER_HEIGHT, leaves).unwrap_or(FINALIZED_EMPTY)
+}
+
+/// The root of a tree with the epochs of the given `epoch_roots` at the given
+/// indices, or [`EMPTY`] if there are none.
+pub fn root(epoch_roots: &[(u16, Fq)]) -> Fq {
+    let leaves = epoch_roots
+        .iter()
+        .map(|(index, root)| (*index as u64, *root));
+    tier_root(2 * TIER_HEIGHT, leaves).unwrap_or(EMPTY)
+}
+
+/// The root of a tree with the `commitments` at the given positions, and no other
+/// commitments.
+///
+/// Only the blocks and epochs that contain one of the `commitments` are part of the
+/// tree, so this is the root of a tree in which no empty block or epoch was
+/// finalized. If a position appears more than once, the last commitment is used.
+/// This fails if a position does not fit in [`HEIGHT`] quaternary levels, that is,
+/// 48 bits.
+pub fn root_of_commitments(commitments: &[(u64, Fq)]) -> Result<Fq, MerkleError> {
+    if commitments
+        .iter()
+        .any(|(position, _)| position >> (2 * HEIGHT) != 0)
+    {
+        return Err(MerkleError::IndexOutOfRange);
+    }
+
+    let leaves = commitments
+        .iter()
+        .map(|(position, commitment)| (*position, hash_leaf(*commitment)));
+    Ok(subtree_root(0, HEIGHT, leaves).unwrap_or(EMPTY))
+}
+
+/// The root of a tier whose leaves, at height `base`, are the given nodes.
+fn tier_root(base: u8, leaves: impl Iterator<Item = (u64, Fq)>) -> Option<Fq> {
+    subtree_root(base, TIER_HEIGHT, leaves)
+}
+
+/// The root of a subtree with `levels` levels whose leaves, at height `base`, are
+/// the given nodes, or `None` if there are no leaves.
+fn subtree_root(base: u8, levels: u8, leaves: impl Iterator<Item = (u64, Fq)>) -> Option<Fq> {
+    let mut layer: BTreeMap<u64, Fq> = leaves.collect();
+    for height in base + 1..=base + levels {
+        let mut parents: BTreeMap<u64, [Fq; 4]> = BTreeMap::new();
+        for (index, node) in layer {
+            let children = parents.entry(index >> 2).or_insert([EMPTY; 4]);
+            children[(index & 3) as usize] = node;
+        }
+        layer = parents
+            .into_iter()
+            .map(|(index, children)| (index, hash_node(height, children)))
+            .collect();
+    }
+
+    layer.into_values().next()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn tct_position_layout() {
+        assert_eq!(position(0, 0, 0), 0);
+        assert_eq!(position(1, 2, 3), (1 << 32) + (2 << 16) + 3);
+        assert_eq!(position(u16::MAX, u16::MAX, u16::MAX), (1 << 48) - 1);
+        assert!(root_of_commitments(&[(1 << 48, Fq::from(1u64))]).is_err());
+    }
+
+    #[test]
+    fn tct_constants() {
+        assert_eq!(EMPTY, Fq::from(0u64));
+        assert_eq!(FINALIZED_EMPTY, Fq::from(1u64));
+        assert_eq!(block_root(&[]), FINALIZED_EMPTY);
+        assert_eq!(epoch_root(&[]), FINALIZED_EMPTY);
+        assert_eq!(root(&[]), EMPTY);
+        assert_eq!(root_of_commitments(&[]).unwrap(), EMPTY);
+    }
+
+    #[test]
+    fn tct_root() {
+        // A single commitment at position 0 is the leftmost child at every height.
+        let commitment = Fq::from(7u64);
+        let expected = (1..=HEIGHT).fold(hash_leaf(commitment), |node, height| {
+            hash_node(height, [node, EMPTY, EMPTY, EMPTY])
+        });
+        assert_eq!(root_of_commitments(&[(0, commitment)]).unwrap(), expected);
+
+        // Commitments in different epochs only meet at the root.
+        let (a, b) = (Fq::from(1u64), Fq::from(2u64));
+        let first = position(0, 0, 0);
+        let last = position(u16::MAX, u16::MAX, u16::MAX);
+        let left = (1..HEIGHT).fold(hash_leaf(a), |node, height| {
+            hash_node(height, [node, EMPTY, EMPTY, EMPTY])
+        });
+        let right = (1..HEIGHT).fold(hash_leaf(b), |node, height| {
+            hash_node(height, [EMPTY, EMPTY, EMPTY, node])
+        });
+        assert_eq!(
+            root_of_commitments(&[(last, b), (first, a)]).unwrap(),
+            hash_node(HEIGHT, [left, EMPTY, EMPTY, right])
+        );
+
+        // Node hashes depend on the height.
+        assert_ne!(hash_node(1, [a; 4]), hash_node(2, [a; 4]));
+    }
+
+    #[test]
+    fn tct_tiers() {
+        let commitments: [(u64, Fq); 3] = [
+            (position(0, 0, 5), Fq::from(1u64)),
+            (position(0, 3, 0), Fq::from(2u64)),
+            (position(2, 1, 9), Fq::from(3u64)),
+        ];
+
+        // Building the tree tier by tier gives the same root as building it at once.
+        let block = block_root(&[(5, Fq::from(1u64))]);
+        let epoch_0 = epoch_root(&[(0, block), (3, block_root(&[(0, Fq::from(2u64))]))]);
+        let epoch_2 = epoch_root(&[(1, block_root(&[(9, Fq::from(3u64))]))]);
+        assert_eq!(
+            root(&[(0, epoch_0), (2, epoch_2)]),
+            root_of_commitments(&commitments).unwrap()
+        );
+
+        // A finalized empty block is part of the epoch, unlike a missing one.
+        assert_ne!(
+            epoch_root(&[(0, block), (1, block_root(&[]))]),
+            epoch_root(&[(0, block)])
+        );
+    }
+}
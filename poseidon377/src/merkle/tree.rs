@@ -0,0 +1,426 @@
+use core::convert::TryFrom;
+
+use alloc::vec::Vec;
+
+use super::{empty_nodes, MerkleProof, MultiProof, MAX_DEPTH};
+use crate::{compress_2, error::MerkleError, Fq};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Minimum number of pairs of children hashed by each parallel task.
+#[cfg(feature = "parallel")]
+const PAR_MIN_PAIRS: usize = 1024;
+
+/// A fixed-depth binary Merkle tree with all of its nodes in memory.
+#[derive(Clone, Debug)]
+pub struct Tree {
+    depth: usize,
+    /// The nodes of each height up to the last non-empty one, from the leaves at
+    /// height `0` to the root at height `depth`.
+    levels: Vec<Vec<Fq>>,
+    /// The empty node of each height.
+    empty: Vec<Fq>,
+}
+
+impl Tree {
+    /// Create a tree of the given `depth` with all leaves empty.
+    pub fn new(depth: usize) -> Result<Self, MerkleError> {
+        Self::from_leaves(depth, &[])
+    }
+
+    /// Create a tree of the given `depth` with `leaves` in the leftmost positions
+    /// and all other leaves empty.
+    pub fn from_leaves(depth: usize, leaves: &[Fq]) -> Result<Self, MerkleError> {
+        Self::build(depth, leaves, |children, empty| {
+            children
+                .chunks(2)
+                .map(|pair| compress_2(pair[0], pair.get(1).copied().unwrap_or(empty)))
+                .collect()
+        })
+    }
+
+    /// Create a tree like [`Tree::from_leaves`], hashing the nodes of each height
+    /// in parallel with rayon.
+    #[cfg(feature = "parallel")]
+    pub fn from_leaves_parallel(depth: usize, leaves: &[Fq]) -> Result<Self, MerkleError> {
+        Self::build(depth, leaves, |children, empty| {
+            children
+                .par_chunks(2)
+                .with_min_len(PAR_MIN_PAIRS)
+                .map(|pair| compress_2(pair[0], pair.get(1).copied().unwrap_or(empty)))
+                .collect()
+        })
+    }
+
+    /// Build the levels of a tree bottom-up, with `parents` computing the nodes of
+    /// a height from their children and the empty node of the children's height.
+    fn build(
+        depth: usize,
+        leaves: &[Fq],
+        parents: impl Fn(&[Fq], Fq) -> Vec<Fq>,
+    ) -> Result<Self, MerkleError> {
+        if depth > MAX_DEPTH {
+            return Err(MerkleError::InvalidDepth);
+        }
+        if leaves.len() as u128 > 1u128 << depth {
+            return Err(MerkleError::TooManyLeaves);
+        }
+
+        let empty = empty_nodes(depth);
+        let mut levels = Vec::with_capacity(depth + 1);
+        levels.push(leaves.to_vec());
+        for height in 0..depth {
+            let next = parents(&levels[height], empty[height]);
+            levels.push(next);
+        }
+
+        Ok(Self {
+            depth,
+            levels,
+            empty,
+        })
+    }
+
+    /// The depth of the tree.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The number of leaves up to the last one that was filled.
+    pub fn len(&self) -> u64 {
+        self.levels[0].len() as u64
+    }
+
+    /// Whether no leaf was filled.
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// The root of the tree.
+    pub fn root(&self) -> Fq {
+        self.node(self.depth, 0)
+    }
+
+    /// The leaf at `index`, or `None` if `index` is not a position of the tree.
+    pub fn leaf(&self, index: u64) -> Option<Fq> {
+        self.in_range(index).then(|| self.node(0, index))
+    }
+
+    /// Set the leaves at the indices of `leaves` to the corresponding values, and
+    /// return the new root.
+    ///
+    /// Each internal node above the updated leaves is recomputed once, however
+    /// many of its leaves were updated. If an index appears more than once, the last
+    /// value is used. Updating a leaf after the last filled one fills the positions
+    /// before it with [`EMPTY_LEAF`](super::EMPTY_LEAF), so each index must be less
+    /// than [`Tree::len`] plus the number of updated leaves: a batch grows the tree
+    /// by at most its own size, and scattered positions should use a
+    /// [`SparseTree`](super::SparseTree) instead. If any index is out of range, the
+    /// tree is left unchanged.
+    pub fn update_batch(&mut self, leaves: &[(u64, Fq)]) -> Result<Fq, MerkleError> {
+        if !leaves.iter().all(|(index, _)| self.in_range(*index)) {
+            return Err(MerkleError::IndexOutOfRange);
+        }
+        let end = self.levels[0].len() as u64 + leaves.len() as u64;
+        if leaves.iter().any(|(index, _)| *index >= end) {
+            return Err(MerkleError::IndexPastEnd);
+        }
+
+        let mut dirty = Vec::with_capacity(leaves.len());
+        for (index, leaf) in leaves {
+            let position = *index as usize;
+            if position >= self.levels[0].len() {
+                self.levels[0].resize(position + 1, self.empty[0]);
+            }
+            self.levels[0][position] = *leaf;
+            dirty.push(*index);
+        }
+        dirty.sort_unstable();
+        dirty.dedup();
+
+        for height in 0..self.depth {
+            let len = self.levels[height].len().div_ceil(2);
+            if self.levels[height + 1].len() < len {
+                self.levels[height + 1].resize(len, self.empty[height + 1]);
+            }
+            dirty = dirty.iter().map(|index| index >> 1).collect();
+            dirty.dedup();
+            for parent in &dirty {
+                let node = compress_2(
+                    self.node(height, parent << 1),
+                    self.node(height, (parent << 1) | 1),
+                );
+                self.levels[height + 1][*parent as usize] = node;
+            }
+        }
+
+        Ok(self.root())
+    }
+
+    /// Generate a proof that the leaf at `index` is in the tree.
+    pub fn proof(&self, index: u64) -> Result<MerkleProof, MerkleError> {
+        if !self.in_range(index) {
+            return Err(MerkleError::IndexOutOfRange);
+        }
+        let siblings = (0..self.depth)
+            .map(|height| self.node(height, (index >> height) ^ 1))
+            .collect();
+        Ok(MerkleProof { index, siblings })
+    }
+
+    /// Generate a proof that the leaves at all of the `indices` are in the tree.
+    ///
+    /// The indices may be given in any order and may repeat; the proof opens them
+    /// in increasing order, without repetitions.
+    pub fn multiproof(&self, indices: &[u64]) -> Result<MultiProof, MerkleError> {
+        if !indices.iter().all(|index| self.in_range(*index)) {
+            return Err(MerkleError::IndexOutOfRange);
+        }
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+
+        // At each height, include the siblings of the known nodes that are not
+        // known themselves, and move up to the parents of the known nodes.
+        let mut nodes = Vec::new();
+        let mut known = indices.clone();
+        for height in 0..self.depth {
+            for (i, index) in known.iter().enumerate() {
+                let sibling = index ^ 1;
+                let sibling_known = if index & 1 == 0 {
+                    known.get(i + 1) == Some(&sibling)
+                } else {
+                    i > 0 && known[i - 1] == sibling
+                };
+                if !sibling_known {
+                    nodes.push(self.node(height, sibling));
+                }
+            }
+            known = known.iter().map(|index| index >> 1).collect();
+            known.dedup();
+        }
+
+        Ok(MultiProof {
+            depth: self.depth,
+            indices,
+            nodes,
+        })
+    }
+
+    fn in_range(&self, index: u64) -> bool {
+        self.depth >= MAX_DEPTH || index >> self.depth == 0
+    }
+
+    /// The node at `height` and `index`, which is empty if it was not stored.
+    fn node(&self, height: usize, index: u64) -> Fq {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.levels[height].get(index))
+            .copied()
+            .unwrap_or(self.empty[height])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::merkle::{empty_node, EMPTY_LEAF};
+
+    fn leaves(n: u64) -> Vec<Fq> {
+        (1..=n).map(Fq::from).collect()
+    }
+
+    #[test]
+    fn tree_root() {
+        let [a, b, c] = [Fq::from(1u64), Fq::from(2u64), Fq::from(3u64)];
+        let tree = Tree::from_leaves(2, &[a, b, c]).unwrap();
+        assert_eq!(
+            tree.root(),
+            compress_2(compress_2(a, b), compress_2(c, EMPTY_LEAF))
+        );
+        assert_eq!(tree.len(), 3);
+
+        assert_eq!(Tree::new(5).unwrap().root(), empty_node(5));
+        assert_eq!(empty_node(1), compress_2(EMPTY_LEAF, EMPTY_LEAF));
+        assert_eq!(Tree::from_leaves(0, &[a]).unwrap().root(), a);
+
+        assert_eq!(
+            Tree::from_leaves(1, &[a, b, c]).unwrap_err(),
+            MerkleError::TooManyLeaves
+        );
+        assert_eq!(
+            Tree::new(MAX_DEPTH + 1).unwrap_err(),
+            MerkleError::InvalidDepth
+        );
+    }
+
+    #[test]
+    fn tree_proofs() {
+        let leaves = leaves(11);
+        let tree = Tree::from_leaves(4, &leaves).unwrap();
+        let root = tree.root();
+
+        for index in 0..16 {
+            let leaf = tree.leaf(index).unwrap();
+            assert_eq!(
+                leaf,
+                leaves.get(index as usize).copied().unwrap_or(EMPTY_LEAF)
+            );
+
+            let proof = tree.proof(index).unwrap();
+            assert_eq!(proof.depth(), 4);
+            assert!(proof.verify(&root, leaf));
+            assert!(!proof.verify(&root, leaf + Fq::from(1u64)));
+
+            let mut wrong_index = proof.clone();
+            wrong_index.index = index + 16;
+            assert!(!wrong_index.verify(&root, leaf));
+        }
+
+        let mut swapped = tree.proof(0).unwrap();
+        swapped.index = 1;
+        assert!(!swapped.verify(&root, leaves[0]));
+
+        assert_eq!(tree.leaf(16), None);
+        assert_eq!(tree.proof(16).unwrap_err(), MerkleError::IndexOutOfRange);
+    }
+
+    #[test]
+    fn proof_encoding() {
+        let tree = Tree::from_leaves(4, &leaves(11)).unwrap();
+        let proof = tree.proof(6).unwrap();
+        let bytes = proof.to_bytes();
+        assert_eq!(bytes.len(), 8 + 4 * 32);
+        assert_eq!(&bytes[..8], &6u64.to_le_bytes());
+        assert_eq!(MerkleProof::from_bytes(&bytes).unwrap(), proof);
+
+        assert_eq!(
+            MerkleProof::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err(),
+            MerkleError::InvalidEncoding
+        );
+        let mut non_canonical = bytes.clone();
+        non_canonical[8..40].copy_from_slice(&[0xff; 32]);
+        assert_eq!(
+            MerkleProof::from_bytes(&non_canonical).unwrap_err(),
+            MerkleError::InvalidEncoding
+        );
+        let mut out_of_range = bytes;
+        out_of_range[..8].copy_from_slice(&16u64.to_le_bytes());
+        assert_eq!(
+            MerkleProof::from_bytes(&out_of_range).unwrap_err(),
+            MerkleError::IndexOutOfRange
+        );
+    }
+
+    #[test]
+    fn tree_multiproofs() {
+        let leaves = leaves(13);
+        let tree = Tree::from_leaves(5, &leaves).unwrap();
+        let root = tree.root();
+
+        let proof = tree.multiproof(&[9, 2, 3, 30, 2]).unwrap();
+        assert_eq!(proof.indices, vec![2, 3, 9, 30]);
+        let opened = [leaves[2], leaves[3], leaves[9], EMPTY_LEAF];
+        assert!(proof.verify(&root, &opened));
+        // Opening 4 leaves separately would take 20 nodes.
+        assert!(proof.nodes.len() < 4 * 5);
+
+        let mut wrong = opened;
+        wrong[2] = leaves[8];
+        assert!(!proof.verify(&root, &wrong));
+        assert!(!proof.verify(&root, &opened[..3]));
+
+        let mut extra = proof.clone();
+        extra.nodes.push(EMPTY_LEAF);
+        assert!(!extra.verify(&root, &opened));
+        let mut unsorted = proof.clone();
+        unsorted.indices.swap(0, 1);
+        assert!(!unsorted.verify(&root, &opened));
+
+        // A multiproof of a single leaf has the nodes of its proof.
+        let single = tree.multiproof(&[6]).unwrap();
+        assert_eq!(single.nodes, tree.proof(6).unwrap().siblings);
+
+        // Opening every leaf takes no nodes.
+        let all: Vec<u64> = (0..32).collect();
+        assert!(tree.multiproof(&all).unwrap().nodes.is_empty());
+
+        assert_eq!(
+            tree.multiproof(&[1, 32]).unwrap_err(),
+            MerkleError::IndexOutOfRange
+        );
+    }
+
+    #[test]
+    fn tree_update_batch() {
+        let mut leaves = leaves(10);
+        let mut tree = Tree::from_leaves(5, &leaves).unwrap();
+
+        let updates = [
+            (3, Fq::from(100u64)),
+            (2, Fq::from(101u64)),
+            (3, Fq::from(102u64)),
+            (13, Fq::from(103u64)),
+        ];
+        let root = tree.update_batch(&updates).unwrap();
+        leaves[2] = Fq::from(101u64);
+        leaves[3] = Fq::from(102u64);
+        leaves.resize(14, EMPTY_LEAF);
+        leaves[13] = Fq::from(103u64);
+        let expected = Tree::from_leaves(5, &leaves).unwrap();
+        assert_eq!(root, expected.root());
+        assert_eq!(tree.len(), 14);
+        assert!(tree.proof(13).unwrap().verify(&root, Fq::from(103u64)));
+        assert_eq!(tree.update_batch(&[]).unwrap(), root);
+
+        assert_eq!(
+            tree.update_batch(&[(0, EMPTY_LEAF), (32, EMPTY_LEAF)])
+                .unwrap_err(),
+            MerkleError::IndexOutOfRange
+        );
+        // A batch of two leaves can grow the tree by at most two leaves.
+        assert_eq!(
+            tree.update_batch(&[(0, EMPTY_LEAF), (16, EMPTY_LEAF)])
+                .unwrap_err(),
+            MerkleError::IndexPastEnd
+        );
+        assert_eq!(tree.root(), root);
+        assert_eq!(tree.len(), 14);
+
+        // The bound does not depend on the depth of the tree.
+        let mut deep = Tree::new(MAX_DEPTH).unwrap();
+        assert_eq!(
+            deep.update_batch(&[(u64::MAX, EMPTY_LEAF)]).unwrap_err(),
+            MerkleError::IndexPastEnd
+        );
+        assert!(deep.is_empty());
+        deep.update_batch(&[(1, Fq::from(1u64)), (0, Fq::from(2u64))])
+            .unwrap();
+        assert_eq!(deep.len(), 2);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_tree() {
+        for n in [0, 1, 2, 3, 2500, 4096] {
+            let leaves = leaves(n);
+            let tree = Tree::from_leaves(12, &leaves).unwrap();
+            let parallel = Tree::from_leaves_parallel(12, &leaves).unwrap();
+            assert_eq!(parallel.root(), tree.root());
+            assert_eq!(parallel.levels, tree.levels);
+        }
+        assert_eq!(
+            Tree::from_leaves_parallel(1, &leaves(3)).unwrap_err(),
+            MerkleError::TooManyLeaves
+        );
+    }
+
+    #[test]
+    fn deep_tree() {
+        let tree = Tree::from_leaves(MAX_DEPTH, &leaves(3)).unwrap();
+        let proof = tree.proof(u64::MAX).unwrap();
+        assert!(proof.verify(&tree.root(), EMPTY_LEAF));
+        assert!(tree.proof(2).unwrap().verify(&tree.root(), Fq::from(3u64)));
+    }
+}
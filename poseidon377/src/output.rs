@@ -0,0 +1,135 @@
+use core::convert::TryFrom;
+
+use crate::{error::OutputError, Fq};
+
+/// Length in bytes of the canonical encoding of a [`HashOutput`].
+const OUTPUT_BYTES: usize = 32;
+
+/// The output of a hash function, with a canonical serialization.
+///
+/// The byte encoding is the canonical 32-byte little-endian encoding of the
+/// [`Fq`] element, and the hex encoding is the lowercase hex of those bytes.
+/// Decoding rejects encodings of integers not less than the modulus, so every
+/// output has exactly one valid encoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HashOutput(pub Fq);
+
+impl HashOutput {
+    /// Encode the output as 32 little-endian bytes.
+    pub fn to_bytes(&self) -> [u8; OUTPUT_BYTES] {
+        self.0.to_bytes()
+    }
+
+    /// Decode an output from its canonical 32-byte little-endian encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, OutputError> {
+        let bytes =
+            <&[u8; OUTPUT_BYTES]>::try_from(bytes).map_err(|_| OutputError::InvalidLength)?;
+        Fq::from_bytes_checked(bytes)
+            .map(Self)
+            .map_err(|_| OutputError::NonCanonical)
+    }
+
+    /// Encode the output as 64 lowercase hex digits.
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> alloc::string::String {
+        use alloc::string::ToString;
+        self.to_string()
+    }
+
+    /// Decode an output from the hex encoding of its canonical byte encoding.
+    ///
+    /// Both lowercase and uppercase hex digits are accepted.
+    pub fn from_hex(hex: &str) -> Result<Self, OutputError> {
+        let hex = hex.as_bytes();
+        if hex.len() != 2 * OUTPUT_BYTES {
+            return Err(OutputError::InvalidLength);
+        }
+
+        let mut bytes = [0u8; OUTPUT_BYTES];
+        for (byte, digits) in bytes.iter_mut().zip(hex.chunks(2)) {
+            *byte = (hex_digit(digits[0])? << 4) | hex_digit(digits[1])?;
+        }
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// Decode a single hex digit.
+fn hex_digit(digit: u8) -> Result<u8, OutputError> {
+    match digit {
+        b'0'..=b'9' => Ok(digit - b'0'),
+        b'a'..=b'f' => Ok(digit - b'a' + 10),
+        b'A'..=b'F' => Ok(digit - b'A' + 10),
+        _ => Err(OutputError::InvalidHex),
+    }
+}
+
+impl core::fmt::Display for HashOutput {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for byte in self.to_bytes() {
+            write!(f, "{:02x}", byte)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Fq> for HashOutput {
+    fn from(output: Fq) -> Self {
+        Self(output)
+    }
+}
+
+impl From<HashOutput> for Fq {
+    fn from(output: HashOutput) -> Self {
+        output.0
+    }
+}
+
+impl TryFrom<&[u8]> for HashOutput {
+    type Error = OutputError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(bytes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hash_1;
+
+    #[test]
+    fn output_round_trip() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let output = HashOutput(hash_1(&domain_sep, Fq::from(1u64)));
+
+        assert_eq!(HashOutput::from_bytes(&output.to_bytes()), Ok(output));
+        assert_eq!(HashOutput::from_hex(&output.to_hex()), Ok(output));
+        assert_eq!(
+            HashOutput::from_hex(&output.to_hex().to_uppercase()),
+            Ok(output)
+        );
+
+        let one = HashOutput(Fq::from(1u64));
+        assert_eq!(
+            one.to_hex(),
+            "0100000000000000000000000000000000000000000000000000000000000000"
+        );
+    }
+
+    #[test]
+    fn output_rejects_invalid_encodings() {
+        assert_eq!(
+            HashOutput::from_bytes(&[0u8; 31]),
+            Err(OutputError::InvalidLength)
+        );
+        assert_eq!(
+            HashOutput::from_bytes(&[0xff; 32]),
+            Err(OutputError::NonCanonical)
+        );
+        assert_eq!(
+            HashOutput::from_hex(&"g".repeat(64)),
+            Err(OutputError::InvalidHex)
+        );
+        assert_eq!(HashOutput::from_hex("00"), Err(OutputError::InvalidLength));
+    }
+}
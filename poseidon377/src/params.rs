@@ -1,7 +1,35 @@
+pub mod inverse;
+#[cfg(feature = "rate-1")]
 pub mod rate_1;
+#[cfg(feature = "rate-10")]
+pub mod rate_10;
+#[cfg(feature = "rate-11")]
+pub mod rate_11;
+#[cfg(feature = "rate-12")]
+pub mod rate_12;
+#[cfg(feature = "rate-13")]
+pub mod rate_13;
+#[cfg(feature = "rate-14")]
+pub mod rate_14;
+#[cfg(feature = "rate-15")]
+pub mod rate_15;
+#[cfg(feature = "rate-16")]
+pub mod rate_16;
+#[cfg(feature = "rate-2")]
 pub mod rate_2;
+#[cfg(feature = "rate-3")]
 pub mod rate_3;
+#[cfg(feature = "rate-4")]
 pub mod rate_4;
+#[cfg(feature = "rate-5")]
 pub mod rate_5;
+#[cfg(feature = "rate-6")]
 pub mod rate_6;
+#[cfg(feature = "rate-7")]
 pub mod rate_7;
+#[cfg(feature = "rate-8")]
+pub mod rate_8;
+#[cfg(feature = "rate-9")]
+pub mod rate_9;
+pub mod security_256;
+pub mod v2;
@@ -0,0 +1,7 @@
+pub mod rate_1;
+pub mod rate_2;
+pub mod rate_3;
+pub mod rate_4;
+pub mod rate_5;
+pub mod rate_6;
+pub mod rate_7;
@@ -0,0 +1,2371 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-1 instance of Poseidon with the inverse S-box.
+pub const fn rate_1() -> PoseidonParameters<2, 1, 4, 1, 73, 2, 146, 65> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<73, 2, 146>::new_from_known([
+            Fq::from_montgomery_limbs([
+                15895198993032053758,
+                18317054528392995046,
+                12975016329636154523,
+                696045811561133802,
+            ]),
+            Fq::from_montgomery_limbs([
+                11135012365599855672,
+                10551472703341626006,
+                285980845476995630,
+                595003651531845589,
+            ]),
+            Fq::from_montgomery_limbs([
+                1174435058757113795,
+                2961864513805472778,
+                16211937462366153364,
+                104329296732648590,
+            ]),
+            Fq::from_montgomery_limbs([
+                13525869041374665802,
+                16525584479940046476,
+                14384136125391138316,
+                278489699627995674,
+            ]),
+            Fq::from_montgomery_limbs([
+                3564539694149100697,
+                11078399881667186538,
+                9995880636525172652,
+                621378143901863629,
+            ]),
+            Fq::from_montgomery_limbs([
+                3056314683085331160,
+                1353556414399135814,
+                18227561755170834106,
+                1226886419734040220,
+            ]),
+            Fq::from_montgomery_limbs([
+                11220837229117663257,
+                13435541797260317085,
+                9906219101556157301,
+                70984442825414393,
+            ]),
+            Fq::from_montgomery_limbs([
+                991223349407100211,
+                7050505429585965683,
+                8639646179660484025,
+                259359391759383242,
+            ]),
+            Fq::from_montgomery_limbs([
+                2878186150483553074,
+                15825497884624167784,
+                9163641916896141683,
+                65285287503919507,
+            ]),
+            Fq::from_montgomery_limbs([
+                2826669660106967834,
+                15125612349989755534,
+                9746455327342653159,
+                449619281313181890,
+            ]),
+            Fq::from_montgomery_limbs([
+                10797266061820615103,
+                7170031438783283356,
+                18284009244093729066,
+                387618957983569049,
+            ]),
+            Fq::from_montgomery_limbs([
+                9933809014223820337,
+                18069026934536442007,
+                9989197503183678225,
+                309600668941988396,
+            ]),
+            Fq::from_montgomery_limbs([
+                9293620351185379963,
+                18126336536235195450,
+                17930442450817789103,
+                631211256884132204,
+            ]),
+            Fq::from_montgomery_limbs([
+                11699005004866165346,
+                1233805636591405338,
+                15432193067380096868,
+                586584223368172881,
+            ]),
+            Fq::from_montgomery_limbs([
+                6937176535841809435,
+                9351973987345096217,
+                3203845437364490854,
+                937614570111659335,
+            ]),
+            Fq::from_montgomery_limbs([
+                10067912548527930715,
+                16305648330032029881,
+                13200470202471893908,
+                415765923337125194,
+            ]),
+            Fq::from_montgomery_limbs([
+                2916041218713324743,
+                13148303190932214384,
+                11922562327890158984,
+                155608950245422349,
+            ]),
+            Fq::from_montgomery_limbs([
+                3783608292650264194,
+                11225543265629811457,
+                16951490429586874452,
+                1081811944216207334,
+            ]),
+            Fq::from_montgomery_limbs([
+                4282420042679097317,
+                8297647152523460576,
+                10551748867243336239,
+                1257537410256590061,
+            ]),
+            Fq::from_montgomery_limbs([
+                10027646448552131316,
+                3422600677969841217,
+                1649162853624420554,
+                838463334654429801,
+            ]),
+            Fq::from_montgomery_limbs([
+                9103064384618027402,
+                363522907510260767,
+                4902387439877938734,
+                807921668486897161,
+            ]),
+            Fq::from_montgomery_limbs([
+                12765944930975391951,
+                12604249609536659712,
+                6500918541595764190,
+                984198166211304411,
+            ]),
+            Fq::from_montgomery_limbs([
+                18163793908993212371,
+                9740401607609380593,
+                12885561857001107539,
+                582744037571569996,
+            ]),
+            Fq::from_montgomery_limbs([
+                4181917387286862708,
+                3878257347735396227,
+                4204034562310784445,
+                1316167883532450602,
+            ]),
+            Fq::from_montgomery_limbs([
+                9794470432459752691,
+                12275335729818936325,
+                17051167136897024187,
+                561637507456674693,
+            ]),
+            Fq::from_montgomery_limbs([
+                13187702714911101321,
+                3546933310085037951,
+                13581207071779820815,
+                388251497974961847,
+            ]),
+            Fq::from_montgomery_limbs([
+                15246131971723748603,
+                13182865565220859400,
+                3851691956782951771,
+                975242749606395900,
+            ]),
+            Fq::from_montgomery_limbs([
+                10462229723571491338,
+                15351646826731449456,
+                4047573121629636407,
+                1145519076780659590,
+            ]),
+            Fq::from_montgomery_limbs([
+                14708805676887903584,
+                640149578884119974,
+                2164399677715973081,
+                1103647367084613873,
+            ]),
+            Fq::from_montgomery_limbs([
+                5444149341114625248,
+                8476503824444772865,
+                2495571519682704085,
+                359077645646916968,
+            ]),
+            Fq::from_montgomery_limbs([
+                14782270552937259931,
+                9025460736571822037,
+                11969815142677743664,
+                631279675719879966,
+            ]),
+            Fq::from_montgomery_limbs([
+                11830152387118618807,
+                5537938112873134356,
+                12093051581145411843,
+                1226189559351781335,
+            ]),
+            Fq::from_montgomery_limbs([
+                15253142843381058901,
+                16492896675125183758,
+                10974774326527792510,
+                772569080008942646,
+            ]),
+            Fq::from_montgomery_limbs([
+                1032059725397409703,
+                10750968560371473695,
+                11455204869747556923,
+                576196411992097076,
+            ]),
+            Fq::from_montgomery_limbs([
+                3469987962611476261,
+                14542716427036370495,
+                16995529911139429282,
+                1335313142728877911,
+            ]),
+            Fq::from_montgomery_limbs([
+                17341384441757132064,
+                18413697884528980589,
+                12842186907000083915,
+                1050517102300492739,
+            ]),
+            Fq::from_montgomery_limbs([
+                16704203795326977936,
+                4359316545665796944,
+                17856880502612298801,
+                949042575574599390,
+            ]),
+            Fq::from_montgomery_limbs([
+                1108863660405706178,
+                14988798657451969059,
+                17548581779400447274,
+                875602151461923286,
+            ]),
+            Fq::from_montgomery_limbs([
+                10279918742612073496,
+                2807539635642180935,
+                9374624150651429397,
+                462697641872254306,
+            ]),
+            Fq::from_montgomery_limbs([
+                13257421126264458279,
+                13691909148477538596,
+                14485117784953830434,
+                347189110906209371,
+            ]),
+            Fq::from_montgomery_limbs([
+                11962592626529884843,
+                1557333946723011441,
+                4842169820362352152,
+                706510603419487095,
+            ]),
+            Fq::from_montgomery_limbs([
+                9412808711936813748,
+                12743529227930636209,
+                18049775774590600889,
+                353194434905930075,
+            ]),
+            Fq::from_montgomery_limbs([
+                18060692984377415341,
+                18411466982216164765,
+                4402454972385850820,
+                1337962854229934063,
+            ]),
+            Fq::from_montgomery_limbs([
+                7293835533397362167,
+                4513489901997116610,
+                18195006092856633460,
+                843995712792961152,
+            ]),
+            Fq::from_montgomery_limbs([
+                2639666404451418346,
+                15169941488222226678,
+                7546381564218051431,
+                423265379514321465,
+            ]),
+            Fq::from_montgomery_limbs([
+                8718240848189936597,
+                10266718478730669915,
+                10996632440146498421,
+                533219892492138354,
+            ]),
+            Fq::from_montgomery_limbs([
+                14008171215533771206,
+                4878068228633847116,
+                1729293246896127240,
+                631701334194309991,
+            ]),
+            Fq::from_montgomery_limbs([
+                5135714579401190025,
+                8900978330607885829,
+                8389155376869242137,
+                1074868219517196147,
+            ]),
+            Fq::from_montgomery_limbs([
+                1775485511076876408,
+                5407732930964184619,
+                10192548052127698526,
+                14396279545839531,
+            ]),
+            Fq::from_montgomery_limbs([
+                5845827589099391191,
+                8478320913809680671,
+                4212749899156603110,
+                414361114716858540,
+            ]),
+            Fq::from_montgomery_limbs([
+                11173429428062659358,
+                17805057880387945296,
+                16175168396906998299,
+                391458814217431658,
+            ]),
+            Fq::from_montgomery_limbs([
+                16894478319334825013,
+                7788742024617638393,
+                6268945204589421103,
+                345450060782918287,
+            ]),
+            Fq::from_montgomery_limbs([
+                1379956592402634581,
+                2009033128833085750,
+                8474380283644628569,
+                484039610277062039,
+            ]),
+            Fq::from_montgomery_limbs([
+                6156337786012678415,
+                2634913674379920900,
+                1486218775694562069,
+                1246573934793133754,
+            ]),
+            Fq::from_montgomery_limbs([
+                2620565373762828367,
+                12094681526183500255,
+                8227238272607444494,
+                1120536539432911510,
+            ]),
+            Fq::from_montgomery_limbs([
+                10708535998319662379,
+                10991863733368116077,
+                12864710372401337673,
+                1316834939889062046,
+            ]),
+            Fq::from_montgomery_limbs([
+                1963629374137595935,
+                1015936624098554812,
+                17692819715687510215,
+                1007452257312156112,
+            ]),
+            Fq::from_montgomery_limbs([
+                7017755417845664008,
+                12951412562139040431,
+                3288701107598282052,
+                836124929704499661,
+            ]),
+            Fq::from_montgomery_limbs([
+                12691560450887403979,
+                3408953261455985260,
+                18320733414342114294,
+                1339989487157430617,
+            ]),
+            Fq::from_montgomery_limbs([
+                864477326364113191,
+                8498087693277835560,
+                3372106441291852115,
+                51804737704893210,
+            ]),
+            Fq::from_montgomery_limbs([
+                7354719756125720446,
+                918390084127196592,
+                8046141432249196686,
+                466667933408462240,
+            ]),
+            Fq::from_montgomery_limbs([
+                6605329733829417782,
+                14370585004661107351,
+                13414262611086416396,
+                1051571792551743437,
+            ]),
+            Fq::from_montgomery_limbs([
+                6462022435253035562,
+                13863222634459145561,
+                47686731745978747,
+                159626523792617900,
+            ]),
+            Fq::from_montgomery_limbs([
+                18419579395640155260,
+                2909577556521526050,
+                1612326710571015406,
+                1235992914099255888,
+            ]),
+            Fq::from_montgomery_limbs([
+                5881064703166264458,
+                14053640410584372047,
+                4815875748858019375,
+                758447987196140881,
+            ]),
+            Fq::from_montgomery_limbs([
+                2484861052548672434,
+                15457255295828472951,
+                18398391047129544464,
+                848222657450827909,
+            ]),
+            Fq::from_montgomery_limbs([
+                3685156803039586736,
+                2362421612748957524,
+                13048732130390950950,
+                825265778472892077,
+            ]),
+            Fq::from_montgomery_limbs([
+                5964415491813339842,
+                11714736194123166804,
+                8348043912846283244,
+                1112343194870394042,
+            ]),
+            Fq::from_montgomery_limbs([
+                10484936565163928149,
+                6436474963154633520,
+                17735487359415966799,
+                885793627610418525,
+            ]),
+            Fq::from_montgomery_limbs([
+                428564781830762142,
+                14248777979098897076,
+                9245867612372004687,
+                661648419996399577,
+            ]),
+            Fq::from_montgomery_limbs([
+                7378089196796332695,
+                4480622626050053058,
+                13810336117290558167,
+                781490323936642417,
+            ]),
+            Fq::from_montgomery_limbs([
+                11886399049692149315,
+                9249187436720123245,
+                13507764756291428063,
+                280544891880673894,
+            ]),
+            Fq::from_montgomery_limbs([
+                4464377021123515853,
+                10965233060694247973,
+                5201331260044332178,
+                621852662557843073,
+            ]),
+            Fq::from_montgomery_limbs([
+                18367858883938274206,
+                16632510522640344656,
+                2208137932224725044,
+                659932240440368261,
+            ]),
+            Fq::from_montgomery_limbs([
+                14754248103442369400,
+                13427243026672680301,
+                15259240372203450129,
+                1125675160546988214,
+            ]),
+            Fq::from_montgomery_limbs([
+                131471907640027536,
+                14646909382986158422,
+                16027327892362129444,
+                909469151365019859,
+            ]),
+            Fq::from_montgomery_limbs([
+                4213141299608323110,
+                9390138325212251710,
+                11140349446225678680,
+                1217140720807323543,
+            ]),
+            Fq::from_montgomery_limbs([
+                10532543510551316018,
+                2384404475066001676,
+                14249738935892440244,
+                649781760990854623,
+            ]),
+            Fq::from_montgomery_limbs([
+                211102933266565296,
+                7494965995428075212,
+                10521235270245377609,
+                23968082321784086,
+            ]),
+            Fq::from_montgomery_limbs([
+                1642631895755872572,
+                8153467008500603979,
+                15825006511164992446,
+                247380181969747758,
+            ]),
+            Fq::from_montgomery_limbs([
+                14869494728670510453,
+                15783200606986577659,
+                6310793548907406292,
+                1024854870554060878,
+            ]),
+            Fq::from_montgomery_limbs([
+                2040774867475456960,
+                5727238524255456470,
+                16863135285324049075,
+                1339472363275507351,
+            ]),
+            Fq::from_montgomery_limbs([
+                15489635208754511617,
+                18095318607068190658,
+                15134770060182062915,
+                125842892198155377,
+            ]),
+            Fq::from_montgomery_limbs([
+                7311403505499717865,
+                864962078034767518,
+                31073858813972772,
+                751042844869113354,
+            ]),
+            Fq::from_montgomery_limbs([
+                4971267734816284959,
+                3754756517200782292,
+                16854045093697440698,
+                448227228390219622,
+            ]),
+            Fq::from_montgomery_limbs([
+                4228391498067973572,
+                4767141982547073742,
+                14009944145300644941,
+                192832750274052274,
+            ]),
+            Fq::from_montgomery_limbs([
+                13976074428587273114,
+                11949141146019234081,
+                2042500127639894728,
+                1004016616131361468,
+            ]),
+            Fq::from_montgomery_limbs([
+                10748747529738726911,
+                13035303594551142822,
+                6819332922344155635,
+                778853503104221772,
+            ]),
+            Fq::from_montgomery_limbs([
+                12502915782673680338,
+                3694420379017395575,
+                11748157738705887035,
+                433187202138718856,
+            ]),
+            Fq::from_montgomery_limbs([
+                1607043682425128141,
+                4458512627164333138,
+                14128347504347560406,
+                470085788861844550,
+            ]),
+            Fq::from_montgomery_limbs([
+                9525994002604326323,
+                5016853655473466660,
+                826312456983027459,
+                1043148378562064878,
+            ]),
+            Fq::from_montgomery_limbs([
+                12408954316899629830,
+                13718667139364044602,
+                13756254442341295407,
+                913713742591967282,
+            ]),
+            Fq::from_montgomery_limbs([
+                7816008235503793281,
+                9946296394299957670,
+                11112166682968911954,
+                144631707838267545,
+            ]),
+            Fq::from_montgomery_limbs([
+                1437981596639406111,
+                17852503340165631633,
+                2966135368048496505,
+                669564974631778630,
+            ]),
+            Fq::from_montgomery_limbs([
+                11603014392906277283,
+                15339216856874691686,
+                17146640907675170424,
+                432889663408234306,
+            ]),
+            Fq::from_montgomery_limbs([
+                7573818504196238697,
+                6546992711623084592,
+                14251488498493091115,
+                330467955406692306,
+            ]),
+            Fq::from_montgomery_limbs([
+                7378032375038388901,
+                555968408932628515,
+                3220603533315905437,
+                797900491429336329,
+            ]),
+            Fq::from_montgomery_limbs([
+                7299971166774928022,
+                12393921393394781915,
+                15110398356389124555,
+                363698771756099341,
+            ]),
+            Fq::from_montgomery_limbs([
+                6120278261036092261,
+                3984184028748777014,
+                8939057183596766435,
+                740467297058698327,
+            ]),
+            Fq::from_montgomery_limbs([
+                11429777967566473880,
+                7305019509381634025,
+                14767968604412573779,
+                989868832273087447,
+            ]),
+            Fq::from_montgomery_limbs([
+                8333948710482598268,
+                13464746657370842022,
+                10292735077130048012,
+                1055055570116403873,
+            ]),
+            Fq::from_montgomery_limbs([
+                3677170518422142476,
+                12555645614038811941,
+                9980573514363649317,
+                450272609342592309,
+            ]),
+            Fq::from_montgomery_limbs([
+                2843287937713485340,
+                10172154367297456308,
+                5957568512244200487,
+                47423236237283439,
+            ]),
+            Fq::from_montgomery_limbs([
+                1328400357411419823,
+                895463379189546403,
+                2144691071347336248,
+                373681993983973956,
+            ]),
+            Fq::from_montgomery_limbs([
+                11233798234523954034,
+                15248987808806498344,
+                14730532250862901339,
+                677393182714581107,
+            ]),
+            Fq::from_montgomery_limbs([
+                1952453828108049765,
+                16576848807446475228,
+                6921872814414304610,
+                647726885779026108,
+            ]),
+            Fq::from_montgomery_limbs([
+                2671604335961194822,
+                7394448529189996098,
+                5946236368175589852,
+                775832335037212855,
+            ]),
+            Fq::from_montgomery_limbs([
+                13913854552741893273,
+                16443732925512514485,
+                15317663300192096580,
+                644260786118009698,
+            ]),
+            Fq::from_montgomery_limbs([
+                11534032059513555436,
+                15722119926423190748,
+                12165136917363398375,
+                286676351902162366,
+            ]),
+            Fq::from_montgomery_limbs([
+                8216040676499593316,
+                13092579709557262127,
+                5668072465112253903,
+                344219610863238324,
+            ]),
+            Fq::from_montgomery_limbs([
+                13243697967702614578,
+                5815538655949134408,
+                16420750374654307379,
+                669595819129072799,
+            ]),
+            Fq::from_montgomery_limbs([
+                13527246301509148071,
+                492180918570784531,
+                2701726508235665427,
+                794186197794327699,
+            ]),
+            Fq::from_montgomery_limbs([
+                702202353720716579,
+                13496643295458384840,
+                8521544865371314330,
+                202574874682834328,
+            ]),
+            Fq::from_montgomery_limbs([
+                4227084595821391293,
+                8754708507852767343,
+                4267617516155137586,
+                487460276517923212,
+            ]),
+            Fq::from_montgomery_limbs([
+                8590212320908598884,
+                11543127296442795984,
+                15830186005686254616,
+                393302199989263720,
+            ]),
+            Fq::from_montgomery_limbs([
+                3790984053248790042,
+                4038314262013746231,
+                11348725752468656669,
+                1189788674881267371,
+            ]),
+            Fq::from_montgomery_limbs([
+                9916159500084924370,
+                9727296954418379583,
+                9265286630308847268,
+                318092683792639341,
+            ]),
+            Fq::from_montgomery_limbs([
+                1432739291145668100,
+                13167354020564662595,
+                17854140061585090109,
+                507233053923183228,
+            ]),
+            Fq::from_montgomery_limbs([
+                2045682193924664398,
+                1539501886359879568,
+                9542410601177334082,
+                32050630988187669,
+            ]),
+            Fq::from_montgomery_limbs([
+                16696924172701252227,
+                1139889152710591430,
+                12890064725393383618,
+                1077429598442021429,
+            ]),
+            Fq::from_montgomery_limbs([
+                2585117183945758560,
+                17069338530266106650,
+                14363067493231380111,
+                849561313100264346,
+            ]),
+            Fq::from_montgomery_limbs([
+                13191776682985545047,
+                5466479319175048828,
+                2997707798893605333,
+                144211233558693070,
+            ]),
+            Fq::from_montgomery_limbs([
+                2491028964136753618,
+                6989941144395453522,
+                10216833631908506893,
+                199106281536561470,
+            ]),
+            Fq::from_montgomery_limbs([
+                15518126230498480936,
+                3980778126301842212,
+                5150086302474469225,
+                1110468776306746661,
+            ]),
+            Fq::from_montgomery_limbs([
+                16009632601789210099,
+                13033161654226838732,
+                10778757901993451367,
+                278214706770756713,
+            ]),
+            Fq::from_montgomery_limbs([
+                3468657013365658440,
+                13374646355906784122,
+                5058370077592920585,
+                147166242039008939,
+            ]),
+            Fq::from_montgomery_limbs([
+                9959311747252786189,
+                137248446506148465,
+                15596680873651984907,
+                1053916253319587109,
+            ]),
+            Fq::from_montgomery_limbs([
+                4362069490918748366,
+                5552528701040734466,
+                1486487322729220195,
+                261703524074307319,
+            ]),
+            Fq::from_montgomery_limbs([
+                9265543150083379528,
+                13445507753247286039,
+                11948170167513541599,
+                1141473161042986749,
+            ]),
+            Fq::from_montgomery_limbs([
+                17091756451015583116,
+                7319121931573517916,
+                1837216881766412914,
+                947768999867177847,
+            ]),
+            Fq::from_montgomery_limbs([
+                13601362410169368711,
+                5727417650406459742,
+                1841450286138763872,
+                1020058647705980946,
+            ]),
+            Fq::from_montgomery_limbs([
+                17021022186312902799,
+                4514972138333468765,
+                7965147200031266206,
+                1312043789134868741,
+            ]),
+            Fq::from_montgomery_limbs([
+                4035252580427933834,
+                3924406847827838230,
+                5993012015326609482,
+                383553295510042000,
+            ]),
+            Fq::from_montgomery_limbs([
+                17392232528875941098,
+                6978675962758783537,
+                4205669903769339760,
+                314840574737155483,
+            ]),
+            Fq::from_montgomery_limbs([
+                8440914719070556924,
+                3876731699797082433,
+                14206988558495683499,
+                715055199351054531,
+            ]),
+            Fq::from_montgomery_limbs([
+                17991316630685596499,
+                5625679428428140020,
+                14168465935698311707,
+                103078773205188159,
+            ]),
+            Fq::from_montgomery_limbs([
+                18009276264588681116,
+                5964371773060485508,
+                8700784628374648269,
+                1323825303737358945,
+            ]),
+            Fq::from_montgomery_limbs([
+                17600889234108624555,
+                3201837572654121373,
+                7956742931571098868,
+                66572507089591330,
+            ]),
+            Fq::from_montgomery_limbs([
+                1096225690144422840,
+                3592669125237624840,
+                11762645353952272598,
+                794088853220073943,
+            ]),
+            Fq::from_montgomery_limbs([
+                15980096721892708401,
+                11953411166605034747,
+                101808210529602552,
+                1132658658162675331,
+            ]),
+            Fq::from_montgomery_limbs([
+                7603093895233717417,
+                2275259632262693584,
+                6502587870667009359,
+                271549807110008512,
+            ]),
+            Fq::from_montgomery_limbs([
+                18347245909853573566,
+                10864688023402082387,
+                13195352983156734608,
+                1175930718990212007,
+            ]),
+            Fq::from_montgomery_limbs([
+                8211312294711321684,
+                5346135886106607424,
+                5809441289681669182,
+                566502016120544754,
+            ]),
+            Fq::from_montgomery_limbs([
+                1602725582528387811,
+                2854353260673583327,
+                9166367765708737982,
+                339772648623769005,
+            ]),
+            Fq::from_montgomery_limbs([
+                12547015699798737898,
+                6351173235594103761,
+                6587206290832493026,
+                749556505342617843,
+            ]),
+            Fq::from_montgomery_limbs([
+                14368560052729504551,
+                17693875481471384961,
+                4597521937887088872,
+                30459034617085383,
+            ]),
+        ]),
+        mds: MdsMatrix::<2, 1, 4, 1>::new_from_known([
+            Fq::from_montgomery_limbs([
+                14093733558879256570,
+                16573587507929677817,
+                13530556323685588983,
+                1151689812725735417,
+            ]),
+            Fq::from_montgomery_limbs([
+                15302903146332116305,
+                8895355854553175376,
+                548696419140371791,
+                319366418254432479,
+            ]),
+            Fq::from_montgomery_limbs([
+                15302903146332116305,
+                8895355854553175376,
+                548696419140371791,
+                319366418254432479,
+            ]),
+            Fq::from_montgomery_limbs([
+                16270238816294404093,
+                17510165790819614716,
+                15988650198697570299,
+                575844906362867708,
+            ]),
+        ]),
+        alpha: Alpha::Inverse,
+        rounds: RoundNumbers { r_P: 65, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                16270238816294404093,
+                17510165790819614716,
+                15988650198697570299,
+                575844906362867708,
+            ])]),
+            v: Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                15302903146332116305,
+                8895355854553175376,
+                548696419140371791,
+                319366418254432479,
+            ])]),
+            w: Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                15302903146332116305,
+                8895355854553175376,
+                548696419140371791,
+                319366418254432479,
+            ])]),
+            M_prime: SquareMatrix::<2, 4>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    16270238816294404093,
+                    17510165790819614716,
+                    15988650198697570299,
+                    575844906362867708,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<2, 4>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    14093733558879256570,
+                    16573587507929677817,
+                    13530556323685588983,
+                    1151689812725735417,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15302903146332116305,
+                    8895355854553175376,
+                    548696419140371791,
+                    319366418254432479,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5871380364199810372,
+                    17134679344503149891,
+                    2194785676561487165,
+                    1277465673017729916,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<2, 4>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    5994009629053419274,
+                    15434301171572932352,
+                    1350487059822599859,
+                    1102422137481273087,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18054649431151608138,
+                    10789890749345300822,
+                    12135909219384222141,
+                    1220664558067982680,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18054649431151608138,
+                    10789890749345300822,
+                    12135909219384222141,
+                    1220664558067982680,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11262517505635122707,
+                    5960750817236483583,
+                    14179438877114240358,
+                    859563904274372775,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                16163137587655999434,
+                1588334981690687431,
+                11094542470912991159,
+                1141836277676842951,
+            ])]),
+            M_00: Fq::from_montgomery_limbs([
+                14093733558879256570,
+                16573587507929677817,
+                13530556323685588983,
+                1151689812725735417,
+            ]),
+            M_i: Matrix::<2, 2, 4>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 4611686018427387904, 0, 0]),
+            ]),
+            v_collection: [
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    15302903146332116305,
+                    8895355854553175376,
+                    548696419140371791,
+                    319366418254432479,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    1281160748661064590,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    992930372509352846,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    920872778471424910,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    902858379961942926,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    898354780334572430,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    897228880427729806,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896947405451019150,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896877036706841486,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896859444520797070,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896855046474285966,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853946962658190,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853672084751246,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853603365274510,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853586185405326,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853581890438030,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580816696206,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580548260750,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580481151886,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580464374670,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580460180366,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580459131790,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580458869646,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580458804110,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580458787726,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580458783630,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580458782606,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580458782350,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580458782286,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580458782270,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580458782266,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    12331662908205987158,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    11178741403599140182,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10890511027447428438,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10818453433409500502,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10800439034900018518,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10795935435272648022,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794809535365805398,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794528060389094742,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794457691644917078,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794440099458872662,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794435701412361558,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434601900733782,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434327022826838,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434258303350102,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434241123480918,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434236828513622,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235754771798,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235486336342,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235419227478,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235402450262,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235398255958,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235397207382,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235396945238,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235396879702,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235396863318,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235396859222,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235396858198,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235396857942,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235396857878,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235396857862,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235396857858,
+                    896853580458782265,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    10794434235396857857,
+                    896853580458782265,
+                ])]),
+            ],
+            w_hat_collection: [
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    5871380364199810372,
+                    17134679344503149891,
+                    2194785676561487165,
+                    1277465673017729916,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    2862272125674542349,
+                    12261906873994007818,
+                    6321048831233967347,
+                    1074021580006399469,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    9272583245283021873,
+                    11217561065666991141,
+                    4379357376214336458,
+                    260245207961077682,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    196844833712984260,
+                    7976756115248861334,
+                    17517429504857345834,
+                    1040980831844310728,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    17057618151146341133,
+                    12523702104395956820,
+                    12271391923288747173,
+                    128082215312722720,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    12890240383456709684,
+                    13201320270164724051,
+                    12192079545735885462,
+                    512328861250890882,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    13941971633936019663,
+                    9450685481039963469,
+                    4906550719283927641,
+                    704035074315390132,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    17423394883381543738,
+                    6433782946050643252,
+                    5689644244654688611,
+                    125579555885213732,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    14353347312397520104,
+                    7288387710493021395,
+                    4311832904909202829,
+                    502318223540854929,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    1347655275989709727,
+                    4245699316062704462,
+                    10279052303396300340,
+                    663992523475246318,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    4665119351487123067,
+                    10521689812050988343,
+                    15701185823635138767,
+                    1310689723212811875,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    16483972148533344745,
+                    4256692817894913241,
+                    5006417198399918906,
+                    1206917780786727308,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    8419151115589576609,
+                    16090192988689716068,
+                    17567574918587694308,
+                    791830011082389037,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    13778856883705323138,
+                    14545068902940102031,
+                    993508820741100432,
+                    476759302953209355,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    17496437634930473479,
+                    14825680012141475389,
+                    15452500040433442369,
+                    561756841124664021,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    13920016566121523227,
+                    15948124448946968822,
+                    17948232698074155269,
+                    901746993810482688,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    17335574612123557994,
+                    13976794743969113048,
+                    2516139938686944276,
+                    916427233865583959,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    12551062722422145446,
+                    6091475924057689953,
+                    14574745195976306768,
+                    975148194085989039,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    11859759237326046870,
+                    11443688791831100804,
+                    7468934004005101886,
+                    1210032034967609362,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    8369043544469936725,
+                    7944688737015363087,
+                    8970898067298874614,
+                    804287027805917254,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    13578426599226763602,
+                    409795969952241723,
+                    3500289563004924887,
+                    526587369847322221,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    16694716497016235335,
+                    13624820501318689005,
+                    7032878935779188570,
+                    761069108701115486,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    9987630261992854810,
+                    4683578953455993781,
+                    14194957110635732328,
+                    353715693428115148,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    2331531148080600167,
+                    12273208361624145621,
+                    12918060978883315103,
+                    69582403024287196,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    9326124592322400668,
+                    12199345299077479252,
+                    14778755768114157182,
+                    278329612097148786,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    411010221870499440,
+                    11903893048890813778,
+                    3774790851327973882,
+                    1113318448388595147,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    17914279703776401853,
+                    9785505765254214980,
+                    12641069530299914214,
+                    417432681489860393,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    15591384841505236723,
+                    14234171535107478802,
+                    6702510657540042648,
+                    324450355271268176,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    7025307144892292044,
+                    1596453919301260363,
+                    8363298556450618979,
+                    1297801421085072705,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    7477979248444469037,
+                    5449237394315104554,
+                    12548356277080942984,
+                    1155364572275770626,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    9288667662653177009,
+                    2413627220660929702,
+                    10841843085892687389,
+                    585617177038562311,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    17982424824431440579,
+                    3193401430443889304,
+                    17952348953620686963,
+                    997188337466075847,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    15138463571653675786,
+                    18298134891085449825,
+                    2532604960873071049,
+                    1298192608487956595,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    3037116808070900773,
+                    16915729060323207556,
+                    7672325968480302883,
+                    1156929321887306185,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    9971961974868455569,
+                    11386105737274238477,
+                    9784465925199678603,
+                    591876175484704546,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    2268857999583003203,
+                    2189827349478021173,
+                    13722840310848651821,
+                    1022224331250644787,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    6898926740916865289,
+                    7822731115022147793,
+                    15539779220963522736,
+                    53056212938058955,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    9148962889957909540,
+                    12844180386379039557,
+                    6818884662725436097,
+                    212224851752235823,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    18149107486122086544,
+                    14483233398097054997,
+                    8828794577192192774,
+                    848899407008943293,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    15805194218416259646,
+                    8117230540569457749,
+                    2931875602578197528,
+                    705036886659426377,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    6429541147592952054,
+                    1099963184168620373,
+                    16237687851541319775,
+                    129586805261358711,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    7271420516662256600,
+                    4399852736674481493,
+                    9610519185036624252,
+                    518347221045434847,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    9913436240467758943,
+                    11138303494498096468,
+                    13027053350196434415,
+                    728108513493565991,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    1309253309508500858,
+                    13184254999883175248,
+                    1278166620885612475,
+                    221873312597917170,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    5237013238034003432,
+                    15843531852113597760,
+                    5112666483542449902,
+                    887493250391668680,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    1050305373483030430,
+                    13558424356635628799,
+                    6514107301688777656,
+                    859412260190327924,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    2750217988988690038,
+                    4417994374723752954,
+                    12119870574274088672,
+                    747088299384964900,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    9549868451011328470,
+                    4749762594495352806,
+                    16096179590905781118,
+                    297792456163512805,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    1305985656626210648,
+                    552306304271859610,
+                    9044486142494469625,
+                    1191169824654051223,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    3047437369089695069,
+                    1272646934197501541,
+                    15273106621256345568,
+                    728838186551684698,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    10738745971415348594,
+                    10615116906099898770,
+                    10262379705125257085,
+                    224792004830391998,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    6061495738242291144,
+                    5566979476980491850,
+                    4156030673081925110,
+                    899168019321567994,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    4348235374316181278,
+                    9345703003522308391,
+                    2687564059846678486,
+                    906111335909925180,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    15941937992321293430,
+                    6013853035980022938,
+                    15260441680615243607,
+                    933884602263353923,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    6976516243213087190,
+                    11133197239520432745,
+                    10211719942560849242,
+                    1044977667677068898,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    7282815641727649621,
+                    6702722527772690850,
+                    1495297747812312422,
+                    144069558643755399,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    10684518493201046868,
+                    8364146037381211785,
+                    5981190991249249689,
+                    576278234575021596,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    5119084072913368399,
+                    8548732623615466021,
+                    16956484648756487780,
+                    959832567611912986,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    578588713000490298,
+                    2825971516352653459,
+                    16995891815125825935,
+                    1148769529071305150,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    137849594586813669,
+                    10367307782520676937,
+                    10185241164362667576,
+                    559237004220700408,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    18272640699585090451,
+                    16561379604173326626,
+                    15325941267500607712,
+                    891667646194628235,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    16299327072268275274,
+                    16429815364874544265,
+                    10473718290102305664,
+                    876109843402166146,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    8406072563001014566,
+                    15903558407679414821,
+                    9511570454218649088,
+                    813878632232317789,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    13726542673351074966,
+                    13798530578898897043,
+                    5662979110684022784,
+                    564953787552924361,
+                ])]),
+                Matrix::<1, 1, 1>::new_from_known([Fq::from_montgomery_limbs([
+                    17287180793513480791,
+                    11839526715976655437,
+                    15683637126495580161,
+                    914534779523524046,
+                ])]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<73, 2, 146>::new_from_known([
+            Fq::from_montgomery_limbs([
+                15895198993032053758,
+                18317054528392995046,
+                12975016329636154523,
+                696045811561133802,
+            ]),
+            Fq::from_montgomery_limbs([
+                11135012365599855672,
+                10551472703341626006,
+                285980845476995630,
+                595003651531845589,
+            ]),
+            Fq::from_montgomery_limbs([
+                1174435058757113795,
+                2961864513805472778,
+                16211937462366153364,
+                104329296732648590,
+            ]),
+            Fq::from_montgomery_limbs([
+                13525869041374665802,
+                16525584479940046476,
+                14384136125391138316,
+                278489699627995674,
+            ]),
+            Fq::from_montgomery_limbs([
+                3564539694149100697,
+                11078399881667186538,
+                9995880636525172652,
+                621378143901863629,
+            ]),
+            Fq::from_montgomery_limbs([
+                3056314683085331160,
+                1353556414399135814,
+                18227561755170834106,
+                1226886419734040220,
+            ]),
+            Fq::from_montgomery_limbs([
+                11220837229117663257,
+                13435541797260317085,
+                9906219101556157301,
+                70984442825414393,
+            ]),
+            Fq::from_montgomery_limbs([
+                991223349407100211,
+                7050505429585965683,
+                8639646179660484025,
+                259359391759383242,
+            ]),
+            Fq::from_montgomery_limbs([
+                2878186150483553074,
+                15825497884624167784,
+                9163641916896141683,
+                65285287503919507,
+            ]),
+            Fq::from_montgomery_limbs([
+                18426516217928322155,
+                7165284977106912004,
+                855546417630907288,
+                779213705539308775,
+            ]),
+            Fq::from_montgomery_limbs([
+                11436468335917565939,
+                3353906203178853951,
+                7924562719323022474,
+                1003935090045777976,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                637110674900857285,
+                2097370882577661681,
+                610697022979810494,
+                1265531667386627832,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5388663114788598029,
+                3524681263600588262,
+                9193954770670863349,
+                1275824233590904857,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16390265865324522608,
+                1207170564016781490,
+                1806545537027770690,
+                1089249939348521823,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11045895769065321549,
+                9896347787780249228,
+                8354829672332756612,
+                1170814694928694902,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9080718977809188607,
+                13763224410388061580,
+                9629573326210064604,
+                1316990524395993634,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9060263668670385280,
+                10225263060910121638,
+                16568795341815769471,
+                1043184530785730082,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13725063230066291646,
+                152174774627343326,
+                7420541438179233123,
+                168831834090377217,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15918281690688580537,
+                13370903275584100535,
+                16352218082100847013,
+                1185812866921287608,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13997500524476520262,
+                3726192362853944642,
+                306798567182899678,
+                909429325201187064,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11679394743059538141,
+                12467230469833758234,
+                3122470643058604303,
+                601693698837604672,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4246276018864282630,
+                11778169569641338645,
+                5792562953952061620,
+                307333480517284393,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14011937932625110627,
+                15619381869934829059,
+                2927092136348294946,
+                883302566989264809,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10003079497293901832,
+                1161956565247638801,
+                5889055237682067388,
+                423184630451037082,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                875142381519078007,
+                7410105096648145811,
+                11139579059759569906,
+                34715020218384,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9842987481997586293,
+                6097416154713367323,
+                16409598110598359270,
+                703123672949901168,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5941163292080710889,
+                16596936625393154972,
+                2852187331479195048,
+                671850718016662903,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5125429299817367081,
+                17201564867320822542,
+                16856489506778301254,
+                1244671349802397697,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14293498958692819522,
+                15460268192632746494,
+                5981938011621875734,
+                657077228219294717,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4829764108789330496,
+                5672593539216715088,
+                3655456012391912750,
+                1120413035578388607,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6018931418537207114,
+                2847366248376588230,
+                16329045090027716608,
+                1083082816128137817,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4463794442661527339,
+                4127451554263217285,
+                3371149534591628000,
+                957997689377125747,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3692606974829209448,
+                6526040298536796314,
+                15602559385359123786,
+                1025637550413176426,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14378083258545031783,
+                2208740788523501439,
+                10904159224102868302,
+                1239949161216341570,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16735034429561442658,
+                1391467494072786623,
+                10011381049629076726,
+                40182857155663050,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3146571852183025154,
+                202479311136553362,
+                15378985553446279378,
+                1046371789233669395,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9839894725752371940,
+                3804166808320023797,
+                17113753269100883135,
+                1189721817474261509,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9598065732712504023,
+                4974531898114812971,
+                603028421348049277,
+                948845446137143937,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15274047866539842185,
+                1349636759798070921,
+                6935534054399067750,
+                1302612665143114070,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6385090408527531279,
+                1113050675269252039,
+                6021580936025619001,
+                754050911166895328,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12305586354712635934,
+                3508432928999277523,
+                17922263802985766972,
+                199479369501910834,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                534335768426968202,
+                5046371391466820472,
+                11452005112111606181,
+                746579991178828746,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12626130839080707388,
+                3179639090059672654,
+                7071775052493658403,
+                9507601973515157,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17159136433388940678,
+                15970886805872695608,
+                8828341253128656995,
+                1132740358284479353,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15641930399716300410,
+                10026214402355811978,
+                3866546353354480226,
+                1025085630426300631,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12111695120434457945,
+                6627611214719513670,
+                9649430650624101879,
+                1110053446532092071,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13476208608645919498,
+                3785836219568520486,
+                16784225613830730218,
+                700443641781292591,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1999785197588541294,
+                12669159764642783715,
+                1497025546081827205,
+                755782621448840410,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16935501087661526420,
+                18270484496056114642,
+                14217785184122388635,
+                737663972678850520,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9256950780017205246,
+                12149214712425512922,
+                4870205304624100394,
+                904092430855650675,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4381684565738027468,
+                9904419022811325833,
+                18234416204794960603,
+                1236615981767198425,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10673323620624786205,
+                2773849723890386014,
+                8566655672281871021,
+                635395560509487474,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13508932325971317950,
+                2504584286768198549,
+                13952233239988877555,
+                547480914802803730,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14568982344202615406,
+                6790378495701373516,
+                5684247065332947659,
+                1016973537642128969,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                419621929565925698,
+                1588660856870654433,
+                8815538598349321534,
+                1203223864431501256,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2256984941635977309,
+                16419556549755137867,
+                17165656807105146825,
+                490030174014585896,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7329880995274937676,
+                1866671350844615991,
+                3730676037630296758,
+                292170267299884628,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11132725916735272048,
+                5460279444282377352,
+                14419934143755649642,
+                1139885127162108795,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10940488532061095733,
+                4425550575455921157,
+                5910363942546128121,
+                480644704379279230,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                305507245300989333,
+                11903599709670828106,
+                11794859915358586503,
+                142499279404197689,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12189793175617559569,
+                3015229584517057547,
+                4015144113408028132,
+                1092971423527296552,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17441820961807174187,
+                4333946299146189802,
+                12601472245906077579,
+                592800723131770810,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14475216928796944523,
+                12891945437063089755,
+                3755858508811634974,
+                989388267883603458,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1767226660854063637,
+                5330258779763505043,
+                3084347731536303106,
+                699676237165964028,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10688017343629557388,
+                10346191715788969386,
+                10897319895523640980,
+                823179228181304626,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15633048868630194746,
+                17602883575571676894,
+                13311779259519351198,
+                630542638421920853,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2710382983273841157,
+                15061547548026409940,
+                14470274985099052794,
+                161825600556515804,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5334376488662416336,
+                6296823795608669831,
+                6221987043913249585,
+                267461466237513267,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4195278550682605931,
+                9661404897478761553,
+                1007420365321221246,
+                1252149684365849003,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8576012577914213034,
+                12673365245307131043,
+                12767746361004657889,
+                433114280535460925,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10539665231719461821,
+                3042049085743388161,
+                5266806758840282658,
+                992190691465538720,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5514525661163031427,
+                7642589055249034612,
+                13367681635737335573,
+                748276088087440225,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16654743416719237565,
+                13805873056912391060,
+                15702778367651820177,
+                1057955023286234297,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                818067546699127008,
+                923847231030760570,
+                1733776482865533302,
+                706629366111494714,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1096225690144422840,
+                3592669125237624840,
+                11762645353952272598,
+                794088853220073943,
+            ]),
+            Fq::from_montgomery_limbs([
+                15980096721892708401,
+                11953411166605034747,
+                101808210529602552,
+                1132658658162675331,
+            ]),
+            Fq::from_montgomery_limbs([
+                7603093895233717417,
+                2275259632262693584,
+                6502587870667009359,
+                271549807110008512,
+            ]),
+            Fq::from_montgomery_limbs([
+                18347245909853573566,
+                10864688023402082387,
+                13195352983156734608,
+                1175930718990212007,
+            ]),
+            Fq::from_montgomery_limbs([
+                8211312294711321684,
+                5346135886106607424,
+                5809441289681669182,
+                566502016120544754,
+            ]),
+            Fq::from_montgomery_limbs([
+                1602725582528387811,
+                2854353260673583327,
+                9166367765708737982,
+                339772648623769005,
+            ]),
+            Fq::from_montgomery_limbs([
+                12547015699798737898,
+                6351173235594103761,
+                6587206290832493026,
+                749556505342617843,
+            ]),
+            Fq::from_montgomery_limbs([
+                14368560052729504551,
+                17693875481471384961,
+                4597521937887088872,
+                30459034617085383,
+            ]),
+        ]),
+    }
+}
@@ -0,0 +1,4040 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-2 instance of Poseidon with the inverse S-box.
+pub const fn rate_2() -> PoseidonParameters<3, 2, 9, 4, 71, 3, 213, 63> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<71, 3, 213>::new_from_known([
+            Fq::from_montgomery_limbs([
+                3415800112407090504,
+                9640355504800384837,
+                11903515404172517534,
+                1345032944892553847,
+            ]),
+            Fq::from_montgomery_limbs([
+                956562501238835079,
+                8848296780948657632,
+                8944703453691795257,
+                305492603987405025,
+            ]),
+            Fq::from_montgomery_limbs([
+                14032117164349689430,
+                8999226453914200340,
+                1971378089549144750,
+                1052932304369877248,
+            ]),
+            Fq::from_montgomery_limbs([
+                2104090116540896595,
+                5327579662064149683,
+                5525041356721424043,
+                882887877464052703,
+            ]),
+            Fq::from_montgomery_limbs([
+                3824564479288028408,
+                187800732652096772,
+                12230120083640899358,
+                1332482629250990804,
+            ]),
+            Fq::from_montgomery_limbs([
+                16027382311713020983,
+                15769642244315241484,
+                3651838465479003579,
+                152242153106192931,
+            ]),
+            Fq::from_montgomery_limbs([
+                921498002606301627,
+                16761054671022055278,
+                8668766376403732835,
+                46902497749081181,
+            ]),
+            Fq::from_montgomery_limbs([
+                414601263689571040,
+                12603268818290641452,
+                707399626691578471,
+                548956439270858932,
+            ]),
+            Fq::from_montgomery_limbs([
+                17347412428407243215,
+                6386599095289052363,
+                16666868181403744928,
+                879643192370926772,
+            ]),
+            Fq::from_montgomery_limbs([
+                3334512527353618053,
+                13874539143072648379,
+                11489510723844971503,
+                597570483526452460,
+            ]),
+            Fq::from_montgomery_limbs([
+                9083424031792637464,
+                9339556884910227191,
+                7294396798843722897,
+                953372063659666028,
+            ]),
+            Fq::from_montgomery_limbs([
+                3847329834639742759,
+                6937028752438820992,
+                15769977650597294747,
+                1312556818526551944,
+            ]),
+            Fq::from_montgomery_limbs([
+                5283294861411203980,
+                3421086423052891763,
+                13891728417302242384,
+                451560900421994671,
+            ]),
+            Fq::from_montgomery_limbs([
+                15280649464907819379,
+                11037407641414959560,
+                8826117349170122849,
+                1253029206146027118,
+            ]),
+            Fq::from_montgomery_limbs([
+                5132925138446874468,
+                6426093553609832774,
+                9719691081635563608,
+                357783452294995093,
+            ]),
+            Fq::from_montgomery_limbs([
+                3027315548926059077,
+                2730737389361470709,
+                8095206984570852617,
+                488240626922909183,
+            ]),
+            Fq::from_montgomery_limbs([
+                15094566776627958719,
+                12072097525685615356,
+                13106778686633799814,
+                675584026438373868,
+            ]),
+            Fq::from_montgomery_limbs([
+                8313286410215258943,
+                15907367574939807287,
+                6760321541247637769,
+                851157015613731456,
+            ]),
+            Fq::from_montgomery_limbs([
+                7783272919147532844,
+                17947392083877917422,
+                9285653910233643739,
+                1194391489985187675,
+            ]),
+            Fq::from_montgomery_limbs([
+                4474793439865068710,
+                2483233766463752177,
+                6033581168407256567,
+                1271829221633484162,
+            ]),
+            Fq::from_montgomery_limbs([
+                7421810643641459551,
+                252440467036789643,
+                15998830049330642684,
+                458607873254150947,
+            ]),
+            Fq::from_montgomery_limbs([
+                9289790979736943154,
+                11845229522199653795,
+                941761643050567402,
+                1160740271824107854,
+            ]),
+            Fq::from_montgomery_limbs([
+                3461058312498699598,
+                14545711623238013971,
+                15851003861376621521,
+                203860908548474492,
+            ]),
+            Fq::from_montgomery_limbs([
+                7099770367199874873,
+                16633920421141566533,
+                15263209848392867489,
+                1200780621086351928,
+            ]),
+            Fq::from_montgomery_limbs([
+                1659150023716516585,
+                13768415077706838235,
+                9282185596104139574,
+                1281192298546282723,
+            ]),
+            Fq::from_montgomery_limbs([
+                3191581028073691228,
+                16045209219829968357,
+                10046909714184288446,
+                277080340475509480,
+            ]),
+            Fq::from_montgomery_limbs([
+                913384214382834308,
+                4647008627398870583,
+                8192728125532327562,
+                935419890940394405,
+            ]),
+            Fq::from_montgomery_limbs([
+                7226181885799436937,
+                6172779325618311282,
+                6004257980195697326,
+                393750961671003890,
+            ]),
+            Fq::from_montgomery_limbs([
+                14189548415383654536,
+                10323917806783954035,
+                15016271331719134653,
+                892450054253983161,
+            ]),
+            Fq::from_montgomery_limbs([
+                9533499942474278148,
+                8294855253268733390,
+                14493058690513527314,
+                894590117924009280,
+            ]),
+            Fq::from_montgomery_limbs([
+                9270427258978688854,
+                14549820308357695961,
+                1641150732740024065,
+                908735843540119396,
+            ]),
+            Fq::from_montgomery_limbs([
+                9820395756282082729,
+                14109818253417839193,
+                16012991005059205822,
+                1266921521207871782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14670132244618987728,
+                5394856153075298204,
+                14402119560343658967,
+                1239776940336393042,
+            ]),
+            Fq::from_montgomery_limbs([
+                5888271187673432014,
+                12336545074454692703,
+                3432242111363638156,
+                294907780856877617,
+            ]),
+            Fq::from_montgomery_limbs([
+                15273316108610361240,
+                12375933044426682311,
+                3014975064630738233,
+                674398369171179368,
+            ]),
+            Fq::from_montgomery_limbs([
+                13142699084693191752,
+                2129381346420951717,
+                759408361555014007,
+                900134725248416488,
+            ]),
+            Fq::from_montgomery_limbs([
+                14674622748587291210,
+                12286716092257357170,
+                12566641668983413038,
+                1250224418556615353,
+            ]),
+            Fq::from_montgomery_limbs([
+                2176862733573013172,
+                6883008582748958230,
+                11868487879991659576,
+                801578961553073463,
+            ]),
+            Fq::from_montgomery_limbs([
+                10567933033976741616,
+                5915033883807575293,
+                14511921085440120637,
+                423394694248887837,
+            ]),
+            Fq::from_montgomery_limbs([
+                6537092515428815285,
+                6894345957106005020,
+                9669680240692082245,
+                774090089194164511,
+            ]),
+            Fq::from_montgomery_limbs([
+                13354595607704375832,
+                14092379983967850872,
+                17180288669217885968,
+                779407712151106469,
+            ]),
+            Fq::from_montgomery_limbs([
+                14870546355437814541,
+                17988462128160752601,
+                17036322457452915684,
+                779912059212613455,
+            ]),
+            Fq::from_montgomery_limbs([
+                306136253603333032,
+                3243106800196629682,
+                3817639414895058873,
+                370385066352418782,
+            ]),
+            Fq::from_montgomery_limbs([
+                3885842475935569355,
+                6290452770433480139,
+                17167475316403260871,
+                909761545944163143,
+            ]),
+            Fq::from_montgomery_limbs([
+                6780624818668848944,
+                13512992344290497490,
+                16695967023825759122,
+                1161110208326941907,
+            ]),
+            Fq::from_montgomery_limbs([
+                9070983711378371117,
+                1045590198148468187,
+                740785624618210361,
+                1187159878444080020,
+            ]),
+            Fq::from_montgomery_limbs([
+                4047315194216577161,
+                14337577182330099658,
+                6049000580447123074,
+                462678511653214138,
+            ]),
+            Fq::from_montgomery_limbs([
+                2007336987168106181,
+                3581184212986519319,
+                4638895522117433339,
+                227362509258357568,
+            ]),
+            Fq::from_montgomery_limbs([
+                17929428916338134502,
+                7483854222107271848,
+                441307600564397802,
+                57220476794895971,
+            ]),
+            Fq::from_montgomery_limbs([
+                17715516144350650130,
+                13952544061148838050,
+                8299621610660410102,
+                542597313505658329,
+            ]),
+            Fq::from_montgomery_limbs([
+                10039074214588230537,
+                17225874116310339933,
+                333305872149355204,
+                878839935489145726,
+            ]),
+            Fq::from_montgomery_limbs([
+                13994744423917234896,
+                7905722269381792417,
+                9823516102875737330,
+                541865481076964269,
+            ]),
+            Fq::from_montgomery_limbs([
+                5557948034734328441,
+                13444120027821047092,
+                11485236124700603806,
+                754559730169847919,
+            ]),
+            Fq::from_montgomery_limbs([
+                16474553384405783674,
+                13022826540602513694,
+                5467351102883937264,
+                767261080853212999,
+            ]),
+            Fq::from_montgomery_limbs([
+                17274967192498089263,
+                6447294166444762126,
+                10164843557543280758,
+                749310086799844250,
+            ]),
+            Fq::from_montgomery_limbs([
+                12374370834186408806,
+                1139677296843824697,
+                9525550886910109504,
+                1178222660072176600,
+            ]),
+            Fq::from_montgomery_limbs([
+                4506472444944522774,
+                4674359877927828138,
+                4597093968508855138,
+                229213711695185217,
+            ]),
+            Fq::from_montgomery_limbs([
+                12375737793182976440,
+                10100461124919664204,
+                18176048987999915426,
+                617921497254381255,
+            ]),
+            Fq::from_montgomery_limbs([
+                13769570152257905868,
+                13993672279323544473,
+                10970533715793426292,
+                1145783715075097476,
+            ]),
+            Fq::from_montgomery_limbs([
+                2196943128674114194,
+                3041575971119160137,
+                15515695093829441492,
+                252757155118063577,
+            ]),
+            Fq::from_montgomery_limbs([
+                3324790366979942175,
+                7486581854375670131,
+                14576709277718376349,
+                1020791748726281035,
+            ]),
+            Fq::from_montgomery_limbs([
+                15870028624426031334,
+                18386559627288708074,
+                2646251068967993689,
+                377895941922054035,
+            ]),
+            Fq::from_montgomery_limbs([
+                9773772067228009799,
+                1609081096853075835,
+                10471789586684535713,
+                559366582078968398,
+            ]),
+            Fq::from_montgomery_limbs([
+                14818525034112927187,
+                12491405309934393820,
+                11860893357979667288,
+                203603405969994756,
+            ]),
+            Fq::from_montgomery_limbs([
+                1964537760842008461,
+                17674698497674380649,
+                5010998407146285188,
+                928703348100189289,
+            ]),
+            Fq::from_montgomery_limbs([
+                6701965398396516479,
+                12416301997597819223,
+                4966122450915055124,
+                426894576493113403,
+            ]),
+            Fq::from_montgomery_limbs([
+                4110767866877916706,
+                5002170474917562,
+                1273332490507298178,
+                726161461796784743,
+            ]),
+            Fq::from_montgomery_limbs([
+                1000228135890542938,
+                876662062254816597,
+                2235713004628313423,
+                866258958269157080,
+            ]),
+            Fq::from_montgomery_limbs([
+                13415619204200929122,
+                2697475148876958272,
+                3932208877527835844,
+                770712207153313347,
+            ]),
+            Fq::from_montgomery_limbs([
+                17418268489664454698,
+                12317461129437011641,
+                11741597020391794218,
+                1252592010880968046,
+            ]),
+            Fq::from_montgomery_limbs([
+                14563330285634226616,
+                4737999847638119887,
+                10537635597759128957,
+                1255782240964592911,
+            ]),
+            Fq::from_montgomery_limbs([
+                6150264126257611150,
+                5405073569851787839,
+                3068977724244622813,
+                859832631719049657,
+            ]),
+            Fq::from_montgomery_limbs([
+                13146346863521004100,
+                9912709959489079725,
+                4106820144161548178,
+                590007302244918174,
+            ]),
+            Fq::from_montgomery_limbs([
+                5494255004260489111,
+                2267470165841543430,
+                15429977064937633196,
+                1196927299911751763,
+            ]),
+            Fq::from_montgomery_limbs([
+                1209376565654893881,
+                6998143997377661347,
+                14392531336892571669,
+                287141588937038876,
+            ]),
+            Fq::from_montgomery_limbs([
+                9036623201747733866,
+                7851969208585380068,
+                17991842701146258420,
+                1141852079353459821,
+            ]),
+            Fq::from_montgomery_limbs([
+                11725774497445798520,
+                12558435278791308274,
+                10918873965371702207,
+                1020191039559842981,
+            ]),
+            Fq::from_montgomery_limbs([
+                12185733538911289260,
+                2243656475682250913,
+                10291947070184129034,
+                1231748208954619519,
+            ]),
+            Fq::from_montgomery_limbs([
+                17767312197869957954,
+                15747421200739688357,
+                992486122187416201,
+                826734412915533354,
+            ]),
+            Fq::from_montgomery_limbs([
+                12150605904596385043,
+                2144361176751614632,
+                13243767677338420425,
+                675888943347717643,
+            ]),
+            Fq::from_montgomery_limbs([
+                17553915777020615831,
+                3683944239096887393,
+                7807596530228580049,
+                107337583835953243,
+            ]),
+            Fq::from_montgomery_limbs([
+                2304616245310680975,
+                240381198939727274,
+                17555519423208898954,
+                1091676774321623447,
+            ]),
+            Fq::from_montgomery_limbs([
+                10838166866363679491,
+                4936800406755161616,
+                1074659443560008706,
+                893280888718594412,
+            ]),
+            Fq::from_montgomery_limbs([
+                7842193024645236062,
+                8906706409604323754,
+                6015414468346818720,
+                707643255359745877,
+            ]),
+            Fq::from_montgomery_limbs([
+                11635682754019229142,
+                8484509826286502560,
+                13870628241202403749,
+                498772233478649311,
+            ]),
+            Fq::from_montgomery_limbs([
+                8089511579137133149,
+                14359094057521270690,
+                5835687326705287690,
+                560839880247656621,
+            ]),
+            Fq::from_montgomery_limbs([
+                13368436013210466052,
+                17764565974282823813,
+                7110036355781304693,
+                544584962832259053,
+            ]),
+            Fq::from_montgomery_limbs([
+                15840165577622325121,
+                2338072694640957509,
+                14911089335437346251,
+                1305770102918811458,
+            ]),
+            Fq::from_montgomery_limbs([
+                2390639181874251064,
+                7806318007461601516,
+                15974135142844401344,
+                556292093354895987,
+            ]),
+            Fq::from_montgomery_limbs([
+                1503044036575391821,
+                8559773890825231033,
+                8093716135957781726,
+                1075064868181425041,
+            ]),
+            Fq::from_montgomery_limbs([
+                8881636074861515334,
+                16971864762228050119,
+                14912444979839512584,
+                1050574831993131918,
+            ]),
+            Fq::from_montgomery_limbs([
+                897453993573122968,
+                891248366897220412,
+                16337664839971024766,
+                624845540590619106,
+            ]),
+            Fq::from_montgomery_limbs([
+                17195709540658171536,
+                6953077068899120390,
+                17953629835061038472,
+                969378014361899349,
+            ]),
+            Fq::from_montgomery_limbs([
+                3075523520099285817,
+                7432230004888774449,
+                12823102379149983782,
+                1267254571148145861,
+            ]),
+            Fq::from_montgomery_limbs([
+                10554030608529802480,
+                16518765970003394034,
+                16783236423434400187,
+                283657784196356436,
+            ]),
+            Fq::from_montgomery_limbs([
+                3519654943610697456,
+                4522405164113277276,
+                6246875579813705530,
+                920028040496474720,
+            ]),
+            Fq::from_montgomery_limbs([
+                3951426226565858602,
+                9354448833490837900,
+                16469061643002601268,
+                827118851460928545,
+            ]),
+            Fq::from_montgomery_limbs([
+                6772770854251864650,
+                12317613920122149103,
+                9566185926104278932,
+                1050376893642914409,
+            ]),
+            Fq::from_montgomery_limbs([
+                1544401817463304911,
+                4228901762992742329,
+                10089398304615697247,
+                1190442929555159592,
+            ]),
+            Fq::from_montgomery_limbs([
+                14788912435327295084,
+                12699881572242079784,
+                325322444307479463,
+                524460114848945459,
+            ]),
+            Fq::from_montgomery_limbs([
+                7150697979689906949,
+                5056307807709196011,
+                8202801847280441273,
+                876005345790541220,
+            ]),
+            Fq::from_montgomery_limbs([
+                12694184335484621894,
+                7274136509839311971,
+                14200243392520481807,
+                1296627968188565231,
+            ]),
+            Fq::from_montgomery_limbs([
+                15020745399138512689,
+                9727417285974698404,
+                9465434594981869006,
+                326282071028182459,
+            ]),
+            Fq::from_montgomery_limbs([
+                5621957049351111297,
+                2251855129664601464,
+                12923679586259815514,
+                910301422868031195,
+            ]),
+            Fq::from_montgomery_limbs([
+                4945392720399456086,
+                890110822888037540,
+                893552609020725410,
+                1103494516978467543,
+            ]),
+            Fq::from_montgomery_limbs([
+                10237286475066218969,
+                8756814177868767803,
+                15898546021842689711,
+                1040925219244756968,
+            ]),
+            Fq::from_montgomery_limbs([
+                9092367096910932264,
+                10387009732067741650,
+                3240565257446361123,
+                1226165379697193546,
+            ]),
+            Fq::from_montgomery_limbs([
+                15051434182914084761,
+                10382577184678628981,
+                13089479350469667435,
+                403332070874788872,
+            ]),
+            Fq::from_montgomery_limbs([
+                6861654387705964633,
+                11756691690151650846,
+                12121688883477243073,
+                252822782219080497,
+            ]),
+            Fq::from_montgomery_limbs([
+                4949268570886557763,
+                11563523901980339537,
+                11814106318757737635,
+                85741916858347648,
+            ]),
+            Fq::from_montgomery_limbs([
+                5644587539513585894,
+                17833872366663534341,
+                11099226693483816498,
+                20028537093869518,
+            ]),
+            Fq::from_montgomery_limbs([
+                2519381277858587363,
+                10177525412004643843,
+                17533152699445823803,
+                1291764756520197934,
+            ]),
+            Fq::from_montgomery_limbs([
+                8546078844347205019,
+                10570970770510041833,
+                10326424985294181507,
+                574580016078442187,
+            ]),
+            Fq::from_montgomery_limbs([
+                8300521182796282499,
+                13549357310633189653,
+                18183177906714826767,
+                298319520749695428,
+            ]),
+            Fq::from_montgomery_limbs([
+                11757280506680052233,
+                7030044504902660551,
+                427339897047903808,
+                409328965191969990,
+            ]),
+            Fq::from_montgomery_limbs([
+                11169762591301629603,
+                14241155434785674778,
+                14214779329759464933,
+                714246195519343476,
+            ]),
+            Fq::from_montgomery_limbs([
+                9795381166437232179,
+                7149562794462814197,
+                3051066708520723736,
+                773234373805250057,
+            ]),
+            Fq::from_montgomery_limbs([
+                7809665156955051287,
+                8251535033617495659,
+                3418660818375694799,
+                902431008598862987,
+            ]),
+            Fq::from_montgomery_limbs([
+                10896155385701335266,
+                4110912716356794331,
+                17169697323154265757,
+                1012254211329104083,
+            ]),
+            Fq::from_montgomery_limbs([
+                12926270957126649798,
+                15968466138238930747,
+                7766869288816671031,
+                1191639223512308033,
+            ]),
+            Fq::from_montgomery_limbs([
+                2479150818668296038,
+                6007151314008483287,
+                17450854485892562424,
+                784615331798743658,
+            ]),
+            Fq::from_montgomery_limbs([
+                307762124088866841,
+                16308977564677907925,
+                2262877863526767017,
+                1290146651175359973,
+            ]),
+            Fq::from_montgomery_limbs([
+                3999689130555933399,
+                307498220126890663,
+                384952807441896545,
+                480511297016541874,
+            ]),
+            Fq::from_montgomery_limbs([
+                18017811366017227173,
+                11662567250589256261,
+                4500591778363556583,
+                107642980974616256,
+            ]),
+            Fq::from_montgomery_limbs([
+                17406078693502483133,
+                8395289305960802750,
+                9854624387288418030,
+                1338191751493137241,
+            ]),
+            Fq::from_montgomery_limbs([
+                6101348377755134959,
+                13243194086882249315,
+                9690102296941934600,
+                1282865172905194573,
+            ]),
+            Fq::from_montgomery_limbs([
+                5553422653728566253,
+                6593414377739857724,
+                14225308984410372641,
+                1169423308438684137,
+            ]),
+            Fq::from_montgomery_limbs([
+                7716684660227612757,
+                5727949165420567415,
+                17964518084902364632,
+                690291247948735549,
+            ]),
+            Fq::from_montgomery_limbs([
+                11675440858481507506,
+                11971188579559933214,
+                11696923132670151670,
+                829529418636862465,
+            ]),
+            Fq::from_montgomery_limbs([
+                6454172679374638143,
+                10202454924601581086,
+                14242788652183702284,
+                790617408810727808,
+            ]),
+            Fq::from_montgomery_limbs([
+                15793017941619541997,
+                17278387000694507369,
+                3019462584271047193,
+                743202473763880468,
+            ]),
+            Fq::from_montgomery_limbs([
+                9956112637888170293,
+                10235597154092376856,
+                17733142865609797790,
+                1273455599577934305,
+            ]),
+            Fq::from_montgomery_limbs([
+                11725074504726050928,
+                7413758701496886043,
+                1004762294453863489,
+                785695714627843299,
+            ]),
+            Fq::from_montgomery_limbs([
+                9187213303400149948,
+                4120431036250948774,
+                17947563018268343713,
+                303009287652985279,
+            ]),
+            Fq::from_montgomery_limbs([
+                7964356929181371835,
+                1044918194880721623,
+                1264285934577489872,
+                292265167569807914,
+            ]),
+            Fq::from_montgomery_limbs([
+                3194184283254429028,
+                11205175090553050571,
+                10727401158175135459,
+                1248886034616174580,
+            ]),
+            Fq::from_montgomery_limbs([
+                10160609173230371284,
+                8341853208674603898,
+                17627467446932909163,
+                260727190893709687,
+            ]),
+            Fq::from_montgomery_limbs([
+                9307377093279671882,
+                15649060430959744736,
+                5311723868476903602,
+                1334842901082453722,
+            ]),
+            Fq::from_montgomery_limbs([
+                10281748531085381660,
+                10906722202614004542,
+                1246589468832500677,
+                98251264020833759,
+            ]),
+            Fq::from_montgomery_limbs([
+                16707600422153690565,
+                2685022744121265881,
+                993617727461587267,
+                45309598439864562,
+            ]),
+            Fq::from_montgomery_limbs([
+                11398160583904900546,
+                14167863965885759655,
+                9357516733667380248,
+                871237338578190232,
+            ]),
+            Fq::from_montgomery_limbs([
+                10324803944591393462,
+                16081468242337403858,
+                17653190278294199792,
+                817615344368541205,
+            ]),
+            Fq::from_montgomery_limbs([
+                10078560532409985756,
+                369108166092716799,
+                10598039427274830031,
+                613350343077703039,
+            ]),
+            Fq::from_montgomery_limbs([
+                3815715013920490038,
+                2849971052564995375,
+                13479457344834338081,
+                697517500401416360,
+            ]),
+            Fq::from_montgomery_limbs([
+                13085452447653526762,
+                17030136041282327717,
+                7636855767105988951,
+                260197739076534919,
+            ]),
+            Fq::from_montgomery_limbs([
+                18039009004857900464,
+                1027523404822120012,
+                6557004932614512633,
+                435150372913663173,
+            ]),
+            Fq::from_montgomery_limbs([
+                16398574235188529521,
+                6633247165295643327,
+                3278960938857256370,
+                785556797904405322,
+            ]),
+            Fq::from_montgomery_limbs([
+                1076645960194764980,
+                2763063242731021164,
+                1010310097256868895,
+                744401100305439527,
+            ]),
+            Fq::from_montgomery_limbs([
+                9475447019346484915,
+                14429410658458185535,
+                16893809326010597381,
+                732508200942747032,
+            ]),
+            Fq::from_montgomery_limbs([
+                17595029985720269389,
+                5892172001479435482,
+                6470358691020955523,
+                833351263866520746,
+            ]),
+            Fq::from_montgomery_limbs([
+                122576347809143800,
+                17547802679726420023,
+                1801377745709793417,
+                949311804750764120,
+            ]),
+            Fq::from_montgomery_limbs([
+                7417903729092420921,
+                14981898405356368111,
+                2138015253016126967,
+                48563321086200485,
+            ]),
+            Fq::from_montgomery_limbs([
+                13697757987985061388,
+                5329253073507580039,
+                17128057972661274330,
+                699271280912879552,
+            ]),
+            Fq::from_montgomery_limbs([
+                5019288713120778439,
+                9429934024291724873,
+                695101296474588774,
+                119484046309511479,
+            ]),
+            Fq::from_montgomery_limbs([
+                10818998576237425362,
+                869539061237601415,
+                9744697835136803008,
+                1757905248317426,
+            ]),
+            Fq::from_montgomery_limbs([
+                17968667798961314562,
+                10784087634415968351,
+                11385624719523746928,
+                97700248594743134,
+            ]),
+            Fq::from_montgomery_limbs([
+                10075052692702638741,
+                13882037271436928084,
+                6485682584177778736,
+                360552342260587274,
+            ]),
+            Fq::from_montgomery_limbs([
+                2669939715337209983,
+                1808543926517410955,
+                14941980787509676175,
+                137669198873814337,
+            ]),
+            Fq::from_montgomery_limbs([
+                370214768554922627,
+                17175520225453953770,
+                6295338447614913852,
+                1081150374591141506,
+            ]),
+            Fq::from_montgomery_limbs([
+                16028214772966652554,
+                12944513587194351892,
+                4106031729632003813,
+                1035139466170949187,
+            ]),
+            Fq::from_montgomery_limbs([
+                16461501196552054082,
+                15457346693922247911,
+                16022796690416234833,
+                1127168707844605022,
+            ]),
+            Fq::from_montgomery_limbs([
+                17471282280149531601,
+                5101469746074446602,
+                15041356836743849648,
+                1124671490502090242,
+            ]),
+            Fq::from_montgomery_limbs([
+                17077394715238237186,
+                15545972055126468349,
+                6588235702542678480,
+                752177452808068301,
+            ]),
+            Fq::from_montgomery_limbs([
+                12381140460001950023,
+                12083223807887682515,
+                16251853011156503082,
+                489676668101372202,
+            ]),
+            Fq::from_montgomery_limbs([
+                17200183984843750665,
+                2028097276071075838,
+                17913237367685394648,
+                1252269216083423030,
+            ]),
+            Fq::from_montgomery_limbs([
+                7366741305078084000,
+                1111335038069625743,
+                11443313191501760620,
+                190236093949494927,
+            ]),
+            Fq::from_montgomery_limbs([
+                3671793220278916702,
+                13928035284478704463,
+                1082983169185428496,
+                1040531003203896463,
+            ]),
+            Fq::from_montgomery_limbs([
+                14698116244745472556,
+                11994109291184602166,
+                13648748261216929879,
+                746660446621351775,
+            ]),
+            Fq::from_montgomery_limbs([
+                12252268753330149884,
+                5794569093069856189,
+                6790916162554334905,
+                1021108732758502882,
+            ]),
+            Fq::from_montgomery_limbs([
+                17270044437042924076,
+                2543400215604677186,
+                12037491684650199835,
+                1099148412870234326,
+            ]),
+            Fq::from_montgomery_limbs([
+                17550385246265407850,
+                13485790421343192664,
+                3499646038083680496,
+                490530777888018773,
+            ]),
+            Fq::from_montgomery_limbs([
+                9723995454705877207,
+                7887549235432773248,
+                8011402499493199647,
+                485319846670236834,
+            ]),
+            Fq::from_montgomery_limbs([
+                3769082999922881933,
+                15758188753945619855,
+                13082107134081931506,
+                216595937788579422,
+            ]),
+            Fq::from_montgomery_limbs([
+                2155626209992585500,
+                1975522998651792572,
+                17129093413667432527,
+                216783019711216660,
+            ]),
+            Fq::from_montgomery_limbs([
+                11483017294273401746,
+                15717977547699424829,
+                14449563740577490611,
+                855824871961837072,
+            ]),
+            Fq::from_montgomery_limbs([
+                8095258902715915979,
+                11261855446482033819,
+                13478188824740707396,
+                941962879008091765,
+            ]),
+            Fq::from_montgomery_limbs([
+                1623997943264610439,
+                4485513811324151004,
+                9224318097036134407,
+                473892343366829304,
+            ]),
+            Fq::from_montgomery_limbs([
+                9257289465517611800,
+                110763257678687409,
+                10079961679715528071,
+                125672406793229922,
+            ]),
+            Fq::from_montgomery_limbs([
+                16300233779528182210,
+                3514300945615353356,
+                9645699519401137520,
+                630380213588051005,
+            ]),
+            Fq::from_montgomery_limbs([
+                8870964956303907716,
+                6990840493213147007,
+                2632318058180559650,
+                605828846004692406,
+            ]),
+            Fq::from_montgomery_limbs([
+                13411746425707948116,
+                11290104949809205702,
+                7485032776306499071,
+                1174073793832807870,
+            ]),
+            Fq::from_montgomery_limbs([
+                5496416497433556493,
+                14438306175409207147,
+                13412823648429726697,
+                1161654535454420313,
+            ]),
+            Fq::from_montgomery_limbs([
+                2862262368579278750,
+                5444046194847689667,
+                15660550862004545755,
+                761468784212067811,
+            ]),
+            Fq::from_montgomery_limbs([
+                7521171437052030900,
+                13640360043123114580,
+                4937139538151986907,
+                875781832310395879,
+            ]),
+            Fq::from_montgomery_limbs([
+                8046928385131140591,
+                683744451617834815,
+                3844880444390081763,
+                1294178324529286601,
+            ]),
+            Fq::from_montgomery_limbs([
+                2479845845592238952,
+                17998379084792470824,
+                15407689442680482156,
+                74854383663144539,
+            ]),
+            Fq::from_montgomery_limbs([
+                13689400820242767038,
+                10069032464367160338,
+                8854182768192179728,
+                1075367220587672408,
+            ]),
+            Fq::from_montgomery_limbs([
+                14859008354288790352,
+                5323908788556351930,
+                14509026946370198406,
+                199242193531092328,
+            ]),
+            Fq::from_montgomery_limbs([
+                1983943003336355831,
+                10498051981290895368,
+                1187996732757400199,
+                1093336407020832932,
+            ]),
+            Fq::from_montgomery_limbs([
+                13617179966199312332,
+                14588245585876090027,
+                1065204736924757446,
+                1051981197505205092,
+            ]),
+            Fq::from_montgomery_limbs([
+                16227402298459767326,
+                2709010367505298450,
+                13043632440669221238,
+                906302415300794913,
+            ]),
+            Fq::from_montgomery_limbs([
+                7109406358139948443,
+                6933456992693634112,
+                5426946873383909833,
+                147657711044688849,
+            ]),
+            Fq::from_montgomery_limbs([
+                1730015613734966281,
+                17404715550462198558,
+                3569390828141576242,
+                1021378291655117049,
+            ]),
+            Fq::from_montgomery_limbs([
+                13968296681432033708,
+                270234871758770182,
+                18440993384472258755,
+                1231714196286326507,
+            ]),
+            Fq::from_montgomery_limbs([
+                15899408724233917752,
+                11728691763666634605,
+                834793021505671045,
+                247668966956696555,
+            ]),
+            Fq::from_montgomery_limbs([
+                5312856287408396058,
+                10600227859925785644,
+                5820140721877775584,
+                639816590223949814,
+            ]),
+            Fq::from_montgomery_limbs([
+                10391849556271316204,
+                3566085163423599263,
+                11081354228042834239,
+                180150733996052756,
+            ]),
+            Fq::from_montgomery_limbs([
+                12006377166549237724,
+                13776384517799346846,
+                16167877095502815957,
+                305791789936495614,
+            ]),
+            Fq::from_montgomery_limbs([
+                1353431701173996053,
+                18046102960556117284,
+                11744013487600773722,
+                219952430035373064,
+            ]),
+            Fq::from_montgomery_limbs([
+                7611834493196733477,
+                15801884844607869406,
+                17671064493144098705,
+                451131518011365605,
+            ]),
+            Fq::from_montgomery_limbs([
+                16008501611520063228,
+                13243316316417604236,
+                13901420612789454456,
+                173469789400607890,
+            ]),
+            Fq::from_montgomery_limbs([
+                11886731143354371729,
+                15595248542459958348,
+                7890135382604143608,
+                113126374846042386,
+            ]),
+            Fq::from_montgomery_limbs([
+                12140362931202246333,
+                15044226727982835952,
+                889815744687143356,
+                646407061701596664,
+            ]),
+            Fq::from_montgomery_limbs([
+                933575174262594656,
+                2649587783755611522,
+                1087494146245046132,
+                544506823489184244,
+            ]),
+            Fq::from_montgomery_limbs([
+                11239709393681430376,
+                17762760672417780515,
+                12111910717293742461,
+                1094335200390732636,
+            ]),
+            Fq::from_montgomery_limbs([
+                15255466252876643788,
+                9633408739300907658,
+                13771149012491987922,
+                877409981818849502,
+            ]),
+            Fq::from_montgomery_limbs([
+                4281994716395646561,
+                7198034936627595887,
+                5242966329463899452,
+                816107255016792459,
+            ]),
+            Fq::from_montgomery_limbs([
+                18235246734599651010,
+                18308008725408267262,
+                4220665386477441365,
+                220933650600361006,
+            ]),
+            Fq::from_montgomery_limbs([
+                4093508337190440413,
+                9385126894672382349,
+                10192192403444403065,
+                1312086064141888838,
+            ]),
+            Fq::from_montgomery_limbs([
+                9404692962872552358,
+                12318106500717315419,
+                11449181954529623210,
+                1153334782164452679,
+            ]),
+            Fq::from_montgomery_limbs([
+                7697549719861681200,
+                16476013579695961,
+                14247997318089131995,
+                1107515721127382066,
+            ]),
+            Fq::from_montgomery_limbs([
+                6777966733996501966,
+                6129634963783303458,
+                17156427415852963479,
+                475069810086529318,
+            ]),
+            Fq::from_montgomery_limbs([
+                2459717063304928110,
+                10350063068778582549,
+                15882308563015790898,
+                215287049550886544,
+            ]),
+        ]),
+        mds: MdsMatrix::<3, 2, 9, 4>::new_from_known([
+            Fq::from_montgomery_limbs([
+                15302903146332116305,
+                8895355854553175376,
+                548696419140371791,
+                319366418254432479,
+            ]),
+            Fq::from_montgomery_limbs([
+                16270238816294404093,
+                17510165790819614716,
+                15988650198697570299,
+                575844906362867708,
+            ]),
+            Fq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+            Fq::from_montgomery_limbs([
+                16270238816294404093,
+                17510165790819614716,
+                15988650198697570299,
+                575844906362867708,
+            ]),
+            Fq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+            Fq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            Fq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+            Fq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+        ]),
+        alpha: Alpha::Inverse,
+        rounds: RoundNumbers { r_P: 63, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<2, 4>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+            ]),
+            v: Matrix::<1, 2, 2>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    16270238816294404093,
+                    17510165790819614716,
+                    15988650198697570299,
+                    575844906362867708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+            ]),
+            w: Matrix::<2, 1, 2>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    16270238816294404093,
+                    17510165790819614716,
+                    15988650198697570299,
+                    575844906362867708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<3, 9>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<3, 9>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    15302903146332116305,
+                    8895355854553175376,
+                    548696419140371791,
+                    319366418254432479,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16270238816294404093,
+                    17510165790819614716,
+                    15988650198697570299,
+                    575844906362867708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7147916296078753751,
+                    11795755565450264533,
+                    9448453213491875784,
+                    183737022913545514,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10406805428937057924,
+                    14160037477503257835,
+                    7036231764655967476,
+                    1082136417579874198,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<3, 9>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    4383832024780107759,
+                    7223259317565124429,
+                    5707462847421561422,
+                    885057472408297272,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6746251504312660021,
+                    9699181025413837338,
+                    15260914163925889303,
+                    35388324151454979,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5008847210566114882,
+                    8801394942975925486,
+                    18175922902442652272,
+                    916924432575702892,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6746251504312660021,
+                    9699181025413837338,
+                    15260914163925889303,
+                    35388324151454979,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15450583684112934333,
+                    18216376800760520619,
+                    4209613945072000948,
+                    155813436852978744,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9834172736316928124,
+                    189466111752828136,
+                    13335900722961176546,
+                    530999546582786085,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5008847210566114882,
+                    8801394942975925486,
+                    18175922902442652272,
+                    916924432575702892,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9834172736316928124,
+                    189466111752828136,
+                    13335900722961176546,
+                    530999546582786085,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11796616273943571364,
+                    15542379905239984210,
+                    1578039792447016471,
+                    947030710751083834,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<2, 4>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17242594123341362780,
+                    10420431729582929402,
+                    13098868289430565627,
+                    261978409307343683,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4962966789362289472,
+                    13671436036280945585,
+                    17033622650389008176,
+                    591212102933547967,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4962966789362289472,
+                    13671436036280945585,
+                    17033622650389008176,
+                    591212102933547967,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2148639234720789121,
+                    15880825911856067065,
+                    16042722653708983750,
+                    635825847167915836,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                15302903146332116305,
+                8895355854553175376,
+                548696419140371791,
+                319366418254432479,
+            ]),
+            M_i: Matrix::<3, 3, 9>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15391431874758848825,
+                    9930478514863663979,
+                    8519746724640521430,
+                    487030469961112469,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5894283800168662667,
+                    9909062195149379308,
+                    15234922836737432538,
+                    986746674052943573,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5894283800168662667,
+                    9909062195149379308,
+                    15234922836737432538,
+                    986746674052943573,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3821451755152819436,
+                    7560426044084710112,
+                    5334312678325579564,
+                    33407578512545524,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16270238816294404093,
+                        17510165790819614716,
+                        15988650198697570299,
+                        575844906362867708,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2093244959304135473,
+                        7921656493611837028,
+                        14184576022206158435,
+                        729731999227928846,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18204910156218979669,
+                        16293041589642941780,
+                        9975069610392864084,
+                        1088801882579738168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3072474957567996411,
+                        7632850653899625657,
+                        6867324839353272651,
+                        1129105644996777990,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11749409346063797401,
+                        13847779958722295463,
+                        5230051856842938218,
+                        809528761888529318,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16538317435082867476,
+                        140673227072662684,
+                        4917458456429054520,
+                        951347002169384040,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8049501819868988169,
+                        4640840621228515661,
+                        5281323386686467274,
+                        1217317166531385469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15861181624207003323,
+                        10871450011713172103,
+                        4492382029642778046,
+                        495041570025076744,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        491531644685882947,
+                        16236469504155238438,
+                        13405996466460073108,
+                        191442324574972544,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14234832595612208455,
+                        16711171142020806901,
+                        9818085755212544560,
+                        433758843793119660,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8692243294689566788,
+                        17131754811489911037,
+                        16082421295010554115,
+                        245109642615998458,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12752747264486126419,
+                        12936758402746978248,
+                        7901753820298393242,
+                        1086817638717069074,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        716899015486837574,
+                        12929955716143301332,
+                        6296181173922398814,
+                        95630164573893864,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10771615223994036552,
+                        12477611863199363886,
+                        5019464732542008653,
+                        260172239809303582,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2083749357590716775,
+                        7802489018239475310,
+                        4719252491703553508,
+                        331544147020630716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11839081110669849836,
+                        16458332811546812068,
+                        4108523134706353886,
+                        1014020374083768583,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11143510974855252250,
+                        1906425947937085984,
+                        2407368748249553491,
+                        504368299222388920,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8041950831366606113,
+                        14611771049638947187,
+                        11221299042990583579,
+                        744635656555856530,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15987773674750471853,
+                        7582780421302456968,
+                        3513070142855093124,
+                        449192997718482772,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1698389311466971615,
+                        4208825745762883192,
+                        17737380562723619127,
+                        1023230515994865951,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15719775543772287808,
+                        4711976878746234330,
+                        9616083067266331949,
+                        1291758636403773818,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11511319411419341690,
+                        4611804125723845050,
+                        17905923766281011346,
+                        701498562864789906,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6775959117219461845,
+                        7248217303083744152,
+                        8323273847079805491,
+                        1272121734883668680,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15960231410259949054,
+                        831075562495169556,
+                        666337526106305827,
+                        827994946738712632,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3075648154561933440,
+                        4787490504369527279,
+                        2103456873768609578,
+                        930535653041788534,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13632064958207562507,
+                        2403612539570381736,
+                        9671114344743639907,
+                        554518676653218341,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16017511834618514656,
+                        10675130055946071909,
+                        4737919029540133580,
+                        682111021257012783,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4054052073615595653,
+                        15900491953977945207,
+                        9657244812600116702,
+                        746793989576728670,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11427159084253523216,
+                        8137374149736453962,
+                        493707633722255071,
+                        574786622341431128,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3016813624761290833,
+                        9410383431703917657,
+                        207322790806709582,
+                        604735846059941483,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13182715603850996122,
+                        1385581621557644096,
+                        15928797776098706943,
+                        1247128249672542744,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12343557143080639521,
+                        10608577464123341465,
+                        8700628699767163855,
+                        822798305869360351,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15640932130097417474,
+                        2198144264546084253,
+                        15157422365823566225,
+                        162345305798039707,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15379897571473551238,
+                        13605062526804796454,
+                        1262497433072300524,
+                        325397323402951460,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11985517729662602408,
+                        11378058393967136140,
+                        10784471266494716066,
+                        355758022531067864,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15749607349459966392,
+                        10347221607350790242,
+                        16868641356169832097,
+                        9481912848277140,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5070404887673962603,
+                        7505346455783472838,
+                        11581801124979799833,
+                        162417281360137989,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14359230556911256253,
+                        4739001612305070282,
+                        615167045189603842,
+                        893440173159515387,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6892861075705566142,
+                        16405352091222855583,
+                        9442037024015442096,
+                        943715695188578421,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2681738006276381668,
+                        13590338127726729778,
+                        9006319668283998389,
+                        571100162557721724,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7926076457258912989,
+                        15568541251154450028,
+                        15222738110907421829,
+                        194241141418124411,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3900695082401480226,
+                        13241392216815987825,
+                        6715445016875356020,
+                        559176536870193173,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4273472679882108688,
+                        7043568940286842511,
+                        9096453621609771899,
+                        490785749945503317,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2078179201499171590,
+                        16327227019578678368,
+                        17980150213785344661,
+                        1297383831585962991,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14728670056946621521,
+                        17042639142917167694,
+                        2971308019248556661,
+                        314387788586761162,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14126190491907727041,
+                        8208403354023925517,
+                        4660262216078686939,
+                        971808842720812284,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12606246547610594540,
+                        9049616062023318605,
+                        11742190485797839027,
+                        90317661102003606,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7135240500841828540,
+                        8166637984141883895,
+                        4762161495768731224,
+                        1056050894881259133,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11752411941768010421,
+                        2371987101660996229,
+                        7910923918069285785,
+                        283757372746488803,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14169661373594066352,
+                        10756814747639910216,
+                        4179402566370914468,
+                        294039297137006515,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12380990947758453459,
+                        15919689303377847053,
+                        4451172135381562238,
+                        643870172347401539,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16919934541410985772,
+                        12894445305080351094,
+                        3222976969400102992,
+                        1274426454940711344,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17739117012754232566,
+                        7855016506876230601,
+                        10199750748041585788,
+                        610234517763900211,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14969807057009266193,
+                        9743543989422659962,
+                        265344874800963549,
+                        193281162476465682,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9334899116745497567,
+                        1443910720530394642,
+                        15242704534506362012,
+                        557844541838643008,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12596150337841453197,
+                        17941854592742713998,
+                        9536226961205720981,
+                        193378317632915294,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2145847197367443746,
+                        3494461491303192245,
+                        8559536859052153255,
+                        188641306996153597,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6619736277081498517,
+                        16354589870583930111,
+                        5727794428272011445,
+                        472934899434235719,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15229354881950810050,
+                        13117262548706587,
+                        18434975406822541359,
+                        430449497798843798,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4585061371183060684,
+                        14866599352008180181,
+                        17851236730068556506,
+                        483368166519918339,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16121753970412208543,
+                        9309799407998260359,
+                        13316268079574695590,
+                        1332560915242838761,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5320180137128926885,
+                        4585099896596608821,
+                        4959026927695406777,
+                        12672238118064834,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10187411999593275144,
+                        11818295958457531453,
+                        10171627801723943129,
+                        134096185666094551,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13679602049257994748,
+                        5087190971181287881,
+                        11477344382740453175,
+                        1280909811145666249,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6113723698667828259,
+                        18153070584833025317,
+                        408206990767238153,
+                        150618847611618392,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13428266140945152032,
+                        4544748749779003102,
+                        16110596387649263088,
+                        1230312000679957335,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5270973436782433771,
+                        9556958203689635851,
+                        13875387471200265745,
+                        1311400066186188619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2989099237946687084,
+                        11163600336391845481,
+                        3745698889462068613,
+                        296953453557752213,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4481734888804850036,
+                        8013793428476134334,
+                        2620596248359280776,
+                        42716181359228413,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4698258328750751451,
+                        14408604555777313394,
+                        11788072955162151957,
+                        36775204519824281,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3548247831672774272,
+                        12828043594462564170,
+                        15633794904336917978,
+                        59515116048088842,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1057934729068353118,
+                        11522746606896859971,
+                        12807825331307794702,
+                        973287514697113012,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16693559800753602892,
+                        14511645649265071070,
+                        6982798249335049083,
+                        981285831405373976,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        105925704364391837,
+                        2424150870089054980,
+                        13785412664879170800,
+                        597387049765564941,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2959125300829315583,
+                        10778709732640671047,
+                        7629286629589980071,
+                        699405785781787638,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3952190214724479237,
+                        1273415412790503212,
+                        4909310586834510495,
+                        761376405939090044,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14187427672632352982,
+                        11191828664797669815,
+                        6265700549007008452,
+                        311619903835811648,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10772187935075651609,
+                        13859288782816084455,
+                        10376359552467390130,
+                        321427144004085093,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17003229748092618526,
+                        8268815287600456258,
+                        1834686892799062648,
+                        250423208503327185,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12421149434382833801,
+                        15046142321287824429,
+                        16483564009145856070,
+                        418159664232307763,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3567489655749371268,
+                        4810728873889035900,
+                        7841654053411528570,
+                        1151159536600316336,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8536559511712926800,
+                        8976478954489625118,
+                        54327018776132480,
+                        870205936700792820,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        63626241259003620,
+                        3444583698538046887,
+                        13902281516871057736,
+                        1092749094470554549,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12562372939505071156,
+                        1294923829245277294,
+                        12089804137038444260,
+                        700540876777739334,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15585699608724869379,
+                        12771826263574868036,
+                        9250003458120465289,
+                        245621273644522572,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8704281285142324087,
+                        12312296297119665604,
+                        18010772023223715659,
+                        858750847484462833,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8847910361385199406,
+                        1931478510666983342,
+                        10153543273732894120,
+                        147406716953375873,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3093497871008714240,
+                        15991452684040220700,
+                        1683963820365487717,
+                        1220621576979241837,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14679728000247755444,
+                        18055522599610733624,
+                        166015854687969637,
+                        412288988978971934,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6757782048978781174,
+                        4849306741458751625,
+                        15469892228773263202,
+                        1031734907581942630,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        891051695598193903,
+                        7247190131280538385,
+                        9061112263051556977,
+                        75042899634361705,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10681183762132471971,
+                        13322253624521134182,
+                        12466161145466534510,
+                        664532322809021116,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        476484101673286868,
+                        5055692949071922965,
+                        1225140943738472097,
+                        798404152405795893,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5619903829605988050,
+                        17497938017621798814,
+                        8756475424792893694,
+                        908202464321404609,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1080314242100436438,
+                        4358202089564673029,
+                        7703015936075409585,
+                        400733265913938173,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12207939251941001038,
+                        8615079718137585757,
+                        15753548269582754209,
+                        903420268250773020,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6554959674275816171,
+                        16449990827446521804,
+                        15234241009704605254,
+                        230716697891249804,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8650357388851572911,
+                        11532735475091865297,
+                        3544009606638567269,
+                        452092796104942679,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4635452681802273084,
+                        15946249204640342229,
+                        6834510508712216872,
+                        1332244472548429799,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5419989817178064948,
+                        10252741167930972498,
+                        10238569742937070886,
+                        135067953156109002,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1733688605560569996,
+                        7725907856698496918,
+                        15671838804120224910,
+                        109589503943947673,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        229235129870372534,
+                        12028132970639051421,
+                        2162529712973771233,
+                        241336167304182443,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7342216870443135905,
+                        968592103167267111,
+                        15806152172419222364,
+                        420882027522999514,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        459886786617141712,
+                        13899223382379346632,
+                        13471569285345449369,
+                        308985392784240846,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4149757398847100850,
+                        11796066240077449462,
+                        14178845474773342209,
+                        404730045106274723,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8334005851062603412,
+                        13281076055032989749,
+                        2089530852214058429,
+                        146318260001300359,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14046990718790246597,
+                        4020273006923332256,
+                        11341486260598615968,
+                        374388459825773017,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12630481326118932269,
+                        10540628434474835870,
+                        17389619842122877967,
+                        472723436285947761,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11605734349890868706,
+                        8063629953147006684,
+                        6314413701553773585,
+                        19136894277328557,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5223860283414484703,
+                        12699067671369311916,
+                        17101324254368327618,
+                        1315057941713297938,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10511814421135738929,
+                        689163883936683576,
+                        13144799739898444726,
+                        1254385320001948306,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18097301183929899706,
+                        5269454856983152055,
+                        10337009110961153482,
+                        1087908483083093873,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13547469898305966275,
+                        12271437353754913740,
+                        5458305043932595842,
+                        1239363366927142679,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8514731607072567648,
+                        6820380235034272511,
+                        8459613057105713961,
+                        780876022796629894,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16896236415275630826,
+                        9062602179843564524,
+                        17095118852270980700,
+                        108962603047232171,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3792593112785350251,
+                        18193431463055824631,
+                        12815474962538970748,
+                        93900883582203912,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4228996660927532626,
+                        13546897409164801454,
+                        266722429599023911,
+                        441026779079599106,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14002478139702405979,
+                        17330340067007293374,
+                        2600979741014449855,
+                        223757755879830692,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4108821250663969440,
+                        3478450969298221541,
+                        3506428813126434515,
+                        708453142427433409,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16967135163168414430,
+                        5261985225511111181,
+                        11191123064818024537,
+                        489835677073672825,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1058830600023748251,
+                        11414115921690914660,
+                        12746015706368163795,
+                        1120183488456547751,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10284113819603330607,
+                        9636331667355125770,
+                        6506500109805759695,
+                        1277088539591237294,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14669222199291923853,
+                        12219550229362504537,
+                        14305517368258174557,
+                        122986034462608639,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3464197146680824658,
+                        12132294245401374501,
+                        3912393193119060917,
+                        1298022533492863966,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17992281905302645845,
+                        14244948724826152685,
+                        10454381501492293843,
+                        868731802129146641,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8642100261224665008,
+                        8494090274532395705,
+                        13679074398766134192,
+                        1006691588319042362,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7147916296078753751,
+                        11795755565450264533,
+                        9448453213491875784,
+                        183737022913545514,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10406805428937057924,
+                        14160037477503257835,
+                        7036231764655967476,
+                        1082136417579874198,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18050286569012579189,
+                        12701735779500542307,
+                        16028299840828156173,
+                        889670182449753716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17537805078915517156,
+                        3881567050914191905,
+                        15320666583506005360,
+                        34817918541568626,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        639651884569206475,
+                        3208454571154516477,
+                        5589814573911893755,
+                        812188059462663385,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6035414598134387451,
+                        221157594769622514,
+                        1246867853953039854,
+                        865666459554075403,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10986953500964328689,
+                        4552354314624673085,
+                        10130711827018722177,
+                        726464205504256466,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14592704253024955499,
+                        4367720503141755092,
+                        3264507028823567377,
+                        503642346381621716,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6929349916420721052,
+                        7263465717590274222,
+                        16428445137775857828,
+                        783617578238482410,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1105466080997706678,
+                        15638892732603802885,
+                        15163494631828844812,
+                        1265995546038282468,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12695614650926966465,
+                        7645153902251269333,
+                        15007858904354383754,
+                        303173888887641556,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13532111267254117566,
+                        453207663850979705,
+                        1118439931840184625,
+                        1109223913114108441,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1127605964966272093,
+                        459071588792078574,
+                        17569200668490318799,
+                        556567547339769587,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        743383751775379478,
+                        2152757563916176356,
+                        18215266305796881375,
+                        613050128242856475,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7479292154348380014,
+                        12505834466797528681,
+                        7061175132070981799,
+                        1037799745298480638,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4733881782580367683,
+                        13385554219210821909,
+                        11595152338390400937,
+                        1286877367267536722,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10482398298648703137,
+                        13154284141257785070,
+                        12900892582326783232,
+                        1312370380898727254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7694076120856540521,
+                        15146007605344947747,
+                        3193830018416013595,
+                        78000754372621570,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5244970134326960239,
+                        15516929113764341255,
+                        17564742414096943825,
+                        565809721348112173,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5301582977685139465,
+                        1069505112975002608,
+                        16894882112854195663,
+                        1006960914609031286,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        933807009911564028,
+                        17655717523721777752,
+                        659854032695183391,
+                        696948171193839726,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14431054331145497962,
+                        17304755953697263142,
+                        1213662370814867240,
+                        406071929554987935,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12663120994246797343,
+                        2294330459533986298,
+                        12312665243517552316,
+                        1162434858386655769,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14926635415117511105,
+                        17764138882091983380,
+                        9845162467394310721,
+                        365262529860339315,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14453117806705169688,
+                        15371763008771329739,
+                        859830826361086373,
+                        695666911485789237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4372294882149658880,
+                        8953368466178039118,
+                        7175551968845157174,
+                        1296799522059563299,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10743785724983518030,
+                        11011793084410944909,
+                        4710170432674399868,
+                        872985210632843618,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17034928244178709962,
+                        7064630411064620365,
+                        11395663509669588708,
+                        435858474778976283,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        376125808430990068,
+                        3949770427712474479,
+                        1831829189608085506,
+                        1033600417294508604,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10474175416970714387,
+                        7638666470072040137,
+                        15323998975775188709,
+                        499861799254400548,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1311503503152331198,
+                        10444356300795820152,
+                        14006643509954394380,
+                        360275028297029580,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8848990081142010576,
+                        3767709270230096287,
+                        3203373808160299103,
+                        388150987057922534,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10527714796170242821,
+                        10614707629823438759,
+                        2166835449751899854,
+                        826442630247846539,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6013609006746740436,
+                        17829255150171580013,
+                        4865370498689675602,
+                        631806865209492278,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15407698184224138641,
+                        13892993523782417432,
+                        11074522626536989473,
+                        1282147673049091223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13566156582579433121,
+                        10666124267110644787,
+                        17585510383615080517,
+                        460461758314188289,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        242069913640544639,
+                        8584725967717195423,
+                        8673969491922319906,
+                        906855204727712918,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7507622872789915132,
+                        8838834878197495556,
+                        4143887577251148647,
+                        147314013318059527,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        739343839918695474,
+                        15137511284934501835,
+                        8206744331706291412,
+                        460517726754831566,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5332098194526476884,
+                        17462053065946204764,
+                        17109918215020530224,
+                        45500621783055396,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7181843829067274762,
+                        93170180385149654,
+                        16601265500332534068,
+                        692920224266685092,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17373541931105532396,
+                        11757401236546761356,
+                        8986928677878657520,
+                        855377794858428568,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16770152912401058713,
+                        7784323106136704842,
+                        3166414142982631778,
+                        252798645948426673,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9867428128375842792,
+                        6330871821464522409,
+                        2509737650104409073,
+                        87377932535113034,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9620378538351684400,
+                        16259903847898782084,
+                        14224517332394904862,
+                        248783024579596034,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15289486933546124534,
+                        5057961130890228616,
+                        5618412873763815158,
+                        1218252246195044797,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18285461524892798097,
+                        1312649631515945874,
+                        2702563113361998981,
+                        156990921411102370,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16897672275046167563,
+                        14631253948673542966,
+                        7825433188494427672,
+                        497140819371349325,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5586425496410891559,
+                        3860435103896432777,
+                        15075562942840298887,
+                        539774915240951994,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18444236515588467614,
+                        2138469830557977395,
+                        8290712504743660259,
+                        832327778452741186,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15144610075012749322,
+                        11801332079357554718,
+                        11652842602527962385,
+                        396912768209767018,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10639969620247737667,
+                        7334159324553590340,
+                        15063492084884778567,
+                        878961650630548788,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18348264072788817511,
+                        8009061068808737218,
+                        6097418426121505352,
+                        1211183153837556564,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17198860156343170394,
+                        2167538157704278478,
+                        10949270097435223366,
+                        928056824653534234,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10634763804674129031,
+                        9612181115928191305,
+                        7893421171473086290,
+                        251268211819044542,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12969986235926373418,
+                        712178654575523278,
+                        3830673788986900912,
+                        1046343437814522982,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4298621992800380154,
+                        4055578536241120435,
+                        9617612244989535309,
+                        382604375840733162,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18067399474523304446,
+                        12223555489918789755,
+                        9993950631657703109,
+                        1048484019905387459,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13058818877006642653,
+                        18414014235319212231,
+                        14027764563566307518,
+                        703825358964196766,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9830948603405623404,
+                        3341559968925646454,
+                        12259602563968066179,
+                        912923676012093495,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8043127764066461437,
+                        14803670394754936674,
+                        15225608398783219441,
+                        893330814736280447,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4386126239109851849,
+                        12096965878015338652,
+                        13672154659709754401,
+                        191338360587662586,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        938229506172670121,
+                        10427875269621448880,
+                        5596681118617214448,
+                        888537937873904937,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8207998122651656890,
+                        2877238957695703928,
+                        13475567558498484051,
+                        526954325042111246,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5503632604272343580,
+                        7179831008834220758,
+                        11157439010937887422,
+                        846327213685284559,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        392761118741628062,
+                        11139817671636712015,
+                        8960712530833917626,
+                        10737784618933277,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15986600065699221925,
+                        6712374428386093415,
+                        17193173044544174133,
+                        757842546987985236,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15931120506099130643,
+                        6052305085134746246,
+                        16225216657477550131,
+                        1208935410212143596,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1725229384163641030,
+                        7758695034627205826,
+                        5536324597477956577,
+                        919894933846446360,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7253098188202906862,
+                        8078457014000502045,
+                        2315253281734153774,
+                        214508471660559633,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14093098816530847651,
+                        10494285585065270783,
+                        838843678711724015,
+                        804356052395389442,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9854996891933576260,
+                        8489229010041566755,
+                        16389501899682150232,
+                        787357302277325315,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17647865866310225636,
+                        6696847627411926719,
+                        11975657046459379191,
+                        963541883942557605,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6735018484035071781,
+                        2716665981376605974,
+                        7634161365221451890,
+                        1248381386402555408,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2364170728072727608,
+                        9901396488803860196,
+                        16226636557741294755,
+                        65952430543177542,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15438260925717623057,
+                        14815248103773306934,
+                        11145121748785138434,
+                        590042978388473668,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11469694916447000514,
+                        6992287346596776898,
+                        3509599069055154977,
+                        965963173999052775,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1001791870677337704,
+                        11914334301763968624,
+                        13867901177510053908,
+                        312783071010740645,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6794476770655400648,
+                        10600353917117025433,
+                        2804827204846645788,
+                        566496752520092056,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14216106339577450905,
+                        13539325837182652679,
+                        11544359078761063278,
+                        1080141828905685988,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2195605167855748475,
+                        16176409727773139927,
+                        1428807828899535806,
+                        250705857422638545,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7601641451109820955,
+                        13008788507843161441,
+                        1669453976921806071,
+                        1214740967249943325,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1125900967895257331,
+                        13710413630063213052,
+                        4495746717781606359,
+                        1240469411630063390,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8423401731906958066,
+                        13092777511346619561,
+                        9654611453297401923,
+                        553783998855024561,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18193394635569668105,
+                        1173682474312430054,
+                        2159369101351328700,
+                        712705049490553852,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14645063165500899132,
+                        5568561263742256743,
+                        13071589555565052966,
+                        130224631088483406,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15807929696005891793,
+                        14574702898430858486,
+                        17170502428939323498,
+                        43708578105173093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6848672996473763845,
+                        3373268343835254539,
+                        15718959521562174948,
+                        187938891152595204,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4218764405255821042,
+                        4091412109477201495,
+                        12287873491922408968,
+                        687105813402325449,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9199788367383711070,
+                        6541734896650345620,
+                        12686515911999431902,
+                        513948789098786742,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        33137235690892033,
+                        11040688309798077305,
+                        5953392685084290898,
+                        951716624103457593,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1540625004816619023,
+                        11842605549233532587,
+                        10670118271543167025,
+                        20958115975822109,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16746347801697493994,
+                        8918235585211377723,
+                        14407803259044154547,
+                        93022018366222387,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6861089910651121795,
+                        16232643579207817149,
+                        14087623686390280159,
+                        486607913966219780,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15079100814272165202,
+                        11890680247121400073,
+                        7186512430543481058,
+                        654245097056973336,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1797893310713711284,
+                        11205343843805256950,
+                        13300000610508862655,
+                        849262290279505041,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8870390497986688558,
+                        10624203789102004534,
+                        1704578555440241260,
+                        1301933563215118114,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12841456220717206452,
+                        1775267567659386872,
+                        13362863975288726118,
+                        1286926009933159562,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7910930952590458571,
+                        16899939258006220904,
+                        13055999994508242107,
+                        416149301338434120,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18255824894990226255,
+                        6365503715401525751,
+                        3089023446686220093,
+                        1123932512518990107,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1594307102344433766,
+                        8702474593698978940,
+                        10374655058030105712,
+                        314691094511027027,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11622776107580522157,
+                        14397931103302425052,
+                        14645948804269890936,
+                        773986123929198892,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6435758678242860573,
+                        12538075728990163093,
+                        6365514185286156907,
+                        384460271218795946,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16455227108648468033,
+                        6362144679030429441,
+                        1564774056202399265,
+                        1157738167465972350,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10763385934005869460,
+                        8306530405409606140,
+                        7519113805282524427,
+                        964281840991618613,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7251744874940568470,
+                        9920385666320796343,
+                        18102102101152181163,
+                        1149623418122833341,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8821775990565393207,
+                        18112124214196452189,
+                        10310113240051296666,
+                        759112477793191914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        311896102049196032,
+                        10013532509262371602,
+                        9542016043418249504,
+                        1107971034670995867,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6684376784091263732,
+                        17673533755920091453,
+                        3224987460236313767,
+                        826515411413897125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6129611098637278345,
+                        7946695551742571185,
+                        6669028933121682030,
+                        65127409273224101,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9440011838813399754,
+                        17769037589054187845,
+                        14253493380824938234,
+                        567981038307081407,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4465648352104893225,
+                        6305454036185469619,
+                        10855685273641706379,
+                        241674110450364866,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14423891575786663488,
+                        5445856760771128751,
+                        2693542992881573876,
+                        364369614547442277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4966459005304464084,
+                        4886402758816107929,
+                        12109880751636358023,
+                        818194099284480276,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5791996560196531047,
+                        3368524468468250672,
+                        4889860902231712570,
+                        42919053164450260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16003421628905609168,
+                        2432838186115524681,
+                        9310187324606595647,
+                        520378378661505272,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5928493019322915864,
+                        14633485322215880111,
+                        8027174479888559493,
+                        687278222986117898,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2682948137764373992,
+                        6714944552048332983,
+                        9621747143303065585,
+                        1047116896229168161,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2543438514283309272,
+                        10435027919527020884,
+                        18274337424161484405,
+                        675718618924392481,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12911285021133227096,
+                        5911525470472563227,
+                        15084595064352758112,
+                        1160358402106358974,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7632282379380762635,
+                        7360346000979559630,
+                        17859427664492423713,
+                        374600696679041298,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1106640261251941633,
+                        1564921939865947084,
+                        1884832283506871754,
+                        1183286210292793216,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3427257070771211633,
+                        5357027406784115786,
+                        14910860801623203948,
+                        550871283644267213,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15130042335694033747,
+                        9136442777085127394,
+                        15886975043411800747,
+                        241278269012953738,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14470233547136014650,
+                        12050446545191081459,
+                        722589402808187717,
+                        58301218473570983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5292507127368187691,
+                        3467192191635772054,
+                        14238261994769709107,
+                        275649424183336626,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<71, 3, 213>::new_from_known([
+            Fq::from_montgomery_limbs([
+                3415800112407090504,
+                9640355504800384837,
+                11903515404172517534,
+                1345032944892553847,
+            ]),
+            Fq::from_montgomery_limbs([
+                956562501238835079,
+                8848296780948657632,
+                8944703453691795257,
+                305492603987405025,
+            ]),
+            Fq::from_montgomery_limbs([
+                14032117164349689430,
+                8999226453914200340,
+                1971378089549144750,
+                1052932304369877248,
+            ]),
+            Fq::from_montgomery_limbs([
+                2104090116540896595,
+                5327579662064149683,
+                5525041356721424043,
+                882887877464052703,
+            ]),
+            Fq::from_montgomery_limbs([
+                3824564479288028408,
+                187800732652096772,
+                12230120083640899358,
+                1332482629250990804,
+            ]),
+            Fq::from_montgomery_limbs([
+                16027382311713020983,
+                15769642244315241484,
+                3651838465479003579,
+                152242153106192931,
+            ]),
+            Fq::from_montgomery_limbs([
+                921498002606301627,
+                16761054671022055278,
+                8668766376403732835,
+                46902497749081181,
+            ]),
+            Fq::from_montgomery_limbs([
+                414601263689571040,
+                12603268818290641452,
+                707399626691578471,
+                548956439270858932,
+            ]),
+            Fq::from_montgomery_limbs([
+                17347412428407243215,
+                6386599095289052363,
+                16666868181403744928,
+                879643192370926772,
+            ]),
+            Fq::from_montgomery_limbs([
+                3334512527353618053,
+                13874539143072648379,
+                11489510723844971503,
+                597570483526452460,
+            ]),
+            Fq::from_montgomery_limbs([
+                9083424031792637464,
+                9339556884910227191,
+                7294396798843722897,
+                953372063659666028,
+            ]),
+            Fq::from_montgomery_limbs([
+                3847329834639742759,
+                6937028752438820992,
+                15769977650597294747,
+                1312556818526551944,
+            ]),
+            Fq::from_montgomery_limbs([
+                5283294861411203980,
+                3421086423052891763,
+                13891728417302242384,
+                451560900421994671,
+            ]),
+            Fq::from_montgomery_limbs([
+                17687858203073506881,
+                9053692414189110463,
+                7782323955868511351,
+                617569462694856109,
+            ]),
+            Fq::from_montgomery_limbs([
+                2045251177428000524,
+                10186583791368528715,
+                12732570239447407674,
+                1041702145787615690,
+            ]),
+            Fq::from_montgomery_limbs([
+                5999381982472945308,
+                7882499534490576956,
+                11475564012712106319,
+                858325286917446749,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16493743081789828542,
+                43778963125279818,
+                14647420888459398753,
+                81070907917856136,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5254672122122134587,
+                17735054359980208557,
+                84350868602469914,
+                710986144215722974,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12735865934739841222,
+                2651527216866524920,
+                11222978381917306657,
+                1335820949125283545,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13145816878611442989,
+                11586291073991801172,
+                12925856912389372401,
+                964085537505267120,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15065729131892205692,
+                11414373800240607298,
+                3910644560513772161,
+                732628209048077702,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4113349051169140187,
+                9024275370516124889,
+                12680040228748431184,
+                143688994339583094,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16781379772508775418,
+                2056263390814112348,
+                7396616351956242248,
+                6110754990301222,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6482726825875200131,
+                15943483184464162213,
+                4724580189000006475,
+                380617546564601260,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4930232589636416139,
+                15287823020898277769,
+                5766135552207892381,
+                1030728221564920118,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2985991773344137490,
+                2804087624644556107,
+                8426453407892848927,
+                396107416560709956,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8223303010543254182,
+                6786352876065408051,
+                15139288252442137517,
+                336902417623875507,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10005285712748710602,
+                1464653235348637075,
+                9513021363748861404,
+                858331645102591145,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11509007037892513116,
+                5359162809484784323,
+                4269044259570733120,
+                60096166799814693,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1503733533251350930,
+                17420509839205731533,
+                1788588709855636140,
+                566133874345958820,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6992265288235817006,
+                5325555891907066983,
+                14174366066821384842,
+                598426730444990265,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8294086065471328038,
+                5313891995423007897,
+                13555683026842254057,
+                1321663651887837915,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1226062269230946461,
+                17795463411252472301,
+                3423855343638404606,
+                1049430026631864389,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                663525170715565219,
+                323073107433574025,
+                17100434956063122243,
+                228458545838011735,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1485054064037199810,
+                11577062422299289444,
+                7297401606098132123,
+                781914691593330666,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11061909377659306951,
+                13048320194416152870,
+                11813102310608749604,
+                152081783771621715,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7312477838616214486,
+                2799856982395255827,
+                14507425384161269243,
+                730834550437750245,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11084249039964985836,
+                11143467816793462140,
+                9699603164779125298,
+                950250414174300387,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12194481814806845511,
+                16873123731499693189,
+                18122063518141602117,
+                1039051436564146112,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8035002700870096973,
+                5643108006028831325,
+                6022051161143917644,
+                307032352637447464,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3445651105489236075,
+                12068190339390380696,
+                3095359379041145198,
+                51699273512088153,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7955986166072741609,
+                2178529364594480994,
+                11096895172922486160,
+                52901899529768090,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5158774064809131987,
+                5871947512898995245,
+                8805764275476210740,
+                1338104758574077484,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8738697568062988007,
+                17932644770298184814,
+                17151574891009115760,
+                865334943048832829,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11255552821911697854,
+                7027526281767725360,
+                4226087676524059066,
+                16399141239367516,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13032216909492870382,
+                5245519110922590399,
+                11051533213877311997,
+                605403269728247940,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9664646127270736355,
+                8328140685378509003,
+                13469615960669934985,
+                965604130442654847,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13013106024672499319,
+                7346628054815698878,
+                13442508133682628625,
+                955323811636681307,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8894790170578190568,
+                14724864947255275820,
+                4978617624375715684,
+                31146896632636287,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6037551205735342367,
+                1003297111707717927,
+                2911593854052791500,
+                1133390192399052520,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14212526772977252211,
+                14182948443540033031,
+                9023260132264958920,
+                954464264290072625,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2949912853982890165,
+                14048819379880480494,
+                7190325968389354974,
+                1208452679413638291,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16676718276095833272,
+                2342715748200032775,
+                2279718766310074828,
+                884808974887437451,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5031132885509626300,
+                12038230871579929559,
+                16918974254782183838,
+                972181613411829177,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10542405193158621555,
+                3840903869223351793,
+                18409871357304047729,
+                1074662187099077573,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14788254150982941843,
+                14309156628217638738,
+                4364160380426210581,
+                533578677224320804,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9274428691220797080,
+                17615593194602671681,
+                4351275468442934763,
+                435583924826840783,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9807837056361232869,
+                18344503577928223200,
+                7170384435561881892,
+                241251079735187204,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12673747424702963305,
+                6535571724863040195,
+                12705076517368046880,
+                1012834786568109107,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12779890090944856262,
+                10097597146539165710,
+                15682950422210704021,
+                359941328691619924,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7563839305052475813,
+                5266845098534951763,
+                8715893594690749965,
+                976678688387270660,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14711626889645649573,
+                13707551958984359557,
+                7570616567505383492,
+                713686945153433026,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10821924965331887193,
+                12740446363456432921,
+                16778264987573634073,
+                1188623552777053251,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10656038677302938011,
+                14460150875975893880,
+                287277486069783677,
+                1309553818555241934,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13631846634102826687,
+                13209264055108990979,
+                3599804481839180559,
+                891473975869502289,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10459425211284139960,
+                16866662026534729702,
+                584002330501928569,
+                750239295813507911,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5825066965373433784,
+                2511584344276380525,
+                2478795257651815514,
+                526352070704776511,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10581465213875134378,
+                7535798267960678394,
+                5493386578755482537,
+                643202358546358160,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3709053860756457663,
+                6276976422142310252,
+                15249820266888388673,
+                1278235859728542989,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10619553304765489764,
+                3309530638640274362,
+                16497329375351335356,
+                1002665690975395672,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10529296959160016972,
+                16863716095824052937,
+                17472432804849651416,
+                947814265373918720,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16316122605901657392,
+                17331453190633624689,
+                4505216592250992781,
+                214792998899545626,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                122147083616376891,
+                10469603464956878160,
+                14843579479393544953,
+                510970427928665789,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1445650195981433568,
+                5189462701807186310,
+                4330712766046186119,
+                316757055749037439,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9269532140761108905,
+                2824029105737893746,
+                15983673022865273689,
+                1223419738868722166,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                494915343990109150,
+                8017864570657978002,
+                16785402573448731505,
+                1061288177923398170,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2384992121165115228,
+                1098246561379756984,
+                10655248630928290771,
+                641553881131669724,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11886731143354371729,
+                15595248542459958348,
+                7890135382604143608,
+                113126374846042386,
+            ]),
+            Fq::from_montgomery_limbs([
+                12140362931202246333,
+                15044226727982835952,
+                889815744687143356,
+                646407061701596664,
+            ]),
+            Fq::from_montgomery_limbs([
+                933575174262594656,
+                2649587783755611522,
+                1087494146245046132,
+                544506823489184244,
+            ]),
+            Fq::from_montgomery_limbs([
+                11239709393681430376,
+                17762760672417780515,
+                12111910717293742461,
+                1094335200390732636,
+            ]),
+            Fq::from_montgomery_limbs([
+                15255466252876643788,
+                9633408739300907658,
+                13771149012491987922,
+                877409981818849502,
+            ]),
+            Fq::from_montgomery_limbs([
+                4281994716395646561,
+                7198034936627595887,
+                5242966329463899452,
+                816107255016792459,
+            ]),
+            Fq::from_montgomery_limbs([
+                18235246734599651010,
+                18308008725408267262,
+                4220665386477441365,
+                220933650600361006,
+            ]),
+            Fq::from_montgomery_limbs([
+                4093508337190440413,
+                9385126894672382349,
+                10192192403444403065,
+                1312086064141888838,
+            ]),
+            Fq::from_montgomery_limbs([
+                9404692962872552358,
+                12318106500717315419,
+                11449181954529623210,
+                1153334782164452679,
+            ]),
+            Fq::from_montgomery_limbs([
+                7697549719861681200,
+                16476013579695961,
+                14247997318089131995,
+                1107515721127382066,
+            ]),
+            Fq::from_montgomery_limbs([
+                6777966733996501966,
+                6129634963783303458,
+                17156427415852963479,
+                475069810086529318,
+            ]),
+            Fq::from_montgomery_limbs([
+                2459717063304928110,
+                10350063068778582549,
+                15882308563015790898,
+                215287049550886544,
+            ]),
+        ]),
+    }
+}
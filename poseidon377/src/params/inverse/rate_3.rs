@@ -0,0 +1,5361 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-3 instance of Poseidon with the inverse S-box.
+pub const fn rate_3() -> PoseidonParameters<4, 3, 16, 9, 68, 4, 272, 60> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<68, 4, 272>::new_from_known([
+            Fq::from_montgomery_limbs([
+                4626289237774878460,
+                198733277357347754,
+                10849297405444480636,
+                134950219144047273,
+            ]),
+            Fq::from_montgomery_limbs([
+                7432296167523944445,
+                12517129769158703114,
+                16760167132821509271,
+                695644487310729485,
+            ]),
+            Fq::from_montgomery_limbs([
+                15383115627129162229,
+                18382988358080022552,
+                10145262553915579100,
+                1084140441936124151,
+            ]),
+            Fq::from_montgomery_limbs([
+                14619846452794389072,
+                5372794595939635403,
+                16389349572478763919,
+                370518896378759538,
+            ]),
+            Fq::from_montgomery_limbs([
+                149110649374376044,
+                11875360297174774141,
+                7370759157405563580,
+                516590369616314979,
+            ]),
+            Fq::from_montgomery_limbs([
+                15695657644873130265,
+                1346857608463653906,
+                10150251805266052222,
+                809274206094684297,
+            ]),
+            Fq::from_montgomery_limbs([
+                14635859071380082852,
+                7178613336375243988,
+                6637786169169269953,
+                810859623994326533,
+            ]),
+            Fq::from_montgomery_limbs([
+                17780163687579399854,
+                17109264805101356917,
+                13173357081187189394,
+                663862961880380969,
+            ]),
+            Fq::from_montgomery_limbs([
+                3459415487274206131,
+                16744672442660119489,
+                11749725763710436606,
+                922602937847058907,
+            ]),
+            Fq::from_montgomery_limbs([
+                18217508895597206960,
+                10265631242015213409,
+                17105380386080932395,
+                1087497955434984898,
+            ]),
+            Fq::from_montgomery_limbs([
+                6384905615110330842,
+                3254272406633651236,
+                114357602993556640,
+                1236665135601771203,
+            ]),
+            Fq::from_montgomery_limbs([
+                2673010102326699656,
+                9903636705186683475,
+                13035934920735466443,
+                184840211937199785,
+            ]),
+            Fq::from_montgomery_limbs([
+                1818027068309755722,
+                4848553927980352321,
+                12090517013431746848,
+                98879906471697068,
+            ]),
+            Fq::from_montgomery_limbs([
+                12331677156980148034,
+                17065206484944168573,
+                5028894249297055301,
+                394932347704016314,
+            ]),
+            Fq::from_montgomery_limbs([
+                100557401774046155,
+                8641571472852537998,
+                4600696733283259504,
+                262064574640147491,
+            ]),
+            Fq::from_montgomery_limbs([
+                7662225094443175420,
+                9333645449416318834,
+                14416879590042255606,
+                897006035754354627,
+            ]),
+            Fq::from_montgomery_limbs([
+                13024562031489671457,
+                18249179014652338074,
+                3233689912588683964,
+                172938494765623179,
+            ]),
+            Fq::from_montgomery_limbs([
+                17223138642912876341,
+                12001729919691578168,
+                2162677460633060572,
+                401304805107690457,
+            ]),
+            Fq::from_montgomery_limbs([
+                11273298811169214532,
+                15599765410894975704,
+                7088675650583300487,
+                1051276461074467373,
+            ]),
+            Fq::from_montgomery_limbs([
+                9779805655715134706,
+                14688702574724760453,
+                12849855964476633097,
+                950558777055352898,
+            ]),
+            Fq::from_montgomery_limbs([
+                4887815979744040069,
+                3808539017845007757,
+                792392088943653526,
+                906877723606584704,
+            ]),
+            Fq::from_montgomery_limbs([
+                1822762619645211470,
+                312804105217908830,
+                7729611693187755469,
+                757960582805462311,
+            ]),
+            Fq::from_montgomery_limbs([
+                1995959790360556813,
+                16278348632618059890,
+                18203299533922488656,
+                1264343119227927466,
+            ]),
+            Fq::from_montgomery_limbs([
+                14481042724573771990,
+                10842209467582015103,
+                3017665483814669471,
+                415822066269419009,
+            ]),
+            Fq::from_montgomery_limbs([
+                6257936008763795807,
+                5340014131993970538,
+                8935447655662247243,
+                1021200529606106881,
+            ]),
+            Fq::from_montgomery_limbs([
+                17638797620436645945,
+                9842198278615593093,
+                7952703659810255771,
+                58827884731469203,
+            ]),
+            Fq::from_montgomery_limbs([
+                13330211002828381278,
+                13680008646663050239,
+                15776056084148741786,
+                1318193441725769374,
+            ]),
+            Fq::from_montgomery_limbs([
+                9475604112498508479,
+                10211786429766266470,
+                17940596917462565325,
+                590616660469768277,
+            ]),
+            Fq::from_montgomery_limbs([
+                11646247852871488308,
+                17960153108355336957,
+                12854608157414782217,
+                234760180718908035,
+            ]),
+            Fq::from_montgomery_limbs([
+                12354640926388966830,
+                11695362229188451464,
+                7684428798871161114,
+                93001181433648692,
+            ]),
+            Fq::from_montgomery_limbs([
+                3069269206472359002,
+                17272016109947235637,
+                1377139856561032802,
+                246233215164658930,
+            ]),
+            Fq::from_montgomery_limbs([
+                12585318084620158578,
+                9840144339752192962,
+                5536591860469250781,
+                1260619928093589424,
+            ]),
+            Fq::from_montgomery_limbs([
+                2873222302191124733,
+                7397134522551293114,
+                12156925423248963621,
+                16612412614470433,
+            ]),
+            Fq::from_montgomery_limbs([
+                14272002123518825151,
+                2264492312975370941,
+                4703660250452448007,
+                1231452732359516155,
+            ]),
+            Fq::from_montgomery_limbs([
+                2833771257624511267,
+                12338378106977472261,
+                7088001599750759826,
+                1213594753348799350,
+            ]),
+            Fq::from_montgomery_limbs([
+                2664565344669489649,
+                3893787604313225365,
+                10832847506312521659,
+                62625620166168292,
+            ]),
+            Fq::from_montgomery_limbs([
+                3980147035799794304,
+                13789799583702000671,
+                6322445822719564999,
+                370079294768822237,
+            ]),
+            Fq::from_montgomery_limbs([
+                8776261066197328715,
+                7422692269932146834,
+                1253829895131792390,
+                1179682802675341033,
+            ]),
+            Fq::from_montgomery_limbs([
+                12111616947430656667,
+                3590006250984984154,
+                539609989982232439,
+                238234654689699356,
+            ]),
+            Fq::from_montgomery_limbs([
+                12158913389657440070,
+                15831945307334588116,
+                15668977288200012454,
+                1195853573560783290,
+            ]),
+            Fq::from_montgomery_limbs([
+                14663343452255098501,
+                11253738391116412638,
+                13967996904384758617,
+                1004598667384920339,
+            ]),
+            Fq::from_montgomery_limbs([
+                7015237386818506981,
+                10742903704811363587,
+                9017779131718297228,
+                612170166684501345,
+            ]),
+            Fq::from_montgomery_limbs([
+                15583484498728476141,
+                7678547032712643506,
+                6677180270134248948,
+                642976854221424273,
+            ]),
+            Fq::from_montgomery_limbs([
+                102293608795687743,
+                11947318779871723150,
+                1290102714657308754,
+                1100848668893288349,
+            ]),
+            Fq::from_montgomery_limbs([
+                4939420401181916813,
+                12818286343316970030,
+                5530637276121676306,
+                905478748040273171,
+            ]),
+            Fq::from_montgomery_limbs([
+                16345485008828615031,
+                10042089939642156561,
+                10959487432563344935,
+                613326743928396261,
+            ]),
+            Fq::from_montgomery_limbs([
+                2473522396481915572,
+                3975308398112765234,
+                8172549515762200126,
+                104967302617327238,
+            ]),
+            Fq::from_montgomery_limbs([
+                6654717443825281016,
+                2376485693725855129,
+                9924317171470958936,
+                676683999030308634,
+            ]),
+            Fq::from_montgomery_limbs([
+                10281857682077880230,
+                6479106746241232532,
+                12635287336084312569,
+                646731238956389660,
+            ]),
+            Fq::from_montgomery_limbs([
+                8154555867377492343,
+                13299345828465573604,
+                6663009170134489207,
+                520812388282785489,
+            ]),
+            Fq::from_montgomery_limbs([
+                9821808982295109787,
+                4306520531565971552,
+                11106756291360164173,
+                443341993693062457,
+            ]),
+            Fq::from_montgomery_limbs([
+                7346728336697779234,
+                6393060073739595360,
+                15779024326452035180,
+                1173148767978264407,
+            ]),
+            Fq::from_montgomery_limbs([
+                17665933708934843650,
+                12213800092957234080,
+                10395642079860748138,
+                1179096975750622224,
+            ]),
+            Fq::from_montgomery_limbs([
+                236284665704655591,
+                10966361578976690848,
+                10765806634510414388,
+                349770157361163959,
+            ]),
+            Fq::from_montgomery_limbs([
+                4003979375006672269,
+                11959005352969618075,
+                14638078248936015445,
+                629398238439096330,
+            ]),
+            Fq::from_montgomery_limbs([
+                17031740596330903400,
+                16927681819602958812,
+                551497117625335079,
+                555820271306753124,
+            ]),
+            Fq::from_montgomery_limbs([
+                2746093858907068380,
+                3376310564515890766,
+                3525721021660084015,
+                678647806671979136,
+            ]),
+            Fq::from_montgomery_limbs([
+                6944825055986865483,
+                16624935530673878143,
+                3138290892432821615,
+                751811197479544766,
+            ]),
+            Fq::from_montgomery_limbs([
+                6356697259932522697,
+                717717426000786400,
+                7162916846344364232,
+                29740175415339138,
+            ]),
+            Fq::from_montgomery_limbs([
+                14999493223469080826,
+                12976232988885168118,
+                11180919448512098792,
+                384208128613387970,
+            ]),
+            Fq::from_montgomery_limbs([
+                13065323098732236633,
+                3032115640266332706,
+                12925005393135896685,
+                879764784281544591,
+            ]),
+            Fq::from_montgomery_limbs([
+                1632979763312834243,
+                9891683597406446215,
+                12722084574389771668,
+                879934133116423642,
+            ]),
+            Fq::from_montgomery_limbs([
+                5429899202388823249,
+                16523518629045065965,
+                3669730617640042033,
+                706538975443834715,
+            ]),
+            Fq::from_montgomery_limbs([
+                4645917617398147716,
+                1779106258454111612,
+                2269327097680888790,
+                988090321873083978,
+            ]),
+            Fq::from_montgomery_limbs([
+                16132809628104236290,
+                5351212962228809334,
+                15016124329241501668,
+                576034264955525167,
+            ]),
+            Fq::from_montgomery_limbs([
+                13318384670916170620,
+                11939036318929147193,
+                14420425860929713099,
+                902251653152344133,
+            ]),
+            Fq::from_montgomery_limbs([
+                3673242515317114404,
+                2100419514066913196,
+                4414144215407122919,
+                1238234828005080144,
+            ]),
+            Fq::from_montgomery_limbs([
+                4973959701242904924,
+                2374625034968381999,
+                1054394861650048011,
+                560018640554341207,
+            ]),
+            Fq::from_montgomery_limbs([
+                12138655003002196362,
+                1464678558223729681,
+                4923394220634705471,
+                793441477271737846,
+            ]),
+            Fq::from_montgomery_limbs([
+                8206869309758812648,
+                14630758472084501782,
+                3319434460297136591,
+                214390922662722479,
+            ]),
+            Fq::from_montgomery_limbs([
+                16579831028027144857,
+                17492605300175846768,
+                3308308496348015790,
+                299937430064274866,
+            ]),
+            Fq::from_montgomery_limbs([
+                13788982474393598874,
+                10881074656103066475,
+                5538764046124830047,
+                618186119328260145,
+            ]),
+            Fq::from_montgomery_limbs([
+                1622683388503448745,
+                6673884493117145601,
+                9054982123875948512,
+                650291123635527672,
+            ]),
+            Fq::from_montgomery_limbs([
+                15708428531485557219,
+                1345512688818704375,
+                359408000899155030,
+                438179985493669506,
+            ]),
+            Fq::from_montgomery_limbs([
+                271588276137507816,
+                7631078026540787997,
+                15003877813496210826,
+                753782344915036808,
+            ]),
+            Fq::from_montgomery_limbs([
+                6525854367876136995,
+                18179975422283023095,
+                13709454405574854544,
+                465185337237457581,
+            ]),
+            Fq::from_montgomery_limbs([
+                10893005886873103055,
+                14283890592686065183,
+                16076489634652772595,
+                323524554540498902,
+            ]),
+            Fq::from_montgomery_limbs([
+                9449971818371801827,
+                14484607263159970574,
+                8362532587836533410,
+                999211971358490442,
+            ]),
+            Fq::from_montgomery_limbs([
+                16410633163541678279,
+                12134983819800173948,
+                4926220673792902014,
+                308656475491963640,
+            ]),
+            Fq::from_montgomery_limbs([
+                7925479914230617204,
+                10676927482844526493,
+                15996096720473883587,
+                691975131647163575,
+            ]),
+            Fq::from_montgomery_limbs([
+                13511286463939721335,
+                14283448463259674230,
+                11219244564568834344,
+                853571525131731523,
+            ]),
+            Fq::from_montgomery_limbs([
+                12812073522514861384,
+                12066855004919774748,
+                11976283025886723260,
+                46889088241501347,
+            ]),
+            Fq::from_montgomery_limbs([
+                4373046130429059965,
+                5589565646033144619,
+                419620089671697128,
+                1255900559051957129,
+            ]),
+            Fq::from_montgomery_limbs([
+                12969319296622317739,
+                11739952175739759676,
+                10929389432836579045,
+                1089090286227551575,
+            ]),
+            Fq::from_montgomery_limbs([
+                14515434497028048446,
+                16166375037505465243,
+                7489691704771562790,
+                837957227713353326,
+            ]),
+            Fq::from_montgomery_limbs([
+                16163087080182359363,
+                1999820035308926315,
+                6567236775654666457,
+                36538329735919271,
+            ]),
+            Fq::from_montgomery_limbs([
+                9599621907015961120,
+                2660394723135028423,
+                5899188885009308188,
+                1112951128880679177,
+            ]),
+            Fq::from_montgomery_limbs([
+                1924758269898200888,
+                13256822830503089081,
+                158612014944447098,
+                539742772078576430,
+            ]),
+            Fq::from_montgomery_limbs([
+                14765166445067461698,
+                5207704987868587451,
+                8715046397598481077,
+                429568928380220359,
+            ]),
+            Fq::from_montgomery_limbs([
+                13234457885881356245,
+                12757942668782936879,
+                8450304203220106187,
+                996820738513317508,
+            ]),
+            Fq::from_montgomery_limbs([
+                7975687859474717331,
+                6254082199938268600,
+                5648874927121229662,
+                214473312611201841,
+            ]),
+            Fq::from_montgomery_limbs([
+                2100045965707892869,
+                2602462286868517116,
+                8294638543416238646,
+                1174464985584928691,
+            ]),
+            Fq::from_montgomery_limbs([
+                2779471205035855636,
+                8140782921341201976,
+                16462176800327773688,
+                1138520032463670018,
+            ]),
+            Fq::from_montgomery_limbs([
+                44214812178359067,
+                591872621562175890,
+                11868149827610647921,
+                1230847815402391951,
+            ]),
+            Fq::from_montgomery_limbs([
+                236949869223190127,
+                4547661857724697392,
+                4715877563350918491,
+                1051088528875581617,
+            ]),
+            Fq::from_montgomery_limbs([
+                1445127452116964226,
+                12532468077007718923,
+                10000536718007439426,
+                516464907061505496,
+            ]),
+            Fq::from_montgomery_limbs([
+                15605474145046802646,
+                3135914691151395665,
+                14777200209860351884,
+                289498292639531941,
+            ]),
+            Fq::from_montgomery_limbs([
+                10782106994339399685,
+                14961512349573759869,
+                6953491950750935053,
+                363316616297518074,
+            ]),
+            Fq::from_montgomery_limbs([
+                2031841796932357773,
+                5282407703718968049,
+                3911370178665860991,
+                322520439180355326,
+            ]),
+            Fq::from_montgomery_limbs([
+                8530212361020878443,
+                4740020245021579124,
+                5797313181271471126,
+                942138979138185229,
+            ]),
+            Fq::from_montgomery_limbs([
+                2949461060829001592,
+                16873007400446131162,
+                5301227572879351257,
+                434527788639620740,
+            ]),
+            Fq::from_montgomery_limbs([
+                14767952578747571886,
+                1162092730401257020,
+                17562304417000040339,
+                382609852942879327,
+            ]),
+            Fq::from_montgomery_limbs([
+                1221333256889326152,
+                5888244271370774535,
+                15722823853420396543,
+                481214067402749287,
+            ]),
+            Fq::from_montgomery_limbs([
+                14541602643025556606,
+                37357134318993712,
+                6108209639025266894,
+                1191028000973296465,
+            ]),
+            Fq::from_montgomery_limbs([
+                16425381918126913766,
+                15908758157296614550,
+                5477769171782591735,
+                305521658833888940,
+            ]),
+            Fq::from_montgomery_limbs([
+                717695728069154933,
+                14802358113200751157,
+                3073195826178928535,
+                718753231097691080,
+            ]),
+            Fq::from_montgomery_limbs([
+                17856704039139325074,
+                2449969987149305727,
+                8280173850669800436,
+                840939744032839330,
+            ]),
+            Fq::from_montgomery_limbs([
+                8072888120697773858,
+                881161554305747889,
+                597528254263906787,
+                390282711022782328,
+            ]),
+            Fq::from_montgomery_limbs([
+                3661197834273149887,
+                10842110775294490485,
+                11994061513594420261,
+                101699634808954790,
+            ]),
+            Fq::from_montgomery_limbs([
+                12421626452272846786,
+                4800847047652417742,
+                15276196496221208750,
+                842741996461249855,
+            ]),
+            Fq::from_montgomery_limbs([
+                1848385090469850084,
+                1105291393227675877,
+                6305588198071252629,
+                699685089830613210,
+            ]),
+            Fq::from_montgomery_limbs([
+                8015910680627097929,
+                4698908303628736863,
+                2582467124425479432,
+                265673747260618630,
+            ]),
+            Fq::from_montgomery_limbs([
+                12842611832890852673,
+                17659433200423621559,
+                15654775873620340179,
+                390592051063345138,
+            ]),
+            Fq::from_montgomery_limbs([
+                17482653339244284795,
+                8002387541472057047,
+                16600151004952817647,
+                1138985579319860359,
+            ]),
+            Fq::from_montgomery_limbs([
+                15621302034949442503,
+                4468487765237759903,
+                14688404360906932657,
+                598617765729966231,
+            ]),
+            Fq::from_montgomery_limbs([
+                3894220914530566741,
+                12927866874528009550,
+                12591062532386771240,
+                636798243193116347,
+            ]),
+            Fq::from_montgomery_limbs([
+                10845006382542272184,
+                15645399344669725223,
+                768891331920683957,
+                789493481084694259,
+            ]),
+            Fq::from_montgomery_limbs([
+                5381706420650663678,
+                3343758807504183888,
+                11890413788095173008,
+                77629748546518830,
+            ]),
+            Fq::from_montgomery_limbs([
+                5274173141983757810,
+                13989447282413860779,
+                15127017357574266261,
+                136368487550193640,
+            ]),
+            Fq::from_montgomery_limbs([
+                16840039693611741188,
+                10572949943891790049,
+                6084835048995710369,
+                1156309787584605884,
+            ]),
+            Fq::from_montgomery_limbs([
+                1994648800647767079,
+                16972929278841377831,
+                5892254483216394243,
+                164417478139615871,
+            ]),
+            Fq::from_montgomery_limbs([
+                3814111525895622210,
+                17364297513175885572,
+                11988280154406891146,
+                255113802166562612,
+            ]),
+            Fq::from_montgomery_limbs([
+                8928702371778757938,
+                7844940677366924249,
+                16005251994606901855,
+                128664376746882149,
+            ]),
+            Fq::from_montgomery_limbs([
+                5592623918200634563,
+                14659207054226674496,
+                7046124454742785587,
+                1185157843388742635,
+            ]),
+            Fq::from_montgomery_limbs([
+                15709255018197299733,
+                17729230117008959924,
+                1351185188937420266,
+                1104818910182000768,
+            ]),
+            Fq::from_montgomery_limbs([
+                13968608586008276205,
+                12108379437600079084,
+                5571288514525935507,
+                1172056708287423665,
+            ]),
+            Fq::from_montgomery_limbs([
+                12734740306272282880,
+                804731452167971617,
+                16853467248534048952,
+                1176569998016476274,
+            ]),
+            Fq::from_montgomery_limbs([
+                8140312352568137368,
+                3771781433111958964,
+                13383381740676543447,
+                551002126954743602,
+            ]),
+            Fq::from_montgomery_limbs([
+                2355977038346384746,
+                8488735326649492367,
+                4874108158805153692,
+                859594317351569868,
+            ]),
+            Fq::from_montgomery_limbs([
+                3696794921434883596,
+                11240272490678688066,
+                2724424575912527724,
+                362955140355209504,
+            ]),
+            Fq::from_montgomery_limbs([
+                7212604994002244345,
+                4893242893261299397,
+                17891511250986956745,
+                1227341802664722328,
+            ]),
+            Fq::from_montgomery_limbs([
+                11431644965964483752,
+                7803629458688943542,
+                17604749815709468276,
+                215684114095190633,
+            ]),
+            Fq::from_montgomery_limbs([
+                4607432547100678704,
+                1918063328698749862,
+                12667947802500441922,
+                927768550065159703,
+            ]),
+            Fq::from_montgomery_limbs([
+                4356945384635639719,
+                17310356216403068933,
+                10580453913165722641,
+                73806195228077040,
+            ]),
+            Fq::from_montgomery_limbs([
+                2985689817092675717,
+                16323951951335722269,
+                16378502724643314896,
+                1151495527285296417,
+            ]),
+            Fq::from_montgomery_limbs([
+                8115291621414134960,
+                507923418976291808,
+                10576163630774978962,
+                217137655556295295,
+            ]),
+            Fq::from_montgomery_limbs([
+                11190441933750252547,
+                16463611133892714819,
+                14531795053005647229,
+                1202755298547841195,
+            ]),
+            Fq::from_montgomery_limbs([
+                4540210040000373499,
+                5671472104096766596,
+                10647640602171183577,
+                796257367803680829,
+            ]),
+            Fq::from_montgomery_limbs([
+                12777815334654288413,
+                11095789328913770250,
+                15435844131005687708,
+                763149137245889841,
+            ]),
+            Fq::from_montgomery_limbs([
+                3425983492637319949,
+                146691603453481683,
+                16982014301571825611,
+                471405281006987023,
+            ]),
+            Fq::from_montgomery_limbs([
+                7457756285198851006,
+                2850370703683453064,
+                14456926076629615692,
+                1103031111421271748,
+            ]),
+            Fq::from_montgomery_limbs([
+                15544288789208578042,
+                13504672420189113610,
+                14234153471526322638,
+                149206468537681050,
+            ]),
+            Fq::from_montgomery_limbs([
+                14319200778095833051,
+                10426174958030509371,
+                11139381029654071857,
+                1053025058921111107,
+            ]),
+            Fq::from_montgomery_limbs([
+                3322890444732237169,
+                8493461460517922674,
+                216151674429926484,
+                126632831428196682,
+            ]),
+            Fq::from_montgomery_limbs([
+                2454160921593445490,
+                9717080890816442235,
+                14848548478403911894,
+                1090030578739866094,
+            ]),
+            Fq::from_montgomery_limbs([
+                15225991263160415925,
+                10116855231068985978,
+                268937861411798947,
+                1302595455325289967,
+            ]),
+            Fq::from_montgomery_limbs([
+                13377270892714551756,
+                17513661533316586249,
+                3775391810175185428,
+                799832257710483824,
+            ]),
+            Fq::from_montgomery_limbs([
+                13919471108787438675,
+                12532461051899965350,
+                2499890036213767802,
+                954561958974580752,
+            ]),
+            Fq::from_montgomery_limbs([
+                394301474631146878,
+                7711414299533810734,
+                18099449364107075656,
+                1121181095345138364,
+            ]),
+            Fq::from_montgomery_limbs([
+                7635119173256503971,
+                3091764206014857013,
+                4901662216612013995,
+                750361634779858967,
+            ]),
+            Fq::from_montgomery_limbs([
+                12758767645199035857,
+                7783105960233728464,
+                7069533352112496466,
+                311571206847367909,
+            ]),
+            Fq::from_montgomery_limbs([
+                5706154869022573471,
+                14318403116760214737,
+                8272752993388240815,
+                1080206810061574242,
+            ]),
+            Fq::from_montgomery_limbs([
+                13812164268708331931,
+                9909440983132110694,
+                12153041428756019830,
+                176840216523775569,
+            ]),
+            Fq::from_montgomery_limbs([
+                10825383127440772245,
+                17117069689876378785,
+                17329098721831877739,
+                766659182056155503,
+            ]),
+            Fq::from_montgomery_limbs([
+                11570983868055472530,
+                5389751701466838380,
+                12503892238300958755,
+                898535395587824878,
+            ]),
+            Fq::from_montgomery_limbs([
+                4100656304283689865,
+                9857093257305788436,
+                4891840649061386825,
+                1121263288638675587,
+            ]),
+            Fq::from_montgomery_limbs([
+                4941367926399034222,
+                16486498626747520624,
+                14821521574575276076,
+                884768309389020651,
+            ]),
+            Fq::from_montgomery_limbs([
+                17966865184807037161,
+                4566083619168152774,
+                8254204359158070898,
+                18641012761812517,
+            ]),
+            Fq::from_montgomery_limbs([
+                8225235132036585975,
+                5431299240339138533,
+                2370615984373334913,
+                392128648739553046,
+            ]),
+            Fq::from_montgomery_limbs([
+                8314854127159625664,
+                14257612476704076407,
+                17259323334762865173,
+                1098573371453672670,
+            ]),
+            Fq::from_montgomery_limbs([
+                2293348259541750632,
+                2197855509058796506,
+                17052567515025978290,
+                157900327550955575,
+            ]),
+            Fq::from_montgomery_limbs([
+                1839196586336009831,
+                11268114930563605750,
+                8901084557431170701,
+                508944464551570141,
+            ]),
+            Fq::from_montgomery_limbs([
+                9278660535486940623,
+                790397017145364163,
+                10257701484248661184,
+                291978019540225662,
+            ]),
+            Fq::from_montgomery_limbs([
+                14187451904367777883,
+                17974419419932654669,
+                3246936778771188517,
+                949603782012291777,
+            ]),
+            Fq::from_montgomery_limbs([
+                9091570009152800505,
+                17958033507640621311,
+                561131745247022148,
+                1037331324982463738,
+            ]),
+            Fq::from_montgomery_limbs([
+                4950029996039892566,
+                2148968099120312738,
+                15886727600472127600,
+                952628640548876868,
+            ]),
+            Fq::from_montgomery_limbs([
+                15108138176454604067,
+                15172057488504286514,
+                13034539162261497764,
+                14877080584416534,
+            ]),
+            Fq::from_montgomery_limbs([
+                10546643046675503976,
+                11489145555340155893,
+                3941206710728384097,
+                1277146503134208757,
+            ]),
+            Fq::from_montgomery_limbs([
+                15627104818167487910,
+                9107997027733360977,
+                12151668828715298993,
+                644779627787629247,
+            ]),
+            Fq::from_montgomery_limbs([
+                1757071678182109804,
+                1711206051200578827,
+                3880434192246957591,
+                242406023295782531,
+            ]),
+            Fq::from_montgomery_limbs([
+                7376803575288077579,
+                13873661461408862169,
+                3610004321209930825,
+                833470165195703783,
+            ]),
+            Fq::from_montgomery_limbs([
+                10305909791169757354,
+                17984387665861945075,
+                15434297083654596179,
+                386073548192318334,
+            ]),
+            Fq::from_montgomery_limbs([
+                7328180372729800458,
+                11425926985297437934,
+                13066917191908800270,
+                88371712278730305,
+            ]),
+            Fq::from_montgomery_limbs([
+                8794875641350028211,
+                12529297273401377949,
+                12266181829905656523,
+                40932163105516118,
+            ]),
+            Fq::from_montgomery_limbs([
+                4141977339045988635,
+                5784086339298545849,
+                6535300317960463617,
+                594781419348445103,
+            ]),
+            Fq::from_montgomery_limbs([
+                2183193840971035723,
+                15386993316511907347,
+                13484562277528075243,
+                389281443385095261,
+            ]),
+            Fq::from_montgomery_limbs([
+                3927687506479099774,
+                15361553327656291680,
+                14365187589034793004,
+                340720701462344696,
+            ]),
+            Fq::from_montgomery_limbs([
+                10688330958581476235,
+                11289439179967570084,
+                4027236184422213584,
+                814983711424271890,
+            ]),
+            Fq::from_montgomery_limbs([
+                1872639905333952406,
+                13932053470900462084,
+                16350269981188541537,
+                1231399979503940999,
+            ]),
+            Fq::from_montgomery_limbs([
+                2781728915457878990,
+                15721869404007677069,
+                14790481383856622740,
+                684704887000766712,
+            ]),
+            Fq::from_montgomery_limbs([
+                15530512724973732454,
+                16392523579258858121,
+                14852073445675127136,
+                1111157602939378145,
+            ]),
+            Fq::from_montgomery_limbs([
+                14991259334005211612,
+                5864021529490501423,
+                954753501537967363,
+                187406187440637203,
+            ]),
+            Fq::from_montgomery_limbs([
+                5319704658803591116,
+                8139409177684572135,
+                10364460635821009240,
+                451420750247752654,
+            ]),
+            Fq::from_montgomery_limbs([
+                4637283507714886746,
+                10118543745727270927,
+                11184837701970578494,
+                688128938073441457,
+            ]),
+            Fq::from_montgomery_limbs([
+                10331486187566620183,
+                15673128830784987434,
+                9411870084495476642,
+                422900471233928142,
+            ]),
+            Fq::from_montgomery_limbs([
+                9680650553441470274,
+                17376072036570146851,
+                7340693106697104544,
+                245304039876968915,
+            ]),
+            Fq::from_montgomery_limbs([
+                14060656511747979090,
+                1685980635608474780,
+                218798721889493637,
+                1228808018642125842,
+            ]),
+            Fq::from_montgomery_limbs([
+                5308442482071166340,
+                9221672648517439527,
+                7487344236959094239,
+                961524236352537622,
+            ]),
+            Fq::from_montgomery_limbs([
+                11205037403894804232,
+                9778950712186791367,
+                15433783120703126030,
+                889790480271506700,
+            ]),
+            Fq::from_montgomery_limbs([
+                8782412962205200294,
+                11955942187095102394,
+                3651272744999187306,
+                510667126805877637,
+            ]),
+            Fq::from_montgomery_limbs([
+                4561178857203917894,
+                14906553284679736024,
+                1134071886606461997,
+                82974463970547249,
+            ]),
+            Fq::from_montgomery_limbs([
+                16671821364201144289,
+                16588272203600623865,
+                2712996065301756142,
+                629180794837001241,
+            ]),
+            Fq::from_montgomery_limbs([
+                11919117620238963562,
+                16944212031063025066,
+                15088796108165498220,
+                844253386991665081,
+            ]),
+            Fq::from_montgomery_limbs([
+                4353163932148003748,
+                8710756719327514807,
+                9034170429936619677,
+                175734132741147492,
+            ]),
+            Fq::from_montgomery_limbs([
+                1493828494556957031,
+                9183279540749091394,
+                3835384539684687473,
+                86878434056943930,
+            ]),
+            Fq::from_montgomery_limbs([
+                7709239244128514379,
+                14852905676846120710,
+                368301141528494798,
+                408987854245935726,
+            ]),
+            Fq::from_montgomery_limbs([
+                11493268367959249473,
+                9474752366995594310,
+                5706310744150389243,
+                981943357032274526,
+            ]),
+            Fq::from_montgomery_limbs([
+                16067604437547152832,
+                10844322215209089406,
+                6561885876579767753,
+                187437414407467592,
+            ]),
+            Fq::from_montgomery_limbs([
+                12945508880398903427,
+                15053766732248941013,
+                856108475529421946,
+                814794825810737806,
+            ]),
+            Fq::from_montgomery_limbs([
+                12700059725570471167,
+                18405930961578126235,
+                9460898279276245059,
+                1320815572596484225,
+            ]),
+            Fq::from_montgomery_limbs([
+                1789393595545085328,
+                3441510810262286814,
+                5730005140732441960,
+                956207354059390492,
+            ]),
+            Fq::from_montgomery_limbs([
+                16495479312415307980,
+                18116993777237545538,
+                15323192949061983310,
+                541739798713754456,
+            ]),
+            Fq::from_montgomery_limbs([
+                3032528018931157195,
+                13029411013556501155,
+                7526031393814146385,
+                795648978445349649,
+            ]),
+            Fq::from_montgomery_limbs([
+                17132430569325583211,
+                10077621599779431927,
+                6996082044502135945,
+                719244387679888561,
+            ]),
+            Fq::from_montgomery_limbs([
+                3470997563746264869,
+                14619920491564122495,
+                8935027608776519834,
+                617086603782914614,
+            ]),
+            Fq::from_montgomery_limbs([
+                13003197186122854324,
+                12843678736787383003,
+                10964488901161669377,
+                470385277937633479,
+            ]),
+            Fq::from_montgomery_limbs([
+                938937426586068286,
+                8289345835295351400,
+                5357127563301623356,
+                972788431324850016,
+            ]),
+            Fq::from_montgomery_limbs([
+                10837824204669199465,
+                4220461657804352425,
+                7352997290045728031,
+                221357326158509478,
+            ]),
+            Fq::from_montgomery_limbs([
+                13252215171050274874,
+                15306454351962353279,
+                17106840333957995685,
+                1177877140302413718,
+            ]),
+            Fq::from_montgomery_limbs([
+                8870273073803387118,
+                14308589293784921315,
+                15347055982509390706,
+                344371910052723097,
+            ]),
+            Fq::from_montgomery_limbs([
+                7904242277293059357,
+                310320023671025934,
+                10911002303844322789,
+                148257710774247914,
+            ]),
+            Fq::from_montgomery_limbs([
+                9921689522714361264,
+                15571536660838944410,
+                10043046666269317593,
+                694869317918013514,
+            ]),
+            Fq::from_montgomery_limbs([
+                2683230773289810265,
+                12013546240702936442,
+                10657335417142204923,
+                924625827576591729,
+            ]),
+            Fq::from_montgomery_limbs([
+                11669890714665126374,
+                8590370539440159156,
+                15860634017846701577,
+                1198013106515149902,
+            ]),
+            Fq::from_montgomery_limbs([
+                12955677058541872403,
+                17986182057736829631,
+                14798417810534587670,
+                364729347927920526,
+            ]),
+            Fq::from_montgomery_limbs([
+                16712532920147080045,
+                269559475228613874,
+                4704076581329457068,
+                142795794543569947,
+            ]),
+            Fq::from_montgomery_limbs([
+                17834735828045610427,
+                5502389764095398815,
+                3506959310202060766,
+                1330019661448422583,
+            ]),
+            Fq::from_montgomery_limbs([
+                2039799954932882626,
+                11388247573561482911,
+                12004324816644389736,
+                788206041679195215,
+            ]),
+            Fq::from_montgomery_limbs([
+                403579282578267394,
+                2172440476638953789,
+                7749614594690661205,
+                703926508386488407,
+            ]),
+            Fq::from_montgomery_limbs([
+                12768505765875503523,
+                16552993754219359508,
+                8435723451451050581,
+                91314652453761308,
+            ]),
+            Fq::from_montgomery_limbs([
+                384674171240469538,
+                8657749837748280093,
+                4779432926982373662,
+                927778735934947262,
+            ]),
+            Fq::from_montgomery_limbs([
+                16442359241848915898,
+                10557037040475943530,
+                12963943097627282788,
+                159618915938802854,
+            ]),
+            Fq::from_montgomery_limbs([
+                4421287320219836320,
+                13264615475008589283,
+                15539686158457208698,
+                960953904162859916,
+            ]),
+            Fq::from_montgomery_limbs([
+                5283073352183292,
+                9501301099539672240,
+                4099731114120404688,
+                779898479615541108,
+            ]),
+            Fq::from_montgomery_limbs([
+                5066922943353488763,
+                9359709559110438496,
+                12072013785940156706,
+                22929340852609468,
+            ]),
+            Fq::from_montgomery_limbs([
+                9347097088384492292,
+                4471575426086413924,
+                7642601473787861309,
+                236029318190777919,
+            ]),
+            Fq::from_montgomery_limbs([
+                4623341782978189184,
+                1812918913590936964,
+                14751824978230458715,
+                673391637710846014,
+            ]),
+            Fq::from_montgomery_limbs([
+                3418792036626809711,
+                4030498224743156994,
+                7962986563453787275,
+                598872808508439200,
+            ]),
+            Fq::from_montgomery_limbs([
+                8309458118959451045,
+                7995421178740032132,
+                11210776035630715977,
+                688987520710767813,
+            ]),
+            Fq::from_montgomery_limbs([
+                5033365920772761612,
+                889027265880387845,
+                10513105838203349951,
+                1258298178903465564,
+            ]),
+            Fq::from_montgomery_limbs([
+                7365350472189553728,
+                7459458283703031149,
+                15983332605117823734,
+                46633298472875064,
+            ]),
+            Fq::from_montgomery_limbs([
+                17409556596695778587,
+                11328537976677811111,
+                12432892517470376670,
+                28966896393253275,
+            ]),
+            Fq::from_montgomery_limbs([
+                13671006663977861930,
+                710106098840308611,
+                11681703456625375149,
+                525026630289505478,
+            ]),
+            Fq::from_montgomery_limbs([
+                5648007393679833158,
+                2108050596194602218,
+                10515650798545371160,
+                956098125885929724,
+            ]),
+            Fq::from_montgomery_limbs([
+                13188847271880852242,
+                2342796601851810995,
+                5801452187450068075,
+                218347230869465269,
+            ]),
+            Fq::from_montgomery_limbs([
+                973879957411133707,
+                11445617615803642684,
+                17669547427949112776,
+                787684503963308842,
+            ]),
+            Fq::from_montgomery_limbs([
+                15186957682476108013,
+                4770121528152110846,
+                17202758041238023752,
+                504556830768237706,
+            ]),
+            Fq::from_montgomery_limbs([
+                5520023554358748468,
+                18156921103321078873,
+                3733457771921709200,
+                887461504812262356,
+            ]),
+            Fq::from_montgomery_limbs([
+                15728577499712270881,
+                8070564570494757613,
+                8447130720334775995,
+                23027366375314417,
+            ]),
+            Fq::from_montgomery_limbs([
+                7650984078023248152,
+                18028661511327014870,
+                1216565800305531238,
+                15435246238740979,
+            ]),
+            Fq::from_montgomery_limbs([
+                3912470773397761513,
+                17693391782527324633,
+                5861582255258287114,
+                818569226711689977,
+            ]),
+            Fq::from_montgomery_limbs([
+                1281153443801137779,
+                10736482148328135429,
+                1468611818439343668,
+                292686906048801179,
+            ]),
+            Fq::from_montgomery_limbs([
+                16676212343406662915,
+                12532861442236246412,
+                9227291777144201291,
+                2973885987750654,
+            ]),
+            Fq::from_montgomery_limbs([
+                391539231406645339,
+                219845783094611233,
+                13279540312538919584,
+                1301672204621729800,
+            ]),
+            Fq::from_montgomery_limbs([
+                5791276487349783753,
+                11534392919717668361,
+                16767753912881292157,
+                527114458353874913,
+            ]),
+            Fq::from_montgomery_limbs([
+                6188439569061539840,
+                16466222909106101700,
+                13219352453546867790,
+                97131422319526883,
+            ]),
+            Fq::from_montgomery_limbs([
+                15358573373570504480,
+                16389617284621326934,
+                2142335004980907800,
+                548645530891589367,
+            ]),
+            Fq::from_montgomery_limbs([
+                8305584934684227813,
+                9635496939936264318,
+                1698233975947992640,
+                872297658747874585,
+            ]),
+            Fq::from_montgomery_limbs([
+                7972984669455129867,
+                5297198015711358953,
+                16832452201004523850,
+                360481966599902112,
+            ]),
+            Fq::from_montgomery_limbs([
+                3801625470025269365,
+                12063135137496399121,
+                5657286456852879207,
+                1221152749879087681,
+            ]),
+            Fq::from_montgomery_limbs([
+                3287570482655658695,
+                5670556264709120035,
+                868765321231751953,
+                280021023973637323,
+            ]),
+            Fq::from_montgomery_limbs([
+                15901974675112021502,
+                1666159419380080133,
+                13225504539964503806,
+                1033801682136009097,
+            ]),
+            Fq::from_montgomery_limbs([
+                17337472015592380809,
+                6655409318927364471,
+                1035725506735515070,
+                1187751961246932281,
+            ]),
+            Fq::from_montgomery_limbs([
+                4307663572877638703,
+                15104601744252126540,
+                14778725712688155733,
+                1176649906151721846,
+            ]),
+            Fq::from_montgomery_limbs([
+                14063930134547634328,
+                10183785675697211633,
+                8737240449263378466,
+                824941418524467887,
+            ]),
+            Fq::from_montgomery_limbs([
+                5419693363862259444,
+                13146395093810085260,
+                11579696053259571841,
+                252692636253431086,
+            ]),
+            Fq::from_montgomery_limbs([
+                15572083090215806404,
+                9909349134065276134,
+                14180760171061450551,
+                667303665756657720,
+            ]),
+            Fq::from_montgomery_limbs([
+                7476867300598554417,
+                14236328963521901243,
+                15848610386825569411,
+                869974584163465676,
+            ]),
+            Fq::from_montgomery_limbs([
+                4533171598744738120,
+                10578207459518437236,
+                17652592073323995619,
+                764670210149861892,
+            ]),
+            Fq::from_montgomery_limbs([
+                8795125030294554426,
+                429639097260806371,
+                4358153334220338100,
+                986200791415020066,
+            ]),
+            Fq::from_montgomery_limbs([
+                7824608946799782915,
+                14222028469967041395,
+                10206843147326342494,
+                503130256442416234,
+            ]),
+            Fq::from_montgomery_limbs([
+                7768720481974636943,
+                10765282296898856654,
+                17673498866098002199,
+                902701250340732908,
+            ]),
+            Fq::from_montgomery_limbs([
+                6409667327929371065,
+                1046965721220690096,
+                13879512738389800517,
+                499816093040784708,
+            ]),
+            Fq::from_montgomery_limbs([
+                15877173119890911331,
+                17603806883557890165,
+                18246331482143588590,
+                218055346584842532,
+            ]),
+            Fq::from_montgomery_limbs([
+                13490594611859682961,
+                6967334791237467527,
+                59995576877379089,
+                523811940870407281,
+            ]),
+            Fq::from_montgomery_limbs([
+                3007237423379021095,
+                5434343611640907369,
+                14080147001138590818,
+                1163737982644848853,
+            ]),
+            Fq::from_montgomery_limbs([
+                901111695691285081,
+                6798501027781781909,
+                17059835832931652262,
+                1042362653909925369,
+            ]),
+            Fq::from_montgomery_limbs([
+                337173820523400589,
+                11107563846918020842,
+                17009798712525704503,
+                109656541916907056,
+            ]),
+            Fq::from_montgomery_limbs([
+                14679060670944291661,
+                11068725621119699417,
+                9448122273372966723,
+                47100285507741049,
+            ]),
+            Fq::from_montgomery_limbs([
+                13871182430951648964,
+                4899407830287706504,
+                11741448443903112417,
+                266788255860261849,
+            ]),
+            Fq::from_montgomery_limbs([
+                6393491350204380938,
+                3683207668503950324,
+                5060323037882234027,
+                1160488470452941198,
+            ]),
+            Fq::from_montgomery_limbs([
+                6573242545526778560,
+                12628430035188265495,
+                16794301583241257793,
+                623002988391532211,
+            ]),
+        ]),
+        mds: MdsMatrix::<4, 3, 16, 9>::new_from_known([
+            Fq::from_montgomery_limbs([
+                16270238816294404093,
+                17510165790819614716,
+                15988650198697570299,
+                575844906362867708,
+            ]),
+            Fq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+            Fq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            Fq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+            Fq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+        ]),
+        alpha: Alpha::Inverse,
+        rounds: RoundNumbers { r_P: 60, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<3, 9>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+            ]),
+            v: Matrix::<1, 3, 3>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+            ]),
+            w: Matrix::<3, 1, 3>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<4, 16>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<4, 16>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    16270238816294404093,
+                    17510165790819614716,
+                    15988650198697570299,
+                    575844906362867708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3912868093747842187,
+                    13891638599538547029,
+                    10034520463596988736,
+                    24923162524038143,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12940192809323646256,
+                    10063211937356421632,
+                    16102475073289099807,
+                    635255441558029483,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13439545502292733320,
+                    8210365196597573290,
+                    10798232243990715182,
+                    209985169044052016,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<4, 16>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9007339992229280139,
+                    10326424826243116856,
+                    5092355938437508509,
+                    1003135009120454797,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3793860473594899407,
+                    15568206114553404763,
+                    16793548156181542160,
+                    297017364836875886,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13118704239553060426,
+                    7548360664834135031,
+                    15187305571267045479,
+                    227114138088772868,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2709900338282071005,
+                    579150611132688193,
+                    11995391540129672972,
+                    212155260597768490,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3793860473594899407,
+                    15568206114553404763,
+                    16793548156181542160,
+                    297017364836875886,
+                ]),
+                Fq::from_montgomery_limbs([
+                    928445210678486263,
+                    8868160626470841658,
+                    3755794146411517614,
+                    1205473917191967362,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10094677827265099397,
+                    10861765592258933950,
+                    11901484874902647424,
+                    945996443767726300,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5126644488318323641,
+                    9647529625230561771,
+                    1074775780748510466,
+                    567785345221932172,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13118704239553060426,
+                    7548360664834135031,
+                    15187305571267045479,
+                    227114138088772868,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10094677827265099397,
+                    10861765592258933950,
+                    11901484874902647424,
+                    945996443767726300,
+                ]),
+                Fq::from_montgomery_limbs([
+                    824862419246658903,
+                    16129767034744593238,
+                    2170701329025733649,
+                    1301512370243790878,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3590917015388128985,
+                    13161152940282392610,
+                    1559289252300983907,
+                    436823818333081923,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2709900338282071005,
+                    579150611132688193,
+                    11995391540129672972,
+                    212155260597768490,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5126644488318323641,
+                    9647529625230561771,
+                    1074775780748510466,
+                    567785345221932172,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3590917015388128985,
+                    13161152940282392610,
+                    1559289252300983907,
+                    436823818333081923,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7790664405396569236,
+                    15096721329668270063,
+                    11927867068824539342,
+                    454228276177545737,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<3, 9>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    3075677070517273610,
+                    12935975526496926241,
+                    5413643861495614409,
+                    209652601170259502,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5785577408799344615,
+                    13515126137629614434,
+                    17409035401625287381,
+                    421807861768027992,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17408664359600548624,
+                    17034391550693366562,
+                    14790887709711326522,
+                    240850225403047528,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5785577408799344615,
+                    13515126137629614434,
+                    17409035401625287381,
+                    421807861768027992,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6563011294473575235,
+                    14911990801343518815,
+                    5387840296410814352,
+                    742514872555164941,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3251880405938485206,
+                    8073678362843046478,
+                    14394469848155607566,
+                    254586312717322188,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17408664359600548624,
+                    17034391550693366562,
+                    14790887709711326522,
+                    240850225403047528,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3251880405938485206,
+                    8073678362843046478,
+                    14394469848155607566,
+                    254586312717322188,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13824643481143691730,
+                    14179537619338997380,
+                    15459123094703748781,
+                    28520280494317649,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                16270238816294404093,
+                17510165790819614716,
+                15988650198697570299,
+                575844906362867708,
+            ]),
+            M_i: Matrix::<4, 4, 16>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    387582533474512044,
+                    16594855285729552217,
+                    13309531966115750176,
+                    851035440571488095,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16957823540891055764,
+                    17371987293502618456,
+                    12556102582827514496,
+                    1090151903667163905,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11513724455373813601,
+                    2599405861689045175,
+                    11585555529700825623,
+                    1028483121368221973,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    16957823540891055764,
+                    17371987293502618456,
+                    12556102582827514496,
+                    1090151903667163905,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7771120428106086506,
+                    15110522224452240295,
+                    10355287658295407836,
+                    1078205229197248069,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2458530112272528423,
+                    309031402215951489,
+                    2123584881910902766,
+                    56310412079132746,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    11513724455373813601,
+                    2599405861689045175,
+                    11585555529700825623,
+                    1028483121368221973,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2458530112272528423,
+                    309031402215951489,
+                    2123584881910902766,
+                    56310412079132746,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2081830487559662246,
+                    11209109685333996031,
+                    3608711189413788936,
+                    378658387793555315,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2093244959304135473,
+                        7921656493611837028,
+                        14184576022206158435,
+                        729731999227928846,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17237574486256691881,
+                        7678231653376502440,
+                        12981859904545217192,
+                        832323394471302938,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14878706952858838161,
+                        15410104227655356999,
+                        17393275270132988194,
+                        905602962502284432,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3544248464247567155,
+                        2397127324301944422,
+                        9674390580977628774,
+                        1114449731390581691,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10793968004403082763,
+                        14776098320186757028,
+                        3926381262294758649,
+                        695945431440508002,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2462384498404250202,
+                        12590306298374345930,
+                        11038784733069574134,
+                        419995880141749771,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13532986407775683895,
+                        9476195915097473266,
+                        1008114724683017572,
+                        545860035442724601,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16204216982352313226,
+                        12925674836598514697,
+                        6751896652466221822,
+                        941483813900869065,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17313019480228630806,
+                        566130559971125633,
+                        11091260944862748358,
+                        830377232140527827,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13653317352261633777,
+                        10493929866956769626,
+                        8396755137825041221,
+                        1146048882209297127,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4132278932442914408,
+                        14861671218963566328,
+                        7959790402925606057,
+                        1318243553399078199,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13456767915419596367,
+                        17246911423899751971,
+                        5711218416857391278,
+                        35403035103928169,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12907160498704673184,
+                        12152379198293294711,
+                        9252679699965582400,
+                        1224554265731421992,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13419199242664167498,
+                        13891452473958728318,
+                        13543072901518191311,
+                        1037106053989502131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16666897222883125359,
+                        12131730689573449836,
+                        13003710399516055414,
+                        648272461984720515,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13991351580088989461,
+                        17864339801785851041,
+                        11695126599066872867,
+                        921749339582652232,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11906579800297278037,
+                        11470441940361903448,
+                        15097374289742739909,
+                        275174984759910272,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18232546040145726314,
+                        14147307759659284922,
+                        991776681097878315,
+                        1065560959455072221,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3117800866767190184,
+                        1873664776760606669,
+                        13118797281780700540,
+                        918694694774008673,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16198059297050513347,
+                        8432506190058226772,
+                        9370514214732897655,
+                        212402421053033076,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2518782581476945766,
+                        6771388879653376635,
+                        1394823901821599775,
+                        786725020056686548,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4999125751846201369,
+                        9316583554979616827,
+                        11717167420397718381,
+                        1066546495878431233,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1873871365393108203,
+                        11936414467331150546,
+                        11113449918732250015,
+                        317274884289334993,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5753682051272078512,
+                        3471409429911499297,
+                        5526758011092646462,
+                        1323229023757477097,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2662759502586109043,
+                        1695416312126819095,
+                        1012723056571339871,
+                        933004606031207884,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16642301735251917773,
+                        1183412391970839036,
+                        3358256470522122468,
+                        496531810743047820,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14229377213726726413,
+                        17261160081985153069,
+                        9576276288950416830,
+                        700741200569462595,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6784297665956970408,
+                        11357803791388406129,
+                        11332180445619439434,
+                        1106781037719016960,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        73464770077821962,
+                        229820375535861605,
+                        4835258747884954879,
+                        991229687813626051,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10226838402609267711,
+                        357277105338386461,
+                        10898037771405216876,
+                        738876256175427730,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12081508766912259869,
+                        15930347767913903841,
+                        8853663281043083447,
+                        10038595465753804,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        112737203409514332,
+                        16114412090287437958,
+                        6856032020202317595,
+                        113207183629292293,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16338645855580340203,
+                        2107869018362076089,
+                        12567383037519742350,
+                        815641356564041922,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12125233544299543001,
+                        11893476647476625145,
+                        15457331543628022009,
+                        800448530217165123,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16675745322148137368,
+                        1493849644880660500,
+                        8185984318393084462,
+                        138242285432587533,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17724307965454702231,
+                        4807522038799077672,
+                        8746642154843823745,
+                        883881308868446414,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5656989936467702279,
+                        3451526239075705633,
+                        12473714456419681817,
+                        720076561380511801,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2752710254647861908,
+                        11030634120397179297,
+                        7520710074325720708,
+                        849095153678924863,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15714192245785854425,
+                        1934486269946887645,
+                        249860026764225808,
+                        1186606499687026685,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1065442882426850105,
+                        18187640339393109068,
+                        8850384031746892082,
+                        317569289072515041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1837681773828322091,
+                        10850972211403707738,
+                        336406577412961307,
+                        885368386317519809,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15758214746904074141,
+                        11583431684684102838,
+                        7662385228843486433,
+                        217065657978291661,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2532634061285367793,
+                        4387885387692314654,
+                        12158131981630891597,
+                        230565483900354414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13346419382798549501,
+                        18280976687063744023,
+                        5606331118089126185,
+                        273578743992252446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17577657789855982030,
+                        9630457784692257422,
+                        12902853055721181839,
+                        36459624765202074,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9935908372408102992,
+                        14641284087709280054,
+                        17684292083487177951,
+                        1155088959507788459,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        541701721303367728,
+                        3959174565794182274,
+                        18151179085562458542,
+                        340776254243440974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17690336527687088662,
+                        10937885152973991052,
+                        3836836874424836886,
+                        1090509013794447238,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12310573214022515884,
+                        3043141462744167127,
+                        7406092407251694082,
+                        9160197655286166,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5101804845510497503,
+                        7081024525590050191,
+                        11990249650950760548,
+                        264716438448155623,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14013782434072143361,
+                        18031550597362521222,
+                        4176916092795610844,
+                        1008783913934215313,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5197923006462389769,
+                        10850213912970425984,
+                        6236288807254229677,
+                        381647096775612803,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16904562081938006603,
+                        1107639524721847015,
+                        12857487247803522951,
+                        271937989000148046,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7013951325055848953,
+                        17628406277137302222,
+                        10293198099660764650,
+                        1028289934164465192,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9440252581760876632,
+                        13906337780931242750,
+                        7219961419517025615,
+                        863594454449643923,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12415229001429896965,
+                        11797271759794076948,
+                        1936084790537971337,
+                        467019200964667988,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13553333187067381210,
+                        1913316352777824197,
+                        2458517210763467943,
+                        416174277494956025,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7868885723930814728,
+                        3969684852222006688,
+                        847603129181085226,
+                        823204710257787357,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2624306993317474736,
+                        1804999503475194353,
+                        13323117091522537138,
+                        113213718216814537,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13028853788877299982,
+                        16207030875451345887,
+                        2928890708384561735,
+                        948835740600760535,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10332479856281518610,
+                        5461907926022368118,
+                        7566281008880752185,
+                        864542613737871414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11550343341750412987,
+                        1921332364560602185,
+                        10690162369512005698,
+                        600190744590186860,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11343163367577472637,
+                        7523233831279170268,
+                        14378595658429552329,
+                        120678106820511750,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1497803481831246817,
+                        7312638636652871954,
+                        34925480233639111,
+                        44726202564591048,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6152756361006073958,
+                        6090155380815116625,
+                        6481578954979806710,
+                        476190110671401465,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18102241645952999143,
+                        12827175776728527569,
+                        10917946848969687318,
+                        1031360619507992497,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10479806376196948796,
+                        1250933353292865403,
+                        9714052636425979898,
+                        724896984716198201,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3536451958691514845,
+                        3820878089540374661,
+                        1602865878357815152,
+                        303292371629072230,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2647458017961703424,
+                        14255003531541688237,
+                        9264134941484745925,
+                        1144438898154277748,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14896674334234435047,
+                        6601140327636410720,
+                        11594733032791631077,
+                        947808208377648736,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        93857753711709862,
+                        17114219253807441153,
+                        16571911382240086620,
+                        644986335071170285,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11491045973623638105,
+                        5832623718891215316,
+                        4637897368334583442,
+                        1270136782835782403,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12059760086965268413,
+                        2104859632417338119,
+                        3074047925029609319,
+                        208685506241841351,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        507458650320731749,
+                        9076652763882528689,
+                        10828995108044982790,
+                        52861166057592230,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5221245564257429544,
+                        602546441039285868,
+                        15463515802777952068,
+                        500262458682360187,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7894603091209534363,
+                        5963299443776147743,
+                        13622917766282071553,
+                        713444535504669723,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4707822806056225947,
+                        13008601324591260598,
+                        6664196336989843628,
+                        361594494120552721,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7234555332968855468,
+                        13223173585719696534,
+                        305242624856210053,
+                        317409461914184558,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11052531972415731524,
+                        6240249964832493008,
+                        4690512975789608724,
+                        274300931659090955,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12865203614941348009,
+                        13629535421935003532,
+                        14381802801123693296,
+                        1108602536075241162,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5342089493189303187,
+                        11299417329134660514,
+                        10437815285599784456,
+                        224625807758727328,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9419019633707904193,
+                        17287104400249488354,
+                        2449834218878066498,
+                        880784160362712225,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1290387194510150606,
+                        300217878573287348,
+                        14712510331530271944,
+                        675169241465258261,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4319449301091482228,
+                        6604997191961440301,
+                        3677473832318302588,
+                        1226257433999087430,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9534885027294214845,
+                        4233504142154122132,
+                        671184154419538440,
+                        588715198189399632,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7834331777517711657,
+                        12046762886677754640,
+                        12085017871628461830,
+                        1280695769450865349,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17051048636914562332,
+                        9931763496822042016,
+                        812132922227142181,
+                        1215806818267015733,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10248407954863227910,
+                        6765342538198222232,
+                        3376905111922655053,
+                        689295470605671650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        852480703421168492,
+                        12355793660799378618,
+                        6731311636032405498,
+                        552777249330804449,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1991623127336121727,
+                        6505031450361927139,
+                        15924460190557187785,
+                        1320271660125443798,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17438135180680113454,
+                        12154982230827864980,
+                        10857096699189529725,
+                        334861850663499479,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12624059910780607141,
+                        14938141535714956518,
+                        10243410760322861612,
+                        175466020402160466,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11857086838875231849,
+                        16760011700969259517,
+                        8560727830203057188,
+                        152554536976681898,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8366377495511845655,
+                        9027212486725311737,
+                        621896686425068484,
+                        59908093638686247,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6821247813496409711,
+                        3809783449247177170,
+                        14246355778269985790,
+                        100067199319926835,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7114331187643612321,
+                        12046884410610249817,
+                        11641883396644737569,
+                        816513613398229934,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10903022878926357374,
+                        5979826527581930957,
+                        9964619736578287748,
+                        1271434037472220735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17632231262698779721,
+                        8707825535027157717,
+                        2435867734082505465,
+                        600255330328129779,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9678276357057662336,
+                        15065198159805039034,
+                        13844736019338558457,
+                        1030744040472859467,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13577465593870985174,
+                        13019988982449073022,
+                        5630144684460110850,
+                        1067109139774292952,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14181846117952974562,
+                        6483192693799719511,
+                        11686204140875594690,
+                        53556822744562628,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8837072896923545588,
+                        15782617742266896467,
+                        17768352032495473622,
+                        874284623546864947,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4930882077235357086,
+                        16598775934612528495,
+                        6427030583744520257,
+                        454940500998793676,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6127875275316077517,
+                        1098018032759962519,
+                        7168921354059351583,
+                        539217452595689197,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16140691465259840229,
+                        15459742228472625389,
+                        684190287722767135,
+                        24975807305976285,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5477237112676987230,
+                        18260040498029279223,
+                        13809783258308105380,
+                        980761446574822176,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1929545376910953199,
+                        16208816609924361154,
+                        18223810928582510715,
+                        511526970360334692,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        745408440353117228,
+                        6446148919829444880,
+                        9859762884989490143,
+                        948867755568230344,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9584744285611425921,
+                        3444162698216444647,
+                        9075048563141887487,
+                        1091845147398737163,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3992387261031178206,
+                        8442288390990939021,
+                        15878335778617413021,
+                        434932132976693117,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5181129751379370298,
+                        11263035640978647486,
+                        15656906907851165855,
+                        760113975833886721,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17283399203902770817,
+                        4823828749608678477,
+                        18335927644322855466,
+                        291075872541594381,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3563402981706258075,
+                        10804664455293863978,
+                        9875622782079335999,
+                        673828909275117105,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7376305669720650864,
+                        18084850803968701462,
+                        7493247936343898672,
+                        242133383968305422,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        768405041291620160,
+                        16402084532604224467,
+                        8326493203130023144,
+                        527375926353461289,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4493884858502538109,
+                        14177524374133685442,
+                        8564208764161603190,
+                        950807508820071672,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5451032349556884040,
+                        11979111833367591785,
+                        4423067886379875705,
+                        714685638652506398,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7280850589105638813,
+                        2021009939841738455,
+                        3305309641247301754,
+                        1145853947100305381,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14277617808656927503,
+                        3424217281043782122,
+                        17387678576036547232,
+                        286945907482331256,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17836403466023391582,
+                        9600058718067800158,
+                        1215064310075334312,
+                        512091907554853722,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15911401798815879246,
+                        3441727988121772734,
+                        571069208554741638,
+                        87781266185380480,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14300405535407053528,
+                        12933427870157369458,
+                        3165407817013718879,
+                        147023269816399912,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16984767622482815352,
+                        15012536080757057955,
+                        13331788611771111559,
+                        790594190457488637,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13666344951743495978,
+                        4011883471625095853,
+                        8687340369204333578,
+                        6335869032028204,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3589738941147745168,
+                        6375438694001021390,
+                        6318076178695241551,
+                        225530171938347001,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17534454671862535945,
+                        8734526482948168277,
+                        18100888928470402529,
+                        412792969510827576,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15210649698843525855,
+                        3317283543412109116,
+                        10266286337495307332,
+                        1133872079638661976,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6863705326169845975,
+                        7913744781595172103,
+                        17721461713943575894,
+                        800986608178990873,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6627643700182591015,
+                        11426893535294500202,
+                        7043441898549305883,
+                        732296594080166130,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2077116234070680341,
+                        9606822698884172893,
+                        225917718822356973,
+                        467011003797948605,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1379071214991643980,
+                        14896879420105586606,
+                        9100121097383763652,
+                        1344423996678203204,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16334202264073061081,
+                        12265074930728041035,
+                        18047637450699268484,
+                        1144762413062845608,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4127793645839256164,
+                        329705433349336544,
+                        12916738011263886703,
+                        1237776029058458664,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10638845718313681251,
+                        5204456183271817174,
+                        5055586724657168401,
+                        244519569217100845,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18168492025145726775,
+                        1800668050125999412,
+                        11263495083059089404,
+                        572604685401127430,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2424127554614197169,
+                        13670313902085523295,
+                        5729581868200892845,
+                        1185633674245725234,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13391715393809511192,
+                        2136015245293052456,
+                        9399429027056635007,
+                        86837547138622120,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14873164522160417143,
+                        12556126955408094439,
+                        11828972389659422827,
+                        777263461471811751,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13843167715386350044,
+                        1117048182126965944,
+                        8319689258810501467,
+                        315176530189306295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7135536190247804692,
+                        9026192352055941907,
+                        6508946745899593356,
+                        715020329629880951,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2950471950444940504,
+                        9931002328508553321,
+                        7423810810280461943,
+                        1337961697757219155,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9376683662997739245,
+                        4890182394417731118,
+                        14544384991196881351,
+                        65676515162486741,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18207352499870555995,
+                        7727940318204839523,
+                        2372324174922918603,
+                        357802969035685875,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1941997307693763690,
+                        13059844071888594021,
+                        13800242043957569572,
+                        148007021621402932,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5228291535864900839,
+                        17818009621279245853,
+                        16683012392490710991,
+                        993430496392305468,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14837173888363587504,
+                        7256433229228745487,
+                        10679295223486765918,
+                        932706843420686165,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        762523639826343513,
+                        14421677125898938007,
+                        8733659425457326140,
+                        417770694270795324,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11251786383603128624,
+                        16995486252256364884,
+                        119680462838471498,
+                        1239882880161636727,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6851787281096470988,
+                        15905444806716867573,
+                        11116281144265974928,
+                        902828350771953574,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8756128085064497195,
+                        1931061559360390117,
+                        15790745217839855376,
+                        123851268364975171,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        229601264774550882,
+                        15643079034841534352,
+                        2776308373436918391,
+                        1191904265601044045,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15378108091219863244,
+                        6530239423753441046,
+                        3621137115945231373,
+                        1339393270487541285,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16021795714268825165,
+                        3298814803480024643,
+                        7346030191665711242,
+                        1149590768838055066,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2568037920841246230,
+                        10745685891977186721,
+                        12748952166809899998,
+                        1102232561863033879,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1998206298009799819,
+                        5352580740808335973,
+                        795752737031846270,
+                        1180776003101888545,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1504662908770842616,
+                        801756760144270900,
+                        7408646004440952909,
+                        240871648327951776,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14319626910735357536,
+                        3162493398424631895,
+                        7683385807359292670,
+                        830923459404970067,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15590439244148767408,
+                        13421343740269634823,
+                        3147387741733834051,
+                        233061649286627582,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5629904360167900415,
+                        1603514194547510457,
+                        12458110764968386040,
+                        420117825975322849,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13508951649538962524,
+                        13842481091735246125,
+                        124814268400897828,
+                        1161188179537001734,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14210640685284632749,
+                        14672537187890273563,
+                        3546985791596883119,
+                        536174322787539002,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4737537776762907681,
+                        479530455693770357,
+                        12169826069606386775,
+                        336196110250222245,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3973766991630405987,
+                        10200113960516900442,
+                        4806008924906189256,
+                        272114102593754671,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6701479506239571206,
+                        6608415268377503943,
+                        12719560801047296636,
+                        369021601491575284,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1683072062812655892,
+                        12899319503890524261,
+                        4352614635926418842,
+                        507399132358953121,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8537869593410534166,
+                        15503161611969327331,
+                        4071991661196311045,
+                        994287224044827301,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12192626066548252232,
+                        13985503311680157749,
+                        6785953890170077671,
+                        862064737656007483,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6040089103948853293,
+                        12226637811569354294,
+                        6659771091553808997,
+                        1205717762694962211,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7953514680433375819,
+                        17230260735890801811,
+                        14875712389992217735,
+                        1200305382196250138,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14927686130640150640,
+                        16017968471334408184,
+                        17012541827534367833,
+                        896255524775006375,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11211878678758284883,
+                        16372744263442638438,
+                        13583346251691749641,
+                        602224944426245292,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10041885777786485869,
+                        14206606210001517869,
+                        6156143775030231620,
+                        443403624895756994,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13940161437808146954,
+                        9410713776269289422,
+                        15043458596435776810,
+                        948320407740031724,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16151029407706589745,
+                        3387263781753596122,
+                        5787685600981826338,
+                        836746092933913308,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11810097346305676049,
+                        13124867174017638702,
+                        2231791726149991627,
+                        882509318769728857,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2141889660997417904,
+                        8982418913023189407,
+                        5460563825801236793,
+                        728620418455168899,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3939377957559014764,
+                        18248969916435128906,
+                        16819449343173283536,
+                        494840621143441195,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7052788913641313403,
+                        5843260224217535964,
+                        4500966417319049989,
+                        1338004115836755486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4312709994106011614,
+                        1432088519157045587,
+                        4562718137526528433,
+                        507022826822793818,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13588210697731073628,
+                        809870067805765342,
+                        15614490267927152554,
+                        909658846658865210,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3912868093747842187,
+                        13891638599538547029,
+                        10034520463596988736,
+                        24923162524038143,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12940192809323646256,
+                        10063211937356421632,
+                        16102475073289099807,
+                        635255441558029483,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13439545502292733320,
+                        8210365196597573290,
+                        10798232243990715182,
+                        209985169044052016,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5751631526606014397,
+                        8462191803631620224,
+                        10509863267471683158,
+                        447861109114791478,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1437717886047567450,
+                        16559126213892176045,
+                        11375302712551931442,
+                        1010730393746897317,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1032470319927068309,
+                        9033645067614029358,
+                        18086293033601348125,
+                        304884835659797125,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10030163671426435897,
+                        10038896509362625400,
+                        11411437429949289797,
+                        606845429174704889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13498048305505589045,
+                        14368156343173972859,
+                        5751396272374329581,
+                        1272023166617958572,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9805803927184323301,
+                        13425206131835998785,
+                        13841345243897428245,
+                        615350201809158017,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14956193223177978026,
+                        4328965669156513709,
+                        1332264507148878686,
+                        908612595539291972,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15512085810689995447,
+                        1278811290279093247,
+                        9713635222980865549,
+                        89918473543807263,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10908982736163998884,
+                        10522718116611575026,
+                        15481410495350859749,
+                        209345561210183580,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11298966019883739719,
+                        3737954136375979756,
+                        15666428783954356951,
+                        93322090349950905,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10237667835076512281,
+                        12325953953570405613,
+                        12285254403281429040,
+                        507107093264211690,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14936641530723543297,
+                        12684654093637137182,
+                        6573648560098330931,
+                        479128193670708215,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4665624059074225901,
+                        569773391663140707,
+                        8039376161136700750,
+                        305908263293765813,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12181552876023559131,
+                        7764448317943448064,
+                        10288520924018884818,
+                        1103852203169073095,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15135999239214672948,
+                        10790616626797062588,
+                        17724896909590777918,
+                        1127104704406195421,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6486714260719829146,
+                        11040751111500249834,
+                        8958297661837625585,
+                        504881187176923268,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9943503409323924620,
+                        9519655296534470798,
+                        10031214880424175980,
+                        880224541550113700,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2137123775246483621,
+                        14575781645937393202,
+                        6082200831828333306,
+                        319693495256136311,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18213874187166503017,
+                        7357620257182256357,
+                        12753979462901781784,
+                        653327632081049969,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5648402480861501803,
+                        1298801804080198935,
+                        16361566576947818947,
+                        92096317293783223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17945395108017238106,
+                        3498558197841053284,
+                        7092504945026653103,
+                        372132312547601421,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15622147422254947733,
+                        250116726000401472,
+                        8318204049018604061,
+                        866935352572882912,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18241804623807754966,
+                        4053430455599371737,
+                        4911593924672747195,
+                        915087345052626509,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4269143728338544596,
+                        14357201884344458328,
+                        903006646797065399,
+                        462454343275509581,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16493823488007719206,
+                        14779329128742765495,
+                        18378884619527915493,
+                        360610956997885799,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8231956009608211607,
+                        2237272948977110346,
+                        11234918376541957482,
+                        211807215316292218,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4694295422393950539,
+                        494515799644279017,
+                        16377559543581347883,
+                        1023664249665260398,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        269632147881481827,
+                        17676834172042717446,
+                        13502391546248246285,
+                        158178875674850167,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        854535799571654664,
+                        4436276133137563398,
+                        12250035363748361097,
+                        569847052981690503,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8356573900420286918,
+                        3943111982741005596,
+                        6690528014909196681,
+                        100899612597296936,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12053108327360532468,
+                        2215278670095839574,
+                        18310257358516903189,
+                        507153778977982696,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15548327836627863789,
+                        11669395097045425760,
+                        16626297677851084169,
+                        692658363999722441,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5590241427234064239,
+                        15193192965574703365,
+                        7643293374071077424,
+                        502287078265137211,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7121109350102790552,
+                        3842989306298966953,
+                        15442678426893498207,
+                        43682019324253859,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8543065120635606147,
+                        17618464589270231617,
+                        4296924638616724859,
+                        409105874035239827,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12676854185827013572,
+                        13888077538347807744,
+                        7139127667431143175,
+                        1225243047885808896,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4347759847866483265,
+                        5855881520281836030,
+                        5505541235252858831,
+                        976243446170017999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1880808402247115426,
+                        943890821775946445,
+                        12000264441794154793,
+                        12973763854532988,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8496845738719301602,
+                        11057791601996073401,
+                        18430915991403133806,
+                        442312408397388928,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4597591998391646042,
+                        17549544536524741100,
+                        7632675978077243758,
+                        789227364575351796,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6247914330752058188,
+                        17379927947827819186,
+                        459262613159719376,
+                        17669136651242999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4416622626270946808,
+                        9011173931926497864,
+                        2385315687804767815,
+                        1100085754679242026,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2858027675101851698,
+                        15199707771225336894,
+                        6912007903404713737,
+                        1195736020550467109,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16536374087395040861,
+                        11905207237145903612,
+                        6114638606661711198,
+                        227197704829902271,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3076102373056113184,
+                        9532492163005512122,
+                        6930666801042367372,
+                        359947620632631939,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9440427530838662463,
+                        13085976282871216533,
+                        11609820600896955445,
+                        51192596107359503,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17890235474475849352,
+                        12861769158994967907,
+                        17199388852287681020,
+                        1318104728008794611,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9609805589266211410,
+                        1670962659820459283,
+                        9562963939311936174,
+                        688275382419503341,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11167389070307520429,
+                        13977359843749392350,
+                        18441644755473839422,
+                        290366407278115355,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12443632002797775505,
+                        4654520606670285598,
+                        7306556664291017650,
+                        1327633699432541153,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8092706722129444827,
+                        3658752323492067072,
+                        4793508300996597563,
+                        747223853807345138,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2950573641865927995,
+                        8127787636945490240,
+                        13677031676353641065,
+                        158618420355845681,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11307462183326711698,
+                        4564920402521666764,
+                        14622180354087693631,
+                        1336492508958389172,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4173008676431506498,
+                        17674916393358760276,
+                        12680688896701595449,
+                        968385841042257188,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15472538886056214069,
+                        16032473331676368569,
+                        16841883688877890652,
+                        857731186179030920,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3288920438915728986,
+                        5565335638237954732,
+                        16168717028973711379,
+                        557711450924669146,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2265592654319698024,
+                        3205443269480017209,
+                        18001932803255029483,
+                        256652906325487142,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        281001495283692254,
+                        13790290956215242906,
+                        864387281468051063,
+                        1176693997057782923,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5123560649935846987,
+                        5199616350359672235,
+                        1101436586367673740,
+                        39975262197625469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6484555314181403346,
+                        13702935644386089262,
+                        1070288899201945043,
+                        1275458004049976710,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        98165037936801382,
+                        7473654891184825762,
+                        349134079485996919,
+                        938092578927267223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14188791834503488423,
+                        18250342088521722141,
+                        2040654749291153960,
+                        926512896204558398,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15224380743676270964,
+                        10786962884449555555,
+                        11361613959686699022,
+                        1210280746834414029,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11759988830619476554,
+                        8373507443143532317,
+                        3120367880452859596,
+                        1169296147393250270,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2846578710538047768,
+                        1316160842247957353,
+                        13088400622784413268,
+                        408653118294756083,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17205667740934035850,
+                        3622944266683523375,
+                        9638129797382886543,
+                        994379064072552476,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10882825922057764254,
+                        14418568533897154353,
+                        491214512167095700,
+                        433897962559667772,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        35128611736586842,
+                        16389786149729784671,
+                        10083558142637806972,
+                        137127276176467702,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6798031404470581862,
+                        17848651283256458296,
+                        16222358536010933656,
+                        803683331342998490,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6972405454939855920,
+                        4552281101007467397,
+                        7264795943490057443,
+                        864688899960148444,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13927481061475209845,
+                        15489762958527206567,
+                        6189983331269957882,
+                        547789234607264680,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3562534859757502067,
+                        1843985785467419577,
+                        4386226263096503262,
+                        1115671597351053159,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12278022351526165705,
+                        17770191387101388710,
+                        10802565891039662934,
+                        1075900781773362211,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9958371080654925973,
+                        11831887817395699647,
+                        1304723427562418477,
+                        354927743406208632,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7902693975921282234,
+                        10693029805943046522,
+                        8006364623005626891,
+                        336833334808426575,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16076162621220796497,
+                        11613654344612125103,
+                        18364396485836487261,
+                        865751367130059847,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        177169936881514673,
+                        15617655321830945408,
+                        2675644582344792450,
+                        813774581927626127,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10024256268223083204,
+                        4626025808735980516,
+                        15602381466554927909,
+                        372989881235976309,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15323355670275250642,
+                        18066772602049731907,
+                        11033816093504217490,
+                        425723417069229499,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13530227036238294143,
+                        4726864651116558793,
+                        11836084010804535503,
+                        345580619383028526,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9033276785296979553,
+                        8015158493901738529,
+                        7685612975844433499,
+                        646369922266692941,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6106858674192767659,
+                        6489383503992760141,
+                        15104627566688873842,
+                        967007977448094027,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15940025338744316838,
+                        9550216566045214897,
+                        11499601007486969156,
+                        519967031225120334,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4457318900167954816,
+                        12351443184813817360,
+                        14113262131954548168,
+                        753463690543950206,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1881864483835241252,
+                        13219574080686529684,
+                        10918646441166728772,
+                        345526905812511168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16713317497695859602,
+                        11594535032725047066,
+                        8614425525972045100,
+                        729458370408931167,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2382365843901650484,
+                        13640386342346411833,
+                        2679421435014953531,
+                        1060573086709931927,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17434826777667612735,
+                        2365065059007002287,
+                        12113557410986701552,
+                        486906253608904981,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15422260981332801416,
+                        11687068808662228912,
+                        15340447593430286694,
+                        1095477751721025581,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3067136625363818837,
+                        15414947239083578872,
+                        4357451922062335136,
+                        670849424861114413,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6857276399606485730,
+                        9283462962781902828,
+                        9036672709333692336,
+                        767246981611698041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4790627237749516576,
+                        16870705317496776272,
+                        8201498104615025061,
+                        1184169050930142288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7547529495962597834,
+                        16334181419310753475,
+                        4959673272289445188,
+                        622064414724316631,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4699554924975257477,
+                        5239406492763952097,
+                        4514138379314892193,
+                        285913371707115885,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13798881461428781360,
+                        13731509523477882383,
+                        16976897769861195832,
+                        849427285657530276,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        952063905276541282,
+                        8269636048634891956,
+                        17964057567515234603,
+                        1070447721298630591,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15015857425828718687,
+                        5775099707931433635,
+                        8502608212811077834,
+                        958497153166197074,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2717189144441328164,
+                        9171329488469202193,
+                        259316541210055751,
+                        558356340017870591,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6065798026006695955,
+                        7792828742456711757,
+                        11440074088348310053,
+                        1075859908346807801,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3134440530553818271,
+                        8255184637299442104,
+                        3914768334809935182,
+                        101044237360384907,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6731623173483973963,
+                        17929509414463545315,
+                        6203380609483126415,
+                        1090117544810917978,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11258118484197197697,
+                        12531230517307317268,
+                        11909932475428620217,
+                        714098560023676855,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16443798894097432740,
+                        17067742110181636056,
+                        968427074407493327,
+                        779479350294359833,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12042739625881714609,
+                        3652554932902842865,
+                        3541534518594911657,
+                        940663901406188983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16465321337635465873,
+                        16583505888349211971,
+                        3081564802538447298,
+                        39284010428842612,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4614030950475594192,
+                        5269526834281982621,
+                        13946689859734295220,
+                        572834450668633829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7045565763189285052,
+                        8188934209194161805,
+                        9264297222580518924,
+                        937792513184682512,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1888603576987175290,
+                        13239830129648327139,
+                        15966528111613565942,
+                        428512520553884462,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14544821369811338629,
+                        9277520510311726109,
+                        1944405612066813917,
+                        2042387845638086,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8148858061024264478,
+                        3186229972503618180,
+                        71750729252111413,
+                        118673777446127206,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12524058261693615091,
+                        1932429661932427840,
+                        1713896310300894608,
+                        1049093835593922568,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2502706814821035925,
+                        18194236067752240105,
+                        14011783808302568395,
+                        848185250263106842,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12840484428748224251,
+                        17699119508710197881,
+                        2831119501902020879,
+                        276048849389384361,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2999251893179110005,
+                        16521814728275655275,
+                        9428411387849592121,
+                        940196927657971330,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13533732460078790337,
+                        387677542177831669,
+                        3109475872432208250,
+                        433410359228626048,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        197547740523383097,
+                        12463508224686310686,
+                        6956928609019547139,
+                        691131875413670961,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14833135481746337585,
+                        4590604263563384575,
+                        2631796799846975241,
+                        356919890071743747,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11396821317904760494,
+                        3191024265870628480,
+                        6297169331814656973,
+                        1330852384014845395,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17425025521921972270,
+                        1677371038032693782,
+                        8085423593266762953,
+                        1031519776432511666,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3301044714222505196,
+                        10284367474567452100,
+                        2436807147485185497,
+                        1282664924374824086,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6671945680829599494,
+                        16431716202886168097,
+                        14676946774024884124,
+                        1118188352225305401,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11283184553346067882,
+                        13147629602550985559,
+                        9306472546366375569,
+                        708925093569345977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7366012340973532759,
+                        17598590574826475813,
+                        18242341335953999325,
+                        850567668381184956,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8429950311742381549,
+                        8860514557548562539,
+                        17157970278619620431,
+                        767591871664523743,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1888950965328985669,
+                        12670964109382138288,
+                        17524780283649105145,
+                        1245637638414959460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13752329753524470530,
+                        12639210299970653052,
+                        13881999051284614757,
+                        220490053230696312,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6222323541059343901,
+                        9704109300052676382,
+                        835626156977381380,
+                        29349964471996200,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3688327562877745006,
+                        5123346408875513657,
+                        4939216998363701398,
+                        304107178075723952,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5953532054017440302,
+                        18255196798464259627,
+                        5380876274516998304,
+                        1269782724401646792,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        276904087294006329,
+                        6844418875307465688,
+                        9968058427245330692,
+                        1341694139918135212,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9449951338909708803,
+                        3390872338574504617,
+                        12121013142137586495,
+                        245914957142285403,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10865415094117673992,
+                        4556156956341555401,
+                        6420378458845214729,
+                        471023451759379443,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6481195991705633241,
+                        6428102562531214382,
+                        6723044289920117994,
+                        426747364467375382,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13172916043941166303,
+                        6320322486661563660,
+                        17188422231386612809,
+                        1115872458774521127,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8216819113878731584,
+                        15916974162129344889,
+                        1186969706974249272,
+                        433225779034895683,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10846661729795939451,
+                        878269941055266353,
+                        16594712509298675752,
+                        640454414412543038,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15375856510128910128,
+                        14295217098300352006,
+                        6527306672583628141,
+                        166311091445497201,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16316003137916413615,
+                        4264198745178742006,
+                        17659554073600769304,
+                        768445626908171016,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5181119182281901138,
+                        14975992557513298573,
+                        15003296890159781346,
+                        638306274107453016,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6590140468054910117,
+                        9295522494191705236,
+                        11879024959256393082,
+                        57008691005531942,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13543643409822467707,
+                        10550115314107366972,
+                        2103649920880306099,
+                        1068419961830727758,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13152486229011673261,
+                        6125422612985959515,
+                        5347486333719740827,
+                        670195614083308539,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1476508125958563869,
+                        1597989171103748464,
+                        1131793197335725002,
+                        286798588605141776,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2963702182986757071,
+                        13924212212050806491,
+                        3988293014526415099,
+                        746098693594074694,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13644408669730568739,
+                        13592240252411350391,
+                        4446894207758663525,
+                        716137638190996475,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11547057497391602222,
+                        9994602871744571254,
+                        16734784772244830970,
+                        429483825369679117,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        606118507872861832,
+                        5389765534705317397,
+                        6600024451260133071,
+                        72217868880249074,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9779170447946803608,
+                        12705811832510739489,
+                        911608193062473198,
+                        349100008632886729,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8606165576589755257,
+                        8454917919204660396,
+                        8701226121311062501,
+                        749145604363211462,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12779786714072407756,
+                        5872162744790673925,
+                        10635629419951499639,
+                        51322060135891106,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10040030253144064139,
+                        5654418216386830440,
+                        2528274219535671088,
+                        276482446112442430,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14000659238631109371,
+                        2075220430183040695,
+                        5301720149663119615,
+                        958247489515557379,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16171758426203361731,
+                        12896589130054300736,
+                        12893521691183845807,
+                        1008711969398206587,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4067587786350638261,
+                        6915565728083163730,
+                        12999273322463316129,
+                        474696316460436694,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11451434072029574210,
+                        13424232926760854206,
+                        15229466561603676335,
+                        1073537223528044718,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16166334128630959753,
+                        18345033569308076345,
+                        7780957120643379072,
+                        416092432953009569,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1609062686490141503,
+                        16772451711215305751,
+                        10373812400171511122,
+                        684506201038205631,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12670603061276857118,
+                        10739344297442210822,
+                        577210140073721397,
+                        714810435996541923,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1030789151231651852,
+                        6276266658231312612,
+                        10387293049858073352,
+                        530506103611925681,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13677764739282977437,
+                        18446098191788425042,
+                        12097518859540165516,
+                        776509510352930991,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4321434974410245218,
+                        5464919931706107873,
+                        5275707242287847685,
+                        825401864154460486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4831963703991846995,
+                        4287573774015716264,
+                        6370329942810860620,
+                        614643526850553511,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14212831383495951965,
+                        7196420999998735392,
+                        10492567783424206457,
+                        981155350299156571,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8973602122571746232,
+                        11912073191015203475,
+                        5340377354743614977,
+                        1005593273503162896,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9335685206694918381,
+                        15382487007485339900,
+                        7078158414808549654,
+                        999997782249936045,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15289226575476575680,
+                        8248158490116784693,
+                        11758775018930311949,
+                        919522356985235055,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9714900137117084682,
+                        16614243471633599480,
+                        8171908118238752425,
+                        1137960326044549832,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12330610354136608315,
+                        1438535907064543232,
+                        10123460495564694210,
+                        1158723854041488361,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15626580778459008978,
+                        13221524369548170243,
+                        634505374112649553,
+                        1329267545538681376,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8823144141484580166,
+                        12365302354970417162,
+                        14770258266512911233,
+                        787210533037091504,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4544823070525253683,
+                        17035252757364324079,
+                        12592094328873391729,
+                        7702135490931267,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14074211369619600043,
+                        16555935682290920070,
+                        15813125417138093955,
+                        188315741408636404,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5679743153092134400,
+                        2922190385880885957,
+                        3113463993443231804,
+                        947788739280009202,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16774707171611185037,
+                        14752763997456234894,
+                        5461985730545885047,
+                        395390743937158505,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5198710621931755538,
+                        17244558763325807787,
+                        10833213891367683257,
+                        610532842826952700,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14472597420623611617,
+                        3185206089742849739,
+                        10931060478790112597,
+                        1012695745624967870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4191957783665913968,
+                        4622745810371195497,
+                        2083192369379864506,
+                        47603334666780890,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<68, 4, 272>::new_from_known([
+            Fq::from_montgomery_limbs([
+                4626289237774878460,
+                198733277357347754,
+                10849297405444480636,
+                134950219144047273,
+            ]),
+            Fq::from_montgomery_limbs([
+                7432296167523944445,
+                12517129769158703114,
+                16760167132821509271,
+                695644487310729485,
+            ]),
+            Fq::from_montgomery_limbs([
+                15383115627129162229,
+                18382988358080022552,
+                10145262553915579100,
+                1084140441936124151,
+            ]),
+            Fq::from_montgomery_limbs([
+                14619846452794389072,
+                5372794595939635403,
+                16389349572478763919,
+                370518896378759538,
+            ]),
+            Fq::from_montgomery_limbs([
+                149110649374376044,
+                11875360297174774141,
+                7370759157405563580,
+                516590369616314979,
+            ]),
+            Fq::from_montgomery_limbs([
+                15695657644873130265,
+                1346857608463653906,
+                10150251805266052222,
+                809274206094684297,
+            ]),
+            Fq::from_montgomery_limbs([
+                14635859071380082852,
+                7178613336375243988,
+                6637786169169269953,
+                810859623994326533,
+            ]),
+            Fq::from_montgomery_limbs([
+                17780163687579399854,
+                17109264805101356917,
+                13173357081187189394,
+                663862961880380969,
+            ]),
+            Fq::from_montgomery_limbs([
+                3459415487274206131,
+                16744672442660119489,
+                11749725763710436606,
+                922602937847058907,
+            ]),
+            Fq::from_montgomery_limbs([
+                18217508895597206960,
+                10265631242015213409,
+                17105380386080932395,
+                1087497955434984898,
+            ]),
+            Fq::from_montgomery_limbs([
+                6384905615110330842,
+                3254272406633651236,
+                114357602993556640,
+                1236665135601771203,
+            ]),
+            Fq::from_montgomery_limbs([
+                2673010102326699656,
+                9903636705186683475,
+                13035934920735466443,
+                184840211937199785,
+            ]),
+            Fq::from_montgomery_limbs([
+                1818027068309755722,
+                4848553927980352321,
+                12090517013431746848,
+                98879906471697068,
+            ]),
+            Fq::from_montgomery_limbs([
+                12331677156980148034,
+                17065206484944168573,
+                5028894249297055301,
+                394932347704016314,
+            ]),
+            Fq::from_montgomery_limbs([
+                100557401774046155,
+                8641571472852537998,
+                4600696733283259504,
+                262064574640147491,
+            ]),
+            Fq::from_montgomery_limbs([
+                7662225094443175420,
+                9333645449416318834,
+                14416879590042255606,
+                897006035754354627,
+            ]),
+            Fq::from_montgomery_limbs([
+                13024562031489671457,
+                18249179014652338074,
+                3233689912588683964,
+                172938494765623179,
+            ]),
+            Fq::from_montgomery_limbs([
+                12070692407367019054,
+                9879488867350737178,
+                10162815514676471369,
+                1113126736728307420,
+            ]),
+            Fq::from_montgomery_limbs([
+                8659462344299814375,
+                17488235214432503630,
+                15445936009429145013,
+                87234112562451480,
+            ]),
+            Fq::from_montgomery_limbs([
+                67777254892897430,
+                13591309880879998400,
+                8015706177659970896,
+                375477756044012300,
+            ]),
+            Fq::from_montgomery_limbs([
+                1211714125016613904,
+                3411706460839321558,
+                828698582378753668,
+                980063800870190765,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                18315291809666369840,
+                6400180208251012352,
+                17992962137253081737,
+                1068153215509798409,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13719555690019153661,
+                16646064999595768980,
+                8801083924635843747,
+                1042156461585281339,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6651995316636372422,
+                1758041012155171154,
+                13219478182490219339,
+                16820168663548481,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3008860870315202509,
+                393145254556554019,
+                14160099184626684850,
+                573073468855813167,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6574737313587464960,
+                14330930811466345933,
+                17369710798984184686,
+                626015090899885277,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10283906789679871040,
+                2369675495341076060,
+                17402470727160901266,
+                147003242162514483,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14291489838603935164,
+                4753870388364710017,
+                14586025983105835822,
+                271306197897975234,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6079860127052178064,
+                17074014042738462869,
+                12702342692142485296,
+                518353618391418429,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15704037203814452976,
+                6273237593793682618,
+                15627416680564887299,
+                1028681101552477478,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2209598947048001665,
+                1134352794741384220,
+                11504981670869863136,
+                9217453613343790,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6095369779230361217,
+                125413668934275242,
+                10066313426466640806,
+                99039600645090839,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8417872208594531048,
+                3318041046108600255,
+                17926550645257570918,
+                1293977439397394211,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10246584654337550591,
+                14708011333978477413,
+                3349235311964738334,
+                797483135334830779,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13596617225265718024,
+                10393553845079363975,
+                3232910429366514598,
+                215747116571796583,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9405735516003833738,
+                749132312997850718,
+                11978061155910634716,
+                132503406290649741,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2968713632976086234,
+                7819584255635180465,
+                6555269969937322048,
+                788646386910370913,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9450831136926388528,
+                3711716993193246506,
+                17320006191529719082,
+                155512483261112142,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14712728334099092712,
+                6290951385853156776,
+                17903658615855214669,
+                97673020341164293,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9795734898231637317,
+                15365533597591006357,
+                7061479029505642827,
+                1296227768141569124,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13273147304412494469,
+                16942601199181744288,
+                13648426093104103264,
+                1225081337697565783,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11189975328574753767,
+                12200112871192174109,
+                17753573285827691534,
+                760105609054450074,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7427678865962654519,
+                2910447187139385564,
+                5156518282055322913,
+                791400432247302099,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                21460459622267716,
+                10990960623741494510,
+                5018202485050504789,
+                429845660948685834,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10403576879245598625,
+                5769912486406439817,
+                13927542851189359352,
+                76811812148907398,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1777730743986705787,
+                2317338043983376724,
+                3629787878696810597,
+                294970492700613205,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                18210847063576558522,
+                4093550421685597589,
+                13695560809531177906,
+                632309532444539558,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10112963188999783802,
+                13902729702066933359,
+                11777574831533796996,
+                157604881645783684,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15852173718235688120,
+                7195870315654855973,
+                10533969422148677280,
+                781039938043791914,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17691181048337333421,
+                13800125527009792472,
+                8837503735471272248,
+                1130140090293547163,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                18129746415235782641,
+                5207835112355285101,
+                4022015546605170566,
+                130938907427727842,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11120853345772211219,
+                7322394693642885554,
+                15088447775892970087,
+                214466593568285569,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16808475698349174487,
+                1052231424351385125,
+                8754217230829945020,
+                1169612195090070463,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2285056480606556308,
+                11747315860992552753,
+                18139253500522947141,
+                910560561872369213,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3882785711611531116,
+                4169694103823143705,
+                17011977600092655264,
+                116419052040014993,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11256079796360431480,
+                14619449840229264469,
+                7450199164061639658,
+                1113167061560322679,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16752126074366178752,
+                3939422278257495955,
+                9907408156381082150,
+                888420841841254122,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16360213333669464335,
+                14020784845673425581,
+                4060499713787805466,
+                222718535429297462,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                862867771493161999,
+                1782001770076634374,
+                3209292095592179774,
+                185938651178859690,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3939036921612400070,
+                13370616559139291875,
+                15818244713687099888,
+                464166905578458551,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8039448081172121602,
+                182946820829784955,
+                9523993070526579023,
+                1161381454376452307,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                311054564638126980,
+                10606694488424207119,
+                3129056405213794761,
+                992703534365638441,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5866451818327441309,
+                2420636191789249787,
+                16129063356587660123,
+                571218801746305306,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                18177368154678728043,
+                2663732040515391529,
+                508010821640635728,
+                210673546636182892,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4049131173642032832,
+                5342184085478543686,
+                169567882797016241,
+                44425178142955726,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                881083033969090388,
+                17505413298236133292,
+                11642528770890754798,
+                914771372101888847,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17779174834076903404,
+                18120583296899305375,
+                17153799492160654237,
+                817299213599208236,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1515349207834814064,
+                5097217727258631603,
+                12424153423570612753,
+                1263270166034389448,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3623233375731248076,
+                15852323851923670217,
+                7400766027851041218,
+                1235381101735507990,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17686421750035078420,
+                8654100849717881310,
+                10039471384257832909,
+                28097129195970345,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9035903206665875803,
+                15726623424431219174,
+                3616468236393309807,
+                558252513088387201,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10031926263741859861,
+                1631286480386390428,
+                6268820953904538694,
+                570364219414530971,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10196908660696515986,
+                168763415752947919,
+                17528052990582167469,
+                416910846627870305,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17833928397762570639,
+                13525148877599802756,
+                10085948120732067520,
+                130176274731018843,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12577976051265733282,
+                3804419745339221582,
+                1624572655290530239,
+                10423725671795034,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13423397537845234460,
+                12952289616390279313,
+                14506172455811486078,
+                22205014721770464,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5839347629689727640,
+                4494629593947974384,
+                1839167739334647660,
+                784233424308089849,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7892412009028309483,
+                5822358494650609076,
+                14676983012541194885,
+                250346121514058443,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                834489815954580076,
+                7588796990405649871,
+                1194886411396100239,
+                1209986531052129957,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15572083090215806404,
+                9909349134065276134,
+                14180760171061450551,
+                667303665756657720,
+            ]),
+            Fq::from_montgomery_limbs([
+                7476867300598554417,
+                14236328963521901243,
+                15848610386825569411,
+                869974584163465676,
+            ]),
+            Fq::from_montgomery_limbs([
+                4533171598744738120,
+                10578207459518437236,
+                17652592073323995619,
+                764670210149861892,
+            ]),
+            Fq::from_montgomery_limbs([
+                8795125030294554426,
+                429639097260806371,
+                4358153334220338100,
+                986200791415020066,
+            ]),
+            Fq::from_montgomery_limbs([
+                7824608946799782915,
+                14222028469967041395,
+                10206843147326342494,
+                503130256442416234,
+            ]),
+            Fq::from_montgomery_limbs([
+                7768720481974636943,
+                10765282296898856654,
+                17673498866098002199,
+                902701250340732908,
+            ]),
+            Fq::from_montgomery_limbs([
+                6409667327929371065,
+                1046965721220690096,
+                13879512738389800517,
+                499816093040784708,
+            ]),
+            Fq::from_montgomery_limbs([
+                15877173119890911331,
+                17603806883557890165,
+                18246331482143588590,
+                218055346584842532,
+            ]),
+            Fq::from_montgomery_limbs([
+                13490594611859682961,
+                6967334791237467527,
+                59995576877379089,
+                523811940870407281,
+            ]),
+            Fq::from_montgomery_limbs([
+                3007237423379021095,
+                5434343611640907369,
+                14080147001138590818,
+                1163737982644848853,
+            ]),
+            Fq::from_montgomery_limbs([
+                901111695691285081,
+                6798501027781781909,
+                17059835832931652262,
+                1042362653909925369,
+            ]),
+            Fq::from_montgomery_limbs([
+                337173820523400589,
+                11107563846918020842,
+                17009798712525704503,
+                109656541916907056,
+            ]),
+            Fq::from_montgomery_limbs([
+                14679060670944291661,
+                11068725621119699417,
+                9448122273372966723,
+                47100285507741049,
+            ]),
+            Fq::from_montgomery_limbs([
+                13871182430951648964,
+                4899407830287706504,
+                11741448443903112417,
+                266788255860261849,
+            ]),
+            Fq::from_montgomery_limbs([
+                6393491350204380938,
+                3683207668503950324,
+                5060323037882234027,
+                1160488470452941198,
+            ]),
+            Fq::from_montgomery_limbs([
+                6573242545526778560,
+                12628430035188265495,
+                16794301583241257793,
+                623002988391532211,
+            ]),
+        ]),
+    }
+}
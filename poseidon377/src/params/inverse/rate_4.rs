@@ -0,0 +1,6918 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-4 instance of Poseidon with the inverse S-box.
+pub const fn rate_4() -> PoseidonParameters<5, 4, 25, 16, 68, 5, 340, 60> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<68, 5, 340>::new_from_known([
+            Fq::from_montgomery_limbs([
+                11444359781275743848,
+                1455917629395179315,
+                2262903860768154529,
+                135894445797360378,
+            ]),
+            Fq::from_montgomery_limbs([
+                11059842620545411989,
+                10365279141557279334,
+                9477008543253703518,
+                672816766707496810,
+            ]),
+            Fq::from_montgomery_limbs([
+                3638917413472569546,
+                6107166115798364001,
+                1290184584215263076,
+                460448795686855285,
+            ]),
+            Fq::from_montgomery_limbs([
+                6809433025061013926,
+                5290285289921514834,
+                6878335838464463692,
+                620414731088357853,
+            ]),
+            Fq::from_montgomery_limbs([
+                14287112920661306258,
+                552049971771472885,
+                16454210513972539858,
+                801163911327420192,
+            ]),
+            Fq::from_montgomery_limbs([
+                1730403402351437022,
+                12256360978882985568,
+                9610616155096468114,
+                403278780515934743,
+            ]),
+            Fq::from_montgomery_limbs([
+                7312080600784983836,
+                131719895904407520,
+                11656980462628393569,
+                1201901630910062296,
+            ]),
+            Fq::from_montgomery_limbs([
+                9717985347310934710,
+                322997940906060467,
+                15428452811957309233,
+                765679228479797476,
+            ]),
+            Fq::from_montgomery_limbs([
+                18209971998091530004,
+                3774255157718202923,
+                11245187046985147533,
+                574328914560891980,
+            ]),
+            Fq::from_montgomery_limbs([
+                14719594514279450112,
+                12546162991102780071,
+                650136013555796689,
+                1193723199667534770,
+            ]),
+            Fq::from_montgomery_limbs([
+                12540368630399625247,
+                6747277740992143419,
+                13901254473808262974,
+                1069697204093621027,
+            ]),
+            Fq::from_montgomery_limbs([
+                6006201097159998132,
+                10993057243736521057,
+                2481643342887052237,
+                1040742999317755196,
+            ]),
+            Fq::from_montgomery_limbs([
+                13983135365813373229,
+                11208211684809202876,
+                9923639616764140716,
+                800988735500877745,
+            ]),
+            Fq::from_montgomery_limbs([
+                10817845003975313022,
+                10199444559971109476,
+                1583825088432788889,
+                1197118818187050562,
+            ]),
+            Fq::from_montgomery_limbs([
+                12908596521829268600,
+                12726919235664744581,
+                13434581855162388549,
+                1260270868210446919,
+            ]),
+            Fq::from_montgomery_limbs([
+                9292047966280685305,
+                16387361546876825382,
+                11386338994503295441,
+                857840649330403150,
+            ]),
+            Fq::from_montgomery_limbs([
+                8646153131933302332,
+                6741783001854103874,
+                9451383129938123241,
+                106732509056129157,
+            ]),
+            Fq::from_montgomery_limbs([
+                5782954814940929954,
+                2405431940747907071,
+                15841248711212087854,
+                1083895051666594422,
+            ]),
+            Fq::from_montgomery_limbs([
+                4340313846582327906,
+                5727693805212258027,
+                10066603269023157785,
+                486039960145075847,
+            ]),
+            Fq::from_montgomery_limbs([
+                9995913207019958712,
+                11617914869142635570,
+                1815443648170262347,
+                716717811676024152,
+            ]),
+            Fq::from_montgomery_limbs([
+                7393248206340594542,
+                11714942883647334975,
+                1136635516182492135,
+                118732845285417990,
+            ]),
+            Fq::from_montgomery_limbs([
+                8268216445798022670,
+                9720016365943680789,
+                13058424808512971932,
+                522703096184988048,
+            ]),
+            Fq::from_montgomery_limbs([
+                14349894218852371473,
+                15124382674883863254,
+                9545419870506081335,
+                313420180808387399,
+            ]),
+            Fq::from_montgomery_limbs([
+                8138420888664401478,
+                9689753152809740081,
+                17497715679329159349,
+                474870953815501711,
+            ]),
+            Fq::from_montgomery_limbs([
+                7134717566106251637,
+                3653969937666748020,
+                12545445268878303916,
+                466291796676310503,
+            ]),
+            Fq::from_montgomery_limbs([
+                15303301878956234969,
+                4254125936812690994,
+                6916778590649204000,
+                393651137068285563,
+            ]),
+            Fq::from_montgomery_limbs([
+                8651480781156783922,
+                4699829695388889012,
+                18087303321813074510,
+                139943893408717703,
+            ]),
+            Fq::from_montgomery_limbs([
+                3983684528202113502,
+                17252238172581929344,
+                6819858906384911441,
+                66260422515549038,
+            ]),
+            Fq::from_montgomery_limbs([
+                5239825952856929994,
+                9822681396149003643,
+                2171807110729082676,
+                870849134296755543,
+            ]),
+            Fq::from_montgomery_limbs([
+                2036663302167975635,
+                8342815218724740238,
+                5678892337175656391,
+                100236418257927126,
+            ]),
+            Fq::from_montgomery_limbs([
+                17725700965690902898,
+                10368278499085728794,
+                4238525364650917395,
+                30364848454958591,
+            ]),
+            Fq::from_montgomery_limbs([
+                4103068242541470556,
+                1847593394253550089,
+                15169371409191457996,
+                1162683734474178009,
+            ]),
+            Fq::from_montgomery_limbs([
+                7804227563589711184,
+                7358909914104619023,
+                8525729503421234434,
+                143484316803429678,
+            ]),
+            Fq::from_montgomery_limbs([
+                7359730198552765771,
+                15769884355835988561,
+                4144913137720137068,
+                74238359904047106,
+            ]),
+            Fq::from_montgomery_limbs([
+                623873110067049394,
+                4583870809066548679,
+                154279206639133244,
+                1068292470914555015,
+            ]),
+            Fq::from_montgomery_limbs([
+                9022527890535265752,
+                337500271043208679,
+                17305287813917797010,
+                714349739862526178,
+            ]),
+            Fq::from_montgomery_limbs([
+                4709043124808021116,
+                7881293438265124560,
+                8281715359608429278,
+                503523091277869168,
+            ]),
+            Fq::from_montgomery_limbs([
+                13393219828077645488,
+                9993532460595647170,
+                18359606242261284305,
+                1082678368710870472,
+            ]),
+            Fq::from_montgomery_limbs([
+                133347833631128448,
+                4088270039450147543,
+                8360952189192868793,
+                741337810194247688,
+            ]),
+            Fq::from_montgomery_limbs([
+                17914067338334163023,
+                16953959986713912729,
+                17787693241653595200,
+                712176054770636512,
+            ]),
+            Fq::from_montgomery_limbs([
+                12594094250170873455,
+                4016263368599862651,
+                12075722062330691625,
+                1176859003904601677,
+            ]),
+            Fq::from_montgomery_limbs([
+                2847133312794790865,
+                16811353587975903279,
+                10378316712534480686,
+                1130127096409893769,
+            ]),
+            Fq::from_montgomery_limbs([
+                9121591897133851292,
+                4602134057186201407,
+                16733873472813961974,
+                116035560422792503,
+            ]),
+            Fq::from_montgomery_limbs([
+                14588003962459862383,
+                8243592546348241999,
+                3654801226279202207,
+                689338124977949942,
+            ]),
+            Fq::from_montgomery_limbs([
+                17092946096540898348,
+                18429858723362496584,
+                3264494815522776412,
+                412255181485942053,
+            ]),
+            Fq::from_montgomery_limbs([
+                5897525235619345412,
+                1063100560334896900,
+                17923538955039018700,
+                16824287588321017,
+            ]),
+            Fq::from_montgomery_limbs([
+                8789695623181894363,
+                17534244784978671639,
+                7976194284526199455,
+                1100597604054065106,
+            ]),
+            Fq::from_montgomery_limbs([
+                3047095362833167675,
+                11164733504939278467,
+                9824245487248313673,
+                1297342716217616953,
+            ]),
+            Fq::from_montgomery_limbs([
+                13640988039966840117,
+                16474879254562835227,
+                6390602556341136634,
+                854589324955352192,
+            ]),
+            Fq::from_montgomery_limbs([
+                14010992546996398438,
+                16738640104780828893,
+                5294869474831797095,
+                654384686312105276,
+            ]),
+            Fq::from_montgomery_limbs([
+                2437517257110028765,
+                13356657092347496929,
+                643011938410623090,
+                32179543651695539,
+            ]),
+            Fq::from_montgomery_limbs([
+                2033458532366329332,
+                13241248540683712141,
+                17186265944837486781,
+                438675627685866008,
+            ]),
+            Fq::from_montgomery_limbs([
+                7354117229113429474,
+                13665873815796204771,
+                7093371426857003445,
+                802693322236941498,
+            ]),
+            Fq::from_montgomery_limbs([
+                13349762696747587801,
+                11965291375988715487,
+                9194245086669984455,
+                342618471869091403,
+            ]),
+            Fq::from_montgomery_limbs([
+                3012642714187869965,
+                13869775151017646451,
+                1984484728461241532,
+                362668126350429695,
+            ]),
+            Fq::from_montgomery_limbs([
+                14956940546568910009,
+                5119281148863309435,
+                3908151176666228423,
+                410782482881696246,
+            ]),
+            Fq::from_montgomery_limbs([
+                15408018849044815872,
+                10280684148743337934,
+                12830672421843184828,
+                547515680437881394,
+            ]),
+            Fq::from_montgomery_limbs([
+                17696479002305442470,
+                12012781605008675928,
+                6820356930797049779,
+                825230446404084268,
+            ]),
+            Fq::from_montgomery_limbs([
+                4670556791416434370,
+                7545634579195057989,
+                17109185836067661742,
+                127440653201441330,
+            ]),
+            Fq::from_montgomery_limbs([
+                8893253942928109933,
+                11311844297174562954,
+                4510449300152353268,
+                212518086856607654,
+            ]),
+            Fq::from_montgomery_limbs([
+                7817694179462214500,
+                3490410040851487343,
+                7550899874713586653,
+                494243291614590957,
+            ]),
+            Fq::from_montgomery_limbs([
+                13989231556768041200,
+                11327333356146622182,
+                8483198607987920464,
+                692517277950399633,
+            ]),
+            Fq::from_montgomery_limbs([
+                6143540116727994159,
+                1574798602562659263,
+                15819856501200580105,
+                359300592511628952,
+            ]),
+            Fq::from_montgomery_limbs([
+                7137257292722434024,
+                15847027328923293355,
+                6907180519048073977,
+                1159389016683311656,
+            ]),
+            Fq::from_montgomery_limbs([
+                5550265698976135089,
+                17442039913986233032,
+                3851603644483726031,
+                632382814124541491,
+            ]),
+            Fq::from_montgomery_limbs([
+                7222658047597205873,
+                15009519127224738738,
+                13161843976219611828,
+                571238052356117842,
+            ]),
+            Fq::from_montgomery_limbs([
+                5140844395262528960,
+                3241095171434137557,
+                5997856229812930348,
+                863674919393281878,
+            ]),
+            Fq::from_montgomery_limbs([
+                6110831522050946210,
+                3690601540422897795,
+                14916109812595742033,
+                861698171542149088,
+            ]),
+            Fq::from_montgomery_limbs([
+                4164760126973588786,
+                14990050700368511804,
+                15286355596384687617,
+                1105799955863022427,
+            ]),
+            Fq::from_montgomery_limbs([
+                4595669788587068269,
+                16872276124093405056,
+                10484199288745937590,
+                472513644202370722,
+            ]),
+            Fq::from_montgomery_limbs([
+                5279949339844057804,
+                1128031606100189254,
+                6456085168627682508,
+                810017880864572840,
+            ]),
+            Fq::from_montgomery_limbs([
+                4147528777400077549,
+                443632320298986537,
+                12798239119557825648,
+                733822065023828251,
+            ]),
+            Fq::from_montgomery_limbs([
+                7784997216767807015,
+                17506027333514554075,
+                1465381579214458066,
+                298895566783845951,
+            ]),
+            Fq::from_montgomery_limbs([
+                6290401510201562542,
+                6941360225721189108,
+                3319968889100897314,
+                94986696850372962,
+            ]),
+            Fq::from_montgomery_limbs([
+                14286646643869915149,
+                7672093690796676153,
+                10262247739990679511,
+                760690395181578922,
+            ]),
+            Fq::from_montgomery_limbs([
+                496947670184930366,
+                13505499772517863748,
+                9613048126341600216,
+                329514823287978961,
+            ]),
+            Fq::from_montgomery_limbs([
+                5130310432385937557,
+                18087536592522726462,
+                8753647678092672329,
+                1035267065872357887,
+            ]),
+            Fq::from_montgomery_limbs([
+                12482154834541049857,
+                6818850351967628535,
+                8951929611562643867,
+                116828909506346067,
+            ]),
+            Fq::from_montgomery_limbs([
+                4410384663195341007,
+                15893051594205661055,
+                7403758627516253183,
+                1344185872492258335,
+            ]),
+            Fq::from_montgomery_limbs([
+                12063738386798107528,
+                4408325455155367026,
+                11683490688198306484,
+                107297023739288430,
+            ]),
+            Fq::from_montgomery_limbs([
+                18033288381427453232,
+                17804698650616543158,
+                9680420043793102871,
+                667015814741632655,
+            ]),
+            Fq::from_montgomery_limbs([
+                8807509295501174773,
+                1627023226731099128,
+                11976907260683702979,
+                1201799035735932935,
+            ]),
+            Fq::from_montgomery_limbs([
+                8457102643935846622,
+                3238825522911940605,
+                3820916597967505605,
+                897631039882127281,
+            ]),
+            Fq::from_montgomery_limbs([
+                7466393516082932824,
+                2818747362766233944,
+                11160116047060059730,
+                826766614238010619,
+            ]),
+            Fq::from_montgomery_limbs([
+                13890143726830873501,
+                14997472361544327910,
+                11037354079383429878,
+                1022256875189913261,
+            ]),
+            Fq::from_montgomery_limbs([
+                13158605144989235850,
+                6141534282727157208,
+                1688597725691383313,
+                50826136621635266,
+            ]),
+            Fq::from_montgomery_limbs([
+                15987677395215464501,
+                2702920973888747468,
+                8277302967307576209,
+                1339945269860203168,
+            ]),
+            Fq::from_montgomery_limbs([
+                8574857405790606900,
+                10119875136012166729,
+                4558184782807702651,
+                614495514047990883,
+            ]),
+            Fq::from_montgomery_limbs([
+                4943546100020860445,
+                8566830470841680581,
+                8279910293389834277,
+                360247934223986166,
+            ]),
+            Fq::from_montgomery_limbs([
+                18249270964571397773,
+                16187509182670360648,
+                8384888486746193691,
+                497661550581382349,
+            ]),
+            Fq::from_montgomery_limbs([
+                13722772218316619784,
+                5940764726459296177,
+                17484412344595992392,
+                582439505236345102,
+            ]),
+            Fq::from_montgomery_limbs([
+                4210459755284545721,
+                18433925902759965201,
+                5947700774429406241,
+                977312202472998429,
+            ]),
+            Fq::from_montgomery_limbs([
+                14059816537124598146,
+                17196494016565201815,
+                7591268883730214006,
+                225539105729960876,
+            ]),
+            Fq::from_montgomery_limbs([
+                13532073128971296732,
+                6759173358189004686,
+                304006880323359131,
+                15698752776850537,
+            ]),
+            Fq::from_montgomery_limbs([
+                15467908661298546954,
+                11620050357354931901,
+                18305512990559872883,
+                104136438282557631,
+            ]),
+            Fq::from_montgomery_limbs([
+                9309468812452045523,
+                10083116220112530131,
+                6871339553724981912,
+                279291542040209645,
+            ]),
+            Fq::from_montgomery_limbs([
+                5682466426315360930,
+                12321629370112482550,
+                17532222666121542927,
+                1330708441686952619,
+            ]),
+            Fq::from_montgomery_limbs([
+                16830834814343084490,
+                16564811827845298910,
+                17263462624123033408,
+                866437919155214210,
+            ]),
+            Fq::from_montgomery_limbs([
+                7246011886655010052,
+                14373393642485268214,
+                11310241329578792267,
+                879380940087477823,
+            ]),
+            Fq::from_montgomery_limbs([
+                13376848565442307751,
+                3831143007504760214,
+                7004450681581015689,
+                736539907140044232,
+            ]),
+            Fq::from_montgomery_limbs([
+                15917118333048846999,
+                2597422107671608431,
+                11841473146556628565,
+                755386279925763185,
+            ]),
+            Fq::from_montgomery_limbs([
+                7589466083804623730,
+                13127810550036926793,
+                11735095756108505748,
+                919805596287729923,
+            ]),
+            Fq::from_montgomery_limbs([
+                814667239801938572,
+                17333186655023754549,
+                7769496654589716492,
+                1298203387284387600,
+            ]),
+            Fq::from_montgomery_limbs([
+                18370413292240594092,
+                14979358052414453206,
+                2076346076274646103,
+                597712525138457306,
+            ]),
+            Fq::from_montgomery_limbs([
+                8710551300465911937,
+                17954016704918477248,
+                6709665985821427122,
+                514583997209804802,
+            ]),
+            Fq::from_montgomery_limbs([
+                10973409474470014373,
+                2829541434527163557,
+                15847443903063460608,
+                1008269913465595194,
+            ]),
+            Fq::from_montgomery_limbs([
+                9766790116310715225,
+                15190341099682099199,
+                6224374055360529038,
+                273283030315272782,
+            ]),
+            Fq::from_montgomery_limbs([
+                13973740100358058942,
+                4106939998899925293,
+                18379771321174893915,
+                10401210943479152,
+            ]),
+            Fq::from_montgomery_limbs([
+                10939379977020882388,
+                3826182764197888813,
+                3076829366242491969,
+                329894704354342727,
+            ]),
+            Fq::from_montgomery_limbs([
+                2357518024464041127,
+                16410112756639892613,
+                6275753651570883191,
+                472288289672484444,
+            ]),
+            Fq::from_montgomery_limbs([
+                16970648747769772239,
+                6049195396120187205,
+                4711997117024899747,
+                296192133340144384,
+            ]),
+            Fq::from_montgomery_limbs([
+                9111086349482947221,
+                9359556588978570526,
+                404405482928882040,
+                722218307575266728,
+            ]),
+            Fq::from_montgomery_limbs([
+                7545275583485871744,
+                14676227459229977719,
+                4235359253720094273,
+                408963796799260433,
+            ]),
+            Fq::from_montgomery_limbs([
+                10455934349839368894,
+                15160723662181230927,
+                15769607533798596973,
+                1203070768670331337,
+            ]),
+            Fq::from_montgomery_limbs([
+                7765770163548483071,
+                3613856156889639027,
+                7466791392318312190,
+                320159567379241848,
+            ]),
+            Fq::from_montgomery_limbs([
+                5762167103953555325,
+                13081338545265284272,
+                1246842974570966864,
+                832774427438314286,
+            ]),
+            Fq::from_montgomery_limbs([
+                5234237302611473206,
+                5936082576025433137,
+                4328203069427856629,
+                739491983136051032,
+            ]),
+            Fq::from_montgomery_limbs([
+                1890962866658788987,
+                1598576425198760893,
+                10056399082732342455,
+                1316494152418945734,
+            ]),
+            Fq::from_montgomery_limbs([
+                4915827516179399550,
+                9980420037322565369,
+                1067899700721645309,
+                249155284392514311,
+            ]),
+            Fq::from_montgomery_limbs([
+                17273754926612603574,
+                7504812450420304729,
+                17826537404586869061,
+                190415314805914822,
+            ]),
+            Fq::from_montgomery_limbs([
+                17907024356821571784,
+                570671747196024495,
+                8673967837449988735,
+                782304775735606672,
+            ]),
+            Fq::from_montgomery_limbs([
+                2791002592376661643,
+                11766203476428629259,
+                16689027751103882511,
+                794901909031780496,
+            ]),
+            Fq::from_montgomery_limbs([
+                9622086378174382907,
+                4699301517967499135,
+                11619746491927150427,
+                434746318159375160,
+            ]),
+            Fq::from_montgomery_limbs([
+                6662871276996692675,
+                434346002548655582,
+                2190491110713454839,
+                239973088050903325,
+            ]),
+            Fq::from_montgomery_limbs([
+                14772045423307051137,
+                9678100537373227469,
+                4755324425799671216,
+                950160359651978855,
+            ]),
+            Fq::from_montgomery_limbs([
+                15320535870262856162,
+                6055864394332096204,
+                11485523979573357278,
+                1331106978997203376,
+            ]),
+            Fq::from_montgomery_limbs([
+                16157840201479800246,
+                15356085375418985048,
+                2673874329235497782,
+                339509131847819242,
+            ]),
+            Fq::from_montgomery_limbs([
+                6170642658023415203,
+                11849183803230429357,
+                4970008758181288265,
+                324799688655781077,
+            ]),
+            Fq::from_montgomery_limbs([
+                7810242228643702244,
+                16225773586503598407,
+                10378311866003884136,
+                252100892923987917,
+            ]),
+            Fq::from_montgomery_limbs([
+                2416730544780954951,
+                12403623446312320878,
+                18053386532949844243,
+                764119872086370051,
+            ]),
+            Fq::from_montgomery_limbs([
+                11148163417840314581,
+                2855303643979081253,
+                16140185171257845862,
+                1039123736578812814,
+            ]),
+            Fq::from_montgomery_limbs([
+                15044642397309173359,
+                1006256330804267630,
+                12442733044305992017,
+                1022355312586565531,
+            ]),
+            Fq::from_montgomery_limbs([
+                8812174179517875927,
+                7683498192650150980,
+                4146846206789933379,
+                645259507325963842,
+            ]),
+            Fq::from_montgomery_limbs([
+                1154349994167526019,
+                30300430942585911,
+                12359676801378649036,
+                457811413403856751,
+            ]),
+            Fq::from_montgomery_limbs([
+                1785852821745362663,
+                4437102475994476365,
+                5315693462643772059,
+                369048951771532416,
+            ]),
+            Fq::from_montgomery_limbs([
+                7871829068547245977,
+                18295933232919177901,
+                10704269033051650971,
+                1198944773191380837,
+            ]),
+            Fq::from_montgomery_limbs([
+                1085145385099584869,
+                1488518730661901981,
+                3688705085422098489,
+                304406630576120096,
+            ]),
+            Fq::from_montgomery_limbs([
+                566006225316943517,
+                11175905210215849296,
+                12704033044714222122,
+                306665586329569616,
+            ]),
+            Fq::from_montgomery_limbs([
+                17566464482467011344,
+                8407432752877932104,
+                15250915544713154071,
+                1117605074343982160,
+            ]),
+            Fq::from_montgomery_limbs([
+                18061061338178433716,
+                12909931840728614652,
+                3753237821220141364,
+                1044484264303071760,
+            ]),
+            Fq::from_montgomery_limbs([
+                16664461886140910037,
+                8962433609725475981,
+                10841109351521388073,
+                498480263193961530,
+            ]),
+            Fq::from_montgomery_limbs([
+                16924584454259911229,
+                9308971900983798710,
+                15942232115709347454,
+                1071198568534104308,
+            ]),
+            Fq::from_montgomery_limbs([
+                12344126661883176128,
+                13125316048337666127,
+                970979956245486199,
+                34725693762696155,
+            ]),
+            Fq::from_montgomery_limbs([
+                12489029642505002041,
+                158614419193508828,
+                15724524508923500208,
+                567430586350506619,
+            ]),
+            Fq::from_montgomery_limbs([
+                5281572003550278845,
+                13068964495204369314,
+                1873321437243161940,
+                992353968182352353,
+            ]),
+            Fq::from_montgomery_limbs([
+                12111566266410729370,
+                8089349696871287858,
+                17965684527160691049,
+                655793133244447514,
+            ]),
+            Fq::from_montgomery_limbs([
+                3534214152499922267,
+                17072022224267961257,
+                8892770104829853429,
+                583195457273933595,
+            ]),
+            Fq::from_montgomery_limbs([
+                2904717391554433737,
+                15024722668854452770,
+                9374686945496897475,
+                79447085790059853,
+            ]),
+            Fq::from_montgomery_limbs([
+                5027433987884588182,
+                2522917969787681265,
+                8786682616327473991,
+                902428513418622435,
+            ]),
+            Fq::from_montgomery_limbs([
+                10149534543051810220,
+                6667850000469934597,
+                1276687791408258164,
+                754281992350074315,
+            ]),
+            Fq::from_montgomery_limbs([
+                10064615362712004358,
+                14604991723761011955,
+                10690638403153551156,
+                884159068085512175,
+            ]),
+            Fq::from_montgomery_limbs([
+                10342328306552277749,
+                109149107689530326,
+                5928750201893917278,
+                1309307293731251003,
+            ]),
+            Fq::from_montgomery_limbs([
+                16888791300695399792,
+                2764123469056829322,
+                6809209035745501224,
+                42685536639549009,
+            ]),
+            Fq::from_montgomery_limbs([
+                14527071387557041996,
+                1026195674076811114,
+                1021947498222830097,
+                404649378173737131,
+            ]),
+            Fq::from_montgomery_limbs([
+                10757071725336234464,
+                13427615385433559930,
+                13051487490142736010,
+                885021471820244536,
+            ]),
+            Fq::from_montgomery_limbs([
+                6421387090390621038,
+                12705859931948419640,
+                10292145185186732304,
+                1016384064598461194,
+            ]),
+            Fq::from_montgomery_limbs([
+                15999972266209661622,
+                11841014757449605099,
+                6164617969493126793,
+                1054691287139991581,
+            ]),
+            Fq::from_montgomery_limbs([
+                11931139917520064166,
+                11341945455821254532,
+                14642916483182605546,
+                745078170810065534,
+            ]),
+            Fq::from_montgomery_limbs([
+                17587634230395259026,
+                4125444832889708304,
+                11299645449541337032,
+                222939588353265889,
+            ]),
+            Fq::from_montgomery_limbs([
+                4606740213587000972,
+                10294658248753984970,
+                8561149117799173737,
+                616250241843385218,
+            ]),
+            Fq::from_montgomery_limbs([
+                3955722105774187713,
+                2884470598245364247,
+                17226948487721956468,
+                149037984699228446,
+            ]),
+            Fq::from_montgomery_limbs([
+                10361233725761130546,
+                8243261024194452467,
+                17240358701473916170,
+                554427615088177442,
+            ]),
+            Fq::from_montgomery_limbs([
+                4476874960839450612,
+                10588215062582474340,
+                3990484839067284848,
+                876446427848796539,
+            ]),
+            Fq::from_montgomery_limbs([
+                6636721091072606609,
+                12485379177871047818,
+                4083223296821209399,
+                1307635134848520381,
+            ]),
+            Fq::from_montgomery_limbs([
+                3312505860499295396,
+                16113693035406758884,
+                1456740931066360568,
+                271992864933357995,
+            ]),
+            Fq::from_montgomery_limbs([
+                7400152326859202316,
+                12869003708945900494,
+                11198209684911849380,
+                1253039621432509812,
+            ]),
+            Fq::from_montgomery_limbs([
+                18111328983210118838,
+                890968636272141644,
+                12523930243441921501,
+                620266698276090141,
+            ]),
+            Fq::from_montgomery_limbs([
+                15798651202281924725,
+                9292758150248675641,
+                5797469057928738941,
+                667824646188715551,
+            ]),
+            Fq::from_montgomery_limbs([
+                5438927785317359907,
+                7362954435429323248,
+                17684455660534217435,
+                891715832794833273,
+            ]),
+            Fq::from_montgomery_limbs([
+                11088062501997918576,
+                2936306310637280409,
+                18422160582877475429,
+                484281530167000615,
+            ]),
+            Fq::from_montgomery_limbs([
+                7755937992850838057,
+                5455506747314397408,
+                527645879918077287,
+                1037237760311237159,
+            ]),
+            Fq::from_montgomery_limbs([
+                1361484680513695555,
+                1073066347770560165,
+                7631486143373813404,
+                537931283720332113,
+            ]),
+            Fq::from_montgomery_limbs([
+                14631542321045187345,
+                5664644823006161374,
+                5789928365369438970,
+                836456293099483576,
+            ]),
+            Fq::from_montgomery_limbs([
+                17153764738659138829,
+                12291740035251981494,
+                9975366725598273402,
+                308012548997885494,
+            ]),
+            Fq::from_montgomery_limbs([
+                14665092947865558642,
+                252125015269138091,
+                15475343025421049770,
+                416182884366316286,
+            ]),
+            Fq::from_montgomery_limbs([
+                3494120612661284202,
+                17510451360813974443,
+                1079097394071596433,
+                349493048522468538,
+            ]),
+            Fq::from_montgomery_limbs([
+                9813163256863013107,
+                10728934356299873487,
+                16122106068985343984,
+                1007777302056134339,
+            ]),
+            Fq::from_montgomery_limbs([
+                14336902357835207707,
+                9093868502424160065,
+                6032805444922259399,
+                521582348912397697,
+            ]),
+            Fq::from_montgomery_limbs([
+                280447140646577085,
+                12924925353236395282,
+                15697125062350020246,
+                193483588665471143,
+            ]),
+            Fq::from_montgomery_limbs([
+                4842005574999981623,
+                15942631280984239489,
+                6796426327508133553,
+                451732601313196296,
+            ]),
+            Fq::from_montgomery_limbs([
+                17010826321953644800,
+                12377797066951739307,
+                2965810040923699979,
+                127329330022539993,
+            ]),
+            Fq::from_montgomery_limbs([
+                1470111747296286135,
+                7936706627196580872,
+                7712937497831243763,
+                1196791257741790089,
+            ]),
+            Fq::from_montgomery_limbs([
+                15574751708139568243,
+                4153343194760931582,
+                10413421590330679587,
+                245844590407162322,
+            ]),
+            Fq::from_montgomery_limbs([
+                11220938682801770197,
+                1979137072569612010,
+                7808385571388184851,
+                140993729198203615,
+            ]),
+            Fq::from_montgomery_limbs([
+                10799687242669742976,
+                12173569044830122185,
+                11801542183990743524,
+                1106215119114976622,
+            ]),
+            Fq::from_montgomery_limbs([
+                2734246178335856362,
+                3607703338274554178,
+                1080879812306769069,
+                754805514876320794,
+            ]),
+            Fq::from_montgomery_limbs([
+                13430192342741681035,
+                13770394876580173989,
+                7551359118452370588,
+                1312888156783309487,
+            ]),
+            Fq::from_montgomery_limbs([
+                13464144981025642451,
+                1905536202567247313,
+                9774634897019264881,
+                577082652030395785,
+            ]),
+            Fq::from_montgomery_limbs([
+                350776645357505513,
+                16721037759164698833,
+                1465377325879092623,
+                277087887388817656,
+            ]),
+            Fq::from_montgomery_limbs([
+                12314568959826306247,
+                15512279064998084306,
+                17104397171232345373,
+                18593682333040413,
+            ]),
+            Fq::from_montgomery_limbs([
+                17139911577177657400,
+                2630429525400683070,
+                9933720497384488358,
+                140326038589642947,
+            ]),
+            Fq::from_montgomery_limbs([
+                1497597652157387574,
+                7184296369151371736,
+                9134446581794464743,
+                1005603998584341902,
+            ]),
+            Fq::from_montgomery_limbs([
+                8124688253757883307,
+                13228249878994418383,
+                18416763881271055805,
+                784929942579794931,
+            ]),
+            Fq::from_montgomery_limbs([
+                758063711499934521,
+                15829895820904093410,
+                8481740210331828824,
+                729376495526691589,
+            ]),
+            Fq::from_montgomery_limbs([
+                395297350546060500,
+                584803991772946489,
+                15083561671185709016,
+                1120828686769129548,
+            ]),
+            Fq::from_montgomery_limbs([
+                13700420599688846775,
+                9070306654063838303,
+                9975890332377240135,
+                1288666061328646919,
+            ]),
+            Fq::from_montgomery_limbs([
+                12085775446874274212,
+                16978356103104232321,
+                5730741818588541623,
+                632595204315022073,
+            ]),
+            Fq::from_montgomery_limbs([
+                15575373430560033982,
+                8023932427817872295,
+                5395483284581520487,
+                60978303783009834,
+            ]),
+            Fq::from_montgomery_limbs([
+                6379521938615180883,
+                6872252199701118638,
+                9425753656390287156,
+                982251268454780926,
+            ]),
+            Fq::from_montgomery_limbs([
+                5468701963975467520,
+                10240604086583937131,
+                14150348685309436692,
+                574949707420727177,
+            ]),
+            Fq::from_montgomery_limbs([
+                13334876468328744984,
+                13075248344140475616,
+                6124147946258519841,
+                344205332699979260,
+            ]),
+            Fq::from_montgomery_limbs([
+                16994622888549446411,
+                2245334822292136855,
+                15438348831179529768,
+                1233577841233021200,
+            ]),
+            Fq::from_montgomery_limbs([
+                16632386892601424295,
+                6191526140375733741,
+                5842743290438242423,
+                1310660107296388055,
+            ]),
+            Fq::from_montgomery_limbs([
+                10745861907535982383,
+                619984383176748839,
+                10849357349362009424,
+                369096176875391485,
+            ]),
+            Fq::from_montgomery_limbs([
+                2977208283970250129,
+                15472326899461392805,
+                10647979598338896674,
+                1252285908024945265,
+            ]),
+            Fq::from_montgomery_limbs([
+                1689445739115987676,
+                14910664429720114143,
+                18443580248568797199,
+                111798271151463026,
+            ]),
+            Fq::from_montgomery_limbs([
+                2752765816034059384,
+                207405832558901986,
+                13869416326536552018,
+                671371208629789537,
+            ]),
+            Fq::from_montgomery_limbs([
+                8335215904546688889,
+                6161454619302035461,
+                15598363339095943545,
+                39793683575678844,
+            ]),
+            Fq::from_montgomery_limbs([
+                11165175455888555580,
+                12987431515756878756,
+                2272580339729914621,
+                902573944276015753,
+            ]),
+            Fq::from_montgomery_limbs([
+                11173701850004784393,
+                9019590859427832900,
+                12124381045247901909,
+                996674220457949108,
+            ]),
+            Fq::from_montgomery_limbs([
+                12274061832639678792,
+                6791237002991811548,
+                17023195322991822119,
+                364787466852969040,
+            ]),
+            Fq::from_montgomery_limbs([
+                15418149784804263201,
+                13030794231256641612,
+                18209367853278758548,
+                1106745969275347271,
+            ]),
+            Fq::from_montgomery_limbs([
+                14814226326616225455,
+                1538462997625209229,
+                1408924565810351923,
+                556214202728230909,
+            ]),
+            Fq::from_montgomery_limbs([
+                17059305197431118141,
+                10174092935907158978,
+                5557597453255134435,
+                272320724863419492,
+            ]),
+            Fq::from_montgomery_limbs([
+                14002129045208147477,
+                2787601431486279964,
+                6151772097389159596,
+                362811782964939643,
+            ]),
+            Fq::from_montgomery_limbs([
+                16773404619378467499,
+                16492144282009911456,
+                10876077751085422131,
+                621218928077050040,
+            ]),
+            Fq::from_montgomery_limbs([
+                338684432650451772,
+                6898001654990424122,
+                8318994259761353485,
+                942949343327642020,
+            ]),
+            Fq::from_montgomery_limbs([
+                503652411505714870,
+                3204870680876109900,
+                11568425500853686636,
+                142357466695532502,
+            ]),
+            Fq::from_montgomery_limbs([
+                16101124428854625298,
+                1388180065039099930,
+                14470792219274585499,
+                1083944871797640815,
+            ]),
+            Fq::from_montgomery_limbs([
+                18128995382622314300,
+                15393517625715787284,
+                17409694895457335334,
+                719230358946964441,
+            ]),
+            Fq::from_montgomery_limbs([
+                983637369850965903,
+                6865995368689513613,
+                17624723866133296929,
+                46078898331030104,
+            ]),
+            Fq::from_montgomery_limbs([
+                15984757696752135132,
+                9705741621291058073,
+                17598170676910361026,
+                802176135995772168,
+            ]),
+            Fq::from_montgomery_limbs([
+                9307133998849192193,
+                11360841503037150089,
+                8758808246042787064,
+                773495595040083739,
+            ]),
+            Fq::from_montgomery_limbs([
+                4535531469075915753,
+                14143840087775064208,
+                15769726010973852979,
+                191875766111303245,
+            ]),
+            Fq::from_montgomery_limbs([
+                18028445865096018045,
+                6695252987720811442,
+                6504458778502712912,
+                292832803703465770,
+            ]),
+            Fq::from_montgomery_limbs([
+                17819696683028573865,
+                12688528061746214691,
+                4571143395903631583,
+                1223949052535499291,
+            ]),
+            Fq::from_montgomery_limbs([
+                12970990781477418991,
+                16439808096111856769,
+                2607073709958752817,
+                971754485419937405,
+            ]),
+            Fq::from_montgomery_limbs([
+                18093750928844832144,
+                15891253833413669210,
+                3086386166163141351,
+                1009921505670215389,
+            ]),
+            Fq::from_montgomery_limbs([
+                11116442827249153556,
+                7297503757024808969,
+                5676677213265118590,
+                291854807766674626,
+            ]),
+            Fq::from_montgomery_limbs([
+                17321581592891733335,
+                10754649558015628904,
+                4076309885760545989,
+                273109213161843286,
+            ]),
+            Fq::from_montgomery_limbs([
+                15864859020906744875,
+                14774914651266858273,
+                12081643832110339099,
+                427879490601126729,
+            ]),
+            Fq::from_montgomery_limbs([
+                13791803757305403388,
+                2100316480038359681,
+                17654276921246373092,
+                908504608089810015,
+            ]),
+            Fq::from_montgomery_limbs([
+                592318268236284777,
+                7122818248510842664,
+                9173088335526746297,
+                833724020389922102,
+            ]),
+            Fq::from_montgomery_limbs([
+                1160317084167927209,
+                14817322798735826317,
+                6604276510363086227,
+                52160302681979756,
+            ]),
+            Fq::from_montgomery_limbs([
+                16126897061635208686,
+                3483525289250775771,
+                3940180898979971608,
+                731072320594591501,
+            ]),
+            Fq::from_montgomery_limbs([
+                5562390651805880701,
+                10130501536668456495,
+                9400721449398900871,
+                523166302994003864,
+            ]),
+            Fq::from_montgomery_limbs([
+                3627413351373806596,
+                1490148336357842989,
+                845666226632819073,
+                453758498599008866,
+            ]),
+            Fq::from_montgomery_limbs([
+                14839595302414769239,
+                2942606589823075751,
+                15045808936302133185,
+                421879235523708047,
+            ]),
+            Fq::from_montgomery_limbs([
+                10951607019106505917,
+                3066021257368170379,
+                4301788755529993547,
+                1058711042717122089,
+            ]),
+            Fq::from_montgomery_limbs([
+                15978121923660101164,
+                2701938112743905479,
+                15070480112491572094,
+                1200950926775510543,
+            ]),
+            Fq::from_montgomery_limbs([
+                6033186164200396616,
+                6094110129692119563,
+                7608802175819372268,
+                480717984178443314,
+            ]),
+            Fq::from_montgomery_limbs([
+                2742424084295381816,
+                13884026058378698851,
+                434736396385262629,
+                805694934176358869,
+            ]),
+            Fq::from_montgomery_limbs([
+                11828551099849838316,
+                7154840198864806296,
+                3601480711042294242,
+                1017478285247432065,
+            ]),
+            Fq::from_montgomery_limbs([
+                4396436351532592254,
+                7737559478416628223,
+                10135661485477817896,
+                363704239929539324,
+            ]),
+            Fq::from_montgomery_limbs([
+                6750449191388567874,
+                17621758383386151613,
+                13179425391366264311,
+                252179832220990882,
+            ]),
+            Fq::from_montgomery_limbs([
+                12879063572383676153,
+                15044972975724090610,
+                16739907152390743072,
+                925876178640718405,
+            ]),
+            Fq::from_montgomery_limbs([
+                267634928017653219,
+                1394704074790816715,
+                13775709585769005310,
+                878835031379433847,
+            ]),
+            Fq::from_montgomery_limbs([
+                10754037752048603282,
+                14269904992949347415,
+                9472378921831654910,
+                794518864847750706,
+            ]),
+            Fq::from_montgomery_limbs([
+                8250353090021013807,
+                11097969001858341922,
+                1542685714557786979,
+                459033019366477368,
+            ]),
+            Fq::from_montgomery_limbs([
+                13342479946602377841,
+                14545488435545872034,
+                4165885479239493135,
+                461334156653357871,
+            ]),
+            Fq::from_montgomery_limbs([
+                3152123528960145656,
+                3040082539723000785,
+                15221746156633246204,
+                974013789897718797,
+            ]),
+            Fq::from_montgomery_limbs([
+                13032228148384397727,
+                1216831424046049565,
+                7665157944966960505,
+                1204514015490489391,
+            ]),
+            Fq::from_montgomery_limbs([
+                11710846508605611368,
+                8324355996719982730,
+                14026424382943239675,
+                25605987269955758,
+            ]),
+            Fq::from_montgomery_limbs([
+                16816110470427981258,
+                9200778939512479914,
+                5402136250371300433,
+                847478298219072996,
+            ]),
+            Fq::from_montgomery_limbs([
+                17644447931118518221,
+                18032036889486913457,
+                8030729198451170384,
+                541162887222227328,
+            ]),
+            Fq::from_montgomery_limbs([
+                14912349848000272973,
+                13331446506614256848,
+                15410218854191915156,
+                649572217224291956,
+            ]),
+            Fq::from_montgomery_limbs([
+                595490176867215979,
+                7890158906021571539,
+                14713676649317401064,
+                1093201815772188367,
+            ]),
+            Fq::from_montgomery_limbs([
+                11462007263002722952,
+                12466436140806491217,
+                2275029542190293904,
+                594776715242536166,
+            ]),
+            Fq::from_montgomery_limbs([
+                13038383493959260240,
+                269377453660409765,
+                9419683186566659022,
+                529439675987630383,
+            ]),
+            Fq::from_montgomery_limbs([
+                2747928198940007157,
+                16415291939510991253,
+                16493504047859758662,
+                584146987148203454,
+            ]),
+            Fq::from_montgomery_limbs([
+                120390972587424121,
+                14868535908017661770,
+                15840941790295410366,
+                1072365324312886114,
+            ]),
+            Fq::from_montgomery_limbs([
+                16911064854690231334,
+                1987520561992853511,
+                650659527882509262,
+                961204912957108640,
+            ]),
+            Fq::from_montgomery_limbs([
+                7816251461923886757,
+                9797702866900348791,
+                13279784393035975042,
+                303483840445343538,
+            ]),
+            Fq::from_montgomery_limbs([
+                10425889916440715720,
+                16928982013112812327,
+                12474493945513960729,
+                62013490801657084,
+            ]),
+            Fq::from_montgomery_limbs([
+                3613428680530286298,
+                16095558588564362881,
+                12525604870792462420,
+                1340368594283962821,
+            ]),
+            Fq::from_montgomery_limbs([
+                1003160567168345245,
+                5554928884364249000,
+                17096064046784485771,
+                1081011132254818866,
+            ]),
+            Fq::from_montgomery_limbs([
+                17475043557551736042,
+                2984728778779590540,
+                9402014309214164258,
+                465136144432187826,
+            ]),
+            Fq::from_montgomery_limbs([
+                10093048344761454084,
+                18162398901229499125,
+                3932298029713597821,
+                2936033519135550,
+            ]),
+            Fq::from_montgomery_limbs([
+                17938627734629643544,
+                4789922997765215858,
+                8676462870992717483,
+                199364262301821704,
+            ]),
+            Fq::from_montgomery_limbs([
+                3527786545399571049,
+                2247908734325642968,
+                7762442598613691444,
+                14408978889280371,
+            ]),
+            Fq::from_montgomery_limbs([
+                6751043434283755115,
+                16359201855277266788,
+                12098748963170657748,
+                34841260202676260,
+            ]),
+            Fq::from_montgomery_limbs([
+                10597516645555454910,
+                17649537769126277751,
+                3897705064373254598,
+                328275625137089711,
+            ]),
+            Fq::from_montgomery_limbs([
+                15724243099841339391,
+                11809956835294337009,
+                1557661609081813555,
+                1185748239423426443,
+            ]),
+            Fq::from_montgomery_limbs([
+                1670377858723402793,
+                13359895913089038760,
+                5489182069575572624,
+                1085423657440671553,
+            ]),
+            Fq::from_montgomery_limbs([
+                13787608675057196050,
+                13642592020387442997,
+                12452204809832002993,
+                425008180149482242,
+            ]),
+            Fq::from_montgomery_limbs([
+                7298278978355947407,
+                15538044027672142968,
+                18084129674405646611,
+                381335158607621079,
+            ]),
+            Fq::from_montgomery_limbs([
+                18028389149531646969,
+                12533063428755486338,
+                5061620096549601863,
+                585524738335045273,
+            ]),
+            Fq::from_montgomery_limbs([
+                12598015456042832694,
+                1910710392998419127,
+                656357532081330357,
+                1180655132212784979,
+            ]),
+            Fq::from_montgomery_limbs([
+                6747749322682891691,
+                670514981080284764,
+                3966127246337252709,
+                461312184461707782,
+            ]),
+            Fq::from_montgomery_limbs([
+                10094306259684051584,
+                8932063573205138162,
+                9507950885527912105,
+                1021455260718769490,
+            ]),
+            Fq::from_montgomery_limbs([
+                15313934385483632156,
+                4611729284631369588,
+                12249022083561022899,
+                210039313482409946,
+            ]),
+            Fq::from_montgomery_limbs([
+                18240665291828971851,
+                18445298705154013220,
+                264106379075392994,
+                356042961144288573,
+            ]),
+            Fq::from_montgomery_limbs([
+                1394558605228470436,
+                994353263364879287,
+                13928997029131461412,
+                226763851568555087,
+            ]),
+            Fq::from_montgomery_limbs([
+                9539883989430709748,
+                6045679286369908539,
+                15157438832885548536,
+                117227514320634277,
+            ]),
+            Fq::from_montgomery_limbs([
+                7491304683558445699,
+                4881143847251629918,
+                1998078712842890248,
+                196516655200147498,
+            ]),
+            Fq::from_montgomery_limbs([
+                15059764057037598487,
+                4495091904765121355,
+                9687481906788255074,
+                322115150606610916,
+            ]),
+            Fq::from_montgomery_limbs([
+                13881861116603667818,
+                6900872427947570052,
+                17317950308376476961,
+                1101016561883148314,
+            ]),
+            Fq::from_montgomery_limbs([
+                11234072060068054576,
+                16377692853478905292,
+                17111730508663648713,
+                1197680513225957372,
+            ]),
+            Fq::from_montgomery_limbs([
+                17916976235178987036,
+                1621774893635324262,
+                13189039618783485770,
+                149260740149699703,
+            ]),
+            Fq::from_montgomery_limbs([
+                12517160923066882516,
+                17919533296124718582,
+                13548685086242371240,
+                900964070895991659,
+            ]),
+            Fq::from_montgomery_limbs([
+                6229248673506287061,
+                2503617367436202637,
+                6021362520363109378,
+                230905895453658218,
+            ]),
+            Fq::from_montgomery_limbs([
+                10898967791377580196,
+                1843850886319852403,
+                14538383259785455801,
+                1341129077222307740,
+            ]),
+            Fq::from_montgomery_limbs([
+                17535797046644739112,
+                1411360021222296663,
+                17542627307576980665,
+                393549884905737153,
+            ]),
+            Fq::from_montgomery_limbs([
+                14665959544261076722,
+                15480219291402253817,
+                5717858319913635342,
+                1025094022123558527,
+            ]),
+            Fq::from_montgomery_limbs([
+                632075114996294031,
+                16501981467240551989,
+                1765424840856891132,
+                690537526417348588,
+            ]),
+            Fq::from_montgomery_limbs([
+                16241977528865291938,
+                5647138377221334609,
+                11094353082982862941,
+                908740411341186568,
+            ]),
+            Fq::from_montgomery_limbs([
+                3592936568354286013,
+                11243782274843267120,
+                6130509710089546051,
+                673202978961920358,
+            ]),
+            Fq::from_montgomery_limbs([
+                14865078746861721859,
+                6304702006958252247,
+                6972740084313329851,
+                975587532246122700,
+            ]),
+            Fq::from_montgomery_limbs([
+                11131867423348420686,
+                8667455972747573018,
+                13263115559905207169,
+                22778312586826957,
+            ]),
+            Fq::from_montgomery_limbs([
+                1102436087977865164,
+                8947107635320846028,
+                249001668357998148,
+                967660270571314573,
+            ]),
+            Fq::from_montgomery_limbs([
+                12334962689452250356,
+                4272871054369212374,
+                15648553584713251532,
+                1290998567099882564,
+            ]),
+            Fq::from_montgomery_limbs([
+                12644119276643788866,
+                2425894941951119678,
+                539306532636700237,
+                984880920333393612,
+            ]),
+            Fq::from_montgomery_limbs([
+                10621354436640940774,
+                17475656276114966326,
+                6183847681359292154,
+                1164106560227002040,
+            ]),
+            Fq::from_montgomery_limbs([
+                16425093438834545859,
+                7980779969036523163,
+                5406485900726435428,
+                53877947430728458,
+            ]),
+            Fq::from_montgomery_limbs([
+                5497534343185614415,
+                5470623129703330703,
+                311427563927426810,
+                960119814927638078,
+            ]),
+            Fq::from_montgomery_limbs([
+                1760393833900533238,
+                1180874064605009903,
+                17584431687696297471,
+                1072042304147993287,
+            ]),
+            Fq::from_montgomery_limbs([
+                13521749936955809291,
+                12732676757332577486,
+                10352194262115730873,
+                748842245993650629,
+            ]),
+            Fq::from_montgomery_limbs([
+                7783486842344714530,
+                17456415948871783336,
+                7655729239101168307,
+                997869106719943867,
+            ]),
+            Fq::from_montgomery_limbs([
+                5394489378650856484,
+                2785561761940662356,
+                7289987728811262985,
+                1192298179968642508,
+            ]),
+            Fq::from_montgomery_limbs([
+                2185564514961315051,
+                9491459801854087989,
+                689190198901233394,
+                1054570174808464759,
+            ]),
+            Fq::from_montgomery_limbs([
+                9803320656157880582,
+                16824247114637191320,
+                15642460354594555810,
+                741853811802697163,
+            ]),
+            Fq::from_montgomery_limbs([
+                3824965405018570967,
+                4760912611827489656,
+                15705207611379967110,
+                261781416976350712,
+            ]),
+            Fq::from_montgomery_limbs([
+                9948666565497094067,
+                1032303232074238532,
+                298300789183560386,
+                61197088897148443,
+            ]),
+            Fq::from_montgomery_limbs([
+                12584558968154535453,
+                4296875434581661533,
+                2099231768046174181,
+                1094935724993865267,
+            ]),
+            Fq::from_montgomery_limbs([
+                2706235373475508564,
+                13662469686906678506,
+                11425794440102395373,
+                1001492819155058979,
+            ]),
+            Fq::from_montgomery_limbs([
+                16194586259949093982,
+                9166220004132787830,
+                5189159251738765109,
+                944159436115566407,
+            ]),
+            Fq::from_montgomery_limbs([
+                9497451808541567591,
+                12818011166775905079,
+                710900486700255010,
+                102314471233788798,
+            ]),
+            Fq::from_montgomery_limbs([
+                16634534028905632221,
+                11271594765464549834,
+                16771926302371527338,
+                349271683367957667,
+            ]),
+            Fq::from_montgomery_limbs([
+                2631465013236619937,
+                5135765551610576530,
+                16177229953788197764,
+                556760782437683569,
+            ]),
+            Fq::from_montgomery_limbs([
+                7769152571464530109,
+                3669475259515903550,
+                17431893895935505452,
+                345181415482104133,
+            ]),
+            Fq::from_montgomery_limbs([
+                9013064684817197370,
+                7457829454159189795,
+                6355973875057495172,
+                222247050620296832,
+            ]),
+            Fq::from_montgomery_limbs([
+                1101942193311592151,
+                8410475600025481564,
+                10583545900194067765,
+                31853211752527483,
+            ]),
+            Fq::from_montgomery_limbs([
+                7169939865979325895,
+                15708834278490582251,
+                13510219568073801760,
+                860676883918173908,
+            ]),
+            Fq::from_montgomery_limbs([
+                11485943637135206662,
+                5634390427736742034,
+                4681618609687180007,
+                1170797859038832594,
+            ]),
+            Fq::from_montgomery_limbs([
+                1420478528863765169,
+                4838095807694475733,
+                10232422396234549089,
+                102514901489451683,
+            ]),
+            Fq::from_montgomery_limbs([
+                4019611711435609657,
+                13741042565157052111,
+                2372271957910884074,
+                562136768498572582,
+            ]),
+            Fq::from_montgomery_limbs([
+                1547088012713907669,
+                2410893170457523232,
+                18337019277456881794,
+                336563950044015201,
+            ]),
+            Fq::from_montgomery_limbs([
+                18283321300066265663,
+                5373809852508555155,
+                1007591060355863660,
+                835389870786637310,
+            ]),
+            Fq::from_montgomery_limbs([
+                16786959288324447406,
+                14916620936647498452,
+                7332216137069030782,
+                1107884217421411771,
+            ]),
+            Fq::from_montgomery_limbs([
+                16892015453562854555,
+                14123644195667492530,
+                17384607211358548170,
+                1263040180505445399,
+            ]),
+            Fq::from_montgomery_limbs([
+                6351024390452377266,
+                6315901530971784062,
+                8990185196153435396,
+                207602098942265722,
+            ]),
+            Fq::from_montgomery_limbs([
+                8507464704422938984,
+                7436139867261684041,
+                16382471416918714125,
+                616557904729058653,
+            ]),
+            Fq::from_montgomery_limbs([
+                14652722836263960724,
+                1132013649842704363,
+                10600496495699042016,
+                789134505606392549,
+            ]),
+            Fq::from_montgomery_limbs([
+                9955289190827064193,
+                18408017328022376118,
+                2398241518626672097,
+                605434309319696457,
+            ]),
+            Fq::from_montgomery_limbs([
+                2223393086542743962,
+                17201448367045239009,
+                6231210262523021467,
+                497050528538555822,
+            ]),
+            Fq::from_montgomery_limbs([
+                7458682393213803626,
+                18276082776898565373,
+                5893168447635887122,
+                281594223319984251,
+            ]),
+            Fq::from_montgomery_limbs([
+                16666203072211055802,
+                8815592868211109659,
+                379418658262000868,
+                1261079285205091818,
+            ]),
+            Fq::from_montgomery_limbs([
+                16750404483357073145,
+                7202271804062043544,
+                8534115717155771908,
+                217086520627375616,
+            ]),
+            Fq::from_montgomery_limbs([
+                8716463027086197234,
+                13738355573723000987,
+                3655676590023383459,
+                63063650122895239,
+            ]),
+            Fq::from_montgomery_limbs([
+                9170850872661052856,
+                2802738167148037961,
+                13740036271321555692,
+                624691989645834505,
+            ]),
+        ]),
+        mds: MdsMatrix::<5, 4, 25, 16>::new_from_known([
+            Fq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+            Fq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+        ]),
+        alpha: Alpha::Inverse,
+        rounds: RoundNumbers { r_P: 60, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<4, 16>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+            ]),
+            v: Matrix::<1, 4, 4>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+            ]),
+            w: Matrix::<4, 1, 4>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<5, 25>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<5, 25>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8008150737402636880,
+                    4488734692587776588,
+                    1547555191554943536,
+                    1006206882335955987,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4174856959927929341,
+                    6956702737728349555,
+                    11313218605335544685,
+                    1279701926361717254,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14997388866253338116,
+                    17951148788181031565,
+                    14101804784614517907,
+                    65578444326456143,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    6853363902592985329,
+                    4486369932351851910,
+                    65610707319252285,
+                    873172475563117547,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<5, 25>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    16044777359948080167,
+                    6379307166694554861,
+                    762089046052004585,
+                    1183646996043825654,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8310126474924308909,
+                    16838376826344772735,
+                    14456183264230470132,
+                    629129040732927162,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10591481161139814707,
+                    3864831283854097767,
+                    6360339012538312403,
+                    727156441545813699,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8580764665041452750,
+                    2596276168345731737,
+                    607940303702198574,
+                    618123929142359699,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18272933275584544879,
+                    10491562475845979009,
+                    9925929908400688531,
+                    449428766487123474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8310126474924308909,
+                    16838376826344772735,
+                    14456183264230470132,
+                    629129040732927162,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11903154552578817451,
+                    8808508453785624876,
+                    14068444835231129096,
+                    1259276684968074962,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2548615176746366879,
+                    17237354895530185263,
+                    1797488250903408702,
+                    291026391931997698,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13620574122907011204,
+                    15580574422211693049,
+                    7152880373115697056,
+                    985573542184802400,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3137742302926509693,
+                    15046605844466714305,
+                    1991063102869025961,
+                    261841845081961819,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10591481161139814707,
+                    3864831283854097767,
+                    6360339012538312403,
+                    727156441545813699,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2548615176746366879,
+                    17237354895530185263,
+                    1797488250903408702,
+                    291026391931997698,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4501910777217553532,
+                    15462242547553693695,
+                    10499850527346203608,
+                    185455733513899003,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16090235568988980360,
+                    8762996204253883831,
+                    17672444665847082580,
+                    116738187400143515,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10965139192629135304,
+                    2507527262352232221,
+                    7729853937802466392,
+                    18035572639182151,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8580764665041452750,
+                    2596276168345731737,
+                    607940303702198574,
+                    618123929142359699,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13620574122907011204,
+                    15580574422211693049,
+                    7152880373115697056,
+                    985573542184802400,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16090235568988980360,
+                    8762996204253883831,
+                    17672444665847082580,
+                    116738187400143515,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10167016895964480411,
+                    16734919608152084608,
+                    17983964361753590390,
+                    420319954536647974,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15950905455332638974,
+                    10638510376174954245,
+                    6190824109415358469,
+                    365471410765096812,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18272933275584544879,
+                    10491562475845979009,
+                    9925929908400688531,
+                    449428766487123474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3137742302926509693,
+                    15046605844466714305,
+                    1991063102869025961,
+                    261841845081961819,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10965139192629135304,
+                    2507527262352232221,
+                    7729853937802466392,
+                    18035572639182151,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15950905455332638974,
+                    10638510376174954245,
+                    6190824109415358469,
+                    365471410765096812,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1117314920009271201,
+                    4543364499625431390,
+                    17867254861738919934,
+                    199268313635132044,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<4, 16>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    2912843796488841427,
+                    2986203785403700346,
+                    8782906370300679609,
+                    72348807101562454,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9009169579583668122,
+                    13163254771921611656,
+                    12500476150373767268,
+                    206041309714119899,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7160019720069709961,
+                    1207930472110715040,
+                    14896636547354890107,
+                    1070558624402680198,
+                ]),
+                Fq::from_montgomery_limbs([
+                    769834061306938378,
+                    434866968268652066,
+                    13507727459817144106,
+                    1015899670515829318,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9009169579583668122,
+                    13163254771921611656,
+                    12500476150373767268,
+                    206041309714119899,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18105737139400699747,
+                    17092832644263385296,
+                    11448292449964576346,
+                    97503347880002371,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2486409206805834145,
+                    7132406107544192230,
+                    16724002743228709842,
+                    204690573034040147,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15517856203532702535,
+                    6456985507109804621,
+                    5236564190669238024,
+                    1219448662068878427,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7160019720069709961,
+                    1207930472110715040,
+                    14896636547354890107,
+                    1070558624402680198,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2486409206805834145,
+                    7132406107544192230,
+                    16724002743228709842,
+                    204690573034040147,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5324099184438075010,
+                    18365509704861776210,
+                    485662210662411512,
+                    332367568902751343,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12123690196900787584,
+                    13150159583617211350,
+                    15652393172789097814,
+                    509338692023573934,
+                ]),
+                Fq::from_montgomery_limbs([
+                    769834061306938378,
+                    434866968268652066,
+                    13507727459817144106,
+                    1015899670515829318,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15517856203532702535,
+                    6456985507109804621,
+                    5236564190669238024,
+                    1219448662068878427,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12123690196900787584,
+                    13150159583617211350,
+                    15652393172789097814,
+                    509338692023573934,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3535747919942277633,
+                    17013697925000670341,
+                    11660128334245281,
+                    1118907618677696094,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+            M_i: Matrix::<5, 5, 25>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1443572968582333919,
+                    6931715233851638320,
+                    16918195909771398712,
+                    78436872996400784,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10364431753259195487,
+                    15347963859529045077,
+                    11932708568846809171,
+                    301395581159797237,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3876702973551082094,
+                    14390267586472422634,
+                    15550419803436388073,
+                    574495754123375582,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2896871696238897041,
+                    7506930730110268084,
+                    6883898080116693810,
+                    561343229269324625,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10364431753259195487,
+                    15347963859529045077,
+                    11932708568846809171,
+                    301395581159797237,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6515063394263799441,
+                    8740878270577945705,
+                    1685440429987950805,
+                    593077835373421375,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8271105552651792808,
+                    17764103283716436242,
+                    6449792210412855334,
+                    482715928303092265,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17120447117100451223,
+                    578044936770465116,
+                    5439394035924184826,
+                    1012073528688119322,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3876702973551082094,
+                    14390267586472422634,
+                    15550419803436388073,
+                    574495754123375582,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8271105552651792808,
+                    17764103283716436242,
+                    6449792210412855334,
+                    482715928303092265,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7592458476103791255,
+                    4933044694633314039,
+                    17598753366231724511,
+                    1084174368372904552,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16120383085805648714,
+                    17761294605096815532,
+                    1024551678256160336,
+                    441653217726754383,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2896871696238897041,
+                    7506930730110268084,
+                    6883898080116693810,
+                    561343229269324625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17120447117100451223,
+                    578044936770465116,
+                    5439394035924184826,
+                    1012073528688119322,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16120383085805648714,
+                    17761294605096815532,
+                    1024551678256160336,
+                    441653217726754383,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14596470538968915404,
+                    18126775476597422538,
+                    12761813592022147815,
+                    533409392230381589,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17237574486256691881,
+                        7678231653376502440,
+                        12981859904545217192,
+                        832323394471302938,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14878706952858838161,
+                        15410104227655356999,
+                        17393275270132988194,
+                        905602962502284432,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17721242321237835775,
+                        11985636621509722110,
+                        11478464757469040638,
+                        960562638525520553,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17640631015407645126,
+                        17416650484724036037,
+                        14803487960933328666,
+                        554882262980868625,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7330330845985706257,
+                        6947957132670498679,
+                        9373711551562393463,
+                        1140097580201425214,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16333313215653526970,
+                        2040802123570873567,
+                        13606956490394234585,
+                        551256550916377040,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5062406009186596704,
+                        16529967443609096569,
+                        8069040895545372020,
+                        1221611533051627754,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8776932849639973133,
+                        4157275033105063229,
+                        5219893242165609753,
+                        441476373599490172,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11152363693389150632,
+                        7168263193567282683,
+                        1452700946751769356,
+                        436217109073156792,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13970337958464724119,
+                        17109877554760172091,
+                        12788764852517710407,
+                        839284445089847973,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15163746983822199844,
+                        11654724925143124644,
+                        9602216610999473293,
+                        519210689768164370,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10182434037205209725,
+                        9621286367095233356,
+                        8404025194304421834,
+                        344727351078320731,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16755358458570043164,
+                        10280398262063275743,
+                        17321778533676321974,
+                        143546509776638006,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9894685689427310276,
+                        13317494114689924797,
+                        8039678456153006986,
+                        320726242987073750,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10892251073208715123,
+                        9975233878611449982,
+                        11944979761650688031,
+                        279665888955724801,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2806869061497062911,
+                        16847498917844641241,
+                        12155099785802870367,
+                        612418149303740633,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1943262751829125522,
+                        12763321846226199313,
+                        6747770469779104151,
+                        951539986961178929,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16058796408745659861,
+                        17170055100384124922,
+                        1586157489739998203,
+                        1036791847629253806,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2170272984479571023,
+                        39206051687503551,
+                        73036971397233251,
+                        323555571475294666,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13939509715106692697,
+                        9339647999495962035,
+                        11925062741591314295,
+                        1167415533183673898,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6979434117673847033,
+                        3048055255536618747,
+                        13743910631909977125,
+                        783906807780161297,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17010292649383249624,
+                        7578772047158193525,
+                        1259524131029379452,
+                        241494970118020102,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8104232396866985927,
+                        12510732557073130064,
+                        9629433082008929246,
+                        1035242752646990544,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16796634656430258298,
+                        14112008759289957096,
+                        6452208208519466013,
+                        1048584847398376572,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15748961458778365618,
+                        8601741670573679852,
+                        9437158324168441506,
+                        977044327661253334,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5158908350016140691,
+                        8596065138269817166,
+                        4752647801985797199,
+                        1176021914613979688,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17831888852747325597,
+                        9652101022265686283,
+                        1555288054471442793,
+                        65814168324416153,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10495561656210166211,
+                        10989451257550783972,
+                        8015055214453272328,
+                        409799830087238982,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2331967402451752193,
+                        2109682558241205572,
+                        1825337663297053092,
+                        878857572660922318,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12336449333325242432,
+                        5499382277476614407,
+                        11404022977159740856,
+                        478044609105512850,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10790217870923516271,
+                        10590091398034592831,
+                        2798879849340254683,
+                        1097244195350236928,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13063047010146157876,
+                        14542415641892323856,
+                        17083019212457052807,
+                        1089087010292700700,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1931130401025711378,
+                        11841912731002402383,
+                        12257729010461348591,
+                        475921619378996396,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2043623821806520253,
+                        6264756844797844292,
+                        9140556588049529897,
+                        357097751413650712,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13748314028353391771,
+                        7554401384369793136,
+                        15484455053202687098,
+                        1015396556130205108,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12112322445843745250,
+                        9643021818792026083,
+                        7010640491952178418,
+                        912560635387385014,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15884856584565086050,
+                        4328763398811725844,
+                        2194324022836935036,
+                        564940183680880064,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10368325909078307315,
+                        16258151264498137456,
+                        12821855576160737507,
+                        168843008052784532,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6775860316590005410,
+                        1587081089529470620,
+                        12761600899048989463,
+                        547743431636452378,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5499459000866536496,
+                        17225191771611977200,
+                        15501710310620154491,
+                        515684463452344496,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12129682192729323843,
+                        8486777067374758801,
+                        1157895296096676184,
+                        662132892983063060,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        174757932292384330,
+                        3152593721116333206,
+                        12164153154782094245,
+                        1099436573600079710,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        615845028898507200,
+                        4670618999889700824,
+                        12443454676890805497,
+                        518935605666369522,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16912386391968429424,
+                        5290244798379778177,
+                        11209464492300379874,
+                        586454799338251353,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12080638299294159093,
+                        1960331238298728784,
+                        2898980097890331004,
+                        921670658451130495,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16215351708207141464,
+                        7345269023494330137,
+                        12994383926037492649,
+                        224185396018231372,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3554838652407488913,
+                        6074835006636416927,
+                        15656375499922407950,
+                        15940578339816345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7221246958182853009,
+                        6510601905224702473,
+                        12824776152597435860,
+                        725837117656279675,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13527778764121271275,
+                        1030490900095715048,
+                        12857340760482021894,
+                        936580603253300439,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12930617827781800730,
+                        3249690311936291363,
+                        1303559984190780453,
+                        990745867675858343,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10347830123499261941,
+                        14744724164671532382,
+                        15017413323955480611,
+                        865517056617574158,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13222342262320869452,
+                        930250546901263311,
+                        6148846827329484586,
+                        816792556508332788,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        270522433778958450,
+                        9810558015885994375,
+                        12801721076082256784,
+                        428014500155741958,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17216419355251603785,
+                        16776182263454362660,
+                        17464958086342114244,
+                        1020854289329138282,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14583513917939718723,
+                        16464432348709798589,
+                        5524662795591881534,
+                        504799586387620122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9494047844709044490,
+                        9514125194357777841,
+                        9204079242382538181,
+                        1326388421861125510,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7051493540766021999,
+                        14714874558861007051,
+                        12179359128314955274,
+                        431397382988804936,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14285688927589864668,
+                        8964657502437295762,
+                        10607145890133654906,
+                        644415764475289967,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9070138432342296337,
+                        17136148269824501435,
+                        16709615536037568565,
+                        1088774414904578634,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9017804360060337869,
+                        5120895363411029290,
+                        14759539588564342239,
+                        251323438509473927,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        614700763379600159,
+                        12518761935375538118,
+                        4440750989807847128,
+                        10689910160914840,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16233894446868518964,
+                        232968488158620891,
+                        3648936371760108401,
+                        87053101363493664,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4682437298124427841,
+                        10048101470247329816,
+                        5838396498674936533,
+                        76707223622697042,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4844729186655784101,
+                        5073462805990634750,
+                        369364776025047222,
+                        1205067782666401609,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11811297399794142233,
+                        1367273165695851273,
+                        9482096466685101348,
+                        278101973543607386,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        700938287003557444,
+                        10784390521545837670,
+                        652198576027059045,
+                        1257111617232546179,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7023353639663907189,
+                        844196405846330427,
+                        3670187877020232969,
+                        467197560404961638,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3255328098459903221,
+                        1373891119440715166,
+                        9229957119786296543,
+                        511813603491106053,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7074698954249379124,
+                        597938652890685454,
+                        4893589759540026630,
+                        13821006431840376,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9050360858429500710,
+                        712154751922466901,
+                        2783564310815451475,
+                        361791763218230554,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2543657380738906130,
+                        7730539901174656512,
+                        9349888058233016421,
+                        1105296308578399874,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12462195693380123490,
+                        9135340915133672169,
+                        2529249849635714105,
+                        1028662249374476992,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3997059354097159592,
+                        14396586541557506890,
+                        15126937915868653526,
+                        1190014880085637787,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        71730461663727489,
+                        6481482986298961851,
+                        2863781531983795764,
+                        676732583839455648,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11976979288710790629,
+                        18385617837556037683,
+                        2427331777175576996,
+                        271478597561256238,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14665157190813807512,
+                        4593895870413934871,
+                        17259515554442068841,
+                        822957347764507743,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7149525206288877270,
+                        17921481572667347774,
+                        2621958866898809461,
+                        1253764315637746764,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3409735133900390936,
+                        136473863329815600,
+                        15309385832522383920,
+                        1169764220492589436,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7033172430223502268,
+                        240255878942338808,
+                        6398794669496471317,
+                        628797443576353581,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7953065623402270259,
+                        8501997013689665019,
+                        5960083925037855492,
+                        295456887863221215,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15195370696120832657,
+                        17781142206152991864,
+                        2238407181422098285,
+                        841138092128746233,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12824831901012180308,
+                        12167693598895623388,
+                        11028443174628128031,
+                        11577460208414501,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3327689546198888048,
+                        12391493112771759962,
+                        307868198412140618,
+                        1159504727071219081,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3049104073000031564,
+                        17357418971393386361,
+                        996987970433781915,
+                        116813261500484748,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10884166330331702882,
+                        17425353526410834453,
+                        13326907998045201293,
+                        413735914240279347,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12473777284989231213,
+                        17638157720713586222,
+                        7280940291577634976,
+                        165394770300217809,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6075495009078142294,
+                        15423443319709019929,
+                        10104593150500616837,
+                        356519351756886421,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4453851468262956706,
+                        17578058950978353938,
+                        5693306616848551388,
+                        65029682653071572,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8478514957045721547,
+                        1837595243779150041,
+                        2731509554274646589,
+                        1330775784497928083,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5199974098799727027,
+                        4404072971729349177,
+                        9646912483549069990,
+                        218329131799988219,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        660001227897806842,
+                        8207988798347696530,
+                        13044592656758679072,
+                        480823461712968712,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2124249976672750300,
+                        16810194224873279051,
+                        8413199247867349887,
+                        252618070410064009,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4556458494955145970,
+                        14725473799943575915,
+                        6762752380673415129,
+                        1316259551723935370,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16979681563871369563,
+                        5158090131973535020,
+                        2347846896725164220,
+                        295285400051150767,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13585313619033311504,
+                        16684285379674594726,
+                        6119191656194738492,
+                        455955275887010759,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3454774308783696473,
+                        10535559679543981910,
+                        15358478203203343679,
+                        240649127531759812,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16198893952998692648,
+                        7621510146767446726,
+                        5423085782991616007,
+                        484917335576147571,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12418560142694938034,
+                        9630762732922483573,
+                        11756502878189980458,
+                        1064509096436490989,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6432783853572915686,
+                        8373421070941771076,
+                        9999207991110274176,
+                        227092656935087544,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2481263986424351332,
+                        5524660820856664127,
+                        1844129905336978496,
+                        940613905523784942,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16138212250303786584,
+                        7239588475022848974,
+                        10699859199281641521,
+                        591755207097992795,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12068127359176715698,
+                        9201897464239423846,
+                        16468376872261041490,
+                        4468311430440924,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4076476707186060475,
+                        17992082137333534347,
+                        6815132661837439512,
+                        278384059575851924,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17018342029879137108,
+                        15055574099156300093,
+                        11677650203572471840,
+                        144664137444733523,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8317396731115292545,
+                        18067636204720558118,
+                        14890417508627474416,
+                        793524012844400414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13379864138421857399,
+                        362217527193745219,
+                        15798919921502256623,
+                        1097917902435666499,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12810510919435274272,
+                        14423646849331158587,
+                        9841501271852473232,
+                        725922426352114610,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17018030548797472114,
+                        14895024219584398144,
+                        12492061782442092972,
+                        762315198620019019,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1701906145521012707,
+                        10296626786628438399,
+                        10754570760997467096,
+                        336488969849416237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        254576632745845475,
+                        16526828097705762792,
+                        14770819661184367983,
+                        243834243852718897,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4102931554245931369,
+                        16027764780080619999,
+                        8334572247339515265,
+                        368246411244951631,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4561744176382664009,
+                        2911477542790161454,
+                        3011707291091124269,
+                        556376879137998058,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3296358868716422366,
+                        9039393853864308074,
+                        17847711501823630457,
+                        1005226492488439642,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4031069363410182489,
+                        7351394901266043534,
+                        16764075333050932509,
+                        1335716800346461455,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7923744999493545562,
+                        5624877492918299446,
+                        1035450251487215992,
+                        66195541204638202,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10021219165650904780,
+                        11526735379442916846,
+                        15137554942549712865,
+                        220711145305168309,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17539749312900092691,
+                        1394325220480450609,
+                        8427780606119340085,
+                        214541826368037298,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14979072356738539172,
+                        8588389061504370168,
+                        17185566872280556497,
+                        1119865054078041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13832855658344903569,
+                        17699617991822202836,
+                        12719246950037163765,
+                        809144901785178492,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5258746201047632203,
+                        16795607952264798969,
+                        12589457419107454073,
+                        852487165526431816,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18017611145361501469,
+                        17582515812396321648,
+                        2919031829448009162,
+                        551871759286894908,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15370904975677001433,
+                        7214748906490449079,
+                        16152167407980539558,
+                        1171554702393986442,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10634655764649983482,
+                        15431077074812693397,
+                        2552280196567255,
+                        712776250826311075,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13708388833019942700,
+                        491334836724789600,
+                        11731367761263890755,
+                        1197388142335296709,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17599603302554434708,
+                        288236569098964218,
+                        6607169511018585362,
+                        742922661904003685,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2999928755320713162,
+                        17709013434910122954,
+                        4421363832423320814,
+                        1079445091024398583,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16365077752081483235,
+                        5107813982252052567,
+                        12615259624205767100,
+                        1004823973637104563,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7473985182851942000,
+                        2598481490162125527,
+                        13173616023304088764,
+                        100586206056458085,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4037893511428073999,
+                        8193570220772267865,
+                        17797092594299866436,
+                        286962692803580544,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12313489747494973882,
+                        2762226945257771097,
+                        5257905303002387848,
+                        947510042297998629,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6756228068000487339,
+                        13091230786864620374,
+                        11293838269917797242,
+                        178794026314568534,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12089529351155084819,
+                        17974846233018640463,
+                        1885543740297669145,
+                        566662849400853262,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14246731722522693036,
+                        1341005967849423401,
+                        7204869264168212780,
+                        568015074888212948,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13221024176534617234,
+                        4632455377071708554,
+                        1927178290186881904,
+                        255046399069171413,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6329806143623058366,
+                        2877484404927753488,
+                        15678279181086816970,
+                        242428113243709052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1376919174449691409,
+                        10078178642356297111,
+                        12126198147380942177,
+                        542255448142967680,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9138545781041832567,
+                        578884360554425858,
+                        17979411078890629932,
+                        923415187963038455,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5948221177533646226,
+                        17071325363317553287,
+                        6183039122023041745,
+                        704196937270803327,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1488507912713204872,
+                        16612904630591262848,
+                        12972339319550101620,
+                        84503673685000948,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13936260729406005413,
+                        11876163101922153366,
+                        16638970622768260408,
+                        754960847513101286,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9207879084224879487,
+                        14927074997316346300,
+                        12192567671174795973,
+                        440422174312259811,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13259605727454639460,
+                        13707769158736348115,
+                        11136871770871140100,
+                        395430410698836862,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10658009287840596263,
+                        18091673856402559687,
+                        16416452300408299717,
+                        675057864354262378,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5712583090855361771,
+                        2728382389642376343,
+                        13688790862929751531,
+                        1155018351452718010,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18045814722218641976,
+                        17607210141991763745,
+                        11820158338284932285,
+                        388269146038862417,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17846421901870277641,
+                        4444213302897781767,
+                        7083047271363270966,
+                        1139474551744345011,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12751078441606785608,
+                        518090047664847458,
+                        4172242781184993511,
+                        1203422517949580240,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8048196198204705727,
+                        16922395517944793559,
+                        1183771461203467425,
+                        1002313662981163018,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10403078271298174405,
+                        2664037932640238394,
+                        7606897906525718205,
+                        1337775203781121013,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13227532567992530681,
+                        239988696107110741,
+                        206780662552802186,
+                        212668362637792016,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11426294773991152885,
+                        7719358359987816045,
+                        3974519486772188691,
+                        890167108936284968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12183318301154152828,
+                        15123784839689446063,
+                        4360918807017836474,
+                        1175262109328452749,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15834405976293932943,
+                        5622536161211339702,
+                        15172632624230255612,
+                        1009075162252460588,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9701318806842926389,
+                        9299451336667579150,
+                        13252173612052997888,
+                        264751941124303276,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9934633899975783608,
+                        9544944535557183010,
+                        4843075331558681563,
+                        1054024360129383549,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2910128804231986933,
+                        2656303044860919,
+                        9656046176419085933,
+                        1057600581821141309,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2954238257700030433,
+                        14798380778084501640,
+                        16125461634679495116,
+                        825593072523281962,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        215255122587716593,
+                        11089324816664916369,
+                        7627774261135516350,
+                        1245459869905576168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4553655001688119856,
+                        6050059151102331079,
+                        13376279339656508599,
+                        336816559922415340,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2011429914820179843,
+                        3430266426005310790,
+                        17634838428003933323,
+                        1026386496025384616,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8803200811718761939,
+                        8671269370748220768,
+                        4562174751865459923,
+                        1182952544867844875,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8542267177695248187,
+                        4742288177589982697,
+                        14776434358159219209,
+                        708630065912816816,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1744898395820558620,
+                        12899602890898194950,
+                        16706575018432834444,
+                        614395776092041058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17821104978661436140,
+                        8400441737534617830,
+                        6403220649008371793,
+                        362698552922551094,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18051264106071926978,
+                        8298421483839485691,
+                        11641576077038964832,
+                        421364428850636139,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6116442036656656253,
+                        16939179226979120470,
+                        2113261774660640278,
+                        1191713266456964062,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2069912960520276348,
+                        816581114802818763,
+                        2094105078714194295,
+                        341377112507136085,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15634022669904897996,
+                        3168289508992626394,
+                        16868960670045878918,
+                        1329573203278527032,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2078280506271796954,
+                        6361340794638982655,
+                        18432300114900014063,
+                        1183478519815103400,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8604229615377242159,
+                        5535896578113960081,
+                        16915129154026755210,
+                        895189152767601017,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1151240763685796219,
+                        15840084654103546658,
+                        3556827417019397472,
+                        352664325435999866,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3463532664275680800,
+                        5877260372779007436,
+                        14210006278055877801,
+                        528929249776692527,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16274514662811703938,
+                        14290349736801227195,
+                        4615385938717861601,
+                        1092129242713495076,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2319136386183038692,
+                        7804775270692216983,
+                        5376326108079381992,
+                        966022472349840945,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1074833979395692273,
+                        12470475858097205718,
+                        17549450847881597843,
+                        850214067241977342,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9944522903322945535,
+                        5592388599926885799,
+                        11495411721728685531,
+                        1056746222282446010,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8665687204657980295,
+                        1858636937320948342,
+                        16184514270172065471,
+                        605036064275853091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16590396491226436831,
+                        5453427107023330274,
+                        702773109769413427,
+                        850946681431712907,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14900306856508425442,
+                        3363274900434320374,
+                        16225289896393526922,
+                        226354937410822667,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3820414542866175721,
+                        14207196530450526301,
+                        8220893761477709216,
+                        1080609896588089951,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18306349170327065039,
+                        2015244247352341467,
+                        17718344102447999976,
+                        991713843830824571,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5267953726994311902,
+                        684277302276594588,
+                        13811268596981466203,
+                        595098848838084421,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14982886470588134713,
+                        9516810311342901112,
+                        2594817663143138715,
+                        1182224363775883013,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11811089204029212476,
+                        2930778266949597738,
+                        16040298998362484530,
+                        1247711875226791864,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11022815927150486840,
+                        1327708829573427325,
+                        16946840254899342588,
+                        415603022335137242,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        639511284259922187,
+                        4400479288932523591,
+                        634118639845417291,
+                        838570198019267377,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2926816041071317920,
+                        7053883140314212626,
+                        18186383620027093801,
+                        60458145272719129,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16407488040532759728,
+                        10800077372946518739,
+                        16547033921294048680,
+                        415345416971631124,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6006153700163275235,
+                        3174357667102591077,
+                        17740869782708675813,
+                        75248004326615264,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2938141567459560648,
+                        8912314078019216540,
+                        10917163994940692557,
+                        237848619242740285,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15381119190554282234,
+                        12151267118441455387,
+                        2751696807740822393,
+                        348155293530936941,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12880969445618977371,
+                        5419470033904039207,
+                        15584614894817785883,
+                        198683706349945871,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5722145652858777513,
+                        11334720387378590384,
+                        16529717759267679783,
+                        545324509009208868,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7490791496189877863,
+                        7735939536689393540,
+                        16371234432570556812,
+                        168544866728392063,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13726230634500818315,
+                        7875944154655361637,
+                        1257787090699905559,
+                        233225977449528620,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7800733665289051361,
+                        2977645651963834449,
+                        16986875878449948742,
+                        554483353037118122,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16087385660493613113,
+                        3084128770287616089,
+                        2412501715658856515,
+                        888741502705232767,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10370303996902603383,
+                        11536554073227010126,
+                        5689892448928774489,
+                        904264040131272304,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7202393124386112547,
+                        12766003823834725722,
+                        9447884893109627177,
+                        675493774895833659,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8224320539439040705,
+                        9274933007351457591,
+                        2023441822940500102,
+                        1054039961733811853,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11878791251179736383,
+                        7654453737667870250,
+                        2916570643088617557,
+                        325965006083802338,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7796103490372321940,
+                        13384787594091928144,
+                        10341518897281278301,
+                        1164780304148933227,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15481566730479180445,
+                        17154615781551713739,
+                        15141233583746474322,
+                        385301630952503082,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1927009007914119975,
+                        1915468693822036289,
+                        14145475826761956982,
+                        1197935446076028751,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2770985047388569705,
+                        8078089188864687875,
+                        18134578942386383192,
+                        1281517504202226958,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5031815037187497788,
+                        4816489693453469258,
+                        12700452716197285826,
+                        598545223444619633,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2765705301409673913,
+                        4319261440937802452,
+                        6300188172241416199,
+                        239311887583363778,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10978241613392593217,
+                        4535215888150949008,
+                        17628229824232670220,
+                        960626760248292400,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9927296862541728329,
+                        13145456390887775367,
+                        15967433741878475423,
+                        1170630264929470262,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17464004657739298100,
+                        17072564726989667997,
+                        1009899580862637595,
+                        1089749915252792938,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16838975467585828239,
+                        1613253642801978195,
+                        18149270604517956413,
+                        721905448659457062,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5538810275956970104,
+                        13938617105149699263,
+                        6501738723341994376,
+                        793033540290888517,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        888268114634137976,
+                        287775057603110977,
+                        15359410967094597945,
+                        1156960546965006496,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9877576055080786345,
+                        12905642770460325052,
+                        4832411104280704984,
+                        1084676512716781070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1469573270025696376,
+                        6415927257324321537,
+                        6734321564518061204,
+                        941483923125909591,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12962085394260445917,
+                        8527994622561723055,
+                        2469625923001037554,
+                        982807636330229846,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18149650912618477047,
+                        4737301084796090235,
+                        9170900122757427272,
+                        354812975326055795,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16619409632295509727,
+                        17694046702307609358,
+                        11690172216482017085,
+                        1111762323879575581,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5692052265644647137,
+                        9214312772958450044,
+                        10110763453521482518,
+                        1027550371681025625,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14770405667875248929,
+                        10453449942225684510,
+                        2259837170864490654,
+                        1250789254132993211,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17907096115066453313,
+                        13509691789220838337,
+                        1294376523973344608,
+                        90987430399219880,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6865053282380054044,
+                        11765616792072129666,
+                        8535616185386017264,
+                        279371285208163888,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17062379851290097975,
+                        539674740602926628,
+                        8973053103662958032,
+                        726691037882691573,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        106679419915664248,
+                        8106200154938778491,
+                        5329583832352979376,
+                        1325473122361966364,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3190893859132263406,
+                        13935610204241277775,
+                        11924481614075815694,
+                        353031099597232448,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2183936914102523442,
+                        4234717370968451638,
+                        18073210672212592215,
+                        479158622316787886,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12091961948190057077,
+                        11318378217786088511,
+                        7107363188640703317,
+                        978250170616747806,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7646686716627102426,
+                        7212936728152461305,
+                        3484902910402929849,
+                        648732624538758546,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12322262084015036734,
+                        11904557605966755800,
+                        4088113703890477073,
+                        1040882697094078684,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13545160919452442724,
+                        14547176743820701686,
+                        2633608020954039262,
+                        619025787534671760,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4395704234827451589,
+                        5704449895509301232,
+                        15104564414738999262,
+                        177138532311286555,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6224998466133279380,
+                        8700445609950377765,
+                        17305148073426453697,
+                        1072802346410689647,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12847178303622696469,
+                        7631958088274230711,
+                        16194136541226512973,
+                        807870932617566544,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7884844427078989503,
+                        5395740272378448579,
+                        1143499708516526624,
+                        219690919704199512,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13128683950734104676,
+                        9439488367434399486,
+                        12924339991233825075,
+                        788559201039575100,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4882837585199552270,
+                        8343341327908307371,
+                        16230472871167729919,
+                        1179118159225149603,
+                    ]),
+                ]),
+                Matrix::<1, 4, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5533725823959250646,
+                        7130148917672354535,
+                        2299935987255664872,
+                        465312273430823613,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4518247362110216556,
+                        1303690937026526233,
+                        14481060414159607800,
+                        777874254091699721,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4852847556437654947,
+                        5502123739536927841,
+                        4843981972657666559,
+                        1080694583669626900,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17068541378202505904,
+                        1117172115349228940,
+                        12468993336551479735,
+                        378531118682947419,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8008150737402636880,
+                        4488734692587776588,
+                        1547555191554943536,
+                        1006206882335955987,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4174856959927929341,
+                        6956702737728349555,
+                        11313218605335544685,
+                        1279701926361717254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14997388866253338116,
+                        17951148788181031565,
+                        14101804784614517907,
+                        65578444326456143,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6853363902592985329,
+                        4486369932351851910,
+                        65610707319252285,
+                        873172475563117547,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        579603889306395083,
+                        2560608140709899462,
+                        18146698412336151881,
+                        60258495702968472,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9924506099485967285,
+                        14841337095946012878,
+                        6550473620052701980,
+                        355439357201872457,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12531688174264614033,
+                        3629799128760975880,
+                        14801960216155767350,
+                        1064176756880888081,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10680016211572180547,
+                        7640186196594536338,
+                        6573062001669273848,
+                        464429714610594606,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18417588127792957015,
+                        16444529523188187364,
+                        12084138139912705579,
+                        16805794537696529,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13339545413024956285,
+                        12462761674569060732,
+                        10134406054826483261,
+                        583754886928078523,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6246845195152016496,
+                        16016738864936728009,
+                        5667948898993596155,
+                        948927193584489029,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17388237537280169500,
+                        14688406879934192659,
+                        9909558203923179050,
+                        70400508517387223,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5474861045381340177,
+                        7686087527544521306,
+                        11338593297362199268,
+                        1021856532337771510,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8052444443922943850,
+                        4009271504061137276,
+                        5568866554849053281,
+                        1340081542973701928,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13718366036845239510,
+                        18043008954918195926,
+                        9743799172723723811,
+                        242610881533123775,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12288963833321771774,
+                        11144465775025909714,
+                        3141275767114171625,
+                        895525891428781574,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12404021506369929265,
+                        14478431220710638983,
+                        16528978820778867199,
+                        1337150421852075847,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14269177948606518010,
+                        14317366494171555302,
+                        17781066350959942798,
+                        197359887673063489,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12899528200685423645,
+                        1817678529746564917,
+                        2099502764987009011,
+                        1116816677457497135,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6364946926969012391,
+                        15881033552478042103,
+                        16127504872579347712,
+                        382615667735657086,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11058646051271038745,
+                        6859926553724662452,
+                        14087301393004364310,
+                        933306102477549317,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1055047094489815101,
+                        4078033726602170946,
+                        12892578489024905517,
+                        1281158139572661976,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5359943878923586203,
+                        16376042868862104786,
+                        5637680489769528437,
+                        274508389146665774,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9466887184987077213,
+                        12707324624592417252,
+                        5674722778664167831,
+                        495915214907561344,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5559270171112387908,
+                        18090552370541844697,
+                        4468532076227855438,
+                        987685496358074261,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10757975082173786027,
+                        17106276533668884653,
+                        16008494306379137448,
+                        1236424776827918502,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12651630831207509026,
+                        9201507732813618723,
+                        16271105479151831696,
+                        1088028967768313039,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1230449153550648107,
+                        265047928608104139,
+                        14808043482234569955,
+                        325688046287390058,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13141399420347535214,
+                        10323718706655672545,
+                        5271972852381490754,
+                        698174654390126163,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        372444215127449162,
+                        6596103022703759073,
+                        1647706108480519163,
+                        886941719362299906,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5032445406852833227,
+                        3216505841560606858,
+                        6517925226383774397,
+                        1070169747543143371,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        606117931900595268,
+                        11653697130177906902,
+                        16942600493441083920,
+                        207641435432611378,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10156513900929988871,
+                        10530356278918523762,
+                        14822893961039084664,
+                        161451167716228232,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5121741770267475287,
+                        15513755736291034019,
+                        15586246947451164544,
+                        318686326614228424,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10544893749060569045,
+                        16634377462455718128,
+                        15424965125419130088,
+                        421312194245698247,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11266056403263964991,
+                        12568371609374858109,
+                        3531321490936409191,
+                        508836381468284493,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14899630606893874181,
+                        6378344959523143322,
+                        5326343488656073582,
+                        678256632460809235,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8604354652434330145,
+                        15276531567938090768,
+                        4221676591098543719,
+                        887292207042946940,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10167720299639556674,
+                        4032113283136195884,
+                        15412529848138749452,
+                        21416729784789829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1744850832220109759,
+                        13008821337905939469,
+                        11071631382001010551,
+                        788542992044475498,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2588487693831923600,
+                        819162455518477850,
+                        14502680861403887785,
+                        203676022757604871,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15050651530407319810,
+                        5238818184223155551,
+                        10359161461431902961,
+                        163152244193461490,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11621410489163276959,
+                        8095296962816118406,
+                        17382993292008629559,
+                        830965834513277734,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4679845673800762002,
+                        11732742802677067267,
+                        5456637834111887652,
+                        399639723348579854,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2687561395762377863,
+                        2742745916341259698,
+                        3044301131411393039,
+                        723385064584425062,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7499903664201677436,
+                        10700255296173911735,
+                        9370120751716629255,
+                        519782401388716596,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12815697858642806748,
+                        3589518328364739416,
+                        5217556848154698002,
+                        1289540407776785865,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5916520816310451970,
+                        9082425633134247720,
+                        12382542148805970812,
+                        992107013138241122,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6236639905261224386,
+                        843729385509220216,
+                        910784084401746031,
+                        557709047224682096,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15307210632392180298,
+                        15607334968149180484,
+                        9709374798094721055,
+                        911705022776362835,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        565211768823822757,
+                        4304458791623152845,
+                        10249442774709061676,
+                        1097659357675265239,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12205146530944986348,
+                        2116986434579909283,
+                        4201175271170209969,
+                        992267258612987371,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12458858951338998496,
+                        4060946816566081281,
+                        4815111439256220560,
+                        889585388579716031,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13330737697681975741,
+                        14306352519046153100,
+                        6792373522794028640,
+                        181857489562014553,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9910876762206447536,
+                        2419056950848665021,
+                        5515306838952911504,
+                        400668493068602121,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4501996026292399474,
+                        3944405189426212415,
+                        2099089514371618361,
+                        339943583855773594,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2693369834887763486,
+                        5226235063818719501,
+                        1134958389215915674,
+                        216164169472267996,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11481921067854847814,
+                        3619328605795306677,
+                        1172215419425542958,
+                        698824999301654900,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5583148815732609314,
+                        7533776866599928206,
+                        4700764388317654893,
+                        795419583657348713,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17490171026965334038,
+                        511945728425642245,
+                        7365182481044577047,
+                        220760401169961586,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3475090391351524558,
+                        14779509624823172685,
+                        3424268588547377302,
+                        807300922038386992,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2661498283128546182,
+                        8564296373141310570,
+                        1331354831148546679,
+                        288633548378759086,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        160646850906444279,
+                        16524505359942069161,
+                        907391002106299867,
+                        559294066232203259,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15868970648699916119,
+                        4564623492190132505,
+                        13103059063354872833,
+                        100862674768271570,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1221307603989354164,
+                        12587043720970902174,
+                        16895819776297027028,
+                        145062209314500623,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16353516398818965607,
+                        14353195469809830563,
+                        6413743319392341778,
+                        328149786789847495,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18408500173092127083,
+                        15531422165208984882,
+                        15085140826729156921,
+                        838526002683321237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17044208833349483673,
+                        4186851180160463186,
+                        18439292304841373891,
+                        635623871921935831,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12010244824104902831,
+                        7094652480399574006,
+                        4750126738460582225,
+                        989637526032941486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4441078329722014290,
+                        13429060628884043553,
+                        17809157578144273686,
+                        434244385592794118,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6991808242812880086,
+                        9244601469821298803,
+                        240701800791269362,
+                        709808634005480046,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13857085282532288295,
+                        308678407453354502,
+                        15922558612862651645,
+                        851414952912951519,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5101399957442677481,
+                        2142578709995607628,
+                        15743510483605305349,
+                        666733543413083275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4873022314864281149,
+                        13307090161474032822,
+                        14158291996867903752,
+                        938052837538358985,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1546160817298626130,
+                        10540049778787005713,
+                        3368713709127751691,
+                        363188968017269367,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        672262991009706902,
+                        12218048109997218244,
+                        13699765194488826580,
+                        1084475947638507828,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18346923840081698634,
+                        12943712128874619670,
+                        4019790911633783753,
+                        1100400008744250128,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17812255713702828327,
+                        18006780541970109111,
+                        15638439909761933062,
+                        607605721282836799,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2146046584044097439,
+                        13101190276266477217,
+                        13417007043729135623,
+                        306909505181101092,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5881505764977051707,
+                        9027582737682954755,
+                        13113110745761042063,
+                        498156427767794980,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18445494826303215741,
+                        1922640032275348520,
+                        18401753303151108255,
+                        413700892364628288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5713551333083111944,
+                        13466982107671483604,
+                        18052718879288026086,
+                        606241695332365536,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5341312667566345417,
+                        15999363884279236272,
+                        3859963172427206256,
+                        137512694474664211,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17490177364963826654,
+                        14543044352686070271,
+                        16150001646614169348,
+                        704698259553703393,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9727568846190657427,
+                        14417114472564400058,
+                        9365197390786121627,
+                        794983300027476088,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12997373201110860726,
+                        14246380874068335614,
+                        13296665919577048738,
+                        1098745640843188596,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7228530868527339586,
+                        15583029622716990094,
+                        8861208517089803667,
+                        82901497917931480,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13638279809916295410,
+                        128790733739477632,
+                        8417886451161888027,
+                        991255423238702330,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11087968183222739640,
+                        2587511469422769048,
+                        14523312587696220059,
+                        192146587408207716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8412996681904903641,
+                        11887442821301638447,
+                        3370639369565289246,
+                        53949002610311715,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14762391536874926895,
+                        10740959434718252074,
+                        1226825919833891779,
+                        365160723178298573,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1555330760834900970,
+                        1518370310210703133,
+                        1735267998098714592,
+                        1025622291285303779,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1334503499975428364,
+                        10717052295534688524,
+                        7361984650156075053,
+                        844791857359624202,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16941837112843173147,
+                        2487213732723210497,
+                        6266342779648955295,
+                        833823163972637517,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17244221202450562885,
+                        9683927217285200288,
+                        17625214279845872049,
+                        72929072308819687,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17322021997939023067,
+                        12230087119796208396,
+                        18396814271884812695,
+                        813805950829206789,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15948063285113474855,
+                        10770603797800150235,
+                        6677092062918475126,
+                        1088103102296276139,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7711244693099635983,
+                        3166785488663936361,
+                        4922456695483615993,
+                        430831973502880121,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3809367189852072520,
+                        9855677685061821074,
+                        945495584216248446,
+                        909359350460583709,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8110395815872458853,
+                        1514592973182402061,
+                        790829722054948037,
+                        353129076761034824,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4490522698778351596,
+                        14224519981854920216,
+                        17099540428194228219,
+                        339422077308612769,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5639095254854416487,
+                        1620715418926540453,
+                        2533378178940440961,
+                        1219281352481947332,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10638608741850236276,
+                        6613656335866104958,
+                        7688920349526656377,
+                        267050381048915175,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3036522849970263675,
+                        6507669881427436695,
+                        17574949570571358776,
+                        1277705835314573728,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2222484869463773519,
+                        6808168746395796147,
+                        4421285536548949511,
+                        221502296849107896,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13864906390918380841,
+                        14542080963104347726,
+                        4946615231209518707,
+                        410716390051665697,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17342164341678591250,
+                        2157153117836804364,
+                        17774122463652037556,
+                        1045677158150316262,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1897195111902571201,
+                        7441808212794130262,
+                        14230292566113962566,
+                        158679422354435267,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12385311968994581122,
+                        13994070371431075240,
+                        8808495601965357849,
+                        1021378877363492133,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6493128916407973417,
+                        7997830985039481850,
+                        7096965712368312908,
+                        1047173662891619916,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4284147259360490869,
+                        16041408471489150031,
+                        7607939514063442430,
+                        829626684549401311,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1856817747001420784,
+                        16357946393175270201,
+                        9210719327832581050,
+                        1018175197760234391,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5500145050367041469,
+                        3840984581227276122,
+                        3431832361289319237,
+                        260564656886891183,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14712945504944782403,
+                        15039214861617765141,
+                        11103751083365574273,
+                        113689317515455733,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        701726807475653267,
+                        14140055316542680948,
+                        3011442975075019770,
+                        584361997905323533,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17748483580890358752,
+                        10604246296825603311,
+                        13033470142003819820,
+                        599441163676514743,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5299230594859812716,
+                        16638780731660150925,
+                        952927811712562861,
+                        498189305785932392,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4432890524542841769,
+                        1996216122820571178,
+                        12192741690714508643,
+                        764076413945664713,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13089352217465064175,
+                        15801731950730062998,
+                        16367845731547800506,
+                        315925055121715682,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4079387977856382544,
+                        9219169523776319636,
+                        17133435751310074522,
+                        1287156460338158966,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14473285560728073066,
+                        3618147328828088415,
+                        4933194636388348895,
+                        391722132844625282,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13555572770500462469,
+                        12796156150384677031,
+                        16297295557264104694,
+                        523410635806816263,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6170968086362616849,
+                        306706920248086968,
+                        4382856058195592988,
+                        1226950348108880303,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3093606516563139647,
+                        4131257826299546810,
+                        10588085520003095457,
+                        1310373646887997506,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15707691940096764596,
+                        14046438460058416295,
+                        11356314773664378791,
+                        147964178255317619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8201755464183735882,
+                        2991972398923105439,
+                        11791554354764211674,
+                        1286871952317928740,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2953821547762446132,
+                        7153208089983489541,
+                        14568712078213723887,
+                        1101235681331403529,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11815785876614174704,
+                        5605059654201074993,
+                        10904154295660225893,
+                        692152707302392583,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9934909506268943046,
+                        2274249149867807254,
+                        3531392088379596658,
+                        660283043698590630,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        958986006304584343,
+                        13001251565471705213,
+                        1416288572598656256,
+                        1221295516882956693,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5730060047175510268,
+                        11255606170310143990,
+                        10933785954373256610,
+                        836303327919337651,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7591124081341998393,
+                        1370521788576229050,
+                        10992525094866264515,
+                        947263384507583288,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13840557080325458896,
+                        1999030170389741283,
+                        69255967485709149,
+                        26526623734053272,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6524833247529906020,
+                        6547125449358199158,
+                        10486547913745735943,
+                        642431929201162991,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3743993753270684145,
+                        14256441935056712499,
+                        7598266108650965827,
+                        1306257536094844669,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16527991762705386673,
+                        3916166590164385326,
+                        7157205865874653987,
+                        637669137848424850,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14453223122790922293,
+                        15399061060958637238,
+                        4258685758769405842,
+                        1229617223288820410,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2868381367633081138,
+                        7242905200458378705,
+                        7968721407964842819,
+                        898701719831007991,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16611732092775667033,
+                        14274172475514068594,
+                        17592529307700387421,
+                        586022339750280023,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7840810345790650116,
+                        10797519165412616360,
+                        15554413846912864588,
+                        609845808208757384,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1747866457134349280,
+                        3111486930449856289,
+                        17775151150666877929,
+                        340056564062709002,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11962487737295208088,
+                        3649224448199230158,
+                        15671608482796905761,
+                        202606136518779082,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3551394541716235712,
+                        15458795042068548462,
+                        8763201515613702199,
+                        318596882355232634,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3756586204440963235,
+                        3389012868051759986,
+                        1805275925912854551,
+                        1316379893047121072,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10271924925276311533,
+                        2194730741738041227,
+                        17814426909830897839,
+                        712404344167208446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17308060947554345144,
+                        12467241468633739409,
+                        9118355796160194614,
+                        33388472976577043,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        19164830849848089,
+                        143558905689572924,
+                        4353552417088241220,
+                        816298221791862897,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        322209453150104,
+                        2220373467811258754,
+                        1505273121791010640,
+                        964331086618765903,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16803569621918928652,
+                        5564507201063323716,
+                        7736978622400100816,
+                        217354645033279944,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14219420199971110875,
+                        15262302736543128130,
+                        18320419495594313626,
+                        961738825728336715,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11797026171982465386,
+                        3595803575855047255,
+                        6921617580506593009,
+                        337853739521994659,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3161295804893700545,
+                        5807094586301350539,
+                        8197975991076123118,
+                        180492011417062412,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6173862194099749497,
+                        15710943169595507768,
+                        4852332296730551568,
+                        137657921815021881,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11339080354842362433,
+                        1161451408765287767,
+                        17219284038412274095,
+                        679780142733261385,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15966228227564393379,
+                        2470459366551046892,
+                        4802315009350553942,
+                        689491300790637000,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9915163745763389021,
+                        16679876175945913629,
+                        3720622063289763069,
+                        142190671626534828,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1815093453618846057,
+                        769154017293633486,
+                        12649965136386538591,
+                        1040001830531782382,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14982788908002903556,
+                        8652556522805123738,
+                        3606670527813954469,
+                        531699001388149447,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8496919964125090326,
+                        8620335883017208954,
+                        962889295104248964,
+                        695542381042564036,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5209338423630472970,
+                        15690382699443408099,
+                        4192836815936466574,
+                        353889142135786535,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5018986566320921340,
+                        18221392658335609720,
+                        17373174658223222823,
+                        1029649048243962254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13947248322232040806,
+                        673381644457119870,
+                        18429416025790294026,
+                        1264341581393915493,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7498716004746708786,
+                        5173580173763102387,
+                        4796490927526224335,
+                        985218166224676055,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3308603613390868610,
+                        7896729176151620412,
+                        2521166612149956456,
+                        712703357602970353,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13871389354009292636,
+                        651007121308391380,
+                        10643834496833538116,
+                        13937875886508120,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7326798514644654361,
+                        14896338241060581586,
+                        5582693705051826582,
+                        593826772607299807,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11770038289583617735,
+                        1326192302383440190,
+                        9430082239345986215,
+                        49066147125875366,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9496803622339210993,
+                        3178603549463848129,
+                        6597532802564566875,
+                        463196292050684711,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9696811084126815353,
+                        4536817640580864933,
+                        3983275156259498042,
+                        34459581232203735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14427565124960874428,
+                        13997653985205131955,
+                        1459506680861872205,
+                        398342241291014753,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16212995212471839259,
+                        9863104710967499483,
+                        7807522845346030107,
+                        955182521788982794,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14828958215517411262,
+                        12308926948014500257,
+                        13423814802448519086,
+                        474091828624275422,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4357449229235209036,
+                        15618665530342097317,
+                        15694894902404607640,
+                        939099521693354901,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10985858319704155619,
+                        5616783075625960363,
+                        17290321724803856706,
+                        818909674525729294,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11545567510967042771,
+                        14796350808536105208,
+                        9122122413459078141,
+                        1088836724045578284,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11376897043594942287,
+                        11608545221773114810,
+                        6432855321369892660,
+                        383675784297906314,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10918129570638682711,
+                        13140374570304658865,
+                        14800936659006888910,
+                        372355056866512772,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2562602316492212852,
+                        5625356014411456958,
+                        8518499388961581470,
+                        526186063310681692,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4897710699234379148,
+                        9148354640075796842,
+                        4247490112513103466,
+                        657023387173490236,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6675200157197957858,
+                        18398042419184914458,
+                        15953912007857993390,
+                        18874676044355916,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        858642831167831373,
+                        3492219762100873548,
+                        13377933032455573909,
+                        989588527566415911,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9396976163441592063,
+                        4603126713396492521,
+                        4071307966329370336,
+                        607847487213142858,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12891278332091399660,
+                        6001957272231618839,
+                        16654937641669777234,
+                        1096369759813730224,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6651495548767529251,
+                        17144117800198967881,
+                        298382895462960868,
+                        636144298765650316,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14879527959950255162,
+                        1347387455793770501,
+                        10626357369153444573,
+                        255363471329324740,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7544634985239230787,
+                        9036333157691137721,
+                        291327307265105467,
+                        1109506290597629471,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13778935816336646491,
+                        16003751385691374366,
+                        17065381583100131526,
+                        617155970101222065,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10506580736486648107,
+                        5838871727747605454,
+                        9914696967948687630,
+                        481161278304655148,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13796487309709731040,
+                        16605621455486380591,
+                        4429397923131891694,
+                        51722719895599469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5349122784910220946,
+                        5236728043991032185,
+                        5747407758800149275,
+                        983583713232342478,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12294522539746670074,
+                        3948439067885433440,
+                        3961311312502900219,
+                        257057117999645382,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3662974892556820505,
+                        11267490968248651674,
+                        16508819707125706754,
+                        633664773399711106,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4730509449783325180,
+                        10519694053350718657,
+                        3894916055899108971,
+                        376874159450628658,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7864647345868038264,
+                        1231451373665486593,
+                        12628310209584670809,
+                        965546423470913356,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4510176081071680366,
+                        18006792534837534731,
+                        3809292409398982268,
+                        850930794038962298,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4653989095969440785,
+                        10260856297411728121,
+                        2678116987300562152,
+                        666460191335497788,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10325038246372830871,
+                        3740139918363841060,
+                        4120986238132908832,
+                        727326647066446407,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12412274733477140508,
+                        13819445988755764478,
+                        8264778620151758130,
+                        1215089371886164782,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16546704583966545316,
+                        3735843615866813028,
+                        8424333045776198101,
+                        625079269359061350,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11845534775645018517,
+                        4174228531903051019,
+                        5988633974959013757,
+                        815260484386992610,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12879665582277878285,
+                        6495227257765088077,
+                        16669542435531672169,
+                        1030691037702746082,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4804248849658354743,
+                        9761482371538851627,
+                        5490955277755772978,
+                        13377812964208918,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16988733596455434764,
+                        11190039545692712900,
+                        11128624540170440888,
+                        379666163349812978,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9907039362207956086,
+                        11315522026742550964,
+                        9707138826559182088,
+                        1032823252320136059,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12533717800311369309,
+                        11469658569540469836,
+                        9983518990937939853,
+                        300870068622457787,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17705699690616080140,
+                        957775302458014974,
+                        13477329654417515551,
+                        672915418148280833,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12062115825887283656,
+                        11776409392822030162,
+                        16918256046922336357,
+                        826496144168603157,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8468560823989063966,
+                        18331520464624821690,
+                        15761821306604513561,
+                        1092269624592388040,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11703876955655870422,
+                        13639643859346961689,
+                        13824305039744743430,
+                        694874320851688238,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14092141051886494144,
+                        10523965803843620728,
+                        10536719683456727688,
+                        595060868648406486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7860878623818046716,
+                        579559111273444892,
+                        7608616025166646141,
+                        748500637811441128,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18051344611454668446,
+                        9147477667058951425,
+                        9117100557954567990,
+                        269700370430606153,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18237746092151932709,
+                        8993845552232231079,
+                        2912841814015883771,
+                        1112315955840818218,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4332893468242207123,
+                        5167681082708949409,
+                        5868217353793870073,
+                        906417749317664178,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13816218607178122154,
+                        12388813031509122064,
+                        7503848250119521503,
+                        60424624232345884,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7081029988148591342,
+                        4339496883205324257,
+                        17146664523794034125,
+                        833727728907566317,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7090251919986611670,
+                        8480839189363839629,
+                        10347115849487323682,
+                        862620153186994162,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        505750523664549830,
+                        15536073745824540488,
+                        12451871059212563212,
+                        153162079881856769,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5165496682606619875,
+                        16140671803133532005,
+                        6684866869036343907,
+                        502897444195918875,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1917727877227325131,
+                        17312953863338036447,
+                        15118778022523342827,
+                        204112196436109423,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4004748757834287748,
+                        352103993474122651,
+                        9363782548125871125,
+                        1272133299552430089,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8044353490413247288,
+                        11273740580382221658,
+                        16320076022743539533,
+                        893511032505224178,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13577472911649850102,
+                        13207771429053363900,
+                        5541810718679012567,
+                        29808681583969005,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1716670740736710666,
+                        9210832840008801447,
+                        5787722731316265398,
+                        21493335964215916,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4764388238645072786,
+                        7594546088555605443,
+                        11383695615434946521,
+                        549784704394472562,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5848594747753007849,
+                        3004795915932170488,
+                        13006673500021332960,
+                        1323651023311888091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3202610042716549629,
+                        8280776254010451779,
+                        13422848663375137887,
+                        702730847063661110,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3256592095168548245,
+                        4563512607601306628,
+                        16879668444379783127,
+                        1338115178519153638,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14891890443619149785,
+                        3948103148235617098,
+                        8982779461164143635,
+                        1295126846359365978,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3469794592443686720,
+                        8043734064873346858,
+                        6838845429269931372,
+                        391174864248897230,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8132011489079282636,
+                        5922787042836250918,
+                        7956735266979399034,
+                        48597448413544881,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4918359587951576990,
+                        16651324764743973762,
+                        6377022960934458315,
+                        883919795175491180,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1053133775789162422,
+                        15009710253703669363,
+                        11048888804970728991,
+                        813380929907432720,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        514757011428073843,
+                        6248753419726327524,
+                        6330762970689082798,
+                        70552712680989308,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1180746559092728920,
+                        8187741036492045543,
+                        13102956155251515176,
+                        716793134708108901,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12418310806064953433,
+                        4075531460799868959,
+                        3872368542560512134,
+                        254531731441422349,
+                    ]),
+                ]),
+                Matrix::<4, 1, 4>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11128513641337788976,
+                        10444159823231853971,
+                        15570500292493263337,
+                        740964064967758241,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10838563896589601595,
+                        11031470011670528156,
+                        5145874123760574409,
+                        241044127738105424,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11201594763191425978,
+                        13794715703201680306,
+                        4718642333327037828,
+                        82343008361416860,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17063884825454256236,
+                        16405926232050944052,
+                        10546382894189499826,
+                        239025912615128045,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<68, 5, 340>::new_from_known([
+            Fq::from_montgomery_limbs([
+                11444359781275743848,
+                1455917629395179315,
+                2262903860768154529,
+                135894445797360378,
+            ]),
+            Fq::from_montgomery_limbs([
+                11059842620545411989,
+                10365279141557279334,
+                9477008543253703518,
+                672816766707496810,
+            ]),
+            Fq::from_montgomery_limbs([
+                3638917413472569546,
+                6107166115798364001,
+                1290184584215263076,
+                460448795686855285,
+            ]),
+            Fq::from_montgomery_limbs([
+                6809433025061013926,
+                5290285289921514834,
+                6878335838464463692,
+                620414731088357853,
+            ]),
+            Fq::from_montgomery_limbs([
+                14287112920661306258,
+                552049971771472885,
+                16454210513972539858,
+                801163911327420192,
+            ]),
+            Fq::from_montgomery_limbs([
+                1730403402351437022,
+                12256360978882985568,
+                9610616155096468114,
+                403278780515934743,
+            ]),
+            Fq::from_montgomery_limbs([
+                7312080600784983836,
+                131719895904407520,
+                11656980462628393569,
+                1201901630910062296,
+            ]),
+            Fq::from_montgomery_limbs([
+                9717985347310934710,
+                322997940906060467,
+                15428452811957309233,
+                765679228479797476,
+            ]),
+            Fq::from_montgomery_limbs([
+                18209971998091530004,
+                3774255157718202923,
+                11245187046985147533,
+                574328914560891980,
+            ]),
+            Fq::from_montgomery_limbs([
+                14719594514279450112,
+                12546162991102780071,
+                650136013555796689,
+                1193723199667534770,
+            ]),
+            Fq::from_montgomery_limbs([
+                12540368630399625247,
+                6747277740992143419,
+                13901254473808262974,
+                1069697204093621027,
+            ]),
+            Fq::from_montgomery_limbs([
+                6006201097159998132,
+                10993057243736521057,
+                2481643342887052237,
+                1040742999317755196,
+            ]),
+            Fq::from_montgomery_limbs([
+                13983135365813373229,
+                11208211684809202876,
+                9923639616764140716,
+                800988735500877745,
+            ]),
+            Fq::from_montgomery_limbs([
+                10817845003975313022,
+                10199444559971109476,
+                1583825088432788889,
+                1197118818187050562,
+            ]),
+            Fq::from_montgomery_limbs([
+                12908596521829268600,
+                12726919235664744581,
+                13434581855162388549,
+                1260270868210446919,
+            ]),
+            Fq::from_montgomery_limbs([
+                9292047966280685305,
+                16387361546876825382,
+                11386338994503295441,
+                857840649330403150,
+            ]),
+            Fq::from_montgomery_limbs([
+                8646153131933302332,
+                6741783001854103874,
+                9451383129938123241,
+                106732509056129157,
+            ]),
+            Fq::from_montgomery_limbs([
+                5782954814940929954,
+                2405431940747907071,
+                15841248711212087854,
+                1083895051666594422,
+            ]),
+            Fq::from_montgomery_limbs([
+                4340313846582327906,
+                5727693805212258027,
+                10066603269023157785,
+                486039960145075847,
+            ]),
+            Fq::from_montgomery_limbs([
+                9995913207019958712,
+                11617914869142635570,
+                1815443648170262347,
+                716717811676024152,
+            ]),
+            Fq::from_montgomery_limbs([
+                7393248206340594542,
+                11714942883647334975,
+                1136635516182492135,
+                118732845285417990,
+            ]),
+            Fq::from_montgomery_limbs([
+                18137405983041578000,
+                4202714963465505293,
+                4095257578408654095,
+                1015373689662352277,
+            ]),
+            Fq::from_montgomery_limbs([
+                11146214900470103112,
+                13804223464147668809,
+                7142241677282906747,
+                1252419934156774629,
+            ]),
+            Fq::from_montgomery_limbs([
+                1512050694575952033,
+                13449370176893481111,
+                6752811659450087165,
+                761188143419873538,
+            ]),
+            Fq::from_montgomery_limbs([
+                7586183134552245627,
+                11512502662190537544,
+                2265406668225595823,
+                700600969063903374,
+            ]),
+            Fq::from_montgomery_limbs([
+                183512853396909809,
+                5284152489389798763,
+                8617307456243468935,
+                260227098889625947,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7644205802303203128,
+                6580914977254252561,
+                11043008863964715831,
+                195549109698466174,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3246001605490887524,
+                16692739296557914815,
+                2466552121001346204,
+                756524644283851265,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4883372769811312700,
+                848585663463374540,
+                587739030687949245,
+                312594967136892176,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15197400914497658734,
+                8522155314553641728,
+                10696365761381558121,
+                769846979358975631,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3980344873664208019,
+                8934455487199147691,
+                6061553014152655205,
+                929605080250809267,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16439162730129891434,
+                103798412997172166,
+                1813547417578366026,
+                501562006703857966,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5367130689367368828,
+                14815159556460648674,
+                17154316545544462623,
+                571240380648893694,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4195722827200670268,
+                11113218649229578114,
+                15815664511708398234,
+                1247588183715052835,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15177065314521766465,
+                3099585388382907283,
+                5912087923845236363,
+                625597930910900541,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16306521103904520926,
+                9829079293751534260,
+                12342496172082573455,
+                157806831915539936,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13645022527960611557,
+                13414254214448555266,
+                12963645295446887592,
+                820384027117089771,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11672224797731650365,
+                1584993539604839434,
+                3714626413287544110,
+                89979171071812203,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7724825467108524417,
+                14962912510821750391,
+                17502262484238465613,
+                1127379491968297267,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17733401467498063098,
+                14533308247751983924,
+                6648780138019635456,
+                1144664534141883829,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17574446647421328208,
+                2643153163893005977,
+                7237381522813994182,
+                947152021137562968,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                636653512417612254,
+                17366723368040581949,
+                3324843600018265115,
+                771664714149164826,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3809389989472076675,
+                7613172703373624609,
+                4772164167859306151,
+                854499153966429210,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13922073041075873998,
+                9809406356993370810,
+                17220193917506568479,
+                587900933419139747,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11068934828810176065,
+                5110919236997144624,
+                5148823805895992516,
+                200110585401802254,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3474047078477609805,
+                16990059213250454118,
+                17915610899263955803,
+                3379672289869431,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8573337414788190981,
+                6467363111037396994,
+                15865973107659468976,
+                561582771533447787,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12285396408702065558,
+                8624026226307384887,
+                14844265183706137855,
+                893594428785087768,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15233871261459897799,
+                8663866192546140487,
+                1212062839831927906,
+                928312677822171120,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10159243241670300845,
+                6395177866360851135,
+                6928961619792317252,
+                539793983792368983,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5388282696555624358,
+                10923757877063404840,
+                9229862269715687610,
+                66109344603525979,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10731593906608840757,
+                6488108142303910001,
+                2061602072451164772,
+                1246318220337199013,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17215489270766605138,
+                6692945690205290347,
+                13548087717317194646,
+                876330914773136367,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6906110006629704011,
+                1915630671440214989,
+                5025075390947529542,
+                1305137922285496306,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12370419216596179787,
+                9760258627684253376,
+                10508229290638022433,
+                160008496730906088,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6774579278090336125,
+                8020290433561653896,
+                13096785120238496597,
+                1205796990790209866,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10769257368408893680,
+                11210788434799213472,
+                10003986968352001827,
+                364763886667022334,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12874046293101132327,
+                2840547359139628879,
+                16237951685605038430,
+                1097032289704022936,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7556262924941915494,
+                6692439199851053965,
+                5565185610546140859,
+                1168934863095549201,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2181919998017801941,
+                3213251312211828173,
+                11108830384054411256,
+                897209499057953714,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13718337626461242867,
+                7791660817436807268,
+                1261792437255430970,
+                584886493182982009,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4815487792929924999,
+                2044021830350745902,
+                17819284493582047499,
+                56181015568901465,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16546040444417639170,
+                11802315492874524887,
+                7062973741175470002,
+                860125787040311162,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11203110945568599193,
+                9401577059658837980,
+                6787489990857293661,
+                178495431645711127,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10398354675565299885,
+                13445812341500758486,
+                10017054624621406086,
+                890538868218904580,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15506114317286844972,
+                12201158869344882631,
+                14862790713413034003,
+                22291732033449694,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4013821827947837317,
+                2693150095791072570,
+                1354897175765950319,
+                932837075268655507,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5480476398749515104,
+                15365182876232654484,
+                7574334362610037124,
+                104744339132682093,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1287277397521993147,
+                6148657634912634885,
+                15810844206218769046,
+                1170031855093331056,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7604936974628814610,
+                17117650174020153315,
+                7256068408922061434,
+                177152432480167067,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4535345395222377067,
+                8128772254832022628,
+                2049262882518143133,
+                1091545876292121859,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6986294237081290874,
+                7953020673788575187,
+                1653528725135799770,
+                908892892842692195,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9575911568786220191,
+                15454059745575746958,
+                7689788523592313100,
+                413668196229772406,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13979107072577839258,
+                3670769633109260446,
+                15360567951573194215,
+                733424855183759121,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14684057221716084044,
+                9281284325398940186,
+                8985592445689274745,
+                64665806848948706,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12215369767984614186,
+                12900893083145766337,
+                753040791005936181,
+                721784064667343676,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4458361885930055797,
+                3452078761434371117,
+                6154722652943432308,
+                63477375548980438,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14192391302888955280,
+                2932551854346578167,
+                9448469792675523398,
+                991861802117260345,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6963306950982951953,
+                1621915481906390920,
+                4129138290187346976,
+                1229206791664201703,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6084103818905134982,
+                9041138674589227345,
+                12725554127112385498,
+                1344959482655447860,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6215076338809917923,
+                2251348406445608171,
+                9705084881465678055,
+                1008932874568665756,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15720827097240417527,
+                16808202599144396622,
+                13810665292477200501,
+                1073778180114547386,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14953822476823993361,
+                14497763467661530875,
+                4500876160851752961,
+                446842110011690415,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4965282825154437336,
+                4209722580393233650,
+                9477556723436563411,
+                575027989784499403,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9013064684817197370,
+                7457829454159189795,
+                6355973875057495172,
+                222247050620296832,
+            ]),
+            Fq::from_montgomery_limbs([
+                1101942193311592151,
+                8410475600025481564,
+                10583545900194067765,
+                31853211752527483,
+            ]),
+            Fq::from_montgomery_limbs([
+                7169939865979325895,
+                15708834278490582251,
+                13510219568073801760,
+                860676883918173908,
+            ]),
+            Fq::from_montgomery_limbs([
+                11485943637135206662,
+                5634390427736742034,
+                4681618609687180007,
+                1170797859038832594,
+            ]),
+            Fq::from_montgomery_limbs([
+                1420478528863765169,
+                4838095807694475733,
+                10232422396234549089,
+                102514901489451683,
+            ]),
+            Fq::from_montgomery_limbs([
+                4019611711435609657,
+                13741042565157052111,
+                2372271957910884074,
+                562136768498572582,
+            ]),
+            Fq::from_montgomery_limbs([
+                1547088012713907669,
+                2410893170457523232,
+                18337019277456881794,
+                336563950044015201,
+            ]),
+            Fq::from_montgomery_limbs([
+                18283321300066265663,
+                5373809852508555155,
+                1007591060355863660,
+                835389870786637310,
+            ]),
+            Fq::from_montgomery_limbs([
+                16786959288324447406,
+                14916620936647498452,
+                7332216137069030782,
+                1107884217421411771,
+            ]),
+            Fq::from_montgomery_limbs([
+                16892015453562854555,
+                14123644195667492530,
+                17384607211358548170,
+                1263040180505445399,
+            ]),
+            Fq::from_montgomery_limbs([
+                6351024390452377266,
+                6315901530971784062,
+                8990185196153435396,
+                207602098942265722,
+            ]),
+            Fq::from_montgomery_limbs([
+                8507464704422938984,
+                7436139867261684041,
+                16382471416918714125,
+                616557904729058653,
+            ]),
+            Fq::from_montgomery_limbs([
+                14652722836263960724,
+                1132013649842704363,
+                10600496495699042016,
+                789134505606392549,
+            ]),
+            Fq::from_montgomery_limbs([
+                9955289190827064193,
+                18408017328022376118,
+                2398241518626672097,
+                605434309319696457,
+            ]),
+            Fq::from_montgomery_limbs([
+                2223393086542743962,
+                17201448367045239009,
+                6231210262523021467,
+                497050528538555822,
+            ]),
+            Fq::from_montgomery_limbs([
+                7458682393213803626,
+                18276082776898565373,
+                5893168447635887122,
+                281594223319984251,
+            ]),
+            Fq::from_montgomery_limbs([
+                16666203072211055802,
+                8815592868211109659,
+                379418658262000868,
+                1261079285205091818,
+            ]),
+            Fq::from_montgomery_limbs([
+                16750404483357073145,
+                7202271804062043544,
+                8534115717155771908,
+                217086520627375616,
+            ]),
+            Fq::from_montgomery_limbs([
+                8716463027086197234,
+                13738355573723000987,
+                3655676590023383459,
+                63063650122895239,
+            ]),
+            Fq::from_montgomery_limbs([
+                9170850872661052856,
+                2802738167148037961,
+                13740036271321555692,
+                624691989645834505,
+            ]),
+        ]),
+    }
+}
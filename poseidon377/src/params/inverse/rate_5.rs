@@ -0,0 +1,8201 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-5 instance of Poseidon with the inverse S-box.
+pub const fn rate_5() -> PoseidonParameters<6, 5, 36, 25, 65, 6, 390, 57> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<65, 6, 390>::new_from_known([
+            Fq::from_montgomery_limbs([
+                6896756996856485679,
+                15841779177013165962,
+                15214999319107067539,
+                799579116957920369,
+            ]),
+            Fq::from_montgomery_limbs([
+                12490528915572582125,
+                16781010963455801061,
+                7217822162841525370,
+                583758431590239696,
+            ]),
+            Fq::from_montgomery_limbs([
+                4103076503312823956,
+                17758287060299897020,
+                4307999606005888186,
+                1170503199703573017,
+            ]),
+            Fq::from_montgomery_limbs([
+                8258525078799980031,
+                17981209026984883509,
+                1705405933095425634,
+                686116416131442965,
+            ]),
+            Fq::from_montgomery_limbs([
+                14230314701524901383,
+                5359672107767046933,
+                12368533871319767094,
+                843835640573373216,
+            ]),
+            Fq::from_montgomery_limbs([
+                8643704922284891790,
+                1825521656328917056,
+                899270813962044093,
+                1061204208642029501,
+            ]),
+            Fq::from_montgomery_limbs([
+                4953924969538642231,
+                13666539068294068783,
+                11444515785794661054,
+                908034692260218959,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314759225172977089,
+                13709782326296732490,
+                8419170689653674679,
+                308813631347902302,
+            ]),
+            Fq::from_montgomery_limbs([
+                12380544042683374095,
+                11861610031062043069,
+                8139214262706710955,
+                1109426478539312383,
+            ]),
+            Fq::from_montgomery_limbs([
+                14996062894406844828,
+                10463007688447955216,
+                9816295069953689883,
+                96634753033372950,
+            ]),
+            Fq::from_montgomery_limbs([
+                5003443347312646154,
+                12088794601721440379,
+                141876702791129010,
+                583163428613983087,
+            ]),
+            Fq::from_montgomery_limbs([
+                15978675488657509273,
+                2000227115909726539,
+                3431572395324014720,
+                1270003269091334935,
+            ]),
+            Fq::from_montgomery_limbs([
+                13145481180479355307,
+                4178318043230229341,
+                17214877682671464756,
+                661390503117260265,
+            ]),
+            Fq::from_montgomery_limbs([
+                10310179957389889995,
+                10867899306511553823,
+                7834927874190244087,
+                200100347383143821,
+            ]),
+            Fq::from_montgomery_limbs([
+                10331689629547846666,
+                17728898712028161349,
+                9449037036373849203,
+                751974232653749583,
+            ]),
+            Fq::from_montgomery_limbs([
+                12297882660040040158,
+                2029088109297254896,
+                12042324552829098347,
+                1200859725133413022,
+            ]),
+            Fq::from_montgomery_limbs([
+                657629048713803968,
+                4384961285920470744,
+                3194096570312252490,
+                1278463812395335295,
+            ]),
+            Fq::from_montgomery_limbs([
+                2119720360683497903,
+                17163632796986597614,
+                716704290417195003,
+                835532625486667986,
+            ]),
+            Fq::from_montgomery_limbs([
+                10176413099011613257,
+                17987703072219667449,
+                3257008508155249153,
+                4611423074909247,
+            ]),
+            Fq::from_montgomery_limbs([
+                15759997935604733825,
+                10621328495674793031,
+                3913347339572315939,
+                450998439067161787,
+            ]),
+            Fq::from_montgomery_limbs([
+                3307239343003667924,
+                9423014334056565941,
+                8272365424846706207,
+                1154673256444886349,
+            ]),
+            Fq::from_montgomery_limbs([
+                16642064667742663839,
+                3060804713054629416,
+                7003569085640654950,
+                979920132801746206,
+            ]),
+            Fq::from_montgomery_limbs([
+                8454313433475921944,
+                13653730000403974157,
+                341352448321914509,
+                1015923902051934401,
+            ]),
+            Fq::from_montgomery_limbs([
+                450312744060749475,
+                15738910883501419082,
+                2086620364508756906,
+                447004216102613191,
+            ]),
+            Fq::from_montgomery_limbs([
+                14785850722344745284,
+                14933076136398833813,
+                7249228375878258894,
+                281264808411538247,
+            ]),
+            Fq::from_montgomery_limbs([
+                13220076458303229455,
+                4481977149282187826,
+                793365699077403583,
+                440285036970590451,
+            ]),
+            Fq::from_montgomery_limbs([
+                13605549378869437143,
+                8870975743874366901,
+                16032023381940874075,
+                1076523602832937987,
+            ]),
+            Fq::from_montgomery_limbs([
+                9559234580624408509,
+                10608881111252245755,
+                6680835184518600303,
+                403631508487286797,
+            ]),
+            Fq::from_montgomery_limbs([
+                8281363687100444003,
+                9458284510638729814,
+                11442425097703731659,
+                226149641926520635,
+            ]),
+            Fq::from_montgomery_limbs([
+                1714862375920387858,
+                18166922021351479172,
+                2880840457955396924,
+                583373742449029267,
+            ]),
+            Fq::from_montgomery_limbs([
+                7421008378360194897,
+                18056583637279425661,
+                15880489830553072170,
+                523629205160397210,
+            ]),
+            Fq::from_montgomery_limbs([
+                17591299560816615242,
+                1269927312482652886,
+                13727563588258826148,
+                136174046658245903,
+            ]),
+            Fq::from_montgomery_limbs([
+                3832070916375850027,
+                7370533577290758977,
+                11718835567507302090,
+                1191333526895090626,
+            ]),
+            Fq::from_montgomery_limbs([
+                14913444569060772611,
+                3661801227161529424,
+                15869227642287402424,
+                772025891269544447,
+            ]),
+            Fq::from_montgomery_limbs([
+                10079875167336093196,
+                3674964296212113468,
+                2772395614619810042,
+                480195055253052083,
+            ]),
+            Fq::from_montgomery_limbs([
+                16189665692965149740,
+                16022284750400178564,
+                5160367553414389385,
+                650420427956582869,
+            ]),
+            Fq::from_montgomery_limbs([
+                1600662253118628721,
+                6150885245141871640,
+                1170174822001991847,
+                929123466786806331,
+            ]),
+            Fq::from_montgomery_limbs([
+                1763360342997039245,
+                18308972939048043522,
+                6347723755571576416,
+                912745969836425551,
+            ]),
+            Fq::from_montgomery_limbs([
+                16073441153290059968,
+                5326381736065646622,
+                3459468437790898971,
+                796313366138244251,
+            ]),
+            Fq::from_montgomery_limbs([
+                14167822767056004260,
+                11258131984047333314,
+                16626565513005296990,
+                354545941154974948,
+            ]),
+            Fq::from_montgomery_limbs([
+                16760604350613443083,
+                2970338690184094591,
+                10893317963816177683,
+                799245258980673628,
+            ]),
+            Fq::from_montgomery_limbs([
+                6804282641358229586,
+                6278548800720471972,
+                5872491343482893714,
+                317321678390451190,
+            ]),
+            Fq::from_montgomery_limbs([
+                7941993997666446958,
+                4339060950815170291,
+                16352647424587784099,
+                971369424112898019,
+            ]),
+            Fq::from_montgomery_limbs([
+                11882232935577179217,
+                7399535405899006474,
+                4025627333904924587,
+                1101934665984573186,
+            ]),
+            Fq::from_montgomery_limbs([
+                11576951909982309860,
+                7841335884318520059,
+                11325808893568175121,
+                398615461569743162,
+            ]),
+            Fq::from_montgomery_limbs([
+                15864175317331247072,
+                11461198075579843594,
+                10692582531660749900,
+                1246197189397361462,
+            ]),
+            Fq::from_montgomery_limbs([
+                17294790976608341440,
+                12526501729986153759,
+                16346606517416602849,
+                776510552717534432,
+            ]),
+            Fq::from_montgomery_limbs([
+                5568243889292945851,
+                9177843524267309722,
+                16343821120496858230,
+                214878032646581276,
+            ]),
+            Fq::from_montgomery_limbs([
+                5469294202534860075,
+                12844918371627875283,
+                11584296647827268762,
+                175504645200167941,
+            ]),
+            Fq::from_montgomery_limbs([
+                10725501859289384350,
+                2795128638365560325,
+                1462379901054168408,
+                582467892744590923,
+            ]),
+            Fq::from_montgomery_limbs([
+                13384557334553305549,
+                11597023705828260983,
+                13572539447433751799,
+                957302532256408852,
+            ]),
+            Fq::from_montgomery_limbs([
+                9655790190496983442,
+                15336782454074396684,
+                12903395605875392900,
+                1303565105966230051,
+            ]),
+            Fq::from_montgomery_limbs([
+                12853196457774175341,
+                16099263209921084574,
+                10151119237211663663,
+                929135870804091534,
+            ]),
+            Fq::from_montgomery_limbs([
+                16394529526595206086,
+                8722778670122344417,
+                9510405144247896203,
+                15644062995412917,
+            ]),
+            Fq::from_montgomery_limbs([
+                10408891497153728467,
+                9622040933575324229,
+                3070070548376545051,
+                113596980623291684,
+            ]),
+            Fq::from_montgomery_limbs([
+                2743793582794869164,
+                13666186926506431604,
+                3066494690705556184,
+                5940073456501974,
+            ]),
+            Fq::from_montgomery_limbs([
+                4999702088515126285,
+                14569980806664124181,
+                15091460860033758598,
+                1303323720414068417,
+            ]),
+            Fq::from_montgomery_limbs([
+                10881136323486197281,
+                12623505194005482616,
+                153894486620192780,
+                1016337934658593023,
+            ]),
+            Fq::from_montgomery_limbs([
+                9621154051401990046,
+                14694708270424603108,
+                5604014955971705536,
+                128372340689032083,
+            ]),
+            Fq::from_montgomery_limbs([
+                1019719964064384634,
+                3894574400382791645,
+                18398445911314767113,
+                1227828505729385268,
+            ]),
+            Fq::from_montgomery_limbs([
+                14745690465593743319,
+                13390315513752565175,
+                3942021608567085667,
+                1321840274234745706,
+            ]),
+            Fq::from_montgomery_limbs([
+                3527389334625726954,
+                15234201272717774655,
+                10937762012629038051,
+                1241905566694202968,
+            ]),
+            Fq::from_montgomery_limbs([
+                16864634205242340467,
+                12748920186405090645,
+                7368950893164879048,
+                406353951113150789,
+            ]),
+            Fq::from_montgomery_limbs([
+                14995991332408099437,
+                11725359279075999550,
+                18002739159267644931,
+                777180405450544651,
+            ]),
+            Fq::from_montgomery_limbs([
+                16035640386364839222,
+                9011228649407258684,
+                2537025378995345403,
+                289902250443590053,
+            ]),
+            Fq::from_montgomery_limbs([
+                4516136611631299653,
+                1074418398820246556,
+                17102045862117306051,
+                996933393657542918,
+            ]),
+            Fq::from_montgomery_limbs([
+                7441880023152517769,
+                9140162330178363991,
+                6653697186860464546,
+                680094234637366049,
+            ]),
+            Fq::from_montgomery_limbs([
+                11332260280689557527,
+                7122188471859185684,
+                227454529819498590,
+                585751159290734132,
+            ]),
+            Fq::from_montgomery_limbs([
+                136350083405528352,
+                11316968013463559103,
+                9225626019503440085,
+                424933748648853981,
+            ]),
+            Fq::from_montgomery_limbs([
+                16727377806486412174,
+                2174749158793510212,
+                17784204571821961627,
+                90397442057337507,
+            ]),
+            Fq::from_montgomery_limbs([
+                13611784610305925675,
+                12155848721412264111,
+                7060782366769022357,
+                379593754134014151,
+            ]),
+            Fq::from_montgomery_limbs([
+                9461492731194938243,
+                16717565975736164544,
+                14678964926582936861,
+                348462809230265892,
+            ]),
+            Fq::from_montgomery_limbs([
+                14115749873994028814,
+                2658431735182057364,
+                17149065811511679896,
+                68936310264120504,
+            ]),
+            Fq::from_montgomery_limbs([
+                13713163280670926947,
+                14970477554573364650,
+                8101524377337969679,
+                673413412187522496,
+            ]),
+            Fq::from_montgomery_limbs([
+                2091496309105782793,
+                3253957391360710203,
+                12207214040232187135,
+                785127354244091164,
+            ]),
+            Fq::from_montgomery_limbs([
+                12443630111519569859,
+                15941917468580975809,
+                5354993040083716048,
+                777181780199717468,
+            ]),
+            Fq::from_montgomery_limbs([
+                8301326782279642557,
+                12945007925349740062,
+                15004739348852024967,
+                1032924900245724717,
+            ]),
+            Fq::from_montgomery_limbs([
+                849806194004264414,
+                17234328484968286652,
+                14604758852344506430,
+                615906722837305402,
+            ]),
+            Fq::from_montgomery_limbs([
+                10073491721410033345,
+                6196054079230475126,
+                9035811199525904274,
+                951082554892550507,
+            ]),
+            Fq::from_montgomery_limbs([
+                17952148243617414232,
+                10500508318423739085,
+                1044456011470198493,
+                1047335690735738436,
+            ]),
+            Fq::from_montgomery_limbs([
+                14356068768444834826,
+                18377003896354685316,
+                17446328179020322107,
+                893853128164715118,
+            ]),
+            Fq::from_montgomery_limbs([
+                17741427209507786901,
+                16066433553173642529,
+                15868562054221329570,
+                593165763338707569,
+            ]),
+            Fq::from_montgomery_limbs([
+                4774725473290686608,
+                16778628976952949099,
+                8237255322783971089,
+                1033380707226777954,
+            ]),
+            Fq::from_montgomery_limbs([
+                3509587601692377911,
+                12642332409096369578,
+                4929665714409377215,
+                504343069388227969,
+            ]),
+            Fq::from_montgomery_limbs([
+                1613473504653328974,
+                9940426892901115066,
+                4383502131874569546,
+                272957448409545506,
+            ]),
+            Fq::from_montgomery_limbs([
+                4868659863285735157,
+                12216296838851784566,
+                9913808224423435534,
+                400968324316031710,
+            ]),
+            Fq::from_montgomery_limbs([
+                4503520340463852369,
+                7598757506001146846,
+                18316290310970793952,
+                1326309327133551507,
+            ]),
+            Fq::from_montgomery_limbs([
+                9606242234133269659,
+                6399396619996260884,
+                12858099153348079950,
+                186268255146579375,
+            ]),
+            Fq::from_montgomery_limbs([
+                8929679736759185931,
+                12424870203155220497,
+                847246357136286276,
+                14621835982376869,
+            ]),
+            Fq::from_montgomery_limbs([
+                15064195739354148298,
+                2479943167115134307,
+                5547453269040536485,
+                94936987427003362,
+            ]),
+            Fq::from_montgomery_limbs([
+                8476123860014608716,
+                601315265801442669,
+                17824736684398646485,
+                901428714566919971,
+            ]),
+            Fq::from_montgomery_limbs([
+                1676790365798621605,
+                7487256928386688223,
+                5456637639662322620,
+                723502163350830598,
+            ]),
+            Fq::from_montgomery_limbs([
+                10313496484750594640,
+                14760569325567278109,
+                6763608252976658206,
+                128652171604029998,
+            ]),
+            Fq::from_montgomery_limbs([
+                16815642737189519642,
+                11613228107131832390,
+                909552302496293014,
+                391414223494841936,
+            ]),
+            Fq::from_montgomery_limbs([
+                8821218336967805457,
+                12878518163324995382,
+                1602875946190733625,
+                17782257988334707,
+            ]),
+            Fq::from_montgomery_limbs([
+                1419249681634828376,
+                4173007224377938718,
+                5501286586907477667,
+                811873887834491500,
+            ]),
+            Fq::from_montgomery_limbs([
+                537036819085807279,
+                4972157174739206548,
+                3083132846312982952,
+                300138491292050366,
+            ]),
+            Fq::from_montgomery_limbs([
+                16707648327782998817,
+                600475821107426006,
+                3642504835130915033,
+                105701356620957923,
+            ]),
+            Fq::from_montgomery_limbs([
+                17240301444839445736,
+                7425330849901460805,
+                11124999411933772025,
+                525590507912985537,
+            ]),
+            Fq::from_montgomery_limbs([
+                8140490352455957594,
+                16680423179660221388,
+                2726116303991933299,
+                938846678240672967,
+            ]),
+            Fq::from_montgomery_limbs([
+                12298208882864007762,
+                7058813516147618797,
+                10355847668174019434,
+                293560557391053204,
+            ]),
+            Fq::from_montgomery_limbs([
+                13939085838844670736,
+                16626135939378426524,
+                15873974407564136319,
+                1071489197495528767,
+            ]),
+            Fq::from_montgomery_limbs([
+                3278805177871324497,
+                8332607298945875552,
+                11113537445152477433,
+                756085704616066170,
+            ]),
+            Fq::from_montgomery_limbs([
+                16071940223979555114,
+                10679941763636510764,
+                12308188291801849500,
+                1233416719058168572,
+            ]),
+            Fq::from_montgomery_limbs([
+                13126437414677789047,
+                8631220605093348735,
+                14057708115875960491,
+                1242561238708294496,
+            ]),
+            Fq::from_montgomery_limbs([
+                7416034501427608783,
+                588617813639269683,
+                6494134261986769717,
+                761268500453461724,
+            ]),
+            Fq::from_montgomery_limbs([
+                15281443214057838880,
+                16646640786932380670,
+                5039625900792325911,
+                826871737341386485,
+            ]),
+            Fq::from_montgomery_limbs([
+                4532145067800554037,
+                12995391210429141678,
+                15984462194737456605,
+                378061318143600992,
+            ]),
+            Fq::from_montgomery_limbs([
+                3606633374000114175,
+                4966252798243792989,
+                18356405841841303328,
+                161087389398388437,
+            ]),
+            Fq::from_montgomery_limbs([
+                8100893038367822366,
+                16195237125006533927,
+                17922833459937454178,
+                1149342412601738222,
+            ]),
+            Fq::from_montgomery_limbs([
+                6230127569073102227,
+                8925226680568055741,
+                6195141242128505120,
+                851305858581729714,
+            ]),
+            Fq::from_montgomery_limbs([
+                7489212472629282257,
+                16479916044100103359,
+                15006968181492630967,
+                1281443890478660199,
+            ]),
+            Fq::from_montgomery_limbs([
+                16780639604834410518,
+                12582324487085362494,
+                10857365927625366456,
+                161925318623554959,
+            ]),
+            Fq::from_montgomery_limbs([
+                17603377683552323132,
+                12334576132106060814,
+                18232470071177023780,
+                1092183497928667035,
+            ]),
+            Fq::from_montgomery_limbs([
+                8981696932771344782,
+                8089793628569610840,
+                694749197461950263,
+                1110758958362988899,
+            ]),
+            Fq::from_montgomery_limbs([
+                9713463448908423696,
+                6955723588922054061,
+                11749255631081302239,
+                298310303893780535,
+            ]),
+            Fq::from_montgomery_limbs([
+                10564845149666832916,
+                17761932014649900479,
+                17801435604388762689,
+                622088521413523792,
+            ]),
+            Fq::from_montgomery_limbs([
+                17253286934675169507,
+                7832051641053986138,
+                490025937212604160,
+                670769486707083092,
+            ]),
+            Fq::from_montgomery_limbs([
+                9773203376886050133,
+                6550570488526586810,
+                16681220685128529214,
+                44371186749061483,
+            ]),
+            Fq::from_montgomery_limbs([
+                12901155513940012558,
+                2965511712632378012,
+                1430276363878854207,
+                1132505265528012995,
+            ]),
+            Fq::from_montgomery_limbs([
+                10724827595985156214,
+                16294380051084004034,
+                17161692064654740866,
+                695713617202111346,
+            ]),
+            Fq::from_montgomery_limbs([
+                17490898785728817029,
+                4745413777575170873,
+                10778769528096861803,
+                448296549821813980,
+            ]),
+            Fq::from_montgomery_limbs([
+                9421015320010234313,
+                9475908238430681398,
+                1539344060699832717,
+                817023297286235450,
+            ]),
+            Fq::from_montgomery_limbs([
+                12223459295901073290,
+                2578697223364220619,
+                134336815477574763,
+                855355236015057010,
+            ]),
+            Fq::from_montgomery_limbs([
+                13182566346137170978,
+                14081660588852231727,
+                7200356302698815861,
+                315438750954454810,
+            ]),
+            Fq::from_montgomery_limbs([
+                2555726086165710707,
+                3639980474439948091,
+                75601964736665960,
+                20844177446901053,
+            ]),
+            Fq::from_montgomery_limbs([
+                16587101105136411799,
+                3526212531699643360,
+                10359883842667700895,
+                921724129048038197,
+            ]),
+            Fq::from_montgomery_limbs([
+                9156301047111149401,
+                15841913151907842277,
+                9500417131233322451,
+                170725140922732921,
+            ]),
+            Fq::from_montgomery_limbs([
+                4431871873290190528,
+                8145879755073264179,
+                16969822416282330153,
+                1155636404426473237,
+            ]),
+            Fq::from_montgomery_limbs([
+                16378924553868252717,
+                14016329620366047766,
+                9920278387864419259,
+                368356226956337943,
+            ]),
+            Fq::from_montgomery_limbs([
+                1126011769366298253,
+                3375120480160848453,
+                3565494077805847192,
+                1154951401506086214,
+            ]),
+            Fq::from_montgomery_limbs([
+                14469560136296007015,
+                14695570508708188401,
+                15127552438718402384,
+                287988081048158541,
+            ]),
+            Fq::from_montgomery_limbs([
+                14140062946269006666,
+                5788161140641351192,
+                701950365672988339,
+                963236008554931821,
+            ]),
+            Fq::from_montgomery_limbs([
+                2598631630421154895,
+                9152222756722826794,
+                2097422965326233821,
+                979231439354269453,
+            ]),
+            Fq::from_montgomery_limbs([
+                4816775701007187599,
+                17423875029970448442,
+                566160106869215278,
+                1037893159429338998,
+            ]),
+            Fq::from_montgomery_limbs([
+                16463970888215111736,
+                11288100557282171294,
+                16716894563073874462,
+                443969571563798424,
+            ]),
+            Fq::from_montgomery_limbs([
+                8276268299906329835,
+                2504272607008340298,
+                2293143091131607089,
+                529294880882961321,
+            ]),
+            Fq::from_montgomery_limbs([
+                14846269706551912926,
+                16190363591168548162,
+                14371521504841603278,
+                233482012180770057,
+            ]),
+            Fq::from_montgomery_limbs([
+                519062297336841380,
+                16361137173291280875,
+                15715491917217610545,
+                434457324968226212,
+            ]),
+            Fq::from_montgomery_limbs([
+                12373108313781360484,
+                6315039624397449449,
+                1548455857913292212,
+                742028353705345440,
+            ]),
+            Fq::from_montgomery_limbs([
+                9426176083047505932,
+                3824966066405041971,
+                10569082868517634224,
+                971222449027559661,
+            ]),
+            Fq::from_montgomery_limbs([
+                7919554338140995630,
+                8274311077135858814,
+                12821619153126920389,
+                1265356741000174383,
+            ]),
+            Fq::from_montgomery_limbs([
+                16388511258125091375,
+                11090761393322642867,
+                3959285975481828880,
+                4859655195124359,
+            ]),
+            Fq::from_montgomery_limbs([
+                13912888705951106475,
+                446627209395037124,
+                18146152450414906573,
+                859097141224379155,
+            ]),
+            Fq::from_montgomery_limbs([
+                103888352869524003,
+                15018634938524261193,
+                10408544702779341058,
+                74171452383821001,
+            ]),
+            Fq::from_montgomery_limbs([
+                4206605640394611834,
+                11765854929698056748,
+                12519678632929447945,
+                66914177162483143,
+            ]),
+            Fq::from_montgomery_limbs([
+                12549796932710153698,
+                5680849656757630528,
+                17317820572176593157,
+                1228702878047866413,
+            ]),
+            Fq::from_montgomery_limbs([
+                6475448943317674226,
+                14585550119705771698,
+                2720073422573467599,
+                1112217861982270562,
+            ]),
+            Fq::from_montgomery_limbs([
+                17340583800094655511,
+                5546440964723326473,
+                11534691056045722475,
+                340074510118036582,
+            ]),
+            Fq::from_montgomery_limbs([
+                10262216203004957657,
+                10170006607036230202,
+                7785723973370427088,
+                383925344597404106,
+            ]),
+            Fq::from_montgomery_limbs([
+                2116091386928420953,
+                3680869372949523795,
+                15661621528103951494,
+                205710313897194775,
+            ]),
+            Fq::from_montgomery_limbs([
+                5600407623731663365,
+                10641173102445213972,
+                11562701580746484532,
+                134390454871409519,
+            ]),
+            Fq::from_montgomery_limbs([
+                5364932017187399312,
+                2748984655270673394,
+                12157733167237461865,
+                296164965599648844,
+            ]),
+            Fq::from_montgomery_limbs([
+                11208590650963242951,
+                7587637481126399194,
+                10007583013712490597,
+                20354999091962653,
+            ]),
+            Fq::from_montgomery_limbs([
+                9760702064678183462,
+                14925183643576656237,
+                1324439062554681467,
+                795215598234183868,
+            ]),
+            Fq::from_montgomery_limbs([
+                11057188487828653876,
+                7543737115149660510,
+                1742623337964071338,
+                1079192247206980813,
+            ]),
+            Fq::from_montgomery_limbs([
+                6696951806576677577,
+                6668100869821338291,
+                744590870632448867,
+                833260502926169226,
+            ]),
+            Fq::from_montgomery_limbs([
+                8715699659556588756,
+                453784076950680639,
+                5974650171522235760,
+                517105813814099715,
+            ]),
+            Fq::from_montgomery_limbs([
+                14356795176406459018,
+                9004168681255863671,
+                196527488459648884,
+                727000108326904061,
+            ]),
+            Fq::from_montgomery_limbs([
+                4256522509221581611,
+                987565292294903218,
+                11086440834313899869,
+                61354780553353738,
+            ]),
+            Fq::from_montgomery_limbs([
+                7180965771012629507,
+                3934944627083618715,
+                7044210365621271225,
+                711860824454266333,
+            ]),
+            Fq::from_montgomery_limbs([
+                5048757463922600282,
+                379098944173394623,
+                3037391959277038438,
+                404527399606165178,
+            ]),
+            Fq::from_montgomery_limbs([
+                13381656686917470817,
+                9460627767330335590,
+                15013493379619446795,
+                222271143133377704,
+            ]),
+            Fq::from_montgomery_limbs([
+                14014427337423622956,
+                12957348383759665314,
+                6352701429339700922,
+                996912087292679724,
+            ]),
+            Fq::from_montgomery_limbs([
+                14355308704067321060,
+                17882472798430432854,
+                16570355916139545856,
+                1159330956171990773,
+            ]),
+            Fq::from_montgomery_limbs([
+                8064652567673126845,
+                10867750694790037368,
+                6070180889140905253,
+                1295073450443792623,
+            ]),
+            Fq::from_montgomery_limbs([
+                12186843935206200599,
+                9675431911667582751,
+                13108833288347983854,
+                544601093223673411,
+            ]),
+            Fq::from_montgomery_limbs([
+                4224785597694794547,
+                13355709919231569288,
+                17680983593787459693,
+                1245301065670228793,
+            ]),
+            Fq::from_montgomery_limbs([
+                16722076495435475384,
+                10068878879529763054,
+                1904179713228746039,
+                847129007849709907,
+            ]),
+            Fq::from_montgomery_limbs([
+                504735720341109443,
+                16922669699923985212,
+                10821230274795264903,
+                1327214685187441168,
+            ]),
+            Fq::from_montgomery_limbs([
+                14612753290449599607,
+                3919686550003276167,
+                12201376504298659582,
+                781149654508581546,
+            ]),
+            Fq::from_montgomery_limbs([
+                2394325799137959029,
+                7711870883709185564,
+                16026873079856872694,
+                90573762876779107,
+            ]),
+            Fq::from_montgomery_limbs([
+                11404961712021902574,
+                5046063349820372733,
+                15225722913330804363,
+                805647619932538473,
+            ]),
+            Fq::from_montgomery_limbs([
+                1858128029949488887,
+                10703285788684359807,
+                2286087852778180428,
+                787440375315206043,
+            ]),
+            Fq::from_montgomery_limbs([
+                8092807900270486988,
+                13737924711179702692,
+                6497967524643449863,
+                270293921249879499,
+            ]),
+            Fq::from_montgomery_limbs([
+                264711284388767640,
+                10738717561192598954,
+                15395335230440776862,
+                418073078824648042,
+            ]),
+            Fq::from_montgomery_limbs([
+                695875371780026097,
+                5698253144231703736,
+                12995029493104228333,
+                328255658234225020,
+            ]),
+            Fq::from_montgomery_limbs([
+                9137052295214462101,
+                12972448261448424503,
+                5919462491183299194,
+                72315748643624943,
+            ]),
+            Fq::from_montgomery_limbs([
+                5786484804600379332,
+                2701936477323339663,
+                1008433163818185454,
+                1210619191074711570,
+            ]),
+            Fq::from_montgomery_limbs([
+                4709784008939373107,
+                15601946578799663977,
+                10364397925670890320,
+                99769029773856335,
+            ]),
+            Fq::from_montgomery_limbs([
+                8854490576083207357,
+                10226420272381324069,
+                9387498309815317340,
+                387466260461458098,
+            ]),
+            Fq::from_montgomery_limbs([
+                11568535820160246612,
+                10236895217412565105,
+                8426481841191554691,
+                1237099699449952309,
+            ]),
+            Fq::from_montgomery_limbs([
+                2887559495930361011,
+                10653490952068535201,
+                6831056371645312164,
+                480317707978873476,
+            ]),
+            Fq::from_montgomery_limbs([
+                9222181359677044028,
+                13909267904887368757,
+                12294108291078082995,
+                825262092828244624,
+            ]),
+            Fq::from_montgomery_limbs([
+                7567371263142596744,
+                1141856910252505838,
+                5507022087354435345,
+                275483821959346165,
+            ]),
+            Fq::from_montgomery_limbs([
+                5297426578403986310,
+                16984957561107641385,
+                1228648001985029572,
+                827799513101498563,
+            ]),
+            Fq::from_montgomery_limbs([
+                5724818919447846387,
+                18365847938692754142,
+                11538788404650398087,
+                1100346738445792863,
+            ]),
+            Fq::from_montgomery_limbs([
+                15063757031249085102,
+                15065032908958487931,
+                9378040013123821584,
+                347987056547424551,
+            ]),
+            Fq::from_montgomery_limbs([
+                5438503175541289052,
+                1411469220561604610,
+                8372505117229346270,
+                580083750061601552,
+            ]),
+            Fq::from_montgomery_limbs([
+                10397028443104167111,
+                6364863769593406730,
+                16600838714983179978,
+                272401395703507950,
+            ]),
+            Fq::from_montgomery_limbs([
+                6392285305740140226,
+                18421515811612730228,
+                17529215335613158724,
+                1222840527885688277,
+            ]),
+            Fq::from_montgomery_limbs([
+                1268568466328948731,
+                14729622775019831371,
+                18288646577013815664,
+                499008566403395255,
+            ]),
+            Fq::from_montgomery_limbs([
+                17433636873291373729,
+                15818585389515974615,
+                16177834525121793961,
+                364570065937750837,
+            ]),
+            Fq::from_montgomery_limbs([
+                15517091457158810074,
+                1888637309863996533,
+                7559529247374042168,
+                894922529221591484,
+            ]),
+            Fq::from_montgomery_limbs([
+                6962940425215644034,
+                16118617295618785918,
+                2775463362641404533,
+                1140224832964771630,
+            ]),
+            Fq::from_montgomery_limbs([
+                12178406934959583012,
+                8555143076378538202,
+                1723662574298529788,
+                41219128368949871,
+            ]),
+            Fq::from_montgomery_limbs([
+                15555505367983633534,
+                16953718966866862935,
+                5349965255924409771,
+                756616904449209453,
+            ]),
+            Fq::from_montgomery_limbs([
+                16045694195436551425,
+                12407944962607622085,
+                12464230004310418366,
+                1069946859737486387,
+            ]),
+            Fq::from_montgomery_limbs([
+                2095975393398299354,
+                8946316158297891927,
+                6579673542748359265,
+                419786122510291443,
+            ]),
+            Fq::from_montgomery_limbs([
+                10431047176044720338,
+                183517847500465339,
+                9299668861250532734,
+                606790554105512447,
+            ]),
+            Fq::from_montgomery_limbs([
+                9872319898122281856,
+                3741032777502817815,
+                10487447012273097362,
+                1172316689426118099,
+            ]),
+            Fq::from_montgomery_limbs([
+                16919480949233595192,
+                3565411218669878124,
+                8392447261603721045,
+                705069115895044344,
+            ]),
+            Fq::from_montgomery_limbs([
+                15952006585469692493,
+                6658347747290021261,
+                217585844561287390,
+                708250153002207226,
+            ]),
+            Fq::from_montgomery_limbs([
+                9084321792322477847,
+                6892536799996718066,
+                17304443733826351590,
+                822684574770776696,
+            ]),
+            Fq::from_montgomery_limbs([
+                14143243371556429244,
+                217230308393986907,
+                1145218019110202362,
+                530504500415873108,
+            ]),
+            Fq::from_montgomery_limbs([
+                14588837226073304207,
+                5673445938905832671,
+                10480587221786449692,
+                1139805008225519418,
+            ]),
+            Fq::from_montgomery_limbs([
+                8414213079993970440,
+                5955306801990285935,
+                3713792644072464594,
+                462590739459616035,
+            ]),
+            Fq::from_montgomery_limbs([
+                11187018553364008705,
+                17667357176277672112,
+                706504377678508035,
+                436172966244541422,
+            ]),
+            Fq::from_montgomery_limbs([
+                6560568067060376716,
+                4993940873915206248,
+                12196571755990782559,
+                688150539120461977,
+            ]),
+            Fq::from_montgomery_limbs([
+                3274348397604298330,
+                13878177139764051703,
+                7204944945128467851,
+                484373666577730495,
+            ]),
+            Fq::from_montgomery_limbs([
+                7649046964055698734,
+                8529333576756200784,
+                10186916267735585576,
+                515155177268871242,
+            ]),
+            Fq::from_montgomery_limbs([
+                14068148475825813682,
+                15095101751886565037,
+                7267873561671182661,
+                699302382786911786,
+            ]),
+            Fq::from_montgomery_limbs([
+                14394114179692352316,
+                6790796777825706343,
+                4588313495211415189,
+                511985542837416309,
+            ]),
+            Fq::from_montgomery_limbs([
+                3919034046286936355,
+                7756690098468969831,
+                7518418574956358481,
+                1294876793960891386,
+            ]),
+            Fq::from_montgomery_limbs([
+                9431341862060391858,
+                271557132779016960,
+                4985382110500411685,
+                507392439751119010,
+            ]),
+            Fq::from_montgomery_limbs([
+                15749213497277392318,
+                15586332937224212921,
+                228306002887056088,
+                636351135005692662,
+            ]),
+            Fq::from_montgomery_limbs([
+                15283694872976032428,
+                7852235826628222634,
+                15554132904879014090,
+                280816883402958018,
+            ]),
+            Fq::from_montgomery_limbs([
+                9912985181432281636,
+                6826791514708378374,
+                10429873501143899950,
+                531761094655787618,
+            ]),
+            Fq::from_montgomery_limbs([
+                11570690161073697828,
+                2198250944745973492,
+                14302901078372960187,
+                199511457674807391,
+            ]),
+            Fq::from_montgomery_limbs([
+                14503144026806996192,
+                15061934584889486133,
+                11267283156511419157,
+                1198267844869404668,
+            ]),
+            Fq::from_montgomery_limbs([
+                16219049339217238152,
+                1159538328822590899,
+                9188328264901543423,
+                242014783751079354,
+            ]),
+            Fq::from_montgomery_limbs([
+                3068471243423001434,
+                16729115760597287027,
+                907449302547108903,
+                1155055345469977774,
+            ]),
+            Fq::from_montgomery_limbs([
+                10147601049708987209,
+                17671913561070855783,
+                4405411727212498953,
+                1274435109865438989,
+            ]),
+            Fq::from_montgomery_limbs([
+                14489123139953437768,
+                189389845644276954,
+                17218569801494386598,
+                1248671205022506827,
+            ]),
+            Fq::from_montgomery_limbs([
+                4332250961607554282,
+                17190761872922943383,
+                7170785664232045126,
+                213975028422119474,
+            ]),
+            Fq::from_montgomery_limbs([
+                4385440632808617492,
+                4707822129041431096,
+                6253081993586639329,
+                344617052575229462,
+            ]),
+            Fq::from_montgomery_limbs([
+                6081991679833460527,
+                14198708256577430617,
+                3059058584210582709,
+                885126191177222505,
+            ]),
+            Fq::from_montgomery_limbs([
+                12798783178729881124,
+                17733672638402075075,
+                13043695527068536132,
+                1065727432264238065,
+            ]),
+            Fq::from_montgomery_limbs([
+                10164751313987849634,
+                7262524311295805546,
+                6112041633909363408,
+                257170384515292415,
+            ]),
+            Fq::from_montgomery_limbs([
+                13166129394958796739,
+                4623313160100230500,
+                17287868462188913537,
+                1133205541176779864,
+            ]),
+            Fq::from_montgomery_limbs([
+                14265469979411576950,
+                8758975867845540394,
+                10419435205136129269,
+                665498787909267937,
+            ]),
+            Fq::from_montgomery_limbs([
+                7306938056003694938,
+                1290178659119492702,
+                15843745520684029504,
+                487734387511614364,
+            ]),
+            Fq::from_montgomery_limbs([
+                8083521323210987597,
+                6918808411138074362,
+                13705647955896594768,
+                1274666619432959467,
+            ]),
+            Fq::from_montgomery_limbs([
+                6274007398474632651,
+                3250021978538727125,
+                12324128658516293570,
+                457152100303201623,
+            ]),
+            Fq::from_montgomery_limbs([
+                18262372638454824192,
+                15561792233751697071,
+                7423735278245259256,
+                684543494010992699,
+            ]),
+            Fq::from_montgomery_limbs([
+                6271721956974247451,
+                18411101479935070461,
+                8233744565678859974,
+                983076073124364241,
+            ]),
+            Fq::from_montgomery_limbs([
+                2929239052694721031,
+                12771994428528427405,
+                7251541834151225267,
+                245123277952460346,
+            ]),
+            Fq::from_montgomery_limbs([
+                16086081587881132928,
+                16692540103402978521,
+                6895138294140276068,
+                155578329705340873,
+            ]),
+            Fq::from_montgomery_limbs([
+                73990973173703193,
+                5041747073794761424,
+                5899635756429414793,
+                1226721776804637306,
+            ]),
+            Fq::from_montgomery_limbs([
+                15874270968700603721,
+                5569305613212276720,
+                7756158351714248227,
+                21405520893186838,
+            ]),
+            Fq::from_montgomery_limbs([
+                1902886041557208676,
+                18314493175561717948,
+                12636383392066652710,
+                427433422768391010,
+            ]),
+            Fq::from_montgomery_limbs([
+                15520205131271957319,
+                3705280757232862549,
+                10459439366131470195,
+                1161167714971130724,
+            ]),
+            Fq::from_montgomery_limbs([
+                6687884049958822330,
+                8030962624055964042,
+                18408665000581911439,
+                1110731041029294344,
+            ]),
+            Fq::from_montgomery_limbs([
+                9603754894531429169,
+                320936654816989201,
+                7940081242472366913,
+                898840760702134762,
+            ]),
+            Fq::from_montgomery_limbs([
+                7207717638281702435,
+                16673758338603079556,
+                7734454798430320880,
+                294338540701329633,
+            ]),
+            Fq::from_montgomery_limbs([
+                16042800715025825332,
+                6182068970098893542,
+                10159816253841595245,
+                1344273441594535521,
+            ]),
+            Fq::from_montgomery_limbs([
+                16944586514940591976,
+                13964247884190511495,
+                18112238843837966303,
+                499028431043824748,
+            ]),
+            Fq::from_montgomery_limbs([
+                3830662912868872772,
+                630151623799003746,
+                15496400952664395231,
+                744144975384078641,
+            ]),
+            Fq::from_montgomery_limbs([
+                12127298451345343947,
+                17246050400589337610,
+                4262144905602793410,
+                367398391743376774,
+            ]),
+            Fq::from_montgomery_limbs([
+                13974862355834209602,
+                5577314568359776425,
+                17458662647507386025,
+                1200863005101320860,
+            ]),
+            Fq::from_montgomery_limbs([
+                418734858608328706,
+                18219058702507342336,
+                7651658261860762485,
+                250208912242133940,
+            ]),
+            Fq::from_montgomery_limbs([
+                10132873732854475987,
+                12770761974656951797,
+                7131566628423686301,
+                910377048878075166,
+            ]),
+            Fq::from_montgomery_limbs([
+                6933293163365968206,
+                3301878245461084303,
+                2958212340015678476,
+                110800485038389055,
+            ]),
+            Fq::from_montgomery_limbs([
+                12241083411077550703,
+                9516849427743718519,
+                1056295747977837213,
+                930451362315768346,
+            ]),
+            Fq::from_montgomery_limbs([
+                8962159427843569949,
+                10850763423615606031,
+                10199667584534162701,
+                633138949817068370,
+            ]),
+            Fq::from_montgomery_limbs([
+                8082048798836334026,
+                12274580638296388275,
+                9652648969436893179,
+                71715729554283799,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277888797189949666,
+                7694661268832230607,
+                11890390687870579460,
+                932777783914522416,
+            ]),
+            Fq::from_montgomery_limbs([
+                736563658148227399,
+                13654196684476303634,
+                7958139183725831662,
+                959875075665941776,
+            ]),
+            Fq::from_montgomery_limbs([
+                4498827524310690217,
+                6527986835273038353,
+                91385542336902191,
+                1252535238695953145,
+            ]),
+            Fq::from_montgomery_limbs([
+                1979061823580427908,
+                14810688706792682114,
+                15493615410689248,
+                1311722759459085718,
+            ]),
+            Fq::from_montgomery_limbs([
+                10451170006932809898,
+                2808229443598107445,
+                15453556576447737188,
+                1142359244506908070,
+            ]),
+            Fq::from_montgomery_limbs([
+                17206112243071061898,
+                1737173955071247789,
+                10563998451034757898,
+                1079978936065519309,
+            ]),
+            Fq::from_montgomery_limbs([
+                5190373748032966534,
+                10719731506795248407,
+                3568260585747588999,
+                402971651583226582,
+            ]),
+            Fq::from_montgomery_limbs([
+                2264302895506662469,
+                4980639616805793611,
+                4408947760703632916,
+                185030908572818318,
+            ]),
+            Fq::from_montgomery_limbs([
+                217138798366820406,
+                17409458071379368743,
+                13771685118357116445,
+                159561952377508009,
+            ]),
+            Fq::from_montgomery_limbs([
+                17232412218577953056,
+                7899598022307552352,
+                2882639464522429632,
+                1084812642193595285,
+            ]),
+            Fq::from_montgomery_limbs([
+                9431561006921478562,
+                1931828376970706176,
+                2033522021246370430,
+                826400684362148415,
+            ]),
+            Fq::from_montgomery_limbs([
+                18398379262964040836,
+                7496682100228498226,
+                4528841707874034736,
+                256705119964683134,
+            ]),
+            Fq::from_montgomery_limbs([
+                9279560600910520457,
+                16395160101884786587,
+                8485407109829515363,
+                252930713030462127,
+            ]),
+            Fq::from_montgomery_limbs([
+                781189353060382803,
+                4937944094220522475,
+                12539265696491791606,
+                168833448361874329,
+            ]),
+            Fq::from_montgomery_limbs([
+                16312770453191620401,
+                18319387978052416556,
+                7435626007492378170,
+                733574324369228596,
+            ]),
+            Fq::from_montgomery_limbs([
+                4971956789560378930,
+                13426604194695584796,
+                2776882719446254924,
+                717088858074126001,
+            ]),
+            Fq::from_montgomery_limbs([
+                14055951689962617940,
+                6377097286992639892,
+                3366642403076488175,
+                1345170308696848972,
+            ]),
+            Fq::from_montgomery_limbs([
+                2792092430561397631,
+                16543305639862101998,
+                5080780069155452846,
+                7548673818879683,
+            ]),
+            Fq::from_montgomery_limbs([
+                2258104775496481391,
+                4603775633556530344,
+                8539664910120292799,
+                280126231380614426,
+            ]),
+            Fq::from_montgomery_limbs([
+                12808656614707417227,
+                15302479858128436175,
+                18015247411080313464,
+                14794102559244269,
+            ]),
+            Fq::from_montgomery_limbs([
+                9587944645756478621,
+                2494558677522830745,
+                14169131069113656939,
+                484979487702976539,
+            ]),
+            Fq::from_montgomery_limbs([
+                17827807333898075241,
+                16670034583744985303,
+                8367834186672064458,
+                739779733588715635,
+            ]),
+            Fq::from_montgomery_limbs([
+                10578129259477127635,
+                3517543303552763231,
+                9040743057756480151,
+                1031773211811749668,
+            ]),
+            Fq::from_montgomery_limbs([
+                14371969935968570226,
+                7708019464094051762,
+                7774907067259622107,
+                2625880833521697,
+            ]),
+            Fq::from_montgomery_limbs([
+                14995766723829528804,
+                10560315654971341350,
+                17164977776627539587,
+                1034112193294865271,
+            ]),
+            Fq::from_montgomery_limbs([
+                12183333885265529097,
+                8367103985337563864,
+                17122289903539115769,
+                655884892056356460,
+            ]),
+            Fq::from_montgomery_limbs([
+                2672374535985305015,
+                5372080517762167546,
+                5785170103725520056,
+                1297765271363168145,
+            ]),
+            Fq::from_montgomery_limbs([
+                16430682200761399920,
+                3556794931422147895,
+                1270236532540805371,
+                556931248470163639,
+            ]),
+            Fq::from_montgomery_limbs([
+                12538564233556398858,
+                12942338537126392450,
+                11284805296522534262,
+                299472510807103215,
+            ]),
+            Fq::from_montgomery_limbs([
+                13560314157174353449,
+                12122247369387821421,
+                6803511454794873019,
+                427529588716115512,
+            ]),
+            Fq::from_montgomery_limbs([
+                11805179142953156069,
+                6399036527689445946,
+                8044740597623414281,
+                396748408604900183,
+            ]),
+            Fq::from_montgomery_limbs([
+                15073414675992816429,
+                13294323807399388168,
+                8220247898088557631,
+                1342597770922256153,
+            ]),
+            Fq::from_montgomery_limbs([
+                17585358945375543515,
+                13550439700661451017,
+                6438841448288831637,
+                1192140685426143005,
+            ]),
+            Fq::from_montgomery_limbs([
+                16727551852938912312,
+                10081704548695700108,
+                10140521550936630062,
+                1285261923841855905,
+            ]),
+            Fq::from_montgomery_limbs([
+                18128064351592325046,
+                18302578598843981661,
+                14737284305694489066,
+                746716543647584122,
+            ]),
+            Fq::from_montgomery_limbs([
+                11788075791170374922,
+                5312823655234992738,
+                6420079313575103741,
+                467050937959348757,
+            ]),
+            Fq::from_montgomery_limbs([
+                6811754107604800164,
+                4902584417860041709,
+                17513253749159268242,
+                945326144956407531,
+            ]),
+            Fq::from_montgomery_limbs([
+                16339128752579399793,
+                15743114651378623782,
+                9938361762756931059,
+                1201133728216519772,
+            ]),
+            Fq::from_montgomery_limbs([
+                248453718305866572,
+                31784253311974781,
+                4372898684670036140,
+                772814377688667373,
+            ]),
+            Fq::from_montgomery_limbs([
+                5280144960381031783,
+                4512442413012222159,
+                9168680170002859104,
+                737204238617274273,
+            ]),
+            Fq::from_montgomery_limbs([
+                989493535090478531,
+                14273802882010966663,
+                2450277050344611744,
+                1239430648285093704,
+            ]),
+            Fq::from_montgomery_limbs([
+                17784095080735681269,
+                15455511684313886789,
+                1337198390000315390,
+                440413483087221121,
+            ]),
+            Fq::from_montgomery_limbs([
+                13632199069285484790,
+                3593989791365830482,
+                366592772990345595,
+                45462324961757669,
+            ]),
+            Fq::from_montgomery_limbs([
+                461346659801058145,
+                12299204749663964449,
+                10571358743801094103,
+                1259155587569891444,
+            ]),
+            Fq::from_montgomery_limbs([
+                4501437345478296421,
+                2044942362661262130,
+                15432589507958069404,
+                1329366114227702042,
+            ]),
+            Fq::from_montgomery_limbs([
+                9237159180894761576,
+                8696092545272269389,
+                1234020173508507445,
+                814585173961119444,
+            ]),
+            Fq::from_montgomery_limbs([
+                441433508433244174,
+                14095488603424629785,
+                13425184449475523419,
+                529971583926504688,
+            ]),
+            Fq::from_montgomery_limbs([
+                12374208404907269395,
+                3076546982373386957,
+                15050398730660996413,
+                749152269046532806,
+            ]),
+            Fq::from_montgomery_limbs([
+                3333107261662057766,
+                3614003310331274951,
+                9255472375930455363,
+                396868680887923358,
+            ]),
+            Fq::from_montgomery_limbs([
+                9470749297289651137,
+                14093936571445764979,
+                5270508205660066751,
+                1209170183918237191,
+            ]),
+            Fq::from_montgomery_limbs([
+                13943108851308078179,
+                4069996187241231164,
+                3781496975289044282,
+                1170362902322155012,
+            ]),
+            Fq::from_montgomery_limbs([
+                10663701126292842727,
+                3706542183740741205,
+                15490812432200964950,
+                534174404788034342,
+            ]),
+            Fq::from_montgomery_limbs([
+                5879767997277667736,
+                12429241324044924458,
+                13723331931914644903,
+                280700425380006715,
+            ]),
+            Fq::from_montgomery_limbs([
+                14651019097721855284,
+                10251369094412130480,
+                16546481290243040573,
+                964177766754477235,
+            ]),
+            Fq::from_montgomery_limbs([
+                2130888442243703993,
+                9807028707115159828,
+                17037116168074609235,
+                679880293737168116,
+            ]),
+            Fq::from_montgomery_limbs([
+                15853153724459811397,
+                8101752554421407855,
+                5334239680025881224,
+                29185830793864792,
+            ]),
+            Fq::from_montgomery_limbs([
+                16057452489998994037,
+                1170330847777057273,
+                15730870543190747621,
+                1216033595942153352,
+            ]),
+            Fq::from_montgomery_limbs([
+                13024325567358348069,
+                14208963720698653262,
+                17489034105806762956,
+                387760103550458195,
+            ]),
+            Fq::from_montgomery_limbs([
+                4100673772319776001,
+                15413527205933890507,
+                9511721716070322077,
+                848841777356973044,
+            ]),
+            Fq::from_montgomery_limbs([
+                814496973412023559,
+                3002949970482165046,
+                17518724988449365326,
+                767952569430909059,
+            ]),
+            Fq::from_montgomery_limbs([
+                17571719715836713159,
+                944209301506444507,
+                15131371108992604527,
+                826604756095023774,
+            ]),
+            Fq::from_montgomery_limbs([
+                196694480093040377,
+                6305518013400865748,
+                1086871710598756775,
+                538583647615702344,
+            ]),
+            Fq::from_montgomery_limbs([
+                11910106095082011629,
+                3238969806711343208,
+                1205407389481318253,
+                598806266220175416,
+            ]),
+            Fq::from_montgomery_limbs([
+                273347842439951577,
+                15770461441766871009,
+                17219548413409456877,
+                402435642225818949,
+            ]),
+            Fq::from_montgomery_limbs([
+                11960635462895915050,
+                15189331602079184950,
+                5441175304617507476,
+                933262401574934566,
+            ]),
+            Fq::from_montgomery_limbs([
+                3239750921507548654,
+                17581902534465146160,
+                5458741253321881492,
+                1110726730686123681,
+            ]),
+            Fq::from_montgomery_limbs([
+                1849262107939463934,
+                9186838152458124716,
+                15766431507190593334,
+                883957802565607192,
+            ]),
+            Fq::from_montgomery_limbs([
+                10526391037718011027,
+                5664732540549514973,
+                6297113794191810365,
+                62804752218051881,
+            ]),
+            Fq::from_montgomery_limbs([
+                1264519401140314767,
+                13888884529285287177,
+                13508053813924350188,
+                346510119929924117,
+            ]),
+            Fq::from_montgomery_limbs([
+                15013311078067560612,
+                12802929118227570647,
+                11687732368420716266,
+                1057390891345156299,
+            ]),
+            Fq::from_montgomery_limbs([
+                6837105990167656922,
+                16162971861431226828,
+                13759520441160678587,
+                942036573753095265,
+            ]),
+            Fq::from_montgomery_limbs([
+                10986429524396277367,
+                16230136511892175701,
+                713840161663141608,
+                322125132137501911,
+            ]),
+            Fq::from_montgomery_limbs([
+                6012252108816544464,
+                1269076899197735004,
+                11610642502755079764,
+                162223085581222806,
+            ]),
+            Fq::from_montgomery_limbs([
+                3801365085619664772,
+                550520479644030765,
+                2980550245380449858,
+                1200686160622109325,
+            ]),
+            Fq::from_montgomery_limbs([
+                12896073455052423046,
+                17571907838524055888,
+                9861731271233081517,
+                1274389077713442270,
+            ]),
+            Fq::from_montgomery_limbs([
+                2332794307037862381,
+                1666716501283686191,
+                11298248760082246116,
+                75124197550002942,
+            ]),
+            Fq::from_montgomery_limbs([
+                2039464653824697950,
+                5395333632369863663,
+                4043535444109530135,
+                873471466869884302,
+            ]),
+            Fq::from_montgomery_limbs([
+                16320752100947883458,
+                700830901673369396,
+                11352385255263757776,
+                1224006094845002091,
+            ]),
+            Fq::from_montgomery_limbs([
+                12483078157249363468,
+                12691535152277239521,
+                3130522622879952733,
+                113517019268943254,
+            ]),
+            Fq::from_montgomery_limbs([
+                15421467332801813623,
+                2344515906765251584,
+                8770913698045407986,
+                244230453890918832,
+            ]),
+            Fq::from_montgomery_limbs([
+                519462922844798243,
+                18388410501234981392,
+                4279425430969792165,
+                959440627014783022,
+            ]),
+            Fq::from_montgomery_limbs([
+                8431217219365662044,
+                7953136617503578600,
+                3344275224654813252,
+                115183095760296493,
+            ]),
+            Fq::from_montgomery_limbs([
+                6764609548472564402,
+                17298503349737942332,
+                13410552148352855986,
+                75073707842765200,
+            ]),
+            Fq::from_montgomery_limbs([
+                1681852025108018224,
+                11420149209563326850,
+                1862523426081884464,
+                94386381325621031,
+            ]),
+            Fq::from_montgomery_limbs([
+                17649444252887017821,
+                16348517102530282151,
+                4711902397776314675,
+                1096226890475134602,
+            ]),
+            Fq::from_montgomery_limbs([
+                8121075793166382819,
+                7571690630483157266,
+                835870400278755256,
+                818950857400656318,
+            ]),
+            Fq::from_montgomery_limbs([
+                13130807348426263414,
+                1819136916336877596,
+                5435104818095019009,
+                268672464744385071,
+            ]),
+            Fq::from_montgomery_limbs([
+                6267498079833246865,
+                11607928781895332302,
+                2789802326363143700,
+                196140365972382496,
+            ]),
+            Fq::from_montgomery_limbs([
+                4650583802703775761,
+                5774439871074543533,
+                2989329495915500748,
+                618346031106368654,
+            ]),
+            Fq::from_montgomery_limbs([
+                7544420238100876447,
+                18198679810740937541,
+                15562062253585888229,
+                35428884172896919,
+            ]),
+            Fq::from_montgomery_limbs([
+                13715725812344956147,
+                9713289778530507559,
+                9813515979705230667,
+                1138919765481886082,
+            ]),
+            Fq::from_montgomery_limbs([
+                8902374513355144108,
+                8142076057557159656,
+                9456369991908013027,
+                188073729369499310,
+            ]),
+            Fq::from_montgomery_limbs([
+                17377517203059402013,
+                11931826450997362142,
+                13627320898961443419,
+                549652328958759127,
+            ]),
+            Fq::from_montgomery_limbs([
+                4205882524155813213,
+                647373380273962999,
+                17419981257004279657,
+                886243148484421629,
+            ]),
+            Fq::from_montgomery_limbs([
+                2789432703182692168,
+                16524814650993979900,
+                7805562330184663901,
+                1124208067827687253,
+            ]),
+            Fq::from_montgomery_limbs([
+                11110408213823582116,
+                16737253397169008961,
+                7321156835310771553,
+                879644649239962734,
+            ]),
+            Fq::from_montgomery_limbs([
+                4824269094328656893,
+                3513378268696067534,
+                9243772064440454867,
+                498567109150531507,
+            ]),
+            Fq::from_montgomery_limbs([
+                10733078689016938147,
+                5501271255232081339,
+                16389268349954769980,
+                311820249375955208,
+            ]),
+            Fq::from_montgomery_limbs([
+                2332238211930137699,
+                6783498216692076440,
+                4970567819017799454,
+                525121129973947848,
+            ]),
+            Fq::from_montgomery_limbs([
+                960214742688556745,
+                17550178864799521127,
+                6831382611511494747,
+                917901373166999078,
+            ]),
+            Fq::from_montgomery_limbs([
+                9893484137362313866,
+                2749298268946154525,
+                1927002380165696682,
+                135266338024243100,
+            ]),
+            Fq::from_montgomery_limbs([
+                2525156019076132570,
+                10243157485403416842,
+                1572477218415301591,
+                898665471108777489,
+            ]),
+            Fq::from_montgomery_limbs([
+                16879125604443904871,
+                12366673949715355242,
+                2234707368506512669,
+                597339720962466855,
+            ]),
+            Fq::from_montgomery_limbs([
+                2993153009718882178,
+                4804275588565988178,
+                478701394358671797,
+                1272821732491992157,
+            ]),
+            Fq::from_montgomery_limbs([
+                7704953741762658152,
+                16035541064536753774,
+                10185557049117623699,
+                280157966618579570,
+            ]),
+            Fq::from_montgomery_limbs([
+                1524645319197809928,
+                15077910943653383955,
+                4312950038525513410,
+                339725406897899670,
+            ]),
+            Fq::from_montgomery_limbs([
+                11409513342407098193,
+                15765946011551723370,
+                5929931126647998349,
+                229040042910939411,
+            ]),
+            Fq::from_montgomery_limbs([
+                4244224238038117258,
+                2452598272873690836,
+                814369287788158097,
+                246892732415573950,
+            ]),
+            Fq::from_montgomery_limbs([
+                3416802789410174569,
+                6653507561127088250,
+                17970854711385112800,
+                1099693390577667132,
+            ]),
+            Fq::from_montgomery_limbs([
+                8549426987964640700,
+                11179570016279763585,
+                6721087815376347166,
+                1106961939441106836,
+            ]),
+            Fq::from_montgomery_limbs([
+                12184516237243243082,
+                8878082825965231951,
+                1351705067663361888,
+                472614222595941267,
+            ]),
+            Fq::from_montgomery_limbs([
+                1937857397393105584,
+                1833935172404613882,
+                17963373769571374325,
+                477972127331085184,
+            ]),
+            Fq::from_montgomery_limbs([
+                17535944779548428328,
+                15747590288247672662,
+                69452417774107279,
+                444285447613879836,
+            ]),
+            Fq::from_montgomery_limbs([
+                17372679034686723501,
+                12158482218914032614,
+                6097759102839590024,
+                910493293889924871,
+            ]),
+            Fq::from_montgomery_limbs([
+                404502556919850749,
+                6817994627201071384,
+                17099410554410177924,
+                10058185507947760,
+            ]),
+            Fq::from_montgomery_limbs([
+                15335548744093067143,
+                12066596742679224543,
+                15358605715927783625,
+                75301380579500335,
+            ]),
+            Fq::from_montgomery_limbs([
+                10012237057803613276,
+                14283802151933852853,
+                6750876200233320776,
+                780215007185659010,
+            ]),
+            Fq::from_montgomery_limbs([
+                6173144502987217918,
+                1793406320768135378,
+                2840401124994048208,
+                80545976076559113,
+            ]),
+            Fq::from_montgomery_limbs([
+                7959110476005238189,
+                9569476742708534310,
+                17961603742821629102,
+                1280336486371066328,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909891710960783119,
+                15001938193312767793,
+                10141950758191680595,
+                399740379909340129,
+            ]),
+            Fq::from_montgomery_limbs([
+                14678888368337618639,
+                11309708771318637268,
+                86876106480322371,
+                479957459380294986,
+            ]),
+            Fq::from_montgomery_limbs([
+                11054671973293448540,
+                6232876651549536039,
+                16873756604276974497,
+                235771987446166353,
+            ]),
+            Fq::from_montgomery_limbs([
+                17900516327343877167,
+                5345596104861490177,
+                13368531647510252753,
+                805917017528376774,
+            ]),
+            Fq::from_montgomery_limbs([
+                7450779115410933404,
+                1080620577022529041,
+                5660547260662174651,
+                366670545026844837,
+            ]),
+            Fq::from_montgomery_limbs([
+                7079982300335493456,
+                18228474186064697326,
+                5794729590809571586,
+                524936618562003089,
+            ]),
+            Fq::from_montgomery_limbs([
+                17038455950976583200,
+                10430864327323998623,
+                3868929358694425816,
+                315331414671118477,
+            ]),
+            Fq::from_montgomery_limbs([
+                1748379948119609181,
+                9833732539786265618,
+                13509025265262494906,
+                334488127522406756,
+            ]),
+            Fq::from_montgomery_limbs([
+                12543445656000322957,
+                4280557824710732545,
+                17670687184559410293,
+                467459201422560311,
+            ]),
+            Fq::from_montgomery_limbs([
+                16708781309939718005,
+                5548885186052990904,
+                742113933798612926,
+                115351361782755082,
+            ]),
+            Fq::from_montgomery_limbs([
+                8709022849911892382,
+                10823110470893641952,
+                1898600801954221939,
+                1166132403768886548,
+            ]),
+            Fq::from_montgomery_limbs([
+                5686203601343942346,
+                872633493800742203,
+                15156226896558992386,
+                870289101739606564,
+            ]),
+            Fq::from_montgomery_limbs([
+                2623119037437170209,
+                18335722442609562601,
+                15321122023679937113,
+                941361635644536405,
+            ]),
+            Fq::from_montgomery_limbs([
+                1008741830074008196,
+                17041905332025096752,
+                12316067997317261387,
+                964097655689105055,
+            ]),
+            Fq::from_montgomery_limbs([
+                17562560358580550073,
+                4626943654301460020,
+                17780997996670305010,
+                556181969418442112,
+            ]),
+        ]),
+        mds: MdsMatrix::<6, 5, 36, 25>::new_from_known([
+            Fq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+        ]),
+        alpha: Alpha::Inverse,
+        rounds: RoundNumbers { r_P: 57, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<5, 25>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            ]),
+            v: Matrix::<1, 5, 5>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+            ]),
+            w: Matrix::<5, 1, 5>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<6, 36>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            ]),
+            M_doubleprime: SquareMatrix::<6, 36>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15152119682022281684,
+                    2079168917784381610,
+                    8548836536267236340,
+                    524962922610130041,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    16770218796354446865,
+                    6164915921600418774,
+                    18434642018604219177,
+                    984431242893424161,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12257484635811173124,
+                    3332345693021229804,
+                    9856107590709416500,
+                    1159223252431020322,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9979132349322887614,
+                    3961848182433907151,
+                    8799570660063224410,
+                    475862931364908156,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3872788615927669352,
+                    15152509537124089722,
+                    5205890896288656861,
+                    1231307161030894178,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<6, 36>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9423078532813425529,
+                    2444734831186772378,
+                    13312700987643289865,
+                    1248785285043953466,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17268489821349265988,
+                    4381498102126941915,
+                    18059594369778317098,
+                    58369093700071757,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4554828072642644132,
+                    1444610657142044086,
+                    17449887890871798968,
+                    1155580816162940184,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14700875084405105752,
+                    9239241115023183874,
+                    7789802599827561984,
+                    594039389414401309,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17369961549732213951,
+                    17978420689641993214,
+                    5737613384797227392,
+                    899750828627372416,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11810971497921864966,
+                    13993073307646756902,
+                    8362406159543098387,
+                    1156913604397747244,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17268489821349265988,
+                    4381498102126941915,
+                    18059594369778317098,
+                    58369093700071757,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11035226460624215086,
+                    14239859242862966780,
+                    1132467188784037199,
+                    834550800812545516,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16880054357686407084,
+                    4825991163227954043,
+                    6215044494445833070,
+                    1088315875473995942,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18168083839917324524,
+                    16478236068428869610,
+                    13105126087642640896,
+                    34766978023081808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17825669542785881313,
+                    12421462367159336603,
+                    2878814310510077253,
+                    503620314193006332,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8217239731080607569,
+                    6429369295863013371,
+                    9835418190294938624,
+                    157201591859370779,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4554828072642644132,
+                    1444610657142044086,
+                    17449887890871798968,
+                    1155580816162940184,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16880054357686407084,
+                    4825991163227954043,
+                    6215044494445833070,
+                    1088315875473995942,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11485023460188927874,
+                    2485551665474920570,
+                    6640923664201453737,
+                    128700539913813365,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8823818330539404675,
+                    14326485851202631671,
+                    17961953298539513959,
+                    284442003114632699,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9085308543112959065,
+                    2317720835922103388,
+                    3188272186429413269,
+                    623518145967541728,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1228919753536764212,
+                    8001001063597501895,
+                    1883429940342590902,
+                    229754754018256412,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14700875084405105752,
+                    9239241115023183874,
+                    7789802599827561984,
+                    594039389414401309,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18168083839917324524,
+                    16478236068428869610,
+                    13105126087642640896,
+                    34766978023081808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8823818330539404675,
+                    14326485851202631671,
+                    17961953298539513959,
+                    284442003114632699,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9493025027446580308,
+                    5326008844078412686,
+                    17968627192349153669,
+                    636747921187750929,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4719209488067015380,
+                    3286149619947952216,
+                    5381059733444658429,
+                    751991172029274729,
+                ]),
+                Fq::from_montgomery_limbs([
+                    671599285952310028,
+                    4063985053036137885,
+                    9646938041918321080,
+                    299288710064420029,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17369961549732213951,
+                    17978420689641993214,
+                    5737613384797227392,
+                    899750828627372416,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17825669542785881313,
+                    12421462367159336603,
+                    2878814310510077253,
+                    503620314193006332,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9085308543112959065,
+                    2317720835922103388,
+                    3188272186429413269,
+                    623518145967541728,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4719209488067015380,
+                    3286149619947952216,
+                    5381059733444658429,
+                    751991172029274729,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6563574222345881967,
+                    1281874181852671859,
+                    11466642440831414650,
+                    443077589787383810,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2252784982698758221,
+                    17647697801612484629,
+                    3564381744461717162,
+                    1112500262860291153,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11810971497921864966,
+                    13993073307646756902,
+                    8362406159543098387,
+                    1156913604397747244,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8217239731080607569,
+                    6429369295863013371,
+                    9835418190294938624,
+                    157201591859370779,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1228919753536764212,
+                    8001001063597501895,
+                    1883429940342590902,
+                    229754754018256412,
+                ]),
+                Fq::from_montgomery_limbs([
+                    671599285952310028,
+                    4063985053036137885,
+                    9646938041918321080,
+                    299288710064420029,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2252784982698758221,
+                    17647697801612484629,
+                    3564381744461717162,
+                    1112500262860291153,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14444310640393871747,
+                    7159616422470945171,
+                    4259499542152695501,
+                    338890106485999353,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<5, 25>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    13904863849479527760,
+                    2515041799497956331,
+                    1093602514364610087,
+                    950491498045111042,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2579295949243395069,
+                    12030978017499995826,
+                    12080587065043491505,
+                    38868424124071283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15092547513410891885,
+                    17256115361070971171,
+                    15459413513070879524,
+                    321960420545902323,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2229704028309941057,
+                    12072197468400244658,
+                    10950994247046669419,
+                    1297185510317020994,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12038262541350864619,
+                    6432253836569655322,
+                    13293017510951151922,
+                    464458410353108600,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2579295949243395069,
+                    12030978017499995826,
+                    12080587065043491505,
+                    38868424124071283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10576000036529335161,
+                    13879266532014962184,
+                    16888595672184660747,
+                    1185164108096765058,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1279444508565403937,
+                    6039704410083424768,
+                    14409182292770989236,
+                    1032663778275682773,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10494653767401929635,
+                    7151674624434035414,
+                    113618062021286492,
+                    734991999940840872,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3733906304148166273,
+                    17683287484600659811,
+                    14933709177852827861,
+                    1249090649945868590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15092547513410891885,
+                    17256115361070971171,
+                    15459413513070879524,
+                    321960420545902323,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1279444508565403937,
+                    6039704410083424768,
+                    14409182292770989236,
+                    1032663778275682773,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17854942931053508049,
+                    12379835822212862963,
+                    7917418649695695574,
+                    818127752736513102,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16713139695953442230,
+                    14827246597006708747,
+                    6565710292947236351,
+                    279636205695659750,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6021453437997963755,
+                    2434637845477546326,
+                    15808901861436069769,
+                    656837851837718215,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2229704028309941057,
+                    12072197468400244658,
+                    10950994247046669419,
+                    1297185510317020994,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10494653767401929635,
+                    7151674624434035414,
+                    113618062021286492,
+                    734991999940840872,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16713139695953442230,
+                    14827246597006708747,
+                    6565710292947236351,
+                    279636205695659750,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1817061708622591813,
+                    7842241796668241324,
+                    11705700881511613623,
+                    124703629193508345,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7645141843637222694,
+                    11261613033476609866,
+                    684978708883533390,
+                    1180658499202360863,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12038262541350864619,
+                    6432253836569655322,
+                    13293017510951151922,
+                    464458410353108600,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3733906304148166273,
+                    17683287484600659811,
+                    14933709177852827861,
+                    1249090649945868590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6021453437997963755,
+                    2434637845477546326,
+                    15808901861436069769,
+                    656837851837718215,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7645141843637222694,
+                    11261613033476609866,
+                    684978708883533390,
+                    1180658499202360863,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6471109714169199028,
+                    651257754441033744,
+                    967849492968733845,
+                    881634047292451586,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            M_i: Matrix::<6, 6, 36>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9676746654607897692,
+                    176822987592575125,
+                    1127327003963924126,
+                    610383533878593573,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10866063728605160318,
+                    15788165770107470867,
+                    5594927169263063463,
+                    100492077207728932,
+                ]),
+                Fq::from_montgomery_limbs([
+                    57996463639530468,
+                    4127316353880702951,
+                    1837519090050544541,
+                    1213106229260309023,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4872166224187235349,
+                    8031280949758658800,
+                    8207185367336947199,
+                    923899465857754821,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2642177073414695890,
+                    5965529204125479428,
+                    17030617766519586480,
+                    632515862607946230,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10866063728605160318,
+                    15788165770107470867,
+                    5594927169263063463,
+                    100492077207728932,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16579312608426380584,
+                    900107033077840316,
+                    10707104599250400497,
+                    260821539210969775,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9154500699636103498,
+                    9810746805247046413,
+                    2277170225848843979,
+                    376184271918216846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8936286403658156308,
+                    13409089003282074453,
+                    4485325905272731633,
+                    1162136327736358765,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9560942192309695329,
+                    3611681798106636532,
+                    8953102757524482277,
+                    48313427798591593,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    57996463639530468,
+                    4127316353880702951,
+                    1837519090050544541,
+                    1213106229260309023,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9154500699636103498,
+                    9810746805247046413,
+                    2277170225848843979,
+                    376184271918216846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12893510137188139763,
+                    13989110889528474876,
+                    17951327688936199988,
+                    696531560466003950,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3078821977932165667,
+                    12203386785117852610,
+                    14087925150439038356,
+                    1104343372731188436,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3294628651300959316,
+                    16736412895002781997,
+                    1916140570371787404,
+                    1004640613595118504,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4872166224187235349,
+                    8031280949758658800,
+                    8207185367336947199,
+                    923899465857754821,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8936286403658156308,
+                    13409089003282074453,
+                    4485325905272731633,
+                    1162136327736358765,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3078821977932165667,
+                    12203386785117852610,
+                    14087925150439038356,
+                    1104343372731188436,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3849060787256261131,
+                    7256927865444281926,
+                    4721613854895322946,
+                    875907360098019826,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11852083732162967582,
+                    12664387301508935124,
+                    17435419208496119206,
+                    932559635375662498,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2642177073414695890,
+                    5965529204125479428,
+                    17030617766519586480,
+                    632515862607946230,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9560942192309695329,
+                    3611681798106636532,
+                    8953102757524482277,
+                    48313427798591593,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3294628651300959316,
+                    16736412895002781997,
+                    1916140570371787404,
+                    1004640613595118504,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11852083732162967582,
+                    12664387301508935124,
+                    17435419208496119206,
+                    932559635375662498,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13144522063862044845,
+                    5958797693309221677,
+                    1328696341514699695,
+                    186033016774544099,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14878706952858838161,
+                        15410104227655356999,
+                        17393275270132988194,
+                        905602962502284432,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17721242321237835775,
+                        11985636621509722110,
+                        11478464757469040638,
+                        960562638525520553,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17640631015407645126,
+                        17416650484724036037,
+                        14803487960933328666,
+                        554882262980868625,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10632745392742701465,
+                        7191381972905833266,
+                        10576427669223334706,
+                        1037506184958051122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7989155441247042094,
+                        18276457113184108543,
+                        17999817914616464103,
+                        943187440870955565,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15846042622821125949,
+                        5578170090212244723,
+                        14386484975980635087,
+                        1162081450610719662,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9425713365442649542,
+                        17724400577059603932,
+                        10835235343511845981,
+                        1179696731387397906,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15470398571621834328,
+                        8748455436620348445,
+                        11923882037413103894,
+                        147851229490754041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3205030528550848740,
+                        11233883805951482885,
+                        4631904539047675327,
+                        85240573307222008,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4306955861584430579,
+                        2866573513612242948,
+                        5009794262667624955,
+                        295301720036234004,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7251188616661835801,
+                        8810908530742177026,
+                        5438752491170217053,
+                        624809552915168399,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8041373032028091555,
+                        15486500451885216694,
+                        2640515848525906572,
+                        1101280997644118799,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1028894706146998760,
+                        13234395603487812099,
+                        310927904286248029,
+                        1284009513603315703,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15907405187752122338,
+                        7460014754810790101,
+                        3112115084952575259,
+                        1176166567473148345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6667915961879815626,
+                        11892868641826580977,
+                        17153671363694122316,
+                        1080025318281387536,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5168631598983031118,
+                        1709031240629846937,
+                        12176717122174422876,
+                        1133547231698905845,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6505284181050366169,
+                        6621303409684149204,
+                        14721846886395529985,
+                        756463071795143691,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10252599865552606113,
+                        8719458131520431579,
+                        4094128546015477610,
+                        154229004185959046,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5962216050578461567,
+                        1852471921743211935,
+                        14625008624739847061,
+                        402573870144144998,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2504232889257023710,
+                        6382931252528192024,
+                        946087668070181732,
+                        39444081019304469,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8935875040282017923,
+                        9359087371918622751,
+                        4377160640113921337,
+                        438001764693096006,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5964768355342862060,
+                        12844248228508214834,
+                        217390320027003427,
+                        519739213235621144,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13476869152223212245,
+                        14360104411032897136,
+                        16239873007321200914,
+                        3853403215029221,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14270258435530476850,
+                        16224741710497622821,
+                        15034723833298150364,
+                        1105854585395665472,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3034628514738856022,
+                        14677362698363483188,
+                        10612467457981582049,
+                        621317746892157952,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12356435149313176789,
+                        14997081370594107955,
+                        2318538420922195008,
+                        27051273329381197,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14179669150985422172,
+                        1587784931571575237,
+                        15544226047094276906,
+                        265503486564006544,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15848703335457516734,
+                        12988350955449684774,
+                        10747751923255963574,
+                        192884410298894891,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13283753749608228542,
+                        15731192032524989112,
+                        10134977648663526427,
+                        1011453356572734072,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8372393423324448788,
+                        2642634076467295974,
+                        2238857053734841195,
+                        713995311234362151,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6650896784315049976,
+                        8618299026172959087,
+                        4613782712309215097,
+                        1194575414958458214,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8401059625843059611,
+                        9360504521211886317,
+                        9752588986385800578,
+                        35467022242567223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14549311442558804272,
+                        14851474988790195550,
+                        17243272363514665510,
+                        556891460284998050,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8839260009215695166,
+                        5332337195891362342,
+                        10215113273837828390,
+                        53276328504747705,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4743214023748331668,
+                        16150812970442549102,
+                        3607008032772749996,
+                        71636842460588166,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7745993606763106157,
+                        400962028791523193,
+                        14448679365436054364,
+                        127361796442057001,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4881557665307853374,
+                        16503843953516371036,
+                        2631138906021440407,
+                        1145135568670602972,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3748730199472583624,
+                        1701168955512052480,
+                        1204298795241125154,
+                        919171892692993894,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16293341788272558865,
+                        17325870627390234246,
+                        16387125646971798797,
+                        1009137174300676172,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2499133372428019558,
+                        15739123510404573354,
+                        10677356306660504584,
+                        958976143899963938,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14252792066102246657,
+                        15864171804698265189,
+                        11911247415967054910,
+                        961487415285183784,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9957038734178160162,
+                        250039500043978770,
+                        375263104710602935,
+                        35544998893003180,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        831877114936420076,
+                        2002790044227393828,
+                        11129740988708533905,
+                        746550053534881799,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4734226494958051881,
+                        18003010652169239318,
+                        16569731595317548852,
+                        109984045623665701,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10630025490758573206,
+                        12380598183142825838,
+                        1493124485160554052,
+                        492990263362994025,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9005167414234319760,
+                        16535434979210366835,
+                        5819620234707341946,
+                        602158252276980823,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9119416898454972043,
+                        8049431789793640151,
+                        13607373929564011639,
+                        761938773322253558,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11646529739221266743,
+                        15631506041020749904,
+                        7526925524884509569,
+                        797718489067789891,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14242496370333104920,
+                        8841581893310155090,
+                        1684451748824201839,
+                        293704403017488420,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12068414265588270249,
+                        7748413288237551533,
+                        18041956849707733339,
+                        1127727052880210045,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16896144613183838177,
+                        12574347096617734902,
+                        8321293894060275613,
+                        1181815496381441993,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11490675786236053006,
+                        9698599818026366787,
+                        5948732065795477184,
+                        425414085344036431,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13535638352040032845,
+                        11010719926202516879,
+                        10750188616385991719,
+                        450767332534563092,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3178227411283526947,
+                        463456667345831080,
+                        14382176159228633426,
+                        829036438269219798,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15205960183032177355,
+                        12047942859395994973,
+                        5508647109209154207,
+                        409271998288939244,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5842441710461865655,
+                        14354544144458273887,
+                        16159087842820577493,
+                        1102018593492537867,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10259604216221740521,
+                        2737735964532783652,
+                        1934594841398453131,
+                        915029026536998488,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5389086520584317977,
+                        2729523047482171967,
+                        12492070423980987131,
+                        339278797072716184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11961359983369405646,
+                        8872285219479794351,
+                        11590963834161858363,
+                        847871031745582321,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15708428924964800383,
+                        9689329403735339585,
+                        876235638671718277,
+                        1293191834696859659,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10926323662882715805,
+                        6357617649943251603,
+                        3882295273855196975,
+                        302265041875854273,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11884492353389440282,
+                        10865891220420186719,
+                        1506391581950765997,
+                        1273730401079532625,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6062018478948078733,
+                        2238300480159412589,
+                        4475922925472998435,
+                        427700151782713553,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14998626184060509521,
+                        8084520935552429366,
+                        16157620168154649636,
+                        936139145933018848,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5424440642649697210,
+                        11030005216981850100,
+                        3736937858414725236,
+                        353926131228082038,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6991010538111487096,
+                        3208699080691172022,
+                        15674148293257243198,
+                        528616403431683993,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5895680427204256637,
+                        15308092775755115835,
+                        5725537177674079322,
+                        624044939296156178,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15682781481863573356,
+                        12524037328552645708,
+                        13137829315048512550,
+                        453866324724543378,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5999454803832491786,
+                        11757829240759186672,
+                        16786792833686808980,
+                        1008783050525293123,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4965854579231858650,
+                        4470286827276633884,
+                        18255756957602409470,
+                        1257925856629374202,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6756792567489648436,
+                        9174065877200954316,
+                        14588982300344994878,
+                        495558322589948188,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16407708660026263913,
+                        17259087838077364390,
+                        13625416430514248811,
+                        207969270690764399,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11870501170821012713,
+                        18047340589874750756,
+                        93512878936056238,
+                        478288405689674144,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        873793291822892867,
+                        17836937206243952238,
+                        5128543094009831337,
+                        132104142075103351,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        930043501771119768,
+                        15613823438280495150,
+                        6464828430156696004,
+                        359908580296612711,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18438521383060368690,
+                        14476395006954860191,
+                        3425945854098388541,
+                        944003065485357481,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3311768994809575814,
+                        10503690422660653146,
+                        10526674813249312919,
+                        864802385440313737,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8810438360452481727,
+                        12680118392135545157,
+                        13641246557527810849,
+                        1252645481585559515,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14299022666252422420,
+                        14841944407301251201,
+                        15136912555606442873,
+                        856379827147710042,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2062956481566651544,
+                        12093197892291904316,
+                        11631702561466346010,
+                        144441024184915083,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7993143086113878189,
+                        3624434478267440806,
+                        13278019726447600986,
+                        654136378501676754,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7992479798903963387,
+                        7895372341947686019,
+                        4334209577850630153,
+                        1180883581857163505,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3027910113375733204,
+                        4485846847893599917,
+                        15294205594975595333,
+                        100423190749786506,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4120333963287836783,
+                        11671291525241561103,
+                        9901986613770683621,
+                        651573815597675240,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11428233072419105889,
+                        2944089198827741952,
+                        5214300364587140869,
+                        1189260788971714219,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4551784723657471230,
+                        11207660824801473415,
+                        13918603200560666189,
+                        909618049825337324,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2675501742361862254,
+                        5250455330270200426,
+                        12586684907650118989,
+                        1330284139416672144,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12040165191610290428,
+                        16503275216221829144,
+                        5260280485839874467,
+                        1227840433161545716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7679795588389348013,
+                        15191087003013740641,
+                        15555116922073130768,
+                        344262909715092301,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6169211658344078831,
+                        531166906056818046,
+                        1322277761354587680,
+                        210108348638141620,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2248963634255452368,
+                        15563761402233708045,
+                        3393738612726595863,
+                        270037018221350140,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11601309892240246141,
+                        3638465656527027416,
+                        1395756619190043331,
+                        138813074475309167,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5291125713635089368,
+                        14819398438069773983,
+                        13114950161797142217,
+                        25590678184791818,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5534877013314393609,
+                        1759072288627502628,
+                        16181954987325767733,
+                        134682133959402637,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4161482061465148858,
+                        16683675972158421075,
+                        3099920947049254669,
+                        957124783703318342,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4510595409768553239,
+                        4974517727576987266,
+                        15978837518179150731,
+                        335342136683204977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6636059739397131000,
+                        9640261418262971507,
+                        18005427333200225259,
+                        755356145676404050,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15131021729179035121,
+                        3322699383837917438,
+                        16694361960801514505,
+                        921109580640113983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7634123202466598220,
+                        810298720516221902,
+                        840599007384281843,
+                        656905365051140366,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11303104649441347181,
+                        12152859936809161042,
+                        8157805368560172079,
+                        226879006121087093,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4222768071479979923,
+                        6221868890067618237,
+                        6706788302094795072,
+                        470857396285528184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        837480829798517493,
+                        2139750872442256742,
+                        5213024737969853757,
+                        586284678362031355,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18413923626929455705,
+                        12351565965899756217,
+                        17706788027426014962,
+                        396824563157594134,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11865172518676463006,
+                        15009302994533156097,
+                        16026973397263722566,
+                        1243693536982901909,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7252462303444002809,
+                        9549080656602382200,
+                        6400682688095758738,
+                        206059245034621582,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8375555895564253468,
+                        7925422648691473256,
+                        16821885625729885556,
+                        985921094928817309,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2046299323863281369,
+                        13882781361146929705,
+                        15448488877161513887,
+                        490830679341871217,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7429671875567937801,
+                        10229990955659579326,
+                        17998819479213658262,
+                        468703275856634003,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        935091299463462187,
+                        8817490257427535584,
+                        4201099561438792936,
+                        308509415209580254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15175273292104113428,
+                        15547411926550064745,
+                        18326735472470278187,
+                        46288025765161350,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2376775726955536549,
+                        7198072882321834778,
+                        5820986721223000167,
+                        1305597767879405697,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11562318758115257029,
+                        422264710018175990,
+                        4674708296176782956,
+                        321605471576378125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14422446717346283559,
+                        4522943560525804925,
+                        12524200552641302343,
+                        992174839589322314,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2660063348396745477,
+                        3046452749899310106,
+                        12556614243413712855,
+                        292167348848223777,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6669133378666467376,
+                        16135058416591179983,
+                        5627772722389914612,
+                        379140231019033687,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5810827329069058342,
+                        9466338503926448033,
+                        13219994636736965707,
+                        1133919653888422832,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11390689618371396272,
+                        9685029449622007826,
+                        13242820419679967997,
+                        1063349475949275121,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17763514783930037833,
+                        4809121417820211582,
+                        2875494172038972593,
+                        296043141357624455,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11611692421411999894,
+                        15220040608516358932,
+                        282037844166434278,
+                        281687965959955676,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4438433961554370400,
+                        12877811549841781756,
+                        15741991214229698793,
+                        280844842261162310,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17169851287016280826,
+                        10822471821114911500,
+                        1491721488328495957,
+                        216887165418048275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10994016828848236257,
+                        13445735015422423625,
+                        5018165562796963731,
+                        602535974428869013,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15126102334199024880,
+                        12867057351876414370,
+                        11159159194149946143,
+                        115881007342718723,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2794226167942571109,
+                        1548538606305510804,
+                        18291849409425428450,
+                        1340198361306759329,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4267162633414624824,
+                        10477712998553375282,
+                        6267269603855607198,
+                        982787929756500753,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17381305832731585223,
+                        9268492096935184370,
+                        5234949576450927455,
+                        21981840360160805,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4087927870940191769,
+                        13753414599816622073,
+                        13058329164603291227,
+                        834131236566284313,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2546006251271733188,
+                        17600491598884247746,
+                        14062949050289708021,
+                        821447001112770449,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11557892681183640983,
+                        3553808879521780009,
+                        5828391499760976335,
+                        706816644254127358,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        87239843232890664,
+                        15700472350663595467,
+                        174519885711847558,
+                        1123281110015984026,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2827103361153395332,
+                        9771223936028754360,
+                        17019668270000185744,
+                        250846897334218988,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5119814427033781946,
+                        2081312228775851068,
+                        14150656259150135415,
+                        412824983197785589,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8649749569221526485,
+                        520969763542437761,
+                        18397072919079815484,
+                        473314797287595707,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8194315404853463748,
+                        15060811055660635178,
+                        11318868018104656047,
+                        759678196288305769,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10987085614436524099,
+                        8214999842286961244,
+                        5709575456336614864,
+                        1056310210546073817,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6532789592575228069,
+                        3265438887018026818,
+                        17111525378271668948,
+                        1320499686924692082,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2197318288869911886,
+                        307033351054159068,
+                        14033422988641489555,
+                        689909787061505964,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3507717375223769090,
+                        10033023697975699121,
+                        15321810854570355253,
+                        890016110699860569,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6897743854949081968,
+                        10158161465287387801,
+                        9179072886178700686,
+                        620001185095628667,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17448998618237282714,
+                        2553852017375909,
+                        1793419264718581373,
+                        725044591608116521,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16012268534560097932,
+                        13416075297285506301,
+                        16493211465851903420,
+                        1091948493998105984,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14392369108156989804,
+                        10617956576365144165,
+                        11809714324716728769,
+                        1188444181516740870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15466384892561009653,
+                        10941962222512674624,
+                        1913904384440848129,
+                        606922266921522145,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14584618083984398210,
+                        14186299915288736440,
+                        17757535025721844034,
+                        1063945284694781692,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6792570643359499560,
+                        4535097730077924957,
+                        12073011546773881134,
+                        1137378726981169881,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15102847980540220978,
+                        5520326730982346297,
+                        2698729817022762322,
+                        1194738055539544118,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6147044654176302920,
+                        2364057582092333147,
+                        11540841437940787480,
+                        556063025467036658,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1647311829762859720,
+                        13415217341886740242,
+                        16485335404491864284,
+                        491439704095662519,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12141727361360860468,
+                        9821467392353266448,
+                        11166495178510190210,
+                        245664677516946430,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5360382176507266601,
+                        768193387830048410,
+                        3996566068451735831,
+                        795512106745688059,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2189128173409064762,
+                        11499748369225599949,
+                        13499847782281945694,
+                        1340871250773004273,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1159278411248794850,
+                        6701355601329876701,
+                        10476550252580870928,
+                        490304301636428667,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4466317563581149355,
+                        15128213745330326318,
+                        5257941398512314848,
+                        1111650855813054172,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8839577424282800883,
+                        7949855030699078116,
+                        2996553645008704135,
+                        85398623640375889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9514362250339879637,
+                        13706294199150347872,
+                        11853022932563321890,
+                        818790762833572114,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9961018519926627619,
+                        14691854011348818301,
+                        10295546679115313973,
+                        671510051305939182,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8675832638178096190,
+                        15384329786338742485,
+                        16839179964273656123,
+                        329507226456575828,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6605728508191898350,
+                        1384848276942303487,
+                        1861962894125812787,
+                        383098583080630689,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4561698591137787041,
+                        1318715877170717908,
+                        11811913496147224297,
+                        1266463546599202644,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3941185879564025102,
+                        9068550332099939649,
+                        3774871808306873747,
+                        113685620429513616,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10900481545363949953,
+                        16115646190847812550,
+                        6550084177013615100,
+                        598385285121881868,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11988242285624917087,
+                        13537139818059184362,
+                        18277156359000612122,
+                        1177098503346466060,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2871317440024188669,
+                        11491484620760015211,
+                        8240161969757450334,
+                        288777199915272071,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7988214670526370098,
+                        11440380003743776649,
+                        5120770144084045661,
+                        673043132284547879,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10244768242455888134,
+                        6826411076153488373,
+                        3992919388432885522,
+                        618450537368913145,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3277025632854039553,
+                        1513597008000026806,
+                        12962944242264185443,
+                        29552244226000196,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9856447415386675359,
+                        6958080017277973499,
+                        1972061050703948700,
+                        363028909113016937,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18144015628780017310,
+                        9215031567519022385,
+                        2579198778706954089,
+                        1344291422453794380,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4561319259222010118,
+                        8097179400034306342,
+                        8673840261524363274,
+                        1292769321746354466,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14354418302042558939,
+                        16816016953906207442,
+                        8683619213886079120,
+                        52255018463283114,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6693923039249343911,
+                        14941670830056166225,
+                        3455836399658454453,
+                        901978063788794507,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        152744225937555083,
+                        6285483582401766798,
+                        13255840582182557046,
+                        1194440288357186914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12031977876097962401,
+                        3812526968386640115,
+                        3106241613052861900,
+                        1043677748307097301,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6583365401122825209,
+                        14856738795586055809,
+                        12856944775360993466,
+                        212571269983147442,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12605571826480031328,
+                        15967087552570539022,
+                        333549723451249733,
+                        622708120507764052,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17802332556116385943,
+                        14279193358216281417,
+                        13642262666760164332,
+                        422066776478733785,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15850710765764048640,
+                        17962678124320687828,
+                        3997090287434441048,
+                        289271071631641400,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16844665295987408289,
+                        465937685972437330,
+                        10295070008257911735,
+                        349028704970499870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9058271006797775102,
+                        1423539866372898588,
+                        10721918054466793206,
+                        1096542337783680000,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15773309499004426381,
+                        7599518864051902227,
+                        4972382211476324593,
+                        1216422750387414796,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5424986989883745608,
+                        9778110820267613400,
+                        1831027857068217721,
+                        823977947288477241,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15417940489780382204,
+                        10068435672441225096,
+                        18066230055400293229,
+                        378529671297816689,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12295828775587450355,
+                        10567986612615843451,
+                        15658819354846655938,
+                        1025977911240631459,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14756112282128265122,
+                        12010110928176797560,
+                        6727464018091229125,
+                        477033296532651022,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2424983758892174810,
+                        8423643924138551621,
+                        15676786918907794767,
+                        153809792304968198,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8032271049558536629,
+                        6859487145408729275,
+                        6281135308324413348,
+                        110878626210009348,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1343135654847183034,
+                        12246336647362309269,
+                        5070221686207819754,
+                        406907614844664345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5757407642175050879,
+                        8980853262227514949,
+                        7638651053604298397,
+                        1000465528826780634,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14017423935650035371,
+                        7040677706281207878,
+                        7888540925660075046,
+                        109104500194828764,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1326605414860569200,
+                        7060794444612497500,
+                        7097533099487389777,
+                        651422803166322839,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16525155321157715236,
+                        959421033614932222,
+                        12209290469304575092,
+                        128541045862130450,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1342500629219066587,
+                        9815991175599539501,
+                        11151963985138994597,
+                        1187664143545680212,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        52458987358552916,
+                        9158236120590147876,
+                        3445541323436234862,
+                        1183463284973607946,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15961335842114154151,
+                        16039160923941474740,
+                        7443985886592979764,
+                        1076100740988838167,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2793920492876597885,
+                        10562106606523716537,
+                        15050919755456360678,
+                        1027030443865543086,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1187534269112578042,
+                        10239928707972033889,
+                        9781739167093911182,
+                        820081671462909741,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11418685412080747750,
+                        15322427537679106749,
+                        2190313769649784436,
+                        218392624616995039,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1082306975475889906,
+                        3634113384477418553,
+                        4008243671618128270,
+                        262019181396317853,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7870180899945877780,
+                        15891270609210961390,
+                        5451013393218617263,
+                        457027430935078259,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11547501954163043770,
+                        6288655663977590598,
+                        948124381784278155,
+                        1218339508006363049,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3301717183549879123,
+                        14779394240514902538,
+                        9553976721758074730,
+                        988398001585804387,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17986503990041976996,
+                        9670459348526534265,
+                        7041092484880821382,
+                        869830712129780083,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2138730446664922674,
+                        11594340190098848041,
+                        5223639756786089372,
+                        570348233802195114,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13342018459741286662,
+                        5682033078496784037,
+                        17373406002280314806,
+                        761706535331176428,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8048173343968512729,
+                        13627483509794212446,
+                        798710180982153110,
+                        981542272094414714,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13720236466464710434,
+                        6422748121684136811,
+                        14520317585211662629,
+                        173146002230128237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10667130060484620993,
+                        623306289159058981,
+                        6316831624646330781,
+                        1332883931855214052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5391957851869859901,
+                        9911841794997399190,
+                        18362736891814594636,
+                        1178921101330801232,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14700148534728075857,
+                        6890233108528399806,
+                        3481539150737824720,
+                        738287060926009146,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15237131592687144758,
+                        2781013327768334960,
+                        5815653347457230723,
+                        29082657398627069,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11748507975201812355,
+                        14677479818082810506,
+                        16545813277194075802,
+                        1059030005546434700,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14133977783861747145,
+                        11457465237874094593,
+                        16542742571067539426,
+                        451875658982283444,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12017585808278199738,
+                        10115116343506974397,
+                        14531186273820088505,
+                        937977153549262010,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10328778031788787828,
+                        8027256693905909560,
+                        11845253070350024857,
+                        727432546205360548,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18397063511133139304,
+                        6327790491556470633,
+                        15114161432453808537,
+                        581501845119414522,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1981618026805277349,
+                        4639781522047071983,
+                        172371809516468114,
+                        1231773822848425101,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6074992462058353839,
+                        11287428010320780644,
+                        7052449867198826585,
+                        1112250085003353320,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11203792804365119259,
+                        4840523746686783788,
+                        1222143318135225470,
+                        3947438057757804,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7973768242155027412,
+                        6404460842647760216,
+                        686642470496984208,
+                        602130064882069209,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3730992366250893777,
+                        8545151995100412865,
+                        16525603961175296767,
+                        514585763979073842,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11217493060580493516,
+                        7165898070975727220,
+                        10203914149272625591,
+                        592650390788560621,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2129815513031391725,
+                        14440587152360761372,
+                        16145812525175341200,
+                        606742082503757578,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15560082085975779824,
+                        1437509409156825496,
+                        15313690645990286173,
+                        706686131234080042,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        444024089721936673,
+                        16336466193482811305,
+                        13536901111644500678,
+                        1284436602635645688,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13609210620755242896,
+                        12921596970525250060,
+                        13979222001010862744,
+                        1299009985989412116,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8332218511316051760,
+                        17179959108010439230,
+                        5620940183205568974,
+                        1082646299076655162,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2979851542670673593,
+                        12079607443017146303,
+                        14502915362850493845,
+                        1175681211643887323,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15302741284019787111,
+                        16506429471545705852,
+                        8963990178574039552,
+                        1191244879851357578,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18170346481036794134,
+                        1536891676136273579,
+                        6753267635799893814,
+                        207702279885778713,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5989869024040945803,
+                        3592930226418963666,
+                        4329340930828577632,
+                        58096485473805633,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13009882475565175479,
+                        14793807350598591352,
+                        8383018976677264996,
+                        891887871634144216,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13340562072844594323,
+                        1182423403159709100,
+                        14538620578962789404,
+                        396366349115395232,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3736406760530554655,
+                        17837526527318091062,
+                        3658803282068515137,
+                        666919873258121699,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6925461885133470424,
+                        3316614205442586618,
+                        4843517405914456734,
+                        39721252276437872,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8409059093956193875,
+                        4588757777063101761,
+                        8070453074629163904,
+                        972187723479030168,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1463625741769817937,
+                        1366029561904111205,
+                        11632227059630580285,
+                        808607744180283126,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13149564286452338472,
+                        14931672994769704882,
+                        16338474881308089528,
+                        628359945079351970,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10973003584183240341,
+                        1583880199352475735,
+                        16620605797002799651,
+                        930572974968400077,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16793968900325823528,
+                        5889459271535763548,
+                        7550784662186469631,
+                        176012418978199807,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5689211714420306977,
+                        11399871447396956110,
+                        3337485899898868838,
+                        651783240127941812,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8917851443178294480,
+                        13748395081483953120,
+                        4557315452713827379,
+                        147762657539951257,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3955976192455827905,
+                        14684815374887203501,
+                        14115034730870569282,
+                        677967804528625654,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12847770742645627571,
+                        1600950334880001568,
+                        14164169117014495458,
+                        514557015340589139,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13446754200867657751,
+                        17911370082571869432,
+                        12799731958583568738,
+                        367983576066387103,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1197352565180980536,
+                        8555886521096200549,
+                        13397187872689656661,
+                        923992466862196827,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1054601691697027389,
+                        1886528641859079855,
+                        14624210122667556038,
+                        800614707975252247,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3285644582050374472,
+                        11714963967979566793,
+                        4315063587227903408,
+                        1129953835430122160,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13998180741742803694,
+                        5501560698131659397,
+                        6739341964146631079,
+                        326687595597086451,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        942390859829203575,
+                        17654412446293412308,
+                        12575547585287829186,
+                        1024655705827085780,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14985969156780216437,
+                        5136192997896882037,
+                        7987078304130207050,
+                        736396213533824366,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6558203775768327721,
+                        2126209853715232702,
+                        8667674820333445572,
+                        691041334356307268,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5058645563234093669,
+                        346363443462133700,
+                        9835178631596644198,
+                        485164810931526156,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7027079683074689869,
+                        6919562850301835628,
+                        1951925389099250691,
+                        392183911596268622,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        640493971363157935,
+                        10141708371449407118,
+                        9764517914236984236,
+                        323990108556688701,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        214684961060234105,
+                        15311205162639543445,
+                        8846388375173193804,
+                        1011438929214393843,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4865700036842581600,
+                        16924112089646045476,
+                        2772148947563662136,
+                        549732518156337070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7603366291281892732,
+                        5202795076726947408,
+                        14936099012175984882,
+                        1138722948214266240,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11274473134253612547,
+                        7716244882203339222,
+                        10301369241715326419,
+                        813493791334445588,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14814338401026743470,
+                        11694328296300132595,
+                        18422264509621804388,
+                        993552147350794006,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12279681565121530953,
+                        11596874679819507201,
+                        11223622910505146082,
+                        662013071577156015,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9836763846204778491,
+                        13060076373366449516,
+                        1824139057175662222,
+                        637462140594230735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1549799237682180696,
+                        1618530674326582142,
+                        17586940232886668863,
+                        876106287497327244,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13174105898857493484,
+                        15986117996330064891,
+                        18433836200016889394,
+                        251062284838873792,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17232097297746088373,
+                        10505084866514142434,
+                        15905821565268737245,
+                        1032056764794531486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4236157095326932559,
+                        1546126351297367098,
+                        18032318210665854013,
+                        1344488598476227135,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17817727441969479529,
+                        6307945344288421234,
+                        4485386454729457657,
+                        512970832892675276,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2734778319702953712,
+                        6503860012699485118,
+                        16295683188581836548,
+                        163462997992586524,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6936468864464501867,
+                        4395501142919212327,
+                        7527647785007030065,
+                        456122874270270042,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17180051009781723783,
+                        16349813580670614258,
+                        2048725242281037728,
+                        924890446185012074,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12723546994209714610,
+                        10297933338565055714,
+                        11159944074292827671,
+                        494367572886696355,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10890504708846183387,
+                        13740763727930182260,
+                        16483324724088371277,
+                        976432502142417822,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11965014768000481373,
+                        4590094365594619962,
+                        12658317028657860240,
+                        1310578006635597200,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17777798447273892170,
+                        2418154452564419329,
+                        13558155121484443984,
+                        851660263984334820,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        667179529763345129,
+                        4353475001809272171,
+                        5660257076099184648,
+                        1209259442283195485,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17751108732424865068,
+                        2242159223924924691,
+                        8444488953065699912,
+                        658575773821939361,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7994868044499673938,
+                        13989329380825810179,
+                        8236249477581168804,
+                        1151905993099991484,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7416870390365817024,
+                        7984733685838329380,
+                        14038417589674285326,
+                        953678303831984067,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12639717847370207636,
+                        9396181989127259189,
+                        16084661217417776121,
+                        512129090137430662,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4319444208928296921,
+                        13490719897445934425,
+                        11276891846876566781,
+                        628448179387185126,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5598421133607129748,
+                        11785821984907912080,
+                        8480762021800170707,
+                        1046797168514345319,
+                    ]),
+                ]),
+                Matrix::<1, 5, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1585605910119518432,
+                        3534732939054458808,
+                        8321173898472322724,
+                        1016934604457479690,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17487884741272881606,
+                        15974716976545831086,
+                        700915398078768172,
+                        281921033655526851,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2614784499221974405,
+                        2565508145633665017,
+                        2384793308899641296,
+                        433084351543853236,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4470706451357580139,
+                        5503963996590888422,
+                        4491784722401268892,
+                        707262244563093487,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13174526769996530991,
+                        9164672417591951694,
+                        1377187716110609170,
+                        873294764331538691,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15152119682022281684,
+                        2079168917784381610,
+                        8548836536267236340,
+                        524962922610130041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16770218796354446865,
+                        6164915921600418774,
+                        18434642018604219177,
+                        984431242893424161,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12257484635811173124,
+                        3332345693021229804,
+                        9856107590709416500,
+                        1159223252431020322,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9979132349322887614,
+                        3961848182433907151,
+                        8799570660063224410,
+                        475862931364908156,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3872788615927669352,
+                        15152509537124089722,
+                        5205890896288656861,
+                        1231307161030894178,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        311271084272320082,
+                        5284197557048869863,
+                        14669222562574339674,
+                        571307699885408030,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11625781241601592791,
+                        14885568706820662540,
+                        17941376171308792723,
+                        783918507477975660,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        226868460330872644,
+                        12734244079593541616,
+                        10451890082278586850,
+                        32508669310149618,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6020187144675607048,
+                        11405887127978088053,
+                        4712122969940341979,
+                        1080334010198437810,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5933505187504807441,
+                        3473057270652036730,
+                        6105009132686901569,
+                        114203646444743763,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1120856672192884245,
+                        16247214934320106390,
+                        15323677081999960076,
+                        62404200512763570,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8831161875549232238,
+                        6077491870312206017,
+                        14081883006003626391,
+                        1154651324772984368,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5661890842653089732,
+                        13320534556163866242,
+                        9035321631852285107,
+                        903630770886300483,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15889657237181390432,
+                        15162271575679238159,
+                        1491114731026258819,
+                        287846816690944321,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7238626723934307483,
+                        13278275328632196610,
+                        8627741857269318907,
+                        925711656222055996,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16274331391150039806,
+                        1623594389399151027,
+                        11932333738554132870,
+                        706372249166702105,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        732452989467352943,
+                        1044771601653765712,
+                        7295501005775861284,
+                        241383337916425122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1055111559926828336,
+                        436063422223614235,
+                        8237830476387497674,
+                        486023893828905207,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11846198484218265818,
+                        8058524634853844099,
+                        813566309310159764,
+                        1342803580678902586,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13415802809644488728,
+                        14511002260322793565,
+                        4157389022600774011,
+                        262652662563663469,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6280812441180517055,
+                        6148355053508262675,
+                        10298538567830482124,
+                        549753875811636329,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        824304312279887084,
+                        5198474444698315745,
+                        4090895165493551175,
+                        1217302205153655151,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4735542953263469139,
+                        612014805877448122,
+                        12832372076520494984,
+                        750953222849642269,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        147828622890240493,
+                        128044761765714597,
+                        12093927754417303852,
+                        132385902652165260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18171144465445367562,
+                        4015656370081309510,
+                        15930285771575041073,
+                        1269412572744267545,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14398639038380864956,
+                        6619595958723804843,
+                        12719064233121758133,
+                        406442443563323199,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10762946454330918789,
+                        5528361687335835568,
+                        3991418360765486778,
+                        286492429663333048,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11742168906901241463,
+                        15503883602330266637,
+                        15831515237753512854,
+                        1044066191448484616,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10525155675978585492,
+                        4670730111394235591,
+                        5961373269656674720,
+                        95271963105126236,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        584627878861922239,
+                        7921433855087631798,
+                        7615313333543717641,
+                        1109592926172816575,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15091688909514107804,
+                        654570390524891199,
+                        16494576826680457410,
+                        30762953858016498,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2761325807027693284,
+                        11935489432137753301,
+                        3489012309486987802,
+                        239715377721177782,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10454593178969047515,
+                        1040128098662881642,
+                        5632035830401774632,
+                        554859180328868399,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5107303737176549426,
+                        15727856129637397988,
+                        6051568784602114728,
+                        873492972237083876,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12130743148391729861,
+                        11271998017451462639,
+                        10929122930592304172,
+                        194097991947183661,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14867630937298531794,
+                        9612402140924249048,
+                        15791934086459495277,
+                        701876161263964392,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4800492369986485195,
+                        837798357520426667,
+                        11678651630838500929,
+                        1197393486814847593,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7361531118817265902,
+                        10654842008121782114,
+                        11329661993953094409,
+                        53031172666072441,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13678736880795169285,
+                        11579896750305940195,
+                        16427828830934341434,
+                        373548996986898141,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3113965972245214273,
+                        3618222426215928202,
+                        14280489027814430075,
+                        1227439762891391632,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1519481477607620457,
+                        10318567135550122014,
+                        263846661207466096,
+                        640929643224454153,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14263939264430200097,
+                        8800960240049783032,
+                        10525864428412568060,
+                        1229598433830944803,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4737100860592755646,
+                        3405741585209394827,
+                        3774719897330733186,
+                        1001424607855656625,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4527327887926361650,
+                        5195716198788998780,
+                        2452454045651672598,
+                        1114842100489228591,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12639481132070877116,
+                        6184431555458882642,
+                        13833172896014011365,
+                        138391625804209902,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3025469591916962139,
+                        1116358649574420645,
+                        10697299179333984962,
+                        1100828152488463516,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17596104129549385707,
+                        117028884523868090,
+                        6843789980420923220,
+                        394144012237385792,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        76569421022187354,
+                        7618174529699040891,
+                        14258607882742494097,
+                        325795777168928125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9488688453300333348,
+                        6023458951346117124,
+                        8882873536266522192,
+                        1011335963622574163,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10273078691683312131,
+                        14951774310795138631,
+                        5056574235010407582,
+                        216683108447003755,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16270690847183812253,
+                        8396384187123364284,
+                        15973135291934403644,
+                        337754294626036574,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11537970389959600601,
+                        15344885270945805038,
+                        16062179622496264928,
+                        634770789960495300,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16629897675960611498,
+                        9857864935176241914,
+                        16649516829790580187,
+                        879077994781187974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15757760830891963153,
+                        1980968732617718233,
+                        363417887657441819,
+                        1245373320221094018,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1369140419065411691,
+                        114665172743829173,
+                        16025067317418460214,
+                        772519184136728441,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5166287970903668709,
+                        13178607088194812953,
+                        13394943015525495010,
+                        1136891698590635716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6786739958142009449,
+                        3712291089779086337,
+                        11114170296176591543,
+                        412243655343207746,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2483986099773441215,
+                        6355297372287593359,
+                        12546131765129037338,
+                        850333435641268865,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4129060137994461513,
+                        15810046888600142778,
+                        14959377549727103485,
+                        983509352989716443,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5375190994774345144,
+                        6642323264045992752,
+                        697489999924152538,
+                        711658337986264203,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10789013922038705474,
+                        15907427163103262467,
+                        6211828439721814286,
+                        821662973636748389,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1245892911222176515,
+                        1760690499003421411,
+                        7411938278280460519,
+                        996198333836410116,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14902739397227121611,
+                        9677074799597948924,
+                        16338232095647494428,
+                        107475751267019502,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14751552868296217802,
+                        10957219946410361892,
+                        2609386566023075942,
+                        795435587890350748,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14228026094996696402,
+                        2826584103258174948,
+                        1310186622837843420,
+                        912856498151667865,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        632309018718566470,
+                        8852208976226519541,
+                        5569100181556353071,
+                        984010564169166433,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6992820576439012611,
+                        9698447268226822027,
+                        17633082455161111009,
+                        90244636707781414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6543679712083684758,
+                        10736979596575357883,
+                        4698076992795071575,
+                        1270787674212113,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16044338327666607926,
+                        3960206126744122590,
+                        4858706793602461066,
+                        561666947698425720,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3368306314740365965,
+                        9177942911310203166,
+                        18367144956945019416,
+                        653903203015259414,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12874723854177830125,
+                        5100681384941725656,
+                        9285950066462290781,
+                        858148062800653733,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4768366185853611631,
+                        14842169834008972704,
+                        16645218449281418514,
+                        817503519482950628,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3007496102230569523,
+                        11399958112043651698,
+                        15787521760129406401,
+                        826416774444573023,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10890936042157055266,
+                        8565215756042961896,
+                        1054057862367192487,
+                        202049359204983814,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2425581217694278079,
+                        12792279644970276082,
+                        4689036727185422224,
+                        1064336763331781201,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2764179143636297935,
+                        18061233587720561716,
+                        12411254568157467507,
+                        427402914001475058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2924816478430507635,
+                        9786303437156946185,
+                        13021435082088378496,
+                        78702703698317598,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8200525868229314140,
+                        16881303073057711951,
+                        4187547714670888467,
+                        455617381392152389,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8310666736636434815,
+                        16453325372394262716,
+                        13511049246920939341,
+                        416209098687774975,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7508636928089493550,
+                        1778929466519171040,
+                        9865075252470321631,
+                        222439820478088778,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        26427962923262805,
+                        13400472629512916744,
+                        3808805306352074297,
+                        128699596583089680,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18283636998376248321,
+                        5779768391741799708,
+                        8993926161412908836,
+                        272788598277303242,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        385260822800957491,
+                        7888556288710245505,
+                        12785711973091118063,
+                        1290081227789032623,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7370461305656006315,
+                        8097104282146432375,
+                        3693139275053762963,
+                        1200841519361465892,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12067130442173286908,
+                        2170379854767453652,
+                        13201336336736961495,
+                        927175852053928205,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11214687023235424600,
+                        5560314158648804455,
+                        17670625093449035024,
+                        1242366510949799159,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8409895882379798325,
+                        14471144258274119497,
+                        5245528502428581668,
+                        869805265859067382,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2802161834127193832,
+                        181192611793840022,
+                        1581405763136818654,
+                        674326086636803499,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5748156006252401196,
+                        9530968273316544473,
+                        1373075747037319701,
+                        478917773953068122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10550634859635316442,
+                        11115297058518078301,
+                        15167740297739703393,
+                        499663014055796942,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8737679817842057617,
+                        14335003891544767889,
+                        5806471893265243804,
+                        1199697546794663346,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18312746592170672376,
+                        2468171777169034619,
+                        11588707770047473860,
+                        1167607620745843361,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8991056605371192176,
+                        9683166706953599777,
+                        3207279773035228554,
+                        1036594322405142084,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10715958137830220707,
+                        13790797151438523296,
+                        3814352367403212083,
+                        338399526865285980,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7602766395993095249,
+                        15421116878917379598,
+                        9842919232591540089,
+                        811441858348229638,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11240616276279859335,
+                        18168970638218441074,
+                        12681307963217316671,
+                        529653072592394540,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15804354330800222400,
+                        2664362506755913571,
+                        15220567525462081033,
+                        415854075276634564,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6499893156022247982,
+                        5370094427775939872,
+                        12693314181999023046,
+                        334522766633278821,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2043116979793859192,
+                        5901796972056645976,
+                        14393765505679755827,
+                        861233648011975692,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15324644517910983402,
+                        7402962800234459251,
+                        15501703598689014650,
+                        866765414305968533,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12865534582606617484,
+                        5710240921655628764,
+                        10047828149580190691,
+                        1035217630777023330,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7623115266181592594,
+                        15654093656542240609,
+                        17584343833694408578,
+                        965948623120670646,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12322860754126106334,
+                        5704375241169042821,
+                        5005598195199710337,
+                        599456016816833315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3114048235662948861,
+                        3186724356925890101,
+                        12603030351469960653,
+                        784880135590304822,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10542157016978853051,
+                        375232718281833036,
+                        8419637174785911645,
+                        480073460241875582,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4619753066505217513,
+                        16240034683968173295,
+                        101722798642334078,
+                        1093802332804170111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11772483107625265757,
+                        10206667679119141509,
+                        18114554164862022458,
+                        1023031741792146569,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13246284581489263787,
+                        14406389897239052674,
+                        3455768173607702603,
+                        1053480392953200960,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6494604848840235336,
+                        6851286908738110852,
+                        6952092093989720037,
+                        528933354597117664,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        625815039158913101,
+                        3482039923172880345,
+                        11185655673758240856,
+                        30871173764135737,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11116836871987577766,
+                        8109541581819717708,
+                        14249648100460872066,
+                        228964235140475035,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10850735048215572309,
+                        4250918544830268539,
+                        1159262399998108531,
+                        597017478561992605,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2429928267440692097,
+                        1037103561750764536,
+                        14249565405637869240,
+                        238948313464233222,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15908449453336200948,
+                        14778706528145004443,
+                        1518131124404071948,
+                        342204757203960757,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10343358798246302148,
+                        4716916005406799178,
+                        1280891908040278925,
+                        33904180033936995,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13657049778223062917,
+                        16159025960783232211,
+                        4256662595768243763,
+                        769690080070442279,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8630441296206546010,
+                        15101393911422801574,
+                        15765903519913875666,
+                        1059019903719527397,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17353580961907941474,
+                        11690315434040365887,
+                        1919970450761622105,
+                        1284644920565762622,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9142190750578974954,
+                        7982580406830591641,
+                        139105874244767315,
+                        1037725609814647069,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8274397077677504305,
+                        7550323696121525646,
+                        13342176390347462405,
+                        495524022793746412,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1300654639607007076,
+                        5418426225271078814,
+                        17799220415649590999,
+                        578082611305900028,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15087651230680029653,
+                        14061399273111500513,
+                        13990992483413246139,
+                        217274859442961675,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17409038465662970942,
+                        16739652419107899003,
+                        1728243859263530675,
+                        1005030425295937500,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9881137796190779983,
+                        3738513419678193257,
+                        6908712468714119492,
+                        128170763609761066,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5980808904168005021,
+                        6535637039411031048,
+                        4353709569732736105,
+                        985581940426054692,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9266459364560412147,
+                        1675941305082716910,
+                        2644496138354097667,
+                        593111726451400001,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11923296558184512186,
+                        14335481794247232343,
+                        1756296816003311831,
+                        1337227067587852298,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15213177739061556154,
+                        5962926969279075759,
+                        9583168463456919751,
+                        1345102811307691605,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7236531265332214668,
+                        11730813784468030291,
+                        3260041626130793112,
+                        413423911331686730,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9992377390528540244,
+                        10251294356867851146,
+                        15408569698061863503,
+                        686801780474850525,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12551312954021730815,
+                        8771782951882110027,
+                        8266520452918776358,
+                        585025591233977219,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5063400267728024015,
+                        14290329839247601111,
+                        12614604995283750144,
+                        230714106990743170,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14919127087277880180,
+                        1233913841901824423,
+                        2945855199399696462,
+                        1124234888120832064,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6630767633426865082,
+                        4134529385803270273,
+                        547558622173751939,
+                        1276870556955963960,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6194800795402001302,
+                        7453146289235044346,
+                        1846631670011345424,
+                        373216392925072559,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8530038482194296425,
+                        1186609470593087346,
+                        1550686771111894200,
+                        918591303091561115,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5499603817197150545,
+                        6278523764748797546,
+                        9492893478468221078,
+                        76690422627606484,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7172281043633854180,
+                        9586248386574401986,
+                        4695159959373826247,
+                        291013948190359675,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1958470378825944596,
+                        13429774428855320394,
+                        14915732503423284573,
+                        675396101420063305,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11013259379518244436,
+                        12321083210650373617,
+                        18169826561711333453,
+                        1123565689252234229,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8538081673660833158,
+                        9406736474943528419,
+                        1248182586638760682,
+                        566915522520784434,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        871762597826126451,
+                        16952899443646083570,
+                        9760070688515351533,
+                        1153171851787119216,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17099176922694092192,
+                        828730151529195508,
+                        4040300595746784683,
+                        896316263468400176,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17895859754552623107,
+                        12372008340476127191,
+                        15869904838348331177,
+                        775743200601290861,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7009270681870686791,
+                        2613922803678169165,
+                        14616132396476908383,
+                        1266967860663970985,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2842431186863606520,
+                        5016515943888671695,
+                        1025755096788745830,
+                        118497541762157376,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15651700296535015112,
+                        8569612184315077364,
+                        15451942282818992185,
+                        541946385718467129,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17425453868458867672,
+                        13259317954798539165,
+                        14653762240483553886,
+                        1289545541558928924,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10182995923953810379,
+                        1555053058248744878,
+                        14233166151877877681,
+                        1314025239224831524,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5382307905322995124,
+                        14222432758856306145,
+                        3195810595195159237,
+                        932323741878209391,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4093215837977364819,
+                        1786552664558589683,
+                        15811959916460894504,
+                        1122605521342489905,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6757580137997166235,
+                        13857613907011413182,
+                        729793288849614944,
+                        140426125151203814,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18248567113979980306,
+                        15307246736403175565,
+                        7970334653691291978,
+                        817641900996362744,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13209637935471398448,
+                        10485350831767594057,
+                        18120173774880047238,
+                        981942705210324429,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13041861333642835240,
+                        17156211741149643971,
+                        4775992643478887474,
+                        160471371624793379,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13194791414892768085,
+                        10208031273676783317,
+                        13638742613730384108,
+                        1170216476231137089,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6592597593493391222,
+                        9163269649054214457,
+                        10293404615795865974,
+                        324258485293055955,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13609429057557207506,
+                        14616812090886894233,
+                        7045165641408138966,
+                        641204890620132139,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7545410603229598238,
+                        13902184854450233173,
+                        4038545310789704146,
+                        320128837601953348,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17475188222658394804,
+                        4640780828143607318,
+                        12680202847212807322,
+                        1110775011309678475,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3485788607384573112,
+                        15177887285887860916,
+                        1970243788897606331,
+                        910298321342259079,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7095925088071484754,
+                        6240599162049381483,
+                        8736713750393401894,
+                        715713872067465450,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13914504886201768213,
+                        17063881335964633653,
+                        4382895857909130973,
+                        781718116754872633,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16251181043393844828,
+                        1368579394245323664,
+                        17904391190856163315,
+                        124000533225278490,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3864289636579407648,
+                        16368707578494782455,
+                        9180953208722234360,
+                        1240720640475350939,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17359424165114157468,
+                        6067204683590968472,
+                        18334926336215034458,
+                        423609358904253954,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13157360218467823015,
+                        6685695112146845309,
+                        9288708084763444719,
+                        700542244964821001,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15050948831398211172,
+                        1290563742383503178,
+                        10020806129447614852,
+                        1293514331447742628,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8970818276312788440,
+                        6606363461056850888,
+                        8236475087493379216,
+                        1227226301123283192,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7285319887855300684,
+                        10394544580564358801,
+                        17814675322732711071,
+                        22060614327930318,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5439715343034964673,
+                        15409384364836672262,
+                        16414082814004524385,
+                        809580634160268341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1781228708809181243,
+                        4149036766911071650,
+                        7213099426334284426,
+                        1145668717932845542,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9274006233122679578,
+                        10882493392418065372,
+                        10346336667117633240,
+                        1120284488934647446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1513954028928690131,
+                        2818565461955902258,
+                        17860337924290489317,
+                        218103206651100160,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9045268837059247997,
+                        13622392886516634231,
+                        5303439620404162545,
+                        273994652535308220,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8013541317490998526,
+                        1254521763995759758,
+                        8455044676025428769,
+                        1256043191756670496,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16778297169251680876,
+                        4339824797760411350,
+                        3889957237137561252,
+                        402202408568933876,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15051563410820756256,
+                        1718741354422101756,
+                        18363114163640005755,
+                        1127076637434643419,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14575400152995614540,
+                        18432820323119512462,
+                        13120064027864136352,
+                        474632200272668531,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10926387599758796369,
+                        2431690831887609648,
+                        2709069852849367434,
+                        1331446784519922045,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        861251784980119374,
+                        17875049195713140395,
+                        3351844751966093471,
+                        313354776732488543,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11585592396923089702,
+                        1750975639688884538,
+                        4506789195710052616,
+                        407768902414881085,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9850086626047696596,
+                        10197965499574464635,
+                        6411205111363814307,
+                        1057436664686908528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10481585948075672131,
+                        2086724495446918088,
+                        13617140519334213700,
+                        1024904508245797796,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15264882671376361310,
+                        2942678916028332923,
+                        8050924727487862099,
+                        607880725997212340,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12551560827872183879,
+                        2374198803617946521,
+                        8698441735924785833,
+                        856342680020363748,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12399655724090580579,
+                        14857517858824245638,
+                        3611523241112216806,
+                        193808092561414188,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6847212244326570380,
+                        1959772897276423063,
+                        8702278966223042646,
+                        1015351658136498612,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1552397323757346546,
+                        8061713571286216841,
+                        9447829490010056251,
+                        683606758660692529,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1734842631517648133,
+                        14513588560367058641,
+                        14760800637682332839,
+                        461064659662867923,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4100635645496815709,
+                        16524437214439110747,
+                        2473083689001936106,
+                        1310235040008371297,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13030369013733820747,
+                        8077705113606832087,
+                        13110975319500048245,
+                        483354750103378366,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11369985778210547016,
+                        3544043457610346415,
+                        15905245757986361756,
+                        674295930803094336,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4108083105970656531,
+                        3237656001949101386,
+                        9406576922067940350,
+                        33727786182437932,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12235764800973995543,
+                        362559061538959288,
+                        9194754566061548365,
+                        758422652103010042,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6743937864254731767,
+                        10228774867544394473,
+                        11281905908782681015,
+                        366717466477029671,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12217717033564929097,
+                        1534660111726873474,
+                        231951720267869441,
+                        932512231065814210,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10746139098146735821,
+                        347089707341598352,
+                        6110688207445483716,
+                        271364548351510977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14587698571073652614,
+                        15026498097213887720,
+                        4945045148149044799,
+                        694650293086814629,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2064647450879446720,
+                        3809030642384398949,
+                        5910491050814576993,
+                        864808347718391410,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8044663684997452278,
+                        12991205044934328635,
+                        2415875854558300996,
+                        1150854816217032347,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5685046128894654793,
+                        7923352691441182636,
+                        11281536844657703398,
+                        1128018475213729673,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1062000374152632895,
+                        15553158500059525746,
+                        14546045756174958481,
+                        609434340290325825,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9533903138527567880,
+                        16080364230200499582,
+                        3254505850564414031,
+                        972355262975543298,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9674352928291006738,
+                        2644923883031226050,
+                        17040820195765866171,
+                        690897848675735306,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7294148403366531454,
+                        11491629828174816943,
+                        13247172014259360454,
+                        1330678270422819215,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16718544008233092023,
+                        13450330912460997321,
+                        13120340124837060175,
+                        929385376435876572,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12552003449344157007,
+                        12122120350943266895,
+                        17021255711130605142,
+                        42609827313167889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15810720928051785268,
+                        11133501163061570738,
+                        999844530387728469,
+                        139555139587462854,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15211240248315575904,
+                        10221489682612462156,
+                        4088720202015447937,
+                        905804242796748933,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17543926439989764162,
+                        1342034367267539210,
+                        15341624924990861853,
+                        18084198363348422,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16969106904643221228,
+                        8969250187767810175,
+                        4696827901265173997,
+                        1265069380110713507,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17192248021626892575,
+                        4421370372127374050,
+                        16556275733982945380,
+                        1069418148870392995,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9274979171967764459,
+                        1483081733641595006,
+                        11540982400274137885,
+                        402779809367533263,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13334683671152127707,
+                        2984582461847258028,
+                        9314449734261064337,
+                        953991437443614027,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5272033347473057536,
+                        611825905849562880,
+                        4654429023219431804,
+                        365244310647133266,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18382576850568903217,
+                        12468426567839599324,
+                        16810101729555377092,
+                        390370173347355293,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4013274042538026656,
+                        15019769821279224935,
+                        5171014445082791545,
+                        596014447347165840,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8571974696864297250,
+                        6111934289092297763,
+                        5666604951217172224,
+                        103488137994267250,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8712277545319577509,
+                        995959683219612787,
+                        13713549085443366905,
+                        709428672338024808,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13686257828002605792,
+                        7618783925343723751,
+                        14417856669824134384,
+                        421605933810601846,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10883502150625101280,
+                        1173546608123856030,
+                        10699565652099358573,
+                        1072645913974184654,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5059103889059345864,
+                        11689422990529109822,
+                        8422013273325264394,
+                        390852563132122222,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16810737189169809501,
+                        1845187939927965991,
+                        14491263626226996423,
+                        280221233387225553,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17656626565283113409,
+                        7971061095621633692,
+                        423042865318997442,
+                        575376798588641527,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12807729919033047881,
+                        8826427396831531603,
+                        2491835954019396701,
+                        1045625199812393371,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3636262528192277984,
+                        10681596237399796454,
+                        8560077953679764116,
+                        277399237766278634,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16249168503608046200,
+                        9766296171325206891,
+                        16080663572884149552,
+                        859387782931486827,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7403592343919090843,
+                        7682480402243725413,
+                        17721417102676009227,
+                        328187598857937527,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2093292646770702933,
+                        15038601806733326661,
+                        13410654790333175198,
+                        1018273323834937444,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11909848294603185502,
+                        17754760195144166368,
+                        12418823197681523041,
+                        870651731345882262,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11647092969587809155,
+                        3452760216841262063,
+                        11124312344402303742,
+                        923269524172935858,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10591689623231306826,
+                        8257850655279850286,
+                        12020327040132442797,
+                        193759969576932987,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11283140821299142884,
+                        3895859826390796160,
+                        5168694099015826604,
+                        1099561393738490445,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5567882154468505703,
+                        1498428087252573341,
+                        16444838641558429333,
+                        816605206412636599,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10204061297644831656,
+                        2214446967182269277,
+                        3188625803355474602,
+                        962699429387647208,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1716450841873584389,
+                        4735620278763344444,
+                        17220472078618053631,
+                        749422143396381879,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        42023140545240338,
+                        13357787969281922620,
+                        15598746796203067291,
+                        230539455807018941,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12681970039347414804,
+                        17876061809212486782,
+                        18035977433219729345,
+                        730486785662783937,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6263767575955127328,
+                        17162887176328116531,
+                        5439159785498351416,
+                        245364303701861433,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9566135984692145569,
+                        9994912588122990419,
+                        4600103960578055164,
+                        832589507707895691,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6993954266896510720,
+                        9137154312572172876,
+                        8948302340815211434,
+                        886909844422682763,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8984816612925427323,
+                        6807020409013021147,
+                        11772005275492178336,
+                        825306524847474646,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14724045719168694296,
+                        16746356113338501495,
+                        13555300246452120091,
+                        14746860818313539,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1966573366774389292,
+                        6050059763627952160,
+                        9507071945628844174,
+                        71361143671924884,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8420161760916709483,
+                        12673031608082756648,
+                        14926315423121109077,
+                        295507964651943346,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9930852797024861967,
+                        1209759205186944718,
+                        6185794834091366163,
+                        1052944109956342819,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7783454150620261175,
+                        1141255048760030667,
+                        16929918578119623192,
+                        552804692596976774,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8152332812560898466,
+                        17288437898568032226,
+                        2455477482354319592,
+                        1312041995226312534,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2311390922730163473,
+                        14619390840582522362,
+                        1435941334552953837,
+                        843375120366943622,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5623827600410908852,
+                        15502324369042553036,
+                        2421479902791589393,
+                        1272397354371205904,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18022355207165965621,
+                        14507633565821454449,
+                        9642190758980238176,
+                        6646643483928611,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17963845332765288937,
+                        3956748559157381908,
+                        6008927063352664465,
+                        1028160062150471821,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3184102538181751395,
+                        1314584439899289414,
+                        12930422909545111761,
+                        335328256111781791,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6420332976970396182,
+                        8134293664822419924,
+                        12769800686524373879,
+                        12726206607391399,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4113201124574870181,
+                        15532296487833245563,
+                        2833349270499055919,
+                        247648550163702175,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5643749248266623627,
+                        10205462288982702883,
+                        5880789130197823102,
+                        611540803557164562,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6399103717807026860,
+                        15058109548912679397,
+                        11016760074070545993,
+                        931361277501156097,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11078749432085444256,
+                        16419284782893950998,
+                        4733804985770742736,
+                        574821602614370679,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4208502242621756334,
+                        10717509077482668046,
+                        1871022063545247669,
+                        788167892189587574,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4472737008582233202,
+                        15875888900738133499,
+                        12356802961800092248,
+                        886136811410101821,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13890812421783777461,
+                        9360394492378364005,
+                        3077110797904994447,
+                        754117820326537373,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14345852067580329452,
+                        1947016463939894216,
+                        13891136200156773818,
+                        172948660570277624,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10699621027484336714,
+                        4032755487396255685,
+                        5273033119626400252,
+                        1333782333465069624,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5248221153890324829,
+                        11107899607615088823,
+                        10324135422358942949,
+                        1257413315177896565,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11484050278233437307,
+                        15731165466309869374,
+                        7062018094072616109,
+                        551414052311844112,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6194370786248859016,
+                        2223000379745384902,
+                        1257775107721993906,
+                        798152289217124642,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11997973397174393220,
+                        7330825366858880699,
+                        887195894586386315,
+                        766533316609715579,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3289361868958048680,
+                        8617751408077586544,
+                        2608794180969440735,
+                        987270511816311958,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4396913866549641456,
+                        14061662556258615669,
+                        17821666047028847046,
+                        438943936206610951,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8418146633362452326,
+                        8808020647675821976,
+                        618839638038770048,
+                        941033512105169657,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15367254840988780823,
+                        4891186500275708333,
+                        5619488436567170304,
+                        393475243492953112,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13067743856512332197,
+                        11622030475789021781,
+                        16473247419861903175,
+                        985810117801266976,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5603582041549611396,
+                        6391231980250089928,
+                        6690971317551209451,
+                        969621477124607982,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9695094428130498990,
+                        16075709194785937437,
+                        10826735508262240734,
+                        621876872075328494,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9183466844258893194,
+                        724136865382781592,
+                        11452265219244992167,
+                        1118949519382420459,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8567686625207614253,
+                        10721985810838690960,
+                        16794556083768076306,
+                        1156101564641434044,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10515336559623136583,
+                        8136864563604862214,
+                        4610553937214888004,
+                        120699565129003410,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11657107934559343454,
+                        3134517457726164983,
+                        7216087056374813565,
+                        383050193177028813,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9111015381189623474,
+                        5502651749269449969,
+                        298963104105244314,
+                        33299121887930299,
+                    ]),
+                ]),
+                Matrix::<5, 1, 5>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15180228421508199719,
+                        6780186851891753567,
+                        8019898160093598459,
+                        109095774071460460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16692845181821304499,
+                        8515438287396976464,
+                        17353585954958592222,
+                        465715504051456052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7012669894356324347,
+                        10223512494091534063,
+                        11687752778997735797,
+                        596226863697029014,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15364530548680849011,
+                        7273653404295387073,
+                        3798237824849034613,
+                        676394374534028014,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15305617146327436952,
+                        1320522230338308603,
+                        16974395690711382407,
+                        23612480712699744,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<65, 6, 390>::new_from_known([
+            Fq::from_montgomery_limbs([
+                6896756996856485679,
+                15841779177013165962,
+                15214999319107067539,
+                799579116957920369,
+            ]),
+            Fq::from_montgomery_limbs([
+                12490528915572582125,
+                16781010963455801061,
+                7217822162841525370,
+                583758431590239696,
+            ]),
+            Fq::from_montgomery_limbs([
+                4103076503312823956,
+                17758287060299897020,
+                4307999606005888186,
+                1170503199703573017,
+            ]),
+            Fq::from_montgomery_limbs([
+                8258525078799980031,
+                17981209026984883509,
+                1705405933095425634,
+                686116416131442965,
+            ]),
+            Fq::from_montgomery_limbs([
+                14230314701524901383,
+                5359672107767046933,
+                12368533871319767094,
+                843835640573373216,
+            ]),
+            Fq::from_montgomery_limbs([
+                8643704922284891790,
+                1825521656328917056,
+                899270813962044093,
+                1061204208642029501,
+            ]),
+            Fq::from_montgomery_limbs([
+                4953924969538642231,
+                13666539068294068783,
+                11444515785794661054,
+                908034692260218959,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314759225172977089,
+                13709782326296732490,
+                8419170689653674679,
+                308813631347902302,
+            ]),
+            Fq::from_montgomery_limbs([
+                12380544042683374095,
+                11861610031062043069,
+                8139214262706710955,
+                1109426478539312383,
+            ]),
+            Fq::from_montgomery_limbs([
+                14996062894406844828,
+                10463007688447955216,
+                9816295069953689883,
+                96634753033372950,
+            ]),
+            Fq::from_montgomery_limbs([
+                5003443347312646154,
+                12088794601721440379,
+                141876702791129010,
+                583163428613983087,
+            ]),
+            Fq::from_montgomery_limbs([
+                15978675488657509273,
+                2000227115909726539,
+                3431572395324014720,
+                1270003269091334935,
+            ]),
+            Fq::from_montgomery_limbs([
+                13145481180479355307,
+                4178318043230229341,
+                17214877682671464756,
+                661390503117260265,
+            ]),
+            Fq::from_montgomery_limbs([
+                10310179957389889995,
+                10867899306511553823,
+                7834927874190244087,
+                200100347383143821,
+            ]),
+            Fq::from_montgomery_limbs([
+                10331689629547846666,
+                17728898712028161349,
+                9449037036373849203,
+                751974232653749583,
+            ]),
+            Fq::from_montgomery_limbs([
+                12297882660040040158,
+                2029088109297254896,
+                12042324552829098347,
+                1200859725133413022,
+            ]),
+            Fq::from_montgomery_limbs([
+                657629048713803968,
+                4384961285920470744,
+                3194096570312252490,
+                1278463812395335295,
+            ]),
+            Fq::from_montgomery_limbs([
+                2119720360683497903,
+                17163632796986597614,
+                716704290417195003,
+                835532625486667986,
+            ]),
+            Fq::from_montgomery_limbs([
+                10176413099011613257,
+                17987703072219667449,
+                3257008508155249153,
+                4611423074909247,
+            ]),
+            Fq::from_montgomery_limbs([
+                15759997935604733825,
+                10621328495674793031,
+                3913347339572315939,
+                450998439067161787,
+            ]),
+            Fq::from_montgomery_limbs([
+                3307239343003667924,
+                9423014334056565941,
+                8272365424846706207,
+                1154673256444886349,
+            ]),
+            Fq::from_montgomery_limbs([
+                16642064667742663839,
+                3060804713054629416,
+                7003569085640654950,
+                979920132801746206,
+            ]),
+            Fq::from_montgomery_limbs([
+                8454313433475921944,
+                13653730000403974157,
+                341352448321914509,
+                1015923902051934401,
+            ]),
+            Fq::from_montgomery_limbs([
+                450312744060749475,
+                15738910883501419082,
+                2086620364508756906,
+                447004216102613191,
+            ]),
+            Fq::from_montgomery_limbs([
+                14785850722344745284,
+                14933076136398833813,
+                7249228375878258894,
+                281264808411538247,
+            ]),
+            Fq::from_montgomery_limbs([
+                15742577503885773992,
+                16138580628546270313,
+                13226266626380987816,
+                1034403794172811674,
+            ]),
+            Fq::from_montgomery_limbs([
+                5864299709456847527,
+                2867423843223801167,
+                11661147539401967571,
+                570914450754804097,
+            ]),
+            Fq::from_montgomery_limbs([
+                8196643398558430356,
+                13739691867914665536,
+                15348612475328050520,
+                1001833774902135228,
+            ]),
+            Fq::from_montgomery_limbs([
+                8157730041377046423,
+                4742357281602049075,
+                8317607110208503522,
+                687329242796503297,
+            ]),
+            Fq::from_montgomery_limbs([
+                8889254347703356239,
+                7823169077590151635,
+                3680332803330805072,
+                117018288607788642,
+            ]),
+            Fq::from_montgomery_limbs([
+                17187324375997622939,
+                13986896892901959608,
+                11608961671411973989,
+                1231346567016094461,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16613480258823555758,
+                7534448498622827074,
+                3598187741109282284,
+                1174910616403463657,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3399236807088804641,
+                6284421572926423096,
+                1079434155979662857,
+                313470604813416015,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14342583609080682838,
+                14787384558049465742,
+                1040271032361797285,
+                1311736658234524419,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9369143122519479238,
+                7816826032005730847,
+                8455328320182237855,
+                722094808564103160,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6574061754644981737,
+                11242117559065088373,
+                13189642727809514904,
+                276315280948264053,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9534837385738919102,
+                10677549443815101171,
+                3054273199394521746,
+                307209474984997871,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                31634493026893011,
+                10822702466281889744,
+                11092762994655234595,
+                1217691124222928379,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7631273872622678443,
+                14159459613183992675,
+                14687411052639059761,
+                1200528428492612351,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10413993279327762739,
+                11460391427828784325,
+                15174039011310627002,
+                943096553533259670,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16149456731135603149,
+                14172683755592431655,
+                4074572551390667772,
+                569307717462866779,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2031198438862198483,
+                12514189202265443682,
+                5165184524181723274,
+                1036880585413024223,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6552377716635217258,
+                17600584743570816499,
+                2858092953844600992,
+                354139955073701218,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12896414334272846599,
+                1848218739105392225,
+                16026361350780790975,
+                1186562969917092370,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9946246681924692644,
+                17069120033935668812,
+                10574624466990950792,
+                342772491358933608,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9030483671754991419,
+                12189831718671229198,
+                12159886051295791703,
+                472096695836182866,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5374085172174058177,
+                1739808455725373987,
+                8612351155610665566,
+                633069120126320007,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13917968843202608564,
+                9710908517829800432,
+                16013544663658600745,
+                40915787615328656,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7370820088052581073,
+                12366513654289939247,
+                12362758160957849473,
+                588726876666015823,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11313456888257122662,
+                14550261905382747039,
+                15848527577408353303,
+                1120659795921235736,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                26285749781711230,
+                18112721472645148989,
+                5124498339473837413,
+                1150484772159552582,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17449062910858192538,
+                3344726651097710503,
+                537590897141280132,
+                1138554687019456715,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12535559812793463205,
+                6013030156055393284,
+                17836832801243486540,
+                1281206362259061397,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8617887322228256402,
+                5795283305485605345,
+                14604741473615771634,
+                22116344549253129,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7749694925630246816,
+                14557327953076578657,
+                2341241884543709340,
+                794138766996857847,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10190348887744436705,
+                7324960808656849476,
+                10708791339990642698,
+                704254987243474546,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8319224832526423946,
+                11189432854407173965,
+                4862196934839184654,
+                911813102629156766,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14713602463687413283,
+                11394605233837197284,
+                2770603303447953293,
+                581902090366711068,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12956365422208570727,
+                2085879885269057884,
+                15840938118741726243,
+                193367559569252014,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11106320576471235193,
+                804352599834259180,
+                2221407247696815017,
+                329333432113231467,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14092671667219855571,
+                5836919576187883036,
+                11009204274254531942,
+                1020397461212881385,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11168779546038532914,
+                6634310165317981175,
+                3283822538112535558,
+                1310979921396423864,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14225654942753348777,
+                9967760776564729594,
+                12246917768857606721,
+                741468723629077658,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12469832390196475525,
+                17031126857069588604,
+                18340331703242911240,
+                224969821907009255,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10048597881547897613,
+                2480649827494107663,
+                15746643118937305863,
+                1343187545200281407,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3941168299748027089,
+                14606280264262875968,
+                4701722066073095353,
+                1344060419396918130,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15977473842768967893,
+                11519651611493895925,
+                16712456209529648048,
+                1226640656969100654,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6490160191073158081,
+                5689792756490299755,
+                4771778784520973973,
+                1181086566622456069,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16156692318239452390,
+                4223356780703989231,
+                4246099559020140724,
+                46281653043098308,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2374859591869441564,
+                6304748328656888738,
+                14951724124648444677,
+                102313374466747329,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12503445838045796799,
+                13483574441433610282,
+                14855578962940213601,
+                1064465083028928040,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2247247767336820315,
+                13395882106375077179,
+                17063702609855514104,
+                1189459775858539141,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3634110420227494606,
+                825223212545425506,
+                9366437521266287731,
+                1037919084310783140,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16062316452338679381,
+                11943391907252396632,
+                7133661811536827302,
+                429458347492611654,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14128154710535598530,
+                14654658543155408468,
+                16889849788831144446,
+                207849858462883994,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                18214025465561714766,
+                3786279597148950187,
+                7318163800804459094,
+                1180659767566807198,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16267937261137759320,
+                8254559380170204728,
+                17119957836215826636,
+                1104255540458990819,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10231739768509946098,
+                2838028733870281191,
+                18246764648457711609,
+                997929999614893689,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13639157963848639555,
+                5315030021280273503,
+                15733336433167516909,
+                52457882805400393,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14787468064792901693,
+                15913681869503052483,
+                13239994291747740141,
+                1229673527871497964,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7070211087087309301,
+                7647725247940675616,
+                6650059122725124384,
+                1032836928696561304,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16566073386598768072,
+                5243261113865660721,
+                13235491259901561522,
+                665457264960415232,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3158200204123645703,
+                17710417243471265097,
+                9764984381442224631,
+                606262195074174719,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10362908306568033709,
+                1889449116897666467,
+                15272822086430357000,
+                1104538628333703850,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3899502788276783753,
+                1698848362494085694,
+                5022718006592176776,
+                688979209142875551,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17744060383664021273,
+                5505933015173558395,
+                8773637787725728558,
+                377797167985194811,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12184516237243243082,
+                8878082825965231951,
+                1351705067663361888,
+                472614222595941267,
+            ]),
+            Fq::from_montgomery_limbs([
+                1937857397393105584,
+                1833935172404613882,
+                17963373769571374325,
+                477972127331085184,
+            ]),
+            Fq::from_montgomery_limbs([
+                17535944779548428328,
+                15747590288247672662,
+                69452417774107279,
+                444285447613879836,
+            ]),
+            Fq::from_montgomery_limbs([
+                17372679034686723501,
+                12158482218914032614,
+                6097759102839590024,
+                910493293889924871,
+            ]),
+            Fq::from_montgomery_limbs([
+                404502556919850749,
+                6817994627201071384,
+                17099410554410177924,
+                10058185507947760,
+            ]),
+            Fq::from_montgomery_limbs([
+                15335548744093067143,
+                12066596742679224543,
+                15358605715927783625,
+                75301380579500335,
+            ]),
+            Fq::from_montgomery_limbs([
+                10012237057803613276,
+                14283802151933852853,
+                6750876200233320776,
+                780215007185659010,
+            ]),
+            Fq::from_montgomery_limbs([
+                6173144502987217918,
+                1793406320768135378,
+                2840401124994048208,
+                80545976076559113,
+            ]),
+            Fq::from_montgomery_limbs([
+                7959110476005238189,
+                9569476742708534310,
+                17961603742821629102,
+                1280336486371066328,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909891710960783119,
+                15001938193312767793,
+                10141950758191680595,
+                399740379909340129,
+            ]),
+            Fq::from_montgomery_limbs([
+                14678888368337618639,
+                11309708771318637268,
+                86876106480322371,
+                479957459380294986,
+            ]),
+            Fq::from_montgomery_limbs([
+                11054671973293448540,
+                6232876651549536039,
+                16873756604276974497,
+                235771987446166353,
+            ]),
+            Fq::from_montgomery_limbs([
+                17900516327343877167,
+                5345596104861490177,
+                13368531647510252753,
+                805917017528376774,
+            ]),
+            Fq::from_montgomery_limbs([
+                7450779115410933404,
+                1080620577022529041,
+                5660547260662174651,
+                366670545026844837,
+            ]),
+            Fq::from_montgomery_limbs([
+                7079982300335493456,
+                18228474186064697326,
+                5794729590809571586,
+                524936618562003089,
+            ]),
+            Fq::from_montgomery_limbs([
+                17038455950976583200,
+                10430864327323998623,
+                3868929358694425816,
+                315331414671118477,
+            ]),
+            Fq::from_montgomery_limbs([
+                1748379948119609181,
+                9833732539786265618,
+                13509025265262494906,
+                334488127522406756,
+            ]),
+            Fq::from_montgomery_limbs([
+                12543445656000322957,
+                4280557824710732545,
+                17670687184559410293,
+                467459201422560311,
+            ]),
+            Fq::from_montgomery_limbs([
+                16708781309939718005,
+                5548885186052990904,
+                742113933798612926,
+                115351361782755082,
+            ]),
+            Fq::from_montgomery_limbs([
+                8709022849911892382,
+                10823110470893641952,
+                1898600801954221939,
+                1166132403768886548,
+            ]),
+            Fq::from_montgomery_limbs([
+                5686203601343942346,
+                872633493800742203,
+                15156226896558992386,
+                870289101739606564,
+            ]),
+            Fq::from_montgomery_limbs([
+                2623119037437170209,
+                18335722442609562601,
+                15321122023679937113,
+                941361635644536405,
+            ]),
+            Fq::from_montgomery_limbs([
+                1008741830074008196,
+                17041905332025096752,
+                12316067997317261387,
+                964097655689105055,
+            ]),
+            Fq::from_montgomery_limbs([
+                17562560358580550073,
+                4626943654301460020,
+                17780997996670305010,
+                556181969418442112,
+            ]),
+        ]),
+    }
+}
@@ -0,0 +1,9674 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-6 instance of Poseidon with the inverse S-box.
+pub const fn rate_6() -> PoseidonParameters<7, 6, 49, 36, 64, 7, 448, 56> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<64, 7, 448>::new_from_known([
+            Fq::from_montgomery_limbs([
+                17282044694857417935,
+                18391879351549925375,
+                16621297296266970955,
+                1187464536096349489,
+            ]),
+            Fq::from_montgomery_limbs([
+                15749981188398128827,
+                18161382821509567906,
+                15833894523733034093,
+                912080688231360886,
+            ]),
+            Fq::from_montgomery_limbs([
+                732857060811375272,
+                6663258164394465450,
+                16961932464131262336,
+                150157956301930030,
+            ]),
+            Fq::from_montgomery_limbs([
+                3324047567408666011,
+                15876905532970464378,
+                2196635168086983028,
+                673260902469948016,
+            ]),
+            Fq::from_montgomery_limbs([
+                9017720082516981326,
+                4622821392487882487,
+                2004143278491771078,
+                79553344654160321,
+            ]),
+            Fq::from_montgomery_limbs([
+                15725156398738899828,
+                134293807826592593,
+                4194609701624605470,
+                923718786850835659,
+            ]),
+            Fq::from_montgomery_limbs([
+                1506612267556911095,
+                15057997080737451663,
+                3200432868016806792,
+                79843457852234413,
+            ]),
+            Fq::from_montgomery_limbs([
+                15381109204849600860,
+                7334421770056067710,
+                8859935324177038538,
+                524893541105483182,
+            ]),
+            Fq::from_montgomery_limbs([
+                12747347892490428888,
+                10744031060619833960,
+                216969153203596046,
+                351627995536373239,
+            ]),
+            Fq::from_montgomery_limbs([
+                15938209448434619941,
+                14787226341305656724,
+                7222886526547825056,
+                260942844205003059,
+            ]),
+            Fq::from_montgomery_limbs([
+                3883873459955052597,
+                14086405361879030618,
+                3732476051093484599,
+                590644766736152823,
+            ]),
+            Fq::from_montgomery_limbs([
+                329551904534808661,
+                14310345516028390776,
+                15991205191381891794,
+                363409986036008362,
+            ]),
+            Fq::from_montgomery_limbs([
+                8141621238399281429,
+                16806319276390326899,
+                2941525204636851132,
+                392321107762395209,
+            ]),
+            Fq::from_montgomery_limbs([
+                10673876685193367379,
+                3226460911137117130,
+                5046904891108020343,
+                891914741970270671,
+            ]),
+            Fq::from_montgomery_limbs([
+                10151950196116649547,
+                12229202773660205998,
+                17942596422641444232,
+                1314592630119966366,
+            ]),
+            Fq::from_montgomery_limbs([
+                7118691704679207443,
+                2328813456362077099,
+                13378448532879389280,
+                1038181286619156144,
+            ]),
+            Fq::from_montgomery_limbs([
+                17617908618647607302,
+                6406755535082945091,
+                10780176738784773047,
+                883959065795231989,
+            ]),
+            Fq::from_montgomery_limbs([
+                220881807308067508,
+                11358203129541665919,
+                5318389498699375157,
+                1334460348994360259,
+            ]),
+            Fq::from_montgomery_limbs([
+                1544095578690086381,
+                14755607229796461289,
+                8552920273964885945,
+                384839325185253427,
+            ]),
+            Fq::from_montgomery_limbs([
+                2246547143510792318,
+                6370524836254480770,
+                12710580973108254123,
+                620733344759850152,
+            ]),
+            Fq::from_montgomery_limbs([
+                1522987048402369297,
+                569413774430809076,
+                3489219997180569932,
+                213490170367966089,
+            ]),
+            Fq::from_montgomery_limbs([
+                18201677476561009639,
+                15711676663786792011,
+                2736274582993393443,
+                1308783324672544688,
+            ]),
+            Fq::from_montgomery_limbs([
+                8988409581077555870,
+                15395729585850181760,
+                17160994265322326073,
+                1321699298615596350,
+            ]),
+            Fq::from_montgomery_limbs([
+                17186365684610427683,
+                14805455850706520512,
+                7398189685997498189,
+                856261473629709007,
+            ]),
+            Fq::from_montgomery_limbs([
+                16135578650066671910,
+                17721600369615142887,
+                12702391566845043909,
+                1039449667664607505,
+            ]),
+            Fq::from_montgomery_limbs([
+                17662868325876307219,
+                3687823276650978561,
+                16903595461023823837,
+                1270259304190491006,
+            ]),
+            Fq::from_montgomery_limbs([
+                5255462344144484412,
+                10398400612426841504,
+                14850551577433287154,
+                100509438257741284,
+            ]),
+            Fq::from_montgomery_limbs([
+                7497201819016677010,
+                12647287024360688182,
+                17167128617451610496,
+                1154391371849389678,
+            ]),
+            Fq::from_montgomery_limbs([
+                8489961240837732083,
+                498896711230559177,
+                12078144390918790031,
+                50188243225952062,
+            ]),
+            Fq::from_montgomery_limbs([
+                18027423015533766568,
+                207133356983650205,
+                11489438573364147788,
+                1283434629144062815,
+            ]),
+            Fq::from_montgomery_limbs([
+                16110665433201965909,
+                12361668793191597062,
+                11874269563436625955,
+                1153023947305468034,
+            ]),
+            Fq::from_montgomery_limbs([
+                13275636695773200684,
+                2929540137089614953,
+                17009362695453166552,
+                1194331421068322527,
+            ]),
+            Fq::from_montgomery_limbs([
+                5491995828042552464,
+                16356277213829253132,
+                11370850406819916049,
+                1088855962253518685,
+            ]),
+            Fq::from_montgomery_limbs([
+                9286648179575746921,
+                1871696794419398547,
+                3340630180345304464,
+                1134587320404747692,
+            ]),
+            Fq::from_montgomery_limbs([
+                10195862806976300823,
+                4163975462710359558,
+                3508545500199944733,
+                1332438265838549581,
+            ]),
+            Fq::from_montgomery_limbs([
+                17451463462816592383,
+                744910521129869081,
+                7194805852600311589,
+                392475548848239248,
+            ]),
+            Fq::from_montgomery_limbs([
+                10689217435201120769,
+                16592212785769743217,
+                16740025104449012227,
+                856509689383933059,
+            ]),
+            Fq::from_montgomery_limbs([
+                3295285215039946265,
+                2097076093539275281,
+                18014748496474678159,
+                864287639062553750,
+            ]),
+            Fq::from_montgomery_limbs([
+                2259018305222105650,
+                3606037463884368107,
+                10252179848321053445,
+                491613425385031693,
+            ]),
+            Fq::from_montgomery_limbs([
+                18434353101451904734,
+                15584196713248206403,
+                9490527889458154629,
+                1313050090317260914,
+            ]),
+            Fq::from_montgomery_limbs([
+                2057869831694122724,
+                12477340821151182544,
+                13809321411093434380,
+                1011579930647609340,
+            ]),
+            Fq::from_montgomery_limbs([
+                11270590241726523728,
+                4719901765205555002,
+                13736946184487400376,
+                1282090817715852644,
+            ]),
+            Fq::from_montgomery_limbs([
+                1070158763551979893,
+                12068074583257126621,
+                18345834547790110425,
+                28209531325185691,
+            ]),
+            Fq::from_montgomery_limbs([
+                16260897952382150271,
+                1716115149843624799,
+                9757028932142614533,
+                842915927793472848,
+            ]),
+            Fq::from_montgomery_limbs([
+                14308236322086281861,
+                15712908036323460278,
+                1351401899633213915,
+                340919501600681873,
+            ]),
+            Fq::from_montgomery_limbs([
+                17736908920768936551,
+                1787393817240615549,
+                243237715497748729,
+                828334889459001665,
+            ]),
+            Fq::from_montgomery_limbs([
+                482910653522672787,
+                9558164645914191514,
+                10067110190160849556,
+                538902548515356755,
+            ]),
+            Fq::from_montgomery_limbs([
+                2832558030751212093,
+                17900766554968747712,
+                12671272984089813694,
+                4661331360671921,
+            ]),
+            Fq::from_montgomery_limbs([
+                7227403405816654310,
+                66765451773816375,
+                4716689251157851395,
+                992338580400250878,
+            ]),
+            Fq::from_montgomery_limbs([
+                6578725054968546821,
+                14290782911632252911,
+                13242729617418224432,
+                773807539342764566,
+            ]),
+            Fq::from_montgomery_limbs([
+                1487360967731755220,
+                10169487323172008569,
+                9054621129034913349,
+                902957745768945479,
+            ]),
+            Fq::from_montgomery_limbs([
+                17203978388903275917,
+                361404075812907366,
+                3445418271958565170,
+                1309404973473188396,
+            ]),
+            Fq::from_montgomery_limbs([
+                5798603325797114309,
+                17346718336008503294,
+                2013999946192016085,
+                233552291612418372,
+            ]),
+            Fq::from_montgomery_limbs([
+                5402259823474250649,
+                6597235492417444903,
+                682705481101123685,
+                1152725773469681775,
+            ]),
+            Fq::from_montgomery_limbs([
+                11802086086658682555,
+                17935799885096844036,
+                1482815265941868116,
+                1250081886917033701,
+            ]),
+            Fq::from_montgomery_limbs([
+                18108106531639457363,
+                10675403757404957228,
+                15698589387916744577,
+                975795424050146953,
+            ]),
+            Fq::from_montgomery_limbs([
+                17249047197029728911,
+                11931545877796072313,
+                12070462069515142094,
+                188907843076506362,
+            ]),
+            Fq::from_montgomery_limbs([
+                17324219273232285813,
+                2484196863860418154,
+                16408236147539109782,
+                144072220482484710,
+            ]),
+            Fq::from_montgomery_limbs([
+                9547831324487937905,
+                3826028683204706817,
+                14170302908234994989,
+                1124583990230299977,
+            ]),
+            Fq::from_montgomery_limbs([
+                529346787655531155,
+                17773749366102988573,
+                5092966964364739263,
+                698896792690035088,
+            ]),
+            Fq::from_montgomery_limbs([
+                6602609749619840751,
+                13455699645990338363,
+                2497963125273905153,
+                196688979997919192,
+            ]),
+            Fq::from_montgomery_limbs([
+                550842214854767718,
+                11409967240534726849,
+                4868518083835366312,
+                1150465108529259292,
+            ]),
+            Fq::from_montgomery_limbs([
+                4752335195727336364,
+                14834549185999853767,
+                18140006269479809864,
+                104060642909896759,
+            ]),
+            Fq::from_montgomery_limbs([
+                9910419247302128828,
+                14332013254573593794,
+                13200936615504569595,
+                1079605149815938122,
+            ]),
+            Fq::from_montgomery_limbs([
+                16689471403916549953,
+                6942453935030726926,
+                6929145310572747687,
+                97022442224688452,
+            ]),
+            Fq::from_montgomery_limbs([
+                16775093380665056147,
+                11245783028657380315,
+                8779891332991361156,
+                1043096808808035798,
+            ]),
+            Fq::from_montgomery_limbs([
+                8465385479613054252,
+                12406961874946308373,
+                15971840845278596625,
+                1150777425525437641,
+            ]),
+            Fq::from_montgomery_limbs([
+                5102580745488142066,
+                9445008755652102990,
+                15714487812279075934,
+                1224755842664510380,
+            ]),
+            Fq::from_montgomery_limbs([
+                10064549233714733817,
+                9656224996343820462,
+                17924889342912671373,
+                1251972994544543902,
+            ]),
+            Fq::from_montgomery_limbs([
+                15351963636151958307,
+                5118365419540209699,
+                14818309034164273947,
+                1060973780757891566,
+            ]),
+            Fq::from_montgomery_limbs([
+                11930267955086349791,
+                7818936522485300500,
+                143978372351745132,
+                925433050941108840,
+            ]),
+            Fq::from_montgomery_limbs([
+                9991001764738519068,
+                16279459797369121914,
+                13967886735977531557,
+                162917603398961210,
+            ]),
+            Fq::from_montgomery_limbs([
+                988731380160541873,
+                13793052131648584072,
+                14531087939224096600,
+                1151138366067516729,
+            ]),
+            Fq::from_montgomery_limbs([
+                9438227424447533900,
+                13392113799269378260,
+                7365162157712503901,
+                139156067181615669,
+            ]),
+            Fq::from_montgomery_limbs([
+                6345968497011243248,
+                7322652268546802167,
+                12931451889440260018,
+                447350275897711344,
+            ]),
+            Fq::from_montgomery_limbs([
+                9461614178361185394,
+                14854718479288785693,
+                6404251011804288624,
+                553507189958859825,
+            ]),
+            Fq::from_montgomery_limbs([
+                8167686787269690125,
+                4045708253320858134,
+                12211574448223770818,
+                945847890506200753,
+            ]),
+            Fq::from_montgomery_limbs([
+                8928166721975193876,
+                6778531527032011746,
+                11028105442838154605,
+                1063223091462546580,
+            ]),
+            Fq::from_montgomery_limbs([
+                2775498123852483283,
+                1123855909461292561,
+                18014906626275203784,
+                1127933708763678761,
+            ]),
+            Fq::from_montgomery_limbs([
+                2162501121573947647,
+                20437034085398193,
+                610695700490629280,
+                769592741752131976,
+            ]),
+            Fq::from_montgomery_limbs([
+                211627366940257489,
+                7696983144455834891,
+                9657128160771089596,
+                99787675177599096,
+            ]),
+            Fq::from_montgomery_limbs([
+                7042148202249745205,
+                6327161850496210893,
+                8216918014106977391,
+                1178522704442987292,
+            ]),
+            Fq::from_montgomery_limbs([
+                15954169377002811185,
+                16927119210591463374,
+                11632115528233492964,
+                372915312509766778,
+            ]),
+            Fq::from_montgomery_limbs([
+                5636622393178496430,
+                18022012259548018792,
+                18086752589947748937,
+                356437284897431757,
+            ]),
+            Fq::from_montgomery_limbs([
+                5096624932432237910,
+                8057206882311697490,
+                14830877867850433239,
+                348118141806882441,
+            ]),
+            Fq::from_montgomery_limbs([
+                11403910752673624586,
+                4458188153551500155,
+                11285939413981447126,
+                407570105603428000,
+            ]),
+            Fq::from_montgomery_limbs([
+                14771745659330022508,
+                4887134989859389769,
+                2177395666725610803,
+                1318420227897333114,
+            ]),
+            Fq::from_montgomery_limbs([
+                15357827734978337637,
+                6010124791407230462,
+                10774941017717888561,
+                444081693413519969,
+            ]),
+            Fq::from_montgomery_limbs([
+                14466225601644500488,
+                1550298447952896546,
+                3883552049054432129,
+                468096298849142440,
+            ]),
+            Fq::from_montgomery_limbs([
+                9625929992975937720,
+                10301209100657805451,
+                2886835073302436277,
+                76914372755090066,
+            ]),
+            Fq::from_montgomery_limbs([
+                596442442394908524,
+                151550652123162264,
+                12570036788357017207,
+                65325199413142065,
+            ]),
+            Fq::from_montgomery_limbs([
+                17915555548536227792,
+                2225298957446912237,
+                16310248507019418605,
+                139302291267630134,
+            ]),
+            Fq::from_montgomery_limbs([
+                6495349820499546840,
+                7614125492700703819,
+                1543366738160864609,
+                451026299051783182,
+            ]),
+            Fq::from_montgomery_limbs([
+                14693416952617328031,
+                17338823648568813696,
+                5381527405497165309,
+                739431763390565884,
+            ]),
+            Fq::from_montgomery_limbs([
+                3914191557723102701,
+                17641002463163576997,
+                1371813683971842757,
+                755375850966929111,
+            ]),
+            Fq::from_montgomery_limbs([
+                866616855356842552,
+                8209883492908823887,
+                613238617417875418,
+                4115823089304019,
+            ]),
+            Fq::from_montgomery_limbs([
+                14302899768311743756,
+                4402823233790319842,
+                13681324285203338532,
+                744820851947696616,
+            ]),
+            Fq::from_montgomery_limbs([
+                8995127897967461471,
+                697130117041225775,
+                17017582463178876212,
+                1047345097854025392,
+            ]),
+            Fq::from_montgomery_limbs([
+                11575731185637935288,
+                10051215131328765321,
+                8035550699626999520,
+                286793440386996519,
+            ]),
+            Fq::from_montgomery_limbs([
+                11490687106595548923,
+                3772727035673416858,
+                15825535596880609776,
+                1182013166621332556,
+            ]),
+            Fq::from_montgomery_limbs([
+                7349980888362737407,
+                1762770494823353717,
+                8138723167364594057,
+                8427105749683750,
+            ]),
+            Fq::from_montgomery_limbs([
+                17579845271571473582,
+                15892448770943089421,
+                4271619932069314806,
+                362224599297867581,
+            ]),
+            Fq::from_montgomery_limbs([
+                5062553285284155507,
+                1167890169143722968,
+                2631126289139407994,
+                656057472346722434,
+            ]),
+            Fq::from_montgomery_limbs([
+                11220682524654218268,
+                6762669840093932950,
+                10257458239219956111,
+                957643085305282870,
+            ]),
+            Fq::from_montgomery_limbs([
+                18261115296605841806,
+                1438664517879155484,
+                2849173493744175806,
+                553121176089503369,
+            ]),
+            Fq::from_montgomery_limbs([
+                7149794764465943710,
+                4119532812047150652,
+                2747588485578433996,
+                827667156876839144,
+            ]),
+            Fq::from_montgomery_limbs([
+                3730480885866530641,
+                15042867186842421403,
+                11122208013987182861,
+                1243676474557793731,
+            ]),
+            Fq::from_montgomery_limbs([
+                7839791956272988757,
+                5365346702080997394,
+                3607729601267415989,
+                653604641078315032,
+            ]),
+            Fq::from_montgomery_limbs([
+                9295485671622950853,
+                10854924445964150901,
+                13966568828848686882,
+                1264356884172335626,
+            ]),
+            Fq::from_montgomery_limbs([
+                11075620291533893058,
+                2948418424483018777,
+                13242348308920546382,
+                899826372800496334,
+            ]),
+            Fq::from_montgomery_limbs([
+                14531075220562562917,
+                7960736273413956114,
+                10891824378170997256,
+                444960791521560295,
+            ]),
+            Fq::from_montgomery_limbs([
+                13805561027130135140,
+                17996905630807014656,
+                1228046048451171809,
+                1304572894098791934,
+            ]),
+            Fq::from_montgomery_limbs([
+                186361738335071927,
+                13285290238390443768,
+                7738270276917419876,
+                735031667414986639,
+            ]),
+            Fq::from_montgomery_limbs([
+                11450044268473868066,
+                2286557492916491223,
+                11766023079882329038,
+                1240247402671164490,
+            ]),
+            Fq::from_montgomery_limbs([
+                15824634647132682959,
+                13680544893186904119,
+                3835119654844258500,
+                746939403264189390,
+            ]),
+            Fq::from_montgomery_limbs([
+                1807404234086317401,
+                4360841207667730351,
+                4765473632937019100,
+                11435502824515036,
+            ]),
+            Fq::from_montgomery_limbs([
+                14331442844455812190,
+                6138582407021786198,
+                10980579016166149027,
+                866758258988482496,
+            ]),
+            Fq::from_montgomery_limbs([
+                10022345952446305376,
+                15708536397876764566,
+                13295286612793504286,
+                1273302828942747892,
+            ]),
+            Fq::from_montgomery_limbs([
+                10286132943972686052,
+                9665680197265809249,
+                1438268839759327609,
+                1273795921924493048,
+            ]),
+            Fq::from_montgomery_limbs([
+                8730697398915550303,
+                3147382101646264883,
+                653310219001665994,
+                805538635577530986,
+            ]),
+            Fq::from_montgomery_limbs([
+                15636223798598690383,
+                16082695105910678656,
+                17356497980440910968,
+                1142793351953861714,
+            ]),
+            Fq::from_montgomery_limbs([
+                4600895304231051591,
+                2939592020027771066,
+                8554726496612769339,
+                183780034882072759,
+            ]),
+            Fq::from_montgomery_limbs([
+                7201756799831509824,
+                1517899833505459478,
+                17913057788417993202,
+                638846297660606987,
+            ]),
+            Fq::from_montgomery_limbs([
+                4780825098628573911,
+                5095764738215506238,
+                15420859975604461239,
+                504497535387846118,
+            ]),
+            Fq::from_montgomery_limbs([
+                3255102055393657158,
+                11972428182212867878,
+                10098723659353755794,
+                1002176521058759054,
+            ]),
+            Fq::from_montgomery_limbs([
+                16497172186609274162,
+                9711955468385012854,
+                17794335694556852116,
+                1206299782288690191,
+            ]),
+            Fq::from_montgomery_limbs([
+                16564463519034085012,
+                8339522885395990325,
+                4555718772993904974,
+                31678176279353525,
+            ]),
+            Fq::from_montgomery_limbs([
+                1466613317404446043,
+                17371283616392160369,
+                2207212062042179153,
+                230801986610908399,
+            ]),
+            Fq::from_montgomery_limbs([
+                3769211155719678114,
+                9056859320579584346,
+                9005708060206805603,
+                758343029867112783,
+            ]),
+            Fq::from_montgomery_limbs([
+                7131341612301275536,
+                4260082037311548528,
+                9653334330692169202,
+                1139060068229131887,
+            ]),
+            Fq::from_montgomery_limbs([
+                12986479288800043947,
+                12342019614235418386,
+                15292423744704305198,
+                9607646406659920,
+            ]),
+            Fq::from_montgomery_limbs([
+                5604869946730685500,
+                13684603447615092194,
+                6009824343766103820,
+                324921702214154942,
+            ]),
+            Fq::from_montgomery_limbs([
+                14023052982514561159,
+                8687582659478694902,
+                8010600312897494631,
+                1106559565883082763,
+            ]),
+            Fq::from_montgomery_limbs([
+                17522065887356748524,
+                16082104871946378620,
+                12602014702884010616,
+                1192088284310284238,
+            ]),
+            Fq::from_montgomery_limbs([
+                6127849793129718313,
+                5326835803974347036,
+                956531402986673955,
+                347453748546516275,
+            ]),
+            Fq::from_montgomery_limbs([
+                6675000523633148625,
+                2138450390950046886,
+                16274824789462726151,
+                1008526358862558440,
+            ]),
+            Fq::from_montgomery_limbs([
+                13889129311292718823,
+                18355915379918417193,
+                5056925190028187854,
+                274498305839986228,
+            ]),
+            Fq::from_montgomery_limbs([
+                1898796316369440534,
+                13542848505777828736,
+                7788668021420266233,
+                961523631908717847,
+            ]),
+            Fq::from_montgomery_limbs([
+                3594290994382339761,
+                4159736198328620155,
+                10789037397690635572,
+                131284879014584822,
+            ]),
+            Fq::from_montgomery_limbs([
+                9514858297533417507,
+                7886299790602351514,
+                16243041596432959471,
+                252450292848449694,
+            ]),
+            Fq::from_montgomery_limbs([
+                8190477683921832524,
+                1942192143847179425,
+                6466570519261660646,
+                1112571712798942762,
+            ]),
+            Fq::from_montgomery_limbs([
+                719588898383645242,
+                1222229083142174821,
+                14872563196450113852,
+                637845485615415416,
+            ]),
+            Fq::from_montgomery_limbs([
+                5581455699569322029,
+                3618078820375824812,
+                4318937349197113018,
+                453874886887272470,
+            ]),
+            Fq::from_montgomery_limbs([
+                14607502597065094612,
+                11817253810380598257,
+                12694016054072355442,
+                521436078377737128,
+            ]),
+            Fq::from_montgomery_limbs([
+                8667689148468046675,
+                7519540815471798079,
+                2186382712493072204,
+                575611648655910700,
+            ]),
+            Fq::from_montgomery_limbs([
+                11267396566337798541,
+                7958445590626667997,
+                13534111627305999546,
+                784758540812162824,
+            ]),
+            Fq::from_montgomery_limbs([
+                15494941736782801380,
+                13774814957343181172,
+                8995269650057340862,
+                1126306413805576760,
+            ]),
+            Fq::from_montgomery_limbs([
+                6617242936310264255,
+                18412010868274882710,
+                16005280415006509139,
+                1051946774527021121,
+            ]),
+            Fq::from_montgomery_limbs([
+                7245643058561843480,
+                15876013788574687197,
+                11375675731603639067,
+                565798027001925205,
+            ]),
+            Fq::from_montgomery_limbs([
+                15488969785004417774,
+                14319138450014048524,
+                2198790609612634682,
+                70972751753789762,
+            ]),
+            Fq::from_montgomery_limbs([
+                16983868383621764639,
+                16931128254392266990,
+                368288521045216948,
+                602601142467178913,
+            ]),
+            Fq::from_montgomery_limbs([
+                14844645871915696373,
+                8584509860513661851,
+                6614100047226526528,
+                719281470314127943,
+            ]),
+            Fq::from_montgomery_limbs([
+                9310297820181972765,
+                476579770498710954,
+                6119759142917607652,
+                1050971223433187042,
+            ]),
+            Fq::from_montgomery_limbs([
+                6368238331645387717,
+                3219819489265177258,
+                16201326978542163873,
+                84588416869804589,
+            ]),
+            Fq::from_montgomery_limbs([
+                4194667335671926675,
+                15181338939927785416,
+                3914301085777902590,
+                942148255704514699,
+            ]),
+            Fq::from_montgomery_limbs([
+                5051208793594721951,
+                16853741791712313938,
+                12515195095646630925,
+                872305623874050742,
+            ]),
+            Fq::from_montgomery_limbs([
+                5393269749195085850,
+                4331001158215290621,
+                9898233517022449670,
+                1448388285780537,
+            ]),
+            Fq::from_montgomery_limbs([
+                12378927505097498808,
+                16857620950800052164,
+                8082320528729146902,
+                1271316407783947675,
+            ]),
+            Fq::from_montgomery_limbs([
+                9455334034152904716,
+                2752399020015774384,
+                15939779229896919646,
+                273830600014930942,
+            ]),
+            Fq::from_montgomery_limbs([
+                14163542023366422421,
+                10222150665788411239,
+                4804904828626905220,
+                243512779500157793,
+            ]),
+            Fq::from_montgomery_limbs([
+                8799371001833437306,
+                14311244837797425468,
+                16602858465618827437,
+                73174041007029202,
+            ]),
+            Fq::from_montgomery_limbs([
+                12967348296601298221,
+                9796050595306055711,
+                673762011543782041,
+                1221936401243659817,
+            ]),
+            Fq::from_montgomery_limbs([
+                4697931167201939634,
+                13073254018064369734,
+                16602363824773418642,
+                29829229213258810,
+            ]),
+            Fq::from_montgomery_limbs([
+                3392987553194187721,
+                15461448685696232872,
+                9255157032833866097,
+                126116766070962008,
+            ]),
+            Fq::from_montgomery_limbs([
+                5661162044487943050,
+                2551672305101585983,
+                7920902498885931207,
+                609821793811327426,
+            ]),
+            Fq::from_montgomery_limbs([
+                7707111749423139522,
+                589142762671476544,
+                5388500911587650188,
+                711964506385044057,
+            ]),
+            Fq::from_montgomery_limbs([
+                1118838445651778860,
+                8693197028077524014,
+                16792497590321631959,
+                345422359311725149,
+            ]),
+            Fq::from_montgomery_limbs([
+                16125444778498900249,
+                15720228372083110670,
+                9001857847045203117,
+                840207422338639655,
+            ]),
+            Fq::from_montgomery_limbs([
+                12288219248149202567,
+                17147811292392610592,
+                16843019432781795756,
+                1117935187702727322,
+            ]),
+            Fq::from_montgomery_limbs([
+                11195247900565987332,
+                14436416646591167916,
+                10993943419699625479,
+                534721428090615703,
+            ]),
+            Fq::from_montgomery_limbs([
+                14346163408860900189,
+                8238022000746153714,
+                4689615889503918278,
+                1197421989331907923,
+            ]),
+            Fq::from_montgomery_limbs([
+                12690708924205030019,
+                11234403626380473087,
+                8186158240623374981,
+                714024101115112205,
+            ]),
+            Fq::from_montgomery_limbs([
+                8075649499226806064,
+                12871266571282856038,
+                11613728774391578072,
+                295682239673624684,
+            ]),
+            Fq::from_montgomery_limbs([
+                8320382245156381624,
+                17079791140146870296,
+                14767410330124591761,
+                1012851545594178798,
+            ]),
+            Fq::from_montgomery_limbs([
+                10150528966276489217,
+                11631960939578771917,
+                2868226590707624128,
+                787879376894682618,
+            ]),
+            Fq::from_montgomery_limbs([
+                5341895866258379780,
+                2491453801967776368,
+                15501055133938126244,
+                961700246728663110,
+            ]),
+            Fq::from_montgomery_limbs([
+                4550267217379075859,
+                12436102956836734461,
+                12554798016847021050,
+                46105561594396106,
+            ]),
+            Fq::from_montgomery_limbs([
+                8327240986691960537,
+                3121208116280514630,
+                17593996858787842529,
+                289375515021537441,
+            ]),
+            Fq::from_montgomery_limbs([
+                1642724616417380096,
+                12227266912664738777,
+                17250027366369344909,
+                414853012495512165,
+            ]),
+            Fq::from_montgomery_limbs([
+                4775615156358305829,
+                13798496011997931960,
+                4165667169267979768,
+                298052756692521038,
+            ]),
+            Fq::from_montgomery_limbs([
+                17464480211497688294,
+                657920738621307550,
+                1346824960571906316,
+                1318646698349130195,
+            ]),
+            Fq::from_montgomery_limbs([
+                7886938141046588884,
+                1639572961496412068,
+                9608614712809060531,
+                1262949683123759037,
+            ]),
+            Fq::from_montgomery_limbs([
+                673518918497973348,
+                17556366404956602829,
+                13144237213677920780,
+                523428235312191108,
+            ]),
+            Fq::from_montgomery_limbs([
+                11489971488303827468,
+                5731830473864786555,
+                13314867850635896251,
+                643622823987448580,
+            ]),
+            Fq::from_montgomery_limbs([
+                5569503028350696199,
+                2714981478469425258,
+                6412632840142898275,
+                290568633560213807,
+            ]),
+            Fq::from_montgomery_limbs([
+                18212453567267763770,
+                5320307765466691500,
+                15712189870192167020,
+                609416443083949850,
+            ]),
+            Fq::from_montgomery_limbs([
+                16356129164027852398,
+                16988264205541252249,
+                3332717657822056903,
+                241214125803824381,
+            ]),
+            Fq::from_montgomery_limbs([
+                14447657416669247425,
+                6351011184772457867,
+                10289797130780467255,
+                692337857930096696,
+            ]),
+            Fq::from_montgomery_limbs([
+                13808441674864633722,
+                4977754138469711428,
+                18114772153931252279,
+                502572075845259016,
+            ]),
+            Fq::from_montgomery_limbs([
+                6055425215518533468,
+                16766589028499868960,
+                5301127936724716825,
+                837118284573063056,
+            ]),
+            Fq::from_montgomery_limbs([
+                11118711363112248545,
+                13435475618535777662,
+                7114652465912135248,
+                357520653840344003,
+            ]),
+            Fq::from_montgomery_limbs([
+                8518964828406631478,
+                11589509648912490327,
+                16502765301005472337,
+                129927111901205533,
+            ]),
+            Fq::from_montgomery_limbs([
+                13448474466373127036,
+                13123617170389672316,
+                16379370674701566365,
+                929787103396922468,
+            ]),
+            Fq::from_montgomery_limbs([
+                1738739124556186103,
+                18266579779363342855,
+                6075996662720002114,
+                1007115751041056261,
+            ]),
+            Fq::from_montgomery_limbs([
+                13551282127849005071,
+                3316574571410699189,
+                15962760025305314144,
+                1014142878614121433,
+            ]),
+            Fq::from_montgomery_limbs([
+                15079017853871469877,
+                7869316777896277462,
+                18108546432941746359,
+                669599196586634170,
+            ]),
+            Fq::from_montgomery_limbs([
+                18144241325276828844,
+                596511055891181143,
+                1132020280056200321,
+                426909074820880657,
+            ]),
+            Fq::from_montgomery_limbs([
+                2440604849911420482,
+                10839734534974232569,
+                12777952009745490681,
+                647131387446795083,
+            ]),
+            Fq::from_montgomery_limbs([
+                4956128563955808717,
+                10492630684482708363,
+                5455435218449470828,
+                1279466486864407564,
+            ]),
+            Fq::from_montgomery_limbs([
+                11427858446768457318,
+                9763070805916217976,
+                3971835549872888602,
+                408865961645600728,
+            ]),
+            Fq::from_montgomery_limbs([
+                16548963155219688942,
+                9459838708497978250,
+                12778634980366682917,
+                830417171783659779,
+            ]),
+            Fq::from_montgomery_limbs([
+                5916588896385258087,
+                1959003040638021981,
+                9613531634074153957,
+                883979752189660954,
+            ]),
+            Fq::from_montgomery_limbs([
+                14457450003918555283,
+                14396493974773243699,
+                11245385427216221476,
+                72986059221405555,
+            ]),
+            Fq::from_montgomery_limbs([
+                4563257268182697781,
+                3993650376370378831,
+                15751031331350238876,
+                620274399218079735,
+            ]),
+            Fq::from_montgomery_limbs([
+                7118272744485319335,
+                5754897124671879056,
+                14785270706478959577,
+                40609595562627248,
+            ]),
+            Fq::from_montgomery_limbs([
+                14573181666636601577,
+                9735895116994616978,
+                5478891310851424753,
+                847242544341138009,
+            ]),
+            Fq::from_montgomery_limbs([
+                5010899240180717767,
+                12556502725714131648,
+                3273475747930030000,
+                962578434744560126,
+            ]),
+            Fq::from_montgomery_limbs([
+                13396340282629155558,
+                11411782470642910282,
+                12487732739543495961,
+                943364541298570286,
+            ]),
+            Fq::from_montgomery_limbs([
+                5816815098870831913,
+                10128226560507592047,
+                9799779112717368417,
+                1068146369859396051,
+            ]),
+            Fq::from_montgomery_limbs([
+                12178677090076606572,
+                7960605475551144045,
+                8275094483559938496,
+                471133921249493801,
+            ]),
+            Fq::from_montgomery_limbs([
+                12299338631767322164,
+                3234652161299436200,
+                15316027553372187182,
+                284370786435360259,
+            ]),
+            Fq::from_montgomery_limbs([
+                565656235338457464,
+                14970700158491259212,
+                14875619168280311945,
+                827528639070207533,
+            ]),
+            Fq::from_montgomery_limbs([
+                2433896116097837948,
+                14902880641354306953,
+                1461201368386194535,
+                271229628948846893,
+            ]),
+            Fq::from_montgomery_limbs([
+                8996584504006568748,
+                10914815457227482563,
+                5052910807560154374,
+                769941215828364346,
+            ]),
+            Fq::from_montgomery_limbs([
+                15228006364602086151,
+                1677520453249197160,
+                14287646120001413093,
+                573728375570638876,
+            ]),
+            Fq::from_montgomery_limbs([
+                4120794279571208791,
+                14632457805851349129,
+                9399525977857155640,
+                621003024132544717,
+            ]),
+            Fq::from_montgomery_limbs([
+                10814511837762148459,
+                8864461441672149707,
+                14582223589329492293,
+                688446737982217996,
+            ]),
+            Fq::from_montgomery_limbs([
+                15885362989032833823,
+                14633454093044750512,
+                17260961015781321709,
+                834248142741158732,
+            ]),
+            Fq::from_montgomery_limbs([
+                16167317256420094937,
+                2878783025529416947,
+                13050649977586308440,
+                586886002368908907,
+            ]),
+            Fq::from_montgomery_limbs([
+                9480974269118412929,
+                2812333727504601930,
+                3175882674015393159,
+                285339909401492602,
+            ]),
+            Fq::from_montgomery_limbs([
+                11926406720750548227,
+                6080979225312651145,
+                14118878266794952441,
+                882796618542617262,
+            ]),
+            Fq::from_montgomery_limbs([
+                2185083485603071707,
+                12705330107780180480,
+                18043044726321371393,
+                812824760996355856,
+            ]),
+            Fq::from_montgomery_limbs([
+                10955404947916189107,
+                10045194761974946750,
+                13315938052328806197,
+                504543025345551704,
+            ]),
+            Fq::from_montgomery_limbs([
+                1489930333587433747,
+                7024943005121295700,
+                14394107849441755299,
+                1042177164631851139,
+            ]),
+            Fq::from_montgomery_limbs([
+                17237804575931937443,
+                10838934333896801527,
+                2593276381034818897,
+                795207625643495491,
+            ]),
+            Fq::from_montgomery_limbs([
+                16729813623554570845,
+                12023698941673873521,
+                6894288461566483199,
+                1116868617538454828,
+            ]),
+            Fq::from_montgomery_limbs([
+                1584238414857965357,
+                14573544968996428546,
+                17555426924663088365,
+                1081234382169457903,
+            ]),
+            Fq::from_montgomery_limbs([
+                1449971972432119864,
+                925264126829250884,
+                5810692250282083260,
+                716392023292272352,
+            ]),
+            Fq::from_montgomery_limbs([
+                14597190195819992593,
+                17161868163606781457,
+                1611007410012868763,
+                55758515146172946,
+            ]),
+            Fq::from_montgomery_limbs([
+                14565448486095737212,
+                18370323466648198772,
+                6413830673043922449,
+                573950615246639708,
+            ]),
+            Fq::from_montgomery_limbs([
+                11506080774134537681,
+                8878861630030729011,
+                914870071053632140,
+                529116128848048981,
+            ]),
+            Fq::from_montgomery_limbs([
+                17741297537288600438,
+                8690841471335947208,
+                3987355988552034000,
+                1344769611165161018,
+            ]),
+            Fq::from_montgomery_limbs([
+                2091432997033325738,
+                10643708228160069522,
+                8433490785696679569,
+                1301228125938297476,
+            ]),
+            Fq::from_montgomery_limbs([
+                5442596161088316339,
+                8378709939005668140,
+                17487032356399488121,
+                801575994990473928,
+            ]),
+            Fq::from_montgomery_limbs([
+                16448353904001710265,
+                7344499989841597073,
+                6652462903821373836,
+                1324955435614153051,
+            ]),
+            Fq::from_montgomery_limbs([
+                7755220901970111909,
+                12957986010614883086,
+                14305108208258743818,
+                863670821107886379,
+            ]),
+            Fq::from_montgomery_limbs([
+                13239294092063434626,
+                1216552873884816653,
+                14112959395875727379,
+                1085782865528559803,
+            ]),
+            Fq::from_montgomery_limbs([
+                18181391896311943214,
+                6807092764958569109,
+                7897434092803614780,
+                730218926796917114,
+            ]),
+            Fq::from_montgomery_limbs([
+                12943602117089949529,
+                16415396652254023472,
+                17974262227911021129,
+                772445397398680754,
+            ]),
+            Fq::from_montgomery_limbs([
+                15253620891524771855,
+                12935781103678975131,
+                6964489276726204184,
+                77664979400564718,
+            ]),
+            Fq::from_montgomery_limbs([
+                17453216229439341104,
+                6547403434939162801,
+                3704188765618833078,
+                962550856433902995,
+            ]),
+            Fq::from_montgomery_limbs([
+                1525226226782045040,
+                4729806575334362957,
+                5540076269624136116,
+                221206335003531951,
+            ]),
+            Fq::from_montgomery_limbs([
+                2540876731247772666,
+                794242417034778794,
+                4715638075007025835,
+                12256346742701812,
+            ]),
+            Fq::from_montgomery_limbs([
+                4177215969088512206,
+                14372235563575024255,
+                297188269044010487,
+                263652534222250540,
+            ]),
+            Fq::from_montgomery_limbs([
+                13492346804739136130,
+                4642342109599832535,
+                16365605219940481051,
+                109868103012464343,
+            ]),
+            Fq::from_montgomery_limbs([
+                9878675069415390943,
+                11735015280142127488,
+                8050431366281737195,
+                1042766937207434198,
+            ]),
+            Fq::from_montgomery_limbs([
+                616365837710365801,
+                17005349719014272316,
+                8043731594443787271,
+                191421304766749501,
+            ]),
+            Fq::from_montgomery_limbs([
+                16593664816951683394,
+                2612725538852490048,
+                3009279920251674226,
+                1158537257411338390,
+            ]),
+            Fq::from_montgomery_limbs([
+                6553512748929906431,
+                7516945222836118106,
+                10665869700121112820,
+                1274104752378502222,
+            ]),
+            Fq::from_montgomery_limbs([
+                10867671924613421052,
+                7956714959850103715,
+                5558368232661171701,
+                353551187299654880,
+            ]),
+            Fq::from_montgomery_limbs([
+                3649916203826250519,
+                11178054177983582480,
+                15442605256299069281,
+                845603973915388462,
+            ]),
+            Fq::from_montgomery_limbs([
+                3831859008568506387,
+                14873616549891961180,
+                4475010821121564596,
+                583721411078668134,
+            ]),
+            Fq::from_montgomery_limbs([
+                4120157565553719879,
+                8783008919750414854,
+                17770081691175008773,
+                1244839826922789254,
+            ]),
+            Fq::from_montgomery_limbs([
+                16862516498912383648,
+                8135507864370251831,
+                13590138885769320461,
+                90702363941968090,
+            ]),
+            Fq::from_montgomery_limbs([
+                16113970117879301018,
+                16285125560441270265,
+                9008826516923904304,
+                1138299524869425435,
+            ]),
+            Fq::from_montgomery_limbs([
+                8694697246672083275,
+                9632891636636166723,
+                183964856104539214,
+                999473366408311461,
+            ]),
+            Fq::from_montgomery_limbs([
+                10102966203480932572,
+                856136850047586198,
+                15995577744475787562,
+                1039743431120823239,
+            ]),
+            Fq::from_montgomery_limbs([
+                14754874319513876901,
+                10652704661863093104,
+                17906383230292704928,
+                852368761286183714,
+            ]),
+            Fq::from_montgomery_limbs([
+                5213156776656258522,
+                1714018377053509908,
+                13757742866099532672,
+                738775145109211739,
+            ]),
+            Fq::from_montgomery_limbs([
+                10938149654588062970,
+                3159553745061648306,
+                1221481683429465090,
+                608615413987430387,
+            ]),
+            Fq::from_montgomery_limbs([
+                9961389809839574516,
+                12466749622915432913,
+                7184382207823410897,
+                100780774411826810,
+            ]),
+            Fq::from_montgomery_limbs([
+                10796221850884029771,
+                8191664107344827917,
+                18054316646074697984,
+                1032363859796537955,
+            ]),
+            Fq::from_montgomery_limbs([
+                13936668949970671136,
+                15273808478813605046,
+                10733947461270813702,
+                877082081440637162,
+            ]),
+            Fq::from_montgomery_limbs([
+                13706650496839866105,
+                6869476721919601616,
+                10591401106031353177,
+                963039253124407658,
+            ]),
+            Fq::from_montgomery_limbs([
+                5849661629510360915,
+                3179334858260925451,
+                180639057290897307,
+                1106590462882586807,
+            ]),
+            Fq::from_montgomery_limbs([
+                6709388534842644811,
+                15136044864386562208,
+                6790418267707083036,
+                972944003172177958,
+            ]),
+            Fq::from_montgomery_limbs([
+                12020747612121081702,
+                3575568464965495687,
+                9484710923710731693,
+                417902200139668573,
+            ]),
+            Fq::from_montgomery_limbs([
+                13040304861770004613,
+                16945894772246152171,
+                2776029997537300520,
+                1191111069453676970,
+            ]),
+            Fq::from_montgomery_limbs([
+                8200323124620941806,
+                10606653645179550965,
+                8284270719030109988,
+                1167284878043663764,
+            ]),
+            Fq::from_montgomery_limbs([
+                5117300799082874429,
+                5429008104385226938,
+                7171951806473424348,
+                749056225567399042,
+            ]),
+            Fq::from_montgomery_limbs([
+                8291902892824220403,
+                5835382530531166928,
+                7031543445321000608,
+                53710638016545368,
+            ]),
+            Fq::from_montgomery_limbs([
+                9874855625675748699,
+                15235950245421961579,
+                5290814031163926902,
+                592962855033587745,
+            ]),
+            Fq::from_montgomery_limbs([
+                2190513925966544464,
+                13611021060634558260,
+                17811334663945195503,
+                913988757786521088,
+            ]),
+            Fq::from_montgomery_limbs([
+                8658428903310375305,
+                14562546131001173573,
+                3484623369951708274,
+                535673449210635680,
+            ]),
+            Fq::from_montgomery_limbs([
+                1427858536595974471,
+                15626455016331677244,
+                182201629706974064,
+                1014001920863214572,
+            ]),
+            Fq::from_montgomery_limbs([
+                15326393711413038069,
+                4933675927354350933,
+                12194766214907312203,
+                402760571721365030,
+            ]),
+            Fq::from_montgomery_limbs([
+                5924765666212620414,
+                6194633077891042034,
+                17217349475732092980,
+                590105992871382224,
+            ]),
+            Fq::from_montgomery_limbs([
+                9545366877007806060,
+                15321339450411014557,
+                2320247128737033182,
+                1125683706307066883,
+            ]),
+            Fq::from_montgomery_limbs([
+                12895632249187589442,
+                3677366482297408190,
+                14567226675016481630,
+                614712110081375917,
+            ]),
+            Fq::from_montgomery_limbs([
+                14509066546789518061,
+                12488376489434090866,
+                10612003147955721474,
+                221360235631016367,
+            ]),
+            Fq::from_montgomery_limbs([
+                17332860944277577516,
+                16428199470176497884,
+                13016068633987943435,
+                90375857914123020,
+            ]),
+            Fq::from_montgomery_limbs([
+                16751205343140141186,
+                4569602756416341348,
+                13994626156996283005,
+                424723784365183233,
+            ]),
+            Fq::from_montgomery_limbs([
+                12957065725021777296,
+                8185800021314684052,
+                5722189325203823349,
+                929748222473873834,
+            ]),
+            Fq::from_montgomery_limbs([
+                17169350634625026832,
+                8634424228595166011,
+                6689397359458030397,
+                1298693667342011910,
+            ]),
+            Fq::from_montgomery_limbs([
+                16726508063913964392,
+                4385464781692893765,
+                17142144457441434729,
+                645938037014500861,
+            ]),
+            Fq::from_montgomery_limbs([
+                10296668240128075368,
+                13819401453315004705,
+                6155461715528432646,
+                702069870052643026,
+            ]),
+            Fq::from_montgomery_limbs([
+                2801880108642048491,
+                11574278403805111104,
+                13924704093842737128,
+                795541711402187315,
+            ]),
+            Fq::from_montgomery_limbs([
+                15308642223572822179,
+                11826498530336523346,
+                9914760558993083237,
+                110550473271526070,
+            ]),
+            Fq::from_montgomery_limbs([
+                16826154677143767833,
+                12283841325997562971,
+                11176651291585048524,
+                500505655157954343,
+            ]),
+            Fq::from_montgomery_limbs([
+                9466603803796951064,
+                14898645409134165552,
+                16862561897941037831,
+                331555905400552609,
+            ]),
+            Fq::from_montgomery_limbs([
+                10968861157959163597,
+                2659789076936699701,
+                5120213850998469419,
+                714467212159645088,
+            ]),
+            Fq::from_montgomery_limbs([
+                5974620240437765114,
+                9859745527972122,
+                9904453454372976960,
+                806709288333416798,
+            ]),
+            Fq::from_montgomery_limbs([
+                10242484298808309541,
+                13641937389702715717,
+                2876631355133490353,
+                426164468476076511,
+            ]),
+            Fq::from_montgomery_limbs([
+                5261282881896004918,
+                16213169529104734885,
+                11614382936753021297,
+                1280325230221512577,
+            ]),
+            Fq::from_montgomery_limbs([
+                15580769664305404745,
+                1372654821263068854,
+                8405851711641698675,
+                102437981563993999,
+            ]),
+            Fq::from_montgomery_limbs([
+                9785692174818863761,
+                14812521946170359963,
+                11271603767300899526,
+                22545832244570120,
+            ]),
+            Fq::from_montgomery_limbs([
+                10877525590711912401,
+                7255201154750468244,
+                4000332127340057655,
+                365811481037636238,
+            ]),
+            Fq::from_montgomery_limbs([
+                10855488876932280175,
+                8905087727340818219,
+                7595843766951374707,
+                901228476480295953,
+            ]),
+            Fq::from_montgomery_limbs([
+                14079191625011465544,
+                14133058749022117136,
+                6225826713568738149,
+                311210369771839597,
+            ]),
+            Fq::from_montgomery_limbs([
+                7738472689865931461,
+                3036581880698616565,
+                7969418195760243519,
+                267518402482943399,
+            ]),
+            Fq::from_montgomery_limbs([
+                3296402326041867552,
+                9392110517354701438,
+                18237200198340627658,
+                586047614419611060,
+            ]),
+            Fq::from_montgomery_limbs([
+                7863741122254495748,
+                12928490840580779168,
+                4580826737306341802,
+                1005787677632507458,
+            ]),
+            Fq::from_montgomery_limbs([
+                12206326586268509778,
+                3045231783081058125,
+                520215445666594221,
+                1107562842822467011,
+            ]),
+            Fq::from_montgomery_limbs([
+                18413111659481393963,
+                16724308397905570961,
+                8565716489439380214,
+                489458705934395374,
+            ]),
+            Fq::from_montgomery_limbs([
+                17304900728454111089,
+                15713589733421942535,
+                11910287928038885708,
+                787075977189100270,
+            ]),
+            Fq::from_montgomery_limbs([
+                11605708155550894542,
+                1433165353356334759,
+                15794125987660620708,
+                566593363179836694,
+            ]),
+            Fq::from_montgomery_limbs([
+                10182866084032126106,
+                10802510855069668149,
+                3083003038739331712,
+                664562867493058924,
+            ]),
+            Fq::from_montgomery_limbs([
+                12087500448301717890,
+                12453681070796572703,
+                14656158714123504138,
+                1011819585236104647,
+            ]),
+            Fq::from_montgomery_limbs([
+                8358379017806781964,
+                15313907128693678499,
+                15649547259327741368,
+                310257569043290518,
+            ]),
+            Fq::from_montgomery_limbs([
+                13590436035886468787,
+                9933108028551806832,
+                16726226672958579455,
+                89374387041270732,
+            ]),
+            Fq::from_montgomery_limbs([
+                13043834278313850040,
+                9492319506836639967,
+                4054280762888216075,
+                97743854858316309,
+            ]),
+            Fq::from_montgomery_limbs([
+                12635081592615323424,
+                4584496848711429408,
+                9297649382674103557,
+                770565663966737357,
+            ]),
+            Fq::from_montgomery_limbs([
+                15495927201276742463,
+                1087932660798772481,
+                10911763019508575276,
+                500484992841534517,
+            ]),
+            Fq::from_montgomery_limbs([
+                2325324453859663309,
+                2859954080714183716,
+                10349148325850158867,
+                870276688205972147,
+            ]),
+            Fq::from_montgomery_limbs([
+                17838391417849123491,
+                13587201710889274968,
+                4923481578114951540,
+                1018297401866168599,
+            ]),
+            Fq::from_montgomery_limbs([
+                10815395309663673081,
+                11961809220380390051,
+                8085855832556812841,
+                696501175888203063,
+            ]),
+            Fq::from_montgomery_limbs([
+                7773661154961894112,
+                11451208047771586909,
+                8137653429549732236,
+                975734535188964566,
+            ]),
+            Fq::from_montgomery_limbs([
+                16756560826410817031,
+                5800964051885133758,
+                9626919243701083199,
+                805405163031655691,
+            ]),
+            Fq::from_montgomery_limbs([
+                16881037049652115658,
+                11268275871713694310,
+                12109410197996232230,
+                188216911862774576,
+            ]),
+            Fq::from_montgomery_limbs([
+                7262290397737655130,
+                3962765586818533977,
+                10594801844356345693,
+                971586739577553644,
+            ]),
+            Fq::from_montgomery_limbs([
+                10303777033180775427,
+                17246293497553878833,
+                1552705039350202537,
+                1248227178846046604,
+            ]),
+            Fq::from_montgomery_limbs([
+                10422594262664736364,
+                9407926439509368016,
+                4274655860409638144,
+                75945046871272502,
+            ]),
+            Fq::from_montgomery_limbs([
+                15598451127221190412,
+                1623151253649968163,
+                11440471597935374492,
+                1316161143601585999,
+            ]),
+            Fq::from_montgomery_limbs([
+                7543467803309646159,
+                13386852945838196899,
+                9075010599974887429,
+                436917976469873760,
+            ]),
+            Fq::from_montgomery_limbs([
+                2660865090162962054,
+                16326345524053919339,
+                6368325556738511293,
+                945914360842310976,
+            ]),
+            Fq::from_montgomery_limbs([
+                10504855510700275067,
+                16479318659262822710,
+                3981596393328905129,
+                775695487607112512,
+            ]),
+            Fq::from_montgomery_limbs([
+                16378353755886850797,
+                15251751772659222554,
+                6850823750297247826,
+                254003979981564164,
+            ]),
+            Fq::from_montgomery_limbs([
+                5090800304856472027,
+                17229368026845935494,
+                10640306566301987368,
+                756991745864123905,
+            ]),
+            Fq::from_montgomery_limbs([
+                13346948692783665443,
+                15461920628137574645,
+                14562452465229821605,
+                939207765677473886,
+            ]),
+            Fq::from_montgomery_limbs([
+                10402758152935815038,
+                1371394473773449926,
+                14123438339912817896,
+                538734963542500843,
+            ]),
+            Fq::from_montgomery_limbs([
+                10877229458049536328,
+                14675570965667478066,
+                16708550450847732595,
+                449601503763513376,
+            ]),
+            Fq::from_montgomery_limbs([
+                12550067288642078353,
+                7763289829578879525,
+                3856243849907612150,
+                642211512210730866,
+            ]),
+            Fq::from_montgomery_limbs([
+                8496448578056232552,
+                18342383555773662360,
+                14179443816074141611,
+                539344654356283020,
+            ]),
+            Fq::from_montgomery_limbs([
+                18354238401708427195,
+                729573085936182855,
+                11645857678814076525,
+                181465991800048346,
+            ]),
+            Fq::from_montgomery_limbs([
+                13464016300965986373,
+                5015367854874808938,
+                11264464710082771559,
+                401218364865012387,
+            ]),
+            Fq::from_montgomery_limbs([
+                14669853334022039982,
+                12557767949223976528,
+                13512545324026084204,
+                971746013106153872,
+            ]),
+            Fq::from_montgomery_limbs([
+                6395663705775809761,
+                16752949676996767951,
+                2331743723340322695,
+                834223140894112045,
+            ]),
+            Fq::from_montgomery_limbs([
+                11008457810925952146,
+                8119815180046590205,
+                4305147710671926515,
+                282341671782761069,
+            ]),
+            Fq::from_montgomery_limbs([
+                4891610299664508719,
+                506293667281338666,
+                3837805332976108905,
+                743342126010403761,
+            ]),
+            Fq::from_montgomery_limbs([
+                1272184384660441406,
+                16335978244672642171,
+                10399457698524183058,
+                624144814195150345,
+            ]),
+            Fq::from_montgomery_limbs([
+                3007886477527542235,
+                9753114286016967,
+                4278720259525047899,
+                450085125983743779,
+            ]),
+            Fq::from_montgomery_limbs([
+                4174668448964314713,
+                3504780543755734964,
+                13795650180998527732,
+                1284511640273290572,
+            ]),
+            Fq::from_montgomery_limbs([
+                13035973659623369615,
+                6135621718836534434,
+                13250872394089119268,
+                942478829707631045,
+            ]),
+            Fq::from_montgomery_limbs([
+                5862202570435148954,
+                1100933661345214380,
+                11840621714550636359,
+                815751801222075861,
+            ]),
+            Fq::from_montgomery_limbs([
+                15302119084181793341,
+                17908436178137468634,
+                9420112149631176516,
+                1001430907319538903,
+            ]),
+            Fq::from_montgomery_limbs([
+                1546596208509464379,
+                12305675691305279492,
+                12288694691773754923,
+                1238842556667730433,
+            ]),
+            Fq::from_montgomery_limbs([
+                6113389082209629942,
+                10704370770942330035,
+                10937920713573732652,
+                1138338864330232143,
+            ]),
+            Fq::from_montgomery_limbs([
+                6537871832235957661,
+                17532058944928271183,
+                16654395791185261444,
+                1119736692141755082,
+            ]),
+            Fq::from_montgomery_limbs([
+                2189435796769769212,
+                1213455376350810770,
+                5561711547073438915,
+                33366609644721940,
+            ]),
+            Fq::from_montgomery_limbs([
+                12449971108722796782,
+                3455295858132675442,
+                6207807499747919438,
+                965323211903519811,
+            ]),
+            Fq::from_montgomery_limbs([
+                10869377225642284193,
+                12485587093280356548,
+                2155260285191528974,
+                597697323095721229,
+            ]),
+            Fq::from_montgomery_limbs([
+                13084681138400949713,
+                8619311311822084665,
+                13109773730753235607,
+                193137580261247234,
+            ]),
+            Fq::from_montgomery_limbs([
+                9892017238570892526,
+                180550799563642613,
+                12643855407345129641,
+                460856626896837467,
+            ]),
+            Fq::from_montgomery_limbs([
+                15767681263921472316,
+                10224208468118622600,
+                2791341998236553903,
+                4046796008284443,
+            ]),
+            Fq::from_montgomery_limbs([
+                548296258664389715,
+                14356819015321676109,
+                14703563411139345056,
+                441503036335246507,
+            ]),
+            Fq::from_montgomery_limbs([
+                16775758771701357940,
+                8834669041480529755,
+                9919605892328818046,
+                489171569473430300,
+            ]),
+            Fq::from_montgomery_limbs([
+                3406288143637566328,
+                4008581681581970795,
+                11728441407909169811,
+                333742419193186830,
+            ]),
+            Fq::from_montgomery_limbs([
+                16855764296415167823,
+                2309158868191427086,
+                9981975238041700952,
+                870348103923176856,
+            ]),
+            Fq::from_montgomery_limbs([
+                2743805757017058758,
+                13751393265693500825,
+                5283712766882501063,
+                1082693064831052306,
+            ]),
+            Fq::from_montgomery_limbs([
+                594501514375344610,
+                16779019869692027148,
+                17432930425734236897,
+                726742077911199861,
+            ]),
+            Fq::from_montgomery_limbs([
+                818116181598232848,
+                10323686728212428269,
+                1623181163401604891,
+                861860804600637102,
+            ]),
+            Fq::from_montgomery_limbs([
+                6615530125104269024,
+                2218875895204107831,
+                11907579001019178396,
+                1102502971774395888,
+            ]),
+            Fq::from_montgomery_limbs([
+                13386210982957286667,
+                8576867551928483857,
+                11321921743678702590,
+                715327825607863501,
+            ]),
+            Fq::from_montgomery_limbs([
+                15697848497269108761,
+                9124732510357878194,
+                11583294941242614722,
+                442472329509749722,
+            ]),
+            Fq::from_montgomery_limbs([
+                1963118889263753155,
+                17063701012671119911,
+                16896515295173709202,
+                788718332083612600,
+            ]),
+            Fq::from_montgomery_limbs([
+                10874464328220370361,
+                8601569717606842858,
+                4856997710933006181,
+                250592437747804823,
+            ]),
+            Fq::from_montgomery_limbs([
+                4549608753792025554,
+                6772446005443659416,
+                13542036228911132740,
+                128718809818246650,
+            ]),
+            Fq::from_montgomery_limbs([
+                2790319332159531342,
+                12273213283719331828,
+                1977416453405613642,
+                854361350807134545,
+            ]),
+            Fq::from_montgomery_limbs([
+                17045058186821456623,
+                9606626411993317897,
+                15965236767189306399,
+                569251938248933805,
+            ]),
+            Fq::from_montgomery_limbs([
+                8280766054183163590,
+                10969327546085271391,
+                16339570060768770482,
+                1179417766486722011,
+            ]),
+            Fq::from_montgomery_limbs([
+                5662322385200403259,
+                13340324462366357801,
+                5998069800488071460,
+                351414665608110241,
+            ]),
+            Fq::from_montgomery_limbs([
+                4523250170196441242,
+                15736885143282507298,
+                10037593468890990362,
+                726448065348934081,
+            ]),
+            Fq::from_montgomery_limbs([
+                15601727966650997179,
+                15819389989307844675,
+                18366289226744257489,
+                507504709464299738,
+            ]),
+            Fq::from_montgomery_limbs([
+                15663260632513995016,
+                10632325334864942461,
+                2950128217702545716,
+                1135068055040992330,
+            ]),
+            Fq::from_montgomery_limbs([
+                5284009020552730484,
+                2829643765239842935,
+                17264829111041697741,
+                537928015914096510,
+            ]),
+            Fq::from_montgomery_limbs([
+                11281937430826480360,
+                11774957301316983744,
+                7985404354493724414,
+                1326170672073026236,
+            ]),
+            Fq::from_montgomery_limbs([
+                11417546833500728224,
+                11605062265264951110,
+                11551661420524009756,
+                1201640538468542704,
+            ]),
+            Fq::from_montgomery_limbs([
+                13162227735534035795,
+                6723901530069198813,
+                5389130447549985617,
+                440020635893920103,
+            ]),
+            Fq::from_montgomery_limbs([
+                11435872875797671886,
+                17260066453827604184,
+                2527208611106468613,
+                41502556959083580,
+            ]),
+            Fq::from_montgomery_limbs([
+                4142291290954420777,
+                14161290030925513375,
+                16747849155379522111,
+                1032677444195646546,
+            ]),
+            Fq::from_montgomery_limbs([
+                6047669561639750067,
+                17714711627375050856,
+                11215332156648415420,
+                228683635758286346,
+            ]),
+            Fq::from_montgomery_limbs([
+                7742458026657560204,
+                7906146942425343319,
+                522998262114083171,
+                882966711689172178,
+            ]),
+            Fq::from_montgomery_limbs([
+                17416923119816937772,
+                1526103566815617707,
+                3452513336411528052,
+                677758748848303455,
+            ]),
+            Fq::from_montgomery_limbs([
+                14041209381389321092,
+                10512074559456481243,
+                5653490446466117459,
+                846559250004091044,
+            ]),
+            Fq::from_montgomery_limbs([
+                16890103206954128380,
+                18191152613553298609,
+                5013501830726963320,
+                578158213714415996,
+            ]),
+            Fq::from_montgomery_limbs([
+                5718926413382087972,
+                11419441222000924432,
+                6817745500571197935,
+                634738104907068442,
+            ]),
+            Fq::from_montgomery_limbs([
+                5890449544537578410,
+                4225902591578584246,
+                556145736383525584,
+                401566219309990321,
+            ]),
+            Fq::from_montgomery_limbs([
+                14823085709304631940,
+                17799778424194590884,
+                8532571120978388242,
+                137579087659191700,
+            ]),
+            Fq::from_montgomery_limbs([
+                17211498339096413675,
+                7451834587008031491,
+                12915884496192581744,
+                803314509660129741,
+            ]),
+            Fq::from_montgomery_limbs([
+                9070388227894623867,
+                1012584047213055849,
+                4029631429266492160,
+                429883602254668558,
+            ]),
+            Fq::from_montgomery_limbs([
+                8165200467359564163,
+                3833881933070502129,
+                5254145186106974827,
+                1303747759282686172,
+            ]),
+            Fq::from_montgomery_limbs([
+                13511125553424296470,
+                3506321144645283103,
+                13939432022188446130,
+                956128955394404782,
+            ]),
+            Fq::from_montgomery_limbs([
+                3155526932096743932,
+                2623605557742371982,
+                9998111568142004178,
+                953366488938283252,
+            ]),
+            Fq::from_montgomery_limbs([
+                17739017974626688890,
+                10210530500432426370,
+                12000776864918778807,
+                1297057930893320958,
+            ]),
+            Fq::from_montgomery_limbs([
+                9261209465338113001,
+                15533511771719727836,
+                469628375137431053,
+                40637073994157299,
+            ]),
+            Fq::from_montgomery_limbs([
+                13508363625066112434,
+                4951805169434471433,
+                4067463965052344304,
+                1343365622913140144,
+            ]),
+            Fq::from_montgomery_limbs([
+                18061266415403699188,
+                11727779085144046739,
+                17136323245529572127,
+                1081546803547387443,
+            ]),
+            Fq::from_montgomery_limbs([
+                16790724182949961468,
+                13597194623158707921,
+                1463238744474966075,
+                1244867914426671365,
+            ]),
+            Fq::from_montgomery_limbs([
+                5889648546264058747,
+                12268680487557917668,
+                17079406320249197589,
+                257881425730358870,
+            ]),
+            Fq::from_montgomery_limbs([
+                4380052585337518023,
+                16976090883293584796,
+                13437395626611027967,
+                87919252203927278,
+            ]),
+            Fq::from_montgomery_limbs([
+                2693804651317510810,
+                15986486185511311629,
+                12271798532253536397,
+                751519308841230274,
+            ]),
+            Fq::from_montgomery_limbs([
+                5376680603172770645,
+                13567289556988233843,
+                17782879664185959077,
+                936825703427240058,
+            ]),
+            Fq::from_montgomery_limbs([
+                9093880217892406029,
+                16915497047670510729,
+                9264502402365704738,
+                623153377399474073,
+            ]),
+            Fq::from_montgomery_limbs([
+                138994639415390290,
+                13103610708394373287,
+                8693395326683760304,
+                545439420314868398,
+            ]),
+            Fq::from_montgomery_limbs([
+                11874283957487801104,
+                8500068037754194319,
+                6512221047587732124,
+                119521844258796457,
+            ]),
+            Fq::from_montgomery_limbs([
+                3351807078406985355,
+                15995810529912458919,
+                8768518272626178916,
+                719174272644423059,
+            ]),
+            Fq::from_montgomery_limbs([
+                8696989857415006691,
+                11315079003932615727,
+                2957064108303121653,
+                1317726829839530174,
+            ]),
+            Fq::from_montgomery_limbs([
+                9829940173014808138,
+                5928688355160305340,
+                5995345956492782001,
+                690514339711029673,
+            ]),
+            Fq::from_montgomery_limbs([
+                10013829914501823202,
+                1924764404748917305,
+                7412107549460279444,
+                272196899003644100,
+            ]),
+            Fq::from_montgomery_limbs([
+                14062127346518257977,
+                17386044745301554515,
+                15828695220049239677,
+                987859525882783839,
+            ]),
+            Fq::from_montgomery_limbs([
+                15794923638683193113,
+                6022943558301356009,
+                1992648775396601474,
+                999659283294916429,
+            ]),
+            Fq::from_montgomery_limbs([
+                14820271396550250818,
+                9460401204321405101,
+                2771408262541970303,
+                15904642009049632,
+            ]),
+            Fq::from_montgomery_limbs([
+                15969168280408105195,
+                7815852375842858405,
+                6412742452410823723,
+                1042797254711367216,
+            ]),
+            Fq::from_montgomery_limbs([
+                12972653621095390377,
+                18349718384034032550,
+                13261734660852200825,
+                411102893649394807,
+            ]),
+            Fq::from_montgomery_limbs([
+                2995999956073879660,
+                14666341539684902784,
+                13089711249865836466,
+                494991701444775354,
+            ]),
+            Fq::from_montgomery_limbs([
+                14295946317945582224,
+                15287270005953550724,
+                7291057438275817118,
+                721877194037686939,
+            ]),
+            Fq::from_montgomery_limbs([
+                6631598608723534694,
+                1016945214139016858,
+                9704728401194576887,
+                521439614957098066,
+            ]),
+            Fq::from_montgomery_limbs([
+                12283318057949449926,
+                17394959868301113609,
+                11345216826173777308,
+                1291609075001962219,
+            ]),
+            Fq::from_montgomery_limbs([
+                1877444140965922427,
+                5674674742184409760,
+                2884938508638418193,
+                1194534948438306273,
+            ]),
+            Fq::from_montgomery_limbs([
+                2719238411703931083,
+                45929341639507760,
+                11307027477647858565,
+                1265259718477157520,
+            ]),
+            Fq::from_montgomery_limbs([
+                4456043348865926248,
+                15630772566669587593,
+                12861103913696645109,
+                448394688470747604,
+            ]),
+            Fq::from_montgomery_limbs([
+                15771115199429069939,
+                4817881345915672343,
+                2111611819737365449,
+                1003738886221220397,
+            ]),
+            Fq::from_montgomery_limbs([
+                5431658726534830657,
+                10110467764404541702,
+                11187538297886751679,
+                960715254036476851,
+            ]),
+            Fq::from_montgomery_limbs([
+                5871615000382021146,
+                7994017193447938986,
+                14760631750145935381,
+                1019472673675932568,
+            ]),
+            Fq::from_montgomery_limbs([
+                15756927759939002032,
+                13402986675441340682,
+                3506935502268935159,
+                513469185560841550,
+            ]),
+            Fq::from_montgomery_limbs([
+                15308484131038412894,
+                16718487718792034107,
+                10087352426725087193,
+                224572312028656735,
+            ]),
+            Fq::from_montgomery_limbs([
+                10716746983455848870,
+                3570370168656329691,
+                7398643824609071891,
+                964797960977319830,
+            ]),
+            Fq::from_montgomery_limbs([
+                7430095870923573667,
+                7730601622345287790,
+                16846752911118897495,
+                1085771965706780546,
+            ]),
+            Fq::from_montgomery_limbs([
+                12476280679467818608,
+                16329892184517213390,
+                14916136316811779236,
+                1211965514116698271,
+            ]),
+            Fq::from_montgomery_limbs([
+                7608348315248891654,
+                16691838558031370307,
+                4431241290374207733,
+                1025129161450804128,
+            ]),
+            Fq::from_montgomery_limbs([
+                11458950336733915375,
+                7261893437648058875,
+                11328168637467818263,
+                1276379276048526394,
+            ]),
+            Fq::from_montgomery_limbs([
+                73531412851177436,
+                10698404604669828625,
+                216843447517002869,
+                137597351589072110,
+            ]),
+            Fq::from_montgomery_limbs([
+                16889850649711334596,
+                13715077645480115510,
+                17812754359229080055,
+                573700787969912767,
+            ]),
+            Fq::from_montgomery_limbs([
+                10461521823030429914,
+                12362041455201667555,
+                8048179147520652020,
+                183952002672048871,
+            ]),
+            Fq::from_montgomery_limbs([
+                3145593030474710589,
+                15365827841572896730,
+                11265859022546969389,
+                1313433095638463332,
+            ]),
+            Fq::from_montgomery_limbs([
+                5232122740984321079,
+                2299128396239106554,
+                6350050182504394279,
+                95503992189138727,
+            ]),
+            Fq::from_montgomery_limbs([
+                1394644105439105889,
+                10340661092601716514,
+                17705389215158382785,
+                590395164229309913,
+            ]),
+            Fq::from_montgomery_limbs([
+                2424597415028204985,
+                9349489271997195863,
+                2730329231442224105,
+                405160481884014626,
+            ]),
+            Fq::from_montgomery_limbs([
+                1211417752142596468,
+                3739531686187189538,
+                1393043342043709068,
+                319490097071413640,
+            ]),
+            Fq::from_montgomery_limbs([
+                6833063907118845778,
+                6085328371732917698,
+                6395017409310096261,
+                1258326450481438814,
+            ]),
+            Fq::from_montgomery_limbs([
+                13663119821207824139,
+                15810758192634396921,
+                15089284929392632882,
+                653892671028341340,
+            ]),
+            Fq::from_montgomery_limbs([
+                5164966141967965208,
+                12847158016757524554,
+                9547503857310374655,
+                849920443814345297,
+            ]),
+            Fq::from_montgomery_limbs([
+                1387833302954139666,
+                9297897297759360980,
+                12458013487154847505,
+                574391216648271395,
+            ]),
+            Fq::from_montgomery_limbs([
+                7657396470086939438,
+                17774114367122582160,
+                4843717596502691392,
+                732079438635595790,
+            ]),
+            Fq::from_montgomery_limbs([
+                15689105957154119283,
+                12205191445178971900,
+                10537269577931325319,
+                702682292479762995,
+            ]),
+            Fq::from_montgomery_limbs([
+                1381060792599411231,
+                11809899260200742181,
+                7011248926288615309,
+                991570619049718742,
+            ]),
+            Fq::from_montgomery_limbs([
+                3803360415360015456,
+                13545175918028487648,
+                12851978637876990269,
+                784000795331115761,
+            ]),
+            Fq::from_montgomery_limbs([
+                3596480607905470678,
+                11708380212230092810,
+                273108714442804084,
+                1132575391660352216,
+            ]),
+        ]),
+        mds: MdsMatrix::<7, 6, 49, 36>::new_from_known([
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+        ]),
+        alpha: Alpha::Inverse,
+        rounds: RoundNumbers { r_P: 56, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<6, 36>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+            ]),
+            v: Matrix::<1, 6, 6>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+            ]),
+            w: Matrix::<6, 1, 6>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<7, 49>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<7, 49>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6933713838801944433,
+                    16845582094611513195,
+                    18106981831632269118,
+                    1315719765541495999,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    11025937787709817432,
+                    2848215840891863663,
+                    12003429085947921194,
+                    123169188111155826,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12625372430371999830,
+                    16677955247940128803,
+                    7323211740492754581,
+                    634092645821649835,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8476375831616065903,
+                    8302751278140753414,
+                    4675397025515049474,
+                    916054888187664134,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10397071512469717592,
+                    5168805500057287779,
+                    15231087545157212262,
+                    795647437775574917,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4753644871978862709,
+                    5862282760667146308,
+                    12460694012157225697,
+                    599558919665641703,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<7, 49>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    18289540298934006401,
+                    12620611207485559719,
+                    12496121289749056322,
+                    817144512748384706,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15589773063064153532,
+                    10937873166103048610,
+                    6318262381745988044,
+                    164305025615087669,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15945838893269705074,
+                    12231655940729207770,
+                    16783976632846575746,
+                    1109102887521365908,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6834634673379885361,
+                    14313980702431346769,
+                    11799433102834887335,
+                    414159939376644417,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6202864031195801806,
+                    9008291636618939950,
+                    4452886644180013898,
+                    834656796167786155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    257831097013115576,
+                    868816813650478155,
+                    3804928554730106375,
+                    37318070574597666,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12731957617084167734,
+                    10385122202334236509,
+                    6304526407551965622,
+                    473830859466044865,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15589773063064153532,
+                    10937873166103048610,
+                    6318262381745988044,
+                    164305025615087669,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6846034351900465196,
+                    14922456655897598264,
+                    3385387262061980880,
+                    41120221023296058,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15382748348274919943,
+                    17176897444608801178,
+                    9672737639142736111,
+                    667324299238078128,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15347282079877344563,
+                    7118861760574925591,
+                    14639960435031189354,
+                    496118406637979928,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10633984218986397485,
+                    1476776383719121929,
+                    18242946201412975574,
+                    235737836971566080,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13255078662550912877,
+                    6201099884138924934,
+                    16598544366614787861,
+                    517978551803961280,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16511322183643786520,
+                    12899213679171041629,
+                    10327663219981717303,
+                    101291905845336522,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15945838893269705074,
+                    12231655940729207770,
+                    16783976632846575746,
+                    1109102887521365908,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15382748348274919943,
+                    17176897444608801178,
+                    9672737639142736111,
+                    667324299238078128,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14205197080554911234,
+                    18284593956791143607,
+                    9307844449907611885,
+                    840522240017846486,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15159258319784243147,
+                    14082599260628033239,
+                    6774164385877933413,
+                    747982014981801174,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1720795814776594401,
+                    1331741422739941001,
+                    1433030060135444919,
+                    661580976464017359,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18293763308414053579,
+                    18299155137503640753,
+                    10462936480575081846,
+                    453983746301356936,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4581286503082050232,
+                    12284808854596259078,
+                    3289926894666988909,
+                    902128282614826112,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6834634673379885361,
+                    14313980702431346769,
+                    11799433102834887335,
+                    414159939376644417,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15347282079877344563,
+                    7118861760574925591,
+                    14639960435031189354,
+                    496118406637979928,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15159258319784243147,
+                    14082599260628033239,
+                    6774164385877933413,
+                    747982014981801174,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8459024031946559011,
+                    9756324734815253725,
+                    14081981857002652443,
+                    1295411099915753127,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12916186197107076085,
+                    5935794698380416041,
+                    14163718741243160273,
+                    1284207706380505486,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18200592249652917081,
+                    10952824854756428652,
+                    13221851894140961386,
+                    952100497899551398,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7909306592215715661,
+                    12372000920976206381,
+                    13047646210570006173,
+                    1134258014683902124,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6202864031195801806,
+                    9008291636618939950,
+                    4452886644180013898,
+                    834656796167786155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10633984218986397485,
+                    1476776383719121929,
+                    18242946201412975574,
+                    235737836971566080,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1720795814776594401,
+                    1331741422739941001,
+                    1433030060135444919,
+                    661580976464017359,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12916186197107076085,
+                    5935794698380416041,
+                    14163718741243160273,
+                    1284207706380505486,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3910809514494604292,
+                    8483245271389311552,
+                    16246267851842217096,
+                    18865255698597878,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3026843501192760993,
+                    8380473052143493977,
+                    3054950936628239486,
+                    722212886196682246,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640176946983601983,
+                    7851638753013976420,
+                    7953773760774643320,
+                    1060395049618029567,
+                ]),
+                Fq::from_montgomery_limbs([
+                    257831097013115576,
+                    868816813650478155,
+                    3804928554730106375,
+                    37318070574597666,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13255078662550912877,
+                    6201099884138924934,
+                    16598544366614787861,
+                    517978551803961280,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18293763308414053579,
+                    18299155137503640753,
+                    10462936480575081846,
+                    453983746301356936,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18200592249652917081,
+                    10952824854756428652,
+                    13221851894140961386,
+                    952100497899551398,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3026843501192760993,
+                    8380473052143493977,
+                    3054950936628239486,
+                    722212886196682246,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6947786146630418877,
+                    15369056627168554704,
+                    17602917142112233480,
+                    860079864989181067,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12292153329582579311,
+                    12340018229585837748,
+                    16138005556776207249,
+                    689763430924300574,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12731957617084167734,
+                    10385122202334236509,
+                    6304526407551965622,
+                    473830859466044865,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16511322183643786520,
+                    12899213679171041629,
+                    10327663219981717303,
+                    101291905845336522,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4581286503082050232,
+                    12284808854596259078,
+                    3289926894666988909,
+                    902128282614826112,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7909306592215715661,
+                    12372000920976206381,
+                    13047646210570006173,
+                    1134258014683902124,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640176946983601983,
+                    7851638753013976420,
+                    7953773760774643320,
+                    1060395049618029567,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12292153329582579311,
+                    12340018229585837748,
+                    16138005556776207249,
+                    689763430924300574,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11306146037240305765,
+                    10984198068608188499,
+                    1191708097986331420,
+                    836519420285054905,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<6, 36>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    3792453098216396897,
+                    12628553005522469477,
+                    16642002769880465331,
+                    421042619293543187,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10263844291975416107,
+                    13672341457628810763,
+                    12509513995609872959,
+                    205381282018859586,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10784713721876928946,
+                    5674574537437802090,
+                    12687938002553184071,
+                    136316472684139419,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16070813810583366554,
+                    1956961633890359529,
+                    13965208031005279836,
+                    927951972604280320,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6738933144333850617,
+                    6100304390614169754,
+                    1210061970152249951,
+                    909818292837236776,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9669791352790763084,
+                    10325954078533278386,
+                    9503492366281944368,
+                    1040674818174287413,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10263844291975416107,
+                    13672341457628810763,
+                    12509513995609872959,
+                    205381282018859586,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6412844366295084778,
+                    7666866282335076917,
+                    1998128405856404681,
+                    74724070388243614,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2820801561543625657,
+                    2418377097726488830,
+                    6026234412050937676,
+                    1126089764215992130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8551209674866173640,
+                    6260366140671248954,
+                    12081978629156052449,
+                    1140556338048141522,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3127468548134575066,
+                    584585047777214689,
+                    7762916153904627652,
+                    166598529350882438,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7594054111820467981,
+                    8571015412052249811,
+                    6107608647809765519,
+                    1023873926642050928,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10784713721876928946,
+                    5674574537437802090,
+                    12687938002553184071,
+                    136316472684139419,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2820801561543625657,
+                    2418377097726488830,
+                    6026234412050937676,
+                    1126089764215992130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10427240268737712782,
+                    8508693586717179156,
+                    9391845005515956255,
+                    937208025112203554,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8182900134894401237,
+                    12638356435589207249,
+                    2514008238224928511,
+                    262393601667707271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9827417092260447852,
+                    9328415136733098350,
+                    7749975888478067278,
+                    1296132886589595648,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16287831069372846812,
+                    12117020194152290696,
+                    2582012005292236373,
+                    964219332138367410,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16070813810583366554,
+                    1956961633890359529,
+                    13965208031005279836,
+                    927951972604280320,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8551209674866173640,
+                    6260366140671248954,
+                    12081978629156052449,
+                    1140556338048141522,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8182900134894401237,
+                    12638356435589207249,
+                    2514008238224928511,
+                    262393601667707271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2689211423437640460,
+                    9937738931024806642,
+                    16752771701136165272,
+                    1203240695252068537,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4340203757804311965,
+                    12676662818122060527,
+                    8325672080044279362,
+                    1087811919015138569,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14389282186921406518,
+                    13071685447061354484,
+                    9323961789214815202,
+                    798867032561203873,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6738933144333850617,
+                    6100304390614169754,
+                    1210061970152249951,
+                    909818292837236776,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3127468548134575066,
+                    584585047777214689,
+                    7762916153904627652,
+                    166598529350882438,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9827417092260447852,
+                    9328415136733098350,
+                    7749975888478067278,
+                    1296132886589595648,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4340203757804311965,
+                    12676662818122060527,
+                    8325672080044279362,
+                    1087811919015138569,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16937615735900532927,
+                    2828202157217662325,
+                    11653172729566405164,
+                    102608297022837914,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10698441721372329435,
+                    14189560518339287134,
+                    16709548602960206315,
+                    1115736315296030670,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9669791352790763084,
+                    10325954078533278386,
+                    9503492366281944368,
+                    1040674818174287413,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7594054111820467981,
+                    8571015412052249811,
+                    6107608647809765519,
+                    1023873926642050928,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16287831069372846812,
+                    12117020194152290696,
+                    2582012005292236373,
+                    964219332138367410,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14389282186921406518,
+                    13071685447061354484,
+                    9323961789214815202,
+                    798867032561203873,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10698441721372329435,
+                    14189560518339287134,
+                    16709548602960206315,
+                    1115736315296030670,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4200873270792393235,
+                    10250359348562610182,
+                    13119023895300261973,
+                    870734268521497409,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            M_i: Matrix::<7, 7, 49>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7001080564569702778,
+                    2402936051000943429,
+                    10640197058284600811,
+                    1292572134515669722,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14621690033417766958,
+                    15468777244275338046,
+                    7175633243909432780,
+                    334188899725599928,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13317494913270031536,
+                    12811504593270824892,
+                    5813805644760458521,
+                    779235300953326806,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15528990593466852681,
+                    2025972323304909151,
+                    2869588981858754541,
+                    795864045294686727,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15852779101262152462,
+                    5546495682458462067,
+                    16108927795984659898,
+                    891481794621504328,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13301177361310246541,
+                    15594225684226239702,
+                    11984994567990410454,
+                    1165363177406899665,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14621690033417766958,
+                    15468777244275338046,
+                    7175633243909432780,
+                    334188899725599928,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15181074351046114554,
+                    1954367294057976038,
+                    16788379671522892966,
+                    133602126943528991,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11774641642774699323,
+                    4076255180723109827,
+                    6576101145681212241,
+                    384353693799773984,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7022225108815249975,
+                    16008983143062357582,
+                    4662661743643916958,
+                    1003977674227994027,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13353678730175159347,
+                    12734552464180882012,
+                    13251898579063351114,
+                    153454195905461978,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8047166648495333250,
+                    1629632495995266077,
+                    5581551373643913780,
+                    821158320459796681,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13317494913270031536,
+                    12811504593270824892,
+                    5813805644760458521,
+                    779235300953326806,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11774641642774699323,
+                    4076255180723109827,
+                    6576101145681212241,
+                    384353693799773984,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12946827056720595245,
+                    2134293672947291634,
+                    4651313101310893248,
+                    1089154963853015229,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8340542519543576675,
+                    14782515954870285513,
+                    15039761997871478323,
+                    1083801632837749236,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7123078720328492843,
+                    4254440076546374530,
+                    1758870492840859241,
+                    218162003895071486,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4606918834852367206,
+                    8640074186431574120,
+                    968363158050114044,
+                    202200374652557106,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15528990593466852681,
+                    2025972323304909151,
+                    2869588981858754541,
+                    795864045294686727,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7022225108815249975,
+                    16008983143062357582,
+                    4662661743643916958,
+                    1003977674227994027,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8340542519543576675,
+                    14782515954870285513,
+                    15039761997871478323,
+                    1083801632837749236,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17769748577023333593,
+                    15343423123637122279,
+                    2167352720762363175,
+                    1183066228509803377,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15066866641202415259,
+                    14281997227065013032,
+                    15948679204877730566,
+                    1292540264724323765,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2105859753807930443,
+                    17925428252531076840,
+                    13089305644036857832,
+                    626887968642662469,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15852779101262152462,
+                    5546495682458462067,
+                    16108927795984659898,
+                    891481794621504328,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13353678730175159347,
+                    12734552464180882012,
+                    13251898579063351114,
+                    153454195905461978,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7123078720328492843,
+                    4254440076546374530,
+                    1758870492840859241,
+                    218162003895071486,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15066866641202415259,
+                    14281997227065013032,
+                    15948679204877730566,
+                    1292540264724323765,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3106383364271133981,
+                    17130021094804798503,
+                    1351614300882997751,
+                    334683308701004069,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13752999940191133384,
+                    12013340737432480940,
+                    2432024599500082446,
+                    1140475918467970211,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13301177361310246541,
+                    15594225684226239702,
+                    11984994567990410454,
+                    1165363177406899665,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8047166648495333250,
+                    1629632495995266077,
+                    5581551373643913780,
+                    821158320459796681,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4606918834852367206,
+                    8640074186431574120,
+                    968363158050114044,
+                    202200374652557106,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2105859753807930443,
+                    17925428252531076840,
+                    13089305644036857832,
+                    626887968642662469,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13752999940191133384,
+                    12013340737432480940,
+                    2432024599500082446,
+                    1140475918467970211,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5413335066550542214,
+                    5345770083435088973,
+                    2557056780329129818,
+                    1276030459587648278,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17721242321237835775,
+                        11985636621509722110,
+                        11478464757469040638,
+                        960562638525520553,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17640631015407645126,
+                        17416650484724036037,
+                        14803487960933328666,
+                        554882262980868625,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10632745392742701465,
+                        7191381972905833266,
+                        10576427669223334706,
+                        1037506184958051122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7989155441247042094,
+                        18276457113184108543,
+                        17999817914616464103,
+                        943187440870955565,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18204910156218979669,
+                        16293041589642941780,
+                        9975069610392864084,
+                        1088801882579738168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15441359965645378481,
+                        7022857083512140090,
+                        11162758362666494739,
+                        1108530997049617801,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10644639721044274615,
+                        16734510270636734171,
+                        6265966780522802029,
+                        1180401342618465036,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1254097908378330934,
+                        13601601811345135765,
+                        15071243631801644424,
+                        297593012698954223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10771062345226876930,
+                        7469649658028794228,
+                        3597902033882677100,
+                        608350358812428664,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10231075707837198839,
+                        11530522131003023037,
+                        1802721152151214035,
+                        1212125375706727806,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        548206758129328348,
+                        778220363894850734,
+                        12082251172806692919,
+                        593860302507973656,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6029427183938663855,
+                        17136269120728144851,
+                        7031277897576748532,
+                        978052702327451285,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14583548544607776390,
+                        1455594659839297233,
+                        7503148269133459078,
+                        979881557166431467,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4091794927241778027,
+                        3984934849444513902,
+                        4168704414264273635,
+                        1243153189837659840,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13672227389423134641,
+                        7889118255497550145,
+                        10535716868579808028,
+                        727851871568307361,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1805790605077811102,
+                        13328027295111756288,
+                        15284753467090911646,
+                        476755389437853977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6818887996128827024,
+                        12980010109101424586,
+                        17837508282492360706,
+                        804695904048021645,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9531071905126860029,
+                        10560990131084904417,
+                        3843562488879133086,
+                        427342220830413508,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12999307184917437199,
+                        4460278989335054240,
+                        8039372983916400823,
+                        524229825913330581,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13201924241923240556,
+                        11745564486322152121,
+                        2471694394944636145,
+                        181216534789741406,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4934105058811497644,
+                        12271038025203690017,
+                        7436143789682315585,
+                        998806416030886275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13725255261959326942,
+                        8555513885879208558,
+                        955378272993441581,
+                        297008239472103166,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10371095811858205849,
+                        8804518571176544229,
+                        11002636904361592873,
+                        660578117299637260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8307653710943752623,
+                        2839318436416200448,
+                        12213117778126871947,
+                        1025759164655249254,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11863327563780294024,
+                        10993730767593839696,
+                        9502873035480842903,
+                        1097248076762680248,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6808878885915903933,
+                        8278632955554852373,
+                        12719166835729596674,
+                        804467256685775329,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16008107817056775055,
+                        8608502583580218397,
+                        2796011695423831437,
+                        949203486167282741,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9591171450453062265,
+                        6399710760130247553,
+                        16473627252289324238,
+                        199489766710074990,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2126693132859812176,
+                        10946771068545746672,
+                        16987361148217467014,
+                        545647818048109007,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11988059750093062229,
+                        9298754840020496938,
+                        15035120852882985245,
+                        1192754512132360647,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8283981142206306939,
+                        15468588506068845291,
+                        14286327059693983748,
+                        168088448899207140,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10471089395398769453,
+                        96236653784383214,
+                        16648343620891915559,
+                        253629587649938570,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2122557167897006073,
+                        6496047148498928328,
+                        4866370458783575330,
+                        393653606927582732,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1613936452873278524,
+                        13810247455069732717,
+                        15313868045534267346,
+                        1243393042619890514,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15475354144715027231,
+                        7573523099482013249,
+                        12718534995660881344,
+                        1280011735315941795,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16713988261819571430,
+                        2839652657083011646,
+                        3620474509759339159,
+                        813365112700437390,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5224860601737206030,
+                        471099951455807337,
+                        4240850224623809066,
+                        1213277143090320240,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8044997223262572963,
+                        8080347158505061529,
+                        3228586894312007285,
+                        331597882779275433,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1208998236383405070,
+                        1447425529631254332,
+                        8002978071615560825,
+                        1168296975382357177,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3717552334324548558,
+                        14379760040658949225,
+                        17287286958139444106,
+                        498156372540796351,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7205892599830853163,
+                        15212333206847125686,
+                        5699921803433736310,
+                        905915093597247729,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        755943729919434357,
+                        10577236669397908241,
+                        14468710867857253298,
+                        1024324011896165462,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8058282063231996405,
+                        17257430900010574484,
+                        2158055163476481194,
+                        595100858348853692,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15085559623482919871,
+                        3472078672722908583,
+                        4599317711792091602,
+                        222588611222065167,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15424099905499014070,
+                        8434797005632313353,
+                        8131086932997815225,
+                        1036565779889668097,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1382697922847342157,
+                        4665453833795528964,
+                        9747274806721360803,
+                        148779622932294522,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4432837085227621901,
+                        3625643010647482210,
+                        9188161200126159834,
+                        1031489011263833493,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2595560161561027549,
+                        10106811297951765366,
+                        17488308776579863485,
+                        206455909861435406,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        820166082021164174,
+                        9749064658839893621,
+                        2697165176777711952,
+                        1013643423254794247,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11434285783669558858,
+                        13584809878479701720,
+                        3915545683544186278,
+                        1023126538570256918,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2165364699284154689,
+                        12685002878895760637,
+                        6867136501788661212,
+                        674836357022179385,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9371284124941532260,
+                        7254488926567729428,
+                        14153148852060364975,
+                        563833886155167209,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12983913502532225115,
+                        10422261732254055015,
+                        9682741985590349706,
+                        1152902980215236093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        53453825694603265,
+                        7363785068269762421,
+                        10014136818496735189,
+                        418364251047633957,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9089547361822769623,
+                        2614400882196504844,
+                        18171746873737288094,
+                        75978327930221735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16792233542280595044,
+                        9194009742559712662,
+                        16681314096151276162,
+                        141104854369611948,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14121587945665084254,
+                        4285652051739813435,
+                        908329507035055833,
+                        1170458112249762210,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17832160706398652605,
+                        12757316993880307572,
+                        17823976387828534641,
+                        1060884446639737566,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12238967831419693891,
+                        17285564337796726189,
+                        772363080009335540,
+                        1133022491303716360,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7101023699634357749,
+                        6639281158265807289,
+                        4981868997280764642,
+                        985691418122098087,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11269122037409773082,
+                        12011421319544460999,
+                        1334150225599331569,
+                        2858146322225763,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9313709853509185496,
+                        16206428797220002184,
+                        13680333402027486517,
+                        1242753153191996216,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17569743508708392526,
+                        3142648113271106845,
+                        1634996132313805038,
+                        345389980530236831,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13214675085238735159,
+                        17546363296125310843,
+                        15104140193957885186,
+                        1009608979945975479,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13156206507994500002,
+                        15331136932468936255,
+                        18120353711369546595,
+                        212936761308279860,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15728832258520667556,
+                        2222718495126847960,
+                        9069363803574780412,
+                        1252511052567294538,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15318220334729666071,
+                        10085274749271496451,
+                        13235713280652147722,
+                        745052808139004576,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13156235912943986954,
+                        5448235351584523656,
+                        14912753996910833259,
+                        1074562042912540040,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10973489371567110798,
+                        13864494226359434162,
+                        2551801121655091132,
+                        842122627627640172,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16434063247854180644,
+                        4535379715543303383,
+                        15837253172489347337,
+                        811511753879666188,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17168911107864577236,
+                        43950174469174547,
+                        5104860072131484949,
+                        973362737471998355,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7931655442728732623,
+                        2231832287024216001,
+                        1215678170119206083,
+                        809922596417314673,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11089365147905175739,
+                        17689470198615435650,
+                        1802029570881529665,
+                        323182645037342266,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15969427871111504498,
+                        5489904452991350903,
+                        6060256254335150822,
+                        327519122975337122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8372741465623248660,
+                        14241887742859321780,
+                        5732992227950195767,
+                        39295360183511289,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1187756953137288615,
+                        6353707652463699269,
+                        16025288734445909769,
+                        689723399381686930,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3641027043425346704,
+                        7529359599459005223,
+                        2263874390889253898,
+                        1174711192482135891,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14213602576882685129,
+                        1072450781726178504,
+                        9166004798283088170,
+                        288986234043895133,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9857091378959575712,
+                        4700244761781549157,
+                        11886572911958021128,
+                        978573057754163783,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6859829417840328625,
+                        5380908914233546421,
+                        9985382358422821006,
+                        809479275163892841,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7129905675772133963,
+                        9947583967153283633,
+                        11488752011523120045,
+                        70770661730429235,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10339301687172431688,
+                        7034120066892139373,
+                        278535235730000358,
+                        1037618672688193269,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8073945182151246976,
+                        8024480602729778451,
+                        14228737842273667747,
+                        5601376964086932,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17455241286370251178,
+                        5877720710031178913,
+                        9559992722432789829,
+                        1325168601990716931,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4443170007910122686,
+                        7353422220255768461,
+                        15086677032035138267,
+                        1000401307557218310,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6016899902613423969,
+                        17460518555665406782,
+                        3732816219387681456,
+                        602171657802810010,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17215551508008455197,
+                        14155576318076749717,
+                        287763472218567445,
+                        348594781061367360,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13673281300516729195,
+                        2214275010578136594,
+                        5278640382055069707,
+                        862836485498449760,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14604602086577261525,
+                        17331229080491881983,
+                        9761345074674761926,
+                        92431445071525752,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        288667004247211189,
+                        8068558316435185759,
+                        34133259048288536,
+                        632325899262964414,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4414532264671055849,
+                        13933747433746332421,
+                        13027565118143790352,
+                        1050571576397137370,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10778047724985809400,
+                        1316157346886280268,
+                        10174764403037871455,
+                        1310251685035182702,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2901779093000588757,
+                        14345292131236108270,
+                        325687763253834560,
+                        1302296993426055332,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10195270688246580514,
+                        15529132938808577668,
+                        16655748986619374751,
+                        1334926369645181743,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10219117138329677661,
+                        17968691841862978952,
+                        2586002244710590318,
+                        890496969841145620,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2498245601951074289,
+                        1598646875827827107,
+                        890275996690762376,
+                        77165559003555915,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2988605310258792866,
+                        15957801327838169961,
+                        7584840999811153279,
+                        434926945147154735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9597713906228190573,
+                        13530531350309731794,
+                        11006707392867763256,
+                        1180021669203726874,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3103797984913840610,
+                        13327065637625915613,
+                        7317743376243954428,
+                        454606898571769263,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13418762319452988162,
+                        7011698969983978549,
+                        10043811570622910529,
+                        1219725988569937648,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16415739938738212197,
+                        18000325972331813382,
+                        2937222969358261592,
+                        751420552035148970,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18143523461998133814,
+                        17501449569222234751,
+                        11831229978852878240,
+                        230065434288493720,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        390587431735370215,
+                        6070182885512254444,
+                        15678300086321846232,
+                        658622796995655025,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11187073703980753366,
+                        11256592440200977896,
+                        1503110027135689183,
+                        1132851611041610655,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13154916648506390915,
+                        5339482676807482888,
+                        7230250399862288620,
+                        218460204422790571,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9102410326146594084,
+                        6165251247104570609,
+                        17897733375690573799,
+                        920164771069067785,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17285085289295929853,
+                        16238198804092185825,
+                        16251509486414134096,
+                        1084909706592090544,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3880782017033225674,
+                        4873252603362867352,
+                        14459262730771211791,
+                        163174741264077656,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4363513037339290429,
+                        11071402644779655945,
+                        18203948441237058377,
+                        487870628328334554,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9854620422114551002,
+                        11862250176770700568,
+                        874594626487585300,
+                        932096667364472947,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2172530204291601442,
+                        6393872278636522840,
+                        684019909052108375,
+                        180921268676761997,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        747820584568953283,
+                        11441694611866320992,
+                        4524856196876367929,
+                        874564518710804172,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7299335342253825037,
+                        12867703813750147845,
+                        7941311700245180087,
+                        1108892033870182325,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17832410557204971163,
+                        4075792975869963488,
+                        7197135716780572438,
+                        152677169125496593,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10163931248253127785,
+                        1981713163631115965,
+                        3150372916074444430,
+                        470994848988866265,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15083629881223276978,
+                        2015879515814390952,
+                        11644449746714623084,
+                        946361618448877426,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16592814677886046887,
+                        8039452671655169748,
+                        2594546365519790998,
+                        584104276493702607,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17407066489676124305,
+                        8656046168534251407,
+                        3138964226038501382,
+                        514356098263219093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15942565322057954282,
+                        6097316293590202889,
+                        6372629697231874413,
+                        113242342217125329,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11571924722120150581,
+                        13912164049336724059,
+                        2502413236406840486,
+                        679604324439169221,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11231555889522859185,
+                        12838308109173382219,
+                        14989494611471795387,
+                        1234334251746422128,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11820760137592674131,
+                        13233941704214912620,
+                        9184955620669308585,
+                        760535087243845704,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18251843258930069429,
+                        6913182660293023569,
+                        15611295871669355399,
+                        543202252785444658,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3623801569902716815,
+                        12012005568369974445,
+                        8975238911566253998,
+                        1306561840926137970,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11553511503558092125,
+                        12278824151250337447,
+                        14503589672502199303,
+                        1306659756102330132,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15708785553057654326,
+                        3840692890311772930,
+                        4102206509788468425,
+                        440698181344607784,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8058055302582355971,
+                        5700106105541005064,
+                        7460926738156841111,
+                        462277946682165887,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4851568100746305461,
+                        6584456459134975532,
+                        17460185792418666468,
+                        793703974849869892,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1493013092751501655,
+                        6157234009848897017,
+                        14948235164845831294,
+                        1116239172277392848,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        541291017778122597,
+                        4361606389405799905,
+                        13559255135673617904,
+                        164046297400629617,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6157883725635917010,
+                        7921949762718398664,
+                        8097426475493743176,
+                        285172774886131081,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        276501516953041810,
+                        15996807350070926146,
+                        1269758076943886300,
+                        195784581070827446,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4853469952320798393,
+                        17379711604415333498,
+                        11341428304819039080,
+                        766000305508925099,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1723028172270801784,
+                        7846165827796379646,
+                        4636451005692610955,
+                        871151940476972966,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6590646061221323902,
+                        889461654044032879,
+                        1505496877923673922,
+                        752475483640516924,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13045448500706444324,
+                        8361654882120486231,
+                        4205925187373381862,
+                        277826998934433801,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9675553409793389785,
+                        5667173122152825040,
+                        8083271914684780456,
+                        706216661468574727,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14266510371042388064,
+                        7106905440715991100,
+                        10411622989018615279,
+                        894382081849790039,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9268336790197882301,
+                        12070173591607011265,
+                        7396189305808814103,
+                        6826922221427887,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11481176188982883772,
+                        15479330738046464105,
+                        15874300506335824,
+                        418080356004810461,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8402920895853151075,
+                        11319528131289558551,
+                        11236635718047704076,
+                        590562434419913557,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11942015297507883097,
+                        16315121378556649183,
+                        668425812435475072,
+                        1203236348881767128,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14719743395974511202,
+                        1514583382813202278,
+                        3223906165579006067,
+                        1338589921222659040,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11353873627418368002,
+                        17696069599691683167,
+                        17867255547403463352,
+                        256390082737611698,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3183181278357516648,
+                        10539132954816790294,
+                        14186140147230647162,
+                        782128376830566716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        24279344590760052,
+                        1500125118480868266,
+                        1805095232159146495,
+                        1156936758956513949,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10537024754772614474,
+                        3289834431146907946,
+                        204955315838625775,
+                        635285322161689619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10063527467101509243,
+                        6065807056365479935,
+                        17027546583602504025,
+                        991768228369353932,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10809287584503006407,
+                        1968797130899798907,
+                        15335034484681843481,
+                        581199600450356946,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5342411348500260963,
+                        6851928330103451454,
+                        17931963062212165213,
+                        1333165923948214303,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5362873004958210141,
+                        4780911804231906429,
+                        491658981443450273,
+                        865870945658130370,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16170855628896579013,
+                        16924415594421177943,
+                        18099375624011254510,
+                        1172947874675982311,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2313253813846593553,
+                        6024058999881721714,
+                        3460494784065903603,
+                        200192466049298184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16495862644170305555,
+                        1027481903728649974,
+                        18424464377133050050,
+                        286370147149132790,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10500420552294712757,
+                        1190864375567816587,
+                        7753792083623713506,
+                        527733109368565721,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14443372038206196537,
+                        7620145268069197030,
+                        14646532763986835342,
+                        456340242357593922,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9232251606394270216,
+                        14408677736204106911,
+                        13271324872968281,
+                        759107595695446407,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17249519010359595711,
+                        3324593693860748091,
+                        17110518692688522506,
+                        1222951351162422358,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18126276283902025445,
+                        4360757737312030104,
+                        7886535856284373354,
+                        1092174897468921446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1062163077313937380,
+                        11987404733688401354,
+                        13771857460633546591,
+                        404835587063112873,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16497288776209779034,
+                        2880366049054710506,
+                        253042145042914898,
+                        795216235095539205,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13406148328760919071,
+                        7241933139744138143,
+                        12901549130103097508,
+                        622176358374555103,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8839697166194087406,
+                        4483906360586614080,
+                        14882309942760908718,
+                        26805057196885842,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7780277578177316464,
+                        8830183239891425324,
+                        17543080001337922949,
+                        763106694028031052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1380876235740608953,
+                        7727437954586184212,
+                        6573237846455394763,
+                        348129619934932866,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14022759702194409553,
+                        8495575903624052978,
+                        4435568764932750980,
+                        556954649769758600,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10833230885057379503,
+                        14946233534988385740,
+                        3482517598744002245,
+                        312685728556510786,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4935346722124605172,
+                        797590664817406232,
+                        6711744841558593604,
+                        1113832946092370199,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4507604340829135994,
+                        6192904589647086945,
+                        349044365259597977,
+                        1152686511406973589,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        195352152493747630,
+                        5233440319211994798,
+                        932481733874805995,
+                        509755197807725335,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6237574338367694863,
+                        18409691934826828587,
+                        2269618770290486313,
+                        1105546858321591640,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12982330271334867216,
+                        3689728804743182371,
+                        11969860905888785248,
+                        384135728283136906,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18265210337391869731,
+                        11610294943475185851,
+                        7713269183082030658,
+                        64710928906612284,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17581461696584771576,
+                        1251761997536591051,
+                        8677916614754373573,
+                        11467824250957215,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5293383473840045151,
+                        14232932703686129211,
+                        16580168622608048461,
+                        944952712971843416,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1172918766922903979,
+                        5995995512044846696,
+                        7739257607297521882,
+                        1042510915533280011,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1981724477088009475,
+                        13354315713534102679,
+                        11891854408097089989,
+                        92846148123740243,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        976886551936370648,
+                        14904356347049243804,
+                        14591979302472212806,
+                        1213715283121821429,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15587984398787314569,
+                        16826502960315805008,
+                        12894364377505862261,
+                        1260461626778401163,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4959549071568407724,
+                        16406890426995168379,
+                        5205271867547136181,
+                        625367672303411178,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4469737807624992151,
+                        13646441970050473001,
+                        14103514615210198911,
+                        951934596751743825,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        179935223424484463,
+                        2716781758867967994,
+                        13772831748270319508,
+                        559465738003921114,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3732370725359378315,
+                        109679493243276219,
+                        881555622161027305,
+                        772217225730861177,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2034891371688198908,
+                        15927708347683796662,
+                        2835422031008901944,
+                        942335024460335016,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10874231289292346814,
+                        11117313284388646608,
+                        16125883931973538451,
+                        1333477728458259050,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5836661691500937581,
+                        2582555451477250318,
+                        16743568702164980685,
+                        1095040268607012655,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7272954408297607208,
+                        9150036449524740804,
+                        14522293235478825462,
+                        1307814718608590686,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9615462087509512420,
+                        18407006699539389137,
+                        12105937210870320021,
+                        334915717490335812,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17236044599015640012,
+                        12993196287659936438,
+                        8029463234952181249,
+                        545652280276967426,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2028197388281786506,
+                        558088757198372204,
+                        17637626445203773435,
+                        1206798656445161141,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14761130123764179949,
+                        11265418430583612050,
+                        9818498330663139783,
+                        1022730897119531235,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3397615805705630748,
+                        6391106927597886648,
+                        6649902044245973904,
+                        1089216490536416501,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13520221757890281877,
+                        10363620320323358890,
+                        17751324121302852883,
+                        90107110007144405,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8255875604531179082,
+                        7870112706925074825,
+                        8789146877826847680,
+                        642889733624171074,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        493962067133449125,
+                        6251544006669872698,
+                        4540239940923512372,
+                        1060959206237777039,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1638041156706212423,
+                        7836615882250146690,
+                        7159390707031842562,
+                        51076366330398700,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9381518452168120554,
+                        6998771482303486456,
+                        15805225993563042757,
+                        843313123821958011,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16610368883323126324,
+                        5133954399952941512,
+                        9402738346287023279,
+                        351094450303845842,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13793383173338905032,
+                        555783363233499261,
+                        2290916186938951351,
+                        1171269890274820780,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15065481074819387429,
+                        7933416354301914107,
+                        15838279605023134691,
+                        379558760919784012,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11659972597161106093,
+                        4621260883882914725,
+                        13050340896830784591,
+                        1138910519212416977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1207220212800265863,
+                        8593185070859409436,
+                        7405669801502705733,
+                        112729665687908497,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3239350163354313461,
+                        281190260843557490,
+                        9211190811160347560,
+                        1010020323979026306,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1284067375064761374,
+                        3119289545587143065,
+                        5521272069904653728,
+                        1297359846962348366,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17206900220473786738,
+                        3934148234889215104,
+                        8942208631684299206,
+                        385635042117115670,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3035693519520552213,
+                        7751689972740612526,
+                        7929110442843351753,
+                        1078850165538083987,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10062197504097587107,
+                        5574029026868088219,
+                        13451373717661660840,
+                        342419957849760555,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        961867630483600628,
+                        18374831599456426287,
+                        2350261434007070707,
+                        314988826229673684,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8225912600509706549,
+                        12551273902107715227,
+                        14047402258665388848,
+                        732750874078790041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3999058566486880127,
+                        14014421094261510839,
+                        12701584083394576446,
+                        674022820778092504,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17781610685659160500,
+                        12064398148064574951,
+                        9154220438468605483,
+                        918647185633428905,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15729762829349542350,
+                        7201661912546822625,
+                        13728939555194207760,
+                        1246317711931391672,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8707895413220798182,
+                        12624250257242923552,
+                        5403448851877000617,
+                        1166306954067792456,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12653562735946834290,
+                        1139377175118639030,
+                        5651823009734352462,
+                        514529582492408282,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5797481280888953984,
+                        12981375722336761650,
+                        182885642560228444,
+                        1168581205717384764,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2661469267515725222,
+                        379870277565166473,
+                        13683919101665406775,
+                        1086828519991137133,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17106358258477527572,
+                        862126438317236669,
+                        11022025808495719341,
+                        122582945847829090,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4462113964606366808,
+                        6685983090332514524,
+                        1129140139985576629,
+                        507584234561018332,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1132337429632033321,
+                        3823599951594069732,
+                        2193709386667135165,
+                        1236457478161012634,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7295554569233182042,
+                        10271037819482158833,
+                        4836718031280547132,
+                        1223096792135065407,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15761307531305557626,
+                        2887539586536966593,
+                        13411250777849031783,
+                        789097369785198371,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9572684182898608939,
+                        1382744000565366596,
+                        13033256134154659492,
+                        635979339356799487,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11225195515300533674,
+                        6245354862492786284,
+                        1846272150325078594,
+                        77193619654172066,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        62450132435679151,
+                        9581600192123758174,
+                        12361811330504691327,
+                        593665006061150970,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3823886276986122435,
+                        230194351718782287,
+                        10988338215493022121,
+                        957611394181382098,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17828647542966135186,
+                        12559071362566525880,
+                        8924272544315686239,
+                        525357775419631166,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12064976607363716861,
+                        9557108716451384760,
+                        13033135122110243219,
+                        864135767934661026,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9006015801642298696,
+                        11905789847137225786,
+                        12934572144306262186,
+                        937434192995378873,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2498788401329376705,
+                        17437774128056063379,
+                        14580931628779833600,
+                        1209233584466886844,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2408955364037030556,
+                        661255501078777470,
+                        684479895245154107,
+                        370249757201578228,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1843717231052307275,
+                        8483085129350566093,
+                        12250076159134503815,
+                        594572048866108599,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3844349169312861731,
+                        3290024291307116879,
+                        3311922699557702099,
+                        940587757097905444,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12364750559721141440,
+                        1434269415179170799,
+                        16289942080479719492,
+                        694706382296149335,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7727334987313469139,
+                        3684586879155807757,
+                        7634532317358565663,
+                        467610152665338952,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7498398324339021663,
+                        10994857941292064016,
+                        1411102589804037621,
+                        517563189052899576,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10886318112938164681,
+                        17997385380398909048,
+                        12087350728746332655,
+                        246695327443072293,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4422535172031419548,
+                        2094577843748590531,
+                        6006445973587027650,
+                        1059556874478372576,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10162805717194651333,
+                        14667701949053148404,
+                        15250855670890210494,
+                        572189705944441118,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16765806876900276035,
+                        6759206828606315160,
+                        7141091610192712120,
+                        1069845515863391560,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14344648303252024197,
+                        9575892383110019743,
+                        939997432210588561,
+                        557106812301633162,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5033423542475230049,
+                        11590395263219580550,
+                        11032453549046453124,
+                        170939121619456497,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5578629766462504009,
+                        11424202874208942573,
+                        925976378920690530,
+                        1046046618610942848,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16847044599344042533,
+                        1750663347277960842,
+                        16012059272350704891,
+                        1108675989244826302,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5800989694687915657,
+                        3739899449981573496,
+                        6075309461218345297,
+                        1055017687490248791,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13346572796857301619,
+                        5339774140426300043,
+                        5039021463508138557,
+                        524980024520881361,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6898659275827743046,
+                        13901814729653076924,
+                        16383855182714589105,
+                        722216034199274348,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5183394081107863970,
+                        12303644781615471063,
+                        570052307164507341,
+                        351525197866255740,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4466536525633401690,
+                        11661237724780133590,
+                        14083736171498126335,
+                        740581978032286819,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18288013875743096510,
+                        7148342341976443371,
+                        7633210862073752826,
+                        385654822414450345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8724187626076759299,
+                        13999015487182382083,
+                        5533776191496991112,
+                        1145413120216420422,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18419698666013894445,
+                        17711873820645277546,
+                        14924975618898891703,
+                        779247526873503361,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4709094663480610714,
+                        2870471921442205175,
+                        10715646680651630902,
+                        605337754261415490,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16395162107860402686,
+                        11684173524666647390,
+                        3568098609692464443,
+                        902174503082339932,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11044293607025488446,
+                        17926861541635682965,
+                        6540465591685515656,
+                        227174276440259549,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17868067182523420348,
+                        11301039131912622542,
+                        2085777367017721138,
+                        1209317175670376465,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9167057021498686818,
+                        12159938597512696378,
+                        13098069417969855388,
+                        1174026067762710293,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18145931985257822804,
+                        5934137506828809738,
+                        14910470689037040364,
+                        340964494610779317,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3781206598182544228,
+                        8833971885326798235,
+                        3187183541437596275,
+                        535352329696979138,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12229965685793762485,
+                        12247028209692446990,
+                        9354824908809533528,
+                        732393611327959337,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14910501517356563485,
+                        5922591545659115368,
+                        12006566461121091783,
+                        1109677184411063641,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6043047473751424952,
+                        8284849742100872285,
+                        1535916096241729005,
+                        484161042148795598,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9410905451563043998,
+                        15147518528248317349,
+                        15533834310927062836,
+                        421256081172694367,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10290626199586927938,
+                        11230702936813880589,
+                        1299074295039967956,
+                        1017863392713767088,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13577662929803192733,
+                        1401858899102912859,
+                        10400240577009220194,
+                        781961109075234322,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6653790398728346808,
+                        4695523185619880982,
+                        697336489888226770,
+                        726920985157706183,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3159639908199085958,
+                        2695789878391046095,
+                        5366917592232281518,
+                        81370373594936674,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4403363675490454484,
+                        2186524578298673747,
+                        5007176625499799547,
+                        1164984869260515162,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7644636576120247260,
+                        14821748148165179929,
+                        13504505699517500378,
+                        424801585832316072,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10301896745525769285,
+                        8096552463599047617,
+                        5944530158281918664,
+                        250793894298299535,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4191115856043005251,
+                        10373244725733958476,
+                        17178294474317898764,
+                        646464294781661892,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15771664056331245718,
+                        17174965474375682244,
+                        2678341978215593351,
+                        82219838688477376,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4085762582617562405,
+                        11608380630147431236,
+                        8149678923651170384,
+                        978890503399573036,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17830752938765429714,
+                        7184706142463358061,
+                        1397555943956004040,
+                        65036538747776574,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8865984980020843879,
+                        2448638968405051035,
+                        676699896323015871,
+                        1125471769043997945,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8068425993748972561,
+                        4432264176576689029,
+                        13082573549316738749,
+                        993387685754647514,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4018664279067706141,
+                        14094282584562318571,
+                        17380794792223157436,
+                        66471279626462894,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17288252670968110927,
+                        5227175746656122200,
+                        13472778124019350892,
+                        220146837983012699,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10643244037313560179,
+                        6081065131094781088,
+                        16920272857335835614,
+                        940325413712506934,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5687551605014413083,
+                        2089568318160185522,
+                        6118073301032675842,
+                        500489798830408848,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14553539354345370873,
+                        7859731015207556500,
+                        5624847653902043852,
+                        578982390490476831,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15900140413952794451,
+                        893101770718357872,
+                        12253279095769718103,
+                        597598722536683753,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6171931393574242029,
+                        7481952020956647691,
+                        5123674511864395741,
+                        1323321654737184178,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6976560520555244459,
+                        14122159967779176699,
+                        7468022681368522500,
+                        1274113434534068743,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4026528393652805686,
+                        11248732096468729498,
+                        11461864881865600807,
+                        1107291851689543570,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13018530565029348972,
+                        8462004868221169380,
+                        6121306318177340481,
+                        156084862283926736,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13505007077145481084,
+                        13845640603320911552,
+                        4997305900496390876,
+                        732316013695932031,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2393542303302904669,
+                        15859670377526501276,
+                        9144245951093408972,
+                        1040167980713108088,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4446703023770863441,
+                        3861306947080741406,
+                        7686714546849211094,
+                        25993725491708418,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3570446663678888707,
+                        13936217713518480698,
+                        14116617254147563488,
+                        976389609854215902,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16123715386389116046,
+                        9905198142805644007,
+                        15276161708782560722,
+                        340920678203989185,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15705001649446996337,
+                        5927112215519259781,
+                        1369516241998045727,
+                        598202717143699446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5804196156202889002,
+                        1452691500422653968,
+                        16714444634302207630,
+                        300393734533526715,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3035441568784729132,
+                        10444476663637577719,
+                        4977343932493968580,
+                        147054477701602730,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7030415533995837898,
+                        2132173450829001314,
+                        13035864360083769433,
+                        964009864748951838,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13992379239547063356,
+                        4199827585111691358,
+                        4908249549923256339,
+                        1240008050740372209,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11341209782612315006,
+                        2425658864548297678,
+                        12016225902723619566,
+                        889038623707219391,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16675838187507881016,
+                        12690135722867958636,
+                        15927630831903857693,
+                        1074242922744098125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3328224466270414299,
+                        11041029638415533390,
+                        652423839543195594,
+                        1061353941738078484,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8733432716886388559,
+                        10939669818831927535,
+                        2506122269725671047,
+                        1107503572276222150,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3240363220292518700,
+                        12037420492591425646,
+                        1197235960087483306,
+                        1258957998781608197,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14554652915192239947,
+                        18031098715408556424,
+                        4694790131907547889,
+                        883324925022817181,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17386437626347316876,
+                        4555679138620518247,
+                        969304620516164315,
+                        476799026969148079,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8144704665060767310,
+                        9900811495249531532,
+                        11303594109354299064,
+                        502504246276503749,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6339780441681273850,
+                        17976602320342010811,
+                        1070605240439213753,
+                        99066296187423662,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12786087202570056406,
+                        6997907048888243191,
+                        2098554864722325734,
+                        738120060340910561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15882555994514887767,
+                        15208467046424205218,
+                        7408339017451034985,
+                        1238154211593554794,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17363762391557379825,
+                        12778846882522705440,
+                        3645626067821139057,
+                        629777961245498748,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2957156405516723395,
+                        9979851791298937659,
+                        1115348871876413375,
+                        750710832116613264,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12119174374935438205,
+                        14209012834135270726,
+                        10600783708590810082,
+                        765776905381177753,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8697278532695175918,
+                        18225742026058655411,
+                        5661799323693198890,
+                        1233526852997926792,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        468766088142412643,
+                        3160862635697685188,
+                        6395788502247663949,
+                        218791903929878966,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5180615016980082024,
+                        13557321170651873580,
+                        7926152958335417069,
+                        594287368702196757,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17406386008962290864,
+                        10438336384122739518,
+                        15304702974428122782,
+                        209460354999928643,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4144985173793757105,
+                        17971204422725454529,
+                        10237018180243402083,
+                        890699007178382118,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18059543621598982088,
+                        11982474294272819658,
+                        4868358608704900794,
+                        3425147248674902,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10350712201751586049,
+                        16319524133965145565,
+                        3434062049619038601,
+                        119891369827757457,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13125679824326994777,
+                        7935091836680941289,
+                        10068710515101984841,
+                        335105137569601667,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16110368975913168041,
+                        3283922234805989730,
+                        17892881948227133877,
+                        427840574359955040,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13582514067860265546,
+                        2723877331441070433,
+                        1134207714247377668,
+                        1024804284828908633,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6175836830317278193,
+                        15070346375630446500,
+                        8862858519521207114,
+                        30522008570443920,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14796948757275666679,
+                        4169747431710721708,
+                        10841445060850351098,
+                        1000211730335291732,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8892518387700022991,
+                        16454672577540783982,
+                        5650799731691358319,
+                        445735384902835448,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3449032217666558837,
+                        5044795803001221225,
+                        13160012823342326122,
+                        1060391582391827487,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13312868733182153714,
+                        295351476451670876,
+                        7909543451507347544,
+                        666037973039312792,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12410441817505771418,
+                        10563360586154821064,
+                        11722875465263431929,
+                        329105685477031152,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4984958222163574337,
+                        3041711074877505031,
+                        16043105640028680879,
+                        1151438422928296676,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12513953470079542352,
+                        17151522349497864546,
+                        5546028103978921224,
+                        440749815381940226,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6815494904900330359,
+                        12484014914065976789,
+                        17840020643696395298,
+                        696028124235627131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8647498275891190166,
+                        8199438906555508042,
+                        17875024411641326503,
+                        766283699353815360,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10315966707941602222,
+                        16988472084297465868,
+                        10278659102339317717,
+                        461131069743579463,
+                    ]),
+                ]),
+                Matrix::<1, 6, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2027569736855988229,
+                        3443358028817753576,
+                        10248913187513668497,
+                        484875842600399256,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        315645972760446850,
+                        1051319947926728528,
+                        6102294523138195410,
+                        801450899228048483,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7361457885721690221,
+                        5509008168623010360,
+                        11995917889188849754,
+                        268981664616658129,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6563017045678796643,
+                        15102280841921105590,
+                        5176633799449765872,
+                        826991097513527395,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5035730383074342491,
+                        11335655553860541019,
+                        3023706514655207358,
+                        759723394965953279,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3942507690903999150,
+                        4572939882599150212,
+                        1899762978875194428,
+                        385949431643067836,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6933713838801944433,
+                        16845582094611513195,
+                        18106981831632269118,
+                        1315719765541495999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11025937787709817432,
+                        2848215840891863663,
+                        12003429085947921194,
+                        123169188111155826,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12625372430371999830,
+                        16677955247940128803,
+                        7323211740492754581,
+                        634092645821649835,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8476375831616065903,
+                        8302751278140753414,
+                        4675397025515049474,
+                        916054888187664134,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10397071512469717592,
+                        5168805500057287779,
+                        15231087545157212262,
+                        795647437775574917,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4753644871978862709,
+                        5862282760667146308,
+                        12460694012157225697,
+                        599558919665641703,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3525611259680496361,
+                        16850996105995319551,
+                        5895804919214678528,
+                        391214039273829079,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16383982061649260213,
+                        7412768188479417373,
+                        18252968433587413341,
+                        921842098972532091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4293960317930015621,
+                        15005027020601642292,
+                        14505531830207858927,
+                        1015323632458039193,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6752151532781968377,
+                        16993009794999287001,
+                        14092320031767020691,
+                        1182677145301447547,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16314233041829270002,
+                        3649678206581272066,
+                        11390243497395273567,
+                        313040234242687401,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16897077646049598862,
+                        5408248314355013755,
+                        10936401950030912526,
+                        513592532346930326,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10142077329767560760,
+                        6792944251304638223,
+                        7902051751595654458,
+                        52562103742172771,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4124245661449561978,
+                        16104205151533781959,
+                        12907591592992771975,
+                        375874492004655234,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        721737094669186114,
+                        9206501775775148139,
+                        4450459856140585915,
+                        711734015520322130,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        614920776545720184,
+                        9338526590820186332,
+                        3423803905067391290,
+                        1297483125050494498,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5114006628798839509,
+                        10631775008980466663,
+                        3587509331998934684,
+                        1269615621594518563,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10586294365539695697,
+                        13070162917427803989,
+                        5131427260750659378,
+                        185094384545327415,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15393156556409725569,
+                        12069454178577165134,
+                        10615051884848589576,
+                        627431092634767494,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2254585774072887271,
+                        9054648099999668560,
+                        10092512970859140868,
+                        128794736276163271,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9634188559612410031,
+                        11231916523014098458,
+                        9347609823309203991,
+                        350537733562293921,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        712883344164921598,
+                        12267969327296118133,
+                        18415463544294982318,
+                        453347153304216575,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6354724406448162447,
+                        16973074452059542227,
+                        1742850061709863465,
+                        29248222949323830,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5847808690552004737,
+                        2583092622487380856,
+                        16226455529753322167,
+                        622812582439549387,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8037108896489144647,
+                        11600187371086455875,
+                        14226278393962213262,
+                        1257430093680684711,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17768344430228638036,
+                        12362984821927414562,
+                        8755702286219707347,
+                        1052522743722421089,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6518969013844050226,
+                        10104698889580418215,
+                        12831301188037688559,
+                        783109772397025172,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6181962216281397012,
+                        8791420351320694807,
+                        5845391987758232061,
+                        691946495243473347,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        555663508144587651,
+                        2813166513808555896,
+                        9604748549845359549,
+                        1115460934710212864,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        638373104524889548,
+                        6961133691969109992,
+                        16899272275885442322,
+                        107354495377389126,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        359839753750440452,
+                        16872903944693818879,
+                        9182594678934428762,
+                        157875028486713218,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18133892901854313763,
+                        2808183347815612802,
+                        13420838164084790320,
+                        592901990662685620,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2972894661558754427,
+                        10007808519010762631,
+                        9092666282720580619,
+                        1045475284031737143,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11312374728773702090,
+                        14199049380673735694,
+                        6883328492121377672,
+                        254205863439515968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1622439358680448127,
+                        16670668058993745597,
+                        4548903667405006277,
+                        711571731093376968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17647983290779098291,
+                        516914030435843715,
+                        12098656310263379019,
+                        275469573180901388,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8548644889741041300,
+                        16958143034062232997,
+                        13155023865968925107,
+                        527671552750155065,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13280891140215524593,
+                        3305529464796220554,
+                        13769067712411857696,
+                        194876564429210557,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4744158468698697612,
+                        16501033922635430369,
+                        13118709481247752005,
+                        455296334218894134,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3276121206714262439,
+                        4384108406151911994,
+                        10320409945935199931,
+                        1129505288669932775,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4802683862047720627,
+                        12048656597166121442,
+                        2985941889978900161,
+                        1215862908384237465,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9878053342652269422,
+                        6952038940472105574,
+                        13204721887023621809,
+                        104559270963520601,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2884484268998399392,
+                        7516340773367415265,
+                        17967577411328781785,
+                        71104776242823513,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6836357534971200276,
+                        12307486411100090586,
+                        3725741383492541705,
+                        708703587844324306,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16065785508863042824,
+                        13168438874677663185,
+                        10334681499739682637,
+                        537952869573618152,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4276306945181457487,
+                        11319798687621632943,
+                        11337729997070203637,
+                        154843360608516964,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15091173599951556922,
+                        10089665296762720564,
+                        1001320269268178313,
+                        1278999239679089342,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6790806302663889439,
+                        15630966142638650191,
+                        7219114191273641870,
+                        607000211505329118,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10941156742810682792,
+                        13379581544377778361,
+                        13597487267674595073,
+                        856893196159093362,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12116163542944543584,
+                        10494464956681785456,
+                        5328861516400529745,
+                        1198358765731586889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18189172451487902278,
+                        4704482581638044529,
+                        984078958545158234,
+                        1214725173814978653,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5693469689620468779,
+                        9572644835043944500,
+                        2366490855072377760,
+                        976822768924111263,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13490986302612722028,
+                        1283285868432826268,
+                        15164008493321253514,
+                        443251753067442416,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13150294025593833313,
+                        5620721896853634047,
+                        4766918037148084086,
+                        1004371804232239176,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11674076986569886445,
+                        14273565036215986478,
+                        7256033114853758831,
+                        1243175635214446154,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13391657488427814604,
+                        11740905283874021531,
+                        9224412564843665854,
+                        277256927127426063,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9479795538791379585,
+                        12974816116199774846,
+                        12093010719550875010,
+                        5065395771365623,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8817914873159831624,
+                        2104380883716865129,
+                        18076504449817050016,
+                        651566378088004291,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2593549556231375650,
+                        9464329604880807882,
+                        931952291445829364,
+                        1296192193647975938,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6791830127635900713,
+                        5571583372402235342,
+                        5980079258218847792,
+                        609581694037305767,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3590436137035362796,
+                        6878827383468400586,
+                        2087187619625759972,
+                        366519334389468484,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8466797588269795178,
+                        6233162019605045514,
+                        7818079020321919455,
+                        1299893902682642414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13213818269144599466,
+                        1289947883313972118,
+                        4059701246652915385,
+                        12056266522820237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4508800680162331521,
+                        15986068886298500867,
+                        14977838885955851194,
+                        38157078418942241,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13953378503175087488,
+                        2632607770308512210,
+                        13562084469560012417,
+                        245249845748719170,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14192710935721498803,
+                        10423371171643016558,
+                        8787645447508907660,
+                        1222320713106865517,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11672182316223918869,
+                        6397196540158184432,
+                        16362125278956639955,
+                        487254253940971622,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3466115228336022838,
+                        17336865038830586933,
+                        15011622032603429066,
+                        886109464755529302,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        60790403003891626,
+                        11789627958407425130,
+                        18093678967772149866,
+                        163664659042316929,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11752605341201064356,
+                        17735918082592333925,
+                        6652470350194431394,
+                        889452019796415044,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8401712234167110287,
+                        10960633415813906272,
+                        11916450035773002049,
+                        1182232446618175169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10396262244990196196,
+                        13566621656449952195,
+                        17637374041715454767,
+                        487328172506255483,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        359851191963578459,
+                        6093707674603330913,
+                        12382809233400904418,
+                        822869288595513424,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8685375674428402008,
+                        11116570913984986227,
+                        13141958117760910576,
+                        694631449425721940,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13799808203577028319,
+                        17015236177612766046,
+                        13321925256996230040,
+                        83728605392058044,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11345927491644574710,
+                        6729113552022061366,
+                        489680359452713554,
+                        1063665064872602168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5144672341518660971,
+                        13584780511637830006,
+                        8111044646642319859,
+                        760808052520956883,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10534617268344014793,
+                        13365424830059569868,
+                        15323686518176324368,
+                        630253372526730391,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5574736329985492350,
+                        1394240824897034276,
+                        12869158727379905882,
+                        927371660714513620,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6250879616233772588,
+                        14830533363705576088,
+                        11405716231454843078,
+                        302485753651389813,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9376998902591535768,
+                        10870546783769916945,
+                        8867938913668980167,
+                        168036903158516371,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17665300127173928717,
+                        16211022938249470990,
+                        17778018974492488988,
+                        1035038949736118270,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8713486127840843643,
+                        12284089155092108591,
+                        9199322335133216480,
+                        1254936361875119138,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10672903121209769745,
+                        1541284167923703332,
+                        354508315039816196,
+                        1077919680069474774,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6003956627532398687,
+                        12900385821050494701,
+                        13212436788748797499,
+                        550552294639902063,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14895950277557938580,
+                        3197587119252958842,
+                        1370401976516442748,
+                        185312282262907769,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4334813612319164060,
+                        1249839787510419412,
+                        2014732433360765992,
+                        674486378020601344,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16372536311394952793,
+                        16876000734634230902,
+                        16256142784705290990,
+                        1216045970949193228,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7511261605071474477,
+                        12731027885113475848,
+                        7275278577140348070,
+                        1332751723743282012,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2087120385657061588,
+                        5805219940951110446,
+                        13072060426636966211,
+                        863754038349923743,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9861938477894648572,
+                        1261863888379893097,
+                        1659273544923639757,
+                        1175309734597332353,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3160898884988868850,
+                        12315548764431121182,
+                        12829922584215852165,
+                        56495588159121736,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9126361106532779429,
+                        8812351712430538764,
+                        16651360315827640335,
+                        488907765340195245,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8831436795438293115,
+                        389432919099331880,
+                        4151373017145697434,
+                        20487081091778889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1478664308612791336,
+                        8186322499300142837,
+                        14558475073251694928,
+                        808252273304974762,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15607838810385275909,
+                        12674581021244262249,
+                        1909980166381620782,
+                        345071109506949067,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10897251009229233031,
+                        3820618022447517075,
+                        10985787470877927823,
+                        403556049280121762,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        930794418602631676,
+                        4219345337777307051,
+                        12545787195024594643,
+                        727460251993377030,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1295669867887905036,
+                        9507152194066574702,
+                        7109356788802489574,
+                        787002368140653778,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3498224858763889755,
+                        302153864491265874,
+                        14518919122820805288,
+                        961799431413434867,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14579188115252453346,
+                        8486675243511966662,
+                        4198502112353568422,
+                        266466804927785090,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5714787944090956648,
+                        8773050216612558194,
+                        4209799818844007033,
+                        157683362695822804,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7490636877172818331,
+                        18234545141478570777,
+                        4868926515076353497,
+                        1040485086378507404,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4007494498414670332,
+                        11381886286508088565,
+                        2983212756609056257,
+                        310114683693996305,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9176086588288648852,
+                        1592592270921797535,
+                        4569673380486221672,
+                        1302061240324827715,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16913435421079334086,
+                        1877470558106542334,
+                        3613514508584067134,
+                        934461884972210272,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5267175603942094137,
+                        1748374762382459641,
+                        4882024791077414221,
+                        1068225445733947215,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4248184777234069323,
+                        13346185782403877767,
+                        10153633758750203581,
+                        141684902152107360,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14329113735110304247,
+                        4241308009922883952,
+                        9772848400881136490,
+                        239381311112673117,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1815346937549645652,
+                        3479767444023955389,
+                        4985298868790684050,
+                        355453443190923870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5747089878066396614,
+                        5454329989338975845,
+                        12341448373351673623,
+                        1202238166588655979,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9296701061462558971,
+                        17770007858418801719,
+                        9914793610472554336,
+                        1281893899901916554,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3010329870005884069,
+                        2836286366415384158,
+                        2351971757588955099,
+                        425289972726450364,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18291414506166784212,
+                        810246456460775309,
+                        17528761664504541127,
+                        1189494422506112895,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13873201020652076543,
+                        6243622914124108573,
+                        6903929397781795660,
+                        574766800775597314,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17971358622227978668,
+                        15854652377477090302,
+                        1063842904034816,
+                        989523394960344619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8015553520299503933,
+                        4607530076716157995,
+                        7439330134573907822,
+                        1104246105186017619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3986330100644126569,
+                        17707403369263436100,
+                        4053571974764234396,
+                        108842167919011278,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5227388868334111117,
+                        12812207377137498929,
+                        2588198532604965494,
+                        961490407308315800,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7646326553387833689,
+                        12724139924596788661,
+                        629496738895522970,
+                        1201441128237966538,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14866344307104327368,
+                        4985794825846590702,
+                        853871436616991822,
+                        749367072047711970,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1876020443251361508,
+                        6552070073373315737,
+                        12995514842585856406,
+                        1275392944325677985,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7601514916050054383,
+                        16910631174758870764,
+                        7353760964415941247,
+                        324644049762087391,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16565109856331579529,
+                        458787328687847682,
+                        5878204963586402256,
+                        1322072415305233581,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12729053080732475923,
+                        11510453208755572522,
+                        3723997403677235406,
+                        73363110170067874,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18174997862860579716,
+                        6182018227945955817,
+                        2127647890385770221,
+                        870293941267412601,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10698372380060816691,
+                        7522180405775599951,
+                        15832604002112875278,
+                        521032436098731379,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15319055731354684350,
+                        1753334646577885863,
+                        4505520717374373397,
+                        984179811150887857,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13141271355432538382,
+                        14700998572061668181,
+                        4549081783230234567,
+                        806347686644718089,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9975557437768999376,
+                        11618707034451291844,
+                        3181936011792873831,
+                        576438052445019413,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16609855326770322939,
+                        13212944030983556053,
+                        3039401190695389047,
+                        1188561968753866414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10305504178314593273,
+                        14719639554020787850,
+                        2480984582534492384,
+                        221582009878780852,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13200038943773919312,
+                        14432882432204232972,
+                        3023115969282520533,
+                        343418406639707901,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9327873286332474704,
+                        5954324830215207584,
+                        3873074387113200139,
+                        744988613833241266,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15847102383028666437,
+                        1096370811445384001,
+                        10166653322883901200,
+                        327347038183216223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17600124854622807025,
+                        15328683957983696187,
+                        3518858654096416419,
+                        638859422450597084,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3012983939074171362,
+                        9083322861410244018,
+                        4322938770639600225,
+                        442252654129847313,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10656857781541021576,
+                        5978481768391996531,
+                        2250259229090183585,
+                        432977302373307667,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14355870696105147319,
+                        8480124686198992740,
+                        4123496920141395243,
+                        506237667861983638,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12320253406233535520,
+                        8322795092624377756,
+                        9572334243002510406,
+                        1226552575804851889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9845446342011599085,
+                        17778086686673507324,
+                        5271342849823127028,
+                        1088489886300171520,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10715976971447272221,
+                        1556354685447448032,
+                        16979954691422909924,
+                        386763037013271508,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6180856312104178084,
+                        11877570767790110506,
+                        11499883073784997611,
+                        393033674847566959,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7644299261552751667,
+                        2236838738262968574,
+                        8460748568952358391,
+                        1177808537547336633,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9135896072628350252,
+                        17288794130611191656,
+                        16136991324140875732,
+                        84153041777780941,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3384444111049248095,
+                        5013442674731807719,
+                        8362856530990022791,
+                        1126331696064245534,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13385137591549846388,
+                        11889738158254938557,
+                        17705852903381913949,
+                        155996115818620695,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15454217037527338314,
+                        9036781253178038581,
+                        12351768307285004715,
+                        776821242022223621,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1200501875000044263,
+                        6384384240290446957,
+                        15639254494736291770,
+                        930709414948460626,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2141846397057350878,
+                        14687259326247822547,
+                        2879743331055932654,
+                        420053148927601148,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18343404923952179637,
+                        1978770268004633152,
+                        18074842923392944526,
+                        99740808436074128,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16339172153591076879,
+                        13902224968596916829,
+                        10987490026958244901,
+                        480318192898237950,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16708778634825784370,
+                        5310850661772903365,
+                        16720939557594415942,
+                        983754580352601152,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8614768398997670932,
+                        10441907275834816828,
+                        11459906999048618974,
+                        98256766838754326,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5461219359492352050,
+                        8928173417887044164,
+                        5632769833875876015,
+                        938637862379787936,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14478729241761067482,
+                        14593688601059211201,
+                        14567193330233850704,
+                        193411838718316245,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6244372198079752707,
+                        12764020255605820167,
+                        3597323991837773264,
+                        232811656021755322,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12794321084179091283,
+                        12550727012798192271,
+                        5852541072119985136,
+                        1021072807450583460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5843482488715644166,
+                        17968652270397807267,
+                        10729147049305849619,
+                        157069170856760481,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16486113458959504396,
+                        3842616654194538021,
+                        16267097259825943411,
+                        595256064535306261,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18403329840356376661,
+                        17180721686489435607,
+                        16602787769632693868,
+                        1218551944743323947,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15792003884980763548,
+                        103687243243986150,
+                        1015407070790759683,
+                        925892726865866689,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4737231254389322774,
+                        1982930339222313430,
+                        17171322757846703283,
+                        898862720123176395,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15341788185538116478,
+                        15152543049620221847,
+                        11673048694712682284,
+                        322745914045333317,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12638501658486051138,
+                        13136127249934274833,
+                        16835414843273620909,
+                        830854396919510911,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15895567503124768810,
+                        17851680667009624675,
+                        9790662472981446008,
+                        688513180346337911,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7539663061168884957,
+                        14647473521866168085,
+                        14254615857988792976,
+                        4914529917008946,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10355365805280135879,
+                        1713320802629047069,
+                        11781471822278287496,
+                        696018085837035473,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17208622806574606453,
+                        2131863109694270375,
+                        17267390257687220562,
+                        717142744114900489,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13115781297674767878,
+                        11806487226059857638,
+                        3832559734382202821,
+                        1327223946228164221,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1519942432011064348,
+                        2971486323152134197,
+                        10412234235600737234,
+                        627890410365264901,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10935974904415640609,
+                        3329695497336026013,
+                        13276582814401540013,
+                        624327570851923410,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        110740126727160908,
+                        1977000808556019402,
+                        17939216501132184661,
+                        456092178041276768,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13974795879491265452,
+                        16756058358467461957,
+                        18345173225794766341,
+                        1240468105851879043,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16648425534044120166,
+                        17300631023568278668,
+                        3769987016608299300,
+                        701256036605548976,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9545137723467262343,
+                        5920020285572073751,
+                        5586902085914198509,
+                        1101010118319202051,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1804254836884575917,
+                        5416042091971666706,
+                        2881258114252429182,
+                        68889272174393525,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15468731159921956531,
+                        15872344848072676105,
+                        1678659996910931386,
+                        827142061320044550,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14524351942355153784,
+                        10797623040866138576,
+                        12874995891465211466,
+                        522880520258235794,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3987121765421903331,
+                        10914786903369841306,
+                        14357739193531493338,
+                        159659900263967910,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1502512509312401925,
+                        17658662731474453106,
+                        12409373669027537750,
+                        369255404008259275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3933340182670563102,
+                        17988389551284440931,
+                        2662254549780507248,
+                        455350437925171541,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12151336628671687181,
+                        16659863612142679844,
+                        4275242264769905759,
+                        226663222642898029,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16130436743434681682,
+                        11552440823414351188,
+                        4441812352013272663,
+                        1024571907948499450,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6575764606651544368,
+                        3768634045069377030,
+                        18077248345407646289,
+                        1109390810042386997,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14122304828382902866,
+                        7822601784757433157,
+                        15085114466160105232,
+                        454674386781135986,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4360976280861672182,
+                        10955034278453270163,
+                        4750776806040874691,
+                        1146899347354107042,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7905709989636472663,
+                        13107061619784694679,
+                        11648125699474996180,
+                        691127494619250564,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14797864082678528444,
+                        5583225186451118041,
+                        18110285596459573915,
+                        791189002348308637,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1184184322954472514,
+                        13832935888906573933,
+                        2998203974728580568,
+                        351605460802175606,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14086100838862448237,
+                        9935468519952352891,
+                        12814295729710558647,
+                        199523979285422320,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15359284420333339584,
+                        3627237788106790731,
+                        6879386164756050253,
+                        1002050051546736879,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15491244182466880101,
+                        4258467769695305947,
+                        10743785058953142755,
+                        140979662068046027,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12780301539774753788,
+                        17041488250196266992,
+                        13255875798713146967,
+                        1200976124651436983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        181235947025658140,
+                        6493888941163219640,
+                        4089919193316172518,
+                        1062883264063471223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13503255116381345737,
+                        17227957392372744845,
+                        6399256014113698262,
+                        1135007382527416949,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12480270667027365521,
+                        11878994403327505107,
+                        7310834011397306815,
+                        1187689995388061161,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8827289624900538263,
+                        1814799601839793045,
+                        5204961896730727271,
+                        1225651164115824000,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14281629148682480532,
+                        14286996705544869655,
+                        18147808448426605766,
+                        113147053903633595,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8479717516918462724,
+                        15908516325134697779,
+                        7741861261754509504,
+                        90115235014469687,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17197709078058759770,
+                        1264296861942686611,
+                        12904568589595894811,
+                        860990108004764079,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17281992003855921015,
+                        18016336011676945608,
+                        6082344652544906862,
+                        66141109302549198,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3485304500307327721,
+                        1349309029202730553,
+                        3316908650900286420,
+                        1118074358564437647,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8568578439696316572,
+                        9374477863934917617,
+                        10068837344325674096,
+                        889286749679068977,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3358453666649355803,
+                        14161646167454594637,
+                        101228813398138626,
+                        1124022599305268226,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15484627110762293323,
+                        10912463149424148128,
+                        2715498987208553899,
+                        9461326486641388,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4095300284954257397,
+                        762019690605643639,
+                        5090835112932689884,
+                        146062249290096680,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4146852446373677547,
+                        4635948778081376031,
+                        15396156154382442138,
+                        875097264229156184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6719026141119974891,
+                        573139675382891283,
+                        13900089609337917666,
+                        1224526703526922043,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11828261484584932599,
+                        8385644820913054299,
+                        8835526833616181674,
+                        579900197351830762,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1554181937338467985,
+                        1949368136051477355,
+                        4489360938173000366,
+                        913963014272619131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5741417179998001577,
+                        13820211862458348,
+                        4083929232112593887,
+                        82540295861345081,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11233015798519183929,
+                        13143086375542385728,
+                        5273768346879663094,
+                        395382838193770334,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12357961434269689795,
+                        7236180147401769115,
+                        5689861729624241741,
+                        1152782268395192011,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9324880106267869026,
+                        9508984077484831944,
+                        6584034388994931439,
+                        406833898193401203,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        293365032940191547,
+                        14721232411470124837,
+                        13146802401513291175,
+                        1316636395547517164,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15158471283153686204,
+                        2806645750773949232,
+                        14534842968188124297,
+                        52987856607297435,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        314282722852558891,
+                        4498044264337048204,
+                        12065342180632120971,
+                        567648256136223203,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16291975873638314993,
+                        10378332309037963785,
+                        7277356814100372294,
+                        486983343510661857,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5919538166509522686,
+                        1957677877547037839,
+                        13404395509285702138,
+                        835436736258688774,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15203170704126093946,
+                        8187897724102884171,
+                        541271563932409005,
+                        1227702517875688227,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7663747222853316248,
+                        11795083524296536868,
+                        9254100357176311188,
+                        872770956972508925,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3840377700941550982,
+                        7215027054273589327,
+                        4159025554123969022,
+                        286273432804326914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5801710561610814695,
+                        9424440825995863278,
+                        7715022124684831786,
+                        893624450648994487,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14143389056809532176,
+                        15969901276213257823,
+                        8431824665703527116,
+                        153739261136164617,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6025581294587420545,
+                        2825719272979219310,
+                        5600442874095999789,
+                        442496112006386751,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1218548299845720656,
+                        4491317468021549573,
+                        6946200198143636099,
+                        991992961149455914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13371091994496514518,
+                        9915773807887929510,
+                        3856645085921091135,
+                        279094855812044724,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1072338232830605995,
+                        7018230341573622936,
+                        4967203180561164922,
+                        628994971858684137,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8101048704925564771,
+                        1103936314872180752,
+                        12531957303934763817,
+                        469719508121739868,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7707017855455946164,
+                        6960603153333735416,
+                        11181466205714683237,
+                        192265130364351060,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15586520291703986668,
+                        16620710738554605490,
+                        2138240931112675245,
+                        347350326561526534,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5848716120003775634,
+                        3438004238768550427,
+                        10536609025185886391,
+                        532452794137606055,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4724317032163426930,
+                        15780675382920831543,
+                        14789562259795168199,
+                        64889017151906865,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4784253777093311811,
+                        11966669802285733176,
+                        14885936044385367603,
+                        150120487658204683,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9021955062732001003,
+                        15896434281096160661,
+                        7947322784258309946,
+                        144116219241258774,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10395200681440741328,
+                        15746724709412396313,
+                        11502918505702555087,
+                        577735934808075540,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13694262590342218867,
+                        4721238176405035873,
+                        6657831051265408919,
+                        807378054243786548,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1426150377387993713,
+                        616595096183674007,
+                        17204719939126091231,
+                        323259681696083449,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8261247594052535332,
+                        4109831480429794263,
+                        14510750706420483220,
+                        952345794750743616,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2451710604667726103,
+                        12546224348155707338,
+                        9198123355962675080,
+                        1307239499572424789,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2685605458425520120,
+                        17241359481150199922,
+                        5519660753477129889,
+                        369145180481391739,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6729566857289039679,
+                        3378908326393047937,
+                        13443432040415837104,
+                        1258715914667254036,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2773937502216775774,
+                        4609839078392697696,
+                        9732913322481910948,
+                        526507209851447062,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        77462447129908837,
+                        14946435645451478455,
+                        5465186261032369192,
+                        191540916306554800,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16022851432734941604,
+                        11529530185493602340,
+                        700102866632638604,
+                        621158078431669326,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18428275683376523329,
+                        13355856390820301560,
+                        12898946085482183185,
+                        694721010708944244,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15209075992953968252,
+                        14081921431781763833,
+                        17863919660554201738,
+                        1165595626267284818,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14695204180301103070,
+                        13737263748314655961,
+                        15860600602729994285,
+                        84465782205944913,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9172552280692854572,
+                        8982340017163340605,
+                        3909807818108696453,
+                        1344480352908740109,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7392833023332698957,
+                        8502402594032867286,
+                        6495007102146179552,
+                        1302760091588930829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13611575835129179214,
+                        4397347950009427979,
+                        11041562058397420034,
+                        1244573691825639604,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9491323393549491088,
+                        16842134838427782505,
+                        13548640714818986822,
+                        979350698596162712,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        690523328189253904,
+                        11071450988850024536,
+                        2182118840375252550,
+                        693870150487732179,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7421169047042390669,
+                        12725792246453156953,
+                        17956566294099880590,
+                        1339858278233497906,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3766306194359261137,
+                        12171290484263515673,
+                        17450787375305623484,
+                        815730605730137568,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17959920212781134398,
+                        2265494859546803137,
+                        7339179829817452611,
+                        735991253663354138,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5995645561758535977,
+                        2879396986383698289,
+                        14798723434700986512,
+                        720770849962382035,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10806741255080992096,
+                        12208033009006416694,
+                        8575175470259241043,
+                        27333706845754117,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5518198623181773383,
+                        13391157257181618320,
+                        9597316702033468357,
+                        1107163230601460181,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11933777269884619560,
+                        11346640734751070422,
+                        8846851580319306785,
+                        1207910069086189624,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        811814798349339248,
+                        14743063871006184547,
+                        15952242214182477698,
+                        208559167073389524,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15739991594016165907,
+                        12924054710294918554,
+                        1117353832488710497,
+                        515384533663726574,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5066115277702578420,
+                        15176721523687432454,
+                        7626920704758121950,
+                        343550930639166767,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11406054426120046802,
+                        1133939152925409950,
+                        14473512609849079205,
+                        65912099064070399,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2764219896030722089,
+                        13042231792786738196,
+                        1691054534408673911,
+                        14240553912295328,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12088855627552343282,
+                        10887712757816453411,
+                        16467128478545476058,
+                        632419964256821064,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8640224517665641752,
+                        7607053195872599391,
+                        783000542569064774,
+                        451473452570926666,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10844473316973532309,
+                        4245859413939757752,
+                        5538935894780278784,
+                        413064531623742430,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1981959785345364054,
+                        14101045701402936335,
+                        7832175213066493878,
+                        148050312721552765,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1255732476744800850,
+                        7391602984221356479,
+                        14904361965341087437,
+                        516193827135560159,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        999440583369550204,
+                        8162649580557249257,
+                        1626610609969651490,
+                        66859463184736177,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8288679431104097849,
+                        6330811499114120578,
+                        10514731124093450945,
+                        506055357698350622,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15883730339647373981,
+                        15092848612296809532,
+                        181877437971542734,
+                        196545660190042275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8173693699831639384,
+                        14937915226929888749,
+                        11197659395383097419,
+                        1301758345794325382,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1682629651641396108,
+                        3516926133290453273,
+                        46247418431780369,
+                        330586754553172287,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6215314608909044962,
+                        18379880054415202514,
+                        6789173475547866766,
+                        395143011231391705,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5985172509261551212,
+                        17023992009126291280,
+                        11724753484619235231,
+                        811357397883895065,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12934274409223901684,
+                        6368814134289857747,
+                        7943475413186671802,
+                        376575365743665397,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2456357089699545830,
+                        15078114442926375130,
+                        13934569693677004939,
+                        458887081575409194,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12496522675935743436,
+                        8555212409485472417,
+                        13947530486134712551,
+                        267593541376486827,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6618041724258275423,
+                        6721442949340262816,
+                        2032180862264339133,
+                        1343111986976973405,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8962876686616058939,
+                        8014984083006483891,
+                        9207713333178803821,
+                        690848603478158757,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8088987673686359695,
+                        457176324318732623,
+                        8317035617865704898,
+                        1026905909547687556,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6998472182775601193,
+                        1992561084754900041,
+                        7351851742413763502,
+                        851365237697327829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4316430745553447517,
+                        4303653340667691509,
+                        12225379890226707796,
+                        653281330061028512,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7253585754044651972,
+                        1273116733671205180,
+                        9673473128995152381,
+                        21546175008875219,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5467352834853416294,
+                        14365834039554100176,
+                        5756930911876021865,
+                        162025584110191847,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        828543477071386263,
+                        3990384306163309914,
+                        11733328727659629628,
+                        704757626854867330,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14803793078271138604,
+                        7127822193002929144,
+                        11945760579445091508,
+                        623787691281770758,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8947105477391517691,
+                        12386643664357660326,
+                        10440674837814765375,
+                        198558634254403243,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17141636049396804138,
+                        1166789380831292428,
+                        13551045974752687337,
+                        947388135904174636,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11565072228736757926,
+                        11712659935359683339,
+                        13564511825480040305,
+                        317933842509154291,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16542963749985364590,
+                        10504398843589323130,
+                        12752161140822882538,
+                        664890097131570495,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17132892861055705462,
+                        7280665485619202951,
+                        3354818736302438379,
+                        1083958435740680909,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1604777356918404217,
+                        13327352738411234984,
+                        15906193133623189459,
+                        261889942155535437,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9650652614771445608,
+                        14022474206127483759,
+                        6093723208076521803,
+                        1015690511414749756,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18269308703027574408,
+                        8292231592146155578,
+                        13241597055344331547,
+                        257668309653716279,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11548306221851730169,
+                        16865575422110954592,
+                        4066441515959022374,
+                        649043175725988335,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3976665167331814389,
+                        1745548912287200517,
+                        16560130108073655355,
+                        259146441453875327,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14919069612917494841,
+                        1015956495471919247,
+                        12718021495889611287,
+                        784919094130952319,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7816542107659936194,
+                        8398882983103335877,
+                        14716094916637775811,
+                        599693300786858055,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16253303158037878679,
+                        14056100177693558960,
+                        14721579816326004365,
+                        1269676109538283984,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1423932378519829341,
+                        2846933666673248734,
+                        16440037770498466125,
+                        1282351527364877327,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14034513464908624594,
+                        9603898020663408384,
+                        13825466186049686965,
+                        701377714626709611,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13453242036713246930,
+                        18107485598994184405,
+                        15801474963642577079,
+                        213220019667564390,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2195256386138433339,
+                        13721671008491056166,
+                        15530533870633523536,
+                        150814538410357435,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16806326548434175618,
+                        18356549721801410743,
+                        1993147541523527457,
+                        1088628205179172270,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7771847307942501781,
+                        11791589606093710604,
+                        11990647020511781048,
+                        933250878373277810,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13131758929472930950,
+                        8352811006278366182,
+                        10009187064956863875,
+                        240697249688540947,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13065389482434567474,
+                        15887613603254132146,
+                        9620790804431192535,
+                        431616950038369675,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12911021488453753870,
+                        18065486636207286908,
+                        7814595481804275466,
+                        1235598696700648631,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8091501948835698371,
+                        4798854615447323287,
+                        9355449789482922867,
+                        516322944733683448,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4415654995596857369,
+                        6471748453807382195,
+                        11203176070545474515,
+                        460169619252458397,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        226778413068638275,
+                        6638458384601221170,
+                        2191401273820780840,
+                        42427669960529650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        642735447454606909,
+                        16633579233627827892,
+                        2614111775516796228,
+                        1000587349909263417,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8776537994381529339,
+                        11754309583841170392,
+                        7295189665124135838,
+                        934298218426878240,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1199754000049896444,
+                        14711977467821385450,
+                        15700369510268835966,
+                        959062592315396664,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17132107759304493980,
+                        737625949547450443,
+                        16854005347058142621,
+                        28982244163952267,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7911417182313280574,
+                        5334412429709103722,
+                        6232725592872379210,
+                        983055202823769936,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17307732082959046234,
+                        6874286853144335138,
+                        791790961785634699,
+                        439972726730813184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3741029694216771045,
+                        17794216702306377931,
+                        18129714797623458220,
+                        211451727208506232,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15184441792343629315,
+                        5950440370633041098,
+                        16130665953523526700,
+                        1179298364183438231,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1049986631814250302,
+                        1667035425510629835,
+                        13385022992867163294,
+                        821567311431546246,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16705778148209187699,
+                        7618958753575897746,
+                        3143961024803926657,
+                        324731596823857874,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13238924131304092113,
+                        16815247379721677232,
+                        5772205651279176768,
+                        704053107355242876,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3430348577593145654,
+                        17003313280005192499,
+                        12035973136716942322,
+                        367068710108285297,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7696673494852126895,
+                        6568493401856287949,
+                        11155645685531718907,
+                        949129064884111676,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8104370165692920885,
+                        17264663365474303994,
+                        3611541849424825311,
+                        732172229748258227,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14023788738831569169,
+                        9636852458358717318,
+                        2883803051455965136,
+                        19888630141611395,
+                    ]),
+                ]),
+                Matrix::<6, 1, 6>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15713107737983872941,
+                        17741933447157420995,
+                        9417494999495666133,
+                        524385790339140003,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14173363087668746242,
+                        5136644024045953872,
+                        14428611010448823953,
+                        511088209734159467,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4587240045772324051,
+                        5878878375792757771,
+                        8003626011477505668,
+                        542904760347735216,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15357050523021918550,
+                        14457781306291784226,
+                        9812731089596218813,
+                        1284049174904520150,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13453625168320838253,
+                        10313675136389560857,
+                        6680675248876479979,
+                        1257966732566207668,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16598877299195424793,
+                        7195637380724688247,
+                        3447977395521306351,
+                        1174223681456088067,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<64, 7, 448>::new_from_known([
+            Fq::from_montgomery_limbs([
+                17282044694857417935,
+                18391879351549925375,
+                16621297296266970955,
+                1187464536096349489,
+            ]),
+            Fq::from_montgomery_limbs([
+                15749981188398128827,
+                18161382821509567906,
+                15833894523733034093,
+                912080688231360886,
+            ]),
+            Fq::from_montgomery_limbs([
+                732857060811375272,
+                6663258164394465450,
+                16961932464131262336,
+                150157956301930030,
+            ]),
+            Fq::from_montgomery_limbs([
+                3324047567408666011,
+                15876905532970464378,
+                2196635168086983028,
+                673260902469948016,
+            ]),
+            Fq::from_montgomery_limbs([
+                9017720082516981326,
+                4622821392487882487,
+                2004143278491771078,
+                79553344654160321,
+            ]),
+            Fq::from_montgomery_limbs([
+                15725156398738899828,
+                134293807826592593,
+                4194609701624605470,
+                923718786850835659,
+            ]),
+            Fq::from_montgomery_limbs([
+                1506612267556911095,
+                15057997080737451663,
+                3200432868016806792,
+                79843457852234413,
+            ]),
+            Fq::from_montgomery_limbs([
+                15381109204849600860,
+                7334421770056067710,
+                8859935324177038538,
+                524893541105483182,
+            ]),
+            Fq::from_montgomery_limbs([
+                12747347892490428888,
+                10744031060619833960,
+                216969153203596046,
+                351627995536373239,
+            ]),
+            Fq::from_montgomery_limbs([
+                15938209448434619941,
+                14787226341305656724,
+                7222886526547825056,
+                260942844205003059,
+            ]),
+            Fq::from_montgomery_limbs([
+                3883873459955052597,
+                14086405361879030618,
+                3732476051093484599,
+                590644766736152823,
+            ]),
+            Fq::from_montgomery_limbs([
+                329551904534808661,
+                14310345516028390776,
+                15991205191381891794,
+                363409986036008362,
+            ]),
+            Fq::from_montgomery_limbs([
+                8141621238399281429,
+                16806319276390326899,
+                2941525204636851132,
+                392321107762395209,
+            ]),
+            Fq::from_montgomery_limbs([
+                10673876685193367379,
+                3226460911137117130,
+                5046904891108020343,
+                891914741970270671,
+            ]),
+            Fq::from_montgomery_limbs([
+                10151950196116649547,
+                12229202773660205998,
+                17942596422641444232,
+                1314592630119966366,
+            ]),
+            Fq::from_montgomery_limbs([
+                7118691704679207443,
+                2328813456362077099,
+                13378448532879389280,
+                1038181286619156144,
+            ]),
+            Fq::from_montgomery_limbs([
+                17617908618647607302,
+                6406755535082945091,
+                10780176738784773047,
+                883959065795231989,
+            ]),
+            Fq::from_montgomery_limbs([
+                220881807308067508,
+                11358203129541665919,
+                5318389498699375157,
+                1334460348994360259,
+            ]),
+            Fq::from_montgomery_limbs([
+                1544095578690086381,
+                14755607229796461289,
+                8552920273964885945,
+                384839325185253427,
+            ]),
+            Fq::from_montgomery_limbs([
+                2246547143510792318,
+                6370524836254480770,
+                12710580973108254123,
+                620733344759850152,
+            ]),
+            Fq::from_montgomery_limbs([
+                1522987048402369297,
+                569413774430809076,
+                3489219997180569932,
+                213490170367966089,
+            ]),
+            Fq::from_montgomery_limbs([
+                18201677476561009639,
+                15711676663786792011,
+                2736274582993393443,
+                1308783324672544688,
+            ]),
+            Fq::from_montgomery_limbs([
+                8988409581077555870,
+                15395729585850181760,
+                17160994265322326073,
+                1321699298615596350,
+            ]),
+            Fq::from_montgomery_limbs([
+                17186365684610427683,
+                14805455850706520512,
+                7398189685997498189,
+                856261473629709007,
+            ]),
+            Fq::from_montgomery_limbs([
+                16135578650066671910,
+                17721600369615142887,
+                12702391566845043909,
+                1039449667664607505,
+            ]),
+            Fq::from_montgomery_limbs([
+                17662868325876307219,
+                3687823276650978561,
+                16903595461023823837,
+                1270259304190491006,
+            ]),
+            Fq::from_montgomery_limbs([
+                5255462344144484412,
+                10398400612426841504,
+                14850551577433287154,
+                100509438257741284,
+            ]),
+            Fq::from_montgomery_limbs([
+                7497201819016677010,
+                12647287024360688182,
+                17167128617451610496,
+                1154391371849389678,
+            ]),
+            Fq::from_montgomery_limbs([
+                8489961240837732083,
+                498896711230559177,
+                12078144390918790031,
+                50188243225952062,
+            ]),
+            Fq::from_montgomery_limbs([
+                10626443535543936748,
+                1956620430709054677,
+                2739286772584637442,
+                1004263472436002528,
+            ]),
+            Fq::from_montgomery_limbs([
+                14699065587967530057,
+                17314670119794363754,
+                12374649964167764533,
+                640251736579755455,
+            ]),
+            Fq::from_montgomery_limbs([
+                7049778909292550647,
+                382333679452015643,
+                14659817117661284539,
+                1083367400775912861,
+            ]),
+            Fq::from_montgomery_limbs([
+                3988782028981679775,
+                8525537119580637713,
+                6057470930882605127,
+                569231764824352851,
+            ]),
+            Fq::from_montgomery_limbs([
+                15340438995865357784,
+                10622037399247122510,
+                17428670527135325656,
+                1239872415344835712,
+            ]),
+            Fq::from_montgomery_limbs([
+                10022942961371580820,
+                4230246082215183949,
+                4811623120622140860,
+                473857962026253882,
+            ]),
+            Fq::from_montgomery_limbs([
+                9256425162287147287,
+                11978593455118848767,
+                10617224616717795906,
+                1142055178035080170,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9006710454935236915,
+                8614002886165177672,
+                15674601583409964319,
+                1255562251612607354,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1207410337149879930,
+                17278181635762121839,
+                11037055091978490732,
+                1105402088020546878,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3027000258338668371,
+                12734423360321197178,
+                2275758134827017139,
+                977106835284266158,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16808650625221158187,
+                5212798112348410457,
+                17659241391232237225,
+                807945361207258895,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11186769421522899926,
+                10707464919101561157,
+                6436709227445074196,
+                750836857915682520,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8454197194406924390,
+                3081003397547976714,
+                6500817826682275903,
+                715397164336828690,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13472312449655890892,
+                18442908168158942541,
+                18385005708012949164,
+                1221946973163075981,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1673941534939172526,
+                7007642430431422364,
+                11076945750252155465,
+                120571060571824151,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12386867349659517333,
+                855381283876325151,
+                3854253337827098660,
+                768858321279226770,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                202024252540750413,
+                8144128713996741657,
+                3347181769004141648,
+                1030683371359427974,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6066462225285907125,
+                10395559742825024420,
+                610026896918093577,
+                364317493668082557,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7422060834575271071,
+                5607743549553195750,
+                16697574756187845638,
+                879298063719373572,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5344193045122320626,
+                10812584049636986585,
+                11692609747614467997,
+                892927178682404150,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16773603275406729054,
+                16416438234410013196,
+                3397759810303301566,
+                583497615536520728,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3171702067618214684,
+                1932213571186770194,
+                17250362907602508639,
+                1296508716754505656,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17571385323034009891,
+                5408823074756057423,
+                4863591349331082654,
+                837227407002936307,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5621042674639360568,
+                17302427710501212814,
+                13318733645281487148,
+                392688501430388338,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6693783581427067971,
+                11611541464150408254,
+                9463434624938829360,
+                548460130910940725,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1723365604387993264,
+                7220136936296595690,
+                13695088647790861288,
+                218204297825762406,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14756411463813582861,
+                8910222982747565370,
+                5956509849893750385,
+                1134514178826311957,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1232916816204780726,
+                7885742554528553246,
+                7932682321060446558,
+                1166911642344997622,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2728610970739451849,
+                6143683698182713502,
+                6782546461027332626,
+                287138689159332062,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9112593094760287794,
+                10457995235157141480,
+                17684049606426169583,
+                1100853184576449040,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4015025339224353066,
+                576008939781098143,
+                8107132205521602886,
+                466135464050482032,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16266899834439540814,
+                7825985700473940903,
+                5067713810865390523,
+                842328160503346366,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7274015649190845081,
+                6202766041747716336,
+                2193815150964578353,
+                1167607825109299405,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10144175534457247476,
+                16908936247831289391,
+                15148762564986507046,
+                1017548924122005845,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6616122086003525833,
+                4946411239339041129,
+                14321027165680329396,
+                385477986945095069,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15349924555592866732,
+                6751443099110250039,
+                4281642272174597581,
+                75467609123233348,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                850812547947229879,
+                10342763728108420987,
+                7815196040498100022,
+                977041425376164330,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3729400307153041031,
+                9205364079711195931,
+                16470852155026369523,
+                8944396369878897,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15274957572312001137,
+                15322592691028447530,
+                5284914928856739819,
+                991615087090815964,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7312396426185515891,
+                6648727557371167704,
+                15843621234400844238,
+                348808611278019271,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4017642017171045002,
+                11442482334473102619,
+                17340394774568579436,
+                576645616213738960,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11487009319290066226,
+                13616797309140567001,
+                12472026395461114241,
+                757504995065123229,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8111526147197297526,
+                9448379867747792325,
+                129084143885642634,
+                205990709225991385,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5791773025225188863,
+                6591270285187649704,
+                3103397662660526373,
+                895969696845366714,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                112685634013188772,
+                8524639102061869981,
+                3167973676511428331,
+                830465842276444495,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17467417920226067446,
+                5594536361705486971,
+                14667741036645886004,
+                1226190754729429332,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3293096666737642412,
+                16056396731682278393,
+                15439625877970216495,
+                111997939497284804,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10494720218230427640,
+                1360573192943508407,
+                16656242937665913862,
+                1129110460075750790,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2480338053062894822,
+                18127817838444526777,
+                12620604852761329260,
+                789601110354042648,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                711159599569204920,
+                3866274437000059204,
+                3301784963860070954,
+                1235978771840854140,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11406643910868969746,
+                7041579846709952768,
+                15861571151704921333,
+                960287710464681277,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                792353066986827902,
+                193635073107968953,
+                6178081335184816520,
+                1016706421149316416,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2753110872927908962,
+                14852117354873059433,
+                3185366950426298195,
+                247958296361733778,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6303432341977917891,
+                12974693694594818291,
+                13004641903911111985,
+                66463761344030845,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1764204820658489240,
+                16981237618146656680,
+                11804613241134235036,
+                616623944515572041,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6731762944629701836,
+                10280637686862873311,
+                4497102813205796500,
+                603485149643554398,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17620164595135936644,
+                7204676793497351482,
+                6845883136946830798,
+                1142918798008434567,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13216955410006525079,
+                5697509749313691001,
+                7112902035371873900,
+                1042700247413207604,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10038877312780060198,
+                13792093569545061260,
+                15954527999048550988,
+                871859027324217441,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10509820783168958021,
+                11138813794733567379,
+                15759872083923488012,
+                688402011128558640,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10079230851869283363,
+                10668581313192404895,
+                12486960208990088016,
+                1264519902804892214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4456043348865926248,
+                15630772566669587593,
+                12861103913696645109,
+                448394688470747604,
+            ]),
+            Fq::from_montgomery_limbs([
+                15771115199429069939,
+                4817881345915672343,
+                2111611819737365449,
+                1003738886221220397,
+            ]),
+            Fq::from_montgomery_limbs([
+                5431658726534830657,
+                10110467764404541702,
+                11187538297886751679,
+                960715254036476851,
+            ]),
+            Fq::from_montgomery_limbs([
+                5871615000382021146,
+                7994017193447938986,
+                14760631750145935381,
+                1019472673675932568,
+            ]),
+            Fq::from_montgomery_limbs([
+                15756927759939002032,
+                13402986675441340682,
+                3506935502268935159,
+                513469185560841550,
+            ]),
+            Fq::from_montgomery_limbs([
+                15308484131038412894,
+                16718487718792034107,
+                10087352426725087193,
+                224572312028656735,
+            ]),
+            Fq::from_montgomery_limbs([
+                10716746983455848870,
+                3570370168656329691,
+                7398643824609071891,
+                964797960977319830,
+            ]),
+            Fq::from_montgomery_limbs([
+                7430095870923573667,
+                7730601622345287790,
+                16846752911118897495,
+                1085771965706780546,
+            ]),
+            Fq::from_montgomery_limbs([
+                12476280679467818608,
+                16329892184517213390,
+                14916136316811779236,
+                1211965514116698271,
+            ]),
+            Fq::from_montgomery_limbs([
+                7608348315248891654,
+                16691838558031370307,
+                4431241290374207733,
+                1025129161450804128,
+            ]),
+            Fq::from_montgomery_limbs([
+                11458950336733915375,
+                7261893437648058875,
+                11328168637467818263,
+                1276379276048526394,
+            ]),
+            Fq::from_montgomery_limbs([
+                73531412851177436,
+                10698404604669828625,
+                216843447517002869,
+                137597351589072110,
+            ]),
+            Fq::from_montgomery_limbs([
+                16889850649711334596,
+                13715077645480115510,
+                17812754359229080055,
+                573700787969912767,
+            ]),
+            Fq::from_montgomery_limbs([
+                10461521823030429914,
+                12362041455201667555,
+                8048179147520652020,
+                183952002672048871,
+            ]),
+            Fq::from_montgomery_limbs([
+                3145593030474710589,
+                15365827841572896730,
+                11265859022546969389,
+                1313433095638463332,
+            ]),
+            Fq::from_montgomery_limbs([
+                5232122740984321079,
+                2299128396239106554,
+                6350050182504394279,
+                95503992189138727,
+            ]),
+            Fq::from_montgomery_limbs([
+                1394644105439105889,
+                10340661092601716514,
+                17705389215158382785,
+                590395164229309913,
+            ]),
+            Fq::from_montgomery_limbs([
+                2424597415028204985,
+                9349489271997195863,
+                2730329231442224105,
+                405160481884014626,
+            ]),
+            Fq::from_montgomery_limbs([
+                1211417752142596468,
+                3739531686187189538,
+                1393043342043709068,
+                319490097071413640,
+            ]),
+            Fq::from_montgomery_limbs([
+                6833063907118845778,
+                6085328371732917698,
+                6395017409310096261,
+                1258326450481438814,
+            ]),
+            Fq::from_montgomery_limbs([
+                13663119821207824139,
+                15810758192634396921,
+                15089284929392632882,
+                653892671028341340,
+            ]),
+            Fq::from_montgomery_limbs([
+                5164966141967965208,
+                12847158016757524554,
+                9547503857310374655,
+                849920443814345297,
+            ]),
+            Fq::from_montgomery_limbs([
+                1387833302954139666,
+                9297897297759360980,
+                12458013487154847505,
+                574391216648271395,
+            ]),
+            Fq::from_montgomery_limbs([
+                7657396470086939438,
+                17774114367122582160,
+                4843717596502691392,
+                732079438635595790,
+            ]),
+            Fq::from_montgomery_limbs([
+                15689105957154119283,
+                12205191445178971900,
+                10537269577931325319,
+                702682292479762995,
+            ]),
+            Fq::from_montgomery_limbs([
+                1381060792599411231,
+                11809899260200742181,
+                7011248926288615309,
+                991570619049718742,
+            ]),
+            Fq::from_montgomery_limbs([
+                3803360415360015456,
+                13545175918028487648,
+                12851978637876990269,
+                784000795331115761,
+            ]),
+            Fq::from_montgomery_limbs([
+                3596480607905470678,
+                11708380212230092810,
+                273108714442804084,
+                1132575391660352216,
+            ]),
+        ]),
+    }
+}
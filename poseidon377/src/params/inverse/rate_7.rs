@@ -0,0 +1,11034 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-7 instance of Poseidon with the inverse S-box.
+pub const fn rate_7() -> PoseidonParameters<8, 7, 64, 49, 62, 8, 496, 54> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<62, 8, 496>::new_from_known([
+            Fq::from_montgomery_limbs([
+                14864108562933674823,
+                14979587931995011084,
+                12907914206163503149,
+                505142564773720425,
+            ]),
+            Fq::from_montgomery_limbs([
+                3313667999158672945,
+                13074515287503057765,
+                15278994316169638814,
+                902059631829495252,
+            ]),
+            Fq::from_montgomery_limbs([
+                6977647357377197332,
+                8246241378024044247,
+                5553849949938488835,
+                787971980296042585,
+            ]),
+            Fq::from_montgomery_limbs([
+                7198976537138658081,
+                5168286913144650999,
+                4988767812055448597,
+                38267654757989216,
+            ]),
+            Fq::from_montgomery_limbs([
+                17339129620671621211,
+                8330029159438372494,
+                6943241289984286089,
+                962300144783314192,
+            ]),
+            Fq::from_montgomery_limbs([
+                10049688062969538178,
+                11070775822728623090,
+                974441504257954434,
+                1283253731580227746,
+            ]),
+            Fq::from_montgomery_limbs([
+                11677721877467938963,
+                4704162690594437147,
+                9802239922555791633,
+                1004674084068601181,
+            ]),
+            Fq::from_montgomery_limbs([
+                11049182996183747395,
+                6758341973229315968,
+                12794635406574876920,
+                93901399045650323,
+            ]),
+            Fq::from_montgomery_limbs([
+                5886070214734015466,
+                12692804240421714755,
+                1188707587346378604,
+                79768788143137114,
+            ]),
+            Fq::from_montgomery_limbs([
+                13394786930649719818,
+                1653732893646047042,
+                5538072130443606274,
+                50643896272808480,
+            ]),
+            Fq::from_montgomery_limbs([
+                13518130302710511507,
+                7949256399903869235,
+                10422736219759354530,
+                872159264783214343,
+            ]),
+            Fq::from_montgomery_limbs([
+                10907540509409256363,
+                14546312012099717925,
+                13734983505148586693,
+                1518405818273735,
+            ]),
+            Fq::from_montgomery_limbs([
+                14817286810090930651,
+                11144449721364869017,
+                9263545029400079888,
+                1110421611642546353,
+            ]),
+            Fq::from_montgomery_limbs([
+                9473291623429482393,
+                15307460134982475756,
+                2684728159638571857,
+                1130495849814812801,
+            ]),
+            Fq::from_montgomery_limbs([
+                11481247265475753165,
+                6650084973990415579,
+                8956274554854348627,
+                632673669114489463,
+            ]),
+            Fq::from_montgomery_limbs([
+                2926673797409022389,
+                16507523254378674101,
+                2491508404616665929,
+                1267232212822441746,
+            ]),
+            Fq::from_montgomery_limbs([
+                18075465715589839337,
+                8641240595736417634,
+                9209437042748062759,
+                119673756615494298,
+            ]),
+            Fq::from_montgomery_limbs([
+                12052165761254689071,
+                9355829416739006943,
+                11692041113970906143,
+                1051102884117138411,
+            ]),
+            Fq::from_montgomery_limbs([
+                17809664420456269760,
+                13061650015330210109,
+                14826151357006375249,
+                603770768740653882,
+            ]),
+            Fq::from_montgomery_limbs([
+                16130005162934933454,
+                2018014610268401350,
+                15352596876284400401,
+                289967842816483842,
+            ]),
+            Fq::from_montgomery_limbs([
+                7568615195262611994,
+                14071205259832457259,
+                14921865248019034152,
+                458400664880042611,
+            ]),
+            Fq::from_montgomery_limbs([
+                18329491688780337251,
+                7130114070235662763,
+                8717868376332336790,
+                571424868786392484,
+            ]),
+            Fq::from_montgomery_limbs([
+                10022602771216003432,
+                12715219746134351484,
+                13286220647683489230,
+                216061640050913974,
+            ]),
+            Fq::from_montgomery_limbs([
+                17695661457113548187,
+                9298022876842354001,
+                12990773028252626975,
+                458380232000726585,
+            ]),
+            Fq::from_montgomery_limbs([
+                10140759686474857866,
+                9629613689727292850,
+                9182903796853053300,
+                1290754144091780386,
+            ]),
+            Fq::from_montgomery_limbs([
+                3123859960914886152,
+                11288134306370562686,
+                6438098372828355793,
+                1275905965787012706,
+            ]),
+            Fq::from_montgomery_limbs([
+                15466316411013171931,
+                18010259221351414095,
+                10953437182238001339,
+                363411617029979603,
+            ]),
+            Fq::from_montgomery_limbs([
+                14145471001926831533,
+                16851110457484552954,
+                3379562730712110950,
+                764484260989255481,
+            ]),
+            Fq::from_montgomery_limbs([
+                1083273036123345835,
+                9494921206142621068,
+                7309734029001230370,
+                40165465453553391,
+            ]),
+            Fq::from_montgomery_limbs([
+                3810547551037909161,
+                12694779575599869537,
+                9837831098193601948,
+                1258611609250866255,
+            ]),
+            Fq::from_montgomery_limbs([
+                18098319469136228743,
+                16990247945506253601,
+                9658942405426645023,
+                47271068120682662,
+            ]),
+            Fq::from_montgomery_limbs([
+                1538341296092534272,
+                7942707993302963897,
+                2264139856778845644,
+                15301967108271492,
+            ]),
+            Fq::from_montgomery_limbs([
+                4583201746237381394,
+                9917458271722608126,
+                341610920887738021,
+                252744244075039930,
+            ]),
+            Fq::from_montgomery_limbs([
+                11710419161313100579,
+                6326247108047734538,
+                12985311582686268291,
+                1072064524962877451,
+            ]),
+            Fq::from_montgomery_limbs([
+                82564914219912576,
+                3999793262033043373,
+                299756897604264791,
+                141118805934659978,
+            ]),
+            Fq::from_montgomery_limbs([
+                12803281602843486242,
+                12264649692326356666,
+                5454580890400037937,
+                863763535871192544,
+            ]),
+            Fq::from_montgomery_limbs([
+                12779739541961864852,
+                8771169865947251786,
+                15961724493656853811,
+                1262358897410883414,
+            ]),
+            Fq::from_montgomery_limbs([
+                10372553729422201702,
+                10718886720239459253,
+                3940544040316812153,
+                910887414477406934,
+            ]),
+            Fq::from_montgomery_limbs([
+                11425931348697290896,
+                4896340284033722510,
+                16774052130019161541,
+                1127047585800005298,
+            ]),
+            Fq::from_montgomery_limbs([
+                7280661952696511336,
+                9612596003683063051,
+                13844555775820729227,
+                634028432452531156,
+            ]),
+            Fq::from_montgomery_limbs([
+                6477800018830873246,
+                15621068558576043144,
+                782129677075990789,
+                100150361920582450,
+            ]),
+            Fq::from_montgomery_limbs([
+                17756428602402897300,
+                11293939968944192329,
+                15046129023806183177,
+                1267532451242679784,
+            ]),
+            Fq::from_montgomery_limbs([
+                12099308185465469313,
+                8793188479093644664,
+                6748399070629657988,
+                532407016601532975,
+            ]),
+            Fq::from_montgomery_limbs([
+                3711050455997160187,
+                14696946254141508685,
+                7572295670614648418,
+                303621339935204533,
+            ]),
+            Fq::from_montgomery_limbs([
+                3309799186716280184,
+                9671919768608598322,
+                10036057092423849740,
+                376250403831192933,
+            ]),
+            Fq::from_montgomery_limbs([
+                1446211342924114800,
+                14368438752211359332,
+                5873637965413325628,
+                1073610114589826600,
+            ]),
+            Fq::from_montgomery_limbs([
+                211435379666277321,
+                15878780034987403039,
+                10136901605473513149,
+                907352738374798223,
+            ]),
+            Fq::from_montgomery_limbs([
+                15344821867484562973,
+                1841624077599950490,
+                13839333378583298399,
+                1314128459573325972,
+            ]),
+            Fq::from_montgomery_limbs([
+                16564462713640492677,
+                14994994303438499977,
+                8378224657470386021,
+                12764559372672255,
+            ]),
+            Fq::from_montgomery_limbs([
+                2117406152906162635,
+                17590057045112765842,
+                14170375524881024199,
+                623937263406432062,
+            ]),
+            Fq::from_montgomery_limbs([
+                13634750321743450445,
+                1652105301386422906,
+                11243833741313920992,
+                5758699720602900,
+            ]),
+            Fq::from_montgomery_limbs([
+                1417583420334654046,
+                16712852891339891653,
+                12887579712690680659,
+                469413672061008270,
+            ]),
+            Fq::from_montgomery_limbs([
+                12208990458620040955,
+                8070279640511855472,
+                8924270508505377771,
+                726359253941699652,
+            ]),
+            Fq::from_montgomery_limbs([
+                7774785559848760159,
+                1131456771095900036,
+                15281099045443703899,
+                440601269762269343,
+            ]),
+            Fq::from_montgomery_limbs([
+                4795905649523767407,
+                13889315938115790291,
+                6765499243442310574,
+                1242714802745319713,
+            ]),
+            Fq::from_montgomery_limbs([
+                2626185365114883896,
+                16530964477207626859,
+                3554883839084027220,
+                469296274187064503,
+            ]),
+            Fq::from_montgomery_limbs([
+                2056499135095689653,
+                9071776116827217988,
+                2625206000076240579,
+                104631642628054265,
+            ]),
+            Fq::from_montgomery_limbs([
+                2873861201782527958,
+                3127857500695338675,
+                5724964426177138740,
+                448713040236913614,
+            ]),
+            Fq::from_montgomery_limbs([
+                10973189204488537394,
+                15518167278633985469,
+                5781838462465729566,
+                656688035649201259,
+            ]),
+            Fq::from_montgomery_limbs([
+                10594753376275439397,
+                6334157353937200760,
+                16333440375756402284,
+                615465421077493608,
+            ]),
+            Fq::from_montgomery_limbs([
+                3091539638603841971,
+                13607794198826853785,
+                14947898415319816021,
+                64849745874077223,
+            ]),
+            Fq::from_montgomery_limbs([
+                8298819503743884650,
+                17926232439670386287,
+                8808074812703296985,
+                972405122445151863,
+            ]),
+            Fq::from_montgomery_limbs([
+                11918601400489149314,
+                5313387162110633191,
+                7250835076749332101,
+                71896539426336643,
+            ]),
+            Fq::from_montgomery_limbs([
+                8763473969561221028,
+                4419304991009711021,
+                612115332035659360,
+                1148109604795691300,
+            ]),
+            Fq::from_montgomery_limbs([
+                1898989015825648690,
+                9359742655632630521,
+                2930390136504532183,
+                275990176205037638,
+            ]),
+            Fq::from_montgomery_limbs([
+                2515155000257066092,
+                15349737936655956507,
+                9382612846829806840,
+                315694335559844406,
+            ]),
+            Fq::from_montgomery_limbs([
+                17938593611975248773,
+                12459037973134690012,
+                16038576091530862216,
+                645153365326440998,
+            ]),
+            Fq::from_montgomery_limbs([
+                14447209247436399252,
+                1997408825465513029,
+                16515725977782999667,
+                19408334565460595,
+            ]),
+            Fq::from_montgomery_limbs([
+                411198678551844374,
+                11578807846418047642,
+                7390717877491221634,
+                220637372671506142,
+            ]),
+            Fq::from_montgomery_limbs([
+                6979366094380012586,
+                14447010874698258143,
+                208326731811005617,
+                523419143335653450,
+            ]),
+            Fq::from_montgomery_limbs([
+                16140243243334235041,
+                1218631945588582453,
+                9888777825573067726,
+                1272011116722687387,
+            ]),
+            Fq::from_montgomery_limbs([
+                7305513633674543496,
+                16223820822461472876,
+                13773531303190987618,
+                319149162571342205,
+            ]),
+            Fq::from_montgomery_limbs([
+                14184716591095031500,
+                13812106413888666801,
+                6832218868170513143,
+                1033321585272059564,
+            ]),
+            Fq::from_montgomery_limbs([
+                6938538792356451412,
+                12672859314470505960,
+                11189792602958549729,
+                965101748426241993,
+            ]),
+            Fq::from_montgomery_limbs([
+                4650523320574465463,
+                8494932011603954495,
+                3602493761255712222,
+                1024594933275424180,
+            ]),
+            Fq::from_montgomery_limbs([
+                5177555726601823887,
+                3655019538905530537,
+                11489304972970048075,
+                221999070858808981,
+            ]),
+            Fq::from_montgomery_limbs([
+                18076284695917802631,
+                9131256914904968660,
+                10293282541000393298,
+                206088959407952394,
+            ]),
+            Fq::from_montgomery_limbs([
+                1542773285165030766,
+                12188846651199609389,
+                9675888338899346383,
+                1138708816904603455,
+            ]),
+            Fq::from_montgomery_limbs([
+                6592164302961109347,
+                3563983783703784936,
+                9661886397756059655,
+                489869281325799532,
+            ]),
+            Fq::from_montgomery_limbs([
+                10377350517953097431,
+                12484631639964743500,
+                12411400203977258312,
+                927701288402531177,
+            ]),
+            Fq::from_montgomery_limbs([
+                17576613323413576759,
+                6477189977412897497,
+                6815862559356853411,
+                382978244570548175,
+            ]),
+            Fq::from_montgomery_limbs([
+                12390721696120045275,
+                13194604198927978807,
+                16145264701285087227,
+                283193837583058394,
+            ]),
+            Fq::from_montgomery_limbs([
+                5159643782312269895,
+                1969085485496341841,
+                18263464816970345390,
+                400606504719427350,
+            ]),
+            Fq::from_montgomery_limbs([
+                6499471873495954728,
+                1489255775950402740,
+                14572012515099031308,
+                143044569280962260,
+            ]),
+            Fq::from_montgomery_limbs([
+                924860216852968576,
+                10213760763626436955,
+                190607639072862528,
+                672425867481805513,
+            ]),
+            Fq::from_montgomery_limbs([
+                13212554822070932279,
+                1678187643505224864,
+                14185005885958279545,
+                1006546029623221461,
+            ]),
+            Fq::from_montgomery_limbs([
+                8051674891345335389,
+                16042650232593434968,
+                18070492691949947472,
+                4329582404896196,
+            ]),
+            Fq::from_montgomery_limbs([
+                3011568434863244865,
+                125239211148350273,
+                8892961529407867411,
+                1200108256127847655,
+            ]),
+            Fq::from_montgomery_limbs([
+                2207387357106855591,
+                7903139190391682201,
+                2077515453020281454,
+                639003313616607936,
+            ]),
+            Fq::from_montgomery_limbs([
+                14676185100081515145,
+                2274834562686908846,
+                2544403573020433622,
+                753050848019723206,
+            ]),
+            Fq::from_montgomery_limbs([
+                13580953331025797651,
+                1402364854456537311,
+                2654889742244138668,
+                416739893138627076,
+            ]),
+            Fq::from_montgomery_limbs([
+                704061920231704606,
+                18433776057796009082,
+                16856610657689668821,
+                88267741881999078,
+            ]),
+            Fq::from_montgomery_limbs([
+                3913180114478628180,
+                3538035269622323744,
+                14906231918021036989,
+                1035824284483099708,
+            ]),
+            Fq::from_montgomery_limbs([
+                2496194312051122870,
+                4305729585126056996,
+                7277868479003402859,
+                297019317879610801,
+            ]),
+            Fq::from_montgomery_limbs([
+                16072102994863745564,
+                3448023910616554360,
+                5161864370856884402,
+                18318648806499860,
+            ]),
+            Fq::from_montgomery_limbs([
+                10132696741463647208,
+                10704026619092935649,
+                15911981340572352623,
+                695276194515127574,
+            ]),
+            Fq::from_montgomery_limbs([
+                6776332830244857824,
+                917560245181558210,
+                7496139739833658492,
+                149295466851693622,
+            ]),
+            Fq::from_montgomery_limbs([
+                5514064534980619043,
+                8478831738258472929,
+                14544094407442517076,
+                237003650644492096,
+            ]),
+            Fq::from_montgomery_limbs([
+                11340360872349335285,
+                16208850245000016363,
+                4776861618574532943,
+                292609012994977485,
+            ]),
+            Fq::from_montgomery_limbs([
+                5205296509111892824,
+                10631140683934887862,
+                2854809863269371075,
+                457952122403509311,
+            ]),
+            Fq::from_montgomery_limbs([
+                8751674739443453993,
+                11777455696379102973,
+                11176481636129703805,
+                837986211562762159,
+            ]),
+            Fq::from_montgomery_limbs([
+                881824056457531482,
+                6611177293743689511,
+                12874927885973010160,
+                365746500037553285,
+            ]),
+            Fq::from_montgomery_limbs([
+                4441067185272400529,
+                7291707716430914216,
+                4826732524059057641,
+                182065101960478364,
+            ]),
+            Fq::from_montgomery_limbs([
+                3022983009387091630,
+                3300356737313840842,
+                488165871620634746,
+                1155009540625379938,
+            ]),
+            Fq::from_montgomery_limbs([
+                1943687854662759545,
+                16367398524040962629,
+                2933787383633253000,
+                1015399811924740398,
+            ]),
+            Fq::from_montgomery_limbs([
+                12614166138799534928,
+                8526368297316058534,
+                3290857803827364356,
+                1100272731705481575,
+            ]),
+            Fq::from_montgomery_limbs([
+                12586956239115360217,
+                9371456655534432697,
+                13914798895199183808,
+                923570629328686212,
+            ]),
+            Fq::from_montgomery_limbs([
+                8301727674535643317,
+                9712171552424055737,
+                16476699916859474881,
+                1240781569232698426,
+            ]),
+            Fq::from_montgomery_limbs([
+                647803511571721801,
+                8921769454459434299,
+                10598052531035448483,
+                575699276727125220,
+            ]),
+            Fq::from_montgomery_limbs([
+                15619831041306860383,
+                2737912483811433032,
+                13531708636812645,
+                9120218934739033,
+            ]),
+            Fq::from_montgomery_limbs([
+                12364636692671352974,
+                13010072491259728492,
+                11692144932286532993,
+                957847677876140697,
+            ]),
+            Fq::from_montgomery_limbs([
+                3788186172470914967,
+                14471539694557120842,
+                11143531244091558408,
+                293614378602569694,
+            ]),
+            Fq::from_montgomery_limbs([
+                12425016366714820787,
+                7299749816434259731,
+                2306967329560756181,
+                590260879301955167,
+            ]),
+            Fq::from_montgomery_limbs([
+                17007072570494671134,
+                280208101974139654,
+                18181814093875739903,
+                1328353255178463928,
+            ]),
+            Fq::from_montgomery_limbs([
+                13680961100444224507,
+                16787935669477233698,
+                5778399716507338128,
+                558251173827024569,
+            ]),
+            Fq::from_montgomery_limbs([
+                16453883213845266257,
+                1533252509727884985,
+                2674044638340066601,
+                1264764450605019134,
+            ]),
+            Fq::from_montgomery_limbs([
+                9507893061924812325,
+                9773684310598057708,
+                5417463752213125350,
+                774459927642669050,
+            ]),
+            Fq::from_montgomery_limbs([
+                2211569044980473885,
+                5245628466719145592,
+                6585048368415803848,
+                1279498824581282802,
+            ]),
+            Fq::from_montgomery_limbs([
+                382266373228647836,
+                10867894881534083127,
+                9596410967351204877,
+                383076988791696626,
+            ]),
+            Fq::from_montgomery_limbs([
+                6354011361454430076,
+                758372642201363990,
+                5401980416600583927,
+                841922162329825139,
+            ]),
+            Fq::from_montgomery_limbs([
+                2881015681037136166,
+                9353256171152258332,
+                2434763451394643188,
+                130567308990846622,
+            ]),
+            Fq::from_montgomery_limbs([
+                6972326076166617899,
+                16508638779591891351,
+                7933798999164118010,
+                1001306343190676466,
+            ]),
+            Fq::from_montgomery_limbs([
+                3106609529154502352,
+                16256210881903522505,
+                6663021022422443928,
+                604623082092455427,
+            ]),
+            Fq::from_montgomery_limbs([
+                8561543773779868120,
+                6258858422851487913,
+                1020472765904743563,
+                784603511713368117,
+            ]),
+            Fq::from_montgomery_limbs([
+                1119565263535861300,
+                12798221346823596575,
+                4242716866504773132,
+                271902384434343432,
+            ]),
+            Fq::from_montgomery_limbs([
+                3414249510606650167,
+                1509258525850692497,
+                789458810229471440,
+                1307334740390104995,
+            ]),
+            Fq::from_montgomery_limbs([
+                7426561046604007670,
+                9216085872955652922,
+                5319370254024929557,
+                175302693058157954,
+            ]),
+            Fq::from_montgomery_limbs([
+                9565301947185340698,
+                6956772637463863066,
+                9522566442794240506,
+                968809160811096510,
+            ]),
+            Fq::from_montgomery_limbs([
+                1128968592540867237,
+                13065464674243038364,
+                15260785926298940983,
+                1141628691639992195,
+            ]),
+            Fq::from_montgomery_limbs([
+                3847504970340684082,
+                11326197815428917833,
+                1523937654656344309,
+                247639699506390921,
+            ]),
+            Fq::from_montgomery_limbs([
+                5255425907991384647,
+                8813103163564324917,
+                17968757593059729533,
+                746767730993952845,
+            ]),
+            Fq::from_montgomery_limbs([
+                13261669515684143790,
+                8319949006053452125,
+                7505444150901130181,
+                1276764767420523103,
+            ]),
+            Fq::from_montgomery_limbs([
+                8524306214494912494,
+                15467937582199202091,
+                16689108966569941268,
+                12744770366503294,
+            ]),
+            Fq::from_montgomery_limbs([
+                14392318342853880822,
+                4749815955386067150,
+                58520034024417597,
+                688220066448585074,
+            ]),
+            Fq::from_montgomery_limbs([
+                2725697425857081407,
+                17665299975498477235,
+                7849043643503401728,
+                716150823696096466,
+            ]),
+            Fq::from_montgomery_limbs([
+                9105111708217520570,
+                1572147351985534558,
+                10376787026917784639,
+                963354734613887334,
+            ]),
+            Fq::from_montgomery_limbs([
+                3221530321564450272,
+                8460460624221695638,
+                17312163127336843297,
+                460852127759168833,
+            ]),
+            Fq::from_montgomery_limbs([
+                17969632805956600784,
+                13498832827454921823,
+                14031899588417029873,
+                577362859433825501,
+            ]),
+            Fq::from_montgomery_limbs([
+                6716176088081027623,
+                8339224248447264093,
+                6278930984698427821,
+                839051794931394845,
+            ]),
+            Fq::from_montgomery_limbs([
+                6162435679047418283,
+                7900259258603055587,
+                17434947778898241548,
+                443071709188921075,
+            ]),
+            Fq::from_montgomery_limbs([
+                18190441418886878500,
+                13730664184791144753,
+                2745994877617955266,
+                832757201322803367,
+            ]),
+            Fq::from_montgomery_limbs([
+                17175857077549062127,
+                8330307103856488986,
+                14437163448555723468,
+                601509480977909213,
+            ]),
+            Fq::from_montgomery_limbs([
+                7094019458086929388,
+                17722336691050404609,
+                13007771824525836254,
+                336444225676873580,
+            ]),
+            Fq::from_montgomery_limbs([
+                17990039558785824005,
+                7334471276204398181,
+                7932859369822718284,
+                1303822313208283646,
+            ]),
+            Fq::from_montgomery_limbs([
+                13050180377796854597,
+                6545905857984737400,
+                16895219752700721997,
+                600374557994343623,
+            ]),
+            Fq::from_montgomery_limbs([
+                14593094308378162000,
+                13558352769313151832,
+                12975331217097439913,
+                902157367226045423,
+            ]),
+            Fq::from_montgomery_limbs([
+                13914744752414161154,
+                13355943236019388876,
+                11387704983311139203,
+                80541254608655518,
+            ]),
+            Fq::from_montgomery_limbs([
+                12546211721351643844,
+                9361022578682409626,
+                10438908025890651899,
+                938923869755771823,
+            ]),
+            Fq::from_montgomery_limbs([
+                7014368891830112091,
+                17339173725355459844,
+                14437655330954366885,
+                1157593559264756787,
+            ]),
+            Fq::from_montgomery_limbs([
+                2536923126028064835,
+                15694457030971218407,
+                6865187020001306435,
+                138373158154441859,
+            ]),
+            Fq::from_montgomery_limbs([
+                7897403193190289044,
+                17508924555133016143,
+                628290533877036164,
+                29062000184881606,
+            ]),
+            Fq::from_montgomery_limbs([
+                5411803301857785691,
+                9594313488919717185,
+                1008685601850174943,
+                1057345334191132522,
+            ]),
+            Fq::from_montgomery_limbs([
+                1613096747564406318,
+                13295421452970575685,
+                5612199645752239253,
+                480185386029900064,
+            ]),
+            Fq::from_montgomery_limbs([
+                4216933586967943993,
+                4388912865970492143,
+                6572940814243783015,
+                938734104008271303,
+            ]),
+            Fq::from_montgomery_limbs([
+                18332569084286729645,
+                7000127055860467202,
+                17409654112406962306,
+                68186982699392168,
+            ]),
+            Fq::from_montgomery_limbs([
+                4947069651949973436,
+                9628018325353643044,
+                9402417982948047008,
+                1128977231955354301,
+            ]),
+            Fq::from_montgomery_limbs([
+                10095717803663094078,
+                3861967938482105422,
+                17249570295765051909,
+                395601641591274547,
+            ]),
+            Fq::from_montgomery_limbs([
+                17928304405040570464,
+                8121748753565255010,
+                10700941104160577121,
+                314185593414055258,
+            ]),
+            Fq::from_montgomery_limbs([
+                13172761796641963668,
+                1329818529820075289,
+                3603651451733983272,
+                647293278489385931,
+            ]),
+            Fq::from_montgomery_limbs([
+                18090270979178049319,
+                12599242296228878167,
+                2352633733299529650,
+                212699584928838969,
+            ]),
+            Fq::from_montgomery_limbs([
+                1230564116083395888,
+                4550761309618190924,
+                16935317517137314185,
+                643301039507808849,
+            ]),
+            Fq::from_montgomery_limbs([
+                12585092124698585890,
+                2682904680379962529,
+                13130009461563740906,
+                660309312464962744,
+            ]),
+            Fq::from_montgomery_limbs([
+                2379791422401739786,
+                10745940287476736116,
+                2642009757821401711,
+                48043841049988986,
+            ]),
+            Fq::from_montgomery_limbs([
+                1772230348406560151,
+                10497866955759604976,
+                4588767299398440859,
+                112188544448162570,
+            ]),
+            Fq::from_montgomery_limbs([
+                15808266128086003371,
+                9766141881688793827,
+                7673973440001603580,
+                810613050562529783,
+            ]),
+            Fq::from_montgomery_limbs([
+                6610855117200335295,
+                11207931351350308870,
+                14348154298193617002,
+                714827176432546545,
+            ]),
+            Fq::from_montgomery_limbs([
+                8500018329389220752,
+                13881738375598952327,
+                2661625909049509951,
+                1284600424232024977,
+            ]),
+            Fq::from_montgomery_limbs([
+                15279927978592372842,
+                17144600782236401971,
+                16027009685601820062,
+                212615852479341543,
+            ]),
+            Fq::from_montgomery_limbs([
+                5416854925657762004,
+                12507186359453518873,
+                17254436216204557597,
+                636115565968930823,
+            ]),
+            Fq::from_montgomery_limbs([
+                17904623151677209769,
+                7639640964791018388,
+                1735436975591037693,
+                923433168784215906,
+            ]),
+            Fq::from_montgomery_limbs([
+                18386134767608858673,
+                339374582068423521,
+                7020687040226921412,
+                58368815989960186,
+            ]),
+            Fq::from_montgomery_limbs([
+                5648967034212601124,
+                16196044712693876682,
+                17845392425804134201,
+                1149394018326167010,
+            ]),
+            Fq::from_montgomery_limbs([
+                5027183233668643710,
+                1426671169928635190,
+                8017669790966742547,
+                436212724557675730,
+            ]),
+            Fq::from_montgomery_limbs([
+                3386459425100499597,
+                15483567638176323731,
+                1901827378146589964,
+                51699020527621975,
+            ]),
+            Fq::from_montgomery_limbs([
+                11194355437347150198,
+                4995693192629024770,
+                7577395231079257774,
+                168062127796251525,
+            ]),
+            Fq::from_montgomery_limbs([
+                7689142427063869649,
+                2283150056741482082,
+                4043998537652808991,
+                93807980647400633,
+            ]),
+            Fq::from_montgomery_limbs([
+                10379337977739263802,
+                7091877332606221885,
+                13348488131307202725,
+                1253605342755507472,
+            ]),
+            Fq::from_montgomery_limbs([
+                16524628083855617799,
+                16395184076985954295,
+                14474101149741190138,
+                230435808826112424,
+            ]),
+            Fq::from_montgomery_limbs([
+                10687385263818687155,
+                4043812810302872674,
+                11962924405342129690,
+                265769041684779856,
+            ]),
+            Fq::from_montgomery_limbs([
+                1334472651404640951,
+                283367689306724599,
+                1835929200045684496,
+                720116420423029908,
+            ]),
+            Fq::from_montgomery_limbs([
+                16006532707020738438,
+                6930734991367424277,
+                10745765283222173843,
+                362882100477831074,
+            ]),
+            Fq::from_montgomery_limbs([
+                16497354838638024295,
+                7604262423400237650,
+                943811060003158477,
+                288414430395096622,
+            ]),
+            Fq::from_montgomery_limbs([
+                10552331183318546686,
+                5673034749657878097,
+                142164807136932135,
+                783705836667778864,
+            ]),
+            Fq::from_montgomery_limbs([
+                6456442254130915011,
+                10476135594607144400,
+                13836691315291709841,
+                467383887281297852,
+            ]),
+            Fq::from_montgomery_limbs([
+                8914010355314000140,
+                6852781059153920040,
+                15452860846504921346,
+                644064645421049345,
+            ]),
+            Fq::from_montgomery_limbs([
+                45829124765047710,
+                10220935416090350816,
+                776181300227155957,
+                627609829214272306,
+            ]),
+            Fq::from_montgomery_limbs([
+                1283447875830395314,
+                17794565941917381257,
+                15893512179298884050,
+                1235542365396959578,
+            ]),
+            Fq::from_montgomery_limbs([
+                6046268729397161058,
+                17982153590646401303,
+                11065545568913203134,
+                1043115233111940699,
+            ]),
+            Fq::from_montgomery_limbs([
+                9600613674201253041,
+                332366576684487729,
+                16840357408247604071,
+                1166966403156259317,
+            ]),
+            Fq::from_montgomery_limbs([
+                9577089676351034753,
+                3449046827186643305,
+                7491824225584257011,
+                810271259504135268,
+            ]),
+            Fq::from_montgomery_limbs([
+                14754601451468443063,
+                394308777213213471,
+                7884349397869591502,
+                373080092654765173,
+            ]),
+            Fq::from_montgomery_limbs([
+                16167095487659690336,
+                7793747005180722947,
+                1986994117335031710,
+                366323660962127834,
+            ]),
+            Fq::from_montgomery_limbs([
+                1370016345005691055,
+                7333973365577930190,
+                12542503619731118851,
+                239384333093256016,
+            ]),
+            Fq::from_montgomery_limbs([
+                15178664411787802568,
+                5682650460410034194,
+                7354081620018342337,
+                125648639334523867,
+            ]),
+            Fq::from_montgomery_limbs([
+                12707670766274127929,
+                15861110575439909062,
+                15914737623557485236,
+                1189552396717832248,
+            ]),
+            Fq::from_montgomery_limbs([
+                2687383214588783731,
+                16697831716761334932,
+                6764557880450593726,
+                853918816374734949,
+            ]),
+            Fq::from_montgomery_limbs([
+                825047808745280443,
+                17172517022813847375,
+                16504270623401392038,
+                320488641225657980,
+            ]),
+            Fq::from_montgomery_limbs([
+                6402664059273899106,
+                6432810531860270127,
+                15165112264997321048,
+                126075531630045189,
+            ]),
+            Fq::from_montgomery_limbs([
+                18363790757205425234,
+                14016881207499218124,
+                18108301620369756341,
+                1175867187018663212,
+            ]),
+            Fq::from_montgomery_limbs([
+                9912647199484004621,
+                15102378190532484203,
+                6734258818118273573,
+                500984141785087007,
+            ]),
+            Fq::from_montgomery_limbs([
+                5840440952024922516,
+                12920735204677923827,
+                2562230254698537868,
+                969805611600016965,
+            ]),
+            Fq::from_montgomery_limbs([
+                3649818074593088640,
+                7591591208486968276,
+                11185271264269345339,
+                62761304806059275,
+            ]),
+            Fq::from_montgomery_limbs([
+                4498256134376080857,
+                17340282711367606370,
+                13241497505288825537,
+                926367204360226133,
+            ]),
+            Fq::from_montgomery_limbs([
+                5697859242019474375,
+                4405738081623946139,
+                16001067424445908051,
+                56964156388959932,
+            ]),
+            Fq::from_montgomery_limbs([
+                15470233741195483984,
+                7298322750476746801,
+                7183885592100881636,
+                1214238712360350900,
+            ]),
+            Fq::from_montgomery_limbs([
+                17334506394004113,
+                6666583334605962460,
+                4891048861557849465,
+                28231759287550769,
+            ]),
+            Fq::from_montgomery_limbs([
+                13111157060731701288,
+                5252051402287899836,
+                7999664052910110983,
+                997439994266955190,
+            ]),
+            Fq::from_montgomery_limbs([
+                11647032357405491911,
+                3528346483321220598,
+                17761373546419680320,
+                1340750375998942264,
+            ]),
+            Fq::from_montgomery_limbs([
+                7191096292963436076,
+                1881870464384799954,
+                3241919220481571183,
+                240918689345328434,
+            ]),
+            Fq::from_montgomery_limbs([
+                7646863911166056138,
+                10288374224884723094,
+                10031172893065683067,
+                512245812687028859,
+            ]),
+            Fq::from_montgomery_limbs([
+                17746464980328963393,
+                726446445168552748,
+                6524876964382093927,
+                379614427169335276,
+            ]),
+            Fq::from_montgomery_limbs([
+                2375466042187963269,
+                10197726020692010811,
+                8765977622960105232,
+                1276770648774345308,
+            ]),
+            Fq::from_montgomery_limbs([
+                12705004478797673895,
+                18224369696478962817,
+                5640184318549485959,
+                137526100517250670,
+            ]),
+            Fq::from_montgomery_limbs([
+                17938842809862771512,
+                6426025172787400705,
+                15417538902838826609,
+                1121763310534579027,
+            ]),
+            Fq::from_montgomery_limbs([
+                9123136093612168952,
+                9270952591004723874,
+                6657297724355456833,
+                627213104790390668,
+            ]),
+            Fq::from_montgomery_limbs([
+                2021009903000984203,
+                17135855081897002972,
+                14642307800806166719,
+                1103569852915430080,
+            ]),
+            Fq::from_montgomery_limbs([
+                15091864582829100361,
+                8878569873499488852,
+                6716254052523319066,
+                666575120749701121,
+            ]),
+            Fq::from_montgomery_limbs([
+                11566545511290544939,
+                16663352478526100417,
+                15275144057413471416,
+                361565781507964635,
+            ]),
+            Fq::from_montgomery_limbs([
+                1657854741006222603,
+                273461920299236732,
+                12778153820692961539,
+                834061558718901869,
+            ]),
+            Fq::from_montgomery_limbs([
+                1267092019329458556,
+                3252467814537131491,
+                1912717992133403426,
+                173503350627976700,
+            ]),
+            Fq::from_montgomery_limbs([
+                17484989758854661401,
+                16715631263346584570,
+                7763548121286364433,
+                771577380011565397,
+            ]),
+            Fq::from_montgomery_limbs([
+                14217579504062079234,
+                13182169154138371961,
+                3831901723289657151,
+                702729085490842856,
+            ]),
+            Fq::from_montgomery_limbs([
+                3006284899872737781,
+                9551241969515169314,
+                9238627762327055312,
+                972537001595625471,
+            ]),
+            Fq::from_montgomery_limbs([
+                446948152328147288,
+                18347638808810591027,
+                7623177652690428886,
+                636591549874044227,
+            ]),
+            Fq::from_montgomery_limbs([
+                997914878754474516,
+                8912960767891932307,
+                6453995973505493463,
+                59488851377301831,
+            ]),
+            Fq::from_montgomery_limbs([
+                16038568786793756913,
+                7990812574208035403,
+                11347970256045423116,
+                480669388812996534,
+            ]),
+            Fq::from_montgomery_limbs([
+                11279553015450121175,
+                18431519974179622586,
+                3453786586004215808,
+                1335704155731545881,
+            ]),
+            Fq::from_montgomery_limbs([
+                16876420001662967102,
+                9609242900955278418,
+                12070752718357456172,
+                1038920655885284899,
+            ]),
+            Fq::from_montgomery_limbs([
+                10002504723896063100,
+                15369788276883609235,
+                15747908064816696870,
+                279559001805375564,
+            ]),
+            Fq::from_montgomery_limbs([
+                7527757044635456039,
+                1427250094908156612,
+                5162654548746562593,
+                1068431053129731126,
+            ]),
+            Fq::from_montgomery_limbs([
+                13932339041147814685,
+                14695890797782266535,
+                951935792924247915,
+                229466163453956040,
+            ]),
+            Fq::from_montgomery_limbs([
+                5344380018814472170,
+                12020740646579497477,
+                7535412754069006885,
+                1083304859217719219,
+            ]),
+            Fq::from_montgomery_limbs([
+                17310033125420146840,
+                16562649495387979250,
+                14790092222591548140,
+                496562209847267445,
+            ]),
+            Fq::from_montgomery_limbs([
+                12225308122664589821,
+                11589367398815839801,
+                4949192080114987038,
+                931030102230179015,
+            ]),
+            Fq::from_montgomery_limbs([
+                17424068414089216041,
+                3677110112673450558,
+                11588424691307103104,
+                1039123226397142737,
+            ]),
+            Fq::from_montgomery_limbs([
+                8162425235052036221,
+                9131050607258458100,
+                17151583274689453589,
+                262481880289188145,
+            ]),
+            Fq::from_montgomery_limbs([
+                1997763713816841177,
+                16962392205914222717,
+                2089674105958600220,
+                991293042272000695,
+            ]),
+            Fq::from_montgomery_limbs([
+                6907431971253462240,
+                7600041577453270475,
+                3945077134762942031,
+                961596429503364944,
+            ]),
+            Fq::from_montgomery_limbs([
+                2426169777260254717,
+                8767803016686892572,
+                14647527509256591624,
+                571585340725023505,
+            ]),
+            Fq::from_montgomery_limbs([
+                18345841320522897549,
+                11364288105799718820,
+                17207904837044161708,
+                184973173499173122,
+            ]),
+            Fq::from_montgomery_limbs([
+                9161100167923848300,
+                662125653466256342,
+                2898814043091845012,
+                129547053848607385,
+            ]),
+            Fq::from_montgomery_limbs([
+                9535335163737535316,
+                889743773260446820,
+                10860409934154523927,
+                1317471582927071998,
+            ]),
+            Fq::from_montgomery_limbs([
+                657719049470459483,
+                11407374462721301809,
+                7356949738532059432,
+                1208367363294633965,
+            ]),
+            Fq::from_montgomery_limbs([
+                9060629493498474799,
+                6635539780780841163,
+                18138280197886176142,
+                1125918592322960626,
+            ]),
+            Fq::from_montgomery_limbs([
+                16631127865808228018,
+                6730764740572434808,
+                13674414284594621918,
+                414866504632338772,
+            ]),
+            Fq::from_montgomery_limbs([
+                10560290609939838815,
+                4488132568752532259,
+                16656931107223031028,
+                573916308461254410,
+            ]),
+            Fq::from_montgomery_limbs([
+                18156739102558146437,
+                14939373043904599900,
+                4419753603582655695,
+                158502952579185070,
+            ]),
+            Fq::from_montgomery_limbs([
+                8380468382325942702,
+                5271889053900978053,
+                2413363905473313642,
+                241943754736062730,
+            ]),
+            Fq::from_montgomery_limbs([
+                17296435500495956707,
+                11364266140979838784,
+                16014260770961108250,
+                227027279169132890,
+            ]),
+            Fq::from_montgomery_limbs([
+                16083736721900716209,
+                6247029018751124603,
+                7000408828517701528,
+                1175713152882733735,
+            ]),
+            Fq::from_montgomery_limbs([
+                6368819679366892761,
+                12036820243809839087,
+                10681070174361161361,
+                834311988374505042,
+            ]),
+            Fq::from_montgomery_limbs([
+                7107129431883271163,
+                13749683138221502277,
+                16822825675223406817,
+                216242466745341641,
+            ]),
+            Fq::from_montgomery_limbs([
+                8164291596155418833,
+                2467498703122316752,
+                542027316327606272,
+                757121757289932964,
+            ]),
+            Fq::from_montgomery_limbs([
+                5896718539935858153,
+                2657389825558399120,
+                1633992168388932003,
+                325761845779149990,
+            ]),
+            Fq::from_montgomery_limbs([
+                14531329690379641803,
+                6845343063180881803,
+                5347855861639685878,
+                137437448965779917,
+            ]),
+            Fq::from_montgomery_limbs([
+                636478161943020569,
+                1093730389336845304,
+                12962804240708526694,
+                846041544781068409,
+            ]),
+            Fq::from_montgomery_limbs([
+                7869291701131232528,
+                3870978818789662905,
+                17037715872746733793,
+                1302874927621646243,
+            ]),
+            Fq::from_montgomery_limbs([
+                2027186290928745372,
+                7658616605057896371,
+                3696343789495802447,
+                45605357267516443,
+            ]),
+            Fq::from_montgomery_limbs([
+                5020049304796981007,
+                5840838586159406339,
+                946646598432013056,
+                644760418188272702,
+            ]),
+            Fq::from_montgomery_limbs([
+                627448184024561264,
+                16938566039654201900,
+                11724266447056695458,
+                822623704775724896,
+            ]),
+            Fq::from_montgomery_limbs([
+                4724616810080526180,
+                2873435808096557751,
+                452853236323935771,
+                561707794256148880,
+            ]),
+            Fq::from_montgomery_limbs([
+                17594255375311411100,
+                4252762943547036143,
+                8080960938674588561,
+                331570868258803540,
+            ]),
+            Fq::from_montgomery_limbs([
+                16750332704356688723,
+                12582957321395253414,
+                8483089675384352666,
+                366581724504263125,
+            ]),
+            Fq::from_montgomery_limbs([
+                9099133761476870355,
+                17148514323540924125,
+                2325900943656214377,
+                1144909221006331715,
+            ]),
+            Fq::from_montgomery_limbs([
+                10228376910634992154,
+                9379480350988173625,
+                4113334214566562386,
+                62621459984078664,
+            ]),
+            Fq::from_montgomery_limbs([
+                7556828717073679733,
+                12712523997023446633,
+                4228260751636779934,
+                299720788338920791,
+            ]),
+            Fq::from_montgomery_limbs([
+                4568792741499965579,
+                11337276400182722642,
+                11560128752655885512,
+                498251338377817203,
+            ]),
+            Fq::from_montgomery_limbs([
+                4801266400019183775,
+                57721865954530556,
+                2136169785216836813,
+                127263774093430247,
+            ]),
+            Fq::from_montgomery_limbs([
+                7974979007080539678,
+                5633903031628085388,
+                12660200028439389432,
+                842106837919030237,
+            ]),
+            Fq::from_montgomery_limbs([
+                7774909781525332085,
+                10402580939737980177,
+                6708230023103308975,
+                446734593657295746,
+            ]),
+            Fq::from_montgomery_limbs([
+                13577391652547421861,
+                5852741030756127083,
+                13066477688933118161,
+                1312878835560502122,
+            ]),
+            Fq::from_montgomery_limbs([
+                347613718590625472,
+                15956464297879375613,
+                13753710689687016879,
+                701189458901245004,
+            ]),
+            Fq::from_montgomery_limbs([
+                18347791379276685996,
+                16814546645365207717,
+                1598765119915667701,
+                1055511269735356814,
+            ]),
+            Fq::from_montgomery_limbs([
+                17843406278233910514,
+                9789773593109002176,
+                2110279113282417812,
+                1298739384195858383,
+            ]),
+            Fq::from_montgomery_limbs([
+                2535727352181190206,
+                15800397878998622460,
+                4358599173858517843,
+                963121978633380982,
+            ]),
+            Fq::from_montgomery_limbs([
+                10159357621452621497,
+                10930320117736478460,
+                15271718845606559323,
+                1314813305527437303,
+            ]),
+            Fq::from_montgomery_limbs([
+                1658740557293092086,
+                9651002157957597633,
+                4661330979353870214,
+                1140353044365186252,
+            ]),
+            Fq::from_montgomery_limbs([
+                556756945329899657,
+                16395349374915000271,
+                5389471639147186790,
+                631252489457654981,
+            ]),
+            Fq::from_montgomery_limbs([
+                11288595720721273435,
+                2248563550447121643,
+                6900132780788115294,
+                503862518248680833,
+            ]),
+            Fq::from_montgomery_limbs([
+                6933282744865340441,
+                2773565269197331361,
+                9183379993638151268,
+                120803353323300141,
+            ]),
+            Fq::from_montgomery_limbs([
+                18270766964459257636,
+                10664142261608399280,
+                16332695637512211577,
+                563729891841022811,
+            ]),
+            Fq::from_montgomery_limbs([
+                5446065115976885889,
+                4203318046588392390,
+                10659727185369441077,
+                366845241359719887,
+            ]),
+            Fq::from_montgomery_limbs([
+                15057048320685830564,
+                4343528613314683830,
+                16415840284891885242,
+                518710964233368449,
+            ]),
+            Fq::from_montgomery_limbs([
+                9280471800707964643,
+                5884930978100456195,
+                6527092921820968468,
+                1143718682835820571,
+            ]),
+            Fq::from_montgomery_limbs([
+                14898750259378802617,
+                15595490235894860313,
+                8971359928735129310,
+                379702630477462227,
+            ]),
+            Fq::from_montgomery_limbs([
+                10696254815256636672,
+                1945570978256057074,
+                9127561157202503587,
+                111921888610110105,
+            ]),
+            Fq::from_montgomery_limbs([
+                8509363379931603333,
+                2573690793874589643,
+                11301062879342431003,
+                876388662608713387,
+            ]),
+            Fq::from_montgomery_limbs([
+                15114168241734281903,
+                15082242057538487760,
+                698032958922588985,
+                902786025386031979,
+            ]),
+            Fq::from_montgomery_limbs([
+                13521961115440860890,
+                147659730058130772,
+                6464733522110451336,
+                1077027276130442692,
+            ]),
+            Fq::from_montgomery_limbs([
+                409720784095570615,
+                6051538898864445460,
+                15651500606914139286,
+                288136112442833496,
+            ]),
+            Fq::from_montgomery_limbs([
+                10783716066218815716,
+                2433730709998847310,
+                10715092459141809985,
+                254011416899323664,
+            ]),
+            Fq::from_montgomery_limbs([
+                5112880195640654226,
+                7726378832922842821,
+                11748339613596818093,
+                759992648725248534,
+            ]),
+            Fq::from_montgomery_limbs([
+                9275987910951107226,
+                8089191855905157136,
+                963682660800714442,
+                635616525666863949,
+            ]),
+            Fq::from_montgomery_limbs([
+                4385923051017118425,
+                6092334854267258027,
+                17509125590149238626,
+                1203631344587631439,
+            ]),
+            Fq::from_montgomery_limbs([
+                5623657590713878672,
+                15106390051901263718,
+                9091065244169645866,
+                339288791772877748,
+            ]),
+            Fq::from_montgomery_limbs([
+                13919648321752413404,
+                16022764074472842493,
+                7934282643282079199,
+                971379910984204497,
+            ]),
+            Fq::from_montgomery_limbs([
+                6771654959219906521,
+                9182883473917860794,
+                7051887224638208256,
+                1093531998817335158,
+            ]),
+            Fq::from_montgomery_limbs([
+                16008651782284297911,
+                7060801776998547406,
+                5024952545144123737,
+                1189683690674648463,
+            ]),
+            Fq::from_montgomery_limbs([
+                12735079165087553399,
+                9655313896632067445,
+                12849844292694451081,
+                984931566994433821,
+            ]),
+            Fq::from_montgomery_limbs([
+                7189794134228826212,
+                4346715051027382450,
+                7687133684760276934,
+                284404790091575045,
+            ]),
+            Fq::from_montgomery_limbs([
+                1919229889878519929,
+                6588745854183660873,
+                13675639090666718022,
+                184646637597914185,
+            ]),
+            Fq::from_montgomery_limbs([
+                6961985067637500145,
+                6769478471956808954,
+                18126054966904594070,
+                702660878668281153,
+            ]),
+            Fq::from_montgomery_limbs([
+                4295104230822761078,
+                7338925966294017783,
+                16279965291224826312,
+                470436044092478723,
+            ]),
+            Fq::from_montgomery_limbs([
+                16111778473635042495,
+                4421034247715148502,
+                17987730134928272352,
+                1049307431299713436,
+            ]),
+            Fq::from_montgomery_limbs([
+                11241797386357938780,
+                4641750387249795869,
+                12524956073702130592,
+                850157999848873155,
+            ]),
+            Fq::from_montgomery_limbs([
+                733606200168273587,
+                13268322139702836465,
+                13103525930187069294,
+                736972614733180824,
+            ]),
+            Fq::from_montgomery_limbs([
+                10742365249468129295,
+                6553317198024646563,
+                5980758536254072822,
+                1245738234331591368,
+            ]),
+            Fq::from_montgomery_limbs([
+                14380793652706908211,
+                16537295094665646962,
+                7342537792658238504,
+                515331470613964566,
+            ]),
+            Fq::from_montgomery_limbs([
+                7572758517830626549,
+                12269825204363327811,
+                10701454914481647407,
+                966116855437159765,
+            ]),
+            Fq::from_montgomery_limbs([
+                6015030962825824911,
+                12121331828730599140,
+                11974922469945780455,
+                93263756763547749,
+            ]),
+            Fq::from_montgomery_limbs([
+                8320351187897738747,
+                10399150786077648081,
+                5169473018057649861,
+                1233173982440815885,
+            ]),
+            Fq::from_montgomery_limbs([
+                12244163561349173756,
+                8190028598442794024,
+                12549822869234636499,
+                1271730759946458996,
+            ]),
+            Fq::from_montgomery_limbs([
+                11271359240292981390,
+                6169103788359727452,
+                5295999704355498939,
+                1086931923505427331,
+            ]),
+            Fq::from_montgomery_limbs([
+                15553877476686931392,
+                232823438119794824,
+                1132279959459455710,
+                733876240173786518,
+            ]),
+            Fq::from_montgomery_limbs([
+                6936399124620668643,
+                14245829531108269291,
+                4205297559752248719,
+                1260508279599739078,
+            ]),
+            Fq::from_montgomery_limbs([
+                3949692881285704470,
+                14537562113994432411,
+                14408823507923807751,
+                535832420888150835,
+            ]),
+            Fq::from_montgomery_limbs([
+                18106419452916012193,
+                15379182712804274674,
+                3614582632532531693,
+                55760376856201871,
+            ]),
+            Fq::from_montgomery_limbs([
+                17157439997167143200,
+                9106484749012289894,
+                3348892221733265801,
+                880126181572198118,
+            ]),
+            Fq::from_montgomery_limbs([
+                2575668507947683118,
+                14905562065467444712,
+                1481529256009286671,
+                1213555249385940826,
+            ]),
+            Fq::from_montgomery_limbs([
+                18187221813105302899,
+                15096966453952086484,
+                12365828054286417159,
+                530162140239125647,
+            ]),
+            Fq::from_montgomery_limbs([
+                2217123333840615286,
+                1912151490732979259,
+                8084561067610972278,
+                628561639017931228,
+            ]),
+            Fq::from_montgomery_limbs([
+                5036649756469099952,
+                15952530636270199744,
+                10541558465094051090,
+                1063966415481264345,
+            ]),
+            Fq::from_montgomery_limbs([
+                16226505644602446006,
+                4408537738457072931,
+                260701259244461283,
+                857572353714128699,
+            ]),
+            Fq::from_montgomery_limbs([
+                17375433774863063726,
+                1937062582436014436,
+                6125059507773700624,
+                1245745583540069749,
+            ]),
+            Fq::from_montgomery_limbs([
+                4247689899898206736,
+                13287453060747309132,
+                7304781507824015667,
+                493161972172409762,
+            ]),
+            Fq::from_montgomery_limbs([
+                10259968585535782348,
+                12600209154861245439,
+                1601468058878921374,
+                782220367439182680,
+            ]),
+            Fq::from_montgomery_limbs([
+                248624826536574627,
+                3253530704972344767,
+                357574360025375474,
+                1136739199851488721,
+            ]),
+            Fq::from_montgomery_limbs([
+                3572824739381284955,
+                4901791038184171938,
+                5754218855477994875,
+                206270910604405408,
+            ]),
+            Fq::from_montgomery_limbs([
+                18014538382404027165,
+                10738523977803803251,
+                908288472667226797,
+                954273527057786936,
+            ]),
+            Fq::from_montgomery_limbs([
+                17291053819927116792,
+                6308876917751847009,
+                9056758811704433185,
+                260504926310761956,
+            ]),
+            Fq::from_montgomery_limbs([
+                4653056514170360952,
+                17214525497685171301,
+                1486038959801441381,
+                1181441237497731444,
+            ]),
+            Fq::from_montgomery_limbs([
+                6916635868643680886,
+                16093448154368510917,
+                14109741015603438924,
+                1023672041726032141,
+            ]),
+            Fq::from_montgomery_limbs([
+                5614832111285263670,
+                1999484516988819458,
+                5111944208593357528,
+                122985665918550689,
+            ]),
+            Fq::from_montgomery_limbs([
+                14479712258192684766,
+                13150787979713121528,
+                14398511445791116009,
+                280778449888821173,
+            ]),
+            Fq::from_montgomery_limbs([
+                9400860815769305235,
+                17203832795457119095,
+                14093784608271622651,
+                65744041973674026,
+            ]),
+            Fq::from_montgomery_limbs([
+                8253332498606470188,
+                17638606470331697674,
+                3999280953511003796,
+                418904691964405115,
+            ]),
+            Fq::from_montgomery_limbs([
+                17184217860960046184,
+                4116120945056863413,
+                16607311860696814291,
+                1224646034851055412,
+            ]),
+            Fq::from_montgomery_limbs([
+                5004050457494997277,
+                13968350095805480287,
+                8340813804658006614,
+                1223340551420324871,
+            ]),
+            Fq::from_montgomery_limbs([
+                11158371723102565131,
+                2853568597124506055,
+                18317094216192138400,
+                973494044062511635,
+            ]),
+            Fq::from_montgomery_limbs([
+                14453764742706817430,
+                7151399386004404109,
+                12561789737278723206,
+                317015342635957470,
+            ]),
+            Fq::from_montgomery_limbs([
+                209650932077666746,
+                11719330214508630662,
+                5881083914778027487,
+                192431108230822361,
+            ]),
+            Fq::from_montgomery_limbs([
+                7040027944450693386,
+                13861306101096143395,
+                6314533149584796551,
+                1213794678145539367,
+            ]),
+            Fq::from_montgomery_limbs([
+                15735970199867616739,
+                7436904267378583151,
+                8724107079624532154,
+                1051538949318179544,
+            ]),
+            Fq::from_montgomery_limbs([
+                16538498994885513578,
+                851612576010260735,
+                15142311630099067305,
+                626139441774807099,
+            ]),
+            Fq::from_montgomery_limbs([
+                12150716401382027495,
+                18104411926857554133,
+                12579655169523244396,
+                1236057746060783976,
+            ]),
+            Fq::from_montgomery_limbs([
+                11677518179004660599,
+                12367087965140334931,
+                13016945351542954117,
+                769017410282800366,
+            ]),
+            Fq::from_montgomery_limbs([
+                563806470740271844,
+                7112404681643339959,
+                181184081463661004,
+                447161886524584609,
+            ]),
+            Fq::from_montgomery_limbs([
+                5803816341188719583,
+                7702661595878456867,
+                15116433824506241403,
+                668946381192105202,
+            ]),
+            Fq::from_montgomery_limbs([
+                11916106163355076294,
+                6702027446749264110,
+                12289467794495358368,
+                996388189981823017,
+            ]),
+            Fq::from_montgomery_limbs([
+                10212187295676381016,
+                5287766273509090557,
+                5761673925826260694,
+                1158483375473974860,
+            ]),
+            Fq::from_montgomery_limbs([
+                9590059389702256069,
+                7078025522009129578,
+                729290089957727208,
+                1239822091860022648,
+            ]),
+            Fq::from_montgomery_limbs([
+                3616083481384996464,
+                4352138415026988402,
+                12855013613580357167,
+                184929931720302480,
+            ]),
+            Fq::from_montgomery_limbs([
+                5279407206807802527,
+                9081241328303534806,
+                4837442663425820087,
+                702891881147193462,
+            ]),
+            Fq::from_montgomery_limbs([
+                13759444481074480177,
+                4689405280298877620,
+                13165281755049402455,
+                229761835795372240,
+            ]),
+            Fq::from_montgomery_limbs([
+                7737613472611448623,
+                4116314675550706713,
+                8985944030832583940,
+                915333931403720429,
+            ]),
+            Fq::from_montgomery_limbs([
+                17548210420114331138,
+                10751367274839492592,
+                13431451581361672636,
+                696157186371232695,
+            ]),
+            Fq::from_montgomery_limbs([
+                3327891340724426300,
+                9021819490431068180,
+                8007551918077709417,
+                1341644923660142183,
+            ]),
+            Fq::from_montgomery_limbs([
+                10539989945935897214,
+                6063047353489582934,
+                8148951323947384863,
+                410609279052585000,
+            ]),
+            Fq::from_montgomery_limbs([
+                9116680180278926884,
+                7290793286945777576,
+                4616688192945461912,
+                373953475841327489,
+            ]),
+            Fq::from_montgomery_limbs([
+                12405397618946415727,
+                13086870801556218742,
+                14168743439113125056,
+                832546699118217234,
+            ]),
+            Fq::from_montgomery_limbs([
+                13120713990448970274,
+                13548505969414300361,
+                5839136599128253472,
+                160896148269205144,
+            ]),
+            Fq::from_montgomery_limbs([
+                14258376474285865723,
+                8369023538419494383,
+                3588149340659417996,
+                849273913234722501,
+            ]),
+            Fq::from_montgomery_limbs([
+                10021844393414955063,
+                9456743338375108535,
+                16268298304520023313,
+                154439352852815064,
+            ]),
+            Fq::from_montgomery_limbs([
+                1844788863864908253,
+                14171653445089880416,
+                3826298397000723841,
+                1177438418064603533,
+            ]),
+            Fq::from_montgomery_limbs([
+                16660840443381862280,
+                17449267337609447363,
+                3913733331235173418,
+                440406283679888800,
+            ]),
+            Fq::from_montgomery_limbs([
+                13295380333036491568,
+                462019274259252331,
+                8648507044126025096,
+                947759191775037259,
+            ]),
+            Fq::from_montgomery_limbs([
+                6974680259394773328,
+                8594202304785079145,
+                5208144383448882332,
+                1086419959570607693,
+            ]),
+            Fq::from_montgomery_limbs([
+                16722708855263221984,
+                52235852773869388,
+                7350223182290241338,
+                654259025718901965,
+            ]),
+            Fq::from_montgomery_limbs([
+                14422445642981917042,
+                7378075061182471594,
+                9762299904991021753,
+                372352084108489863,
+            ]),
+            Fq::from_montgomery_limbs([
+                6835502275661166113,
+                5364348558885753128,
+                15616537542459645200,
+                937807775309398158,
+            ]),
+            Fq::from_montgomery_limbs([
+                5976186372423876617,
+                18272418393559595359,
+                12675095077874743431,
+                531601066767859660,
+            ]),
+            Fq::from_montgomery_limbs([
+                15933966533634337711,
+                17188181904376408331,
+                18172082691051476256,
+                1103547383013955973,
+            ]),
+            Fq::from_montgomery_limbs([
+                5985282242501249852,
+                11854199439268016453,
+                9380111416856157875,
+                135543771510688261,
+            ]),
+            Fq::from_montgomery_limbs([
+                14857759479963585770,
+                14611948890979999553,
+                4780728551956538562,
+                19525004039410010,
+            ]),
+            Fq::from_montgomery_limbs([
+                12603999908480939976,
+                4501862703627949235,
+                2097578267862444960,
+                178875443184867550,
+            ]),
+            Fq::from_montgomery_limbs([
+                17717945329305067600,
+                16342788642170716023,
+                7862965523751235702,
+                188856543580618628,
+            ]),
+            Fq::from_montgomery_limbs([
+                5809783926094743914,
+                329691040821959877,
+                17836809275066712543,
+                942411751761824154,
+            ]),
+            Fq::from_montgomery_limbs([
+                3852465111544006525,
+                14860167767708697103,
+                6824604714064304728,
+                584726570078578052,
+            ]),
+            Fq::from_montgomery_limbs([
+                14867579258606322811,
+                7463786114869709214,
+                15968624913578229032,
+                252556519470884222,
+            ]),
+            Fq::from_montgomery_limbs([
+                16972071490377217963,
+                13467738207052652325,
+                17757799203145330209,
+                834127851574224279,
+            ]),
+            Fq::from_montgomery_limbs([
+                11486739002229821423,
+                12514113200362060076,
+                17681627898232967665,
+                18194110581824131,
+            ]),
+            Fq::from_montgomery_limbs([
+                3016493547048840486,
+                16919837234558479086,
+                5395602692176991431,
+                24156012430127133,
+            ]),
+            Fq::from_montgomery_limbs([
+                1308729082224245492,
+                13368514659899002823,
+                6049361147597489668,
+                1214714254277998412,
+            ]),
+            Fq::from_montgomery_limbs([
+                3702471561268516944,
+                17471060930516621125,
+                8587937925607434302,
+                162595857970234484,
+            ]),
+            Fq::from_montgomery_limbs([
+                11795227852877775964,
+                13454944323565674227,
+                16115113998400960398,
+                1305250144640873209,
+            ]),
+            Fq::from_montgomery_limbs([
+                14798900869570690054,
+                12873864363332896185,
+                8721074158007686096,
+                545658714289544162,
+            ]),
+            Fq::from_montgomery_limbs([
+                13656741531448086963,
+                18076250490569044134,
+                3723115198085601298,
+                786937513698249396,
+            ]),
+            Fq::from_montgomery_limbs([
+                543115355619738566,
+                7374252724060893387,
+                2039349536962378585,
+                1130229052690738928,
+            ]),
+            Fq::from_montgomery_limbs([
+                15852672894927365926,
+                10488107768239235261,
+                12622343658370647259,
+                773600303227399358,
+            ]),
+            Fq::from_montgomery_limbs([
+                17149450016793269375,
+                11101019476093587774,
+                14287254883653857390,
+                1005853352444886384,
+            ]),
+            Fq::from_montgomery_limbs([
+                8892464745868704527,
+                15752882820364487073,
+                7350340243090617136,
+                1270735344102563230,
+            ]),
+            Fq::from_montgomery_limbs([
+                15430874999586841324,
+                1768471494733348691,
+                16089696964947453717,
+                77850475983458378,
+            ]),
+            Fq::from_montgomery_limbs([
+                14639911535017725236,
+                10163163235696539306,
+                13136973721706313987,
+                630510141560049691,
+            ]),
+            Fq::from_montgomery_limbs([
+                10839923386492115566,
+                1746084635887211237,
+                4199672733508890970,
+                797091044683053368,
+            ]),
+            Fq::from_montgomery_limbs([
+                4460150756565822803,
+                17996923830135629415,
+                9678014884176856531,
+                1181033952064921028,
+            ]),
+            Fq::from_montgomery_limbs([
+                13966199837594192147,
+                12035063201558834100,
+                15767656515557978406,
+                781613163120237951,
+            ]),
+            Fq::from_montgomery_limbs([
+                6494622675418592255,
+                2430258865307105052,
+                3456735852441612864,
+                769576822551390866,
+            ]),
+            Fq::from_montgomery_limbs([
+                9847061538400643410,
+                2096619969925801360,
+                10692657538046397146,
+                86055379825068048,
+            ]),
+            Fq::from_montgomery_limbs([
+                3702578103472198351,
+                457207116212653526,
+                13922806736777674813,
+                847911231855146886,
+            ]),
+            Fq::from_montgomery_limbs([
+                13321749795370386415,
+                238331953778937421,
+                5912067236396427379,
+                550281735722294454,
+            ]),
+            Fq::from_montgomery_limbs([
+                11093197950382405061,
+                12190345423389326924,
+                8446089260490276400,
+                580514062547842047,
+            ]),
+            Fq::from_montgomery_limbs([
+                11867380087550658603,
+                2232814972264599050,
+                98663967073521103,
+                1277067151458541954,
+            ]),
+            Fq::from_montgomery_limbs([
+                1750907785522231487,
+                17528060626503201768,
+                18380316380053612911,
+                79019234134287723,
+            ]),
+            Fq::from_montgomery_limbs([
+                63481944715765457,
+                6527812697356450550,
+                4711722411220990500,
+                23192372978456204,
+            ]),
+            Fq::from_montgomery_limbs([
+                3731821613212399911,
+                1243619800799627809,
+                8182887800049881557,
+                617454174789603441,
+            ]),
+            Fq::from_montgomery_limbs([
+                11320613663524750339,
+                11923079954664183135,
+                16298884720241168857,
+                520900167950808911,
+            ]),
+            Fq::from_montgomery_limbs([
+                5315621318797133536,
+                4562092193400005296,
+                2921910705729165433,
+                1255973940892895964,
+            ]),
+            Fq::from_montgomery_limbs([
+                11519953759967892948,
+                16163048193159079397,
+                5617670671693799328,
+                1142755203973566892,
+            ]),
+            Fq::from_montgomery_limbs([
+                17043601156414912350,
+                15352091652410482285,
+                10015196017177012280,
+                216982774632300734,
+            ]),
+            Fq::from_montgomery_limbs([
+                14586284753153169854,
+                8952505803118564477,
+                7924312849283386557,
+                966558310457631539,
+            ]),
+            Fq::from_montgomery_limbs([
+                15150375747754370532,
+                11152063989940793191,
+                571732735462755724,
+                940713264491443736,
+            ]),
+            Fq::from_montgomery_limbs([
+                6214346741798560429,
+                7051119567868666493,
+                10088480646380024325,
+                1171094597187403986,
+            ]),
+            Fq::from_montgomery_limbs([
+                9725134489566376162,
+                14247091270568453802,
+                1441581649280778651,
+                460775302350368408,
+            ]),
+            Fq::from_montgomery_limbs([
+                11280509254147332200,
+                408425589225053930,
+                18014372877159783346,
+                788546825195848295,
+            ]),
+            Fq::from_montgomery_limbs([
+                1887025252402801220,
+                9444540743215818713,
+                8107506648754052558,
+                763264984891760421,
+            ]),
+            Fq::from_montgomery_limbs([
+                10974277740330216457,
+                2910183994672367233,
+                12107236526463529623,
+                781933534161173990,
+            ]),
+            Fq::from_montgomery_limbs([
+                6306590258706477115,
+                8120827641074284671,
+                4885014120531215730,
+                160340657664749104,
+            ]),
+            Fq::from_montgomery_limbs([
+                976971879666672619,
+                3724365740196067436,
+                7817981769317230035,
+                467488508299868426,
+            ]),
+            Fq::from_montgomery_limbs([
+                18024251909431177219,
+                10115550045406800693,
+                13393222077500339183,
+                620981658006832202,
+            ]),
+            Fq::from_montgomery_limbs([
+                6651080343884096794,
+                1303407115690939385,
+                9159881886984866987,
+                1281553844232196999,
+            ]),
+            Fq::from_montgomery_limbs([
+                7586838480488137155,
+                1513180381502750985,
+                10023065659265076361,
+                1069926077809784693,
+            ]),
+            Fq::from_montgomery_limbs([
+                12076197959265374477,
+                9345061992395169170,
+                7782728394991197749,
+                445936826088619068,
+            ]),
+            Fq::from_montgomery_limbs([
+                7256630202104093436,
+                9221868214447520491,
+                919464778653749069,
+                1174651698298486721,
+            ]),
+            Fq::from_montgomery_limbs([
+                15262469966309251453,
+                1043599550766081739,
+                15738473844232373324,
+                899025537274107401,
+            ]),
+            Fq::from_montgomery_limbs([
+                12323651101224216715,
+                10237220205957400152,
+                12028774229851432455,
+                926430311825363082,
+            ]),
+            Fq::from_montgomery_limbs([
+                7377631051913775212,
+                15309144112835941755,
+                4044570458177279734,
+                690052498306511623,
+            ]),
+            Fq::from_montgomery_limbs([
+                10009420052201705655,
+                7683710082835148252,
+                4767847016789696307,
+                1282744941545329601,
+            ]),
+            Fq::from_montgomery_limbs([
+                3979486060288901533,
+                5573430228298408595,
+                3955126911776076833,
+                73775600770213550,
+            ]),
+            Fq::from_montgomery_limbs([
+                12104823538940405006,
+                16939294959551508697,
+                13109571548253069183,
+                1196789930483069781,
+            ]),
+            Fq::from_montgomery_limbs([
+                13590563289703073709,
+                12210589199000471616,
+                7048332931474534252,
+                862358339272924585,
+            ]),
+            Fq::from_montgomery_limbs([
+                13552878872905308829,
+                18284763609389466580,
+                8411962786019774472,
+                454614627603795374,
+            ]),
+            Fq::from_montgomery_limbs([
+                9521061040175380666,
+                12350040759038666855,
+                1200845884191233191,
+                453171369958175670,
+            ]),
+            Fq::from_montgomery_limbs([
+                11400991135746244594,
+                4008192174635918029,
+                3566115222051954982,
+                307963224625600716,
+            ]),
+            Fq::from_montgomery_limbs([
+                4902720152594269267,
+                15156738706685664368,
+                7644958303350926456,
+                960407921744051690,
+            ]),
+            Fq::from_montgomery_limbs([
+                10904175215907823140,
+                3279550196915235564,
+                2145044551714959925,
+                396668772362421901,
+            ]),
+            Fq::from_montgomery_limbs([
+                10330989707201570566,
+                3992976101907001120,
+                4386171735959777136,
+                334346315006468900,
+            ]),
+            Fq::from_montgomery_limbs([
+                16011684669559358070,
+                9385619834851659315,
+                13029933051677739185,
+                283271275046927826,
+            ]),
+            Fq::from_montgomery_limbs([
+                5689289489585087861,
+                14882376137198585709,
+                5119554847809097024,
+                252235890553025847,
+            ]),
+            Fq::from_montgomery_limbs([
+                10966014075326442947,
+                10124307166287365298,
+                2688325100868170669,
+                1176687684350682186,
+            ]),
+            Fq::from_montgomery_limbs([
+                4352939745843853510,
+                8929192944117947586,
+                10678558932384102278,
+                1084030157609476401,
+            ]),
+            Fq::from_montgomery_limbs([
+                12001103496232035201,
+                8515671253866218708,
+                11236909325664198294,
+                42803087951738633,
+            ]),
+            Fq::from_montgomery_limbs([
+                4677788792635528294,
+                9173189044422738971,
+                10350261653340565268,
+                318175869041828987,
+            ]),
+            Fq::from_montgomery_limbs([
+                13563109473224304721,
+                15602722656334363940,
+                6193332492321604528,
+                952103891527332973,
+            ]),
+            Fq::from_montgomery_limbs([
+                6679385597520934447,
+                14816553153797744061,
+                17377817196956336532,
+                532407020092564351,
+            ]),
+            Fq::from_montgomery_limbs([
+                18104565364443559273,
+                6592343036768960588,
+                16894508927140068246,
+                688262290148068969,
+            ]),
+            Fq::from_montgomery_limbs([
+                5450593836210634447,
+                13603345262462808801,
+                324139347659865941,
+                711077978774998673,
+            ]),
+            Fq::from_montgomery_limbs([
+                14010413566946521622,
+                16224801399008299974,
+                9073189920896083665,
+                635701275014152658,
+            ]),
+            Fq::from_montgomery_limbs([
+                16270809769978424190,
+                56460865589024854,
+                17221765175580162870,
+                313772217327445851,
+            ]),
+            Fq::from_montgomery_limbs([
+                6485623502733388038,
+                14791897102641943804,
+                9934155462098685559,
+                945433611557190642,
+            ]),
+            Fq::from_montgomery_limbs([
+                16564703514935104961,
+                2115655386095484639,
+                10787985846752520751,
+                557500368030424527,
+            ]),
+            Fq::from_montgomery_limbs([
+                17551874868429954471,
+                11472632109572208952,
+                3941984358800311069,
+                483850434687007875,
+            ]),
+            Fq::from_montgomery_limbs([
+                10275775697213984107,
+                6772137871172044422,
+                436678404813674879,
+                40405962652606666,
+            ]),
+            Fq::from_montgomery_limbs([
+                4390795409504428538,
+                18295448844939101832,
+                15225012575944341089,
+                669679672692011539,
+            ]),
+            Fq::from_montgomery_limbs([
+                4051488008572102392,
+                15734301079564929367,
+                7626009730269223510,
+                548670053188812401,
+            ]),
+            Fq::from_montgomery_limbs([
+                3091543796692427886,
+                17227663789388420891,
+                16221285325294943288,
+                957168143512865178,
+            ]),
+            Fq::from_montgomery_limbs([
+                2135270412815825304,
+                2928944033108758855,
+                4301082350836170661,
+                425895514268654545,
+            ]),
+            Fq::from_montgomery_limbs([
+                15898789718117035620,
+                11833845810037619197,
+                12406083212172164642,
+                1268739013117333657,
+            ]),
+            Fq::from_montgomery_limbs([
+                1250228511248889505,
+                16612560676854706044,
+                17641857898879614797,
+                794032398384968042,
+            ]),
+            Fq::from_montgomery_limbs([
+                17843012730212227854,
+                937066352408465121,
+                8090023128887819734,
+                231933393244787280,
+            ]),
+            Fq::from_montgomery_limbs([
+                6074175076620643361,
+                2358252677195825135,
+                12514115696935889655,
+                847865250856173420,
+            ]),
+            Fq::from_montgomery_limbs([
+                10118735433076384978,
+                3715058960731268039,
+                5313714650541025080,
+                17637326417173939,
+            ]),
+            Fq::from_montgomery_limbs([
+                13183258488825461075,
+                11605220765019399598,
+                9859467505264663257,
+                41030017162258515,
+            ]),
+            Fq::from_montgomery_limbs([
+                6375698124637877797,
+                5629831298163133259,
+                14904133955901525735,
+                558798140734731524,
+            ]),
+            Fq::from_montgomery_limbs([
+                9703837185159134278,
+                8116055065076777489,
+                13129828714917958005,
+                175350984486839583,
+            ]),
+            Fq::from_montgomery_limbs([
+                1996204862349998966,
+                15170059452234337776,
+                12336659270087700154,
+                1312304880555223665,
+            ]),
+            Fq::from_montgomery_limbs([
+                3590326444381034164,
+                11081047508292750292,
+                13929248981607412389,
+                1148551456529969961,
+            ]),
+            Fq::from_montgomery_limbs([
+                15748711275568111378,
+                9827821351496978377,
+                1089451566498109435,
+                385573108562977388,
+            ]),
+            Fq::from_montgomery_limbs([
+                5854308759232665075,
+                14646745164651706053,
+                1059818340393879209,
+                278073037803678303,
+            ]),
+            Fq::from_montgomery_limbs([
+                782875270311229251,
+                14129736259963306101,
+                17676571092472799579,
+                99784368099657632,
+            ]),
+            Fq::from_montgomery_limbs([
+                6194170668440688218,
+                14282655764847950690,
+                9256960510560043677,
+                738800301246671320,
+            ]),
+            Fq::from_montgomery_limbs([
+                5647874486303058603,
+                5594031317822353747,
+                1185150640969897168,
+                1250626751811179876,
+            ]),
+            Fq::from_montgomery_limbs([
+                7010803484069701924,
+                12818075181882154010,
+                16406691674597778333,
+                332452086185430739,
+            ]),
+            Fq::from_montgomery_limbs([
+                10558603365142197609,
+                11373600441535346477,
+                9726798938291635143,
+                500581893892681231,
+            ]),
+            Fq::from_montgomery_limbs([
+                17131488617883841252,
+                3385215517201649311,
+                6293710470421583532,
+                1292140013300148706,
+            ]),
+            Fq::from_montgomery_limbs([
+                16405571866748578361,
+                8346417963005706131,
+                6891455370369515846,
+                332616568299419767,
+            ]),
+            Fq::from_montgomery_limbs([
+                17183384594343797329,
+                2919752086761121270,
+                7919437726720679875,
+                832859427841304916,
+            ]),
+            Fq::from_montgomery_limbs([
+                4259649235776782634,
+                16933161878908259009,
+                16645343458225013262,
+                177054171537045438,
+            ]),
+            Fq::from_montgomery_limbs([
+                7893282804315531011,
+                7181748259050086665,
+                9135073823090683089,
+                1044994866088352692,
+            ]),
+            Fq::from_montgomery_limbs([
+                11393344498918750487,
+                12041340704289672685,
+                15561100566248834211,
+                940255115909675717,
+            ]),
+            Fq::from_montgomery_limbs([
+                16853825124296855945,
+                12640313510403419631,
+                5377413186105875938,
+                801496584973066062,
+            ]),
+            Fq::from_montgomery_limbs([
+                9056285154570923331,
+                356918824519122980,
+                6910520113130040956,
+                409464575493953339,
+            ]),
+            Fq::from_montgomery_limbs([
+                16711436605099243862,
+                15893275493260980658,
+                14432027051421917234,
+                1153577179318646422,
+            ]),
+            Fq::from_montgomery_limbs([
+                7734754032896877523,
+                16505006266553674644,
+                12884940138859397527,
+                843389732174119606,
+            ]),
+            Fq::from_montgomery_limbs([
+                4824715170359514276,
+                303364379103377811,
+                14613520644787478108,
+                1234987705897889628,
+            ]),
+            Fq::from_montgomery_limbs([
+                5162235577849424098,
+                17366789317221808794,
+                14336156627491895866,
+                33481018837137662,
+            ]),
+            Fq::from_montgomery_limbs([
+                8663174270219934585,
+                7712730996668459388,
+                11821491074593993434,
+                784443953675314530,
+            ]),
+            Fq::from_montgomery_limbs([
+                1508340974944431647,
+                3601973860161993386,
+                12800432673129849392,
+                728332055529059138,
+            ]),
+            Fq::from_montgomery_limbs([
+                7221075483357098124,
+                7987592524343018443,
+                5793260830898302072,
+                307945833752362188,
+            ]),
+            Fq::from_montgomery_limbs([
+                4239430024091038436,
+                3174815200553333671,
+                17077712470644489873,
+                763307098023159221,
+            ]),
+            Fq::from_montgomery_limbs([
+                11159655911192590320,
+                3765420882842581742,
+                15236876498537301538,
+                405919150343767661,
+            ]),
+            Fq::from_montgomery_limbs([
+                3390863659191225763,
+                18101882968319659842,
+                17217261695529609403,
+                1176312514039579581,
+            ]),
+            Fq::from_montgomery_limbs([
+                7267337626587624560,
+                9710998069454229270,
+                2804038872846790284,
+                874697408246268272,
+            ]),
+            Fq::from_montgomery_limbs([
+                472099205892538830,
+                13955143156109384962,
+                9899555855436721512,
+                93129938491162901,
+            ]),
+            Fq::from_montgomery_limbs([
+                2472697551768019341,
+                12463736814021393633,
+                10075113450776466385,
+                600266021745863152,
+            ]),
+            Fq::from_montgomery_limbs([
+                4499264873699708339,
+                11960299134664465463,
+                4906517236926788877,
+                1287390496324344728,
+            ]),
+            Fq::from_montgomery_limbs([
+                3440393536074736584,
+                14325114223416017606,
+                4399892897936904155,
+                129925042594696382,
+            ]),
+        ]),
+        mds: MdsMatrix::<8, 7, 64, 49>::new_from_known([
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+        ]),
+        alpha: Alpha::Inverse,
+        rounds: RoundNumbers { r_P: 54, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<7, 49>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+            ]),
+            v: Matrix::<1, 7, 7>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+            ]),
+            w: Matrix::<7, 1, 7>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<8, 64>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<8, 64>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5469465372454405746,
+                    11693688927749785195,
+                    9284229770381589387,
+                    263916402201309764,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8025142443496737775,
+                    1611073224873590596,
+                    1595200312703520851,
+                    268362793599128279,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2646197433325367530,
+                    3327161674116363870,
+                    17523969835882673765,
+                    1224872920276084181,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3531022552160069605,
+                    1044916798369741293,
+                    13484540382795405112,
+                    975392012720575741,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3532672362950129848,
+                    13232567668052949956,
+                    3949082925084889589,
+                    1169300250855119928,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17405909644706202533,
+                    9068195655028130334,
+                    4993737853698066182,
+                    760630245260613434,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10760096219897954109,
+                    15819961273994906871,
+                    12000866060961275644,
+                    494051747708222359,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<8, 64>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    13084505025340709209,
+                    6658414703856389670,
+                    1229628874832604981,
+                    544699057731131701,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9271504314401065906,
+                    16573554673674659136,
+                    11112785265390720139,
+                    695302674280213266,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17755863455076530065,
+                    714019269282123475,
+                    2516777105896065646,
+                    758853547373067215,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18230149865394473823,
+                    17597653080170983126,
+                    8526134725373349310,
+                    254065511080298010,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12943062609168455850,
+                    4036522178185995734,
+                    13999395164871811986,
+                    158263095242933732,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9727917081763370379,
+                    12523860206965601712,
+                    6438415234309761598,
+                    578292727069385307,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4722164372208948539,
+                    6191995659471889704,
+                    9488304077820150497,
+                    383493821809394045,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6308417298426862088,
+                    11443735935349473316,
+                    5741617995111411363,
+                    1072746374115513684,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9271504314401065906,
+                    16573554673674659136,
+                    11112785265390720139,
+                    695302674280213266,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6461820328084053982,
+                    3713881430924031860,
+                    2384237101556363354,
+                    925204973024161309,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11045222492845432763,
+                    12330616953129622165,
+                    6721295490146207098,
+                    737212261478666249,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17374877272010299584,
+                    12910097275334870758,
+                    11030123918921243889,
+                    999547114982057960,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2710620113890028948,
+                    7440189668352585633,
+                    11434649235474493285,
+                    24415954842353912,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7017296967873341431,
+                    5083670538613016079,
+                    13450510072544819929,
+                    553876772227031394,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13756252569614172144,
+                    10114182252140374927,
+                    8424160399132869901,
+                    370135544443278065,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8192890567029291618,
+                    6189339163642963502,
+                    10515708329663122411,
+                    718287917303790275,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17755863455076530065,
+                    714019269282123475,
+                    2516777105896065646,
+                    758853547373067215,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11045222492845432763,
+                    12330616953129622165,
+                    6721295490146207098,
+                    737212261478666249,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1107303498763469844,
+                    5522738209527454529,
+                    7444362964372879018,
+                    94326278492989394,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17301615911438019293,
+                    6207101570101901683,
+                    6912719408314906153,
+                    670136716188149414,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8052773691804060961,
+                    7894827066734284032,
+                    17737200459541160653,
+                    1344927881702280038,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3579877764578944756,
+                    17358359975581377230,
+                    2902328015395623063,
+                    1076553286270705854,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7922495546740583511,
+                    13377932406425725078,
+                    312087136472518954,
+                    724215661521432074,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14304988956035390969,
+                    2978747834443270582,
+                    211153190861140083,
+                    1101825800236420991,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18230149865394473823,
+                    17597653080170983126,
+                    8526134725373349310,
+                    254065511080298010,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17374877272010299584,
+                    12910097275334870758,
+                    11030123918921243889,
+                    999547114982057960,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17301615911438019293,
+                    6207101570101901683,
+                    6912719408314906153,
+                    670136716188149414,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11280947822772662719,
+                    3998258523005585690,
+                    3083179120639342004,
+                    919842772758514385,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9288629788793402586,
+                    2259376874925369448,
+                    13217169487415398853,
+                    539099117435770765,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8430375547150652021,
+                    1001249403497996831,
+                    16789953243695164761,
+                    1344355087100203329,
+                ]),
+                Fq::from_montgomery_limbs([
+                    527596723520651020,
+                    15187839531048996200,
+                    6255568353483246533,
+                    170911683896477388,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1734565667097520919,
+                    12020388067710193448,
+                    6095663732563761512,
+                    1138214739842689620,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12943062609168455850,
+                    4036522178185995734,
+                    13999395164871811986,
+                    158263095242933732,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2710620113890028948,
+                    7440189668352585633,
+                    11434649235474493285,
+                    24415954842353912,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8052773691804060961,
+                    7894827066734284032,
+                    17737200459541160653,
+                    1344927881702280038,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9288629788793402586,
+                    2259376874925369448,
+                    13217169487415398853,
+                    539099117435770765,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11829305752964125757,
+                    10778200385642703226,
+                    2095381984842221696,
+                    365296429444826263,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9209237328018148115,
+                    9013177660724622161,
+                    13411670809827118984,
+                    1159740245409298491,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1786760175787716883,
+                    16585118128488698423,
+                    3661827234912273881,
+                    1175179421357105610,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6997506992288193640,
+                    3314349379260916067,
+                    10669743096907430976,
+                    828027530724672872,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9727917081763370379,
+                    12523860206965601712,
+                    6438415234309761598,
+                    578292727069385307,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7017296967873341431,
+                    5083670538613016079,
+                    13450510072544819929,
+                    553876772227031394,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3579877764578944756,
+                    17358359975581377230,
+                    2902328015395623063,
+                    1076553286270705854,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8430375547150652021,
+                    1001249403497996831,
+                    16789953243695164761,
+                    1344355087100203329,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9209237328018148115,
+                    9013177660724622161,
+                    13411670809827118984,
+                    1159740245409298491,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2800053360411677182,
+                    7739112841912438666,
+                    4458443078052365313,
+                    1221561517743072277,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5714397546912390046,
+                    7204578223640664713,
+                    3369806468691410388,
+                    19539807714888073,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7912928165080095595,
+                    10691173171385779887,
+                    7900069818404182519,
+                    558654216499734169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4722164372208948539,
+                    6191995659471889704,
+                    9488304077820150497,
+                    383493821809394045,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13756252569614172144,
+                    10114182252140374927,
+                    8424160399132869901,
+                    370135544443278065,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7922495546740583511,
+                    13377932406425725078,
+                    312087136472518954,
+                    724215661521432074,
+                ]),
+                Fq::from_montgomery_limbs([
+                    527596723520651020,
+                    15187839531048996200,
+                    6255568353483246533,
+                    170911683896477388,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1786760175787716883,
+                    16585118128488698423,
+                    3661827234912273881,
+                    1175179421357105610,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5714397546912390046,
+                    7204578223640664713,
+                    3369806468691410388,
+                    19539807714888073,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11467623733094325177,
+                    12601944683740404995,
+                    14270804009154313170,
+                    1283543454792166291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1591478553039085540,
+                    13629203225225281079,
+                    9223729349286412660,
+                    541375501705678927,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6308417298426862088,
+                    11443735935349473316,
+                    5741617995111411363,
+                    1072746374115513684,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8192890567029291618,
+                    6189339163642963502,
+                    10515708329663122411,
+                    718287917303790275,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14304988956035390969,
+                    2978747834443270582,
+                    211153190861140083,
+                    1101825800236420991,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1734565667097520919,
+                    12020388067710193448,
+                    6095663732563761512,
+                    1138214739842689620,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6997506992288193640,
+                    3314349379260916067,
+                    10669743096907430976,
+                    828027530724672872,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7912928165080095595,
+                    10691173171385779887,
+                    7900069818404182519,
+                    558654216499734169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1591478553039085540,
+                    13629203225225281079,
+                    9223729349286412660,
+                    541375501705678927,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1183879673177082740,
+                    4195985459140120089,
+                    6602546398975040286,
+                    268356784116832629,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<7, 49>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    4596204885914937926,
+                    7838695242625897787,
+                    3035134948628881865,
+                    1207227057562625259,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15896440128761118173,
+                    15649465122428497798,
+                    4384954020081155217,
+                    913236075158308182,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2335397575999068166,
+                    14422783505571319241,
+                    2719190544357073120,
+                    274885737548821162,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9009029437947626511,
+                    7346990291023997455,
+                    3414440920242711508,
+                    50729493538167678,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1147572841630042462,
+                    8929633407603160962,
+                    14641836547291178689,
+                    426104246454882272,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17053865508971679549,
+                    2198382506890441430,
+                    11312636819879946010,
+                    1106661992673439325,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13854198271768011224,
+                    4053309739918946052,
+                    18433076019880982368,
+                    615216866081782140,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15896440128761118173,
+                    15649465122428497798,
+                    4384954020081155217,
+                    913236075158308182,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4761711418629517518,
+                    591743220415883789,
+                    2347216482122737487,
+                    541885199414988935,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16049706050976647208,
+                    9176322874162797755,
+                    12242590547654900654,
+                    61012554895502211,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10907149703911289876,
+                    345335516406280141,
+                    13671249158696272740,
+                    1076200797284812494,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3783029764742972160,
+                    8718337959668375608,
+                    9493474458284681386,
+                    1324609056502214356,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7820919546658569809,
+                    8474571377527450081,
+                    15463257988737541409,
+                    600187776405677822,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17765856891579054878,
+                    11653192174908829654,
+                    4966200605019140816,
+                    13151716642854399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2335397575999068166,
+                    14422783505571319241,
+                    2719190544357073120,
+                    274885737548821162,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16049706050976647208,
+                    9176322874162797755,
+                    12242590547654900654,
+                    61012554895502211,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13442108051579804680,
+                    3187031622749351601,
+                    18435466452818364440,
+                    257424390549798158,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14787872399840322022,
+                    11612292071578382904,
+                    2915446897265489403,
+                    1027394734812832541,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14650186278276933856,
+                    11736505343490130034,
+                    4478509448304020774,
+                    790137565622286814,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16227186307902919639,
+                    13050765287152844350,
+                    6672057872444298207,
+                    1120660629979522345,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17280730943809781377,
+                    2101938472365641700,
+                    2221542087098170284,
+                    682975842437066220,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9009029437947626511,
+                    7346990291023997455,
+                    3414440920242711508,
+                    50729493538167678,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10907149703911289876,
+                    345335516406280141,
+                    13671249158696272740,
+                    1076200797284812494,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14787872399840322022,
+                    11612292071578382904,
+                    2915446897265489403,
+                    1027394734812832541,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9754754145024164416,
+                    9500194995930094065,
+                    4605218223030367360,
+                    489015282389927384,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11235823697266068713,
+                    9767911161281654263,
+                    15004902496107301847,
+                    1252504438181231825,
+                ]),
+                Fq::from_montgomery_limbs([
+                    773466991163756584,
+                    6984379341355406564,
+                    2865211391772182450,
+                    1128797324971138943,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3783029764742972160,
+                    8718337959668375608,
+                    9493474458284681386,
+                    1324609056502214356,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1147572841630042462,
+                    8929633407603160962,
+                    14641836547291178689,
+                    426104246454882272,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3783029764742972160,
+                    8718337959668375608,
+                    9493474458284681386,
+                    1324609056502214356,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14650186278276933856,
+                    11736505343490130034,
+                    4478509448304020774,
+                    790137565622286814,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11235823697266068713,
+                    9767911161281654263,
+                    15004902496107301847,
+                    1252504438181231825,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10750604789646168140,
+                    372497979918419381,
+                    10429606583776526343,
+                    928966821232337907,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10962493869149920375,
+                    10887885654637674355,
+                    384224715829329873,
+                    165837155970255258,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2645295168975782308,
+                    13863838601458690618,
+                    7409022743292405220,
+                    617686796815916414,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17053865508971679549,
+                    2198382506890441430,
+                    11312636819879946010,
+                    1106661992673439325,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7820919546658569809,
+                    8474571377527450081,
+                    15463257988737541409,
+                    600187776405677822,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16227186307902919639,
+                    13050765287152844350,
+                    6672057872444298207,
+                    1120660629979522345,
+                ]),
+                Fq::from_montgomery_limbs([
+                    773466991163756584,
+                    6984379341355406564,
+                    2865211391772182450,
+                    1128797324971138943,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10962493869149920375,
+                    10887885654637674355,
+                    384224715829329873,
+                    165837155970255258,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17704196732594477900,
+                    7529737242141985421,
+                    3056083190610322131,
+                    537754595320396000,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4588045927327100104,
+                    15273424236288740466,
+                    3730020508107780990,
+                    1184499396891674504,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13854198271768011224,
+                    4053309739918946052,
+                    18433076019880982368,
+                    615216866081782140,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17765856891579054878,
+                    11653192174908829654,
+                    4966200605019140816,
+                    13151716642854399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17280730943809781377,
+                    2101938472365641700,
+                    2221542087098170284,
+                    682975842437066220,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3783029764742972160,
+                    8718337959668375608,
+                    9493474458284681386,
+                    1324609056502214356,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2645295168975782308,
+                    13863838601458690618,
+                    7409022743292405220,
+                    617686796815916414,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4588045927327100104,
+                    15273424236288740466,
+                    3730020508107780990,
+                    1184499396891674504,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5513671693009890019,
+                    13840477803572862779,
+                    13148892977164251883,
+                    530785029804846654,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            M_i: Matrix::<8, 8, 64>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2993552946244981208,
+                    2811409048921633080,
+                    15599136794178834648,
+                    1158315557451137435,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5674610480636705945,
+                    5692908388701221498,
+                    11027560066087921967,
+                    1233666539881230762,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16239403337986286086,
+                    16121701495986491495,
+                    5330236610910382313,
+                    86250205351001159,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12227500521668474429,
+                    18052667526139165323,
+                    1183704998273594537,
+                    789555927514719247,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14074475294596281098,
+                    15650878014471899089,
+                    5592333379661334428,
+                    1291309576839151179,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2608570563387156274,
+                    6811572099313420967,
+                    13555295777019456928,
+                    844772645840867070,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10589825703536414738,
+                    4063357722990315468,
+                    2781829946940344014,
+                    53524305007326959,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5674610480636705945,
+                    5692908388701221498,
+                    11027560066087921967,
+                    1233666539881230762,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4465901541981918381,
+                    5559283541481765580,
+                    15930831541993607803,
+                    1191346103064912907,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2575513339562532686,
+                    14947730371642919315,
+                    7481918938988883259,
+                    1236426319594803258,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12334577693106458772,
+                    13356181766894539400,
+                    17120998997628207555,
+                    1255828765342631327,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14154343397923331166,
+                    6384431759489515553,
+                    11340173629986908949,
+                    395704660766492403,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16714064209344157840,
+                    16242270146097148407,
+                    2564480184549472343,
+                    709068167539187668,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11982947160040912427,
+                    12786354184055367995,
+                    12045765562529007720,
+                    772233580916974532,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    16239403337986286086,
+                    16121701495986491495,
+                    5330236610910382313,
+                    86250205351001159,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2575513339562532686,
+                    14947730371642919315,
+                    7481918938988883259,
+                    1236426319594803258,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10624331987937373686,
+                    2546345426541302340,
+                    8953891776765578360,
+                    321421759697898224,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15064256198821842973,
+                    16215311001566990372,
+                    3084676449443316940,
+                    703433576915758324,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5042175247253429253,
+                    14085530650264239944,
+                    2442297940254232802,
+                    1329329539510571805,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5107917625653371528,
+                    276455861253002854,
+                    9155101870671731182,
+                    710277116261071192,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11130670736758059986,
+                    15701520021801407243,
+                    14904884970948355982,
+                    911094277169858801,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12227500521668474429,
+                    18052667526139165323,
+                    1183704998273594537,
+                    789555927514719247,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12334577693106458772,
+                    13356181766894539400,
+                    17120998997628207555,
+                    1255828765342631327,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15064256198821842973,
+                    16215311001566990372,
+                    3084676449443316940,
+                    703433576915758324,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17554272249998566833,
+                    6694970440581263667,
+                    4724305318739741123,
+                    483131370049072853,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10120741810245752136,
+                    12019349118356478824,
+                    9511464401055683654,
+                    685404915951315468,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1665743336378191281,
+                    3446519105946854533,
+                    7195947707384598081,
+                    1288698095747401074,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4313641329143609112,
+                    1539206427224064052,
+                    17352019704330472030,
+                    1190027239271141991,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14074475294596281098,
+                    15650878014471899089,
+                    5592333379661334428,
+                    1291309576839151179,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14154343397923331166,
+                    6384431759489515553,
+                    11340173629986908949,
+                    395704660766492403,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5042175247253429253,
+                    14085530650264239944,
+                    2442297940254232802,
+                    1329329539510571805,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10120741810245752136,
+                    12019349118356478824,
+                    9511464401055683654,
+                    685404915951315468,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13213764958795820255,
+                    14027693630915348926,
+                    13102282047794773409,
+                    729561827228291503,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15923638559221015529,
+                    9678289844797304562,
+                    403681780731131683,
+                    617668239251084708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12679164731885262064,
+                    3033402383877862497,
+                    12418057132760300645,
+                    1200232661351445724,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2608570563387156274,
+                    6811572099313420967,
+                    13555295777019456928,
+                    844772645840867070,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16714064209344157840,
+                    16242270146097148407,
+                    2564480184549472343,
+                    709068167539187668,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5107917625653371528,
+                    276455861253002854,
+                    9155101870671731182,
+                    710277116261071192,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1665743336378191281,
+                    3446519105946854533,
+                    7195947707384598081,
+                    1288698095747401074,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15923638559221015529,
+                    9678289844797304562,
+                    403681780731131683,
+                    617668239251084708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10080543991066711169,
+                    6059003343984955432,
+                    18397700167065451238,
+                    450307653654010180,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1138269374722032584,
+                    12492829733911154361,
+                    230882141110268266,
+                    465759358202464703,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10589825703536414738,
+                    4063357722990315468,
+                    2781829946940344014,
+                    53524305007326959,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11982947160040912427,
+                    12786354184055367995,
+                    12045765562529007720,
+                    772233580916974532,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11130670736758059986,
+                    15701520021801407243,
+                    14904884970948355982,
+                    911094277169858801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4313641329143609112,
+                    1539206427224064052,
+                    17352019704330472030,
+                    1190027239271141991,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12679164731885262064,
+                    3033402383877862497,
+                    12418057132760300645,
+                    1200232661351445724,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1138269374722032584,
+                    12492829733911154361,
+                    230882141110268266,
+                    465759358202464703,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5166318390649425348,
+                    18017065457986060705,
+                    10259888256420984286,
+                    809271710187186311,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17640631015407645126,
+                        17416650484724036037,
+                        14803487960933328666,
+                        554882262980868625,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10632745392742701465,
+                        7191381972905833266,
+                        10576427669223334706,
+                        1037506184958051122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7989155441247042094,
+                        18276457113184108543,
+                        17999817914616464103,
+                        943187440870955565,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18204910156218979669,
+                        16293041589642941780,
+                        9975069610392864084,
+                        1088801882579738168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15441359965645378481,
+                        7022857083512140090,
+                        11162758362666494739,
+                        1108530997049617801,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7802104352665277001,
+                        1712233803072817444,
+                        12180777293186749586,
+                        1125441666595228915,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7330330845985706257,
+                        6947957132670498679,
+                        9373711551562393463,
+                        1140097580201425214,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18366132767879360967,
+                        5431013863214313926,
+                        3325023203464288028,
+                        747241129062195048,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4861545468195100803,
+                        11791803721085820360,
+                        16861242526837808182,
+                        587224779081284519,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6038434302262732315,
+                        17199494462234427620,
+                        17847977008226730647,
+                        613398622514363430,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8422783269805610096,
+                        16656220536572751252,
+                        7918639979705401643,
+                        170910085608382473,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18349346982789927355,
+                        8017591337915184637,
+                        18372658176979362927,
+                        927323834744911125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6098144161467281354,
+                        2162126959782930019,
+                        8016715582812993181,
+                        87916415604842323,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14926888907449900570,
+                        16034226105739794058,
+                        8242495446855270900,
+                        170857479056428767,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13761299995988853307,
+                        10330765076738035315,
+                        13655052507848964233,
+                        121839220114831784,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15369322242721768225,
+                        7050142047224256972,
+                        12643665775034470999,
+                        1102328692521833676,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10555474781715655116,
+                        6656109843697517764,
+                        16567476834724252290,
+                        1315407115111807184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15635518385029844173,
+                        579774923863089338,
+                        4907175580968184975,
+                        679471495325209728,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11185461100085345821,
+                        8221939874744266357,
+                        12287775310123057539,
+                        1019299127883999231,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        586274284242963056,
+                        8310909834705600558,
+                        3054222778649762639,
+                        763190023338919332,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6717490082866871699,
+                        9561189803595277803,
+                        2358146706687598339,
+                        229707033421591253,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9862704231385218313,
+                        13914100249743202263,
+                        10014223727046890158,
+                        679200801234608014,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16286005160576575401,
+                        2766108936000922095,
+                        13859049965904094985,
+                        720362228683248654,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18028696975939559798,
+                        6945291324150110288,
+                        3594371327059432745,
+                        1256526022736975448,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13724722228213474447,
+                        17954541567374385206,
+                        10748596771783277924,
+                        116970063643881223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17627786090808150672,
+                        7901620020601608720,
+                        1877160764958626418,
+                        756288000881176875,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14537377980409294015,
+                        18005737005152562388,
+                        9571375812741383887,
+                        59753059053707643,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4228748937594043872,
+                        11086846199995865064,
+                        15359245871481193897,
+                        1099589394616459299,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6835681309479739469,
+                        2522595035308448012,
+                        13579423836020142879,
+                        321430679545810677,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8928134061485321773,
+                        14157720579697729134,
+                        10344780082168020995,
+                        97202987142506800,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3126697640027307204,
+                        1153410184564297189,
+                        4809813821719177551,
+                        994774295722128937,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3918576597698600036,
+                        13183810862438807325,
+                        15214887128855617985,
+                        279159001700097111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2830967354854515654,
+                        17469509170635724421,
+                        8035672669498122237,
+                        267629026497518109,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13871567487914888086,
+                        5246409741722402492,
+                        16832389611931438025,
+                        1295010963726258350,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17057934674164606699,
+                        6421519839681476555,
+                        2956724044796119398,
+                        327825682992218753,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4795884052041907920,
+                        14375534011696101577,
+                        2427060550596560456,
+                        393919458849239754,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15372002461943753964,
+                        11342165919824154367,
+                        11980800855746001270,
+                        994521975576249810,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3093026885592381242,
+                        16909708896875106142,
+                        9191689902351332536,
+                        192118286974103755,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2545301077237922815,
+                        15144706238324940883,
+                        6113286606593174468,
+                        372600161316484359,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2142773713360590483,
+                        4755608127774394592,
+                        14813148169732966311,
+                        917761966614679749,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4228649950611283773,
+                        11633437476481650378,
+                        40335982213672820,
+                        963989416626637070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7391565095119442761,
+                        5231979613403440246,
+                        15148609598356850747,
+                        95369680580052342,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6221242600163036024,
+                        13151745595003222769,
+                        1880070415953293074,
+                        859483057557051135,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4057704583061373110,
+                        330438596431033553,
+                        11138603368324646862,
+                        567019942240333219,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6808348652855723900,
+                        16038145083514774712,
+                        7335293401571608063,
+                        1256860263027810296,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14712597649859914536,
+                        2461131389363589307,
+                        14971367896336061978,
+                        440886131765627826,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12563821899625315748,
+                        1397025790367362621,
+                        11711011249975071041,
+                        1326491445493487906,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7386366618870533036,
+                        17891650149672567880,
+                        7623078793319051970,
+                        896259535119079804,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10076387373785417531,
+                        11621404135443198278,
+                        2342191148009104794,
+                        1208570082728513479,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15679839989875735864,
+                        3094159736795405193,
+                        8433247987399569527,
+                        598394739716746812,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13169926426306420693,
+                        1494478164246339314,
+                        1807871555080064306,
+                        341871317105251652,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8871477167695700102,
+                        7124763693791718581,
+                        9576781928097497051,
+                        714519781637518717,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5983131451216637528,
+                        14746501237947100556,
+                        5131581902376092495,
+                        53578888100518755,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4061768962354414816,
+                        11864639021370326188,
+                        865372274790703007,
+                        960248048765553610,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13741178490350281184,
+                        16104064291813496481,
+                        430569565272838614,
+                        1002559943654640050,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2394748621667543278,
+                        9724971631453415812,
+                        4137744279709050144,
+                        1128204720227512009,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8347131072359520901,
+                        7051906710316929352,
+                        16145803176151958291,
+                        425602845445213918,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1777783046051939958,
+                        9344856863579469212,
+                        15141578180236122558,
+                        1282494716270035254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9034384578885080751,
+                        4457232682759798992,
+                        2752092198692805323,
+                        133997596024339666,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6632806707492790657,
+                        4419466991289176034,
+                        10545594397744340230,
+                        225792487504094029,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13666870638471858948,
+                        6325954648727450515,
+                        6820384268867641136,
+                        650912658514216838,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18425172866543397587,
+                        460014089142918565,
+                        13475177727189665478,
+                        755199790640816277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17074816481639390245,
+                        8326625026248068453,
+                        11628221368426774047,
+                        168403074861182097,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6496117145112017285,
+                        8108611305258852743,
+                        1288333658986417240,
+                        489166257449840915,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5905164426314492425,
+                        14726319284315803257,
+                        15419119459033009106,
+                        748785652021292365,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2903900515980491508,
+                        14518787377368638193,
+                        5737950893394728457,
+                        1246585555966129910,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13545067403644787822,
+                        12929589241462425919,
+                        6328577959723156897,
+                        669792805130642725,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10880665937604410417,
+                        12182942785008105675,
+                        8043148962657686879,
+                        114751567600998309,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        475884094757470315,
+                        13519631230544868403,
+                        15555278094421071668,
+                        284046901452720062,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4404185810772829111,
+                        13082485550708084688,
+                        4458404332519456617,
+                        747118566938719314,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9005422667436345080,
+                        6610327294069915149,
+                        3392047280994887983,
+                        185928194404395622,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5907489245706778002,
+                        2611521107474717810,
+                        15424563447378789128,
+                        1081732924695236796,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6936175948569238427,
+                        16265819354303431672,
+                        9296694820053549015,
+                        380971488662375691,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11847879878992849222,
+                        17246157629192690467,
+                        2240336221502957918,
+                        551120206298529047,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13322826606934505627,
+                        11726197427344458839,
+                        11800080869580456447,
+                        426669602496174268,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6217036164702465819,
+                        14690910531871435703,
+                        13161157531336562298,
+                        732442567722820811,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14882293327891629398,
+                        9369498032959659338,
+                        7000848696812799171,
+                        754562041670533469,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7595387151201254327,
+                        16626796275200533088,
+                        2940178911825115337,
+                        1184031511121567741,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9948362750111031958,
+                        14209593175711129688,
+                        8446364573948386189,
+                        802006068920106393,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15169311970095301438,
+                        12318244252296365506,
+                        11639105278755798079,
+                        84817162733084173,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5831579622220754273,
+                        10846086049576885619,
+                        15991974831220053526,
+                        756084870377153501,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4538407139413953048,
+                        3210811593774606829,
+                        7928892177512965493,
+                        295438458271184500,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16833512221632219759,
+                        4464061448066183306,
+                        13162091753490675863,
+                        292236190186079508,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13779800725609980259,
+                        9773765625728017659,
+                        15074810316420391540,
+                        950783607166623145,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15895706787288222123,
+                        9777370406550782832,
+                        1347824831892755594,
+                        1241011767014366278,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12826978362776209042,
+                        10633709265050784734,
+                        17957605540213546613,
+                        887698438045779720,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7324188878915493946,
+                        6492479003127466111,
+                        8377249725203128879,
+                        1000161290003049779,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6704086117324761110,
+                        146265122731217307,
+                        2383903051894120264,
+                        719647807141165334,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1940312057575972126,
+                        17930067253838912265,
+                        317360086895888872,
+                        5748540581767140,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17103257683729273147,
+                        13931874259783487325,
+                        11872224486078756015,
+                        956814895668207498,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13769500095117682353,
+                        516850361598419166,
+                        16964626273259476242,
+                        769634211482580445,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3113137472185380309,
+                        3788943308088978270,
+                        15570984136244536866,
+                        64143465381601420,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7162163846740423034,
+                        17407158529676601980,
+                        982523548091459495,
+                        1241886141897586570,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14248266967200521181,
+                        12810010597713281838,
+                        15956352259342376510,
+                        1249991327654319042,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14421471441198040024,
+                        4052632755320875533,
+                        10507979567878886533,
+                        966126597683502788,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1330169793100628257,
+                        5902370011388045841,
+                        4268141693837804182,
+                        504954696237745802,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12989224119893885561,
+                        17234018500273188391,
+                        10939008301878322098,
+                        259903928118608299,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10430146681254844704,
+                        16501713083418526441,
+                        1818813698196386223,
+                        290604987547570674,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2272958606081209500,
+                        2462669223449072253,
+                        9500254477224930595,
+                        45443314528508583,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15776657251710579335,
+                        14822492561927322901,
+                        3903750765721394200,
+                        630182332308162068,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12563868195165779174,
+                        12561252953252587550,
+                        11907364401269631108,
+                        581676404653821486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        148332194159159988,
+                        10068425626588210986,
+                        13535393921505334097,
+                        153553395999465163,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7747426768506042379,
+                        7652171709845608793,
+                        11069887529886935999,
+                        60205371617013159,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9292570341502960523,
+                        3236193786725371597,
+                        8898130554136882239,
+                        1009410450149925646,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12885815634665358426,
+                        15963150787962414249,
+                        4369817253063661209,
+                        642948406974549063,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1380867230584918716,
+                        16725144872260989882,
+                        11444322500251449972,
+                        837022230218511427,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1087920425360181417,
+                        17141257576042242544,
+                        8987292985620878561,
+                        1110305532217600753,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10359964772262918650,
+                        10077460265989495047,
+                        17578499003187392916,
+                        757342367774183991,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2779779035056260202,
+                        2282975003625087828,
+                        1919400866622348845,
+                        493318954648288095,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15672164960009522373,
+                        3256607523370073938,
+                        13852207222752867333,
+                        1244522698781289416,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12874545038411867774,
+                        1217025423748364582,
+                        75869870772488332,
+                        1001217245879206186,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8523257600072604774,
+                        8621165006673159831,
+                        1464388999950649637,
+                        1217963817687697670,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15922812187951378322,
+                        4516460162368536866,
+                        15018766830043386765,
+                        1340613465456337965,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        287441026936003136,
+                        14764192345714853717,
+                        4173276030750185119,
+                        472111033963352598,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10839733167051473961,
+                        3454260583859678864,
+                        11304905847738494430,
+                        650946296327920383,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9515448149018827481,
+                        3488032113770392732,
+                        10593726245432275549,
+                        583635792309485522,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2742448600840811735,
+                        6652758193289359848,
+                        9635320418424938172,
+                        967821689260084870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9881468678921411834,
+                        11537438085600848038,
+                        7216253876764370660,
+                        1304070984061260617,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6196769084394513175,
+                        1271040910625745125,
+                        11073763102896512393,
+                        1165794523446068715,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15799597978241847363,
+                        12818531158251383624,
+                        6963429972659041410,
+                        397194962489148197,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        138391674938830183,
+                        6888521073413350790,
+                        13718840433311127770,
+                        1101538822720967029,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12684981053383903956,
+                        5500073381157750545,
+                        4169486943568405011,
+                        879134225324190092,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        464851715795247463,
+                        2927900015464510347,
+                        17346267830876442053,
+                        1137028262870600951,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1966072756669882436,
+                        14267740345733608423,
+                        3868025407329923728,
+                        1329300268340841458,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12791556832852048085,
+                        7187847328998161782,
+                        6410129678700860221,
+                        1141843742471101195,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17394082241710727109,
+                        3925707544410201182,
+                        15631236622778680461,
+                        867359934041458160,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12197981561714622691,
+                        3447558190007550222,
+                        8731482567404865792,
+                        504033851083789168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15548011549070215821,
+                        18176746913742005334,
+                        6759691930131684149,
+                        748826448544702472,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14426381672075599127,
+                        6754779155763548400,
+                        1569326308679565884,
+                        301254674498691116,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1190926793578469751,
+                        4569874070454135204,
+                        15349066761719847213,
+                        1143729593598143496,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4377698380788322951,
+                        626231241120536954,
+                        5782570196326664329,
+                        794203002404066761,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18203023968026598600,
+                        769509082182406304,
+                        14347905833288757784,
+                        849309248009120890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6219667569923491560,
+                        12993749535175636460,
+                        9317843769426981121,
+                        466063065647080758,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12360205003642410439,
+                        12233377474426101645,
+                        8749241487473360725,
+                        771733806369229100,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12909744147344161535,
+                        15270140525914393786,
+                        958053852923168614,
+                        1122903083941798107,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11512594517381251244,
+                        7788994558197263271,
+                        17789727628017887833,
+                        604964920168437514,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11365008776320664479,
+                        7674849678441687585,
+                        10947191902817323894,
+                        745598673291635479,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9298337976025138670,
+                        3504219772802793091,
+                        18423486933153538231,
+                        674863471944898182,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10037370553997808960,
+                        5643541321548796784,
+                        3954322339884102888,
+                        1116686823797890292,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9151115627925470056,
+                        1080688967380275155,
+                        15991630648226528878,
+                        1130423917661403285,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16294982486276945283,
+                        7049820508618491066,
+                        8145571059898393342,
+                        186002704339584638,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        755176559839955182,
+                        11156178401444222993,
+                        14456074258881310686,
+                        278477091367678088,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1257685548905109784,
+                        5141440235439936072,
+                        17862579642779035136,
+                        330137635933850138,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7830781886086349569,
+                        12605497826381107847,
+                        6483908192271317597,
+                        742032532267494998,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15889009530646425854,
+                        4873730462912310136,
+                        2661766248464352916,
+                        886629163939946863,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12291205541312705254,
+                        7759245016766434277,
+                        14282726477924298523,
+                        275206715444074183,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7015277851946876128,
+                        6540146872039720132,
+                        3413400018095950201,
+                        488630649619127768,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8080067703614789529,
+                        14335022802712765520,
+                        12181558540579879368,
+                        1181883134308686818,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3930610870091700987,
+                        11870595227272460342,
+                        5614691549969018824,
+                        1036838548873476989,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15116537084696928201,
+                        6562302783432438956,
+                        8446888808137423003,
+                        1165521566881319341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7622363474306732593,
+                        14018499983613194146,
+                        5504535021523794239,
+                        1156251508187455677,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18353812883604490408,
+                        7863175561039540930,
+                        16157508248448358210,
+                        295160799461001215,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9630152100811825815,
+                        18335618989479732132,
+                        8847214215895375728,
+                        938895491334142608,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13116080893996470839,
+                        13627909126954009780,
+                        15760064020863415616,
+                        884515223942721558,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6687780439253411478,
+                        6719196503388333019,
+                        88771745794551678,
+                        987619641692746386,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18227317062064988196,
+                        14045861136043637767,
+                        16184554390694852575,
+                        168316121892514264,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2567020624281662578,
+                        12408201210305429297,
+                        15172611508502121839,
+                        184308211509770511,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6504349685450813046,
+                        6151032549024957176,
+                        8446915662866336279,
+                        721911887099189465,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3337661731072526341,
+                        2993440831960528581,
+                        1509729075929391927,
+                        478329275350342603,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1009230922039257241,
+                        6930789170818330480,
+                        4189309447677808153,
+                        116099938331986078,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11734050699831527761,
+                        10637489645393719148,
+                        526453959140559372,
+                        879302010498683200,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4873204063755775221,
+                        9933480403362446589,
+                        9949453296663390065,
+                        246028972103247826,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1486515864865111240,
+                        8186666825731582288,
+                        17298579416284146352,
+                        75432580959795592,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        505624159752585300,
+                        400192717675446053,
+                        16830576854563029465,
+                        1083591845791403649,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15707645648143644435,
+                        15104101221834649015,
+                        2714910978190477603,
+                        1152442812583011873,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13680539759561187051,
+                        166623071797346207,
+                        14072328976002035503,
+                        1262049047131890020,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1997216355444588659,
+                        4362151030955817495,
+                        4119100627602964220,
+                        86616090171999332,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2511307420156057573,
+                        14546073810053310617,
+                        2740719298105876931,
+                        1306803605810669667,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9796594921607316066,
+                        9046940149281062534,
+                        3066668479453506525,
+                        629717731494457650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15245080183700001743,
+                        17772522498139932944,
+                        1303200707700973869,
+                        1321448515508953860,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15382852889694635458,
+                        13258286043643865801,
+                        9129565020842704924,
+                        647265428745294973,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        406339679439528630,
+                        358526789424126981,
+                        15360185281241795369,
+                        402771395127408535,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14729795014613647379,
+                        6659603767330364601,
+                        10382867237853880917,
+                        983014168589450240,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        130913533882362207,
+                        3464497722457480940,
+                        6174897712775413536,
+                        362284933576221116,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6802705157024058052,
+                        13442544871481896094,
+                        13780491571272760495,
+                        909644424511448796,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15361452699552249664,
+                        9640740743000109947,
+                        5568737378741199451,
+                        962844949024683508,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        459261181401017545,
+                        7171449908290213843,
+                        15871949118083492196,
+                        158962359341824787,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7906547241546612427,
+                        7911718846541029411,
+                        14951976648354808013,
+                        421942213347946833,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11183093321955941025,
+                        11064782095852757040,
+                        17028937525080502941,
+                        84626144143427215,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15224853022182992564,
+                        6262171785917223470,
+                        16177655818423871790,
+                        1014568265658824394,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        625719919361420446,
+                        8380808592474630812,
+                        3431067771421433694,
+                        687333698000967202,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16024186533956349632,
+                        6397405785318083633,
+                        15989828043790400414,
+                        850765743467676249,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16821206357298616469,
+                        1130392039725774709,
+                        5626389420189454642,
+                        1118339463429080277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8638502030638568937,
+                        14913248685358382503,
+                        2067471807256194154,
+                        1100154439569300281,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17564526714798056567,
+                        2679133728209391057,
+                        12485118550630518387,
+                        962529009983964522,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4671481911068696338,
+                        11211404871059653651,
+                        4714104003967750529,
+                        460522700989518506,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6601065644493764993,
+                        6108401876661130452,
+                        518511813649845646,
+                        611513774682652448,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2861830270848819795,
+                        16356361265808876600,
+                        12810879004275814176,
+                        1286972374050895235,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15194368129140163866,
+                        4422109454732341537,
+                        16494227980417936974,
+                        290982210272018089,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17007536869668539214,
+                        3870339684122566773,
+                        7418389053554657334,
+                        263511272051190454,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12410612082473707857,
+                        4040398757796296521,
+                        16094020059960359234,
+                        939075584469470040,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7418236128585187557,
+                        15691596850766474049,
+                        4839121991874976767,
+                        1173030423683112566,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5215564176166340124,
+                        2655381196051933641,
+                        6045374250672288630,
+                        176416756249057657,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13952195320089654608,
+                        13938861125107555897,
+                        13572960470464709581,
+                        247820705708880751,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7397318582807137181,
+                        18043760894393149278,
+                        14776480882092071507,
+                        1134492376276011511,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10024735062566598040,
+                        5074414902312726054,
+                        16541770110513564624,
+                        1319392307629355494,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17701692352398604469,
+                        12227868835058163041,
+                        6355645072062803626,
+                        46662470597422118,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17486353498011500258,
+                        14688387905134443671,
+                        86673003791736603,
+                        334193962485781999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2725938978570184818,
+                        12538279414757715369,
+                        4423739824876634382,
+                        990960136859773111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1658418413165666717,
+                        4481948955101880251,
+                        2380234418157645031,
+                        242453692938449648,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16462799862547279938,
+                        15860491361132810167,
+                        17855590817263886803,
+                        566253932393246465,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7795866114233701913,
+                        671818567137775288,
+                        8587656269292170703,
+                        1248608466500904131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12734974453986836356,
+                        13876952892523324606,
+                        8958492656376686082,
+                        1219220119207244562,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16939094048589357804,
+                        27828887622580244,
+                        17879324893769825916,
+                        1323982021311509582,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3829148264852223810,
+                        16344601064097516632,
+                        17405856957400469367,
+                        1015406940125315334,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2496078501178333445,
+                        3431033003985481851,
+                        18046940389757892591,
+                        78803126847324668,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10354574228107355459,
+                        2956204224517963071,
+                        4281810991966937657,
+                        773005109651151512,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11661373254033173032,
+                        7617994408757678472,
+                        3818523458153749994,
+                        1124211529831618075,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5343527255304572547,
+                        18057091451537453565,
+                        7219465431491887651,
+                        438777407253439449,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6944386246740202781,
+                        15457528402087694028,
+                        3684425466632772887,
+                        516255567451219667,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11265451120049686448,
+                        17733431402661201252,
+                        10181100791013355567,
+                        1222168459125423207,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16541130910067039592,
+                        8550248424255720611,
+                        5388592089365012797,
+                        401807388479450663,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15535862948076515909,
+                        14246335147027067446,
+                        3440553300570031637,
+                        1140798916603065405,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        181454369079184993,
+                        10259998444066142751,
+                        13378833995981646023,
+                        1233479771041134216,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16855179079358889281,
+                        15202871199402424660,
+                        10704979988227736970,
+                        1092640973189828568,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8641652447153871125,
+                        14767053670343766852,
+                        14349039701716536407,
+                        776309354634057048,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16709348381005515895,
+                        522436120289473263,
+                        2848448538858947951,
+                        930729981926185853,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14011758428706132863,
+                        13238226960477147744,
+                        13153722515261824172,
+                        645450845277186315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4814946540728558020,
+                        13498721617822816453,
+                        6123249997194763041,
+                        341203117201975482,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4764785085000274002,
+                        13213746307545533284,
+                        4710818056024779171,
+                        811448038874038121,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5830237069668886723,
+                        15246078622812969229,
+                        9220658192101045222,
+                        408275388400960945,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9134040424536926982,
+                        11508819347016605953,
+                        8328580295870717371,
+                        959614177290100723,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16286880784913001976,
+                        1824797716299321256,
+                        11049767109937668763,
+                        452808183746655701,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1806835097044955902,
+                        9357901081557371342,
+                        3238806099641496088,
+                        1314120223513707099,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13872955018782188047,
+                        17141452019439412466,
+                        8834096828444870858,
+                        270167394614144655,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14889394544994406934,
+                        14995096606848976379,
+                        17033971416442548991,
+                        736281424671216367,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6866984466208118346,
+                        14938739167728431959,
+                        4065292295250803245,
+                        1304135538479657980,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4781338345307874867,
+                        1071801237339867461,
+                        7582959293887525535,
+                        154022343681685860,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11343450134456109743,
+                        12138446454647516314,
+                        18248755285435265717,
+                        1202826287248419759,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5061364984886647558,
+                        16913875466690176181,
+                        541353232551118813,
+                        1093213718974842516,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1414006183558971659,
+                        1184943762192115283,
+                        9952425075542130668,
+                        277535172743840198,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2925426561557197193,
+                        3149002227384814996,
+                        8292543996164012253,
+                        377421699100693393,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4563065276842301031,
+                        12299326654538095428,
+                        1173783352867519045,
+                        660896701541059556,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17556343144894259172,
+                        15120967812140656475,
+                        13220306807671690049,
+                        515582209649030112,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17930502794455172994,
+                        12456573919922082698,
+                        5098691047476636389,
+                        1155239922908338744,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        715806743321705319,
+                        8595789391717250265,
+                        7886204696705561158,
+                        853966478543067433,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11065876043640872814,
+                        12711604906869650004,
+                        17386448605416025576,
+                        580288364316986753,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6213638082845260449,
+                        2146217676632809529,
+                        13388827710246608327,
+                        625014626569470556,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3543804354196038188,
+                        6513090773584810121,
+                        5947745618612045258,
+                        783637552587629458,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10178582550216573001,
+                        13216433819459327972,
+                        12536880456651522901,
+                        273425122393692042,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17740484416723395066,
+                        14002449190732619624,
+                        5196200919915012057,
+                        1072209538808574928,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14432551861977310170,
+                        6303405288421791767,
+                        10962705538772700272,
+                        125164950614679120,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17974915471234340629,
+                        9543882335835977273,
+                        18026962959457048037,
+                        859447104833739508,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17444478013164258628,
+                        7273153924847744184,
+                        5396109026766977713,
+                        1068826445810574364,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9548303526902181946,
+                        15499485550041624706,
+                        12166355514379983576,
+                        1124882228362424729,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2979470622631373385,
+                        15073919062463444322,
+                        554623808409994550,
+                        946452764475542335,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        984132395536701910,
+                        18433285285224050088,
+                        12270712682369892409,
+                        1084365930797075238,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7520934378517952418,
+                        13513057665114654410,
+                        16741686064340829582,
+                        412887969274321169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9949723241354625169,
+                        10713418444736513894,
+                        7982487733616369891,
+                        245024375773699484,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12032877172458649835,
+                        17069321760044153284,
+                        9655926395213048896,
+                        581454382578203421,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16711544098076610974,
+                        6177218907137966949,
+                        3414532654483400227,
+                        756610593452625055,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13706200968393957073,
+                        906151647055084144,
+                        5864490308258522930,
+                        232694794204621381,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9448382689974735840,
+                        17250025131065133211,
+                        14120992101448989991,
+                        1145565656769863596,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6096148983564906688,
+                        5813116070781438424,
+                        13450781310800242979,
+                        241924230903099083,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        285133544331105135,
+                        16037018433252623646,
+                        8018845554237859132,
+                        1237928289942665790,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10321246138114167834,
+                        2324365293843164583,
+                        12127237184526489667,
+                        999741269671475197,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17322056436147930534,
+                        8402475071245201608,
+                        1392950622756684053,
+                        716234910069651336,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8985026131927716767,
+                        17244817037312890880,
+                        9394390292964977546,
+                        354518252935520471,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17433559164936434923,
+                        7573189988292700213,
+                        8427576734073809090,
+                        794285092656879304,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7500811320781468319,
+                        4205904386655516181,
+                        4767161016651446083,
+                        927325947975307039,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        649118638772333401,
+                        14597423544581700044,
+                        13345552822332256224,
+                        901616216116464715,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13168530739223392019,
+                        14006712552237763163,
+                        12730168354620647702,
+                        706025546834856091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16279259286255584891,
+                        13193677874064319184,
+                        12181781625870580659,
+                        221683761293050761,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3961746958875235934,
+                        10529938434953939275,
+                        10890877525924564522,
+                        751018078769119443,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        711495979245472474,
+                        13184975240898626902,
+                        9313924287610665431,
+                        696646744401976384,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2214021228271359901,
+                        10586405736539241655,
+                        5019606523131847504,
+                        771281283461154521,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14129509407451485373,
+                        10199651551092787707,
+                        14285926751346896009,
+                        187885689162926472,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7508711681572683441,
+                        2631086703102342514,
+                        504358639681984076,
+                        752523651759067959,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17624887039322113123,
+                        16679801216104191425,
+                        11778297425378443243,
+                        50481177351901627,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12681996423169027490,
+                        11653976282841864748,
+                        16226567289109259864,
+                        824728196819956368,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11364755881652296704,
+                        16998909948311421765,
+                        3063737385413636929,
+                        473886649455589953,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2598032035736567817,
+                        11589377226800348974,
+                        11087079484698060768,
+                        1279409324148152829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4655189106511832363,
+                        7762281719950992109,
+                        16134025833514183038,
+                        680621507384430367,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11742803292200462821,
+                        2139530817450795563,
+                        12434934110107355570,
+                        828025657975557101,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3969767813443426523,
+                        5892335294289474920,
+                        7106964973561137035,
+                        244111293279158316,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10493546777163376150,
+                        5708398121513453961,
+                        15680153387604976112,
+                        729600591898217860,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        363292574663496377,
+                        1136683687494712442,
+                        4247997807193505373,
+                        49605521356682181,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7035596225685999378,
+                        17049700952043975634,
+                        17205008328221272477,
+                        272960924441472243,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11582039627210957045,
+                        15611501201393673977,
+                        16967993787488404944,
+                        145624909351882476,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4731201452239113764,
+                        1340232661153679533,
+                        14593644392177029681,
+                        517368075449696196,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13066758996452793726,
+                        16214974985435938938,
+                        6399708084409283232,
+                        5302883328224284,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10422562553495691137,
+                        14914596718697189442,
+                        17984920572338944639,
+                        217009020725352756,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        453488069723541035,
+                        11734589955945386478,
+                        18212341353181325576,
+                        639791378183400052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14220878952248797403,
+                        16716764649605884107,
+                        701336993516118458,
+                        666149607040173520,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9127496339707576465,
+                        14818479461168940596,
+                        16230404419900423738,
+                        988927262695225441,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2726863094754858857,
+                        1712560942941593446,
+                        4095753574461947108,
+                        1078370094611479125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2438986215369277357,
+                        13415817889172955040,
+                        17560306157769364455,
+                        1326221518005363136,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15243183838274999461,
+                        8415910600421224481,
+                        18279926690772407161,
+                        161461781513411889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2640300567525272264,
+                        7606218648024755159,
+                        10476469434839234830,
+                        442783509641879630,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16968806766873878350,
+                        4954538759260676115,
+                        10621287521875719506,
+                        807416455615221393,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16697901909834790030,
+                        5657334563427422822,
+                        15242423588748866450,
+                        1017855868292015258,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1341805508574314373,
+                        16546012774902582064,
+                        14556727592533986923,
+                        1127184660204153369,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16976307985566208169,
+                        8253971883774004211,
+                        8975096235511154839,
+                        1247302563374825131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10438454914622896608,
+                        8018469155043729676,
+                        7229231061274691836,
+                        772505206002333169,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6630143742479235717,
+                        15925223753853849195,
+                        4125221402835191611,
+                        513783281050037647,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14802053557818742483,
+                        8483139925455275649,
+                        15321292097397708984,
+                        425836885272652246,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14188820253857764638,
+                        11840083288536205731,
+                        8986228883780290181,
+                        290774962715844561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15197033182479132985,
+                        8933181639686877654,
+                        6542343788997281341,
+                        112116414418892967,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7462278082807681858,
+                        6816460752148449840,
+                        5580283054881472020,
+                        1329112971120268296,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11851463227874941373,
+                        14558197894046887066,
+                        6374909319418492835,
+                        1232323831476101874,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3496296601029433686,
+                        3041832587258927107,
+                        4369015451940600643,
+                        155375148634027125,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11167960750533876264,
+                        3447353511834393446,
+                        7014316093406378023,
+                        876117280172166926,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16552764800911333126,
+                        16337432273125439187,
+                        4970463646918810987,
+                        911667036434400993,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9851984615316747293,
+                        7493747882796099274,
+                        2196698337672476499,
+                        949969053046165850,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14183058206261530360,
+                        17581792687570514391,
+                        10776876415340847280,
+                        643881116420121988,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12642857463908914805,
+                        14214544323218714835,
+                        7139873927931602547,
+                        488946649207260524,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11395698848120549140,
+                        9979102838888863907,
+                        6989994138717605747,
+                        752925066854851575,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4753539900839094430,
+                        9929966902606101587,
+                        12351623486369533704,
+                        904619552107100386,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3239169339680459609,
+                        8833819370222527329,
+                        10496265525216960833,
+                        847887188558803199,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17087175081455869981,
+                        10197256131696750898,
+                        6382998290012009782,
+                        191978512174848518,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12588311503058727354,
+                        725124800503475487,
+                        16104814397664337776,
+                        225177289897936322,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1230688817300654532,
+                        1829498158079983198,
+                        15711227052208484926,
+                        671225624254469407,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14040512966177529559,
+                        13479457389804070889,
+                        4612087170101195295,
+                        1057941163139172833,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18084382810131523847,
+                        17938604544471724468,
+                        6941928965884625611,
+                        621697813275870288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7079486720991556996,
+                        17278771714541741456,
+                        9245816176486867690,
+                        836272991489170228,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8409384128484952804,
+                        17535365547275168352,
+                        14626921959842422273,
+                        1001770024426950946,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4626388865911476405,
+                        17321146697877939322,
+                        15969386274425941721,
+                        1167675212022430527,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13505786182126905299,
+                        3507152780028323409,
+                        10129367021732957127,
+                        143501833614023187,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11793928574893844815,
+                        7489084691725642480,
+                        9993812161511081482,
+                        920855519297390398,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2693904132292960069,
+                        14079752275573874115,
+                        7820689196771346729,
+                        694803485442770053,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15424547745322929306,
+                        17791623364891025112,
+                        8803417879351917063,
+                        1495917113431711,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3154207211456197714,
+                        7510800239026621111,
+                        17886807671714940411,
+                        1137292724317836202,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2636651232245666398,
+                        350595942779115784,
+                        15242888794664919848,
+                        1106975995087466226,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12790127358420561843,
+                        2372890771876836537,
+                        8090640480311789084,
+                        1251271038540525977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15734748035166676000,
+                        4294679848959764323,
+                        7432740343166421297,
+                        318937840290747354,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14503986596598521180,
+                        10514185913712847133,
+                        6237428202019960946,
+                        1141312351413165175,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1342422522899863126,
+                        3026024647482576100,
+                        4640160960346769939,
+                        1171281326229137168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11313896811067983978,
+                        9837505949627269730,
+                        3156729632912045142,
+                        1211845001952571921,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5433600193520881795,
+                        10150165017581013996,
+                        10260241136875297833,
+                        440484734220006931,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7378886025178853113,
+                        9033578957046337616,
+                        18203504094207890154,
+                        397726132154161585,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2777884169954153160,
+                        1698568243990322800,
+                        5543372703984528373,
+                        462191437628265361,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12362923658858573345,
+                        1843229303661198560,
+                        691309134570153048,
+                        894135359515208541,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8442199919006383906,
+                        15004588249497661533,
+                        13935112266299114891,
+                        6996326098247630,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2869311342212831262,
+                        6691382531546576948,
+                        9483740139924829382,
+                        1282125318263986556,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13699559999490654029,
+                        6514225887431393856,
+                        14159723524098125458,
+                        1299377113447437022,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7729890106087253624,
+                        8719050316854077258,
+                        4119768837654533528,
+                        100250163385213892,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7687962074736394902,
+                        3647188269471265502,
+                        3028430552713145776,
+                        857986993659392753,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        390343389569144165,
+                        1415053568343047361,
+                        16588877265677118635,
+                        271101468926404645,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4722744132494168886,
+                        4093442917419729954,
+                        2158366265456962227,
+                        1113236197702400294,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12913842462536653139,
+                        6783874319358920812,
+                        29531000276238774,
+                        420791130201703650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17019647369061239757,
+                        16587827491039156697,
+                        16732185245628304582,
+                        147685527622451205,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14511232703145193456,
+                        12867076578150783318,
+                        5451120181808487970,
+                        161018323287962642,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3473694293913547944,
+                        6104011690635400516,
+                        11572640886309187162,
+                        114066022659071381,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16441493185166081473,
+                        17539054849039036378,
+                        7019867174958234820,
+                        196305568224625605,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1224888236520826049,
+                        8395790234177118714,
+                        6890177007823437453,
+                        158254186320474113,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4431991137551985727,
+                        16319633214132158424,
+                        18089336831343747240,
+                        1203370832238810238,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3761907457691696816,
+                        111284038203950354,
+                        14599734349660813422,
+                        91019717248496884,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13944872196204042631,
+                        10262177403439376863,
+                        16631362580818683697,
+                        850856020738001091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4251507373589672264,
+                        4409938276513512144,
+                        2488356773482684153,
+                        366489460157182137,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10795794795583114686,
+                        149021686175864995,
+                        9912016731132676259,
+                        1192018108828303558,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13983269131464638381,
+                        15076637463907375405,
+                        10888811628369864255,
+                        14403268888100143,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11271155259310820683,
+                        8222407038128059076,
+                        8572600665724660644,
+                        882709823294927547,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3239727189120777155,
+                        3487538005531569816,
+                        11814537687460454375,
+                        796490698002577460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7951292924908345261,
+                        12033935059826661734,
+                        3163486345934548216,
+                        798171174178221431,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13696573206980486618,
+                        14302625264891000527,
+                        13675071266833064567,
+                        692503242166031183,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15037084089071595381,
+                        15397458498258582862,
+                        17373482867532424702,
+                        593336997116306058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6759225741772012626,
+                        13055254831664164065,
+                        2711614388332909835,
+                        1270298255379526716,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14142443143472528850,
+                        3190533898930162089,
+                        4489186008152564993,
+                        1120879097137830879,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2312973519095301378,
+                        1918216265838740871,
+                        875310494419218244,
+                        1204188452521432230,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4973315711481592219,
+                        545101191302558652,
+                        81804908715283471,
+                        853103328694225459,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18253351252203311458,
+                        18252852148909720791,
+                        2416955441516703927,
+                        64047212696682152,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16783431761733058185,
+                        2052627833946807440,
+                        12495955703943405026,
+                        535566689467778525,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16320340244075449867,
+                        1552756087934462472,
+                        6014247911367836000,
+                        57835388348577760,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3853936482683230834,
+                        17521625534984749514,
+                        2479492070261338854,
+                        921352646835507719,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        328157245107618927,
+                        7353555033220915515,
+                        14180261462147223443,
+                        129281111999245985,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9751673264158252451,
+                        11220260103489229440,
+                        7850243293178650987,
+                        1050830003504220371,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12752868110910607966,
+                        13720399190259631962,
+                        15570140475160570942,
+                        365267272419782267,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17960159030295515925,
+                        12077557966341264716,
+                        3126256000493893274,
+                        31153885247055555,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2447496266216090530,
+                        13463566135485097076,
+                        7453036824604523750,
+                        101668906374165244,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13683052604054767593,
+                        12489762534241783697,
+                        6996608919991269700,
+                        259061292491918915,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14902242518989057463,
+                        18135073000951543340,
+                        13043976238414716206,
+                        1039177170993494377,
+                    ]),
+                ]),
+                Matrix::<1, 7, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7146215532431858431,
+                        17717268958696430540,
+                        2850542275365663470,
+                        1118752894616580710,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3227905073486940566,
+                        3663165089067267856,
+                        2857309986350002189,
+                        526604225454855282,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8647448897707262576,
+                        2330015868939821427,
+                        13460886972811506849,
+                        857620386469630380,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5519410700183915003,
+                        10547949236728633115,
+                        7135755498133091337,
+                        290342902395329379,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10069056859370964838,
+                        11131929082873225483,
+                        5891640315747228688,
+                        523051429584243581,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6005952430775942111,
+                        18291015892997486621,
+                        7441315733089762641,
+                        970050792889632155,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14950018215226214136,
+                        2168283848304570895,
+                        5728905980060737167,
+                        1342404826533806689,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5469465372454405746,
+                        11693688927749785195,
+                        9284229770381589387,
+                        263916402201309764,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8025142443496737775,
+                        1611073224873590596,
+                        1595200312703520851,
+                        268362793599128279,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2646197433325367530,
+                        3327161674116363870,
+                        17523969835882673765,
+                        1224872920276084181,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3531022552160069605,
+                        1044916798369741293,
+                        13484540382795405112,
+                        975392012720575741,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3532672362950129848,
+                        13232567668052949956,
+                        3949082925084889589,
+                        1169300250855119928,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17405909644706202533,
+                        9068195655028130334,
+                        4993737853698066182,
+                        760630245260613434,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10760096219897954109,
+                        15819961273994906871,
+                        12000866060961275644,
+                        494051747708222359,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6362811837083607038,
+                        10112120774028974554,
+                        18223478424715278594,
+                        334017453759956923,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9425287269899366801,
+                        11550170751949156528,
+                        13535057945185910331,
+                        481928711879823346,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17472259370242997056,
+                        5455690506444253075,
+                        8901482994501262481,
+                        216965978934341710,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6461374808168440859,
+                        2522061882145442234,
+                        15960549034903032234,
+                        172640120507478914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15948529219279584260,
+                        15982605346874278694,
+                        6269535640895470052,
+                        598935737770408401,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6387843144535167444,
+                        3817387412721348785,
+                        8453759171668596794,
+                        249153266075439378,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12733356128673768262,
+                        1719659461140646111,
+                        6773786044742649854,
+                        1187765335723023490,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13381096107094038201,
+                        17813208526318089114,
+                        18335536409132991824,
+                        339697501389957039,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17742161265183589279,
+                        16788867255988109560,
+                        14412857631192854758,
+                        241525962952821929,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3132656462108501803,
+                        7337059831094147936,
+                        10405916960575845901,
+                        228104793330536468,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4603265731852134279,
+                        5399214122633296191,
+                        8344043762552329652,
+                        985455486033881462,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14183899592653698860,
+                        2177269342994828180,
+                        10884678024220653483,
+                        217447665355687992,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11323936221359713158,
+                        3341391736943984982,
+                        17508560691141182274,
+                        13239895022264913,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13840945524485292215,
+                        9974520753931283863,
+                        4195523720400973895,
+                        762227204403897594,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4114179516470424298,
+                        6425976053490693203,
+                        12882959905974456947,
+                        110103675307258331,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15116210321604905525,
+                        4118549106744296512,
+                        3828886987178664227,
+                        25697701772294022,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17140438063675080897,
+                        14878172760305139023,
+                        16025135762152951507,
+                        855206538655380046,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10902652921411376407,
+                        298732053043081440,
+                        3474200546567636561,
+                        73249755607734230,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10553805817393106605,
+                        11201074828085121396,
+                        245273431044608348,
+                        1118444153373328294,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10914295885365195477,
+                        9781502968861552189,
+                        3016024785085649390,
+                        1070566622248381309,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17207023773498274582,
+                        16270409020606599297,
+                        15615674408609362833,
+                        496415543408113636,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12908589501999937487,
+                        8065526495594728145,
+                        14801981742319743709,
+                        275346991162297736,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17950935244956210974,
+                        2150016307817314195,
+                        16459867286579284659,
+                        1042519399748946084,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17900103050404502082,
+                        14452186078281849672,
+                        6480606341801761206,
+                        622530813027865777,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6659043465579778142,
+                        5257478652828025077,
+                        4476708827204863480,
+                        16044674601944326,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12982211781831995222,
+                        12049124325752315255,
+                        8705698258631367529,
+                        1297634030024262944,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6491213021781179190,
+                        6621864740150572837,
+                        16900492784527502379,
+                        1222186981051134852,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10406390218848689011,
+                        15713870170786523261,
+                        10757974052401463167,
+                        1094311561986616580,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7587047764889575280,
+                        11453570134345482138,
+                        6685160859155170229,
+                        1173043895898907255,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16754290437895255736,
+                        9337309604462582506,
+                        12206937090776739366,
+                        420058562194519310,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6762379518159147694,
+                        8708770658919355547,
+                        10850018038610162125,
+                        1008085848049838570,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13364112208667503342,
+                        15694121621742726571,
+                        10880035211673377855,
+                        373642360429768529,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2270999049018193688,
+                        1120874468185376800,
+                        411758025544146420,
+                        1286339318101647584,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6487825500972649335,
+                        16454995602857338429,
+                        5467561124775466727,
+                        878704090415683008,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1065155450424764553,
+                        12374494253224172277,
+                        8660000041795033471,
+                        1111585064452242609,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13207693125029693018,
+                        4845337225517968099,
+                        6777888238547968314,
+                        1213784418907181141,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8840475980926169942,
+                        2439983533649319439,
+                        10722568534952104178,
+                        237040204857642763,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12845192406743600306,
+                        2989732167685602396,
+                        9737375858921653666,
+                        1230697363927166390,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17347381872588777362,
+                        13861116144606039805,
+                        13092379011701123650,
+                        764219716687899335,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17560394342108123527,
+                        2417597080550067173,
+                        4892519055479234860,
+                        41147728874013821,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16525591378009204013,
+                        15575464991459515509,
+                        8422968616365174468,
+                        411199147172891757,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2381387149440902461,
+                        5412036122050682991,
+                        13528312040883572709,
+                        906698787086289152,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3906793572005745013,
+                        18158346260651031144,
+                        13885155522309058406,
+                        81682927312739464,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11556601788249882111,
+                        16294603434168644341,
+                        3233905800589936432,
+                        205935519984731,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15060956636956141748,
+                        14545891040349678052,
+                        11359264221189720465,
+                        356824974507480378,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13651037643855144634,
+                        9057349283398801990,
+                        11128939939991098829,
+                        339192257850626331,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13297032679166497156,
+                        9615759066125111838,
+                        17802636590285232697,
+                        561508163130847528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8397658199708546603,
+                        7714753189153428100,
+                        7790178778963243461,
+                        185139104215370464,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2180790926999539040,
+                        13412426570621087300,
+                        11981287146222961423,
+                        639288905678439923,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14052035671409641250,
+                        13059764049401289819,
+                        11971569074865081653,
+                        1150074447408905206,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9430708294750522894,
+                        10157980309621419039,
+                        15263989057722108309,
+                        986177323832836397,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16901845294627087710,
+                        3489277370587647922,
+                        16697435307791475526,
+                        77304496269930872,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11082416581543669906,
+                        10393162674600623371,
+                        3285406343096341615,
+                        1301030845466123760,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18004954247480805141,
+                        5840089398123238351,
+                        10933632362911021710,
+                        691842568234993272,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17084441454288473640,
+                        2235549442526480819,
+                        4281084601663253516,
+                        854500520628530615,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15995978179451110635,
+                        5254996480963543781,
+                        12228168137501357375,
+                        768298814850744789,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14897923782972976882,
+                        14769556083418318998,
+                        1736582590048574781,
+                        834637190815349022,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15951362473351174286,
+                        7550580364442366310,
+                        1142582367377712360,
+                        493901798774432186,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6843713892592087790,
+                        17187186375181169829,
+                        2346758743517403254,
+                        440860120126405528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16233685001159101924,
+                        3591097020136681981,
+                        3308092724991209243,
+                        753631765436882275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12817371395153180792,
+                        11781447572225130349,
+                        6186298687319142696,
+                        3684689165947228,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        373243522249877820,
+                        14362378102133706173,
+                        11334127490376039043,
+                        1029386563565171794,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3320051586177558481,
+                        3693313581776176503,
+                        4243258718797155237,
+                        280296683627599109,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15777714567664176983,
+                        13315644392893971158,
+                        17663595314793470728,
+                        277994346352588461,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10698748229571229513,
+                        4993514528007275782,
+                        9851470184989070944,
+                        486473547501834583,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15731541659238915573,
+                        10580160097474154328,
+                        14072730437229004460,
+                        541485168141790138,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3621838382408245781,
+                        5140837518507686846,
+                        692462686779552266,
+                        1071238232671920069,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16903035807706989718,
+                        16402342976729397848,
+                        6638418596135594138,
+                        766006849863633370,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3391835462656069558,
+                        8478240763735883610,
+                        6583767175704093831,
+                        605702159954668519,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13153440956937332187,
+                        8778062398439311613,
+                        14496687227146002795,
+                        1184865635693926839,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5492025723621450587,
+                        4802310335533070821,
+                        5103778166610184416,
+                        1237824309223979752,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13230781860208750726,
+                        13444089818089484139,
+                        17921412814723519524,
+                        1222680843018701540,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6469994574006187252,
+                        9454411230540098121,
+                        347262197325431191,
+                        486705196263913773,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3947674970632735265,
+                        2916300977963946491,
+                        6881904307166023017,
+                        457112286806257099,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8734203063029639947,
+                        9235030054635977911,
+                        17117573327238268274,
+                        1336943205846791314,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14915061407614767729,
+                        5343187615398945512,
+                        5552119928369165945,
+                        230612833250200287,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15343760251811669074,
+                        11849716817931855852,
+                        12235003752862513196,
+                        1092174591577707855,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16055670419025007879,
+                        10167255563356161134,
+                        5873677174484181331,
+                        1119183194058634412,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6545073505115712765,
+                        12208880957985144024,
+                        2413898051352165752,
+                        200177087583496979,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10675772825457541870,
+                        13221200307080776964,
+                        8360161479127911800,
+                        166239764386669819,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2006419824145230446,
+                        1298290087846863922,
+                        11954724771700273308,
+                        1258575857924774921,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5182647705377916601,
+                        12490693483304324120,
+                        16433633239279713593,
+                        95750642123415707,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        95333990082317168,
+                        17157473840728451869,
+                        10012571168547117601,
+                        89162384671094023,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4909644498074356100,
+                        4736187679400324044,
+                        5882012018493447769,
+                        1092049061252631253,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7311109856180655396,
+                        11772066066061778775,
+                        4758323204662324777,
+                        424401521788398695,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10327551942056000767,
+                        7896891757351259654,
+                        5415141459879741043,
+                        970729526627356745,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16598021919990022911,
+                        4748198554306289116,
+                        17393884082489388120,
+                        1042289367721564407,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6071815438135838764,
+                        6957160250256416369,
+                        5727172448350823478,
+                        363080876294209892,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9289142807381535883,
+                        17159982138314567248,
+                        17888157697874972247,
+                        525936327365438813,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13845673834923418042,
+                        3021171551531346231,
+                        6196055431827427575,
+                        879039969582851499,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5868202154697212122,
+                        13487005442942356371,
+                        16163257120298149093,
+                        656445568990681140,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6021369790491535593,
+                        2415914828808962567,
+                        8804789562076458409,
+                        141257607088107152,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8888538666372726960,
+                        417693810472292690,
+                        6625260792856360214,
+                        48639855527887045,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6477686476345016713,
+                        5099365493188187874,
+                        9710843539546247353,
+                        356644031678310872,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        514050899336931730,
+                        1305432265166896997,
+                        3022676376566559511,
+                        992533360610514607,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3586775418789355531,
+                        9516764397514990497,
+                        13077440505677103078,
+                        1193605133897764836,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11915336245727353796,
+                        3787026284924392313,
+                        9686187959466848805,
+                        331336880299973516,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13079652964588155522,
+                        3686330961737298646,
+                        12410090506639101095,
+                        1254304946836077906,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14841602521644800285,
+                        9707581858559806753,
+                        8194272850889842673,
+                        1175777861699186223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8340457880130547197,
+                        17733479127111148650,
+                        9935013927746385786,
+                        727641041410784864,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15762625424586722564,
+                        3435382265052399320,
+                        8159926067386473340,
+                        197998409655179296,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8605915918798970675,
+                        13692084072552856212,
+                        4328810091072359910,
+                        868588572376767645,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1361081942051675201,
+                        15249716130118278159,
+                        2121941051566676057,
+                        559576702023081922,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10752810451997705936,
+                        2862464874834320603,
+                        10309748098106940300,
+                        1304236557076849780,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2835871431608154484,
+                        1749514314752450621,
+                        2725234542965358215,
+                        1201694229063035252,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1073940549267563537,
+                        6847045449574399581,
+                        17756819714770896582,
+                        1117463338790312145,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12704094153865092665,
+                        8539050408973245584,
+                        17734788789994913591,
+                        929238621728331350,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10082772236499648827,
+                        15108304350645714070,
+                        3086595294879967436,
+                        1200387991311725607,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5047622605931054175,
+                        7557906648527048263,
+                        9624095609160422424,
+                        45506591031030909,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6965550813182250949,
+                        565066047981282920,
+                        13462964114732145360,
+                        980356066902323608,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15558299170349086309,
+                        1286346204199469533,
+                        3574858446200038472,
+                        851991524979889708,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7325586067383897002,
+                        10233684635542340023,
+                        17557031856919477181,
+                        132029230490414274,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7217923862445368663,
+                        3794612520606418012,
+                        8670181285195573375,
+                        240971390392704685,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        537441915444801171,
+                        3014981172602304732,
+                        1479722163959615169,
+                        155749091741220885,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13062793399299583557,
+                        1159027774347651884,
+                        2336679478816355142,
+                        270200510125259051,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3831694067573543757,
+                        10964370664526245902,
+                        4087998121879874921,
+                        905965084930019006,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13194259065052094927,
+                        388722439697642959,
+                        178624285496351497,
+                        578824842066706531,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17948259725763227950,
+                        9014484167981796209,
+                        6107081279184176814,
+                        69534426400404264,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7978550262967360739,
+                        7491565367832202367,
+                        4365729291374404994,
+                        1335365781615388178,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10669459440663038789,
+                        6552676677342008487,
+                        13316372054996453680,
+                        550925049999738916,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4419333495078684322,
+                        6927538080001000341,
+                        2225108594758156053,
+                        1079053614093719540,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1972675078636948291,
+                        12089663092664065043,
+                        17766574022138625521,
+                        121432615644490431,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1053520141057629369,
+                        10770912132989963882,
+                        1148187868111152786,
+                        707938827427743885,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3260322753636284964,
+                        10729835216415125135,
+                        716444025392599556,
+                        690504199610876560,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6934370135811844333,
+                        6301658833825444793,
+                        4848235658034124485,
+                        472091301484588447,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9777643377073268806,
+                        791842489813847254,
+                        12448150674843197239,
+                        458910758561278370,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15803810666230694733,
+                        17547487450377911929,
+                        11906139405746854533,
+                        825285431509255503,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16600353337473024459,
+                        1886864376737549006,
+                        616311009164692973,
+                        639902607392049032,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1680268521087434801,
+                        1091097160854707765,
+                        18087803601592847532,
+                        572438372434779394,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13425154509660903739,
+                        7155042440442144058,
+                        5484293510771639701,
+                        440694050533320954,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18374411341880395201,
+                        14499066397809595914,
+                        13606711309161552316,
+                        988627169135416549,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2227485226860398923,
+                        6037771840516072237,
+                        10092335873046301438,
+                        263890669231622026,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16396442279804673220,
+                        11758563075897600330,
+                        14728620288909029404,
+                        1009629620490761817,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17746049445618651584,
+                        15474250106095074477,
+                        6808865525843107141,
+                        252069263355480366,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10094119795803932244,
+                        10978354614679659751,
+                        7451382830965148493,
+                        1131617137883762978,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16463020271793267367,
+                        16484453755363327531,
+                        16299428051085732734,
+                        1215317962997421603,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        222141886324808295,
+                        13928396678279854231,
+                        10143359980064533818,
+                        815662865945589973,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12231656940181472813,
+                        6572412520702222123,
+                        12613052303172694748,
+                        266113525618574574,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        988110300489242272,
+                        2246797931487708434,
+                        10572926100714461647,
+                        903118853801541805,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        530795899436597525,
+                        8521163943052335338,
+                        8151289800410686217,
+                        36252124276088895,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15950738796712300438,
+                        5211784799364900458,
+                        10872161777257884476,
+                        622500837165264302,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1796299668914971469,
+                        10069069742410689124,
+                        17398930418864211639,
+                        571074796095573997,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17415629526903541572,
+                        60522099572337001,
+                        7357276421993594658,
+                        67139681069623750,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3150476614427182634,
+                        11400695529363767640,
+                        9974085938634932190,
+                        201799321821634924,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17211147489720667761,
+                        15454125189814636772,
+                        1785683860014593630,
+                        1234545901167080662,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12917754021603623789,
+                        10145571812631521550,
+                        5237021007548635344,
+                        113519696768852825,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15503258828146475946,
+                        914788767927389955,
+                        8850539145480636682,
+                        452504293017031100,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3745554364046936255,
+                        4597306622888789154,
+                        5979651250338667136,
+                        1238385628454513051,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3776630884323931547,
+                        3549782146440747750,
+                        5508785071859620867,
+                        701959603932551327,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15669417982403128442,
+                        4987063011318496848,
+                        2675129774208819610,
+                        752856218864954922,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12384169795306075140,
+                        13266521557806758758,
+                        8976316392650036414,
+                        779369456063978984,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7882432423342926513,
+                        17205292058596297059,
+                        11479846445566224721,
+                        1260089851413577942,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14020835325904272518,
+                        10352766579517264043,
+                        15417820050597494655,
+                        621361945178146722,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17051687952810193326,
+                        6871472877919148363,
+                        3489400199188897362,
+                        687766677359196782,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17382705588788402853,
+                        4598916697035859633,
+                        4518832565581736311,
+                        653080619696360531,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2250500707327888710,
+                        8472537755265510597,
+                        8234912680399854173,
+                        435433220178846885,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11580178681277497490,
+                        5498607256285409706,
+                        4135785859002967214,
+                        1124489661838811902,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17779714097123614458,
+                        8699423459123996923,
+                        15631484148488778752,
+                        909516986337557641,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18163751795979980990,
+                        11867922209368440763,
+                        10515761459427034219,
+                        1332994349752029313,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6467500727988715108,
+                        9306282235622577642,
+                        13541102366651570877,
+                        416775728642319774,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10383099790322168017,
+                        17058343682036470720,
+                        16556281441888606360,
+                        1108176927190266030,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8622953880956768188,
+                        7398970229585501839,
+                        3084527402086859984,
+                        1314489003639612227,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2647974631426734642,
+                        3924418247812102940,
+                        4184932287519868312,
+                        1194114797716949792,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14598747211696490139,
+                        10879316167117011549,
+                        9287974170985802960,
+                        731429090606059611,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9444556880219125709,
+                        5586056795682328427,
+                        7367116120983861589,
+                        588219877692556070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10966009764315859760,
+                        10742608081271307349,
+                        3709772539537310986,
+                        497532886403996414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15416260817024711436,
+                        431667905910500746,
+                        1387964135713748516,
+                        918060216721194080,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11657403806820742348,
+                        10510065601598889238,
+                        8349835292055724063,
+                        502241567933397208,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5125027997144123095,
+                        7303165910753964935,
+                        10691796975927186405,
+                        1054799562052499765,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3677058682136778866,
+                        2112392306281972958,
+                        6200265695478657913,
+                        720439016775781556,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8272146025997512256,
+                        10341698166702554611,
+                        15851101317909170235,
+                        452521866559100336,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9968119379746477391,
+                        13931547738079661500,
+                        12809160964499846690,
+                        770440521239403115,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11434415940462413800,
+                        16684667769948280834,
+                        17418898698438354203,
+                        1004769570334088081,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3258653721799542419,
+                        3871032496675885535,
+                        15086212796812657551,
+                        548348409520277973,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9315341100800814485,
+                        16973311177794380607,
+                        9399876472392037546,
+                        371744665007363788,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13784204593688045203,
+                        987916117600213001,
+                        10766183396659311558,
+                        616603627600417345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16045652418239566399,
+                        8490486261587787544,
+                        17701136459350400996,
+                        200715104605533295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16284971980816740279,
+                        8206294557799882679,
+                        2790028614836001157,
+                        1230396046687758171,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9870167375285365220,
+                        7246310481239931900,
+                        17367559916410875637,
+                        828013539220163509,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16072962173730053757,
+                        4193938980038706222,
+                        11164976873168367038,
+                        230857852443905890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7523323272036182261,
+                        7860098358273104019,
+                        14378442365280097332,
+                        1330897076351403427,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5166916295217303780,
+                        12258429519770930476,
+                        7573997247042435361,
+                        682178487313104306,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2043764091903620445,
+                        4485903403091863087,
+                        7882707843266586726,
+                        32588602135411527,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15445407966754968040,
+                        18357001192960460653,
+                        4704086230082948769,
+                        786939246434391312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16117366278228333427,
+                        18271129267524238221,
+                        10279609063883049289,
+                        1039698878929789014,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7187518414442758799,
+                        1779717612181090682,
+                        11702580549115117112,
+                        938810897647106356,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17722222577775244941,
+                        9996465098177475040,
+                        6523413930963943566,
+                        594985722442752339,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14942503613037167587,
+                        18043065733746265056,
+                        18350946329946766564,
+                        456482704506596726,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        413913659191909137,
+                        6804960474770818549,
+                        11211569852612831192,
+                        29172099643213194,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12244020429757927595,
+                        7878431481226438368,
+                        15736494002689538032,
+                        952552586365680365,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6811639335636941408,
+                        13087253934833450042,
+                        14243138544038514009,
+                        802500736974958878,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5119841013779673124,
+                        924763452388520667,
+                        13728889174508658116,
+                        1253763325687067827,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15043507624384025219,
+                        14829641103772380163,
+                        3009461704678867396,
+                        937642236989310683,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11671642017952121222,
+                        16541053200478701290,
+                        1980039776401781944,
+                        588963913112990120,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10584858696775764162,
+                        11016739814752144940,
+                        16324314276275577748,
+                        654922621317102495,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3244897877015736117,
+                        11237789704802235882,
+                        15022173147815144257,
+                        40109243059779922,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13421818826894964525,
+                        6843121314819036302,
+                        177656756133214759,
+                        1219229288475674852,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6718699512784917601,
+                        586674041776306822,
+                        18334677892276409595,
+                        789222582574373919,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2284827956975526833,
+                        6582209151387402444,
+                        16582912253436322211,
+                        341803586231976372,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7256396544134998118,
+                        8214951682602116321,
+                        3245431556959173874,
+                        1245025275513704302,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15432965983836887543,
+                        14720892157109837025,
+                        15495565877696769481,
+                        28040874075986580,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2845048156588876954,
+                        8511864041625766030,
+                        12227720668782503297,
+                        751125685338714507,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16400929974316258476,
+                        14955948068318960184,
+                        10179371694614714547,
+                        1203413977422071153,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6945749691605470013,
+                        11814685140591736549,
+                        9661986044853389934,
+                        549110123010799116,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10314852142802128718,
+                        16931011931436831749,
+                        6272554436628472312,
+                        613946066674111963,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7885885393747543748,
+                        6205273416854026781,
+                        3045614234898978712,
+                        325443134576651832,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16340716059032367665,
+                        13900104810468791197,
+                        13840116352417324181,
+                        807825755702914983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11307065830129329386,
+                        17314979792657924564,
+                        1031027443933101440,
+                        670942943796932032,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16427383528817659047,
+                        269318770779983782,
+                        12646967434416881049,
+                        792232402464509209,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5305513183378836911,
+                        3912518176481377699,
+                        15813014949092997837,
+                        717786010393961950,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18397671474353577170,
+                        6538128035681545084,
+                        11539580803797495006,
+                        1334525365964874487,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16868316401430670648,
+                        716974312679076264,
+                        14083729700466879252,
+                        1187128554911325043,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2876259300169286740,
+                        13109188437009823755,
+                        4017915685590401807,
+                        622911668442299596,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11880880200720987277,
+                        17119531408323851982,
+                        8839283008378336460,
+                        67524139085251589,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14639691296413871729,
+                        11172781529958344184,
+                        1363556540791006543,
+                        188991557253721914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15937639780661327076,
+                        18106660371837518401,
+                        1478539873920589372,
+                        577013079573416297,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18334072654702043942,
+                        14368736099542340084,
+                        7250957797034705483,
+                        1253607897360826621,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4248413409467388922,
+                        15761082456398241510,
+                        16743215736958699735,
+                        177847528258155100,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12202612760372759514,
+                        10080954059489605620,
+                        8751318521338979084,
+                        483455047399046700,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        750082731838511934,
+                        2326389239183021890,
+                        3818830683000326870,
+                        478939994518975636,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10132226595811080951,
+                        17387498601319437715,
+                        5787964249016396466,
+                        1345074805688242087,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16708703426806524765,
+                        15985942403121066795,
+                        8473615746001759421,
+                        398538353246825353,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4334403656715748324,
+                        15969717484973130182,
+                        9224473663332211436,
+                        601397220735949442,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14236392927140979729,
+                        2893738271518606284,
+                        10987261669738820263,
+                        123072552812815046,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12789812855399883391,
+                        11038328021615419560,
+                        12827203897647197614,
+                        180849332338203315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7351557856252440899,
+                        14483298224425451852,
+                        6002561265277437607,
+                        900151348022610695,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7640792938636624253,
+                        4199794107869308619,
+                        7491269058073148011,
+                        923155577010470933,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15875750491326428969,
+                        13026113586247951090,
+                        10374482251247712980,
+                        557038412077624410,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1245864383651649558,
+                        13798858114319547759,
+                        12950533405701266392,
+                        785988676846716949,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3188823128780892123,
+                        5770257107744787922,
+                        783289279472719900,
+                        273959668317921670,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16303991455154868408,
+                        18102862433442338662,
+                        1431802778745081698,
+                        793760687192969693,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9618941920220763176,
+                        1087781133550163264,
+                        6359414783406930955,
+                        295091210654881078,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16807344371956595892,
+                        7794608854850224662,
+                        3108227374807802647,
+                        109304101673217131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13636392457482723851,
+                        18336670268277316292,
+                        15191465831376376327,
+                        531114483281250255,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        640465844291417289,
+                        12494066241897778091,
+                        14529584139850608599,
+                        803369714905419804,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        38393796248081839,
+                        9184455341595279543,
+                        3203995646411812068,
+                        628174722629174912,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10947569431999699464,
+                        3021177917471066879,
+                        14346041497879074533,
+                        1115919045152227965,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11943992651473154276,
+                        12106670904109368895,
+                        14963805373594777715,
+                        998270685763378037,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16749211185201403006,
+                        15855311540067200408,
+                        2667773970509191462,
+                        247808470393965871,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5439827280788578829,
+                        1850472395463317309,
+                        17860145873378102573,
+                        149005438858035879,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7608133607935933776,
+                        12590678744737589380,
+                        8605381668712078324,
+                        1073073892373686835,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1316943381699612591,
+                        12858164669440597004,
+                        2183258135107868207,
+                        81931953909230105,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7113105017095330546,
+                        14812948164249732586,
+                        5899326617954286533,
+                        907689788597316103,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5917754557945383047,
+                        12345851306760039337,
+                        9563688870767348462,
+                        1065240583876894774,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13358464543441541975,
+                        1932909953874434334,
+                        14581956408187846177,
+                        957876290957727244,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5595728912866733231,
+                        225928470847226627,
+                        1685291406208513277,
+                        516202138791825026,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9158560871397898216,
+                        8164585909775249815,
+                        3403947949336370484,
+                        594895518012417322,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16750988847574514700,
+                        17297022143248344288,
+                        9064391948139615046,
+                        347771385321431233,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7521648615878315260,
+                        7012644754034461138,
+                        7117472662288612097,
+                        1020204760939728520,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3581483864295547732,
+                        17426131014029483350,
+                        8058084146963898406,
+                        1155241704950140324,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6632043073724162532,
+                        8142544663226838205,
+                        14051932395616359415,
+                        464659730832739038,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6361412339665925375,
+                        10036015943054158734,
+                        12969269829327404646,
+                        319740051397478633,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13369033479839602942,
+                        3341958882742678127,
+                        3319225681399024719,
+                        842334025282623397,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1578322151919846160,
+                        10353683884834926295,
+                        1411668196461895696,
+                        1344525912915839676,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9990111752246525719,
+                        1988968700044349358,
+                        2242845652736845321,
+                        428387229075830678,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        577476491557218899,
+                        9340833790510852132,
+                        11319881521460625066,
+                        16502427405796132,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5653706303817706150,
+                        8571447751556720804,
+                        12402351268191648040,
+                        867900745239566832,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10378773572906320104,
+                        1858279699003124351,
+                        12507190735136187579,
+                        172956771301269438,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17764285305764040283,
+                        12141886635547089235,
+                        11004235859362712335,
+                        1307970404375429766,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15252651222706221078,
+                        10118986586413971018,
+                        29542786502540215,
+                        1104339137595620312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8047585583961386866,
+                        12177208897808367892,
+                        14846056751117722013,
+                        519266863060770345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11948156480703487853,
+                        15741635403010681109,
+                        1415707061201438325,
+                        782857265418837738,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9289857076796753919,
+                        5252954922234862489,
+                        4361505444855734243,
+                        1057733506790169211,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9436234838928908113,
+                        7330623271476481109,
+                        5604225105258738347,
+                        610340954876086231,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2172764199699994552,
+                        18394048844743981434,
+                        13573960468759945127,
+                        90076672317993115,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13185099598472431041,
+                        10367067922436652881,
+                        13138991688701937804,
+                        543814637859564989,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6751919655849132273,
+                        14743355791392754783,
+                        17870658061516992278,
+                        1134874868368692888,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16055411811663394782,
+                        1942832419987462503,
+                        4762514553380736305,
+                        40595492180531288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12104912267399131509,
+                        1221117259735688202,
+                        12485942314254474705,
+                        1299517633316715437,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        490536002531962058,
+                        11210869466436184382,
+                        2514117742720961521,
+                        208047220187563852,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15070661087490552116,
+                        18097940462331182182,
+                        8705689359468053316,
+                        1000171051474201643,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7202276395492507452,
+                        3533720442712776718,
+                        7277275747455329569,
+                        1100016412804751930,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5020565000102631851,
+                        15944727856077607305,
+                        6931762430154984484,
+                        1251946072212571113,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15079757722308222483,
+                        9325211314152859518,
+                        17796759545532605009,
+                        962563314386435789,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5672245220127731622,
+                        12285143775375061558,
+                        6435460796644883456,
+                        677497135000059054,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1446539018151766867,
+                        6128098550122978623,
+                        11758375476927983542,
+                        587382772607641342,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12732892752739666650,
+                        1751831982579239574,
+                        18210711088688767698,
+                        822039748008271137,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10815889800006644043,
+                        8818567483483264396,
+                        7179756185418151008,
+                        1334195095143384858,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17326819347086627986,
+                        16380536448077317335,
+                        16848512439145646669,
+                        649655970418514069,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10906160409580211789,
+                        1435290352151546494,
+                        3857157077267056065,
+                        722682603599296109,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12295166844318631549,
+                        12901031554021429377,
+                        15821768891961366231,
+                        1054775933511482469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11867821191068451960,
+                        2562597018424365697,
+                        15358835725505108076,
+                        1092918890796437808,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8933387647820265389,
+                        6351539628631103845,
+                        3663915634939280979,
+                        646087887042211300,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3804080276478946680,
+                        174152794783600470,
+                        729107659201870422,
+                        301273068177958023,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4537514048758275807,
+                        3456962256968922307,
+                        1997475177416034345,
+                        496818365973583817,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13487877175517523456,
+                        15794960861705584630,
+                        11286463379058180471,
+                        538300379477646983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9826161976332621774,
+                        6268083217792185806,
+                        13579237775129664728,
+                        1231932966390073819,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6461918770205013317,
+                        10548100271372342016,
+                        12032903702105412638,
+                        384336620491668084,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4745041282447018289,
+                        10359317677859312078,
+                        1863496560693066938,
+                        177550867643558664,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9637817308162320328,
+                        782956597043937635,
+                        2138165501732043594,
+                        601016062237485041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3040645594129571564,
+                        7949715897747336592,
+                        10452387079293833099,
+                        1298014152110968094,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6299290982071971661,
+                        5946528043023372945,
+                        14019847373710979886,
+                        1328397511551243885,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17489182761990046315,
+                        13545169126420009269,
+                        9620368527079981269,
+                        343675036814028472,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1660659759863183234,
+                        16413105418636127719,
+                        5485384101688287420,
+                        368667492470778516,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13416914397079619754,
+                        3071655510828695484,
+                        18372752101125177380,
+                        456375223791862239,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11344898796840847576,
+                        9628330255074911222,
+                        3527257430559598738,
+                        99552400198632233,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1787955508696298949,
+                        11342745877612896794,
+                        11638999380045310429,
+                        953428826511350468,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9545386961644093150,
+                        267581260178225302,
+                        1578576491901557202,
+                        611100447440301470,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1718945813534023200,
+                        16598938356289099706,
+                        13157837284433773103,
+                        247796668588540767,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        146486652682710323,
+                        15336735020237499550,
+                        8436542296730912221,
+                        907854398955593158,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12524686405110643247,
+                        8215191381016007664,
+                        8223268568706287544,
+                        777807578672335850,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18187733024507173065,
+                        3816116283189443735,
+                        11298874557691377787,
+                        540559663536994564,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2083491629209449674,
+                        10130475717907838358,
+                        12500305289762309805,
+                        271158713928004131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4972577320757947891,
+                        4912615156800654756,
+                        10759940739444575338,
+                        393714176272742886,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17740591614729587252,
+                        3769149987041116553,
+                        3300543195038939160,
+                        471222910267280903,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3753834751408339699,
+                        1990490524630948413,
+                        2507288116328271509,
+                        317149652583629307,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5559962686531478121,
+                        13161285538242956663,
+                        6221169715810374626,
+                        150491815354948819,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2866076657762042761,
+                        574570006543144693,
+                        3381492991970675380,
+                        297242754263112163,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13241602298049064629,
+                        12973029617678289857,
+                        9238671300494588625,
+                        1019430819199483460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4834190508851055349,
+                        11311431882840298678,
+                        8108956373782040405,
+                        884344015502098049,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17612858049241417544,
+                        7863628912704837677,
+                        15672654192156557584,
+                        805463324023645061,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4402781284615036794,
+                        3384076077317948163,
+                        8322394482624321418,
+                        51002169200273489,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5807499645637519713,
+                        12019358095861562049,
+                        12202721219275657599,
+                        237446841073341052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1826212326438680983,
+                        4594361216879673471,
+                        14462319598692475303,
+                        799257042570630454,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        655383653670306152,
+                        10893172699819587845,
+                        6444078651646844966,
+                        1238188678731928272,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11933477000953832992,
+                        8659260972216130722,
+                        4176203059503266510,
+                        462081501590791202,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8449158320039069638,
+                        7780893915415517973,
+                        16326455918196262343,
+                        766574441915912060,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13450512112834174713,
+                        4300391989860041131,
+                        1123853854978314062,
+                        447242607813998706,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11588531860548922751,
+                        872631309321163076,
+                        1789106339178180257,
+                        608728686416677515,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        933912183286200956,
+                        16816230150886755860,
+                        7282260885540899064,
+                        154144372957813946,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16106052175894184486,
+                        2725856802446827591,
+                        9348682234734528944,
+                        473268949642055693,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2323091109820312135,
+                        16196244330285717732,
+                        1883276650353645331,
+                        1274732535112294912,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7270792482942612658,
+                        16717430492640287502,
+                        18364807681028464844,
+                        1110172211114556856,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2752853995630237120,
+                        4283352080262014388,
+                        3815474923872474423,
+                        407550740377382291,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6052780345620958946,
+                        12911366885490786288,
+                        10870804072960742740,
+                        923944298180583963,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2033568403054856341,
+                        9399435399220413872,
+                        1022554518247709895,
+                        1100871279212745324,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3455053165591577509,
+                        3144922162015320388,
+                        14617435243023895992,
+                        914282837810459778,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1168616914844637709,
+                        7654368454192376626,
+                        17684107196523548183,
+                        672375745436302849,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3943159715779237595,
+                        14207319375801615357,
+                        6240875752156108086,
+                        775366181912093516,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14762446791208151384,
+                        10779321703599341699,
+                        7503564908281425251,
+                        279786952355310981,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10545305037110510872,
+                        16433444437080516089,
+                        735519372039071430,
+                        1043207900846793063,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8961521384592334478,
+                        14373224264903602052,
+                        16872734019198474519,
+                        1275638240745643618,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14348226376862671467,
+                        6034018522315336936,
+                        4055914782235663241,
+                        1250793087572033315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13522148692554291791,
+                        10620517994173712305,
+                        13471978260546326570,
+                        973330219432290253,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10895370525161829129,
+                        13820769680688384477,
+                        14594656961558915658,
+                        1329339544638887784,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15163521448097853702,
+                        5808258008141504025,
+                        12808032529087736316,
+                        628300080179749153,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1691638475032772227,
+                        107609131966864937,
+                        5649596695199847731,
+                        1324479732259453731,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2078912861289552878,
+                        1505311571100329179,
+                        8392317575330664396,
+                        389564857912897342,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11732615104699318692,
+                        3017505019598265769,
+                        11125855191256576486,
+                        1157622554481009875,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7343847210854699325,
+                        2405897561067921513,
+                        7146574416944257976,
+                        1254449054414521138,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4695984638360114129,
+                        17519613873507035906,
+                        930584255273581734,
+                        146100881049956257,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4293965612038978977,
+                        4750780985522360699,
+                        1635089401159460018,
+                        1135360405404254979,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1400185681658280600,
+                        17366870673321980620,
+                        16596536959645468162,
+                        1073296228956831162,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15581960183229935945,
+                        1187628478792273015,
+                        12707936407689908329,
+                        494605410869909712,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        455113727051763245,
+                        3453690827658004876,
+                        17396384635306902218,
+                        1240640321463479175,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8710826496238294029,
+                        11743852777143377617,
+                        1923500000023743741,
+                        23769033506664002,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12118972672527388036,
+                        10896060048689013042,
+                        8144790218597221824,
+                        245854233874482917,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10508616101363217795,
+                        3725008692016736382,
+                        8653088289793425023,
+                        261060588050132006,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3160619642197211316,
+                        2822334132368652949,
+                        3398193553689684102,
+                        420282723643502762,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16712122890094698346,
+                        17028061973461873210,
+                        2112311066552765271,
+                        88245962124575613,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6392118782880483172,
+                        16114279647078862935,
+                        1205579199651908388,
+                        548575505162254954,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15884512171693697603,
+                        5967407702718358148,
+                        1692599227801645590,
+                        1312183973393817862,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2916453512401505515,
+                        12138889066664937379,
+                        8352825468489550775,
+                        865619437179964736,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9411291605105321067,
+                        11128807435722580995,
+                        3867736832051270086,
+                        877181907967318281,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6074739948239827253,
+                        7875063682943422324,
+                        4639093304546699255,
+                        904986720571390368,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        525461026430872156,
+                        11233807189135862476,
+                        11094737420855516875,
+                        33495591463983284,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10359934179301357244,
+                        14727022082490959043,
+                        12612709298739870762,
+                        16600277518340478,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16976441031108982331,
+                        15365064893108976123,
+                        4662037033388344378,
+                        1067988703927177922,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12040549892472155860,
+                        10512479594555902579,
+                        3451561488501415243,
+                        639755010809481605,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5275588378290977119,
+                        6805063540593134772,
+                        8481957967553608950,
+                        253762608505626627,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14548863945631403190,
+                        13259352587789083910,
+                        7051408550411506736,
+                        1319960627967720093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3015044906073649280,
+                        9532079416250041034,
+                        5352999418076762223,
+                        91852301624390277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13035549391205334001,
+                        14108978652524443615,
+                        2538364711814634077,
+                        988448648257864185,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2732358421174531623,
+                        5012006589687792247,
+                        16205498021979168866,
+                        118916467714724808,
+                    ]),
+                ]),
+                Matrix::<7, 1, 7>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14492697800336972553,
+                        11541026836034716432,
+                        16064074715518262469,
+                        17461970784605995,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14226844750656769196,
+                        7570868083405350493,
+                        16934003686161011774,
+                        219520356901731987,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8766597239963409793,
+                        5889486061701204563,
+                        1131025337904173270,
+                        1299515009247304863,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16794093099454582953,
+                        7450186255472031011,
+                        11222873743434260385,
+                        672104226017142099,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13084799097772533501,
+                        16516804992641325348,
+                        12576462959866002318,
+                        746732271201784132,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16616195912902270084,
+                        14117930645222098744,
+                        12379456341883900122,
+                        362121039947518035,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10091434971270834798,
+                        14421460739250683494,
+                        11033132424396116862,
+                        1136415075551284123,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<62, 8, 496>::new_from_known([
+            Fq::from_montgomery_limbs([
+                14864108562933674823,
+                14979587931995011084,
+                12907914206163503149,
+                505142564773720425,
+            ]),
+            Fq::from_montgomery_limbs([
+                3313667999158672945,
+                13074515287503057765,
+                15278994316169638814,
+                902059631829495252,
+            ]),
+            Fq::from_montgomery_limbs([
+                6977647357377197332,
+                8246241378024044247,
+                5553849949938488835,
+                787971980296042585,
+            ]),
+            Fq::from_montgomery_limbs([
+                7198976537138658081,
+                5168286913144650999,
+                4988767812055448597,
+                38267654757989216,
+            ]),
+            Fq::from_montgomery_limbs([
+                17339129620671621211,
+                8330029159438372494,
+                6943241289984286089,
+                962300144783314192,
+            ]),
+            Fq::from_montgomery_limbs([
+                10049688062969538178,
+                11070775822728623090,
+                974441504257954434,
+                1283253731580227746,
+            ]),
+            Fq::from_montgomery_limbs([
+                11677721877467938963,
+                4704162690594437147,
+                9802239922555791633,
+                1004674084068601181,
+            ]),
+            Fq::from_montgomery_limbs([
+                11049182996183747395,
+                6758341973229315968,
+                12794635406574876920,
+                93901399045650323,
+            ]),
+            Fq::from_montgomery_limbs([
+                5886070214734015466,
+                12692804240421714755,
+                1188707587346378604,
+                79768788143137114,
+            ]),
+            Fq::from_montgomery_limbs([
+                13394786930649719818,
+                1653732893646047042,
+                5538072130443606274,
+                50643896272808480,
+            ]),
+            Fq::from_montgomery_limbs([
+                13518130302710511507,
+                7949256399903869235,
+                10422736219759354530,
+                872159264783214343,
+            ]),
+            Fq::from_montgomery_limbs([
+                10907540509409256363,
+                14546312012099717925,
+                13734983505148586693,
+                1518405818273735,
+            ]),
+            Fq::from_montgomery_limbs([
+                14817286810090930651,
+                11144449721364869017,
+                9263545029400079888,
+                1110421611642546353,
+            ]),
+            Fq::from_montgomery_limbs([
+                9473291623429482393,
+                15307460134982475756,
+                2684728159638571857,
+                1130495849814812801,
+            ]),
+            Fq::from_montgomery_limbs([
+                11481247265475753165,
+                6650084973990415579,
+                8956274554854348627,
+                632673669114489463,
+            ]),
+            Fq::from_montgomery_limbs([
+                2926673797409022389,
+                16507523254378674101,
+                2491508404616665929,
+                1267232212822441746,
+            ]),
+            Fq::from_montgomery_limbs([
+                18075465715589839337,
+                8641240595736417634,
+                9209437042748062759,
+                119673756615494298,
+            ]),
+            Fq::from_montgomery_limbs([
+                12052165761254689071,
+                9355829416739006943,
+                11692041113970906143,
+                1051102884117138411,
+            ]),
+            Fq::from_montgomery_limbs([
+                17809664420456269760,
+                13061650015330210109,
+                14826151357006375249,
+                603770768740653882,
+            ]),
+            Fq::from_montgomery_limbs([
+                16130005162934933454,
+                2018014610268401350,
+                15352596876284400401,
+                289967842816483842,
+            ]),
+            Fq::from_montgomery_limbs([
+                7568615195262611994,
+                14071205259832457259,
+                14921865248019034152,
+                458400664880042611,
+            ]),
+            Fq::from_montgomery_limbs([
+                18329491688780337251,
+                7130114070235662763,
+                8717868376332336790,
+                571424868786392484,
+            ]),
+            Fq::from_montgomery_limbs([
+                10022602771216003432,
+                12715219746134351484,
+                13286220647683489230,
+                216061640050913974,
+            ]),
+            Fq::from_montgomery_limbs([
+                17695661457113548187,
+                9298022876842354001,
+                12990773028252626975,
+                458380232000726585,
+            ]),
+            Fq::from_montgomery_limbs([
+                10140759686474857866,
+                9629613689727292850,
+                9182903796853053300,
+                1290754144091780386,
+            ]),
+            Fq::from_montgomery_limbs([
+                3123859960914886152,
+                11288134306370562686,
+                6438098372828355793,
+                1275905965787012706,
+            ]),
+            Fq::from_montgomery_limbs([
+                15466316411013171931,
+                18010259221351414095,
+                10953437182238001339,
+                363411617029979603,
+            ]),
+            Fq::from_montgomery_limbs([
+                14145471001926831533,
+                16851110457484552954,
+                3379562730712110950,
+                764484260989255481,
+            ]),
+            Fq::from_montgomery_limbs([
+                1083273036123345835,
+                9494921206142621068,
+                7309734029001230370,
+                40165465453553391,
+            ]),
+            Fq::from_montgomery_limbs([
+                3810547551037909161,
+                12694779575599869537,
+                9837831098193601948,
+                1258611609250866255,
+            ]),
+            Fq::from_montgomery_limbs([
+                18098319469136228743,
+                16990247945506253601,
+                9658942405426645023,
+                47271068120682662,
+            ]),
+            Fq::from_montgomery_limbs([
+                1538341296092534272,
+                7942707993302963897,
+                2264139856778845644,
+                15301967108271492,
+            ]),
+            Fq::from_montgomery_limbs([
+                4583201746237381394,
+                9917458271722608126,
+                341610920887738021,
+                252744244075039930,
+            ]),
+            Fq::from_montgomery_limbs([
+                8372020260732172479,
+                16636328198506918950,
+                17812500733410905241,
+                1125150579640853199,
+            ]),
+            Fq::from_montgomery_limbs([
+                5070080203775897641,
+                4936091092553000288,
+                3444807547670252313,
+                17986110592955626,
+            ]),
+            Fq::from_montgomery_limbs([
+                3200716680788850198,
+                5823731340917087886,
+                15032923535400232055,
+                755347220268882857,
+            ]),
+            Fq::from_montgomery_limbs([
+                10901322712907552306,
+                12591518144627614876,
+                13127092321853466355,
+                1030333720114089444,
+            ]),
+            Fq::from_montgomery_limbs([
+                9184881618789505725,
+                9677895291294455136,
+                12542548311523993149,
+                1178418167942098392,
+            ]),
+            Fq::from_montgomery_limbs([
+                6273210806183902303,
+                16688468396335821923,
+                7027933954392311143,
+                1140300326838695146,
+            ]),
+            Fq::from_montgomery_limbs([
+                9727656812794629333,
+                13524231028479766766,
+                7856237396549426738,
+                299673033368495582,
+            ]),
+            Fq::from_montgomery_limbs([
+                6209385009546738643,
+                17254968780954378679,
+                17987470466070489737,
+                1307527050623446644,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11255801027972722021,
+                1178970079660985838,
+                11377746228475375320,
+                697048435249580409,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2712791500153460984,
+                302219723541720487,
+                15117670196373627615,
+                747446707254178412,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14962433726338595264,
+                14208706480872303480,
+                14766314427514128848,
+                931109628460238796,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7506350088602681258,
+                11194900472314094376,
+                8554144678614151638,
+                224294489338450950,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2676326861886063436,
+                9010710790873273670,
+                13622532357650665770,
+                745856275924191275,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8117748736477928519,
+                12992369836194893096,
+                7326908320925500486,
+                1162534541455848233,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7044124657978698132,
+                6590255682308148726,
+                4993183108348625654,
+                1334690863543293790,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7293944925888555764,
+                18238878890476431260,
+                18118008267877154207,
+                882462278221994339,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13041829593242230023,
+                17384228775622214677,
+                2818699625761963247,
+                145726980790812268,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5761505865480205249,
+                13996677297510746717,
+                11971749229371047396,
+                194333499420331029,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15472167532062704544,
+                10920831305130251489,
+                17671881744894165076,
+                1075364925849795364,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17663020300238273739,
+                9837846281719200888,
+                6888264026060148097,
+                700911986019398368,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4021808231945525898,
+                5532689088971561060,
+                5075272142332671985,
+                134891461869291906,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7377227824476285236,
+                14354631138829916958,
+                12715101544274805737,
+                917062106586728147,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14169398710709063787,
+                7670578178758671826,
+                6435354539882573115,
+                542256313737212483,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15426726798464458825,
+                9554352343939376394,
+                5899940918009040754,
+                682665888458013777,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6243707250400706868,
+                11347219061780363064,
+                528679049559326399,
+                357724971037870483,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14764683294943482084,
+                1382597270218379449,
+                13888574376200763525,
+                1169963228521214625,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1552007318200798214,
+                6747489902905261050,
+                16097920607899013714,
+                271046280612930355,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6112685575568412550,
+                17922535478160183477,
+                6006639061004420440,
+                647088748538573225,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1300103284346203287,
+                8893214734311888802,
+                16027889720374740244,
+                471221229729368074,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10362899511818517322,
+                17250799171495258339,
+                11295946038524086339,
+                771484969073787414,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1175915143394688143,
+                8057082216384232729,
+                1632043440004236634,
+                239242083811753599,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1780378672862974492,
+                7223522906906004768,
+                15896615868571430145,
+                491542952161503087,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9237937152939777550,
+                3876964932665357995,
+                4490184121575723652,
+                826082119624815386,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13180008063572828773,
+                1308874167728775471,
+                9161251691065956033,
+                248756522559460391,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6160097673912560513,
+                9355876305428066776,
+                851697169414280142,
+                1298858290963992844,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16588857470237849524,
+                15342822605227093236,
+                5150285078031088530,
+                306025454124157058,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                18211384025986608992,
+                8094247716087827686,
+                4608170406404543381,
+                1111877216034513678,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3457941691201776951,
+                6439427561412434998,
+                2012839556582474158,
+                751250339241025898,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17644997575545929212,
+                12711735055372365575,
+                17773925422709753358,
+                615209948975583587,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1354299370332695843,
+                16497125985660381775,
+                6463595243097506567,
+                33151821345494977,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                529680007928004919,
+                9346802552794164542,
+                4238027988719853680,
+                249123245710158776,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6981267986596739336,
+                4088676149395257290,
+                5169042512787022983,
+                631584147382811654,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12228981684162664178,
+                11979670080943664402,
+                5981543482037786532,
+                376292579647277505,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10256311841035976469,
+                3845078070248317958,
+                18269263129711651418,
+                826703817515784362,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11149745049622562368,
+                4353462881268122786,
+                3374597951771939483,
+                312204797404754988,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4493837138622943088,
+                17038882180376833101,
+                4134004757221455082,
+                799980535057110896,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6739122660544154420,
+                18068161999360016128,
+                18430212405156559716,
+                1033056027807321992,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2058087018273906915,
+                11203217644281522532,
+                6916433662878203335,
+                1115535185409622683,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4057278707356695564,
+                16442742269954112531,
+                7139494537840827916,
+                1194190880786598046,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14512892211770482388,
+                14301407392462593376,
+                6040449486985983504,
+                1247509107432221458,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7013322978725963414,
+                1735449895281827280,
+                9645814538152156957,
+                273044805367817824,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2695617577355700606,
+                8332560651251845555,
+                10359571076193134506,
+                751621492463850809,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3006827174863354748,
+                11409042790965198156,
+                10766639940989388832,
+                597580646341296426,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9305971541080112262,
+                15645041868237765488,
+                12667314593323411202,
+                162471630602611711,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13167894255482164061,
+                10808231977946324776,
+                8006335200384936058,
+                1012784444440670913,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4506687772097515062,
+                7771615636688271270,
+                16919983537056949647,
+                60089531250260089,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11907715876977417711,
+                18067292166780177929,
+                16973997730509467442,
+                371269779492437120,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9869492371096723477,
+                6347223698965314510,
+                8569137551942264771,
+                834822063125396359,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7847585057277765908,
+                9037582375225212845,
+                10696668499044960291,
+                1197963153079754225,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7347727693872348374,
+                12807660491243977337,
+                2812202200406194903,
+                931937631462162896,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1996204862349998966,
+                15170059452234337776,
+                12336659270087700154,
+                1312304880555223665,
+            ]),
+            Fq::from_montgomery_limbs([
+                3590326444381034164,
+                11081047508292750292,
+                13929248981607412389,
+                1148551456529969961,
+            ]),
+            Fq::from_montgomery_limbs([
+                15748711275568111378,
+                9827821351496978377,
+                1089451566498109435,
+                385573108562977388,
+            ]),
+            Fq::from_montgomery_limbs([
+                5854308759232665075,
+                14646745164651706053,
+                1059818340393879209,
+                278073037803678303,
+            ]),
+            Fq::from_montgomery_limbs([
+                782875270311229251,
+                14129736259963306101,
+                17676571092472799579,
+                99784368099657632,
+            ]),
+            Fq::from_montgomery_limbs([
+                6194170668440688218,
+                14282655764847950690,
+                9256960510560043677,
+                738800301246671320,
+            ]),
+            Fq::from_montgomery_limbs([
+                5647874486303058603,
+                5594031317822353747,
+                1185150640969897168,
+                1250626751811179876,
+            ]),
+            Fq::from_montgomery_limbs([
+                7010803484069701924,
+                12818075181882154010,
+                16406691674597778333,
+                332452086185430739,
+            ]),
+            Fq::from_montgomery_limbs([
+                10558603365142197609,
+                11373600441535346477,
+                9726798938291635143,
+                500581893892681231,
+            ]),
+            Fq::from_montgomery_limbs([
+                17131488617883841252,
+                3385215517201649311,
+                6293710470421583532,
+                1292140013300148706,
+            ]),
+            Fq::from_montgomery_limbs([
+                16405571866748578361,
+                8346417963005706131,
+                6891455370369515846,
+                332616568299419767,
+            ]),
+            Fq::from_montgomery_limbs([
+                17183384594343797329,
+                2919752086761121270,
+                7919437726720679875,
+                832859427841304916,
+            ]),
+            Fq::from_montgomery_limbs([
+                4259649235776782634,
+                16933161878908259009,
+                16645343458225013262,
+                177054171537045438,
+            ]),
+            Fq::from_montgomery_limbs([
+                7893282804315531011,
+                7181748259050086665,
+                9135073823090683089,
+                1044994866088352692,
+            ]),
+            Fq::from_montgomery_limbs([
+                11393344498918750487,
+                12041340704289672685,
+                15561100566248834211,
+                940255115909675717,
+            ]),
+            Fq::from_montgomery_limbs([
+                16853825124296855945,
+                12640313510403419631,
+                5377413186105875938,
+                801496584973066062,
+            ]),
+            Fq::from_montgomery_limbs([
+                9056285154570923331,
+                356918824519122980,
+                6910520113130040956,
+                409464575493953339,
+            ]),
+            Fq::from_montgomery_limbs([
+                16711436605099243862,
+                15893275493260980658,
+                14432027051421917234,
+                1153577179318646422,
+            ]),
+            Fq::from_montgomery_limbs([
+                7734754032896877523,
+                16505006266553674644,
+                12884940138859397527,
+                843389732174119606,
+            ]),
+            Fq::from_montgomery_limbs([
+                4824715170359514276,
+                303364379103377811,
+                14613520644787478108,
+                1234987705897889628,
+            ]),
+            Fq::from_montgomery_limbs([
+                5162235577849424098,
+                17366789317221808794,
+                14336156627491895866,
+                33481018837137662,
+            ]),
+            Fq::from_montgomery_limbs([
+                8663174270219934585,
+                7712730996668459388,
+                11821491074593993434,
+                784443953675314530,
+            ]),
+            Fq::from_montgomery_limbs([
+                1508340974944431647,
+                3601973860161993386,
+                12800432673129849392,
+                728332055529059138,
+            ]),
+            Fq::from_montgomery_limbs([
+                7221075483357098124,
+                7987592524343018443,
+                5793260830898302072,
+                307945833752362188,
+            ]),
+            Fq::from_montgomery_limbs([
+                4239430024091038436,
+                3174815200553333671,
+                17077712470644489873,
+                763307098023159221,
+            ]),
+            Fq::from_montgomery_limbs([
+                11159655911192590320,
+                3765420882842581742,
+                15236876498537301538,
+                405919150343767661,
+            ]),
+            Fq::from_montgomery_limbs([
+                3390863659191225763,
+                18101882968319659842,
+                17217261695529609403,
+                1176312514039579581,
+            ]),
+            Fq::from_montgomery_limbs([
+                7267337626587624560,
+                9710998069454229270,
+                2804038872846790284,
+                874697408246268272,
+            ]),
+            Fq::from_montgomery_limbs([
+                472099205892538830,
+                13955143156109384962,
+                9899555855436721512,
+                93129938491162901,
+            ]),
+            Fq::from_montgomery_limbs([
+                2472697551768019341,
+                12463736814021393633,
+                10075113450776466385,
+                600266021745863152,
+            ]),
+            Fq::from_montgomery_limbs([
+                4499264873699708339,
+                11960299134664465463,
+                4906517236926788877,
+                1287390496324344728,
+            ]),
+            Fq::from_montgomery_limbs([
+                3440393536074736584,
+                14325114223416017606,
+                4399892897936904155,
+                129925042594696382,
+            ]),
+        ]),
+    }
+}
@@ -0,0 +1,11750 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-10 instance of Poseidon.
+pub const fn rate_10() -> PoseidonParameters<11, 10, 121, 100, 39, 11, 429, 31> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<39, 11, 429>::new_from_known([
+            Fq::from_montgomery_limbs([
+                14131054418777488854,
+                396201730103805847,
+                10716186622642889704,
+                856377251280961738,
+            ]),
+            Fq::from_montgomery_limbs([
+                14159017566367665609,
+                16475790876623558337,
+                16130819625780716090,
+                812130851825605617,
+            ]),
+            Fq::from_montgomery_limbs([
+                10606257543333808031,
+                5707856953834521134,
+                17983729648524286828,
+                957065544299167485,
+            ]),
+            Fq::from_montgomery_limbs([
+                6878796606992495895,
+                8856573295413326897,
+                648803285772359125,
+                760051340846749185,
+            ]),
+            Fq::from_montgomery_limbs([
+                7400754013503752250,
+                11196556398769135870,
+                16048325941650866236,
+                768883517817519486,
+            ]),
+            Fq::from_montgomery_limbs([
+                12381053993196356774,
+                2550120875241147891,
+                10388413343741381007,
+                263779225291900913,
+            ]),
+            Fq::from_montgomery_limbs([
+                10983348790583613482,
+                440647102037862789,
+                1019191662018519122,
+                1226012539888459601,
+            ]),
+            Fq::from_montgomery_limbs([
+                15577318595772972195,
+                17607278286106180828,
+                14497383893778094429,
+                493249527998681286,
+            ]),
+            Fq::from_montgomery_limbs([
+                17831646288271643414,
+                17505126732804397166,
+                12981417346481678792,
+                874183644415012319,
+            ]),
+            Fq::from_montgomery_limbs([
+                2617515050485373089,
+                7609263567260690216,
+                11327828554351122713,
+                331772373735536183,
+            ]),
+            Fq::from_montgomery_limbs([
+                13247508706634788003,
+                13020182594489846012,
+                4372426384807603895,
+                1097226436963926611,
+            ]),
+            Fq::from_montgomery_limbs([
+                10721272377812258791,
+                11876612898022753835,
+                11948063768216928972,
+                9771532930211916,
+            ]),
+            Fq::from_montgomery_limbs([
+                13379504513870505926,
+                5845858180964105873,
+                889707027654931036,
+                1265790587027212570,
+            ]),
+            Fq::from_montgomery_limbs([
+                4447488680794998826,
+                9246168605084850420,
+                3291003783612107978,
+                265899312720817807,
+            ]),
+            Fq::from_montgomery_limbs([
+                665057425395378301,
+                11766068748612278406,
+                10520922414257207266,
+                835304351079404733,
+            ]),
+            Fq::from_montgomery_limbs([
+                3898866475532241445,
+                1152055524368575410,
+                16077254628997370065,
+                365057244874627274,
+            ]),
+            Fq::from_montgomery_limbs([
+                3140772546930670733,
+                11949255924741779677,
+                10357197867991389857,
+                1100059218462113054,
+            ]),
+            Fq::from_montgomery_limbs([
+                11192592590115335074,
+                9837685389025563344,
+                9493158642047160754,
+                758559153214604442,
+            ]),
+            Fq::from_montgomery_limbs([
+                17266978040569687959,
+                14495298528849212170,
+                7938336919058307042,
+                627809548770967299,
+            ]),
+            Fq::from_montgomery_limbs([
+                14257124664885929242,
+                14243644806647218635,
+                12777472786425265320,
+                707056327364571007,
+            ]),
+            Fq::from_montgomery_limbs([
+                17717595291600038578,
+                14013583214152537438,
+                14022910745577464285,
+                1279071614801530740,
+            ]),
+            Fq::from_montgomery_limbs([
+                3980929200573455294,
+                5142079044939144408,
+                12829295618389177610,
+                422222167392554867,
+            ]),
+            Fq::from_montgomery_limbs([
+                14885001468087365778,
+                7573026965561486433,
+                1613637141658861325,
+                531106786975717145,
+            ]),
+            Fq::from_montgomery_limbs([
+                11918661544252571702,
+                821183842587206068,
+                12613760167538206003,
+                1255807271791581663,
+            ]),
+            Fq::from_montgomery_limbs([
+                1325220430074941210,
+                15564362403535057990,
+                15959187642898089850,
+                1247358261719249764,
+            ]),
+            Fq::from_montgomery_limbs([
+                9816089363295956,
+                6242518884794997712,
+                11701938109987035794,
+                582597716952519190,
+            ]),
+            Fq::from_montgomery_limbs([
+                3515356696014383261,
+                15261136382978025771,
+                10075082878724311485,
+                167495628706570933,
+            ]),
+            Fq::from_montgomery_limbs([
+                11534725860438110059,
+                15928760277397233955,
+                5949584697149749379,
+                806560199257584950,
+            ]),
+            Fq::from_montgomery_limbs([
+                8808774936506915762,
+                12638464696777057929,
+                16999732419701578242,
+                123783177466243044,
+            ]),
+            Fq::from_montgomery_limbs([
+                7498751366617875652,
+                18044204780868637633,
+                6072697687797469560,
+                1296387161708738860,
+            ]),
+            Fq::from_montgomery_limbs([
+                1707055192244912406,
+                3013405036080013183,
+                84341988700764617,
+                1296143434529826906,
+            ]),
+            Fq::from_montgomery_limbs([
+                8393222070270342341,
+                7607268795872281977,
+                6842789420562568882,
+                1282314820114071423,
+            ]),
+            Fq::from_montgomery_limbs([
+                11979611098910610052,
+                17852071314266815583,
+                2373674796090549947,
+                1341873180636211404,
+            ]),
+            Fq::from_montgomery_limbs([
+                5363908796662445939,
+                15542369272824421054,
+                16050570237375653197,
+                982641266612013532,
+            ]),
+            Fq::from_montgomery_limbs([
+                16909574450905146993,
+                553337112694813422,
+                4175372255115864803,
+                610410898248057387,
+            ]),
+            Fq::from_montgomery_limbs([
+                6767505859809311541,
+                10693196442741514204,
+                9986241465096252756,
+                722800719558255923,
+            ]),
+            Fq::from_montgomery_limbs([
+                17205297488478347866,
+                14433306589503049808,
+                9179062248843807411,
+                76390074461146517,
+            ]),
+            Fq::from_montgomery_limbs([
+                3683944305798428896,
+                15578366475882024292,
+                2575308672979133627,
+                87643516194004288,
+            ]),
+            Fq::from_montgomery_limbs([
+                16848704354962426609,
+                10754581896689200393,
+                12595375850726877236,
+                1248087486250545005,
+            ]),
+            Fq::from_montgomery_limbs([
+                2910875184217387298,
+                13916125098400420580,
+                1380839709793729387,
+                248653651447658865,
+            ]),
+            Fq::from_montgomery_limbs([
+                13342923149676514039,
+                14204741375918420643,
+                3078149257126236769,
+                214348459582899690,
+            ]),
+            Fq::from_montgomery_limbs([
+                7193796817462321547,
+                15831654021932264036,
+                2848792915167491320,
+                115945105252202350,
+            ]),
+            Fq::from_montgomery_limbs([
+                5600101877133926022,
+                9883149222397911984,
+                17422107274899123009,
+                963356694814185354,
+            ]),
+            Fq::from_montgomery_limbs([
+                14873627637488587773,
+                1057188953344876822,
+                1339936461542707327,
+                138716589258740178,
+            ]),
+            Fq::from_montgomery_limbs([
+                12059290824584550131,
+                11732708759530020628,
+                780059053182505933,
+                511930134672770392,
+            ]),
+            Fq::from_montgomery_limbs([
+                17391758424098126507,
+                10153789464696602343,
+                15523671582325377446,
+                1276592790937698734,
+            ]),
+            Fq::from_montgomery_limbs([
+                14762759266716330708,
+                8401287301393960230,
+                17876753894837707012,
+                209572472419436837,
+            ]),
+            Fq::from_montgomery_limbs([
+                10396950304473447039,
+                7140897899822054891,
+                7289737528871489979,
+                960240693158188124,
+            ]),
+            Fq::from_montgomery_limbs([
+                3602472576728484429,
+                7203564447845877144,
+                2909015482287236037,
+                671570846051177371,
+            ]),
+            Fq::from_montgomery_limbs([
+                10338832965316506533,
+                16022366803908157160,
+                8938361117700152590,
+                900729638228457920,
+            ]),
+            Fq::from_montgomery_limbs([
+                13931716190395570806,
+                10015768854363594924,
+                5761892785193738415,
+                478307608304561913,
+            ]),
+            Fq::from_montgomery_limbs([
+                11780775409969380474,
+                15083970429212362189,
+                12912953519259881967,
+                129883952297363611,
+            ]),
+            Fq::from_montgomery_limbs([
+                5733334832009123279,
+                17919325483924212854,
+                13251494251429120819,
+                340556527179613385,
+            ]),
+            Fq::from_montgomery_limbs([
+                454480604585553173,
+                8680445184352154784,
+                17746853843175853296,
+                439371346714180405,
+            ]),
+            Fq::from_montgomery_limbs([
+                4751666001078731726,
+                7432419545537323166,
+                9779036360822039254,
+                922169544025865407,
+            ]),
+            Fq::from_montgomery_limbs([
+                5188102555951266743,
+                4072104422144881059,
+                8631736045529614362,
+                906322692749762078,
+            ]),
+            Fq::from_montgomery_limbs([
+                9793220032748102010,
+                2277472951388142492,
+                9411418866582371077,
+                172143389685410520,
+            ]),
+            Fq::from_montgomery_limbs([
+                2076092884493193377,
+                7048761338965405381,
+                14016558020320349205,
+                1277979306695622121,
+            ]),
+            Fq::from_montgomery_limbs([
+                17271363463553226482,
+                2523516627447777248,
+                6366597414393520700,
+                521872485708044801,
+            ]),
+            Fq::from_montgomery_limbs([
+                11670116613151913378,
+                7928434071397661919,
+                14588929144097468727,
+                761931585541340306,
+            ]),
+            Fq::from_montgomery_limbs([
+                16125911589941628743,
+                14465636346347964493,
+                1640507797953969166,
+                486875288234717831,
+            ]),
+            Fq::from_montgomery_limbs([
+                1806199592605252720,
+                4772111440036806637,
+                18029025227641331732,
+                1302231775132966194,
+            ]),
+            Fq::from_montgomery_limbs([
+                14012791034969956861,
+                2757396876781437488,
+                247902831562187702,
+                5054921547239547,
+            ]),
+            Fq::from_montgomery_limbs([
+                13785654238067656196,
+                10250945087682464142,
+                12432423737826742559,
+                789377207647738204,
+            ]),
+            Fq::from_montgomery_limbs([
+                8064640605376565881,
+                10137931557318473178,
+                6223757298123928418,
+                981439369046379610,
+            ]),
+            Fq::from_montgomery_limbs([
+                17251735167957408684,
+                429463128505468022,
+                3448446574856703059,
+                999165736346588907,
+            ]),
+            Fq::from_montgomery_limbs([
+                1228661585473052596,
+                17511856798855170391,
+                13898561836714155672,
+                1040092720549220667,
+            ]),
+            Fq::from_montgomery_limbs([
+                1779478885174514145,
+                10670435671220008875,
+                15420090897185644937,
+                17868146063330516,
+            ]),
+            Fq::from_montgomery_limbs([
+                10008877161857174505,
+                13180216991331714025,
+                8664223731453187602,
+                1117888655241345344,
+            ]),
+            Fq::from_montgomery_limbs([
+                9763965819106844754,
+                984105683291885713,
+                10156884428856509496,
+                149218549343208995,
+            ]),
+            Fq::from_montgomery_limbs([
+                14828328362751069631,
+                13499690736759551206,
+                500244650582953721,
+                876182787805491382,
+            ]),
+            Fq::from_montgomery_limbs([
+                4925876532594703171,
+                14522947784350564084,
+                9724454188775846865,
+                7195960762300298,
+            ]),
+            Fq::from_montgomery_limbs([
+                14117838294694232074,
+                2074738540509517235,
+                6507943463121797443,
+                236100568685232221,
+            ]),
+            Fq::from_montgomery_limbs([
+                12183604358360435498,
+                14735467047457257645,
+                12529611385971291122,
+                680894691120026158,
+            ]),
+            Fq::from_montgomery_limbs([
+                16841252947740330858,
+                466626777918496860,
+                9964231646481319475,
+                1029331947394568477,
+            ]),
+            Fq::from_montgomery_limbs([
+                8221956534892432747,
+                13694853452247807541,
+                14829285102454309106,
+                201992187502643191,
+            ]),
+            Fq::from_montgomery_limbs([
+                7608817999618992000,
+                6245390178728795816,
+                13536970487271421120,
+                501309995113791166,
+            ]),
+            Fq::from_montgomery_limbs([
+                9375920969189054356,
+                13641868070453612763,
+                4750666850763275439,
+                1090346304950223563,
+            ]),
+            Fq::from_montgomery_limbs([
+                2552147537360592793,
+                7824697762649987214,
+                11069864014071194014,
+                140496295632340644,
+            ]),
+            Fq::from_montgomery_limbs([
+                976677473360796530,
+                414020105754593161,
+                14257572376672995240,
+                1177318665682329330,
+            ]),
+            Fq::from_montgomery_limbs([
+                18180977558304142298,
+                3616048648173282445,
+                6386684809369974803,
+                793698982786242420,
+            ]),
+            Fq::from_montgomery_limbs([
+                9095338350099019973,
+                12021599876548477824,
+                1292557176722040034,
+                1057985985283599197,
+            ]),
+            Fq::from_montgomery_limbs([
+                13654956855477090798,
+                7183960687937078856,
+                6830117888991393095,
+                993739314221164568,
+            ]),
+            Fq::from_montgomery_limbs([
+                4184923355345890157,
+                8115505172895874366,
+                9577839109006017473,
+                512649272351700456,
+            ]),
+            Fq::from_montgomery_limbs([
+                13728510613345299749,
+                8310128050391117196,
+                5131170254842342916,
+                1207896680448936703,
+            ]),
+            Fq::from_montgomery_limbs([
+                3091333313056802823,
+                4377629957785447471,
+                18225359799841022457,
+                1155162353931776352,
+            ]),
+            Fq::from_montgomery_limbs([
+                6224394919677040944,
+                8071737779834952724,
+                15894060575622541293,
+                894273795543678099,
+            ]),
+            Fq::from_montgomery_limbs([
+                9965997920692636954,
+                94544479665108436,
+                14790855133451551725,
+                932651531089461645,
+            ]),
+            Fq::from_montgomery_limbs([
+                7358120144821681436,
+                9047687251430448843,
+                2064564485180177430,
+                1126298002442598512,
+            ]),
+            Fq::from_montgomery_limbs([
+                14929572220133668030,
+                6764620694825992777,
+                3900323022496509997,
+                1267760210788477363,
+            ]),
+            Fq::from_montgomery_limbs([
+                2489408408247706794,
+                8362122332600529229,
+                16237012330858855262,
+                91291497976993502,
+            ]),
+            Fq::from_montgomery_limbs([
+                12819213870705346529,
+                10212295234609775303,
+                739604426298077662,
+                625034044466699761,
+            ]),
+            Fq::from_montgomery_limbs([
+                18102461658878101377,
+                1096878721697593129,
+                2308201388338736000,
+                923104870212060135,
+            ]),
+            Fq::from_montgomery_limbs([
+                9345145330882887218,
+                2917562784025215707,
+                18009596186584047305,
+                767561527153548534,
+            ]),
+            Fq::from_montgomery_limbs([
+                3295646520665910811,
+                6445801378417730680,
+                6363953859143567708,
+                403510179493291556,
+            ]),
+            Fq::from_montgomery_limbs([
+                3554136981378198697,
+                213049513712632815,
+                311866224800745195,
+                257511134053240993,
+            ]),
+            Fq::from_montgomery_limbs([
+                8314042011179494824,
+                9771492611271630617,
+                1955453292664227167,
+                205804012042736840,
+            ]),
+            Fq::from_montgomery_limbs([
+                15063278187935419424,
+                16684367251529542820,
+                13343797128255945798,
+                1159952756469416125,
+            ]),
+            Fq::from_montgomery_limbs([
+                11407308773809864471,
+                16945726917925955539,
+                2715124961993505988,
+                447587979661540547,
+            ]),
+            Fq::from_montgomery_limbs([
+                4625733141788983578,
+                1899636403537535801,
+                3954949003002209788,
+                1037439310509002144,
+            ]),
+            Fq::from_montgomery_limbs([
+                16539523958017275617,
+                14386615830607494904,
+                17600206350071037013,
+                1100938684772643179,
+            ]),
+            Fq::from_montgomery_limbs([
+                12307524266843291198,
+                17772286704236242057,
+                4311316225432906567,
+                749316933638066978,
+            ]),
+            Fq::from_montgomery_limbs([
+                10096857659358495577,
+                11087716407272717269,
+                1789569176259746888,
+                641536497490595608,
+            ]),
+            Fq::from_montgomery_limbs([
+                17970033601972986804,
+                5385880017245708078,
+                17296333974366539899,
+                511429564355561248,
+            ]),
+            Fq::from_montgomery_limbs([
+                14434752189657712699,
+                12767171150134998742,
+                4509989501284134746,
+                767792717631748988,
+            ]),
+            Fq::from_montgomery_limbs([
+                710790064618257725,
+                5009900606346955283,
+                15824083626593169753,
+                783310663933303751,
+            ]),
+            Fq::from_montgomery_limbs([
+                13743481800760365381,
+                993305642323028785,
+                7572039857836383296,
+                95070444486630883,
+            ]),
+            Fq::from_montgomery_limbs([
+                10941968802375911949,
+                3566438665623514706,
+                15722662040840658368,
+                339537779848499886,
+            ]),
+            Fq::from_montgomery_limbs([
+                15523761425524403089,
+                2618689227268987282,
+                16005122962816831698,
+                818632395075849860,
+            ]),
+            Fq::from_montgomery_limbs([
+                4079340867919509944,
+                7678938651521148704,
+                5934647476489128699,
+                1121218720112705430,
+            ]),
+            Fq::from_montgomery_limbs([
+                4750046065880952607,
+                1705232726434372063,
+                8501500327627629774,
+                1025340295860189517,
+            ]),
+            Fq::from_montgomery_limbs([
+                17073801244067598355,
+                16364540616831998768,
+                11407149006875615981,
+                162725020342308854,
+            ]),
+            Fq::from_montgomery_limbs([
+                9301361723833337706,
+                3557105689309395115,
+                5882413944468208007,
+                1186175599356019463,
+            ]),
+            Fq::from_montgomery_limbs([
+                17482327684929155324,
+                14495292440255385539,
+                12244452663501087612,
+                599345707598670184,
+            ]),
+            Fq::from_montgomery_limbs([
+                11215967457839864612,
+                697402264842595666,
+                3725563252709563215,
+                647031623494303932,
+            ]),
+            Fq::from_montgomery_limbs([
+                8547640525987938973,
+                5857854164443042339,
+                17275799807717763622,
+                1137948625628139613,
+            ]),
+            Fq::from_montgomery_limbs([
+                9131892596472785979,
+                7087863015212216259,
+                14759296885426731843,
+                157736189608083101,
+            ]),
+            Fq::from_montgomery_limbs([
+                10569432202253631720,
+                4995168918816697300,
+                7737178782520633396,
+                1142278988859413390,
+            ]),
+            Fq::from_montgomery_limbs([
+                12477589213877658073,
+                1171223044225642814,
+                16948100475391147688,
+                316639805778585667,
+            ]),
+            Fq::from_montgomery_limbs([
+                10221212699232418734,
+                14959991134705378149,
+                1833584186501843713,
+                455964320710896465,
+            ]),
+            Fq::from_montgomery_limbs([
+                15324573417826514827,
+                8523791285063878536,
+                10634448318510209882,
+                356948236645044469,
+            ]),
+            Fq::from_montgomery_limbs([
+                13014150339333106113,
+                6446603028841474265,
+                14855798141317122083,
+                558554456838939584,
+            ]),
+            Fq::from_montgomery_limbs([
+                15166073727789463584,
+                12268918880930345431,
+                7488814183075658022,
+                1111933506093611029,
+            ]),
+            Fq::from_montgomery_limbs([
+                6315357798948280729,
+                7719320127134917870,
+                10657505484222192212,
+                960482923751824586,
+            ]),
+            Fq::from_montgomery_limbs([
+                252375795399154509,
+                1298574472716024721,
+                8875512735331563894,
+                1324873122855533456,
+            ]),
+            Fq::from_montgomery_limbs([
+                11421356399012077409,
+                6961547647417548558,
+                8582990370202859921,
+                235442174983788092,
+            ]),
+            Fq::from_montgomery_limbs([
+                14960318945788923769,
+                13586254057064125295,
+                10871780512420267157,
+                855001997740592543,
+            ]),
+            Fq::from_montgomery_limbs([
+                6901705652176583927,
+                9505002439762919934,
+                8905370357631744511,
+                254735432399038948,
+            ]),
+            Fq::from_montgomery_limbs([
+                2058765574577388265,
+                14702888334650608934,
+                11487462354925723847,
+                1068557246152345477,
+            ]),
+            Fq::from_montgomery_limbs([
+                9986365949649134146,
+                13237441143843654247,
+                4260674120843513755,
+                580152972841195201,
+            ]),
+            Fq::from_montgomery_limbs([
+                14665139426422474446,
+                17678532278123487740,
+                11208777951066700845,
+                606694109348895176,
+            ]),
+            Fq::from_montgomery_limbs([
+                7772767696192802724,
+                9050080886306627200,
+                5460857637038195342,
+                955620203831296059,
+            ]),
+            Fq::from_montgomery_limbs([
+                13238089622716078773,
+                1436218842048201702,
+                12105585333516776907,
+                1957390682194617,
+            ]),
+            Fq::from_montgomery_limbs([
+                2304121201022805582,
+                12451088002593484712,
+                17466340677740712954,
+                924318422967716258,
+            ]),
+            Fq::from_montgomery_limbs([
+                5448226549549511286,
+                14777010278822207603,
+                6785527281066730606,
+                93432315816485876,
+            ]),
+            Fq::from_montgomery_limbs([
+                2105320432314789698,
+                13819648878678801523,
+                15130146546625564669,
+                910798089488471190,
+            ]),
+            Fq::from_montgomery_limbs([
+                12555293840047971399,
+                9399318561862161338,
+                6199601932803751917,
+                431012788731533410,
+            ]),
+            Fq::from_montgomery_limbs([
+                4770880492894147432,
+                3325833076137215256,
+                1599377135782217711,
+                1182854609447238653,
+            ]),
+            Fq::from_montgomery_limbs([
+                12187770945128833376,
+                8359096137480313344,
+                4649020342106344488,
+                465682877949005470,
+            ]),
+            Fq::from_montgomery_limbs([
+                17747186896385987269,
+                14113769130530683404,
+                1861734079676321963,
+                677805820746504792,
+            ]),
+            Fq::from_montgomery_limbs([
+                4607523944577654192,
+                14688643835541632054,
+                17644009917202932143,
+                661970176770208440,
+            ]),
+            Fq::from_montgomery_limbs([
+                2183153255090639363,
+                7767684517239743062,
+                11716302843508588172,
+                476212559949166387,
+            ]),
+            Fq::from_montgomery_limbs([
+                13072712785469249303,
+                1127023340719227555,
+                6385229734567492107,
+                746097071682018515,
+            ]),
+            Fq::from_montgomery_limbs([
+                2470574255186571344,
+                12632783289664019755,
+                12953341569649069202,
+                1085551875720578193,
+            ]),
+            Fq::from_montgomery_limbs([
+                13903503482548562979,
+                16962382239925789983,
+                11166074941235292194,
+                948597768610099193,
+            ]),
+            Fq::from_montgomery_limbs([
+                7114975813724591079,
+                8109410219067371608,
+                6295275952331433892,
+                703439891093858337,
+            ]),
+            Fq::from_montgomery_limbs([
+                4313162025081629392,
+                6083296377717850233,
+                3100456688327439777,
+                1282966798506684694,
+            ]),
+            Fq::from_montgomery_limbs([
+                13988112774318667579,
+                1477290895028930702,
+                13499851584297070819,
+                262091275742864453,
+            ]),
+            Fq::from_montgomery_limbs([
+                9421189095844001600,
+                9911286330052136158,
+                4177646765009337695,
+                214337397408568079,
+            ]),
+            Fq::from_montgomery_limbs([
+                17133716923198321838,
+                17234390916779570034,
+                9819348678454311512,
+                639024865468752438,
+            ]),
+            Fq::from_montgomery_limbs([
+                3070215224562503911,
+                7377414471752251452,
+                14141678609237405551,
+                736707062475367453,
+            ]),
+            Fq::from_montgomery_limbs([
+                3458811534600322785,
+                16095875400294175498,
+                13018253181042886760,
+                394045573850603465,
+            ]),
+            Fq::from_montgomery_limbs([
+                5139643934272411916,
+                10003888055092884851,
+                12591231249967730036,
+                563420762743170476,
+            ]),
+            Fq::from_montgomery_limbs([
+                3402183298738548478,
+                12053529711173238985,
+                14536790428755164837,
+                863386023273975093,
+            ]),
+            Fq::from_montgomery_limbs([
+                1718526168657215270,
+                12687641304329181430,
+                12704016103507973256,
+                225600748582472582,
+            ]),
+            Fq::from_montgomery_limbs([
+                13599392069697861793,
+                3778758373155821003,
+                18444771036427862253,
+                649519607815156475,
+            ]),
+            Fq::from_montgomery_limbs([
+                17050744673076214912,
+                15318484573762586280,
+                10176472265310616296,
+                677590417798041422,
+            ]),
+            Fq::from_montgomery_limbs([
+                11369664710292529107,
+                2816258506297685320,
+                8076563442637906607,
+                219310402066160333,
+            ]),
+            Fq::from_montgomery_limbs([
+                4312847295040030500,
+                7893272514697454054,
+                2206767839648783637,
+                1236929276307447590,
+            ]),
+            Fq::from_montgomery_limbs([
+                8545712951960877560,
+                8620410760532911730,
+                4784935310311299464,
+                23487290137212638,
+            ]),
+            Fq::from_montgomery_limbs([
+                18410483981855212588,
+                5607582132908142239,
+                2283925138767934777,
+                1247509199855834887,
+            ]),
+            Fq::from_montgomery_limbs([
+                2496338928222676003,
+                7491038347364868054,
+                16550554251083191628,
+                1120579602839500611,
+            ]),
+            Fq::from_montgomery_limbs([
+                15590164970828051653,
+                5960635094787782754,
+                479875925197908325,
+                200962584707238394,
+            ]),
+            Fq::from_montgomery_limbs([
+                6761864142566456970,
+                8278652422654307007,
+                10142887209556895684,
+                307624967715344748,
+            ]),
+            Fq::from_montgomery_limbs([
+                3534623545183347952,
+                7984707508813201183,
+                10034368524745062747,
+                137164230575490065,
+            ]),
+            Fq::from_montgomery_limbs([
+                15761227217880562413,
+                13890873936979335517,
+                14123592139400820029,
+                969818069551186497,
+            ]),
+            Fq::from_montgomery_limbs([
+                17251466914715712292,
+                9196646029922074524,
+                8046204195753418242,
+                1319197091508149039,
+            ]),
+            Fq::from_montgomery_limbs([
+                8066069291552421135,
+                9613240239291160598,
+                6491264888207047981,
+                533493089130546757,
+            ]),
+            Fq::from_montgomery_limbs([
+                2682503914967642019,
+                11464990965625325401,
+                1003998960627202319,
+                780797158428428091,
+            ]),
+            Fq::from_montgomery_limbs([
+                3796270324897153659,
+                6026196594245439617,
+                17336756475521720817,
+                961802454236898073,
+            ]),
+            Fq::from_montgomery_limbs([
+                17001833551489400206,
+                3907701360575022564,
+                11338819902684821331,
+                616953184211509384,
+            ]),
+            Fq::from_montgomery_limbs([
+                2655782137076601479,
+                10470293751373727000,
+                12543306084596537903,
+                377495046872709620,
+            ]),
+            Fq::from_montgomery_limbs([
+                1839913704563798894,
+                2456850167910634037,
+                10512065346440419339,
+                1063131085804605650,
+            ]),
+            Fq::from_montgomery_limbs([
+                9101737341281919473,
+                8700826899431074422,
+                10835171197515131805,
+                1232451047822926885,
+            ]),
+            Fq::from_montgomery_limbs([
+                3483050994410593202,
+                11871268308973913011,
+                3656085240271772899,
+                645665777203286101,
+            ]),
+            Fq::from_montgomery_limbs([
+                284192640089459797,
+                9881430485859306131,
+                446129196093216898,
+                1296123879858937611,
+            ]),
+            Fq::from_montgomery_limbs([
+                14930180930154107678,
+                16555360996804719541,
+                6232580344054958596,
+                442170726825850230,
+            ]),
+            Fq::from_montgomery_limbs([
+                263511276650893371,
+                3573511116640861942,
+                9228933960353273850,
+                1015910284519151431,
+            ]),
+            Fq::from_montgomery_limbs([
+                16559848036018539370,
+                16830670411022477895,
+                13314617038549300291,
+                845640516979687208,
+            ]),
+            Fq::from_montgomery_limbs([
+                17791066994538861797,
+                12683166766519470762,
+                9372183164566748578,
+                978127980463655543,
+            ]),
+            Fq::from_montgomery_limbs([
+                13551129120153046958,
+                3196809350164190232,
+                12202382659558225053,
+                610941695506134523,
+            ]),
+            Fq::from_montgomery_limbs([
+                13607343210985424436,
+                1030478510746327716,
+                16412684333670500672,
+                642178433594416759,
+            ]),
+            Fq::from_montgomery_limbs([
+                10178819092601364007,
+                17588889355501191548,
+                7533539862769225391,
+                1311221177286623072,
+            ]),
+            Fq::from_montgomery_limbs([
+                7153359661390564299,
+                5111998898193147769,
+                6051877181106016100,
+                1082516987918678251,
+            ]),
+            Fq::from_montgomery_limbs([
+                892454513223754660,
+                7204263886264594121,
+                13277089520336671196,
+                866021840773304073,
+            ]),
+            Fq::from_montgomery_limbs([
+                14056767774798048347,
+                15361760923234954041,
+                10489510569197661254,
+                959149108733689369,
+            ]),
+            Fq::from_montgomery_limbs([
+                14403969547863698713,
+                10891479688973946787,
+                9288701644051664923,
+                706119757227218012,
+            ]),
+            Fq::from_montgomery_limbs([
+                18379703400864388228,
+                7513178069398664631,
+                4523387981003273304,
+                1303984215121154321,
+            ]),
+            Fq::from_montgomery_limbs([
+                12388720813424587165,
+                14659585181382038359,
+                1251037816933984783,
+                855403027873204843,
+            ]),
+            Fq::from_montgomery_limbs([
+                5255834879975443523,
+                4806011668329592524,
+                4173497945779073271,
+                140277503456244389,
+            ]),
+            Fq::from_montgomery_limbs([
+                5921314403700087229,
+                2326755606578203316,
+                7936741389431040730,
+                274866115311589019,
+            ]),
+            Fq::from_montgomery_limbs([
+                15654722668322824071,
+                1041907628966259955,
+                7509226373496648459,
+                708286017766542743,
+            ]),
+            Fq::from_montgomery_limbs([
+                16459858778808226757,
+                2083951089479803901,
+                8674037836808101049,
+                1292770526326455736,
+            ]),
+            Fq::from_montgomery_limbs([
+                7524597121579039823,
+                6079164329962247965,
+                1730487478941820988,
+                344014111947543062,
+            ]),
+            Fq::from_montgomery_limbs([
+                5477724010894593745,
+                648286338435980074,
+                2947828605481067922,
+                427900964220981876,
+            ]),
+            Fq::from_montgomery_limbs([
+                11611108139034212252,
+                5729186624628874347,
+                15894722164596278437,
+                356704800967456893,
+            ]),
+            Fq::from_montgomery_limbs([
+                18360499118453835083,
+                14634463085125320344,
+                16706505935561216424,
+                151079381175965203,
+            ]),
+            Fq::from_montgomery_limbs([
+                16048124274998015486,
+                11557342528744203636,
+                807585187054972222,
+                814423767311711099,
+            ]),
+            Fq::from_montgomery_limbs([
+                14193880073589047223,
+                14229904605411679744,
+                12667674757359827436,
+                139261185297823755,
+            ]),
+            Fq::from_montgomery_limbs([
+                14390127962953683079,
+                16177641929971100824,
+                2670953830669955050,
+                124973441731466101,
+            ]),
+            Fq::from_montgomery_limbs([
+                2437693421511821442,
+                5285398266575321225,
+                8447944141785821897,
+                954414238602492463,
+            ]),
+            Fq::from_montgomery_limbs([
+                14485371452017004705,
+                4034484126628614395,
+                12361904810594331781,
+                412053430449770015,
+            ]),
+            Fq::from_montgomery_limbs([
+                7476778573138784805,
+                15982563014789292898,
+                8464016229501004790,
+                455459941560255527,
+            ]),
+            Fq::from_montgomery_limbs([
+                9424539635736737431,
+                13676390993989884405,
+                6592065289270865946,
+                573803131460882037,
+            ]),
+            Fq::from_montgomery_limbs([
+                17752151389498555095,
+                7564527992317127467,
+                6004598849783228607,
+                952899622524701198,
+            ]),
+            Fq::from_montgomery_limbs([
+                3549874815309372288,
+                3236591213479157738,
+                3143742512672589422,
+                1184114186104399688,
+            ]),
+            Fq::from_montgomery_limbs([
+                5675812749159812385,
+                11110480352924640916,
+                324884639471545131,
+                608240505210476194,
+            ]),
+            Fq::from_montgomery_limbs([
+                1425577647519912829,
+                17969582564980698186,
+                5615693020119973760,
+                822891499376817405,
+            ]),
+            Fq::from_montgomery_limbs([
+                7565234196869780173,
+                6991296894832711797,
+                9958814080497591361,
+                1254365170169271082,
+            ]),
+            Fq::from_montgomery_limbs([
+                15989458320766968873,
+                2933826409659192991,
+                16830113370754510631,
+                203256999677115919,
+            ]),
+            Fq::from_montgomery_limbs([
+                1296679683231633839,
+                1380865455964251453,
+                6137894738776998510,
+                432162810124051293,
+            ]),
+            Fq::from_montgomery_limbs([
+                2052691787952702030,
+                12085712121787923330,
+                15501955175737463983,
+                689389659545135396,
+            ]),
+            Fq::from_montgomery_limbs([
+                14809845285497473540,
+                6938890534060090219,
+                11890263175308804524,
+                742731888367424135,
+            ]),
+            Fq::from_montgomery_limbs([
+                13831828991765299607,
+                17239800227598813519,
+                6833621851524958628,
+                783033898929476190,
+            ]),
+            Fq::from_montgomery_limbs([
+                14438548612579043382,
+                11890373977589556031,
+                5345617701674254692,
+                1113216613042179626,
+            ]),
+            Fq::from_montgomery_limbs([
+                11589841929087960839,
+                6394757401977602323,
+                4775248710846964283,
+                1084610594226909200,
+            ]),
+            Fq::from_montgomery_limbs([
+                12210349843343929400,
+                2262126867278351538,
+                16269003860022936561,
+                141941406671550387,
+            ]),
+            Fq::from_montgomery_limbs([
+                17030131827829718979,
+                4183191800710581667,
+                6934581409444537854,
+                185100916521456861,
+            ]),
+            Fq::from_montgomery_limbs([
+                16904152082586377475,
+                7752578175771963741,
+                2179977504425823417,
+                3817375002024176,
+            ]),
+            Fq::from_montgomery_limbs([
+                16313058237160636958,
+                7073096096712588321,
+                14678430659389878271,
+                796118400659685026,
+            ]),
+            Fq::from_montgomery_limbs([
+                14404326211725420577,
+                7864717004965600532,
+                7835261398704368669,
+                1178186827564403664,
+            ]),
+            Fq::from_montgomery_limbs([
+                775759423678849078,
+                16741445379101611001,
+                7431639938750446935,
+                954724644391398010,
+            ]),
+            Fq::from_montgomery_limbs([
+                18060740597581485325,
+                10504838483149313984,
+                9106939638446289608,
+                98849082380264165,
+            ]),
+            Fq::from_montgomery_limbs([
+                12827080225052001218,
+                15120521856284876489,
+                11028655022773535396,
+                536669971091827802,
+            ]),
+            Fq::from_montgomery_limbs([
+                14081575119449015279,
+                1687749454305352117,
+                17092174979576881331,
+                764502145130010226,
+            ]),
+            Fq::from_montgomery_limbs([
+                16993031935104678293,
+                9710967254762251805,
+                8647567466223794212,
+                972252496004878210,
+            ]),
+            Fq::from_montgomery_limbs([
+                6062272989326441806,
+                13116525613215800071,
+                144176214737694415,
+                257204894690238430,
+            ]),
+            Fq::from_montgomery_limbs([
+                3738337495045545779,
+                5680716649054212071,
+                16408608791787722393,
+                816794560251263565,
+            ]),
+            Fq::from_montgomery_limbs([
+                11443485894539443758,
+                3997988250858096465,
+                7909912961441099073,
+                702280182601598950,
+            ]),
+            Fq::from_montgomery_limbs([
+                418432870696740254,
+                6968447439854970304,
+                5902883278082396316,
+                113083678267339095,
+            ]),
+            Fq::from_montgomery_limbs([
+                1562465224484652670,
+                8222248699547121941,
+                16407416470269002451,
+                680531342179468890,
+            ]),
+            Fq::from_montgomery_limbs([
+                9254275620959128238,
+                5673953472088930196,
+                5321291860434730798,
+                219451821807968530,
+            ]),
+            Fq::from_montgomery_limbs([
+                1754304588136102854,
+                15226136027391832362,
+                11962980494945222607,
+                637545680024628249,
+            ]),
+            Fq::from_montgomery_limbs([
+                12425673638225733549,
+                13122410034995040018,
+                10337313315063792158,
+                474523019933417214,
+            ]),
+            Fq::from_montgomery_limbs([
+                12827630562661526870,
+                13694258082503095471,
+                415810868492449416,
+                835324145519689059,
+            ]),
+            Fq::from_montgomery_limbs([
+                10997030696301836638,
+                2400493668593548611,
+                9764158502682661422,
+                164017206633426327,
+            ]),
+            Fq::from_montgomery_limbs([
+                13636815262545706532,
+                10372325011270277536,
+                17913306330097411997,
+                732653079738258582,
+            ]),
+            Fq::from_montgomery_limbs([
+                13998083769393970514,
+                15370723697050544107,
+                13962205746380166335,
+                214085421073633899,
+            ]),
+            Fq::from_montgomery_limbs([
+                7339251764373340564,
+                17163233818179653203,
+                5127975654218443332,
+                8992459381469458,
+            ]),
+            Fq::from_montgomery_limbs([
+                12996532251103239499,
+                790255188693757380,
+                6345267751939348723,
+                362702180657849768,
+            ]),
+            Fq::from_montgomery_limbs([
+                5296895825812399270,
+                5020549523809883616,
+                15989580549671479238,
+                691650391118409490,
+            ]),
+            Fq::from_montgomery_limbs([
+                6439185527102459722,
+                1954252780376662429,
+                17260248926519346907,
+                950777802665744618,
+            ]),
+            Fq::from_montgomery_limbs([
+                5336180413987700486,
+                1258067375390621751,
+                1729269070336270351,
+                1094682475068457159,
+            ]),
+            Fq::from_montgomery_limbs([
+                8223461288461728095,
+                7450997839220783258,
+                13271514200524631066,
+                796055840905686956,
+            ]),
+            Fq::from_montgomery_limbs([
+                3409970994279739958,
+                9544602782274347541,
+                8563308505573418594,
+                799088252102778316,
+            ]),
+            Fq::from_montgomery_limbs([
+                15867185482546566036,
+                724941163877346457,
+                1549672457488902491,
+                278104016216739645,
+            ]),
+            Fq::from_montgomery_limbs([
+                608482874130229632,
+                7359229826950401213,
+                1274650996920868597,
+                876587414981094087,
+            ]),
+            Fq::from_montgomery_limbs([
+                816214301034922190,
+                18069937935912491159,
+                6412348716002722868,
+                323350352463685920,
+            ]),
+            Fq::from_montgomery_limbs([
+                17188389153411581133,
+                5813265751438263854,
+                5263707117823691620,
+                874123192755224182,
+            ]),
+            Fq::from_montgomery_limbs([
+                10812177209766115936,
+                9723347461307706524,
+                5598218643059225456,
+                901341679309490727,
+            ]),
+            Fq::from_montgomery_limbs([
+                15489705677050583810,
+                9076123616848375773,
+                17472059659319245903,
+                517657559208263213,
+            ]),
+            Fq::from_montgomery_limbs([
+                5010705053009599294,
+                9057001897038279552,
+                14350317662651517699,
+                124555357361539265,
+            ]),
+            Fq::from_montgomery_limbs([
+                10474087931070131996,
+                13655843487955032502,
+                4715605577251605017,
+                1217762613884199623,
+            ]),
+            Fq::from_montgomery_limbs([
+                11612488974354651126,
+                16653656181722669023,
+                9553140280562048541,
+                872080550209250673,
+            ]),
+            Fq::from_montgomery_limbs([
+                12460348723330084176,
+                8929325875808369689,
+                17579055255489599126,
+                588123052771493815,
+            ]),
+            Fq::from_montgomery_limbs([
+                5525539191403812266,
+                8331528131706627507,
+                16372368447378485169,
+                668275373610908392,
+            ]),
+            Fq::from_montgomery_limbs([
+                3507407807263063343,
+                4289971311780948157,
+                12868353558360266078,
+                466676210442847153,
+            ]),
+            Fq::from_montgomery_limbs([
+                213707224014821120,
+                9740356787157795865,
+                1569312297609185534,
+                1150538816895932368,
+            ]),
+            Fq::from_montgomery_limbs([
+                7342343070882613631,
+                2162055145190167936,
+                927652305383557154,
+                971018477088813962,
+            ]),
+            Fq::from_montgomery_limbs([
+                15789741458518058782,
+                12964875420160888139,
+                10510046760997475930,
+                1073039198636566382,
+            ]),
+            Fq::from_montgomery_limbs([
+                6344501002766418801,
+                12738571773211861109,
+                4659680961596268250,
+                879150108360713188,
+            ]),
+            Fq::from_montgomery_limbs([
+                4387525817111633545,
+                10434016922792561780,
+                10832712908027732572,
+                309462962230473448,
+            ]),
+            Fq::from_montgomery_limbs([
+                4290313417131556586,
+                15947310344726583401,
+                4394662184054052698,
+                265505508180073167,
+            ]),
+            Fq::from_montgomery_limbs([
+                843164661460988707,
+                2212642652142331225,
+                5404371316283468515,
+                266090379863717530,
+            ]),
+            Fq::from_montgomery_limbs([
+                6169213604765302998,
+                3167840768002248260,
+                11196047726574697770,
+                461038706665460270,
+            ]),
+            Fq::from_montgomery_limbs([
+                4966797353008877472,
+                8072442858304711684,
+                1381152478960875150,
+                424982770107550815,
+            ]),
+            Fq::from_montgomery_limbs([
+                9314953282175213618,
+                3997138538738266113,
+                15470863078715710979,
+                513454665355403120,
+            ]),
+            Fq::from_montgomery_limbs([
+                16671356181030910395,
+                7698172729247338011,
+                10035609683697586510,
+                923792851567705903,
+            ]),
+            Fq::from_montgomery_limbs([
+                9161817327288808447,
+                6808031217060986112,
+                11236211024684055947,
+                193236099773899824,
+            ]),
+            Fq::from_montgomery_limbs([
+                2112953209361493445,
+                14567344285497736408,
+                10393611026470684490,
+                818412145340083021,
+            ]),
+            Fq::from_montgomery_limbs([
+                5598270893248388153,
+                2725464528254615759,
+                1244980742497482482,
+                484471427693273877,
+            ]),
+            Fq::from_montgomery_limbs([
+                9611688901643823433,
+                4639719913571819304,
+                1282715916921785288,
+                233213282074630000,
+            ]),
+            Fq::from_montgomery_limbs([
+                1300075729102534828,
+                1697317428231148629,
+                3276660752790359844,
+                551557939493090952,
+            ]),
+            Fq::from_montgomery_limbs([
+                300951947864586569,
+                17482401574010200793,
+                15572189153274200650,
+                261551271244955483,
+            ]),
+            Fq::from_montgomery_limbs([
+                14173096757897250291,
+                13624508334754375039,
+                3623848092962274983,
+                1096416220161722408,
+            ]),
+            Fq::from_montgomery_limbs([
+                724138993446350279,
+                3859932524082249187,
+                7160090719428560107,
+                792248459168645121,
+            ]),
+            Fq::from_montgomery_limbs([
+                3462887361949967104,
+                2324862138590333326,
+                2442347065615075586,
+                1311555130220922962,
+            ]),
+            Fq::from_montgomery_limbs([
+                10066734742863701748,
+                11249857355121971855,
+                9008446223800720140,
+                675818199499485459,
+            ]),
+            Fq::from_montgomery_limbs([
+                14470572568289009504,
+                9392976287124498741,
+                2423243339034222116,
+                16746542123171581,
+            ]),
+            Fq::from_montgomery_limbs([
+                17364939630744543540,
+                4530203364407601007,
+                17823628929341986962,
+                299133848979813441,
+            ]),
+            Fq::from_montgomery_limbs([
+                18104733833256855663,
+                16907085263096812160,
+                14918311529407294380,
+                1193173655784091825,
+            ]),
+            Fq::from_montgomery_limbs([
+                2240188250748042933,
+                2330905068769744651,
+                7173755778798925985,
+                1328778991255071162,
+            ]),
+            Fq::from_montgomery_limbs([
+                9298401511281208269,
+                8296308864418881993,
+                12284946657443206837,
+                397694005629325237,
+            ]),
+            Fq::from_montgomery_limbs([
+                911331752753095002,
+                15268729140706689839,
+                4939748207148225197,
+                446993631367035256,
+            ]),
+            Fq::from_montgomery_limbs([
+                15364409830431045498,
+                6581947434408518191,
+                11931871841608290455,
+                312565320431941231,
+            ]),
+            Fq::from_montgomery_limbs([
+                7814917023190386334,
+                7003235516528371196,
+                13622685304251907762,
+                329375473336820891,
+            ]),
+            Fq::from_montgomery_limbs([
+                16221200510762183673,
+                12953566282926396809,
+                13267226616785962314,
+                924998178078667245,
+            ]),
+            Fq::from_montgomery_limbs([
+                834921079042012935,
+                2906670601218583150,
+                8096334608205195359,
+                715541099470154436,
+            ]),
+            Fq::from_montgomery_limbs([
+                4822720132097488001,
+                5000547150135924497,
+                4936301903749188209,
+                300990992110901034,
+            ]),
+            Fq::from_montgomery_limbs([
+                3994866362227794858,
+                17576131741545593277,
+                9429711295987252900,
+                754404337300119826,
+            ]),
+            Fq::from_montgomery_limbs([
+                3831992202636983923,
+                4656089316794369239,
+                2959282481052830686,
+                275443144604636284,
+            ]),
+            Fq::from_montgomery_limbs([
+                1159810578250436235,
+                8345530137192169966,
+                17401070035168551012,
+                335645952438832892,
+            ]),
+            Fq::from_montgomery_limbs([
+                3384872281775403431,
+                8244729474207701825,
+                4585103025418764862,
+                524965665991024996,
+            ]),
+            Fq::from_montgomery_limbs([
+                4668966353697657576,
+                8392099613454296958,
+                2946721836108699165,
+                150677725137520208,
+            ]),
+            Fq::from_montgomery_limbs([
+                11135159524124258435,
+                6556397599869267903,
+                14465929194787571568,
+                438073427737072390,
+            ]),
+            Fq::from_montgomery_limbs([
+                12254771292693841809,
+                6406088491683725580,
+                17096131097869316539,
+                581503009090506743,
+            ]),
+            Fq::from_montgomery_limbs([
+                14029340952958409397,
+                4880768904539366701,
+                7307346752039708407,
+                318694223058439960,
+            ]),
+            Fq::from_montgomery_limbs([
+                6844907622164681640,
+                4812719005457454428,
+                16820810234001397216,
+                625135633305602995,
+            ]),
+            Fq::from_montgomery_limbs([
+                15129643521841599498,
+                315520946360994124,
+                6651694545276240938,
+                868430336433207833,
+            ]),
+            Fq::from_montgomery_limbs([
+                14212579311996119421,
+                13561534027109343637,
+                13841470238359534845,
+                1229177744855298733,
+            ]),
+            Fq::from_montgomery_limbs([
+                11372591395250285100,
+                5816605963155037398,
+                15865388542781274054,
+                626727936342520503,
+            ]),
+            Fq::from_montgomery_limbs([
+                10045738240851143016,
+                15990306830558125609,
+                3256289665031559089,
+                1315941841856825682,
+            ]),
+            Fq::from_montgomery_limbs([
+                14553503867605775201,
+                8589276377093410635,
+                1142470697812890430,
+                228635421645013615,
+            ]),
+            Fq::from_montgomery_limbs([
+                7240260040474097295,
+                358453286569104125,
+                10470695518321211205,
+                1042691280151598983,
+            ]),
+            Fq::from_montgomery_limbs([
+                627049947197052339,
+                17576744789586710838,
+                16299696149320211019,
+                851665565211756956,
+            ]),
+            Fq::from_montgomery_limbs([
+                3923814186608060728,
+                688922988440693799,
+                2315441010434638020,
+                1088075623998745838,
+            ]),
+            Fq::from_montgomery_limbs([
+                5617863164963944581,
+                9187983487862703719,
+                15399903307679323117,
+                913729544147786422,
+            ]),
+            Fq::from_montgomery_limbs([
+                1062635153623101104,
+                18438674059440530007,
+                16663204341205801403,
+                40572614785456049,
+            ]),
+            Fq::from_montgomery_limbs([
+                17013434328751755590,
+                9709271508581224877,
+                6146190091811901523,
+                194550497474594788,
+            ]),
+            Fq::from_montgomery_limbs([
+                8742133004973430255,
+                5411451357670189024,
+                7531482040679858469,
+                723573746826433624,
+            ]),
+            Fq::from_montgomery_limbs([
+                13414463033680035227,
+                1020419725818415008,
+                15188457498561102172,
+                723116797668393064,
+            ]),
+            Fq::from_montgomery_limbs([
+                18237386705149319484,
+                3528178417840040854,
+                10083953920501309935,
+                836012502034363141,
+            ]),
+            Fq::from_montgomery_limbs([
+                2261933852632878390,
+                16630160714273948447,
+                7462092307134599673,
+                149814917207736306,
+            ]),
+            Fq::from_montgomery_limbs([
+                12413479169772865773,
+                6473692980455832249,
+                7178108960267362605,
+                1285409099188124145,
+            ]),
+            Fq::from_montgomery_limbs([
+                7241284686311301369,
+                8236127205186762848,
+                4776044384460579393,
+                447912146572363866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7168365553011763867,
+                4447140312202875514,
+                18547898924309809,
+                1053081130286615702,
+            ]),
+            Fq::from_montgomery_limbs([
+                5009099473820200526,
+                1347903082005488785,
+                1400292223001456982,
+                321461749911659671,
+            ]),
+            Fq::from_montgomery_limbs([
+                3786344644150362117,
+                9979668694648189638,
+                12077334792418701717,
+                498388254571601045,
+            ]),
+            Fq::from_montgomery_limbs([
+                15826394119898373695,
+                10042071305728543228,
+                2974967150499197869,
+                725749828597702908,
+            ]),
+            Fq::from_montgomery_limbs([
+                12562703400343540133,
+                13610852133073301206,
+                12272383371662855394,
+                673795865447983705,
+            ]),
+            Fq::from_montgomery_limbs([
+                18066435880890032971,
+                1110295579970132721,
+                16144946331936911364,
+                380257574420049789,
+            ]),
+            Fq::from_montgomery_limbs([
+                3869355135057889314,
+                4582705753160572672,
+                4914317516849186406,
+                455345486861071169,
+            ]),
+            Fq::from_montgomery_limbs([
+                10345365930028288080,
+                16100588063960367850,
+                9256378995109517125,
+                610170079666531784,
+            ]),
+            Fq::from_montgomery_limbs([
+                6081096305804324809,
+                17308167331919317981,
+                11255540472851211165,
+                1091767628765135827,
+            ]),
+            Fq::from_montgomery_limbs([
+                9127639341061233500,
+                13958192611292732039,
+                13501068176944459985,
+                1274221448303243174,
+            ]),
+            Fq::from_montgomery_limbs([
+                7271211659987649616,
+                3112036676856391669,
+                1386945622391659812,
+                213652760496487951,
+            ]),
+            Fq::from_montgomery_limbs([
+                1251016529975276904,
+                16817330127446319173,
+                16739131879379771488,
+                43819330587191669,
+            ]),
+            Fq::from_montgomery_limbs([
+                14890567911688141632,
+                194743354362545460,
+                3106245116036555853,
+                387924354457924067,
+            ]),
+            Fq::from_montgomery_limbs([
+                13830317449875211576,
+                9347580099995269440,
+                13501846572118092267,
+                602777243785962691,
+            ]),
+            Fq::from_montgomery_limbs([
+                6925878737113706118,
+                16917171941527749900,
+                2064792082763564791,
+                1020048910110404445,
+            ]),
+            Fq::from_montgomery_limbs([
+                1444079223039459413,
+                13613211618860198396,
+                10220160637817551698,
+                211627571250418949,
+            ]),
+            Fq::from_montgomery_limbs([
+                13351873186444020480,
+                16321122314990270225,
+                11335009054924041567,
+                439264144960590963,
+            ]),
+            Fq::from_montgomery_limbs([
+                12556387720310274140,
+                2977816995243291316,
+                17126536468509170368,
+                1143629248740186581,
+            ]),
+            Fq::from_montgomery_limbs([
+                15325490448358106288,
+                15969088434543139623,
+                6567306820705964918,
+                130541232225319806,
+            ]),
+            Fq::from_montgomery_limbs([
+                4982816095514687031,
+                15869783885283086525,
+                5644666990771788684,
+                88305554789993315,
+            ]),
+            Fq::from_montgomery_limbs([
+                17533117179078446247,
+                11303497198987752484,
+                12704305230785558151,
+                1275404579749897632,
+            ]),
+            Fq::from_montgomery_limbs([
+                13029806760658268640,
+                5007395681746089824,
+                6150753438558132777,
+                737117225585461306,
+            ]),
+            Fq::from_montgomery_limbs([
+                15422571164383624810,
+                2563398056307370935,
+                14218960541294611083,
+                382271885464830326,
+            ]),
+            Fq::from_montgomery_limbs([
+                11718308955703851993,
+                7646550394623279888,
+                1912192626932248461,
+                48707998752801305,
+            ]),
+            Fq::from_montgomery_limbs([
+                7622126137877336519,
+                18015443433815698508,
+                3136218389486559725,
+                498812560897789153,
+            ]),
+            Fq::from_montgomery_limbs([
+                18055188253755111031,
+                3387976333657628402,
+                17259089409844070016,
+                664710153047914461,
+            ]),
+            Fq::from_montgomery_limbs([
+                15593486457459313914,
+                6798009389898061806,
+                15310619695665924196,
+                668684473457992168,
+            ]),
+            Fq::from_montgomery_limbs([
+                16119516630897456416,
+                5777358469149471415,
+                15436115904021885576,
+                1082588253406017792,
+            ]),
+            Fq::from_montgomery_limbs([
+                3953428760615101518,
+                14524769980114981870,
+                11228244097424177189,
+                702546377837508448,
+            ]),
+            Fq::from_montgomery_limbs([
+                8291657460670033882,
+                14361056556526350095,
+                10946589464299691052,
+                109876258382804601,
+            ]),
+            Fq::from_montgomery_limbs([
+                12675969968106805488,
+                11931919113240301298,
+                14927213204426010086,
+                529338963711683909,
+            ]),
+            Fq::from_montgomery_limbs([
+                7039184708834218252,
+                15867921595368206777,
+                3990374057104775726,
+                1289106637004753942,
+            ]),
+            Fq::from_montgomery_limbs([
+                7349183471416349312,
+                9321285618773220351,
+                3288957746484766350,
+                764427608410795149,
+            ]),
+            Fq::from_montgomery_limbs([
+                13144009898136199079,
+                13987417659702796565,
+                13196826149466899483,
+                70293074437967358,
+            ]),
+            Fq::from_montgomery_limbs([
+                17460274557855079998,
+                4404328621717450714,
+                15888732955948319673,
+                332716590311132191,
+            ]),
+            Fq::from_montgomery_limbs([
+                12634076982005653008,
+                13463869851021703655,
+                17532388782893711551,
+                592622090276579659,
+            ]),
+            Fq::from_montgomery_limbs([
+                2602993583441955650,
+                683641409558506794,
+                15067941081367884721,
+                674045201505478867,
+            ]),
+            Fq::from_montgomery_limbs([
+                1205635412771082576,
+                13738348017499336174,
+                12093496715738210489,
+                40255553081353921,
+            ]),
+            Fq::from_montgomery_limbs([
+                4300149040499847108,
+                4442201169362235311,
+                12391238694722414965,
+                727206272155255883,
+            ]),
+            Fq::from_montgomery_limbs([
+                17266177947712679371,
+                18124606531132854668,
+                4422381094755860196,
+                644268272894653623,
+            ]),
+            Fq::from_montgomery_limbs([
+                14082419354662038739,
+                17447454248437073210,
+                3371504635486311669,
+                1052640004773381587,
+            ]),
+            Fq::from_montgomery_limbs([
+                10674716171519201217,
+                5694870961858484714,
+                13574941684034380349,
+                1214283968453089387,
+            ]),
+            Fq::from_montgomery_limbs([
+                13880638372043872203,
+                7675140470819475334,
+                2338090663866902889,
+                743324122955300493,
+            ]),
+            Fq::from_montgomery_limbs([
+                16616328477756684569,
+                3348113125067161118,
+                6774144650398967662,
+                66423403911101928,
+            ]),
+            Fq::from_montgomery_limbs([
+                1644369671958112268,
+                14154386778414401557,
+                15776915790375951347,
+                880192672088834300,
+            ]),
+            Fq::from_montgomery_limbs([
+                8281070594472187002,
+                7168929900038711206,
+                16323864123133626725,
+                428392371929237231,
+            ]),
+            Fq::from_montgomery_limbs([
+                3344282822679102630,
+                15826852601131250814,
+                18188587566222798091,
+                626478011111452177,
+            ]),
+            Fq::from_montgomery_limbs([
+                14581413569181693916,
+                14153031045627428477,
+                3426064204587557569,
+                251139660374802005,
+            ]),
+            Fq::from_montgomery_limbs([
+                11769088695186676536,
+                12780561781953914948,
+                17411343527744822923,
+                1331552458998605237,
+            ]),
+            Fq::from_montgomery_limbs([
+                1270199254723694211,
+                17909252509737368707,
+                6537469158728335388,
+                1125950286706333671,
+            ]),
+            Fq::from_montgomery_limbs([
+                10851657563163882835,
+                8012827362099444173,
+                10004819102116917076,
+                718037534840673006,
+            ]),
+            Fq::from_montgomery_limbs([
+                8441129291679788021,
+                9159003564943636650,
+                16823962463255696822,
+                59474083590522397,
+            ]),
+            Fq::from_montgomery_limbs([
+                3204872479883846897,
+                13248491082410999812,
+                2905504368707349961,
+                628358118370959837,
+            ]),
+            Fq::from_montgomery_limbs([
+                447035237762915028,
+                17683256785274495110,
+                17353193265163542649,
+                255945238869233149,
+            ]),
+            Fq::from_montgomery_limbs([
+                12797320625500017526,
+                16584911710461616455,
+                10028530222650947952,
+                739150014990604305,
+            ]),
+            Fq::from_montgomery_limbs([
+                17376131328413684839,
+                17456534300377390143,
+                5908841704086266715,
+                1297558529603669878,
+            ]),
+            Fq::from_montgomery_limbs([
+                16296231166456569839,
+                2121067793577399950,
+                5609622278204437731,
+                1077515161038726885,
+            ]),
+            Fq::from_montgomery_limbs([
+                4342317609299204385,
+                11743897850399821520,
+                1890640881650927421,
+                220582451993074739,
+            ]),
+            Fq::from_montgomery_limbs([
+                9353606679172325449,
+                52060774412836401,
+                4991483155150091329,
+                976474133330102657,
+            ]),
+            Fq::from_montgomery_limbs([
+                12731705612005704353,
+                16667434429686139645,
+                13460857561080239361,
+                541340390248033375,
+            ]),
+            Fq::from_montgomery_limbs([
+                12084997276726617183,
+                5160013951003710687,
+                5892074447804977160,
+                1071526049262269265,
+            ]),
+            Fq::from_montgomery_limbs([
+                9957354266305047958,
+                7617068754601675103,
+                7794172222990643845,
+                1042652241588330771,
+            ]),
+            Fq::from_montgomery_limbs([
+                17937210056061802380,
+                4127927024179262585,
+                2754402947067411131,
+                173834493254513539,
+            ]),
+            Fq::from_montgomery_limbs([
+                11600058646239439204,
+                12147476296672082959,
+                8382630275644499593,
+                1064938170710798602,
+            ]),
+            Fq::from_montgomery_limbs([
+                3277519320508002431,
+                5181710722288575103,
+                15809534180011044578,
+                1300183406738622388,
+            ]),
+            Fq::from_montgomery_limbs([
+                8698613017707976184,
+                12938393398616366991,
+                7472727461689278674,
+                556668091286893493,
+            ]),
+            Fq::from_montgomery_limbs([
+                13970485143727280940,
+                6134776590988703905,
+                13380597222146608436,
+                1187518521619907760,
+            ]),
+            Fq::from_montgomery_limbs([
+                12918316757551477305,
+                16656415759589887695,
+                15946808219830715761,
+                422517797348814411,
+            ]),
+            Fq::from_montgomery_limbs([
+                2848597640441580058,
+                524724755183692973,
+                16006498323563405235,
+                126687251985211325,
+            ]),
+            Fq::from_montgomery_limbs([
+                18371292593562659538,
+                1768181923538788290,
+                18196294911697289731,
+                546386132264899131,
+            ]),
+            Fq::from_montgomery_limbs([
+                12565916870343280604,
+                4719806775418042250,
+                9268759002568850608,
+                857283793569542841,
+            ]),
+            Fq::from_montgomery_limbs([
+                13506052259667953337,
+                2630987589599440761,
+                2187077608214970450,
+                141839515279816533,
+            ]),
+            Fq::from_montgomery_limbs([
+                3179608595359288001,
+                1789108272608736026,
+                7518927380750473328,
+                154675111155940010,
+            ]),
+            Fq::from_montgomery_limbs([
+                1456219113857893395,
+                4464106645847553783,
+                12914469701711590009,
+                1126069924153909720,
+            ]),
+            Fq::from_montgomery_limbs([
+                10730533131046804168,
+                863055231130510993,
+                15470858811836137843,
+                114427902963614568,
+            ]),
+            Fq::from_montgomery_limbs([
+                1527636679737847491,
+                15415609664200649127,
+                16563577550115140186,
+                90284266777654489,
+            ]),
+            Fq::from_montgomery_limbs([
+                16913186060169146935,
+                6349761876517996871,
+                7747159587438340531,
+                1035848188622321096,
+            ]),
+            Fq::from_montgomery_limbs([
+                7835053111969954263,
+                16820852943434756385,
+                17272424019113957388,
+                1236763857696392509,
+            ]),
+            Fq::from_montgomery_limbs([
+                2641017158057542424,
+                8088109139214981249,
+                10648680839827430394,
+                1239720612199315471,
+            ]),
+            Fq::from_montgomery_limbs([
+                663822900835126190,
+                14991330423050095469,
+                10367359378114337833,
+                470669558652977696,
+            ]),
+            Fq::from_montgomery_limbs([
+                14171880865822974992,
+                16070500032304434644,
+                17644373414523963876,
+                305668782344439245,
+            ]),
+            Fq::from_montgomery_limbs([
+                7743023723769995452,
+                2564271715752698834,
+                1394053445177365637,
+                1037747386974602566,
+            ]),
+            Fq::from_montgomery_limbs([
+                7080029216048359518,
+                48379455397819083,
+                9663210595515435810,
+                214724385640262733,
+            ]),
+            Fq::from_montgomery_limbs([
+                10644272124190472665,
+                2609240279992051233,
+                10768054913430240226,
+                1242111146951188196,
+            ]),
+            Fq::from_montgomery_limbs([
+                121445502891508084,
+                7504258445104395803,
+                481969538743407715,
+                330856110928688454,
+            ]),
+            Fq::from_montgomery_limbs([
+                3330354334899119016,
+                5680814955688298453,
+                7763858231549043435,
+                1124051223925617827,
+            ]),
+            Fq::from_montgomery_limbs([
+                3326771298019902008,
+                13602013693461148333,
+                10741020990723760968,
+                1271844507634107566,
+            ]),
+            Fq::from_montgomery_limbs([
+                8690212218286238375,
+                927745461831578232,
+                13421327456723786819,
+                159488321227943348,
+            ]),
+            Fq::from_montgomery_limbs([
+                17599332383237777431,
+                4558948867047711296,
+                15021163160450407402,
+                868430297425540605,
+            ]),
+            Fq::from_montgomery_limbs([
+                12726291304620661753,
+                15109041339577748426,
+                3096906421633314528,
+                767833436354854135,
+            ]),
+            Fq::from_montgomery_limbs([
+                8961544951960859615,
+                14490621325234423672,
+                6324781297580413548,
+                1118402992856114548,
+            ]),
+            Fq::from_montgomery_limbs([
+                15176984725861658665,
+                15572540036967107143,
+                17723383547646753304,
+                382225397373402247,
+            ]),
+            Fq::from_montgomery_limbs([
+                5600626867135564547,
+                172394935647799179,
+                8627912323397679908,
+                498880909219013084,
+            ]),
+            Fq::from_montgomery_limbs([
+                11044136449062236757,
+                3675513540667772389,
+                10403291466917188770,
+                680313133917354614,
+            ]),
+            Fq::from_montgomery_limbs([
+                18240929854168152412,
+                12522705135628111592,
+                12137985104438880852,
+                723973623997246671,
+            ]),
+            Fq::from_montgomery_limbs([
+                5346421101899223291,
+                14464020279717460204,
+                12890929165519274591,
+                153057119535473451,
+            ]),
+            Fq::from_montgomery_limbs([
+                12708364026105473943,
+                17507882706073383313,
+                17076845488395978546,
+                285630270509338361,
+            ]),
+            Fq::from_montgomery_limbs([
+                4311014241424974595,
+                2597829159821508114,
+                13709411907402934925,
+                1216701736636980855,
+            ]),
+            Fq::from_montgomery_limbs([
+                13622801840912941635,
+                12187816420238353803,
+                3270820642096473684,
+                445610528937068021,
+            ]),
+            Fq::from_montgomery_limbs([
+                15516718388705914847,
+                8055638147130353016,
+                10660750061586223561,
+                157152788599740362,
+            ]),
+            Fq::from_montgomery_limbs([
+                12243093046064441802,
+                375162848472553139,
+                14387808297813043979,
+                853640194603645165,
+            ]),
+            Fq::from_montgomery_limbs([
+                13894794537575244334,
+                10312141573450940832,
+                567719020459864488,
+                691070082768451815,
+            ]),
+            Fq::from_montgomery_limbs([
+                6433364813878257669,
+                11003170772670092673,
+                10003688689640762376,
+                1252273632601354595,
+            ]),
+            Fq::from_montgomery_limbs([
+                3432145370762408712,
+                9582851918690526704,
+                6055418213616776825,
+                993173746789152862,
+            ]),
+            Fq::from_montgomery_limbs([
+                17272225351137104778,
+                8996255204110128973,
+                9890500128700187444,
+                464009671025052445,
+            ]),
+            Fq::from_montgomery_limbs([
+                9471706413599736295,
+                10213833235592502275,
+                2868636637173571618,
+                398886672275717850,
+            ]),
+            Fq::from_montgomery_limbs([
+                15553741239031421279,
+                1471159805139835991,
+                13991200889516256017,
+                1084867736955083774,
+            ]),
+            Fq::from_montgomery_limbs([
+                1680273315461040394,
+                3965462129978292961,
+                4060824080207859997,
+                575618286886351616,
+            ]),
+            Fq::from_montgomery_limbs([
+                2295964613247445697,
+                16310404165352173987,
+                5164606945173116022,
+                274497340840497631,
+            ]),
+            Fq::from_montgomery_limbs([
+                14174733092413918289,
+                3869887476772607057,
+                17129368339331753665,
+                739915739005182275,
+            ]),
+            Fq::from_montgomery_limbs([
+                17987399906267294068,
+                17049275721583952838,
+                10375124627296736955,
+                240727953269509818,
+            ]),
+            Fq::from_montgomery_limbs([
+                1266982565785711339,
+                2507891683418070900,
+                18195035991845653068,
+                1201646487146078623,
+            ]),
+            Fq::from_montgomery_limbs([
+                4271203785925431274,
+                8184114758003709238,
+                3997475248767393053,
+                1227060835958076741,
+            ]),
+            Fq::from_montgomery_limbs([
+                9357773245180430970,
+                11769548726485183761,
+                10178263776277285696,
+                534936304275471796,
+            ]),
+        ]),
+        mds: MdsMatrix::<11, 10, 121, 100>::new_from_known([
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+        ]),
+        alpha: Alpha::Exponent(17),
+        rounds: RoundNumbers { r_P: 31, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<10, 100>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+            ]),
+            v: Matrix::<1, 10, 10>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+            ]),
+            w: Matrix::<10, 1, 10>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<11, 121>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<11, 121>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2124666949204268122,
+                    17868549573926278224,
+                    13377648236432012289,
+                    76508185047532167,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12468529521233858987,
+                    10711505901518971070,
+                    4598818191680682009,
+                    1253573039997503216,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7031960978200403191,
+                    2574615666237673190,
+                    17097789064224877311,
+                    611416421827088460,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    16116001355017634012,
+                    3797642348156488161,
+                    8442797550372214426,
+                    570942751708628769,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7550425512774939835,
+                    12155741381469351100,
+                    17744221774895938952,
+                    1039110227269251336,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8828528873936079174,
+                    16497727693982402703,
+                    15710697025169400283,
+                    326091050366916057,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17392967990311079192,
+                    9525303858791708772,
+                    12725344340629377967,
+                    905946062507784302,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15795078555222378519,
+                    4239053439859557467,
+                    17543334825201801998,
+                    1322375063585281792,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13665385723616569337,
+                    15192216537319734924,
+                    17244485342802624934,
+                    456061892597021667,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17065602944315846433,
+                    12599472757624769951,
+                    136350343153398653,
+                    297824106946277200,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<11, 121>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    445274648097712628,
+                    4921753182278812605,
+                    14241747337661209002,
+                    1291391020802106767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17435326295996426649,
+                    3625836183605907678,
+                    14227038863630282471,
+                    987971414577888227,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5394262226635545028,
+                    17739824447324827612,
+                    12354939351338088958,
+                    361134466222950276,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3247653681690544697,
+                    3900617065537264659,
+                    8522038271610042668,
+                    1231843234455456017,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8257844185360280797,
+                    1935656081120110795,
+                    14700478219819458542,
+                    779285104241483493,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12401646964454253087,
+                    8267430230272975783,
+                    4250693184536538148,
+                    27466473020506595,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7693033679999051660,
+                    6882158816161506210,
+                    13447616042926397181,
+                    398885082107267958,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2166413819951439066,
+                    3111172007379532998,
+                    18335116625533222233,
+                    1140191450002621145,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11268325932420880253,
+                    11122284200346196764,
+                    15047574457807883976,
+                    37731662917430824,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9267222490804456834,
+                    15051508767890798327,
+                    14250250546110917395,
+                    1332703149715696456,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15790779124251198803,
+                    7726576318744864856,
+                    2295786313820301616,
+                    1161360194974791287,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17435326295996426649,
+                    3625836183605907678,
+                    14227038863630282471,
+                    987971414577888227,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3008667962451674373,
+                    9680106337251966655,
+                    16690218560426489229,
+                    77037393316424372,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3691464061046268292,
+                    14734070117813337610,
+                    4031677891274743102,
+                    211801819398672339,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13925309731965889514,
+                    12743842126718738092,
+                    15542297485500538761,
+                    1220639629218608844,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10487391742568584924,
+                    7342658913354203941,
+                    4545814136588836191,
+                    1195448651058221922,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12387891562800099950,
+                    3783264065305981868,
+                    7108229229640665728,
+                    590861989592489241,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14893268932003501319,
+                    3771570946019326815,
+                    14033609244865695631,
+                    686661825512664880,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10557240702807039335,
+                    7294237743407019636,
+                    11556977588151286679,
+                    274879607457998291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7988237920989610426,
+                    16498636530136710369,
+                    16212269797243541277,
+                    628378775256987726,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7387682806304500864,
+                    12587251777722490867,
+                    11543654727321982137,
+                    1264541634285788143,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11117991142358233522,
+                    4843252356353192491,
+                    6509534411714646185,
+                    820642613151857145,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5394262226635545028,
+                    17739824447324827612,
+                    12354939351338088958,
+                    361134466222950276,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3691464061046268292,
+                    14734070117813337610,
+                    4031677891274743102,
+                    211801819398672339,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2658881687344180174,
+                    14029471278536389372,
+                    359014110111972910,
+                    178387872804130427,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10017483954575642226,
+                    10886817607058653439,
+                    2315477291597893575,
+                    1039367254164561196,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14032568820775399393,
+                    9825452711049960241,
+                    14914408187108964883,
+                    1137146878720301782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18325134942177204879,
+                    10515950290280209173,
+                    8324338051583235537,
+                    1072538331538606672,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6311633476073118449,
+                    3393874113512757767,
+                    13899764575598572664,
+                    1310363569680406681,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1124592139206110897,
+                    16143763710868114077,
+                    16348428381039578017,
+                    110353867412131028,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7222084045440039674,
+                    5876268976757100671,
+                    9012083142711702050,
+                    411621596097176322,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17549657364693897549,
+                    6060589117874504303,
+                    9649994631715097030,
+                    229030954078581536,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18335611137968932361,
+                    190945065612612125,
+                    16903679471668694675,
+                    628740480282132241,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3247653681690544697,
+                    3900617065537264659,
+                    8522038271610042668,
+                    1231843234455456017,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13925309731965889514,
+                    12743842126718738092,
+                    15542297485500538761,
+                    1220639629218608844,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10017483954575642226,
+                    10886817607058653439,
+                    2315477291597893575,
+                    1039367254164561196,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1894922878097067415,
+                    628631125498927490,
+                    10680201760371522164,
+                    413048733740408526,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17934413031113428966,
+                    13762874869439357681,
+                    11824649638463442614,
+                    1312675476505703938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3850597480000013549,
+                    12024215732070680287,
+                    15026644576697875657,
+                    809109301991117932,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8999481721214863341,
+                    14561347833911059411,
+                    5894105401287682028,
+                    1094651141343521304,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7626399566839564232,
+                    451760794888001078,
+                    7196411788753834772,
+                    1098897477282758819,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1392378301090435646,
+                    140662418467754103,
+                    5942799236854210630,
+                    191773784602969925,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12254200775684036021,
+                    10980667444473258088,
+                    4960363157901064883,
+                    1112628619339456995,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1606017001237740163,
+                    13373150451984214665,
+                    5470842280225732075,
+                    793520821361501741,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8257844185360280797,
+                    1935656081120110795,
+                    14700478219819458542,
+                    779285104241483493,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10487391742568584924,
+                    7342658913354203941,
+                    4545814136588836191,
+                    1195448651058221922,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14032568820775399393,
+                    9825452711049960241,
+                    14914408187108964883,
+                    1137146878720301782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17934413031113428966,
+                    13762874869439357681,
+                    11824649638463442614,
+                    1312675476505703938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1885157317007979169,
+                    1187561003880839526,
+                    9026110427760377811,
+                    397445624904665476,
+                ]),
+                Fq::from_montgomery_limbs([
+                    826602443185095523,
+                    15649252137763884956,
+                    18427562069094039052,
+                    1087277721186318388,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9702394015865201236,
+                    12961765243161627710,
+                    6495840978007689813,
+                    874042265496052508,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8885084883187881351,
+                    15896947782431811846,
+                    9493208602437256800,
+                    574432563939721662,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15839296358945400141,
+                    485439946751789724,
+                    14205725258753241414,
+                    450346034590819656,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13219777413049234006,
+                    15267396001916796062,
+                    18441490279080198561,
+                    332396759113161015,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15079696574939762809,
+                    9696251695866118246,
+                    15970775865513377343,
+                    309998277291841572,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12401646964454253087,
+                    8267430230272975783,
+                    4250693184536538148,
+                    27466473020506595,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12387891562800099950,
+                    3783264065305981868,
+                    7108229229640665728,
+                    590861989592489241,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18325134942177204879,
+                    10515950290280209173,
+                    8324338051583235537,
+                    1072538331538606672,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3850597480000013549,
+                    12024215732070680287,
+                    15026644576697875657,
+                    809109301991117932,
+                ]),
+                Fq::from_montgomery_limbs([
+                    826602443185095523,
+                    15649252137763884956,
+                    18427562069094039052,
+                    1087277721186318388,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4106967029632542624,
+                    1548416656926906666,
+                    12642978711356661894,
+                    1331071379242180375,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314649806388640372,
+                    8630705991713656285,
+                    2111310955836794760,
+                    475780547600762569,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12494558191483432846,
+                    816201241101500322,
+                    8607032337735905944,
+                    789653448767634165,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7225347310729399473,
+                    4294900162856956442,
+                    13290370661042009163,
+                    582118645472623555,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10500766554727583383,
+                    3089361020970067032,
+                    4938422581525598528,
+                    384512616243064795,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7459596750011293455,
+                    5515348134177144627,
+                    11920215732632859522,
+                    725283816104490252,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7693033679999051660,
+                    6882158816161506210,
+                    13447616042926397181,
+                    398885082107267958,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14893268932003501319,
+                    3771570946019326815,
+                    14033609244865695631,
+                    686661825512664880,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6311633476073118449,
+                    3393874113512757767,
+                    13899764575598572664,
+                    1310363569680406681,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8999481721214863341,
+                    14561347833911059411,
+                    5894105401287682028,
+                    1094651141343521304,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9702394015865201236,
+                    12961765243161627710,
+                    6495840978007689813,
+                    874042265496052508,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314649806388640372,
+                    8630705991713656285,
+                    2111310955836794760,
+                    475780547600762569,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16022286665462746509,
+                    5046209690329423283,
+                    1179908433281935015,
+                    281248049866962567,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3464962691630765803,
+                    17389304242058604031,
+                    5349555639677246514,
+                    775069101234480087,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14428738938983409785,
+                    17129322182649530666,
+                    4849216195677288194,
+                    1299324403178626967,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10013057662533902722,
+                    86679326083346553,
+                    16136865876716490242,
+                    1166527323743169422,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13909113876615773341,
+                    18305325501750097515,
+                    4533122844027143147,
+                    783525318886730368,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2166413819951439066,
+                    3111172007379532998,
+                    18335116625533222233,
+                    1140191450002621145,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10557240702807039335,
+                    7294237743407019636,
+                    11556977588151286679,
+                    274879607457998291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1124592139206110897,
+                    16143763710868114077,
+                    16348428381039578017,
+                    110353867412131028,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7626399566839564232,
+                    451760794888001078,
+                    7196411788753834772,
+                    1098897477282758819,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8885084883187881351,
+                    15896947782431811846,
+                    9493208602437256800,
+                    574432563939721662,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12494558191483432846,
+                    816201241101500322,
+                    8607032337735905944,
+                    789653448767634165,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3464962691630765803,
+                    17389304242058604031,
+                    5349555639677246514,
+                    775069101234480087,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6434355165910697652,
+                    12070676158117916413,
+                    11027915170246764243,
+                    375926567435149651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13671372537085634220,
+                    3868843508756091926,
+                    13992815203980273811,
+                    406823600647962398,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5588861231527636349,
+                    829961328605249534,
+                    10786159481370107895,
+                    721807883073121509,
+                ]),
+                Fq::from_montgomery_limbs([
+                    813306083309360477,
+                    14185171867186292295,
+                    10759932094427533434,
+                    634181761950636593,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11268325932420880253,
+                    11122284200346196764,
+                    15047574457807883976,
+                    37731662917430824,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7988237920989610426,
+                    16498636530136710369,
+                    16212269797243541277,
+                    628378775256987726,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7222084045440039674,
+                    5876268976757100671,
+                    9012083142711702050,
+                    411621596097176322,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1392378301090435646,
+                    140662418467754103,
+                    5942799236854210630,
+                    191773784602969925,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15839296358945400141,
+                    485439946751789724,
+                    14205725258753241414,
+                    450346034590819656,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7225347310729399473,
+                    4294900162856956442,
+                    13290370661042009163,
+                    582118645472623555,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14428738938983409785,
+                    17129322182649530666,
+                    4849216195677288194,
+                    1299324403178626967,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13671372537085634220,
+                    3868843508756091926,
+                    13992815203980273811,
+                    406823600647962398,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14955525657043322860,
+                    8374363231556193941,
+                    825522729815111983,
+                    120394639373586420,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2717105407978112385,
+                    3572054586371870905,
+                    18129970008608633177,
+                    1264085782307992326,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3023238152629632565,
+                    3180439121970209879,
+                    2399888564484958137,
+                    414807521763563136,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9267222490804456834,
+                    15051508767890798327,
+                    14250250546110917395,
+                    1332703149715696456,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7387682806304500864,
+                    12587251777722490867,
+                    11543654727321982137,
+                    1264541634285788143,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17549657364693897549,
+                    6060589117874504303,
+                    9649994631715097030,
+                    229030954078581536,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12254200775684036021,
+                    10980667444473258088,
+                    4960363157901064883,
+                    1112628619339456995,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13219777413049234006,
+                    15267396001916796062,
+                    18441490279080198561,
+                    332396759113161015,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10500766554727583383,
+                    3089361020970067032,
+                    4938422581525598528,
+                    384512616243064795,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10013057662533902722,
+                    86679326083346553,
+                    16136865876716490242,
+                    1166527323743169422,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5588861231527636349,
+                    829961328605249534,
+                    10786159481370107895,
+                    721807883073121509,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2717105407978112385,
+                    3572054586371870905,
+                    18129970008608633177,
+                    1264085782307992326,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2260799073095663328,
+                    8358429586905828945,
+                    11351928776306252583,
+                    939009670092955599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6280493422903084027,
+                    6029616197041847848,
+                    12950450894623985713,
+                    1204585421785912217,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15790779124251198803,
+                    7726576318744864856,
+                    2295786313820301616,
+                    1161360194974791287,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11117991142358233522,
+                    4843252356353192491,
+                    6509534411714646185,
+                    820642613151857145,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18335611137968932361,
+                    190945065612612125,
+                    16903679471668694675,
+                    628740480282132241,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1606017001237740163,
+                    13373150451984214665,
+                    5470842280225732075,
+                    793520821361501741,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15079696574939762809,
+                    9696251695866118246,
+                    15970775865513377343,
+                    309998277291841572,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7459596750011293455,
+                    5515348134177144627,
+                    11920215732632859522,
+                    725283816104490252,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13909113876615773341,
+                    18305325501750097515,
+                    4533122844027143147,
+                    783525318886730368,
+                ]),
+                Fq::from_montgomery_limbs([
+                    813306083309360477,
+                    14185171867186292295,
+                    10759932094427533434,
+                    634181761950636593,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3023238152629632565,
+                    3180439121970209879,
+                    2399888564484958137,
+                    414807521763563136,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6280493422903084027,
+                    6029616197041847848,
+                    12950450894623985713,
+                    1204585421785912217,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161638628367943937,
+                    9934206470348456007,
+                    17323909064890603922,
+                    155632279404156516,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<10, 100>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17835054843410459202,
+                    8452414414405432317,
+                    141432773346005343,
+                    252775051402063237,
+                ]),
+                Fq::from_montgomery_limbs([
+                    576127727221739315,
+                    212913162610169377,
+                    11230062014174969637,
+                    106198513378560419,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11940779907714568686,
+                    17458731146160442362,
+                    10276514583607013959,
+                    934665959203021392,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18382725405624241487,
+                    12646205447613787904,
+                    16762223203360786098,
+                    235831361019454126,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18148656442456215206,
+                    17922992595707216216,
+                    8927868212558821659,
+                    645987204739052354,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10396005236206496264,
+                    7246734669203332603,
+                    12023221071111830103,
+                    376299563579594866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2015145132440770804,
+                    1862608320232255,
+                    4872314834838035173,
+                    102330383391336060,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17808943229137197827,
+                    5195166009872215533,
+                    3085477702271772198,
+                    1320125928743219515,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10021195144001250322,
+                    1903543417937678233,
+                    13596492320232401505,
+                    585532459560986414,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18213500048852095806,
+                    16370071049132430796,
+                    8570697627996702986,
+                    139333466449531902,
+                ]),
+                Fq::from_montgomery_limbs([
+                    576127727221739315,
+                    212913162610169377,
+                    11230062014174969637,
+                    106198513378560419,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16249428790601228312,
+                    13107375538239058738,
+                    15337871437489312291,
+                    1277860418942747132,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3081686273045166810,
+                    2996241833356698779,
+                    10436927375581955802,
+                    625596885604622192,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14847898981567446619,
+                    10389764059465391615,
+                    12680379810017354779,
+                    550269959435892678,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12247840386171915040,
+                    4152999370821260700,
+                    5015586139047313165,
+                    737139916386213687,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16778226155958366527,
+                    10372220747355828307,
+                    4317904514420087689,
+                    211157517475080852,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15932945131495259755,
+                    7683022187508781883,
+                    18170479549736829386,
+                    190572333358082833,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5543828520807511708,
+                    10078906908563369711,
+                    16650165991476744510,
+                    1268461012366228161,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12685662934139938840,
+                    4971902568235726923,
+                    14072246331700593854,
+                    1288698186010404321,
+                ]),
+                Fq::from_montgomery_limbs([
+                    746548481916323646,
+                    17590916555614154507,
+                    4011567549555229335,
+                    568404867760399359,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11940779907714568686,
+                    17458731146160442362,
+                    10276514583607013959,
+                    934665959203021392,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3081686273045166810,
+                    2996241833356698779,
+                    10436927375581955802,
+                    625596885604622192,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6316147063759103784,
+                    4675454129110087353,
+                    1487844688697112532,
+                    574924227701049602,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1904259997941587967,
+                    6032901506317543526,
+                    10384885043929434035,
+                    728431921563153018,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12356626516632683093,
+                    17916373641046452488,
+                    6377124895121892571,
+                    804895919044144838,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5581035542466896370,
+                    13868370866030605498,
+                    15557810206904481687,
+                    546288701431149588,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16249653942818495313,
+                    14489178599446977668,
+                    13683061464140228332,
+                    540001580002954367,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8709302768077448760,
+                    6505439315327003112,
+                    8737650413863809554,
+                    502817618107277911,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15480472809610404961,
+                    10700584868623472620,
+                    17829970628447076206,
+                    112093761045905400,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7915254954437866750,
+                    7483603037831093544,
+                    16620570038203872139,
+                    492792034023063462,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18382725405624241487,
+                    12646205447613787904,
+                    16762223203360786098,
+                    235831361019454126,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14847898981567446619,
+                    10389764059465391615,
+                    12680379810017354779,
+                    550269959435892678,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1904259997941587967,
+                    6032901506317543526,
+                    10384885043929434035,
+                    728431921563153018,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17109236642398903895,
+                    4435954190629947772,
+                    14899826606078791750,
+                    644102258574917811,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7815485380350380787,
+                    15677588021112473250,
+                    11339692152299321643,
+                    202713174322874315,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9663529768514303599,
+                    17206787029784578801,
+                    4580096837498981878,
+                    1016045646994948830,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9322687409984076940,
+                    5464908071261565259,
+                    13017720339277553106,
+                    1195585319656354597,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12254200775684036021,
+                    10980667444473258088,
+                    4960363157901064883,
+                    1112628619339456995,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8749562417880982068,
+                    16076391804142859611,
+                    13870967807718230312,
+                    1008489477989064168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14701287262873777639,
+                    46688881225881450,
+                    14742166444880144685,
+                    402377024127518735,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18148656442456215206,
+                    17922992595707216216,
+                    8927868212558821659,
+                    645987204739052354,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12247840386171915040,
+                    4152999370821260700,
+                    5015586139047313165,
+                    737139916386213687,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12356626516632683093,
+                    17916373641046452488,
+                    6377124895121892571,
+                    804895919044144838,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7815485380350380787,
+                    15677588021112473250,
+                    11339692152299321643,
+                    202713174322874315,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9464160628791654623,
+                    11076923955203450741,
+                    12999085076187963501,
+                    634467578387184201,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9095608506818983539,
+                    5105086268659780096,
+                    12672086536878915496,
+                    804250120487592770,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15584507692337862315,
+                    5895659416129347458,
+                    5095469415266984571,
+                    49253704861060631,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7853288522065641038,
+                    4865140623291062990,
+                    6947941264077798276,
+                    1174060124176223503,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4626205961360129550,
+                    1057771144388733981,
+                    17846999949243860014,
+                    1084052370467313744,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2881122212168613804,
+                    7823583784676003615,
+                    8105453064570518272,
+                    948729370981371804,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10396005236206496264,
+                    7246734669203332603,
+                    12023221071111830103,
+                    376299563579594866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16778226155958366527,
+                    10372220747355828307,
+                    4317904514420087689,
+                    211157517475080852,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5581035542466896370,
+                    13868370866030605498,
+                    15557810206904481687,
+                    546288701431149588,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9663529768514303599,
+                    17206787029784578801,
+                    4580096837498981878,
+                    1016045646994948830,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9095608506818983539,
+                    5105086268659780096,
+                    12672086536878915496,
+                    804250120487592770,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1660946332318347994,
+                    7299932859524607034,
+                    5600741024511588146,
+                    360880815721047717,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8193268233326367285,
+                    16821796414373958365,
+                    5018071414827618572,
+                    1003235613961554042,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10692839213956231224,
+                    13579196419803872918,
+                    1633140186572963548,
+                    159768818535816431,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16681770509641096940,
+                    957861819401920169,
+                    10240611896844087480,
+                    201098814602599536,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17064058525501872860,
+                    5401745135635674155,
+                    3397118538937859163,
+                    131684927543988297,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2015145132440770804,
+                    1862608320232255,
+                    4872314834838035173,
+                    102330383391336060,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15932945131495259755,
+                    7683022187508781883,
+                    18170479549736829386,
+                    190572333358082833,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16249653942818495313,
+                    14489178599446977668,
+                    13683061464140228332,
+                    540001580002954367,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9322687409984076940,
+                    5464908071261565259,
+                    13017720339277553106,
+                    1195585319656354597,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15584507692337862315,
+                    5895659416129347458,
+                    5095469415266984571,
+                    49253704861060631,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8193268233326367285,
+                    16821796414373958365,
+                    5018071414827618572,
+                    1003235613961554042,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10752415051595170760,
+                    15446730872593081229,
+                    12369942613585599335,
+                    771014276428049868,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1919202927308425843,
+                    16960509244605337412,
+                    9883270118054718074,
+                    74481527420065032,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15655165792689889680,
+                    2614987441225351577,
+                    6007093152108775935,
+                    832588574149087298,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2108187300679394597,
+                    1059362316564155349,
+                    3571160777302651814,
+                    1213595443144185101,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17808943229137197827,
+                    5195166009872215533,
+                    3085477702271772198,
+                    1320125928743219515,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5543828520807511708,
+                    10078906908563369711,
+                    16650165991476744510,
+                    1268461012366228161,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8709302768077448760,
+                    6505439315327003112,
+                    8737650413863809554,
+                    502817618107277911,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12254200775684036021,
+                    10980667444473258088,
+                    4960363157901064883,
+                    1112628619339456995,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7853288522065641038,
+                    4865140623291062990,
+                    6947941264077798276,
+                    1174060124176223503,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10692839213956231224,
+                    13579196419803872918,
+                    1633140186572963548,
+                    159768818535816431,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1919202927308425843,
+                    16960509244605337412,
+                    9883270118054718074,
+                    74481527420065032,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2033331050967861375,
+                    5728727212599470532,
+                    12802979813291639212,
+                    107054585722264595,
+                ]),
+                Fq::from_montgomery_limbs([
+                    633755001276177061,
+                    2300230775290835540,
+                    5665809850799769903,
+                    820105676629041802,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6893775053220224624,
+                    17974847512277440216,
+                    9912598687206880151,
+                    83169427922518924,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10021195144001250322,
+                    1903543417937678233,
+                    13596492320232401505,
+                    585532459560986414,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12685662934139938840,
+                    4971902568235726923,
+                    14072246331700593854,
+                    1288698186010404321,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15480472809610404961,
+                    10700584868623472620,
+                    17829970628447076206,
+                    112093761045905400,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8749562417880982068,
+                    16076391804142859611,
+                    13870967807718230312,
+                    1008489477989064168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4626205961360129550,
+                    1057771144388733981,
+                    17846999949243860014,
+                    1084052370467313744,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16681770509641096940,
+                    957861819401920169,
+                    10240611896844087480,
+                    201098814602599536,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15655165792689889680,
+                    2614987441225351577,
+                    6007093152108775935,
+                    832588574149087298,
+                ]),
+                Fq::from_montgomery_limbs([
+                    633755001276177061,
+                    2300230775290835540,
+                    5665809850799769903,
+                    820105676629041802,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8620496064918348414,
+                    16772795271605988807,
+                    17010043975985383561,
+                    190149458193823508,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11139229147276070546,
+                    1098146733606104402,
+                    4297299495813494503,
+                    1315131453066260288,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18213500048852095806,
+                    16370071049132430796,
+                    8570697627996702986,
+                    139333466449531902,
+                ]),
+                Fq::from_montgomery_limbs([
+                    746548481916323646,
+                    17590916555614154507,
+                    4011567549555229335,
+                    568404867760399359,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7915254954437866750,
+                    7483603037831093544,
+                    16620570038203872139,
+                    492792034023063462,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14701287262873777639,
+                    46688881225881450,
+                    14742166444880144685,
+                    402377024127518735,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2881122212168613804,
+                    7823583784676003615,
+                    8105453064570518272,
+                    948729370981371804,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17064058525501872860,
+                    5401745135635674155,
+                    3397118538937859163,
+                    131684927543988297,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2108187300679394597,
+                    1059362316564155349,
+                    3571160777302651814,
+                    1213595443144185101,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6893775053220224624,
+                    17974847512277440216,
+                    9912598687206880151,
+                    83169427922518924,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11139229147276070546,
+                    1098146733606104402,
+                    4297299495813494503,
+                    1315131453066260288,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7425696723263348535,
+                    7252905770523281940,
+                    13092284144469293069,
+                    1093822033036761498,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            M_i: Matrix::<11, 11, 121>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    6153935049430316909,
+                    287961480192977383,
+                    12893598935707097963,
+                    1070510186568741997,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16651554054639731804,
+                    4730694418321979245,
+                    9980276515090376275,
+                    41514220396075907,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7387420948041729479,
+                    16616584847380847839,
+                    17333953772854548444,
+                    267655664342472948,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7507701523056274280,
+                    12588491594438694902,
+                    1232217770126268750,
+                    262838949752647850,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8354764848146230624,
+                    8787399424426134328,
+                    14298868438960067821,
+                    1258699011982544494,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10299717841443992081,
+                    3170377399171114580,
+                    6410786741473876556,
+                    88159355506886569,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10027931846714216687,
+                    13318974462654628187,
+                    15590401393387378772,
+                    1301803560011793550,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4470403635395688566,
+                    11461466507790888907,
+                    14278385329673793227,
+                    262661853760472874,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12733692738699536244,
+                    4582608631413585845,
+                    9866506655982975702,
+                    1193697699758960169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5721730630164707396,
+                    12434455327020858686,
+                    6712257128801134452,
+                    633948410965278102,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    16651554054639731804,
+                    4730694418321979245,
+                    9980276515090376275,
+                    41514220396075907,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13436309962402241854,
+                    15970945930820657506,
+                    4727633684149997193,
+                    371544698471846189,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2554913707695961290,
+                    17060272146605662854,
+                    13679986669430202280,
+                    632676835141711426,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13961313855769260265,
+                    17779903909189991452,
+                    5266220681401731696,
+                    77977362712439472,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11887662835879439710,
+                    6900216157071405477,
+                    13744858417715602246,
+                    1222946375964135598,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13124970232184417790,
+                    291001521116684942,
+                    8268213984564829067,
+                    369663063657473770,
+                ]),
+                Fq::from_montgomery_limbs([
+                    388599713169413299,
+                    17930855851817963289,
+                    5810995992379134533,
+                    579981661981518772,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16738769116075473583,
+                    13919380666086843389,
+                    1919312665461218072,
+                    362197328921474479,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11734738568518096406,
+                    4477515218650950818,
+                    16305173962014617183,
+                    1330724480463885516,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17923462718216716516,
+                    14680765405577632762,
+                    5537181099317900090,
+                    1183470716898818551,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7387420948041729479,
+                    16616584847380847839,
+                    17333953772854548444,
+                    267655664342472948,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2554913707695961290,
+                    17060272146605662854,
+                    13679986669430202280,
+                    632676835141711426,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10687927012614955537,
+                    2893429646400497083,
+                    8512499213493392555,
+                    641304762590150747,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16350750062017571611,
+                    9091616708507870249,
+                    15575897107524068612,
+                    328406406287485230,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5368374811934823868,
+                    11330698116117722573,
+                    11534258442405015985,
+                    1192057394542025464,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10791117942218913575,
+                    10116638043632713225,
+                    4784764298447482556,
+                    434662745771261651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4664942295994783968,
+                    9072699846556661993,
+                    14024264304375063246,
+                    446261279041509719,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9689814570221329180,
+                    7513532521871923473,
+                    10045020239328666597,
+                    466527972324781122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3467185248055917450,
+                    265307495597422924,
+                    3893935220884843882,
+                    676261748495516748,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15682254056588167922,
+                    12004721527512764806,
+                    16703565966954275273,
+                    291324194467203893,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7507701523056274280,
+                    12588491594438694902,
+                    1232217770126268750,
+                    262838949752647850,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13961313855769260265,
+                    17779903909189991452,
+                    5266220681401731696,
+                    77977362712439472,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16350750062017571611,
+                    9091616708507870249,
+                    15575897107524068612,
+                    328406406287485230,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1220336375565053655,
+                    7224842488190895561,
+                    7633499928198024667,
+                    402068896122737918,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2559021222598345771,
+                    13152631848350113269,
+                    10763040894238536395,
+                    1329949152410633238,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15710805500471931689,
+                    14651524381692729996,
+                    1885682516673073780,
+                    1276630055773124463,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9423661634252941212,
+                    4153159705441750334,
+                    2114524409237136801,
+                    816606804424260267,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10726735387887287431,
+                    10387742266757180630,
+                    462625157570097178,
+                    510941352414628233,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11435457897602563964,
+                    14110098550064686633,
+                    992614178203377994,
+                    271521975666419708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9689592903221903992,
+                    3782183161807379710,
+                    274630707614942318,
+                    652207588156367390,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8354764848146230624,
+                    8787399424426134328,
+                    14298868438960067821,
+                    1258699011982544494,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11887662835879439710,
+                    6900216157071405477,
+                    13744858417715602246,
+                    1222946375964135598,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5368374811934823868,
+                    11330698116117722573,
+                    11534258442405015985,
+                    1192057394542025464,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2559021222598345771,
+                    13152631848350113269,
+                    10763040894238536395,
+                    1329949152410633238,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4763825385420311230,
+                    8977375057051208028,
+                    12608242375652560750,
+                    1127777060556286730,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17531946405203231295,
+                    1207786600588432844,
+                    15248509747695285559,
+                    175980364911194597,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16497299726876901642,
+                    18334274822527292414,
+                    6757936950250281848,
+                    651409343509785437,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6664743647549527370,
+                    18404893015982020183,
+                    8411799499481389575,
+                    1138129325321558456,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17454769965535924199,
+                    17710176233329664442,
+                    14239949735609854147,
+                    742959974507281842,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3490351092462743649,
+                    8901876566630274818,
+                    6441808489474093629,
+                    211208305050559270,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10299717841443992081,
+                    3170377399171114580,
+                    6410786741473876556,
+                    88159355506886569,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13124970232184417790,
+                    291001521116684942,
+                    8268213984564829067,
+                    369663063657473770,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10791117942218913575,
+                    10116638043632713225,
+                    4784764298447482556,
+                    434662745771261651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15710805500471931689,
+                    14651524381692729996,
+                    1885682516673073780,
+                    1276630055773124463,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17531946405203231295,
+                    1207786600588432844,
+                    15248509747695285559,
+                    175980364911194597,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17571078452996306700,
+                    10692827447956277638,
+                    2520730254413514148,
+                    591606281093378285,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10279418474041242397,
+                    17889457428402489209,
+                    13915452261246074396,
+                    1159980075261678918,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12151076534158730063,
+                    6753243015615318112,
+                    12672382901088701789,
+                    607934287566032403,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12109850966033514358,
+                    10554178759091963902,
+                    6488391018120344878,
+                    168431645316407806,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6369736117534761296,
+                    10922193935147776607,
+                    1301001738656290239,
+                    714994064728056614,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10027931846714216687,
+                    13318974462654628187,
+                    15590401393387378772,
+                    1301803560011793550,
+                ]),
+                Fq::from_montgomery_limbs([
+                    388599713169413299,
+                    17930855851817963289,
+                    5810995992379134533,
+                    579981661981518772,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4664942295994783968,
+                    9072699846556661993,
+                    14024264304375063246,
+                    446261279041509719,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9423661634252941212,
+                    4153159705441750334,
+                    2114524409237136801,
+                    816606804424260267,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16497299726876901642,
+                    18334274822527292414,
+                    6757936950250281848,
+                    651409343509785437,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10279418474041242397,
+                    17889457428402489209,
+                    13915452261246074396,
+                    1159980075261678918,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16565750006756856943,
+                    9982961464060738788,
+                    3774090120596444117,
+                    16545949127158131,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6524674280131422047,
+                    6805062870425356820,
+                    3210799721916125619,
+                    341822168313891747,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8161226910954886954,
+                    1595823349272384762,
+                    12657081136930600032,
+                    810994828792766411,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16879979294286525228,
+                    13860680953648463518,
+                    5315760785258081541,
+                    1308781294399786172,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4470403635395688566,
+                    11461466507790888907,
+                    14278385329673793227,
+                    262661853760472874,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16738769116075473583,
+                    13919380666086843389,
+                    1919312665461218072,
+                    362197328921474479,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9689814570221329180,
+                    7513532521871923473,
+                    10045020239328666597,
+                    466527972324781122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10726735387887287431,
+                    10387742266757180630,
+                    462625157570097178,
+                    510941352414628233,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6664743647549527370,
+                    18404893015982020183,
+                    8411799499481389575,
+                    1138129325321558456,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12151076534158730063,
+                    6753243015615318112,
+                    12672382901088701789,
+                    607934287566032403,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6524674280131422047,
+                    6805062870425356820,
+                    3210799721916125619,
+                    341822168313891747,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3792386586319563169,
+                    17919141761827007543,
+                    1223351602409935009,
+                    654609656543925679,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8581458986025268272,
+                    3016396721853973495,
+                    3857661218488536470,
+                    315293856244289504,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9275919165582303894,
+                    10689936164293649765,
+                    4343961797428113711,
+                    222835581272224402,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12733692738699536244,
+                    4582608631413585845,
+                    9866506655982975702,
+                    1193697699758960169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11734738568518096406,
+                    4477515218650950818,
+                    16305173962014617183,
+                    1330724480463885516,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3467185248055917450,
+                    265307495597422924,
+                    3893935220884843882,
+                    676261748495516748,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11435457897602563964,
+                    14110098550064686633,
+                    992614178203377994,
+                    271521975666419708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17454769965535924199,
+                    17710176233329664442,
+                    14239949735609854147,
+                    742959974507281842,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12109850966033514358,
+                    10554178759091963902,
+                    6488391018120344878,
+                    168431645316407806,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8161226910954886954,
+                    1595823349272384762,
+                    12657081136930600032,
+                    810994828792766411,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8581458986025268272,
+                    3016396721853973495,
+                    3857661218488536470,
+                    315293856244289504,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7035498120589331807,
+                    2295561404827608063,
+                    4542385954343912286,
+                    29696764792046742,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6806792458248793379,
+                    10240402282211200451,
+                    12024685584350624372,
+                    395346276647833229,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5721730630164707396,
+                    12434455327020858686,
+                    6712257128801134452,
+                    633948410965278102,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17923462718216716516,
+                    14680765405577632762,
+                    5537181099317900090,
+                    1183470716898818551,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15682254056588167922,
+                    12004721527512764806,
+                    16703565966954275273,
+                    291324194467203893,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9689592903221903992,
+                    3782183161807379710,
+                    274630707614942318,
+                    652207588156367390,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3490351092462743649,
+                    8901876566630274818,
+                    6441808489474093629,
+                    211208305050559270,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6369736117534761296,
+                    10922193935147776607,
+                    1301001738656290239,
+                    714994064728056614,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16879979294286525228,
+                    13860680953648463518,
+                    5315760785258081541,
+                    1308781294399786172,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9275919165582303894,
+                    10689936164293649765,
+                    4343961797428113711,
+                    222835581272224402,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6806792458248793379,
+                    10240402282211200451,
+                    12024685584350624372,
+                    395346276647833229,
+                ]),
+                Fq::from_montgomery_limbs([
+                    542138418377252671,
+                    16936350172241174864,
+                    10666191470339324416,
+                    680274696098040197,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18204910156218979669,
+                        16293041589642941780,
+                        9975069610392864084,
+                        1088801882579738168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15441359965645378481,
+                        7022857083512140090,
+                        11162758362666494739,
+                        1108530997049617801,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7802104352665277001,
+                        1712233803072817444,
+                        12180777293186749586,
+                        1125441666595228915,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7330330845985706257,
+                        6947957132670498679,
+                        9373711551562393463,
+                        1140097580201425214,
+                    ]),
+                    Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                    Fq::from_montgomery_limbs([
+                        829043150522309210,
+                        7485532467658058089,
+                        17072453966431109601,
+                        610297755857677130,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18043687544558598371,
+                        8708325242362018018,
+                        16625116017321440141,
+                        277441131490434312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1903578231313020389,
+                        13252279451564941743,
+                        6429417326301386859,
+                        900077037001125169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14902495609461984461,
+                        16049616749407607193,
+                        8772353492731922841,
+                        1191393277823112260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5443236819267423281,
+                        9444106377351672003,
+                        16592192658774520588,
+                        1198721234626210409,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4986960398740682814,
+                        16378185069905663317,
+                        10198532689939407840,
+                        617208162144260385,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11991714433758836750,
+                        9235406755776349712,
+                        1118520274450302931,
+                        783550884028685958,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4607485660484847593,
+                        14312398785489429385,
+                        10453722256240243503,
+                        940260892902272815,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15220418686564351498,
+                        5572016207661264946,
+                        3817562863097008521,
+                        751941409162438463,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4735424854338219945,
+                        7903471161161703842,
+                        7677006832945477662,
+                        48644143202271686,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12851671705375047342,
+                        17681493092029488284,
+                        2180134596270207777,
+                        1083017665087360680,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1229295449288445653,
+                        10150694347832653531,
+                        13094754123493285451,
+                        983638067723010090,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6620315887270632163,
+                        14099867613632553119,
+                        15355548272290379080,
+                        708125197780485249,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2913124056500834627,
+                        10804019315569276326,
+                        2693749394707248245,
+                        474269501548224617,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6608752262772801174,
+                        16081814952700581319,
+                        7997868191361962824,
+                        140039920892270217,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10701597015130722003,
+                        13674278589798992304,
+                        5043060888990725341,
+                        769481562557475503,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14338077236046844737,
+                        17063953593781823475,
+                        8336497154501874113,
+                        835876064791380753,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4765556986140731539,
+                        8308055774696162157,
+                        11124873877112429576,
+                        332986595043890070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7851723418564639746,
+                        17035416796063209826,
+                        12521225257968717280,
+                        859878229004789968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3740606310173141341,
+                        3303938109855435192,
+                        15924217711793884700,
+                        1116593405275331936,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5824036493829784260,
+                        12210919788575414953,
+                        1773830037435581551,
+                        1289164905782012388,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15801120090691768804,
+                        2584943699939770899,
+                        3574883838116533648,
+                        1164229112758285190,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13784747989553992966,
+                        9921568020805983343,
+                        7679511489588337745,
+                        672212070832664427,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8582128436594135963,
+                        9877728178119967410,
+                        1137740432441376123,
+                        1159451559526529590,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1449137017673923390,
+                        9531026871999024632,
+                        2040605840374642704,
+                        1071128945618371052,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1448265643140778618,
+                        16109021419590944714,
+                        5660342042878559923,
+                        993203803860901269,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7656758156490931160,
+                        9485921024407535129,
+                        240223846426405967,
+                        339554586510064729,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5449898310155726320,
+                        15937621279212475060,
+                        17751379068634772400,
+                        1310196353446451224,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17290738888678955574,
+                        12846658409833265544,
+                        2491694774496937316,
+                        524315311747756159,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9155163004941229188,
+                        17588001909892798488,
+                        6195063282106952408,
+                        219256369022018775,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6735454177634684943,
+                        6640539779483490981,
+                        16315146249829706324,
+                        1023134757419978282,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18058960132124147986,
+                        6262829761794593622,
+                        3314804143426635381,
+                        1337787688379652656,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7262994074729983864,
+                        5706369754963884058,
+                        6075224447782681930,
+                        988603493029780648,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3496060086448536221,
+                        1313609487984800454,
+                        4235039373072306281,
+                        75315597516322471,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10586985314211551754,
+                        2561865481511696825,
+                        8574307627493075941,
+                        594022742311184057,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14037715585944910902,
+                        2374359971696587159,
+                        7492741011932750362,
+                        396096711093650603,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13765030230284070895,
+                        9263818859580292643,
+                        10035223693620628527,
+                        430413664275166661,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14205854853854688766,
+                        1877803771078181930,
+                        627851006343384610,
+                        329492048395811284,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9919279859393963760,
+                        10652819329750830499,
+                        6421309616475463463,
+                        1178376153243640732,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10560619285522459711,
+                        10009067836450789319,
+                        15736340021729061125,
+                        242101142809304516,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        389241757777626703,
+                        11132137022987056137,
+                        14505604610522999091,
+                        103533239576779123,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13588446288444174472,
+                        14458103615096583925,
+                        10283140110896307752,
+                        142444146552571229,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1779300398124186357,
+                        7485725474180327894,
+                        17929787150607248683,
+                        114910324284091699,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7102888263908393463,
+                        3261875714187857073,
+                        16215913817996567133,
+                        325229088504190238,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11244553314203882403,
+                        15615619869490594718,
+                        1724908008358610902,
+                        1164597721474907734,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10648395984656814804,
+                        5411545940042629565,
+                        10850817673262037228,
+                        1049624438308489987,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2068668515365002044,
+                        11253623262724233332,
+                        16846802879255868652,
+                        886921689901220345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7113307825402340285,
+                        1150456034120890228,
+                        14977425428672084913,
+                        863591728673822272,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3641300085732263154,
+                        9022050209999182809,
+                        15244988925740120537,
+                        145318103110637019,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14255489944099855807,
+                        6446424282788118330,
+                        13353078695499320393,
+                        925659432834049837,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2002465525614280274,
+                        13370889301950684303,
+                        1082194025778090132,
+                        404168598973147151,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13943910772483140207,
+                        314050941217621138,
+                        13560922586506647967,
+                        737677426705869483,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8174382099714218051,
+                        2532753261945461455,
+                        15446645155195427972,
+                        401502438661692250,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13869522620871510617,
+                        17633833727367020876,
+                        4413541725592162635,
+                        665666981235490288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15696382992874855699,
+                        7104124557855227397,
+                        1299574385316033538,
+                        1060791038390174037,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12197316856655724384,
+                        16234747659254280708,
+                        7690473503582560404,
+                        666413309582498454,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12836203690198432220,
+                        10914317777876262744,
+                        15954852694669016739,
+                        340036678839694161,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17641981569861387004,
+                        4207028269905934667,
+                        4965259886297508203,
+                        938934078438111689,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        893134231658590327,
+                        12438858129697892328,
+                        4926343913022392288,
+                        1244688791282075818,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7008698037353140262,
+                        17010064659682035986,
+                        10250708322999066102,
+                        720883630855164548,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5539455045893582765,
+                        9084195754460939979,
+                        12503397094525652161,
+                        124932349597205826,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4316061949469532770,
+                        9199931827742667658,
+                        9599836714889377140,
+                        507287649809473241,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17498476736237789907,
+                        14210702592622579570,
+                        8499184766339412406,
+                        729045253757039202,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4263407194090168268,
+                        5601470173656170226,
+                        12483947819543827320,
+                        1058197032540925942,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3918810685479412826,
+                        8164362846570417501,
+                        2624879486986584132,
+                        62482843594262724,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14589759864118511337,
+                        1753848639311695031,
+                        1692494597694291031,
+                        408732521810763382,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17723763289796072730,
+                        4880454776365333766,
+                        14316102022784246327,
+                        901714923786376097,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16979645615534228945,
+                        13939597042093682515,
+                        13908223194948085441,
+                        1221372439173864762,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        773443136154262491,
+                        6143653199712425111,
+                        16059523892056671062,
+                        1204865917017510206,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16087433065926406474,
+                        8631222471037512964,
+                        1800155090952382032,
+                        1121001636645102512,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17409558733434817157,
+                        17206388990552184015,
+                        6245072091038557528,
+                        957535618310515170,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1254489778404103478,
+                        3867640425471810922,
+                        261792308600615688,
+                        200879231706483358,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1964426722588633251,
+                        18061803228611281346,
+                        2584450466354727332,
+                        906957920331242089,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16603813402383223946,
+                        12110873008841366398,
+                        6108125842983551010,
+                        130654034936077822,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        895415301694817368,
+                        4513762053110236218,
+                        14097004467372444998,
+                        1209417985712438956,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        282892021011975956,
+                        17147332493876427815,
+                        5989138964327509320,
+                        466115840810926993,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8607254532170140721,
+                        9272625119366023581,
+                        14912025608285041170,
+                        924386341241637434,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14408254507195551254,
+                        11665080246244877678,
+                        2521718765367275965,
+                        809092158607215865,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8058749586058913249,
+                        12237505751476514205,
+                        9490511342157891713,
+                        33663839953607410,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3532141421494915580,
+                        8343600769922161132,
+                        17445095045183199322,
+                        322941523749420847,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6904224378512524427,
+                        2883171594064510640,
+                        9979299632897860218,
+                        339166956510224588,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11772458447348426517,
+                        4174680267765508778,
+                        8598515749324792949,
+                        633625124568859117,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16356349194889618813,
+                        13479866405697749429,
+                        3223419426760989062,
+                        290728550274385454,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6802561024785553836,
+                        7353392932544608891,
+                        3706694240656115844,
+                        735597397230247461,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4902795518821149130,
+                        15833899823147043574,
+                        7046252631177508818,
+                        1024279205689004967,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5080029135277469681,
+                        13824312012645228365,
+                        880500535107813282,
+                        600243138905963601,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11066573161257378166,
+                        5280327850110113286,
+                        15111936057188174489,
+                        535634528893263724,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15234630574899094345,
+                        14996377923627923709,
+                        17728029314859504110,
+                        5335017820361246,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14220332170095480187,
+                        13807295717411590216,
+                        16283242080065128851,
+                        1050459276788470193,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5651668357647037263,
+                        3794543755174566469,
+                        4290434423179452655,
+                        16154748264643964,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3932651861393874610,
+                        13453899080901186409,
+                        7136961859113181017,
+                        1333780867816260932,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6682595934829774757,
+                        5024355322010648324,
+                        11815618995381278217,
+                        860907607357138531,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2410558100203332145,
+                        1079693419327924285,
+                        2036789978284409102,
+                        486108794878527922,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8512218971886077768,
+                        17544398297278632489,
+                        2695258551767426197,
+                        510304665003236633,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16002266976898417856,
+                        15252458578788222833,
+                        8565530479949464069,
+                        234392789956087974,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4291418917321271419,
+                        9184293188339081151,
+                        5723068917466341130,
+                        1112162651352198262,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9647794536402043762,
+                        6717465394476482688,
+                        18415770171599919800,
+                        1102399247385853227,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3586509667679339602,
+                        169540583707028696,
+                        18304948817801872838,
+                        108698457012374369,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3203057128912890142,
+                        13440515442811662819,
+                        7675218726444582092,
+                        941634691728939229,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10936143207142369205,
+                        15174950362121924205,
+                        13486341709782942737,
+                        1014647305872077942,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14456191057738729820,
+                        14182239229813613892,
+                        3288089484770651595,
+                        20559229162671078,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2486046116678657712,
+                        10953454194828050999,
+                        17182362995864104460,
+                        553939404328647373,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        630247754314423307,
+                        14303539432781929301,
+                        8968276113836770246,
+                        460334886150996320,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2122147489425228542,
+                        4472520395616672743,
+                        1002758794634438995,
+                        349480235390700553,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10655768097555021522,
+                        17351309493178954457,
+                        11020701328776019531,
+                        811953432507140387,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9123234759288744458,
+                        11052837759764804464,
+                        4323055484120270444,
+                        1109429300911501741,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11186372469782832748,
+                        9534890247059402089,
+                        12058895746371637042,
+                        536212454121556626,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9198559661090073833,
+                        9247193114568330009,
+                        5500525314166144091,
+                        1343202927868517912,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9531109387175870102,
+                        3943919150265118713,
+                        4046705419886426679,
+                        912961692219898445,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5021173911741575222,
+                        11389614144666402721,
+                        10780285001731373013,
+                        271501662373035668,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1307628182039666497,
+                        13197374829750037846,
+                        11251652725183428898,
+                        588600740910995980,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13734645335079593628,
+                        4540948146225979680,
+                        321303672723921268,
+                        375790891532582135,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16548201033176070349,
+                        13333043238883336759,
+                        7441633724784365458,
+                        927994406176024082,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12531627408220524027,
+                        8003959287863612589,
+                        12808706992298076918,
+                        769860231512914619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10887826418188217771,
+                        11245344737027829984,
+                        17657464945935010308,
+                        1154201415040352350,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11543547873706792254,
+                        14481393847304462010,
+                        3238118958498161432,
+                        1026584772286900407,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13379810920903134084,
+                        1673406628580705403,
+                        14613644333917755162,
+                        31097488778386298,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18410667778280331077,
+                        15796941146012535035,
+                        2212501387144908683,
+                        904593732371392906,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11695461407002076413,
+                        9221377279292811687,
+                        965276327377593305,
+                        821061495833784494,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6580727120258274423,
+                        11708549055327121081,
+                        13588570454925944857,
+                        1306327557468298711,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17589460430740533506,
+                        8236364718212721747,
+                        16743352233652211470,
+                        267891872651418052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14913978050468505941,
+                        13658755435209228305,
+                        3878887501972622690,
+                        941757286144089699,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18215611026154445302,
+                        10079753341227747385,
+                        15926887714314302806,
+                        743340357672501355,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17523372677349030207,
+                        8730113435090766994,
+                        4348194166930177033,
+                        437146948988744219,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        831546454393354276,
+                        34115312102904701,
+                        14441572854176040661,
+                        708612350679222786,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17722621372288417245,
+                        10084695886962143751,
+                        7073160029355991025,
+                        1256619136976069519,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16756705702432502713,
+                        3447949349838031746,
+                        14210727037811356601,
+                        75674227022146958,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7955265250191598559,
+                        4073997759172068326,
+                        4739119045241448500,
+                        572627378345356459,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15306396120419741085,
+                        11735033734188544325,
+                        8566514374862250739,
+                        484394669733853067,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12604200944658450538,
+                        6608646389395045761,
+                        460552966920430222,
+                        1305114867895072168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        190770908850949763,
+                        13070021337051120064,
+                        1617460550705722747,
+                        203893974623267730,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13447283016825696727,
+                        16385036656512209318,
+                        12404957679114928588,
+                        1134324400980713195,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10299837782649309883,
+                        13436188669739121014,
+                        3077250592696491430,
+                        251993675843808600,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10827857124254913921,
+                        7476178666631694226,
+                        15004468185631288143,
+                        614173845862243693,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2762042419080340927,
+                        3973791136776182865,
+                        7329916476415744121,
+                        1169182013419205684,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1606544568625194937,
+                        16698783535715710639,
+                        7639082512416723934,
+                        1187792919901221964,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11465116424981665251,
+                        9330161239028050987,
+                        1159832688407031027,
+                        1307104499057872330,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18086771359274847560,
+                        2198892281251260802,
+                        17270227503666197536,
+                        639946631469933447,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14324227252986609696,
+                        3267909898623813021,
+                        1178709149336585981,
+                        295843831709573972,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        476609878747240617,
+                        332274128085010777,
+                        18161503679672751183,
+                        776214386914678071,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2868852551857342917,
+                        17035400048005214094,
+                        6249045357206892859,
+                        887230359400517343,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17809069562788177744,
+                        7681938595194997264,
+                        6698288349587012135,
+                        750248326169360786,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11459809562666710110,
+                        10956072070197566149,
+                        1601928432060370629,
+                        1214387170691010588,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9304677836237770576,
+                        16020632153994792358,
+                        12863104989332513673,
+                        79164887841963145,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2035144359754501834,
+                        1553009229252059252,
+                        15672530523234025493,
+                        198447753439615864,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2136647197228508634,
+                        4556094744323723478,
+                        7895058227681019404,
+                        552209024177692360,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11506323798377007389,
+                        9468883594115264584,
+                        12863581097754150288,
+                        111385066920714742,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8169614892975907269,
+                        1464804182795034472,
+                        2124262479528056799,
+                        501722636802201271,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11213475553213887711,
+                        1705500116052844675,
+                        13713485842855939647,
+                        609729496525115757,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        170214078956615193,
+                        11294489437890641885,
+                        7817983184092788943,
+                        64134378858222372,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11837400377543332874,
+                        3679163219039805409,
+                        8120897587568065096,
+                        661108828326335711,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9212749825310114169,
+                        9507325269998385877,
+                        9546984072393538005,
+                        80815576656928680,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4082854614000638839,
+                        13934506524615600422,
+                        4980111054139778954,
+                        846593291467065514,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7518878435957908936,
+                        11970428540361868930,
+                        17898908523380704915,
+                        633065736535948375,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1737471828787747632,
+                        4326607945167429269,
+                        166118452403108543,
+                        185160410015422366,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11730773770811736660,
+                        15682426249014847450,
+                        2488454057943688888,
+                        640942878733865816,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16779652672246140991,
+                        1722854307349591716,
+                        481977222364354567,
+                        615774112879692318,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17949201832882139462,
+                        8594975599085286440,
+                        14645540381136026937,
+                        246357314257298449,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4968675953733917914,
+                        653818585256528375,
+                        10659339831916728754,
+                        1303941102779152890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1478184440391190505,
+                        12141276762936175827,
+                        4007165871989665995,
+                        1200565935132254726,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6170048655073613392,
+                        16921470843428797759,
+                        7723445070970388617,
+                        555948869359097659,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16311163119768178150,
+                        17407856889514520025,
+                        12080959820123582362,
+                        1140686777595025085,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3902770208632981371,
+                        14673865331722199675,
+                        11697961997550177385,
+                        1183985116137157844,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6460892579302650084,
+                        10991715126003554177,
+                        12735737978545134163,
+                        235726034695948785,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3458112284083335127,
+                        17040546355509447778,
+                        8465197550717100392,
+                        907267341642386758,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6706673946144100850,
+                        8658675068446615156,
+                        337003133912681548,
+                        260113056983856891,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14613113704336056221,
+                        3960957691570531003,
+                        6904842278444603683,
+                        372782865105036348,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14696695848397486883,
+                        15086964089820618117,
+                        579113995978788415,
+                        334977805814373994,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9053149877261159473,
+                        16463812090541943816,
+                        14534506950076049166,
+                        1119448669448597292,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4633764292395582281,
+                        3206237110665463947,
+                        689749486300207013,
+                        208668495769806159,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2536294024324075081,
+                        7238212607395594807,
+                        7967697413934067486,
+                        906095183593119121,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9478633689254170471,
+                        5111318090729552142,
+                        2321832275833608553,
+                        1157151892638548898,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15237114653853036427,
+                        11149729291149809393,
+                        13496869654749685562,
+                        1264479967655909980,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1909398253016194327,
+                        3121925859372942600,
+                        1147424753245481937,
+                        287032679181429987,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10685420476028687581,
+                        17864572739377770798,
+                        2846942166596301720,
+                        1076491196833332118,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15370950272534761152,
+                        18387541827097367653,
+                        10757641348767744108,
+                        1031749842514535805,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5701561714552689342,
+                        12262121060678902302,
+                        11563241448914391690,
+                        1018427534786276456,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16005474345413511519,
+                        14412996081169486244,
+                        14856367759153681384,
+                        901391184078177194,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17105208358895732155,
+                        10858211760598248577,
+                        6170140840546550507,
+                        1116194280597668297,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16538815221006941386,
+                        9304862538254000348,
+                        1877023951947016622,
+                        970790061534831356,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7513976415880555817,
+                        13482399238884278100,
+                        17036640640224161534,
+                        1205310929347583482,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4959783283468750542,
+                        14072311282159202532,
+                        18350671146053498158,
+                        405131893651126890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7347002274248521566,
+                        8958962177479767168,
+                        7489439026120807939,
+                        997556546761906024,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11375623306980953449,
+                        6675996802811673868,
+                        3656934686775287172,
+                        71334456808029854,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9799540168687846792,
+                        13487567234639835012,
+                        9878135687747605601,
+                        288171131978028418,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1874070884903857004,
+                        16142977450533983423,
+                        18262312017915256984,
+                        1215960191533291732,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1368051636761986153,
+                        15403789757483425605,
+                        7897706837492649207,
+                        470862554082606843,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2985427016898118869,
+                        18409032440891898139,
+                        7803602872525143403,
+                        313022589451668058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10170650912121515837,
+                        10426165341216580564,
+                        573109393847021766,
+                        433306115336662382,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11132927355121916579,
+                        7152668438131257480,
+                        4988600513488850013,
+                        744831910283546694,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12661316600717245247,
+                        1997785533784800383,
+                        6548555678182390924,
+                        1129626791427700925,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11254785200641435402,
+                        15229321419338639109,
+                        8929025367474826529,
+                        837138746694033297,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15730507260683453527,
+                        4109257788974060503,
+                        17545638150395746181,
+                        158234078523154500,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4391991484930922967,
+                        10319910841451007958,
+                        3031741531976192768,
+                        147817903774084667,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13607030729641438205,
+                        17891384880449584440,
+                        15749351829836168314,
+                        990380544370209818,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4430177367012141593,
+                        17790568679961998367,
+                        9191083497209459802,
+                        645191786286588290,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5786497870405511890,
+                        799839064965906898,
+                        12102294385432580750,
+                        36417638358328366,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11174844970227562216,
+                        657017698040236530,
+                        7209861558413060457,
+                        551647420745719177,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13813012104944115026,
+                        5622688252621775087,
+                        3643728386965066847,
+                        894585897920273489,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13505294887210303466,
+                        505421639901748250,
+                        2650296729294086924,
+                        163669810402325493,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5126643061323203157,
+                        12507465178220110692,
+                        16360694942462173144,
+                        86968688443594278,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8929826485922998035,
+                        15794713145852376840,
+                        11655543527140722986,
+                        375069740973733739,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9220443712054439344,
+                        18068237994353398629,
+                        13535500619362828581,
+                        851957855483388977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13283152137744114221,
+                        7478094165099377682,
+                        7690067911750345578,
+                        1100917933151408091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3528132969588549404,
+                        9501512196522864670,
+                        14557656709032275654,
+                        123405650010383761,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8736957010293597670,
+                        10233649569782526919,
+                        8558504222369660906,
+                        579783937308850314,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3210342723898518747,
+                        8582742624059186385,
+                        15498680418032324279,
+                        965329181518856040,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16671934352744637061,
+                        6803237373432156390,
+                        17665303899892600492,
+                        1187796854878882146,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7726929617774563625,
+                        15592408517736462555,
+                        11912095093956055103,
+                        391369215740284352,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16352804994286198491,
+                        5284540288642141351,
+                        7168773058954986474,
+                        1110461938469735374,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2358357388508134954,
+                        11172442495343334803,
+                        11210899978311355313,
+                        35687869108438564,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9937076027196599045,
+                        3235273424359024983,
+                        11138331312417559201,
+                        487571128314277257,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        829272519477524335,
+                        6208433708366207334,
+                        2553626679725320146,
+                        367899262345166508,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12724706638242387802,
+                        8954575307991925432,
+                        10879556743602148448,
+                        834089888976593396,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9722893785702669362,
+                        16248955123563090083,
+                        3617858259181996025,
+                        148181197316944665,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        462614910765266576,
+                        7282548361586594297,
+                        14575085338934863160,
+                        45064584755879066,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11799926598650462200,
+                        9341700367985264147,
+                        7193742133471621797,
+                        1024913017630325536,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7690245026024476118,
+                        14343755290159572743,
+                        7996670361827151883,
+                        94617529800426016,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15085851249117989301,
+                        1178793135121103107,
+                        12845908152085687472,
+                        269228976196545434,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16136366841281146036,
+                        1704242288725304207,
+                        4198727846859661441,
+                        31995623246322379,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14226065739627939141,
+                        4396101690091354832,
+                        110659912439867354,
+                        768670203397056435,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9179873441518401125,
+                        18009774732908659069,
+                        17088975946917229729,
+                        533415743576440349,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14061890767533084123,
+                        4935090422910703400,
+                        15029419877286528271,
+                        1054508639156024942,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12891502087424881293,
+                        7848206624709091217,
+                        17248978929291204946,
+                        1170611233696304114,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2168355813549046907,
+                        8440776896121846822,
+                        4788656518527748567,
+                        71940595660863680,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4103256673370671700,
+                        11226489686233209200,
+                        8245043908398414298,
+                        260124621282421858,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5377316030777616766,
+                        2989034090186864827,
+                        13415557882679801861,
+                        253633275838490807,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10664090425808358016,
+                        15325709373613614209,
+                        12303897356629173849,
+                        177128404567627917,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9660409364254796516,
+                        5402952861576970016,
+                        11079997612891086969,
+                        901690211709921480,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        344447589998611855,
+                        12133503270640954142,
+                        10206865120970184522,
+                        300912733561277535,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6617335439740167283,
+                        3824662729000125844,
+                        2711429988931652116,
+                        78603785314336793,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16420860166601327798,
+                        15013010458350531276,
+                        14260225701946274349,
+                        3536993077880698,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12242335554557925987,
+                        5059476284096267712,
+                        9725780181483139410,
+                        294971490079250610,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11764164213025898800,
+                        13105528636290514719,
+                        8060464028877011232,
+                        1260683117103804730,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18211192077952650500,
+                        12919616022502744697,
+                        13749323164843860305,
+                        479851708543493732,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4619010012679924264,
+                        17154489243241164637,
+                        17718432478061342554,
+                        767308703420735595,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4633915337158296360,
+                        669647360353592920,
+                        13519529028853364939,
+                        697175924754062147,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9083960953569554317,
+                        8986567240142494453,
+                        14818469434313184006,
+                        266799082145592188,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14145418688383935698,
+                        14663184926113156248,
+                        10253034031528979194,
+                        265614829860450234,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4035194393082117656,
+                        5432767731338684398,
+                        12060690261456997695,
+                        747722751905109103,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17441827883786981757,
+                        8740354044787084230,
+                        16252467843157370140,
+                        926427615999691279,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18245293097973417138,
+                        8276715227092000950,
+                        9674335692590905169,
+                        976947362827197132,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8360020362403423876,
+                        13728008092042262618,
+                        5243208278946996809,
+                        1090206262626371851,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8499748137831289329,
+                        8910524611822826378,
+                        11383267116696494212,
+                        805019008066765549,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1821747469660731553,
+                        2991062774488570223,
+                        1937419205596327492,
+                        503223666442877496,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7100263656492941633,
+                        9756265857873474953,
+                        4335829796026278876,
+                        712257676978726633,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4002900468657556655,
+                        15071293280525886415,
+                        3846849214598250806,
+                        1197130964243834002,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1493192945394125323,
+                        8013047255462685871,
+                        16498276986304542866,
+                        889201532981282091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9140228564759824717,
+                        3819322052066421981,
+                        5710984255232627365,
+                        1083183397800377599,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        543829704886706767,
+                        11376297635509898766,
+                        12325619606892316551,
+                        666931810544902898,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2511781249567962966,
+                        16873250195885428595,
+                        10567752359467817335,
+                        152526022850486811,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16378925584846427831,
+                        16407244764116818200,
+                        4579740661899788093,
+                        303469776866938810,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12838994838528530918,
+                        3897271234261446072,
+                        11335523207481655599,
+                        755423152810203494,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1117848623596919613,
+                        1593174701088217464,
+                        15476766963777480309,
+                        1136243523109057153,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5800707219005992457,
+                        2157793306002832172,
+                        10819779683356294751,
+                        865792743092135259,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15789383676908542986,
+                        17841062353173862149,
+                        3687246603884657225,
+                        987688736146628606,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16128208473806851211,
+                        6519134072620628742,
+                        6508292462800686264,
+                        753880745736105415,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3570563014904320366,
+                        6115424325474309889,
+                        11880765716238004821,
+                        775782791076951346,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        254757012034376797,
+                        16677424428668253873,
+                        17326056754287001828,
+                        229340420767473902,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18052570023472357652,
+                        473308446918446170,
+                        1142176851168532532,
+                        657414153328886529,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14214289034044969526,
+                        1669588849897622273,
+                        14647811540816909282,
+                        493371616475667450,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4370305828531641726,
+                        2070398442876330579,
+                        1742315402599892626,
+                        1067674529044525460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        787764279013933733,
+                        8161362944238967438,
+                        13196484406603907729,
+                        1036012100743093674,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5703073405936753096,
+                        1704129229676432955,
+                        10642202841320414789,
+                        429947602738593125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16036268409866073315,
+                        12133735665452907370,
+                        12991399887397203327,
+                        1005476648805360206,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14186695291561434914,
+                        2764496007852416837,
+                        3968318079533357386,
+                        513582538126741489,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4590794339060818197,
+                        7130951807040105580,
+                        6737024702141898447,
+                        880652327729892742,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10240677935027961973,
+                        11583667797077152835,
+                        14260026022689188543,
+                        59482689316204197,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13187073903815578274,
+                        10262434367455488926,
+                        12433129998910301512,
+                        1152833354970461910,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5106319542904636572,
+                        8253668575769421712,
+                        609199310899606310,
+                        77663957235864229,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11365276130487554391,
+                        4580562374095799646,
+                        4003220009154335906,
+                        1330204458889772210,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12648817039353388238,
+                        5465678595537767695,
+                        3335490225339494550,
+                        917939768210214395,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8453867630655541168,
+                        13831887432351882213,
+                        6233371736293032036,
+                        1117278448074691750,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2768739566883087316,
+                        16948206958211416015,
+                        15587122883569168615,
+                        289359154703143338,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13408170959839761636,
+                        17073094760368121775,
+                        12563253339255778554,
+                        466727130581274946,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17851839063388275479,
+                        4906186412072438766,
+                        15934251055480823228,
+                        572527486985103910,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1308321193172398818,
+                        13112596713778950833,
+                        11787686977879035428,
+                        152704351273311941,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7477279510785863543,
+                        3530455007098931404,
+                        13352917164318835731,
+                        70608421538305867,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5896647810982895781,
+                        12898850733643362996,
+                        2243917277941208678,
+                        634129473656766559,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3234089865674647999,
+                        9751166919548448502,
+                        1398598161322721833,
+                        860372968944731061,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17990350572932196459,
+                        16199001135438427623,
+                        12537192300826253455,
+                        226184855388847238,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13552938911612161324,
+                        3549607306656175410,
+                        10594511808867258160,
+                        563831343613813018,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13185543715043698238,
+                        5029873778852089198,
+                        10400928009668752526,
+                        593849192849714255,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9159858404081884477,
+                        18384576710449007935,
+                        12787729052069820593,
+                        150451048258125126,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3659238069331434758,
+                        3054992480059232047,
+                        7901449960426643197,
+                        1230326599710820108,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18404995935092051699,
+                        9233237184670593601,
+                        2441460374333325654,
+                        1292138066074027198,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14786534265472050755,
+                        11405732012603777879,
+                        5098691005787877526,
+                        476534314645943721,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12870759893000809643,
+                        17796251666373539277,
+                        2944818341290420776,
+                        1319154299386549390,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17830670828937449862,
+                        18405182250167279460,
+                        17418841903055181120,
+                        971817289056065819,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16258423516850311889,
+                        1416977466959064020,
+                        4226708654115376662,
+                        1174014339129008000,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14060280991045183819,
+                        7177817866021110518,
+                        1160014587765224213,
+                        1142423887521984776,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11607394584259480817,
+                        16477596931573117503,
+                        7068544275319001682,
+                        349768871582089724,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7892772849264736457,
+                        12399693081732477230,
+                        860427683843737379,
+                        1245608562904040061,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14054890506972907993,
+                        3425503531427980367,
+                        12110719747589959339,
+                        1297230153409243247,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2610113287310478783,
+                        10025266942487475679,
+                        12426633063610218853,
+                        604518005320572674,
+                    ]),
+                ]),
+                Matrix::<1, 10, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16105215532897573015,
+                        12710934840330871591,
+                        9519808149546532322,
+                        792724764824025886,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17031575577766322407,
+                        13086432606393476372,
+                        444804592880138317,
+                        132874241000147094,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17736099474798996906,
+                        2567692597544909431,
+                        11410084257377309854,
+                        845177031722570557,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3997044799238351861,
+                        6032248831403336612,
+                        3381552955259091498,
+                        618638515010373,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6417458696363371468,
+                        17814131245958205615,
+                        7925970586941481841,
+                        597191644654754018,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5115211234876860982,
+                        7652314912464196897,
+                        7773268631690612251,
+                        873356364832825810,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7333768105805654287,
+                        7636866218343627194,
+                        11723714568795433621,
+                        611186480599219831,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5011906704756171609,
+                        17426060211396446990,
+                        10002963551781525667,
+                        1010366625603090136,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1586876916637581008,
+                        3746147173603322880,
+                        14412334716048993962,
+                        883230107131860379,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1430762882011452376,
+                        9279701845670743685,
+                        17774926799192274711,
+                        253275193422565825,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2124666949204268122,
+                        17868549573926278224,
+                        13377648236432012289,
+                        76508185047532167,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12468529521233858987,
+                        10711505901518971070,
+                        4598818191680682009,
+                        1253573039997503216,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7031960978200403191,
+                        2574615666237673190,
+                        17097789064224877311,
+                        611416421827088460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16116001355017634012,
+                        3797642348156488161,
+                        8442797550372214426,
+                        570942751708628769,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7550425512774939835,
+                        12155741381469351100,
+                        17744221774895938952,
+                        1039110227269251336,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8828528873936079174,
+                        16497727693982402703,
+                        15710697025169400283,
+                        326091050366916057,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17392967990311079192,
+                        9525303858791708772,
+                        12725344340629377967,
+                        905946062507784302,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15795078555222378519,
+                        4239053439859557467,
+                        17543334825201801998,
+                        1322375063585281792,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13665385723616569337,
+                        15192216537319734924,
+                        17244485342802624934,
+                        456061892597021667,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17065602944315846433,
+                        12599472757624769951,
+                        136350343153398653,
+                        297824106946277200,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3673696421853030435,
+                        16113413654668576746,
+                        8742106010918014299,
+                        752898338437515561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2924649915874609434,
+                        8235778354783718612,
+                        13306248853519204180,
+                        604580900179927634,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10017243217939605822,
+                        14465001998769770371,
+                        14647339395387827080,
+                        357935689941463519,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5558939297209280262,
+                        13915337935671765185,
+                        18190646905523245707,
+                        306939561748878494,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8622607188647696361,
+                        14496898433510896504,
+                        4380632066554553478,
+                        345014379233284838,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7345274676012935065,
+                        12538145864640407158,
+                        14483957236356786214,
+                        805425003629937533,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1570944440726208790,
+                        13138084745339564255,
+                        5685894021987857503,
+                        582267059569219280,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17049804420107647738,
+                        13708989679781060033,
+                        8576870141763170517,
+                        626314065083375890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12952850750869848576,
+                        1303524893123663516,
+                        5212627258784631596,
+                        500217237410494308,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6689932380531582407,
+                        6698935876764250150,
+                        4176044933044251587,
+                        142314537581111526,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9924575562847167169,
+                        12606776593548676856,
+                        5354258750977897859,
+                        510451584281653267,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16659407558651250002,
+                        17302366506788340804,
+                        6780778851698527640,
+                        72625832673946117,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7748362367806141753,
+                        2110484193437037661,
+                        13298378632222837310,
+                        1059921535753358142,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7544672656051543334,
+                        1883361104124814949,
+                        5123181552440211047,
+                        299239246780798323,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11536123394106811175,
+                        15412030763788433522,
+                        7962121129995966162,
+                        573097458906730848,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14996166771118359209,
+                        4719857869183456633,
+                        14680801379043101949,
+                        656639118341015746,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17948244156358769928,
+                        13144202279952579299,
+                        2926759896858702383,
+                        574313258393994085,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12110696654356540460,
+                        3967835953369325202,
+                        7872544905608217145,
+                        573254058004456848,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        317336577099133230,
+                        4751106988340640751,
+                        6595189674760673495,
+                        319432771502105136,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10698289077384400196,
+                        13428501558445202007,
+                        17267629813610367768,
+                        406845444735088857,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13527502186349842437,
+                        13255139835267088942,
+                        7349739121926044434,
+                        464116046475847636,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4407692073915624395,
+                        2080397055073387776,
+                        14738349756709513141,
+                        672305345692245737,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5388391858881720227,
+                        14293344584001768498,
+                        14261541269005952554,
+                        987546210043278072,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3201689280796061301,
+                        17056373588086195834,
+                        3143196784038109352,
+                        452554865097147300,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14251257040592975103,
+                        245107911758018223,
+                        657822648595599575,
+                        168804317262778856,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16242364790934963373,
+                        17349661880389183763,
+                        9930414347355347753,
+                        1207415923483281881,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18240878647323450831,
+                        14951718967410180865,
+                        18302106910260422042,
+                        107920904400119016,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5460242098010316953,
+                        13864033863478357532,
+                        5128402169074835599,
+                        1275572994740616528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18163492286068539833,
+                        1759217274957438327,
+                        8161941490677633372,
+                        243284400809909445,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1682611042587852152,
+                        9957195574560197726,
+                        6966653635599415834,
+                        704427905306369280,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7751448603421137213,
+                        16402291491458314242,
+                        4078345880654444236,
+                        1217399617182125998,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13738002445068276130,
+                        14048637665516382398,
+                        4231492884399494704,
+                        888051790060119112,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15630308984902485747,
+                        13918748031460238576,
+                        3555913648701526915,
+                        926915415695854370,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4317939027133062121,
+                        510525338050508420,
+                        15251395076282035110,
+                        1214639545052182499,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11643297120260163670,
+                        12644863966963578849,
+                        240476745440781930,
+                        627215428564690925,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12902066717268070480,
+                        1866498664245937284,
+                        1678208126303241973,
+                        483827520530908066,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11911488857624474896,
+                        6088127540650871634,
+                        6146057857204427007,
+                        819291061363123699,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9669125644589397438,
+                        7562163506580503632,
+                        9150922021366752740,
+                        349570960787385762,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6727441705320806860,
+                        16413371169551755910,
+                        17073287740446187252,
+                        816401064891358200,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10463755179932542154,
+                        10304073342316308078,
+                        11570144146161471751,
+                        1326013045270490142,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2063727354301327961,
+                        8003140835359536085,
+                        9324459676114457496,
+                        901100432941463020,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6018151156141611738,
+                        1987598566251888197,
+                        16905011897997046171,
+                        62942399582463781,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10963486875500639735,
+                        11014247922111956038,
+                        12456965485096136037,
+                        1074874895376910241,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10135978560845654411,
+                        6577412540119815591,
+                        16041667655784429552,
+                        967255185659453315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9267619933207840034,
+                        13515915494309976841,
+                        7033182030303166199,
+                        270482271162780466,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15027245578965425561,
+                        16112351791401709904,
+                        8247231400525739846,
+                        216843434674932424,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9228292242131497619,
+                        5334551666305689993,
+                        1552794478190250681,
+                        713748433977145016,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11158508750062946101,
+                        15510309088583818530,
+                        6096430739102109763,
+                        636951084887830876,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2893621837067158383,
+                        3382525772952615368,
+                        6899554222306823777,
+                        731663240662932511,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        396545438245193964,
+                        6787604565118768900,
+                        16800705277634811174,
+                        1344586422496194851,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5402655301433126844,
+                        9861764434983034305,
+                        3635942573971090834,
+                        674422484086773028,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14604524961268442390,
+                        2448346220849399672,
+                        13812924730262292154,
+                        1233239932727888750,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13512400347258922113,
+                        10398713898671911464,
+                        3085330884141645455,
+                        1280869317088203860,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8463216705665317234,
+                        16299484504429812590,
+                        17408772609663096037,
+                        817754940478676978,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4835051532990839492,
+                        5833638714234680215,
+                        1885146295119990564,
+                        264389521591842704,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16538229161071788692,
+                        5548046141344019027,
+                        14524502709639293104,
+                        993562182012459849,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10697125423723801361,
+                        13116601324908336066,
+                        7332827759458447562,
+                        394510876777072721,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3386709422923197109,
+                        4280442249985198097,
+                        183241281767112919,
+                        751394149928800340,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1373658526532311671,
+                        14035842410762494,
+                        13911656711422178983,
+                        445406536980976858,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17861028932938376285,
+                        205235743240172157,
+                        13146228289935713280,
+                        690874414971621513,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18299216916914296466,
+                        15605415656017533536,
+                        3920582374720546510,
+                        1199639423901780596,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7586593185495187461,
+                        1721055689554300525,
+                        12887047051690503948,
+                        509237536038014611,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13751532987929583929,
+                        1177635176924472584,
+                        2339835435153425381,
+                        1070067117324954352,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13171698880997391362,
+                        8676015750709244007,
+                        5217102304040807195,
+                        258955994029659836,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8841448634067841356,
+                        9620081827889872752,
+                        827442338831923820,
+                        958847460655498506,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4154257527809729676,
+                        2905958067204788431,
+                        11463217006022719200,
+                        1052528537258343223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8696112413774655386,
+                        6244245488040087722,
+                        815687257324088015,
+                        260488190636346448,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1935438595871263611,
+                        6375665243411118897,
+                        2960243036388211078,
+                        37123963406602909,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15391623670285338561,
+                        14352449368002492837,
+                        2847445055030514819,
+                        1068799633926336496,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7650640699073994685,
+                        6333611457426050595,
+                        17267012642200455231,
+                        105906708473791729,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2836175635402334776,
+                        1807259849579000523,
+                        98952164811665429,
+                        793859240931720722,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6476777604952273058,
+                        14327129427086342479,
+                        13754063401634936484,
+                        1025046950092663887,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4815667358028668160,
+                        7125499396586277275,
+                        18424278103241994448,
+                        931355374519958964,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4786039808211118901,
+                        12048854517145321479,
+                        854251193052184511,
+                        626459905677498300,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14014620770722739600,
+                        13658720878424136828,
+                        16311183116299426724,
+                        799906764587591184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15328524586920618394,
+                        4459544497768124468,
+                        12120551782228613592,
+                        1124476720766001542,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2061903210368274074,
+                        13184529701990832968,
+                        11271190451106530403,
+                        1208490270655654084,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11328018127139571525,
+                        12083347596091954095,
+                        482358751354228131,
+                        228724256509974953,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15732284521866970138,
+                        11747322592956993644,
+                        17135466854914168069,
+                        981843884952826070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2966616907312071149,
+                        6466756379474139658,
+                        11910644851984038862,
+                        470632184885371508,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1450312668345225922,
+                        12187233365366310893,
+                        5537846045734441748,
+                        1022181740406563788,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18333109401034142768,
+                        1556126264309443461,
+                        17990071847219480485,
+                        940429923168557845,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6466038268098161254,
+                        11949090995110291718,
+                        10346915039570289040,
+                        219584323154782047,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3799847900212036010,
+                        1096865054216851873,
+                        6337253432696454552,
+                        377417797949991290,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16528728553067293748,
+                        8200285924691179465,
+                        14645714636316762896,
+                        675984191775061569,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18088764447804537996,
+                        7117653596240969548,
+                        17337841414886733151,
+                        1086342970331801173,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4140312507411178676,
+                        9299090247602864010,
+                        11854798374069529970,
+                        538544869476954354,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14922986208050089107,
+                        6391543637078034306,
+                        4308733768672598524,
+                        349587469057091877,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6462079791307157030,
+                        3035792576435485365,
+                        2553213252600096960,
+                        1287060134615153523,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7393906377360082472,
+                        18021808970863894448,
+                        10522724665496235147,
+                        285195089555967164,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11911308203395536708,
+                        9369440531009534372,
+                        14529065772115169663,
+                        927199355310782227,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6515544332938929078,
+                        15228900551517144888,
+                        12705590729335503208,
+                        1298163730690461623,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3860841772688683399,
+                        11992493017572942216,
+                        15213800333284245360,
+                        1131755039148675701,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12767352998636094902,
+                        16364305175151359283,
+                        2841745188847894996,
+                        752358812357553424,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6812993231340872588,
+                        12246423589676220783,
+                        1906091438571634936,
+                        988228436886789538,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11982774440512278035,
+                        5752031836758101456,
+                        4685321371907116008,
+                        1264845011492209834,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12036320588246257248,
+                        992055506812368795,
+                        18056708547690324206,
+                        917631243422751658,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9917803214816681225,
+                        13731131142752911165,
+                        17289449971027627910,
+                        369194892691434771,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12082364901298704077,
+                        327132307808244890,
+                        18123402670345538820,
+                        893750494214099914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7069025213719612728,
+                        4083415679417809661,
+                        15238520879608654954,
+                        394740181779272321,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14646051212756415366,
+                        16501161211614525933,
+                        16170229196201442236,
+                        679886612408312397,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1803382870772877403,
+                        11122814725907066678,
+                        769352582642007327,
+                        443486383858022160,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2991930708999610802,
+                        9717129015941044495,
+                        14559341928281520569,
+                        727356764281423916,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16020437192446660140,
+                        1927739627321197298,
+                        11583092564425432205,
+                        556423785553101804,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10935955783568781174,
+                        15018609309575986020,
+                        13605374897967784123,
+                        439629298746937151,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12165123479502050030,
+                        5495272682418678859,
+                        10139218126798414679,
+                        184975146702358369,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13209566457154325828,
+                        8005338905791656880,
+                        10911154384898950438,
+                        276094661864107620,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5372860341584424282,
+                        3288377351162576647,
+                        7812216868661450994,
+                        129904342771075473,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8194806704120839627,
+                        801802250924725130,
+                        13997874138426883346,
+                        429350017757474479,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10674689880868109021,
+                        2941302635128406761,
+                        7696074052727801501,
+                        1262088975032618588,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1460761196660377215,
+                        6545689220601271913,
+                        1142190095957768938,
+                        837079165116281492,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18145210625922522945,
+                        12432191747888376356,
+                        17018577597262557156,
+                        698044901851709275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13904917043929059067,
+                        14371641946762528703,
+                        271928580951532855,
+                        1283477467640794619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2263818310082399471,
+                        8835564587412572121,
+                        14061187418155065795,
+                        224529240408425222,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3470636856835601999,
+                        11469020443548636258,
+                        1591941230346790254,
+                        830845110841274642,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3711390903923883942,
+                        12052467894644281778,
+                        9256822657493372950,
+                        563276654422786037,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10019810813504235973,
+                        3389965099029711555,
+                        12086462712818517415,
+                        192901100769241060,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6976560083668337494,
+                        15914322996578864187,
+                        14093011763248859778,
+                        137663208433628023,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4742991272324818376,
+                        6357657650262605583,
+                        6316443480383595299,
+                        894984601479128695,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12399800935584004823,
+                        6916080611290127867,
+                        5398614876329289205,
+                        1018694678927184667,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2465571212644631579,
+                        17680787031177378137,
+                        4660252714042603290,
+                        157515761397686072,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17092702760466176343,
+                        572159208906875678,
+                        7624824932348055134,
+                        209060917852359063,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10865877615877772435,
+                        17263956030535392397,
+                        16041327143904515357,
+                        1102975502144669812,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8947750251345431070,
+                        10307689652023627105,
+                        15800759019743082519,
+                        501303495087515564,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2502169817982242566,
+                        12708884036179023474,
+                        2789291909372272578,
+                        159070571307588229,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14677316186073258296,
+                        12730492381731043223,
+                        14474352769385311659,
+                        970193412848151228,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3904794528900776054,
+                        5736544639754824430,
+                        3430116008651576003,
+                        312308325313232719,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12089819813659109985,
+                        12603999119309470603,
+                        15392016510455531936,
+                        344779329693010981,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1286412499512150362,
+                        17702263091311208808,
+                        4089461493956933622,
+                        1327116060834415525,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13372828742988267458,
+                        13467505264154743166,
+                        16027516323111880295,
+                        358911790423723285,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1495932511747421354,
+                        15872291009233781229,
+                        2598119935794193685,
+                        549466561411999088,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11811009733385986099,
+                        10420613235354492373,
+                        14167454265119558959,
+                        644135313516311841,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15777001185913973051,
+                        7733631054312512097,
+                        7798945472103705345,
+                        184334669858012635,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8051201715658152681,
+                        14726339096056505885,
+                        2362171512365886627,
+                        31548235113267749,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14209899981472141147,
+                        8989557772270580647,
+                        403526247822386440,
+                        1158768449089878435,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3801547078206802469,
+                        7052387330178139558,
+                        9347977138939902974,
+                        50149508200525186,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16651142616773619247,
+                        14744731750181673890,
+                        5857885839267952048,
+                        1224213573117217712,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1703787943678846465,
+                        7811190272155233910,
+                        6024973357674965557,
+                        312331899757066880,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2112829831101448099,
+                        7767394931088287967,
+                        11104578816107807793,
+                        500348469309183850,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10623832152263301271,
+                        2398464053590914924,
+                        16152715206825971693,
+                        568391041795268541,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9387935509828281856,
+                        13500523574900675390,
+                        4837037024995521700,
+                        142014063756132410,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1088780415123929280,
+                        3669534707290646671,
+                        5245394148410210427,
+                        1333500401039887205,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6913836163330821316,
+                        16583810173398260920,
+                        11772722499680709358,
+                        273998718141973238,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15804644139460595100,
+                        10530178686651142604,
+                        9364892750729308025,
+                        724334428082523770,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10783293333021748459,
+                        5403379889325688026,
+                        10975555776635276682,
+                        1088565003150970417,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        795841096194960746,
+                        8313454537087764390,
+                        8540700109531558855,
+                        368351784736742692,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9167362804796986996,
+                        9609704022719897864,
+                        8175341565601795914,
+                        115829892092111097,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8786745983896607056,
+                        277695348361197025,
+                        11244670877770076715,
+                        1274146893043555603,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6584036772128619597,
+                        2907810273949876935,
+                        1899021765125861203,
+                        1229989218635372663,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1836065354965734758,
+                        17678055617073430099,
+                        3289763363189591996,
+                        798506974279190974,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10937098150983491839,
+                        14458511532646030438,
+                        17043777771826193429,
+                        716225982540371872,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17488007790239587,
+                        16159278274011363351,
+                        14441748411663488709,
+                        251739981560143045,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6123777663074863947,
+                        732851281335716366,
+                        9580773734752958057,
+                        249635519955307118,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        22112601956099617,
+                        14072401287901833791,
+                        16839174483328616645,
+                        531727774189542700,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11043833401445400327,
+                        4050037298187427195,
+                        14400211931312430213,
+                        520030200201813795,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15403978746494291599,
+                        17290254959021967543,
+                        15778403648279340586,
+                        689280147282955670,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3736225449047004922,
+                        18183764317579543053,
+                        10123888275681546501,
+                        938950158183721119,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7507115665426209195,
+                        15132199365434387819,
+                        18204576045155875161,
+                        1229665367173038088,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8630159250874875155,
+                        11797749805175474623,
+                        3160301197873592420,
+                        602922855625033314,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10466673479404325131,
+                        6613974500825870439,
+                        14012406571958401815,
+                        81905733537642381,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6862201440397514153,
+                        8221954468656304880,
+                        3501705460358667554,
+                        282313185991595040,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13736117601006383227,
+                        15241368906616906159,
+                        13715347100746874122,
+                        191318975404372610,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4708367771721559529,
+                        9136492732168353053,
+                        9963304033929507708,
+                        1113756539320816830,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        788193601803325983,
+                        14773351337675782436,
+                        17425928947823632192,
+                        351264106938372448,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17761167021693312732,
+                        17117762054749855412,
+                        10466399562854454609,
+                        347231800972380971,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11608583822779475705,
+                        17746098718902620311,
+                        9614577810803980959,
+                        1149019117889717018,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16165736239982068208,
+                        6578998553523417190,
+                        12953871749274306240,
+                        269363222004112017,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11362419204080882998,
+                        8567027281041004539,
+                        3301125571283241290,
+                        748713980668127528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10711395965782944797,
+                        17533809888554965624,
+                        15514738362528537979,
+                        648946117781722982,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3330570346980371449,
+                        7198334039284456424,
+                        166136364971586111,
+                        554521083810533844,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11391350528528446623,
+                        13056702319457819646,
+                        12918797479947417610,
+                        1130788032878924633,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11162681889975472504,
+                        893387794647926103,
+                        10618976371881860044,
+                        77524440681216808,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6486898319859612475,
+                        14268177826998346825,
+                        8705580703953305126,
+                        336330622327977969,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18093648736956189073,
+                        8888713241962350678,
+                        3105919943120162984,
+                        1130630391127006200,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17734950959028786524,
+                        3220176738714453187,
+                        17922572482015782546,
+                        573906120395093978,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17194903067811312071,
+                        14696220534945405100,
+                        15901979994866825055,
+                        806382953118898601,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11485272113399090525,
+                        6471869561123460971,
+                        10286226734262758388,
+                        934145902725948799,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        21716375050420294,
+                        5788149115597791707,
+                        8367237848544904859,
+                        615022151183107307,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10200251763520768303,
+                        4578284237459762363,
+                        3921925835655066368,
+                        984702205786070481,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14212922864276936415,
+                        13689690436285275252,
+                        2490617151762295100,
+                        537652520701836492,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14301743687206454350,
+                        3929303322462753749,
+                        4369410127399448139,
+                        1327379962592972774,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7995952369149167567,
+                        14018173437237408291,
+                        15718871706990201065,
+                        81148349491989221,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8562306357002449065,
+                        15898941950106185756,
+                        108977511051244115,
+                        115432616586980198,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8250847775779316966,
+                        9026846713285958031,
+                        3707237180014003825,
+                        835294922804081131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7605045414342888143,
+                        15065827567917638084,
+                        10274540909432223510,
+                        778105083904140052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1259701812219457733,
+                        4874007303352635697,
+                        16333249733190266836,
+                        1028272172690387353,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14809915447654809346,
+                        2723878446759440776,
+                        16020617018199087739,
+                        1206461629728684034,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12685873190777491485,
+                        16811035305137922786,
+                        12797663476375480936,
+                        1042567121276471141,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1476671739974907360,
+                        16264751072862872140,
+                        3584239099182474687,
+                        1273868365927559759,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10638253608258395693,
+                        8416893305413567450,
+                        130170405657450896,
+                        1073812757402558562,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3854075152523962034,
+                        3591504098824967387,
+                        14230618208379699820,
+                        903998436862746962,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11850635707380884606,
+                        889924483945714117,
+                        2493699742081673820,
+                        733438017257585568,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15982659023475084276,
+                        8946078240611060616,
+                        619345927141897386,
+                        1071478013113825890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10122235365680424115,
+                        9388999708078627840,
+                        8431999788742084215,
+                        856502278638026535,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15819945515706869437,
+                        15037056255314644213,
+                        4345954839497231667,
+                        507240656065772899,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4724458565480271729,
+                        1217283698018874029,
+                        15835473834172770159,
+                        37356058926650890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10012824300836249519,
+                        10046246817919282054,
+                        16979086247714705482,
+                        330949182153297523,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5740596034474142299,
+                        4629724483015798297,
+                        4284006659805551866,
+                        456174203533635216,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15901595557160925834,
+                        11858914857014279698,
+                        16441399513598760168,
+                        309894690312958560,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3499154083233638743,
+                        12025172112887107528,
+                        10423185010068220496,
+                        895449418022721578,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11830450623821169039,
+                        15299080417736048492,
+                        11968817218727168582,
+                        930832210768898674,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6709422758798062337,
+                        35387065422369599,
+                        1815331380513120175,
+                        452276764956211388,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11702535393185969746,
+                        10198725046067990305,
+                        1969325163575923448,
+                        48967810161475862,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3245969749912202065,
+                        13644797784445059842,
+                        524509174067698588,
+                        213738585121587629,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8141467080406319818,
+                        16729573815801884699,
+                        6751357516160046987,
+                        746073314587903568,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10300747486624452506,
+                        13992695104775686839,
+                        16848208973180013225,
+                        931145721820519837,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6820538006330627285,
+                        17441303386104042535,
+                        13409816719242474494,
+                        435950068210201910,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5186141342102734095,
+                        12836346721663134460,
+                        7637709585915769445,
+                        705185870593357681,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15963571463052801915,
+                        5941207234290861883,
+                        5296434972036419741,
+                        1235324968064109585,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6401383122330977662,
+                        16945352087774260585,
+                        1470586474763293901,
+                        1236778760036688234,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4298802939881947775,
+                        12699774810365240979,
+                        18242627165361945060,
+                        1210829361532368402,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12057739558466863422,
+                        8633364710722765389,
+                        12347780450416852488,
+                        122399459391603093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7792399449218355883,
+                        13737802674518386323,
+                        10216848459701984047,
+                        706833546920659947,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16226160395023259446,
+                        15901650473121909796,
+                        298641385962372901,
+                        492854808140560587,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17048075620836166771,
+                        11722359684927680946,
+                        3115303908871691457,
+                        753517435999510547,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6006416809066159842,
+                        3885423831250569831,
+                        7156981116286599638,
+                        1114415332393649228,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8518823716042834120,
+                        322042820531197088,
+                        18011577532166285826,
+                        168967278368863176,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14153681347151705917,
+                        15210531743942663493,
+                        4789589660369940513,
+                        962707034812048977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15599581887416514762,
+                        2829688123290414599,
+                        875211970033523227,
+                        971618233642143187,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17233523227906468029,
+                        11029194696140497197,
+                        129898440778989049,
+                        252072934206909170,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7258863259048286426,
+                        18350950391267608031,
+                        1799949745034911986,
+                        114176513135309171,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11711884603349038115,
+                        1881140400107969182,
+                        1314942840526127565,
+                        675252576590638551,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8064063596630465433,
+                        17888503919613841043,
+                        16798949233053655433,
+                        78136277208627344,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8638274042139150511,
+                        14472172685834137044,
+                        12524653430238176749,
+                        281479034516134225,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16005323051230290756,
+                        1428670452824893002,
+                        13947296954627801428,
+                        173387528011669725,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11243638128330658053,
+                        2270371744390556491,
+                        14058786267486418872,
+                        1338487908754128856,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18421019754524053967,
+                        17277656831955839487,
+                        7467581496930945077,
+                        230571638537208110,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10303769449532749420,
+                        17125698903632031718,
+                        16232850622324083362,
+                        310590334126707293,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3681755660704374538,
+                        14454270316537837704,
+                        6815356449340797193,
+                        754196627035873709,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16971024862611011223,
+                        2139096974226449513,
+                        8108563707026646630,
+                        1164945266635694543,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6747894294294620532,
+                        933320122806408750,
+                        9688514786949696208,
+                        1294632179329442761,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4401333405511808822,
+                        16685963769948870904,
+                        2314650422982079191,
+                        29129684088702424,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1281246744422645776,
+                        10283976650547987250,
+                        11586387664097883838,
+                        1330437089047822347,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10048336899179041861,
+                        6762281089744722761,
+                        10877937795426751078,
+                        1188209803257800936,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17089838596832979512,
+                        8596978209382919105,
+                        14935531031550150599,
+                        461489532296074994,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6561485018003232852,
+                        3143413337660683524,
+                        4567512450288475578,
+                        321433604937509460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15884818643286472428,
+                        8001818919130848501,
+                        13180831478252588224,
+                        199958868779807657,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1288226517226169222,
+                        12090956101707117561,
+                        13569775309615183811,
+                        792915741986308987,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1121947585202469349,
+                        16661904975597592861,
+                        11804107955798575214,
+                        303475184174301712,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9233191199305224940,
+                        10401336144255053632,
+                        17303108473248582992,
+                        968868400680974033,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1440344529174451289,
+                        16560001116859565608,
+                        8168818935668460307,
+                        899461604687248105,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8943993057025220264,
+                        10180556536085429812,
+                        16266976267815566187,
+                        863653763028442424,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12133336953649567977,
+                        16824263443518872463,
+                        4687577105730982111,
+                        808037555503150829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13236687095969144508,
+                        16458781263389359456,
+                        135625984488950247,
+                        374740921784936326,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18053177319324338078,
+                        12275769129553242736,
+                        9962183455306688617,
+                        777078338991321524,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8339524935748090819,
+                        5637659384242449902,
+                        8848127432758477195,
+                        601162477068100848,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        413687815808895000,
+                        4004763990854307917,
+                        4501644480976727488,
+                        573811691771766358,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7457209465536909381,
+                        11570163446608151348,
+                        10530553683628690351,
+                        826220419196637264,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10274332033653529395,
+                        16757678960853643452,
+                        13349751280159128484,
+                        368483883113355151,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7235043284165664063,
+                        15070828560041816082,
+                        8574911695067309516,
+                        369406356164737007,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4209660642567166510,
+                        4160338676574462485,
+                        2651919250513754007,
+                        222702496122717226,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12745780727945178447,
+                        15272120693885512058,
+                        4486777515170039899,
+                        1303944111177091785,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7185094350621581328,
+                        14452926270773225731,
+                        3390610688527372758,
+                        1251064400479663329,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18100301282055862920,
+                        15166547966043571775,
+                        2699171608246215922,
+                        332870878085891795,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5456498648489510641,
+                        957703431438745787,
+                        11835667514381774621,
+                        288223460660656941,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7305986538929797279,
+                        11557782751024682695,
+                        1031350013603137810,
+                        1334504594858038578,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12028900851285101229,
+                        10341021634680379565,
+                        12158258634541865431,
+                        1272427388570410770,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15624310133402585020,
+                        10447192851816540402,
+                        18132085303243873512,
+                        318850153530583892,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14016791765296899842,
+                        6889832459512019469,
+                        10025974783090102011,
+                        979004800071889071,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6320099589798429195,
+                        225227379901692302,
+                        3614184862938255630,
+                        187756163161338939,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4012429953609589680,
+                        11261080555510582921,
+                        11413950603512082137,
+                        1068844975088566697,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        550620624112007120,
+                        14099400483516925153,
+                        1650355906382849742,
+                        228275393699484544,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7763516570898270647,
+                        10384677053924964806,
+                        10141745806330963793,
+                        216007282128540792,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13131383188750294599,
+                        10100324317848595162,
+                        6907226423455497247,
+                        873940304971864929,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1292509091149277546,
+                        112519555316910650,
+                        2676057956486709536,
+                        1222297360476954355,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4286501499563327666,
+                        13947742739104192709,
+                        17762646077351006387,
+                        798481619044185417,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17542688158441095992,
+                        8520716762916436090,
+                        17831226304664001659,
+                        542218186381185851,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15016929587603602826,
+                        5271727368368461650,
+                        1484539389610224739,
+                        206676671126833326,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1926104676692059191,
+                        13070346966428302467,
+                        6271173486228729339,
+                        1216756811310110483,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6230890396247941607,
+                        18401378248076638028,
+                        1052250640675893199,
+                        380964903014300248,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4241825452799053159,
+                        4905070300760658019,
+                        4408664369048019946,
+                        519502694095351715,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15154169277232992493,
+                        10834790114311962477,
+                        13676746078109260797,
+                        781439943245341099,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12327222137734077435,
+                        6275561157246314404,
+                        3522474371326187580,
+                        1123064152705968229,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3030193125721546788,
+                        7831002641922871344,
+                        9263984968910026253,
+                        982774697289788621,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15999165289192036473,
+                        16440726898840564895,
+                        9954653196664909350,
+                        1225749423917538563,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10223237968555152807,
+                        14309027688547609651,
+                        15320414097190844662,
+                        29320313826214759,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13941656661101125717,
+                        9241602070925287261,
+                        16672777519331940988,
+                        398398621233016771,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5354988553848837282,
+                        10227105544253136500,
+                        4136555990790584096,
+                        1183664269425751119,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8757623850716097999,
+                        14766440401243877969,
+                        16591116944596527383,
+                        637682214078344463,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13700021801681568938,
+                        3318515099368216028,
+                        3576925319074446740,
+                        611174180890815862,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4409986947849423066,
+                        17926782391344790976,
+                        3290268057553353652,
+                        76261273636455432,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11208768245385557774,
+                        15903175243813492353,
+                        3173759194275818170,
+                        889396988328622656,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2739965618295027352,
+                        2094453962802238019,
+                        1389022165249968216,
+                        137973929680021868,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16988404678764519353,
+                        4493921940764927979,
+                        12922745708188899020,
+                        413687481636287015,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13684359162943852632,
+                        9858522229360260662,
+                        13451744733603674190,
+                        927619933620850474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8899435224378175208,
+                        15570870945162282210,
+                        4684478579878709035,
+                        1041393872094922872,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11218498580756749749,
+                        12775715772879384788,
+                        7502513465255803316,
+                        1111574333020966098,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13972083330195848864,
+                        252895287929623877,
+                        5288029470313943887,
+                        1041682884919198699,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5444662717188031562,
+                        18194052928612694300,
+                        14659981451805988389,
+                        490687036695695472,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6517440143633829827,
+                        243111228343018165,
+                        12983876508600339212,
+                        1234012756867224799,
+                    ]),
+                ]),
+                Matrix::<10, 1, 10>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6031266053851095875,
+                        11469689306643674921,
+                        7870548202207660982,
+                        31195858073361496,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9070900518170275890,
+                        8633349635691387285,
+                        266470026003502541,
+                        698650509113048377,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9789900706725673294,
+                        18366011648810526082,
+                        474104816353767152,
+                        118951054029063689,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4505882132072785427,
+                        8403274441932963903,
+                        2674527246608696825,
+                        568118228317585615,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14776602745523327218,
+                        12747488066767951974,
+                        14952589931183935233,
+                        1230684876507645936,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15611069936045993891,
+                        13642345561429971075,
+                        14975179638602646556,
+                        1001672671602668323,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        77155993447215470,
+                        139346388587893239,
+                        10578205068565696407,
+                        366159849992033058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14404634432408417137,
+                        17242665352346446279,
+                        1401961359059168123,
+                        385199095629240894,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2452861819918905095,
+                        11720033082415251983,
+                        511811738659944895,
+                        366275456328671223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        564058673103217630,
+                        10059730543811712436,
+                        8531583237455709291,
+                        496671210542091678,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<39, 11, 429>::new_from_known([
+            Fq::from_montgomery_limbs([
+                14131054418777488854,
+                396201730103805847,
+                10716186622642889704,
+                856377251280961738,
+            ]),
+            Fq::from_montgomery_limbs([
+                14159017566367665609,
+                16475790876623558337,
+                16130819625780716090,
+                812130851825605617,
+            ]),
+            Fq::from_montgomery_limbs([
+                10606257543333808031,
+                5707856953834521134,
+                17983729648524286828,
+                957065544299167485,
+            ]),
+            Fq::from_montgomery_limbs([
+                6878796606992495895,
+                8856573295413326897,
+                648803285772359125,
+                760051340846749185,
+            ]),
+            Fq::from_montgomery_limbs([
+                7400754013503752250,
+                11196556398769135870,
+                16048325941650866236,
+                768883517817519486,
+            ]),
+            Fq::from_montgomery_limbs([
+                12381053993196356774,
+                2550120875241147891,
+                10388413343741381007,
+                263779225291900913,
+            ]),
+            Fq::from_montgomery_limbs([
+                10983348790583613482,
+                440647102037862789,
+                1019191662018519122,
+                1226012539888459601,
+            ]),
+            Fq::from_montgomery_limbs([
+                15577318595772972195,
+                17607278286106180828,
+                14497383893778094429,
+                493249527998681286,
+            ]),
+            Fq::from_montgomery_limbs([
+                17831646288271643414,
+                17505126732804397166,
+                12981417346481678792,
+                874183644415012319,
+            ]),
+            Fq::from_montgomery_limbs([
+                2617515050485373089,
+                7609263567260690216,
+                11327828554351122713,
+                331772373735536183,
+            ]),
+            Fq::from_montgomery_limbs([
+                13247508706634788003,
+                13020182594489846012,
+                4372426384807603895,
+                1097226436963926611,
+            ]),
+            Fq::from_montgomery_limbs([
+                10721272377812258791,
+                11876612898022753835,
+                11948063768216928972,
+                9771532930211916,
+            ]),
+            Fq::from_montgomery_limbs([
+                13379504513870505926,
+                5845858180964105873,
+                889707027654931036,
+                1265790587027212570,
+            ]),
+            Fq::from_montgomery_limbs([
+                4447488680794998826,
+                9246168605084850420,
+                3291003783612107978,
+                265899312720817807,
+            ]),
+            Fq::from_montgomery_limbs([
+                665057425395378301,
+                11766068748612278406,
+                10520922414257207266,
+                835304351079404733,
+            ]),
+            Fq::from_montgomery_limbs([
+                3898866475532241445,
+                1152055524368575410,
+                16077254628997370065,
+                365057244874627274,
+            ]),
+            Fq::from_montgomery_limbs([
+                3140772546930670733,
+                11949255924741779677,
+                10357197867991389857,
+                1100059218462113054,
+            ]),
+            Fq::from_montgomery_limbs([
+                11192592590115335074,
+                9837685389025563344,
+                9493158642047160754,
+                758559153214604442,
+            ]),
+            Fq::from_montgomery_limbs([
+                17266978040569687959,
+                14495298528849212170,
+                7938336919058307042,
+                627809548770967299,
+            ]),
+            Fq::from_montgomery_limbs([
+                14257124664885929242,
+                14243644806647218635,
+                12777472786425265320,
+                707056327364571007,
+            ]),
+            Fq::from_montgomery_limbs([
+                17717595291600038578,
+                14013583214152537438,
+                14022910745577464285,
+                1279071614801530740,
+            ]),
+            Fq::from_montgomery_limbs([
+                3980929200573455294,
+                5142079044939144408,
+                12829295618389177610,
+                422222167392554867,
+            ]),
+            Fq::from_montgomery_limbs([
+                14885001468087365778,
+                7573026965561486433,
+                1613637141658861325,
+                531106786975717145,
+            ]),
+            Fq::from_montgomery_limbs([
+                11918661544252571702,
+                821183842587206068,
+                12613760167538206003,
+                1255807271791581663,
+            ]),
+            Fq::from_montgomery_limbs([
+                1325220430074941210,
+                15564362403535057990,
+                15959187642898089850,
+                1247358261719249764,
+            ]),
+            Fq::from_montgomery_limbs([
+                9816089363295956,
+                6242518884794997712,
+                11701938109987035794,
+                582597716952519190,
+            ]),
+            Fq::from_montgomery_limbs([
+                3515356696014383261,
+                15261136382978025771,
+                10075082878724311485,
+                167495628706570933,
+            ]),
+            Fq::from_montgomery_limbs([
+                11534725860438110059,
+                15928760277397233955,
+                5949584697149749379,
+                806560199257584950,
+            ]),
+            Fq::from_montgomery_limbs([
+                8808774936506915762,
+                12638464696777057929,
+                16999732419701578242,
+                123783177466243044,
+            ]),
+            Fq::from_montgomery_limbs([
+                7498751366617875652,
+                18044204780868637633,
+                6072697687797469560,
+                1296387161708738860,
+            ]),
+            Fq::from_montgomery_limbs([
+                1707055192244912406,
+                3013405036080013183,
+                84341988700764617,
+                1296143434529826906,
+            ]),
+            Fq::from_montgomery_limbs([
+                8393222070270342341,
+                7607268795872281977,
+                6842789420562568882,
+                1282314820114071423,
+            ]),
+            Fq::from_montgomery_limbs([
+                11979611098910610052,
+                17852071314266815583,
+                2373674796090549947,
+                1341873180636211404,
+            ]),
+            Fq::from_montgomery_limbs([
+                5363908796662445939,
+                15542369272824421054,
+                16050570237375653197,
+                982641266612013532,
+            ]),
+            Fq::from_montgomery_limbs([
+                16909574450905146993,
+                553337112694813422,
+                4175372255115864803,
+                610410898248057387,
+            ]),
+            Fq::from_montgomery_limbs([
+                6767505859809311541,
+                10693196442741514204,
+                9986241465096252756,
+                722800719558255923,
+            ]),
+            Fq::from_montgomery_limbs([
+                17205297488478347866,
+                14433306589503049808,
+                9179062248843807411,
+                76390074461146517,
+            ]),
+            Fq::from_montgomery_limbs([
+                3683944305798428896,
+                15578366475882024292,
+                2575308672979133627,
+                87643516194004288,
+            ]),
+            Fq::from_montgomery_limbs([
+                16848704354962426609,
+                10754581896689200393,
+                12595375850726877236,
+                1248087486250545005,
+            ]),
+            Fq::from_montgomery_limbs([
+                2910875184217387298,
+                13916125098400420580,
+                1380839709793729387,
+                248653651447658865,
+            ]),
+            Fq::from_montgomery_limbs([
+                13342923149676514039,
+                14204741375918420643,
+                3078149257126236769,
+                214348459582899690,
+            ]),
+            Fq::from_montgomery_limbs([
+                7193796817462321547,
+                15831654021932264036,
+                2848792915167491320,
+                115945105252202350,
+            ]),
+            Fq::from_montgomery_limbs([
+                5600101877133926022,
+                9883149222397911984,
+                17422107274899123009,
+                963356694814185354,
+            ]),
+            Fq::from_montgomery_limbs([
+                14873627637488587773,
+                1057188953344876822,
+                1339936461542707327,
+                138716589258740178,
+            ]),
+            Fq::from_montgomery_limbs([
+                12059290824584550131,
+                11732708759530020628,
+                780059053182505933,
+                511930134672770392,
+            ]),
+            Fq::from_montgomery_limbs([
+                15850017159122981288,
+                13304531836111186993,
+                12665525141442127911,
+                970411984000016113,
+            ]),
+            Fq::from_montgomery_limbs([
+                11565790762359116054,
+                12720175933020046679,
+                1999903244787310319,
+                42322013682854702,
+            ]),
+            Fq::from_montgomery_limbs([
+                17369443232234541906,
+                12184693678531801255,
+                2870396793530896160,
+                1295957728854833224,
+            ]),
+            Fq::from_montgomery_limbs([
+                6302996149087471342,
+                9850379949490709922,
+                13884737490605011997,
+                229425383459309281,
+            ]),
+            Fq::from_montgomery_limbs([
+                13493724703755832903,
+                3286054523679538842,
+                7718533558395339060,
+                582896685724248182,
+            ]),
+            Fq::from_montgomery_limbs([
+                12584675795022448737,
+                17689539731978381656,
+                8134707317031907158,
+                385886221519223774,
+            ]),
+            Fq::from_montgomery_limbs([
+                14162958758209240822,
+                10530121022949775013,
+                12547745712242442278,
+                1112528735389083711,
+            ]),
+            Fq::from_montgomery_limbs([
+                17425577910455497573,
+                12054953510652989024,
+                16395471228127335197,
+                429404855437509753,
+            ]),
+            Fq::from_montgomery_limbs([
+                5295990883643945810,
+                10672625132214854818,
+                16976783524375050427,
+                1268910850398563737,
+            ]),
+            Fq::from_montgomery_limbs([
+                1951451476617717466,
+                15086148686356987039,
+                14063391239041642220,
+                635457715227309643,
+            ]),
+            Fq::from_montgomery_limbs([
+                16643481056424663109,
+                11765639621884641435,
+                598494957852873401,
+                1258037728405017189,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8753352385737886982,
+                2375779946582677330,
+                15999833750731381198,
+                1314037764219698171,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4630449991097531490,
+                2427870966212641457,
+                3215069940516610669,
+                667436650014288957,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1411351081582470552,
+                1205067104848710841,
+                12753727477305546457,
+                567282684185166711,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6525949375522276666,
+                9554082114934090324,
+                6536014884255370779,
+                11767500026703582,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2670232351573484542,
+                7978263678443121823,
+                18365756034050304679,
+                869820752356899881,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                750023723679236266,
+                3201068394232714408,
+                9812811644084917844,
+                964313486589231148,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10093044425187048041,
+                11580302495938809169,
+                12941409723128006206,
+                378589500331140762,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                474144526135610427,
+                41863571833094683,
+                6716108180275203353,
+                1156426156260482108,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                18106023094041245175,
+                17206947847478315942,
+                13634920790964153453,
+                1210863689523061929,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                18420839758285593800,
+                2387885845127898463,
+                13896609021959572390,
+                494025125139926165,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12362977066444120225,
+                8615994893060616562,
+                5763941552655831818,
+                663300418768567595,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16177419068301528779,
+                365984886720958407,
+                11223147373006561785,
+                922126461702072273,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16021212957145698337,
+                5395625038053274374,
+                1226191836970769893,
+                815453603313091632,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1318386382952698541,
+                1816358329145004986,
+                14828780367669067273,
+                84238018725636333,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10158068344801578903,
+                418611242656817581,
+                17591293119161739305,
+                461951564865632490,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15735800420252047139,
+                2797007668635117186,
+                5980894187712613680,
+                1126093348284132068,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10140333853325137544,
+                16692133259887095479,
+                15350069286015355046,
+                625827498980814124,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6733706531115820356,
+                6049272582565075780,
+                17017223873148836034,
+                544543860816609154,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17595727422891765047,
+                11648007878297222224,
+                4970378951417772121,
+                1248726721623678389,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3161460788611062269,
+                2609427623215193671,
+                13017675392832136454,
+                915302201731329036,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                799042127992454960,
+                10520951528690576030,
+                9193529745940744933,
+                996104157977025112,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2521927381479541088,
+                7177553562316236133,
+                5575129210886753467,
+                586565180417295208,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17057114580939415796,
+                15041436576827602628,
+                4978349808783063216,
+                984312714281276215,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5069139883032251227,
+                73340173930402506,
+                1773901553693777411,
+                1147582854577347865,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14060859991047512280,
+                13185972746325584321,
+                8346857568029271460,
+                636825467439495036,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15237130577239872064,
+                11006044460127073712,
+                8959519667778161703,
+                478217039909426690,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                18120242321919673218,
+                4804363157751500142,
+                16794365937417942464,
+                870802263740123,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                361000288114448370,
+                9107777556523344143,
+                294198611573851831,
+                1302728107685372099,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17126238081285902863,
+                14891487835309946411,
+                4356042945388688768,
+                473441742689997174,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12565916870343280604,
+                4719806775418042250,
+                9268759002568850608,
+                857283793569542841,
+            ]),
+            Fq::from_montgomery_limbs([
+                13506052259667953337,
+                2630987589599440761,
+                2187077608214970450,
+                141839515279816533,
+            ]),
+            Fq::from_montgomery_limbs([
+                3179608595359288001,
+                1789108272608736026,
+                7518927380750473328,
+                154675111155940010,
+            ]),
+            Fq::from_montgomery_limbs([
+                1456219113857893395,
+                4464106645847553783,
+                12914469701711590009,
+                1126069924153909720,
+            ]),
+            Fq::from_montgomery_limbs([
+                10730533131046804168,
+                863055231130510993,
+                15470858811836137843,
+                114427902963614568,
+            ]),
+            Fq::from_montgomery_limbs([
+                1527636679737847491,
+                15415609664200649127,
+                16563577550115140186,
+                90284266777654489,
+            ]),
+            Fq::from_montgomery_limbs([
+                16913186060169146935,
+                6349761876517996871,
+                7747159587438340531,
+                1035848188622321096,
+            ]),
+            Fq::from_montgomery_limbs([
+                7835053111969954263,
+                16820852943434756385,
+                17272424019113957388,
+                1236763857696392509,
+            ]),
+            Fq::from_montgomery_limbs([
+                2641017158057542424,
+                8088109139214981249,
+                10648680839827430394,
+                1239720612199315471,
+            ]),
+            Fq::from_montgomery_limbs([
+                663822900835126190,
+                14991330423050095469,
+                10367359378114337833,
+                470669558652977696,
+            ]),
+            Fq::from_montgomery_limbs([
+                14171880865822974992,
+                16070500032304434644,
+                17644373414523963876,
+                305668782344439245,
+            ]),
+            Fq::from_montgomery_limbs([
+                7743023723769995452,
+                2564271715752698834,
+                1394053445177365637,
+                1037747386974602566,
+            ]),
+            Fq::from_montgomery_limbs([
+                7080029216048359518,
+                48379455397819083,
+                9663210595515435810,
+                214724385640262733,
+            ]),
+            Fq::from_montgomery_limbs([
+                10644272124190472665,
+                2609240279992051233,
+                10768054913430240226,
+                1242111146951188196,
+            ]),
+            Fq::from_montgomery_limbs([
+                121445502891508084,
+                7504258445104395803,
+                481969538743407715,
+                330856110928688454,
+            ]),
+            Fq::from_montgomery_limbs([
+                3330354334899119016,
+                5680814955688298453,
+                7763858231549043435,
+                1124051223925617827,
+            ]),
+            Fq::from_montgomery_limbs([
+                3326771298019902008,
+                13602013693461148333,
+                10741020990723760968,
+                1271844507634107566,
+            ]),
+            Fq::from_montgomery_limbs([
+                8690212218286238375,
+                927745461831578232,
+                13421327456723786819,
+                159488321227943348,
+            ]),
+            Fq::from_montgomery_limbs([
+                17599332383237777431,
+                4558948867047711296,
+                15021163160450407402,
+                868430297425540605,
+            ]),
+            Fq::from_montgomery_limbs([
+                12726291304620661753,
+                15109041339577748426,
+                3096906421633314528,
+                767833436354854135,
+            ]),
+            Fq::from_montgomery_limbs([
+                8961544951960859615,
+                14490621325234423672,
+                6324781297580413548,
+                1118402992856114548,
+            ]),
+            Fq::from_montgomery_limbs([
+                15176984725861658665,
+                15572540036967107143,
+                17723383547646753304,
+                382225397373402247,
+            ]),
+            Fq::from_montgomery_limbs([
+                5600626867135564547,
+                172394935647799179,
+                8627912323397679908,
+                498880909219013084,
+            ]),
+            Fq::from_montgomery_limbs([
+                11044136449062236757,
+                3675513540667772389,
+                10403291466917188770,
+                680313133917354614,
+            ]),
+            Fq::from_montgomery_limbs([
+                18240929854168152412,
+                12522705135628111592,
+                12137985104438880852,
+                723973623997246671,
+            ]),
+            Fq::from_montgomery_limbs([
+                5346421101899223291,
+                14464020279717460204,
+                12890929165519274591,
+                153057119535473451,
+            ]),
+            Fq::from_montgomery_limbs([
+                12708364026105473943,
+                17507882706073383313,
+                17076845488395978546,
+                285630270509338361,
+            ]),
+            Fq::from_montgomery_limbs([
+                4311014241424974595,
+                2597829159821508114,
+                13709411907402934925,
+                1216701736636980855,
+            ]),
+            Fq::from_montgomery_limbs([
+                13622801840912941635,
+                12187816420238353803,
+                3270820642096473684,
+                445610528937068021,
+            ]),
+            Fq::from_montgomery_limbs([
+                15516718388705914847,
+                8055638147130353016,
+                10660750061586223561,
+                157152788599740362,
+            ]),
+            Fq::from_montgomery_limbs([
+                12243093046064441802,
+                375162848472553139,
+                14387808297813043979,
+                853640194603645165,
+            ]),
+            Fq::from_montgomery_limbs([
+                13894794537575244334,
+                10312141573450940832,
+                567719020459864488,
+                691070082768451815,
+            ]),
+            Fq::from_montgomery_limbs([
+                6433364813878257669,
+                11003170772670092673,
+                10003688689640762376,
+                1252273632601354595,
+            ]),
+            Fq::from_montgomery_limbs([
+                3432145370762408712,
+                9582851918690526704,
+                6055418213616776825,
+                993173746789152862,
+            ]),
+            Fq::from_montgomery_limbs([
+                17272225351137104778,
+                8996255204110128973,
+                9890500128700187444,
+                464009671025052445,
+            ]),
+            Fq::from_montgomery_limbs([
+                9471706413599736295,
+                10213833235592502275,
+                2868636637173571618,
+                398886672275717850,
+            ]),
+            Fq::from_montgomery_limbs([
+                15553741239031421279,
+                1471159805139835991,
+                13991200889516256017,
+                1084867736955083774,
+            ]),
+            Fq::from_montgomery_limbs([
+                1680273315461040394,
+                3965462129978292961,
+                4060824080207859997,
+                575618286886351616,
+            ]),
+            Fq::from_montgomery_limbs([
+                2295964613247445697,
+                16310404165352173987,
+                5164606945173116022,
+                274497340840497631,
+            ]),
+            Fq::from_montgomery_limbs([
+                14174733092413918289,
+                3869887476772607057,
+                17129368339331753665,
+                739915739005182275,
+            ]),
+            Fq::from_montgomery_limbs([
+                17987399906267294068,
+                17049275721583952838,
+                10375124627296736955,
+                240727953269509818,
+            ]),
+            Fq::from_montgomery_limbs([
+                1266982565785711339,
+                2507891683418070900,
+                18195035991845653068,
+                1201646487146078623,
+            ]),
+            Fq::from_montgomery_limbs([
+                4271203785925431274,
+                8184114758003709238,
+                3997475248767393053,
+                1227060835958076741,
+            ]),
+            Fq::from_montgomery_limbs([
+                9357773245180430970,
+                11769548726485183761,
+                10178263776277285696,
+                534936304275471796,
+            ]),
+        ]),
+    }
+}
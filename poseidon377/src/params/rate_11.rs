@@ -0,0 +1,13244 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-11 instance of Poseidon.
+pub const fn rate_11() -> PoseidonParameters<12, 11, 144, 121, 39, 12, 468, 31> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<39, 12, 468>::new_from_known([
+            Fq::from_montgomery_limbs([
+                16632938916876319268,
+                6278944211561501192,
+                12601405938806706802,
+                661377568436377385,
+            ]),
+            Fq::from_montgomery_limbs([
+                10774872491476595502,
+                6008456309288488161,
+                16127975749917949644,
+                96485433137271061,
+            ]),
+            Fq::from_montgomery_limbs([
+                18242884960062457044,
+                16114950486395307300,
+                1804687910289770734,
+                41138593389540529,
+            ]),
+            Fq::from_montgomery_limbs([
+                11966747793414092012,
+                2599495824123442761,
+                10426391661471627170,
+                13880890741331434,
+            ]),
+            Fq::from_montgomery_limbs([
+                1244334737076864402,
+                13508119243717545594,
+                8377895030277161361,
+                950051271819620075,
+            ]),
+            Fq::from_montgomery_limbs([
+                4917409736926194330,
+                11000514154627912145,
+                16657505476988497994,
+                606127200599468164,
+            ]),
+            Fq::from_montgomery_limbs([
+                14813599648439013932,
+                13339613532402540694,
+                15474573061813622979,
+                546292755280080794,
+            ]),
+            Fq::from_montgomery_limbs([
+                17382923883657694490,
+                390585098163740035,
+                15008138474636799511,
+                23667028793005338,
+            ]),
+            Fq::from_montgomery_limbs([
+                16839902645986267838,
+                11543168292190074415,
+                6607296450714950897,
+                88890859614968271,
+            ]),
+            Fq::from_montgomery_limbs([
+                15205494337581825070,
+                16112561752176320567,
+                931468456196686534,
+                1231294465782471298,
+            ]),
+            Fq::from_montgomery_limbs([
+                2286081498810290799,
+                9332260496541964389,
+                16525228356614204776,
+                1120271425471260989,
+            ]),
+            Fq::from_montgomery_limbs([
+                4544229891734006687,
+                13918256964831410904,
+                3556400010508168055,
+                842423103749983593,
+            ]),
+            Fq::from_montgomery_limbs([
+                2044532473593858049,
+                2001384063006573006,
+                3891443507992341150,
+                1082356936116457172,
+            ]),
+            Fq::from_montgomery_limbs([
+                17694327538658910603,
+                10442360183276977441,
+                13261268678997175872,
+                472578634775614324,
+            ]),
+            Fq::from_montgomery_limbs([
+                10948756146344922512,
+                7683181120746227906,
+                10887975850504067264,
+                382499161587988815,
+            ]),
+            Fq::from_montgomery_limbs([
+                9804639488303611810,
+                10654286368574314079,
+                6837644815046676522,
+                1272527167554470672,
+            ]),
+            Fq::from_montgomery_limbs([
+                7294928731105687432,
+                6043049974735690333,
+                4767449811193044395,
+                908811744579708982,
+            ]),
+            Fq::from_montgomery_limbs([
+                9432617829572490978,
+                17070526606061729723,
+                10347314551953159717,
+                394941453132138595,
+            ]),
+            Fq::from_montgomery_limbs([
+                8651960302050668406,
+                11261996985385302541,
+                12110050223912665445,
+                407953421620199119,
+            ]),
+            Fq::from_montgomery_limbs([
+                15245882424574603598,
+                6459707653882545744,
+                17596186895440203526,
+                366264318416324424,
+            ]),
+            Fq::from_montgomery_limbs([
+                6762205894112099118,
+                12651154075499134522,
+                6065043969165173545,
+                312510765032836035,
+            ]),
+            Fq::from_montgomery_limbs([
+                11182449767636567310,
+                2867565507955427984,
+                17431927399781692169,
+                261152343624181483,
+            ]),
+            Fq::from_montgomery_limbs([
+                11329634546541323238,
+                10057762990180139953,
+                4479052930599396378,
+                881192624382372891,
+            ]),
+            Fq::from_montgomery_limbs([
+                10523292705658055505,
+                8511791982406430639,
+                6996622901393783211,
+                1063859409356395272,
+            ]),
+            Fq::from_montgomery_limbs([
+                3507340157705241625,
+                9211557146266413144,
+                17682344797500248617,
+                843796012294789429,
+            ]),
+            Fq::from_montgomery_limbs([
+                10990488147574048418,
+                1952476703157470517,
+                5852246204409837675,
+                685856827906785300,
+            ]),
+            Fq::from_montgomery_limbs([
+                12991829192013404779,
+                14549125245067704791,
+                13024045485207006802,
+                1237319803844018975,
+            ]),
+            Fq::from_montgomery_limbs([
+                2514416321669496143,
+                698853897183065706,
+                17906539639485445928,
+                65277400335228719,
+            ]),
+            Fq::from_montgomery_limbs([
+                7255040994947942500,
+                1615703976733828905,
+                15012523234144085328,
+                473851074660310747,
+            ]),
+            Fq::from_montgomery_limbs([
+                5304404235919330526,
+                15668759905070224802,
+                14376011596246864746,
+                1107350067694478668,
+            ]),
+            Fq::from_montgomery_limbs([
+                66183234706852036,
+                8817465955334719734,
+                1105083135476743492,
+                1131606893454942741,
+            ]),
+            Fq::from_montgomery_limbs([
+                9228026120655134912,
+                3179073697588025142,
+                2133314237067696336,
+                495342647429947247,
+            ]),
+            Fq::from_montgomery_limbs([
+                1545081888928775362,
+                17108090472910430239,
+                17220143313323945285,
+                644834593164820987,
+            ]),
+            Fq::from_montgomery_limbs([
+                16858589008352661669,
+                16182733689713942251,
+                2337033299776473877,
+                1304519307088787888,
+            ]),
+            Fq::from_montgomery_limbs([
+                11525110978782997509,
+                10169839883275032216,
+                17145854050883962736,
+                1192423234933032540,
+            ]),
+            Fq::from_montgomery_limbs([
+                16927001820947803298,
+                11992717508998403742,
+                1980801809865391975,
+                1096886416804619080,
+            ]),
+            Fq::from_montgomery_limbs([
+                11874287433121915275,
+                7308025537350412474,
+                18382276862648781045,
+                1127442288654038731,
+            ]),
+            Fq::from_montgomery_limbs([
+                15200167252478420426,
+                1241562081166702344,
+                3268521225989361459,
+                374035634421454746,
+            ]),
+            Fq::from_montgomery_limbs([
+                17832208925902070320,
+                15740630745315573277,
+                8251968463766309422,
+                829183259841138537,
+            ]),
+            Fq::from_montgomery_limbs([
+                10284480741202859284,
+                10298733295486740030,
+                14389708067860178002,
+                1135021052653182803,
+            ]),
+            Fq::from_montgomery_limbs([
+                3321519450801407262,
+                15017957235312160170,
+                7406011237308573769,
+                266251624792110241,
+            ]),
+            Fq::from_montgomery_limbs([
+                4392080903532215336,
+                282369756989735071,
+                12212062393304397376,
+                1190168331469517579,
+            ]),
+            Fq::from_montgomery_limbs([
+                3752871672953265560,
+                13172255478844235253,
+                9580989314942725154,
+                1247594034579966253,
+            ]),
+            Fq::from_montgomery_limbs([
+                13983661234206946425,
+                8284184646875366312,
+                5229614962730807380,
+                442403022540221240,
+            ]),
+            Fq::from_montgomery_limbs([
+                8852609145559030300,
+                800534754665570489,
+                12265203657973950117,
+                255563197873423360,
+            ]),
+            Fq::from_montgomery_limbs([
+                15023475560818722723,
+                5615927434746197370,
+                8558908400807314483,
+                622279669225398690,
+            ]),
+            Fq::from_montgomery_limbs([
+                16756465923210571385,
+                16313783351605859432,
+                3789736591000306309,
+                589084829185755562,
+            ]),
+            Fq::from_montgomery_limbs([
+                10324912850652095798,
+                729348061665055707,
+                3856003895966154098,
+                751231172298732216,
+            ]),
+            Fq::from_montgomery_limbs([
+                1772885260873054138,
+                14013752347423199276,
+                12942795078854071907,
+                456182839390845112,
+            ]),
+            Fq::from_montgomery_limbs([
+                12041083180044845507,
+                4504598436574162157,
+                10782017531823745677,
+                130515193549512064,
+            ]),
+            Fq::from_montgomery_limbs([
+                11923448844797287166,
+                5224350784835782176,
+                6962576576795903866,
+                643019920379900340,
+            ]),
+            Fq::from_montgomery_limbs([
+                469431953847777519,
+                9200444754029209138,
+                3509185239783967896,
+                847121142663404593,
+            ]),
+            Fq::from_montgomery_limbs([
+                898911752351327629,
+                3032461967920423897,
+                8936742616837763480,
+                1327828619919091643,
+            ]),
+            Fq::from_montgomery_limbs([
+                16776862754428356852,
+                6432952932283856883,
+                5359282592692610352,
+                268293531001663858,
+            ]),
+            Fq::from_montgomery_limbs([
+                5429812273811411245,
+                5177328162782734698,
+                9760479396209354714,
+                1156078079927720424,
+            ]),
+            Fq::from_montgomery_limbs([
+                8081842668667218423,
+                1015061898150281123,
+                15976632080706267611,
+                890372608888675728,
+            ]),
+            Fq::from_montgomery_limbs([
+                12530097711931821015,
+                15232657330825333102,
+                1519979835417600004,
+                1095035373631102268,
+            ]),
+            Fq::from_montgomery_limbs([
+                2004008576239994616,
+                10876189812453624883,
+                4283048375349799702,
+                739588910931128223,
+            ]),
+            Fq::from_montgomery_limbs([
+                1576284453399444772,
+                15123310633585640463,
+                3495767443236451649,
+                308069925310637329,
+            ]),
+            Fq::from_montgomery_limbs([
+                15119360460419267381,
+                12637412336836147123,
+                16076778271895484652,
+                431599382564475677,
+            ]),
+            Fq::from_montgomery_limbs([
+                136197116954626365,
+                2829657122836304665,
+                7999431248647869665,
+                640176196717308379,
+            ]),
+            Fq::from_montgomery_limbs([
+                18357840403813777421,
+                8410129313104173372,
+                17412001449987813578,
+                1334501112512318620,
+            ]),
+            Fq::from_montgomery_limbs([
+                14474837693416560021,
+                10576867765461770609,
+                6163689439464344389,
+                572528349849749049,
+            ]),
+            Fq::from_montgomery_limbs([
+                595689263571277731,
+                7037681745356074625,
+                6285646023556516445,
+                815890139928072139,
+            ]),
+            Fq::from_montgomery_limbs([
+                10595169332909220231,
+                11459049885142522279,
+                18231864943278123428,
+                346491302319923291,
+            ]),
+            Fq::from_montgomery_limbs([
+                8579236341375077370,
+                5863506092483232723,
+                13267400110605382390,
+                1216354034300965240,
+            ]),
+            Fq::from_montgomery_limbs([
+                11018310261651348748,
+                13965385390614732129,
+                1074237895280246236,
+                761732151549496353,
+            ]),
+            Fq::from_montgomery_limbs([
+                2263729275138102564,
+                14042657971061742136,
+                2911843896944403618,
+                802734614012003947,
+            ]),
+            Fq::from_montgomery_limbs([
+                9568490136206059911,
+                10088371863131522231,
+                11708382203973009804,
+                1093012165252810757,
+            ]),
+            Fq::from_montgomery_limbs([
+                7364091171566981264,
+                14302075533979093655,
+                14544234631675352280,
+                73844979777908386,
+            ]),
+            Fq::from_montgomery_limbs([
+                11401537384687632838,
+                11784910756024139673,
+                10578722665866188605,
+                1185579683620713686,
+            ]),
+            Fq::from_montgomery_limbs([
+                17983692532491340070,
+                15781852936233286296,
+                3483338187673854086,
+                694600684235452311,
+            ]),
+            Fq::from_montgomery_limbs([
+                16023690926674816321,
+                7835907118836418518,
+                1706943668789865394,
+                216396427439045114,
+            ]),
+            Fq::from_montgomery_limbs([
+                1376404388157703661,
+                13374138005636972452,
+                775765875239272900,
+                342622879636794764,
+            ]),
+            Fq::from_montgomery_limbs([
+                17631552056047591274,
+                15254930249595322002,
+                16337062190301707075,
+                1158928309747732347,
+            ]),
+            Fq::from_montgomery_limbs([
+                6270094032754972898,
+                3138867571414988593,
+                16892569807643548338,
+                1100944846305364070,
+            ]),
+            Fq::from_montgomery_limbs([
+                5738226006678060122,
+                11550082892445625060,
+                3936740860361715911,
+                979874517068765108,
+            ]),
+            Fq::from_montgomery_limbs([
+                17782875311369687770,
+                6912160281741490310,
+                8389511383461126620,
+                881788988161322453,
+            ]),
+            Fq::from_montgomery_limbs([
+                7807318971387025824,
+                9869565581890136352,
+                5918092051111200495,
+                23211020921018106,
+            ]),
+            Fq::from_montgomery_limbs([
+                10003220216578469254,
+                11641311639972101652,
+                6857586389078430762,
+                13206033770553019,
+            ]),
+            Fq::from_montgomery_limbs([
+                230496399188797589,
+                17853022255694316545,
+                8933798667797830513,
+                1136622908290817641,
+            ]),
+            Fq::from_montgomery_limbs([
+                17540760768695725505,
+                428741278077085168,
+                12431565985789158902,
+                1036573916592117171,
+            ]),
+            Fq::from_montgomery_limbs([
+                3239848919547637627,
+                4477018446961122292,
+                5614819580814733923,
+                458179072778540483,
+            ]),
+            Fq::from_montgomery_limbs([
+                15824588161604078496,
+                8244197852899847246,
+                5616995466701699552,
+                115527525776080251,
+            ]),
+            Fq::from_montgomery_limbs([
+                7554705899219518891,
+                289708524243530770,
+                16087965667091425315,
+                1256841945196368899,
+            ]),
+            Fq::from_montgomery_limbs([
+                474602637138796517,
+                14316900719532498922,
+                9402440728658886860,
+                1279030390249729249,
+            ]),
+            Fq::from_montgomery_limbs([
+                12829709184581299352,
+                6794515149441560261,
+                4199165148393674130,
+                1320984215770229802,
+            ]),
+            Fq::from_montgomery_limbs([
+                2283862493889261518,
+                12324323725569336142,
+                2235848307577645792,
+                437618299691135433,
+            ]),
+            Fq::from_montgomery_limbs([
+                8540229310512906104,
+                17614309191753710058,
+                8150939132390783350,
+                1138261795029394488,
+            ]),
+            Fq::from_montgomery_limbs([
+                18387934970360771194,
+                13130957348295209975,
+                11650021161925126595,
+                922727547225891134,
+            ]),
+            Fq::from_montgomery_limbs([
+                12459332815597211150,
+                4348923653119082450,
+                12638790149165416034,
+                144299684489527840,
+            ]),
+            Fq::from_montgomery_limbs([
+                11720217953198733011,
+                8415443773551355096,
+                11433159542812522149,
+                1149940392192730983,
+            ]),
+            Fq::from_montgomery_limbs([
+                7091288766844047525,
+                8713554045670947714,
+                7974355013816919567,
+                1109428341067664500,
+            ]),
+            Fq::from_montgomery_limbs([
+                9572811351141408658,
+                12888229054334913341,
+                11165893324223203613,
+                923833762774781962,
+            ]),
+            Fq::from_montgomery_limbs([
+                14988124628593518340,
+                15141685904478039403,
+                5419826145053155867,
+                642883675360179847,
+            ]),
+            Fq::from_montgomery_limbs([
+                14456137203114067542,
+                5203982122728305995,
+                8988006938761620818,
+                2167500408871648,
+            ]),
+            Fq::from_montgomery_limbs([
+                2153985763165743113,
+                7968859345067894728,
+                12511233887650265169,
+                82552430789212352,
+            ]),
+            Fq::from_montgomery_limbs([
+                16635840420065654405,
+                4717388557764066999,
+                17085026534938274431,
+                1093538157769852573,
+            ]),
+            Fq::from_montgomery_limbs([
+                10867166713132437863,
+                14715173941514514614,
+                1284266855228678348,
+                84441439838452552,
+            ]),
+            Fq::from_montgomery_limbs([
+                18312181872689057057,
+                6442568047953433614,
+                13470312325391712660,
+                1265877583215997032,
+            ]),
+            Fq::from_montgomery_limbs([
+                10927078229756835760,
+                9022585463346780976,
+                15083283951913741612,
+                712042112241635171,
+            ]),
+            Fq::from_montgomery_limbs([
+                11484233694300443005,
+                12160723243467969309,
+                10753907448354777221,
+                1037655206738729082,
+            ]),
+            Fq::from_montgomery_limbs([
+                5325329178052398344,
+                1601575816538006834,
+                13585471480154712795,
+                890996237783408879,
+            ]),
+            Fq::from_montgomery_limbs([
+                6462703883900573055,
+                3851645495997547027,
+                8260031231789569501,
+                552919981805120027,
+            ]),
+            Fq::from_montgomery_limbs([
+                13597182060808225489,
+                10585649110068382747,
+                5675944175088210551,
+                320398561425011294,
+            ]),
+            Fq::from_montgomery_limbs([
+                5408584123880559595,
+                18350297667792737855,
+                9166222367098456095,
+                809616072150003622,
+            ]),
+            Fq::from_montgomery_limbs([
+                5658051691193930300,
+                15265471927555823523,
+                1956577145105305612,
+                1223600725645378563,
+            ]),
+            Fq::from_montgomery_limbs([
+                10893231054285141422,
+                7974157728189081257,
+                5317173782122723145,
+                702908096733409364,
+            ]),
+            Fq::from_montgomery_limbs([
+                5077068292239237947,
+                2842724082599278202,
+                14603816120992147824,
+                337290362343352958,
+            ]),
+            Fq::from_montgomery_limbs([
+                15754851854554919536,
+                15318164465414726050,
+                3152978990801327041,
+                1319693182865432365,
+            ]),
+            Fq::from_montgomery_limbs([
+                4147720832762002045,
+                15354981784250570537,
+                4845016157811755152,
+                97982838437171656,
+            ]),
+            Fq::from_montgomery_limbs([
+                7174233201040835299,
+                8140171440220223088,
+                13165343115476191777,
+                1031761869915947877,
+            ]),
+            Fq::from_montgomery_limbs([
+                12147024410677279705,
+                1885323929735685571,
+                16033851459366032260,
+                80905573150326141,
+            ]),
+            Fq::from_montgomery_limbs([
+                16442432251801374464,
+                15969321031353353774,
+                6670026000517456815,
+                667312279683657964,
+            ]),
+            Fq::from_montgomery_limbs([
+                16857694320355247682,
+                561241813792945928,
+                8348839759687234304,
+                527208711749348640,
+            ]),
+            Fq::from_montgomery_limbs([
+                6105500022265080227,
+                17361680132515628701,
+                8717552963177706010,
+                939342758018881824,
+            ]),
+            Fq::from_montgomery_limbs([
+                6429541645103807591,
+                11179816480441063276,
+                5120247439779872138,
+                327475611723132675,
+            ]),
+            Fq::from_montgomery_limbs([
+                749513866507165933,
+                6242826493216580681,
+                15475848675219228155,
+                1236768013113022861,
+            ]),
+            Fq::from_montgomery_limbs([
+                10768088085857339256,
+                7638644376975333435,
+                969842904361329134,
+                47224279671169738,
+            ]),
+            Fq::from_montgomery_limbs([
+                5202185233710684680,
+                5101381256112981522,
+                995376203203847306,
+                456279109864484,
+            ]),
+            Fq::from_montgomery_limbs([
+                13445842296779728565,
+                368890079177167626,
+                11952979848181072829,
+                1022656662189881270,
+            ]),
+            Fq::from_montgomery_limbs([
+                12044358361567508149,
+                7502307425818067597,
+                2171331812725544789,
+                1161720286728218160,
+            ]),
+            Fq::from_montgomery_limbs([
+                8416228526397459618,
+                2103806083329722623,
+                12285365318489944601,
+                1118552577384078799,
+            ]),
+            Fq::from_montgomery_limbs([
+                5101692558690974162,
+                5272266829801826812,
+                1323524364341982665,
+                748210295362764997,
+            ]),
+            Fq::from_montgomery_limbs([
+                15346639916969535551,
+                16507351701627116948,
+                16495088806469336093,
+                1189755668423791303,
+            ]),
+            Fq::from_montgomery_limbs([
+                12118192342062075796,
+                5088995090521538729,
+                14589117114720761948,
+                615224336240578507,
+            ]),
+            Fq::from_montgomery_limbs([
+                8661247995506265540,
+                7146225364314961303,
+                8252201126461616461,
+                70465638858983019,
+            ]),
+            Fq::from_montgomery_limbs([
+                7889338276124510165,
+                9307456601035244300,
+                12702731468094646480,
+                1307370879572655403,
+            ]),
+            Fq::from_montgomery_limbs([
+                3793241945197161005,
+                6156195616867459684,
+                7601856902905334353,
+                1035198773154539682,
+            ]),
+            Fq::from_montgomery_limbs([
+                12234073052834923507,
+                10453388605758651663,
+                7946263490903544380,
+                236940102245597443,
+            ]),
+            Fq::from_montgomery_limbs([
+                13764620462664182829,
+                6245664938862691023,
+                9770491553338821337,
+                815954749145962996,
+            ]),
+            Fq::from_montgomery_limbs([
+                4732018966379724090,
+                15604745265364655916,
+                4835609247626890664,
+                981018257546859057,
+            ]),
+            Fq::from_montgomery_limbs([
+                15219882215063808375,
+                5944054599687630296,
+                14577871621427571308,
+                288470374235269072,
+            ]),
+            Fq::from_montgomery_limbs([
+                2865611449569918988,
+                5980134921736027696,
+                2173233101016257827,
+                220944583905124758,
+            ]),
+            Fq::from_montgomery_limbs([
+                371318331611531850,
+                14371393665492741984,
+                7115192108572491143,
+                709180615400291441,
+            ]),
+            Fq::from_montgomery_limbs([
+                16534732287720091199,
+                12747276569316208032,
+                18102997919925882384,
+                408254339362398987,
+            ]),
+            Fq::from_montgomery_limbs([
+                9367183488343282760,
+                14377451640651982304,
+                6548961358297245264,
+                32035882980364323,
+            ]),
+            Fq::from_montgomery_limbs([
+                4164029437428189737,
+                8921806103021813495,
+                12704221827803427666,
+                401951509294895818,
+            ]),
+            Fq::from_montgomery_limbs([
+                536494976651564170,
+                16826344905069226754,
+                4809301583534374259,
+                978648915922157518,
+            ]),
+            Fq::from_montgomery_limbs([
+                15553145921025147533,
+                14933934263751033049,
+                17733362101888456241,
+                707203157353279767,
+            ]),
+            Fq::from_montgomery_limbs([
+                8725587849494763124,
+                4320404016179025715,
+                9749713360589577192,
+                2171800581597759,
+            ]),
+            Fq::from_montgomery_limbs([
+                1121463757028632377,
+                14267994417354257459,
+                4105669862661416038,
+                1133071724387497274,
+            ]),
+            Fq::from_montgomery_limbs([
+                5162159933504401325,
+                15992795072356343691,
+                8620933514307687681,
+                1050710948688582069,
+            ]),
+            Fq::from_montgomery_limbs([
+                16451997948265228770,
+                226816033387997491,
+                5937754878823754938,
+                959162972583089221,
+            ]),
+            Fq::from_montgomery_limbs([
+                3101250236823957443,
+                12387067539058576613,
+                13562743438613395283,
+                264014509100188599,
+            ]),
+            Fq::from_montgomery_limbs([
+                17566275379711605718,
+                11883621593376839109,
+                15124473073903245617,
+                475629108116294877,
+            ]),
+            Fq::from_montgomery_limbs([
+                12050105936696355246,
+                13214278792650280316,
+                4424509373614904330,
+                1289493776719203665,
+            ]),
+            Fq::from_montgomery_limbs([
+                10183323251257855811,
+                2279768097454516871,
+                13663680786179568821,
+                758145108028928387,
+            ]),
+            Fq::from_montgomery_limbs([
+                174618952759045710,
+                2635753744670460315,
+                3702783299330057828,
+                397761124285641902,
+            ]),
+            Fq::from_montgomery_limbs([
+                17920815042432751803,
+                3334881458497140387,
+                15935462143377992678,
+                31365328035009566,
+            ]),
+            Fq::from_montgomery_limbs([
+                14281778456689498000,
+                1428844307965223152,
+                5950373766540166837,
+                916572302591889077,
+            ]),
+            Fq::from_montgomery_limbs([
+                8940221409011313770,
+                1390415074024626444,
+                6726012828970512728,
+                146995100107418338,
+            ]),
+            Fq::from_montgomery_limbs([
+                6662778052598365740,
+                296998340657314078,
+                17665192028561602275,
+                1210329596829962177,
+            ]),
+            Fq::from_montgomery_limbs([
+                10375314408329414708,
+                12743482032749728157,
+                3323842417876902998,
+                732113201100587066,
+            ]),
+            Fq::from_montgomery_limbs([
+                13028466541603587162,
+                8421353330419422278,
+                5269360215869034910,
+                760784670374604683,
+            ]),
+            Fq::from_montgomery_limbs([
+                15350334494544153339,
+                2508473980258818420,
+                6137805137763704813,
+                1335588405711084301,
+            ]),
+            Fq::from_montgomery_limbs([
+                16362670653893415762,
+                9382737642366912941,
+                11392700355210276275,
+                781158747153509674,
+            ]),
+            Fq::from_montgomery_limbs([
+                57560344320429928,
+                16720529970777201852,
+                6024010870241523375,
+                113645161110126316,
+            ]),
+            Fq::from_montgomery_limbs([
+                8612010946863478054,
+                13286994365650924631,
+                9536734147620275067,
+                1009959882899879346,
+            ]),
+            Fq::from_montgomery_limbs([
+                5925040266031275174,
+                9213988986327296040,
+                4403447146203995856,
+                1081358943592527904,
+            ]),
+            Fq::from_montgomery_limbs([
+                2050625726408153671,
+                8316253243861811976,
+                10815631226604241099,
+                1308047086280103563,
+            ]),
+            Fq::from_montgomery_limbs([
+                4547991529625458037,
+                2074642745922750000,
+                13233668011489523493,
+                1006330345896492960,
+            ]),
+            Fq::from_montgomery_limbs([
+                718289864625301591,
+                6733882210178625746,
+                18026826401727519129,
+                412588429500426668,
+            ]),
+            Fq::from_montgomery_limbs([
+                17813949152347727793,
+                7553384223185293623,
+                6156726811134912452,
+                502333419835780065,
+            ]),
+            Fq::from_montgomery_limbs([
+                12082911136015447243,
+                9697534272803862072,
+                12367019147224823391,
+                113722359025679465,
+            ]),
+            Fq::from_montgomery_limbs([
+                9105997544434736552,
+                16577634938731807435,
+                9127061305461101809,
+                1065142355798416277,
+            ]),
+            Fq::from_montgomery_limbs([
+                14133645536485594520,
+                16810665563092284371,
+                4737645901625996751,
+                426736729259411999,
+            ]),
+            Fq::from_montgomery_limbs([
+                6784251140332241541,
+                9633182216475930108,
+                8750922961023124673,
+                519267540878175586,
+            ]),
+            Fq::from_montgomery_limbs([
+                14250859569768054167,
+                14555726455647558349,
+                10510115017327805744,
+                477450960864427610,
+            ]),
+            Fq::from_montgomery_limbs([
+                2661661251137630489,
+                17817816610917132047,
+                937518191042034780,
+                422707071523829667,
+            ]),
+            Fq::from_montgomery_limbs([
+                3747383068607518154,
+                4983377093383297045,
+                7603325156965995187,
+                952430857938718818,
+            ]),
+            Fq::from_montgomery_limbs([
+                1781406413236335544,
+                1182121737067605132,
+                1523009369264007121,
+                1318258007565096913,
+            ]),
+            Fq::from_montgomery_limbs([
+                1485456800838058732,
+                8152908494816539974,
+                1081695528508950542,
+                1094965397278095656,
+            ]),
+            Fq::from_montgomery_limbs([
+                10561240127242953662,
+                2575169039167327917,
+                17503953584132294600,
+                895721725813139339,
+            ]),
+            Fq::from_montgomery_limbs([
+                978448343532216613,
+                7052760469457712093,
+                7558488242939589944,
+                1013538482969202923,
+            ]),
+            Fq::from_montgomery_limbs([
+                16785377610125634171,
+                13233546427589375700,
+                11106320807411642697,
+                128587488279872663,
+            ]),
+            Fq::from_montgomery_limbs([
+                6811841390945932961,
+                277794515220144821,
+                6927409302661799310,
+                1326953001078901367,
+            ]),
+            Fq::from_montgomery_limbs([
+                11399762072908500136,
+                324980420809549684,
+                12754416012669064515,
+                1298273854497762244,
+            ]),
+            Fq::from_montgomery_limbs([
+                10238276210454625826,
+                11797612293916996526,
+                7896863655196477734,
+                593822529580847970,
+            ]),
+            Fq::from_montgomery_limbs([
+                16905165088924504077,
+                684619341674155114,
+                184506981451065012,
+                1263040220781593052,
+            ]),
+            Fq::from_montgomery_limbs([
+                8692177334983846187,
+                7440817317160459893,
+                3802478749878791640,
+                1161098777725030960,
+            ]),
+            Fq::from_montgomery_limbs([
+                3207944830029128626,
+                3959588286184765622,
+                11567572750062928279,
+                1048483373881181546,
+            ]),
+            Fq::from_montgomery_limbs([
+                4447746802895636778,
+                2960147024784289567,
+                7714825931603148707,
+                1309698962697376299,
+            ]),
+            Fq::from_montgomery_limbs([
+                3228120615197284848,
+                1606616304767475319,
+                7607805449249438122,
+                746228723960976995,
+            ]),
+            Fq::from_montgomery_limbs([
+                515802098822444939,
+                15390706871906106384,
+                11917035019109374715,
+                948040313940723525,
+            ]),
+            Fq::from_montgomery_limbs([
+                4753335293100171819,
+                17585288493211748025,
+                18101575818613808869,
+                462412999009210048,
+            ]),
+            Fq::from_montgomery_limbs([
+                12836321976826950653,
+                2455586526692776158,
+                10114026120548354327,
+                1296855005270664063,
+            ]),
+            Fq::from_montgomery_limbs([
+                2001694354281274189,
+                14339687266580375372,
+                12856462572672665039,
+                1024562069527793827,
+            ]),
+            Fq::from_montgomery_limbs([
+                451012983609604117,
+                17513489464681977059,
+                14899214492922730180,
+                287150876674140021,
+            ]),
+            Fq::from_montgomery_limbs([
+                4778947460524140256,
+                2991573141651024707,
+                17339669898258106058,
+                656193285268240104,
+            ]),
+            Fq::from_montgomery_limbs([
+                6481680173179307357,
+                11270955685509623128,
+                14892115409986062958,
+                379486119825610305,
+            ]),
+            Fq::from_montgomery_limbs([
+                4283717024387838895,
+                4830375864412010877,
+                1670073736572355171,
+                162186077862799862,
+            ]),
+            Fq::from_montgomery_limbs([
+                15159049918256828542,
+                17248763404288989794,
+                16231480649658622130,
+                288298046308208501,
+            ]),
+            Fq::from_montgomery_limbs([
+                7748696413966835177,
+                4329548631360627737,
+                2605042408413112716,
+                1118969309768016172,
+            ]),
+            Fq::from_montgomery_limbs([
+                9436016091962666789,
+                7070803564545661870,
+                3403119741879711642,
+                800046012679682367,
+            ]),
+            Fq::from_montgomery_limbs([
+                16973613223577715708,
+                5141666692653099802,
+                1850534107575777339,
+                722840737010373454,
+            ]),
+            Fq::from_montgomery_limbs([
+                16993863127963668503,
+                9496579191017312919,
+                12904740983880151882,
+                450320724128335626,
+            ]),
+            Fq::from_montgomery_limbs([
+                14900543504146041091,
+                11258993208839810182,
+                13329823322347397021,
+                592822336082545300,
+            ]),
+            Fq::from_montgomery_limbs([
+                12574834270754622659,
+                10182736682065247682,
+                14059221513031246602,
+                927353532154958498,
+            ]),
+            Fq::from_montgomery_limbs([
+                2065754847013689481,
+                16991900212871893104,
+                5804270551420076706,
+                459072244129984913,
+            ]),
+            Fq::from_montgomery_limbs([
+                8666624727194333869,
+                12582708817143620086,
+                7944055720986796399,
+                238558212358803745,
+            ]),
+            Fq::from_montgomery_limbs([
+                17211366124826496877,
+                14462024012194888866,
+                15503332522357952602,
+                129541187942764503,
+            ]),
+            Fq::from_montgomery_limbs([
+                6017897896397797900,
+                8727389221366521566,
+                6285453279044622683,
+                922219621708937109,
+            ]),
+            Fq::from_montgomery_limbs([
+                12301941264327395332,
+                8493176751414543447,
+                9158429173444546179,
+                153804509848410865,
+            ]),
+            Fq::from_montgomery_limbs([
+                18065405468157343035,
+                3679937226087531334,
+                7486789583195200191,
+                180531998583084819,
+            ]),
+            Fq::from_montgomery_limbs([
+                1757166475728243800,
+                3804371360567834312,
+                8903186971295840446,
+                558748173628104729,
+            ]),
+            Fq::from_montgomery_limbs([
+                11958842044330151543,
+                12253221044889364882,
+                4602005723734352428,
+                248381649318618324,
+            ]),
+            Fq::from_montgomery_limbs([
+                174333390996444959,
+                7773140627286243019,
+                5175028482295653509,
+                470589242348822737,
+            ]),
+            Fq::from_montgomery_limbs([
+                6948140009207797037,
+                10599851895963936846,
+                866477376728232044,
+                104388097738447332,
+            ]),
+            Fq::from_montgomery_limbs([
+                11064320082098211058,
+                5088480238594272162,
+                311469299998578791,
+                577321802762296838,
+            ]),
+            Fq::from_montgomery_limbs([
+                5965194185137282346,
+                829998781349654999,
+                16634950697185591695,
+                512136591482767772,
+            ]),
+            Fq::from_montgomery_limbs([
+                5904103552800289091,
+                4085432627810388229,
+                12289514302336807442,
+                457077043512088439,
+            ]),
+            Fq::from_montgomery_limbs([
+                3211238454196188709,
+                17959540555353882216,
+                5740050280993214560,
+                718072057555121965,
+            ]),
+            Fq::from_montgomery_limbs([
+                14407234727953564215,
+                17287601206757653537,
+                4387670437409222341,
+                3854772433671951,
+            ]),
+            Fq::from_montgomery_limbs([
+                9632392594164105323,
+                13428673757272035350,
+                4553556268436323298,
+                792392283350253538,
+            ]),
+            Fq::from_montgomery_limbs([
+                10633285819953140513,
+                14582703326353622873,
+                12177977137401703733,
+                10812350403420824,
+            ]),
+            Fq::from_montgomery_limbs([
+                2275544392598601430,
+                9519870506194414701,
+                2657766297587465947,
+                407789317884529859,
+            ]),
+            Fq::from_montgomery_limbs([
+                6217139331352978227,
+                12235225822572729384,
+                11057109231258883023,
+                1164965851729967163,
+            ]),
+            Fq::from_montgomery_limbs([
+                17331556173569147780,
+                9352015555787089794,
+                2766691214952131208,
+                446037251429185679,
+            ]),
+            Fq::from_montgomery_limbs([
+                15151077938065743805,
+                5842057620566134707,
+                7100886101748478154,
+                69374034571043330,
+            ]),
+            Fq::from_montgomery_limbs([
+                6517759536810134600,
+                3724340081262200618,
+                7168053266527867905,
+                1224610653625996766,
+            ]),
+            Fq::from_montgomery_limbs([
+                15228432194682334441,
+                7905273482139642834,
+                13209509636580639849,
+                802582888467576953,
+            ]),
+            Fq::from_montgomery_limbs([
+                15310847551823817182,
+                7194166348988040343,
+                502325079705715890,
+                888020155554939319,
+            ]),
+            Fq::from_montgomery_limbs([
+                9512797611272307824,
+                8165475563327453788,
+                6430590576450678919,
+                414932822623497511,
+            ]),
+            Fq::from_montgomery_limbs([
+                17064200560987308158,
+                4534858108793317118,
+                16322331433982917338,
+                827332080340813370,
+            ]),
+            Fq::from_montgomery_limbs([
+                3360471163384446474,
+                15843164725414928499,
+                17658532393032062676,
+                753095945051465731,
+            ]),
+            Fq::from_montgomery_limbs([
+                6711521161452129488,
+                6483213571507339398,
+                12556014422792327148,
+                1252752530475752901,
+            ]),
+            Fq::from_montgomery_limbs([
+                10850021043771116391,
+                2038530069529746468,
+                13790902778311493830,
+                658499621996886852,
+            ]),
+            Fq::from_montgomery_limbs([
+                3603249328367141949,
+                15280386685424380175,
+                1073470604768786502,
+                1244415892385040558,
+            ]),
+            Fq::from_montgomery_limbs([
+                9656776590600486860,
+                13023516484599383924,
+                6490199871126536897,
+                576247052730890459,
+            ]),
+            Fq::from_montgomery_limbs([
+                14475600333543584835,
+                312891882974677382,
+                18198847366559891294,
+                424469896776241322,
+            ]),
+            Fq::from_montgomery_limbs([
+                5004160321216178975,
+                6390778291404245872,
+                96800088079666719,
+                355562267755829910,
+            ]),
+            Fq::from_montgomery_limbs([
+                16647805025214448367,
+                1914085652226432227,
+                4728967516290760311,
+                947360827999011801,
+            ]),
+            Fq::from_montgomery_limbs([
+                9521718656836392373,
+                6524794580288626580,
+                6638394949295668966,
+                974802325416660345,
+            ]),
+            Fq::from_montgomery_limbs([
+                18365781019611912282,
+                18143379386780438514,
+                2759194315869851462,
+                662108976103948355,
+            ]),
+            Fq::from_montgomery_limbs([
+                17629439168308386320,
+                11826036825426790208,
+                4125886962606788227,
+                1039399398624804500,
+            ]),
+            Fq::from_montgomery_limbs([
+                15985597864705580519,
+                3597892192445976051,
+                12791226971462766719,
+                1074789396504439516,
+            ]),
+            Fq::from_montgomery_limbs([
+                17140223280573164722,
+                9624480152114545251,
+                15208356213924295254,
+                568604869007673256,
+            ]),
+            Fq::from_montgomery_limbs([
+                3193949054444762826,
+                11193422356762078254,
+                7579974769491566298,
+                297613962331904915,
+            ]),
+            Fq::from_montgomery_limbs([
+                2968931501720196354,
+                8415624805096677876,
+                13308333844100524519,
+                59059831982112229,
+            ]),
+            Fq::from_montgomery_limbs([
+                4987896375660225181,
+                8675038638450615948,
+                13717662708960972588,
+                934400753821397769,
+            ]),
+            Fq::from_montgomery_limbs([
+                115086742920674221,
+                2685404984610192647,
+                3848116057798410982,
+                1300483646944755517,
+            ]),
+            Fq::from_montgomery_limbs([
+                11744617705745541327,
+                9734959914866517170,
+                8384596853618350555,
+                738804950844417233,
+            ]),
+            Fq::from_montgomery_limbs([
+                9815609075867273537,
+                15759029001592734094,
+                9658406326260525505,
+                1335979397373999095,
+            ]),
+            Fq::from_montgomery_limbs([
+                11575959672971246787,
+                4303111338723915856,
+                15215413178261903455,
+                618916165990989732,
+            ]),
+            Fq::from_montgomery_limbs([
+                16043867454568244792,
+                8301231349471338971,
+                18020403359776923673,
+                710774610037268497,
+            ]),
+            Fq::from_montgomery_limbs([
+                192111706019343324,
+                11219978601629735581,
+                7432779412411770082,
+                1251515986590752303,
+            ]),
+            Fq::from_montgomery_limbs([
+                16040530888683585668,
+                1517212257679113456,
+                18231096060191123118,
+                989613095640806167,
+            ]),
+            Fq::from_montgomery_limbs([
+                1172242488634033939,
+                10778362271787339676,
+                5489868500041566371,
+                277439398949169857,
+            ]),
+            Fq::from_montgomery_limbs([
+                9329236895789854964,
+                6852761128858051083,
+                16997811860363617948,
+                451337098794494136,
+            ]),
+            Fq::from_montgomery_limbs([
+                1439687596030626414,
+                3405256826060134808,
+                14725234670534218417,
+                716897234166198472,
+            ]),
+            Fq::from_montgomery_limbs([
+                18436163386717786721,
+                7231388810330837188,
+                2304482039961002284,
+                1170189441981696645,
+            ]),
+            Fq::from_montgomery_limbs([
+                5723963625298013227,
+                12031583193691492864,
+                11803106037286821764,
+                922321669689300784,
+            ]),
+            Fq::from_montgomery_limbs([
+                1741489523477178485,
+                8326121025937702835,
+                10833680491367673704,
+                757114471841171629,
+            ]),
+            Fq::from_montgomery_limbs([
+                2890541766752623076,
+                810023227687405220,
+                10097381421854290794,
+                386840514680947967,
+            ]),
+            Fq::from_montgomery_limbs([
+                11190141648753930467,
+                13074342513580271800,
+                2666246728675027322,
+                1325160692363053449,
+            ]),
+            Fq::from_montgomery_limbs([
+                9182260759049985009,
+                7382036526040915872,
+                2273063354988982806,
+                1130417607561716320,
+            ]),
+            Fq::from_montgomery_limbs([
+                5133829457262757315,
+                5962978508322396403,
+                7200950860145997706,
+                72029484925127134,
+            ]),
+            Fq::from_montgomery_limbs([
+                17386628444163851163,
+                6650215636972426046,
+                960166615995375940,
+                699941454061522441,
+            ]),
+            Fq::from_montgomery_limbs([
+                14706680154154986714,
+                10538678736241598557,
+                12945351684261181287,
+                756792466027922615,
+            ]),
+            Fq::from_montgomery_limbs([
+                17808262247011624046,
+                17533811465382036652,
+                13052822334085653604,
+                1195395375594038784,
+            ]),
+            Fq::from_montgomery_limbs([
+                13949098550672656807,
+                12321244822059880577,
+                16027228462308765331,
+                327012933012496463,
+            ]),
+            Fq::from_montgomery_limbs([
+                6731004008458023594,
+                7577364924827630689,
+                14837750261289458161,
+                494817621312934868,
+            ]),
+            Fq::from_montgomery_limbs([
+                411990988008752582,
+                4910739580817186377,
+                450901044881660096,
+                878536990302861438,
+            ]),
+            Fq::from_montgomery_limbs([
+                6549503395495409705,
+                12255615588275007740,
+                4063967319121888646,
+                112612245123818824,
+            ]),
+            Fq::from_montgomery_limbs([
+                11193537191149426154,
+                15976272579107338604,
+                5693027121282955195,
+                1301240196007324654,
+            ]),
+            Fq::from_montgomery_limbs([
+                10822712456585583396,
+                3452525933518658283,
+                14208206846762716952,
+                569216028982137359,
+            ]),
+            Fq::from_montgomery_limbs([
+                4568573033851979452,
+                10638754190872155746,
+                7344544340977641581,
+                208274192269286979,
+            ]),
+            Fq::from_montgomery_limbs([
+                2093653936197507867,
+                1542048212877108510,
+                9036069743984664282,
+                361201513998541598,
+            ]),
+            Fq::from_montgomery_limbs([
+                4203883280001016886,
+                4247726971705177714,
+                16234653329718048369,
+                34909216179490184,
+            ]),
+            Fq::from_montgomery_limbs([
+                9241661435212987439,
+                12645922819513075336,
+                15944319064258272336,
+                1179621233500865645,
+            ]),
+            Fq::from_montgomery_limbs([
+                13518148904169883773,
+                2767098184526584817,
+                17940814155587556805,
+                1338393100156926429,
+            ]),
+            Fq::from_montgomery_limbs([
+                11325394633228696286,
+                8189660453691490054,
+                17879878587934139829,
+                120381580005118126,
+            ]),
+            Fq::from_montgomery_limbs([
+                5177461500745554851,
+                10390085107790943219,
+                4423004038240605191,
+                425615173168437067,
+            ]),
+            Fq::from_montgomery_limbs([
+                1823233307150107753,
+                1901726648328308219,
+                16108856571576094786,
+                747958554720034628,
+            ]),
+            Fq::from_montgomery_limbs([
+                12474782989405460471,
+                12763089255516661082,
+                2704180790852313520,
+                385518337229925953,
+            ]),
+            Fq::from_montgomery_limbs([
+                2736401537748693030,
+                9871537349371479701,
+                1791857244970424508,
+                1000913711645112841,
+            ]),
+            Fq::from_montgomery_limbs([
+                6399872027427335060,
+                8195246683264223530,
+                6685906580375152681,
+                622033598758678788,
+            ]),
+            Fq::from_montgomery_limbs([
+                863503950103961287,
+                16772828792145084062,
+                14613638507898873482,
+                1259626352426774629,
+            ]),
+            Fq::from_montgomery_limbs([
+                6871362423372372826,
+                5109464012760559543,
+                10953751661871833193,
+                1220247240015578110,
+            ]),
+            Fq::from_montgomery_limbs([
+                13178160327246314688,
+                3072887444079251521,
+                2264716027372120664,
+                401865734310187520,
+            ]),
+            Fq::from_montgomery_limbs([
+                10956901729929587450,
+                185141747984626446,
+                10325172086146616548,
+                525540927246189395,
+            ]),
+            Fq::from_montgomery_limbs([
+                17056535500676476201,
+                8736272020906985989,
+                8378578353649229633,
+                386380524748997790,
+            ]),
+            Fq::from_montgomery_limbs([
+                13800325220085248831,
+                5822205787077188421,
+                13052543409948081137,
+                144824414766663926,
+            ]),
+            Fq::from_montgomery_limbs([
+                11318499875493727342,
+                15677816742841423682,
+                9874869029582965776,
+                1026115095621951470,
+            ]),
+            Fq::from_montgomery_limbs([
+                16976690492094375795,
+                16071909704113019490,
+                12815077251439450405,
+                574319913220050890,
+            ]),
+            Fq::from_montgomery_limbs([
+                727158074347546113,
+                12120369009880027208,
+                17622365358297325188,
+                260236030347479914,
+            ]),
+            Fq::from_montgomery_limbs([
+                3635390949156595884,
+                8191157391159533222,
+                2511106816561894208,
+                109102978560341138,
+            ]),
+            Fq::from_montgomery_limbs([
+                7996837418367388233,
+                6791833087237006062,
+                13387121235489585255,
+                1191170110839301779,
+            ]),
+            Fq::from_montgomery_limbs([
+                15143611900538297328,
+                647333753876951570,
+                12720348187383445877,
+                515272133014730798,
+            ]),
+            Fq::from_montgomery_limbs([
+                7798932932127351149,
+                15517332875710292019,
+                18296035372644742977,
+                91485633840505398,
+            ]),
+            Fq::from_montgomery_limbs([
+                67976726012299152,
+                13028349396047732847,
+                1228129303099046390,
+                531408248903779826,
+            ]),
+            Fq::from_montgomery_limbs([
+                6961295903219541499,
+                6152970110762926153,
+                317284102330402305,
+                642373078087238410,
+            ]),
+            Fq::from_montgomery_limbs([
+                5663062602846175241,
+                16163628389450018698,
+                12789064428175772835,
+                985322764190853518,
+            ]),
+            Fq::from_montgomery_limbs([
+                4177888005867674283,
+                16543079509317786660,
+                10907682060295111924,
+                1217621242831995509,
+            ]),
+            Fq::from_montgomery_limbs([
+                1507373607702936337,
+                4418266304087647916,
+                11060917499286386130,
+                1158532490493970298,
+            ]),
+            Fq::from_montgomery_limbs([
+                15044280819631308602,
+                1205856339057333641,
+                1937535837587601247,
+                1079291954000037709,
+            ]),
+            Fq::from_montgomery_limbs([
+                2420046498200992593,
+                6399937595687434554,
+                8094766221349545941,
+                700381013909617702,
+            ]),
+            Fq::from_montgomery_limbs([
+                16950385816568200534,
+                5294177823900022332,
+                18059584305513985388,
+                112513764644196176,
+            ]),
+            Fq::from_montgomery_limbs([
+                10314831105184024766,
+                7814810666051263008,
+                979691686466685971,
+                631165456173515867,
+            ]),
+            Fq::from_montgomery_limbs([
+                9058625493799932906,
+                8545872007533838669,
+                16807704108027688252,
+                48342858643896541,
+            ]),
+            Fq::from_montgomery_limbs([
+                2364148296584502901,
+                6465633953799717149,
+                10237261946511038114,
+                897922572359062565,
+            ]),
+            Fq::from_montgomery_limbs([
+                16379873716644754827,
+                12541004043884707917,
+                8817473653159037280,
+                459943352697913709,
+            ]),
+            Fq::from_montgomery_limbs([
+                4547341017836306224,
+                15754821221739738583,
+                9589038568563778063,
+                774977336783503389,
+            ]),
+            Fq::from_montgomery_limbs([
+                5533993743605391893,
+                2656026959333936057,
+                8089422810141723061,
+                107815807197773985,
+            ]),
+            Fq::from_montgomery_limbs([
+                18175207206385552090,
+                17717510867105149334,
+                7114828628003478372,
+                1001437650828527908,
+            ]),
+            Fq::from_montgomery_limbs([
+                8013668982820303773,
+                13472361751364291863,
+                7621351867253650662,
+                1296586591205832415,
+            ]),
+            Fq::from_montgomery_limbs([
+                8041936736921956658,
+                3713321506908523485,
+                4097298010468115854,
+                415592991140500878,
+            ]),
+            Fq::from_montgomery_limbs([
+                9387660117260750462,
+                7248909634880429473,
+                491464143327776781,
+                1008651444123331217,
+            ]),
+            Fq::from_montgomery_limbs([
+                15721832042947629361,
+                18403057509937073043,
+                9059470988483134906,
+                1263670385891426437,
+            ]),
+            Fq::from_montgomery_limbs([
+                13071723202217829790,
+                2534309598934937608,
+                3411262680677216414,
+                1297097287634485734,
+            ]),
+            Fq::from_montgomery_limbs([
+                17994754933959357503,
+                6632890898491655739,
+                1425079530722815901,
+                202930567280442693,
+            ]),
+            Fq::from_montgomery_limbs([
+                6138780228164933911,
+                17598935338715527309,
+                5722019774965895222,
+                1265591491606559317,
+            ]),
+            Fq::from_montgomery_limbs([
+                1341644155771498250,
+                3804333998865129939,
+                14487949132476933279,
+                358874361748249861,
+            ]),
+            Fq::from_montgomery_limbs([
+                2143491738366682091,
+                9419886799982433962,
+                1299384962421617879,
+                1111787371495303433,
+            ]),
+            Fq::from_montgomery_limbs([
+                821625347966601690,
+                5129656995981099353,
+                9301265736637392713,
+                394773472218004283,
+            ]),
+            Fq::from_montgomery_limbs([
+                8437636759281571104,
+                13128133714820596962,
+                8273787092588233219,
+                902614992945465470,
+            ]),
+            Fq::from_montgomery_limbs([
+                16252792813743918384,
+                4638708699734217174,
+                61334990464240135,
+                355057131185864371,
+            ]),
+            Fq::from_montgomery_limbs([
+                12465301981366407309,
+                17975729228571340415,
+                842778286705072982,
+                1076624677454167114,
+            ]),
+            Fq::from_montgomery_limbs([
+                404370476517238255,
+                6607115500279300021,
+                2711831313149135966,
+                51194523308855909,
+            ]),
+            Fq::from_montgomery_limbs([
+                8546141051421991843,
+                4579716314478547531,
+                5269461197457421919,
+                972975352869979954,
+            ]),
+            Fq::from_montgomery_limbs([
+                5170698750358389572,
+                14809825309740005931,
+                7485511621220102370,
+                241285629431607582,
+            ]),
+            Fq::from_montgomery_limbs([
+                10699299948882047553,
+                14484610499397030089,
+                14410912293894210228,
+                132697059342280914,
+            ]),
+            Fq::from_montgomery_limbs([
+                7160380691426194544,
+                10797100208939462295,
+                2317661147234022297,
+                410839316028163391,
+            ]),
+            Fq::from_montgomery_limbs([
+                3051139229241024559,
+                6184588761442541620,
+                3401746759156465106,
+                1072099066535626571,
+            ]),
+            Fq::from_montgomery_limbs([
+                11581294936446840375,
+                8801097752809368387,
+                886217015566737190,
+                698370574035755307,
+            ]),
+            Fq::from_montgomery_limbs([
+                8613890586795077524,
+                17001031036350047035,
+                15264701653402350562,
+                31940643416158805,
+            ]),
+            Fq::from_montgomery_limbs([
+                8011159202983176425,
+                9541979252995179132,
+                4073088028221241158,
+                889046867129664764,
+            ]),
+            Fq::from_montgomery_limbs([
+                10672046937504154390,
+                11407545630187140415,
+                4755557071259992005,
+                1335935265662687138,
+            ]),
+            Fq::from_montgomery_limbs([
+                2237267200992642646,
+                11488822005219166621,
+                14329217897527898818,
+                53888132608887646,
+            ]),
+            Fq::from_montgomery_limbs([
+                9533213740921271693,
+                17906649420631906859,
+                15928692637500791701,
+                398307512473751115,
+            ]),
+            Fq::from_montgomery_limbs([
+                16755337510264371018,
+                9996911596190466930,
+                15107827095436335717,
+                964950534132779582,
+            ]),
+            Fq::from_montgomery_limbs([
+                14220108699738679205,
+                11023259605670022085,
+                1439318681431555935,
+                1039448017882053594,
+            ]),
+            Fq::from_montgomery_limbs([
+                2394741735903878323,
+                10383827056993949432,
+                13930380239059688407,
+                1166623872682629137,
+            ]),
+            Fq::from_montgomery_limbs([
+                4631156680052804080,
+                139678784230420842,
+                15342333787926359592,
+                1163024990298586495,
+            ]),
+            Fq::from_montgomery_limbs([
+                16658140822021525468,
+                1239160555973915659,
+                7616653125567315025,
+                844651261794051898,
+            ]),
+            Fq::from_montgomery_limbs([
+                14049524420643673775,
+                5844061678510875194,
+                10241683016085542606,
+                1071987641506469558,
+            ]),
+            Fq::from_montgomery_limbs([
+                11533512673873210634,
+                8419863848074602178,
+                2280589042402177490,
+                1166564794110922278,
+            ]),
+            Fq::from_montgomery_limbs([
+                12831835886656130402,
+                10971755890697791913,
+                1707800261843422341,
+                490821000922954604,
+            ]),
+            Fq::from_montgomery_limbs([
+                2334524034304519107,
+                8210030039586256321,
+                6718166646493433576,
+                1212069052442213282,
+            ]),
+            Fq::from_montgomery_limbs([
+                5601277263947669996,
+                16135316946900265281,
+                12421799897841779794,
+                755443229023670166,
+            ]),
+            Fq::from_montgomery_limbs([
+                1269867714906390151,
+                4362239026502498470,
+                2797716630893542623,
+                1000638202699558798,
+            ]),
+            Fq::from_montgomery_limbs([
+                5191982423417680232,
+                4800657509548670157,
+                11944730429489549789,
+                831723895569069878,
+            ]),
+            Fq::from_montgomery_limbs([
+                7051450185081026879,
+                7906593028489786011,
+                12949902181820467393,
+                360629417605427540,
+            ]),
+            Fq::from_montgomery_limbs([
+                3439883695713669874,
+                6889685047863317125,
+                6594376638977287759,
+                772506958341620918,
+            ]),
+            Fq::from_montgomery_limbs([
+                8217891122409928587,
+                13368131488939918895,
+                16880538718290896439,
+                1183872135319068378,
+            ]),
+            Fq::from_montgomery_limbs([
+                7728724612470313012,
+                4407275296829901880,
+                10743541209753826430,
+                333309748822297193,
+            ]),
+            Fq::from_montgomery_limbs([
+                3216795556466636452,
+                10442952660064369294,
+                12916380199236087496,
+                476117508708876478,
+            ]),
+            Fq::from_montgomery_limbs([
+                17993664108663513700,
+                18032081023209888767,
+                5414843725542409496,
+                636285831574596188,
+            ]),
+            Fq::from_montgomery_limbs([
+                200259753434014374,
+                7987300612882495624,
+                14455193940970748950,
+                337109639645042663,
+            ]),
+            Fq::from_montgomery_limbs([
+                4115959500482387416,
+                13323281098204940568,
+                6017077542800548325,
+                223190788174762882,
+            ]),
+            Fq::from_montgomery_limbs([
+                4031014780732882368,
+                9431732314581638006,
+                9162336465748495642,
+                310125675050387446,
+            ]),
+            Fq::from_montgomery_limbs([
+                15050168671742561060,
+                4341111880150286248,
+                1485952567358412103,
+                957936175287691037,
+            ]),
+            Fq::from_montgomery_limbs([
+                16234044391199979426,
+                15567151624697122443,
+                4199503443378225244,
+                280972601245722016,
+            ]),
+            Fq::from_montgomery_limbs([
+                644952906497753867,
+                16676714615776507849,
+                13079183288146419787,
+                1152980736551018406,
+            ]),
+            Fq::from_montgomery_limbs([
+                16135882118396515607,
+                12790971970485639642,
+                1780628114501551077,
+                106606692882922026,
+            ]),
+            Fq::from_montgomery_limbs([
+                14696319170830997557,
+                2967804771697343843,
+                13618943165321475126,
+                50801423513954092,
+            ]),
+            Fq::from_montgomery_limbs([
+                5077156437930049756,
+                11322967991010492088,
+                1180225836041442513,
+                1183582770321631585,
+            ]),
+            Fq::from_montgomery_limbs([
+                11131231845240984563,
+                6858337344901921578,
+                10829450167036216189,
+                821420231126066045,
+            ]),
+            Fq::from_montgomery_limbs([
+                263914954744908555,
+                10583492546005062304,
+                415511457266195727,
+                1222411615808409938,
+            ]),
+            Fq::from_montgomery_limbs([
+                4163649763489524362,
+                9506189869323850744,
+                6015704787596550448,
+                47043220607486348,
+            ]),
+            Fq::from_montgomery_limbs([
+                2984967716290476691,
+                7672852642818166306,
+                12049167365183348023,
+                1170261025036737448,
+            ]),
+            Fq::from_montgomery_limbs([
+                14639981732750389785,
+                834108959860939789,
+                12400244014737100007,
+                481715114919605157,
+            ]),
+            Fq::from_montgomery_limbs([
+                7128918643742949890,
+                3092736954689892457,
+                260560652971534030,
+                576170717504961133,
+            ]),
+            Fq::from_montgomery_limbs([
+                6919252170641254279,
+                16620582620978052667,
+                1646232620357191643,
+                47869425488662358,
+            ]),
+            Fq::from_montgomery_limbs([
+                2969310163227915805,
+                9000213446899766603,
+                4855992603256624509,
+                257384000859462853,
+            ]),
+            Fq::from_montgomery_limbs([
+                9421630822610749007,
+                9642247818279377313,
+                14960405385377968206,
+                1016064428836909697,
+            ]),
+            Fq::from_montgomery_limbs([
+                16509334880313179804,
+                12362792302449647149,
+                10336877119690423045,
+                1279468436037904169,
+            ]),
+            Fq::from_montgomery_limbs([
+                3808184144154104797,
+                10302301924473144371,
+                18247258295203981539,
+                428040050656993595,
+            ]),
+            Fq::from_montgomery_limbs([
+                224177716442715249,
+                11592169926838827609,
+                5622052867557152813,
+                293024292582246713,
+            ]),
+            Fq::from_montgomery_limbs([
+                1663522254002263396,
+                17790881078340976740,
+                11833974543354545100,
+                811731480369489242,
+            ]),
+            Fq::from_montgomery_limbs([
+                6782146193112226693,
+                16812625415914940542,
+                8865603278024643108,
+                1222402540764625780,
+            ]),
+            Fq::from_montgomery_limbs([
+                9585691597877912349,
+                3849593710807271915,
+                3060385892596775646,
+                890159564184027048,
+            ]),
+            Fq::from_montgomery_limbs([
+                1543792914381284332,
+                771723571288191022,
+                15879942943371120598,
+                1114816104645644037,
+            ]),
+            Fq::from_montgomery_limbs([
+                17381740250472433663,
+                17800999447775068956,
+                7263215393166703013,
+                1307984880055710057,
+            ]),
+            Fq::from_montgomery_limbs([
+                1827999638518210426,
+                13377953343435828103,
+                17531476862420646229,
+                603122865200101013,
+            ]),
+            Fq::from_montgomery_limbs([
+                5377804532642692055,
+                15606751632270991273,
+                82974271058187236,
+                452490282485356589,
+            ]),
+            Fq::from_montgomery_limbs([
+                15257448810475125110,
+                14077922813358709066,
+                7481553305768790348,
+                362308855348230197,
+            ]),
+            Fq::from_montgomery_limbs([
+                16250817828937575201,
+                1979685324088198481,
+                2711572322074161952,
+                610694107096629528,
+            ]),
+            Fq::from_montgomery_limbs([
+                16311260229643307351,
+                18050677804919812560,
+                17797233001165763592,
+                508967912389670087,
+            ]),
+            Fq::from_montgomery_limbs([
+                7099956925415304542,
+                2476309432971628058,
+                2044559398667054589,
+                1197599947550561568,
+            ]),
+            Fq::from_montgomery_limbs([
+                4503926666786979590,
+                16000489433997362639,
+                3314605366935834410,
+                125900547694929019,
+            ]),
+            Fq::from_montgomery_limbs([
+                8481700081267645203,
+                3506278267136216685,
+                8675383713563853811,
+                717219267334346176,
+            ]),
+            Fq::from_montgomery_limbs([
+                5202296923322479614,
+                2360801168400524419,
+                10646504385075654976,
+                303543541699028904,
+            ]),
+            Fq::from_montgomery_limbs([
+                1657358973528984583,
+                8077292243453728001,
+                5619988547113561451,
+                387605411782901767,
+            ]),
+            Fq::from_montgomery_limbs([
+                1947973773995932272,
+                11162288947155056993,
+                11780705191011135591,
+                1001113703406547239,
+            ]),
+            Fq::from_montgomery_limbs([
+                10115137288016435346,
+                2681757516878752396,
+                9203991828627561742,
+                53278897415300710,
+            ]),
+            Fq::from_montgomery_limbs([
+                7270627108308940539,
+                5162050612220206576,
+                8047074417060250291,
+                1193997647375266487,
+            ]),
+            Fq::from_montgomery_limbs([
+                363676612017813730,
+                5296738374506476751,
+                12947382462826730324,
+                110195674178567968,
+            ]),
+            Fq::from_montgomery_limbs([
+                16610673449407232003,
+                2260627488701078426,
+                8899552540407365247,
+                397717500380405393,
+            ]),
+            Fq::from_montgomery_limbs([
+                12757754738593523152,
+                11704512933267936863,
+                9093992328134799948,
+                1112916005449546668,
+            ]),
+            Fq::from_montgomery_limbs([
+                2129301988599311728,
+                14085884396655786820,
+                6422820252759129603,
+                340660676369030445,
+            ]),
+            Fq::from_montgomery_limbs([
+                17512664650397992470,
+                1356147711403635395,
+                11595752988439885783,
+                263257146335968256,
+            ]),
+            Fq::from_montgomery_limbs([
+                16104134982113446814,
+                13628130958523116692,
+                8868105227372807457,
+                858441232683999332,
+            ]),
+            Fq::from_montgomery_limbs([
+                4585763395874567795,
+                15409371460604897386,
+                12076528210588538925,
+                193069003276949885,
+            ]),
+            Fq::from_montgomery_limbs([
+                12858044059576315724,
+                408447216512270523,
+                9201293651796143267,
+                1105039282867304833,
+            ]),
+            Fq::from_montgomery_limbs([
+                7718585737859794709,
+                10239324219188305430,
+                2780439687085946406,
+                11587392078380132,
+            ]),
+            Fq::from_montgomery_limbs([
+                9337485934429377608,
+                2753372683127066129,
+                2632833182886348743,
+                1206081456746043669,
+            ]),
+            Fq::from_montgomery_limbs([
+                485969699185032386,
+                14545956808591573857,
+                10950664937553379550,
+                100291195527784085,
+            ]),
+            Fq::from_montgomery_limbs([
+                6720091532970741431,
+                10450301277852858148,
+                12590395106701444357,
+                786384476087250547,
+            ]),
+            Fq::from_montgomery_limbs([
+                924140695978742455,
+                13006530073909047872,
+                16744654361868001080,
+                252390773413274589,
+            ]),
+            Fq::from_montgomery_limbs([
+                12496445944352379226,
+                13713868059308677186,
+                3617291780018973610,
+                1108678552086646462,
+            ]),
+            Fq::from_montgomery_limbs([
+                15508988328255871703,
+                5524908602322500888,
+                13255247026017505006,
+                40452848794099149,
+            ]),
+            Fq::from_montgomery_limbs([
+                14747155606841929119,
+                16639297370337801717,
+                213667921459777078,
+                1325018144729234736,
+            ]),
+            Fq::from_montgomery_limbs([
+                4097925012073146557,
+                6979729610389817186,
+                7804176220169459844,
+                1258350815303254928,
+            ]),
+            Fq::from_montgomery_limbs([
+                18266606323797621732,
+                16627739305668723466,
+                335349087141503674,
+                577727870809215791,
+            ]),
+            Fq::from_montgomery_limbs([
+                3324052202617299664,
+                14180832642642167949,
+                11694280413178363617,
+                1031459245097234709,
+            ]),
+            Fq::from_montgomery_limbs([
+                16777600714617530762,
+                12151157144060058390,
+                2206604000833476455,
+                931569570831274292,
+            ]),
+            Fq::from_montgomery_limbs([
+                8949962819301670515,
+                15597204987975923042,
+                8414995621363210184,
+                1055168943945405991,
+            ]),
+            Fq::from_montgomery_limbs([
+                16093194656079002089,
+                3465021972411013285,
+                10039573917218849642,
+                861900859630330727,
+            ]),
+            Fq::from_montgomery_limbs([
+                10031204430331195501,
+                14592966068292354894,
+                16515026544773540780,
+                15761965640605451,
+            ]),
+            Fq::from_montgomery_limbs([
+                6075394622483449551,
+                7965786001853970776,
+                5975841978350428842,
+                469061289040889584,
+            ]),
+            Fq::from_montgomery_limbs([
+                15413273336399126693,
+                1909780716858847704,
+                12299360603953181868,
+                341169200651085270,
+            ]),
+            Fq::from_montgomery_limbs([
+                15368223103970014257,
+                11701558317150705689,
+                256916820351528297,
+                1103263578541470212,
+            ]),
+            Fq::from_montgomery_limbs([
+                4243830636025802581,
+                15785294062391096416,
+                14249312550774747407,
+                59266610708470075,
+            ]),
+            Fq::from_montgomery_limbs([
+                1895044669504838932,
+                152152324462211753,
+                4793927125922329468,
+                1210227180732432034,
+            ]),
+            Fq::from_montgomery_limbs([
+                744495798020828477,
+                4347107131413765551,
+                9315674937780974696,
+                967554915461757093,
+            ]),
+            Fq::from_montgomery_limbs([
+                11741530407002526544,
+                5341602005740706147,
+                4712770184286511710,
+                274506632008204487,
+            ]),
+            Fq::from_montgomery_limbs([
+                7543892959227498418,
+                520453892976489198,
+                10628537566665610477,
+                1048585373320735596,
+            ]),
+            Fq::from_montgomery_limbs([
+                12842437846224496716,
+                14648290074406975674,
+                16963103954838617636,
+                1212388085194192856,
+            ]),
+            Fq::from_montgomery_limbs([
+                6103999429654437021,
+                3578870793882262605,
+                10475906052739781076,
+                791327344418792022,
+            ]),
+            Fq::from_montgomery_limbs([
+                16730713618121323118,
+                5454878769716689869,
+                5964671993226182778,
+                1062907112233269502,
+            ]),
+            Fq::from_montgomery_limbs([
+                6531059121737619956,
+                7401835798433222146,
+                10773551317008857887,
+                368028073403727890,
+            ]),
+            Fq::from_montgomery_limbs([
+                14618257344708996557,
+                954723661573158790,
+                871035779172331096,
+                1062874702598501096,
+            ]),
+            Fq::from_montgomery_limbs([
+                11009422755186913021,
+                11191725178734652017,
+                7055525707594095636,
+                253900205998073893,
+            ]),
+            Fq::from_montgomery_limbs([
+                7091189346721442386,
+                14772591004223658667,
+                483483410740308902,
+                760800561216851459,
+            ]),
+            Fq::from_montgomery_limbs([
+                7689459034276840844,
+                8934630457408828756,
+                17484472752952863316,
+                1021123464465977001,
+            ]),
+            Fq::from_montgomery_limbs([
+                9206472016036363509,
+                4055331965654480165,
+                7681507747431018703,
+                180029677282353231,
+            ]),
+            Fq::from_montgomery_limbs([
+                18360158537789375323,
+                5893526811952233207,
+                16796775918819721322,
+                128438802872714546,
+            ]),
+            Fq::from_montgomery_limbs([
+                6902954690958907722,
+                14566876696632142805,
+                4356274283528664731,
+                846403371447963743,
+            ]),
+            Fq::from_montgomery_limbs([
+                13085609659997848451,
+                14491537799963823657,
+                18427185896792224096,
+                1275917180821703486,
+            ]),
+            Fq::from_montgomery_limbs([
+                17210445694812923026,
+                15425747662001882664,
+                8397217914209382911,
+                1191627581154357020,
+            ]),
+            Fq::from_montgomery_limbs([
+                16322043879994456558,
+                14359737400185378962,
+                6631765366397254117,
+                858151552562083283,
+            ]),
+            Fq::from_montgomery_limbs([
+                2152078896307564490,
+                11846164723371488364,
+                2966568072710520099,
+                1088732329527010515,
+            ]),
+            Fq::from_montgomery_limbs([
+                12498725972780656961,
+                13181470547498505132,
+                11146940566320933304,
+                538535453239318990,
+            ]),
+            Fq::from_montgomery_limbs([
+                10257584390439030720,
+                9250208970149276627,
+                17523972249860038680,
+                1165629637191364374,
+            ]),
+            Fq::from_montgomery_limbs([
+                2144634500437853569,
+                4631959497359269513,
+                9414383319306409173,
+                82467256219518545,
+            ]),
+            Fq::from_montgomery_limbs([
+                15560338967437296955,
+                1221012097737312435,
+                8494401740307712204,
+                1146633301199982890,
+            ]),
+            Fq::from_montgomery_limbs([
+                12221626725364195186,
+                12748619946657051130,
+                8908696122709497282,
+                18158362142525744,
+            ]),
+            Fq::from_montgomery_limbs([
+                9798152948829689807,
+                5810807910852795608,
+                15272610421484805561,
+                1148791092989849508,
+            ]),
+            Fq::from_montgomery_limbs([
+                14724467063786624846,
+                2859986419261516914,
+                8142829858799559109,
+                734862181984424022,
+            ]),
+            Fq::from_montgomery_limbs([
+                7614748546606178871,
+                470486123924990932,
+                11508575293938048335,
+                184109488453545629,
+            ]),
+            Fq::from_montgomery_limbs([
+                11733597845853284511,
+                5772234106958148095,
+                16166145989110033314,
+                53261225799539031,
+            ]),
+            Fq::from_montgomery_limbs([
+                956238097149081026,
+                823431159877382691,
+                10735275174253236834,
+                38172862942800677,
+            ]),
+            Fq::from_montgomery_limbs([
+                12718186906561158309,
+                10137288682505304528,
+                15780705171875672695,
+                767529651519816831,
+            ]),
+            Fq::from_montgomery_limbs([
+                6072933746267241550,
+                1511163048078603439,
+                15655121243427388544,
+                1189476367518933481,
+            ]),
+            Fq::from_montgomery_limbs([
+                16395495154724531262,
+                5329880896848925849,
+                5989791295325964789,
+                720443877656015122,
+            ]),
+            Fq::from_montgomery_limbs([
+                3433954846020173680,
+                9134087985387204629,
+                11660320478541581260,
+                1300823941933698688,
+            ]),
+            Fq::from_montgomery_limbs([
+                4981920578347604751,
+                4039624890031526563,
+                8519379607816478597,
+                1077219229072752956,
+            ]),
+            Fq::from_montgomery_limbs([
+                12329144399800108201,
+                11738808353572125176,
+                15678722138041251442,
+                1214534963171610743,
+            ]),
+            Fq::from_montgomery_limbs([
+                4843055113728752032,
+                6676008456161425710,
+                9483079952706543681,
+                809430281949042211,
+            ]),
+            Fq::from_montgomery_limbs([
+                6942213891530582986,
+                15418638511141310076,
+                15756589393886642076,
+                1125742118793400985,
+            ]),
+            Fq::from_montgomery_limbs([
+                14298191943168916389,
+                10701146453248677112,
+                3887587718426979182,
+                1154211060226604636,
+            ]),
+            Fq::from_montgomery_limbs([
+                2036087001900666654,
+                561471636338262388,
+                18382244847858823809,
+                1091123180004779699,
+            ]),
+            Fq::from_montgomery_limbs([
+                11026151292555174678,
+                15955443088572875355,
+                6258768471363759509,
+                1212396892024158831,
+            ]),
+            Fq::from_montgomery_limbs([
+                13501946388662198900,
+                17326984318076315150,
+                3461702941504234606,
+                743027343842507144,
+            ]),
+            Fq::from_montgomery_limbs([
+                16745052300345855390,
+                15251360508393668557,
+                7643763334474640960,
+                1141752809090554613,
+            ]),
+            Fq::from_montgomery_limbs([
+                10181373680635555548,
+                7612775112942743747,
+                2713459361149107617,
+                1067907298607112920,
+            ]),
+            Fq::from_montgomery_limbs([
+                2781201631580984482,
+                11520827918983943849,
+                6518139444498223817,
+                1213364774466955071,
+            ]),
+            Fq::from_montgomery_limbs([
+                1357015109188739219,
+                6620761087081147884,
+                2972070783836321095,
+                685609124791640488,
+            ]),
+            Fq::from_montgomery_limbs([
+                213206301104355711,
+                6377812353351491689,
+                16038555843121022130,
+                353362104731185235,
+            ]),
+            Fq::from_montgomery_limbs([
+                2036559516380647856,
+                14452448209245873934,
+                7064447635401196270,
+                555844342705721109,
+            ]),
+            Fq::from_montgomery_limbs([
+                7811749713839145520,
+                15869776637685996837,
+                17050365857528520675,
+                399172746676141039,
+            ]),
+            Fq::from_montgomery_limbs([
+                6986657951069135044,
+                1909764209004850290,
+                394354830309903883,
+                256553378413266523,
+            ]),
+            Fq::from_montgomery_limbs([
+                16158656257466769940,
+                2207246893510610564,
+                15783874162358744589,
+                1177058455831889162,
+            ]),
+            Fq::from_montgomery_limbs([
+                3163844353643762227,
+                6670948437862610873,
+                7984261773362468280,
+                976409107888387972,
+            ]),
+            Fq::from_montgomery_limbs([
+                6983862073721005810,
+                4121703754457709446,
+                15405709522420474979,
+                953350124340249443,
+            ]),
+            Fq::from_montgomery_limbs([
+                11469144286125544215,
+                10507386404365435975,
+                11286043888537140640,
+                54705696506017703,
+            ]),
+        ]),
+        mds: MdsMatrix::<12, 11, 144, 121>::new_from_known([
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+        ]),
+        alpha: Alpha::Exponent(17),
+        rounds: RoundNumbers { r_P: 31, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<11, 121>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+            ]),
+            v: Matrix::<1, 11, 11>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+            ]),
+            w: Matrix::<11, 1, 11>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<12, 144>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<12, 144>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8375407017592638895,
+                    9252095150812742603,
+                    14452302444128254679,
+                    726342781765472175,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    11006206701155563554,
+                    11826595293958141183,
+                    14374931592144507852,
+                    1106021540262976892,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12948612932198377710,
+                    12682300185017792826,
+                    2068245127460229803,
+                    1244145918211021829,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4773172233637430752,
+                    17395167251847192285,
+                    8749051444962303232,
+                    173168730774364871,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9428956392638666971,
+                    13333165096953048843,
+                    4734532024815464964,
+                    471168813600870848,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12058785378722374304,
+                    13622800117163112387,
+                    12526509891994050183,
+                    361961664772074798,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10717147538104255062,
+                    18412664190059943373,
+                    789619171820895387,
+                    704410459096901158,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14276012135167570277,
+                    14559156650876576334,
+                    9020731490824922376,
+                    1135682357560118350,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7579827696670124534,
+                    7746079855422155655,
+                    17046277457950126011,
+                    319999049634595166,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14908522759314931767,
+                    1000387597762261745,
+                    11528786659833101948,
+                    980224581660160168,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12023470382715277224,
+                    20289440716999971,
+                    15839219580816308738,
+                    325559194081966270,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<12, 144>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    3698139646914804141,
+                    9260555502873159801,
+                    13107174418122146709,
+                    848312408459819246,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4195723039139231395,
+                    15272320416972246798,
+                    4999834090118203209,
+                    882276079772158737,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10424630250007651817,
+                    16183862261034006937,
+                    12159904673319507115,
+                    290308681212492621,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9961856055516117728,
+                    17310502700828670691,
+                    2355937737606329476,
+                    1180955599071385164,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3142886208253659471,
+                    7077235925576603971,
+                    3730176047445395139,
+                    50115965198595266,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15980227315157626253,
+                    9065576459296848693,
+                    3136607104634831627,
+                    1103104227812525641,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4440322598097209446,
+                    15072620199507661412,
+                    12344720049312080211,
+                    988075061605509563,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17156505689778848277,
+                    3610466449555352140,
+                    2076252015024616151,
+                    635505600663365138,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1924949481947732785,
+                    17430184151060593260,
+                    16498832853417349786,
+                    1222715632113313100,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4679378876612161317,
+                    9202475458221310678,
+                    6929653183493992110,
+                    638803835602797004,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15706107230085149148,
+                    7296055801186352934,
+                    10177597195803980697,
+                    750055108800882277,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7761569273933788611,
+                    11685161069048549001,
+                    16151747284508219455,
+                    1170040058874480018,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4195723039139231395,
+                    15272320416972246798,
+                    4999834090118203209,
+                    882276079772158737,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13731938627718238622,
+                    18137691873318751357,
+                    16147279545618778340,
+                    233736543993967630,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12407646702453956676,
+                    12055901756821282057,
+                    17450189846701255912,
+                    1213823722773742959,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13541279971961434567,
+                    14762968382284374398,
+                    795742838746180835,
+                    1203117812227975559,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6693057076912498861,
+                    13140529906414060540,
+                    13862967579470176308,
+                    1257194764708911461,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10936563342300883474,
+                    5105752968513867329,
+                    16440646200012018786,
+                    1145516564428180248,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3643580188160674377,
+                    10642804923809576612,
+                    1905840299389231588,
+                    1143334179236181373,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15701705499912134995,
+                    14331656684264824616,
+                    7145554043057029499,
+                    36208615948585257,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17649459280212547747,
+                    1583063183276012785,
+                    13358236428675701124,
+                    454011082868038511,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1221781484409528833,
+                    486298415317632119,
+                    16393796677579033533,
+                    37923159222996891,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11196038420556690623,
+                    2398592059568444590,
+                    17734070025361081427,
+                    652048326249987069,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6639813334526531566,
+                    2833976130240118167,
+                    4173199571699304326,
+                    331448980684935255,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10424630250007651817,
+                    16183862261034006937,
+                    12159904673319507115,
+                    290308681212492621,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12407646702453956676,
+                    12055901756821282057,
+                    17450189846701255912,
+                    1213823722773742959,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6576878940196355531,
+                    13910494472201020762,
+                    15672000715638736065,
+                    622316454758105703,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14276493389373357604,
+                    12579456326552439879,
+                    14331261424683100473,
+                    1213492092634106777,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16500457934652550332,
+                    14798880484180262056,
+                    6776344300724286425,
+                    179024071246191830,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6153784379076788893,
+                    14129569637194890662,
+                    7671891196888991008,
+                    278357162673002083,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8017775966651834586,
+                    1459380453485585167,
+                    16058870721228155585,
+                    1016835943801435831,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3886818589831087357,
+                    15397371524719612419,
+                    15758567141375780274,
+                    106990524248294098,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9311265603383790073,
+                    2548529103863235790,
+                    3030766225910078014,
+                    127489111572381886,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16184208739966073341,
+                    7127925464546799665,
+                    4799608511559340286,
+                    1077475550651871286,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9019189746263135082,
+                    2999286386399327154,
+                    1615258897726571893,
+                    842985804662803538,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1902074105594142259,
+                    14508247393278649604,
+                    5086680762216633448,
+                    976312059588194762,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9961856055516117728,
+                    17310502700828670691,
+                    2355937737606329476,
+                    1180955599071385164,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13541279971961434567,
+                    14762968382284374398,
+                    795742838746180835,
+                    1203117812227975559,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14276493389373357604,
+                    12579456326552439879,
+                    14331261424683100473,
+                    1213492092634106777,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17665223236442554147,
+                    7838414376101961574,
+                    14332687875458001251,
+                    341122032675686089,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15492049111344468594,
+                    4129183342328853822,
+                    12123059220234647355,
+                    1017364615779541160,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3443801606660685444,
+                    2346820097393652674,
+                    11547720412233426580,
+                    73767729572152666,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15802581290971130835,
+                    10741582148340879804,
+                    504340179747868781,
+                    177848364516854767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9943478837519951970,
+                    9973184373904820463,
+                    2482554308804382223,
+                    225943340086174755,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3869458952764578091,
+                    2366048283700927196,
+                    7942079401492299058,
+                    565597003126920360,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8014891535960987678,
+                    126643414188594280,
+                    5459764234915344934,
+                    757246264492597792,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10599985789062789587,
+                    8216573485769182597,
+                    6523642537778640134,
+                    1003036072108778371,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1883874784453611653,
+                    17494270805835581294,
+                    2760556669599978451,
+                    54786851338209267,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3142886208253659471,
+                    7077235925576603971,
+                    3730176047445395139,
+                    50115965198595266,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6693057076912498861,
+                    13140529906414060540,
+                    13862967579470176308,
+                    1257194764708911461,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16500457934652550332,
+                    14798880484180262056,
+                    6776344300724286425,
+                    179024071246191830,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15492049111344468594,
+                    4129183342328853822,
+                    12123059220234647355,
+                    1017364615779541160,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4028220315588286784,
+                    9173744117909709044,
+                    3072047922219082364,
+                    986943844718704913,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4833319986024940824,
+                    1973131621980074782,
+                    5265051666893653217,
+                    1284545350824817598,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9444407536053386545,
+                    3442810074366340337,
+                    3453655921135980616,
+                    1296700509454399529,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7613423334776931464,
+                    14364225785726718090,
+                    190997604237216974,
+                    1280832891886898392,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5444401549811782343,
+                    15134455110492711357,
+                    12464430978836789883,
+                    402930987916428203,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9832561368705028224,
+                    6036996632821069809,
+                    12681235370666728557,
+                    482482883979133539,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16679153692081436634,
+                    17476999385180901684,
+                    3651982306688522989,
+                    770759381024621740,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7238680318771722735,
+                    1766260997954463294,
+                    12429356996090433416,
+                    258499038923599006,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15980227315157626253,
+                    9065576459296848693,
+                    3136607104634831627,
+                    1103104227812525641,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10936563342300883474,
+                    5105752968513867329,
+                    16440646200012018786,
+                    1145516564428180248,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6153784379076788893,
+                    14129569637194890662,
+                    7671891196888991008,
+                    278357162673002083,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3443801606660685444,
+                    2346820097393652674,
+                    11547720412233426580,
+                    73767729572152666,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4833319986024940824,
+                    1973131621980074782,
+                    5265051666893653217,
+                    1284545350824817598,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10526677505319336724,
+                    7948349569614103316,
+                    13751669773578367148,
+                    4451633541875716,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12781501894136032225,
+                    1772994968720563335,
+                    7706986969774658973,
+                    429694283574632517,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9285940990150439263,
+                    5946757783941852426,
+                    16630604571653876755,
+                    468018730201667762,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9278721144508174795,
+                    16083199059091148540,
+                    10357432254216705684,
+                    871125377590971299,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4026674268250449108,
+                    8311844678309507521,
+                    12241305056074268229,
+                    933043776300192562,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2640399986010287499,
+                    3895809702784934845,
+                    18025335164821009325,
+                    792419216770069674,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16308812595498638115,
+                    10048604989306843149,
+                    4161994250091878070,
+                    1054080479269611927,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4440322598097209446,
+                    15072620199507661412,
+                    12344720049312080211,
+                    988075061605509563,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3643580188160674377,
+                    10642804923809576612,
+                    1905840299389231588,
+                    1143334179236181373,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8017775966651834586,
+                    1459380453485585167,
+                    16058870721228155585,
+                    1016835943801435831,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15802581290971130835,
+                    10741582148340879804,
+                    504340179747868781,
+                    177848364516854767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9444407536053386545,
+                    3442810074366340337,
+                    3453655921135980616,
+                    1296700509454399529,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12781501894136032225,
+                    1772994968720563335,
+                    7706986969774658973,
+                    429694283574632517,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11540323937466583953,
+                    8875004857243086996,
+                    3485513132302347446,
+                    1259401307390155105,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14407267181003347804,
+                    11474318123327352078,
+                    7962178105209621736,
+                    866879141305262848,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11228779889068150820,
+                    2649254595325358219,
+                    1544337360657189614,
+                    175106010405459703,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5870056666689715195,
+                    17957839749838446177,
+                    17861693765660430315,
+                    1049405227115328042,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3666820249035041056,
+                    6178362956879029078,
+                    2764267436287750887,
+                    1333366258657775683,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4630889820833540582,
+                    11777794312333313906,
+                    7057626790635844369,
+                    321355071667257317,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17156505689778848277,
+                    3610466449555352140,
+                    2076252015024616151,
+                    635505600663365138,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15701705499912134995,
+                    14331656684264824616,
+                    7145554043057029499,
+                    36208615948585257,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3886818589831087357,
+                    15397371524719612419,
+                    15758567141375780274,
+                    106990524248294098,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9943478837519951970,
+                    9973184373904820463,
+                    2482554308804382223,
+                    225943340086174755,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7613423334776931464,
+                    14364225785726718090,
+                    190997604237216974,
+                    1280832891886898392,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9285940990150439263,
+                    5946757783941852426,
+                    16630604571653876755,
+                    468018730201667762,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14407267181003347804,
+                    11474318123327352078,
+                    7962178105209621736,
+                    866879141305262848,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14362133098821527872,
+                    6161548412573922345,
+                    13251132418997869931,
+                    338190360541802847,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14470338668076804724,
+                    16215004470512123123,
+                    10304232491735036735,
+                    1213347785126283136,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5695960101172893667,
+                    9666252398332451628,
+                    17105467411816674726,
+                    1259385086612782858,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12739587386951573175,
+                    4559224308454023600,
+                    1246074344312137700,
+                    1204344824667001416,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3548792467448682344,
+                    13725463450469320234,
+                    3235266897671413214,
+                    890052367852794841,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1924949481947732785,
+                    17430184151060593260,
+                    16498832853417349786,
+                    1222715632113313100,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17649459280212547747,
+                    1583063183276012785,
+                    13358236428675701124,
+                    454011082868038511,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9311265603383790073,
+                    2548529103863235790,
+                    3030766225910078014,
+                    127489111572381886,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3869458952764578091,
+                    2366048283700927196,
+                    7942079401492299058,
+                    565597003126920360,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5444401549811782343,
+                    15134455110492711357,
+                    12464430978836789883,
+                    402930987916428203,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9278721144508174795,
+                    16083199059091148540,
+                    10357432254216705684,
+                    871125377590971299,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11228779889068150820,
+                    2649254595325358219,
+                    1544337360657189614,
+                    175106010405459703,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14470338668076804724,
+                    16215004470512123123,
+                    10304232491735036735,
+                    1213347785126283136,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11247752240264098575,
+                    13538599834538994817,
+                    17779250670063659251,
+                    213784742824410934,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17085314198088710885,
+                    537123535578930850,
+                    11488027478060723948,
+                    84746852783188217,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4795175079716264556,
+                    17464931490293631570,
+                    15163209730815835994,
+                    736578642401548695,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9574398693144578169,
+                    479621589647616587,
+                    10239408841355569476,
+                    642607448699124839,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4679378876612161317,
+                    9202475458221310678,
+                    6929653183493992110,
+                    638803835602797004,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1221781484409528833,
+                    486298415317632119,
+                    16393796677579033533,
+                    37923159222996891,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16184208739966073341,
+                    7127925464546799665,
+                    4799608511559340286,
+                    1077475550651871286,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8014891535960987678,
+                    126643414188594280,
+                    5459764234915344934,
+                    757246264492597792,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9832561368705028224,
+                    6036996632821069809,
+                    12681235370666728557,
+                    482482883979133539,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4026674268250449108,
+                    8311844678309507521,
+                    12241305056074268229,
+                    933043776300192562,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5870056666689715195,
+                    17957839749838446177,
+                    17861693765660430315,
+                    1049405227115328042,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5695960101172893667,
+                    9666252398332451628,
+                    17105467411816674726,
+                    1259385086612782858,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17085314198088710885,
+                    537123535578930850,
+                    11488027478060723948,
+                    84746852783188217,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14889143541017238370,
+                    14936251162748034342,
+                    14973982487393236525,
+                    1331483779268577992,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7818279410821265023,
+                    2029188728834294511,
+                    9112573126523752006,
+                    1051903030783385258,
+                ]),
+                Fq::from_montgomery_limbs([
+                    962093136098553282,
+                    13851035071982548450,
+                    1076928023080319184,
+                    363047676361008422,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15706107230085149148,
+                    7296055801186352934,
+                    10177597195803980697,
+                    750055108800882277,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11196038420556690623,
+                    2398592059568444590,
+                    17734070025361081427,
+                    652048326249987069,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9019189746263135082,
+                    2999286386399327154,
+                    1615258897726571893,
+                    842985804662803538,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10599985789062789587,
+                    8216573485769182597,
+                    6523642537778640134,
+                    1003036072108778371,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16679153692081436634,
+                    17476999385180901684,
+                    3651982306688522989,
+                    770759381024621740,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2640399986010287499,
+                    3895809702784934845,
+                    18025335164821009325,
+                    792419216770069674,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3666820249035041056,
+                    6178362956879029078,
+                    2764267436287750887,
+                    1333366258657775683,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12739587386951573175,
+                    4559224308454023600,
+                    1246074344312137700,
+                    1204344824667001416,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4795175079716264556,
+                    17464931490293631570,
+                    15163209730815835994,
+                    736578642401548695,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7818279410821265023,
+                    2029188728834294511,
+                    9112573126523752006,
+                    1051903030783385258,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7492697910639668322,
+                    10125899450187955001,
+                    10317537725631970132,
+                    884479712770628165,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16708651302258279200,
+                    16267742392625093963,
+                    11137606626267579397,
+                    1119327523262022635,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7761569273933788611,
+                    11685161069048549001,
+                    16151747284508219455,
+                    1170040058874480018,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6639813334526531566,
+                    2833976130240118167,
+                    4173199571699304326,
+                    331448980684935255,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1902074105594142259,
+                    14508247393278649604,
+                    5086680762216633448,
+                    976312059588194762,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1883874784453611653,
+                    17494270805835581294,
+                    2760556669599978451,
+                    54786851338209267,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7238680318771722735,
+                    1766260997954463294,
+                    12429356996090433416,
+                    258499038923599006,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16308812595498638115,
+                    10048604989306843149,
+                    4161994250091878070,
+                    1054080479269611927,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4630889820833540582,
+                    11777794312333313906,
+                    7057626790635844369,
+                    321355071667257317,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3548792467448682344,
+                    13725463450469320234,
+                    3235266897671413214,
+                    890052367852794841,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9574398693144578169,
+                    479621589647616587,
+                    10239408841355569476,
+                    642607448699124839,
+                ]),
+                Fq::from_montgomery_limbs([
+                    962093136098553282,
+                    13851035071982548450,
+                    1076928023080319184,
+                    363047676361008422,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16708651302258279200,
+                    16267742392625093963,
+                    11137606626267579397,
+                    1119327523262022635,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2639491137097757681,
+                    10112197513022174024,
+                    14672886813149707776,
+                    1192644799334261220,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<11, 121>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    2892261390509219140,
+                    15336040130200622796,
+                    9530955479911406175,
+                    558599778060154470,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6549141123688306757,
+                    9146502490133081789,
+                    11403595892814308130,
+                    1003819874273421545,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2261869215427055245,
+                    4796677386575647293,
+                    2642040419263940123,
+                    142689231328569476,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17401665459568177038,
+                    15173658468099319670,
+                    18444904245339354901,
+                    1136620205851930145,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4077233675424104989,
+                    14663821942564338609,
+                    13485546706107324488,
+                    1091183926210729619,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17325817592792138636,
+                    2568249512095314173,
+                    15731089510656714082,
+                    408754314277079198,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11350031890473257874,
+                    12474167598935462595,
+                    14974533538483945798,
+                    834337477841824399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11961872184979037772,
+                    6321040808522185152,
+                    18082012202728731770,
+                    138149298305617190,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7418793954506901184,
+                    2928123530925006976,
+                    3589807425645405025,
+                    903031561424698549,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16643082133764127980,
+                    10243305816521024990,
+                    17189090054575208558,
+                    257987768520659232,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2374833008222755747,
+                    10386316961023398153,
+                    1185824671469870832,
+                    187665127907602175,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6549141123688306757,
+                    9146502490133081789,
+                    11403595892814308130,
+                    1003819874273421545,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9355531821009004993,
+                    5294542401138664121,
+                    12123699265017153687,
+                    1055978990630789690,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7931697791103116889,
+                    12192631141889779392,
+                    13925266580962467556,
+                    303727276784323444,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8702335744247889159,
+                    16209620201774267215,
+                    14091570434193582261,
+                    390759537026842107,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13012440741234194856,
+                    13839960995540050789,
+                    10808199798228986444,
+                    90829818556153144,
+                ]),
+                Fq::from_montgomery_limbs([
+                    865466690535993160,
+                    11404230351140933865,
+                    1896562135893195371,
+                    945274339687422067,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7306212690819561863,
+                    2559973003610984044,
+                    11530460750577699001,
+                    572064920513351646,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14413091218101277404,
+                    3786885463256369047,
+                    14321069892455178625,
+                    1314128880758361694,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7111629457237477575,
+                    9926400650938136036,
+                    326996627956321484,
+                    944518337019942094,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9751188773216415482,
+                    4556871154783870665,
+                    1563386599559375338,
+                    613816894614747616,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13755492897068501711,
+                    1025560562083074771,
+                    7135537727791770453,
+                    49992993255240966,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2261869215427055245,
+                    4796677386575647293,
+                    2642040419263940123,
+                    142689231328569476,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7931697791103116889,
+                    12192631141889779392,
+                    13925266580962467556,
+                    303727276784323444,
+                ]),
+                Fq::from_montgomery_limbs([
+                    229919797399137804,
+                    6328884072577927408,
+                    130409017071375743,
+                    497945814754769867,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12350500126523155488,
+                    9340920402083711248,
+                    16551994926605821021,
+                    992564490270698436,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7926324434120158487,
+                    11044167006904653278,
+                    9504692169230695342,
+                    241741696566863109,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15956001028860059850,
+                    5697675781737934877,
+                    2657061480145263555,
+                    280912631772091664,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12205477441527211005,
+                    17155116783671106459,
+                    2905433889264425764,
+                    767173295021856983,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13736312799786386579,
+                    9598072745568242787,
+                    9407719844851306766,
+                    314303834387788308,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10339716627269389110,
+                    13609825815870974458,
+                    4695978502949082305,
+                    372399478828090873,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9612337560681582821,
+                    18189245450723133196,
+                    13188838567727090339,
+                    580377959532861087,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7877018590479404784,
+                    13985593584186405580,
+                    5307838920810631938,
+                    297692735929779451,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17401665459568177038,
+                    15173658468099319670,
+                    18444904245339354901,
+                    1136620205851930145,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8702335744247889159,
+                    16209620201774267215,
+                    14091570434193582261,
+                    390759537026842107,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12350500126523155488,
+                    9340920402083711248,
+                    16551994926605821021,
+                    992564490270698436,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4863449788151655828,
+                    14408417062651520527,
+                    1344924499745539623,
+                    61683990294919057,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1324708208476653544,
+                    17238241408965611519,
+                    6113777873916853159,
+                    885793258553691528,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13266238781928745583,
+                    6513863721007873641,
+                    492645816345663494,
+                    444378468459909977,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12324582563851582302,
+                    2005635804917698950,
+                    11214779269382532776,
+                    967622211003165945,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15954786991173363784,
+                    18396488662254848659,
+                    16741266519354914675,
+                    1251045432410998878,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5872460951494845454,
+                    7104537786858168099,
+                    1373203872171272094,
+                    436060414908670993,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2964688207782507004,
+                    10330492326416940812,
+                    9340084707730968056,
+                    515630047608125824,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3968910509461208903,
+                    11653420467890463713,
+                    7059907610597664843,
+                    952132095451244708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4077233675424104989,
+                    14663821942564338609,
+                    13485546706107324488,
+                    1091183926210729619,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13012440741234194856,
+                    13839960995540050789,
+                    10808199798228986444,
+                    90829818556153144,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7926324434120158487,
+                    11044167006904653278,
+                    9504692169230695342,
+                    241741696566863109,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1324708208476653544,
+                    17238241408965611519,
+                    6113777873916853159,
+                    885793258553691528,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8017259338232079560,
+                    18193122262451995713,
+                    12556567053999943128,
+                    987234357870033402,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10779277860734456669,
+                    17864159979326479012,
+                    6124508230362152378,
+                    938980471005314865,
+                ]),
+                Fq::from_montgomery_limbs([
+                    106254307180746898,
+                    1898802276658743715,
+                    5201979429828352240,
+                    288116522248558066,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7602229807721979958,
+                    8402756621202329810,
+                    9899342182241754370,
+                    102485338540114270,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1586569922581948589,
+                    5488841519832098993,
+                    9390212136840845755,
+                    106305682973232899,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12087952099835412374,
+                    2064609841159231911,
+                    1090146667625992252,
+                    98744385286436195,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18377949924751403886,
+                    5885155124542307400,
+                    11729870148890594428,
+                    578349035027750206,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17325817592792138636,
+                    2568249512095314173,
+                    15731089510656714082,
+                    408754314277079198,
+                ]),
+                Fq::from_montgomery_limbs([
+                    865466690535993160,
+                    11404230351140933865,
+                    1896562135893195371,
+                    945274339687422067,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15956001028860059850,
+                    5697675781737934877,
+                    2657061480145263555,
+                    280912631772091664,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13266238781928745583,
+                    6513863721007873641,
+                    492645816345663494,
+                    444378468459909977,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10779277860734456669,
+                    17864159979326479012,
+                    6124508230362152378,
+                    938980471005314865,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7673006824001154036,
+                    9288728826107914484,
+                    10908591930651342982,
+                    588360268828297255,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10145433525018537353,
+                    8512491547403713389,
+                    16468787164903751726,
+                    35654611878461749,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4585504713845847387,
+                    10871992157605780431,
+                    96172364259550923,
+                    1189257122650478239,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3992044214679692288,
+                    7180482430119656243,
+                    12799511213468869776,
+                    1110829582936599290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13381045846245652152,
+                    5076110592832772784,
+                    18343489529288809244,
+                    1180410914842576968,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7244666105920680756,
+                    1318535851862709181,
+                    2152676736025879663,
+                    714122295532430812,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11350031890473257874,
+                    12474167598935462595,
+                    14974533538483945798,
+                    834337477841824399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7306212690819561863,
+                    2559973003610984044,
+                    11530460750577699001,
+                    572064920513351646,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12205477441527211005,
+                    17155116783671106459,
+                    2905433889264425764,
+                    767173295021856983,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12324582563851582302,
+                    2005635804917698950,
+                    11214779269382532776,
+                    967622211003165945,
+                ]),
+                Fq::from_montgomery_limbs([
+                    106254307180746898,
+                    1898802276658743715,
+                    5201979429828352240,
+                    288116522248558066,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10145433525018537353,
+                    8512491547403713389,
+                    16468787164903751726,
+                    35654611878461749,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16616001031821194570,
+                    15711596938391721101,
+                    1534015483788104259,
+                    142793953751913706,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13029251734086582149,
+                    2399438324947996427,
+                    13000792095875432010,
+                    221291685303499832,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1052099332284502335,
+                    15507939998001097048,
+                    12905800850726212243,
+                    482067196978475903,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2339197420920643764,
+                    12947680242692196924,
+                    4761067033253541275,
+                    92259820869120104,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1743232470015867922,
+                    9169419277075662278,
+                    6862366739096863936,
+                    507771154652441579,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11961872184979037772,
+                    6321040808522185152,
+                    18082012202728731770,
+                    138149298305617190,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14413091218101277404,
+                    3786885463256369047,
+                    14321069892455178625,
+                    1314128880758361694,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13736312799786386579,
+                    9598072745568242787,
+                    9407719844851306766,
+                    314303834387788308,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15954786991173363784,
+                    18396488662254848659,
+                    16741266519354914675,
+                    1251045432410998878,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7602229807721979958,
+                    8402756621202329810,
+                    9899342182241754370,
+                    102485338540114270,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4585504713845847387,
+                    10871992157605780431,
+                    96172364259550923,
+                    1189257122650478239,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13029251734086582149,
+                    2399438324947996427,
+                    13000792095875432010,
+                    221291685303499832,
+                ]),
+                Fq::from_montgomery_limbs([
+                    381940972743228910,
+                    17440459828669866824,
+                    8567230793148100810,
+                    141827988506959621,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5564160158773714954,
+                    7997825780546195981,
+                    13564472413999270835,
+                    14528031905689408,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4489622301521681428,
+                    7201912442099648434,
+                    11998773911039265669,
+                    623116251595980998,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4190871476744394152,
+                    1103057470537778226,
+                    3370343568257137646,
+                    333499222119720588,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7418793954506901184,
+                    2928123530925006976,
+                    3589807425645405025,
+                    903031561424698549,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7111629457237477575,
+                    9926400650938136036,
+                    326996627956321484,
+                    944518337019942094,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10339716627269389110,
+                    13609825815870974458,
+                    4695978502949082305,
+                    372399478828090873,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5872460951494845454,
+                    7104537786858168099,
+                    1373203872171272094,
+                    436060414908670993,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1586569922581948589,
+                    5488841519832098993,
+                    9390212136840845755,
+                    106305682973232899,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3992044214679692288,
+                    7180482430119656243,
+                    12799511213468869776,
+                    1110829582936599290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1052099332284502335,
+                    15507939998001097048,
+                    12905800850726212243,
+                    482067196978475903,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5564160158773714954,
+                    7997825780546195981,
+                    13564472413999270835,
+                    14528031905689408,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12863350573315366361,
+                    3517372804552470979,
+                    1127114157180545106,
+                    876016378351942655,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12187916291120908533,
+                    2411614166771036147,
+                    12118787149840655368,
+                    1323071288127193199,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1077757838889161146,
+                    18100326035915058898,
+                    8353114606746790464,
+                    7567269282036588,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16643082133764127980,
+                    10243305816521024990,
+                    17189090054575208558,
+                    257987768520659232,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9751188773216415482,
+                    4556871154783870665,
+                    1563386599559375338,
+                    613816894614747616,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9612337560681582821,
+                    18189245450723133196,
+                    13188838567727090339,
+                    580377959532861087,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2964688207782507004,
+                    10330492326416940812,
+                    9340084707730968056,
+                    515630047608125824,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12087952099835412374,
+                    2064609841159231911,
+                    1090146667625992252,
+                    98744385286436195,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13381045846245652152,
+                    5076110592832772784,
+                    18343489529288809244,
+                    1180410914842576968,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2339197420920643764,
+                    12947680242692196924,
+                    4761067033253541275,
+                    92259820869120104,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4489622301521681428,
+                    7201912442099648434,
+                    11998773911039265669,
+                    623116251595980998,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12187916291120908533,
+                    2411614166771036147,
+                    12118787149840655368,
+                    1323071288127193199,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2637558382302208114,
+                    3924695679763077765,
+                    16343352603997866804,
+                    1072185309705120462,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12549302025196157013,
+                    14805639446867353449,
+                    12152480690907152073,
+                    418803422972810146,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2374833008222755747,
+                    10386316961023398153,
+                    1185824671469870832,
+                    187665127907602175,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13755492897068501711,
+                    1025560562083074771,
+                    7135537727791770453,
+                    49992993255240966,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7877018590479404784,
+                    13985593584186405580,
+                    5307838920810631938,
+                    297692735929779451,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3968910509461208903,
+                    11653420467890463713,
+                    7059907610597664843,
+                    952132095451244708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18377949924751403886,
+                    5885155124542307400,
+                    11729870148890594428,
+                    578349035027750206,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7244666105920680756,
+                    1318535851862709181,
+                    2152676736025879663,
+                    714122295532430812,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1743232470015867922,
+                    9169419277075662278,
+                    6862366739096863936,
+                    507771154652441579,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4190871476744394152,
+                    1103057470537778226,
+                    3370343568257137646,
+                    333499222119720588,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1077757838889161146,
+                    18100326035915058898,
+                    8353114606746790464,
+                    7567269282036588,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12549302025196157013,
+                    14805639446867353449,
+                    12152480690907152073,
+                    418803422972810146,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12703203068240995441,
+                    9992949890585164955,
+                    8078343663911185889,
+                    659343359289315622,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            M_i: Matrix::<12, 12, 144>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14383764950224124300,
+                    5067625615292165969,
+                    1629836187151610748,
+                    1133316493453343651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15244412610076867041,
+                    13364227409843238715,
+                    12108766514993485967,
+                    948768716120042779,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17145860332859577865,
+                    9611792619118776648,
+                    13214165680654291533,
+                    377217488405916946,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10417857480330563789,
+                    3672650819851766030,
+                    2289499341558970208,
+                    819759164760729111,
+                ]),
+                Fq::from_montgomery_limbs([
+                    853126972657214948,
+                    3557792415418747303,
+                    7082935391649499018,
+                    381044357566544388,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17313546321454614219,
+                    6309354198700353089,
+                    5406820482949123755,
+                    524922273998959263,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3017805502744493169,
+                    14413330968718734841,
+                    8389117194744343408,
+                    629971246280988912,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1415597038580600547,
+                    11281018358397274739,
+                    2622157027084291236,
+                    282535577227028671,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3522353815320666122,
+                    3783221579718181263,
+                    5575631655455976864,
+                    555759933996671035,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17729646342579726179,
+                    1046401658658475469,
+                    1736419987818588669,
+                    51997312131135672,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4352278039477967372,
+                    7418731782624945208,
+                    6685679718878956353,
+                    1037171252464589714,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15244412610076867041,
+                    13364227409843238715,
+                    12108766514993485967,
+                    948768716120042779,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11842676843145963665,
+                    5248273416216190739,
+                    4846170853606531939,
+                    751391026323761908,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7535411950298455560,
+                    9391535459649980639,
+                    1420396188891948990,
+                    805858931240798406,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11888357480462153971,
+                    6742527396216645785,
+                    1446692096970793716,
+                    743944450431836759,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11409057190040451217,
+                    269307731941697824,
+                    6473521699175051893,
+                    1126906082293946675,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3257761880801633542,
+                    4975604789588444673,
+                    6330950079626388200,
+                    339009244479849452,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17360923196012649177,
+                    5221821609093805809,
+                    915602777221184984,
+                    856318182560987892,
+                ]),
+                Fq::from_montgomery_limbs([
+                    830091558339313441,
+                    3473194379559497145,
+                    16400567125878948681,
+                    286865649210884290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4549810559278695553,
+                    17981611224941560996,
+                    17255683148690954108,
+                    364146080285907292,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10379563813412045101,
+                    420305230139869427,
+                    4212921363798278129,
+                    248123942701911182,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8735020455493541838,
+                    428565209801993891,
+                    16686913261765517273,
+                    392667487850621927,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17145860332859577865,
+                    9611792619118776648,
+                    13214165680654291533,
+                    377217488405916946,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7535411950298455560,
+                    9391535459649980639,
+                    1420396188891948990,
+                    805858931240798406,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14146955574974309098,
+                    10559141617276359712,
+                    12298191988170338355,
+                    760906305338249246,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4909596453723944005,
+                    3068591992821980484,
+                    15919281233243334591,
+                    193988722037556231,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3395902837533124365,
+                    12881944046209126196,
+                    5827969079915234592,
+                    193497813111399431,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7003357564090052325,
+                    15440414639683174701,
+                    5676125807988778101,
+                    75171080021002040,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5578022993844423620,
+                    8973361674343208610,
+                    7654692838791795526,
+                    822056753065652792,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17818907570625834509,
+                    15385044068489447159,
+                    14074175407625581460,
+                    904292058823593454,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9908529077638108981,
+                    18311938067727146356,
+                    7078565674310864466,
+                    14373802447560533,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7664971027018044109,
+                    13934751913145852402,
+                    1729980285084745937,
+                    478601701064047767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16864243773946266901,
+                    2365409714510756283,
+                    6053395065709151473,
+                    118942129374124263,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10417857480330563789,
+                    3672650819851766030,
+                    2289499341558970208,
+                    819759164760729111,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11888357480462153971,
+                    6742527396216645785,
+                    1446692096970793716,
+                    743944450431836759,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4909596453723944005,
+                    3068591992821980484,
+                    15919281233243334591,
+                    193988722037556231,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3236616059984939171,
+                    16020593713623545135,
+                    5483122337264645233,
+                    840555803433483002,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16498832046826245071,
+                    1717110160026983881,
+                    11758299391486581939,
+                    980476447962874870,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14289545138966708053,
+                    2094064581966014526,
+                    14067490605201997876,
+                    743313434441343447,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8822577205397817650,
+                    8652922827639387955,
+                    3295215966478074531,
+                    587608508225198440,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17864196065915921641,
+                    613222860854842701,
+                    15175341859510529965,
+                    724613259926005585,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16149420516569947217,
+                    18177434133422053121,
+                    8369349273104835864,
+                    1167369481235255015,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12953721632553623113,
+                    9759328158824053436,
+                    8660836343102540738,
+                    1041731548124338198,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18252719679842082569,
+                    18112390401083302147,
+                    1802931699213704537,
+                    882607316306063362,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    853126972657214948,
+                    3557792415418747303,
+                    7082935391649499018,
+                    381044357566544388,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11409057190040451217,
+                    269307731941697824,
+                    6473521699175051893,
+                    1126906082293946675,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3395902837533124365,
+                    12881944046209126196,
+                    5827969079915234592,
+                    193497813111399431,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16498832046826245071,
+                    1717110160026983881,
+                    11758299391486581939,
+                    980476447962874870,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9656631422136507814,
+                    803456021690714683,
+                    14868317012691540699,
+                    398842631750856053,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14125768736358427134,
+                    16754589653703380185,
+                    17325128783989446179,
+                    1303538060700488883,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13019261074594589767,
+                    16852664344954177267,
+                    6879477705562856977,
+                    355857046879713105,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1580517527663891425,
+                    14480528031184069875,
+                    7399148008757118032,
+                    981785632280065659,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18177668696934009957,
+                    5697767587281881329,
+                    6105264026671559923,
+                    627024109255647829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18033491346494194442,
+                    463885869624146451,
+                    9193582043119043190,
+                    701159602511727038,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8445743948620720364,
+                    2086577247669913096,
+                    4546186395718976514,
+                    627087125304211093,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17313546321454614219,
+                    6309354198700353089,
+                    5406820482949123755,
+                    524922273998959263,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3257761880801633542,
+                    4975604789588444673,
+                    6330950079626388200,
+                    339009244479849452,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7003357564090052325,
+                    15440414639683174701,
+                    5676125807988778101,
+                    75171080021002040,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14289545138966708053,
+                    2094064581966014526,
+                    14067490605201997876,
+                    743313434441343447,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14125768736358427134,
+                    16754589653703380185,
+                    17325128783989446179,
+                    1303538060700488883,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13371963632275079170,
+                    12201263746911008941,
+                    14162959996589972327,
+                    1172303922531914123,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17551080135468574344,
+                    6102369477958418275,
+                    2532983719424648678,
+                    797229282587074359,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16476063407100075140,
+                    9993153285351255262,
+                    15637040671666352156,
+                    1022169198632289443,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7212739585906524638,
+                    185122599857219468,
+                    8122379070770327740,
+                    1023131316331018250,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14037254921938474694,
+                    14510402145997356831,
+                    6739773672556279127,
+                    1002724540526429469,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13995910322408648193,
+                    6186900444617777238,
+                    3865294546477754946,
+                    779866433611065535,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3017805502744493169,
+                    14413330968718734841,
+                    8389117194744343408,
+                    629971246280988912,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17360923196012649177,
+                    5221821609093805809,
+                    915602777221184984,
+                    856318182560987892,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5578022993844423620,
+                    8973361674343208610,
+                    7654692838791795526,
+                    822056753065652792,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8822577205397817650,
+                    8652922827639387955,
+                    3295215966478074531,
+                    587608508225198440,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13019261074594589767,
+                    16852664344954177267,
+                    6879477705562856977,
+                    355857046879713105,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17551080135468574344,
+                    6102369477958418275,
+                    2532983719424648678,
+                    797229282587074359,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4096508019860969267,
+                    14116910955926344930,
+                    8751283157428739997,
+                    889949091514957747,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11618955606976010338,
+                    5596823787507546289,
+                    10282998138859261058,
+                    146076792951304953,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7130821030468632154,
+                    18132825202954347175,
+                    13316875385298368433,
+                    204161768051914190,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13602204181513300374,
+                    1567267217816578569,
+                    7996187188002333342,
+                    843040512298704945,
+                ]),
+                Fq::from_montgomery_limbs([
+                    577893428808737657,
+                    5073583813722154993,
+                    3925156909676366593,
+                    493172086851342831,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1415597038580600547,
+                    11281018358397274739,
+                    2622157027084291236,
+                    282535577227028671,
+                ]),
+                Fq::from_montgomery_limbs([
+                    830091558339313441,
+                    3473194379559497145,
+                    16400567125878948681,
+                    286865649210884290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17818907570625834509,
+                    15385044068489447159,
+                    14074175407625581460,
+                    904292058823593454,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17864196065915921641,
+                    613222860854842701,
+                    15175341859510529965,
+                    724613259926005585,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1580517527663891425,
+                    14480528031184069875,
+                    7399148008757118032,
+                    981785632280065659,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16476063407100075140,
+                    9993153285351255262,
+                    15637040671666352156,
+                    1022169198632289443,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11618955606976010338,
+                    5596823787507546289,
+                    10282998138859261058,
+                    146076792951304953,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1872353699894475392,
+                    4914335679371854843,
+                    2058701458259086294,
+                    106833971727653570,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11971886954173971194,
+                    4301932464462667555,
+                    17288841405052366652,
+                    1325849815643216064,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12183822109508646662,
+                    18195764320276371832,
+                    10436929215761855754,
+                    552085024210511584,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1180825861349729032,
+                    2454430605367365228,
+                    1517479953984153169,
+                    542889398140116850,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3522353815320666122,
+                    3783221579718181263,
+                    5575631655455976864,
+                    555759933996671035,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4549810559278695553,
+                    17981611224941560996,
+                    17255683148690954108,
+                    364146080285907292,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9908529077638108981,
+                    18311938067727146356,
+                    7078565674310864466,
+                    14373802447560533,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16149420516569947217,
+                    18177434133422053121,
+                    8369349273104835864,
+                    1167369481235255015,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18177668696934009957,
+                    5697767587281881329,
+                    6105264026671559923,
+                    627024109255647829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7212739585906524638,
+                    185122599857219468,
+                    8122379070770327740,
+                    1023131316331018250,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7130821030468632154,
+                    18132825202954347175,
+                    13316875385298368433,
+                    204161768051914190,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11971886954173971194,
+                    4301932464462667555,
+                    17288841405052366652,
+                    1325849815643216064,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10876400344704381942,
+                    16702831619241715265,
+                    6405508516370801751,
+                    1025808483644049500,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17764762695391128747,
+                    2697613507480715719,
+                    6197870843222868588,
+                    1247665577117037421,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237463313319317670,
+                    1747901254742696527,
+                    6533493659198582049,
+                    972962355309703692,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17729646342579726179,
+                    1046401658658475469,
+                    1736419987818588669,
+                    51997312131135672,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10379563813412045101,
+                    420305230139869427,
+                    4212921363798278129,
+                    248123942701911182,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7664971027018044109,
+                    13934751913145852402,
+                    1729980285084745937,
+                    478601701064047767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12953721632553623113,
+                    9759328158824053436,
+                    8660836343102540738,
+                    1041731548124338198,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18033491346494194442,
+                    463885869624146451,
+                    9193582043119043190,
+                    701159602511727038,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14037254921938474694,
+                    14510402145997356831,
+                    6739773672556279127,
+                    1002724540526429469,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13602204181513300374,
+                    1567267217816578569,
+                    7996187188002333342,
+                    843040512298704945,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12183822109508646662,
+                    18195764320276371832,
+                    10436929215761855754,
+                    552085024210511584,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17764762695391128747,
+                    2697613507480715719,
+                    6197870843222868588,
+                    1247665577117037421,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10950339116951670064,
+                    4546733478567791881,
+                    7076069432648633306,
+                    761724099506938900,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11270772021774390424,
+                    10759174996157885322,
+                    9864068174281832158,
+                    1173932410489140784,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4352278039477967372,
+                    7418731782624945208,
+                    6685679718878956353,
+                    1037171252464589714,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8735020455493541838,
+                    428565209801993891,
+                    16686913261765517273,
+                    392667487850621927,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16864243773946266901,
+                    2365409714510756283,
+                    6053395065709151473,
+                    118942129374124263,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18252719679842082569,
+                    18112390401083302147,
+                    1802931699213704537,
+                    882607316306063362,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8445743948620720364,
+                    2086577247669913096,
+                    4546186395718976514,
+                    627087125304211093,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13995910322408648193,
+                    6186900444617777238,
+                    3865294546477754946,
+                    779866433611065535,
+                ]),
+                Fq::from_montgomery_limbs([
+                    577893428808737657,
+                    5073583813722154993,
+                    3925156909676366593,
+                    493172086851342831,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1180825861349729032,
+                    2454430605367365228,
+                    1517479953984153169,
+                    542889398140116850,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237463313319317670,
+                    1747901254742696527,
+                    6533493659198582049,
+                    972962355309703692,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11270772021774390424,
+                    10759174996157885322,
+                    9864068174281832158,
+                    1173932410489140784,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6072365827080479938,
+                    13700708502340248231,
+                    16989262129673091506,
+                    105017662757802605,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15441359965645378481,
+                        7022857083512140090,
+                        11162758362666494739,
+                        1108530997049617801,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7802104352665277001,
+                        1712233803072817444,
+                        12180777293186749586,
+                        1125441666595228915,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7330330845985706257,
+                        6947957132670498679,
+                        9373711551562393463,
+                        1140097580201425214,
+                    ]),
+                    Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                    Fq::from_montgomery_limbs([
+                        829043150522309210,
+                        7485532467658058089,
+                        17072453966431109601,
+                        610297755857677130,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18043687544558598371,
+                        8708325242362018018,
+                        16625116017321440141,
+                        277441131490434312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1903578231313020389,
+                        13252279451564941743,
+                        6429417326301386859,
+                        900077037001125169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14902495609461984461,
+                        16049616749407607193,
+                        8772353492731922841,
+                        1191393277823112260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5443236819267423281,
+                        9444106377351672003,
+                        16592192658774520588,
+                        1198721234626210409,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13217949757478296855,
+                        18361600593446830079,
+                        18223280994163007859,
+                        471593720435477782,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14891635570577939411,
+                        4930228641865103982,
+                        677506429995745101,
+                        158637391136506271,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15925027800626522375,
+                        11330262051645359760,
+                        9659363215590318185,
+                        1236770241103835416,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13281265462344926550,
+                        1946075634967000886,
+                        16634500894150540557,
+                        704548642720287261,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17826515674060446540,
+                        1366042044916171884,
+                        14266855512421582509,
+                        440763060591034712,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7167231193755357267,
+                        12331350806542318038,
+                        618994387486011156,
+                        348349087898553160,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18249754527180420839,
+                        11284621586747177993,
+                        2937417961457554018,
+                        691778766037307082,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3769855397721126433,
+                        6380500329606026939,
+                        6269525289744717707,
+                        269298838809775986,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6081292450639885537,
+                        8384220332007933599,
+                        10102424316010113681,
+                        1142797563979406967,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        320749838154170665,
+                        7810967227905669573,
+                        11395619181389497640,
+                        1313168771099265604,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10976883598678346111,
+                        4506987246943090884,
+                        12474147950124336819,
+                        937449989971173884,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9317967826951221226,
+                        8601115530340921042,
+                        9347543157836367652,
+                        141941917288213276,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10691424186741408065,
+                        9353008836409080970,
+                        3385887101841429359,
+                        238017096070724897,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        399753002780690566,
+                        7186482414823501912,
+                        3604022191825982277,
+                        932452397114952125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4282033338727581060,
+                        13524548942127328971,
+                        12944034045483271178,
+                        1305832765943019006,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7114793882752374837,
+                        16526137269494904582,
+                        16417244921731562847,
+                        1070323640230014248,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15247305855271721200,
+                        6641330249832549451,
+                        8566910615610491140,
+                        1094589443676108199,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10144918337969575977,
+                        1596601304712905247,
+                        12994578778412876568,
+                        263342330152671439,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        381798920294866289,
+                        4527867648540211277,
+                        11533735796284087730,
+                        471173618582593603,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9740714656632896953,
+                        849757235097042818,
+                        7120514365419763265,
+                        202309268101259796,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14395121044314732302,
+                        7964221356937875918,
+                        5687060405712763726,
+                        325397077368077795,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7916026636507286020,
+                        14490156480897459913,
+                        9778850466677388063,
+                        643873659420268248,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8134497573974444706,
+                        1718413888789987641,
+                        2129816465057559332,
+                        989688937934019955,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3647350159968688096,
+                        2004974546116423957,
+                        2192567619823083400,
+                        809626242644645477,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9179309333188025861,
+                        15336071626906950135,
+                        15970298095896077941,
+                        780810315160764490,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11688781320354474584,
+                        6643920520668756398,
+                        16538831802367095671,
+                        53595567282387849,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3769430202548523344,
+                        7512253872289897743,
+                        2079248929257261758,
+                        1204381951095469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15220639730889627717,
+                        7766308048677835086,
+                        3532145983045430202,
+                        1193861608518876807,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7340308109205552369,
+                        2096442321333225838,
+                        3481854221289090949,
+                        1064001585529953244,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3777069274809571842,
+                        5831351815902537987,
+                        12751951092247743151,
+                        393685443165307144,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12948968572667023241,
+                        18188929834316136730,
+                        15811639865994945554,
+                        1341131630743046798,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1871769144350029797,
+                        16492481502573078936,
+                        12465605796709956807,
+                        57063062599110029,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11828081845160119593,
+                        15809112829221655224,
+                        12313758490626276590,
+                        440290814051989554,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8737713420548863667,
+                        11224615778465736058,
+                        8785674452752167514,
+                        725439005282707560,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18076840907175660180,
+                        15998855182242321206,
+                        3482027561225773243,
+                        80969678861753698,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5352183575491826141,
+                        12544295787576290129,
+                        10098748847671071177,
+                        716848191485718091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4939221519494842051,
+                        9455668322467411067,
+                        9039080435573738847,
+                        509048395617415859,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3679345890240450250,
+                        16266429793133909178,
+                        6237269443911293813,
+                        629011704556629817,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15660709262456442703,
+                        8252687084479921960,
+                        15966235269952349447,
+                        681622750037615828,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2095522696051857045,
+                        12448720137712793980,
+                        2706711670621264039,
+                        1235574565422295550,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16093130998807578713,
+                        12666476987931641190,
+                        9139794219777636712,
+                        411707298203038131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7824183975577115927,
+                        5228418969531286397,
+                        7865380916137972567,
+                        894538914507198711,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10675470204071802452,
+                        5523494135769624893,
+                        17167417429077619360,
+                        130922223080289250,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18247561308389893907,
+                        1430793647380898773,
+                        17476603638914665818,
+                        466321232367618351,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13374840275471217575,
+                        7490802249259707133,
+                        173960929381289818,
+                        1121239403695461032,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16208942411190985124,
+                        6877345381831892308,
+                        16065162518338476034,
+                        181037935580101267,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9282991130610501637,
+                        3296405306035329233,
+                        7349855845052863587,
+                        98018916724859831,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14767945077533996159,
+                        4143181371379657191,
+                        7157652623034888774,
+                        1076916391526900354,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7901047136877793996,
+                        8939033237439241157,
+                        6751486720861384119,
+                        1153850460836125117,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18091220883711560393,
+                        13582747720242774132,
+                        8787174885718255402,
+                        533534290667924396,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4784011397651578942,
+                        5434325963819247331,
+                        5284876571156455884,
+                        1331319304354402697,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17243530570027134001,
+                        17764123168623047353,
+                        6365054995301970877,
+                        557866862565379632,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12013390924078574761,
+                        2943939916300640948,
+                        9268310274781912843,
+                        441881594683895473,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17313008759072660715,
+                        12972429610421043213,
+                        3292433937808380812,
+                        473716798154261526,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2899326912695930922,
+                        8047669711380396365,
+                        271022410569812419,
+                        671571554098778789,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12463882892683089437,
+                        12108908001077282047,
+                        11321448407163681463,
+                        849456551332174860,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7983050833622427266,
+                        975459245381500561,
+                        13263949206161664320,
+                        584535936732146469,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6489529241569394728,
+                        6461000464472119517,
+                        18273580761515632876,
+                        152735065817306026,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6789381638129847466,
+                        8790859863764022190,
+                        13079505108787755444,
+                        774595013137536598,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5932926742443176123,
+                        9526061441684525514,
+                        15739936732623100944,
+                        359064528620196948,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5098164480848980343,
+                        10216282773633910751,
+                        6272134262881586696,
+                        1282147218802246632,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16451265041965856989,
+                        14804944643930546963,
+                        18317856720227704985,
+                        621984217150416992,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13463928494094911083,
+                        2768212270176405365,
+                        14323347232839102625,
+                        1138992948154493955,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3536613570914878958,
+                        14520108967463934399,
+                        15177347162513800771,
+                        1116583782232807579,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15523436221562601430,
+                        12611532557422112438,
+                        13142179898374807334,
+                        340345462195706606,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4955451964646784542,
+                        18436950322578760718,
+                        17296568397145446958,
+                        1028814754707495013,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2680154784877324186,
+                        1763469238276985918,
+                        8324401848586311860,
+                        1304880780524547630,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6062002309638458263,
+                        16847032196047758994,
+                        16993585139695281873,
+                        241324041572813016,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18395929092965708428,
+                        199481103329780807,
+                        2697036363069425596,
+                        26936147596614595,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13656148295335358635,
+                        6904195505355650731,
+                        14804103715365912057,
+                        409128256306463930,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11604978885139368561,
+                        16951701494184166178,
+                        17509150640310022953,
+                        1009500311863550927,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5126439157815346015,
+                        15423709192202556100,
+                        4715610767554961310,
+                        831078405184248808,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2306925264509191051,
+                        10740590950092029818,
+                        6050336851226139716,
+                        294815937026038304,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18341999936359506899,
+                        6344234978429044317,
+                        4462933530171223615,
+                        235284142128643559,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16295731161264039378,
+                        2581913166830694698,
+                        1504366583571699654,
+                        831048056440883816,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1412308802062440309,
+                        17928613016878241109,
+                        147192193977769360,
+                        899252646346690484,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5143066517667627549,
+                        8911680064737088885,
+                        8295503391888125931,
+                        214232556962406018,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14710801907736536012,
+                        8504935286942244239,
+                        467674688022120691,
+                        1219403551079489762,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16057396709748614105,
+                        15621300293571933057,
+                        12296670185614442090,
+                        92576895959220832,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2628211416391274930,
+                        1776203340991664079,
+                        11953723153782073624,
+                        1118068899163242085,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2589557641507694379,
+                        14511588551265511701,
+                        4226233005380288888,
+                        742852579557338106,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6436682568255854499,
+                        11396599082271531713,
+                        373448833576876348,
+                        94962356035379169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9706728107404584729,
+                        577742263039619653,
+                        12121673013472503202,
+                        175445854220706778,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16624665966998183129,
+                        10518017574303923034,
+                        4089282249218228293,
+                        803891103218058306,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13692440644261230379,
+                        18181750260396321108,
+                        5509207941172771567,
+                        57932842787344553,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12022520042865984069,
+                        2129034556061907329,
+                        8887877889929591400,
+                        1077322870132579720,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2895974780776196401,
+                        4129995838225291429,
+                        17261709363239185610,
+                        162477239240876789,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16874289753297565786,
+                        13272554882003163867,
+                        9981132955982544509,
+                        798376766432708699,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16043624120045636170,
+                        1262780345885749871,
+                        15839731119410172299,
+                        407715702327835327,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7800120246505038766,
+                        10385232423000953174,
+                        17064220297980545268,
+                        541023503266014733,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13694656074880616496,
+                        11988014737179045104,
+                        14093842012860068115,
+                        76151568775340181,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15361603545970225511,
+                        9951062136741242645,
+                        16191622027329351265,
+                        848630733286815462,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        411133834785225223,
+                        4394758960705797370,
+                        8654872867213118764,
+                        329214938321294122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13855031387654191127,
+                        17402419858683387877,
+                        2608649743036064359,
+                        138641283177654058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        976230919556487609,
+                        10996272393166784699,
+                        10490702690836250226,
+                        353936495468197288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15062758112468775000,
+                        11506169629142940467,
+                        5660974321035855390,
+                        478941982589525935,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11260089048753586905,
+                        12089772075786158480,
+                        10037458864739384213,
+                        607156972261994388,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5277205904021821671,
+                        7667064118082640891,
+                        16349181858914816598,
+                        1126755863302571194,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1868873238165527046,
+                        8886007159796334672,
+                        2490790959757273722,
+                        1064736276611464799,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10346179500903301237,
+                        4508859205697659250,
+                        16013512579479460313,
+                        443609702828513399,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11430231316627562785,
+                        13470404633121567720,
+                        10440781825694631320,
+                        1015582049483987826,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14085881861579218338,
+                        9215626324876922534,
+                        18408748783739594152,
+                        911703228213531139,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2957281881108433466,
+                        8393977988523504112,
+                        4407699318013786669,
+                        691027581870817652,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6576845259932004985,
+                        4632191787142580565,
+                        6487869388878527647,
+                        1059571698444151534,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3020636617796480158,
+                        9951393295652113900,
+                        9176065267466304188,
+                        312838651313328745,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5728437550099756527,
+                        85585833179507724,
+                        14283172237966055516,
+                        705870877654122838,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14048055221824776517,
+                        17296995143877385989,
+                        16263831692508049714,
+                        236083398377991592,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4234132716425238067,
+                        13089356847822472212,
+                        10306452789714534378,
+                        223904532260616027,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12059211102301105534,
+                        11212899145355027754,
+                        4774303376105825424,
+                        1057769922283039703,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1313317778831349910,
+                        3160900488726925261,
+                        18324794877015488031,
+                        288853551737507227,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13037073262591677992,
+                        12936677018074106522,
+                        1758679761754228384,
+                        1051915404814995960,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7341998358374939715,
+                        1052062236820046301,
+                        1917600891601147096,
+                        702774764902785042,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4047608232077836663,
+                        15876376608126934918,
+                        1694289777087174074,
+                        767206187005076157,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12323312063239509614,
+                        3051737177706648209,
+                        12208233297815069532,
+                        1236292994546056481,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8383850401847818317,
+                        2575853894603995743,
+                        8774474785848758492,
+                        886651618189866898,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10864456205315859080,
+                        13558827279168358890,
+                        12880417139615379394,
+                        677663093223050563,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6705477100642458843,
+                        2825359120997751960,
+                        5091288443512403064,
+                        1323682993611486374,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18285942385414955656,
+                        12394771433886121929,
+                        2096563050729983140,
+                        437898375150077553,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7695203580139702783,
+                        14483166028087359289,
+                        13858884863739239161,
+                        48802327352847934,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2331684030903379212,
+                        12233194437778919602,
+                        14992251736317379385,
+                        277341269983829013,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4156286200168406993,
+                        4447602843584548808,
+                        16877794331900906900,
+                        894008898056658050,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        276015989438284745,
+                        5500652038844432490,
+                        12608754126695005885,
+                        1181605108597138292,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7590585050686745292,
+                        3942484123323524304,
+                        17712389844448365942,
+                        210354881443183869,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1991794800755857958,
+                        10182577739871380105,
+                        12829418367629756738,
+                        834981114497924490,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7351367209658213751,
+                        1083433526480437431,
+                        12424655864173077748,
+                        831416453816447508,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18366501439484513592,
+                        13605020402097277816,
+                        13643157989526491124,
+                        887970292354309472,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1046438835801118505,
+                        16145492691620494101,
+                        2280200397061283444,
+                        869224925563029658,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5122040573412921701,
+                        13088215908163640108,
+                        18351732509348233508,
+                        129622665952461281,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5767719575926801676,
+                        13767829831429563149,
+                        10594562093641137203,
+                        470839419744288139,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        929558438405754368,
+                        15124736999277101357,
+                        6889758013731008005,
+                        729643457768986941,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2423865843974768855,
+                        9534677223268484542,
+                        12833954523347969357,
+                        263867847763775603,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1486206632722481732,
+                        5762664032286147323,
+                        3305205812822090674,
+                        210123317943712446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2101654864239915967,
+                        12969440909093908634,
+                        11678147429163710798,
+                        242318735689048904,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8457082637048969514,
+                        15403419719270729547,
+                        4750551596657005930,
+                        434818594493704244,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2194206368195936582,
+                        7245715503377379469,
+                        7055952070902183143,
+                        91104532888364058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3270806957369931506,
+                        9834191856043643371,
+                        5026199281454202843,
+                        559532708372784548,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14585283646984344202,
+                        13880578773537223698,
+                        773186409493473937,
+                        269877628595645288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17455425572480751172,
+                        1308168451553286478,
+                        7953089068759208712,
+                        1282599051608689459,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12422756575473903597,
+                        295696327161840348,
+                        11354550043512766936,
+                        1343655361187218730,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11577434652158864991,
+                        17966543491495636694,
+                        12200870678653632782,
+                        1239148381531917535,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9033611167535966802,
+                        5332711051826238344,
+                        12542363996572488240,
+                        1137592419855910698,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2722340670558993133,
+                        14734113342920397238,
+                        16711339409771658005,
+                        284115289037213106,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14765775209070162690,
+                        2450719588311977968,
+                        138092276418171631,
+                        39820352824297591,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        85894131835342612,
+                        8872328619030791330,
+                        18258151626820225018,
+                        31772459297956639,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1870327074485330190,
+                        15549358326298769670,
+                        11207772998521772725,
+                        1053087067314375304,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13242588975997715616,
+                        11843688194974892007,
+                        5635818228058319652,
+                        124609973495198841,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4211483220335919386,
+                        10092674748094622488,
+                        18089280461647923458,
+                        537645582642721464,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8637692413273970298,
+                        5894022054572823796,
+                        1087688297135589612,
+                        1035612413295525370,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15954493964519048860,
+                        14473861734260905223,
+                        2742422929764314393,
+                        1285938692772415323,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        671258884869948862,
+                        13014436014208043188,
+                        2867263025159073014,
+                        1334930149000533596,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7564001282001983906,
+                        15311348299811813992,
+                        8667140636673595263,
+                        31920221470116029,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11912907618423292647,
+                        6435032640679817508,
+                        2925392433446713542,
+                        861907464456255524,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        980600032051402604,
+                        14180663875041935000,
+                        6234798570067067453,
+                        1155092750918052483,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10461221643896343985,
+                        15591930048058108,
+                        2334633433151256492,
+                        1143401064650148110,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1852487157685589480,
+                        1412626767163083403,
+                        14085585923264363150,
+                        626330789674340168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        824181319074128176,
+                        15266024095976195693,
+                        13339631853183707268,
+                        178466415072227956,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12397853460355391439,
+                        8396552372960855855,
+                        16218003522334552475,
+                        673171552418580326,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17699049282590439062,
+                        9892451976162282791,
+                        1545828046888408936,
+                        946092986018301560,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15333301816540812168,
+                        1447451116634154044,
+                        6313211938930234595,
+                        850657002780536742,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4870038755460466729,
+                        1677173220986478940,
+                        17106021280006947653,
+                        838088163036218856,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6148637301731271102,
+                        6675518182296146750,
+                        8610780960014244469,
+                        1288623298177591808,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18374109224154317144,
+                        7276981264541460429,
+                        8164014017318609664,
+                        650963473026328226,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9607442420136317199,
+                        742588058198000937,
+                        11940704522500289273,
+                        384774831810348258,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8002185943657188375,
+                        6308567026480335632,
+                        14913327519061760061,
+                        79523780510454548,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2640726480989141255,
+                        14906311901295338983,
+                        14797143063455362758,
+                        480092657826456795,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1188435316863004864,
+                        8624118494503821212,
+                        5672140544275353105,
+                        953009774774225614,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1788158679159050547,
+                        15606444008768223090,
+                        7773356142076118860,
+                        38932657459958252,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6350522484090722708,
+                        9663382819669849182,
+                        12838379180003172306,
+                        2367960080214572,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11097104958011980516,
+                        2714534716757604333,
+                        7030039821273302189,
+                        219150312034174851,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1565132897861694252,
+                        12016834077824791768,
+                        16602858724877965266,
+                        551781904407392813,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15044708247621606451,
+                        9058394922791177339,
+                        15696294290495794386,
+                        743985597793838405,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13018036651100965488,
+                        17980339992488307798,
+                        1886652622594068533,
+                        1176548156443498213,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9340521405485977690,
+                        11095447257217913247,
+                        17721640030802208701,
+                        643585090203999309,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6696284539991591142,
+                        9429731668498076170,
+                        10782811513934494053,
+                        1099701861442426241,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12719713162514811959,
+                        13998465069632481010,
+                        479725572414985662,
+                        294345602692672221,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15426873855493517752,
+                        8687770684800025775,
+                        12215588446675493636,
+                        1251453126210481896,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17177710148816485626,
+                        3196543362373831615,
+                        8660501777283707972,
+                        307068037535977145,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10693312921170931086,
+                        8347963517694411892,
+                        14128285463328499230,
+                        912298634025626224,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15847495893738261665,
+                        6304702025020691810,
+                        17698027400676802771,
+                        1072028335633516170,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14485878925045058397,
+                        7419614530693504532,
+                        11955243202011029315,
+                        321354059365110240,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7284767072479401272,
+                        3028482290100067300,
+                        8759624208709818756,
+                        1118037021055195584,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4905811007095384015,
+                        8827318865486997543,
+                        17908310506854817392,
+                        552702037143322179,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10037032408400399113,
+                        11438733019781466437,
+                        124500536416422954,
+                        967879033446139254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12944731459167099624,
+                        15006716873322099114,
+                        12757783973761507812,
+                        619413576951201120,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11542823529991812784,
+                        17475878732420456171,
+                        5938018086828923805,
+                        84203717875644263,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12598745697003303191,
+                        14568524548050401897,
+                        3286278878048717281,
+                        1301972381861113533,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17565887632327511343,
+                        14836088904382262800,
+                        15551753580174264932,
+                        1074541760918448977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18030039383853021916,
+                        5221585577804228371,
+                        16086057892090232094,
+                        1322862724575292591,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16266062880514821923,
+                        17510268047116220144,
+                        13742917461246054552,
+                        642108698100048106,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13969853260563676081,
+                        6028404734261723956,
+                        8859143860608505760,
+                        524421125069247585,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        556343346344813721,
+                        13205574293791674882,
+                        16600579213798452469,
+                        300034191414008430,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12098075185259868149,
+                        9135780817306884457,
+                        11738686510863632700,
+                        621450606970832940,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10697751945978575022,
+                        326116518552859473,
+                        9210633569200199817,
+                        1063507393017173388,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7369926281433527602,
+                        16775520065328267348,
+                        18338177766721984269,
+                        187857302955790232,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7238808794986222677,
+                        1120070055199952566,
+                        13380091987991088099,
+                        583056209998473711,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6456814696097921032,
+                        8373360244391034709,
+                        10970053795876189853,
+                        511975658667380157,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2664618090153420902,
+                        11766425175138099112,
+                        9412571711773355445,
+                        1273327257262782297,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4953303362636226589,
+                        10534345733823546604,
+                        10165824518221573896,
+                        252316222488882115,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11180855549043957683,
+                        11954736560409406387,
+                        4784700732499828096,
+                        835915552629216005,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16275330849716120392,
+                        13560862988738309189,
+                        2033130232320515196,
+                        1161086294148548784,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16598013127732773606,
+                        11492605163728652045,
+                        8379453447327353005,
+                        394752925269060563,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15221151352962818841,
+                        1612329524909949539,
+                        6445244451132243348,
+                        588249495227674661,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4679162008562252856,
+                        9164007672322769393,
+                        2168556536115875329,
+                        460628563418717275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2502007021351471849,
+                        13723716209692663263,
+                        12026074108998479002,
+                        405571903589813545,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17676917303869571368,
+                        14909877768977307202,
+                        13301158287363883898,
+                        1182057917439385351,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16038875566701053443,
+                        17572852117079417849,
+                        15571258246224983132,
+                        941586624689948936,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15046696750971635790,
+                        8622222304541586802,
+                        713183057268487151,
+                        309839014619720832,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8451452145732538895,
+                        13144712921865065974,
+                        4430799566859745708,
+                        515419390363679307,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15147192062685957921,
+                        17201344660155807235,
+                        2978472463023343718,
+                        1214351865493722241,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11654464029777835336,
+                        12821825433129740413,
+                        6686041228001536093,
+                        930434935000511022,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6328345960228429770,
+                        1629421741570218485,
+                        15245553439426293144,
+                        735535286269720053,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3461271115767362924,
+                        14317183478118987536,
+                        8811234872751021609,
+                        808822043758127288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16047004739559360755,
+                        11052432743085314745,
+                        826825368410237615,
+                        1073771685011797020,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6740504881497174184,
+                        8648122155252756151,
+                        1581691302780715438,
+                        938732475737521274,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12380072585089819148,
+                        3114858179183498698,
+                        17002752923563310715,
+                        998110944829672555,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17882287412106138159,
+                        6237662973424521907,
+                        12813175401968715578,
+                        1167979822443780330,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1156416198546450284,
+                        10735337658200596686,
+                        4468125018389793486,
+                        27710448480562846,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6626917906730917844,
+                        15321855045669567992,
+                        18013546488960188716,
+                        422339503212117307,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7520054922670394354,
+                        4812944223984789867,
+                        16557749013576667842,
+                        190400830413514821,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3259600608394303754,
+                        15658135749548569852,
+                        11356925212624858360,
+                        116709404642645431,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5757070502437833424,
+                        18354833282495257525,
+                        11027190336196277806,
+                        1265049165712228714,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3492382819971271182,
+                        1942651260002656766,
+                        12516413331778185495,
+                        1328073327505887692,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10146465154609992462,
+                        13103943643589437402,
+                        17916637002057447547,
+                        192161635571230919,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11865166316538639653,
+                        17001122971276265010,
+                        1479300666786959738,
+                        834043999402247622,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15160284041060202867,
+                        12925771786226409962,
+                        16262690966708364382,
+                        995079007207449716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4780958829941319956,
+                        6092961898201469598,
+                        14299866858200972110,
+                        545684650434210851,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4763535148149204442,
+                        16416718734271623309,
+                        791019467892754335,
+                        623871623836280127,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9485131660570091696,
+                        16547427265917156344,
+                        6288431321313325914,
+                        345456569475537274,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4828151210501747142,
+                        11085814272217217914,
+                        9543157082327497519,
+                        758856889030075746,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14562800042420113911,
+                        11385087401106158145,
+                        6739145539783313870,
+                        1307131674033604730,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1964477105624974475,
+                        7125945520245146910,
+                        9457012556470642489,
+                        594380479320487225,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16810959886270913324,
+                        4219880043213422004,
+                        15654726738071995376,
+                        1187052053193220461,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15901050250637486866,
+                        14893689414917458030,
+                        18433871956705429472,
+                        794537353853253222,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10995373417997009655,
+                        10274773345880199036,
+                        12233580283788381746,
+                        567387116287076265,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13378396217193732007,
+                        17455553659985658740,
+                        10817352485932545965,
+                        1106335955957450677,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15315492910913149215,
+                        184425581035526792,
+                        7349339349294289902,
+                        1155461200631485288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15639858407502374461,
+                        12024152130345648734,
+                        11815661358029478325,
+                        259578731882976031,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8754081515721651001,
+                        5305774854215741075,
+                        7636302005672422972,
+                        2535883015909614,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9753767873068923881,
+                        13988745128229673167,
+                        15048403039722976332,
+                        1236590515622879186,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6377733865339055840,
+                        12494162903021844305,
+                        9310789367098404316,
+                        571439240754289357,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1518391417725771299,
+                        1658243785276699114,
+                        9034790369095288155,
+                        778505932740269847,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12745887615282610624,
+                        9933130337948926362,
+                        8047052570911055568,
+                        873742121833921289,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1455889740786855655,
+                        3862996763327541642,
+                        4767277600579651591,
+                        92617644790524486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14497192391966308523,
+                        18444722881102843633,
+                        13509175021213066232,
+                        794709981784724381,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2120871731963559969,
+                        15377869149780068454,
+                        4731351512877040014,
+                        1207944535283891769,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7242969051630836513,
+                        5843938726836675417,
+                        11202564231505474023,
+                        1179320279671634917,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7732206387707270200,
+                        4879103480804517514,
+                        4391019516517271644,
+                        1157219288360268113,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7227130891894584375,
+                        5992012009613802724,
+                        4032872824525398145,
+                        159547389256710716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9956517335993076255,
+                        2083999840782768035,
+                        16829649694446004541,
+                        919380711059718758,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11769525445400406642,
+                        10292633966458572384,
+                        4219108668939565373,
+                        1330556718998865339,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12565698361582919219,
+                        15832109358915900999,
+                        2320526687115304567,
+                        263409353219830442,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4139693141442601812,
+                        2593454227151330310,
+                        2613763948659406601,
+                        291635107991026088,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11843431702866717505,
+                        11750261183358340422,
+                        16719211200397612976,
+                        1128732919285084956,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5451830394777531929,
+                        14298820667777475987,
+                        17262696090162110921,
+                        1127391833432113148,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8186484674240554598,
+                        16840188109703931479,
+                        9878056912677072687,
+                        1177593491823282739,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13328407001059415396,
+                        8525699781259967106,
+                        1098775765029604426,
+                        385313606016687192,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16178034282913895708,
+                        10528763600331727191,
+                        11945165481837367971,
+                        830868801892659935,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15531120436267125484,
+                        14546484920753991544,
+                        680906221403250143,
+                        602880406132511072,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17969351450052502043,
+                        9105941400057621510,
+                        2861420526741787430,
+                        1143273678228438872,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5153820223629856110,
+                        2260476424451460771,
+                        2863963968859286265,
+                        287629152264679562,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11447436723756189158,
+                        7169301227712575586,
+                        3325982962901508619,
+                        1244211431586569147,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5838809364311245290,
+                        15926482691717117405,
+                        9957657168710619449,
+                        999472809624658136,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8873037101121332806,
+                        9428674497133151891,
+                        252395474510839189,
+                        468533810269278573,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12905761136095885048,
+                        13550905816665037619,
+                        6547964729004539867,
+                        1187349810338142579,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5100722986013021317,
+                        2460163239769525430,
+                        150880438048527310,
+                        900991085806621356,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13092722140686540946,
+                        1353265204903124499,
+                        16051811938694942200,
+                        808433965656892773,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3632931170705442387,
+                        16842337023944087094,
+                        17004608285314874741,
+                        555780473281049025,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10477955477392443360,
+                        15062255774974055788,
+                        14834311616982730174,
+                        828969519659481539,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17712976906196980613,
+                        8084668986225805545,
+                        4193377282153310609,
+                        1300667554797882923,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12393786864394381832,
+                        13025738405132180836,
+                        10032122122842209002,
+                        393438815973071530,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4446095565602684096,
+                        10705641780359905279,
+                        12566424378493842775,
+                        772851113666065703,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8155552916858800687,
+                        17092378253855630979,
+                        11931583942985467618,
+                        558607237419945274,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        298894249864654535,
+                        1253104478752160389,
+                        13016205249674804339,
+                        362297154893451857,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13341597536673606259,
+                        7826355829643275641,
+                        17407593119298398133,
+                        932336821528278468,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6690434074444555524,
+                        10967931959716503721,
+                        17043341414673633752,
+                        109708137418318071,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15469258401710121157,
+                        5114788796992927952,
+                        8276164777468168755,
+                        1133937994067686549,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17865812332187935812,
+                        8004396930382833151,
+                        2910020192347461641,
+                        1241681530082147062,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13008739520694723726,
+                        2955422964047081701,
+                        16478618878095144531,
+                        1145945408489269829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6347535141911831872,
+                        8540155833879882478,
+                        8698748426103849466,
+                        1045127703494058932,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11711435357782868892,
+                        7692955584831850506,
+                        8375613882855991187,
+                        1277533940657160275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9318072499074970128,
+                        497084133302698847,
+                        12473976993113212675,
+                        1296359484071737466,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14361629513207846909,
+                        3356530476750276735,
+                        4086983988746416497,
+                        809031269809625023,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3616787902938012563,
+                        18299097282482558868,
+                        15787541631816218036,
+                        1300835894819012117,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13529416189973366753,
+                        7111039520350941186,
+                        6475162582114385725,
+                        70863250965108192,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7220735487763136353,
+                        11670391179431207880,
+                        874525203760003046,
+                        1008911111665124397,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11471893294159719190,
+                        15576193900247838304,
+                        663558150072165582,
+                        1230662634653647109,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16997234225636771421,
+                        2213338431884350992,
+                        11237641824300502514,
+                        240967778875205722,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10569461521458080717,
+                        16770761297722503020,
+                        1240540407169405676,
+                        186286492775643310,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15900477816837767113,
+                        14116088270486545167,
+                        1145773404659939020,
+                        170815979068992769,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11358280308938473637,
+                        9088558288643310756,
+                        10702886936547484070,
+                        154612155303590134,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17557455060224596360,
+                        5116870750297839764,
+                        7194284101966739794,
+                        749040860572769287,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2535566991592840847,
+                        8548892781280842155,
+                        11120878209923351952,
+                        486756963864248203,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17599252766519337134,
+                        14247200273335004314,
+                        5779598980627967736,
+                        963804644303224281,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1473367129481641991,
+                        16178250167665177914,
+                        11778515810009063615,
+                        250964641010544248,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5417006167235040375,
+                        16007968129911097278,
+                        18413903908825135536,
+                        185989256370967734,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6337997970565415570,
+                        6393032853670406529,
+                        6041500325913932319,
+                        1026458471163521504,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2024691075755018622,
+                        2761931399032985365,
+                        8343083924779230004,
+                        353152997260244465,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        195847018426286367,
+                        10275121363740379258,
+                        12132817428350996665,
+                        421584232762467963,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3495256960669367527,
+                        17377463381865045265,
+                        18357534417669494527,
+                        695437917113949282,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3743244482084362850,
+                        17606992607169310888,
+                        8786460204153056734,
+                        344634794733920558,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        139161934422509977,
+                        4838596894324373440,
+                        12591264710562344244,
+                        1295374100030142798,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13066541640704755680,
+                        6932902088636037552,
+                        11317418245586644037,
+                        586991622683020639,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14984771036486614885,
+                        18254819850147127930,
+                        14479867574497613345,
+                        469800205517858988,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9172246492054283445,
+                        1531993086150946317,
+                        16067608968261709293,
+                        873066194892213903,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1307487829884751303,
+                        10207173912480624446,
+                        5196338614156190511,
+                        390453634901484979,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2958288600653720075,
+                        16802342676460258136,
+                        15691602682983993872,
+                        474723142786341443,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12267863871867797658,
+                        11371578961158362736,
+                        220698716794286865,
+                        999827230559070061,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16626558648935316548,
+                        16770790523077206153,
+                        3284023810399343439,
+                        267027035296153063,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1998165257387040868,
+                        11316419154288206884,
+                        17600768364236086356,
+                        1230506762094533937,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9441766999853695449,
+                        16348538631236674023,
+                        14911823363917231947,
+                        710841261445576588,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4396845055117098036,
+                        5560560716458493890,
+                        11658410626175604924,
+                        239673544802822666,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6542419503978647611,
+                        5941676302803154774,
+                        12257578496427677872,
+                        1147283401247752394,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15298207671113075890,
+                        8959071477967009301,
+                        272070181972774806,
+                        637173323388530368,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15641226696787984662,
+                        8673138054986198850,
+                        4874278419638308935,
+                        446091485329772145,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12708275563985258491,
+                        13534303933540697659,
+                        6603476792355697831,
+                        935129305285286420,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13489672840639898960,
+                        5446965077069698258,
+                        7262313300212534758,
+                        1079889835313122068,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1962105742069640391,
+                        6118759968978748162,
+                        1251929621806452816,
+                        461535191209900021,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18355899680667976273,
+                        14424971826143350946,
+                        1346149584391356272,
+                        1071703294262338239,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4802065560646081687,
+                        360348428758782758,
+                        17347228042762179509,
+                        977118739918656277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16261718374683804262,
+                        7075597276247066977,
+                        6679499599367258757,
+                        874088409097913767,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3227231011995154238,
+                        11477525816064987016,
+                        13246535036432198182,
+                        851390131853804550,
+                    ]),
+                ]),
+                Matrix::<1, 11, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6899917736591200697,
+                        11762279184183378897,
+                        4209559886985158045,
+                        408512620231034221,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8270354293369279332,
+                        10297897509981219465,
+                        12374840454495031652,
+                        1097650312229914849,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14468107085438244672,
+                        13211862027658056561,
+                        5013529887565726361,
+                        295360821610940550,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2264282882547181298,
+                        13080058881167605583,
+                        2614956447249158513,
+                        23072894669028867,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3393717897456524936,
+                        15946429610906451996,
+                        9842358603522097511,
+                        674299112083458111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2601823445955890130,
+                        7835798312523201726,
+                        11244370770304471909,
+                        1289699971452139538,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8925040251611249537,
+                        8928957298151618229,
+                        2743125668905942442,
+                        41086040851644541,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15895155096450460174,
+                        382347908530652203,
+                        7077196943217849936,
+                        488503729121038208,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8382011315485523325,
+                        9273572677908103071,
+                        14430653660295440431,
+                        878043915542441278,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7405811372105065501,
+                        16094135596986693780,
+                        13167026940977626905,
+                        1147923582818027302,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7824316981324560145,
+                        10101425226124418438,
+                        1345253857896108485,
+                        528821267909987280,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8375407017592638895,
+                        9252095150812742603,
+                        14452302444128254679,
+                        726342781765472175,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11006206701155563554,
+                        11826595293958141183,
+                        14374931592144507852,
+                        1106021540262976892,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12948612932198377710,
+                        12682300185017792826,
+                        2068245127460229803,
+                        1244145918211021829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4773172233637430752,
+                        17395167251847192285,
+                        8749051444962303232,
+                        173168730774364871,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9428956392638666971,
+                        13333165096953048843,
+                        4734532024815464964,
+                        471168813600870848,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12058785378722374304,
+                        13622800117163112387,
+                        12526509891994050183,
+                        361961664772074798,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10717147538104255062,
+                        18412664190059943373,
+                        789619171820895387,
+                        704410459096901158,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14276012135167570277,
+                        14559156650876576334,
+                        9020731490824922376,
+                        1135682357560118350,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7579827696670124534,
+                        7746079855422155655,
+                        17046277457950126011,
+                        319999049634595166,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14908522759314931767,
+                        1000387597762261745,
+                        11528786659833101948,
+                        980224581660160168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12023470382715277224,
+                        20289440716999971,
+                        15839219580816308738,
+                        325559194081966270,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14111955744058210886,
+                        12016866232890553313,
+                        10361648381524303571,
+                        196536281569348494,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6003599591658437016,
+                        10065641418691114539,
+                        8058857217798941897,
+                        1218335589033973492,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7073802284406095659,
+                        17691791232418140309,
+                        4856057157512171123,
+                        360590772334154872,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8730183696957226520,
+                        1382686712181527157,
+                        6387229989676331727,
+                        276419907098923346,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4239189605560312336,
+                        5485491154261337728,
+                        13614301786627890625,
+                        31911555757539961,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1427340493009970425,
+                        7682084503662609635,
+                        44863829938699371,
+                        1086414767898894579,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14769528778607896436,
+                        15089794731855527777,
+                        436219928544457419,
+                        71194953736224142,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7238684916346908208,
+                        3838041187132567013,
+                        17678259094715336181,
+                        1085561719734226080,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14538572153472674305,
+                        5433715369068509568,
+                        13503723501259031049,
+                        645497358393716652,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2413433053418033899,
+                        17154338295379037045,
+                        16671849848483180747,
+                        500600388451691143,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16082063858681752872,
+                        894755957683621833,
+                        1148217594481439179,
+                        485274239809138697,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8478990302494480123,
+                        8959062958338913637,
+                        16883749706692054157,
+                        388534928075983634,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8815198984777266143,
+                        13209410855722209178,
+                        5482792327113256866,
+                        1085581027218967061,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7313086216813701933,
+                        12580323768817975307,
+                        7646867319038834545,
+                        283473076009977224,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11087566557580967296,
+                        13492020260735509136,
+                        9975159216760238211,
+                        225459760132697377,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3957305827327988488,
+                        6859610968551666750,
+                        4718643645832947907,
+                        1316129795575297690,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12646264328082280159,
+                        2056428540977981623,
+                        4455754889378876526,
+                        1010377839589320443,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11023605345594047486,
+                        13559640861102913218,
+                        5489889120193741102,
+                        1144752452698275352,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7926149815586215719,
+                        16192423313684946911,
+                        9535852898488579682,
+                        600944693052995663,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4372814323312345988,
+                        3573065304627098919,
+                        8280632718616791623,
+                        64448302094580292,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6473878144129050482,
+                        1725335828272095952,
+                        1074810424458876802,
+                        1013041139032409858,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7216976489471309464,
+                        15797298562519208618,
+                        10213047718557266538,
+                        860780349100234227,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10060379658942930363,
+                        17164898934614769904,
+                        4549225653154082735,
+                        1323435037288287273,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4512822420694575570,
+                        14425133314789565954,
+                        6541973924169134182,
+                        515916951609797369,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15121470013834445233,
+                        16904616106873730869,
+                        3285383188834190525,
+                        489375830797899794,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3551084536615071004,
+                        14594366363030538475,
+                        14816099462612366056,
+                        370483939221324668,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17762174603914517067,
+                        16325090248324497751,
+                        17341653894787112050,
+                        903272439704792275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7213686558329348166,
+                        5499617316848466409,
+                        3825038013135138952,
+                        1227652899895825733,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1865140579891858264,
+                        8220320559239445598,
+                        14461910491409761623,
+                        829651864430567523,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10438593877536039246,
+                        13178280468458860339,
+                        1127882927643109933,
+                        595760313886205122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8338447582389251018,
+                        7933905724020181846,
+                        10153964724634944641,
+                        927386623095216985,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15688010962866270316,
+                        18359870657669565037,
+                        16564212285078746715,
+                        536794562466640492,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1757982239727013657,
+                        2879178469283127327,
+                        205926696490209822,
+                        1179643431245874272,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10959166084326315961,
+                        16531832191722197860,
+                        9248822818418511023,
+                        1068007127404493950,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2913178531598958073,
+                        1903369060864506277,
+                        7868039521705769659,
+                        651714267717041173,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10215732116678944994,
+                        4438468130986478279,
+                        6038042973024265414,
+                        761391641899630131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        125657124704600873,
+                        7190662063722305126,
+                        3709725136119578592,
+                        1237165237618080915,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4560547269253492212,
+                        3720824467512561299,
+                        12715727572487998103,
+                        682398741343541327,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6651790129384602061,
+                        1551425843526529564,
+                        100364525237801109,
+                        747264155432969342,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7144231148358918980,
+                        14642408281684177177,
+                        4454110046602139733,
+                        882707366924865054,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8249313390922709645,
+                        12804334693268929123,
+                        240778847309404972,
+                        1176525956957597393,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14526161407808391752,
+                        3182688479973799175,
+                        4693023137496724656,
+                        406461533232163232,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3186238208646607458,
+                        18296421548923618153,
+                        16607646955734223946,
+                        450270452751871420,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14309236192142381055,
+                        12914347524730173357,
+                        857166680633497826,
+                        514687951179582541,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12477947419119495418,
+                        12768804377766130018,
+                        7139332660323211513,
+                        956506536726339160,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8772660042747721162,
+                        3396143485349497623,
+                        5925940187891128955,
+                        677360205490143945,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8996288482841183149,
+                        11949272312844993261,
+                        11248529633201428893,
+                        1222846040826236131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9115134500189289311,
+                        3054146948187778013,
+                        15120960827326891519,
+                        325937419410612112,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7182956904860548747,
+                        17655959113744732589,
+                        5327449633340910370,
+                        869469522742408508,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12434490669482601222,
+                        556674520222757019,
+                        4911337236329365078,
+                        807040684563332243,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5493780469923674629,
+                        3086197930533943226,
+                        11102660613291087336,
+                        548042735954710051,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        848184456023526488,
+                        14831972769714165217,
+                        15662979561102667223,
+                        741249968626330778,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17730168561330214090,
+                        18338910436095102594,
+                        10570280674634098104,
+                        78480567787676046,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5707699024710654699,
+                        14000821489048986446,
+                        13002368506171447780,
+                        600663510807782823,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4937433879519319744,
+                        5534930335211778036,
+                        16985461468645693278,
+                        886184229124346740,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        27674664179355293,
+                        15765399988720161085,
+                        3072700257685200187,
+                        692042089335985108,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2783877212014411091,
+                        5658378651526746819,
+                        14234755712572703107,
+                        1265434816781446407,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17027618917883462295,
+                        10911276027872894323,
+                        7550513686280234603,
+                        740862806896596640,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16240785610089168280,
+                        511803199975002063,
+                        11301016266526370086,
+                        1118033387904756791,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6602845776605245793,
+                        794478290657192203,
+                        7622894442982502744,
+                        654754676135271350,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18098907000770366736,
+                        974782808791720184,
+                        7843044920184913057,
+                        1258039898403628789,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17001213436293745815,
+                        12848810131322837760,
+                        9319954077989046677,
+                        941755200200296275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8589662231656750820,
+                        14806598020155987462,
+                        11340135490113229517,
+                        240612398070881052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        364001790105335757,
+                        18112834115847319228,
+                        7720807089146412521,
+                        329589408775951951,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14760000729714339842,
+                        11477531447826478877,
+                        15912518756069536756,
+                        1317938401798729448,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1501393330988103125,
+                        6233628428391676325,
+                        3174282846571060825,
+                        908245643692017291,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2361388289886856531,
+                        17945417520183769839,
+                        8009985779758025809,
+                        190566939519721475,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        885555725481914457,
+                        14559911419212588490,
+                        11064772166347174619,
+                        959093163936796529,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16046208556085503196,
+                        11200259741740618728,
+                        15236296226807405584,
+                        893522510878464729,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1395042158265573989,
+                        8392343073116141253,
+                        5983779191815577195,
+                        784619439650708432,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8352222525063858940,
+                        11500645041029670507,
+                        16425851072433568198,
+                        93924740206291069,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1725006821358112189,
+                        15226176588172475203,
+                        8839669044621671167,
+                        969958460654694560,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18401065707013626866,
+                        8000366717954595914,
+                        8575062614490085371,
+                        655805629939137971,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        461605447508128688,
+                        12778377220812767608,
+                        3733212205713475223,
+                        185464464306412769,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1984593860961654189,
+                        16697292247552583216,
+                        9855530269751436796,
+                        937777069713702718,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15448300076747835925,
+                        14018440962846556949,
+                        11307183795749203517,
+                        241833950901166498,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9048532073506464058,
+                        3399653784393817923,
+                        7084552004888502071,
+                        1312391213197736729,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17974780001000129553,
+                        5422671654034604476,
+                        61191357676993206,
+                        161277959595729242,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1255047292518222815,
+                        7053270649484282188,
+                        3103717504173083711,
+                        997699776124490853,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15434421293206968444,
+                        11544272112310390778,
+                        1909263693039868338,
+                        427582052075378254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18184592774502598112,
+                        9136043834091932222,
+                        973489767373919344,
+                        1255699847372823689,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9240567185730382899,
+                        8539532327116667401,
+                        4430402518977208581,
+                        896428891782134490,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        517684082446992199,
+                        15741797441590086816,
+                        6264993896492403879,
+                        804051792328639958,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10167698805570507167,
+                        4030739549621706235,
+                        8190036744934451356,
+                        43885193140133555,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13060207827439487778,
+                        4811514960916424496,
+                        2058696113126627710,
+                        805934321049489632,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16164906069106805074,
+                        17936629959414722110,
+                        14373094698761867814,
+                        706506194605445808,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5324501627133353243,
+                        5043490909244500634,
+                        8152586524646439986,
+                        295271751692074704,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        380545956653470319,
+                        9913117604913942660,
+                        5224779915208346609,
+                        1081030623228323980,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15135923052333473036,
+                        13586522359580987433,
+                        12580226717685084494,
+                        954638251399266074,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9507875731432824650,
+                        7679363833080932407,
+                        7842092951696242641,
+                        330832148185075816,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12956461719333339452,
+                        2642661513340121630,
+                        1490406490433375486,
+                        125068264766103471,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11374810920426297039,
+                        7081628035312095759,
+                        10886917114144085705,
+                        250171370664399232,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15040859138954950316,
+                        4354267787616859615,
+                        5289104694052516387,
+                        830334920467314254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15791203495566540089,
+                        2524469109363547360,
+                        2515511609844876149,
+                        1316816575711540518,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12279189142034204857,
+                        11350007582774382311,
+                        16182196709276936811,
+                        501662662166825886,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13509873018210574342,
+                        12952054867943076298,
+                        3691060787450326360,
+                        78160111566373520,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3903469723757240678,
+                        3285872638100160507,
+                        17469612580738210230,
+                        916749722349179254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5954581414788954683,
+                        17889379851600718087,
+                        1615395839614199347,
+                        89005663451387925,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10927635425992596973,
+                        12614757238449414004,
+                        6939358016706227008,
+                        1120962608257761917,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5361697058340621833,
+                        6042983433462842315,
+                        15082655345498924385,
+                        408136674014842364,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6596906714244029089,
+                        13489480660671196680,
+                        7560286580988263827,
+                        287752016381815821,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3100216213577589608,
+                        7073374456446105009,
+                        11804051601293120997,
+                        542536879058927519,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8492736656417512700,
+                        13480543951912813002,
+                        18185908994764603103,
+                        1032790845710183008,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12863462704190348136,
+                        8483042704904749172,
+                        14921283629288037830,
+                        519366806042185866,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7501872802765592441,
+                        17504595150403042604,
+                        4304831675259171105,
+                        1087494858080690934,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1384299533851746974,
+                        16663336521027890708,
+                        4246195781334818082,
+                        247358695214084226,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7720397524531383085,
+                        3752900336451259654,
+                        17760786228874305015,
+                        585862118702887063,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12023827986274139598,
+                        5520464062012618887,
+                        8126589897419400829,
+                        62764239455367370,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12572439048032216217,
+                        12429188075818383338,
+                        8695458791235854241,
+                        986564596887984317,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11870248096464154793,
+                        4929597503147279079,
+                        9496830969404934903,
+                        818856785582644279,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7378996974852180138,
+                        12168323543959106498,
+                        9897157305592879702,
+                        1116850955569583840,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4539607482676250886,
+                        696150940561394725,
+                        14287193998318923807,
+                        477723081705782907,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2864036805893561751,
+                        10252452402469163924,
+                        7971484785492771405,
+                        45227802648488724,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        520948151248605339,
+                        12126521723051462986,
+                        10633862011704363110,
+                        615589582493671103,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6384600442753855967,
+                        16146496981099033009,
+                        16355197400218802048,
+                        751406374422299315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3936924429146827406,
+                        15309521472693668333,
+                        7657272117721299326,
+                        917674242450710856,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13177219699321851335,
+                        17430574263098806852,
+                        10799152010651819601,
+                        930181523771111093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7946369027526014201,
+                        14547477139728179206,
+                        7108384968557807713,
+                        940428391133147872,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5417363393584126590,
+                        13498413930778544276,
+                        5354915012384132293,
+                        1145721010769009149,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7165159734569699724,
+                        6249813169303176758,
+                        16244998200756459222,
+                        64788526734345776,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11734504721218067475,
+                        5529607965387279923,
+                        13738194745843755699,
+                        492640600093765967,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2495703552814318314,
+                        525800187100262632,
+                        11564788107152073953,
+                        299849969005991914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17111484974751120837,
+                        10025998017938617434,
+                        8949435131025425328,
+                        129573636200009527,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        373571931937056180,
+                        1064355745810180578,
+                        13834850138855296640,
+                        962825439665996925,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17944215120164132814,
+                        6858164950151631143,
+                        812813996327894688,
+                        687987326154579976,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9994135929659883089,
+                        17495599528225082434,
+                        13505582374637113894,
+                        131094316741906486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14973735523918487224,
+                        4470748373160502529,
+                        4958687774853392439,
+                        495762492019496776,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3040088370486034874,
+                        13052025566899199215,
+                        14933813280914315589,
+                        76666560880451271,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        469464139236652113,
+                        10482535689656372382,
+                        10411125705622219787,
+                        545843678885927745,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15265068899585242609,
+                        6036732905455786900,
+                        7606453910931582756,
+                        521219229485328834,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13531360660418520520,
+                        3518356316760737480,
+                        10473094224840953775,
+                        333558718800809276,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11328366703507687299,
+                        13955505401591021470,
+                        14321872195946454375,
+                        467282493771155214,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16477771397782085091,
+                        16269880443179575315,
+                        6009952163742705806,
+                        507575243386324262,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15584076656547407250,
+                        10555844689597871400,
+                        3255695852464415440,
+                        134038406744900008,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13137619814325529286,
+                        18432740834476030787,
+                        2931730173747592071,
+                        894470749214362212,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5374187622046473800,
+                        2978308250455640879,
+                        12118470809812537725,
+                        318103209409241281,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2508909175552233901,
+                        12214661816278222797,
+                        15867308266631564910,
+                        827945052594264543,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        976801522100313975,
+                        11871762239655955513,
+                        14381512524680277673,
+                        684066676278537103,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17410526750926450447,
+                        6025587335475550429,
+                        1274953111769615637,
+                        402182212205496762,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13866133229415548087,
+                        15208900901472093196,
+                        9701128662698932541,
+                        625547112378831168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8421315254934368356,
+                        4181434678890617335,
+                        1834344569798524396,
+                        213974248592723531,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7416167617487652782,
+                        2893960024496485966,
+                        11987821090130282319,
+                        576937453312328817,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3078278997511314931,
+                        15039778790589401568,
+                        12573558223738400149,
+                        901509930935050565,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4356803941733752554,
+                        8764747594917767152,
+                        13074483173466636547,
+                        697467253906510918,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5003204845492620537,
+                        2472182302919019241,
+                        2365489493464392972,
+                        820133949887116609,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12472933187606134285,
+                        7542035569435822800,
+                        17854195413186648066,
+                        510975331972715034,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        686372647019116852,
+                        15442629404942151741,
+                        5237603053465977046,
+                        488742275363408519,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6234407586410692284,
+                        9504927635134886932,
+                        7963368379611544369,
+                        708054914373025107,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        175802813908328187,
+                        2119801085096603555,
+                        7866101208087863970,
+                        1251710754102625696,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10703931176325009728,
+                        8597965149683889143,
+                        16632322497550489074,
+                        441781875766015839,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17871164496414668397,
+                        12004882355867333728,
+                        5443412776712187287,
+                        822488095248320832,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4833156268119124881,
+                        8968338632601520601,
+                        13674469209867558049,
+                        480818993020896466,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5850777055115212980,
+                        12753457678816980340,
+                        9963882871323860977,
+                        263568574946892463,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6103453100055004042,
+                        4249849107213701675,
+                        12044829227387644494,
+                        1163637097695544852,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17168117967123780490,
+                        11891770083177854807,
+                        3051342313997534744,
+                        915342636963637173,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10663086664839952515,
+                        16080400059731457266,
+                        8610177696260714719,
+                        1112572716150934920,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6278273884957368583,
+                        11594176101877707166,
+                        17345518442144265358,
+                        591521523896591572,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2914496827407598181,
+                        2473103804542123347,
+                        15392842837386356213,
+                        1031177046918869511,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6238850364486299782,
+                        11895603636877895590,
+                        397780821769124062,
+                        870430779868402245,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14485991268302022418,
+                        6731849646797880032,
+                        4918034710893436668,
+                        95553011410947410,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        696296819879511212,
+                        9496739237263100586,
+                        11461559110899583225,
+                        846060181302223733,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8032384596610656169,
+                        12600784545283581009,
+                        1333770429356623242,
+                        1288703949619931154,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9523695436090506105,
+                        6065637973107592130,
+                        6308792891471943415,
+                        135183823727235497,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5990723548244680534,
+                        8202289279721062142,
+                        13736396667765102311,
+                        104573717831374205,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17674656306505365448,
+                        12228432093553143147,
+                        13565858048470457403,
+                        1285591689955260017,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6248982339115641084,
+                        6453539807021841744,
+                        123898391217060837,
+                        1023274178270466141,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11646989950323059824,
+                        16643481197093873514,
+                        13629625184905407349,
+                        686222161554658701,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8679981454701670322,
+                        16289874805679202977,
+                        7196748639716090883,
+                        1035938816071510871,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7071185282256959663,
+                        1352362635571128986,
+                        10726574871690582657,
+                        513937229978941647,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12379937795924703495,
+                        12376870560723456180,
+                        12760138457659935545,
+                        578120972845296194,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8951727009367239901,
+                        15537407432949768172,
+                        11887903828393838053,
+                        1324041028310359413,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11447848732171430812,
+                        2332739187723544642,
+                        4639748706298652996,
+                        1077153107649496428,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14846601306049417454,
+                        12307398000494317144,
+                        4677711137078759877,
+                        419154130672967663,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4043654074613079515,
+                        8119147940827888976,
+                        8596868830020083382,
+                        1194715045057931324,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4388178973529158400,
+                        2011541798360553964,
+                        5195293839001169789,
+                        121416188882873181,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14201947851813543227,
+                        18110165859464661683,
+                        15266209138608230953,
+                        86163770333575082,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6463944625465687853,
+                        10282471819271401410,
+                        3577825246010758932,
+                        82895575757864099,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16841314690760149290,
+                        5398231112609757564,
+                        15174071736812603791,
+                        723317478236361058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7496958253128269660,
+                        16898033122716136545,
+                        6086984488732441836,
+                        32237910472019260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17876529977980466654,
+                        4251983668211171092,
+                        6587817805150641249,
+                        876811860881108388,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1698608084238574127,
+                        3541539659116973869,
+                        3902773191013398007,
+                        122960948055979737,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16851468952482181868,
+                        5955458970503952063,
+                        3945790788050109208,
+                        40242424151684559,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5489741873434346383,
+                        9094791394932524585,
+                        14413682953590806769,
+                        546411967380668308,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5801193606152029589,
+                        2258011868976320101,
+                        4152369625379221242,
+                        27141523595137942,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        384079778558122764,
+                        5271754835179151806,
+                        15974661263236217258,
+                        433748812292090389,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        680424126667667393,
+                        18427743117411106226,
+                        12155400356782131378,
+                        944395656101254825,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13983270003741657019,
+                        17374503889120789204,
+                        15878675784063941151,
+                        849411364685746369,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11066340475311049496,
+                        14850508638253390087,
+                        4793824427725046177,
+                        333776857176388336,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16394592630384833391,
+                        16151477644213023395,
+                        11988043792106687570,
+                        405128673472071791,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18379631484796345372,
+                        6576707897746076789,
+                        15594435480258310234,
+                        1223290871433319358,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13602781108456760859,
+                        16498203163508477740,
+                        3486133031297366801,
+                        1340794652817589165,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14921063838015428249,
+                        11655389713193557503,
+                        2513533133060985929,
+                        498740723888786493,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5849377948015343212,
+                        8818763450827100565,
+                        5948901284447277929,
+                        1217711667307420735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15761661313221190757,
+                        3970763849510903949,
+                        14204349725528350142,
+                        1079297317013438653,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14853320188730676896,
+                        16463741826033525756,
+                        9430104028873832562,
+                        121665810488029675,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1972314641329980898,
+                        2255922594834960630,
+                        1027754833191555421,
+                        580330049193895426,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7062440239078249593,
+                        10087959059415813660,
+                        5739717511221708099,
+                        857259255775504021,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3627159723069607586,
+                        5981015058347394835,
+                        16866428557954352115,
+                        1003410646315805786,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12025461047633829268,
+                        12984469271400542219,
+                        4642785500435805076,
+                        33617146579177806,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2295912755779876738,
+                        14299466565985434023,
+                        5443817532167257155,
+                        498460188704324590,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10841673141382798108,
+                        15897096161379128732,
+                        4821174290052403711,
+                        1012821847840323849,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10742697534149613831,
+                        13559704417963320125,
+                        12255722979506191550,
+                        1112046441371325935,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        759203228920311193,
+                        202287732868509085,
+                        5702708082762875617,
+                        156474638655666439,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5534778004659620275,
+                        15354080737762683602,
+                        4999596648008747459,
+                        572672810033762350,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14690609808286195358,
+                        2804553297587625310,
+                        5203842384265466410,
+                        80649105840823606,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4258504346063565818,
+                        12393006860032780040,
+                        6418342142394472268,
+                        438362942881051411,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1570090374102448813,
+                        1641788670183253667,
+                        17947930306944129783,
+                        312362642359757626,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7888585023051919196,
+                        3856606855838164224,
+                        17078630134872321200,
+                        385377609441132127,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7869609861051665682,
+                        3740621077377117647,
+                        6246469034489614200,
+                        451546951394110171,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3047845328988820299,
+                        6422211846528768704,
+                        15125937454064027267,
+                        547779549564717737,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14982168538642477007,
+                        4052860022489433263,
+                        7421864809367575370,
+                        612367517708915326,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5359714798219349535,
+                        14549051444054096695,
+                        18089482426784444575,
+                        1266064847808893069,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6339972529537466249,
+                        10760313868042758901,
+                        2249812517444425501,
+                        325236832008835570,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15592452685504509475,
+                        5647167040412558192,
+                        6008796369517784390,
+                        1326531043542770512,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10052241170526623146,
+                        2304305949665478848,
+                        2153681727949693316,
+                        331069671557236267,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10230328420417752154,
+                        5244456604979360051,
+                        9141352624289912302,
+                        898560756449172268,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4712676881906936242,
+                        6791463901703800503,
+                        11768041644273391706,
+                        1034973488034797202,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2603158816286737766,
+                        2968743018876764408,
+                        13608024132526956667,
+                        401229638620542310,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15503914466646875540,
+                        14076550626529548498,
+                        2624678263170837400,
+                        800844552920675310,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        960343829757443758,
+                        7168944583746408308,
+                        116120217262989102,
+                        106081783349150166,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10282491368645630694,
+                        9325740557998814708,
+                        16957233520672511803,
+                        587606362360551600,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9341490446385347240,
+                        12152443095809134864,
+                        3949888555082921467,
+                        938066150391858968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17568773504078449206,
+                        1264544896668738234,
+                        13216257654439768443,
+                        906934674358142795,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3864301234429423229,
+                        11034034445148419321,
+                        11601223503178960383,
+                        215120552770473757,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13204200100086444792,
+                        17086268744880086373,
+                        9130387153183484653,
+                        895076087233434859,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6506447350993308362,
+                        14484169547524551919,
+                        11647607527860731631,
+                        284714474251488137,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16218802872510429386,
+                        10456204207918373158,
+                        12230862478295424463,
+                        1066752870302186116,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1894555924202813214,
+                        4949719729084619700,
+                        9838267783394366379,
+                        1080240468290851780,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5334554268051872468,
+                        1705178397691426894,
+                        575473037503158634,
+                        1316381903327626140,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13696698132474789661,
+                        1372924250175431124,
+                        14485639791116448865,
+                        4895929577593415,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8358622582998453919,
+                        15383003421741926123,
+                        3850573612493738682,
+                        1265330125362977778,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10975354926835718562,
+                        722101618046792913,
+                        3138543046063553048,
+                        901962713363371767,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2282342422273699359,
+                        10478199017562144867,
+                        13264706556331681654,
+                        622897617487702683,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1295486969193678750,
+                        3556691464161658292,
+                        3938149105182588459,
+                        383697683574751080,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10557001501336607237,
+                        2852235841499817426,
+                        5277902013999754418,
+                        380901668693426798,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13114598969718415458,
+                        1734910593513534854,
+                        11011846429387167953,
+                        104784081810986968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5628959094189589738,
+                        18102483130579551849,
+                        2820085439076086542,
+                        79990180450469885,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9289730507658335881,
+                        8578773213345482271,
+                        459048417430052993,
+                        18756422334081935,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7110939438551506081,
+                        10403411101548695553,
+                        4214938479829313447,
+                        869437775630050788,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1712551362425663781,
+                        15676094492456131135,
+                        7478653156900676651,
+                        1065108926541619321,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5742410335382470300,
+                        16946801242477110364,
+                        7317760459334917139,
+                        801428707077693671,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17052603377952959479,
+                        16150953922086418121,
+                        13252740680556444716,
+                        108774305488179259,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6685872059045984845,
+                        3936536806381691886,
+                        525733352847524764,
+                        282756278886634951,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18264074825747227834,
+                        5464969504269223163,
+                        1947236707363977055,
+                        1033501739021177890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12224777939436529793,
+                        11360059187057475626,
+                        9199708962407711549,
+                        755579152172987350,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14656637602838925719,
+                        1543542182763851973,
+                        4958978516477687053,
+                        615908157190596918,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7389102624158414369,
+                        10671343762108947104,
+                        10407315057852430014,
+                        1272895922781685343,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6959721473975562364,
+                        2195903590795049578,
+                        16660688126253652632,
+                        882152142639433009,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10852035549004468972,
+                        2534429941734589417,
+                        11616813430080281850,
+                        1175257955614416003,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        618595401381143520,
+                        11663958323400235259,
+                        11290172426133482503,
+                        1309200119981795723,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6771421405091441972,
+                        2305861743346072118,
+                        1376301317773265655,
+                        476596379293442324,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16125547131305757135,
+                        3923648408461416343,
+                        18072867061210057113,
+                        586087383866118688,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11148051396961670597,
+                        10830130695552857515,
+                        8198873368003645529,
+                        678753012384463867,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17259123960252378974,
+                        324460567601151869,
+                        2056213838156492452,
+                        1336858269728376927,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13167529532757241903,
+                        5447009570258740603,
+                        12335727678740198501,
+                        98483086310752947,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5179221188625396148,
+                        12757787172422732418,
+                        1429635758204713504,
+                        246259851932508391,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17900036011641677471,
+                        16416701971011317922,
+                        12752414174708699264,
+                        968293566080786370,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6747066195378530518,
+                        12411382634558176244,
+                        6381806173439674101,
+                        698423132758392509,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16432529042479629995,
+                        5374181026332897499,
+                        15856757880186809078,
+                        791451785519329294,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12428398457934024770,
+                        12789492794940240052,
+                        2159586384381878451,
+                        646186354707788008,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1101867118239193135,
+                        4086522097923979035,
+                        16555899609410169345,
+                        1292896676606259940,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13650348621714694063,
+                        4686049592826432811,
+                        3555832879579688145,
+                        511948648784837082,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13634842058251327555,
+                        459723884217156833,
+                        43749844240148532,
+                        667929319209948464,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        786706878509652226,
+                        7328033863043189445,
+                        7705732655212594498,
+                        331929406622105871,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15132749940939259247,
+                        10382594772789437,
+                        2412650983585703672,
+                        753176814486988656,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18301383622588592043,
+                        7172098118668694059,
+                        9097029606334730277,
+                        198928096674649918,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5380889146033186057,
+                        5330336802697787446,
+                        4565858464207089626,
+                        375147545103057277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3502678182972687308,
+                        7067316222573979757,
+                        5656176598149301549,
+                        359478355754989783,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2097583486538032261,
+                        428740038675218127,
+                        239968764262854016,
+                        1167968560150572121,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2436851381676350598,
+                        10850631972094381824,
+                        5011657482285877416,
+                        116132899960639052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2235866614016493273,
+                        5207938089628170630,
+                        15062234456247869938,
+                        285363193228077983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6927637414632212816,
+                        9624255783003103490,
+                        18036848665385236034,
+                        333454972902090362,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6502391179618235957,
+                        5606987035887647275,
+                        2186017102515917354,
+                        1183173704899618217,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12556236825629272608,
+                        1398107678415954487,
+                        1345366223620318211,
+                        1198632677555846444,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4897207017220319348,
+                        14842393257290020942,
+                        15418445531924457600,
+                        1115774698473997874,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4539554173512412643,
+                        4381418360975151840,
+                        5324565228403900913,
+                        414578276719029273,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9620968780842258695,
+                        14995512336422844825,
+                        4162607986240669422,
+                        907106061944299168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2147532482688780759,
+                        17153219366596555986,
+                        1051526409452046605,
+                        1298236446046709059,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17314217548991168162,
+                        16009079912148030146,
+                        3229924588911980668,
+                        52852393168323707,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10061041647468920835,
+                        12694763581296675178,
+                        11158074831525429848,
+                        276442112339931073,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10602220335329815935,
+                        16720272295282231419,
+                        15770564739685007139,
+                        732827761850754322,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6872624221953951841,
+                        16270146228840466272,
+                        608066681562330527,
+                        621187201950850032,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11962041189748425443,
+                        5043917439810870854,
+                        1596931030153152470,
+                        105513210377711350,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17309207774451574385,
+                        762204912076165408,
+                        12365795444221323400,
+                        882248097951526403,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9899909629953271362,
+                        16016830441656514676,
+                        17468784352212751482,
+                        1008212458852478172,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9666788992788002573,
+                        17770465916922598761,
+                        1102149511463146459,
+                        301187651811358896,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5031911121320318793,
+                        10373319925575020125,
+                        6493734132404542559,
+                        1206297306117325888,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12887200207689945819,
+                        789859950381174740,
+                        13651937632547032393,
+                        1126155504919195968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10561457132730327282,
+                        2160601955967877548,
+                        5686363565334479055,
+                        469740283328408440,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8007717106386710989,
+                        3874651212274062687,
+                        3599141909962306417,
+                        67777343644886619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1486683279517676420,
+                        3183295238880077255,
+                        10409240232983784447,
+                        1168822354253262895,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3660447507955757903,
+                        7809394297803372382,
+                        7592966377552835324,
+                        571519569760246801,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7396991499470346747,
+                        12403261890012474429,
+                        14522859428482774423,
+                        1160552342256670612,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8181008096520142285,
+                        11287772373854117201,
+                        3850356855871652976,
+                        1176130307317183034,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4609680935483706363,
+                        3276162431516630629,
+                        10365534410637097925,
+                        1025955935706207989,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6734208289390478629,
+                        3542006873259894673,
+                        14489425249814924365,
+                        651846629711882311,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        713280991047727667,
+                        9991739257968668138,
+                        16334707262387996631,
+                        690931776287356110,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13870144304621029619,
+                        1325516506127863464,
+                        1105364672384523057,
+                        728000450918760296,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15213506276011447321,
+                        16317880560133623750,
+                        9871053126272464303,
+                        1312327974418627708,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13348675639625490714,
+                        11243137119924251375,
+                        2115500080848937543,
+                        389943850067300930,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9309563909571584257,
+                        736554680647414284,
+                        7866233306821656682,
+                        1334976237487733502,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17610463234355541868,
+                        461594573756524543,
+                        15366511429637485090,
+                        471125798904417816,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9860569524192685291,
+                        4969674326598927339,
+                        6523521173543955364,
+                        496301124891179264,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16624704200887914167,
+                        15334395801040556113,
+                        11903093569431467795,
+                        848385248556998721,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4684347645938733296,
+                        2564416541379859684,
+                        4270284922340942045,
+                        418092287988767239,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16054435346356668233,
+                        17123247183517578395,
+                        17850486077662327404,
+                        181315993067278399,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12624570725353748719,
+                        15077439714426271564,
+                        9469388897754075381,
+                        15821679235700009,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6076092196921956799,
+                        6926532280189703339,
+                        15519676848770498543,
+                        422417221996243477,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17460816803422901689,
+                        1063467252705740695,
+                        2015067718332847039,
+                        1041151856627927146,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18201328145209520202,
+                        12819188787870711688,
+                        429039082576626336,
+                        266616158950537374,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7076108143369728227,
+                        117925159155303022,
+                        4368969134743842990,
+                        780005287884558470,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14610762600152754906,
+                        5349117759748651240,
+                        5695001216106707092,
+                        506388778930585536,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11364272894127322570,
+                        6402701060858779011,
+                        13719069204609508354,
+                        451223360592593859,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11424690016421525696,
+                        559661707119021131,
+                        8886793183313401711,
+                        1250551906874323310,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5296902127399150414,
+                        8835926369741042568,
+                        8513298205414453098,
+                        408346684587227625,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6798759430291187898,
+                        3969721729747205758,
+                        8679362783788990600,
+                        275258060861647609,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4875592975954398732,
+                        6272191452655435623,
+                        11781220856209782842,
+                        926208278734728870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13738827598832893724,
+                        14850622837584515060,
+                        14253579728227196336,
+                        457913823994156795,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2505655938228636580,
+                        9074065046319233231,
+                        2890653587581066752,
+                        99300771699601650,
+                    ]),
+                ]),
+                Matrix::<11, 1, 11>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        742509929636314278,
+                        9612322476040591457,
+                        16595374833905816196,
+                        601587315619165282,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5251148479379474317,
+                        5457593109449129710,
+                        13201166658769502774,
+                        362795505842623437,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1957601278238591882,
+                        5860706009206583841,
+                        17201059893524044794,
+                        352192057676407252,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10171915915509238384,
+                        11531260081840041768,
+                        8554211880931846731,
+                        584438017167527428,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7318319927171351439,
+                        1869923574402604204,
+                        8228443283104252988,
+                        1061531495742747654,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2154683283639230790,
+                        1463618628149065236,
+                        10899720736102149818,
+                        952005743275420068,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        785212909862959053,
+                        2453955547791715999,
+                        3213618423514811465,
+                        1271064254597270456,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9045722449471133757,
+                        6736427984133360313,
+                        8326433582907924799,
+                        591775939250389467,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7633952154000588144,
+                        195125961067492815,
+                        11113607560931676653,
+                        714330120148051575,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2731233328375485184,
+                        15285696438478085505,
+                        1624645967103162750,
+                        374674655216423355,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16454500631876283702,
+                        12982591173431651147,
+                        1784966140095630883,
+                        1009509804815779741,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<39, 12, 468>::new_from_known([
+            Fq::from_montgomery_limbs([
+                16632938916876319268,
+                6278944211561501192,
+                12601405938806706802,
+                661377568436377385,
+            ]),
+            Fq::from_montgomery_limbs([
+                10774872491476595502,
+                6008456309288488161,
+                16127975749917949644,
+                96485433137271061,
+            ]),
+            Fq::from_montgomery_limbs([
+                18242884960062457044,
+                16114950486395307300,
+                1804687910289770734,
+                41138593389540529,
+            ]),
+            Fq::from_montgomery_limbs([
+                11966747793414092012,
+                2599495824123442761,
+                10426391661471627170,
+                13880890741331434,
+            ]),
+            Fq::from_montgomery_limbs([
+                1244334737076864402,
+                13508119243717545594,
+                8377895030277161361,
+                950051271819620075,
+            ]),
+            Fq::from_montgomery_limbs([
+                4917409736926194330,
+                11000514154627912145,
+                16657505476988497994,
+                606127200599468164,
+            ]),
+            Fq::from_montgomery_limbs([
+                14813599648439013932,
+                13339613532402540694,
+                15474573061813622979,
+                546292755280080794,
+            ]),
+            Fq::from_montgomery_limbs([
+                17382923883657694490,
+                390585098163740035,
+                15008138474636799511,
+                23667028793005338,
+            ]),
+            Fq::from_montgomery_limbs([
+                16839902645986267838,
+                11543168292190074415,
+                6607296450714950897,
+                88890859614968271,
+            ]),
+            Fq::from_montgomery_limbs([
+                15205494337581825070,
+                16112561752176320567,
+                931468456196686534,
+                1231294465782471298,
+            ]),
+            Fq::from_montgomery_limbs([
+                2286081498810290799,
+                9332260496541964389,
+                16525228356614204776,
+                1120271425471260989,
+            ]),
+            Fq::from_montgomery_limbs([
+                4544229891734006687,
+                13918256964831410904,
+                3556400010508168055,
+                842423103749983593,
+            ]),
+            Fq::from_montgomery_limbs([
+                2044532473593858049,
+                2001384063006573006,
+                3891443507992341150,
+                1082356936116457172,
+            ]),
+            Fq::from_montgomery_limbs([
+                17694327538658910603,
+                10442360183276977441,
+                13261268678997175872,
+                472578634775614324,
+            ]),
+            Fq::from_montgomery_limbs([
+                10948756146344922512,
+                7683181120746227906,
+                10887975850504067264,
+                382499161587988815,
+            ]),
+            Fq::from_montgomery_limbs([
+                9804639488303611810,
+                10654286368574314079,
+                6837644815046676522,
+                1272527167554470672,
+            ]),
+            Fq::from_montgomery_limbs([
+                7294928731105687432,
+                6043049974735690333,
+                4767449811193044395,
+                908811744579708982,
+            ]),
+            Fq::from_montgomery_limbs([
+                9432617829572490978,
+                17070526606061729723,
+                10347314551953159717,
+                394941453132138595,
+            ]),
+            Fq::from_montgomery_limbs([
+                8651960302050668406,
+                11261996985385302541,
+                12110050223912665445,
+                407953421620199119,
+            ]),
+            Fq::from_montgomery_limbs([
+                15245882424574603598,
+                6459707653882545744,
+                17596186895440203526,
+                366264318416324424,
+            ]),
+            Fq::from_montgomery_limbs([
+                6762205894112099118,
+                12651154075499134522,
+                6065043969165173545,
+                312510765032836035,
+            ]),
+            Fq::from_montgomery_limbs([
+                11182449767636567310,
+                2867565507955427984,
+                17431927399781692169,
+                261152343624181483,
+            ]),
+            Fq::from_montgomery_limbs([
+                11329634546541323238,
+                10057762990180139953,
+                4479052930599396378,
+                881192624382372891,
+            ]),
+            Fq::from_montgomery_limbs([
+                10523292705658055505,
+                8511791982406430639,
+                6996622901393783211,
+                1063859409356395272,
+            ]),
+            Fq::from_montgomery_limbs([
+                3507340157705241625,
+                9211557146266413144,
+                17682344797500248617,
+                843796012294789429,
+            ]),
+            Fq::from_montgomery_limbs([
+                10990488147574048418,
+                1952476703157470517,
+                5852246204409837675,
+                685856827906785300,
+            ]),
+            Fq::from_montgomery_limbs([
+                12991829192013404779,
+                14549125245067704791,
+                13024045485207006802,
+                1237319803844018975,
+            ]),
+            Fq::from_montgomery_limbs([
+                2514416321669496143,
+                698853897183065706,
+                17906539639485445928,
+                65277400335228719,
+            ]),
+            Fq::from_montgomery_limbs([
+                7255040994947942500,
+                1615703976733828905,
+                15012523234144085328,
+                473851074660310747,
+            ]),
+            Fq::from_montgomery_limbs([
+                5304404235919330526,
+                15668759905070224802,
+                14376011596246864746,
+                1107350067694478668,
+            ]),
+            Fq::from_montgomery_limbs([
+                66183234706852036,
+                8817465955334719734,
+                1105083135476743492,
+                1131606893454942741,
+            ]),
+            Fq::from_montgomery_limbs([
+                9228026120655134912,
+                3179073697588025142,
+                2133314237067696336,
+                495342647429947247,
+            ]),
+            Fq::from_montgomery_limbs([
+                1545081888928775362,
+                17108090472910430239,
+                17220143313323945285,
+                644834593164820987,
+            ]),
+            Fq::from_montgomery_limbs([
+                16858589008352661669,
+                16182733689713942251,
+                2337033299776473877,
+                1304519307088787888,
+            ]),
+            Fq::from_montgomery_limbs([
+                11525110978782997509,
+                10169839883275032216,
+                17145854050883962736,
+                1192423234933032540,
+            ]),
+            Fq::from_montgomery_limbs([
+                16927001820947803298,
+                11992717508998403742,
+                1980801809865391975,
+                1096886416804619080,
+            ]),
+            Fq::from_montgomery_limbs([
+                11874287433121915275,
+                7308025537350412474,
+                18382276862648781045,
+                1127442288654038731,
+            ]),
+            Fq::from_montgomery_limbs([
+                15200167252478420426,
+                1241562081166702344,
+                3268521225989361459,
+                374035634421454746,
+            ]),
+            Fq::from_montgomery_limbs([
+                17832208925902070320,
+                15740630745315573277,
+                8251968463766309422,
+                829183259841138537,
+            ]),
+            Fq::from_montgomery_limbs([
+                10284480741202859284,
+                10298733295486740030,
+                14389708067860178002,
+                1135021052653182803,
+            ]),
+            Fq::from_montgomery_limbs([
+                3321519450801407262,
+                15017957235312160170,
+                7406011237308573769,
+                266251624792110241,
+            ]),
+            Fq::from_montgomery_limbs([
+                4392080903532215336,
+                282369756989735071,
+                12212062393304397376,
+                1190168331469517579,
+            ]),
+            Fq::from_montgomery_limbs([
+                3752871672953265560,
+                13172255478844235253,
+                9580989314942725154,
+                1247594034579966253,
+            ]),
+            Fq::from_montgomery_limbs([
+                13983661234206946425,
+                8284184646875366312,
+                5229614962730807380,
+                442403022540221240,
+            ]),
+            Fq::from_montgomery_limbs([
+                8852609145559030300,
+                800534754665570489,
+                12265203657973950117,
+                255563197873423360,
+            ]),
+            Fq::from_montgomery_limbs([
+                15023475560818722723,
+                5615927434746197370,
+                8558908400807314483,
+                622279669225398690,
+            ]),
+            Fq::from_montgomery_limbs([
+                16756465923210571385,
+                16313783351605859432,
+                3789736591000306309,
+                589084829185755562,
+            ]),
+            Fq::from_montgomery_limbs([
+                10324912850652095798,
+                729348061665055707,
+                3856003895966154098,
+                751231172298732216,
+            ]),
+            Fq::from_montgomery_limbs([
+                1772885260873054138,
+                14013752347423199276,
+                12942795078854071907,
+                456182839390845112,
+            ]),
+            Fq::from_montgomery_limbs([
+                11723802530632797948,
+                14103373005053310152,
+                1870023180951982364,
+                1243346625007391828,
+            ]),
+            Fq::from_montgomery_limbs([
+                5708376542682742115,
+                9103044406725610556,
+                2096177391301797662,
+                995343358118095035,
+            ]),
+            Fq::from_montgomery_limbs([
+                10327713101709202421,
+                13699815181121307956,
+                3598845437557286600,
+                784476837089432956,
+            ]),
+            Fq::from_montgomery_limbs([
+                11612110953258641075,
+                16697257529297935143,
+                4063100594692199221,
+                1247540135579297856,
+            ]),
+            Fq::from_montgomery_limbs([
+                5003543284127289654,
+                3614594444063211309,
+                12765084568174851604,
+                56044986074720122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7590160709980622537,
+                2056419742384851766,
+                8097459520423486334,
+                1344053870771687013,
+            ]),
+            Fq::from_montgomery_limbs([
+                4680726976436327319,
+                11203715565505527207,
+                14526954973818375203,
+                657923818026194442,
+            ]),
+            Fq::from_montgomery_limbs([
+                3757050357539741437,
+                2395089607674179725,
+                8573403832001416453,
+                1006873666060918950,
+            ]),
+            Fq::from_montgomery_limbs([
+                5437014303596618432,
+                13841251313478491685,
+                5290298943728021371,
+                231236413137237495,
+            ]),
+            Fq::from_montgomery_limbs([
+                9599092600537839646,
+                13290058073083625870,
+                8533988395419467966,
+                1085980356219065372,
+            ]),
+            Fq::from_montgomery_limbs([
+                17506374614380930562,
+                9066981107250467187,
+                5997813255275756377,
+                988184694474569145,
+            ]),
+            Fq::from_montgomery_limbs([
+                8727709576481338081,
+                15791768565929784697,
+                3787857135881922309,
+                1175179515813613395,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6133707878606537425,
+                10585150384990359636,
+                1676057825728598663,
+                1252219671939822464,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4830415433523671687,
+                4718838953615893177,
+                16612732554469600229,
+                854851352540489618,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7492319418430518617,
+                1242555432580039645,
+                15616174950035079409,
+                1055999461169817166,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7497990446090525345,
+                13787917164749479496,
+                15169902368432135793,
+                1145776939472675737,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16736318259369259824,
+                8550096169444827544,
+                13781841379375535692,
+                633759795200114062,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10694315061170772934,
+                8183949222782337146,
+                15924118920559502322,
+                272299117036783008,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5080551003991809928,
+                9781637236042307977,
+                8323311349089212054,
+                794602170907331073,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5899095831761965003,
+                13527466864358066535,
+                1819406030794740997,
+                654651164106179185,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4795813751094134655,
+                6373098169793033280,
+                11564590184467746948,
+                1092654376040634824,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3321129498695979029,
+                13319770216347492410,
+                8220790855682994191,
+                33901813671132876,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6299535684786893013,
+                7878923531834395087,
+                5326323434666993874,
+                607784434611708574,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6407496613439994124,
+                14568063589636473827,
+                16849538423168544343,
+                629738941055937202,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5453232369909601970,
+                13502015415454219426,
+                10058930900746789487,
+                739272406683464053,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5776237747934143856,
+                5580208774667348032,
+                12943072455563101058,
+                558553687207349614,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15324761787794287058,
+                10809536762045233105,
+                8393879306579416678,
+                1059134051074825191,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13634070630918519455,
+                8769558858947843898,
+                6414175925358475252,
+                253917230343101365,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3360492336272578815,
+                16809317413011265767,
+                7666786010516827622,
+                1303919474726301387,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8547865835586346086,
+                2451326005962438620,
+                18207379280442974732,
+                1266189842890615344,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1178782613427820635,
+                1150374808002165068,
+                12001131363411326373,
+                394846366612733558,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10628363501373517154,
+                6804344003884235133,
+                2027506949704938129,
+                944459144230960834,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5360307297660718886,
+                9752621150669661232,
+                5740717890102798486,
+                385103036688421598,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7500427961978818626,
+                11891579260287044120,
+                604873454510589272,
+                1159111194558034753,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5365063577923426360,
+                10449686163387536855,
+                678794091133555306,
+                1080849553420029107,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15486856823655172934,
+                3273098276695186106,
+                1715040208475647458,
+                550150739567319355,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7065661563717979004,
+                13187102341943020208,
+                12186952487047549496,
+                573337002876527156,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10476477550397595797,
+                11695647752157856297,
+                14599692404079811090,
+                1209923485688143337,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4299515525426055706,
+                16245002559711845768,
+                12259864270660946372,
+                1018076345667011153,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12536668491303372795,
+                16918097355039338308,
+                11330162781240829837,
+                382192991626884071,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3948305650963918928,
+                17086369556877531551,
+                587244818817662535,
+                610113157020748154,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6103999429654437021,
+                3578870793882262605,
+                10475906052739781076,
+                791327344418792022,
+            ]),
+            Fq::from_montgomery_limbs([
+                16730713618121323118,
+                5454878769716689869,
+                5964671993226182778,
+                1062907112233269502,
+            ]),
+            Fq::from_montgomery_limbs([
+                6531059121737619956,
+                7401835798433222146,
+                10773551317008857887,
+                368028073403727890,
+            ]),
+            Fq::from_montgomery_limbs([
+                14618257344708996557,
+                954723661573158790,
+                871035779172331096,
+                1062874702598501096,
+            ]),
+            Fq::from_montgomery_limbs([
+                11009422755186913021,
+                11191725178734652017,
+                7055525707594095636,
+                253900205998073893,
+            ]),
+            Fq::from_montgomery_limbs([
+                7091189346721442386,
+                14772591004223658667,
+                483483410740308902,
+                760800561216851459,
+            ]),
+            Fq::from_montgomery_limbs([
+                7689459034276840844,
+                8934630457408828756,
+                17484472752952863316,
+                1021123464465977001,
+            ]),
+            Fq::from_montgomery_limbs([
+                9206472016036363509,
+                4055331965654480165,
+                7681507747431018703,
+                180029677282353231,
+            ]),
+            Fq::from_montgomery_limbs([
+                18360158537789375323,
+                5893526811952233207,
+                16796775918819721322,
+                128438802872714546,
+            ]),
+            Fq::from_montgomery_limbs([
+                6902954690958907722,
+                14566876696632142805,
+                4356274283528664731,
+                846403371447963743,
+            ]),
+            Fq::from_montgomery_limbs([
+                13085609659997848451,
+                14491537799963823657,
+                18427185896792224096,
+                1275917180821703486,
+            ]),
+            Fq::from_montgomery_limbs([
+                17210445694812923026,
+                15425747662001882664,
+                8397217914209382911,
+                1191627581154357020,
+            ]),
+            Fq::from_montgomery_limbs([
+                16322043879994456558,
+                14359737400185378962,
+                6631765366397254117,
+                858151552562083283,
+            ]),
+            Fq::from_montgomery_limbs([
+                2152078896307564490,
+                11846164723371488364,
+                2966568072710520099,
+                1088732329527010515,
+            ]),
+            Fq::from_montgomery_limbs([
+                12498725972780656961,
+                13181470547498505132,
+                11146940566320933304,
+                538535453239318990,
+            ]),
+            Fq::from_montgomery_limbs([
+                10257584390439030720,
+                9250208970149276627,
+                17523972249860038680,
+                1165629637191364374,
+            ]),
+            Fq::from_montgomery_limbs([
+                2144634500437853569,
+                4631959497359269513,
+                9414383319306409173,
+                82467256219518545,
+            ]),
+            Fq::from_montgomery_limbs([
+                15560338967437296955,
+                1221012097737312435,
+                8494401740307712204,
+                1146633301199982890,
+            ]),
+            Fq::from_montgomery_limbs([
+                12221626725364195186,
+                12748619946657051130,
+                8908696122709497282,
+                18158362142525744,
+            ]),
+            Fq::from_montgomery_limbs([
+                9798152948829689807,
+                5810807910852795608,
+                15272610421484805561,
+                1148791092989849508,
+            ]),
+            Fq::from_montgomery_limbs([
+                14724467063786624846,
+                2859986419261516914,
+                8142829858799559109,
+                734862181984424022,
+            ]),
+            Fq::from_montgomery_limbs([
+                7614748546606178871,
+                470486123924990932,
+                11508575293938048335,
+                184109488453545629,
+            ]),
+            Fq::from_montgomery_limbs([
+                11733597845853284511,
+                5772234106958148095,
+                16166145989110033314,
+                53261225799539031,
+            ]),
+            Fq::from_montgomery_limbs([
+                956238097149081026,
+                823431159877382691,
+                10735275174253236834,
+                38172862942800677,
+            ]),
+            Fq::from_montgomery_limbs([
+                12718186906561158309,
+                10137288682505304528,
+                15780705171875672695,
+                767529651519816831,
+            ]),
+            Fq::from_montgomery_limbs([
+                6072933746267241550,
+                1511163048078603439,
+                15655121243427388544,
+                1189476367518933481,
+            ]),
+            Fq::from_montgomery_limbs([
+                16395495154724531262,
+                5329880896848925849,
+                5989791295325964789,
+                720443877656015122,
+            ]),
+            Fq::from_montgomery_limbs([
+                3433954846020173680,
+                9134087985387204629,
+                11660320478541581260,
+                1300823941933698688,
+            ]),
+            Fq::from_montgomery_limbs([
+                4981920578347604751,
+                4039624890031526563,
+                8519379607816478597,
+                1077219229072752956,
+            ]),
+            Fq::from_montgomery_limbs([
+                12329144399800108201,
+                11738808353572125176,
+                15678722138041251442,
+                1214534963171610743,
+            ]),
+            Fq::from_montgomery_limbs([
+                4843055113728752032,
+                6676008456161425710,
+                9483079952706543681,
+                809430281949042211,
+            ]),
+            Fq::from_montgomery_limbs([
+                6942213891530582986,
+                15418638511141310076,
+                15756589393886642076,
+                1125742118793400985,
+            ]),
+            Fq::from_montgomery_limbs([
+                14298191943168916389,
+                10701146453248677112,
+                3887587718426979182,
+                1154211060226604636,
+            ]),
+            Fq::from_montgomery_limbs([
+                2036087001900666654,
+                561471636338262388,
+                18382244847858823809,
+                1091123180004779699,
+            ]),
+            Fq::from_montgomery_limbs([
+                11026151292555174678,
+                15955443088572875355,
+                6258768471363759509,
+                1212396892024158831,
+            ]),
+            Fq::from_montgomery_limbs([
+                13501946388662198900,
+                17326984318076315150,
+                3461702941504234606,
+                743027343842507144,
+            ]),
+            Fq::from_montgomery_limbs([
+                16745052300345855390,
+                15251360508393668557,
+                7643763334474640960,
+                1141752809090554613,
+            ]),
+            Fq::from_montgomery_limbs([
+                10181373680635555548,
+                7612775112942743747,
+                2713459361149107617,
+                1067907298607112920,
+            ]),
+            Fq::from_montgomery_limbs([
+                2781201631580984482,
+                11520827918983943849,
+                6518139444498223817,
+                1213364774466955071,
+            ]),
+            Fq::from_montgomery_limbs([
+                1357015109188739219,
+                6620761087081147884,
+                2972070783836321095,
+                685609124791640488,
+            ]),
+            Fq::from_montgomery_limbs([
+                213206301104355711,
+                6377812353351491689,
+                16038555843121022130,
+                353362104731185235,
+            ]),
+            Fq::from_montgomery_limbs([
+                2036559516380647856,
+                14452448209245873934,
+                7064447635401196270,
+                555844342705721109,
+            ]),
+            Fq::from_montgomery_limbs([
+                7811749713839145520,
+                15869776637685996837,
+                17050365857528520675,
+                399172746676141039,
+            ]),
+            Fq::from_montgomery_limbs([
+                6986657951069135044,
+                1909764209004850290,
+                394354830309903883,
+                256553378413266523,
+            ]),
+            Fq::from_montgomery_limbs([
+                16158656257466769940,
+                2207246893510610564,
+                15783874162358744589,
+                1177058455831889162,
+            ]),
+            Fq::from_montgomery_limbs([
+                3163844353643762227,
+                6670948437862610873,
+                7984261773362468280,
+                976409107888387972,
+            ]),
+            Fq::from_montgomery_limbs([
+                6983862073721005810,
+                4121703754457709446,
+                15405709522420474979,
+                953350124340249443,
+            ]),
+            Fq::from_montgomery_limbs([
+                11469144286125544215,
+                10507386404365435975,
+                11286043888537140640,
+                54705696506017703,
+            ]),
+        ]),
+    }
+}
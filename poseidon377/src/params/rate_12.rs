@@ -0,0 +1,14812 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-12 instance of Poseidon.
+pub const fn rate_12() -> PoseidonParameters<13, 12, 169, 144, 39, 13, 507, 31> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<39, 13, 507>::new_from_known([
+            Fq::from_montgomery_limbs([
+                7899589069007061071,
+                577396428671901092,
+                9661558962678495189,
+                346960094046901596,
+            ]),
+            Fq::from_montgomery_limbs([
+                8308149155987396087,
+                6937895683605094570,
+                10986006096945728097,
+                896234553296298157,
+            ]),
+            Fq::from_montgomery_limbs([
+                15272404063596757082,
+                7814139948628342614,
+                2320934006248137633,
+                892381195986839601,
+            ]),
+            Fq::from_montgomery_limbs([
+                13741810145087622900,
+                11238852996714373859,
+                15771328342155855008,
+                831992989441707761,
+            ]),
+            Fq::from_montgomery_limbs([
+                952421083603895515,
+                11041584410218406934,
+                12802753445159368535,
+                582055393791945972,
+            ]),
+            Fq::from_montgomery_limbs([
+                3007848351212284489,
+                949272350630896601,
+                14265667718824942593,
+                722198829055988353,
+            ]),
+            Fq::from_montgomery_limbs([
+                6979648075045902349,
+                2497090708498336055,
+                14679167270013869122,
+                144867084590015769,
+            ]),
+            Fq::from_montgomery_limbs([
+                5823753818196686752,
+                281703859448568238,
+                8690399989670783436,
+                102481388962980988,
+            ]),
+            Fq::from_montgomery_limbs([
+                13093250294523357021,
+                10250911395406648058,
+                10457105889586564708,
+                1300656848179837584,
+            ]),
+            Fq::from_montgomery_limbs([
+                14521058288503478411,
+                14260297630959293718,
+                7290229544653488408,
+                1188521120781040545,
+            ]),
+            Fq::from_montgomery_limbs([
+                6873249444525237205,
+                3097449335597123319,
+                9953629433727332001,
+                616643893454883107,
+            ]),
+            Fq::from_montgomery_limbs([
+                11952368789382535985,
+                1114491751318714863,
+                15045916753867394705,
+                312907562559993654,
+            ]),
+            Fq::from_montgomery_limbs([
+                12311985704566262088,
+                11865623165499947375,
+                18126487934431458473,
+                249122531215864562,
+            ]),
+            Fq::from_montgomery_limbs([
+                12007264327434192557,
+                16175089475082761934,
+                1627929504681688313,
+                880206346066771783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15433572352699832033,
+                12363038002464184229,
+                4057216038533184072,
+                1306710721718853340,
+            ]),
+            Fq::from_montgomery_limbs([
+                12155793935576216581,
+                16556466942468011902,
+                18338455610012080617,
+                667232655556413603,
+            ]),
+            Fq::from_montgomery_limbs([
+                4446605230312520221,
+                10012058186162227930,
+                11574897079571113975,
+                484637833578956236,
+            ]),
+            Fq::from_montgomery_limbs([
+                2432830609503040452,
+                5808986834106169575,
+                11596330043805130436,
+                1122634109277862447,
+            ]),
+            Fq::from_montgomery_limbs([
+                4550304615123355763,
+                10298869568626957880,
+                1058093468802816278,
+                795839015661633822,
+            ]),
+            Fq::from_montgomery_limbs([
+                18032707717928615266,
+                12161810223966456570,
+                6252179702064496028,
+                720323296500710459,
+            ]),
+            Fq::from_montgomery_limbs([
+                3557809765040744674,
+                14853435844540677078,
+                17983495423964589804,
+                391971696552378555,
+            ]),
+            Fq::from_montgomery_limbs([
+                7587533779489273825,
+                4154794124732357961,
+                15003823867310812694,
+                234810694893143202,
+            ]),
+            Fq::from_montgomery_limbs([
+                16108571030019841391,
+                2269873372961933373,
+                1505226792826509519,
+                384874539121616915,
+            ]),
+            Fq::from_montgomery_limbs([
+                16732692445281483935,
+                16256663946695208349,
+                17534157668509230987,
+                234327135117826633,
+            ]),
+            Fq::from_montgomery_limbs([
+                9580697613370001990,
+                5563778377149078368,
+                4924654992608226107,
+                691694170303194134,
+            ]),
+            Fq::from_montgomery_limbs([
+                11033415722732979369,
+                2678323396149495756,
+                8143146102096266034,
+                1220331541606279561,
+            ]),
+            Fq::from_montgomery_limbs([
+                14990156583630501941,
+                15195200654231438567,
+                2375828669419341470,
+                1028650649633476983,
+            ]),
+            Fq::from_montgomery_limbs([
+                853984491366247739,
+                6444427760297195029,
+                17436117193835116079,
+                948630576619562789,
+            ]),
+            Fq::from_montgomery_limbs([
+                4305200131265163896,
+                7621815613905571088,
+                8012719878295845466,
+                159166700033733088,
+            ]),
+            Fq::from_montgomery_limbs([
+                10036277559670040690,
+                10142025966482450403,
+                1595098361065470123,
+                1101061277218164254,
+            ]),
+            Fq::from_montgomery_limbs([
+                7369069681877314703,
+                1392535229635644382,
+                16084030607208707493,
+                436152251348990856,
+            ]),
+            Fq::from_montgomery_limbs([
+                7740793666872350959,
+                15906561111471528644,
+                572507581727332683,
+                1164441990058982101,
+            ]),
+            Fq::from_montgomery_limbs([
+                1295319021403171469,
+                4344207847465005979,
+                7888576438948362564,
+                1298535038857826865,
+            ]),
+            Fq::from_montgomery_limbs([
+                8838046643575773297,
+                11616331061968118980,
+                7682166250940627324,
+                100200291480803756,
+            ]),
+            Fq::from_montgomery_limbs([
+                15144448265090719441,
+                9403625006593706676,
+                786725459894456126,
+                836801213291348797,
+            ]),
+            Fq::from_montgomery_limbs([
+                12503555616934832730,
+                7345872578397045773,
+                12356288280733440785,
+                208308756026382815,
+            ]),
+            Fq::from_montgomery_limbs([
+                10580329960683822384,
+                12746350879013617271,
+                2761010704390601285,
+                196512715050884667,
+            ]),
+            Fq::from_montgomery_limbs([
+                10134623468553464121,
+                5910386690596272912,
+                12646954701604491207,
+                1158379505386968766,
+            ]),
+            Fq::from_montgomery_limbs([
+                1637337835997529961,
+                17601109495714221156,
+                10206162083573464715,
+                518374385095081113,
+            ]),
+            Fq::from_montgomery_limbs([
+                12575334179238769458,
+                5336038529436105804,
+                4711123437071426512,
+                1044633700574273480,
+            ]),
+            Fq::from_montgomery_limbs([
+                87077827916576594,
+                3065080445669604803,
+                8955009626207189643,
+                366057003645921234,
+            ]),
+            Fq::from_montgomery_limbs([
+                10816897855357854024,
+                3279298514593027871,
+                11417180538233072047,
+                1085470081710165145,
+            ]),
+            Fq::from_montgomery_limbs([
+                11044356612896447876,
+                17924943751760977145,
+                15111134259969931925,
+                843350692618310504,
+            ]),
+            Fq::from_montgomery_limbs([
+                11295951267499731131,
+                15998071993107643259,
+                15858761717824859403,
+                187275798996626791,
+            ]),
+            Fq::from_montgomery_limbs([
+                4929980450973491182,
+                304184989738873363,
+                2527855375641266062,
+                673425778523333009,
+            ]),
+            Fq::from_montgomery_limbs([
+                16685862168918431277,
+                6810950192186913504,
+                17369247511156460659,
+                886115933571683785,
+            ]),
+            Fq::from_montgomery_limbs([
+                3414255904374161906,
+                14073917629129657249,
+                987373786190679715,
+                584425157964605506,
+            ]),
+            Fq::from_montgomery_limbs([
+                15021379487478033777,
+                17004127049646921056,
+                9358954867685976699,
+                944728371203239890,
+            ]),
+            Fq::from_montgomery_limbs([
+                319123098909590003,
+                2637356664141265130,
+                4421420848760393198,
+                539618272152041217,
+            ]),
+            Fq::from_montgomery_limbs([
+                17796832916936056630,
+                3385435710473134775,
+                5025170591613587801,
+                1012371949098515468,
+            ]),
+            Fq::from_montgomery_limbs([
+                152545010414933160,
+                13501387011146440063,
+                8531844882401696245,
+                973157602634297979,
+            ]),
+            Fq::from_montgomery_limbs([
+                12998492373590997176,
+                11446682627092737715,
+                7326447103904667532,
+                1126877146180880708,
+            ]),
+            Fq::from_montgomery_limbs([
+                18220830645582160083,
+                2643354381175549889,
+                15425374017078246304,
+                356661347757826736,
+            ]),
+            Fq::from_montgomery_limbs([
+                11360906455439162593,
+                9617369794312355250,
+                15181387076013979741,
+                303176980466179397,
+            ]),
+            Fq::from_montgomery_limbs([
+                2128057690821908543,
+                15093550315977385253,
+                7934108784401183053,
+                324837546830938341,
+            ]),
+            Fq::from_montgomery_limbs([
+                7215627392994993103,
+                7448231465306344412,
+                6889010920388987318,
+                240485840590544591,
+            ]),
+            Fq::from_montgomery_limbs([
+                16749539877725885251,
+                11490690171598221448,
+                79155939321868840,
+                1168936456006014949,
+            ]),
+            Fq::from_montgomery_limbs([
+                14971140201572343840,
+                15435303992024089625,
+                9273115923496676942,
+                460295849335193082,
+            ]),
+            Fq::from_montgomery_limbs([
+                3049612783137698630,
+                17304692993818641561,
+                13131435060340674587,
+                835561797704034324,
+            ]),
+            Fq::from_montgomery_limbs([
+                9351649681862570379,
+                6947881814354681941,
+                16585831567274857375,
+                393009838860609170,
+            ]),
+            Fq::from_montgomery_limbs([
+                17045957266956417032,
+                6504234303380833603,
+                1680848142228675035,
+                598035896322544750,
+            ]),
+            Fq::from_montgomery_limbs([
+                12295030415316008744,
+                10880678092134281282,
+                15797310908341823974,
+                1077724811444354397,
+            ]),
+            Fq::from_montgomery_limbs([
+                17990256297530860329,
+                10710892474655723999,
+                7403335726948970976,
+                1130054852406958314,
+            ]),
+            Fq::from_montgomery_limbs([
+                13371861107040409104,
+                17913478499705985298,
+                6635893898203905840,
+                513129329220519877,
+            ]),
+            Fq::from_montgomery_limbs([
+                9232313631114894669,
+                17324739482772460108,
+                6786937453790926895,
+                436377513011371692,
+            ]),
+            Fq::from_montgomery_limbs([
+                7094369473708873568,
+                15736972024205565630,
+                572855865724667351,
+                606554874421809843,
+            ]),
+            Fq::from_montgomery_limbs([
+                14304764742221928309,
+                7950077922440291223,
+                9136973551751034370,
+                73539468923037656,
+            ]),
+            Fq::from_montgomery_limbs([
+                7089826513743154930,
+                8763554425815616165,
+                9929094042113489636,
+                115485512999898645,
+            ]),
+            Fq::from_montgomery_limbs([
+                10846778696140660935,
+                8909282444607772925,
+                17164907704960591614,
+                1147301553152610142,
+            ]),
+            Fq::from_montgomery_limbs([
+                7815439801978417630,
+                689489109842398009,
+                3567251288495284854,
+                1152120627735666477,
+            ]),
+            Fq::from_montgomery_limbs([
+                10015273972114766262,
+                16528318775543812574,
+                17932077997400369968,
+                10921324040708994,
+            ]),
+            Fq::from_montgomery_limbs([
+                15774407786726451243,
+                16396582331052506670,
+                9662348458723823969,
+                929352445302592723,
+            ]),
+            Fq::from_montgomery_limbs([
+                254978625644646421,
+                10966108925257327690,
+                5428006976914136237,
+                133498352538278213,
+            ]),
+            Fq::from_montgomery_limbs([
+                13438304094863205760,
+                3534013330083713089,
+                4916153977962312931,
+                1180168286520085744,
+            ]),
+            Fq::from_montgomery_limbs([
+                16131042468340377904,
+                2737485654896063191,
+                12384346706289280763,
+                220728422430305794,
+            ]),
+            Fq::from_montgomery_limbs([
+                10149160505707810759,
+                4147129193729703823,
+                12879391759807835246,
+                720199190051303676,
+            ]),
+            Fq::from_montgomery_limbs([
+                12795958834783651283,
+                13830898718124576876,
+                3466017710294141634,
+                440013116947392081,
+            ]),
+            Fq::from_montgomery_limbs([
+                8584120157799437053,
+                3951197350167540524,
+                7730578993482140591,
+                1196436783939611609,
+            ]),
+            Fq::from_montgomery_limbs([
+                11168165211979244838,
+                8203956677395469885,
+                5488105241961078674,
+                957537939661953544,
+            ]),
+            Fq::from_montgomery_limbs([
+                11718152913044480041,
+                11289127733829503836,
+                14058488687289570969,
+                814479104875931138,
+            ]),
+            Fq::from_montgomery_limbs([
+                12336438555333507153,
+                8243049493371109075,
+                18218785848997394018,
+                83551405112540799,
+            ]),
+            Fq::from_montgomery_limbs([
+                915310092778079422,
+                8560488004678597769,
+                4086794298857090035,
+                1135914911145934634,
+            ]),
+            Fq::from_montgomery_limbs([
+                4190046698190079512,
+                950492458049307603,
+                801403066926306849,
+                1176379444261116175,
+            ]),
+            Fq::from_montgomery_limbs([
+                8365309352802268174,
+                8865555189837540456,
+                10224038376864600206,
+                173850438588964060,
+            ]),
+            Fq::from_montgomery_limbs([
+                2149155066360270460,
+                6210432266654246895,
+                2151104346622686720,
+                414459713949198941,
+            ]),
+            Fq::from_montgomery_limbs([
+                3070716925671988071,
+                6550121703841365837,
+                10376420903970940758,
+                715043463558542465,
+            ]),
+            Fq::from_montgomery_limbs([
+                14857204752425418650,
+                5699591965831587536,
+                7055644912868819024,
+                1069477240484132149,
+            ]),
+            Fq::from_montgomery_limbs([
+                11010512523298123704,
+                3211942080667079990,
+                10088588931880158316,
+                602127661072537754,
+            ]),
+            Fq::from_montgomery_limbs([
+                11452657684213543009,
+                1147184769904750110,
+                11983686085251000296,
+                306554951671070075,
+            ]),
+            Fq::from_montgomery_limbs([
+                157748943306717701,
+                16655735765138151293,
+                14144832197961370948,
+                808805105040729718,
+            ]),
+            Fq::from_montgomery_limbs([
+                18163576563287729618,
+                5538406826570703860,
+                15691043449571886836,
+                870136298259400277,
+            ]),
+            Fq::from_montgomery_limbs([
+                15465091538275056297,
+                12214194335779768622,
+                10325084876803109811,
+                971041244011320594,
+            ]),
+            Fq::from_montgomery_limbs([
+                8397587566350072088,
+                17104433787669273920,
+                18356940893988721526,
+                480618212802833319,
+            ]),
+            Fq::from_montgomery_limbs([
+                14807515906421900459,
+                1138632631166566061,
+                2303145817343687258,
+                161359407467817341,
+            ]),
+            Fq::from_montgomery_limbs([
+                1572185532999770679,
+                16442124141027182102,
+                17706581761586398126,
+                914203436096277166,
+            ]),
+            Fq::from_montgomery_limbs([
+                13239085076205381310,
+                17968192759462966872,
+                14603340677666629560,
+                135416116537167075,
+            ]),
+            Fq::from_montgomery_limbs([
+                9040441914504657751,
+                599471593420799028,
+                17002574110524811008,
+                519628722882618580,
+            ]),
+            Fq::from_montgomery_limbs([
+                3502292364636636721,
+                8348556173682189213,
+                645793020099346702,
+                114646354749896921,
+            ]),
+            Fq::from_montgomery_limbs([
+                1797293020218577705,
+                2278201091744501037,
+                3932392419316763026,
+                55791306527063354,
+            ]),
+            Fq::from_montgomery_limbs([
+                6937172097310731561,
+                1447881920762014326,
+                2256188556541238929,
+                1137387526269679480,
+            ]),
+            Fq::from_montgomery_limbs([
+                2124638168892558564,
+                10290359877533231235,
+                2924819374397079411,
+                1184860661648943695,
+            ]),
+            Fq::from_montgomery_limbs([
+                6699753511892308876,
+                16828860652821154311,
+                2601749552796770496,
+                154845184708675522,
+            ]),
+            Fq::from_montgomery_limbs([
+                11236334372969124810,
+                17610930473593020882,
+                2082710326355797694,
+                224399572914677857,
+            ]),
+            Fq::from_montgomery_limbs([
+                10807961120937581846,
+                18140534435979728638,
+                6717922976670061661,
+                9181319969308134,
+            ]),
+            Fq::from_montgomery_limbs([
+                6974104125354039752,
+                5966040961489071958,
+                16145866221063732757,
+                579279743596283864,
+            ]),
+            Fq::from_montgomery_limbs([
+                17792186986059815767,
+                8089042702413843041,
+                4868636067368409552,
+                218449185572369782,
+            ]),
+            Fq::from_montgomery_limbs([
+                5996926740630401886,
+                6036538157690885307,
+                2366807876542248825,
+                926269205558032733,
+            ]),
+            Fq::from_montgomery_limbs([
+                14158339101342160387,
+                8043086016692962441,
+                11513644084178853542,
+                386032389267620473,
+            ]),
+            Fq::from_montgomery_limbs([
+                1496829679379739192,
+                1599876888254810618,
+                17145837521412252613,
+                1062910765605264009,
+            ]),
+            Fq::from_montgomery_limbs([
+                11701641351731922806,
+                6345655202570643639,
+                2492326603769939599,
+                631955548002011421,
+            ]),
+            Fq::from_montgomery_limbs([
+                230081721308101045,
+                16411334773147170466,
+                11560451799394589283,
+                1226374148463785031,
+            ]),
+            Fq::from_montgomery_limbs([
+                11502159307029289599,
+                14115435242720133567,
+                12658260516407158806,
+                317701402438652708,
+            ]),
+            Fq::from_montgomery_limbs([
+                3699259704503796129,
+                13324768420643619346,
+                5532149761664692374,
+                698909550870793902,
+            ]),
+            Fq::from_montgomery_limbs([
+                10197941131606938366,
+                5477938409316338942,
+                11051149986022208043,
+                1133733761295415252,
+            ]),
+            Fq::from_montgomery_limbs([
+                7251736380927625005,
+                12398947830391627448,
+                5086212643625295221,
+                1166840078940304057,
+            ]),
+            Fq::from_montgomery_limbs([
+                486729589074185521,
+                1359924083221619939,
+                16486576147489601722,
+                456610081180925708,
+            ]),
+            Fq::from_montgomery_limbs([
+                6439063604327786233,
+                6815176258225196226,
+                10422224170516733222,
+                177321757097946011,
+            ]),
+            Fq::from_montgomery_limbs([
+                12402429898807973206,
+                8112135692514503841,
+                5127468369560193395,
+                634275015772883230,
+            ]),
+            Fq::from_montgomery_limbs([
+                14956444469338989048,
+                11556720707326635866,
+                2900290394665633597,
+                151196107608664701,
+            ]),
+            Fq::from_montgomery_limbs([
+                14472288692947911807,
+                8588290841466501452,
+                13344653468233886281,
+                1119469745672535837,
+            ]),
+            Fq::from_montgomery_limbs([
+                10734492987732200535,
+                3866603946555171804,
+                9117695203749060360,
+                1204820986546190696,
+            ]),
+            Fq::from_montgomery_limbs([
+                15057024423216775545,
+                1797525239716003110,
+                10305125811854804045,
+                1225957839069398600,
+            ]),
+            Fq::from_montgomery_limbs([
+                15595877613920050353,
+                10337146009462070443,
+                9786351591282957748,
+                633684462871729677,
+            ]),
+            Fq::from_montgomery_limbs([
+                7139531014850883271,
+                7039946777993102987,
+                14084263658402688059,
+                157519802632386193,
+            ]),
+            Fq::from_montgomery_limbs([
+                11049255099051843528,
+                879199117418904748,
+                18283749113856493372,
+                209226125487326621,
+            ]),
+            Fq::from_montgomery_limbs([
+                6293448140448011894,
+                14381699248101716192,
+                1058700632091357700,
+                773529202368494094,
+            ]),
+            Fq::from_montgomery_limbs([
+                9707999571564776630,
+                4105418170511960691,
+                15868841001543846777,
+                1154475987304210029,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277189249493898552,
+                10348679288243128138,
+                9913504791835538216,
+                881292035470720519,
+            ]),
+            Fq::from_montgomery_limbs([
+                8565197629119568556,
+                17418349284310471379,
+                14611822231952728299,
+                314014464979817337,
+            ]),
+            Fq::from_montgomery_limbs([
+                3424881438942294058,
+                10310411474782938030,
+                8849180102950628716,
+                703178118623125368,
+            ]),
+            Fq::from_montgomery_limbs([
+                7958663214834039906,
+                784615237553846113,
+                10574098622566457057,
+                949987257738426226,
+            ]),
+            Fq::from_montgomery_limbs([
+                1020245030560008205,
+                1571250826271900544,
+                13576888868605088101,
+                958749320840053861,
+            ]),
+            Fq::from_montgomery_limbs([
+                15128102331069209673,
+                16620527620342958086,
+                532011214399774384,
+                1140768613112117565,
+            ]),
+            Fq::from_montgomery_limbs([
+                8717038262471461237,
+                9802545840520076482,
+                3217061172536839301,
+                482923437552795030,
+            ]),
+            Fq::from_montgomery_limbs([
+                8421218552705712034,
+                9040117197266709585,
+                12103373790287216628,
+                947764904677077271,
+            ]),
+            Fq::from_montgomery_limbs([
+                948676103999147700,
+                6491125080012113477,
+                11447842575766727717,
+                906001952332445532,
+            ]),
+            Fq::from_montgomery_limbs([
+                17087098079108753327,
+                9165038401951099046,
+                11609634648568346502,
+                349531643389305043,
+            ]),
+            Fq::from_montgomery_limbs([
+                6311054839239128574,
+                1135815743994082162,
+                15687608772060721724,
+                27283334259967889,
+            ]),
+            Fq::from_montgomery_limbs([
+                17694021840907761103,
+                1115268787524544400,
+                157347068743227175,
+                313440216235276597,
+            ]),
+            Fq::from_montgomery_limbs([
+                17683391766730196600,
+                5756266900607371710,
+                5725413633696507018,
+                1013921093487554376,
+            ]),
+            Fq::from_montgomery_limbs([
+                2279696805892204585,
+                18212530432662227644,
+                6069580069314991421,
+                744972379293102626,
+            ]),
+            Fq::from_montgomery_limbs([
+                18193020972961043182,
+                1711407244243283830,
+                6636410005632805468,
+                95785302616237918,
+            ]),
+            Fq::from_montgomery_limbs([
+                796448897552214228,
+                14106328463026011234,
+                963678702279272371,
+                918819339876873819,
+            ]),
+            Fq::from_montgomery_limbs([
+                1838536875710659943,
+                12873299406986894819,
+                16503192946284627750,
+                858925342710283169,
+            ]),
+            Fq::from_montgomery_limbs([
+                11675166341144877080,
+                1850208620837088401,
+                4434707554082498204,
+                951562303616670775,
+            ]),
+            Fq::from_montgomery_limbs([
+                2694922956465415243,
+                14872774076296364596,
+                11393344043053968608,
+                180969883889283187,
+            ]),
+            Fq::from_montgomery_limbs([
+                2666289396094554213,
+                503181117306453089,
+                8164570286066764494,
+                863991242760494495,
+            ]),
+            Fq::from_montgomery_limbs([
+                11242896372182523533,
+                13264782757003577531,
+                3813963870175946821,
+                169458571742304020,
+            ]),
+            Fq::from_montgomery_limbs([
+                4041290871403907048,
+                3974911766227791341,
+                13051014058319742710,
+                574771519405870480,
+            ]),
+            Fq::from_montgomery_limbs([
+                13741002195214211771,
+                12162457352036324286,
+                41830426699007085,
+                1084825131622709333,
+            ]),
+            Fq::from_montgomery_limbs([
+                633969215869400789,
+                8065423925648507052,
+                14546478991099628107,
+                1095167729191877634,
+            ]),
+            Fq::from_montgomery_limbs([
+                16775249153116789101,
+                13314156240450247415,
+                17368237834286371726,
+                900192356558974373,
+            ]),
+            Fq::from_montgomery_limbs([
+                389218844756564936,
+                9982332786721408004,
+                9600210931086478057,
+                1315571267058088895,
+            ]),
+            Fq::from_montgomery_limbs([
+                5082350519110452857,
+                13711682437390087090,
+                16024419450318413465,
+                1256756335603167214,
+            ]),
+            Fq::from_montgomery_limbs([
+                18083103724614260537,
+                7285092079546869444,
+                15445091608881466340,
+                1114891285406653348,
+            ]),
+            Fq::from_montgomery_limbs([
+                6859121273532058968,
+                11090303117367425726,
+                10615405614283415717,
+                795127277216499837,
+            ]),
+            Fq::from_montgomery_limbs([
+                13916995663358709509,
+                11613804939169844836,
+                12571260518135148981,
+                480213347195217834,
+            ]),
+            Fq::from_montgomery_limbs([
+                3185928283415168025,
+                2190999179001418297,
+                2807966349126208643,
+                161979490382112971,
+            ]),
+            Fq::from_montgomery_limbs([
+                14959612971328346579,
+                1149570941076910066,
+                4979504944506674189,
+                737044439000897795,
+            ]),
+            Fq::from_montgomery_limbs([
+                9355995630514926119,
+                13803341774769720176,
+                14435662097761902105,
+                698174720583541695,
+            ]),
+            Fq::from_montgomery_limbs([
+                14436462188502416953,
+                16760894040597870105,
+                14487608323733807924,
+                4723895934473752,
+            ]),
+            Fq::from_montgomery_limbs([
+                3158434270223566999,
+                4117662248228534966,
+                671113746817787081,
+                1312975781795455464,
+            ]),
+            Fq::from_montgomery_limbs([
+                17743579190047933539,
+                11683472195991093429,
+                139329929547814839,
+                1132391980977209374,
+            ]),
+            Fq::from_montgomery_limbs([
+                4914490132158123655,
+                1160564409427300491,
+                9837071192735202350,
+                1023483711040858999,
+            ]),
+            Fq::from_montgomery_limbs([
+                3101200221999184612,
+                13390646056763015652,
+                18085524841265695374,
+                272389101667999989,
+            ]),
+            Fq::from_montgomery_limbs([
+                13082097969706534497,
+                6336797701409733511,
+                3982570647005891310,
+                424334467586344104,
+            ]),
+            Fq::from_montgomery_limbs([
+                6139862291305690655,
+                8698514958386030565,
+                17692045006826633112,
+                1014866962512543266,
+            ]),
+            Fq::from_montgomery_limbs([
+                8726454470169619525,
+                1557729646070782391,
+                2834883292471679889,
+                244092266643926846,
+            ]),
+            Fq::from_montgomery_limbs([
+                17760397836730947195,
+                7401736882516737597,
+                3149991576445225729,
+                1147353450035655896,
+            ]),
+            Fq::from_montgomery_limbs([
+                10057919899103196910,
+                11947567294504417429,
+                3121378466286360110,
+                164532329135588704,
+            ]),
+            Fq::from_montgomery_limbs([
+                5834570801015742981,
+                8506866233711098700,
+                14855632953499164337,
+                424932401401370292,
+            ]),
+            Fq::from_montgomery_limbs([
+                9197586753353377402,
+                9158458922149953749,
+                8723804986825747649,
+                886303309496897330,
+            ]),
+            Fq::from_montgomery_limbs([
+                4066415786876791595,
+                7226306810332635353,
+                17938647921688435599,
+                81565497885928193,
+            ]),
+            Fq::from_montgomery_limbs([
+                5488713761769100354,
+                5078241553787356141,
+                4739089252133122180,
+                1115555011920832805,
+            ]),
+            Fq::from_montgomery_limbs([
+                8542589343613219978,
+                7108390625418296171,
+                15564741749489936220,
+                265790861787207847,
+            ]),
+            Fq::from_montgomery_limbs([
+                2311766980839051928,
+                1419365408194441728,
+                12537780794117120801,
+                897491027562836536,
+            ]),
+            Fq::from_montgomery_limbs([
+                5363814804707656366,
+                11962759443383221819,
+                897197179741276081,
+                766110778962739187,
+            ]),
+            Fq::from_montgomery_limbs([
+                5767353091723887737,
+                2180444478468294028,
+                330285230432232386,
+                1003552982061189140,
+            ]),
+            Fq::from_montgomery_limbs([
+                17413734028007752946,
+                17256079030828604815,
+                11055685941248280894,
+                399518251632124052,
+            ]),
+            Fq::from_montgomery_limbs([
+                12934777650906226009,
+                6723386695849420467,
+                2589418253942658925,
+                128286068622576769,
+            ]),
+            Fq::from_montgomery_limbs([
+                8703243003171272835,
+                8002964134529217772,
+                9356531586874425582,
+                1110528497495106911,
+            ]),
+            Fq::from_montgomery_limbs([
+                14009990204344457924,
+                4303769872291343924,
+                2053903211373862528,
+                107798763202782234,
+            ]),
+            Fq::from_montgomery_limbs([
+                453117118937740499,
+                15911845102478503141,
+                9610669667921873101,
+                77053182274180610,
+            ]),
+            Fq::from_montgomery_limbs([
+                10907316877444574552,
+                15154850326642391745,
+                16128614910980240954,
+                153854798294485175,
+            ]),
+            Fq::from_montgomery_limbs([
+                2657940063998127580,
+                9119620923694812326,
+                5225546830276417560,
+                953290936997089471,
+            ]),
+            Fq::from_montgomery_limbs([
+                17418070109891432993,
+                8331246796372326484,
+                3020043915039710926,
+                161078080994807435,
+            ]),
+            Fq::from_montgomery_limbs([
+                2685507584468006335,
+                3245995880010876808,
+                3977236821604421051,
+                206994443905238965,
+            ]),
+            Fq::from_montgomery_limbs([
+                8818476179246326111,
+                4413414172679794920,
+                15344499768310229824,
+                367807126020231033,
+            ]),
+            Fq::from_montgomery_limbs([
+                9012274993523894180,
+                9198345154857882826,
+                16420912285219438235,
+                1220540349121220026,
+            ]),
+            Fq::from_montgomery_limbs([
+                15405320206227529847,
+                4407921313607357345,
+                14575906913283910596,
+                1088079632205139142,
+            ]),
+            Fq::from_montgomery_limbs([
+                5314155692078515111,
+                3269944324042487382,
+                6630719130897713707,
+                605666063883076580,
+            ]),
+            Fq::from_montgomery_limbs([
+                9034205733917641841,
+                671540119769998999,
+                1536675441400918268,
+                848646943475730899,
+            ]),
+            Fq::from_montgomery_limbs([
+                8596586151119000420,
+                6047684135536442991,
+                17246264513139893853,
+                1302765522466832828,
+            ]),
+            Fq::from_montgomery_limbs([
+                8505880702792812574,
+                8297649343312088792,
+                11441254918577418392,
+                416631389332535371,
+            ]),
+            Fq::from_montgomery_limbs([
+                9526122801389818905,
+                7571142004465221166,
+                14768288284937262853,
+                620238100290142315,
+            ]),
+            Fq::from_montgomery_limbs([
+                12915396480826834652,
+                3878504734700059998,
+                16684062387279720178,
+                570619423608652741,
+            ]),
+            Fq::from_montgomery_limbs([
+                11228797748178598020,
+                13760900182295883503,
+                7792400533280014088,
+                848474480230122784,
+            ]),
+            Fq::from_montgomery_limbs([
+                6155706063781897727,
+                3158491097795571582,
+                11242419868070062515,
+                945615474476356300,
+            ]),
+            Fq::from_montgomery_limbs([
+                10111368604256086471,
+                12411708667551641840,
+                1235548456473724279,
+                786425419612587341,
+            ]),
+            Fq::from_montgomery_limbs([
+                3104868724981530270,
+                12325151083597351253,
+                1375551742991491835,
+                871092114487410724,
+            ]),
+            Fq::from_montgomery_limbs([
+                10073572496706901292,
+                3106368335933370352,
+                2760862162149548953,
+                984195802292604364,
+            ]),
+            Fq::from_montgomery_limbs([
+                12238716347180267633,
+                3205533445073247263,
+                11409380373470570730,
+                617522940552574767,
+            ]),
+            Fq::from_montgomery_limbs([
+                4816781535180411069,
+                16812342579993755407,
+                18094889021947443843,
+                27885238525748704,
+            ]),
+            Fq::from_montgomery_limbs([
+                14981019947488283349,
+                7667642515682707762,
+                13678048209894703352,
+                616080957899067801,
+            ]),
+            Fq::from_montgomery_limbs([
+                2218304794621108410,
+                14313439075980005070,
+                791206513008700536,
+                150169547178626450,
+            ]),
+            Fq::from_montgomery_limbs([
+                2118533731038420713,
+                17457988463204675952,
+                17704487406382211449,
+                62492697955677367,
+            ]),
+            Fq::from_montgomery_limbs([
+                3900691867455658036,
+                12435862521251121042,
+                4531624159906158450,
+                872538153262446782,
+            ]),
+            Fq::from_montgomery_limbs([
+                12652430212474634043,
+                5594880612822938422,
+                10103732353087063448,
+                339884369035837960,
+            ]),
+            Fq::from_montgomery_limbs([
+                5963310742115912669,
+                9094510793020487406,
+                14225832160658124014,
+                906154703951265387,
+            ]),
+            Fq::from_montgomery_limbs([
+                5521882394963436174,
+                5459541043537924259,
+                2950020875371538170,
+                435406930507838176,
+            ]),
+            Fq::from_montgomery_limbs([
+                1907605307960376232,
+                14406771370937939843,
+                7136046778737650865,
+                1233741694374194821,
+            ]),
+            Fq::from_montgomery_limbs([
+                4984402690148049936,
+                16102154885960022967,
+                6604374591542342674,
+                640898010079718531,
+            ]),
+            Fq::from_montgomery_limbs([
+                5614624323946459529,
+                14318864173067585682,
+                8798686789828608899,
+                144842719498408788,
+            ]),
+            Fq::from_montgomery_limbs([
+                4478576247331180337,
+                7782984879131906315,
+                7614010900439984218,
+                712087827300528707,
+            ]),
+            Fq::from_montgomery_limbs([
+                7104790143898231864,
+                13087983048132540290,
+                13798942253728194061,
+                856764153366611515,
+            ]),
+            Fq::from_montgomery_limbs([
+                6643538326220726252,
+                6722132806612214881,
+                16673027198790124047,
+                909422925859394505,
+            ]),
+            Fq::from_montgomery_limbs([
+                5742783791065774990,
+                12858361934666167199,
+                18178371322432194566,
+                57802469599748360,
+            ]),
+            Fq::from_montgomery_limbs([
+                9162339962920641918,
+                5640721475504131937,
+                5078085675141220586,
+                358274167944073443,
+            ]),
+            Fq::from_montgomery_limbs([
+                13456833538482471902,
+                17685798905255452271,
+                11901456082140820400,
+                1090180218736939173,
+            ]),
+            Fq::from_montgomery_limbs([
+                10014696351638642293,
+                4911285877360338586,
+                7288566360945238694,
+                658099041790655120,
+            ]),
+            Fq::from_montgomery_limbs([
+                1470170403757779489,
+                9926965483014000254,
+                8450152291505813033,
+                1182626941709188889,
+            ]),
+            Fq::from_montgomery_limbs([
+                16220804058369364179,
+                5303166803065969781,
+                14538909590711359108,
+                373733061029917278,
+            ]),
+            Fq::from_montgomery_limbs([
+                12556757306290748219,
+                83802909237061610,
+                2104779772968289258,
+                637448598500986024,
+            ]),
+            Fq::from_montgomery_limbs([
+                11158138134880757324,
+                2773764785236980336,
+                1502492936893311770,
+                1104570844125898024,
+            ]),
+            Fq::from_montgomery_limbs([
+                16483526371471827831,
+                17236530541229264600,
+                2849392606152351625,
+                1308289224941529377,
+            ]),
+            Fq::from_montgomery_limbs([
+                6108851108892711988,
+                3032453981155606733,
+                5289120579799933601,
+                663537948279623369,
+            ]),
+            Fq::from_montgomery_limbs([
+                10737245921704574853,
+                4738326587025683217,
+                5370808290674252239,
+                676882198592606971,
+            ]),
+            Fq::from_montgomery_limbs([
+                9066703483486254846,
+                9008351055194588745,
+                10343047739760516991,
+                437524163872291864,
+            ]),
+            Fq::from_montgomery_limbs([
+                10766340994092717278,
+                8601660508925677749,
+                12916162156766081099,
+                894543365958915317,
+            ]),
+            Fq::from_montgomery_limbs([
+                1850334833706289999,
+                4534439372116552661,
+                8242403866238821343,
+                566165667792183369,
+            ]),
+            Fq::from_montgomery_limbs([
+                18264933804337577140,
+                17881011154990975539,
+                6461348028649871799,
+                852804687361985778,
+            ]),
+            Fq::from_montgomery_limbs([
+                5266757559272837408,
+                6943604598079116295,
+                14319729626048678950,
+                267002626921751774,
+            ]),
+            Fq::from_montgomery_limbs([
+                7255389461251064410,
+                13033918671155736225,
+                1331301896388872582,
+                1333027384598071509,
+            ]),
+            Fq::from_montgomery_limbs([
+                11177789666021798217,
+                11753966103788396905,
+                10545102550668766924,
+                587384605953851508,
+            ]),
+            Fq::from_montgomery_limbs([
+                6304383360685816256,
+                1413840705002095518,
+                12127340292382130302,
+                1343359819502892855,
+            ]),
+            Fq::from_montgomery_limbs([
+                6107068751977295477,
+                17894090250596008811,
+                13994038624089688141,
+                233885886358963285,
+            ]),
+            Fq::from_montgomery_limbs([
+                14567121309761031205,
+                4902338264045890284,
+                2689676158933604454,
+                624670057878511902,
+            ]),
+            Fq::from_montgomery_limbs([
+                5315396089176624457,
+                16145183142435695202,
+                2653833396529100194,
+                1029447476434845955,
+            ]),
+            Fq::from_montgomery_limbs([
+                7225675486532381022,
+                7154199273740052442,
+                8368671732089114169,
+                317839492481412019,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878069961432521410,
+                1914914753331898520,
+                469745125327466888,
+                1270637882837835280,
+            ]),
+            Fq::from_montgomery_limbs([
+                17257545358011099253,
+                17342398645821398931,
+                3036163338587456533,
+                1121113086772098208,
+            ]),
+            Fq::from_montgomery_limbs([
+                138919643372371045,
+                17797757157457131529,
+                5117368927894028912,
+                265675521311169026,
+            ]),
+            Fq::from_montgomery_limbs([
+                4222992981606725728,
+                12453756677397814179,
+                2159247547728858544,
+                533749265953536305,
+            ]),
+            Fq::from_montgomery_limbs([
+                13395849154219413223,
+                8716207132500148387,
+                12677355493551813616,
+                483230153963458763,
+            ]),
+            Fq::from_montgomery_limbs([
+                4484027647666367487,
+                10724937699442601154,
+                12284704554682633948,
+                459209581083657234,
+            ]),
+            Fq::from_montgomery_limbs([
+                7046003413660012434,
+                1919842982608869286,
+                2651488070100144586,
+                103963464913485918,
+            ]),
+            Fq::from_montgomery_limbs([
+                2153489147780735534,
+                3613619998536497226,
+                7430403426731984249,
+                573247221935667122,
+            ]),
+            Fq::from_montgomery_limbs([
+                14181816291297076278,
+                15358479136062708458,
+                5950734488011542549,
+                1043331816668838556,
+            ]),
+            Fq::from_montgomery_limbs([
+                12213436458798210997,
+                6987138162875647422,
+                4619050058767903698,
+                1231619976315939208,
+            ]),
+            Fq::from_montgomery_limbs([
+                10424240802865885372,
+                3994049829379741949,
+                14857446184445402475,
+                291571400327825989,
+            ]),
+            Fq::from_montgomery_limbs([
+                13924143098252332194,
+                12811458332427688484,
+                17275531342559682186,
+                526289749352024040,
+            ]),
+            Fq::from_montgomery_limbs([
+                13881779738363184448,
+                2298408364167900871,
+                12134399921293376868,
+                184250334766702620,
+            ]),
+            Fq::from_montgomery_limbs([
+                31658473000087843,
+                1237659354818888512,
+                6046923193462066473,
+                363487242999112290,
+            ]),
+            Fq::from_montgomery_limbs([
+                650841286834419895,
+                1872198296622530436,
+                18433970927809022901,
+                61245841662618129,
+            ]),
+            Fq::from_montgomery_limbs([
+                14957952963185236497,
+                1955109762170844009,
+                1379474919071642025,
+                781821055707133883,
+            ]),
+            Fq::from_montgomery_limbs([
+                1169426383157682431,
+                8812026439259711690,
+                7707826765887115067,
+                763237547062041931,
+            ]),
+            Fq::from_montgomery_limbs([
+                12624739295913524810,
+                9334810954096273600,
+                17595713456935146049,
+                337771378809317671,
+            ]),
+            Fq::from_montgomery_limbs([
+                9501308958364871446,
+                11116191266876632971,
+                18132066898925126419,
+                508129793996001556,
+            ]),
+            Fq::from_montgomery_limbs([
+                12406676942109796950,
+                8572188081567084503,
+                16443734503825764783,
+                1253224421974027561,
+            ]),
+            Fq::from_montgomery_limbs([
+                11770670927098841374,
+                16533718516873343509,
+                2050546791548640591,
+                69261102434138165,
+            ]),
+            Fq::from_montgomery_limbs([
+                2322340504079123735,
+                17105054335536569366,
+                4310653321659217291,
+                1339220822501720322,
+            ]),
+            Fq::from_montgomery_limbs([
+                5119006644228814123,
+                13444181864660631123,
+                13519997950749922465,
+                884536808405418035,
+            ]),
+            Fq::from_montgomery_limbs([
+                6736579382137163776,
+                5303194207101155691,
+                10490700336460072472,
+                469058707891726840,
+            ]),
+            Fq::from_montgomery_limbs([
+                10965215154141775317,
+                6651395131962429148,
+                5968784846265253847,
+                120158401586476407,
+            ]),
+            Fq::from_montgomery_limbs([
+                10291519453660770846,
+                9883465634407404262,
+                7979162797043983814,
+                311226317951060817,
+            ]),
+            Fq::from_montgomery_limbs([
+                13567485751581655131,
+                16707731092946086822,
+                8997774589575783373,
+                943042858846068064,
+            ]),
+            Fq::from_montgomery_limbs([
+                9031288604756062975,
+                18333895863400388677,
+                3466196250012033207,
+                1238589283638443269,
+            ]),
+            Fq::from_montgomery_limbs([
+                16508295584366462031,
+                454476841000078681,
+                4552431276794968471,
+                914489455485277261,
+            ]),
+            Fq::from_montgomery_limbs([
+                4188031064452960202,
+                14746835442177793628,
+                2319967575728954147,
+                925413704092475853,
+            ]),
+            Fq::from_montgomery_limbs([
+                15538572838938852835,
+                11691115998231238881,
+                18331172670660937870,
+                420973056135570736,
+            ]),
+            Fq::from_montgomery_limbs([
+                8902000216064861283,
+                12350784826387479927,
+                3934278935618297190,
+                226222375372093330,
+            ]),
+            Fq::from_montgomery_limbs([
+                2863563524114954814,
+                14384944691220327379,
+                8514999342636956654,
+                866546922555174930,
+            ]),
+            Fq::from_montgomery_limbs([
+                5965133246294377758,
+                14158856764579345121,
+                7393894669168988184,
+                726744962940027044,
+            ]),
+            Fq::from_montgomery_limbs([
+                420133742909095720,
+                7363238205839699162,
+                5988304072453543491,
+                515341574190262152,
+            ]),
+            Fq::from_montgomery_limbs([
+                16990284168250432433,
+                12113159154086267532,
+                1726350673588146896,
+                1037860742415488676,
+            ]),
+            Fq::from_montgomery_limbs([
+                1019409380027526953,
+                16266445256410191,
+                14668175150866027406,
+                763472821440703550,
+            ]),
+            Fq::from_montgomery_limbs([
+                15535433010314793084,
+                13532904137482570566,
+                1445270022607498070,
+                1145034094485559454,
+            ]),
+            Fq::from_montgomery_limbs([
+                891194993980974331,
+                13795156073012353696,
+                14174916281544983012,
+                320662264392043928,
+            ]),
+            Fq::from_montgomery_limbs([
+                17545912377320567793,
+                17675425196209645885,
+                5909001837487913501,
+                1232151251899254629,
+            ]),
+            Fq::from_montgomery_limbs([
+                8722624505372921470,
+                5792875481541965916,
+                13742866846342463307,
+                721863819760140426,
+            ]),
+            Fq::from_montgomery_limbs([
+                13429832184560114310,
+                12087488729607314021,
+                394108485814162200,
+                1154490298232357804,
+            ]),
+            Fq::from_montgomery_limbs([
+                14476245845587284277,
+                3683784704203476237,
+                17386974278472320058,
+                794935671122345939,
+            ]),
+            Fq::from_montgomery_limbs([
+                10436235951031551660,
+                6932845169649529813,
+                2587197460674177238,
+                79603482644553825,
+            ]),
+            Fq::from_montgomery_limbs([
+                12243662306990561961,
+                11725258311207561954,
+                14644425151946539304,
+                409985658502593030,
+            ]),
+            Fq::from_montgomery_limbs([
+                3706167046484499080,
+                16127026533477743065,
+                2145031546769251843,
+                979809496403306794,
+            ]),
+            Fq::from_montgomery_limbs([
+                4914916948305241524,
+                12686560547271268956,
+                2172267364235538296,
+                206445292543064158,
+            ]),
+            Fq::from_montgomery_limbs([
+                8441755680577815633,
+                16233019315151610468,
+                16154103423376506281,
+                158486339208550014,
+            ]),
+            Fq::from_montgomery_limbs([
+                13245261930828103915,
+                12670106053813011449,
+                15402221718252267039,
+                430864797272494936,
+            ]),
+            Fq::from_montgomery_limbs([
+                4367179171170934626,
+                3545212995914402708,
+                12991366250321107224,
+                423445230531116544,
+            ]),
+            Fq::from_montgomery_limbs([
+                3720878770993448262,
+                12692269728335729515,
+                6268469708145041188,
+                1131392546795785991,
+            ]),
+            Fq::from_montgomery_limbs([
+                12274506521958951339,
+                1668935475568730315,
+                17807007979382497059,
+                1126976735466534666,
+            ]),
+            Fq::from_montgomery_limbs([
+                12104624769416998135,
+                8157333006828847062,
+                7267113785959558930,
+                37320130988365155,
+            ]),
+            Fq::from_montgomery_limbs([
+                4821622064798987277,
+                10586229528722064215,
+                14182099470725486123,
+                632952118080789421,
+            ]),
+            Fq::from_montgomery_limbs([
+                13979764206645375195,
+                7033185442904366952,
+                1400828102485670426,
+                574813131175972805,
+            ]),
+            Fq::from_montgomery_limbs([
+                3230308227121346150,
+                2816552975969151753,
+                4993081824031161518,
+                1148288448267257338,
+            ]),
+            Fq::from_montgomery_limbs([
+                7115427845546399795,
+                2923155082421063856,
+                561061393466619102,
+                1311988687869355166,
+            ]),
+            Fq::from_montgomery_limbs([
+                16411831814567890343,
+                13961096709951269833,
+                12916750040433361794,
+                126933722801498413,
+            ]),
+            Fq::from_montgomery_limbs([
+                1004578876303761342,
+                5585618256492870069,
+                5309927658918486626,
+                558689889944914236,
+            ]),
+            Fq::from_montgomery_limbs([
+                2455752340524422460,
+                8461695512195763429,
+                17443558926695489579,
+                1168150996213905732,
+            ]),
+            Fq::from_montgomery_limbs([
+                11250410733876529864,
+                6537816571172047636,
+                4506233846997642800,
+                1076885844557610829,
+            ]),
+            Fq::from_montgomery_limbs([
+                15253168823416091769,
+                9698781875164688249,
+                8646658359019757872,
+                813518811238110286,
+            ]),
+            Fq::from_montgomery_limbs([
+                8576918447847614800,
+                6922033450752230734,
+                1242631780281582985,
+                563593006439921279,
+            ]),
+            Fq::from_montgomery_limbs([
+                17436043719486305208,
+                10386727594740771501,
+                7498259869098708291,
+                392114097289444522,
+            ]),
+            Fq::from_montgomery_limbs([
+                9508438304046981623,
+                12596048019124704475,
+                9021023765918270200,
+                178969090633672567,
+            ]),
+            Fq::from_montgomery_limbs([
+                8525751666935878305,
+                6955699928843576223,
+                16481490362703546432,
+                1105949870553455839,
+            ]),
+            Fq::from_montgomery_limbs([
+                1369848236716049021,
+                9809051520118805193,
+                16057267950560568549,
+                258956209708408125,
+            ]),
+            Fq::from_montgomery_limbs([
+                17137583037476415938,
+                3148002005721804302,
+                9551928832497440902,
+                733790204081014173,
+            ]),
+            Fq::from_montgomery_limbs([
+                11448645554735746636,
+                15660012340099992093,
+                14663056869074893275,
+                293654764054875479,
+            ]),
+            Fq::from_montgomery_limbs([
+                7535599309680867352,
+                9863317028754905057,
+                12965078007051769276,
+                574272283298013032,
+            ]),
+            Fq::from_montgomery_limbs([
+                2104852516602768173,
+                271945093213431920,
+                14634982826424361334,
+                700847149023699773,
+            ]),
+            Fq::from_montgomery_limbs([
+                6766511982855085750,
+                4389729251288098541,
+                13775900476181335505,
+                154911600411337235,
+            ]),
+            Fq::from_montgomery_limbs([
+                8947965143504104554,
+                948194452665760303,
+                5502883881651824921,
+                1041995732686673820,
+            ]),
+            Fq::from_montgomery_limbs([
+                15803178800001382387,
+                1166830233661161598,
+                16849328018551604837,
+                948361853938891376,
+            ]),
+            Fq::from_montgomery_limbs([
+                735836157231422115,
+                16477306917815825503,
+                10413119839567717336,
+                614567765427692029,
+            ]),
+            Fq::from_montgomery_limbs([
+                1811862788646547767,
+                17884406321605900008,
+                8199244640833704844,
+                218620988237724664,
+            ]),
+            Fq::from_montgomery_limbs([
+                13850715084181454144,
+                5919408702340096676,
+                14579122548108743709,
+                1306944439483854510,
+            ]),
+            Fq::from_montgomery_limbs([
+                565369579171293233,
+                17050143359294009629,
+                7946202942250081600,
+                402411946641855354,
+            ]),
+            Fq::from_montgomery_limbs([
+                9885531142306434313,
+                10759186089863150170,
+                231664170483880410,
+                813276147086472366,
+            ]),
+            Fq::from_montgomery_limbs([
+                6830812400978357944,
+                1988623902958440230,
+                13419805107101193876,
+                1344163854320506248,
+            ]),
+            Fq::from_montgomery_limbs([
+                3750580191407063694,
+                8109603139166340988,
+                2840358945426985408,
+                528540045168076057,
+            ]),
+            Fq::from_montgomery_limbs([
+                8583789005288390116,
+                8383831840806352566,
+                3158134007752256265,
+                860688899462863126,
+            ]),
+            Fq::from_montgomery_limbs([
+                7084216994492266396,
+                3032539284087124761,
+                2741220414352631478,
+                612817103416906200,
+            ]),
+            Fq::from_montgomery_limbs([
+                2956666162438536367,
+                11567632757717728448,
+                11344009275273640309,
+                254297092834185877,
+            ]),
+            Fq::from_montgomery_limbs([
+                675979300730987303,
+                5588827871557160944,
+                16955541094717056027,
+                153424850460699261,
+            ]),
+            Fq::from_montgomery_limbs([
+                16311201432960758653,
+                14010965997781457236,
+                15676979527374242046,
+                1053915561551576231,
+            ]),
+            Fq::from_montgomery_limbs([
+                8245194352369981076,
+                13214801089146387806,
+                11241796176942632501,
+                432702085198267750,
+            ]),
+            Fq::from_montgomery_limbs([
+                540643464169932474,
+                8236899719816823259,
+                8855028060542867527,
+                888372422092236682,
+            ]),
+            Fq::from_montgomery_limbs([
+                14791638999151993990,
+                14574346536368981554,
+                9633071121145472518,
+                157515530094516005,
+            ]),
+            Fq::from_montgomery_limbs([
+                16306999189007712838,
+                5555812789848291377,
+                11309528332424682331,
+                1339984696085668048,
+            ]),
+            Fq::from_montgomery_limbs([
+                13867200468924928225,
+                16161163033409858692,
+                15764947583726829360,
+                267624713265395850,
+            ]),
+            Fq::from_montgomery_limbs([
+                3056713811195746042,
+                5445027505794928261,
+                10995825755443168298,
+                1138479564057871074,
+            ]),
+            Fq::from_montgomery_limbs([
+                6746964299441382846,
+                2443907410248330032,
+                16031230239995530009,
+                788942429025530542,
+            ]),
+            Fq::from_montgomery_limbs([
+                12240239866670687943,
+                76489370812194222,
+                1228561315212361831,
+                444040418268370558,
+            ]),
+            Fq::from_montgomery_limbs([
+                11617500078675342260,
+                4576561432916731411,
+                5991337609404568516,
+                1125053894707288262,
+            ]),
+            Fq::from_montgomery_limbs([
+                995552291561675265,
+                16215684995262272787,
+                2139747902964217928,
+                887616329568988838,
+            ]),
+            Fq::from_montgomery_limbs([
+                4260425153110847223,
+                17117467253923753935,
+                1693961666805178543,
+                1014355874557794435,
+            ]),
+            Fq::from_montgomery_limbs([
+                926186306058228825,
+                2327804134358060607,
+                3187938489271155225,
+                1063212738296916948,
+            ]),
+            Fq::from_montgomery_limbs([
+                390536324853539832,
+                12736643688369923596,
+                1067734095300403875,
+                1315161801763824644,
+            ]),
+            Fq::from_montgomery_limbs([
+                14488741139191019070,
+                4885297743522979697,
+                10434930888954082804,
+                1018516295473802563,
+            ]),
+            Fq::from_montgomery_limbs([
+                17596236592450732657,
+                9250734853919853148,
+                11646160495268479771,
+                1206476934866451552,
+            ]),
+            Fq::from_montgomery_limbs([
+                4389207566530203503,
+                7033068665866734243,
+                10284467612641448594,
+                1031454605503563258,
+            ]),
+            Fq::from_montgomery_limbs([
+                2831479503282084927,
+                4121617545639278047,
+                15198906335400881556,
+                1018178973543019656,
+            ]),
+            Fq::from_montgomery_limbs([
+                1278488774015814167,
+                13532339484152429432,
+                12425589772471541926,
+                1088915954131521703,
+            ]),
+            Fq::from_montgomery_limbs([
+                17590937434210471762,
+                17921033871267947578,
+                10066318465606231539,
+                1011557070506803059,
+            ]),
+            Fq::from_montgomery_limbs([
+                12168615598624496806,
+                13018328388135808754,
+                4649766078297017352,
+                791501377002930634,
+            ]),
+            Fq::from_montgomery_limbs([
+                4515867348715048676,
+                14677413546796323769,
+                6697548223603124661,
+                1316570641145908904,
+            ]),
+            Fq::from_montgomery_limbs([
+                11377562268941967239,
+                15319832970175245499,
+                9211151718380699503,
+                152040805539626769,
+            ]),
+            Fq::from_montgomery_limbs([
+                5881755746256895634,
+                11731086523180738322,
+                4755299556043239819,
+                1044866261660141398,
+            ]),
+            Fq::from_montgomery_limbs([
+                10314846067443817162,
+                18075912011687646587,
+                14686522210110753607,
+                408368714390084154,
+            ]),
+            Fq::from_montgomery_limbs([
+                3214224029688226430,
+                15257147092489164150,
+                456129720682159266,
+                930288414899672676,
+            ]),
+            Fq::from_montgomery_limbs([
+                18080669173643932994,
+                10122614878202578234,
+                14352181979902563080,
+                627274244891583237,
+            ]),
+            Fq::from_montgomery_limbs([
+                17380236856949425379,
+                7305019162959441663,
+                10370965137326505851,
+                754993985686621631,
+            ]),
+            Fq::from_montgomery_limbs([
+                9704165708434094808,
+                14319830300506416037,
+                6323574476124350047,
+                1103463051021623359,
+            ]),
+            Fq::from_montgomery_limbs([
+                8921456611745908222,
+                12222391998018044468,
+                6116763829370951622,
+                1084534666315531789,
+            ]),
+            Fq::from_montgomery_limbs([
+                10092250797562000028,
+                4915782847968810460,
+                12908991059326049098,
+                1308439087960654191,
+            ]),
+            Fq::from_montgomery_limbs([
+                12261078268142972587,
+                9488723020761790679,
+                10255001945270875680,
+                737861135454335960,
+            ]),
+            Fq::from_montgomery_limbs([
+                8846880380340439547,
+                13427088559257608069,
+                54861225410639786,
+                96926877352109757,
+            ]),
+            Fq::from_montgomery_limbs([
+                317442334802379553,
+                5463071384854448569,
+                6118763688550830813,
+                844207783394274825,
+            ]),
+            Fq::from_montgomery_limbs([
+                9361620165180442857,
+                15736505867271161815,
+                7195323728776470819,
+                278420636308136710,
+            ]),
+            Fq::from_montgomery_limbs([
+                8571822674285772248,
+                5641380184220694251,
+                13000033453006716588,
+                1285674855467424086,
+            ]),
+            Fq::from_montgomery_limbs([
+                14028113232694740701,
+                12107495793549364342,
+                13894545128406600203,
+                1048372026654156287,
+            ]),
+            Fq::from_montgomery_limbs([
+                11151178035827813709,
+                11807837839410605447,
+                15045047404957005939,
+                613803208845255931,
+            ]),
+            Fq::from_montgomery_limbs([
+                14137692645999980249,
+                16263771086332524739,
+                12366436854243622172,
+                322427165216448102,
+            ]),
+            Fq::from_montgomery_limbs([
+                14510213739341090634,
+                10731185127284783629,
+                8028977267464624736,
+                1326612006907268315,
+            ]),
+            Fq::from_montgomery_limbs([
+                16448746977135623311,
+                412114426691839576,
+                6192892768710612494,
+                888720093560110575,
+            ]),
+            Fq::from_montgomery_limbs([
+                12768570232083494232,
+                2534855653167458622,
+                17543684742671777679,
+                484793600466427368,
+            ]),
+            Fq::from_montgomery_limbs([
+                14617095943539538871,
+                634735199991550350,
+                9670647108455546036,
+                1102342010486135767,
+            ]),
+            Fq::from_montgomery_limbs([
+                10626280923238631900,
+                1155054479550641892,
+                4843332981803914895,
+                1030882310639581675,
+            ]),
+            Fq::from_montgomery_limbs([
+                8635886676593139800,
+                5463788919813217518,
+                9046463712490324765,
+                989740297983208756,
+            ]),
+            Fq::from_montgomery_limbs([
+                9443515491543889185,
+                640568052064105550,
+                1661997575841717062,
+                978514043490707410,
+            ]),
+            Fq::from_montgomery_limbs([
+                12934856080506213799,
+                3093193291610745938,
+                9358940876764317076,
+                479753600251045095,
+            ]),
+            Fq::from_montgomery_limbs([
+                9680598049002011444,
+                1109658122404842943,
+                18100459325503043127,
+                841968121179493900,
+            ]),
+            Fq::from_montgomery_limbs([
+                4551734566797249304,
+                10795156084658977240,
+                379171896537431768,
+                988281129121251130,
+            ]),
+            Fq::from_montgomery_limbs([
+                2135483705228676534,
+                5269554558764181819,
+                444666470357750595,
+                687784262274483944,
+            ]),
+            Fq::from_montgomery_limbs([
+                5050804047823883030,
+                3147314912177598913,
+                3927264104632191716,
+                943380741349137385,
+            ]),
+            Fq::from_montgomery_limbs([
+                15477892322582156392,
+                12737349344239574918,
+                13944205296755048474,
+                193107255593509500,
+            ]),
+            Fq::from_montgomery_limbs([
+                11910866923535083085,
+                14901311351914349512,
+                10935150865297195536,
+                845554858136292620,
+            ]),
+            Fq::from_montgomery_limbs([
+                5122970929313251522,
+                13922143060847062791,
+                11646936560655389275,
+                1018098704019770306,
+            ]),
+            Fq::from_montgomery_limbs([
+                17841353294986077900,
+                4773746451893734964,
+                16284735161171406006,
+                496526557936831266,
+            ]),
+            Fq::from_montgomery_limbs([
+                13111293946832496160,
+                18224525822011874730,
+                13977293746629243889,
+                216629397958891846,
+            ]),
+            Fq::from_montgomery_limbs([
+                114074425339442427,
+                7209560221738872267,
+                13855995843366224233,
+                615639655647447395,
+            ]),
+            Fq::from_montgomery_limbs([
+                14452622168648490891,
+                16602202485673902064,
+                8893063282786604032,
+                958643488665359912,
+            ]),
+            Fq::from_montgomery_limbs([
+                546638927661586706,
+                457252530661972807,
+                15259862650988542708,
+                175074602532489558,
+            ]),
+            Fq::from_montgomery_limbs([
+                766656977382640455,
+                16595998380396440099,
+                2849853961849248525,
+                715719567191822623,
+            ]),
+            Fq::from_montgomery_limbs([
+                5193004102114747027,
+                9657199030127263496,
+                11113683216466785850,
+                282510836440334468,
+            ]),
+            Fq::from_montgomery_limbs([
+                611375838255767657,
+                565292077524122882,
+                4890427089520958086,
+                1094203356658004738,
+            ]),
+            Fq::from_montgomery_limbs([
+                4242278405517852885,
+                3685740866826175602,
+                13951048095616501318,
+                406968138245400023,
+            ]),
+            Fq::from_montgomery_limbs([
+                17409373601894669524,
+                940414977903339429,
+                6547572506807171344,
+                500207159549559957,
+            ]),
+            Fq::from_montgomery_limbs([
+                3527273384906372892,
+                15329915496185175879,
+                821917312774487796,
+                1296523394180508083,
+            ]),
+            Fq::from_montgomery_limbs([
+                2534289501948670316,
+                15664038425852831056,
+                3595704311471564742,
+                552900036408219847,
+            ]),
+            Fq::from_montgomery_limbs([
+                9942349258486254979,
+                14803295526475648322,
+                7203283599792662922,
+                944836872781464728,
+            ]),
+            Fq::from_montgomery_limbs([
+                4161156481300295889,
+                5885397959313210306,
+                1334470571298067484,
+                455321709100902555,
+            ]),
+            Fq::from_montgomery_limbs([
+                11547746909148431777,
+                10575695732509362785,
+                16928382704492653418,
+                590249495761211456,
+            ]),
+            Fq::from_montgomery_limbs([
+                5972642721904293904,
+                9790514377412861376,
+                14800804815544440980,
+                437581850267360797,
+            ]),
+            Fq::from_montgomery_limbs([
+                9806187183974569732,
+                4604354678296605624,
+                16252302269048966279,
+                867395997360946144,
+            ]),
+            Fq::from_montgomery_limbs([
+                3744106903293162975,
+                12882482137362277016,
+                16790922959354408441,
+                162423989077067828,
+            ]),
+            Fq::from_montgomery_limbs([
+                3518010623552559976,
+                14045337256062110382,
+                11325314106935341944,
+                820073069577527340,
+            ]),
+            Fq::from_montgomery_limbs([
+                15045428187680873343,
+                3565227011280434348,
+                1931309567859121753,
+                458003681451793892,
+            ]),
+            Fq::from_montgomery_limbs([
+                17606550452632950788,
+                5656900257809020976,
+                2486784591825128724,
+                667924151400152465,
+            ]),
+            Fq::from_montgomery_limbs([
+                7620125284594629876,
+                4010954489009620520,
+                9000799877317787926,
+                1059406671473535126,
+            ]),
+            Fq::from_montgomery_limbs([
+                1638021111439317397,
+                61185879825328381,
+                2463912345591418104,
+                1025686442824874330,
+            ]),
+            Fq::from_montgomery_limbs([
+                18379220545786913923,
+                9126340640051055160,
+                6869473910696892702,
+                1061442764886358854,
+            ]),
+            Fq::from_montgomery_limbs([
+                11238640377533527037,
+                9630512693814195299,
+                14617247723775349715,
+                1260184677118969602,
+            ]),
+            Fq::from_montgomery_limbs([
+                2466241193619140785,
+                10721789872370180098,
+                8742146071164022844,
+                1278440402112169225,
+            ]),
+            Fq::from_montgomery_limbs([
+                14042745276436858351,
+                12490072787625385698,
+                2419277173425038926,
+                962649267036026056,
+            ]),
+            Fq::from_montgomery_limbs([
+                10301553855650759533,
+                6175912835000605963,
+                14402350114771141207,
+                1164286826563671652,
+            ]),
+            Fq::from_montgomery_limbs([
+                11009174126631630091,
+                17368598642196042742,
+                11981928537536689519,
+                388276292255235163,
+            ]),
+            Fq::from_montgomery_limbs([
+                193589457111298076,
+                1755066611936603564,
+                13050800495492394381,
+                805579482622776978,
+            ]),
+            Fq::from_montgomery_limbs([
+                1245445843656965468,
+                18401340275460360725,
+                3936655053722637318,
+                17163352805631036,
+            ]),
+            Fq::from_montgomery_limbs([
+                17428893701113881430,
+                644773368681600439,
+                10240698371383225168,
+                615243202833760674,
+            ]),
+            Fq::from_montgomery_limbs([
+                16623597248450621197,
+                11095629412989858647,
+                462933974658157719,
+                586290626467559340,
+            ]),
+            Fq::from_montgomery_limbs([
+                589684090465294774,
+                812570136314157109,
+                15034070981215416916,
+                716772961084325931,
+            ]),
+            Fq::from_montgomery_limbs([
+                10481954741633243744,
+                13945326032514957989,
+                16700786741098915337,
+                167580814360731237,
+            ]),
+            Fq::from_montgomery_limbs([
+                17351764048432126089,
+                13102030326715356336,
+                3723986681596837874,
+                1126784860996983138,
+            ]),
+            Fq::from_montgomery_limbs([
+                126194331904481022,
+                15792230366284762217,
+                12527971250975208666,
+                1016807016061843890,
+            ]),
+            Fq::from_montgomery_limbs([
+                13596257086796177076,
+                16980449372855554441,
+                3403519254699352712,
+                216064673201054686,
+            ]),
+            Fq::from_montgomery_limbs([
+                17462785696244263292,
+                7388993851768415847,
+                16572801437432393714,
+                495311699946018180,
+            ]),
+            Fq::from_montgomery_limbs([
+                13944633177911056679,
+                12156404505430828826,
+                4952119568053166963,
+                197383065563578042,
+            ]),
+            Fq::from_montgomery_limbs([
+                3141534285734279418,
+                16359293102289654963,
+                4298444347391055163,
+                1271108699627913244,
+            ]),
+            Fq::from_montgomery_limbs([
+                1337991620756697148,
+                15567282853984277576,
+                15982683758126949035,
+                7945165156231727,
+            ]),
+            Fq::from_montgomery_limbs([
+                7859039559714132644,
+                17590124048102128920,
+                12018360058767768347,
+                207272307157495459,
+            ]),
+            Fq::from_montgomery_limbs([
+                3538574611411100627,
+                1428903337785572787,
+                5479644510552812668,
+                164267889871755558,
+            ]),
+            Fq::from_montgomery_limbs([
+                6983167429761493074,
+                908338121482597817,
+                12159031253317138497,
+                26800443497189557,
+            ]),
+            Fq::from_montgomery_limbs([
+                14055766910436127955,
+                8337486192256308013,
+                6482761558267158601,
+                1228339021866145711,
+            ]),
+            Fq::from_montgomery_limbs([
+                4990175234309450189,
+                11295593108182868750,
+                4587820201326923896,
+                1317725933771925046,
+            ]),
+            Fq::from_montgomery_limbs([
+                7068447449064506462,
+                3529782242082840137,
+                17070542132239145306,
+                378925588754474078,
+            ]),
+            Fq::from_montgomery_limbs([
+                6078317568799165744,
+                13833005284242696135,
+                649072479096338737,
+                607863097030137750,
+            ]),
+            Fq::from_montgomery_limbs([
+                13328614429025863403,
+                11895463398615844442,
+                2146545281299147030,
+                403237009826577363,
+            ]),
+            Fq::from_montgomery_limbs([
+                11710281388241240251,
+                9954801897664325791,
+                2742317943469291510,
+                204631178577869054,
+            ]),
+            Fq::from_montgomery_limbs([
+                2732743170361284973,
+                8418555382290030089,
+                2492527900185938876,
+                667433396270849728,
+            ]),
+            Fq::from_montgomery_limbs([
+                10718641898172503739,
+                3395960711810245157,
+                4091152988196986976,
+                1187878243469158506,
+            ]),
+            Fq::from_montgomery_limbs([
+                9548017901695305379,
+                6661553994904981982,
+                17418946897649310123,
+                731536195315131112,
+            ]),
+            Fq::from_montgomery_limbs([
+                12309478154566425653,
+                1035205234625481320,
+                385074107246100668,
+                41564931381433763,
+            ]),
+            Fq::from_montgomery_limbs([
+                6404550173745381970,
+                689174276123037163,
+                8834967759973097985,
+                1310400507831450334,
+            ]),
+            Fq::from_montgomery_limbs([
+                1933477699528008331,
+                3890065832551633922,
+                2282544759022408913,
+                652828591434101034,
+            ]),
+            Fq::from_montgomery_limbs([
+                12456521108064407619,
+                9691910747276548262,
+                1656417181992584708,
+                125116334724980987,
+            ]),
+            Fq::from_montgomery_limbs([
+                5280777087349722381,
+                12043730884311578742,
+                4444898881741193416,
+                478235287838146870,
+            ]),
+            Fq::from_montgomery_limbs([
+                6367460483096633318,
+                3959005423584714674,
+                13870789132834833194,
+                756871419612072113,
+            ]),
+            Fq::from_montgomery_limbs([
+                16292767175785153690,
+                13252489607969616389,
+                7158856121109365580,
+                906277787620590132,
+            ]),
+            Fq::from_montgomery_limbs([
+                15956723476698452608,
+                9821057920911849834,
+                15730340270492724842,
+                1053945194980898134,
+            ]),
+            Fq::from_montgomery_limbs([
+                10994270970879169702,
+                15880003074525912314,
+                9939224059256493063,
+                265713815895055799,
+            ]),
+            Fq::from_montgomery_limbs([
+                16647201746161635566,
+                1961910028010447243,
+                14015941272988832137,
+                159639591953632028,
+            ]),
+            Fq::from_montgomery_limbs([
+                1175544340360040813,
+                3789931739112637067,
+                13946489502523992840,
+                959285431476879943,
+            ]),
+            Fq::from_montgomery_limbs([
+                8349680999645108928,
+                7125153650032975858,
+                3316484141076044450,
+                657702253554332,
+            ]),
+            Fq::from_montgomery_limbs([
+                16070783279737404957,
+                6708504716397061079,
+                725426795443216044,
+                44344632631506657,
+            ]),
+            Fq::from_montgomery_limbs([
+                16691276352248191958,
+                17483971901807156606,
+                12765793746833856597,
+                681685585330974506,
+            ]),
+            Fq::from_montgomery_limbs([
+                6639708816427398555,
+                15512738180629496800,
+                6599257773048756845,
+                1294598070354902664,
+            ]),
+            Fq::from_montgomery_limbs([
+                17206477090482471401,
+                490144173715839130,
+                1509333252655423953,
+                673651040113276737,
+            ]),
+            Fq::from_montgomery_limbs([
+                13765008246127466593,
+                3829395994620232786,
+                2577377854564189626,
+                28049578877931352,
+            ]),
+            Fq::from_montgomery_limbs([
+                6114003384353346310,
+                18051674632233970772,
+                10141513360284145436,
+                582719563157582334,
+            ]),
+            Fq::from_montgomery_limbs([
+                13629327651398284406,
+                16064773726102567327,
+                9658312412995966664,
+                1262613787238787213,
+            ]),
+            Fq::from_montgomery_limbs([
+                8377432041352375372,
+                3998905450634994369,
+                166176864376241617,
+                156735559352636049,
+            ]),
+            Fq::from_montgomery_limbs([
+                8261260690022770822,
+                12566021289116959263,
+                10424364082417532446,
+                712306720761567854,
+            ]),
+            Fq::from_montgomery_limbs([
+                4328939822932614061,
+                2253382766871332423,
+                2876553252583873210,
+                470666560135102267,
+            ]),
+            Fq::from_montgomery_limbs([
+                4606038135225761979,
+                4337748248308961625,
+                5725481151114156551,
+                399936269972316101,
+            ]),
+            Fq::from_montgomery_limbs([
+                8428906360912225340,
+                1075098032632283254,
+                14045547843599349917,
+                229954545377905757,
+            ]),
+            Fq::from_montgomery_limbs([
+                7200055720989310021,
+                13960663493106979434,
+                6342628078740842513,
+                461895691637559237,
+            ]),
+            Fq::from_montgomery_limbs([
+                9735454405555596743,
+                5496454677771809821,
+                15106584150195796125,
+                1043919422165554049,
+            ]),
+            Fq::from_montgomery_limbs([
+                15318217589646137453,
+                16189470105107333555,
+                7521246472597893144,
+                754724648236605577,
+            ]),
+            Fq::from_montgomery_limbs([
+                46659049368360189,
+                698750861271777148,
+                7010220269793120098,
+                766834960124979076,
+            ]),
+            Fq::from_montgomery_limbs([
+                15416362734687139405,
+                7532084927548180288,
+                2983574162683126123,
+                215794927405097222,
+            ]),
+            Fq::from_montgomery_limbs([
+                1551835063637399781,
+                3770970662051752674,
+                15728956629499760569,
+                787079062303660933,
+            ]),
+            Fq::from_montgomery_limbs([
+                889893873296231531,
+                1319004907763880767,
+                17562007358403026498,
+                384082339582244663,
+            ]),
+            Fq::from_montgomery_limbs([
+                14214777911745740373,
+                14054937888561726488,
+                14547548338309157460,
+                961340243542668170,
+            ]),
+            Fq::from_montgomery_limbs([
+                3467333797216422536,
+                10085847317910544480,
+                13328961897947055256,
+                58578248184489661,
+            ]),
+            Fq::from_montgomery_limbs([
+                1770360803004582984,
+                15260169900876158712,
+                12135484658485623231,
+                133507092316926038,
+            ]),
+            Fq::from_montgomery_limbs([
+                11797553629020564091,
+                11807473812586764690,
+                10994735422767846939,
+                1331647501239706078,
+            ]),
+            Fq::from_montgomery_limbs([
+                11260140172946169548,
+                1475085352212205420,
+                8034603969845432310,
+                931623022739251106,
+            ]),
+            Fq::from_montgomery_limbs([
+                2400521166097179752,
+                173310169246384125,
+                545289732518555046,
+                311417748329776550,
+            ]),
+            Fq::from_montgomery_limbs([
+                13071550821831212137,
+                15188615390759460733,
+                4864393670155831926,
+                1013499469358726020,
+            ]),
+            Fq::from_montgomery_limbs([
+                14058787913699662244,
+                17822569799945723086,
+                18029828148898019233,
+                605257418741003382,
+            ]),
+            Fq::from_montgomery_limbs([
+                551282213788223346,
+                4594966201156988883,
+                432114872194060182,
+                732215059480077525,
+            ]),
+            Fq::from_montgomery_limbs([
+                13737512758462949676,
+                15619558084974839125,
+                1356096242578918233,
+                757523266232535117,
+            ]),
+            Fq::from_montgomery_limbs([
+                3655542303323119543,
+                12201746510635950022,
+                8654641316888702883,
+                9978881942296738,
+            ]),
+            Fq::from_montgomery_limbs([
+                2239382325552601919,
+                13507007011379094432,
+                11848251214710104590,
+                430113423684354821,
+            ]),
+            Fq::from_montgomery_limbs([
+                5951549527978271817,
+                4087571781582321103,
+                3126175559368625842,
+                1049176591073559910,
+            ]),
+            Fq::from_montgomery_limbs([
+                965693838543553578,
+                11306287683005854746,
+                9786255620587508103,
+                226097048614352913,
+            ]),
+            Fq::from_montgomery_limbs([
+                3082023837598981097,
+                11514711527568659344,
+                18083802609995806591,
+                1170907943473709670,
+            ]),
+            Fq::from_montgomery_limbs([
+                8238274665580880255,
+                15127698059223950963,
+                16410207913279017822,
+                672040077957242268,
+            ]),
+            Fq::from_montgomery_limbs([
+                8391582243584206759,
+                5043556674227534492,
+                1979290207634174554,
+                184387387836309549,
+            ]),
+            Fq::from_montgomery_limbs([
+                8690986127698324718,
+                15012540417290473995,
+                7141508193361533219,
+                194655473508835774,
+            ]),
+            Fq::from_montgomery_limbs([
+                9797430095087124473,
+                8005803375845646696,
+                7478137525079349342,
+                39066476702049914,
+            ]),
+            Fq::from_montgomery_limbs([
+                986327011756921333,
+                13665214489205068645,
+                8477208854997892859,
+                27609895459642675,
+            ]),
+            Fq::from_montgomery_limbs([
+                15448973151246786399,
+                13320701183361721282,
+                2279802873042523401,
+                297969266721263852,
+            ]),
+            Fq::from_montgomery_limbs([
+                2123022781665005167,
+                7797340282540441941,
+                12823499527603442107,
+                1159677778711068987,
+            ]),
+            Fq::from_montgomery_limbs([
+                4191580846331285179,
+                8159548502280179631,
+                14083689165462994097,
+                1306560819858565252,
+            ]),
+            Fq::from_montgomery_limbs([
+                11995858244647887298,
+                15279629978982873015,
+                15656177528090864005,
+                265903777585934089,
+            ]),
+            Fq::from_montgomery_limbs([
+                12214076524576605389,
+                18154002547035656274,
+                4482854873035489043,
+                366389835565350926,
+            ]),
+            Fq::from_montgomery_limbs([
+                1542854622976461232,
+                16980996067821895098,
+                10839829671635306158,
+                830595146975548525,
+            ]),
+            Fq::from_montgomery_limbs([
+                10762937219250748781,
+                13937489568255566750,
+                18349152733462912850,
+                1221681534116166703,
+            ]),
+            Fq::from_montgomery_limbs([
+                2225479448237455092,
+                18364968012024868297,
+                7668464416389885436,
+                665751405682009930,
+            ]),
+            Fq::from_montgomery_limbs([
+                8933806459818972593,
+                7737112037175652869,
+                10206441352405165850,
+                544995528070875794,
+            ]),
+            Fq::from_montgomery_limbs([
+                12211595271167602605,
+                11842960878014751619,
+                6943445143813176979,
+                944761112550788610,
+            ]),
+            Fq::from_montgomery_limbs([
+                11568380130316345006,
+                16275184393576078030,
+                16835968763164045418,
+                1093569887206939933,
+            ]),
+            Fq::from_montgomery_limbs([
+                15298434964866679216,
+                3378824051814122377,
+                2333812236419164019,
+                109223642359961281,
+            ]),
+            Fq::from_montgomery_limbs([
+                13425754489805681226,
+                4890088961283400814,
+                9789450162887255318,
+                705872916229176325,
+            ]),
+            Fq::from_montgomery_limbs([
+                3273479514631773476,
+                14398237972917005287,
+                14662690522119887735,
+                77530057647941164,
+            ]),
+            Fq::from_montgomery_limbs([
+                17934577023280612443,
+                3140463820475981518,
+                3103882981290508036,
+                33835746554833714,
+            ]),
+            Fq::from_montgomery_limbs([
+                16323966688664804017,
+                9388390283955051999,
+                15462776813268951717,
+                997180828293869200,
+            ]),
+            Fq::from_montgomery_limbs([
+                3542120623415741086,
+                13142659184178331789,
+                8378592546140127589,
+                331228684056903852,
+            ]),
+            Fq::from_montgomery_limbs([
+                519991645893854192,
+                16498117727806912421,
+                3958043202811513556,
+                137174955369375864,
+            ]),
+            Fq::from_montgomery_limbs([
+                14836594273339656067,
+                15779988768964136819,
+                6898586195842225701,
+                581445777039058797,
+            ]),
+            Fq::from_montgomery_limbs([
+                5817597831898181418,
+                15379685909449363794,
+                3111751434666054698,
+                1069391098810894895,
+            ]),
+            Fq::from_montgomery_limbs([
+                11082816764532785134,
+                14655859072094099946,
+                14260531002282667467,
+                501621525544368923,
+            ]),
+            Fq::from_montgomery_limbs([
+                1592095032683511153,
+                6964180308818150924,
+                312295154610208242,
+                268943363159958475,
+            ]),
+            Fq::from_montgomery_limbs([
+                7355799802090299633,
+                8553203358624327795,
+                3380789021550125127,
+                1337787422398770951,
+            ]),
+            Fq::from_montgomery_limbs([
+                6697918873557794575,
+                9320900493656585792,
+                5296178940116722733,
+                469388722512599972,
+            ]),
+        ]),
+        mds: MdsMatrix::<13, 12, 169, 144>::new_from_known([
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+        ]),
+        alpha: Alpha::Exponent(17),
+        rounds: RoundNumbers { r_P: 31, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<12, 144>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+            ]),
+            v: Matrix::<1, 12, 12>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+            ]),
+            w: Matrix::<12, 1, 12>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<13, 169>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<13, 169>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12844567717592844275,
+                    10012778178265293652,
+                    7879998048570482860,
+                    19623228098164709,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4463614096393202408,
+                    15524282777039793927,
+                    3891121405977841185,
+                    1016482375447525435,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17557705359768926993,
+                    161910628347263066,
+                    15217645725283406572,
+                    796921329996968619,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18078193985085943358,
+                    11532445054590757270,
+                    7901440968232805219,
+                    996853071498040248,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14973467041236660061,
+                    2769474649933801071,
+                    7486638538413412772,
+                    480712674277630600,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8799601002142550471,
+                    6262284423266154917,
+                    11872520694997151592,
+                    442040283428895618,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    6763729357486795124,
+                    15345754222608674590,
+                    1964705425185461018,
+                    1084982934448361138,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7969432885398756902,
+                    4368325708740636236,
+                    16736201055274197456,
+                    375763586546973951,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2213781644650116184,
+                    5050556572068049816,
+                    5476929779637429312,
+                    176737858351791111,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    16886420711850332276,
+                    7159944011924785978,
+                    7542719154085731321,
+                    387238932730938283,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2234428878566421938,
+                    10960871944053832944,
+                    7594738321706507824,
+                    138985813016684722,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14558569232177166635,
+                    14523033443484228582,
+                    418341920675413036,
+                    1265359036450667524,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<13, 169>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    11436292712094246426,
+                    2373216627362516351,
+                    15833772218612091235,
+                    1211271851546994337,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2397934614004524497,
+                    44892797819502841,
+                    5575498594727152799,
+                    488097738153954180,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16273593004402688828,
+                    16270276901820457774,
+                    591606675228756754,
+                    952537986234999228,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12746098152715876305,
+                    6875159993751463965,
+                    2149179333261731010,
+                    273076686611255896,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1112706079973227431,
+                    4694032194965661915,
+                    17979469657855613735,
+                    1187406862973908898,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17414199200372187376,
+                    4712200685957780239,
+                    7530313903112162544,
+                    420996020571371998,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8554431881266296484,
+                    11747415225878619890,
+                    4975214582702007585,
+                    614714982619237580,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13063338404133623292,
+                    4029615375611668909,
+                    17233016296893101392,
+                    1155664574573188938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12304836649304859242,
+                    14957375256964488100,
+                    4726944013235841079,
+                    882892848862163818,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3022251753508582897,
+                    16347958109680399227,
+                    1481419476913771120,
+                    630139394040370097,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9112112333288986287,
+                    11089475642562176796,
+                    4848224888305914106,
+                    1320815067673951410,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1066696036815208354,
+                    17184461839974365991,
+                    12317033799979150174,
+                    6672355367515087,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12944221065981215824,
+                    7885531826074516455,
+                    11569820210678848576,
+                    187516328067684081,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2397934614004524497,
+                    44892797819502841,
+                    5575498594727152799,
+                    488097738153954180,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8010912289178405516,
+                    7890612316556801801,
+                    17595363385350301013,
+                    905077927407352266,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14387066914677987146,
+                    13997650049880246385,
+                    5947598498653390572,
+                    92381240723291743,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4651670246543652124,
+                    15306085718559770802,
+                    13036776631954849263,
+                    1112020379093511090,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8219109145398523894,
+                    8684746852531921413,
+                    13177621396553256439,
+                    887905433480315189,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16697551602720510755,
+                    7995637297049142402,
+                    1403179899236323912,
+                    1260927148866201402,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15998757545616725360,
+                    7437521125410624729,
+                    12571370447932784195,
+                    1051638547988925231,
+                ]),
+                Fq::from_montgomery_limbs([
+                    526351520756009364,
+                    223166596882197504,
+                    1087238845800260118,
+                    490417137804863649,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12662791727255424659,
+                    4798792506884893104,
+                    15537886713300901747,
+                    442125884821456556,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4069448501427542283,
+                    15473323639059005652,
+                    17013103367707101724,
+                    1268122593991444908,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10022141452007847518,
+                    4227996063923321992,
+                    11867763695076624795,
+                    562701969327105726,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14994741869623747088,
+                    16984956649802162259,
+                    1803619694207873622,
+                    707248369572883355,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13415305838300253626,
+                    10316278883489509940,
+                    8126778115964808364,
+                    846855393392572725,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16273593004402688828,
+                    16270276901820457774,
+                    591606675228756754,
+                    952537986234999228,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14387066914677987146,
+                    13997650049880246385,
+                    5947598498653390572,
+                    92381240723291743,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15658335260959173204,
+                    17386236881654128055,
+                    11902679991149955841,
+                    389105958155945572,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5128687893879281223,
+                    4294747098731026959,
+                    15929686532957846418,
+                    487004763243146480,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16453977217239848783,
+                    9462912629380797752,
+                    16603336450446797821,
+                    1010373775709361098,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5639284782882891747,
+                    2503857537720564489,
+                    526017170980167304,
+                    249437746552401117,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12175766719957630046,
+                    7839470997300950665,
+                    9413398770682043568,
+                    303275638354592303,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7158820775322902282,
+                    3756991649330043524,
+                    18043120600140971751,
+                    1088288147822535845,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15054709552518183134,
+                    12317248664185524296,
+                    12472395409820836675,
+                    1083333752843591828,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4213721421237276610,
+                    4549448791665104431,
+                    711378456895624897,
+                    315876734932023417,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14418953982178940145,
+                    550018635096138015,
+                    9996613273351734169,
+                    320004242232514626,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12187676926704089211,
+                    7551709256205812674,
+                    15689455405761490002,
+                    281783021198588450,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10043270450792368813,
+                    8237013231692870014,
+                    3628678056868250991,
+                    190390917726718065,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12746098152715876305,
+                    6875159993751463965,
+                    2149179333261731010,
+                    273076686611255896,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4651670246543652124,
+                    15306085718559770802,
+                    13036776631954849263,
+                    1112020379093511090,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5128687893879281223,
+                    4294747098731026959,
+                    15929686532957846418,
+                    487004763243146480,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1583347422723358735,
+                    14258250638624005194,
+                    9981823377547570556,
+                    315425431459757740,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6130610677020431751,
+                    9325704187410968961,
+                    5547030002241790109,
+                    275648291891959732,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11021681574480307260,
+                    7854131206842373711,
+                    15768952951013193151,
+                    1112760734348179184,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4215168437827012114,
+                    1466372133768913632,
+                    9466264633583859598,
+                    1215415827679537575,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1023443985052466770,
+                    6529805900672443327,
+                    6890935285946953289,
+                    892642233058089234,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4858480340517892522,
+                    16761398758962944557,
+                    10950704318503872162,
+                    515648803278740420,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11799645740763556021,
+                    12247713702802527074,
+                    17885909038372020546,
+                    534667637244758905,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11800163502103261214,
+                    1336081431554340491,
+                    5502090773690696123,
+                    132110567690039541,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15803506475671880967,
+                    14740218041004108085,
+                    9440305982599546345,
+                    22286237355970352,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14252855342555709427,
+                    12379260283171264265,
+                    3642955048740556644,
+                    202934864327865007,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1112706079973227431,
+                    4694032194965661915,
+                    17979469657855613735,
+                    1187406862973908898,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8219109145398523894,
+                    8684746852531921413,
+                    13177621396553256439,
+                    887905433480315189,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16453977217239848783,
+                    9462912629380797752,
+                    16603336450446797821,
+                    1010373775709361098,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6130610677020431751,
+                    9325704187410968961,
+                    5547030002241790109,
+                    275648291891959732,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14377216761363897445,
+                    6304044357483667240,
+                    6502182067388806448,
+                    719105073745851364,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2762421967097498743,
+                    2508376141975545398,
+                    11390553718104589946,
+                    1320764977293227068,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17781207683219867200,
+                    2402580746178617937,
+                    17176148176956206694,
+                    811824626199345658,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7851049642374020756,
+                    123960283839624719,
+                    12039070678352912399,
+                    1183069122527999500,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12359389983826434328,
+                    13461488318435320362,
+                    15184970546630219271,
+                    927398946970830995,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17736385141455279616,
+                    5436006545701702161,
+                    15167313100096848512,
+                    672403207082296891,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8628709054788202763,
+                    2116436108191722956,
+                    9995291822251634229,
+                    574231092651381437,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6280016386739670734,
+                    2154387798979415190,
+                    8461730034726039376,
+                    1006150884735014267,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6726613511339899956,
+                    12999466165661010250,
+                    6305570999918484068,
+                    293984272893172420,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17414199200372187376,
+                    4712200685957780239,
+                    7530313903112162544,
+                    420996020571371998,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16697551602720510755,
+                    7995637297049142402,
+                    1403179899236323912,
+                    1260927148866201402,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5639284782882891747,
+                    2503857537720564489,
+                    526017170980167304,
+                    249437746552401117,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11021681574480307260,
+                    7854131206842373711,
+                    15768952951013193151,
+                    1112760734348179184,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2762421967097498743,
+                    2508376141975545398,
+                    11390553718104589946,
+                    1320764977293227068,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11788608209757459591,
+                    15978823513148006068,
+                    3013804878526481891,
+                    895398528800289292,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18400626401530931089,
+                    13636767065549694695,
+                    15879640271345951590,
+                    414724672208224813,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10656093087098801999,
+                    12004803520514660105,
+                    11880093632225572455,
+                    157970364445347994,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18432613884298742364,
+                    11440394705008571254,
+                    11833474707290201815,
+                    87514165031853470,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13696313866963396757,
+                    12735443709195594470,
+                    4168101350970818079,
+                    536997944894661027,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9364720275421953736,
+                    15917746592185008287,
+                    2413455978426974220,
+                    1014059247881142429,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4997835394697026443,
+                    16132870927338372978,
+                    5951732317176539522,
+                    179570969418203923,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10429733104206411081,
+                    7179680831877602716,
+                    3217828291507561909,
+                    669490860738636759,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8554431881266296484,
+                    11747415225878619890,
+                    4975214582702007585,
+                    614714982619237580,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15998757545616725360,
+                    7437521125410624729,
+                    12571370447932784195,
+                    1051638547988925231,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12175766719957630046,
+                    7839470997300950665,
+                    9413398770682043568,
+                    303275638354592303,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4215168437827012114,
+                    1466372133768913632,
+                    9466264633583859598,
+                    1215415827679537575,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17781207683219867200,
+                    2402580746178617937,
+                    17176148176956206694,
+                    811824626199345658,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18400626401530931089,
+                    13636767065549694695,
+                    15879640271345951590,
+                    414724672208224813,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9021309672880493451,
+                    5802963259075482250,
+                    10163564030559760162,
+                    712735226869948002,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5215253159162219777,
+                    1061316599003187984,
+                    13738653261553429786,
+                    1177583162451525568,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17959345567655760208,
+                    14713766731860534759,
+                    8905134047311235468,
+                    166532644290559997,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12734085742080389473,
+                    16204155758798769378,
+                    9447882501684137124,
+                    1229727107302886869,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4282873472535042663,
+                    2124550075088473119,
+                    2136596595033523048,
+                    563527443795943890,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16323345253462214868,
+                    5295700948508717928,
+                    14979396908253344855,
+                    823359992562949448,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14546635533165761751,
+                    9360737456734075842,
+                    14694287639669620854,
+                    566657174519558243,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13063338404133623292,
+                    4029615375611668909,
+                    17233016296893101392,
+                    1155664574573188938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    526351520756009364,
+                    223166596882197504,
+                    1087238845800260118,
+                    490417137804863649,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7158820775322902282,
+                    3756991649330043524,
+                    18043120600140971751,
+                    1088288147822535845,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1023443985052466770,
+                    6529805900672443327,
+                    6890935285946953289,
+                    892642233058089234,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7851049642374020756,
+                    123960283839624719,
+                    12039070678352912399,
+                    1183069122527999500,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10656093087098801999,
+                    12004803520514660105,
+                    11880093632225572455,
+                    157970364445347994,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5215253159162219777,
+                    1061316599003187984,
+                    13738653261553429786,
+                    1177583162451525568,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12457916745046585991,
+                    9083481098222624370,
+                    7339644112753475375,
+                    587144086276154226,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9967922386754374320,
+                    15392766332171089758,
+                    16039654950131448688,
+                    901045653726060351,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5072926167514321722,
+                    2979101537913041883,
+                    15745311588750122900,
+                    1308578367519676136,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5304891204169576114,
+                    10589155146173957605,
+                    13106961086222611599,
+                    123943908221115938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5579829030071288066,
+                    7823605305324930556,
+                    1751627513148558949,
+                    1289704839884446240,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16125487085697384749,
+                    2011637041032864954,
+                    11217388394374515463,
+                    734880462505837216,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12304836649304859242,
+                    14957375256964488100,
+                    4726944013235841079,
+                    882892848862163818,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12662791727255424659,
+                    4798792506884893104,
+                    15537886713300901747,
+                    442125884821456556,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15054709552518183134,
+                    12317248664185524296,
+                    12472395409820836675,
+                    1083333752843591828,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4858480340517892522,
+                    16761398758962944557,
+                    10950704318503872162,
+                    515648803278740420,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12359389983826434328,
+                    13461488318435320362,
+                    15184970546630219271,
+                    927398946970830995,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18432613884298742364,
+                    11440394705008571254,
+                    11833474707290201815,
+                    87514165031853470,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17959345567655760208,
+                    14713766731860534759,
+                    8905134047311235468,
+                    166532644290559997,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9967922386754374320,
+                    15392766332171089758,
+                    16039654950131448688,
+                    901045653726060351,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13966221459699408292,
+                    9552411332933504400,
+                    14085941071175544089,
+                    1236731165528137367,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12631948079647346186,
+                    7695702308190345551,
+                    415914914757919853,
+                    604127465792668860,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16470509978839559097,
+                    2684865214270240087,
+                    2453582202971426353,
+                    304842870966288321,
+                ]),
+                Fq::from_montgomery_limbs([
+                    197430720301593328,
+                    9873465095954278983,
+                    17132318189003410200,
+                    1063167785478556522,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3046758740483882708,
+                    4449470411166964550,
+                    14197634995575547130,
+                    610399908182336181,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3022251753508582897,
+                    16347958109680399227,
+                    1481419476913771120,
+                    630139394040370097,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4069448501427542283,
+                    15473323639059005652,
+                    17013103367707101724,
+                    1268122593991444908,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4213721421237276610,
+                    4549448791665104431,
+                    711378456895624897,
+                    315876734932023417,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11799645740763556021,
+                    12247713702802527074,
+                    17885909038372020546,
+                    534667637244758905,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17736385141455279616,
+                    5436006545701702161,
+                    15167313100096848512,
+                    672403207082296891,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13696313866963396757,
+                    12735443709195594470,
+                    4168101350970818079,
+                    536997944894661027,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12734085742080389473,
+                    16204155758798769378,
+                    9447882501684137124,
+                    1229727107302886869,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5072926167514321722,
+                    2979101537913041883,
+                    15745311588750122900,
+                    1308578367519676136,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12631948079647346186,
+                    7695702308190345551,
+                    415914914757919853,
+                    604127465792668860,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14456629267733363065,
+                    8695246998580102984,
+                    13744920205507705620,
+                    1205629084340372628,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11323057071401248064,
+                    8343342827932838609,
+                    18142455420198886393,
+                    1081172799591359940,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3486334877248900086,
+                    16552481307743474450,
+                    18430519552304631461,
+                    603648027890034899,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1180184349005893647,
+                    10525014133509241905,
+                    4275239115621534074,
+                    278359623375983018,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9112112333288986287,
+                    11089475642562176796,
+                    4848224888305914106,
+                    1320815067673951410,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10022141452007847518,
+                    4227996063923321992,
+                    11867763695076624795,
+                    562701969327105726,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14418953982178940145,
+                    550018635096138015,
+                    9996613273351734169,
+                    320004242232514626,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11800163502103261214,
+                    1336081431554340491,
+                    5502090773690696123,
+                    132110567690039541,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8628709054788202763,
+                    2116436108191722956,
+                    9995291822251634229,
+                    574231092651381437,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9364720275421953736,
+                    15917746592185008287,
+                    2413455978426974220,
+                    1014059247881142429,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4282873472535042663,
+                    2124550075088473119,
+                    2136596595033523048,
+                    563527443795943890,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5304891204169576114,
+                    10589155146173957605,
+                    13106961086222611599,
+                    123943908221115938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16470509978839559097,
+                    2684865214270240087,
+                    2453582202971426353,
+                    304842870966288321,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11323057071401248064,
+                    8343342827932838609,
+                    18142455420198886393,
+                    1081172799591359940,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16967994427355972452,
+                    14709991963709797541,
+                    5276505047029986874,
+                    255647838261815160,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3662726610807133272,
+                    2222886768671304551,
+                    1628874331404080560,
+                    452359718190092888,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4801310052356970341,
+                    8146217382984750030,
+                    4960085760963186867,
+                    1081694690054127568,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1066696036815208354,
+                    17184461839974365991,
+                    12317033799979150174,
+                    6672355367515087,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14994741869623747088,
+                    16984956649802162259,
+                    1803619694207873622,
+                    707248369572883355,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12187676926704089211,
+                    7551709256205812674,
+                    15689455405761490002,
+                    281783021198588450,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15803506475671880967,
+                    14740218041004108085,
+                    9440305982599546345,
+                    22286237355970352,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6280016386739670734,
+                    2154387798979415190,
+                    8461730034726039376,
+                    1006150884735014267,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4997835394697026443,
+                    16132870927338372978,
+                    5951732317176539522,
+                    179570969418203923,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16323345253462214868,
+                    5295700948508717928,
+                    14979396908253344855,
+                    823359992562949448,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5579829030071288066,
+                    7823605305324930556,
+                    1751627513148558949,
+                    1289704839884446240,
+                ]),
+                Fq::from_montgomery_limbs([
+                    197430720301593328,
+                    9873465095954278983,
+                    17132318189003410200,
+                    1063167785478556522,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3486334877248900086,
+                    16552481307743474450,
+                    18430519552304631461,
+                    603648027890034899,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3662726610807133272,
+                    2222886768671304551,
+                    1628874331404080560,
+                    452359718190092888,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16358199487599466534,
+                    13835830345035039824,
+                    8196590531329270253,
+                    649448079010611021,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9768588963340794701,
+                    15033336647017346746,
+                    824371908023390968,
+                    673785294593465621,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12944221065981215824,
+                    7885531826074516455,
+                    11569820210678848576,
+                    187516328067684081,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13415305838300253626,
+                    10316278883489509940,
+                    8126778115964808364,
+                    846855393392572725,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10043270450792368813,
+                    8237013231692870014,
+                    3628678056868250991,
+                    190390917726718065,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14252855342555709427,
+                    12379260283171264265,
+                    3642955048740556644,
+                    202934864327865007,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6726613511339899956,
+                    12999466165661010250,
+                    6305570999918484068,
+                    293984272893172420,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10429733104206411081,
+                    7179680831877602716,
+                    3217828291507561909,
+                    669490860738636759,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14546635533165761751,
+                    9360737456734075842,
+                    14694287639669620854,
+                    566657174519558243,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16125487085697384749,
+                    2011637041032864954,
+                    11217388394374515463,
+                    734880462505837216,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3046758740483882708,
+                    4449470411166964550,
+                    14197634995575547130,
+                    610399908182336181,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1180184349005893647,
+                    10525014133509241905,
+                    4275239115621534074,
+                    278359623375983018,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4801310052356970341,
+                    8146217382984750030,
+                    4960085760963186867,
+                    1081694690054127568,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9768588963340794701,
+                    15033336647017346746,
+                    824371908023390968,
+                    673785294593465621,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6149894002518304919,
+                    13688240122816194857,
+                    6557971093361089441,
+                    756577069855925171,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<12, 144>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    306351731805427016,
+                    17812951292552755816,
+                    12316121243078840536,
+                    1020441697904372526,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4833121389201640710,
+                    2840822647111168996,
+                    3855101036016986968,
+                    564616357628599250,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3419333083352191232,
+                    7224988128467807043,
+                    5360539661347380949,
+                    1047876271855564097,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8380099694660226522,
+                    16752260241999280533,
+                    9683819124894968574,
+                    1213239749242916055,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4212248006373507950,
+                    13231629507482196739,
+                    13042745729071928543,
+                    841795763728656892,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12179031805427048511,
+                    13965031528809066401,
+                    5334834751027780606,
+                    1106014400809032551,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16977979330593354609,
+                    4158470094707447270,
+                    7661838045849911105,
+                    684900613789208290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18162494737023808008,
+                    18278606374778596635,
+                    15068396281455258390,
+                    707549739534605472,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16111126648958484987,
+                    9231677116211341770,
+                    11265478710021842182,
+                    705327922276667318,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18348664896304272480,
+                    12773111436603635290,
+                    7487135433873721459,
+                    1170572677028592904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4404942874263263713,
+                    16918820777790202371,
+                    17047630302346567614,
+                    663763664807766251,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14895818127670057865,
+                    14612433350001516563,
+                    8535536120567455738,
+                    605583772354105378,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4833121389201640710,
+                    2840822647111168996,
+                    3855101036016986968,
+                    564616357628599250,
+                ]),
+                Fq::from_montgomery_limbs([
+                    38862721456228754,
+                    15697814685398676993,
+                    5110689684204197291,
+                    18875486995556129,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15339051051609445724,
+                    5529066640621385485,
+                    141730717546877617,
+                    852975350761187036,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9367777706491633306,
+                    3578316077873109187,
+                    2901226496734790404,
+                    1287293013448863363,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13205323099034053063,
+                    2215266179728104515,
+                    9462527988447098488,
+                    1304693606831292943,
+                ]),
+                Fq::from_montgomery_limbs([
+                    982894476538528913,
+                    16732614866430576388,
+                    11243884308326024383,
+                    1244763103192520577,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9261779641667081164,
+                    5108844550957076196,
+                    14115253581271688739,
+                    642710143334514634,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17471435657130446093,
+                    10494579093398631639,
+                    2238946731262496369,
+                    1280726369628581697,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3847611637277121142,
+                    18361226670606196897,
+                    11293001229308098840,
+                    506422502155627967,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9255756590346775522,
+                    5996273251990783156,
+                    16714946297118633741,
+                    759430015291023878,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15206843699134482815,
+                    10509923885137166303,
+                    1484865687260756274,
+                    39641413029161073,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12454489530044598365,
+                    3235188853060117749,
+                    6633661591525577907,
+                    22947261700015343,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3419333083352191232,
+                    7224988128467807043,
+                    5360539661347380949,
+                    1047876271855564097,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15339051051609445724,
+                    5529066640621385485,
+                    141730717546877617,
+                    852975350761187036,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1767671782242458305,
+                    456672899587049599,
+                    16470775987434883402,
+                    646944350532839065,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5270790362279736744,
+                    1999158568880149039,
+                    10595315602816113912,
+                    229779837547732155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13072701829631588665,
+                    642224940578548180,
+                    18430842317777710968,
+                    786649822370061589,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17189614938608596546,
+                    13404468762097823087,
+                    9376185407953215630,
+                    1328740493992345136,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17807919965126672356,
+                    2014823993091368843,
+                    16618631837074837181,
+                    352859727632475036,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9570406346891768131,
+                    9102990228658589589,
+                    3417442318994498242,
+                    36832057377052887,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14418953982178940145,
+                    550018635096138015,
+                    9996613273351734169,
+                    320004242232514626,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12987995879454258424,
+                    7240232882829627818,
+                    8328171857264461549,
+                    931994484902502398,
+                ]),
+                Fq::from_montgomery_limbs([
+                    782489813798366319,
+                    11623305144480877195,
+                    10793459489237020706,
+                    537925371218847790,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15317863524185091440,
+                    2509378527043272743,
+                    14535698114008565337,
+                    731262734254239751,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8380099694660226522,
+                    16752260241999280533,
+                    9683819124894968574,
+                    1213239749242916055,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9367777706491633306,
+                    3578316077873109187,
+                    2901226496734790404,
+                    1287293013448863363,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5270790362279736744,
+                    1999158568880149039,
+                    10595315602816113912,
+                    229779837547732155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9949114508303260767,
+                    7990145942730248393,
+                    15314134312366361451,
+                    1152344947316287418,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2514699176943400754,
+                    165807732051272159,
+                    8661518243954165213,
+                    613885438001455389,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15869989202527757041,
+                    7526085933413773647,
+                    6254998108830596899,
+                    139455473906686038,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4434691970793228909,
+                    9984367672361014918,
+                    8582134764673763257,
+                    683021853801863673,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17574579637522066805,
+                    3068173508350224382,
+                    11860368977936044745,
+                    1202311026323400815,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12239629086347193399,
+                    8391388095663284866,
+                    374734604110688887,
+                    388453873841974048,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5697360780930049767,
+                    708222915369259506,
+                    5181219354032933567,
+                    1025557616034352896,
+                ]),
+                Fq::from_montgomery_limbs([
+                    437522775758576949,
+                    8156749549142676527,
+                    5377208625614035568,
+                    1005360048420401451,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13253769264047190610,
+                    16369898411867207606,
+                    5096824474600161445,
+                    343408756297207692,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4212248006373507950,
+                    13231629507482196739,
+                    13042745729071928543,
+                    841795763728656892,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13205323099034053063,
+                    2215266179728104515,
+                    9462527988447098488,
+                    1304693606831292943,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13072701829631588665,
+                    642224940578548180,
+                    18430842317777710968,
+                    786649822370061589,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2514699176943400754,
+                    165807732051272159,
+                    8661518243954165213,
+                    613885438001455389,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4849284120655105341,
+                    3154543010643225157,
+                    8652503559586293636,
+                    89849892202000306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4324963190528624056,
+                    11959723187765668247,
+                    13438769038494331244,
+                    862428707633810403,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13375631602587157772,
+                    2003425407433894689,
+                    329085556246914891,
+                    1043002957485652401,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306938691897900730,
+                    12718732377186127510,
+                    13046769052161973460,
+                    251268867217116216,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15572832796464115031,
+                    547021274498154254,
+                    14373799031160834273,
+                    397342586410073011,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11274568231964416467,
+                    9528854874638486245,
+                    17573742332423679645,
+                    258948642422609671,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14670737583567331278,
+                    2280275410969619248,
+                    16499882513555386669,
+                    630106656486562565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5321813812793337081,
+                    4340776199897593638,
+                    292237667786571698,
+                    537692238327876034,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12179031805427048511,
+                    13965031528809066401,
+                    5334834751027780606,
+                    1106014400809032551,
+                ]),
+                Fq::from_montgomery_limbs([
+                    982894476538528913,
+                    16732614866430576388,
+                    11243884308326024383,
+                    1244763103192520577,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17189614938608596546,
+                    13404468762097823087,
+                    9376185407953215630,
+                    1328740493992345136,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15869989202527757041,
+                    7526085933413773647,
+                    6254998108830596899,
+                    139455473906686038,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4324963190528624056,
+                    11959723187765668247,
+                    13438769038494331244,
+                    862428707633810403,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13208761955564883548,
+                    7467467134986250049,
+                    969189958114167346,
+                    249949933408172995,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15488182557405493422,
+                    17035284256941992701,
+                    14495890261426591036,
+                    236360273535813883,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4962549026674287064,
+                    11425409689612305182,
+                    4560095960611177358,
+                    586467842610426092,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16725516020653264080,
+                    9435723838039892198,
+                    8130118733430229538,
+                    1117939747032132927,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8491713190102099692,
+                    16182381983312258856,
+                    10909992245550123104,
+                    643806029369291850,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1429892480304381848,
+                    5232786703887599904,
+                    16127159886624785892,
+                    189974755275593914,
+                ]),
+                Fq::from_montgomery_limbs([
+                    213950750395960472,
+                    995700468704511513,
+                    17648841007757824821,
+                    1218760978611952742,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16977979330593354609,
+                    4158470094707447270,
+                    7661838045849911105,
+                    684900613789208290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9261779641667081164,
+                    5108844550957076196,
+                    14115253581271688739,
+                    642710143334514634,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17807919965126672356,
+                    2014823993091368843,
+                    16618631837074837181,
+                    352859727632475036,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4434691970793228909,
+                    9984367672361014918,
+                    8582134764673763257,
+                    683021853801863673,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13375631602587157772,
+                    2003425407433894689,
+                    329085556246914891,
+                    1043002957485652401,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15488182557405493422,
+                    17035284256941992701,
+                    14495890261426591036,
+                    236360273535813883,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15998821398070688797,
+                    8309124852653183890,
+                    13000732881481847814,
+                    1339851899942432320,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12445469545957761935,
+                    6172458155844710924,
+                    8752087305670077762,
+                    209824778542686273,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1023119455850710367,
+                    372073008285232260,
+                    4130790745379452354,
+                    193479540670718053,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13775007919526553728,
+                    15610366371594165077,
+                    18373457791730502166,
+                    1013198694803167545,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2874068969989358258,
+                    18022734239318236474,
+                    13284197942523902457,
+                    313391514077610311,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9710615375987677514,
+                    11196310547731165224,
+                    9659585278033104650,
+                    1339593372803089404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18162494737023808008,
+                    18278606374778596635,
+                    15068396281455258390,
+                    707549739534605472,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17471435657130446093,
+                    10494579093398631639,
+                    2238946731262496369,
+                    1280726369628581697,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9570406346891768131,
+                    9102990228658589589,
+                    3417442318994498242,
+                    36832057377052887,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17574579637522066805,
+                    3068173508350224382,
+                    11860368977936044745,
+                    1202311026323400815,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306938691897900730,
+                    12718732377186127510,
+                    13046769052161973460,
+                    251268867217116216,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4962549026674287064,
+                    11425409689612305182,
+                    4560095960611177358,
+                    586467842610426092,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12445469545957761935,
+                    6172458155844710924,
+                    8752087305670077762,
+                    209824778542686273,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15968748227611194678,
+                    7190842288545234779,
+                    11119966462829877071,
+                    978717124068259006,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14708819757958653422,
+                    2758372402200530198,
+                    5894117291201747254,
+                    618284944287756211,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12197119318764938850,
+                    12680333369131266707,
+                    5486494948688289037,
+                    824793732357381512,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14646805362570397145,
+                    2618728049008656973,
+                    11684246823816981724,
+                    676766574064954125,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17621098939308665491,
+                    16198120626491699797,
+                    2265862821498724982,
+                    88554033998687072,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16111126648958484987,
+                    9231677116211341770,
+                    11265478710021842182,
+                    705327922276667318,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3847611637277121142,
+                    18361226670606196897,
+                    11293001229308098840,
+                    506422502155627967,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14418953982178940145,
+                    550018635096138015,
+                    9996613273351734169,
+                    320004242232514626,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12239629086347193399,
+                    8391388095663284866,
+                    374734604110688887,
+                    388453873841974048,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15572832796464115031,
+                    547021274498154254,
+                    14373799031160834273,
+                    397342586410073011,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16725516020653264080,
+                    9435723838039892198,
+                    8130118733430229538,
+                    1117939747032132927,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1023119455850710367,
+                    372073008285232260,
+                    4130790745379452354,
+                    193479540670718053,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14708819757958653422,
+                    2758372402200530198,
+                    5894117291201747254,
+                    618284944287756211,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13171370259703786902,
+                    7807520499699178816,
+                    11746328121276460278,
+                    421349390735404713,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15737427922226741131,
+                    15181100629730288885,
+                    5028596148895770351,
+                    357140187640097125,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5900187841324308031,
+                    5298294752056211348,
+                    6094202303947024576,
+                    1290304329581533267,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14635667344200757266,
+                    3301744448463346239,
+                    9266044749790859815,
+                    269904567173028045,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18348664896304272480,
+                    12773111436603635290,
+                    7487135433873721459,
+                    1170572677028592904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9255756590346775522,
+                    5996273251990783156,
+                    16714946297118633741,
+                    759430015291023878,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12987995879454258424,
+                    7240232882829627818,
+                    8328171857264461549,
+                    931994484902502398,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5697360780930049767,
+                    708222915369259506,
+                    5181219354032933567,
+                    1025557616034352896,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11274568231964416467,
+                    9528854874638486245,
+                    17573742332423679645,
+                    258948642422609671,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8491713190102099692,
+                    16182381983312258856,
+                    10909992245550123104,
+                    643806029369291850,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13775007919526553728,
+                    15610366371594165077,
+                    18373457791730502166,
+                    1013198694803167545,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12197119318764938850,
+                    12680333369131266707,
+                    5486494948688289037,
+                    824793732357381512,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15737427922226741131,
+                    15181100629730288885,
+                    5028596148895770351,
+                    357140187640097125,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16108471364233758385,
+                    12370078833857247770,
+                    11209830064907235908,
+                    498448789107728180,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4095006105969114156,
+                    6300141843204639955,
+                    13649971732409230300,
+                    753035923601618445,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14642871211081312099,
+                    14588781309048677908,
+                    14730292033431903331,
+                    237443068973802043,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4404942874263263713,
+                    16918820777790202371,
+                    17047630302346567614,
+                    663763664807766251,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15206843699134482815,
+                    10509923885137166303,
+                    1484865687260756274,
+                    39641413029161073,
+                ]),
+                Fq::from_montgomery_limbs([
+                    782489813798366319,
+                    11623305144480877195,
+                    10793459489237020706,
+                    537925371218847790,
+                ]),
+                Fq::from_montgomery_limbs([
+                    437522775758576949,
+                    8156749549142676527,
+                    5377208625614035568,
+                    1005360048420401451,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14670737583567331278,
+                    2280275410969619248,
+                    16499882513555386669,
+                    630106656486562565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1429892480304381848,
+                    5232786703887599904,
+                    16127159886624785892,
+                    189974755275593914,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2874068969989358258,
+                    18022734239318236474,
+                    13284197942523902457,
+                    313391514077610311,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14646805362570397145,
+                    2618728049008656973,
+                    11684246823816981724,
+                    676766574064954125,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5900187841324308031,
+                    5298294752056211348,
+                    6094202303947024576,
+                    1290304329581533267,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4095006105969114156,
+                    6300141843204639955,
+                    13649971732409230300,
+                    753035923601618445,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4765286761093471934,
+                    1657531435537121541,
+                    2421098971403628421,
+                    1056638339603167105,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15337501089470912985,
+                    5393620012705560463,
+                    246763789617202958,
+                    659439305672068328,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14895818127670057865,
+                    14612433350001516563,
+                    8535536120567455738,
+                    605583772354105378,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12454489530044598365,
+                    3235188853060117749,
+                    6633661591525577907,
+                    22947261700015343,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15317863524185091440,
+                    2509378527043272743,
+                    14535698114008565337,
+                    731262734254239751,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13253769264047190610,
+                    16369898411867207606,
+                    5096824474600161445,
+                    343408756297207692,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5321813812793337081,
+                    4340776199897593638,
+                    292237667786571698,
+                    537692238327876034,
+                ]),
+                Fq::from_montgomery_limbs([
+                    213950750395960472,
+                    995700468704511513,
+                    17648841007757824821,
+                    1218760978611952742,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9710615375987677514,
+                    11196310547731165224,
+                    9659585278033104650,
+                    1339593372803089404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17621098939308665491,
+                    16198120626491699797,
+                    2265862821498724982,
+                    88554033998687072,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14635667344200757266,
+                    3301744448463346239,
+                    9266044749790859815,
+                    269904567173028045,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14642871211081312099,
+                    14588781309048677908,
+                    14730292033431903331,
+                    237443068973802043,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15337501089470912985,
+                    5393620012705560463,
+                    246763789617202958,
+                    659439305672068328,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16661657921886234957,
+                    15035048185440850217,
+                    3072606694549091199,
+                    202297188605119166,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            M_i: Matrix::<13, 13, 169>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5048794699978773897,
+                    10259870726530239883,
+                    4760878054374578102,
+                    1079958585686696319,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11702936413104287290,
+                    15018355445596799945,
+                    12868941674620449583,
+                    275391629763747651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3507820852454877112,
+                    6385369593571050863,
+                    16753424582773777049,
+                    1174585556410736585,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3245794314031781238,
+                    5666725617419398019,
+                    13899969571856954590,
+                    630210315769053511,
+                ]),
+                Fq::from_montgomery_limbs([
+                    82922682576783248,
+                    18237671060184104605,
+                    11944380770099038441,
+                    1316863846925286256,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1248467610087595933,
+                    11577512625019393617,
+                    16276256630477391699,
+                    849317645930118297,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10441685552146618286,
+                    9204012907080465821,
+                    13902692094968213291,
+                    1296942842939993194,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9010797711204810748,
+                    19714913432479207,
+                    13793387317486510591,
+                    505410453034306003,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10055284179393788642,
+                    14798441843543547487,
+                    11886119694140429120,
+                    294706106985181433,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15674369469117534320,
+                    5402304505756275339,
+                    11347768464848270770,
+                    807478219797394730,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10411395242581313409,
+                    4956150527606711032,
+                    9649303464227282137,
+                    1206241743835381893,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5852046616903811431,
+                    7205228883702485292,
+                    15137841759912473599,
+                    161968282585828597,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    11702936413104287290,
+                    15018355445596799945,
+                    12868941674620449583,
+                    275391629763747651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1259147870024725172,
+                    18050371306571355400,
+                    10317672742004803267,
+                    764510748600004165,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9947626230127570395,
+                    16057162144099902141,
+                    7132627687849416700,
+                    790580209279313894,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13363662791849302387,
+                    4283255802612223390,
+                    12612012038111107474,
+                    330889857327824552,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10802998288182837788,
+                    5037389069540747705,
+                    261476785937946355,
+                    462757017605064989,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13372855464217139300,
+                    1926378711242084173,
+                    3170809045675110582,
+                    850723817494816946,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15564257842234253973,
+                    11851438757056337876,
+                    14498182966561773982,
+                    553699605433652569,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13733655233332309370,
+                    2993995143752260725,
+                    12397139818377719082,
+                    870917564537692824,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14613752957597208510,
+                    13451557728704935023,
+                    2500249592317509320,
+                    1063810952089961673,
+                ]),
+                Fq::from_montgomery_limbs([
+                    412778953751459785,
+                    3116023680168185453,
+                    15721389480104888512,
+                    581358278899521584,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9134023940363488260,
+                    13548964005261273375,
+                    17030085941115572243,
+                    429045919624233805,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2011611498010484266,
+                    10543585133089709328,
+                    14805497434670349685,
+                    377114458205891616,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3507820852454877112,
+                    6385369593571050863,
+                    16753424582773777049,
+                    1174585556410736585,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9947626230127570395,
+                    16057162144099902141,
+                    7132627687849416700,
+                    790580209279313894,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10318790106757402459,
+                    574376969794628330,
+                    1125078710848114150,
+                    206839807151670103,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14618253850184117536,
+                    4376020836497186074,
+                    14192003755973688444,
+                    588137726574779630,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12021100524974827356,
+                    11924581017583111963,
+                    15548700377666273887,
+                    144700431220435682,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17215318788147816862,
+                    6888735538663203730,
+                    16170902281825670410,
+                    659928032166981413,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16937382319268220902,
+                    11269588480046894715,
+                    4738706590783532503,
+                    111451475518923831,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12507263401641237293,
+                    12363514401950304168,
+                    12415829550731170065,
+                    286178787983195753,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3661479716878690800,
+                    17226759263134801969,
+                    1051537415456967224,
+                    602187925873947431,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17075657525357100669,
+                    12308945272925884972,
+                    15729147115961405197,
+                    999750739356173332,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16652458759840197747,
+                    13261953882324365698,
+                    6101386762429496993,
+                    1283307004439393906,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11064469150162738277,
+                    13516678637483857926,
+                    3915749525964073486,
+                    539100738476410894,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3245794314031781238,
+                    5666725617419398019,
+                    13899969571856954590,
+                    630210315769053511,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13363662791849302387,
+                    4283255802612223390,
+                    12612012038111107474,
+                    330889857327824552,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14618253850184117536,
+                    4376020836497186074,
+                    14192003755973688444,
+                    588137726574779630,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12077478752109928591,
+                    2613103548990190629,
+                    7392188916977071457,
+                    288900364541659832,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9582871216245965011,
+                    17899425386065300410,
+                    18123088648877652037,
+                    1249428638675210835,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8848641955743765583,
+                    272262580652403060,
+                    1814978777076493052,
+                    888402447789757055,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6915732024704783906,
+                    2090522189347732987,
+                    17120481560807593060,
+                    47783237792411606,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10508126002370979713,
+                    1874578914489337851,
+                    4637038413542408392,
+                    352854803636845351,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6640318668380715208,
+                    2246336396870076519,
+                    1268985965489142802,
+                    609136600805964672,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4226547763601647966,
+                    6602867230632542254,
+                    14685578092124454751,
+                    794098679409494434,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11847926000340516610,
+                    3115703336783554648,
+                    5765685233883635291,
+                    1085371855432185247,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13966290739609042188,
+                    14619598724568410504,
+                    4166801196582302974,
+                    1319395585264122145,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    82922682576783248,
+                    18237671060184104605,
+                    11944380770099038441,
+                    1316863846925286256,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10802998288182837788,
+                    5037389069540747705,
+                    261476785937946355,
+                    462757017605064989,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12021100524974827356,
+                    11924581017583111963,
+                    15548700377666273887,
+                    144700431220435682,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9582871216245965011,
+                    17899425386065300410,
+                    18123088648877652037,
+                    1249428638675210835,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5073293952299202394,
+                    3722512087679444808,
+                    17601552924559043456,
+                    1061550991692010584,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13271179635591172784,
+                    14914571498609694116,
+                    15359542126794479520,
+                    1305715798799489959,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17918106228108671795,
+                    14499179655777708787,
+                    9840353946141992555,
+                    809576447066951468,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4927663562695103760,
+                    17754185535099702814,
+                    15122125244435257809,
+                    147313249003977543,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4578571169076322404,
+                    5602426478554920435,
+                    12478858653448697027,
+                    1023421572480473337,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6458661882184706752,
+                    15562269990146426809,
+                    16553914885203259189,
+                    563808506416063956,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11194939494520869544,
+                    14346437239098029321,
+                    11858842415140409834,
+                    538766381181248235,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16000409001211882596,
+                    17042671595898419479,
+                    12286469133887663162,
+                    1066890292645185022,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1248467610087595933,
+                    11577512625019393617,
+                    16276256630477391699,
+                    849317645930118297,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13372855464217139300,
+                    1926378711242084173,
+                    3170809045675110582,
+                    850723817494816946,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17215318788147816862,
+                    6888735538663203730,
+                    16170902281825670410,
+                    659928032166981413,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8848641955743765583,
+                    272262580652403060,
+                    1814978777076493052,
+                    888402447789757055,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13271179635591172784,
+                    14914571498609694116,
+                    15359542126794479520,
+                    1305715798799489959,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16529538504094395363,
+                    4852803738986240496,
+                    12247090618306874343,
+                    381805018468656912,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6113041126084245033,
+                    6371036879952984860,
+                    2398020160473182471,
+                    21054099202256028,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1660679412112636958,
+                    2016117682063489636,
+                    5376525143409843591,
+                    125150762631468211,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6215312636229464205,
+                    13994492200698521419,
+                    14120708399992482678,
+                    609570973291082261,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17477268674811377930,
+                    767148462645496699,
+                    4149483939189758814,
+                    576772740032054330,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3147265889765609846,
+                    6911731318231037726,
+                    7838758710957988131,
+                    1067197777910130110,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2853542777799218479,
+                    9473286563069588207,
+                    8778443904892760142,
+                    211630868426373101,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10441685552146618286,
+                    9204012907080465821,
+                    13902692094968213291,
+                    1296942842939993194,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15564257842234253973,
+                    11851438757056337876,
+                    14498182966561773982,
+                    553699605433652569,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16937382319268220902,
+                    11269588480046894715,
+                    4738706590783532503,
+                    111451475518923831,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6915732024704783906,
+                    2090522189347732987,
+                    17120481560807593060,
+                    47783237792411606,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17918106228108671795,
+                    14499179655777708787,
+                    9840353946141992555,
+                    809576447066951468,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6113041126084245033,
+                    6371036879952984860,
+                    2398020160473182471,
+                    21054099202256028,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15928702250674129781,
+                    11765927242825403801,
+                    9132823684419023892,
+                    575174241056319406,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8586445466364240382,
+                    15435371792731804327,
+                    10487627026784148750,
+                    521820861615212112,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8994833414180297761,
+                    14405429034032304681,
+                    373803347597138409,
+                    1324196676386553471,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9028970030908246260,
+                    11579480789366678626,
+                    11731538651579484086,
+                    963865701138328751,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14033944523922838764,
+                    17047577740190700851,
+                    1363199024518147035,
+                    655213817690353714,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16964857844992674858,
+                    12496553776207549921,
+                    2166876843896540412,
+                    916069357317022651,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9010797711204810748,
+                    19714913432479207,
+                    13793387317486510591,
+                    505410453034306003,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13733655233332309370,
+                    2993995143752260725,
+                    12397139818377719082,
+                    870917564537692824,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12507263401641237293,
+                    12363514401950304168,
+                    12415829550731170065,
+                    286178787983195753,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10508126002370979713,
+                    1874578914489337851,
+                    4637038413542408392,
+                    352854803636845351,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4927663562695103760,
+                    17754185535099702814,
+                    15122125244435257809,
+                    147313249003977543,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1660679412112636958,
+                    2016117682063489636,
+                    5376525143409843591,
+                    125150762631468211,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8586445466364240382,
+                    15435371792731804327,
+                    10487627026784148750,
+                    521820861615212112,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17463948149842811738,
+                    12244342150808263968,
+                    12388749070388826253,
+                    1187674529902605100,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9911762824133676287,
+                    6139527426994400446,
+                    2782669178737602347,
+                    125056990412128530,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13981414142142484812,
+                    15683252966011274210,
+                    16505324591487656139,
+                    910768547799325820,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1194401824865236608,
+                    15141182919266445841,
+                    17100936637125062408,
+                    1165645638847122042,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4337680694398038930,
+                    16443295134599698365,
+                    7706005616152010930,
+                    1326709347356106289,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10055284179393788642,
+                    14798441843543547487,
+                    11886119694140429120,
+                    294706106985181433,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14613752957597208510,
+                    13451557728704935023,
+                    2500249592317509320,
+                    1063810952089961673,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3661479716878690800,
+                    17226759263134801969,
+                    1051537415456967224,
+                    602187925873947431,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6640318668380715208,
+                    2246336396870076519,
+                    1268985965489142802,
+                    609136600805964672,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4578571169076322404,
+                    5602426478554920435,
+                    12478858653448697027,
+                    1023421572480473337,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6215312636229464205,
+                    13994492200698521419,
+                    14120708399992482678,
+                    609570973291082261,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8994833414180297761,
+                    14405429034032304681,
+                    373803347597138409,
+                    1324196676386553471,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9911762824133676287,
+                    6139527426994400446,
+                    2782669178737602347,
+                    125056990412128530,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7450497616153378725,
+                    15632820413818607369,
+                    3314675931915446060,
+                    11657514169527932,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7068801273369217707,
+                    16562079953923911123,
+                    3683203922191865892,
+                    224036055654917083,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11034026305651629477,
+                    7603506114687713278,
+                    18403808863034951341,
+                    258014120893174585,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15783859497365658006,
+                    15249542167560526922,
+                    18137910014978629331,
+                    226652510992887771,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15674369469117534320,
+                    5402304505756275339,
+                    11347768464848270770,
+                    807478219797394730,
+                ]),
+                Fq::from_montgomery_limbs([
+                    412778953751459785,
+                    3116023680168185453,
+                    15721389480104888512,
+                    581358278899521584,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17075657525357100669,
+                    12308945272925884972,
+                    15729147115961405197,
+                    999750739356173332,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4226547763601647966,
+                    6602867230632542254,
+                    14685578092124454751,
+                    794098679409494434,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6458661882184706752,
+                    15562269990146426809,
+                    16553914885203259189,
+                    563808506416063956,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17477268674811377930,
+                    767148462645496699,
+                    4149483939189758814,
+                    576772740032054330,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9028970030908246260,
+                    11579480789366678626,
+                    11731538651579484086,
+                    963865701138328751,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13981414142142484812,
+                    15683252966011274210,
+                    16505324591487656139,
+                    910768547799325820,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7068801273369217707,
+                    16562079953923911123,
+                    3683203922191865892,
+                    224036055654917083,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16754863821313157648,
+                    3743769676497099529,
+                    14428327729098137571,
+                    1053223799757419270,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8444331579368843473,
+                    8883058929930143720,
+                    17286888220024827822,
+                    316005386912639528,
+                ]),
+                Fq::from_montgomery_limbs([
+                    462048367362208413,
+                    18319042076365159876,
+                    8874602115044292228,
+                    1034624794061496240,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10411395242581313409,
+                    4956150527606711032,
+                    9649303464227282137,
+                    1206241743835381893,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9134023940363488260,
+                    13548964005261273375,
+                    17030085941115572243,
+                    429045919624233805,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16652458759840197747,
+                    13261953882324365698,
+                    6101386762429496993,
+                    1283307004439393906,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11847926000340516610,
+                    3115703336783554648,
+                    5765685233883635291,
+                    1085371855432185247,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11194939494520869544,
+                    14346437239098029321,
+                    11858842415140409834,
+                    538766381181248235,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3147265889765609846,
+                    6911731318231037726,
+                    7838758710957988131,
+                    1067197777910130110,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14033944523922838764,
+                    17047577740190700851,
+                    1363199024518147035,
+                    655213817690353714,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1194401824865236608,
+                    15141182919266445841,
+                    17100936637125062408,
+                    1165645638847122042,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11034026305651629477,
+                    7603506114687713278,
+                    18403808863034951341,
+                    258014120893174585,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8444331579368843473,
+                    8883058929930143720,
+                    17286888220024827822,
+                    316005386912639528,
+                ]),
+                Fq::from_montgomery_limbs([
+                    517812512490347574,
+                    15511382730424895065,
+                    4341992912047030553,
+                    1321051734215516172,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16206411411828388144,
+                    14391617164686087438,
+                    13383880841911641869,
+                    69733119005934148,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5852046616903811431,
+                    7205228883702485292,
+                    15137841759912473599,
+                    161968282585828597,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2011611498010484266,
+                    10543585133089709328,
+                    14805497434670349685,
+                    377114458205891616,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11064469150162738277,
+                    13516678637483857926,
+                    3915749525964073486,
+                    539100738476410894,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13966290739609042188,
+                    14619598724568410504,
+                    4166801196582302974,
+                    1319395585264122145,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16000409001211882596,
+                    17042671595898419479,
+                    12286469133887663162,
+                    1066890292645185022,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2853542777799218479,
+                    9473286563069588207,
+                    8778443904892760142,
+                    211630868426373101,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16964857844992674858,
+                    12496553776207549921,
+                    2166876843896540412,
+                    916069357317022651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4337680694398038930,
+                    16443295134599698365,
+                    7706005616152010930,
+                    1326709347356106289,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15783859497365658006,
+                    15249542167560526922,
+                    18137910014978629331,
+                    226652510992887771,
+                ]),
+                Fq::from_montgomery_limbs([
+                    462048367362208413,
+                    18319042076365159876,
+                    8874602115044292228,
+                    1034624794061496240,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16206411411828388144,
+                    14391617164686087438,
+                    13383880841911641869,
+                    69733119005934148,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9563766081535829291,
+                    14958849784755812045,
+                    10135449740727912404,
+                    385678612214986127,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7802104352665277001,
+                        1712233803072817444,
+                        12180777293186749586,
+                        1125441666595228915,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7330330845985706257,
+                        6947957132670498679,
+                        9373711551562393463,
+                        1140097580201425214,
+                    ]),
+                    Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                    Fq::from_montgomery_limbs([
+                        829043150522309210,
+                        7485532467658058089,
+                        17072453966431109601,
+                        610297755857677130,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18043687544558598371,
+                        8708325242362018018,
+                        16625116017321440141,
+                        277441131490434312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1903578231313020389,
+                        13252279451564941743,
+                        6429417326301386859,
+                        900077037001125169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14902495609461984461,
+                        16049616749407607193,
+                        8772353492731922841,
+                        1191393277823112260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5443236819267423281,
+                        9444106377351672003,
+                        16592192658774520588,
+                        1198721234626210409,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13217949757478296855,
+                        18361600593446830079,
+                        18223280994163007859,
+                        471593720435477782,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14891635570577939411,
+                        4930228641865103982,
+                        677506429995745101,
+                        158637391136506271,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        241833917490571947,
+                        2153702484066609835,
+                        8471674463316687531,
+                        1217041126633955783,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15611345302311497892,
+                        9150344584784175431,
+                        7017882794185538273,
+                        953114622258489808,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9667547282933221489,
+                        1431358987416662151,
+                        860165733118981897,
+                        1243816353414506714,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14540372014589060213,
+                        17579924568899182856,
+                        3401388222389887845,
+                        651049799415096727,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11830438839469277165,
+                        5441759101332457941,
+                        647144911897463852,
+                        1303406942057228690,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14900498641145447648,
+                        11460375835530627725,
+                        4587638824935521547,
+                        263674070630718999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4235908119733977286,
+                        18427294034911646893,
+                        7246209814882913280,
+                        1094113984264240919,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3556883622498099374,
+                        12292334336550983648,
+                        2497419020766355204,
+                        608196631562162229,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16029611270957704956,
+                        7692387557740620392,
+                        6380243652293083857,
+                        1185707632422454620,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15111818084496036150,
+                        1500320198469051870,
+                        9690857239102481423,
+                        1091981507216293720,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5148290445740239348,
+                        7873899951762691249,
+                        9144191574860371312,
+                        92290519567549843,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17270358174677855798,
+                        10262171729889545692,
+                        13786772088261460829,
+                        780358940232249964,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4034771480828509378,
+                        17807775568766248235,
+                        9510680804361066182,
+                        868595468050484088,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8187359342557887497,
+                        7699486077333537454,
+                        5538039548277496209,
+                        428083970212131441,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15833415111881835117,
+                        18358481507316504124,
+                        17403748657136284052,
+                        110159117314077715,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7033989652641939083,
+                        2865086508946038680,
+                        13543822285087699009,
+                        114126602583835486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8969378832847477658,
+                        6845639133954844393,
+                        6045705697695652112,
+                        496615412392792758,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        780919836531743761,
+                        2732988905978184752,
+                        12895547301667468709,
+                        244572996155859694,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12834235358300625658,
+                        7658205369259121668,
+                        6996214968074988836,
+                        275035823933999979,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        58449074335112718,
+                        6901982513386340257,
+                        6303261753340856470,
+                        423521689738327651,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4288372650557092529,
+                        12573800750674873169,
+                        184442929749054956,
+                        639346359498566144,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3885648784693974117,
+                        12213904421896241756,
+                        14679449109828638223,
+                        253624295401951611,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11314612097848194448,
+                        16543750524997074547,
+                        11436093138665511799,
+                        117133375734016459,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15627994728704393066,
+                        17774256986985196133,
+                        13328740307053530930,
+                        518526302008037976,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16078816310610434854,
+                        6537583532197951217,
+                        9087996720298685893,
+                        522990822453334752,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3725500231602670326,
+                        12843112233112125158,
+                        4602220195281936523,
+                        193120752986627973,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15213558121580473806,
+                        556858193536190179,
+                        4305504430109957226,
+                        616346757836463048,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2205476173854830792,
+                        12905065627524580144,
+                        5569730578038022058,
+                        692311678608600848,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2610516139912455691,
+                        2777157468670180081,
+                        9025899634072063635,
+                        959494706523266441,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17189023833237717100,
+                        1881743604854311571,
+                        7825036186009223840,
+                        615905920215913260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10166235633458372589,
+                        9644673869239377606,
+                        9710521661114151273,
+                        230829608597335017,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14185983070368772743,
+                        15569846710019049628,
+                        3236933263374108282,
+                        54038984025033736,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4308581354792705973,
+                        7429223985957891928,
+                        1703743420960003238,
+                        52943265304194028,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7906945455644193032,
+                        16388400350141360279,
+                        1633838775802983186,
+                        535903138548220853,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3699952292992531126,
+                        18251344860213593995,
+                        9091500102331836965,
+                        1231097328586556403,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7576978511463486739,
+                        9170328415504062323,
+                        8905826899324405106,
+                        1302638977161240413,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11410306677043671443,
+                        16135312482742725762,
+                        9273811978986339781,
+                        454283967554556639,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16245579311003982366,
+                        1552019683930711281,
+                        7472216843401406739,
+                        728451083130888263,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10019324379368537290,
+                        17425708424430062944,
+                        3478983455095874877,
+                        431969641254166484,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1037643867017428768,
+                        5928981096726701998,
+                        12719801668690638399,
+                        683860685958372547,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7556289684744093849,
+                        429042479191066909,
+                        4224999523245047270,
+                        537773583929500527,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3989603930015885551,
+                        9651160803179498405,
+                        15905651782539774424,
+                        1302417757085927709,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2000903259215847951,
+                        15059369578770236390,
+                        8104241091876173523,
+                        1307938529757936619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12222891050668317294,
+                        5983573817672299444,
+                        16739901799606958536,
+                        1093033846340823358,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1772895853038750390,
+                        16653257102271823375,
+                        16389806989226230357,
+                        335594294970046416,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2135588660340474253,
+                        18133420098882595109,
+                        3751524020816949919,
+                        663444931562185197,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16961075270226344540,
+                        16118015107840360589,
+                        5495084708864414240,
+                        497396289059510480,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9298150244332657744,
+                        13553503299515940428,
+                        13627628444287276090,
+                        115943654108487919,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8156523524372286080,
+                        13959202146904430387,
+                        7145910591601959701,
+                        1292341395397862364,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15784362205795143485,
+                        11280155021110493504,
+                        11267389987422222511,
+                        344337380173065316,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17644594131677881340,
+                        14641490917106022602,
+                        15046144971116614617,
+                        272273076796219292,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10149954178054352107,
+                        12421590805967992525,
+                        10044542380588032138,
+                        775823390213952089,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13056942940078308708,
+                        17220545180277902733,
+                        2669008545787325291,
+                        654378167905947455,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13060755343069484720,
+                        1157962347691535340,
+                        5354078249958647479,
+                        860012234115631473,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11088135714272698488,
+                        13236811966857315158,
+                        12065812311713839758,
+                        825707273066699944,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13895742713024840088,
+                        11182363232143111131,
+                        15828713899405921355,
+                        419730393220379287,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4002469402043159420,
+                        11473834943983135304,
+                        10099914150943467490,
+                        1006522558753185667,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1207864568489785394,
+                        2431599766569765577,
+                        12654870120406142000,
+                        551407515502477305,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5801812323590953164,
+                        4004939211942622059,
+                        6308886250261878898,
+                        451237230839955973,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11176839123126564080,
+                        16474463998639150754,
+                        1915660873788262895,
+                        1221064372413740025,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15193944757173313015,
+                        7661475822552177508,
+                        9464959345908262991,
+                        211208144333554342,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4066868195218373138,
+                        15510570916613454965,
+                        2496178648294213461,
+                        207777351891868148,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16121366037880773787,
+                        15890511765756816929,
+                        7511263727129966092,
+                        558125498197821708,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15765806656647922350,
+                        5759800844046506133,
+                        5204285447926929641,
+                        268192807449108447,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14928120247378395369,
+                        9287308594168169871,
+                        7625946877763673390,
+                        159143855817530919,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11658257803253004624,
+                        16257232004879009285,
+                        9063815792119763619,
+                        1207319797370021772,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1236485966159950711,
+                        17487067372606053718,
+                        17978635146968547439,
+                        184678845172346928,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10642343383154728527,
+                        1670236118252011757,
+                        1603241329796741702,
+                        75121007684302602,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5907610303933996167,
+                        349257809746536603,
+                        14584240902841529878,
+                        197410103921944623,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4116783692190173797,
+                        13714711737572702353,
+                        12610150135451125314,
+                        795148144607432179,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1268059573096634802,
+                        16136518055508509991,
+                        4013151165386611891,
+                        1329376580384690680,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1712503478142668872,
+                        18181854162530291671,
+                        5373657098377119389,
+                        1093019754593780886,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11606047902684141494,
+                        10816136978733916291,
+                        14527380288772355589,
+                        384355170622935824,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        746448934361995384,
+                        16557762742643393582,
+                        2609583598546832443,
+                        1035876239627168335,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13092553281112936751,
+                        7102381554730403539,
+                        9343268554995649464,
+                        22750203983511279,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5841998580942268773,
+                        17641922908283350267,
+                        9098911546294986170,
+                        203094538734346609,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12106497514408595925,
+                        987617566406640293,
+                        14162984923357374107,
+                        289695381496682432,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6371308080112997311,
+                        3432969071865767198,
+                        13933475780787240769,
+                        392217202298925707,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16273510134895326324,
+                        4712652325831443016,
+                        2747338063604297556,
+                        36681462412148220,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5346033350166368064,
+                        15438699386668405584,
+                        2154184184955290422,
+                        726577692297887283,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18004735195738377667,
+                        17204820237788342613,
+                        1012781431944121862,
+                        152374798059771150,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10942683110544746070,
+                        10947859852766408138,
+                        5413276092966453670,
+                        1219423366715062081,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16926707973112193608,
+                        14166675356751169718,
+                        11284716935879861261,
+                        643205744176188761,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18115816649904972191,
+                        2199195576988481193,
+                        6425008337995256357,
+                        1149887573099453822,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9293589046783425291,
+                        7112981374437716610,
+                        12493357003671641964,
+                        465866922978767433,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13016525520001248134,
+                        11550356151766267763,
+                        9033057514599731240,
+                        297732810048292421,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        259707762132914314,
+                        3384472746772064216,
+                        6737101721755218048,
+                        421812879115879391,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12270130997469612306,
+                        5737152845317513516,
+                        13987043662234157544,
+                        1019166096525938466,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10016876885316115382,
+                        6257700933866260318,
+                        362632716613571154,
+                        786752574678113154,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10402096356497770580,
+                        11273162695133777084,
+                        17842321908247073423,
+                        184680544811454014,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5010440476528457217,
+                        12179687018251123073,
+                        7445776902123682908,
+                        189150575244320646,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5221328323670174295,
+                        14489684530625192159,
+                        10886284899368029668,
+                        312270403440319283,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5950939550337383871,
+                        10307504922529325265,
+                        48847861699958249,
+                        64557306317318490,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10480076058029668955,
+                        9225618991616230150,
+                        12501325686522493762,
+                        1023197694086742548,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9148299711820678636,
+                        14680645017353031586,
+                        2929907175679995644,
+                        811239222312197951,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11616055672448604629,
+                        17587863968177797389,
+                        8818933196570149302,
+                        915421840248025481,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14859777756086584364,
+                        18093017755228984228,
+                        5181083085524362233,
+                        795076424257267086,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14099453165965155785,
+                        2410536707952368332,
+                        6399487452160208461,
+                        949627066686464500,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1738552675531594643,
+                        625743701200254360,
+                        3502055549811990051,
+                        121158422053107145,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8264995263006656183,
+                        16313040825467773044,
+                        6178158573052431668,
+                        11574779804680405,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2889836613854503706,
+                        7852117106668477484,
+                        7813252440841721681,
+                        590451276730784708,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14406957789319946815,
+                        14371081284732477643,
+                        4607824894096157638,
+                        1193040985228466852,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12675267931896139361,
+                        9273553365438913052,
+                        3186721472984555427,
+                        321478177432166696,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12347711619121322024,
+                        10672945530347366299,
+                        6668311652205512077,
+                        883693158802851402,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15319313087562241941,
+                        17875767326188269565,
+                        18344126687805844132,
+                        687930743799575494,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        113903082863168894,
+                        11946802666209362112,
+                        11741634547172487346,
+                        684578333317569435,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13334088219308162916,
+                        1338966114057283054,
+                        758799137828619904,
+                        383837378225759400,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10483507530117161537,
+                        12170279118443285661,
+                        3659520219604918063,
+                        468486529712900375,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1863822420332324895,
+                        840265013127805216,
+                        355279651349913774,
+                        1067838283252185542,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16201512991570582373,
+                        2511070582381555958,
+                        12669143687619652007,
+                        1135704985251980983,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9562799492930786836,
+                        11165498917862006342,
+                        14605976123850229680,
+                        1046709283981867907,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14370586398168431055,
+                        830023041286087387,
+                        1274334890433024317,
+                        688317941314674612,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10948038750192868030,
+                        5298441136621413230,
+                        1281515526098449985,
+                        1093636994196587632,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9570309596221453109,
+                        13872730702785507382,
+                        4762390475971556094,
+                        933884403011506476,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3131248428389814736,
+                        15306270758016743428,
+                        40988789174977257,
+                        1250085952520535635,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15630398750321709901,
+                        12175125070940186253,
+                        5604896607351800998,
+                        1066568056048207339,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15787020268375955345,
+                        14154716781134453281,
+                        4334689128952784245,
+                        43604662970506745,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6756253698384402773,
+                        11758320546067466040,
+                        3757643397816993612,
+                        1056954094024858058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14487573029402253737,
+                        3604907372338372145,
+                        9776368058615101711,
+                        1254721822562286295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7270423457569061770,
+                        13030953408784789474,
+                        4674605346390807581,
+                        1164749473079951878,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17212353292636829566,
+                        8781740311971013246,
+                        10203617241084669485,
+                        209067236435305741,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1919599999476565283,
+                        9164721879592452084,
+                        8451646767350731388,
+                        1060457113644944833,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2533132727804988144,
+                        5544323194148625708,
+                        13320137968027255277,
+                        157400833127281864,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4268685981526722535,
+                        13372054156126470174,
+                        4003886044118534939,
+                        969112667333750307,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15560026994272400317,
+                        7669828995299113540,
+                        10092576306437483607,
+                        580586899207088706,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16537394577270240168,
+                        11810063170048196213,
+                        8957795890579644761,
+                        1114778130884189937,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7743295798817154783,
+                        12097165468930073247,
+                        7523663967837574999,
+                        249670773594104097,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9627391682048587447,
+                        16003144590780079805,
+                        7536396968902121367,
+                        1260811609185011345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14430289907844442378,
+                        6747560194667692672,
+                        3263363597304545347,
+                        1224458441901126612,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6483015691033021404,
+                        14649090891827399987,
+                        11919674016134247805,
+                        803719859079687334,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3602046258813766624,
+                        6817774330964793028,
+                        17190957444288187134,
+                        537787561977139935,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13446655749762355834,
+                        3261136994430800227,
+                        15868138585910894424,
+                        51925537603710562,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11433280251511145157,
+                        12112254905388224904,
+                        776654501917085552,
+                        20055079560744246,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14210202426606703816,
+                        832958540422289858,
+                        9554416471761104969,
+                        396100500424997577,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5354982106832499826,
+                        13363193184897463193,
+                        5939923728826392173,
+                        118398948405115067,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15117493196430313670,
+                        17127296182543268033,
+                        15909269622014941077,
+                        347453054631102905,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8779002650715784744,
+                        6733183735119438226,
+                        3876117284883347855,
+                        788833679946756983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9798856367087360646,
+                        515768456057478612,
+                        3242478303199923399,
+                        694982971478282049,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13930716712695946348,
+                        15281485248398960810,
+                        7806061373786524188,
+                        601917856302884679,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8755139312413993065,
+                        14513121697842520245,
+                        12352229279003728985,
+                        800042035790181255,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17739659970831519399,
+                        12522189931683177242,
+                        11516838823855470019,
+                        183294760185560273,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12806732176381183745,
+                        8320144036785008411,
+                        3795848949795429973,
+                        604974654403695316,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3189648413282206151,
+                        7042664718925977111,
+                        18294039177030476714,
+                        855582559139249672,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        432010244615968240,
+                        12629883532619512574,
+                        9424149076604613725,
+                        912628021358487012,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14853208222179805509,
+                        17516272354826872640,
+                        9783129615473614925,
+                        244378131556144184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6494918006035421223,
+                        4930990366400993350,
+                        1302291498716182962,
+                        308894578031532557,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3755757101868246105,
+                        14602700053473014411,
+                        11484907429282515372,
+                        450501741014987537,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2739078904613882928,
+                        14658532313573894361,
+                        2127865146839285753,
+                        159418100708848136,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14339219079952003134,
+                        4544554526794277771,
+                        6802702707528818914,
+                        185040066719803026,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6958424698982266117,
+                        2372796078936276099,
+                        12980730484686825939,
+                        1094287030768645705,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1590963034544065193,
+                        6640153071603988893,
+                        4179621673764703654,
+                        1017315666933552845,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11053676830326927776,
+                        16920845059029821824,
+                        1829570861574801209,
+                        1305096872127727687,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        164115180056968917,
+                        4886576255617314439,
+                        5095117384034973137,
+                        207979289576979463,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7064380295561394462,
+                        9260909582614350569,
+                        3805708800193421526,
+                        171810792259720465,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6113035151913152120,
+                        5363769832318980167,
+                        9275812196741865806,
+                        397055816462278096,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11488916959798569362,
+                        5180145595212552437,
+                        14205932678998494349,
+                        949761086446771340,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4843194913601222809,
+                        2925351437358722535,
+                        11353353680133182111,
+                        576335341865507066,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3761532472446782517,
+                        11149830485237830307,
+                        4050666546723781039,
+                        1266548357657309053,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18221148191767463141,
+                        18428358957171920837,
+                        17430247982730722686,
+                        163298928184351787,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9535018913034598699,
+                        8651986122225287866,
+                        10962650959762283711,
+                        932914136724203111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5688327576919231518,
+                        7499339335970071486,
+                        16755942800020832114,
+                        66660640007874496,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15063267692772345607,
+                        3870385489088422720,
+                        17259681474303501238,
+                        423006728360210490,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15435566785268659307,
+                        8379256700151040912,
+                        16136197957623172047,
+                        454768453623029413,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14937140896912492156,
+                        1965563582873757031,
+                        4194376685316182751,
+                        483949469452635341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10678966512824160176,
+                        6725902865428599448,
+                        12056620190728985620,
+                        792344337802928859,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4027438029793353448,
+                        3681770428349683418,
+                        13184784812075305985,
+                        1034406319334165633,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6712989199349970871,
+                        11686436204695859819,
+                        9628438606674699811,
+                        922800442613569579,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        828579801872093312,
+                        7230602802316665504,
+                        3054272790745398418,
+                        128502963693049771,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11708468920939977672,
+                        16597870677972375117,
+                        3312636377074666340,
+                        957242046795881792,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16185907177710010112,
+                        13048671336558600525,
+                        6511618356713507126,
+                        59051285511199000,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14398792471012235399,
+                        10875513485299239017,
+                        1623841798528174857,
+                        1343296827906129815,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16555136572337105759,
+                        14293241841557034353,
+                        946614619972460352,
+                        738925261364639342,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16286253427673669976,
+                        16973929157230451109,
+                        16858608564632917730,
+                        723147153456666357,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4435411456852257497,
+                        16000458795743617177,
+                        2594299354538616158,
+                        287594973030539417,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16532642068812296364,
+                        7543694281424556310,
+                        18356478366374333207,
+                        236686857410807708,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8374804772015561979,
+                        14849364204262243341,
+                        5830114061926067531,
+                        1233722944155409212,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1121559393704081457,
+                        7618542771801257239,
+                        18376893154822286041,
+                        1013211748323717164,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2235506300687523783,
+                        58796502351825293,
+                        9429943391979497213,
+                        305826961330869494,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3644567810122970512,
+                        1919000612110867283,
+                        16778890272104628205,
+                        353157884939782039,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13784885480237221854,
+                        5264163841609652991,
+                        8168344702330351202,
+                        587709757780348330,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13894540588500649203,
+                        14132108311551903548,
+                        11227793740810530860,
+                        150350633087023819,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8897225648994347846,
+                        15122472902073251059,
+                        2424373575461366405,
+                        1012350224865395797,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17566738662542466343,
+                        10310451565815573528,
+                        12040986584351834632,
+                        658774186601226197,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        956003458159079820,
+                        2415003671022402661,
+                        2108211725342461383,
+                        646431755274846780,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8061047649546961474,
+                        11395957303124578979,
+                        6740293008780851611,
+                        1313295271682122897,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        96369654703752046,
+                        13746024164160099241,
+                        14870570087601718864,
+                        155009091691809139,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16459219732193078429,
+                        16086671488321347498,
+                        7197084995511091148,
+                        1016534062747154784,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10998281560231999352,
+                        4008301216874645101,
+                        16904953260591851884,
+                        1337919513092983750,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17288157757050163462,
+                        5651864089211661001,
+                        13971259545727992806,
+                        993213802768741145,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13369823233136575152,
+                        4165062135431278303,
+                        7828013771707223838,
+                        172917696242482346,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7986790407184869478,
+                        3171903063851712177,
+                        11722165565876563097,
+                        1018274324057648202,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1878916776135665882,
+                        9095496514090357288,
+                        16237261707223515915,
+                        9743579741161139,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17710572297287560391,
+                        10404597953132663316,
+                        13964060606638046691,
+                        1043560963540354979,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17242444951570491936,
+                        642027126232074254,
+                        2161132958003242647,
+                        600405019251379561,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5713723528391822307,
+                        15066519619091523678,
+                        12117597551929000539,
+                        1237830366467053770,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7650883371249681322,
+                        5823464083759113402,
+                        14616083470123465805,
+                        1299039201844255745,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3883938298067440265,
+                        666920366611090809,
+                        16645688190306112916,
+                        435589442745895268,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11252021254032774181,
+                        9883446321590467193,
+                        10754508706360314524,
+                        640014267800241643,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14400524536384802201,
+                        11063022035644141211,
+                        10639755730201971319,
+                        416692445368213716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9830529655688225006,
+                        254076599585109415,
+                        7285038964773912856,
+                        1006929864310105409,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6327558963950082641,
+                        3046238430708930968,
+                        11749778623057777721,
+                        464592010505406000,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6688021005021407401,
+                        3412137460857340998,
+                        1540752490831024538,
+                        1212462049390883715,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15398197717839308205,
+                        9044181738360985488,
+                        9554658630096459638,
+                        909739416470254429,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        810936752873142667,
+                        8452069048303277393,
+                        4602035653796972726,
+                        799542388511311315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8327279668778430181,
+                        12965932425217664437,
+                        3418051793408699052,
+                        965429889375472431,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8035952647743270073,
+                        15700073293237833,
+                        13112586691230739534,
+                        192450995057903831,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10819136118157897948,
+                        6350501227681164802,
+                        7187316352702014111,
+                        987024078491621217,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7594256448328848927,
+                        3759683230910952229,
+                        16273008751133495523,
+                        1282156802475642765,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11712748038290874896,
+                        7967340981876551942,
+                        17118635063063068612,
+                        318774183399985822,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6711959703088759943,
+                        11673444694829364590,
+                        15317053900714332134,
+                        771937594857898902,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3892091475367510630,
+                        9768502141521866484,
+                        4410465326311950891,
+                        339417615570729701,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10330169479663309205,
+                        4265007484732624234,
+                        13681141916035405337,
+                        1055225507945019898,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4072156269525475261,
+                        13527978723069458708,
+                        3601059339725193310,
+                        672648844884170202,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2368475971376404786,
+                        11599516411073279859,
+                        14562572813409639374,
+                        604495491090955362,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4080752467140089908,
+                        13852399229413261350,
+                        8156880440122239573,
+                        465170616010527991,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10317815954217542366,
+                        18437630369558397691,
+                        4294369142696862627,
+                        1242307668817103742,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6785067222360766962,
+                        12957115649623363977,
+                        13355539455845578198,
+                        1012289328445415473,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11829322794502492241,
+                        14069872370769974266,
+                        2939155325441534339,
+                        1005822552789695735,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17071576394218848839,
+                        14019459365700949560,
+                        3555553420273644068,
+                        593056149228703497,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16990730654247194696,
+                        9301530859662300284,
+                        16006139866173283258,
+                        498932451952621683,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7432054851016537517,
+                        14741926315007942702,
+                        1573327890290505644,
+                        667782971769679704,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13890054846556112956,
+                        3063386496686223616,
+                        8514242237430530672,
+                        155287937794393031,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14220521424482232640,
+                        13548492720651070979,
+                        13256658483334600009,
+                        727917922201317071,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7905419442824319465,
+                        16918787224492243861,
+                        11089660163319582659,
+                        411268445609651356,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        479267884784492319,
+                        7275341663495346180,
+                        5867965202949117380,
+                        373384465691373613,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15970360038026263518,
+                        3623485062429142685,
+                        11110521131248095887,
+                        444396775642668505,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14233966112152157630,
+                        6517220136427178768,
+                        4741100844372022107,
+                        1225661613263963331,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        432275503685075328,
+                        8782383980757340056,
+                        7589320435798189830,
+                        409569285687294064,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6389322584775897906,
+                        9188831600129741404,
+                        873086499233022215,
+                        1049768979420760627,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8752496909954471095,
+                        1078569673778794243,
+                        2630036950137622654,
+                        469789163406937620,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6500920521991980184,
+                        16708580113695466750,
+                        14911652353946997211,
+                        19247455440302138,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2706739832066184803,
+                        7092663001154371496,
+                        5353840350475279686,
+                        874508788477914431,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17254790602734837372,
+                        13533130520497387178,
+                        17542734811562767175,
+                        345489733080076791,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        322431095522090684,
+                        15907122414014009816,
+                        5647113285585629108,
+                        322746600644835266,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2647392030861051547,
+                        8737619668380770824,
+                        9909581024717276030,
+                        898389861580404382,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17311139450985503930,
+                        5860383442261897100,
+                        10299883627844198346,
+                        640490172323342137,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15552834567189534715,
+                        402128369059597556,
+                        8081185992434404250,
+                        1108040091059146624,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13966487648508027606,
+                        16782558835852228451,
+                        5683786319928492109,
+                        736842889631296829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        114880815112940030,
+                        14875122646665267665,
+                        6574144521016302831,
+                        1114553834494835749,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2311676110536287321,
+                        17707971998943146773,
+                        11279613639413701347,
+                        1115390388766226659,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7959542175665283572,
+                        5247529230858509108,
+                        10492305959953748290,
+                        114375293583540099,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17927807139931227506,
+                        2550832123801291650,
+                        16943651676417931317,
+                        817180569868127967,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        681086033902652853,
+                        16469393202341910045,
+                        13246808337988587982,
+                        1034824735242849674,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17400286183918426948,
+                        17675549302901675133,
+                        16724115909658428876,
+                        205939848618039331,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14319468287730795615,
+                        16886617423974951599,
+                        5602698982731701464,
+                        732939068295529969,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6278518592062634194,
+                        8291598039677626948,
+                        5255926957102465772,
+                        1175551936571982093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14978049946491524746,
+                        6135378740295507166,
+                        13734972242337274054,
+                        172000347792143646,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5756251084344368166,
+                        2965895048578728957,
+                        15647214353512881126,
+                        512535277723050419,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17610564757966961051,
+                        3346869773498835997,
+                        3164502517771185211,
+                        978937267304464711,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11987757542020649494,
+                        12487084566086970336,
+                        173919791991572589,
+                        56877635834926128,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4463394670262471756,
+                        12714986351078945067,
+                        13208914520206731425,
+                        306891290229940718,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8579983035458243005,
+                        2094073325848072286,
+                        9854120359621699683,
+                        1311500565831542300,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2293346035332560022,
+                        7410560904201150967,
+                        17125398695397918091,
+                        340871374433683003,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17633539444047419690,
+                        17631646623105554203,
+                        12429040314812057787,
+                        535975965718922896,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12272163503462718198,
+                        13496697639907660524,
+                        12080431855444431752,
+                        875604007807865645,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15311697073945541318,
+                        9758242721585248517,
+                        10915281118009655898,
+                        109275981065207293,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16300201968318304026,
+                        7657084468941118026,
+                        16902304148214918575,
+                        449050959459149946,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1248133926553852846,
+                        6182139158101728694,
+                        14186100221760133195,
+                        1165806015177200818,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12799647531478810571,
+                        13031119581977619559,
+                        3472476923904774806,
+                        71806192251348496,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5827120896505507081,
+                        1370436917961793019,
+                        13570299084042605012,
+                        1245984539279314881,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8036026715034479424,
+                        8021221674753160478,
+                        8292742075368861442,
+                        962701278897240727,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5478539001697233171,
+                        5618415476013291170,
+                        1415246542169037286,
+                        393732267758295075,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8210696784163368068,
+                        11197335592220709522,
+                        2544412700355379899,
+                        318974309960873383,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3167472124776379743,
+                        7849775606962026063,
+                        7883519989609549300,
+                        553196666873419964,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6479660346410404368,
+                        1506753224522157415,
+                        595273931174394436,
+                        760721682275983895,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4376733860671397700,
+                        7413066670898372269,
+                        16755829855169366282,
+                        150558670551385752,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11901009618836122612,
+                        4540920299264745431,
+                        586522073565496570,
+                        246770229078419790,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12355312295949193368,
+                        9190530281470523488,
+                        15307706785908380090,
+                        743250429105338189,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7192944080248224050,
+                        7444920964609645223,
+                        9832065232239173695,
+                        189034508049807799,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8771193592095180646,
+                        6052518613325203569,
+                        17407954773682992714,
+                        418025897750418268,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4467184682823093424,
+                        3637506772946240525,
+                        1749957948843896833,
+                        838126423410799166,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11933061943689192454,
+                        9461823531877969148,
+                        12932366166938790360,
+                        1333839985829134796,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13091949571458454636,
+                        16500334499572676113,
+                        1936016903127204797,
+                        465860802475789794,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2794072740470691735,
+                        11583822081547428584,
+                        18078604335849559298,
+                        309257705676247473,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13106205476135514634,
+                        4146832058802410567,
+                        6786171539012437042,
+                        1305137230494522607,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1489839887158517871,
+                        11375057282593792825,
+                        1234619747060967213,
+                        451982711046597108,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8681149394055662828,
+                        17558539776703790278,
+                        9155029312390385015,
+                        850428525449600373,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12280454599347081830,
+                        6923906067278388157,
+                        6051504274046806670,
+                        320174128703103777,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9994061404591798167,
+                        15129438352637168142,
+                        4565738184160806821,
+                        1057811921791174686,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6723866092435562869,
+                        11143113635895217877,
+                        16741500050047671859,
+                        266547966479865447,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        967878104290702271,
+                        17337298239885623817,
+                        5521657913271165803,
+                        173782879467985898,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8658329087871383040,
+                        14813492838854308623,
+                        99547014132786111,
+                        946534435005086200,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13155228622243633004,
+                        17420414175032649974,
+                        6408114346210177227,
+                        1061344202541020440,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14669859619932504185,
+                        12614454943107589219,
+                        5419636599524830010,
+                        1255350508141386705,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6892442724438756864,
+                        4644520541371078563,
+                        8393926136039518843,
+                        1251388605468543444,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9174691637471519687,
+                        4140133796275121479,
+                        3115862535320024603,
+                        927854023211796014,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12794729598636732361,
+                        9670234819699162316,
+                        9654129138583254450,
+                        169678802241976536,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10739277592050889247,
+                        17091291352283207745,
+                        17335435313769275954,
+                        1200009624541550754,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8116879448639147089,
+                        14834167126943239928,
+                        5167465455143222073,
+                        871352141334710415,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6047403010000130670,
+                        6586078179227323067,
+                        15406924796918638710,
+                        1137423475760215732,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6156240545067026902,
+                        14353973030133068969,
+                        3871090612378087834,
+                        790431276451172631,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7402722234020893628,
+                        7408240853799246306,
+                        9249720426474895311,
+                        677932470838587014,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9364734949969161204,
+                        10160830749720105804,
+                        17992529040071256713,
+                        1229746287038611749,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17671615863334899899,
+                        6644917283465276626,
+                        12202696888387787840,
+                        1142073329191787770,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5462744518718050085,
+                        14790785413173180169,
+                        13864401605641854971,
+                        876702987310905600,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8607617049801945450,
+                        14348863709340068694,
+                        15721199854134718049,
+                        575570818909994322,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12375055177641949579,
+                        8260804785789774760,
+                        3133202567132232573,
+                        861455605313364319,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12242660451862939216,
+                        2321426050362368836,
+                        3147695798872010905,
+                        1042910946815865094,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3521552787858538548,
+                        5813095006248521306,
+                        11092714461867067165,
+                        483088756307511119,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4299500154461491774,
+                        4764543097504232288,
+                        13223133494111440589,
+                        1259924302311646010,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16468874128306694324,
+                        16295726874958375642,
+                        7177260657639841350,
+                        127346967086414834,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2450698985857938231,
+                        9345524735590277778,
+                        13068300729361374904,
+                        830218230316953299,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10851225877312538113,
+                        15161055884641362547,
+                        10491953296377053824,
+                        1318708948906110080,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2856056568246568366,
+                        2942065385114930754,
+                        10384275682620789276,
+                        29204083194600437,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13448795131273106481,
+                        9048697148253978799,
+                        9942171005465811140,
+                        1268087096647040258,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13836763248671622316,
+                        6424167932389787203,
+                        12378722510430142727,
+                        1099223157417753945,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11926063401218858713,
+                        4875892737923002311,
+                        18207737292325343619,
+                        807529666988461973,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6919624121392590379,
+                        8995069486144839405,
+                        6066747076173044816,
+                        397263783862967774,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5450971206662796167,
+                        12768273334642753742,
+                        5479790118797704290,
+                        984265866683996820,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16161059801856963717,
+                        12893720806464919976,
+                        4614064391068977838,
+                        928023344626245887,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15092092767946885874,
+                        15341918420813081256,
+                        186678392399212989,
+                        529406797865476493,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17587411922758080020,
+                        2532682157940225090,
+                        6679941490457594744,
+                        1096360397549119469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10495722214728708338,
+                        1637085186646163342,
+                        13348572195701688265,
+                        971090246559492101,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16295651430036485329,
+                        3711810899841402069,
+                        11328013085464815850,
+                        1156410645146782432,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1505158851682944556,
+                        2475590415233618775,
+                        4079739095760924576,
+                        842619348404712752,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2435366368427291305,
+                        2456959709846794799,
+                        17190574259390689394,
+                        159028244941008757,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        166846668280319173,
+                        2543107959938460589,
+                        15057934640908872960,
+                        792461229739658093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15171363630905789632,
+                        15599982522889633145,
+                        15821345718660557846,
+                        723264911880027653,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1569059791393106036,
+                        17619497883270492882,
+                        14610713067734852683,
+                        169435966974704877,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2832496925445645512,
+                        4199266788280342242,
+                        8829431048493349320,
+                        957224288195474687,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4954283538223836276,
+                        10799512259929281883,
+                        12232783987094280940,
+                        788924054206338866,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18400038306505069495,
+                        6862717370646321106,
+                        5865640352120381771,
+                        879143177216105641,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4943714424226547170,
+                        18368328847596946761,
+                        18008633977573603754,
+                        171150846128727277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12263290696410929960,
+                        17102407428011228948,
+                        6414203950617280873,
+                        1008290215203583882,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13890013033577183460,
+                        15162567295186232299,
+                        4552452826349477744,
+                        21637802506445910,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5333570111329400928,
+                        15252003378849844352,
+                        10384010667784365349,
+                        968137799152083695,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2337901526811771264,
+                        1994494891824740619,
+                        14745783504144379483,
+                        1202607710393641603,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13148512134200171951,
+                        4868106455079243598,
+                        3005610404064805941,
+                        340249623285104233,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8135098304519625768,
+                        614327061970677992,
+                        16611063304402355568,
+                        879554723388217330,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16225541372697908240,
+                        3839910051783791186,
+                        10866188991995350054,
+                        622020947501635442,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17547183110196420447,
+                        5104328422337245754,
+                        7078657816739515959,
+                        252431115075283562,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1694471499564373984,
+                        1751291285583405278,
+                        14067365889494644114,
+                        81329295352495612,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1849235134715527059,
+                        8060658178292341185,
+                        17191866988555954730,
+                        991686543199887942,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        88066108537487041,
+                        17945283313862457776,
+                        5415907081022007909,
+                        47128004949179636,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1131886066246648896,
+                        267170516166669923,
+                        15667184722635645734,
+                        687009844047770382,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8123690142345925147,
+                        8977061916048561373,
+                        6243889201707664355,
+                        1210281331279511447,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4208047923345910691,
+                        14289271052195203064,
+                        7588976163574480160,
+                        679803690363792495,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11184607732004055249,
+                        422459469099504170,
+                        326559198086572092,
+                        1189349185017111840,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8896602971181243779,
+                        3210456366674244057,
+                        16533539486509481772,
+                        830969500948288866,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        702615050804640598,
+                        193879020555367281,
+                        4067308035628508374,
+                        1271641490547235604,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2046188173918999808,
+                        7861794686771397328,
+                        13518479744865121901,
+                        816370680824412458,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        549550223063570283,
+                        12848877961097413568,
+                        4754935105013854694,
+                        636585660077932217,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        717531525068920206,
+                        3792178026225569634,
+                        9033660070731838938,
+                        1143509578125684286,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15331422682978508531,
+                        14844066893821987568,
+                        2276002624212091654,
+                        439685462709296135,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10480099274043454900,
+                        16067204266945727635,
+                        10152937135335526538,
+                        1153936839135618641,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16950620930135461159,
+                        15621246648308179358,
+                        17989321410386477793,
+                        287632962507717705,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14280920830534333931,
+                        457911278529342898,
+                        15234212839329206217,
+                        1155909553295434589,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15932371776261164254,
+                        5592329862830862342,
+                        16930779874751480686,
+                        419614162212216039,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        779320359080588477,
+                        124492544737700245,
+                        8963254620275492322,
+                        154832551426693408,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10179645407857439502,
+                        9651448438103548053,
+                        18309612547978125850,
+                        467272568150981692,
+                    ]),
+                ]),
+                Matrix::<1, 12, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4870524792098753916,
+                        10271879902816670259,
+                        4204669517627433289,
+                        388579338760285595,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17835267911814226197,
+                        1539826846016138341,
+                        104362175260456384,
+                        51689152158723923,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11087424402332133005,
+                        1684240454331218705,
+                        2064012908378144809,
+                        259100799285512306,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16702237384418286474,
+                        8446461812735218417,
+                        699286092191784273,
+                        107879729680657815,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7135126329525282052,
+                        9402339101134765432,
+                        9389083866909614348,
+                        928543564906833162,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7020707600103065640,
+                        9085861369258298974,
+                        1075396664649134818,
+                        566194522641280578,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16639515016055518515,
+                        13944873024934039056,
+                        9515108458816154456,
+                        1206173832807974912,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4991817997437226987,
+                        13253803815626745828,
+                        2324703853706545753,
+                        1010555090858855276,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8865127515999233722,
+                        12015144970937531373,
+                        139939197313671712,
+                        746695629127616490,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12586321218018014115,
+                        18322052002394671610,
+                        3080883561842229714,
+                        935511947312018883,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13626695582271603253,
+                        7496515090032768988,
+                        2464152853307241454,
+                        329336183774849577,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1868432701006282783,
+                        954813510691056351,
+                        7072064432144741123,
+                        923368945902624573,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12844567717592844275,
+                        10012778178265293652,
+                        7879998048570482860,
+                        19623228098164709,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4463614096393202408,
+                        15524282777039793927,
+                        3891121405977841185,
+                        1016482375447525435,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17557705359768926993,
+                        161910628347263066,
+                        15217645725283406572,
+                        796921329996968619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18078193985085943358,
+                        11532445054590757270,
+                        7901440968232805219,
+                        996853071498040248,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14973467041236660061,
+                        2769474649933801071,
+                        7486638538413412772,
+                        480712674277630600,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8799601002142550471,
+                        6262284423266154917,
+                        11872520694997151592,
+                        442040283428895618,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6763729357486795124,
+                        15345754222608674590,
+                        1964705425185461018,
+                        1084982934448361138,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7969432885398756902,
+                        4368325708740636236,
+                        16736201055274197456,
+                        375763586546973951,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2213781644650116184,
+                        5050556572068049816,
+                        5476929779637429312,
+                        176737858351791111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16886420711850332276,
+                        7159944011924785978,
+                        7542719154085731321,
+                        387238932730938283,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2234428878566421938,
+                        10960871944053832944,
+                        7594738321706507824,
+                        138985813016684722,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14558569232177166635,
+                        14523033443484228582,
+                        418341920675413036,
+                        1265359036450667524,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14668811251127479793,
+                        2975218851335511761,
+                        16797564062725415415,
+                        99605796608149793,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15916060646609140156,
+                        11866893168969408104,
+                        9272497971178169379,
+                        1136398693139961550,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1791510238369093210,
+                        10155737753237843879,
+                        1088471921839233305,
+                        1141515155939875587,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3881258217128596118,
+                        11334942096494600283,
+                        17198769478363329660,
+                        973270305914086698,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11419041001187038473,
+                        13248592539139708035,
+                        15271196790668181985,
+                        311506895754200812,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2940380212636342106,
+                        15656615747987783188,
+                        7037060760761491537,
+                        397927511842094541,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15371429714437155312,
+                        7800246038470904486,
+                        18270036458051694720,
+                        181064247627371935,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9327818013752561839,
+                        16145875283185895418,
+                        14914247357453600737,
+                        280558332596504918,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10621128043950309994,
+                        13025726695143138567,
+                        5178879532821920753,
+                        1111797936736978930,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2060170060720000637,
+                        8340741939337401920,
+                        10074512805801544480,
+                        539557840827246140,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15386093746482825459,
+                        6796053488516079698,
+                        4041329748594739411,
+                        241679190887668765,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11983307158417045233,
+                        15679901514880065153,
+                        10840806361659866331,
+                        965779344640745535,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18189477107989916689,
+                        11506506957082285911,
+                        18383442969484879919,
+                        15436171146230046,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2828887656085113897,
+                        14668913284340293982,
+                        16757020771939783531,
+                        525780427214276901,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9610177786174485042,
+                        1793285737371029830,
+                        1773549430329826673,
+                        143046921312134732,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2763110687625701312,
+                        9115497595692162980,
+                        2367816882370135193,
+                        1339375037269406585,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12980231869503304111,
+                        9725272069866843531,
+                        3682678074298545302,
+                        44731661072551906,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6476211811603120708,
+                        14875150952365609076,
+                        12148892241223129951,
+                        854591421758351219,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8985744644015334594,
+                        16905672330908661792,
+                        7778784349715554971,
+                        311018402096229738,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12969462477387203239,
+                        5680857646018210952,
+                        14684721291261544814,
+                        305532647285821749,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6708163195967003715,
+                        3126215061900171371,
+                        11669865463445850071,
+                        95554542945648057,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8166328161695324785,
+                        5880337433548180337,
+                        6726750950441334341,
+                        1270681060270644513,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5067697789065567715,
+                        5195974691398546571,
+                        554658230889064274,
+                        500852654093533517,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8703028471675615317,
+                        11624314974951917672,
+                        16539179421278926535,
+                        1018674617568654113,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10640579667637724385,
+                        6780622053426369512,
+                        8007618041489297193,
+                        792004285745993597,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12793997438613119156,
+                        3587031370184100012,
+                        5357235249323511079,
+                        173645768302583634,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8027091900718741282,
+                        5992798249240023176,
+                        11380943321198974575,
+                        27898029685624861,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4284510423268411479,
+                        7881092764455814460,
+                        7284292158602807785,
+                        1289890094442936878,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13559088235651128347,
+                        9537029855668383249,
+                        8597339837374633972,
+                        1217815256903407895,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14326947803423640148,
+                        1182460000100110428,
+                        11831598140053472493,
+                        290887307755613343,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9258799083376752482,
+                        16464476117762141966,
+                        14103335316418944947,
+                        1206707872055224393,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8363845945351452731,
+                        7237547453791972609,
+                        11065481845256015869,
+                        1211887529098286860,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12924649127060947402,
+                        16248656621603287926,
+                        14153802692823978328,
+                        517724033429893305,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7856877451360952160,
+                        1998496713696381874,
+                        11963675336152969564,
+                        910133039385176282,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15798945096107607223,
+                        12651223467682513152,
+                        12896762015340341467,
+                        952957966478912241,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5235649186523036889,
+                        16700044057354221232,
+                        17648369056352769056,
+                        753670711632982416,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17943757831584207752,
+                        5183678138932733329,
+                        18035054051359029980,
+                        888661386576595125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5868914220711894749,
+                        5955708376936583349,
+                        15701592504859344193,
+                        609964302375910088,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15772018779438839330,
+                        17395596183429218506,
+                        434618130163814590,
+                        396234676109498991,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17163056293830043520,
+                        5166162880516947851,
+                        9853692677436558607,
+                        1275701236603858999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12704047971823463471,
+                        2821055726520802316,
+                        12177797866078284098,
+                        712476181210976180,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2066838410032338319,
+                        4819280041198119097,
+                        10314378662777200344,
+                        432866693249084224,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9089786333270403824,
+                        7933440930536350654,
+                        11004959817049281662,
+                        16400901393596830,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2458798691132390919,
+                        10501326409705390227,
+                        1298393366889738467,
+                        1423701760992446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7387787809634210669,
+                        5098015132003579752,
+                        9414622185366828618,
+                        282123316515906668,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9810662563327092976,
+                        17209433021653849129,
+                        118637376647249083,
+                        91639715896046180,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1408379856993260285,
+                        11678114818302713457,
+                        12918637118231571486,
+                        194655093845270443,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8503928866915390780,
+                        17852473142107932806,
+                        9442177240713316478,
+                        478164370784459738,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6311396414356582490,
+                        15789528824827309578,
+                        12737245486257538660,
+                        239747674394325670,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1143357697298610004,
+                        7707156539806655746,
+                        7567617906543169868,
+                        1219618237004304309,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1266021057789557530,
+                        3418933753287602451,
+                        10731608457376115301,
+                        177939943660402890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8998613974396395510,
+                        15208837405729360513,
+                        3893329782937517216,
+                        736400999124262857,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5375912980306921342,
+                        8359883994452520859,
+                        5877252576910431544,
+                        275922259492086570,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1631710567717057120,
+                        8220435341895698599,
+                        6641996786155099770,
+                        246935506228606170,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17635070866129224401,
+                        17368361706258869666,
+                        2681103276836220346,
+                        953745877320025631,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11196084063587565378,
+                        13232420520996658211,
+                        14618429210439427507,
+                        345751776627651132,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12269229884761070372,
+                        18041680377297219385,
+                        15548795746672564899,
+                        717347260555604782,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1378755933735632151,
+                        706625408031421504,
+                        3220535267686167779,
+                        1327118344968637982,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8389687139109531848,
+                        13639690529791593119,
+                        13226828183853340543,
+                        333636969815559021,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9818876913950516982,
+                        8353059662303516569,
+                        7961368049143896124,
+                        1230971617665102854,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9846681184558975707,
+                        4117560126200559779,
+                        1184867364985049086,
+                        788087524179549510,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3780268625441162661,
+                        13276403940206213057,
+                        7822680580940714163,
+                        1054308603662570828,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5807117473800204592,
+                        3736247430649895973,
+                        4364405793344850964,
+                        292288216834766135,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1632847376589359125,
+                        6744841113288490390,
+                        17437740187368220000,
+                        488541107202168354,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9177067667117872054,
+                        14509308886922451289,
+                        6131038865849081297,
+                        996392670631346727,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5730891066228071055,
+                        9152435529569846144,
+                        13566146218519562800,
+                        43109015339425686,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12354830883377169424,
+                        9990457916804799355,
+                        18178649179854380928,
+                        658812967431940440,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15797770842141132440,
+                        6802577461221701122,
+                        638917896253231513,
+                        777895484982419479,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10863845339373363375,
+                        5169713283150814490,
+                        12666611413300235338,
+                        1170277673977683564,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18283126895111087888,
+                        17177987598556306681,
+                        11000259780329933609,
+                        1214815632590634414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2413610520980708636,
+                        8247475414124958550,
+                        3946468625936872280,
+                        838240286467612831,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6071488292374357874,
+                        14874047955299692455,
+                        2691996919931257480,
+                        365140534723147538,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8674614209478126888,
+                        12591056172012588814,
+                        1710257493318151501,
+                        509170901685473263,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18328170040044791792,
+                        1463971861233970234,
+                        5876461923793859095,
+                        1107783211928977741,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10954993789146357375,
+                        292892319522500457,
+                        17351734903665603724,
+                        629355280875483555,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9476895924779877031,
+                        7919198509626958922,
+                        7848287902215163910,
+                        341982870505313395,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17048153861185065090,
+                        16824088090571929339,
+                        10065430473978018230,
+                        777772684561504217,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14089216891848569110,
+                        2631039910066995425,
+                        4857990031674770119,
+                        990393841357394971,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14482020030756477114,
+                        17766118374312214984,
+                        11759114396527591595,
+                        694482059398364972,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4940429049789406714,
+                        17770153698445478293,
+                        18062553532258413357,
+                        404787574469051766,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15420374148988405283,
+                        13646256950496919163,
+                        10926006876957290730,
+                        792132930678662457,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15066693611697049051,
+                        12348759981162364542,
+                        16569898108867227034,
+                        926678541944864966,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3573823714696487195,
+                        7097079782129220978,
+                        1004854802748566781,
+                        397160068779663296,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2761155376391189127,
+                        12485409409535773369,
+                        3089805542455257652,
+                        966556859952174268,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7713905142430119785,
+                        13838201095247772904,
+                        1274952567082735846,
+                        1130378719578195687,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17168581245826462420,
+                        14011803880816910300,
+                        14726140569107679425,
+                        1238232081316639169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17358897955845820624,
+                        235942128607467020,
+                        9840283934424011122,
+                        690957379982294399,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7469212181807962323,
+                        11001438089904436567,
+                        12270410436028890124,
+                        700697211908974184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13278934868198402517,
+                        2619367878265447884,
+                        15735911863902989500,
+                        138082783737348108,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12377955814848810049,
+                        1025885043640425406,
+                        13527178740893329756,
+                        801113352064489923,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1519957392578239527,
+                        5730690017212288930,
+                        14603885199865501958,
+                        549343255830390967,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7616223624745316637,
+                        15222984134195265761,
+                        17470789976486853137,
+                        1187041021591914655,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2292415560101239902,
+                        42396785210815766,
+                        4172507298809339685,
+                        986983603322539177,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9895725907284771882,
+                        2944601951191111946,
+                        5669533659332921528,
+                        691671628768706080,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7775176968683361486,
+                        5524344200530455409,
+                        10476625181996084832,
+                        153702866116221182,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9096883598461687506,
+                        11915398314425657690,
+                        11105667098312709148,
+                        493937322379969782,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16372237484576373499,
+                        17251302127252317,
+                        11787204787799757725,
+                        1246771401609456529,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3716549375532134732,
+                        14650468011406532450,
+                        8911405190756730821,
+                        331533141921791683,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12661357714822292804,
+                        16343389063559756174,
+                        6554791108567856095,
+                        922675861399826248,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10948139879663310448,
+                        9506654629925011889,
+                        3106026865439068347,
+                        1261465845074226591,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12549870130345349038,
+                        2041455404435021992,
+                        588111809467366407,
+                        129423737950082341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2811587396106588279,
+                        10177494553800514259,
+                        7402129493843979505,
+                        809651166605916281,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        911418900733917230,
+                        10077506289227080985,
+                        15874131885652441980,
+                        935085120550907283,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5517549767144750271,
+                        17746094345210579065,
+                        8023353941318336215,
+                        443649438111706093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        831421380037750989,
+                        11047745918797311714,
+                        11507109296045220031,
+                        438158492420417565,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1093559117637411278,
+                        8839487971545715557,
+                        1894329244459432430,
+                        439393010572640865,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1248436221974558400,
+                        3027160217968915146,
+                        10775937951031688301,
+                        974125492145505146,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7912911222600140432,
+                        6147813301508247612,
+                        7384579798374423865,
+                        207244748743164061,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16404316921447468520,
+                        10766086858515219392,
+                        10422943654303973266,
+                        518109950951257690,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14824567278173522031,
+                        5441441996266215306,
+                        16735389376806675388,
+                        1224406938724014712,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9193478756933547456,
+                        13860675604418803509,
+                        3628957977768939899,
+                        222934093047137727,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12183930254412019922,
+                        17327814753480815024,
+                        10601431247453232841,
+                        1268583131752904686,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16582876816698154581,
+                        4439030985102812133,
+                        17969785742732840259,
+                        713694937676933926,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10062805474130071289,
+                        14337612199624376885,
+                        14278023458726679210,
+                        173941056739746405,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1398283366532216255,
+                        17074989682608394130,
+                        17884905463683294639,
+                        616056054384449553,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10164439027652101331,
+                        4555085483879760311,
+                        4724519274404145775,
+                        341872502092190446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5771028007007636471,
+                        18236890354843524418,
+                        6031742694801057032,
+                        140731250325635567,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10265322575647623581,
+                        1269606621283038633,
+                        18242062307147808502,
+                        62149472392294586,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3739932788500372117,
+                        11102270464093922249,
+                        2669274962428907563,
+                        392000074339818737,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4777693691711335691,
+                        9687619495853801048,
+                        9942200273943657098,
+                        941904754518921075,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8277711340980384367,
+                        11911197768196239039,
+                        17693009594081137747,
+                        910624518162202965,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12358751932779246878,
+                        10245144024446499585,
+                        9970866600383748906,
+                        22121427389411200,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3712962076509686675,
+                        15567000333562592029,
+                        7829587840270689536,
+                        51565942701728710,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18232975251620791126,
+                        6794540470521945557,
+                        4726403871940567547,
+                        429870958742832503,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4251055012370391321,
+                        17233082258467477641,
+                        14214959694698856805,
+                        1156472321921276843,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8556078832554098305,
+                        5884678400678147872,
+                        9669322555695993918,
+                        308700017612759322,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7764037661316935299,
+                        6250443511586339898,
+                        11775607871661552916,
+                        717537126611059070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6537922392380267266,
+                        18179650679724476675,
+                        10269233630974856885,
+                        459211970242745464,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11231732758681995642,
+                        14981347796146440736,
+                        3464415133704248931,
+                        648180206175662267,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10908510304199465587,
+                        561319230517415930,
+                        7191468651049590575,
+                        928270127350073239,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9252935230574706318,
+                        8958553206671987510,
+                        2853928888903026959,
+                        1086583969038236968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9223488548925006892,
+                        12183876187700412215,
+                        4547321825119317253,
+                        493466914314035004,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18015558607654993517,
+                        4934149976027206500,
+                        897962484324152140,
+                        1059164364643574706,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6752675930912046749,
+                        752685405953473042,
+                        16827317982659799171,
+                        813993134739560677,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8085919512574945461,
+                        17686836191208013340,
+                        848392735824973010,
+                        955516352141609193,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8595241983179931049,
+                        4596797961723479421,
+                        1093335816239586392,
+                        112693192524195414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17474794637770509334,
+                        5575361747603919191,
+                        13062648043016658462,
+                        774219161029906288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13921677434156075482,
+                        16156647276772684915,
+                        16529101620226050989,
+                        177001754617613214,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1950308307186964221,
+                        4800096872195934132,
+                        8092652798329074828,
+                        1055593837416952683,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16456532026900415461,
+                        18303616189143851038,
+                        159394002112425864,
+                        1136483394525677304,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10410847965910978357,
+                        4109461676283534263,
+                        13301864288645259562,
+                        360791366410872015,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2573077058972732921,
+                        18363799749448616981,
+                        7819480453035881704,
+                        59610981690715562,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12407583884951893227,
+                        12059476471573543193,
+                        16688729466015823807,
+                        1282595595118832134,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12079985957614094198,
+                        14504263290052528829,
+                        14972536475331444067,
+                        998900328528507624,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18178638121445990964,
+                        5274467555932032775,
+                        15265867241948823287,
+                        708507299810464257,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7342169732634289449,
+                        5332736144358696056,
+                        15330854924421969776,
+                        765722075172782698,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15513220439884159007,
+                        11423094246664521284,
+                        16195242653010599209,
+                        445963672879031275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4916805920406538346,
+                        3071466962140193032,
+                        7707517968406686591,
+                        455466943714844297,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9019353939442689264,
+                        7785939298382182156,
+                        3923010960489323357,
+                        346314253324917513,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8615853160925560016,
+                        13715080337489080383,
+                        16821750867685456512,
+                        628329379924453376,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14911462253773263623,
+                        12773683120614253960,
+                        1470519891431331791,
+                        977736271925853461,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16281602767287718091,
+                        14527866475906504183,
+                        2960917842251661022,
+                        1067632607015536735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3033671692742808425,
+                        5507441361166709989,
+                        14635584855685675446,
+                        925194783259431654,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4289766848226987020,
+                        5593353604638028080,
+                        16263623706920432745,
+                        644463515881834287,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10419514493101504945,
+                        6561574277472758447,
+                        17668420725514278090,
+                        767090688592783672,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15330336008666904439,
+                        7171789745145230487,
+                        4057099952033981892,
+                        280391042402447815,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15798328117242233384,
+                        4034672114166930012,
+                        13137587024001811138,
+                        1051696135455806182,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10344071052554281285,
+                        7987630805131612088,
+                        5392504580516311307,
+                        755795145821188191,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11413061232111482348,
+                        2752144529315965513,
+                        14321616127298723904,
+                        35824591995906887,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15520493576211953201,
+                        4968810072080563175,
+                        10092301202277917347,
+                        221749270435408386,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16894257330369190711,
+                        7295264525928088328,
+                        17809399099563952855,
+                        333613335114163166,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15307140522722790499,
+                        15558223672416819638,
+                        391278007777309320,
+                        306880972107227719,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12524640455618383324,
+                        17935334991771579407,
+                        14162821334681765026,
+                        596052136569824387,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11570485515950384091,
+                        786647232316533550,
+                        7462795448551395981,
+                        622305395315780282,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12711132167294018881,
+                        5551627813072972219,
+                        8194411280192563284,
+                        1089206382580192471,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13514484728785371939,
+                        13032863294200910572,
+                        7691949795393002569,
+                        1284476690380505176,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3049184213107431994,
+                        18349521866916705933,
+                        18381939465741816525,
+                        1216234400345348439,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13767763562539541119,
+                        13778954035655707384,
+                        16704844998861399995,
+                        763649967550534881,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4557020697601249790,
+                        17407957817456484747,
+                        18197973418090909606,
+                        893656826977335486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7759797110580075858,
+                        15076477507462874593,
+                        1232354406713761984,
+                        1012809236392737214,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13778884568523224318,
+                        1397103046005278818,
+                        4642165888144759125,
+                        1147053529541405761,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12000963673574656667,
+                        14592102699494186144,
+                        17853676766363040926,
+                        893817562420619804,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14583706797777172092,
+                        5834229091240150822,
+                        14372355465567143372,
+                        380662769171929822,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18010964435924050496,
+                        6152944818032691811,
+                        15384640264714177249,
+                        387388483268573093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7094003511079870934,
+                        14769956458922715283,
+                        16678224626016743428,
+                        956920373629668437,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3109182654755129774,
+                        16850754203229695993,
+                        14765682807546481667,
+                        311704707146229652,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12451683642243013038,
+                        8156142263457826897,
+                        15531229993352488970,
+                        874890554479245175,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17607973550904191119,
+                        6130695969732609473,
+                        3498564374758422188,
+                        362261040498583277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14617864437241666429,
+                        13481701760745165731,
+                        1175504981021113486,
+                        1322980676867904391,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9928948030037091499,
+                        13370313545026365287,
+                        2482965961411351173,
+                        497537505219370697,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4947092017213847282,
+                        3587810291350631839,
+                        6555599310968214790,
+                        1141104605622252485,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14253170094756441279,
+                        4164080378394891241,
+                        17294959712811869730,
+                        1155922224159252968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7439672363799953110,
+                        15689661766436725681,
+                        14700469721554585181,
+                        1155023637466418943,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11534362577046298054,
+                        13672218541264300351,
+                        16401530756615004128,
+                        197592672006823691,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7006239254135287021,
+                        3976857288960956145,
+                        241741671522844770,
+                        1289791148973512885,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10092900366445104568,
+                        11036250204501857813,
+                        8172515551305147421,
+                        962842414412014028,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12448351866049968305,
+                        7571920044871473080,
+                        1466470021731191574,
+                        556571984074106805,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7349536166423403949,
+                        1763534498949376101,
+                        4860891077344966300,
+                        160615791467407280,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10238237393410864058,
+                        2777835467961968479,
+                        171753554444127278,
+                        984457293737515089,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12023468961986601183,
+                        6716535818260615971,
+                        1842788422867795762,
+                        187312674033428521,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5599240845137230650,
+                        4208494349445935189,
+                        8006756411914613906,
+                        382573822113660637,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13871023811033467490,
+                        5903772994733185044,
+                        5320393809973372694,
+                        862738411302547755,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6799566094380918053,
+                        13438644208727613307,
+                        4224538583125549031,
+                        701816216319125156,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17410294695496194637,
+                        17356103683559261650,
+                        10438181552058567704,
+                        173401162309006899,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2226506568571077853,
+                        7930435881352354741,
+                        147770925965286300,
+                        1231564670561052384,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1844912860209751011,
+                        8897294623561555905,
+                        16486841713840839077,
+                        1203551566462267466,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        305978412530167473,
+                        6692346337160387694,
+                        16227790728051128018,
+                        325174851149540061,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10051365972961467778,
+                        13110965622551413459,
+                        3617589192492727334,
+                        523270977429722830,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3467712621909319847,
+                        2216550973895989292,
+                        14613032368183383696,
+                        434566463802269324,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6985026408991823601,
+                        18198482927532542439,
+                        1409840235901011434,
+                        563191030309288326,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9813233819799211105,
+                        6276623294836078357,
+                        4687140574293683800,
+                        681989605815487324,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14965237370326242978,
+                        2846473664846260505,
+                        15134871454195633081,
+                        1228822187749358215,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11269646033568733795,
+                        13024761286578335342,
+                        570563598430028014,
+                        269480274100060298,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12483633150405053418,
+                        15355536904729855223,
+                        7850949439193061980,
+                        1125004994275938701,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9272638363033882144,
+                        8753999659705465605,
+                        1757326086701293802,
+                        799659040249009647,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9903400954809466634,
+                        12587019999763151977,
+                        15808743776112958903,
+                        405881923075912668,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5367434402393958719,
+                        3047013030255431076,
+                        1912587018683223158,
+                        423941954997610846,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7378849646239969477,
+                        15999541095403833060,
+                        5401697385663646839,
+                        563173091739100929,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3356637435409738590,
+                        257590775043472544,
+                        13587233457060791852,
+                        161655985960570736,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1510779768330845226,
+                        7019438112950777259,
+                        14649779083219917924,
+                        646967259038796686,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16661318790744323949,
+                        9428522000797095881,
+                        4205335220285094007,
+                        1227662822961355590,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4489243826302893208,
+                        14256983209778670669,
+                        6407459368740650063,
+                        467987538533448809,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11490991734394931189,
+                        8152191889442896913,
+                        7442091706480315809,
+                        676694091570367955,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15140613546384640069,
+                        15180705408599786979,
+                        14648030907657100000,
+                        599368079895109714,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13883440662192633060,
+                        16738758411747085195,
+                        16361931594986587894,
+                        1067586819359772773,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8843003464970287866,
+                        16226073403952894739,
+                        8925589454398331343,
+                        1328340934594875990,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3745136541198152591,
+                        6561067970621455490,
+                        376684275310656328,
+                        1166264397764398164,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1388916615087959578,
+                        4926816372329635212,
+                        9509725388215261037,
+                        353901000538994107,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10200607338908771909,
+                        5479644737554450352,
+                        4350567019198706781,
+                        555085414678734475,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16208759366064446324,
+                        3765616031530605332,
+                        3383554223409314661,
+                        402339250064621921,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10557070325024523493,
+                        13308320780709990972,
+                        4577708888223996023,
+                        702662350544872264,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17783031883320227488,
+                        13441141592425327200,
+                        10695200938034481404,
+                        231998584505541192,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7146836749288188572,
+                        15085575716214754566,
+                        14084662619055365971,
+                        342256666788756948,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1531678923492849814,
+                        4555609734684647059,
+                        13694492256582041931,
+                        992362297192041126,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9310552746739163048,
+                        2881396380045523206,
+                        2075984923818922867,
+                        399650341763655027,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5776529953999789464,
+                        18309508836664064899,
+                        15817890365538559805,
+                        1311061309149325377,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9540451162831293001,
+                        11553953120945258455,
+                        9784041029423288103,
+                        996886623671258907,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11103847442583193014,
+                        11500310482658661013,
+                        2096414989518288915,
+                        1195079499531258523,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14379981399953162319,
+                        365632657142261309,
+                        18292713718441194515,
+                        1281112032201507814,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8276987417662281531,
+                        15354298044768784263,
+                        657382360032274285,
+                        1109221196280266922,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13888968508097238550,
+                        14994955604239129952,
+                        9605168521510041369,
+                        585710226381015436,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1283434941790675300,
+                        14839751369657203088,
+                        13043237311740103917,
+                        294163919948056838,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11908431433766061129,
+                        12675833398559709738,
+                        4979233616002921987,
+                        1187586961381055625,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3534575398244613243,
+                        76635614588527694,
+                        16501148438367631768,
+                        403899668644836384,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11120498516097374220,
+                        1864823059677421966,
+                        12260556148807343681,
+                        1085130175752974870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6932036100190735277,
+                        12582921594167211552,
+                        12470164570663947777,
+                        642894251594325982,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14568718485416531250,
+                        4805469485329690910,
+                        1701639047813930154,
+                        552445444636928567,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10016314263520509674,
+                        4456347577932646421,
+                        194908366700100794,
+                        280963501089704804,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12987617895684420734,
+                        14302841230268186516,
+                        9984627979292100405,
+                        486029600229830730,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8023082414617102212,
+                        4080402404212336385,
+                        6483051730181012479,
+                        211562817870928727,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5297778272893123530,
+                        546599192219966023,
+                        13020777066211153092,
+                        245797359132931505,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16096350819840927598,
+                        10066914860650500066,
+                        14968486499550570240,
+                        620420694668685118,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14289369756215657080,
+                        656452978503232227,
+                        16955695373151358223,
+                        135480349905268739,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15163909788644348357,
+                        15713882724264788557,
+                        15154825799241574589,
+                        576737841868156692,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5104920849868205893,
+                        1426098883694792196,
+                        14318842319257470135,
+                        1247483151694593208,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4589827851895744738,
+                        8173571512348974560,
+                        8876355687972896892,
+                        511320405351587911,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17739518617204742539,
+                        17271542727349565201,
+                        14477290918410601460,
+                        775894205468960236,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18338806671696375864,
+                        5961226647481729920,
+                        1264237183491603891,
+                        1323496899146910482,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6837741119214117423,
+                        4856138864117928299,
+                        16788098054579659182,
+                        460802547299212546,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15348866152852774388,
+                        6265019364051677761,
+                        851516426156596777,
+                        1105830436770904295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4548308737722338502,
+                        3642107953295416494,
+                        605160499596224553,
+                        718366003586722777,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15380904184699784073,
+                        15127500901853850971,
+                        12368090830492140498,
+                        1205486081658316541,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5337167433570287872,
+                        12572697121216927384,
+                        16097830931630218049,
+                        577612648738547185,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15730104490660111743,
+                        3211192000688048106,
+                        6490462597001741704,
+                        1193513427796917600,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17650079287445064428,
+                        16726773213954041720,
+                        13664408100769405273,
+                        845637203319789992,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        823026543188473186,
+                        15284561845178397047,
+                        7458360902973590402,
+                        594655078064501058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9238752475891193583,
+                        10125769062329350649,
+                        6168583393293527530,
+                        13477958256449022,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        910155221727557200,
+                        17209834139032426439,
+                        4412200698821791387,
+                        1026852691590749582,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8937050872603959246,
+                        11412368408048020458,
+                        17440358632923711381,
+                        538545024652769586,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4219222306163519813,
+                        3487120906746885899,
+                        16812216117884175504,
+                        388678112168247695,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4160933866678627782,
+                        6466873597571324731,
+                        12034230201809305610,
+                        1236154254425147395,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14185015956522414948,
+                        13925127196584437690,
+                        10112998396036762727,
+                        1063440573465929739,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14208844450947486092,
+                        4758591329885008506,
+                        17271160775064528920,
+                        303333947885004782,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14648012396754050661,
+                        10382340366176493668,
+                        8013066629747062956,
+                        241622852138156727,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2678605882686947994,
+                        10533980861310910539,
+                        1641575386214106196,
+                        634338222080453909,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12576749438921977358,
+                        1574235662326121879,
+                        6308600550663974165,
+                        160621058964541222,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13399165380863742637,
+                        7365761218069963570,
+                        4215370406351134236,
+                        323616385321845366,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2654263738577322500,
+                        4857089549414105800,
+                        967839574416852136,
+                        1337832936403974405,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10128167415158148732,
+                        8660349828997879463,
+                        18386807852415040124,
+                        494695116800446799,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8195730082702332247,
+                        11986330718082905430,
+                        7597469911153620956,
+                        9367754760612605,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10484681269504508465,
+                        3773589804423943948,
+                        18137677149854437376,
+                        290446113210717455,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16363138776727936195,
+                        12365851402746113759,
+                        12785333721963623959,
+                        928744368262196171,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9494891321858800801,
+                        4058422030570848001,
+                        10143807127843532403,
+                        9035439836155998,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3546579407574963437,
+                        16109126792079502647,
+                        5715301445443792735,
+                        837011982415602997,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10997343413725882782,
+                        1452622304817255859,
+                        550650769416859883,
+                        678348028665558330,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17589728033910033838,
+                        3501496804707206309,
+                        262890544259819928,
+                        430921343513878357,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15005063945945778399,
+                        6612489286488107434,
+                        14996601493904271621,
+                        173875297701477869,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3333944119193164296,
+                        3065708752174774710,
+                        2909229840960633096,
+                        63626078064645279,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11311676901840589806,
+                        1470753883291944451,
+                        14827265660395208348,
+                        306687080111780564,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15743342284604111862,
+                        5128194736403924193,
+                        17787865207410891763,
+                        1095285774601586339,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10895431555585809742,
+                        7762582427512434839,
+                        4128686168564640929,
+                        802458285352410968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4769009107927670330,
+                        17293816037449936595,
+                        8080292033793671493,
+                        1148729637792749530,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12915003659219013322,
+                        325612212862241203,
+                        7035833947447695272,
+                        823996757297144910,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9501617906922215912,
+                        15890795626258427048,
+                        4179521926356164675,
+                        252946479243124758,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14932149232309880527,
+                        8969019823943714306,
+                        14740368784072159781,
+                        196382306888346942,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16831660495011979289,
+                        14431697452174413467,
+                        11629994329972386433,
+                        1343932970983572802,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16602367632006823734,
+                        10961818653457673587,
+                        6147819886833480470,
+                        132501092824645041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13802115858918915563,
+                        459517346614600958,
+                        12696685316780876888,
+                        911172892185503583,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16994420263387561657,
+                        6881705418751830311,
+                        5985610480667182811,
+                        864048359914992552,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11867706908388248544,
+                        10921264747705753006,
+                        10691640668895789773,
+                        1271864177362122657,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8938569685212984964,
+                        4523624304851219719,
+                        10930058526414660940,
+                        86562196406050038,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4772971479695063936,
+                        15746547956293953985,
+                        15139746670892923004,
+                        601812659915661709,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11472588727935359493,
+                        12643821589135464299,
+                        1364426886403232964,
+                        361722069272156726,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13718956141003543097,
+                        16053055507646511752,
+                        3747623685679238446,
+                        1028455622283534362,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        377178474590987104,
+                        4632566023255019686,
+                        485886510968944957,
+                        714124569029392774,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4957679809853901453,
+                        17971987608483155254,
+                        4061268396003831006,
+                        443308365845436164,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13631162150991571790,
+                        17267110251010349043,
+                        16584110418479440043,
+                        434147305736527822,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5232502450624792667,
+                        12465455813312688023,
+                        63449087288669898,
+                        855068464205692621,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13661587950513420073,
+                        17783960212434851178,
+                        6265939919282093994,
+                        992655377102364776,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5127872288124010575,
+                        62199867158538071,
+                        1286914626823179288,
+                        787610085984620876,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14172584245147053193,
+                        298104447876951323,
+                        4089794738315352623,
+                        1211564909264409996,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13277863481649568553,
+                        6140798623950150394,
+                        7340824623783645061,
+                        664220311802656793,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1774522496164112228,
+                        6349734942074261484,
+                        109645467990838204,
+                        45840274239457527,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10772449460705202647,
+                        9463725448416086644,
+                        5795839256950822131,
+                        650221594377431557,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7385486979546281937,
+                        8949068371428750420,
+                        13174089799117017554,
+                        206666595863700565,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7918880481030740153,
+                        10480772395593144721,
+                        15703297947147773360,
+                        423093975173473963,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15845551904284994209,
+                        12725004554732526689,
+                        8294988772933756373,
+                        186825779176304338,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12678717710843440580,
+                        15722718776290563405,
+                        12233784530311902054,
+                        671264616951519422,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6137924750476008065,
+                        13977038987785138539,
+                        11955336978268916818,
+                        295232332548322825,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        576469414790995800,
+                        5979806622694568416,
+                        8287178820535560248,
+                        578925430350492116,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4451894515869318777,
+                        13844422567877742154,
+                        15487122959007347450,
+                        938907959828451081,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6785712080528773346,
+                        14659687011243850052,
+                        16114723129064454185,
+                        198269425319942928,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11298190021958225522,
+                        16661723180136853932,
+                        762597839474195443,
+                        343308887724069532,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18186011605957522748,
+                        1327917573955485621,
+                        13480456663695600847,
+                        724788692191170474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7142569544073196700,
+                        9621049318551099529,
+                        13205093441728412757,
+                        296698407619731866,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16827416076748495285,
+                        12493606241833747337,
+                        8611053630208129493,
+                        591601298206611295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9792526837990900229,
+                        8599521830470980847,
+                        13977113085392283600,
+                        629966314897374331,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10525840956629747528,
+                        2516897193242592125,
+                        4615238861857759193,
+                        1288882640696429913,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9037880369372222195,
+                        5088556840236993029,
+                        12590376817117540110,
+                        754363162470408991,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9803351114520087436,
+                        17945248642764253785,
+                        8428896304314686809,
+                        97455559351430019,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17760309327253425302,
+                        7380688688322130914,
+                        16905556896739815325,
+                        735336796471613711,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2999938627493451122,
+                        1766056475027077069,
+                        12320048939815834537,
+                        790361473308413910,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2973795474938431768,
+                        4636384750498417923,
+                        13969114392753449465,
+                        628834541936656861,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3769976762096377867,
+                        15171632674304627936,
+                        8436145552746910449,
+                        1168935487825524059,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2228060154185260216,
+                        17879339655608430787,
+                        2487186751055325706,
+                        583871290157379756,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11965305380245501387,
+                        7860742085608334520,
+                        6782634576945216998,
+                        1096377453047183482,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11702299334829893689,
+                        1563248449168757416,
+                        2090496323194576429,
+                        1002063188095491749,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8098691422608746374,
+                        7376132682197837441,
+                        6188295896482372968,
+                        811352076340705291,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15718915152855458755,
+                        17726584747702115835,
+                        9156517107886934698,
+                        334835455543263325,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6033522281368360658,
+                        13449491879764067378,
+                        339921963663613625,
+                        525103847140029800,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11595018856141404976,
+                        8655334089599742041,
+                        5329517837744219634,
+                        738027196826006397,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1935266850572581431,
+                        540357697130551411,
+                        17697414771088501484,
+                        413039876827016187,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4171831599304722110,
+                        17043036579924952394,
+                        10649808531818413638,
+                        1109870835637190391,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6016790411922911700,
+                        5694980960893235533,
+                        15071769565059393562,
+                        173681823339051809,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5902728925848793968,
+                        12919592178297462838,
+                        4867989872963527824,
+                        171577394106381395,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2538906406602678785,
+                        11667353400792002144,
+                        11168949029159825681,
+                        818383557999169071,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2433244922829516073,
+                        11520194526545153818,
+                        10947216366338545362,
+                        1100706738170949205,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11727219223836056941,
+                        6195550478413438307,
+                        10373129966976506628,
+                        298963026648178413,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15488472264647883884,
+                        15285541359470892517,
+                        14542564241495931964,
+                        1152114244210302210,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7966880553724839838,
+                        4548058611184798567,
+                        4634274323778020467,
+                        563490703352086619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6839993284210966418,
+                        14788234398215418912,
+                        11420155731641736066,
+                        71292167457650438,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6015232751948642812,
+                        12825855899665145608,
+                        6966837883910440962,
+                        1287778737599190477,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18046294868164365831,
+                        11666607027576855014,
+                        12391104728926620063,
+                        700242941041755453,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1559318892638638259,
+                        9279917697080705073,
+                        2388190184402921482,
+                        1020592488676264355,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16532209417441919876,
+                        9780646240207508156,
+                        2021918289798336219,
+                        613315862703697938,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        685420397973645278,
+                        10642823992444579275,
+                        13997084897992687302,
+                        178150281772242495,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17453618998785015358,
+                        13945589245882020,
+                        1707019960023865843,
+                        125535725979640207,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5501855648219500131,
+                        17662837199866032283,
+                        10097694608020978288,
+                        181727031677559933,
+                    ]),
+                ]),
+                Matrix::<12, 1, 12>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15747885902937528141,
+                        12775637018979205306,
+                        17769629050352797186,
+                        445024613955225299,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5806979258360703415,
+                        227361957869816916,
+                        9879968011618842489,
+                        436909830715417695,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        899289731007283774,
+                        17377519632648256100,
+                        13084507404281290432,
+                        271986207847980110,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        328495859117012912,
+                        9644987140530645239,
+                        14864572859592538111,
+                        329745834777850775,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8619012523616345142,
+                        1129486263235246587,
+                        13721357172534779942,
+                        1057977388981467987,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        794107824735774222,
+                        8803201127122876736,
+                        1847211364117097596,
+                        551317764587933754,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6854989586099227226,
+                        1477120608638503870,
+                        14874919096065072240,
+                        1224045545939066291,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        141873555565122398,
+                        5762734346174456648,
+                        4790478799648946436,
+                        302985512997892833,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8001337180130315105,
+                        1972398928621437330,
+                        3185562410564457192,
+                        993790934629116382,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11869539864600563180,
+                        5491911890489966855,
+                        3432988671147871325,
+                        291866556807431276,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1583177731413957967,
+                        17335117353174950807,
+                        11043688638520019746,
+                        256959507083949082,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10557269457709916666,
+                        12093886972705652523,
+                        13431959226803486032,
+                        488122545992027102,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<39, 13, 507>::new_from_known([
+            Fq::from_montgomery_limbs([
+                7899589069007061071,
+                577396428671901092,
+                9661558962678495189,
+                346960094046901596,
+            ]),
+            Fq::from_montgomery_limbs([
+                8308149155987396087,
+                6937895683605094570,
+                10986006096945728097,
+                896234553296298157,
+            ]),
+            Fq::from_montgomery_limbs([
+                15272404063596757082,
+                7814139948628342614,
+                2320934006248137633,
+                892381195986839601,
+            ]),
+            Fq::from_montgomery_limbs([
+                13741810145087622900,
+                11238852996714373859,
+                15771328342155855008,
+                831992989441707761,
+            ]),
+            Fq::from_montgomery_limbs([
+                952421083603895515,
+                11041584410218406934,
+                12802753445159368535,
+                582055393791945972,
+            ]),
+            Fq::from_montgomery_limbs([
+                3007848351212284489,
+                949272350630896601,
+                14265667718824942593,
+                722198829055988353,
+            ]),
+            Fq::from_montgomery_limbs([
+                6979648075045902349,
+                2497090708498336055,
+                14679167270013869122,
+                144867084590015769,
+            ]),
+            Fq::from_montgomery_limbs([
+                5823753818196686752,
+                281703859448568238,
+                8690399989670783436,
+                102481388962980988,
+            ]),
+            Fq::from_montgomery_limbs([
+                13093250294523357021,
+                10250911395406648058,
+                10457105889586564708,
+                1300656848179837584,
+            ]),
+            Fq::from_montgomery_limbs([
+                14521058288503478411,
+                14260297630959293718,
+                7290229544653488408,
+                1188521120781040545,
+            ]),
+            Fq::from_montgomery_limbs([
+                6873249444525237205,
+                3097449335597123319,
+                9953629433727332001,
+                616643893454883107,
+            ]),
+            Fq::from_montgomery_limbs([
+                11952368789382535985,
+                1114491751318714863,
+                15045916753867394705,
+                312907562559993654,
+            ]),
+            Fq::from_montgomery_limbs([
+                12311985704566262088,
+                11865623165499947375,
+                18126487934431458473,
+                249122531215864562,
+            ]),
+            Fq::from_montgomery_limbs([
+                12007264327434192557,
+                16175089475082761934,
+                1627929504681688313,
+                880206346066771783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15433572352699832033,
+                12363038002464184229,
+                4057216038533184072,
+                1306710721718853340,
+            ]),
+            Fq::from_montgomery_limbs([
+                12155793935576216581,
+                16556466942468011902,
+                18338455610012080617,
+                667232655556413603,
+            ]),
+            Fq::from_montgomery_limbs([
+                4446605230312520221,
+                10012058186162227930,
+                11574897079571113975,
+                484637833578956236,
+            ]),
+            Fq::from_montgomery_limbs([
+                2432830609503040452,
+                5808986834106169575,
+                11596330043805130436,
+                1122634109277862447,
+            ]),
+            Fq::from_montgomery_limbs([
+                4550304615123355763,
+                10298869568626957880,
+                1058093468802816278,
+                795839015661633822,
+            ]),
+            Fq::from_montgomery_limbs([
+                18032707717928615266,
+                12161810223966456570,
+                6252179702064496028,
+                720323296500710459,
+            ]),
+            Fq::from_montgomery_limbs([
+                3557809765040744674,
+                14853435844540677078,
+                17983495423964589804,
+                391971696552378555,
+            ]),
+            Fq::from_montgomery_limbs([
+                7587533779489273825,
+                4154794124732357961,
+                15003823867310812694,
+                234810694893143202,
+            ]),
+            Fq::from_montgomery_limbs([
+                16108571030019841391,
+                2269873372961933373,
+                1505226792826509519,
+                384874539121616915,
+            ]),
+            Fq::from_montgomery_limbs([
+                16732692445281483935,
+                16256663946695208349,
+                17534157668509230987,
+                234327135117826633,
+            ]),
+            Fq::from_montgomery_limbs([
+                9580697613370001990,
+                5563778377149078368,
+                4924654992608226107,
+                691694170303194134,
+            ]),
+            Fq::from_montgomery_limbs([
+                11033415722732979369,
+                2678323396149495756,
+                8143146102096266034,
+                1220331541606279561,
+            ]),
+            Fq::from_montgomery_limbs([
+                14990156583630501941,
+                15195200654231438567,
+                2375828669419341470,
+                1028650649633476983,
+            ]),
+            Fq::from_montgomery_limbs([
+                853984491366247739,
+                6444427760297195029,
+                17436117193835116079,
+                948630576619562789,
+            ]),
+            Fq::from_montgomery_limbs([
+                4305200131265163896,
+                7621815613905571088,
+                8012719878295845466,
+                159166700033733088,
+            ]),
+            Fq::from_montgomery_limbs([
+                10036277559670040690,
+                10142025966482450403,
+                1595098361065470123,
+                1101061277218164254,
+            ]),
+            Fq::from_montgomery_limbs([
+                7369069681877314703,
+                1392535229635644382,
+                16084030607208707493,
+                436152251348990856,
+            ]),
+            Fq::from_montgomery_limbs([
+                7740793666872350959,
+                15906561111471528644,
+                572507581727332683,
+                1164441990058982101,
+            ]),
+            Fq::from_montgomery_limbs([
+                1295319021403171469,
+                4344207847465005979,
+                7888576438948362564,
+                1298535038857826865,
+            ]),
+            Fq::from_montgomery_limbs([
+                8838046643575773297,
+                11616331061968118980,
+                7682166250940627324,
+                100200291480803756,
+            ]),
+            Fq::from_montgomery_limbs([
+                15144448265090719441,
+                9403625006593706676,
+                786725459894456126,
+                836801213291348797,
+            ]),
+            Fq::from_montgomery_limbs([
+                12503555616934832730,
+                7345872578397045773,
+                12356288280733440785,
+                208308756026382815,
+            ]),
+            Fq::from_montgomery_limbs([
+                10580329960683822384,
+                12746350879013617271,
+                2761010704390601285,
+                196512715050884667,
+            ]),
+            Fq::from_montgomery_limbs([
+                10134623468553464121,
+                5910386690596272912,
+                12646954701604491207,
+                1158379505386968766,
+            ]),
+            Fq::from_montgomery_limbs([
+                1637337835997529961,
+                17601109495714221156,
+                10206162083573464715,
+                518374385095081113,
+            ]),
+            Fq::from_montgomery_limbs([
+                12575334179238769458,
+                5336038529436105804,
+                4711123437071426512,
+                1044633700574273480,
+            ]),
+            Fq::from_montgomery_limbs([
+                87077827916576594,
+                3065080445669604803,
+                8955009626207189643,
+                366057003645921234,
+            ]),
+            Fq::from_montgomery_limbs([
+                10816897855357854024,
+                3279298514593027871,
+                11417180538233072047,
+                1085470081710165145,
+            ]),
+            Fq::from_montgomery_limbs([
+                11044356612896447876,
+                17924943751760977145,
+                15111134259969931925,
+                843350692618310504,
+            ]),
+            Fq::from_montgomery_limbs([
+                11295951267499731131,
+                15998071993107643259,
+                15858761717824859403,
+                187275798996626791,
+            ]),
+            Fq::from_montgomery_limbs([
+                4929980450973491182,
+                304184989738873363,
+                2527855375641266062,
+                673425778523333009,
+            ]),
+            Fq::from_montgomery_limbs([
+                16685862168918431277,
+                6810950192186913504,
+                17369247511156460659,
+                886115933571683785,
+            ]),
+            Fq::from_montgomery_limbs([
+                3414255904374161906,
+                14073917629129657249,
+                987373786190679715,
+                584425157964605506,
+            ]),
+            Fq::from_montgomery_limbs([
+                15021379487478033777,
+                17004127049646921056,
+                9358954867685976699,
+                944728371203239890,
+            ]),
+            Fq::from_montgomery_limbs([
+                319123098909590003,
+                2637356664141265130,
+                4421420848760393198,
+                539618272152041217,
+            ]),
+            Fq::from_montgomery_limbs([
+                17796832916936056630,
+                3385435710473134775,
+                5025170591613587801,
+                1012371949098515468,
+            ]),
+            Fq::from_montgomery_limbs([
+                152545010414933160,
+                13501387011146440063,
+                8531844882401696245,
+                973157602634297979,
+            ]),
+            Fq::from_montgomery_limbs([
+                12998492373590997176,
+                11446682627092737715,
+                7326447103904667532,
+                1126877146180880708,
+            ]),
+            Fq::from_montgomery_limbs([
+                18220830645582160083,
+                2643354381175549889,
+                15425374017078246304,
+                356661347757826736,
+            ]),
+            Fq::from_montgomery_limbs([
+                6216646264775751874,
+                11288551617798799092,
+                15965674824704349122,
+                238890683001625137,
+            ]),
+            Fq::from_montgomery_limbs([
+                7905019557267229523,
+                14929441755478316460,
+                1675030588777398078,
+                993693248998944460,
+            ]),
+            Fq::from_montgomery_limbs([
+                11055338021419093386,
+                9486001951792429438,
+                3729468292842893004,
+                668355833557332637,
+            ]),
+            Fq::from_montgomery_limbs([
+                6628612119257915248,
+                11311409461354301172,
+                13235164655667793806,
+                382140040316134033,
+            ]),
+            Fq::from_montgomery_limbs([
+                17275172743869135939,
+                6711672319130501673,
+                10204482930106409102,
+                992849328152996652,
+            ]),
+            Fq::from_montgomery_limbs([
+                5379517664254292494,
+                6257377395101538142,
+                18141629632722719505,
+                715291488716328368,
+            ]),
+            Fq::from_montgomery_limbs([
+                8823720702103091048,
+                2555648352645875955,
+                4451282888297196180,
+                599266472154410003,
+            ]),
+            Fq::from_montgomery_limbs([
+                3561239954239587260,
+                8735142809235141641,
+                129216406047469910,
+                926325883618702332,
+            ]),
+            Fq::from_montgomery_limbs([
+                1257492207602672064,
+                4756710453019782300,
+                5477687374625976705,
+                1009809259245879012,
+            ]),
+            Fq::from_montgomery_limbs([
+                7021264438979759294,
+                960956956129915968,
+                4725521661728874067,
+                1241784680085591776,
+            ]),
+            Fq::from_montgomery_limbs([
+                5171302353115861717,
+                8372851279235821813,
+                12010322552299670118,
+                215212573396577709,
+            ]),
+            Fq::from_montgomery_limbs([
+                5537296520497299024,
+                17358891553464109530,
+                3717783485693203198,
+                1116290310710952213,
+            ]),
+            Fq::from_montgomery_limbs([
+                4901143331467112453,
+                7030564363305715829,
+                14762644143987398597,
+                1300435975611854973,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7347792402012728472,
+                16005484914148355625,
+                13107878634663727164,
+                1012726091833960650,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14625718172249190166,
+                11123128155639751731,
+                9337292635817706084,
+                1222014566053888254,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2775247269740829478,
+                10262411386362707906,
+                795579769855175299,
+                372893744311095891,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3600414763680526783,
+                18343091705505476767,
+                9683480010701937068,
+                705990692620836336,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2933275596189768899,
+                16754007273347892566,
+                15654891041972112918,
+                414486730781908853,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9486602833092820755,
+                16949605732819233587,
+                3513603920046363337,
+                456776470473997203,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4435293752583521147,
+                13905017552438906379,
+                13618084323750903814,
+                410758644640304576,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10253017407540914878,
+                14121157795863453085,
+                6699369285601575635,
+                582103306756824775,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8927212430501368381,
+                11061252902951431218,
+                4939948996415064044,
+                671276368250261449,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6043990250264851368,
+                16863443916132909261,
+                10206784548027510338,
+                463701225119889248,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9914655606780194354,
+                17867474357437510892,
+                4080684784018121912,
+                363798966306477827,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6613009255627555870,
+                9895781091338504096,
+                12242481845302012207,
+                588053223962188352,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6517110765906321817,
+                16671979194178882693,
+                11172165745661845617,
+                834063793948028348,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14623836546503297912,
+                6056395682597972806,
+                17884682558080078818,
+                477667679773405810,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5583062042332751312,
+                17503244304817542963,
+                17947696681329662344,
+                235974116280287551,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7661121551453853096,
+                15816854937276734123,
+                2427287508001790697,
+                349737555068783564,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3841402432782523683,
+                6687552920502425732,
+                5385094618539061386,
+                792221650389218238,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11700196131010973342,
+                1237874741654158624,
+                6976749561596816080,
+                1309496901576304390,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10100199709988942982,
+                12726503954853691887,
+                11414701967608731987,
+                579900573006461547,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3122476094904776053,
+                15858349350608131574,
+                3108637284112036661,
+                148783116694635585,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17833823707603167729,
+                1752113648821614691,
+                9613325270654139896,
+                182420542752971604,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2028584780427654886,
+                12056103885103921088,
+                5210882390988200120,
+                1054899129383916726,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                119016020477036017,
+                4310503358137183140,
+                9517923945535031427,
+                535020225364451057,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10823346907360237504,
+                14039818811636095409,
+                7438459383224674770,
+                686311282452978785,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12202485557802405410,
+                14988094622125604258,
+                7292097503808975583,
+                489264233774673611,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4739514375405474530,
+                6053087865772831242,
+                9649753517353606535,
+                418040163772417163,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16921945853388554671,
+                90829728408356334,
+                8995254173219691986,
+                731808660190318265,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4487627186811855435,
+                5294337326200699191,
+                6877742224001879858,
+                719664798921059610,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6522181923945966846,
+                16973185065529542041,
+                9545135165797211851,
+                129874201169463209,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8428906360912225340,
+                1075098032632283254,
+                14045547843599349917,
+                229954545377905757,
+            ]),
+            Fq::from_montgomery_limbs([
+                7200055720989310021,
+                13960663493106979434,
+                6342628078740842513,
+                461895691637559237,
+            ]),
+            Fq::from_montgomery_limbs([
+                9735454405555596743,
+                5496454677771809821,
+                15106584150195796125,
+                1043919422165554049,
+            ]),
+            Fq::from_montgomery_limbs([
+                15318217589646137453,
+                16189470105107333555,
+                7521246472597893144,
+                754724648236605577,
+            ]),
+            Fq::from_montgomery_limbs([
+                46659049368360189,
+                698750861271777148,
+                7010220269793120098,
+                766834960124979076,
+            ]),
+            Fq::from_montgomery_limbs([
+                15416362734687139405,
+                7532084927548180288,
+                2983574162683126123,
+                215794927405097222,
+            ]),
+            Fq::from_montgomery_limbs([
+                1551835063637399781,
+                3770970662051752674,
+                15728956629499760569,
+                787079062303660933,
+            ]),
+            Fq::from_montgomery_limbs([
+                889893873296231531,
+                1319004907763880767,
+                17562007358403026498,
+                384082339582244663,
+            ]),
+            Fq::from_montgomery_limbs([
+                14214777911745740373,
+                14054937888561726488,
+                14547548338309157460,
+                961340243542668170,
+            ]),
+            Fq::from_montgomery_limbs([
+                3467333797216422536,
+                10085847317910544480,
+                13328961897947055256,
+                58578248184489661,
+            ]),
+            Fq::from_montgomery_limbs([
+                1770360803004582984,
+                15260169900876158712,
+                12135484658485623231,
+                133507092316926038,
+            ]),
+            Fq::from_montgomery_limbs([
+                11797553629020564091,
+                11807473812586764690,
+                10994735422767846939,
+                1331647501239706078,
+            ]),
+            Fq::from_montgomery_limbs([
+                11260140172946169548,
+                1475085352212205420,
+                8034603969845432310,
+                931623022739251106,
+            ]),
+            Fq::from_montgomery_limbs([
+                2400521166097179752,
+                173310169246384125,
+                545289732518555046,
+                311417748329776550,
+            ]),
+            Fq::from_montgomery_limbs([
+                13071550821831212137,
+                15188615390759460733,
+                4864393670155831926,
+                1013499469358726020,
+            ]),
+            Fq::from_montgomery_limbs([
+                14058787913699662244,
+                17822569799945723086,
+                18029828148898019233,
+                605257418741003382,
+            ]),
+            Fq::from_montgomery_limbs([
+                551282213788223346,
+                4594966201156988883,
+                432114872194060182,
+                732215059480077525,
+            ]),
+            Fq::from_montgomery_limbs([
+                13737512758462949676,
+                15619558084974839125,
+                1356096242578918233,
+                757523266232535117,
+            ]),
+            Fq::from_montgomery_limbs([
+                3655542303323119543,
+                12201746510635950022,
+                8654641316888702883,
+                9978881942296738,
+            ]),
+            Fq::from_montgomery_limbs([
+                2239382325552601919,
+                13507007011379094432,
+                11848251214710104590,
+                430113423684354821,
+            ]),
+            Fq::from_montgomery_limbs([
+                5951549527978271817,
+                4087571781582321103,
+                3126175559368625842,
+                1049176591073559910,
+            ]),
+            Fq::from_montgomery_limbs([
+                965693838543553578,
+                11306287683005854746,
+                9786255620587508103,
+                226097048614352913,
+            ]),
+            Fq::from_montgomery_limbs([
+                3082023837598981097,
+                11514711527568659344,
+                18083802609995806591,
+                1170907943473709670,
+            ]),
+            Fq::from_montgomery_limbs([
+                8238274665580880255,
+                15127698059223950963,
+                16410207913279017822,
+                672040077957242268,
+            ]),
+            Fq::from_montgomery_limbs([
+                8391582243584206759,
+                5043556674227534492,
+                1979290207634174554,
+                184387387836309549,
+            ]),
+            Fq::from_montgomery_limbs([
+                8690986127698324718,
+                15012540417290473995,
+                7141508193361533219,
+                194655473508835774,
+            ]),
+            Fq::from_montgomery_limbs([
+                9797430095087124473,
+                8005803375845646696,
+                7478137525079349342,
+                39066476702049914,
+            ]),
+            Fq::from_montgomery_limbs([
+                986327011756921333,
+                13665214489205068645,
+                8477208854997892859,
+                27609895459642675,
+            ]),
+            Fq::from_montgomery_limbs([
+                15448973151246786399,
+                13320701183361721282,
+                2279802873042523401,
+                297969266721263852,
+            ]),
+            Fq::from_montgomery_limbs([
+                2123022781665005167,
+                7797340282540441941,
+                12823499527603442107,
+                1159677778711068987,
+            ]),
+            Fq::from_montgomery_limbs([
+                4191580846331285179,
+                8159548502280179631,
+                14083689165462994097,
+                1306560819858565252,
+            ]),
+            Fq::from_montgomery_limbs([
+                11995858244647887298,
+                15279629978982873015,
+                15656177528090864005,
+                265903777585934089,
+            ]),
+            Fq::from_montgomery_limbs([
+                12214076524576605389,
+                18154002547035656274,
+                4482854873035489043,
+                366389835565350926,
+            ]),
+            Fq::from_montgomery_limbs([
+                1542854622976461232,
+                16980996067821895098,
+                10839829671635306158,
+                830595146975548525,
+            ]),
+            Fq::from_montgomery_limbs([
+                10762937219250748781,
+                13937489568255566750,
+                18349152733462912850,
+                1221681534116166703,
+            ]),
+            Fq::from_montgomery_limbs([
+                2225479448237455092,
+                18364968012024868297,
+                7668464416389885436,
+                665751405682009930,
+            ]),
+            Fq::from_montgomery_limbs([
+                8933806459818972593,
+                7737112037175652869,
+                10206441352405165850,
+                544995528070875794,
+            ]),
+            Fq::from_montgomery_limbs([
+                12211595271167602605,
+                11842960878014751619,
+                6943445143813176979,
+                944761112550788610,
+            ]),
+            Fq::from_montgomery_limbs([
+                11568380130316345006,
+                16275184393576078030,
+                16835968763164045418,
+                1093569887206939933,
+            ]),
+            Fq::from_montgomery_limbs([
+                15298434964866679216,
+                3378824051814122377,
+                2333812236419164019,
+                109223642359961281,
+            ]),
+            Fq::from_montgomery_limbs([
+                13425754489805681226,
+                4890088961283400814,
+                9789450162887255318,
+                705872916229176325,
+            ]),
+            Fq::from_montgomery_limbs([
+                3273479514631773476,
+                14398237972917005287,
+                14662690522119887735,
+                77530057647941164,
+            ]),
+            Fq::from_montgomery_limbs([
+                17934577023280612443,
+                3140463820475981518,
+                3103882981290508036,
+                33835746554833714,
+            ]),
+            Fq::from_montgomery_limbs([
+                16323966688664804017,
+                9388390283955051999,
+                15462776813268951717,
+                997180828293869200,
+            ]),
+            Fq::from_montgomery_limbs([
+                3542120623415741086,
+                13142659184178331789,
+                8378592546140127589,
+                331228684056903852,
+            ]),
+            Fq::from_montgomery_limbs([
+                519991645893854192,
+                16498117727806912421,
+                3958043202811513556,
+                137174955369375864,
+            ]),
+            Fq::from_montgomery_limbs([
+                14836594273339656067,
+                15779988768964136819,
+                6898586195842225701,
+                581445777039058797,
+            ]),
+            Fq::from_montgomery_limbs([
+                5817597831898181418,
+                15379685909449363794,
+                3111751434666054698,
+                1069391098810894895,
+            ]),
+            Fq::from_montgomery_limbs([
+                11082816764532785134,
+                14655859072094099946,
+                14260531002282667467,
+                501621525544368923,
+            ]),
+            Fq::from_montgomery_limbs([
+                1592095032683511153,
+                6964180308818150924,
+                312295154610208242,
+                268943363159958475,
+            ]),
+            Fq::from_montgomery_limbs([
+                7355799802090299633,
+                8553203358624327795,
+                3380789021550125127,
+                1337787422398770951,
+            ]),
+            Fq::from_montgomery_limbs([
+                6697918873557794575,
+                9320900493656585792,
+                5296178940116722733,
+                469388722512599972,
+            ]),
+        ]),
+    }
+}
@@ -0,0 +1,16454 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-13 instance of Poseidon.
+pub const fn rate_13() -> PoseidonParameters<14, 13, 196, 169, 39, 14, 546, 31> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<39, 14, 546>::new_from_known([
+            Fq::from_montgomery_limbs([
+                10246281588401305552,
+                13261394688604202531,
+                17586153264070975322,
+                1081009887269668527,
+            ]),
+            Fq::from_montgomery_limbs([
+                2651947439136580097,
+                3099573733413270690,
+                16405817597898257615,
+                1157382479045953938,
+            ]),
+            Fq::from_montgomery_limbs([
+                4686281599552105625,
+                517911903218502860,
+                17960798645929375362,
+                193814639833123314,
+            ]),
+            Fq::from_montgomery_limbs([
+                11367411935373351609,
+                2203766394725007238,
+                14449518025887102565,
+                878982557539094332,
+            ]),
+            Fq::from_montgomery_limbs([
+                11037335403495786410,
+                771400029560249798,
+                14536988634627395914,
+                794869868257777564,
+            ]),
+            Fq::from_montgomery_limbs([
+                11543544777097901177,
+                18167929097656764143,
+                18015148900227014723,
+                201602314600037198,
+            ]),
+            Fq::from_montgomery_limbs([
+                7629117166608910760,
+                3307599371846056999,
+                3800041378487869465,
+                78088898770097535,
+            ]),
+            Fq::from_montgomery_limbs([
+                15680955765176036455,
+                9952953835648447833,
+                17605719541968295614,
+                555928056077801350,
+            ]),
+            Fq::from_montgomery_limbs([
+                6444229998496857791,
+                1301848375725259209,
+                5689591707460213892,
+                858513124525352653,
+            ]),
+            Fq::from_montgomery_limbs([
+                6264445246203279361,
+                9224520754895195660,
+                7891303155509788676,
+                939359100380854584,
+            ]),
+            Fq::from_montgomery_limbs([
+                16320368098214281130,
+                15603879887976027252,
+                5879549832010431034,
+                192645677156178832,
+            ]),
+            Fq::from_montgomery_limbs([
+                16576462470231521128,
+                5204444136901326196,
+                6401809141642313956,
+                327474302444593384,
+            ]),
+            Fq::from_montgomery_limbs([
+                15504040003494180802,
+                3792784981648050252,
+                14328776330169592080,
+                1120696503293614598,
+            ]),
+            Fq::from_montgomery_limbs([
+                4252743852007907861,
+                2287035070223848840,
+                7734527217735201411,
+                440307099015507328,
+            ]),
+            Fq::from_montgomery_limbs([
+                941718340149254478,
+                1147663524190417649,
+                7998526298041288323,
+                708229596795294300,
+            ]),
+            Fq::from_montgomery_limbs([
+                17176908991775989835,
+                10320620671166954469,
+                4698435250872848190,
+                807244189135548116,
+            ]),
+            Fq::from_montgomery_limbs([
+                8549678509197179708,
+                14655569499909677190,
+                18393966263471207658,
+                873609379321934113,
+            ]),
+            Fq::from_montgomery_limbs([
+                18085090979736497342,
+                1173577025045792301,
+                11168299505713325806,
+                487116396348158083,
+            ]),
+            Fq::from_montgomery_limbs([
+                18039909479683557813,
+                13541565866999046889,
+                16028983461029022538,
+                720729773623483204,
+            ]),
+            Fq::from_montgomery_limbs([
+                1746551752795599625,
+                3997703680282796750,
+                10895930759897669213,
+                986806436062928752,
+            ]),
+            Fq::from_montgomery_limbs([
+                1756124948482675216,
+                14838939743408501232,
+                16721685272579090226,
+                992732003314252650,
+            ]),
+            Fq::from_montgomery_limbs([
+                15802519489038001917,
+                15091089501488088485,
+                15849929320828340824,
+                385011017208096745,
+            ]),
+            Fq::from_montgomery_limbs([
+                6254080459714848517,
+                11970645016206512569,
+                14770710413896302299,
+                23920374055452755,
+            ]),
+            Fq::from_montgomery_limbs([
+                2701407309904682376,
+                8791787821049472328,
+                325888287634688006,
+                347281340126524539,
+            ]),
+            Fq::from_montgomery_limbs([
+                17953958619963886899,
+                39273889736532982,
+                14820244450025834865,
+                963822312289879395,
+            ]),
+            Fq::from_montgomery_limbs([
+                10426185438367552947,
+                4936738267948705873,
+                5128359080824640132,
+                712554281816274465,
+            ]),
+            Fq::from_montgomery_limbs([
+                9728470006005565475,
+                8310411506376039252,
+                9199536633065780529,
+                153718450309680972,
+            ]),
+            Fq::from_montgomery_limbs([
+                13092719068500373323,
+                1569682994465501011,
+                729875507780416674,
+                1111466574919556982,
+            ]),
+            Fq::from_montgomery_limbs([
+                9466624337256429741,
+                10388667090221056580,
+                3225057637610976515,
+                870365624448780361,
+            ]),
+            Fq::from_montgomery_limbs([
+                12292892456060020187,
+                4784600528174196184,
+                1655831770428196977,
+                305963091866910341,
+            ]),
+            Fq::from_montgomery_limbs([
+                6360390117649906900,
+                821614179153238271,
+                15567964531926044769,
+                980383133864355523,
+            ]),
+            Fq::from_montgomery_limbs([
+                61801464952438671,
+                11985157620921304065,
+                3095985060335493205,
+                500963464980039172,
+            ]),
+            Fq::from_montgomery_limbs([
+                8265203002722997361,
+                10703766189197049979,
+                15195980868964942545,
+                1257460829993708878,
+            ]),
+            Fq::from_montgomery_limbs([
+                8744765142636715040,
+                8778794334053278971,
+                480396155376023009,
+                818728133486715928,
+            ]),
+            Fq::from_montgomery_limbs([
+                3591203120127610942,
+                7191982636743092289,
+                7562260309377577549,
+                179677521895582525,
+            ]),
+            Fq::from_montgomery_limbs([
+                9373689679042313043,
+                37490832751280801,
+                926738537394161182,
+                916936690330678828,
+            ]),
+            Fq::from_montgomery_limbs([
+                4570644689015563442,
+                3253502931028402398,
+                13607556491443301671,
+                380803016238063985,
+            ]),
+            Fq::from_montgomery_limbs([
+                15201028607890905811,
+                6186198066479374374,
+                9113776172267789050,
+                468517007928355750,
+            ]),
+            Fq::from_montgomery_limbs([
+                17881217819097278652,
+                4857240666020718707,
+                6217212986152181426,
+                720315507182846422,
+            ]),
+            Fq::from_montgomery_limbs([
+                9468761334950943357,
+                6515414782971689366,
+                13158917906521745664,
+                736424580771204048,
+            ]),
+            Fq::from_montgomery_limbs([
+                2167269384850536964,
+                15476276117683807028,
+                14252477756137622709,
+                489136691974157654,
+            ]),
+            Fq::from_montgomery_limbs([
+                1807133779171525980,
+                9596228155984958586,
+                17235551389247473298,
+                520236028973605326,
+            ]),
+            Fq::from_montgomery_limbs([
+                7519323722977666369,
+                13114635293649830960,
+                8757072864484773168,
+                204797307779751377,
+            ]),
+            Fq::from_montgomery_limbs([
+                18383848020865923893,
+                3265212579990343319,
+                17982046298585752031,
+                1320790295316798981,
+            ]),
+            Fq::from_montgomery_limbs([
+                9603650883709373050,
+                4507782419486819240,
+                10398244896574484832,
+                1212179972937275255,
+            ]),
+            Fq::from_montgomery_limbs([
+                12252000898787902230,
+                446228064437003417,
+                14100535926236563229,
+                920463611472176489,
+            ]),
+            Fq::from_montgomery_limbs([
+                898543768706581296,
+                3536300577058220206,
+                7701367876876410278,
+                418459717290737708,
+            ]),
+            Fq::from_montgomery_limbs([
+                13297809171458885986,
+                6533109385967117320,
+                2733206036706878896,
+                332485338971883776,
+            ]),
+            Fq::from_montgomery_limbs([
+                7640384498582447664,
+                15230969592054289663,
+                7404588395403537152,
+                77712015802175205,
+            ]),
+            Fq::from_montgomery_limbs([
+                18159939492357630985,
+                9534104341405191716,
+                14282435173268491646,
+                820392860838085771,
+            ]),
+            Fq::from_montgomery_limbs([
+                14261207220478573107,
+                811080570541188517,
+                5713835925787496879,
+                543361723356803866,
+            ]),
+            Fq::from_montgomery_limbs([
+                13368290019798535558,
+                9961042679479597691,
+                5024692740755046565,
+                191543480222526790,
+            ]),
+            Fq::from_montgomery_limbs([
+                14596866734738571506,
+                15598536019258731984,
+                12179940523445183940,
+                422425936254409801,
+            ]),
+            Fq::from_montgomery_limbs([
+                15516609564237658287,
+                1664601750534451129,
+                3993130630646364915,
+                377462609036885134,
+            ]),
+            Fq::from_montgomery_limbs([
+                17825633862646935935,
+                9272665176990575372,
+                8040776082753605078,
+                438034340766081171,
+            ]),
+            Fq::from_montgomery_limbs([
+                2586947954673144991,
+                9508074439862270462,
+                11828523209891937485,
+                173340468147482329,
+            ]),
+            Fq::from_montgomery_limbs([
+                7720686851077772440,
+                16240187679988880738,
+                7560670502924630322,
+                583992280629587559,
+            ]),
+            Fq::from_montgomery_limbs([
+                7939120564836683040,
+                5749397569529831598,
+                16926499005226076357,
+                194730229259481806,
+            ]),
+            Fq::from_montgomery_limbs([
+                1639781240160943894,
+                12720374907099464639,
+                7064034805892510871,
+                330272956749376689,
+            ]),
+            Fq::from_montgomery_limbs([
+                4902476216981604745,
+                2961522519997476993,
+                8409852591018968113,
+                260405252059056752,
+            ]),
+            Fq::from_montgomery_limbs([
+                3849006185573593323,
+                752335242422978683,
+                4605752200162231822,
+                633688868667034385,
+            ]),
+            Fq::from_montgomery_limbs([
+                2536744048954306956,
+                12282983601167037230,
+                16259527338556621194,
+                1035942278961051741,
+            ]),
+            Fq::from_montgomery_limbs([
+                3915216653493429926,
+                4157085463031800942,
+                1428955382384856657,
+                215312773708914004,
+            ]),
+            Fq::from_montgomery_limbs([
+                14664866070952707688,
+                6673340924148493558,
+                16762355440026324124,
+                1117224985996445760,
+            ]),
+            Fq::from_montgomery_limbs([
+                4205357635558328111,
+                1786767802817875504,
+                13066180756082632203,
+                232720668826610661,
+            ]),
+            Fq::from_montgomery_limbs([
+                1590897381262215507,
+                15624974022540723626,
+                16401319069754978758,
+                683131779153976333,
+            ]),
+            Fq::from_montgomery_limbs([
+                6216111846417540211,
+                10701852472350851089,
+                213445745780766667,
+                916449334189510574,
+            ]),
+            Fq::from_montgomery_limbs([
+                12805775132392654267,
+                14461921965679703064,
+                15635790859810130963,
+                290568558347456888,
+            ]),
+            Fq::from_montgomery_limbs([
+                15414739242033615746,
+                10694774462303987160,
+                9782784738784143151,
+                636031722208033823,
+            ]),
+            Fq::from_montgomery_limbs([
+                6179273864936581800,
+                12277901512727322284,
+                11970457538413490357,
+                587972532184953865,
+            ]),
+            Fq::from_montgomery_limbs([
+                7570452031901510290,
+                16117901884126864997,
+                10990447922742017345,
+                1093115292321109137,
+            ]),
+            Fq::from_montgomery_limbs([
+                2165365994403663724,
+                14428723945053915000,
+                9721970929328845263,
+                71289678592363320,
+            ]),
+            Fq::from_montgomery_limbs([
+                16128729031779505646,
+                4366454329450234655,
+                12196017587100453394,
+                1305629123889008240,
+            ]),
+            Fq::from_montgomery_limbs([
+                468436406560866348,
+                13869126076240768958,
+                17945648219239464665,
+                103600846557782744,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721405075232866724,
+                9271829381157107712,
+                12721430041191474133,
+                571849403701349591,
+            ]),
+            Fq::from_montgomery_limbs([
+                1693736019083253445,
+                6870023383598579004,
+                1766291847152987076,
+                61438738871138140,
+            ]),
+            Fq::from_montgomery_limbs([
+                1191594730592472642,
+                7224307391157442410,
+                261634908363785138,
+                194194799895436580,
+            ]),
+            Fq::from_montgomery_limbs([
+                10844517225335113181,
+                10190030830639498219,
+                3770310450657217876,
+                28594074239362978,
+            ]),
+            Fq::from_montgomery_limbs([
+                17848790628853661676,
+                17265897644380436842,
+                4039068567360066551,
+                1300183615024592762,
+            ]),
+            Fq::from_montgomery_limbs([
+                3471908969480892782,
+                2155838011576440875,
+                9202298467215458331,
+                662760858501212522,
+            ]),
+            Fq::from_montgomery_limbs([
+                9181019378248146095,
+                9774020220647595861,
+                9739063914109722875,
+                1089623925708086313,
+            ]),
+            Fq::from_montgomery_limbs([
+                3545029045885395789,
+                10171021173051818608,
+                7746380111190487299,
+                1224731265038193024,
+            ]),
+            Fq::from_montgomery_limbs([
+                5652150908606168109,
+                10854061871680300026,
+                606365660091516640,
+                1118069695606868972,
+            ]),
+            Fq::from_montgomery_limbs([
+                15593487041209683970,
+                12002649513622952602,
+                12105467253640286550,
+                165801061938825288,
+            ]),
+            Fq::from_montgomery_limbs([
+                11365586149048229916,
+                1193632422560511922,
+                1803649391266050665,
+                162934594264042040,
+            ]),
+            Fq::from_montgomery_limbs([
+                5433047177277293084,
+                4899156328776721199,
+                2061651742914250002,
+                1290623233907308403,
+            ]),
+            Fq::from_montgomery_limbs([
+                1251889063523504426,
+                5900760629957809555,
+                13004732705402984048,
+                215554545239095185,
+            ]),
+            Fq::from_montgomery_limbs([
+                11307810807176255556,
+                12415050032640825790,
+                5905287615779515000,
+                1064433417998189494,
+            ]),
+            Fq::from_montgomery_limbs([
+                17004016799479390293,
+                12117944438552235229,
+                547363848913557248,
+                433824852275909273,
+            ]),
+            Fq::from_montgomery_limbs([
+                5046939278359577194,
+                686410351578255818,
+                5836758671977466679,
+                1121831606218902766,
+            ]),
+            Fq::from_montgomery_limbs([
+                17524866591865758325,
+                7488392820669349523,
+                1682146408509364368,
+                332149699842458341,
+            ]),
+            Fq::from_montgomery_limbs([
+                11814347138859110581,
+                7835316287482286766,
+                18319611944475626875,
+                840164564373009398,
+            ]),
+            Fq::from_montgomery_limbs([
+                3320405175878023046,
+                1873178017579099454,
+                3568024116063336936,
+                461419774565521720,
+            ]),
+            Fq::from_montgomery_limbs([
+                9684443665464136166,
+                17156886594431942181,
+                5283134051669379753,
+                48896246283171256,
+            ]),
+            Fq::from_montgomery_limbs([
+                4680038118050737740,
+                17778793225823670260,
+                4173141734328857250,
+                192505650393513568,
+            ]),
+            Fq::from_montgomery_limbs([
+                2305688323399781229,
+                16716355077705418436,
+                11474143028811908711,
+                971317213144006654,
+            ]),
+            Fq::from_montgomery_limbs([
+                16515922998716038215,
+                7712534526849459765,
+                14885165527268098354,
+                379505442672250294,
+            ]),
+            Fq::from_montgomery_limbs([
+                493660580258743318,
+                5672141520737230019,
+                15080184926795488512,
+                748472617605796949,
+            ]),
+            Fq::from_montgomery_limbs([
+                13825492686946316412,
+                2987991030779891145,
+                2827384179990725750,
+                963062839519745112,
+            ]),
+            Fq::from_montgomery_limbs([
+                9292320494356459492,
+                11974230810328807636,
+                9297359995636293559,
+                1284798412885660583,
+            ]),
+            Fq::from_montgomery_limbs([
+                9083345317541541135,
+                11868648681247487670,
+                4956937333154399512,
+                313088033848650943,
+            ]),
+            Fq::from_montgomery_limbs([
+                3087691060597707635,
+                8324577019462590099,
+                11120414104738192156,
+                40805837028616970,
+            ]),
+            Fq::from_montgomery_limbs([
+                16030976342993943645,
+                14547759673718671025,
+                5772268889341763131,
+                903471626555407469,
+            ]),
+            Fq::from_montgomery_limbs([
+                10570208133097668825,
+                14294967213686708111,
+                233100505282009359,
+                136319713432686622,
+            ]),
+            Fq::from_montgomery_limbs([
+                3275783550965310332,
+                3878909601747943545,
+                2999784130833549347,
+                592602307103250932,
+            ]),
+            Fq::from_montgomery_limbs([
+                8475260263372343575,
+                14888173446586881738,
+                10825892159111829415,
+                1089849245609271925,
+            ]),
+            Fq::from_montgomery_limbs([
+                18224996184393146577,
+                6502873856063770342,
+                17165790870318087523,
+                339893576135411672,
+            ]),
+            Fq::from_montgomery_limbs([
+                8807796868407030978,
+                7099035783869473106,
+                1284050766236279534,
+                30601290340561806,
+            ]),
+            Fq::from_montgomery_limbs([
+                6597977318364536012,
+                2481653542724813707,
+                15085203282213581437,
+                1320190563088140308,
+            ]),
+            Fq::from_montgomery_limbs([
+                14287663121441028348,
+                7516298680863069608,
+                5959039155163880081,
+                423014377507613664,
+            ]),
+            Fq::from_montgomery_limbs([
+                207220579015944191,
+                7674408108762697551,
+                13622877077840079854,
+                371656098783555905,
+            ]),
+            Fq::from_montgomery_limbs([
+                8966940578911984893,
+                4399875600718029097,
+                12196952959292409921,
+                263491505872271564,
+            ]),
+            Fq::from_montgomery_limbs([
+                9432163637720309937,
+                12129636072593950634,
+                17167903288195164433,
+                103021349604374830,
+            ]),
+            Fq::from_montgomery_limbs([
+                12287735297038346406,
+                15153781213117268751,
+                11563502396190182927,
+                1285167597623382753,
+            ]),
+            Fq::from_montgomery_limbs([
+                9085322487621797544,
+                2048337935992957377,
+                11074294462182248348,
+                82281760064648830,
+            ]),
+            Fq::from_montgomery_limbs([
+                12545804058002078612,
+                115124508824575341,
+                13117443192486186555,
+                171075235650326761,
+            ]),
+            Fq::from_montgomery_limbs([
+                13165952069559429538,
+                171319223542281659,
+                18124311710940299580,
+                433985280437295106,
+            ]),
+            Fq::from_montgomery_limbs([
+                872631463749616382,
+                5443830295279996393,
+                11294801962093645542,
+                160788883809071062,
+            ]),
+            Fq::from_montgomery_limbs([
+                4797772628712613826,
+                8397212823545019774,
+                1075325149432068037,
+                1101304747117419456,
+            ]),
+            Fq::from_montgomery_limbs([
+                12096377244627420139,
+                14562126139770468798,
+                10484229860468064815,
+                1116517484107384556,
+            ]),
+            Fq::from_montgomery_limbs([
+                15718987964196443294,
+                17402634395302613975,
+                8674154793251620219,
+                486286502503993918,
+            ]),
+            Fq::from_montgomery_limbs([
+                4404490439699576630,
+                3828378406385112054,
+                1178184090528163266,
+                955485509862181133,
+            ]),
+            Fq::from_montgomery_limbs([
+                8963664854639720371,
+                8723262574371956306,
+                9732160391885468804,
+                700781094767480217,
+            ]),
+            Fq::from_montgomery_limbs([
+                6607862950531504189,
+                10616950196139206915,
+                8996983276934072263,
+                245200378804290756,
+            ]),
+            Fq::from_montgomery_limbs([
+                2498429581470895076,
+                10530435192687612934,
+                17155607818111720014,
+                1184517819778154664,
+            ]),
+            Fq::from_montgomery_limbs([
+                17178284335981864768,
+                11044728369888625118,
+                12793486914989797428,
+                892983842253192982,
+            ]),
+            Fq::from_montgomery_limbs([
+                13369366085636594842,
+                1553515135331298867,
+                10574256060698786004,
+                897923245039457175,
+            ]),
+            Fq::from_montgomery_limbs([
+                1418555345736522604,
+                10629537977540559318,
+                17604855491486906940,
+                422378342853166923,
+            ]),
+            Fq::from_montgomery_limbs([
+                11025794278769808763,
+                16892125010809940844,
+                10905861404618604685,
+                805920383678802680,
+            ]),
+            Fq::from_montgomery_limbs([
+                6779876070148649088,
+                17420084334651228863,
+                3706141608575107841,
+                191919024470277895,
+            ]),
+            Fq::from_montgomery_limbs([
+                2058617098146295302,
+                9612248614573898022,
+                14398912743362835431,
+                62612844451231151,
+            ]),
+            Fq::from_montgomery_limbs([
+                17506587640589943115,
+                1666522390678590375,
+                5708607489233442437,
+                856712494087891291,
+            ]),
+            Fq::from_montgomery_limbs([
+                7121958129129646499,
+                12642349154154840885,
+                12529719899200311289,
+                217686939774337680,
+            ]),
+            Fq::from_montgomery_limbs([
+                16523791155333689954,
+                18084466355742927909,
+                16418268348957009438,
+                1037927295182248870,
+            ]),
+            Fq::from_montgomery_limbs([
+                2764539600698380193,
+                16167421502435198019,
+                1057515925215166861,
+                939792875294916578,
+            ]),
+            Fq::from_montgomery_limbs([
+                12556619940467670882,
+                14826752698857098164,
+                15047435547353005596,
+                233911505902868258,
+            ]),
+            Fq::from_montgomery_limbs([
+                16425633029443509620,
+                16894664393572394849,
+                7247914819885792923,
+                131700888751684659,
+            ]),
+            Fq::from_montgomery_limbs([
+                260859402144721876,
+                11274681931590088876,
+                14343210333330761319,
+                455280646753490713,
+            ]),
+            Fq::from_montgomery_limbs([
+                6421560034470271721,
+                12757306743640520312,
+                6913386283066738296,
+                568196607769640204,
+            ]),
+            Fq::from_montgomery_limbs([
+                8646658875465351045,
+                4197842774335138484,
+                7438369687924261245,
+                615992262511188386,
+            ]),
+            Fq::from_montgomery_limbs([
+                5404785211627589336,
+                13099533251671931037,
+                7939755202726422232,
+                536825584134474412,
+            ]),
+            Fq::from_montgomery_limbs([
+                479091472976653530,
+                17665321384038210331,
+                8916517712706255614,
+                413253712402878468,
+            ]),
+            Fq::from_montgomery_limbs([
+                5874296937303989969,
+                5132851846704710667,
+                12603731070970677853,
+                743952985674583731,
+            ]),
+            Fq::from_montgomery_limbs([
+                16864072773394182111,
+                8631873819781268378,
+                18377743669549806185,
+                11265245959174633,
+            ]),
+            Fq::from_montgomery_limbs([
+                17677816926086616041,
+                2356506506901616465,
+                11759283287855525234,
+                858661041555492210,
+            ]),
+            Fq::from_montgomery_limbs([
+                12868245552081233885,
+                1116208441629815679,
+                3649938507596374798,
+                266386477269156519,
+            ]),
+            Fq::from_montgomery_limbs([
+                6400174776599915956,
+                15990758640952680883,
+                14295904885490404318,
+                1139553962488446460,
+            ]),
+            Fq::from_montgomery_limbs([
+                2741549679098643277,
+                10795386129711317765,
+                6670019331400908939,
+                379198285851159786,
+            ]),
+            Fq::from_montgomery_limbs([
+                8723430663484747001,
+                7804448727675633194,
+                8405801877479552583,
+                320623676002229484,
+            ]),
+            Fq::from_montgomery_limbs([
+                8818501463635198294,
+                9641496002696035776,
+                5258433409769335905,
+                486542284208351318,
+            ]),
+            Fq::from_montgomery_limbs([
+                15231616634103345826,
+                8509168582353640722,
+                13467805827239318516,
+                156971798748288169,
+            ]),
+            Fq::from_montgomery_limbs([
+                12680050275369915114,
+                4498074479414981914,
+                6290805451071818796,
+                647266982871959690,
+            ]),
+            Fq::from_montgomery_limbs([
+                16437727663747543084,
+                1695377328881028433,
+                147774550035144432,
+                432754388987597309,
+            ]),
+            Fq::from_montgomery_limbs([
+                14756269198899454851,
+                6246982381397927052,
+                1354261456489272473,
+                923982106952442835,
+            ]),
+            Fq::from_montgomery_limbs([
+                14436518609885676638,
+                5912865220632563580,
+                2189664855118799968,
+                116737576489021530,
+            ]),
+            Fq::from_montgomery_limbs([
+                1009783725903443709,
+                18011368437563634509,
+                9655199580392419754,
+                378719761738845683,
+            ]),
+            Fq::from_montgomery_limbs([
+                17655826314510412953,
+                12019037345027321704,
+                695089247034466155,
+                568416138792168193,
+            ]),
+            Fq::from_montgomery_limbs([
+                7584481486768399668,
+                2037293670403856092,
+                15709443209464735149,
+                1132817395134858276,
+            ]),
+            Fq::from_montgomery_limbs([
+                16895626023972130070,
+                4598702889397966119,
+                17872541872907960670,
+                1049035844739176911,
+            ]),
+            Fq::from_montgomery_limbs([
+                14073813633307131752,
+                10967707338381744726,
+                5963556048553682979,
+                879078305606217647,
+            ]),
+            Fq::from_montgomery_limbs([
+                7692612299295826746,
+                8398564922924851627,
+                2574717206810785061,
+                210688817157733576,
+            ]),
+            Fq::from_montgomery_limbs([
+                18389877317820307717,
+                14067135129364002611,
+                4910170413481857633,
+                342519964996140569,
+            ]),
+            Fq::from_montgomery_limbs([
+                9809483672937736403,
+                1248724721292924746,
+                11920205086095757936,
+                292783692197296207,
+            ]),
+            Fq::from_montgomery_limbs([
+                15050997231412034566,
+                150430562054460981,
+                14074773639661335392,
+                948140393276232308,
+            ]),
+            Fq::from_montgomery_limbs([
+                9231073017290383640,
+                16211156088388526104,
+                1853869192447166468,
+                537395132734502047,
+            ]),
+            Fq::from_montgomery_limbs([
+                2379438935674785707,
+                8156038372419545554,
+                8286657361072574952,
+                1158842604669755588,
+            ]),
+            Fq::from_montgomery_limbs([
+                15702057309191671270,
+                12100141415444583241,
+                12775123610755945812,
+                112696406586741681,
+            ]),
+            Fq::from_montgomery_limbs([
+                795701643982537807,
+                8512598580161230242,
+                18196544650884443892,
+                1162612583060360467,
+            ]),
+            Fq::from_montgomery_limbs([
+                13509487030043250688,
+                17266647929208585304,
+                889456806263914631,
+                656755810485258420,
+            ]),
+            Fq::from_montgomery_limbs([
+                3722924970686328645,
+                9601375649773645813,
+                13683098926573389593,
+                894781120248341853,
+            ]),
+            Fq::from_montgomery_limbs([
+                11670821817354245827,
+                9843293423858844312,
+                2383058102986820209,
+                757781214671649670,
+            ]),
+            Fq::from_montgomery_limbs([
+                13426734699971791484,
+                15959365756693506134,
+                13051803659985175113,
+                577498822438374782,
+            ]),
+            Fq::from_montgomery_limbs([
+                11164300040352045718,
+                5403818191649392600,
+                9873485668490687616,
+                293002723708532707,
+            ]),
+            Fq::from_montgomery_limbs([
+                9485386850823562833,
+                17359170110546381557,
+                984852464469731792,
+                654595182272218551,
+            ]),
+            Fq::from_montgomery_limbs([
+                4160433743010334289,
+                11729912309506008941,
+                17453724587510480434,
+                1068701014160574841,
+            ]),
+            Fq::from_montgomery_limbs([
+                4039834527904231831,
+                14282869140360180493,
+                12731480506183973938,
+                402592847576858251,
+            ]),
+            Fq::from_montgomery_limbs([
+                14267713793752068334,
+                11668118947972913749,
+                18270075933311658944,
+                99072281590586520,
+            ]),
+            Fq::from_montgomery_limbs([
+                4804284689749860948,
+                13129132057641431370,
+                3609264557850125083,
+                1082422071508585447,
+            ]),
+            Fq::from_montgomery_limbs([
+                452409562369937006,
+                15533668257027267451,
+                16678150438328875323,
+                1262934008610864235,
+            ]),
+            Fq::from_montgomery_limbs([
+                10344421633490579662,
+                4503408271170920341,
+                12560148670075756205,
+                555918285956663127,
+            ]),
+            Fq::from_montgomery_limbs([
+                8076529714632401277,
+                3786463589014751626,
+                8746551953132610939,
+                923305759331186230,
+            ]),
+            Fq::from_montgomery_limbs([
+                11309047158707126995,
+                8082745472602663200,
+                848139140837279174,
+                874727162256380403,
+            ]),
+            Fq::from_montgomery_limbs([
+                10644245267569022453,
+                713429554663658666,
+                13731523279521200750,
+                91483420507407548,
+            ]),
+            Fq::from_montgomery_limbs([
+                18002413195502014015,
+                8837788100956550793,
+                13149897899236869055,
+                758549900512530975,
+            ]),
+            Fq::from_montgomery_limbs([
+                15382851174481918567,
+                9751723220435910532,
+                12775660391331321453,
+                227133185924551436,
+            ]),
+            Fq::from_montgomery_limbs([
+                18260566076513616946,
+                16389367215940174814,
+                69065161214053843,
+                320449684031952866,
+            ]),
+            Fq::from_montgomery_limbs([
+                13169746909736989982,
+                1414024851016209886,
+                6921480909375168522,
+                1135522326179594074,
+            ]),
+            Fq::from_montgomery_limbs([
+                10660722505126883761,
+                3569444269624778226,
+                10140934251958811129,
+                1225268932069316435,
+            ]),
+            Fq::from_montgomery_limbs([
+                17962358116925183590,
+                8406765584344452303,
+                14081860053828782478,
+                94358732214451037,
+            ]),
+            Fq::from_montgomery_limbs([
+                4718718975440423736,
+                4946524632728140306,
+                483410222885520057,
+                600897724132528301,
+            ]),
+            Fq::from_montgomery_limbs([
+                2985354124532320892,
+                13346733419518535410,
+                10538713834343904312,
+                1254099865724085025,
+            ]),
+            Fq::from_montgomery_limbs([
+                4558936469060555394,
+                2972919975918206977,
+                9163137306676186193,
+                4423037755138142,
+            ]),
+            Fq::from_montgomery_limbs([
+                13736138323076256264,
+                15052181512006322824,
+                2971113344509548611,
+                1111545966086208629,
+            ]),
+            Fq::from_montgomery_limbs([
+                8040989363660252003,
+                8822582271559023611,
+                16900737687916712905,
+                863656379708585185,
+            ]),
+            Fq::from_montgomery_limbs([
+                3182306257443950767,
+                452981730631137304,
+                8206042275587989749,
+                533245642631683921,
+            ]),
+            Fq::from_montgomery_limbs([
+                1310697083955252024,
+                8317553960618363499,
+                9458886043679262628,
+                620472211499277211,
+            ]),
+            Fq::from_montgomery_limbs([
+                10064593793975156378,
+                8850785172680600408,
+                10772113200117452190,
+                308357507854219767,
+            ]),
+            Fq::from_montgomery_limbs([
+                18033033716204175339,
+                5492502294501747987,
+                14214924072513152995,
+                576993938719225147,
+            ]),
+            Fq::from_montgomery_limbs([
+                8828448229397231025,
+                16720370822063999852,
+                6994103412916057286,
+                347414693168985890,
+            ]),
+            Fq::from_montgomery_limbs([
+                15971055884495452814,
+                10210806232947470483,
+                11689724201065013844,
+                738432173321487996,
+            ]),
+            Fq::from_montgomery_limbs([
+                14141470196242873637,
+                3151539162010811849,
+                11342562568641652144,
+                1132378643082705051,
+            ]),
+            Fq::from_montgomery_limbs([
+                11521819841520456162,
+                15994198872691342393,
+                13277360342396128432,
+                625660948796744896,
+            ]),
+            Fq::from_montgomery_limbs([
+                16440533643991618387,
+                1173208360409082281,
+                6555858377803469900,
+                6046799039490378,
+            ]),
+            Fq::from_montgomery_limbs([
+                12953849268773712031,
+                6635533881333959597,
+                5845244762765364984,
+                1301357269645591348,
+            ]),
+            Fq::from_montgomery_limbs([
+                7161216164995845464,
+                10782953999847021289,
+                6034858246921834010,
+                939203576019470936,
+            ]),
+            Fq::from_montgomery_limbs([
+                14238678494102086163,
+                8517865108208188758,
+                13776796110740202942,
+                1248031129002217078,
+            ]),
+            Fq::from_montgomery_limbs([
+                203955966654063308,
+                6251846513424997414,
+                8229965373836300387,
+                307290224963040667,
+            ]),
+            Fq::from_montgomery_limbs([
+                16365000675652793353,
+                6988308699593395986,
+                13837698104424379209,
+                546977079194600227,
+            ]),
+            Fq::from_montgomery_limbs([
+                6560029053515400225,
+                3041001469153783911,
+                9698088442385556897,
+                300256273260679553,
+            ]),
+            Fq::from_montgomery_limbs([
+                2578084151142134070,
+                4098943841958224060,
+                16931794807664640684,
+                252559977991115060,
+            ]),
+            Fq::from_montgomery_limbs([
+                7764500556411085929,
+                3699296341040006656,
+                15436730859549582639,
+                1309886143773117677,
+            ]),
+            Fq::from_montgomery_limbs([
+                9348641120981223583,
+                10166157794289874285,
+                2460888879082128244,
+                806420652207818050,
+            ]),
+            Fq::from_montgomery_limbs([
+                13554546486310841924,
+                4996387128343334461,
+                10376798126263867519,
+                359778964615680707,
+            ]),
+            Fq::from_montgomery_limbs([
+                17135571655167930769,
+                1457583544821346408,
+                2693429905692350254,
+                269706811713683329,
+            ]),
+            Fq::from_montgomery_limbs([
+                1306699130510372833,
+                28066843104671473,
+                6980054771922162497,
+                400563321477351699,
+            ]),
+            Fq::from_montgomery_limbs([
+                751058883365047138,
+                4877640671948259453,
+                11378582821150965964,
+                390802224766049047,
+            ]),
+            Fq::from_montgomery_limbs([
+                1280254867234829434,
+                16288128868748656835,
+                10421359884192022694,
+                260128534475494097,
+            ]),
+            Fq::from_montgomery_limbs([
+                17984624787877147081,
+                3171144205380081101,
+                2577510140299609512,
+                190427887649326833,
+            ]),
+            Fq::from_montgomery_limbs([
+                6668098291897020814,
+                11702728661590474369,
+                2869169942323391643,
+                842298695772049371,
+            ]),
+            Fq::from_montgomery_limbs([
+                5341135877237489729,
+                16868105207918586435,
+                15893257078724429312,
+                438909664557268960,
+            ]),
+            Fq::from_montgomery_limbs([
+                1046483017830560789,
+                8811940759682864090,
+                2509894986619893289,
+                373096364141914670,
+            ]),
+            Fq::from_montgomery_limbs([
+                14709273111169915370,
+                11741493174848420570,
+                5331010743656990820,
+                485483965599509137,
+            ]),
+            Fq::from_montgomery_limbs([
+                3513239656911337680,
+                14396635143304522316,
+                11358505278932019446,
+                642164060220579269,
+            ]),
+            Fq::from_montgomery_limbs([
+                17281362751685245602,
+                18388000620465010662,
+                10594269778083580339,
+                224465673344431592,
+            ]),
+            Fq::from_montgomery_limbs([
+                15133675434650709302,
+                17925235491449802992,
+                3589353835597214700,
+                498103454438030208,
+            ]),
+            Fq::from_montgomery_limbs([
+                13471211632770727062,
+                17671319499788096063,
+                14156575241671446745,
+                383076991096164960,
+            ]),
+            Fq::from_montgomery_limbs([
+                898049003588521300,
+                10562357883425332063,
+                7103635950500518613,
+                648548543454784118,
+            ]),
+            Fq::from_montgomery_limbs([
+                7072289446242588040,
+                9138692967058678917,
+                8735610073258452843,
+                304204268783822964,
+            ]),
+            Fq::from_montgomery_limbs([
+                7001360854334929679,
+                6939934381790557603,
+                10270841147258948344,
+                702674812401885262,
+            ]),
+            Fq::from_montgomery_limbs([
+                5255469732160606525,
+                17368234659440666700,
+                6468801585106908110,
+                929264612468968377,
+            ]),
+            Fq::from_montgomery_limbs([
+                2007955090777137878,
+                14920599515039453883,
+                904211712827662923,
+                1338730391441527744,
+            ]),
+            Fq::from_montgomery_limbs([
+                800536952532059473,
+                15487859414899434549,
+                8656876854315781203,
+                52343008168089723,
+            ]),
+            Fq::from_montgomery_limbs([
+                5161188053084994405,
+                11340217970025843,
+                2902318765888584947,
+                743400441833414002,
+            ]),
+            Fq::from_montgomery_limbs([
+                1686052584325147518,
+                2038963121261545782,
+                4720735708258810319,
+                509554955125264984,
+            ]),
+            Fq::from_montgomery_limbs([
+                13367088077059437436,
+                14603495293490993788,
+                8627391940794960599,
+                126241293418462452,
+            ]),
+            Fq::from_montgomery_limbs([
+                16236421705304861136,
+                4098371688902246398,
+                3310703137667766407,
+                1175377011961283788,
+            ]),
+            Fq::from_montgomery_limbs([
+                6524589644024576137,
+                12658997050376019730,
+                2620116909746005733,
+                357390492222857995,
+            ]),
+            Fq::from_montgomery_limbs([
+                794347300326037089,
+                11214794568427687045,
+                12393379495358790330,
+                315917507659103684,
+            ]),
+            Fq::from_montgomery_limbs([
+                2848678850751324758,
+                1361059667544922348,
+                7161765785825399066,
+                621338977869935507,
+            ]),
+            Fq::from_montgomery_limbs([
+                9729761822174216114,
+                434987060184830926,
+                11172585338637136491,
+                853829235096350011,
+            ]),
+            Fq::from_montgomery_limbs([
+                16728396098207379310,
+                4673718879684903699,
+                14060700012171228755,
+                471519391091148608,
+            ]),
+            Fq::from_montgomery_limbs([
+                13371661918512644647,
+                4237734295911445692,
+                8670657152067192791,
+                295018857091176942,
+            ]),
+            Fq::from_montgomery_limbs([
+                1910672438006714610,
+                12777327187135646108,
+                13116904169703489913,
+                690726023909081105,
+            ]),
+            Fq::from_montgomery_limbs([
+                1169805507586121653,
+                13896679876180807448,
+                16638478084695377843,
+                335586325970962198,
+            ]),
+            Fq::from_montgomery_limbs([
+                17508813715721380072,
+                6407958946079041731,
+                2562999427718175106,
+                58728991228211157,
+            ]),
+            Fq::from_montgomery_limbs([
+                8057885192023440898,
+                10176258476520374622,
+                5456904083193227252,
+                39227309352228265,
+            ]),
+            Fq::from_montgomery_limbs([
+                18116599720527742794,
+                1187824531691776111,
+                9339347989278366284,
+                711884240868859867,
+            ]),
+            Fq::from_montgomery_limbs([
+                2267637623533672471,
+                11259352364655025254,
+                4816578492599305040,
+                472083512703577118,
+            ]),
+            Fq::from_montgomery_limbs([
+                11929144789879094684,
+                12040766139496445928,
+                17378766951214253921,
+                417810030314797534,
+            ]),
+            Fq::from_montgomery_limbs([
+                10643962786599752806,
+                6614817053095738026,
+                937518874454355410,
+                652397242766568106,
+            ]),
+            Fq::from_montgomery_limbs([
+                12212137391777018257,
+                15148419302413242327,
+                2191129204974715169,
+                404464720543171776,
+            ]),
+            Fq::from_montgomery_limbs([
+                16656940020165616620,
+                8290923648579342698,
+                11408329759497358592,
+                908660296158646979,
+            ]),
+            Fq::from_montgomery_limbs([
+                988495860222488738,
+                14501474557140637439,
+                3096140717520474738,
+                1010409938006095776,
+            ]),
+            Fq::from_montgomery_limbs([
+                8643401896703634312,
+                16395842580233022985,
+                4194179504582697678,
+                161577812229101528,
+            ]),
+            Fq::from_montgomery_limbs([
+                3949283133224786674,
+                651680681472004932,
+                3166569926968290179,
+                958995547508561413,
+            ]),
+            Fq::from_montgomery_limbs([
+                18338735552629356336,
+                14140286626776517933,
+                5575485834993218529,
+                1190733801059433239,
+            ]),
+            Fq::from_montgomery_limbs([
+                9588722351779287024,
+                13671426771849200283,
+                15537856544014504666,
+                259148698951651105,
+            ]),
+            Fq::from_montgomery_limbs([
+                3764656932287116871,
+                2823517258570415304,
+                12328754583738721279,
+                966944518211610446,
+            ]),
+            Fq::from_montgomery_limbs([
+                4624180287798668413,
+                12810968961896789375,
+                8670295183956337969,
+                1259515691556076821,
+            ]),
+            Fq::from_montgomery_limbs([
+                3846942711703140057,
+                12033479789793408670,
+                7487485366977931726,
+                713791444871883537,
+            ]),
+            Fq::from_montgomery_limbs([
+                5942752696716714166,
+                10546307802374856404,
+                16647186594221898168,
+                112079429392782400,
+            ]),
+            Fq::from_montgomery_limbs([
+                10769859649026438927,
+                7436954936143738351,
+                6259034295775957810,
+                699475979360027410,
+            ]),
+            Fq::from_montgomery_limbs([
+                3229631936497407421,
+                12209015287597760966,
+                11960259112272828303,
+                130508885865102574,
+            ]),
+            Fq::from_montgomery_limbs([
+                8417559406455944626,
+                7938139666417679662,
+                5824321627737855965,
+                829007657649303742,
+            ]),
+            Fq::from_montgomery_limbs([
+                15855284660236467255,
+                10485779372272502319,
+                15012063150275395569,
+                55594156080786743,
+            ]),
+            Fq::from_montgomery_limbs([
+                7569455000707355733,
+                8147218096921766773,
+                3968687686324093435,
+                231822041369045298,
+            ]),
+            Fq::from_montgomery_limbs([
+                10818209950837179973,
+                13474244396693885230,
+                12915118761701090708,
+                880086199837908227,
+            ]),
+            Fq::from_montgomery_limbs([
+                107891810021304568,
+                9609860964175791557,
+                4269119719968982152,
+                60898773078346967,
+            ]),
+            Fq::from_montgomery_limbs([
+                12694268057516871408,
+                11244533141263829517,
+                1024097558307188107,
+                518426963898924195,
+            ]),
+            Fq::from_montgomery_limbs([
+                9539374969670270364,
+                7717756422954812901,
+                17839941075966686448,
+                886638447347064487,
+            ]),
+            Fq::from_montgomery_limbs([
+                17979144743104955377,
+                9605361389708494765,
+                18241603130496555973,
+                239358122880070792,
+            ]),
+            Fq::from_montgomery_limbs([
+                5277746091768291181,
+                11584867836727877398,
+                14349585055424939441,
+                846341480341828572,
+            ]),
+            Fq::from_montgomery_limbs([
+                7965846682366909409,
+                12615641239979206167,
+                9737516544013342350,
+                119673332460004047,
+            ]),
+            Fq::from_montgomery_limbs([
+                12598363522395482969,
+                6042889578358822049,
+                8891959045108083848,
+                891170262624933914,
+            ]),
+            Fq::from_montgomery_limbs([
+                11338002182507258381,
+                6824589467533118864,
+                15175172117756294152,
+                929224950547689557,
+            ]),
+            Fq::from_montgomery_limbs([
+                4707890200873161425,
+                11009983726860682357,
+                2591041685991360035,
+                746926095467760290,
+            ]),
+            Fq::from_montgomery_limbs([
+                17169400658630919934,
+                5117566691862132771,
+                6865271936215742231,
+                660084413884770718,
+            ]),
+            Fq::from_montgomery_limbs([
+                1883311480301229866,
+                4254835090088349675,
+                12904158882318022090,
+                1085916073289061951,
+            ]),
+            Fq::from_montgomery_limbs([
+                15388660833583337426,
+                1162130990792181821,
+                1741419088642895957,
+                369953843700914656,
+            ]),
+            Fq::from_montgomery_limbs([
+                17709402935722272113,
+                17306120130775986317,
+                6768848224495285438,
+                375283008266414929,
+            ]),
+            Fq::from_montgomery_limbs([
+                1484409485856887840,
+                9257693130141000925,
+                3923338574684136478,
+                872302931910354872,
+            ]),
+            Fq::from_montgomery_limbs([
+                8746667670350449359,
+                11984464042906235745,
+                15705486055221040248,
+                979931687084454403,
+            ]),
+            Fq::from_montgomery_limbs([
+                11017760295227568110,
+                3123399877989678780,
+                6888654654137558679,
+                504216213224650649,
+            ]),
+            Fq::from_montgomery_limbs([
+                295875107499917987,
+                17196617229120371823,
+                2532959049617351379,
+                1299931059258262040,
+            ]),
+            Fq::from_montgomery_limbs([
+                4870798006532216922,
+                4900465189125636343,
+                12245606291047481499,
+                365926241049524715,
+            ]),
+            Fq::from_montgomery_limbs([
+                18303706779349345022,
+                9583466449490708354,
+                7545071805661161372,
+                743052882186200394,
+            ]),
+            Fq::from_montgomery_limbs([
+                15005584024617084166,
+                16477762717568701888,
+                17251284752027418154,
+                245699353708438615,
+            ]),
+            Fq::from_montgomery_limbs([
+                11126200434711013870,
+                10986235869387011727,
+                11994298444465839246,
+                582001545109034209,
+            ]),
+            Fq::from_montgomery_limbs([
+                16029077172957745293,
+                7465615371762204622,
+                2433416308026937284,
+                675581780389352983,
+            ]),
+            Fq::from_montgomery_limbs([
+                9357136997353207824,
+                5378356974669980284,
+                12340401486155626570,
+                576195908351028606,
+            ]),
+            Fq::from_montgomery_limbs([
+                9486298815435555525,
+                9746267994849462882,
+                12349228522166642478,
+                1292529572002170177,
+            ]),
+            Fq::from_montgomery_limbs([
+                3694385496602311673,
+                11839731165721385602,
+                2097949269174422802,
+                193322419177469072,
+            ]),
+            Fq::from_montgomery_limbs([
+                3028839840942405145,
+                3524329018844079643,
+                14793954176333572036,
+                44163965890951081,
+            ]),
+            Fq::from_montgomery_limbs([
+                15488943569464511793,
+                6824907428164552586,
+                13562640512916276623,
+                645379751757014422,
+            ]),
+            Fq::from_montgomery_limbs([
+                12726393319509823643,
+                4506618745282391151,
+                13682424328394243636,
+                827959292723750438,
+            ]),
+            Fq::from_montgomery_limbs([
+                9253110552117879671,
+                4335268200812734281,
+                4527807889969524360,
+                420889987839861956,
+            ]),
+            Fq::from_montgomery_limbs([
+                16666030035905739631,
+                5794049858912264317,
+                11552639211868418829,
+                1148466932604043958,
+            ]),
+            Fq::from_montgomery_limbs([
+                18139295921052587821,
+                5661966699594160627,
+                1369842843182437517,
+                260428250158147415,
+            ]),
+            Fq::from_montgomery_limbs([
+                14275886088767159106,
+                10288180613262154735,
+                10566606454146626157,
+                1303762492080125010,
+            ]),
+            Fq::from_montgomery_limbs([
+                8902077076412823767,
+                2046303659067185982,
+                15684509058433808349,
+                1065429347449471359,
+            ]),
+            Fq::from_montgomery_limbs([
+                17050516174441737571,
+                9151424214280834087,
+                11700974894093445707,
+                821396819077690658,
+            ]),
+            Fq::from_montgomery_limbs([
+                7671172496602624051,
+                15970276712669877213,
+                16748173244672322920,
+                822369608953619125,
+            ]),
+            Fq::from_montgomery_limbs([
+                1679202075894445754,
+                15247328327812040239,
+                5052114011919774296,
+                32094611884134831,
+            ]),
+            Fq::from_montgomery_limbs([
+                7280447525025096905,
+                1387001230001913383,
+                11215463201402242540,
+                375480758436099010,
+            ]),
+            Fq::from_montgomery_limbs([
+                10180612314449101515,
+                16479691636431547040,
+                1537760971067200853,
+                662219826761139358,
+            ]),
+            Fq::from_montgomery_limbs([
+                6317733461501260478,
+                5723919335077173711,
+                14773069569414546344,
+                355977726680421946,
+            ]),
+            Fq::from_montgomery_limbs([
+                4017600747350796756,
+                318979566546212417,
+                3030294795958128783,
+                536818320845844647,
+            ]),
+            Fq::from_montgomery_limbs([
+                996499929932431991,
+                14748694864033643087,
+                12480563654443244159,
+                70658608500791662,
+            ]),
+            Fq::from_montgomery_limbs([
+                10288989430932296590,
+                17274907521886504960,
+                3562273300085232170,
+                1148132685037075206,
+            ]),
+            Fq::from_montgomery_limbs([
+                11492490399455879280,
+                1876742573707139787,
+                3148256260348014468,
+                453815564791592386,
+            ]),
+            Fq::from_montgomery_limbs([
+                2451169915049905184,
+                2076705614180238774,
+                1526401474548391253,
+                1234556166447202930,
+            ]),
+            Fq::from_montgomery_limbs([
+                14053041866195192212,
+                17332255981656163031,
+                12176140243032359143,
+                1126613949642761519,
+            ]),
+            Fq::from_montgomery_limbs([
+                14052029924498206716,
+                9126529396341178284,
+                3681020078475016109,
+                843450347499545041,
+            ]),
+            Fq::from_montgomery_limbs([
+                3868221716224086910,
+                8075373178849579572,
+                1164106021751057376,
+                592134149390392827,
+            ]),
+            Fq::from_montgomery_limbs([
+                13139837338579079309,
+                2589423501287050532,
+                16180244219887269586,
+                1140503787561511020,
+            ]),
+            Fq::from_montgomery_limbs([
+                7766438616545098511,
+                68618141087994671,
+                1583489959224555265,
+                26236553087344928,
+            ]),
+            Fq::from_montgomery_limbs([
+                3821097146087390780,
+                2283180669530777799,
+                5615620186229858006,
+                827544319449532456,
+            ]),
+            Fq::from_montgomery_limbs([
+                1908488774578625176,
+                32375968893971643,
+                1253793092035050460,
+                896053039022698246,
+            ]),
+            Fq::from_montgomery_limbs([
+                1914215329655986748,
+                11504921081661879163,
+                963976105566085805,
+                869954369546512003,
+            ]),
+            Fq::from_montgomery_limbs([
+                2690217650331687534,
+                18189598914085710186,
+                7292378291930949579,
+                894502715913468713,
+            ]),
+            Fq::from_montgomery_limbs([
+                3198116987447576264,
+                11738904980252954960,
+                14175837888670040709,
+                217656084450982075,
+            ]),
+            Fq::from_montgomery_limbs([
+                4292109729959261869,
+                17915540993812705919,
+                4147467570537565320,
+                278276406048462369,
+            ]),
+            Fq::from_montgomery_limbs([
+                1625082042022523006,
+                9345819391150387523,
+                4758144293898796388,
+                973847961259151085,
+            ]),
+            Fq::from_montgomery_limbs([
+                9041975382400615039,
+                8737366705443268356,
+                9672491820090816734,
+                1191459170232089027,
+            ]),
+            Fq::from_montgomery_limbs([
+                231310363141257560,
+                1701593894296191590,
+                5357368581897288883,
+                480857885348742730,
+            ]),
+            Fq::from_montgomery_limbs([
+                8289226573663174392,
+                3814011928134312122,
+                5233235737996513007,
+                571099947241020865,
+            ]),
+            Fq::from_montgomery_limbs([
+                16497542611534088257,
+                10296105944002178691,
+                764378209135744323,
+                561477945969837636,
+            ]),
+            Fq::from_montgomery_limbs([
+                4308101535517828075,
+                6454211013800932729,
+                16591037286374748055,
+                936794846685594552,
+            ]),
+            Fq::from_montgomery_limbs([
+                2382426058566410802,
+                1298673694681109099,
+                7940998941782865013,
+                1291933836633258039,
+            ]),
+            Fq::from_montgomery_limbs([
+                10366102445579860530,
+                1130046654217638128,
+                9112235557047140411,
+                842974738158896962,
+            ]),
+            Fq::from_montgomery_limbs([
+                17307568981206413732,
+                16759629810467581294,
+                13706543091203944766,
+                337047690477965166,
+            ]),
+            Fq::from_montgomery_limbs([
+                4931544806382174509,
+                18291710067415842506,
+                16056446391734475734,
+                271791441982863468,
+            ]),
+            Fq::from_montgomery_limbs([
+                3396379322324989757,
+                5567743951717254249,
+                12667084005370333173,
+                1077910833047817237,
+            ]),
+            Fq::from_montgomery_limbs([
+                11782196679283566061,
+                6415027592392725209,
+                3707578317878066189,
+                963570372335627225,
+            ]),
+            Fq::from_montgomery_limbs([
+                10434753360440248323,
+                17944862253021779195,
+                181722012803367964,
+                279357526608040292,
+            ]),
+            Fq::from_montgomery_limbs([
+                6566910575461655478,
+                14586113219145981696,
+                8443510753323910016,
+                539704752169904828,
+            ]),
+            Fq::from_montgomery_limbs([
+                253679517303231970,
+                14596596023715514302,
+                12593576382224891529,
+                123475409993244798,
+            ]),
+            Fq::from_montgomery_limbs([
+                9387067896384665768,
+                2563189185683913637,
+                1238196701337049665,
+                737376553455256397,
+            ]),
+            Fq::from_montgomery_limbs([
+                12344337649624966943,
+                17903675778342714560,
+                3142226095929989974,
+                368354314690906515,
+            ]),
+            Fq::from_montgomery_limbs([
+                4775317799970390607,
+                15646876649613492775,
+                8301428968569448207,
+                274400106011354129,
+            ]),
+            Fq::from_montgomery_limbs([
+                5655965930644684074,
+                356073045093459297,
+                8116919189927000200,
+                230547887756282731,
+            ]),
+            Fq::from_montgomery_limbs([
+                20870680352220131,
+                108323435377195981,
+                4273795745706587884,
+                48318619577122995,
+            ]),
+            Fq::from_montgomery_limbs([
+                13840205916050673602,
+                10475570964141113970,
+                12753493627562870651,
+                822446909953680697,
+            ]),
+            Fq::from_montgomery_limbs([
+                7774342647318436199,
+                17951304903057699666,
+                5851978948994219895,
+                1254715956194199562,
+            ]),
+            Fq::from_montgomery_limbs([
+                12636437205948183099,
+                9556571061085975821,
+                8113800635021251639,
+                1042700651112412240,
+            ]),
+            Fq::from_montgomery_limbs([
+                13277741849771869165,
+                15021696782618758112,
+                6146557164372978696,
+                857877583413046190,
+            ]),
+            Fq::from_montgomery_limbs([
+                10786245123128200232,
+                12885933795906832160,
+                13564141492747084479,
+                1162609315734579787,
+            ]),
+            Fq::from_montgomery_limbs([
+                2558049049715541531,
+                14955219458447357563,
+                13904292007372063743,
+                337981064346094233,
+            ]),
+            Fq::from_montgomery_limbs([
+                11852069379299073876,
+                7738926941664900819,
+                8513445452181408582,
+                515513787907661704,
+            ]),
+            Fq::from_montgomery_limbs([
+                16519863445275075469,
+                3685353298780696063,
+                589852488244775995,
+                166404064088821246,
+            ]),
+            Fq::from_montgomery_limbs([
+                14225189889490901665,
+                7355588840116388073,
+                16531535008117928656,
+                640787418225033373,
+            ]),
+            Fq::from_montgomery_limbs([
+                10840258681943348034,
+                17368586966420858444,
+                13709101805828914876,
+                539202928716324034,
+            ]),
+            Fq::from_montgomery_limbs([
+                15409292334296423560,
+                17807995371473709688,
+                9973226122368985581,
+                423087141147427896,
+            ]),
+            Fq::from_montgomery_limbs([
+                1495446486227229496,
+                15609825971515592905,
+                8465294992716128821,
+                1082178778114151090,
+            ]),
+            Fq::from_montgomery_limbs([
+                8973920061844011857,
+                1640964576029581537,
+                1620838986268687868,
+                794497609331994470,
+            ]),
+            Fq::from_montgomery_limbs([
+                16773853315459331174,
+                16713366134383702694,
+                9522437874107583455,
+                459447895976866034,
+            ]),
+            Fq::from_montgomery_limbs([
+                14565988126010871335,
+                12138589510870037152,
+                5979795467172851104,
+                113899136602326304,
+            ]),
+            Fq::from_montgomery_limbs([
+                12641302770834823770,
+                1386841598031656067,
+                3063238557141339172,
+                1242460952210781068,
+            ]),
+            Fq::from_montgomery_limbs([
+                3285366934576279210,
+                2731970759568718790,
+                3527284026873332099,
+                784257971951730495,
+            ]),
+            Fq::from_montgomery_limbs([
+                1674443281073659353,
+                16045250772277687976,
+                4935255757226707514,
+                975119854194506996,
+            ]),
+            Fq::from_montgomery_limbs([
+                8488194983196139412,
+                13473762464023503057,
+                11541508830277593061,
+                319779393846592595,
+            ]),
+            Fq::from_montgomery_limbs([
+                14955731980443887295,
+                9781988187973080000,
+                11213431479681107012,
+                792924573799185462,
+            ]),
+            Fq::from_montgomery_limbs([
+                17048503050936710208,
+                523370852475448556,
+                16258257699411417506,
+                1320301970749934976,
+            ]),
+            Fq::from_montgomery_limbs([
+                6303819133760880546,
+                15359583346726996884,
+                7429641660879352367,
+                244440141751568843,
+            ]),
+            Fq::from_montgomery_limbs([
+                9144608689410947134,
+                18326048570858025263,
+                12278571120651681585,
+                1216566339293741835,
+            ]),
+            Fq::from_montgomery_limbs([
+                10485726603086192675,
+                6390352459096081751,
+                15586389920104013282,
+                103220813257074632,
+            ]),
+            Fq::from_montgomery_limbs([
+                11819141297878832797,
+                8317570300358307019,
+                6267900666016774927,
+                90539594250250042,
+            ]),
+            Fq::from_montgomery_limbs([
+                923081152647188201,
+                3078759891103605255,
+                12941461915287440848,
+                932705487564969631,
+            ]),
+            Fq::from_montgomery_limbs([
+                10597202429082940008,
+                14197710321812415628,
+                6748723689027239060,
+                256499669099115790,
+            ]),
+            Fq::from_montgomery_limbs([
+                2711013027766462396,
+                4690174077323734353,
+                7018550318252803338,
+                256920550452786017,
+            ]),
+            Fq::from_montgomery_limbs([
+                9016769911608652209,
+                10769083157276207757,
+                9532955166199443442,
+                1170910016289229413,
+            ]),
+            Fq::from_montgomery_limbs([
+                18117789092251726844,
+                8291018747853442686,
+                16670558817145961843,
+                810920691424817262,
+            ]),
+            Fq::from_montgomery_limbs([
+                8096987654062331167,
+                13889072638136203802,
+                10146176680279463033,
+                262326532439829233,
+            ]),
+            Fq::from_montgomery_limbs([
+                9463308479148021799,
+                3422279074755886818,
+                2000910647302398708,
+                617522511431506216,
+            ]),
+            Fq::from_montgomery_limbs([
+                8291175314452896135,
+                1189083258043964473,
+                9695871436109655930,
+                209556690251646526,
+            ]),
+            Fq::from_montgomery_limbs([
+                14107049811015168904,
+                6245061574483827753,
+                13334076939677145253,
+                1266330182487864108,
+            ]),
+            Fq::from_montgomery_limbs([
+                10964071593335288967,
+                7177493225790005345,
+                15380656942425167023,
+                1050373931004426698,
+            ]),
+            Fq::from_montgomery_limbs([
+                4989038912276650034,
+                13847110490413732948,
+                12308755615717664648,
+                539857441082185643,
+            ]),
+            Fq::from_montgomery_limbs([
+                15853328799249725287,
+                3508972454289789180,
+                8415028588260892850,
+                563618024250556950,
+            ]),
+            Fq::from_montgomery_limbs([
+                2692121684615099069,
+                9026485937285024381,
+                18103187477458989360,
+                37341499552336588,
+            ]),
+            Fq::from_montgomery_limbs([
+                3267217456645540443,
+                11337284919704221888,
+                8270333539799458637,
+                51041023859166905,
+            ]),
+            Fq::from_montgomery_limbs([
+                15585194635383427072,
+                1146915379576543752,
+                9692767813192030093,
+                1327685912075668013,
+            ]),
+            Fq::from_montgomery_limbs([
+                16999321032904087622,
+                3341995730019025734,
+                9343758082434331319,
+                371262335804675135,
+            ]),
+            Fq::from_montgomery_limbs([
+                164328930212115250,
+                8886647009618613092,
+                17352671088172202903,
+                1343915164592089700,
+            ]),
+            Fq::from_montgomery_limbs([
+                8240080380530539391,
+                3230117752976232044,
+                9576880468695410930,
+                23786344000398813,
+            ]),
+            Fq::from_montgomery_limbs([
+                8051300153840186488,
+                7128232865904326923,
+                6490435793548441890,
+                1163530731706961791,
+            ]),
+            Fq::from_montgomery_limbs([
+                3106289831863122933,
+                520662934103161970,
+                13412494385009018901,
+                659875374124152791,
+            ]),
+            Fq::from_montgomery_limbs([
+                3068070917565700496,
+                16794508557524790403,
+                7815995979669319648,
+                1008662177441904655,
+            ]),
+            Fq::from_montgomery_limbs([
+                248386857367067334,
+                15310648018320884420,
+                2450757026337881309,
+                806800194358542194,
+            ]),
+            Fq::from_montgomery_limbs([
+                5497846004362148056,
+                15219867689740294765,
+                9361650833976411186,
+                1083471762652254717,
+            ]),
+            Fq::from_montgomery_limbs([
+                6748046855453987898,
+                12851789274806510045,
+                14235179691077871122,
+                926520417531593159,
+            ]),
+            Fq::from_montgomery_limbs([
+                14923856031980774981,
+                1757772463865070067,
+                15035545447437193485,
+                938820706497311769,
+            ]),
+            Fq::from_montgomery_limbs([
+                9889848158977980448,
+                9551867219433473372,
+                15760115856808009263,
+                727462531096521748,
+            ]),
+            Fq::from_montgomery_limbs([
+                12472742104746626777,
+                7009285322019172517,
+                11063826624156763647,
+                471062033384488141,
+            ]),
+            Fq::from_montgomery_limbs([
+                2707945465360869920,
+                15453542186117087368,
+                16110808676894468894,
+                549814715641218106,
+            ]),
+            Fq::from_montgomery_limbs([
+                6273379585489027509,
+                7407071499849555392,
+                448653797166551867,
+                158484720942495251,
+            ]),
+            Fq::from_montgomery_limbs([
+                16068253311113991500,
+                5313298848782958147,
+                15099454651315878079,
+                645239972726986763,
+            ]),
+            Fq::from_montgomery_limbs([
+                3166260684386440734,
+                927625392489861625,
+                4550638389253356197,
+                732784515359289277,
+            ]),
+            Fq::from_montgomery_limbs([
+                7825718526555504243,
+                3074561828893640139,
+                8504610340209180759,
+                1204938301513946232,
+            ]),
+            Fq::from_montgomery_limbs([
+                16590089562050045166,
+                15318040791485973363,
+                16296951362248527138,
+                94816801637853836,
+            ]),
+            Fq::from_montgomery_limbs([
+                209434884099981984,
+                2525842171155962054,
+                18108633626655749873,
+                634919261711101386,
+            ]),
+            Fq::from_montgomery_limbs([
+                1694457101213952637,
+                7837580239482951920,
+                7935209482441249784,
+                1138192988130713211,
+            ]),
+            Fq::from_montgomery_limbs([
+                4571453007850918411,
+                7496332742003952080,
+                4645650270200687704,
+                677474432189874890,
+            ]),
+            Fq::from_montgomery_limbs([
+                3810310658983818136,
+                16777907134258243712,
+                7618653208566868762,
+                80685722358562659,
+            ]),
+            Fq::from_montgomery_limbs([
+                6074822922716894159,
+                379135554677269370,
+                4755274287891192639,
+                712149995405816538,
+            ]),
+            Fq::from_montgomery_limbs([
+                9398146340450603614,
+                13656626109170949355,
+                4206388252900436669,
+                712374851684604587,
+            ]),
+            Fq::from_montgomery_limbs([
+                13229014451969367473,
+                12397958304261392972,
+                186636368608756996,
+                1312321585709495991,
+            ]),
+            Fq::from_montgomery_limbs([
+                10458180247700457184,
+                5175760340997939484,
+                3757482351096738149,
+                750206174018533066,
+            ]),
+            Fq::from_montgomery_limbs([
+                10557949515959643562,
+                2907325034248742142,
+                7820457820694761159,
+                290749739335722645,
+            ]),
+            Fq::from_montgomery_limbs([
+                16688704309944938500,
+                17954458139214695546,
+                15036073402706434263,
+                994989918543406897,
+            ]),
+            Fq::from_montgomery_limbs([
+                8360857513566531461,
+                12639699046081178706,
+                16971221084187574270,
+                847197506118687572,
+            ]),
+            Fq::from_montgomery_limbs([
+                17304799071807649965,
+                16705132920247540546,
+                11342974618217714755,
+                779570634854687985,
+            ]),
+            Fq::from_montgomery_limbs([
+                11122287374362842801,
+                16068855722185348298,
+                720482043908170630,
+                1337259726411140477,
+            ]),
+            Fq::from_montgomery_limbs([
+                4128000705429688983,
+                12555918725387059738,
+                229070124062304980,
+                1038583356800825925,
+            ]),
+            Fq::from_montgomery_limbs([
+                15079168814135007815,
+                13560529375771456713,
+                17378380060632869672,
+                1251212563465078830,
+            ]),
+            Fq::from_montgomery_limbs([
+                10583578867119842790,
+                15895432159400160946,
+                12520440837657689893,
+                378944284664662868,
+            ]),
+            Fq::from_montgomery_limbs([
+                15049607456079385625,
+                1244524219329539087,
+                776874999438654473,
+                1035263765070149268,
+            ]),
+            Fq::from_montgomery_limbs([
+                9106509982640717793,
+                1935121792416877046,
+                14195000593193744232,
+                613157581553676667,
+            ]),
+            Fq::from_montgomery_limbs([
+                17139940223150587046,
+                18212045110211804545,
+                13467455029176599776,
+                2862430910784558,
+            ]),
+            Fq::from_montgomery_limbs([
+                2986309766187572246,
+                8161089396013002711,
+                871748944517281667,
+                611601724840435664,
+            ]),
+            Fq::from_montgomery_limbs([
+                14269179762374003766,
+                2558731276372429143,
+                13895692991742592550,
+                792890617223684794,
+            ]),
+            Fq::from_montgomery_limbs([
+                15182031257285619846,
+                559336077037355236,
+                2141467392895649714,
+                1115595814311673694,
+            ]),
+            Fq::from_montgomery_limbs([
+                13916694289621399273,
+                9203335025901541072,
+                3011170307945601647,
+                746171957751059944,
+            ]),
+            Fq::from_montgomery_limbs([
+                11356392461894158691,
+                2377761000047318539,
+                15499658155668402427,
+                1144050151777776588,
+            ]),
+            Fq::from_montgomery_limbs([
+                4749039603335125343,
+                15027688761292083551,
+                6793263167067414305,
+                1076776384471150913,
+            ]),
+            Fq::from_montgomery_limbs([
+                13601435860162460119,
+                4281859761315550976,
+                4140581273909054020,
+                550224951244287912,
+            ]),
+            Fq::from_montgomery_limbs([
+                14452136125215456848,
+                8117169532059770228,
+                8366335879838362637,
+                813189157831908785,
+            ]),
+            Fq::from_montgomery_limbs([
+                17965791946252756702,
+                1782995469274990925,
+                1546625699976414237,
+                1167479806585556429,
+            ]),
+            Fq::from_montgomery_limbs([
+                6706008356343320663,
+                15197938903185865846,
+                11295762097706528948,
+                1146711620415947855,
+            ]),
+            Fq::from_montgomery_limbs([
+                7529901621498064259,
+                14949803348258726086,
+                5949347659619190856,
+                261668282562446407,
+            ]),
+            Fq::from_montgomery_limbs([
+                1618335208919415263,
+                7649771183831572177,
+                15267991431625213301,
+                1051076097571387214,
+            ]),
+            Fq::from_montgomery_limbs([
+                2021524993860164215,
+                6742811608757140104,
+                2219563905641018105,
+                620830181964694649,
+            ]),
+            Fq::from_montgomery_limbs([
+                13679281090568803599,
+                12279197653164348480,
+                16639967111838469954,
+                800568921349012709,
+            ]),
+            Fq::from_montgomery_limbs([
+                17699385913850761703,
+                9000613873257822795,
+                3365552778106442675,
+                1021671596961498348,
+            ]),
+            Fq::from_montgomery_limbs([
+                12095280725428160834,
+                13245397684286454112,
+                15366360449863108874,
+                20332569756556480,
+            ]),
+            Fq::from_montgomery_limbs([
+                10389375573870449050,
+                10162905687213105526,
+                14502707019238843498,
+                315932370515477568,
+            ]),
+            Fq::from_montgomery_limbs([
+                12507204590259436758,
+                4548895085290843869,
+                13070918718643817940,
+                647837623274106272,
+            ]),
+            Fq::from_montgomery_limbs([
+                7926957930111138763,
+                17163508456805475875,
+                17509126965019470094,
+                1297829314811904637,
+            ]),
+            Fq::from_montgomery_limbs([
+                6603657254003143828,
+                5067113185954270620,
+                18066800702830678583,
+                480216422820809999,
+            ]),
+            Fq::from_montgomery_limbs([
+                14354026207865681871,
+                17876762337369634788,
+                6055656461794007489,
+                136601389233539575,
+            ]),
+            Fq::from_montgomery_limbs([
+                8846127626412737040,
+                13522114759664547847,
+                5914831906430692374,
+                1254658871126732685,
+            ]),
+            Fq::from_montgomery_limbs([
+                9423670838435603896,
+                5582865680046729463,
+                11453604313436141824,
+                279942356476018684,
+            ]),
+            Fq::from_montgomery_limbs([
+                10100901511586489394,
+                3102965767007288441,
+                13842092038380591638,
+                309000393644733318,
+            ]),
+            Fq::from_montgomery_limbs([
+                17424283936957438653,
+                16038867713021434234,
+                15305854988627494575,
+                434981817589966951,
+            ]),
+            Fq::from_montgomery_limbs([
+                10315332220971088231,
+                6403833922955769491,
+                17733952791238384187,
+                1188041989394949736,
+            ]),
+            Fq::from_montgomery_limbs([
+                8619001037595728449,
+                756518432992042660,
+                648867700699607083,
+                147081464955229559,
+            ]),
+            Fq::from_montgomery_limbs([
+                12055298703575283697,
+                11372730656636899847,
+                7695279434279059760,
+                430663454196003483,
+            ]),
+            Fq::from_montgomery_limbs([
+                7831157321996369238,
+                8525379696728048192,
+                5537570257529731166,
+                271954256579798822,
+            ]),
+            Fq::from_montgomery_limbs([
+                10285072428852080886,
+                9453319818802459065,
+                4414555697282984063,
+                419543154423683216,
+            ]),
+            Fq::from_montgomery_limbs([
+                8623083057897693655,
+                5321895717937314029,
+                8866443591631431119,
+                249934004949445082,
+            ]),
+            Fq::from_montgomery_limbs([
+                6611589654169923134,
+                6165794549739122385,
+                3618836214046100946,
+                995727511866928300,
+            ]),
+            Fq::from_montgomery_limbs([
+                17659572695341115508,
+                8151631649054889762,
+                9091386574483692216,
+                1239151137396722426,
+            ]),
+            Fq::from_montgomery_limbs([
+                13332948987006359328,
+                11243869676364922109,
+                1355906186482080145,
+                1338350766905350333,
+            ]),
+            Fq::from_montgomery_limbs([
+                14848061394465218539,
+                7230337283640823794,
+                1145359904248371950,
+                31312763315701097,
+            ]),
+            Fq::from_montgomery_limbs([
+                10264696895645781643,
+                9781222319332361898,
+                4249086114864416204,
+                404300997687790756,
+            ]),
+            Fq::from_montgomery_limbs([
+                14703847408588668030,
+                13139538198295869635,
+                15946606906976933980,
+                584451306389372710,
+            ]),
+            Fq::from_montgomery_limbs([
+                8571296973873443351,
+                5579085162346014993,
+                3009874933870173958,
+                307236118668962775,
+            ]),
+            Fq::from_montgomery_limbs([
+                10753473981156631900,
+                16210406066469191674,
+                3645345566250770730,
+                524828787667497400,
+            ]),
+            Fq::from_montgomery_limbs([
+                11020720037108622862,
+                4731360110856014106,
+                17439649643793869285,
+                1011797814211688225,
+            ]),
+            Fq::from_montgomery_limbs([
+                2025932076155854108,
+                15415291597291741444,
+                5370487235974620969,
+                485010955337500446,
+            ]),
+            Fq::from_montgomery_limbs([
+                10039804333889995232,
+                1603924354686325005,
+                13488713443325349377,
+                512480708223174722,
+            ]),
+            Fq::from_montgomery_limbs([
+                6838465933067546366,
+                6375761555474343401,
+                17306950058772231431,
+                676338285192619294,
+            ]),
+            Fq::from_montgomery_limbs([
+                6557502706085664639,
+                11800058166169609383,
+                13414073819293042668,
+                1051987702596099315,
+            ]),
+            Fq::from_montgomery_limbs([
+                17457085020310459441,
+                9604015820333107174,
+                16610416843005877994,
+                653000572095507459,
+            ]),
+            Fq::from_montgomery_limbs([
+                11239653120644352458,
+                13215092606135168930,
+                13853640726441514588,
+                491904790487770917,
+            ]),
+            Fq::from_montgomery_limbs([
+                12388610963427082540,
+                8588353481596582538,
+                6224565906694025168,
+                4450339470804250,
+            ]),
+            Fq::from_montgomery_limbs([
+                1360895278082123854,
+                4425765911936386481,
+                4933029523818278077,
+                1255194766375522459,
+            ]),
+            Fq::from_montgomery_limbs([
+                10997442437177320508,
+                11307473842400821367,
+                5674744090501267204,
+                561746049595568228,
+            ]),
+            Fq::from_montgomery_limbs([
+                6580888919012335861,
+                4483231750732135889,
+                11066763705945337652,
+                887980935920553784,
+            ]),
+            Fq::from_montgomery_limbs([
+                12201873745478817706,
+                12539697304761479958,
+                7168743621473187107,
+                81892637121051813,
+            ]),
+            Fq::from_montgomery_limbs([
+                12957446575214274944,
+                818630920428784927,
+                8028980307526442369,
+                362277193614302242,
+            ]),
+            Fq::from_montgomery_limbs([
+                16441903800826785506,
+                4893940562371085665,
+                8943826448574419501,
+                761983845484674994,
+            ]),
+            Fq::from_montgomery_limbs([
+                11945525083004781820,
+                7933140436433025647,
+                12831559082054521686,
+                180095650249938339,
+            ]),
+            Fq::from_montgomery_limbs([
+                13083834613627567757,
+                14650651674927144492,
+                8005594884873785067,
+                685145743779146510,
+            ]),
+            Fq::from_montgomery_limbs([
+                1806916684431257285,
+                7322952327499742552,
+                929995553645611087,
+                26216246110674618,
+            ]),
+            Fq::from_montgomery_limbs([
+                1868216509923091449,
+                3256767755178886827,
+                15757994913916298297,
+                277050455802605581,
+            ]),
+            Fq::from_montgomery_limbs([
+                7222614397520618199,
+                285925531104200531,
+                9615877651629292934,
+                734747825202877498,
+            ]),
+            Fq::from_montgomery_limbs([
+                8766218392624732709,
+                15626352422449719897,
+                1364289674181685189,
+                263939518365815692,
+            ]),
+            Fq::from_montgomery_limbs([
+                16494372655822012023,
+                13793567603105198032,
+                3952403833302480398,
+                1013620353261658052,
+            ]),
+            Fq::from_montgomery_limbs([
+                14552605403229212206,
+                9077858199961846232,
+                6335875375246775223,
+                884108256920542822,
+            ]),
+            Fq::from_montgomery_limbs([
+                15781631520715456438,
+                16011223026301467308,
+                18273783711368106514,
+                337713403232479394,
+            ]),
+            Fq::from_montgomery_limbs([
+                2331579550164617443,
+                15313501529563677854,
+                13650293811008869511,
+                911821240136203017,
+            ]),
+            Fq::from_montgomery_limbs([
+                12648205783473631050,
+                13050669802285902577,
+                10894851397381049732,
+                475930803168453709,
+            ]),
+            Fq::from_montgomery_limbs([
+                4199425603207459854,
+                9696875946114088403,
+                11670445875171998042,
+                115581294739556178,
+            ]),
+            Fq::from_montgomery_limbs([
+                12888235663925034537,
+                12635420495357906354,
+                15446076241703318784,
+                354157930420716598,
+            ]),
+            Fq::from_montgomery_limbs([
+                8350720804275174769,
+                3324579013199306227,
+                17966249601417130036,
+                863366707546495616,
+            ]),
+            Fq::from_montgomery_limbs([
+                8896843971063482051,
+                6150311025803635476,
+                7691624663721327150,
+                1195531663138779983,
+            ]),
+            Fq::from_montgomery_limbs([
+                5957791375758802694,
+                547655494963361135,
+                7818638246702954548,
+                1309237963437611597,
+            ]),
+            Fq::from_montgomery_limbs([
+                14510198047027160250,
+                5944200919202126587,
+                17614817573483756409,
+                322251125054476709,
+            ]),
+            Fq::from_montgomery_limbs([
+                8299717096465473997,
+                9378776476922042323,
+                16778734249568365271,
+                136338964104468684,
+            ]),
+            Fq::from_montgomery_limbs([
+                13325839854980833489,
+                7008909107583921936,
+                10840835137777992143,
+                1224480656521590808,
+            ]),
+            Fq::from_montgomery_limbs([
+                6583430563251747868,
+                17474344670280231398,
+                9942835450663565687,
+                362285910377628792,
+            ]),
+            Fq::from_montgomery_limbs([
+                17693668625508214322,
+                15578950702654056291,
+                11720500300464986845,
+                888287680711279017,
+            ]),
+            Fq::from_montgomery_limbs([
+                13633756099285019616,
+                2639254643084558228,
+                3316733787704168169,
+                98252009794785276,
+            ]),
+            Fq::from_montgomery_limbs([
+                15438347715313813676,
+                14204578308772480392,
+                2405954628914080747,
+                593383184267230648,
+            ]),
+            Fq::from_montgomery_limbs([
+                14390939472872414995,
+                6723599994053949636,
+                7719068117079455090,
+                569411217023320325,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441111968622351938,
+                3306758772824150647,
+                14155216287878497331,
+                335743432529757710,
+            ]),
+            Fq::from_montgomery_limbs([
+                16353078132542315513,
+                16661344352167922816,
+                6814653935903964634,
+                684853553251634726,
+            ]),
+            Fq::from_montgomery_limbs([
+                7058388037654535394,
+                10218588620156638801,
+                4390162122604560050,
+                240436233025147900,
+            ]),
+            Fq::from_montgomery_limbs([
+                9770894966771722990,
+                13148190807128975340,
+                18359564855051885212,
+                77028156380655248,
+            ]),
+            Fq::from_montgomery_limbs([
+                12428738226968571343,
+                7483127523846549569,
+                2350828867512223683,
+                530431416818812045,
+            ]),
+            Fq::from_montgomery_limbs([
+                16082684939151747725,
+                18186448787137315203,
+                13101282262565109770,
+                571299151310900231,
+            ]),
+            Fq::from_montgomery_limbs([
+                15025097252526737186,
+                267522472518637123,
+                865902149954290605,
+                308128082144277312,
+            ]),
+            Fq::from_montgomery_limbs([
+                7286289087511392001,
+                8042607287137306117,
+                6696256449188059663,
+                323019173624071977,
+            ]),
+            Fq::from_montgomery_limbs([
+                362189797960808848,
+                1768677699372549990,
+                14365509670582212574,
+                778750678657633503,
+            ]),
+            Fq::from_montgomery_limbs([
+                1648612042896348518,
+                6069052209739706650,
+                4578251149647972531,
+                51999794111772272,
+            ]),
+            Fq::from_montgomery_limbs([
+                7038705965443879055,
+                18033025617790987631,
+                3926743875613798775,
+                210292702295299913,
+            ]),
+            Fq::from_montgomery_limbs([
+                18088956673641739422,
+                11240454085805512728,
+                811340669826973601,
+                1073368656994547672,
+            ]),
+            Fq::from_montgomery_limbs([
+                2329845114941179010,
+                5455197097243920354,
+                15501006592869012925,
+                149822331412178416,
+            ]),
+            Fq::from_montgomery_limbs([
+                2046409455430636442,
+                5798058422476380167,
+                13641655309385248651,
+                605904760107919263,
+            ]),
+            Fq::from_montgomery_limbs([
+                2765745068841391649,
+                15754460350748458290,
+                1067895905991450216,
+                1108816934221606254,
+            ]),
+            Fq::from_montgomery_limbs([
+                12236988021340026815,
+                6605981147474776646,
+                12159951035388484029,
+                989507464463207175,
+            ]),
+            Fq::from_montgomery_limbs([
+                2565136022898539176,
+                5898967318553345302,
+                10519970360719057258,
+                1335627164555087980,
+            ]),
+            Fq::from_montgomery_limbs([
+                1899170277317909619,
+                3903606086852139539,
+                1569715214596441606,
+                537938927164632834,
+            ]),
+            Fq::from_montgomery_limbs([
+                14377004036228463100,
+                13884531393256135037,
+                16785967592517056771,
+                971130941245907844,
+            ]),
+            Fq::from_montgomery_limbs([
+                16517595505747876031,
+                10695428620982982643,
+                17902977011753176753,
+                414080157193217342,
+            ]),
+            Fq::from_montgomery_limbs([
+                12861454224398249306,
+                4427212108516523896,
+                9680494455747704731,
+                882854219936053581,
+            ]),
+            Fq::from_montgomery_limbs([
+                18421831060106896303,
+                4591588344291539813,
+                8596768247083940908,
+                100463334932668435,
+            ]),
+            Fq::from_montgomery_limbs([
+                6884303217121207496,
+                6643321269552783060,
+                15447610599378039068,
+                1075407988534691950,
+            ]),
+            Fq::from_montgomery_limbs([
+                12691953431650041322,
+                3070555314035558976,
+                12620768937331401632,
+                501854500456079711,
+            ]),
+            Fq::from_montgomery_limbs([
+                16635981235271059864,
+                4382809221985020886,
+                14585572596436192059,
+                366727186109234398,
+            ]),
+            Fq::from_montgomery_limbs([
+                8044854798318201439,
+                1942027497327862734,
+                1114554029773193662,
+                833422882209976129,
+            ]),
+            Fq::from_montgomery_limbs([
+                10803896681457076385,
+                4965726744911587041,
+                7835914204394462906,
+                261102170344394955,
+            ]),
+            Fq::from_montgomery_limbs([
+                8646493149162605255,
+                8599718904823501503,
+                2078357101832997089,
+                867549592724743171,
+            ]),
+            Fq::from_montgomery_limbs([
+                13338063478434626603,
+                8592750320643548305,
+                2381082446924771343,
+                421568457458901953,
+            ]),
+            Fq::from_montgomery_limbs([
+                8007725389287649244,
+                1933006982481958215,
+                17082328733919670031,
+                274272879454078543,
+            ]),
+            Fq::from_montgomery_limbs([
+                8974406735032645958,
+                14463203048092788690,
+                5586214615650386808,
+                1112186704964753558,
+            ]),
+            Fq::from_montgomery_limbs([
+                17590993187194015370,
+                1941954509452557752,
+                5578471546267898749,
+                320612954920932640,
+            ]),
+            Fq::from_montgomery_limbs([
+                16113086414630441769,
+                6951996000615760544,
+                4134835195487724156,
+                983871474164576061,
+            ]),
+            Fq::from_montgomery_limbs([
+                18072599409155259911,
+                522686414497854748,
+                14888185274318166577,
+                686170048690167505,
+            ]),
+            Fq::from_montgomery_limbs([
+                18117523416664913421,
+                2091354521292077134,
+                16770142514374276294,
+                444938951735603945,
+            ]),
+            Fq::from_montgomery_limbs([
+                6669012711501435001,
+                14680978138326831381,
+                17692227144773468875,
+                571790772755351479,
+            ]),
+            Fq::from_montgomery_limbs([
+                551649610550401944,
+                13064474688739741976,
+                13744403183929279538,
+                409774136299625809,
+            ]),
+            Fq::from_montgomery_limbs([
+                12603432824596095242,
+                2896371567848062279,
+                3747749936406648789,
+                1157533236661903265,
+            ]),
+            Fq::from_montgomery_limbs([
+                7875446363122710755,
+                15813975292686464565,
+                4047929278227204931,
+                185250085632988976,
+            ]),
+            Fq::from_montgomery_limbs([
+                6963270672833118126,
+                889262136681532921,
+                4735806924791593867,
+                134989135766740515,
+            ]),
+            Fq::from_montgomery_limbs([
+                14276605466786675879,
+                4299997600399188402,
+                15065649890208689864,
+                720475593695216403,
+            ]),
+            Fq::from_montgomery_limbs([
+                16423585900078135031,
+                9685760567779794069,
+                12286538129011698454,
+                1071661762698928445,
+            ]),
+            Fq::from_montgomery_limbs([
+                13934506748135487365,
+                14831476571116488253,
+                2230237695211091541,
+                443794757403525790,
+            ]),
+            Fq::from_montgomery_limbs([
+                15742515544674722696,
+                3819991402408037078,
+                4001568198763353664,
+                338791585255934212,
+            ]),
+            Fq::from_montgomery_limbs([
+                576088269219064898,
+                17122485426894884517,
+                6555368543729518575,
+                428871848374365290,
+            ]),
+            Fq::from_montgomery_limbs([
+                134513238053566374,
+                879144446341590733,
+                16020692186306163498,
+                133354295590421929,
+            ]),
+            Fq::from_montgomery_limbs([
+                13688474863119279882,
+                17615687008249095050,
+                1327917845947713179,
+                171570537941364694,
+            ]),
+            Fq::from_montgomery_limbs([
+                3834244769757578956,
+                17483002540086802602,
+                3180077761419275783,
+                366823511150355773,
+            ]),
+            Fq::from_montgomery_limbs([
+                16901482421875478975,
+                5849544982390815353,
+                15136147457215954829,
+                23300089029330332,
+            ]),
+            Fq::from_montgomery_limbs([
+                13888881305744423945,
+                17867242798477498818,
+                4745485017764378216,
+                955568148536909494,
+            ]),
+        ]),
+        mds: MdsMatrix::<14, 13, 196, 169>::new_from_known([
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+        ]),
+        alpha: Alpha::Exponent(17),
+        rounds: RoundNumbers { r_P: 31, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<13, 169>::new_from_known([
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+            ]),
+            v: Matrix::<1, 13, 13>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+            ]),
+            w: Matrix::<13, 1, 13>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<14, 196>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<14, 196>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9815670447847785208,
+                    4079722876937034884,
+                    11422378354384329757,
+                    649165021361371380,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17511430873597716413,
+                    5928873472577192420,
+                    601659625206462026,
+                    1012012616824126626,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13020196276380576785,
+                    3383189349857083150,
+                    13321358381597132627,
+                    336353199953950399,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7212006124599567829,
+                    5963800377430924987,
+                    10691281994720493347,
+                    906053260013683670,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    779741893339597989,
+                    8271220249268629585,
+                    1516467479562616512,
+                    354453287334411338,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17311713310339808713,
+                    5418945487078430132,
+                    16995790256922293747,
+                    296427064414833774,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18443760932771078673,
+                    3573418537246988751,
+                    17886835747984975455,
+                    993472777891601895,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    16039718217605904964,
+                    14428872421704602280,
+                    2265362357159518543,
+                    175173992188968561,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    16200769246183089049,
+                    5301470113129033670,
+                    5782779082402413054,
+                    725031552893183902,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3531984614542155345,
+                    345225405672502444,
+                    4256534367274605288,
+                    113430696835942176,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8431184946226427808,
+                    14531258229319175920,
+                    7855670430886528500,
+                    1136824546426329288,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17064449589624660505,
+                    2828292894870035524,
+                    5004361708518878852,
+                    1061074234081591076,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18206602904122391115,
+                    581558783644439675,
+                    18370197606070546546,
+                    603461597426783843,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<14, 196>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    1095951094000311856,
+                    18212547121051977198,
+                    18131027634969741558,
+                    425212786724209901,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6119058429645824891,
+                    739585290520487833,
+                    10363080840375298482,
+                    174689933526925552,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3809470201779011112,
+                    150466566200723806,
+                    4693728173639381138,
+                    286327575262966388,
+                ]),
+                Fq::from_montgomery_limbs([
+                    246981734425887848,
+                    15743674416863714750,
+                    14189671461655646469,
+                    46511506917050520,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4705592443893827962,
+                    2009136474172575275,
+                    14787307099654726895,
+                    995546896941075500,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3452867402760551650,
+                    10447834375387434578,
+                    2945622335959399123,
+                    1031922944187240502,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1668693899837068203,
+                    12260387823576752257,
+                    569110638888919499,
+                    1227498486577321731,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16988766478210573110,
+                    10551192947580686713,
+                    16294702766890116280,
+                    318816419102157308,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6311313745628212547,
+                    10492382116294521638,
+                    2646710642159566337,
+                    322808784612834760,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2297976230230982554,
+                    16785549466528471319,
+                    11172271982010147260,
+                    772124825223061998,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8863417573661602129,
+                    12927458529252529629,
+                    3385444916827195801,
+                    913979407490224279,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7801206335749864827,
+                    13928158722757376622,
+                    11742038383506547919,
+                    1122936077626849692,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3205510748843708578,
+                    1363790908460527973,
+                    11514071928158522061,
+                    728226258609417625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9614255394433869742,
+                    15041239040848279739,
+                    11267996682892551416,
+                    783203172168519894,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6119058429645824891,
+                    739585290520487833,
+                    10363080840375298482,
+                    174689933526925552,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15249656151287422766,
+                    15542205811528487626,
+                    11340043635140990385,
+                    1291214399369904924,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8365501407128757660,
+                    1986217886630901026,
+                    12915913822237484743,
+                    273736634936349125,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8927584358624374848,
+                    10629436841026892771,
+                    4147621106771160120,
+                    53688881870802455,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5798919373243422375,
+                    16380195037768391766,
+                    10408145161544374767,
+                    1321651975408418738,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10099683424582031839,
+                    6926777543868661337,
+                    15859868308472022602,
+                    878998544063358204,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12674561459715478267,
+                    12970841064890027785,
+                    10491829599856956524,
+                    1314979546211358669,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17257553405439618413,
+                    15121059445937049163,
+                    8240101243244988032,
+                    526133014781365378,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2753409439660589400,
+                    6649208199463996805,
+                    7907636541333172639,
+                    1096396218644068145,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16530077176916104359,
+                    417840956546880569,
+                    12528931055195185009,
+                    716215270066610610,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8384578301436330765,
+                    15321071073566409911,
+                    4100677991200131764,
+                    702574868673272995,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4672819145254928146,
+                    8840217311776623437,
+                    356858643063445458,
+                    177485843780399050,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14807006430490666683,
+                    10843643477468829187,
+                    6483100681421021302,
+                    950073773888164572,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13392669371368016626,
+                    10477886869924224872,
+                    15036916168519525228,
+                    158817352186659789,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3809470201779011112,
+                    150466566200723806,
+                    4693728173639381138,
+                    286327575262966388,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8365501407128757660,
+                    1986217886630901026,
+                    12915913822237484743,
+                    273736634936349125,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9930618444410952198,
+                    12563693290698591329,
+                    11027180672168359726,
+                    648267648896378569,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8262076573213334418,
+                    10589421978349780400,
+                    4978770768309404018,
+                    61386361879349611,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3331985375469540807,
+                    13919702840535437827,
+                    15628836429388664011,
+                    1336609212533435069,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2804713691784957934,
+                    8981230398373561874,
+                    1949349660345134292,
+                    410792206953918734,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13392379732613932774,
+                    13649652873859711853,
+                    3780813764820580746,
+                    1216059917293716585,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15511179482801664728,
+                    2052875227378630655,
+                    8847155787674438774,
+                    1134272817992427152,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11988665345415078169,
+                    17991110510832378710,
+                    3099728579845399315,
+                    98622755025426155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9753222183817297377,
+                    1890757296925858349,
+                    10255886727902695779,
+                    85146733701759242,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17814315849035338791,
+                    3394046531364177534,
+                    12579002418177576,
+                    1270101281764205750,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5320320639730529794,
+                    15808722401268016344,
+                    18425160863141109967,
+                    787698449114595831,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15117687221026609607,
+                    17216432509975613018,
+                    3718319368970051146,
+                    1038248857904119895,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2716407950039968722,
+                    17318392847731381101,
+                    15233143023190214261,
+                    911806396885757150,
+                ]),
+                Fq::from_montgomery_limbs([
+                    246981734425887848,
+                    15743674416863714750,
+                    14189671461655646469,
+                    46511506917050520,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8927584358624374848,
+                    10629436841026892771,
+                    4147621106771160120,
+                    53688881870802455,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8262076573213334418,
+                    10589421978349780400,
+                    4978770768309404018,
+                    61386361879349611,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2939560842701251567,
+                    8259186940603607320,
+                    18095381471441794956,
+                    355790532487595387,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12128765085002122169,
+                    1322890612908484362,
+                    17754259948600477712,
+                    441141260495502883,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15961686065009111929,
+                    8059062629235583019,
+                    14158724915817607121,
+                    1171857207593406827,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6611352008939909712,
+                    12628517462179102475,
+                    1177665978374585049,
+                    630425747210040989,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1736806018035357194,
+                    9947941167130239581,
+                    11309274810415175918,
+                    1215065337983930552,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14124231542268644342,
+                    984685503843371512,
+                    11433475304208541822,
+                    1232482335795630133,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12629395766741204723,
+                    7873259771943743062,
+                    10094273249030779330,
+                    982909854589204248,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8615796647946293689,
+                    18445346400078939094,
+                    5291667500559135861,
+                    1070703200299007823,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17949778351526391728,
+                    15918824916496678702,
+                    2801935133819949365,
+                    315251360889826173,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10411099572076264329,
+                    4997929689553212004,
+                    857934500952303135,
+                    924718528083246131,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14367522636536139980,
+                    10327813856430622783,
+                    14018627257220189633,
+                    610635158692265368,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4705592443893827962,
+                    2009136474172575275,
+                    14787307099654726895,
+                    995546896941075500,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5798919373243422375,
+                    16380195037768391766,
+                    10408145161544374767,
+                    1321651975408418738,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3331985375469540807,
+                    13919702840535437827,
+                    15628836429388664011,
+                    1336609212533435069,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12128765085002122169,
+                    1322890612908484362,
+                    17754259948600477712,
+                    441141260495502883,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11203212862597760460,
+                    827896485450742525,
+                    14341982938530179396,
+                    524493610765194867,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3425667292440550516,
+                    5268110741063084557,
+                    10307088482920534486,
+                    219495537721795275,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2137222196428355696,
+                    15531025817491073674,
+                    17718553003337474550,
+                    1070566999010742840,
+                ]),
+                Fq::from_montgomery_limbs([
+                    505781348686389329,
+                    5909935450016985962,
+                    16453308672813254509,
+                    459094465543072056,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11980623666849851847,
+                    12651754529057552476,
+                    12178560435628760812,
+                    754677973525206789,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8029217147985905122,
+                    13643396620175634393,
+                    16491302980755971069,
+                    852213369337697473,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12823302530989610738,
+                    16116490772209900195,
+                    9769659635864716908,
+                    87860719005908193,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2140294280676906685,
+                    12249596406389974695,
+                    15920200540240104376,
+                    1300214425957257609,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5834545611737431563,
+                    15540984069140422791,
+                    1160677709036397251,
+                    771858410338084196,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1256884037283066404,
+                    11957722219433469673,
+                    4463785632279152406,
+                    471386936800413722,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3452867402760551650,
+                    10447834375387434578,
+                    2945622335959399123,
+                    1031922944187240502,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10099683424582031839,
+                    6926777543868661337,
+                    15859868308472022602,
+                    878998544063358204,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2804713691784957934,
+                    8981230398373561874,
+                    1949349660345134292,
+                    410792206953918734,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15961686065009111929,
+                    8059062629235583019,
+                    14158724915817607121,
+                    1171857207593406827,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3425667292440550516,
+                    5268110741063084557,
+                    10307088482920534486,
+                    219495537721795275,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16985145996106016959,
+                    4938086000855353235,
+                    5776702241465193737,
+                    134048573399398527,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14229349562194668998,
+                    2553949316273353626,
+                    18238890376207852208,
+                    1338982654206314482,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13972940680689317168,
+                    16447466617805330422,
+                    10484281167951295232,
+                    1070996174984098406,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12025425526900444421,
+                    6067176145543680353,
+                    7678478302659654604,
+                    255419103864821375,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16328242478102543484,
+                    5661398631589423751,
+                    16421604840282273392,
+                    291493265252281459,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14191234560201642904,
+                    2395780007576887751,
+                    12995536330946607235,
+                    1138765615379003350,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13256490586297974913,
+                    6704952391170544959,
+                    3851008305145414458,
+                    910698068228852254,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9892854635621846612,
+                    14127604305775854208,
+                    12894110448163714402,
+                    907835509027261143,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14156732655704522284,
+                    16436112986316916578,
+                    14856218431383290786,
+                    1229261237295064257,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1668693899837068203,
+                    12260387823576752257,
+                    569110638888919499,
+                    1227498486577321731,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12674561459715478267,
+                    12970841064890027785,
+                    10491829599856956524,
+                    1314979546211358669,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13392379732613932774,
+                    13649652873859711853,
+                    3780813764820580746,
+                    1216059917293716585,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6611352008939909712,
+                    12628517462179102475,
+                    1177665978374585049,
+                    630425747210040989,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2137222196428355696,
+                    15531025817491073674,
+                    17718553003337474550,
+                    1070566999010742840,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14229349562194668998,
+                    2553949316273353626,
+                    18238890376207852208,
+                    1338982654206314482,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11926627905868641545,
+                    5788326574649214690,
+                    8400919989589847995,
+                    1304838833676597161,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9204524479097346079,
+                    17056670379706464027,
+                    4015947676792887911,
+                    713931879809552735,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18121633179361841317,
+                    15014055572091148838,
+                    8459436679423992255,
+                    785023276874911931,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11833273543714010678,
+                    17100536439755791516,
+                    1074634195530383764,
+                    1208682286158942597,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17372460431795827433,
+                    9387439957439073098,
+                    10936340296085339265,
+                    117722620445662569,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6111592632543913193,
+                    13338859682839077290,
+                    10742218907541677277,
+                    1021900725009771734,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12962582522743050421,
+                    17321935222908341699,
+                    16760012087412574701,
+                    1043326713297291398,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17444581196789532540,
+                    6664032038445126490,
+                    13865304360161218988,
+                    921373674624330265,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16988766478210573110,
+                    10551192947580686713,
+                    16294702766890116280,
+                    318816419102157308,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17257553405439618413,
+                    15121059445937049163,
+                    8240101243244988032,
+                    526133014781365378,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15511179482801664728,
+                    2052875227378630655,
+                    8847155787674438774,
+                    1134272817992427152,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1736806018035357194,
+                    9947941167130239581,
+                    11309274810415175918,
+                    1215065337983930552,
+                ]),
+                Fq::from_montgomery_limbs([
+                    505781348686389329,
+                    5909935450016985962,
+                    16453308672813254509,
+                    459094465543072056,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13972940680689317168,
+                    16447466617805330422,
+                    10484281167951295232,
+                    1070996174984098406,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9204524479097346079,
+                    17056670379706464027,
+                    4015947676792887911,
+                    713931879809552735,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13763863110776251247,
+                    13408892456068639,
+                    305983500086784608,
+                    45218574826868444,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5465969695636098153,
+                    14171673382171977743,
+                    5228037701265891018,
+                    780427467360231918,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15803960728317416977,
+                    10859246496107080287,
+                    9554051541625538770,
+                    211023381869487549,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12164165678074932287,
+                    2528683790756566710,
+                    12077942712424942049,
+                    327080993726751281,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15854744087066029368,
+                    4328333044146487644,
+                    10889183735192754878,
+                    95349733502961385,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1696533046286002165,
+                    5166879479149767291,
+                    8844066094173614261,
+                    832047818233545534,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14514554497795299035,
+                    11515071338172977988,
+                    10387287484730496523,
+                    94069881695663139,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6311313745628212547,
+                    10492382116294521638,
+                    2646710642159566337,
+                    322808784612834760,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2753409439660589400,
+                    6649208199463996805,
+                    7907636541333172639,
+                    1096396218644068145,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11988665345415078169,
+                    17991110510832378710,
+                    3099728579845399315,
+                    98622755025426155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14124231542268644342,
+                    984685503843371512,
+                    11433475304208541822,
+                    1232482335795630133,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11980623666849851847,
+                    12651754529057552476,
+                    12178560435628760812,
+                    754677973525206789,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12025425526900444421,
+                    6067176145543680353,
+                    7678478302659654604,
+                    255419103864821375,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18121633179361841317,
+                    15014055572091148838,
+                    8459436679423992255,
+                    785023276874911931,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5465969695636098153,
+                    14171673382171977743,
+                    5228037701265891018,
+                    780427467360231918,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8348826541449107032,
+                    7440441043703659076,
+                    10574466414744353314,
+                    1130957381750653754,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9085710073918779034,
+                    15387322115024758677,
+                    4455742835827722019,
+                    1285292785139495888,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2778419355725133165,
+                    2096159159563233536,
+                    4012631403846548843,
+                    1338075836999235641,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6398462322308929717,
+                    10603986675098105992,
+                    5769147337872390224,
+                    740512074070702604,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6752919219304833385,
+                    9736018919907302278,
+                    8105000784875086810,
+                    741373055906409399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15471262902688132452,
+                    5848680474256613237,
+                    738689097977789755,
+                    174046651659874773,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2297976230230982554,
+                    16785549466528471319,
+                    11172271982010147260,
+                    772124825223061998,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16530077176916104359,
+                    417840956546880569,
+                    12528931055195185009,
+                    716215270066610610,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9753222183817297377,
+                    1890757296925858349,
+                    10255886727902695779,
+                    85146733701759242,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12629395766741204723,
+                    7873259771943743062,
+                    10094273249030779330,
+                    982909854589204248,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8029217147985905122,
+                    13643396620175634393,
+                    16491302980755971069,
+                    852213369337697473,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16328242478102543484,
+                    5661398631589423751,
+                    16421604840282273392,
+                    291493265252281459,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11833273543714010678,
+                    17100536439755791516,
+                    1074634195530383764,
+                    1208682286158942597,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15803960728317416977,
+                    10859246496107080287,
+                    9554051541625538770,
+                    211023381869487549,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9085710073918779034,
+                    15387322115024758677,
+                    4455742835827722019,
+                    1285292785139495888,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17454626981908828733,
+                    7282765463582381907,
+                    3421156106296120062,
+                    1176444830972764225,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11185025735404875941,
+                    11496607754971019011,
+                    13699215791435724645,
+                    921734717191291767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16781851554798603286,
+                    14466290102369313973,
+                    4048060379730702145,
+                    691887364155200512,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6932747623693792244,
+                    12057166733778880753,
+                    2712084083503176751,
+                    603205440102761630,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4844910376970828822,
+                    4578111422473002427,
+                    3005174012293457078,
+                    498428038109023035,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8863417573661602129,
+                    12927458529252529629,
+                    3385444916827195801,
+                    913979407490224279,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8384578301436330765,
+                    15321071073566409911,
+                    4100677991200131764,
+                    702574868673272995,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17814315849035338791,
+                    3394046531364177534,
+                    12579002418177576,
+                    1270101281764205750,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8615796647946293689,
+                    18445346400078939094,
+                    5291667500559135861,
+                    1070703200299007823,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12823302530989610738,
+                    16116490772209900195,
+                    9769659635864716908,
+                    87860719005908193,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14191234560201642904,
+                    2395780007576887751,
+                    12995536330946607235,
+                    1138765615379003350,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17372460431795827433,
+                    9387439957439073098,
+                    10936340296085339265,
+                    117722620445662569,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12164165678074932287,
+                    2528683790756566710,
+                    12077942712424942049,
+                    327080993726751281,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2778419355725133165,
+                    2096159159563233536,
+                    4012631403846548843,
+                    1338075836999235641,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11185025735404875941,
+                    11496607754971019011,
+                    13699215791435724645,
+                    921734717191291767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17630381077754355340,
+                    7301792533613986081,
+                    17609226742052331600,
+                    1205878664972436664,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6607085957334975653,
+                    14442114061413798416,
+                    6539686991510294165,
+                    804495414584458449,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3566563015520135050,
+                    453606081509072137,
+                    2657735443750611150,
+                    480492291968750036,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2890018098339553979,
+                    10152711856149102386,
+                    16647020284873762043,
+                    261147125390181199,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7801206335749864827,
+                    13928158722757376622,
+                    11742038383506547919,
+                    1122936077626849692,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4672819145254928146,
+                    8840217311776623437,
+                    356858643063445458,
+                    177485843780399050,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5320320639730529794,
+                    15808722401268016344,
+                    18425160863141109967,
+                    787698449114595831,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17949778351526391728,
+                    15918824916496678702,
+                    2801935133819949365,
+                    315251360889826173,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2140294280676906685,
+                    12249596406389974695,
+                    15920200540240104376,
+                    1300214425957257609,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13256490586297974913,
+                    6704952391170544959,
+                    3851008305145414458,
+                    910698068228852254,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6111592632543913193,
+                    13338859682839077290,
+                    10742218907541677277,
+                    1021900725009771734,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15854744087066029368,
+                    4328333044146487644,
+                    10889183735192754878,
+                    95349733502961385,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6398462322308929717,
+                    10603986675098105992,
+                    5769147337872390224,
+                    740512074070702604,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16781851554798603286,
+                    14466290102369313973,
+                    4048060379730702145,
+                    691887364155200512,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6607085957334975653,
+                    14442114061413798416,
+                    6539686991510294165,
+                    804495414584458449,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8031664240505963725,
+                    10763495079529090155,
+                    14926005655550355862,
+                    760390194823954125,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3667162775070640993,
+                    15327163742906544940,
+                    2470859845407932968,
+                    131963477918548543,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7060289448800091029,
+                    8741695383630165172,
+                    16226461145404022574,
+                    853297951294525223,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3205510748843708578,
+                    1363790908460527973,
+                    11514071928158522061,
+                    728226258609417625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14807006430490666683,
+                    10843643477468829187,
+                    6483100681421021302,
+                    950073773888164572,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15117687221026609607,
+                    17216432509975613018,
+                    3718319368970051146,
+                    1038248857904119895,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10411099572076264329,
+                    4997929689553212004,
+                    857934500952303135,
+                    924718528083246131,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5834545611737431563,
+                    15540984069140422791,
+                    1160677709036397251,
+                    771858410338084196,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9892854635621846612,
+                    14127604305775854208,
+                    12894110448163714402,
+                    907835509027261143,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12962582522743050421,
+                    17321935222908341699,
+                    16760012087412574701,
+                    1043326713297291398,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1696533046286002165,
+                    5166879479149767291,
+                    8844066094173614261,
+                    832047818233545534,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6752919219304833385,
+                    9736018919907302278,
+                    8105000784875086810,
+                    741373055906409399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6932747623693792244,
+                    12057166733778880753,
+                    2712084083503176751,
+                    603205440102761630,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3566563015520135050,
+                    453606081509072137,
+                    2657735443750611150,
+                    480492291968750036,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3667162775070640993,
+                    15327163742906544940,
+                    2470859845407932968,
+                    131963477918548543,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8518961801562857477,
+                    1358383796321304235,
+                    6892566172754944911,
+                    1119993311038006640,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2109433331612035168,
+                    12164574520802933551,
+                    11078174737917971441,
+                    626873551303308939,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9614255394433869742,
+                    15041239040848279739,
+                    11267996682892551416,
+                    783203172168519894,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13392669371368016626,
+                    10477886869924224872,
+                    15036916168519525228,
+                    158817352186659789,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2716407950039968722,
+                    17318392847731381101,
+                    15233143023190214261,
+                    911806396885757150,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14367522636536139980,
+                    10327813856430622783,
+                    14018627257220189633,
+                    610635158692265368,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1256884037283066404,
+                    11957722219433469673,
+                    4463785632279152406,
+                    471386936800413722,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14156732655704522284,
+                    16436112986316916578,
+                    14856218431383290786,
+                    1229261237295064257,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17444581196789532540,
+                    6664032038445126490,
+                    13865304360161218988,
+                    921373674624330265,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14514554497795299035,
+                    11515071338172977988,
+                    10387287484730496523,
+                    94069881695663139,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15471262902688132452,
+                    5848680474256613237,
+                    738689097977789755,
+                    174046651659874773,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4844910376970828822,
+                    4578111422473002427,
+                    3005174012293457078,
+                    498428038109023035,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2890018098339553979,
+                    10152711856149102386,
+                    16647020284873762043,
+                    261147125390181199,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7060289448800091029,
+                    8741695383630165172,
+                    16226461145404022574,
+                    853297951294525223,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2109433331612035168,
+                    12164574520802933551,
+                    11078174737917971441,
+                    626873551303308939,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8063266013863840285,
+                    15130802301163481073,
+                    11350334164976167347,
+                    859016385326045936,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<13, 169>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    11190216169286472062,
+                    22923249640519128,
+                    1312663316884898036,
+                    866718167904297219,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8830231788127457907,
+                    10718669828527738857,
+                    6519273456789504083,
+                    854292040060312728,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15522022533464403005,
+                    13222296337832939049,
+                    11632862780348445961,
+                    16458461794811480,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1768613197413149562,
+                    17022655841024308248,
+                    5349619018021089954,
+                    99300347528312775,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8680588449268989206,
+                    7711160618546807926,
+                    5664776150071742492,
+                    180630897173343368,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3277520456235009408,
+                    2012332210940356603,
+                    4227078456888909519,
+                    160827627993044513,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12490243462571199588,
+                    18233479384289265518,
+                    16470479920430304589,
+                    1177601499369336175,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16375556178534435756,
+                    11836518456769196935,
+                    7888033642428885983,
+                    1037577883878241054,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16683673897598479632,
+                    438412775849205681,
+                    8232846633164639968,
+                    708871197224365759,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3448432083638768093,
+                    5268778659859224382,
+                    18378366323258303109,
+                    281098262874735743,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9520178447732217647,
+                    5161468349003541116,
+                    5872954902233966372,
+                    73367123532425824,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4553170006134497460,
+                    8766921562784669240,
+                    6846146445688518818,
+                    1312423546622201228,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10309881887686848491,
+                    8752695988214914541,
+                    5685053525084833724,
+                    420307831887526548,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8830231788127457907,
+                    10718669828527738857,
+                    6519273456789504083,
+                    854292040060312728,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8594872032467630697,
+                    17580901382291730738,
+                    7928454709104152413,
+                    1229289517950163057,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12912443883056822067,
+                    12485106355723290611,
+                    6894377634225900432,
+                    970747319213816484,
+                ]),
+                Fq::from_montgomery_limbs([
+                    435153199096908614,
+                    13685928083392321437,
+                    5261939361850166818,
+                    672399319839788412,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9363362734115578098,
+                    6303075354634904465,
+                    2707045623624359936,
+                    102018189089811366,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9450996881734926710,
+                    10683359193582984225,
+                    228608007355785871,
+                    819138460044214076,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6510267334929832479,
+                    15153542145604567723,
+                    17311980417786476677,
+                    944114947875133396,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6447335255175166151,
+                    4465417920812291576,
+                    11357985493377730968,
+                    65631960259709070,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6411828870595523692,
+                    2708821764260535669,
+                    5426973988852971405,
+                    1342133850213323723,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10946823727354620685,
+                    15387163436005989633,
+                    10560413900273812263,
+                    472537887070604763,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12103767869551226822,
+                    7965908038740208053,
+                    9422671693140030517,
+                    1079189300131196287,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8810139395144047446,
+                    9953764063752789283,
+                    5968941661470321009,
+                    965376145149969781,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9931921510392811968,
+                    8995089002562014090,
+                    3178807481740275091,
+                    590498125179156274,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15522022533464403005,
+                    13222296337832939049,
+                    11632862780348445961,
+                    16458461794811480,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12912443883056822067,
+                    12485106355723290611,
+                    6894377634225900432,
+                    970747319213816484,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12327519728712964759,
+                    12402057427218662611,
+                    12738906179054794749,
+                    1277226213216510459,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12718665154801579227,
+                    11070044088453778001,
+                    8203095685914384827,
+                    1257067842026179519,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12093131431446271084,
+                    13496095995546037519,
+                    8196065050912339122,
+                    241843575216312400,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17940918914554010885,
+                    15049808002107769108,
+                    2244233686935651150,
+                    1062119293613840651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3528179132297110318,
+                    1020330994248235646,
+                    134641407526948035,
+                    388010989455095538,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5149277947240701930,
+                    2995554661648373037,
+                    292813896992106428,
+                    812879068661717905,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4879653026539474685,
+                    14543953656893108153,
+                    3117568280720397618,
+                    762877751797748187,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6575693369036492893,
+                    6796957827728020492,
+                    5187492035878138181,
+                    533749478342985695,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11931933853449776799,
+                    16812405581104867133,
+                    5374852107412737310,
+                    249219297007587235,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11734185258477489101,
+                    12382629697205983328,
+                    16919379502203530020,
+                    604906451381762515,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14569888080946328052,
+                    7753566245119090921,
+                    8947214442439250086,
+                    1107005892454772925,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1768613197413149562,
+                    17022655841024308248,
+                    5349619018021089954,
+                    99300347528312775,
+                ]),
+                Fq::from_montgomery_limbs([
+                    435153199096908614,
+                    13685928083392321437,
+                    5261939361850166818,
+                    672399319839788412,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12718665154801579227,
+                    11070044088453778001,
+                    8203095685914384827,
+                    1257067842026179519,
+                ]),
+                Fq::from_montgomery_limbs([
+                    983172044062140768,
+                    12523716277140219108,
+                    7570447750841726714,
+                    823104101680161760,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10481282309434551956,
+                    6826100582926389614,
+                    2731259757361951155,
+                    673675476696049814,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5061613232751104144,
+                    1035401721166071578,
+                    1181236866889164970,
+                    71371133267382856,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16127544478788758800,
+                    10620104295108411153,
+                    11664072907781080698,
+                    731559782248910062,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15001563726088776896,
+                    7879719134910947583,
+                    615565632791984002,
+                    169693603572798399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14900526743196742298,
+                    13461192349710181061,
+                    7689494683806551797,
+                    1126933626567935483,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17484676215996071570,
+                    7745246608984463915,
+                    152900540893829955,
+                    456562041989197447,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6848726861704217655,
+                    7244698542385315715,
+                    10365476951177027240,
+                    276607673947840010,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13518058627099521708,
+                    4338282772186896975,
+                    12900277003464074759,
+                    355119912996351896,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2700188382339448790,
+                    12494090595115853213,
+                    8454923470656229823,
+                    725844714324147783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8680588449268989206,
+                    7711160618546807926,
+                    5664776150071742492,
+                    180630897173343368,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9363362734115578098,
+                    6303075354634904465,
+                    2707045623624359936,
+                    102018189089811366,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12093131431446271084,
+                    13496095995546037519,
+                    8196065050912339122,
+                    241843575216312400,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10481282309434551956,
+                    6826100582926389614,
+                    2731259757361951155,
+                    673675476696049814,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1748164678933996629,
+                    5174905936762873282,
+                    8785543256331127901,
+                    1164510053319442428,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4494225136530592023,
+                    11078495890356570832,
+                    2928287112529960799,
+                    955359917345660658,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9254612240569564942,
+                    12482274898543697165,
+                    3630553644270772220,
+                    1179611893448709971,
+                ]),
+                Fq::from_montgomery_limbs([
+                    923189945266941899,
+                    4451390071796001,
+                    14668195577893834924,
+                    584439111613241689,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10838376368954879017,
+                    6963892241318983137,
+                    826075252708958415,
+                    180860263386895108,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4935101789517589929,
+                    9263109340907807022,
+                    13963330777635494361,
+                    1139725281328249597,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17805455693107823470,
+                    5022661823030897205,
+                    11867418243877699896,
+                    1110869316519765116,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4325302778609925529,
+                    11551767792433335619,
+                    12632077792540770787,
+                    1194112789626576277,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8239850542832019505,
+                    5189985709095920702,
+                    9203281144869766444,
+                    840195238155540806,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3277520456235009408,
+                    2012332210940356603,
+                    4227078456888909519,
+                    160827627993044513,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9450996881734926710,
+                    10683359193582984225,
+                    228608007355785871,
+                    819138460044214076,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17940918914554010885,
+                    15049808002107769108,
+                    2244233686935651150,
+                    1062119293613840651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5061613232751104144,
+                    1035401721166071578,
+                    1181236866889164970,
+                    71371133267382856,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4494225136530592023,
+                    11078495890356570832,
+                    2928287112529960799,
+                    955359917345660658,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2813408670933926127,
+                    7503564196521658117,
+                    6503214423751121463,
+                    265416335806592818,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15895498232618890653,
+                    14712045717856326223,
+                    647404155180486922,
+                    474977299187407293,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16721109755762174603,
+                    13594654775161653121,
+                    15218151562360257491,
+                    61179259828387239,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12262531137635768863,
+                    1687466907107076617,
+                    7674082946747805993,
+                    375850593712253852,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5112214596600923742,
+                    6826899015354734954,
+                    14381815966362196369,
+                    1191835651907859544,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10571063955541201324,
+                    14455455951866370546,
+                    16144247019027817185,
+                    43411830494494077,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7054653235448411524,
+                    10815823274174173105,
+                    9055749212053524076,
+                    951628844254067463,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7890327905174547210,
+                    1703405873818047453,
+                    11052752356520934986,
+                    970150650318377819,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12490243462571199588,
+                    18233479384289265518,
+                    16470479920430304589,
+                    1177601499369336175,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6510267334929832479,
+                    15153542145604567723,
+                    17311980417786476677,
+                    944114947875133396,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3528179132297110318,
+                    1020330994248235646,
+                    134641407526948035,
+                    388010989455095538,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16127544478788758800,
+                    10620104295108411153,
+                    11664072907781080698,
+                    731559782248910062,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9254612240569564942,
+                    12482274898543697165,
+                    3630553644270772220,
+                    1179611893448709971,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15895498232618890653,
+                    14712045717856326223,
+                    647404155180486922,
+                    474977299187407293,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10372761491709798271,
+                    18042525883590223819,
+                    6228024225556763602,
+                    1200829060036917292,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2823186350243963514,
+                    6584175804569506415,
+                    14782089242891429789,
+                    991450849229719467,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2894962087440587756,
+                    12925794085758904043,
+                    17589796322404702591,
+                    86015239838984175,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7991015862020731999,
+                    7298707028438786401,
+                    11651948319834123161,
+                    1278601588926207737,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1587492703154635576,
+                    5062040757498443909,
+                    10205560848892728136,
+                    13984627580434336,
+                ]),
+                Fq::from_montgomery_limbs([
+                    651659873836462709,
+                    14206824587666554033,
+                    13626657834176008850,
+                    852389094714177296,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14171850621039945327,
+                    5491899167600019008,
+                    16697775878596627919,
+                    15097635333871861,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16375556178534435756,
+                    11836518456769196935,
+                    7888033642428885983,
+                    1037577883878241054,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6447335255175166151,
+                    4465417920812291576,
+                    11357985493377730968,
+                    65631960259709070,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5149277947240701930,
+                    2995554661648373037,
+                    292813896992106428,
+                    812879068661717905,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15001563726088776896,
+                    7879719134910947583,
+                    615565632791984002,
+                    169693603572798399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    923189945266941899,
+                    4451390071796001,
+                    14668195577893834924,
+                    584439111613241689,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16721109755762174603,
+                    13594654775161653121,
+                    15218151562360257491,
+                    61179259828387239,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2823186350243963514,
+                    6584175804569506415,
+                    14782089242891429789,
+                    991450849229719467,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9779218455353471631,
+                    8767458869994754903,
+                    12022423763683669868,
+                    405262534163954117,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8815084631305626618,
+                    14229265721379034967,
+                    14781987893132660494,
+                    746522765051257834,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13902741206214244004,
+                    345981032748669835,
+                    8508353573520559035,
+                    325036978698809042,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1081794111767166022,
+                    14757312434314178418,
+                    10334963074739408089,
+                    226104361506366288,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3651775691630815365,
+                    10312964343153241548,
+                    17928988716573235896,
+                    1206295076719536827,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7792083439050767054,
+                    7293716647449482447,
+                    5702405023197721266,
+                    818616869986719392,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16683673897598479632,
+                    438412775849205681,
+                    8232846633164639968,
+                    708871197224365759,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6411828870595523692,
+                    2708821764260535669,
+                    5426973988852971405,
+                    1342133850213323723,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4879653026539474685,
+                    14543953656893108153,
+                    3117568280720397618,
+                    762877751797748187,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14900526743196742298,
+                    13461192349710181061,
+                    7689494683806551797,
+                    1126933626567935483,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10838376368954879017,
+                    6963892241318983137,
+                    826075252708958415,
+                    180860263386895108,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12262531137635768863,
+                    1687466907107076617,
+                    7674082946747805993,
+                    375850593712253852,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2894962087440587756,
+                    12925794085758904043,
+                    17589796322404702591,
+                    86015239838984175,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8815084631305626618,
+                    14229265721379034967,
+                    14781987893132660494,
+                    746522765051257834,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10579736447483128163,
+                    9327872445665172761,
+                    3015116597474010049,
+                    358150580826022760,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11275708306993394270,
+                    9525326809806823761,
+                    9843808989485528061,
+                    574338603345939185,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3760329580417087562,
+                    15666896591844786199,
+                    10504206389334346219,
+                    1139198418558971221,
+                ]),
+                Fq::from_montgomery_limbs([
+                    964756080434395367,
+                    2533643074555236889,
+                    14933105636384429445,
+                    491377676501818952,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11711719063387246344,
+                    17839582136764064109,
+                    10131099502622111644,
+                    288874901482594687,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3448432083638768093,
+                    5268778659859224382,
+                    18378366323258303109,
+                    281098262874735743,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10946823727354620685,
+                    15387163436005989633,
+                    10560413900273812263,
+                    472537887070604763,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6575693369036492893,
+                    6796957827728020492,
+                    5187492035878138181,
+                    533749478342985695,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17484676215996071570,
+                    7745246608984463915,
+                    152900540893829955,
+                    456562041989197447,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4935101789517589929,
+                    9263109340907807022,
+                    13963330777635494361,
+                    1139725281328249597,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5112214596600923742,
+                    6826899015354734954,
+                    14381815966362196369,
+                    1191835651907859544,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7991015862020731999,
+                    7298707028438786401,
+                    11651948319834123161,
+                    1278601588926207737,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13902741206214244004,
+                    345981032748669835,
+                    8508353573520559035,
+                    325036978698809042,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11275708306993394270,
+                    9525326809806823761,
+                    9843808989485528061,
+                    574338603345939185,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6715340861470661738,
+                    7390570942686447097,
+                    16823935090360360689,
+                    97247237333700248,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2712252677363622379,
+                    3554448476896508342,
+                    14685586704580358760,
+                    80226807472742045,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4903022211748831289,
+                    1794390185310921504,
+                    4105400173603350490,
+                    1334199629090765836,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10880765282275292667,
+                    8707391799933452044,
+                    14015437510708314178,
+                    255843522574328007,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9520178447732217647,
+                    5161468349003541116,
+                    5872954902233966372,
+                    73367123532425824,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12103767869551226822,
+                    7965908038740208053,
+                    9422671693140030517,
+                    1079189300131196287,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11931933853449776799,
+                    16812405581104867133,
+                    5374852107412737310,
+                    249219297007587235,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6848726861704217655,
+                    7244698542385315715,
+                    10365476951177027240,
+                    276607673947840010,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17805455693107823470,
+                    5022661823030897205,
+                    11867418243877699896,
+                    1110869316519765116,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10571063955541201324,
+                    14455455951866370546,
+                    16144247019027817185,
+                    43411830494494077,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1587492703154635576,
+                    5062040757498443909,
+                    10205560848892728136,
+                    13984627580434336,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1081794111767166022,
+                    14757312434314178418,
+                    10334963074739408089,
+                    226104361506366288,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3760329580417087562,
+                    15666896591844786199,
+                    10504206389334346219,
+                    1139198418558971221,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2712252677363622379,
+                    3554448476896508342,
+                    14685586704580358760,
+                    80226807472742045,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2301459030913881895,
+                    14786594199142307817,
+                    11055823936309774336,
+                    874739166186081692,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5462464953704519371,
+                    17551942689103155277,
+                    11534230951765721742,
+                    776016420422103350,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10525385718721771824,
+                    8559392347180235532,
+                    18080580783156410339,
+                    860388464355235904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4553170006134497460,
+                    8766921562784669240,
+                    6846146445688518818,
+                    1312423546622201228,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8810139395144047446,
+                    9953764063752789283,
+                    5968941661470321009,
+                    965376145149969781,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11734185258477489101,
+                    12382629697205983328,
+                    16919379502203530020,
+                    604906451381762515,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13518058627099521708,
+                    4338282772186896975,
+                    12900277003464074759,
+                    355119912996351896,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4325302778609925529,
+                    11551767792433335619,
+                    12632077792540770787,
+                    1194112789626576277,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7054653235448411524,
+                    10815823274174173105,
+                    9055749212053524076,
+                    951628844254067463,
+                ]),
+                Fq::from_montgomery_limbs([
+                    651659873836462709,
+                    14206824587666554033,
+                    13626657834176008850,
+                    852389094714177296,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3651775691630815365,
+                    10312964343153241548,
+                    17928988716573235896,
+                    1206295076719536827,
+                ]),
+                Fq::from_montgomery_limbs([
+                    964756080434395367,
+                    2533643074555236889,
+                    14933105636384429445,
+                    491377676501818952,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4903022211748831289,
+                    1794390185310921504,
+                    4105400173603350490,
+                    1334199629090765836,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5462464953704519371,
+                    17551942689103155277,
+                    11534230951765721742,
+                    776016420422103350,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12319071399095917866,
+                    16018379407381788514,
+                    5754269756472630132,
+                    286339890068031239,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14896841881295849721,
+                    1371501380010682776,
+                    13484261017847134660,
+                    288780830366087919,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10309881887686848491,
+                    8752695988214914541,
+                    5685053525084833724,
+                    420307831887526548,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9931921510392811968,
+                    8995089002562014090,
+                    3178807481740275091,
+                    590498125179156274,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14569888080946328052,
+                    7753566245119090921,
+                    8947214442439250086,
+                    1107005892454772925,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2700188382339448790,
+                    12494090595115853213,
+                    8454923470656229823,
+                    725844714324147783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8239850542832019505,
+                    5189985709095920702,
+                    9203281144869766444,
+                    840195238155540806,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7890327905174547210,
+                    1703405873818047453,
+                    11052752356520934986,
+                    970150650318377819,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14171850621039945327,
+                    5491899167600019008,
+                    16697775878596627919,
+                    15097635333871861,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7792083439050767054,
+                    7293716647449482447,
+                    5702405023197721266,
+                    818616869986719392,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11711719063387246344,
+                    17839582136764064109,
+                    10131099502622111644,
+                    288874901482594687,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10880765282275292667,
+                    8707391799933452044,
+                    14015437510708314178,
+                    255843522574328007,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10525385718721771824,
+                    8559392347180235532,
+                    18080580783156410339,
+                    860388464355235904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14896841881295849721,
+                    1371501380010682776,
+                    13484261017847134660,
+                    288780830366087919,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10402055588239700754,
+                    2971831522434463600,
+                    12390069864884462633,
+                    725073627937216984,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            M_i: Matrix::<14, 14, 196>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2538553266070732335,
+                    2775300319508079521,
+                    6491041004731273577,
+                    1192916514988563329,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8837192990535786074,
+                    3990353913935916975,
+                    9915815024518052347,
+                    239940166368681493,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17771640064131657169,
+                    9025874375714377119,
+                    3456327113234637782,
+                    80098633495407341,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1620588123307541722,
+                    3613264088567023895,
+                    17061174174129582255,
+                    518835551810833978,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8785041879524080688,
+                    16354309526653115075,
+                    7209569947283967868,
+                    763065627897007288,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5537458906520778969,
+                    35194391964615945,
+                    1464861554216983697,
+                    1051916598704609159,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3671363285419193921,
+                    6942489526560239162,
+                    6396245046813019872,
+                    1157887728467259445,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1681733916846059387,
+                    7522129847300940894,
+                    2563899392241626577,
+                    533871370068084656,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5243070862143680798,
+                    16236197107312531261,
+                    1522603161585426883,
+                    198439061956077690,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3635205751596133490,
+                    10464638875521886351,
+                    6261286754332782371,
+                    596476861632071017,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14709094380813763759,
+                    13623706258040132995,
+                    2232007098682105195,
+                    822290894078631596,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5858353599980945419,
+                    13209290101085663249,
+                    16668551879743643082,
+                    526353739703258388,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7109186148554833238,
+                    15455116356194255081,
+                    7255020126662504727,
+                    933462645571809434,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8837192990535786074,
+                    3990353913935916975,
+                    9915815024518052347,
+                    239940166368681493,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1087585966363836454,
+                    4719872655091867910,
+                    5287262603607553894,
+                    415131482411617002,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13108735229450786993,
+                    9129770503664076490,
+                    11787480518367982625,
+                    595272035776760292,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7499666308026534822,
+                    7483782758245119509,
+                    13872811651800858152,
+                    990042507605073891,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17627888184357737575,
+                    28173673959816622,
+                    15099428837247938891,
+                    106861608879424267,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1880963768253463610,
+                    5447375620686293218,
+                    5396829534075030062,
+                    786041706966942210,
+                ]),
+                Fq::from_montgomery_limbs([
+                    101770290590214456,
+                    17448148580368470538,
+                    11349053499863026452,
+                    1291604218319844247,
+                ]),
+                Fq::from_montgomery_limbs([
+                    63818687549160799,
+                    13925359153658251179,
+                    9165194855112182981,
+                    809589699028332056,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3796166555619654353,
+                    6958375276346843838,
+                    10478115890545096996,
+                    1055693593883015292,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10559813781980402729,
+                    17264017683743112709,
+                    16060806980078533278,
+                    1343963742126639172,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14027541582886875156,
+                    3384353261549377387,
+                    12427130350932855342,
+                    525326889981733608,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16065530035254948223,
+                    4688978343985105380,
+                    13364101481639278987,
+                    413015287443835174,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10849655879168484907,
+                    8159114382082704263,
+                    9680572476501647405,
+                    824196341898672402,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17771640064131657169,
+                    9025874375714377119,
+                    3456327113234637782,
+                    80098633495407341,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13108735229450786993,
+                    9129770503664076490,
+                    11787480518367982625,
+                    595272035776760292,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11523729302853677790,
+                    12900834687015404291,
+                    10799638969994649641,
+                    703583977209147175,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8621515949316739522,
+                    7644638534520647901,
+                    12179953574484143687,
+                    662659794042645667,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11609515782803675828,
+                    2191634152598830875,
+                    15961620906279297979,
+                    11169488342397035,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11745053235578154071,
+                    2736537839720484205,
+                    2230288494533879776,
+                    1094666974939760496,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10841549342965985136,
+                    13819514478817656813,
+                    14898080063018909177,
+                    228755391630695055,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13663748694495719777,
+                    11016624229668839805,
+                    5513961667232249758,
+                    265988839234684383,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1619399202337842516,
+                    6350181722461714686,
+                    13951442751674422194,
+                    368269574754001895,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17102877389437514314,
+                    4022033427416823044,
+                    10846266657738008844,
+                    347957573208322867,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16687732263481428175,
+                    10748975670194207578,
+                    3192082492325847562,
+                    834390957396059498,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2982701860315127142,
+                    11406777888190002369,
+                    4696577423211233838,
+                    41631612105424911,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4592980783288114898,
+                    5985884132012199771,
+                    786569551644147916,
+                    285328106978464140,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1620588123307541722,
+                    3613264088567023895,
+                    17061174174129582255,
+                    518835551810833978,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7499666308026534822,
+                    7483782758245119509,
+                    13872811651800858152,
+                    990042507605073891,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8621515949316739522,
+                    7644638534520647901,
+                    12179953574484143687,
+                    662659794042645667,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8255988746856963280,
+                    17676946240852877620,
+                    14410244752508909633,
+                    457107141096237819,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9293815081617198311,
+                    11497432197757485776,
+                    1390151721385731639,
+                    525477127965142091,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14307952118145874021,
+                    5589245610331582700,
+                    17360477041005190138,
+                    11911705389007523,
+                ]),
+                Fq::from_montgomery_limbs([
+                    587071824118852921,
+                    12853463823617293599,
+                    12100904207486580393,
+                    78978246194662037,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9978851309283612257,
+                    5662403853628023487,
+                    6878645122817092792,
+                    522331950819251263,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13910203574282228182,
+                    16062830520514310097,
+                    6355691034956155450,
+                    246802389642074124,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16876141254906159929,
+                    1926781727626754927,
+                    12960492554458667506,
+                    537544288138429796,
+                ]),
+                Fq::from_montgomery_limbs([
+                    308264554393123439,
+                    2569205330787494450,
+                    9741491247747328611,
+                    1136297064712807078,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2403180423239080646,
+                    13195885638674749289,
+                    1561779914079762389,
+                    335436842427912670,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14346006030226750213,
+                    9613111383286136616,
+                    11322078314737537529,
+                    863199374326022785,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8785041879524080688,
+                    16354309526653115075,
+                    7209569947283967868,
+                    763065627897007288,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17627888184357737575,
+                    28173673959816622,
+                    15099428837247938891,
+                    106861608879424267,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11609515782803675828,
+                    2191634152598830875,
+                    15961620906279297979,
+                    11169488342397035,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9293815081617198311,
+                    11497432197757485776,
+                    1390151721385731639,
+                    525477127965142091,
+                ]),
+                Fq::from_montgomery_limbs([
+                    28155039036129259,
+                    3801776864461974539,
+                    738419419387914312,
+                    817368497383973769,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15159215789988572625,
+                    1399253478417249937,
+                    9767856111260835275,
+                    970422177394364451,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10904101728422845126,
+                    12233995829022407460,
+                    931799205287589167,
+                    716983892017821384,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8443536272798827954,
+                    15655584703292638842,
+                    18157716460739841181,
+                    531405440218587598,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10423737141842964083,
+                    13663900316721666905,
+                    3012121761109971827,
+                    751238545470024675,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10138096183744352001,
+                    17683021305609891241,
+                    11946864528150529163,
+                    484233685118099491,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14535134777769220769,
+                    13187703343063951581,
+                    5836682010784620262,
+                    867767064573924885,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9423135546028364780,
+                    8621902025976077215,
+                    4784316252557608591,
+                    463817115604533978,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5229238181723231862,
+                    1594078310289156835,
+                    15278788122503640361,
+                    480561932588870429,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5537458906520778969,
+                    35194391964615945,
+                    1464861554216983697,
+                    1051916598704609159,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1880963768253463610,
+                    5447375620686293218,
+                    5396829534075030062,
+                    786041706966942210,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11745053235578154071,
+                    2736537839720484205,
+                    2230288494533879776,
+                    1094666974939760496,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14307952118145874021,
+                    5589245610331582700,
+                    17360477041005190138,
+                    11911705389007523,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15159215789988572625,
+                    1399253478417249937,
+                    9767856111260835275,
+                    970422177394364451,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15447025541644711585,
+                    3116086092785554155,
+                    4823310153841157734,
+                    149805901587643875,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12592409157080532450,
+                    3238651506055637389,
+                    9529454631248437492,
+                    1283494134248862845,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12779116391644799837,
+                    11939422513631999242,
+                    5306474264975975940,
+                    250579518171898696,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9106823000081569319,
+                    8018038377564945639,
+                    11151104075260758459,
+                    104137305236270097,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5616010125310379582,
+                    2469025745204179491,
+                    6246958410016777454,
+                    252494778799514350,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4341462121295695018,
+                    15148672647295054927,
+                    9776938388799384536,
+                    771307136411742822,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7644312569795261736,
+                    15631752084048947828,
+                    6900877083827286691,
+                    519407948315869268,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15215372292409985501,
+                    12952063083891262430,
+                    6590422644840497487,
+                    440926896402529804,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3671363285419193921,
+                    6942489526560239162,
+                    6396245046813019872,
+                    1157887728467259445,
+                ]),
+                Fq::from_montgomery_limbs([
+                    101770290590214456,
+                    17448148580368470538,
+                    11349053499863026452,
+                    1291604218319844247,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10841549342965985136,
+                    13819514478817656813,
+                    14898080063018909177,
+                    228755391630695055,
+                ]),
+                Fq::from_montgomery_limbs([
+                    587071824118852921,
+                    12853463823617293599,
+                    12100904207486580393,
+                    78978246194662037,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10904101728422845126,
+                    12233995829022407460,
+                    931799205287589167,
+                    716983892017821384,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12592409157080532450,
+                    3238651506055637389,
+                    9529454631248437492,
+                    1283494134248862845,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4755440503703705065,
+                    4524558001737791258,
+                    437026952987106006,
+                    59856594765380122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8508186696844316829,
+                    3706258997812933023,
+                    15604475444552262641,
+                    815899527417774698,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15913874724511950916,
+                    11446160828364458522,
+                    3590225196882351091,
+                    114565651548015454,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15342992845931620512,
+                    14975487036165265785,
+                    11152833996098739878,
+                    1299764831890233098,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15864287037829314714,
+                    13810943522843122624,
+                    13666418483890027793,
+                    723803795141039212,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7516901243443039194,
+                    5303414721307020989,
+                    13877846754321839944,
+                    1313786341444281015,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18186391989667579979,
+                    10391382908121498345,
+                    11357969292625937744,
+                    1254915394521960192,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1681733916846059387,
+                    7522129847300940894,
+                    2563899392241626577,
+                    533871370068084656,
+                ]),
+                Fq::from_montgomery_limbs([
+                    63818687549160799,
+                    13925359153658251179,
+                    9165194855112182981,
+                    809589699028332056,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13663748694495719777,
+                    11016624229668839805,
+                    5513961667232249758,
+                    265988839234684383,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9978851309283612257,
+                    5662403853628023487,
+                    6878645122817092792,
+                    522331950819251263,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8443536272798827954,
+                    15655584703292638842,
+                    18157716460739841181,
+                    531405440218587598,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12779116391644799837,
+                    11939422513631999242,
+                    5306474264975975940,
+                    250579518171898696,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8508186696844316829,
+                    3706258997812933023,
+                    15604475444552262641,
+                    815899527417774698,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4540342299293326589,
+                    4127032595594198608,
+                    11990478131089764801,
+                    668439954730471780,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8745506457360288470,
+                    4106409111000624136,
+                    2908705658598882331,
+                    435729155445773784,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5779360198488683864,
+                    15583756362703908031,
+                    8374931626189626856,
+                    214865371689548846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4235534867631070001,
+                    15329469838846830739,
+                    11644314067924268914,
+                    468580156312789287,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11617380416448422428,
+                    8040525572375408732,
+                    13444978041098226913,
+                    605577495638923896,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15409767851926896132,
+                    14393420942298572835,
+                    11884831344422887126,
+                    1180346034380494364,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5243070862143680798,
+                    16236197107312531261,
+                    1522603161585426883,
+                    198439061956077690,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3796166555619654353,
+                    6958375276346843838,
+                    10478115890545096996,
+                    1055693593883015292,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1619399202337842516,
+                    6350181722461714686,
+                    13951442751674422194,
+                    368269574754001895,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13910203574282228182,
+                    16062830520514310097,
+                    6355691034956155450,
+                    246802389642074124,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10423737141842964083,
+                    13663900316721666905,
+                    3012121761109971827,
+                    751238545470024675,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9106823000081569319,
+                    8018038377564945639,
+                    11151104075260758459,
+                    104137305236270097,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15913874724511950916,
+                    11446160828364458522,
+                    3590225196882351091,
+                    114565651548015454,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8745506457360288470,
+                    4106409111000624136,
+                    2908705658598882331,
+                    435729155445773784,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10838957703589232845,
+                    2461702360442975160,
+                    11347149941087253503,
+                    769829791856291443,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13936813604802910014,
+                    7047715223244506389,
+                    5640647056088781305,
+                    120890863542026957,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18412138016534839167,
+                    6920053410287762015,
+                    3584251580193078253,
+                    1294201908675194297,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13950558290909128988,
+                    15879778960628854289,
+                    7552080204445611898,
+                    469501569561996001,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17027903037911846986,
+                    8981109894051437413,
+                    14719218490019613120,
+                    828719208921530957,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3635205751596133490,
+                    10464638875521886351,
+                    6261286754332782371,
+                    596476861632071017,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10559813781980402729,
+                    17264017683743112709,
+                    16060806980078533278,
+                    1343963742126639172,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17102877389437514314,
+                    4022033427416823044,
+                    10846266657738008844,
+                    347957573208322867,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16876141254906159929,
+                    1926781727626754927,
+                    12960492554458667506,
+                    537544288138429796,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10138096183744352001,
+                    17683021305609891241,
+                    11946864528150529163,
+                    484233685118099491,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5616010125310379582,
+                    2469025745204179491,
+                    6246958410016777454,
+                    252494778799514350,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15342992845931620512,
+                    14975487036165265785,
+                    11152833996098739878,
+                    1299764831890233098,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5779360198488683864,
+                    15583756362703908031,
+                    8374931626189626856,
+                    214865371689548846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13936813604802910014,
+                    7047715223244506389,
+                    5640647056088781305,
+                    120890863542026957,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12061282743519548311,
+                    2798114322350770818,
+                    14247187187049658902,
+                    1095093760521991479,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14160209050073577958,
+                    3157209367644156392,
+                    1989901182537114148,
+                    496626468206182032,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7705433978013001351,
+                    9472093556514353290,
+                    15510309290286014156,
+                    776394268196344610,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2895581509164096324,
+                    2503563099268647274,
+                    1610278804063992089,
+                    1329376445227163117,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14709094380813763759,
+                    13623706258040132995,
+                    2232007098682105195,
+                    822290894078631596,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14027541582886875156,
+                    3384353261549377387,
+                    12427130350932855342,
+                    525326889981733608,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16687732263481428175,
+                    10748975670194207578,
+                    3192082492325847562,
+                    834390957396059498,
+                ]),
+                Fq::from_montgomery_limbs([
+                    308264554393123439,
+                    2569205330787494450,
+                    9741491247747328611,
+                    1136297064712807078,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14535134777769220769,
+                    13187703343063951581,
+                    5836682010784620262,
+                    867767064573924885,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4341462121295695018,
+                    15148672647295054927,
+                    9776938388799384536,
+                    771307136411742822,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15864287037829314714,
+                    13810943522843122624,
+                    13666418483890027793,
+                    723803795141039212,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4235534867631070001,
+                    15329469838846830739,
+                    11644314067924268914,
+                    468580156312789287,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18412138016534839167,
+                    6920053410287762015,
+                    3584251580193078253,
+                    1294201908675194297,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14160209050073577958,
+                    3157209367644156392,
+                    1989901182537114148,
+                    496626468206182032,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11063121720558151625,
+                    7740784925258030176,
+                    11287156183159548995,
+                    895923300867852699,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14667410739730063940,
+                    3833715336668507002,
+                    11231729509931144198,
+                    434345426573879551,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12213016398494509683,
+                    12255246592689841926,
+                    4879501113019220757,
+                    647509405094789672,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5858353599980945419,
+                    13209290101085663249,
+                    16668551879743643082,
+                    526353739703258388,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16065530035254948223,
+                    4688978343985105380,
+                    13364101481639278987,
+                    413015287443835174,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2982701860315127142,
+                    11406777888190002369,
+                    4696577423211233838,
+                    41631612105424911,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2403180423239080646,
+                    13195885638674749289,
+                    1561779914079762389,
+                    335436842427912670,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9423135546028364780,
+                    8621902025976077215,
+                    4784316252557608591,
+                    463817115604533978,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7644312569795261736,
+                    15631752084048947828,
+                    6900877083827286691,
+                    519407948315869268,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7516901243443039194,
+                    5303414721307020989,
+                    13877846754321839944,
+                    1313786341444281015,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11617380416448422428,
+                    8040525572375408732,
+                    13444978041098226913,
+                    605577495638923896,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13950558290909128988,
+                    15879778960628854289,
+                    7552080204445611898,
+                    469501569561996001,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7705433978013001351,
+                    9472093556514353290,
+                    15510309290286014156,
+                    776394268196344610,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14667410739730063940,
+                    3833715336668507002,
+                    11231729509931144198,
+                    434345426573879551,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4049029297310968085,
+                    2580718159807549997,
+                    5392841189962287543,
+                    858491894982390929,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15413262281288530500,
+                    1364456296790755982,
+                    122865362122778319,
+                    1186140987444311278,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7109186148554833238,
+                    15455116356194255081,
+                    7255020126662504727,
+                    933462645571809434,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10849655879168484907,
+                    8159114382082704263,
+                    9680572476501647405,
+                    824196341898672402,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4592980783288114898,
+                    5985884132012199771,
+                    786569551644147916,
+                    285328106978464140,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14346006030226750213,
+                    9613111383286136616,
+                    11322078314737537529,
+                    863199374326022785,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5229238181723231862,
+                    1594078310289156835,
+                    15278788122503640361,
+                    480561932588870429,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15215372292409985501,
+                    12952063083891262430,
+                    6590422644840497487,
+                    440926896402529804,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18186391989667579979,
+                    10391382908121498345,
+                    11357969292625937744,
+                    1254915394521960192,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15409767851926896132,
+                    14393420942298572835,
+                    11884831344422887126,
+                    1180346034380494364,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17027903037911846986,
+                    8981109894051437413,
+                    14719218490019613120,
+                    828719208921530957,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2895581509164096324,
+                    2503563099268647274,
+                    1610278804063992089,
+                    1329376445227163117,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12213016398494509683,
+                    12255246592689841926,
+                    4879501113019220757,
+                    647509405094789672,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15413262281288530500,
+                    1364456296790755982,
+                    122865362122778319,
+                    1186140987444311278,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16078992669935765894,
+                    13199861743676776306,
+                    1275330371964835549,
+                    740887863858431856,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7330330845985706257,
+                        6947957132670498679,
+                        9373711551562393463,
+                        1140097580201425214,
+                    ]),
+                    Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                    Fq::from_montgomery_limbs([
+                        829043150522309210,
+                        7485532467658058089,
+                        17072453966431109601,
+                        610297755857677130,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18043687544558598371,
+                        8708325242362018018,
+                        16625116017321440141,
+                        277441131490434312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1903578231313020389,
+                        13252279451564941743,
+                        6429417326301386859,
+                        900077037001125169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14902495609461984461,
+                        16049616749407607193,
+                        8772353492731922841,
+                        1191393277823112260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5443236819267423281,
+                        9444106377351672003,
+                        16592192658774520588,
+                        1198721234626210409,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13217949757478296855,
+                        18361600593446830079,
+                        18223280994163007859,
+                        471593720435477782,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14891635570577939411,
+                        4930228641865103982,
+                        677506429995745101,
+                        158637391136506271,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        241833917490571947,
+                        2153702484066609835,
+                        8471674463316687531,
+                        1217041126633955783,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15611345302311497892,
+                        9150344584784175431,
+                        7017882794185538273,
+                        953114622258489808,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17306802895913322969,
+                        6741982267855984797,
+                        18288890876308278666,
+                        1226905683868895599,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6363878173450358936,
+                        10112955129707898349,
+                        3431100839901599668,
+                        1081814334785738474,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13015401582743701485,
+                        99021920379228901,
+                        15990834599944049967,
+                        1250016932247897455,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9650647954814615719,
+                        17525965197622983450,
+                        5408657878167052668,
+                        813838439362302012,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11758222862368032352,
+                        7949097982986379595,
+                        2746769843798240693,
+                        780188676608551749,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9007950493211424150,
+                        11303012366753188030,
+                        10877333652817483927,
+                        1319415368779856690,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11276424855573653721,
+                        4314168154231718126,
+                        13677099202256174837,
+                        1120255551963425688,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3318986396445040881,
+                        11096713928970105668,
+                        1360894689949882286,
+                        1229188134079228519,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12164746766547092796,
+                        16646475333691040315,
+                        12156017480010357619,
+                        28145562434814652,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        866790559630167886,
+                        13296635599915198643,
+                        1329242630429327456,
+                        935368749069653142,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4295608667191669015,
+                        5989210449120778569,
+                        16844320870839370198,
+                        459962876803120083,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8005879945137200536,
+                        615247156916158164,
+                        10587129435558664822,
+                        536717137234649264,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8445872841531609405,
+                        17767099328042616383,
+                        9225194748443181038,
+                        227913322671271488,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11579391545647715689,
+                        9763065863813555817,
+                        11161930109127523750,
+                        766165847823757841,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17951062090089418026,
+                        1476362472284548924,
+                        8385008469616889831,
+                        1210297656758158210,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13363108837410987576,
+                        7644218202833682448,
+                        16211222754671516670,
+                        1048844100080816418,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12511359242580751024,
+                        8455486316477951803,
+                        13283784831135325579,
+                        1109842316251184976,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16151242762508232011,
+                        16177764797560945228,
+                        9496341677909298168,
+                        592393119685467988,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6425249755705584573,
+                        1546198194404195691,
+                        12158076808578249803,
+                        807852487340801717,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1789681673726434796,
+                        18123788136646406453,
+                        13435302062554483395,
+                        114938553103742659,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1730320530501648817,
+                        1707768021879070266,
+                        3845694434877013049,
+                        1076040904373893878,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2987004353369773229,
+                        6022403589813148610,
+                        4174631298138908242,
+                        922071111564640752,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11474994194406856678,
+                        18355138432541414757,
+                        440880990099010479,
+                        250614024816324387,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10674432236390152349,
+                        1133731010565654036,
+                        10132812718213032291,
+                        1106636876296417692,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4669309405861239339,
+                        8533306218855857954,
+                        13505949773389214806,
+                        286361390395089091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14357310172472982888,
+                        5155117495039989051,
+                        16713111049326620909,
+                        308688993012770367,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12517392403929911191,
+                        11125319736284000709,
+                        6652126706277243216,
+                        1165394186981803665,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9348108429407198787,
+                        7875901781101937618,
+                        6805256758908695974,
+                        1015893537597011626,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4535034874752770720,
+                        18151639428072480297,
+                        4543196453237251344,
+                        914093853503835857,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3560589833580127002,
+                        13274181093728618159,
+                        808324056029693982,
+                        1342998230926981956,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16636376873750926224,
+                        15585931518211954789,
+                        4179139309426314793,
+                        525639795314829328,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2006032716801459667,
+                        5841890352443686412,
+                        845277820497652172,
+                        578530202460673839,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13119781891470105296,
+                        11895621915352739052,
+                        1814321489855703422,
+                        330541819931045372,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9531604146832238256,
+                        4728416855454184074,
+                        5649747422514502810,
+                        253460588875708787,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4290934279819643095,
+                        4981055186779137557,
+                        3670395489492388830,
+                        3907541864446827,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8030337788342444689,
+                        15667187640770332658,
+                        2445949837022816562,
+                        974044549881865911,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3841754909397746703,
+                        12219021271913727539,
+                        7633349399601372640,
+                        299432738444582674,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11535939763700614165,
+                        5160634017812695744,
+                        10333686827006010672,
+                        101729333379073182,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4409473472882022157,
+                        16881923839955333777,
+                        11528173042947956643,
+                        1234127403039168156,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3521656909188993954,
+                        12156386664562024227,
+                        3042033091259440520,
+                        607863881575946118,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10031276546578753263,
+                        16683327606114898224,
+                        7344140469798678912,
+                        923813978263189837,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18073663454023607353,
+                        10930922661793051564,
+                        10537048442944921753,
+                        437695940981137991,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7946127593120734284,
+                        17800269580910845931,
+                        15184524175934965429,
+                        255167258772607010,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1684540645054399182,
+                        9382376904067206558,
+                        6194125641553238899,
+                        1338273663401868669,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14082214105455265535,
+                        9077750454684422439,
+                        9244957395210511575,
+                        76480999729387650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10450373854398212602,
+                        4339143408959528797,
+                        16774311230121801504,
+                        957637940151431247,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        91676746270277287,
+                        5308910570118725950,
+                        17981966913832545554,
+                        612083808648434732,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7377150248193828444,
+                        15787816706930789913,
+                        8505602724298145396,
+                        1009592827277697537,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13433692115580640948,
+                        16361704714067170142,
+                        6693602968536153129,
+                        1206461761348192667,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18012452350663622492,
+                        15293808692760446249,
+                        6205841393961496836,
+                        904385875844182818,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15420400563057246822,
+                        14555468569056810098,
+                        6013053221473121517,
+                        145609735896394648,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4566061553274848655,
+                        16152158192697957373,
+                        16440174587758407728,
+                        1217225540857648088,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14719150502493602889,
+                        11831514668715218424,
+                        14243695880000405940,
+                        1139673250918481404,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17088041619174319928,
+                        17869564982627874640,
+                        3028138973735534734,
+                        343385763376786554,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11056189781606374717,
+                        681185782043994510,
+                        254600277131042645,
+                        305795039649343667,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15361136572772705492,
+                        14606542514926628398,
+                        12947104726866486919,
+                        1088579263092810824,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15013878644110726332,
+                        11637099740511635020,
+                        2296993124911791428,
+                        211394772035137999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4820244557603160465,
+                        11886751252288408546,
+                        3582411458341024331,
+                        739957268404524963,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16812866576513190251,
+                        11850554730542140256,
+                        4347152142617489391,
+                        288539500316791972,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18032624697552975705,
+                        4007596282398786392,
+                        5392035152918205092,
+                        998906301907318505,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18201550081127316640,
+                        4351836592678366421,
+                        10469108651054997721,
+                        1086768796898393342,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18319886657869875900,
+                        12233806647384632457,
+                        2800669420561702339,
+                        1118470044054537353,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2879921732557772771,
+                        18247447831004134578,
+                        15978778942523465618,
+                        166596264367780111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18109024340485212322,
+                        7301967174815467813,
+                        8290041930066988455,
+                        9083456050100534,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8406772656354170040,
+                        17152950721993859129,
+                        14916750082449940708,
+                        247076829490938338,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14957958713477582681,
+                        16632021810513757434,
+                        10085001738443071695,
+                        1321987359925609447,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12392153531102267938,
+                        10928519697965570690,
+                        4159037025598128103,
+                        317239680540592160,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1893957989622793959,
+                        11133785931189452477,
+                        11483450222023698356,
+                        807841543647058170,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9550067146274411033,
+                        11999807865080794183,
+                        11162064472576902910,
+                        113044365299303925,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10816706558898810455,
+                        15460045186189365590,
+                        13250565028913236739,
+                        878781572606399996,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4264579370741236280,
+                        9025758258765648135,
+                        7831169897667529457,
+                        691152344619346017,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13818433074447908202,
+                        8634780735402927812,
+                        9634587913751215900,
+                        351398609684429084,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11892593892160116764,
+                        13835165005250135225,
+                        9294020043466894813,
+                        440563229364018558,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10450234573379191937,
+                        11585507981617668119,
+                        1124479766861605917,
+                        545976540262107860,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16986916638034447954,
+                        12565274328759283565,
+                        16371195350237756341,
+                        1092701530720078166,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15323832633867768241,
+                        6813474834214877046,
+                        18342988111794667393,
+                        800376929067957603,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        750401076376055734,
+                        13429352859572639480,
+                        3531950934283017507,
+                        1110478642431854787,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9138904884346105904,
+                        18428067988226133645,
+                        6979554027059048713,
+                        292151289282922706,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7018761863483002360,
+                        6527411026155664637,
+                        2943146062652129910,
+                        1207574845718182603,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11320896331343635647,
+                        5503128334849057667,
+                        243686448876237904,
+                        940430925991885651,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        757338143111934992,
+                        15942091041248493747,
+                        5221423176460027862,
+                        407616393980664740,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9312998848153462512,
+                        790079919858862728,
+                        17102047639172744351,
+                        1123106776969298841,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13667688851039508035,
+                        8287583885754817739,
+                        9117138478657480083,
+                        1103658587584245869,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1873397731061223598,
+                        17165868696232639405,
+                        12554483865820123457,
+                        291973610784850010,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14398840209921866268,
+                        8910968506932060634,
+                        15148270179789483024,
+                        986606671534033204,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15483940682540140870,
+                        17949233439125588977,
+                        6184005977049435926,
+                        151162116628185069,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14812505643530357969,
+                        9636378046320615982,
+                        13008884098588536219,
+                        27945878009282806,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11016331775375059536,
+                        7713256244325989408,
+                        14548875314178119796,
+                        763221927818741670,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6783214283544676536,
+                        4580033141685662361,
+                        1405763845326093956,
+                        1231502601519224315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9899646866917059964,
+                        8920252825625585546,
+                        2848209654972427418,
+                        927545191131733581,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10377432701813593169,
+                        4235781485415140782,
+                        8327400181665246645,
+                        732791799778680379,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8375461483191855297,
+                        9338087426502086924,
+                        9463853045527376344,
+                        650497189877534053,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5960055831178548611,
+                        285990149530335127,
+                        4211887939754929585,
+                        246170455220583181,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16138021527609859116,
+                        5207112762072958443,
+                        3187958022041153784,
+                        284999932246027398,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17738317771679525934,
+                        4259891517845485078,
+                        1815840670620586054,
+                        820093186621114208,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12225456383603735067,
+                        1432972713689853290,
+                        1406510967045453803,
+                        216866201597005506,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4264898853834076305,
+                        3060027384281387815,
+                        16636225048946507259,
+                        691782484827256140,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18151819843390993901,
+                        7993616439285759822,
+                        11820575734036832557,
+                        471072426481939446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11542047531499116081,
+                        6754374927689293984,
+                        18109252105935758671,
+                        530476550514376141,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14336556448850428208,
+                        16359274908556914907,
+                        315988260443130054,
+                        1319652301799802712,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8085327330770798062,
+                        17865884203225343768,
+                        9441740719508909454,
+                        986882970649928081,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4633219253982952696,
+                        8504278193909905396,
+                        14662260673675198473,
+                        325638931232966448,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13640710519664969623,
+                        10926816135246930327,
+                        5033377347937536616,
+                        400831637026226521,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16667973339804532748,
+                        13290992504476964600,
+                        13783072788325536033,
+                        239849221813019263,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6425486408774441359,
+                        10176753813392637489,
+                        12085098466995943510,
+                        769971327718388396,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6437518523886159914,
+                        295873509965434911,
+                        8969760679931561839,
+                        1144057895892598700,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14107622941750342745,
+                        4675783848874233386,
+                        1599372428323762599,
+                        1173088664435031026,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5619014063642874306,
+                        979295232468374249,
+                        3674928689640410678,
+                        208792860474316512,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        271526207809489410,
+                        16768585117413048931,
+                        5168368629626699852,
+                        418100388183170752,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3518887563029962000,
+                        6338447914566053284,
+                        15157619082144902730,
+                        1113610726607353120,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12968473929687792623,
+                        16656451498318144534,
+                        14289545081282012186,
+                        85534849533640281,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6167819125726341487,
+                        13074162010273548807,
+                        16896362188759737960,
+                        1100862897350229908,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2890087814192321291,
+                        15809022291733748338,
+                        1553688080261602679,
+                        703103399561597837,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        906775998954703580,
+                        5669817669349299300,
+                        239201307449250705,
+                        145433264591204102,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13479099744864664128,
+                        3502333366978667918,
+                        8712226635871634615,
+                        860968210999335581,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10691989656790844338,
+                        18224994827090397471,
+                        9886090757478575543,
+                        351623830661710735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2189875655772155914,
+                        2604651594749526377,
+                        11052663122971959066,
+                        1202781068916163196,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17401336258965035977,
+                        14723466517664000712,
+                        7180218013197664920,
+                        77612934481709955,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9644617056925586190,
+                        17720551363012011058,
+                        10730142168694648654,
+                        358827838552792581,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12404067159157564503,
+                        6288549291550196869,
+                        1679405479259620321,
+                        961697600379648516,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2980683222383338796,
+                        17530502315450352290,
+                        12863267702461956160,
+                        165818794192818499,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4238025626223032956,
+                        10024427608843745950,
+                        13885412317437195037,
+                        260877817238256901,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8531826372851399703,
+                        4843819541085114961,
+                        533714394888186057,
+                        472781883123611010,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1244181350536877015,
+                        12988502379450352112,
+                        9676877446103793603,
+                        23719483797788310,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8789760178089371500,
+                        2184811756351209508,
+                        9448467625828585210,
+                        1005635621127961519,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7418228743499538996,
+                        14541168927602612767,
+                        669877359553561130,
+                        641902373578352454,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11339346412535984358,
+                        16866833435582750845,
+                        5141684917904002248,
+                        1014545733901620764,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7474660870807997065,
+                        10688928859880705119,
+                        14516447842724834882,
+                        541303705423316141,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15842378452420703223,
+                        7765923555562178963,
+                        9641650878518098777,
+                        688345173986810714,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17905353942408470462,
+                        6210553519086371472,
+                        4466353260640504183,
+                        624301581168102870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16346385299619293998,
+                        5246702220924390206,
+                        13797658436581105292,
+                        402815890261937729,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7877326770106782120,
+                        14386590298115802581,
+                        18341527437087621403,
+                        5043563485931649,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11047909722533543610,
+                        7700834724676398816,
+                        607559724158996410,
+                        236852515977150546,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9942436497975488530,
+                        6462676045975243046,
+                        10208966565883848713,
+                        627712720793094058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11354793968071459543,
+                        7169382126799172847,
+                        14612240805704598069,
+                        1184884507875613486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14591999823944576511,
+                        5933203152446835327,
+                        13733727866052570698,
+                        612807031193106136,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10510986279001415721,
+                        1865814373070988614,
+                        2121831936282019377,
+                        237869071560697657,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11418996668010692812,
+                        10712265988197487505,
+                        7352511578702668504,
+                        272378040324319616,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4030250027060226067,
+                        8068583482533078805,
+                        8947577901388776941,
+                        282721624279860451,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4852020275440152641,
+                        17907342894708198555,
+                        5135931559072503583,
+                        387386111804448856,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7249268788388791897,
+                        12831847505515993812,
+                        14127794179473090197,
+                        170658706160444052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4699386010651056244,
+                        15224735711241556491,
+                        3247847827966973018,
+                        1278702243677151090,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3805657502621032411,
+                        216883035039637933,
+                        11429240438769544939,
+                        206245198260778225,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15861578808104687977,
+                        2871809646084189909,
+                        2471583321332042250,
+                        550284459343725961,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17666482875422793659,
+                        13216624400864752525,
+                        7789071216087447668,
+                        697900623748213139,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3152982129587623667,
+                        11855266860125266757,
+                        8174938916144698764,
+                        58292352243114378,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11452979554300395035,
+                        2080189123193728913,
+                        1708391040673697667,
+                        316950269938766449,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4949482019650345740,
+                        4877625200827604029,
+                        8772129775121014903,
+                        374883791753286625,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3081995619385294997,
+                        3128468070132577177,
+                        15050290585195515127,
+                        1100986836473928360,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9136275217521020675,
+                        16569275820386457188,
+                        1613263972128767937,
+                        877954131656150539,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10150460831990738849,
+                        11758439002514470948,
+                        8751492907960180064,
+                        506742124407533410,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3704741676542822905,
+                        2801336096871406819,
+                        10154025956100922476,
+                        1344246849765135552,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1057007115096185990,
+                        6459825538886253061,
+                        4592998144560896284,
+                        988344374343773741,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1108144062177461887,
+                        16073363040382781964,
+                        10088520139166273601,
+                        680161723307416005,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17416521968702813860,
+                        1029294305440606299,
+                        3601285219007915489,
+                        1160998036061460533,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17191525853297079532,
+                        8640744777782774800,
+                        13424794259771160894,
+                        627883995535835989,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5450778862024498209,
+                        7949550906820124978,
+                        10410729426099890827,
+                        519597473460460313,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17658752684723158188,
+                        9763617342187220982,
+                        1085434551047862257,
+                        1094978127570736761,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17798421012284243181,
+                        16603598765538198759,
+                        6360300093493652470,
+                        792564177974162644,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1085306129524327337,
+                        16983435617598742050,
+                        7453710106765453677,
+                        636720685209030557,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2556583584324547794,
+                        38999501551590599,
+                        7238110678308677586,
+                        157263521427731054,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14849752381718162741,
+                        9517565145310276401,
+                        13094179591981557977,
+                        781332034058655554,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3296717490596579,
+                        10041135372888500479,
+                        15196452722383132747,
+                        589874939844380653,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1261492133939221155,
+                        1150517195013397701,
+                        255674682384484358,
+                        945654363741762036,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13629756502521472991,
+                        16374318111909023163,
+                        6784522259123972382,
+                        719471617414193672,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        158673833805190149,
+                        5456836069654099187,
+                        17589614495156727950,
+                        14253258430323471,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14404520208617320420,
+                        10706481286855819661,
+                        8200663285291664799,
+                        766655317581238083,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1421327259217181136,
+                        18106599804266327700,
+                        9022727608638967286,
+                        787100568143167727,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11332497563614254729,
+                        485385800289207160,
+                        10336806625378637669,
+                        558519535093729955,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12646268777777360953,
+                        13971941366258397594,
+                        4817617590743953881,
+                        732166620250807125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8306630015286539824,
+                        8767943279097997305,
+                        13284024232039239677,
+                        1042197595053262548,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1747096002219620857,
+                        1388626758172978513,
+                        10135247665179994852,
+                        871561530651732537,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        105848366145447242,
+                        15445440443045017454,
+                        3038766866290961570,
+                        924428558031450586,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9747642591468051024,
+                        8178504923173527020,
+                        3523286412901906116,
+                        993646033901084579,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17963797360811434329,
+                        5833755853267478070,
+                        11059189778805776617,
+                        1117029563220939412,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9776477656888596059,
+                        4123972855987093917,
+                        5109104762063196420,
+                        873372104526411,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1666696904468435102,
+                        2518526630152607818,
+                        11734265975099943588,
+                        1092452831137586752,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13318047088408508727,
+                        5776795249521774335,
+                        15530584573991099580,
+                        691312875495736756,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16153073268887916929,
+                        2118297028297682089,
+                        255691861412235299,
+                        836381702874414087,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        402644753777101882,
+                        12141274296408387959,
+                        3345889596354474409,
+                        561975757108172490,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9646237450576353575,
+                        14391362790052978507,
+                        12633011254696696040,
+                        1161746170893003630,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15923905644410757032,
+                        15756979799526804003,
+                        15343698507140448393,
+                        1235914147037329414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12987324236251484210,
+                        9426067899759991118,
+                        6330093578451525081,
+                        830560842406515894,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8188788255476271627,
+                        12932491102235763645,
+                        3037787323025409122,
+                        755025388254401192,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1530799875260247459,
+                        410559900557283580,
+                        2786043354375426400,
+                        115851549332319539,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7529296420184652365,
+                        1410278350925582982,
+                        13818725634000832700,
+                        1069860406588422806,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8988001087826261165,
+                        2692666466956349024,
+                        109923348441852183,
+                        1332486057145830788,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9950393372179749227,
+                        14486552507502650051,
+                        1709587731405844820,
+                        83270126243927050,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14888717915614820897,
+                        3623227920778371035,
+                        10842572890195205215,
+                        1267457203603380133,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11591002175486340747,
+                        1118260682727714220,
+                        7598012728561471036,
+                        351762386529455205,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18094072559385725970,
+                        8311672668418999428,
+                        18401965113638002384,
+                        1335239230550195501,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2701709545563725373,
+                        4410203946258901140,
+                        127977835153035260,
+                        716971872708169281,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8223328948666929065,
+                        7799828570468194304,
+                        3155768912999113716,
+                        218124141091481824,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6609513320002802811,
+                        14483821656622611253,
+                        5287496870503061415,
+                        624339232992070513,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8180609858965255868,
+                        46531072049172885,
+                        13610525063268974554,
+                        711595878750421132,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18405011670741419591,
+                        7224245335770896017,
+                        635896683703833181,
+                        994886498034352546,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11806860908931615565,
+                        875273699999459579,
+                        13346921529098476389,
+                        145097537512890048,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14221092792060694188,
+                        11867954480024282486,
+                        1505566952384454411,
+                        928438999617521810,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12828561790445315131,
+                        16645909347666298147,
+                        4832439075422564291,
+                        400788135458799925,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15614954913216472570,
+                        7070751661002972887,
+                        13339876461784045304,
+                        1058526299064300135,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7571729954712502552,
+                        10641183046981436580,
+                        16968802582024480123,
+                        111192801588694876,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        814363878310270050,
+                        16068203454041336443,
+                        7848591842102055727,
+                        698541199220715354,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17920950947828081835,
+                        18003521464178647479,
+                        15757664491904822761,
+                        326465583141335046,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8269243031340694121,
+                        6997290410256229448,
+                        2374774558009074088,
+                        1079104624623433217,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4031543148600678102,
+                        17217537683034647979,
+                        1047904732157679479,
+                        1157028130438340957,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15990210240925152297,
+                        1889757386203833158,
+                        8834758678344027520,
+                        334991954077568431,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13982200419948229464,
+                        15148893795380187507,
+                        14606701135786917508,
+                        880787008995064477,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4994512884300054049,
+                        10743890917311405002,
+                        9587807311616457124,
+                        283502921379701932,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2838518465037669581,
+                        7171395168073595021,
+                        13461809087723422990,
+                        381651915868883906,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12729361633157235996,
+                        14137039767895918863,
+                        14224230885319347102,
+                        456924425053631495,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18296075048161686382,
+                        2719107055878608943,
+                        12784064752563741838,
+                        154987337534341263,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14554045644621439010,
+                        12647996450907228685,
+                        18039448322722294967,
+                        836467369198032196,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3925079564457548701,
+                        10791439435082556731,
+                        18024517319040556372,
+                        436598039730583387,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11526024970261506123,
+                        14183741860967428001,
+                        15793145655459393859,
+                        196810127626114303,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6406769602476501828,
+                        17009683811538695893,
+                        11103375203640334039,
+                        930076338261987083,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6791865864478265547,
+                        17863495684796907168,
+                        8306892304349813517,
+                        241263777396523896,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17918235176742415033,
+                        17868305906297980873,
+                        3757748037426821204,
+                        1278587404859585491,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        656856996450512268,
+                        4625676982347659707,
+                        12356742010570194979,
+                        833231148489885871,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5884307835801872791,
+                        13036058909548687891,
+                        582202959356495765,
+                        80247713836010126,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3562478117426949915,
+                        11950227099569887652,
+                        1945165182256247863,
+                        767323647856532139,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18110333920223927607,
+                        16397027210261936225,
+                        15530680174795213222,
+                        370449859624274654,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4698334482005057297,
+                        3543140374644527241,
+                        4170950579013971026,
+                        633669896149367022,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14488216394121834496,
+                        12211225828685929703,
+                        4658769018115964976,
+                        599915753603010155,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5186159039954556473,
+                        9080827354176950936,
+                        1054237670649895770,
+                        1192572720235821726,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5809753222827730714,
+                        12856938296571521436,
+                        18173376317303023448,
+                        741718298527413648,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3348664573778557040,
+                        6342336608405984801,
+                        17178538107545996637,
+                        795832993098776919,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11435144058083543476,
+                        3403742193967896682,
+                        13989904242684817065,
+                        604592746196041302,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7865386158869765640,
+                        16458708941184150531,
+                        4528492657561898610,
+                        671874014693960345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5728640996860497522,
+                        12261270254391711562,
+                        15813646048326845794,
+                        496323826537073844,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17710476523036173921,
+                        17143833154285401301,
+                        3199292246809083970,
+                        381795331921314673,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6471618469731954094,
+                        3559850545656408146,
+                        2729631562871268599,
+                        244360276958021229,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4469289512000351904,
+                        13465453379378574731,
+                        18388340308112803641,
+                        937489638189246427,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3477753318255846836,
+                        15054922946279020150,
+                        11534208408702200140,
+                        1188855024211543463,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9941797424677875205,
+                        17837061970903096697,
+                        845226270675303362,
+                        1229506586213865149,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12256100516980571811,
+                        8946977310232472596,
+                        11225391980326009139,
+                        849959630472999404,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3610472292401334973,
+                        31989544400938977,
+                        2387369378704735223,
+                        663948211650953634,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10769685087058749813,
+                        1201336617744621676,
+                        14708905080892020843,
+                        139690113674747026,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15535844718604436488,
+                        6871110853643868613,
+                        11000686867317269172,
+                        1056494368954625225,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15624111885598686477,
+                        6568896320743772548,
+                        8252221250860072824,
+                        1075188682906482182,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11264711223465748745,
+                        278180359791383261,
+                        10792752011563533176,
+                        650108863058631894,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7253503720798228218,
+                        8346647182162717858,
+                        10495605760908282866,
+                        992249791436910362,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6919451838956337713,
+                        11257316617527500904,
+                        6794491842761594572,
+                        417415898410469329,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9236517481928300527,
+                        4569404043036180472,
+                        17485819699829177984,
+                        997538897604522376,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7073499438852194038,
+                        6622688083779899198,
+                        11347755774877744827,
+                        299033193151318419,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16534762550808025544,
+                        6199346664012163316,
+                        15375020742826089932,
+                        1164995782602407340,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13662197614784017185,
+                        1963660037696222399,
+                        12938481612251330393,
+                        1240637746888863588,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7016760977769407706,
+                        1233632003397681219,
+                        15241335188541420236,
+                        935182562986301288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9969738925785440465,
+                        15055852121110019247,
+                        520397034372972451,
+                        768866935492732200,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11882869638068173342,
+                        4830761470711946917,
+                        8160198024187327832,
+                        445112877721852650,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6341748821732790360,
+                        15379051739535967800,
+                        3554020670341654866,
+                        542344772493615527,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9729827461474420641,
+                        15439958701644462758,
+                        6492473819039429153,
+                        154716097535518825,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2522109997788697609,
+                        7305120457501952538,
+                        15598817347865321251,
+                        1342525985818970728,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2194067670136886997,
+                        17012747278576736927,
+                        17145476894269409161,
+                        278083604100469399,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16042534223093808561,
+                        14416734703834910447,
+                        16731840157481630732,
+                        944218040891052737,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7125911118744265432,
+                        7968559466406594814,
+                        10035770719670312662,
+                        1145142505601865786,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5739158450033356699,
+                        5980552366267562292,
+                        16198593125798892158,
+                        1306501978945242129,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17984158677786219204,
+                        12914430311731388232,
+                        15210450154540500791,
+                        85360652661336681,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6340236045342458682,
+                        16967431193128665936,
+                        979634105746793133,
+                        1308202617185151164,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12019947736700964637,
+                        7448489635804578602,
+                        15432235764087769052,
+                        991634188311145754,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9031281592837391818,
+                        16435506160910540433,
+                        4116487321625170631,
+                        1164542187589839680,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10133700827040676769,
+                        11044120306904320006,
+                        7898144031613008194,
+                        405179682785065111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15233996849193600590,
+                        13822497674446764302,
+                        1962469304263754299,
+                        608378576498771321,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13615276360215888644,
+                        12412197518940163128,
+                        44412420504261544,
+                        1055135858877738921,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        556354544095295930,
+                        7618848913840777221,
+                        526834111954353812,
+                        1058654318047728934,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1168259470703607067,
+                        17898386433795901756,
+                        2256646809108665552,
+                        1023471044099467585,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11040092808128203310,
+                        12243094326352422725,
+                        1497902773137241120,
+                        456544552687528042,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7468662661076994178,
+                        16840198466632321632,
+                        14031790766168522596,
+                        116331806076684331,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13789333414975408217,
+                        11822060050834366395,
+                        16765014221548275999,
+                        679421084962409586,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7888528396614155124,
+                        4820996466945297928,
+                        6540524676946236726,
+                        982396039958737544,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12627068472662475363,
+                        14086877643022591566,
+                        620631648775238263,
+                        652484040339966007,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4782118846664787778,
+                        12873050075258153856,
+                        17942515158846610358,
+                        104224330949304708,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11461798771061110905,
+                        5699090958583796598,
+                        9853684086354233262,
+                        1113936758691747988,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8031028376264121861,
+                        3777649095060052411,
+                        13039547246844386820,
+                        960846910924905549,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10876396740768936260,
+                        11278786326518605004,
+                        16839381626852867148,
+                        871903183715029710,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3969642563243236226,
+                        4172370068583337099,
+                        453406305362766850,
+                        508926996455723883,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3185969337453663830,
+                        4514792906476017250,
+                        16418835211510571710,
+                        1283737598912417133,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6955111841889475090,
+                        14489801925275450337,
+                        7865536110304688062,
+                        1278539915495633694,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5545726234507715496,
+                        861764998713980083,
+                        12378148102524918790,
+                        735762368101756932,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        175917705357233640,
+                        15103935126084222252,
+                        14486977588672448514,
+                        487383976931369037,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9991662142111636769,
+                        11266261208091853509,
+                        13491502884289002039,
+                        1179728148798064638,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13768251730026329039,
+                        14452437892493945411,
+                        7748895083463528600,
+                        1156375514114420935,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4003028901188374308,
+                        9261875104747910348,
+                        18310247360786514,
+                        956958906647988130,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6493331748945939471,
+                        12535998091293856632,
+                        17778678392332900699,
+                        855994867122098391,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18279337790064270175,
+                        2365235413626693535,
+                        14288832469818642330,
+                        470563689602956545,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9008851574131906415,
+                        2763855770340368056,
+                        15276354676202508773,
+                        1298834647485553886,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6543182431555876777,
+                        3005562967826358199,
+                        4085251905671964645,
+                        324547059049028138,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1193746457766764349,
+                        8997393509290296615,
+                        5196959774117891546,
+                        289282934335309934,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3162219238013921151,
+                        5964286035093520214,
+                        10738230918782545629,
+                        16993989655872748,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17416863247179525108,
+                        8029128737525531160,
+                        5427186019846958983,
+                        866839169607019299,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14216341830412857909,
+                        4623624604913600907,
+                        5446941465755254203,
+                        1311870081325924597,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15223053177516798438,
+                        7833252404639718851,
+                        3619913449926311762,
+                        1268340763633666105,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12970738342211404055,
+                        1811671122321249325,
+                        8066387344238536161,
+                        1298834147184429705,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3336618070986467862,
+                        9607663281562341932,
+                        2573483447935757963,
+                        1220668315736023588,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10887021052049639550,
+                        2058082443055984892,
+                        4247941108856939609,
+                        985787898944530243,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5795871339779803642,
+                        12379465025100508985,
+                        1366151649285523595,
+                        730103293347530313,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13722052924803419638,
+                        14043144752274915327,
+                        502941346998843037,
+                        202187519743590468,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1303864695783958476,
+                        12246359488476766200,
+                        15057538202018049288,
+                        315588312162487079,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16463208307334448874,
+                        5971798767218653828,
+                        4724284130366558527,
+                        265374878517602717,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6344861640205640006,
+                        6890310594727014294,
+                        11401521276951770788,
+                        1086255530710852159,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18431474606226949361,
+                        16177801521506314004,
+                        14402401584880763033,
+                        1321269856934916347,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10859659223470252627,
+                        15858555128560346830,
+                        14745990770510531297,
+                        690618830186883365,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12620583016691118398,
+                        5115388333486287008,
+                        12637537314560086383,
+                        190614887840215266,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15362280495025898876,
+                        13450734252060058157,
+                        15690715919073172823,
+                        1031530204978694566,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15449122537079605128,
+                        3918921125194644142,
+                        7220396846605454071,
+                        437050753255579584,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5886342142675755569,
+                        5834017854942774930,
+                        6254657957380860380,
+                        308569112812756185,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9216959847545392400,
+                        8102362187113416164,
+                        1134832071184773902,
+                        805549938995211730,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12533850095131859476,
+                        2176436906075131940,
+                        14305678357768524601,
+                        576268285685187128,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2256714025878016672,
+                        192241768579705874,
+                        5701663988077889599,
+                        752682747547383889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12235173258622475654,
+                        13465555402489023897,
+                        1808186339819414638,
+                        556400051889853329,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11023024659878896437,
+                        314997717672706253,
+                        15352536582202574615,
+                        1072175940394549698,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15011289599793982567,
+                        10082107691410492020,
+                        5487522290988344574,
+                        360907445654314381,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10985876618434455552,
+                        1673250754931579202,
+                        11174233704647896016,
+                        394523057820257066,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10630738318853131738,
+                        9955855906107185691,
+                        18032106572096193303,
+                        864242057289925969,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5014332622548567119,
+                        6851602763378312876,
+                        12602319149587059808,
+                        635263466851081229,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9349339614817321463,
+                        7896338689851590697,
+                        1856730843450456630,
+                        388988469737300571,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9822831319249661472,
+                        8911376178295088764,
+                        2791726529161332587,
+                        696084494031139918,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12605291641540560580,
+                        18394330772625722549,
+                        11722118676938306873,
+                        126368286885213001,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8237874939480868899,
+                        18308980816213194904,
+                        14021774957187131895,
+                        1233313185503012265,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7802202392068203594,
+                        7628369541530158556,
+                        11057278164495487874,
+                        631849616037930867,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2093346972143895713,
+                        7647927813777508218,
+                        13139950389299443775,
+                        905893227028661358,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        569275653993648308,
+                        2155515038777360983,
+                        15204175470927454744,
+                        218808216962433181,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3368401348594646497,
+                        6880455507050426850,
+                        13093290772670567058,
+                        629048169655398558,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9956676790354350544,
+                        9311811638576227918,
+                        14302689390827890192,
+                        3330839544143491,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8549832889101642808,
+                        2210875762976615970,
+                        10518490424850324882,
+                        371101997563769094,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6390840675950223358,
+                        9292914588988532713,
+                        1751324627129643987,
+                        328276510252400939,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15472837249155042324,
+                        11787895319817556624,
+                        11766857501074773163,
+                        1263976003677653652,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13599497901459462450,
+                        14724602530718952381,
+                        10485121911780422702,
+                        1246483448468088066,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12238789962043117094,
+                        5380427408193559488,
+                        12620820524827605110,
+                        1264654306209205489,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3990557934558282172,
+                        8482632960916358125,
+                        13348126338205414084,
+                        946138210988067533,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11731033530025292552,
+                        16581067566588334796,
+                        16486036119253300363,
+                        271154336104846153,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11676070001555480643,
+                        15661564639273537038,
+                        13004965570259577505,
+                        829423492827964122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4044677746944679693,
+                        11013486433422133303,
+                        12105475509238095924,
+                        672591009341815177,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3537451149624829349,
+                        14529218771377507638,
+                        5710966808628049169,
+                        11966298155005516,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4113532487521611796,
+                        13505579558584298964,
+                        1396195227509700689,
+                        840075435493354942,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8760819283724196766,
+                        17763181566883300432,
+                        2697785151734931514,
+                        840914986806668607,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10129323725616466776,
+                        15436220715112185370,
+                        15987540789071858587,
+                        519643057663270629,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7916032971779182667,
+                        14399142366214107654,
+                        16666964961189283670,
+                        1062496231917832434,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14478501884237351677,
+                        18038687005385593297,
+                        2686737415273285550,
+                        1092275567588291689,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17986379899284473254,
+                        12678856013278730333,
+                        8483532420559055586,
+                        690494377616182785,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1444867608082851665,
+                        6341270876985361844,
+                        9069355740123531330,
+                        1051189897603549792,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4194735600553821945,
+                        9229145492711648189,
+                        17706475697932861429,
+                        801843883314924284,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4988477530250848904,
+                        10181141013328354493,
+                        5156515357447105951,
+                        889512721331712867,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8848129338388315565,
+                        14812092446427299460,
+                        15552257649878220731,
+                        571123384894026983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10695391303208266448,
+                        2504082796413193057,
+                        6228144716378959019,
+                        477955418440755829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8387968253014334948,
+                        1275163079976958838,
+                        14724003880350369289,
+                        1050656811443193624,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18293843957569873689,
+                        12342354253466039797,
+                        16562513348078874966,
+                        426462784906567705,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14554781756868170637,
+                        6527012027146268133,
+                        16038728438348727718,
+                        395550588970441910,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4432748809822677068,
+                        7403284272894520651,
+                        1864822110125034663,
+                        259681120515252275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15763725587593990602,
+                        275925525414841197,
+                        10495325602519076678,
+                        1249293785989137479,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4430525544256709930,
+                        4414607679533634636,
+                        17741206845119702634,
+                        1195249748265102940,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8559930808898060450,
+                        18050336832547560625,
+                        1499332040468999481,
+                        1187512294891374297,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5814153739016203023,
+                        9468077893947045091,
+                        5254479171414563335,
+                        300095021882250625,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5103683102851573036,
+                        10972963600128132364,
+                        8697028314673633469,
+                        1177470370335245339,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12511411165450595705,
+                        7494108379936370708,
+                        510134352336612011,
+                        560659085144800775,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12940993730717294747,
+                        16934016978167200439,
+                        11403095739510888042,
+                        148863653301587429,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15271007966465571339,
+                        4302394551095224028,
+                        12408928707340041274,
+                        61733842103734163,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        993172182714988553,
+                        16167128836484954623,
+                        10643549282004987628,
+                        135358439567765395,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3195719354482740434,
+                        1697106931936799034,
+                        10246053809217178224,
+                        183254576694727053,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10850747700397005661,
+                        16691232892966770819,
+                        10587595445393413092,
+                        1094243180842885867,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13958489267631119383,
+                        10100522154436595663,
+                        9360951534151738215,
+                        929890714851662769,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6473264099853767729,
+                        9410854549366137495,
+                        9438347608706070605,
+                        528786093346202649,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5249182890956117102,
+                        9916996428713380374,
+                        8780010019570701812,
+                        1273385242790094389,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1055199029733499115,
+                        921266909082384623,
+                        929645582110851528,
+                        143933825329591380,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4558177855323068613,
+                        10690076281561164841,
+                        3406654920590952477,
+                        956440966916324486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3033074190157745804,
+                        2295082644205636383,
+                        18119257353863168206,
+                        352442962909993418,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10412167907768240593,
+                        3758527133311176512,
+                        4957640360017286841,
+                        548822427773796051,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3161469107065307408,
+                        5186145075962012979,
+                        15341734800039119865,
+                        956898223662337693,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14426953525218264599,
+                        6264363247754765313,
+                        11648555234756157227,
+                        507729258388836917,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        527431161130313103,
+                        17175439448178578960,
+                        14590232862002894016,
+                        167880791134420277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10609888681914099571,
+                        16413522339597976649,
+                        4585273117622014339,
+                        1283963077573350020,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1500556078265630369,
+                        13091406195472997175,
+                        8608612044113985925,
+                        1024773803352878630,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11989391788175633261,
+                        4802652207510030354,
+                        12988826255205767403,
+                        657951896294229479,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3701002155829365748,
+                        13822883064259266093,
+                        17737280153877218882,
+                        196117365101607315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2096305925721528650,
+                        16229876831208241922,
+                        15344227764536318860,
+                        1218172626682756606,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15099688313080726609,
+                        11208035132252636139,
+                        8166062212274380453,
+                        844632610438425869,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7142559293619604510,
+                        10502293735114890630,
+                        11673559797553638054,
+                        328455014354838684,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10823303015873312768,
+                        12113947686368093274,
+                        11697101675166476155,
+                        475086851097545822,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9187757725494870881,
+                        535714132887622242,
+                        8740256893950177624,
+                        401349652468972776,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3258387595093691856,
+                        10524243264499662001,
+                        1599799495989453458,
+                        988842990506651315,
+                    ]),
+                ]),
+                Matrix::<1, 13, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7715503632192311875,
+                        12128975207445449986,
+                        18314733615222387783,
+                        550753137228563643,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3993075596548884183,
+                        17511494744897600527,
+                        14007486944898397385,
+                        1303506922831015894,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14523125182013453206,
+                        16781638693909795160,
+                        17315485378028781060,
+                        268639430984697006,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14188510750469640935,
+                        4891291916485436036,
+                        7473268892046825902,
+                        1244734981254733329,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12220288066935474213,
+                        4819930704345206942,
+                        1069701800724951017,
+                        391128490725733879,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4043555813212135816,
+                        1432827444299651991,
+                        17882170639321821965,
+                        159858198815482853,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12463079881500420306,
+                        7331968862491212635,
+                        16565037959850014715,
+                        1317919997745262873,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2698535164823913940,
+                        12849419641653454365,
+                        4587072992431418535,
+                        928802516029077108,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14668246463973363654,
+                        2527443584824351661,
+                        10087740064495472667,
+                        862308467082593334,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17551410868668864265,
+                        17546708110196048596,
+                        3470103821888878658,
+                        268410641043327248,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14745299688147360228,
+                        13546115218565935698,
+                        5834944401283745890,
+                        1113437333611152810,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5282434944938889566,
+                        16968992617461537372,
+                        696852929709070498,
+                        1099996875662441934,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        312268378733766370,
+                        8454223559721492387,
+                        15930630777767052390,
+                        1109030355835116487,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9815670447847785208,
+                        4079722876937034884,
+                        11422378354384329757,
+                        649165021361371380,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17511430873597716413,
+                        5928873472577192420,
+                        601659625206462026,
+                        1012012616824126626,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13020196276380576785,
+                        3383189349857083150,
+                        13321358381597132627,
+                        336353199953950399,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7212006124599567829,
+                        5963800377430924987,
+                        10691281994720493347,
+                        906053260013683670,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        779741893339597989,
+                        8271220249268629585,
+                        1516467479562616512,
+                        354453287334411338,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17311713310339808713,
+                        5418945487078430132,
+                        16995790256922293747,
+                        296427064414833774,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18443760932771078673,
+                        3573418537246988751,
+                        17886835747984975455,
+                        993472777891601895,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16039718217605904964,
+                        14428872421704602280,
+                        2265362357159518543,
+                        175173992188968561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16200769246183089049,
+                        5301470113129033670,
+                        5782779082402413054,
+                        725031552893183902,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3531984614542155345,
+                        345225405672502444,
+                        4256534367274605288,
+                        113430696835942176,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8431184946226427808,
+                        14531258229319175920,
+                        7855670430886528500,
+                        1136824546426329288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17064449589624660505,
+                        2828292894870035524,
+                        5004361708518878852,
+                        1061074234081591076,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18206602904122391115,
+                        581558783644439675,
+                        18370197606070546546,
+                        603461597426783843,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2503807448287875875,
+                        4439669532810361049,
+                        16285160185956805071,
+                        786896223382239636,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12736966845521239559,
+                        6078309418700144680,
+                        9498756447978224179,
+                        866013657697668693,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5812868342136647126,
+                        368940114696497635,
+                        18057004400473073712,
+                        780578064169486890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12911071214486489824,
+                        11949273093369655323,
+                        13784521372380206974,
+                        838206261365037412,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10891871410014195811,
+                        8706602213046578139,
+                        15179310121854333162,
+                        223825420393630050,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10035461830403568437,
+                        5051952416988180616,
+                        8717329452103146172,
+                        286923837996667497,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13609917696950093371,
+                        1037871351664938037,
+                        2607959996922440930,
+                        837002904545394023,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12558840386701207010,
+                        12615016525883575161,
+                        14004519352184360583,
+                        1243888800348623607,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7919197906426113726,
+                        4536857663082673922,
+                        7854473127334555169,
+                        138899371370893220,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12709573631669042839,
+                        8218040996253995150,
+                        13049101847556668193,
+                        73634845229149841,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13556326120652453454,
+                        10874317917372134616,
+                        12888965901905328226,
+                        982301426720985388,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15857945814330343615,
+                        8436357436485397985,
+                        14661554596463067335,
+                        983458901038450000,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6015306531258913558,
+                        14844286712581388224,
+                        2796404950741948724,
+                        632924013228115624,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11883743637217339618,
+                        17776861761029133221,
+                        10327587957650667856,
+                        785025446695754905,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9082104759529406323,
+                        8166987933536549845,
+                        8030748763235489325,
+                        916608942771422292,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11065412998985986678,
+                        12649907425646783282,
+                        390018718069043201,
+                        413911501501516585,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3460499678323375147,
+                        8609848616419904480,
+                        10497901470458438565,
+                        211002777334103753,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7323002873616841726,
+                        13592290243052633325,
+                        15188614226854990691,
+                        998541890493206023,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2588947992026688897,
+                        12737107707622196890,
+                        17230900786922321610,
+                        175185661249015896,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        602832717370882535,
+                        7971255838917870161,
+                        8991078363360630089,
+                        369906605936922039,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12421859167860755736,
+                        7254905595362193221,
+                        1942300726401675801,
+                        788358469235591621,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8438739026673404833,
+                        5117433527634424143,
+                        9941960574218037206,
+                        1313672507772322295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12936130826717981401,
+                        12124954364037067982,
+                        14303377655103328726,
+                        140379180511705726,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2337049575002033773,
+                        11086527240938613879,
+                        2170067424103841974,
+                        1195258020111093499,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6253264146199010241,
+                        6632195012851283939,
+                        7269630452378293467,
+                        7964485418454716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15690441367335868953,
+                        9669322458849937922,
+                        4051870828293095337,
+                        296916092105124878,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16077727854871325418,
+                        12465322508273937749,
+                        15372055943467001093,
+                        56508538552999735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7375749214467603419,
+                        12696106746057509032,
+                        1651753952696173763,
+                        982650765078880124,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9334043951127830096,
+                        4283843936182575731,
+                        2759347847909073821,
+                        488560462349045965,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15941532587587575481,
+                        18337850063476869002,
+                        1645644221746378692,
+                        177535565213217613,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16799383940117011986,
+                        5056341462791660018,
+                        2185020158236107014,
+                        962955178921003974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10969007526843139888,
+                        2862008910118892698,
+                        12510251012244017823,
+                        200064918888354624,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10776439329132525411,
+                        15978558599322783633,
+                        10553011220079433496,
+                        325855349280489290,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18044136670188499421,
+                        15477961882009220457,
+                        6244586057538400802,
+                        719672646593337935,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1579103067966590263,
+                        8654686888855387043,
+                        15777210061352755089,
+                        141361357064701656,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7795896553727123328,
+                        3106284967742171457,
+                        143320441818669785,
+                        1206165526736898478,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2250180875072320371,
+                        13244977837590211961,
+                        5856386409539849233,
+                        1328851730245764263,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3273691363652794496,
+                        8245769561840750604,
+                        6023798652814389059,
+                        948758097650856111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12825188945566495886,
+                        5905688232111270072,
+                        10722875303947703631,
+                        326937016206434600,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6943285901029042640,
+                        4950603374639883492,
+                        2177510313280420062,
+                        1000273805647464302,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11919864552532560747,
+                        15693582403329700642,
+                        10289069924757988778,
+                        980572112831464880,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8052777241681432197,
+                        10869589099390045896,
+                        15427588178223445934,
+                        320906342976081817,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12767922982205838959,
+                        9622913183072601751,
+                        8190423349303046932,
+                        525931684501648628,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8640589670309689890,
+                        10154229871135397331,
+                        3360536314819559521,
+                        258537907599170740,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14437749964627178710,
+                        479510901712039909,
+                        10484063683134659580,
+                        1135023731140766199,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7223258017371323790,
+                        11231281154408748569,
+                        10328364119942701066,
+                        1116738720988947212,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8173744614874367955,
+                        17495352477663352131,
+                        12192107801559915644,
+                        235734613469030234,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        948941114847889912,
+                        16205183109196971713,
+                        1511153471612565024,
+                        74774464802691156,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13978515283349383597,
+                        12411368093898603957,
+                        15137590010205303876,
+                        1258592969223353151,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3543772617250806572,
+                        11102315918156783588,
+                        8152074406223388739,
+                        549913259165990503,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3126471343434876434,
+                        5308769027365760831,
+                        16851796743608172609,
+                        1258974032993149550,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15684559788784494496,
+                        16699664469609798877,
+                        2028782109220107939,
+                        592487696579492392,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5426908477460161381,
+                        18257925553462625549,
+                        3974627805790169601,
+                        76473015382080604,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15123992150727559756,
+                        5992480430769012250,
+                        65668448887547038,
+                        351733281404008204,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10329577455050018364,
+                        14968359103814846568,
+                        3693652775342231892,
+                        798903746361342315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16323427911464788432,
+                        13197719781070985643,
+                        5314110109204577480,
+                        406248611824504322,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4910280053935313979,
+                        3806565843370318406,
+                        6829498062464364646,
+                        909863056826235371,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12531515991129145257,
+                        9728883994410682027,
+                        10609032208234739997,
+                        406942107969748655,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10761717899837019894,
+                        14399958642614744399,
+                        314068592582951737,
+                        522681859076854551,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6807284539046103344,
+                        16569689505430716256,
+                        10925195982569062709,
+                        1023070506615832749,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15132806154357624078,
+                        13929915965464212078,
+                        3018217232107647034,
+                        568809352888721830,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9941548211626243068,
+                        12992619128677087251,
+                        14369908994464001967,
+                        830074907410592073,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17085584534629034839,
+                        10519586133558677953,
+                        6230143800329562520,
+                        417122776549536246,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14544503824197050442,
+                        1620055201228341898,
+                        14826775418733511593,
+                        1165155132480034637,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12934038366768724099,
+                        7838691910460716445,
+                        2069859192284161342,
+                        590187103654087749,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9965216293817086789,
+                        13179955207628349329,
+                        9780983746525923818,
+                        491475330002805627,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16046472119602782991,
+                        9346208319338408201,
+                        1180781892962099479,
+                        1252957590383248985,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11985787382570877567,
+                        18167951342554668557,
+                        5889760132365509153,
+                        334952181698381751,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3017936455590006328,
+                        2928394428618298526,
+                        187419346649349240,
+                        380215338285092210,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11882704483160583127,
+                        17575428377592892242,
+                        13229911143109342608,
+                        473112395546473444,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16415915105464141978,
+                        15293739858055649390,
+                        16456143322552156426,
+                        495069710740252345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3488982234662217505,
+                        15721655050424018525,
+                        959346322664770505,
+                        179524280346656089,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5662967258490137623,
+                        14042465179529522300,
+                        841422947875696678,
+                        8362199445662742,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15144099354822315204,
+                        11306753585095957332,
+                        63683075578299688,
+                        502682132081571515,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14062300196784861243,
+                        14791820170970958528,
+                        11829869294090570117,
+                        714839660219374609,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        268850900121436411,
+                        11897279574183528082,
+                        10788678461359239515,
+                        119343358976630178,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15935684601121486025,
+                        13451558733820431725,
+                        5801665038880622083,
+                        638331825127247304,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7825649966974905720,
+                        9106104092093560568,
+                        18321820344710928934,
+                        242843418730784231,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17155817758016218729,
+                        16151029189630721980,
+                        645136422113626943,
+                        1171155294919816149,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7443113675368662787,
+                        15475353981177630688,
+                        344364731843159089,
+                        900152387105270561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17738245393304814314,
+                        18399787720482176619,
+                        16394484471547671021,
+                        604163827323082001,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17163724476054346633,
+                        2844802278810338040,
+                        15691767881388799309,
+                        853632036284817705,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9583589931356945267,
+                        15860526651910434176,
+                        12280717828128099511,
+                        1249464563213778962,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11928982707911758955,
+                        17790835414599867609,
+                        17338516523772817240,
+                        532135242908614992,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6865656746589740260,
+                        15425402245633392898,
+                        15789735737657902285,
+                        5161226791259227,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2050120297666745097,
+                        10898110455638922599,
+                        7235685889704633451,
+                        904013674387823889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7798374716887228827,
+                        7157363856838789997,
+                        13413246674727539040,
+                        124864652402632771,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3223742734090054332,
+                        17771078349957819770,
+                        16019468542510167648,
+                        360400589164403029,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12468148754839688298,
+                        17611708552839497162,
+                        1153345787677893501,
+                        1192634642175579438,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4842997777981241909,
+                        11368174576927606857,
+                        9603532550042165634,
+                        7497818617682692,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10877990015418966592,
+                        15137261354180839850,
+                        14986255210157778570,
+                        1190979001603705229,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7481250806481550163,
+                        1106387603552153623,
+                        10831601414730126324,
+                        1312909078637127660,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4912323578898233963,
+                        6270013759309695504,
+                        15009235407425544049,
+                        544779428864360479,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15158747801965621705,
+                        8841193406352561183,
+                        8220482474393252158,
+                        1189532150252813567,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12947951606854190954,
+                        13666626695269925118,
+                        14554088300887855210,
+                        453005557301458000,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9122174760839513435,
+                        9733309638630483635,
+                        17536326830317959117,
+                        754775086722083446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9820468184502867317,
+                        10564931984056055237,
+                        8239794618258305461,
+                        585127676540703641,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15136839796450484582,
+                        11085741934954779564,
+                        9091740559844197145,
+                        384333856399295432,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14930975402158199139,
+                        14333772728020581354,
+                        1328137928990391671,
+                        1112125407267195392,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17081614430556443288,
+                        10379849146557453521,
+                        10694478214406870478,
+                        1330116161666829110,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5778857127408388443,
+                        347375990882474276,
+                        7692695741474828535,
+                        963249768412658513,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10310284322327806107,
+                        5696008378951162643,
+                        4202378474801975154,
+                        109717210927138161,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3108629595522094306,
+                        17433017911906897434,
+                        11620798331173751228,
+                        802258243559574464,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14865776534288127445,
+                        17627372517056973242,
+                        7455300866517187572,
+                        395873983248360756,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8341477297930443148,
+                        17936720043039796776,
+                        4995749521585346861,
+                        447606648045280918,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10588787437092387258,
+                        7811853796986239153,
+                        9810942136992056922,
+                        1042824172784534303,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9599905965454187213,
+                        10819993488260283166,
+                        8015828597039315056,
+                        564975801033614475,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1511375362186446254,
+                        14084379265033419913,
+                        7087604848489848921,
+                        237782878285467957,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1588272476331798660,
+                        15799608898568324697,
+                        1103001837191307835,
+                        570632677701884991,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3646671556488848206,
+                        10226057995688008437,
+                        15773619662913344655,
+                        110694367385146338,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11573061971988909764,
+                        10543303330801040975,
+                        14849925053046915364,
+                        397695598493702460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8119288804401794989,
+                        6775182699361312086,
+                        4784979645867281733,
+                        798921764278987034,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6657899544323448836,
+                        16085365293504668853,
+                        7675278464463077251,
+                        326823089336231367,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12887514085010532425,
+                        91976330750349371,
+                        15535483231743949041,
+                        466227177485385874,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6142111917634923958,
+                        6572837414532974592,
+                        9558269606843776307,
+                        558577907973105316,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14197708525560468172,
+                        4475949076297582953,
+                        8660299087789705496,
+                        271801909369910510,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        114697866986703315,
+                        7459486641656597669,
+                        17677889464300249259,
+                        546321307105381898,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9080568624220262025,
+                        945870915018810276,
+                        2519692369661953265,
+                        36549408610591793,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16323238374075889409,
+                        15401957987741810644,
+                        15864762758136666233,
+                        660592159957122656,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17311291759396486255,
+                        3132247452883696603,
+                        6855155566726209126,
+                        751160650118077557,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4652333126106664822,
+                        5786284100960360707,
+                        12081813461995525751,
+                        1155500328190201377,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11728823286000896560,
+                        4947713500837731188,
+                        18144995919995226192,
+                        79543282013896366,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4817523205120118853,
+                        15935531555731671621,
+                        2659502144145366810,
+                        1249077811632996779,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4786557333896332234,
+                        18237761145497912459,
+                        5568233349482691874,
+                        1284865280579040616,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2591559984482966843,
+                        15311391403445209382,
+                        13059910454712936899,
+                        992370914064564868,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9547040560018143165,
+                        11959496099010542359,
+                        16943953198287278276,
+                        188612085253687106,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3496562976101236558,
+                        29614739783631142,
+                        8433401612308963511,
+                        997092689168197448,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10136075500980991127,
+                        9225189329406896287,
+                        4714453617552346602,
+                        757368599890442467,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16175244856782761255,
+                        6996926446572435958,
+                        1519426742930805097,
+                        371864120664403421,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14548826494935557759,
+                        766970816419873976,
+                        3163665324239151473,
+                        902602842714879123,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        380042504750414862,
+                        13461951934883443951,
+                        4736909423855418744,
+                        957882778135019935,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15731797260235335021,
+                        384750000978597264,
+                        13131341576321898089,
+                        348397916646724883,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17768007846426470816,
+                        7084696136111880073,
+                        16777288556741831959,
+                        1200197109729308999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17711896671990570222,
+                        15043792779460846438,
+                        14957413786221691133,
+                        67195248940350518,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13025169185748601903,
+                        8721159689276731984,
+                        3356623689466201709,
+                        370215186911605916,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9122020253539783994,
+                        14558662032594192618,
+                        9345074402394696989,
+                        1066268674973931799,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2725506965424355851,
+                        15138623117947679991,
+                        11201376115373368731,
+                        429791253870825365,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13915446472909375568,
+                        5298044244649194614,
+                        4143123387954719992,
+                        635771309047874345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17397912206457613457,
+                        1622315302502740079,
+                        18385145123167249599,
+                        214592052803015160,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4497679698695744773,
+                        3255565421633326770,
+                        1477318427691621642,
+                        306903713803708749,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7927772930827724707,
+                        10405660405237716602,
+                        2486212221769098016,
+                        885998398395395722,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3875244788546441362,
+                        1196981115378455595,
+                        7897852147307050931,
+                        995565354896192752,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13207850236801675757,
+                        14828455405370201788,
+                        17568018844832567678,
+                        285609694095833212,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7272554307807856325,
+                        6266727981853792893,
+                        3174375242185500634,
+                        1054280946058030373,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        482324731928681136,
+                        15481699387841597314,
+                        2801915138342644525,
+                        1218857059092223093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14523765882638498041,
+                        5130024911030155568,
+                        14848544526881177672,
+                        197340861280612693,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4103966569007949971,
+                        8068011148154938907,
+                        3365717887760252335,
+                        750773402245556362,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1077768828491786336,
+                        7004348733660729466,
+                        852501468956154384,
+                        1298162498745650460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6929943175120223385,
+                        6677966579892186469,
+                        11551927564705784260,
+                        351960168282407577,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5015012067414397610,
+                        17052090592376145028,
+                        17754816112236257338,
+                        1224695627652752747,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16416230091472257494,
+                        13339488613648622759,
+                        822556678560266755,
+                        245096834949896156,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16161140190149850727,
+                        612877748797119700,
+                        15680146054207894044,
+                        97625192199090570,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18308802746953200638,
+                        4058558752977203742,
+                        10012276009292114593,
+                        1216941015684202102,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5896317626614547293,
+                        12495784297118860599,
+                        7523927746223972048,
+                        1028742787329679101,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7196096659819816721,
+                        7956905139603118503,
+                        12176067930277809594,
+                        194416724613674612,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6373698540113812437,
+                        17011931335850877624,
+                        18208198573477391683,
+                        556262924229442410,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4488495211434141435,
+                        10198888956749603672,
+                        12964783648399518154,
+                        653458783790919457,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6817112897474374793,
+                        12083630363687181655,
+                        12872150268242549243,
+                        65363900725835945,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12067356006479378654,
+                        11262210174074697494,
+                        13180900120578352695,
+                        986178844795720814,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16356079691650933515,
+                        536209953876421554,
+                        5517705407678590342,
+                        986787496712118927,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13881544246766742393,
+                        8488273415639461393,
+                        11682352206548627707,
+                        458928012788764009,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15849468683074392389,
+                        11455558696230892987,
+                        10967700578993145697,
+                        421800621911281051,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3022063783591262470,
+                        15569223537497370876,
+                        11877303406486417991,
+                        292435698412546456,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3750430456342293011,
+                        5186148640838542559,
+                        16511029103908466150,
+                        675456427091006539,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14274010408110940789,
+                        6910689940240371892,
+                        8513049291984200052,
+                        251603523681952858,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        769975948540845454,
+                        14633757971050026752,
+                        1912523693095813858,
+                        953433471058211179,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11450361872851074715,
+                        11943746277374953957,
+                        15875438995305175819,
+                        1050014918335265936,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13131400534847159636,
+                        7275295919851125741,
+                        16284788515746796467,
+                        1168539610743849707,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5599024748727932294,
+                        13686874361400429176,
+                        14072846367946849224,
+                        916866258778732051,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3282505632137814957,
+                        15446072129021529299,
+                        9257719237231723757,
+                        703805811550728009,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6842708641989473572,
+                        11850451387452349371,
+                        11642328397294272588,
+                        983287136831411623,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3206202488333521030,
+                        4382690682980478676,
+                        1761372747497012910,
+                        394256031032467091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7483121845878256608,
+                        5968424518657560268,
+                        9535690596041489146,
+                        835347604378591146,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4139243082374271432,
+                        12299855830277521653,
+                        17064461241437783589,
+                        524178266540344706,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        45765280974679094,
+                        14010357715064963900,
+                        10472927309736279756,
+                        999792050193405268,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        78935355501197081,
+                        12494996474495229129,
+                        5008080015395586663,
+                        874254924084217807,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10264138275916859013,
+                        784602200722368743,
+                        4485687562596283426,
+                        1117536526957930261,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10317047762299089880,
+                        11073145055813839311,
+                        16717781896787836494,
+                        677344069590743037,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13423571648089105047,
+                        2360651981680018786,
+                        2606107335303270279,
+                        1165236690280709137,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12042987004768176909,
+                        5689446186652369948,
+                        78622112911526635,
+                        894615299924668161,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12822836718666113662,
+                        17775328414382765731,
+                        15579613922797672246,
+                        929685883962030232,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10792764828271011349,
+                        11964686703518900405,
+                        5829266626478736904,
+                        382671135136770737,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10552875752045794459,
+                        14979866898653775920,
+                        1183647814827252374,
+                        203830369775869750,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13661199603780660566,
+                        3223256459351313760,
+                        4120102275174497806,
+                        1340723589277760364,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3192779932841015252,
+                        8623413873167545808,
+                        10354888174439424237,
+                        948238333075847349,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10541728536622125636,
+                        10419131410765673359,
+                        6677804018286080592,
+                        936166277888578458,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1635033041911407470,
+                        6529361875260307013,
+                        10248315742456647910,
+                        360320787775546907,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8592045936964029208,
+                        10607359011576144326,
+                        10146222707708146956,
+                        724689482754892131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7795628248326844590,
+                        3939903075209654473,
+                        3010696386824037391,
+                        325946734321569933,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6105892518049659503,
+                        11782959704609608441,
+                        3974850838378129935,
+                        160089847217522878,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17629862242817216409,
+                        11229466720581827051,
+                        6667336759270526318,
+                        1232566337369912022,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9656407935545498579,
+                        229589949396570830,
+                        12872947536201562578,
+                        1194602847000030195,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15682334905939815900,
+                        1425823613749360785,
+                        11460459978556067697,
+                        514158177488115990,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4302663123084739116,
+                        16143792223385103623,
+                        17065994485413841776,
+                        1169605186637317492,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10907727752833735948,
+                        3647186607929709753,
+                        3685759673139595130,
+                        602227847831115841,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14354107311346915655,
+                        12790355787772929192,
+                        8199792100176895899,
+                        1109606935491510193,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18012096504996110159,
+                        3588506138081423799,
+                        15325702509811463485,
+                        753045911784863909,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9391515365674410432,
+                        13939229309011757859,
+                        1279938084166459716,
+                        1115267082211262037,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16781292374091446320,
+                        17691586157421600377,
+                        14635155252376090429,
+                        341942683150513884,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6695447137005200688,
+                        15036273414942367995,
+                        5386371010998504809,
+                        167172038309687523,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4736827426474161822,
+                        6264410805587114096,
+                        11456852391121585606,
+                        1259795448131011934,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9832212884393635374,
+                        6345815698689483541,
+                        3701439826596940917,
+                        850538437595643618,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4044427876238156399,
+                        654847972477029101,
+                        3034408113539991596,
+                        143803035018920929,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4704683147606594359,
+                        8586251028805567809,
+                        14623747477406711966,
+                        393420327403286933,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4930761394467347073,
+                        13488064034585704238,
+                        18113571908418770291,
+                        1308544266544666876,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6170641415926625417,
+                        453514232395138849,
+                        3737566539013568049,
+                        309861696921775416,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10803515807339940854,
+                        4192437405117855969,
+                        3219511197913369536,
+                        219466661642145966,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4192895985771758032,
+                        11668573856394427090,
+                        6889692506817062052,
+                        568120982612675817,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        696126262132469380,
+                        10007332989422556899,
+                        1583277663503645476,
+                        945555788093562875,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12434336199699261824,
+                        16510136452445006750,
+                        13782114552213415412,
+                        650990304389588897,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12298309392970198931,
+                        11437171387430900573,
+                        11640783628739199161,
+                        294221707041431791,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1803947467811580590,
+                        7034622174769348395,
+                        9639282275224709520,
+                        1174989618050117455,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9236075799549598514,
+                        3358467920579918481,
+                        8734151169938046160,
+                        1037918042879594320,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12277765125003916907,
+                        16116764062530408557,
+                        7710165280684896342,
+                        721548463765021519,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3970471231823686656,
+                        288661839439771305,
+                        988061872059931030,
+                        904548213395973340,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8703899777582983832,
+                        9119616843193099514,
+                        18375773921469595991,
+                        815586885015670300,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5823255329467918062,
+                        17644378336586355372,
+                        345004942577769075,
+                        124430949182934430,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13164562109925750841,
+                        16370693152596246791,
+                        5641792723028921232,
+                        704492416351043747,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6186438337968314054,
+                        17635576268189859407,
+                        2996087549212223747,
+                        532104630601411299,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6788929760335380922,
+                        1937080771334407383,
+                        17011331909551889669,
+                        1264525370750519729,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14845932076359225501,
+                        17394994716479617301,
+                        9624590451156794358,
+                        523499935857713270,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3958849971273800562,
+                        2146332933603818982,
+                        2980829866238445536,
+                        528441223824425737,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1566822947107499898,
+                        1331226725906077375,
+                        7805406717558447927,
+                        287119106512306446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10751398647653582469,
+                        9228572937896249482,
+                        2864443467625037574,
+                        702843756207416609,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15000045640707075551,
+                        14038076048344916017,
+                        7540300770175759891,
+                        722865073243898018,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9734959741842710549,
+                        5633500825436107812,
+                        7323587140815255771,
+                        550690775733205420,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13879281685124835190,
+                        7190679745385189576,
+                        13776121850726518494,
+                        861315570356277704,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3382980362065225464,
+                        9455708461821764658,
+                        5771453505447475236,
+                        50543938600229362,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15505214318034345097,
+                        14591529292669180600,
+                        4609316563437640407,
+                        68590699579182710,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17675561103671012019,
+                        15150587989256100169,
+                        15429519165337739923,
+                        970148165429152677,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18344175257572770955,
+                        1496191238810463400,
+                        5117343846941911755,
+                        71608035449659333,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16994449659135269610,
+                        12072341213609663003,
+                        8835130234738244752,
+                        1282705988438019242,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1335166659343294331,
+                        1283437610574077200,
+                        6024225860089902644,
+                        1302608070129910132,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        59305984780549541,
+                        665262395344485746,
+                        9650332112472029030,
+                        349711511147904867,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2899607168736071099,
+                        7906958957652792865,
+                        13615822173935076318,
+                        1299368865549518760,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4423891880546515475,
+                        13393141369628337015,
+                        2246881643327403184,
+                        511274585598938097,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18401055242115589539,
+                        4271051725277184561,
+                        622243966852626442,
+                        965864460347440643,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15449626577960391058,
+                        4159288962531768428,
+                        2610516878603606341,
+                        954303101729099405,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        222386438038362079,
+                        5193170903207200641,
+                        15965755956610845300,
+                        513034580921596575,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13730933975089017453,
+                        2448459030499896657,
+                        14068987657344058990,
+                        244803363272999930,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10215508542373131185,
+                        5063604810195446948,
+                        5080713940655694054,
+                        304439447076814805,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7234185308334249671,
+                        8227170881632560282,
+                        5371131747290022110,
+                        1269448137761068083,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14533276249467693835,
+                        1186975265122413927,
+                        973026156799626522,
+                        1253356147884312171,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7965309703533101276,
+                        11187016466581053451,
+                        3937272915508001847,
+                        1258268950320503435,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5618474607632355747,
+                        18433301586181615517,
+                        11945681402038622483,
+                        912387557253526776,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6079584513509547617,
+                        3259914085842718535,
+                        17061976147262651050,
+                        1286630312063354227,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12321745126911985608,
+                        8808761483498187280,
+                        8473254460893894604,
+                        129927951888197855,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17631279858284848708,
+                        10315538324352931014,
+                        12232120369419841348,
+                        112697206168811817,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15380074379201287347,
+                        14338327489866483531,
+                        17837209700346413943,
+                        93569946273850638,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2492733088693251315,
+                        2821943406336592026,
+                        85785599582963674,
+                        381666983301784374,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6928301453410115259,
+                        12585662778694424684,
+                        12116114923621185638,
+                        1087271963011021641,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8607947681284881873,
+                        4163762983443190172,
+                        3304313024738913927,
+                        285026182051289170,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7344582843345329364,
+                        2895499816081490613,
+                        12193124825159752781,
+                        407439513628124855,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8120429657516218515,
+                        11315787604036101620,
+                        14651096290152865432,
+                        546254516170050836,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14321152977976303141,
+                        4033206795867171947,
+                        14147462680676432047,
+                        290940842081540823,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10533856112753664963,
+                        990840932467320676,
+                        7865078289137229191,
+                        388174287752015153,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14115091557915242924,
+                        12397707023022988481,
+                        15831027791296847237,
+                        147213472184451838,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13818809977432234969,
+                        7524710026047975409,
+                        2226106558884190623,
+                        216270583456790080,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        907308266531881474,
+                        16402338662197438922,
+                        7437772807262701578,
+                        140576458956022228,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11928816001125078579,
+                        3034939530726727500,
+                        3381938201350611053,
+                        659267571376381154,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15254050469779747554,
+                        10046912855315834907,
+                        7388370064874939427,
+                        924234933778571085,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8133399189405878977,
+                        11798083165226819643,
+                        13485939334733455035,
+                        61945156578393826,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3984480510996988363,
+                        3298151841579526001,
+                        17873981472044158743,
+                        471589142719530711,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2823149998844487934,
+                        13529119119909928342,
+                        5950064523763717809,
+                        892879836866488862,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4831428094914824445,
+                        6448072009086475298,
+                        16993068670065288215,
+                        1148342547363086898,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15896410082104712874,
+                        9774752342395211015,
+                        3672265636033917153,
+                        581200412230992290,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3311158652303226792,
+                        7447204975278055953,
+                        15903207318727112610,
+                        306636990082351569,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1099966367334734035,
+                        4961077335136157435,
+                        6841901382763528893,
+                        917636973949205147,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10898018398483135312,
+                        6214739097131988288,
+                        7836665951633682235,
+                        1231864318426350633,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8553295432367824139,
+                        17082579616041098260,
+                        6624559471651590174,
+                        810635722893451524,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3677911317388833910,
+                        6180985189011379212,
+                        7107053633124249298,
+                        1288251521927485581,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1677334096851595533,
+                        5894903051778111257,
+                        15515387784892558287,
+                        600254451172681576,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14231820342395403324,
+                        12389206227130043867,
+                        4658539110709304922,
+                        421510775571852504,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3584645886770953816,
+                        6382489456230492655,
+                        13934543655421454495,
+                        182807489665258120,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1431655294576483369,
+                        14349065542441554810,
+                        16433243997832935428,
+                        141571506979256968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17189905552544408246,
+                        9758977388447889022,
+                        10693854288432211578,
+                        1147818764250788385,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9510978400386145079,
+                        16082107217163592062,
+                        11124240734345996318,
+                        967301359888930398,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15010384044284890538,
+                        6241406119867189481,
+                        15399013472707207218,
+                        211902812347569897,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2166296253874755656,
+                        13864301391382763955,
+                        2571781302265384443,
+                        1122501482719023352,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        242198188448791971,
+                        8157158408320074557,
+                        11611028660279926773,
+                        470378629580830256,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        526413309093273588,
+                        11199464900386145682,
+                        12942000805202590257,
+                        223615935625510951,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4115294228559803135,
+                        5779729593038380035,
+                        3486536510757237830,
+                        1071070933875654518,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5628978710047673068,
+                        8357471363096035176,
+                        16471580870886811979,
+                        1233584523985120809,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14449226411630786125,
+                        9872924601224823299,
+                        9388038992345167095,
+                        457935564837612916,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1979982183747886650,
+                        1527936261105809881,
+                        14274471850191498524,
+                        1241691162310835881,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8570923726084031453,
+                        10791139366545740829,
+                        3266083785138255683,
+                        1187558195133381426,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15823862203330945878,
+                        1668299911774974302,
+                        4256653270172032704,
+                        400124145194302975,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17463087025836387104,
+                        6071849107894494371,
+                        9421855038916944644,
+                        1146450621355793806,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6416153304558601618,
+                        3114929430545027132,
+                        5944670155024821417,
+                        477037262772059849,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        377107689291999126,
+                        15124462179330256247,
+                        6504264711289537148,
+                        1050129218958109635,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9438893363209892201,
+                        14324382316722420111,
+                        469638064958969942,
+                        1319952336963019430,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10878357678705533893,
+                        11430462347112395916,
+                        5243499982188795944,
+                        15493367388890988,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13169894389953333725,
+                        7437965887727916915,
+                        3354537759326469765,
+                        488470386686839828,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1929246617070098778,
+                        15159277762746106841,
+                        3424927179753956732,
+                        774269719188622086,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16559018629526333776,
+                        14429277787304352462,
+                        1408957722235782658,
+                        806330181715593,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11799326176288549759,
+                        13032772742972586699,
+                        13021158367198704214,
+                        503320473934377766,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        400214197284755267,
+                        4649048001865409945,
+                        17114991273961361909,
+                        1215263742568723279,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11262105810222884168,
+                        13445356079585002958,
+                        17493133933999408974,
+                        1299812060180439471,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7440105703527637193,
+                        11768319673174321033,
+                        1491638995901160256,
+                        609126677712617584,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        460807471306438309,
+                        2649248800030747586,
+                        15632635139688535774,
+                        251939009514768667,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8877830085121074081,
+                        12804404801697709036,
+                        7566294651626368924,
+                        253198231598465725,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3547505019445509798,
+                        1597461837808683733,
+                        7519506937041519417,
+                        46999960276119645,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18406654990911319548,
+                        4818232493482954677,
+                        10544259037490247601,
+                        128661566367707595,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12974419085184753926,
+                        5326378608317914812,
+                        2216403222266416425,
+                        339330995646233144,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16948846565874111693,
+                        3375215865061535515,
+                        13902230141056784571,
+                        471826766979072914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1725735230679726495,
+                        4190842756028072238,
+                        13930675339564191263,
+                        908058172619161811,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3247912805433080317,
+                        6244915383072511604,
+                        5368240299650009146,
+                        1064211756868806910,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7063597558267462694,
+                        17776543728164663813,
+                        329489588472921144,
+                        940571240179334959,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3472570015025509614,
+                        17118986617278705140,
+                        13771807911445547777,
+                        872134282501823414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2273879543176014416,
+                        1871094863420839401,
+                        130752677250869170,
+                        1316108933053621090,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        997073102525834772,
+                        13251426614682791536,
+                        13990866665608913134,
+                        868940072090256222,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6283512842321759223,
+                        5988957314193627815,
+                        12422952828365359271,
+                        693121643920953022,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11633187665222679982,
+                        12584882357257870661,
+                        11862463091543812503,
+                        314605559046915357,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12800751002983402461,
+                        2006069148729354355,
+                        12624510679149900701,
+                        1343724917119752608,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13514068615676471341,
+                        5176679346599146484,
+                        3070619646827838434,
+                        254295955977933740,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3370613283121333514,
+                        12664219052533524914,
+                        13799938082950336593,
+                        878878314488144056,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4440642996987295697,
+                        14134002220279509310,
+                        9412787064269745578,
+                        235255190486609760,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13875879612576193287,
+                        6802692633395786216,
+                        5053578477303078255,
+                        655032714203084870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13086163922085327018,
+                        14411477589152784984,
+                        18385465146198606094,
+                        95902831552561921,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5814616623205050845,
+                        15992297151995205445,
+                        12510714018721300282,
+                        290262623807742555,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5778147170209413046,
+                        6203139907831866796,
+                        10328987057640228325,
+                        107246531923365667,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        974891942013645847,
+                        16177230306896820452,
+                        4462231868584747859,
+                        395195617799706590,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1579225084079892070,
+                        9222540577598515172,
+                        17992697286826246421,
+                        1151037316814642072,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1818974653229118130,
+                        15750015254675416195,
+                        16531859324189843359,
+                        861977231530363570,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2413853937452558923,
+                        16608265156907633675,
+                        6510173798313924447,
+                        1345084972420551427,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10887157731141392955,
+                        17209257556354366638,
+                        12277363074316321669,
+                        994521531375324059,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11618876788443748451,
+                        4767948563990588268,
+                        271312057584853274,
+                        1221866235214975074,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14237017708991274372,
+                        12486854625796804903,
+                        3404292579863741164,
+                        1005033573660438154,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15025645036184881718,
+                        6914248647275767081,
+                        7379766150589137802,
+                        537723320954399231,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14245870099357093091,
+                        13692846910941558542,
+                        7262327127265329696,
+                        51230258413053700,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6262985152151260827,
+                        6587857109223036915,
+                        6763511389828497783,
+                        958645643306620133,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13072925970279985491,
+                        5200479464258294694,
+                        11621301362877826114,
+                        21845727723695332,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15475433725060632347,
+                        1833081903968275616,
+                        11327603499711263867,
+                        1046821748564026534,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9751783896311459005,
+                        12317519662525450565,
+                        9703970054131212616,
+                        690488149553753561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17013269436759440418,
+                        837825562694604407,
+                        6414659653051931328,
+                        781573629943112707,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10172545597578197127,
+                        17059601914626947856,
+                        6081607219785972615,
+                        478036442257637793,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7166503402492785951,
+                        6479289713335684817,
+                        2323673089421699033,
+                        1332471285800526602,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16096480152482202189,
+                        4465852697623896026,
+                        8276213231315825287,
+                        280134926431263275,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4570444763202538428,
+                        1000099855273448274,
+                        17295971884805868764,
+                        244198764809453225,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15263857146994357867,
+                        5832057910276317350,
+                        9374088905366572517,
+                        162315071943524841,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9789878526306322603,
+                        10589864333568106148,
+                        16279261095282128430,
+                        365767253632897290,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        635919715767814213,
+                        2819925059600267529,
+                        2895575397158723356,
+                        825694695848014049,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4901590158309778272,
+                        15726250177606627650,
+                        14971682639251579609,
+                        199501519212649339,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15159684353928502147,
+                        12346778079469881612,
+                        411787388278428388,
+                        443189651696675391,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11983450504440905669,
+                        16313111605695230555,
+                        9100546765257922819,
+                        863606043227924883,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5801814497304932150,
+                        10101385726068221944,
+                        15083172699089618515,
+                        79933690841159964,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9864274277311223578,
+                        2013433617749669523,
+                        15680840746795967786,
+                        768046867064169175,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5275982040827196750,
+                        10536197943887423765,
+                        14788286796869581511,
+                        65259951667501226,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15248958773403954511,
+                        4593562858348966321,
+                        16655688069498019416,
+                        1134966007680827619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2512033637233523331,
+                        14066425926471477345,
+                        6662232388289133650,
+                        1182686522479441567,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10988307932354163332,
+                        9106922768029645328,
+                        10490125152892194379,
+                        1233329169309342499,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5938768510232977135,
+                        8962944232099927897,
+                        7220636836740897169,
+                        468763986813809447,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12586503817820862977,
+                        10923639549363557658,
+                        8170597479765042992,
+                        777025842703129323,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2733405468170435755,
+                        1914819639042647333,
+                        9656293833993289447,
+                        356382038010718868,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14934756996051074601,
+                        4732052447905228138,
+                        14467242098383413921,
+                        1153887601780592745,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8171204479642285662,
+                        6572917993207560027,
+                        16201073763588964591,
+                        744921346454665849,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        110006895166711147,
+                        9630859595323661622,
+                        4192193728382150721,
+                        476608509446105244,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16791223926264584556,
+                        15453329149531235660,
+                        15527103169665821754,
+                        1272329281311003034,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        769003885351222714,
+                        15892256681470202089,
+                        10661858562422946723,
+                        456399221747575215,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14716432306885843508,
+                        13550503191398769675,
+                        14711560861373031328,
+                        1268425318428924469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14432813794400069277,
+                        8584710498132293223,
+                        13556336379928684598,
+                        303988312980986656,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17727775491634644099,
+                        14092313788480657167,
+                        608386672485526258,
+                        708727438559086087,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7456219501344600099,
+                        14653582428606416438,
+                        16193547571346082616,
+                        402309538831783698,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13164250328449201637,
+                        5946898215924520568,
+                        10709336126676265203,
+                        463301232005962100,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17697164667151055614,
+                        7010873147332003576,
+                        11601106016704603218,
+                        1111883986655202474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        321583906690977523,
+                        15932936679221663839,
+                        4741711561631743569,
+                        867910389895728042,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7411118395445185815,
+                        14735386880171029237,
+                        13429913831779977011,
+                        350011032771893235,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9171250520560950245,
+                        158787372016832147,
+                        3503912194560145656,
+                        34520828298767357,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15014555013774022743,
+                        10568899924747009550,
+                        12811509210087640533,
+                        522281110528928889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8860354490097211387,
+                        11131680766087239185,
+                        7728305580563194478,
+                        1084336660568402059,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6836235385737943016,
+                        1427763199030011866,
+                        4416795324803718442,
+                        742264644783715145,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12961504717112787132,
+                        13676856846812997014,
+                        17491602563368692624,
+                        936042935678568825,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2800813984690044675,
+                        11379140722505829960,
+                        5326300881749403558,
+                        1130127937152915380,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4752729155187300862,
+                        9916876849236381986,
+                        17017888810233975889,
+                        822931624837516837,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15741819793799993888,
+                        3899985948881636810,
+                        9312669191999901039,
+                        1000666750050192363,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10797844605069391311,
+                        18056257162869319050,
+                        13564014961326096895,
+                        1038851785591551715,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        44319299628155255,
+                        12480032217565899302,
+                        8311442912416888474,
+                        479613855215165272,
+                    ]),
+                ]),
+                Matrix::<13, 1, 13>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3385004621088858447,
+                        11992633864358970054,
+                        17413279541606556477,
+                        1264486801605328309,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6291713155324627370,
+                        15771222972518015535,
+                        526784334061756376,
+                        1310655851063419237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16731444718675999234,
+                        16706543885532359328,
+                        12035686946676956722,
+                        726926790072397861,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11336972262138414147,
+                        8277851750659031002,
+                        8516026759377705730,
+                        1024765376378395152,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17928337491363024532,
+                        13958108201539874260,
+                        8883278827828122792,
+                        1239869240731115320,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        916033039769444245,
+                        4985136855245612939,
+                        8317425486457661864,
+                        384941634784944855,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2665790293200716342,
+                        8267787600257390364,
+                        17297718573951343053,
+                        1075640424531785164,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6205231335736119916,
+                        344871325152926177,
+                        7631449223932965467,
+                        228008825126026482,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16989439007368390602,
+                        35658380631345534,
+                        9643061461590040004,
+                        376074815578822663,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15595794967295621538,
+                        14928680362120498504,
+                        702555961302689330,
+                        471324806014482345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13536010724707202764,
+                        577760469897740070,
+                        96377816980807591,
+                        1090832639728098646,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6623409708163315762,
+                        3758628156793724346,
+                        13760749701963121409,
+                        1283682755128048632,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14694078971950041164,
+                        13448812909558549075,
+                        5233986109352133542,
+                        791904876086432554,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<39, 14, 546>::new_from_known([
+            Fq::from_montgomery_limbs([
+                10246281588401305552,
+                13261394688604202531,
+                17586153264070975322,
+                1081009887269668527,
+            ]),
+            Fq::from_montgomery_limbs([
+                2651947439136580097,
+                3099573733413270690,
+                16405817597898257615,
+                1157382479045953938,
+            ]),
+            Fq::from_montgomery_limbs([
+                4686281599552105625,
+                517911903218502860,
+                17960798645929375362,
+                193814639833123314,
+            ]),
+            Fq::from_montgomery_limbs([
+                11367411935373351609,
+                2203766394725007238,
+                14449518025887102565,
+                878982557539094332,
+            ]),
+            Fq::from_montgomery_limbs([
+                11037335403495786410,
+                771400029560249798,
+                14536988634627395914,
+                794869868257777564,
+            ]),
+            Fq::from_montgomery_limbs([
+                11543544777097901177,
+                18167929097656764143,
+                18015148900227014723,
+                201602314600037198,
+            ]),
+            Fq::from_montgomery_limbs([
+                7629117166608910760,
+                3307599371846056999,
+                3800041378487869465,
+                78088898770097535,
+            ]),
+            Fq::from_montgomery_limbs([
+                15680955765176036455,
+                9952953835648447833,
+                17605719541968295614,
+                555928056077801350,
+            ]),
+            Fq::from_montgomery_limbs([
+                6444229998496857791,
+                1301848375725259209,
+                5689591707460213892,
+                858513124525352653,
+            ]),
+            Fq::from_montgomery_limbs([
+                6264445246203279361,
+                9224520754895195660,
+                7891303155509788676,
+                939359100380854584,
+            ]),
+            Fq::from_montgomery_limbs([
+                16320368098214281130,
+                15603879887976027252,
+                5879549832010431034,
+                192645677156178832,
+            ]),
+            Fq::from_montgomery_limbs([
+                16576462470231521128,
+                5204444136901326196,
+                6401809141642313956,
+                327474302444593384,
+            ]),
+            Fq::from_montgomery_limbs([
+                15504040003494180802,
+                3792784981648050252,
+                14328776330169592080,
+                1120696503293614598,
+            ]),
+            Fq::from_montgomery_limbs([
+                4252743852007907861,
+                2287035070223848840,
+                7734527217735201411,
+                440307099015507328,
+            ]),
+            Fq::from_montgomery_limbs([
+                941718340149254478,
+                1147663524190417649,
+                7998526298041288323,
+                708229596795294300,
+            ]),
+            Fq::from_montgomery_limbs([
+                17176908991775989835,
+                10320620671166954469,
+                4698435250872848190,
+                807244189135548116,
+            ]),
+            Fq::from_montgomery_limbs([
+                8549678509197179708,
+                14655569499909677190,
+                18393966263471207658,
+                873609379321934113,
+            ]),
+            Fq::from_montgomery_limbs([
+                18085090979736497342,
+                1173577025045792301,
+                11168299505713325806,
+                487116396348158083,
+            ]),
+            Fq::from_montgomery_limbs([
+                18039909479683557813,
+                13541565866999046889,
+                16028983461029022538,
+                720729773623483204,
+            ]),
+            Fq::from_montgomery_limbs([
+                1746551752795599625,
+                3997703680282796750,
+                10895930759897669213,
+                986806436062928752,
+            ]),
+            Fq::from_montgomery_limbs([
+                1756124948482675216,
+                14838939743408501232,
+                16721685272579090226,
+                992732003314252650,
+            ]),
+            Fq::from_montgomery_limbs([
+                15802519489038001917,
+                15091089501488088485,
+                15849929320828340824,
+                385011017208096745,
+            ]),
+            Fq::from_montgomery_limbs([
+                6254080459714848517,
+                11970645016206512569,
+                14770710413896302299,
+                23920374055452755,
+            ]),
+            Fq::from_montgomery_limbs([
+                2701407309904682376,
+                8791787821049472328,
+                325888287634688006,
+                347281340126524539,
+            ]),
+            Fq::from_montgomery_limbs([
+                17953958619963886899,
+                39273889736532982,
+                14820244450025834865,
+                963822312289879395,
+            ]),
+            Fq::from_montgomery_limbs([
+                10426185438367552947,
+                4936738267948705873,
+                5128359080824640132,
+                712554281816274465,
+            ]),
+            Fq::from_montgomery_limbs([
+                9728470006005565475,
+                8310411506376039252,
+                9199536633065780529,
+                153718450309680972,
+            ]),
+            Fq::from_montgomery_limbs([
+                13092719068500373323,
+                1569682994465501011,
+                729875507780416674,
+                1111466574919556982,
+            ]),
+            Fq::from_montgomery_limbs([
+                9466624337256429741,
+                10388667090221056580,
+                3225057637610976515,
+                870365624448780361,
+            ]),
+            Fq::from_montgomery_limbs([
+                12292892456060020187,
+                4784600528174196184,
+                1655831770428196977,
+                305963091866910341,
+            ]),
+            Fq::from_montgomery_limbs([
+                6360390117649906900,
+                821614179153238271,
+                15567964531926044769,
+                980383133864355523,
+            ]),
+            Fq::from_montgomery_limbs([
+                61801464952438671,
+                11985157620921304065,
+                3095985060335493205,
+                500963464980039172,
+            ]),
+            Fq::from_montgomery_limbs([
+                8265203002722997361,
+                10703766189197049979,
+                15195980868964942545,
+                1257460829993708878,
+            ]),
+            Fq::from_montgomery_limbs([
+                8744765142636715040,
+                8778794334053278971,
+                480396155376023009,
+                818728133486715928,
+            ]),
+            Fq::from_montgomery_limbs([
+                3591203120127610942,
+                7191982636743092289,
+                7562260309377577549,
+                179677521895582525,
+            ]),
+            Fq::from_montgomery_limbs([
+                9373689679042313043,
+                37490832751280801,
+                926738537394161182,
+                916936690330678828,
+            ]),
+            Fq::from_montgomery_limbs([
+                4570644689015563442,
+                3253502931028402398,
+                13607556491443301671,
+                380803016238063985,
+            ]),
+            Fq::from_montgomery_limbs([
+                15201028607890905811,
+                6186198066479374374,
+                9113776172267789050,
+                468517007928355750,
+            ]),
+            Fq::from_montgomery_limbs([
+                17881217819097278652,
+                4857240666020718707,
+                6217212986152181426,
+                720315507182846422,
+            ]),
+            Fq::from_montgomery_limbs([
+                9468761334950943357,
+                6515414782971689366,
+                13158917906521745664,
+                736424580771204048,
+            ]),
+            Fq::from_montgomery_limbs([
+                2167269384850536964,
+                15476276117683807028,
+                14252477756137622709,
+                489136691974157654,
+            ]),
+            Fq::from_montgomery_limbs([
+                1807133779171525980,
+                9596228155984958586,
+                17235551389247473298,
+                520236028973605326,
+            ]),
+            Fq::from_montgomery_limbs([
+                7519323722977666369,
+                13114635293649830960,
+                8757072864484773168,
+                204797307779751377,
+            ]),
+            Fq::from_montgomery_limbs([
+                18383848020865923893,
+                3265212579990343319,
+                17982046298585752031,
+                1320790295316798981,
+            ]),
+            Fq::from_montgomery_limbs([
+                9603650883709373050,
+                4507782419486819240,
+                10398244896574484832,
+                1212179972937275255,
+            ]),
+            Fq::from_montgomery_limbs([
+                12252000898787902230,
+                446228064437003417,
+                14100535926236563229,
+                920463611472176489,
+            ]),
+            Fq::from_montgomery_limbs([
+                898543768706581296,
+                3536300577058220206,
+                7701367876876410278,
+                418459717290737708,
+            ]),
+            Fq::from_montgomery_limbs([
+                13297809171458885986,
+                6533109385967117320,
+                2733206036706878896,
+                332485338971883776,
+            ]),
+            Fq::from_montgomery_limbs([
+                7640384498582447664,
+                15230969592054289663,
+                7404588395403537152,
+                77712015802175205,
+            ]),
+            Fq::from_montgomery_limbs([
+                18159939492357630985,
+                9534104341405191716,
+                14282435173268491646,
+                820392860838085771,
+            ]),
+            Fq::from_montgomery_limbs([
+                14261207220478573107,
+                811080570541188517,
+                5713835925787496879,
+                543361723356803866,
+            ]),
+            Fq::from_montgomery_limbs([
+                13368290019798535558,
+                9961042679479597691,
+                5024692740755046565,
+                191543480222526790,
+            ]),
+            Fq::from_montgomery_limbs([
+                14596866734738571506,
+                15598536019258731984,
+                12179940523445183940,
+                422425936254409801,
+            ]),
+            Fq::from_montgomery_limbs([
+                15516609564237658287,
+                1664601750534451129,
+                3993130630646364915,
+                377462609036885134,
+            ]),
+            Fq::from_montgomery_limbs([
+                17825633862646935935,
+                9272665176990575372,
+                8040776082753605078,
+                438034340766081171,
+            ]),
+            Fq::from_montgomery_limbs([
+                2586947954673144991,
+                9508074439862270462,
+                11828523209891937485,
+                173340468147482329,
+            ]),
+            Fq::from_montgomery_limbs([
+                7720686851077772440,
+                16240187679988880738,
+                7560670502924630322,
+                583992280629587559,
+            ]),
+            Fq::from_montgomery_limbs([
+                9834668721653715478,
+                7991909583059011880,
+                5199513680684790336,
+                1236639832020937809,
+            ]),
+            Fq::from_montgomery_limbs([
+                11035756771879769813,
+                8532541966162860393,
+                10757807783543824718,
+                1111920556475509454,
+            ]),
+            Fq::from_montgomery_limbs([
+                3630189479170257632,
+                4761274939644890854,
+                12969244333379844392,
+                878396816203665635,
+            ]),
+            Fq::from_montgomery_limbs([
+                18269978887482322972,
+                12633481541539599289,
+                7152106836443732885,
+                1033194111699214412,
+            ]),
+            Fq::from_montgomery_limbs([
+                15280438945334346649,
+                5114333389620465787,
+                14433425281259543761,
+                1313300996109638925,
+            ]),
+            Fq::from_montgomery_limbs([
+                7264552425745318068,
+                9497661228827953145,
+                10600595971067375516,
+                414750329652589536,
+            ]),
+            Fq::from_montgomery_limbs([
+                5943351734947641174,
+                5744635512964773580,
+                8292750885973985830,
+                1237454591765273914,
+            ]),
+            Fq::from_montgomery_limbs([
+                7417354451235786465,
+                4377483703642615712,
+                5763752239693637816,
+                1149825727434090488,
+            ]),
+            Fq::from_montgomery_limbs([
+                189411730896773719,
+                1655249582512030147,
+                17792224471087960025,
+                390355152797550268,
+            ]),
+            Fq::from_montgomery_limbs([
+                15573358377057065785,
+                5947180913540380373,
+                10220578504473615135,
+                131564584736926860,
+            ]),
+            Fq::from_montgomery_limbs([
+                9914667751716309774,
+                12426796367402390490,
+                15971235498344247588,
+                530874117447738311,
+            ]),
+            Fq::from_montgomery_limbs([
+                3266108141461118281,
+                17408830641561572690,
+                4405222565117264839,
+                817920478375398635,
+            ]),
+            Fq::from_montgomery_limbs([
+                11982021439305718302,
+                17614925305902299281,
+                8105849883739368633,
+                227580559937751689,
+            ]),
+            Fq::from_montgomery_limbs([
+                3753244109940548368,
+                1359452173174789928,
+                10312288031095644047,
+                380252631796122096,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17908856677366425418,
+                16152519112854927155,
+                10919457932590369437,
+                1123577635122022210,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1922575738732189107,
+                12727535011818404025,
+                13007572636458662307,
+                532686069930268746,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10998053578257540575,
+                17728523945038557040,
+                57591583074676175,
+                1234615427676106612,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13816222204350770208,
+                148502677422299457,
+                3935478536425985065,
+                11715481026958905,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5053590146942954678,
+                1889783984685467207,
+                9862834533355284138,
+                13073837233845356,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17222596694654599582,
+                13250926111524984353,
+                7212124890677095024,
+                128624336650635826,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12559586809453677131,
+                12507891807124507101,
+                1443131119956284758,
+                880945291620971735,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6898119163005438748,
+                13763513403018990558,
+                9849987128854358735,
+                109848768436244836,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13642060956160433287,
+                15635590247373700751,
+                3951037598811911451,
+                1192596088689405068,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15688813326074089055,
+                146343155376084088,
+                7673995188332213394,
+                667049691753890087,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9107511387976162130,
+                14074947529639523347,
+                122586785663063392,
+                501249502538655228,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1000561373449163489,
+                4157738002902712071,
+                7525846587811799866,
+                1210609097700603250,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16809866160848993547,
+                13420713923543845782,
+                11086786088500356744,
+                1170509505886050687,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11052425529063992348,
+                7216674620256760876,
+                15855987405688009735,
+                1268003087291473610,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3798163946475255788,
+                3927629331212709929,
+                6461632766497453705,
+                841097765375224282,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17614532020830395813,
+                4265668095642483905,
+                2691039929292215445,
+                1065279670999012562,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11219704712734358474,
+                16203412589724368971,
+                395102220288166655,
+                1167322097177905000,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17447172317905238136,
+                10475573294239319003,
+                9921801700298598307,
+                484258590761379964,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12404859702873306710,
+                1748551592993112653,
+                2019651044646701203,
+                231186127626579845,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5799079994595895752,
+                7014420147525051795,
+                5608040805948425595,
+                670981593271881114,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6523018733957365770,
+                2726708757946116551,
+                11485628531522399328,
+                384168831183389403,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1928661773223959563,
+                10885581806701546939,
+                6380593849649518736,
+                1018967871510049580,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12578625425269619205,
+                11973478717027984957,
+                14392304751802178129,
+                216572346324276154,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2290001376621552513,
+                7637074989371596101,
+                4235025509942424682,
+                52125231704548955,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2572011466394786902,
+                14508087442349587106,
+                6822990675441595527,
+                98858366660155117,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5413770343095117336,
+                17209681637224520879,
+                17593164062771967109,
+                1187971226012718721,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7903434326934039561,
+                5156213635057451917,
+                9113731638766064232,
+                540713900150055885,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7233275166986150554,
+                16987547246530073131,
+                3988397037367238364,
+                770225002232917288,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7613040092151803154,
+                4720651035487592747,
+                14071757850805470768,
+                1225041617308847548,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13325839854980833489,
+                7008909107583921936,
+                10840835137777992143,
+                1224480656521590808,
+            ]),
+            Fq::from_montgomery_limbs([
+                6583430563251747868,
+                17474344670280231398,
+                9942835450663565687,
+                362285910377628792,
+            ]),
+            Fq::from_montgomery_limbs([
+                17693668625508214322,
+                15578950702654056291,
+                11720500300464986845,
+                888287680711279017,
+            ]),
+            Fq::from_montgomery_limbs([
+                13633756099285019616,
+                2639254643084558228,
+                3316733787704168169,
+                98252009794785276,
+            ]),
+            Fq::from_montgomery_limbs([
+                15438347715313813676,
+                14204578308772480392,
+                2405954628914080747,
+                593383184267230648,
+            ]),
+            Fq::from_montgomery_limbs([
+                14390939472872414995,
+                6723599994053949636,
+                7719068117079455090,
+                569411217023320325,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441111968622351938,
+                3306758772824150647,
+                14155216287878497331,
+                335743432529757710,
+            ]),
+            Fq::from_montgomery_limbs([
+                16353078132542315513,
+                16661344352167922816,
+                6814653935903964634,
+                684853553251634726,
+            ]),
+            Fq::from_montgomery_limbs([
+                7058388037654535394,
+                10218588620156638801,
+                4390162122604560050,
+                240436233025147900,
+            ]),
+            Fq::from_montgomery_limbs([
+                9770894966771722990,
+                13148190807128975340,
+                18359564855051885212,
+                77028156380655248,
+            ]),
+            Fq::from_montgomery_limbs([
+                12428738226968571343,
+                7483127523846549569,
+                2350828867512223683,
+                530431416818812045,
+            ]),
+            Fq::from_montgomery_limbs([
+                16082684939151747725,
+                18186448787137315203,
+                13101282262565109770,
+                571299151310900231,
+            ]),
+            Fq::from_montgomery_limbs([
+                15025097252526737186,
+                267522472518637123,
+                865902149954290605,
+                308128082144277312,
+            ]),
+            Fq::from_montgomery_limbs([
+                7286289087511392001,
+                8042607287137306117,
+                6696256449188059663,
+                323019173624071977,
+            ]),
+            Fq::from_montgomery_limbs([
+                362189797960808848,
+                1768677699372549990,
+                14365509670582212574,
+                778750678657633503,
+            ]),
+            Fq::from_montgomery_limbs([
+                1648612042896348518,
+                6069052209739706650,
+                4578251149647972531,
+                51999794111772272,
+            ]),
+            Fq::from_montgomery_limbs([
+                7038705965443879055,
+                18033025617790987631,
+                3926743875613798775,
+                210292702295299913,
+            ]),
+            Fq::from_montgomery_limbs([
+                18088956673641739422,
+                11240454085805512728,
+                811340669826973601,
+                1073368656994547672,
+            ]),
+            Fq::from_montgomery_limbs([
+                2329845114941179010,
+                5455197097243920354,
+                15501006592869012925,
+                149822331412178416,
+            ]),
+            Fq::from_montgomery_limbs([
+                2046409455430636442,
+                5798058422476380167,
+                13641655309385248651,
+                605904760107919263,
+            ]),
+            Fq::from_montgomery_limbs([
+                2765745068841391649,
+                15754460350748458290,
+                1067895905991450216,
+                1108816934221606254,
+            ]),
+            Fq::from_montgomery_limbs([
+                12236988021340026815,
+                6605981147474776646,
+                12159951035388484029,
+                989507464463207175,
+            ]),
+            Fq::from_montgomery_limbs([
+                2565136022898539176,
+                5898967318553345302,
+                10519970360719057258,
+                1335627164555087980,
+            ]),
+            Fq::from_montgomery_limbs([
+                1899170277317909619,
+                3903606086852139539,
+                1569715214596441606,
+                537938927164632834,
+            ]),
+            Fq::from_montgomery_limbs([
+                14377004036228463100,
+                13884531393256135037,
+                16785967592517056771,
+                971130941245907844,
+            ]),
+            Fq::from_montgomery_limbs([
+                16517595505747876031,
+                10695428620982982643,
+                17902977011753176753,
+                414080157193217342,
+            ]),
+            Fq::from_montgomery_limbs([
+                12861454224398249306,
+                4427212108516523896,
+                9680494455747704731,
+                882854219936053581,
+            ]),
+            Fq::from_montgomery_limbs([
+                18421831060106896303,
+                4591588344291539813,
+                8596768247083940908,
+                100463334932668435,
+            ]),
+            Fq::from_montgomery_limbs([
+                6884303217121207496,
+                6643321269552783060,
+                15447610599378039068,
+                1075407988534691950,
+            ]),
+            Fq::from_montgomery_limbs([
+                12691953431650041322,
+                3070555314035558976,
+                12620768937331401632,
+                501854500456079711,
+            ]),
+            Fq::from_montgomery_limbs([
+                16635981235271059864,
+                4382809221985020886,
+                14585572596436192059,
+                366727186109234398,
+            ]),
+            Fq::from_montgomery_limbs([
+                8044854798318201439,
+                1942027497327862734,
+                1114554029773193662,
+                833422882209976129,
+            ]),
+            Fq::from_montgomery_limbs([
+                10803896681457076385,
+                4965726744911587041,
+                7835914204394462906,
+                261102170344394955,
+            ]),
+            Fq::from_montgomery_limbs([
+                8646493149162605255,
+                8599718904823501503,
+                2078357101832997089,
+                867549592724743171,
+            ]),
+            Fq::from_montgomery_limbs([
+                13338063478434626603,
+                8592750320643548305,
+                2381082446924771343,
+                421568457458901953,
+            ]),
+            Fq::from_montgomery_limbs([
+                8007725389287649244,
+                1933006982481958215,
+                17082328733919670031,
+                274272879454078543,
+            ]),
+            Fq::from_montgomery_limbs([
+                8974406735032645958,
+                14463203048092788690,
+                5586214615650386808,
+                1112186704964753558,
+            ]),
+            Fq::from_montgomery_limbs([
+                17590993187194015370,
+                1941954509452557752,
+                5578471546267898749,
+                320612954920932640,
+            ]),
+            Fq::from_montgomery_limbs([
+                16113086414630441769,
+                6951996000615760544,
+                4134835195487724156,
+                983871474164576061,
+            ]),
+            Fq::from_montgomery_limbs([
+                18072599409155259911,
+                522686414497854748,
+                14888185274318166577,
+                686170048690167505,
+            ]),
+            Fq::from_montgomery_limbs([
+                18117523416664913421,
+                2091354521292077134,
+                16770142514374276294,
+                444938951735603945,
+            ]),
+            Fq::from_montgomery_limbs([
+                6669012711501435001,
+                14680978138326831381,
+                17692227144773468875,
+                571790772755351479,
+            ]),
+            Fq::from_montgomery_limbs([
+                551649610550401944,
+                13064474688739741976,
+                13744403183929279538,
+                409774136299625809,
+            ]),
+            Fq::from_montgomery_limbs([
+                12603432824596095242,
+                2896371567848062279,
+                3747749936406648789,
+                1157533236661903265,
+            ]),
+            Fq::from_montgomery_limbs([
+                7875446363122710755,
+                15813975292686464565,
+                4047929278227204931,
+                185250085632988976,
+            ]),
+            Fq::from_montgomery_limbs([
+                6963270672833118126,
+                889262136681532921,
+                4735806924791593867,
+                134989135766740515,
+            ]),
+            Fq::from_montgomery_limbs([
+                14276605466786675879,
+                4299997600399188402,
+                15065649890208689864,
+                720475593695216403,
+            ]),
+            Fq::from_montgomery_limbs([
+                16423585900078135031,
+                9685760567779794069,
+                12286538129011698454,
+                1071661762698928445,
+            ]),
+            Fq::from_montgomery_limbs([
+                13934506748135487365,
+                14831476571116488253,
+                2230237695211091541,
+                443794757403525790,
+            ]),
+            Fq::from_montgomery_limbs([
+                15742515544674722696,
+                3819991402408037078,
+                4001568198763353664,
+                338791585255934212,
+            ]),
+            Fq::from_montgomery_limbs([
+                576088269219064898,
+                17122485426894884517,
+                6555368543729518575,
+                428871848374365290,
+            ]),
+            Fq::from_montgomery_limbs([
+                134513238053566374,
+                879144446341590733,
+                16020692186306163498,
+                133354295590421929,
+            ]),
+            Fq::from_montgomery_limbs([
+                13688474863119279882,
+                17615687008249095050,
+                1327917845947713179,
+                171570537941364694,
+            ]),
+            Fq::from_montgomery_limbs([
+                3834244769757578956,
+                17483002540086802602,
+                3180077761419275783,
+                366823511150355773,
+            ]),
+            Fq::from_montgomery_limbs([
+                16901482421875478975,
+                5849544982390815353,
+                15136147457215954829,
+                23300089029330332,
+            ]),
+            Fq::from_montgomery_limbs([
+                13888881305744423945,
+                17867242798477498818,
+                4745485017764378216,
+                955568148536909494,
+            ]),
+        ]),
+    }
+}
@@ -0,0 +1,18170 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-14 instance of Poseidon.
+pub const fn rate_14() -> PoseidonParameters<15, 14, 225, 196, 39, 15, 585, 31> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<39, 15, 585>::new_from_known([
+            Fq::from_montgomery_limbs([
+                18308084057473604412,
+                5489731857298421512,
+                4258450907128428813,
+                884957275673051892,
+            ]),
+            Fq::from_montgomery_limbs([
+                1272794352578233488,
+                4897741565411562819,
+                14465078897568210251,
+                110081842056619818,
+            ]),
+            Fq::from_montgomery_limbs([
+                2825267567891830470,
+                10481453081609924854,
+                1384358595340448702,
+                729364596643087818,
+            ]),
+            Fq::from_montgomery_limbs([
+                16039707790931904618,
+                3608880475537882901,
+                13876239505403276641,
+                239203807281236172,
+            ]),
+            Fq::from_montgomery_limbs([
+                3843011967725031067,
+                15027361217463019073,
+                15273838363955264525,
+                686253461831385916,
+            ]),
+            Fq::from_montgomery_limbs([
+                18149993311708208529,
+                18294640717719854258,
+                12800491994158817028,
+                1038100061001222583,
+            ]),
+            Fq::from_montgomery_limbs([
+                579003635857673820,
+                14983734209235445985,
+                1352393097263128346,
+                1106801298485841576,
+            ]),
+            Fq::from_montgomery_limbs([
+                16181938007842827295,
+                7359994375761214853,
+                14776805548749259129,
+                641128107804286353,
+            ]),
+            Fq::from_montgomery_limbs([
+                6701839941476003779,
+                15019843352074411245,
+                10328613553524298318,
+                118098333543327477,
+            ]),
+            Fq::from_montgomery_limbs([
+                1208250685027852604,
+                11313579207909808398,
+                17414460712310764961,
+                874068969179538995,
+            ]),
+            Fq::from_montgomery_limbs([
+                1632002177900688912,
+                146396251540645569,
+                12335054109575443777,
+                439413999039603112,
+            ]),
+            Fq::from_montgomery_limbs([
+                12511932926406430162,
+                12271798818296516757,
+                18002023907994355484,
+                304507009374750137,
+            ]),
+            Fq::from_montgomery_limbs([
+                15131090052164468554,
+                7043970176598983245,
+                11160388890928803661,
+                1111218965318599886,
+            ]),
+            Fq::from_montgomery_limbs([
+                9051514367982524396,
+                269349758023438832,
+                10538984020034974419,
+                180420024367591554,
+            ]),
+            Fq::from_montgomery_limbs([
+                7334643170540006672,
+                16109128843936757777,
+                5650946313412420671,
+                466441949793554303,
+            ]),
+            Fq::from_montgomery_limbs([
+                11319488525518003917,
+                13134966361646589085,
+                1798377273057680673,
+                790169698125370756,
+            ]),
+            Fq::from_montgomery_limbs([
+                3910153256021726127,
+                16121958461586998475,
+                536618421093394297,
+                832058781570349769,
+            ]),
+            Fq::from_montgomery_limbs([
+                15735469782786499438,
+                3258990666106120895,
+                2910993358914929215,
+                1190376512657190702,
+            ]),
+            Fq::from_montgomery_limbs([
+                8644394199540097835,
+                1886395076205233601,
+                3803594076596352179,
+                794557353626217986,
+            ]),
+            Fq::from_montgomery_limbs([
+                3938311766918286452,
+                3099405685646712267,
+                8254111466774267860,
+                118806076642330862,
+            ]),
+            Fq::from_montgomery_limbs([
+                17512121623243217402,
+                15164388877975470463,
+                4951978534138157836,
+                184592042174608545,
+            ]),
+            Fq::from_montgomery_limbs([
+                1496787188547880324,
+                7443093160170175389,
+                14220789772482417892,
+                1052029390160094235,
+            ]),
+            Fq::from_montgomery_limbs([
+                7807262218096821986,
+                441699664483617865,
+                7261106891211397292,
+                224564376962743983,
+            ]),
+            Fq::from_montgomery_limbs([
+                5199190521864755883,
+                16324296195816620466,
+                3890263789745768098,
+                331156176017181758,
+            ]),
+            Fq::from_montgomery_limbs([
+                13839154718504622762,
+                5933840245694963054,
+                15765306641982422092,
+                371829367755699929,
+            ]),
+            Fq::from_montgomery_limbs([
+                7732474730380761237,
+                14654563260095297546,
+                12739882774811550610,
+                217351352864374233,
+            ]),
+            Fq::from_montgomery_limbs([
+                13797933815252580099,
+                16532811063610155618,
+                5283197381598485764,
+                1035437947635981887,
+            ]),
+            Fq::from_montgomery_limbs([
+                10953145039115092815,
+                18124893806893878222,
+                14405981581851639591,
+                503738681451731514,
+            ]),
+            Fq::from_montgomery_limbs([
+                15457451679454567022,
+                820380345268473401,
+                11173834609817588740,
+                1006970548510176946,
+            ]),
+            Fq::from_montgomery_limbs([
+                4136206206019913225,
+                3108354001222301738,
+                12090405216101960012,
+                208725560220035248,
+            ]),
+            Fq::from_montgomery_limbs([
+                13287752109281451047,
+                17935529569893493909,
+                4177932291077601489,
+                876543939475699531,
+            ]),
+            Fq::from_montgomery_limbs([
+                9013134599274291304,
+                5230277629953919505,
+                11901351881132999962,
+                724821299932538331,
+            ]),
+            Fq::from_montgomery_limbs([
+                2910283266108477308,
+                2354655083405936597,
+                13329372210449767929,
+                1127007883164275718,
+            ]),
+            Fq::from_montgomery_limbs([
+                2390480710934245985,
+                7224220568459381476,
+                5951518706089781254,
+                789670202802377253,
+            ]),
+            Fq::from_montgomery_limbs([
+                6940426989865089540,
+                11791542662193105342,
+                5540680464476732592,
+                549780689115442453,
+            ]),
+            Fq::from_montgomery_limbs([
+                12436790908465836051,
+                9239332715126808844,
+                1649998657485509187,
+                842291997060708586,
+            ]),
+            Fq::from_montgomery_limbs([
+                5617725237877946066,
+                15799849346095457524,
+                14287785100806862192,
+                668383716475639003,
+            ]),
+            Fq::from_montgomery_limbs([
+                10185373022441057932,
+                11243133359078204052,
+                11483895702392036131,
+                335837759586382565,
+            ]),
+            Fq::from_montgomery_limbs([
+                7993832671854783560,
+                3498368359188699355,
+                14437395672142899084,
+                690910087849513675,
+            ]),
+            Fq::from_montgomery_limbs([
+                10918946283906482078,
+                8461737921657521096,
+                13247459718778131557,
+                871675192726582189,
+            ]),
+            Fq::from_montgomery_limbs([
+                12258166863344085269,
+                16056041202046508907,
+                16634979568120315047,
+                331476037099337432,
+            ]),
+            Fq::from_montgomery_limbs([
+                3677403777614403494,
+                8319785719974407003,
+                10428433726742972612,
+                1327516670907933800,
+            ]),
+            Fq::from_montgomery_limbs([
+                146015397262316649,
+                17322574936635830849,
+                7514465726138845435,
+                443196575949233,
+            ]),
+            Fq::from_montgomery_limbs([
+                11762469566952521449,
+                9056683462714090619,
+                13093858268020073535,
+                948751780028272643,
+            ]),
+            Fq::from_montgomery_limbs([
+                8235965040162572323,
+                8596077192999837946,
+                17764313198726873155,
+                1235234599637985257,
+            ]),
+            Fq::from_montgomery_limbs([
+                13116075210618895575,
+                11574291250050222562,
+                12840476069873417018,
+                201128790511774349,
+            ]),
+            Fq::from_montgomery_limbs([
+                11141508862254281571,
+                1992815376676803932,
+                10813005397113913737,
+                690044218847039241,
+            ]),
+            Fq::from_montgomery_limbs([
+                5547426095819640968,
+                13075130842621399626,
+                4272074965045000520,
+                785641636255909979,
+            ]),
+            Fq::from_montgomery_limbs([
+                2022530877692421698,
+                8606186233022646625,
+                756888699341738047,
+                696650977285931105,
+            ]),
+            Fq::from_montgomery_limbs([
+                15411308658153064927,
+                817079547870196099,
+                11807039696266530919,
+                232758034419300791,
+            ]),
+            Fq::from_montgomery_limbs([
+                1020072514826925809,
+                1388990440000525455,
+                845100568921007135,
+                740980215635733728,
+            ]),
+            Fq::from_montgomery_limbs([
+                8456517355009430172,
+                1408921361337015006,
+                722025857239314051,
+                765073350983890641,
+            ]),
+            Fq::from_montgomery_limbs([
+                10033928650639208252,
+                15940866197279247145,
+                3529592831953386370,
+                1298620073781936296,
+            ]),
+            Fq::from_montgomery_limbs([
+                14645806911834573775,
+                7075571312626088833,
+                3674606675594056682,
+                291998875370634477,
+            ]),
+            Fq::from_montgomery_limbs([
+                3538586419399279179,
+                10300549741126595128,
+                11010730525530984904,
+                538656727874608105,
+            ]),
+            Fq::from_montgomery_limbs([
+                7834242144583198780,
+                2340086396925202398,
+                2390508358663653464,
+                1100114636614950740,
+            ]),
+            Fq::from_montgomery_limbs([
+                8783361557674181347,
+                853572580978410178,
+                13399452589311149373,
+                389313093294353104,
+            ]),
+            Fq::from_montgomery_limbs([
+                672219141613780414,
+                18406018010317417732,
+                16342136847253989310,
+                1312931990163857855,
+            ]),
+            Fq::from_montgomery_limbs([
+                16792252202287757294,
+                13017696867108281323,
+                12267137411344211873,
+                484130281992530641,
+            ]),
+            Fq::from_montgomery_limbs([
+                8870816554669005575,
+                13071499269681828444,
+                3027023959595129729,
+                36364074644819767,
+            ]),
+            Fq::from_montgomery_limbs([
+                393388219226962277,
+                17487057442008805691,
+                8550383193659920928,
+                1333748469485008906,
+            ]),
+            Fq::from_montgomery_limbs([
+                9955334282518709114,
+                1109969058717485744,
+                4264503119681872288,
+                536337856404399251,
+            ]),
+            Fq::from_montgomery_limbs([
+                14577084696158645932,
+                1907210496877833960,
+                7833993356219055844,
+                499764583289807365,
+            ]),
+            Fq::from_montgomery_limbs([
+                12367019000936556658,
+                17429311811558337342,
+                14212405085994031506,
+                1219571339427361878,
+            ]),
+            Fq::from_montgomery_limbs([
+                13803425332065362805,
+                15585483932431537981,
+                11075574559153608167,
+                974145915389222752,
+            ]),
+            Fq::from_montgomery_limbs([
+                8408215590607031656,
+                14647959712236887495,
+                13335207584259398930,
+                730436276594817092,
+            ]),
+            Fq::from_montgomery_limbs([
+                17982562614770774184,
+                13324655538486697729,
+                14959143724793985239,
+                253151344923826738,
+            ]),
+            Fq::from_montgomery_limbs([
+                3956590264661341853,
+                17256799723787279769,
+                10162878966837408393,
+                734025780413042538,
+            ]),
+            Fq::from_montgomery_limbs([
+                12096108394674455007,
+                15680722658791803835,
+                14773779188460275393,
+                1307141032322555927,
+            ]),
+            Fq::from_montgomery_limbs([
+                6162530060518756102,
+                12732266596371288112,
+                17244748397552035441,
+                1074568407757923805,
+            ]),
+            Fq::from_montgomery_limbs([
+                7208918547071407007,
+                12311763016214054276,
+                335378250692329521,
+                799277824443340221,
+            ]),
+            Fq::from_montgomery_limbs([
+                17656236088096364316,
+                3579007498022495227,
+                12431485517560570791,
+                478196606274278242,
+            ]),
+            Fq::from_montgomery_limbs([
+                9800031521226023845,
+                3923534639001358040,
+                10346275352713917353,
+                1092517127342153,
+            ]),
+            Fq::from_montgomery_limbs([
+                14591731806375855320,
+                16227008170388701593,
+                7080782099819303650,
+                135808269561022583,
+            ]),
+            Fq::from_montgomery_limbs([
+                14990538012050067819,
+                12498544582736070339,
+                12346078816669098606,
+                252525904776013829,
+            ]),
+            Fq::from_montgomery_limbs([
+                1826225742209321312,
+                4146486740477252649,
+                18225521721385867537,
+                54420825169768928,
+            ]),
+            Fq::from_montgomery_limbs([
+                7068555685380907825,
+                12420909383998731535,
+                8393487049415675771,
+                630933215084641058,
+            ]),
+            Fq::from_montgomery_limbs([
+                7315649394023454589,
+                5355043877287090324,
+                1355819691268362832,
+                175088855998375023,
+            ]),
+            Fq::from_montgomery_limbs([
+                17548813953135054824,
+                2524821289279268145,
+                6415894340197420570,
+                802600760714874798,
+            ]),
+            Fq::from_montgomery_limbs([
+                17019592890933883252,
+                1139608509055880836,
+                12864029584565899029,
+                569898684601496135,
+            ]),
+            Fq::from_montgomery_limbs([
+                5363078481805906585,
+                12488694880700916803,
+                17139299052960982627,
+                294009190432650524,
+            ]),
+            Fq::from_montgomery_limbs([
+                2065522301987140882,
+                5196182735679549611,
+                10998846630167500812,
+                849361853310369389,
+            ]),
+            Fq::from_montgomery_limbs([
+                6337575754887156478,
+                6165640773002224207,
+                14512076993356863454,
+                1043330773148481605,
+            ]),
+            Fq::from_montgomery_limbs([
+                13740018036763271165,
+                9450691565036436890,
+                8693713472686540844,
+                653041724724288190,
+            ]),
+            Fq::from_montgomery_limbs([
+                10836581723963137481,
+                14872230199071955616,
+                8095232891864416581,
+                8991720038776971,
+            ]),
+            Fq::from_montgomery_limbs([
+                10425424879343983409,
+                5983769779434577028,
+                11661184246581745584,
+                214664429770781460,
+            ]),
+            Fq::from_montgomery_limbs([
+                3574450969238103704,
+                753777549526103817,
+                10859473328706798356,
+                652294419440662956,
+            ]),
+            Fq::from_montgomery_limbs([
+                10843366305387155766,
+                14748238655109328895,
+                4814444820298808632,
+                584830838814536674,
+            ]),
+            Fq::from_montgomery_limbs([
+                1416399759629920270,
+                15002525024659277389,
+                11963559668213273731,
+                685042925214866552,
+            ]),
+            Fq::from_montgomery_limbs([
+                11497333846735730413,
+                2336263304586989124,
+                16420363334284691730,
+                833165021107899366,
+            ]),
+            Fq::from_montgomery_limbs([
+                6116720756525893730,
+                16193861715174568897,
+                4735977846252283789,
+                659916232383429190,
+            ]),
+            Fq::from_montgomery_limbs([
+                15100836879178903575,
+                7457946997272320752,
+                5454989542539624389,
+                497274872419231542,
+            ]),
+            Fq::from_montgomery_limbs([
+                554167597883189788,
+                425389086448289056,
+                2141227313488928375,
+                1078538383837785211,
+            ]),
+            Fq::from_montgomery_limbs([
+                10705767160374387338,
+                2778134564232688528,
+                14825805727656252649,
+                679762981864518747,
+            ]),
+            Fq::from_montgomery_limbs([
+                124757726865905525,
+                14750131793728570774,
+                7462661289771252398,
+                411624594788106434,
+            ]),
+            Fq::from_montgomery_limbs([
+                8218122798313052367,
+                10325908836344712314,
+                3402699498230575841,
+                265033269470894619,
+            ]),
+            Fq::from_montgomery_limbs([
+                14026257273517668883,
+                1946026957761363722,
+                12908026300481566132,
+                90938986740133684,
+            ]),
+            Fq::from_montgomery_limbs([
+                7701765476903029188,
+                4179314255641192299,
+                12181735018569844608,
+                902187104888770376,
+            ]),
+            Fq::from_montgomery_limbs([
+                755704421879229995,
+                5877775886172709209,
+                3371275503243264770,
+                592204716445677714,
+            ]),
+            Fq::from_montgomery_limbs([
+                9742040434876195738,
+                5265860612352337069,
+                7596116585937392929,
+                854542790539023491,
+            ]),
+            Fq::from_montgomery_limbs([
+                18122694652254803123,
+                18298763802783617002,
+                2394652054558399360,
+                319992900618751133,
+            ]),
+            Fq::from_montgomery_limbs([
+                11178407961345616904,
+                17494226103735662574,
+                8883577094789299661,
+                875264942303411304,
+            ]),
+            Fq::from_montgomery_limbs([
+                1454458846792499826,
+                6490694088902316307,
+                5036098292787676303,
+                727515382830636873,
+            ]),
+            Fq::from_montgomery_limbs([
+                7537915324810615172,
+                10472124990106061828,
+                2694121835461282738,
+                623552083247792616,
+            ]),
+            Fq::from_montgomery_limbs([
+                10074324278783345214,
+                10128525535586264651,
+                14167495591209858777,
+                786667284184666368,
+            ]),
+            Fq::from_montgomery_limbs([
+                10022909117496823856,
+                15873491861687211136,
+                3458405153127823135,
+                855498505333729421,
+            ]),
+            Fq::from_montgomery_limbs([
+                967722169110551580,
+                6032871312025079780,
+                11081292548389186495,
+                893819132764989788,
+            ]),
+            Fq::from_montgomery_limbs([
+                15696814988614148577,
+                12165683743776992249,
+                4676410895094967944,
+                842723958490764856,
+            ]),
+            Fq::from_montgomery_limbs([
+                11563353090782966121,
+                10214590217366716935,
+                12383162507024350668,
+                830184535960246232,
+            ]),
+            Fq::from_montgomery_limbs([
+                2059347399062006136,
+                4033598083934027993,
+                17343876074739970887,
+                998659543946819927,
+            ]),
+            Fq::from_montgomery_limbs([
+                5366466832143280632,
+                567870358957792401,
+                3177746581685239582,
+                1195928463741631409,
+            ]),
+            Fq::from_montgomery_limbs([
+                862623968847475315,
+                8864877022073067408,
+                11379298455809481914,
+                491894724545855378,
+            ]),
+            Fq::from_montgomery_limbs([
+                14040952668728126432,
+                235902641268198829,
+                6291913041584247229,
+                705969189306038188,
+            ]),
+            Fq::from_montgomery_limbs([
+                1328700084855149496,
+                4376524698580514879,
+                2598867876541043812,
+                790645642904811075,
+            ]),
+            Fq::from_montgomery_limbs([
+                13632132402378881995,
+                18067200360865871473,
+                11206383742229306271,
+                559598110266999944,
+            ]),
+            Fq::from_montgomery_limbs([
+                16130734619891585123,
+                1441942867223349835,
+                425972507755915276,
+                918150646317018282,
+            ]),
+            Fq::from_montgomery_limbs([
+                2083654459007897223,
+                9464492729545330497,
+                1160066920771201046,
+                832670103531940331,
+            ]),
+            Fq::from_montgomery_limbs([
+                17078804129722798267,
+                559913995825355443,
+                4549729453223449254,
+                317495837240814316,
+            ]),
+            Fq::from_montgomery_limbs([
+                3372484808618184818,
+                1782132636357187944,
+                12657511058887676819,
+                15023007128983221,
+            ]),
+            Fq::from_montgomery_limbs([
+                16158819057827933593,
+                3029532857259685326,
+                2870207600848389788,
+                1107153676065426158,
+            ]),
+            Fq::from_montgomery_limbs([
+                5397185577215918541,
+                5114001368926750239,
+                7904428513186467239,
+                1289469745704210623,
+            ]),
+            Fq::from_montgomery_limbs([
+                1575621145820591615,
+                3956236436814333075,
+                475829278073131414,
+                218197076879540780,
+            ]),
+            Fq::from_montgomery_limbs([
+                17487653068232357150,
+                8414534490127928479,
+                1545954742832892649,
+                490352259488417993,
+            ]),
+            Fq::from_montgomery_limbs([
+                16985495901999802979,
+                18263936044752187653,
+                7698698362438935637,
+                497701693453378481,
+            ]),
+            Fq::from_montgomery_limbs([
+                9183332004021712857,
+                5193430546268047128,
+                1066172345310725592,
+                534789611434168934,
+            ]),
+            Fq::from_montgomery_limbs([
+                15130031668331517266,
+                15559514526975864295,
+                3677072306363236649,
+                172126559342289801,
+            ]),
+            Fq::from_montgomery_limbs([
+                11180248189293122144,
+                2112083681528248073,
+                12289139679565670621,
+                267432810235475020,
+            ]),
+            Fq::from_montgomery_limbs([
+                14830999169394792190,
+                2506826818186790911,
+                8269543917358853921,
+                973616470803612907,
+            ]),
+            Fq::from_montgomery_limbs([
+                8539601531191810404,
+                12760518586062486762,
+                8870310003974944778,
+                1023575574432054105,
+            ]),
+            Fq::from_montgomery_limbs([
+                8190566545077469168,
+                10221245244555197879,
+                3098249279442140464,
+                1248673507694002348,
+            ]),
+            Fq::from_montgomery_limbs([
+                12183835040263501163,
+                8113319301302143874,
+                14982645605725480113,
+                1312015237063857108,
+            ]),
+            Fq::from_montgomery_limbs([
+                6297974823928556851,
+                7856978663913220431,
+                11514898191879277263,
+                776977841839099563,
+            ]),
+            Fq::from_montgomery_limbs([
+                9776631573403012220,
+                9143427033303080264,
+                10578803638109442175,
+                764735775883811062,
+            ]),
+            Fq::from_montgomery_limbs([
+                1493770983850919764,
+                219171260458634216,
+                11880983332117547518,
+                1089974405366775924,
+            ]),
+            Fq::from_montgomery_limbs([
+                12329222223181563833,
+                5133782492733788662,
+                17577389145946399662,
+                77266841978087968,
+            ]),
+            Fq::from_montgomery_limbs([
+                7921768974225645159,
+                8746401307096917499,
+                13233105041835280940,
+                1007986233349123318,
+            ]),
+            Fq::from_montgomery_limbs([
+                2732574655764496697,
+                15152907260471502953,
+                420253064501903664,
+                139628292685190462,
+            ]),
+            Fq::from_montgomery_limbs([
+                9330912746097093595,
+                13719097453417110803,
+                8285840628793354816,
+                588073511712048359,
+            ]),
+            Fq::from_montgomery_limbs([
+                12118172802146765353,
+                8607457439414180547,
+                331569465928710227,
+                249051294578624170,
+            ]),
+            Fq::from_montgomery_limbs([
+                17351184102531732944,
+                17014746165776216128,
+                4296418467444530909,
+                463534061615186657,
+            ]),
+            Fq::from_montgomery_limbs([
+                8387370573571594293,
+                14062652483576011312,
+                6658821119261373297,
+                851795613191964699,
+            ]),
+            Fq::from_montgomery_limbs([
+                10327783101105270487,
+                16279939185427029838,
+                6704208894690090128,
+                968918265256073629,
+            ]),
+            Fq::from_montgomery_limbs([
+                4504954758009578481,
+                7054236807829483600,
+                12820082620822185499,
+                102517796764409438,
+            ]),
+            Fq::from_montgomery_limbs([
+                17509600863015190871,
+                4217007154491200733,
+                3977652510497942770,
+                1090861068734052107,
+            ]),
+            Fq::from_montgomery_limbs([
+                6429087808692738599,
+                12230160706963512898,
+                2593922227167973502,
+                1117919802899008075,
+            ]),
+            Fq::from_montgomery_limbs([
+                13126849637314892426,
+                16509362504782971489,
+                12138872519622965111,
+                898754923625799385,
+            ]),
+            Fq::from_montgomery_limbs([
+                12388514560969633826,
+                3031569639909199301,
+                6123729753135482835,
+                783144878545680102,
+            ]),
+            Fq::from_montgomery_limbs([
+                8524319138362520191,
+                2485148450752736045,
+                16569829607708213316,
+                552893583692026643,
+            ]),
+            Fq::from_montgomery_limbs([
+                5171364251976132798,
+                14131844819999809336,
+                16618625886557572646,
+                673683200743576524,
+            ]),
+            Fq::from_montgomery_limbs([
+                6744423352644534270,
+                13611600371336865467,
+                9800903690714703214,
+                617441499375167307,
+            ]),
+            Fq::from_montgomery_limbs([
+                12668601462548796058,
+                10505100662849826711,
+                2440900334934359905,
+                42967148748440612,
+            ]),
+            Fq::from_montgomery_limbs([
+                9701516013831920382,
+                13983436666506292355,
+                14998210172019115559,
+                809275289561882300,
+            ]),
+            Fq::from_montgomery_limbs([
+                13988836795302106445,
+                17082223372929644479,
+                9123619859795760825,
+                62007473314501969,
+            ]),
+            Fq::from_montgomery_limbs([
+                10387049360665196310,
+                126121895557944806,
+                17270926337291134781,
+                274750465340513178,
+            ]),
+            Fq::from_montgomery_limbs([
+                8305192618804984236,
+                1371244492510051681,
+                14036996152150174070,
+                1330220984810643795,
+            ]),
+            Fq::from_montgomery_limbs([
+                14245352401072113949,
+                3928736941604041594,
+                14297180907778750583,
+                432988622679770072,
+            ]),
+            Fq::from_montgomery_limbs([
+                18382837839369409070,
+                7177593430712965677,
+                5080187978518277997,
+                1203017100071256461,
+            ]),
+            Fq::from_montgomery_limbs([
+                6105448912472088984,
+                17362764367090105237,
+                18390040390685024684,
+                318568074787935718,
+            ]),
+            Fq::from_montgomery_limbs([
+                1316369868604376226,
+                16100731982174143026,
+                10144942884590282917,
+                346485239264516649,
+            ]),
+            Fq::from_montgomery_limbs([
+                4891652180109603836,
+                14930588681630032961,
+                11312788793710347930,
+                812093535216106327,
+            ]),
+            Fq::from_montgomery_limbs([
+                4472424805881708427,
+                15440764421088629933,
+                11951008163278218118,
+                733070121480154224,
+            ]),
+            Fq::from_montgomery_limbs([
+                9562106375837960451,
+                2396047533220965208,
+                8498013100575600050,
+                560198082941979664,
+            ]),
+            Fq::from_montgomery_limbs([
+                17120816769123835862,
+                9551352334399806265,
+                10782574668983016743,
+                1177915623089843170,
+            ]),
+            Fq::from_montgomery_limbs([
+                15594397521791482103,
+                10081964314969960193,
+                17875172207941101036,
+                1171934354811684638,
+            ]),
+            Fq::from_montgomery_limbs([
+                8692631959009286268,
+                7436596436418404274,
+                12848283242574460095,
+                518726746508861563,
+            ]),
+            Fq::from_montgomery_limbs([
+                1906605195997975649,
+                10127637456524159578,
+                3848641991862910558,
+                814670185599807664,
+            ]),
+            Fq::from_montgomery_limbs([
+                17042563460749858648,
+                1738706035226300188,
+                12311053556596402461,
+                172761787734723476,
+            ]),
+            Fq::from_montgomery_limbs([
+                2211925209407258544,
+                3414583677858366645,
+                14235534256556842322,
+                943417679142423562,
+            ]),
+            Fq::from_montgomery_limbs([
+                17413829339576633973,
+                8258239350559921671,
+                17207668667557900883,
+                761579168100683411,
+            ]),
+            Fq::from_montgomery_limbs([
+                7353231253050598539,
+                9184641334358250583,
+                4588997297870450712,
+                806330260250313270,
+            ]),
+            Fq::from_montgomery_limbs([
+                13969773401197787885,
+                13180134027724818992,
+                2596467020882111613,
+                945988869857089462,
+            ]),
+            Fq::from_montgomery_limbs([
+                15740767510689449676,
+                5641404769943319773,
+                6931891106068164043,
+                1151844280618306979,
+            ]),
+            Fq::from_montgomery_limbs([
+                7763900078265646684,
+                7884268534959775118,
+                3404386703242396169,
+                632693236154002037,
+            ]),
+            Fq::from_montgomery_limbs([
+                17423572155883609400,
+                716400115365031854,
+                13557880261414020950,
+                803394926488402860,
+            ]),
+            Fq::from_montgomery_limbs([
+                4167122530376175755,
+                3810888152436640780,
+                15127541499314027494,
+                732791311651567406,
+            ]),
+            Fq::from_montgomery_limbs([
+                9045630993892230866,
+                1608749521929560468,
+                8947702459144314531,
+                748686296591041516,
+            ]),
+            Fq::from_montgomery_limbs([
+                17660631889675987352,
+                10601462888494214028,
+                9019061227683829619,
+                375365485062997626,
+            ]),
+            Fq::from_montgomery_limbs([
+                17200719713115723507,
+                13337737720796200855,
+                17993744685176061543,
+                1089871608145112776,
+            ]),
+            Fq::from_montgomery_limbs([
+                10583752031600712051,
+                13741957225435528393,
+                2377828113126295228,
+                1051929255915292005,
+            ]),
+            Fq::from_montgomery_limbs([
+                7742519912862454226,
+                14908017954398315098,
+                13435862925805596893,
+                893091148572559619,
+            ]),
+            Fq::from_montgomery_limbs([
+                18029573648659489176,
+                14545351077330534746,
+                7246346840549104913,
+                104574679620040486,
+            ]),
+            Fq::from_montgomery_limbs([
+                11060192012156274229,
+                12997700759313221115,
+                14492187034751074586,
+                630315164883805516,
+            ]),
+            Fq::from_montgomery_limbs([
+                16921038261653581653,
+                9021812839974712486,
+                12283181312943343730,
+                39424314804692480,
+            ]),
+            Fq::from_montgomery_limbs([
+                13948766876765214959,
+                14166382340530803281,
+                2726955247792780678,
+                1083412457057620430,
+            ]),
+            Fq::from_montgomery_limbs([
+                8234275842812687182,
+                7528676571604881220,
+                9157222512551473185,
+                725763617332501780,
+            ]),
+            Fq::from_montgomery_limbs([
+                14606847447356556772,
+                1596117796345596327,
+                12227496665543704040,
+                736668739054699226,
+            ]),
+            Fq::from_montgomery_limbs([
+                9676416605891024271,
+                15585811854388466049,
+                13318631728130052368,
+                1132035098666496867,
+            ]),
+            Fq::from_montgomery_limbs([
+                4707953217249879264,
+                5314013093627936923,
+                16303101269878581610,
+                390267052374887611,
+            ]),
+            Fq::from_montgomery_limbs([
+                14901322014965590479,
+                3441889401751832118,
+                9113757940260401417,
+                874318907809726308,
+            ]),
+            Fq::from_montgomery_limbs([
+                12931181822238373986,
+                6990304248915984045,
+                9926394519205350424,
+                1296330192788528692,
+            ]),
+            Fq::from_montgomery_limbs([
+                13325883790590046303,
+                10453754572875936086,
+                6557141718549151143,
+                669875021684592286,
+            ]),
+            Fq::from_montgomery_limbs([
+                3070417189759671718,
+                15176419617325041719,
+                12625449685660997856,
+                151250744681971270,
+            ]),
+            Fq::from_montgomery_limbs([
+                13190340816104113209,
+                16627696487296643140,
+                9769768119731437969,
+                542165709031254364,
+            ]),
+            Fq::from_montgomery_limbs([
+                1388638801708482221,
+                16918091899479688035,
+                2588981402241687479,
+                1064100042726924179,
+            ]),
+            Fq::from_montgomery_limbs([
+                6802389741458867585,
+                10539618290826297651,
+                7658849392353117903,
+                391200517844314404,
+            ]),
+            Fq::from_montgomery_limbs([
+                470668509219940618,
+                9080365466998120078,
+                8905254552029685058,
+                43442416648443986,
+            ]),
+            Fq::from_montgomery_limbs([
+                10563195974023379011,
+                1148700774276097046,
+                13844432884802577205,
+                850975091330141122,
+            ]),
+            Fq::from_montgomery_limbs([
+                6433735498351969351,
+                9932283142179450111,
+                5559131224547426254,
+                633118814895958674,
+            ]),
+            Fq::from_montgomery_limbs([
+                13951768927381928455,
+                15628661321713784161,
+                17724138031182485611,
+                62154134854689523,
+            ]),
+            Fq::from_montgomery_limbs([
+                14531923242347840736,
+                14291267201443139669,
+                1515031557356723626,
+                36198732903338013,
+            ]),
+            Fq::from_montgomery_limbs([
+                1236280174729842663,
+                14562176266942452408,
+                17501054996204286803,
+                106537535269262963,
+            ]),
+            Fq::from_montgomery_limbs([
+                1791599223545309272,
+                13251206015409168005,
+                4130990754493123811,
+                320350970933093867,
+            ]),
+            Fq::from_montgomery_limbs([
+                6004229730388711390,
+                3946948424885820932,
+                3480131075931526984,
+                1180251216547843488,
+            ]),
+            Fq::from_montgomery_limbs([
+                16878741655864121249,
+                10574557313753675354,
+                2673535018967930726,
+                731673142257281430,
+            ]),
+            Fq::from_montgomery_limbs([
+                16215353527914489089,
+                15092258733351559830,
+                1500368040691329421,
+                1247388810427418078,
+            ]),
+            Fq::from_montgomery_limbs([
+                16314695464702301580,
+                17975605045665261747,
+                7362516745406885766,
+                994346729646896374,
+            ]),
+            Fq::from_montgomery_limbs([
+                8066305695621298857,
+                2950171274490311814,
+                2757052029417338156,
+                421464001856391453,
+            ]),
+            Fq::from_montgomery_limbs([
+                17819535460333289534,
+                17931619431739641196,
+                8270284676281481907,
+                1235803885453176158,
+            ]),
+            Fq::from_montgomery_limbs([
+                7785390407516803933,
+                17197139336561303433,
+                13855170994439195511,
+                1137295912748252654,
+            ]),
+            Fq::from_montgomery_limbs([
+                9038047273471495735,
+                6301788475049792570,
+                15676225492311513289,
+                1253274788559991352,
+            ]),
+            Fq::from_montgomery_limbs([
+                1339532660414497719,
+                16550598293839663218,
+                6339159769655234224,
+                669209907865830204,
+            ]),
+            Fq::from_montgomery_limbs([
+                3307160355753455589,
+                16154034788093056384,
+                2679607388687587117,
+                461906696426614598,
+            ]),
+            Fq::from_montgomery_limbs([
+                3104575124738380154,
+                2316384807125062982,
+                16329647036674059875,
+                862914577008581316,
+            ]),
+            Fq::from_montgomery_limbs([
+                8392563072436558561,
+                1261477522939327756,
+                9994041667470790717,
+                584178666919806735,
+            ]),
+            Fq::from_montgomery_limbs([
+                11105811097919202134,
+                16909802665021595261,
+                10724870521721118589,
+                1247359488322864205,
+            ]),
+            Fq::from_montgomery_limbs([
+                166028121134215977,
+                15177581207573215450,
+                16533527872013845488,
+                878065303460906437,
+            ]),
+            Fq::from_montgomery_limbs([
+                2242800189618825487,
+                6916942332726966416,
+                13395965725161270974,
+                1059407713715237863,
+            ]),
+            Fq::from_montgomery_limbs([
+                110985671113931304,
+                14577004959193615621,
+                4364291516361420811,
+                912721380807769890,
+            ]),
+            Fq::from_montgomery_limbs([
+                12453724560649286574,
+                4544359819338146722,
+                16910250226586196245,
+                1253109538096872947,
+            ]),
+            Fq::from_montgomery_limbs([
+                16070252756741301207,
+                5321620861167327722,
+                6665256856952268683,
+                798189301672859952,
+            ]),
+            Fq::from_montgomery_limbs([
+                4750530696849789621,
+                3344101079917391581,
+                5307167086900588400,
+                1261221523983571910,
+            ]),
+            Fq::from_montgomery_limbs([
+                2351227094704174708,
+                7097486704110714993,
+                12363189863518981921,
+                258056601420832196,
+            ]),
+            Fq::from_montgomery_limbs([
+                15288801367137640286,
+                18205485490058621631,
+                12722810920373670054,
+                996681121937769,
+            ]),
+            Fq::from_montgomery_limbs([
+                15617275772171804759,
+                16095979559570624190,
+                16136069164398951737,
+                623303920894210052,
+            ]),
+            Fq::from_montgomery_limbs([
+                17020257229130942693,
+                13649151322393708641,
+                14007414214762836976,
+                479637749847757131,
+            ]),
+            Fq::from_montgomery_limbs([
+                15874865337977126527,
+                15763760935393356892,
+                12041499273113721075,
+                427408023004294915,
+            ]),
+            Fq::from_montgomery_limbs([
+                16239582025420673615,
+                10104147211503843070,
+                12644880334646711214,
+                132628141572323901,
+            ]),
+            Fq::from_montgomery_limbs([
+                6988369273723725499,
+                9632463343149615827,
+                17587274145541776678,
+                382143348286462065,
+            ]),
+            Fq::from_montgomery_limbs([
+                4147405324235428481,
+                12380827040539911769,
+                3444290334609927345,
+                242923796142445090,
+            ]),
+            Fq::from_montgomery_limbs([
+                1346267014052158762,
+                4087121362043788261,
+                12420426713895910615,
+                247166833789405900,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779911014886983911,
+                3768100990107333280,
+                1363736265172830773,
+                256551181245239480,
+            ]),
+            Fq::from_montgomery_limbs([
+                8995811262740047461,
+                11713415107758279568,
+                17211909767742001731,
+                448222170195575960,
+            ]),
+            Fq::from_montgomery_limbs([
+                10808084753278186471,
+                9480186339792144923,
+                1007827191163234740,
+                61794797632523541,
+            ]),
+            Fq::from_montgomery_limbs([
+                5044460842241628165,
+                13468941812706746076,
+                18045662487868646233,
+                1188673879554053291,
+            ]),
+            Fq::from_montgomery_limbs([
+                13946158407483611738,
+                13021947616068715626,
+                16462968773557854908,
+                303569276861001139,
+            ]),
+            Fq::from_montgomery_limbs([
+                15154269554863183633,
+                6605843793573905156,
+                11787931845805958277,
+                326094252705911796,
+            ]),
+            Fq::from_montgomery_limbs([
+                17609584717001143213,
+                10503515763905369081,
+                11677506327501410397,
+                674746650556937139,
+            ]),
+            Fq::from_montgomery_limbs([
+                18048965435554148729,
+                1988915653037141677,
+                2217775199126757195,
+                723668761566061823,
+            ]),
+            Fq::from_montgomery_limbs([
+                4642511537687784188,
+                2724489967603333799,
+                7804076738788798086,
+                913538380621245061,
+            ]),
+            Fq::from_montgomery_limbs([
+                18230518934276039102,
+                27097510547771981,
+                15529037679609300151,
+                1297707702663710981,
+            ]),
+            Fq::from_montgomery_limbs([
+                12104992071715704527,
+                9567360021855161205,
+                14415576332128753481,
+                301071635735181363,
+            ]),
+            Fq::from_montgomery_limbs([
+                16106563112028365447,
+                12391762291738792331,
+                17594345010029670900,
+                1140852850861159895,
+            ]),
+            Fq::from_montgomery_limbs([
+                68187732804005244,
+                1222773718738383812,
+                3075673660465075098,
+                1288924246592461716,
+            ]),
+            Fq::from_montgomery_limbs([
+                18071876821912475002,
+                3796146478235575908,
+                14411572012914375606,
+                966088035185281022,
+            ]),
+            Fq::from_montgomery_limbs([
+                248043225602821927,
+                9111230923455571279,
+                15453180771706441254,
+                987342763406186290,
+            ]),
+            Fq::from_montgomery_limbs([
+                18114896937803565666,
+                3256751067701179554,
+                3663675071437519424,
+                523741553724858237,
+            ]),
+            Fq::from_montgomery_limbs([
+                7999339523755167997,
+                1192610511507386511,
+                18012085988281860465,
+                853004096769512345,
+            ]),
+            Fq::from_montgomery_limbs([
+                18117954981775139630,
+                17961448117561860271,
+                12174195419645236287,
+                932587045408804663,
+            ]),
+            Fq::from_montgomery_limbs([
+                14196854952975870933,
+                15798847566428013413,
+                7530093456685755089,
+                34782464158783396,
+            ]),
+            Fq::from_montgomery_limbs([
+                8226346074299552840,
+                5282038641529408943,
+                6181746700183670957,
+                1343306116846031390,
+            ]),
+            Fq::from_montgomery_limbs([
+                13857166377789763632,
+                4227589057942682106,
+                10995025298270937779,
+                764337765682533127,
+            ]),
+            Fq::from_montgomery_limbs([
+                13712447621498092819,
+                6755744216229859953,
+                17347545889510265473,
+                769749822940526669,
+            ]),
+            Fq::from_montgomery_limbs([
+                2197182898794027960,
+                6606311257325642849,
+                14904086781625634192,
+                1287623542658536192,
+            ]),
+            Fq::from_montgomery_limbs([
+                10595690146232922558,
+                1260203284809762358,
+                4213727598811906542,
+                1171185418538829515,
+            ]),
+            Fq::from_montgomery_limbs([
+                9928670405240356503,
+                5946435121403177608,
+                18102206058617607654,
+                555407345521875132,
+            ]),
+            Fq::from_montgomery_limbs([
+                8229632287610014420,
+                4873194460569184022,
+                18229031056686327550,
+                604184520170693971,
+            ]),
+            Fq::from_montgomery_limbs([
+                4738053212224533962,
+                11977930956253815380,
+                6106583874607792820,
+                353240896924785473,
+            ]),
+            Fq::from_montgomery_limbs([
+                4245836567263421727,
+                9910908781271732948,
+                289064020923121699,
+                301646971606580092,
+            ]),
+            Fq::from_montgomery_limbs([
+                17413865144406608144,
+                2341963194433400959,
+                16944653600252528443,
+                1166113331261996818,
+            ]),
+            Fq::from_montgomery_limbs([
+                17612679449065053656,
+                11783733895076195998,
+                11188014264456540190,
+                649219054637714642,
+            ]),
+            Fq::from_montgomery_limbs([
+                10223284651172235628,
+                17679305333581780591,
+                4957221697947288344,
+                769211605943313199,
+            ]),
+            Fq::from_montgomery_limbs([
+                7706000369194976486,
+                5610355896495199737,
+                17222187238063840214,
+                67802795426123043,
+            ]),
+            Fq::from_montgomery_limbs([
+                10544032663882688217,
+                3856949671404543164,
+                7982766788711955893,
+                579099237779448240,
+            ]),
+            Fq::from_montgomery_limbs([
+                5455405408660475873,
+                6663183310367654121,
+                12164033410037905700,
+                792795949032133096,
+            ]),
+            Fq::from_montgomery_limbs([
+                7852569913604016658,
+                10905588239531359784,
+                6245962325842919654,
+                819691968699099305,
+            ]),
+            Fq::from_montgomery_limbs([
+                10463657990396215038,
+                9972072636477635213,
+                11363012198563097011,
+                49512864080797656,
+            ]),
+            Fq::from_montgomery_limbs([
+                11634280761104630487,
+                13962401740739028479,
+                10200274779094352939,
+                757022734746517073,
+            ]),
+            Fq::from_montgomery_limbs([
+                12370288317661838463,
+                1941453567013869679,
+                4376468935185367179,
+                197025162348426007,
+            ]),
+            Fq::from_montgomery_limbs([
+                17828962747971195516,
+                4973867051189337777,
+                12682288902468660972,
+                965629096981980069,
+            ]),
+            Fq::from_montgomery_limbs([
+                10932043516749117638,
+                546927968326830719,
+                625328658463981831,
+                363836489151169491,
+            ]),
+            Fq::from_montgomery_limbs([
+                8058829380707741626,
+                7661755146344286492,
+                5809551376958741888,
+                414738932383849896,
+            ]),
+            Fq::from_montgomery_limbs([
+                2302041565574230214,
+                7265886205158314077,
+                7081934953003717962,
+                1316118192042462340,
+            ]),
+            Fq::from_montgomery_limbs([
+                3736883622966215099,
+                7133761095841144826,
+                7974276337640086096,
+                1199549168136037150,
+            ]),
+            Fq::from_montgomery_limbs([
+                10052623360359974682,
+                3604519126239306735,
+                11103268052574239354,
+                1260364744021236152,
+            ]),
+            Fq::from_montgomery_limbs([
+                16273863863794184301,
+                14264341264371614576,
+                8228812166307477076,
+                564196319233966071,
+            ]),
+            Fq::from_montgomery_limbs([
+                14670989570558941127,
+                7557501209256862513,
+                8414678663971065148,
+                168168623934820314,
+            ]),
+            Fq::from_montgomery_limbs([
+                10756117291899134496,
+                68347113098642369,
+                6038151518932129706,
+                239784894017167168,
+            ]),
+            Fq::from_montgomery_limbs([
+                11488591138793949615,
+                16789048362997979,
+                14529875609983410432,
+                180620823259960844,
+            ]),
+            Fq::from_montgomery_limbs([
+                9016237878676897686,
+                10355655284492309469,
+                17582425732348403927,
+                849746616369653367,
+            ]),
+            Fq::from_montgomery_limbs([
+                10069001221781421439,
+                6874774183165810283,
+                7377299425230826937,
+                757364454106920877,
+            ]),
+            Fq::from_montgomery_limbs([
+                1718121645543724018,
+                269386195743913407,
+                5320531478710451323,
+                800425541894073761,
+            ]),
+            Fq::from_montgomery_limbs([
+                11748743311561342037,
+                11664113969946002173,
+                7238850722177335757,
+                587569084282355285,
+            ]),
+            Fq::from_montgomery_limbs([
+                894406467353605802,
+                7066196163675956153,
+                6539142462390778179,
+                698816653332616880,
+            ]),
+            Fq::from_montgomery_limbs([
+                11854056452051205793,
+                18352422968644519592,
+                6982760435257840692,
+                313444125854709085,
+            ]),
+            Fq::from_montgomery_limbs([
+                7142152194166700589,
+                5795845203356549051,
+                6230529383087167057,
+                454072272978536275,
+            ]),
+            Fq::from_montgomery_limbs([
+                13916450656332501385,
+                5636670614359578737,
+                11187202624374944534,
+                1318678634719903947,
+            ]),
+            Fq::from_montgomery_limbs([
+                16933756492968684605,
+                385280343526473458,
+                4557701963801574170,
+                1125597728818172653,
+            ]),
+            Fq::from_montgomery_limbs([
+                4927995088524022540,
+                1088046645368282191,
+                14824199789042956686,
+                1063725319720270423,
+            ]),
+            Fq::from_montgomery_limbs([
+                14876208575898967282,
+                11656577065892365843,
+                11036342199907347025,
+                1282291848788982547,
+            ]),
+            Fq::from_montgomery_limbs([
+                17474233980258075379,
+                12195292419612878945,
+                18299849083904242194,
+                34378227710515239,
+            ]),
+            Fq::from_montgomery_limbs([
+                4109905541557672640,
+                3768851119399096538,
+                16021222107808697656,
+                340881580658515316,
+            ]),
+            Fq::from_montgomery_limbs([
+                12475052993529156205,
+                14427382920879279009,
+                13176992915913505513,
+                49903914531059197,
+            ]),
+            Fq::from_montgomery_limbs([
+                9533798833789505392,
+                4982143039136142057,
+                218596613281397415,
+                1312691854311084319,
+            ]),
+            Fq::from_montgomery_limbs([
+                8990456845494048492,
+                563273972070304187,
+                11367413515480213663,
+                957571585491261686,
+            ]),
+            Fq::from_montgomery_limbs([
+                6030738443302246207,
+                13731569136893984828,
+                15960349163956639335,
+                1191612135287401402,
+            ]),
+            Fq::from_montgomery_limbs([
+                3207472024026016932,
+                9411198106255275799,
+                6725706934003053501,
+                290351897499293687,
+            ]),
+            Fq::from_montgomery_limbs([
+                14088597370719933280,
+                2189462475963936568,
+                3361783231213386875,
+                1251612998880733560,
+            ]),
+            Fq::from_montgomery_limbs([
+                12606928229340610414,
+                4987227512106001415,
+                5292467014921941463,
+                847302522486572105,
+            ]),
+            Fq::from_montgomery_limbs([
+                13620340350885724399,
+                2030370498302197710,
+                5295980525929891198,
+                501785942521160703,
+            ]),
+            Fq::from_montgomery_limbs([
+                1057560823006781045,
+                14211716079399092707,
+                11849611907690398012,
+                424109105095402711,
+            ]),
+            Fq::from_montgomery_limbs([
+                1148451416202385778,
+                2246009450150205386,
+                11562444713487446934,
+                33189642147628658,
+            ]),
+            Fq::from_montgomery_limbs([
+                608688011593753381,
+                14862641993921186933,
+                4483720462547698794,
+                77789276136388339,
+            ]),
+            Fq::from_montgomery_limbs([
+                4141909756008336466,
+                4499283631396569687,
+                14499500365244237857,
+                1026848980594827166,
+            ]),
+            Fq::from_montgomery_limbs([
+                15944545071851324856,
+                3840322209507910987,
+                4921713800737099855,
+                391594841616132898,
+            ]),
+            Fq::from_montgomery_limbs([
+                13930897077240186269,
+                14132364897539684887,
+                15686857315964540059,
+                1078179158025126502,
+            ]),
+            Fq::from_montgomery_limbs([
+                16668545966971128952,
+                4805488656734622222,
+                13466321610730491526,
+                1198098046402510687,
+            ]),
+            Fq::from_montgomery_limbs([
+                4557537588483781063,
+                16113262267493406036,
+                10190107965494688236,
+                511461093649776051,
+            ]),
+            Fq::from_montgomery_limbs([
+                5387092697063117423,
+                61840989937597015,
+                14606469401967305134,
+                1026163071885702369,
+            ]),
+            Fq::from_montgomery_limbs([
+                2863798186240756100,
+                11709527654749303141,
+                17726666866482379052,
+                897701447400780981,
+            ]),
+            Fq::from_montgomery_limbs([
+                14496346290473119510,
+                12981411842671401981,
+                2450878540254126122,
+                176703784036767314,
+            ]),
+            Fq::from_montgomery_limbs([
+                11675951746700449190,
+                15953363067618034730,
+                9327327647649777419,
+                961941565643899485,
+            ]),
+            Fq::from_montgomery_limbs([
+                7973686986470464849,
+                13396339617477294083,
+                9866369525358597481,
+                663860965412337667,
+            ]),
+            Fq::from_montgomery_limbs([
+                14949329373272579693,
+                16781777698808488089,
+                11605186675412424109,
+                392231567706076937,
+            ]),
+            Fq::from_montgomery_limbs([
+                12622111342534587991,
+                17856078204913892041,
+                2779865670547076507,
+                632119019016592935,
+            ]),
+            Fq::from_montgomery_limbs([
+                2711467222109809129,
+                8702653619359850205,
+                2875864406178833462,
+                106103035413546767,
+            ]),
+            Fq::from_montgomery_limbs([
+                7033747571876777418,
+                11955889179492125788,
+                12798470068193495804,
+                899777917645754096,
+            ]),
+            Fq::from_montgomery_limbs([
+                6945762332038260498,
+                11106724777266394901,
+                431933413374812364,
+                183337680813332851,
+            ]),
+            Fq::from_montgomery_limbs([
+                2449122769328853908,
+                14518757129860182433,
+                12517422805495357434,
+                968269383823252495,
+            ]),
+            Fq::from_montgomery_limbs([
+                14867071635879264297,
+                9355111465277515950,
+                14355230345238943789,
+                630906574791636457,
+            ]),
+            Fq::from_montgomery_limbs([
+                9274690576780558289,
+                16828829198813947204,
+                2040123228715611421,
+                180719788324050257,
+            ]),
+            Fq::from_montgomery_limbs([
+                16443221754900525812,
+                2368670309608162822,
+                810623812646436253,
+                362432745486213563,
+            ]),
+            Fq::from_montgomery_limbs([
+                15964691512506203307,
+                13029224738571658905,
+                12613045813949952049,
+                444748672025693935,
+            ]),
+            Fq::from_montgomery_limbs([
+                15944914058394234075,
+                871355994333215694,
+                14319819531429203474,
+                68347574065220213,
+            ]),
+            Fq::from_montgomery_limbs([
+                18114744976079220828,
+                2982359890576376812,
+                10971577972236567262,
+                406135819998901919,
+            ]),
+            Fq::from_montgomery_limbs([
+                16403189386093722587,
+                12784072312935682354,
+                6922935854468718311,
+                193164242810929719,
+            ]),
+            Fq::from_montgomery_limbs([
+                4444348589668759965,
+                17108356802270620290,
+                16050834752517964580,
+                846311008745332342,
+            ]),
+            Fq::from_montgomery_limbs([
+                13903761291092746438,
+                6894644252625324970,
+                9760553778863800953,
+                1030275380814875480,
+            ]),
+            Fq::from_montgomery_limbs([
+                4306052948724163686,
+                7398580313121214757,
+                3423055037603456942,
+                1037823546773362695,
+            ]),
+            Fq::from_montgomery_limbs([
+                4042158914499001811,
+                9798740327849964981,
+                17416488778372751730,
+                28717568064495275,
+            ]),
+            Fq::from_montgomery_limbs([
+                7695964786014981923,
+                11545781302461020665,
+                1731470861569130334,
+                1321136078674895915,
+            ]),
+            Fq::from_montgomery_limbs([
+                9250758370133889964,
+                12158474784513283703,
+                5951796756438265180,
+                1041492921263316225,
+            ]),
+            Fq::from_montgomery_limbs([
+                2449265364538002370,
+                8899428802052848706,
+                9132763277959810061,
+                76084103726588858,
+            ]),
+            Fq::from_montgomery_limbs([
+                1442964508291926659,
+                16145749477197190495,
+                1614267904678021368,
+                764361051009405396,
+            ]),
+            Fq::from_montgomery_limbs([
+                11420024190411081019,
+                13379862485435312472,
+                7813024480170117421,
+                1245001529997983518,
+            ]),
+            Fq::from_montgomery_limbs([
+                6474145231600802143,
+                17474811379969579501,
+                5093480027306380428,
+                279688197947663258,
+            ]),
+            Fq::from_montgomery_limbs([
+                17949675395204567756,
+                6465709251522846547,
+                13918174518882232788,
+                994442257629449317,
+            ]),
+            Fq::from_montgomery_limbs([
+                17356154990734677978,
+                5808483639416847415,
+                10646456502664103159,
+                819949059412930265,
+            ]),
+            Fq::from_montgomery_limbs([
+                15432466829578774969,
+                12996210410037075829,
+                14799584536208903421,
+                1310041361701149528,
+            ]),
+            Fq::from_montgomery_limbs([
+                5405054989015298671,
+                3958390759502409567,
+                2014985457027948527,
+                433503525028308895,
+            ]),
+            Fq::from_montgomery_limbs([
+                615783755558410953,
+                1483425713723130718,
+                1701768734265559963,
+                1073981516284121037,
+            ]),
+            Fq::from_montgomery_limbs([
+                1199184013348074012,
+                1834851048339423552,
+                14421081338273741401,
+                962079979120339133,
+            ]),
+            Fq::from_montgomery_limbs([
+                12871321370472504514,
+                16216137920022301146,
+                3233538074502451687,
+                1219437971985805194,
+            ]),
+            Fq::from_montgomery_limbs([
+                16212344218950628660,
+                14340248318261942885,
+                7590398493723387817,
+                768789765968271596,
+            ]),
+            Fq::from_montgomery_limbs([
+                4546853675332872363,
+                17527098204260017534,
+                216691692375543857,
+                924369062392261073,
+            ]),
+            Fq::from_montgomery_limbs([
+                6358247242093244915,
+                6255699414453307935,
+                18154572248856664004,
+                377024028481603780,
+            ]),
+            Fq::from_montgomery_limbs([
+                8207323012142772050,
+                3704796798401683377,
+                7723044616180930248,
+                1049612270481716691,
+            ]),
+            Fq::from_montgomery_limbs([
+                15856433596114035357,
+                16056296614605872642,
+                2021862276403119341,
+                186772247812306294,
+            ]),
+            Fq::from_montgomery_limbs([
+                6932823582004939847,
+                7967613924709751725,
+                7269567464163874588,
+                819909995381173238,
+            ]),
+            Fq::from_montgomery_limbs([
+                3312300591112083216,
+                7994458855356119128,
+                6036897993540054611,
+                514823798717655671,
+            ]),
+            Fq::from_montgomery_limbs([
+                550661212043128550,
+                10111877393614969600,
+                8010799028700044140,
+                648907063726991765,
+            ]),
+            Fq::from_montgomery_limbs([
+                10054968677474240478,
+                2243256454696311711,
+                4597811356936343766,
+                774702379952675388,
+            ]),
+            Fq::from_montgomery_limbs([
+                7029409860035050956,
+                17878844889587744556,
+                12245543777008516581,
+                925409974016202110,
+            ]),
+            Fq::from_montgomery_limbs([
+                2864858482817667585,
+                13085277673248191634,
+                1920305407333510541,
+                433276010029534063,
+            ]),
+            Fq::from_montgomery_limbs([
+                1299022537086705261,
+                9971159833642375416,
+                16068329696462206228,
+                1311462944619573129,
+            ]),
+            Fq::from_montgomery_limbs([
+                8388349917371451358,
+                1652755649977772061,
+                4060767515796553588,
+                313333837511934899,
+            ]),
+            Fq::from_montgomery_limbs([
+                14483776648325961166,
+                8379700385518555054,
+                12069528738584610223,
+                549935500967547207,
+            ]),
+            Fq::from_montgomery_limbs([
+                8286588979764371188,
+                10309322226564225164,
+                6406329881924645976,
+                492406523466141638,
+            ]),
+            Fq::from_montgomery_limbs([
+                1455883357161210395,
+                12297631684038038535,
+                10863718707294200770,
+                573314447188578419,
+            ]),
+            Fq::from_montgomery_limbs([
+                8242057745048862812,
+                17769271867536683007,
+                4994381080901574033,
+                1171473881913290379,
+            ]),
+            Fq::from_montgomery_limbs([
+                16556254605204724163,
+                9031814154693837588,
+                2222725723213292047,
+                811549642629809359,
+            ]),
+            Fq::from_montgomery_limbs([
+                16522086139454310494,
+                1586376061522817940,
+                7027283279913463724,
+                389217268641169192,
+            ]),
+            Fq::from_montgomery_limbs([
+                1148996437956955335,
+                741553004337834059,
+                439686668431144595,
+                610727576959585946,
+            ]),
+            Fq::from_montgomery_limbs([
+                5807757862918524852,
+                17175762950584267165,
+                15812862496181965572,
+                1148924852209043819,
+            ]),
+            Fq::from_montgomery_limbs([
+                703201589215884109,
+                10408165082995615921,
+                12274306379753118172,
+                371846305946128581,
+            ]),
+            Fq::from_montgomery_limbs([
+                3557097085895284584,
+                6575543295354294103,
+                2817748048030657896,
+                27898149100698221,
+            ]),
+            Fq::from_montgomery_limbs([
+                16571212594144887319,
+                3990053493667958324,
+                13715106329848368522,
+                725170136717847023,
+            ]),
+            Fq::from_montgomery_limbs([
+                9381810441628343950,
+                3977286581298700733,
+                32142885902907169,
+                389708071264640584,
+            ]),
+            Fq::from_montgomery_limbs([
+                14831168380880149563,
+                16084839134199965547,
+                14026892878257343208,
+                599104835867249597,
+            ]),
+            Fq::from_montgomery_limbs([
+                4724472453573552345,
+                13668972786981374735,
+                3347664426226317421,
+                255853131382892093,
+            ]),
+            Fq::from_montgomery_limbs([
+                17274395516412553298,
+                6381891142282177010,
+                14720339254080384111,
+                507764911233135134,
+            ]),
+            Fq::from_montgomery_limbs([
+                12733112113382583251,
+                5476657763001374567,
+                1875052761962176713,
+                510546364632807639,
+            ]),
+            Fq::from_montgomery_limbs([
+                13751966485170968656,
+                10656164623288245582,
+                1596361345199769151,
+                964723876919620574,
+            ]),
+            Fq::from_montgomery_limbs([
+                2393804003135540319,
+                8376309491746970762,
+                18093273106100567797,
+                404261559969061659,
+            ]),
+            Fq::from_montgomery_limbs([
+                9011663692578511172,
+                5373274670431365743,
+                2766049605670671537,
+                529095970279697018,
+            ]),
+            Fq::from_montgomery_limbs([
+                16034114643814727287,
+                3263168708854894847,
+                8952048723568895093,
+                25928391378053578,
+            ]),
+            Fq::from_montgomery_limbs([
+                13033245017622057201,
+                4334003473119449566,
+                16074138237042527659,
+                220592774962458522,
+            ]),
+            Fq::from_montgomery_limbs([
+                4715686314527461215,
+                10461095328063606564,
+                1899561508324168493,
+                1024991119536207156,
+            ]),
+            Fq::from_montgomery_limbs([
+                14491683419604757693,
+                5136050715547108817,
+                1642175417301782301,
+                30769501239574590,
+            ]),
+            Fq::from_montgomery_limbs([
+                4902269484535341742,
+                12129200688006424583,
+                15865887377725042830,
+                247287569640968355,
+            ]),
+            Fq::from_montgomery_limbs([
+                16369616112109484571,
+                5178286253248872797,
+                2784173738695934448,
+                459286006746516009,
+            ]),
+            Fq::from_montgomery_limbs([
+                4959293196893793403,
+                2365971872997678829,
+                13434955604433225012,
+                1270158101330193762,
+            ]),
+            Fq::from_montgomery_limbs([
+                7750245670627750786,
+                4401091265090842423,
+                9252735385629705227,
+                9805137377382822,
+            ]),
+            Fq::from_montgomery_limbs([
+                8484571962775293759,
+                18383745135197468634,
+                2333756795309354694,
+                418336794381640322,
+            ]),
+            Fq::from_montgomery_limbs([
+                8462034793931840707,
+                1550205753039443425,
+                681583184739590034,
+                562980675828434873,
+            ]),
+            Fq::from_montgomery_limbs([
+                15633688112916823719,
+                11405016007687429928,
+                9545096447680777097,
+                486582595488889232,
+            ]),
+            Fq::from_montgomery_limbs([
+                16771471282872134470,
+                9150613520103008442,
+                6847813014345031372,
+                477382904847206590,
+            ]),
+            Fq::from_montgomery_limbs([
+                15594853201938210720,
+                7246068001445510237,
+                9542078789033672276,
+                885468261554542755,
+            ]),
+            Fq::from_montgomery_limbs([
+                15510929136102385544,
+                2451234549868224113,
+                9155304694970509026,
+                978681389384533440,
+            ]),
+            Fq::from_montgomery_limbs([
+                5046038587346821997,
+                6046993411975496893,
+                9950231102007198737,
+                1087171146223362278,
+            ]),
+            Fq::from_montgomery_limbs([
+                1210483819231532640,
+                2844747517042557984,
+                16651014756501845761,
+                1193994704352005193,
+            ]),
+            Fq::from_montgomery_limbs([
+                12268062642867147755,
+                9817832361514591043,
+                15882086707771201133,
+                712579458783289902,
+            ]),
+            Fq::from_montgomery_limbs([
+                15991958342248776125,
+                14877444806377384453,
+                2161499415276881310,
+                1299864106405514515,
+            ]),
+            Fq::from_montgomery_limbs([
+                18272806882701980248,
+                13158670053935785120,
+                3928577248229257933,
+                1305146352391360028,
+            ]),
+            Fq::from_montgomery_limbs([
+                2035926386267771219,
+                14487865293228075938,
+                4870042469499477058,
+                193366194208190835,
+            ]),
+            Fq::from_montgomery_limbs([
+                561189494356445195,
+                3987100309250835405,
+                17345811685678323077,
+                141608245542633681,
+            ]),
+            Fq::from_montgomery_limbs([
+                8378702700681520584,
+                6211463117563370472,
+                1837043714190957422,
+                911926896459542858,
+            ]),
+            Fq::from_montgomery_limbs([
+                1477864022749523099,
+                1797146931401057351,
+                10930507867627157650,
+                551136961163740589,
+            ]),
+            Fq::from_montgomery_limbs([
+                16076885957569981881,
+                6189086307897032200,
+                321425626204365967,
+                1077310328790016289,
+            ]),
+            Fq::from_montgomery_limbs([
+                7522837608117648005,
+                9866758236394487831,
+                13873940643045984432,
+                790926675077279725,
+            ]),
+            Fq::from_montgomery_limbs([
+                1068652733078479138,
+                15139672386963459247,
+                17509830412473469233,
+                915931482757473531,
+            ]),
+            Fq::from_montgomery_limbs([
+                12542800187191765043,
+                3838533156577214095,
+                6027747947356449983,
+                671725481869443718,
+            ]),
+            Fq::from_montgomery_limbs([
+                12969149510012913663,
+                4018525628649245505,
+                847265587946287627,
+                695726491653141823,
+            ]),
+            Fq::from_montgomery_limbs([
+                3569908830254286341,
+                11594451829999442168,
+                9346970170986532763,
+                592913866839907318,
+            ]),
+            Fq::from_montgomery_limbs([
+                15399744716583407297,
+                15712904863395855644,
+                15918588893683727846,
+                45916340716771599,
+            ]),
+            Fq::from_montgomery_limbs([
+                14598118716249044464,
+                13022479745498586482,
+                14096729042192919956,
+                1209032889985192109,
+            ]),
+            Fq::from_montgomery_limbs([
+                2142839249660344828,
+                10776418004239909643,
+                4099798848919917362,
+                1226951814743963734,
+            ]),
+            Fq::from_montgomery_limbs([
+                12856903695655283684,
+                4705750046088963379,
+                4564035744142681406,
+                446050768008800404,
+            ]),
+            Fq::from_montgomery_limbs([
+                4673085275264079991,
+                12335995436162040280,
+                1588669303971786364,
+                1219092495231709609,
+            ]),
+            Fq::from_montgomery_limbs([
+                12034274847693610088,
+                3244998784905830599,
+                6478315637843837539,
+                983575547967480448,
+            ]),
+            Fq::from_montgomery_limbs([
+                1882156823273102101,
+                8831120286965295426,
+                16087137145925928237,
+                1234058826192838671,
+            ]),
+            Fq::from_montgomery_limbs([
+                2944401031799256140,
+                10152026557708919899,
+                17827765828557522667,
+                463634704352915960,
+            ]),
+            Fq::from_montgomery_limbs([
+                10410868764659378155,
+                16121441109914137770,
+                1854085042447846168,
+                1158077401520629585,
+            ]),
+            Fq::from_montgomery_limbs([
+                8946955766477587200,
+                13146194420396118997,
+                10741004400669861841,
+                946841094568930751,
+            ]),
+            Fq::from_montgomery_limbs([
+                10608683031405556797,
+                8446113334548262514,
+                1598651954538886437,
+                269649230963262410,
+            ]),
+            Fq::from_montgomery_limbs([
+                13255183325710112717,
+                3389390050892692299,
+                8285528803290425787,
+                1296225730725610859,
+            ]),
+            Fq::from_montgomery_limbs([
+                14245828220603705113,
+                15270693610472048998,
+                12669957858305850851,
+                318203398806330906,
+            ]),
+            Fq::from_montgomery_limbs([
+                13976105499694309433,
+                14732396513624170152,
+                13124188636709348478,
+                567594149596740355,
+            ]),
+            Fq::from_montgomery_limbs([
+                12375629736456901071,
+                9560812418729302949,
+                16743214211617157560,
+                353724506241869339,
+            ]),
+            Fq::from_montgomery_limbs([
+                15344439297226405117,
+                7578516075434080922,
+                1939051413860809991,
+                718990761541651034,
+            ]),
+            Fq::from_montgomery_limbs([
+                7023187463770371246,
+                10238288774064491607,
+                4780443652792402129,
+                960466521486331107,
+            ]),
+            Fq::from_montgomery_limbs([
+                11078287688739600162,
+                7063580205855473149,
+                8293056762439772424,
+                1094975449470834721,
+            ]),
+            Fq::from_montgomery_limbs([
+                2629129406347748744,
+                11709280436245140114,
+                10574472385732370042,
+                559274751147451736,
+            ]),
+            Fq::from_montgomery_limbs([
+                14351098061333981535,
+                15226160070786715071,
+                11066390022699249835,
+                991532126797749356,
+            ]),
+            Fq::from_montgomery_limbs([
+                9727991074592687429,
+                17092436231465113149,
+                13408044968782306741,
+                1105032517789622309,
+            ]),
+            Fq::from_montgomery_limbs([
+                4357165655759484223,
+                6021411741198968453,
+                18045956090300432155,
+                138915923752032588,
+            ]),
+            Fq::from_montgomery_limbs([
+                8172232466106845275,
+                8152918675315082414,
+                8259660744556010780,
+                200688000140472582,
+            ]),
+            Fq::from_montgomery_limbs([
+                758564844688191706,
+                12950130689109236541,
+                16189476296144060141,
+                318624348733489333,
+            ]),
+            Fq::from_montgomery_limbs([
+                11238950774318969979,
+                2983347334669022015,
+                9440949574963165868,
+                790420163083355808,
+            ]),
+            Fq::from_montgomery_limbs([
+                9452252023106396349,
+                4533176847261980953,
+                2039818800751948877,
+                1232107368510041504,
+            ]),
+            Fq::from_montgomery_limbs([
+                16258726272387334369,
+                796719619892254452,
+                13458853216125105671,
+                387578433439639464,
+            ]),
+            Fq::from_montgomery_limbs([
+                13911036410198793491,
+                16006290479184858612,
+                14500288289161127482,
+                584055669984978997,
+            ]),
+            Fq::from_montgomery_limbs([
+                2544005554877757380,
+                3453005637686364485,
+                4825226064055118577,
+                729015376517667126,
+            ]),
+            Fq::from_montgomery_limbs([
+                18375409949835325157,
+                11015708168555406173,
+                1964562592704164362,
+                840958353707472493,
+            ]),
+            Fq::from_montgomery_limbs([
+                17406252604452025428,
+                6094036225502909992,
+                2956939367835014150,
+                44189180746996437,
+            ]),
+            Fq::from_montgomery_limbs([
+                4369235756159662280,
+                12586400722257011753,
+                10881754468821874247,
+                506980412463087395,
+            ]),
+            Fq::from_montgomery_limbs([
+                8785364524494710134,
+                4734342488472459340,
+                11617187158945307562,
+                12394494316102004,
+            ]),
+            Fq::from_montgomery_limbs([
+                10536438503678650825,
+                16004788242069964058,
+                12501293726458240591,
+                599240361793922746,
+            ]),
+            Fq::from_montgomery_limbs([
+                3169088006781901875,
+                5375065405574804745,
+                12119823529434554794,
+                363599155504795167,
+            ]),
+            Fq::from_montgomery_limbs([
+                906053570287625982,
+                3602439772308817929,
+                1833296503033091083,
+                1312567349392892230,
+            ]),
+            Fq::from_montgomery_limbs([
+                16644550191347787601,
+                3235608026713538077,
+                10789992828387055593,
+                686156380625017041,
+            ]),
+            Fq::from_montgomery_limbs([
+                2333059030034260625,
+                6889406200925189854,
+                14163828133192331352,
+                125761582164926576,
+            ]),
+            Fq::from_montgomery_limbs([
+                4620232013032221771,
+                15636705420653556325,
+                9932068016378104716,
+                1283040563119178163,
+            ]),
+            Fq::from_montgomery_limbs([
+                13597615386023108531,
+                3674643581936701439,
+                1488912081213124350,
+                206560358188962870,
+            ]),
+            Fq::from_montgomery_limbs([
+                12031720285611574966,
+                8652449127969179543,
+                4547302515913890719,
+                806318431973613572,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204807190387887449,
+                7839911808329681318,
+                6546256384642684974,
+                898627360988048498,
+            ]),
+            Fq::from_montgomery_limbs([
+                13875789123100216813,
+                5574845665644701876,
+                17033369670463924149,
+                1046448238625222156,
+            ]),
+            Fq::from_montgomery_limbs([
+                7472625974479596988,
+                4832556093651466316,
+                14226175243237833367,
+                941692068756441313,
+            ]),
+            Fq::from_montgomery_limbs([
+                11762535857860587612,
+                17857603126932959452,
+                13669303505263452997,
+                927575299907735215,
+            ]),
+            Fq::from_montgomery_limbs([
+                3594723794296955242,
+                13945772434898600526,
+                11920322178207715564,
+                158833750403593485,
+            ]),
+            Fq::from_montgomery_limbs([
+                17359821746727998948,
+                5170815189668985537,
+                8564668026677738902,
+                500285178229299603,
+            ]),
+            Fq::from_montgomery_limbs([
+                10969393573207961319,
+                6675844937224772673,
+                2417972241620589043,
+                689544845807034688,
+            ]),
+            Fq::from_montgomery_limbs([
+                5621232396049853609,
+                18376009848463529566,
+                6041023307694374654,
+                1315249158047436543,
+            ]),
+            Fq::from_montgomery_limbs([
+                5945418145872359094,
+                5319101964686295767,
+                16839488032375835614,
+                740518867477296972,
+            ]),
+            Fq::from_montgomery_limbs([
+                8178259356895852377,
+                6383524494737424827,
+                3122552042256939971,
+                56059744714384629,
+            ]),
+            Fq::from_montgomery_limbs([
+                11354283135267563500,
+                17158177856570858483,
+                7678656897647308619,
+                808276869428549133,
+            ]),
+            Fq::from_montgomery_limbs([
+                1883476907308696078,
+                303015203373073081,
+                2097722130904003012,
+                983319021825489065,
+            ]),
+            Fq::from_montgomery_limbs([
+                812783122483903458,
+                11329556748794278001,
+                17739635544157528733,
+                1188863702647093508,
+            ]),
+            Fq::from_montgomery_limbs([
+                2883027938128769772,
+                3579143081837746102,
+                5076764950468237413,
+                1186438190464236930,
+            ]),
+            Fq::from_montgomery_limbs([
+                4694624697339723373,
+                14147978288165433114,
+                14682642344473341284,
+                659600807040250027,
+            ]),
+            Fq::from_montgomery_limbs([
+                14335446662882925116,
+                18034608529060943644,
+                17905599692398295064,
+                27620333060672079,
+            ]),
+            Fq::from_montgomery_limbs([
+                16105505112233102423,
+                18089700449903582044,
+                5394371834567460581,
+                1248666387577939760,
+            ]),
+            Fq::from_montgomery_limbs([
+                11485005319325558344,
+                7274978079720354081,
+                17649079285238235318,
+                630309408163288029,
+            ]),
+            Fq::from_montgomery_limbs([
+                6583934748453888231,
+                16865134912007881211,
+                3592486135237202953,
+                1237532598686351810,
+            ]),
+            Fq::from_montgomery_limbs([
+                14486544900886292233,
+                12086656098163452976,
+                3821255792872599495,
+                660759688716676066,
+            ]),
+            Fq::from_montgomery_limbs([
+                9530410077633359381,
+                846143075090712471,
+                11021626119818274194,
+                182141121478834067,
+            ]),
+            Fq::from_montgomery_limbs([
+                16852161196337448374,
+                5189053762117292337,
+                15439615126153900327,
+                658546513152339049,
+            ]),
+            Fq::from_montgomery_limbs([
+                4215698867331968699,
+                17165417478193176655,
+                10396586968768893714,
+                1331975726021778279,
+            ]),
+            Fq::from_montgomery_limbs([
+                13948449042500011386,
+                11071755137437565230,
+                13585135047693717582,
+                115145175425561459,
+            ]),
+            Fq::from_montgomery_limbs([
+                17818882770376465318,
+                806313384517995236,
+                10642153361176736692,
+                437822215244498997,
+            ]),
+            Fq::from_montgomery_limbs([
+                18011755294268129362,
+                6796093528230891084,
+                13014123891846910438,
+                68411329204033251,
+            ]),
+            Fq::from_montgomery_limbs([
+                16893966336468272384,
+                4818642608683534020,
+                13424179192371073054,
+                927081180746833517,
+            ]),
+            Fq::from_montgomery_limbs([
+                2601589229150532757,
+                8187775754238696567,
+                1466818951627034108,
+                720841448727718370,
+            ]),
+            Fq::from_montgomery_limbs([
+                2966003896025475489,
+                5933313139920833113,
+                17441969624958465818,
+                350723696982852502,
+            ]),
+            Fq::from_montgomery_limbs([
+                5233602055722469007,
+                10997421643426300120,
+                11273768652175215750,
+                1274820077222273384,
+            ]),
+            Fq::from_montgomery_limbs([
+                10513467595617585762,
+                15496492418188550067,
+                15772841247665917857,
+                233999344094167226,
+            ]),
+            Fq::from_montgomery_limbs([
+                5435409649114827376,
+                374366149103524515,
+                3661990335403860499,
+                376271459774102256,
+            ]),
+            Fq::from_montgomery_limbs([
+                13909489127501615037,
+                9765510987326576116,
+                10944558167635419277,
+                713921321596478239,
+            ]),
+            Fq::from_montgomery_limbs([
+                5571419136319420820,
+                14510810327334196492,
+                11668150472848376271,
+                280393535238848091,
+            ]),
+            Fq::from_montgomery_limbs([
+                9862437562480417320,
+                14786241296894424722,
+                12625413598750748529,
+                36255948812274435,
+            ]),
+            Fq::from_montgomery_limbs([
+                14520515964126880754,
+                15985928642257909172,
+                4527295086472356685,
+                493999365363402306,
+            ]),
+            Fq::from_montgomery_limbs([
+                2457260717011137469,
+                10476764268453367840,
+                11935291724687395993,
+                552416114272662891,
+            ]),
+            Fq::from_montgomery_limbs([
+                16466657476490162841,
+                6577879108471102295,
+                15596835583893835223,
+                317148986817226459,
+            ]),
+            Fq::from_montgomery_limbs([
+                258755445641365590,
+                12280357598090603351,
+                11513474130717533417,
+                1253399151907960468,
+            ]),
+            Fq::from_montgomery_limbs([
+                7331788868698713679,
+                12411183855395154510,
+                16946504571342004802,
+                873615004248801306,
+            ]),
+            Fq::from_montgomery_limbs([
+                8794163135920214058,
+                11647897792221400264,
+                4500911599290468686,
+                1332342568126295739,
+            ]),
+            Fq::from_montgomery_limbs([
+                13998110581432174936,
+                11890745203113695702,
+                3879208124559167534,
+                1246619664076316781,
+            ]),
+            Fq::from_montgomery_limbs([
+                9982401323665275165,
+                16597047424027694346,
+                4787413270410382730,
+                1216654323926089579,
+            ]),
+            Fq::from_montgomery_limbs([
+                15465180887211912850,
+                10291682057313077586,
+                13169322259146846312,
+                983844071332345699,
+            ]),
+            Fq::from_montgomery_limbs([
+                6593159750276032872,
+                1049898127383787411,
+                17264087857956517781,
+                733125102420769892,
+            ]),
+            Fq::from_montgomery_limbs([
+                3360498755895877516,
+                6765453461292201629,
+                13938033745482556984,
+                80455616048331113,
+            ]),
+            Fq::from_montgomery_limbs([
+                6509336978774560135,
+                8222226653907918164,
+                9499722294586313489,
+                1115437468671085093,
+            ]),
+            Fq::from_montgomery_limbs([
+                496537003058864738,
+                15170784086485189043,
+                1865241856011955887,
+                294813210303896895,
+            ]),
+            Fq::from_montgomery_limbs([
+                4394496933231428812,
+                4462558011171944839,
+                15145675688750261537,
+                430222995817257639,
+            ]),
+            Fq::from_montgomery_limbs([
+                9060967866438085061,
+                3452943209061273936,
+                14628789881897752952,
+                1215564475819598481,
+            ]),
+            Fq::from_montgomery_limbs([
+                11501748438950214310,
+                16586897331283579857,
+                521241537932095881,
+                386828703277976018,
+            ]),
+            Fq::from_montgomery_limbs([
+                14372620087491953553,
+                1840006731903591729,
+                16370810289422107573,
+                590030065479647004,
+            ]),
+            Fq::from_montgomery_limbs([
+                10589620756275322989,
+                6813224617377070042,
+                15571680383002477307,
+                385739390523037213,
+            ]),
+            Fq::from_montgomery_limbs([
+                3643816146697107482,
+                16072511103221841002,
+                9902507660309000527,
+                236321586519809608,
+            ]),
+            Fq::from_montgomery_limbs([
+                8099881108583680905,
+                10325750449827980385,
+                17226022556056880622,
+                772311579475982498,
+            ]),
+            Fq::from_montgomery_limbs([
+                17194338667346455122,
+                4609846236349624621,
+                14547748911032745950,
+                134767214784829672,
+            ]),
+            Fq::from_montgomery_limbs([
+                8053702071452500229,
+                17672263354509635251,
+                16673104088422795543,
+                841389459891335131,
+            ]),
+            Fq::from_montgomery_limbs([
+                1545276568770749647,
+                2232523632918763652,
+                11060963800378493685,
+                379251703967595142,
+            ]),
+            Fq::from_montgomery_limbs([
+                17035293807257174014,
+                5586089506263819911,
+                3468659808150211624,
+                997354646257422294,
+            ]),
+            Fq::from_montgomery_limbs([
+                1172260908850145595,
+                3213715039687048073,
+                7583028689833137400,
+                1195251838418699500,
+            ]),
+            Fq::from_montgomery_limbs([
+                17894110132347511188,
+                13457244956832165705,
+                4291988050067872475,
+                1134390103006493157,
+            ]),
+            Fq::from_montgomery_limbs([
+                6706512871673091532,
+                17265523656339235387,
+                6184138509268703012,
+                652028308918242294,
+            ]),
+            Fq::from_montgomery_limbs([
+                10480924029779691395,
+                15308868429573358325,
+                4048040831190253395,
+                507041535680407416,
+            ]),
+            Fq::from_montgomery_limbs([
+                17660677737665173163,
+                15994374347746464277,
+                4637730689043419944,
+                638502337298506704,
+            ]),
+            Fq::from_montgomery_limbs([
+                2309373083034116840,
+                7013027448916897845,
+                16049478480365480931,
+                1249987953003113532,
+            ]),
+            Fq::from_montgomery_limbs([
+                287306334842626921,
+                5265075989674097020,
+                11505621491813439863,
+                431494935985655406,
+            ]),
+            Fq::from_montgomery_limbs([
+                4442585663494404652,
+                13798181239307909825,
+                89105917838341213,
+                1097898490593215794,
+            ]),
+            Fq::from_montgomery_limbs([
+                7499002846992782523,
+                8627311848776636828,
+                3908363034916789856,
+                359004357016062724,
+            ]),
+            Fq::from_montgomery_limbs([
+                11344584882923107787,
+                6658674778071059030,
+                17751864031875458614,
+                600726383578404618,
+            ]),
+            Fq::from_montgomery_limbs([
+                2586697925530378848,
+                15545298152988359516,
+                17976661979229799448,
+                362406544770624059,
+            ]),
+            Fq::from_montgomery_limbs([
+                7752497334975279133,
+                6213139559136607062,
+                11458833195979502350,
+                538863930378731177,
+            ]),
+            Fq::from_montgomery_limbs([
+                14024017766784494994,
+                2667713437661546140,
+                16065746480394068605,
+                97645803162959555,
+            ]),
+            Fq::from_montgomery_limbs([
+                3032147218906460268,
+                5058522732443682217,
+                2219772673532698958,
+                150621468666492378,
+            ]),
+            Fq::from_montgomery_limbs([
+                17460743432208710106,
+                16691203746224983721,
+                11166766542289762889,
+                39121395786389402,
+            ]),
+            Fq::from_montgomery_limbs([
+                17126703142152339250,
+                9194707909557409121,
+                7490779453910855434,
+                223150137473851456,
+            ]),
+            Fq::from_montgomery_limbs([
+                9353140246686315761,
+                3197824445711886079,
+                12363023385971585232,
+                222646183204689714,
+            ]),
+            Fq::from_montgomery_limbs([
+                9056340674396718816,
+                9191601062733278351,
+                17678004872281828060,
+                1323513755411678773,
+            ]),
+            Fq::from_montgomery_limbs([
+                9412201324466702436,
+                11360631261452320908,
+                4897947251507596364,
+                1190200409024643739,
+            ]),
+            Fq::from_montgomery_limbs([
+                4746341536317940548,
+                5990930204823555857,
+                13218578404079027709,
+                1248629702051199222,
+            ]),
+            Fq::from_montgomery_limbs([
+                5412560528786081069,
+                18022501658147295427,
+                12612460239986306050,
+                795892608139388487,
+            ]),
+            Fq::from_montgomery_limbs([
+                11315723060914649379,
+                3079479705549702089,
+                12471907933806184683,
+                1280656042452718580,
+            ]),
+            Fq::from_montgomery_limbs([
+                6285123947181717516,
+                12294587353976768591,
+                14997990574023284312,
+                940413702414751232,
+            ]),
+            Fq::from_montgomery_limbs([
+                8394942942923770658,
+                10589475567760368244,
+                4987348235627928392,
+                48324519489045815,
+            ]),
+            Fq::from_montgomery_limbs([
+                13581689577954861888,
+                9958926930053144026,
+                1243191982323085063,
+                1345261057448900310,
+            ]),
+            Fq::from_montgomery_limbs([
+                8018738443719845976,
+                5436985039259258427,
+                642552234098720271,
+                786189149532257232,
+            ]),
+            Fq::from_montgomery_limbs([
+                17624759765459457842,
+                5490704996359581459,
+                15345783217774043233,
+                133925716541224649,
+            ]),
+            Fq::from_montgomery_limbs([
+                3232801700531887913,
+                5006855454765361576,
+                4421285949883804731,
+                36992176594320934,
+            ]),
+            Fq::from_montgomery_limbs([
+                11886751876051442072,
+                9769166756828632013,
+                8832004451209083220,
+                1239838521422301603,
+            ]),
+            Fq::from_montgomery_limbs([
+                1869472012635061085,
+                6982559072953489189,
+                8729290393197490485,
+                305217165270235047,
+            ]),
+            Fq::from_montgomery_limbs([
+                1638526610617424045,
+                16568113764068918996,
+                4271432408785318646,
+                619368372626105266,
+            ]),
+            Fq::from_montgomery_limbs([
+                17828249058149030438,
+                4154239032445167855,
+                14045771388431621098,
+                805416544990847831,
+            ]),
+            Fq::from_montgomery_limbs([
+                794928292106948534,
+                11073778702756842460,
+                11590570199614550397,
+                453740720736209443,
+            ]),
+            Fq::from_montgomery_limbs([
+                5598667878133684246,
+                14500280939102471965,
+                13003200686904562881,
+                84314511410444090,
+            ]),
+            Fq::from_montgomery_limbs([
+                10992445251144854193,
+                14873305031121990228,
+                16998450639850325902,
+                797651183182402135,
+            ]),
+            Fq::from_montgomery_limbs([
+                5398984828530370176,
+                11673106470333587130,
+                5890697881690816465,
+                817713634111387698,
+            ]),
+            Fq::from_montgomery_limbs([
+                15254847493636685156,
+                10093542403657533252,
+                13333896343421006676,
+                1137189034727920915,
+            ]),
+            Fq::from_montgomery_limbs([
+                5188611932366626439,
+                1825825348641952542,
+                5636826247181787904,
+                589979407514727359,
+            ]),
+            Fq::from_montgomery_limbs([
+                4254172054694378118,
+                7360086518347432696,
+                9263006381465754863,
+                370155135614548575,
+            ]),
+            Fq::from_montgomery_limbs([
+                1945841263702980399,
+                4358299002895293026,
+                13416858416992270215,
+                789305626308013968,
+            ]),
+            Fq::from_montgomery_limbs([
+                14916904790901110345,
+                10794027284923854731,
+                18287457065518452646,
+                1020783655288093247,
+            ]),
+            Fq::from_montgomery_limbs([
+                5998117733076824647,
+                13186793574874002347,
+                10877091263552564503,
+                366624963077665272,
+            ]),
+            Fq::from_montgomery_limbs([
+                14957290856190698027,
+                1817555610538001139,
+                10619780942629413629,
+                156930787106807785,
+            ]),
+            Fq::from_montgomery_limbs([
+                9432025522033696768,
+                12062892720540583034,
+                8575273391680002811,
+                1035643416083400618,
+            ]),
+            Fq::from_montgomery_limbs([
+                13518565549207327565,
+                10358036952313611541,
+                14746017618863577779,
+                473803439582904381,
+            ]),
+            Fq::from_montgomery_limbs([
+                16223916160380111756,
+                10326730827930978469,
+                6857916318572490043,
+                732448093060678049,
+            ]),
+            Fq::from_montgomery_limbs([
+                6303589318519741326,
+                14449416942794767344,
+                10320043311443462586,
+                1041727804247963619,
+            ]),
+            Fq::from_montgomery_limbs([
+                4399157332073245358,
+                2950141682970966831,
+                3696731802621157399,
+                901696623522630684,
+            ]),
+            Fq::from_montgomery_limbs([
+                12560203889416900496,
+                895165303728796581,
+                10174643751596721871,
+                583639131684371630,
+            ]),
+            Fq::from_montgomery_limbs([
+                11023775026354205623,
+                11169737544783274661,
+                11940198135483730928,
+                679763920774075647,
+            ]),
+            Fq::from_montgomery_limbs([
+                6914655485839727369,
+                10573969194095572771,
+                9608867332612902000,
+                307319586811603592,
+            ]),
+            Fq::from_montgomery_limbs([
+                9582336708025245608,
+                870928264672747650,
+                5181546031369964704,
+                193154118088956915,
+            ]),
+            Fq::from_montgomery_limbs([
+                1736922270243267529,
+                14295379944067358475,
+                12140728213530822628,
+                1053369914422534925,
+            ]),
+            Fq::from_montgomery_limbs([
+                14366402998251886528,
+                11097591400721930065,
+                11698795101784793828,
+                694085737150978551,
+            ]),
+            Fq::from_montgomery_limbs([
+                9635615122491008272,
+                11574456220226329034,
+                14020669832878026551,
+                242021539238766722,
+            ]),
+            Fq::from_montgomery_limbs([
+                7169625346694254797,
+                5536218470749318985,
+                7575035677192264706,
+                403481290997580896,
+            ]),
+            Fq::from_montgomery_limbs([
+                7037466538603165069,
+                12528036234529468606,
+                10691442333948872208,
+                1162941285672424468,
+            ]),
+            Fq::from_montgomery_limbs([
+                16186927630380345129,
+                3100771406531874384,
+                15560684174865478913,
+                793797628069713429,
+            ]),
+            Fq::from_montgomery_limbs([
+                17027422759471654037,
+                15315428998511398058,
+                7156794061103613005,
+                252876827450033403,
+            ]),
+            Fq::from_montgomery_limbs([
+                12011467595110531289,
+                9261630528339711828,
+                6362802218043859657,
+                1274255344095926308,
+            ]),
+            Fq::from_montgomery_limbs([
+                17256218633851943288,
+                7340626169170929617,
+                4045894614753472545,
+                117370838367433268,
+            ]),
+            Fq::from_montgomery_limbs([
+                1940586057930556906,
+                3434227479031343892,
+                993453383195206298,
+                24385000924864761,
+            ]),
+            Fq::from_montgomery_limbs([
+                1038324103384638955,
+                10674601288940900733,
+                10284495318219736606,
+                1001320758252003575,
+            ]),
+            Fq::from_montgomery_limbs([
+                10572551308611633156,
+                5432310797087618215,
+                16495684963415140448,
+                384491981631033054,
+            ]),
+            Fq::from_montgomery_limbs([
+                11838211203321056222,
+                17343481411847420973,
+                2854494973132285864,
+                859797803410458337,
+            ]),
+            Fq::from_montgomery_limbs([
+                3771067433614884471,
+                13329638886706599254,
+                10367928269499999681,
+                1103700473173455961,
+            ]),
+            Fq::from_montgomery_limbs([
+                10103906926633234988,
+                2534736636148567604,
+                14952613056563236408,
+                1185708084470239423,
+            ]),
+            Fq::from_montgomery_limbs([
+                8901226200527931766,
+                11513675612424203660,
+                2096148750587643256,
+                654185448797432391,
+            ]),
+            Fq::from_montgomery_limbs([
+                7061810472414433932,
+                6598793323361826786,
+                16695754978955677643,
+                444469668563526644,
+            ]),
+            Fq::from_montgomery_limbs([
+                17352104428318880199,
+                16365518589992400225,
+                17312393558271738875,
+                750173611970977756,
+            ]),
+            Fq::from_montgomery_limbs([
+                7818869192012769214,
+                9218737990992735768,
+                8588819471747881715,
+                657701777197829150,
+            ]),
+            Fq::from_montgomery_limbs([
+                11385322463099410513,
+                7459938230210921644,
+                1894418803040692475,
+                789632631941553608,
+            ]),
+            Fq::from_montgomery_limbs([
+                12145490903726644848,
+                17073291084333045535,
+                7089368401568901532,
+                1328417234937095419,
+            ]),
+            Fq::from_montgomery_limbs([
+                16146139008065423033,
+                1790820798415556294,
+                16790313805176585782,
+                725420923660204156,
+            ]),
+            Fq::from_montgomery_limbs([
+                10245576855235654259,
+                14089376858004111330,
+                16399220889295690539,
+                406897473279213051,
+            ]),
+            Fq::from_montgomery_limbs([
+                15582130801734254816,
+                15164653383197900819,
+                320537078437390665,
+                550332272645209477,
+            ]),
+            Fq::from_montgomery_limbs([
+                1219694032815735872,
+                9842809782639655869,
+                14895449469368227139,
+                575580241252599615,
+            ]),
+            Fq::from_montgomery_limbs([
+                13583771849309727783,
+                14290725850554473314,
+                13279341107429715892,
+                598480030806856605,
+            ]),
+            Fq::from_montgomery_limbs([
+                11484300973121360609,
+                7234895792480630315,
+                7870390172257027364,
+                1066196704378182,
+            ]),
+            Fq::from_montgomery_limbs([
+                13653714222133323700,
+                14803081186098458272,
+                2815332836903672873,
+                586991125375928070,
+            ]),
+            Fq::from_montgomery_limbs([
+                3763049492196082291,
+                13684346144348545604,
+                6247875733177057906,
+                860142073672418279,
+            ]),
+            Fq::from_montgomery_limbs([
+                10358594958450176595,
+                5723856226384845216,
+                1032727983621612389,
+                816903689903131993,
+            ]),
+        ]),
+        mds: MdsMatrix::<15, 14, 225, 196>::new_from_known([
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                4803295199375898767,
+                3754249901789907182,
+                10248134097739830010,
+                585421998305823688,
+            ]),
+        ]),
+        alpha: Alpha::Exponent(17),
+        rounds: RoundNumbers { r_P: 31, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<14, 196>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+            ]),
+            v: Matrix::<1, 14, 14>::new_from_known([
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+            ]),
+            w: Matrix::<14, 1, 14>::new_from_known([
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<15, 225>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<15, 225>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16246031968297287321,
+                    4752177947664711306,
+                    7144919494155578907,
+                    1271378857821479902,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    704647768167774740,
+                    9619601590015342328,
+                    17414258539299429896,
+                    242877169243138367,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12998503386263682932,
+                    15639660643237715116,
+                    281163968472549090,
+                    1262385398022512491,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17424027838979772012,
+                    14791485191171562740,
+                    2683672722004429764,
+                    980067464609797783,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1674190939765801517,
+                    7090666687897256949,
+                    1614341337926446694,
+                    165499584115938373,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14624017600530905744,
+                    13174003116921099803,
+                    8671658350158914560,
+                    258891225054240766,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3233888229135110993,
+                    6644252771967296501,
+                    7771032971150710119,
+                    1140658935924538385,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2187080536377483518,
+                    17670928380102631202,
+                    13241669798149191471,
+                    1223874125281889474,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    950222807244355090,
+                    11587428985807020723,
+                    7884671892193122639,
+                    950266818904961218,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1538801669203387696,
+                    12178409291677400058,
+                    12050449009852930155,
+                    65960103667034288,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4164324376092936610,
+                    7909782383235350532,
+                    12943184620962048184,
+                    1148451177715714021,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9935926620289616530,
+                    11743592828628611678,
+                    3136280146418507233,
+                    1282983267302070871,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1158940581826086761,
+                    14108873544460669419,
+                    12267380954456758240,
+                    842241098127976520,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13713353395636923832,
+                    13194025535593533781,
+                    13759894171316179860,
+                    291849446821765887,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<15, 225>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17225109018538332485,
+                    11008129222985040934,
+                    16097271245492325941,
+                    8228861282866481,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4579179525798103725,
+                    7799789939045458690,
+                    8075499348633646849,
+                    120312483996798200,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16587241425389314905,
+                    6489501896708171441,
+                    14684150009526381575,
+                    196193063161924545,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5830690080263789050,
+                    14322033227680407198,
+                    18101651186716842160,
+                    99605296057618116,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4154498868964412291,
+                    2546716311640260466,
+                    8934643190183641404,
+                    1258957287612179165,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6788153877634383686,
+                    2240883433676803762,
+                    15450455717044818488,
+                    293498482458380391,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2201861132095308242,
+                    858898868007820099,
+                    14459000207902343797,
+                    611534164542222418,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3292782626176689492,
+                    10138749678685591933,
+                    11955518323374123934,
+                    603469056814770279,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7930677869392710429,
+                    10216114704442496299,
+                    16363743107555367846,
+                    700548656906887271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11163725618058153258,
+                    6081718352275268236,
+                    12651142216412404889,
+                    1278452529297212421,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9511161579978779192,
+                    4524690095638042362,
+                    5437963428399722358,
+                    1316689264731832556,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6133879647516428902,
+                    272015915870176410,
+                    13480601512961872609,
+                    411112335981891958,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1853268663961046789,
+                    1421893802917659543,
+                    5995440274459181574,
+                    1263947745214761084,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11426435566926055010,
+                    9820866304298745644,
+                    9116551485971173887,
+                    639667700042252102,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12516437418126202173,
+                    14356950062117260709,
+                    14952602041264413748,
+                    952809465690001394,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4579179525798103725,
+                    7799789939045458690,
+                    8075499348633646849,
+                    120312483996798200,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15840995188007815310,
+                    13764673401106074386,
+                    275548797247026804,
+                    469582601240610737,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1157010950705131162,
+                    14926904483009316829,
+                    9541184397372373713,
+                    790807310554321559,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7552450767901435527,
+                    5105432004098749824,
+                    13057774401353674809,
+                    1110462654391245218,
+                ]),
+                Fq::from_montgomery_limbs([
+                    296895317370316669,
+                    16496022962362679817,
+                    10824069116968515810,
+                    950913639133273196,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14193763123736002065,
+                    18056943779969923100,
+                    13352994071658809903,
+                    693482815468287740,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17462985041672878112,
+                    7289973620789174678,
+                    14155221896396278279,
+                    777109774630080422,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18035780224490622965,
+                    16327204063616544366,
+                    2959391627148998878,
+                    858245603146085507,
+                ]),
+                Fq::from_montgomery_limbs([
+                    627437392940550959,
+                    946630212501134679,
+                    9359956754281594057,
+                    708509747977039147,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1473280370388388949,
+                    12666234971784157118,
+                    13134537967061342299,
+                    773919294866019270,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17739519871453549663,
+                    4955043805983872372,
+                    4149471020123932794,
+                    1287176072166166286,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16405175865642995701,
+                    16516222493112326466,
+                    16567318192040536455,
+                    424814260524100335,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5718279784735451795,
+                    470452562451898155,
+                    851630738145831295,
+                    850700517093959081,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14668777348448153083,
+                    9133515875196705482,
+                    12888976176617873015,
+                    1216116933498466813,
+                ]),
+                Fq::from_montgomery_limbs([
+                    346124512608438723,
+                    8289307403788228411,
+                    2276619658082870167,
+                    1026545851041551988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16587241425389314905,
+                    6489501896708171441,
+                    14684150009526381575,
+                    196193063161924545,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1157010950705131162,
+                    14926904483009316829,
+                    9541184397372373713,
+                    790807310554321559,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13882780707087794992,
+                    10142993206140646103,
+                    3671562809634758724,
+                    610290546321149072,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15956174633473526553,
+                    10704583293288007947,
+                    9283565868836703674,
+                    904961309128271832,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7501855378325703224,
+                    16306172445677715652,
+                    10622844837775648020,
+                    149694000294575730,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2591229447938760587,
+                    5185124471527748266,
+                    10805681911364088029,
+                    589289125250481241,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8071256571446576735,
+                    15209823850868750806,
+                    14127677548477304901,
+                    640650715401347564,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2962688111245448370,
+                    12444958668831129888,
+                    1106493561707791376,
+                    279699473474232399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10203445559841739535,
+                    8336801262650103692,
+                    16724249254842589681,
+                    1160494227033090376,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15411737601611193266,
+                    10787237046416821381,
+                    170238873852652371,
+                    444663743597063160,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17930257067594296880,
+                    14905911237555375668,
+                    7165207245483513712,
+                    1258954458192801216,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13111035309445241971,
+                    13770683298508433086,
+                    9738229195158687527,
+                    590038583261473423,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2598524334315027599,
+                    12196876266163916252,
+                    4822027404682993475,
+                    737088920961382744,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9066739302664731532,
+                    17093871611171252534,
+                    2192840725549853356,
+                    527035347815336979,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11942590047450211601,
+                    2263580193838327018,
+                    13523595777314272583,
+                    353215985136743266,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5830690080263789050,
+                    14322033227680407198,
+                    18101651186716842160,
+                    99605296057618116,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7552450767901435527,
+                    5105432004098749824,
+                    13057774401353674809,
+                    1110462654391245218,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15956174633473526553,
+                    10704583293288007947,
+                    9283565868836703674,
+                    904961309128271832,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6030331414654755629,
+                    397946309858777060,
+                    2763524678295539047,
+                    990701917220303374,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16221725730607814331,
+                    349991599343282440,
+                    11525032549809538693,
+                    24808343953907743,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13254248123143097297,
+                    4390179579543519937,
+                    6357250739457893434,
+                    349133294535438134,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8717918510300794439,
+                    9755410576645473041,
+                    7789374033757429662,
+                    151691839742421201,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4512720620533704937,
+                    2388683719040600792,
+                    13806428946814753358,
+                    260494975890190800,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4269856975779332068,
+                    8853204623310557044,
+                    6913880837604527111,
+                    290812697238764750,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4118634752632395555,
+                    13896958303972398632,
+                    8709534949595629907,
+                    1093152608567871937,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9661909838015694524,
+                    3699772958589916032,
+                    12910765521904007374,
+                    671989158644993771,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7940527802495372172,
+                    9172586350636355987,
+                    13886465605141014804,
+                    327618873824074320,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9292448391398144176,
+                    11074672075201955149,
+                    3884518668863098280,
+                    121782542005388082,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5107315985185566317,
+                    11561379593681185326,
+                    7915876016571004577,
+                    333430114732959215,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3276082705407271573,
+                    1456842485599387143,
+                    15798502347291491896,
+                    35027651514915121,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4154498868964412291,
+                    2546716311640260466,
+                    8934643190183641404,
+                    1258957287612179165,
+                ]),
+                Fq::from_montgomery_limbs([
+                    296895317370316669,
+                    16496022962362679817,
+                    10824069116968515810,
+                    950913639133273196,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7501855378325703224,
+                    16306172445677715652,
+                    10622844837775648020,
+                    149694000294575730,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16221725730607814331,
+                    349991599343282440,
+                    11525032549809538693,
+                    24808343953907743,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6925329739736490832,
+                    4673845095265748761,
+                    15917447127739065636,
+                    509135007304794063,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14804551563384599502,
+                    4464953208012914636,
+                    18246199142946790002,
+                    1038722628300302131,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18385287171731423893,
+                    13146876087783228178,
+                    5521651132325751830,
+                    772525510817435591,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3104635866375928895,
+                    10790866360082377740,
+                    5536428136537014494,
+                    274339106142372533,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2155314522979118888,
+                    18289317247743680118,
+                    9209244802990774721,
+                    1328508246597584847,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6815882764240028261,
+                    12659612182857707304,
+                    4855513257928546057,
+                    1237757561117788561,
+                ]),
+                Fq::from_montgomery_limbs([
+                    166289656692240611,
+                    7291043167993943564,
+                    12198490087806550370,
+                    1290463226055819427,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2705747062199042616,
+                    17206460936200237998,
+                    3879584425940796682,
+                    69965920007185944,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10532007885782947263,
+                    3185417905758734782,
+                    9880630782888367387,
+                    664307137794905842,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1727931656254625325,
+                    12502876717696881918,
+                    7248852284500518452,
+                    1109344868518600266,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7255117949458951237,
+                    3438042872316059386,
+                    11716685945969002223,
+                    1174034074393032802,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6788153877634383686,
+                    2240883433676803762,
+                    15450455717044818488,
+                    293498482458380391,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14193763123736002065,
+                    18056943779969923100,
+                    13352994071658809903,
+                    693482815468287740,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2591229447938760587,
+                    5185124471527748266,
+                    10805681911364088029,
+                    589289125250481241,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13254248123143097297,
+                    4390179579543519937,
+                    6357250739457893434,
+                    349133294535438134,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14804551563384599502,
+                    4464953208012914636,
+                    18246199142946790002,
+                    1038722628300302131,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14770862676619448780,
+                    18082752560958429738,
+                    7145974725817117426,
+                    1336484784517522624,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6973427405216962170,
+                    14023336914736606632,
+                    8236570106743813571,
+                    740114441759513959,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18209569074957816239,
+                    5494428099469774559,
+                    18121340368247715821,
+                    919030806821786836,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1999685294871727338,
+                    6958162198403130831,
+                    4606652795235078583,
+                    1342473774699083958,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17369395338106085477,
+                    975559258095947045,
+                    6293886906955776180,
+                    1082032699260020306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4150615563015851322,
+                    13316640558667267774,
+                    434025165146810116,
+                    217969778356596686,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6032430015980514203,
+                    373772591724651086,
+                    17786072542796220108,
+                    851712768667191394,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5404928113750600537,
+                    2647454736659819873,
+                    11872302216185791108,
+                    42167346147701128,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14276251076413820860,
+                    12629389516361266017,
+                    8179472024916792639,
+                    518431261283328324,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14517137885241008000,
+                    13221637994206708624,
+                    2944528748468739108,
+                    1161289395841298237,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2201861132095308242,
+                    858898868007820099,
+                    14459000207902343797,
+                    611534164542222418,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17462985041672878112,
+                    7289973620789174678,
+                    14155221896396278279,
+                    777109774630080422,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8071256571446576735,
+                    15209823850868750806,
+                    14127677548477304901,
+                    640650715401347564,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8717918510300794439,
+                    9755410576645473041,
+                    7789374033757429662,
+                    151691839742421201,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18385287171731423893,
+                    13146876087783228178,
+                    5521651132325751830,
+                    772525510817435591,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6973427405216962170,
+                    14023336914736606632,
+                    8236570106743813571,
+                    740114441759513959,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11372927826908485425,
+                    5604829022377832941,
+                    9889294381611694334,
+                    595502507369546499,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1452220038465597247,
+                    18412199748051946075,
+                    17424781794815895281,
+                    322224000024693446,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4273321711199891978,
+                    8362382171273292035,
+                    6515453655802896898,
+                    355030606391702563,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2002030715732125135,
+                    1375555221096032319,
+                    2285193976433466580,
+                    600726947714529801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18114138631444072634,
+                    6535645486553124228,
+                    7611237494573135735,
+                    726472609345778265,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1142838093638364447,
+                    1837582548549911557,
+                    17092927144039566954,
+                    740136846047727699,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3143677914274739346,
+                    17913445391805311380,
+                    7940042381899000588,
+                    137261368112792290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15987144624306549558,
+                    11585471890751245772,
+                    13979526912955214603,
+                    321177275732084703,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16953787406931664816,
+                    5407111035521899038,
+                    954887448092186688,
+                    1343609226806844370,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3292782626176689492,
+                    10138749678685591933,
+                    11955518323374123934,
+                    603469056814770279,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18035780224490622965,
+                    16327204063616544366,
+                    2959391627148998878,
+                    858245603146085507,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2962688111245448370,
+                    12444958668831129888,
+                    1106493561707791376,
+                    279699473474232399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4512720620533704937,
+                    2388683719040600792,
+                    13806428946814753358,
+                    260494975890190800,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3104635866375928895,
+                    10790866360082377740,
+                    5536428136537014494,
+                    274339106142372533,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18209569074957816239,
+                    5494428099469774559,
+                    18121340368247715821,
+                    919030806821786836,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1452220038465597247,
+                    18412199748051946075,
+                    17424781794815895281,
+                    322224000024693446,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16252118835287925788,
+                    3795251196191525630,
+                    17289876568783845918,
+                    583332269529009497,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17764528849723158828,
+                    3865399928793799645,
+                    1617513781650153937,
+                    1062752635802909406,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14309243383409999600,
+                    16342838800852468726,
+                    15469508741504567695,
+                    1312470346706397721,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3734007560486421591,
+                    8664303697540685442,
+                    17377381850492025594,
+                    186102621634051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14011660748552281887,
+                    3815744602504799673,
+                    5907933287059806817,
+                    43984721738322040,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12192959855947138832,
+                    3949647265270094484,
+                    2456201211839474390,
+                    746738228353850195,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8316210685930883769,
+                    17920571521074435820,
+                    351579215250866252,
+                    564578803307316298,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1935862955884409962,
+                    16503743054635223081,
+                    14772307994682765777,
+                    643792831530233100,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7930677869392710429,
+                    10216114704442496299,
+                    16363743107555367846,
+                    700548656906887271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    627437392940550959,
+                    946630212501134679,
+                    9359956754281594057,
+                    708509747977039147,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10203445559841739535,
+                    8336801262650103692,
+                    16724249254842589681,
+                    1160494227033090376,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4269856975779332068,
+                    8853204623310557044,
+                    6913880837604527111,
+                    290812697238764750,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2155314522979118888,
+                    18289317247743680118,
+                    9209244802990774721,
+                    1328508246597584847,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1999685294871727338,
+                    6958162198403130831,
+                    4606652795235078583,
+                    1342473774699083958,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4273321711199891978,
+                    8362382171273292035,
+                    6515453655802896898,
+                    355030606391702563,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17764528849723158828,
+                    3865399928793799645,
+                    1617513781650153937,
+                    1062752635802909406,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2825811915946670965,
+                    217362374121006429,
+                    1743035648061013929,
+                    445256467111114991,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10287280862968903616,
+                    14488515210710076376,
+                    9065094688731137481,
+                    892112733383366932,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8052305210106830775,
+                    18242653310274664107,
+                    13925258803154520448,
+                    234742953268498421,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8574093133339147752,
+                    16570321126533993171,
+                    11503692063593916175,
+                    1260200127807898056,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17041916588678648464,
+                    3263552117389338727,
+                    2872854379075563670,
+                    351163059919865191,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314716744494187227,
+                    10627950258551910285,
+                    16630366807622678682,
+                    775544329583473293,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12726176454152324341,
+                    5093261543799080762,
+                    16952821260320432300,
+                    606933412454636012,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11163725618058153258,
+                    6081718352275268236,
+                    12651142216412404889,
+                    1278452529297212421,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1473280370388388949,
+                    12666234971784157118,
+                    13134537967061342299,
+                    773919294866019270,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15411737601611193266,
+                    10787237046416821381,
+                    170238873852652371,
+                    444663743597063160,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4118634752632395555,
+                    13896958303972398632,
+                    8709534949595629907,
+                    1093152608567871937,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6815882764240028261,
+                    12659612182857707304,
+                    4855513257928546057,
+                    1237757561117788561,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17369395338106085477,
+                    975559258095947045,
+                    6293886906955776180,
+                    1082032699260020306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2002030715732125135,
+                    1375555221096032319,
+                    2285193976433466580,
+                    600726947714529801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14309243383409999600,
+                    16342838800852468726,
+                    15469508741504567695,
+                    1312470346706397721,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10287280862968903616,
+                    14488515210710076376,
+                    9065094688731137481,
+                    892112733383366932,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11790452975109379735,
+                    8750741491466793343,
+                    4562675520806052869,
+                    1024609237200249444,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8831287779417567552,
+                    520239752654590170,
+                    11738028221639100231,
+                    1118411849277904532,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13950306099395954843,
+                    12274799666556653902,
+                    4994534936823775339,
+                    356747861043846871,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6556765193368360519,
+                    9949683901100883920,
+                    2767734613051837862,
+                    895581550317628716,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5566687221726689217,
+                    11796164467091878623,
+                    3031922537318797503,
+                    286506220324807225,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12148879652015371076,
+                    12532534911924309159,
+                    12123384116676420824,
+                    89964950210253973,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9511161579978779192,
+                    4524690095638042362,
+                    5437963428399722358,
+                    1316689264731832556,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17739519871453549663,
+                    4955043805983872372,
+                    4149471020123932794,
+                    1287176072166166286,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17930257067594296880,
+                    14905911237555375668,
+                    7165207245483513712,
+                    1258954458192801216,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9661909838015694524,
+                    3699772958589916032,
+                    12910765521904007374,
+                    671989158644993771,
+                ]),
+                Fq::from_montgomery_limbs([
+                    166289656692240611,
+                    7291043167993943564,
+                    12198490087806550370,
+                    1290463226055819427,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4150615563015851322,
+                    13316640558667267774,
+                    434025165146810116,
+                    217969778356596686,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18114138631444072634,
+                    6535645486553124228,
+                    7611237494573135735,
+                    726472609345778265,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3734007560486421591,
+                    8664303697540685442,
+                    17377381850492025594,
+                    186102621634051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8052305210106830775,
+                    18242653310274664107,
+                    13925258803154520448,
+                    234742953268498421,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8831287779417567552,
+                    520239752654590170,
+                    11738028221639100231,
+                    1118411849277904532,
+                ]),
+                Fq::from_montgomery_limbs([
+                    889550240552310021,
+                    1639159902017806092,
+                    3530169629383754015,
+                    1231265515758497834,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11143634788652073724,
+                    8209718068636151409,
+                    590369557106149484,
+                    1205943262766347445,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13674484761404727992,
+                    13435911021183696713,
+                    10987144134568453569,
+                    1209939978567851535,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15724723021000131180,
+                    14966878844967993335,
+                    10552776196037085181,
+                    979224194159247083,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8664851473185914731,
+                    10834952799651072849,
+                    3126749365472674517,
+                    1049844723323693436,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6133879647516428902,
+                    272015915870176410,
+                    13480601512961872609,
+                    411112335981891958,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16405175865642995701,
+                    16516222493112326466,
+                    16567318192040536455,
+                    424814260524100335,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13111035309445241971,
+                    13770683298508433086,
+                    9738229195158687527,
+                    590038583261473423,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7940527802495372172,
+                    9172586350636355987,
+                    13886465605141014804,
+                    327618873824074320,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2705747062199042616,
+                    17206460936200237998,
+                    3879584425940796682,
+                    69965920007185944,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6032430015980514203,
+                    373772591724651086,
+                    17786072542796220108,
+                    851712768667191394,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1142838093638364447,
+                    1837582548549911557,
+                    17092927144039566954,
+                    740136846047727699,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14011660748552281887,
+                    3815744602504799673,
+                    5907933287059806817,
+                    43984721738322040,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8574093133339147752,
+                    16570321126533993171,
+                    11503692063593916175,
+                    1260200127807898056,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13950306099395954843,
+                    12274799666556653902,
+                    4994534936823775339,
+                    356747861043846871,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11143634788652073724,
+                    8209718068636151409,
+                    590369557106149484,
+                    1205943262766347445,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12015253643419060772,
+                    15603795703146701151,
+                    1212820971881150326,
+                    1038753536395661559,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16732581207863650975,
+                    8440645136239089158,
+                    10868944624034012017,
+                    811747946218351725,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5368807803230836497,
+                    6638809447201594133,
+                    10330847873196026004,
+                    479081916628043580,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16314522638599236273,
+                    8754454963190357892,
+                    6783466968397596135,
+                    1082383237481990180,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1853268663961046789,
+                    1421893802917659543,
+                    5995440274459181574,
+                    1263947745214761084,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5718279784735451795,
+                    470452562451898155,
+                    851630738145831295,
+                    850700517093959081,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2598524334315027599,
+                    12196876266163916252,
+                    4822027404682993475,
+                    737088920961382744,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9292448391398144176,
+                    11074672075201955149,
+                    3884518668863098280,
+                    121782542005388082,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10532007885782947263,
+                    3185417905758734782,
+                    9880630782888367387,
+                    664307137794905842,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5404928113750600537,
+                    2647454736659819873,
+                    11872302216185791108,
+                    42167346147701128,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3143677914274739346,
+                    17913445391805311380,
+                    7940042381899000588,
+                    137261368112792290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12192959855947138832,
+                    3949647265270094484,
+                    2456201211839474390,
+                    746738228353850195,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17041916588678648464,
+                    3263552117389338727,
+                    2872854379075563670,
+                    351163059919865191,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6556765193368360519,
+                    9949683901100883920,
+                    2767734613051837862,
+                    895581550317628716,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13674484761404727992,
+                    13435911021183696713,
+                    10987144134568453569,
+                    1209939978567851535,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16732581207863650975,
+                    8440645136239089158,
+                    10868944624034012017,
+                    811747946218351725,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16972423789873067885,
+                    6278574360615464458,
+                    674264509211697021,
+                    591013281263564103,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3021224468464634505,
+                    7852029181000161124,
+                    14845876260986110119,
+                    265681012244953728,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8249833696840740757,
+                    10966794037455661576,
+                    11958945665010115239,
+                    204597387439929960,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11426435566926055010,
+                    9820866304298745644,
+                    9116551485971173887,
+                    639667700042252102,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14668777348448153083,
+                    9133515875196705482,
+                    12888976176617873015,
+                    1216116933498466813,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9066739302664731532,
+                    17093871611171252534,
+                    2192840725549853356,
+                    527035347815336979,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5107315985185566317,
+                    11561379593681185326,
+                    7915876016571004577,
+                    333430114732959215,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1727931656254625325,
+                    12502876717696881918,
+                    7248852284500518452,
+                    1109344868518600266,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14276251076413820860,
+                    12629389516361266017,
+                    8179472024916792639,
+                    518431261283328324,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15987144624306549558,
+                    11585471890751245772,
+                    13979526912955214603,
+                    321177275732084703,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8316210685930883769,
+                    17920571521074435820,
+                    351579215250866252,
+                    564578803307316298,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314716744494187227,
+                    10627950258551910285,
+                    16630366807622678682,
+                    775544329583473293,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5566687221726689217,
+                    11796164467091878623,
+                    3031922537318797503,
+                    286506220324807225,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15724723021000131180,
+                    14966878844967993335,
+                    10552776196037085181,
+                    979224194159247083,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5368807803230836497,
+                    6638809447201594133,
+                    10330847873196026004,
+                    479081916628043580,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3021224468464634505,
+                    7852029181000161124,
+                    14845876260986110119,
+                    265681012244953728,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14904151831499767288,
+                    1484174770087937672,
+                    12147425548965998947,
+                    474174136969238974,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3550250813192031990,
+                    10842954001677942698,
+                    8884659096386574942,
+                    948706298030914036,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12516437418126202173,
+                    14356950062117260709,
+                    14952602041264413748,
+                    952809465690001394,
+                ]),
+                Fq::from_montgomery_limbs([
+                    346124512608438723,
+                    8289307403788228411,
+                    2276619658082870167,
+                    1026545851041551988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11942590047450211601,
+                    2263580193838327018,
+                    13523595777314272583,
+                    353215985136743266,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3276082705407271573,
+                    1456842485599387143,
+                    15798502347291491896,
+                    35027651514915121,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7255117949458951237,
+                    3438042872316059386,
+                    11716685945969002223,
+                    1174034074393032802,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14517137885241008000,
+                    13221637994206708624,
+                    2944528748468739108,
+                    1161289395841298237,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16953787406931664816,
+                    5407111035521899038,
+                    954887448092186688,
+                    1343609226806844370,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1935862955884409962,
+                    16503743054635223081,
+                    14772307994682765777,
+                    643792831530233100,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12726176454152324341,
+                    5093261543799080762,
+                    16952821260320432300,
+                    606933412454636012,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12148879652015371076,
+                    12532534911924309159,
+                    12123384116676420824,
+                    89964950210253973,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8664851473185914731,
+                    10834952799651072849,
+                    3126749365472674517,
+                    1049844723323693436,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16314522638599236273,
+                    8754454963190357892,
+                    6783466968397596135,
+                    1082383237481990180,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8249833696840740757,
+                    10966794037455661576,
+                    11958945665010115239,
+                    204597387439929960,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3550250813192031990,
+                    10842954001677942698,
+                    8884659096386574942,
+                    948706298030914036,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2721259121419749844,
+                    13532820618354140316,
+                    6162929524928845686,
+                    763175992081942109,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<14, 196>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9896556299607204041,
+                    1057482722794680843,
+                    2887965463388814935,
+                    600904472108173352,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9213602223850167926,
+                    1019297973218353046,
+                    10285111634508849805,
+                    223433643130103943,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11220358403970195559,
+                    5194526162873608505,
+                    9636817919330713686,
+                    361900749183287293,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12997659940745577875,
+                    1833579014239331173,
+                    12941621542780413228,
+                    702853264420684680,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3925102035948620537,
+                    13673926816322632161,
+                    7055352912144064617,
+                    998775941215819335,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15464521650577004508,
+                    18247516029907402027,
+                    2229208684618814070,
+                    13876960261251436,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8143268781847761862,
+                    11245280928765086584,
+                    7199780917891613136,
+                    555943896275724567,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5504020470298672859,
+                    3654212743800793341,
+                    702061005369136779,
+                    1272947080164532506,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8542994087597659594,
+                    2941415669486908046,
+                    6126887938795364082,
+                    28648736369423681,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17960164860262496387,
+                    1856225243560796297,
+                    2614236940782462519,
+                    872979633484262531,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5957556806025421027,
+                    7480136522197451278,
+                    13799735819278258448,
+                    1126910299887463764,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8377546853237777597,
+                    8391096255389015375,
+                    9953001318190772860,
+                    60999469105059235,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1172661858153047419,
+                    6311658491872781585,
+                    8297636603823611610,
+                    1191389426005417024,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1342048750278338353,
+                    16363569102868045156,
+                    2388629371710172134,
+                    141147189656750683,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9213602223850167926,
+                    1019297973218353046,
+                    10285111634508849805,
+                    223433643130103943,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5452389128315456003,
+                    210085880295501395,
+                    981033938828350859,
+                    767203676842411281,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17030396319157383144,
+                    8477643984235241999,
+                    5141053192543365631,
+                    835463780401208768,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7210415987903654720,
+                    12511328554773519095,
+                    3175748317217932868,
+                    3707591338565343,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10960821004857546147,
+                    10708896071118195913,
+                    13989717192808857607,
+                    1085642915700916558,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15542895542185656100,
+                    7861502304092461192,
+                    3912496624251116125,
+                    745046609963432670,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7522686798129758670,
+                    1515458470490754229,
+                    724953670762446888,
+                    1132736011452805759,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11412010758906041411,
+                    17610407934531311334,
+                    3225261979552097628,
+                    1275787723703855113,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14377863808978335455,
+                    10325866294384759889,
+                    13667472313121194594,
+                    771391842896202626,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6402217151655844421,
+                    4252029866327454301,
+                    12813218024042307915,
+                    708144859188074284,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6575632198573774256,
+                    12174964212684665418,
+                    15978491581631138698,
+                    23281194774606489,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18426212964343225841,
+                    15562404627967868471,
+                    2274512714322712928,
+                    1313479510571739900,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1045639178344986820,
+                    6809312808704419814,
+                    15898488892911033037,
+                    187055923953227881,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6940654157946497559,
+                    13893594265460642420,
+                    16817504345624934738,
+                    322953689107943323,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11220358403970195559,
+                    5194526162873608505,
+                    9636817919330713686,
+                    361900749183287293,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17030396319157383144,
+                    8477643984235241999,
+                    5141053192543365631,
+                    835463780401208768,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9963749279793093996,
+                    13872128182858516877,
+                    15004922130302347700,
+                    139626195257911765,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5996354235580458171,
+                    17087357004823373726,
+                    15290845832772264354,
+                    1062933918752203831,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13303528316232152532,
+                    11138645334413621960,
+                    10768974711364248792,
+                    855347452285751629,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9682970656285730113,
+                    11799279404624738049,
+                    16435855115207083540,
+                    904077001398897560,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5974477675138630808,
+                    5625794790607246576,
+                    7772352163859252767,
+                    24005645469087189,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14606027468901658682,
+                    16902661980969050697,
+                    9169563724046313739,
+                    367801412483220002,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6611492368957028723,
+                    6118209698910636387,
+                    5053151374188295062,
+                    1161362339219632882,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9446075024257961280,
+                    14267442900298983347,
+                    259359642629796536,
+                    403540709426512491,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17716595528702271277,
+                    10157117718775656765,
+                    13192588405531894922,
+                    919954911305351608,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10000022140021033808,
+                    16263767134765317031,
+                    13982071238129929437,
+                    806224111667464834,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18217122999782700026,
+                    9161049254932737128,
+                    14666805890378139975,
+                    1106830009133523086,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4243388586806212544,
+                    17241219511946079227,
+                    6995741682746654636,
+                    698652991282439181,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12997659940745577875,
+                    1833579014239331173,
+                    12941621542780413228,
+                    702853264420684680,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7210415987903654720,
+                    12511328554773519095,
+                    3175748317217932868,
+                    3707591338565343,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5996354235580458171,
+                    17087357004823373726,
+                    15290845832772264354,
+                    1062933918752203831,
+                ]),
+                Fq::from_montgomery_limbs([
+                    746767474787953512,
+                    14506864304109790150,
+                    8802744504638887251,
+                    648623995547063257,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13820670726252507619,
+                    16597095165286063694,
+                    6936355777316395472,
+                    833516142414221553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11258866351471019872,
+                    7256083579064768205,
+                    11865375814571931049,
+                    612901540188550338,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7605636832611404643,
+                    13518603560221516649,
+                    14637684673686802486,
+                    642741356185387727,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15364679963942511420,
+                    9816652267231204264,
+                    3451804580531850725,
+                    429754105092552499,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2395353087256704534,
+                    17191511551610996935,
+                    18311999567168772777,
+                    841162117573815974,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1008922569559360370,
+                    16131363539568684109,
+                    15279499365116276119,
+                    873356271848504409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    55181495357818409,
+                    1898443446949718507,
+                    16712403180598944175,
+                    1046509315957891537,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10097921926279474164,
+                    3556229952251380424,
+                    9935685533006751974,
+                    156563033058342539,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9728101121145306459,
+                    6950213785875646419,
+                    5596851937741124632,
+                    88202425183198922,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16845133055476090668,
+                    7267456011972276903,
+                    14747294801835369684,
+                    500198994543311954,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3925102035948620537,
+                    13673926816322632161,
+                    7055352912144064617,
+                    998775941215819335,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10960821004857546147,
+                    10708896071118195913,
+                    13989717192808857607,
+                    1085642915700916558,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13303528316232152532,
+                    11138645334413621960,
+                    10768974711364248792,
+                    855347452285751629,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13820670726252507619,
+                    16597095165286063694,
+                    6936355777316395472,
+                    833516142414221553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17245455419902502173,
+                    9662977925750941090,
+                    11412065286278473391,
+                    419850391704388513,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8922064955156530734,
+                    6934368250746032378,
+                    14789040841793984618,
+                    1013779867760089138,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3776767892723257705,
+                    15041497005862228644,
+                    13985747395136601436,
+                    1280799601501343253,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14376219625534639882,
+                    12879867617382137273,
+                    7458079192618193825,
+                    1169564966164577786,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2013803618948044554,
+                    11017911794372159804,
+                    11209479311920957329,
+                    816120762483719021,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11628208351021659270,
+                    6313234361048603001,
+                    5126407194504693530,
+                    290853051973294348,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16640456798678451357,
+                    3916538599403566772,
+                    15310144975881015047,
+                    491082037948773852,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17078264717322081034,
+                    1012062465428849930,
+                    14391227330520401309,
+                    154160857426208280,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7325463227810505757,
+                    7330866479350089353,
+                    10436142240641044380,
+                    492586604834442058,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17643771868247091821,
+                    4621256654823483149,
+                    7316653435731644439,
+                    1299880074229271682,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15464521650577004508,
+                    18247516029907402027,
+                    2229208684618814070,
+                    13876960261251436,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15542895542185656100,
+                    7861502304092461192,
+                    3912496624251116125,
+                    745046609963432670,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9682970656285730113,
+                    11799279404624738049,
+                    16435855115207083540,
+                    904077001398897560,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11258866351471019872,
+                    7256083579064768205,
+                    11865375814571931049,
+                    612901540188550338,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8922064955156530734,
+                    6934368250746032378,
+                    14789040841793984618,
+                    1013779867760089138,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7226835704531279856,
+                    2118242023989770657,
+                    9699768897081553503,
+                    1256619313056281949,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10589608635959381999,
+                    10229006971125416177,
+                    7061927433269864796,
+                    763082384013975803,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10225474031961713850,
+                    12004862697535327544,
+                    17833631736054660408,
+                    787302627727967992,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3134770989972266290,
+                    9932608301724688903,
+                    14182189416464585641,
+                    592866604311196313,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8643528698284328572,
+                    5597416906890234544,
+                    14327026494887978817,
+                    544264425304034109,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10790757531840908953,
+                    14189676187717120259,
+                    16314466745087068628,
+                    296433302155598156,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11216575429000999256,
+                    6785735722273099372,
+                    5126869254182730575,
+                    529917775260611109,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6056749011486367812,
+                    9615589135669550570,
+                    11821991031764840740,
+                    1058429102198387067,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8774451573180597343,
+                    5230766584170496357,
+                    14210552182378995297,
+                    324492345492824057,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8143268781847761862,
+                    11245280928765086584,
+                    7199780917891613136,
+                    555943896275724567,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7522686798129758670,
+                    1515458470490754229,
+                    724953670762446888,
+                    1132736011452805759,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5974477675138630808,
+                    5625794790607246576,
+                    7772352163859252767,
+                    24005645469087189,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7605636832611404643,
+                    13518603560221516649,
+                    14637684673686802486,
+                    642741356185387727,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3776767892723257705,
+                    15041497005862228644,
+                    13985747395136601436,
+                    1280799601501343253,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10589608635959381999,
+                    10229006971125416177,
+                    7061927433269864796,
+                    763082384013975803,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15239329223533814696,
+                    3959906406038516064,
+                    633214920084169127,
+                    248062699201895158,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16770468212396828244,
+                    4672247227150196003,
+                    3215293596649490184,
+                    394117205842950514,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8403501862160480410,
+                    3188062796334919894,
+                    10139707268451267597,
+                    523496222010606109,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11030784367964757115,
+                    16599030298272434909,
+                    2055041897814228232,
+                    317201505449026157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14547451905812971333,
+                    6565986938645103262,
+                    12942941094894856708,
+                    1118364403687686738,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12695786192673821631,
+                    13033235720903346910,
+                    13738326226240690671,
+                    64833273584741237,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18077228205416660467,
+                    16746330848352125422,
+                    17077714805442371083,
+                    618458379739558872,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11202610342251309352,
+                    8418154732672095312,
+                    7221424968665560293,
+                    697729346027279136,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5504020470298672859,
+                    3654212743800793341,
+                    702061005369136779,
+                    1272947080164532506,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11412010758906041411,
+                    17610407934531311334,
+                    3225261979552097628,
+                    1275787723703855113,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14606027468901658682,
+                    16902661980969050697,
+                    9169563724046313739,
+                    367801412483220002,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15364679963942511420,
+                    9816652267231204264,
+                    3451804580531850725,
+                    429754105092552499,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14376219625534639882,
+                    12879867617382137273,
+                    7458079192618193825,
+                    1169564966164577786,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10225474031961713850,
+                    12004862697535327544,
+                    17833631736054660408,
+                    787302627727967992,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16770468212396828244,
+                    4672247227150196003,
+                    3215293596649490184,
+                    394117205842950514,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13396592002022908244,
+                    2749283967239309691,
+                    9501739000320598765,
+                    918509905347996814,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6343274675912796330,
+                    13199700836080166328,
+                    16634765618117341555,
+                    467305235838223529,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10506840553457686928,
+                    359222115231650920,
+                    9504775050305954060,
+                    781337660663818019,
+                ]),
+                Fq::from_montgomery_limbs([
+                    644473777007314845,
+                    2406526062474729846,
+                    17855308213158933952,
+                    756854223080085404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    668000773760579676,
+                    9418274288732186690,
+                    7107675374307838381,
+                    704179975779000125,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16724194344247202496,
+                    6016749831792863913,
+                    5562385033170353006,
+                    1211494414649893462,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12442668507348120693,
+                    7884330760118796697,
+                    3310965111521977031,
+                    955067674656696523,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8542994087597659594,
+                    2941415669486908046,
+                    6126887938795364082,
+                    28648736369423681,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14377863808978335455,
+                    10325866294384759889,
+                    13667472313121194594,
+                    771391842896202626,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6611492368957028723,
+                    6118209698910636387,
+                    5053151374188295062,
+                    1161362339219632882,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2395353087256704534,
+                    17191511551610996935,
+                    18311999567168772777,
+                    841162117573815974,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2013803618948044554,
+                    11017911794372159804,
+                    11209479311920957329,
+                    816120762483719021,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3134770989972266290,
+                    9932608301724688903,
+                    14182189416464585641,
+                    592866604311196313,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8403501862160480410,
+                    3188062796334919894,
+                    10139707268451267597,
+                    523496222010606109,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6343274675912796330,
+                    13199700836080166328,
+                    16634765618117341555,
+                    467305235838223529,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13742709774929383726,
+                    14824269699879364598,
+                    4247332839699721937,
+                    1174065957789417836,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14237414018509321264,
+                    8379070796067486752,
+                    16518099492213506327,
+                    167761777391685679,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9982324829024759843,
+                    8419777746454111935,
+                    12250392207599212778,
+                    813319628773861703,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16585997885731305740,
+                    11958503929071561378,
+                    7771583679451908678,
+                    373476986834300352,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13073495447413965676,
+                    17433475650186960676,
+                    13575967985911469052,
+                    200025677945856324,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11906986760083754842,
+                    2620800995564213799,
+                    13746715748287221067,
+                    619343476122416811,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17960164860262496387,
+                    1856225243560796297,
+                    2614236940782462519,
+                    872979633484262531,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6402217151655844421,
+                    4252029866327454301,
+                    12813218024042307915,
+                    708144859188074284,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9446075024257961280,
+                    14267442900298983347,
+                    259359642629796536,
+                    403540709426512491,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1008922569559360370,
+                    16131363539568684109,
+                    15279499365116276119,
+                    873356271848504409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11628208351021659270,
+                    6313234361048603001,
+                    5126407194504693530,
+                    290853051973294348,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8643528698284328572,
+                    5597416906890234544,
+                    14327026494887978817,
+                    544264425304034109,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11030784367964757115,
+                    16599030298272434909,
+                    2055041897814228232,
+                    317201505449026157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10506840553457686928,
+                    359222115231650920,
+                    9504775050305954060,
+                    781337660663818019,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14237414018509321264,
+                    8379070796067486752,
+                    16518099492213506327,
+                    167761777391685679,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14364994025199709267,
+                    2809245433887183300,
+                    302336060403597522,
+                    573680986314048205,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9977235992633424033,
+                    2397762331690552268,
+                    4047729121579696178,
+                    473138780144247324,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7391646932694465228,
+                    15427259254503887453,
+                    18176990045974642950,
+                    1052163782395814831,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15699820533303940475,
+                    5496446799243553995,
+                    1262657933036559243,
+                    912150980146011446,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14727465962380763277,
+                    7608549393572414112,
+                    9698386912598832600,
+                    583118258284679486,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5957556806025421027,
+                    7480136522197451278,
+                    13799735819278258448,
+                    1126910299887463764,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6575632198573774256,
+                    12174964212684665418,
+                    15978491581631138698,
+                    23281194774606489,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17716595528702271277,
+                    10157117718775656765,
+                    13192588405531894922,
+                    919954911305351608,
+                ]),
+                Fq::from_montgomery_limbs([
+                    55181495357818409,
+                    1898443446949718507,
+                    16712403180598944175,
+                    1046509315957891537,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16640456798678451357,
+                    3916538599403566772,
+                    15310144975881015047,
+                    491082037948773852,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10790757531840908953,
+                    14189676187717120259,
+                    16314466745087068628,
+                    296433302155598156,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14547451905812971333,
+                    6565986938645103262,
+                    12942941094894856708,
+                    1118364403687686738,
+                ]),
+                Fq::from_montgomery_limbs([
+                    644473777007314845,
+                    2406526062474729846,
+                    17855308213158933952,
+                    756854223080085404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9982324829024759843,
+                    8419777746454111935,
+                    12250392207599212778,
+                    813319628773861703,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9977235992633424033,
+                    2397762331690552268,
+                    4047729121579696178,
+                    473138780144247324,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12085087932760900340,
+                    6675683875928317421,
+                    14090714572219270862,
+                    846630563568563042,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9317372753808914891,
+                    6499554997085090607,
+                    8185518939859748102,
+                    486081927921032192,
+                ]),
+                Fq::from_montgomery_limbs([
+                    319955150034432283,
+                    1554921038617260517,
+                    5652346989173606613,
+                    1175134245783505041,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14025115063494719814,
+                    9274675445540201683,
+                    8527944798165032063,
+                    995409415717365706,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8377546853237777597,
+                    8391096255389015375,
+                    9953001318190772860,
+                    60999469105059235,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18426212964343225841,
+                    15562404627967868471,
+                    2274512714322712928,
+                    1313479510571739900,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10000022140021033808,
+                    16263767134765317031,
+                    13982071238129929437,
+                    806224111667464834,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10097921926279474164,
+                    3556229952251380424,
+                    9935685533006751974,
+                    156563033058342539,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17078264717322081034,
+                    1012062465428849930,
+                    14391227330520401309,
+                    154160857426208280,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11216575429000999256,
+                    6785735722273099372,
+                    5126869254182730575,
+                    529917775260611109,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12695786192673821631,
+                    13033235720903346910,
+                    13738326226240690671,
+                    64833273584741237,
+                ]),
+                Fq::from_montgomery_limbs([
+                    668000773760579676,
+                    9418274288732186690,
+                    7107675374307838381,
+                    704179975779000125,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16585997885731305740,
+                    11958503929071561378,
+                    7771583679451908678,
+                    373476986834300352,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7391646932694465228,
+                    15427259254503887453,
+                    18176990045974642950,
+                    1052163782395814831,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9317372753808914891,
+                    6499554997085090607,
+                    8185518939859748102,
+                    486081927921032192,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7426305801678571264,
+                    3019378405087077568,
+                    13144304422242027065,
+                    913946546830238873,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9983386157538295065,
+                    306402894307673560,
+                    16792368115376387709,
+                    1122505899898302672,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1871333143195325319,
+                    15064519559557840066,
+                    13319809013901247943,
+                    100595930169371590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1172661858153047419,
+                    6311658491872781585,
+                    8297636603823611610,
+                    1191389426005417024,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1045639178344986820,
+                    6809312808704419814,
+                    15898488892911033037,
+                    187055923953227881,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18217122999782700026,
+                    9161049254932737128,
+                    14666805890378139975,
+                    1106830009133523086,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9728101121145306459,
+                    6950213785875646419,
+                    5596851937741124632,
+                    88202425183198922,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7325463227810505757,
+                    7330866479350089353,
+                    10436142240641044380,
+                    492586604834442058,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6056749011486367812,
+                    9615589135669550570,
+                    11821991031764840740,
+                    1058429102198387067,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18077228205416660467,
+                    16746330848352125422,
+                    17077714805442371083,
+                    618458379739558872,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16724194344247202496,
+                    6016749831792863913,
+                    5562385033170353006,
+                    1211494414649893462,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13073495447413965676,
+                    17433475650186960676,
+                    13575967985911469052,
+                    200025677945856324,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15699820533303940475,
+                    5496446799243553995,
+                    1262657933036559243,
+                    912150980146011446,
+                ]),
+                Fq::from_montgomery_limbs([
+                    319955150034432283,
+                    1554921038617260517,
+                    5652346989173606613,
+                    1175134245783505041,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9983386157538295065,
+                    306402894307673560,
+                    16792368115376387709,
+                    1122505899898302672,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10906001124202355120,
+                    2944598328283894894,
+                    8891505441135652560,
+                    1104310160241957463,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4687365516738643626,
+                    6915275441715980198,
+                    16706327299891186400,
+                    351808001698809014,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1342048750278338353,
+                    16363569102868045156,
+                    2388629371710172134,
+                    141147189656750683,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6940654157946497559,
+                    13893594265460642420,
+                    16817504345624934738,
+                    322953689107943323,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4243388586806212544,
+                    17241219511946079227,
+                    6995741682746654636,
+                    698652991282439181,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16845133055476090668,
+                    7267456011972276903,
+                    14747294801835369684,
+                    500198994543311954,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17643771868247091821,
+                    4621256654823483149,
+                    7316653435731644439,
+                    1299880074229271682,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8774451573180597343,
+                    5230766584170496357,
+                    14210552182378995297,
+                    324492345492824057,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11202610342251309352,
+                    8418154732672095312,
+                    7221424968665560293,
+                    697729346027279136,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12442668507348120693,
+                    7884330760118796697,
+                    3310965111521977031,
+                    955067674656696523,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11906986760083754842,
+                    2620800995564213799,
+                    13746715748287221067,
+                    619343476122416811,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14727465962380763277,
+                    7608549393572414112,
+                    9698386912598832600,
+                    583118258284679486,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14025115063494719814,
+                    9274675445540201683,
+                    8527944798165032063,
+                    995409415717365706,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1871333143195325319,
+                    15064519559557840066,
+                    13319809013901247943,
+                    100595930169371590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4687365516738643626,
+                    6915275441715980198,
+                    16706327299891186400,
+                    351808001698809014,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5191137394774967560,
+                    18373318837199366290,
+                    8119298997463039081,
+                    574568402352827177,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            M_i: Matrix::<15, 15, 225>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10848452814009625376,
+                    14719107759072453422,
+                    2569370466765346975,
+                    564142059901799860,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3310240453149459859,
+                    9992712054877156206,
+                    595461009012257530,
+                    808244987791567243,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8020591319012646041,
+                    13491313047130005347,
+                    2988638193735570656,
+                    709530303331499615,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18124580308476232642,
+                    16843686763850589477,
+                    11126770297841588800,
+                    439664233598082743,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5979585221672675236,
+                    10919749539417039483,
+                    16052809417809065535,
+                    396742598423480841,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14210816771902947815,
+                    8553839968281724275,
+                    412461992787156771,
+                    1085546425839186441,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7893458161827867547,
+                    13183262711008019859,
+                    17018736342875966789,
+                    79800583543032646,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7071056117203656712,
+                    11175041607047453535,
+                    7760378046779712970,
+                    145952362005623594,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10818612111549326148,
+                    11187933934338474786,
+                    14885451337775946856,
+                    122490179708454716,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13905383233850391594,
+                    5249808260382325144,
+                    11398498957500102347,
+                    647582321584038971,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14764457015234778686,
+                    246501263120697223,
+                    11402723673286031628,
+                    758725725824742019,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8254452158813971993,
+                    6643163444436916960,
+                    3292197929914850905,
+                    53477486692476630,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9251337537787241224,
+                    17890627662737381377,
+                    4285804682902211840,
+                    1027326454275096024,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2333132314767380155,
+                    6926177274550734569,
+                    5478909134561161908,
+                    670103134038131691,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3310240453149459859,
+                    9992712054877156206,
+                    595461009012257530,
+                    808244987791567243,
+                ]),
+                Fq::from_montgomery_limbs([
+                    391783778987699890,
+                    10793794290159656943,
+                    8444344211687424799,
+                    1209387008752743389,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14810509939432696234,
+                    14101474467049789117,
+                    3535975107406280088,
+                    1321326061387059307,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4521077713553399972,
+                    17594661194187793039,
+                    12794150789820555235,
+                    1145904875099485756,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15664837609248931537,
+                    6361301837600716027,
+                    16389680725880956254,
+                    1221304292728106063,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9477537961215441527,
+                    372568660297534626,
+                    12463513330716951399,
+                    818377993233583927,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15419836726377068773,
+                    13460380510790037410,
+                    3049390334766149309,
+                    923819461618311287,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15855186725589829905,
+                    15119623529014587096,
+                    10849073392595286243,
+                    843354410926854343,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3827918399933509821,
+                    7285330141579220521,
+                    14191016087772154931,
+                    32441030310935957,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11677134658359380424,
+                    16483865855356246109,
+                    4924637721375565895,
+                    784688750078601382,
+                ]),
+                Fq::from_montgomery_limbs([
+                    262278613800815701,
+                    8801438944186724504,
+                    12517958972425311884,
+                    595272805854184544,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15407248934585057607,
+                    10245054821651858015,
+                    9306529275945156487,
+                    510775929640960598,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7138510894376525054,
+                    2801816324761436457,
+                    538806060137242809,
+                    381194063240975657,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18363520876003684562,
+                    5401563013052694340,
+                    6596856178507139942,
+                    198461828798576378,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8020591319012646041,
+                    13491313047130005347,
+                    2988638193735570656,
+                    709530303331499615,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14810509939432696234,
+                    14101474467049789117,
+                    3535975107406280088,
+                    1321326061387059307,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11041240083047687798,
+                    5207500104535098195,
+                    8017802004771830477,
+                    1160466864361155714,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2559934911501583176,
+                    15682355944954684197,
+                    269599075586700018,
+                    1014686146700526461,
+                ]),
+                Fq::from_montgomery_limbs([
+                    171247426029478014,
+                    1103785899621228592,
+                    16897159345319369790,
+                    846401551321784022,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2569283629639618839,
+                    9600364735143692348,
+                    8637550409594569448,
+                    556742717475086490,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1598120207354075099,
+                    17280521946928866015,
+                    17005449613644397611,
+                    576762715875878335,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16822406827114105372,
+                    11464817594689946545,
+                    3686272210094456812,
+                    865464347790014834,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12040104104719640528,
+                    17205693891797400928,
+                    16498096256292438449,
+                    889388420261177302,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18205780291167273990,
+                    13018426044887496083,
+                    16052149848762271871,
+                    708212256005733793,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11155358265264508363,
+                    13750871268421802988,
+                    2943024091198449350,
+                    69808580947520130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16344845010909994170,
+                    9926498354348312290,
+                    6127374995724526044,
+                    482409636863225129,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16795736790804604580,
+                    16818695002972943887,
+                    13236090768845857733,
+                    653007467823830651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1164483535612068032,
+                    10420478116503352543,
+                    7718098347913015059,
+                    1028491868312187858,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18124580308476232642,
+                    16843686763850589477,
+                    11126770297841588800,
+                    439664233598082743,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4521077713553399972,
+                    17594661194187793039,
+                    12794150789820555235,
+                    1145904875099485756,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2559934911501583176,
+                    15682355944954684197,
+                    269599075586700018,
+                    1014686146700526461,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2626006553190904231,
+                    14600730642684191011,
+                    17518151255225401288,
+                    187991290577645068,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11355495611030324649,
+                    11486638204769602729,
+                    11454693755707558818,
+                    945268982412425364,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6819211639566184792,
+                    8792937430104488006,
+                    1902841933189439608,
+                    211963230195296761,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6516592546496623015,
+                    12864104945161453550,
+                    1154332012659247311,
+                    927593296329802340,
+                ]),
+                Fq::from_montgomery_limbs([
+                    188046769125750569,
+                    11257998213892623407,
+                    13356757080820130835,
+                    767397501884272140,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12667799540060527040,
+                    15316168367820613700,
+                    8329718297519998210,
+                    1199284843257256515,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4427019552923200351,
+                    7901637461864419755,
+                    1765221970962641524,
+                    841650846792676368,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13098243436959101891,
+                    9350337138574214665,
+                    12142641124842600223,
+                    409769862037550904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14830220055483149247,
+                    9517457816252373219,
+                    17850107354823218892,
+                    22985726907611928,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13349043173643967687,
+                    3283121231197615551,
+                    3502867470493999068,
+                    1967984186530399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2400382165583320771,
+                    925045028937576577,
+                    1314074291668905654,
+                    380877922026243339,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5979585221672675236,
+                    10919749539417039483,
+                    16052809417809065535,
+                    396742598423480841,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15664837609248931537,
+                    6361301837600716027,
+                    16389680725880956254,
+                    1221304292728106063,
+                ]),
+                Fq::from_montgomery_limbs([
+                    171247426029478014,
+                    1103785899621228592,
+                    16897159345319369790,
+                    846401551321784022,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11355495611030324649,
+                    11486638204769602729,
+                    11454693755707558818,
+                    945268982412425364,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12626376180989235204,
+                    1823683396322740701,
+                    12446138680541603126,
+                    23583985089241061,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6193665688576552519,
+                    1684328973717041299,
+                    17191261841663395414,
+                    649537812769925040,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7560829857861424972,
+                    5756337991338747530,
+                    17342879634494817624,
+                    1248779475121523413,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17317958609597965539,
+                    11040868626267042799,
+                    9805951530890022461,
+                    268198819994695293,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12215011758757415241,
+                    17971654378912600961,
+                    3836701233062720633,
+                    10791354749748525,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15065236460773922327,
+                    13582210720818421325,
+                    14121485351626714111,
+                    447042716117296506,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1996240351930067515,
+                    12527536073715571729,
+                    7251084249939732850,
+                    743752832829229796,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9199627676965344695,
+                    11125588659678963947,
+                    10965385958214598118,
+                    906940276397600473,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6474810261232749579,
+                    15720010081567872769,
+                    3266498443579196484,
+                    706450141325008216,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16693862177047493338,
+                    8944343011606237210,
+                    3661632856689280364,
+                    345939449922194062,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14210816771902947815,
+                    8553839968281724275,
+                    412461992787156771,
+                    1085546425839186441,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9477537961215441527,
+                    372568660297534626,
+                    12463513330716951399,
+                    818377993233583927,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2569283629639618839,
+                    9600364735143692348,
+                    8637550409594569448,
+                    556742717475086490,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6819211639566184792,
+                    8792937430104488006,
+                    1902841933189439608,
+                    211963230195296761,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6193665688576552519,
+                    1684328973717041299,
+                    17191261841663395414,
+                    649537812769925040,
+                ]),
+                Fq::from_montgomery_limbs([
+                    470935273670039570,
+                    8494738095977702046,
+                    9143188843835578289,
+                    310657948644698375,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4618483881782962524,
+                    6769872434509048333,
+                    4647815368328468205,
+                    391880827508270505,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7766621468431185221,
+                    18101789441581950260,
+                    17129341431052257998,
+                    427912919401616408,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14347161781069240906,
+                    5569589805969445134,
+                    14053317513967539995,
+                    686187034915360900,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4659172130505131709,
+                    11065291766228237659,
+                    10741788564691744286,
+                    742700402226093885,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12186499031593482457,
+                    15290432718499985230,
+                    6661665019263122891,
+                    166402115587552357,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10892524014851962513,
+                    3895399727098071259,
+                    7365690294383730717,
+                    1211784033191764554,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4567476700043786393,
+                    14127420437724046015,
+                    15736350272019798613,
+                    882224087488949301,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9189172641516563802,
+                    15815339231202802455,
+                    9528370127355767159,
+                    685542399540613213,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7893458161827867547,
+                    13183262711008019859,
+                    17018736342875966789,
+                    79800583543032646,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15419836726377068773,
+                    13460380510790037410,
+                    3049390334766149309,
+                    923819461618311287,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1598120207354075099,
+                    17280521946928866015,
+                    17005449613644397611,
+                    576762715875878335,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6516592546496623015,
+                    12864104945161453550,
+                    1154332012659247311,
+                    927593296329802340,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7560829857861424972,
+                    5756337991338747530,
+                    17342879634494817624,
+                    1248779475121523413,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4618483881782962524,
+                    6769872434509048333,
+                    4647815368328468205,
+                    391880827508270505,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14939122051873930929,
+                    8146376262165674827,
+                    7370893028842117405,
+                    1024838706916775961,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13769275650081399984,
+                    1518194900983744144,
+                    18159915853221422140,
+                    767711815557669210,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2653996423012621826,
+                    7934246529325531812,
+                    2213144106125797387,
+                    833263785144552351,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8037692275971612397,
+                    3803454570762528968,
+                    8438891017897721640,
+                    910689014349821076,
+                ]),
+                Fq::from_montgomery_limbs([
+                    236948567474638787,
+                    16873330833884278602,
+                    3286187390432620493,
+                    311482788300203872,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15593384262824382439,
+                    12698375797567440007,
+                    16419056440487715525,
+                    609448383189526884,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12773039257717901935,
+                    14448394295891001663,
+                    17824188254884505945,
+                    112499656570606973,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4191342592428239107,
+                    10219378688680036636,
+                    17214351723167487655,
+                    542477551365220222,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7071056117203656712,
+                    11175041607047453535,
+                    7760378046779712970,
+                    145952362005623594,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15855186725589829905,
+                    15119623529014587096,
+                    10849073392595286243,
+                    843354410926854343,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16822406827114105372,
+                    11464817594689946545,
+                    3686272210094456812,
+                    865464347790014834,
+                ]),
+                Fq::from_montgomery_limbs([
+                    188046769125750569,
+                    11257998213892623407,
+                    13356757080820130835,
+                    767397501884272140,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17317958609597965539,
+                    11040868626267042799,
+                    9805951530890022461,
+                    268198819994695293,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7766621468431185221,
+                    18101789441581950260,
+                    17129341431052257998,
+                    427912919401616408,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13769275650081399984,
+                    1518194900983744144,
+                    18159915853221422140,
+                    767711815557669210,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7312294528252446143,
+                    1908137824228193959,
+                    13597860691798816041,
+                    1131146012894512931,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2586514869198441791,
+                    9197422480209640065,
+                    1734887690869468687,
+                    945968879644846197,
+                ]),
+                Fq::from_montgomery_limbs([
+                    99322855587874899,
+                    11652237058865517112,
+                    13953328707470530768,
+                    50651152144285833,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15812176254650678398,
+                    10157994720914419047,
+                    13659085106476977199,
+                    852450819165918257,
+                ]),
+                Fq::from_montgomery_limbs([
+                    235726130499025019,
+                    5589990582130807359,
+                    8882764553499097214,
+                    764545351584626568,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5908000493255741637,
+                    4942160517821119876,
+                    4016175015131593439,
+                    453142394661092607,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1940922741308133438,
+                    13122067836333167686,
+                    14089894079184334486,
+                    62474268138751494,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10818612111549326148,
+                    11187933934338474786,
+                    14885451337775946856,
+                    122490179708454716,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3827918399933509821,
+                    7285330141579220521,
+                    14191016087772154931,
+                    32441030310935957,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12040104104719640528,
+                    17205693891797400928,
+                    16498096256292438449,
+                    889388420261177302,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12667799540060527040,
+                    15316168367820613700,
+                    8329718297519998210,
+                    1199284843257256515,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12215011758757415241,
+                    17971654378912600961,
+                    3836701233062720633,
+                    10791354749748525,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14347161781069240906,
+                    5569589805969445134,
+                    14053317513967539995,
+                    686187034915360900,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2653996423012621826,
+                    7934246529325531812,
+                    2213144106125797387,
+                    833263785144552351,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2586514869198441791,
+                    9197422480209640065,
+                    1734887690869468687,
+                    945968879644846197,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4711746067670401243,
+                    16135996078602358826,
+                    7997018383987894855,
+                    984299513616974044,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4244095017035675738,
+                    16101100367239794476,
+                    2020565235925297203,
+                    1139043507576027086,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7767061128447679224,
+                    13441819116424751033,
+                    3017273394908140517,
+                    954778909211464737,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3383732309026703963,
+                    8453761582255091584,
+                    6910928867457257278,
+                    628135516403149176,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14054384993389366891,
+                    11196811156090729062,
+                    6618723373558404282,
+                    200965458054209761,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9758130266672919060,
+                    8540469276063668934,
+                    15223418339215966445,
+                    452961188934560638,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13905383233850391594,
+                    5249808260382325144,
+                    11398498957500102347,
+                    647582321584038971,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11677134658359380424,
+                    16483865855356246109,
+                    4924637721375565895,
+                    784688750078601382,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18205780291167273990,
+                    13018426044887496083,
+                    16052149848762271871,
+                    708212256005733793,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4427019552923200351,
+                    7901637461864419755,
+                    1765221970962641524,
+                    841650846792676368,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15065236460773922327,
+                    13582210720818421325,
+                    14121485351626714111,
+                    447042716117296506,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4659172130505131709,
+                    11065291766228237659,
+                    10741788564691744286,
+                    742700402226093885,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8037692275971612397,
+                    3803454570762528968,
+                    8438891017897721640,
+                    910689014349821076,
+                ]),
+                Fq::from_montgomery_limbs([
+                    99322855587874899,
+                    11652237058865517112,
+                    13953328707470530768,
+                    50651152144285833,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4244095017035675738,
+                    16101100367239794476,
+                    2020565235925297203,
+                    1139043507576027086,
+                ]),
+                Fq::from_montgomery_limbs([
+                    784723755390648544,
+                    13579737958979385669,
+                    2301095020281877706,
+                    79295411159649926,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7812844678681332937,
+                    12125480677855465805,
+                    8947874611538035470,
+                    699320006003617005,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17315773681085365459,
+                    5743132880819373586,
+                    12529640772461221733,
+                    371597297710781028,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3474253863748476133,
+                    9073908206605410747,
+                    6770101567686595936,
+                    49849090901927231,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14718379416979820974,
+                    4692350989763994481,
+                    2612836540611504307,
+                    502481186683066381,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14764457015234778686,
+                    246501263120697223,
+                    11402723673286031628,
+                    758725725824742019,
+                ]),
+                Fq::from_montgomery_limbs([
+                    262278613800815701,
+                    8801438944186724504,
+                    12517958972425311884,
+                    595272805854184544,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11155358265264508363,
+                    13750871268421802988,
+                    2943024091198449350,
+                    69808580947520130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13098243436959101891,
+                    9350337138574214665,
+                    12142641124842600223,
+                    409769862037550904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1996240351930067515,
+                    12527536073715571729,
+                    7251084249939732850,
+                    743752832829229796,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12186499031593482457,
+                    15290432718499985230,
+                    6661665019263122891,
+                    166402115587552357,
+                ]),
+                Fq::from_montgomery_limbs([
+                    236948567474638787,
+                    16873330833884278602,
+                    3286187390432620493,
+                    311482788300203872,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15812176254650678398,
+                    10157994720914419047,
+                    13659085106476977199,
+                    852450819165918257,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7767061128447679224,
+                    13441819116424751033,
+                    3017273394908140517,
+                    954778909211464737,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7812844678681332937,
+                    12125480677855465805,
+                    8947874611538035470,
+                    699320006003617005,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12365697384927820911,
+                    1979255247665929467,
+                    289111757138171671,
+                    1343947628040025037,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14965960876784644174,
+                    5316423662942412488,
+                    1237663499757596790,
+                    66302201147172511,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5037037466040930884,
+                    4702023643138241098,
+                    15774403794162114752,
+                    1068079605080916953,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7535555345473570122,
+                    4586246369306422757,
+                    6395009489034428275,
+                    1034163412336652890,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8254452158813971993,
+                    6643163444436916960,
+                    3292197929914850905,
+                    53477486692476630,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15407248934585057607,
+                    10245054821651858015,
+                    9306529275945156487,
+                    510775929640960598,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16344845010909994170,
+                    9926498354348312290,
+                    6127374995724526044,
+                    482409636863225129,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14830220055483149247,
+                    9517457816252373219,
+                    17850107354823218892,
+                    22985726907611928,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9199627676965344695,
+                    11125588659678963947,
+                    10965385958214598118,
+                    906940276397600473,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10892524014851962513,
+                    3895399727098071259,
+                    7365690294383730717,
+                    1211784033191764554,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15593384262824382439,
+                    12698375797567440007,
+                    16419056440487715525,
+                    609448383189526884,
+                ]),
+                Fq::from_montgomery_limbs([
+                    235726130499025019,
+                    5589990582130807359,
+                    8882764553499097214,
+                    764545351584626568,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3383732309026703963,
+                    8453761582255091584,
+                    6910928867457257278,
+                    628135516403149176,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17315773681085365459,
+                    5743132880819373586,
+                    12529640772461221733,
+                    371597297710781028,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14965960876784644174,
+                    5316423662942412488,
+                    1237663499757596790,
+                    66302201147172511,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7590420918733916657,
+                    18114852840819202623,
+                    8112022780871957445,
+                    262263589992743433,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8553566810888389398,
+                    2395750225308127793,
+                    8569539521407799402,
+                    1125999265145360829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16087030330508989187,
+                    4623171276258643183,
+                    11005066023260587652,
+                    843493505459312967,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9251337537787241224,
+                    17890627662737381377,
+                    4285804682902211840,
+                    1027326454275096024,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7138510894376525054,
+                    2801816324761436457,
+                    538806060137242809,
+                    381194063240975657,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16795736790804604580,
+                    16818695002972943887,
+                    13236090768845857733,
+                    653007467823830651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13349043173643967687,
+                    3283121231197615551,
+                    3502867470493999068,
+                    1967984186530399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6474810261232749579,
+                    15720010081567872769,
+                    3266498443579196484,
+                    706450141325008216,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4567476700043786393,
+                    14127420437724046015,
+                    15736350272019798613,
+                    882224087488949301,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12773039257717901935,
+                    14448394295891001663,
+                    17824188254884505945,
+                    112499656570606973,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5908000493255741637,
+                    4942160517821119876,
+                    4016175015131593439,
+                    453142394661092607,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14054384993389366891,
+                    11196811156090729062,
+                    6618723373558404282,
+                    200965458054209761,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3474253863748476133,
+                    9073908206605410747,
+                    6770101567686595936,
+                    49849090901927231,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5037037466040930884,
+                    4702023643138241098,
+                    15774403794162114752,
+                    1068079605080916953,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8553566810888389398,
+                    2395750225308127793,
+                    8569539521407799402,
+                    1125999265145360829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2831900111301622792,
+                    12231228396464836366,
+                    6565189472832405983,
+                    988696503950958156,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17127634788509336235,
+                    13559422189452565684,
+                    10647172227790833868,
+                    148918887438551926,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2333132314767380155,
+                    6926177274550734569,
+                    5478909134561161908,
+                    670103134038131691,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18363520876003684562,
+                    5401563013052694340,
+                    6596856178507139942,
+                    198461828798576378,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1164483535612068032,
+                    10420478116503352543,
+                    7718098347913015059,
+                    1028491868312187858,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2400382165583320771,
+                    925045028937576577,
+                    1314074291668905654,
+                    380877922026243339,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16693862177047493338,
+                    8944343011606237210,
+                    3661632856689280364,
+                    345939449922194062,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9189172641516563802,
+                    15815339231202802455,
+                    9528370127355767159,
+                    685542399540613213,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4191342592428239107,
+                    10219378688680036636,
+                    17214351723167487655,
+                    542477551365220222,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1940922741308133438,
+                    13122067836333167686,
+                    14089894079184334486,
+                    62474268138751494,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9758130266672919060,
+                    8540469276063668934,
+                    15223418339215966445,
+                    452961188934560638,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14718379416979820974,
+                    4692350989763994481,
+                    2612836540611504307,
+                    502481186683066381,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7535555345473570122,
+                    4586246369306422757,
+                    6395009489034428275,
+                    1034163412336652890,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16087030330508989187,
+                    4623171276258643183,
+                    11005066023260587652,
+                    843493505459312967,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17127634788509336235,
+                    13559422189452565684,
+                    10647172227790833868,
+                    148918887438551926,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2948618268584239232,
+                    8608478740054498938,
+                    3796280530733398724,
+                    480037351985850734,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                    Fq::from_montgomery_limbs([
+                        829043150522309210,
+                        7485532467658058089,
+                        17072453966431109601,
+                        610297755857677130,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18043687544558598371,
+                        8708325242362018018,
+                        16625116017321440141,
+                        277441131490434312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1903578231313020389,
+                        13252279451564941743,
+                        6429417326301386859,
+                        900077037001125169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14902495609461984461,
+                        16049616749407607193,
+                        8772353492731922841,
+                        1191393277823112260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5443236819267423281,
+                        9444106377351672003,
+                        16592192658774520588,
+                        1198721234626210409,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13217949757478296855,
+                        18361600593446830079,
+                        18223280994163007859,
+                        471593720435477782,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14891635570577939411,
+                        4930228641865103982,
+                        677506429995745101,
+                        158637391136506271,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        241833917490571947,
+                        2153702484066609835,
+                        8471674463316687531,
+                        1217041126633955783,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15611345302311497892,
+                        9150344584784175431,
+                        7017882794185538273,
+                        953114622258489808,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17306802895913322969,
+                        6741982267855984797,
+                        18288890876308278666,
+                        1226905683868895599,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6363878173450358936,
+                        10112955129707898349,
+                        3431100839901599668,
+                        1081814334785738474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13487175089423272229,
+                        13310042664491099282,
+                        351156267858854473,
+                        1235361018641701157,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12160849746824021663,
+                        1940579672552365232,
+                        5173518843609944629,
+                        775261558130140407,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15144329526952556408,
+                        18203319233474217028,
+                        17244027956048610372,
+                        1255512899850221067,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7478528667476135442,
+                        9480979810112190959,
+                        16027776518155980265,
+                        244811928723357602,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17632830819387950851,
+                        7381918564425502443,
+                        2425665154995255926,
+                        960388326003357609,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14330149474250522082,
+                        17470597591851444465,
+                        10364459309187484767,
+                        418284760117495402,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5283442699880150997,
+                        4817348815440150972,
+                        13421797427243273941,
+                        780932762210485740,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8154592809540622850,
+                        16052358235041287483,
+                        4242518626099520110,
+                        1313646878115360617,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5805076131352176811,
+                        6336159385742172852,
+                        5843152280919458898,
+                        308713250755092005,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12266832440517877631,
+                        1537679286183492205,
+                        9387979922308351839,
+                        627581905285369151,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4474524191518793150,
+                        11372965873278868060,
+                        14167079016223884669,
+                        269384102632879212,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10161909798975831802,
+                        12424200247158954345,
+                        4252844313951873517,
+                        614704874521026805,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3393175082715144034,
+                        7530466771929336970,
+                        9844295439315404707,
+                        555056145893047347,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5290467093665068100,
+                        16644571166359544696,
+                        2345080986198185984,
+                        1048166405351148261,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17147182078627258806,
+                        10384935101441273970,
+                        3067063291081951405,
+                        1171685986274971937,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16240636754265401715,
+                        7667575448621407260,
+                        5765076182843688960,
+                        717188278474732736,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13784239273450123906,
+                        2064189148824236944,
+                        8746916025435654999,
+                        1039907268194438259,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11889040410658275672,
+                        13900317561416703074,
+                        12608988619756975572,
+                        940375189532201913,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16592211588461275185,
+                        9948169664073492551,
+                        1096402765570570432,
+                        1050283536188812240,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17230812414714464203,
+                        10725710982627060656,
+                        14654715414689431965,
+                        533982307648729148,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6653502428349875005,
+                        16538466790061793415,
+                        13598177074516660366,
+                        989922918366073095,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5218070412454179004,
+                        17662445103129008202,
+                        8370166119325893258,
+                        503924528497984064,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11649715185072375837,
+                        17803892041703896821,
+                        17854482107452793163,
+                        354421166929056537,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7455560809037577320,
+                        833394754550916958,
+                        911021434369910141,
+                        133611051541169224,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13241868990615435852,
+                        7323897851125899194,
+                        15308085374658352867,
+                        40468670695054198,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6665844100528087135,
+                        4164250145024431803,
+                        17053259085906330236,
+                        62564027160997583,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4591929497476280538,
+                        16513216231338021764,
+                        5189131464947647563,
+                        365266277753488979,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3881697766968952440,
+                        3329934317554131041,
+                        4715420597194837804,
+                        978980913094379988,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14560871174007111112,
+                        5912421479357417950,
+                        11797592939524632347,
+                        858071459996345694,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1684027695655463057,
+                        11053780060154430607,
+                        2796042777572302776,
+                        1155276681392407787,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12550270979766983189,
+                        2931591509085280806,
+                        17267196383041444731,
+                        420446509603490829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8437317257845628485,
+                        10389289879602786487,
+                        16465858176059382016,
+                        34291582280829266,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11260578679092966705,
+                        10639963144729930526,
+                        11475740511238415310,
+                        609041660428117652,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2264467045449993390,
+                        12601546206481066787,
+                        576070823381276044,
+                        653218349229324692,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8531403118081872431,
+                        10611169728616170650,
+                        6718841048701288073,
+                        672803549457856487,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17434521802127695842,
+                        17986933641692293762,
+                        14019377069837521552,
+                        573368210714995629,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12152257380844276805,
+                        4061448197313730829,
+                        10801598217391010794,
+                        472324859564797112,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9486378302574785591,
+                        11133580133818271830,
+                        6722457385737395470,
+                        391802012643465063,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1244601787582987926,
+                        10814034122985145910,
+                        2584093611069023587,
+                        329485454591367801,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14650749870307221279,
+                        7929822466944334631,
+                        559450214359602421,
+                        688335374161954327,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16395342575047528261,
+                        74282526464283369,
+                        12149887943659159770,
+                        276694267059056222,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8982782735723698920,
+                        3435385724478866011,
+                        2513109967426638668,
+                        609413236919207419,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10271052236694007451,
+                        10989299760607971868,
+                        9696361845050740835,
+                        491237364590129749,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8073725195679695234,
+                        15674395015187048245,
+                        10529607163624201391,
+                        745111979054500710,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8528275298461744822,
+                        14814511096754991431,
+                        3168760279112802927,
+                        222157578596220000,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11273066816255965533,
+                        14649508374592199409,
+                        836973105286357200,
+                        1189407063641069864,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8375986616475577120,
+                        4486676664412087266,
+                        16689573768319345546,
+                        6569855577600111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9255502719564447429,
+                        2130093765632613166,
+                        13398255847837237689,
+                        728399618164971917,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11496620294451012497,
+                        10183897855499541461,
+                        9773137490440517946,
+                        968353933177821905,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14180176579884665995,
+                        9800663436894780042,
+                        9653130729377225776,
+                        57443629539933812,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10963550310635530406,
+                        10490467307444944017,
+                        8534102759478026858,
+                        82002487348273631,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14556180382837402423,
+                        15686267633830870551,
+                        9866172973250383400,
+                        1195174992098077030,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11807627613997743112,
+                        7691908995452121804,
+                        14282713271019095,
+                        324818758745302572,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2803696345689684190,
+                        1523303865592689595,
+                        1025047330041157455,
+                        612239446062047339,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1534811705529590763,
+                        14086705197747113908,
+                        17764679815422391049,
+                        305915174202891601,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7106363618663481535,
+                        14988859159673357742,
+                        3602664020057166040,
+                        1316426182163598845,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10769311146663184917,
+                        12315449291689921797,
+                        14948121245076831815,
+                        545002856461119112,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6768571916457296379,
+                        17945474862156591866,
+                        1276960872476928670,
+                        451278021193945,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5853081616288170001,
+                        16367236027841848049,
+                        16862396335437198171,
+                        825918050166336718,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17513622332243105089,
+                        12493989110756286017,
+                        3966896647007389116,
+                        1080912308354859684,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1890424653790037555,
+                        13176861269179846272,
+                        12036715929075710169,
+                        739727622513107759,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5021949056837926852,
+                        9008963947662683740,
+                        3840133296317595566,
+                        84600778677638173,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17278021512065631355,
+                        10538937394134115254,
+                        2564347383156839826,
+                        316228212567092097,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13876213228048482849,
+                        8245339435026677976,
+                        1475189390195013509,
+                        376188565191379017,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5912607903618107373,
+                        4744417702851998890,
+                        3706360839824330873,
+                        909702574719144099,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17491109374857838707,
+                        12500514635047477979,
+                        15920835756378727856,
+                        1019108015900318238,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4297592899157636660,
+                        203194985059134974,
+                        15101823654379074015,
+                        201186197550170139,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12026224833958288093,
+                        8691032210485641748,
+                        4933270927518624932,
+                        976169772909795679,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7037607493654736440,
+                        1006261299720493677,
+                        1592968122635039756,
+                        1178621562049045561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17189784593951106316,
+                        12246118749041030082,
+                        14669833586980726041,
+                        140908721451028175,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11233810483387634800,
+                        17719598156031096153,
+                        17778227033706248840,
+                        511040599555144778,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15678587619846590530,
+                        15164047457513896794,
+                        4222409027341482151,
+                        274720055462878637,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7009148895517651234,
+                        11647470437164694042,
+                        337894401635420886,
+                        1007656918211964297,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8585617446975610761,
+                        11204361665666862414,
+                        8136281592567346716,
+                        1284294035096738580,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17433695362319399333,
+                        699376563392436519,
+                        394090333167879373,
+                        857799810729837122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4995075923656623,
+                        3414842979375307973,
+                        13596592256618312608,
+                        1324310819114498877,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12922410080374153564,
+                        9944949044372907518,
+                        4656073986227847785,
+                        1227834938960942054,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2996455694056136372,
+                        10001076557328556747,
+                        13542001072965337852,
+                        905764204615260111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5448650238083649692,
+                        2419377548603449975,
+                        18007688398408850959,
+                        1174379756945594652,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3710167024345916055,
+                        8080543424589697197,
+                        8716606117774420425,
+                        772011433195707100,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6646892813716435844,
+                        16119079737857822561,
+                        17474974413671737305,
+                        971892142476256346,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7042228331602455769,
+                        1637586312203530427,
+                        17127343231222218767,
+                        733413957504288974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        459959122809179786,
+                        17267849186365101164,
+                        15986405746853109559,
+                        602199436600512831,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13646971348709969882,
+                        8974951490154700484,
+                        6853105805238792388,
+                        642598092036467463,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4147663235509669598,
+                        4957342249488515874,
+                        2703038948544173142,
+                        1103480942017927501,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17074622546799612515,
+                        10577732041390343507,
+                        3276609026878094852,
+                        76719963331655932,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4941600495488996441,
+                        15770873768765054964,
+                        12121030039454115318,
+                        134832196927570041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5478136344448091662,
+                        9134578550272563578,
+                        8024353985968253648,
+                        696311265229505210,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3361824922068404016,
+                        10923276037212997947,
+                        5511862064693516716,
+                        267995068032100889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        343448486184440116,
+                        8553679677816056889,
+                        7124589195027163744,
+                        248197974093241540,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11587350223087083428,
+                        9416053751656818747,
+                        11540347004868385346,
+                        1235599858173599778,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9299333655146892065,
+                        16387579926199696225,
+                        15238795861544096820,
+                        1022415787828035222,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4490611681394173222,
+                        13388786740706654002,
+                        13594495241321306026,
+                        1011662907381428656,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13443836704932137610,
+                        6369563118548313553,
+                        10844057723163048454,
+                        1006572492374129422,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16501008446693819256,
+                        4746154225767978784,
+                        4906847352670698661,
+                        1222936327601387789,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13153575624564094154,
+                        6121404043909846083,
+                        15723384972348213806,
+                        1235456106999884886,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15134357551170651636,
+                        4743587936933076045,
+                        9525948427162670997,
+                        1263051872157581809,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5350542683645282837,
+                        13750970766903064461,
+                        10220583244881984731,
+                        547787553624965221,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14238638635124897332,
+                        8209496241479750468,
+                        3744904536304017532,
+                        516714302638555859,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4893241637200635390,
+                        15913630133145182436,
+                        18172737207869362379,
+                        668408665536143491,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11746252981830259450,
+                        10277558128450968187,
+                        398357482649587129,
+                        40247026078598648,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14023129991555340814,
+                        3787253174197562142,
+                        3338558809153491551,
+                        184069443338293372,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14381188255553066019,
+                        16673965327647509756,
+                        18081699821844943383,
+                        129367764377320295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11975173021585287175,
+                        12013909520619356108,
+                        1826293915233561231,
+                        117832485075794757,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12674200958046695684,
+                        18084494838828202919,
+                        15155104108870127035,
+                        1328735817721589072,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7516640272611396310,
+                        7345212986866968531,
+                        15928520844128735134,
+                        306408643009202938,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18427614190123268622,
+                        4910317973054969368,
+                        14535350565294308059,
+                        485598118446839623,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18195273976128195363,
+                        11005822763644401096,
+                        5192732692560431652,
+                        1277942724572340675,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2015920304796041922,
+                        1062943331402479158,
+                        9909483318967201752,
+                        293241210716166597,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        928271989426978075,
+                        10905675353218575398,
+                        18052348683739763263,
+                        1077661403743948547,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16408726831534462496,
+                        10996657849902343020,
+                        9627987746934968471,
+                        1032489430331323224,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7061761893257408359,
+                        2389601317236019575,
+                        669425016678451433,
+                        210981208161200400,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1712892406914275075,
+                        7369868503388335423,
+                        4524533500062342345,
+                        430243190300518304,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16181345586950394045,
+                        6707738646611924348,
+                        14704260047596435420,
+                        136052855108886271,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5134943735186056260,
+                        13505916809224577627,
+                        9145034274352838012,
+                        234672242275145077,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7440907611114199404,
+                        5552738383974833556,
+                        10434941006736698841,
+                        324498801268331914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17677615101530450481,
+                        8308880559306227788,
+                        11426938846889101957,
+                        1085445520308394484,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16406084486421643470,
+                        7464947666090030013,
+                        13101046583660719757,
+                        569404901425693391,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4462107564663499840,
+                        995755893197597087,
+                        15920679930053201011,
+                        884034072078556207,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3937788236452003068,
+                        4978063954843563340,
+                        5073760485968580584,
+                        401120728727983509,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15404244517684387187,
+                        2795886215807697479,
+                        16063835617901360830,
+                        882753985777171537,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        285613345515334945,
+                        10149868858883115026,
+                        16734114134992486033,
+                        438647475643738178,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1764092550370821926,
+                        1170241145149439010,
+                        16268215419715253359,
+                        679897250394395112,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16142332081519425608,
+                        9193659395480888045,
+                        13069563992283968496,
+                        962013194230042353,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14954761831880987124,
+                        15848583772403614373,
+                        11400764400165744782,
+                        844023461618570618,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17206797135599219368,
+                        6319161401606035534,
+                        5316250520130608779,
+                        149974119411794656,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6347752531548455414,
+                        17645692856560013940,
+                        1032183502356347918,
+                        967722649436394049,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14075185717257625818,
+                        4808995186129516348,
+                        7355761974491783243,
+                        1333337278339624283,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5188102905359020134,
+                        4125246528339696669,
+                        16458182498733347378,
+                        374249527586866370,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7837342806501782450,
+                        10758368753110392315,
+                        8670294165166134786,
+                        905594758607836987,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10775043327613375613,
+                        9141972891332122698,
+                        8837407769933628373,
+                        160337615640772603,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16233359977948481598,
+                        16811834448295595284,
+                        7028118059183310118,
+                        857277615777733317,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1213138698600584710,
+                        9540803719749049286,
+                        6859792530068158499,
+                        21570906450225598,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9951727664782458762,
+                        14522098616795587213,
+                        11932116209093542942,
+                        547626202738955268,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11346258729047434999,
+                        6495560392540207887,
+                        13990270071670235907,
+                        163129566196895209,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10374999743281093980,
+                        6413078647983992552,
+                        5638208691682347944,
+                        720296687816191908,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14251919293697620744,
+                        2833249552598279296,
+                        18026674142635942825,
+                        338815546764279475,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12852113848809581061,
+                        809694738791222639,
+                        2811424122823030094,
+                        44023586445284839,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4801257131521816137,
+                        15999450542878005279,
+                        13254046329485804534,
+                        919894659525634498,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11639983178477795711,
+                        8677244984040185903,
+                        6446492411877849261,
+                        214970812911059151,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10451600872334310596,
+                        12221506751228628171,
+                        8854428515087687098,
+                        709119693108627258,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6584929403571980889,
+                        703861539172678372,
+                        343456624204338313,
+                        748200930120541853,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10325416364559922854,
+                        13663054164257232905,
+                        16994522316389984158,
+                        24264922939178474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7090142260803256941,
+                        12572625282811588526,
+                        7415765851335001974,
+                        619078733142413898,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7759375353514307025,
+                        18007187843797485251,
+                        1374792436434527532,
+                        821777785146519232,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3502757193205818654,
+                        14590699224699466808,
+                        15263080294066420580,
+                        506183362203359321,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2654348372913269726,
+                        3133477509207628559,
+                        12252533270424259700,
+                        899751342656300791,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18371612513237308941,
+                        502438377940552705,
+                        11639744425446829077,
+                        487801446888663314,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1723978731692865398,
+                        14534987445175376659,
+                        17342690189959532987,
+                        615982957667117297,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10872822677872519167,
+                        12668944773138384821,
+                        2572376163281905959,
+                        1078137687658105288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14449469331231592634,
+                        2635325782505862678,
+                        4248032632667252055,
+                        265453664042720673,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1436739582816428020,
+                        7187870551661633266,
+                        8951111601060856041,
+                        946650292073095481,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4473567210542918110,
+                        6097498617067588368,
+                        4658811148255225439,
+                        173121870277478441,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13443840390509499666,
+                        17914026516359654928,
+                        17292837880718054431,
+                        1036531072203940184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10379627709238100006,
+                        10813741897846518584,
+                        8010990244527741425,
+                        355042347911882329,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12917173648391002924,
+                        5948795666444256541,
+                        15435245022895774084,
+                        1900037587103451,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8048987125048979745,
+                        16881697444143021060,
+                        12659169752157252750,
+                        202437665267276880,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11249779642591819604,
+                        3140202770938036050,
+                        13146503102815023868,
+                        427623199282487658,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7064764780435074738,
+                        3400319867142737026,
+                        10610652868810178260,
+                        113100263555639253,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3190484108325918168,
+                        14901245344787753341,
+                        3871514927006123737,
+                        1179992271855183548,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13883803598322555024,
+                        5680970196703071893,
+                        16962968870765434170,
+                        773256758767622553,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13763955070420934426,
+                        10673181910293554003,
+                        8942728555970579020,
+                        1017161569414530536,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10712994354038137531,
+                        8671705610147067107,
+                        8318740998553782052,
+                        401622295568203041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12852382241541421211,
+                        9901179194088645896,
+                        3217243358696021762,
+                        788924819076471890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10045594391938350771,
+                        3693138191499929188,
+                        13052255271386172819,
+                        1252894470251180642,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7900094789458307506,
+                        11564403948571425006,
+                        9258089285272487274,
+                        631933118579241571,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7852339588499458681,
+                        4802192788931567559,
+                        6708667761111996766,
+                        1004392559135821865,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17812427493881309872,
+                        3387757165133676887,
+                        12785629232133447872,
+                        1103927947472210221,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4710828467487209996,
+                        2254098335338302344,
+                        1974834118736897488,
+                        370736705990260820,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        519089945593398138,
+                        3501924526596883817,
+                        14125953892227625407,
+                        688140399918668757,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4787076358811306430,
+                        10747596435681193959,
+                        12652492802098485150,
+                        1092952132955942757,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4840022623452722953,
+                        7679260363922608245,
+                        6668647327159273202,
+                        205477453070146364,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14822971881436710993,
+                        11367596017656400059,
+                        18007692905786042889,
+                        412478227166113375,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12464889930570546825,
+                        3620146150145099991,
+                        18226436929231454088,
+                        1113548888734920335,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        37234827537989514,
+                        8396140246011398039,
+                        12182543710617951325,
+                        504568515754043867,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15722253655353743782,
+                        6446653910359017826,
+                        4334841294285752129,
+                        336710352554307466,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14682692708535967639,
+                        13779067253942986341,
+                        7422041493155913969,
+                        376883922492663431,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1731413701883960304,
+                        9635431623137522638,
+                        11070977156409758550,
+                        409645689131744546,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1525893339172564224,
+                        15721892365037256900,
+                        15240705693448059313,
+                        526650858899246717,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9653027129440482820,
+                        15772061990771743263,
+                        13488688038693936214,
+                        1128673244908873559,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9770296765335661532,
+                        1393095696427670996,
+                        9517404999397165646,
+                        1201046150307672629,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17565458417398587628,
+                        13884165543772466379,
+                        1952683035226388436,
+                        29884686118414195,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15100907845946014281,
+                        6335421792212145700,
+                        16923529050041015885,
+                        59382277278014096,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15952674849469931180,
+                        4290664323084245677,
+                        2845908680104220,
+                        1004113516676405112,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10248968063120787655,
+                        13905882599786926167,
+                        14400905068084786915,
+                        1289456479063148921,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5439129148483428627,
+                        4957089496325470609,
+                        7309733221297554852,
+                        843766175205157563,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10868467551291895310,
+                        15913246094452458722,
+                        16987793394293672300,
+                        818408192226067831,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12871350383103353246,
+                        16633634277069598970,
+                        13892193021765707514,
+                        152753607222184260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16861225622073860024,
+                        15977859116721901329,
+                        6270418692442184701,
+                        243382608957086678,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1594557849813888198,
+                        11003431027799404821,
+                        8899992281981911057,
+                        1233266946271728367,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11487566664101323159,
+                        16327706264451888383,
+                        1528905545087611404,
+                        1132129804481004121,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7627592940546545568,
+                        4009241957771941489,
+                        10266917883594598695,
+                        59240589731262104,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        339024219313451287,
+                        15876205801508974851,
+                        8215252780991119987,
+                        509015814077086943,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9137385707474603232,
+                        3678770895844260330,
+                        12483853037887670879,
+                        414416412875795720,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9318678188065856643,
+                        7533350014269650833,
+                        16225636909534959097,
+                        674333025469929367,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        745355720490694619,
+                        14358619485452968919,
+                        17267897806744868975,
+                        55227662373900411,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6376655777412088725,
+                        906833579158934080,
+                        2750053183853999682,
+                        165199939537357441,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1426626231230643389,
+                        6040504990418034981,
+                        5140674581724011935,
+                        1221145948011604732,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4576199405106116328,
+                        14320210912923923597,
+                        17573260232337802642,
+                        5221000937095282,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15775130228737441903,
+                        12675957607686293836,
+                        16211502880164497124,
+                        978616012414703325,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5007102683248968663,
+                        16710183549464390099,
+                        5622722052833679004,
+                        258386317019064890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5343654543868634926,
+                        10086969666700965726,
+                        1958965178667899722,
+                        649414072698609164,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7607257818654972172,
+                        13936538765767722534,
+                        11859750482938664404,
+                        1334609395024898504,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11852341508043979682,
+                        18152640815575722635,
+                        12142127029738880460,
+                        237507739501873474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15110566897997928980,
+                        16426356071495194124,
+                        7979609341993429717,
+                        1323869051104109567,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12143205683947924437,
+                        8973397434702203297,
+                        12427113644258529114,
+                        666715151859513875,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11995756903422671563,
+                        2373841944008269627,
+                        11144780222220854650,
+                        364429181574372131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1087613830390931032,
+                        1521771181829579536,
+                        5624301994817947110,
+                        280092712564661717,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10736236779565186541,
+                        2266970488255005304,
+                        7214582085974706319,
+                        299972278616227841,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17309967243658836635,
+                        5444227791430883679,
+                        12241805085626399237,
+                        1009756401156287534,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17391734476461164207,
+                        11659976272417364639,
+                        2083837425874665210,
+                        660701638944765268,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5471082469709602850,
+                        3527738107219669693,
+                        5742291632887472797,
+                        263348288401762885,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10327452005815850890,
+                        3173759319032359834,
+                        530174972988267980,
+                        1168564157399150816,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        871823107408341711,
+                        13644042335055703831,
+                        16063035936192858274,
+                        297192382018093967,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8944790805746576335,
+                        17062146465998538341,
+                        15459069792216963274,
+                        245245208365503486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16951710936125912489,
+                        17234200245042404096,
+                        3765133795692510945,
+                        1210052709402716003,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5638459907348520247,
+                        3305672023187995981,
+                        15244722277390882283,
+                        868494249381725798,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15689372852478951369,
+                        8689931250588638220,
+                        4874143659780420548,
+                        119163638304551237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5910234186481040377,
+                        5805592809510084087,
+                        13264480741655794990,
+                        648353152953742974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17444097980044912434,
+                        1746274265787826813,
+                        7090357269388732871,
+                        89038797204626421,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6462499806566111875,
+                        8439568198367591947,
+                        2177417193502290757,
+                        1321183769377346532,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17759807729005910806,
+                        11404616450071573307,
+                        13781852674563685459,
+                        1218506078647580704,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7491300635711434870,
+                        15699760285774906405,
+                        7677915685710573546,
+                        566958545164639783,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9938748458333812134,
+                        4928527710208385172,
+                        6041378140269801941,
+                        871656232092947547,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1441818414067001702,
+                        12602025821648209044,
+                        8700496739244909166,
+                        205618843212136418,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16908847684285533757,
+                        5020399570777678974,
+                        12055405001840210111,
+                        666670688910644196,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13797648376420247712,
+                        7941507218939975945,
+                        10227141976972190492,
+                        1048468879304016249,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16281004518580556168,
+                        709512407086267019,
+                        7603174629652580825,
+                        1176479636617601055,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17684578962796990305,
+                        2881760030364550656,
+                        5027623675280364260,
+                        384586571403990153,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8568439376714289478,
+                        9089398681550248715,
+                        3672561080097502680,
+                        1080474046559919740,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12908664784159832723,
+                        17903044699786642222,
+                        4137379883377484275,
+                        805609829840715383,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11642988116476373274,
+                        10959225097985126951,
+                        14640377446099886871,
+                        93430662754800327,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2904494858600636539,
+                        8756876311877628274,
+                        14075263709012982394,
+                        36090569850839327,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6181271434836250080,
+                        11758809977942607518,
+                        1846463544002353100,
+                        184465935107673234,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12394219802316408548,
+                        6042211564143765858,
+                        17101444918449289676,
+                        806829135452046561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4085454528817250181,
+                        9078829971329075553,
+                        1392933444945921274,
+                        64597890711921544,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3625276521404382522,
+                        5724145442980236244,
+                        508334493165371500,
+                        770518857744074829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4386668308156950276,
+                        16920818824496412521,
+                        11890471456025790304,
+                        964451638743509614,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9963467901874189741,
+                        4576521951144005087,
+                        5218859665754757694,
+                        1287153477487612015,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17754608746826082419,
+                        13818111893946027666,
+                        2038578110290794014,
+                        386638920276934071,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16168893052714293761,
+                        17557045081308799717,
+                        16612166589207541922,
+                        928650364559313343,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5631208438634856076,
+                        8369102476634355040,
+                        4286017272923273881,
+                        1125359547494763368,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1452932236729762362,
+                        12473584098146303476,
+                        330649788772637236,
+                        877531536599899737,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        503877888514626909,
+                        11178251579219823338,
+                        6465225110064443513,
+                        380985002173723839,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11709086598246811942,
+                        15538281204029664185,
+                        17674744779509416132,
+                        994537433935496667,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18089743194892105280,
+                        1711453483716787422,
+                        10798420340190707540,
+                        355090501309520081,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13007336076096598621,
+                        14219914328060204730,
+                        1472024000460041009,
+                        53107955023144839,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16365962011872444497,
+                        10330037136624549279,
+                        10456220972441048687,
+                        160145215807512301,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14915716778063131845,
+                        919920933812433336,
+                        18074506013740945550,
+                        228231644050110888,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16879645833720844288,
+                        7869120497943099640,
+                        16748042819995995187,
+                        212991824818031272,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        290691617559290374,
+                        10735196016537823599,
+                        4884577995775216188,
+                        423212188516464296,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18157605536654169732,
+                        4651423607018449658,
+                        18440008399472658942,
+                        1222474180773090306,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18367257384478635491,
+                        16785204788618271711,
+                        2868624782756995095,
+                        746011552456251900,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5278340568752348896,
+                        12435751811937519027,
+                        16663595233602917309,
+                        862225406185601402,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3920209808485711618,
+                        18445881744821399713,
+                        9867566369132592032,
+                        741313996371219999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16573460366694968828,
+                        9426596072296881506,
+                        256171462767268387,
+                        22775586106739870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16215807911695256884,
+                        5901861542858001920,
+                        5161985217687817210,
+                        486179176177808122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7938023501835986738,
+                        11567037399894831120,
+                        9234757283115256045,
+                        1194294800965073125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13823449374934439749,
+                        8224485311317380372,
+                        7157524628143381281,
+                        984602001496147850,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7919638488323567903,
+                        17523693271811813563,
+                        9609517029148829749,
+                        264641587708685211,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17257269908738969317,
+                        1651753617787889183,
+                        12518766623015818905,
+                        707282548626109314,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5612438568332512374,
+                        10223805551879544754,
+                        13230209891307116263,
+                        454454395663876189,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17654149597913419723,
+                        8587582644605147015,
+                        9986917398489978252,
+                        15713250066293326,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3583522961997803539,
+                        12274865856600189546,
+                        3643180307337263838,
+                        1287942151146685289,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5449937448089922100,
+                        14395815245109986278,
+                        4171891301020586645,
+                        395864128244342598,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1831769976545052595,
+                        14237989491035373778,
+                        3810865199039731093,
+                        1187540077778258654,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5981404383826649952,
+                        11775885296172738594,
+                        475627027452416985,
+                        114055679222279775,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7155537240469286422,
+                        1353686144036656099,
+                        8891461269516860564,
+                        116400518858396964,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13878913618194495097,
+                        2447606129737569508,
+                        6632794416046023510,
+                        610159168298150141,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10781728390611009134,
+                        11368397405265422971,
+                        13155978963257598653,
+                        118978675179719811,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7097762850746720013,
+                        8690787216045550137,
+                        1969555915411389263,
+                        756327700091226875,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16509737933349510986,
+                        49230121633071866,
+                        4753710284186691240,
+                        516623455939995341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14515511008845898660,
+                        7484085446787805787,
+                        10100039707321049165,
+                        1306861347980800577,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6404374834001797900,
+                        7149357339143130074,
+                        12313326965356547771,
+                        32716658704050306,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4926205005536285417,
+                        5779583563756523507,
+                        14835636269876622520,
+                        272278119089297590,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9256585247329769123,
+                        8818863041185028079,
+                        2985001807255869699,
+                        366573203958075309,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10054524987390969207,
+                        4721897502911363351,
+                        12758280208114294275,
+                        682274721622022496,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15396630774699389287,
+                        566104890612997811,
+                        4402213024329785141,
+                        694092393553838230,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15161819546209415011,
+                        7485928969872221936,
+                        13534303768056752460,
+                        1273147264660936191,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8018504769302285424,
+                        10132334875652948081,
+                        14378056690109184881,
+                        794387060221143779,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8380292735486137310,
+                        15730069301077978746,
+                        880551419338538668,
+                        524598280943769655,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18376309152145998259,
+                        480164824859540678,
+                        464393916834433708,
+                        1090517056147402747,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3644733179088910047,
+                        16304478284047352903,
+                        10269509163619357050,
+                        565254404352126261,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3719819309463377696,
+                        7540292942168502368,
+                        14103741080039766500,
+                        750776658882609807,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9486639536694770054,
+                        17568608888423934889,
+                        12033839230262798820,
+                        166511418076854982,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9768462559641988497,
+                        4867991528127907713,
+                        13764712917888659726,
+                        253383135187013110,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9345090829865039332,
+                        3155317211272125134,
+                        7995226493245594630,
+                        89353223434947891,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        417688966547300336,
+                        13141394826558786775,
+                        13007606528001913860,
+                        702077499084221452,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5782404435985861516,
+                        14091544082741649993,
+                        9301125819160040305,
+                        1093587823601614667,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16204739236889573011,
+                        13901499932593890512,
+                        7819866447651648122,
+                        1047585702099797323,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9711968725081193513,
+                        16213548334318088950,
+                        8771724059051520240,
+                        1135387595512898463,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8574718786981470932,
+                        2743774196386619300,
+                        13950526484746485511,
+                        964991004566229909,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14135514264233326142,
+                        7943064728379765887,
+                        9944916034887560833,
+                        1084089320925839887,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15977427490234291591,
+                        5155359666232786923,
+                        11741621238997596540,
+                        575504482859314864,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14503989701371622599,
+                        17036278210523942578,
+                        18388664980357721658,
+                        485192449425852349,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16936495480550777963,
+                        13377295567776357493,
+                        13283658108847628170,
+                        265944560145130207,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        685302600013718304,
+                        4394955418464988554,
+                        9609326656194924570,
+                        480596522360393590,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16175579174044807394,
+                        1105984066655220171,
+                        14582352593082130371,
+                        302659444514808159,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10523587854012686785,
+                        4590025168468210610,
+                        2508817943893916719,
+                        1322218443941271908,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12963167578872337917,
+                        5197989977144027857,
+                        7128119816879868467,
+                        1320198748501050842,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8821721205524819221,
+                        16454891843048024438,
+                        1444138919473746453,
+                        240900647106529513,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1977855177644816804,
+                        3310105043647115055,
+                        14858705880837597629,
+                        1033920793945790102,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6647935915493011813,
+                        15646759137520239983,
+                        2890890912430525878,
+                        136467448442232633,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1884956841745569095,
+                        13896654039913209805,
+                        7446135918259788940,
+                        907559330644922281,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6962120821702280694,
+                        5232782515715251593,
+                        15774498481634017821,
+                        1276794883189958247,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5084272934507243128,
+                        7428954102911099447,
+                        16698238038808609657,
+                        16229541193217137,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4283025677232800671,
+                        16722544089785076567,
+                        5812959733320135521,
+                        418684434361832451,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15522146129503227952,
+                        12291273172052773414,
+                        9401316990250604844,
+                        1016459151577571424,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4052173986427310012,
+                        2753558241969732523,
+                        9621001490385717383,
+                        1221460198480100149,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        324549489940277100,
+                        6239064758094416468,
+                        8615689022007122280,
+                        491132153399317115,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9137024516458662466,
+                        2185680734140145245,
+                        4841119597564616848,
+                        872084987416596374,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17301478518519808471,
+                        5137186628856348273,
+                        8366974676150213946,
+                        745056033248060867,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3424619741864353072,
+                        11975501672323441110,
+                        8712982204810205715,
+                        1340041594131797778,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11530451234463452928,
+                        5135878004105947126,
+                        7592966945082169177,
+                        1014754052946576109,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6599834385407314559,
+                        7093414222419444973,
+                        1959654822143566348,
+                        863727084215669789,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15897159623116405840,
+                        15434230801095985641,
+                        1618727727459357538,
+                        524782734411585454,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2535800270733422719,
+                        11060360227880750437,
+                        17632695261028002760,
+                        717151701697692856,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1817833168936643943,
+                        4256832238202345467,
+                        1256406031805386093,
+                        375330358720481493,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15831222100059047327,
+                        6791392961812067251,
+                        2220778929338886021,
+                        244159708688455147,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4245756103308222430,
+                        12872737156375117264,
+                        4590318844450818411,
+                        1276453828916473328,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2380381350145103805,
+                        4829434479567926797,
+                        10079772271124455941,
+                        245188759037021931,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16203345244755063724,
+                        2363921764452782643,
+                        2614488322471053948,
+                        619670738020430190,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11557626511668406832,
+                        10046125497143691527,
+                        11834048837046223702,
+                        756774024242225986,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16217488425645620594,
+                        12424254299556514866,
+                        17899057112593993321,
+                        1177791591995882474,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13196399163425056979,
+                        569089283633829974,
+                        7824326775999661443,
+                        795674678626756567,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3616832355265497284,
+                        7049403251305358344,
+                        10881345687215110023,
+                        620294459691487132,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6087309180376693234,
+                        1590927227603503292,
+                        11353553602111442748,
+                        1250614167043822825,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2645116196624439432,
+                        4593849558488170505,
+                        11874429164300496426,
+                        295299084599409854,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5296782562364844563,
+                        3271611355106524605,
+                        1150746593180123701,
+                        1139887275010365355,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9110007626080712556,
+                        1949524781472721689,
+                        8904399791484442832,
+                        883633000150187908,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17450820592961676829,
+                        1330496079249303647,
+                        15814141757917842972,
+                        1234983766202251121,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        836634800682857019,
+                        15534633384943828118,
+                        11276289650067319087,
+                        143810717778733976,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6906646678078749589,
+                        2417331545813865142,
+                        7248267371778574074,
+                        231788108581927323,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18043343743544723669,
+                        7079574479558233944,
+                        4355803320148344298,
+                        569566271831950524,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2979396777927782694,
+                        5805080727604299588,
+                        15831277915509350825,
+                        791282609325088022,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18267143900753093792,
+                        10079777071534176436,
+                        2496033909466170583,
+                        133615546884905369,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9418798433307634895,
+                        4348185546734869141,
+                        4033851721265997729,
+                        698176988140317914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4942289732849099322,
+                        1515179354681604349,
+                        5557307404880144370,
+                        955707597461049622,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5001905892749755909,
+                        16822658962187460564,
+                        4563421934756820950,
+                        455199146700048630,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13834680642402527553,
+                        1026563209152355050,
+                        16043371057658645037,
+                        710244229292265119,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4344477192049556105,
+                        9923239382364266514,
+                        5361158507749296541,
+                        73582501605539446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14915037049757346546,
+                        18151478373174710136,
+                        5196624195335825970,
+                        739790643908635357,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7228810673973748192,
+                        6467165521500214532,
+                        15817545659469756139,
+                        750543510824079551,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4115943165901873526,
+                        17320734053533301188,
+                        9579849118061657948,
+                        882084473948622407,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12331541601428343013,
+                        12497157642591094394,
+                        10447844034169782124,
+                        245864594562559377,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14008220251498158153,
+                        10143710780688152607,
+                        13625993669705156330,
+                        830425829186939200,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2088132011034534261,
+                        5109948139824248221,
+                        7371648158727828183,
+                        1330570273688534178,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8973038606849033732,
+                        9397136576078951241,
+                        5741428152652386225,
+                        1266830939845382286,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15477284231410590802,
+                        5727804966057045795,
+                        15789173677204722405,
+                        201199505319656835,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8546738176164483105,
+                        9298553405979328847,
+                        15269828182170740629,
+                        881119399282058936,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9446337301350576715,
+                        15561110352949736460,
+                        11456896683230575287,
+                        1017307810689845484,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9302291271640631438,
+                        9944299952162420659,
+                        4536400993241775128,
+                        1246880315526651359,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4981324833739174906,
+                        12534420589675468025,
+                        11416374990339029819,
+                        1156039205608258959,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8208180354795211034,
+                        1752846051184343457,
+                        12194254029384583941,
+                        856690270464195621,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        789519198723727347,
+                        8763185417339113337,
+                        12318433962704522300,
+                        379362060001775520,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11121883522080635714,
+                        7767099374927525348,
+                        11710094681739179196,
+                        781607735611426813,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14738509863740408850,
+                        3276839062366434219,
+                        6746044640959937518,
+                        655391948118753599,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4438559927779869700,
+                        909289194095546948,
+                        5683552065921785697,
+                        624656209353045918,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15353342668319722086,
+                        10368221440835492052,
+                        9355546714948063880,
+                        683332380507725305,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8511305714456147081,
+                        4890243432772760607,
+                        4724092345195498412,
+                        164646381577787261,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1903315313743510082,
+                        5255859736119246423,
+                        13531824535882985478,
+                        772077074062699655,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        391193393678544752,
+                        8631012679729887190,
+                        806948976895298252,
+                        1176316420193670883,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3242258632777814228,
+                        1076376251895308304,
+                        3445929250858347312,
+                        334532213766178875,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14895887578232390072,
+                        5991455771188779528,
+                        5769898803456741706,
+                        279539781116505315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16720431444942856081,
+                        17965960687549140741,
+                        17958713834529834208,
+                        579225177426680610,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        932829861320117986,
+                        15080467982238824606,
+                        6733747311113234461,
+                        499606397261699329,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7406899913864375895,
+                        11870693697577052249,
+                        14937180962817759392,
+                        650812179971576573,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5809501431523264822,
+                        12975890504241265040,
+                        3327567969033298641,
+                        231756314576144717,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15085008558173152220,
+                        1177683698041771467,
+                        14101857439228311881,
+                        540130380897008924,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6424203458457507731,
+                        11360476524156896996,
+                        1582777718102855033,
+                        696288009361529501,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15402749247211862000,
+                        12104594938447570547,
+                        6799143933969335256,
+                        172467087304647630,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12859314424956861579,
+                        16947535006423989933,
+                        6024185467031020183,
+                        90004729772756536,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8762848491363237947,
+                        17496323471135733198,
+                        17567687625341530288,
+                        867507035240939506,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8916745147770803744,
+                        15618952112777119009,
+                        18292790208825882899,
+                        1025603747940574260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1051654755574209626,
+                        940079719796996289,
+                        1988809221763371005,
+                        649821029941186115,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2930707855234383122,
+                        2681895678418764272,
+                        2945188206709741238,
+                        124485132226769966,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10763870898044615445,
+                        4283588495286414219,
+                        12048144405591555400,
+                        998576846456714545,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17821530242623517071,
+                        16648430854264552062,
+                        14576867247090231916,
+                        672740025893799465,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        214317891039535311,
+                        5479457713844555087,
+                        12227125262937693824,
+                        324846092446746914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6847013100239935527,
+                        7034499131445328797,
+                        7243960698270295570,
+                        855461561827410759,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16683450627105671776,
+                        15666975344943275246,
+                        5788250437765458236,
+                        811367367391216766,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9152815136060851633,
+                        5214128978061669236,
+                        16205829372944496706,
+                        907301739952270192,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9440268160208021871,
+                        1468326666836108414,
+                        13957399643893236325,
+                        745118489766681067,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14260700054605591068,
+                        3819793896300415161,
+                        9291106228502273659,
+                        861393264991834867,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14559855119651285674,
+                        7645440151069992965,
+                        3893806970336210733,
+                        856450811625877733,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9317615111896283228,
+                        15885911377808992009,
+                        17150432849947766382,
+                        80724180757641857,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        305629684084322729,
+                        8283234567130230632,
+                        4932929532746043182,
+                        66202757602051727,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12080310927786912214,
+                        12023266973066784093,
+                        15055237392548115225,
+                        376309928939514032,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16316867559764314819,
+                        2733179717140648124,
+                        7830381997392123532,
+                        129992507931090051,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3176203044978337411,
+                        1384352450803551451,
+                        12698454534956744849,
+                        538105369597053693,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7634737302107491163,
+                        2708451614171547257,
+                        1092200577374126093,
+                        1007140371712584642,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1125604984945706711,
+                        12872396050093580448,
+                        5460174926418343857,
+                        932435239591806356,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4758007605839264980,
+                        15773679173628432594,
+                        9852471815467368011,
+                        314990274901976581,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16005750417243742609,
+                        13994538834772971795,
+                        8353820970718154330,
+                        862039005838968029,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9200975945726182358,
+                        3381464185267446547,
+                        7796114009057591041,
+                        249538882082294817,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12449341658170181665,
+                        4597882538825772666,
+                        5636505126535378530,
+                        697946063953574587,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3600341776248648209,
+                        4169171286989945723,
+                        7100747275676481694,
+                        415624520084109737,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18233674274274958839,
+                        40025776230609583,
+                        5810710014481292927,
+                        408091828432064561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14877080891037933608,
+                        13926876343668949717,
+                        12647183810613538216,
+                        848501732752252967,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10902037078854279940,
+                        2765510118780932861,
+                        7688730665960413504,
+                        706584285934056558,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10822066469442891654,
+                        11879267262200261729,
+                        7559246573422045912,
+                        49126687293521318,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3213520246530421850,
+                        4391455759612627600,
+                        2954545280054329778,
+                        88495452908615508,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14806230499687414545,
+                        6428977497622164654,
+                        14622017317614785344,
+                        826393989669308929,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12228919239223128339,
+                        15251487000635187487,
+                        16876376501524001746,
+                        687348255004499709,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10747925591007064435,
+                        12423147662564370811,
+                        3408819430290294521,
+                        82911965958993605,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14423976013548187938,
+                        9834177787524119202,
+                        15871830358609465282,
+                        794623904932119606,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11051925950055569895,
+                        6791918421883022241,
+                        8441711158932497814,
+                        275326157782109378,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1396574807833596199,
+                        15072710162489949946,
+                        620526806080362492,
+                        623233679791370554,
+                    ]),
+                ]),
+                Matrix::<1, 14, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4306290441957963315,
+                        1344534661245935625,
+                        15047287906132680711,
+                        745743831330075992,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15854166474422170311,
+                        8597999176008421924,
+                        14544687601341991957,
+                        1014719760523821889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16115240922345803838,
+                        2469725522891365386,
+                        7699986478142248252,
+                        121983841289764040,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7961358551840903921,
+                        2552116329178395614,
+                        11932776740350401788,
+                        896132457100135093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6091802490099966478,
+                        15951329481086849333,
+                        18103689210632827181,
+                        524188368752774881,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17091930032961117784,
+                        13650581903758821252,
+                        15564456518683340293,
+                        955408378769525368,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12882785509094812754,
+                        12737139206785664248,
+                        11320879175652941049,
+                        1329212561612243772,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8565114572662163778,
+                        8496766582745458405,
+                        10004350227705273634,
+                        904057951274575127,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15189221250542589984,
+                        5648045142894300635,
+                        2633087154412199893,
+                        755900558607195132,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13031016497339833149,
+                        9043566087492233586,
+                        17768135900738304629,
+                        857048981985498704,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1193048612610051454,
+                        7976481364891088177,
+                        16736919947942350924,
+                        1157332277565210135,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17097663403995740779,
+                        13915011506684429126,
+                        10482519157274609815,
+                        66211606362074508,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2180447162205028128,
+                        1547245055253868722,
+                        4320374426906573376,
+                        836570308543445738,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9073850476482645689,
+                        12662247797017394968,
+                        13040897899717350863,
+                        103278843790654452,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16246031968297287321,
+                        4752177947664711306,
+                        7144919494155578907,
+                        1271378857821479902,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        704647768167774740,
+                        9619601590015342328,
+                        17414258539299429896,
+                        242877169243138367,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12998503386263682932,
+                        15639660643237715116,
+                        281163968472549090,
+                        1262385398022512491,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17424027838979772012,
+                        14791485191171562740,
+                        2683672722004429764,
+                        980067464609797783,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1674190939765801517,
+                        7090666687897256949,
+                        1614341337926446694,
+                        165499584115938373,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14624017600530905744,
+                        13174003116921099803,
+                        8671658350158914560,
+                        258891225054240766,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3233888229135110993,
+                        6644252771967296501,
+                        7771032971150710119,
+                        1140658935924538385,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2187080536377483518,
+                        17670928380102631202,
+                        13241669798149191471,
+                        1223874125281889474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        950222807244355090,
+                        11587428985807020723,
+                        7884671892193122639,
+                        950266818904961218,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1538801669203387696,
+                        12178409291677400058,
+                        12050449009852930155,
+                        65960103667034288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4164324376092936610,
+                        7909782383235350532,
+                        12943184620962048184,
+                        1148451177715714021,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9935926620289616530,
+                        11743592828628611678,
+                        3136280146418507233,
+                        1282983267302070871,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1158940581826086761,
+                        14108873544460669419,
+                        12267380954456758240,
+                        842241098127976520,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13713353395636923832,
+                        13194025535593533781,
+                        13759894171316179860,
+                        291849446821765887,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12502945184094031878,
+                        4288949133796150679,
+                        3694634599867021555,
+                        733792963677790420,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2408748274059642623,
+                        9628717313694070992,
+                        13209778312176997080,
+                        46920152328300895,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12516454052796479171,
+                        13856138195816616803,
+                        1271824456645335328,
+                        112350006400444937,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12058034051568762816,
+                        15602313276179963009,
+                        4349182084632363095,
+                        1078192357217886086,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3177178949511188411,
+                        2690255700046879994,
+                        879783990951404762,
+                        735090242099557147,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16624664576392022999,
+                        16714280997481405263,
+                        1254147223490504264,
+                        740157714146402497,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4319221455524153455,
+                        3634796945233296350,
+                        11842365879991944390,
+                        1080067124316811409,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15645493434410241644,
+                        5873030807400264307,
+                        12437124602209146025,
+                        418510733063789217,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3637215135974421547,
+                        2524625420504810470,
+                        10010556816125419689,
+                        1068585166260853399,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10234188201646423570,
+                        4787899819704915357,
+                        4015999602433955750,
+                        1095447788329650081,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2182202444987922411,
+                        4508575694840999401,
+                        14857904254391566606,
+                        136748502316803645,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8921814237218938043,
+                        7015512834959987199,
+                        14721588987192469632,
+                        1296623755156818435,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16508253706437527325,
+                        15990810338443882928,
+                        3978265558997782959,
+                        17926474890716239,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13580596421731918527,
+                        1543073496342975797,
+                        12135752626807941707,
+                        1011916631033595788,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2548698544017224802,
+                        10151879483651800346,
+                        17250577433067075960,
+                        255145253861468944,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8626544853989456127,
+                        12644300050464244468,
+                        14858189622192666696,
+                        540888698281662474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4253986278335305496,
+                        4086786908039676566,
+                        17317916532645637171,
+                        231579257248643538,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16521156463218910377,
+                        6187768446104779756,
+                        4522863117282204675,
+                        517263818538039092,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14446806976888546923,
+                        8911007476400738176,
+                        1481624065487666916,
+                        920849593547790018,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1431707838498974010,
+                        3310169198672355808,
+                        8636893109160121698,
+                        747526524082410344,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7593965270318942111,
+                        8713773759038579976,
+                        1685670153643573098,
+                        1116838424003190672,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16985747883963379877,
+                        4394814146889202461,
+                        16520161163881631150,
+                        1207070604915168244,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12314026059163260843,
+                        977081582776992604,
+                        5592784989873056136,
+                        569515163834350558,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10563011963598125912,
+                        10180766842202234259,
+                        17427420722067330338,
+                        357160813272194068,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6371588261797926104,
+                        16806342881768020769,
+                        17160247284410955608,
+                        154397866660442886,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6130882696359418961,
+                        15137702653746044228,
+                        6963233275479125181,
+                        76956443388924419,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10653674084690046150,
+                        6977290803263227036,
+                        16357974144174082346,
+                        473523800610122967,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1578071422945258939,
+                        17293153456332712781,
+                        13999357456429854280,
+                        858570417275654726,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3535253406807027449,
+                        16857915701698863108,
+                        16943218966647545809,
+                        475475475339744747,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3010193472979788586,
+                        15087324238848924630,
+                        10019069363326078375,
+                        532868255529475881,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4718299072904482658,
+                        16301701593633225949,
+                        12404201801042913153,
+                        696301261106759469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7427899678271740229,
+                        8528026748707600581,
+                        4019190763697837854,
+                        1246549887875057241,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6085471897093150460,
+                        1015764266560987317,
+                        11185099974658974414,
+                        238790492194964289,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15219436162844651621,
+                        6858117039546033309,
+                        12719100075277224542,
+                        17974669219292934,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7129184116835166924,
+                        10674059463896178330,
+                        15488860785489193505,
+                        289763065613039896,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13105308465380391912,
+                        922061573329240196,
+                        4012206032682615669,
+                        381730914561161280,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12170524580489215354,
+                        9381072671401523237,
+                        16219489850946356191,
+                        657298280041009683,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2759667388194147948,
+                        13860811506776215955,
+                        6577072619872051886,
+                        1044412962735771813,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1045373875994566334,
+                        14945278953150670213,
+                        12313319425988966737,
+                        555830373659112905,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12476917456273628215,
+                        15301901173967627537,
+                        4778283232991155801,
+                        1166965059865517702,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15280670509623248161,
+                        1117138246633010449,
+                        12734245119444337832,
+                        1138732758643863227,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        293534940348659343,
+                        153303771933665218,
+                        16255325462695678440,
+                        22445528658762404,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15796464584190719581,
+                        4374873697248867512,
+                        16791877485047092540,
+                        97499616030705354,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14025363221748320661,
+                        14988226615092369363,
+                        9324822953117562070,
+                        582382496467051137,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7372180634437227885,
+                        8714133170114179102,
+                        8805010923344356472,
+                        1038499259283176399,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7270515648259757687,
+                        10273003167168833398,
+                        14309738278022675799,
+                        281518845507430581,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8456615210354233744,
+                        7287570895546785655,
+                        6985183507323218399,
+                        1250858296817000319,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4152685681361528457,
+                        16045260132298552640,
+                        12970482420941504272,
+                        697999226408567670,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9240105788562748423,
+                        13778318293022770502,
+                        18086093142017403552,
+                        586085199678346167,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10376033029343514192,
+                        17679891100906453219,
+                        18262656380472886803,
+                        735534843874071699,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6066787922770515901,
+                        6963820343100929608,
+                        5748884655737629970,
+                        863676405885498571,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6381117807605623571,
+                        6408742563487182080,
+                        8320611285622803689,
+                        98430982258763322,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13033404401530877242,
+                        12218880645420438355,
+                        8934155893996981164,
+                        24829250625479089,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5702485422187749618,
+                        4529290792042910224,
+                        3504509781014921047,
+                        529227841028734168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3597399586402510125,
+                        16330952213613644895,
+                        7044818211434634241,
+                        2602700618057139,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15925595208259463426,
+                        9696588650655297181,
+                        15529425135050779892,
+                        306995174631129670,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5660297799088485814,
+                        7149958604951134306,
+                        13206631308156695909,
+                        19412985370073758,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17896676394526762002,
+                        9351354276403060630,
+                        16070780578190794775,
+                        266192258914406992,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        888742285754206799,
+                        10441063313909865488,
+                        14119806178746361873,
+                        1271490354522007729,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13125980510323453286,
+                        14836765574462107118,
+                        909525016663948988,
+                        430407764990599366,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18119366909016189935,
+                        13676388263421824337,
+                        16407134955793971545,
+                        1162605389809922149,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7138035213981095656,
+                        17337078603427863766,
+                        7027848884577079684,
+                        983372257502416213,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15545114877713533211,
+                        15420559415910335653,
+                        3459882255627687480,
+                        651930690018160172,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12536503005366358275,
+                        9256655387448083385,
+                        2740730007444890961,
+                        569732180673480219,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14748483529960534261,
+                        5505336344088258742,
+                        11592396808248116757,
+                        332096003989712012,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6192768059137316982,
+                        8557149500584960106,
+                        13422074981276387104,
+                        268222798836904829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16993670512828899197,
+                        13768408500696350096,
+                        7557931440326068210,
+                        588619716524653975,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15146926885613346442,
+                        7796820377450290833,
+                        3525133221303346024,
+                        39608628890023546,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15977444773995488338,
+                        13915791067333425957,
+                        9630813536061053690,
+                        272773116462948077,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16525370409562533487,
+                        1037294401212311847,
+                        14347131837649204095,
+                        580618420632657538,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5241513952134514020,
+                        2366986604071891161,
+                        9083292905857234278,
+                        52260989857845512,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8911226647829912310,
+                        11836633433060719668,
+                        3187279298613585764,
+                        121657208380612507,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7906583747867880352,
+                        3175103782955599607,
+                        11874489490539957600,
+                        646095190678389945,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16145700795668415922,
+                        5802308599408071505,
+                        1523219931114184528,
+                        1072771773819857230,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12338076813197801691,
+                        8607836520249033134,
+                        12191178379452880704,
+                        583162814885024242,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14294405419416552558,
+                        11144949424316560143,
+                        8382305899980369656,
+                        1148869861564233169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10668336259226799533,
+                        14387907186189766954,
+                        17920818577757804122,
+                        800984341932043761,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10828447199490666126,
+                        8652974244037209857,
+                        6942263721235852318,
+                        112357495663585631,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14885133133705148822,
+                        16532201715623769700,
+                        17628199952098453612,
+                        1085445864172334395,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17478195145801883927,
+                        17073511956532394202,
+                        2694226554935042211,
+                        1277751173165119784,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11562199379006616519,
+                        9036463346131955058,
+                        3365061069965435634,
+                        720193803254065894,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13674548385543941612,
+                        11936073496458928439,
+                        4672197967566038235,
+                        85794829528011657,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2423161919143903452,
+                        15965045334478765045,
+                        7424418331019844288,
+                        1089846988371077365,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4442412516159435488,
+                        1412449527864930857,
+                        14630452081437150192,
+                        604600427898630172,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12516807930184753841,
+                        14282610353114769192,
+                        3299579136475937344,
+                        146713291572215889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4277377766678363527,
+                        3487009997765906337,
+                        15988587033059875660,
+                        1012409985444825969,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13380940682365784617,
+                        9251113909870289468,
+                        4535713863674493214,
+                        572071923940177511,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1125635596265815377,
+                        10121843155938158288,
+                        15269216463337439202,
+                        816770623325028082,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        122060949382159518,
+                        11933589551213465095,
+                        10045778506934180831,
+                        311276062536442116,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1792531856511347234,
+                        2567294482804150570,
+                        12605167344712587046,
+                        1098049760885484224,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1970218822117822764,
+                        3164718567634333041,
+                        4364810091673160804,
+                        1167900109713986001,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15308784578401851639,
+                        9493532105221626922,
+                        8825777008915876302,
+                        955812029121970093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13123507920041431357,
+                        12189702616787722433,
+                        13955890321039421015,
+                        318876240769920588,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5321904859723961797,
+                        3822851196604363506,
+                        7724815281201126226,
+                        300586900177678797,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5874935417535698787,
+                        17176851007076658472,
+                        3770498379905118176,
+                        557956241123525528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11141171594983548130,
+                        10693877665385765876,
+                        1526880065763757343,
+                        1164220593752909192,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1031723266789846638,
+                        12177504031308520392,
+                        9443009107134824320,
+                        150326879947784595,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8268466793055515185,
+                        444089662438517535,
+                        4103586726376854356,
+                        71392770883186308,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3399306293748220805,
+                        6729990560121992216,
+                        15912929917515074017,
+                        1127934754504164989,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17619957906091869883,
+                        14509791724333142649,
+                        1847027386529452107,
+                        1024640118055945110,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11395662263763201914,
+                        7281435544772434056,
+                        9138128079058532694,
+                        589234793057107666,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13487824070742141198,
+                        9172714239421640414,
+                        17276518643973917356,
+                        310295294022030590,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2706859907758941518,
+                        6859379056874173110,
+                        4414636485404237398,
+                        1242080215045548657,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5823150889645067898,
+                        2290349701658121045,
+                        5740299069857240858,
+                        343940390421809736,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13246631403171515057,
+                        12861035380540000126,
+                        12280664469072937476,
+                        372723720339312011,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7503404170802025727,
+                        6926267771957429195,
+                        4062365605495757476,
+                        415338099455296059,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18382987726569284861,
+                        363860419487645523,
+                        16572351704961270899,
+                        408103318503389814,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9921147906140506005,
+                        10926328910770955035,
+                        9484357412214357612,
+                        674136314353919609,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9362257601741962462,
+                        9767823947203316822,
+                        16113193516500678107,
+                        1066054858678546755,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13972603745834962643,
+                        11235961014920783821,
+                        15648284855604504501,
+                        569762306795877731,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4882782409561489822,
+                        2741650838970013222,
+                        15815037328889996978,
+                        447720082931874274,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2510669711682443965,
+                        17004190691703123827,
+                        13234316369652278059,
+                        591736742994734599,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2455142888922813511,
+                        13480282216327839642,
+                        5747506631088639044,
+                        1194006490206508490,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1726080129158857108,
+                        1675034152178868578,
+                        351714813638356341,
+                        97128732544990723,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4962667485720356329,
+                        4005564107811850497,
+                        14645515547539576811,
+                        602309880790771589,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2985013843917568323,
+                        7464994688133329500,
+                        3772446863923860983,
+                        525586418791558337,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5375356993095081094,
+                        822863051159814129,
+                        5369374160632751987,
+                        743789092292093346,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2759059234198775871,
+                        14749332044083604473,
+                        8545432866293996571,
+                        523097637199337705,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13933778682877779869,
+                        17578607623791061678,
+                        859631176779298096,
+                        294042715453612206,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11363214008681982245,
+                        13714003805487122883,
+                        9176306490182476896,
+                        525536463466212439,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3812905988683434926,
+                        11017006645614307504,
+                        1566543414027459921,
+                        632737713860444258,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14855712677523680427,
+                        5433047537737598351,
+                        11716036431004351990,
+                        246447712923148798,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2457424645497725606,
+                        12964829550110633689,
+                        354373333527659943,
+                        809483145577569751,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15002077456087779758,
+                        731059664753133851,
+                        3790699250228074104,
+                        1247449668571746896,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13992704524748939685,
+                        15407993784303469085,
+                        7660414529845713644,
+                        502480117379231444,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8256831826636971761,
+                        13581495813005961039,
+                        1987626224810890121,
+                        1061093111831159008,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8182476165223307078,
+                        17865886722513307282,
+                        18353423555897431328,
+                        595718535835757547,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12320845791400676510,
+                        15977126442677177107,
+                        9858825510114666449,
+                        341331288210897548,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9840071833220450508,
+                        6663007497195734181,
+                        17843313851235829652,
+                        47958668993123905,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8692339791832508946,
+                        15226021551171695938,
+                        14559544084905227957,
+                        227607639786681814,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8176736410521818163,
+                        17705056672648125615,
+                        5538813668708913929,
+                        687320880164658071,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16804961783981941805,
+                        6697545216860515592,
+                        12789572990265195966,
+                        821868290970051484,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5022546266314646702,
+                        15978519539176231063,
+                        17574651677163569150,
+                        737521973821905689,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1691587797677045926,
+                        2334023542603634817,
+                        2451114812242503125,
+                        522770873500810404,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16084112457579061344,
+                        14329065263954471452,
+                        3179358478346001340,
+                        1050594853578544068,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1308220215677522574,
+                        16637554545000198670,
+                        14874978908537319067,
+                        756957347221455568,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10239422703221180879,
+                        7845515900012569711,
+                        17622272112906005292,
+                        967556385186315251,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        929110704519741481,
+                        14536705364962092995,
+                        11227985114402533826,
+                        554182363433416623,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4368236338642629925,
+                        720032437120188132,
+                        5597569656025202669,
+                        1249224895099001679,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11032283661069762254,
+                        15969775304242475435,
+                        18266442327481403676,
+                        822820017215996277,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12585908211478344417,
+                        15024119084498448922,
+                        17654738718291335696,
+                        567748832842725321,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17880022342999833575,
+                        1332525666178539154,
+                        13887248196895811058,
+                        640271580392777461,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3949244398341688458,
+                        8897804160976830063,
+                        9789823655932343327,
+                        635507627088226123,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3158605149496827474,
+                        11056809994783919098,
+                        1313310270080897820,
+                        43036958749583564,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16154121908048126296,
+                        16758166715579762431,
+                        13251718300875212209,
+                        321725065894521391,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10418580381554504247,
+                        4614526433153368744,
+                        1492606328129924272,
+                        1295765341486009743,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17197283736928377850,
+                        1181512692702872598,
+                        8895712570219874904,
+                        1028528446228814429,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3649445750471368802,
+                        7203500205672980341,
+                        10674605735554789228,
+                        270588496780256874,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8409880551891775030,
+                        5852515079024706422,
+                        18224147137177334788,
+                        396577092502210125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        188385437207991034,
+                        8599274596402389007,
+                        6367949693785308045,
+                        191489053016842565,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5492931638535771375,
+                        14990180820073687257,
+                        17818731230517088617,
+                        911723632490262658,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6421199587272529052,
+                        15118726077901345534,
+                        2993959660356002611,
+                        269493135922645600,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14073898443497378052,
+                        4129724428977462706,
+                        1392166921859646849,
+                        859417824425086488,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7514446138369599889,
+                        3346401073964221866,
+                        11588881970155588488,
+                        684713454561676213,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12502817042071346400,
+                        7757516808156306476,
+                        12230129591959433265,
+                        618954479766211623,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10433724909357762264,
+                        5232447573320306071,
+                        17981096926968017919,
+                        1166427716991508394,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3625772325057771959,
+                        1535234037407196776,
+                        15658521933096875747,
+                        461979762511054559,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15824380758154338763,
+                        13665169575453336248,
+                        12112208479602363846,
+                        906488301319119829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9107302633313470615,
+                        16019952360201825867,
+                        16700084157659576843,
+                        1181542710734336857,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8797484954284042987,
+                        17628533358248218430,
+                        7813075373662078834,
+                        1034972825831234970,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8730436143675524585,
+                        12792291265745511343,
+                        9032694848266933947,
+                        418157743441597942,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3187097922931781957,
+                        12013673091828121668,
+                        11619528568479668247,
+                        941980800930333165,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8919841098545901607,
+                        13370369029067659249,
+                        15184880528201454244,
+                        829712284839214471,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1454955847826298420,
+                        7000938660410695854,
+                        6089355014490563533,
+                        255474173945812582,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13943057363453246715,
+                        16197918490473108144,
+                        4395838577307534293,
+                        105480595710435514,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15180045947555226991,
+                        4553251351198901582,
+                        683401851365691471,
+                        27080488482774723,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5418957108733909094,
+                        1416339049573535155,
+                        11878290699847892501,
+                        730548332531532024,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        664591747355569042,
+                        7192979532692795566,
+                        16921214026686379458,
+                        1314609009707490806,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15397086000317305306,
+                        16883912646957193704,
+                        17104745055358703860,
+                        1320631178094445989,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15886344125518365863,
+                        814732387526820128,
+                        14204788779572118183,
+                        1230832039594383334,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14325190798907396324,
+                        15609388506032810725,
+                        3950331147041130530,
+                        106818337223189403,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2732138764600039853,
+                        261880162300540087,
+                        4252003510877757006,
+                        1117715528161221904,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13580110802196326582,
+                        12327861728858137886,
+                        11292127099011268951,
+                        12622627606083327,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14682545034834988155,
+                        12754436054165630459,
+                        13588385876870841451,
+                        1325797336423516378,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8509158718685584606,
+                        12742642907386503419,
+                        15881449171617624186,
+                        922731736602055522,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4828882598828886959,
+                        10365891323130750392,
+                        15421658834074552264,
+                        1273148109052410723,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4462011238645148042,
+                        437455074991505997,
+                        15068724121948930800,
+                        1070466164380604974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15642995930235631240,
+                        10346468629132303318,
+                        11736294307699555461,
+                        1150555512719007776,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12113331718401576155,
+                        13866490765384924697,
+                        15600527727721536714,
+                        117502274612122822,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2185203898375048390,
+                        12351048895802907777,
+                        9585432948666206065,
+                        1201239542197327381,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16663498740487320284,
+                        15449200071392921064,
+                        3725032120188745192,
+                        557603106997567353,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        412797644070886829,
+                        12480466006929632170,
+                        7378947057776511076,
+                        189185663950661690,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3949912741567275259,
+                        983640225393444323,
+                        5419409626000554128,
+                        870844435698105436,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2574364456420984109,
+                        15371657823136069971,
+                        13456061728087341450,
+                        404841024444054914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9980916236643363623,
+                        16676683585075717559,
+                        2911830483979667035,
+                        817327931655329133,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13919724812015999530,
+                        15274609129118963306,
+                        14380907529173753710,
+                        902960446513332224,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13076556627964407550,
+                        15375639436072610392,
+                        8652511978984114552,
+                        553990078480270321,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13186881043239317813,
+                        2514952750363369957,
+                        4417955751877920525,
+                        1058228977964410879,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14010066655958895298,
+                        8199797025275322194,
+                        11933002504615149273,
+                        1061464889018108111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        326923646657323487,
+                        3066540179882462637,
+                        6153193451308153412,
+                        643272863870164801,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3669186380650375292,
+                        8589193311211647205,
+                        10610699456892725077,
+                        558742806548189611,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12772935646856831980,
+                        10945339207270537816,
+                        12249884199606821232,
+                        1311050198782795065,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5193373232228513684,
+                        12802483802631099916,
+                        5513643649018212069,
+                        519695736139828404,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14833178109112581911,
+                        7391232768940542137,
+                        7953952213148268887,
+                        484448544288182974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15386979053934990067,
+                        1713695685058749356,
+                        6384648456049747277,
+                        1198402475813425425,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2314870624887430945,
+                        8842523782662187241,
+                        3972016762176973482,
+                        1018901999800642246,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14794594908729416512,
+                        9380150854234580088,
+                        5392540021262108016,
+                        1164562424053477628,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18359612608127061509,
+                        2887652173072864565,
+                        5657746593722889117,
+                        928450981744511354,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17522602780355328320,
+                        15180943811864901548,
+                        15007579064198006075,
+                        361212867094757050,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3904397681245356208,
+                        2906280342950622279,
+                        1264772259577892044,
+                        1219671112633643958,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12206594030611066831,
+                        7149987709633044499,
+                        16737403131823012933,
+                        1007225496412621708,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1141253688169122507,
+                        12101408511880466492,
+                        4572576152939185040,
+                        1275032273029949311,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9815126995817207727,
+                        6998223968011105000,
+                        15800635302365684507,
+                        1035757374796123629,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8163198631975843019,
+                        12985538363460056671,
+                        10570233114919866148,
+                        1039710949017501898,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1474040658066062176,
+                        16486795562186238995,
+                        4160401067429828003,
+                        118321934185709412,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3969610721493658077,
+                        5168012767241265031,
+                        7786355954841377478,
+                        393192071217058847,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1893717540173567276,
+                        18253126058210005401,
+                        3069285958687840951,
+                        910981653404321057,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16454006456245028014,
+                        17945086697637214117,
+                        6391750926816512957,
+                        988281640569660903,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18188701623312791983,
+                        2003624608956772542,
+                        12752266497583475029,
+                        1160377591301095371,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10405426618492382747,
+                        12485865236723278631,
+                        17991764731279450454,
+                        734815552209991000,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17528190796206026228,
+                        2673781377563407819,
+                        13809396786364739239,
+                        404761948360539314,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6547445366750231294,
+                        9525113524222469184,
+                        3873132348486490925,
+                        721270698172205255,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1487544758789859385,
+                        13288881328039137890,
+                        14879379352015333520,
+                        1059738231502936029,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9050870025512804136,
+                        14716775110200693575,
+                        17645328195408778539,
+                        409325500732909100,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14145625451478152770,
+                        7032961813115344848,
+                        9174239215252837955,
+                        880123312676924314,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4557078096791060037,
+                        3380340806132975622,
+                        5555475938914062980,
+                        1142268017150306966,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14311408094684678883,
+                        14464835459449119006,
+                        5151066652883559752,
+                        654167458756941735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15844736789164513256,
+                        3100310075081914110,
+                        9433875795208767494,
+                        1245029194464341729,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10008335119599606719,
+                        2222230356726043885,
+                        17804246520530086408,
+                        1242252132375883838,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7790043760682103775,
+                        2115393470997103419,
+                        1174335822959657713,
+                        1073118805613504847,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10690804867967148922,
+                        15096663979490774468,
+                        3730474145093879719,
+                        892385846175869661,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4288228073530864066,
+                        3286068513638790860,
+                        8010980665604066862,
+                        1068979498193627188,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6686579182468791064,
+                        15124803887993483205,
+                        11065957742943316746,
+                        79719356794693469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14912467241428130158,
+                        15155588048393796094,
+                        2509952154570739851,
+                        103542387454495858,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4462639504724002506,
+                        16461575487585816764,
+                        5842937088048241286,
+                        525596708487510701,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6055883620302950771,
+                        4362128667716422080,
+                        4453088467817727131,
+                        1335835667539432662,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9117911884786576706,
+                        15171982024063069383,
+                        14660811378197080693,
+                        804548741538974001,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1275296157971618415,
+                        13769999037630312689,
+                        16554070779953142922,
+                        191306025863128074,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14648646301018884580,
+                        7834200248475116243,
+                        12483080279121554065,
+                        564802994131062612,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14570773828806708442,
+                        16866283872061698506,
+                        7889377397061756881,
+                        576003039155108800,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10910232955196921757,
+                        12518785641732905880,
+                        5685067078353941262,
+                        1172455911729768582,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18160559842491100315,
+                        6158277981544244459,
+                        17314265968759834384,
+                        324960744256012653,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11636136232029648476,
+                        702033770145155535,
+                        5391296961998501327,
+                        1165006320259557127,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16612319247620502723,
+                        12637417972457905486,
+                        6935360994029319234,
+                        207216041628708019,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7785825224195237476,
+                        15706028304496056016,
+                        12405801244039371938,
+                        119269575114611405,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1280781584638459074,
+                        5193385709042394784,
+                        13409978899074143315,
+                        952900092687347223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2605216633526212227,
+                        5156081492537341784,
+                        10059799137861209570,
+                        29398292846889538,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9284491855363064708,
+                        5182662248520042611,
+                        9951415997746363596,
+                        446527012010820596,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9025211624367888083,
+                        7117568779718379975,
+                        2724202457410084725,
+                        1134233283082669847,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6376046649259179003,
+                        10705555128302738506,
+                        10307707483003356627,
+                        584237884450218585,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16240943146384284029,
+                        6096558299733426986,
+                        2884552183650003470,
+                        435286355811790999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        345794594652971767,
+                        7037035246096397531,
+                        10104700579852867071,
+                        441281902970586457,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3736072743938140932,
+                        17061650713049393212,
+                        7033135592606090507,
+                        405461753003631858,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3695371856529016964,
+                        4942719007116444110,
+                        10946412146578804745,
+                        162296926803141767,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7844255720842894672,
+                        11128786602468887303,
+                        12791147285912662051,
+                        239164231338646916,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15935712567852576380,
+                        10016160942741784729,
+                        11096254666811648890,
+                        1116139468620363675,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9152292100746807882,
+                        13465852829310904337,
+                        16664036536442333464,
+                        983774323437035119,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15276332033119994527,
+                        1234126636566397557,
+                        2650495152407845340,
+                        1180551299815312470,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4030390140181341020,
+                        15541542363549209519,
+                        14766597850857719931,
+                        331119580852801005,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3080869511848703318,
+                        14805245656269071033,
+                        13655666185495585007,
+                        709467515063646325,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        87659950833152420,
+                        12100654935341022186,
+                        17875056662213536269,
+                        57076874575070574,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2048886349182393333,
+                        2110944341099965658,
+                        5099781443343312143,
+                        641927513699570942,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3713977479613543443,
+                        13553868978162644866,
+                        15435633681644337210,
+                        1207363974583198152,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5104064397829815408,
+                        13221521738994803146,
+                        9562187682499097647,
+                        870065851871615270,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13518193828923159047,
+                        8785708042168032847,
+                        7649323220009398158,
+                        971885211252537913,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8803440685748325987,
+                        11579644997919056505,
+                        8504567229565254103,
+                        604237213015460456,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8054010340656868295,
+                        14804142830254778634,
+                        3898268732200311946,
+                        55464581404138990,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5482432663807362715,
+                        9540226118547977328,
+                        4415023856686883702,
+                        1148631983992663872,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9343810467141226137,
+                        2893163562502000301,
+                        3957137135531450795,
+                        318908311541862069,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12817491320644463675,
+                        17161689733121071598,
+                        9482186933044231844,
+                        770761382986324871,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4139010962050909120,
+                        11690274738144021674,
+                        1941583595643159632,
+                        52874237180194571,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1472417417166178228,
+                        18062972898751875277,
+                        2088330735891852265,
+                        149795362930392890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4556564482693745170,
+                        14724546355641878897,
+                        5305975367676083753,
+                        43398075242589889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7438888393893571722,
+                        4579311489624784383,
+                        14972254853651424280,
+                        1023875227467165952,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6509224080385845764,
+                        7576478981222139556,
+                        14294542853037138444,
+                        328705466257824375,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8376394383476648924,
+                        7870337888706853654,
+                        8967512383429726859,
+                        1102847188235318083,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7798968728959477587,
+                        1392128704827597802,
+                        12936970197958302421,
+                        208673577247992034,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8562434538180348279,
+                        8350296601495925393,
+                        13267752927958208029,
+                        1001236438928585974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        928595090544628228,
+                        13412391730107456113,
+                        17525581225258716837,
+                        1204038729451637708,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18045659236124693888,
+                        2194895498977784542,
+                        16352298804131430291,
+                        373393215472708492,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14377293363370688297,
+                        1421118548838252796,
+                        5948231644124990258,
+                        768596666079146084,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13442945625180322664,
+                        7349611145142198387,
+                        15641403416499670536,
+                        77741872755181784,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13922576467047347257,
+                        9045607817180307198,
+                        6091375480565266494,
+                        285984086517760404,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4920302842402611623,
+                        15971059435572933675,
+                        1338007694107096748,
+                        257221696177305141,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3424212978329547836,
+                        13850259193187937691,
+                        17590860954905408706,
+                        768661967992297778,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7156182179621979446,
+                        6969381446271011772,
+                        12067379721733680200,
+                        873935047780079986,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4312965093324274748,
+                        2548596334936377828,
+                        9912431282012162535,
+                        260562280932267049,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16084131900036000548,
+                        6344051791580094400,
+                        4784702406238012440,
+                        570593209599307872,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18038863314867875913,
+                        13031337887463599980,
+                        10052041030021143660,
+                        38602018911821359,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        163112421614636642,
+                        14447455209317230330,
+                        230029075522388702,
+                        396939135011416896,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12524301101718555923,
+                        1544753264166472283,
+                        16883350409737046464,
+                        795291903591363670,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16841977305615092039,
+                        1065329885887489598,
+                        12213369628235207256,
+                        343343520766718160,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17183810334817536579,
+                        6712773463224582852,
+                        8258372282136075412,
+                        336777844770272603,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14543457188027836617,
+                        5510602768042411474,
+                        10956743255656364055,
+                        448531040061622231,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5535232838474863605,
+                        9725234191227995245,
+                        1427230280446883233,
+                        882808186589853771,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2925567158832529899,
+                        16867778318136630215,
+                        333368683098785350,
+                        751184265158604891,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16331303563304924484,
+                        1608119086204917690,
+                        4264897856847231579,
+                        1195657337241616047,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3131225338742454780,
+                        16725100741389486684,
+                        9217459028185131543,
+                        531643739198297563,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17572868056239614375,
+                        16418951065819860854,
+                        16981419531621791827,
+                        1307481105723137891,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4528169417436279211,
+                        8995433484584768926,
+                        8714573356389630035,
+                        655285991152791840,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4279192813725422454,
+                        11180611425076754289,
+                        17533035208034278929,
+                        314925203861342149,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3602030628191744205,
+                        3980478771199130060,
+                        17243028924835224484,
+                        866021716719057610,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1340927361844716148,
+                        17197626948338244763,
+                        7411439319137026939,
+                        806742157844224234,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6451151533240872383,
+                        10289814780862658346,
+                        4205270073763936832,
+                        69229542100934607,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6783980122812821389,
+                        6445356240528276136,
+                        9908272523569264737,
+                        861660308498970371,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2484750508962039664,
+                        10323060519879139419,
+                        9317089072624454655,
+                        1236493843895681048,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3997007692029617904,
+                        1963907685528381757,
+                        5285479720553302566,
+                        1143251338068079511,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15985247081470395521,
+                        4790717345635296347,
+                        14781457861402023832,
+                        610575079774631582,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15602204720253621681,
+                        5933070463502281593,
+                        578142077705719711,
+                        1282872144205694517,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10288021555661994977,
+                        14221055249067948999,
+                        10873586943625910765,
+                        1060528609270311107,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13249880421180037192,
+                        9348684356850632995,
+                        11888146792266533766,
+                        321355586189137534,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10269254415213237496,
+                        12917688576452336537,
+                        3820282690802880320,
+                        101757103731049650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6415268097692261360,
+                        1013895441988930234,
+                        14905024938034245183,
+                        1088090655716985618,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7295218913930367839,
+                        2704218425080965940,
+                        11319933087861215882,
+                        1105922941087451425,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10933650368980268257,
+                        10478967554603118520,
+                        13414242408635488658,
+                        453105713544028210,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14699611124620889585,
+                        11398883578586891239,
+                        12241117888730079849,
+                        258724627534526418,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14027771278256870134,
+                        8644166763295738153,
+                        15860857399858200981,
+                        132778420927416510,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6788519863788656786,
+                        4628990902820189977,
+                        17617649002641245035,
+                        872451239560380579,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4622275988994263522,
+                        17487839426598592250,
+                        14761483102144304667,
+                        474516386340273979,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11707913367823663580,
+                        12297573064434452403,
+                        8123137198815767029,
+                        566752136742101295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8012039075524676930,
+                        16463368101755326756,
+                        3047900056089113349,
+                        685148924819431570,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1893581733683641170,
+                        6396233409956866060,
+                        839101266937703785,
+                        908540866860618164,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11291993146468134105,
+                        11617992638693621343,
+                        13979954584836657208,
+                        157693665292724002,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15814571701430473414,
+                        10009495929798072497,
+                        7834652470587810877,
+                        330914558086758793,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10527915901778135060,
+                        10678113830596413171,
+                        15645911535342061703,
+                        845671474147734118,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14115123131775171317,
+                        10693429113627961789,
+                        7191910574573445457,
+                        585903957267357410,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2016426366305297954,
+                        16929705845129734536,
+                        15837937333608192750,
+                        526321538049805474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6536281265667095679,
+                        5711632619861385571,
+                        1244453214760430057,
+                        745507426131458254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8879486607973551323,
+                        7226577943058727102,
+                        4252374008921478915,
+                        323873038591606227,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16030183291735389250,
+                        14801448891451646863,
+                        6820590535093918693,
+                        1113984280825375095,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2619066522927410847,
+                        2783884806732449300,
+                        7446850870237990112,
+                        415754870844452461,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5966633573333607471,
+                        11940794834760190473,
+                        3673125276125150804,
+                        908838582695361900,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14239153968035959945,
+                        7640292055020465723,
+                        3374801521101545032,
+                        298415569415519826,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13830094055271323646,
+                        17445934098247675139,
+                        5152810343859138606,
+                        1138941770047656764,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1414524704838856493,
+                        11523852730304852259,
+                        17124448198379526171,
+                        202995798477406321,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6726101413850042847,
+                        3781530743102681010,
+                        15113360426679925561,
+                        435360027813440253,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11467717529354530939,
+                        2919947245343812442,
+                        11465170425496804797,
+                        1170439081425545856,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1188199064759922543,
+                        10406632518203357126,
+                        11731104141164881834,
+                        203731935625012946,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13569514798254566865,
+                        17781425352218611795,
+                        18334175132729515696,
+                        126767065195394765,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12315988518124542502,
+                        15801042220788564596,
+                        8823687575188322760,
+                        712600502902516858,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15811653880750051915,
+                        2750193681513699773,
+                        11777857390006901580,
+                        192629047376549185,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8414748990022758540,
+                        16206945163463146122,
+                        11613052279591380222,
+                        662180633611585935,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10325003122662227505,
+                        3768898659766643915,
+                        8628225336190259058,
+                        335577772348233842,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13067498401258873491,
+                        5515875032070062098,
+                        3836260789171255962,
+                        623555984536090523,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8013409684859332839,
+                        4488600744393984826,
+                        2646197584800334283,
+                        10021237239880895,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16142785640091656264,
+                        14656293529607828365,
+                        1360727446011788138,
+                        1340535490621373215,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1977796735434111043,
+                        13446866343985367946,
+                        15564993348573070225,
+                        533861204906021493,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10046014392358853116,
+                        12983884607668848655,
+                        10520210076605040440,
+                        529371667747614605,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5296565488721869318,
+                        14025810869906384934,
+                        10162362351679936241,
+                        437972885537000942,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16508995536688722778,
+                        16203507354795135999,
+                        7105169659899511512,
+                        665773520506593543,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2843398054369520416,
+                        11214050823287903172,
+                        5637461570245083440,
+                        581613510613751026,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12720659414114164909,
+                        18234457988676072112,
+                        253340835270651844,
+                        1038632630046997724,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8811281247937002350,
+                        10603629466280421919,
+                        1768575279501854190,
+                        295483762936068102,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3136747370307701979,
+                        833444999221827491,
+                        11364395293438582366,
+                        199701154113127700,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9589530226444030569,
+                        6294415660414085885,
+                        18237294896924611236,
+                        766612131934214979,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5752987312391662550,
+                        7422284027073640319,
+                        15309191797269135450,
+                        497888667420363063,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6533385310512952081,
+                        14809102395818540021,
+                        15835971287327052306,
+                        473838303813367663,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3174734347112402247,
+                        17263457727604907960,
+                        1187961321497840900,
+                        42642164557676496,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12538208791013197171,
+                        9827667062666993953,
+                        3295061329882810735,
+                        440166114376746295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10709361115668792559,
+                        1439740240220230963,
+                        9227330560405005459,
+                        216622006279367686,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9481584000234224171,
+                        9994839559495650273,
+                        6164690692243069509,
+                        683788555407524512,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9429017040577091189,
+                        11185889133970889381,
+                        4545552967279137189,
+                        1317373017839083699,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17556330042905943261,
+                        5010454061150194552,
+                        10506413606317379703,
+                        964339696505977164,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15416769632642745966,
+                        2695523013436504381,
+                        5398274066879578579,
+                        443448197254101356,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15493988516354659320,
+                        7927894956614321056,
+                        10228527656849713248,
+                        941624805876361444,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18236475065185228165,
+                        11742536393865593936,
+                        18423756225473577873,
+                        1277324143280489024,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17941869221652390048,
+                        845687638498752950,
+                        12096636792243408128,
+                        485587341284218334,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1271298474976863944,
+                        10386182057900356962,
+                        4649289154079518144,
+                        949886221270816124,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16200596305091915967,
+                        16381707729789304336,
+                        17134347550643162586,
+                        1170253648859829184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6156984629500696089,
+                        11738018395683397632,
+                        13139169042647430608,
+                        1179623099512498081,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3010638496715381450,
+                        1348664049325368254,
+                        11131305304998457309,
+                        191756371762406294,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8602244206438547131,
+                        14288729679809279389,
+                        15366342265253703867,
+                        969131001928104133,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11701054150701774851,
+                        15487675548971059232,
+                        15997548891393030930,
+                        577435292795481568,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7847833977342491180,
+                        12929768596137347979,
+                        4584363318262863002,
+                        498199541296675497,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13800016428053017317,
+                        3532387563267703764,
+                        631822311516269472,
+                        656716917063439523,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2843301462820941214,
+                        9822227713198913058,
+                        1133969997744136713,
+                        649914015161717697,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15328801727501437209,
+                        9832191227421781505,
+                        18024442666583767562,
+                        909379175631638977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17080514637020166241,
+                        5395982116955495334,
+                        15388725761042864878,
+                        365433938001908810,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12354832224724678435,
+                        5206426582387145599,
+                        14346235950854356838,
+                        237697627892947676,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14900619575764244627,
+                        14600459000909373711,
+                        11910223862238841100,
+                        209421053674101212,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8739947883244423612,
+                        12433401836960889646,
+                        9937326511168222415,
+                        61711896221504976,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8232270629579745578,
+                        1674355028149197986,
+                        9330164811702036035,
+                        259144652813302012,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6546023160091923857,
+                        18099522153226817215,
+                        5066042278515935618,
+                        260366729799121941,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6838702346696543359,
+                        8206031204558453533,
+                        723113506240299978,
+                        841396144762015805,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13853939459344384278,
+                        17663005899825245346,
+                        10034159628094662966,
+                        290986527079782055,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2898470976927601114,
+                        9534357329065099184,
+                        12344567858236501696,
+                        1213574050321598,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10502058603704375315,
+                        4619351846121974839,
+                        2945751517482478677,
+                        1225673735078223769,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        482115828062302235,
+                        5442899180784132126,
+                        11553020911423329436,
+                        53436278667862068,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13693980900584807733,
+                        1288689807293231561,
+                        9531972158191878709,
+                        700941736689307561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8347304771891769722,
+                        17520305295864761180,
+                        15817711893132715181,
+                        487735557403087121,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1881307886593678248,
+                        10888315200931490851,
+                        12562771682744694743,
+                        610615857634040434,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9321496256516458146,
+                        10895786620056576840,
+                        1584352068340487122,
+                        1146514169459955050,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17850504464678251098,
+                        14537420286139392142,
+                        16816811924277690223,
+                        54805875270256138,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        423827460526666745,
+                        17651721569273268310,
+                        14846359847751680200,
+                        694327505300255894,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4802537123807758769,
+                        16909575161598688216,
+                        17922389693115661364,
+                        1154525517680560369,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        658036764885108916,
+                        17515142661578333383,
+                        6899756826131031587,
+                        890766133646415095,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9121657046985751188,
+                        2620121169961916177,
+                        2020089317928358118,
+                        1075432271296339747,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14906393480671066892,
+                        1570772813253599452,
+                        12398280583191858011,
+                        277476831185964487,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15324908895688622083,
+                        11511376181997137402,
+                        2594386518531363380,
+                        638106724638171197,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        650344549727787558,
+                        10353922032662197748,
+                        4866388833989520721,
+                        374050668773134148,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7730404967192513131,
+                        12770658750498470120,
+                        12556714582221171991,
+                        117169735754042487,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17209008719985895503,
+                        15844944974879747014,
+                        9250100702218362183,
+                        497025950436137610,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17868575321071362450,
+                        9091535309005787505,
+                        18256663457285712534,
+                        755043390029259252,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12869666949381246021,
+                        12478192684785458455,
+                        3635792410770606631,
+                        182269681833824439,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1048751137434814281,
+                        7034270976023535945,
+                        4953471687736537045,
+                        537737570536623544,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2458798543937541705,
+                        17074877604046199063,
+                        11535557679221304030,
+                        767562582784501291,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2870680176241052404,
+                        11494604637512762499,
+                        694798585241857079,
+                        1329181380936963100,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4333450465748886583,
+                        4485725245825748864,
+                        12992150559631277159,
+                        1163938833630061853,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16298807096161280382,
+                        130869956077250841,
+                        686385249301354709,
+                        267577593985970052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14809871201848511355,
+                        10388687595866682739,
+                        5342228956298645852,
+                        109982016575370945,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2143646735622070042,
+                        17613041248758720173,
+                        14350129789241720935,
+                        714152101916603920,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5674158362747093495,
+                        14357685135202013624,
+                        3991641824670145491,
+                        1133309656904745928,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1309221957199475918,
+                        10955545712222593144,
+                        669022171095332193,
+                        1051328996810412678,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14893699792199718757,
+                        17982627672411691291,
+                        12418305474592263037,
+                        1168944359698297403,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4848303776864446524,
+                        7276697791505872511,
+                        15912138602435317198,
+                        692200753756292046,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13996567205052403890,
+                        4577854218402185980,
+                        17710316113359145124,
+                        994681976503960365,
+                    ]),
+                ]),
+                Matrix::<14, 1, 14>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9811544879690913085,
+                        5495918444618373263,
+                        18126816809952747803,
+                        66072021543936501,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7823301816714608723,
+                        929126870279212028,
+                        6822992957525311663,
+                        1315501693878477770,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1747232031165409729,
+                        7198594876682134279,
+                        9848226032343263772,
+                        86960509169161246,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        726438627454688674,
+                        6665778299796740023,
+                        11106249100604029118,
+                        504304839443599967,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8834142942808204589,
+                        2027206236995974886,
+                        6717233364683847587,
+                        919631682489206417,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10839212483215164872,
+                        8090053245708275171,
+                        17912211400073719198,
+                        611432259625534210,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13462561080434773000,
+                        1589444873904005057,
+                        9882189644094682839,
+                        858687697819423101,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5147113484039650250,
+                        12048771600563765515,
+                        13479936807116189045,
+                        721286404181538815,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16081951132755216939,
+                        18289182240955975037,
+                        14403869226386161682,
+                        956055710621113742,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10257507640015018533,
+                        2198561773977692364,
+                        1263992757742998483,
+                        27856694555050147,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3721746474236821006,
+                        4681444499455346408,
+                        14607441604154281648,
+                        525925282758035464,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2093719059910778063,
+                        16853521611116230730,
+                        13368432491285307334,
+                        1210796268394013875,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7553798426691471602,
+                        17075575302367419515,
+                        1295975842133324417,
+                        917955171422197086,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1772194432805144671,
+                        8146825268959792583,
+                        3871488328560296356,
+                        23697613540228435,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<39, 15, 585>::new_from_known([
+            Fq::from_montgomery_limbs([
+                18308084057473604412,
+                5489731857298421512,
+                4258450907128428813,
+                884957275673051892,
+            ]),
+            Fq::from_montgomery_limbs([
+                1272794352578233488,
+                4897741565411562819,
+                14465078897568210251,
+                110081842056619818,
+            ]),
+            Fq::from_montgomery_limbs([
+                2825267567891830470,
+                10481453081609924854,
+                1384358595340448702,
+                729364596643087818,
+            ]),
+            Fq::from_montgomery_limbs([
+                16039707790931904618,
+                3608880475537882901,
+                13876239505403276641,
+                239203807281236172,
+            ]),
+            Fq::from_montgomery_limbs([
+                3843011967725031067,
+                15027361217463019073,
+                15273838363955264525,
+                686253461831385916,
+            ]),
+            Fq::from_montgomery_limbs([
+                18149993311708208529,
+                18294640717719854258,
+                12800491994158817028,
+                1038100061001222583,
+            ]),
+            Fq::from_montgomery_limbs([
+                579003635857673820,
+                14983734209235445985,
+                1352393097263128346,
+                1106801298485841576,
+            ]),
+            Fq::from_montgomery_limbs([
+                16181938007842827295,
+                7359994375761214853,
+                14776805548749259129,
+                641128107804286353,
+            ]),
+            Fq::from_montgomery_limbs([
+                6701839941476003779,
+                15019843352074411245,
+                10328613553524298318,
+                118098333543327477,
+            ]),
+            Fq::from_montgomery_limbs([
+                1208250685027852604,
+                11313579207909808398,
+                17414460712310764961,
+                874068969179538995,
+            ]),
+            Fq::from_montgomery_limbs([
+                1632002177900688912,
+                146396251540645569,
+                12335054109575443777,
+                439413999039603112,
+            ]),
+            Fq::from_montgomery_limbs([
+                12511932926406430162,
+                12271798818296516757,
+                18002023907994355484,
+                304507009374750137,
+            ]),
+            Fq::from_montgomery_limbs([
+                15131090052164468554,
+                7043970176598983245,
+                11160388890928803661,
+                1111218965318599886,
+            ]),
+            Fq::from_montgomery_limbs([
+                9051514367982524396,
+                269349758023438832,
+                10538984020034974419,
+                180420024367591554,
+            ]),
+            Fq::from_montgomery_limbs([
+                7334643170540006672,
+                16109128843936757777,
+                5650946313412420671,
+                466441949793554303,
+            ]),
+            Fq::from_montgomery_limbs([
+                11319488525518003917,
+                13134966361646589085,
+                1798377273057680673,
+                790169698125370756,
+            ]),
+            Fq::from_montgomery_limbs([
+                3910153256021726127,
+                16121958461586998475,
+                536618421093394297,
+                832058781570349769,
+            ]),
+            Fq::from_montgomery_limbs([
+                15735469782786499438,
+                3258990666106120895,
+                2910993358914929215,
+                1190376512657190702,
+            ]),
+            Fq::from_montgomery_limbs([
+                8644394199540097835,
+                1886395076205233601,
+                3803594076596352179,
+                794557353626217986,
+            ]),
+            Fq::from_montgomery_limbs([
+                3938311766918286452,
+                3099405685646712267,
+                8254111466774267860,
+                118806076642330862,
+            ]),
+            Fq::from_montgomery_limbs([
+                17512121623243217402,
+                15164388877975470463,
+                4951978534138157836,
+                184592042174608545,
+            ]),
+            Fq::from_montgomery_limbs([
+                1496787188547880324,
+                7443093160170175389,
+                14220789772482417892,
+                1052029390160094235,
+            ]),
+            Fq::from_montgomery_limbs([
+                7807262218096821986,
+                441699664483617865,
+                7261106891211397292,
+                224564376962743983,
+            ]),
+            Fq::from_montgomery_limbs([
+                5199190521864755883,
+                16324296195816620466,
+                3890263789745768098,
+                331156176017181758,
+            ]),
+            Fq::from_montgomery_limbs([
+                13839154718504622762,
+                5933840245694963054,
+                15765306641982422092,
+                371829367755699929,
+            ]),
+            Fq::from_montgomery_limbs([
+                7732474730380761237,
+                14654563260095297546,
+                12739882774811550610,
+                217351352864374233,
+            ]),
+            Fq::from_montgomery_limbs([
+                13797933815252580099,
+                16532811063610155618,
+                5283197381598485764,
+                1035437947635981887,
+            ]),
+            Fq::from_montgomery_limbs([
+                10953145039115092815,
+                18124893806893878222,
+                14405981581851639591,
+                503738681451731514,
+            ]),
+            Fq::from_montgomery_limbs([
+                15457451679454567022,
+                820380345268473401,
+                11173834609817588740,
+                1006970548510176946,
+            ]),
+            Fq::from_montgomery_limbs([
+                4136206206019913225,
+                3108354001222301738,
+                12090405216101960012,
+                208725560220035248,
+            ]),
+            Fq::from_montgomery_limbs([
+                13287752109281451047,
+                17935529569893493909,
+                4177932291077601489,
+                876543939475699531,
+            ]),
+            Fq::from_montgomery_limbs([
+                9013134599274291304,
+                5230277629953919505,
+                11901351881132999962,
+                724821299932538331,
+            ]),
+            Fq::from_montgomery_limbs([
+                2910283266108477308,
+                2354655083405936597,
+                13329372210449767929,
+                1127007883164275718,
+            ]),
+            Fq::from_montgomery_limbs([
+                2390480710934245985,
+                7224220568459381476,
+                5951518706089781254,
+                789670202802377253,
+            ]),
+            Fq::from_montgomery_limbs([
+                6940426989865089540,
+                11791542662193105342,
+                5540680464476732592,
+                549780689115442453,
+            ]),
+            Fq::from_montgomery_limbs([
+                12436790908465836051,
+                9239332715126808844,
+                1649998657485509187,
+                842291997060708586,
+            ]),
+            Fq::from_montgomery_limbs([
+                5617725237877946066,
+                15799849346095457524,
+                14287785100806862192,
+                668383716475639003,
+            ]),
+            Fq::from_montgomery_limbs([
+                10185373022441057932,
+                11243133359078204052,
+                11483895702392036131,
+                335837759586382565,
+            ]),
+            Fq::from_montgomery_limbs([
+                7993832671854783560,
+                3498368359188699355,
+                14437395672142899084,
+                690910087849513675,
+            ]),
+            Fq::from_montgomery_limbs([
+                10918946283906482078,
+                8461737921657521096,
+                13247459718778131557,
+                871675192726582189,
+            ]),
+            Fq::from_montgomery_limbs([
+                12258166863344085269,
+                16056041202046508907,
+                16634979568120315047,
+                331476037099337432,
+            ]),
+            Fq::from_montgomery_limbs([
+                3677403777614403494,
+                8319785719974407003,
+                10428433726742972612,
+                1327516670907933800,
+            ]),
+            Fq::from_montgomery_limbs([
+                146015397262316649,
+                17322574936635830849,
+                7514465726138845435,
+                443196575949233,
+            ]),
+            Fq::from_montgomery_limbs([
+                11762469566952521449,
+                9056683462714090619,
+                13093858268020073535,
+                948751780028272643,
+            ]),
+            Fq::from_montgomery_limbs([
+                8235965040162572323,
+                8596077192999837946,
+                17764313198726873155,
+                1235234599637985257,
+            ]),
+            Fq::from_montgomery_limbs([
+                13116075210618895575,
+                11574291250050222562,
+                12840476069873417018,
+                201128790511774349,
+            ]),
+            Fq::from_montgomery_limbs([
+                11141508862254281571,
+                1992815376676803932,
+                10813005397113913737,
+                690044218847039241,
+            ]),
+            Fq::from_montgomery_limbs([
+                5547426095819640968,
+                13075130842621399626,
+                4272074965045000520,
+                785641636255909979,
+            ]),
+            Fq::from_montgomery_limbs([
+                2022530877692421698,
+                8606186233022646625,
+                756888699341738047,
+                696650977285931105,
+            ]),
+            Fq::from_montgomery_limbs([
+                15411308658153064927,
+                817079547870196099,
+                11807039696266530919,
+                232758034419300791,
+            ]),
+            Fq::from_montgomery_limbs([
+                1020072514826925809,
+                1388990440000525455,
+                845100568921007135,
+                740980215635733728,
+            ]),
+            Fq::from_montgomery_limbs([
+                8456517355009430172,
+                1408921361337015006,
+                722025857239314051,
+                765073350983890641,
+            ]),
+            Fq::from_montgomery_limbs([
+                10033928650639208252,
+                15940866197279247145,
+                3529592831953386370,
+                1298620073781936296,
+            ]),
+            Fq::from_montgomery_limbs([
+                14645806911834573775,
+                7075571312626088833,
+                3674606675594056682,
+                291998875370634477,
+            ]),
+            Fq::from_montgomery_limbs([
+                3538586419399279179,
+                10300549741126595128,
+                11010730525530984904,
+                538656727874608105,
+            ]),
+            Fq::from_montgomery_limbs([
+                7834242144583198780,
+                2340086396925202398,
+                2390508358663653464,
+                1100114636614950740,
+            ]),
+            Fq::from_montgomery_limbs([
+                8783361557674181347,
+                853572580978410178,
+                13399452589311149373,
+                389313093294353104,
+            ]),
+            Fq::from_montgomery_limbs([
+                672219141613780414,
+                18406018010317417732,
+                16342136847253989310,
+                1312931990163857855,
+            ]),
+            Fq::from_montgomery_limbs([
+                16792252202287757294,
+                13017696867108281323,
+                12267137411344211873,
+                484130281992530641,
+            ]),
+            Fq::from_montgomery_limbs([
+                8870816554669005575,
+                13071499269681828444,
+                3027023959595129729,
+                36364074644819767,
+            ]),
+            Fq::from_montgomery_limbs([
+                393388219226962277,
+                17487057442008805691,
+                8550383193659920928,
+                1333748469485008906,
+            ]),
+            Fq::from_montgomery_limbs([
+                11136095326179917777,
+                8665355037033043742,
+                11434320734014928355,
+                343461506319450333,
+            ]),
+            Fq::from_montgomery_limbs([
+                13235744994279289196,
+                8582512140214491366,
+                12761252408727327707,
+                190677111526113802,
+            ]),
+            Fq::from_montgomery_limbs([
+                8095958875873714466,
+                13703749242542171331,
+                872923580410921633,
+                412894081606452762,
+            ]),
+            Fq::from_montgomery_limbs([
+                8721126262521519950,
+                12618472956102327530,
+                15361161451571826348,
+                154124093485954853,
+            ]),
+            Fq::from_montgomery_limbs([
+                12650012652556426709,
+                17104387649736293173,
+                7029375944986358602,
+                208458210896630208,
+            ]),
+            Fq::from_montgomery_limbs([
+                11925793174612200149,
+                3750273564129156865,
+                16979478700467744771,
+                16788774801510865,
+            ]),
+            Fq::from_montgomery_limbs([
+                9651907057362992891,
+                12400229050161383481,
+                15850995026490805715,
+                1238325525013223864,
+            ]),
+            Fq::from_montgomery_limbs([
+                10056383726285265894,
+                14074593632493339994,
+                3604469851267671519,
+                1123202196449989164,
+            ]),
+            Fq::from_montgomery_limbs([
+                5754302344808168454,
+                17378318525072203825,
+                15573585888645198042,
+                380124573966783252,
+            ]),
+            Fq::from_montgomery_limbs([
+                11906201413715350477,
+                13946708272121957050,
+                16477518368703074378,
+                966785648454110050,
+            ]),
+            Fq::from_montgomery_limbs([
+                10697629359902426485,
+                17116805008247280096,
+                1800466026623829895,
+                1301885920481462896,
+            ]),
+            Fq::from_montgomery_limbs([
+                13332322481295831151,
+                11695997625744945859,
+                2188500959515076446,
+                281248696563699439,
+            ]),
+            Fq::from_montgomery_limbs([
+                1442968847196692933,
+                140678073472433055,
+                7915896985981249780,
+                891690430686249411,
+            ]),
+            Fq::from_montgomery_limbs([
+                7335933405579280925,
+                10984110972010058189,
+                9140168355657246705,
+                130875544980519647,
+            ]),
+            Fq::from_montgomery_limbs([
+                10730225962708288142,
+                7094040921073194738,
+                2822114867194811791,
+                1114698646043077752,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15858008481195504016,
+                670203117520725000,
+                15065697518102636045,
+                162374345800912007,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3557165511214199210,
+                11920490168762337533,
+                13408538495061822449,
+                1175164684494223190,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17476117758136045380,
+                8118778480197528243,
+                6008434808477574353,
+                257156107417779601,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11918879262383962147,
+                15375451832027518829,
+                16432165510818982482,
+                1098457883129984357,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9451947490723863151,
+                1829930289645644685,
+                7378624011101140575,
+                572185252756452073,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17361375731057522322,
+                12192980173571843493,
+                7405045524285325498,
+                386293794043977230,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17712761379395268843,
+                15437141633799008268,
+                1897831078569014595,
+                10087835939086903,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3150258270010973406,
+                5977297164559911514,
+                17358988773061157171,
+                910558040155190249,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8449853379141959390,
+                16774064533559707795,
+                14817912543974352686,
+                341981762015244889,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6588637900161846828,
+                17498873408515827455,
+                7162172895567496531,
+                1107336049246683415,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8129772612546829976,
+                9994781697001710056,
+                8175159136710407023,
+                477035615744388261,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9641700500093814544,
+                12172998157595907913,
+                9291914491854237554,
+                621650273510978379,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3677229149824160479,
+                14862746716429415290,
+                14063969086973230225,
+                305207895128904127,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17865690466330912971,
+                8131117071376042644,
+                13968162178528695625,
+                200920099681923289,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17601117716038412342,
+                15290191703304205849,
+                18323635346821067907,
+                192352338681268166,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10903941410303879357,
+                15989341071371898167,
+                18182296354477429764,
+                99676880251733498,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2198496880366119192,
+                747659305267141075,
+                3250290061418849425,
+                105825290524789181,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7250525522509189301,
+                7860725638149407295,
+                10005830232031564992,
+                1143262300746360736,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10969010222674581171,
+                10172505684682865834,
+                3187904525903374855,
+                327418404460779633,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4591200946875025260,
+                1538862300737084433,
+                13676567726618198911,
+                515067629530819576,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2236646331817193295,
+                5113823989075821252,
+                14285702105701168517,
+                148354260184346482,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5994920128709652731,
+                17118036459973103825,
+                3899932825588517502,
+                822743278836220288,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5101485623422729134,
+                12653475409224082527,
+                11279623718316241475,
+                240524031072826460,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13976599785943095814,
+                9984796344152972826,
+                4365330033157993652,
+                716057512688852909,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15318898980166163906,
+                8102326523760390562,
+                16565413821101836078,
+                1136214932518506746,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4845735134663482653,
+                4350442912466408744,
+                5169447698919360890,
+                197549751229397939,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16426639006779878412,
+                17510086623352369523,
+                14136470699459235522,
+                322881214894761604,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12143419245534066102,
+                15884743532248672930,
+                8970268696594003982,
+                1295376753047748478,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16565492943025653858,
+                9448521318430197135,
+                10551334414833117958,
+                419442622374502902,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6285123947181717516,
+                12294587353976768591,
+                14997990574023284312,
+                940413702414751232,
+            ]),
+            Fq::from_montgomery_limbs([
+                8394942942923770658,
+                10589475567760368244,
+                4987348235627928392,
+                48324519489045815,
+            ]),
+            Fq::from_montgomery_limbs([
+                13581689577954861888,
+                9958926930053144026,
+                1243191982323085063,
+                1345261057448900310,
+            ]),
+            Fq::from_montgomery_limbs([
+                8018738443719845976,
+                5436985039259258427,
+                642552234098720271,
+                786189149532257232,
+            ]),
+            Fq::from_montgomery_limbs([
+                17624759765459457842,
+                5490704996359581459,
+                15345783217774043233,
+                133925716541224649,
+            ]),
+            Fq::from_montgomery_limbs([
+                3232801700531887913,
+                5006855454765361576,
+                4421285949883804731,
+                36992176594320934,
+            ]),
+            Fq::from_montgomery_limbs([
+                11886751876051442072,
+                9769166756828632013,
+                8832004451209083220,
+                1239838521422301603,
+            ]),
+            Fq::from_montgomery_limbs([
+                1869472012635061085,
+                6982559072953489189,
+                8729290393197490485,
+                305217165270235047,
+            ]),
+            Fq::from_montgomery_limbs([
+                1638526610617424045,
+                16568113764068918996,
+                4271432408785318646,
+                619368372626105266,
+            ]),
+            Fq::from_montgomery_limbs([
+                17828249058149030438,
+                4154239032445167855,
+                14045771388431621098,
+                805416544990847831,
+            ]),
+            Fq::from_montgomery_limbs([
+                794928292106948534,
+                11073778702756842460,
+                11590570199614550397,
+                453740720736209443,
+            ]),
+            Fq::from_montgomery_limbs([
+                5598667878133684246,
+                14500280939102471965,
+                13003200686904562881,
+                84314511410444090,
+            ]),
+            Fq::from_montgomery_limbs([
+                10992445251144854193,
+                14873305031121990228,
+                16998450639850325902,
+                797651183182402135,
+            ]),
+            Fq::from_montgomery_limbs([
+                5398984828530370176,
+                11673106470333587130,
+                5890697881690816465,
+                817713634111387698,
+            ]),
+            Fq::from_montgomery_limbs([
+                15254847493636685156,
+                10093542403657533252,
+                13333896343421006676,
+                1137189034727920915,
+            ]),
+            Fq::from_montgomery_limbs([
+                5188611932366626439,
+                1825825348641952542,
+                5636826247181787904,
+                589979407514727359,
+            ]),
+            Fq::from_montgomery_limbs([
+                4254172054694378118,
+                7360086518347432696,
+                9263006381465754863,
+                370155135614548575,
+            ]),
+            Fq::from_montgomery_limbs([
+                1945841263702980399,
+                4358299002895293026,
+                13416858416992270215,
+                789305626308013968,
+            ]),
+            Fq::from_montgomery_limbs([
+                14916904790901110345,
+                10794027284923854731,
+                18287457065518452646,
+                1020783655288093247,
+            ]),
+            Fq::from_montgomery_limbs([
+                5998117733076824647,
+                13186793574874002347,
+                10877091263552564503,
+                366624963077665272,
+            ]),
+            Fq::from_montgomery_limbs([
+                14957290856190698027,
+                1817555610538001139,
+                10619780942629413629,
+                156930787106807785,
+            ]),
+            Fq::from_montgomery_limbs([
+                9432025522033696768,
+                12062892720540583034,
+                8575273391680002811,
+                1035643416083400618,
+            ]),
+            Fq::from_montgomery_limbs([
+                13518565549207327565,
+                10358036952313611541,
+                14746017618863577779,
+                473803439582904381,
+            ]),
+            Fq::from_montgomery_limbs([
+                16223916160380111756,
+                10326730827930978469,
+                6857916318572490043,
+                732448093060678049,
+            ]),
+            Fq::from_montgomery_limbs([
+                6303589318519741326,
+                14449416942794767344,
+                10320043311443462586,
+                1041727804247963619,
+            ]),
+            Fq::from_montgomery_limbs([
+                4399157332073245358,
+                2950141682970966831,
+                3696731802621157399,
+                901696623522630684,
+            ]),
+            Fq::from_montgomery_limbs([
+                12560203889416900496,
+                895165303728796581,
+                10174643751596721871,
+                583639131684371630,
+            ]),
+            Fq::from_montgomery_limbs([
+                11023775026354205623,
+                11169737544783274661,
+                11940198135483730928,
+                679763920774075647,
+            ]),
+            Fq::from_montgomery_limbs([
+                6914655485839727369,
+                10573969194095572771,
+                9608867332612902000,
+                307319586811603592,
+            ]),
+            Fq::from_montgomery_limbs([
+                9582336708025245608,
+                870928264672747650,
+                5181546031369964704,
+                193154118088956915,
+            ]),
+            Fq::from_montgomery_limbs([
+                1736922270243267529,
+                14295379944067358475,
+                12140728213530822628,
+                1053369914422534925,
+            ]),
+            Fq::from_montgomery_limbs([
+                14366402998251886528,
+                11097591400721930065,
+                11698795101784793828,
+                694085737150978551,
+            ]),
+            Fq::from_montgomery_limbs([
+                9635615122491008272,
+                11574456220226329034,
+                14020669832878026551,
+                242021539238766722,
+            ]),
+            Fq::from_montgomery_limbs([
+                7169625346694254797,
+                5536218470749318985,
+                7575035677192264706,
+                403481290997580896,
+            ]),
+            Fq::from_montgomery_limbs([
+                7037466538603165069,
+                12528036234529468606,
+                10691442333948872208,
+                1162941285672424468,
+            ]),
+            Fq::from_montgomery_limbs([
+                16186927630380345129,
+                3100771406531874384,
+                15560684174865478913,
+                793797628069713429,
+            ]),
+            Fq::from_montgomery_limbs([
+                17027422759471654037,
+                15315428998511398058,
+                7156794061103613005,
+                252876827450033403,
+            ]),
+            Fq::from_montgomery_limbs([
+                12011467595110531289,
+                9261630528339711828,
+                6362802218043859657,
+                1274255344095926308,
+            ]),
+            Fq::from_montgomery_limbs([
+                17256218633851943288,
+                7340626169170929617,
+                4045894614753472545,
+                117370838367433268,
+            ]),
+            Fq::from_montgomery_limbs([
+                1940586057930556906,
+                3434227479031343892,
+                993453383195206298,
+                24385000924864761,
+            ]),
+            Fq::from_montgomery_limbs([
+                1038324103384638955,
+                10674601288940900733,
+                10284495318219736606,
+                1001320758252003575,
+            ]),
+            Fq::from_montgomery_limbs([
+                10572551308611633156,
+                5432310797087618215,
+                16495684963415140448,
+                384491981631033054,
+            ]),
+            Fq::from_montgomery_limbs([
+                11838211203321056222,
+                17343481411847420973,
+                2854494973132285864,
+                859797803410458337,
+            ]),
+            Fq::from_montgomery_limbs([
+                3771067433614884471,
+                13329638886706599254,
+                10367928269499999681,
+                1103700473173455961,
+            ]),
+            Fq::from_montgomery_limbs([
+                10103906926633234988,
+                2534736636148567604,
+                14952613056563236408,
+                1185708084470239423,
+            ]),
+            Fq::from_montgomery_limbs([
+                8901226200527931766,
+                11513675612424203660,
+                2096148750587643256,
+                654185448797432391,
+            ]),
+            Fq::from_montgomery_limbs([
+                7061810472414433932,
+                6598793323361826786,
+                16695754978955677643,
+                444469668563526644,
+            ]),
+            Fq::from_montgomery_limbs([
+                17352104428318880199,
+                16365518589992400225,
+                17312393558271738875,
+                750173611970977756,
+            ]),
+            Fq::from_montgomery_limbs([
+                7818869192012769214,
+                9218737990992735768,
+                8588819471747881715,
+                657701777197829150,
+            ]),
+            Fq::from_montgomery_limbs([
+                11385322463099410513,
+                7459938230210921644,
+                1894418803040692475,
+                789632631941553608,
+            ]),
+            Fq::from_montgomery_limbs([
+                12145490903726644848,
+                17073291084333045535,
+                7089368401568901532,
+                1328417234937095419,
+            ]),
+            Fq::from_montgomery_limbs([
+                16146139008065423033,
+                1790820798415556294,
+                16790313805176585782,
+                725420923660204156,
+            ]),
+            Fq::from_montgomery_limbs([
+                10245576855235654259,
+                14089376858004111330,
+                16399220889295690539,
+                406897473279213051,
+            ]),
+            Fq::from_montgomery_limbs([
+                15582130801734254816,
+                15164653383197900819,
+                320537078437390665,
+                550332272645209477,
+            ]),
+            Fq::from_montgomery_limbs([
+                1219694032815735872,
+                9842809782639655869,
+                14895449469368227139,
+                575580241252599615,
+            ]),
+            Fq::from_montgomery_limbs([
+                13583771849309727783,
+                14290725850554473314,
+                13279341107429715892,
+                598480030806856605,
+            ]),
+            Fq::from_montgomery_limbs([
+                11484300973121360609,
+                7234895792480630315,
+                7870390172257027364,
+                1066196704378182,
+            ]),
+            Fq::from_montgomery_limbs([
+                13653714222133323700,
+                14803081186098458272,
+                2815332836903672873,
+                586991125375928070,
+            ]),
+            Fq::from_montgomery_limbs([
+                3763049492196082291,
+                13684346144348545604,
+                6247875733177057906,
+                860142073672418279,
+            ]),
+            Fq::from_montgomery_limbs([
+                10358594958450176595,
+                5723856226384845216,
+                1032727983621612389,
+                816903689903131993,
+            ]),
+        ]),
+    }
+}
@@ -0,0 +1,19960 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-15 instance of Poseidon.
+pub const fn rate_15() -> PoseidonParameters<16, 15, 256, 225, 39, 16, 624, 31> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<39, 16, 624>::new_from_known([
+            Fq::from_montgomery_limbs([
+                7411270673441529716,
+                6308985163655666832,
+                9017065419253201486,
+                1273036220096323704,
+            ]),
+            Fq::from_montgomery_limbs([
+                2754481538479821082,
+                13142387114410948291,
+                15614410621228036303,
+                60799155456132103,
+            ]),
+            Fq::from_montgomery_limbs([
+                17681218254003890668,
+                391061690871725535,
+                3295905609211558288,
+                967878701866012597,
+            ]),
+            Fq::from_montgomery_limbs([
+                9382385917264579619,
+                1042981499962302920,
+                139661287799805261,
+                275002209344561392,
+            ]),
+            Fq::from_montgomery_limbs([
+                3356127090706323211,
+                17457882483960025298,
+                16074483218178366824,
+                628970122872722509,
+            ]),
+            Fq::from_montgomery_limbs([
+                13462764728625831335,
+                10644438855851007328,
+                15113772679872861730,
+                551589203084750099,
+            ]),
+            Fq::from_montgomery_limbs([
+                4490885036584545826,
+                294744012913749609,
+                14929471119568885487,
+                199825482639017355,
+            ]),
+            Fq::from_montgomery_limbs([
+                11139153319706881816,
+                4245900581361682809,
+                17354086123417629052,
+                50445950495378456,
+            ]),
+            Fq::from_montgomery_limbs([
+                17726412809381474395,
+                1185542180996861490,
+                16776597330553208130,
+                93580226522239066,
+            ]),
+            Fq::from_montgomery_limbs([
+                6489837635897767275,
+                9517955511991088438,
+                3365970773577828706,
+                65131757989219483,
+            ]),
+            Fq::from_montgomery_limbs([
+                13195680198221082238,
+                2383910281870666191,
+                1751784164612983911,
+                1295852563093527892,
+            ]),
+            Fq::from_montgomery_limbs([
+                7599836752410862561,
+                13075540022144339987,
+                6056774453545501119,
+                83814826306509281,
+            ]),
+            Fq::from_montgomery_limbs([
+                14644057716587539970,
+                5560237006344492025,
+                7027360837916113013,
+                1280399964310289185,
+            ]),
+            Fq::from_montgomery_limbs([
+                12897533187573387415,
+                9510037741928507348,
+                11888709895318763661,
+                429046206843651946,
+            ]),
+            Fq::from_montgomery_limbs([
+                1191312506224952220,
+                17465108299008007220,
+                4861273103718473552,
+                977018214961082243,
+            ]),
+            Fq::from_montgomery_limbs([
+                4433928987232758141,
+                10070898845275748321,
+                10240923463622893096,
+                63644698799876675,
+            ]),
+            Fq::from_montgomery_limbs([
+                3404030751357400165,
+                9022606047806285066,
+                1229479431923646198,
+                956191681276825462,
+            ]),
+            Fq::from_montgomery_limbs([
+                16642323736040229713,
+                16365680258596711750,
+                222643453659539029,
+                746711359925830444,
+            ]),
+            Fq::from_montgomery_limbs([
+                14838753900531502837,
+                14549797137591825435,
+                17779732971370123843,
+                296327785807264915,
+            ]),
+            Fq::from_montgomery_limbs([
+                1692880410177102943,
+                2201718689788150186,
+                11801128233516208895,
+                644088368976324965,
+            ]),
+            Fq::from_montgomery_limbs([
+                14600655902172280777,
+                2133007916749663594,
+                2336120572595589606,
+                1156126723298506111,
+            ]),
+            Fq::from_montgomery_limbs([
+                3389840373816541874,
+                13117728105779072629,
+                15113852838538008817,
+                1202564071852770459,
+            ]),
+            Fq::from_montgomery_limbs([
+                15858855412337336447,
+                6320335126041882256,
+                17894518801214338994,
+                675022417689048120,
+            ]),
+            Fq::from_montgomery_limbs([
+                17289224702611261,
+                2084133037868211175,
+                8498115080144768495,
+                1225244605346733206,
+            ]),
+            Fq::from_montgomery_limbs([
+                6550601404889645163,
+                15245941719049518580,
+                2657253156478402355,
+                1248919711798218453,
+            ]),
+            Fq::from_montgomery_limbs([
+                14329251677778643340,
+                15851848482071801359,
+                15068422321109667761,
+                338444175817276314,
+            ]),
+            Fq::from_montgomery_limbs([
+                9001754648848512408,
+                18126147854230739651,
+                2546530148984129641,
+                1069863388194689308,
+            ]),
+            Fq::from_montgomery_limbs([
+                13398985390342509119,
+                8320344976078102731,
+                4593591666116341430,
+                1317901298790438930,
+            ]),
+            Fq::from_montgomery_limbs([
+                16339853447097255167,
+                15067313439405269005,
+                8328698015177502820,
+                1306915863904246027,
+            ]),
+            Fq::from_montgomery_limbs([
+                18055607770420239695,
+                8510070023587245419,
+                13979835543472845557,
+                1135556679081857825,
+            ]),
+            Fq::from_montgomery_limbs([
+                10391556789540864033,
+                12509559194552040872,
+                2195456274702733728,
+                1054007862678499811,
+            ]),
+            Fq::from_montgomery_limbs([
+                16005075313160758392,
+                17190633201933889209,
+                12961286432151203310,
+                908555160493793095,
+            ]),
+            Fq::from_montgomery_limbs([
+                5941286409643059367,
+                4216455443648782001,
+                14922174002802797696,
+                1145688508124505912,
+            ]),
+            Fq::from_montgomery_limbs([
+                4081626116450082823,
+                986857377554817864,
+                13320078669435567902,
+                121130509074165585,
+            ]),
+            Fq::from_montgomery_limbs([
+                13965697914427859302,
+                6730055884632064028,
+                4009962198248029138,
+                1258196817268490501,
+            ]),
+            Fq::from_montgomery_limbs([
+                4181018001998533725,
+                1169375653294767443,
+                2317340319855180390,
+                610271809394963211,
+            ]),
+            Fq::from_montgomery_limbs([
+                15345592974214864765,
+                11756985772280829740,
+                3249691021255748636,
+                928650240255052872,
+            ]),
+            Fq::from_montgomery_limbs([
+                14721789589393511871,
+                3801559902612939274,
+                1269843483399815700,
+                729474487024310697,
+            ]),
+            Fq::from_montgomery_limbs([
+                10812041293865829605,
+                13641653068199214502,
+                16511168247030098959,
+                581163797429420120,
+            ]),
+            Fq::from_montgomery_limbs([
+                4278670999095461702,
+                16017393301504358304,
+                5661940865333181991,
+                430324343886282152,
+            ]),
+            Fq::from_montgomery_limbs([
+                9883986771031314364,
+                4344563170235282606,
+                117511927586584387,
+                656539544478436494,
+            ]),
+            Fq::from_montgomery_limbs([
+                3893450878444113547,
+                1340615706018353837,
+                3111845862707977871,
+                794599599646950517,
+            ]),
+            Fq::from_montgomery_limbs([
+                14307547483085359895,
+                15935438713017829972,
+                7185097188745225557,
+                1288831561442847496,
+            ]),
+            Fq::from_montgomery_limbs([
+                1708738519292765530,
+                7600010890490490885,
+                3599496688147059489,
+                185682915389923716,
+            ]),
+            Fq::from_montgomery_limbs([
+                2375250159949755826,
+                12586228962985077151,
+                2504069817274929624,
+                1019357997219268757,
+            ]),
+            Fq::from_montgomery_limbs([
+                5047553599136320138,
+                12944649827273566831,
+                14094014207017714172,
+                1268728803688924111,
+            ]),
+            Fq::from_montgomery_limbs([
+                9522887523345397497,
+                14369640279108840219,
+                2674259964551331209,
+                1152106214351373351,
+            ]),
+            Fq::from_montgomery_limbs([
+                14308534478599417955,
+                1635134900133544950,
+                15580239714380892153,
+                987297846788680911,
+            ]),
+            Fq::from_montgomery_limbs([
+                11888780574212852122,
+                9843552218247047745,
+                2103343019576331380,
+                269648191012281603,
+            ]),
+            Fq::from_montgomery_limbs([
+                8681871298857029777,
+                2693459785378542920,
+                18405343396128185155,
+                1170547261402466001,
+            ]),
+            Fq::from_montgomery_limbs([
+                10510347393101314767,
+                17156935645029422426,
+                10131767878161748647,
+                1075787453378598328,
+            ]),
+            Fq::from_montgomery_limbs([
+                12946748829372988719,
+                8789845603108855356,
+                5358042317598432313,
+                1176660036200666057,
+            ]),
+            Fq::from_montgomery_limbs([
+                2199557982095912605,
+                4654560912825113894,
+                7467648603159747528,
+                13260115585984035,
+            ]),
+            Fq::from_montgomery_limbs([
+                7638115408211449559,
+                2663121918148992786,
+                4643421311575631205,
+                327260884114408498,
+            ]),
+            Fq::from_montgomery_limbs([
+                13801202696324311895,
+                6305258592999489804,
+                10064082026568031373,
+                792217452205937761,
+            ]),
+            Fq::from_montgomery_limbs([
+                12003293573085016508,
+                3737195379828864730,
+                7326750963840042234,
+                389860117833651710,
+            ]),
+            Fq::from_montgomery_limbs([
+                3254076379832815052,
+                6236871367175874311,
+                6352888970302594044,
+                1150530127698376577,
+            ]),
+            Fq::from_montgomery_limbs([
+                12729046537229850973,
+                8282839418501142558,
+                7575004300165644929,
+                750409314119831997,
+            ]),
+            Fq::from_montgomery_limbs([
+                17194721353212298574,
+                2256515527322867839,
+                11911341969523066809,
+                1164972466728630927,
+            ]),
+            Fq::from_montgomery_limbs([
+                3797154598862283085,
+                2725094488220713450,
+                1718896754257539896,
+                42164594973570673,
+            ]),
+            Fq::from_montgomery_limbs([
+                18032951845433229954,
+                5498143562700363701,
+                3814505891790267676,
+                1321840624545695718,
+            ]),
+            Fq::from_montgomery_limbs([
+                12363046526883363527,
+                15507207959004475087,
+                12250841796820316586,
+                486252329752231860,
+            ]),
+            Fq::from_montgomery_limbs([
+                15877878112580301418,
+                4109651771473457722,
+                3303492269317715575,
+                293706834652252476,
+            ]),
+            Fq::from_montgomery_limbs([
+                16830677894951778370,
+                1230222398571433180,
+                15851252194851748756,
+                987818256421020848,
+            ]),
+            Fq::from_montgomery_limbs([
+                6741692981707131145,
+                14112698735249445588,
+                2551588788534321380,
+                394267348532361382,
+            ]),
+            Fq::from_montgomery_limbs([
+                8702421201972816875,
+                17278365514681645460,
+                3495543709723318219,
+                1014605187006771967,
+            ]),
+            Fq::from_montgomery_limbs([
+                581118779092768220,
+                17088226506636956310,
+                9606783770342189363,
+                1184173810063137509,
+            ]),
+            Fq::from_montgomery_limbs([
+                16261686719131684602,
+                15100718139628462442,
+                856944934698216675,
+                108547572288900472,
+            ]),
+            Fq::from_montgomery_limbs([
+                10634243042613731373,
+                8764003198328889144,
+                12127908702996994820,
+                132134247006823632,
+            ]),
+            Fq::from_montgomery_limbs([
+                13711299138719598060,
+                8945286207701653096,
+                4840027027301997423,
+                29168844136078445,
+            ]),
+            Fq::from_montgomery_limbs([
+                9351874929887397576,
+                10013885821047836020,
+                12382463627237648643,
+                1199721673261094152,
+            ]),
+            Fq::from_montgomery_limbs([
+                14905550264244516622,
+                4030332713049370279,
+                7589779729985061483,
+                527316465719246196,
+            ]),
+            Fq::from_montgomery_limbs([
+                8118676904000675813,
+                483123489332522554,
+                9286767660091044701,
+                716178519768770482,
+            ]),
+            Fq::from_montgomery_limbs([
+                11744871075751016606,
+                7936682910482058550,
+                164314493471193672,
+                467156593681510777,
+            ]),
+            Fq::from_montgomery_limbs([
+                13282766127087053283,
+                5344290460029466732,
+                1522763300843620195,
+                262298051764301965,
+            ]),
+            Fq::from_montgomery_limbs([
+                7849884780392833186,
+                1895791023140499993,
+                12027715597314105368,
+                1284270812282425205,
+            ]),
+            Fq::from_montgomery_limbs([
+                11130592825590435539,
+                15695290109115428945,
+                16519514877349463906,
+                101241673297807571,
+            ]),
+            Fq::from_montgomery_limbs([
+                13540730952712849625,
+                15705377705952730963,
+                15287496513852055808,
+                532512919959084920,
+            ]),
+            Fq::from_montgomery_limbs([
+                8628551502483837507,
+                2693015845011165754,
+                5433293683136735373,
+                475718465616008780,
+            ]),
+            Fq::from_montgomery_limbs([
+                37396984969592985,
+                5635817387237406291,
+                9790058676552749724,
+                1014753304344826206,
+            ]),
+            Fq::from_montgomery_limbs([
+                12124334413796319698,
+                288769670996725463,
+                7144631735310004599,
+                743377539806105590,
+            ]),
+            Fq::from_montgomery_limbs([
+                160203697485457536,
+                17701093523329823504,
+                17155626840564585449,
+                719604998934602612,
+            ]),
+            Fq::from_montgomery_limbs([
+                4389299788133745626,
+                17395248540228219606,
+                10493586562837645826,
+                512583043161084140,
+            ]),
+            Fq::from_montgomery_limbs([
+                5852158182757689713,
+                167477092343148358,
+                15431792216639433664,
+                854372732030055063,
+            ]),
+            Fq::from_montgomery_limbs([
+                14693201610666958695,
+                9252703688328825156,
+                17913039741882981898,
+                1022424926666088379,
+            ]),
+            Fq::from_montgomery_limbs([
+                17694411466976579750,
+                13573295432702956464,
+                3105887247276515006,
+                1039220265199306250,
+            ]),
+            Fq::from_montgomery_limbs([
+                8283221162208710445,
+                1025872683347888500,
+                5314313343090598584,
+                1325088335745526334,
+            ]),
+            Fq::from_montgomery_limbs([
+                9580179834913965129,
+                10173259919073154743,
+                13971854516057594320,
+                1190085327704019981,
+            ]),
+            Fq::from_montgomery_limbs([
+                17664807404636212181,
+                17754610864731024616,
+                17730645745466062766,
+                1074301675622044637,
+            ]),
+            Fq::from_montgomery_limbs([
+                13939045559739783969,
+                5270525923411606907,
+                10487581307681407484,
+                553344833039299765,
+            ]),
+            Fq::from_montgomery_limbs([
+                1124978411701313626,
+                11874524858168629292,
+                1533948607916111265,
+                1205938633280133704,
+            ]),
+            Fq::from_montgomery_limbs([
+                14680661886130755709,
+                12922246570292940665,
+                2212705634590884967,
+                1073039203471233354,
+            ]),
+            Fq::from_montgomery_limbs([
+                2255008945962441549,
+                1105051928904938541,
+                1963834329501885090,
+                652835379520961357,
+            ]),
+            Fq::from_montgomery_limbs([
+                14359186085595698899,
+                9753149889560256802,
+                362782298327918179,
+                1290739622700201911,
+            ]),
+            Fq::from_montgomery_limbs([
+                14807678138501417265,
+                6172436976639119235,
+                3906109276463111459,
+                685786108423214803,
+            ]),
+            Fq::from_montgomery_limbs([
+                3307102442295601868,
+                4527946136967343272,
+                16694623494477906593,
+                720715042259285706,
+            ]),
+            Fq::from_montgomery_limbs([
+                6498893868748031777,
+                8221053618293076640,
+                7107393664149109696,
+                1318469929619731098,
+            ]),
+            Fq::from_montgomery_limbs([
+                8079349498515246399,
+                18102293299272609733,
+                36325418344587017,
+                351080586143435454,
+            ]),
+            Fq::from_montgomery_limbs([
+                18234959629061554236,
+                8677024088575078764,
+                18193379501791444872,
+                504174195540887244,
+            ]),
+            Fq::from_montgomery_limbs([
+                17800296938395418710,
+                3385742313497292242,
+                17576637597531278208,
+                71831718876373861,
+            ]),
+            Fq::from_montgomery_limbs([
+                17368520003341944198,
+                14806638083407569978,
+                18082158021264405728,
+                105017156680829060,
+            ]),
+            Fq::from_montgomery_limbs([
+                4659211982764313642,
+                17570082658393698110,
+                11235499384111843081,
+                1156509388729175769,
+            ]),
+            Fq::from_montgomery_limbs([
+                7992053870104287760,
+                5412653927569678982,
+                8013837977694941700,
+                218651019244428001,
+            ]),
+            Fq::from_montgomery_limbs([
+                10108234629296271700,
+                10970806382378334427,
+                7864601526838625612,
+                1029120560407398205,
+            ]),
+            Fq::from_montgomery_limbs([
+                7060399399247908440,
+                12494840498406326497,
+                2407744693520981079,
+                53532477600051353,
+            ]),
+            Fq::from_montgomery_limbs([
+                14117762572658696505,
+                18431147793833925081,
+                13325087245243470581,
+                1137795094932997372,
+            ]),
+            Fq::from_montgomery_limbs([
+                2104120105728737193,
+                8120673162612199357,
+                9812590319307292952,
+                176049896232135118,
+            ]),
+            Fq::from_montgomery_limbs([
+                8684307276187609774,
+                15896756273955179322,
+                10231576514619765908,
+                53531931719391976,
+            ]),
+            Fq::from_montgomery_limbs([
+                519234107205674880,
+                4197878264613617426,
+                8985012881146884494,
+                473878536788082939,
+            ]),
+            Fq::from_montgomery_limbs([
+                15167723026021987903,
+                8155348320897610824,
+                8807483441905252786,
+                404784605778584756,
+            ]),
+            Fq::from_montgomery_limbs([
+                13091454858932951376,
+                13238085145284997438,
+                14973504155776650165,
+                1295783491307021744,
+            ]),
+            Fq::from_montgomery_limbs([
+                1473942327113067512,
+                4108364720800331518,
+                7932452304299065045,
+                462562072877673172,
+            ]),
+            Fq::from_montgomery_limbs([
+                11632460009165927449,
+                13811770098609790292,
+                10813046751231568355,
+                69719818370858520,
+            ]),
+            Fq::from_montgomery_limbs([
+                8069483981256642486,
+                9679399740097273913,
+                8705093337042679385,
+                882586730735918943,
+            ]),
+            Fq::from_montgomery_limbs([
+                7132680396767372311,
+                10013237338608933654,
+                2381372522100382244,
+                62414237517594695,
+            ]),
+            Fq::from_montgomery_limbs([
+                12482212540900023563,
+                15072947163807562473,
+                12772829405662883694,
+                640391484454534896,
+            ]),
+            Fq::from_montgomery_limbs([
+                17616381818327035677,
+                2064197582531133239,
+                15151640522570219520,
+                1031281059194266864,
+            ]),
+            Fq::from_montgomery_limbs([
+                9005219080496169396,
+                2338705354521040374,
+                11098972134701472411,
+                706960044806662240,
+            ]),
+            Fq::from_montgomery_limbs([
+                12727308851159226943,
+                11267555039162024834,
+                17457049122150314651,
+                1229686610794881018,
+            ]),
+            Fq::from_montgomery_limbs([
+                16604447341031333627,
+                7369882512345782158,
+                1451670407132815375,
+                1127667489576747205,
+            ]),
+            Fq::from_montgomery_limbs([
+                7333072863777071386,
+                8343947490052658482,
+                7044863645184958198,
+                1282014175508030039,
+            ]),
+            Fq::from_montgomery_limbs([
+                6023112603126524141,
+                12917670260925139637,
+                8314717794631184298,
+                7440759001567204,
+            ]),
+            Fq::from_montgomery_limbs([
+                17877314827106218211,
+                14061122308376003312,
+                9142179285368200433,
+                668025854565182984,
+            ]),
+            Fq::from_montgomery_limbs([
+                17009399946270939891,
+                8295924978001683501,
+                16488577901657036206,
+                711469759915152667,
+            ]),
+            Fq::from_montgomery_limbs([
+                4191732817425799430,
+                16968489624267010935,
+                10310580065353731616,
+                352114592289639689,
+            ]),
+            Fq::from_montgomery_limbs([
+                14160064816635709774,
+                327732701500158329,
+                7575093078436707293,
+                1336190391902436443,
+            ]),
+            Fq::from_montgomery_limbs([
+                13609295826303643701,
+                18296093276280971161,
+                16972862083620157498,
+                1283580997928705193,
+            ]),
+            Fq::from_montgomery_limbs([
+                1133398159296700262,
+                18235650879379984545,
+                1972162912899661098,
+                796392066927034777,
+            ]),
+            Fq::from_montgomery_limbs([
+                201295154190782966,
+                16488865098027107848,
+                18430753157973870310,
+                562701230384618778,
+            ]),
+            Fq::from_montgomery_limbs([
+                7233870934485947964,
+                12454410534400809663,
+                2360854510226025093,
+                451267336156117353,
+            ]),
+            Fq::from_montgomery_limbs([
+                7053100916292814910,
+                16995194392856366345,
+                15710194585797266144,
+                1077831434056267303,
+            ]),
+            Fq::from_montgomery_limbs([
+                14570270652017322372,
+                8173275297670823797,
+                4331041802034125162,
+                491451815452404829,
+            ]),
+            Fq::from_montgomery_limbs([
+                5184508629655596738,
+                5451604583034345330,
+                10859492102500354022,
+                46826086661440513,
+            ]),
+            Fq::from_montgomery_limbs([
+                804604613645279192,
+                15887697604126037906,
+                7326908713267865134,
+                1120403889173414759,
+            ]),
+            Fq::from_montgomery_limbs([
+                7554266023542674035,
+                12753449899522482127,
+                7618805833003725046,
+                1006993592051917603,
+            ]),
+            Fq::from_montgomery_limbs([
+                12370425933367508613,
+                9086730441859861030,
+                3432509267659554817,
+                421760959842946123,
+            ]),
+            Fq::from_montgomery_limbs([
+                10270347950554241502,
+                17494337237060684967,
+                2538752754071650722,
+                282175892369187384,
+            ]),
+            Fq::from_montgomery_limbs([
+                18145060657749904963,
+                660074573498936401,
+                15745527617248181768,
+                302727141361913497,
+            ]),
+            Fq::from_montgomery_limbs([
+                2760859183269108192,
+                15667949797206779578,
+                2085218585492414351,
+                304079182438145637,
+            ]),
+            Fq::from_montgomery_limbs([
+                16552673550041851001,
+                7900740991017936920,
+                16480063590554757210,
+                289337694137577137,
+            ]),
+            Fq::from_montgomery_limbs([
+                7538658111138457558,
+                12579458371771017073,
+                3911296620902826120,
+                282461971645278028,
+            ]),
+            Fq::from_montgomery_limbs([
+                8176149363153184868,
+                6803201907669410493,
+                4913690752674376989,
+                1015500465659807731,
+            ]),
+            Fq::from_montgomery_limbs([
+                8129209352624216461,
+                15081173681506669084,
+                7636404774150183920,
+                535688938472200305,
+            ]),
+            Fq::from_montgomery_limbs([
+                7487951211382442387,
+                4622260551302096124,
+                14520680971947127655,
+                1126733355891040498,
+            ]),
+            Fq::from_montgomery_limbs([
+                3756145143010411490,
+                4365536648354616999,
+                3127375075150461981,
+                587499023329866956,
+            ]),
+            Fq::from_montgomery_limbs([
+                8868793051864977218,
+                8680715045310169772,
+                2341336256757599462,
+                588841996308127209,
+            ]),
+            Fq::from_montgomery_limbs([
+                8116877464649786617,
+                6830739947776215405,
+                1449256113526991934,
+                1266416978259827143,
+            ]),
+            Fq::from_montgomery_limbs([
+                1277462881463758364,
+                8549782318000752761,
+                8688840867735661680,
+                696807265539777760,
+            ]),
+            Fq::from_montgomery_limbs([
+                8437585635370150926,
+                15398601965999028448,
+                3446280859409807108,
+                59412885330781726,
+            ]),
+            Fq::from_montgomery_limbs([
+                16135712872516408008,
+                8707770465087196385,
+                7025362074275217672,
+                153257558479369351,
+            ]),
+            Fq::from_montgomery_limbs([
+                2640299040067237132,
+                5613542611702652357,
+                619904877199226037,
+                994702754525440351,
+            ]),
+            Fq::from_montgomery_limbs([
+                13531896270797700218,
+                5606471453053173305,
+                17280405331072053302,
+                520809703122591785,
+            ]),
+            Fq::from_montgomery_limbs([
+                8302574234559846079,
+                470711732995997241,
+                17755190101254399404,
+                26607076907601148,
+            ]),
+            Fq::from_montgomery_limbs([
+                13210311710849175524,
+                10435756173559843305,
+                6610910139977763211,
+                643669004850881883,
+            ]),
+            Fq::from_montgomery_limbs([
+                14001952185091645852,
+                11422767342390842590,
+                3608570620833037343,
+                24883788531901847,
+            ]),
+            Fq::from_montgomery_limbs([
+                2183296579795573383,
+                13678910697215983924,
+                6983705601929405464,
+                677370913141398261,
+            ]),
+            Fq::from_montgomery_limbs([
+                5506051355105824274,
+                16930258135554130943,
+                12364969789183411387,
+                1200379534844591316,
+            ]),
+            Fq::from_montgomery_limbs([
+                17699726171294463018,
+                17278706745866104523,
+                8163279265007615344,
+                1206906333166424420,
+            ]),
+            Fq::from_montgomery_limbs([
+                4500020908871594273,
+                8684379743236390358,
+                626540388371395497,
+                749458076247051622,
+            ]),
+            Fq::from_montgomery_limbs([
+                5347427641129794787,
+                16278255656202551457,
+                16005179570419037625,
+                1335817559249663305,
+            ]),
+            Fq::from_montgomery_limbs([
+                9399571288986390235,
+                10329364595152281613,
+                6136188414149764982,
+                616922513223232706,
+            ]),
+            Fq::from_montgomery_limbs([
+                10780976468439016865,
+                9078104984357458101,
+                13637203514199520967,
+                777451113912141407,
+            ]),
+            Fq::from_montgomery_limbs([
+                7978832419074628881,
+                1849843598726456494,
+                14523115303110689229,
+                874081649113988342,
+            ]),
+            Fq::from_montgomery_limbs([
+                6842779394398633297,
+                2558586625448481099,
+                14202813902850351664,
+                67216276689799187,
+            ]),
+            Fq::from_montgomery_limbs([
+                10223803683716575013,
+                17259264019798023290,
+                3649179214769194122,
+                851350606612752841,
+            ]),
+            Fq::from_montgomery_limbs([
+                13522308760607396196,
+                11246118405884063995,
+                11105948229138541959,
+                298629387113566205,
+            ]),
+            Fq::from_montgomery_limbs([
+                16293320040883580234,
+                10684492464991793768,
+                6468099663931979538,
+                688864614756469046,
+            ]),
+            Fq::from_montgomery_limbs([
+                7532732199462157337,
+                347216398181708706,
+                6367215484346906059,
+                421804406327266551,
+            ]),
+            Fq::from_montgomery_limbs([
+                8809244602734790053,
+                4583095450993953702,
+                7768430344121267265,
+                974315346723178320,
+            ]),
+            Fq::from_montgomery_limbs([
+                17875039081610949832,
+                9513423782924040396,
+                6896742248267248095,
+                965525138254219815,
+            ]),
+            Fq::from_montgomery_limbs([
+                7507224294046148918,
+                17064688230248618820,
+                16737180922954015122,
+                315693928749451863,
+            ]),
+            Fq::from_montgomery_limbs([
+                9699291829999749675,
+                10783567614746719536,
+                8887578540278978249,
+                1023654178170023249,
+            ]),
+            Fq::from_montgomery_limbs([
+                17112778878336938654,
+                11634665404467980709,
+                14303328140155096768,
+                214788900305000554,
+            ]),
+            Fq::from_montgomery_limbs([
+                11398422473704201163,
+                3023190341658911670,
+                3222183374128596037,
+                293221566352578847,
+            ]),
+            Fq::from_montgomery_limbs([
+                8843793355096407549,
+                5778838293776546882,
+                8210921419795923700,
+                584378917566175885,
+            ]),
+            Fq::from_montgomery_limbs([
+                1689887927131560766,
+                10211717995592636653,
+                15429008763288522143,
+                377531132580184173,
+            ]),
+            Fq::from_montgomery_limbs([
+                12198564136873138753,
+                11650228716536558520,
+                17419710487263651197,
+                305458329379614512,
+            ]),
+            Fq::from_montgomery_limbs([
+                16837991064378529388,
+                15092676703916322042,
+                1181757486188686748,
+                781758075903784236,
+            ]),
+            Fq::from_montgomery_limbs([
+                10668245913673290473,
+                12376853433784332744,
+                473557974766743014,
+                248037508482813803,
+            ]),
+            Fq::from_montgomery_limbs([
+                2912766069283741585,
+                1517682773194870993,
+                9982420768685080163,
+                293543134873239575,
+            ]),
+            Fq::from_montgomery_limbs([
+                5061509235164822160,
+                10355777757177453930,
+                1523686378023464199,
+                1034472500151030800,
+            ]),
+            Fq::from_montgomery_limbs([
+                14405639321112671328,
+                17606598430878955714,
+                11062163119437718372,
+                747415474247739100,
+            ]),
+            Fq::from_montgomery_limbs([
+                6406595823596261,
+                5936724667348214780,
+                11202659589316026517,
+                1265774246517875173,
+            ]),
+            Fq::from_montgomery_limbs([
+                3460463203543968051,
+                17359592988388544474,
+                4314349051624274243,
+                519729460154279654,
+            ]),
+            Fq::from_montgomery_limbs([
+                10008399986978872438,
+                6284078016520877737,
+                17823131280685644709,
+                374401271292851509,
+            ]),
+            Fq::from_montgomery_limbs([
+                7089870672075067686,
+                8107289564402888961,
+                3539022247709082033,
+                882447030935528075,
+            ]),
+            Fq::from_montgomery_limbs([
+                16783031293757989445,
+                4218625643100380039,
+                12176875638873211099,
+                901703622450590899,
+            ]),
+            Fq::from_montgomery_limbs([
+                3235666791042236959,
+                11277960931562139692,
+                16619021028044453282,
+                1208113125162498504,
+            ]),
+            Fq::from_montgomery_limbs([
+                8809203517808697910,
+                12380281985058327041,
+                4705835134798979804,
+                1113615098578148849,
+            ]),
+            Fq::from_montgomery_limbs([
+                4100684117037485164,
+                10373126721574748248,
+                14554924094114046195,
+                321051565025508654,
+            ]),
+            Fq::from_montgomery_limbs([
+                4000629075267008244,
+                11054449688849742564,
+                17781366022048752850,
+                374653357051151771,
+            ]),
+            Fq::from_montgomery_limbs([
+                7461470195404392024,
+                12002473607787070210,
+                1803150974077977050,
+                531677510528368637,
+            ]),
+            Fq::from_montgomery_limbs([
+                17222517919657496525,
+                14232712723484130884,
+                17584692085487870080,
+                780664337824790947,
+            ]),
+            Fq::from_montgomery_limbs([
+                1991589934747350962,
+                2822291748397634460,
+                10167206443084068980,
+                103634758013372610,
+            ]),
+            Fq::from_montgomery_limbs([
+                17161570494005799397,
+                13353160478789031194,
+                15070369515815943388,
+                850673975322594223,
+            ]),
+            Fq::from_montgomery_limbs([
+                3551081561357581166,
+                1660807395176131913,
+                9473627966139517217,
+                1212412883393310704,
+            ]),
+            Fq::from_montgomery_limbs([
+                15002102109327860187,
+                10105709656053815886,
+                10089470319498739403,
+                1261286766406770046,
+            ]),
+            Fq::from_montgomery_limbs([
+                10463253043481700318,
+                10561044778004008528,
+                8847983451072244024,
+                797289588465359025,
+            ]),
+            Fq::from_montgomery_limbs([
+                795604122827269318,
+                10351730643442978714,
+                13957143017618830167,
+                13410293917020751,
+            ]),
+            Fq::from_montgomery_limbs([
+                11046710175686470022,
+                1351164400866857089,
+                14071343235054578242,
+                1080146201126166585,
+            ]),
+            Fq::from_montgomery_limbs([
+                106368099065214455,
+                10122791807640882652,
+                2591614942559862421,
+                657123279584501299,
+            ]),
+            Fq::from_montgomery_limbs([
+                6127122301463398435,
+                7160819764227710682,
+                14265727372827696414,
+                166149815324114864,
+            ]),
+            Fq::from_montgomery_limbs([
+                8815851919000941337,
+                11310114667644859487,
+                7961497395543220833,
+                446968427655092979,
+            ]),
+            Fq::from_montgomery_limbs([
+                7726039306714367432,
+                14773601675554228005,
+                1529973615153110007,
+                1043467981737623492,
+            ]),
+            Fq::from_montgomery_limbs([
+                18387525379669945350,
+                8392501886368318901,
+                11422828455589911676,
+                50826074546057500,
+            ]),
+            Fq::from_montgomery_limbs([
+                15177547375310384447,
+                7828425962856400467,
+                3912779065840950853,
+                54552351480702221,
+            ]),
+            Fq::from_montgomery_limbs([
+                17082354395526064454,
+                5346462920798236794,
+                2259840341409069664,
+                104680245605105607,
+            ]),
+            Fq::from_montgomery_limbs([
+                4537268666285521845,
+                1396453481488960028,
+                8014019542949604834,
+                1116948216206349476,
+            ]),
+            Fq::from_montgomery_limbs([
+                5612668065850570819,
+                15969935153308574989,
+                524940779077010790,
+                1207662986350115540,
+            ]),
+            Fq::from_montgomery_limbs([
+                8740536936339182969,
+                1525381636952698921,
+                12357544712387103945,
+                1065324240201190819,
+            ]),
+            Fq::from_montgomery_limbs([
+                3189448207036404725,
+                17503166345529915305,
+                2009630314023202955,
+                993846417292415881,
+            ]),
+            Fq::from_montgomery_limbs([
+                8276087126725628652,
+                7917931242458105914,
+                10614052677860951587,
+                563770019380389099,
+            ]),
+            Fq::from_montgomery_limbs([
+                16396176445000923826,
+                14365274688488890641,
+                3060712866820818139,
+                296203710445695137,
+            ]),
+            Fq::from_montgomery_limbs([
+                7732704650471153796,
+                5105781472306931143,
+                1829729449866745781,
+                1244129828116395436,
+            ]),
+            Fq::from_montgomery_limbs([
+                10777510278129324540,
+                13262361500329406840,
+                7937049342712108839,
+                1329398833244705552,
+            ]),
+            Fq::from_montgomery_limbs([
+                8307666399884414419,
+                9714654889941743148,
+                6875522279810834256,
+                346845708147554625,
+            ]),
+            Fq::from_montgomery_limbs([
+                4180352408874314132,
+                16999434502941725041,
+                16413729442563326750,
+                956423437998288977,
+            ]),
+            Fq::from_montgomery_limbs([
+                7817450195798515219,
+                15558368962173616240,
+                61517347839900380,
+                661725198145188895,
+            ]),
+            Fq::from_montgomery_limbs([
+                12078422588979733903,
+                14302632337127349086,
+                10244160163258480474,
+                640902575745936000,
+            ]),
+            Fq::from_montgomery_limbs([
+                6886813474729619544,
+                10300151854441834143,
+                5111107072675733879,
+                698951659409458959,
+            ]),
+            Fq::from_montgomery_limbs([
+                17128743361528782865,
+                14351298726400246743,
+                12781651848561693119,
+                186429171960266227,
+            ]),
+            Fq::from_montgomery_limbs([
+                7459981173635434159,
+                10910010979805708129,
+                14112179791652436382,
+                337983360753907871,
+            ]),
+            Fq::from_montgomery_limbs([
+                2844228076344708956,
+                15111019550356102824,
+                7560009590560658979,
+                327995213227589921,
+            ]),
+            Fq::from_montgomery_limbs([
+                10336980571209700701,
+                7266447010694868636,
+                9691017672119551483,
+                1132519806874309439,
+            ]),
+            Fq::from_montgomery_limbs([
+                6193431823716779090,
+                9599508217508658918,
+                7031792805964470375,
+                1011015810112518139,
+            ]),
+            Fq::from_montgomery_limbs([
+                1081093818343289309,
+                4340491543965134719,
+                10668444170354481265,
+                1327979761490730417,
+            ]),
+            Fq::from_montgomery_limbs([
+                11157761386086007919,
+                458646165709463679,
+                3074318339390295246,
+                809648444606678088,
+            ]),
+            Fq::from_montgomery_limbs([
+                2518368006775002294,
+                5680063771253213800,
+                8798593961128329909,
+                1280305937882066845,
+            ]),
+            Fq::from_montgomery_limbs([
+                1287158019256320924,
+                17043883512765765103,
+                3598058698614620498,
+                206291638476201017,
+            ]),
+            Fq::from_montgomery_limbs([
+                13791485484643479925,
+                9287748656501109295,
+                5325584328452848562,
+                845725575051549379,
+            ]),
+            Fq::from_montgomery_limbs([
+                14185812062753535194,
+                1760060745624745951,
+                5983504281437373025,
+                128367777289483405,
+            ]),
+            Fq::from_montgomery_limbs([
+                15273456195843347198,
+                13820853568309483652,
+                15896361451859960949,
+                745053083954116270,
+            ]),
+            Fq::from_montgomery_limbs([
+                5889594103011686306,
+                8299397164717148668,
+                5089542778299398874,
+                438709229526072023,
+            ]),
+            Fq::from_montgomery_limbs([
+                15331364870608983287,
+                14867873397071681539,
+                2334679657814034048,
+                1314301969834441928,
+            ]),
+            Fq::from_montgomery_limbs([
+                11436554313190868444,
+                7908504007410283275,
+                12565639378073977305,
+                30484863689195625,
+            ]),
+            Fq::from_montgomery_limbs([
+                18104195439636998456,
+                10000006509873139266,
+                2491255664261666188,
+                20802197971431117,
+            ]),
+            Fq::from_montgomery_limbs([
+                15658103572132624779,
+                7706080763560683022,
+                2143353190938039996,
+                730711187714181284,
+            ]),
+            Fq::from_montgomery_limbs([
+                6180712950928806263,
+                17781464519197005892,
+                13879836102560375538,
+                1235977642572792953,
+            ]),
+            Fq::from_montgomery_limbs([
+                10968115061186059256,
+                6465548360599617215,
+                5255464529314084980,
+                993538849285403932,
+            ]),
+            Fq::from_montgomery_limbs([
+                6583638359047256351,
+                2773916693137433710,
+                11818797126156488283,
+                286266285348438740,
+            ]),
+            Fq::from_montgomery_limbs([
+                2860951283386657632,
+                14776153814748447353,
+                11524664602950716937,
+                812688368939636962,
+            ]),
+            Fq::from_montgomery_limbs([
+                6031396389658674950,
+                13580054905819429122,
+                16175387703145352250,
+                1244647562686554730,
+            ]),
+            Fq::from_montgomery_limbs([
+                15185586415665933187,
+                8962355847294938229,
+                9269081966752868426,
+                817239351757261392,
+            ]),
+            Fq::from_montgomery_limbs([
+                5980332397023466497,
+                6404963539154654674,
+                817144664282008521,
+                471585469540545332,
+            ]),
+            Fq::from_montgomery_limbs([
+                15476809523207331806,
+                15456869280088255344,
+                5200906215718982437,
+                93088011294382097,
+            ]),
+            Fq::from_montgomery_limbs([
+                4630234718727781015,
+                9145349250288628104,
+                16018704378657718561,
+                55259062140323482,
+            ]),
+            Fq::from_montgomery_limbs([
+                18087110186754138661,
+                13605202208131714431,
+                9164580452962101740,
+                1243550330943548605,
+            ]),
+            Fq::from_montgomery_limbs([
+                16681617634100421974,
+                1581435204778740493,
+                15932087475513127651,
+                85636354605537346,
+            ]),
+            Fq::from_montgomery_limbs([
+                3731598431703878959,
+                6208305492077962263,
+                9372868126679184978,
+                965117454781510765,
+            ]),
+            Fq::from_montgomery_limbs([
+                8001690940614880832,
+                18281586682424063321,
+                10793536001474473835,
+                942799872847436074,
+            ]),
+            Fq::from_montgomery_limbs([
+                11064069491846175,
+                18416060324051406706,
+                780795159932826502,
+                871111002193361975,
+            ]),
+            Fq::from_montgomery_limbs([
+                2894784706550094751,
+                6417717342437730903,
+                3283421290376860667,
+                374083898984144640,
+            ]),
+            Fq::from_montgomery_limbs([
+                12733512771405380688,
+                14188928547289462706,
+                10545087899832980467,
+                387954383923566880,
+            ]),
+            Fq::from_montgomery_limbs([
+                18222722316157313059,
+                2948508908960885736,
+                11470754166598859874,
+                112741699048300695,
+            ]),
+            Fq::from_montgomery_limbs([
+                9635354934665052693,
+                4746550460563796979,
+                1049456173766701973,
+                168077750756581112,
+            ]),
+            Fq::from_montgomery_limbs([
+                16509700040156086164,
+                6398911093365199462,
+                4088659878370183559,
+                1076029972581727574,
+            ]),
+            Fq::from_montgomery_limbs([
+                9211133445350945306,
+                4263447622298348318,
+                3926830951895207666,
+                258877253176753381,
+            ]),
+            Fq::from_montgomery_limbs([
+                6675198855495456444,
+                6349117565316769960,
+                3246497304794878541,
+                1212980267530264395,
+            ]),
+            Fq::from_montgomery_limbs([
+                3218919006770683237,
+                15411056000890997569,
+                15751576296919972179,
+                780761027962008689,
+            ]),
+            Fq::from_montgomery_limbs([
+                15702570171484060919,
+                5194469456811220349,
+                11284208220348947867,
+                483160419124859752,
+            ]),
+            Fq::from_montgomery_limbs([
+                6329989731760332075,
+                10744546504069877784,
+                17140531937890223322,
+                370155594806655786,
+            ]),
+            Fq::from_montgomery_limbs([
+                17929503608220064918,
+                12609469509941716559,
+                5891844231414459801,
+                816002812367359134,
+            ]),
+            Fq::from_montgomery_limbs([
+                1616408002092236387,
+                8227439932332327593,
+                5224069338349934214,
+                35834388527794852,
+            ]),
+            Fq::from_montgomery_limbs([
+                9223042875478350969,
+                16922766913964278015,
+                2552037991757243805,
+                493768258966449837,
+            ]),
+            Fq::from_montgomery_limbs([
+                16084247615458851089,
+                7513239753610503359,
+                3899960917287740318,
+                478070160866507849,
+            ]),
+            Fq::from_montgomery_limbs([
+                8276499730400367060,
+                3421137339274262625,
+                2025371444911540291,
+                327483393071560391,
+            ]),
+            Fq::from_montgomery_limbs([
+                11595624606083969139,
+                14049012556628300,
+                14500828712924357992,
+                1312673803336180632,
+            ]),
+            Fq::from_montgomery_limbs([
+                7857651035521439062,
+                9938101414876083319,
+                1314093975573260624,
+                184272952038345448,
+            ]),
+            Fq::from_montgomery_limbs([
+                685407128837885372,
+                14852653166083067544,
+                4622718646285780739,
+                765604347493641660,
+            ]),
+            Fq::from_montgomery_limbs([
+                4880433825505259826,
+                13844165267487487799,
+                7736555778731472902,
+                731190699855408174,
+            ]),
+            Fq::from_montgomery_limbs([
+                16408775688444434705,
+                1149226771888229458,
+                11716981939565493015,
+                179809862083368846,
+            ]),
+            Fq::from_montgomery_limbs([
+                9251896763186464346,
+                4380450085114029725,
+                5984212348262768937,
+                1018178443537122109,
+            ]),
+            Fq::from_montgomery_limbs([
+                1970099491868337164,
+                862747147795718402,
+                17446585868012723466,
+                1154148612109292006,
+            ]),
+            Fq::from_montgomery_limbs([
+                12021209421309764085,
+                11769271418298724856,
+                16246860836432767084,
+                96178281741896269,
+            ]),
+            Fq::from_montgomery_limbs([
+                13927296271230887261,
+                4135783210755841732,
+                3275035731212956419,
+                423871120016746327,
+            ]),
+            Fq::from_montgomery_limbs([
+                3653994728387516080,
+                12450499716983833927,
+                9238543922332521170,
+                761450729676611023,
+            ]),
+            Fq::from_montgomery_limbs([
+                7626967700870752471,
+                13075595535249919836,
+                15604472730785905398,
+                567735524160426817,
+            ]),
+            Fq::from_montgomery_limbs([
+                17096058542950387617,
+                13775587519673063987,
+                8708173720978829009,
+                361847844959498968,
+            ]),
+            Fq::from_montgomery_limbs([
+                13724192651060181129,
+                10143620334877955620,
+                1431495938867372537,
+                404772325916774099,
+            ]),
+            Fq::from_montgomery_limbs([
+                2450887102250583379,
+                6952958347693109038,
+                11789591595907816705,
+                962893104240425376,
+            ]),
+            Fq::from_montgomery_limbs([
+                10005081481587421161,
+                17397819884570212620,
+                7169251844211066737,
+                285556415145358032,
+            ]),
+            Fq::from_montgomery_limbs([
+                9348559862927564964,
+                16251278120781047038,
+                267564161992972137,
+                663768290663442326,
+            ]),
+            Fq::from_montgomery_limbs([
+                2730354333937123010,
+                1046865265352523226,
+                7046408381421913543,
+                1065271807331845118,
+            ]),
+            Fq::from_montgomery_limbs([
+                9553107931335316040,
+                14079926010365712472,
+                2405895159465606834,
+                360063943474103527,
+            ]),
+            Fq::from_montgomery_limbs([
+                2281615224620622698,
+                2977048628609039126,
+                17420949578611528220,
+                948002768334786406,
+            ]),
+            Fq::from_montgomery_limbs([
+                18069501829872692513,
+                3246256635768925038,
+                17269302086171270487,
+                452394831007475230,
+            ]),
+            Fq::from_montgomery_limbs([
+                12074536992447110758,
+                2447506465113731924,
+                319132297814797705,
+                163546009685349337,
+            ]),
+            Fq::from_montgomery_limbs([
+                7156875787355066185,
+                8081270775333567784,
+                5757269585998635266,
+                799314536066633875,
+            ]),
+            Fq::from_montgomery_limbs([
+                18405674337999536344,
+                1528157061586332580,
+                14141391068974079724,
+                974604915319163695,
+            ]),
+            Fq::from_montgomery_limbs([
+                9934112189878716424,
+                7753150528087042065,
+                5693530386749779224,
+                217686160832795888,
+            ]),
+            Fq::from_montgomery_limbs([
+                7618452983983645404,
+                2072579964019493326,
+                8620396017285578703,
+                899834619765848326,
+            ]),
+            Fq::from_montgomery_limbs([
+                16591951799067748917,
+                14466704546902120115,
+                14618101895994803992,
+                648215956839479480,
+            ]),
+            Fq::from_montgomery_limbs([
+                15514057861544217927,
+                14958768749717493881,
+                9147077720993326034,
+                835182066348783064,
+            ]),
+            Fq::from_montgomery_limbs([
+                12811595159329085217,
+                10990524215107728438,
+                3139006570155959704,
+                1021685293801326368,
+            ]),
+            Fq::from_montgomery_limbs([
+                10588659376198420079,
+                12902368598267708240,
+                7045974860477132959,
+                191793862466086549,
+            ]),
+            Fq::from_montgomery_limbs([
+                2811750045966911037,
+                10263285439309455828,
+                14582998656698566322,
+                610953325077427973,
+            ]),
+            Fq::from_montgomery_limbs([
+                8673963112858800389,
+                17257330948943077866,
+                18223832217965689980,
+                73318314512582502,
+            ]),
+            Fq::from_montgomery_limbs([
+                13045338540960874739,
+                3921034923420484489,
+                16067782266247029061,
+                1213163407144017462,
+            ]),
+            Fq::from_montgomery_limbs([
+                2882209305770026507,
+                16932550202688991740,
+                17469676752109849555,
+                468399487665949972,
+            ]),
+            Fq::from_montgomery_limbs([
+                973312502121163067,
+                12317247437037787471,
+                2173482883506480148,
+                627794189129942826,
+            ]),
+            Fq::from_montgomery_limbs([
+                16384469573564775231,
+                16970481015772470449,
+                2418588989096504973,
+                129389264059798726,
+            ]),
+            Fq::from_montgomery_limbs([
+                14024285833459392204,
+                114426590359486356,
+                8511933495717349479,
+                559741643759912352,
+            ]),
+            Fq::from_montgomery_limbs([
+                6510103935856218998,
+                5459022545755008288,
+                16502493482972040501,
+                169589023791775598,
+            ]),
+            Fq::from_montgomery_limbs([
+                2978322613836872298,
+                9524771417325627886,
+                11700221073982862156,
+                116131908488331420,
+            ]),
+            Fq::from_montgomery_limbs([
+                10281646735591496536,
+                2070277278216589936,
+                14288611645555259568,
+                736351207736714754,
+            ]),
+            Fq::from_montgomery_limbs([
+                3347716862318320381,
+                7753489803887181358,
+                3078621017630290197,
+                546400761092640624,
+            ]),
+            Fq::from_montgomery_limbs([
+                17023135355710936311,
+                17458919335769031867,
+                8644067818568852377,
+                166291552324566888,
+            ]),
+            Fq::from_montgomery_limbs([
+                6940508337955530498,
+                15603258991702182670,
+                11398034273171554536,
+                490546364582574793,
+            ]),
+            Fq::from_montgomery_limbs([
+                714880025767162353,
+                1561047425518506558,
+                18300917588554921036,
+                459934390926939754,
+            ]),
+            Fq::from_montgomery_limbs([
+                7052263652008998422,
+                10878078011807497044,
+                316144707029241723,
+                1217422399788142514,
+            ]),
+            Fq::from_montgomery_limbs([
+                7997740162552613662,
+                15975262333320070525,
+                16076734663447383777,
+                927528005373121076,
+            ]),
+            Fq::from_montgomery_limbs([
+                17894942738194991982,
+                14324291663431954692,
+                8472326438898770086,
+                444678746960458273,
+            ]),
+            Fq::from_montgomery_limbs([
+                9531119188799104827,
+                17502187814610233512,
+                7133448812964719685,
+                821357206280123639,
+            ]),
+            Fq::from_montgomery_limbs([
+                1276728070611205264,
+                8168983603260619462,
+                12156367401350491198,
+                957425547013723522,
+            ]),
+            Fq::from_montgomery_limbs([
+                16734403345451334233,
+                12155684058868722029,
+                9463968097727657862,
+                1208132417626380280,
+            ]),
+            Fq::from_montgomery_limbs([
+                17708094762790260201,
+                16146767999994820054,
+                156855872362109059,
+                939854479511337555,
+            ]),
+            Fq::from_montgomery_limbs([
+                3729308744843236077,
+                16955958849124333637,
+                10940838942871271870,
+                728234786768123290,
+            ]),
+            Fq::from_montgomery_limbs([
+                1274219862727410071,
+                7761187830309464464,
+                6126966738520588098,
+                348604912062029976,
+            ]),
+            Fq::from_montgomery_limbs([
+                1418158846136481179,
+                4666983852732444852,
+                15746257840851045864,
+                335842908400593710,
+            ]),
+            Fq::from_montgomery_limbs([
+                3284444588687617830,
+                5236891763677093242,
+                441939727296183852,
+                975235451808450580,
+            ]),
+            Fq::from_montgomery_limbs([
+                17073779421090836121,
+                2626511966098161246,
+                14089214329810475928,
+                120043298737010098,
+            ]),
+            Fq::from_montgomery_limbs([
+                8632104058250559128,
+                9630385257846789931,
+                11633617260242589562,
+                69244275588577070,
+            ]),
+            Fq::from_montgomery_limbs([
+                8867012989400699629,
+                16807733362016194691,
+                12669774023226986857,
+                1116105095380854606,
+            ]),
+            Fq::from_montgomery_limbs([
+                10426362769017547974,
+                2322750723755342515,
+                17679725405093942415,
+                1155257389686991335,
+            ]),
+            Fq::from_montgomery_limbs([
+                12666477231315151976,
+                15672476807437490912,
+                12971738585362382525,
+                1202128320844647381,
+            ]),
+            Fq::from_montgomery_limbs([
+                17619631996554190164,
+                11744965461883012719,
+                1657078017363626948,
+                1322786376428871236,
+            ]),
+            Fq::from_montgomery_limbs([
+                662827005990570872,
+                3188697781727741301,
+                2709406386740568359,
+                1288085129034999771,
+            ]),
+            Fq::from_montgomery_limbs([
+                16756277273673003262,
+                920601635182161171,
+                14771490470368045872,
+                1201351271747260112,
+            ]),
+            Fq::from_montgomery_limbs([
+                11407610175188198206,
+                3240339342292299125,
+                9687601158980547410,
+                703675152096075280,
+            ]),
+            Fq::from_montgomery_limbs([
+                5154815913219911278,
+                2334954223293296423,
+                9627480029349905567,
+                732371696846409094,
+            ]),
+            Fq::from_montgomery_limbs([
+                8863450448172377108,
+                17681334140176218293,
+                9373551067552945131,
+                53100525619657952,
+            ]),
+            Fq::from_montgomery_limbs([
+                13865699117649697492,
+                8615840207039855716,
+                7808200967185417601,
+                66157698144667798,
+            ]),
+            Fq::from_montgomery_limbs([
+                6467016374606186071,
+                18316556388729733837,
+                17595520259092583979,
+                725373025063376684,
+            ]),
+            Fq::from_montgomery_limbs([
+                16799003160189599956,
+                3373885000099591845,
+                11181816252081562544,
+                472909381160541176,
+            ]),
+            Fq::from_montgomery_limbs([
+                1118926412942666487,
+                4425468847985467239,
+                684247933512844021,
+                221083335148542978,
+            ]),
+            Fq::from_montgomery_limbs([
+                2112817576042670576,
+                9108354286589891001,
+                9212210696219618169,
+                560821831898687070,
+            ]),
+            Fq::from_montgomery_limbs([
+                17068990849244967426,
+                1667775948779194357,
+                4145874704916666074,
+                525498269962212964,
+            ]),
+            Fq::from_montgomery_limbs([
+                7161479742284090370,
+                3062736772607472641,
+                4844032647216560119,
+                1024562269887344954,
+            ]),
+            Fq::from_montgomery_limbs([
+                5123404833956419793,
+                2071493784592693644,
+                10227399869105512688,
+                1289809701427217024,
+            ]),
+            Fq::from_montgomery_limbs([
+                6932563380788147420,
+                16656026102414929293,
+                8019918197142244540,
+                327528661244889907,
+            ]),
+            Fq::from_montgomery_limbs([
+                8061496998091252099,
+                9379216241994523137,
+                4094068822872781357,
+                1206460370697776433,
+            ]),
+            Fq::from_montgomery_limbs([
+                15936656486808565769,
+                12583438172674900924,
+                16514862382531131953,
+                1163702407308297132,
+            ]),
+            Fq::from_montgomery_limbs([
+                5245335800874568882,
+                10373110967892026559,
+                953573694799472177,
+                945370022291733253,
+            ]),
+            Fq::from_montgomery_limbs([
+                17452535545904527251,
+                7591183604507336592,
+                2541114319615857577,
+                553121377381778831,
+            ]),
+            Fq::from_montgomery_limbs([
+                769801688245004378,
+                9511931103555030785,
+                10799137948694718776,
+                365235267563686363,
+            ]),
+            Fq::from_montgomery_limbs([
+                7339823235328439464,
+                13478618574892826392,
+                16622330196095164378,
+                944869254042549669,
+            ]),
+            Fq::from_montgomery_limbs([
+                16261045863190190600,
+                12131718424645079627,
+                2690033342744860635,
+                1094695127105297216,
+            ]),
+            Fq::from_montgomery_limbs([
+                2140893272151879225,
+                17078557662372103415,
+                4424984821583817177,
+                813811105384906487,
+            ]),
+            Fq::from_montgomery_limbs([
+                14315457898405787771,
+                13626325350269943847,
+                3676914049013154332,
+                533734681748584205,
+            ]),
+            Fq::from_montgomery_limbs([
+                17438597006029456187,
+                13030096982856424771,
+                5982097374976696613,
+                496282828309364690,
+            ]),
+            Fq::from_montgomery_limbs([
+                11998254540970451843,
+                9995854333037610332,
+                5428889668134974212,
+                129797707128806325,
+            ]),
+            Fq::from_montgomery_limbs([
+                10285944271991499923,
+                3987743317841586971,
+                13692613308155051116,
+                870086687839514808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17847158572759579287,
+                12498944161749103522,
+                2521303160475869450,
+                600503897310769498,
+            ]),
+            Fq::from_montgomery_limbs([
+                4384038014341375687,
+                17263409450641759728,
+                1003550887770836233,
+                1256638861724304813,
+            ]),
+            Fq::from_montgomery_limbs([
+                12961079808065004572,
+                4345508845702074354,
+                17885869157832239508,
+                284075257180556639,
+            ]),
+            Fq::from_montgomery_limbs([
+                9249255508019453193,
+                14668271250883074121,
+                1306431736209966276,
+                246879859099790599,
+            ]),
+            Fq::from_montgomery_limbs([
+                11850358287876139147,
+                16960437762734924752,
+                11520338510106091265,
+                992925826203971203,
+            ]),
+            Fq::from_montgomery_limbs([
+                6178205002749260810,
+                13863089276656961182,
+                16224235067952276869,
+                281153624586230443,
+            ]),
+            Fq::from_montgomery_limbs([
+                6817264677345553819,
+                8697745722073354782,
+                15010031617792127947,
+                699640378206706159,
+            ]),
+            Fq::from_montgomery_limbs([
+                9206554059599422155,
+                14423943978345502814,
+                7280824843380447389,
+                26106439330143010,
+            ]),
+            Fq::from_montgomery_limbs([
+                9658601277586436437,
+                10448269719814906517,
+                15792243082730839516,
+                1265911667998130761,
+            ]),
+            Fq::from_montgomery_limbs([
+                10856979546929943305,
+                16796765890083165220,
+                8266055318376485998,
+                918761740242249723,
+            ]),
+            Fq::from_montgomery_limbs([
+                298176093713349088,
+                2370043787721339323,
+                10512115920818865989,
+                710829727124146463,
+            ]),
+            Fq::from_montgomery_limbs([
+                15083613470408266204,
+                5468617127523822189,
+                5991541458118834166,
+                1054054309922842957,
+            ]),
+            Fq::from_montgomery_limbs([
+                14566057933766508445,
+                15640676556474928849,
+                12314411359482774088,
+                874513448040528506,
+            ]),
+            Fq::from_montgomery_limbs([
+                6790427765792424101,
+                7213076277372414294,
+                1896122166649989684,
+                319771592756163172,
+            ]),
+            Fq::from_montgomery_limbs([
+                4903173577516491916,
+                2530156599877159740,
+                3014996267401942054,
+                1102166792008179967,
+            ]),
+            Fq::from_montgomery_limbs([
+                3071996524379600678,
+                16275508425741873092,
+                8359250124920538795,
+                966124215325238302,
+            ]),
+            Fq::from_montgomery_limbs([
+                15062542417368194422,
+                5800230934114033320,
+                17390494650945526952,
+                1047276982236609819,
+            ]),
+            Fq::from_montgomery_limbs([
+                2177911251136482550,
+                10402359091287272069,
+                1541582072527666064,
+                78041817884396385,
+            ]),
+            Fq::from_montgomery_limbs([
+                6274295930800582875,
+                10309969508592795162,
+                9025441964382018222,
+                1000877605740997386,
+            ]),
+            Fq::from_montgomery_limbs([
+                14115672792039505771,
+                3869611003573774333,
+                13949081265612064746,
+                941773702006978742,
+            ]),
+            Fq::from_montgomery_limbs([
+                4556018356935598502,
+                10269871716009744632,
+                5786706843986449853,
+                25240736138357234,
+            ]),
+            Fq::from_montgomery_limbs([
+                13178125018096273592,
+                13843258279008650272,
+                12067177670131277184,
+                751647078249209236,
+            ]),
+            Fq::from_montgomery_limbs([
+                14749110556628784221,
+                13378302916406318657,
+                3123636248458210797,
+                616045722288177328,
+            ]),
+            Fq::from_montgomery_limbs([
+                14992096577708800926,
+                120436262549551606,
+                10990063108469232635,
+                973012877810961983,
+            ]),
+            Fq::from_montgomery_limbs([
+                5101831384591989263,
+                10575556135831243792,
+                11901525610144874602,
+                1180551585108597136,
+            ]),
+            Fq::from_montgomery_limbs([
+                4790088511347779379,
+                878635350230868515,
+                17283280073234997289,
+                1050882322892608356,
+            ]),
+            Fq::from_montgomery_limbs([
+                1413509811485333237,
+                16492745047139057474,
+                4461973810131292377,
+                57066175293399755,
+            ]),
+            Fq::from_montgomery_limbs([
+                12293785096676181858,
+                16685434485449653797,
+                6125901497245498335,
+                1256682508866554387,
+            ]),
+            Fq::from_montgomery_limbs([
+                15061909435916120223,
+                12326715760984588375,
+                7909112001514934354,
+                1107189719072227757,
+            ]),
+            Fq::from_montgomery_limbs([
+                650280072909872719,
+                11874812105183745195,
+                148269409543560418,
+                157611632628472278,
+            ]),
+            Fq::from_montgomery_limbs([
+                12355585115245201540,
+                11542710536734861600,
+                3588229993712193715,
+                996510456713618383,
+            ]),
+            Fq::from_montgomery_limbs([
+                12411107062477300879,
+                263804949254351783,
+                11317660032809637279,
+                1242066333175273751,
+            ]),
+            Fq::from_montgomery_limbs([
+                872670342782529186,
+                1265260672016044277,
+                847063221777852643,
+                777426925713311588,
+            ]),
+            Fq::from_montgomery_limbs([
+                6319691687724770512,
+                13879484390842863773,
+                15711446585317866199,
+                214474397497215842,
+            ]),
+            Fq::from_montgomery_limbs([
+                9276436077229890046,
+                6056801637947992667,
+                6505028611625829298,
+                151539047574984387,
+            ]),
+            Fq::from_montgomery_limbs([
+                9993443930731691389,
+                13463591709577153555,
+                7607106588505582652,
+                562568215715236453,
+            ]),
+            Fq::from_montgomery_limbs([
+                1551219640996174854,
+                17782751927440250429,
+                6778073682215907806,
+                588442521730327250,
+            ]),
+            Fq::from_montgomery_limbs([
+                10779840755397516470,
+                16123560394527035388,
+                17175025616696990082,
+                161591468424557853,
+            ]),
+            Fq::from_montgomery_limbs([
+                10100063351729350960,
+                14168740743057989706,
+                16566327551664299453,
+                394692853438297709,
+            ]),
+            Fq::from_montgomery_limbs([
+                539589175776372576,
+                10016316337048217747,
+                10662015736213939739,
+                578387540668312812,
+            ]),
+            Fq::from_montgomery_limbs([
+                2238186762345373032,
+                17963029861846672924,
+                4828950284671727190,
+                1117974583848347191,
+            ]),
+            Fq::from_montgomery_limbs([
+                12452185575392352067,
+                8094905177985225472,
+                9529538544101325325,
+                113066757957501047,
+            ]),
+            Fq::from_montgomery_limbs([
+                3480771980850152856,
+                16962393543501508647,
+                4737150915747846031,
+                387827003706171623,
+            ]),
+            Fq::from_montgomery_limbs([
+                13938705373584067041,
+                5359083769394493183,
+                8206552412985746448,
+                1092228417542040092,
+            ]),
+            Fq::from_montgomery_limbs([
+                10180496998045083141,
+                809438985500332129,
+                14880102515211998795,
+                233815309036533411,
+            ]),
+            Fq::from_montgomery_limbs([
+                3020600251803315951,
+                9926158298334915656,
+                2520451414950740433,
+                123504498895598510,
+            ]),
+            Fq::from_montgomery_limbs([
+                11225145441994471682,
+                17867147830159984022,
+                10185206199692978779,
+                530745732962961715,
+            ]),
+            Fq::from_montgomery_limbs([
+                4399344993704915267,
+                6463426343052602402,
+                3428530644109621771,
+                528521666013429158,
+            ]),
+            Fq::from_montgomery_limbs([
+                18041792163916633775,
+                3594729711061603162,
+                7562558653758602617,
+                332080387256743133,
+            ]),
+            Fq::from_montgomery_limbs([
+                8337179209382127005,
+                9048972203904194448,
+                15007273733485671023,
+                621299609624235927,
+            ]),
+            Fq::from_montgomery_limbs([
+                17453088962197071147,
+                12314991007992303865,
+                13474161010553830513,
+                320905366319421901,
+            ]),
+            Fq::from_montgomery_limbs([
+                10977717476454693674,
+                16967984004991392850,
+                8142382727365216153,
+                148426575976126756,
+            ]),
+            Fq::from_montgomery_limbs([
+                14083707450876111789,
+                6486048439602485310,
+                1640342958134292207,
+                164273764026961626,
+            ]),
+            Fq::from_montgomery_limbs([
+                7522863055487927667,
+                14995207922030985485,
+                18445452404682786781,
+                1271300328109361270,
+            ]),
+            Fq::from_montgomery_limbs([
+                5396292615361366000,
+                4397568021695015475,
+                758462842742644567,
+                945297043604612215,
+            ]),
+            Fq::from_montgomery_limbs([
+                8656938040871652662,
+                17168899395518235722,
+                12680731767870686113,
+                1170411122250552778,
+            ]),
+            Fq::from_montgomery_limbs([
+                1271668826498404983,
+                5431973756694685313,
+                5356718241700918386,
+                935435144765808115,
+            ]),
+            Fq::from_montgomery_limbs([
+                13742951468457461330,
+                3252148032372980207,
+                15689887901593077697,
+                420180856055558478,
+            ]),
+            Fq::from_montgomery_limbs([
+                6052145324588401383,
+                12460341505540313578,
+                15829725918730871546,
+                13092408349507471,
+            ]),
+            Fq::from_montgomery_limbs([
+                12652852093681836636,
+                16120792551642962712,
+                12141386869507347273,
+                581428107703888065,
+            ]),
+            Fq::from_montgomery_limbs([
+                7184268618703912542,
+                51042779734000789,
+                8678478698359782499,
+                669664460062462101,
+            ]),
+            Fq::from_montgomery_limbs([
+                17524364907725962731,
+                9665514054768723269,
+                13658311708482683866,
+                317612053171397647,
+            ]),
+            Fq::from_montgomery_limbs([
+                15232027923256073665,
+                13090703484240617945,
+                5858542706869147116,
+                838473907887932002,
+            ]),
+            Fq::from_montgomery_limbs([
+                12795475528813481260,
+                6239166078578613521,
+                9825927156763631322,
+                1003327213345949480,
+            ]),
+            Fq::from_montgomery_limbs([
+                44612284860280835,
+                326334684453180319,
+                6037047510482588406,
+                765530600735883352,
+            ]),
+            Fq::from_montgomery_limbs([
+                7387099927224492662,
+                2903605245391247890,
+                12784128966675368769,
+                441653344151297733,
+            ]),
+            Fq::from_montgomery_limbs([
+                1356054952634727447,
+                16743194830818828889,
+                11554322651993557487,
+                387793137438816621,
+            ]),
+            Fq::from_montgomery_limbs([
+                16837881913484959429,
+                3793589503110624785,
+                12872170647305157046,
+                889705036605592432,
+            ]),
+            Fq::from_montgomery_limbs([
+                17044143653196075446,
+                12022329115309133953,
+                10009848349156969191,
+                648185684822031536,
+            ]),
+            Fq::from_montgomery_limbs([
+                14448760322552723250,
+                12121844492805328212,
+                14331848367236433814,
+                1331966456769111559,
+            ]),
+            Fq::from_montgomery_limbs([
+                14754500210654344382,
+                2859326675537127032,
+                15072127287197699285,
+                1056199792433989972,
+            ]),
+            Fq::from_montgomery_limbs([
+                404726497863979620,
+                1354704220516392027,
+                11735270641007053306,
+                337795318854367800,
+            ]),
+            Fq::from_montgomery_limbs([
+                5963247531335434967,
+                10170788370890826965,
+                2351348857703492850,
+                245700910770370246,
+            ]),
+            Fq::from_montgomery_limbs([
+                1923164143827362258,
+                11591054129709928904,
+                17912132349173909379,
+                471483738758781598,
+            ]),
+            Fq::from_montgomery_limbs([
+                2717765512598161698,
+                14319026016451264896,
+                5176780219360246892,
+                1163865734246250976,
+            ]),
+            Fq::from_montgomery_limbs([
+                10295127373478255038,
+                3223474287856998645,
+                18161162496810110554,
+                389821377012220,
+            ]),
+            Fq::from_montgomery_limbs([
+                13703459941688112502,
+                5435232177732359072,
+                5951301333922369989,
+                1263623583139618455,
+            ]),
+            Fq::from_montgomery_limbs([
+                2139746575643151591,
+                16331887203254574255,
+                14632205075752942720,
+                577509752355216893,
+            ]),
+            Fq::from_montgomery_limbs([
+                8452732994234194739,
+                425361076474611527,
+                3011265094793485298,
+                78808498298703148,
+            ]),
+            Fq::from_montgomery_limbs([
+                9537068957842511601,
+                4296048903797164958,
+                14692688290315444841,
+                968451827140038999,
+            ]),
+            Fq::from_montgomery_limbs([
+                7843910837564676704,
+                1795263830662123377,
+                5359174821390621406,
+                134709732952479369,
+            ]),
+            Fq::from_montgomery_limbs([
+                17375506975714485512,
+                13207236149842436873,
+                15372605387463407228,
+                214039425906432217,
+            ]),
+            Fq::from_montgomery_limbs([
+                6999930549642074802,
+                14156181816923913315,
+                4522982440916992086,
+                343419496143492372,
+            ]),
+            Fq::from_montgomery_limbs([
+                14488560615059868104,
+                12564769898501799034,
+                1656426609328852482,
+                438412456782624352,
+            ]),
+            Fq::from_montgomery_limbs([
+                13654069976900603105,
+                8441894158707743456,
+                12029465296160832079,
+                572827124460826593,
+            ]),
+            Fq::from_montgomery_limbs([
+                7018843027411386814,
+                1400681406526162707,
+                1800660526881787620,
+                1243099116151349046,
+            ]),
+            Fq::from_montgomery_limbs([
+                15955021858343739068,
+                17224705425464069740,
+                14090728319325030417,
+                189875225084182548,
+            ]),
+            Fq::from_montgomery_limbs([
+                15444999650750039756,
+                12642129039678772188,
+                11967080294320480284,
+                1241123296728008968,
+            ]),
+            Fq::from_montgomery_limbs([
+                17215103904023759594,
+                1114192193985629003,
+                8175510570245184463,
+                177867087855011068,
+            ]),
+            Fq::from_montgomery_limbs([
+                16787052769336018992,
+                3234122436149186037,
+                9551397863721047550,
+                1256821356648830436,
+            ]),
+            Fq::from_montgomery_limbs([
+                5170754106822940231,
+                11570820049786362463,
+                4084630216637266768,
+                1019852068665297415,
+            ]),
+            Fq::from_montgomery_limbs([
+                2694681154565470476,
+                1329671543903118950,
+                13692042096501524422,
+                595228267058802486,
+            ]),
+            Fq::from_montgomery_limbs([
+                6489518402545382984,
+                6596672745327129995,
+                11512059858793673938,
+                725727793527895034,
+            ]),
+            Fq::from_montgomery_limbs([
+                6178084550774673062,
+                10628228538122730433,
+                16901487015201920959,
+                614213331884963817,
+            ]),
+            Fq::from_montgomery_limbs([
+                9134241021194717252,
+                17271150174879754072,
+                17191032661910779987,
+                966776462475406993,
+            ]),
+            Fq::from_montgomery_limbs([
+                2837427916490305908,
+                5193083807276241482,
+                720473966778487384,
+                674012298437570508,
+            ]),
+            Fq::from_montgomery_limbs([
+                11974239656844184028,
+                3640635782304188823,
+                10762005198256662028,
+                163172754119379849,
+            ]),
+            Fq::from_montgomery_limbs([
+                16473138895426861593,
+                13706730296037400407,
+                18173298005519118141,
+                436044876490513319,
+            ]),
+            Fq::from_montgomery_limbs([
+                16269942526418915715,
+                852792983974805451,
+                17938229097472484464,
+                761173979809893029,
+            ]),
+            Fq::from_montgomery_limbs([
+                2441363683062156826,
+                14997555233673412139,
+                4986009150507523241,
+                847866736545864483,
+            ]),
+            Fq::from_montgomery_limbs([
+                13886977315890395976,
+                13865463321297909830,
+                8977687504564502398,
+                92663550400667004,
+            ]),
+            Fq::from_montgomery_limbs([
+                1666962435500701819,
+                13000546587182406198,
+                2397298704960191619,
+                1273517600071710660,
+            ]),
+            Fq::from_montgomery_limbs([
+                6243839891627403243,
+                3003279059991755312,
+                15495783771804834376,
+                16291223345475673,
+            ]),
+            Fq::from_montgomery_limbs([
+                8460940298288797860,
+                7891704809841636809,
+                4755137095000375056,
+                175162463811537346,
+            ]),
+            Fq::from_montgomery_limbs([
+                3370764498298435789,
+                12672752234856483095,
+                11629829469322951578,
+                420370607938109187,
+            ]),
+            Fq::from_montgomery_limbs([
+                11235598400398772943,
+                5065730251373091332,
+                13025387590261009971,
+                51297990859383006,
+            ]),
+            Fq::from_montgomery_limbs([
+                14099292293457835941,
+                7033157551832294191,
+                18078258392285641504,
+                608045484164083988,
+            ]),
+            Fq::from_montgomery_limbs([
+                8184976916891845539,
+                10189974963655026232,
+                3424439856607827863,
+                1023511602392328011,
+            ]),
+            Fq::from_montgomery_limbs([
+                9956846391562446180,
+                751958082857994039,
+                15212765613688234103,
+                529794058933179042,
+            ]),
+            Fq::from_montgomery_limbs([
+                8772309044358518311,
+                17795874615736492424,
+                9234123474938439656,
+                76307721324645086,
+            ]),
+            Fq::from_montgomery_limbs([
+                2844682236336078849,
+                4200085066390401301,
+                12822133327347235970,
+                508563623672578969,
+            ]),
+            Fq::from_montgomery_limbs([
+                6276363479848800189,
+                5499772185492736588,
+                15018701209851486534,
+                978738915126495127,
+            ]),
+            Fq::from_montgomery_limbs([
+                8684064943146591030,
+                12900286407934791850,
+                17629997923054446066,
+                1126957580982311009,
+            ]),
+            Fq::from_montgomery_limbs([
+                14219930647609068223,
+                5297667391354747274,
+                3157188998556696077,
+                502131018697019607,
+            ]),
+            Fq::from_montgomery_limbs([
+                269697416716401078,
+                3823932298969202499,
+                493989937227974287,
+                893950957344005912,
+            ]),
+            Fq::from_montgomery_limbs([
+                14905990758212425183,
+                5005632562449514661,
+                9922013358204926168,
+                236414307769393598,
+            ]),
+            Fq::from_montgomery_limbs([
+                9897013056890449063,
+                9814212674078704932,
+                6795298639572607572,
+                1097875163270864502,
+            ]),
+            Fq::from_montgomery_limbs([
+                10898308326476315449,
+                5959994168870996622,
+                16880930096479651899,
+                134442237767286099,
+            ]),
+            Fq::from_montgomery_limbs([
+                15861767078042325304,
+                18024217647776619113,
+                15692973260364808572,
+                1241084946434842104,
+            ]),
+            Fq::from_montgomery_limbs([
+                11346002051201497081,
+                2291012791713898360,
+                16506906027516122916,
+                524196771558470445,
+            ]),
+            Fq::from_montgomery_limbs([
+                18237076412814905610,
+                5199634242138080896,
+                488321012510120898,
+                1286197132352427964,
+            ]),
+            Fq::from_montgomery_limbs([
+                9104766137992585374,
+                12706874492336409207,
+                15674137030226682078,
+                786941803203106236,
+            ]),
+            Fq::from_montgomery_limbs([
+                14506856351285038554,
+                10204856536745270735,
+                3967343749402114616,
+                186704512915457884,
+            ]),
+            Fq::from_montgomery_limbs([
+                10802924668719832187,
+                15206847422153732102,
+                16294931701772320317,
+                612895346218470479,
+            ]),
+            Fq::from_montgomery_limbs([
+                10040965912164399679,
+                5721209560383186431,
+                3826340217196209942,
+                947695151791873291,
+            ]),
+            Fq::from_montgomery_limbs([
+                5599144199691619972,
+                12949894124697130416,
+                4503752033593410908,
+                288242301152648898,
+            ]),
+            Fq::from_montgomery_limbs([
+                10714320736238088183,
+                7222235590673481038,
+                13032173143884659659,
+                1029823772590199025,
+            ]),
+            Fq::from_montgomery_limbs([
+                7796434480303299943,
+                1814060309512378920,
+                9978594839928914801,
+                1245962599545874852,
+            ]),
+            Fq::from_montgomery_limbs([
+                17722271031399218229,
+                395932349093858712,
+                15834341471487418398,
+                847983298575031594,
+            ]),
+            Fq::from_montgomery_limbs([
+                11385398205462133470,
+                8329127004415741118,
+                9451880866497732989,
+                838466260225415638,
+            ]),
+            Fq::from_montgomery_limbs([
+                14983731160578300883,
+                12044700531313741765,
+                12936571617863096245,
+                303730384806806763,
+            ]),
+            Fq::from_montgomery_limbs([
+                4428041830809962213,
+                11470799218329518793,
+                14542245576661506929,
+                504092058597156214,
+            ]),
+            Fq::from_montgomery_limbs([
+                3459780570018826201,
+                15831595649791542517,
+                13510654359669319755,
+                760378348021913631,
+            ]),
+            Fq::from_montgomery_limbs([
+                2445227955508039135,
+                16316176198893780593,
+                4575309517112028179,
+                646104140032418787,
+            ]),
+            Fq::from_montgomery_limbs([
+                905821689334438311,
+                16319899753174684371,
+                15462722123298611840,
+                162094749163425209,
+            ]),
+            Fq::from_montgomery_limbs([
+                16499158484229141626,
+                10741472825087060717,
+                2636651400183405986,
+                559840053714708933,
+            ]),
+            Fq::from_montgomery_limbs([
+                9327407750726386014,
+                14162583094071524648,
+                17335456824627982478,
+                304436118286260878,
+            ]),
+            Fq::from_montgomery_limbs([
+                17800382192389030562,
+                17809620808287795337,
+                5398630621185354245,
+                710233495668149770,
+            ]),
+            Fq::from_montgomery_limbs([
+                4408287822121678564,
+                565346519357324440,
+                4991375904005502152,
+                195316994818052708,
+            ]),
+            Fq::from_montgomery_limbs([
+                3324951924073183823,
+                13361890302809359927,
+                16725264836786041313,
+                1042255452358235917,
+            ]),
+            Fq::from_montgomery_limbs([
+                7167527773342602970,
+                12661084941482070995,
+                11829845327528554448,
+                52775274485446859,
+            ]),
+            Fq::from_montgomery_limbs([
+                11543169809442352648,
+                9030197157989530557,
+                11038400854884006,
+                268430964714272282,
+            ]),
+            Fq::from_montgomery_limbs([
+                589455926767141547,
+                2747041844731386263,
+                8389928227763223718,
+                933814452927418251,
+            ]),
+            Fq::from_montgomery_limbs([
+                8905467742304952839,
+                5907867026123398582,
+                7956220016753782316,
+                626794902480117216,
+            ]),
+            Fq::from_montgomery_limbs([
+                1428716568713910041,
+                2641812975343167672,
+                12877946447904498515,
+                1150984865256615101,
+            ]),
+            Fq::from_montgomery_limbs([
+                16575790968725271289,
+                8210688260946007353,
+                3052369218649113044,
+                653007437521655647,
+            ]),
+            Fq::from_montgomery_limbs([
+                9754501835832132310,
+                3574053321852037241,
+                13641730756195401537,
+                571370013744367121,
+            ]),
+            Fq::from_montgomery_limbs([
+                15290703414536453533,
+                14143626702607458058,
+                8112903326356937711,
+                1098314178598445207,
+            ]),
+            Fq::from_montgomery_limbs([
+                586311984623077332,
+                16468939037584811066,
+                3647871018893652449,
+                632355104912930075,
+            ]),
+            Fq::from_montgomery_limbs([
+                6955330499782458834,
+                6275098279323640836,
+                8242358106923658891,
+                666437443521906363,
+            ]),
+            Fq::from_montgomery_limbs([
+                7114854153743436052,
+                11524488522601501557,
+                13314347434025398004,
+                828045875174547436,
+            ]),
+            Fq::from_montgomery_limbs([
+                18035230275797660415,
+                7625543982788573884,
+                3260832028865406086,
+                1213550436596864599,
+            ]),
+            Fq::from_montgomery_limbs([
+                7202726868055853943,
+                12786081934416532847,
+                6018190845511601673,
+                642747497509913243,
+            ]),
+            Fq::from_montgomery_limbs([
+                8584818201118434885,
+                12587917141432936540,
+                552529873113709475,
+                603610666849720538,
+            ]),
+            Fq::from_montgomery_limbs([
+                8348975703788782219,
+                15443043441968687333,
+                175184833811279124,
+                821891528463458171,
+            ]),
+            Fq::from_montgomery_limbs([
+                16341254384815986859,
+                2833702217142791061,
+                1825706674620811052,
+                329886299105430107,
+            ]),
+            Fq::from_montgomery_limbs([
+                11414969503720306805,
+                18140845725392693996,
+                16920514667044085130,
+                1258878601683025329,
+            ]),
+            Fq::from_montgomery_limbs([
+                14705928381464617545,
+                2077256141281484796,
+                17895313963276976982,
+                640523413894062440,
+            ]),
+            Fq::from_montgomery_limbs([
+                5712921410153799545,
+                185212494461797686,
+                6370027701662925969,
+                949834234779621220,
+            ]),
+            Fq::from_montgomery_limbs([
+                15362324675526774116,
+                3281836469740471294,
+                4810765161575659444,
+                302378724018185551,
+            ]),
+            Fq::from_montgomery_limbs([
+                6633881222835985159,
+                17976373349820732978,
+                3306368458287260322,
+                632763291356244986,
+            ]),
+            Fq::from_montgomery_limbs([
+                17859719714599733173,
+                801097487939763142,
+                11764125929770106640,
+                1241095652702639249,
+            ]),
+            Fq::from_montgomery_limbs([
+                17238470753098392253,
+                6195642645639232238,
+                13869173803647696001,
+                104849717126356792,
+            ]),
+            Fq::from_montgomery_limbs([
+                6560055821540595286,
+                7548671606347399719,
+                17287200431286086763,
+                406062756032735666,
+            ]),
+            Fq::from_montgomery_limbs([
+                14207119950292010426,
+                3487263526950711636,
+                10744342644241048444,
+                619695501643186942,
+            ]),
+            Fq::from_montgomery_limbs([
+                4946247233045917254,
+                14335444800612523777,
+                8229287457566538714,
+                153967513942891272,
+            ]),
+            Fq::from_montgomery_limbs([
+                16526525785322143594,
+                1949744354198256519,
+                10640301124334716217,
+                384683580070660665,
+            ]),
+            Fq::from_montgomery_limbs([
+                16199993853961134149,
+                4535777042031876172,
+                13865521866259101728,
+                446497388413712752,
+            ]),
+            Fq::from_montgomery_limbs([
+                6545541928036951503,
+                17162039902641637791,
+                17512426109771204976,
+                1053533317977283407,
+            ]),
+            Fq::from_montgomery_limbs([
+                11646861030403000376,
+                4393022776393769909,
+                12550249062251936776,
+                98812010889998866,
+            ]),
+            Fq::from_montgomery_limbs([
+                1430313533326276551,
+                2958122709493286341,
+                13152587600513915902,
+                1208014493162971778,
+            ]),
+            Fq::from_montgomery_limbs([
+                1152030847110205202,
+                11730934811472457351,
+                1621476545741288279,
+                393263707713886441,
+            ]),
+            Fq::from_montgomery_limbs([
+                6317980880579093087,
+                2133525460638506826,
+                4465005695170843541,
+                954936155028419482,
+            ]),
+            Fq::from_montgomery_limbs([
+                3324498420665886264,
+                5482104538209405656,
+                12375541685929499270,
+                775557322555418893,
+            ]),
+            Fq::from_montgomery_limbs([
+                8557638484579700253,
+                16572719168601240611,
+                3723925714901453567,
+                766046946204647449,
+            ]),
+            Fq::from_montgomery_limbs([
+                18018351082623476329,
+                15337686980972032025,
+                2237900522226827916,
+                338390882675206845,
+            ]),
+            Fq::from_montgomery_limbs([
+                9042177183782830023,
+                4511853365051176773,
+                15336901219398702513,
+                960407187709220165,
+            ]),
+            Fq::from_montgomery_limbs([
+                14875769256001743319,
+                11760991332073946641,
+                13298289633861843345,
+                354482719723503879,
+            ]),
+            Fq::from_montgomery_limbs([
+                12646299514484235432,
+                14096109938618062816,
+                15868010032624920559,
+                102635250041521592,
+            ]),
+            Fq::from_montgomery_limbs([
+                7410027301583952176,
+                16150536162676412911,
+                3884766958541232386,
+                812806437946389935,
+            ]),
+            Fq::from_montgomery_limbs([
+                6128461159680493420,
+                7851771983337684565,
+                13804470101592903839,
+                983156503206892089,
+            ]),
+            Fq::from_montgomery_limbs([
+                17534835531962747676,
+                16771877101310131848,
+                16151569310320172040,
+                374715133669356680,
+            ]),
+            Fq::from_montgomery_limbs([
+                10952975825336626076,
+                9794665296407029367,
+                9043491974958735815,
+                730495474115375014,
+            ]),
+            Fq::from_montgomery_limbs([
+                3535959407995585307,
+                18255471450673354669,
+                9055125237008630617,
+                656864924380272259,
+            ]),
+            Fq::from_montgomery_limbs([
+                13139261517136225102,
+                13211520956534170573,
+                11532737355503840286,
+                1149601132725794793,
+            ]),
+            Fq::from_montgomery_limbs([
+                13947417003499287757,
+                17521295484998415582,
+                8636742548243525928,
+                466095459741490322,
+            ]),
+            Fq::from_montgomery_limbs([
+                16603676501168528335,
+                11988331691625891473,
+                10778046282744150468,
+                674158357436189367,
+            ]),
+            Fq::from_montgomery_limbs([
+                2780333541004389479,
+                87384062674434647,
+                5862360937706796831,
+                1332946687153495482,
+            ]),
+            Fq::from_montgomery_limbs([
+                12670752456976231768,
+                18407442671205178787,
+                11541734462391770841,
+                1194313380883479457,
+            ]),
+            Fq::from_montgomery_limbs([
+                6962853113744351588,
+                10025350853068900087,
+                18235105653756739411,
+                877795219790340730,
+            ]),
+            Fq::from_montgomery_limbs([
+                13221321585510357855,
+                5694371753065163134,
+                15061908440413978607,
+                1216749340428798971,
+            ]),
+            Fq::from_montgomery_limbs([
+                15964188613707526561,
+                9349924055010758475,
+                7321962974540858755,
+                839420668380490798,
+            ]),
+            Fq::from_montgomery_limbs([
+                16018229631826425045,
+                13894052879401373811,
+                10845310153637980596,
+                177748019783004594,
+            ]),
+            Fq::from_montgomery_limbs([
+                9734946384405607497,
+                17619871130602223934,
+                8736601268686465129,
+                1078569722001354737,
+            ]),
+            Fq::from_montgomery_limbs([
+                5742971211518525563,
+                2809131330919642383,
+                16252137352273911495,
+                468508419890747989,
+            ]),
+            Fq::from_montgomery_limbs([
+                5636932603636459936,
+                7467036559625587133,
+                6545307046477678235,
+                218892442077986162,
+            ]),
+            Fq::from_montgomery_limbs([
+                16423210531209651500,
+                13148724585045798838,
+                15122145126404041618,
+                637247055676627285,
+            ]),
+            Fq::from_montgomery_limbs([
+                1946498733180032189,
+                13808974288744703535,
+                8479869364844437713,
+                707117426306014196,
+            ]),
+            Fq::from_montgomery_limbs([
+                11408277155993202459,
+                10705220541315440947,
+                12479410046187832653,
+                570192658095571184,
+            ]),
+            Fq::from_montgomery_limbs([
+                10235078709773282894,
+                1238066045265724168,
+                3902477577153233549,
+                804909369154962679,
+            ]),
+            Fq::from_montgomery_limbs([
+                2405276304154254357,
+                15040216996024221093,
+                12729224967016801065,
+                1326054962321093547,
+            ]),
+            Fq::from_montgomery_limbs([
+                1011259919271465442,
+                826110975314839469,
+                11251722963446475037,
+                1304803208779679293,
+            ]),
+            Fq::from_montgomery_limbs([
+                14261614176912237987,
+                12529439007674601251,
+                12574871911642696007,
+                781466329249326479,
+            ]),
+            Fq::from_montgomery_limbs([
+                14171379931444735151,
+                13718906366966310106,
+                16506815104648085212,
+                682750804169926596,
+            ]),
+            Fq::from_montgomery_limbs([
+                17004135579289941533,
+                10078533679260882772,
+                9370597267773934322,
+                1061456429833991158,
+            ]),
+            Fq::from_montgomery_limbs([
+                5965949505430635081,
+                8612076383503134963,
+                17575894516355050620,
+                362885326183955590,
+            ]),
+            Fq::from_montgomery_limbs([
+                8549106745439456766,
+                15322393380632920700,
+                2214855789480459954,
+                405255311534648827,
+            ]),
+            Fq::from_montgomery_limbs([
+                9694740102498733310,
+                13635580175237606097,
+                3251475513399041288,
+                1160344512815177247,
+            ]),
+            Fq::from_montgomery_limbs([
+                6623055772613293187,
+                12169766406406281508,
+                5066790847053373139,
+                1155100823547494114,
+            ]),
+            Fq::from_montgomery_limbs([
+                3224714940122703820,
+                11937529108703499721,
+                13495549386102264708,
+                279840756382716256,
+            ]),
+            Fq::from_montgomery_limbs([
+                8297950903421725610,
+                9237877810187199361,
+                6933991137793095447,
+                621433441848910570,
+            ]),
+            Fq::from_montgomery_limbs([
+                4408018004402850710,
+                1729451794286861304,
+                2029036517604644737,
+                930716098793479919,
+            ]),
+            Fq::from_montgomery_limbs([
+                9672285143427939778,
+                4110197552018060383,
+                11066818807929616924,
+                374389600548710970,
+            ]),
+            Fq::from_montgomery_limbs([
+                267596768193449185,
+                17151256690563673075,
+                12211846505623967771,
+                349612535949313248,
+            ]),
+            Fq::from_montgomery_limbs([
+                6895124383637710840,
+                15117601962991039071,
+                4341253277711178102,
+                1060132276190232545,
+            ]),
+            Fq::from_montgomery_limbs([
+                8413114547849239899,
+                15522690778416690257,
+                1872890863676226589,
+                785437673137306494,
+            ]),
+            Fq::from_montgomery_limbs([
+                11345173030547206818,
+                2282527624657950946,
+                10309789137278276311,
+                328901595327018193,
+            ]),
+            Fq::from_montgomery_limbs([
+                13278573517684035446,
+                5054342173734938206,
+                18322773600398349114,
+                463311543957198895,
+            ]),
+            Fq::from_montgomery_limbs([
+                354330049278188407,
+                4251371920724647485,
+                4678813440988787274,
+                496217503583902828,
+            ]),
+            Fq::from_montgomery_limbs([
+                1800510706723023583,
+                12694189400530705118,
+                724673862534797106,
+                241931646530408994,
+            ]),
+            Fq::from_montgomery_limbs([
+                2165358813762768002,
+                17697706443096745364,
+                9472668865658084421,
+                504989661114226284,
+            ]),
+            Fq::from_montgomery_limbs([
+                16207250066481473155,
+                10507660960975797223,
+                7596622973305063505,
+                789861306499825514,
+            ]),
+            Fq::from_montgomery_limbs([
+                14238397072095193019,
+                15052223533815193666,
+                2530672952001606494,
+                358655041843282184,
+            ]),
+            Fq::from_montgomery_limbs([
+                14071966617609789019,
+                9761559114362063348,
+                11603824424000788433,
+                10184984680437988,
+            ]),
+            Fq::from_montgomery_limbs([
+                6894125618950071224,
+                14129489406901423023,
+                6246682231757428888,
+                256761955107262127,
+            ]),
+            Fq::from_montgomery_limbs([
+                1296430030668017526,
+                13981578573589903311,
+                15140605384024214226,
+                1182690950719122135,
+            ]),
+            Fq::from_montgomery_limbs([
+                7348249968636425036,
+                8197013944318465517,
+                9531739565016493909,
+                690832546251557941,
+            ]),
+            Fq::from_montgomery_limbs([
+                6993955759896022545,
+                5881175635906640603,
+                6634481163981783926,
+                753247948710660536,
+            ]),
+            Fq::from_montgomery_limbs([
+                17943897564033183216,
+                7450506874514999728,
+                2328157965327995786,
+                5085405156243327,
+            ]),
+            Fq::from_montgomery_limbs([
+                4304603681842051587,
+                11727108614995019317,
+                1617335938022560992,
+                1077342978184573275,
+            ]),
+            Fq::from_montgomery_limbs([
+                13241854272168467284,
+                2530452164349335794,
+                3816833967701320423,
+                1075458162216611363,
+            ]),
+            Fq::from_montgomery_limbs([
+                17656209238846495965,
+                12868723306121528088,
+                4397652482036272240,
+                970898270509613605,
+            ]),
+            Fq::from_montgomery_limbs([
+                11073355600568037077,
+                14687896260565562336,
+                10362697314439178046,
+                1324138624495315131,
+            ]),
+            Fq::from_montgomery_limbs([
+                8247752878089668724,
+                8059889514901226923,
+                9591508751497366767,
+                77314929801850134,
+            ]),
+            Fq::from_montgomery_limbs([
+                7195024031219045751,
+                15419510607481405591,
+                10696235157017855871,
+                1151047399343150109,
+            ]),
+            Fq::from_montgomery_limbs([
+                6176529981211954442,
+                8715972605655838438,
+                9497263294056128889,
+                65057435363252518,
+            ]),
+            Fq::from_montgomery_limbs([
+                18084999812505683849,
+                18084128509508539477,
+                3475390743918366006,
+                632958315986852299,
+            ]),
+            Fq::from_montgomery_limbs([
+                11235734765161961813,
+                9475903576248976692,
+                3735281593469965492,
+                1187846371522997596,
+            ]),
+            Fq::from_montgomery_limbs([
+                5588923984769690640,
+                1182686389773350616,
+                12444169492162272677,
+                675383676578328828,
+            ]),
+            Fq::from_montgomery_limbs([
+                3862846314110079304,
+                5449988219734836373,
+                11887340827696404415,
+                632833109782285531,
+            ]),
+            Fq::from_montgomery_limbs([
+                14130029094516312948,
+                8757410617222136184,
+                16565879725838489576,
+                350151191881702068,
+            ]),
+            Fq::from_montgomery_limbs([
+                1696497043471473506,
+                1403151274073014399,
+                3958576593022642419,
+                846716926560126699,
+            ]),
+            Fq::from_montgomery_limbs([
+                1280520137161382964,
+                17912956996451491678,
+                17678395748826089007,
+                172121818559888678,
+            ]),
+            Fq::from_montgomery_limbs([
+                16794878928362718453,
+                11102063523418823284,
+                4405038262735076139,
+                919466341364487515,
+            ]),
+            Fq::from_montgomery_limbs([
+                17025244069123800067,
+                479066302927988527,
+                14493471034877721698,
+                143786056938894955,
+            ]),
+            Fq::from_montgomery_limbs([
+                13855233434307963034,
+                17992425559965634860,
+                17378002511450525517,
+                999600472516878878,
+            ]),
+            Fq::from_montgomery_limbs([
+                5236000797211094812,
+                14327582194697403093,
+                13923635977272707171,
+                20022767824901714,
+            ]),
+            Fq::from_montgomery_limbs([
+                11567949642906537088,
+                7291883314851967437,
+                10070846047976833705,
+                520612204167410096,
+            ]),
+            Fq::from_montgomery_limbs([
+                8978529284744938422,
+                4422846689456026818,
+                2469619988745704152,
+                858869895238457060,
+            ]),
+            Fq::from_montgomery_limbs([
+                7200446426941280546,
+                4071738437446250002,
+                9456445025028840517,
+                645670081000948380,
+            ]),
+            Fq::from_montgomery_limbs([
+                8714274678698873080,
+                6967511994807152045,
+                10225692452229209175,
+                131093184692577958,
+            ]),
+            Fq::from_montgomery_limbs([
+                9471558927000203341,
+                15062007731548262138,
+                10880899469780313713,
+                738274374266202545,
+            ]),
+            Fq::from_montgomery_limbs([
+                17038865956596055626,
+                5381869766017962025,
+                17104451991251399266,
+                128409957786576664,
+            ]),
+            Fq::from_montgomery_limbs([
+                3280566453648437273,
+                16122478470419493823,
+                18379936691882702088,
+                184643472243081349,
+            ]),
+            Fq::from_montgomery_limbs([
+                12219038624989320048,
+                6709295381965113060,
+                16190499721159181722,
+                648851354433475170,
+            ]),
+            Fq::from_montgomery_limbs([
+                4713209860148553393,
+                10094225008088771564,
+                7656782719918128387,
+                722614960439044721,
+            ]),
+            Fq::from_montgomery_limbs([
+                14226513627384429888,
+                17025278165745378395,
+                1124714023424255708,
+                1038219570818514262,
+            ]),
+            Fq::from_montgomery_limbs([
+                11868849666495177599,
+                11752224350421394105,
+                16900486582478540416,
+                459441508310376314,
+            ]),
+            Fq::from_montgomery_limbs([
+                1516675636089102042,
+                7287251477307333450,
+                3652087741432609583,
+                579427703657106898,
+            ]),
+            Fq::from_montgomery_limbs([
+                10019231733723430550,
+                5756727287014100730,
+                1505077744556985774,
+                996083397868164205,
+            ]),
+            Fq::from_montgomery_limbs([
+                17391493609949271420,
+                3378863206631289223,
+                5238217800763009591,
+                1082845684384675883,
+            ]),
+            Fq::from_montgomery_limbs([
+                9003980406932379920,
+                16710255509982168390,
+                11397642691382097295,
+                43623450215898907,
+            ]),
+            Fq::from_montgomery_limbs([
+                10115225201889256831,
+                8592404005424373843,
+                14569637172146345785,
+                771986975920184616,
+            ]),
+            Fq::from_montgomery_limbs([
+                12438124060591050670,
+                250799793419615578,
+                4363326096334124713,
+                517173240964675597,
+            ]),
+            Fq::from_montgomery_limbs([
+                1231603215378556634,
+                9171647802886045480,
+                4785213623983802763,
+                3605902272625754,
+            ]),
+            Fq::from_montgomery_limbs([
+                5695923275728231640,
+                9892261347593355977,
+                4719495140205587691,
+                1138288268871405005,
+            ]),
+            Fq::from_montgomery_limbs([
+                11325460431201698353,
+                4417860116092697057,
+                517036508815136728,
+                1207469954069004488,
+            ]),
+            Fq::from_montgomery_limbs([
+                3549598542703968697,
+                15613219709730434763,
+                16186566158549578833,
+                1078036039093377182,
+            ]),
+            Fq::from_montgomery_limbs([
+                472462492819687977,
+                1283486765221140717,
+                2041455286581656508,
+                1295769770803010063,
+            ]),
+            Fq::from_montgomery_limbs([
+                15430767864098576481,
+                18045310271318320502,
+                8241253702124509892,
+                751655776772231504,
+            ]),
+            Fq::from_montgomery_limbs([
+                3066176491716874986,
+                5898103142913942319,
+                3645164203815048727,
+                236198016604213384,
+            ]),
+            Fq::from_montgomery_limbs([
+                1132644883169016455,
+                13724430140962047896,
+                3142945395484545793,
+                478585442665119974,
+            ]),
+        ]),
+        mds: MdsMatrix::<16, 15, 256, 225>::new_from_known([
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                4803295199375898767,
+                3754249901789907182,
+                10248134097739830010,
+                585421998305823688,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                4803295199375898767,
+                3754249901789907182,
+                10248134097739830010,
+                585421998305823688,
+            ]),
+            Fq::from_montgomery_limbs([
+                6971725754975006348,
+                12411354022823329792,
+                12595780155201759418,
+                908437045561825590,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                4803295199375898767,
+                3754249901789907182,
+                10248134097739830010,
+                585421998305823688,
+            ]),
+            Fq::from_montgomery_limbs([
+                6971725754975006348,
+                12411354022823329792,
+                12595780155201759418,
+                908437045561825590,
+            ]),
+            Fq::from_montgomery_limbs([
+                14741272997801603163,
+                8464900402126683431,
+                15422399899660498898,
+                380032526733808404,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                4803295199375898767,
+                3754249901789907182,
+                10248134097739830010,
+                585421998305823688,
+            ]),
+            Fq::from_montgomery_limbs([
+                6971725754975006348,
+                12411354022823329792,
+                12595780155201759418,
+                908437045561825590,
+            ]),
+            Fq::from_montgomery_limbs([
+                14741272997801603163,
+                8464900402126683431,
+                15422399899660498898,
+                380032526733808404,
+            ]),
+            Fq::from_montgomery_limbs([
+                17031940698379603434,
+                5695668047032466743,
+                13046264909972903847,
+                751958880912339834,
+            ]),
+        ]),
+        alpha: Alpha::Exponent(17),
+        rounds: RoundNumbers { r_P: 31, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<15, 225>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14741272997801603163,
+                    8464900402126683431,
+                    15422399899660498898,
+                    380032526733808404,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14741272997801603163,
+                    8464900402126683431,
+                    15422399899660498898,
+                    380032526733808404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17031940698379603434,
+                    5695668047032466743,
+                    13046264909972903847,
+                    751958880912339834,
+                ]),
+            ]),
+            v: Matrix::<1, 15, 15>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+            ]),
+            w: Matrix::<15, 1, 15>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<16, 256>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14741272997801603163,
+                    8464900402126683431,
+                    15422399899660498898,
+                    380032526733808404,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14741272997801603163,
+                    8464900402126683431,
+                    15422399899660498898,
+                    380032526733808404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17031940698379603434,
+                    5695668047032466743,
+                    13046264909972903847,
+                    751958880912339834,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<16, 256>::new_from_known([
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([
+                    699159006121912295,
+                    14279466650777789460,
+                    4939937579884041597,
+                    1017082372351395396,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1477851250516161867,
+                    5413064237113646004,
+                    13597222131169844970,
+                    624459990153521964,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18359563372370245081,
+                    3819301594510390091,
+                    2392655706678069430,
+                    43188089203424299,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5250760138576676173,
+                    13845745620862124721,
+                    3015685424282426947,
+                    109582624354065779,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13176317722943715068,
+                    6816765581649393581,
+                    8185401893312863358,
+                    1124180240365435802,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15875655342365578103,
+                    16319086982832767240,
+                    4532143049080509570,
+                    966261975930384894,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    11128688392247129616,
+                    6142984630695405757,
+                    52307268044029943,
+                    170080638725853976,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17229028253275539495,
+                    8007972473388264908,
+                    6883280005668962319,
+                    1068899332758660687,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13037229471829089347,
+                    9165981502545003926,
+                    11865894825327322436,
+                    1031528592219571110,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5259400325387435111,
+                    15660626299826583995,
+                    2107820284907104682,
+                    338250694110698269,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    6418539163486760578,
+                    17664401637728469300,
+                    15523134576638463947,
+                    1282766191205318603,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2392028063498899799,
+                    14526566396119718804,
+                    7869436056856552768,
+                    912839644114432060,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    458071265827297405,
+                    17980132859994568976,
+                    9919476817220431966,
+                    540365348309667790,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7267288061295828820,
+                    9657852397968678123,
+                    69913071222011592,
+                    1325579251099542686,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    11514076649704206503,
+                    10385521512029851893,
+                    7289678841490839816,
+                    189998872249050072,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<16, 256>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17929766453675866607,
+                    13874503415296354261,
+                    376409965116500271,
+                    109377588702441928,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16517460969258589245,
+                    9478564594113150237,
+                    6732296582423135995,
+                    472727888458569263,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13377106084290494540,
+                    6128232397861392304,
+                    4193504973736294678,
+                    1332367470233314811,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3577123390232589503,
+                    13827699398438659533,
+                    4975688071253682866,
+                    218138664113830921,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10159846378668735255,
+                    17110178512812333452,
+                    9130556521475998679,
+                    1209012662106691110,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13745606699544499293,
+                    11095419616097876290,
+                    629079527523667305,
+                    1090472659802236085,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11276103301377650849,
+                    4142851020370513421,
+                    2185522397365298764,
+                    673467663220031154,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1408383432361853860,
+                    15029277082283024427,
+                    469142757429077328,
+                    1193118606054880698,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10328844597188030999,
+                    12939202030154156987,
+                    5078375975111127902,
+                    79102821193078963,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17248389760343475695,
+                    12122560735037470850,
+                    3582153318813633122,
+                    230407246461277454,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13516467139929092991,
+                    12095391432470657735,
+                    16952289702225810138,
+                    640909072710625116,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7547729782012563776,
+                    4813467545787981363,
+                    2376958219298300299,
+                    1204652223577913095,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9542042986319672937,
+                    17127587050939120242,
+                    9904647467853282833,
+                    216125400737318495,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5067090985860263503,
+                    11524135567944625772,
+                    5802571068120465244,
+                    363238548268751423,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5554139225959905958,
+                    4580172936922407876,
+                    13748196494477441644,
+                    18254623277279937,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6135266935411917112,
+                    1650868955923405537,
+                    10179499258216306464,
+                    403224815538901932,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16517460969258589245,
+                    9478564594113150237,
+                    6732296582423135995,
+                    472727888458569263,
+                ]),
+                Fq::from_montgomery_limbs([
+                    428891905129253535,
+                    17412673239973657702,
+                    14210513200952968417,
+                    58846867222271521,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6755799506638641500,
+                    4414075598456753225,
+                    3154637342722484652,
+                    933432063743632855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12722707459222945986,
+                    1158167568933522093,
+                    16422262687704934177,
+                    1182090111540772434,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2647632411699800018,
+                    626947247209653794,
+                    7265440813284066460,
+                    434070720558476629,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12318814852441632083,
+                    3686747655518144230,
+                    15029105464725466773,
+                    81171550769820290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16595705248301271510,
+                    4619902013802504617,
+                    16821670407525025583,
+                    210216786288232753,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10653824012853028719,
+                    12598238747201889092,
+                    10890083319636123471,
+                    675129713814688289,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6361781564270442245,
+                    7915856419711004187,
+                    13859627275708849089,
+                    143343416878037974,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17301371737276825899,
+                    2527953027428166586,
+                    17563059422258335623,
+                    804420712813033796,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13050624786351388544,
+                    18348082850545207456,
+                    16202236375224783148,
+                    19823006730107888,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18133346811184222632,
+                    10823716089982966530,
+                    18217599441421367892,
+                    512787561728071933,
+                ]),
+                Fq::from_montgomery_limbs([
+                    883072622666872550,
+                    12472786831108568248,
+                    14401044937110416895,
+                    698162723582016327,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6432328019502498069,
+                    12636833326557314500,
+                    16598882070397457036,
+                    228325065711595910,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14399435705833686714,
+                    16093424452455489816,
+                    6885058319891193026,
+                    771911295841717999,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2677218533706352798,
+                    4178769727519631061,
+                    14776315289025006584,
+                    1061442319938309869,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13377106084290494540,
+                    6128232397861392304,
+                    4193504973736294678,
+                    1332367470233314811,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6755799506638641500,
+                    4414075598456753225,
+                    3154637342722484652,
+                    933432063743632855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11292332190554657062,
+                    13368901327547661064,
+                    5041048751307558980,
+                    881008598935723719,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7781680373406415361,
+                    2809503102733828390,
+                    78567065553140083,
+                    608325292314877494,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4753131917231259948,
+                    8111910407361387699,
+                    18165308324279075659,
+                    463176156461485581,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13597260384984430252,
+                    10944136400803998436,
+                    3112508563276385626,
+                    1043752442730330064,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3918878001204264098,
+                    89595065302573235,
+                    11370375520683460213,
+                    1080766871832167092,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11459692675113286456,
+                    4248953715751752021,
+                    4574998769560456663,
+                    765196958993892892,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7165621566962981704,
+                    13854077231741395190,
+                    14693740037690018517,
+                    1325976102087332437,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5741292553083703541,
+                    14589117373652006833,
+                    13906163513588327911,
+                    51777901216855795,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14935063600988645337,
+                    15835640075598766534,
+                    6242452227019727783,
+                    452467563944825196,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7015271741907740395,
+                    12409702742204713182,
+                    6280380435637446135,
+                    1084843310391625092,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15650587803176555955,
+                    4827520182359662850,
+                    739297384384875453,
+                    1116865412564605635,
+                ]),
+                Fq::from_montgomery_limbs([
+                    510282722931135464,
+                    79750126177824290,
+                    9574053860120677725,
+                    310970048903015955,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7686616446597759085,
+                    2019135772344103937,
+                    6091107206947717624,
+                    309302022005852160,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13938126527232399819,
+                    16010745564527691749,
+                    3482879038284932875,
+                    812020567794530274,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3577123390232589503,
+                    13827699398438659533,
+                    4975688071253682866,
+                    218138664113830921,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12722707459222945986,
+                    1158167568933522093,
+                    16422262687704934177,
+                    1182090111540772434,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7781680373406415361,
+                    2809503102733828390,
+                    78567065553140083,
+                    608325292314877494,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16811374702973726677,
+                    16967279383194194891,
+                    4727930093143190280,
+                    91442813583596716,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17703032756825334251,
+                    7199623348032730540,
+                    15801248470024761222,
+                    1206695707390615059,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5956016512110225310,
+                    7335099740945940520,
+                    4608169581612977209,
+                    889154497300040712,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16149473144221653992,
+                    6492404458840184349,
+                    2398196196121585017,
+                    260865036836508602,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5569430992899570428,
+                    676793943802717345,
+                    8672561771031170651,
+                    1152445170107212213,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14850404706689257849,
+                    2259215579246566400,
+                    2835204500095701761,
+                    711306282165910424,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5402301399365012010,
+                    5252364841191578880,
+                    14389715557035787328,
+                    792467610652828717,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11274057840655105575,
+                    10548512182402073609,
+                    430751702640064394,
+                    1078537980209046515,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1460773541013057730,
+                    10950442770124178910,
+                    3663805770870763927,
+                    1233569746713416964,
+                ]),
+                Fq::from_montgomery_limbs([
+                    320110911139303428,
+                    17391839348229432101,
+                    14318030037731712717,
+                    574929328160976225,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13630603462294697495,
+                    10715020136956356716,
+                    1909393489795059648,
+                    461425106318522775,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12253097522287678439,
+                    14314353672950832692,
+                    6571631764294864961,
+                    680991504258080438,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10088966916853087687,
+                    10821523856182131243,
+                    10655997212948065861,
+                    947989750195074290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10159846378668735255,
+                    17110178512812333452,
+                    9130556521475998679,
+                    1209012662106691110,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2647632411699800018,
+                    626947247209653794,
+                    7265440813284066460,
+                    434070720558476629,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4753131917231259948,
+                    8111910407361387699,
+                    18165308324279075659,
+                    463176156461485581,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17703032756825334251,
+                    7199623348032730540,
+                    15801248470024761222,
+                    1206695707390615059,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10307762091309111126,
+                    6585497733147015050,
+                    12403531213799536051,
+                    468435808117692503,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11372811987285384619,
+                    16862514574727599465,
+                    13600354152593539850,
+                    791370500615567715,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2223642555353016045,
+                    2533046713645283914,
+                    9942812905528485052,
+                    874113331933904534,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17919880955200735423,
+                    15661880154487167080,
+                    10185180480499978522,
+                    640709323771661881,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8882035278498587798,
+                    14614616762672737831,
+                    6869435813062458854,
+                    546245349229062642,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9285120325907957027,
+                    9032932519932889118,
+                    11636692276786882300,
+                    1194215840252480376,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14255545376993747320,
+                    18017580785549416428,
+                    17308240232855984276,
+                    929336317832324466,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15411290473415538633,
+                    18066536714995409310,
+                    2586531641310823043,
+                    750462224714075357,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9545340914128317132,
+                    15777636107488411154,
+                    12445815827276562713,
+                    1090646801336416229,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8815234485182165596,
+                    5644206184637531726,
+                    1835149481125951997,
+                    385207490472743927,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7249192185809329202,
+                    4739712249662256759,
+                    12370491108341551833,
+                    501855692366342431,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7031244939151593297,
+                    5954361019130631477,
+                    5014833379455312312,
+                    362717580162724949,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13745606699544499293,
+                    11095419616097876290,
+                    629079527523667305,
+                    1090472659802236085,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12318814852441632083,
+                    3686747655518144230,
+                    15029105464725466773,
+                    81171550769820290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13597260384984430252,
+                    10944136400803998436,
+                    3112508563276385626,
+                    1043752442730330064,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5956016512110225310,
+                    7335099740945940520,
+                    4608169581612977209,
+                    889154497300040712,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11372811987285384619,
+                    16862514574727599465,
+                    13600354152593539850,
+                    791370500615567715,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18441605784256591470,
+                    12396253880454930987,
+                    9291802247892436235,
+                    970804191617236454,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12095392269880416898,
+                    13966405557091903631,
+                    9826207343647012259,
+                    612870248244019343,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4964858843541813677,
+                    2927008900574599545,
+                    1487227732314259222,
+                    1172209879377397571,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12810646823423145624,
+                    17229619985269650001,
+                    5013148285349088663,
+                    138130119672891632,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11993454641753991430,
+                    4875365530482010531,
+                    5757564333906449030,
+                    524463807680305951,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16876345404046860806,
+                    14573617091338028802,
+                    4625832209548378047,
+                    1038534346273104308,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7562676292709059544,
+                    8063740978048322476,
+                    17569040100112738678,
+                    411931397236572586,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17102871402874750770,
+                    14916562440357813221,
+                    12727861475597995118,
+                    1085415335965801399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4668173239093871602,
+                    2147518209816402371,
+                    4186902955673655126,
+                    785845434648246937,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3666750855924398147,
+                    12065482255965128257,
+                    4282638578388391438,
+                    598559942030784703,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14419421137017024163,
+                    15589222113217615859,
+                    5082973097050805192,
+                    285137779733725693,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11276103301377650849,
+                    4142851020370513421,
+                    2185522397365298764,
+                    673467663220031154,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16595705248301271510,
+                    4619902013802504617,
+                    16821670407525025583,
+                    210216786288232753,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3918878001204264098,
+                    89595065302573235,
+                    11370375520683460213,
+                    1080766871832167092,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16149473144221653992,
+                    6492404458840184349,
+                    2398196196121585017,
+                    260865036836508602,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2223642555353016045,
+                    2533046713645283914,
+                    9942812905528485052,
+                    874113331933904534,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12095392269880416898,
+                    13966405557091903631,
+                    9826207343647012259,
+                    612870248244019343,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16649232823507719327,
+                    12574058234899845728,
+                    8961607362352941045,
+                    1023774087965382502,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11513498577124808346,
+                    14933022960327809691,
+                    14825427432106800194,
+                    748414490821389969,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15771278178696009987,
+                    15391678341837511940,
+                    3012037307773531157,
+                    995888164442790751,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13115388079779192699,
+                    3259012072755174943,
+                    12316844294981633141,
+                    173106995691879532,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8324995802168837335,
+                    10273730657917817181,
+                    8948453443070844914,
+                    41822613350022495,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14299095386777925455,
+                    7662603461368343757,
+                    5586426311659653418,
+                    1074326529946857382,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6023854436283775655,
+                    175257740167536228,
+                    3131108018945854624,
+                    878194415574598985,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17687445464220042275,
+                    16147279396626315255,
+                    13408647350457956936,
+                    488508786799139556,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8642853589588414660,
+                    283306784355695812,
+                    16874829236337202492,
+                    578955363798480530,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2105714530885888121,
+                    15682268158618184009,
+                    4333120394108310248,
+                    1299332640413627645,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1408383432361853860,
+                    15029277082283024427,
+                    469142757429077328,
+                    1193118606054880698,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10653824012853028719,
+                    12598238747201889092,
+                    10890083319636123471,
+                    675129713814688289,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11459692675113286456,
+                    4248953715751752021,
+                    4574998769560456663,
+                    765196958993892892,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5569430992899570428,
+                    676793943802717345,
+                    8672561771031170651,
+                    1152445170107212213,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17919880955200735423,
+                    15661880154487167080,
+                    10185180480499978522,
+                    640709323771661881,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4964858843541813677,
+                    2927008900574599545,
+                    1487227732314259222,
+                    1172209879377397571,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11513498577124808346,
+                    14933022960327809691,
+                    14825427432106800194,
+                    748414490821389969,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1353854462641853710,
+                    7681944380762508234,
+                    13869017777893787574,
+                    402523731121612711,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3041099664205745783,
+                    10975980728999992862,
+                    902373268753287218,
+                    990417333716500492,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17701266339400731892,
+                    5987737677592687928,
+                    14842225833174013561,
+                    1090245912707371400,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8782668969701460417,
+                    11819109492703539197,
+                    12105124218263143575,
+                    492837602470033772,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3468114591444952671,
+                    13328311199588031059,
+                    5593310345170418012,
+                    41396658463153235,
+                ]),
+                Fq::from_montgomery_limbs([
+                    532477886130259258,
+                    17596407941784394760,
+                    14805599810771721096,
+                    445585174632815729,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11383377121370267597,
+                    14173312664675382709,
+                    11100670095152500824,
+                    688014690529718768,
+                ]),
+                Fq::from_montgomery_limbs([
+                    890073457017901760,
+                    17224098768273570651,
+                    8744995928300394297,
+                    1159095048244644042,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9074702488179827054,
+                    17770852707543869329,
+                    12009175354315165471,
+                    693374411611815109,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10328844597188030999,
+                    12939202030154156987,
+                    5078375975111127902,
+                    79102821193078963,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6361781564270442245,
+                    7915856419711004187,
+                    13859627275708849089,
+                    143343416878037974,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7165621566962981704,
+                    13854077231741395190,
+                    14693740037690018517,
+                    1325976102087332437,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14850404706689257849,
+                    2259215579246566400,
+                    2835204500095701761,
+                    711306282165910424,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8882035278498587798,
+                    14614616762672737831,
+                    6869435813062458854,
+                    546245349229062642,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12810646823423145624,
+                    17229619985269650001,
+                    5013148285349088663,
+                    138130119672891632,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15771278178696009987,
+                    15391678341837511940,
+                    3012037307773531157,
+                    995888164442790751,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3041099664205745783,
+                    10975980728999992862,
+                    902373268753287218,
+                    990417333716500492,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15593131424427353195,
+                    11687680100940454513,
+                    16066557160367625671,
+                    67473297778108213,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2780801527056985146,
+                    16273092068062944411,
+                    16938850385723806939,
+                    685210881638032809,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9248199017368231347,
+                    1761773905029251553,
+                    10550856033091297813,
+                    98940309098606473,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12716186713176873309,
+                    17082736199905815860,
+                    12606298510681990143,
+                    578548489856126602,
+                ]),
+                Fq::from_montgomery_limbs([
+                    102273964688563919,
+                    2991374337225149914,
+                    8651715816746283291,
+                    834924003274132888,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16147010172090153014,
+                    17111593275902328943,
+                    10123959631029334029,
+                    404618631664577836,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1438467505269476323,
+                    13121397076343279884,
+                    4281652706941563356,
+                    151936044446804745,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1191934583643457734,
+                    7138124489528789443,
+                    10902687217973779783,
+                    609138412147662077,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17248389760343475695,
+                    12122560735037470850,
+                    3582153318813633122,
+                    230407246461277454,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17301371737276825899,
+                    2527953027428166586,
+                    17563059422258335623,
+                    804420712813033796,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5741292553083703541,
+                    14589117373652006833,
+                    13906163513588327911,
+                    51777901216855795,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5402301399365012010,
+                    5252364841191578880,
+                    14389715557035787328,
+                    792467610652828717,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9285120325907957027,
+                    9032932519932889118,
+                    11636692276786882300,
+                    1194215840252480376,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11993454641753991430,
+                    4875365530482010531,
+                    5757564333906449030,
+                    524463807680305951,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13115388079779192699,
+                    3259012072755174943,
+                    12316844294981633141,
+                    173106995691879532,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17701266339400731892,
+                    5987737677592687928,
+                    14842225833174013561,
+                    1090245912707371400,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2780801527056985146,
+                    16273092068062944411,
+                    16938850385723806939,
+                    685210881638032809,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17261923198622818624,
+                    17251913419559770764,
+                    17990826585045008868,
+                    1081071027495196111,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5127411693078273880,
+                    5230950222298532220,
+                    6724024558196009882,
+                    572961284454881758,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3510479072758606078,
+                    13153553334983382505,
+                    3519156491649224345,
+                    130346923995473860,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13082917572211798955,
+                    6891790184384495867,
+                    11459980146356671436,
+                    25590923719538991,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11338644535506718866,
+                    12160866911962031360,
+                    8740629436834324738,
+                    262898918811300201,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3446251164768892347,
+                    6964152526751095156,
+                    11783651011346860125,
+                    959185826092871719,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7532716791169723171,
+                    17960390971469196657,
+                    3362765928100909709,
+                    313814805478844556,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13516467139929092991,
+                    12095391432470657735,
+                    16952289702225810138,
+                    640909072710625116,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13050624786351388544,
+                    18348082850545207456,
+                    16202236375224783148,
+                    19823006730107888,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14935063600988645337,
+                    15835640075598766534,
+                    6242452227019727783,
+                    452467563944825196,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11274057840655105575,
+                    10548512182402073609,
+                    430751702640064394,
+                    1078537980209046515,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14255545376993747320,
+                    18017580785549416428,
+                    17308240232855984276,
+                    929336317832324466,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16876345404046860806,
+                    14573617091338028802,
+                    4625832209548378047,
+                    1038534346273104308,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8324995802168837335,
+                    10273730657917817181,
+                    8948453443070844914,
+                    41822613350022495,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8782668969701460417,
+                    11819109492703539197,
+                    12105124218263143575,
+                    492837602470033772,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9248199017368231347,
+                    1761773905029251553,
+                    10550856033091297813,
+                    98940309098606473,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5127411693078273880,
+                    5230950222298532220,
+                    6724024558196009882,
+                    572961284454881758,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4477125250656072306,
+                    16030076026191545220,
+                    1861029166755474663,
+                    886832766605548076,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17821516503722271406,
+                    11198625920009252394,
+                    15658224908214838481,
+                    1287095078890472523,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5999055417664414000,
+                    13700174440072378630,
+                    13996708990196996778,
+                    957960697277821382,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7205681916266316791,
+                    8767893707006994781,
+                    2092194603543768442,
+                    472322343904089852,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1981173084867979156,
+                    12897769657977833847,
+                    1173944011624294726,
+                    435530676026040023,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1826169526549730967,
+                    17771274086682716379,
+                    11266648887692964850,
+                    75416290532997777,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7547729782012563776,
+                    4813467545787981363,
+                    2376958219298300299,
+                    1204652223577913095,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18133346811184222632,
+                    10823716089982966530,
+                    18217599441421367892,
+                    512787561728071933,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7015271741907740395,
+                    12409702742204713182,
+                    6280380435637446135,
+                    1084843310391625092,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1460773541013057730,
+                    10950442770124178910,
+                    3663805770870763927,
+                    1233569746713416964,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15411290473415538633,
+                    18066536714995409310,
+                    2586531641310823043,
+                    750462224714075357,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7562676292709059544,
+                    8063740978048322476,
+                    17569040100112738678,
+                    411931397236572586,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14299095386777925455,
+                    7662603461368343757,
+                    5586426311659653418,
+                    1074326529946857382,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3468114591444952671,
+                    13328311199588031059,
+                    5593310345170418012,
+                    41396658463153235,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12716186713176873309,
+                    17082736199905815860,
+                    12606298510681990143,
+                    578548489856126602,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3510479072758606078,
+                    13153553334983382505,
+                    3519156491649224345,
+                    130346923995473860,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17821516503722271406,
+                    11198625920009252394,
+                    15658224908214838481,
+                    1287095078890472523,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18341183596250749427,
+                    15090258779940110793,
+                    17372469601533991396,
+                    768209007472185649,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2679854754130983765,
+                    8466701691753072399,
+                    17438861245274030566,
+                    355982101151575621,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11925656056756247478,
+                    7594287525956573423,
+                    2614933887388799346,
+                    1022427787787383770,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9496238741672430837,
+                    13654677450709844633,
+                    5689640959004343408,
+                    815763974522088377,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18405676994047060535,
+                    15226979646301249944,
+                    13862391247953576815,
+                    383394178023550984,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9542042986319672937,
+                    17127587050939120242,
+                    9904647467853282833,
+                    216125400737318495,
+                ]),
+                Fq::from_montgomery_limbs([
+                    883072622666872550,
+                    12472786831108568248,
+                    14401044937110416895,
+                    698162723582016327,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15650587803176555955,
+                    4827520182359662850,
+                    739297384384875453,
+                    1116865412564605635,
+                ]),
+                Fq::from_montgomery_limbs([
+                    320110911139303428,
+                    17391839348229432101,
+                    14318030037731712717,
+                    574929328160976225,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9545340914128317132,
+                    15777636107488411154,
+                    12445815827276562713,
+                    1090646801336416229,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17102871402874750770,
+                    14916562440357813221,
+                    12727861475597995118,
+                    1085415335965801399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6023854436283775655,
+                    175257740167536228,
+                    3131108018945854624,
+                    878194415574598985,
+                ]),
+                Fq::from_montgomery_limbs([
+                    532477886130259258,
+                    17596407941784394760,
+                    14805599810771721096,
+                    445585174632815729,
+                ]),
+                Fq::from_montgomery_limbs([
+                    102273964688563919,
+                    2991374337225149914,
+                    8651715816746283291,
+                    834924003274132888,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13082917572211798955,
+                    6891790184384495867,
+                    11459980146356671436,
+                    25590923719538991,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5999055417664414000,
+                    13700174440072378630,
+                    13996708990196996778,
+                    957960697277821382,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2679854754130983765,
+                    8466701691753072399,
+                    17438861245274030566,
+                    355982101151575621,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10108664406688683877,
+                    18158527119358063325,
+                    5502591825197636382,
+                    38714066490233579,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5164734917013938862,
+                    337648791559526290,
+                    684023076094379760,
+                    1342761240086250335,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8709631872466048742,
+                    10644826952411552590,
+                    4992858100224886038,
+                    359823035423119737,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1656423881015227288,
+                    3379297497481665438,
+                    7690767000660257905,
+                    299455007946371118,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5067090985860263503,
+                    11524135567944625772,
+                    5802571068120465244,
+                    363238548268751423,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6432328019502498069,
+                    12636833326557314500,
+                    16598882070397457036,
+                    228325065711595910,
+                ]),
+                Fq::from_montgomery_limbs([
+                    510282722931135464,
+                    79750126177824290,
+                    9574053860120677725,
+                    310970048903015955,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13630603462294697495,
+                    10715020136956356716,
+                    1909393489795059648,
+                    461425106318522775,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8815234485182165596,
+                    5644206184637531726,
+                    1835149481125951997,
+                    385207490472743927,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4668173239093871602,
+                    2147518209816402371,
+                    4186902955673655126,
+                    785845434648246937,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17687445464220042275,
+                    16147279396626315255,
+                    13408647350457956936,
+                    488508786799139556,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11383377121370267597,
+                    14173312664675382709,
+                    11100670095152500824,
+                    688014690529718768,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16147010172090153014,
+                    17111593275902328943,
+                    10123959631029334029,
+                    404618631664577836,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11338644535506718866,
+                    12160866911962031360,
+                    8740629436834324738,
+                    262898918811300201,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7205681916266316791,
+                    8767893707006994781,
+                    2092194603543768442,
+                    472322343904089852,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11925656056756247478,
+                    7594287525956573423,
+                    2614933887388799346,
+                    1022427787787383770,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5164734917013938862,
+                    337648791559526290,
+                    684023076094379760,
+                    1342761240086250335,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16713485725518690599,
+                    16889479681656602044,
+                    7146069999806830044,
+                    774903170813107066,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6123780670266677528,
+                    3465879386663577458,
+                    2238096337839864047,
+                    671535102065645546,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4003648126069587294,
+                    11729421456234276047,
+                    16272564375917627344,
+                    1076538523613004431,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5554139225959905958,
+                    4580172936922407876,
+                    13748196494477441644,
+                    18254623277279937,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14399435705833686714,
+                    16093424452455489816,
+                    6885058319891193026,
+                    771911295841717999,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7686616446597759085,
+                    2019135772344103937,
+                    6091107206947717624,
+                    309302022005852160,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12253097522287678439,
+                    14314353672950832692,
+                    6571631764294864961,
+                    680991504258080438,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7249192185809329202,
+                    4739712249662256759,
+                    12370491108341551833,
+                    501855692366342431,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3666750855924398147,
+                    12065482255965128257,
+                    4282638578388391438,
+                    598559942030784703,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8642853589588414660,
+                    283306784355695812,
+                    16874829236337202492,
+                    578955363798480530,
+                ]),
+                Fq::from_montgomery_limbs([
+                    890073457017901760,
+                    17224098768273570651,
+                    8744995928300394297,
+                    1159095048244644042,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1438467505269476323,
+                    13121397076343279884,
+                    4281652706941563356,
+                    151936044446804745,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3446251164768892347,
+                    6964152526751095156,
+                    11783651011346860125,
+                    959185826092871719,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1981173084867979156,
+                    12897769657977833847,
+                    1173944011624294726,
+                    435530676026040023,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9496238741672430837,
+                    13654677450709844633,
+                    5689640959004343408,
+                    815763974522088377,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8709631872466048742,
+                    10644826952411552590,
+                    4992858100224886038,
+                    359823035423119737,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6123780670266677528,
+                    3465879386663577458,
+                    2238096337839864047,
+                    671535102065645546,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17656435318757476912,
+                    16722460265479606667,
+                    13730091690312376105,
+                    1055814578382427999,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15839175307698943854,
+                    5665574652064088846,
+                    15103209580465020414,
+                    416107296987531024,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6135266935411917112,
+                    1650868955923405537,
+                    10179499258216306464,
+                    403224815538901932,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2677218533706352798,
+                    4178769727519631061,
+                    14776315289025006584,
+                    1061442319938309869,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13938126527232399819,
+                    16010745564527691749,
+                    3482879038284932875,
+                    812020567794530274,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10088966916853087687,
+                    10821523856182131243,
+                    10655997212948065861,
+                    947989750195074290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7031244939151593297,
+                    5954361019130631477,
+                    5014833379455312312,
+                    362717580162724949,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14419421137017024163,
+                    15589222113217615859,
+                    5082973097050805192,
+                    285137779733725693,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2105714530885888121,
+                    15682268158618184009,
+                    4333120394108310248,
+                    1299332640413627645,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9074702488179827054,
+                    17770852707543869329,
+                    12009175354315165471,
+                    693374411611815109,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1191934583643457734,
+                    7138124489528789443,
+                    10902687217973779783,
+                    609138412147662077,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7532716791169723171,
+                    17960390971469196657,
+                    3362765928100909709,
+                    313814805478844556,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1826169526549730967,
+                    17771274086682716379,
+                    11266648887692964850,
+                    75416290532997777,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18405676994047060535,
+                    15226979646301249944,
+                    13862391247953576815,
+                    383394178023550984,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1656423881015227288,
+                    3379297497481665438,
+                    7690767000660257905,
+                    299455007946371118,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4003648126069587294,
+                    11729421456234276047,
+                    16272564375917627344,
+                    1076538523613004431,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15839175307698943854,
+                    5665574652064088846,
+                    15103209580465020414,
+                    416107296987531024,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10064530315433209257,
+                    8291677311295365042,
+                    4064302494945906740,
+                    1034613566851785616,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<15, 225>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    15041269133085749102,
+                    3576417852156045103,
+                    12433282442016205067,
+                    665861383652702655,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1297243514826479190,
+                    15522014113295091505,
+                    476061637288415649,
+                    94027684775329195,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6267893417764351077,
+                    6408660583900391152,
+                    3818582134515442988,
+                    1247970713402507172,
+                ]),
+                Fq::from_montgomery_limbs([
+                    186044296292106939,
+                    14801177655202566597,
+                    4873996909871071969,
+                    1097157838800499307,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2427558552808240059,
+                    17809916307616337934,
+                    200898791807699725,
+                    1116551393494533410,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8442259208713262083,
+                    12532576871483949370,
+                    2845155768001510337,
+                    1262324680370561727,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7581100457130748028,
+                    4261148773632251160,
+                    17324470923461703704,
+                    1205980042520457767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5253617786208746833,
+                    1062257399922146596,
+                    11243061650262856061,
+                    794152049755357645,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5965791848933318952,
+                    7267682762681496223,
+                    11982739236613719710,
+                    741903508948021205,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2219311954012783507,
+                    11105587948464195588,
+                    12545455055818183571,
+                    627434358436798187,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3854079243507445075,
+                    18193937427611191806,
+                    3912943871864388946,
+                    1211024694532618107,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3254083509534896999,
+                    5966599094293350620,
+                    17531592113694315688,
+                    548929962967144526,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18424689599565389915,
+                    9529568872472476318,
+                    9982143478322206966,
+                    830107297772979945,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8987108570298355133,
+                    7007488674683910304,
+                    2359120197757982416,
+                    258592140203371878,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12551012134372429025,
+                    9138483427158676089,
+                    4594069410442897632,
+                    1288699919446573308,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1297243514826479190,
+                    15522014113295091505,
+                    476061637288415649,
+                    94027684775329195,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7901725128768657387,
+                    12818976876807309069,
+                    2116613181496908308,
+                    891121830190233504,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17677902988503845377,
+                    4455281683923981946,
+                    10083678061616092473,
+                    1332351334225483996,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10314700666513554735,
+                    9631523175857794234,
+                    14213429654234435967,
+                    697880548495321638,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5881361354909080020,
+                    680939361666230985,
+                    12488513501171810308,
+                    312327920852796752,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11399153575025370304,
+                    10348387006696177918,
+                    15623232126886412587,
+                    1255732666438708152,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2375629152476024257,
+                    15496788363627696615,
+                    1045537159697104165,
+                    903232989158747145,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10125946588003169577,
+                    9294331698863906825,
+                    10898481382486692853,
+                    597187414061379252,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3761532155894249073,
+                    10179669234396236265,
+                    9820218808277852221,
+                    1078295412599212950,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13911343845518170006,
+                    7199449375764138406,
+                    834023895388223371,
+                    950684169619368985,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7681569419602669194,
+                    14241871374851720013,
+                    16112440021414214890,
+                    1316882639102467424,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11320810401912518628,
+                    6686468388120302555,
+                    10029607691020497819,
+                    117245037297751382,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10591267681613193878,
+                    15080105937033776092,
+                    14071945438003863659,
+                    211353504681570101,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3409371768115750509,
+                    8690348871220435802,
+                    7469366512757463962,
+                    1291562974830571556,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15090947536878980551,
+                    2332885851460365608,
+                    7076237526631787541,
+                    1258748967492249643,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6267893417764351077,
+                    6408660583900391152,
+                    3818582134515442988,
+                    1247970713402507172,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17677902988503845377,
+                    4455281683923981946,
+                    10083678061616092473,
+                    1332351334225483996,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8848744443116360791,
+                    8011868035220749282,
+                    6244006892229858655,
+                    188606658882717563,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17638916578994156096,
+                    9479805032946914101,
+                    8969391635126884671,
+                    23945334226143913,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2087784782139868996,
+                    15215670308873237375,
+                    1341150409092731521,
+                    660550041121556047,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9032229770308309437,
+                    3629957979443512637,
+                    10692655369756964797,
+                    249393105487995182,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2440167098020821179,
+                    10530103943747239562,
+                    12553088053708993682,
+                    483284485349902880,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14870333922145015074,
+                    656138745421375973,
+                    17538447819444553446,
+                    957892163216429715,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5771305982825354925,
+                    14892350011024741438,
+                    6595614239628501476,
+                    455710061552445301,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12299987538780793707,
+                    11603857281132694105,
+                    17312848279919712613,
+                    140507104767679,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11475515565310754470,
+                    18399915100911932950,
+                    12844751979360592066,
+                    1180751573743812060,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8093380926599746645,
+                    10019024240338768696,
+                    6085260973688889292,
+                    89479442157065060,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5324022110125893680,
+                    12180300771150324341,
+                    10677538729293468484,
+                    1163086008666146409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3232974858321351247,
+                    10375202013144361120,
+                    7474570621090609609,
+                    50178321366384874,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12951193646936875730,
+                    18400598786218319577,
+                    17584019970178708013,
+                    764481309129532907,
+                ]),
+                Fq::from_montgomery_limbs([
+                    186044296292106939,
+                    14801177655202566597,
+                    4873996909871071969,
+                    1097157838800499307,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10314700666513554735,
+                    9631523175857794234,
+                    14213429654234435967,
+                    697880548495321638,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17638916578994156096,
+                    9479805032946914101,
+                    8969391635126884671,
+                    23945334226143913,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7198238700682146195,
+                    817771636817464438,
+                    15171452844870960801,
+                    287793506462342379,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8259847601256957827,
+                    18375825551283255265,
+                    13433007094922410509,
+                    1318903710208525508,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7488743577524288591,
+                    5341109538245604068,
+                    14255832257276747992,
+                    1270661064185643336,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5046021349688480914,
+                    4734743108457463904,
+                    6415591163891973236,
+                    1126922084699145732,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9684284087945507168,
+                    12660410381795222838,
+                    7563047808192444790,
+                    933269937074053108,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11052655090578899826,
+                    1075172265760265751,
+                    16175258937310166875,
+                    1280592509669276564,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5967407451947021263,
+                    9691019300551280324,
+                    11484580379433096913,
+                    1209801568877094757,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18174127066981285596,
+                    2015237070496640117,
+                    9726796267867880160,
+                    1027757632804501100,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13582905450409366561,
+                    15507861272000364970,
+                    1968190444234000350,
+                    1285018054152646401,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17889179132182358876,
+                    5115024019486483876,
+                    15342782898960069516,
+                    1073648957884283390,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16260344519270012046,
+                    12498043559246573504,
+                    12140247551392422093,
+                    1123064161171397345,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14881609260230716631,
+                    15277486976091952290,
+                    8405818240780804403,
+                    989816803084773858,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2427558552808240059,
+                    17809916307616337934,
+                    200898791807699725,
+                    1116551393494533410,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5881361354909080020,
+                    680939361666230985,
+                    12488513501171810308,
+                    312327920852796752,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2087784782139868996,
+                    15215670308873237375,
+                    1341150409092731521,
+                    660550041121556047,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8259847601256957827,
+                    18375825551283255265,
+                    13433007094922410509,
+                    1318903710208525508,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10870702505096099298,
+                    18292143660465009740,
+                    2894767503134604048,
+                    1287445293760664318,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8115180663329906172,
+                    16506861957486356277,
+                    8527421093243963619,
+                    1192894229956357675,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5020282068811019132,
+                    6589016057264668907,
+                    16991958137522905249,
+                    299407731513868046,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14572988496279558311,
+                    16464038234878169655,
+                    827059905585352980,
+                    715633378271018735,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4480510082917808736,
+                    17759726713888888283,
+                    645568087443964076,
+                    583066188933581298,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15013240731828373694,
+                    11929772433158682435,
+                    16844727536479582149,
+                    134526013268619028,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3665885315470103061,
+                    14450447990169269056,
+                    18163264792614420332,
+                    711411943126568895,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9636132261424543305,
+                    2519631366749344723,
+                    8685834888488581570,
+                    83301965492670073,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17410285678529913815,
+                    3442488392277769518,
+                    1122269907821640934,
+                    1225927594249460916,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8686580989248257828,
+                    14356339350046918606,
+                    7399016679528617473,
+                    215982256968773122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7004837335231132288,
+                    5552357652619993455,
+                    2224969653543100717,
+                    956567832838161192,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8442259208713262083,
+                    12532576871483949370,
+                    2845155768001510337,
+                    1262324680370561727,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11399153575025370304,
+                    10348387006696177918,
+                    15623232126886412587,
+                    1255732666438708152,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9032229770308309437,
+                    3629957979443512637,
+                    10692655369756964797,
+                    249393105487995182,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7488743577524288591,
+                    5341109538245604068,
+                    14255832257276747992,
+                    1270661064185643336,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8115180663329906172,
+                    16506861957486356277,
+                    8527421093243963619,
+                    1192894229956357675,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14688491614707124465,
+                    11411959488784022277,
+                    6303585410207898175,
+                    987826092463790588,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17662393960246793110,
+                    9816881902584028292,
+                    4433535131628929811,
+                    891622300927289484,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3110729295673232873,
+                    18169010825357548009,
+                    273821573433957377,
+                    90535287676617341,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10511476149926324487,
+                    17427555085296485407,
+                    329559241357618999,
+                    701865966472818266,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11697540815985407085,
+                    8678115145202586324,
+                    1319656428007844025,
+                    700546060357868314,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10432231744598873183,
+                    14793159930422798473,
+                    17062931245570166590,
+                    1315174388383582729,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2456440421588241155,
+                    212632352359576089,
+                    12828787874184478034,
+                    242415489684114000,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2042922217346377685,
+                    5523037559643996988,
+                    9041987493034614606,
+                    1232099641807143095,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10781269959006619934,
+                    14023941999000197722,
+                    6947389346434708520,
+                    611797376395075972,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12329434815466807438,
+                    10516170077504746878,
+                    12542712985647342074,
+                    49167968701700494,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7581100457130748028,
+                    4261148773632251160,
+                    17324470923461703704,
+                    1205980042520457767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2375629152476024257,
+                    15496788363627696615,
+                    1045537159697104165,
+                    903232989158747145,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2440167098020821179,
+                    10530103943747239562,
+                    12553088053708993682,
+                    483284485349902880,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5046021349688480914,
+                    4734743108457463904,
+                    6415591163891973236,
+                    1126922084699145732,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5020282068811019132,
+                    6589016057264668907,
+                    16991958137522905249,
+                    299407731513868046,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17662393960246793110,
+                    9816881902584028292,
+                    4433535131628929811,
+                    891622300927289484,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2104438887188632756,
+                    11435012040021085210,
+                    5728700519278442481,
+                    215299222633518262,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15475082591481464585,
+                    956125910441153947,
+                    14694161994156789202,
+                    958337067337610622,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16840160271708691063,
+                    5276767113857566079,
+                    18011620354015025245,
+                    861111947841244113,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16442821843606446565,
+                    6668964228230674740,
+                    13154998091172617036,
+                    1299487285606645618,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8083432510560508704,
+                    14749073432715851283,
+                    2515020289059874200,
+                    1142447242049845142,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12099938902784089218,
+                    8711442452827373791,
+                    4337262245801905136,
+                    58119805386889008,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6177611090930225459,
+                    164580403194964914,
+                    18361702155616138138,
+                    1130559891112332135,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6062111586831631256,
+                    5337490397154020151,
+                    12137402690601734645,
+                    1256446843932394520,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3762735209266005229,
+                    13927737427751491574,
+                    14290814398236574433,
+                    1051164571774743065,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5253617786208746833,
+                    1062257399922146596,
+                    11243061650262856061,
+                    794152049755357645,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10125946588003169577,
+                    9294331698863906825,
+                    10898481382486692853,
+                    597187414061379252,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14870333922145015074,
+                    656138745421375973,
+                    17538447819444553446,
+                    957892163216429715,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9684284087945507168,
+                    12660410381795222838,
+                    7563047808192444790,
+                    933269937074053108,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14572988496279558311,
+                    16464038234878169655,
+                    827059905585352980,
+                    715633378271018735,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3110729295673232873,
+                    18169010825357548009,
+                    273821573433957377,
+                    90535287676617341,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15475082591481464585,
+                    956125910441153947,
+                    14694161994156789202,
+                    958337067337610622,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16321871688645484670,
+                    15750162988866361413,
+                    8656329074450596,
+                    455923823315847601,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13312783204852362821,
+                    5091565756539068967,
+                    4000020347186017225,
+                    889393528209467976,
+                ]),
+                Fq::from_montgomery_limbs([
+                    194546235193896570,
+                    8293206092028930793,
+                    5462171908692006193,
+                    806055130033872321,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17924450488449102757,
+                    9132396116401973277,
+                    8179386657829208608,
+                    975421893896034026,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10555607179932395769,
+                    10968335804580479482,
+                    11776956015940641393,
+                    119274857610590412,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18232977258267926513,
+                    17051497679393552565,
+                    5856835029170000580,
+                    694442958733961163,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2982779333648657541,
+                    11228127021048051527,
+                    10348957052869784173,
+                    1339019747142383616,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14707630587482699544,
+                    18111945122980033311,
+                    5196442496764293932,
+                    532209540890969173,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5965791848933318952,
+                    7267682762681496223,
+                    11982739236613719710,
+                    741903508948021205,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3761532155894249073,
+                    10179669234396236265,
+                    9820218808277852221,
+                    1078295412599212950,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5771305982825354925,
+                    14892350011024741438,
+                    6595614239628501476,
+                    455710061552445301,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11052655090578899826,
+                    1075172265760265751,
+                    16175258937310166875,
+                    1280592509669276564,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4480510082917808736,
+                    17759726713888888283,
+                    645568087443964076,
+                    583066188933581298,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10511476149926324487,
+                    17427555085296485407,
+                    329559241357618999,
+                    701865966472818266,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16840160271708691063,
+                    5276767113857566079,
+                    18011620354015025245,
+                    861111947841244113,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13312783204852362821,
+                    5091565756539068967,
+                    4000020347186017225,
+                    889393528209467976,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16444035348938159478,
+                    18005281552733587887,
+                    13669258962544583845,
+                    49703964253132163,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9837717289768236687,
+                    17543218843385530421,
+                    4556886628112550457,
+                    1217647260737591888,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2993782853898448196,
+                    7020615774178771985,
+                    2177703259771730659,
+                    54991692071020790,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10393876440231526194,
+                    18135452443957132976,
+                    1449435139425523756,
+                    288379020890799820,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2819571348906839568,
+                    17115268384212163617,
+                    3920908679505508701,
+                    320760316005721563,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3954142270918184224,
+                    15125867105266556909,
+                    10181199163101670473,
+                    946786955265495713,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7897900599522171945,
+                    8116196979456445557,
+                    3185399183705083225,
+                    349758273234365797,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2219311954012783507,
+                    11105587948464195588,
+                    12545455055818183571,
+                    627434358436798187,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13911343845518170006,
+                    7199449375764138406,
+                    834023895388223371,
+                    950684169619368985,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12299987538780793707,
+                    11603857281132694105,
+                    17312848279919712613,
+                    140507104767679,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5967407451947021263,
+                    9691019300551280324,
+                    11484580379433096913,
+                    1209801568877094757,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15013240731828373694,
+                    11929772433158682435,
+                    16844727536479582149,
+                    134526013268619028,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11697540815985407085,
+                    8678115145202586324,
+                    1319656428007844025,
+                    700546060357868314,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16442821843606446565,
+                    6668964228230674740,
+                    13154998091172617036,
+                    1299487285606645618,
+                ]),
+                Fq::from_montgomery_limbs([
+                    194546235193896570,
+                    8293206092028930793,
+                    5462171908692006193,
+                    806055130033872321,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9837717289768236687,
+                    17543218843385530421,
+                    4556886628112550457,
+                    1217647260737591888,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13181105248235887109,
+                    16676933926921238382,
+                    5213711940265123286,
+                    473478550915563065,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2060641015307962365,
+                    16374640045893482662,
+                    17470053410871811297,
+                    696100846603277370,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12573078587285925984,
+                    10776325602296766620,
+                    8433109672002151976,
+                    1340569671584848270,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17805836456905229028,
+                    11912016591951297560,
+                    16503374281955108399,
+                    127823865179607677,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13248708040759464180,
+                    12819333612488319201,
+                    252349161962866537,
+                    1181991963953944331,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9522636378250048392,
+                    6909979098462495020,
+                    10964368310211921829,
+                    745093357247356842,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3854079243507445075,
+                    18193937427611191806,
+                    3912943871864388946,
+                    1211024694532618107,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7681569419602669194,
+                    14241871374851720013,
+                    16112440021414214890,
+                    1316882639102467424,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11475515565310754470,
+                    18399915100911932950,
+                    12844751979360592066,
+                    1180751573743812060,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18174127066981285596,
+                    2015237070496640117,
+                    9726796267867880160,
+                    1027757632804501100,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3665885315470103061,
+                    14450447990169269056,
+                    18163264792614420332,
+                    711411943126568895,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10432231744598873183,
+                    14793159930422798473,
+                    17062931245570166590,
+                    1315174388383582729,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8083432510560508704,
+                    14749073432715851283,
+                    2515020289059874200,
+                    1142447242049845142,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17924450488449102757,
+                    9132396116401973277,
+                    8179386657829208608,
+                    975421893896034026,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2993782853898448196,
+                    7020615774178771985,
+                    2177703259771730659,
+                    54991692071020790,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2060641015307962365,
+                    16374640045893482662,
+                    17470053410871811297,
+                    696100846603277370,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16685959918414162492,
+                    9742095701008479705,
+                    15487052098239992425,
+                    601770020810692766,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16717010299518758899,
+                    17673318299820293419,
+                    1977471339498124194,
+                    873594558711395571,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18364556838883306823,
+                    13782517174072118688,
+                    14652552446618108683,
+                    844763161720518889,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13646080363873545668,
+                    14636166616185024042,
+                    6916447028806070443,
+                    1334292241067907065,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7762208216781324892,
+                    11086538536178252231,
+                    16315971971132805910,
+                    630085743442420232,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3254083509534896999,
+                    5966599094293350620,
+                    17531592113694315688,
+                    548929962967144526,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11320810401912518628,
+                    6686468388120302555,
+                    10029607691020497819,
+                    117245037297751382,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8093380926599746645,
+                    10019024240338768696,
+                    6085260973688889292,
+                    89479442157065060,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13582905450409366561,
+                    15507861272000364970,
+                    1968190444234000350,
+                    1285018054152646401,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9636132261424543305,
+                    2519631366749344723,
+                    8685834888488581570,
+                    83301965492670073,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2456440421588241155,
+                    212632352359576089,
+                    12828787874184478034,
+                    242415489684114000,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12099938902784089218,
+                    8711442452827373791,
+                    4337262245801905136,
+                    58119805386889008,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10555607179932395769,
+                    10968335804580479482,
+                    11776956015940641393,
+                    119274857610590412,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10393876440231526194,
+                    18135452443957132976,
+                    1449435139425523756,
+                    288379020890799820,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12573078587285925984,
+                    10776325602296766620,
+                    8433109672002151976,
+                    1340569671584848270,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16717010299518758899,
+                    17673318299820293419,
+                    1977471339498124194,
+                    873594558711395571,
+                ]),
+                Fq::from_montgomery_limbs([
+                    107628062528486518,
+                    13557113651286740291,
+                    12831564782383893295,
+                    254203059889911485,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5630217296922179625,
+                    10001598592893411236,
+                    14159821510518188524,
+                    264595905093851903,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2177227425976239253,
+                    6005629861802208221,
+                    8868888024531194117,
+                    879132829497527986,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13393246747139988431,
+                    4942604891722488491,
+                    1012594850730850805,
+                    1246193668935612071,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18424689599565389915,
+                    9529568872472476318,
+                    9982143478322206966,
+                    830107297772979945,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10591267681613193878,
+                    15080105937033776092,
+                    14071945438003863659,
+                    211353504681570101,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5324022110125893680,
+                    12180300771150324341,
+                    10677538729293468484,
+                    1163086008666146409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17889179132182358876,
+                    5115024019486483876,
+                    15342782898960069516,
+                    1073648957884283390,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17410285678529913815,
+                    3442488392277769518,
+                    1122269907821640934,
+                    1225927594249460916,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2042922217346377685,
+                    5523037559643996988,
+                    9041987493034614606,
+                    1232099641807143095,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6177611090930225459,
+                    164580403194964914,
+                    18361702155616138138,
+                    1130559891112332135,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18232977258267926513,
+                    17051497679393552565,
+                    5856835029170000580,
+                    694442958733961163,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2819571348906839568,
+                    17115268384212163617,
+                    3920908679505508701,
+                    320760316005721563,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17805836456905229028,
+                    11912016591951297560,
+                    16503374281955108399,
+                    127823865179607677,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18364556838883306823,
+                    13782517174072118688,
+                    14652552446618108683,
+                    844763161720518889,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5630217296922179625,
+                    10001598592893411236,
+                    14159821510518188524,
+                    264595905093851903,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7482173942850666164,
+                    5576865486770977687,
+                    13800233171399680429,
+                    1213066540894527598,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2572312833895769502,
+                    16758718391003094723,
+                    3594031352043942648,
+                    97185676088136361,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18241130365157500055,
+                    11392649686884095234,
+                    2091067893907153434,
+                    106313882754217047,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8987108570298355133,
+                    7007488674683910304,
+                    2359120197757982416,
+                    258592140203371878,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3409371768115750509,
+                    8690348871220435802,
+                    7469366512757463962,
+                    1291562974830571556,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3232974858321351247,
+                    10375202013144361120,
+                    7474570621090609609,
+                    50178321366384874,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16260344519270012046,
+                    12498043559246573504,
+                    12140247551392422093,
+                    1123064161171397345,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8686580989248257828,
+                    14356339350046918606,
+                    7399016679528617473,
+                    215982256968773122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10781269959006619934,
+                    14023941999000197722,
+                    6947389346434708520,
+                    611797376395075972,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6062111586831631256,
+                    5337490397154020151,
+                    12137402690601734645,
+                    1256446843932394520,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2982779333648657541,
+                    11228127021048051527,
+                    10348957052869784173,
+                    1339019747142383616,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3954142270918184224,
+                    15125867105266556909,
+                    10181199163101670473,
+                    946786955265495713,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13248708040759464180,
+                    12819333612488319201,
+                    252349161962866537,
+                    1181991963953944331,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13646080363873545668,
+                    14636166616185024042,
+                    6916447028806070443,
+                    1334292241067907065,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2177227425976239253,
+                    6005629861802208221,
+                    8868888024531194117,
+                    879132829497527986,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2572312833895769502,
+                    16758718391003094723,
+                    3594031352043942648,
+                    97185676088136361,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8675876566502384366,
+                    5306044282897785118,
+                    17685563855380198658,
+                    217974904886056111,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12736464702717839988,
+                    3958607779359886083,
+                    4468121679943262885,
+                    527921122122401650,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12551012134372429025,
+                    9138483427158676089,
+                    4594069410442897632,
+                    1288699919446573308,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15090947536878980551,
+                    2332885851460365608,
+                    7076237526631787541,
+                    1258748967492249643,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12951193646936875730,
+                    18400598786218319577,
+                    17584019970178708013,
+                    764481309129532907,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14881609260230716631,
+                    15277486976091952290,
+                    8405818240780804403,
+                    989816803084773858,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7004837335231132288,
+                    5552357652619993455,
+                    2224969653543100717,
+                    956567832838161192,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12329434815466807438,
+                    10516170077504746878,
+                    12542712985647342074,
+                    49167968701700494,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3762735209266005229,
+                    13927737427751491574,
+                    14290814398236574433,
+                    1051164571774743065,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14707630587482699544,
+                    18111945122980033311,
+                    5196442496764293932,
+                    532209540890969173,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7897900599522171945,
+                    8116196979456445557,
+                    3185399183705083225,
+                    349758273234365797,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9522636378250048392,
+                    6909979098462495020,
+                    10964368310211921829,
+                    745093357247356842,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7762208216781324892,
+                    11086538536178252231,
+                    16315971971132805910,
+                    630085743442420232,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13393246747139988431,
+                    4942604891722488491,
+                    1012594850730850805,
+                    1246193668935612071,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18241130365157500055,
+                    11392649686884095234,
+                    2091067893907153434,
+                    106313882754217047,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12736464702717839988,
+                    3958607779359886083,
+                    4468121679943262885,
+                    527921122122401650,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7724699021640803311,
+                    3236536685850486747,
+                    7096309113934762500,
+                    1191350826085570580,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            M_i: Matrix::<16, 16, 256>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    6977918620786825966,
+                    16214836549150387416,
+                    10197212961234077588,
+                    975536841794729709,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6740353358800110324,
+                    12712785154650035019,
+                    13269778685966336346,
+                    634991271669047103,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16960489071951544597,
+                    8041419406601741653,
+                    10579653720295519621,
+                    374757608248456370,
+                ]),
+                Fq::from_montgomery_limbs([
+                    45097781709125281,
+                    3120504907554149502,
+                    153884468008786356,
+                    739084308268054764,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7986358726117469116,
+                    11295132026284990101,
+                    10745533261029473050,
+                    631573840039788909,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3939355611876927037,
+                    13208814531404909272,
+                    6774956048591263867,
+                    782912582749146617,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2185789325081722359,
+                    10198629743808085416,
+                    16525027160259597439,
+                    806926024684828597,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1039866025386359861,
+                    2963567852532519375,
+                    9900315424511599686,
+                    649265394038177053,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16123685677174967426,
+                    8786553128791457929,
+                    11760750208260511147,
+                    1253657896846133399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15006528347541269901,
+                    11438067263378978051,
+                    3391036339207695211,
+                    173095276882438311,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16120024810679592654,
+                    14692136836086733616,
+                    3188103767946357120,
+                    1087850275377885995,
+                ]),
+                Fq::from_montgomery_limbs([
+                    632720164756945991,
+                    16049827085754550001,
+                    12029586569829723925,
+                    651849635138218331,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14960493808208294335,
+                    5775107246590901215,
+                    9510916679812390128,
+                    160494483098066538,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3964426727557602355,
+                    5064361255506059137,
+                    5678584997783269734,
+                    1218033554445400627,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9468740620313585033,
+                    11801558903525033873,
+                    11150256986239891467,
+                    938522954066225711,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    6740353358800110324,
+                    12712785154650035019,
+                    13269778685966336346,
+                    634991271669047103,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14168960373610185170,
+                    17076619868301584791,
+                    2283689821619721332,
+                    842402035015777093,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2535512103114195893,
+                    6940572649071405591,
+                    17044396742104156649,
+                    175358033909939815,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10341203579140295888,
+                    8722359005340854974,
+                    8379583429947769841,
+                    641019435456091010,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1750379650250618038,
+                    9966213571707374198,
+                    4914169552318201046,
+                    201639622913349979,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9018743430873558659,
+                    1317280198651584705,
+                    17813361833867990080,
+                    1268872052614922021,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15489869603406149971,
+                    15883427509448392877,
+                    2886329733198045961,
+                    592400099380940012,
+                ]),
+                Fq::from_montgomery_limbs([
+                    891034862722815890,
+                    13035355338046964233,
+                    17899075063243338290,
+                    229803044769026587,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15109195535292190543,
+                    9377159316528887636,
+                    13670194207414941026,
+                    1106195406885639669,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12621564202126686879,
+                    342642071982290787,
+                    17538042720495802103,
+                    435615752989439788,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3423703830446901699,
+                    14138239106481054014,
+                    9988646885736212664,
+                    379835450390782586,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8255095612822223605,
+                    5499545242786721799,
+                    11870287754539512434,
+                    1003272862740469471,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3234972901805613138,
+                    11347118949585570779,
+                    12811853553743804944,
+                    1203655562369291006,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2271306720216727119,
+                    3766840709406935772,
+                    1029299891966669106,
+                    941699822839499069,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5108927484451137634,
+                    16182400996843095891,
+                    17446462716989652840,
+                    815393078055929287,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    16960489071951544597,
+                    8041419406601741653,
+                    10579653720295519621,
+                    374757608248456370,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2535512103114195893,
+                    6940572649071405591,
+                    17044396742104156649,
+                    175358033909939815,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5799530353259096752,
+                    9355623798420199445,
+                    14938201402953505280,
+                    327770305143616875,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2747690150716526629,
+                    6209510923198077436,
+                    13549950506895605439,
+                    1187968313539893731,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14336460662261234600,
+                    8211455990921294336,
+                    9253554292991315099,
+                    169112187926310376,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6934849420706105720,
+                    6280799488449439469,
+                    6322389457028355372,
+                    1184493925250123251,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8069109871812459179,
+                    466092928643460872,
+                    7192530522574750373,
+                    664677921314752854,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17927639964905993318,
+                    3702450342103775780,
+                    9902322246390544585,
+                    823082476356823367,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5346384245224596670,
+                    18090538143613961485,
+                    14341153534791877403,
+                    483390363110733786,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9645230102108421012,
+                    7840029556914035991,
+                    8768995392834558641,
+                    595852076538791103,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8465700637872881105,
+                    1181267197456638878,
+                    9102958901653206430,
+                    491598095455374221,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3468779244607594924,
+                    3893702585361062029,
+                    17361677079040830680,
+                    289843181840141260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10096222163823092858,
+                    8487201394280731308,
+                    495731370858247137,
+                    876001306795206526,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9476447531710761579,
+                    5774783531380862269,
+                    11943569834906511188,
+                    820159890707369242,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8126029176630242517,
+                    5760514380640919642,
+                    4359774380913060066,
+                    250337604607633537,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    45097781709125281,
+                    3120504907554149502,
+                    153884468008786356,
+                    739084308268054764,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10341203579140295888,
+                    8722359005340854974,
+                    8379583429947769841,
+                    641019435456091010,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2747690150716526629,
+                    6209510923198077436,
+                    13549950506895605439,
+                    1187968313539893731,
+                ]),
+                Fq::from_montgomery_limbs([
+                    97947657338829689,
+                    10735500445145041653,
+                    2519938729867272397,
+                    301580988158315960,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1751997077233257956,
+                    14280528862108384090,
+                    7532585665133089237,
+                    1284509032275333320,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6936316190109891450,
+                    12580461915914114619,
+                    15882654089767672507,
+                    930954426815915433,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8630918437662861613,
+                    13282613704764705188,
+                    6775426987276748950,
+                    756714839693510675,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2697103395627241533,
+                    15126716567616635894,
+                    5335814928124006665,
+                    25614771317958286,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6151519694202598709,
+                    17587800724156272498,
+                    5942197911443629537,
+                    1277107049990436133,
+                ]),
+                Fq::from_montgomery_limbs([
+                    999420167800488329,
+                    16753155354558108289,
+                    3434447525492699555,
+                    980139198529824744,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10813569304667167984,
+                    17168325073763537024,
+                    7911449018632783351,
+                    423749996938109188,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8500315741919734037,
+                    6545339156231150484,
+                    11370893894496554666,
+                    774594305003945370,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9117231759993558972,
+                    11837482454329779924,
+                    7690862200938605050,
+                    668043237774865238,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1253575155022475698,
+                    3436683396903639252,
+                    11343747128792784206,
+                    1332153211362479517,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7181725989305784219,
+                    5335626784367090276,
+                    14442294871096865759,
+                    1230339250799339478,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7986358726117469116,
+                    11295132026284990101,
+                    10745533261029473050,
+                    631573840039788909,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1750379650250618038,
+                    9966213571707374198,
+                    4914169552318201046,
+                    201639622913349979,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14336460662261234600,
+                    8211455990921294336,
+                    9253554292991315099,
+                    169112187926310376,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1751997077233257956,
+                    14280528862108384090,
+                    7532585665133089237,
+                    1284509032275333320,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16092753797207333576,
+                    13653162728685398076,
+                    3716290898191624354,
+                    646702726516637729,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9312484829836481397,
+                    8681118256379068401,
+                    3247445330104929267,
+                    531314045999466917,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18188233626415861598,
+                    17980320257148540861,
+                    15525924021614937597,
+                    1169307376333838675,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16437282302283173150,
+                    16749236554514411589,
+                    9625803923748782621,
+                    850282240683597440,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3159826342701565236,
+                    578194347215806493,
+                    11151263491913139483,
+                    114614953277058589,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14176679300396137812,
+                    3099747700109598042,
+                    9888606160222161351,
+                    868398209500547623,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16231790247139977205,
+                    14691122760692765033,
+                    6594839879440932392,
+                    436959895980545882,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11307068764462521220,
+                    1052416556436769566,
+                    12432980473647020194,
+                    214359356999724293,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11430041999327999903,
+                    4322719866968985279,
+                    7581975735650263169,
+                    956287702006765154,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3683440492750234653,
+                    7624430428767115384,
+                    5922376497741908134,
+                    699106867879732223,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13044023159701058765,
+                    2512961708252133082,
+                    12220828279407620623,
+                    564422781402345968,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3939355611876927037,
+                    13208814531404909272,
+                    6774956048591263867,
+                    782912582749146617,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9018743430873558659,
+                    1317280198651584705,
+                    17813361833867990080,
+                    1268872052614922021,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6934849420706105720,
+                    6280799488449439469,
+                    6322389457028355372,
+                    1184493925250123251,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6936316190109891450,
+                    12580461915914114619,
+                    15882654089767672507,
+                    930954426815915433,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9312484829836481397,
+                    8681118256379068401,
+                    3247445330104929267,
+                    531314045999466917,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6598274567186547805,
+                    11014162982509082048,
+                    12093202343280791297,
+                    1137927848764252566,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17386650011237380383,
+                    5072758474791651095,
+                    3971506782977232472,
+                    910298319778587770,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2805846675550109171,
+                    14157679615565967205,
+                    12445954962881264619,
+                    933036040211430952,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7579247648101713545,
+                    8982146479986094458,
+                    733051019393301709,
+                    644429511965753577,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8291415331670897600,
+                    1385271014845676188,
+                    1345144775283711540,
+                    704547468364670639,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12184873177882036677,
+                    10003871049383638160,
+                    10106849320235338849,
+                    699804123177580417,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13265913686290239633,
+                    15195037254699470392,
+                    11508624094507528330,
+                    705188462218429840,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14792487370068175463,
+                    1263718190164501015,
+                    1060159715829858103,
+                    593507671673499851,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15682819016017162844,
+                    11463698621363466367,
+                    18082009447768892425,
+                    382488860644179211,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2746485232226687222,
+                    2215379257737300325,
+                    12772545472663663399,
+                    510310375743386534,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2185789325081722359,
+                    10198629743808085416,
+                    16525027160259597439,
+                    806926024684828597,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15489869603406149971,
+                    15883427509448392877,
+                    2886329733198045961,
+                    592400099380940012,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8069109871812459179,
+                    466092928643460872,
+                    7192530522574750373,
+                    664677921314752854,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8630918437662861613,
+                    13282613704764705188,
+                    6775426987276748950,
+                    756714839693510675,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18188233626415861598,
+                    17980320257148540861,
+                    15525924021614937597,
+                    1169307376333838675,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17386650011237380383,
+                    5072758474791651095,
+                    3971506782977232472,
+                    910298319778587770,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12276493640815305982,
+                    17289018612900192581,
+                    10457683957305955162,
+                    1259031655350737246,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18385344849545129420,
+                    1312452474726657748,
+                    12878653970581891661,
+                    741734781984984819,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14862494620267025902,
+                    14726219194801396248,
+                    6808985238641662184,
+                    1130713069928769672,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9168555137723465281,
+                    3647703074832666669,
+                    18005135613253236575,
+                    508794273577370749,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1676484378834657456,
+                    13255917849968107018,
+                    17871839007069155819,
+                    56362247146018756,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15408232014224788666,
+                    12293535888372330161,
+                    3611613833292076990,
+                    705933667467871024,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4888053754512294763,
+                    1442103220037717529,
+                    13420261370012002026,
+                    1259079573639219651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6000175447823611497,
+                    6155239707067910821,
+                    16824768795074846633,
+                    66660656485713221,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16505199246970688885,
+                    2363197261532395647,
+                    12221785760177045402,
+                    175141177863662877,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1039866025386359861,
+                    2963567852532519375,
+                    9900315424511599686,
+                    649265394038177053,
+                ]),
+                Fq::from_montgomery_limbs([
+                    891034862722815890,
+                    13035355338046964233,
+                    17899075063243338290,
+                    229803044769026587,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17927639964905993318,
+                    3702450342103775780,
+                    9902322246390544585,
+                    823082476356823367,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2697103395627241533,
+                    15126716567616635894,
+                    5335814928124006665,
+                    25614771317958286,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16437282302283173150,
+                    16749236554514411589,
+                    9625803923748782621,
+                    850282240683597440,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2805846675550109171,
+                    14157679615565967205,
+                    12445954962881264619,
+                    933036040211430952,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18385344849545129420,
+                    1312452474726657748,
+                    12878653970581891661,
+                    741734781984984819,
+                ]),
+                Fq::from_montgomery_limbs([
+                    531537160846695417,
+                    2702807758388460069,
+                    2989979326761204034,
+                    871854540183445984,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12042762014017075127,
+                    9597049569747443049,
+                    6482590006586235747,
+                    675019764291925915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14864273131980100400,
+                    1956110576831725611,
+                    10081972245409293325,
+                    666955485209928843,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4536493120414551021,
+                    8129555076681468544,
+                    17173986269081223408,
+                    278916112854252584,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7125192488478108110,
+                    6495646011209147602,
+                    6042586462323212127,
+                    27509864176858895,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15948584362669189412,
+                    7260859319223672840,
+                    17511214221429672653,
+                    226566230522603539,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7148272344213902517,
+                    17326196516807484701,
+                    2878938561001488200,
+                    1322875619631750334,
+                ]),
+                Fq::from_montgomery_limbs([
+                    569867301318423739,
+                    3882081805517020611,
+                    9012363070378929745,
+                    411786076648338986,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    16123685677174967426,
+                    8786553128791457929,
+                    11760750208260511147,
+                    1253657896846133399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15109195535292190543,
+                    9377159316528887636,
+                    13670194207414941026,
+                    1106195406885639669,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5346384245224596670,
+                    18090538143613961485,
+                    14341153534791877403,
+                    483390363110733786,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6151519694202598709,
+                    17587800724156272498,
+                    5942197911443629537,
+                    1277107049990436133,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3159826342701565236,
+                    578194347215806493,
+                    11151263491913139483,
+                    114614953277058589,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7579247648101713545,
+                    8982146479986094458,
+                    733051019393301709,
+                    644429511965753577,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14862494620267025902,
+                    14726219194801396248,
+                    6808985238641662184,
+                    1130713069928769672,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12042762014017075127,
+                    9597049569747443049,
+                    6482590006586235747,
+                    675019764291925915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6024191204230512447,
+                    7355115038832006911,
+                    2033484454134977929,
+                    1118537920799612983,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6295224754815099131,
+                    3681101581439616131,
+                    7776723588870569630,
+                    575986377764035573,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18335743801255891723,
+                    10450969841650855048,
+                    10184850241689932280,
+                    965789038423785614,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3594268644990704529,
+                    3737321572244299667,
+                    9856210818708652318,
+                    1185265744826807484,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13584701614541831460,
+                    4503594460025750952,
+                    5803936838624168206,
+                    139484511301269071,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8379215557723284757,
+                    6527177349590260299,
+                    17201546608214844834,
+                    949289087219093421,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4127262917101710797,
+                    10509640345267605179,
+                    16064526043161929782,
+                    1247681650639754508,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15006528347541269901,
+                    11438067263378978051,
+                    3391036339207695211,
+                    173095276882438311,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12621564202126686879,
+                    342642071982290787,
+                    17538042720495802103,
+                    435615752989439788,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9645230102108421012,
+                    7840029556914035991,
+                    8768995392834558641,
+                    595852076538791103,
+                ]),
+                Fq::from_montgomery_limbs([
+                    999420167800488329,
+                    16753155354558108289,
+                    3434447525492699555,
+                    980139198529824744,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14176679300396137812,
+                    3099747700109598042,
+                    9888606160222161351,
+                    868398209500547623,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8291415331670897600,
+                    1385271014845676188,
+                    1345144775283711540,
+                    704547468364670639,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9168555137723465281,
+                    3647703074832666669,
+                    18005135613253236575,
+                    508794273577370749,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14864273131980100400,
+                    1956110576831725611,
+                    10081972245409293325,
+                    666955485209928843,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6295224754815099131,
+                    3681101581439616131,
+                    7776723588870569630,
+                    575986377764035573,
+                ]),
+                Fq::from_montgomery_limbs([
+                    78026649728710997,
+                    6388016997672812027,
+                    14853646195783357460,
+                    513119820560353354,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6118823737632014507,
+                    6851733667814789736,
+                    1859828598219069015,
+                    344674130203763474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7268575099990025432,
+                    13644241865590951995,
+                    16322711015034153657,
+                    461730602574271558,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10928817730889232325,
+                    16338814767099641171,
+                    10266422207561887339,
+                    36599772999668665,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7282289322435802314,
+                    16662121952788863791,
+                    9010669694499596436,
+                    570666116163317739,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17532995796561796059,
+                    2993593328874431238,
+                    156473973795202761,
+                    1181368866110144833,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    16120024810679592654,
+                    14692136836086733616,
+                    3188103767946357120,
+                    1087850275377885995,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3423703830446901699,
+                    14138239106481054014,
+                    9988646885736212664,
+                    379835450390782586,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8465700637872881105,
+                    1181267197456638878,
+                    9102958901653206430,
+                    491598095455374221,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10813569304667167984,
+                    17168325073763537024,
+                    7911449018632783351,
+                    423749996938109188,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16231790247139977205,
+                    14691122760692765033,
+                    6594839879440932392,
+                    436959895980545882,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12184873177882036677,
+                    10003871049383638160,
+                    10106849320235338849,
+                    699804123177580417,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1676484378834657456,
+                    13255917849968107018,
+                    17871839007069155819,
+                    56362247146018756,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4536493120414551021,
+                    8129555076681468544,
+                    17173986269081223408,
+                    278916112854252584,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18335743801255891723,
+                    10450969841650855048,
+                    10184850241689932280,
+                    965789038423785614,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6118823737632014507,
+                    6851733667814789736,
+                    1859828598219069015,
+                    344674130203763474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2752523897111761544,
+                    17211197650376464128,
+                    10332179442781840716,
+                    911326860938979209,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15922060490401607144,
+                    14534689567467236857,
+                    13761933530985477894,
+                    222615579680320310,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12054909362810180474,
+                    3307960941011968401,
+                    5204575748791684792,
+                    762059719242778326,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3858701844207961600,
+                    2095634161654533478,
+                    1977267627020441078,
+                    1190670489007154939,
+                ]),
+                Fq::from_montgomery_limbs([
+                    404424548444853299,
+                    924894877118082528,
+                    10039039478485844975,
+                    289583491652389165,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    632720164756945991,
+                    16049827085754550001,
+                    12029586569829723925,
+                    651849635138218331,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8255095612822223605,
+                    5499545242786721799,
+                    11870287754539512434,
+                    1003272862740469471,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3468779244607594924,
+                    3893702585361062029,
+                    17361677079040830680,
+                    289843181840141260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8500315741919734037,
+                    6545339156231150484,
+                    11370893894496554666,
+                    774594305003945370,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11307068764462521220,
+                    1052416556436769566,
+                    12432980473647020194,
+                    214359356999724293,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13265913686290239633,
+                    15195037254699470392,
+                    11508624094507528330,
+                    705188462218429840,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15408232014224788666,
+                    12293535888372330161,
+                    3611613833292076990,
+                    705933667467871024,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7125192488478108110,
+                    6495646011209147602,
+                    6042586462323212127,
+                    27509864176858895,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3594268644990704529,
+                    3737321572244299667,
+                    9856210818708652318,
+                    1185265744826807484,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7268575099990025432,
+                    13644241865590951995,
+                    16322711015034153657,
+                    461730602574271558,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15922060490401607144,
+                    14534689567467236857,
+                    13761933530985477894,
+                    222615579680320310,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16163801670513173458,
+                    3600188966523569075,
+                    3092276081868017637,
+                    319564770483502573,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5877574388865038702,
+                    16228470959974214750,
+                    1712538875324197231,
+                    1076456320572719289,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11287117484739627507,
+                    6299478355652718007,
+                    1480134668251312806,
+                    1180377707221737169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12780477720771652939,
+                    1847738178099461685,
+                    17390164308024248835,
+                    349042411558106396,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14960493808208294335,
+                    5775107246590901215,
+                    9510916679812390128,
+                    160494483098066538,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3234972901805613138,
+                    11347118949585570779,
+                    12811853553743804944,
+                    1203655562369291006,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10096222163823092858,
+                    8487201394280731308,
+                    495731370858247137,
+                    876001306795206526,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9117231759993558972,
+                    11837482454329779924,
+                    7690862200938605050,
+                    668043237774865238,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11430041999327999903,
+                    4322719866968985279,
+                    7581975735650263169,
+                    956287702006765154,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14792487370068175463,
+                    1263718190164501015,
+                    1060159715829858103,
+                    593507671673499851,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4888053754512294763,
+                    1442103220037717529,
+                    13420261370012002026,
+                    1259079573639219651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15948584362669189412,
+                    7260859319223672840,
+                    17511214221429672653,
+                    226566230522603539,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13584701614541831460,
+                    4503594460025750952,
+                    5803936838624168206,
+                    139484511301269071,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10928817730889232325,
+                    16338814767099641171,
+                    10266422207561887339,
+                    36599772999668665,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12054909362810180474,
+                    3307960941011968401,
+                    5204575748791684792,
+                    762059719242778326,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5877574388865038702,
+                    16228470959974214750,
+                    1712538875324197231,
+                    1076456320572719289,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12462518352203106177,
+                    16193162658779859287,
+                    8602257383711694628,
+                    688406718925571524,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17566562773107717975,
+                    13223262788119456202,
+                    2357731297379527779,
+                    321092077869109634,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7489520034665094598,
+                    8725894553316135719,
+                    17443385638760421908,
+                    361036246825839127,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3964426727557602355,
+                    5064361255506059137,
+                    5678584997783269734,
+                    1218033554445400627,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2271306720216727119,
+                    3766840709406935772,
+                    1029299891966669106,
+                    941699822839499069,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9476447531710761579,
+                    5774783531380862269,
+                    11943569834906511188,
+                    820159890707369242,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1253575155022475698,
+                    3436683396903639252,
+                    11343747128792784206,
+                    1332153211362479517,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3683440492750234653,
+                    7624430428767115384,
+                    5922376497741908134,
+                    699106867879732223,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15682819016017162844,
+                    11463698621363466367,
+                    18082009447768892425,
+                    382488860644179211,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6000175447823611497,
+                    6155239707067910821,
+                    16824768795074846633,
+                    66660656485713221,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7148272344213902517,
+                    17326196516807484701,
+                    2878938561001488200,
+                    1322875619631750334,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8379215557723284757,
+                    6527177349590260299,
+                    17201546608214844834,
+                    949289087219093421,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7282289322435802314,
+                    16662121952788863791,
+                    9010669694499596436,
+                    570666116163317739,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3858701844207961600,
+                    2095634161654533478,
+                    1977267627020441078,
+                    1190670489007154939,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11287117484739627507,
+                    6299478355652718007,
+                    1480134668251312806,
+                    1180377707221737169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17566562773107717975,
+                    13223262788119456202,
+                    2357731297379527779,
+                    321092077869109634,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8478297715338312333,
+                    4597885080406739372,
+                    10403062399751596003,
+                    1082295726170686175,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4172773665021035902,
+                    3437110944150324671,
+                    4484971093201352924,
+                    1075358499888788732,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9468740620313585033,
+                    11801558903525033873,
+                    11150256986239891467,
+                    938522954066225711,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5108927484451137634,
+                    16182400996843095891,
+                    17446462716989652840,
+                    815393078055929287,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8126029176630242517,
+                    5760514380640919642,
+                    4359774380913060066,
+                    250337604607633537,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7181725989305784219,
+                    5335626784367090276,
+                    14442294871096865759,
+                    1230339250799339478,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13044023159701058765,
+                    2512961708252133082,
+                    12220828279407620623,
+                    564422781402345968,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2746485232226687222,
+                    2215379257737300325,
+                    12772545472663663399,
+                    510310375743386534,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16505199246970688885,
+                    2363197261532395647,
+                    12221785760177045402,
+                    175141177863662877,
+                ]),
+                Fq::from_montgomery_limbs([
+                    569867301318423739,
+                    3882081805517020611,
+                    9012363070378929745,
+                    411786076648338986,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4127262917101710797,
+                    10509640345267605179,
+                    16064526043161929782,
+                    1247681650639754508,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17532995796561796059,
+                    2993593328874431238,
+                    156473973795202761,
+                    1181368866110144833,
+                ]),
+                Fq::from_montgomery_limbs([
+                    404424548444853299,
+                    924894877118082528,
+                    10039039478485844975,
+                    289583491652389165,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12780477720771652939,
+                    1847738178099461685,
+                    17390164308024248835,
+                    349042411558106396,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7489520034665094598,
+                    8725894553316135719,
+                    17443385638760421908,
+                    361036246825839127,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4172773665021035902,
+                    3437110944150324671,
+                    4484971093201352924,
+                    1075358499888788732,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6164184493548368313,
+                    9390090201378157701,
+                    6299336655450941534,
+                    840894161730587783,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        829043150522309210,
+                        7485532467658058089,
+                        17072453966431109601,
+                        610297755857677130,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18043687544558598371,
+                        8708325242362018018,
+                        16625116017321440141,
+                        277441131490434312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1903578231313020389,
+                        13252279451564941743,
+                        6429417326301386859,
+                        900077037001125169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14902495609461984461,
+                        16049616749407607193,
+                        8772353492731922841,
+                        1191393277823112260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5443236819267423281,
+                        9444106377351672003,
+                        16592192658774520588,
+                        1198721234626210409,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13217949757478296855,
+                        18361600593446830079,
+                        18223280994163007859,
+                        471593720435477782,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14891635570577939411,
+                        4930228641865103982,
+                        677506429995745101,
+                        158637391136506271,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        241833917490571947,
+                        2153702484066609835,
+                        8471674463316687531,
+                        1217041126633955783,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15611345302311497892,
+                        9150344584784175431,
+                        7017882794185538273,
+                        953114622258489808,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17306802895913322969,
+                        6741982267855984797,
+                        18288890876308278666,
+                        1226905683868895599,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6363878173450358936,
+                        10112955129707898349,
+                        3431100839901599668,
+                        1081814334785738474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13487175089423272229,
+                        13310042664491099282,
+                        351156267858854473,
+                        1235361018641701157,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12160849746824021663,
+                        1940579672552365232,
+                        5173518843609944629,
+                        775261558130140407,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4027916299228711049,
+                        6704532292435164092,
+                        8170995433901452220,
+                        1242688975444799306,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        290813590050878893,
+                        265784628708063694,
+                        13739393643636800082,
+                        30906427573009594,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        829043150522309210,
+                        7485532467658058089,
+                        17072453966431109601,
+                        33837003554253642,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11783881580010635245,
+                        16584023570882395089,
+                        4283428041549330321,
+                        1119508136283995882,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17091977473898010672,
+                        12992905830651939891,
+                        15954573338277375198,
+                        47794563421310523,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11043885291224331139,
+                        1548647617977869577,
+                        12818035953821738085,
+                        19358019759012056,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13580859889242783631,
+                        8989493729880265956,
+                        3834830131972278827,
+                        92958512514590956,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17547166027347084559,
+                        7462317210660889195,
+                        11820283106009876553,
+                        606235229811539244,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6646302707553329124,
+                        7538521908335387506,
+                        7309542752385821433,
+                        766275829648205607,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9166342750995036267,
+                        732122649547674188,
+                        13531374525346446799,
+                        1000646095342385870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11144481606165133926,
+                        3184423911807914634,
+                        4508873957097022252,
+                        1003914103492234149,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11387868205105183969,
+                        6006732153669323807,
+                        1874714280705284054,
+                        623573494508630899,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15945493651786195207,
+                        5491089404532761478,
+                        17733495413721832461,
+                        1038788178178868104,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7714674286968289875,
+                        17128841015060688265,
+                        14474417246965845074,
+                        1118490779249300001,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15978475240404160590,
+                        10251844473625293097,
+                        10835055558619097193,
+                        918725653297826264,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8594131294425437552,
+                        8865423165167680323,
+                        2281730302606354747,
+                        530354889916431339,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15561961418804398556,
+                        13262274976070743829,
+                        13708421929985289214,
+                        536613091745258793,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5481416202970029093,
+                        3427701596428103643,
+                        4547384182819492751,
+                        762026830400715270,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12891624923106753283,
+                        2324760134918309134,
+                        156094276920706521,
+                        60840462115001711,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13572641580930894275,
+                        11801366410204556313,
+                        3511147109899192512,
+                        670718804718577621,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2330567015875691787,
+                        8313804534097631109,
+                        17305770858609674707,
+                        383731653388290607,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15243158694955967887,
+                        7488087384671985806,
+                        11994013622455125547,
+                        796827812126978170,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18373304130030945006,
+                        2432299548306079164,
+                        13749359027671170270,
+                        1279902444689346175,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        732704845690707009,
+                        9330307059042816173,
+                        9541144672070161575,
+                        419694344054685868,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        854302062530530304,
+                        2372329924551366374,
+                        7345491118941616892,
+                        816858741970184002,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11967170106917094243,
+                        14990991030258743984,
+                        15850028710144179763,
+                        1165682349745305164,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17669767007327634578,
+                        7776936118806100470,
+                        86143911139273141,
+                        254646172986952845,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        211788882869644324,
+                        12534341103680824604,
+                        6628061221599607620,
+                        283368471247269587,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17506964413737875239,
+                        6560114066791646401,
+                        9528833670513194414,
+                        394434212241822273,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9397394097161534255,
+                        17950475373840587642,
+                        1026892204020404739,
+                        529671962094373230,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5040441456611612092,
+                        11327536934847600942,
+                        2319727248560377681,
+                        452106405983513848,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6990884822909805925,
+                        12765323262011210272,
+                        16502975824235056396,
+                        947716471597247302,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7070271493696888226,
+                        17087808062370043463,
+                        16464730500260491331,
+                        903954221844541164,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4573364344299186758,
+                        4698154942324757501,
+                        1455263277693417543,
+                        204911742986055879,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4774406173326328703,
+                        10423517536790496785,
+                        6376913031563264832,
+                        417058250801990824,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5845852522713656095,
+                        823519207070016745,
+                        4097928019961822788,
+                        656840113788878555,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17931081639261140623,
+                        16276007082163819864,
+                        7188896779228492953,
+                        757348047730737237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12682311202976775163,
+                        1784167280292974118,
+                        2008672272068129270,
+                        1176262231560045083,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12801264814301566891,
+                        2703827011725036101,
+                        14918703219047284237,
+                        952114258367125390,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8479463923606039970,
+                        18419906103116794420,
+                        13053299539616230647,
+                        301100060267658992,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10574784434273189490,
+                        10669586733204802178,
+                        9422753229035428689,
+                        367404998067945199,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12152566001545711693,
+                        11984956549569703967,
+                        6481590102417070245,
+                        1195223679183381713,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4478292304246164686,
+                        9695265509933375794,
+                        16123762648973300369,
+                        47512644441982242,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1110180595742625782,
+                        4177976017153804289,
+                        9590332252256509760,
+                        441901055583435203,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8595891678961818814,
+                        7413888830727642660,
+                        8008300296918531920,
+                        158425107020835217,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8719153263848030876,
+                        7666718108183454077,
+                        8721224523759550135,
+                        1212067132247652689,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9860861075194162004,
+                        3035797630603012332,
+                        9837965549474333580,
+                        555804963531103037,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7964224813354187760,
+                        11672925659895153522,
+                        10734781678145640077,
+                        698681881668182103,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1066828532169632151,
+                        15744273354778281653,
+                        10980423507343662364,
+                        760638687292692155,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13500816629535617781,
+                        3139736352686989629,
+                        6051590017239586595,
+                        1151997855703600153,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15496978799347332827,
+                        1725062491391846487,
+                        16474597511804416170,
+                        720519945993181173,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4357725593593511436,
+                        12419846530904496638,
+                        7512190660864118310,
+                        885070071778686047,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        41890346807961700,
+                        3052488502918632681,
+                        11069286215240792734,
+                        1270856722486490974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12522787288970605309,
+                        10799388575942315494,
+                        5594276409054296597,
+                        752828008587247208,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        986476726356404747,
+                        17995179590114923223,
+                        3706374359542816161,
+                        802049257180491848,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        917041321972104639,
+                        14406543696649748052,
+                        3478493546305129728,
+                        672545893208335148,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2056295666501169079,
+                        10946201586585862978,
+                        9728629539647164534,
+                        1302948054792766477,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8201723865600359288,
+                        5957131294331790447,
+                        9903137581778241705,
+                        926501738069478306,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1341054006860257557,
+                        6931863136535190727,
+                        1486721508037521909,
+                        968781648949646295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13483767914174191959,
+                        9627201954134330101,
+                        11708847790399063932,
+                        566643194441566163,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11279657734448476264,
+                        3501879382831539036,
+                        8378772646517618356,
+                        1305584205697497626,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1429391145733548154,
+                        16971528718556973437,
+                        3416635258442238310,
+                        63788450154618085,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18205249197510704878,
+                        17070947473589427814,
+                        3897654986133581365,
+                        302803225371913936,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6817894613344915843,
+                        8893827175059194790,
+                        9395370885611607313,
+                        283901510758998385,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16606408387352822640,
+                        96965581441606302,
+                        17307207657243312045,
+                        55285493636289701,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7812135841987684429,
+                        17163083725122073409,
+                        14760633573275595004,
+                        1237119305357886817,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13411396029961738715,
+                        5065165093798402833,
+                        13354261774131463742,
+                        172692016330730668,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13819728429905631997,
+                        18125698777813917462,
+                        15383843364888126916,
+                        410919663577024282,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        572118533845942894,
+                        12502312034218490824,
+                        364518656539282776,
+                        823926924334309720,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        844727950439859424,
+                        2328493059227601186,
+                        1245609361561303013,
+                        1020398808872680782,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6679290142258391749,
+                        2612166873313477252,
+                        11840934103842652834,
+                        673340472180814038,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1754748866935649737,
+                        11919710661226349228,
+                        10702776091030932826,
+                        719930297677528038,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8066157769707803129,
+                        12524455062570715423,
+                        9809765378380420481,
+                        114597198283305695,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11090180089652187605,
+                        17734455193518439645,
+                        8777759561400737505,
+                        258733805201598192,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        820583417503985526,
+                        1921518134999631446,
+                        12048501050460883925,
+                        603898415350154505,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17718166140533752811,
+                        6158474259326286608,
+                        9822391951111973486,
+                        642890143956434424,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9450141083464728828,
+                        2255037498950227303,
+                        16334106384827962626,
+                        423531949341297885,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4334063447153059872,
+                        15658013358301756815,
+                        4876599756907649274,
+                        13575995352231973,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6745873384295281228,
+                        10055685454629296814,
+                        9798865800905484968,
+                        71811618391241766,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2963962567560343910,
+                        17740664324208935607,
+                        2523510849464445211,
+                        1103484113727638986,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6214026378232506781,
+                        7836260236746938577,
+                        9726470874263244237,
+                        676224168344446883,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11903944158216701373,
+                        17777357757946331557,
+                        445702643079311880,
+                        332711283033130463,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5521256004326382754,
+                        17978862686497062330,
+                        14569893589711202203,
+                        606379714733834986,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        374700422858685860,
+                        9728614358465105688,
+                        7203579790236549772,
+                        1053582638411917288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        406907222283743790,
+                        12934923524852245898,
+                        7558826365946573645,
+                        393043656368390252,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9119517884673632405,
+                        8125968608761840123,
+                        2700717691853639157,
+                        1158075084768579750,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8148466809536833479,
+                        17285190314674741333,
+                        15281992817584177274,
+                        867682306504590898,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8198673574111385456,
+                        2249413862215431304,
+                        16079336054101552981,
+                        1288275609449576832,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17656219999424171787,
+                        7333888750067015796,
+                        2551505533293902653,
+                        1044283156973222292,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17273487494398778040,
+                        4098975791005241528,
+                        3644987098273602832,
+                        311832606416688195,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16389025280007863114,
+                        8241504768878087653,
+                        8923213804166091836,
+                        160081173790165452,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7857905835713265785,
+                        16335215798034478082,
+                        2772466733647680027,
+                        519294031513129898,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17553223959528311064,
+                        5570185397496576999,
+                        18403762229171290493,
+                        998518618095296830,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5537188845880707512,
+                        11488262590893019227,
+                        9013430971547685074,
+                        70634650551896037,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9279111566549235898,
+                        6212373363678708316,
+                        9620484368571951138,
+                        424102616325637790,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2493548349028816045,
+                        14983650934773025653,
+                        10532498400158586239,
+                        73524981216799040,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12373373607460348048,
+                        3736874768608937773,
+                        235535698948826321,
+                        377820363295576397,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3852680488299091264,
+                        3266379176614505651,
+                        6765269627320211797,
+                        1090513624402520546,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4027596967987795125,
+                        4268671820812927321,
+                        8579300712083791193,
+                        460785469266425206,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2475592998086087741,
+                        14538683519389473323,
+                        8558356902149967875,
+                        1017368409100534147,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11676925251890966196,
+                        17996866637124132223,
+                        14077630807801256036,
+                        1337628554704282922,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6582522040368742620,
+                        3892963552393548790,
+                        10336015966599747068,
+                        1124590131752569338,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6609822172716692992,
+                        8972638197830864155,
+                        11102609973820848201,
+                        1271015184017159107,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5520610439587651483,
+                        6992102153428138981,
+                        2418051051123796552,
+                        1071341145233704644,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5972178589708050927,
+                        10900264192547153977,
+                        18029206312716833613,
+                        683092190614728310,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16712279736219758832,
+                        7860569038884613874,
+                        3775104698453561536,
+                        165956878379079420,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12282487081811583440,
+                        1660140699346629154,
+                        16038347925143430318,
+                        1086269625742830967,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10704896033969616259,
+                        2655369470177178442,
+                        9456951289325497860,
+                        413386264722199650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4325790966728103363,
+                        14122720901355999569,
+                        16005520420867194599,
+                        521045006411897044,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9480279745394283418,
+                        2108694119365684270,
+                        9275451082267622381,
+                        827039659784449225,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8137657356840967684,
+                        11133817288542474272,
+                        16014502525253382189,
+                        795895407939366155,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16462547277826224577,
+                        14016058853274872557,
+                        18310000126174731491,
+                        93766962663587839,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5041991810742775239,
+                        10115568641173012358,
+                        669061623529601649,
+                        946902927172666711,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4442591384134661706,
+                        3134851740126379814,
+                        6059369144631480445,
+                        160884190727051821,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14269986740738220017,
+                        3503318813919236066,
+                        16841164069312724668,
+                        674771719052776220,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11778657947376499386,
+                        5557534703392861123,
+                        7023546977621806456,
+                        747170182719684881,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17073970884425092688,
+                        16054664205592286791,
+                        12185960500846313257,
+                        1226203619194306775,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10713373666954164104,
+                        10180347326390743151,
+                        17463555628425419480,
+                        396153716475130088,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10781851796211249584,
+                        12420569597981424404,
+                        6449098007942667656,
+                        1035344150093968041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11179281152301807369,
+                        9724480437385391877,
+                        6782293040887974009,
+                        1058096256780437323,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        88067801877026467,
+                        10336420852436642526,
+                        3661899251623162721,
+                        983133591111989949,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11112174749365109987,
+                        13820804159004813228,
+                        15922985527571140602,
+                        1147617849987689443,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6193207437767652198,
+                        3014254065618580763,
+                        10749514363456795392,
+                        540333069242444454,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17879618582253855432,
+                        59009490059223467,
+                        17795178534500534699,
+                        1102760221538561768,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12774486309429279717,
+                        17806725476018866533,
+                        10833006723496459429,
+                        553259121029502470,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14427672579868217299,
+                        4416733945680736693,
+                        11968506473146243719,
+                        230066708561295159,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15907637931970296787,
+                        12709636865604175144,
+                        2346173868088806892,
+                        164776935717047465,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7238790987709417391,
+                        11129074956420383824,
+                        12092522529684043064,
+                        313646691757153418,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7960751235004583588,
+                        15909285679029659878,
+                        10069665899125728557,
+                        742135173166474171,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14031252616646170333,
+                        333519921831393804,
+                        17457635707090106843,
+                        114544381846798239,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13719296101416818675,
+                        18100101929810561249,
+                        17591263478917240813,
+                        441670740957934583,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4951711710897610580,
+                        15844156979112922392,
+                        11544114765533525499,
+                        1202783809731173729,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10602640003426076484,
+                        17354411653029410216,
+                        6884617650743687290,
+                        186260820259206309,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10872209660782659917,
+                        8634647470826631606,
+                        315510646025271557,
+                        95113883459401739,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15357757389070979146,
+                        13988695511106505763,
+                        16663560148811314319,
+                        602486125287902359,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2724201576793615623,
+                        7916022586857174232,
+                        9253638394745409090,
+                        692825304870752523,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14284653845693013818,
+                        4177567507525333910,
+                        11816271893859883182,
+                        1280950188840903743,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2344466384248334304,
+                        9375198509936745795,
+                        12701141210147619866,
+                        1238831045156582040,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7616469755765826009,
+                        13734206220969145557,
+                        7974434995209066412,
+                        43110090145721373,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17972061951454885493,
+                        4116712201404719168,
+                        15461182746547807993,
+                        1258087932281278626,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17269017704940015548,
+                        18050489970036468425,
+                        8833157665707123924,
+                        876831466261201526,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3524652138646457843,
+                        7066169135314834968,
+                        707753264721723381,
+                        549939188567389767,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18122789217222060455,
+                        13749716485230471861,
+                        3876215801418988956,
+                        408759274612881837,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4245045377745493497,
+                        16140118305371410217,
+                        2949401772270144174,
+                        176154307548729189,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9533122709361222173,
+                        4071074804952340102,
+                        14309810154119245646,
+                        1214610012304882923,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13689770762652661814,
+                        863099550605207422,
+                        15577537029978175141,
+                        1263600600491144789,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7822366538894736926,
+                        15340980427024809902,
+                        10652467072547122171,
+                        231244740728375682,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13471845968564492755,
+                        8410534986723214549,
+                        1399520956112924442,
+                        571994967547211151,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2962259295553248634,
+                        6071684259873406667,
+                        8189369850791054738,
+                        731559519334696376,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2158899217893617313,
+                        11520211527193863682,
+                        10076550351962727481,
+                        740714699935414690,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9473214048962073916,
+                        9117043842745034542,
+                        8145424685563893747,
+                        295574763320152184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16597094380396589407,
+                        7778409861103824900,
+                        7877775626940072125,
+                        1318739608922364730,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5224262858792638868,
+                        10256306166809645092,
+                        15496069453053697274,
+                        298674067180546623,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12151272623930986364,
+                        11106490074210677713,
+                        1769279939445052625,
+                        218467735176449794,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8333506507734144604,
+                        16861731047179402695,
+                        3197942551943405508,
+                        806884701670843381,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6539711144130596389,
+                        8787645565805640176,
+                        7804697801739915448,
+                        177090139252696618,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3013571487780303551,
+                        7556249210262898189,
+                        15771599686634049572,
+                        396878461941981528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12876015635317947671,
+                        12269566702995653804,
+                        14215099594498342938,
+                        779849087140993601,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7808329229225395788,
+                        2339590010925992922,
+                        8828252551294580188,
+                        63477805582691460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14337231731525167540,
+                        7494172592301569199,
+                        5678312730941542890,
+                        159456409677453157,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2719865501708150306,
+                        13109283414076471280,
+                        5231816565351681393,
+                        975064474755089921,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16935777594765725367,
+                        9211842850259283592,
+                        9122399600975090155,
+                        1135694866861699397,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4929187562474044523,
+                        10374809921454125837,
+                        4352694939803046839,
+                        354879449467549087,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14490580611083476151,
+                        863321503850824864,
+                        9242585411351357914,
+                        1174062063087177009,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17679364883776089144,
+                        14330604594905526546,
+                        7421556981673022224,
+                        544141983089393682,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1334259265768256937,
+                        7694526761949961148,
+                        4136099326616254662,
+                        15160660635889879,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2879402223195888055,
+                        1318551790628109503,
+                        14383606145779416957,
+                        780804699173774709,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15559152651264405566,
+                        6612657941437730199,
+                        945518616421886938,
+                        506129575496469470,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4449905719466279645,
+                        6538177584552245308,
+                        16026289483266786235,
+                        1066465476496680643,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13466274789040423024,
+                        10852960302862846735,
+                        6060952901131234351,
+                        483190641322496863,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16986519751474868118,
+                        15098859836592600038,
+                        16330973216664039814,
+                        680550966154401679,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9265100426992821707,
+                        17940630210367661329,
+                        7493086319519406683,
+                        1186735924524166474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6533243505577249933,
+                        17248368231569846975,
+                        11012816703919915422,
+                        235171261839635198,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1745635906904654698,
+                        16979094951476937936,
+                        14991211962886192493,
+                        13488254483797482,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        484794119906611596,
+                        14651402953847804999,
+                        5475061576682120345,
+                        166455122787921901,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1285056372852103600,
+                        3124013148987855080,
+                        1421543589527999534,
+                        98318563757916640,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17005194804880604378,
+                        10484972988891132926,
+                        9556620417366431828,
+                        461966633578514608,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8928137432102770851,
+                        13247917227019867218,
+                        11246381023662677203,
+                        469915566476133249,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16944578506362799315,
+                        11159026779669453252,
+                        2667953702480824111,
+                        1261859072652625223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10808454628529195977,
+                        6445281186430937970,
+                        17652172573136078798,
+                        116846993785107660,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12980816422291051916,
+                        8268524263717103125,
+                        13730035582514118294,
+                        1302515207369458866,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5514276708157760734,
+                        7623889997370297827,
+                        9882818353662590457,
+                        964374553642024767,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4709790473753170977,
+                        389905791680311527,
+                        10293703333811011847,
+                        527489661575947201,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9567127701102909955,
+                        15748567719567212170,
+                        12523486439964826097,
+                        1093156262567902069,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5506778544258979044,
+                        13263231696725826368,
+                        2435416046792440750,
+                        1074575389464958572,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4470289526895233143,
+                        2590821153894317341,
+                        9348104547820513304,
+                        649454194113978555,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4912419093580767925,
+                        11976906185397885180,
+                        4745004707454517208,
+                        954988910357657947,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2198539197116696124,
+                        3792516608232397659,
+                        10355180258067570096,
+                        535478293575886288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12889852362965042803,
+                        15991187617073264100,
+                        16089305078178441954,
+                        517871628570116352,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8055190990856631165,
+                        9723069154732052365,
+                        16204729940874985255,
+                        1275444444269962906,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17913291167160742317,
+                        12206407865058752682,
+                        15108190585242249429,
+                        1103808555989346425,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3455306911573664127,
+                        7433919723618968988,
+                        2170637938401242421,
+                        127712892668546928,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14801193210082706901,
+                        13244619098550208638,
+                        3105535038053349009,
+                        795686123379619133,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8914645207625991971,
+                        17286904235694519411,
+                        3667477516156736130,
+                        1194674753687039095,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16606020878929346560,
+                        12830319839931047930,
+                        6223422784253934179,
+                        188776450347637544,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18092159795808750219,
+                        7001088364163342391,
+                        4750758023081193603,
+                        538352457158595491,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8703610144580899767,
+                        3910307741295756696,
+                        11795900237596992724,
+                        707709652533019851,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8255862119205523404,
+                        5207899354121998808,
+                        4897161771344741723,
+                        877747222411958053,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13318571952629189155,
+                        13087097296477110817,
+                        9521630187393018328,
+                        364493136330579617,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17588039668300955785,
+                        11072710125793837419,
+                        405112348431821110,
+                        746006050466681009,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9297059406361450586,
+                        10695376205636483974,
+                        16794823495475118551,
+                        1194504818096989161,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11155263128298891902,
+                        12884661722614808233,
+                        12284897311481878903,
+                        290794367590701687,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4457764409865262402,
+                        12700414221602893992,
+                        11321306500820819786,
+                        1093897502658809574,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8717256751826055618,
+                        3552299404214703577,
+                        15557214723498867738,
+                        740397769259000287,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16930953622979496405,
+                        17732747221758767325,
+                        13694594675988776544,
+                        410674760984292742,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10399207999630506398,
+                        392962502517761551,
+                        11303102945913668427,
+                        849971850286865150,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18176953891862491527,
+                        13947898102111371823,
+                        846384656364976,
+                        973388813041506051,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9744699641626480091,
+                        12686420299483897781,
+                        15811253727331942130,
+                        1110679405724784916,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9860354735564420582,
+                        4909096611360125054,
+                        12869663170413678796,
+                        1014104652775859950,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14535354070407625150,
+                        14435697013230891469,
+                        13493187812640924823,
+                        239329923621185971,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17337708226664076940,
+                        14181811704361853696,
+                        12879513680330498358,
+                        1310112563853664908,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15185863898412907874,
+                        13301362156100174607,
+                        13359256399868348683,
+                        65656192843459149,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18146585736412326219,
+                        18056878316995022561,
+                        1034893582365238680,
+                        257689366268196015,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14172768015271684300,
+                        8369168647870007226,
+                        2257547391193396061,
+                        725055667414082341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6768370118289313689,
+                        5205987747412994347,
+                        13266506576407644225,
+                        366101108891549052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2333474422603598508,
+                        2178693494916788051,
+                        1148357560109076889,
+                        274241837563097617,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6167566795791036157,
+                        3039329245727661207,
+                        9495810818552420007,
+                        127370786649453033,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3174296382862071390,
+                        4920925969600599014,
+                        16587145286623350995,
+                        42724464277202711,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15089069546124049446,
+                        13324688762033515546,
+                        12964159473935453352,
+                        474903623689361040,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12352335405466298380,
+                        10759669288357591583,
+                        8706880872025796892,
+                        862368473599589294,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13010458205530736239,
+                        13495758064824431475,
+                        90274988957556203,
+                        177512244299324842,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2064500468451411082,
+                        6193998864709632212,
+                        2963656291948801273,
+                        1324590049969505799,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5006843546511394059,
+                        8588425603202135068,
+                        990902049171302351,
+                        380605261922169440,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15579614166516754048,
+                        6829964584388523789,
+                        503730072776907263,
+                        1314336649779780413,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4202005169476983949,
+                        2191059262752010002,
+                        10665786635552179874,
+                        1031869742117098812,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15154927327674732533,
+                        9674580595454745688,
+                        8953665605947722415,
+                        450250054303992758,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4824188794413148811,
+                        9716096932698239118,
+                        16290342692307195110,
+                        817500471881528682,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        678137337018626462,
+                        33154379432062765,
+                        14755433565822574807,
+                        806444464092408493,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        759732238639244296,
+                        7716624502184438531,
+                        7249296776416043654,
+                        901427645732777500,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        702406077999740703,
+                        13610523380598992515,
+                        1201636831032973756,
+                        127341085229771807,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16444619467571417446,
+                        6832879211741398657,
+                        6105942043276754152,
+                        1229443134681659361,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16244723877941897342,
+                        4176685630329322774,
+                        5619142895038259098,
+                        846851462212295285,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16767353176971839640,
+                        5954108122307289248,
+                        6415313101844303073,
+                        143059091153344488,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10267956534445456723,
+                        13129496641333050522,
+                        1762761900200775289,
+                        595965401538959738,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18332833488448808448,
+                        7516591308113190982,
+                        12138706193180793464,
+                        452828634473731943,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17266623415306809945,
+                        15861976620075632928,
+                        16813774908577312528,
+                        583559806225399901,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4746503189032939753,
+                        13168542749711692163,
+                        9093349638838451322,
+                        1248336263331083031,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16622776302938139810,
+                        11898349089130347830,
+                        13184009903706487284,
+                        87257762031362593,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2940635467126473821,
+                        168666694068556204,
+                        1840976920785634090,
+                        621058473993309089,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13728165472398640428,
+                        14326280757670065665,
+                        12119551455429138591,
+                        770031291424110394,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7412590919062634691,
+                        16370260549880119723,
+                        11493572153388688647,
+                        1092708553641312179,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2915975817920180470,
+                        17893111848469465896,
+                        9938683110948165855,
+                        814662681334049515,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1791986084775532758,
+                        12227174319510891282,
+                        7629995732190806463,
+                        737123353902251418,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14252769828243268256,
+                        18240742350671739081,
+                        16178603008412994618,
+                        1020156215794452144,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16605995069053139800,
+                        18393334871812233900,
+                        15797899187943697974,
+                        266362729876547609,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1281483026452833230,
+                        15888183045777382692,
+                        12214896700977104017,
+                        284805980574955026,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14075622113959284151,
+                        13936874446962455727,
+                        5897519667674231911,
+                        980644772562789398,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1102778027595740384,
+                        3924610834887945419,
+                        4817795217217162449,
+                        475204123872685968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7524925523527887520,
+                        1200801126556206082,
+                        18117409546085892616,
+                        952676747709237089,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        709794793879593566,
+                        18063077462829756754,
+                        17635080246330220364,
+                        292068251931364013,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16396066549120542586,
+                        4703527408627442236,
+                        16604343976964979379,
+                        399835308990759092,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16124141019242640122,
+                        15713822443761307838,
+                        10328694437751709633,
+                        329987466727778187,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15092951654777735450,
+                        3789049021948848178,
+                        1652580426088715842,
+                        1005723044782969885,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11834544740871749897,
+                        14477580583555169644,
+                        4855651408705646570,
+                        476783988223207803,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5978089550520591398,
+                        9995822959770532781,
+                        1969182398876733079,
+                        952718559784074702,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10737183904033293913,
+                        9190594277701686906,
+                        920101188529905173,
+                        715569111540826677,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2855088237203062697,
+                        10060561511837148459,
+                        7672089784617339081,
+                        1321896758835708340,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10958682337101998637,
+                        15767642145290739394,
+                        15674073172684305988,
+                        744760248483459562,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15743379391432499114,
+                        13848085129219484541,
+                        9729952649552323222,
+                        671672931443075384,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12301149325177903633,
+                        7308393954411561829,
+                        14230510797227940892,
+                        1231531198006716345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6061558250194619113,
+                        4609422038152676165,
+                        14782074332821682656,
+                        156948347069529550,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15239296007926756134,
+                        4978606152817296693,
+                        10005034372423258857,
+                        937460679218790015,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12481904268299334200,
+                        8378487228751942853,
+                        18224369558338114287,
+                        724897091288744673,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7747619466676506088,
+                        16706514677251535527,
+                        4122051629862248747,
+                        235487816341091817,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2222416421436484217,
+                        2368059317203565625,
+                        9432012971332940726,
+                        903543601343901553,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14486539009513655817,
+                        4508401614656629616,
+                        14577936148523757729,
+                        457216819911068664,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8923258730708840817,
+                        17943443016328828349,
+                        2678757338085494290,
+                        449199896942846779,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        987053170009667753,
+                        16529347458976633917,
+                        8466842420801337331,
+                        679028523268500877,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13945723974663923338,
+                        17534312325030423626,
+                        18090761406141924047,
+                        190691121934363808,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7254609256539154048,
+                        10338354364934931786,
+                        9536927883797522361,
+                        1018128253050290528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4326192948660663784,
+                        826004970756962842,
+                        9711680927572488036,
+                        641232905132819731,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        461347850011893636,
+                        8165938152462408261,
+                        13657809258324642375,
+                        45111167026437017,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        643805973361247915,
+                        18285817618237994284,
+                        11175543830716541198,
+                        975663428677067654,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16768631053329352192,
+                        14040779705681344113,
+                        3340080154971387977,
+                        152564960211947533,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6660910888817755366,
+                        11892208073509982685,
+                        18010331728529193565,
+                        246557291549958190,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        59551307125167377,
+                        11168929542524796500,
+                        8192075493712202307,
+                        194114820715244013,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9466336700734596111,
+                        10884042615624651255,
+                        12034068474009066307,
+                        1116316057302426815,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3301696131789218822,
+                        15293951997209131,
+                        9318166797724654587,
+                        851996405336024801,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11084627383484509217,
+                        8018866618305407756,
+                        10868094963664171691,
+                        658096825823950160,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15551487892520547778,
+                        7269172787210495889,
+                        18218729941928022988,
+                        117072944433791624,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11636035270101299462,
+                        7104854262748968279,
+                        15035736937780284671,
+                        296722353336216336,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1860997502615502479,
+                        5958278827906753986,
+                        10454202686653946948,
+                        1208187082663488680,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3098708403865258689,
+                        6470925295547875389,
+                        3109539654894855885,
+                        196438126662112591,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5538431521820123184,
+                        2782307667130282124,
+                        4005954184778744612,
+                        193503206119554807,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6787351224305101312,
+                        3960593682081695490,
+                        11336477796949078728,
+                        1100894795670658649,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11432261920529479186,
+                        12578566924402656744,
+                        13890159951552549613,
+                        1173145474297375641,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9245777951242621848,
+                        17167902312440352617,
+                        6657059511367778151,
+                        94292547696022176,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11874965753974204808,
+                        8043782779264497223,
+                        32224519629795591,
+                        218117999953300891,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4824762285583909689,
+                        17654354422270461516,
+                        15967358469331292589,
+                        41566917613455723,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1635222531771747577,
+                        17873014429069001397,
+                        12080408470314870531,
+                        445312712424082035,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15425104196460592330,
+                        4465951132367023666,
+                        11063360012757180842,
+                        251855001109537256,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7360703109474177735,
+                        17785441109565647652,
+                        9081159227712342619,
+                        168731870972181039,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17534027104882815044,
+                        12496639424923964020,
+                        8099254620562646902,
+                        542536057719308231,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13888669983225082012,
+                        16348372384267396286,
+                        14364619137917104984,
+                        942360040845847505,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7969718605702149819,
+                        2742184274953912982,
+                        8261488845786901982,
+                        332057228984062972,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16515103912023976844,
+                        11908377436272510185,
+                        11126632368794407416,
+                        1084640380634999977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15233049828499722986,
+                        15843138597244195387,
+                        6842344258441668465,
+                        1015187523768849057,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8873538182191720872,
+                        2428238769706912551,
+                        9659976443527869222,
+                        66505483017649975,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4626761204396494478,
+                        8787222862530496827,
+                        16278442163223165790,
+                        562235593131494135,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8135366848207477498,
+                        300768545312653694,
+                        5058270231676328219,
+                        1020176185100742518,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7041863770524880694,
+                        7980635139741750007,
+                        6903265351307878890,
+                        394814632948605219,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13956490549119202817,
+                        3828328851202889308,
+                        15558765933536815760,
+                        540604320053346692,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17945788044233003371,
+                        15077663286362163769,
+                        6556312850580796225,
+                        234076204662318758,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11485487233790862743,
+                        18336354268750327926,
+                        12163675715374279815,
+                        457011312537288612,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13624576493220095635,
+                        17342618088907186045,
+                        12959142587695163332,
+                        871820201505200514,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9570675468760745866,
+                        8889543234757081244,
+                        16896503813606010166,
+                        3564601391785610,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        889452806589544989,
+                        12616184776640046299,
+                        5288935358747769333,
+                        1199071807582736240,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7658001431077010319,
+                        11857860156094423913,
+                        6430156393656638336,
+                        918773825107649493,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5925603596822286754,
+                        17391484352418694707,
+                        4309885091208169717,
+                        646224219939420504,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10524201000241059811,
+                        9867947224740199056,
+                        10777825689701007806,
+                        1196601299818142075,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13012325901795006280,
+                        254589908268794095,
+                        6485911164426173559,
+                        741480824737292872,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8050596095185163176,
+                        15947168498912836520,
+                        4305594565440527335,
+                        1283370193657736491,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14615230032237743337,
+                        6481735976773386741,
+                        356117401505355271,
+                        107955069016278429,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4605197405911272892,
+                        7776650410803629191,
+                        14626793908048982124,
+                        573029163700768035,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        795420528070189761,
+                        3889366236982045612,
+                        7291723732687239869,
+                        671590608789648394,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8519349311275779280,
+                        9303096089962856445,
+                        11870509817300781397,
+                        1011437629313698288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10640148551342297202,
+                        4458000394923564588,
+                        4142316816086679026,
+                        929390173378213520,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9046964322733692193,
+                        8264348573407102066,
+                        12090087205371162196,
+                        1216678529758806641,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6573816276844963053,
+                        1110178966432508416,
+                        5697011598774783490,
+                        783224490696574330,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16663999680342990729,
+                        12579796152047396812,
+                        14990065417880572753,
+                        690716788076305564,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7082234174656877835,
+                        4664899107041623854,
+                        15731323644644746836,
+                        326525706314849573,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6422742549936599967,
+                        16642734250151851163,
+                        15724580362880259072,
+                        568513348141572959,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15890060488973336133,
+                        8407796181451659860,
+                        6499183165960577740,
+                        915903708841781253,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17592431965269849820,
+                        6669373883891832955,
+                        3845135816458441501,
+                        386374015183375574,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2562139186448202816,
+                        4053437961155406118,
+                        11236431750557254067,
+                        1319974224490451363,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5216726970659911936,
+                        8753041807595232424,
+                        13793540048737957777,
+                        127047875979427900,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15168657601186915646,
+                        1759879887836029577,
+                        5465664227798169324,
+                        1200087231669483211,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13272945317090766247,
+                        6160082878725488500,
+                        8902327719833746000,
+                        592343150331389694,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15421266014156281153,
+                        7938314058432394873,
+                        8834993769477151969,
+                        232082089798571993,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17942880317858597424,
+                        9880396511418948919,
+                        423980738551249658,
+                        711430575760762071,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9734240215929857479,
+                        6035196921471424935,
+                        534253298568473849,
+                        534932934623023600,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17744818118450596215,
+                        14103505727391169417,
+                        3278041709432171283,
+                        136459407251254336,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17103120188762938623,
+                        16264180282903762280,
+                        2965143066274883608,
+                        735999228645546820,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        245879995223516104,
+                        9617498306909654609,
+                        17560795945814355733,
+                        63801405394995847,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13380327298772305525,
+                        9893580732710284963,
+                        10716875110356599356,
+                        276575657016348420,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5413892268699592440,
+                        17864371876618332973,
+                        6978271274123747793,
+                        113705178667970949,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1676268984438158814,
+                        9335700385917491675,
+                        5399476808860587462,
+                        1325654649073707103,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8814625309405843975,
+                        10011302522436812559,
+                        8537948041918289616,
+                        97113154135845539,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3371559925070980455,
+                        14957545088300748821,
+                        3264036202919615006,
+                        934169461060200977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7858928815608782741,
+                        15553060927865289514,
+                        8019277816018743192,
+                        441105064940782717,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8791848607209684384,
+                        313214932531004619,
+                        14880728538301071595,
+                        187302135725248540,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17738622813662691517,
+                        5185363089396693484,
+                        5740889049596250187,
+                        526530704629105449,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15712285620773551856,
+                        252145769882949327,
+                        10733108088017896601,
+                        334132091339644377,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3754876661517865837,
+                        6637073737711025271,
+                        2297417001168429708,
+                        601299509425755577,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15643729984764810279,
+                        551618171638586707,
+                        4299127806034543233,
+                        1318375758414547902,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10369783970638998000,
+                        5427457155767306094,
+                        6960051887480742978,
+                        373552782054183369,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10724985997072782199,
+                        16659930038758769957,
+                        9455846680816706619,
+                        226894442497685796,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16981735309563180690,
+                        5196384117150659179,
+                        7329730842544400680,
+                        1011393162622243682,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4006246818645540624,
+                        4643158128578459342,
+                        10710699364089289297,
+                        189802847875652425,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7026158122349494076,
+                        690523225764355682,
+                        15295185932467344779,
+                        724449013515117913,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12891457079638432432,
+                        12649628157091877580,
+                        7475114336156551183,
+                        1242517623709974471,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9611926122891990307,
+                        5704464729051205383,
+                        3084442851828312361,
+                        166396964840099153,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14403420513699140097,
+                        202427746277292562,
+                        14419738192717997586,
+                        46062250545297224,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11240552091103493676,
+                        2216766208757236797,
+                        4852559626500951982,
+                        340071110553196517,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17081913829388782981,
+                        4644509003285753233,
+                        8047344886886351898,
+                        939221785782876838,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14288451189608454699,
+                        8903360440611848666,
+                        17208443278929266881,
+                        367583063383744290,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17452207016747404955,
+                        4149342721250645746,
+                        16147592344109953477,
+                        512624471488046736,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8529706748649368298,
+                        18252028054669225772,
+                        17132418175985362912,
+                        1102808330998097433,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15265785692819309896,
+                        6261882180455373342,
+                        16521962687582955148,
+                        145143733941515057,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10225622600288984327,
+                        2073590746555728630,
+                        10674508958873630727,
+                        766891668530162927,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14858231113451916710,
+                        9398195699615225970,
+                        550956389091001007,
+                        775186840876878019,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8089844743127582850,
+                        18174192884090595849,
+                        8821649239963128037,
+                        633946007616437256,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12959594939298135070,
+                        16673415924784883599,
+                        4133688978881917906,
+                        676113173296725686,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5789604240396668339,
+                        8826170140226050673,
+                        7166522826229394087,
+                        157342534375131013,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9923421851171226545,
+                        3152970344816781674,
+                        17282031259121959799,
+                        957448535880811378,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6847349014566279158,
+                        16477798667462412562,
+                        7746962956885083545,
+                        936081110681814819,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6585510879408242940,
+                        5242412016489687405,
+                        1575459378339188077,
+                        452888145794956776,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14671844489288570143,
+                        3151651458032250169,
+                        2955066118681108731,
+                        301336459455458981,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11137491797178869111,
+                        9977961123040376789,
+                        4892962139656970796,
+                        1187519602981453078,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15005527637081339029,
+                        7193790375682102952,
+                        1431175470147757216,
+                        1026139506397317919,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1059570480034648521,
+                        17402196714942241917,
+                        6018935342878511978,
+                        641962455947357931,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4720680542854135130,
+                        11644260324440219569,
+                        6338494340831790419,
+                        255567010415801684,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6571220084340572233,
+                        14716835449007719353,
+                        15270172923628746130,
+                        469363403449195077,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11052460261087954390,
+                        2026017637466062691,
+                        7804165193666568063,
+                        633048618849355094,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4809533439962978210,
+                        18204616280510037968,
+                        4536728622695441644,
+                        87781300382352053,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        584261238173388391,
+                        5478519459914332586,
+                        14017159375042223280,
+                        521132109705226612,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15940234291804046479,
+                        16675332778742299117,
+                        4755422854598145710,
+                        410340466070463229,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7708320907432518621,
+                        12894201748475176800,
+                        16003986430309345878,
+                        975454932601326758,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14825860718065023872,
+                        15551821511358037077,
+                        17741569623944177657,
+                        443436010342461027,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4201807618490820149,
+                        5181420837213783259,
+                        11093489545226972537,
+                        1061211161989703693,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7685254498179433893,
+                        17803353931310593808,
+                        2559695597330638061,
+                        1088007110756518664,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3364798800646654044,
+                        5252093494352080786,
+                        5270002819952758739,
+                        827592560402004516,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1360508008615087124,
+                        556629834487206658,
+                        8056299965191790392,
+                        105953235705121777,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17144598709070047048,
+                        6431457694126343138,
+                        10343447213143945579,
+                        1132912851184526809,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16206173172482181479,
+                        15390729831313386503,
+                        9024212769694916978,
+                        143190798101408481,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5792833832942303893,
+                        4201645738404764241,
+                        512799959855173997,
+                        56555856335804260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7192611450473182217,
+                        2803005318410884935,
+                        9583265006692249665,
+                        500887884675560274,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12158547715042108963,
+                        3975801988711223862,
+                        357878456638092821,
+                        1273931330088782700,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5074816516705780094,
+                        2522697366337592825,
+                        3557061821511836629,
+                        1010065236314329303,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12954725375862705205,
+                        4320192131047427084,
+                        13475174593488828189,
+                        57792539497790684,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11893454150842464967,
+                        15971193595065748134,
+                        11281349633108165178,
+                        682943852894498464,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1629593390296572809,
+                        6856706869894033810,
+                        7719651451501501081,
+                        886579008089103650,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13928103283292060242,
+                        12297666748913880009,
+                        11555991068724386099,
+                        264082775906970049,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        523478455393758969,
+                        8792971135664382419,
+                        833013567782270874,
+                        445293309255099707,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        26632263577272274,
+                        2538448521630976014,
+                        2115482365954957459,
+                        1022173755341368316,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5406477465826553118,
+                        11001683349852601645,
+                        11990220631624454490,
+                        970640173526845933,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17502538896437871607,
+                        1630128005980899631,
+                        1020848909140202298,
+                        1113606291999618280,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9585734682943592057,
+                        14580359404219063800,
+                        425484820885833481,
+                        716091542626105500,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2078193575238491814,
+                        867726098628524843,
+                        15911268735850333645,
+                        1191635741670722897,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9650559937223712177,
+                        13589909095569079433,
+                        10437944409065326601,
+                        767809140257742387,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10469351772460688027,
+                        12574924855082026589,
+                        2124790325212583115,
+                        674613625110162489,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4090442627904341733,
+                        12098792584632258713,
+                        3384597043416876034,
+                        922623056947274100,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14562910795721681212,
+                        18139255051029777449,
+                        6129531910814672555,
+                        89888598305526178,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14137384101026415292,
+                        18388809696992348914,
+                        4010821004383489184,
+                        700262350175377454,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15002990901746214539,
+                        382893117769886904,
+                        9565096518200706646,
+                        895887478169534173,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15266449612213727272,
+                        10487540538322551991,
+                        150233594762056787,
+                        305723284376984010,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12867056110514111129,
+                        14142428120899601033,
+                        14037565925322610229,
+                        809373345537011747,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12723555132415181685,
+                        12688781568469885626,
+                        6826167141101850830,
+                        879337117473842345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5628149891221732380,
+                        6777663584674807710,
+                        2139529525257275217,
+                        403766176010176126,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14400672372474219886,
+                        14946413098324748046,
+                        14832299517706162295,
+                        125701560608061324,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13864168628895349185,
+                        3884093200474605446,
+                        2086800340126697847,
+                        934694723727067575,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10883766497659050807,
+                        4929115376300847505,
+                        16207772372020798166,
+                        840656337486558447,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5362632603399372897,
+                        4409577498201623147,
+                        11359034908318486711,
+                        1067250243289011364,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11586352750998377832,
+                        2908789569674611170,
+                        6743730050741002886,
+                        1306486981598947480,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15052524748540915055,
+                        13702844710624470733,
+                        8040530410300050590,
+                        135896560184497848,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10539934018129770382,
+                        1030146124052204731,
+                        3394420349483345218,
+                        494758599635279654,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11369543984476117867,
+                        9193810295425809537,
+                        18111790156178218642,
+                        1014928685882141759,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17885979731633433876,
+                        16064339243219948907,
+                        8015869308413939932,
+                        1118731325919189104,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4603859886525558133,
+                        10403297319359819671,
+                        17644273712093179026,
+                        773517923477966456,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9414460919378036566,
+                        2836118824456961759,
+                        393226623418059265,
+                        935991027477016101,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7508784716043117372,
+                        12918366147892605654,
+                        14122071146674762152,
+                        184305070592805534,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9704450609668300530,
+                        12773322716620929545,
+                        18133043373779616283,
+                        1284301129530408937,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12159281883626299701,
+                        17898607519852457210,
+                        18220512499689333126,
+                        195447998324121279,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11103201505110870122,
+                        1138773752740142627,
+                        15868775783474153240,
+                        219815342895618337,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18409086073518464162,
+                        3615713095999085904,
+                        13402422047572271240,
+                        77362615843921821,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18113855572952389434,
+                        6376509465227938585,
+                        7935908923454537416,
+                        823559107782765968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14389945955550715798,
+                        8003203011235195412,
+                        5362678512686581594,
+                        736594277837208611,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13357637130299263007,
+                        9445195884838308475,
+                        10598615374168388181,
+                        603938462627632035,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16455844468691134562,
+                        15095145213542269069,
+                        1583109288577513019,
+                        635483821348910700,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2768562542882269157,
+                        13706619400199243763,
+                        12344466653523484882,
+                        381752914957517224,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15547946363093700194,
+                        17148748836848239589,
+                        14048619543543400549,
+                        1169297728489381498,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10481529430215110752,
+                        11781785790876722389,
+                        13433384536965679483,
+                        2547912202042006,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14373980353237724376,
+                        9556719750633589670,
+                        13169516918133688453,
+                        266617866018133202,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12552215147513969770,
+                        239491223540308800,
+                        1741596999143827061,
+                        1239077242124583292,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8461580754799358115,
+                        15439145509639676123,
+                        6448902691176914406,
+                        617961991479176396,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14021046638478223995,
+                        17904389737486684287,
+                        13361478806540976298,
+                        243538844707003958,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4241336437417975408,
+                        80817564643479686,
+                        4634871321447061784,
+                        814142143551201254,
+                    ]),
+                ]),
+                Matrix::<1, 15, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17221090726743436067,
+                        15324897122626883667,
+                        18347146221260468574,
+                        745422215628955184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15416703630431627623,
+                        7685107234997501999,
+                        6557150203863011143,
+                        1144127789779521728,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6110254941929191621,
+                        70066620069299456,
+                        2420813826434383048,
+                        1205212406483401412,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1463193107860259479,
+                        15865895841300384351,
+                        7630988860441561199,
+                        508865607373785151,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17816116335349924202,
+                        8777769196022059726,
+                        4435732983212391909,
+                        1280911228553451137,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17956623288897661153,
+                        1774535656761176675,
+                        8181443168388805196,
+                        510726924812699745,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10663056138803963457,
+                        10801296670116781421,
+                        11273692402697929970,
+                        274505824009787739,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6125137483769734284,
+                        7625723386253684398,
+                        12723125141494317423,
+                        957449294716974291,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14368896505100920296,
+                        9846845732131196479,
+                        13356609969162533271,
+                        325952031059859021,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13687801122316581327,
+                        12919375400899629346,
+                        2160525751006478241,
+                        478878315209812632,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        974404595883832425,
+                        8887658879798843296,
+                        5402967854458989631,
+                        269820373014200104,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2983557801741294798,
+                        9703554571338623981,
+                        15655272372866689888,
+                        595712720204253925,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11770225321669942082,
+                        14463311283850963162,
+                        18435992681335614041,
+                        898563499334907854,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10296169594521522313,
+                        3568934371173015599,
+                        15319632892980313966,
+                        956129015095074752,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9394693273908125991,
+                        4929318090062649681,
+                        5638024707374626465,
+                        1134688223894681422,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        699159006121912295,
+                        14279466650777789460,
+                        4939937579884041597,
+                        1017082372351395396,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1477851250516161867,
+                        5413064237113646004,
+                        13597222131169844970,
+                        624459990153521964,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18359563372370245081,
+                        3819301594510390091,
+                        2392655706678069430,
+                        43188089203424299,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5250760138576676173,
+                        13845745620862124721,
+                        3015685424282426947,
+                        109582624354065779,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13176317722943715068,
+                        6816765581649393581,
+                        8185401893312863358,
+                        1124180240365435802,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15875655342365578103,
+                        16319086982832767240,
+                        4532143049080509570,
+                        966261975930384894,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11128688392247129616,
+                        6142984630695405757,
+                        52307268044029943,
+                        170080638725853976,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17229028253275539495,
+                        8007972473388264908,
+                        6883280005668962319,
+                        1068899332758660687,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13037229471829089347,
+                        9165981502545003926,
+                        11865894825327322436,
+                        1031528592219571110,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5259400325387435111,
+                        15660626299826583995,
+                        2107820284907104682,
+                        338250694110698269,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6418539163486760578,
+                        17664401637728469300,
+                        15523134576638463947,
+                        1282766191205318603,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2392028063498899799,
+                        14526566396119718804,
+                        7869436056856552768,
+                        912839644114432060,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        458071265827297405,
+                        17980132859994568976,
+                        9919476817220431966,
+                        540365348309667790,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7267288061295828820,
+                        9657852397968678123,
+                        69913071222011592,
+                        1325579251099542686,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11514076649704206503,
+                        10385521512029851893,
+                        7289678841490839816,
+                        189998872249050072,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5299849835324845426,
+                        136085227291172857,
+                        16085000881701008097,
+                        1313462513760893960,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4970614412312010161,
+                        7294243932348782083,
+                        17751997520786549686,
+                        768095050136727956,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12367200146281169243,
+                        17823598750354398925,
+                        3805660922488748588,
+                        483918214142540391,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7604355323618800545,
+                        9782933298126314748,
+                        13240625904055814172,
+                        320200177400774992,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9434394177376024463,
+                        11715815229614652621,
+                        264169652567010656,
+                        1054954604664653486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14287047965134562102,
+                        9137891754266944990,
+                        3282961370026436054,
+                        1295837212834164165,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15757645315456482499,
+                        17142103962361126776,
+                        16016106932992144269,
+                        358464224688130195,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17162570296125865653,
+                        9243134575552973358,
+                        8669856494132511578,
+                        46763789119160590,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9347495667274228747,
+                        6710231525788739283,
+                        4779146807868036817,
+                        160837736695348505,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10513172011657996696,
+                        12211620272063760542,
+                        16670229682069275567,
+                        726174503996479261,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9792974096173238971,
+                        16502042636823185689,
+                        14966055087243658730,
+                        957610992162562704,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1070476617790343126,
+                        16778330916010498912,
+                        6265014412379157998,
+                        630885551441922934,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12610672348520636583,
+                        14175781344369156529,
+                        14022493773530980520,
+                        1021067590977091822,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5420595003948192664,
+                        9609463903068578715,
+                        4362266080694986465,
+                        678212879227771858,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16643998906435329993,
+                        7734082066616230311,
+                        15160156161973306626,
+                        378935900693052691,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6724451566128092458,
+                        6395993560083827187,
+                        6877865076191629231,
+                        1327497126831006829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2170429950172237527,
+                        1330238991271064562,
+                        5662344546739996769,
+                        167486679204160258,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1462336215365222052,
+                        9643216086433234418,
+                        7889853971606623047,
+                        211171469829959633,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16445219260967261003,
+                        11125225267843037143,
+                        1012535222655940625,
+                        870599411236711880,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1880700530832358381,
+                        418048693445251697,
+                        1498074106918029144,
+                        1209482287843232175,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1003181332019258354,
+                        6931665797741725935,
+                        7922872143659902644,
+                        690023113395680224,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11956480979491066718,
+                        8406149916798008977,
+                        5730816280235342853,
+                        1314954801788275421,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        704114634240029356,
+                        11148276147575587751,
+                        2917634916684208056,
+                        362863078152458976,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17319959771426420249,
+                        760981541129014018,
+                        565157441241500167,
+                        1140557993818537670,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10809178961833792363,
+                        2857319547271361851,
+                        15043530875973048101,
+                        1105762991102454870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7223830021907634130,
+                        4754517234860217475,
+                        13729305605154582993,
+                        13746108289629253,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15163461074441552744,
+                        8945496984994400055,
+                        13869989098618718424,
+                        956661993569183188,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3781899673489235780,
+                        13791270901906428686,
+                        1478316920212022003,
+                        51974837087085525,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14494495517957839573,
+                        2089064203068574840,
+                        11456301275664748502,
+                        55846697519717964,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18031749279683347312,
+                        13915420351400747658,
+                        14697740325048596910,
+                        1325404378431200196,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13773030683830317026,
+                        17112865191117612745,
+                        6702427423348914998,
+                        644833348609199292,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4442620382967299418,
+                        16631170805494914267,
+                        14936330672697990739,
+                        661039898179656320,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14389768689039335246,
+                        11703323796647467765,
+                        4403219157989506879,
+                        309808708636602119,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6524892683855893596,
+                        15527313197714976224,
+                        10715101957055006852,
+                        176260006184099219,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11261933972898978590,
+                        9876852334713959713,
+                        11466181922235492865,
+                        237195604187124714,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15996234538586225635,
+                        5193203181518273719,
+                        2839914323899010071,
+                        1064446206731864090,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        66088205595860007,
+                        9328912830839784785,
+                        6482292978332779203,
+                        166383720700028920,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18392679901544648382,
+                        5542649407598579614,
+                        8325480025155851442,
+                        1338175506881989998,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10633358613857452389,
+                        1855241799745079543,
+                        3873168414757903106,
+                        70070949412607505,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7588280027296931211,
+                        4672573948443980308,
+                        16371000633488064217,
+                        178719900788151689,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8059635391088744697,
+                        17156795384141687623,
+                        10880358321486325522,
+                        320665603617535735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6637973030802809038,
+                        8981957882764060580,
+                        15730419390835794933,
+                        625426772765139347,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6778671912906879,
+                        3452374936022808778,
+                        1289936481611051097,
+                        956501322511231997,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16513449992595809308,
+                        4873861491214276956,
+                        12715125162581127071,
+                        1208772370478617809,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15273750507118480789,
+                        18350792036790004977,
+                        5667261015818160938,
+                        1047494586258598574,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        707677038591692733,
+                        3615332054370935850,
+                        6307303907041444985,
+                        159316356615575777,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5189936537301967695,
+                        188546369911331201,
+                        8912484149224268209,
+                        624289879534750415,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17689405010780223346,
+                        13018461193285989601,
+                        575509464580346634,
+                        775606086623339962,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7698643453703433538,
+                        4992127824276284163,
+                        8414487220749420952,
+                        266481513947911096,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12748664112476395220,
+                        13620409482550898252,
+                        13771515773079786598,
+                        408374950110366338,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13643224615931713625,
+                        7484536972985083116,
+                        14381362117799421920,
+                        660665348648552569,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2902455771332189807,
+                        5662321220657993670,
+                        8290083583424715072,
+                        555620818713931595,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3307285117580295990,
+                        8550916732356889792,
+                        4405280800963462113,
+                        817651577225450433,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14137538289077684208,
+                        15705440547289436237,
+                        11113622573402613077,
+                        470496662436676930,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14480033807270430815,
+                        652244382541695893,
+                        11219889340181421738,
+                        648493811010715758,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1466687870585672942,
+                        8742021103982432969,
+                        7219673313127902361,
+                        861906616114810145,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1700303263502386363,
+                        10152855368337219599,
+                        12822029267256061255,
+                        1291408348615344851,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16206658350952769053,
+                        10714286594523003424,
+                        6182053735266161562,
+                        442505066263002451,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8990405724898462948,
+                        1692823442727123213,
+                        3741570399780115568,
+                        1123776179800359006,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13496483979206902289,
+                        10905983868071095397,
+                        11362525156220686174,
+                        1102244349000121268,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12968367382761117019,
+                        4535968714278569087,
+                        16778003226688548500,
+                        152185932609389863,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        466327330122562426,
+                        8077077138693350056,
+                        1251538931239373795,
+                        334834735143553908,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2616740620323009845,
+                        13283505405410921990,
+                        13982493046383884655,
+                        264454558719810902,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6309202149658379990,
+                        4528820966320085588,
+                        16128650385624600264,
+                        1290775016624164818,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10451951531282426327,
+                        3371970209947243704,
+                        3013828086437428460,
+                        179931308567029354,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9183410780288425007,
+                        8337961597074415434,
+                        11733207528223070884,
+                        592745007634367259,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17770572532708859720,
+                        12813471068440955373,
+                        7921740698788153779,
+                        1074277143322944124,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9664249399700299104,
+                        4207724815109217138,
+                        8568562542864169354,
+                        760887040974126063,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17461467282119632485,
+                        2609950469904423715,
+                        11147933204863116252,
+                        447366519404357155,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16846311582607572436,
+                        8791831624866741911,
+                        16289354140977880759,
+                        533959160039803110,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11919267991222772392,
+                        11892718986573452037,
+                        12767026677400418241,
+                        1757584259718574,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4971298240480684896,
+                        4322165940141131169,
+                        2674413003846609332,
+                        198498669705495366,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9584297435906974171,
+                        12023794369061442468,
+                        17161526726353223629,
+                        279090744552690546,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15929115476911761042,
+                        15634965758634860088,
+                        7508644874856417857,
+                        753275064028662966,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12045416156803912373,
+                        5276132173114307275,
+                        8801442326793308201,
+                        130605774293211944,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12847738785006757773,
+                        14010820916234829919,
+                        9073381953660804299,
+                        143204989481860110,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6005583813759025382,
+                        12399177314236408117,
+                        8962687823983418307,
+                        945599747624482381,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17631629338784338862,
+                        17934406346649815177,
+                        16993186537761683178,
+                        146017590964112992,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11710016989474966636,
+                        16058554074516784415,
+                        3823273802230239441,
+                        1103784421844072484,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1044582473945537645,
+                        15574725211190518763,
+                        15749329152288295312,
+                        244875155277073060,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9422606421813046628,
+                        16604741110484638065,
+                        10084706833582250553,
+                        1110418956011733453,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10571825333930436590,
+                        2758440025940036277,
+                        5329766164337887759,
+                        1080258613174387500,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9711728800671713401,
+                        11588002668106519953,
+                        6343689459179758557,
+                        806374752255112677,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3086267661355546007,
+                        3090656264483278144,
+                        1779327481004815695,
+                        408653962647343091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6901236978665872666,
+                        5805220834790542879,
+                        8691096844564588129,
+                        89674699736710054,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14685650703008017217,
+                        10889727541648864717,
+                        17395971106816503196,
+                        556933099351307834,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5917412790273340009,
+                        5818941294792307842,
+                        15625113731029878012,
+                        787172723403452307,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13536893062591924038,
+                        16121749665357959521,
+                        1971275066550816124,
+                        208493873118691258,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12343138320975898866,
+                        11961917097561594326,
+                        3911257316214471209,
+                        927755953928672565,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1214248716639750938,
+                        17867968378242451013,
+                        2507939416895729197,
+                        146556545549575975,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14725941977961863193,
+                        8054898665583520580,
+                        5602090640913367400,
+                        445826538580204747,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5313036217393081931,
+                        5642750746522678307,
+                        18227906960597223171,
+                        711278315231759539,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17805703013711805712,
+                        7963880103626075080,
+                        17958711605001694874,
+                        164145947754070578,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11492399476955178862,
+                        11382258595027109672,
+                        11997079039125595580,
+                        618590481484818515,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5714365223740950762,
+                        2169493694155522514,
+                        18279024666009629405,
+                        830122544207670015,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7925070925776123446,
+                        9165665390575987392,
+                        4194674121182213592,
+                        197405909031282025,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17200142817982741717,
+                        6872786709923881962,
+                        7358346062702047645,
+                        533519758314675373,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13185948658159145370,
+                        13044567438260894441,
+                        13749632118896715450,
+                        1143390827421913057,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13634272652988931135,
+                        14249280429317495228,
+                        14205401663652872128,
+                        1034829131446224106,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13676543240647630437,
+                        14809378250978698873,
+                        18208694265651913132,
+                        524237820183635814,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16279610306393942161,
+                        14392248190611786025,
+                        6676466871045001907,
+                        1205363788239925275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5475598118485768460,
+                        8088385900890425275,
+                        12369916408441250806,
+                        1284931590754002357,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3717470466070007650,
+                        1568966207777738247,
+                        1114310519466517607,
+                        552946486376426786,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17571113599051400485,
+                        2412989460191089981,
+                        2857308426581441527,
+                        985691692611153360,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1161599634381815899,
+                        14326219709418740054,
+                        4087179026702615726,
+                        1083362519451030586,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18163425331961913715,
+                        13443499957027996877,
+                        945624214926040118,
+                        709136539945075479,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2607668470213086847,
+                        4723281499638144543,
+                        7735932038421512665,
+                        1188365627187680238,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        145035780751833663,
+                        6051761088719393604,
+                        6455022077446548058,
+                        650102884243199105,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8188973217018912015,
+                        10637649518015774295,
+                        16570908046683871819,
+                        1080162107426918006,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        789276712413031492,
+                        10646770092462882438,
+                        13765586363987910543,
+                        549557205745742306,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6370629464296726133,
+                        12606041588617745030,
+                        13451140642393135484,
+                        342495783109286154,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10833599434702961429,
+                        4214079752894537433,
+                        12442075643317230805,
+                        71132020164483193,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10390183143803249149,
+                        5983032904858106820,
+                        15616605850013175628,
+                        638306027179981139,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9261522533760217574,
+                        12853616144646867788,
+                        2740293911332651429,
+                        814463260904344834,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9793916419406935018,
+                        10969722165783573161,
+                        7257343412411992021,
+                        774269507208038919,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3031215386214078961,
+                        12094514835960463279,
+                        18247507683014016520,
+                        209932479442427739,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14693689705718198727,
+                        12873155871890390708,
+                        1079274573416330409,
+                        881909120478849490,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        779968322898680043,
+                        8913229876304944867,
+                        1612214061152341626,
+                        201519635666743849,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5766603877054557003,
+                        9341309472798460113,
+                        14764479918346227441,
+                        1215060342677005749,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8587123365848649475,
+                        6061516964148108487,
+                        1360400216541731779,
+                        154173668253844505,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14781370603010632817,
+                        17248516007823242790,
+                        17902683527951395311,
+                        125455118135176116,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3886454671682897273,
+                        5853091081928995614,
+                        14980974525061530111,
+                        123343898398221961,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13417882887589327552,
+                        5519129834283256531,
+                        15300841482391542888,
+                        120855041767057513,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3246813212515750981,
+                        1992639251572763693,
+                        2531348061613661610,
+                        958598680763037662,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7811548523069574083,
+                        12549422204666624156,
+                        6389259131333716659,
+                        578509047095492793,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16337572482983919825,
+                        8961697423010256623,
+                        3535884641044510328,
+                        59982733461895337,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12166082288808494280,
+                        10353740138916199891,
+                        12484104132381472713,
+                        619041241026303282,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        731034507161159154,
+                        810898692573603191,
+                        9689834160182969329,
+                        550519416689671112,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13238874517147224426,
+                        9516708627625030423,
+                        419978559173975012,
+                        1145891982727360479,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18246172286768460667,
+                        16781006492757515190,
+                        547734111618271330,
+                        790223744342017434,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7462875441915875459,
+                        17553109192937610774,
+                        10464610559306485096,
+                        1289480584843080995,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17481792257955296328,
+                        13220816228578054012,
+                        4958726960213098026,
+                        779463519903242306,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15833166899343335297,
+                        14932389102127522201,
+                        18432748893191396229,
+                        587328034396138921,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3975158305385876959,
+                        10191080260230140642,
+                        3172040667439131491,
+                        1324129995118653535,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2169298266636357501,
+                        11535327414868831907,
+                        1462555702331186890,
+                        602825153631809527,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1297335091228518946,
+                        15941810514390837536,
+                        3198143226190964373,
+                        317210675761175724,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17488274619941913770,
+                        2129123910444028703,
+                        12314084021511454631,
+                        199069556192565977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7351842367723351055,
+                        2254069262031358787,
+                        15972110516213546059,
+                        713495322816260355,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11561940077635866762,
+                        17714266667865440669,
+                        11411502195461636656,
+                        776187034583334884,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1134057078811875156,
+                        10554738513219212163,
+                        10815850369253677726,
+                        1074510439715006369,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3105018205463288745,
+                        9913462585003774011,
+                        3149903688746148123,
+                        966081305966990223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5591561423565154565,
+                        15252924383271291512,
+                        2177467073058932107,
+                        299529495808333355,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2461749648089529464,
+                        5396070135744457270,
+                        2217603908665995111,
+                        939020424541674717,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18240484937332497903,
+                        11796369536067455221,
+                        14571916936994152994,
+                        254008898474005388,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18272326097879839138,
+                        9670247754362721996,
+                        8371883943776750030,
+                        15702536687195101,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5647726814071239526,
+                        5794320926192828715,
+                        8448042738169121745,
+                        443387197683698641,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9312865037025212654,
+                        3404864915605907308,
+                        15488435841983146980,
+                        484522810872840275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3834411200061165264,
+                        7402837245366531902,
+                        6876658264867603078,
+                        13325753966229588,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8722416355411341864,
+                        1230031018010629748,
+                        15908135762163590339,
+                        523123759604285438,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17472018795991244338,
+                        12506115591644732,
+                        16489667710494419622,
+                        479557302185385213,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11891203626011956797,
+                        17428163882695446102,
+                        5635289138439553610,
+                        433057298305959486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14195639577655093161,
+                        13488449911431685181,
+                        1523901641891314417,
+                        753285561504438206,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17738664882129163,
+                        10119755425672321853,
+                        11141889355486077623,
+                        599857039246675581,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14732391734463018045,
+                        6767806453622655514,
+                        4064479125918390184,
+                        612909841540880606,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16585473329048242337,
+                        1722932065307337218,
+                        10606216691467542581,
+                        831838446602035086,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12945309610593271475,
+                        10890463532582216491,
+                        17820707476194692003,
+                        797522374082099173,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9666273966633563718,
+                        15129200142688080612,
+                        16698802030583477614,
+                        454813986624560328,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16269399371899088524,
+                        6597166600536747420,
+                        9652904060160374756,
+                        882061930604363475,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14341908203633708687,
+                        7458836925582485170,
+                        14933449325289145662,
+                        462229419670026201,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2323069650869830135,
+                        6965866109754762030,
+                        3572551510503435765,
+                        76250817999411369,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2343941471336476107,
+                        16477420956493808217,
+                        14320782772863476280,
+                        737262216349298727,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11279963015322634118,
+                        17760208847461335657,
+                        5414184486241346644,
+                        710717506349959918,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14518982604304675007,
+                        8573875087040428290,
+                        10070987487530987819,
+                        477530187902369619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5503093090708336635,
+                        135364544204463429,
+                        1124680258516362017,
+                        215014030980545675,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9002779130491503157,
+                        983537698338667143,
+                        13367989760147379422,
+                        299727315762795658,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1074533944442027187,
+                        16700468662572179441,
+                        3313571904088466449,
+                        704126195719125963,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17689661666321335924,
+                        15765629468130021721,
+                        8392180329388669632,
+                        634861863346661022,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7912880506942569752,
+                        4096314479987581536,
+                        15370115622884567100,
+                        552320323970316524,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13296163807016195409,
+                        13162462987450757121,
+                        5246829794894305323,
+                        275433060179711865,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9312419717964722204,
+                        233640467140258260,
+                        14004212198696367789,
+                        1010046517448364046,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2154957414134297997,
+                        3998836635246759132,
+                        11644320618788238134,
+                        849615373567410648,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2306063502727787824,
+                        14768513766490989092,
+                        13053875778486413473,
+                        37328286648854512,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14037094849829061046,
+                        1333124419871851595,
+                        15580908204995718943,
+                        829733229474523023,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7726891882135799675,
+                        12810755476883471672,
+                        12733088681766315017,
+                        969464462450552364,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12898266306999227612,
+                        4228832042927467618,
+                        11542261819483501822,
+                        415690652005598349,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13134096319203003864,
+                        11337154834795229533,
+                        15895125949731910500,
+                        1146506454802974079,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1164259803972606054,
+                        9870164471981435799,
+                        3045578765362513258,
+                        591130868756719192,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2203060375152383924,
+                        14882573278981868778,
+                        821495062591875566,
+                        1076107815488761734,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        58034418635325076,
+                        5931720955476239799,
+                        17700444606603321974,
+                        28008008687323503,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13659699171602269481,
+                        1566245011142048465,
+                        1112287368049493140,
+                        181458040774508635,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14743248820033477959,
+                        15017478033367667174,
+                        15667753959842247294,
+                        430081776524267662,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5693312429547240223,
+                        16765823363957265696,
+                        8145471825194761307,
+                        833643681203244790,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10870758983356202999,
+                        13006064856703686820,
+                        11294631742138232680,
+                        1271795078296341859,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13506275041333318836,
+                        5287346607341743596,
+                        2663862482951957813,
+                        187021720147840481,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7248631747631313055,
+                        18202526096042775374,
+                        8235687004948054090,
+                        410602160339464472,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4401023098131997906,
+                        1112642961324121458,
+                        11346975734032359259,
+                        56378521357432366,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14801309991194580405,
+                        5788947724059903605,
+                        8479301178577375740,
+                        65981280359369701,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13082085618633751007,
+                        13524205625794924045,
+                        2131720709167671278,
+                        925860288139002153,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16294939994869684010,
+                        5329106914789579964,
+                        5085444078128534588,
+                        459289995645371456,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1664396853522362490,
+                        10785132384271837358,
+                        17672371899388315762,
+                        448516393644478094,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10649146519025902366,
+                        14875085564568134201,
+                        4280126381235245680,
+                        238193828294899177,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4993930019559112478,
+                        2204454836961692353,
+                        9104960700187957501,
+                        133196100448411219,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16351922889789662448,
+                        11081483132460922245,
+                        3656039160831385006,
+                        770414535064638084,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3485905599017581359,
+                        15306820283499307335,
+                        10005798296230777205,
+                        1092160105867455239,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14667946693042100469,
+                        2353201748263949036,
+                        18288606741232258250,
+                        337527033195810650,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2989019155161379746,
+                        12346194913133279863,
+                        16217983779377787321,
+                        1041615171124082330,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15915517393089023272,
+                        8311509844862061546,
+                        3411040221097891327,
+                        436387282184774198,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10249685428425636525,
+                        1982872961518760911,
+                        11941555184482691869,
+                        1012269617078991635,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14073355320873088375,
+                        1647605370243472590,
+                        5014968819434531254,
+                        131927896548238717,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16262913725532465909,
+                        6954316890847280376,
+                        18371596249357929746,
+                        1192341556481709573,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17357253883043511524,
+                        6714979814283689078,
+                        6563465647304897063,
+                        4818388017986642,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15436647044718286368,
+                        14348889261715358846,
+                        14805472984240575578,
+                        419665870939424678,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1707710654857550991,
+                        6464989642548990989,
+                        18087196988521563311,
+                        1121343133168046133,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10873746953900304334,
+                        861148677682274206,
+                        8884184373390325613,
+                        344534133745736968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16480712446356388275,
+                        14419596629717658197,
+                        17899895491332304806,
+                        468841409851713259,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7603571544179537134,
+                        11008291844150674950,
+                        3142822067985155074,
+                        29400407444546968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        334201501497489377,
+                        1738050167224521221,
+                        16372099980568324002,
+                        988334659528875952,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5139244194067180448,
+                        283043949425358093,
+                        16749588594732673084,
+                        525840292374713551,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12607989235356739024,
+                        9851708371005272634,
+                        451530584655930951,
+                        276340453516691856,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17012673057186851301,
+                        13961530717943959863,
+                        17589410001619022197,
+                        271271885753411001,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16811905466539039683,
+                        13611713154355822389,
+                        2617872248129003143,
+                        491892533865982916,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9700546141087699407,
+                        6496457657126518329,
+                        14854824179018450440,
+                        7828257391488913,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8161757032051451133,
+                        11776200095644205712,
+                        5453105552573932194,
+                        140039780335229042,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12364288227790618435,
+                        4229100980162099637,
+                        17380289909605688513,
+                        871479050164295350,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15320028351406736324,
+                        16819732040038687423,
+                        2362389696494368422,
+                        909241830908426278,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        707049630614740777,
+                        12632094253487531066,
+                        9311832154316579738,
+                        1248066801864412245,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2177437500137081865,
+                        3575425207750152087,
+                        5279750703392419302,
+                        1333772352850499044,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2186083214420214823,
+                        12842829892802195560,
+                        6939015407368584291,
+                        672778105728168878,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        631618562699817743,
+                        5714403616471548872,
+                        10053499728582688830,
+                        1308100873282303998,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14109448919517489385,
+                        5116984942123763692,
+                        17150124542168554263,
+                        731397863779331562,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3648129690185968981,
+                        17784116849810075643,
+                        12376154357355246324,
+                        134697882011545294,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16437443887905004219,
+                        5914668251083446331,
+                        15859806977084525346,
+                        219616271236068010,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7803326147924776779,
+                        15728234810293447074,
+                        7934980143459560883,
+                        137393420113999108,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13056146939888671167,
+                        13303824277126139141,
+                        6984569411818186554,
+                        1101686615842848516,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5690152028998264894,
+                        5943465810886223966,
+                        13510077524624952164,
+                        705703305835800090,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11656797233054011391,
+                        16783724411141088147,
+                        13070418942972706647,
+                        221467745484837476,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12886375817555630360,
+                        3648986493873860365,
+                        5863327170606347980,
+                        1124801117130136795,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8816721710676588314,
+                        7527156128305952226,
+                        5586507383978522402,
+                        43082476741164620,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7066346324084258193,
+                        12320507497190757953,
+                        2021755434486996810,
+                        1267054291645191894,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16507984488057599615,
+                        5644013230499399719,
+                        41198116005181680,
+                        794590164363510160,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11135192916163954768,
+                        12135492047831831515,
+                        13839908489284037716,
+                        1035670825456775055,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12446546425281544356,
+                        6637532844731923236,
+                        15276683708111798314,
+                        337690909939452549,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16481789771028510510,
+                        3410796102841485038,
+                        17129326759316786468,
+                        656447769837026200,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2923294988170845049,
+                        10458093227526370823,
+                        6548289152155195641,
+                        131755607175292949,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15128988589083187596,
+                        5406157119458677273,
+                        16734335137373854443,
+                        451707284409035104,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6086652504126757766,
+                        4469015479379765745,
+                        15416415084984193608,
+                        585679322812065899,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6550121785460625769,
+                        5802617336466924853,
+                        17893258258029860995,
+                        220260474403582989,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13659413414721120438,
+                        3497558319407654737,
+                        15376794163531801144,
+                        225207653975119282,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10624528774704695374,
+                        2350395164548230841,
+                        9273716112703314429,
+                        683686260621899964,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17179163731575066230,
+                        2891791635125275704,
+                        18327597241046137264,
+                        1144863874541346440,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1645149665793036309,
+                        3732101908169526562,
+                        8798588532665207967,
+                        1113567759426913234,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12331002664111013675,
+                        10041375714325900591,
+                        464513446204948509,
+                        193657929718060430,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6581097008020476827,
+                        11395738005110117685,
+                        7088087779796901964,
+                        1257356003934076704,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3749760962367119113,
+                        14748171795056555454,
+                        16763083104354435667,
+                        259615591724029549,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9797844412237174158,
+                        2948984465973037177,
+                        4027653854977196589,
+                        1230574746067587673,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4778486209973700203,
+                        2520979007810588139,
+                        12021518785539187804,
+                        268153623999598635,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4305632714803844667,
+                        2308195011442256531,
+                        14282543683971627719,
+                        809208856696077204,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4489173809982488896,
+                        7964947623263908733,
+                        3433180209718351644,
+                        865614208766743247,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3500386819375692152,
+                        10730561962117534299,
+                        3994418405643932735,
+                        639313725689542107,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8048848815732444241,
+                        7822853970145279501,
+                        14131281826081539989,
+                        1027522473742334156,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9190256396666201249,
+                        11042348779764164521,
+                        5948054179034743226,
+                        446277239044053049,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10544892612579415470,
+                        7553238541304337087,
+                        5278287144814149707,
+                        523458031265064148,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13434517506075559832,
+                        7293736277278938386,
+                        7390889080946239558,
+                        787300184613679114,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        251220867351467850,
+                        12609612884537668578,
+                        7107691731990001069,
+                        937536721454473111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15934325680389972422,
+                        1969681687142755427,
+                        8814774317558876371,
+                        539490414702758300,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3664902053479977357,
+                        18238841591819812527,
+                        15855741853268400474,
+                        370183531222467818,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3838017893472510678,
+                        11658442040074939281,
+                        12576028504577409256,
+                        1178030457104861028,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10401323756466159791,
+                        17250889441982705988,
+                        8341066359233004729,
+                        1317806859425259874,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16889342767767720634,
+                        13101171158650783486,
+                        11235808991286761034,
+                        1014311106644002613,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5915430106044325554,
+                        15795796996910802791,
+                        16597880229598132049,
+                        502019107693960965,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11999030182262880154,
+                        15733928718466694897,
+                        4641237726806406924,
+                        657739136748050500,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16538347197445924667,
+                        10612485687865047295,
+                        4483790368489476538,
+                        746218533154677818,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9496800215380265034,
+                        225482054347562786,
+                        17702770859041652524,
+                        1122768377092127757,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17614693621675908741,
+                        9370320938909967136,
+                        16664685087441727156,
+                        1049519677248431257,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10034857149720079787,
+                        8159731428446115687,
+                        8855462326597922059,
+                        818339792586277277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6714505947996469571,
+                        3730834521905119957,
+                        17281731047790104251,
+                        1191245802004998057,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13141549112357832307,
+                        14627805027993306062,
+                        6367630278208848485,
+                        781842912563555293,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1746725214614318259,
+                        5577030999780401696,
+                        16059349224671923525,
+                        1087500505270922364,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11290886721692646331,
+                        10778879896653738236,
+                        16461351675652828085,
+                        1217247022133572608,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15454527028177408887,
+                        5598411702808273838,
+                        2538685581166304400,
+                        892031423268389707,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10686398102380825406,
+                        3409269897046948656,
+                        9938857381772098033,
+                        880058095488276658,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3082853321318845817,
+                        8887719263600197415,
+                        7498150079433280018,
+                        114873718760542345,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13696044692680678306,
+                        7542965558656976374,
+                        16184783832491631884,
+                        1023116247406317634,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17275801850768696430,
+                        6164655877742291024,
+                        15567316575495772884,
+                        662187106367041681,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4199368773165941050,
+                        6428682063052921812,
+                        11999967327939309479,
+                        927229666342016826,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1871402780464507436,
+                        7910100999369099229,
+                        14970086346897587366,
+                        736697940484237597,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5578401027374544850,
+                        6256809206599734809,
+                        8869904270634844384,
+                        133776055021771070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13521984966672117535,
+                        1390374000397863166,
+                        740247592408913771,
+                        607151862066069692,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5683532750825414304,
+                        25177877907886679,
+                        7875323771674046475,
+                        312815558589980463,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11933478401283036435,
+                        13526723801526914533,
+                        4742241324150241583,
+                        867421983169082171,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17496415371142694268,
+                        10126439994011873015,
+                        16996511097640025362,
+                        803281562983938058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4148115947861810971,
+                        1799897545614599742,
+                        12760655981348405695,
+                        514747917434762915,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2422148077190839949,
+                        8494227023593415795,
+                        8409790620514987087,
+                        265499588911373150,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12886793599904985676,
+                        8109888210125809070,
+                        17411834614240936061,
+                        1009764043870883573,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9305769643475474432,
+                        2059488294831498489,
+                        176592238401194517,
+                        629869519764040893,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1042267679778899944,
+                        9958469898829768884,
+                        5515233986299780433,
+                        25403908628511944,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        179373993413416162,
+                        15729700904430633507,
+                        13069228414615752063,
+                        1060316456195789730,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5929657296343504551,
+                        708912648081271842,
+                        6597727330835881607,
+                        797387070589429108,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18437525662895797839,
+                        15966860023477874531,
+                        1439028836312885503,
+                        89001182312227081,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11285367563681073882,
+                        18439416288412858102,
+                        18146705377473492798,
+                        644429069997451188,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12196485244492471745,
+                        807092148251843400,
+                        3274997337506596835,
+                        571804461717957072,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14751260114200217636,
+                        12673433507316118482,
+                        13522768576373099772,
+                        58614177721682024,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15834520323435657709,
+                        7993099683698298137,
+                        1818551858772895501,
+                        1299189320449506649,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12214860895434830733,
+                        919848287780040080,
+                        2915348077186141288,
+                        40561882559295691,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5130023312180708548,
+                        1208675228216168971,
+                        1116167660746074481,
+                        406040196026180244,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        509113287484362624,
+                        2003200983195867153,
+                        2236155920857620185,
+                        73804262717998587,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7102131920826986305,
+                        149716538330841241,
+                        14160392710948098431,
+                        186087518644478630,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9921546435664914203,
+                        18281631604067830639,
+                        8884988240077276200,
+                        744530012257641817,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3637675693608396419,
+                        273942441838961042,
+                        11512817959041831815,
+                        21553412523732481,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3048776676845011108,
+                        2758309865076332015,
+                        10474777552900636550,
+                        679623511229907386,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        608797571799087358,
+                        17703927531538487670,
+                        2297907098423896805,
+                        411154369741638274,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6486873577129510742,
+                        18108565536595825363,
+                        8799786204538254703,
+                        843565178909356808,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        424570687790875910,
+                        18154541829710657,
+                        8250078142970755289,
+                        4566734518318785,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11523940086557962065,
+                        7966433529907704256,
+                        4603906173807366780,
+                        773464103959188245,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16957912773807818503,
+                        13941584247244812866,
+                        5243185425257238108,
+                        743884830172385341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8814935328614591218,
+                        6587927096081629940,
+                        9458646251246759132,
+                        542340116190552124,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8501259402912883052,
+                        17407341261954633602,
+                        3100891947572205705,
+                        598847261362894669,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11929045645209559073,
+                        10722466834396035142,
+                        2104298342904123666,
+                        143277829205064941,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6921912723011129754,
+                        1352924675772602573,
+                        3406716546400112522,
+                        782181871871717215,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13925869833604591746,
+                        6039499828622935881,
+                        13210902187070284078,
+                        305508027754228963,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8585055511669088497,
+                        2072790753657421724,
+                        16069552765527771023,
+                        813149664328018237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9300294027292993741,
+                        5640044233071876884,
+                        3489169628955024884,
+                        789090493367884274,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11277049670603387662,
+                        14374180316381730305,
+                        8240944318488353279,
+                        831609577259257511,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10290384901919546948,
+                        8442987064873475977,
+                        11296521045274676591,
+                        521697421265539987,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17100935867664716358,
+                        14008154400221100857,
+                        399087786521487238,
+                        454210233702246453,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18269315944718998774,
+                        8355709975012550294,
+                        3675730482516297904,
+                        1253370801655019147,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3912083879293660169,
+                        5031529418237919087,
+                        13257935311054359290,
+                        152683372602923125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12232434876981402025,
+                        9606823973591601573,
+                        13084017124934326243,
+                        693728796229430458,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6932201368467086594,
+                        10795643267063460146,
+                        10162533319347272886,
+                        540025812037758514,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1598749542420381058,
+                        7959229720308188074,
+                        9135058030856655711,
+                        181338799502983501,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10502482565319609383,
+                        5519756734934555700,
+                        4711456253766098604,
+                        202339198070997684,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12301753791750460199,
+                        5984711678411428406,
+                        16704548851687679171,
+                        51912459321602215,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11128293513828329763,
+                        5604628064042831866,
+                        7906438089406171182,
+                        459837266949299968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11435093063351815916,
+                        15823783251855010172,
+                        3261562746952998435,
+                        885189907287175138,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8284582787461719603,
+                        2871508432809225382,
+                        6968641841344157603,
+                        42260059723003451,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7145313524475269029,
+                        12323513210576440336,
+                        1161588594727997966,
+                        697178389144105239,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17576366265966766610,
+                        8187845090636731752,
+                        15324069527804241404,
+                        570755022663100058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        533861260937075701,
+                        4412212122189789959,
+                        4970550150240530563,
+                        747931712521279225,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6536537833475221654,
+                        7759892874746056927,
+                        17371196372756842030,
+                        284439089643138332,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12462324854103318662,
+                        620077874913527716,
+                        13773738916951105222,
+                        557687552520558183,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3371139438798886239,
+                        16078424167555497656,
+                        5484439655203151935,
+                        116235900964157425,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13171620982337298741,
+                        8731138963664486021,
+                        15070673135662651131,
+                        1033854306557403241,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3785724536792963733,
+                        15220431304383216592,
+                        1038726737237412008,
+                        1207100170115791009,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1665040252616675210,
+                        14417477558721975152,
+                        4019930021189891911,
+                        250106283152873200,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12582323948468627111,
+                        4524681759884791600,
+                        12187979570761679775,
+                        1296828323775627181,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12773768944009814322,
+                        6824367225984417547,
+                        1566831658141192803,
+                        1263824086590607057,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17726767392884685291,
+                        2772564268316763993,
+                        9901866225800401950,
+                        113974698942813711,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7076694419656786976,
+                        9483706722371895851,
+                        2306788005315319295,
+                        715483687463622436,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12295102318670985604,
+                        12304719669840275865,
+                        916925861853911091,
+                        849482803622387428,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6617540887162835190,
+                        13757313715693428468,
+                        12869482025163107483,
+                        1264907615411046815,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13043840961531666849,
+                        11130666741282750015,
+                        15410471508007951322,
+                        577584017266120137,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5566590419561929812,
+                        12548455844093832278,
+                        11510296752356846207,
+                        600803771257545806,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5262910419318805938,
+                        6804412316548814054,
+                        8237743935702715507,
+                        120197212352968588,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4009983848264242441,
+                        619104490142114660,
+                        10080693486703361585,
+                        1208421934451319154,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15430370326694509925,
+                        8221052191629729087,
+                        1767800777287659231,
+                        1119305451097065046,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9012313310875912034,
+                        17947933036888265212,
+                        14692486434622991514,
+                        206627316242047267,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        31543674037217278,
+                        6988401572317044141,
+                        2809311506126233326,
+                        1074456646147137317,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9717949192390999594,
+                        12770321825481512240,
+                        6765710318220136561,
+                        445693477849787834,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3217399951355837231,
+                        11117307406986479165,
+                        9668661564769784436,
+                        681221949272849299,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16166137999629244757,
+                        12716003669902937334,
+                        16784112233515808843,
+                        416962657198805894,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6195487106918401343,
+                        2650906688478009313,
+                        523216100681232595,
+                        361850394299257668,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18408038349106188237,
+                        17801971167450873062,
+                        9895328014143680644,
+                        883092988624821309,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16059724270743746926,
+                        11102670074665392438,
+                        8280364131894384839,
+                        209778698362249261,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15957396754665939988,
+                        6250635845263465835,
+                        5566979556390565790,
+                        388918857851290330,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16394060604614925113,
+                        3917657787363691522,
+                        17997820892574914850,
+                        691697696103562650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        495678841855484587,
+                        6803601146803273374,
+                        3903812772465215665,
+                        543944521078211319,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6979619360189046888,
+                        4543736008907771371,
+                        4604431644139677845,
+                        1146604956231062784,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10319740315204439767,
+                        15295381550399155342,
+                        1376161719829121080,
+                        816705571996838742,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7599005848076085933,
+                        10361839976287664296,
+                        10874600309755582107,
+                        1018579079398428697,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2067484289091343281,
+                        11894543310106328791,
+                        11773412302477144143,
+                        581230970459635384,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2135887726805941724,
+                        6308453950867933131,
+                        10228038668633995962,
+                        231234339862294368,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4341539204129625897,
+                        12958981108398762696,
+                        14238077698096142767,
+                        312631730874833687,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18277331388336558108,
+                        2560662188073267820,
+                        16389674166168893617,
+                        1122500437247112523,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13518087406075743634,
+                        14709571747754925956,
+                        3934933090798229920,
+                        1224795860300420233,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13190694990427136266,
+                        389844610193546476,
+                        13662855545839716571,
+                        287966811609377374,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9968125939979872951,
+                        2880448759287813526,
+                        17358259014369151576,
+                        817418886195930240,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11325434200932273064,
+                        5584050032542676416,
+                        11132966615086459401,
+                        1271934314150614000,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15185368278915895356,
+                        10155945181134947351,
+                        523946014968716262,
+                        800428256105694523,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9045413565094436971,
+                        7163815493646823232,
+                        2822374545694121009,
+                        472684254877718273,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7987379339165772775,
+                        5392955210706093953,
+                        16550827938580815241,
+                        1087345814437778962,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3872620313644963303,
+                        5882596296111006034,
+                        7983367431872322813,
+                        1032711058989989023,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12454322172375886329,
+                        13320709636911833497,
+                        8462999378633088877,
+                        854017526417957614,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12140040698668842658,
+                        2947631777547192765,
+                        9516631010107279039,
+                        174115525388318189,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9163808867197300952,
+                        7466889577783832106,
+                        18385361826484440735,
+                        533661037184635966,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2669740316252852687,
+                        634116824626075659,
+                        1432843632106176005,
+                        804604883376670407,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        782265271758080398,
+                        8828380285367812909,
+                        7045569900781660562,
+                        456329835668661596,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4293501783236310283,
+                        16420252918696494821,
+                        2860421076901336635,
+                        779670650615564664,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8544107299073715049,
+                        15742394961416968107,
+                        3371958432404112215,
+                        1137936853295896626,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15360334697160107849,
+                        843838480770954011,
+                        8501292773562760379,
+                        481457760969822649,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10445140842003951876,
+                        8220093822166336172,
+                        12836192361814240234,
+                        1301074604393757392,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9818193014731746998,
+                        10964912630066721157,
+                        15914183709586978352,
+                        59076633430664426,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9172842185194499434,
+                        9848327405861442558,
+                        4198302128872323934,
+                        983708258340015876,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9368126822991728610,
+                        8886514473593640359,
+                        646662264111619443,
+                        614039225765785027,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9697777875294295215,
+                        4634006914258790041,
+                        659963215272494208,
+                        683348721067627052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11320421737603200774,
+                        18017611628909108682,
+                        2787199606131697894,
+                        1330568462742075284,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3805666193385138374,
+                        15049170101719457051,
+                        13672228753613605087,
+                        1140825117237854542,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11075466715667092542,
+                        3617433509264918641,
+                        4317794085024956136,
+                        731398934956222830,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2350210141613502730,
+                        7663415791723502354,
+                        9287729338813856217,
+                        858833556156478736,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11356626318978003607,
+                        17742677195599314910,
+                        13435804513596492774,
+                        894765546836766675,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16501553066566462489,
+                        12163963612284280146,
+                        14607528691230309442,
+                        944561460528661628,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14848610031087979233,
+                        5287944150014836339,
+                        11448960605552626584,
+                        430568487910496878,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9339535505032121184,
+                        169058506300729207,
+                        7449927167248378995,
+                        515898052489176495,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17023058423367360362,
+                        8260942580841598999,
+                        10650367719295872116,
+                        1108780089000442896,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5015209324968953549,
+                        8865908756579679705,
+                        629422904120961707,
+                        290698271490713685,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7818754009688104822,
+                        3323962727793610656,
+                        15764888247072257681,
+                        461412964056661613,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3493145685142326210,
+                        1922193987892501258,
+                        8883052704502147604,
+                        989330756240349048,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6567214830550817316,
+                        17266227465960099704,
+                        17508474593681642760,
+                        1205900599768880520,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15696624957631962941,
+                        4030921656141846703,
+                        1629887537275776150,
+                        1249863897377796005,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17333834555491129808,
+                        1421287620511245746,
+                        11263133013204069015,
+                        878266587172245851,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3406351895419872241,
+                        9787076586501970616,
+                        13692140294228273506,
+                        547029290486947387,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13378507248228383778,
+                        5888303177537739929,
+                        12115739834193201402,
+                        1262078902974144392,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13195987482492105049,
+                        15072750403635360187,
+                        5729649655852302592,
+                        148388114751548122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6488003234722253595,
+                        14274309590195462935,
+                        3908682742137315333,
+                        1078768349672739316,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8371188918264167153,
+                        6799700688616033418,
+                        11735967484142656174,
+                        712400086155026526,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6550052171201138168,
+                        11046658226330246108,
+                        9756159230671084104,
+                        273728552143474297,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16185328444168161501,
+                        16401173845558481555,
+                        4863828278368129811,
+                        391079382915091991,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2059230574482849776,
+                        6011027180781918737,
+                        3701592247983065526,
+                        145204436760729095,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11207144832299570363,
+                        13335313246753386486,
+                        2380060756486233476,
+                        494061767933407958,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16606034439881852052,
+                        3645828583932618658,
+                        4425748313258993284,
+                        86481451493079418,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8632185294183662020,
+                        2814466489336474298,
+                        8720766369967575333,
+                        838776942755755285,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12625044708837964876,
+                        3721932267236259992,
+                        6562017725188994315,
+                        699649284993541870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17409515810137641742,
+                        6983118860168338392,
+                        7891796534521193142,
+                        250294725885297181,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11960192550348046507,
+                        1270990770158990454,
+                        15820016751501715588,
+                        654951101125723071,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        718195852084171976,
+                        5717854654132937671,
+                        16256422945620796464,
+                        609260793863228955,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10292442447906293907,
+                        9683518586355756941,
+                        3688399482617552705,
+                        117632377169201426,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16509285710949503207,
+                        17646010904424339708,
+                        18094802609779736160,
+                        769920100992161287,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18088388113172082040,
+                        4585335344622401655,
+                        3063787793441035252,
+                        112189374929201597,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14041116989049566819,
+                        1546585555582636644,
+                        2562953561369698746,
+                        157135341682466777,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15463124674172481566,
+                        11196896782808492512,
+                        3520443235700836731,
+                        345956124588717392,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8585761700677431502,
+                        16332919857715487807,
+                        2851226399848783780,
+                        742137870660495725,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13764210227311501573,
+                        11755703151276826468,
+                        944569424803740740,
+                        328704967468250983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6222005428075958557,
+                        2863459479933422254,
+                        5265480358640529079,
+                        493525213397689237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6869302409057225191,
+                        5898232864690843132,
+                        8472322292364725786,
+                        920093753672991669,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14674169263264109804,
+                        3885554254722417019,
+                        1342721164080102362,
+                        88288673297884077,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16653966726285770259,
+                        2493671959289797775,
+                        13304009615851364489,
+                        596577717555546083,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7089585718910073030,
+                        14797851079892771085,
+                        13174073978180970514,
+                        1091564405450560921,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17202302006773313003,
+                        5160981438842236162,
+                        8585128699035597368,
+                        824828955532044530,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8981899221985235353,
+                        7723183037199108038,
+                        10033314346822176318,
+                        1221594949354695037,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16889378904185713985,
+                        289294253195199930,
+                        14648471868085938621,
+                        1343095408353525645,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14213278084657839492,
+                        17354245609805304837,
+                        9633400299853230370,
+                        1017853097344748161,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3007833792898076197,
+                        7189213166639843242,
+                        9178779081148571259,
+                        21827055719383791,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6909002971280897813,
+                        2541580665989983090,
+                        2489749072235701755,
+                        1056891937403385258,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13670372127604133918,
+                        2421672147660655005,
+                        4841614927031915975,
+                        138842639503281350,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3435702568923361904,
+                        1104805672087835580,
+                        10052647937445791796,
+                        452977381837154221,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1836379512050666411,
+                        1873870755740669166,
+                        3175878998117324313,
+                        186747648193884313,
+                    ]),
+                ]),
+                Matrix::<15, 1, 15>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16708711522734617100,
+                        16553216476851290730,
+                        7114709306424550958,
+                        1002022263727201335,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1498360185380762343,
+                        11463122533334414625,
+                        10693395789529352899,
+                        749377475538226624,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16500581388612149973,
+                        11990445115328210775,
+                        4741525561774398273,
+                        657497113302860954,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16365820195514419554,
+                        6979824806977997551,
+                        12078966952451996035,
+                        383920325686927182,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17089852641423503916,
+                        17041118628873771552,
+                        5694882860137450301,
+                        1231442542920695747,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16248630865504155003,
+                        10739276347069021595,
+                        1250699611444293227,
+                        862055269688341879,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7393209420826452374,
+                        7228130273662448056,
+                        11147174441096984618,
+                        1073175332919195340,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12257332929182205154,
+                        13600593063103866481,
+                        11705682149470667578,
+                        607554348620717580,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5922783470233470888,
+                        6764882337670616877,
+                        18340782117330066831,
+                        1148220177404816196,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4715551850121151366,
+                        3140663049095935640,
+                        18178433802006265689,
+                        1058725259053763647,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7940093899997584457,
+                        8419176325051188314,
+                        17680792771522555703,
+                        386026768983995352,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16213721110686637259,
+                        16661786069882075570,
+                        6474959527066185596,
+                        554958916662549698,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15215447736150498919,
+                        14626226055920647958,
+                        12792790036779190284,
+                        408411722279638674,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11926882362092123756,
+                        15739330879898614265,
+                        1473606952869743828,
+                        607785057639039302,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3914044629385404868,
+                        1808717813633220686,
+                        5104542726254484789,
+                        1062949016859862546,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<39, 16, 624>::new_from_known([
+            Fq::from_montgomery_limbs([
+                7411270673441529716,
+                6308985163655666832,
+                9017065419253201486,
+                1273036220096323704,
+            ]),
+            Fq::from_montgomery_limbs([
+                2754481538479821082,
+                13142387114410948291,
+                15614410621228036303,
+                60799155456132103,
+            ]),
+            Fq::from_montgomery_limbs([
+                17681218254003890668,
+                391061690871725535,
+                3295905609211558288,
+                967878701866012597,
+            ]),
+            Fq::from_montgomery_limbs([
+                9382385917264579619,
+                1042981499962302920,
+                139661287799805261,
+                275002209344561392,
+            ]),
+            Fq::from_montgomery_limbs([
+                3356127090706323211,
+                17457882483960025298,
+                16074483218178366824,
+                628970122872722509,
+            ]),
+            Fq::from_montgomery_limbs([
+                13462764728625831335,
+                10644438855851007328,
+                15113772679872861730,
+                551589203084750099,
+            ]),
+            Fq::from_montgomery_limbs([
+                4490885036584545826,
+                294744012913749609,
+                14929471119568885487,
+                199825482639017355,
+            ]),
+            Fq::from_montgomery_limbs([
+                11139153319706881816,
+                4245900581361682809,
+                17354086123417629052,
+                50445950495378456,
+            ]),
+            Fq::from_montgomery_limbs([
+                17726412809381474395,
+                1185542180996861490,
+                16776597330553208130,
+                93580226522239066,
+            ]),
+            Fq::from_montgomery_limbs([
+                6489837635897767275,
+                9517955511991088438,
+                3365970773577828706,
+                65131757989219483,
+            ]),
+            Fq::from_montgomery_limbs([
+                13195680198221082238,
+                2383910281870666191,
+                1751784164612983911,
+                1295852563093527892,
+            ]),
+            Fq::from_montgomery_limbs([
+                7599836752410862561,
+                13075540022144339987,
+                6056774453545501119,
+                83814826306509281,
+            ]),
+            Fq::from_montgomery_limbs([
+                14644057716587539970,
+                5560237006344492025,
+                7027360837916113013,
+                1280399964310289185,
+            ]),
+            Fq::from_montgomery_limbs([
+                12897533187573387415,
+                9510037741928507348,
+                11888709895318763661,
+                429046206843651946,
+            ]),
+            Fq::from_montgomery_limbs([
+                1191312506224952220,
+                17465108299008007220,
+                4861273103718473552,
+                977018214961082243,
+            ]),
+            Fq::from_montgomery_limbs([
+                4433928987232758141,
+                10070898845275748321,
+                10240923463622893096,
+                63644698799876675,
+            ]),
+            Fq::from_montgomery_limbs([
+                3404030751357400165,
+                9022606047806285066,
+                1229479431923646198,
+                956191681276825462,
+            ]),
+            Fq::from_montgomery_limbs([
+                16642323736040229713,
+                16365680258596711750,
+                222643453659539029,
+                746711359925830444,
+            ]),
+            Fq::from_montgomery_limbs([
+                14838753900531502837,
+                14549797137591825435,
+                17779732971370123843,
+                296327785807264915,
+            ]),
+            Fq::from_montgomery_limbs([
+                1692880410177102943,
+                2201718689788150186,
+                11801128233516208895,
+                644088368976324965,
+            ]),
+            Fq::from_montgomery_limbs([
+                14600655902172280777,
+                2133007916749663594,
+                2336120572595589606,
+                1156126723298506111,
+            ]),
+            Fq::from_montgomery_limbs([
+                3389840373816541874,
+                13117728105779072629,
+                15113852838538008817,
+                1202564071852770459,
+            ]),
+            Fq::from_montgomery_limbs([
+                15858855412337336447,
+                6320335126041882256,
+                17894518801214338994,
+                675022417689048120,
+            ]),
+            Fq::from_montgomery_limbs([
+                17289224702611261,
+                2084133037868211175,
+                8498115080144768495,
+                1225244605346733206,
+            ]),
+            Fq::from_montgomery_limbs([
+                6550601404889645163,
+                15245941719049518580,
+                2657253156478402355,
+                1248919711798218453,
+            ]),
+            Fq::from_montgomery_limbs([
+                14329251677778643340,
+                15851848482071801359,
+                15068422321109667761,
+                338444175817276314,
+            ]),
+            Fq::from_montgomery_limbs([
+                9001754648848512408,
+                18126147854230739651,
+                2546530148984129641,
+                1069863388194689308,
+            ]),
+            Fq::from_montgomery_limbs([
+                13398985390342509119,
+                8320344976078102731,
+                4593591666116341430,
+                1317901298790438930,
+            ]),
+            Fq::from_montgomery_limbs([
+                16339853447097255167,
+                15067313439405269005,
+                8328698015177502820,
+                1306915863904246027,
+            ]),
+            Fq::from_montgomery_limbs([
+                18055607770420239695,
+                8510070023587245419,
+                13979835543472845557,
+                1135556679081857825,
+            ]),
+            Fq::from_montgomery_limbs([
+                10391556789540864033,
+                12509559194552040872,
+                2195456274702733728,
+                1054007862678499811,
+            ]),
+            Fq::from_montgomery_limbs([
+                16005075313160758392,
+                17190633201933889209,
+                12961286432151203310,
+                908555160493793095,
+            ]),
+            Fq::from_montgomery_limbs([
+                5941286409643059367,
+                4216455443648782001,
+                14922174002802797696,
+                1145688508124505912,
+            ]),
+            Fq::from_montgomery_limbs([
+                4081626116450082823,
+                986857377554817864,
+                13320078669435567902,
+                121130509074165585,
+            ]),
+            Fq::from_montgomery_limbs([
+                13965697914427859302,
+                6730055884632064028,
+                4009962198248029138,
+                1258196817268490501,
+            ]),
+            Fq::from_montgomery_limbs([
+                4181018001998533725,
+                1169375653294767443,
+                2317340319855180390,
+                610271809394963211,
+            ]),
+            Fq::from_montgomery_limbs([
+                15345592974214864765,
+                11756985772280829740,
+                3249691021255748636,
+                928650240255052872,
+            ]),
+            Fq::from_montgomery_limbs([
+                14721789589393511871,
+                3801559902612939274,
+                1269843483399815700,
+                729474487024310697,
+            ]),
+            Fq::from_montgomery_limbs([
+                10812041293865829605,
+                13641653068199214502,
+                16511168247030098959,
+                581163797429420120,
+            ]),
+            Fq::from_montgomery_limbs([
+                4278670999095461702,
+                16017393301504358304,
+                5661940865333181991,
+                430324343886282152,
+            ]),
+            Fq::from_montgomery_limbs([
+                9883986771031314364,
+                4344563170235282606,
+                117511927586584387,
+                656539544478436494,
+            ]),
+            Fq::from_montgomery_limbs([
+                3893450878444113547,
+                1340615706018353837,
+                3111845862707977871,
+                794599599646950517,
+            ]),
+            Fq::from_montgomery_limbs([
+                14307547483085359895,
+                15935438713017829972,
+                7185097188745225557,
+                1288831561442847496,
+            ]),
+            Fq::from_montgomery_limbs([
+                1708738519292765530,
+                7600010890490490885,
+                3599496688147059489,
+                185682915389923716,
+            ]),
+            Fq::from_montgomery_limbs([
+                2375250159949755826,
+                12586228962985077151,
+                2504069817274929624,
+                1019357997219268757,
+            ]),
+            Fq::from_montgomery_limbs([
+                5047553599136320138,
+                12944649827273566831,
+                14094014207017714172,
+                1268728803688924111,
+            ]),
+            Fq::from_montgomery_limbs([
+                9522887523345397497,
+                14369640279108840219,
+                2674259964551331209,
+                1152106214351373351,
+            ]),
+            Fq::from_montgomery_limbs([
+                14308534478599417955,
+                1635134900133544950,
+                15580239714380892153,
+                987297846788680911,
+            ]),
+            Fq::from_montgomery_limbs([
+                11888780574212852122,
+                9843552218247047745,
+                2103343019576331380,
+                269648191012281603,
+            ]),
+            Fq::from_montgomery_limbs([
+                8681871298857029777,
+                2693459785378542920,
+                18405343396128185155,
+                1170547261402466001,
+            ]),
+            Fq::from_montgomery_limbs([
+                10510347393101314767,
+                17156935645029422426,
+                10131767878161748647,
+                1075787453378598328,
+            ]),
+            Fq::from_montgomery_limbs([
+                12946748829372988719,
+                8789845603108855356,
+                5358042317598432313,
+                1176660036200666057,
+            ]),
+            Fq::from_montgomery_limbs([
+                2199557982095912605,
+                4654560912825113894,
+                7467648603159747528,
+                13260115585984035,
+            ]),
+            Fq::from_montgomery_limbs([
+                7638115408211449559,
+                2663121918148992786,
+                4643421311575631205,
+                327260884114408498,
+            ]),
+            Fq::from_montgomery_limbs([
+                13801202696324311895,
+                6305258592999489804,
+                10064082026568031373,
+                792217452205937761,
+            ]),
+            Fq::from_montgomery_limbs([
+                12003293573085016508,
+                3737195379828864730,
+                7326750963840042234,
+                389860117833651710,
+            ]),
+            Fq::from_montgomery_limbs([
+                3254076379832815052,
+                6236871367175874311,
+                6352888970302594044,
+                1150530127698376577,
+            ]),
+            Fq::from_montgomery_limbs([
+                12729046537229850973,
+                8282839418501142558,
+                7575004300165644929,
+                750409314119831997,
+            ]),
+            Fq::from_montgomery_limbs([
+                17194721353212298574,
+                2256515527322867839,
+                11911341969523066809,
+                1164972466728630927,
+            ]),
+            Fq::from_montgomery_limbs([
+                3797154598862283085,
+                2725094488220713450,
+                1718896754257539896,
+                42164594973570673,
+            ]),
+            Fq::from_montgomery_limbs([
+                18032951845433229954,
+                5498143562700363701,
+                3814505891790267676,
+                1321840624545695718,
+            ]),
+            Fq::from_montgomery_limbs([
+                12363046526883363527,
+                15507207959004475087,
+                12250841796820316586,
+                486252329752231860,
+            ]),
+            Fq::from_montgomery_limbs([
+                15877878112580301418,
+                4109651771473457722,
+                3303492269317715575,
+                293706834652252476,
+            ]),
+            Fq::from_montgomery_limbs([
+                16830677894951778370,
+                1230222398571433180,
+                15851252194851748756,
+                987818256421020848,
+            ]),
+            Fq::from_montgomery_limbs([
+                6741692981707131145,
+                14112698735249445588,
+                2551588788534321380,
+                394267348532361382,
+            ]),
+            Fq::from_montgomery_limbs([
+                10169295149296033596,
+                16175106632601096959,
+                17022442806561895468,
+                538649062862145701,
+            ]),
+            Fq::from_montgomery_limbs([
+                15119127471985775176,
+                15370508837039422876,
+                10911879907705403127,
+                1339146012319949611,
+            ]),
+            Fq::from_montgomery_limbs([
+                11391967386740570864,
+                9999169111770955995,
+                3666852998806082443,
+                943413958261265320,
+            ]),
+            Fq::from_montgomery_limbs([
+                3983125880290462847,
+                3973225684610892711,
+                883987247136784383,
+                1024566413779119617,
+            ]),
+            Fq::from_montgomery_limbs([
+                12893906978548600159,
+                7660025040966648196,
+                8528530288383539226,
+                1096003441123903391,
+            ]),
+            Fq::from_montgomery_limbs([
+                1350092101861913463,
+                11109318571973194812,
+                15816225999186785110,
+                906521204711158133,
+            ]),
+            Fq::from_montgomery_limbs([
+                832512130987438828,
+                15863526051164496121,
+                3969621638669257136,
+                1247004569438290704,
+            ]),
+            Fq::from_montgomery_limbs([
+                8514344534555787154,
+                6313504214597363759,
+                17474321080312173101,
+                470571124603245515,
+            ]),
+            Fq::from_montgomery_limbs([
+                17619676192235251414,
+                17950729325535177932,
+                2925451308128674987,
+                1137497649381917883,
+            ]),
+            Fq::from_montgomery_limbs([
+                14314687430940801067,
+                4926723357325686664,
+                8528310997801595506,
+                1019391846264915828,
+            ]),
+            Fq::from_montgomery_limbs([
+                17768131318719267919,
+                6665260543876709203,
+                14843941321293652013,
+                476561171431771391,
+            ]),
+            Fq::from_montgomery_limbs([
+                9127311361061583002,
+                13390154307072713013,
+                7368426493621778462,
+                510530365864966201,
+            ]),
+            Fq::from_montgomery_limbs([
+                454112050247927607,
+                11722941586330855583,
+                742186254703987459,
+                834586457001054537,
+            ]),
+            Fq::from_montgomery_limbs([
+                2981505209803579980,
+                7955563509795789577,
+                6759052440331482921,
+                48907467813199799,
+            ]),
+            Fq::from_montgomery_limbs([
+                2522016954100819466,
+                14263961442616965577,
+                11613433130541471136,
+                819075256406555704,
+            ]),
+            Fq::from_montgomery_limbs([
+                18148705605238363648,
+                1841778258019501571,
+                10652899187772538513,
+                425395077183612642,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15880660039582854528,
+                14922463699042233277,
+                18399310752844001847,
+                246810748531976477,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5506023737845701019,
+                2751112801266769471,
+                16511787375581801711,
+                186488129872170045,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16310545226734590634,
+                14948585720750603221,
+                11397563805999397691,
+                232005863950290504,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15945782755646478824,
+                12929049331172459175,
+                12617284272393328245,
+                718438656023155857,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4219442662376549022,
+                11596712254481029533,
+                1113659657919378168,
+                362061911995323830,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2790142628724285337,
+                569387873259930500,
+                8860387149556300402,
+                601347384539340292,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2427518818225902507,
+                9361065089547471722,
+                18336335873973672422,
+                121724162373679824,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5518306005316105582,
+                4993596090488356954,
+                4921788976682540538,
+                39923851689436128,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7435539529792660199,
+                7878004985761165741,
+                3701062396467398083,
+                307975265781256526,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2940996657902898175,
+                9340146141545050536,
+                7924895160350305144,
+                80303881408687158,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12751703664624808996,
+                5089264631607726261,
+                2927178368857549218,
+                659365786017783715,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12458179563992514935,
+                5431544857521222765,
+                15993486321354317021,
+                224989824926388268,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2972915724047881612,
+                10164738025690567262,
+                18302023864981711484,
+                432676287206162107,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                817647735712776586,
+                17523984631973956585,
+                5765579874582028172,
+                520418575775243026,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                18145030905570768533,
+                949513846202192642,
+                9111650909567535977,
+                1261240182729647610,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                192855586496873290,
+                7066788146465887669,
+                8209682552062645756,
+                590984012839784860,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1918445231046611211,
+                13565227937369196247,
+                3654765529958918803,
+                36326236260158289,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7165083421278785602,
+                18228835942780545420,
+                4406297121261866034,
+                286388834844442786,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10389675179261605839,
+                4141177202003591343,
+                5149850873125938346,
+                222193896722921316,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11664199406477993165,
+                13769958673876187783,
+                9223236416161922323,
+                1088069485057362535,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11520007282170256649,
+                563128080047762555,
+                11527169685884028013,
+                208360113342026344,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                927719647895214369,
+                1342721105575688916,
+                16438161999053283102,
+                347797620434732981,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13185194984857719191,
+                5947402200694919772,
+                6643761142576663920,
+                849824244979316292,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3310115173673826687,
+                709185736936306740,
+                1710802595868105881,
+                716138726107196904,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                18341646035812546036,
+                8386705560292581284,
+                5443295508237531637,
+                524336386636176337,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10004495458843235354,
+                4348946315431760553,
+                8032711056147807730,
+                951742694778394194,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15471757555195097715,
+                5247524429953340464,
+                617488928367741642,
+                393746496858854336,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8896346834360276758,
+                14558497533807795552,
+                12087536308821088955,
+                686833842726690003,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12904237074486822199,
+                6077520738475102722,
+                4785000749268608486,
+                1133246337873608960,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6623055772613293187,
+                12169766406406281508,
+                5066790847053373139,
+                1155100823547494114,
+            ]),
+            Fq::from_montgomery_limbs([
+                3224714940122703820,
+                11937529108703499721,
+                13495549386102264708,
+                279840756382716256,
+            ]),
+            Fq::from_montgomery_limbs([
+                8297950903421725610,
+                9237877810187199361,
+                6933991137793095447,
+                621433441848910570,
+            ]),
+            Fq::from_montgomery_limbs([
+                4408018004402850710,
+                1729451794286861304,
+                2029036517604644737,
+                930716098793479919,
+            ]),
+            Fq::from_montgomery_limbs([
+                9672285143427939778,
+                4110197552018060383,
+                11066818807929616924,
+                374389600548710970,
+            ]),
+            Fq::from_montgomery_limbs([
+                267596768193449185,
+                17151256690563673075,
+                12211846505623967771,
+                349612535949313248,
+            ]),
+            Fq::from_montgomery_limbs([
+                6895124383637710840,
+                15117601962991039071,
+                4341253277711178102,
+                1060132276190232545,
+            ]),
+            Fq::from_montgomery_limbs([
+                8413114547849239899,
+                15522690778416690257,
+                1872890863676226589,
+                785437673137306494,
+            ]),
+            Fq::from_montgomery_limbs([
+                11345173030547206818,
+                2282527624657950946,
+                10309789137278276311,
+                328901595327018193,
+            ]),
+            Fq::from_montgomery_limbs([
+                13278573517684035446,
+                5054342173734938206,
+                18322773600398349114,
+                463311543957198895,
+            ]),
+            Fq::from_montgomery_limbs([
+                354330049278188407,
+                4251371920724647485,
+                4678813440988787274,
+                496217503583902828,
+            ]),
+            Fq::from_montgomery_limbs([
+                1800510706723023583,
+                12694189400530705118,
+                724673862534797106,
+                241931646530408994,
+            ]),
+            Fq::from_montgomery_limbs([
+                2165358813762768002,
+                17697706443096745364,
+                9472668865658084421,
+                504989661114226284,
+            ]),
+            Fq::from_montgomery_limbs([
+                16207250066481473155,
+                10507660960975797223,
+                7596622973305063505,
+                789861306499825514,
+            ]),
+            Fq::from_montgomery_limbs([
+                14238397072095193019,
+                15052223533815193666,
+                2530672952001606494,
+                358655041843282184,
+            ]),
+            Fq::from_montgomery_limbs([
+                14071966617609789019,
+                9761559114362063348,
+                11603824424000788433,
+                10184984680437988,
+            ]),
+            Fq::from_montgomery_limbs([
+                6894125618950071224,
+                14129489406901423023,
+                6246682231757428888,
+                256761955107262127,
+            ]),
+            Fq::from_montgomery_limbs([
+                1296430030668017526,
+                13981578573589903311,
+                15140605384024214226,
+                1182690950719122135,
+            ]),
+            Fq::from_montgomery_limbs([
+                7348249968636425036,
+                8197013944318465517,
+                9531739565016493909,
+                690832546251557941,
+            ]),
+            Fq::from_montgomery_limbs([
+                6993955759896022545,
+                5881175635906640603,
+                6634481163981783926,
+                753247948710660536,
+            ]),
+            Fq::from_montgomery_limbs([
+                17943897564033183216,
+                7450506874514999728,
+                2328157965327995786,
+                5085405156243327,
+            ]),
+            Fq::from_montgomery_limbs([
+                4304603681842051587,
+                11727108614995019317,
+                1617335938022560992,
+                1077342978184573275,
+            ]),
+            Fq::from_montgomery_limbs([
+                13241854272168467284,
+                2530452164349335794,
+                3816833967701320423,
+                1075458162216611363,
+            ]),
+            Fq::from_montgomery_limbs([
+                17656209238846495965,
+                12868723306121528088,
+                4397652482036272240,
+                970898270509613605,
+            ]),
+            Fq::from_montgomery_limbs([
+                11073355600568037077,
+                14687896260565562336,
+                10362697314439178046,
+                1324138624495315131,
+            ]),
+            Fq::from_montgomery_limbs([
+                8247752878089668724,
+                8059889514901226923,
+                9591508751497366767,
+                77314929801850134,
+            ]),
+            Fq::from_montgomery_limbs([
+                7195024031219045751,
+                15419510607481405591,
+                10696235157017855871,
+                1151047399343150109,
+            ]),
+            Fq::from_montgomery_limbs([
+                6176529981211954442,
+                8715972605655838438,
+                9497263294056128889,
+                65057435363252518,
+            ]),
+            Fq::from_montgomery_limbs([
+                18084999812505683849,
+                18084128509508539477,
+                3475390743918366006,
+                632958315986852299,
+            ]),
+            Fq::from_montgomery_limbs([
+                11235734765161961813,
+                9475903576248976692,
+                3735281593469965492,
+                1187846371522997596,
+            ]),
+            Fq::from_montgomery_limbs([
+                5588923984769690640,
+                1182686389773350616,
+                12444169492162272677,
+                675383676578328828,
+            ]),
+            Fq::from_montgomery_limbs([
+                3862846314110079304,
+                5449988219734836373,
+                11887340827696404415,
+                632833109782285531,
+            ]),
+            Fq::from_montgomery_limbs([
+                14130029094516312948,
+                8757410617222136184,
+                16565879725838489576,
+                350151191881702068,
+            ]),
+            Fq::from_montgomery_limbs([
+                1696497043471473506,
+                1403151274073014399,
+                3958576593022642419,
+                846716926560126699,
+            ]),
+            Fq::from_montgomery_limbs([
+                1280520137161382964,
+                17912956996451491678,
+                17678395748826089007,
+                172121818559888678,
+            ]),
+            Fq::from_montgomery_limbs([
+                16794878928362718453,
+                11102063523418823284,
+                4405038262735076139,
+                919466341364487515,
+            ]),
+            Fq::from_montgomery_limbs([
+                17025244069123800067,
+                479066302927988527,
+                14493471034877721698,
+                143786056938894955,
+            ]),
+            Fq::from_montgomery_limbs([
+                13855233434307963034,
+                17992425559965634860,
+                17378002511450525517,
+                999600472516878878,
+            ]),
+            Fq::from_montgomery_limbs([
+                5236000797211094812,
+                14327582194697403093,
+                13923635977272707171,
+                20022767824901714,
+            ]),
+            Fq::from_montgomery_limbs([
+                11567949642906537088,
+                7291883314851967437,
+                10070846047976833705,
+                520612204167410096,
+            ]),
+            Fq::from_montgomery_limbs([
+                8978529284744938422,
+                4422846689456026818,
+                2469619988745704152,
+                858869895238457060,
+            ]),
+            Fq::from_montgomery_limbs([
+                7200446426941280546,
+                4071738437446250002,
+                9456445025028840517,
+                645670081000948380,
+            ]),
+            Fq::from_montgomery_limbs([
+                8714274678698873080,
+                6967511994807152045,
+                10225692452229209175,
+                131093184692577958,
+            ]),
+            Fq::from_montgomery_limbs([
+                9471558927000203341,
+                15062007731548262138,
+                10880899469780313713,
+                738274374266202545,
+            ]),
+            Fq::from_montgomery_limbs([
+                17038865956596055626,
+                5381869766017962025,
+                17104451991251399266,
+                128409957786576664,
+            ]),
+            Fq::from_montgomery_limbs([
+                3280566453648437273,
+                16122478470419493823,
+                18379936691882702088,
+                184643472243081349,
+            ]),
+            Fq::from_montgomery_limbs([
+                12219038624989320048,
+                6709295381965113060,
+                16190499721159181722,
+                648851354433475170,
+            ]),
+            Fq::from_montgomery_limbs([
+                4713209860148553393,
+                10094225008088771564,
+                7656782719918128387,
+                722614960439044721,
+            ]),
+            Fq::from_montgomery_limbs([
+                14226513627384429888,
+                17025278165745378395,
+                1124714023424255708,
+                1038219570818514262,
+            ]),
+            Fq::from_montgomery_limbs([
+                11868849666495177599,
+                11752224350421394105,
+                16900486582478540416,
+                459441508310376314,
+            ]),
+            Fq::from_montgomery_limbs([
+                1516675636089102042,
+                7287251477307333450,
+                3652087741432609583,
+                579427703657106898,
+            ]),
+            Fq::from_montgomery_limbs([
+                10019231733723430550,
+                5756727287014100730,
+                1505077744556985774,
+                996083397868164205,
+            ]),
+            Fq::from_montgomery_limbs([
+                17391493609949271420,
+                3378863206631289223,
+                5238217800763009591,
+                1082845684384675883,
+            ]),
+            Fq::from_montgomery_limbs([
+                9003980406932379920,
+                16710255509982168390,
+                11397642691382097295,
+                43623450215898907,
+            ]),
+            Fq::from_montgomery_limbs([
+                10115225201889256831,
+                8592404005424373843,
+                14569637172146345785,
+                771986975920184616,
+            ]),
+            Fq::from_montgomery_limbs([
+                12438124060591050670,
+                250799793419615578,
+                4363326096334124713,
+                517173240964675597,
+            ]),
+            Fq::from_montgomery_limbs([
+                1231603215378556634,
+                9171647802886045480,
+                4785213623983802763,
+                3605902272625754,
+            ]),
+            Fq::from_montgomery_limbs([
+                5695923275728231640,
+                9892261347593355977,
+                4719495140205587691,
+                1138288268871405005,
+            ]),
+            Fq::from_montgomery_limbs([
+                11325460431201698353,
+                4417860116092697057,
+                517036508815136728,
+                1207469954069004488,
+            ]),
+            Fq::from_montgomery_limbs([
+                3549598542703968697,
+                15613219709730434763,
+                16186566158549578833,
+                1078036039093377182,
+            ]),
+            Fq::from_montgomery_limbs([
+                472462492819687977,
+                1283486765221140717,
+                2041455286581656508,
+                1295769770803010063,
+            ]),
+            Fq::from_montgomery_limbs([
+                15430767864098576481,
+                18045310271318320502,
+                8241253702124509892,
+                751655776772231504,
+            ]),
+            Fq::from_montgomery_limbs([
+                3066176491716874986,
+                5898103142913942319,
+                3645164203815048727,
+                236198016604213384,
+            ]),
+            Fq::from_montgomery_limbs([
+                1132644883169016455,
+                13724430140962047896,
+                3142945395484545793,
+                478585442665119974,
+            ]),
+        ]),
+    }
+}
@@ -0,0 +1,21854 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-16 instance of Poseidon.
+pub const fn rate_16() -> PoseidonParameters<17, 16, 289, 256, 39, 17, 663, 31> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<39, 17, 663>::new_from_known([
+            Fq::from_montgomery_limbs([
+                9581419031222190322,
+                5210476888335524045,
+                16280172889506106702,
+                61916542041203128,
+            ]),
+            Fq::from_montgomery_limbs([
+                1336547432065696007,
+                6686419986182718904,
+                15567329935455861594,
+                1181250163697775871,
+            ]),
+            Fq::from_montgomery_limbs([
+                6653095628345450770,
+                8195302822981904447,
+                2864191407815075501,
+                1319305375098393788,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637262675339575999,
+                3050431777078115230,
+                3807776730267188835,
+                877489925446359522,
+            ]),
+            Fq::from_montgomery_limbs([
+                950766340161820704,
+                3587644507320000022,
+                4973272674142563956,
+                40735447838252930,
+            ]),
+            Fq::from_montgomery_limbs([
+                792225735780456873,
+                9245825414050435618,
+                14081611059060548638,
+                678504056824425115,
+            ]),
+            Fq::from_montgomery_limbs([
+                9108055554479502582,
+                1172549363446145236,
+                1716742965988245241,
+                793192906936849801,
+            ]),
+            Fq::from_montgomery_limbs([
+                5701312954453174644,
+                3292957906697952685,
+                7612377737351738916,
+                715841028860741809,
+            ]),
+            Fq::from_montgomery_limbs([
+                7628514421238759054,
+                16189199190432915955,
+                120306631169826977,
+                872943400717347639,
+            ]),
+            Fq::from_montgomery_limbs([
+                17013845265488232376,
+                5150815978988502610,
+                9489983193259467001,
+                818111430670008147,
+            ]),
+            Fq::from_montgomery_limbs([
+                1183632966436800198,
+                12721209216748448144,
+                11758742264706452860,
+                785130516421029688,
+            ]),
+            Fq::from_montgomery_limbs([
+                9179830599964930791,
+                16970998671994800924,
+                105031891158005894,
+                1154542632865941506,
+            ]),
+            Fq::from_montgomery_limbs([
+                4199810447341097231,
+                16293726787971933227,
+                7163619929537087676,
+                905344460933010058,
+            ]),
+            Fq::from_montgomery_limbs([
+                1987101065343867727,
+                5739737022008282486,
+                9237646317580772330,
+                193666689444071256,
+            ]),
+            Fq::from_montgomery_limbs([
+                10281083237027810615,
+                10990458230938611104,
+                16665044785424592390,
+                240060772288227764,
+            ]),
+            Fq::from_montgomery_limbs([
+                12227417003645510891,
+                15161655888058427285,
+                12532020654772275235,
+                191567267074842521,
+            ]),
+            Fq::from_montgomery_limbs([
+                13588077092373441116,
+                13568445940585925217,
+                10071095771333029207,
+                596333589286539508,
+            ]),
+            Fq::from_montgomery_limbs([
+                11587757304065222898,
+                8662184680536745896,
+                1409377377056033280,
+                1249810382602575922,
+            ]),
+            Fq::from_montgomery_limbs([
+                13203153321668995713,
+                14692820363314613602,
+                6579211989411212911,
+                1245204724772318550,
+            ]),
+            Fq::from_montgomery_limbs([
+                18131934794155659789,
+                4627664332804486360,
+                1057933359741348550,
+                1072517789412842481,
+            ]),
+            Fq::from_montgomery_limbs([
+                14887587828925127721,
+                18273948259069763455,
+                1901386078381693783,
+                964612986311433889,
+            ]),
+            Fq::from_montgomery_limbs([
+                7071237661851184034,
+                13228244633135044975,
+                8642837098973236738,
+                816195637135428457,
+            ]),
+            Fq::from_montgomery_limbs([
+                14001898592082447969,
+                9705026715743397829,
+                10595652403184611397,
+                276668257971270960,
+            ]),
+            Fq::from_montgomery_limbs([
+                3320296070150408618,
+                5744640190227967136,
+                9991024836124273815,
+                33544757176090403,
+            ]),
+            Fq::from_montgomery_limbs([
+                15902327367024084880,
+                1767430481627219685,
+                9255102443366948605,
+                510156262272131556,
+            ]),
+            Fq::from_montgomery_limbs([
+                6293351874832653030,
+                16220797628587901091,
+                17685455868543144438,
+                72784069764148636,
+            ]),
+            Fq::from_montgomery_limbs([
+                13105042454833500083,
+                10963567188663597274,
+                9877943357888857749,
+                1270459916885021506,
+            ]),
+            Fq::from_montgomery_limbs([
+                16874999463369808484,
+                18075243263092295587,
+                14305465026403980636,
+                455297212036878654,
+            ]),
+            Fq::from_montgomery_limbs([
+                6659600514050025435,
+                17292946779883064151,
+                277788519439178344,
+                1024991263399764588,
+            ]),
+            Fq::from_montgomery_limbs([
+                13303772843999407682,
+                6715113361089363816,
+                4205851296015110953,
+                422728870271603178,
+            ]),
+            Fq::from_montgomery_limbs([
+                9165743763915823487,
+                14873137519130977646,
+                575625624156701306,
+                620003264966894421,
+            ]),
+            Fq::from_montgomery_limbs([
+                2675722179648392418,
+                5585844672885335992,
+                15113681713720910351,
+                361475522049622386,
+            ]),
+            Fq::from_montgomery_limbs([
+                1586327558532299074,
+                15540689304920167859,
+                5350757025867747594,
+                332275369952740097,
+            ]),
+            Fq::from_montgomery_limbs([
+                14135704728820581939,
+                18050012749257813237,
+                503697459949027804,
+                550005354906063995,
+            ]),
+            Fq::from_montgomery_limbs([
+                8509740390626535420,
+                9833546741828319920,
+                17034860342062725607,
+                1158479887756544128,
+            ]),
+            Fq::from_montgomery_limbs([
+                4470326376704285333,
+                2607219090756789927,
+                9431810618514947496,
+                931432029925856771,
+            ]),
+            Fq::from_montgomery_limbs([
+                8522281963829902721,
+                14073176301763948003,
+                11772706518619454457,
+                54109420719422651,
+            ]),
+            Fq::from_montgomery_limbs([
+                899220260982040430,
+                10494368063958176818,
+                1082510304430573121,
+                554742694385917203,
+            ]),
+            Fq::from_montgomery_limbs([
+                17192098924008727037,
+                5737420743753667822,
+                5035543822398524947,
+                64306063207015734,
+            ]),
+            Fq::from_montgomery_limbs([
+                13398139189254764087,
+                14986581544444759409,
+                7034658303781704218,
+                690964111186389876,
+            ]),
+            Fq::from_montgomery_limbs([
+                8735146977808336520,
+                11760938425913357049,
+                2086541718224675303,
+                2112043270687346,
+            ]),
+            Fq::from_montgomery_limbs([
+                18236166520751815993,
+                8540271672706710332,
+                14761246675459506461,
+                597507910681456992,
+            ]),
+            Fq::from_montgomery_limbs([
+                13896930264870230134,
+                15235906932518392542,
+                7321672641748010408,
+                338107527390755215,
+            ]),
+            Fq::from_montgomery_limbs([
+                430193357433575356,
+                573548079432283454,
+                14228263832105034406,
+                1123839393636648054,
+            ]),
+            Fq::from_montgomery_limbs([
+                12255348672023084189,
+                18290997644909676148,
+                3515207917753215498,
+                116702720268313280,
+            ]),
+            Fq::from_montgomery_limbs([
+                12879993786584288761,
+                1556885377627822637,
+                12973683629791291231,
+                447831799410748664,
+            ]),
+            Fq::from_montgomery_limbs([
+                696379836860844553,
+                2805363707514197154,
+                4597261928088448107,
+                954412493945935895,
+            ]),
+            Fq::from_montgomery_limbs([
+                16146808573377422596,
+                17744886485408276771,
+                15264669590844697806,
+                397711111644439875,
+            ]),
+            Fq::from_montgomery_limbs([
+                570633415583483068,
+                12406305897892862152,
+                13820213058498179372,
+                1196943180722334374,
+            ]),
+            Fq::from_montgomery_limbs([
+                1354165059018794566,
+                18278310541211445201,
+                7320583606808928630,
+                175617647814851263,
+            ]),
+            Fq::from_montgomery_limbs([
+                7679660649483812965,
+                6270379121195172965,
+                17983285011460590580,
+                121871326603299737,
+            ]),
+            Fq::from_montgomery_limbs([
+                1312877144475763645,
+                15311840865763560895,
+                7272491418070542311,
+                1035752786457757192,
+            ]),
+            Fq::from_montgomery_limbs([
+                3682939052921597099,
+                5495404988348743435,
+                12292496235869104295,
+                484667187497535742,
+            ]),
+            Fq::from_montgomery_limbs([
+                8134019250544826708,
+                13958940162663520456,
+                14809038070757837828,
+                592164474836619388,
+            ]),
+            Fq::from_montgomery_limbs([
+                1154007277844617566,
+                2722517023627074028,
+                15223674908576777702,
+                666590329906068884,
+            ]),
+            Fq::from_montgomery_limbs([
+                14374425189237101671,
+                10043586393369866953,
+                1848214587225176328,
+                853294692832306591,
+            ]),
+            Fq::from_montgomery_limbs([
+                3351267131129131896,
+                12463040602019259089,
+                8610737899562849134,
+                568677313619499275,
+            ]),
+            Fq::from_montgomery_limbs([
+                16077662472155082316,
+                14114308726860549622,
+                3013927512835182958,
+                571769536113060739,
+            ]),
+            Fq::from_montgomery_limbs([
+                14810662319981377449,
+                17104668117311060258,
+                1663330158602703846,
+                26236808660107952,
+            ]),
+            Fq::from_montgomery_limbs([
+                12653334005559750704,
+                4523651724172855698,
+                6594606915757018280,
+                801977721547936992,
+            ]),
+            Fq::from_montgomery_limbs([
+                10855485752859079028,
+                9156906423209462820,
+                216694908507293981,
+                1221401697329760555,
+            ]),
+            Fq::from_montgomery_limbs([
+                16380087325908648234,
+                18242818266488782470,
+                9185209588176890509,
+                1006432003150504729,
+            ]),
+            Fq::from_montgomery_limbs([
+                9005991578514842469,
+                7294704011817285927,
+                17045078740564364128,
+                177888178688690840,
+            ]),
+            Fq::from_montgomery_limbs([
+                14778266667966004882,
+                12601136042432953565,
+                4567569030996207156,
+                701208621725182299,
+            ]),
+            Fq::from_montgomery_limbs([
+                5557284792547312009,
+                5474831875830457540,
+                3726961756901985274,
+                277312885634410077,
+            ]),
+            Fq::from_montgomery_limbs([
+                1027823916746326335,
+                13031733966678739449,
+                3863714818345387835,
+                1203879527571191287,
+            ]),
+            Fq::from_montgomery_limbs([
+                6463019086664350598,
+                12154691338113436257,
+                9843481261782262421,
+                840120650332334551,
+            ]),
+            Fq::from_montgomery_limbs([
+                1268931336566849674,
+                8224310740713990405,
+                16369223902312945424,
+                1056419960474598292,
+            ]),
+            Fq::from_montgomery_limbs([
+                7190736424177008867,
+                294530240961090306,
+                4385403564543094874,
+                193384734196906168,
+            ]),
+            Fq::from_montgomery_limbs([
+                4405827281374251965,
+                13323191442378854139,
+                7990121694130299870,
+                734886094492915169,
+            ]),
+            Fq::from_montgomery_limbs([
+                12492585706666811687,
+                17917349024809056110,
+                14594716068636813283,
+                1161682726152372302,
+            ]),
+            Fq::from_montgomery_limbs([
+                7607334857187751120,
+                13465406665323081701,
+                10384478816381565342,
+                735679102855808942,
+            ]),
+            Fq::from_montgomery_limbs([
+                4404826754878640068,
+                11384828993286946896,
+                14961617948449662127,
+                143316868163878638,
+            ]),
+            Fq::from_montgomery_limbs([
+                16972994104584923076,
+                18068186418010543415,
+                1837401875132432940,
+                367326346015494010,
+            ]),
+            Fq::from_montgomery_limbs([
+                7500105980853785827,
+                12159081970975187227,
+                17793028786317788090,
+                697644581810912538,
+            ]),
+            Fq::from_montgomery_limbs([
+                9625631446029719405,
+                1488214559699196311,
+                7054369654159067417,
+                815256904673899277,
+            ]),
+            Fq::from_montgomery_limbs([
+                6116032409490796398,
+                7463527954523460823,
+                12407922760282568593,
+                207164181153764436,
+            ]),
+            Fq::from_montgomery_limbs([
+                14975405063612664995,
+                17945407152547410796,
+                78707834640874998,
+                1105028649936323710,
+            ]),
+            Fq::from_montgomery_limbs([
+                9825063764706019841,
+                227405106844569552,
+                7017259367570690259,
+                868217780820563739,
+            ]),
+            Fq::from_montgomery_limbs([
+                10769339849250424588,
+                5006132137515885792,
+                2859570758132256198,
+                570793696062494440,
+            ]),
+            Fq::from_montgomery_limbs([
+                3291056299580312435,
+                4891361167854275806,
+                12043003879858028703,
+                808329751260494901,
+            ]),
+            Fq::from_montgomery_limbs([
+                10854133905386182917,
+                13249272089424183363,
+                9411152807174791689,
+                568336578713574778,
+            ]),
+            Fq::from_montgomery_limbs([
+                1543788187659031549,
+                3686917056089079101,
+                5945731340264287140,
+                895959741846464335,
+            ]),
+            Fq::from_montgomery_limbs([
+                3330384298261715506,
+                15943288953559519522,
+                11942179486368273072,
+                1217428330397269139,
+            ]),
+            Fq::from_montgomery_limbs([
+                14159228568113767160,
+                10060449514337434325,
+                12160295094641915851,
+                1108027665057885824,
+            ]),
+            Fq::from_montgomery_limbs([
+                7137489973403014090,
+                11579577893002915834,
+                6467806181820976079,
+                1231741251503405413,
+            ]),
+            Fq::from_montgomery_limbs([
+                988248700577153007,
+                15085218635071934713,
+                18289672660512017133,
+                184495211155614456,
+            ]),
+            Fq::from_montgomery_limbs([
+                4960069656141360215,
+                15523603995661940737,
+                4835362028542731511,
+                23421643423615005,
+            ]),
+            Fq::from_montgomery_limbs([
+                15895256621439656693,
+                10918197062004863024,
+                1243780530952175753,
+                376114375548617024,
+            ]),
+            Fq::from_montgomery_limbs([
+                191111780965033976,
+                6276015495796043469,
+                11812576314800810839,
+                764841812609339273,
+            ]),
+            Fq::from_montgomery_limbs([
+                2290609380633464640,
+                8695952690946194514,
+                12296159459582852247,
+                700663144234728678,
+            ]),
+            Fq::from_montgomery_limbs([
+                10039154624586603270,
+                15989415914274424382,
+                12924608376066084679,
+                1252070756584015479,
+            ]),
+            Fq::from_montgomery_limbs([
+                6077985820211826068,
+                4758494978596127616,
+                7657485570968169960,
+                559619293790147839,
+            ]),
+            Fq::from_montgomery_limbs([
+                8915565477456149240,
+                3979197212530382422,
+                7098289448568226875,
+                750242016410465957,
+            ]),
+            Fq::from_montgomery_limbs([
+                13939629445835488598,
+                2559485653924171965,
+                16435024424035572951,
+                1089773562922623410,
+            ]),
+            Fq::from_montgomery_limbs([
+                13255711379646460715,
+                8234898089319841667,
+                11714478137684306213,
+                261804699298080163,
+            ]),
+            Fq::from_montgomery_limbs([
+                5158332602434964461,
+                15589316776534770349,
+                8678542149305542747,
+                706638837464866376,
+            ]),
+            Fq::from_montgomery_limbs([
+                10581213615114136995,
+                456351061973178008,
+                5451952616544943774,
+                1277431428973967360,
+            ]),
+            Fq::from_montgomery_limbs([
+                15245756424147404080,
+                708513608214897735,
+                9508090452033876726,
+                54077304180652574,
+            ]),
+            Fq::from_montgomery_limbs([
+                13574071405930250836,
+                5731542880805944148,
+                534333445387488412,
+                255747347072030218,
+            ]),
+            Fq::from_montgomery_limbs([
+                1388583925430809118,
+                955603397205331215,
+                10632323921288276198,
+                386085259832276052,
+            ]),
+            Fq::from_montgomery_limbs([
+                13271187763926842473,
+                3964864074561327359,
+                8539476036469919403,
+                273681188625017466,
+            ]),
+            Fq::from_montgomery_limbs([
+                16688159379034012066,
+                5096531866104244240,
+                7952994324954096669,
+                1041931664824010896,
+            ]),
+            Fq::from_montgomery_limbs([
+                8351405089061046068,
+                6410035311436690345,
+                14841269622281201027,
+                832767284969904839,
+            ]),
+            Fq::from_montgomery_limbs([
+                8805978768655767940,
+                8592401614712517950,
+                3269652132611660154,
+                1203991425691507952,
+            ]),
+            Fq::from_montgomery_limbs([
+                18343187939764724942,
+                12850107364597317209,
+                12204483115441815160,
+                1061026748494865016,
+            ]),
+            Fq::from_montgomery_limbs([
+                8679370928842649682,
+                5991050929874556728,
+                9712317830845331429,
+                769754107075200271,
+            ]),
+            Fq::from_montgomery_limbs([
+                14124853538131999493,
+                14043405283988348676,
+                8355563910778322913,
+                447704055611500992,
+            ]),
+            Fq::from_montgomery_limbs([
+                7032227625640879404,
+                3232574616291468214,
+                9136527282008485359,
+                314294897024025184,
+            ]),
+            Fq::from_montgomery_limbs([
+                6312088660120509708,
+                8116299260895691555,
+                10245145657294085122,
+                220108077659104969,
+            ]),
+            Fq::from_montgomery_limbs([
+                8051479965626886485,
+                2923684039769311506,
+                16510269450184104825,
+                308904033127475122,
+            ]),
+            Fq::from_montgomery_limbs([
+                6836553686644594986,
+                5920447156073468900,
+                15468009161902722118,
+                909532424858009272,
+            ]),
+            Fq::from_montgomery_limbs([
+                4645947671027679871,
+                17226830583636554698,
+                15262811080762860606,
+                469153361225995289,
+            ]),
+            Fq::from_montgomery_limbs([
+                3357171383742721252,
+                10673319594929746322,
+                13601224700427266476,
+                693200012777628685,
+            ]),
+            Fq::from_montgomery_limbs([
+                5917764092871652721,
+                12945106348085245477,
+                8939377528342490001,
+                1077326402945365919,
+            ]),
+            Fq::from_montgomery_limbs([
+                10426439804644961442,
+                17218511179002168397,
+                920752672040305864,
+                999713962207833807,
+            ]),
+            Fq::from_montgomery_limbs([
+                14696027223082391772,
+                15518394104597706453,
+                11363986064755556632,
+                344200608156284756,
+            ]),
+            Fq::from_montgomery_limbs([
+                3211421238673751157,
+                16998557507466826452,
+                12623258972600735411,
+                1178618024337878824,
+            ]),
+            Fq::from_montgomery_limbs([
+                12721524023048047095,
+                3637566219548600176,
+                9648528266476008223,
+                431529566834747698,
+            ]),
+            Fq::from_montgomery_limbs([
+                11811792510772065338,
+                6789085577846871590,
+                434596364292693268,
+                369461092059176598,
+            ]),
+            Fq::from_montgomery_limbs([
+                5567992985616545537,
+                2636522315411928192,
+                3054175601032570294,
+                100749678840700014,
+            ]),
+            Fq::from_montgomery_limbs([
+                16515223988363499998,
+                16228169840794075673,
+                12653732176294746720,
+                10596772658667310,
+            ]),
+            Fq::from_montgomery_limbs([
+                10993525584619395288,
+                579485505212289882,
+                17520982927165989756,
+                885733104981516953,
+            ]),
+            Fq::from_montgomery_limbs([
+                9989824959002805007,
+                14075078137834620627,
+                11395032181904465025,
+                1048171891207708587,
+            ]),
+            Fq::from_montgomery_limbs([
+                1286422265451386463,
+                4412967629242333140,
+                10033986546244466740,
+                7804030021407784,
+            ]),
+            Fq::from_montgomery_limbs([
+                9951839621515554619,
+                9727606988817821056,
+                15620390375118484541,
+                385603478746621742,
+            ]),
+            Fq::from_montgomery_limbs([
+                1279479062858079466,
+                12412773313092184236,
+                491728722817005084,
+                116671850206797697,
+            ]),
+            Fq::from_montgomery_limbs([
+                2710398861516984915,
+                14041713201550807042,
+                17921821120838731315,
+                83811945594696987,
+            ]),
+            Fq::from_montgomery_limbs([
+                18168889334486820497,
+                1024458361240836023,
+                8841182642694237071,
+                798790015477676069,
+            ]),
+            Fq::from_montgomery_limbs([
+                9946917890319261844,
+                8201379266063804893,
+                5177731922232458027,
+                768973824717697204,
+            ]),
+            Fq::from_montgomery_limbs([
+                7086831973220536779,
+                14240886522102791987,
+                13197733183768660980,
+                100769916618447069,
+            ]),
+            Fq::from_montgomery_limbs([
+                5124466107158717101,
+                7024130277165122568,
+                16447231087631466362,
+                12671653299117604,
+            ]),
+            Fq::from_montgomery_limbs([
+                16061181601309134525,
+                11416512293884238046,
+                4571809994299085489,
+                900015969159224799,
+            ]),
+            Fq::from_montgomery_limbs([
+                16642163921396767752,
+                1943955930086919103,
+                9523274159965378454,
+                734684338669392112,
+            ]),
+            Fq::from_montgomery_limbs([
+                10829871127436553579,
+                8527413452653563269,
+                2961297936848712480,
+                530535795208499775,
+            ]),
+            Fq::from_montgomery_limbs([
+                9053962609352564140,
+                3558825114921002541,
+                17862414841387284615,
+                1093993326296802175,
+            ]),
+            Fq::from_montgomery_limbs([
+                17972536636287874857,
+                14377194554867973088,
+                10449204658944982431,
+                274983189761374095,
+            ]),
+            Fq::from_montgomery_limbs([
+                5266773137149851017,
+                2731648421344595416,
+                13633223759185648501,
+                411577084301586914,
+            ]),
+            Fq::from_montgomery_limbs([
+                7470258536965391381,
+                17638812253632703875,
+                9103972262787981939,
+                511055364027671812,
+            ]),
+            Fq::from_montgomery_limbs([
+                2366304321569724392,
+                8111591928759778576,
+                9226316702450904449,
+                216587199141958279,
+            ]),
+            Fq::from_montgomery_limbs([
+                11056961827837445369,
+                10693387776793348293,
+                11761717636379360127,
+                758671514521337747,
+            ]),
+            Fq::from_montgomery_limbs([
+                17911132010252671504,
+                7892136436924415341,
+                10423957157071833029,
+                1343245502364502987,
+            ]),
+            Fq::from_montgomery_limbs([
+                4808363708369895100,
+                13914580221795987555,
+                8610898519236674942,
+                1237828570030760566,
+            ]),
+            Fq::from_montgomery_limbs([
+                9005940495295293555,
+                357750686032086041,
+                7602202493741179834,
+                63485617638279747,
+            ]),
+            Fq::from_montgomery_limbs([
+                11981376045571849499,
+                11659253594500119675,
+                10721081597721469032,
+                751597693047220196,
+            ]),
+            Fq::from_montgomery_limbs([
+                13884430463137530898,
+                189868713339586133,
+                16928567925975043500,
+                314945206805447569,
+            ]),
+            Fq::from_montgomery_limbs([
+                144349669230610449,
+                4801667767979784790,
+                310474069357982393,
+                959507370300279656,
+            ]),
+            Fq::from_montgomery_limbs([
+                8384645212442395493,
+                13761555188637637248,
+                18295459022401496953,
+                1341514638872757055,
+            ]),
+            Fq::from_montgomery_limbs([
+                892196376108262783,
+                7627267946558493844,
+                13066617694904527526,
+                312273657592048019,
+            ]),
+            Fq::from_montgomery_limbs([
+                11852897675402375002,
+                16419331069832745080,
+                5879862624015656416,
+                617119789583571170,
+            ]),
+            Fq::from_montgomery_limbs([
+                14211917062688876491,
+                10616541696202348551,
+                3967018899798127589,
+                634069037248297547,
+            ]),
+            Fq::from_montgomery_limbs([
+                14310046468673853741,
+                12817255528941823575,
+                10738102157224465760,
+                834683184258651023,
+            ]),
+            Fq::from_montgomery_limbs([
+                14352037971235449056,
+                3313590678991543255,
+                13285917655174006499,
+                79260245129007997,
+            ]),
+            Fq::from_montgomery_limbs([
+                16191744844487491833,
+                16436491867020442108,
+                15551383410108089046,
+                717274080735565091,
+            ]),
+            Fq::from_montgomery_limbs([
+                818968498534821291,
+                13661377355467339665,
+                16910216856159746818,
+                1321717030727631952,
+            ]),
+            Fq::from_montgomery_limbs([
+                3341575485954130835,
+                15949523857014248425,
+                17581765430673008240,
+                473528926929836293,
+            ]),
+            Fq::from_montgomery_limbs([
+                7599915852074118874,
+                7581148980192735119,
+                6033362563619477033,
+                999564088695699506,
+            ]),
+            Fq::from_montgomery_limbs([
+                15808404789692961474,
+                10260206927528094204,
+                11572606417918727998,
+                163814131274211402,
+            ]),
+            Fq::from_montgomery_limbs([
+                2286455237226316281,
+                14938251239678520482,
+                9531337837190793920,
+                15129221020707031,
+            ]),
+            Fq::from_montgomery_limbs([
+                14211879382587920277,
+                5669307456719234480,
+                1003685051859209644,
+                329951484921563609,
+            ]),
+            Fq::from_montgomery_limbs([
+                15042522702391532809,
+                3101007425748598549,
+                10316561849612330925,
+                265519352288701455,
+            ]),
+            Fq::from_montgomery_limbs([
+                6086349687125553353,
+                2660716555993001771,
+                1622163588904096238,
+                395356742984178688,
+            ]),
+            Fq::from_montgomery_limbs([
+                14676960226057130954,
+                10286106563788460111,
+                11249827371582042213,
+                18417308681158701,
+            ]),
+            Fq::from_montgomery_limbs([
+                2357093473455522805,
+                10377394370968214352,
+                9833841434994018297,
+                995292247684284690,
+            ]),
+            Fq::from_montgomery_limbs([
+                1193228979888865391,
+                6583828933397968294,
+                14857265733190467629,
+                542091302743573662,
+            ]),
+            Fq::from_montgomery_limbs([
+                14366468018476508387,
+                4517330738991770763,
+                4103232997309260908,
+                350013465995774207,
+            ]),
+            Fq::from_montgomery_limbs([
+                7601046689699910081,
+                6118747543853391525,
+                15553164840460117983,
+                1297241274977268967,
+            ]),
+            Fq::from_montgomery_limbs([
+                5098502199903043967,
+                12523166757352347983,
+                9250477403865932185,
+                577157483589187215,
+            ]),
+            Fq::from_montgomery_limbs([
+                1852582200743727899,
+                14311144984663248572,
+                7079542128477223063,
+                874454173131285524,
+            ]),
+            Fq::from_montgomery_limbs([
+                11875689742876058426,
+                11771039319523581991,
+                15903469502540906535,
+                1228598015640800964,
+            ]),
+            Fq::from_montgomery_limbs([
+                4458531782541630494,
+                16232552936445820177,
+                1648843764728950530,
+                150618545989159140,
+            ]),
+            Fq::from_montgomery_limbs([
+                984807175361368180,
+                13535924907888151519,
+                8369289490474243472,
+                491286366851896615,
+            ]),
+            Fq::from_montgomery_limbs([
+                5991065222892562675,
+                14254104308333785543,
+                5432231030216490829,
+                945988952407276429,
+            ]),
+            Fq::from_montgomery_limbs([
+                9811322688262915135,
+                5067753455998791420,
+                6523944985698968402,
+                733861411786418274,
+            ]),
+            Fq::from_montgomery_limbs([
+                13833841723030736555,
+                16772091625927119521,
+                5964809827388725933,
+                649243279623910659,
+            ]),
+            Fq::from_montgomery_limbs([
+                7883939155804658962,
+                4972231346140221405,
+                8851259646854709103,
+                677764351539313513,
+            ]),
+            Fq::from_montgomery_limbs([
+                3041756015423309328,
+                736108986205143705,
+                14637604199146244503,
+                480440306792637889,
+            ]),
+            Fq::from_montgomery_limbs([
+                5664165062391437446,
+                2215499900124781653,
+                13246655089547205069,
+                1218112246908574469,
+            ]),
+            Fq::from_montgomery_limbs([
+                6557756889330858665,
+                12694275628501456840,
+                745899459638693325,
+                551203928009359022,
+            ]),
+            Fq::from_montgomery_limbs([
+                13844400890237095775,
+                15628163949881106970,
+                5040269132487800478,
+                947530848174270077,
+            ]),
+            Fq::from_montgomery_limbs([
+                13672708603962049758,
+                15340069226622398116,
+                8016283218836263688,
+                716781176269161622,
+            ]),
+            Fq::from_montgomery_limbs([
+                16759630371765813623,
+                424378838031705915,
+                1727102460846873138,
+                525454848956977673,
+            ]),
+            Fq::from_montgomery_limbs([
+                11634103930200297817,
+                14694442968254652546,
+                15986321156534109241,
+                545013295285692627,
+            ]),
+            Fq::from_montgomery_limbs([
+                1556664708583554052,
+                12066906841841512750,
+                5366974187863387052,
+                1204606143071352508,
+            ]),
+            Fq::from_montgomery_limbs([
+                6503176776402700337,
+                6196827798161876217,
+                3407314293308906800,
+                876420029041555526,
+            ]),
+            Fq::from_montgomery_limbs([
+                3219751597433975390,
+                805213503918284236,
+                12625310188483447429,
+                577254773763361181,
+            ]),
+            Fq::from_montgomery_limbs([
+                17685701993577276208,
+                13706035291615013857,
+                1696868443878914431,
+                1250382877391890913,
+            ]),
+            Fq::from_montgomery_limbs([
+                4299334522017211489,
+                15740162971159772666,
+                12924331969640862260,
+                228316926890720713,
+            ]),
+            Fq::from_montgomery_limbs([
+                10571911539528331643,
+                17623255399550803685,
+                6190134238782299713,
+                571958013057611206,
+            ]),
+            Fq::from_montgomery_limbs([
+                168263670582407710,
+                5955845659592362333,
+                15798873361234406179,
+                303524543850352878,
+            ]),
+            Fq::from_montgomery_limbs([
+                15701211476931206651,
+                16042225704251521108,
+                7230809720025394427,
+                461253014957981468,
+            ]),
+            Fq::from_montgomery_limbs([
+                15788666195789919042,
+                1587841440640793903,
+                11886420718156960728,
+                1083736118928353845,
+            ]),
+            Fq::from_montgomery_limbs([
+                14484872426159151005,
+                15831157894710595581,
+                5301809521518976871,
+                256942044932723034,
+            ]),
+            Fq::from_montgomery_limbs([
+                14958447271739084918,
+                3101963992583744879,
+                16234036915449785318,
+                860772059685273323,
+            ]),
+            Fq::from_montgomery_limbs([
+                15294689260487878995,
+                16105681778432087440,
+                17963314367752526223,
+                760090456158282796,
+            ]),
+            Fq::from_montgomery_limbs([
+                11151920045335180308,
+                8054542286380704307,
+                3937217851119137434,
+                1076489316746327014,
+            ]),
+            Fq::from_montgomery_limbs([
+                17007378457461143755,
+                13466009752903731235,
+                17419296329420221588,
+                864575370727347525,
+            ]),
+            Fq::from_montgomery_limbs([
+                2940415550005221356,
+                2615999997808619714,
+                9996841587999153546,
+                790587537690418682,
+            ]),
+            Fq::from_montgomery_limbs([
+                17845811610150721466,
+                9072137262844037876,
+                18373330728528892161,
+                67099566380124031,
+            ]),
+            Fq::from_montgomery_limbs([
+                2880953238654064290,
+                10663988065007051685,
+                6810660565153525077,
+                537061337573760216,
+            ]),
+            Fq::from_montgomery_limbs([
+                14637842133917741987,
+                1805135804899905240,
+                18070497171484628981,
+                804441329604022013,
+            ]),
+            Fq::from_montgomery_limbs([
+                1520815392263152623,
+                5773396834899874729,
+                11651932182917461402,
+                150381855583639347,
+            ]),
+            Fq::from_montgomery_limbs([
+                16396779778125618524,
+                9367283511122471785,
+                3751685809360049666,
+                1085974763489757258,
+            ]),
+            Fq::from_montgomery_limbs([
+                9063438579807151363,
+                10895485889301046489,
+                9708241269451585313,
+                1074439981133335508,
+            ]),
+            Fq::from_montgomery_limbs([
+                9622214437100111873,
+                11582883407033633069,
+                5796229115773283813,
+                560424744122220650,
+            ]),
+            Fq::from_montgomery_limbs([
+                14978707951572594228,
+                10301100804313970039,
+                14500618105535114496,
+                766490198756586612,
+            ]),
+            Fq::from_montgomery_limbs([
+                18047780513599049716,
+                8919518734997572580,
+                16233555073885142796,
+                1215652743709276573,
+            ]),
+            Fq::from_montgomery_limbs([
+                9008816957470848192,
+                12302439426369241167,
+                8883963008583359490,
+                159115923760495806,
+            ]),
+            Fq::from_montgomery_limbs([
+                4959765682850273426,
+                6409285836718000047,
+                13209525825139278070,
+                1144393215007681384,
+            ]),
+            Fq::from_montgomery_limbs([
+                8543471710279929862,
+                2713975169621084250,
+                6275342634860117456,
+                117051838822796811,
+            ]),
+            Fq::from_montgomery_limbs([
+                6081089553765870770,
+                11679242247370682673,
+                14859274797069345750,
+                408559652864171918,
+            ]),
+            Fq::from_montgomery_limbs([
+                7976142395261706141,
+                4256633367090652193,
+                4414280575312262747,
+                710855828330687252,
+            ]),
+            Fq::from_montgomery_limbs([
+                13712863910740826508,
+                1168037211000793632,
+                17880398724460793467,
+                555504057742118521,
+            ]),
+            Fq::from_montgomery_limbs([
+                16256027037363063625,
+                11937865438706451764,
+                17156908929162349471,
+                85861429985362453,
+            ]),
+            Fq::from_montgomery_limbs([
+                8633645887298846638,
+                3789298139770681242,
+                13223967718380269701,
+                201377663363171005,
+            ]),
+            Fq::from_montgomery_limbs([
+                3319381827149319908,
+                18005562790345012663,
+                15916862515776112975,
+                849786155191989620,
+            ]),
+            Fq::from_montgomery_limbs([
+                10741172005867891549,
+                17811261431638486386,
+                14399580513301614476,
+                493553647254752054,
+            ]),
+            Fq::from_montgomery_limbs([
+                4570093441054467849,
+                1707201271417074952,
+                12670447272554500094,
+                107465928525886540,
+            ]),
+            Fq::from_montgomery_limbs([
+                3510706394478816206,
+                8342382676441050235,
+                4224908292330876323,
+                284971552319272279,
+            ]),
+            Fq::from_montgomery_limbs([
+                14856477523930503978,
+                14887127366182384146,
+                6231265853342833407,
+                843978676327466760,
+            ]),
+            Fq::from_montgomery_limbs([
+                6753301146236825525,
+                17896138959395468013,
+                7330228900850914225,
+                333699361326988355,
+            ]),
+            Fq::from_montgomery_limbs([
+                7221805583974373313,
+                5364802441183316085,
+                13678421823471280927,
+                7234769164728052,
+            ]),
+            Fq::from_montgomery_limbs([
+                5949706969264802651,
+                16106708410260197017,
+                14050642974773898044,
+                604069020110640476,
+            ]),
+            Fq::from_montgomery_limbs([
+                18322005458839666132,
+                11783747583112389588,
+                9996931751763758264,
+                60176148414025904,
+            ]),
+            Fq::from_montgomery_limbs([
+                15488693180170927203,
+                16751006794142479730,
+                5358178528955049464,
+                1306699044418995511,
+            ]),
+            Fq::from_montgomery_limbs([
+                4587075678246197115,
+                11329631136603627230,
+                5341332731962179876,
+                180221949616560785,
+            ]),
+            Fq::from_montgomery_limbs([
+                17839388444475265408,
+                347830755105819663,
+                1328107004908178922,
+                365556671035022061,
+            ]),
+            Fq::from_montgomery_limbs([
+                456148237380445917,
+                15902346747594762751,
+                7159146697541738561,
+                604238694591127396,
+            ]),
+            Fq::from_montgomery_limbs([
+                10602066577018631645,
+                7960833793540261533,
+                4981997454635531619,
+                619350234998036971,
+            ]),
+            Fq::from_montgomery_limbs([
+                5247408881176059880,
+                17257508356341192693,
+                4365596052842219562,
+                1061238028581133995,
+            ]),
+            Fq::from_montgomery_limbs([
+                11893860526074333240,
+                4920531103960225833,
+                806684880924323798,
+                103135623849771492,
+            ]),
+            Fq::from_montgomery_limbs([
+                14149449014373407968,
+                13183319255757255805,
+                17469289114679262891,
+                15659451834062417,
+            ]),
+            Fq::from_montgomery_limbs([
+                7182740173203711837,
+                1439585677121628785,
+                2288730530164050047,
+                1021551388486302811,
+            ]),
+            Fq::from_montgomery_limbs([
+                5501450416939310949,
+                15506158848418670831,
+                17636453562170240169,
+                937054322423982345,
+            ]),
+            Fq::from_montgomery_limbs([
+                1154786246029904334,
+                4019401752061381698,
+                10786899990178717506,
+                1234525105731166371,
+            ]),
+            Fq::from_montgomery_limbs([
+                587854497700354118,
+                16961356993978589819,
+                12347943080368551715,
+                311941237191392491,
+            ]),
+            Fq::from_montgomery_limbs([
+                18406724865487989170,
+                18339277154730291960,
+                5279757066864954266,
+                701308488676337438,
+            ]),
+            Fq::from_montgomery_limbs([
+                3468460133800848280,
+                3448341503174575629,
+                6317445393258253096,
+                1295665838975681695,
+            ]),
+            Fq::from_montgomery_limbs([
+                5777345929819139218,
+                1754300238282296129,
+                10026704233200737905,
+                1138862571505645408,
+            ]),
+            Fq::from_montgomery_limbs([
+                3237737858982680866,
+                17145086642569412553,
+                2541994230249620677,
+                546075672084258123,
+            ]),
+            Fq::from_montgomery_limbs([
+                151316481364201451,
+                13772731423440767989,
+                15114291844275495678,
+                669303790968088449,
+            ]),
+            Fq::from_montgomery_limbs([
+                3558643110534226766,
+                13655826422985146663,
+                18238732978386889064,
+                1178790293893619403,
+            ]),
+            Fq::from_montgomery_limbs([
+                17696544659284735937,
+                2516211111612413079,
+                10906119985188174069,
+                250656160967087811,
+            ]),
+            Fq::from_montgomery_limbs([
+                10863518520361173446,
+                11112765812078070297,
+                17248677756770733030,
+                387431571012114929,
+            ]),
+            Fq::from_montgomery_limbs([
+                9253571191661426727,
+                2155290421112918220,
+                4540866028623201639,
+                500943871992598862,
+            ]),
+            Fq::from_montgomery_limbs([
+                5112405709907842786,
+                564697308093464849,
+                6161502707126282832,
+                1174936473539659941,
+            ]),
+            Fq::from_montgomery_limbs([
+                11935448403905428966,
+                3493077928305072650,
+                1530561870051812022,
+                1175829360303833925,
+            ]),
+            Fq::from_montgomery_limbs([
+                18056122967375691996,
+                13945888614397099901,
+                8140023323507926262,
+                657759566023902600,
+            ]),
+            Fq::from_montgomery_limbs([
+                17567406446629126570,
+                13829950967533486702,
+                15291774536781584128,
+                1048073418547628144,
+            ]),
+            Fq::from_montgomery_limbs([
+                5527967819037432856,
+                9473429374330730501,
+                5227024943264276750,
+                439235460931482655,
+            ]),
+            Fq::from_montgomery_limbs([
+                3645373111565128998,
+                3112889736948068041,
+                16109020050729046184,
+                543107158536771700,
+            ]),
+            Fq::from_montgomery_limbs([
+                2050764858599900360,
+                12889802534736919360,
+                14556810216567008450,
+                1323192758128817732,
+            ]),
+            Fq::from_montgomery_limbs([
+                18022179995680407456,
+                13541450314214591202,
+                425509323259104471,
+                625395746539940098,
+            ]),
+            Fq::from_montgomery_limbs([
+                12417639501912447935,
+                17184933115985180470,
+                11311553812642845278,
+                222457518411755425,
+            ]),
+            Fq::from_montgomery_limbs([
+                13985979826005770030,
+                13901452797798753080,
+                14895443323270240298,
+                1322422192031185449,
+            ]),
+            Fq::from_montgomery_limbs([
+                6401917929531478211,
+                13139063095563805519,
+                14954485213271243017,
+                794923487470150551,
+            ]),
+            Fq::from_montgomery_limbs([
+                4475230306283212613,
+                1654293528096186770,
+                5358545364696638952,
+                21785617501631465,
+            ]),
+            Fq::from_montgomery_limbs([
+                14309679810132670189,
+                17365034328885052074,
+                7523244031246679055,
+                656263985469598213,
+            ]),
+            Fq::from_montgomery_limbs([
+                9619609110167467728,
+                7569113157829588200,
+                1625472368650208507,
+                523988916747619407,
+            ]),
+            Fq::from_montgomery_limbs([
+                12708055897773398801,
+                111749544409816638,
+                17135431550526695967,
+                340370434812843655,
+            ]),
+            Fq::from_montgomery_limbs([
+                4367367457533193439,
+                15890898996287329993,
+                5895705510081141941,
+                723054921878322850,
+            ]),
+            Fq::from_montgomery_limbs([
+                11058550602025667858,
+                4567126170919337618,
+                1614787678409023341,
+                991550575929725430,
+            ]),
+            Fq::from_montgomery_limbs([
+                10759725675839753919,
+                15450404677146386385,
+                17228823381193119804,
+                116968649381482408,
+            ]),
+            Fq::from_montgomery_limbs([
+                7117612355488389687,
+                5509941409554065843,
+                11621538644931120983,
+                873311689917822678,
+            ]),
+            Fq::from_montgomery_limbs([
+                17978597544565693161,
+                1141179032154337895,
+                11256790041083934128,
+                166390856329652287,
+            ]),
+            Fq::from_montgomery_limbs([
+                9072607518996412671,
+                12373565283068032724,
+                17026836543684582431,
+                343591764002152444,
+            ]),
+            Fq::from_montgomery_limbs([
+                2226560771069631223,
+                11107543974853677312,
+                1154489192640862566,
+                962520098062351796,
+            ]),
+            Fq::from_montgomery_limbs([
+                14291551955614241898,
+                16586639708028290101,
+                8086533845592186321,
+                294316660619533425,
+            ]),
+            Fq::from_montgomery_limbs([
+                2268272179349839020,
+                7671778692349467809,
+                10091532928621696695,
+                1289433584267872842,
+            ]),
+            Fq::from_montgomery_limbs([
+                1347560381823951973,
+                9618821760782843160,
+                8870919067875565152,
+                345548062170384787,
+            ]),
+            Fq::from_montgomery_limbs([
+                8985666990434979367,
+                7684099370176403406,
+                7844415733318224629,
+                113222685229177502,
+            ]),
+            Fq::from_montgomery_limbs([
+                12755958495652292586,
+                3438035969031983136,
+                13245935498340600273,
+                825942785769804399,
+            ]),
+            Fq::from_montgomery_limbs([
+                11124755036258275124,
+                14032954403553013018,
+                9177866266521789549,
+                345849831415166158,
+            ]),
+            Fq::from_montgomery_limbs([
+                8203963467994954200,
+                15285588731233286723,
+                6905999461654154982,
+                717506168459397698,
+            ]),
+            Fq::from_montgomery_limbs([
+                17597424059390267526,
+                15991076623889919029,
+                2518315799624911814,
+                1078380277667544867,
+            ]),
+            Fq::from_montgomery_limbs([
+                17531943787980176697,
+                4579394465031871242,
+                13142122510938404324,
+                1249824774329785182,
+            ]),
+            Fq::from_montgomery_limbs([
+                2407561084028434249,
+                10319586763084493750,
+                16489682048263010789,
+                650918554534482006,
+            ]),
+            Fq::from_montgomery_limbs([
+                1866072765727756421,
+                1691502226131789937,
+                3517734187644176751,
+                667200301876180149,
+            ]),
+            Fq::from_montgomery_limbs([
+                8175472226316951723,
+                4827332078066394612,
+                5202579200140087803,
+                1152526272352274671,
+            ]),
+            Fq::from_montgomery_limbs([
+                6785929028519830431,
+                231996482202234976,
+                3545444541386148659,
+                530759634463461542,
+            ]),
+            Fq::from_montgomery_limbs([
+                15852040270148434670,
+                12025401871966916891,
+                16496696688890758443,
+                798743126422344200,
+            ]),
+            Fq::from_montgomery_limbs([
+                11550619385334563521,
+                11040971630450332077,
+                14934359933877107103,
+                181419726826737711,
+            ]),
+            Fq::from_montgomery_limbs([
+                18059632528570258630,
+                9623603952523083162,
+                911964743199253493,
+                1081762525557151826,
+            ]),
+            Fq::from_montgomery_limbs([
+                7928962803568991925,
+                592544953007694659,
+                7334920133369425641,
+                713978390028073435,
+            ]),
+            Fq::from_montgomery_limbs([
+                2077358438281824642,
+                1534750511760133041,
+                16207843792669089532,
+                649757652439375317,
+            ]),
+            Fq::from_montgomery_limbs([
+                8045496694022444811,
+                13172150068001435241,
+                4520941021998355464,
+                270859403068760625,
+            ]),
+            Fq::from_montgomery_limbs([
+                16690816676734518811,
+                14306410128709063556,
+                13234076402898698946,
+                362207665400250840,
+            ]),
+            Fq::from_montgomery_limbs([
+                4628920805307787131,
+                5149682523090638064,
+                17724744309324279705,
+                64813246829529653,
+            ]),
+            Fq::from_montgomery_limbs([
+                7607112043868959932,
+                5173120949481455141,
+                1307372506049235009,
+                404718305830537366,
+            ]),
+            Fq::from_montgomery_limbs([
+                9572125858217951321,
+                948027378567776133,
+                8798264046184848198,
+                604150892456579088,
+            ]),
+            Fq::from_montgomery_limbs([
+                8095265156157194576,
+                15234751509343939097,
+                13074297822696053316,
+                929668668917367089,
+            ]),
+            Fq::from_montgomery_limbs([
+                611517451080949643,
+                3898529067263896897,
+                14634439666970490031,
+                941217137872926313,
+            ]),
+            Fq::from_montgomery_limbs([
+                11183274870702935621,
+                3715354818018342066,
+                6009117315918953525,
+                522280483926407317,
+            ]),
+            Fq::from_montgomery_limbs([
+                5487014655083242456,
+                942736734416236480,
+                9459155486313838854,
+                513782541047850901,
+            ]),
+            Fq::from_montgomery_limbs([
+                10121209688788615696,
+                13790278144928594488,
+                17946846210855222452,
+                113247404033453836,
+            ]),
+            Fq::from_montgomery_limbs([
+                156684278126515157,
+                5057313741521345878,
+                3615442263789615160,
+                1289648717851941198,
+            ]),
+            Fq::from_montgomery_limbs([
+                12162004652051462733,
+                17039051651444647745,
+                13856831402320611279,
+                961214641288198537,
+            ]),
+            Fq::from_montgomery_limbs([
+                2049685318733797326,
+                3070597771376118241,
+                5512818381526585541,
+                266514492760946753,
+            ]),
+            Fq::from_montgomery_limbs([
+                14507357653267980202,
+                2453100666829456918,
+                15266892963210243666,
+                700883591032378038,
+            ]),
+            Fq::from_montgomery_limbs([
+                13134244511870029504,
+                2999026469296935602,
+                16741627180688748980,
+                1082439247726272636,
+            ]),
+            Fq::from_montgomery_limbs([
+                10954578601105808759,
+                9441978883856617752,
+                523998213094760991,
+                644110166388375664,
+            ]),
+            Fq::from_montgomery_limbs([
+                16473059045883179657,
+                328100452271735194,
+                3044572924015254368,
+                378607665053780393,
+            ]),
+            Fq::from_montgomery_limbs([
+                8337324221186899587,
+                15990595419562370147,
+                10936828900699463397,
+                1316725482010433836,
+            ]),
+            Fq::from_montgomery_limbs([
+                8149042421847112780,
+                17311722726790936914,
+                5172642665913958918,
+                174044684404426840,
+            ]),
+            Fq::from_montgomery_limbs([
+                18235362376274135261,
+                16119581338355156020,
+                6406114871587346751,
+                1297574712620847572,
+            ]),
+            Fq::from_montgomery_limbs([
+                6289530207086624412,
+                9339531961256599168,
+                14952895721251576648,
+                1066249555221845109,
+            ]),
+            Fq::from_montgomery_limbs([
+                10184332200460712138,
+                13790724477154826135,
+                16097827773860664507,
+                802928735694159352,
+            ]),
+            Fq::from_montgomery_limbs([
+                15904019557221203050,
+                10103983996264057893,
+                12303662276535529726,
+                939266829669873091,
+            ]),
+            Fq::from_montgomery_limbs([
+                11368674466750209731,
+                15672501086802908100,
+                18135975156759260365,
+                870284041125105071,
+            ]),
+            Fq::from_montgomery_limbs([
+                8185127937307739846,
+                10416621775342252089,
+                14335404213032554699,
+                1133155550591922906,
+            ]),
+            Fq::from_montgomery_limbs([
+                4443734160893178576,
+                8207916865861505166,
+                4854727091698370709,
+                1287149564784357069,
+            ]),
+            Fq::from_montgomery_limbs([
+                13678460086055155643,
+                13084055079844188734,
+                8940640584456780495,
+                333760909477171645,
+            ]),
+            Fq::from_montgomery_limbs([
+                8011656335051370308,
+                845150486133989747,
+                1364142827007802475,
+                805894056739720067,
+            ]),
+            Fq::from_montgomery_limbs([
+                8888764319685611798,
+                17966046379917135222,
+                672212637960834316,
+                606304008325184569,
+            ]),
+            Fq::from_montgomery_limbs([
+                6523491754541599039,
+                294402049019987405,
+                13232643428010773755,
+                312340643692770360,
+            ]),
+            Fq::from_montgomery_limbs([
+                10096347085138178487,
+                12459154354377186195,
+                9880085597441505004,
+                1054181857907658121,
+            ]),
+            Fq::from_montgomery_limbs([
+                16056499912458184024,
+                8655144871562380138,
+                17689063537967617529,
+                821886137516353419,
+            ]),
+            Fq::from_montgomery_limbs([
+                1322346283244039841,
+                13634854958876008196,
+                9707556706360869312,
+                1096712479299908064,
+            ]),
+            Fq::from_montgomery_limbs([
+                7603875863871878395,
+                4350924755233855089,
+                12196060803329783245,
+                60765983239040390,
+            ]),
+            Fq::from_montgomery_limbs([
+                14306587303585403688,
+                1265505614027500162,
+                3753159830167960132,
+                421519627967589695,
+            ]),
+            Fq::from_montgomery_limbs([
+                12699047338131080979,
+                5783131659533693168,
+                2152740373132887099,
+                106381413296989546,
+            ]),
+            Fq::from_montgomery_limbs([
+                7508652971164250496,
+                8519544060621861014,
+                4223714604426517072,
+                1263456164551466381,
+            ]),
+            Fq::from_montgomery_limbs([
+                11534604951695825994,
+                12695698478268839273,
+                684579648446237057,
+                617528247982848175,
+            ]),
+            Fq::from_montgomery_limbs([
+                6325333592572758203,
+                8054058323006219449,
+                846695600641848485,
+                987714607636766762,
+            ]),
+            Fq::from_montgomery_limbs([
+                17472751718381101675,
+                7050321521071419143,
+                12450353905739663478,
+                613460349010520736,
+            ]),
+            Fq::from_montgomery_limbs([
+                2115939570030656543,
+                12889448256852020572,
+                7667220048155050310,
+                1024134261864105068,
+            ]),
+            Fq::from_montgomery_limbs([
+                1800019032949089440,
+                11048861522579478976,
+                12371644652850803924,
+                881164260297930073,
+            ]),
+            Fq::from_montgomery_limbs([
+                14300299090053814953,
+                15159616501654962130,
+                17858931892177232575,
+                37265578280320887,
+            ]),
+            Fq::from_montgomery_limbs([
+                9529245959801115909,
+                5832034048821782878,
+                11195180312007298559,
+                1136265855166421363,
+            ]),
+            Fq::from_montgomery_limbs([
+                1860753280854713979,
+                16137900038244562434,
+                7718066854814927499,
+                1065488153342181645,
+            ]),
+            Fq::from_montgomery_limbs([
+                3761313459032564847,
+                10896897269221230506,
+                15124243420616916479,
+                1047188454560976477,
+            ]),
+            Fq::from_montgomery_limbs([
+                8427416812289043061,
+                3231889688525998771,
+                3084394319969929622,
+                398291539903423400,
+            ]),
+            Fq::from_montgomery_limbs([
+                9350060910823157961,
+                1643591970587487927,
+                3870155763975950094,
+                331155987171632860,
+            ]),
+            Fq::from_montgomery_limbs([
+                6737983619252306281,
+                17008218707030513951,
+                15231606595727177444,
+                332575899631459920,
+            ]),
+            Fq::from_montgomery_limbs([
+                9048754323887084925,
+                15969094775280589077,
+                8901200464541202439,
+                1193946938087198946,
+            ]),
+            Fq::from_montgomery_limbs([
+                17824461793887167507,
+                8879997383476389191,
+                3989162743261716401,
+                21195034876017359,
+            ]),
+            Fq::from_montgomery_limbs([
+                5056074297395110692,
+                3427535900986346948,
+                8964359503647218677,
+                1212119454782259786,
+            ]),
+            Fq::from_montgomery_limbs([
+                1130405228168039481,
+                2175282827960713537,
+                3351937405149099826,
+                944073164110600905,
+            ]),
+            Fq::from_montgomery_limbs([
+                8912007097781056010,
+                14778350174219028407,
+                4561080165963147218,
+                638994429943799638,
+            ]),
+            Fq::from_montgomery_limbs([
+                13496990457363415328,
+                9975736379352057046,
+                8062171272043491944,
+                440370192155465566,
+            ]),
+            Fq::from_montgomery_limbs([
+                9995531966192258887,
+                8380506166057967793,
+                4430380099866278956,
+                1211329382211177468,
+            ]),
+            Fq::from_montgomery_limbs([
+                10277922521088523783,
+                713286728000920115,
+                187249435757547030,
+                1045690423722390682,
+            ]),
+            Fq::from_montgomery_limbs([
+                10589788457662325031,
+                1223975128840358359,
+                13601648785609571630,
+                1188304502839841523,
+            ]),
+            Fq::from_montgomery_limbs([
+                11047171848444345342,
+                13452424600298318536,
+                7171467430067086957,
+                1032175019047181825,
+            ]),
+            Fq::from_montgomery_limbs([
+                15414654076370063981,
+                15526207231727107480,
+                12224957091134050156,
+                367609165534501239,
+            ]),
+            Fq::from_montgomery_limbs([
+                10437120101549936645,
+                10970387049686701967,
+                9875945906305293981,
+                610692278221366514,
+            ]),
+            Fq::from_montgomery_limbs([
+                3620403610645153033,
+                15362918234595003751,
+                9550862431445394105,
+                1104814937452783441,
+            ]),
+            Fq::from_montgomery_limbs([
+                6955525823366684513,
+                7902781130778551478,
+                8375564210348090480,
+                772871932824669928,
+            ]),
+            Fq::from_montgomery_limbs([
+                8341154673203794411,
+                17889571211461570948,
+                7973158064384643694,
+                1217815525454009131,
+            ]),
+            Fq::from_montgomery_limbs([
+                9602438124805931557,
+                18352896512466846872,
+                18138292407133672470,
+                367522891444806843,
+            ]),
+            Fq::from_montgomery_limbs([
+                3151034524674467028,
+                12176771118481871211,
+                14172238519670961875,
+                988558201075169967,
+            ]),
+            Fq::from_montgomery_limbs([
+                10138323161045052235,
+                7175131172398912005,
+                17131422158685790750,
+                967375711566267772,
+            ]),
+            Fq::from_montgomery_limbs([
+                14020470808218012545,
+                16810609269918488070,
+                9317825369261858309,
+                213623090528306639,
+            ]),
+            Fq::from_montgomery_limbs([
+                5082718442281501594,
+                12688171517344947324,
+                9064308851680488244,
+                738671471171240418,
+            ]),
+            Fq::from_montgomery_limbs([
+                6049933041280263727,
+                17048142224811664431,
+                11010340091154160129,
+                987532896000314001,
+            ]),
+            Fq::from_montgomery_limbs([
+                15237361301395955929,
+                2162553098122406338,
+                2845406106842249823,
+                623221109211793885,
+            ]),
+            Fq::from_montgomery_limbs([
+                11218058762003694120,
+                17625810177155101339,
+                8457563244521037603,
+                652792847746514863,
+            ]),
+            Fq::from_montgomery_limbs([
+                6372453968622733850,
+                15628489385634717504,
+                870841246264056339,
+                1286700327149384159,
+            ]),
+            Fq::from_montgomery_limbs([
+                16318433510683677157,
+                17494200503364775193,
+                14797470159019178938,
+                1281577371838037057,
+            ]),
+            Fq::from_montgomery_limbs([
+                15136140965180698000,
+                15395201998865300978,
+                10578071867046352855,
+                1317904714442907826,
+            ]),
+            Fq::from_montgomery_limbs([
+                1073762564171445299,
+                1467063898709475301,
+                13348727174358515363,
+                521703202507484097,
+            ]),
+            Fq::from_montgomery_limbs([
+                6136158371235654579,
+                16817205332869989432,
+                9946208634740423828,
+                200786969359615012,
+            ]),
+            Fq::from_montgomery_limbs([
+                12849199145212260656,
+                1436926961597818225,
+                15128182549290442624,
+                343617930974617714,
+            ]),
+            Fq::from_montgomery_limbs([
+                13112482477973726189,
+                13277785830576247581,
+                7787552922223026602,
+                342306581742990706,
+            ]),
+            Fq::from_montgomery_limbs([
+                15619729497604855669,
+                8582685404331278598,
+                2764519284337418831,
+                526392681258817037,
+            ]),
+            Fq::from_montgomery_limbs([
+                9931604636033786410,
+                14712747986382668096,
+                13344137595716674177,
+                1075654639870132864,
+            ]),
+            Fq::from_montgomery_limbs([
+                17208739811034689783,
+                16762712723014783391,
+                15887552447447362367,
+                968587408997840685,
+            ]),
+            Fq::from_montgomery_limbs([
+                12964375469800740446,
+                5466025345055937419,
+                8288136308188104072,
+                1222415983353728206,
+            ]),
+            Fq::from_montgomery_limbs([
+                2721247223686846619,
+                13442110251925479886,
+                12026154421709235431,
+                1125248921245360249,
+            ]),
+            Fq::from_montgomery_limbs([
+                4823127714003138225,
+                2663330077281809422,
+                14260710562638296627,
+                847035686599741406,
+            ]),
+            Fq::from_montgomery_limbs([
+                4848120970934863448,
+                5722225241064315574,
+                16439375413465341887,
+                337594657096068551,
+            ]),
+            Fq::from_montgomery_limbs([
+                6008551865624645786,
+                7264786164402455814,
+                18287377252189135068,
+                512471022335342364,
+            ]),
+            Fq::from_montgomery_limbs([
+                4053652851419071896,
+                2291052577656273424,
+                2914989898703620381,
+                466529992438837286,
+            ]),
+            Fq::from_montgomery_limbs([
+                13356642770936062084,
+                15575733902488334714,
+                11941016096225891750,
+                108958640910826453,
+            ]),
+            Fq::from_montgomery_limbs([
+                13954586516121026609,
+                8462603332873011249,
+                6399209440684013788,
+                1132563414160488758,
+            ]),
+            Fq::from_montgomery_limbs([
+                17252726799911303210,
+                1720677697855298760,
+                6626457036548356985,
+                222712780059854441,
+            ]),
+            Fq::from_montgomery_limbs([
+                11723984718328297926,
+                11354442045615433642,
+                8139540115391060871,
+                368196364191470776,
+            ]),
+            Fq::from_montgomery_limbs([
+                14205101913550131874,
+                2559031581303139082,
+                10842603788690004623,
+                798392714913957356,
+            ]),
+            Fq::from_montgomery_limbs([
+                16110491247998142342,
+                354929291584077054,
+                17691605584004704332,
+                391120853860549811,
+            ]),
+            Fq::from_montgomery_limbs([
+                2815145619654333687,
+                822952185724719133,
+                4196711975176239901,
+                571716091200910659,
+            ]),
+            Fq::from_montgomery_limbs([
+                11927016985947682302,
+                14881914387885503945,
+                16714904588875223976,
+                725187454083254319,
+            ]),
+            Fq::from_montgomery_limbs([
+                6020408392572496513,
+                11439539533260883517,
+                16701316886503014582,
+                1312272040708983719,
+            ]),
+            Fq::from_montgomery_limbs([
+                2379764171343256639,
+                5164131540547903776,
+                16675986921426248719,
+                391584368122551784,
+            ]),
+            Fq::from_montgomery_limbs([
+                12439941557579703681,
+                7302167286865353153,
+                1016074545931964495,
+                666785228187861726,
+            ]),
+            Fq::from_montgomery_limbs([
+                12165320642507726595,
+                3795538972043654821,
+                1654922209466577769,
+                12466717832678766,
+            ]),
+            Fq::from_montgomery_limbs([
+                2136504589951920554,
+                4049672460999147971,
+                749422556653545267,
+                34960916641596173,
+            ]),
+            Fq::from_montgomery_limbs([
+                15553033538675780606,
+                7837268305449053011,
+                4966642141667894588,
+                211930212677153668,
+            ]),
+            Fq::from_montgomery_limbs([
+                7925008358058373782,
+                4313055510228899942,
+                15171361497064674012,
+                664674046677296067,
+            ]),
+            Fq::from_montgomery_limbs([
+                13247757934035255807,
+                3324273575661015416,
+                16109638311490130096,
+                1290066844119546529,
+            ]),
+            Fq::from_montgomery_limbs([
+                15270081048516760074,
+                2129882141756772747,
+                8190227588713058888,
+                935756908279990780,
+            ]),
+            Fq::from_montgomery_limbs([
+                4708328719589200663,
+                10621776932520764714,
+                8519958057207279451,
+                1301498548436267508,
+            ]),
+            Fq::from_montgomery_limbs([
+                4391879050991022853,
+                10271126058149372435,
+                875368429621696434,
+                200679951638106753,
+            ]),
+            Fq::from_montgomery_limbs([
+                11742137142089455486,
+                17595718929650718062,
+                15398217117011759327,
+                911790477133276275,
+            ]),
+            Fq::from_montgomery_limbs([
+                7389059691530388995,
+                11700057338122636627,
+                12118199163764793840,
+                128231934755624305,
+            ]),
+            Fq::from_montgomery_limbs([
+                8282687765509349175,
+                1477312458913714305,
+                10393873268964347526,
+                671021459982042112,
+            ]),
+            Fq::from_montgomery_limbs([
+                5996034645718099921,
+                12070274995188783044,
+                307178625169187508,
+                483818145945325553,
+            ]),
+            Fq::from_montgomery_limbs([
+                15156159252922353657,
+                7683579087286960455,
+                10345184972964878300,
+                272667061190369946,
+            ]),
+            Fq::from_montgomery_limbs([
+                17926663445960805461,
+                16504603534642348352,
+                10005198666089850704,
+                868140390857234467,
+            ]),
+            Fq::from_montgomery_limbs([
+                3832603738830538392,
+                16698701787960284209,
+                12158127096792155718,
+                260407356028601024,
+            ]),
+            Fq::from_montgomery_limbs([
+                965209426276896302,
+                2224234674149898278,
+                10693481099405395078,
+                784180449900377868,
+            ]),
+            Fq::from_montgomery_limbs([
+                12183272652299867710,
+                13943936057182099766,
+                6260839944789594842,
+                1059782761626994555,
+            ]),
+            Fq::from_montgomery_limbs([
+                18168416758394049358,
+                4317625086561484215,
+                16788107935584934562,
+                585894166673668041,
+            ]),
+            Fq::from_montgomery_limbs([
+                16024765817478578878,
+                17283217310372527941,
+                1457000786021502440,
+                1167011114687589559,
+            ]),
+            Fq::from_montgomery_limbs([
+                3856313631168894736,
+                505925786718272978,
+                5493233560632558691,
+                656303005912077003,
+            ]),
+            Fq::from_montgomery_limbs([
+                16708519883163372933,
+                6867157456898961322,
+                15342233546793660262,
+                1292839416584855056,
+            ]),
+            Fq::from_montgomery_limbs([
+                6149275420026283301,
+                3230360503927653845,
+                13399739764004550765,
+                255635138574680068,
+            ]),
+            Fq::from_montgomery_limbs([
+                11242951552630169425,
+                17890222003640219315,
+                7686936364686096475,
+                76306005372221031,
+            ]),
+            Fq::from_montgomery_limbs([
+                15967860116359708222,
+                17953701655676179825,
+                3075010962088299374,
+                646790915486546624,
+            ]),
+            Fq::from_montgomery_limbs([
+                1209929780278750001,
+                12818025305236431914,
+                15415511350211684122,
+                77234908874441290,
+            ]),
+            Fq::from_montgomery_limbs([
+                2093787252335352365,
+                15544318425982754079,
+                8784134268413898668,
+                1066175934835184490,
+            ]),
+            Fq::from_montgomery_limbs([
+                18147677888242460171,
+                1005129778204491323,
+                13699363007645248702,
+                1269911083363732513,
+            ]),
+            Fq::from_montgomery_limbs([
+                10441738931743762323,
+                18358305656845050833,
+                17748881913909464105,
+                21241575723127182,
+            ]),
+            Fq::from_montgomery_limbs([
+                16826671050545208,
+                17883531786814318073,
+                3310408323426954645,
+                1027132071084495551,
+            ]),
+            Fq::from_montgomery_limbs([
+                4629303103339980149,
+                10074371068403097225,
+                4566593269486360756,
+                902970050702114071,
+            ]),
+            Fq::from_montgomery_limbs([
+                7748928135603597115,
+                17736293958975912240,
+                18359899957664863722,
+                801894493254269301,
+            ]),
+            Fq::from_montgomery_limbs([
+                6537280508929349566,
+                548727786107474353,
+                11656960384699863489,
+                372700391626227887,
+            ]),
+            Fq::from_montgomery_limbs([
+                13748168543315113812,
+                11390633735253555709,
+                8165289575169613588,
+                348293158567653607,
+            ]),
+            Fq::from_montgomery_limbs([
+                4653195243239089702,
+                8859163469610127181,
+                10752497697822932229,
+                71199660761243839,
+            ]),
+            Fq::from_montgomery_limbs([
+                17396056965905185269,
+                751652015206225309,
+                2753697468093387149,
+                350029848913539829,
+            ]),
+            Fq::from_montgomery_limbs([
+                14345750412818932413,
+                16851786493494872507,
+                17352358844517551750,
+                625116652696468340,
+            ]),
+            Fq::from_montgomery_limbs([
+                3397247643327839340,
+                11148766248740557182,
+                4387080171316553865,
+                151048624901066043,
+            ]),
+            Fq::from_montgomery_limbs([
+                1884856181467535738,
+                10895703149198503119,
+                1631003469338427949,
+                27386786980653453,
+            ]),
+            Fq::from_montgomery_limbs([
+                12286665579953552143,
+                10597605962282680350,
+                15811383540992154005,
+                675990743383399294,
+            ]),
+            Fq::from_montgomery_limbs([
+                1828925271926641809,
+                6880123696283112995,
+                1441915024532553105,
+                162356496573352876,
+            ]),
+            Fq::from_montgomery_limbs([
+                132355866245626834,
+                16042926185762579419,
+                13351785525622552069,
+                1174223291454828499,
+            ]),
+            Fq::from_montgomery_limbs([
+                981697735048022942,
+                2839255952599672178,
+                3790302405503991237,
+                798862494624801548,
+            ]),
+            Fq::from_montgomery_limbs([
+                8725860011650930555,
+                4139692685704617570,
+                7680093794835258615,
+                941604672941631316,
+            ]),
+            Fq::from_montgomery_limbs([
+                1202604774571743404,
+                2753322575437426170,
+                995589383061949224,
+                906990267509343115,
+            ]),
+            Fq::from_montgomery_limbs([
+                14676892285848604160,
+                8777307029909669823,
+                4350381876492889738,
+                284765335742115829,
+            ]),
+            Fq::from_montgomery_limbs([
+                7153307922645146904,
+                2012188244074177352,
+                6747342853641879833,
+                699340139448245472,
+            ]),
+            Fq::from_montgomery_limbs([
+                15318015852179026157,
+                12164268131301318294,
+                4324094068209048746,
+                1079994775195734019,
+            ]),
+            Fq::from_montgomery_limbs([
+                13413904095790617050,
+                15579126705696498167,
+                14291949102677574795,
+                823560549479289094,
+            ]),
+            Fq::from_montgomery_limbs([
+                4507082690200081720,
+                6065082648811966362,
+                16141996720561239795,
+                556995497729566396,
+            ]),
+            Fq::from_montgomery_limbs([
+                7132810709307861348,
+                2459600435392700056,
+                6474208609142517167,
+                828781771249734294,
+            ]),
+            Fq::from_montgomery_limbs([
+                12530786095175896416,
+                8832303319290880599,
+                459525122219302265,
+                300550078207375387,
+            ]),
+            Fq::from_montgomery_limbs([
+                1465448223282076863,
+                347033541475526530,
+                15989348566553178733,
+                1248158724106726276,
+            ]),
+            Fq::from_montgomery_limbs([
+                2249698357466108905,
+                18435769657277368578,
+                7513968251541623619,
+                549236834147538307,
+            ]),
+            Fq::from_montgomery_limbs([
+                15302583286007958935,
+                12316632078239782840,
+                10287203067210700695,
+                1178867813587995493,
+            ]),
+            Fq::from_montgomery_limbs([
+                12899303281013313633,
+                1524998286023000338,
+                13516984730655701548,
+                81198042964008657,
+            ]),
+            Fq::from_montgomery_limbs([
+                12971909937872305031,
+                8288079883716948096,
+                89681826659402537,
+                616781664900583220,
+            ]),
+            Fq::from_montgomery_limbs([
+                158379831781733079,
+                17615225368893335474,
+                972394939878491351,
+                1263705880122624063,
+            ]),
+            Fq::from_montgomery_limbs([
+                13305475237453685418,
+                184123916379705176,
+                9501222010456552702,
+                75251073370328135,
+            ]),
+            Fq::from_montgomery_limbs([
+                11937660104854925035,
+                13747032460851225761,
+                8951761981380853993,
+                522762209684877097,
+            ]),
+            Fq::from_montgomery_limbs([
+                550863992062989723,
+                1244565545186909789,
+                12736113209915413676,
+                274943275771956597,
+            ]),
+            Fq::from_montgomery_limbs([
+                15812145883139139960,
+                15045778868004615904,
+                6176581782190786066,
+                740828812681442454,
+            ]),
+            Fq::from_montgomery_limbs([
+                2349384771835730283,
+                18357366900932661321,
+                2983759561502441062,
+                7871643682905194,
+            ]),
+            Fq::from_montgomery_limbs([
+                13903062098451886385,
+                1639994390922498891,
+                10791886688002923336,
+                743946880268515308,
+            ]),
+            Fq::from_montgomery_limbs([
+                16600826213357303038,
+                8144547900405039912,
+                9501972806745767389,
+                1129042079548260159,
+            ]),
+            Fq::from_montgomery_limbs([
+                4841862781660686065,
+                9510862991834062131,
+                2315369565714966907,
+                1163375930330057190,
+            ]),
+            Fq::from_montgomery_limbs([
+                10037352153233739152,
+                7515656071455462107,
+                4010281908070033447,
+                908532478899931906,
+            ]),
+            Fq::from_montgomery_limbs([
+                11066945972639004068,
+                10985731270336298125,
+                16557419713846203271,
+                611127815226778545,
+            ]),
+            Fq::from_montgomery_limbs([
+                14745505509765418166,
+                7982017123552164662,
+                6265737503581221356,
+                102541604337015075,
+            ]),
+            Fq::from_montgomery_limbs([
+                5304624174146888447,
+                221711692365204910,
+                4132075247848733218,
+                1128062627281689644,
+            ]),
+            Fq::from_montgomery_limbs([
+                12767818945117392288,
+                2176661185100750285,
+                3389168643800497613,
+                1329361775022896759,
+            ]),
+            Fq::from_montgomery_limbs([
+                3798212207367653888,
+                12035656520792414669,
+                13755085650628400275,
+                450068205508660009,
+            ]),
+            Fq::from_montgomery_limbs([
+                14317764943943050530,
+                2146736591046928191,
+                17449184419743481210,
+                1110170355238636349,
+            ]),
+            Fq::from_montgomery_limbs([
+                16235325299964566873,
+                8278256795183711175,
+                12458585589918840900,
+                361107759233490732,
+            ]),
+            Fq::from_montgomery_limbs([
+                9530531622888323504,
+                1250440857646691821,
+                2772473630560518949,
+                1296635946283688442,
+            ]),
+            Fq::from_montgomery_limbs([
+                7816551898751661975,
+                16587859767437067820,
+                5699925686980414866,
+                569935463513345367,
+            ]),
+            Fq::from_montgomery_limbs([
+                711384779023514995,
+                14707619405869076528,
+                6587973369203467759,
+                1000362297446859035,
+            ]),
+            Fq::from_montgomery_limbs([
+                3352616456228076589,
+                6168141437024239980,
+                3695559448817079182,
+                302237324649968138,
+            ]),
+            Fq::from_montgomery_limbs([
+                5015381777087908755,
+                13683710811278040339,
+                14903188576055137133,
+                938778500259665483,
+            ]),
+            Fq::from_montgomery_limbs([
+                11178023293157605329,
+                7480894117221210160,
+                959836239176602728,
+                253321455730064997,
+            ]),
+            Fq::from_montgomery_limbs([
+                16903331825455608953,
+                16692436494701865942,
+                2777256592342195755,
+                1273932037038910968,
+            ]),
+            Fq::from_montgomery_limbs([
+                13001376606809621571,
+                9629756602189220357,
+                10948550875497555194,
+                199227506801784399,
+            ]),
+            Fq::from_montgomery_limbs([
+                1488346726275061907,
+                10097997469120402884,
+                6870441833775158903,
+                302255106011755387,
+            ]),
+            Fq::from_montgomery_limbs([
+                16561625567935788941,
+                1271909798310267905,
+                12708621840755487757,
+                1013419283690298283,
+            ]),
+            Fq::from_montgomery_limbs([
+                14411051712394216808,
+                13449011134118789106,
+                15363787097180661413,
+                884613145692534997,
+            ]),
+            Fq::from_montgomery_limbs([
+                5059464069636669963,
+                4975580491437643937,
+                8720343363190376516,
+                496445775321503086,
+            ]),
+            Fq::from_montgomery_limbs([
+                4075928182085410795,
+                7110413459863412376,
+                15840047122010304358,
+                489654719083215298,
+            ]),
+            Fq::from_montgomery_limbs([
+                15995014752404648548,
+                16201780722639546765,
+                5322767539190789496,
+                450699102076231781,
+            ]),
+            Fq::from_montgomery_limbs([
+                766685113402087067,
+                12977660846554676949,
+                1547200720544377201,
+                1209722930053478643,
+            ]),
+            Fq::from_montgomery_limbs([
+                11063843537124431621,
+                15261828955010577646,
+                3308003608272598426,
+                281455506912699402,
+            ]),
+            Fq::from_montgomery_limbs([
+                14442345053552440480,
+                11677989500384482847,
+                10222882010364490228,
+                465954529287357142,
+            ]),
+            Fq::from_montgomery_limbs([
+                17242626029323482355,
+                16109514899522893153,
+                11701806595280921750,
+                159417156539150637,
+            ]),
+            Fq::from_montgomery_limbs([
+                18188465579287975592,
+                8311356868553707615,
+                3970651160039926566,
+                956061424524875937,
+            ]),
+            Fq::from_montgomery_limbs([
+                18285663127409744899,
+                16781572230761441409,
+                8070607946708779604,
+                282412936808751945,
+            ]),
+            Fq::from_montgomery_limbs([
+                10260218134446091400,
+                1480185728256039939,
+                5939296907660983299,
+                65181633936406678,
+            ]),
+            Fq::from_montgomery_limbs([
+                2137840173376036704,
+                1246520209605540329,
+                17326361735179112376,
+                323648130635617427,
+            ]),
+            Fq::from_montgomery_limbs([
+                16285650753556197806,
+                7821303712894897107,
+                11551780344810020883,
+                827785552752991530,
+            ]),
+            Fq::from_montgomery_limbs([
+                4356303459259735665,
+                6530443680102347548,
+                6245654693819417280,
+                58545495382014565,
+            ]),
+            Fq::from_montgomery_limbs([
+                16281688703970115914,
+                9400417151954556525,
+                6519556046914958356,
+                685834379036088728,
+            ]),
+            Fq::from_montgomery_limbs([
+                11412884347578820485,
+                2719038836815645884,
+                10112752577144748530,
+                139073495143064499,
+            ]),
+            Fq::from_montgomery_limbs([
+                941538459770925533,
+                2979273170773894732,
+                651400082066491498,
+                277012162065791450,
+            ]),
+            Fq::from_montgomery_limbs([
+                15520415527566049345,
+                6500908802351188894,
+                11285511632584835202,
+                1198522922144097878,
+            ]),
+            Fq::from_montgomery_limbs([
+                9463163296002774085,
+                1519037273698819179,
+                17691561074208356546,
+                11871397228829679,
+            ]),
+            Fq::from_montgomery_limbs([
+                6369897102106529260,
+                18425442081180370364,
+                14293047481496881384,
+                655367569792640099,
+            ]),
+            Fq::from_montgomery_limbs([
+                17017325614812037026,
+                12667007489239735776,
+                12146034497403053138,
+                1157481047131500898,
+            ]),
+            Fq::from_montgomery_limbs([
+                5768002270347474383,
+                16018475490802166720,
+                15784771539154160723,
+                59663649999808502,
+            ]),
+            Fq::from_montgomery_limbs([
+                15643831766244365676,
+                12178534871884907231,
+                12485624713529574071,
+                548219750037256318,
+            ]),
+            Fq::from_montgomery_limbs([
+                12762204884698775919,
+                11497865290643921617,
+                11307698980225554978,
+                1078445092804820165,
+            ]),
+            Fq::from_montgomery_limbs([
+                107874038616112212,
+                18043623959702517786,
+                16393949753966297183,
+                177509222917072721,
+            ]),
+            Fq::from_montgomery_limbs([
+                12173872042271254032,
+                17910516617935937561,
+                11864775769072062063,
+                598070590926602915,
+            ]),
+            Fq::from_montgomery_limbs([
+                14653459333910289747,
+                13793973501694016360,
+                4914292040432063535,
+                984259233910711265,
+            ]),
+            Fq::from_montgomery_limbs([
+                9617642131203311134,
+                7521045077386724979,
+                2993407665461767492,
+                610510415347833772,
+            ]),
+            Fq::from_montgomery_limbs([
+                3284973821477992706,
+                7131700377830386102,
+                2867456559867809958,
+                691138916018739135,
+            ]),
+            Fq::from_montgomery_limbs([
+                11360422097621990486,
+                5783068172721030252,
+                4693333124797912097,
+                217616356790761318,
+            ]),
+            Fq::from_montgomery_limbs([
+                15486989624604771975,
+                5732458975161250305,
+                11693413771779511181,
+                137275839555185617,
+            ]),
+            Fq::from_montgomery_limbs([
+                4328567636315509787,
+                1437297708588006835,
+                7089443363457299027,
+                774444663743043608,
+            ]),
+            Fq::from_montgomery_limbs([
+                14438476648826658150,
+                10656708736959017609,
+                11375955806413048223,
+                744882230500236499,
+            ]),
+            Fq::from_montgomery_limbs([
+                13614898503446047915,
+                17198277391662440825,
+                16218561844374992578,
+                636606937725359372,
+            ]),
+            Fq::from_montgomery_limbs([
+                3866566224865957336,
+                907569111611392731,
+                3249919925856601229,
+                115574212902305800,
+            ]),
+            Fq::from_montgomery_limbs([
+                17260838680664251141,
+                6538516492089172904,
+                13191006507264330470,
+                523518789471723460,
+            ]),
+            Fq::from_montgomery_limbs([
+                1043595370040008617,
+                16040331100499361832,
+                1559940696621765370,
+                214476078138434329,
+            ]),
+            Fq::from_montgomery_limbs([
+                2230839665913709468,
+                1262182492005497931,
+                13764621464627300251,
+                733681993438173265,
+            ]),
+            Fq::from_montgomery_limbs([
+                2413808441999675671,
+                8988313188803146749,
+                2021938035360439410,
+                660900691827106128,
+            ]),
+            Fq::from_montgomery_limbs([
+                8792167381955248492,
+                17467383363827623978,
+                11344511765878336002,
+                787556237032319195,
+            ]),
+            Fq::from_montgomery_limbs([
+                17252609531970957946,
+                7390946048916124673,
+                6623002509065936790,
+                1078981119192867608,
+            ]),
+            Fq::from_montgomery_limbs([
+                1754466633699329957,
+                8650140933106732241,
+                9627388686184897722,
+                102217145540056489,
+            ]),
+            Fq::from_montgomery_limbs([
+                5890670009680715333,
+                8991310908876194064,
+                13515476595883375678,
+                372966991988198403,
+            ]),
+            Fq::from_montgomery_limbs([
+                400977738579647458,
+                4059013279827630835,
+                6621428659115438138,
+                769645786063177143,
+            ]),
+            Fq::from_montgomery_limbs([
+                8531369216288862708,
+                9560987000676726787,
+                16974490871498173869,
+                462180470265390123,
+            ]),
+            Fq::from_montgomery_limbs([
+                2362202258883579071,
+                11863761230584067327,
+                12317935819090713437,
+                1271950143436984797,
+            ]),
+            Fq::from_montgomery_limbs([
+                2159301413765309314,
+                4283919667190641047,
+                17057577376321346535,
+                827883541155264783,
+            ]),
+            Fq::from_montgomery_limbs([
+                12553751213005556626,
+                17358303876907167914,
+                13505463990306589178,
+                618625366376126587,
+            ]),
+            Fq::from_montgomery_limbs([
+                15912342761000853562,
+                12011887339028340554,
+                17835446329217179909,
+                922048431305189980,
+            ]),
+            Fq::from_montgomery_limbs([
+                16012199972247930025,
+                11076481442995633960,
+                11014914441124307414,
+                727553613226256798,
+            ]),
+            Fq::from_montgomery_limbs([
+                12854257418504866070,
+                2896959672971747818,
+                18426067575792003539,
+                222466110875134325,
+            ]),
+            Fq::from_montgomery_limbs([
+                5894664963625733327,
+                10925122624648800433,
+                17919621056730420647,
+                1046317349113781882,
+            ]),
+            Fq::from_montgomery_limbs([
+                1046558596496652298,
+                6263507080597444093,
+                8816841839571874573,
+                936634633500104494,
+            ]),
+            Fq::from_montgomery_limbs([
+                14006807051552763910,
+                15427736927095377426,
+                15949257087649948408,
+                1316940642349780187,
+            ]),
+            Fq::from_montgomery_limbs([
+                17955168292313528867,
+                6496939313532666055,
+                34301173898902058,
+                1106490331572996797,
+            ]),
+            Fq::from_montgomery_limbs([
+                13324250713367540980,
+                11015193126372900032,
+                16678934508611819902,
+                784562057469476777,
+            ]),
+            Fq::from_montgomery_limbs([
+                4469888155398988196,
+                5783824336651734831,
+                10161444385562625042,
+                440654708219867093,
+            ]),
+            Fq::from_montgomery_limbs([
+                1136531801742926974,
+                16970245112421583023,
+                4267344791707191542,
+                634678009300590277,
+            ]),
+            Fq::from_montgomery_limbs([
+                13969244107274754473,
+                12090520750481944884,
+                15630309850174869515,
+                119772122185418855,
+            ]),
+            Fq::from_montgomery_limbs([
+                10970749766870219037,
+                5351918746146299978,
+                14675859923796743439,
+                1263336182667251159,
+            ]),
+            Fq::from_montgomery_limbs([
+                7036763010135900425,
+                17695480884665780903,
+                5513936761592528181,
+                461150083580486660,
+            ]),
+            Fq::from_montgomery_limbs([
+                4917188153419142271,
+                12919403356295425854,
+                8870976109606553461,
+                263353106782069325,
+            ]),
+            Fq::from_montgomery_limbs([
+                18436788830946816833,
+                4649942327435137529,
+                8516777685208053871,
+                1080289072485411440,
+            ]),
+            Fq::from_montgomery_limbs([
+                5833212503119793957,
+                6444294675785583240,
+                3894200202126405162,
+                835753330140964608,
+            ]),
+            Fq::from_montgomery_limbs([
+                17394351553554188408,
+                2867435583704511519,
+                4389561836044725753,
+                1085718602027266837,
+            ]),
+            Fq::from_montgomery_limbs([
+                1972826138446619628,
+                5959864440527739660,
+                13310535240022549755,
+                340126954381515361,
+            ]),
+            Fq::from_montgomery_limbs([
+                13530802423024583883,
+                5323929009567760089,
+                13184123964224382590,
+                1252632169102601016,
+            ]),
+            Fq::from_montgomery_limbs([
+                11910477626780874395,
+                2615683991330625317,
+                7949300981378602086,
+                816881600805569280,
+            ]),
+            Fq::from_montgomery_limbs([
+                1798799625520927588,
+                14187557613048605350,
+                15162436860852506111,
+                807984596128720928,
+            ]),
+            Fq::from_montgomery_limbs([
+                17737136769754017644,
+                3514778152634221209,
+                9537086320462404748,
+                544609545746224677,
+            ]),
+            Fq::from_montgomery_limbs([
+                18232459112786080678,
+                16344156736810082059,
+                7256403478947315658,
+                421324640729707452,
+            ]),
+            Fq::from_montgomery_limbs([
+                6987167621697831747,
+                5600893862350655333,
+                2100437401502902305,
+                1136168439828005078,
+            ]),
+            Fq::from_montgomery_limbs([
+                6853791393245837924,
+                6971264554107033344,
+                7460348483086264050,
+                121783353679073389,
+            ]),
+            Fq::from_montgomery_limbs([
+                6668023326966333803,
+                3885223061175110814,
+                7507084669699443095,
+                1288526798737930509,
+            ]),
+            Fq::from_montgomery_limbs([
+                14398510386258779010,
+                5618931896112573351,
+                3702748750906681375,
+                294929317825320767,
+            ]),
+            Fq::from_montgomery_limbs([
+                2646546818116235235,
+                5929640892335010472,
+                9186414585247169318,
+                428164621628262578,
+            ]),
+            Fq::from_montgomery_limbs([
+                3464580880680040705,
+                2152908387013107799,
+                5422519304025678716,
+                105858197419802515,
+            ]),
+            Fq::from_montgomery_limbs([
+                15708197233118800146,
+                8063467222442179879,
+                4267165344736866038,
+                1242865478379287364,
+            ]),
+            Fq::from_montgomery_limbs([
+                15969093863518084319,
+                1734434303725253470,
+                1596943207181402139,
+                1331886848693993340,
+            ]),
+            Fq::from_montgomery_limbs([
+                9467917619293759766,
+                17159442509657235539,
+                14026275900538140170,
+                312615422048909162,
+            ]),
+            Fq::from_montgomery_limbs([
+                1098604017427259577,
+                4873094061706590627,
+                12011062491611230191,
+                307934375135772661,
+            ]),
+            Fq::from_montgomery_limbs([
+                7664386649865838291,
+                7568916163589226978,
+                10994116923985540334,
+                1154211321381004494,
+            ]),
+            Fq::from_montgomery_limbs([
+                13865607023876852528,
+                7816419131068939467,
+                3246057279777775756,
+                959918294248859566,
+            ]),
+            Fq::from_montgomery_limbs([
+                3714424167255199363,
+                7629499589903417465,
+                17220332288936389907,
+                1096585821062533898,
+            ]),
+            Fq::from_montgomery_limbs([
+                14193339795016249982,
+                8231475572085612806,
+                8632723441594167969,
+                4582809243919432,
+            ]),
+            Fq::from_montgomery_limbs([
+                6057246614852479770,
+                8072259176626192225,
+                13461864260612597218,
+                455800937605991960,
+            ]),
+            Fq::from_montgomery_limbs([
+                8647150541367202703,
+                7115517126098970914,
+                6717123907194372608,
+                1096516223134131930,
+            ]),
+            Fq::from_montgomery_limbs([
+                2023630868218670489,
+                503976021080122521,
+                16752845392716096289,
+                205008246828467683,
+            ]),
+            Fq::from_montgomery_limbs([
+                10126747629542331352,
+                15320186624638419658,
+                1271135435419515099,
+                287025102212618035,
+            ]),
+            Fq::from_montgomery_limbs([
+                9207257226601745308,
+                14180812816614781551,
+                3171338431798641356,
+                75540515946316764,
+            ]),
+            Fq::from_montgomery_limbs([
+                14858709344389675762,
+                15241964864734891753,
+                11524428705084187952,
+                1142566012459307116,
+            ]),
+            Fq::from_montgomery_limbs([
+                17380183331047606904,
+                8398680288441465050,
+                7281677035716565695,
+                251759154929530138,
+            ]),
+            Fq::from_montgomery_limbs([
+                5505764039143944878,
+                13277316822703405570,
+                8644345382759083974,
+                806897381537402869,
+            ]),
+            Fq::from_montgomery_limbs([
+                1207060712632594178,
+                17438985866945790609,
+                269926584483713024,
+                624336109516452293,
+            ]),
+            Fq::from_montgomery_limbs([
+                16220705671798420601,
+                10066125743671280244,
+                3893038076421886081,
+                179293042799293738,
+            ]),
+            Fq::from_montgomery_limbs([
+                13874706423396560248,
+                12918236309835401166,
+                11263525063542979768,
+                282846353419247253,
+            ]),
+            Fq::from_montgomery_limbs([
+                13220436165201797984,
+                11644176256594825873,
+                11018483759996934011,
+                883825702249501986,
+            ]),
+            Fq::from_montgomery_limbs([
+                736914022089537110,
+                11662866511328736216,
+                1296218658718531699,
+                15040044446687214,
+            ]),
+            Fq::from_montgomery_limbs([
+                3306937137907958711,
+                8022611685643695092,
+                5429143229966163657,
+                721851677980951416,
+            ]),
+            Fq::from_montgomery_limbs([
+                12315453478565131079,
+                12460671458022369732,
+                17022852484685151744,
+                465142835489373369,
+            ]),
+            Fq::from_montgomery_limbs([
+                14049081389343763133,
+                3677948169688951659,
+                6273470841992080488,
+                111501319368579024,
+            ]),
+            Fq::from_montgomery_limbs([
+                10867129962774019768,
+                11725029840249000789,
+                9503606739258819565,
+                1001927416661660376,
+            ]),
+            Fq::from_montgomery_limbs([
+                504832685995338073,
+                3303532936999434559,
+                8406247474638545201,
+                365859279198253853,
+            ]),
+            Fq::from_montgomery_limbs([
+                12854412298742170087,
+                12977070050560087041,
+                17988036567359275200,
+                1151640485914468962,
+            ]),
+            Fq::from_montgomery_limbs([
+                16560731856097206120,
+                16773273661564610732,
+                13900075966421207564,
+                993775891821068244,
+            ]),
+            Fq::from_montgomery_limbs([
+                8922668117966155641,
+                245095102198001266,
+                5029707133504891186,
+                320284190039936500,
+            ]),
+            Fq::from_montgomery_limbs([
+                11151001467966948278,
+                1160194782949347014,
+                14453405094900852013,
+                285569105846548330,
+            ]),
+            Fq::from_montgomery_limbs([
+                10323443856300546608,
+                18181267538127441555,
+                11103574332956302936,
+                716699361890020512,
+            ]),
+            Fq::from_montgomery_limbs([
+                2473376916729583325,
+                9898721032923312562,
+                11713069655975002900,
+                846743031159258101,
+            ]),
+            Fq::from_montgomery_limbs([
+                3040002269292653771,
+                12587284279609872656,
+                18255444538216836399,
+                222530637210084074,
+            ]),
+            Fq::from_montgomery_limbs([
+                1865951616801014017,
+                17430750409940696029,
+                12237975633996615772,
+                1274752449874258663,
+            ]),
+            Fq::from_montgomery_limbs([
+                17483666665231777754,
+                10605165075621980103,
+                10041754763959371552,
+                1138586864178195326,
+            ]),
+            Fq::from_montgomery_limbs([
+                12143839741107729233,
+                1829643429479933822,
+                8217420545484978120,
+                450339075155128027,
+            ]),
+            Fq::from_montgomery_limbs([
+                7153762888898903066,
+                16072895831293316500,
+                788172770235753163,
+                1050313055240219079,
+            ]),
+            Fq::from_montgomery_limbs([
+                2897679947908513559,
+                8268690391304484100,
+                16457937946140985115,
+                941916630218126747,
+            ]),
+            Fq::from_montgomery_limbs([
+                3148973039319966049,
+                13573198994275356172,
+                7777374157198429050,
+                1218963577858169784,
+            ]),
+            Fq::from_montgomery_limbs([
+                16159445398311177263,
+                9888504435790167388,
+                17097305158041125157,
+                32031831755312623,
+            ]),
+            Fq::from_montgomery_limbs([
+                14325670840916474300,
+                2122630693824890372,
+                10917216978783022666,
+                1050252528433723151,
+            ]),
+            Fq::from_montgomery_limbs([
+                15097943799496871527,
+                186506149711553605,
+                5011955963718628941,
+                1270574195593128722,
+            ]),
+            Fq::from_montgomery_limbs([
+                11708593565990914102,
+                7150614033177014081,
+                17349555212831904950,
+                132565662526350180,
+            ]),
+            Fq::from_montgomery_limbs([
+                6165597142047759547,
+                480945604980148405,
+                3372883320238852988,
+                172555748615726179,
+            ]),
+            Fq::from_montgomery_limbs([
+                10884552381905883393,
+                11480209919516050043,
+                3509300924217169835,
+                789203600007769590,
+            ]),
+            Fq::from_montgomery_limbs([
+                16560768316964127453,
+                9275333434593219070,
+                16976596187371184213,
+                1078072634938015375,
+            ]),
+            Fq::from_montgomery_limbs([
+                6233313667829630443,
+                3329106208153198995,
+                14304333702048596580,
+                481139113371895087,
+            ]),
+            Fq::from_montgomery_limbs([
+                5445211281156642707,
+                5101378093478939602,
+                16279446189157282959,
+                143468753694481517,
+            ]),
+            Fq::from_montgomery_limbs([
+                17213044965545363064,
+                7586348778904667159,
+                11221707402433697716,
+                130078465746097644,
+            ]),
+            Fq::from_montgomery_limbs([
+                11322421457620538848,
+                10725895497259423382,
+                11698965780974190718,
+                840237328903195308,
+            ]),
+            Fq::from_montgomery_limbs([
+                5301075308566214323,
+                7213931717248417805,
+                11378969512370070317,
+                148220822717525887,
+            ]),
+            Fq::from_montgomery_limbs([
+                2474581405230981792,
+                5319379671620146557,
+                16472828536749432027,
+                992886427343855925,
+            ]),
+            Fq::from_montgomery_limbs([
+                4206220916072586032,
+                5551743968023498330,
+                17702879089253650410,
+                1227097319556301711,
+            ]),
+            Fq::from_montgomery_limbs([
+                17297421772381504166,
+                5712211545076798084,
+                6880154935833373884,
+                830438550655204616,
+            ]),
+            Fq::from_montgomery_limbs([
+                16656847744587521689,
+                4039691750581181440,
+                8207367377598443581,
+                601277475403627390,
+            ]),
+            Fq::from_montgomery_limbs([
+                15911067231877078659,
+                14118848579823365965,
+                18093128339476259000,
+                46914712823429800,
+            ]),
+            Fq::from_montgomery_limbs([
+                13435638383167566170,
+                1061487002509671624,
+                10839283778440433613,
+                227140537130851488,
+            ]),
+            Fq::from_montgomery_limbs([
+                11128509054237142857,
+                15530848866549393428,
+                7526092012579365137,
+                90995453894448218,
+            ]),
+            Fq::from_montgomery_limbs([
+                11189018575186519716,
+                4522433541260082787,
+                15221707329251400903,
+                1181333098523495259,
+            ]),
+            Fq::from_montgomery_limbs([
+                15331716973820008193,
+                4254071327645189529,
+                9365719880207854283,
+                314513792523314556,
+            ]),
+            Fq::from_montgomery_limbs([
+                277343617296982566,
+                4984791850595310388,
+                660244346778796854,
+                446413156716264944,
+            ]),
+            Fq::from_montgomery_limbs([
+                1400445372444493994,
+                7620289742838461319,
+                1532115290805924207,
+                826861076333396355,
+            ]),
+            Fq::from_montgomery_limbs([
+                15419578302888625606,
+                120123395249464222,
+                11828949280857398422,
+                981714237953249704,
+            ]),
+            Fq::from_montgomery_limbs([
+                7124775523214534038,
+                17861920671827559884,
+                15658611370856290026,
+                255205819484817294,
+            ]),
+            Fq::from_montgomery_limbs([
+                8774559036451549404,
+                8781141314418160004,
+                9193532927437186891,
+                82327940795193101,
+            ]),
+            Fq::from_montgomery_limbs([
+                9439783978109764831,
+                8218660223973429544,
+                8689457005119516701,
+                647127556352455213,
+            ]),
+            Fq::from_montgomery_limbs([
+                14427977173644144496,
+                3049239185903980947,
+                2640215100631186982,
+                279282959355462923,
+            ]),
+            Fq::from_montgomery_limbs([
+                16338419495463180053,
+                7385693388873588031,
+                8286306053773396121,
+                126528102702900686,
+            ]),
+            Fq::from_montgomery_limbs([
+                3304015005411805916,
+                13839715069618569269,
+                17104685862808217233,
+                1050526621341149808,
+            ]),
+            Fq::from_montgomery_limbs([
+                3889813112798901771,
+                11775682225288404483,
+                4098094413231562729,
+                204161140615757875,
+            ]),
+            Fq::from_montgomery_limbs([
+                176550562579139717,
+                6940993736879475195,
+                17974298251378620679,
+                957611251173905859,
+            ]),
+            Fq::from_montgomery_limbs([
+                8243867914806588270,
+                18353531917044913405,
+                12172963936744344692,
+                457864550236002525,
+            ]),
+            Fq::from_montgomery_limbs([
+                10395019267997036309,
+                2199897264712811188,
+                6745104012605541874,
+                266803915690742150,
+            ]),
+            Fq::from_montgomery_limbs([
+                13749671937598196578,
+                10750971219310342757,
+                2270931199922585578,
+                750860905363781176,
+            ]),
+            Fq::from_montgomery_limbs([
+                5957786289504668720,
+                1782453772507500795,
+                12691058384105298575,
+                865413254783282226,
+            ]),
+            Fq::from_montgomery_limbs([
+                3430258251138819352,
+                9155021000291281975,
+                3824422363238705113,
+                771441883695423415,
+            ]),
+            Fq::from_montgomery_limbs([
+                8463463528300034996,
+                13592877045765608676,
+                14825998820037406332,
+                699510812613164251,
+            ]),
+            Fq::from_montgomery_limbs([
+                5434782644297722744,
+                1446631603458891503,
+                352147874941373716,
+                630023095579253380,
+            ]),
+            Fq::from_montgomery_limbs([
+                4307370338508405436,
+                4633276210871445064,
+                6843001677815803950,
+                956744391510269632,
+            ]),
+            Fq::from_montgomery_limbs([
+                12400241763282769025,
+                5154740485849302372,
+                17252362200162886253,
+                1298298285172531442,
+            ]),
+            Fq::from_montgomery_limbs([
+                11368845856243597580,
+                7342900439376709478,
+                16495521060654266789,
+                565525882799130532,
+            ]),
+            Fq::from_montgomery_limbs([
+                13492496186420531640,
+                14410475665888088454,
+                8357624046105095992,
+                36391156503208929,
+            ]),
+            Fq::from_montgomery_limbs([
+                1659037759364362750,
+                8769003666864840889,
+                658030342478903151,
+                1301433280736829944,
+            ]),
+            Fq::from_montgomery_limbs([
+                91683582333605224,
+                17616446797203279818,
+                12245436840802385369,
+                1011711537194331259,
+            ]),
+            Fq::from_montgomery_limbs([
+                9173142408350640709,
+                682982419096105746,
+                13205515068746845317,
+                752740617895437315,
+            ]),
+            Fq::from_montgomery_limbs([
+                8823318057786671739,
+                14848417269687866992,
+                321157496002381823,
+                379238423501351177,
+            ]),
+            Fq::from_montgomery_limbs([
+                10324897923656223104,
+                8459081303191042419,
+                11838850554449507822,
+                348291817435653567,
+            ]),
+            Fq::from_montgomery_limbs([
+                6878439796700546538,
+                7941352280324055753,
+                472859603365455164,
+                302822801506103768,
+            ]),
+            Fq::from_montgomery_limbs([
+                5976241904424606817,
+                11810053444844473694,
+                5689779028449016612,
+                2625791438686225,
+            ]),
+            Fq::from_montgomery_limbs([
+                12917700924664381705,
+                7276176642899491683,
+                5666727574813260632,
+                390417216621445514,
+            ]),
+            Fq::from_montgomery_limbs([
+                13814967873439814383,
+                526303985186698558,
+                8900503640804838366,
+                688050162222282301,
+            ]),
+            Fq::from_montgomery_limbs([
+                15064352306124986921,
+                13228081128975440020,
+                8726498701461414748,
+                1100088180540524160,
+            ]),
+            Fq::from_montgomery_limbs([
+                1789209816067976138,
+                1406461753692539595,
+                15810601264775222846,
+                158700739727439190,
+            ]),
+            Fq::from_montgomery_limbs([
+                4450508056136508862,
+                1021990581837586744,
+                9229567951310009302,
+                179974289375464358,
+            ]),
+            Fq::from_montgomery_limbs([
+                15653662779418619401,
+                13295866608677153157,
+                17735499711626156707,
+                324954375479585658,
+            ]),
+            Fq::from_montgomery_limbs([
+                5232349027323553968,
+                1019592218155228755,
+                4727477504845148295,
+                431157195764102814,
+            ]),
+            Fq::from_montgomery_limbs([
+                13924837559494904461,
+                5725577856096270373,
+                986256889094461406,
+                963679699648477182,
+            ]),
+            Fq::from_montgomery_limbs([
+                18087352299469355545,
+                3028474449342326153,
+                16836891429022675672,
+                1308783652480722988,
+            ]),
+            Fq::from_montgomery_limbs([
+                15586605667266686417,
+                8862272567885334680,
+                4455410304813778770,
+                244284594547814282,
+            ]),
+            Fq::from_montgomery_limbs([
+                14502560955392963498,
+                15098339755937056029,
+                17692163729327532131,
+                118664298262376807,
+            ]),
+            Fq::from_montgomery_limbs([
+                4017954938254292519,
+                15815963516276055779,
+                1343588332765181459,
+                943232643475971862,
+            ]),
+            Fq::from_montgomery_limbs([
+                16659827640831544759,
+                7835031318831766594,
+                17031471968119130586,
+                27345765580425211,
+            ]),
+            Fq::from_montgomery_limbs([
+                8804259266721801958,
+                7615117456552991025,
+                11596445472944970138,
+                276458091464693615,
+            ]),
+            Fq::from_montgomery_limbs([
+                16013275158349540104,
+                6354234541926342453,
+                3721746940071196501,
+                802481853002345685,
+            ]),
+            Fq::from_montgomery_limbs([
+                14621956540652106253,
+                16936956768425913948,
+                9711168005070490844,
+                770259087825094513,
+            ]),
+            Fq::from_montgomery_limbs([
+                7735071930118497049,
+                4950028485734728573,
+                14450449170597815062,
+                90651670061608021,
+            ]),
+            Fq::from_montgomery_limbs([
+                5954582606531881571,
+                8940828506212157859,
+                7334701812330283165,
+                223085231997045359,
+            ]),
+            Fq::from_montgomery_limbs([
+                5420867966268468388,
+                10776815478280681696,
+                10743909387757436363,
+                366554284693929942,
+            ]),
+            Fq::from_montgomery_limbs([
+                5458622216665890652,
+                14694553987210420165,
+                1122274271612087518,
+                316006204291448577,
+            ]),
+            Fq::from_montgomery_limbs([
+                35314897763729957,
+                2666529882117094200,
+                9876137199046623214,
+                675769036935171530,
+            ]),
+            Fq::from_montgomery_limbs([
+                2533680139849631153,
+                14123572578307507499,
+                15178098168821798393,
+                730502022144772337,
+            ]),
+            Fq::from_montgomery_limbs([
+                7267889045352407486,
+                12406025298345746513,
+                18125980672776362494,
+                846533633571805222,
+            ]),
+            Fq::from_montgomery_limbs([
+                5649960498118664900,
+                13620732904976276184,
+                5741847318226259842,
+                191578718054708803,
+            ]),
+            Fq::from_montgomery_limbs([
+                15587217163222291854,
+                13253704229300834697,
+                10673077071933776486,
+                636196620260716700,
+            ]),
+            Fq::from_montgomery_limbs([
+                15485929390050442081,
+                1221033015309607503,
+                4433472447311528834,
+                423642625955843620,
+            ]),
+            Fq::from_montgomery_limbs([
+                1895198991976301453,
+                4202325598826148820,
+                8605922667749975217,
+                971369304418566458,
+            ]),
+            Fq::from_montgomery_limbs([
+                3839051467326723890,
+                9048345457307548812,
+                17046384152815783708,
+                1192403728398385231,
+            ]),
+            Fq::from_montgomery_limbs([
+                2337224000853289606,
+                14200515363569258356,
+                1425814972239724896,
+                1099078532968993221,
+            ]),
+            Fq::from_montgomery_limbs([
+                4902146653544354042,
+                13189543842307404455,
+                11547093455861790101,
+                814563008268698364,
+            ]),
+        ]),
+        mds: MdsMatrix::<17, 16, 289, 256>::new_from_known([
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                4803295199375898767,
+                3754249901789907182,
+                10248134097739830010,
+                585421998305823688,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                4803295199375898767,
+                3754249901789907182,
+                10248134097739830010,
+                585421998305823688,
+            ]),
+            Fq::from_montgomery_limbs([
+                6971725754975006348,
+                12411354022823329792,
+                12595780155201759418,
+                908437045561825590,
+            ]),
+            Fq::from_montgomery_limbs([
+                12160849746824021663,
+                1940579672552365232,
+                5173518843609944629,
+                775261558130140407,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                4803295199375898767,
+                3754249901789907182,
+                10248134097739830010,
+                585421998305823688,
+            ]),
+            Fq::from_montgomery_limbs([
+                6971725754975006348,
+                12411354022823329792,
+                12595780155201759418,
+                908437045561825590,
+            ]),
+            Fq::from_montgomery_limbs([
+                14741272997801603163,
+                8464900402126683431,
+                15422399899660498898,
+                380032526733808404,
+            ]),
+            Fq::from_montgomery_limbs([
+                4027916299228711049,
+                6704532292435164092,
+                8170995433901452220,
+                1242688975444799306,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                4803295199375898767,
+                3754249901789907182,
+                10248134097739830010,
+                585421998305823688,
+            ]),
+            Fq::from_montgomery_limbs([
+                6971725754975006348,
+                12411354022823329792,
+                12595780155201759418,
+                908437045561825590,
+            ]),
+            Fq::from_montgomery_limbs([
+                14741272997801603163,
+                8464900402126683431,
+                15422399899660498898,
+                380032526733808404,
+            ]),
+            Fq::from_montgomery_limbs([
+                17031940698379603434,
+                5695668047032466743,
+                13046264909972903847,
+                751958880912339834,
+            ]),
+            Fq::from_montgomery_limbs([
+                290813590050878893,
+                265784628708063694,
+                13739393643636800082,
+                30906427573009594,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                4803295199375898767,
+                3754249901789907182,
+                10248134097739830010,
+                585421998305823688,
+            ]),
+            Fq::from_montgomery_limbs([
+                6971725754975006348,
+                12411354022823329792,
+                12595780155201759418,
+                908437045561825590,
+            ]),
+            Fq::from_montgomery_limbs([
+                14741272997801603163,
+                8464900402126683431,
+                15422399899660498898,
+                380032526733808404,
+            ]),
+            Fq::from_montgomery_limbs([
+                17031940698379603434,
+                5695668047032466743,
+                13046264909972903847,
+                751958880912339834,
+            ]),
+            Fq::from_montgomery_limbs([
+                3702153530650528485,
+                10931788079643135390,
+                1630873882524374909,
+                707336769176158702,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                4803295199375898767,
+                3754249901789907182,
+                10248134097739830010,
+                585421998305823688,
+            ]),
+            Fq::from_montgomery_limbs([
+                6971725754975006348,
+                12411354022823329792,
+                12595780155201759418,
+                908437045561825590,
+            ]),
+            Fq::from_montgomery_limbs([
+                14741272997801603163,
+                8464900402126683431,
+                15422399899660498898,
+                380032526733808404,
+            ]),
+            Fq::from_montgomery_limbs([
+                17031940698379603434,
+                5695668047032466743,
+                13046264909972903847,
+                751958880912339834,
+            ]),
+            Fq::from_montgomery_limbs([
+                3702153530650528485,
+                10931788079643135390,
+                1630873882524374909,
+                707336769176158702,
+            ]),
+            Fq::from_montgomery_limbs([
+                483667834981143894,
+                4307404968133219670,
+                16943348926633375062,
+                1281160748661064590,
+            ]),
+            Fq::from_montgomery_limbs([
+                15202715113712620389,
+                14394769546364496554,
+                2173784385715807821,
+                762822603853042988,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637893612115930413,
+                12966138270683804852,
+                8536226983215554800,
+                305148877928838565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3556142792549140305,
+                11940255622032845327,
+                5363929692277096097,
+                1257344889050995605,
+            ]),
+            Fq::from_montgomery_limbs([
+                161222611660381298,
+                7584716347280923762,
+                11796697666780975559,
+                811360751089303855,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909246444443958411,
+                1025000188063209970,
+                5827315040396227225,
+                1334816016203717291,
+            ]),
+            Fq::from_montgomery_limbs([
+                1314539991892368115,
+                9856693451882385624,
+                15922220358125724726,
+                1122678703844649283,
+            ]),
+            Fq::from_montgomery_limbs([
+                11779702514766120593,
+                6648357329303933033,
+                2217524307145988359,
+                1266363912808654866,
+            ]),
+            Fq::from_montgomery_limbs([
+                7813998680966850151,
+                11255362100803718349,
+                7870316404486216909,
+                1268336824255642829,
+            ]),
+            Fq::from_montgomery_limbs([
+                2277451795404256931,
+                17752493254162974271,
+                7738310976938064821,
+                1335836928836347155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3084369285869569561,
+                17175978951630526562,
+                2556863950652739974,
+                1272000802657191904,
+            ]),
+            Fq::from_montgomery_limbs([
+                4803295199375898767,
+                3754249901789907182,
+                10248134097739830010,
+                585421998305823688,
+            ]),
+            Fq::from_montgomery_limbs([
+                6971725754975006348,
+                12411354022823329792,
+                12595780155201759418,
+                908437045561825590,
+            ]),
+            Fq::from_montgomery_limbs([
+                14741272997801603163,
+                8464900402126683431,
+                15422399899660498898,
+                380032526733808404,
+            ]),
+            Fq::from_montgomery_limbs([
+                17031940698379603434,
+                5695668047032466743,
+                13046264909972903847,
+                751958880912339834,
+            ]),
+            Fq::from_montgomery_limbs([
+                3702153530650528485,
+                10931788079643135390,
+                1630873882524374909,
+                707336769176158702,
+            ]),
+            Fq::from_montgomery_limbs([
+                483667834981143894,
+                4307404968133219670,
+                16943348926633375062,
+                1281160748661064590,
+            ]),
+            Fq::from_montgomery_limbs([
+                17937024485016592551,
+                15377689228029016439,
+                5120002763406077115,
+                129371851786040633,
+            ]),
+        ]),
+        alpha: Alpha::Exponent(17),
+        rounds: RoundNumbers { r_P: 31, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<16, 256>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14741272997801603163,
+                    8464900402126683431,
+                    15422399899660498898,
+                    380032526733808404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14741272997801603163,
+                    8464900402126683431,
+                    15422399899660498898,
+                    380032526733808404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17031940698379603434,
+                    5695668047032466743,
+                    13046264909972903847,
+                    751958880912339834,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14741272997801603163,
+                    8464900402126683431,
+                    15422399899660498898,
+                    380032526733808404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17031940698379603434,
+                    5695668047032466743,
+                    13046264909972903847,
+                    751958880912339834,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3702153530650528485,
+                    10931788079643135390,
+                    1630873882524374909,
+                    707336769176158702,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14741272997801603163,
+                    8464900402126683431,
+                    15422399899660498898,
+                    380032526733808404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17031940698379603434,
+                    5695668047032466743,
+                    13046264909972903847,
+                    751958880912339834,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3702153530650528485,
+                    10931788079643135390,
+                    1630873882524374909,
+                    707336769176158702,
+                ]),
+                Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    1281160748661064590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14741272997801603163,
+                    8464900402126683431,
+                    15422399899660498898,
+                    380032526733808404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17031940698379603434,
+                    5695668047032466743,
+                    13046264909972903847,
+                    751958880912339834,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3702153530650528485,
+                    10931788079643135390,
+                    1630873882524374909,
+                    707336769176158702,
+                ]),
+                Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    1281160748661064590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17937024485016592551,
+                    15377689228029016439,
+                    5120002763406077115,
+                    129371851786040633,
+                ]),
+            ]),
+            v: Matrix::<1, 16, 16>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+            ]),
+            w: Matrix::<16, 1, 16>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<17, 289>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14741272997801603163,
+                    8464900402126683431,
+                    15422399899660498898,
+                    380032526733808404,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14741272997801603163,
+                    8464900402126683431,
+                    15422399899660498898,
+                    380032526733808404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17031940698379603434,
+                    5695668047032466743,
+                    13046264909972903847,
+                    751958880912339834,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14741272997801603163,
+                    8464900402126683431,
+                    15422399899660498898,
+                    380032526733808404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17031940698379603434,
+                    5695668047032466743,
+                    13046264909972903847,
+                    751958880912339834,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3702153530650528485,
+                    10931788079643135390,
+                    1630873882524374909,
+                    707336769176158702,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14741272997801603163,
+                    8464900402126683431,
+                    15422399899660498898,
+                    380032526733808404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17031940698379603434,
+                    5695668047032466743,
+                    13046264909972903847,
+                    751958880912339834,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3702153530650528485,
+                    10931788079643135390,
+                    1630873882524374909,
+                    707336769176158702,
+                ]),
+                Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    1281160748661064590,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9637893612115930413,
+                    12966138270683804852,
+                    8536226983215554800,
+                    305148877928838565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3556142792549140305,
+                    11940255622032845327,
+                    5363929692277096097,
+                    1257344889050995605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    161222611660381298,
+                    7584716347280923762,
+                    11796697666780975559,
+                    811360751089303855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14909246444443958411,
+                    1025000188063209970,
+                    5827315040396227225,
+                    1334816016203717291,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1314539991892368115,
+                    9856693451882385624,
+                    15922220358125724726,
+                    1122678703844649283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11779702514766120593,
+                    6648357329303933033,
+                    2217524307145988359,
+                    1266363912808654866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7813998680966850151,
+                    11255362100803718349,
+                    7870316404486216909,
+                    1268336824255642829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2277451795404256931,
+                    17752493254162974271,
+                    7738310976938064821,
+                    1335836928836347155,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3084369285869569561,
+                    17175978951630526562,
+                    2556863950652739974,
+                    1272000802657191904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4803295199375898767,
+                    3754249901789907182,
+                    10248134097739830010,
+                    585421998305823688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6971725754975006348,
+                    12411354022823329792,
+                    12595780155201759418,
+                    908437045561825590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14741272997801603163,
+                    8464900402126683431,
+                    15422399899660498898,
+                    380032526733808404,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17031940698379603434,
+                    5695668047032466743,
+                    13046264909972903847,
+                    751958880912339834,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3702153530650528485,
+                    10931788079643135390,
+                    1630873882524374909,
+                    707336769176158702,
+                ]),
+                Fq::from_montgomery_limbs([
+                    483667834981143894,
+                    4307404968133219670,
+                    16943348926633375062,
+                    1281160748661064590,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17937024485016592551,
+                    15377689228029016439,
+                    5120002763406077115,
+                    129371851786040633,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<17, 289>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12160849746824021663,
+                    1940579672552365232,
+                    5173518843609944629,
+                    775261558130140407,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4027916299228711049,
+                    6704532292435164092,
+                    8170995433901452220,
+                    1242688975444799306,
+                ]),
+                Fq::from_montgomery_limbs([
+                    290813590050878893,
+                    265784628708063694,
+                    13739393643636800082,
+                    30906427573009594,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                Fq::from_montgomery_limbs([
+                    15202715113712620389,
+                    14394769546364496554,
+                    2173784385715807821,
+                    762822603853042988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13075701118111638000,
+                    10741433203584197087,
+                    17921229055756635145,
+                    256574013720845599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14054381381872118132,
+                    8603112955308423980,
+                    12739147348695443246,
+                    137047403898055090,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4562309381723307366,
+                    1895458783403419785,
+                    4654864263220378952,
+                    463138252082145316,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1071851773156967290,
+                    6482838276101158884,
+                    77482839816446721,
+                    134634348193217550,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14992751840174826997,
+                    12792661577185486108,
+                    15888904412983263693,
+                    1276352662064716426,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    6153006276771092106,
+                    15769431845143720501,
+                    5579312526305770534,
+                    916160678905880619,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5593209658946438208,
+                    14467738203776034545,
+                    10998040510423545571,
+                    1149835491928142178,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1846163777154618697,
+                    16092807644264104222,
+                    15301828073462509039,
+                    84813325321182053,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    123322753904278450,
+                    9389705752971514391,
+                    7973607612754258311,
+                    637092870183426147,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7030967341323588116,
+                    1897759328280586130,
+                    3321549888248292433,
+                    1053571471475563380,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13042059283265089666,
+                    17087687016009031189,
+                    5761017234523266125,
+                    250036199325094301,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2384831484982093493,
+                    11868299288856100828,
+                    6901408464090110069,
+                    139867023176814101,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1126127459403478010,
+                    16889675225471694813,
+                    7630901738312878329,
+                    775674046624052601,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1693981273286975885,
+                    17741853536418548418,
+                    14383095078774558408,
+                    1124272912469026663,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    3930953962061887819,
+                    16377910513443795213,
+                    17455399696205138392,
+                    851369714056733385,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5270913399222227849,
+                    13537107687428303390,
+                    702553057554171337,
+                    1171999140187714399,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<17, 289>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    4755627766281256905,
+                    8900377416521813974,
+                    6480762139781132663,
+                    267884798460974334,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2536077089528903756,
+                    17991814956610334623,
+                    4315029164686549485,
+                    872461895545042773,
+                ]),
+                Fq::from_montgomery_limbs([
+                    552633322260358936,
+                    5068530776199609237,
+                    16356618343462868324,
+                    832619993930201606,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2819838020607817634,
+                    9468844305071947451,
+                    13209271616361783177,
+                    1449978564808173,
+                ]),
+                Fq::from_montgomery_limbs([
+                    153189107424983116,
+                    2376203490717459708,
+                    17907180542583060477,
+                    1304947032223303417,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14316766425965821195,
+                    4309687580459575261,
+                    17200421981165327099,
+                    1170045388512826633,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13341475258734967627,
+                    113095462444423767,
+                    13523862692663510720,
+                    1012675834393160036,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8370454841083603377,
+                    17352693699567582781,
+                    14570114604400945038,
+                    343488772377783537,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14344435505599311842,
+                    10134679765071023321,
+                    6309545817737601735,
+                    556836343022022830,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10176712300527850461,
+                    2218543084150421349,
+                    967260527076747871,
+                    131788071778138545,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3488672852657420066,
+                    7597057343372202407,
+                    3114762700272090801,
+                    754916805516072900,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16389241024609683355,
+                    1795374715652178728,
+                    6289419663504579141,
+                    1082574643299589026,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3377084459913973208,
+                    3859381075680443611,
+                    6043626741174635551,
+                    146658351834202423,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8260952937542930798,
+                    15597362614389154886,
+                    13423559820836351313,
+                    662087953457434652,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18319869636121772433,
+                    4755020004383803461,
+                    13660186433956242585,
+                    900798460853097090,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11673822733720659479,
+                    4217881960883102911,
+                    10221283795684980911,
+                    896064604379919300,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6133504343659800292,
+                    4167602844206793941,
+                    15439844382101665198,
+                    342338364186191120,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2536077089528903756,
+                    17991814956610334623,
+                    4315029164686549485,
+                    872461895545042773,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12804740806496200253,
+                    9860066887696607710,
+                    12586337567925646748,
+                    1235082795006903230,
+                ]),
+                Fq::from_montgomery_limbs([
+                    818929465483942816,
+                    6863415633187708923,
+                    9173995519479556350,
+                    517735301734800399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8398109534411233122,
+                    18434484227593180259,
+                    5699903650998436720,
+                    596419877400214419,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13797822893265864833,
+                    1096056408180853447,
+                    11510050790844322665,
+                    620509079159586038,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5614571201951239348,
+                    14985968052792545821,
+                    8638099358476625122,
+                    450516361462502465,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7469254553507980742,
+                    12014789829163647723,
+                    12264726527101167220,
+                    334483061333804835,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8945179191394421779,
+                    14207943191228436403,
+                    16325096732886451858,
+                    1232680959782888688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16964325922496845658,
+                    16438843763082657406,
+                    4857516044351348285,
+                    1286566828745056367,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16793821511716271617,
+                    10802201271430972513,
+                    13492897681022565445,
+                    473068074563426287,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16296305605454008752,
+                    12849689617850069410,
+                    12860542957720990097,
+                    1304836779516349793,
+                ]),
+                Fq::from_montgomery_limbs([
+                    562371737166972896,
+                    8004612963745283424,
+                    6700339070823314838,
+                    694464566553925986,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2582143199583836172,
+                    6316751372684508961,
+                    14295924210243407184,
+                    127006934981006152,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7550548262282513287,
+                    16636813572811885615,
+                    649430676823897513,
+                    23005806979637123,
+                ]),
+                Fq::from_montgomery_limbs([
+                    721900293417635718,
+                    6724380434008063900,
+                    18015991367760036237,
+                    326135498005631552,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1157508668003259537,
+                    15756773702099567618,
+                    1607288029037957916,
+                    282667288458003264,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1497276927952227303,
+                    13672100315531860700,
+                    14222146137590557416,
+                    1316628216682663020,
+                ]),
+                Fq::from_montgomery_limbs([
+                    552633322260358936,
+                    5068530776199609237,
+                    16356618343462868324,
+                    832619993930201606,
+                ]),
+                Fq::from_montgomery_limbs([
+                    818929465483942816,
+                    6863415633187708923,
+                    9173995519479556350,
+                    517735301734800399,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9537318615844405336,
+                    16614567306062349273,
+                    16373851265143566679,
+                    1179865337965295596,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3361497930555160478,
+                    10955455269703568996,
+                    6880739528862202540,
+                    533610277755699552,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14742838920567065292,
+                    13264862147345707282,
+                    2213452745163677696,
+                    1297473051379868952,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5162810370659279598,
+                    10677997003408965624,
+                    9595428313851307937,
+                    673020688125632940,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9012907703032442672,
+                    5211324790184662820,
+                    3740898475222115748,
+                    698358624079425894,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15706752014694219664,
+                    1568612476828019682,
+                    7418848640441870480,
+                    604452111971453256,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5198372789643437151,
+                    14987191703596577993,
+                    4408540375366423507,
+                    899264390473980286,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7317410571602162839,
+                    5318923594803059300,
+                    1177443521012102004,
+                    157962078060695658,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3422397293121922347,
+                    4696924019402366695,
+                    13828950689531277089,
+                    631316329519961026,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6155298896912641853,
+                    4639966508409220361,
+                    1519854877084233314,
+                    1121697242078588729,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11918583319458330357,
+                    11869990230670664712,
+                    13057602858239293765,
+                    160389762154116486,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8642891609927166118,
+                    2141911864828968325,
+                    2749023155030106685,
+                    428199939670379917,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5857926241251319138,
+                    3180956969895031995,
+                    11766158372645210808,
+                    1339829344985209841,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2057822181159914488,
+                    12939517955527758243,
+                    7543321979998040415,
+                    1271322847758383367,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17220302292904892273,
+                    9757679834235162166,
+                    6413955992722141917,
+                    1302759095536845948,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2819838020607817634,
+                    9468844305071947451,
+                    13209271616361783177,
+                    1449978564808173,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8398109534411233122,
+                    18434484227593180259,
+                    5699903650998436720,
+                    596419877400214419,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3361497930555160478,
+                    10955455269703568996,
+                    6880739528862202540,
+                    533610277755699552,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5274669052001640700,
+                    12251657763952127035,
+                    8527307845671468145,
+                    1237437404714971081,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1136303146581889110,
+                    17642221491626775227,
+                    4281655682036612263,
+                    1325559117241224738,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6239843246088574301,
+                    17850079437464800565,
+                    3921522333788535336,
+                    129882084646630957,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17847416598687130942,
+                    15126141182744516281,
+                    12853004362484392000,
+                    434918717159386632,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9365204943544102121,
+                    7078074809993797261,
+                    16441650909828625270,
+                    163637281778252061,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3054292690659620276,
+                    13702352251464072692,
+                    10067724631216336095,
+                    970703893009070959,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17359394527663458537,
+                    17548606293089585608,
+                    210054266808588936,
+                    1227254402683322250,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4082857604820916540,
+                    12962634370438946375,
+                    9016639859713765345,
+                    608105448437654391,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1359833079303620932,
+                    12379825296445263856,
+                    17795295249162472398,
+                    1154882657256083471,
+                ]),
+                Fq::from_montgomery_limbs([
+                    40129042571487985,
+                    4996787577711555741,
+                    659331326935156994,
+                    1228190517484627062,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4242019961143081353,
+                    2406250667014226974,
+                    3912482843918302301,
+                    1020933484891663138,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1769538931061394278,
+                    57746777862474228,
+                    12523637376728094584,
+                    71770667098278698,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14785136669959608462,
+                    13034104233771384517,
+                    9188077034878154911,
+                    663577888969315916,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6831097842558124480,
+                    12181820976771410948,
+                    6735489512305962822,
+                    1120778889231087706,
+                ]),
+                Fq::from_montgomery_limbs([
+                    153189107424983116,
+                    2376203490717459708,
+                    17907180542583060477,
+                    1304947032223303417,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13797822893265864833,
+                    1096056408180853447,
+                    11510050790844322665,
+                    620509079159586038,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14742838920567065292,
+                    13264862147345707282,
+                    2213452745163677696,
+                    1297473051379868952,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1136303146581889110,
+                    17642221491626775227,
+                    4281655682036612263,
+                    1325559117241224738,
+                ]),
+                Fq::from_montgomery_limbs([
+                    715062528492070487,
+                    4405869815727472363,
+                    15207083363651047818,
+                    226834626033594231,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5907190924456484479,
+                    772040166155023268,
+                    5536523153508228420,
+                    131959441400778571,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15064990060061181868,
+                    16665241226943066759,
+                    11610306726691898245,
+                    359306405455588829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15916491481660367937,
+                    1418802039490197564,
+                    7091019296632087505,
+                    427178549460913271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9816841969153168148,
+                    3757577102123487001,
+                    17688160381864511769,
+                    1332521078940915461,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11875051019045109411,
+                    8323964059892355155,
+                    12131835210835762152,
+                    887768817144754621,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9347252442424484903,
+                    12986516083966803067,
+                    9690012453064160188,
+                    606040488347698344,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3186656711948502126,
+                    14520967249645443166,
+                    14463073510201233723,
+                    1309207861803940217,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11766535209475588850,
+                    16328687237527385599,
+                    297146670892309153,
+                    372847179973702948,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10562591965562936966,
+                    13798901096568685384,
+                    1083140723193145402,
+                    1042086720832481292,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10697996105824218478,
+                    17536741230206412371,
+                    2206492485905581004,
+                    1200909971512596736,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13602477140718103931,
+                    14782909734881659326,
+                    3766090633242339860,
+                    1133168269682523302,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8373073211779504380,
+                    14724758017222080543,
+                    12532471375553665091,
+                    977013573851907948,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14316766425965821195,
+                    4309687580459575261,
+                    17200421981165327099,
+                    1170045388512826633,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5614571201951239348,
+                    14985968052792545821,
+                    8638099358476625122,
+                    450516361462502465,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5162810370659279598,
+                    10677997003408965624,
+                    9595428313851307937,
+                    673020688125632940,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6239843246088574301,
+                    17850079437464800565,
+                    3921522333788535336,
+                    129882084646630957,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5907190924456484479,
+                    772040166155023268,
+                    5536523153508228420,
+                    131959441400778571,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4934523603454766587,
+                    1673282686765451126,
+                    8918481666186623952,
+                    944323764208881828,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6795640872923321175,
+                    10770051739859543785,
+                    17956998069890716487,
+                    752089225940429091,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4275981297604816880,
+                    16274240525038255423,
+                    7404930276321231047,
+                    1326135492958558571,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12903673196539194125,
+                    18413255086760884716,
+                    981915006197335439,
+                    865857821941157065,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2065518575521233394,
+                    4280340411390531283,
+                    15466620680007353065,
+                    744918278344907735,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10413787538143240813,
+                    12185655994097792777,
+                    15570230032722509254,
+                    746237996494636790,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17258119229818850504,
+                    4556282976256406668,
+                    2151869381281733410,
+                    552582797168521073,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8766539795120407564,
+                    12467795442065213043,
+                    5891365331536674909,
+                    11084927912057790,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16089706140350344972,
+                    1362583621113901689,
+                    12474635942403512821,
+                    536730817852254357,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17655866635563195558,
+                    5299133779286489763,
+                    6805229358668107770,
+                    1207466294602734084,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12113141941950496373,
+                    7925623743919980963,
+                    12394868274146021414,
+                    1323233705834561075,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8531675457258525365,
+                    12512382292497056316,
+                    16012384659454410820,
+                    135088920465853451,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13341475258734967627,
+                    113095462444423767,
+                    13523862692663510720,
+                    1012675834393160036,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7469254553507980742,
+                    12014789829163647723,
+                    12264726527101167220,
+                    334483061333804835,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9012907703032442672,
+                    5211324790184662820,
+                    3740898475222115748,
+                    698358624079425894,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17847416598687130942,
+                    15126141182744516281,
+                    12853004362484392000,
+                    434918717159386632,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15064990060061181868,
+                    16665241226943066759,
+                    11610306726691898245,
+                    359306405455588829,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6795640872923321175,
+                    10770051739859543785,
+                    17956998069890716487,
+                    752089225940429091,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3326365168639370349,
+                    4063408824365651291,
+                    4362692029749642398,
+                    887224358192865190,
+                ]),
+                Fq::from_montgomery_limbs([
+                    158033792939115949,
+                    8255039313971576865,
+                    10106100990659460496,
+                    228306870536561589,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8887642880676504567,
+                    4805560830648233461,
+                    15026412106977873475,
+                    1290880537077200822,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5988037022254847762,
+                    5138009707970973989,
+                    8363375250673410584,
+                    240132070439787800,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18382104068375342266,
+                    9735446821294546977,
+                    11263099078382592307,
+                    338780388787188425,
+                ]),
+                Fq::from_montgomery_limbs([
+                    639014354628344331,
+                    4029031899557332843,
+                    2395382075368604267,
+                    789218786109388600,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7744710431010600908,
+                    9023004459815930084,
+                    13444495386795497352,
+                    656861775374421009,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6371064541352539994,
+                    2671427808645396043,
+                    9924977867819340058,
+                    583582557813462336,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16497229730467424039,
+                    17236738455475091646,
+                    17233831755903629832,
+                    872097604060931905,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10468293158218312934,
+                    15418397316739613719,
+                    12544333390556312459,
+                    1238035886351489577,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7406643314208610841,
+                    18027487159436242497,
+                    7366789550771854460,
+                    693285394098275234,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8370454841083603377,
+                    17352693699567582781,
+                    14570114604400945038,
+                    343488772377783537,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8945179191394421779,
+                    14207943191228436403,
+                    16325096732886451858,
+                    1232680959782888688,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15706752014694219664,
+                    1568612476828019682,
+                    7418848640441870480,
+                    604452111971453256,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9365204943544102121,
+                    7078074809993797261,
+                    16441650909828625270,
+                    163637281778252061,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15916491481660367937,
+                    1418802039490197564,
+                    7091019296632087505,
+                    427178549460913271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4275981297604816880,
+                    16274240525038255423,
+                    7404930276321231047,
+                    1326135492958558571,
+                ]),
+                Fq::from_montgomery_limbs([
+                    158033792939115949,
+                    8255039313971576865,
+                    10106100990659460496,
+                    228306870536561589,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10701416578612878315,
+                    18117982186235028912,
+                    10565072327611039303,
+                    1104171922859512798,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11281026157842484640,
+                    8771858752973931998,
+                    10649431266875090072,
+                    1175403614038966135,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12648314426983967073,
+                    781224126097613137,
+                    6358107824003210143,
+                    78039055065904343,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4292479001828030894,
+                    3669494329168214959,
+                    7538644841928452031,
+                    267350358692972929,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1293561511935004418,
+                    2109071199295250684,
+                    5348276561024662750,
+                    136427981951410398,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3796531979386059067,
+                    2500235488488931292,
+                    6885654273712959541,
+                    317566477895769569,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9679038051821310536,
+                    4370945094141376179,
+                    140067055127182496,
+                    1127641855635590289,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5407026914201312937,
+                    16579421824865589950,
+                    15298601451245838930,
+                    1128400490136032895,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6841227607145826074,
+                    3076259073556275066,
+                    12899432499139161727,
+                    895081659719496157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13660971243167908144,
+                    8140375988514523545,
+                    15398865792564462476,
+                    1044596876683336561,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14344435505599311842,
+                    10134679765071023321,
+                    6309545817737601735,
+                    556836343022022830,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16964325922496845658,
+                    16438843763082657406,
+                    4857516044351348285,
+                    1286566828745056367,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5198372789643437151,
+                    14987191703596577993,
+                    4408540375366423507,
+                    899264390473980286,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3054292690659620276,
+                    13702352251464072692,
+                    10067724631216336095,
+                    970703893009070959,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9816841969153168148,
+                    3757577102123487001,
+                    17688160381864511769,
+                    1332521078940915461,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12903673196539194125,
+                    18413255086760884716,
+                    981915006197335439,
+                    865857821941157065,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8887642880676504567,
+                    4805560830648233461,
+                    15026412106977873475,
+                    1290880537077200822,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11281026157842484640,
+                    8771858752973931998,
+                    10649431266875090072,
+                    1175403614038966135,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5977652176280034788,
+                    11837737521963316498,
+                    406235999487208027,
+                    1233825117406368125,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17259254343267863693,
+                    11954606151512392883,
+                    7040838117956547113,
+                    405277704857838588,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3428754235286011795,
+                    8722203106463729489,
+                    14734006780266139530,
+                    531447283949505696,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14306612927021481270,
+                    11869755105290120457,
+                    5758763162111145808,
+                    736529343371396122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5597485253912899560,
+                    9213481224750412425,
+                    14583346677441734018,
+                    935519591963722607,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4354301876814570478,
+                    8787179888138687808,
+                    3161460582993340411,
+                    575980014417844826,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16450685728205781262,
+                    10593788532181403865,
+                    1870889950874080355,
+                    380257653809163616,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12712140007499538336,
+                    17859691885116010098,
+                    11818314232471930001,
+                    770550486324643992,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15036013580636401096,
+                    6882381962789703169,
+                    5816272354345900379,
+                    731941597595090743,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10176712300527850461,
+                    2218543084150421349,
+                    967260527076747871,
+                    131788071778138545,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16793821511716271617,
+                    10802201271430972513,
+                    13492897681022565445,
+                    473068074563426287,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7317410571602162839,
+                    5318923594803059300,
+                    1177443521012102004,
+                    157962078060695658,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17359394527663458537,
+                    17548606293089585608,
+                    210054266808588936,
+                    1227254402683322250,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11875051019045109411,
+                    8323964059892355155,
+                    12131835210835762152,
+                    887768817144754621,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2065518575521233394,
+                    4280340411390531283,
+                    15466620680007353065,
+                    744918278344907735,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5988037022254847762,
+                    5138009707970973989,
+                    8363375250673410584,
+                    240132070439787800,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12648314426983967073,
+                    781224126097613137,
+                    6358107824003210143,
+                    78039055065904343,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17259254343267863693,
+                    11954606151512392883,
+                    7040838117956547113,
+                    405277704857838588,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5549108921358257546,
+                    14895428150150991360,
+                    14715991488772762244,
+                    122385178542917708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15802462044568411288,
+                    11528713755051609001,
+                    13724885762386917654,
+                    1022124437553837741,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13317000028112977790,
+                    11922961191888597178,
+                    16247933117139191513,
+                    1079382649065906912,
+                ]),
+                Fq::from_montgomery_limbs([
+                    741255313989679613,
+                    16340908586852099054,
+                    9447200251529230492,
+                    343743490907592800,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2541306609625426804,
+                    1782029082157976053,
+                    4548087991516522459,
+                    1180074982980293091,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4581050573280774948,
+                    9018855210719107198,
+                    7218655369031837552,
+                    910009899362590432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2888943620030627395,
+                    9738058810699317455,
+                    15174784853048132538,
+                    533274804657018284,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7797444114019995658,
+                    1212844612148232354,
+                    9105572401321819910,
+                    523412153741619378,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3488672852657420066,
+                    7597057343372202407,
+                    3114762700272090801,
+                    754916805516072900,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16296305605454008752,
+                    12849689617850069410,
+                    12860542957720990097,
+                    1304836779516349793,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3422397293121922347,
+                    4696924019402366695,
+                    13828950689531277089,
+                    631316329519961026,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4082857604820916540,
+                    12962634370438946375,
+                    9016639859713765345,
+                    608105448437654391,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9347252442424484903,
+                    12986516083966803067,
+                    9690012453064160188,
+                    606040488347698344,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10413787538143240813,
+                    12185655994097792777,
+                    15570230032722509254,
+                    746237996494636790,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18382104068375342266,
+                    9735446821294546977,
+                    11263099078382592307,
+                    338780388787188425,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4292479001828030894,
+                    3669494329168214959,
+                    7538644841928452031,
+                    267350358692972929,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3428754235286011795,
+                    8722203106463729489,
+                    14734006780266139530,
+                    531447283949505696,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15802462044568411288,
+                    11528713755051609001,
+                    13724885762386917654,
+                    1022124437553837741,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14493285722168359672,
+                    17791890455680264385,
+                    12317656457786502044,
+                    1184596975742397367,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17339550439897916843,
+                    12388803764810779618,
+                    10352850980880727767,
+                    178406691844536371,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1940855773599126302,
+                    3553684460105533323,
+                    4989651020879498282,
+                    639390787067730966,
+                ]),
+                Fq::from_montgomery_limbs([
+                    861094974984741191,
+                    10390894090236928155,
+                    2852638039961024711,
+                    410128443002178724,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7835023149085070419,
+                    6207262208748598782,
+                    5969691442268225700,
+                    1210952587962721133,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3601496357577917613,
+                    6066506949083542699,
+                    4730508050668841707,
+                    639990726635470917,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2581026597022117720,
+                    1825275050591063566,
+                    7519369876798084742,
+                    546455801351392501,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16389241024609683355,
+                    1795374715652178728,
+                    6289419663504579141,
+                    1082574643299589026,
+                ]),
+                Fq::from_montgomery_limbs([
+                    562371737166972896,
+                    8004612963745283424,
+                    6700339070823314838,
+                    694464566553925986,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6155298896912641853,
+                    4639966508409220361,
+                    1519854877084233314,
+                    1121697242078588729,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1359833079303620932,
+                    12379825296445263856,
+                    17795295249162472398,
+                    1154882657256083471,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3186656711948502126,
+                    14520967249645443166,
+                    14463073510201233723,
+                    1309207861803940217,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17258119229818850504,
+                    4556282976256406668,
+                    2151869381281733410,
+                    552582797168521073,
+                ]),
+                Fq::from_montgomery_limbs([
+                    639014354628344331,
+                    4029031899557332843,
+                    2395382075368604267,
+                    789218786109388600,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1293561511935004418,
+                    2109071199295250684,
+                    5348276561024662750,
+                    136427981951410398,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14306612927021481270,
+                    11869755105290120457,
+                    5758763162111145808,
+                    736529343371396122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13317000028112977790,
+                    11922961191888597178,
+                    16247933117139191513,
+                    1079382649065906912,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17339550439897916843,
+                    12388803764810779618,
+                    10352850980880727767,
+                    178406691844536371,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15463222598816681253,
+                    2624394968042553649,
+                    17399316906939150780,
+                    623522167888775335,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1696459759172870819,
+                    185077821039108062,
+                    3272339977561778619,
+                    1149883120746319467,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13145803149996305672,
+                    3980853729359382255,
+                    1020670089319902183,
+                    840137831504129222,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5174665158662803602,
+                    2925897814458615377,
+                    10233892487059775347,
+                    700226173351345979,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16097548328171941262,
+                    15141053617503010794,
+                    6248890508091235957,
+                    90917996895302387,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13243291686849773301,
+                    3756571499461258745,
+                    146552737582044334,
+                    598807579476221650,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3377084459913973208,
+                    3859381075680443611,
+                    6043626741174635551,
+                    146658351834202423,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2582143199583836172,
+                    6316751372684508961,
+                    14295924210243407184,
+                    127006934981006152,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11918583319458330357,
+                    11869990230670664712,
+                    13057602858239293765,
+                    160389762154116486,
+                ]),
+                Fq::from_montgomery_limbs([
+                    40129042571487985,
+                    4996787577711555741,
+                    659331326935156994,
+                    1228190517484627062,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11766535209475588850,
+                    16328687237527385599,
+                    297146670892309153,
+                    372847179973702948,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8766539795120407564,
+                    12467795442065213043,
+                    5891365331536674909,
+                    11084927912057790,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7744710431010600908,
+                    9023004459815930084,
+                    13444495386795497352,
+                    656861775374421009,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3796531979386059067,
+                    2500235488488931292,
+                    6885654273712959541,
+                    317566477895769569,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5597485253912899560,
+                    9213481224750412425,
+                    14583346677441734018,
+                    935519591963722607,
+                ]),
+                Fq::from_montgomery_limbs([
+                    741255313989679613,
+                    16340908586852099054,
+                    9447200251529230492,
+                    343743490907592800,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1940855773599126302,
+                    3553684460105533323,
+                    4989651020879498282,
+                    639390787067730966,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1696459759172870819,
+                    185077821039108062,
+                    3272339977561778619,
+                    1149883120746319467,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4045778164136758399,
+                    873752541998702686,
+                    8747034562966719806,
+                    165154102193738673,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1376220820871374899,
+                    17794503181960731627,
+                    15012611244788714453,
+                    638218594222384904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1729627458608829214,
+                    13945186722731880105,
+                    16804238242532018268,
+                    175536427463462921,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3000213848958387601,
+                    8667224241581429322,
+                    6731087840826624564,
+                    577652971834181516,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10354215200362735304,
+                    3791862942804370284,
+                    1069993239844175771,
+                    680944856725183654,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8260952937542930798,
+                    15597362614389154886,
+                    13423559820836351313,
+                    662087953457434652,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7550548262282513287,
+                    16636813572811885615,
+                    649430676823897513,
+                    23005806979637123,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8642891609927166118,
+                    2141911864828968325,
+                    2749023155030106685,
+                    428199939670379917,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4242019961143081353,
+                    2406250667014226974,
+                    3912482843918302301,
+                    1020933484891663138,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10562591965562936966,
+                    13798901096568685384,
+                    1083140723193145402,
+                    1042086720832481292,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16089706140350344972,
+                    1362583621113901689,
+                    12474635942403512821,
+                    536730817852254357,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6371064541352539994,
+                    2671427808645396043,
+                    9924977867819340058,
+                    583582557813462336,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9679038051821310536,
+                    4370945094141376179,
+                    140067055127182496,
+                    1127641855635590289,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4354301876814570478,
+                    8787179888138687808,
+                    3161460582993340411,
+                    575980014417844826,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2541306609625426804,
+                    1782029082157976053,
+                    4548087991516522459,
+                    1180074982980293091,
+                ]),
+                Fq::from_montgomery_limbs([
+                    861094974984741191,
+                    10390894090236928155,
+                    2852638039961024711,
+                    410128443002178724,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13145803149996305672,
+                    3980853729359382255,
+                    1020670089319902183,
+                    840137831504129222,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1376220820871374899,
+                    17794503181960731627,
+                    15012611244788714453,
+                    638218594222384904,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8756084748927633667,
+                    3022907020868099374,
+                    8817047644562243361,
+                    641872075138194705,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9470124757416811469,
+                    16377307130900847844,
+                    5272289936798654916,
+                    1214841155134320823,
+                ]),
+                Fq::from_montgomery_limbs([
+                    373414506051910279,
+                    7733292726299386993,
+                    3491548997339095802,
+                    333575633835997799,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18347760332832177782,
+                    17552587525079667214,
+                    14879223001136383890,
+                    38855500264351800,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18319869636121772433,
+                    4755020004383803461,
+                    13660186433956242585,
+                    900798460853097090,
+                ]),
+                Fq::from_montgomery_limbs([
+                    721900293417635718,
+                    6724380434008063900,
+                    18015991367760036237,
+                    326135498005631552,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5857926241251319138,
+                    3180956969895031995,
+                    11766158372645210808,
+                    1339829344985209841,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1769538931061394278,
+                    57746777862474228,
+                    12523637376728094584,
+                    71770667098278698,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10697996105824218478,
+                    17536741230206412371,
+                    2206492485905581004,
+                    1200909971512596736,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17655866635563195558,
+                    5299133779286489763,
+                    6805229358668107770,
+                    1207466294602734084,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16497229730467424039,
+                    17236738455475091646,
+                    17233831755903629832,
+                    872097604060931905,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5407026914201312937,
+                    16579421824865589950,
+                    15298601451245838930,
+                    1128400490136032895,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16450685728205781262,
+                    10593788532181403865,
+                    1870889950874080355,
+                    380257653809163616,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4581050573280774948,
+                    9018855210719107198,
+                    7218655369031837552,
+                    910009899362590432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7835023149085070419,
+                    6207262208748598782,
+                    5969691442268225700,
+                    1210952587962721133,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5174665158662803602,
+                    2925897814458615377,
+                    10233892487059775347,
+                    700226173351345979,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1729627458608829214,
+                    13945186722731880105,
+                    16804238242532018268,
+                    175536427463462921,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9470124757416811469,
+                    16377307130900847844,
+                    5272289936798654916,
+                    1214841155134320823,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5006452297646274897,
+                    4237682680807359865,
+                    15831445689877046328,
+                    988357107805174433,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12542811456910690027,
+                    14485899844434273708,
+                    10866285210187647772,
+                    802272725542040611,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1433062291835729757,
+                    10782628442333419815,
+                    12773081803159979453,
+                    140979520848932796,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11673822733720659479,
+                    4217881960883102911,
+                    10221283795684980911,
+                    896064604379919300,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1157508668003259537,
+                    15756773702099567618,
+                    1607288029037957916,
+                    282667288458003264,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2057822181159914488,
+                    12939517955527758243,
+                    7543321979998040415,
+                    1271322847758383367,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14785136669959608462,
+                    13034104233771384517,
+                    9188077034878154911,
+                    663577888969315916,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13602477140718103931,
+                    14782909734881659326,
+                    3766090633242339860,
+                    1133168269682523302,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12113141941950496373,
+                    7925623743919980963,
+                    12394868274146021414,
+                    1323233705834561075,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10468293158218312934,
+                    15418397316739613719,
+                    12544333390556312459,
+                    1238035886351489577,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6841227607145826074,
+                    3076259073556275066,
+                    12899432499139161727,
+                    895081659719496157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12712140007499538336,
+                    17859691885116010098,
+                    11818314232471930001,
+                    770550486324643992,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2888943620030627395,
+                    9738058810699317455,
+                    15174784853048132538,
+                    533274804657018284,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3601496357577917613,
+                    6066506949083542699,
+                    4730508050668841707,
+                    639990726635470917,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16097548328171941262,
+                    15141053617503010794,
+                    6248890508091235957,
+                    90917996895302387,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3000213848958387601,
+                    8667224241581429322,
+                    6731087840826624564,
+                    577652971834181516,
+                ]),
+                Fq::from_montgomery_limbs([
+                    373414506051910279,
+                    7733292726299386993,
+                    3491548997339095802,
+                    333575633835997799,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12542811456910690027,
+                    14485899844434273708,
+                    10866285210187647772,
+                    802272725542040611,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9243597559395854453,
+                    993911721547287198,
+                    11448003270471946344,
+                    628441978712173855,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2586423960480393923,
+                    16075536983414711842,
+                    16523975300366121574,
+                    228984683938597310,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6133504343659800292,
+                    4167602844206793941,
+                    15439844382101665198,
+                    342338364186191120,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1497276927952227303,
+                    13672100315531860700,
+                    14222146137590557416,
+                    1316628216682663020,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17220302292904892273,
+                    9757679834235162166,
+                    6413955992722141917,
+                    1302759095536845948,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6831097842558124480,
+                    12181820976771410948,
+                    6735489512305962822,
+                    1120778889231087706,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8373073211779504380,
+                    14724758017222080543,
+                    12532471375553665091,
+                    977013573851907948,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8531675457258525365,
+                    12512382292497056316,
+                    16012384659454410820,
+                    135088920465853451,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7406643314208610841,
+                    18027487159436242497,
+                    7366789550771854460,
+                    693285394098275234,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13660971243167908144,
+                    8140375988514523545,
+                    15398865792564462476,
+                    1044596876683336561,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15036013580636401096,
+                    6882381962789703169,
+                    5816272354345900379,
+                    731941597595090743,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7797444114019995658,
+                    1212844612148232354,
+                    9105572401321819910,
+                    523412153741619378,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2581026597022117720,
+                    1825275050591063566,
+                    7519369876798084742,
+                    546455801351392501,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13243291686849773301,
+                    3756571499461258745,
+                    146552737582044334,
+                    598807579476221650,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10354215200362735304,
+                    3791862942804370284,
+                    1069993239844175771,
+                    680944856725183654,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18347760332832177782,
+                    17552587525079667214,
+                    14879223001136383890,
+                    38855500264351800,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1433062291835729757,
+                    10782628442333419815,
+                    12773081803159979453,
+                    140979520848932796,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2586423960480393923,
+                    16075536983414711842,
+                    16523975300366121574,
+                    228984683938597310,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1690952378497903614,
+                    13568702181714813037,
+                    7328234900924401670,
+                    356892790136805270,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<16, 256>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    5494725212967685012,
+                    14566965814698976631,
+                    5953281325722426436,
+                    1145639459056341320,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6188898000841981994,
+                    6030291722972113052,
+                    3996789473318058513,
+                    868412725599028504,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5324214304508149264,
+                    16218847089352382644,
+                    7784760850107385658,
+                    341290258317045136,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18045592233989922710,
+                    18150738638492464802,
+                    1685109465507298254,
+                    1344728718770877095,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15334845452357164518,
+                    15366671356752110412,
+                    12159406210785513632,
+                    236696078318455311,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14969894273834913873,
+                    17886707103020923751,
+                    8108851797677363342,
+                    297299835096469649,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16146015910040600361,
+                    1805624682799653270,
+                    16089247978616794756,
+                    552480810430920999,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14970602634680232216,
+                    3132658651774856572,
+                    4799229009654886047,
+                    1075180500249327749,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15056887553971062578,
+                    12148466795796449144,
+                    4421453053716804590,
+                    1018057740988503631,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14953640816461000781,
+                    16395275637686801741,
+                    15681273789794681110,
+                    951382739928011137,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6569153147379871138,
+                    1273477831199610280,
+                    300695891194144840,
+                    228693531522117178,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14495413656839069179,
+                    476753430562041427,
+                    11570726512800564362,
+                    822458316280864395,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4716900149802912771,
+                    1779961277806138481,
+                    4890626983728961116,
+                    301996296933341229,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4923458331419888898,
+                    2762758559901315701,
+                    8641519250080912266,
+                    644659345580208994,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11675444205617226207,
+                    15444192243058938736,
+                    2580145364328076778,
+                    757074667218357135,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17090335750256450591,
+                    8001569003441606772,
+                    9025758826401353583,
+                    900611368804385263,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6188898000841981994,
+                    6030291722972113052,
+                    3996789473318058513,
+                    868412725599028504,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7759623757605360468,
+                    14594279885147846763,
+                    11707067034498799829,
+                    87604068602838366,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13070522121966783314,
+                    18299384096965720876,
+                    3288813618830385690,
+                    22586271445491818,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12725416456300236620,
+                    759309683325556798,
+                    148834129637383926,
+                    956584377746804034,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4856480947343589669,
+                    8030165672165470855,
+                    8174755360981786926,
+                    936555963870990242,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5547292973932919552,
+                    12943936223569879634,
+                    14350543684104976372,
+                    610238294418952867,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10505588268482750856,
+                    17589746753444290689,
+                    8490946709887017350,
+                    301774660424703929,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1936686670265488890,
+                    12984976274576795694,
+                    6552616692209096336,
+                    704347280992355691,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3914835434813512528,
+                    11531302860238811499,
+                    5046228520309522100,
+                    1231213530799130670,
+                ]),
+                Fq::from_montgomery_limbs([
+                    948186841455727211,
+                    6544895933681061135,
+                    6413711690035256657,
+                    735277401650865909,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10496727436029764577,
+                    7269676640788816102,
+                    6529149277901894785,
+                    263856111287428316,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8709606150275121141,
+                    3682072525334444460,
+                    10380667751858707298,
+                    854195359202350208,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16996703855355055554,
+                    13045116676073315834,
+                    12027438171736891878,
+                    680371407410734086,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1623263548566246923,
+                    4187854957625061246,
+                    7795239524638107031,
+                    408578196321731848,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17081748576687197551,
+                    7569252396221888788,
+                    6452231111348962712,
+                    381348780940540247,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2123810274182663486,
+                    7445621973539117709,
+                    7690208098653210325,
+                    465566889960413592,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5324214304508149264,
+                    16218847089352382644,
+                    7784760850107385658,
+                    341290258317045136,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13070522121966783314,
+                    18299384096965720876,
+                    3288813618830385690,
+                    22586271445491818,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6252282975298455620,
+                    14742134506974534256,
+                    16400991677969094554,
+                    1225141871518561173,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12506998475254878445,
+                    17345611259870634823,
+                    10885623459715972042,
+                    691295011969719214,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14098463072120054878,
+                    10034113237846857657,
+                    9049296745170174041,
+                    875802583899611097,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2251093494737171767,
+                    5325121706859362514,
+                    11784737386671814249,
+                    367961263894456015,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15290890161335277867,
+                    8631821718711828003,
+                    2763440761742688705,
+                    1091791429945138330,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10990009566264431875,
+                    10407364977924751728,
+                    15506031859678361873,
+                    724615093691274798,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17380097559829909623,
+                    3026359342766265647,
+                    1241201452423799413,
+                    821736572084777897,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18213029521804850877,
+                    14968824186923473554,
+                    796601662140731163,
+                    1080112813226226776,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4956108963286762051,
+                    4466720368084393025,
+                    5398829728195448513,
+                    558122005910425228,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7616351034651503656,
+                    6093784252347399898,
+                    5352861845343101156,
+                    512288910894522712,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1784178919352284422,
+                    18372392849811069407,
+                    8614326952910522574,
+                    1324197823111400231,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7452454032581019912,
+                    10757834169739838141,
+                    14235689158524725483,
+                    360710240017722810,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17047701763408907322,
+                    4620294959774069609,
+                    13437052581506444987,
+                    67677826110157734,
+                ]),
+                Fq::from_montgomery_limbs([
+                    306512119522033101,
+                    16264776672310635204,
+                    10939184009418679375,
+                    350567266081713785,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18045592233989922710,
+                    18150738638492464802,
+                    1685109465507298254,
+                    1344728718770877095,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12725416456300236620,
+                    759309683325556798,
+                    148834129637383926,
+                    956584377746804034,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12506998475254878445,
+                    17345611259870634823,
+                    10885623459715972042,
+                    691295011969719214,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1217635281712735549,
+                    3204711582245475511,
+                    9684289107702474214,
+                    511566020816521810,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6561672253436303512,
+                    8858541009205757611,
+                    16281760254382018299,
+                    296898757352452188,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9477626582680097553,
+                    8432595703383190592,
+                    1447440774743458931,
+                    694671809126625813,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4160235136952644555,
+                    8022489096819258599,
+                    17701865387970995368,
+                    397421133494543347,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4929252906827736352,
+                    9582700275303393886,
+                    2838061609791092741,
+                    163218374072630178,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8726359722176082393,
+                    11165003799522742413,
+                    13894625652600227659,
+                    1255981570644072230,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9184233593876676163,
+                    13310605501360212110,
+                    18059548082356982925,
+                    225446575091529603,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10713919076242348995,
+                    9628099568879785768,
+                    10217590919368257086,
+                    674516665531960996,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2188938810591756429,
+                    10309995442051090120,
+                    9120160224544291243,
+                    1189111427339948728,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1722649633665452710,
+                    12098238892331713258,
+                    1419130991589093771,
+                    794954908812321580,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7968471351603391619,
+                    1837808511751935809,
+                    1723522846286642095,
+                    667453916440210069,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6479483761089762320,
+                    580858038724981544,
+                    4764721649411833575,
+                    701747332421731276,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4755483960613221611,
+                    16088666731126206473,
+                    4176932257537957297,
+                    662895495064261560,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15334845452357164518,
+                    15366671356752110412,
+                    12159406210785513632,
+                    236696078318455311,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4856480947343589669,
+                    8030165672165470855,
+                    8174755360981786926,
+                    936555963870990242,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14098463072120054878,
+                    10034113237846857657,
+                    9049296745170174041,
+                    875802583899611097,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6561672253436303512,
+                    8858541009205757611,
+                    16281760254382018299,
+                    296898757352452188,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6462822043065311840,
+                    6335044456559117368,
+                    2475581371252603002,
+                    40438539242061003,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2299039763790263100,
+                    7555203754291383146,
+                    6333654751317126651,
+                    315773269937562370,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16477349385519813825,
+                    7626556945983340993,
+                    9196325478210766861,
+                    250970996169683044,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4754085361086588129,
+                    9140108472451684351,
+                    9536701767251827276,
+                    919058986579103585,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11726696228616977050,
+                    13905305293830757599,
+                    3172413989549738300,
+                    392571914621051522,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14552730288307747841,
+                    6878623902629228254,
+                    15525373096820942490,
+                    660717033142103731,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6115635877718772005,
+                    6603715370255231300,
+                    7811972758116088429,
+                    1202435210337052294,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9458862627837328222,
+                    12110332647370247898,
+                    7624048917240539100,
+                    785437920957247604,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16380678398300848198,
+                    16964319733506780719,
+                    88378624326088552,
+                    1316608080283430364,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9996374758131714,
+                    1744719719941839578,
+                    967773447545853657,
+                    92372660837508228,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11517666127886569314,
+                    1070493269727102974,
+                    16760089496109986297,
+                    739084210174702207,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6394735398588407230,
+                    17737365311087155528,
+                    5364540053458699172,
+                    140890053504220478,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14969894273834913873,
+                    17886707103020923751,
+                    8108851797677363342,
+                    297299835096469649,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5547292973932919552,
+                    12943936223569879634,
+                    14350543684104976372,
+                    610238294418952867,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2251093494737171767,
+                    5325121706859362514,
+                    11784737386671814249,
+                    367961263894456015,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9477626582680097553,
+                    8432595703383190592,
+                    1447440774743458931,
+                    694671809126625813,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2299039763790263100,
+                    7555203754291383146,
+                    6333654751317126651,
+                    315773269937562370,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13418613661249524928,
+                    5210548671663204364,
+                    11926412897220425782,
+                    986053179254136604,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1844779782194891130,
+                    7677456447329727001,
+                    11988329243723052875,
+                    441426943953401735,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3925213106913510316,
+                    9610637731982857381,
+                    8768308865012121392,
+                    716061151580227344,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17925850236091430151,
+                    14152085196462160069,
+                    4137350492592394014,
+                    341132014116169170,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13008523853314108548,
+                    3454850070905205748,
+                    11393719502841910010,
+                    370677232132989300,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7672903191647418937,
+                    10201618995506752840,
+                    5562983070823200704,
+                    377512814473509736,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13513788840740227696,
+                    13951124898722991102,
+                    17057948062585605670,
+                    296799774128986106,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6854436744191432009,
+                    15855747651855790476,
+                    1207260937038782282,
+                    732761342441746905,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15512334253888931663,
+                    13517789536397112117,
+                    12604138798450463023,
+                    1180099986541466045,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2706173222072818090,
+                    7303952324053435698,
+                    516950899570716393,
+                    100211113304844031,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12830919279778752637,
+                    2045162484721580210,
+                    9437967934804697509,
+                    646006211594478317,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16146015910040600361,
+                    1805624682799653270,
+                    16089247978616794756,
+                    552480810430920999,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10505588268482750856,
+                    17589746753444290689,
+                    8490946709887017350,
+                    301774660424703929,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15290890161335277867,
+                    8631821718711828003,
+                    2763440761742688705,
+                    1091791429945138330,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4160235136952644555,
+                    8022489096819258599,
+                    17701865387970995368,
+                    397421133494543347,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16477349385519813825,
+                    7626556945983340993,
+                    9196325478210766861,
+                    250970996169683044,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1844779782194891130,
+                    7677456447329727001,
+                    11988329243723052875,
+                    441426943953401735,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4824878823011782610,
+                    8029167523900666307,
+                    12115439088933670782,
+                    404560266530630536,
+                ]),
+                Fq::from_montgomery_limbs([
+                    306780671465805686,
+                    4880155197427992635,
+                    10558598215391229489,
+                    1006557917769085771,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9940858699033961754,
+                    10460582801014503891,
+                    8201834692251771377,
+                    285989788922958881,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10612149773396255962,
+                    10369798883883503497,
+                    4631061564475896124,
+                    1216381014108245816,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12620983738376918969,
+                    15214087775915956509,
+                    988212142256466255,
+                    1052619325337399436,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17291538922172476243,
+                    1307612009401386933,
+                    39028290756468461,
+                    1174856957051566957,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5996545808694733961,
+                    11674627254407589815,
+                    13986256951525442360,
+                    964637183285604182,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2619708647370559245,
+                    8218005168427385690,
+                    1296479685833164449,
+                    651305364361079877,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3564242095275723321,
+                    18213716173371591310,
+                    14306200988973594427,
+                    721444610736852081,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11958155251019659866,
+                    6575594743420400584,
+                    570804280065834723,
+                    772800538638613012,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14970602634680232216,
+                    3132658651774856572,
+                    4799229009654886047,
+                    1075180500249327749,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1936686670265488890,
+                    12984976274576795694,
+                    6552616692209096336,
+                    704347280992355691,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10990009566264431875,
+                    10407364977924751728,
+                    15506031859678361873,
+                    724615093691274798,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4929252906827736352,
+                    9582700275303393886,
+                    2838061609791092741,
+                    163218374072630178,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4754085361086588129,
+                    9140108472451684351,
+                    9536701767251827276,
+                    919058986579103585,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3925213106913510316,
+                    9610637731982857381,
+                    8768308865012121392,
+                    716061151580227344,
+                ]),
+                Fq::from_montgomery_limbs([
+                    306780671465805686,
+                    4880155197427992635,
+                    10558598215391229489,
+                    1006557917769085771,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9409517404350454062,
+                    14208139016190854039,
+                    2209890607803458305,
+                    294234682284296136,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6931819558703228673,
+                    4478827288407322165,
+                    18357435085114042209,
+                    512635936192756255,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5672527911606406822,
+                    8210641081105962305,
+                    17459818643825021491,
+                    959839925223168125,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12797950777302123156,
+                    16000298242568109189,
+                    2133728581566809938,
+                    1138067291244355982,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14536604809252320862,
+                    14923499944073233012,
+                    18319451028846756648,
+                    699099028609449825,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5030508449650500314,
+                    12186448941117462338,
+                    1173740337787386156,
+                    991072466412063931,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4462672997151956852,
+                    4399973863113969215,
+                    1268678658979657677,
+                    964536479312553788,
+                ]),
+                Fq::from_montgomery_limbs([
+                    207246944248318757,
+                    3048838069521889835,
+                    10545100627602712342,
+                    384638647086331278,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9113886917718928054,
+                    16785208972345848508,
+                    14578006927374014758,
+                    332067992926529515,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15056887553971062578,
+                    12148466795796449144,
+                    4421453053716804590,
+                    1018057740988503631,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3914835434813512528,
+                    11531302860238811499,
+                    5046228520309522100,
+                    1231213530799130670,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17380097559829909623,
+                    3026359342766265647,
+                    1241201452423799413,
+                    821736572084777897,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8726359722176082393,
+                    11165003799522742413,
+                    13894625652600227659,
+                    1255981570644072230,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11726696228616977050,
+                    13905305293830757599,
+                    3172413989549738300,
+                    392571914621051522,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17925850236091430151,
+                    14152085196462160069,
+                    4137350492592394014,
+                    341132014116169170,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9940858699033961754,
+                    10460582801014503891,
+                    8201834692251771377,
+                    285989788922958881,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6931819558703228673,
+                    4478827288407322165,
+                    18357435085114042209,
+                    512635936192756255,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9074283156283611758,
+                    3307997708972395059,
+                    7726327062036878823,
+                    934072086863775805,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7183751058122744537,
+                    10491364743247671574,
+                    10599033174937483510,
+                    924412069579414791,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9956183759383315270,
+                    3060339326930784422,
+                    1124803467133230544,
+                    778770287133077042,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8363858156226861080,
+                    5467181771463248749,
+                    1633721724866874784,
+                    27826170516925652,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18050703211962231224,
+                    6171211871191050212,
+                    6687555080402968468,
+                    446067321584678643,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10490644423677785720,
+                    16875360370843644305,
+                    13086236220334965176,
+                    1036887749390257240,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5541474015187478732,
+                    2080281639630555288,
+                    15394924546035609209,
+                    776955308789119602,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13593268240902641595,
+                    5180850414296912501,
+                    10073166376720730515,
+                    1339050216722558930,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14953640816461000781,
+                    16395275637686801741,
+                    15681273789794681110,
+                    951382739928011137,
+                ]),
+                Fq::from_montgomery_limbs([
+                    948186841455727211,
+                    6544895933681061135,
+                    6413711690035256657,
+                    735277401650865909,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18213029521804850877,
+                    14968824186923473554,
+                    796601662140731163,
+                    1080112813226226776,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9184233593876676163,
+                    13310605501360212110,
+                    18059548082356982925,
+                    225446575091529603,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14552730288307747841,
+                    6878623902629228254,
+                    15525373096820942490,
+                    660717033142103731,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13008523853314108548,
+                    3454850070905205748,
+                    11393719502841910010,
+                    370677232132989300,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10612149773396255962,
+                    10369798883883503497,
+                    4631061564475896124,
+                    1216381014108245816,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5672527911606406822,
+                    8210641081105962305,
+                    17459818643825021491,
+                    959839925223168125,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7183751058122744537,
+                    10491364743247671574,
+                    10599033174937483510,
+                    924412069579414791,
+                ]),
+                Fq::from_montgomery_limbs([
+                    338390816888002263,
+                    7677042885434525282,
+                    1274440080653424371,
+                    810223741681465843,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16088785519639481799,
+                    8671645796558860925,
+                    4651282303990682911,
+                    114932215128713850,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12006849172899228516,
+                    3570846063426403141,
+                    18129167550021593997,
+                    448485427929138044,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5587310989722541324,
+                    14002536037754631130,
+                    4673209642163930359,
+                    879634665712948394,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14231794730750636849,
+                    14501281982699645965,
+                    11106852158696765005,
+                    949926803685521611,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17224637650706788788,
+                    13078622114217119809,
+                    5096731770631236041,
+                    961251957628545501,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2347388292285598413,
+                    399874464087381483,
+                    1349749649733086392,
+                    289880510991718094,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6569153147379871138,
+                    1273477831199610280,
+                    300695891194144840,
+                    228693531522117178,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10496727436029764577,
+                    7269676640788816102,
+                    6529149277901894785,
+                    263856111287428316,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4956108963286762051,
+                    4466720368084393025,
+                    5398829728195448513,
+                    558122005910425228,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10713919076242348995,
+                    9628099568879785768,
+                    10217590919368257086,
+                    674516665531960996,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6115635877718772005,
+                    6603715370255231300,
+                    7811972758116088429,
+                    1202435210337052294,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7672903191647418937,
+                    10201618995506752840,
+                    5562983070823200704,
+                    377512814473509736,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12620983738376918969,
+                    15214087775915956509,
+                    988212142256466255,
+                    1052619325337399436,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12797950777302123156,
+                    16000298242568109189,
+                    2133728581566809938,
+                    1138067291244355982,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9956183759383315270,
+                    3060339326930784422,
+                    1124803467133230544,
+                    778770287133077042,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16088785519639481799,
+                    8671645796558860925,
+                    4651282303990682911,
+                    114932215128713850,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16431663708685094742,
+                    2252265865700000940,
+                    16020408992322101484,
+                    485745952118312725,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13528599999036514333,
+                    7808521540385331224,
+                    17660076236450204224,
+                    160418825132393343,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11730010005036698646,
+                    8790908091648301826,
+                    15271675064897027298,
+                    437703926323469504,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7493822441329023663,
+                    164220964674756895,
+                    15376140012777841012,
+                    731779649776349369,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5146071829390338953,
+                    2415530875510094479,
+                    18173762085423852253,
+                    1160831598160840427,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2591627154873453566,
+                    12750293993355660238,
+                    10354140685410203193,
+                    242180526632439685,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14495413656839069179,
+                    476753430562041427,
+                    11570726512800564362,
+                    822458316280864395,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8709606150275121141,
+                    3682072525334444460,
+                    10380667751858707298,
+                    854195359202350208,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7616351034651503656,
+                    6093784252347399898,
+                    5352861845343101156,
+                    512288910894522712,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2188938810591756429,
+                    10309995442051090120,
+                    9120160224544291243,
+                    1189111427339948728,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9458862627837328222,
+                    12110332647370247898,
+                    7624048917240539100,
+                    785437920957247604,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13513788840740227696,
+                    13951124898722991102,
+                    17057948062585605670,
+                    296799774128986106,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17291538922172476243,
+                    1307612009401386933,
+                    39028290756468461,
+                    1174856957051566957,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14536604809252320862,
+                    14923499944073233012,
+                    18319451028846756648,
+                    699099028609449825,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8363858156226861080,
+                    5467181771463248749,
+                    1633721724866874784,
+                    27826170516925652,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12006849172899228516,
+                    3570846063426403141,
+                    18129167550021593997,
+                    448485427929138044,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13528599999036514333,
+                    7808521540385331224,
+                    17660076236450204224,
+                    160418825132393343,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16252215971934938445,
+                    5916485378649175537,
+                    10307608441960102720,
+                    1060033329143603104,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17118330262610936298,
+                    4364164514745128617,
+                    9657548075398790469,
+                    967996396917889380,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13279955226602307697,
+                    8965402008165398197,
+                    1743449233204747025,
+                    468261076400578676,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4669697811090288564,
+                    4868926535890836859,
+                    16219906183930193527,
+                    1057220190322027724,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7007264367677970081,
+                    1610278389395488944,
+                    6174438789347270264,
+                    807147491441477505,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4716900149802912771,
+                    1779961277806138481,
+                    4890626983728961116,
+                    301996296933341229,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16996703855355055554,
+                    13045116676073315834,
+                    12027438171736891878,
+                    680371407410734086,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1784178919352284422,
+                    18372392849811069407,
+                    8614326952910522574,
+                    1324197823111400231,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1722649633665452710,
+                    12098238892331713258,
+                    1419130991589093771,
+                    794954908812321580,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16380678398300848198,
+                    16964319733506780719,
+                    88378624326088552,
+                    1316608080283430364,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6854436744191432009,
+                    15855747651855790476,
+                    1207260937038782282,
+                    732761342441746905,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5996545808694733961,
+                    11674627254407589815,
+                    13986256951525442360,
+                    964637183285604182,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5030508449650500314,
+                    12186448941117462338,
+                    1173740337787386156,
+                    991072466412063931,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18050703211962231224,
+                    6171211871191050212,
+                    6687555080402968468,
+                    446067321584678643,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5587310989722541324,
+                    14002536037754631130,
+                    4673209642163930359,
+                    879634665712948394,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11730010005036698646,
+                    8790908091648301826,
+                    15271675064897027298,
+                    437703926323469504,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17118330262610936298,
+                    4364164514745128617,
+                    9657548075398790469,
+                    967996396917889380,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16354311072192320559,
+                    12785497168319526394,
+                    10037895928311464800,
+                    983003505094923284,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15927364704538212093,
+                    1827620802987814676,
+                    2262532500832146016,
+                    510870367296723,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10295113311347704292,
+                    11935451482321959527,
+                    16555202792006230747,
+                    845497764927037298,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6082490729251648688,
+                    11648786568256946088,
+                    9620292300301396164,
+                    269066439623347946,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4923458331419888898,
+                    2762758559901315701,
+                    8641519250080912266,
+                    644659345580208994,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1623263548566246923,
+                    4187854957625061246,
+                    7795239524638107031,
+                    408578196321731848,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7452454032581019912,
+                    10757834169739838141,
+                    14235689158524725483,
+                    360710240017722810,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7968471351603391619,
+                    1837808511751935809,
+                    1723522846286642095,
+                    667453916440210069,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9996374758131714,
+                    1744719719941839578,
+                    967773447545853657,
+                    92372660837508228,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15512334253888931663,
+                    13517789536397112117,
+                    12604138798450463023,
+                    1180099986541466045,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2619708647370559245,
+                    8218005168427385690,
+                    1296479685833164449,
+                    651305364361079877,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4462672997151956852,
+                    4399973863113969215,
+                    1268678658979657677,
+                    964536479312553788,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10490644423677785720,
+                    16875360370843644305,
+                    13086236220334965176,
+                    1036887749390257240,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14231794730750636849,
+                    14501281982699645965,
+                    11106852158696765005,
+                    949926803685521611,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7493822441329023663,
+                    164220964674756895,
+                    15376140012777841012,
+                    731779649776349369,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13279955226602307697,
+                    8965402008165398197,
+                    1743449233204747025,
+                    468261076400578676,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15927364704538212093,
+                    1827620802987814676,
+                    2262532500832146016,
+                    510870367296723,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10737444197536044906,
+                    459097242724382957,
+                    6209764410668747423,
+                    1240287230156544069,
+                ]),
+                Fq::from_montgomery_limbs([
+                    618767333695691392,
+                    14136711098188890569,
+                    15577933192525511944,
+                    1289998849347036043,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8613802684902065486,
+                    14070756786965787213,
+                    15070868236127575905,
+                    475351102602896925,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11675444205617226207,
+                    15444192243058938736,
+                    2580145364328076778,
+                    757074667218357135,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17081748576687197551,
+                    7569252396221888788,
+                    6452231111348962712,
+                    381348780940540247,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17047701763408907322,
+                    4620294959774069609,
+                    13437052581506444987,
+                    67677826110157734,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6479483761089762320,
+                    580858038724981544,
+                    4764721649411833575,
+                    701747332421731276,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11517666127886569314,
+                    1070493269727102974,
+                    16760089496109986297,
+                    739084210174702207,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2706173222072818090,
+                    7303952324053435698,
+                    516950899570716393,
+                    100211113304844031,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3564242095275723321,
+                    18213716173371591310,
+                    14306200988973594427,
+                    721444610736852081,
+                ]),
+                Fq::from_montgomery_limbs([
+                    207246944248318757,
+                    3048838069521889835,
+                    10545100627602712342,
+                    384638647086331278,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5541474015187478732,
+                    2080281639630555288,
+                    15394924546035609209,
+                    776955308789119602,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17224637650706788788,
+                    13078622114217119809,
+                    5096731770631236041,
+                    961251957628545501,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5146071829390338953,
+                    2415530875510094479,
+                    18173762085423852253,
+                    1160831598160840427,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4669697811090288564,
+                    4868926535890836859,
+                    16219906183930193527,
+                    1057220190322027724,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10295113311347704292,
+                    11935451482321959527,
+                    16555202792006230747,
+                    845497764927037298,
+                ]),
+                Fq::from_montgomery_limbs([
+                    618767333695691392,
+                    14136711098188890569,
+                    15577933192525511944,
+                    1289998849347036043,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11628406384038854180,
+                    4753098285538274149,
+                    3126976044520552863,
+                    530896753951174769,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5274925748092066605,
+                    15605226971066225742,
+                    1798641529807357254,
+                    969278590000708524,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17090335750256450591,
+                    8001569003441606772,
+                    9025758826401353583,
+                    900611368804385263,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2123810274182663486,
+                    7445621973539117709,
+                    7690208098653210325,
+                    465566889960413592,
+                ]),
+                Fq::from_montgomery_limbs([
+                    306512119522033101,
+                    16264776672310635204,
+                    10939184009418679375,
+                    350567266081713785,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4755483960613221611,
+                    16088666731126206473,
+                    4176932257537957297,
+                    662895495064261560,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6394735398588407230,
+                    17737365311087155528,
+                    5364540053458699172,
+                    140890053504220478,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12830919279778752637,
+                    2045162484721580210,
+                    9437967934804697509,
+                    646006211594478317,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11958155251019659866,
+                    6575594743420400584,
+                    570804280065834723,
+                    772800538638613012,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9113886917718928054,
+                    16785208972345848508,
+                    14578006927374014758,
+                    332067992926529515,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13593268240902641595,
+                    5180850414296912501,
+                    10073166376720730515,
+                    1339050216722558930,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2347388292285598413,
+                    399874464087381483,
+                    1349749649733086392,
+                    289880510991718094,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2591627154873453566,
+                    12750293993355660238,
+                    10354140685410203193,
+                    242180526632439685,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7007264367677970081,
+                    1610278389395488944,
+                    6174438789347270264,
+                    807147491441477505,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6082490729251648688,
+                    11648786568256946088,
+                    9620292300301396164,
+                    269066439623347946,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8613802684902065486,
+                    14070756786965787213,
+                    15070868236127575905,
+                    475351102602896925,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5274925748092066605,
+                    15605226971066225742,
+                    1798641529807357254,
+                    969278590000708524,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6460217803644866556,
+                    5531794343580189856,
+                    8061226922767879242,
+                    395202210916842833,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            M_i: Matrix::<17, 17, 289>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17257608518213458063,
+                    13456494796950632173,
+                    11470818935815187145,
+                    299955807786231763,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17778257845973834853,
+                    16553335679049885030,
+                    17081794635270150451,
+                    252252423949795461,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12057539276481995801,
+                    7901460644460879470,
+                    8279398237843507092,
+                    116565766848643462,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5482091957300852000,
+                    18382649338801782158,
+                    16485643713391826551,
+                    151781758091027445,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6212879062398106700,
+                    1336966893402354366,
+                    15596821873587867423,
+                    375308360476621868,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4584582009024215098,
+                    9353417851837023069,
+                    574883021077208701,
+                    775960198604463382,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4766530205137474574,
+                    4339669309635627387,
+                    5867069229822540856,
+                    50776162306628711,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7421359571272381660,
+                    9263721211352024472,
+                    10872434830753319020,
+                    1098614224067938402,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17296559907331163631,
+                    8847641097985288427,
+                    14500248003428194572,
+                    1329077885762130546,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10439166034353921928,
+                    6351993136580958880,
+                    14730308669728540376,
+                    1003262779070991555,
+                ]),
+                Fq::from_montgomery_limbs([
+                    234370077417711753,
+                    44854401402179786,
+                    15473482790600606715,
+                    400034390943392865,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16033536936264339136,
+                    3878325383720394874,
+                    10211946420170263985,
+                    494952956462065916,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5025484277096637337,
+                    13159896977427086012,
+                    17059079105658054984,
+                    1092942120023241615,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14740899763809734482,
+                    14854054312174320329,
+                    15611850851347183642,
+                    1057957232391983531,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11401786509720806161,
+                    661572554887414549,
+                    11523936958286613496,
+                    1192802586982866799,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2518290198554342271,
+                    3320019132616029210,
+                    198199903906885576,
+                    938309482048573579,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17778257845973834853,
+                    16553335679049885030,
+                    17081794635270150451,
+                    252252423949795461,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5401556744438993804,
+                    18348841977416254224,
+                    5918150684593340322,
+                    331532723996595508,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1376548818819474875,
+                    17999485423283036876,
+                    11909422071718958843,
+                    873475014036008716,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2158458764776870517,
+                    8238613099391441481,
+                    2826705641903865359,
+                    186221750923772489,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5397530592516787380,
+                    12888571076090230725,
+                    567203698076972092,
+                    1065294816191251448,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10007190660082032707,
+                    3142701586478843092,
+                    7743428211787330962,
+                    671286689575155295,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11254657343746274635,
+                    12469998716394730532,
+                    6613440085744675906,
+                    854686191347109095,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13814879555581395339,
+                    17549900730786850042,
+                    2336519358109800468,
+                    10659106179571071,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16186947349832917083,
+                    4993547712596448389,
+                    7610701708043758118,
+                    966952061577968938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4008653979177934326,
+                    9936002022042695641,
+                    10470394686749175252,
+                    131729197291250675,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6686983573196183471,
+                    7388177812187602952,
+                    13950710921351064301,
+                    1193409050018026438,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13441329297494033477,
+                    17973284199142905638,
+                    9270722533919024679,
+                    780494754995497332,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11096903215257041153,
+                    170853807222651139,
+                    12782809851498911661,
+                    1131049980650766370,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12181970843179931373,
+                    13614626147588359039,
+                    16379209961052766859,
+                    1182351612484912758,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4805155117940022892,
+                    17463181432411754605,
+                    17259550030322195790,
+                    230451743543206981,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12634069990058427951,
+                    6099791115192700097,
+                    1546764348625467872,
+                    1319573662426433724,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12057539276481995801,
+                    7901460644460879470,
+                    8279398237843507092,
+                    116565766848643462,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1376548818819474875,
+                    17999485423283036876,
+                    11909422071718958843,
+                    873475014036008716,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15039419022067876624,
+                    10429879626811842590,
+                    392903313231959238,
+                    265810495403306369,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4851233076091596333,
+                    8601809319837684758,
+                    7562635528039427304,
+                    26598255257283939,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13063691228392800418,
+                    3190772259097437647,
+                    9643407706716954098,
+                    588716973293677689,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6618808634491975474,
+                    5675966790949924407,
+                    16214881904499082616,
+                    839172345749456107,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14418358358465991498,
+                    4442278011115042526,
+                    14976089751977702121,
+                    1119864696151239427,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5897263807613308288,
+                    16485691193954331560,
+                    16964014894432144279,
+                    273284023664727968,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16791086560423961333,
+                    17824734539466154079,
+                    1656107401547354326,
+                    365653591086279290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12173597467829219157,
+                    1664211141561809169,
+                    7596588859382354579,
+                    693547800733532089,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9962308168918887449,
+                    1725059197645838142,
+                    3946139847411931845,
+                    531217006764715051,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7758761987330450627,
+                    7052580537726896802,
+                    7037036768552405596,
+                    495139673818878207,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5201549862947707879,
+                    379350171926995629,
+                    10161036834160448899,
+                    482477303501940806,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15183806998099240467,
+                    6523680492469805062,
+                    318062664825339713,
+                    249901355315966242,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1822462971159582272,
+                    2533740881063399066,
+                    17929828033274266243,
+                    1183042539898250341,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11906944812172853778,
+                    11463695544019989952,
+                    11169962573497962821,
+                    626526609108514369,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5482091957300852000,
+                    18382649338801782158,
+                    16485643713391826551,
+                    151781758091027445,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2158458764776870517,
+                    8238613099391441481,
+                    2826705641903865359,
+                    186221750923772489,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4851233076091596333,
+                    8601809319837684758,
+                    7562635528039427304,
+                    26598255257283939,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10076079763123632010,
+                    8909770122677772022,
+                    8615917371235427404,
+                    539823759043581305,
+                ]),
+                Fq::from_montgomery_limbs([
+                    110933391185614495,
+                    747882536038111240,
+                    6117133930951919008,
+                    506807363783075767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11839740598338082184,
+                    18110261771347704263,
+                    1145147349511341346,
+                    118472529921577236,
+                ]),
+                Fq::from_montgomery_limbs([
+                    372801975680382242,
+                    13679176402173703619,
+                    17329786797735032694,
+                    599253629875888276,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16524319384032182746,
+                    7300754091438325237,
+                    10292504197336712149,
+                    1075321744035135290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16201066321202793174,
+                    14408865834954659815,
+                    11717902877595720377,
+                    1135562000409638135,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15297372374243786680,
+                    11111838969264694865,
+                    17457348277556573375,
+                    717716084360594916,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6577816840739686211,
+                    10982400389143666911,
+                    17588805923421954382,
+                    45169185439473031,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9351070574776788449,
+                    12290856605170650898,
+                    4051224493672722357,
+                    427176784587211566,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15620919751483099670,
+                    5367973730818775270,
+                    48046915659200583,
+                    1303093432184389123,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4498258714106144795,
+                    13865078724717567815,
+                    1493970632859243483,
+                    706959754805486578,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12007931605816926184,
+                    7048736613988307626,
+                    4411917728383510379,
+                    135366077672132025,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9246849312998499977,
+                    14229298033148702975,
+                    6613540824468553796,
+                    380186529098080710,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    6212879062398106700,
+                    1336966893402354366,
+                    15596821873587867423,
+                    375308360476621868,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5397530592516787380,
+                    12888571076090230725,
+                    567203698076972092,
+                    1065294816191251448,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13063691228392800418,
+                    3190772259097437647,
+                    9643407706716954098,
+                    588716973293677689,
+                ]),
+                Fq::from_montgomery_limbs([
+                    110933391185614495,
+                    747882536038111240,
+                    6117133930951919008,
+                    506807363783075767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4508716931360520579,
+                    1527361926973117150,
+                    1237966588052922402,
+                    133775361217999281,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3489074601218658653,
+                    10893414918923449868,
+                    16246281180622811381,
+                    138721060849082535,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16987905897859085100,
+                    3203324875281864272,
+                    3605136323233606357,
+                    205562847998507344,
+                ]),
+                Fq::from_montgomery_limbs([
+                    586516802973673924,
+                    6910050496719043243,
+                    7999455889187264767,
+                    609746574979275139,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14763085403458069782,
+                    7465665678751971109,
+                    7759192845006840893,
+                    195945861796600243,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4687145361283404265,
+                    16138689050021301493,
+                    4336995868454901112,
+                    257970590199569913,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6431049771046043203,
+                    14778717399192387137,
+                    69332018095478644,
+                    1327581532488780304,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16895615905868015808,
+                    12482026920157710223,
+                    9082940201693118723,
+                    1202041049401287922,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5189596957819037101,
+                    5070903866405536030,
+                    18261764868049178442,
+                    285110394740913681,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12605713981913696219,
+                    1339417321584593675,
+                    15852981462858557487,
+                    796569419020048413,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9895875564983832810,
+                    5087308107835199617,
+                    13922243613809274587,
+                    392084605214379776,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3246972717843185720,
+                    12762781952707285972,
+                    8537125056408771178,
+                    590724938747180586,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4584582009024215098,
+                    9353417851837023069,
+                    574883021077208701,
+                    775960198604463382,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10007190660082032707,
+                    3142701586478843092,
+                    7743428211787330962,
+                    671286689575155295,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6618808634491975474,
+                    5675966790949924407,
+                    16214881904499082616,
+                    839172345749456107,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11839740598338082184,
+                    18110261771347704263,
+                    1145147349511341346,
+                    118472529921577236,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3489074601218658653,
+                    10893414918923449868,
+                    16246281180622811381,
+                    138721060849082535,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3997202280282908825,
+                    312730315941925933,
+                    10241710222774482320,
+                    411860914013279819,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5020744953456593031,
+                    6695636911007248424,
+                    13505458138795680982,
+                    1146191306905435313,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4135815686520113078,
+                    1433064332954447537,
+                    14234646585512027352,
+                    1094098255186396751,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16072521426615455331,
+                    14125717530438416998,
+                    13691171190633928474,
+                    133440761216087112,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3927633271004894743,
+                    13034239926070463815,
+                    14036387269635242556,
+                    1041997227556693489,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16588009406156520095,
+                    9096957969748967990,
+                    12098254403848641732,
+                    550444946649572539,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9274847207147281285,
+                    11226141971920585118,
+                    3893733355148490969,
+                    696904714223525361,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18124217697907224985,
+                    8600513057491678489,
+                    5769465088237626579,
+                    89164804913121679,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13601808115342448741,
+                    45060723602098460,
+                    12068953495585384254,
+                    1239260266983403490,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4136434476238237967,
+                    17490188792237386477,
+                    14609791939676256503,
+                    1144130900361710995,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5757991687628640106,
+                    3150358002928490196,
+                    10764719891495926151,
+                    399944411048570507,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4766530205137474574,
+                    4339669309635627387,
+                    5867069229822540856,
+                    50776162306628711,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11254657343746274635,
+                    12469998716394730532,
+                    6613440085744675906,
+                    854686191347109095,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14418358358465991498,
+                    4442278011115042526,
+                    14976089751977702121,
+                    1119864696151239427,
+                ]),
+                Fq::from_montgomery_limbs([
+                    372801975680382242,
+                    13679176402173703619,
+                    17329786797735032694,
+                    599253629875888276,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16987905897859085100,
+                    3203324875281864272,
+                    3605136323233606357,
+                    205562847998507344,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5020744953456593031,
+                    6695636911007248424,
+                    13505458138795680982,
+                    1146191306905435313,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3165745714254593859,
+                    14301587108268859020,
+                    5846992886454006600,
+                    135650894950444758,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7047738204121597249,
+                    10568486545647626872,
+                    14165040991053599727,
+                    128730991638304826,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16433112633546480228,
+                    11477262975722233906,
+                    5831701365684331618,
+                    847435704456266394,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1485033708660057055,
+                    13023283108839146104,
+                    3568284826824339101,
+                    800171919340790767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9161218028908727388,
+                    16277611541974188114,
+                    3804689879344267113,
+                    1000155434894904154,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11627380525269703378,
+                    12678626086481736802,
+                    13069458548242702926,
+                    1044301213601029051,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6245118248396952397,
+                    7618151856483264549,
+                    12556984550400931281,
+                    221538667674150251,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12384175239672121792,
+                    17195919279709833774,
+                    7713687578317482248,
+                    93609982975805614,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11171100179785552067,
+                    1774061622575883677,
+                    2585908944309352358,
+                    466483052096020413,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12792744624599064116,
+                    14154352723070840708,
+                    3778453721339352026,
+                    90813475684744831,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7421359571272381660,
+                    9263721211352024472,
+                    10872434830753319020,
+                    1098614224067938402,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13814879555581395339,
+                    17549900730786850042,
+                    2336519358109800468,
+                    10659106179571071,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5897263807613308288,
+                    16485691193954331560,
+                    16964014894432144279,
+                    273284023664727968,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16524319384032182746,
+                    7300754091438325237,
+                    10292504197336712149,
+                    1075321744035135290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    586516802973673924,
+                    6910050496719043243,
+                    7999455889187264767,
+                    609746574979275139,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4135815686520113078,
+                    1433064332954447537,
+                    14234646585512027352,
+                    1094098255186396751,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7047738204121597249,
+                    10568486545647626872,
+                    14165040991053599727,
+                    128730991638304826,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11832000388500654329,
+                    4067739640546653763,
+                    2406579270423090380,
+                    212470838522706548,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6844868975336143152,
+                    6220798801772635554,
+                    8949463495537869019,
+                    629017951746088507,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1489323916217220102,
+                    16768026382141887064,
+                    18137368096666538186,
+                    328286183690621552,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17579937902245410364,
+                    9017914508209609884,
+                    14167680209446497360,
+                    223580003534681024,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3998087115167671576,
+                    5880802256581702478,
+                    12245255920692473068,
+                    909046735738198118,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12222105290973287705,
+                    8433591102802292829,
+                    15455096430214211066,
+                    435074054272731636,
+                ]),
+                Fq::from_montgomery_limbs([
+                    563045924832737189,
+                    16704036554326046770,
+                    14110787794722698038,
+                    580151427341079897,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13953743768363470711,
+                    1414078491554198144,
+                    711352140578826455,
+                    450992245317070781,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3064578852631112073,
+                    11252687532988225192,
+                    15867388621623451171,
+                    827019770840860521,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17296559907331163631,
+                    8847641097985288427,
+                    14500248003428194572,
+                    1329077885762130546,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16186947349832917083,
+                    4993547712596448389,
+                    7610701708043758118,
+                    966952061577968938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16791086560423961333,
+                    17824734539466154079,
+                    1656107401547354326,
+                    365653591086279290,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16201066321202793174,
+                    14408865834954659815,
+                    11717902877595720377,
+                    1135562000409638135,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14763085403458069782,
+                    7465665678751971109,
+                    7759192845006840893,
+                    195945861796600243,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16072521426615455331,
+                    14125717530438416998,
+                    13691171190633928474,
+                    133440761216087112,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16433112633546480228,
+                    11477262975722233906,
+                    5831701365684331618,
+                    847435704456266394,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6844868975336143152,
+                    6220798801772635554,
+                    8949463495537869019,
+                    629017951746088507,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5777844875284176694,
+                    7978172393064442319,
+                    17068962835400936663,
+                    742804284883305355,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8520868277005161445,
+                    6707974303485379824,
+                    9062462018319145332,
+                    1244566539036802779,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9034742275038416557,
+                    14617014217371438207,
+                    8786936786177648259,
+                    134587359994064963,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9205043132505034180,
+                    7889816674423236377,
+                    16355677080185822351,
+                    422428355066637161,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17891966206509753652,
+                    10416283984730825464,
+                    2011604985167487566,
+                    1101492716075235783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17669598381643440496,
+                    3295436341953217425,
+                    16061012017738219840,
+                    978004528773368644,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8240546327761098749,
+                    14871221017591979001,
+                    9388426423373191226,
+                    1099292190557898362,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11691943689059433495,
+                    8391492059370675112,
+                    3035783531567754365,
+                    1007168205201973312,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10439166034353921928,
+                    6351993136580958880,
+                    14730308669728540376,
+                    1003262779070991555,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4008653979177934326,
+                    9936002022042695641,
+                    10470394686749175252,
+                    131729197291250675,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12173597467829219157,
+                    1664211141561809169,
+                    7596588859382354579,
+                    693547800733532089,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15297372374243786680,
+                    11111838969264694865,
+                    17457348277556573375,
+                    717716084360594916,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4687145361283404265,
+                    16138689050021301493,
+                    4336995868454901112,
+                    257970590199569913,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3927633271004894743,
+                    13034239926070463815,
+                    14036387269635242556,
+                    1041997227556693489,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1485033708660057055,
+                    13023283108839146104,
+                    3568284826824339101,
+                    800171919340790767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1489323916217220102,
+                    16768026382141887064,
+                    18137368096666538186,
+                    328286183690621552,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8520868277005161445,
+                    6707974303485379824,
+                    9062462018319145332,
+                    1244566539036802779,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8564622184340496492,
+                    14041721020083502624,
+                    11687194949370098410,
+                    415305579009749856,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2215483294075548079,
+                    4619448732855623294,
+                    3878243626434873464,
+                    1151745481663212948,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11069149189617834649,
+                    9902365848212197051,
+                    10049179252898936900,
+                    1199094818673695129,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8416636228481688554,
+                    17078427873224351175,
+                    5509371789606845517,
+                    460386776155310518,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17466952672448818477,
+                    7537234592112312148,
+                    5710519156046195663,
+                    1157485173798832965,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14556269315230261862,
+                    2794988985448654339,
+                    8096090112024899854,
+                    1114594664592225286,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11453736344560698140,
+                    8769950189847424208,
+                    2409481884800273483,
+                    1087810976818743451,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    234370077417711753,
+                    44854401402179786,
+                    15473482790600606715,
+                    400034390943392865,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6686983573196183471,
+                    7388177812187602952,
+                    13950710921351064301,
+                    1193409050018026438,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9962308168918887449,
+                    1725059197645838142,
+                    3946139847411931845,
+                    531217006764715051,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6577816840739686211,
+                    10982400389143666911,
+                    17588805923421954382,
+                    45169185439473031,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6431049771046043203,
+                    14778717399192387137,
+                    69332018095478644,
+                    1327581532488780304,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16588009406156520095,
+                    9096957969748967990,
+                    12098254403848641732,
+                    550444946649572539,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9161218028908727388,
+                    16277611541974188114,
+                    3804689879344267113,
+                    1000155434894904154,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17579937902245410364,
+                    9017914508209609884,
+                    14167680209446497360,
+                    223580003534681024,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9034742275038416557,
+                    14617014217371438207,
+                    8786936786177648259,
+                    134587359994064963,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2215483294075548079,
+                    4619448732855623294,
+                    3878243626434873464,
+                    1151745481663212948,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13619688698611470258,
+                    17301722836716188207,
+                    8106129620080954396,
+                    372576532172087060,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3684614169248050127,
+                    15096959514942550988,
+                    17222471546033653031,
+                    1334278536840631767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3703039787090908090,
+                    12701669851636079334,
+                    17501456982004389686,
+                    41070841208607095,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17945853247670030000,
+                    4781655479628035430,
+                    17672308162347701854,
+                    719948551115342921,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9744715739840900375,
+                    15937225944883008373,
+                    10977596168700319058,
+                    1336393135657818035,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8949231817054603228,
+                    1214644624037539460,
+                    14495467048328126639,
+                    547536979430899474,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    16033536936264339136,
+                    3878325383720394874,
+                    10211946420170263985,
+                    494952956462065916,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13441329297494033477,
+                    17973284199142905638,
+                    9270722533919024679,
+                    780494754995497332,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7758761987330450627,
+                    7052580537726896802,
+                    7037036768552405596,
+                    495139673818878207,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9351070574776788449,
+                    12290856605170650898,
+                    4051224493672722357,
+                    427176784587211566,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16895615905868015808,
+                    12482026920157710223,
+                    9082940201693118723,
+                    1202041049401287922,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9274847207147281285,
+                    11226141971920585118,
+                    3893733355148490969,
+                    696904714223525361,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11627380525269703378,
+                    12678626086481736802,
+                    13069458548242702926,
+                    1044301213601029051,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3998087115167671576,
+                    5880802256581702478,
+                    12245255920692473068,
+                    909046735738198118,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9205043132505034180,
+                    7889816674423236377,
+                    16355677080185822351,
+                    422428355066637161,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11069149189617834649,
+                    9902365848212197051,
+                    10049179252898936900,
+                    1199094818673695129,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3684614169248050127,
+                    15096959514942550988,
+                    17222471546033653031,
+                    1334278536840631767,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4594874253213539169,
+                    963157311253124440,
+                    113594350287843778,
+                    544593962854304963,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15986236797304809199,
+                    79834948156979232,
+                    3321467107457475963,
+                    287926469643251775,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8223681394278224941,
+                    14527431366941636549,
+                    12742920232028036042,
+                    548506397910630507,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4499523591028257533,
+                    13413448394411441086,
+                    8424081826998764203,
+                    286826793199686691,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3328996903167170902,
+                    4050654812237804842,
+                    8445834442523829116,
+                    1086248301819188760,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5025484277096637337,
+                    13159896977427086012,
+                    17059079105658054984,
+                    1092942120023241615,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11096903215257041153,
+                    170853807222651139,
+                    12782809851498911661,
+                    1131049980650766370,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5201549862947707879,
+                    379350171926995629,
+                    10161036834160448899,
+                    482477303501940806,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15620919751483099670,
+                    5367973730818775270,
+                    48046915659200583,
+                    1303093432184389123,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5189596957819037101,
+                    5070903866405536030,
+                    18261764868049178442,
+                    285110394740913681,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18124217697907224985,
+                    8600513057491678489,
+                    5769465088237626579,
+                    89164804913121679,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6245118248396952397,
+                    7618151856483264549,
+                    12556984550400931281,
+                    221538667674150251,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12222105290973287705,
+                    8433591102802292829,
+                    15455096430214211066,
+                    435074054272731636,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17891966206509753652,
+                    10416283984730825464,
+                    2011604985167487566,
+                    1101492716075235783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8416636228481688554,
+                    17078427873224351175,
+                    5509371789606845517,
+                    460386776155310518,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3703039787090908090,
+                    12701669851636079334,
+                    17501456982004389686,
+                    41070841208607095,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15986236797304809199,
+                    79834948156979232,
+                    3321467107457475963,
+                    287926469643251775,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7333283470981966689,
+                    6730698409518312877,
+                    16872538364525798298,
+                    374608832921760547,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5798654743298593466,
+                    3477679127817391853,
+                    7409379838704481440,
+                    952690231017300509,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11415152442491898595,
+                    7708573295339568458,
+                    7205604900908668286,
+                    1288559159837815229,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6706888379001819493,
+                    13277376831573007951,
+                    8372131215628955612,
+                    249976131176843329,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14740899763809734482,
+                    14854054312174320329,
+                    15611850851347183642,
+                    1057957232391983531,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12181970843179931373,
+                    13614626147588359039,
+                    16379209961052766859,
+                    1182351612484912758,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15183806998099240467,
+                    6523680492469805062,
+                    318062664825339713,
+                    249901355315966242,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4498258714106144795,
+                    13865078724717567815,
+                    1493970632859243483,
+                    706959754805486578,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12605713981913696219,
+                    1339417321584593675,
+                    15852981462858557487,
+                    796569419020048413,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13601808115342448741,
+                    45060723602098460,
+                    12068953495585384254,
+                    1239260266983403490,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12384175239672121792,
+                    17195919279709833774,
+                    7713687578317482248,
+                    93609982975805614,
+                ]),
+                Fq::from_montgomery_limbs([
+                    563045924832737189,
+                    16704036554326046770,
+                    14110787794722698038,
+                    580151427341079897,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17669598381643440496,
+                    3295436341953217425,
+                    16061012017738219840,
+                    978004528773368644,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17466952672448818477,
+                    7537234592112312148,
+                    5710519156046195663,
+                    1157485173798832965,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17945853247670030000,
+                    4781655479628035430,
+                    17672308162347701854,
+                    719948551115342921,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8223681394278224941,
+                    14527431366941636549,
+                    12742920232028036042,
+                    548506397910630507,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5798654743298593466,
+                    3477679127817391853,
+                    7409379838704481440,
+                    952690231017300509,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7081096335043895326,
+                    6552913780760974986,
+                    12823190166666776483,
+                    804824559950995268,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9743345533323740839,
+                    7151543178471802516,
+                    16613514406335272961,
+                    386326159141252411,
+                ]),
+                Fq::from_montgomery_limbs([
+                    789684894129463550,
+                    8384933769573583477,
+                    18346643962584700130,
+                    979400867060494169,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    11401786509720806161,
+                    661572554887414549,
+                    11523936958286613496,
+                    1192802586982866799,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4805155117940022892,
+                    17463181432411754605,
+                    17259550030322195790,
+                    230451743543206981,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1822462971159582272,
+                    2533740881063399066,
+                    17929828033274266243,
+                    1183042539898250341,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12007931605816926184,
+                    7048736613988307626,
+                    4411917728383510379,
+                    135366077672132025,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9895875564983832810,
+                    5087308107835199617,
+                    13922243613809274587,
+                    392084605214379776,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4136434476238237967,
+                    17490188792237386477,
+                    14609791939676256503,
+                    1144130900361710995,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11171100179785552067,
+                    1774061622575883677,
+                    2585908944309352358,
+                    466483052096020413,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13953743768363470711,
+                    1414078491554198144,
+                    711352140578826455,
+                    450992245317070781,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8240546327761098749,
+                    14871221017591979001,
+                    9388426423373191226,
+                    1099292190557898362,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14556269315230261862,
+                    2794988985448654339,
+                    8096090112024899854,
+                    1114594664592225286,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9744715739840900375,
+                    15937225944883008373,
+                    10977596168700319058,
+                    1336393135657818035,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4499523591028257533,
+                    13413448394411441086,
+                    8424081826998764203,
+                    286826793199686691,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11415152442491898595,
+                    7708573295339568458,
+                    7205604900908668286,
+                    1288559159837815229,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9743345533323740839,
+                    7151543178471802516,
+                    16613514406335272961,
+                    386326159141252411,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10944270999246594872,
+                    3781475688690456625,
+                    3735572379021051292,
+                    434857396252503180,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14025340973498288081,
+                    13065768969688504954,
+                    11167676465236832992,
+                    401794334137563447,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2518290198554342271,
+                    3320019132616029210,
+                    198199903906885576,
+                    938309482048573579,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12634069990058427951,
+                    6099791115192700097,
+                    1546764348625467872,
+                    1319573662426433724,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11906944812172853778,
+                    11463695544019989952,
+                    11169962573497962821,
+                    626526609108514369,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9246849312998499977,
+                    14229298033148702975,
+                    6613540824468553796,
+                    380186529098080710,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3246972717843185720,
+                    12762781952707285972,
+                    8537125056408771178,
+                    590724938747180586,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5757991687628640106,
+                    3150358002928490196,
+                    10764719891495926151,
+                    399944411048570507,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12792744624599064116,
+                    14154352723070840708,
+                    3778453721339352026,
+                    90813475684744831,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3064578852631112073,
+                    11252687532988225192,
+                    15867388621623451171,
+                    827019770840860521,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11691943689059433495,
+                    8391492059370675112,
+                    3035783531567754365,
+                    1007168205201973312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11453736344560698140,
+                    8769950189847424208,
+                    2409481884800273483,
+                    1087810976818743451,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8949231817054603228,
+                    1214644624037539460,
+                    14495467048328126639,
+                    547536979430899474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3328996903167170902,
+                    4050654812237804842,
+                    8445834442523829116,
+                    1086248301819188760,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6706888379001819493,
+                    13277376831573007951,
+                    8372131215628955612,
+                    249976131176843329,
+                ]),
+                Fq::from_montgomery_limbs([
+                    789684894129463550,
+                    8384933769573583477,
+                    18346643962584700130,
+                    979400867060494169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14025340973498288081,
+                    13065768969688504954,
+                    11167676465236832992,
+                    401794334137563447,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8914377744985740385,
+                    16098303538143792656,
+                    6277455854787989408,
+                    1027241679526089999,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18043687544558598371,
+                        8708325242362018018,
+                        16625116017321440141,
+                        277441131490434312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1903578231313020389,
+                        13252279451564941743,
+                        6429417326301386859,
+                        900077037001125169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14902495609461984461,
+                        16049616749407607193,
+                        8772353492731922841,
+                        1191393277823112260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5443236819267423281,
+                        9444106377351672003,
+                        16592192658774520588,
+                        1198721234626210409,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13217949757478296855,
+                        18361600593446830079,
+                        18223280994163007859,
+                        471593720435477782,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14891635570577939411,
+                        4930228641865103982,
+                        677506429995745101,
+                        158637391136506271,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        241833917490571947,
+                        2153702484066609835,
+                        8471674463316687531,
+                        1217041126633955783,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15611345302311497892,
+                        9150344584784175431,
+                        7017882794185538273,
+                        953114622258489808,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17306802895913322969,
+                        6741982267855984797,
+                        18288890876308278666,
+                        1226905683868895599,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6363878173450358936,
+                        10112955129707898349,
+                        3431100839901599668,
+                        1081814334785738474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13487175089423272229,
+                        13310042664491099282,
+                        351156267858854473,
+                        1235361018641701157,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12160849746824021663,
+                        1940579672552365232,
+                        5173518843609944629,
+                        775261558130140407,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4027916299228711049,
+                        6704532292435164092,
+                        8170995433901452220,
+                        1242688975444799306,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        290813590050878893,
+                        265784628708063694,
+                        13739393643636800082,
+                        30906427573009594,
+                    ]),
+                    Fq::from_montgomery_limbs([0, 0, 0, 576460752303423488]),
+                    Fq::from_montgomery_limbs([
+                        15202715113712620389,
+                        14394769546364496554,
+                        2173784385715807821,
+                        762822603853042988,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9131295684914383799,
+                        2203294423878042671,
+                        15057168350346396318,
+                        1317572624249769145,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4102116438074989862,
+                        14211584889659760104,
+                        2322095613450823220,
+                        1152792571444036054,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1499587266747343757,
+                        6147108702578884008,
+                        539949017617531241,
+                        895205889873960171,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17023556679357996691,
+                        9711517229633709041,
+                        5965797357174981931,
+                        301060629339050058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5511882254355224804,
+                        7260383544295899082,
+                        3428775836456030307,
+                        1186855803339944465,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2037433375646398537,
+                        2689507493388519124,
+                        2600643550854984713,
+                        804425415837928955,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3092596579881176136,
+                        17605768422165886933,
+                        13500340157146730717,
+                        297813722289934269,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8893756995127857663,
+                        8910454335770434619,
+                        15463395218556647591,
+                        44586672345438642,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11777304322465701539,
+                        6865135142808763565,
+                        11667088266855733056,
+                        483048819115525892,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7211383723379010247,
+                        728016012275831580,
+                        11212331977634438912,
+                        753439244989599461,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11849273638818949454,
+                        6191548351997699622,
+                        17651318893069617327,
+                        103174051775909380,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6156029838320469944,
+                        15432115874088112196,
+                        2652624061400857811,
+                        351725361858973499,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13419292700987609231,
+                        7796786161615542463,
+                        4217641271207760463,
+                        776350455353295004,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1935215810351531192,
+                        18008925887645213512,
+                        4634697480042625917,
+                        1153037673673610924,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11273652922154312989,
+                        1208157637093696453,
+                        15931475569233598797,
+                        401390989350379869,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6894046905463146999,
+                        5279082107336206492,
+                        14316102240285825105,
+                        331814701352157937,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14586216018798230882,
+                        5755395680065468419,
+                        15123505241376771912,
+                        216269750174848522,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13682815567690629698,
+                        9463854300880760066,
+                        15812635072782556676,
+                        1138308780257820765,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9097415969848534025,
+                        15890888989530043122,
+                        12904259080285057763,
+                        982133933958653743,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6624653048894638844,
+                        11517136370766333181,
+                        9602592381575626103,
+                        579050920284078119,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5229711728815625521,
+                        11035738600045252818,
+                        6496649705072165730,
+                        1250412737789004478,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10770028097538534426,
+                        12342398353828434672,
+                        9619283505306400602,
+                        1305282992350595413,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12148700652231075802,
+                        4310523537000647275,
+                        16947517767337336718,
+                        918131904762323133,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14632819201555440098,
+                        13749012433716239373,
+                        498065659140388226,
+                        1130761431161860784,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4844391065345889320,
+                        44926011965336532,
+                        10246893959415436858,
+                        582320997467713916,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13320510717561593528,
+                        11860587567275895489,
+                        2225283356831587736,
+                        1343674429367876963,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5704963411664347879,
+                        4418008531741194498,
+                        14493236860148394678,
+                        1121877409752760133,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14215796318241158443,
+                        14562559445203787777,
+                        13776574306822682447,
+                        459084654130871569,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11243880401657218369,
+                        15866510227119017390,
+                        12538385855399087371,
+                        701897125742939372,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1051263739550551530,
+                        8451368553790609057,
+                        3282325108612180296,
+                        921982015066679651,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4965158743601468261,
+                        16797405590188694911,
+                        16499584004034661468,
+                        156125824994336495,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15012192606324995634,
+                        623521186984486543,
+                        14545959863656528232,
+                        662718690425389806,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8455214428933677800,
+                        6180993422058598587,
+                        12780710007421067160,
+                        739518201544831740,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1324133776916736037,
+                        12802407183458380575,
+                        5739942530555139561,
+                        344650758038911291,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11604242213808316591,
+                        17232866250291157500,
+                        17061949776724137439,
+                        740103436309796235,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9970931071182215308,
+                        11319776887515708670,
+                        9491690840672539072,
+                        1114003259651236577,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4615547131291070393,
+                        5045692161150362422,
+                        2114604709398809390,
+                        1102809833087118258,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18442799855811233447,
+                        8758055513228524583,
+                        3750333286909681021,
+                        1160330159904365254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11048471733567542306,
+                        5146049061588420877,
+                        8069235139157169282,
+                        53071088130941659,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14603464552356584394,
+                        16443824290669971910,
+                        12639512632755551634,
+                        1179391979532387716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1058250602757047864,
+                        3741265006585951473,
+                        6049072173965470233,
+                        1329301227354919296,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11247045422029295748,
+                        11493642439321267457,
+                        12260492971131673493,
+                        1001065988334401576,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        319257096871496783,
+                        2086658896676187375,
+                        12530344015237632627,
+                        1168589021089267622,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11916515215309578762,
+                        14218429474032919144,
+                        9809554333928926653,
+                        462570033762589384,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10242251505956709785,
+                        17674323089533218951,
+                        17743204043251983331,
+                        428672479695545659,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7240598033250684473,
+                        8279029956564283093,
+                        10646639157483951277,
+                        6636884438584270,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10900891235489096034,
+                        18291580865393535934,
+                        687033527783896076,
+                        296842675444382874,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15085701624977622233,
+                        11413933535833275024,
+                        5775885184797854436,
+                        427042853291277358,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17995251816532162661,
+                        12763598470428141240,
+                        11704955755279420690,
+                        948670118568091265,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5557825381578587147,
+                        15541164076386541711,
+                        13133475835712270102,
+                        81862102897723890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6536798618701197727,
+                        13289955196304895286,
+                        620894837047976640,
+                        614386194194111840,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13500790268045296214,
+                        14435944819005179235,
+                        2189882853119669660,
+                        468181398969912462,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7017608164878865470,
+                        1158978870069287309,
+                        14922756224125542090,
+                        490651254277474669,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4035178573178702594,
+                        13968779475468459665,
+                        4488204471464472598,
+                        1125915584250753045,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7327536416869167911,
+                        12245186849567203306,
+                        8910651438863383502,
+                        43425991954562163,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12856555124078793311,
+                        956470565558887164,
+                        2718959086417308072,
+                        457810065745817328,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13082712521106061284,
+                        542249737956542797,
+                        9839818866322351270,
+                        1200870172654250255,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17503393269683234619,
+                        6315494614735795738,
+                        8304608130927389907,
+                        149242825197127988,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12446431831996332510,
+                        4252514128934402355,
+                        15228750274117340452,
+                        1019722211382372947,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17349778466051582214,
+                        6507599138809566628,
+                        12221254946375377186,
+                        886732565619836536,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14903731393224176072,
+                        4952407791177712169,
+                        7445035862493507777,
+                        326873499344181570,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13296455838052743126,
+                        17895260973277110241,
+                        4031975993732128139,
+                        360868109433638244,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12563046951998130254,
+                        14404272925098701542,
+                        4672745122638235682,
+                        922910250897914361,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11633482310903278876,
+                        12997439148886814329,
+                        10789934854992209059,
+                        499403700369947848,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17836191594808555,
+                        16054211104962515609,
+                        2133453143501793465,
+                        143165270677229886,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11005483748305166366,
+                        7620993273618085964,
+                        15523334995822172403,
+                        457848951643000884,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4344605404507956508,
+                        15594208365140703320,
+                        1449215307461978422,
+                        1339912479850235122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9701016381447727344,
+                        1223813677539325047,
+                        10629102863262498914,
+                        992584686400152326,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8436833729908765904,
+                        15613927815788308666,
+                        6801261701945217669,
+                        225399678144538650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5693299781954223944,
+                        587004544650971554,
+                        17927435258048956655,
+                        329770489298461078,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18066777069674434891,
+                        17134072103703220118,
+                        10193313396109382983,
+                        271016921062542162,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7560826014372087295,
+                        6143770213945702262,
+                        4400776500112744876,
+                        1344664094622120070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2796756470366172432,
+                        4717147909861952632,
+                        5063846101747692110,
+                        880588688230158423,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12016984013487086085,
+                        10083132150437290279,
+                        11428505476091061238,
+                        1341967703264333014,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7535852530641331939,
+                        15902294516003183586,
+                        11544580377679661677,
+                        800324422135742270,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6352014014221625641,
+                        10747926058148168665,
+                        4088033371761788729,
+                        1269665214703579883,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10679878098318661366,
+                        1592106354218525524,
+                        12383284148931582779,
+                        370809649414721103,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12788411299604037001,
+                        14137236794811830312,
+                        10048637517041166264,
+                        1146076569103401706,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3580300974571725061,
+                        12269133957736639691,
+                        3820097013879120345,
+                        1129703951426383533,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12914065441308458581,
+                        5579652695107179070,
+                        9043094637190237970,
+                        901196301276405990,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5246926288552425656,
+                        8742159036309394806,
+                        4067524330337514229,
+                        360865575747925419,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4768005371227731912,
+                        971568261466437822,
+                        7166988688791408292,
+                        459550140760209281,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6166264394389048772,
+                        1295344049063407811,
+                        6871295456932980302,
+                        559234172416292234,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3469761665681696438,
+                        2298438434648303929,
+                        17498594963292487131,
+                        1193070165581929030,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18418032164001587572,
+                        6852469896881750385,
+                        1011531170233466732,
+                        200543196741326714,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2719088415345591092,
+                        15283629715924643316,
+                        10389448113322395796,
+                        206932186251572449,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14341136814219067410,
+                        3880699351332059178,
+                        15993441086779470705,
+                        501816629631691854,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4557451559895430246,
+                        13272727722015475162,
+                        7447244719631814052,
+                        604927337834273952,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7053423572929629524,
+                        7292979032272587404,
+                        3848501111903077514,
+                        531979385510746888,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12546560380626693887,
+                        15648746138096824043,
+                        15251795144463144332,
+                        1127401875964871907,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11633176374275978650,
+                        7210400236487914953,
+                        11783182804068099691,
+                        922652672425147555,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2017152773330620755,
+                        3148132717071725909,
+                        6097271218030058831,
+                        720117142797804675,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16211910424168523694,
+                        15848180903998611408,
+                        9704423571684280730,
+                        710996774728081771,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12546515241501971587,
+                        17250471003634028663,
+                        18443269831582349191,
+                        820024968005405022,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7452741518215340843,
+                        7846054970899950721,
+                        13196451138625653743,
+                        1184688794262453826,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7133625316025151468,
+                        11104409756328356763,
+                        9006417038989528025,
+                        1212463804094096933,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18178309560699274466,
+                        17719627513650074420,
+                        16093933640022611486,
+                        589781989522943091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9008005136699075023,
+                        5729946863317022447,
+                        7622468172778624874,
+                        1250272211966188539,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17345290167020642033,
+                        17267678393115363399,
+                        9598837011557647796,
+                        1253805118563152926,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        420741425059342631,
+                        8805112500005063281,
+                        9113709499875973196,
+                        73849682341106353,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11496733552509947946,
+                        7764169774339892615,
+                        5156148679361141404,
+                        564316597416601124,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12622079639066120661,
+                        3266901843750390123,
+                        18320727954954935968,
+                        494465857441479999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2653576976063122390,
+                        13650198291488315920,
+                        4072033883091329136,
+                        1166296059590395506,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12276589373980677996,
+                        6747615065784514664,
+                        5241749085916048568,
+                        828703320815114480,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15801191759776563804,
+                        11906477662892691005,
+                        2596116854177209866,
+                        140885077662599075,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11556213755046117587,
+                        14840039979108020518,
+                        18022479590815971996,
+                        482964654878851886,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7831462237507523996,
+                        4375808712138412251,
+                        14238976094002258528,
+                        878247968664938711,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6638631580513284482,
+                        10443142731856202323,
+                        16143127112997324119,
+                        1190043784194774655,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12747632869854765290,
+                        16188855133498771934,
+                        15449608238552885507,
+                        290237552336236665,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11894929943876435156,
+                        15994494389582679059,
+                        10429260195653789969,
+                        918667819310588301,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7738929113818827188,
+                        17286076113231126878,
+                        7073068312512145185,
+                        433891149864026312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4961815909451123513,
+                        9603040401796666809,
+                        13315138754516145846,
+                        288760734155783834,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10370174269865967878,
+                        6679489058678058923,
+                        7113756982520038621,
+                        1270885709456160260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8029159862189643761,
+                        7559467932910244901,
+                        15270189161945694292,
+                        1193238369778977563,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8659087318425358189,
+                        14023045512866690785,
+                        43506079069592953,
+                        218725496496261102,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3164731824537263157,
+                        18227788971397655098,
+                        1707861536539020788,
+                        592644854430250705,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6186045084114830541,
+                        3602298335673150574,
+                        15665541754892196887,
+                        394507815659653999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5711933431501070947,
+                        13767836017969932922,
+                        9966069838228486910,
+                        363368143395566571,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7164113799557520642,
+                        382544792524337956,
+                        4860230192569951519,
+                        418960796585564821,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11393759970496279369,
+                        15503653456410766106,
+                        14018003676137771272,
+                        744584872034829482,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6527070063634616360,
+                        12614784375780035762,
+                        10791243836296521244,
+                        1303730511609800777,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1683755181927760299,
+                        10810765859281827234,
+                        12386912647671427437,
+                        381377273617257559,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3864021083547713129,
+                        18397611896979927478,
+                        2142933713349203212,
+                        161239824949889018,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6889624682337925188,
+                        17727100474504839390,
+                        12126574145258848586,
+                        45995004477257277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10933900358209380586,
+                        10477364556364848363,
+                        839852329537855297,
+                        598808985997546934,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14570775672791323921,
+                        3885834484865519126,
+                        17801274550466579105,
+                        1086189380128599676,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7605697229511350346,
+                        12310515183487316841,
+                        13296040354319821411,
+                        1176913422277863107,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6994261437458931822,
+                        13951509110768087123,
+                        4909166179891867071,
+                        655287209867974094,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17556220701714104190,
+                        2606034147837033609,
+                        12381852845026009730,
+                        1133008474149971512,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17756383955901049393,
+                        7911436759326828515,
+                        3350599286943704113,
+                        575025887945410855,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8502226298110585134,
+                        11086820694121022050,
+                        7216964557321543969,
+                        145187465216168056,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17921568686091572252,
+                        10541007657375412096,
+                        6644812414878992278,
+                        310893659853877292,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14131985511597820081,
+                        889482072076351532,
+                        15442334086410794378,
+                        811518589088250832,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7524357411249472352,
+                        7992395332838905360,
+                        604927339193945050,
+                        478950235625231378,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1514015207572729630,
+                        6009383297241166553,
+                        13336751867689784132,
+                        1012283055299903239,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2598713178991477499,
+                        9002974558966932943,
+                        6959191882956480587,
+                        503308644654652132,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4274302772873135853,
+                        15054062442098682172,
+                        16328790561655230738,
+                        676252933577173296,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17287715180216674687,
+                        18283730465299080139,
+                        3824270613703512293,
+                        1296916416565678239,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17855465974205487526,
+                        8502440072737271377,
+                        15093646773032300779,
+                        771309226876337,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        448272006128973286,
+                        18004807908711064960,
+                        18215685654034556220,
+                        596275986966250672,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16919999269055569419,
+                        9373632697973711316,
+                        1273959831054692850,
+                        824824111245546046,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3673511008983172813,
+                        8782123814118417265,
+                        13606681678827136719,
+                        71793325035923983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13455382028822315192,
+                        17360335814479845190,
+                        16849044497961598180,
+                        78480663025458365,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3796374622595514890,
+                        17475210637545733073,
+                        13516157616291249611,
+                        894993030141373869,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1158555446325321360,
+                        5952998820522536084,
+                        7207284787339560762,
+                        925896995884695096,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17077027522151917807,
+                        35304388809142495,
+                        4682985528082316831,
+                        1236098335579654057,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4491847878249436326,
+                        6815089290163615451,
+                        7104116846519452531,
+                        346901502801821195,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15699185833684047079,
+                        1887634774002392524,
+                        15639353088392499238,
+                        1024716025037843394,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1620412438602884814,
+                        6452924579119564714,
+                        1837060130938421370,
+                        21970217862449094,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8111330629502502426,
+                        14926407226584468879,
+                        5882162671562516994,
+                        613784692258966528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17845997114747939654,
+                        7386938403763780484,
+                        7005407134749384846,
+                        689512628831418991,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5504226975411667260,
+                        12119908631894312534,
+                        15207444287010561400,
+                        942082737716212184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7352013417184022796,
+                        18033226033143391858,
+                        12484092667251150373,
+                        1097308253504824549,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7643610267266911913,
+                        7939715979142579174,
+                        13105691481141410986,
+                        398658518282346341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3811735265410976556,
+                        204926722492113344,
+                        2267095670399364813,
+                        72104921631281205,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4720156958449273564,
+                        2306953569464204304,
+                        8296738819511961957,
+                        1283260736437153213,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15566248186791771665,
+                        3318115454354803564,
+                        10393441646852066211,
+                        201877997747049314,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12913720735893653318,
+                        4207948251435056874,
+                        15845225706436688284,
+                        69451321733677463,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10734231796850249664,
+                        12736464242685921592,
+                        6466740730356055655,
+                        406264026888138752,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9110941686202071973,
+                        3318537068497454802,
+                        12061589285485513552,
+                        1052719740088592469,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9702408921584078324,
+                        14365901479420521798,
+                        10253984391415261099,
+                        609640423579588063,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17440687051929656629,
+                        13472641582225056051,
+                        8726961394394391800,
+                        298495231863759178,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16999160752883928277,
+                        8039661631857232234,
+                        15566129454965931561,
+                        495061216648925626,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1605217963191025874,
+                        11787641279418910567,
+                        14441735874704483244,
+                        779721849299220884,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17949311330137483451,
+                        4987949218180626749,
+                        5291364891115499617,
+                        450605589637919195,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15309147882884498766,
+                        11404050736210509496,
+                        2671044221635750831,
+                        983521943327943142,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16393441616061160258,
+                        12408417576982207867,
+                        16476777355143720376,
+                        1257892350879450957,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        88336466720529212,
+                        17157259299380245932,
+                        10549281847300776623,
+                        572720123499707023,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11708189741983407026,
+                        5738507139909500801,
+                        10957198370688544801,
+                        379643693711928580,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1244553907202353266,
+                        1065202009161653540,
+                        10386288320302801811,
+                        238808649293007384,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17252100346563190043,
+                        10735195958862010068,
+                        10794777234390191148,
+                        1126800977484709061,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16408975363637722264,
+                        6150593005607985538,
+                        922331702214426880,
+                        575044601058349761,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7307685530705431641,
+                        15744120807473868532,
+                        7513619430768901717,
+                        1239259219955206781,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16789643773455327770,
+                        17592937497089133059,
+                        1683959621061079100,
+                        506693130571885002,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14143235088768369432,
+                        11408196713839975893,
+                        16329621815113399240,
+                        1051511686221062379,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5332992548255336371,
+                        1358267855101653542,
+                        587151769106852308,
+                        171773173850963791,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5877461619040059520,
+                        15774377367068441552,
+                        10616449231287295294,
+                        704453045083284546,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7035820348446604016,
+                        1364203264701086379,
+                        14686459817112298201,
+                        1078335237486504568,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3832002795616077738,
+                        5809203325780730318,
+                        1608793254773701555,
+                        1180636157369056024,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1756509039593215220,
+                        6115853652398885161,
+                        1726989054423849632,
+                        577103510397222802,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7433932364840252431,
+                        2258851129630088330,
+                        9623789626636060836,
+                        1067990586174495218,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15494313290825166833,
+                        10103001389749561564,
+                        7249793041606032894,
+                        770615070700435458,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8146598963806268491,
+                        13830581241165627995,
+                        7569361702132994906,
+                        844144322504613330,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11543346971378080457,
+                        1030857743394506944,
+                        3641878915305953266,
+                        1167380360843081311,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8564883268717060447,
+                        2127777221499314250,
+                        11110086889226645375,
+                        740942296598163980,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12654034107658674148,
+                        146801024326803935,
+                        11264090171901855225,
+                        1275083332857619815,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7708338760401226073,
+                        8844431796154019020,
+                        16268327542711301243,
+                        44978165470282427,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1671509021090933714,
+                        14714671088478091390,
+                        5917513369120704866,
+                        469781919425184629,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        979884456857393563,
+                        7785014661492056264,
+                        11079004091427020517,
+                        1022537092668214070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4108897581038487864,
+                        1627094210148729699,
+                        1856594216379641932,
+                        680003701527776868,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9888218918192562648,
+                        4910381142214343659,
+                        11159155871183041610,
+                        947006842425326726,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10707708268699629933,
+                        738824573510307123,
+                        10382142697636336894,
+                        546469657741266150,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9844305375767811848,
+                        4559614696892567942,
+                        3595190493455043396,
+                        106046293136377962,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1091062912289593279,
+                        15882972571848559436,
+                        10489800834645073008,
+                        917035398574779528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12280801322285489667,
+                        17057135536092974718,
+                        5165215803878247315,
+                        117317201303019542,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17347526461212782642,
+                        12162937901914685697,
+                        15268448453261413457,
+                        469685470721497341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15151340595831987302,
+                        16607861867355796408,
+                        7137429536414358501,
+                        366315591449941469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1832409962326817426,
+                        9854349554467667440,
+                        2279000322060165232,
+                        421472407737584540,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17929916939977316323,
+                        18142851787168731727,
+                        16595959574990972215,
+                        579509164121373192,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5433091590132168529,
+                        3597179598300525662,
+                        12727987683350131406,
+                        43805094016511211,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15548760273523383213,
+                        15605813456431178870,
+                        16350113494521408617,
+                        990814035416625008,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8716451039311424934,
+                        15956359906919428708,
+                        3286938403121586778,
+                        54996502282725403,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5203068219373890418,
+                        6492867848906790111,
+                        11667790659848571781,
+                        937173987832319431,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10229256714539703564,
+                        6596112673848606994,
+                        12914526614512438474,
+                        1325206590347944505,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7728666504907021870,
+                        14351221635741176418,
+                        14502844269117715758,
+                        1273544743686251537,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9059917493970212785,
+                        460515526808893214,
+                        1935212669406133972,
+                        1169526173136498534,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11119182207046226947,
+                        8404345108646597396,
+                        15679729836850153704,
+                        876893174082406987,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2907576399123638240,
+                        3311557912625860417,
+                        8450655702513435886,
+                        8567606267330927,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1936884816729118210,
+                        15529270460312293317,
+                        13750567898153513461,
+                        64405686595060413,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11822790215114202943,
+                        9099923372011351087,
+                        6484828379040359255,
+                        1125777133681160230,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18370784462861098954,
+                        17330260367540353488,
+                        16841577107618394426,
+                        656419510700031727,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        297495662792932402,
+                        8918758225924963622,
+                        12672526008257247108,
+                        416474948824733225,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10154557590555130082,
+                        15169116056277255637,
+                        16456264419270122479,
+                        188443541314437514,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12208800714210134353,
+                        6729559799362689689,
+                        13596746148045699449,
+                        73247417328763464,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9014890039291201283,
+                        14520294764267136350,
+                        10030024239239047588,
+                        583371008886234380,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9028893951814453354,
+                        4366168941490832494,
+                        3954136238583186384,
+                        1048278926779539910,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18357579735635558627,
+                        8762540925911338545,
+                        6131260496672843837,
+                        468817648228482998,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17149693350370224315,
+                        5451591772028465138,
+                        12053848121985885935,
+                        940717833566981032,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4270816174873986029,
+                        17795804187874566956,
+                        2568897107683773968,
+                        318205118124046858,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16741572589016516410,
+                        5434895172127222886,
+                        10725683131966627087,
+                        1183511926781880217,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9116606919161067441,
+                        11385419170281893870,
+                        16025495526127613777,
+                        415259366678150518,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2721091099748442560,
+                        7929824544369152598,
+                        857494882838644703,
+                        296830535535516472,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12017700576642285907,
+                        16590746420334261575,
+                        7104282359809907538,
+                        637744455494201738,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1169173370962264137,
+                        8852279050244336441,
+                        581772941967293161,
+                        690726524618592762,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1533465827094199540,
+                        16473839404312724092,
+                        9987558617764563654,
+                        880928069422364042,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3868193834098768209,
+                        8078591369232508645,
+                        384627435417514650,
+                        1250743631423339474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17558698373166129167,
+                        3927049329383333792,
+                        9217196820148316462,
+                        64157152973090025,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8807508857029790973,
+                        14330613339068224032,
+                        10660463543778546404,
+                        601897855235490890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8623134316531049513,
+                        10486420194733910900,
+                        13990931617320861569,
+                        960505809616079569,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7539761335661883776,
+                        2622652813314665155,
+                        3845950183634453519,
+                        1062931830917808273,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        574446151684236560,
+                        12390343189917831149,
+                        1192625895197061388,
+                        193289876318202745,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7948755173553525674,
+                        12681809938188793214,
+                        5249577757513024824,
+                        1100719852919406016,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6958853663305533219,
+                        16323856265483965582,
+                        825637487441250777,
+                        333864299838592565,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7892322294403793456,
+                        13395129515140857186,
+                        5713564651597894147,
+                        1040754994043294928,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11981561131848860736,
+                        6986344335794632329,
+                        11089666059537652007,
+                        729702215471207415,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4066524798214035205,
+                        4753935135895292745,
+                        17168097053282701607,
+                        1276389245782722870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5406515489444675055,
+                        1137710897087931258,
+                        10275372907939193907,
+                        1165344743547949996,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2306045057038015827,
+                        16712056170694857651,
+                        10592291446962684004,
+                        28821309252975281,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        926720394852271377,
+                        12424220199856073728,
+                        9010002621425334362,
+                        211945260911303947,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8375057474269595722,
+                        965242499678573451,
+                        14632495954491311484,
+                        1324167170421741100,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5508213641816312359,
+                        17799179694230623822,
+                        18315488178529590028,
+                        272797587025941075,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7072685130562179933,
+                        11552197551556498701,
+                        2940146458839689358,
+                        1074612656481474154,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1419906070601110297,
+                        3874626476133237893,
+                        7613255964428038713,
+                        15261713437555941,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2630920421596297788,
+                        7260145016791719064,
+                        2426347822682387302,
+                        55331752373908355,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12673100469579905238,
+                        16936338514940348711,
+                        10743909535058826380,
+                        767354809713165229,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15603256998174170029,
+                        11771669863717071206,
+                        7573453326569481785,
+                        727773662670986631,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5760984061336749632,
+                        8850281854738120495,
+                        8010102956925014147,
+                        327827480544942784,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6641796267604201330,
+                        6027347283015169483,
+                        13259738973816525338,
+                        814691430592052485,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10659539726202681930,
+                        7343618882197162125,
+                        1176330985933994845,
+                        1197360750280391655,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16937408062173906563,
+                        5039089687290704600,
+                        14015217488307447255,
+                        601756851350921098,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12644736454544192029,
+                        6298800055717878252,
+                        2708844009234609871,
+                        487894800270844105,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13886802795777450,
+                        12397220131561213836,
+                        11295780625075564224,
+                        492400971954743097,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10268816384029664568,
+                        7753821214394418657,
+                        809019817409288029,
+                        1001736781231402014,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        395296892990789502,
+                        4125045450146540419,
+                        8678362340662981336,
+                        350248603711249307,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4589659460953780291,
+                        8025165478295553211,
+                        4946570879126955193,
+                        323063023659501540,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17888521256137126036,
+                        16775275897775487432,
+                        5815078560278696261,
+                        779924366050876257,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12098246862134642094,
+                        6401878856763046804,
+                        11491643281630884880,
+                        714904804454918531,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11289136422610454801,
+                        4534941715044303180,
+                        9302514332846837979,
+                        1287434821908803888,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1654378163561881909,
+                        18305134837550019861,
+                        668281036148207349,
+                        603903182396735736,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13692123669487148911,
+                        12251683104751621166,
+                        10358322571791885427,
+                        473260799130529416,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7536630495677855626,
+                        13382559966364015223,
+                        9654183030876177244,
+                        946027197270550921,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3479798714381587498,
+                        15929485412018710865,
+                        4038911171028370827,
+                        508972570735215628,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7558424467002441825,
+                        16866896308198209926,
+                        10072171519600728155,
+                        742767756269517187,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9523818237556971923,
+                        7924199132526292683,
+                        6883865240163647410,
+                        771361273223812545,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13036317495250438307,
+                        4310070340247371606,
+                        15572263420360627251,
+                        580577778559026486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4510994707429546514,
+                        15148059537156345146,
+                        11671325241224348125,
+                        984225750894719671,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12507987361608268227,
+                        7807849771566013600,
+                        10358349214069149108,
+                        1221061893807717849,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6419553014969491710,
+                        14080908631247884473,
+                        17175160673473182983,
+                        278201332408620235,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8401733829494355264,
+                        17752092096837451307,
+                        9133993617611535249,
+                        768045293856296605,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5913627625163362559,
+                        9065699538388337657,
+                        11500970245732618176,
+                        729365064533550207,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1688772897516273463,
+                        6878369741535621687,
+                        9748585044082452626,
+                        847541691621961379,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5480265384793891665,
+                        10328439794865415514,
+                        6287267372938822398,
+                        1244796793360099568,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17190342909341897443,
+                        13351178826508794346,
+                        469650610372693123,
+                        934112594503389629,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7024207665354523195,
+                        941447820742038958,
+                        12890594973202152484,
+                        536842276363928186,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9611557089679077331,
+                        12024395467286357849,
+                        370376233355046437,
+                        169038286197827687,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15206929098992814493,
+                        1621319520086084670,
+                        3381743201828051275,
+                        317241604981512177,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10136659531827238397,
+                        8115125146055082802,
+                        14325398063008529652,
+                        1266771445939634069,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8327864824928313676,
+                        6613380720124397411,
+                        3878866266457713588,
+                        1030213174295763384,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7535836887675195401,
+                        7689999077057748960,
+                        16858432049393652956,
+                        426630712606788258,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11670199074928658272,
+                        8223698180401612887,
+                        4238499440845508349,
+                        1275995814706826324,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5585308980836706850,
+                        3846707914933688948,
+                        13158489033871310738,
+                        314426224907521731,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17173855863845522547,
+                        1003203932023982468,
+                        14321960530079715992,
+                        1126078260827822951,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5208287642470406990,
+                        13616252137232196819,
+                        8388953347511140928,
+                        17274523918770736,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12624909464903166677,
+                        15463398796368125818,
+                        13162184717865550199,
+                        519522508229241381,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2457517800451217154,
+                        16220110218703439862,
+                        1570688185348244253,
+                        734672463161693037,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1159114057686721050,
+                        2833897809733721089,
+                        15398258041675726529,
+                        429960199647407005,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13751077139424382075,
+                        12770977169189564818,
+                        5532247303883835520,
+                        1299661358801123578,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17174282478053092340,
+                        9254252009684759152,
+                        17329726724005494861,
+                        1313014555983996594,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16548973668738475644,
+                        12323872078707889072,
+                        14322747196151917233,
+                        231947673917233064,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16226894204384621972,
+                        10628488996775265032,
+                        7666743457890646413,
+                        981974425387161187,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15869417497717431538,
+                        15158721885679683373,
+                        16977090585448007832,
+                        279374505599558655,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13463874920513710634,
+                        4871331802573783974,
+                        12582791470142749529,
+                        1164157243536701589,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3133523127006986308,
+                        8972975462242269686,
+                        3082500148241467600,
+                        1008531161523366804,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15559835381083320344,
+                        16389474596343429708,
+                        7245776870724235357,
+                        566725961066021416,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1059448421556428235,
+                        15753277965363579681,
+                        5594453358509650267,
+                        389832166069704924,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15938601892206072293,
+                        8516549559747052489,
+                        15944670279677540031,
+                        45785874568984074,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17690894990683516395,
+                        17318261912477899118,
+                        5395031518776230004,
+                        1343717103459818372,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6450626234625650801,
+                        320126222736737164,
+                        14964356488616625485,
+                        1100444574293429431,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8488984526948392125,
+                        7521926670256897448,
+                        2263749250052153077,
+                        956478984004631877,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        577147365179890625,
+                        3940842260935620289,
+                        10363626775189987316,
+                        821531901179867774,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        782640555184984821,
+                        2044656778787812456,
+                        8890246346339130061,
+                        1006032224059067508,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10146860838993174602,
+                        16584372848042771147,
+                        8605344768650603466,
+                        925453716685060876,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14300789371878137580,
+                        14045964922178957360,
+                        4072782587271295310,
+                        966941709956248166,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12615098876604839728,
+                        3515587530750353006,
+                        3454424821407596345,
+                        913826613966284797,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2654253456520571748,
+                        8132222255207403978,
+                        17510308789186352393,
+                        991328611565792797,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14857602383712931575,
+                        13383556079137641473,
+                        5381745628002266690,
+                        301169189379890223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5665943580232819835,
+                        15219025184123816770,
+                        4650262998901321796,
+                        114506615777369194,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        495943422413343176,
+                        12097498453860546545,
+                        3439031868547212124,
+                        1102452936054583009,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16313831285326568188,
+                        1677815174609608039,
+                        11701212813592454653,
+                        260795568438412211,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13805400598190056339,
+                        18196106240079933029,
+                        6096546791100484058,
+                        225530163635558189,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12393570054941230778,
+                        664365280941930523,
+                        16124882117065383399,
+                        1062108283042162315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13774036587721042328,
+                        3018766419550903000,
+                        2479082916896715125,
+                        1214145092958652503,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16766652469077248573,
+                        6519487251316275545,
+                        10966938988061634775,
+                        688753080129182441,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11651457486425104260,
+                        9023652979817954539,
+                        5813207541799393505,
+                        1019645620453200,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1193251746084442318,
+                        8149380929832615952,
+                        3106491531818730443,
+                        753317158934887948,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8560176545362243469,
+                        13001042495857438179,
+                        12270798360503453768,
+                        957736995135192745,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8540734199865832406,
+                        4056439249514600944,
+                        18413921261773365760,
+                        772195322595155604,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11798279654823695643,
+                        8206700651196316216,
+                        3828811709358712239,
+                        302379794720348457,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5060568657400734376,
+                        1673129744191173251,
+                        8683302666982794959,
+                        203584140505641650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13866066113318524228,
+                        4348024470100344772,
+                        5372253441392551941,
+                        390816638412606811,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2050200548125024344,
+                        9668080123074567798,
+                        10885947498609234296,
+                        261724312983167002,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6906448595827455059,
+                        2331019210207022398,
+                        636602392155525442,
+                        807211314432660341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17619152906654350467,
+                        4154093717009604932,
+                        5760873728600817760,
+                        11073858326544343,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7998331377928998667,
+                        4212662842963652816,
+                        9197153366156577793,
+                        177520210475194308,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17786220410671588911,
+                        12585040943704749988,
+                        1956903487545464527,
+                        1214072979748184850,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14544929330067539789,
+                        4917138703297561435,
+                        3945531774542160191,
+                        280647558010782617,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16443442560866716475,
+                        15058683116031984694,
+                        16235077551575775861,
+                        668645779786405891,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8226449088708421556,
+                        6583931517392423948,
+                        3257318366863976740,
+                        264687224382005974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17318711741716188432,
+                        11845211617861035305,
+                        9555526104817506294,
+                        373560956875798067,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13427197641458560387,
+                        18235676214157176155,
+                        11961255719059301988,
+                        305694897150516316,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6789872708220715480,
+                        18307985842873721296,
+                        13790245763293485409,
+                        895670039551536735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17902555786137630531,
+                        14249024766944377246,
+                        15655178699326663597,
+                        119173860430319555,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6788857514352814490,
+                        404102275087972342,
+                        10120321502960624083,
+                        1187990212808511524,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2216036581387197563,
+                        17515454179799652763,
+                        17050159239757726422,
+                        700021341077978450,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12212660806442913491,
+                        14546687196876169119,
+                        18240441681008384173,
+                        939760408002780494,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18125124517228350113,
+                        12074428104970962673,
+                        2452543306963634257,
+                        1342636808353686971,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2508713935068617127,
+                        9105136541538441538,
+                        2575052258316888183,
+                        919454016562951685,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2353696124065517388,
+                        1680501557410535844,
+                        4948554500744815525,
+                        555662546045242036,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8344806843433201874,
+                        8790268928624575846,
+                        3860075726790560660,
+                        717136834906094983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17020777714124912138,
+                        16716703115941973903,
+                        4765498825693520703,
+                        468946550074225106,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5902002803428838766,
+                        17806561967496965637,
+                        833691337769655392,
+                        660848875291975784,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16055206587672456953,
+                        16962737488997049244,
+                        1668115614992075899,
+                        1209128658323917848,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6902942701546863405,
+                        15031673768509727047,
+                        13648977175589359995,
+                        708332396049394866,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4742994843797122413,
+                        9256336980813510430,
+                        12845786870807763529,
+                        550451098306096900,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18373695423707514645,
+                        1202874005995141605,
+                        17553826140630996845,
+                        655479050036878660,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7445894529187291804,
+                        16870347984599673925,
+                        5533042481475726938,
+                        1303624449254264402,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11786438796384119405,
+                        9949528611193843331,
+                        15445267954230906757,
+                        896291755406767691,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16965079587492850040,
+                        5732118434954148341,
+                        7364511572495587909,
+                        806408804200344524,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11692474327878167309,
+                        1727986406842161065,
+                        5430072955753903089,
+                        218212758813052512,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2376687695748699599,
+                        8881077201506404076,
+                        14877247294864243587,
+                        1084133929766895514,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10661901854353824738,
+                        2390250049839603063,
+                        3955639988869068405,
+                        563780056559346023,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6325257811996556267,
+                        5637950331916869609,
+                        4538724814902105566,
+                        281604292773536028,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9031615173468988078,
+                        1612562018691729203,
+                        4734108939699607108,
+                        1037718369012050840,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        454881683559559327,
+                        11783835503888897324,
+                        10866673075305741486,
+                        600879928977695631,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2521579667098926315,
+                        17978365763372909013,
+                        2860371691170829254,
+                        272437197960765249,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        609881192939260017,
+                        2529332681543357020,
+                        10939593758515545021,
+                        452507869312686837,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8440229617408007690,
+                        8618791083070638327,
+                        6151503925390897846,
+                        204815461279565811,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7025418854051215008,
+                        15499798867828051616,
+                        5616017301355295097,
+                        1304501330056343213,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7191745152930156853,
+                        15997748383090684780,
+                        10769841279624027258,
+                        1286581037680807738,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7401155195665369805,
+                        9165555764145531878,
+                        16104521036038599625,
+                        686789245121333165,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6039037610396950081,
+                        5205900071065542429,
+                        9177736908324512014,
+                        564968688969339902,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7866864819880275367,
+                        7156053373963810708,
+                        15468981827160661419,
+                        1226841521128127396,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7644472598168770582,
+                        18123251164646088345,
+                        6910667967313589527,
+                        666303935415900440,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5948052106613164470,
+                        17588612811019795355,
+                        17547762765910568326,
+                        168648211173377582,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        169548799541941738,
+                        11530030398676199425,
+                        11812158699699769153,
+                        926291491060721878,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5309351048840373368,
+                        10629943730156745932,
+                        5518657424315047757,
+                        462021069127740903,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4162635078121034172,
+                        2553607292692022160,
+                        11211845604187596903,
+                        862262559041067097,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16638094644642020406,
+                        17027761535496269739,
+                        8665424998051317169,
+                        657156875841888159,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4751238387467148949,
+                        5019772198360619398,
+                        10918839498266414951,
+                        525934805453896347,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9126424506601862877,
+                        7852424748527158898,
+                        14494694350077485521,
+                        1147302791056611355,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3147667178622609443,
+                        2009595605150537539,
+                        5763212341436832355,
+                        440545117130120524,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3318536735027354380,
+                        10908979928054537328,
+                        18009320442089033210,
+                        1206930988338980381,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13598883943955094075,
+                        13756744716472547123,
+                        14295671398252259689,
+                        35571578814287,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17605781122056964692,
+                        6446691978380748629,
+                        10876683846805162081,
+                        916727068126618573,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10730029386166487846,
+                        15100395272978864609,
+                        15439137024744178300,
+                        506888808192219464,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        817444699419211401,
+                        12074814820894225106,
+                        2539841057140297419,
+                        979509241636597926,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8557904411740510961,
+                        9400016661783659576,
+                        11657126901867969338,
+                        801650705830633183,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16339786361047664380,
+                        7864280128113355801,
+                        2763890727186048216,
+                        593418920233359679,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4320133625868073603,
+                        9717264474878425893,
+                        10053186365680443810,
+                        230627671609517686,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9921621752494173128,
+                        14672478292247380684,
+                        6353657995566320146,
+                        910612250928644384,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2270460761251297684,
+                        5880256801618912934,
+                        11813867009179921203,
+                        504450246010613558,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4784886159061498438,
+                        15832273876491168826,
+                        15288268365436649853,
+                        77820993382707232,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        785987207419538863,
+                        17874139667895012133,
+                        9021200274944150977,
+                        999046350048527169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13516743320662866782,
+                        2998563124587039719,
+                        10728169019555986226,
+                        545308817989299683,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2547294608180454743,
+                        2301656001887159982,
+                        16233732229436183158,
+                        724436813126802980,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18101016883389148875,
+                        2325943489742850892,
+                        7047347777852309286,
+                        212684399744397638,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16184372351569879825,
+                        16790873766901952704,
+                        12460725781854669110,
+                        1027087170352243000,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15767440493910292129,
+                        17298707038557549857,
+                        15181997451957387431,
+                        1029520549146886360,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2551393392909425766,
+                        17449191500977727357,
+                        10794602180319518590,
+                        1325852761709340685,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8605352119982522112,
+                        12956271251334396109,
+                        12817460558950604540,
+                        835191421208875255,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11571891988694373716,
+                        15426652648619243591,
+                        12033717572265955587,
+                        178021348345336758,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4549905168552634945,
+                        17904878192630862866,
+                        8147007046576131902,
+                        877517854624776276,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2629701989611869143,
+                        14008020440069448225,
+                        6862446911965130219,
+                        431679656024217630,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5457597914656391938,
+                        1345170695900227568,
+                        12617270638527557500,
+                        775690619576715287,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2271218728850194350,
+                        13613298390939127405,
+                        16913676874376322447,
+                        83579228753243622,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2424081135755678471,
+                        18003093653694573698,
+                        11598454043650720902,
+                        1155761722027404519,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16544340868239442433,
+                        11328938050361637957,
+                        12347314459589889825,
+                        316583637980348290,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6031831246155284347,
+                        6535314634257510341,
+                        17383569737360962183,
+                        1092203573217398258,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16269580143593366289,
+                        2531380659005061075,
+                        16322202656328027364,
+                        1072229272008898770,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17192154055306843654,
+                        12054008820766813750,
+                        4873298713422429431,
+                        633331313573266038,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17955122485797181936,
+                        5925529301073622356,
+                        2684518604340047921,
+                        1139934039127113618,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8200372661852164678,
+                        3981166042713568534,
+                        17639986205798688118,
+                        1165975764187885276,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3929377507270710018,
+                        10079494014205565586,
+                        10463275379816220824,
+                        809599759702026588,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3872448055381489601,
+                        9490087648213253131,
+                        17898038227376572193,
+                        392336160864878053,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1187498891608569533,
+                        13203002305272577125,
+                        12537057747590515836,
+                        960241434474972165,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4624653433863667282,
+                        3170333389988121314,
+                        14626016197979164555,
+                        1091916507332795694,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8063530108462413993,
+                        5167282110266809707,
+                        12940539060548459199,
+                        159790023818852121,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12865057616090707162,
+                        14527549994988357275,
+                        11373072126221761173,
+                        784168097185483792,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2088418515351969087,
+                        6293258521434745485,
+                        747041136744351393,
+                        517145344991090160,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7264458869893081500,
+                        8522841086338305882,
+                        18142217873999799542,
+                        790427869512613728,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14925757020044744963,
+                        3353856741202447539,
+                        15393715783178938570,
+                        1266187739411644883,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8025397163383735197,
+                        7621225555928513898,
+                        9955122925884175990,
+                        969214056544434163,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14620580411053569109,
+                        6400357522678432649,
+                        7835117768820883454,
+                        670759720574579291,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18202760835338900509,
+                        17042261558268557908,
+                        10759788979877165403,
+                        867704241421251252,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12157509488742569891,
+                        4673099495898160795,
+                        5617776606624467826,
+                        520046163469816308,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9423039706533555241,
+                        6303502419470548488,
+                        10790786823938266772,
+                        131943261117593218,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        308570220088454790,
+                        2023623573614920891,
+                        12051168786273003571,
+                        302296106930941819,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4129374959033831458,
+                        2345461059363156607,
+                        6616981751189128920,
+                        930739664108676569,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16152162094513330550,
+                        6157780768071699204,
+                        2798632631732259678,
+                        621080815471605842,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        287605307890197421,
+                        5675236924065296104,
+                        11286976349794540350,
+                        1134510132886868322,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9792356196647841203,
+                        12853993906150536827,
+                        6398084595618985304,
+                        385009593918113620,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15849935030917688356,
+                        8441468308462568279,
+                        18422774089184957617,
+                        386629255699794665,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12477283350297053591,
+                        3164786034269043488,
+                        16456797259572485282,
+                        985703145470435774,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14673324398335360472,
+                        14115108571724218377,
+                        16135477002364597116,
+                        1329446108989841085,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17690378389449238864,
+                        14346704321082244903,
+                        4743060527305642141,
+                        302830811171322984,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8160774808809865823,
+                        3371338297007039020,
+                        11623128217958458139,
+                        1203123663580723174,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18240483456054512723,
+                        8695767511944801173,
+                        11336851208215086006,
+                        702451378093113995,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8488804764219995842,
+                        5681977204239376172,
+                        11481324684447635590,
+                        329538264930135728,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4627894771095915959,
+                        1443713413044367587,
+                        8736698154601661961,
+                        1252641316713055036,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2269945061622253413,
+                        1411451591111036504,
+                        16633477856942124113,
+                        1130621213408631415,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4612444736905836495,
+                        13424430516045004403,
+                        94992010433610069,
+                        590777487894806002,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5869374707334568796,
+                        16123644536889026627,
+                        7457695458810552412,
+                        259898136077245976,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5117512213646684746,
+                        15212869937603929946,
+                        9056506481455641233,
+                        855110505003713708,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11961085570943988488,
+                        2843344492596758129,
+                        11862079535040258553,
+                        69459149413183259,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17756703757740816466,
+                        8683139872775578549,
+                        9492747124110695779,
+                        694033003674277605,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12668141262453784336,
+                        13784722208477598316,
+                        14725856499686932792,
+                        1042543601708443875,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4610870156323084267,
+                        16901295846106804466,
+                        17719197551947994365,
+                        1263332520828894891,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7865504422566496500,
+                        8917618749683223479,
+                        17398660347773312812,
+                        511980712975524039,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9795539872590273157,
+                        7457979573546614647,
+                        15480733610807804582,
+                        1314947213931393839,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5901092590140997782,
+                        7460593041363098935,
+                        1666309994437873951,
+                        842524880297417067,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18386888851955394715,
+                        14429571328791959585,
+                        14132526459797174773,
+                        473684854257573516,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9987451754185475177,
+                        13663737401414163159,
+                        2626760708833154023,
+                        837136897660901216,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6766016799855555238,
+                        13350298198637782970,
+                        9586193159902682448,
+                        946996991959331423,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11776529036524496124,
+                        6674091161839573572,
+                        8371003693667693116,
+                        588579681795544152,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14625867996270089665,
+                        3414716629203764420,
+                        1108218109235415166,
+                        935405196520590082,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10640106982073933912,
+                        12645411467203010400,
+                        10052068589513258877,
+                        451784079591017540,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2903753282264587117,
+                        11651392212767736306,
+                        7945604361691402813,
+                        697073992173303147,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        810055310345817643,
+                        8180440514044128369,
+                        5994606589293257257,
+                        1305769967314936370,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11351463005209516336,
+                        7328707146360220372,
+                        13034003007657091994,
+                        848523205794815034,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5795176340108636057,
+                        1523314395151499835,
+                        17316865638957134295,
+                        903455440049477547,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10368477485827240655,
+                        7905141248561688933,
+                        7911366719215679132,
+                        282907007793806631,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9011596084142233833,
+                        3091546382135683776,
+                        9734059682984289674,
+                        174421568484931222,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        525352772142197745,
+                        766282788540872300,
+                        7742645792487064181,
+                        1020531102201868878,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6758424071039372636,
+                        2893268573491070452,
+                        7144942847223905477,
+                        261125491959446276,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10562276672459145926,
+                        727321821107779654,
+                        7460072530160873852,
+                        1171425464076152528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1257885706693918119,
+                        14108038384708104654,
+                        1546106511399391191,
+                        1061447466246248595,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7009754371463731722,
+                        9660392382900117220,
+                        13387671954641214272,
+                        1182449287180869432,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13423234183525439689,
+                        18316258615734735785,
+                        15336417370579677450,
+                        10704917942220263,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16844003859157962178,
+                        818300793090232277,
+                        18331192486574909395,
+                        589100228017590444,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11102797181523527356,
+                        778734631093745050,
+                        13130828261258288720,
+                        936163456928043686,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17992358940864620464,
+                        17606366537167162093,
+                        13485774027813564562,
+                        639768155015428824,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13663794103089097575,
+                        742350473638723039,
+                        13649144072648734352,
+                        1203481195390741654,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3487686817483206187,
+                        5803361207838724604,
+                        10146574632169575301,
+                        661383227487177682,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14576332045347341212,
+                        7541182783118271384,
+                        7614254569273623782,
+                        436147639716884482,
+                    ]),
+                ]),
+                Matrix::<1, 16, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14523524228360120504,
+                        2807949315092488746,
+                        77037999492493833,
+                        196006769452104978,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8139372847994215793,
+                        7848514778089228842,
+                        16058373806643791522,
+                        439527445413015675,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11104761769019034855,
+                        11084701179968084101,
+                        6084996415645992544,
+                        754716717868333573,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16625086659247933641,
+                        10998425785214471811,
+                        10940694460370868362,
+                        1113805760485792019,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8663815083072522416,
+                        8144537262375227587,
+                        14961579949833780738,
+                        1070027954165063085,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17798896780266719589,
+                        9630060178460954418,
+                        15571577588204477572,
+                        393434227001199164,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        936557191652009375,
+                        6458495796817482886,
+                        10158718165914969808,
+                        1067352581308004191,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7141138701792670342,
+                        16311518916169974883,
+                        17316710802405962079,
+                        594975573881341556,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7753291310040421055,
+                        9878280926539576544,
+                        17363552228509617690,
+                        956140900471059654,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16385952287279500699,
+                        18130327582475516099,
+                        14172015138999398899,
+                        1116329102150887590,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15270643235446718040,
+                        428459267602243267,
+                        14462083130735071170,
+                        658000697229731558,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        888157881475711300,
+                        12699936570620279696,
+                        15985025556378779425,
+                        1344016500027039082,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14877491119920434134,
+                        15611589831383796982,
+                        13559506259008177130,
+                        121221936511038955,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11399808952907165059,
+                        13609953780702274284,
+                        6398711703671637664,
+                        658023953903049194,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4348404768635539708,
+                        17842003547202773419,
+                        16824928098794917121,
+                        1135565689354179511,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14124933315418764490,
+                        1638502775857516122,
+                        12212003619576740196,
+                        512431893292214394,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13075701118111638000,
+                        10741433203584197087,
+                        17921229055756635145,
+                        256574013720845599,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14054381381872118132,
+                        8603112955308423980,
+                        12739147348695443246,
+                        137047403898055090,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4562309381723307366,
+                        1895458783403419785,
+                        4654864263220378952,
+                        463138252082145316,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1071851773156967290,
+                        6482838276101158884,
+                        77482839816446721,
+                        134634348193217550,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14992751840174826997,
+                        12792661577185486108,
+                        15888904412983263693,
+                        1276352662064716426,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6153006276771092106,
+                        15769431845143720501,
+                        5579312526305770534,
+                        916160678905880619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5593209658946438208,
+                        14467738203776034545,
+                        10998040510423545571,
+                        1149835491928142178,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1846163777154618697,
+                        16092807644264104222,
+                        15301828073462509039,
+                        84813325321182053,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        123322753904278450,
+                        9389705752971514391,
+                        7973607612754258311,
+                        637092870183426147,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7030967341323588116,
+                        1897759328280586130,
+                        3321549888248292433,
+                        1053571471475563380,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13042059283265089666,
+                        17087687016009031189,
+                        5761017234523266125,
+                        250036199325094301,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2384831484982093493,
+                        11868299288856100828,
+                        6901408464090110069,
+                        139867023176814101,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1126127459403478010,
+                        16889675225471694813,
+                        7630901738312878329,
+                        775674046624052601,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1693981273286975885,
+                        17741853536418548418,
+                        14383095078774558408,
+                        1124272912469026663,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3930953962061887819,
+                        16377910513443795213,
+                        17455399696205138392,
+                        851369714056733385,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5270913399222227849,
+                        13537107687428303390,
+                        702553057554171337,
+                        1171999140187714399,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16699798259250539823,
+                        18248041563998304460,
+                        11966125590504599840,
+                        1276675948423120270,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8624516040844667507,
+                        4008092580189741526,
+                        15573243355001709517,
+                        547093644539721766,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15468019287712854761,
+                        11069852172754141467,
+                        7018677214363956475,
+                        599993076146089668,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5207406036394573893,
+                        9179703418555740502,
+                        11240247370484918265,
+                        972743356344961968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16867207869193570365,
+                        282288296370770043,
+                        1280453574280508855,
+                        782929663355903587,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7641233080796303366,
+                        8389470829150412294,
+                        2587776162172428193,
+                        515790837035199278,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1022654983419716857,
+                        16024576487884355860,
+                        12470251835147058485,
+                        1331159131858825144,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5568682588773228309,
+                        131706804250914179,
+                        3917583969109195199,
+                        317046483096355012,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10876819017876579325,
+                        3782628952948170632,
+                        10427134214686661549,
+                        1053382801100426386,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5396762837075760360,
+                        11903959110675648393,
+                        16867239408541743321,
+                        378562793112055357,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10671020745352420370,
+                        8659048517450786433,
+                        4307015287253437238,
+                        1065847873456343837,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14251037169144287491,
+                        11504992447807966777,
+                        11928665208624060103,
+                        1034191278494363064,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9615211070267575242,
+                        12422407145477755808,
+                        9266697404179236595,
+                        1307791640119261985,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7927150975824639690,
+                        8930529787953831205,
+                        13726903632734853482,
+                        1275404981199027060,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17883894864400217953,
+                        18140700884097387980,
+                        12562349684135336153,
+                        364326984679069535,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9695716298086553548,
+                        1285752106947937178,
+                        13548004829012343102,
+                        501739391129545198,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9982694229358495075,
+                        8439720289826850821,
+                        11834489090062425505,
+                        501694145478979792,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8803997570241889152,
+                        1479435086768388293,
+                        12429983409765923854,
+                        828125308687810449,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        236350894567486002,
+                        15465611711497234588,
+                        14907403442906736188,
+                        611593600704206863,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12081927089659311716,
+                        16839852287615385518,
+                        7070272773085250896,
+                        813149095277015657,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17331953861691909396,
+                        13952449974245551574,
+                        14427805424062177499,
+                        243882645705749929,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15576978682813740950,
+                        9958605938471258792,
+                        227111519646774191,
+                        1027976486622329505,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6396679513496899897,
+                        857852135409437768,
+                        14050083771307634584,
+                        424767298265416060,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3075627197848284391,
+                        10742375183428353056,
+                        3111308792222019279,
+                        665539366077026865,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15461472937850415865,
+                        4668482429628078071,
+                        14148451552705791669,
+                        1105937262704553315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        45580851255736667,
+                        13746586017523011654,
+                        15282676937030837485,
+                        869750997906820220,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14452928133837412288,
+                        2264533016219720723,
+                        8308403021143899101,
+                        414677937504249869,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9569819280419470882,
+                        10801991604844134371,
+                        8101799642641690645,
+                        611668417230245159,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5140395439531005742,
+                        3888395315427601960,
+                        15646573361022649468,
+                        766346468133031042,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8148753052047051565,
+                        16168101527902719585,
+                        11752055588102793468,
+                        306184356490684848,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        165501858638679719,
+                        892847817877839162,
+                        7352747053833247458,
+                        391574195875689531,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7657140623494770231,
+                        7272579691922034572,
+                        16819746449530146741,
+                        764248935532434274,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3533084299652856351,
+                        9130671528092463663,
+                        286420877041290911,
+                        932700379215049437,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8672092530014090971,
+                        12948002132968387770,
+                        563197040031398788,
+                        980051788715195949,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4533398755412817783,
+                        18410812149481688717,
+                        15777192627515794004,
+                        801349557335698405,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3802422207757956979,
+                        15658164776753404670,
+                        240414543033819290,
+                        3419965462338890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13957029832179626752,
+                        13128015352107398603,
+                        3406022022420881744,
+                        464429192178077590,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13722954165892401606,
+                        3814194395705553224,
+                        4836655077488801202,
+                        780024800959025102,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4547272972113463324,
+                        5240863966604176338,
+                        18220684682106457821,
+                        816956321456258529,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11863898791851757805,
+                        14611872668339474701,
+                        8038837359990155118,
+                        185355968984918449,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17852313882098398397,
+                        14392941135453003974,
+                        1694781599704816163,
+                        197343586439636072,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3258076513807127389,
+                        12391402360461478489,
+                        11409060536590898309,
+                        1014282819427597679,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17816290613093659977,
+                        10642838708075976262,
+                        17013676714797957492,
+                        1331960500471720896,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12847161957635877803,
+                        3628646028718014006,
+                        3097691559611452179,
+                        566382582729629773,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11597294408488832929,
+                        6332161016789807028,
+                        1250461315990947104,
+                        460733741024181483,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7071611921178555115,
+                        16902225975213891302,
+                        3791195064797484679,
+                        669513387347780836,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8359973408909385214,
+                        6555690590687030585,
+                        8150864553741806931,
+                        752333481577116575,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15832364121979054340,
+                        1945591763983333310,
+                        591473541981520593,
+                        454810836186610819,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10932194904088530073,
+                        10714652935679560140,
+                        13041323138488728542,
+                        436288551590847017,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1696247101592651339,
+                        14876878444785885749,
+                        11386799957232221057,
+                        285672669712925003,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1056776387457311544,
+                        10962583120311192890,
+                        7110933172859429511,
+                        1192472348962288024,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12117835908187355188,
+                        1489384692113011274,
+                        7529621500758727702,
+                        259699554682763771,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5494706790728116827,
+                        14902745623044977672,
+                        1543240747404187217,
+                        1213590930715433618,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14933197896409669655,
+                        9525038643071268645,
+                        10624890237694928943,
+                        1141416075999333753,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12661128988354051661,
+                        13182787579324894108,
+                        17313805578259916388,
+                        1229959829844717579,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7230282213507429837,
+                        9300881246245153337,
+                        15579133294942618747,
+                        765060527671146031,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15680574952484508479,
+                        782830600833581785,
+                        1651406427694207307,
+                        1019084245052303327,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9246573869962549445,
+                        11744765920425466859,
+                        16442567081496214489,
+                        1224139704973121051,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14653060860664563753,
+                        474366500037879844,
+                        2398667526975694395,
+                        623950385268704300,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1883061831423552793,
+                        17215552694992055562,
+                        12661254469668207979,
+                        751615197637351424,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1522476757878745350,
+                        10947989042145069668,
+                        5348803656816592678,
+                        1016555794224099708,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        51577310803243446,
+                        17706951325892370626,
+                        3756923010485892283,
+                        1221947098299734974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17617273667049129226,
+                        18430729695269366325,
+                        6455765088568344811,
+                        996157916592388314,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        276373529589059513,
+                        15892905728484895846,
+                        17089405619292460879,
+                        902905228869661349,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1804043213815144905,
+                        2109973331216260743,
+                        9807029406142856142,
+                        901753634756302051,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11039857788621192967,
+                        13431674300783208768,
+                        14735147569389056271,
+                        458849774200541931,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1512657287443664106,
+                        15891343987454756217,
+                        6114608226988952363,
+                        1015168473347753791,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5501581619893477586,
+                        8358747079332845594,
+                        1627221545369096153,
+                        836679375221920508,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10975381056304642928,
+                        776654648058608037,
+                        9898605937917542844,
+                        682967332456754604,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10421063809321824759,
+                        12908054087040684182,
+                        15771452871732967396,
+                        858177427408904692,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4281329446494268626,
+                        8867436514330794107,
+                        6611295609695780024,
+                        891235139279611251,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16099535783388279391,
+                        16383778330718125003,
+                        4457692200206109737,
+                        558428047132572863,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13680359970096894488,
+                        17910008854684030110,
+                        12040698475760357999,
+                        597787741586983152,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13524866920346146736,
+                        1993567719927644294,
+                        12657235563286803748,
+                        318197144958527881,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16612545518555464366,
+                        1955322655644611020,
+                        9577690344092561656,
+                        1299023239699183970,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15513861011353607952,
+                        4543140564294172854,
+                        18180598634553348759,
+                        548146184360165499,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3786306788992195232,
+                        2366825150841378424,
+                        10194146668373627876,
+                        648591792692261782,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16262203501895847964,
+                        10371786736323678158,
+                        4722697268328489975,
+                        1055483067611526298,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        490120418273045687,
+                        13139151956815780950,
+                        12197376552767593182,
+                        265638404860853018,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11875944840050283387,
+                        9669862162493891513,
+                        17648711597816625198,
+                        518480276007565017,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4059686260536774732,
+                        11798214515029576224,
+                        5609981665672658096,
+                        729028891450170046,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5656056134868062890,
+                        10813966324363071089,
+                        9897176859862207889,
+                        68227131310207593,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5001307393965533856,
+                        1018124157937992143,
+                        8066150456906888324,
+                        741223098251410994,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2775696219085088579,
+                        14284750512859370997,
+                        16619610405238554421,
+                        70398024994513416,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11099576611786122212,
+                        16256928865840658173,
+                        11193374568222205818,
+                        593331691490552585,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4468174237958705407,
+                        2096567221388157716,
+                        8525924800177602254,
+                        157940737002421763,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9409122870234718024,
+                        6744127489151147501,
+                        8443186092846368545,
+                        208093270908839568,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6641829365406132836,
+                        10392812241676097378,
+                        3299641484259457321,
+                        1072602028662608167,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4583260601788072379,
+                        15700015212149313137,
+                        12927371651962872175,
+                        1173666487543841074,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16495280528197690031,
+                        9013934438579945835,
+                        202316074195454448,
+                        6918697625383784,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5079361694921648253,
+                        13119135218334920708,
+                        18194425390857364547,
+                        845054514783452299,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2423074593754557172,
+                        9377215020175116535,
+                        3670572316674805334,
+                        1127682936536706297,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1256372238991137120,
+                        5337934613772879648,
+                        12161442793871636739,
+                        1333747843261805987,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1926039353056127097,
+                        6767903796821285853,
+                        1661079124782567887,
+                        1184433378427098890,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17925188380476922930,
+                        15598367387833446043,
+                        4003949104175965777,
+                        396836817320709563,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13442280902205569717,
+                        53488288474121161,
+                        12818060027309048291,
+                        290935056576454481,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2648906638040205077,
+                        2411385673655363189,
+                        12947722211185016033,
+                        524477207094102540,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17361384785008003660,
+                        5195167442710889451,
+                        2837588320793171369,
+                        1088544739772070401,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11234092647365999872,
+                        15295670622360412850,
+                        15760237864504532800,
+                        1286149658857880529,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4706427179420698427,
+                        17749326894756259093,
+                        10161127024080091411,
+                        520874644689959762,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5154378201662726428,
+                        8946737499493207278,
+                        16620438732938369676,
+                        505351126409010677,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7566879049964765305,
+                        10080358731090759754,
+                        6829091189709326737,
+                        1151610561736343361,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2660549529598979930,
+                        15612604765260208088,
+                        365857420919257665,
+                        202162976490996970,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5550322460889100633,
+                        16972115816444892747,
+                        7443152431279070785,
+                        191864353444585227,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1451145091069549374,
+                        3348150848695906052,
+                        17997508905839975144,
+                        414534749990877413,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11258255731271896830,
+                        14716607225388557663,
+                        157219714968881688,
+                        418746625502847141,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6257980078328726815,
+                        18054630834953050914,
+                        16621820011868540647,
+                        91894319840724816,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17696500407075369308,
+                        7895265685919593277,
+                        6552084163760655610,
+                        525809544671380111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15599211411314553788,
+                        8154429310174141762,
+                        3167218001302864411,
+                        438783729680935200,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8545958793433088883,
+                        12576996139996168899,
+                        12288716861435685883,
+                        286309084116702280,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8515393322940889072,
+                        12676206707923314295,
+                        16479098678929042165,
+                        341965717920513596,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        590014368234697743,
+                        15513504838637429995,
+                        17577251849042177824,
+                        64522166891904041,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1041894743128343968,
+                        8129491614132443393,
+                        13041425943628232195,
+                        1288098171610110525,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14256187116141258765,
+                        10737789832887444602,
+                        16950444836063954309,
+                        634530523073607718,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6910352360038375492,
+                        13705189640058275368,
+                        2799680725723694303,
+                        111406187656900351,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10914912308341562733,
+                        18304775672373854952,
+                        3691680943273122454,
+                        280283972668817844,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17596928607087862252,
+                        15289530349933083590,
+                        17834240080896015311,
+                        489513104803926187,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12692182348738266771,
+                        2554188800104702507,
+                        4741074135107752862,
+                        9938061881227763,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10330387568796193186,
+                        17607385415947978935,
+                        7116896836697641618,
+                        707891396281444635,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7615047844429859105,
+                        7435574638338891999,
+                        4745959336468789936,
+                        991240603967116813,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15244615305734677532,
+                        1005447614579478679,
+                        11992920226720795356,
+                        100705841843884266,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16118944949519587532,
+                        10838445148348594074,
+                        8350845565515314744,
+                        823966126166732869,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17903190961420179061,
+                        1337879604244959087,
+                        15271241686925897420,
+                        1307993499446845480,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6452465846144535123,
+                        18299952253140420491,
+                        5428943358850582298,
+                        324670383544309196,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1639387797523188219,
+                        12187650335107844093,
+                        4337755530128392592,
+                        1095637193318087571,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        661106062390743399,
+                        6131913013294257430,
+                        473434975347574865,
+                        715003960738575962,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10952501209828541349,
+                        9229211599070539650,
+                        8951975154827381848,
+                        1226312237570195655,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10812787846289392626,
+                        7438031521625563423,
+                        17227615253824980787,
+                        1108803272580483974,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7740920876792774441,
+                        18248430132094659579,
+                        16245116580574858633,
+                        1045455259714140295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17747026872788932920,
+                        12546925883126165721,
+                        4265000384698545017,
+                        1195871998260780071,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17152755332266336730,
+                        14812369448976521179,
+                        1281079035128724219,
+                        1037473810935158914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9115920238276415488,
+                        13323822392295085499,
+                        11912965882352179068,
+                        40042055719583069,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14324286394935803476,
+                        5599887837173452762,
+                        3995560977376940430,
+                        1290224875308619970,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17400536184734142834,
+                        10555529420299946367,
+                        886862369229380475,
+                        906283897471904953,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5294820515622613581,
+                        15903710121146674624,
+                        16440250049624202100,
+                        278904155356218974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16302881859304218073,
+                        16005432338687822488,
+                        10320554806062311819,
+                        972356503182335256,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3653148221198081182,
+                        6600817758273779452,
+                        12214182949549485068,
+                        1311933566650221485,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        530023911233569058,
+                        1389516343766620926,
+                        9431313880655161663,
+                        544401367933440824,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2651089114936119895,
+                        5126686051053840994,
+                        15338256771488983890,
+                        432536835761990399,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17824852510594436887,
+                        16755717264443950592,
+                        8625424550341303151,
+                        974876743599913251,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11963133164111163691,
+                        4674065818634786139,
+                        7811430627233440070,
+                        1045941611009310819,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17938829513524895112,
+                        13764522938329089299,
+                        15527626799801965846,
+                        1323620074778685735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4628064233428578642,
+                        12140182097792441791,
+                        15766358265424005219,
+                        808893047696791149,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18098966972536932713,
+                        13523220060307762391,
+                        2017632063554466499,
+                        1081431252031116087,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        559796063609817059,
+                        10232396247802260539,
+                        4819457539919040082,
+                        589467900464748244,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14845522478218816692,
+                        8223026167921387846,
+                        15370385687616680826,
+                        281035104981138225,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6824493139531988698,
+                        13654962651621866629,
+                        13511545382373959970,
+                        949266125547249155,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8412355353871594058,
+                        2668827093311086884,
+                        10900627354509925105,
+                        465783891745154873,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5582354852962820848,
+                        11784830733614277170,
+                        9771414899660098301,
+                        867163303939352273,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4453607897676495563,
+                        5887516454791113110,
+                        576311096525039067,
+                        986580591570966242,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4467117499133529310,
+                        559802683769341830,
+                        5809857559849887233,
+                        170411135912101408,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14217603331427399471,
+                        3802501397952312531,
+                        14886499204208891870,
+                        429598612609076091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11270984842691363191,
+                        15481921217504814031,
+                        1731036665160651438,
+                        1111196965178267904,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2573355019405722838,
+                        5319859537866403158,
+                        10507653681535248756,
+                        1049029024606467388,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11273334584630251664,
+                        14752944689562293170,
+                        11143177611542958544,
+                        675924733537458658,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15459097800432882027,
+                        1906350686979733601,
+                        1919526907075647007,
+                        1274039596113431217,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13555737664952179174,
+                        16616906963006534531,
+                        7753376157941216745,
+                        434439921299097410,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1197916447808448805,
+                        5388587352285527719,
+                        2737327640262625332,
+                        605529060297289177,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14813128491407699783,
+                        1319470709764241584,
+                        3390109187489643578,
+                        890210678956426722,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        320088739714054283,
+                        9133921990951855606,
+                        1383500146267788412,
+                        694217111971331665,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9378976234942407750,
+                        12733262484197537628,
+                        2670975228114512298,
+                        95488327920537742,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17350461113913388899,
+                        3359637328060604152,
+                        10850714571420127404,
+                        1331152129418476361,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13496613391168576632,
+                        3081728129880379584,
+                        14475256230662344826,
+                        441720006449755357,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10663762131648741170,
+                        15600933613185679883,
+                        18445445021017360040,
+                        70699893023469974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9001093038951205334,
+                        18027892465821951983,
+                        2555683633949675952,
+                        169364479959389161,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        47275113553332693,
+                        74944996671123967,
+                        10628184406484870421,
+                        838402802850177602,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8732860445252075656,
+                        12225566785301602312,
+                        7771918428529316650,
+                        880919700196343594,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10722656445955994572,
+                        15048528141038691249,
+                        2792045251822938941,
+                        1156995289626724128,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7399250202471858476,
+                        13396939109875713854,
+                        16034697946278348742,
+                        565531595802435275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15305430129995306111,
+                        8331710627217302645,
+                        12337595374994573223,
+                        955537411142296406,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3549635789841367483,
+                        5070083612371552736,
+                        18078462766903354636,
+                        160632338595487593,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13345215971738170292,
+                        11397822846937653676,
+                        9469175278678151113,
+                        704657948296500812,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14966404590003658432,
+                        3286472083750008482,
+                        4152086993990005293,
+                        314899684043702068,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13026315462480573483,
+                        8036284923808192213,
+                        4626646576923624293,
+                        320229971232075182,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5266020365682030062,
+                        17350704819857377954,
+                        9600542689890888043,
+                        935328033040508604,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7017667145883375850,
+                        1259994529950098847,
+                        14564617077361657506,
+                        1201958729943250150,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14986737633449241237,
+                        11409807175193192256,
+                        16018774079972458834,
+                        946874539425575558,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15068455444855017816,
+                        16964825114630953086,
+                        6647217965759053238,
+                        278946109418715784,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1220446444530700001,
+                        11512597834845576365,
+                        18141981492433837775,
+                        1341141963180897967,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13875775183371135089,
+                        9862020689190361590,
+                        12176821657508648993,
+                        715530582253578038,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7077437827323196639,
+                        7145247274465822031,
+                        7829065339781222954,
+                        122940656431364570,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2982240420140122297,
+                        5423539826191464604,
+                        11199331599091140071,
+                        882698315093205355,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14034031068732508432,
+                        13916999374779228913,
+                        1247054812392832336,
+                        986399889132892874,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5564031949270633388,
+                        17058470030746514763,
+                        18059393698882047675,
+                        333441317403113538,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13106287956421916985,
+                        7024046675022981987,
+                        2539763803471326577,
+                        410862762670662474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3334130622810735782,
+                        5168617635875728474,
+                        15291546249005143891,
+                        1018739705462627956,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13624422534255795660,
+                        2962737480775812540,
+                        18397411008007043160,
+                        1063921134254366037,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5083570094227719150,
+                        5119471299105048381,
+                        11029763731279600008,
+                        63870453744913152,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7622739423210139762,
+                        6479737327164843368,
+                        4842570789797613749,
+                        450652371632422502,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13238032759581830729,
+                        10211987532024309692,
+                        590331741369945631,
+                        669649615435141039,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12905334740662454198,
+                        3219216127087468692,
+                        1706423302232289054,
+                        475261867490096172,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15948740741673249317,
+                        12201812739100374669,
+                        10608101553078673111,
+                        1212977259427753238,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18342029517867639444,
+                        826080058337088682,
+                        12398679853299323493,
+                        1017819140108845385,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        448113372656066594,
+                        8597450320239817497,
+                        14174959096925775153,
+                        56084228476786153,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8354109631299277480,
+                        3406560101285062453,
+                        5983914482012665979,
+                        1286596137315536314,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9226549402134409130,
+                        16147219986698375797,
+                        431458296254253469,
+                        1325849267666956251,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8811295166702495012,
+                        7843841963012540336,
+                        6056593266778801455,
+                        689311973013165786,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15813820942890815716,
+                        16835493583134198511,
+                        4034980245030597198,
+                        443464780519599638,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18323382291588316985,
+                        4473394486204762062,
+                        16223695026081282749,
+                        391430759850850016,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4374093128819650522,
+                        1419750411559520228,
+                        12681650773287611358,
+                        607293120705697646,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17163781023617622809,
+                        10086178013389239624,
+                        3727490560335882211,
+                        260801932399423381,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4662362739100589750,
+                        1327977600629605143,
+                        384417422695844359,
+                        643520448834279910,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13258908005777921996,
+                        7485460757994470118,
+                        4790454926025029335,
+                        1339170671927129070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2676907287999160025,
+                        1076372806335932972,
+                        5759579348596205711,
+                        696438269113650625,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14772711958597925187,
+                        3133051321445954652,
+                        15682041521376192930,
+                        199147014365071403,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8662797440686858247,
+                        14768838744180134608,
+                        2116399614632369150,
+                        864819142793237125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        457883952602377369,
+                        15281203723406411447,
+                        16026619922878420464,
+                        1120428413368818046,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14027676165853369724,
+                        7292628096712481423,
+                        17971584097019564196,
+                        607833766990645571,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3051896807481681955,
+                        17860674750344342590,
+                        15901235613453728783,
+                        523606521778656201,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13033129355261913198,
+                        7729356175933418828,
+                        18372128918206003007,
+                        975208125969340918,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3836587189716188789,
+                        4457463403083038655,
+                        4067787087180760382,
+                        453823402736880522,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10770367887697964529,
+                        1042656595592952123,
+                        5494423201955212351,
+                        1237529596104279342,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16942425846088634969,
+                        13430081252002621110,
+                        14414201584650581120,
+                        342509943136541747,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3144168440812458295,
+                        6145108702690696126,
+                        17711360217515752520,
+                        624020462410267532,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1070326624013355881,
+                        4959818690382920835,
+                        1022456865845653235,
+                        473551669544314934,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15519970838206266724,
+                        11548558022632360370,
+                        16558748665362579314,
+                        71116498366591611,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2536226400869830099,
+                        7338289036646494128,
+                        7535551736380023350,
+                        1109152563409302732,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11046007259416548709,
+                        12829109604529226145,
+                        9352601638640220085,
+                        1159013910067100341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15702014471084344390,
+                        3587048239987437252,
+                        1003356853402909411,
+                        512840675542458645,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1802731144606082337,
+                        10518500715680236005,
+                        2432604155934533284,
+                        565820300494458063,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7008009252409729440,
+                        16829930393240019919,
+                        11745563659201371513,
+                        364771717328171418,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11695890436739674543,
+                        8589861705957247433,
+                        8702815813077810423,
+                        338645154361238904,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4710025159449351151,
+                        1236318335938284465,
+                        1512353422262435781,
+                        60387171441883267,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18278475254935900245,
+                        5604961522359504545,
+                        4957534071629043377,
+                        326929926108570723,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17561187382545770162,
+                        5166554186946855773,
+                        10980525666795962342,
+                        1233298178296020778,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9829582232124332373,
+                        8813266648791092497,
+                        7509157622781549167,
+                        938837746864390598,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3298347183223947175,
+                        4194948110764277327,
+                        5145781575404024955,
+                        280717838484430270,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13924196141429707885,
+                        5264242030464866336,
+                        15331921595558447408,
+                        59287850066119178,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14740686872600185648,
+                        16520019104194077875,
+                        3640498802898650861,
+                        836961071145734934,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8384744097958018665,
+                        6195646790967174743,
+                        12383826110400352658,
+                        374375590447274049,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2441094493939346822,
+                        9392297778658268540,
+                        1327249629088094084,
+                        861274533780902941,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10635768664734972428,
+                        4299862355525347756,
+                        10702072344661351771,
+                        1272257078576993118,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12559375176779767433,
+                        14168670663754925439,
+                        8422607605812192538,
+                        1109070379551743460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5219586170508149505,
+                        8078039469348399821,
+                        14785111817803753435,
+                        767374342987981650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4290279604651810137,
+                        10373415993706815307,
+                        3227748702545065044,
+                        683852902115339414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7408793710138139843,
+                        14860989694854279011,
+                        8263517930926968590,
+                        768041053618293435,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3676000349525793552,
+                        6180444112956854975,
+                        15369765362748745942,
+                        111657202256332954,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11258831871377454331,
+                        10977916377465910406,
+                        12307091438737000500,
+                        717822958399071495,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8678837010068986066,
+                        15008580708110964422,
+                        11821066726306748948,
+                        115738678319194609,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8479800603735154809,
+                        1002124376882788834,
+                        7151955438050754708,
+                        816678104438275634,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7741212955246078509,
+                        15019921574727855810,
+                        9926074840418601761,
+                        745723986129022150,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2706822246290255586,
+                        15744858036844431279,
+                        13802521050306895370,
+                        1052279525004761813,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4372177262185400522,
+                        13491949790188046211,
+                        16482684895353218820,
+                        77897629808535005,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4348923717460961089,
+                        7990746003898181858,
+                        7590905445302287102,
+                        1121059750858455018,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8500417831389222068,
+                        15887590683095564534,
+                        2094416928103884327,
+                        424204227306189091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8919943095669573642,
+                        11895974945066954755,
+                        12903040719412826196,
+                        1186585241303737888,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6648282347839267207,
+                        3594995825784268850,
+                        17971257568906625072,
+                        1013727456903407014,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17449497016803086361,
+                        17085482796179097673,
+                        9733045992042935832,
+                        672399581249687111,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1420425143721304461,
+                        10010047528836191364,
+                        102098202866291428,
+                        1298789385720621190,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8868963829694275174,
+                        4037061393391204970,
+                        12474204231363620622,
+                        1030679417204893685,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1871941511343604390,
+                        11732896721863333558,
+                        974533570959672769,
+                        1095165582971059869,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7676056598671027199,
+                        10641617410363723230,
+                        9389559563909314879,
+                        341684034764619920,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11132229137898876471,
+                        16128159283551485508,
+                        2750321276932101738,
+                        484077665388713372,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6614841415309300980,
+                        15383305570522150035,
+                        2365483702189737117,
+                        538433507597600942,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17741377514851960470,
+                        3857368651414833668,
+                        1820925964768768074,
+                        191382554794461714,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7279572968411220414,
+                        3127361822848226003,
+                        7316043496461655681,
+                        596976038725639058,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13334633557069889411,
+                        16543127583183903173,
+                        3426052840559034769,
+                        1328908717851984938,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7774038137759342613,
+                        11604892353448288382,
+                        9378263294093641309,
+                        6092730207129813,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6458985197205727612,
+                        1784425137822482654,
+                        4234025680749477195,
+                        677427340097622417,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4637368146265470970,
+                        2922436224082778152,
+                        249840561336451658,
+                        463764275497986063,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4217984327538794562,
+                        7328213352083400734,
+                        14815210648025290780,
+                        1125370684047995277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18305364890203098669,
+                        11507518660965272637,
+                        9164626827767006366,
+                        83500544048871868,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11333936310977440184,
+                        10068593603457345709,
+                        12900108085673749282,
+                        762291182968570988,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4849984293993896820,
+                        9015348740322768049,
+                        5181016265460934493,
+                        20838037550798759,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        258710696528296364,
+                        17641587912986890922,
+                        8591463796212259385,
+                        688108173149940947,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2799322245673564904,
+                        11876309775660981650,
+                        680222314489318386,
+                        77198568627163883,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4412976520642968480,
+                        3605609402011093992,
+                        15318878892464235525,
+                        820571212463581701,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8055600464090192593,
+                        14829897964368140134,
+                        2512939032551573528,
+                        1330940682815307503,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10834952755088754330,
+                        6592406488191654199,
+                        17783897247368095086,
+                        292725266663782637,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14707916211506791416,
+                        758191909800539372,
+                        13722359522984024981,
+                        444470334970456748,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12050630229088588756,
+                        15129163946927881458,
+                        14100497759577751092,
+                        193309163426483635,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9469008725659659073,
+                        2492702877211737463,
+                        15457274518403914139,
+                        566601105291330659,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1910272643363492282,
+                        14004473535642749942,
+                        7315531655677489765,
+                        548887168913835401,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        671641376131163534,
+                        29318356958781095,
+                        5938811745645109244,
+                        403537374333257662,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12845293225272856280,
+                        10338987492123602395,
+                        613392901822409497,
+                        1042409760308173254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3166001387562242233,
+                        12040121943673201257,
+                        9217676555436851556,
+                        598846112359580445,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        458846981572919313,
+                        16228731397226915332,
+                        6579802553630030662,
+                        70930530409100015,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13099988533800874841,
+                        18110836094769722684,
+                        3201804601862415817,
+                        831980372055865688,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2813923916880286346,
+                        9020610384538479231,
+                        301975739477540087,
+                        1329456539015428455,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13383542631770795310,
+                        11546512190916699120,
+                        6108867298496791298,
+                        357889764506602070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8971513242120617140,
+                        6964685554197261589,
+                        10635931517999192974,
+                        697452870787612790,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7759414759468807257,
+                        14329291471732100585,
+                        4259228529672627511,
+                        430958493103054743,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3404901016028503856,
+                        877516975394592459,
+                        15856951661272598968,
+                        267069361749106846,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2526791658604116832,
+                        14407435088055280650,
+                        5900819690844325658,
+                        1246916417322475832,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12264102053394759495,
+                        2366373729745820996,
+                        16250513442329418622,
+                        867796992837373610,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11524495589987752780,
+                        4362929216603211590,
+                        1346218734333249726,
+                        820327514783654678,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8835521940019058248,
+                        3943179074264961954,
+                        3241527792449179037,
+                        132002166547834979,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5924796782254334041,
+                        7377754638614440755,
+                        2551251510829278133,
+                        906283505830633659,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17350740047270949627,
+                        11116415406269507402,
+                        13259567953774590629,
+                        180904746444489952,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15762779506152732254,
+                        4457183984036622553,
+                        17659115937799169580,
+                        557491251226287790,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17764843699331988695,
+                        17020151803522229733,
+                        820645569316111849,
+                        1230559706297969699,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6702497324065262253,
+                        14749300765089543521,
+                        1538854631084277471,
+                        272471041980175137,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16516271524398367503,
+                        7405076225627723416,
+                        13978444385138329332,
+                        1097890993693926125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13910730540007587055,
+                        8738047652304929965,
+                        17579148307884995576,
+                        336023149294681953,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5190965219358778429,
+                        6343475584909980867,
+                        15037161689973301880,
+                        563721325603136691,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4360024185557056220,
+                        840976920198844441,
+                        15013973167805281666,
+                        1344149661270947922,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11725975201389465508,
+                        2756048972909113374,
+                        15542666897380798006,
+                        662995061433950943,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17993743214034271179,
+                        1660923488787120847,
+                        4738530888107851944,
+                        507821332046678695,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10375260618587528911,
+                        3658043508132922215,
+                        14443584845376487802,
+                        393180105364170883,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9290816134703754799,
+                        8358970676834745930,
+                        2108927214503226099,
+                        1117981916038802459,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5392789793551153169,
+                        753109607366324648,
+                        4519412044908181009,
+                        166628832304627758,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18111137496818419162,
+                        3124786408502939041,
+                        2956417938110604845,
+                        112310352446138557,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17599077732974827968,
+                        3539587623617939472,
+                        7833714451459850569,
+                        1051689380006915810,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16807642596289062922,
+                        7551627882500413482,
+                        11956443365571971134,
+                        327516741365239539,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2442855591152460530,
+                        12533711680242003200,
+                        14960831061456060687,
+                        908037347997002724,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11484985060006939170,
+                        14919519383382183346,
+                        4198588002102368287,
+                        352654303088789808,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10718268338425371499,
+                        9135258383626830105,
+                        5084548653034634469,
+                        974188762747846449,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11912156257818255903,
+                        956496271794699171,
+                        4659820806955985175,
+                        254164705940393881,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17974023213437966532,
+                        6768974702789489366,
+                        3305636088936481148,
+                        723279896659345902,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3567811721358580113,
+                        1249869428576115771,
+                        11188703571958665302,
+                        831297642462196024,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13196464620596611856,
+                        12363716798356779081,
+                        5244315890585885723,
+                        687958834982645446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1440242200809421725,
+                        11262459943844383247,
+                        5887144827861018649,
+                        357759222130279869,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6518694973255686041,
+                        614462668091132196,
+                        15834446543731035542,
+                        508665650839705332,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16204984450476070434,
+                        17372240933494073094,
+                        2951914110056330234,
+                        295442962149190062,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6178962046684821572,
+                        4029324706010864210,
+                        2890164363970110465,
+                        1173445609493369650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5011677162033528672,
+                        15035058577512213516,
+                        10416640847428109557,
+                        1019670176456942052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5811609310917203110,
+                        13707468361078806441,
+                        1034889270768660006,
+                        762433423100274273,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10083020863339030060,
+                        1139252020195645327,
+                        9138336172704100648,
+                        903383372762025686,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17174086989344886024,
+                        9344984479650458245,
+                        2139823116542719395,
+                        986882169493516577,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17656677813578723006,
+                        7372771553102400757,
+                        17523971841182238516,
+                        917045239054738424,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2646619777080144986,
+                        2419328537721200397,
+                        3006817248437904732,
+                        1084389073893826534,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5412627399827395979,
+                        4416794676289737864,
+                        9661252635241427853,
+                        1100016297587827271,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7271249851472416951,
+                        15943709647632373025,
+                        14147970590871940098,
+                        785779257542938426,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17825538033904417827,
+                        10115623328440333968,
+                        9220917985265390321,
+                        1029404163896529488,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5244944749638725870,
+                        9435043167790601556,
+                        4187349639949845860,
+                        221780858155324635,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8445406227932526468,
+                        17918621992697726376,
+                        4912964625200347522,
+                        986038117352033578,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7565287464351981227,
+                        18035971725806116648,
+                        9492676127038005521,
+                        986402143411368586,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13943766156639232281,
+                        15109830674303520085,
+                        973995456519720833,
+                        834865950568525211,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1012947362611375500,
+                        8369596097450877399,
+                        3299500151972383144,
+                        350760119131449729,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3580727582152511376,
+                        14200528701300892104,
+                        1043202043429516996,
+                        1087224538622373955,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8763607230722948622,
+                        14822284945008987615,
+                        6720726200260506306,
+                        23610281865408267,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17509799921036689881,
+                        17445898412427070872,
+                        7753948050675487795,
+                        1187069969775694829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16910084984397756170,
+                        16723096708426518071,
+                        5222698240845049302,
+                        487510051230644634,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6216481127545256964,
+                        12256437476570884900,
+                        11370011048200221977,
+                        1052292161101620707,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2789261454853440996,
+                        2370773414119180290,
+                        6757316104748458795,
+                        396705211551076666,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9512671989203007563,
+                        11474084394043310486,
+                        11364771751463404823,
+                        955548032913123959,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1813950484228389063,
+                        14555602749690485,
+                        11002560435254166980,
+                        1240695163588550172,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10746029149157634612,
+                        2786810027273909474,
+                        5628969711738852591,
+                        1261165633628042564,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5999058022921749739,
+                        4791114445433383007,
+                        15272671148031206104,
+                        1156257624373419928,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15219627621838971370,
+                        2604449117369749873,
+                        7620083609214494591,
+                        11651056931982548,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13369036225797036731,
+                        17820143515309998319,
+                        6870275708756765508,
+                        1060315178279577077,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6348643830298522019,
+                        10546879226261163097,
+                        6084533992804260018,
+                        738264145493209325,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16229387769982351810,
+                        13746692357329643063,
+                        10159323350629910391,
+                        12797501570881331,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11516471006243497928,
+                        856700836073914090,
+                        228355579423791790,
+                        577817954163169087,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1868698623278697588,
+                        15403877053429049518,
+                        6855126150175818963,
+                        10071931116479964,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        832758968560456298,
+                        16308464065121109476,
+                        2048141479551499532,
+                        157266086218256397,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3394883573555753226,
+                        10982461976808240259,
+                        15759898041459801383,
+                        1257847045501903312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14941228543354774911,
+                        10237827522244285145,
+                        3677648245386433153,
+                        1307259200856969078,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5481556147345304053,
+                        5702373476664509915,
+                        14925494424108772316,
+                        304984599488172645,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1403492157500683508,
+                        5314318271548596344,
+                        9461454928875577837,
+                        361330090634002842,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13981480546697308375,
+                        1494259446099609454,
+                        12229789223096487505,
+                        409564517938696894,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13822457463938620614,
+                        14796058036796768917,
+                        14091214569078917328,
+                        1266779586751973446,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8751840930408675695,
+                        11876844936743993025,
+                        14611088808573985957,
+                        272451009105414514,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        584948904342769937,
+                        4824902872403477724,
+                        11172284697351907157,
+                        323931662814595582,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15641560896006109801,
+                        12957405350547546755,
+                        1120863601924173295,
+                        572720704509072465,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7717341725826718409,
+                        9114218949154536719,
+                        7470578096403139629,
+                        1334352480205322986,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5560974173414298486,
+                        10918041292392981894,
+                        15744041296880611640,
+                        623320027750434666,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10034884424066940048,
+                        9744501741208940644,
+                        11714316261811721515,
+                        955838631011683120,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4034979185652173825,
+                        16811789667948810407,
+                        16366269290497412831,
+                        828536891219008739,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        506654858543066893,
+                        4015297425023987063,
+                        15071047671402910268,
+                        94102438552912341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6380524025026587332,
+                        8846919587464777078,
+                        17424292910478163795,
+                        142536866281693437,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        941490954959334721,
+                        6870306729466629436,
+                        13348386130344727737,
+                        94616649470599525,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15007668159963837215,
+                        2270785322095458239,
+                        15811977093402019098,
+                        452263620511415091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10059203956711982003,
+                        9359108498297161588,
+                        17402154657346259546,
+                        81147817743024217,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2235185219663284892,
+                        10416661020210902569,
+                        4364650969357254840,
+                        16101238216374706,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14993282719209693439,
+                        7563073749977576486,
+                        2212512340956961514,
+                        606854129805209221,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2675274001171257536,
+                        5847602906799509470,
+                        7432360819701232502,
+                        440230916495315347,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17823428111556904614,
+                        5871525391532193557,
+                        10622671575164085246,
+                        169709711759242063,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18014776246615017822,
+                        17246938908862363813,
+                        2290441460565403549,
+                        495688598430922845,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2315268134346907420,
+                        7504848026346836097,
+                        1165860095787482357,
+                        181563407447874646,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7741237656927546336,
+                        11329312842711547158,
+                        3169078454210083779,
+                        720562412723389555,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15007589950186353139,
+                        9906283577409007027,
+                        16856523496247811536,
+                        1016359555024672023,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11277306349050868239,
+                        4042943319670981855,
+                        13286547440088993517,
+                        263527385526472084,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16488448931227904894,
+                        3015818988707061152,
+                        6571431259549515122,
+                        769904384597342138,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1978986685863782217,
+                        15217045518841285769,
+                        2088270347366717709,
+                        9851954884349517,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15057368335972958299,
+                        8096827309850339626,
+                        1197776596721317227,
+                        997250597603991254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2612189546574901407,
+                        11842668135207550997,
+                        9642817924505788165,
+                        1289736641080947445,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9005457499515212074,
+                        8945704379555341352,
+                        17249385089480823806,
+                        1166630467228098861,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        317543701385589482,
+                        5044409136002970088,
+                        11582703567015996545,
+                        326475913246167431,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8428551188138326664,
+                        11400253234118264632,
+                        17302513043325446723,
+                        1177494938388834635,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18394611914879577787,
+                        15378167629407008242,
+                        2266830330309253696,
+                        409387643610169429,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13773366257579966139,
+                        13665577061904315850,
+                        6424865776631071559,
+                        309425794059249761,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11481490706513535387,
+                        17235648951287808734,
+                        7687709027463464887,
+                        707470695651094371,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15895749685317170990,
+                        13611366436136389508,
+                        6685388659774929633,
+                        809609209781589756,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14906588285442804544,
+                        12069705174323936077,
+                        11933801502547855978,
+                        1236763857529862856,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7599239555686166190,
+                        12753485600185724617,
+                        4722955723916297970,
+                        375564760189670724,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        785865437390231729,
+                        11568205517588530652,
+                        5893718575660458891,
+                        943893786072456509,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2244033794343757840,
+                        17771132271268230437,
+                        10834098950955791403,
+                        819160589512356303,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13062544164792343999,
+                        10722274290210901744,
+                        13905703515844751591,
+                        921539737154654904,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2891399894307425672,
+                        7031375984314664700,
+                        53649696803689433,
+                        84530936106110994,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16877557845970180351,
+                        4130591120364108911,
+                        18174276223913648956,
+                        881253065420359637,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16220887469298629735,
+                        11549062047003583228,
+                        7865678879898108574,
+                        116328110726817328,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1260606751286325161,
+                        17894181077853864803,
+                        2481126465382012155,
+                        364078855806146170,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11459778208294518567,
+                        13470277600498637286,
+                        855735915728597281,
+                        224567009902099652,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4742673968621799429,
+                        4959874640877837535,
+                        8837449536772420641,
+                        1247109059489130709,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17815956549180917263,
+                        13525346120381975503,
+                        16809572187766605332,
+                        69930372897195719,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        157400062598158558,
+                        15178259622015153247,
+                        17312318522771545832,
+                        190972829171558972,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2128755508596102584,
+                        9828806230679699525,
+                        16769720943156609143,
+                        244009630969361336,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11638025012540869693,
+                        8981304983422217374,
+                        12285598074959567067,
+                        921193288054716033,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7923214498835219042,
+                        5789066945836402000,
+                        14739734288713549706,
+                        597004684272158523,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9531752467481432413,
+                        2552126115521649907,
+                        18198663824330832398,
+                        451760026671511798,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9805489073928798078,
+                        17214831542929726865,
+                        14641206839972762912,
+                        351235087932867982,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2770470278562693190,
+                        16367894168003323024,
+                        6652115190762807000,
+                        141093246937913310,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6827947314848269871,
+                        18271835060308621667,
+                        12766838925956986909,
+                        18092510499596687,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        871580718313007694,
+                        9711335108083625140,
+                        18419431625120013287,
+                        1208103516298762315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11276973066139992966,
+                        9764708222123624202,
+                        9660608366997436345,
+                        1315998790390345372,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        903596177109537566,
+                        10569699978106151565,
+                        11819461672991410065,
+                        852571768196568725,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17642568599804075519,
+                        808591385112088705,
+                        17527011275855630675,
+                        1317553054406917794,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7369824103415312556,
+                        9409263053979361643,
+                        1857971948545611319,
+                        364549436628176113,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2582008907983024353,
+                        10464051730584045150,
+                        5927674773061337939,
+                        143825183115357916,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7038613639420803633,
+                        7229139163723008505,
+                        1189799194613168916,
+                        607864529679285433,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3706972855141561547,
+                        16568373430032060320,
+                        360123844970888996,
+                        1263281171164301716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12588547578301853612,
+                        9014141986574982629,
+                        15589682411887820287,
+                        855847129952462257,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14214782455953455392,
+                        933040440517248401,
+                        13340332279570656000,
+                        1129501150812929798,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6055450215376541885,
+                        6103966567997768834,
+                        3662625349450783295,
+                        832581931351383577,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5637907343781890639,
+                        6036273119225230824,
+                        8205564546569573458,
+                        532221326904286473,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14602094299630016356,
+                        7912051431317846443,
+                        17106123716337034749,
+                        412634044775727709,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10985668709415805415,
+                        16916509638416883787,
+                        13684838940407217357,
+                        770198600928537541,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15835262504191741817,
+                        15767650055462228470,
+                        17134081527697571453,
+                        650756413606403882,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10547807475515782271,
+                        9962821424200781986,
+                        16036038136251971189,
+                        1104146469375378461,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7459380345322016885,
+                        4518643666057139546,
+                        9272019672056016984,
+                        41764736752549960,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9647704865547489997,
+                        13206890898805130213,
+                        4074717761546937331,
+                        199283462048890059,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6920010435168432509,
+                        6746821928434359630,
+                        2657049359234876523,
+                        1037544970481979453,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10609124640579144912,
+                        4155240167301800846,
+                        17686092301753596144,
+                        1298653863212869878,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18110461221485023380,
+                        7355397689601139096,
+                        4467503901391272833,
+                        1227328980707571739,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14310113878652347517,
+                        12368749196569992024,
+                        4091418731751323654,
+                        151875128261877239,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17781854583178852259,
+                        12964354690983034810,
+                        8380861231236129943,
+                        849651663820474402,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9065839138887626905,
+                        18379319955080709693,
+                        6418608168048088085,
+                        1188785040797317490,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11424651183797837718,
+                        16879464036479468622,
+                        11456226333149525833,
+                        1097579377864671829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3494354921969883774,
+                        15707966673452973009,
+                        4300552939184937727,
+                        1270289080641368754,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16978053001971041254,
+                        1801795298325875546,
+                        13719179466701496238,
+                        633509609374326664,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1511734972617813125,
+                        11519178120611947391,
+                        6426551394766622789,
+                        1088035015431250210,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6398754002611681655,
+                        15635541084859111814,
+                        4312033923423964123,
+                        772026965323282700,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6758499786022518692,
+                        7997234475560979826,
+                        7027806460456754050,
+                        859281188761322575,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3844682300266432283,
+                        17679579024402317814,
+                        2193462760868472017,
+                        875065525383401596,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7569726411413751234,
+                        16922960898827906297,
+                        12759866659763550825,
+                        196598607316986043,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10108275997621274410,
+                        14978968669671875736,
+                        208050732693957695,
+                        664081558592583055,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13714372682539620117,
+                        9566144733835987055,
+                        18261468869365929238,
+                        639313480519562160,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14616855537120396041,
+                        2818316668596297358,
+                        12253411529121803086,
+                        1283415937292636383,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1150916154128906131,
+                        11867691509678001201,
+                        1794838920758252932,
+                        1326703357134832625,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2010783871919968876,
+                        17232431996373493470,
+                        3924904325059129022,
+                        1148228227432095669,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10020813074065929732,
+                        3083239245601781514,
+                        1060738263884161153,
+                        924334092281288462,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11274091573072521523,
+                        3724840599341948404,
+                        18395064340080001928,
+                        342233779928519054,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2800482991962144544,
+                        3286494288148835024,
+                        4461387064272369468,
+                        988761058492902158,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11161073221853888339,
+                        7395744541896767522,
+                        5874053165261587527,
+                        56913882752446069,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4543493608420045080,
+                        3002194114537113358,
+                        9127392648595502718,
+                        583303950710400179,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2512940061538685672,
+                        2384737743077334595,
+                        6654554735584987424,
+                        729907658857562974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15421266352274341465,
+                        14212962675705587586,
+                        46758652046903116,
+                        1252807088072000654,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11852295508367593563,
+                        945904260116826342,
+                        17460453654534401942,
+                        1154393681885709860,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        443559825697463782,
+                        5642068963671561589,
+                        3742805868936193450,
+                        186276604042776602,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11694454791813107983,
+                        220873067676192403,
+                        9169826717048591665,
+                        591352286924056344,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14827161616967841434,
+                        5982596049033707661,
+                        7588986339087189841,
+                        404358487715836588,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14467082204058112202,
+                        1863068751474100506,
+                        14039799007080417321,
+                        1027258154365594620,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3756305409646867345,
+                        8043281142484017851,
+                        4907477525780629991,
+                        610375155739735999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2403149816205035761,
+                        8610248736239518003,
+                        12968733929016901482,
+                        897461285305968809,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11961618318450419982,
+                        1949999641557130228,
+                        5753809807278726035,
+                        479377423598936721,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2033608245290148130,
+                        16158438874623871868,
+                        14567121689632539908,
+                        16231522263574453,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9638368059084913360,
+                        13583239390742679000,
+                        3917870210704726490,
+                        312861466223188307,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17014909822165768632,
+                        6191349242731806563,
+                        5912371316918605682,
+                        516620982814962083,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16777331826503411696,
+                        2080366901315046762,
+                        7269448770604699547,
+                        231634586281496423,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8522162332986646910,
+                        10327981783190340236,
+                        10830470803571135558,
+                        818361075561737395,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11497051693497656739,
+                        9337788964341523979,
+                        9866513969466443728,
+                        1091682151844874070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        329443864538162499,
+                        3336350940529571913,
+                        14828629836469123874,
+                        847817248638315642,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1669016277265433412,
+                        15953796966650968462,
+                        7984710186394196502,
+                        829805373715820626,
+                    ]),
+                ]),
+                Matrix::<16, 1, 16>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3901556231219750502,
+                        18046880418710586421,
+                        17229062735934076718,
+                        39091682190757057,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12483244240806198070,
+                        9254324601109586556,
+                        13211597555368576224,
+                        1225169410827172392,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12546811353315211157,
+                        7263233275825639211,
+                        4234665074270321328,
+                        1042050577413581069,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18220852912326804625,
+                        5309207360187496269,
+                        14708217525141235042,
+                        424677052777105393,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13030371214687642134,
+                        18371672514776966628,
+                        14195261140432739358,
+                        1056543385111401289,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11711116634680973158,
+                        13247689059080662049,
+                        17485707715051577159,
+                        151198004501856203,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17659570690717825789,
+                        6802840386337026797,
+                        18033473263017754102,
+                        548408912454578276,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10596783186305912325,
+                        16726699965753036806,
+                        17693984447950340795,
+                        871392502059603856,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12406292890344825034,
+                        2964486920366646891,
+                        16365878420036211154,
+                        179933068593368923,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16514213083294820141,
+                        12747566846626796094,
+                        14498923586812763886,
+                        935487689842234083,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1428459807133319566,
+                        6655874938457368328,
+                        15698548627045574163,
+                        9330574522452637,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10449539501219563242,
+                        6917678167192734052,
+                        9986705330490168779,
+                        286483726300920550,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16813037479754428977,
+                        16579521173701269300,
+                        1152109073719406288,
+                        274486379138588383,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10083959435362188676,
+                        983822441826797745,
+                        15324321034662355599,
+                        693853649361878685,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12118523204876525491,
+                        11274867503767872638,
+                        15122515203029574406,
+                        303403382059199644,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14833153436149837462,
+                        5180786175943614910,
+                        99404257320440873,
+                        1016833502872704865,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<39, 17, 663>::new_from_known([
+            Fq::from_montgomery_limbs([
+                9581419031222190322,
+                5210476888335524045,
+                16280172889506106702,
+                61916542041203128,
+            ]),
+            Fq::from_montgomery_limbs([
+                1336547432065696007,
+                6686419986182718904,
+                15567329935455861594,
+                1181250163697775871,
+            ]),
+            Fq::from_montgomery_limbs([
+                6653095628345450770,
+                8195302822981904447,
+                2864191407815075501,
+                1319305375098393788,
+            ]),
+            Fq::from_montgomery_limbs([
+                9637262675339575999,
+                3050431777078115230,
+                3807776730267188835,
+                877489925446359522,
+            ]),
+            Fq::from_montgomery_limbs([
+                950766340161820704,
+                3587644507320000022,
+                4973272674142563956,
+                40735447838252930,
+            ]),
+            Fq::from_montgomery_limbs([
+                792225735780456873,
+                9245825414050435618,
+                14081611059060548638,
+                678504056824425115,
+            ]),
+            Fq::from_montgomery_limbs([
+                9108055554479502582,
+                1172549363446145236,
+                1716742965988245241,
+                793192906936849801,
+            ]),
+            Fq::from_montgomery_limbs([
+                5701312954453174644,
+                3292957906697952685,
+                7612377737351738916,
+                715841028860741809,
+            ]),
+            Fq::from_montgomery_limbs([
+                7628514421238759054,
+                16189199190432915955,
+                120306631169826977,
+                872943400717347639,
+            ]),
+            Fq::from_montgomery_limbs([
+                17013845265488232376,
+                5150815978988502610,
+                9489983193259467001,
+                818111430670008147,
+            ]),
+            Fq::from_montgomery_limbs([
+                1183632966436800198,
+                12721209216748448144,
+                11758742264706452860,
+                785130516421029688,
+            ]),
+            Fq::from_montgomery_limbs([
+                9179830599964930791,
+                16970998671994800924,
+                105031891158005894,
+                1154542632865941506,
+            ]),
+            Fq::from_montgomery_limbs([
+                4199810447341097231,
+                16293726787971933227,
+                7163619929537087676,
+                905344460933010058,
+            ]),
+            Fq::from_montgomery_limbs([
+                1987101065343867727,
+                5739737022008282486,
+                9237646317580772330,
+                193666689444071256,
+            ]),
+            Fq::from_montgomery_limbs([
+                10281083237027810615,
+                10990458230938611104,
+                16665044785424592390,
+                240060772288227764,
+            ]),
+            Fq::from_montgomery_limbs([
+                12227417003645510891,
+                15161655888058427285,
+                12532020654772275235,
+                191567267074842521,
+            ]),
+            Fq::from_montgomery_limbs([
+                13588077092373441116,
+                13568445940585925217,
+                10071095771333029207,
+                596333589286539508,
+            ]),
+            Fq::from_montgomery_limbs([
+                11587757304065222898,
+                8662184680536745896,
+                1409377377056033280,
+                1249810382602575922,
+            ]),
+            Fq::from_montgomery_limbs([
+                13203153321668995713,
+                14692820363314613602,
+                6579211989411212911,
+                1245204724772318550,
+            ]),
+            Fq::from_montgomery_limbs([
+                18131934794155659789,
+                4627664332804486360,
+                1057933359741348550,
+                1072517789412842481,
+            ]),
+            Fq::from_montgomery_limbs([
+                14887587828925127721,
+                18273948259069763455,
+                1901386078381693783,
+                964612986311433889,
+            ]),
+            Fq::from_montgomery_limbs([
+                7071237661851184034,
+                13228244633135044975,
+                8642837098973236738,
+                816195637135428457,
+            ]),
+            Fq::from_montgomery_limbs([
+                14001898592082447969,
+                9705026715743397829,
+                10595652403184611397,
+                276668257971270960,
+            ]),
+            Fq::from_montgomery_limbs([
+                3320296070150408618,
+                5744640190227967136,
+                9991024836124273815,
+                33544757176090403,
+            ]),
+            Fq::from_montgomery_limbs([
+                15902327367024084880,
+                1767430481627219685,
+                9255102443366948605,
+                510156262272131556,
+            ]),
+            Fq::from_montgomery_limbs([
+                6293351874832653030,
+                16220797628587901091,
+                17685455868543144438,
+                72784069764148636,
+            ]),
+            Fq::from_montgomery_limbs([
+                13105042454833500083,
+                10963567188663597274,
+                9877943357888857749,
+                1270459916885021506,
+            ]),
+            Fq::from_montgomery_limbs([
+                16874999463369808484,
+                18075243263092295587,
+                14305465026403980636,
+                455297212036878654,
+            ]),
+            Fq::from_montgomery_limbs([
+                6659600514050025435,
+                17292946779883064151,
+                277788519439178344,
+                1024991263399764588,
+            ]),
+            Fq::from_montgomery_limbs([
+                13303772843999407682,
+                6715113361089363816,
+                4205851296015110953,
+                422728870271603178,
+            ]),
+            Fq::from_montgomery_limbs([
+                9165743763915823487,
+                14873137519130977646,
+                575625624156701306,
+                620003264966894421,
+            ]),
+            Fq::from_montgomery_limbs([
+                2675722179648392418,
+                5585844672885335992,
+                15113681713720910351,
+                361475522049622386,
+            ]),
+            Fq::from_montgomery_limbs([
+                1586327558532299074,
+                15540689304920167859,
+                5350757025867747594,
+                332275369952740097,
+            ]),
+            Fq::from_montgomery_limbs([
+                14135704728820581939,
+                18050012749257813237,
+                503697459949027804,
+                550005354906063995,
+            ]),
+            Fq::from_montgomery_limbs([
+                8509740390626535420,
+                9833546741828319920,
+                17034860342062725607,
+                1158479887756544128,
+            ]),
+            Fq::from_montgomery_limbs([
+                4470326376704285333,
+                2607219090756789927,
+                9431810618514947496,
+                931432029925856771,
+            ]),
+            Fq::from_montgomery_limbs([
+                8522281963829902721,
+                14073176301763948003,
+                11772706518619454457,
+                54109420719422651,
+            ]),
+            Fq::from_montgomery_limbs([
+                899220260982040430,
+                10494368063958176818,
+                1082510304430573121,
+                554742694385917203,
+            ]),
+            Fq::from_montgomery_limbs([
+                17192098924008727037,
+                5737420743753667822,
+                5035543822398524947,
+                64306063207015734,
+            ]),
+            Fq::from_montgomery_limbs([
+                13398139189254764087,
+                14986581544444759409,
+                7034658303781704218,
+                690964111186389876,
+            ]),
+            Fq::from_montgomery_limbs([
+                8735146977808336520,
+                11760938425913357049,
+                2086541718224675303,
+                2112043270687346,
+            ]),
+            Fq::from_montgomery_limbs([
+                18236166520751815993,
+                8540271672706710332,
+                14761246675459506461,
+                597507910681456992,
+            ]),
+            Fq::from_montgomery_limbs([
+                13896930264870230134,
+                15235906932518392542,
+                7321672641748010408,
+                338107527390755215,
+            ]),
+            Fq::from_montgomery_limbs([
+                430193357433575356,
+                573548079432283454,
+                14228263832105034406,
+                1123839393636648054,
+            ]),
+            Fq::from_montgomery_limbs([
+                12255348672023084189,
+                18290997644909676148,
+                3515207917753215498,
+                116702720268313280,
+            ]),
+            Fq::from_montgomery_limbs([
+                12879993786584288761,
+                1556885377627822637,
+                12973683629791291231,
+                447831799410748664,
+            ]),
+            Fq::from_montgomery_limbs([
+                696379836860844553,
+                2805363707514197154,
+                4597261928088448107,
+                954412493945935895,
+            ]),
+            Fq::from_montgomery_limbs([
+                16146808573377422596,
+                17744886485408276771,
+                15264669590844697806,
+                397711111644439875,
+            ]),
+            Fq::from_montgomery_limbs([
+                570633415583483068,
+                12406305897892862152,
+                13820213058498179372,
+                1196943180722334374,
+            ]),
+            Fq::from_montgomery_limbs([
+                1354165059018794566,
+                18278310541211445201,
+                7320583606808928630,
+                175617647814851263,
+            ]),
+            Fq::from_montgomery_limbs([
+                7679660649483812965,
+                6270379121195172965,
+                17983285011460590580,
+                121871326603299737,
+            ]),
+            Fq::from_montgomery_limbs([
+                1312877144475763645,
+                15311840865763560895,
+                7272491418070542311,
+                1035752786457757192,
+            ]),
+            Fq::from_montgomery_limbs([
+                3682939052921597099,
+                5495404988348743435,
+                12292496235869104295,
+                484667187497535742,
+            ]),
+            Fq::from_montgomery_limbs([
+                8134019250544826708,
+                13958940162663520456,
+                14809038070757837828,
+                592164474836619388,
+            ]),
+            Fq::from_montgomery_limbs([
+                1154007277844617566,
+                2722517023627074028,
+                15223674908576777702,
+                666590329906068884,
+            ]),
+            Fq::from_montgomery_limbs([
+                14374425189237101671,
+                10043586393369866953,
+                1848214587225176328,
+                853294692832306591,
+            ]),
+            Fq::from_montgomery_limbs([
+                3351267131129131896,
+                12463040602019259089,
+                8610737899562849134,
+                568677313619499275,
+            ]),
+            Fq::from_montgomery_limbs([
+                16077662472155082316,
+                14114308726860549622,
+                3013927512835182958,
+                571769536113060739,
+            ]),
+            Fq::from_montgomery_limbs([
+                14810662319981377449,
+                17104668117311060258,
+                1663330158602703846,
+                26236808660107952,
+            ]),
+            Fq::from_montgomery_limbs([
+                12653334005559750704,
+                4523651724172855698,
+                6594606915757018280,
+                801977721547936992,
+            ]),
+            Fq::from_montgomery_limbs([
+                10855485752859079028,
+                9156906423209462820,
+                216694908507293981,
+                1221401697329760555,
+            ]),
+            Fq::from_montgomery_limbs([
+                16380087325908648234,
+                18242818266488782470,
+                9185209588176890509,
+                1006432003150504729,
+            ]),
+            Fq::from_montgomery_limbs([
+                9005991578514842469,
+                7294704011817285927,
+                17045078740564364128,
+                177888178688690840,
+            ]),
+            Fq::from_montgomery_limbs([
+                14778266667966004882,
+                12601136042432953565,
+                4567569030996207156,
+                701208621725182299,
+            ]),
+            Fq::from_montgomery_limbs([
+                5557284792547312009,
+                5474831875830457540,
+                3726961756901985274,
+                277312885634410077,
+            ]),
+            Fq::from_montgomery_limbs([
+                1027823916746326335,
+                13031733966678739449,
+                3863714818345387835,
+                1203879527571191287,
+            ]),
+            Fq::from_montgomery_limbs([
+                6463019086664350598,
+                12154691338113436257,
+                9843481261782262421,
+                840120650332334551,
+            ]),
+            Fq::from_montgomery_limbs([
+                1268931336566849674,
+                8224310740713990405,
+                16369223902312945424,
+                1056419960474598292,
+            ]),
+            Fq::from_montgomery_limbs([
+                7190736424177008867,
+                294530240961090306,
+                4385403564543094874,
+                193384734196906168,
+            ]),
+            Fq::from_montgomery_limbs([
+                8682115787337954809,
+                9036438158458303438,
+                4157151464615002490,
+                683807000524197621,
+            ]),
+            Fq::from_montgomery_limbs([
+                10780560509684276563,
+                14616246858719720582,
+                8724799357310634348,
+                100793611075805535,
+            ]),
+            Fq::from_montgomery_limbs([
+                775450020513695687,
+                1488804820184107682,
+                9410420464018904457,
+                152507159931710171,
+            ]),
+            Fq::from_montgomery_limbs([
+                5431500527637474536,
+                18337730689289208173,
+                5664974385759913348,
+                431102890571739747,
+            ]),
+            Fq::from_montgomery_limbs([
+                4268055907555437934,
+                2333444475726400630,
+                1365360632120763250,
+                843162299526338854,
+            ]),
+            Fq::from_montgomery_limbs([
+                5879823992456550710,
+                7250720417170214814,
+                280587472010742234,
+                646728621601265841,
+            ]),
+            Fq::from_montgomery_limbs([
+                15059935324356046231,
+                3600202445429482050,
+                1337468792992258539,
+                485624294336819235,
+            ]),
+            Fq::from_montgomery_limbs([
+                12134639187529493168,
+                3006516019953884650,
+                10269471916468874829,
+                98603388411321584,
+            ]),
+            Fq::from_montgomery_limbs([
+                17746282945168065358,
+                10719677044794955457,
+                12480295029605066824,
+                306722040914902619,
+            ]),
+            Fq::from_montgomery_limbs([
+                3986444091817695583,
+                918291583966853052,
+                16920112898635851718,
+                638283860332119258,
+            ]),
+            Fq::from_montgomery_limbs([
+                6823540201285386884,
+                5130023909476472671,
+                11117336670434614519,
+                1289727199152588486,
+            ]),
+            Fq::from_montgomery_limbs([
+                10155158596987161271,
+                11486261052649874151,
+                14971016587204390624,
+                824007801190044044,
+            ]),
+            Fq::from_montgomery_limbs([
+                8235430427361308748,
+                14915520803862431671,
+                9373267021385868829,
+                714138547891518286,
+            ]),
+            Fq::from_montgomery_limbs([
+                6795683563045553948,
+                15776199924255068095,
+                4541873846383421665,
+                779795037267873614,
+            ]),
+            Fq::from_montgomery_limbs([
+                12816315589015272975,
+                2925751315959033669,
+                1913629245587929341,
+                981398537578023090,
+            ]),
+            Fq::from_montgomery_limbs([
+                10507558772552578944,
+                4287608646946650618,
+                360842174295076291,
+                813635923237763014,
+            ]),
+            Fq::from_montgomery_limbs([
+                2102476407606680308,
+                2399257470970286701,
+                17171981299999742043,
+                1219078063791142350,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12662299301775335823,
+                1716813137422197588,
+                12173095146711159913,
+                173710196124841958,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                306241736914148316,
+                3195791087724185431,
+                2961707900485386075,
+                666649371944373852,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13968818179455850477,
+                10018617234731163887,
+                4236554607278409750,
+                9434796527904940,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6997442992797331220,
+                8056288271209329395,
+                17208955961790211747,
+                971872227740026469,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7359741406476989400,
+                14829000346617644959,
+                18159517460216323428,
+                1072874058184262924,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3349011252547653196,
+                7688198290738922422,
+                14372027220261851254,
+                361825940821272348,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2817282780937164575,
+                11012661895588808808,
+                7365209260035068784,
+                29410219115829668,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                18438081834782989473,
+                13470018803358581479,
+                12769849593302184293,
+                1199322734512392613,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12651453954053195382,
+                13592310890030355227,
+                356009444950319012,
+                532069842172548580,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4197106664231231719,
+                17032966552692569015,
+                16585232316441171273,
+                1315229849514613684,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9015859730237535040,
+                8870267771814241568,
+                2284026123804738690,
+                493463513606284771,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13593905432022366721,
+                18105521121885860573,
+                7435826344577250061,
+                1015215054094423713,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10929760908031262235,
+                8764621732428158434,
+                16660709026050231191,
+                912250198504792623,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14896990739996177970,
+                4976454795508547304,
+                4000341226540693443,
+                1152811308407462502,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14422206260842731877,
+                5652686390443579292,
+                4526730871225032924,
+                327466354254691782,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14186681462690775772,
+                9797797123650310573,
+                9360054071909971371,
+                132287497867325250,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7544285658434788738,
+                8918140084613855078,
+                14244972012132271622,
+                735136209262994069,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16846853091584820252,
+                9022978310050119753,
+                4895166539281754021,
+                592258839664415135,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14554633738571798793,
+                5764058529897090277,
+                13272211576923342194,
+                1240477156977658696,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2360727296389545494,
+                6749518659045942222,
+                8196418902931565018,
+                597465550721383622,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3883301050262596257,
+                14925804204028651561,
+                457117228704905204,
+                635417739210999647,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12787228589430952673,
+                11591174163687556175,
+                5298557260189725204,
+                808443367816061986,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15203593928279448860,
+                15688239630501467992,
+                17434108983075675431,
+                78296536477447547,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1535179307837012256,
+                10732921141318789133,
+                9439922049439228789,
+                834238866802887729,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17494098441262034630,
+                17239351657963810577,
+                426210026721003689,
+                35110936929297246,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16977313412362215135,
+                9526315690455538536,
+                10687278873217109723,
+                1186367599444878309,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14572713241749264561,
+                14558817631851822043,
+                17613049653112016995,
+                1271155578408780856,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7253233541212733266,
+                15306136503949497223,
+                5665740989054251287,
+                664073365398777870,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1319863569561162857,
+                10575693624090064914,
+                13975471773972882628,
+                428150031766959888,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2474581405230981792,
+                5319379671620146557,
+                16472828536749432027,
+                992886427343855925,
+            ]),
+            Fq::from_montgomery_limbs([
+                4206220916072586032,
+                5551743968023498330,
+                17702879089253650410,
+                1227097319556301711,
+            ]),
+            Fq::from_montgomery_limbs([
+                17297421772381504166,
+                5712211545076798084,
+                6880154935833373884,
+                830438550655204616,
+            ]),
+            Fq::from_montgomery_limbs([
+                16656847744587521689,
+                4039691750581181440,
+                8207367377598443581,
+                601277475403627390,
+            ]),
+            Fq::from_montgomery_limbs([
+                15911067231877078659,
+                14118848579823365965,
+                18093128339476259000,
+                46914712823429800,
+            ]),
+            Fq::from_montgomery_limbs([
+                13435638383167566170,
+                1061487002509671624,
+                10839283778440433613,
+                227140537130851488,
+            ]),
+            Fq::from_montgomery_limbs([
+                11128509054237142857,
+                15530848866549393428,
+                7526092012579365137,
+                90995453894448218,
+            ]),
+            Fq::from_montgomery_limbs([
+                11189018575186519716,
+                4522433541260082787,
+                15221707329251400903,
+                1181333098523495259,
+            ]),
+            Fq::from_montgomery_limbs([
+                15331716973820008193,
+                4254071327645189529,
+                9365719880207854283,
+                314513792523314556,
+            ]),
+            Fq::from_montgomery_limbs([
+                277343617296982566,
+                4984791850595310388,
+                660244346778796854,
+                446413156716264944,
+            ]),
+            Fq::from_montgomery_limbs([
+                1400445372444493994,
+                7620289742838461319,
+                1532115290805924207,
+                826861076333396355,
+            ]),
+            Fq::from_montgomery_limbs([
+                15419578302888625606,
+                120123395249464222,
+                11828949280857398422,
+                981714237953249704,
+            ]),
+            Fq::from_montgomery_limbs([
+                7124775523214534038,
+                17861920671827559884,
+                15658611370856290026,
+                255205819484817294,
+            ]),
+            Fq::from_montgomery_limbs([
+                8774559036451549404,
+                8781141314418160004,
+                9193532927437186891,
+                82327940795193101,
+            ]),
+            Fq::from_montgomery_limbs([
+                9439783978109764831,
+                8218660223973429544,
+                8689457005119516701,
+                647127556352455213,
+            ]),
+            Fq::from_montgomery_limbs([
+                14427977173644144496,
+                3049239185903980947,
+                2640215100631186982,
+                279282959355462923,
+            ]),
+            Fq::from_montgomery_limbs([
+                16338419495463180053,
+                7385693388873588031,
+                8286306053773396121,
+                126528102702900686,
+            ]),
+            Fq::from_montgomery_limbs([
+                3304015005411805916,
+                13839715069618569269,
+                17104685862808217233,
+                1050526621341149808,
+            ]),
+            Fq::from_montgomery_limbs([
+                3889813112798901771,
+                11775682225288404483,
+                4098094413231562729,
+                204161140615757875,
+            ]),
+            Fq::from_montgomery_limbs([
+                176550562579139717,
+                6940993736879475195,
+                17974298251378620679,
+                957611251173905859,
+            ]),
+            Fq::from_montgomery_limbs([
+                8243867914806588270,
+                18353531917044913405,
+                12172963936744344692,
+                457864550236002525,
+            ]),
+            Fq::from_montgomery_limbs([
+                10395019267997036309,
+                2199897264712811188,
+                6745104012605541874,
+                266803915690742150,
+            ]),
+            Fq::from_montgomery_limbs([
+                13749671937598196578,
+                10750971219310342757,
+                2270931199922585578,
+                750860905363781176,
+            ]),
+            Fq::from_montgomery_limbs([
+                5957786289504668720,
+                1782453772507500795,
+                12691058384105298575,
+                865413254783282226,
+            ]),
+            Fq::from_montgomery_limbs([
+                3430258251138819352,
+                9155021000291281975,
+                3824422363238705113,
+                771441883695423415,
+            ]),
+            Fq::from_montgomery_limbs([
+                8463463528300034996,
+                13592877045765608676,
+                14825998820037406332,
+                699510812613164251,
+            ]),
+            Fq::from_montgomery_limbs([
+                5434782644297722744,
+                1446631603458891503,
+                352147874941373716,
+                630023095579253380,
+            ]),
+            Fq::from_montgomery_limbs([
+                4307370338508405436,
+                4633276210871445064,
+                6843001677815803950,
+                956744391510269632,
+            ]),
+            Fq::from_montgomery_limbs([
+                12400241763282769025,
+                5154740485849302372,
+                17252362200162886253,
+                1298298285172531442,
+            ]),
+            Fq::from_montgomery_limbs([
+                11368845856243597580,
+                7342900439376709478,
+                16495521060654266789,
+                565525882799130532,
+            ]),
+            Fq::from_montgomery_limbs([
+                13492496186420531640,
+                14410475665888088454,
+                8357624046105095992,
+                36391156503208929,
+            ]),
+            Fq::from_montgomery_limbs([
+                1659037759364362750,
+                8769003666864840889,
+                658030342478903151,
+                1301433280736829944,
+            ]),
+            Fq::from_montgomery_limbs([
+                91683582333605224,
+                17616446797203279818,
+                12245436840802385369,
+                1011711537194331259,
+            ]),
+            Fq::from_montgomery_limbs([
+                9173142408350640709,
+                682982419096105746,
+                13205515068746845317,
+                752740617895437315,
+            ]),
+            Fq::from_montgomery_limbs([
+                8823318057786671739,
+                14848417269687866992,
+                321157496002381823,
+                379238423501351177,
+            ]),
+            Fq::from_montgomery_limbs([
+                10324897923656223104,
+                8459081303191042419,
+                11838850554449507822,
+                348291817435653567,
+            ]),
+            Fq::from_montgomery_limbs([
+                6878439796700546538,
+                7941352280324055753,
+                472859603365455164,
+                302822801506103768,
+            ]),
+            Fq::from_montgomery_limbs([
+                5976241904424606817,
+                11810053444844473694,
+                5689779028449016612,
+                2625791438686225,
+            ]),
+            Fq::from_montgomery_limbs([
+                12917700924664381705,
+                7276176642899491683,
+                5666727574813260632,
+                390417216621445514,
+            ]),
+            Fq::from_montgomery_limbs([
+                13814967873439814383,
+                526303985186698558,
+                8900503640804838366,
+                688050162222282301,
+            ]),
+            Fq::from_montgomery_limbs([
+                15064352306124986921,
+                13228081128975440020,
+                8726498701461414748,
+                1100088180540524160,
+            ]),
+            Fq::from_montgomery_limbs([
+                1789209816067976138,
+                1406461753692539595,
+                15810601264775222846,
+                158700739727439190,
+            ]),
+            Fq::from_montgomery_limbs([
+                4450508056136508862,
+                1021990581837586744,
+                9229567951310009302,
+                179974289375464358,
+            ]),
+            Fq::from_montgomery_limbs([
+                15653662779418619401,
+                13295866608677153157,
+                17735499711626156707,
+                324954375479585658,
+            ]),
+            Fq::from_montgomery_limbs([
+                5232349027323553968,
+                1019592218155228755,
+                4727477504845148295,
+                431157195764102814,
+            ]),
+            Fq::from_montgomery_limbs([
+                13924837559494904461,
+                5725577856096270373,
+                986256889094461406,
+                963679699648477182,
+            ]),
+            Fq::from_montgomery_limbs([
+                18087352299469355545,
+                3028474449342326153,
+                16836891429022675672,
+                1308783652480722988,
+            ]),
+            Fq::from_montgomery_limbs([
+                15586605667266686417,
+                8862272567885334680,
+                4455410304813778770,
+                244284594547814282,
+            ]),
+            Fq::from_montgomery_limbs([
+                14502560955392963498,
+                15098339755937056029,
+                17692163729327532131,
+                118664298262376807,
+            ]),
+            Fq::from_montgomery_limbs([
+                4017954938254292519,
+                15815963516276055779,
+                1343588332765181459,
+                943232643475971862,
+            ]),
+            Fq::from_montgomery_limbs([
+                16659827640831544759,
+                7835031318831766594,
+                17031471968119130586,
+                27345765580425211,
+            ]),
+            Fq::from_montgomery_limbs([
+                8804259266721801958,
+                7615117456552991025,
+                11596445472944970138,
+                276458091464693615,
+            ]),
+            Fq::from_montgomery_limbs([
+                16013275158349540104,
+                6354234541926342453,
+                3721746940071196501,
+                802481853002345685,
+            ]),
+            Fq::from_montgomery_limbs([
+                14621956540652106253,
+                16936956768425913948,
+                9711168005070490844,
+                770259087825094513,
+            ]),
+            Fq::from_montgomery_limbs([
+                7735071930118497049,
+                4950028485734728573,
+                14450449170597815062,
+                90651670061608021,
+            ]),
+            Fq::from_montgomery_limbs([
+                5954582606531881571,
+                8940828506212157859,
+                7334701812330283165,
+                223085231997045359,
+            ]),
+            Fq::from_montgomery_limbs([
+                5420867966268468388,
+                10776815478280681696,
+                10743909387757436363,
+                366554284693929942,
+            ]),
+            Fq::from_montgomery_limbs([
+                5458622216665890652,
+                14694553987210420165,
+                1122274271612087518,
+                316006204291448577,
+            ]),
+            Fq::from_montgomery_limbs([
+                35314897763729957,
+                2666529882117094200,
+                9876137199046623214,
+                675769036935171530,
+            ]),
+            Fq::from_montgomery_limbs([
+                2533680139849631153,
+                14123572578307507499,
+                15178098168821798393,
+                730502022144772337,
+            ]),
+            Fq::from_montgomery_limbs([
+                7267889045352407486,
+                12406025298345746513,
+                18125980672776362494,
+                846533633571805222,
+            ]),
+            Fq::from_montgomery_limbs([
+                5649960498118664900,
+                13620732904976276184,
+                5741847318226259842,
+                191578718054708803,
+            ]),
+            Fq::from_montgomery_limbs([
+                15587217163222291854,
+                13253704229300834697,
+                10673077071933776486,
+                636196620260716700,
+            ]),
+            Fq::from_montgomery_limbs([
+                15485929390050442081,
+                1221033015309607503,
+                4433472447311528834,
+                423642625955843620,
+            ]),
+            Fq::from_montgomery_limbs([
+                1895198991976301453,
+                4202325598826148820,
+                8605922667749975217,
+                971369304418566458,
+            ]),
+            Fq::from_montgomery_limbs([
+                3839051467326723890,
+                9048345457307548812,
+                17046384152815783708,
+                1192403728398385231,
+            ]),
+            Fq::from_montgomery_limbs([
+                2337224000853289606,
+                14200515363569258356,
+                1425814972239724896,
+                1099078532968993221,
+            ]),
+            Fq::from_montgomery_limbs([
+                4902146653544354042,
+                13189543842307404455,
+                11547093455861790101,
+                814563008268698364,
+            ]),
+        ]),
+    }
+}
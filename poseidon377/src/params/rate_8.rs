@@ -0,0 +1,8894 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-8 instance of Poseidon.
+pub const fn rate_8() -> PoseidonParameters<9, 8, 81, 64, 39, 9, 351, 31> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<39, 9, 351>::new_from_known([
+            Fq::from_montgomery_limbs([
+                10201853239810132854,
+                13371867521406010250,
+                1700541822071243406,
+                195313311928034790,
+            ]),
+            Fq::from_montgomery_limbs([
+                16154342525220351098,
+                13262156807567021152,
+                16356673692040418582,
+                350244407592144863,
+            ]),
+            Fq::from_montgomery_limbs([
+                12099909040567800728,
+                10341958551803486341,
+                8092854215755121091,
+                810043704237387478,
+            ]),
+            Fq::from_montgomery_limbs([
+                550616237166555126,
+                1267658684286191462,
+                12696893761282310283,
+                687887157179048610,
+            ]),
+            Fq::from_montgomery_limbs([
+                8394931224251290159,
+                3546414484182007322,
+                17210851903430067460,
+                29741179667446326,
+            ]),
+            Fq::from_montgomery_limbs([
+                7663372929941436886,
+                17269684479209698724,
+                2140673952869432922,
+                857788194092333313,
+            ]),
+            Fq::from_montgomery_limbs([
+                15664277524890466887,
+                10404960733359588299,
+                5101239576810141337,
+                504179257271959051,
+            ]),
+            Fq::from_montgomery_limbs([
+                12228278784797598676,
+                8499238634556631937,
+                7861030531681392652,
+                906714920349483907,
+            ]),
+            Fq::from_montgomery_limbs([
+                12841056056659623553,
+                15579956229405378606,
+                5274235013913040110,
+                755900995070248939,
+            ]),
+            Fq::from_montgomery_limbs([
+                1476951610651101311,
+                4087875914128508001,
+                11838636028724522351,
+                1160936026177424472,
+            ]),
+            Fq::from_montgomery_limbs([
+                14602989099918145579,
+                5635458450488319138,
+                6743051819603190881,
+                215261377525450030,
+            ]),
+            Fq::from_montgomery_limbs([
+                14868909705206047144,
+                1874204996613417671,
+                4106244837287628752,
+                188328888280666696,
+            ]),
+            Fq::from_montgomery_limbs([
+                12610078053655325215,
+                3857612327153925830,
+                15861957146543516138,
+                858253247368512894,
+            ]),
+            Fq::from_montgomery_limbs([
+                13953679288526442879,
+                10720832918615351837,
+                16022034781366168996,
+                297597593296496228,
+            ]),
+            Fq::from_montgomery_limbs([
+                6376100284953962357,
+                12423405611485554147,
+                1081394178843108947,
+                1256887901426171988,
+            ]),
+            Fq::from_montgomery_limbs([
+                8158865741164984657,
+                11517726388655795847,
+                8791002920672387007,
+                1166316734508759526,
+            ]),
+            Fq::from_montgomery_limbs([
+                16074538482966790467,
+                1889695742182425820,
+                1988009630148298141,
+                747985512537520349,
+            ]),
+            Fq::from_montgomery_limbs([
+                6908974931338345648,
+                2509447823331723008,
+                6061181428098122720,
+                309865580617607620,
+            ]),
+            Fq::from_montgomery_limbs([
+                1542642171686531607,
+                9795761202672306411,
+                16275396653030347372,
+                606362968650290708,
+            ]),
+            Fq::from_montgomery_limbs([
+                250439127331548732,
+                13548306429719457237,
+                18200308757697593585,
+                608140832794646496,
+            ]),
+            Fq::from_montgomery_limbs([
+                9792429147052773903,
+                13932225170212453681,
+                7445093537295219872,
+                1310204422318721540,
+            ]),
+            Fq::from_montgomery_limbs([
+                13168625466320176513,
+                8668758253748849942,
+                14712022192522737137,
+                236676817856650400,
+            ]),
+            Fq::from_montgomery_limbs([
+                5538029257215681339,
+                18328247777762505587,
+                10213819180545996374,
+                749500787894965691,
+            ]),
+            Fq::from_montgomery_limbs([
+                15736678092569930629,
+                12803716758737594905,
+                16810263143168145991,
+                1318354266830266470,
+            ]),
+            Fq::from_montgomery_limbs([
+                2916718689179155180,
+                134559886715066582,
+                4332830762296752426,
+                1246479789540676222,
+            ]),
+            Fq::from_montgomery_limbs([
+                4404487185882818970,
+                14729527076164402500,
+                15825768527741860020,
+                621664866817310049,
+            ]),
+            Fq::from_montgomery_limbs([
+                8819462154402103901,
+                2582124528649838386,
+                7220488413449483972,
+                718265034232331587,
+            ]),
+            Fq::from_montgomery_limbs([
+                6771333096856843160,
+                1402107517857396655,
+                1133009138288524612,
+                1149275298706647856,
+            ]),
+            Fq::from_montgomery_limbs([
+                17491869211217561017,
+                2829254324190470398,
+                4324666525940431304,
+                176474596954741140,
+            ]),
+            Fq::from_montgomery_limbs([
+                7726384653235379131,
+                15005674743911252682,
+                13888834447189590138,
+                1014628311520343272,
+            ]),
+            Fq::from_montgomery_limbs([
+                14186994624663379615,
+                14042491295549231137,
+                11873143682435129396,
+                706853857900696184,
+            ]),
+            Fq::from_montgomery_limbs([
+                17084281042223149986,
+                3358911587953170313,
+                1861482273957226231,
+                965663650392071752,
+            ]),
+            Fq::from_montgomery_limbs([
+                13688390163184041427,
+                12218533625005547788,
+                3684843324949300991,
+                59246723449851546,
+            ]),
+            Fq::from_montgomery_limbs([
+                12924050682620449274,
+                9581352560075473262,
+                1693865507277786019,
+                37010082849115733,
+            ]),
+            Fq::from_montgomery_limbs([
+                16440127558289254856,
+                7820276423986984729,
+                15891555793663590997,
+                655997647322925579,
+            ]),
+            Fq::from_montgomery_limbs([
+                275910814369036721,
+                7830344065161235298,
+                12046346545077535828,
+                154276828524452023,
+            ]),
+            Fq::from_montgomery_limbs([
+                8672153946578624631,
+                17233094054637290301,
+                1295802356452750280,
+                1000346036275653060,
+            ]),
+            Fq::from_montgomery_limbs([
+                7800290990246797564,
+                16350358953087183751,
+                1617080743003254543,
+                284801398363606284,
+            ]),
+            Fq::from_montgomery_limbs([
+                17851958961960573790,
+                17802335786895371191,
+                14459445535794229723,
+                1025976879122571971,
+            ]),
+            Fq::from_montgomery_limbs([
+                18072030819593519447,
+                5714735120146498457,
+                9012140844174600528,
+                116122294735379193,
+            ]),
+            Fq::from_montgomery_limbs([
+                7841124045074871740,
+                12239766093473933650,
+                16053186591408458816,
+                532884434454675286,
+            ]),
+            Fq::from_montgomery_limbs([
+                8390766526089079724,
+                10652372959145428135,
+                15709532966338454334,
+                136573505693717815,
+            ]),
+            Fq::from_montgomery_limbs([
+                6130610546725576110,
+                3307866066772764232,
+                339487046562091895,
+                1035310203960350272,
+            ]),
+            Fq::from_montgomery_limbs([
+                1530185199674976198,
+                18156768701312352780,
+                17595971241117010860,
+                1062890191609765870,
+            ]),
+            Fq::from_montgomery_limbs([
+                15481489683627333129,
+                17955817394704215285,
+                8931673437941676011,
+                1258353874065247059,
+            ]),
+            Fq::from_montgomery_limbs([
+                2484789365548133509,
+                2115135633968339797,
+                1400053395548078902,
+                1011640556002173459,
+            ]),
+            Fq::from_montgomery_limbs([
+                2948308698146444176,
+                13645519187196770485,
+                5336433994728177941,
+                782837964968158329,
+            ]),
+            Fq::from_montgomery_limbs([
+                12235858274264503940,
+                6591567530427311278,
+                14029196789933448032,
+                1227348208252384795,
+            ]),
+            Fq::from_montgomery_limbs([
+                13263338150352032441,
+                15184966598903256291,
+                18323682397781727288,
+                1089584417863042970,
+            ]),
+            Fq::from_montgomery_limbs([
+                980520672280324239,
+                3868731209739750044,
+                5114670897245660817,
+                801543428938034743,
+            ]),
+            Fq::from_montgomery_limbs([
+                6716896392390134493,
+                16039992827102372650,
+                4255243367983922401,
+                533953408310986377,
+            ]),
+            Fq::from_montgomery_limbs([
+                12315165005716179394,
+                17303379976058032663,
+                17053548325065231275,
+                426955387652412363,
+            ]),
+            Fq::from_montgomery_limbs([
+                7448658191196907453,
+                3334392774527796951,
+                17718591333075590798,
+                329945433971362805,
+            ]),
+            Fq::from_montgomery_limbs([
+                18205618629580565070,
+                11526423414594717486,
+                1161519771819892656,
+                361449138499118645,
+            ]),
+            Fq::from_montgomery_limbs([
+                3177893315046169217,
+                12234968482060802315,
+                4419631006703035971,
+                806109723755904564,
+            ]),
+            Fq::from_montgomery_limbs([
+                4270834697842491456,
+                17988358610387888171,
+                10926675753870629535,
+                628327677459126546,
+            ]),
+            Fq::from_montgomery_limbs([
+                1715532936415104319,
+                16298868885710197299,
+                11643240519696309375,
+                440801712741796239,
+            ]),
+            Fq::from_montgomery_limbs([
+                4738924579378779955,
+                15206134270372601235,
+                16238658830734994541,
+                421619294033088789,
+            ]),
+            Fq::from_montgomery_limbs([
+                3885148830298259226,
+                9836584196020697149,
+                6688363115805471438,
+                298004975574645639,
+            ]),
+            Fq::from_montgomery_limbs([
+                490544272458469825,
+                10378257784348435971,
+                6064195098546370529,
+                351057943682936969,
+            ]),
+            Fq::from_montgomery_limbs([
+                11680211252328532762,
+                10082601149851599677,
+                8247669232215690524,
+                596868789652946197,
+            ]),
+            Fq::from_montgomery_limbs([
+                5871036736889602349,
+                8373493687237790567,
+                8999179009262499937,
+                105970277822346596,
+            ]),
+            Fq::from_montgomery_limbs([
+                1874723561589265023,
+                18116248425675216034,
+                3318785052356424934,
+                854884048585542339,
+            ]),
+            Fq::from_montgomery_limbs([
+                2271960619614742091,
+                2641344218720034589,
+                9759273663099333248,
+                704191091827414507,
+            ]),
+            Fq::from_montgomery_limbs([
+                12249497755099616283,
+                14654712749369973980,
+                5383498334334333265,
+                130449193469124754,
+            ]),
+            Fq::from_montgomery_limbs([
+                2377095639838351487,
+                16981979908342463776,
+                17883000490689846458,
+                1313493459225953115,
+            ]),
+            Fq::from_montgomery_limbs([
+                6118237015266672851,
+                9885258615763449508,
+                5722037933360454438,
+                329538575157988847,
+            ]),
+            Fq::from_montgomery_limbs([
+                2326964937490415524,
+                1996845013220505470,
+                11238175818744728510,
+                1077620708258228364,
+            ]),
+            Fq::from_montgomery_limbs([
+                2905637807630438111,
+                11681040983534122941,
+                1480056147455998835,
+                191250621739223456,
+            ]),
+            Fq::from_montgomery_limbs([
+                17355087471272975405,
+                16937264161594680082,
+                16137820419878587726,
+                1074661496926905300,
+            ]),
+            Fq::from_montgomery_limbs([
+                17175377579132528481,
+                9089487659629602178,
+                10925152995117295205,
+                977103098389293346,
+            ]),
+            Fq::from_montgomery_limbs([
+                3060344928304546917,
+                2738380238916022824,
+                12868037821292899013,
+                383904254971126713,
+            ]),
+            Fq::from_montgomery_limbs([
+                8919310775491750471,
+                1191524578575139522,
+                773012015251132351,
+                119447754031827599,
+            ]),
+            Fq::from_montgomery_limbs([
+                7963172272740710801,
+                16619608207891859118,
+                3162494952380648567,
+                1154750825360237559,
+            ]),
+            Fq::from_montgomery_limbs([
+                14715304393545055542,
+                6313882564566655602,
+                14693218689057869334,
+                943467444310570808,
+            ]),
+            Fq::from_montgomery_limbs([
+                15380671815643171764,
+                6978593273149384935,
+                5127938226228948630,
+                1167136071057214679,
+            ]),
+            Fq::from_montgomery_limbs([
+                3097293190703039479,
+                10658820098859198684,
+                2483459511081334434,
+                213927875422759074,
+            ]),
+            Fq::from_montgomery_limbs([
+                14420901856516214262,
+                2735528079159381559,
+                11537598207814726412,
+                1011381328812781785,
+            ]),
+            Fq::from_montgomery_limbs([
+                7527658155670674896,
+                9490798498071565270,
+                1158360679535503417,
+                312157524908205740,
+            ]),
+            Fq::from_montgomery_limbs([
+                1813682343803605522,
+                5583836397438602925,
+                5514701022085624518,
+                33202680157258642,
+            ]),
+            Fq::from_montgomery_limbs([
+                2400895191988697448,
+                1547630242720185481,
+                16519915147986482403,
+                208792763124696646,
+            ]),
+            Fq::from_montgomery_limbs([
+                16625555545377545648,
+                12001757085666902235,
+                12455426575376352165,
+                936857540509112861,
+            ]),
+            Fq::from_montgomery_limbs([
+                869495028932459674,
+                4435424779782450054,
+                17219861000714608603,
+                1146433417284160602,
+            ]),
+            Fq::from_montgomery_limbs([
+                328339912950589687,
+                16884278228116654831,
+                5097261961370125910,
+                334387844322503935,
+            ]),
+            Fq::from_montgomery_limbs([
+                2785750596877983503,
+                5422197291235763924,
+                6002406462495623484,
+                584613317439841730,
+            ]),
+            Fq::from_montgomery_limbs([
+                12137285302047361796,
+                1388668477505622082,
+                9222383335100074594,
+                239977307199389359,
+            ]),
+            Fq::from_montgomery_limbs([
+                15748711222882014388,
+                3237445874417489853,
+                10623893825747724730,
+                981008479510058607,
+            ]),
+            Fq::from_montgomery_limbs([
+                13463887647802577232,
+                10810427171325810226,
+                16511909227808010012,
+                451056729378080874,
+            ]),
+            Fq::from_montgomery_limbs([
+                4375532794918960947,
+                9640234998490513780,
+                4022036432347734374,
+                57401962873909640,
+            ]),
+            Fq::from_montgomery_limbs([
+                2282558083896905636,
+                14311356732776839162,
+                17289098691971153770,
+                245170647256395307,
+            ]),
+            Fq::from_montgomery_limbs([
+                7526196616335843383,
+                7715028930341787488,
+                9187465446509964325,
+                365475381307871887,
+            ]),
+            Fq::from_montgomery_limbs([
+                4811948681330593257,
+                626948441783366907,
+                6672134841584778121,
+                1006923912108267982,
+            ]),
+            Fq::from_montgomery_limbs([
+                15147954989900874118,
+                3686068963327807812,
+                6996223460944747344,
+                401214733816249748,
+            ]),
+            Fq::from_montgomery_limbs([
+                15967034816374846958,
+                14156210016871035876,
+                6169318495339961538,
+                444467269598576128,
+            ]),
+            Fq::from_montgomery_limbs([
+                1162876394153034758,
+                3591367866474282049,
+                4023916654433639535,
+                12928119627239756,
+            ]),
+            Fq::from_montgomery_limbs([
+                8459073299823963518,
+                12182374923512807413,
+                2676627434020069043,
+                1316115414997592122,
+            ]),
+            Fq::from_montgomery_limbs([
+                14998429092817994453,
+                4666459659281741718,
+                12741274716302316322,
+                926152797365361625,
+            ]),
+            Fq::from_montgomery_limbs([
+                3077122619281524632,
+                17623867387655736797,
+                1417645929625990368,
+                681765992962924460,
+            ]),
+            Fq::from_montgomery_limbs([
+                9960793740019383988,
+                12332389373379850312,
+                2031630421235607836,
+                988232744841907093,
+            ]),
+            Fq::from_montgomery_limbs([
+                10661698114763565688,
+                8173140449900492687,
+                5969898649017237102,
+                884179738437583139,
+            ]),
+            Fq::from_montgomery_limbs([
+                3181229450517816580,
+                5008790591884662922,
+                11457978776465389257,
+                100829497982019871,
+            ]),
+            Fq::from_montgomery_limbs([
+                473369647437570726,
+                1969354900848059461,
+                15859383467969799297,
+                860286704918355406,
+            ]),
+            Fq::from_montgomery_limbs([
+                9774847289113796552,
+                11209111186630765013,
+                6893063188467873388,
+                522413690080265662,
+            ]),
+            Fq::from_montgomery_limbs([
+                6316516502747009960,
+                10456926916732579680,
+                17953196183357915535,
+                1187502933528864847,
+            ]),
+            Fq::from_montgomery_limbs([
+                4940463307827995766,
+                9602114393821216067,
+                1553764785928515692,
+                445689320032254482,
+            ]),
+            Fq::from_montgomery_limbs([
+                7869291607121878470,
+                4200409643031042478,
+                12795958403432276709,
+                520622825555990631,
+            ]),
+            Fq::from_montgomery_limbs([
+                9950553020187635936,
+                8906244417765925075,
+                12465692656664906535,
+                874146427655303175,
+            ]),
+            Fq::from_montgomery_limbs([
+                12430044005684339878,
+                4700840393559469915,
+                17281144850036619832,
+                262785595504509823,
+            ]),
+            Fq::from_montgomery_limbs([
+                12014030420094534841,
+                6381568492438858768,
+                15064255190673366132,
+                847052436281307945,
+            ]),
+            Fq::from_montgomery_limbs([
+                17575342124343407665,
+                14705479130456907276,
+                16247796629053916689,
+                954237320762095024,
+            ]),
+            Fq::from_montgomery_limbs([
+                14819875681778602526,
+                11564445134199475982,
+                601504137259776412,
+                992982134349922912,
+            ]),
+            Fq::from_montgomery_limbs([
+                3619746316034017517,
+                13252002398735922956,
+                8269723435570765035,
+                560626952691852655,
+            ]),
+            Fq::from_montgomery_limbs([
+                18302676295866002684,
+                15474936971330188868,
+                8445279476572836193,
+                509342433437917849,
+            ]),
+            Fq::from_montgomery_limbs([
+                15820273964570495076,
+                17768289785986574962,
+                5658479222215327185,
+                1008774209788624155,
+            ]),
+            Fq::from_montgomery_limbs([
+                3037421034381051300,
+                13604202975714459774,
+                16861985106323776778,
+                1112554048356913625,
+            ]),
+            Fq::from_montgomery_limbs([
+                12396855738182563372,
+                4426854402510856769,
+                10742884113713475153,
+                795725227456231251,
+            ]),
+            Fq::from_montgomery_limbs([
+                437743033447997466,
+                8197821873185173168,
+                11718877381966959127,
+                1094110468911035384,
+            ]),
+            Fq::from_montgomery_limbs([
+                17766961748370771212,
+                9475416639765908535,
+                2556342828888321443,
+                923559927965642767,
+            ]),
+            Fq::from_montgomery_limbs([
+                3295046459556920405,
+                11777094342503867716,
+                17358638418681084626,
+                709437046004488473,
+            ]),
+            Fq::from_montgomery_limbs([
+                5432966643046189147,
+                8120274022586075356,
+                9668097016102982081,
+                647052192564681427,
+            ]),
+            Fq::from_montgomery_limbs([
+                12462369951732652106,
+                9169808167489191751,
+                3484707730682519693,
+                164676546942756935,
+            ]),
+            Fq::from_montgomery_limbs([
+                10084781339680173911,
+                4992360760061918379,
+                1665140683049696855,
+                126967468973282197,
+            ]),
+            Fq::from_montgomery_limbs([
+                5843098202474427477,
+                12220932873532645881,
+                13995820858052726627,
+                785294171220708480,
+            ]),
+            Fq::from_montgomery_limbs([
+                5564506029678162447,
+                13204809602040601931,
+                7933081226880954409,
+                310292426228404632,
+            ]),
+            Fq::from_montgomery_limbs([
+                4912349251194407415,
+                12346745362780762372,
+                12524033784099194751,
+                106657249132602040,
+            ]),
+            Fq::from_montgomery_limbs([
+                5737454499243293701,
+                8819569890613110028,
+                2088465568252990498,
+                610091962273480327,
+            ]),
+            Fq::from_montgomery_limbs([
+                7949118384402632886,
+                11051172570102303919,
+                5078036288642517513,
+                844347384840913808,
+            ]),
+            Fq::from_montgomery_limbs([
+                15638305839006279361,
+                6178281059935477487,
+                6015644008967255682,
+                557431737054187406,
+            ]),
+            Fq::from_montgomery_limbs([
+                16536667649594037914,
+                16243857140029461601,
+                6837168689149023970,
+                1214872120114713179,
+            ]),
+            Fq::from_montgomery_limbs([
+                17229074664051086239,
+                17879112823057227049,
+                3366726426123554552,
+                860319355627088952,
+            ]),
+            Fq::from_montgomery_limbs([
+                6002892506517228143,
+                5653463312604672773,
+                6792759338521014635,
+                559708407456624084,
+            ]),
+            Fq::from_montgomery_limbs([
+                15853476798575543148,
+                1851290899221139816,
+                16392773746728624615,
+                343041998287461699,
+            ]),
+            Fq::from_montgomery_limbs([
+                459954071484487346,
+                6253985312522142132,
+                9718217013674226651,
+                410290838024766219,
+            ]),
+            Fq::from_montgomery_limbs([
+                11053764394850625943,
+                2418847175262425868,
+                11405794128347220737,
+                931891830664619004,
+            ]),
+            Fq::from_montgomery_limbs([
+                10660405780449550161,
+                5492404844201059148,
+                11655054044194038169,
+                148797575288275667,
+            ]),
+            Fq::from_montgomery_limbs([
+                5741833707534844749,
+                3578230736230496250,
+                10933589615535047653,
+                550627230176816731,
+            ]),
+            Fq::from_montgomery_limbs([
+                15334018351230049540,
+                12852078552358423880,
+                5758005822638903998,
+                1073772599846286527,
+            ]),
+            Fq::from_montgomery_limbs([
+                3006747108499668261,
+                4673027131745595783,
+                9050696649303552442,
+                344331863458273051,
+            ]),
+            Fq::from_montgomery_limbs([
+                15615932803510547469,
+                2253019764918375090,
+                12237723036780950473,
+                1125448587044593954,
+            ]),
+            Fq::from_montgomery_limbs([
+                11063525524207380643,
+                13370776480321081994,
+                13394379511021345244,
+                957069547391894229,
+            ]),
+            Fq::from_montgomery_limbs([
+                1631963705013525633,
+                12844097521528691435,
+                12538102254778499230,
+                1173405008986208286,
+            ]),
+            Fq::from_montgomery_limbs([
+                4208234752558451542,
+                12338936773376186249,
+                8291863525932696090,
+                1194566389771657287,
+            ]),
+            Fq::from_montgomery_limbs([
+                7151006151096348794,
+                16144334449579713627,
+                9329749637865072836,
+                523924281014579983,
+            ]),
+            Fq::from_montgomery_limbs([
+                10022340414205029513,
+                7573972240063429231,
+                15015717909967586427,
+                1191889392515760449,
+            ]),
+            Fq::from_montgomery_limbs([
+                14908154480425122302,
+                11531142125310823505,
+                15898376067257966411,
+                1254740259435150489,
+            ]),
+            Fq::from_montgomery_limbs([
+                16029788486998931595,
+                4804972196920017419,
+                3487844581690264441,
+                1268392454430498383,
+            ]),
+            Fq::from_montgomery_limbs([
+                3088538144900244307,
+                15480397362698632132,
+                4753412320881610399,
+                1285738691432807948,
+            ]),
+            Fq::from_montgomery_limbs([
+                901054690070827864,
+                16565523115985010212,
+                7915258479501367566,
+                957464999383235878,
+            ]),
+            Fq::from_montgomery_limbs([
+                9710016262759295888,
+                4135998217231951529,
+                16285406489305719095,
+                766284871103729102,
+            ]),
+            Fq::from_montgomery_limbs([
+                14145836012279087773,
+                14316541305878914515,
+                17818370900232283705,
+                1271798605562105652,
+            ]),
+            Fq::from_montgomery_limbs([
+                17680609978084531751,
+                986658448060601408,
+                1383503264414309291,
+                1044842590923688300,
+            ]),
+            Fq::from_montgomery_limbs([
+                11823317329542148609,
+                6224378229289304917,
+                2355030603216713835,
+                1309382701059954156,
+            ]),
+            Fq::from_montgomery_limbs([
+                6808847853801032929,
+                13454179721690007136,
+                16719548495111180122,
+                892468347762713143,
+            ]),
+            Fq::from_montgomery_limbs([
+                11862441858721001361,
+                3720457049309364107,
+                2595438784916016657,
+                803515218739083961,
+            ]),
+            Fq::from_montgomery_limbs([
+                15700664022187345401,
+                11044493398436071279,
+                14229611647208789097,
+                440057806288547723,
+            ]),
+            Fq::from_montgomery_limbs([
+                16494821265156894059,
+                12492571107673391018,
+                5847281956737793904,
+                1211443084570759802,
+            ]),
+            Fq::from_montgomery_limbs([
+                3831460418618634762,
+                18205277337034788111,
+                14121075353869290901,
+                70370654384627683,
+            ]),
+            Fq::from_montgomery_limbs([
+                17782913438541937266,
+                15867811317384924940,
+                4891725563514576740,
+                1158930964948426763,
+            ]),
+            Fq::from_montgomery_limbs([
+                12531660811526626169,
+                6958324480583125045,
+                18144886356827458396,
+                258359140977996032,
+            ]),
+            Fq::from_montgomery_limbs([
+                10733667746083296455,
+                18308438525210460341,
+                5960600436580230194,
+                470998104788952966,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217080364780639445,
+                8019324683016052363,
+                6176387298339364984,
+                479468061580188245,
+            ]),
+            Fq::from_montgomery_limbs([
+                10497012733867694369,
+                17308259373327506725,
+                1944772611672173494,
+                142157655660788645,
+            ]),
+            Fq::from_montgomery_limbs([
+                9846387911403631191,
+                13033741061093719565,
+                16040045861233051360,
+                634820460156847828,
+            ]),
+            Fq::from_montgomery_limbs([
+                12332639203822498481,
+                5269427380955850750,
+                15153303570081609460,
+                501433229823469268,
+            ]),
+            Fq::from_montgomery_limbs([
+                3297088606759945770,
+                12749946861359003262,
+                7189242494787797613,
+                135070750045436820,
+            ]),
+            Fq::from_montgomery_limbs([
+                16321016844866258749,
+                3414438557819629192,
+                1142249113590001629,
+                283504313619652722,
+            ]),
+            Fq::from_montgomery_limbs([
+                4445878820318919478,
+                14166065666386179693,
+                9365707971086865466,
+                641488226677519077,
+            ]),
+            Fq::from_montgomery_limbs([
+                460920276445855569,
+                5779693490310882337,
+                12932174586503153174,
+                98340723175362175,
+            ]),
+            Fq::from_montgomery_limbs([
+                10773849045255829787,
+                9031660345737781539,
+                13566802937172820542,
+                916192737893108628,
+            ]),
+            Fq::from_montgomery_limbs([
+                17147401009951847257,
+                1760652812569605846,
+                2124850554444242160,
+                313027698782739283,
+            ]),
+            Fq::from_montgomery_limbs([
+                6866241551854175991,
+                2623035522958032491,
+                17836234780048337861,
+                1231535591689678776,
+            ]),
+            Fq::from_montgomery_limbs([
+                1080329226001776429,
+                14698603291152524052,
+                9014535321343364941,
+                1118491696442455794,
+            ]),
+            Fq::from_montgomery_limbs([
+                9477743980355985610,
+                1737765258289309299,
+                7862704922538731327,
+                680811755188512196,
+            ]),
+            Fq::from_montgomery_limbs([
+                16974207132722121392,
+                4520006891923132347,
+                13139674397091707384,
+                1104303683945601975,
+            ]),
+            Fq::from_montgomery_limbs([
+                17048285873007105406,
+                8928907622094920634,
+                11453307205392666103,
+                284007514461767557,
+            ]),
+            Fq::from_montgomery_limbs([
+                12607841094576273760,
+                6913753116077502646,
+                2403710355030871640,
+                777185406314935379,
+            ]),
+            Fq::from_montgomery_limbs([
+                17977872665282199164,
+                5362403408439303269,
+                2956030334218415669,
+                967280617065162041,
+            ]),
+            Fq::from_montgomery_limbs([
+                6142168311526719982,
+                11427941792385156362,
+                16841850043325847536,
+                143288120825235650,
+            ]),
+            Fq::from_montgomery_limbs([
+                2900033965874149299,
+                8463225674606297531,
+                15403256883563801780,
+                1248965358086155055,
+            ]),
+            Fq::from_montgomery_limbs([
+                10432334730939067016,
+                17589721175484933324,
+                901552708766725898,
+                461536715570247559,
+            ]),
+            Fq::from_montgomery_limbs([
+                5163380012758325519,
+                11303932102988781799,
+                15513778986105047401,
+                738884058278867349,
+            ]),
+            Fq::from_montgomery_limbs([
+                6398290491185264732,
+                7166180448571811880,
+                8791759317604746175,
+                265460818975159730,
+            ]),
+            Fq::from_montgomery_limbs([
+                870638445919343325,
+                10710619698965247629,
+                16915748071272821645,
+                955264987823487643,
+            ]),
+            Fq::from_montgomery_limbs([
+                5064266998552356124,
+                10274895984339435791,
+                7681363455870079285,
+                664525446201143805,
+            ]),
+            Fq::from_montgomery_limbs([
+                10654758194061733275,
+                7664512599123034302,
+                14413440525948159834,
+                480525635411179614,
+            ]),
+            Fq::from_montgomery_limbs([
+                13173218391284580925,
+                5620220712823698646,
+                1904640118835006478,
+                682815117489980266,
+            ]),
+            Fq::from_montgomery_limbs([
+                9900104375200889196,
+                11154192848321526635,
+                8254013524431872818,
+                427661811925781275,
+            ]),
+            Fq::from_montgomery_limbs([
+                6119628700734066314,
+                10644494207276860342,
+                6379089322289684170,
+                17823735329236550,
+            ]),
+            Fq::from_montgomery_limbs([
+                4185205792351831677,
+                5743171380737024281,
+                4985300799857740755,
+                557302362669515309,
+            ]),
+            Fq::from_montgomery_limbs([
+                10518589555959162223,
+                15575662747462650416,
+                6196555657835126901,
+                754349541202149552,
+            ]),
+            Fq::from_montgomery_limbs([
+                3106561122658217440,
+                6900941139022716712,
+                2984999613213765466,
+                164222408803289592,
+            ]),
+            Fq::from_montgomery_limbs([
+                16334782685797661791,
+                7080936140056317271,
+                17627004457989105866,
+                1289266239050180980,
+            ]),
+            Fq::from_montgomery_limbs([
+                13141625358513822576,
+                13336736958347242364,
+                6692427952162132340,
+                446491245194148251,
+            ]),
+            Fq::from_montgomery_limbs([
+                17503379192233771090,
+                10779597696239069153,
+                7146750910501688103,
+                1073891125413647737,
+            ]),
+            Fq::from_montgomery_limbs([
+                6495463101308755209,
+                8451565008604106263,
+                1576204386767407205,
+                542985986639891259,
+            ]),
+            Fq::from_montgomery_limbs([
+                2166375833039515449,
+                4545488886633329512,
+                14691607554224161641,
+                821723752936336016,
+            ]),
+            Fq::from_montgomery_limbs([
+                17242671234838440401,
+                5289815436873003454,
+                13074258161738263329,
+                531149112108191108,
+            ]),
+            Fq::from_montgomery_limbs([
+                5195866528667413581,
+                16773573464494004556,
+                14373263746653422365,
+                929846977249050402,
+            ]),
+            Fq::from_montgomery_limbs([
+                14257583721771679699,
+                6401848577373766440,
+                6176374491331218814,
+                152728232515667029,
+            ]),
+            Fq::from_montgomery_limbs([
+                1818051867232967066,
+                8241960923253393965,
+                16901517285643761773,
+                787900698835125264,
+            ]),
+            Fq::from_montgomery_limbs([
+                3924544412936678142,
+                2842473310106930360,
+                8011839074709560059,
+                59085705958886610,
+            ]),
+            Fq::from_montgomery_limbs([
+                11139497190411918759,
+                5996035186505770383,
+                2944845289235046081,
+                957407830047398059,
+            ]),
+            Fq::from_montgomery_limbs([
+                6857509626169369887,
+                1308218980169487518,
+                1121870727502523504,
+                743842713502886682,
+            ]),
+            Fq::from_montgomery_limbs([
+                12181462350130995276,
+                13503924573887246069,
+                12767664259293171801,
+                811303195187057679,
+            ]),
+            Fq::from_montgomery_limbs([
+                7086483113970479399,
+                8524074036068170311,
+                202231281436452467,
+                1126606723091378768,
+            ]),
+            Fq::from_montgomery_limbs([
+                14873167936546027474,
+                3581026437743831975,
+                5831040091973515148,
+                525655640503780614,
+            ]),
+            Fq::from_montgomery_limbs([
+                15596348899748707760,
+                11030520640785203150,
+                17997748916140448999,
+                1113446356677395039,
+            ]),
+            Fq::from_montgomery_limbs([
+                15562031436238960018,
+                8958812541212700978,
+                13307132588487038821,
+                720029628142363089,
+            ]),
+            Fq::from_montgomery_limbs([
+                6066414821204594314,
+                7709357165231667690,
+                8039319025393373027,
+                214361201511905218,
+            ]),
+            Fq::from_montgomery_limbs([
+                10763890163887934422,
+                12812983314088026191,
+                15913728158395328815,
+                760255202317217430,
+            ]),
+            Fq::from_montgomery_limbs([
+                13289143362459856467,
+                9706502194365764852,
+                7592746239865036049,
+                652443242850121072,
+            ]),
+            Fq::from_montgomery_limbs([
+                12107530748729584483,
+                9067256948427177836,
+                6805105040594673610,
+                798433345756793078,
+            ]),
+            Fq::from_montgomery_limbs([
+                7108932530741030112,
+                2898366149882689478,
+                3522885332430960418,
+                996929288926706503,
+            ]),
+            Fq::from_montgomery_limbs([
+                511511233836747696,
+                9662840264642359640,
+                6399779884804730335,
+                276426445850259116,
+            ]),
+            Fq::from_montgomery_limbs([
+                18199124694577686383,
+                556093294960306916,
+                12733043510529836920,
+                856822059419040147,
+            ]),
+            Fq::from_montgomery_limbs([
+                3186478160831870600,
+                18382348723031459354,
+                2456718458307434737,
+                592859542586162673,
+            ]),
+            Fq::from_montgomery_limbs([
+                17061874908456955359,
+                848668730483787115,
+                14374365752686088125,
+                333278489502165914,
+            ]),
+            Fq::from_montgomery_limbs([
+                14641782570174528157,
+                2725982088225095664,
+                10903923399667598867,
+                717173791547294041,
+            ]),
+            Fq::from_montgomery_limbs([
+                3389928256642354969,
+                15604782093964005091,
+                18022982450564988702,
+                699545869388275500,
+            ]),
+            Fq::from_montgomery_limbs([
+                13006443265230302682,
+                17131919540935530971,
+                14498638009460130192,
+                790451229432682019,
+            ]),
+            Fq::from_montgomery_limbs([
+                8925749194871862091,
+                16507096828849957227,
+                12542442124326712686,
+                581681253109946641,
+            ]),
+            Fq::from_montgomery_limbs([
+                2654372362425679534,
+                7234866315062343244,
+                17760988697319828060,
+                370120521067411533,
+            ]),
+            Fq::from_montgomery_limbs([
+                1584686283257056895,
+                16813461232184163676,
+                15215950177338296215,
+                100619045994249303,
+            ]),
+            Fq::from_montgomery_limbs([
+                17641245043953805539,
+                15980197945511630849,
+                428760899705815461,
+                1244051389708280827,
+            ]),
+            Fq::from_montgomery_limbs([
+                5832362977702250902,
+                1192561603887550518,
+                8161655093152317515,
+                410053221165340293,
+            ]),
+            Fq::from_montgomery_limbs([
+                12036903416314029820,
+                18023955750414888737,
+                9285998926539761271,
+                706795283762471110,
+            ]),
+            Fq::from_montgomery_limbs([
+                3097576850619517147,
+                14913488442936066577,
+                12402910660184019374,
+                164433195604794725,
+            ]),
+            Fq::from_montgomery_limbs([
+                18137010366057137674,
+                9955308088208821679,
+                12638896732704744047,
+                707291430796508701,
+            ]),
+            Fq::from_montgomery_limbs([
+                8225658822327990366,
+                16295707842087286713,
+                8644564141742576507,
+                1017992758881645914,
+            ]),
+            Fq::from_montgomery_limbs([
+                14824883334995016839,
+                2720092984350008982,
+                16775850647268085652,
+                5075966403545137,
+            ]),
+            Fq::from_montgomery_limbs([
+                12348835057036157163,
+                13888349265909027726,
+                11133196105846278240,
+                135605656857532305,
+            ]),
+            Fq::from_montgomery_limbs([
+                9944223106929166631,
+                7980474472453682447,
+                9715935366570469117,
+                932732123905526736,
+            ]),
+            Fq::from_montgomery_limbs([
+                6511033952134749538,
+                15739899239016457440,
+                5146083476879847391,
+                421838614332380306,
+            ]),
+            Fq::from_montgomery_limbs([
+                9012229307718264302,
+                2946763839017906838,
+                1911086851118994211,
+                1097085287574927895,
+            ]),
+            Fq::from_montgomery_limbs([
+                1407954002123467700,
+                1847588185912816365,
+                4857095844628421941,
+                1307987139503141435,
+            ]),
+            Fq::from_montgomery_limbs([
+                13885369254822787470,
+                14432637873944453582,
+                4149343094661365455,
+                245342897798351526,
+            ]),
+            Fq::from_montgomery_limbs([
+                9271175422390331134,
+                2318559992713734541,
+                10914924460339684954,
+                611956524509165646,
+            ]),
+            Fq::from_montgomery_limbs([
+                16146688480281701859,
+                5966921400804699577,
+                3826145619124924988,
+                909016011071232946,
+            ]),
+            Fq::from_montgomery_limbs([
+                5463759914185255713,
+                1765642299194487693,
+                13281871365058405462,
+                1243987817410750178,
+            ]),
+            Fq::from_montgomery_limbs([
+                9514058538715175358,
+                14618194590936343693,
+                16102354501450126589,
+                514217955202317549,
+            ]),
+            Fq::from_montgomery_limbs([
+                8283409775856656109,
+                3140229382328457712,
+                3119146289724665961,
+                649583913913319922,
+            ]),
+            Fq::from_montgomery_limbs([
+                15871427768154680674,
+                7160633664063636067,
+                16550189420194244347,
+                642698188834162055,
+            ]),
+            Fq::from_montgomery_limbs([
+                16807967697959763471,
+                5033885243660919771,
+                13396323018938633237,
+                822047754557817022,
+            ]),
+            Fq::from_montgomery_limbs([
+                10781065380970369748,
+                8387433124867895267,
+                15531196270170918708,
+                848645096418327964,
+            ]),
+            Fq::from_montgomery_limbs([
+                11881662464676968926,
+                7119322812800718250,
+                12243320766715050526,
+                368820299032354597,
+            ]),
+            Fq::from_montgomery_limbs([
+                12402766829201625187,
+                1279414450373755060,
+                15550781148446728391,
+                527872562016412746,
+            ]),
+            Fq::from_montgomery_limbs([
+                12568361460553885878,
+                6619757253775881395,
+                17013876776925260683,
+                784414766058618697,
+            ]),
+            Fq::from_montgomery_limbs([
+                11026329638774787583,
+                8685136422646155893,
+                8978079170551929828,
+                1211694234180067969,
+            ]),
+            Fq::from_montgomery_limbs([
+                8951251416264489595,
+                921401751819397191,
+                12367694307699524768,
+                959625245647529015,
+            ]),
+            Fq::from_montgomery_limbs([
+                16935604567242583570,
+                2454897112749915271,
+                3846734109494569911,
+                1090490141999486198,
+            ]),
+            Fq::from_montgomery_limbs([
+                8311738955180247728,
+                5603528371450845200,
+                18339504071550203845,
+                477948533329384322,
+            ]),
+            Fq::from_montgomery_limbs([
+                15442387590205463816,
+                14002118501430914269,
+                9076875159934538433,
+                76105176182572950,
+            ]),
+            Fq::from_montgomery_limbs([
+                10636717512349834204,
+                3013733785668134133,
+                8205689862027798859,
+                432386384430552398,
+            ]),
+            Fq::from_montgomery_limbs([
+                2531057690286023008,
+                1685008912373042880,
+                14008656812446340697,
+                1041844601086848874,
+            ]),
+            Fq::from_montgomery_limbs([
+                18018301644743585176,
+                8062906363368384260,
+                7574090297443123585,
+                46661871678825051,
+            ]),
+            Fq::from_montgomery_limbs([
+                12688664592577321445,
+                14015667305039670912,
+                94506217496930317,
+                665146307408490320,
+            ]),
+            Fq::from_montgomery_limbs([
+                7828630587952469470,
+                17014996052958959379,
+                6965374942379861515,
+                224507821868949708,
+            ]),
+            Fq::from_montgomery_limbs([
+                2050796345975263269,
+                11640827378622517165,
+                9680414161773448495,
+                388343286390991169,
+            ]),
+            Fq::from_montgomery_limbs([
+                8588304085299843112,
+                2795159832180099062,
+                7564820899466563378,
+                795568994920719036,
+            ]),
+            Fq::from_montgomery_limbs([
+                12593275538490611814,
+                2574435971464429938,
+                626480180225370054,
+                969595206902239884,
+            ]),
+            Fq::from_montgomery_limbs([
+                8227195083827527731,
+                3886816956669576692,
+                13621840734587788463,
+                101788719093289705,
+            ]),
+            Fq::from_montgomery_limbs([
+                15013559128851398698,
+                11618666644938875340,
+                10715086763428032124,
+                624271418240840206,
+            ]),
+            Fq::from_montgomery_limbs([
+                15203980722911042979,
+                13378092706900989940,
+                11066269760611075599,
+                627704580435408770,
+            ]),
+            Fq::from_montgomery_limbs([
+                8976427749652007406,
+                6962635409967883630,
+                13651012784937918982,
+                903095781778756070,
+            ]),
+            Fq::from_montgomery_limbs([
+                8948221487821917351,
+                17991227101020378664,
+                16763548264084950077,
+                512549677859506417,
+            ]),
+            Fq::from_montgomery_limbs([
+                17773287067767413445,
+                15939164446895214454,
+                6748527114134324815,
+                1182632039970167343,
+            ]),
+            Fq::from_montgomery_limbs([
+                17617689472748334348,
+                12771568282372676362,
+                12816659558231439102,
+                552764556084580362,
+            ]),
+            Fq::from_montgomery_limbs([
+                10960457600518687570,
+                889442779849808397,
+                616382588957652674,
+                1162212496395373868,
+            ]),
+            Fq::from_montgomery_limbs([
+                13054079840424447912,
+                14543378537587797540,
+                5509823361611865311,
+                209505560827247871,
+            ]),
+            Fq::from_montgomery_limbs([
+                720883017986672578,
+                15361537341976041541,
+                1147812474558580636,
+                1335476306795569567,
+            ]),
+            Fq::from_montgomery_limbs([
+                17805281888687697203,
+                3787577519535970779,
+                14415099219493266428,
+                563766584728026774,
+            ]),
+            Fq::from_montgomery_limbs([
+                9132963001305776156,
+                9295161864889596325,
+                4655040200026059395,
+                210680319077597906,
+            ]),
+            Fq::from_montgomery_limbs([
+                4098680535128832798,
+                1933336081414138981,
+                7955887488987752821,
+                150854208092191812,
+            ]),
+            Fq::from_montgomery_limbs([
+                6391651409807119789,
+                10265218069884081244,
+                14273777594024376650,
+                1339263358824607294,
+            ]),
+            Fq::from_montgomery_limbs([
+                3969125120708067139,
+                15110362191170002489,
+                2282875019582617666,
+                275846615236844095,
+            ]),
+            Fq::from_montgomery_limbs([
+                18292259280059788909,
+                10649585152159131782,
+                586101716700501962,
+                553972199176726407,
+            ]),
+            Fq::from_montgomery_limbs([
+                14521570112102167644,
+                14983508869753427488,
+                11157165240271522272,
+                196893137254444465,
+            ]),
+            Fq::from_montgomery_limbs([
+                13403483262686984770,
+                3583339924926972095,
+                9641906266688062672,
+                391580466749703887,
+            ]),
+            Fq::from_montgomery_limbs([
+                3745756433386524680,
+                17331074972579358616,
+                2830406868340873292,
+                1086011946670071427,
+            ]),
+            Fq::from_montgomery_limbs([
+                6302581137386073504,
+                3993521924293932428,
+                4052263111773373770,
+                527740957726788463,
+            ]),
+            Fq::from_montgomery_limbs([
+                4866335792836169690,
+                11513583204016321740,
+                4851898784777642778,
+                285535860264580541,
+            ]),
+            Fq::from_montgomery_limbs([
+                6122987509035352457,
+                722230482986007914,
+                272261014510905641,
+                530570261693391336,
+            ]),
+            Fq::from_montgomery_limbs([
+                38572871985993852,
+                17612825572656161578,
+                10029460286721329722,
+                1310707125569844494,
+            ]),
+            Fq::from_montgomery_limbs([
+                5126995005604190204,
+                13917743607786142095,
+                17077042893858842766,
+                969732320768508912,
+            ]),
+            Fq::from_montgomery_limbs([
+                2527058908346553564,
+                16421692328455701938,
+                1269216080333393555,
+                1201532274974784236,
+            ]),
+            Fq::from_montgomery_limbs([
+                15971939788437070404,
+                4235898123386598777,
+                16514683219186520210,
+                572651383058987025,
+            ]),
+            Fq::from_montgomery_limbs([
+                7786235547760960591,
+                3835591337587841253,
+                15562963918838892930,
+                592403375114956310,
+            ]),
+            Fq::from_montgomery_limbs([
+                2503504023813071937,
+                16837370445959572608,
+                3093747410748613925,
+                118912211516549723,
+            ]),
+            Fq::from_montgomery_limbs([
+                16920635969026906000,
+                15746831782741135498,
+                16758946161313594607,
+                318591184940482274,
+            ]),
+            Fq::from_montgomery_limbs([
+                18163302185764563538,
+                6923271388044194157,
+                14891350437710593744,
+                707029934120901277,
+            ]),
+            Fq::from_montgomery_limbs([
+                16315878530582343826,
+                18084488859371794723,
+                9456475903743645653,
+                1008770909246494705,
+            ]),
+            Fq::from_montgomery_limbs([
+                7916538302219478671,
+                17105942070831786780,
+                11273108800123197704,
+                215665609510126717,
+            ]),
+            Fq::from_montgomery_limbs([
+                13644687303333955571,
+                12950745333683519866,
+                4827204603030326548,
+                1152273883663089515,
+            ]),
+            Fq::from_montgomery_limbs([
+                17313026753108667244,
+                9225920186596667791,
+                18207838920182566977,
+                1286587733471372683,
+            ]),
+            Fq::from_montgomery_limbs([
+                12414008424608998393,
+                1355991658331248357,
+                5916576944421378554,
+                718668354157835428,
+            ]),
+            Fq::from_montgomery_limbs([
+                16553655449708738172,
+                13701457876540218103,
+                6811389505303145972,
+                198251063885717785,
+            ]),
+            Fq::from_montgomery_limbs([
+                12497324693625143100,
+                11080365566366243601,
+                17114570559613380368,
+                1344492362072363335,
+            ]),
+            Fq::from_montgomery_limbs([
+                1919701748913641267,
+                16523888377896353634,
+                16695612728452467533,
+                1067198960836246363,
+            ]),
+            Fq::from_montgomery_limbs([
+                6384853710842097900,
+                16997435582354021130,
+                11876769332404286238,
+                45319312109879353,
+            ]),
+            Fq::from_montgomery_limbs([
+                8360764203200138933,
+                7114601820306208437,
+                15415924424979959037,
+                1309639373636346997,
+            ]),
+            Fq::from_montgomery_limbs([
+                1036986536915585277,
+                9326589218195656433,
+                1453199395066764173,
+                185858069546612119,
+            ]),
+            Fq::from_montgomery_limbs([
+                16205204468705503158,
+                11607962165072790295,
+                7890496234627791350,
+                529389405927273215,
+            ]),
+            Fq::from_montgomery_limbs([
+                6001533212126686597,
+                14722676013048873674,
+                2673281962283026571,
+                1203098575372325800,
+            ]),
+            Fq::from_montgomery_limbs([
+                6988543832405423497,
+                2333423003524830854,
+                8550997542203415721,
+                1291390595888173180,
+            ]),
+            Fq::from_montgomery_limbs([
+                1767735559366952542,
+                14427431796697950418,
+                9116916222385102084,
+                1147642541398636296,
+            ]),
+            Fq::from_montgomery_limbs([
+                1850169588772850152,
+                7953941854579913075,
+                7543007566307434507,
+                1047972599739602954,
+            ]),
+            Fq::from_montgomery_limbs([
+                18380100408549142215,
+                630885032019473900,
+                10069101182209585673,
+                1114184790654136195,
+            ]),
+            Fq::from_montgomery_limbs([
+                16343170413307550739,
+                11829917212890290105,
+                16242484369847629119,
+                789728061320040442,
+            ]),
+            Fq::from_montgomery_limbs([
+                18432158460366259903,
+                7664982361568478458,
+                1668093620535284,
+                1252940647539749033,
+            ]),
+            Fq::from_montgomery_limbs([
+                17823826977790637070,
+                5676017805513081836,
+                14789256903052112238,
+                956302933692505593,
+            ]),
+            Fq::from_montgomery_limbs([
+                17326939511004021342,
+                12468815350921386304,
+                15602595237924084126,
+                1063602816548677431,
+            ]),
+            Fq::from_montgomery_limbs([
+                9314424558956455318,
+                11316550325976089689,
+                10937519020185975804,
+                561419609524133244,
+            ]),
+            Fq::from_montgomery_limbs([
+                1816967053868205401,
+                2474703622053487976,
+                7374573658032696152,
+                1119247125872434738,
+            ]),
+            Fq::from_montgomery_limbs([
+                6415001718084353317,
+                17102275264337965223,
+                9337996981830811246,
+                366224418453326249,
+            ]),
+            Fq::from_montgomery_limbs([
+                3228542701914731683,
+                17816397482442674032,
+                4877785275101663421,
+                350274021859925301,
+            ]),
+            Fq::from_montgomery_limbs([
+                3446787474433720330,
+                3533947397919108364,
+                2828792568721393621,
+                509411203950402535,
+            ]),
+            Fq::from_montgomery_limbs([
+                17506134385984160288,
+                17580168595721972420,
+                691090508496416872,
+                404961967683957286,
+            ]),
+            Fq::from_montgomery_limbs([
+                2339965229983811335,
+                15190116320304359439,
+                5844176575063075808,
+                1175875000818572094,
+            ]),
+            Fq::from_montgomery_limbs([
+                6757490488888447561,
+                7057082526081950992,
+                658853042809938848,
+                1632681802549001,
+            ]),
+            Fq::from_montgomery_limbs([
+                8517647164180765035,
+                1002575156610826097,
+                6316877664602462916,
+                168142516121278192,
+            ]),
+            Fq::from_montgomery_limbs([
+                16869892714423333459,
+                16856797021030631706,
+                10432139940450912904,
+                244131028228352987,
+            ]),
+            Fq::from_montgomery_limbs([
+                15801870071323099661,
+                1341488642882084873,
+                6327208823200216368,
+                586589930341109336,
+            ]),
+            Fq::from_montgomery_limbs([
+                5424984692674692745,
+                4863664280332845703,
+                3976071960474231545,
+                1243356774020316478,
+            ]),
+            Fq::from_montgomery_limbs([
+                14851638290530152271,
+                15342111290246176807,
+                7149614070569527684,
+                310162070624199102,
+            ]),
+            Fq::from_montgomery_limbs([
+                5968543964331807221,
+                11252382463021302150,
+                15040751522972531091,
+                920093238940579438,
+            ]),
+            Fq::from_montgomery_limbs([
+                18294856914387428640,
+                7732953992703807462,
+                5607290693964873544,
+                273187437089777281,
+            ]),
+            Fq::from_montgomery_limbs([
+                16271899606589611184,
+                8129567029377751921,
+                330646425667713896,
+                950856500689097577,
+            ]),
+            Fq::from_montgomery_limbs([
+                13011128399387399122,
+                2045594717757625719,
+                14737761438439035903,
+                1246332520350120647,
+            ]),
+            Fq::from_montgomery_limbs([
+                8772730634967469122,
+                4693782814696841346,
+                3594107804787758369,
+                807202366028488635,
+            ]),
+            Fq::from_montgomery_limbs([
+                12466335421337443910,
+                16247789566258793119,
+                12395792089717616816,
+                944115159297132546,
+            ]),
+            Fq::from_montgomery_limbs([
+                6342577107578703346,
+                13824554984311340741,
+                11154048863408510818,
+                792971012258948385,
+            ]),
+            Fq::from_montgomery_limbs([
+                7977219875706108424,
+                7511985463844578227,
+                17144057035915158432,
+                1080800615283931521,
+            ]),
+            Fq::from_montgomery_limbs([
+                7119642447621829806,
+                12630188104425412772,
+                6734472731721694283,
+                731262142369417026,
+            ]),
+            Fq::from_montgomery_limbs([
+                1619213814543187799,
+                12829157040659552988,
+                7638993944384276767,
+                875115481375822949,
+            ]),
+            Fq::from_montgomery_limbs([
+                803906007343738283,
+                14328836340031912005,
+                8807620656043194902,
+                160339955011559636,
+            ]),
+            Fq::from_montgomery_limbs([
+                14554614749198380347,
+                2444284652953174071,
+                16710399763346932338,
+                515778771388983576,
+            ]),
+            Fq::from_montgomery_limbs([
+                11662154889180148768,
+                12929608364139089719,
+                8686896760482046339,
+                1230145210936798524,
+            ]),
+            Fq::from_montgomery_limbs([
+                6849876090584787281,
+                2781140689147201308,
+                9512491943302792394,
+                937469995381870265,
+            ]),
+            Fq::from_montgomery_limbs([
+                9747903122269173656,
+                926157508485706195,
+                13130660627605678758,
+                287931682565412467,
+            ]),
+            Fq::from_montgomery_limbs([
+                3828398096599775949,
+                14763388321440019555,
+                10965665820928408894,
+                1231532666700932645,
+            ]),
+            Fq::from_montgomery_limbs([
+                16931320682697115759,
+                15048637674738557659,
+                845724760409887822,
+                572118602833173291,
+            ]),
+            Fq::from_montgomery_limbs([
+                11406501702890293703,
+                8917177274625237888,
+                11705655057661938576,
+                688969510700032124,
+            ]),
+            Fq::from_montgomery_limbs([
+                4959617853745737642,
+                10010565982303406771,
+                14952006949637204874,
+                1257239375060454480,
+            ]),
+            Fq::from_montgomery_limbs([
+                18293686241925096528,
+                15505041210000990458,
+                1555059354156618810,
+                974603348702864957,
+            ]),
+            Fq::from_montgomery_limbs([
+                705880827513782255,
+                12233562047130269862,
+                500188547376920981,
+                37920155209363781,
+            ]),
+            Fq::from_montgomery_limbs([
+                3705329762357930833,
+                14473549517275658481,
+                17765012210336228796,
+                539592903617021087,
+            ]),
+            Fq::from_montgomery_limbs([
+                9160811102626089181,
+                11593365721109279031,
+                11903118409625680886,
+                1199670414850192412,
+            ]),
+            Fq::from_montgomery_limbs([
+                15690170364757488475,
+                12905811089678490326,
+                13453257705664812058,
+                52873918444003179,
+            ]),
+            Fq::from_montgomery_limbs([
+                5477990853955359052,
+                17526407841246853374,
+                6871488703184635204,
+                488028662478457408,
+            ]),
+            Fq::from_montgomery_limbs([
+                16251775867357216391,
+                1500045442471785820,
+                8591151950340399082,
+                809023648528381287,
+            ]),
+            Fq::from_montgomery_limbs([
+                2160834104405851038,
+                9771497685585416601,
+                360498165382250234,
+                51544607293392920,
+            ]),
+        ]),
+        mds: MdsMatrix::<9, 8, 81, 64>::new_from_known([
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+        ]),
+        alpha: Alpha::Exponent(17),
+        rounds: RoundNumbers { r_P: 31, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<8, 64>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+            ]),
+            v: Matrix::<1, 8, 8>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+            ]),
+            w: Matrix::<8, 1, 8>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<9, 81>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<9, 81>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11738604281243526561,
+                    4781427651196537855,
+                    11656817317081414594,
+                    74769487572114430,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1837325351615383592,
+                    14993493736544850342,
+                    8290779673102001293,
+                    832115724425473731,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8349916801170236627,
+                    149583357632573401,
+                    11715649778757746902,
+                    1262122030646274423,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8045625620315945304,
+                    1995830356710874034,
+                    1679071660637833653,
+                    219657431649533445,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10091116086211147286,
+                    2713360843774189783,
+                    5712452338043765592,
+                    1030703017451954970,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8071065446812442932,
+                    16553816416727326828,
+                    14558816056211727591,
+                    16962575699738561,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15195002240067566314,
+                    15833531177699155921,
+                    16833437579074607443,
+                    377096144896733015,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7503120522514816782,
+                    5894902138933683811,
+                    17651909846607548369,
+                    662671645650783132,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<9, 81>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    14125117945850289679,
+                    2908429517316864131,
+                    12138856245627964909,
+                    748501556703472665,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5751801461817652631,
+                    2616092595329959276,
+                    10565531883850650702,
+                    790988771388284840,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14213635549878037142,
+                    15604692803566702022,
+                    7284329626985663439,
+                    538085711601327357,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2779868033594301624,
+                    18088314170075937984,
+                    15754513787884389196,
+                    88122246473339,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1537514326089927407,
+                    15055819010865408892,
+                    7391236030220044314,
+                    103305166286019094,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11927415123609960207,
+                    4557230396483759592,
+                    14670896758247717592,
+                    1138537854746425199,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4372954050555479840,
+                    45705165491353738,
+                    2552801014151314694,
+                    248187953601218512,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1486627741335017569,
+                    3806270370745027672,
+                    5874221738747090394,
+                    1238914104859079750,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17442719586959537270,
+                    2197616579320104258,
+                    8616629239889107211,
+                    177820901299544967,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5751801461817652631,
+                    2616092595329959276,
+                    10565531883850650702,
+                    790988771388284840,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15430442947296305944,
+                    1233880619796783027,
+                    11143077074706597695,
+                    1213723193585859903,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5433123120308479172,
+                    10970492015255784270,
+                    1107507824845514784,
+                    1076612034435021414,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2987685045181684933,
+                    5790742226911818986,
+                    4823684027071761925,
+                    102181607643484011,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9609064712990842256,
+                    7693846964701142660,
+                    11466811793792885509,
+                    2643667394200195,
+                ]),
+                Fq::from_montgomery_limbs([
+                    783668496860869762,
+                    2718007362814632844,
+                    3225820018169964745,
+                    38052731170246729,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10369170793921156387,
+                    251000800519630825,
+                    1059566810161364138,
+                    313021808046242513,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3089625740932514273,
+                    639565575555042739,
+                    17922456388863227408,
+                    414738734819139583,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13087931762486193761,
+                    1136198234126441259,
+                    16227059872342932657,
+                    1049818521162913264,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14213635549878037142,
+                    15604692803566702022,
+                    7284329626985663439,
+                    538085711601327357,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5433123120308479172,
+                    10970492015255784270,
+                    1107507824845514784,
+                    1076612034435021414,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6825618785145867421,
+                    439562424917082247,
+                    17132469455368869443,
+                    531592709966865818,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10317143591822913114,
+                    10478472731655382132,
+                    752931432207473107,
+                    546148897153637954,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3022056074833422687,
+                    11586731492028190782,
+                    3722808005489745052,
+                    790759860119568158,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7560071459726025126,
+                    11159609690870115171,
+                    5349148217391169850,
+                    393365177870574328,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2232592636657472849,
+                    14573497261817657414,
+                    490524621705989419,
+                    588971032943469414,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10416384347364843323,
+                    7961218457081451727,
+                    70361756301501002,
+                    433301838731695482,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13624909552984831343,
+                    12328425362694875147,
+                    12177728289894349227,
+                    516129281320965442,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2779868033594301624,
+                    18088314170075937984,
+                    15754513787884389196,
+                    88122246473339,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2987685045181684933,
+                    5790742226911818986,
+                    4823684027071761925,
+                    102181607643484011,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10317143591822913114,
+                    10478472731655382132,
+                    752931432207473107,
+                    546148897153637954,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8513556096196526025,
+                    4443094710782549374,
+                    17890205040657765995,
+                    1122326987243309309,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17504429866292444083,
+                    968710277566059589,
+                    14791167766636902009,
+                    667428676312814774,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8102309369133568244,
+                    15833085095985810000,
+                    175935838577017509,
+                    927031461498904940,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9197667640166159001,
+                    5084715628602537724,
+                    17535475195019164586,
+                    572711107077389073,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13665066116710273371,
+                    15233526783913702406,
+                    15379228116525522630,
+                    534861209951932408,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2751640606240390088,
+                    11585010570330910681,
+                    1196680916075037058,
+                    418375816562766759,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1537514326089927407,
+                    15055819010865408892,
+                    7391236030220044314,
+                    103305166286019094,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9609064712990842256,
+                    7693846964701142660,
+                    11466811793792885509,
+                    2643667394200195,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3022056074833422687,
+                    11586731492028190782,
+                    3722808005489745052,
+                    790759860119568158,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17504429866292444083,
+                    968710277566059589,
+                    14791167766636902009,
+                    667428676312814774,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9688294854325637220,
+                    2516870115101509429,
+                    8562225304789531672,
+                    920772181118147731,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18103384410572790239,
+                    17436934438195260992,
+                    8084720223174587017,
+                    457703849714387663,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13825692371612041483,
+                    8407188395079459826,
+                    9738483349340664627,
+                    609788924475800273,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2671327478789506032,
+                    5774807727893256163,
+                    13240425336522241868,
+                    380911959300832826,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12144266474528564086,
+                    12149487358641369511,
+                    14484097210543333937,
+                    922090876411800449,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11927415123609960207,
+                    4557230396483759592,
+                    14670896758247717592,
+                    1138537854746425199,
+                ]),
+                Fq::from_montgomery_limbs([
+                    783668496860869762,
+                    2718007362814632844,
+                    3225820018169964745,
+                    38052731170246729,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7560071459726025126,
+                    11159609690870115171,
+                    5349148217391169850,
+                    393365177870574328,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8102309369133568244,
+                    15833085095985810000,
+                    175935838577017509,
+                    927031461498904940,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18103384410572790239,
+                    17436934438195260992,
+                    8084720223174587017,
+                    457703849714387663,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6855844750121059588,
+                    5384860438622471700,
+                    12420969818631614650,
+                    1116611823326893227,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1302596353216625892,
+                    10857341191843260724,
+                    14364257923983957089,
+                    714152996179182325,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330945647747003245,
+                    529545606757826538,
+                    2911874658480930134,
+                    1070962885839772206,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8938859138235895106,
+                    10899724904641404508,
+                    8550817147402767219,
+                    1333362640082552566,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4372954050555479840,
+                    45705165491353738,
+                    2552801014151314694,
+                    248187953601218512,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10369170793921156387,
+                    251000800519630825,
+                    1059566810161364138,
+                    313021808046242513,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2232592636657472849,
+                    14573497261817657414,
+                    490524621705989419,
+                    588971032943469414,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9197667640166159001,
+                    5084715628602537724,
+                    17535475195019164586,
+                    572711107077389073,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13825692371612041483,
+                    8407188395079459826,
+                    9738483349340664627,
+                    609788924475800273,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1302596353216625892,
+                    10857341191843260724,
+                    14364257923983957089,
+                    714152996179182325,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5343555488472222055,
+                    7792919219932346072,
+                    13078967794845895925,
+                    768286116933191668,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10066650881456123078,
+                    6833326987087988009,
+                    6664729673863563195,
+                    887730031418401270,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7189287518995356201,
+                    15159954278839014877,
+                    5871434703628491668,
+                    874187026632527838,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1486627741335017569,
+                    3806270370745027672,
+                    5874221738747090394,
+                    1238914104859079750,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3089625740932514273,
+                    639565575555042739,
+                    17922456388863227408,
+                    414738734819139583,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10416384347364843323,
+                    7961218457081451727,
+                    70361756301501002,
+                    433301838731695482,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13665066116710273371,
+                    15233526783913702406,
+                    15379228116525522630,
+                    534861209951932408,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2671327478789506032,
+                    5774807727893256163,
+                    13240425336522241868,
+                    380911959300832826,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330945647747003245,
+                    529545606757826538,
+                    2911874658480930134,
+                    1070962885839772206,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10066650881456123078,
+                    6833326987087988009,
+                    6664729673863563195,
+                    887730031418401270,
+                ]),
+                Fq::from_montgomery_limbs([
+                    902754672498486076,
+                    12517779635415231452,
+                    13155303329242204219,
+                    1289665243616955086,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7652041772470796788,
+                    8981893450818608651,
+                    9209711461462255937,
+                    1077612943174814160,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17442719586959537270,
+                    2197616579320104258,
+                    8616629239889107211,
+                    177820901299544967,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13087931762486193761,
+                    1136198234126441259,
+                    16227059872342932657,
+                    1049818521162913264,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13624909552984831343,
+                    12328425362694875147,
+                    12177728289894349227,
+                    516129281320965442,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2751640606240390088,
+                    11585010570330910681,
+                    1196680916075037058,
+                    418375816562766759,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12144266474528564086,
+                    12149487358641369511,
+                    14484097210543333937,
+                    922090876411800449,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8938859138235895106,
+                    10899724904641404508,
+                    8550817147402767219,
+                    1333362640082552566,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7189287518995356201,
+                    15159954278839014877,
+                    5871434703628491668,
+                    874187026632527838,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7652041772470796788,
+                    8981893450818608651,
+                    9209711461462255937,
+                    1077612943174814160,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11791505032690829219,
+                    13538019108583005790,
+                    12722760625010986052,
+                    806907851170852393,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<8, 64>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    3472697372407899841,
+                    16544229236306855772,
+                    14815162528681725643,
+                    1034550313658870381,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6531628527658761518,
+                    1375936492096069238,
+                    5162597520125437283,
+                    753364057365094970,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17772744427723628729,
+                    13176935205831466937,
+                    15902377141084755751,
+                    1179674864543238823,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12722125532998872628,
+                    1657174689630458033,
+                    5334871548453715488,
+                    414013765362336436,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1501077398539652430,
+                    16634277348976189180,
+                    12808219470309836002,
+                    289633391260688825,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8734474714881129402,
+                    9935717511068940205,
+                    1255631118005415574,
+                    389199376114538252,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3997495989798730364,
+                    970252553620940237,
+                    11923618315694686379,
+                    766457025843633809,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11646170206321510453,
+                    2517823832088710483,
+                    5523488496027719809,
+                    381766610224744640,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6531628527658761518,
+                    1375936492096069238,
+                    5162597520125437283,
+                    753364057365094970,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15965101045257322197,
+                    3515550468563531120,
+                    4916107498869020370,
+                    651300429496639231,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10596776180967316554,
+                    225179252833885887,
+                    10729564497691050657,
+                    147123165387726579,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16611306768924215216,
+                    2560303514338865012,
+                    16364276214366165560,
+                    872103157353257372,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7632237298392162332,
+                    7698917717061907845,
+                    11218122385686841551,
+                    829217181052062960,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4733564937170936652,
+                    256322830778027069,
+                    6758345174156470108,
+                    434188546596084582,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12083431336599254187,
+                    12384916553025618716,
+                    16826365495566633891,
+                    691820100121742323,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4873853528674259085,
+                    16369962720013626670,
+                    13832963141828351083,
+                    152070984285871914,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17772744427723628729,
+                    13176935205831466937,
+                    15902377141084755751,
+                    1179674864543238823,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10596776180967316554,
+                    225179252833885887,
+                    10729564497691050657,
+                    147123165387726579,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6614111795752893437,
+                    5410270938975028696,
+                    12237210548144262759,
+                    658705598878782693,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3435168843820198907,
+                    4619635048481112464,
+                    1894118712821909790,
+                    1293137932659299658,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2196509717324404246,
+                    7080471211866985269,
+                    16002781611267705185,
+                    371045031352725097,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6526340161376652969,
+                    17756651974418833177,
+                    4295049858496922717,
+                    191799147776556247,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14498991427989326310,
+                    14939398330639456688,
+                    4891044929710870429,
+                    121560462214500735,
+                ]),
+                Fq::from_montgomery_limbs([
+                    451752145287995747,
+                    10098856000778127717,
+                    13306610858333922048,
+                    286623102658238453,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12722125532998872628,
+                    1657174689630458033,
+                    5334871548453715488,
+                    414013765362336436,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16611306768924215216,
+                    2560303514338865012,
+                    16364276214366165560,
+                    872103157353257372,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3435168843820198907,
+                    4619635048481112464,
+                    1894118712821909790,
+                    1293137932659299658,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13524365147305696369,
+                    13027979144535091217,
+                    17796894917246173259,
+                    795634957923892284,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4590521000228428122,
+                    6622808712010123418,
+                    15929233895224303148,
+                    1114694782271344629,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12575166581440508000,
+                    7505865357729620722,
+                    14259447638371694220,
+                    240623913250950605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13540977501705131065,
+                    6950307482373171865,
+                    17217679725315748170,
+                    154759715343433141,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14386708573955995526,
+                    5656837544088385198,
+                    3866836728999660333,
+                    23945074085024856,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1501077398539652430,
+                    16634277348976189180,
+                    12808219470309836002,
+                    289633391260688825,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7632237298392162332,
+                    7698917717061907845,
+                    11218122385686841551,
+                    829217181052062960,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2196509717324404246,
+                    7080471211866985269,
+                    16002781611267705185,
+                    371045031352725097,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4590521000228428122,
+                    6622808712010123418,
+                    15929233895224303148,
+                    1114694782271344629,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15070154937959202395,
+                    3248544569751880714,
+                    661789926060478300,
+                    636609603432249058,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16308509436127011873,
+                    14861091406182993671,
+                    18214688034464749728,
+                    678570586891957502,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10922621116853927433,
+                    7374821673119171068,
+                    9460593723609905173,
+                    125297939328458988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16038927038956825784,
+                    7199223172922027514,
+                    1168024222741020477,
+                    1309362759560636114,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8734474714881129402,
+                    9935717511068940205,
+                    1255631118005415574,
+                    389199376114538252,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4733564937170936652,
+                    256322830778027069,
+                    6758345174156470108,
+                    434188546596084582,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6526340161376652969,
+                    17756651974418833177,
+                    4295049858496922717,
+                    191799147776556247,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12575166581440508000,
+                    7505865357729620722,
+                    14259447638371694220,
+                    240623913250950605,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16308509436127011873,
+                    14861091406182993671,
+                    18214688034464749728,
+                    678570586891957502,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15902564838111883158,
+                    5307049581965871302,
+                    6355686099568311868,
+                    661037926984580026,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7865680991081183190,
+                    18305899405066229991,
+                    1289232386676722624,
+                    357144811101446232,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16503297387410283974,
+                    13077873853501323474,
+                    2923095378138040083,
+                    892586935032663785,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3997495989798730364,
+                    970252553620940237,
+                    11923618315694686379,
+                    766457025843633809,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12083431336599254187,
+                    12384916553025618716,
+                    16826365495566633891,
+                    691820100121742323,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14498991427989326310,
+                    14939398330639456688,
+                    4891044929710870429,
+                    121560462214500735,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13540977501705131065,
+                    6950307482373171865,
+                    17217679725315748170,
+                    154759715343433141,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10922621116853927433,
+                    7374821673119171068,
+                    9460593723609905173,
+                    125297939328458988,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7865680991081183190,
+                    18305899405066229991,
+                    1289232386676722624,
+                    357144811101446232,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12076094773719611591,
+                    2196667680523264869,
+                    1931685846099458836,
+                    940510179172147218,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3245750827451990119,
+                    11452650538623302854,
+                    1220913256332285596,
+                    300995627752942527,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11646170206321510453,
+                    2517823832088710483,
+                    5523488496027719809,
+                    381766610224744640,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4873853528674259085,
+                    16369962720013626670,
+                    13832963141828351083,
+                    152070984285871914,
+                ]),
+                Fq::from_montgomery_limbs([
+                    451752145287995747,
+                    10098856000778127717,
+                    13306610858333922048,
+                    286623102658238453,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14386708573955995526,
+                    5656837544088385198,
+                    3866836728999660333,
+                    23945074085024856,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16038927038956825784,
+                    7199223172922027514,
+                    1168024222741020477,
+                    1309362759560636114,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16503297387410283974,
+                    13077873853501323474,
+                    2923095378138040083,
+                    892586935032663785,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3245750827451990119,
+                    11452650538623302854,
+                    1220913256332285596,
+                    300995627752942527,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8191725183313124874,
+                    1615204035270658232,
+                    15017220248745748432,
+                    821075410484091633,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            M_i: Matrix::<9, 9, 81>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    16520688317110333070,
+                    17372191804399504939,
+                    5076047561591735877,
+                    913725867146813780,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17091985271510940463,
+                    2858853557846773248,
+                    5038253393116139649,
+                    950295998175746345,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5496134450967461870,
+                    8210946739205979034,
+                    1979552669856348307,
+                    190711011771773022,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6571625770425801011,
+                    3797028549695363529,
+                    7043834100299973129,
+                    623449099578681734,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11384677918809971734,
+                    8061048799667385603,
+                    10309131485734158534,
+                    591319887305555011,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5486224520119160023,
+                    3739776503118284772,
+                    5467833611063236583,
+                    1151482869081812727,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12775146431126054240,
+                    13630159955113927852,
+                    7964591764084703963,
+                    1139149117366074226,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10621361483754255083,
+                    17144167284437859362,
+                    13552313447034700283,
+                    432281080250334678,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17091985271510940463,
+                    2858853557846773248,
+                    5038253393116139649,
+                    950295998175746345,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17846731600635459523,
+                    5430249111190214914,
+                    8454385842687813670,
+                    707670488614248482,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3588589125896020630,
+                    3344731471899465399,
+                    15455184074539338961,
+                    1114848989900097914,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14461704347424375596,
+                    2385935560119082419,
+                    13394905607259914432,
+                    1083338709778671724,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18159457423626205352,
+                    17110218008205759140,
+                    5824282582304774735,
+                    34792720595606766,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13238671159409965736,
+                    9172139617359448991,
+                    9449457013764728099,
+                    710085185968023768,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12888755076589333980,
+                    12312975914596040409,
+                    1249144286695004031,
+                    713691314127017609,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4341828357775480073,
+                    13338819603724350302,
+                    242163094656894566,
+                    643054336384278320,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5496134450967461870,
+                    8210946739205979034,
+                    1979552669856348307,
+                    190711011771773022,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3588589125896020630,
+                    3344731471899465399,
+                    15455184074539338961,
+                    1114848989900097914,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12347671140823209553,
+                    10602653822395055378,
+                    12417996147726824977,
+                    212478784387898098,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8507018874071542805,
+                    9691816873740372940,
+                    10231898518953816709,
+                    738480892642298189,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8519354956242454806,
+                    9112854684495927005,
+                    15561060151975350179,
+                    1219855309441345097,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7217387545262804163,
+                    16783381768724651695,
+                    2197655342614109020,
+                    392375465602781821,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8112448215520731256,
+                    16980200692478534592,
+                    1065956301995686850,
+                    505480388481351193,
+                ]),
+                Fq::from_montgomery_limbs([
+                    134417068419866627,
+                    8058692538701158724,
+                    17348889708565078754,
+                    1133259760118636483,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    6571625770425801011,
+                    3797028549695363529,
+                    7043834100299973129,
+                    623449099578681734,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14461704347424375596,
+                    2385935560119082419,
+                    13394905607259914432,
+                    1083338709778671724,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8507018874071542805,
+                    9691816873740372940,
+                    10231898518953816709,
+                    738480892642298189,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2882450356900286005,
+                    14026994957908752264,
+                    17564780377254093801,
+                    58573386813453630,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3061788501783851003,
+                    18096676739304700295,
+                    14610434781307981583,
+                    620917692149375175,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16933504250075373243,
+                    5311927866112214610,
+                    3845730872542005994,
+                    382485390055500363,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15367618137688131543,
+                    17649045880149053668,
+                    9570030575523801696,
+                    546879988772386824,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18304040949268982436,
+                    9940713439396795854,
+                    12736189969399055080,
+                    592081839514432039,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    11384677918809971734,
+                    8061048799667385603,
+                    10309131485734158534,
+                    591319887305555011,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18159457423626205352,
+                    17110218008205759140,
+                    5824282582304774735,
+                    34792720595606766,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8519354956242454806,
+                    9112854684495927005,
+                    15561060151975350179,
+                    1219855309441345097,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3061788501783851003,
+                    18096676739304700295,
+                    14610434781307981583,
+                    620917692149375175,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5779905847053612079,
+                    8338377602131596203,
+                    762973888251826860,
+                    944153289702238548,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11663357764371942331,
+                    2996680796186966847,
+                    2661662806870748389,
+                    1103331277735481193,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7187675462469054510,
+                    2812791669580745930,
+                    11755867072768330613,
+                    62627108312583966,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14981955637496014455,
+                    2356290500371205526,
+                    6178541624682749387,
+                    117839694885288425,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5486224520119160023,
+                    3739776503118284772,
+                    5467833611063236583,
+                    1151482869081812727,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13238671159409965736,
+                    9172139617359448991,
+                    9449457013764728099,
+                    710085185968023768,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7217387545262804163,
+                    16783381768724651695,
+                    2197655342614109020,
+                    392375465602781821,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16933504250075373243,
+                    5311927866112214610,
+                    3845730872542005994,
+                    382485390055500363,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11663357764371942331,
+                    2996680796186966847,
+                    2661662806870748389,
+                    1103331277735481193,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2935340762781635743,
+                    13999941081745153956,
+                    226506754503333061,
+                    115160957972927019,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13388725222648726197,
+                    16142211132531013364,
+                    10675357162563725408,
+                    1025254426334791449,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2447431291426965875,
+                    294723282805095333,
+                    2105382363485842679,
+                    36760375927179973,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12775146431126054240,
+                    13630159955113927852,
+                    7964591764084703963,
+                    1139149117366074226,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12888755076589333980,
+                    12312975914596040409,
+                    1249144286695004031,
+                    713691314127017609,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8112448215520731256,
+                    16980200692478534592,
+                    1065956301995686850,
+                    505480388481351193,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15367618137688131543,
+                    17649045880149053668,
+                    9570030575523801696,
+                    546879988772386824,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7187675462469054510,
+                    2812791669580745930,
+                    11755867072768330613,
+                    62627108312583966,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13388725222648726197,
+                    16142211132531013364,
+                    10675357162563725408,
+                    1025254426334791449,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17017965759616617402,
+                    5778107719604425008,
+                    4017202869752005309,
+                    256606332479451223,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16364134823686078945,
+                    4738924908583930093,
+                    1131674484914722087,
+                    1120920274489842244,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10621361483754255083,
+                    17144167284437859362,
+                    13552313447034700283,
+                    432281080250334678,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4341828357775480073,
+                    13338819603724350302,
+                    242163094656894566,
+                    643054336384278320,
+                ]),
+                Fq::from_montgomery_limbs([
+                    134417068419866627,
+                    8058692538701158724,
+                    17348889708565078754,
+                    1133259760118636483,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18304040949268982436,
+                    9940713439396795854,
+                    12736189969399055080,
+                    592081839514432039,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14981955637496014455,
+                    2356290500371205526,
+                    6178541624682749387,
+                    117839694885288425,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2447431291426965875,
+                    294723282805095333,
+                    2105382363485842679,
+                    36760375927179973,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16364134823686078945,
+                    4738924908583930093,
+                    1131674484914722087,
+                    1120920274489842244,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8270164023198137550,
+                    11049516062307829632,
+                    12698053047389864733,
+                    714945442856155114,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10632745392742701465,
+                        7191381972905833266,
+                        10576427669223334706,
+                        1037506184958051122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7989155441247042094,
+                        18276457113184108543,
+                        17999817914616464103,
+                        943187440870955565,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18204910156218979669,
+                        16293041589642941780,
+                        9975069610392864084,
+                        1088801882579738168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15441359965645378481,
+                        7022857083512140090,
+                        11162758362666494739,
+                        1108530997049617801,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7802104352665277001,
+                        1712233803072817444,
+                        12180777293186749586,
+                        1125441666595228915,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7330330845985706257,
+                        6947957132670498679,
+                        9373711551562393463,
+                        1140097580201425214,
+                    ]),
+                    Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                    Fq::from_montgomery_limbs([
+                        829043150522309210,
+                        7485532467658058089,
+                        17072453966431109601,
+                        610297755857677130,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11035801921893654710,
+                        16929800804253366863,
+                        12398055725611446180,
+                        760065053467616809,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8923440442149696128,
+                        4984170922181405776,
+                        6244995778228741393,
+                        1074227914012810302,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7049765143685462488,
+                        15701751610672416457,
+                        4564235891372808009,
+                        681954810927415504,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17010226681213361474,
+                        1947629347689653556,
+                        2065818344502599545,
+                        488918548801413476,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5146314634524264562,
+                        12985625379044561611,
+                        7822851564890648627,
+                        521904428273081007,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9419126683398604095,
+                        10236887702786385024,
+                        4142255571846968370,
+                        1046923845301111796,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15633807592373508564,
+                        16238753952984345465,
+                        7259607605738892086,
+                        311654619673577396,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10070077179493264337,
+                        399894709660858127,
+                        17446873557159998129,
+                        1238805961930408685,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17763823340741864007,
+                        13688048320723664515,
+                        1554170575306948270,
+                        1089932081810567550,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14413554205720714215,
+                        12666988096053381072,
+                        13733719735313981246,
+                        425646075306443834,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14006225490214584859,
+                        3916200035219435577,
+                        13361287101171816020,
+                        950919313681928916,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17602045733015983252,
+                        16500809082450842332,
+                        12901928412415074225,
+                        851778412489791285,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16318253517396906807,
+                        13640063307874270866,
+                        1900655319412111816,
+                        581748851194644398,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1363999602167029513,
+                        16529682682864036658,
+                        10195002930146982000,
+                        294505563918732440,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5435889975523469280,
+                        14106400869326277643,
+                        17610698801996411281,
+                        762853467263009508,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8861367526330504683,
+                        12551289492670191802,
+                        16663350770995106182,
+                        1331413450403964842,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10776354714680864470,
+                        7917153991609404394,
+                        6852637758987891210,
+                        1310204544952620704,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1678866576825289132,
+                        3349062370218611571,
+                        1482187123500088377,
+                        470976570440727462,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3232575116481607966,
+                        5484422768150576259,
+                        7465125228700013123,
+                        938628169664006580,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11315128360485690118,
+                        16174988841975521641,
+                        8649694431500796935,
+                        401520045262440295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2950253646523956169,
+                        12167154546231134362,
+                        14143629255958026247,
+                        246373778697580997,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10281269675653676020,
+                        4088177422809244669,
+                        531433062017184902,
+                        462772077967162764,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10182952767187988496,
+                        4123619145317785148,
+                        15864610667228984614,
+                        474788384341718915,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6746816650763645352,
+                        6128894525782906756,
+                        16503811677044184192,
+                        569741536310537107,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11343116761854293656,
+                        10147105871675532887,
+                        17626832195351140365,
+                        991337071280471915,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7909284385949960899,
+                        16910728893934864524,
+                        17210454034003596202,
+                        114320065044863552,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13461629547630489809,
+                        11503679994500858007,
+                        14211309248418949867,
+                        254052884896164504,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2443492458135215396,
+                        13447128028980291363,
+                        3281274202308271632,
+                        412226613793824141,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1167000299874248842,
+                        16731370738820867944,
+                        17602557110218511484,
+                        1051510847183979235,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3840131579015022233,
+                        2938796536078079208,
+                        12709091670668596518,
+                        482260825380694457,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15246525631446466466,
+                        15233663401113750688,
+                        2798719750734932706,
+                        326343170128724784,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17667364711018651771,
+                        5985420612191972846,
+                        13623154577172603979,
+                        854043483296525096,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17822942355343264125,
+                        4714212623023292576,
+                        2674464566006308390,
+                        1042500080719148007,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2052993252412064300,
+                        11174826235204333422,
+                        14611975064942326129,
+                        1232619301557806867,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6994438014328280152,
+                        11792399011976490051,
+                        13420297916245734081,
+                        98142030938963071,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6122828558180120167,
+                        5208020158122968757,
+                        16516365450346286825,
+                        1325683338458875204,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15288119049180577976,
+                        5702239698560490583,
+                        10103336794612159852,
+                        116178625545902015,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4051335082853549427,
+                        8164503378219731587,
+                        17148072402858067003,
+                        790652194082120290,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1537457433255413678,
+                        13879143532592036211,
+                        16956611314861527227,
+                        659325194003475750,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11233793882448653331,
+                        3043413982435126054,
+                        10270684075822347775,
+                        893861369718913487,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9808520124715376346,
+                        15609293207874110984,
+                        10732654482692707792,
+                        1146287545162292771,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2749470077610403553,
+                        13514379483617638264,
+                        18399802224119269852,
+                        174073652425641597,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11520445740521987461,
+                        8082066432682132202,
+                        7071889057729561766,
+                        389611806928896119,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18422421825207341641,
+                        15136178167777776512,
+                        2723187409152355732,
+                        170484164557099816,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11643596019544393695,
+                        4900974797688018022,
+                        16963454473283391330,
+                        716468985794966169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1653191216998656199,
+                        8956019595679449906,
+                        12447630730365106422,
+                        644720607665142232,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5421681843198716748,
+                        2602837118310713826,
+                        4105848318498180188,
+                        451416754997989278,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3384250244262220978,
+                        2854747086452978491,
+                        6705977074140386594,
+                        701940581279680385,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4459156727889938686,
+                        713250264836429834,
+                        18222402788792781205,
+                        1305744629507302937,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14964825672437682109,
+                        15013870111589444016,
+                        18359412862134704407,
+                        851823857034432901,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4002950594318139437,
+                        14256284199292774345,
+                        9749408336921857546,
+                        309463895225164742,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6448461664035470683,
+                        7819929318851232879,
+                        4585529125814325668,
+                        882988453166337299,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4468858352550486666,
+                        7870950686552012779,
+                        13548893710698788388,
+                        1166626308593289839,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13834764245836062061,
+                        15495708956785427129,
+                        14823076735611942374,
+                        179301932503630533,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14392690646873931902,
+                        6152200998544040125,
+                        10011778213507609457,
+                        1290655800080905118,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1625218277131869735,
+                        16175235177441105662,
+                        11685814963630992502,
+                        1183057778642107585,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13017228373195503956,
+                        12230747419338302078,
+                        17263299208097785907,
+                        1284866695267985817,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16168998401909424115,
+                        10895165757065097549,
+                        11350247493744713001,
+                        1135353221073831291,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10273038616328622662,
+                        14479018784598217238,
+                        9766774077678228017,
+                        1207617264652766604,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15836176712065858741,
+                        10338338350978906628,
+                        16178720787430703015,
+                        944594681818815191,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3271995535506338931,
+                        8192993737423137063,
+                        15791559891865397992,
+                        22705142524573108,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3993474448985532330,
+                        3639064265711311730,
+                        6718068521140122966,
+                        383248110981770394,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9492095517076219407,
+                        7592850834432888477,
+                        11768259941284085296,
+                        574301115907621577,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4262418966381705371,
+                        13252548117988672442,
+                        12272759904349165318,
+                        600154338307068054,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16971304409706663287,
+                        4059346938374753540,
+                        13232872953632647420,
+                        1008785826761250212,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1531392756020271794,
+                        3027232543825542505,
+                        13052320614677310980,
+                        126548105259679798,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7953634271727431947,
+                        6747959150321282547,
+                        18100385407679395895,
+                        1015945640388165660,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8995624744483589795,
+                        9755282315329844456,
+                        2927338000840904787,
+                        845683038436995460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3426641227231174830,
+                        12231924185364347092,
+                        4292661815764843726,
+                        1244312094804111140,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17345354505324614063,
+                        594490449607399069,
+                        2449704478423316515,
+                        1095477001993230493,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10474537418457538521,
+                        6792509687833588699,
+                        13393944709149392227,
+                        534739277017791457,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17518678326900360887,
+                        7525807947638893121,
+                        13556955595671878235,
+                        30209250616515409,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13742506926168769727,
+                        11038595886952023339,
+                        3701001248629565931,
+                        1015606567692618639,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10653440240489263917,
+                        11537286138542804804,
+                        17217136615645637940,
+                        770211789618726393,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13666453990806631623,
+                        1318187744085149157,
+                        7013132640059164205,
+                        1072646584987256145,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3023274654275766761,
+                        13436445425944833199,
+                        7833807081937734543,
+                        19486874451653923,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13845526801132968318,
+                        1474919087448706450,
+                        3684373339440069223,
+                        488094926036105324,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        320270184093253734,
+                        17696520436387361959,
+                        9996305789425179585,
+                        1178815243771361521,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12146943803101338712,
+                        7251626626993825947,
+                        9315606456451424308,
+                        795366810799664029,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15419687172849590427,
+                        2791743253888711589,
+                        2207350072976523205,
+                        165909916844405700,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9896034312349995086,
+                        16093385838026756462,
+                        16925853434636373937,
+                        498702893342086205,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14572653042420421836,
+                        2923463625342581458,
+                        14432890069592751901,
+                        58005770759623186,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11297623620404139610,
+                        6552827488669833034,
+                        11621022622135802856,
+                        508198290706074082,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3383738621063347297,
+                        8152794485589611445,
+                        7468063384920996950,
+                        227638509938744017,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3356677761128814245,
+                        5721970089340033418,
+                        5428201786819102042,
+                        782459018659898458,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3820304429592347394,
+                        14578414062240265498,
+                        16154466241125591779,
+                        574347929954533302,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11789576396521912690,
+                        5226761264039181239,
+                        17687206032762281033,
+                        466122495629301994,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3897052992999769711,
+                        17274188602369817926,
+                        1687784483585476378,
+                        153883133391487320,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17161107652731673541,
+                        3073683557416374032,
+                        16710585127481450849,
+                        122337377542111825,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1365808754591249110,
+                        4613118654029417414,
+                        6508847094078136957,
+                        795933591641925897,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4875743983777844649,
+                        14873388394983371616,
+                        14075127307603364534,
+                        293568470846001989,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1753271848268858648,
+                        5990667751000843931,
+                        4001423150159029842,
+                        1173495176097464608,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4812189211317633024,
+                        2012021762051424968,
+                        5273944394079132039,
+                        1073768096056703548,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        424666158425826800,
+                        16167046445049349389,
+                        13631394296041780534,
+                        585200201854998460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6539830511027994415,
+                        15269745683847819583,
+                        4206238147087986866,
+                        1302972532415703295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        302300405819660575,
+                        16462648810560420421,
+                        7998203770974936711,
+                        295327496343326905,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7148000471342452770,
+                        8852022346987592295,
+                        632884369346075858,
+                        36442350334176174,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15146686853925204212,
+                        14820562641580675287,
+                        2176239618510666629,
+                        1307861686187142374,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7005868492320494512,
+                        14955845219500790730,
+                        2081125396763027525,
+                        6138993913176061,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13026157833696954714,
+                        9843526361726789435,
+                        4414463675013375111,
+                        365771050973787721,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14546396485927232562,
+                        12898976553271974882,
+                        1400224367389591263,
+                        430320367573860103,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14568972314151382805,
+                        17930943124376760623,
+                        4783600381020679293,
+                        376571711628799765,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18291943970625960672,
+                        8017218097690185119,
+                        5549059485639318398,
+                        46356915911800561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16682884091904623366,
+                        12511302878781725528,
+                        17222311098805586641,
+                        830868368516186929,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11106461088529849965,
+                        2237808323244371314,
+                        16660257020010009738,
+                        298604776770069088,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3607332035171600459,
+                        10906863751788942632,
+                        8836615041030793067,
+                        578399912314561895,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        970097098111090324,
+                        10087741441163691861,
+                        11339927909887994371,
+                        152053267378807312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15624222113349956548,
+                        7597260374850454347,
+                        4223643762510038976,
+                        93826706599156644,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14839256555739902510,
+                        7353172544221601319,
+                        11675649191159524738,
+                        838086776442268961,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4120005521322451317,
+                        17223961038985718114,
+                        4237108659489051045,
+                        107194402753730450,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14317298019350450198,
+                        2137252039148900576,
+                        2112254394391922189,
+                        99952319024084078,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15809219895708808685,
+                        306839837742108505,
+                        136120805396880399,
+                        340524254190587818,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15327898951615295014,
+                        18430174875733870528,
+                        18072956489860584491,
+                        144918378345321468,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1464159758746486807,
+                        10791030624632626712,
+                        16712025860093182057,
+                        142053408495119475,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3929619741413361522,
+                        8134535398521714843,
+                        9991501716521328912,
+                        771553948377335966,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        639723721008010681,
+                        10593234041919714315,
+                        13611001926137363455,
+                        794605364252420530,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16503986551076532605,
+                        14875523310214542175,
+                        8478102086975848351,
+                        1006011079531986280,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        654299728559432125,
+                        7760356713809259363,
+                        18240237817942527118,
+                        172508436620123441,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13921680847761141107,
+                        7529771150038331651,
+                        12737204683878040950,
+                        426233678952889959,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4590769775235270188,
+                        9041925656640853215,
+                        4090609729659454568,
+                        1203448208757961340,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7699040678809630407,
+                        9560653731074143898,
+                        17075213227541631168,
+                        1181043829589503477,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6806146858690415347,
+                        16111947698400416291,
+                        4339138266349395725,
+                        989861013649255815,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18268648311377574940,
+                        17601160150116113053,
+                        6406878201816494568,
+                        1169012048043015952,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9948154649055240674,
+                        7478952460291659671,
+                        15706181873580472951,
+                        755927866513532652,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11355453345997809179,
+                        13686387440035131135,
+                        3435908465418804103,
+                        357962587767138921,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13609258742106208100,
+                        13672414215990825116,
+                        4966452533752255982,
+                        892614566706299053,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15530860957057121101,
+                        12814961814531305102,
+                        2228447964213127421,
+                        690297029247896989,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        120546543761530994,
+                        16175953418724738158,
+                        16970989008229560337,
+                        600532328684563611,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        888605635694073349,
+                        14295045063913168738,
+                        4600230902464835778,
+                        1212224926152970889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12466590199956595049,
+                        1532140711396018654,
+                        17787351045279615626,
+                        342533675962447219,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8846790718798251567,
+                        12685866452276306189,
+                        2191937006666256358,
+                        144769688422243017,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7692559226079848477,
+                        14135565457169764170,
+                        6642359125514717704,
+                        599601232173677933,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16371385164197212776,
+                        14518555606241521266,
+                        877014966105180310,
+                        32779526187306668,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7696146978964029133,
+                        969483905352070715,
+                        18426714731326517379,
+                        96262231680098841,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2241814338118657607,
+                        5290297867497495652,
+                        3147696872790273062,
+                        524556298115452045,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3822613261415092446,
+                        7511054776446458343,
+                        9339572900344590897,
+                        807690652491488002,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14451116463110348294,
+                        9013674270085783707,
+                        6861756681104034950,
+                        1200416854944263927,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4695856753062310654,
+                        11022585187505977417,
+                        1644489432427952909,
+                        323956450455984341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10419154589480862147,
+                        13971954567193824928,
+                        12477041756211883440,
+                        1049512710080278405,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14607742387832875684,
+                        16578813348531921537,
+                        8193805288650922489,
+                        1251124664274144708,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6746174244974480736,
+                        17797429517764716002,
+                        9057811904727741581,
+                        348968945042868382,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4380057909400064444,
+                        17925739460060043775,
+                        16841007964556977235,
+                        310879979345595940,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        234282441157200312,
+                        3370294141180194153,
+                        7520033657133301083,
+                        1046634857692053459,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10025268860317953198,
+                        3355842582951904886,
+                        1492728352155769079,
+                        1224965779564025381,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9257807357521133358,
+                        4650918755645577860,
+                        11421525750115159639,
+                        514925713793806371,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18294494755571865981,
+                        9713863505969567132,
+                        8689646254994423920,
+                        715090770581554992,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13829753376716426642,
+                        17039420179150676294,
+                        15262467024208136843,
+                        351501450866333924,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12219800740627122326,
+                        5367817451937225165,
+                        5059164546612641546,
+                        221292483670595282,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16418422054617956294,
+                        4210478318929624304,
+                        11744746808842457414,
+                        1023946875999463492,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17666348326851385706,
+                        12115208709149368586,
+                        9001388004627693263,
+                        799098945780480353,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1824455820136060352,
+                        15434539960184959805,
+                        4218711465785568747,
+                        763951614140473357,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6224497416420480586,
+                        6699518951950147185,
+                        12994163854057938124,
+                        1132574430964493472,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1793824813198835549,
+                        4413288874598419342,
+                        14770756114034639747,
+                        557823747265369722,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16255700836205654100,
+                        3165744038937475953,
+                        10230875417190256221,
+                        386504777548220290,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7250017665734738959,
+                        13258019582187582849,
+                        16761366963347311523,
+                        567809250431323059,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8829432100402919805,
+                        398635482179173989,
+                        6949507124139582956,
+                        854216079737008802,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13823664132573650820,
+                        13325777818311633370,
+                        18247609150799600765,
+                        1291748364178907237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13793142330327216891,
+                        993976648958383219,
+                        8499308732918569561,
+                        585469374800935691,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9027154701106718805,
+                        9735857908214611572,
+                        2551885302607650650,
+                        440629552912202384,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15458047699683801468,
+                        11468645091771547018,
+                        5061919980577102012,
+                        693212277840109754,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6971485728596174063,
+                        9206151705031473988,
+                        14376169366315062977,
+                        630323765667845847,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8488341370106756685,
+                        12886558390057048222,
+                        8948995552280899726,
+                        1163253702533448897,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17845628922564183580,
+                        7165913714155010953,
+                        12685036441625906445,
+                        106835572932425771,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2838376424597874866,
+                        17603743109337104856,
+                        9131584519609647254,
+                        514204416549839995,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11359428055605289064,
+                        12234013081302661394,
+                        1527977796785374323,
+                        709351601989345586,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17471810878275410410,
+                        11889658250360153000,
+                        14884319060121124032,
+                        846330039440189275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18222822953210135031,
+                        15905619486890381379,
+                        1939088665457776343,
+                        1224396903192385133,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12303133404692551548,
+                        18327338037400591015,
+                        10218608761346087983,
+                        1181390618711115979,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6684533118178562485,
+                        962410910011376944,
+                        8905473534191567613,
+                        701733621148014642,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4604736501525077803,
+                        13090433992785298323,
+                        9536110020257199296,
+                        81805510938272686,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1670070006881034330,
+                        15351417751016789579,
+                        1306310218533644067,
+                        799258323601187957,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11360756632636782712,
+                        17181776660783046505,
+                        6343525905750378525,
+                        431849793533500609,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        816543069949850179,
+                        5984483556497389786,
+                        12502049297399613261,
+                        10925207742697009,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4178900307684491697,
+                        10983356755394341197,
+                        17567432201079409945,
+                        927200351493319258,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6106861180763482544,
+                        8074874887212583163,
+                        15268506016523692381,
+                        1028260167212285527,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        548311881220213461,
+                        324294120364918683,
+                        17903538452024868972,
+                        1236802555130246911,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6047673519147916923,
+                        2294824432577142611,
+                        5379173860832113644,
+                        55158844548291838,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        677883447580343184,
+                        9944701450730545154,
+                        15111407949753828772,
+                        721913257157998500,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1075678222679940333,
+                        18095280989641408508,
+                        16907807547856763094,
+                        299222426461063024,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14060368456207181334,
+                        13040110137218626893,
+                        15774399289421715748,
+                        1328719784139242688,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7520516497414380804,
+                        4129251482680869786,
+                        17791400610255442082,
+                        1047433646493011622,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15780227880413732164,
+                        13520586225655805051,
+                        5279590144408341284,
+                        890054632335106746,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6518073600330184531,
+                        10775946512049396420,
+                        14883049973154748776,
+                        524838840652244416,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9288609432053478772,
+                        13462653854329916555,
+                        5130378940948682948,
+                        636302216333972908,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        165753146554642706,
+                        17054940669083050190,
+                        2839432793286737670,
+                        816056122492371436,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10473168599914945234,
+                        17186723810118842366,
+                        14369286329456769755,
+                        435986247158263860,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        734535206330673372,
+                        12328687782409980029,
+                        12078482454385642975,
+                        132879832503909417,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7713986188579435474,
+                        4708855751927153157,
+                        16248140903581003561,
+                        955320138152228434,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5641653908465956629,
+                        5332987535907919147,
+                        3624944065299922067,
+                        591170910237029383,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18144690838290825083,
+                        16579576489033938017,
+                        3408393339110688767,
+                        714713172982048072,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14807028735818802427,
+                        12661286145131957554,
+                        9544545870333173458,
+                        44600780982570655,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12669366197212186852,
+                        12887973577194994645,
+                        12338854366682905038,
+                        627993310876310266,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15119834095307046557,
+                        5639848677578457124,
+                        14280957394495126712,
+                        1137764709213994899,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13121991769258815776,
+                        15421349046954124703,
+                        6438937987206816083,
+                        11183401290920689,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16515869154571639099,
+                        6520774597455994518,
+                        14078894527285925089,
+                        1289732115541556800,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18131068431206049103,
+                        1131708073899585123,
+                        3251180788997960850,
+                        705467875783950626,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14530950757663223386,
+                        16146456905474645040,
+                        14584972399892150928,
+                        1285746950113838102,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        596750855166820215,
+                        2983597568372681947,
+                        3200040313600409669,
+                        947026861054994138,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14922881485938554472,
+                        4498191358068879505,
+                        17908839964220297018,
+                        1302493145839868652,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7837068411557830808,
+                        16608775113757156127,
+                        14753967995425959158,
+                        1181629451702366299,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14192679995854996034,
+                        8653166199667321413,
+                        129837896020892506,
+                        883907651595397207,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13079487757730056378,
+                        10901701970658886583,
+                        13382555332058309350,
+                        881379089471463951,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3740664709556956497,
+                        2348273652978120259,
+                        2959285948504361978,
+                        1222316071135368432,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8302143225879094950,
+                        6005850766917255441,
+                        9800270642687373371,
+                        240163568097185697,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13763947052390203411,
+                        918968526960964537,
+                        13692531421087127548,
+                        785739614081817442,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6904535550549491468,
+                        14724698342138117262,
+                        1422618799919481722,
+                        945590960732770403,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        737640038895878803,
+                        9901009661470978006,
+                        14718625196285185474,
+                        68490053067285285,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18076679663295888137,
+                        101926404058588525,
+                        9228978639269969426,
+                        390334876868960857,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8945170805765942712,
+                        3119351743299702291,
+                        7046155258049722758,
+                        1020825406131044870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14207864659618310000,
+                        11100824730060559839,
+                        15890793103717596952,
+                        548213785888100246,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7854398341570842827,
+                        6376062227042860655,
+                        3057456026382079076,
+                        626138105581066463,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6352088739528463552,
+                        15351892525289690314,
+                        13692764688733199456,
+                        674805342172598540,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14579096860471252735,
+                        1965346479083620164,
+                        3178780937204674169,
+                        932827528387723437,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15527745134991115075,
+                        1669067553352211130,
+                        954492900400723529,
+                        730592386896096038,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17568844888418543768,
+                        15466419499290954515,
+                        13893143774815237090,
+                        5743900136362574,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10240174293749365246,
+                        5616126612184123399,
+                        13226849830122306404,
+                        509629440203272258,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11315002893601390056,
+                        2217880511705827506,
+                        2707287596129552513,
+                        1191881433749351244,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9238071658845859751,
+                        10724559055263453025,
+                        7990646036917015689,
+                        735062300718189338,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5165897449741332966,
+                        6559753848154150102,
+                        15442853466936595128,
+                        663888660749280388,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15465513646126061386,
+                        10053977124427244191,
+                        9311919388251145793,
+                        713269389720697387,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12473424046221144090,
+                        4709582747643076371,
+                        13229093908890356793,
+                        81625253131474908,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14986067459110310527,
+                        7344251488975413199,
+                        17318378728318650337,
+                        574328562831003650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7854255459674063975,
+                        14058778250355955181,
+                        4417443456293371012,
+                        888347353567966631,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8329080782729823648,
+                        16248714452702032148,
+                        12778021595471849354,
+                        212204399837828856,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15309184507644553268,
+                        7759517058802446940,
+                        1801481112560472267,
+                        49546196244333715,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14955849441968190164,
+                        7984122211984847336,
+                        8658138043140284864,
+                        1060226754676010484,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5080542945225471589,
+                        14514336059369295212,
+                        14437212375645445622,
+                        231044714152436190,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3140115003072076536,
+                        12774070254074245952,
+                        1170088315567485185,
+                        1051592193398222823,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11363156644984965943,
+                        11733217082461821950,
+                        1708725694952494363,
+                        1299603492887439908,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17721391344706525859,
+                        7825444566099724129,
+                        14635195086420251038,
+                        409497071789054801,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5317181643968749394,
+                        10507505849011442000,
+                        11970259919697615212,
+                        171518139434366865,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1656770804657059896,
+                        3727511326403005302,
+                        5757784567083232069,
+                        146443703814518613,
+                    ]),
+                ]),
+                Matrix::<1, 8, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6175140142522076497,
+                        4927955590682642734,
+                        16216577542131610669,
+                        119503967116062944,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17512993254064852546,
+                        4019236732738016449,
+                        13629812303630219625,
+                        712370326438536769,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8869298103707335803,
+                        6520950204046213584,
+                        5487867576401157706,
+                        796105868690677526,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16236751731532633634,
+                        12124162254046430865,
+                        6060652770278572322,
+                        1315203713836169275,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6293465926342837686,
+                        11718766835079291236,
+                        13963217876417603107,
+                        208705534876817748,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15221267963252736303,
+                        408486807096930676,
+                        1570150061578747423,
+                        440226453904817464,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3493793570042930125,
+                        9385729721331140671,
+                        15317790136535671436,
+                        527135018173949269,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2587086914518762121,
+                        5607946197300662473,
+                        15264229601303871110,
+                        452245568078080397,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11738604281243526561,
+                        4781427651196537855,
+                        11656817317081414594,
+                        74769487572114430,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1837325351615383592,
+                        14993493736544850342,
+                        8290779673102001293,
+                        832115724425473731,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8349916801170236627,
+                        149583357632573401,
+                        11715649778757746902,
+                        1262122030646274423,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8045625620315945304,
+                        1995830356710874034,
+                        1679071660637833653,
+                        219657431649533445,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10091116086211147286,
+                        2713360843774189783,
+                        5712452338043765592,
+                        1030703017451954970,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8071065446812442932,
+                        16553816416727326828,
+                        14558816056211727591,
+                        16962575699738561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15195002240067566314,
+                        15833531177699155921,
+                        16833437579074607443,
+                        377096144896733015,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7503120522514816782,
+                        5894902138933683811,
+                        17651909846607548369,
+                        662671645650783132,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6202249398127153778,
+                        12507558991562801223,
+                        11949190553500985473,
+                        1204811177152405795,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6307860315963058800,
+                        773649057066801678,
+                        6627376894567823023,
+                        462726635393957440,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4322501393236875163,
+                        10398344100568703527,
+                        1661681021877326078,
+                        582190098240808052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14777739640542155884,
+                        14952573287156564025,
+                        10347958627391708707,
+                        918275673205159431,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4977495293688975530,
+                        6187075301624392873,
+                        6047416591218293619,
+                        659273459332093147,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9220495991484310256,
+                        9521442840470200069,
+                        13849751377484388558,
+                        1265858033244235820,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10531301362232621619,
+                        3243510871428181876,
+                        17614206164977259162,
+                        655147742041307930,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        357700252862776004,
+                        5031360370533983042,
+                        14432905040657898355,
+                        243208483712233388,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13810576800533848374,
+                        6468923654170800234,
+                        3761982704498883534,
+                        138463606483814101,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16549970479149223614,
+                        3264199915478780035,
+                        11311040237895268797,
+                        957280174773428510,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15958631244565113369,
+                        753418787878900380,
+                        17265617006222035204,
+                        120778065137523832,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9005591990161758624,
+                        15486455772458559257,
+                        21565790237192809,
+                        173067383453760896,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10994197996621593696,
+                        892739591477825157,
+                        1043584568320997616,
+                        653725389502196737,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12430030675800975823,
+                        10810947269014494410,
+                        18405260686332413161,
+                        1338499738398780258,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2609127805335034408,
+                        3055487631228568441,
+                        10556000153078276859,
+                        537774723535395959,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17961951919380270995,
+                        11792515529303299168,
+                        4551266871419666632,
+                        874022938558153652,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        372761995971061539,
+                        9294643391523205956,
+                        1482953053637078543,
+                        1269344929902732034,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13967123178290464094,
+                        18015880382080316364,
+                        17172605413124779783,
+                        19702357388130447,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11520929406828477651,
+                        8959847946770914180,
+                        8793096584793105745,
+                        471718570463394276,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2889890414975536150,
+                        14104113975594687453,
+                        1847843556624591832,
+                        891013479274137587,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18226033976904073761,
+                        7302626773576971181,
+                        4178062075357636967,
+                        446892545975741596,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17665674565415584638,
+                        5436418687837859556,
+                        9138011259925754941,
+                        1282797480174522469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16388656592238448991,
+                        16970480372333203877,
+                        17769640035287932939,
+                        405572706561606238,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2903466500830797514,
+                        15488163554972942946,
+                        9255532894887666746,
+                        25291718421740695,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17264598382346088310,
+                        6626081164370810562,
+                        2410061032665071763,
+                        624433106713468543,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11823445007733889327,
+                        7398198087627276444,
+                        7199775265957555882,
+                        276712115582853507,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13513956838780872954,
+                        2135742813078509505,
+                        9881640450785162183,
+                        157558297977013145,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8735744286678315875,
+                        17627939940773669348,
+                        6911054698096935938,
+                        306239131217827101,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8580281871623133072,
+                        1399537198060882474,
+                        17137598936977576935,
+                        198505976837111082,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13708795629253095935,
+                        16415934172517546412,
+                        14455099504760645534,
+                        631876089993075290,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12671239638190901932,
+                        8144202003777663977,
+                        17443888463186211246,
+                        787455732508746312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4440948533988500030,
+                        4892805050530481395,
+                        7376293698802172272,
+                        474278253293759280,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        315470352476081053,
+                        11473535812080727186,
+                        6342382165104361948,
+                        726596843107425492,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2388762583842375286,
+                        17325652356129159425,
+                        3669214917426892086,
+                        1046949260040070876,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8325087491287029142,
+                        12398026860738646908,
+                        5744999700485913808,
+                        379585429309478175,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2081476910787838561,
+                        10170044464646800338,
+                        5645128160244340038,
+                        523153174173259759,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9761495564868950578,
+                        16895368772694035777,
+                        618684770460009459,
+                        833989194887621019,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15274257505594888121,
+                        3130559333386359047,
+                        11317667520941448046,
+                        477080836684592146,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10534261419319393273,
+                        12557414166230799817,
+                        12760404922802005450,
+                        1103155959303143122,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15115926133415990202,
+                        16950189807755202123,
+                        11228817603262992355,
+                        1306314544891420988,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17236124801292393482,
+                        16769363978222642208,
+                        7627112297540303143,
+                        102054128474773215,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17468800419768790151,
+                        12850025370735932795,
+                        18341641105771464062,
+                        858450030655668312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15133942855743703590,
+                        12716725428792472205,
+                        9656618689320489343,
+                        675955215795416010,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3777937710114195182,
+                        7282823941065479917,
+                        15855751682169894190,
+                        94448866276541741,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18119355867181649547,
+                        3934670131793771004,
+                        16700379537506112653,
+                        241690706807784368,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10945578206732498541,
+                        8276196146913581371,
+                        17956778410605536985,
+                        379182799320548956,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16440377338227081452,
+                        6493251560216903147,
+                        2747100893842169586,
+                        659735268580537698,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9964715493893805746,
+                        1479376038870035847,
+                        8017308822000123091,
+                        1018115320318138904,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18236821215970291732,
+                        9292878995823060576,
+                        16480054472624542589,
+                        1188081294280539182,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8358503404491839932,
+                        4083964959681676462,
+                        12776433650556260825,
+                        1300560063239692968,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6646024944841464471,
+                        3712290412324827129,
+                        98647403290113668,
+                        1088703790811192452,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8503478186824016279,
+                        7104527154048734097,
+                        10050808948726525160,
+                        1019898097047960737,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11158148013235810153,
+                        7151054657695425176,
+                        5759951319015002266,
+                        157273320260698300,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1714850732407427448,
+                        15629530241409968219,
+                        16250861270450235856,
+                        455868064107315805,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10821525799272459957,
+                        15803060934336089845,
+                        9945003255330492954,
+                        1021240139916835567,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8956708967269562694,
+                        957349509024463340,
+                        9058418226523962251,
+                        1316874876081054448,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11928248878852919088,
+                        908224202523529000,
+                        12142056120228781069,
+                        1201405278085240943,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6645419123270624154,
+                        4282140617608084771,
+                        9729191294879770837,
+                        566807420583237616,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12000243285105539793,
+                        17753710137259016357,
+                        9017262855271580093,
+                        685352403684613702,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1338671999858385758,
+                        18285830210940752513,
+                        5569230021137944445,
+                        579334964751625092,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3333944726667644555,
+                        12838147182661192129,
+                        13286339853299219336,
+                        353793781611312000,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12515039104991329799,
+                        7856518301393997938,
+                        3319290273091072968,
+                        835150300684121615,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6999117655279578873,
+                        3935151071760818260,
+                        11071066662863064229,
+                        471164851730367733,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17616012733859513947,
+                        18061016774795059050,
+                        10751388664766249735,
+                        1030126877766199827,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4309471943793071910,
+                        16209907518277189887,
+                        13859079921806087638,
+                        156500004490495395,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15436543758362440824,
+                        9185472181125252958,
+                        10588514587120715363,
+                        512443070167725169,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        846594830825241057,
+                        14444246886921461488,
+                        16225085872951981694,
+                        199423965361926859,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6242917843977232222,
+                        1712383935695265221,
+                        7543086093960507079,
+                        381639812213206184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15437588080643198905,
+                        6270714469475216669,
+                        4849240656845206436,
+                        22310737875334578,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8223023399738530594,
+                        10461016387666687858,
+                        16185094095607955905,
+                        253894700694126827,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13251498541608354703,
+                        7951710893894302919,
+                        3804681084974882630,
+                        1167507373134245563,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4842197548643571861,
+                        9572639373616508683,
+                        17093242511000547552,
+                        1301935443820789528,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7165772602348329946,
+                        15125695288879488992,
+                        17402713234012527841,
+                        1242496223550292719,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5340551051163059100,
+                        11271559580698490971,
+                        3873960393964415422,
+                        454668128910653963,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13520195734550708110,
+                        15953292648971101067,
+                        8936246225928276920,
+                        1177099092608645823,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8899251110114138427,
+                        17865606023494053158,
+                        166679151228087874,
+                        10954435473399783,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6194302845110114465,
+                        7653372725366109453,
+                        6932235319197318611,
+                        858377016912994835,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2198282616787985051,
+                        16182176247946072898,
+                        13767804806800173562,
+                        40216437966516453,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2691386197881432732,
+                        17709740934224834294,
+                        6197017448421922115,
+                        521756243895212101,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10186934637231631682,
+                        728354396148753092,
+                        9370826459176323585,
+                        765208664359955974,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2522869633803347366,
+                        1103746861131337492,
+                        12132268050507674548,
+                        650703213147873065,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10885036570975979096,
+                        3450143691968224683,
+                        2198523515066954520,
+                        731198352194418062,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17067476576494104112,
+                        11310865372520547178,
+                        17849980466326099195,
+                        517839287121450505,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        744678484776348697,
+                        9952054620042222261,
+                        2116470118671974248,
+                        1279441387876294586,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        161838774404815495,
+                        2497711087198897879,
+                        5616179547460349104,
+                        460571250970886099,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12158135660654360143,
+                        2507092976764764364,
+                        1179496952651837014,
+                        164938189518478727,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1261524417752293833,
+                        10514112817023192364,
+                        8522340422466064507,
+                        778262566099927074,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17029347286817680622,
+                        11672429823491746909,
+                        15887499020387199344,
+                        511339044055518291,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14275152893305262646,
+                        2702987105032836866,
+                        7363016410216794688,
+                        1180707650480732751,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11403320925645028388,
+                        13082491577657377246,
+                        10428126979587763923,
+                        206509352625782462,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2664770750146475248,
+                        9818949128311994244,
+                        13263633259304189218,
+                        94249391920858528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12446865208530287295,
+                        11743841252762391,
+                        1421705985994136821,
+                        1158466954094333856,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1321093080070839659,
+                        5818432408021765971,
+                        974262665200334944,
+                        1186373971357089469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3561122190749966573,
+                        11977096565584198082,
+                        6157237382186457300,
+                        721784425134582333,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16696168610054060748,
+                        8724723786226247473,
+                        11362570117980524761,
+                        848526304744462307,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17846482890484333923,
+                        6890135432866475896,
+                        1005015730839012965,
+                        685346350175907808,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3081759466174535056,
+                        16059419396315110504,
+                        11523460760518189505,
+                        5528935209427983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14815968666571720366,
+                        13261963159779856825,
+                        1365263402089183814,
+                        821744872369152038,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6839580936434978305,
+                        6292220031985837393,
+                        8447519359196163666,
+                        1165875764889953007,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14153735945342038287,
+                        12666018904357478076,
+                        4314105207250577123,
+                        235092787312099416,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7198762533939885413,
+                        4051181526083006924,
+                        1660993064019520296,
+                        779546173723916970,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14522434450589510031,
+                        9699530224584746795,
+                        13807071376125362055,
+                        118867470633706199,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11817897698411238491,
+                        3165151001876635389,
+                        16438208200936976478,
+                        278795924493439659,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        34371403715436006,
+                        4756832963624070717,
+                        16578626693161238517,
+                        824504497720603870,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13052870365536770752,
+                        18202755095918779208,
+                        13558339178670055581,
+                        996754862829880037,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5809913564381349567,
+                        104005079898805196,
+                        8785455264829083900,
+                        276133875162043070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5254715320451113964,
+                        7891623136133057183,
+                        9833382468485162206,
+                        1216770800234915683,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11423411060400600165,
+                        12046895229284012854,
+                        13395016680227380638,
+                        702459597644015181,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10911605199897052373,
+                        12583544468258405811,
+                        12084614224084338806,
+                        139951777587272469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        856335687129410057,
+                        10458991543841316400,
+                        3166407296970366139,
+                        1204818360844027733,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16877409629764578191,
+                        14188580244299567827,
+                        15205424791005047912,
+                        988175648561110939,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13368534168303757863,
+                        12188437579265015955,
+                        10557785008159026374,
+                        713267537064531236,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15333559706522847445,
+                        595288943195041257,
+                        10399956059615317994,
+                        1301124083646774952,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4986340920518155764,
+                        8210170450249220187,
+                        2179294744778579817,
+                        1041091086661376410,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6218118431012755205,
+                        10197323880844352403,
+                        12941617008763525733,
+                        994038434592772683,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8646965870251158657,
+                        13362224428529956335,
+                        11501602014573431944,
+                        1100904923037473052,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9452763160301710262,
+                        16552134034823409425,
+                        10673905772270239950,
+                        532278856780006119,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5512627621466341992,
+                        12647896508085085,
+                        17839533686641586030,
+                        710432342925628399,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7513617012371300538,
+                        18339527239207505329,
+                        2018187245786472999,
+                        1304371872752478606,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11824939989453699004,
+                        13574894463503770155,
+                        13838993563535362879,
+                        397706549808898015,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10379274641420524224,
+                        7656696382109246686,
+                        7342334670632667934,
+                        887517669301282467,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8982537049106235413,
+                        3864996008346178510,
+                        6209931541783406068,
+                        1063403104845413423,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6297873245736292941,
+                        7267732500154940029,
+                        3004162526450170870,
+                        14466670105659483,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9329888861895002397,
+                        3016229695181372692,
+                        18007959927584503188,
+                        344627320315213660,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3926541594468173394,
+                        11582313038156023953,
+                        16463943587473649099,
+                        1291412240445833606,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8162710646172535617,
+                        3892449539549591683,
+                        4965545338025700163,
+                        475450163174848588,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4503672276062649615,
+                        1743583505694220790,
+                        16697251409198373887,
+                        19201173753794634,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17322785659434390935,
+                        5509175287978066232,
+                        5018975340805410678,
+                        1329435183867991495,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13513544323556173434,
+                        8670942347734432386,
+                        15962259278303378406,
+                        482675653397551015,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1994147157123928196,
+                        12705048446600706893,
+                        9313760640575330491,
+                        974405476005747444,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        256773809602695468,
+                        4686530523027145970,
+                        8971939996379312753,
+                        351917442471342290,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2476276002779461311,
+                        15059588642583264647,
+                        14910980597965446489,
+                        370170532834354471,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12271545201562023456,
+                        4193011663943853238,
+                        5410201273095902044,
+                        341412907462819696,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8326523282956506748,
+                        16061848467441490255,
+                        10944110232799070103,
+                        181745253886034554,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4597642864751857973,
+                        16202692637675306939,
+                        4582917336526603158,
+                        1109114426603781019,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3154232561929501261,
+                        3172441617476796581,
+                        3544954237786150961,
+                        805918968637919154,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5125684594857097504,
+                        6552782913108872493,
+                        13211322542423475117,
+                        354096379513788397,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16793911302868512611,
+                        18041459269877569485,
+                        6243091018872293886,
+                        383557764730683540,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3307993888055210669,
+                        17085103886666793660,
+                        152662493023343013,
+                        929380306038839762,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13261229127565520253,
+                        17851014299455271003,
+                        975392960526183503,
+                        182659277054608504,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16309493187983035638,
+                        17963172457556085445,
+                        4814957314229866570,
+                        1119720783090518299,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4223629235047888825,
+                        3596581951457842297,
+                        2572188343223861257,
+                        163324487284730246,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1887916001130815115,
+                        11827736719806521680,
+                        6042867363276518912,
+                        264739241637995924,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15457655896135703183,
+                        6008128708466488194,
+                        5745465992051511606,
+                        1305395511897601509,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7853123572385142645,
+                        18223472310074508364,
+                        6387210350667653418,
+                        1200637179319994097,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        961591950636844151,
+                        1492291382697173489,
+                        7412287086853256848,
+                        1344202599015365944,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2535733288668160744,
+                        6828403630402699903,
+                        11691317198681866421,
+                        858385366878675617,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16813586131143177644,
+                        2063943679101935161,
+                        7231297084542357061,
+                        729365747834642896,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4536418084254652771,
+                        15495689197818581435,
+                        8324045050244354860,
+                        1257450970036417127,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17070514749844219328,
+                        3551970137106663033,
+                        2178462514086810282,
+                        641981892986820992,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11289147424137354077,
+                        15815170431994038296,
+                        15160672003576923711,
+                        568706499808866237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3369532541155988779,
+                        17615393459457147938,
+                        8465235200467739942,
+                        424789589836451426,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14788274950785513667,
+                        10580134548713992067,
+                        2547960919021048501,
+                        1157051520009797642,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4037066547749110531,
+                        6693220331980650802,
+                        989047424897683110,
+                        948156514629119068,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2932916420469707425,
+                        6129444398785773312,
+                        3555469470385376362,
+                        921830299234963927,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2161396698958511936,
+                        16525375630293398743,
+                        9366358080828666155,
+                        1115314517101527198,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11326898725465949114,
+                        7448460265125915062,
+                        4690592652636825525,
+                        438082208872353295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9063145806845254084,
+                        1065386293412965658,
+                        6121554667541258491,
+                        286604933997521807,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16450521310536239634,
+                        6013849913667333120,
+                        945493674474205083,
+                        496581710145354463,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15907709372432191988,
+                        17281244647438734281,
+                        3966420660733279113,
+                        1023108662867969622,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        635661114849628719,
+                        12497410409981754361,
+                        1640040916671289298,
+                        11544576484599587,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5566087510478641094,
+                        16652155734814964479,
+                        7387143688083280638,
+                        176326540537861986,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1800918830315766168,
+                        3023204538852555422,
+                        3435009880924290386,
+                        680861628976758269,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10420848072433209167,
+                        9712607643478610217,
+                        2913753681663361979,
+                        193899858244730610,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7699840562737262238,
+                        13968687854032700983,
+                        7810510099013964536,
+                        349412301240640579,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2649121371925194145,
+                        5700367884691536636,
+                        6704634976184421573,
+                        45802896297182714,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13288809391650206167,
+                        395849269213286176,
+                        6847739792237409449,
+                        1102311068727483659,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16269787019564013112,
+                        14830108917510036018,
+                        13403880497510117744,
+                        76865716023426904,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7526689205442261988,
+                        4038313746140351816,
+                        8711696909920957334,
+                        362476737438352017,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        342905309693622580,
+                        11634301816370444253,
+                        3292137567728362566,
+                        724489367857248568,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9968319198435492824,
+                        5996959405794183953,
+                        8089686624448738110,
+                        838666292114231059,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12766364797019306038,
+                        1869668551057646907,
+                        6498404382562608952,
+                        189895355542671303,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9606995172332424783,
+                        13394646575707076232,
+                        9429091426964569370,
+                        1295258628478812331,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9638576763933264367,
+                        17134208307285880939,
+                        13020891803440758090,
+                        923381923538406548,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1051711664465447808,
+                        1232948959882462714,
+                        7854989553791532451,
+                        906739651735455696,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3406359795466005104,
+                        12595995242711668846,
+                        6272102536459423378,
+                        1030907677395910841,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6736690966323737890,
+                        9290276230718708901,
+                        13096775544919453893,
+                        835453089051130044,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8329461089474181406,
+                        164549415178042662,
+                        10662420145569070453,
+                        558381423478197126,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13431087376924055908,
+                        3514899809743304411,
+                        11254811580980548410,
+                        639191307679150379,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7588571143149289394,
+                        5647826977191142147,
+                        612608495867482873,
+                        687780253597554451,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7175908688250786346,
+                        8422376293754799966,
+                        14149720025638639579,
+                        451419716765863783,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5140559325551628213,
+                        2764507418148537984,
+                        10688338744613513448,
+                        949303997219682222,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7717008612450962071,
+                        17652976099873920456,
+                        12967107180295006564,
+                        1085260658019770610,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13741661250020425316,
+                        15781130015623119221,
+                        12053787263354595908,
+                        609337305649351551,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8712774864983289738,
+                        12617160718187893884,
+                        2136397231029672099,
+                        458117140079841951,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7893941366051206318,
+                        15079359585833408502,
+                        8818666431251323271,
+                        1065130870456852955,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7803819030132398263,
+                        8607258216423769851,
+                        9194528161971357054,
+                        265722424264604722,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17354540831264672208,
+                        5361444810575649233,
+                        13654554259910334135,
+                        1309481407148949902,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1897952346554602799,
+                        10999797720344324961,
+                        9697437442350596973,
+                        10090995053158320,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15119925738885899248,
+                        2834074949061576753,
+                        16570549194877904291,
+                        812145611318228533,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11845305531582841274,
+                        6865793606919538102,
+                        6536594381429039794,
+                        160724451130687101,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15870045854261406804,
+                        846553328663930154,
+                        13703640534085013955,
+                        31901312265069999,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17777868338713318238,
+                        4018344121960949792,
+                        15551605074397316839,
+                        171486213138744741,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4304477151823191530,
+                        8802919319506919527,
+                        12287267196446406649,
+                        815007828434602158,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12958778020606585055,
+                        4943578634080607728,
+                        14841167575802040748,
+                        77305064526304277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7668386878261466012,
+                        11500705032983173380,
+                        16641362733283722430,
+                        1288207927203783389,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12852860804724698814,
+                        13932134978803314853,
+                        12552054132376539269,
+                        1277689279275435540,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        795872789869024308,
+                        1941403201279133079,
+                        7693748338249473057,
+                        1320364676527176163,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15690270921519504043,
+                        4327137212657363154,
+                        8487441899092028243,
+                        166809323361454528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6524927808802216690,
+                        17708506634732829285,
+                        991759863513041743,
+                        517790358864675679,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11529590728780675040,
+                        326833698022861569,
+                        18151912096309990440,
+                        481919617795228748,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9110036054404625747,
+                        1242614787191099813,
+                        7927689659795588519,
+                        301967671938833405,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5430188433048304045,
+                        7376433547297290472,
+                        10623362271320354075,
+                        1277706241725652160,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15259362858723572374,
+                        12999162154359606668,
+                        17698613438376164988,
+                        50340022570227050,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11391012100209889246,
+                        5749779845019995641,
+                        8882981304787005884,
+                        1100067918338372842,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3156459528724316854,
+                        6562542856045739458,
+                        3452592903204308621,
+                        1154734979583327880,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9632717566439113218,
+                        8821134320368448689,
+                        4123098811249424506,
+                        761905941720944040,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15359425056201590402,
+                        12197064706992841211,
+                        16530147668950340883,
+                        446869808227143267,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9690543160894913105,
+                        1214961371467828751,
+                        10651453660860969471,
+                        1116244159962067869,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17405178633470315256,
+                        799855790165055647,
+                        17986970459150629301,
+                        336970355766137866,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16095751735101557677,
+                        4650188985756069481,
+                        2841270114251305181,
+                        1107274332317601316,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3288963016747454797,
+                        14527653922181682282,
+                        17249478566068470088,
+                        555921454774456580,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12131957392461213116,
+                        2447662367777243166,
+                        3805833306678468324,
+                        99591837628342312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2440511413659381112,
+                        3903034068891353360,
+                        10476990898387625159,
+                        242457960403679482,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3831064670154464505,
+                        4074824626249655799,
+                        16389297433157577688,
+                        631036093735713756,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2711954200287817531,
+                        8134393628700519763,
+                        15786219827439963286,
+                        1053865675382370401,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16851103045480922324,
+                        17007337006340723719,
+                        7145460191296772530,
+                        184971983048715552,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14683997206779890063,
+                        11516024823182572113,
+                        7626965117692023511,
+                        757142558371076232,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18212347911052811845,
+                        10394005714802126745,
+                        13780682664511838535,
+                        1111058012548940210,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13120312402932044220,
+                        5397425571654964650,
+                        6660901683816533975,
+                        974833734704610912,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15178132372850435973,
+                        6704234967411521807,
+                        10862943557080076249,
+                        834278157453102647,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11082321887031241699,
+                        5601657284143726238,
+                        14069949355835659970,
+                        17445800722916948,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        538760712508941515,
+                        1350116026574413632,
+                        7035879678056152065,
+                        113046305037847909,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2585952313596039399,
+                        9946589133647717256,
+                        10862445536718354252,
+                        509928649394202710,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12383384870397654057,
+                        1712051961117650513,
+                        18026405708795328584,
+                        494792519695109354,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7876668975818121822,
+                        445295553651618134,
+                        121510109337058635,
+                        93041040366408058,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12929379943427849622,
+                        4094579991496066202,
+                        17331999804506834052,
+                        207640928036894643,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8440531761792911198,
+                        112988549635965883,
+                        18142840226850177071,
+                        588823466569571375,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10481984680605310025,
+                        13234778030836426495,
+                        1220322293576522679,
+                        536188093816201854,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2586050576900560449,
+                        8922554953434113859,
+                        9641092150906530730,
+                        1075369679234141962,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4845875805549162187,
+                        4234323970008102415,
+                        10104870525649999307,
+                        268617649694149916,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7112074967905473616,
+                        2718749847273796788,
+                        8943337427018343063,
+                        945536069526127824,
+                    ]),
+                ]),
+                Matrix::<8, 1, 8>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17283409599714137286,
+                        4869315721994130359,
+                        5252442107161819027,
+                        996935302146333505,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15120659771620485674,
+                        4581207072019548656,
+                        5960455207982414505,
+                        547872226445791588,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5649657975227330215,
+                        805288621064665146,
+                        18063733599520049768,
+                        93746042233274747,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14662890347499563631,
+                        15178856833756924547,
+                        10742172522891838930,
+                        926879023688789618,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10166107109481053054,
+                        4593029577935759346,
+                        15102498410309874606,
+                        1142038064719806339,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15928613187109642911,
+                        17405290895987719812,
+                        2037498332646186718,
+                        863771480568335274,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10600157633522990637,
+                        2235245413885505066,
+                        7261237200684282097,
+                        1036127347366850104,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8699590202573950459,
+                        6785911490464535191,
+                        15351501945738943887,
+                        71147419215468909,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<39, 9, 351>::new_from_known([
+            Fq::from_montgomery_limbs([
+                10201853239810132854,
+                13371867521406010250,
+                1700541822071243406,
+                195313311928034790,
+            ]),
+            Fq::from_montgomery_limbs([
+                16154342525220351098,
+                13262156807567021152,
+                16356673692040418582,
+                350244407592144863,
+            ]),
+            Fq::from_montgomery_limbs([
+                12099909040567800728,
+                10341958551803486341,
+                8092854215755121091,
+                810043704237387478,
+            ]),
+            Fq::from_montgomery_limbs([
+                550616237166555126,
+                1267658684286191462,
+                12696893761282310283,
+                687887157179048610,
+            ]),
+            Fq::from_montgomery_limbs([
+                8394931224251290159,
+                3546414484182007322,
+                17210851903430067460,
+                29741179667446326,
+            ]),
+            Fq::from_montgomery_limbs([
+                7663372929941436886,
+                17269684479209698724,
+                2140673952869432922,
+                857788194092333313,
+            ]),
+            Fq::from_montgomery_limbs([
+                15664277524890466887,
+                10404960733359588299,
+                5101239576810141337,
+                504179257271959051,
+            ]),
+            Fq::from_montgomery_limbs([
+                12228278784797598676,
+                8499238634556631937,
+                7861030531681392652,
+                906714920349483907,
+            ]),
+            Fq::from_montgomery_limbs([
+                12841056056659623553,
+                15579956229405378606,
+                5274235013913040110,
+                755900995070248939,
+            ]),
+            Fq::from_montgomery_limbs([
+                1476951610651101311,
+                4087875914128508001,
+                11838636028724522351,
+                1160936026177424472,
+            ]),
+            Fq::from_montgomery_limbs([
+                14602989099918145579,
+                5635458450488319138,
+                6743051819603190881,
+                215261377525450030,
+            ]),
+            Fq::from_montgomery_limbs([
+                14868909705206047144,
+                1874204996613417671,
+                4106244837287628752,
+                188328888280666696,
+            ]),
+            Fq::from_montgomery_limbs([
+                12610078053655325215,
+                3857612327153925830,
+                15861957146543516138,
+                858253247368512894,
+            ]),
+            Fq::from_montgomery_limbs([
+                13953679288526442879,
+                10720832918615351837,
+                16022034781366168996,
+                297597593296496228,
+            ]),
+            Fq::from_montgomery_limbs([
+                6376100284953962357,
+                12423405611485554147,
+                1081394178843108947,
+                1256887901426171988,
+            ]),
+            Fq::from_montgomery_limbs([
+                8158865741164984657,
+                11517726388655795847,
+                8791002920672387007,
+                1166316734508759526,
+            ]),
+            Fq::from_montgomery_limbs([
+                16074538482966790467,
+                1889695742182425820,
+                1988009630148298141,
+                747985512537520349,
+            ]),
+            Fq::from_montgomery_limbs([
+                6908974931338345648,
+                2509447823331723008,
+                6061181428098122720,
+                309865580617607620,
+            ]),
+            Fq::from_montgomery_limbs([
+                1542642171686531607,
+                9795761202672306411,
+                16275396653030347372,
+                606362968650290708,
+            ]),
+            Fq::from_montgomery_limbs([
+                250439127331548732,
+                13548306429719457237,
+                18200308757697593585,
+                608140832794646496,
+            ]),
+            Fq::from_montgomery_limbs([
+                9792429147052773903,
+                13932225170212453681,
+                7445093537295219872,
+                1310204422318721540,
+            ]),
+            Fq::from_montgomery_limbs([
+                13168625466320176513,
+                8668758253748849942,
+                14712022192522737137,
+                236676817856650400,
+            ]),
+            Fq::from_montgomery_limbs([
+                5538029257215681339,
+                18328247777762505587,
+                10213819180545996374,
+                749500787894965691,
+            ]),
+            Fq::from_montgomery_limbs([
+                15736678092569930629,
+                12803716758737594905,
+                16810263143168145991,
+                1318354266830266470,
+            ]),
+            Fq::from_montgomery_limbs([
+                2916718689179155180,
+                134559886715066582,
+                4332830762296752426,
+                1246479789540676222,
+            ]),
+            Fq::from_montgomery_limbs([
+                4404487185882818970,
+                14729527076164402500,
+                15825768527741860020,
+                621664866817310049,
+            ]),
+            Fq::from_montgomery_limbs([
+                8819462154402103901,
+                2582124528649838386,
+                7220488413449483972,
+                718265034232331587,
+            ]),
+            Fq::from_montgomery_limbs([
+                6771333096856843160,
+                1402107517857396655,
+                1133009138288524612,
+                1149275298706647856,
+            ]),
+            Fq::from_montgomery_limbs([
+                17491869211217561017,
+                2829254324190470398,
+                4324666525940431304,
+                176474596954741140,
+            ]),
+            Fq::from_montgomery_limbs([
+                7726384653235379131,
+                15005674743911252682,
+                13888834447189590138,
+                1014628311520343272,
+            ]),
+            Fq::from_montgomery_limbs([
+                14186994624663379615,
+                14042491295549231137,
+                11873143682435129396,
+                706853857900696184,
+            ]),
+            Fq::from_montgomery_limbs([
+                17084281042223149986,
+                3358911587953170313,
+                1861482273957226231,
+                965663650392071752,
+            ]),
+            Fq::from_montgomery_limbs([
+                13688390163184041427,
+                12218533625005547788,
+                3684843324949300991,
+                59246723449851546,
+            ]),
+            Fq::from_montgomery_limbs([
+                12924050682620449274,
+                9581352560075473262,
+                1693865507277786019,
+                37010082849115733,
+            ]),
+            Fq::from_montgomery_limbs([
+                16440127558289254856,
+                7820276423986984729,
+                15891555793663590997,
+                655997647322925579,
+            ]),
+            Fq::from_montgomery_limbs([
+                275910814369036721,
+                7830344065161235298,
+                12046346545077535828,
+                154276828524452023,
+            ]),
+            Fq::from_montgomery_limbs([
+                8672153946578624631,
+                17233094054637290301,
+                1295802356452750280,
+                1000346036275653060,
+            ]),
+            Fq::from_montgomery_limbs([
+                12065521625298848476,
+                18084326587214882001,
+                2400393572621930360,
+                1128403919603031703,
+            ]),
+            Fq::from_montgomery_limbs([
+                10046888141969435217,
+                7497861017140595205,
+                6540552123918641718,
+                543592178018354055,
+            ]),
+            Fq::from_montgomery_limbs([
+                12194698847670805333,
+                15957701646331545893,
+                10227145797668973115,
+                531966469855558798,
+            ]),
+            Fq::from_montgomery_limbs([
+                14703902323876320251,
+                9116806018005263138,
+                533913699104380775,
+                611964610799631958,
+            ]),
+            Fq::from_montgomery_limbs([
+                17743947105104964935,
+                13168054462820127967,
+                1638477051327562547,
+                530605318619333057,
+            ]),
+            Fq::from_montgomery_limbs([
+                4455892105650774714,
+                15053559165395179626,
+                5669007569013879872,
+                1031980265507550250,
+            ]),
+            Fq::from_montgomery_limbs([
+                10057827368276354877,
+                15781996038752069708,
+                14292903074211562977,
+                489325694713080855,
+            ]),
+            Fq::from_montgomery_limbs([
+                13682650351584118679,
+                18411301537986071779,
+                8084420610006242238,
+                893336564752283949,
+            ]),
+            Fq::from_montgomery_limbs([
+                14403409335896157667,
+                13960057189108165434,
+                9315196805171496053,
+                574552447055170057,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12179527056668024297,
+                12641542037838007291,
+                17243655392062736022,
+                1041718913818467967,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15097460028788601801,
+                6177737351091716620,
+                15858592146523963335,
+                153527473061986595,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9358689010171136381,
+                10568465318462299430,
+                5011580483137890912,
+                1093763959247555401,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14800582179010787485,
+                8515154225820825789,
+                11463551405935752421,
+                55358093056987822,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2734845728734955768,
+                12357753553781054824,
+                13869341411334526581,
+                472031736733691049,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2519407426376435854,
+                10532649432018848098,
+                9513162322929559836,
+                7565106325057376,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6435431546911569143,
+                18423133150868518840,
+                5684769838653678650,
+                509525312352022587,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14555649984385970289,
+                18016543190447516610,
+                6943425119940804463,
+                851112112758807130,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3786080129850591432,
+                16538991223484542339,
+                5108083152578822164,
+                290438807672764173,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1637141892835886610,
+                10005563774101329759,
+                6079775964836939105,
+                970215010404059840,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8957744677360567335,
+                9783474207838970341,
+                15974670714545981218,
+                441194340077682117,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3266478677610683488,
+                2017269075015331883,
+                13147045793929064510,
+                318908349616052609,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                18044203964235056609,
+                1397823064779583649,
+                18221391998813490720,
+                17535260397739238,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4871226499098387928,
+                5821335141236094012,
+                5996175382328697489,
+                1155200730009562974,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15534288185828960649,
+                15292861133448101944,
+                912424978150073491,
+                13332420292372951,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17737076158716157844,
+                6904821663681900360,
+                10718554161482215824,
+                50533343022499613,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15226259779413195132,
+                9028086528517490162,
+                7370759957084941044,
+                364737598910219551,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12229735001867846172,
+                89381657615221191,
+                12727789484758756875,
+                528238685045178723,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10899150311033233710,
+                8291474502891925709,
+                2457836211938637393,
+                48646771391994045,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10351205784592397552,
+                8165405827409340504,
+                2969357939175526759,
+                612500682631891159,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1188621323991432701,
+                11574780721079690500,
+                16673448071954683710,
+                1225683096306007334,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9202959816634599712,
+                13965746514796820217,
+                11714390831904522215,
+                876804902628363513,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13675266094793466311,
+                2845980225777216500,
+                11610775793657698370,
+                1029280220346407118,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9975553923785788308,
+                14623213214656512620,
+                10425804573499717765,
+                252107008609575139,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9281676118408925145,
+                16893243850126579185,
+                18239624720959966081,
+                24550007678501181,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7066729934072727636,
+                4704289187159654871,
+                2083907627873765086,
+                1157738152331833691,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                746295529388506234,
+                7228464186130179074,
+                512220268563534137,
+                240443810625526802,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5444483968086618102,
+                15349283069040594657,
+                16255163668587641277,
+                662239665505760574,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2948012582649057730,
+                15461791585040677870,
+                4116240565665006648,
+                1130102106743106611,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3446787474433720330,
+                3533947397919108364,
+                2828792568721393621,
+                509411203950402535,
+            ]),
+            Fq::from_montgomery_limbs([
+                17506134385984160288,
+                17580168595721972420,
+                691090508496416872,
+                404961967683957286,
+            ]),
+            Fq::from_montgomery_limbs([
+                2339965229983811335,
+                15190116320304359439,
+                5844176575063075808,
+                1175875000818572094,
+            ]),
+            Fq::from_montgomery_limbs([
+                6757490488888447561,
+                7057082526081950992,
+                658853042809938848,
+                1632681802549001,
+            ]),
+            Fq::from_montgomery_limbs([
+                8517647164180765035,
+                1002575156610826097,
+                6316877664602462916,
+                168142516121278192,
+            ]),
+            Fq::from_montgomery_limbs([
+                16869892714423333459,
+                16856797021030631706,
+                10432139940450912904,
+                244131028228352987,
+            ]),
+            Fq::from_montgomery_limbs([
+                15801870071323099661,
+                1341488642882084873,
+                6327208823200216368,
+                586589930341109336,
+            ]),
+            Fq::from_montgomery_limbs([
+                5424984692674692745,
+                4863664280332845703,
+                3976071960474231545,
+                1243356774020316478,
+            ]),
+            Fq::from_montgomery_limbs([
+                14851638290530152271,
+                15342111290246176807,
+                7149614070569527684,
+                310162070624199102,
+            ]),
+            Fq::from_montgomery_limbs([
+                5968543964331807221,
+                11252382463021302150,
+                15040751522972531091,
+                920093238940579438,
+            ]),
+            Fq::from_montgomery_limbs([
+                18294856914387428640,
+                7732953992703807462,
+                5607290693964873544,
+                273187437089777281,
+            ]),
+            Fq::from_montgomery_limbs([
+                16271899606589611184,
+                8129567029377751921,
+                330646425667713896,
+                950856500689097577,
+            ]),
+            Fq::from_montgomery_limbs([
+                13011128399387399122,
+                2045594717757625719,
+                14737761438439035903,
+                1246332520350120647,
+            ]),
+            Fq::from_montgomery_limbs([
+                8772730634967469122,
+                4693782814696841346,
+                3594107804787758369,
+                807202366028488635,
+            ]),
+            Fq::from_montgomery_limbs([
+                12466335421337443910,
+                16247789566258793119,
+                12395792089717616816,
+                944115159297132546,
+            ]),
+            Fq::from_montgomery_limbs([
+                6342577107578703346,
+                13824554984311340741,
+                11154048863408510818,
+                792971012258948385,
+            ]),
+            Fq::from_montgomery_limbs([
+                7977219875706108424,
+                7511985463844578227,
+                17144057035915158432,
+                1080800615283931521,
+            ]),
+            Fq::from_montgomery_limbs([
+                7119642447621829806,
+                12630188104425412772,
+                6734472731721694283,
+                731262142369417026,
+            ]),
+            Fq::from_montgomery_limbs([
+                1619213814543187799,
+                12829157040659552988,
+                7638993944384276767,
+                875115481375822949,
+            ]),
+            Fq::from_montgomery_limbs([
+                803906007343738283,
+                14328836340031912005,
+                8807620656043194902,
+                160339955011559636,
+            ]),
+            Fq::from_montgomery_limbs([
+                14554614749198380347,
+                2444284652953174071,
+                16710399763346932338,
+                515778771388983576,
+            ]),
+            Fq::from_montgomery_limbs([
+                11662154889180148768,
+                12929608364139089719,
+                8686896760482046339,
+                1230145210936798524,
+            ]),
+            Fq::from_montgomery_limbs([
+                6849876090584787281,
+                2781140689147201308,
+                9512491943302792394,
+                937469995381870265,
+            ]),
+            Fq::from_montgomery_limbs([
+                9747903122269173656,
+                926157508485706195,
+                13130660627605678758,
+                287931682565412467,
+            ]),
+            Fq::from_montgomery_limbs([
+                3828398096599775949,
+                14763388321440019555,
+                10965665820928408894,
+                1231532666700932645,
+            ]),
+            Fq::from_montgomery_limbs([
+                16931320682697115759,
+                15048637674738557659,
+                845724760409887822,
+                572118602833173291,
+            ]),
+            Fq::from_montgomery_limbs([
+                11406501702890293703,
+                8917177274625237888,
+                11705655057661938576,
+                688969510700032124,
+            ]),
+            Fq::from_montgomery_limbs([
+                4959617853745737642,
+                10010565982303406771,
+                14952006949637204874,
+                1257239375060454480,
+            ]),
+            Fq::from_montgomery_limbs([
+                18293686241925096528,
+                15505041210000990458,
+                1555059354156618810,
+                974603348702864957,
+            ]),
+            Fq::from_montgomery_limbs([
+                705880827513782255,
+                12233562047130269862,
+                500188547376920981,
+                37920155209363781,
+            ]),
+            Fq::from_montgomery_limbs([
+                3705329762357930833,
+                14473549517275658481,
+                17765012210336228796,
+                539592903617021087,
+            ]),
+            Fq::from_montgomery_limbs([
+                9160811102626089181,
+                11593365721109279031,
+                11903118409625680886,
+                1199670414850192412,
+            ]),
+            Fq::from_montgomery_limbs([
+                15690170364757488475,
+                12905811089678490326,
+                13453257705664812058,
+                52873918444003179,
+            ]),
+            Fq::from_montgomery_limbs([
+                5477990853955359052,
+                17526407841246853374,
+                6871488703184635204,
+                488028662478457408,
+            ]),
+            Fq::from_montgomery_limbs([
+                16251775867357216391,
+                1500045442471785820,
+                8591151950340399082,
+                809023648528381287,
+            ]),
+            Fq::from_montgomery_limbs([
+                2160834104405851038,
+                9771497685585416601,
+                360498165382250234,
+                51544607293392920,
+            ]),
+        ]),
+    }
+}
@@ -0,0 +1,10285 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-9 instance of Poseidon.
+pub const fn rate_9() -> PoseidonParameters<10, 9, 100, 81, 39, 10, 390, 31> {
+    PoseidonParameters {
+        M: 128,
+        arc: ArcMatrix::<39, 10, 390>::new_from_known([
+            Fq::from_montgomery_limbs([
+                12281176924709909641,
+                6424249400884933270,
+                16417602090625824001,
+                234323863735557382,
+            ]),
+            Fq::from_montgomery_limbs([
+                2287977886932182805,
+                8616110427403804851,
+                7877170773598988169,
+                757243470739612326,
+            ]),
+            Fq::from_montgomery_limbs([
+                4059349993927663522,
+                13814111220690981182,
+                12307061201507458357,
+                62167591881446035,
+            ]),
+            Fq::from_montgomery_limbs([
+                666018570100989600,
+                18072993762380764755,
+                16738103721481031830,
+                656832286912586135,
+            ]),
+            Fq::from_montgomery_limbs([
+                4418989821420376381,
+                17796414275961279928,
+                11198953521760786702,
+                457159332203806008,
+            ]),
+            Fq::from_montgomery_limbs([
+                81260625048574615,
+                7914808201987407181,
+                4948158294286861652,
+                249134449773293270,
+            ]),
+            Fq::from_montgomery_limbs([
+                11602368608394833934,
+                5755586560673210108,
+                6212057355912776694,
+                922856438873310000,
+            ]),
+            Fq::from_montgomery_limbs([
+                17814653184896454720,
+                12550174325120927637,
+                10164557207417699036,
+                1266835034158223961,
+            ]),
+            Fq::from_montgomery_limbs([
+                829109742552018352,
+                15122133246989233113,
+                2568663843065651826,
+                387496886948047096,
+            ]),
+            Fq::from_montgomery_limbs([
+                15002050296182720926,
+                9200751043674577367,
+                6987423913153883233,
+                114193818408746152,
+            ]),
+            Fq::from_montgomery_limbs([
+                7243456654579666321,
+                501022540675516962,
+                3573138200879257244,
+                240290553435205923,
+            ]),
+            Fq::from_montgomery_limbs([
+                7934486043694699707,
+                14863133019215871426,
+                14556028476521229569,
+                858209589091726662,
+            ]),
+            Fq::from_montgomery_limbs([
+                13733217662111373111,
+                4842541414460582375,
+                1375040453142767718,
+                665814505641957281,
+            ]),
+            Fq::from_montgomery_limbs([
+                2998187583700112881,
+                15102166748512677325,
+                13560292381659029811,
+                584410444537183008,
+            ]),
+            Fq::from_montgomery_limbs([
+                9517439486319555247,
+                13638871389942961139,
+                16522780275982957082,
+                900778072398431062,
+            ]),
+            Fq::from_montgomery_limbs([
+                4552459231078500597,
+                6894080431397274841,
+                3110316858644377105,
+                791486926963016322,
+            ]),
+            Fq::from_montgomery_limbs([
+                5607593374026253244,
+                15761043532537496917,
+                1137042188485169146,
+                643006159564234363,
+            ]),
+            Fq::from_montgomery_limbs([
+                7015930589662383805,
+                4557642136049263682,
+                962594582129517425,
+                563349296047607222,
+            ]),
+            Fq::from_montgomery_limbs([
+                16824317191956908965,
+                3330828635599956555,
+                6146610777021208625,
+                86823888941026452,
+            ]),
+            Fq::from_montgomery_limbs([
+                16025175272381128447,
+                18173632225672526565,
+                10436370159331553420,
+                1273103148235706456,
+            ]),
+            Fq::from_montgomery_limbs([
+                867095923713527808,
+                13202048167956820469,
+                2994016504893311201,
+                675242191495982320,
+            ]),
+            Fq::from_montgomery_limbs([
+                15048313635311114741,
+                9931417064887097859,
+                12340641932419288944,
+                681242061225412873,
+            ]),
+            Fq::from_montgomery_limbs([
+                5402186434032964028,
+                13234274208027670010,
+                18380510966962530926,
+                821260563685665271,
+            ]),
+            Fq::from_montgomery_limbs([
+                14877963953078108488,
+                6324637919111504030,
+                89730208329156831,
+                1244789094174821590,
+            ]),
+            Fq::from_montgomery_limbs([
+                5338819167417770307,
+                13267677190765269834,
+                11691074212128667744,
+                269718109075204597,
+            ]),
+            Fq::from_montgomery_limbs([
+                6743496559256925037,
+                1805295689457084589,
+                4519232289568085907,
+                712185148664504828,
+            ]),
+            Fq::from_montgomery_limbs([
+                10787249040234103237,
+                18043256471477289559,
+                11599734279584507040,
+                1002614927160812179,
+            ]),
+            Fq::from_montgomery_limbs([
+                2083086100340287265,
+                10612954369717460107,
+                5923993409348849191,
+                751951379085690198,
+            ]),
+            Fq::from_montgomery_limbs([
+                3710002326172437973,
+                7001296599672823447,
+                8297762681148802346,
+                288684460641881929,
+            ]),
+            Fq::from_montgomery_limbs([
+                2043992827660229294,
+                15257079092859365609,
+                9725666085649959072,
+                671972932151328659,
+            ]),
+            Fq::from_montgomery_limbs([
+                10033412611981252610,
+                11811880234092854741,
+                16033594048822908946,
+                472082281121920404,
+            ]),
+            Fq::from_montgomery_limbs([
+                11516154326633033675,
+                12803604439720956101,
+                18082718834190787478,
+                135374488411404071,
+            ]),
+            Fq::from_montgomery_limbs([
+                209771447195823290,
+                933714282539245476,
+                5029373013638349497,
+                741243393714391518,
+            ]),
+            Fq::from_montgomery_limbs([
+                2036542585013439908,
+                3002580710363723427,
+                7263983037309199966,
+                1318650345065122972,
+            ]),
+            Fq::from_montgomery_limbs([
+                3759692547630496127,
+                5169214329047576768,
+                11816421620919662448,
+                545583012470938177,
+            ]),
+            Fq::from_montgomery_limbs([
+                8664337012098000642,
+                5799771267191841235,
+                8249141371540071971,
+                370345474057616126,
+            ]),
+            Fq::from_montgomery_limbs([
+                13411290199175628629,
+                10038996896644529243,
+                11947263012875073479,
+                1050405225465388504,
+            ]),
+            Fq::from_montgomery_limbs([
+                3013922637634451706,
+                17350372338959868978,
+                17491021125798821054,
+                405969455674430226,
+            ]),
+            Fq::from_montgomery_limbs([
+                2871971279569302460,
+                5902200185045773105,
+                15272071853036571083,
+                941279412367001988,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909589899485380334,
+                2917475496634709669,
+                805576903243861046,
+                12408968652323648,
+            ]),
+            Fq::from_montgomery_limbs([
+                4965718533832256809,
+                1754212836488011074,
+                17929916215787627657,
+                31212560827472606,
+            ]),
+            Fq::from_montgomery_limbs([
+                2761575767060764962,
+                17582598044455960198,
+                2260072759581007944,
+                390204999023225814,
+            ]),
+            Fq::from_montgomery_limbs([
+                6000890753602699186,
+                14764835572759006411,
+                1063907173787507662,
+                613205996146506943,
+            ]),
+            Fq::from_montgomery_limbs([
+                7333145568214920747,
+                1737555306566868306,
+                17941298539928975573,
+                278825415352075571,
+            ]),
+            Fq::from_montgomery_limbs([
+                7432591725640774190,
+                6493996545925996536,
+                1188257416562935007,
+                411306841113646042,
+            ]),
+            Fq::from_montgomery_limbs([
+                2711106038033265105,
+                11118211557749770772,
+                7379893710788908592,
+                878192822989680089,
+            ]),
+            Fq::from_montgomery_limbs([
+                4362484575735942439,
+                13328730784911444975,
+                15668161633378009698,
+                1005459882525844626,
+            ]),
+            Fq::from_montgomery_limbs([
+                4794773082004900222,
+                6385786977413052677,
+                2156766067204696346,
+                760359649138028114,
+            ]),
+            Fq::from_montgomery_limbs([
+                15546765600554116520,
+                14875962316228012648,
+                3918275049756397922,
+                239083336000709562,
+            ]),
+            Fq::from_montgomery_limbs([
+                7434956490598820062,
+                14531880917509348538,
+                18216832203468450900,
+                320038995879416749,
+            ]),
+            Fq::from_montgomery_limbs([
+                14977774753663589550,
+                5503577290380497583,
+                17527142666411582363,
+                591959032983684589,
+            ]),
+            Fq::from_montgomery_limbs([
+                1170728656204645779,
+                14862523769491067120,
+                3434891977092312767,
+                949735811583834873,
+            ]),
+            Fq::from_montgomery_limbs([
+                14053359736762356977,
+                1156047123158134558,
+                10885691800990006794,
+                157271261566966199,
+            ]),
+            Fq::from_montgomery_limbs([
+                9281177629263119619,
+                6797393736576243215,
+                2637550208062889933,
+                682245920522203567,
+            ]),
+            Fq::from_montgomery_limbs([
+                17215958106464329568,
+                6103845207304009572,
+                10574396339495835930,
+                442578842759132420,
+            ]),
+            Fq::from_montgomery_limbs([
+                14640944236413235006,
+                11269340209128161061,
+                10281597813735091563,
+                1054687371613932331,
+            ]),
+            Fq::from_montgomery_limbs([
+                414835181819145196,
+                13882287878849157992,
+                14523819844805801112,
+                169561125227328963,
+            ]),
+            Fq::from_montgomery_limbs([
+                2049713794955368287,
+                5970886798243263924,
+                17191353989337807117,
+                827120373677458497,
+            ]),
+            Fq::from_montgomery_limbs([
+                9697552181845312639,
+                12107933604586556471,
+                11510527868274350912,
+                568034022086212933,
+            ]),
+            Fq::from_montgomery_limbs([
+                116715547538720327,
+                9466933037422463607,
+                6181725135471831867,
+                828970631459151413,
+            ]),
+            Fq::from_montgomery_limbs([
+                17914878555332800228,
+                16777894093621431987,
+                5104620355217332082,
+                889880469006026558,
+            ]),
+            Fq::from_montgomery_limbs([
+                1453472239034227060,
+                15922167554793863631,
+                17077228879475494412,
+                1122459514150845270,
+            ]),
+            Fq::from_montgomery_limbs([
+                6870933578598812878,
+                14112278218028834371,
+                2079743554196503580,
+                405615265207036657,
+            ]),
+            Fq::from_montgomery_limbs([
+                897889131427059928,
+                2495844136673244722,
+                2643811698566657878,
+                200465620760783811,
+            ]),
+            Fq::from_montgomery_limbs([
+                5638545391898282259,
+                10935426399162266874,
+                17678536805640918636,
+                386317439758844154,
+            ]),
+            Fq::from_montgomery_limbs([
+                15831978271556260534,
+                15097708244250846646,
+                17118347000661721738,
+                1005838334386631120,
+            ]),
+            Fq::from_montgomery_limbs([
+                17463828397110405839,
+                10322348021788092728,
+                7373493969546786082,
+                709531307622511427,
+            ]),
+            Fq::from_montgomery_limbs([
+                1754629458363223060,
+                5086841130420478806,
+                6663547795775159404,
+                799512686609661619,
+            ]),
+            Fq::from_montgomery_limbs([
+                2643428232571100361,
+                4500110336372230836,
+                2678965924814938019,
+                1148280852387385662,
+            ]),
+            Fq::from_montgomery_limbs([
+                3268702911850063918,
+                3479196187024790361,
+                17448882948840575007,
+                296610675463021192,
+            ]),
+            Fq::from_montgomery_limbs([
+                1495238640349483889,
+                504290373292679943,
+                15006519596352360450,
+                635522534005452514,
+            ]),
+            Fq::from_montgomery_limbs([
+                15824388406184047678,
+                599122231065586780,
+                17486996505639080682,
+                70808945350019942,
+            ]),
+            Fq::from_montgomery_limbs([
+                11729927411091018748,
+                27552606393414569,
+                14360877879707831598,
+                301276183204924534,
+            ]),
+            Fq::from_montgomery_limbs([
+                360925921503312239,
+                6141604803001633641,
+                4591764765572443494,
+                120850511806775857,
+            ]),
+            Fq::from_montgomery_limbs([
+                813579043764521137,
+                4953399583923345717,
+                6671485351869768560,
+                1197240030566139229,
+            ]),
+            Fq::from_montgomery_limbs([
+                4579063662029851516,
+                13090187046460747110,
+                14677840736968831921,
+                1330292297326114497,
+            ]),
+            Fq::from_montgomery_limbs([
+                1856164455207550389,
+                15520240614451979722,
+                2904894101546202788,
+                1215654072207446990,
+            ]),
+            Fq::from_montgomery_limbs([
+                11318828578945954002,
+                13409644070278434627,
+                12454031311444507068,
+                1039330181049407451,
+            ]),
+            Fq::from_montgomery_limbs([
+                6792450182092886028,
+                7933890824410028465,
+                18201655551076083742,
+                1300172004485680708,
+            ]),
+            Fq::from_montgomery_limbs([
+                15773304014970844406,
+                14792292892401737085,
+                14537393626942605221,
+                929600297315495974,
+            ]),
+            Fq::from_montgomery_limbs([
+                6737916636017383383,
+                929296935099576368,
+                12190335483901360747,
+                544966617516850462,
+            ]),
+            Fq::from_montgomery_limbs([
+                1257113229122215738,
+                405083767969969501,
+                13630446527512445657,
+                454829842364547826,
+            ]),
+            Fq::from_montgomery_limbs([
+                12112605754930995919,
+                12652299872554098861,
+                14787133473400254568,
+                995849712089858785,
+            ]),
+            Fq::from_montgomery_limbs([
+                147901270899974326,
+                10772757202294877275,
+                10019249856565975860,
+                834724486550966293,
+            ]),
+            Fq::from_montgomery_limbs([
+                12091572865511381210,
+                13045020365988088985,
+                17471191148283615424,
+                979398461080539505,
+            ]),
+            Fq::from_montgomery_limbs([
+                7668893165937229477,
+                1484444927451882759,
+                14433762922926127756,
+                392692412182838366,
+            ]),
+            Fq::from_montgomery_limbs([
+                5432679767348999711,
+                1550767359851453673,
+                742626838703372777,
+                598311483442397843,
+            ]),
+            Fq::from_montgomery_limbs([
+                17798203328368829269,
+                14386385685475393374,
+                6425139829510510103,
+                831037308110194051,
+            ]),
+            Fq::from_montgomery_limbs([
+                2257913548160933161,
+                4003908526921836088,
+                14689177924173593776,
+                1200776765800372539,
+            ]),
+            Fq::from_montgomery_limbs([
+                13618708214165806874,
+                6282405889745294237,
+                6960417241107145669,
+                1195624386806597519,
+            ]),
+            Fq::from_montgomery_limbs([
+                8872949787816685341,
+                14703762659253543623,
+                17819677134392961464,
+                1007800828715988295,
+            ]),
+            Fq::from_montgomery_limbs([
+                7820544586126045095,
+                15504527020801317067,
+                3926309980377216432,
+                171027262573790514,
+            ]),
+            Fq::from_montgomery_limbs([
+                12006357144065149206,
+                16756660672680298429,
+                5212899370764119491,
+                935770055338586835,
+            ]),
+            Fq::from_montgomery_limbs([
+                12282949813330932131,
+                16347534616566963317,
+                4571175086719002563,
+                412347087720386280,
+            ]),
+            Fq::from_montgomery_limbs([
+                7348621477364278345,
+                7314523110859278015,
+                3116069988395707108,
+                210406421890913091,
+            ]),
+            Fq::from_montgomery_limbs([
+                8924444012762278588,
+                17413284485793952732,
+                9641634248495589494,
+                584843524848111125,
+            ]),
+            Fq::from_montgomery_limbs([
+                16553998276848173773,
+                2810016573380774794,
+                2548835717351282392,
+                805754091799735784,
+            ]),
+            Fq::from_montgomery_limbs([
+                4874301542854818001,
+                2866453397480579621,
+                2776032655618510474,
+                1311374675779451831,
+            ]),
+            Fq::from_montgomery_limbs([
+                3575738541534014291,
+                993740635029249569,
+                9839842723392237072,
+                390204065665320788,
+            ]),
+            Fq::from_montgomery_limbs([
+                11663903543031595853,
+                8628433655226662510,
+                6651686162539968752,
+                302215041111066496,
+            ]),
+            Fq::from_montgomery_limbs([
+                1439342069275702241,
+                16834888699931027480,
+                8716141793541764659,
+                173476136110606159,
+            ]),
+            Fq::from_montgomery_limbs([
+                3355127138243171855,
+                5008603361170616540,
+                257391918807150034,
+                456073441712926258,
+            ]),
+            Fq::from_montgomery_limbs([
+                5211491748004972654,
+                10113055228011440112,
+                12832086310224639131,
+                827583451584678810,
+            ]),
+            Fq::from_montgomery_limbs([
+                5225741846991513735,
+                9143721343634781113,
+                6656100496209742664,
+                977084871045115189,
+            ]),
+            Fq::from_montgomery_limbs([
+                12217934071296215415,
+                4936465304176039768,
+                8902173610275683983,
+                589194588895933519,
+            ]),
+            Fq::from_montgomery_limbs([
+                5351672468857879115,
+                10988475084821835319,
+                12231203789214630514,
+                126377257450895011,
+            ]),
+            Fq::from_montgomery_limbs([
+                3412602201403183741,
+                8565345518542494774,
+                17283271939044382619,
+                573414813589194831,
+            ]),
+            Fq::from_montgomery_limbs([
+                12210561640256270455,
+                13101904792554312512,
+                134745096952465252,
+                1314171222210753499,
+            ]),
+            Fq::from_montgomery_limbs([
+                3928030537440656509,
+                10876856907995441433,
+                16560113395551795165,
+                231789097824255190,
+            ]),
+            Fq::from_montgomery_limbs([
+                17894472382483826699,
+                17370857233496060199,
+                12400295981331242254,
+                512740790433458253,
+            ]),
+            Fq::from_montgomery_limbs([
+                10626222870168372385,
+                15959267331843658107,
+                4353620314660423831,
+                1006418811421590084,
+            ]),
+            Fq::from_montgomery_limbs([
+                3684520296482174647,
+                1420022664028689751,
+                13692805475047412122,
+                1328763188985510757,
+            ]),
+            Fq::from_montgomery_limbs([
+                11079847640718330116,
+                7529617009781880920,
+                15242757220964575660,
+                566772843168826128,
+            ]),
+            Fq::from_montgomery_limbs([
+                6314430979916941155,
+                8847731080066745294,
+                3364400620850564660,
+                978522333884718955,
+            ]),
+            Fq::from_montgomery_limbs([
+                15418850366207854337,
+                15012784491098143604,
+                14532855024228172327,
+                321263439271529057,
+            ]),
+            Fq::from_montgomery_limbs([
+                13455873039878866722,
+                15557771980589707957,
+                12508802056719628778,
+                1233101067540751374,
+            ]),
+            Fq::from_montgomery_limbs([
+                1363777136428471363,
+                4139383200583556453,
+                106447304529990519,
+                401173113006021074,
+            ]),
+            Fq::from_montgomery_limbs([
+                13456670357788972836,
+                11859383559138934992,
+                17976170894445418686,
+                819979175885871256,
+            ]),
+            Fq::from_montgomery_limbs([
+                5016045844812080618,
+                10651450782508165238,
+                9910363932327151801,
+                68001170886632229,
+            ]),
+            Fq::from_montgomery_limbs([
+                11697711322192053558,
+                17034501709915223802,
+                11640364980035451142,
+                1245895564461431557,
+            ]),
+            Fq::from_montgomery_limbs([
+                15878604403993596575,
+                5113312129835523380,
+                5551883126661262505,
+                464365690853909757,
+            ]),
+            Fq::from_montgomery_limbs([
+                15781152033075769266,
+                12531594182187417023,
+                4159092358841813853,
+                640859820241854813,
+            ]),
+            Fq::from_montgomery_limbs([
+                11012734791683201004,
+                10309310447657753400,
+                15769908986284357044,
+                606510042369212220,
+            ]),
+            Fq::from_montgomery_limbs([
+                12258466451423357351,
+                15357851454650836556,
+                2074761355019936088,
+                770884552872238503,
+            ]),
+            Fq::from_montgomery_limbs([
+                15756832999976300728,
+                11395952224036382569,
+                12158708915206101140,
+                396015750430983417,
+            ]),
+            Fq::from_montgomery_limbs([
+                13565012845473507431,
+                10286116593780102000,
+                9669513275286195771,
+                627075283533547482,
+            ]),
+            Fq::from_montgomery_limbs([
+                9974333652323390191,
+                8740929943512414581,
+                4221343922531212367,
+                451683948508793850,
+            ]),
+            Fq::from_montgomery_limbs([
+                1396349748102770844,
+                5512181760046820023,
+                13692739358824488162,
+                1117474712012834870,
+            ]),
+            Fq::from_montgomery_limbs([
+                4177485707502599343,
+                11301860057327455156,
+                583398831755024033,
+                668919437042450590,
+            ]),
+            Fq::from_montgomery_limbs([
+                1118293185469960271,
+                14642308999110819425,
+                15740927063262817134,
+                645797330895056695,
+            ]),
+            Fq::from_montgomery_limbs([
+                5503073922626537522,
+                9643002656128033448,
+                4900033017156668475,
+                483377669853815688,
+            ]),
+            Fq::from_montgomery_limbs([
+                7052271035039508483,
+                15763051324856238402,
+                17684950635006322456,
+                701796016762462051,
+            ]),
+            Fq::from_montgomery_limbs([
+                13941784935441821568,
+                2714486913355719522,
+                13579811741938749366,
+                1018381343116630851,
+            ]),
+            Fq::from_montgomery_limbs([
+                16520639872322411985,
+                11442995549060996475,
+                5559002705439810873,
+                646900497356104749,
+            ]),
+            Fq::from_montgomery_limbs([
+                2469420754982499815,
+                1199337948927244074,
+                5786425156634937353,
+                159762100446233572,
+            ]),
+            Fq::from_montgomery_limbs([
+                10114200853988937142,
+                1229038736108167400,
+                6824934810040473770,
+                408098035545623421,
+            ]),
+            Fq::from_montgomery_limbs([
+                6207877445747398437,
+                14445241001285855845,
+                14588611598472952430,
+                1157240897446119141,
+            ]),
+            Fq::from_montgomery_limbs([
+                2285228623078757495,
+                5157315247217448889,
+                16041239476350713014,
+                253216586376768114,
+            ]),
+            Fq::from_montgomery_limbs([
+                4161198681133278164,
+                2335079095212264703,
+                10795345779278652160,
+                940512854713242282,
+            ]),
+            Fq::from_montgomery_limbs([
+                17428395965830068990,
+                6016967388136860473,
+                2016996415187452364,
+                961755791994752287,
+            ]),
+            Fq::from_montgomery_limbs([
+                17113233730444926771,
+                15956906132951768169,
+                18199512135347922354,
+                897641026524828448,
+            ]),
+            Fq::from_montgomery_limbs([
+                8053882171639307181,
+                1883624716716986535,
+                15505061436920593862,
+                982582568414160666,
+            ]),
+            Fq::from_montgomery_limbs([
+                11229766958792256439,
+                3820884775436950517,
+                18206629519428679252,
+                757812222086879583,
+            ]),
+            Fq::from_montgomery_limbs([
+                9946035948746221890,
+                11730118025395296614,
+                16464508640933415619,
+                299296782590971565,
+            ]),
+            Fq::from_montgomery_limbs([
+                3624919667285038541,
+                7092919701138616504,
+                7599264481394256864,
+                574711233405861218,
+            ]),
+            Fq::from_montgomery_limbs([
+                7153700327685161006,
+                8748061243172277631,
+                13187495741239663575,
+                1339660018657175511,
+            ]),
+            Fq::from_montgomery_limbs([
+                15351510491388883455,
+                4394449898029882513,
+                9919393555545986928,
+                426967607057148421,
+            ]),
+            Fq::from_montgomery_limbs([
+                18196703092419422776,
+                16767629091754165849,
+                10069397138713191112,
+                660890836785816338,
+            ]),
+            Fq::from_montgomery_limbs([
+                15665159608650112959,
+                668878110933580138,
+                1007660682101762455,
+                1173512832713002948,
+            ]),
+            Fq::from_montgomery_limbs([
+                7946916544040180902,
+                14655350370208945527,
+                636931963582476005,
+                626851240170709980,
+            ]),
+            Fq::from_montgomery_limbs([
+                17376591815979335206,
+                16985293965645465459,
+                6852040149907156248,
+                281183352791651049,
+            ]),
+            Fq::from_montgomery_limbs([
+                9527906313749465143,
+                861681526478516218,
+                12411791146690535091,
+                23086956958532113,
+            ]),
+            Fq::from_montgomery_limbs([
+                12008929353636116791,
+                2246241667065332772,
+                11379868016142928710,
+                689136581418943669,
+            ]),
+            Fq::from_montgomery_limbs([
+                17218327222982483674,
+                8944136421431650901,
+                5243198888459114853,
+                519300292002482693,
+            ]),
+            Fq::from_montgomery_limbs([
+                6201186176561339121,
+                9693692840717312572,
+                1098278597967806884,
+                1110912937852191367,
+            ]),
+            Fq::from_montgomery_limbs([
+                15014665683539239947,
+                5750719359089251246,
+                3326557069542598568,
+                860970926866117584,
+            ]),
+            Fq::from_montgomery_limbs([
+                1583771737609351412,
+                13879180866815020096,
+                18067938988303306190,
+                1266259272824817959,
+            ]),
+            Fq::from_montgomery_limbs([
+                2231074815476788661,
+                16633208640269160280,
+                10535562724641171012,
+                1154753194309002386,
+            ]),
+            Fq::from_montgomery_limbs([
+                11080779205597129470,
+                10241624827021879928,
+                8704387963725873602,
+                916627060540985643,
+            ]),
+            Fq::from_montgomery_limbs([
+                13426287714007069862,
+                17444605792420207500,
+                12096129187258796411,
+                461222658536125448,
+            ]),
+            Fq::from_montgomery_limbs([
+                14716270866155818040,
+                1631315399203401828,
+                4253650535883320765,
+                729355508024356996,
+            ]),
+            Fq::from_montgomery_limbs([
+                9936768041136140865,
+                5834199141514897436,
+                11807989639684859651,
+                1134331538855668,
+            ]),
+            Fq::from_montgomery_limbs([
+                8163094073319166254,
+                14094024749412490181,
+                5683981942129060981,
+                1002137804172587269,
+            ]),
+            Fq::from_montgomery_limbs([
+                13843422906427281222,
+                15117133540542337599,
+                10404386819206817484,
+                25011490965142104,
+            ]),
+            Fq::from_montgomery_limbs([
+                1422047721127959097,
+                16766266410949183559,
+                12996896204230112330,
+                1044133690143596612,
+            ]),
+            Fq::from_montgomery_limbs([
+                6449816540790052488,
+                13094710613216765626,
+                7900035258607541610,
+                90887640804549083,
+            ]),
+            Fq::from_montgomery_limbs([
+                9005845569386682354,
+                11100960861565775422,
+                16270557090265419626,
+                696463688780075950,
+            ]),
+            Fq::from_montgomery_limbs([
+                9735985085531929398,
+                11135769622872513666,
+                11052475865604592329,
+                441929876679604862,
+            ]),
+            Fq::from_montgomery_limbs([
+                2032561928722668936,
+                10250644868761835743,
+                13535934258502364437,
+                7067639408146139,
+            ]),
+            Fq::from_montgomery_limbs([
+                8765612357127397753,
+                9089425764374196244,
+                14288747411249612703,
+                1187330583152358214,
+            ]),
+            Fq::from_montgomery_limbs([
+                11171067439995035004,
+                14640967257804856594,
+                5969994573262634875,
+                460357531297455871,
+            ]),
+            Fq::from_montgomery_limbs([
+                16335055851183300991,
+                12731216029350043947,
+                14483719221742392563,
+                904011059426315773,
+            ]),
+            Fq::from_montgomery_limbs([
+                11480331133394697014,
+                6460707748587352008,
+                10130929308164759744,
+                258327102615294320,
+            ]),
+            Fq::from_montgomery_limbs([
+                9213891122011495499,
+                7988518384686001081,
+                5629344677888655603,
+                17223941634397151,
+            ]),
+            Fq::from_montgomery_limbs([
+                8959821546646684749,
+                7010469067282268555,
+                14958436879493008757,
+                284273442461783071,
+            ]),
+            Fq::from_montgomery_limbs([
+                2105256752854249613,
+                6932860569229114593,
+                3062688340404665962,
+                852368872569312485,
+            ]),
+            Fq::from_montgomery_limbs([
+                4913168551972169193,
+                793525372137445586,
+                3293975810047127102,
+                1266323614430122996,
+            ]),
+            Fq::from_montgomery_limbs([
+                6104841506187972777,
+                10321813393907197488,
+                6104892590174641892,
+                1302473123060281275,
+            ]),
+            Fq::from_montgomery_limbs([
+                14121259658466769381,
+                9909775360665635747,
+                11798935635355407360,
+                104283536935407449,
+            ]),
+            Fq::from_montgomery_limbs([
+                15678651960453201982,
+                12952367012496598642,
+                12646935497463887643,
+                9519131291779161,
+            ]),
+            Fq::from_montgomery_limbs([
+                8448090077276896739,
+                3603967011945029170,
+                9249937046135951494,
+                288544523227886683,
+            ]),
+            Fq::from_montgomery_limbs([
+                10082560025633930811,
+                2341672569347926819,
+                4187206116435530899,
+                50148482793401438,
+            ]),
+            Fq::from_montgomery_limbs([
+                17192862900455218401,
+                12954128262292151438,
+                8802388196110169643,
+                1245730015822812771,
+            ]),
+            Fq::from_montgomery_limbs([
+                5665688929756493109,
+                18335699273724637387,
+                1799911130570706349,
+                738262562874956403,
+            ]),
+            Fq::from_montgomery_limbs([
+                4182830533101985036,
+                11157357771712235468,
+                15714206542300790906,
+                542179997490572777,
+            ]),
+            Fq::from_montgomery_limbs([
+                5435994516032873243,
+                12052623447366585588,
+                6669100303518315390,
+                1226932062566262246,
+            ]),
+            Fq::from_montgomery_limbs([
+                5736313850571777152,
+                13564381500829477693,
+                10595714517321499848,
+                434237013546874690,
+            ]),
+            Fq::from_montgomery_limbs([
+                13428982348188847297,
+                6552507464255109625,
+                17159058473687148784,
+                339042541001083653,
+            ]),
+            Fq::from_montgomery_limbs([
+                17410964483634144656,
+                12793504615793975641,
+                10460869852241627634,
+                232524423297358504,
+            ]),
+            Fq::from_montgomery_limbs([
+                9528308774263202456,
+                1037169288127765407,
+                9918829838842543538,
+                234611031278712886,
+            ]),
+            Fq::from_montgomery_limbs([
+                15466026887082456161,
+                15587963649296639917,
+                1875771363543599014,
+                255606779791530204,
+            ]),
+            Fq::from_montgomery_limbs([
+                6120476954319091789,
+                18317829642171928776,
+                125033043625228603,
+                226795408620701502,
+            ]),
+            Fq::from_montgomery_limbs([
+                3774209822229947088,
+                824059715439716060,
+                7507454344673756072,
+                974350776404486388,
+            ]),
+            Fq::from_montgomery_limbs([
+                2053485447294460620,
+                16358046412569612635,
+                7654182001916439798,
+                392004434789922159,
+            ]),
+            Fq::from_montgomery_limbs([
+                6043676674823046715,
+                9224004092697961175,
+                6315732683796660830,
+                977314892887542741,
+            ]),
+            Fq::from_montgomery_limbs([
+                12202838493643103647,
+                14676412792501515892,
+                17433843769830361144,
+                1103750029511898346,
+            ]),
+            Fq::from_montgomery_limbs([
+                6396496402437775041,
+                15052773144860643996,
+                8607958089080083962,
+                689408332536914427,
+            ]),
+            Fq::from_montgomery_limbs([
+                949307279584944895,
+                11529169593381625142,
+                2811754979208538365,
+                655432096476124157,
+            ]),
+            Fq::from_montgomery_limbs([
+                15524593410373820270,
+                13800296165857883798,
+                15383699582970622973,
+                4020240540849452,
+            ]),
+            Fq::from_montgomery_limbs([
+                14378287561983356735,
+                14210670081238230108,
+                1365055332525286748,
+                1304711327463130182,
+            ]),
+            Fq::from_montgomery_limbs([
+                9116818654297179441,
+                10263837259494772879,
+                14912922364052316458,
+                686046663890418174,
+            ]),
+            Fq::from_montgomery_limbs([
+                1034423425575347924,
+                10584139408820717460,
+                14563469801916666709,
+                1337315191640628910,
+            ]),
+            Fq::from_montgomery_limbs([
+                17905911898441058907,
+                7045038983963475476,
+                2804123352675481065,
+                1052655207106106424,
+            ]),
+            Fq::from_montgomery_limbs([
+                17346978427020981687,
+                4691357631030528081,
+                11281219203238406012,
+                1292198798984967273,
+            ]),
+            Fq::from_montgomery_limbs([
+                553013496878410269,
+                11075356359981402443,
+                5474294579333803070,
+                704430422119237392,
+            ]),
+            Fq::from_montgomery_limbs([
+                15601884759128764076,
+                13049284466220912624,
+                9233096398287489239,
+                1328621697122066064,
+            ]),
+            Fq::from_montgomery_limbs([
+                8143727216347324529,
+                7498337408079625014,
+                16480408634251122009,
+                676618355368344757,
+            ]),
+            Fq::from_montgomery_limbs([
+                2398364774793098649,
+                16636812884870471277,
+                812846581072993145,
+                183653775444903350,
+            ]),
+            Fq::from_montgomery_limbs([
+                10395820969764488466,
+                14165271256168121649,
+                1293776738811582087,
+                1220818586163232765,
+            ]),
+            Fq::from_montgomery_limbs([
+                8427758048439131575,
+                17857524114384863185,
+                15059389719636335348,
+                384856687080609486,
+            ]),
+            Fq::from_montgomery_limbs([
+                12494529899833612165,
+                2037510275470764013,
+                14969647797218471400,
+                474024545902253130,
+            ]),
+            Fq::from_montgomery_limbs([
+                2391409966957431575,
+                6697860759701775486,
+                13173081833578300725,
+                340554861789984476,
+            ]),
+            Fq::from_montgomery_limbs([
+                10575551750488719,
+                9547307826208832386,
+                2738774812964777044,
+                729616835280168438,
+            ]),
+            Fq::from_montgomery_limbs([
+                11821958276156608877,
+                11605626404013671587,
+                12204289654231265213,
+                1167940039751545028,
+            ]),
+            Fq::from_montgomery_limbs([
+                8930210162877229212,
+                2514500561434772666,
+                10795977637410945589,
+                764078796316063796,
+            ]),
+            Fq::from_montgomery_limbs([
+                12628507617478626671,
+                15228660151825133264,
+                14454772208201620696,
+                303205864229263542,
+            ]),
+            Fq::from_montgomery_limbs([
+                10599213986160593873,
+                10348404143807429550,
+                3726346376326582452,
+                1331954295488685811,
+            ]),
+            Fq::from_montgomery_limbs([
+                10884254451630152996,
+                10336644774379288043,
+                7807087958156515550,
+                747222702188473813,
+            ]),
+            Fq::from_montgomery_limbs([
+                6122917144147676899,
+                10068351357269541329,
+                3159045966605360161,
+                110621099283505708,
+            ]),
+            Fq::from_montgomery_limbs([
+                1431555354523269885,
+                10871216727488550770,
+                12119294346060507488,
+                145129808231380197,
+            ]),
+            Fq::from_montgomery_limbs([
+                3147319964289878381,
+                15400150257529547624,
+                7167990727356435466,
+                197951156035992202,
+            ]),
+            Fq::from_montgomery_limbs([
+                13121823617948390669,
+                3627406753194871774,
+                16232276324961694122,
+                1163466546735831390,
+            ]),
+            Fq::from_montgomery_limbs([
+                7757573074676813940,
+                4728182560873190994,
+                2552664761627227523,
+                31050212127415892,
+            ]),
+            Fq::from_montgomery_limbs([
+                4596877394654435044,
+                17075002337632777493,
+                5777692936138418720,
+                715154209674446495,
+            ]),
+            Fq::from_montgomery_limbs([
+                4760381603538562846,
+                5409810238490083358,
+                11881917783844264451,
+                770319167735276531,
+            ]),
+            Fq::from_montgomery_limbs([
+                3547684986154559167,
+                14694723716653567054,
+                12995696098362392750,
+                558201011138774913,
+            ]),
+            Fq::from_montgomery_limbs([
+                17506297522518190228,
+                18027926028077443885,
+                251647785644092848,
+                649441296300953865,
+            ]),
+            Fq::from_montgomery_limbs([
+                10892679085500347559,
+                4032299896842538474,
+                935153689645671708,
+                372096411868987204,
+            ]),
+            Fq::from_montgomery_limbs([
+                4141210460010046926,
+                4735515190766888669,
+                17896385345303672541,
+                910846265469291939,
+            ]),
+            Fq::from_montgomery_limbs([
+                5740724422363660164,
+                3883751887514471177,
+                15672801191927897378,
+                762019379633448035,
+            ]),
+            Fq::from_montgomery_limbs([
+                1561916407228540491,
+                13415321671871891625,
+                17889818363267638994,
+                1068939056400554336,
+            ]),
+            Fq::from_montgomery_limbs([
+                4334269330317822586,
+                4299629234613645505,
+                15174446315096593916,
+                1017038936063652118,
+            ]),
+            Fq::from_montgomery_limbs([
+                15032264764501168245,
+                14396302262320048665,
+                6704366933034534802,
+                435907899970212397,
+            ]),
+            Fq::from_montgomery_limbs([
+                11520631604966111399,
+                17888143188193718464,
+                14022473650782066237,
+                870907218305143846,
+            ]),
+            Fq::from_montgomery_limbs([
+                12577784166923449403,
+                2105724198789447163,
+                5984702193657242689,
+                739114474468005039,
+            ]),
+            Fq::from_montgomery_limbs([
+                849437732813607045,
+                11467526954219382979,
+                17769321710190078819,
+                290785831635790292,
+            ]),
+            Fq::from_montgomery_limbs([
+                11111967853349408716,
+                16718175281384468558,
+                14225876538018862197,
+                53397694420184178,
+            ]),
+            Fq::from_montgomery_limbs([
+                1674095203239687522,
+                15766932025287643495,
+                4973547395045396099,
+                800678326134029967,
+            ]),
+            Fq::from_montgomery_limbs([
+                17802084847522293928,
+                9509022443603996228,
+                8414768780307731829,
+                8106293882713690,
+            ]),
+            Fq::from_montgomery_limbs([
+                9697297314334290421,
+                9795193404405411445,
+                1590354406587529054,
+                238128357787341557,
+            ]),
+            Fq::from_montgomery_limbs([
+                3044342968372620112,
+                12712978332444742592,
+                5874079202521472446,
+                440519162054293091,
+            ]),
+            Fq::from_montgomery_limbs([
+                8463625494492002766,
+                10472652621251318081,
+                14285237463671573811,
+                1129340620509315404,
+            ]),
+            Fq::from_montgomery_limbs([
+                17709825792390003583,
+                10689432867914760232,
+                3134589148821731608,
+                657434939129487964,
+            ]),
+            Fq::from_montgomery_limbs([
+                17897854356145812397,
+                884959927874031176,
+                16986206336705502643,
+                434286735441788873,
+            ]),
+            Fq::from_montgomery_limbs([
+                14227350370017084387,
+                12689144876344698806,
+                9929270984934293557,
+                1145360719477119527,
+            ]),
+            Fq::from_montgomery_limbs([
+                15074793902375389237,
+                16029305421630195890,
+                4224703150153815357,
+                811706588694563155,
+            ]),
+            Fq::from_montgomery_limbs([
+                14512371608458782908,
+                690070156529038517,
+                210050894520981561,
+                387080514227742633,
+            ]),
+            Fq::from_montgomery_limbs([
+                14244853170454626858,
+                2292560124597248210,
+                14378865208103333035,
+                227202092807026159,
+            ]),
+            Fq::from_montgomery_limbs([
+                7343201682727829331,
+                17483856029799941824,
+                12606895015461659719,
+                833190536899034276,
+            ]),
+            Fq::from_montgomery_limbs([
+                7155384175485800479,
+                8938159901480293228,
+                14817512109901953603,
+                902079978723759496,
+            ]),
+            Fq::from_montgomery_limbs([
+                9461839513434910293,
+                9152635521798415581,
+                14914637481129911522,
+                28831879722058819,
+            ]),
+            Fq::from_montgomery_limbs([
+                18114032757492315942,
+                15187186672852639566,
+                16956428703417229237,
+                1261259079500627115,
+            ]),
+            Fq::from_montgomery_limbs([
+                3905789945818876329,
+                13430556248319339613,
+                14716680052270236128,
+                1315308224067559905,
+            ]),
+            Fq::from_montgomery_limbs([
+                378352419968141800,
+                17747188815561388865,
+                17782841932508943933,
+                420791777450634309,
+            ]),
+            Fq::from_montgomery_limbs([
+                6763662245021679870,
+                10139744808141798387,
+                9438179359598790129,
+                16895059059633134,
+            ]),
+            Fq::from_montgomery_limbs([
+                2688559320753298740,
+                16857494806571047290,
+                5905252525121310957,
+                121254313893190389,
+            ]),
+            Fq::from_montgomery_limbs([
+                15663407528509479513,
+                3843468942620458193,
+                5986483695162838381,
+                40159681788991176,
+            ]),
+            Fq::from_montgomery_limbs([
+                16663076805177589080,
+                9574875550762391527,
+                1839358570260179279,
+                1200485238205822409,
+            ]),
+            Fq::from_montgomery_limbs([
+                1661644954706403607,
+                4651521526511398618,
+                10588946684548427023,
+                1254444785536846182,
+            ]),
+            Fq::from_montgomery_limbs([
+                11945311246863817536,
+                4272557843398397364,
+                14272915735658198623,
+                562389554890099628,
+            ]),
+            Fq::from_montgomery_limbs([
+                3974911168905264191,
+                14705901515113781883,
+                1341640226326989150,
+                694750269715478108,
+            ]),
+            Fq::from_montgomery_limbs([
+                13322202438074487961,
+                9299683347869815476,
+                16299848273431571688,
+                875691220470300875,
+            ]),
+            Fq::from_montgomery_limbs([
+                18212153132828796002,
+                17005879879215710755,
+                9180156865881351756,
+                1274420452436230938,
+            ]),
+            Fq::from_montgomery_limbs([
+                17942709561158396698,
+                1225742763603176425,
+                1408766795311935399,
+                634803615070159948,
+            ]),
+            Fq::from_montgomery_limbs([
+                11877167305409565383,
+                1413077095746103771,
+                7468939402633971282,
+                177129723508703358,
+            ]),
+            Fq::from_montgomery_limbs([
+                8507927645886798389,
+                14348804926434902919,
+                17060405493473758732,
+                1085103842405664023,
+            ]),
+            Fq::from_montgomery_limbs([
+                17130439340081115597,
+                4396792762464011395,
+                8907789049018102631,
+                749374870436577340,
+            ]),
+            Fq::from_montgomery_limbs([
+                13776561607470428182,
+                10777077377252531157,
+                2166139732208325886,
+                1073643297593246494,
+            ]),
+            Fq::from_montgomery_limbs([
+                8414648304950632911,
+                7058212902746503578,
+                13997465298095133540,
+                1092791403827568296,
+            ]),
+            Fq::from_montgomery_limbs([
+                14982831853406827175,
+                8943591480421706062,
+                4887036246797207460,
+                670571995181893054,
+            ]),
+            Fq::from_montgomery_limbs([
+                3107798337958578651,
+                5909546307368690609,
+                12952605218555260879,
+                395923079112002316,
+            ]),
+            Fq::from_montgomery_limbs([
+                6368151860885463604,
+                1698526425289815236,
+                10887596104543683729,
+                225787424002662573,
+            ]),
+            Fq::from_montgomery_limbs([
+                14153826868358689695,
+                16785517563415889413,
+                9232096614224686307,
+                268179923717921300,
+            ]),
+            Fq::from_montgomery_limbs([
+                17494787372734446838,
+                5694281830835093376,
+                5564645813041240240,
+                1340101183610627274,
+            ]),
+            Fq::from_montgomery_limbs([
+                2818445946540570597,
+                1075839329105055695,
+                10711766344930587753,
+                830761688761626466,
+            ]),
+            Fq::from_montgomery_limbs([
+                2823231202099181920,
+                2415666330169812381,
+                3371571720196930585,
+                490485578298224981,
+            ]),
+            Fq::from_montgomery_limbs([
+                15096667259996478762,
+                14021128922626880558,
+                11390557422659666448,
+                1196768985565740175,
+            ]),
+            Fq::from_montgomery_limbs([
+                14548110619011052814,
+                4969623112111799184,
+                7527081018554298199,
+                781082195751194526,
+            ]),
+            Fq::from_montgomery_limbs([
+                10567680747548446269,
+                9580995815127630182,
+                17153600904188563758,
+                1330917306154275154,
+            ]),
+            Fq::from_montgomery_limbs([
+                3713256048533572740,
+                620607592585110619,
+                577136160931306026,
+                752906935047315889,
+            ]),
+            Fq::from_montgomery_limbs([
+                4258575602740113247,
+                10966103619891299951,
+                15098720982527329591,
+                1165740611065666315,
+            ]),
+            Fq::from_montgomery_limbs([
+                8581003967175595561,
+                1718645009009114303,
+                15724135656422848697,
+                361931671800962221,
+            ]),
+            Fq::from_montgomery_limbs([
+                14069469341656972513,
+                10755465169305292953,
+                2021962160286501649,
+                592946973428097630,
+            ]),
+            Fq::from_montgomery_limbs([
+                13019850811351794609,
+                7250947847924036624,
+                11341915745935998834,
+                790061843453606362,
+            ]),
+            Fq::from_montgomery_limbs([
+                10951905195093737496,
+                13860805794722448223,
+                5410780350851295799,
+                932195684579566777,
+            ]),
+            Fq::from_montgomery_limbs([
+                10478757536932427189,
+                15197428249636701664,
+                15657593253270180582,
+                835492490236214645,
+            ]),
+            Fq::from_montgomery_limbs([
+                1509114512111963376,
+                8693450481763518452,
+                17510803014200871414,
+                393062015380969608,
+            ]),
+            Fq::from_montgomery_limbs([
+                5659114767952250757,
+                15031551842778004374,
+                3132952400576469127,
+                9498786324883478,
+            ]),
+            Fq::from_montgomery_limbs([
+                349459192906020913,
+                5151961682704557868,
+                12572414014965476478,
+                1006671053476155687,
+            ]),
+            Fq::from_montgomery_limbs([
+                4202611012107428199,
+                16844668207257703011,
+                16379827711478680161,
+                355605931888467998,
+            ]),
+            Fq::from_montgomery_limbs([
+                6637477418427333121,
+                1348265801084710327,
+                9477485551587487229,
+                380491634491540605,
+            ]),
+            Fq::from_montgomery_limbs([
+                4959929011038733880,
+                12610860731834655945,
+                14628065506340668703,
+                1265673269179724237,
+            ]),
+            Fq::from_montgomery_limbs([
+                14472954624132178066,
+                18234005429942340542,
+                12290777109763571465,
+                900458376729483266,
+            ]),
+            Fq::from_montgomery_limbs([
+                8577608540133175640,
+                12574844716200856565,
+                9670204509850952448,
+                1285505973660211250,
+            ]),
+            Fq::from_montgomery_limbs([
+                11837092860034984545,
+                17971933332082090428,
+                494512390460113849,
+                928841767595763468,
+            ]),
+            Fq::from_montgomery_limbs([
+                2351790784960728134,
+                10587810795161961187,
+                10057230905526365422,
+                1176170483988185349,
+            ]),
+            Fq::from_montgomery_limbs([
+                12138912172488822112,
+                17099559751224002341,
+                536569432314686138,
+                329655543091612780,
+            ]),
+            Fq::from_montgomery_limbs([
+                8707897095077719175,
+                2070522636808386738,
+                2537389550461087695,
+                49586696792233662,
+            ]),
+            Fq::from_montgomery_limbs([
+                9797979349252449113,
+                12910063778342373598,
+                15567072095019838274,
+                615898850895926513,
+            ]),
+            Fq::from_montgomery_limbs([
+                11434587772200717765,
+                6097354794337381238,
+                1247946713348455110,
+                957173672703828275,
+            ]),
+            Fq::from_montgomery_limbs([
+                1474840517792564507,
+                6918968391387297102,
+                10183935970519964842,
+                1284378451243016395,
+            ]),
+            Fq::from_montgomery_limbs([
+                8547712710369956458,
+                9657195791919375537,
+                17257489104876268311,
+                482389243824552522,
+            ]),
+            Fq::from_montgomery_limbs([
+                3523143714493278057,
+                7441222978155647899,
+                6833336225640262811,
+                980685323180219680,
+            ]),
+            Fq::from_montgomery_limbs([
+                12810629566011854160,
+                9190570071341086750,
+                5651372891185540762,
+                264518009509430710,
+            ]),
+            Fq::from_montgomery_limbs([
+                8751323695564091882,
+                981246998086587420,
+                4784533031551981378,
+                274096744174879417,
+            ]),
+            Fq::from_montgomery_limbs([
+                4260651256321144984,
+                14532748901599405801,
+                3116038330442740346,
+                457572461702990341,
+            ]),
+            Fq::from_montgomery_limbs([
+                3899318335545457705,
+                1506147811969432101,
+                13557766250887551245,
+                436315619255297818,
+            ]),
+            Fq::from_montgomery_limbs([
+                15867353028948267592,
+                4818979226575763395,
+                2995406062814142287,
+                686650039239388787,
+            ]),
+            Fq::from_montgomery_limbs([
+                4729741687356431079,
+                5194647742987793027,
+                4786680399430922377,
+                709319614300291788,
+            ]),
+            Fq::from_montgomery_limbs([
+                18183896433877306232,
+                540070187928061848,
+                11250913986624224144,
+                211922472902678753,
+            ]),
+            Fq::from_montgomery_limbs([
+                7255312928270747200,
+                11419759988203082268,
+                1430608066652628567,
+                452367321564138837,
+            ]),
+            Fq::from_montgomery_limbs([
+                17639535452466106563,
+                11155372458920925421,
+                3506246823403294680,
+                408971162991684469,
+            ]),
+            Fq::from_montgomery_limbs([
+                14183171206297212299,
+                18380425827919303385,
+                7698661773460762300,
+                508721887002230791,
+            ]),
+            Fq::from_montgomery_limbs([
+                13548991656138419440,
+                11551751846232862703,
+                7923235186918478140,
+                149781029126062001,
+            ]),
+            Fq::from_montgomery_limbs([
+                4563446739256915103,
+                14578791987643924615,
+                10212199323994541826,
+                138991960614607171,
+            ]),
+            Fq::from_montgomery_limbs([
+                9589057798994575328,
+                3633372669892856696,
+                1041857347023416716,
+                358197938947830543,
+            ]),
+            Fq::from_montgomery_limbs([
+                10902223166684812429,
+                12589364613966865504,
+                1935894251029774602,
+                131209711448558671,
+            ]),
+            Fq::from_montgomery_limbs([
+                6146949283072209214,
+                4157829920080670129,
+                784576540822272382,
+                1268901862296506200,
+            ]),
+            Fq::from_montgomery_limbs([
+                11020430882652833560,
+                11288048067783401373,
+                18438835353531219852,
+                1117013482344585841,
+            ]),
+            Fq::from_montgomery_limbs([
+                13014815738835206272,
+                2323432685589498247,
+                11153032825733287015,
+                668894348785141812,
+            ]),
+            Fq::from_montgomery_limbs([
+                7393290668728591901,
+                18199845340747658744,
+                4222242275490156571,
+                312958092984053925,
+            ]),
+            Fq::from_montgomery_limbs([
+                17581347101902039019,
+                11626114969743709738,
+                7675138452357794084,
+                710307980100794972,
+            ]),
+            Fq::from_montgomery_limbs([
+                16807180077186172379,
+                2065228992963671432,
+                40798450170388270,
+                1134405341776094816,
+            ]),
+            Fq::from_montgomery_limbs([
+                4111573039907182361,
+                2367024902952472568,
+                14805967440162987791,
+                279937141782637028,
+            ]),
+            Fq::from_montgomery_limbs([
+                4061212016922791946,
+                14324320600900741263,
+                11976046708137698788,
+                1317142109548218786,
+            ]),
+            Fq::from_montgomery_limbs([
+                4573237734378393932,
+                8262671671371357155,
+                4229407842796474524,
+                900857661270965329,
+            ]),
+            Fq::from_montgomery_limbs([
+                13918540060844571243,
+                13851465716420828068,
+                17705362765833690831,
+                1075921619685071881,
+            ]),
+            Fq::from_montgomery_limbs([
+                17768631691644616170,
+                2161381556080409618,
+                53584698756104581,
+                1299483185598463039,
+            ]),
+            Fq::from_montgomery_limbs([
+                3515768629545731852,
+                10438270189094565020,
+                2593677022587091731,
+                1304787279201634350,
+            ]),
+            Fq::from_montgomery_limbs([
+                5610115602880103528,
+                15429489716323990804,
+                11871577470508193819,
+                1183874547024175691,
+            ]),
+            Fq::from_montgomery_limbs([
+                17107708522496782425,
+                3118056976418574294,
+                12888876529873432088,
+                1074402884965452425,
+            ]),
+            Fq::from_montgomery_limbs([
+                16850393815617415069,
+                538960466210112923,
+                4185039129568677932,
+                137759923628574428,
+            ]),
+            Fq::from_montgomery_limbs([
+                8338803782727795062,
+                11543898960421814133,
+                14416088988817687135,
+                564087557875672467,
+            ]),
+            Fq::from_montgomery_limbs([
+                1631662567599091912,
+                13020815701276416601,
+                465793213213279508,
+                42650005332727822,
+            ]),
+            Fq::from_montgomery_limbs([
+                14355521559103698610,
+                15327402911538575082,
+                3906461552809514790,
+                938284109916345982,
+            ]),
+            Fq::from_montgomery_limbs([
+                6837068573822027955,
+                16864192738813472541,
+                718648687444028450,
+                808350801834959397,
+            ]),
+            Fq::from_montgomery_limbs([
+                9713644522260553499,
+                15088319588321781330,
+                12856697806497799698,
+                698127325961600511,
+            ]),
+            Fq::from_montgomery_limbs([
+                17515529275763514986,
+                15913948473045303739,
+                5507800408541477786,
+                870655840400810284,
+            ]),
+            Fq::from_montgomery_limbs([
+                2186447258467027478,
+                7040515718266183634,
+                62180450887297020,
+                991367779169665878,
+            ]),
+            Fq::from_montgomery_limbs([
+                2126007984617909608,
+                16623466884488688601,
+                9332571405859495072,
+                286490403013578286,
+            ]),
+            Fq::from_montgomery_limbs([
+                14789160018684430346,
+                12113221928979236138,
+                9798801801274469584,
+                1195961523677518655,
+            ]),
+            Fq::from_montgomery_limbs([
+                13546097298986066792,
+                9524666578446260876,
+                5202006688683741895,
+                874016196988929767,
+            ]),
+            Fq::from_montgomery_limbs([
+                1416561242978634900,
+                14797937727768124600,
+                8429074438763470656,
+                1044451843159345404,
+            ]),
+            Fq::from_montgomery_limbs([
+                7395725137715384418,
+                4912967251416203732,
+                9151071992894976517,
+                1209590678637206631,
+            ]),
+            Fq::from_montgomery_limbs([
+                17819354899520349923,
+                11074134881578665707,
+                9855599330097296621,
+                479704984082559932,
+            ]),
+            Fq::from_montgomery_limbs([
+                15244403266260911189,
+                6423268715527344292,
+                6143204819323161482,
+                703072387753318367,
+            ]),
+            Fq::from_montgomery_limbs([
+                9755032864732632591,
+                5728831881255816997,
+                13988105933803394772,
+                1258000934720742433,
+            ]),
+            Fq::from_montgomery_limbs([
+                17085106302840372018,
+                1062956921818368989,
+                275319613450984650,
+                1293631481763216180,
+            ]),
+            Fq::from_montgomery_limbs([
+                6859335937518986249,
+                7286000975454741376,
+                4742375507811459431,
+                793924632482725736,
+            ]),
+            Fq::from_montgomery_limbs([
+                14607931768955340558,
+                17530735792200410890,
+                17345239320458913286,
+                726245815117474777,
+            ]),
+            Fq::from_montgomery_limbs([
+                10044930521853437097,
+                2877912515895504967,
+                18035052145330597777,
+                33064885354983112,
+            ]),
+            Fq::from_montgomery_limbs([
+                9382880122260864933,
+                17058597661703525282,
+                6556783905869804897,
+                1168870260449205008,
+            ]),
+            Fq::from_montgomery_limbs([
+                18432528797484006433,
+                4735722900425166584,
+                18265368024891288921,
+                1027470586870758805,
+            ]),
+            Fq::from_montgomery_limbs([
+                15719656347167412293,
+                11332557354049636842,
+                519942159990717230,
+                772666384261156981,
+            ]),
+            Fq::from_montgomery_limbs([
+                9321751289388745336,
+                14253755837443665045,
+                3168938111435047357,
+                420111122235415811,
+            ]),
+            Fq::from_montgomery_limbs([
+                13059683238057048005,
+                18288382662468137834,
+                1823611276910947821,
+                690198691824589796,
+            ]),
+            Fq::from_montgomery_limbs([
+                18162828489183545438,
+                10351953785278496677,
+                9286254997771830812,
+                927279774944502804,
+            ]),
+            Fq::from_montgomery_limbs([
+                4963522345382144594,
+                4253154676900921460,
+                7282282161051089230,
+                593587527071216780,
+            ]),
+            Fq::from_montgomery_limbs([
+                6479485194355552383,
+                15251033991938434070,
+                12590591367953273056,
+                274424551013743663,
+            ]),
+            Fq::from_montgomery_limbs([
+                2620796545435651276,
+                14586846717862121128,
+                2302332097837636045,
+                1283819796493180077,
+            ]),
+            Fq::from_montgomery_limbs([
+                8991468273571358236,
+                7227313118633653499,
+                15747490088173436757,
+                561599486120549554,
+            ]),
+            Fq::from_montgomery_limbs([
+                18294313051361620990,
+                14965102751025789813,
+                15706172076599615971,
+                802378037031916183,
+            ]),
+            Fq::from_montgomery_limbs([
+                1064780434465538961,
+                4607860607817195808,
+                9030343624340936138,
+                833831034884030663,
+            ]),
+            Fq::from_montgomery_limbs([
+                13416431627176782435,
+                10066339175618225206,
+                572112985484452636,
+                822499382746857240,
+            ]),
+            Fq::from_montgomery_limbs([
+                7143711692038495865,
+                12219773754522029043,
+                17242582676156166270,
+                777289807176518798,
+            ]),
+            Fq::from_montgomery_limbs([
+                7113128717936292292,
+                13857688210100771388,
+                8793336458737340360,
+                1330563484766800196,
+            ]),
+            Fq::from_montgomery_limbs([
+                2730533637401062800,
+                4389919884552042601,
+                13851575688231639897,
+                236614382605506649,
+            ]),
+            Fq::from_montgomery_limbs([
+                17358821117845333391,
+                4091803739023226619,
+                8948513744110832556,
+                231736711022474418,
+            ]),
+            Fq::from_montgomery_limbs([
+                7525422049735017990,
+                5816926910449671754,
+                2946348668431926471,
+                473912870826693324,
+            ]),
+            Fq::from_montgomery_limbs([
+                15022760476885596465,
+                8777648649255031612,
+                5258841101915267847,
+                176970954051611421,
+            ]),
+            Fq::from_montgomery_limbs([
+                8126893524716324009,
+                16080651881406269453,
+                13291422568851640799,
+                1155650272966203044,
+            ]),
+            Fq::from_montgomery_limbs([
+                15350389434008552926,
+                3983401594947074567,
+                8320244211595590690,
+                933663017215356916,
+            ]),
+            Fq::from_montgomery_limbs([
+                12639111985855809416,
+                12674676990244963713,
+                12240668328821425162,
+                345986273063363717,
+            ]),
+            Fq::from_montgomery_limbs([
+                2898677285425868830,
+                6536906805649771176,
+                11349791580100289305,
+                99788647319827915,
+            ]),
+            Fq::from_montgomery_limbs([
+                2167792350480917328,
+                10589339628863920664,
+                7699977007227927339,
+                793939266875570330,
+            ]),
+            Fq::from_montgomery_limbs([
+                8444282800967210325,
+                759373735047886169,
+                16753673678151798746,
+                1050202154677582396,
+            ]),
+            Fq::from_montgomery_limbs([
+                10129561244087704230,
+                5414967705790977670,
+                3812232643872852410,
+                736979276485277292,
+            ]),
+            Fq::from_montgomery_limbs([
+                6553964492519427603,
+                5621330608573000640,
+                647164520143749491,
+                857936355866178316,
+            ]),
+            Fq::from_montgomery_limbs([
+                16917175845255297458,
+                14902769560824204802,
+                13364901292932298968,
+                254879825976482888,
+            ]),
+            Fq::from_montgomery_limbs([
+                17604611775216405316,
+                10074391535614335317,
+                7938180133983796667,
+                795763492170113068,
+            ]),
+            Fq::from_montgomery_limbs([
+                16129353635343846276,
+                118924070074156239,
+                5769885889604993219,
+                640412620105514222,
+            ]),
+            Fq::from_montgomery_limbs([
+                4436710323259045428,
+                3157658111094362024,
+                14205278966518752479,
+                249458572467107909,
+            ]),
+            Fq::from_montgomery_limbs([
+                9495877350319107901,
+                12614925493550843935,
+                910471512100498256,
+                635940713216673844,
+            ]),
+            Fq::from_montgomery_limbs([
+                6816593327656799161,
+                14990003390002406416,
+                2134917325919873748,
+                253204383704722232,
+            ]),
+            Fq::from_montgomery_limbs([
+                1312844402086509858,
+                5035352872659912609,
+                6154765806631455701,
+                1344234666107787112,
+            ]),
+            Fq::from_montgomery_limbs([
+                10710900327348193728,
+                2417552609569632747,
+                5805242454197579417,
+                114309584150664154,
+            ]),
+            Fq::from_montgomery_limbs([
+                17408989706991867480,
+                9421945546499341389,
+                14411019173772756235,
+                1160260016630264827,
+            ]),
+            Fq::from_montgomery_limbs([
+                13207692001601626216,
+                11838677159016750842,
+                4984734708755270432,
+                766235337952488170,
+            ]),
+            Fq::from_montgomery_limbs([
+                4650502189017190309,
+                17817715353396297679,
+                14399617884320905796,
+                583659183149749248,
+            ]),
+            Fq::from_montgomery_limbs([
+                5707286640737596443,
+                10032967129287362657,
+                10890310144471980168,
+                866298111687890363,
+            ]),
+        ]),
+        mds: MdsMatrix::<10, 9, 100, 81>::new_from_known([
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                7989155441247042094,
+                18276457113184108543,
+                17999817914616464103,
+                943187440870955565,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                18204910156218979669,
+                16293041589642941780,
+                9975069610392864084,
+                1088801882579738168,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                15441359965645378481,
+                7022857083512140090,
+                11162758362666494739,
+                1108530997049617801,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                7802104352665277001,
+                1712233803072817444,
+                12180777293186749586,
+                1125441666595228915,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                7330330845985706257,
+                6947957132670498679,
+                9373711551562393463,
+                1140097580201425214,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                829043150522309210,
+                7485532467658058089,
+                17072453966431109601,
+                610297755857677130,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                18043687544558598371,
+                8708325242362018018,
+                16625116017321440141,
+                277441131490434312,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                1903578231313020389,
+                13252279451564941743,
+                6429417326301386859,
+                900077037001125169,
+            ]),
+            Fq::from_montgomery_limbs([
+                14902495609461984461,
+                16049616749407607193,
+                8772353492731922841,
+                1191393277823112260,
+            ]),
+            Fq::from_montgomery_limbs([
+                5443236819267423281,
+                9444106377351672003,
+                16592192658774520588,
+                1198721234626210409,
+            ]),
+            Fq::from_montgomery_limbs([
+                13217949757478296855,
+                18361600593446830079,
+                18223280994163007859,
+                471593720435477782,
+            ]),
+            Fq::from_montgomery_limbs([
+                14891635570577939411,
+                4930228641865103982,
+                677506429995745101,
+                158637391136506271,
+            ]),
+            Fq::from_montgomery_limbs([
+                241833917490571947,
+                2153702484066609835,
+                8471674463316687531,
+                1217041126633955783,
+            ]),
+            Fq::from_montgomery_limbs([
+                15611345302311497892,
+                9150344584784175431,
+                7017882794185538273,
+                953114622258489808,
+            ]),
+            Fq::from_montgomery_limbs([
+                17306802895913322969,
+                6741982267855984797,
+                18288890876308278666,
+                1226905683868895599,
+            ]),
+            Fq::from_montgomery_limbs([
+                6363878173450358936,
+                10112955129707898349,
+                3431100839901599668,
+                1081814334785738474,
+            ]),
+            Fq::from_montgomery_limbs([
+                13487175089423272229,
+                13310042664491099282,
+                351156267858854473,
+                1235361018641701157,
+            ]),
+        ]),
+        alpha: Alpha::Exponent(17),
+        rounds: RoundNumbers { r_P: 31, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<9, 81>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+            ]),
+            v: Matrix::<1, 9, 9>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+            ]),
+            w: Matrix::<9, 1, 9>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<10, 100>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14902495609461984461,
+                    16049616749407607193,
+                    8772353492731922841,
+                    1191393277823112260,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5443236819267423281,
+                    9444106377351672003,
+                    16592192658774520588,
+                    1198721234626210409,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13217949757478296855,
+                    18361600593446830079,
+                    18223280994163007859,
+                    471593720435477782,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14891635570577939411,
+                    4930228641865103982,
+                    677506429995745101,
+                    158637391136506271,
+                ]),
+                Fq::from_montgomery_limbs([
+                    241833917490571947,
+                    2153702484066609835,
+                    8471674463316687531,
+                    1217041126633955783,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15611345302311497892,
+                    9150344584784175431,
+                    7017882794185538273,
+                    953114622258489808,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17306802895913322969,
+                    6741982267855984797,
+                    18288890876308278666,
+                    1226905683868895599,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6363878173450358936,
+                    10112955129707898349,
+                    3431100839901599668,
+                    1081814334785738474,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13487175089423272229,
+                    13310042664491099282,
+                    351156267858854473,
+                    1235361018641701157,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<10, 100>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7989155441247042094,
+                    18276457113184108543,
+                    17999817914616464103,
+                    943187440870955565,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18204910156218979669,
+                    16293041589642941780,
+                    9975069610392864084,
+                    1088801882579738168,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15441359965645378481,
+                    7022857083512140090,
+                    11162758362666494739,
+                    1108530997049617801,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7802104352665277001,
+                    1712233803072817444,
+                    12180777293186749586,
+                    1125441666595228915,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7330330845985706257,
+                    6947957132670498679,
+                    9373711551562393463,
+                    1140097580201425214,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                Fq::from_montgomery_limbs([
+                    829043150522309210,
+                    7485532467658058089,
+                    17072453966431109601,
+                    610297755857677130,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18043687544558598371,
+                    8708325242362018018,
+                    16625116017321440141,
+                    277441131490434312,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1903578231313020389,
+                    13252279451564941743,
+                    6429417326301386859,
+                    900077037001125169,
+                ]),
+                Fq::from_montgomery_limbs([
+                    22441232234112986,
+                    3799035518881103639,
+                    780789349836548724,
+                    268412650062153680,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1293914879304640780,
+                    4775710345941485153,
+                    8471033183625180885,
+                    811672190941271036,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8594394549473523339,
+                    10645501266342357950,
+                    18178869997317741464,
+                    278866667552558297,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    6220921764124794410,
+                    10827177577305781300,
+                    17569370803172628479,
+                    33478767828431371,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9218771003581596401,
+                    7584367402220131040,
+                    4682727734357677672,
+                    1291714342162683203,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    4219288248971473478,
+                    18045295962176302167,
+                    4686625907008311977,
+                    55797946380718953,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    108334751330443434,
+                    17860887709594633151,
+                    13979335019469946020,
+                    980865491968399390,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2809343791818564545,
+                    2759683716479272601,
+                    4595644873306541379,
+                    641135718527980277,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17684391756717204689,
+                    9806787052492077582,
+                    12961568506985873356,
+                    1334704723492118620,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<10, 100>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    15249187168119787456,
+                    9195756912562271948,
+                    14661555648391263964,
+                    111034142355872267,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13263401525601745014,
+                    7670651669529865682,
+                    18001853619745979230,
+                    384445549848192087,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1566414946750690505,
+                    2582248817309531968,
+                    5572327052756988015,
+                    1344722263127175579,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9516776021004256491,
+                    10188069752282058779,
+                    760900594859917194,
+                    1002540495239761351,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18019990371737788827,
+                    6035281126979490750,
+                    2954899361600123319,
+                    1132883343931052559,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13201855872323983114,
+                    6955113915974384131,
+                    1830022024437003997,
+                    877947391086662702,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10861071460048380588,
+                    3597694426099321746,
+                    16403297026802579504,
+                    542858535863580866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18253810440169573161,
+                    3017852210588787844,
+                    18073604915420725906,
+                    989457128693557367,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9567785306609161169,
+                    12820435761672610382,
+                    7364717946375889161,
+                    133433715747981011,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6797989652584238202,
+                    10794391507939135128,
+                    5805743288000685330,
+                    686193504801123611,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13263401525601745014,
+                    7670651669529865682,
+                    18001853619745979230,
+                    384445549848192087,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6142312474444405866,
+                    16420249156013673456,
+                    5288600924182396016,
+                    383050280945697540,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4530455749725950725,
+                    18119386012006838012,
+                    13797443054824200730,
+                    112756277220284793,
+                ]),
+                Fq::from_montgomery_limbs([
+                    885540353755388817,
+                    8710854581373914690,
+                    7069150294875753551,
+                    1308445271662317339,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14362639665459442147,
+                    6591935293967728928,
+                    14299043506564579340,
+                    1146765382280917981,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9410099639803759427,
+                    2361482406340449549,
+                    267114100750294584,
+                    991682130998604333,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1206214369763890823,
+                    1640217486736933761,
+                    928555208229579511,
+                    220300340187137926,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12711394552311939668,
+                    14244165224878838584,
+                    4697276433488921692,
+                    667873631155778923,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8581150404023456548,
+                    14207152597941898035,
+                    16289054420451528703,
+                    1123207046878235548,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12467704651021039485,
+                    13948442900809834511,
+                    17423480210112975592,
+                    1180782626507250870,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1566414946750690505,
+                    2582248817309531968,
+                    5572327052756988015,
+                    1344722263127175579,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4530455749725950725,
+                    18119386012006838012,
+                    13797443054824200730,
+                    112756277220284793,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7104962067401153548,
+                    14902907239058923873,
+                    15824895963957159652,
+                    423437643972683642,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14242352548511050266,
+                    8350579165514103847,
+                    10117865074569188931,
+                    800645944968643452,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16263537044262169686,
+                    14613682638183761534,
+                    15501545368066196027,
+                    381652016929262742,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9196262731527678451,
+                    7731498329775929811,
+                    3542320501266952716,
+                    706895933782334798,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7043603706017778709,
+                    11795886718876365903,
+                    7152915125134132926,
+                    653301194645779106,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14217458245006581568,
+                    2126193780916938463,
+                    10446818482503074263,
+                    729652200799253991,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9792139322324235861,
+                    12289550353329704495,
+                    3776931041221196791,
+                    436978152856961433,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1738996199679916061,
+                    17069277438955499041,
+                    15562415747998878060,
+                    1074049014889602617,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9516776021004256491,
+                    10188069752282058779,
+                    760900594859917194,
+                    1002540495239761351,
+                ]),
+                Fq::from_montgomery_limbs([
+                    885540353755388817,
+                    8710854581373914690,
+                    7069150294875753551,
+                    1308445271662317339,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14242352548511050266,
+                    8350579165514103847,
+                    10117865074569188931,
+                    800645944968643452,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9911624457590756309,
+                    12351689994345517592,
+                    2165364758185548047,
+                    292410456865787712,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13229973575287815151,
+                    7318446334466955490,
+                    1148130447841844848,
+                    213157851885755926,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16697424339735341084,
+                    6160883257294485039,
+                    4973015915743837685,
+                    295496987750763452,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3499587910404455126,
+                    3519433962555022987,
+                    12046468115171447982,
+                    963426679861676427,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5836240623896046283,
+                    16020525518017829748,
+                    1354835618294232724,
+                    1070615640483107446,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10264752257051983034,
+                    4321525272276530590,
+                    12350286327425357101,
+                    13425797426240173,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7675725888058406285,
+                    3104447121982396668,
+                    4841388204291076829,
+                    210674455718715088,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18019990371737788827,
+                    6035281126979490750,
+                    2954899361600123319,
+                    1132883343931052559,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14362639665459442147,
+                    6591935293967728928,
+                    14299043506564579340,
+                    1146765382280917981,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16263537044262169686,
+                    14613682638183761534,
+                    15501545368066196027,
+                    381652016929262742,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13229973575287815151,
+                    7318446334466955490,
+                    1148130447841844848,
+                    213157851885755926,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2118830017736396469,
+                    14148294325123372160,
+                    17000909812812699468,
+                    198744303981821613,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17296512835807761384,
+                    14805754294297207561,
+                    12220052992913245806,
+                    324580256120072397,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4150495567885048119,
+                    2516804060769156755,
+                    13039765963611219369,
+                    1301619071603246622,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12279353170002697471,
+                    15683494993634300732,
+                    4957517710861833698,
+                    1182056485493130672,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18141035865013300593,
+                    3216123660566531237,
+                    11610689275563798845,
+                    732705280373640762,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14905839536764009725,
+                    2196431542794434234,
+                    13630136179630027412,
+                    86109629647034036,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13201855872323983114,
+                    6955113915974384131,
+                    1830022024437003997,
+                    877947391086662702,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9410099639803759427,
+                    2361482406340449549,
+                    267114100750294584,
+                    991682130998604333,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9196262731527678451,
+                    7731498329775929811,
+                    3542320501266952716,
+                    706895933782334798,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16697424339735341084,
+                    6160883257294485039,
+                    4973015915743837685,
+                    295496987750763452,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17296512835807761384,
+                    14805754294297207561,
+                    12220052992913245806,
+                    324580256120072397,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7682676196050347750,
+                    6512893102686364722,
+                    5830150300024879641,
+                    470749001115668048,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4987278241353048060,
+                    11637597066752801073,
+                    5739631741838239512,
+                    840015394001285657,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1547192011937775387,
+                    9646384952127530585,
+                    8134713595611663410,
+                    878584707904694187,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10224885784259558464,
+                    1492240119500599915,
+                    5199944649215890187,
+                    469701118751512536,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16720947800480420522,
+                    8845502514078120031,
+                    15350012071331520598,
+                    531174365209673638,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10861071460048380588,
+                    3597694426099321746,
+                    16403297026802579504,
+                    542858535863580866,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1206214369763890823,
+                    1640217486736933761,
+                    928555208229579511,
+                    220300340187137926,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7043603706017778709,
+                    11795886718876365903,
+                    7152915125134132926,
+                    653301194645779106,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3499587910404455126,
+                    3519433962555022987,
+                    12046468115171447982,
+                    963426679861676427,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4150495567885048119,
+                    2516804060769156755,
+                    13039765963611219369,
+                    1301619071603246622,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4987278241353048060,
+                    11637597066752801073,
+                    5739631741838239512,
+                    840015394001285657,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12557489988940725533,
+                    12343650236715197024,
+                    1464389550390875662,
+                    1106612102232590864,
+                ]),
+                Fq::from_montgomery_limbs([
+                    862978279250655942,
+                    83857272912931277,
+                    6786305662097167353,
+                    1236884096960469535,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2955157479275320167,
+                    9549693937371954351,
+                    5333459528036106207,
+                    147582876105195139,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11126464894815798706,
+                    7663210717928910473,
+                    1198427596629919243,
+                    689009023085309957,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18253810440169573161,
+                    3017852210588787844,
+                    18073604915420725906,
+                    989457128693557367,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12711394552311939668,
+                    14244165224878838584,
+                    4697276433488921692,
+                    667873631155778923,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14217458245006581568,
+                    2126193780916938463,
+                    10446818482503074263,
+                    729652200799253991,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5836240623896046283,
+                    16020525518017829748,
+                    1354835618294232724,
+                    1070615640483107446,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12279353170002697471,
+                    15683494993634300732,
+                    4957517710861833698,
+                    1182056485493130672,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1547192011937775387,
+                    9646384952127530585,
+                    8134713595611663410,
+                    878584707904694187,
+                ]),
+                Fq::from_montgomery_limbs([
+                    862978279250655942,
+                    83857272912931277,
+                    6786305662097167353,
+                    1236884096960469535,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14422294331648288931,
+                    16408789894219856301,
+                    15626954719342888968,
+                    1102325118282649398,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3723540910724391477,
+                    5244182564096919008,
+                    2807771932408751600,
+                    500940985196398250,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6880322051418061580,
+                    10799495935499972969,
+                    2414234670614345497,
+                    763776081060673570,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9567785306609161169,
+                    12820435761672610382,
+                    7364717946375889161,
+                    133433715747981011,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8581150404023456548,
+                    14207152597941898035,
+                    16289054420451528703,
+                    1123207046878235548,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9792139322324235861,
+                    12289550353329704495,
+                    3776931041221196791,
+                    436978152856961433,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10264752257051983034,
+                    4321525272276530590,
+                    12350286327425357101,
+                    13425797426240173,
+                ]),
+                Fq::from_montgomery_limbs([
+                    18141035865013300593,
+                    3216123660566531237,
+                    11610689275563798845,
+                    732705280373640762,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10224885784259558464,
+                    1492240119500599915,
+                    5199944649215890187,
+                    469701118751512536,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2955157479275320167,
+                    9549693937371954351,
+                    5333459528036106207,
+                    147582876105195139,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3723540910724391477,
+                    5244182564096919008,
+                    2807771932408751600,
+                    500940985196398250,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16211088801669100737,
+                    4461378888023873870,
+                    13611451410348292024,
+                    1104568612146148460,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11189130476587193528,
+                    3034133497890713823,
+                    15642636676274940655,
+                    182772805719686010,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6797989652584238202,
+                    10794391507939135128,
+                    5805743288000685330,
+                    686193504801123611,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12467704651021039485,
+                    13948442900809834511,
+                    17423480210112975592,
+                    1180782626507250870,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1738996199679916061,
+                    17069277438955499041,
+                    15562415747998878060,
+                    1074049014889602617,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7675725888058406285,
+                    3104447121982396668,
+                    4841388204291076829,
+                    210674455718715088,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14905839536764009725,
+                    2196431542794434234,
+                    13630136179630027412,
+                    86109629647034036,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16720947800480420522,
+                    8845502514078120031,
+                    15350012071331520598,
+                    531174365209673638,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11126464894815798706,
+                    7663210717928910473,
+                    1198427596629919243,
+                    689009023085309957,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6880322051418061580,
+                    10799495935499972969,
+                    2414234670614345497,
+                    763776081060673570,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11189130476587193528,
+                    3034133497890713823,
+                    15642636676274940655,
+                    182772805719686010,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14232933401131809933,
+                    2880961190266451931,
+                    4361173203528020868,
+                    1314818236401559063,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<9, 81>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    7301739759757637710,
+                    13799993167131916737,
+                    8705922930300048267,
+                    1292855977527056295,
+                ]),
+                Fq::from_montgomery_limbs([
+                    41973514891907022,
+                    13426482974049627722,
+                    6270841519493638245,
+                    470518254591640499,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1220149173037943061,
+                    3640162805110137195,
+                    7117046385830339027,
+                    102710341122324050,
+                ]),
+                Fq::from_montgomery_limbs([
+                    576326202901038665,
+                    16572553411353560112,
+                    13728357008797352415,
+                    850931275154623190,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16420605219940877369,
+                    13322840955578470439,
+                    5771598400165473918,
+                    926904554125406639,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3066517947936525788,
+                    14279368808543411696,
+                    8449085508600888834,
+                    175670285206037740,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12455205429362923006,
+                    15104746373785801085,
+                    9292725100145952908,
+                    471029403212605364,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6190002877797714884,
+                    7657471785624441817,
+                    15129534350608708205,
+                    860703539684882283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1730443732329294936,
+                    7333235070640252449,
+                    3613632815540050119,
+                    791073140036866979,
+                ]),
+                Fq::from_montgomery_limbs([
+                    41973514891907022,
+                    13426482974049627722,
+                    6270841519493638245,
+                    470518254591640499,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3081853068763787919,
+                    5335211188030400067,
+                    8660689048913912540,
+                    609664543749540500,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7547080298702875512,
+                    5078776886161569282,
+                    4866404687863127969,
+                    1169108234619556085,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1361765087441968523,
+                    12075224659121320211,
+                    12528071536196273521,
+                    1107210396125152881,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15462955891696507705,
+                    4788964779947990042,
+                    1904418552779800606,
+                    1160338971894718654,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12468222732518885570,
+                    15555547834778778437,
+                    9849435243663622508,
+                    965711579513480405,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15285280353177982361,
+                    8087762360077923067,
+                    8203886647082448005,
+                    762281425713295504,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7357926179723160052,
+                    14782720439932965285,
+                    4925292213880429302,
+                    929222332522260890,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1661842830740489758,
+                    13560029198323811237,
+                    6061068508980576339,
+                    1111454678805943063,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1220149173037943061,
+                    3640162805110137195,
+                    7117046385830339027,
+                    102710341122324050,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7547080298702875512,
+                    5078776886161569282,
+                    4866404687863127969,
+                    1169108234619556085,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15734355386885906557,
+                    963633547732001741,
+                    14198368465331346295,
+                    79254065546138914,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16979962035946855030,
+                    11798425666407972744,
+                    7422391150959831757,
+                    1314984832218393347,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4624648327646145145,
+                    10622909740148550542,
+                    3787752745246068066,
+                    954078486457255695,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8298565173226089909,
+                    10361680660530093811,
+                    2418095963317220337,
+                    109244239039725333,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5958991887403578626,
+                    3663711379505215683,
+                    11662318229179637364,
+                    217390296349414257,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10672159128531480806,
+                    6075993704202572449,
+                    5922625893352567866,
+                    1174185533156483493,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8907767047330689312,
+                    5443966707522162143,
+                    17585195990436750420,
+                    28475670749366318,
+                ]),
+                Fq::from_montgomery_limbs([
+                    576326202901038665,
+                    16572553411353560112,
+                    13728357008797352415,
+                    850931275154623190,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1361765087441968523,
+                    12075224659121320211,
+                    12528071536196273521,
+                    1107210396125152881,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16979962035946855030,
+                    11798425666407972744,
+                    7422391150959831757,
+                    1314984832218393347,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11187759392120570164,
+                    4997997381575071044,
+                    4056221571477538593,
+                    894774470978547657,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7055976053512366468,
+                    8789673662540980688,
+                    1612689484622808757,
+                    287156285475849447,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6000212806090015412,
+                    9396072674771468249,
+                    6769905238011338761,
+                    667962189013558830,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15977729224879219080,
+                    7815973685687411749,
+                    3597122618259954444,
+                    930406863403999725,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17026208001611498430,
+                    1671830417980468814,
+                    3204727132811225422,
+                    1331554027665129892,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9104935070800284206,
+                    8453663428899722342,
+                    12604406955087329015,
+                    294870854984326825,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16420605219940877369,
+                    13322840955578470439,
+                    5771598400165473918,
+                    926904554125406639,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15462955891696507705,
+                    4788964779947990042,
+                    1904418552779800606,
+                    1160338971894718654,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4624648327646145145,
+                    10622909740148550542,
+                    3787752745246068066,
+                    954078486457255695,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7055976053512366468,
+                    8789673662540980688,
+                    1612689484622808757,
+                    287156285475849447,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9455240139249681491,
+                    6362797159710562651,
+                    16816890147053860469,
+                    799683428284059448,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12515375702473458688,
+                    13908625396298790694,
+                    6507436635063729379,
+                    777642109594247406,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3008487953619161459,
+                    14855721415358732311,
+                    14000694558516580540,
+                    1281224103247303702,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5065213787701650783,
+                    4150424964216287172,
+                    694358778912852311,
+                    318711345498033968,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7990951923795859241,
+                    17467991226597198055,
+                    10104099976118413649,
+                    1004324627082909215,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3066517947936525788,
+                    14279368808543411696,
+                    8449085508600888834,
+                    175670285206037740,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12468222732518885570,
+                    15555547834778778437,
+                    9849435243663622508,
+                    965711579513480405,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8298565173226089909,
+                    10361680660530093811,
+                    2418095963317220337,
+                    109244239039725333,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6000212806090015412,
+                    9396072674771468249,
+                    6769905238011338761,
+                    667962189013558830,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12515375702473458688,
+                    13908625396298790694,
+                    6507436635063729379,
+                    777642109594247406,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9491437361379153816,
+                    17660183235482532309,
+                    9461365748100626265,
+                    50517297916444543,
+                ]),
+                Fq::from_montgomery_limbs([
+                    636189761984436138,
+                    9413278040547575292,
+                    8345135426917048808,
+                    369041269915860157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1097111040272316562,
+                    6975871866395183646,
+                    3621352005921518246,
+                    1145664121591010355,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13272452332368146691,
+                    7635610868734653777,
+                    13290103418607966213,
+                    140090818188682339,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12455205429362923006,
+                    15104746373785801085,
+                    9292725100145952908,
+                    471029403212605364,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15285280353177982361,
+                    8087762360077923067,
+                    8203886647082448005,
+                    762281425713295504,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5958991887403578626,
+                    3663711379505215683,
+                    11662318229179637364,
+                    217390296349414257,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15977729224879219080,
+                    7815973685687411749,
+                    3597122618259954444,
+                    930406863403999725,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3008487953619161459,
+                    14855721415358732311,
+                    14000694558516580540,
+                    1281224103247303702,
+                ]),
+                Fq::from_montgomery_limbs([
+                    636189761984436138,
+                    9413278040547575292,
+                    8345135426917048808,
+                    369041269915860157,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14635308784909119225,
+                    3668917040674384355,
+                    8150287024573236087,
+                    65870730650371322,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3825035928869509077,
+                    4719382719585346508,
+                    7353592147007499175,
+                    1225931473230545878,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15262218362907378076,
+                    6891806478230684694,
+                    12002213863476320312,
+                    15693254627866520,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6190002877797714884,
+                    7657471785624441817,
+                    15129534350608708205,
+                    860703539684882283,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7357926179723160052,
+                    14782720439932965285,
+                    4925292213880429302,
+                    929222332522260890,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10672159128531480806,
+                    6075993704202572449,
+                    5922625893352567866,
+                    1174185533156483493,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17026208001611498430,
+                    1671830417980468814,
+                    3204727132811225422,
+                    1331554027665129892,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5065213787701650783,
+                    4150424964216287172,
+                    694358778912852311,
+                    318711345498033968,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1097111040272316562,
+                    6975871866395183646,
+                    3621352005921518246,
+                    1145664121591010355,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3825035928869509077,
+                    4719382719585346508,
+                    7353592147007499175,
+                    1225931473230545878,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6949656133009905829,
+                    11575707857356795414,
+                    2683396793018985875,
+                    832697179133343100,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10351762368992393794,
+                    11417046072007778782,
+                    7435458460524312195,
+                    463303865631988654,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1730443732329294936,
+                    7333235070640252449,
+                    3613632815540050119,
+                    791073140036866979,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1661842830740489758,
+                    13560029198323811237,
+                    6061068508980576339,
+                    1111454678805943063,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8907767047330689312,
+                    5443966707522162143,
+                    17585195990436750420,
+                    28475670749366318,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9104935070800284206,
+                    8453663428899722342,
+                    12604406955087329015,
+                    294870854984326825,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7990951923795859241,
+                    17467991226597198055,
+                    10104099976118413649,
+                    1004324627082909215,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13272452332368146691,
+                    7635610868734653777,
+                    13290103418607966213,
+                    140090818188682339,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15262218362907378076,
+                    6891806478230684694,
+                    12002213863476320312,
+                    15693254627866520,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10351762368992393794,
+                    11417046072007778782,
+                    7435458460524312195,
+                    463303865631988654,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5662369349167614429,
+                    3446751822332625835,
+                    9768700865575091416,
+                    324826925523633438,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+            M_i: Matrix::<10, 10, 100>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17729031980770642134,
+                    17225333965730895432,
+                    15278644711773518278,
+                    829938258113263359,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5609220983975880033,
+                    2491272259195789099,
+                    10696098289257866136,
+                    819399867602815282,
+                ]),
+                Fq::from_montgomery_limbs([
+                    466915560970807890,
+                    13015079333613930905,
+                    9064083073180765253,
+                    630546959772831677,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2099761052037283606,
+                    6715383789265355797,
+                    12592827448404554582,
+                    194396405499680495,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15805425774623141110,
+                    13750611370887637098,
+                    17809907305852845717,
+                    570162793424220048,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1308313778099416734,
+                    13557892902984118430,
+                    1203351180103877401,
+                    144248965932446052,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8461108423432117777,
+                    4402710475281914882,
+                    17344591808251130046,
+                    1045905795497378750,
+                ]),
+                Fq::from_montgomery_limbs([
+                    240895109557473417,
+                    6982815478425825105,
+                    7966143655626428109,
+                    380254286866128709,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13608188641838206383,
+                    899377520204525551,
+                    1603443079633610559,
+                    284168071877666048,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5609220983975880033,
+                    2491272259195789099,
+                    10696098289257866136,
+                    819399867602815282,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1592004871760573686,
+                    9546109403857152144,
+                    7280018510616386258,
+                    181370844641007471,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1473319356006801730,
+                    764431432051214077,
+                    4730850293662114734,
+                    1060777702612554022,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11724461862461575856,
+                    1305470386236970372,
+                    8129349308614747292,
+                    739730232296846760,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5218949937750707937,
+                    16496557012188900936,
+                    10390811784577389532,
+                    171012015747392999,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12952741483934487322,
+                    6148931800769735119,
+                    16134548288695493017,
+                    842891580547873427,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15264505591591523678,
+                    13855687895013144597,
+                    3317682012671482443,
+                    822365056478743726,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3763096237656137784,
+                    16613701071337797033,
+                    7280940267737371305,
+                    682864323625010935,
+                ]),
+                Fq::from_montgomery_limbs([
+                    355832333642176157,
+                    5784390115071177006,
+                    3465695840288242465,
+                    1253349658557657518,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    466915560970807890,
+                    13015079333613930905,
+                    9064083073180765253,
+                    630546959772831677,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1473319356006801730,
+                    764431432051214077,
+                    4730850293662114734,
+                    1060777702612554022,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13819526782206347871,
+                    9686815089146003153,
+                    9878591726749538244,
+                    229039217178163587,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1612850672424924805,
+                    16340156395841959005,
+                    16882189674431535893,
+                    18515129578879062,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17241796375497702768,
+                    13859380779728119852,
+                    18382512110238808800,
+                    1342306130031255873,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1194063405306542243,
+                    3550515381661018940,
+                    16390572262625317979,
+                    884510540814589192,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7632260338281315490,
+                    11233277554367318024,
+                    15154257520215174981,
+                    494681582654058132,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3098031531007410112,
+                    7490230030191070456,
+                    9556665217779594560,
+                    984587082772922179,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2576140227937305226,
+                    1427186623336774976,
+                    233693286690601954,
+                    699534262657121660,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2099761052037283606,
+                    6715383789265355797,
+                    12592827448404554582,
+                    194396405499680495,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11724461862461575856,
+                    1305470386236970372,
+                    8129349308614747292,
+                    739730232296846760,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1612850672424924805,
+                    16340156395841959005,
+                    16882189674431535893,
+                    18515129578879062,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5332619600786881752,
+                    5152112784133813153,
+                    6539048661304402480,
+                    920699233009986392,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4521675337628456915,
+                    15204761258736155216,
+                    9501239296547797149,
+                    1043111286558572482,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8822122414256617743,
+                    6010647413550639789,
+                    5016497068373414372,
+                    745211845629913942,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17558123929575760484,
+                    4453929580582553173,
+                    18195234480478536397,
+                    743755471465944546,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4568675489590702255,
+                    17294988964853233688,
+                    13411941287064578498,
+                    288443321574480126,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11860338640336995497,
+                    16518050599657470345,
+                    7273761463634351117,
+                    232583719825748596,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15805425774623141110,
+                    13750611370887637098,
+                    17809907305852845717,
+                    570162793424220048,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5218949937750707937,
+                    16496557012188900936,
+                    10390811784577389532,
+                    171012015747392999,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17241796375497702768,
+                    13859380779728119852,
+                    18382512110238808800,
+                    1342306130031255873,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4521675337628456915,
+                    15204761258736155216,
+                    9501239296547797149,
+                    1043111286558572482,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9153972490082610597,
+                    17360292006244933013,
+                    13201051771987106119,
+                    1229359295331812953,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8808494304310581971,
+                    14716594919787229334,
+                    389460590509078948,
+                    827879470498629460,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15895514314368731960,
+                    5745328055243797554,
+                    8827803419356876709,
+                    489130739305729651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9856299197961730748,
+                    4453397632493863312,
+                    4114445606445602719,
+                    497915523179204047,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4018835917018864775,
+                    9880899582936184550,
+                    16113535623854480821,
+                    359478848988198012,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1308313778099416734,
+                    13557892902984118430,
+                    1203351180103877401,
+                    144248965932446052,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12952741483934487322,
+                    6148931800769735119,
+                    16134548288695493017,
+                    842891580547873427,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1194063405306542243,
+                    3550515381661018940,
+                    16390572262625317979,
+                    884510540814589192,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8822122414256617743,
+                    6010647413550639789,
+                    5016497068373414372,
+                    745211845629913942,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8808494304310581971,
+                    14716594919787229334,
+                    389460590509078948,
+                    827879470498629460,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7686408986020181714,
+                    4162425056397565562,
+                    9552510972182035818,
+                    681700899941044058,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6330829204117290950,
+                    886843514959030724,
+                    10574375993141199985,
+                    978986818804311037,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17979805464007247724,
+                    7575962255361316563,
+                    13371591603004018864,
+                    990898841106243927,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8766530479753992958,
+                    5662551366666769215,
+                    9786077075893396262,
+                    156645658252859700,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    8461108423432117777,
+                    4402710475281914882,
+                    17344591808251130046,
+                    1045905795497378750,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15264505591591523678,
+                    13855687895013144597,
+                    3317682012671482443,
+                    822365056478743726,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7632260338281315490,
+                    11233277554367318024,
+                    15154257520215174981,
+                    494681582654058132,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17558123929575760484,
+                    4453929580582553173,
+                    18195234480478536397,
+                    743755471465944546,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15895514314368731960,
+                    5745328055243797554,
+                    8827803419356876709,
+                    489130739305729651,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6330829204117290950,
+                    886843514959030724,
+                    10574375993141199985,
+                    978986818804311037,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7296044795013585921,
+                    10996266712934510526,
+                    13366851038022459747,
+                    520018184740551424,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13614718496977912009,
+                    2981661787835292976,
+                    4223753279274236958,
+                    649571302217800861,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16910283748960509501,
+                    12377375641385558677,
+                    4621814986470974209,
+                    709845700937703547,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    240895109557473417,
+                    6982815478425825105,
+                    7966143655626428109,
+                    380254286866128709,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3763096237656137784,
+                    16613701071337797033,
+                    7280940267737371305,
+                    682864323625010935,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3098031531007410112,
+                    7490230030191070456,
+                    9556665217779594560,
+                    984587082772922179,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4568675489590702255,
+                    17294988964853233688,
+                    13411941287064578498,
+                    288443321574480126,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9856299197961730748,
+                    4453397632493863312,
+                    4114445606445602719,
+                    497915523179204047,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17979805464007247724,
+                    7575962255361316563,
+                    13371591603004018864,
+                    990898841106243927,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13614718496977912009,
+                    2981661787835292976,
+                    4223753279274236958,
+                    649571302217800861,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12185692551914202110,
+                    12055742266510799401,
+                    10594746508777357045,
+                    1002043488487880441,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1428259139905367494,
+                    18051773716566783872,
+                    969131142447693131,
+                    729235298288705742,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13608188641838206383,
+                    899377520204525551,
+                    1603443079633610559,
+                    284168071877666048,
+                ]),
+                Fq::from_montgomery_limbs([
+                    355832333642176157,
+                    5784390115071177006,
+                    3465695840288242465,
+                    1253349658557657518,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2576140227937305226,
+                    1427186623336774976,
+                    233693286690601954,
+                    699534262657121660,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11860338640336995497,
+                    16518050599657470345,
+                    7273761463634351117,
+                    232583719825748596,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4018835917018864775,
+                    9880899582936184550,
+                    16113535623854480821,
+                    359478848988198012,
+                ]),
+                Fq::from_montgomery_limbs([
+                    8766530479753992958,
+                    5662551366666769215,
+                    9786077075893396262,
+                    156645658252859700,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16910283748960509501,
+                    12377375641385558677,
+                    4621814986470974209,
+                    709845700937703547,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1428259139905367494,
+                    18051773716566783872,
+                    969131142447693131,
+                    729235298288705742,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16692937631898252690,
+                    17924680941778031717,
+                    11319554656850819564,
+                    947927141049785330,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7989155441247042094,
+                        18276457113184108543,
+                        17999817914616464103,
+                        943187440870955565,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18204910156218979669,
+                        16293041589642941780,
+                        9975069610392864084,
+                        1088801882579738168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15441359965645378481,
+                        7022857083512140090,
+                        11162758362666494739,
+                        1108530997049617801,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7802104352665277001,
+                        1712233803072817444,
+                        12180777293186749586,
+                        1125441666595228915,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7330330845985706257,
+                        6947957132670498679,
+                        9373711551562393463,
+                        1140097580201425214,
+                    ]),
+                    Fq::from_montgomery_limbs([0, 0, 0, 1152921504606846976]),
+                    Fq::from_montgomery_limbs([
+                        829043150522309210,
+                        7485532467658058089,
+                        17072453966431109601,
+                        610297755857677130,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18043687544558598371,
+                        8708325242362018018,
+                        16625116017321440141,
+                        277441131490434312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1903578231313020389,
+                        13252279451564941743,
+                        6429417326301386859,
+                        900077037001125169,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12258905657966325090,
+                        8687947815976330854,
+                        16195743738125052239,
+                        1097074533736016703,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3286917460604164931,
+                        16991813550988576124,
+                        4789310344871697867,
+                        493577590844772231,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9323163651852226315,
+                        15850911705983947793,
+                        15459955173968832274,
+                        989790942997285959,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14624737409383708902,
+                        12698961932839846586,
+                        11601167906907115167,
+                        1320364368784688480,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9137939797475397324,
+                        12222926309854633559,
+                        6674030016678628929,
+                        502790637465975311,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5254892865001486735,
+                        4665502286911591357,
+                        3640346974663179452,
+                        900720135175763420,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10263261881527141883,
+                        5951254117953040063,
+                        10207955959714576119,
+                        1068324803488529835,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5828644299297391173,
+                        9643454635718062918,
+                        17498742389568816019,
+                        834237552640550585,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4055173449023589914,
+                        16150257888705184407,
+                        1381200690866407729,
+                        1003241908984463944,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17127692126933025052,
+                        3742706607348330002,
+                        9977360464930321424,
+                        1236181131097936926,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11097607762960543821,
+                        197182341991408218,
+                        7546089965549687692,
+                        321385894330782535,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11152771702291582264,
+                        823280036784270548,
+                        5454553016927024058,
+                        5032907997450468,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5767066048035254338,
+                        5018363432760611478,
+                        16591598528248802647,
+                        1092776717835112602,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8180730041345521994,
+                        15925391909960361961,
+                        12978609406479101355,
+                        579987525965835397,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1738060130248407770,
+                        15296110162067086465,
+                        14969187761548585621,
+                        1336992846774367008,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10472020949175422865,
+                        16809304069750276014,
+                        3140921285096365442,
+                        726759567123786807,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15549919043111558704,
+                        13444520455223879306,
+                        15410969404046214986,
+                        522144308901118993,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15213923364105269007,
+                        17487789851896493875,
+                        13946008185692683318,
+                        1211884842233514032,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16108691417700722799,
+                        8341745068895444591,
+                        8701693217568148132,
+                        576571614737850375,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6997920734633525315,
+                        8657063579199750373,
+                        963070741124159558,
+                        502823870662260939,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14976430330685265402,
+                        11785691654476009645,
+                        12511507162017111318,
+                        917741352447309394,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10901026033116711627,
+                        3391607658430875745,
+                        8911183161707535398,
+                        779859728226707158,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16250170588041146154,
+                        10767228605579781806,
+                        10237610074620747206,
+                        1141880054171899178,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10849052899809605683,
+                        7049711608955741818,
+                        17990882902997267891,
+                        546272774841896593,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5598973768281114882,
+                        7772290206234855858,
+                        5832747320206158791,
+                        446589827110624532,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4423480490098319096,
+                        14045139008337901286,
+                        13562824702145692335,
+                        882239687248326062,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14960643895102629196,
+                        8617465280826275070,
+                        12998290583502357010,
+                        1089044895708363798,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5717956246447831251,
+                        15259988027197986183,
+                        3080758367832086516,
+                        985964021774202136,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8849752805499997714,
+                        13580266600270854352,
+                        5709057779854012093,
+                        1039832560000083019,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7860927131077616971,
+                        10029476991862349375,
+                        11398111040026531496,
+                        855072944545767412,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2862350089481383332,
+                        9470821471594912549,
+                        11478104832471581004,
+                        185274555590577781,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1495036797126599088,
+                        9009811507190687720,
+                        107779564342935527,
+                        599189150262379916,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2717610036613000748,
+                        15254979353635820433,
+                        2086106675863542675,
+                        1214981470849077208,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5745709195091327723,
+                        11662018434129809752,
+                        7186618583682262103,
+                        505445245327836720,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12910390221193969133,
+                        7289631543153515791,
+                        3308451094524012946,
+                        206488505886055914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1577418386326127634,
+                        6669366545717086866,
+                        5905096677737171654,
+                        380371738142121312,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7669271718800143855,
+                        17412853760914025659,
+                        13161096774340635081,
+                        1167249174715519854,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17147351329290784396,
+                        15484396594706143337,
+                        4173097918504310872,
+                        918866120835883375,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5196258224343670761,
+                        8550534766362706264,
+                        13811842899581699322,
+                        1081097902030666843,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        692050090851479960,
+                        17339571599921371718,
+                        12800142686354905820,
+                        136349116472549349,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3000136416335110113,
+                        184046650792987280,
+                        3157797804797962137,
+                        503762059817173146,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15159499759262119169,
+                        9347702328535157076,
+                        16466647296124425606,
+                        711435140860573677,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17129019539787667075,
+                        12193872105270580207,
+                        5035455603924841720,
+                        1041568595257695454,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11316793756411358744,
+                        4505669922310291437,
+                        6641848174032360075,
+                        208989063095306949,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        175167210784764759,
+                        377326801472078264,
+                        10546932556587786085,
+                        113146508625263292,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        647502239245158118,
+                        9069043327047458584,
+                        8567602370321284284,
+                        511938625982737263,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10492136832349994628,
+                        11933398375467626965,
+                        5613341820356305,
+                        170212148413101721,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5499142128419169134,
+                        8868551451692712876,
+                        4287782530504219024,
+                        993565478716142453,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12423080864345950212,
+                        17741895514811873287,
+                        9665479056501607517,
+                        1181156382531440456,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6763486935619838898,
+                        2460702011203385243,
+                        11703054682617404337,
+                        711224845408836020,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1270683135378648748,
+                        5881783410549637818,
+                        6718222383716210870,
+                        1343321995617141104,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3913545336538207934,
+                        5504161081980166459,
+                        5799148088380308357,
+                        325333908911788087,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14880054801997233075,
+                        8810680811835193817,
+                        16778341334244733153,
+                        306580239468706777,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6126471731914276957,
+                        17291474191458357548,
+                        7360759802928582580,
+                        558610689833950048,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6513363640885223422,
+                        2778262583486341615,
+                        5837490431624710353,
+                        1158303411456222184,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1127996520874219063,
+                        2871843175679848517,
+                        12810774471297762051,
+                        567334873629504722,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15226811557395198888,
+                        16968319118852943482,
+                        6659912769335909556,
+                        1141620000311924259,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8724979091872578291,
+                        15219220221724932358,
+                        5143510255102967489,
+                        636569332620086341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9431639304003967553,
+                        2298189422987091176,
+                        3558362822701981052,
+                        38840877072986690,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14109794042234317623,
+                        5008242898751393141,
+                        15250117658779241391,
+                        1209136190233550967,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4871095624287786433,
+                        1081968236993377918,
+                        3845562651280577193,
+                        829497771767182800,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10349184557806281872,
+                        12177313694712354954,
+                        10544241559073850059,
+                        965036365675384022,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2808756067009160179,
+                        4310694066758531545,
+                        1469168205921334567,
+                        515661232128195307,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11833930152446429296,
+                        14589223616499679342,
+                        8772870797122359343,
+                        98342530126680624,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1344026642539558765,
+                        7819536937699967113,
+                        16623729754890510425,
+                        563932531527140320,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2237081760165346599,
+                        3405896916168343678,
+                        144199435090292760,
+                        23397500910530418,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9285940552627649912,
+                        6456634048553194858,
+                        4334292285255144978,
+                        625612545250491499,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14565225458277668111,
+                        10877995019889838122,
+                        6838881616829727876,
+                        804335967906041809,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        433589707694057770,
+                        14272466027003592057,
+                        2114519621386662469,
+                        313887880674032186,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13031753192573779610,
+                        1740279697658598224,
+                        13385242955379834754,
+                        1310584717609876506,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2275713881061019393,
+                        6588725602885933378,
+                        12332420326689701802,
+                        353845828811465168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6404181447493183217,
+                        4695807290487685277,
+                        13934496915392075029,
+                        417453310688334204,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17035433461497262857,
+                        3404875091715547957,
+                        12157285451185028056,
+                        419322166537891876,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1379771351446165702,
+                        6647005228202632450,
+                        1049987388079806500,
+                        1167320954860200387,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        78533653593106933,
+                        2712290709061721000,
+                        7254731675224646753,
+                        950404164822086106,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14202127520547402720,
+                        15120542538578153416,
+                        904943040273457603,
+                        168504935513706304,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13429965640302650181,
+                        9294285110730346123,
+                        7653190367945147408,
+                        413230627029876312,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12230295218761278032,
+                        10116610404265077203,
+                        2162346202734217679,
+                        280401816830787743,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10338099861734648978,
+                        9327645780100308500,
+                        11020228040646823048,
+                        1197776222369111197,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15497123202428387425,
+                        7053253659597824191,
+                        1432460422351402077,
+                        139462758354808740,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12980894786111980577,
+                        4561878560015134405,
+                        15439897699444550943,
+                        1068501235553258058,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12514008620502646885,
+                        14829441980469990076,
+                        3595758028247436618,
+                        863794236865885730,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5118667098335532451,
+                        9503402335363698705,
+                        12476754603310264775,
+                        707224552288387129,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        536951831440471210,
+                        3661048913652878071,
+                        12212396433926511076,
+                        1273620392514937907,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17147027386017033312,
+                        909005550875463531,
+                        17640403853675711910,
+                        857136296827554763,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11775276311456019285,
+                        14445087955768205000,
+                        985416362643562942,
+                        784396804939106574,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5401414813408343878,
+                        6386423721095398145,
+                        6104422752107783407,
+                        548884060868462444,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11748376648059684394,
+                        6713599537890287861,
+                        15507483434982592273,
+                        995922264390960875,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14292358002354599608,
+                        6939408731552564505,
+                        15071256856251914173,
+                        1087178342271343311,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4088431734668200665,
+                        12966616961674390441,
+                        8091852071837178782,
+                        208943094968033597,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6251922694920673715,
+                        997972023955635133,
+                        16263592997589652998,
+                        567789699944720062,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13454155828518537773,
+                        5946317293675107374,
+                        10659294864819777774,
+                        263051539890722378,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12326015005325373357,
+                        5660778052555453714,
+                        10956088984119915933,
+                        207943155487088822,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3768784814818200348,
+                        16521502922571692891,
+                        6735529458539316038,
+                        1113693114512297842,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11623525196707405442,
+                        16278415059907579925,
+                        14425945253270340304,
+                        1116200521033088155,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14520457479488986265,
+                        15940177830190936527,
+                        1183802644896247686,
+                        1083708734053750462,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3116785430041073657,
+                        13665096386651315246,
+                        16684114200754215585,
+                        1231051356527338630,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14752340238769016511,
+                        17435280089040085417,
+                        6170363101558225712,
+                        165791060544302862,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16701279909673647261,
+                        17766457019667211096,
+                        2245622263720663017,
+                        1299391118282927736,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11395647084801492269,
+                        11541921539173282786,
+                        1787590848967327098,
+                        790052669454539256,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5125913467508210153,
+                        2049874832075960195,
+                        5463693959452434187,
+                        516563434065291392,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14009624114841748552,
+                        13342894817222064146,
+                        4986806399805789992,
+                        322004624988933258,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3202758618747017116,
+                        9126001552133885385,
+                        1790570591511486718,
+                        86791641262940436,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12082938465578819248,
+                        11060143382965171651,
+                        6440851168691585527,
+                        362299021291087450,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3037230511474098784,
+                        13471421150805536949,
+                        8226683898417943893,
+                        1084197073692603181,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1392023298787058833,
+                        9318621823862609192,
+                        15965066459185260926,
+                        313681420066504139,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15799288657244189445,
+                        2279620152228926797,
+                        10321988844072477955,
+                        1010055744449482375,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7812427401067661766,
+                        1105954243828249876,
+                        13497225614059058552,
+                        1113732797617787963,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7866084073463995902,
+                        4120175972500492335,
+                        10569248092719784923,
+                        244684867052115617,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18087956759553396179,
+                        12348489279459961098,
+                        13863719747849822956,
+                        251103171736350821,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14623676340010661918,
+                        10744102209921089644,
+                        4849854276056824111,
+                        972822420742052408,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16991749148731068149,
+                        2360959215613753900,
+                        13232891768638734211,
+                        329465483279664586,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12027442706096665193,
+                        15440483372124476731,
+                        15172957736159104512,
+                        444897862579310265,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6891104972094156494,
+                        6500313694950045456,
+                        9092389647759729639,
+                        452597977382681295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16709017919051750794,
+                        7822452954295016827,
+                        18312844444468001590,
+                        1029864006997849622,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        445020823683469336,
+                        7369695531287753196,
+                        14782323817494419000,
+                        28338169399398988,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        900658855444163818,
+                        13962507191987637295,
+                        17014818320439374351,
+                        825392058904988760,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9539145036659601164,
+                        4045697338650921513,
+                        12337216159672222040,
+                        684258179084449805,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        332992278034040472,
+                        16228439142053287702,
+                        4779993379980645084,
+                        338520173654073490,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14983622196623200538,
+                        3610180088270242671,
+                        6076146658363911828,
+                        1250680073325175907,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2596244611702491812,
+                        17150360589142154996,
+                        13103771511843154306,
+                        736857052160661960,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9262021348478815719,
+                        13218726603387328073,
+                        4533138379485609168,
+                        965125436487197600,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6034803464245265990,
+                        6297757251496996034,
+                        6909060328745187362,
+                        208223072657729863,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8290971423922666473,
+                        15129809367072969842,
+                        18215089350270484088,
+                        246973301272155996,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3949129832744890116,
+                        3614206933434727148,
+                        425585515584276014,
+                        62140673971311004,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7032591761989490454,
+                        5861776710194295332,
+                        12804991012278793640,
+                        399846044803887911,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9053733920300821078,
+                        11986918546326996229,
+                        4257150473408420489,
+                        1290225262166581703,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1526042158956857752,
+                        8626292352853014415,
+                        8333842179329093219,
+                        679439792864088286,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14900275430560349834,
+                        7685113680054892888,
+                        17238696752114412394,
+                        632888902614049592,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18372400176257341841,
+                        3050621597683667695,
+                        10436207041236023085,
+                        122022805094917545,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15485684294238855659,
+                        907201016019028850,
+                        7446107629702212994,
+                        1236446350921018702,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18301504940617430352,
+                        4007376991579408521,
+                        3597871906771297823,
+                        730276131683377243,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11454598307253836701,
+                        17432905110200284427,
+                        4451396580215113468,
+                        1087635927275988523,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12844885804924867435,
+                        9070504044473846437,
+                        336709985018921374,
+                        1196700678227202185,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10661290413591345959,
+                        1143041543002001773,
+                        15396250933193595837,
+                        564203683881810348,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6271058881523111156,
+                        11708841032685807060,
+                        9161352711499400430,
+                        765602216643450296,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13863929175002234451,
+                        17378772388104121505,
+                        11203008273819756372,
+                        209535608821454633,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7273073238727037279,
+                        17672415553682707881,
+                        15596666427058676872,
+                        112614582546259163,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13564846797682492261,
+                        6112770812407928280,
+                        18349240888059224523,
+                        155547258519351646,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18253736338641700172,
+                        6872571247516300969,
+                        5807508442340539236,
+                        474172615867037642,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2353444952985914239,
+                        8040600458993063819,
+                        9420348296363409986,
+                        962384282504882488,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10799511191000054896,
+                        17992767171464787435,
+                        1847517820975808936,
+                        283158152219077706,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3927451276852091157,
+                        5296574664047331305,
+                        10922752851720457182,
+                        53069846175757730,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11313206930502226896,
+                        3829148170417908106,
+                        12636400343455064870,
+                        679095514712925382,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17905217765139117168,
+                        1104952100721448588,
+                        2474257726312863880,
+                        1199784325143528053,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1165642345380460351,
+                        7603902668604297735,
+                        16688223426182592551,
+                        184330642692651422,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4311566324918096062,
+                        8262240389739024327,
+                        16663460113751818062,
+                        1106489326168000712,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2789658656671658887,
+                        4260018545292651641,
+                        7574067378316286838,
+                        776452717482072738,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12314453906226549215,
+                        1346835584931797135,
+                        13803166641854961499,
+                        440745406677901045,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17687305833719976360,
+                        4186975128990286396,
+                        4440852816076981797,
+                        1103775876776672547,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10100190448439992136,
+                        11411117586756512211,
+                        18109322632013100035,
+                        127713919634548689,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11265045979304577746,
+                        3725567753428237804,
+                        17007475497633445296,
+                        3478354955553680,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10259707563392221516,
+                        3641453695398204373,
+                        4065045177310493908,
+                        808777846369571740,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15270099936511281713,
+                        4254546640862370125,
+                        5146583713358215046,
+                        554313599480214378,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        44470199930156786,
+                        17076631340972923348,
+                        16213256831512366994,
+                        1206433020071971382,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17252050841718745878,
+                        1366366056966887393,
+                        7015626040787918495,
+                        658310470201484969,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9217712980398231249,
+                        12765135337107299355,
+                        16808878653672774030,
+                        94365006461058131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13001414955159293429,
+                        3612718100152381696,
+                        14561780488384276273,
+                        489474664715997695,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3652216553323089953,
+                        6389333470813504692,
+                        2190469540094966908,
+                        654385838682104339,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12559445203567993048,
+                        4373390275808945898,
+                        10937896159735594311,
+                        29607767839007966,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4593520674591230025,
+                        1504070087545589556,
+                        3963314446690512987,
+                        637952664660580368,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6988561200549180478,
+                        9794448910759731265,
+                        8917963624223625577,
+                        501476298266400275,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6602275251056523356,
+                        4004195187026505474,
+                        11815543180233741524,
+                        122203231381760735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14477644631015565162,
+                        8328851479327553490,
+                        17372655507366099648,
+                        345472659689063577,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13740085347846248628,
+                        16976545976467683902,
+                        6359940139691045851,
+                        520641301911255769,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17544458580790479798,
+                        2887010462620259106,
+                        10892629977290079953,
+                        784631751893133447,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3357953081939959914,
+                        11544837030073198630,
+                        586613553877271790,
+                        871348628150014173,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15778742477474294457,
+                        2843221353727066895,
+                        7650878825875875265,
+                        1107671539734070971,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6935060750853666093,
+                        17202369949699614054,
+                        17426883980510640807,
+                        74910729506397596,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12834412418845131013,
+                        11774340440536022757,
+                        9298228965082528941,
+                        77971280841885251,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9141822531124835347,
+                        12016963454494489813,
+                        2383491227826411540,
+                        440685836494085417,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10866456719102467818,
+                        4211117404449385207,
+                        7984956096061265502,
+                        398998684557698640,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6687794975534938323,
+                        14381709328087584081,
+                        16527829755701778495,
+                        1064596883321557134,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15378985683171677872,
+                        12824399139585616195,
+                        4575143860023977855,
+                        24286422113657718,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6263411164912978590,
+                        13285272015832244710,
+                        13381599435614974932,
+                        650374976000111304,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5953205763275920793,
+                        13586613865862695827,
+                        13368907760994186507,
+                        740358882090396338,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4814867183274386936,
+                        7490076559042098747,
+                        1182838313100401865,
+                        1013226248881902908,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8137157893778890502,
+                        9691144051203898582,
+                        1065953743453846802,
+                        426469153930333812,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10833729044095305624,
+                        15877816427499200541,
+                        6978535354472663095,
+                        1039863311555811086,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9603813503840058906,
+                        10259255267927818993,
+                        2551757769838042171,
+                        287688087380981808,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17128308919246603621,
+                        11678751489762516668,
+                        12707911621367144365,
+                        1152994855803437402,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10914435664191368441,
+                        17788331698562844341,
+                        3340937251867129193,
+                        1013143546942228334,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4823464876526364598,
+                        10908534479211309005,
+                        16075763020914571018,
+                        566197190934807498,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15771002619071594265,
+                        11984958643817742429,
+                        3003996590282367950,
+                        1035877870636706830,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8732938205359442247,
+                        15527435649220813394,
+                        10402061373547015658,
+                        1182835571066346561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14604696213146053636,
+                        733632134921039680,
+                        15986855480762235597,
+                        1101553009492673067,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2421267880719973286,
+                        18007163628369397978,
+                        6228827593017000694,
+                        639655317020349464,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12450971945330835896,
+                        4278936874675299327,
+                        13728577356887275090,
+                        118943353353526561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17601690833295803115,
+                        2536732514129694518,
+                        13487264590558545476,
+                        626345424207008072,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1941553676337922535,
+                        9464547242575144528,
+                        14984644582057178849,
+                        797561169260172956,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9079913419255453489,
+                        10322882080299379576,
+                        612786793331876361,
+                        18554767440386528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15162579569862321256,
+                        6429692818805920700,
+                        9003377238829650928,
+                        699048184529613487,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8589965168982131370,
+                        17465838444417150986,
+                        10495246339577925197,
+                        485040846624391121,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9733321903679255966,
+                        781572867595570034,
+                        3015859400101071310,
+                        1287114052930061674,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2868344615020581884,
+                        6510139883016960598,
+                        10883387407043875235,
+                        477601407041323897,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2324146698558161678,
+                        3561249006961855764,
+                        17772300096655450969,
+                        989228536908418288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6961214279479130188,
+                        10025920760730461244,
+                        11594559782857142525,
+                        727485479759451609,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12966069906441182768,
+                        787351010889175250,
+                        15498343211275197136,
+                        238775195231355143,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9246107860799702802,
+                        3970582124716473758,
+                        1740252802184525878,
+                        1019175937462212077,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3777643863264382679,
+                        5382786067225771510,
+                        4817781030054984576,
+                        1318900967339121246,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5834738338538418994,
+                        12629228782988067996,
+                        13458007271588958381,
+                        604976499982032778,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16417530396485312827,
+                        17238733800795016844,
+                        4439342442360375490,
+                        1244509644722525018,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6739133296200692309,
+                        7665428025519459118,
+                        1931388378452710907,
+                        1057876372717233077,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6754753674254835158,
+                        14961726927070939830,
+                        10719801404298223762,
+                        1218899116422640971,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13992659039052977727,
+                        17267171677153926455,
+                        6520928461692051460,
+                        930018566310812428,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2598104924463535801,
+                        4130236382153084966,
+                        2706427667426593191,
+                        945416482741789720,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18075346512123121139,
+                        1014213800300014589,
+                        10328821142705880968,
+                        9553705216993532,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9620932995220563858,
+                        6760907100881767194,
+                        17661437856406563331,
+                        1302794271111342682,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16974379523251600535,
+                        1163011809424478608,
+                        895452325098229772,
+                        765582568543048515,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8605573701929632978,
+                        13416362593301444553,
+                        2582743814526294238,
+                        393905840897257998,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8849360964014911785,
+                        10406868876676303024,
+                        7841819439424714691,
+                        1153700193093569996,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17625038741624973828,
+                        8368279983661188166,
+                        10090623069963080241,
+                        710800677835072585,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2666523701664079272,
+                        3861826700792950612,
+                        3189874141717607832,
+                        1011940703256183884,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13320236513044379707,
+                        11753197903306541558,
+                        13048526344700853082,
+                        257904309358072274,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17023376933065217639,
+                        97656157239208553,
+                        1382759005172248880,
+                        1292699100071513181,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14102099369837209879,
+                        8976847939440607044,
+                        16550629343020908224,
+                        399239569309689413,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        412958910200920375,
+                        2973443970197431045,
+                        14322255154307993711,
+                        424707106518455093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11118980020065177322,
+                        4201035401855132784,
+                        13780499219540234215,
+                        278818241914840439,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10606552137597677537,
+                        2990839051904777276,
+                        11486466217260880513,
+                        1320339167481512189,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3147252317636108452,
+                        9100193710334494267,
+                        8148930448243714772,
+                        981747613808338156,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13666528263732531220,
+                        9878929161391360628,
+                        4087183761354091713,
+                        444411876300146230,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13310180667146025736,
+                        4377358038197890104,
+                        2959965446961550420,
+                        959058103521324479,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10700280962721972604,
+                        12220359432643128685,
+                        14655300456482564560,
+                        10512938609034079,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10014350570492249941,
+                        6611155465079209276,
+                        7757866832681673667,
+                        526612051250227576,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        493307141249769817,
+                        10454049783811848164,
+                        12833272509156085186,
+                        1013626703050324230,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8976181518455943096,
+                        3069046483748004013,
+                        3742049399218261971,
+                        510187541140510827,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15670061985960205666,
+                        17584255287497345835,
+                        7487062993002135503,
+                        1132705911450101509,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2731023445957956253,
+                        4666758111382704905,
+                        263724228943737419,
+                        1172631538189896562,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7423965861315318467,
+                        1224813465422775371,
+                        10609075467537704559,
+                        140403688782307347,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16238382664545829247,
+                        10046440963383662314,
+                        12705193215299721097,
+                        267010193073661977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4222046091131571490,
+                        425606991958405415,
+                        10594866344330117953,
+                        216216523118743970,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2531931486532220055,
+                        18111696772667567945,
+                        10389516952521611962,
+                        203924525674267266,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        839762025694824600,
+                        15368741333841064243,
+                        11360059680718108155,
+                        1107633164051990201,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14912788432951366631,
+                        7978307266874936227,
+                        8176098919024781146,
+                        127178973631962013,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9055231230969430677,
+                        18180920716082173880,
+                        12031153910569354856,
+                        1141394024180075503,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9575823377303685124,
+                        3654070162621260333,
+                        4895874639789414960,
+                        890623858620411430,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17162835110336303601,
+                        6322765685795402494,
+                        8595195514315219504,
+                        246947138511875542,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8574795766295516781,
+                        17988720257052775066,
+                        10520974786338733048,
+                        1168644137151914442,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15239062941613831214,
+                        15268553111139272468,
+                        5870584124220571720,
+                        217814196849826741,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3124138237753499264,
+                        1761190213717782620,
+                        13417724592901174098,
+                        12357577151973057,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5672146222689919372,
+                        14176844941854272702,
+                        14705414290908025928,
+                        336423482729446952,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11703035743336441146,
+                        1772247492483161058,
+                        14180543615927186455,
+                        803834970020638145,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2286165228961164011,
+                        2574904747189446153,
+                        17581993974512256002,
+                        48168763897518329,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16945405171299468079,
+                        1640898951726939198,
+                        12812909453631910865,
+                        871832449592041179,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6958363398662763298,
+                        11386752453176798251,
+                        10859883554163211634,
+                        160465990412557408,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1798157690521963213,
+                        17518765027840911107,
+                        15503983200744937385,
+                        70387856482602045,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8552246521490196224,
+                        5498963720567082041,
+                        12292321973774379525,
+                        873698133116962560,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7191655119932878386,
+                        11400575183448376137,
+                        15408698486509662884,
+                        412839092664725455,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12290213418544397775,
+                        5502609951298429223,
+                        7942830238321098276,
+                        150598410996131476,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7542382650737749574,
+                        13787189446490606026,
+                        12770740473653380934,
+                        184044329470135527,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17860874074184107933,
+                        8482547003156341900,
+                        17321342185805029668,
+                        1059709617521731025,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5135915594831093198,
+                        3462062441425354367,
+                        9622379697120797961,
+                        911573121445861691,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8052263098333787974,
+                        4420983057914237954,
+                        13334387633011178566,
+                        559842870539050645,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7088841313997185193,
+                        7095118078148842519,
+                        14809938479439477572,
+                        480281069199093341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7665904806772056647,
+                        6238107129513304191,
+                        17848645625453382053,
+                        1081534860814428627,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5912013083265854524,
+                        16863339549866738235,
+                        5213048937446306360,
+                        398620612433807774,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6358317868550283924,
+                        661371264667409875,
+                        4740722019203859914,
+                        780385398356711354,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3255894857460349905,
+                        8989554961091754679,
+                        15166682128930579099,
+                        642777720462663628,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11317546977302158306,
+                        12885944447828841360,
+                        4059207071230936387,
+                        990053335588077144,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11701691649219401247,
+                        10098825662051948389,
+                        5511377344264935968,
+                        731527628086992415,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7782114548598799305,
+                        4338403139386456017,
+                        4426329791970684657,
+                        63867361033539533,
+                    ]),
+                ]),
+                Matrix::<1, 9, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17026403306921046144,
+                        2855405572034529087,
+                        1661674446039328971,
+                        984674498254661922,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3274846498436974755,
+                        12295044372207493481,
+                        9797366835970666197,
+                        558506136918992826,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16572475873576167935,
+                        3452340568640528222,
+                        185697905579625865,
+                        631350880891574500,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15460016047582974479,
+                        1372514510928760440,
+                        12915982897738316663,
+                        1283044503822006375,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16500181941558852393,
+                        7732979025559252822,
+                        6418398622196234848,
+                        528452090147709558,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16012043269432119918,
+                        12822959281529070306,
+                        4290142708295722581,
+                        510590264000949009,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17674660791271944574,
+                        6369031933640705534,
+                        6194434361588268691,
+                        962746698608874137,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10270507918879899398,
+                        6783065312985490013,
+                        8279578532691993455,
+                        56290591869181254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5153688086741909330,
+                        7258959451488216919,
+                        5105692929413072277,
+                        1065105335435667041,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        22441232234112986,
+                        3799035518881103639,
+                        780789349836548724,
+                        268412650062153680,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1293914879304640780,
+                        4775710345941485153,
+                        8471033183625180885,
+                        811672190941271036,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8594394549473523339,
+                        10645501266342357950,
+                        18178869997317741464,
+                        278866667552558297,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6220921764124794410,
+                        10827177577305781300,
+                        17569370803172628479,
+                        33478767828431371,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9218771003581596401,
+                        7584367402220131040,
+                        4682727734357677672,
+                        1291714342162683203,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4219288248971473478,
+                        18045295962176302167,
+                        4686625907008311977,
+                        55797946380718953,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        108334751330443434,
+                        17860887709594633151,
+                        13979335019469946020,
+                        980865491968399390,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2809343791818564545,
+                        2759683716479272601,
+                        4595644873306541379,
+                        641135718527980277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17684391756717204689,
+                        9806787052492077582,
+                        12961568506985873356,
+                        1334704723492118620,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17203437630529235170,
+                        15565107354261760900,
+                        3185166991250917857,
+                        703398272020157125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2018461072795262146,
+                        10780809913799908692,
+                        17117052343253236698,
+                        904262863655787561,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17520705258786789707,
+                        4953900172186399345,
+                        6358198801253955400,
+                        833976737542429091,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15403558193560700239,
+                        11165429256304860819,
+                        5126279794638933247,
+                        738589397071432474,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10807263122125602463,
+                        11951610060884825787,
+                        9155891786509006598,
+                        412004351929897290,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6081215651271442384,
+                        2335695064021625608,
+                        7880408212100736281,
+                        111530646820939073,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4518182234389901567,
+                        4153271999152875887,
+                        114569735457543939,
+                        695255947270240465,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7442138989960238681,
+                        17025274622197754841,
+                        13817878480092568752,
+                        700407544361735785,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1485072147976908115,
+                        651228649745952647,
+                        10236957397968452900,
+                        361303986599933010,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15563494164823908698,
+                        13983050576355565978,
+                        16312335884709916021,
+                        1254105283758406535,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7652291228952239707,
+                        392919899107083995,
+                        17234129777158551710,
+                        1278438123582119114,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10700281315759576464,
+                        8514960992362918485,
+                        326395390647171025,
+                        933334390800576009,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11998277267785973403,
+                        15032555105672581543,
+                        2502368166735184540,
+                        994251764081265542,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8481603438377773988,
+                        18347713664390048549,
+                        1084409717121912316,
+                        303219125401501686,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6966116885803494341,
+                        5252958131428959920,
+                        8402639715137438526,
+                        296422643097928434,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15811079226009863367,
+                        8555651543412765925,
+                        12388647694594007442,
+                        1163210241633302789,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8120876950883887292,
+                        13833776717284973904,
+                        7908963785254836473,
+                        147781322853889180,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4069603370404416051,
+                        8834560775626712918,
+                        16567122342043058192,
+                        193009077884347567,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9126655254192951589,
+                        2065750245689076263,
+                        15534780597621783305,
+                        978584179735138041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11730840652116390283,
+                        8799872639733161982,
+                        4344698374990239804,
+                        1010828600198346179,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8987300977881955335,
+                        243851609730558684,
+                        8984290612399407719,
+                        122686936308787230,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15697622878886322732,
+                        38608836123252962,
+                        6971464295875470542,
+                        386406314664232039,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3221001425656512257,
+                        173685460228681050,
+                        14633623689779823066,
+                        464395773774107393,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3886683410877816692,
+                        3674122403877866519,
+                        17635321116339115527,
+                        855409222095383005,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1694444909468508004,
+                        2212593935575124012,
+                        1287495601852180793,
+                        1122265879503349956,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16590472962432962951,
+                        10456123712477976536,
+                        11913316785265810283,
+                        823502380884999950,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4644140834518366415,
+                        13787542440834082041,
+                        155111770386229462,
+                        228837913451795275,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3410574541284491032,
+                        11983483733099363585,
+                        6864408855105808608,
+                        1342806017897606516,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14927941818016129627,
+                        3128438045215557166,
+                        5548711939681979058,
+                        66818054559488277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5933935458948034041,
+                        14223825169746229602,
+                        6574116050603296616,
+                        992528585692093760,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9047664566098041072,
+                        7839578420429221159,
+                        15247688030058875118,
+                        25467296281636307,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14039595948065116149,
+                        3039187292342289650,
+                        9465621105440966322,
+                        712566489177063427,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5129141382628022897,
+                        3790923620915445235,
+                        5719647161985792897,
+                        367613037221981721,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18327376068078819345,
+                        1472460577482814291,
+                        11492286877766675024,
+                        313959422642144225,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8997324167069938708,
+                        16238244225940229548,
+                        12603297949512871365,
+                        839376271495814492,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7300674364341315316,
+                        5536804721360940277,
+                        17082043595803991987,
+                        1113879227371716268,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8662864443485756183,
+                        14375864448858069730,
+                        6148227883677622751,
+                        136056574915655056,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12619369181960036406,
+                        5014165031046956698,
+                        328809193341658656,
+                        1096182075095107787,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18435433805055718785,
+                        15279795307961315042,
+                        14355021675912940660,
+                        182701793793609517,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13800461640032197376,
+                        6990591898639745659,
+                        606253865211455289,
+                        153298186252158532,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3357458474457787303,
+                        16481741075196510905,
+                        11680237506600874333,
+                        51994241321798573,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3369828085828279596,
+                        7733103773386390567,
+                        7026727208084186711,
+                        1064003050388013467,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4740678217356364877,
+                        18072631088012217767,
+                        3914189467980502072,
+                        658593093859432753,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18396461234468657421,
+                        5491046522991323412,
+                        1749429327751607014,
+                        959035135069210069,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17837281850315244589,
+                        2858220201904596765,
+                        7256213540062386246,
+                        545588592018225153,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2824811523351093541,
+                        9094343610290734521,
+                        5309815545929731243,
+                        773072642481492483,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14093016574762180366,
+                        12024043935016449575,
+                        5742001620658866330,
+                        694866959928713198,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5661671638292256886,
+                        2614460415190480871,
+                        13189448453617727696,
+                        612411643161563647,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14622366608609223702,
+                        15071319834878848410,
+                        16963561100774740409,
+                        690433159478426826,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17097466248901628764,
+                        17200839604916907935,
+                        1086801589470071477,
+                        124514312259943247,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16037588478495718911,
+                        5722604910576331390,
+                        3070746469551857687,
+                        299445871189724024,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15755999680613799636,
+                        10055449213537765299,
+                        9352325783769947202,
+                        1092820330348816641,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11639256556326651630,
+                        2309773356633913097,
+                        7848259970023422728,
+                        13585007804102193,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4657176889293554825,
+                        8791982182896226755,
+                        3631053622079781209,
+                        435995181645022746,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5477544711166384492,
+                        4817892053924654072,
+                        16418302304825044419,
+                        628620628843274320,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13870459670351577167,
+                        11037052110516579145,
+                        8186697280035494906,
+                        1130282869711953027,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7062618300049389582,
+                        10286925011196031472,
+                        17333557367748957100,
+                        540164512154486811,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13965799473079638890,
+                        13957923210773506765,
+                        6642192978601453361,
+                        1232549773654830834,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9574759587961059524,
+                        18113460782257042031,
+                        12689190797265738577,
+                        834544586567040070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2484213839083302749,
+                        6291958626727282226,
+                        10277400137076986645,
+                        641106775032693586,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7899603970586518006,
+                        4546458674031131501,
+                        13962166836592159637,
+                        812020378515756007,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5587996614101258499,
+                        16232123105381772539,
+                        11948446829150510404,
+                        71311525263883061,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5937683815946450401,
+                        15096349495831986437,
+                        11535614216984999366,
+                        1058487282322076453,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8432036300613581019,
+                        16688689171947446200,
+                        17354549716140527936,
+                        1250622022261613896,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5159500697667575830,
+                        18103007904629773804,
+                        13118209199908850603,
+                        1286704615636749416,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17335157346466844827,
+                        4681255824368763713,
+                        13067720071079514490,
+                        1262683058332809728,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9594400875036042543,
+                        15183882488469731575,
+                        14618642261883608179,
+                        605874483998121663,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8916228722982959407,
+                        17983594043845512250,
+                        18098899297823150694,
+                        1022866911166671369,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15376089805320348777,
+                        6642687356474671619,
+                        9417053563372515680,
+                        879645995500231584,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4530984047826054881,
+                        7542473175044855484,
+                        15202624741380705504,
+                        135680766084222123,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3285048810264646823,
+                        14700570851237763434,
+                        11840777178977786130,
+                        305050876621100505,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10265796613566144612,
+                        14418297502139275625,
+                        8882066261877696302,
+                        1225783590666815904,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17119237487269372760,
+                        13215935674214065160,
+                        17959354847131768109,
+                        799464604744755716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6134878470311218527,
+                        11750624312799638528,
+                        11247133370091547067,
+                        964806841373349296,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5394098073345408850,
+                        2851083347986689300,
+                        16147996023684875745,
+                        316458074260588454,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3578029214417847535,
+                        11982578457717577242,
+                        11782678056405538475,
+                        644713315298219223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13601740983722042437,
+                        8685670895451590085,
+                        15412615455177530885,
+                        1052150177278503015,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17676640061538401630,
+                        8514087588254669918,
+                        17655010788763795638,
+                        172255190805335981,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8289678658083516223,
+                        11087513482812487944,
+                        8978822246322746901,
+                        1004740636380147542,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3863737381509194504,
+                        8830117372866933974,
+                        13336780440988155516,
+                        80559698506125179,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5938180478505861963,
+                        6157687348832244178,
+                        7566490937964283021,
+                        457004302547230409,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1229320547804236694,
+                        10726029821716941006,
+                        8629045933353421174,
+                        104834023288102109,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13986895662075138679,
+                        1963157127574203238,
+                        17162070196889538990,
+                        694485110239642849,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10207549608392661348,
+                        2709102564228850202,
+                        3066852259181616928,
+                        680310160094410118,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13737565692102355615,
+                        1077444693199602743,
+                        8730302353356541774,
+                        907714810427079398,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        53218927021488615,
+                        2363017512436680088,
+                        3767312630574814676,
+                        710737278267604935,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16449850966220661816,
+                        2012278318368481831,
+                        11762342359739435180,
+                        387441654158420200,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5302665320010855924,
+                        16255183744025369844,
+                        3371378062562623774,
+                        1281231816703590413,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2022085244091912752,
+                        10404960831196787839,
+                        12309250960456935367,
+                        194977870575854341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8457321389645956676,
+                        4532873755860354647,
+                        13129316783227508992,
+                        1263685437649399865,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5915314867344442907,
+                        14485236836540661167,
+                        3166572002950362521,
+                        1009672388908072025,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9756662702417540239,
+                        9358148403657459224,
+                        1856697297522199291,
+                        28905245265109096,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17999900385189804612,
+                        8768124417092145238,
+                        15384838427260076426,
+                        4100780911037670,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16057237116803306962,
+                        3451553279616400450,
+                        8279144754496749760,
+                        27821833238840717,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13731425973251212010,
+                        18223957055672852407,
+                        16874419451327536147,
+                        647194462589917284,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4312404685996641313,
+                        11212450080762974270,
+                        12951384303479018516,
+                        288840314847587614,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9366374891348301952,
+                        3774266132832173137,
+                        18106541030088207804,
+                        539324079541334042,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12831789499302718089,
+                        18092000744129844497,
+                        5269117297764563801,
+                        1053726191079051832,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17831336130731608366,
+                        11433585231466857240,
+                        2995037481866452393,
+                        1211238240363120556,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11279368055245616840,
+                        11719640283953713821,
+                        12987709223560582832,
+                        325490736467827743,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13642310336306536913,
+                        3139958847374477533,
+                        6583649400383141960,
+                        633379345982471384,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7375964649877270491,
+                        1943045089525705782,
+                        15627422866777105642,
+                        381393968475547630,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15383733788337882531,
+                        6336517067002301222,
+                        13611171183121731611,
+                        791740309370641473,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2614103390390649867,
+                        168282890281804770,
+                        18273030159919559703,
+                        45607115020467237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10789310084174703673,
+                        10537076804577552208,
+                        13967283181814351876,
+                        18261638019024796,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3575586996121035702,
+                        492791686931447645,
+                        18199197899604409643,
+                        35599537215193421,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18409423611055749207,
+                        17750056339096817876,
+                        952909044125435873,
+                        536966930101257473,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14120591460212409471,
+                        6603017755031768335,
+                        10649443677928674774,
+                        387737609071604815,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14413636387068109358,
+                        6014295802856149715,
+                        17321564225294636376,
+                        1028640318399050429,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13230790789253596898,
+                        4515985256934033067,
+                        22435982848905912,
+                        847108123125981745,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9660669410242065313,
+                        11754274298264992318,
+                        373780580422833521,
+                        1303727385915154912,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10831609010178203321,
+                        12626078451607782833,
+                        10332911434440013878,
+                        362906478348073996,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16963662087455222034,
+                        10051793893423550742,
+                        4393335693810873514,
+                        519313040425265238,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12045836335091852761,
+                        14866237105986100850,
+                        4723780741550643899,
+                        424091670772134686,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7223602930314022184,
+                        5799719164430011792,
+                        17929403733007713743,
+                        211016195692892070,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9165493635279369245,
+                        15628777314862299266,
+                        16563140905030484884,
+                        1080721130814508944,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5844038010863693598,
+                        571243826012689477,
+                        6021613027686930705,
+                        1293929938997230442,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12068307277665606631,
+                        11524603284832382626,
+                        3861549669082568336,
+                        909317871706169646,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2664146522247963376,
+                        2796274953288260141,
+                        17703360508182430463,
+                        239492460448313000,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5999724782520435821,
+                        7748905729425047284,
+                        8605849139853809174,
+                        1294123697886043239,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16163215402680945316,
+                        5071258854663705958,
+                        5672677118359121031,
+                        11731486485694411,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1021304531622144483,
+                        887849313982360786,
+                        9982102708786309997,
+                        433508871717038139,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3601139484394811648,
+                        1675897540474682709,
+                        1656605215283328555,
+                        1091681290963533222,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16511074010363144356,
+                        591582629761919757,
+                        11068127802715146828,
+                        155979742875246891,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4488138203928308399,
+                        13225858866692119098,
+                        2319462163766052007,
+                        637735991149528552,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16922810273090570726,
+                        18081172800092631139,
+                        5193280809806306129,
+                        138320180672714830,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7280002629687608042,
+                        3796221359819943990,
+                        12863404832658014789,
+                        415552752149514788,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1230131018920197552,
+                        6325632808309014518,
+                        2627942416448030491,
+                        962922309086986563,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4056985110920116485,
+                        12808914409236037922,
+                        5269831798147823546,
+                        194564802595503435,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9104649958826050481,
+                        1357371501760686365,
+                        7351934570756469229,
+                        516987885805351856,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15286479174713947235,
+                        16806570061029461999,
+                        3138327388953010612,
+                        319460972106047180,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18102661314513669501,
+                        5851543657308655050,
+                        989100047985988500,
+                        8165330913044650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8874558667068105774,
+                        11764523909052333345,
+                        12523170805831434826,
+                        686203021471671470,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12015907883300346359,
+                        1130273846818802330,
+                        11504436501439345721,
+                        575147596739994878,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8202204698629908715,
+                        4835196297891278826,
+                        14337642069277969702,
+                        1271022222232792309,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5590186953316537383,
+                        1887330738567976434,
+                        13989443720405000295,
+                        1004015280033109264,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16658692627699239173,
+                        2373139212892237253,
+                        2685123959749248184,
+                        178758314513863360,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12706879495137514702,
+                        15684375043625523459,
+                        14335747735330293835,
+                        488774270764248271,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2454234935837992904,
+                        9997132879205344749,
+                        6176492603137131885,
+                        297617352350594553,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13154294774563687338,
+                        8725095832165153600,
+                        14342628309853312921,
+                        852109763914468778,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13294777820645604574,
+                        17446951706394792352,
+                        7252346706754592331,
+                        1058563513717759446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10992981058631174408,
+                        7351644421803424425,
+                        18242183068654417273,
+                        892489086525764315,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9018472988949435437,
+                        3321381105566627213,
+                        10536782174604418971,
+                        420599773528149103,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17983224867517555231,
+                        15880981149483651852,
+                        9262521556394256276,
+                        1110777771366635075,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13886004780590858707,
+                        4803853017534754056,
+                        7495559631606917083,
+                        564764317621749614,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15772830459356651749,
+                        13556371761814665964,
+                        3804571156773496144,
+                        1200987620916816507,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6675978544774401658,
+                        15394971031873189207,
+                        13687634939734563226,
+                        175188063532961676,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16106845474367880618,
+                        18361099486658639383,
+                        797780088335819098,
+                        629338723512189027,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6969118084496145649,
+                        15453121626190877143,
+                        4470714803384194071,
+                        638370062648163110,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3652664347050893526,
+                        13875941898182331451,
+                        18213025317519429506,
+                        73812759891187041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12523744031505419923,
+                        6844954549478518237,
+                        1961118366404806575,
+                        1170556804525834525,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14096538721227022248,
+                        12148786483960010201,
+                        15606359233426931230,
+                        130863640989542907,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16096016159093006750,
+                        6124411462010273539,
+                        854135379343898248,
+                        612988788784380517,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        527421141522286247,
+                        3047356000552729802,
+                        2125330817565988936,
+                        860061983571382821,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5394068341813406711,
+                        3782143995533452498,
+                        6990407514113023511,
+                        567795695092237979,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3567809738152250083,
+                        17402076933568475983,
+                        3587190613372542814,
+                        21248582605428174,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14801430588634812806,
+                        277868116419808919,
+                        1862281068787688000,
+                        620187881780918414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18312613112639684888,
+                        10929536679043400754,
+                        9230115403063880756,
+                        85013313482353358,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14762885454231076134,
+                        11374265464588267309,
+                        7250265463091526616,
+                        1243199193156619366,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10396171452360571721,
+                        12326843006237370930,
+                        5536433293438132282,
+                        271868938727933136,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15112835894458694581,
+                        5829716214471765789,
+                        13800189399023728639,
+                        501952804315701657,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17303229445677432078,
+                        15558687420072303096,
+                        3009807092064788583,
+                        1233699390416731354,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11418509132093640066,
+                        4667108778357412242,
+                        7435483010607831227,
+                        429826324662395537,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14287317352698220791,
+                        16221273453559743330,
+                        3671569468089725227,
+                        191065993286658579,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12917058440541988519,
+                        7165146432991627345,
+                        103181631311996371,
+                        309902530742293524,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5207360325818649360,
+                        11527169453834510458,
+                        62760717649417799,
+                        351564354986590500,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5673009708860956323,
+                        17193771988277812553,
+                        13059401729151854086,
+                        50538655660991476,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        507691387365988240,
+                        11105763092331716041,
+                        5988261934655274437,
+                        533595006739673644,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2491065635225845214,
+                        13477895138957145347,
+                        14984599236792645950,
+                        597514457393951911,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4585953725825729065,
+                        13450138441366771075,
+                        16223137088058738147,
+                        829984782572377450,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15247803761040323288,
+                        13509406815139323490,
+                        4725864126933851707,
+                        690847378635995143,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1299736551139528971,
+                        14146408683402860129,
+                        12817508527424907189,
+                        627519213260884591,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14550981591761093350,
+                        17813563771955880596,
+                        12968812685113144801,
+                        306348157087422135,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16344982761302924699,
+                        14008657849199343580,
+                        2108670325422987163,
+                        509552070883424817,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8874429007156004370,
+                        1367857167238374358,
+                        9230347689030584760,
+                        57712589799889827,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15655521634853819703,
+                        14118431357958599926,
+                        16891263007368454876,
+                        517301108726093834,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15252409796052642302,
+                        16039465166566532508,
+                        8889693919339491616,
+                        34109433198882550,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16597664676004377039,
+                        4225376959274497387,
+                        7026029098616345023,
+                        1211195927386180322,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12585075729095032025,
+                        15062421809442736657,
+                        14958274129637896767,
+                        545441170776494949,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8566820059657748421,
+                        13804135371859252483,
+                        7396960235400142766,
+                        979752914460713441,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        587095125736225389,
+                        4306694421567842381,
+                        8837277205007735192,
+                        1047503339547962455,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14744066476891535271,
+                        15383566579671875929,
+                        2687354582200727164,
+                        148201693334598439,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11583633824337132234,
+                        13889367551102580071,
+                        9249703381446319570,
+                        510750672410211732,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        941056211206668286,
+                        17214469776987427304,
+                        8926573087697010324,
+                        285106579007339728,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        480733953185098853,
+                        931975066976191526,
+                        5188155805559268912,
+                        847867746286903529,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12061919374237650099,
+                        2584688047564047849,
+                        2245550260990048767,
+                        520466195474215630,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7076214775799306864,
+                        17407948374434659643,
+                        5884328782701041803,
+                        1301753679686299606,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6920514507807191057,
+                        9379408031602401220,
+                        9636337292669982352,
+                        1128075879122186572,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1355852321825739115,
+                        6934337605810239070,
+                        17592242537700116696,
+                        881890813874007244,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6978435680754141266,
+                        15755013162384923546,
+                        9184367108984865050,
+                        310944018888189834,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15964633200536506904,
+                        4109630251483301559,
+                        8254601673858786221,
+                        43489236204632780,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5092506484528036363,
+                        4458382561246196920,
+                        6059349197439639523,
+                        305803703594663785,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15061626520324379441,
+                        2051084881287403008,
+                        929725434321904105,
+                        124420349260090207,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9907104746661611600,
+                        10379243920802743054,
+                        6240243776382502133,
+                        571231462495348945,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7903021267613831124,
+                        7081766638279530459,
+                        14962847271610889786,
+                        958107989176598698,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        645478099477172785,
+                        4481630771681613396,
+                        382508229096032945,
+                        258874130260685729,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9793486991509828581,
+                        1179426575851819545,
+                        6646257825461108171,
+                        625823189828225644,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5253626530678907510,
+                        6581656569851546692,
+                        872292554957448615,
+                        1087988635148365548,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16104504324390722668,
+                        3141630892341158404,
+                        3678952390311351962,
+                        544611179503608045,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4399975880135698701,
+                        31201519146841265,
+                        10916022144939738174,
+                        853119658270891537,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17297413712735299856,
+                        16833149380357490448,
+                        11810443361250010470,
+                        1289178197576192731,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10321905170642269903,
+                        17885977731791583709,
+                        11175318481185280049,
+                        756520004854966714,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3239568117899384042,
+                        14215694263380561750,
+                        1118169363882153839,
+                        806462013440933998,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15280241369309730112,
+                        1616841487596597531,
+                        6367110554760509766,
+                        344578600949893132,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9660051926233816449,
+                        11024336252384700043,
+                        5335602347499286284,
+                        1127238168393176568,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12423228038181746299,
+                        14468342104781180778,
+                        713905591030832868,
+                        1338529599815543980,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1523866383929436827,
+                        5040279344810542284,
+                        12236033827249488969,
+                        143551231187190125,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4455259788496685100,
+                        5997725618597198527,
+                        5151370344200727064,
+                        761255975889220985,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        527362259561231108,
+                        13364949102297041972,
+                        3638878146878054918,
+                        1302097861812294328,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13834822828595038797,
+                        6109173477830929918,
+                        565061771997307706,
+                        1199129128460046967,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12413049113320927699,
+                        9033595405487956904,
+                        1166441948164581973,
+                        1053175355814204593,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3899448787970456592,
+                        15814845982013390679,
+                        5087420759075147718,
+                        1221370835145871678,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13661417823716588190,
+                        6957333254998388668,
+                        16948828667962511082,
+                        775203622438255204,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17959047167703172414,
+                        14880482755181798356,
+                        7596987524828459285,
+                        1183997810781713378,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2744554359497202880,
+                        13271005389580488238,
+                        412402808413163722,
+                        648391487499591719,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1349441671274955745,
+                        7530378729127228225,
+                        9828798649086553791,
+                        921208661420375472,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16193138414620096414,
+                        3521630910083938698,
+                        7919202768778392508,
+                        713751961147463147,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13127418850557717690,
+                        12972770216235279686,
+                        2334779928065219902,
+                        554137556458270027,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8038013871101286583,
+                        1309891588164989597,
+                        4303833616233223777,
+                        1244540889447196844,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15951269621120369173,
+                        12699268221495820702,
+                        15144282570638719593,
+                        682987048115251922,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16127659978825934496,
+                        3741007374897487719,
+                        5865754892303202166,
+                        1326172183189216777,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6170786692459637669,
+                        3575185510082904462,
+                        964950138790002653,
+                        350304524572752357,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10693394972172827150,
+                        13876595901577099540,
+                        8849361893827375030,
+                        702347973846733463,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18283307554717049975,
+                        6016641383190238586,
+                        17705844963295116110,
+                        910591606253706862,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4024606542898876141,
+                        9849572115155211020,
+                        8562008735593955567,
+                        75892693351870415,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11204538642053923479,
+                        1010835074648653707,
+                        8991502012719751732,
+                        412577470977933849,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17586558239300313509,
+                        16775029066333429916,
+                        12167269805322080245,
+                        265318352851622188,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13538708474798558418,
+                        17588797261888642112,
+                        6533960888500635859,
+                        693294153216111237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9027910155873523242,
+                        3333117915094545547,
+                        16096427220954266596,
+                        1303788838102789402,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18261425036410699371,
+                        4059930055394972364,
+                        16763181484066271788,
+                        269022690061972519,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10169722201999802509,
+                        16363239560170380267,
+                        13216315902966589360,
+                        910355556639953398,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12382216067300527255,
+                        3368114412605177838,
+                        7383680688110793481,
+                        435946618641206320,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13376147611538239856,
+                        117144932782085745,
+                        4583912356373196814,
+                        1295269787911247598,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10655236433200794801,
+                        9444663591571180046,
+                        13338571336153850919,
+                        911343820175027039,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5739929774262582874,
+                        17101979521702776394,
+                        16535815071980867101,
+                        775430334273433154,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        674760365291143789,
+                        14538884062324267132,
+                        5998970388450282281,
+                        215560608051379421,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2901874608013947553,
+                        10052364611112631761,
+                        2083147672952857114,
+                        791438858833111516,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2771544651517739330,
+                        11626597742373015759,
+                        1247426634404989706,
+                        426827868291306341,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4242779589500541684,
+                        4651104156294863256,
+                        16632436355522698686,
+                        897894131244248717,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18046650087244780872,
+                        5185362173513126881,
+                        17404842604438664342,
+                        564934088347206609,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7836811139333993591,
+                        2311948492697583926,
+                        8419960152713097746,
+                        1307299740033699354,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13285448913339348881,
+                        8814719737738492791,
+                        12852566478817651185,
+                        1105528762288174333,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13676030359462073106,
+                        18432377634411143163,
+                        139298322130817762,
+                        125723818621720697,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2884095447705991234,
+                        5336702873713935672,
+                        16065897558372434397,
+                        162201422277605762,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12277734492197114658,
+                        16237544931185963085,
+                        3452268117191783536,
+                        1010470275988073247,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8780884424080109073,
+                        15088914467521872652,
+                        1573161866568923600,
+                        189241964449201089,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3762538536479286177,
+                        14603293881442416811,
+                        9526397993572365098,
+                        1004251265481139259,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13097370725048276807,
+                        1774171859682189866,
+                        11865360439172278415,
+                        572792711905026752,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9507048234632077924,
+                        12055751836261596627,
+                        3302166331323431088,
+                        6002364309861554,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14748332159746757611,
+                        10650822121259020136,
+                        17338394555555800962,
+                        31108983886488690,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7132466198380895968,
+                        13493018851926098967,
+                        3162381123157642520,
+                        701996098891872205,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16293338635979202224,
+                        14517874861454633149,
+                        3700382475967728137,
+                        290816053504259826,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16514485522030337051,
+                        18108578684625308959,
+                        1460735413241834948,
+                        654286350052296594,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9198705633604590108,
+                        9711385832119521107,
+                        10892806849034105338,
+                        224619082998510295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6273310553802791405,
+                        2311806969992032272,
+                        17700166632976553190,
+                        808541192418307300,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15179775812937116596,
+                        3890246577116425487,
+                        5533716368934751256,
+                        887848852893300033,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13184301715839063948,
+                        4074153753175666809,
+                        17589231136285725539,
+                        190754994912393374,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9277645530513000107,
+                        13104692385038071670,
+                        16163022005453082184,
+                        656554054104183665,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11551850743393144874,
+                        4909699142580093053,
+                        14472428962390732141,
+                        929622493610343945,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14753849771889767007,
+                        10494681513797145560,
+                        16333727575516804934,
+                        79284389074911529,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6364880094293961824,
+                        12368063662930621785,
+                        11622630791259822332,
+                        681781489946848592,
+                    ]),
+                ]),
+                Matrix::<9, 1, 9>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2353984069396919914,
+                        9808979347430158797,
+                        14837341844622769706,
+                        309584843128335593,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3448720563462504487,
+                        13679826294613756607,
+                        11265554260601176035,
+                        485455757562926180,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9619210315148050168,
+                        378943148324593362,
+                        5236572049525514944,
+                        323210805869568440,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5214852437734152717,
+                        10727669839763259121,
+                        12300335249478642701,
+                        31924568265888673,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2253971146751193038,
+                        4494829896773667987,
+                        10451379816229533988,
+                        1216831563956406053,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13645633220592439336,
+                        7271596374998136176,
+                        7194026141668833159,
+                        197187870796934665,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        292866071137107892,
+                        18370462290414478220,
+                        7966837296075087829,
+                        1043444730108791423,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10028962150143085364,
+                        10565587836589921812,
+                        10355861897937610504,
+                        1203183824278892891,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16617675142809487736,
+                        11586822755095793801,
+                        16603331532519404343,
+                        12248809116043138,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<39, 10, 390>::new_from_known([
+            Fq::from_montgomery_limbs([
+                12281176924709909641,
+                6424249400884933270,
+                16417602090625824001,
+                234323863735557382,
+            ]),
+            Fq::from_montgomery_limbs([
+                2287977886932182805,
+                8616110427403804851,
+                7877170773598988169,
+                757243470739612326,
+            ]),
+            Fq::from_montgomery_limbs([
+                4059349993927663522,
+                13814111220690981182,
+                12307061201507458357,
+                62167591881446035,
+            ]),
+            Fq::from_montgomery_limbs([
+                666018570100989600,
+                18072993762380764755,
+                16738103721481031830,
+                656832286912586135,
+            ]),
+            Fq::from_montgomery_limbs([
+                4418989821420376381,
+                17796414275961279928,
+                11198953521760786702,
+                457159332203806008,
+            ]),
+            Fq::from_montgomery_limbs([
+                81260625048574615,
+                7914808201987407181,
+                4948158294286861652,
+                249134449773293270,
+            ]),
+            Fq::from_montgomery_limbs([
+                11602368608394833934,
+                5755586560673210108,
+                6212057355912776694,
+                922856438873310000,
+            ]),
+            Fq::from_montgomery_limbs([
+                17814653184896454720,
+                12550174325120927637,
+                10164557207417699036,
+                1266835034158223961,
+            ]),
+            Fq::from_montgomery_limbs([
+                829109742552018352,
+                15122133246989233113,
+                2568663843065651826,
+                387496886948047096,
+            ]),
+            Fq::from_montgomery_limbs([
+                15002050296182720926,
+                9200751043674577367,
+                6987423913153883233,
+                114193818408746152,
+            ]),
+            Fq::from_montgomery_limbs([
+                7243456654579666321,
+                501022540675516962,
+                3573138200879257244,
+                240290553435205923,
+            ]),
+            Fq::from_montgomery_limbs([
+                7934486043694699707,
+                14863133019215871426,
+                14556028476521229569,
+                858209589091726662,
+            ]),
+            Fq::from_montgomery_limbs([
+                13733217662111373111,
+                4842541414460582375,
+                1375040453142767718,
+                665814505641957281,
+            ]),
+            Fq::from_montgomery_limbs([
+                2998187583700112881,
+                15102166748512677325,
+                13560292381659029811,
+                584410444537183008,
+            ]),
+            Fq::from_montgomery_limbs([
+                9517439486319555247,
+                13638871389942961139,
+                16522780275982957082,
+                900778072398431062,
+            ]),
+            Fq::from_montgomery_limbs([
+                4552459231078500597,
+                6894080431397274841,
+                3110316858644377105,
+                791486926963016322,
+            ]),
+            Fq::from_montgomery_limbs([
+                5607593374026253244,
+                15761043532537496917,
+                1137042188485169146,
+                643006159564234363,
+            ]),
+            Fq::from_montgomery_limbs([
+                7015930589662383805,
+                4557642136049263682,
+                962594582129517425,
+                563349296047607222,
+            ]),
+            Fq::from_montgomery_limbs([
+                16824317191956908965,
+                3330828635599956555,
+                6146610777021208625,
+                86823888941026452,
+            ]),
+            Fq::from_montgomery_limbs([
+                16025175272381128447,
+                18173632225672526565,
+                10436370159331553420,
+                1273103148235706456,
+            ]),
+            Fq::from_montgomery_limbs([
+                867095923713527808,
+                13202048167956820469,
+                2994016504893311201,
+                675242191495982320,
+            ]),
+            Fq::from_montgomery_limbs([
+                15048313635311114741,
+                9931417064887097859,
+                12340641932419288944,
+                681242061225412873,
+            ]),
+            Fq::from_montgomery_limbs([
+                5402186434032964028,
+                13234274208027670010,
+                18380510966962530926,
+                821260563685665271,
+            ]),
+            Fq::from_montgomery_limbs([
+                14877963953078108488,
+                6324637919111504030,
+                89730208329156831,
+                1244789094174821590,
+            ]),
+            Fq::from_montgomery_limbs([
+                5338819167417770307,
+                13267677190765269834,
+                11691074212128667744,
+                269718109075204597,
+            ]),
+            Fq::from_montgomery_limbs([
+                6743496559256925037,
+                1805295689457084589,
+                4519232289568085907,
+                712185148664504828,
+            ]),
+            Fq::from_montgomery_limbs([
+                10787249040234103237,
+                18043256471477289559,
+                11599734279584507040,
+                1002614927160812179,
+            ]),
+            Fq::from_montgomery_limbs([
+                2083086100340287265,
+                10612954369717460107,
+                5923993409348849191,
+                751951379085690198,
+            ]),
+            Fq::from_montgomery_limbs([
+                3710002326172437973,
+                7001296599672823447,
+                8297762681148802346,
+                288684460641881929,
+            ]),
+            Fq::from_montgomery_limbs([
+                2043992827660229294,
+                15257079092859365609,
+                9725666085649959072,
+                671972932151328659,
+            ]),
+            Fq::from_montgomery_limbs([
+                10033412611981252610,
+                11811880234092854741,
+                16033594048822908946,
+                472082281121920404,
+            ]),
+            Fq::from_montgomery_limbs([
+                11516154326633033675,
+                12803604439720956101,
+                18082718834190787478,
+                135374488411404071,
+            ]),
+            Fq::from_montgomery_limbs([
+                209771447195823290,
+                933714282539245476,
+                5029373013638349497,
+                741243393714391518,
+            ]),
+            Fq::from_montgomery_limbs([
+                2036542585013439908,
+                3002580710363723427,
+                7263983037309199966,
+                1318650345065122972,
+            ]),
+            Fq::from_montgomery_limbs([
+                3759692547630496127,
+                5169214329047576768,
+                11816421620919662448,
+                545583012470938177,
+            ]),
+            Fq::from_montgomery_limbs([
+                8664337012098000642,
+                5799771267191841235,
+                8249141371540071971,
+                370345474057616126,
+            ]),
+            Fq::from_montgomery_limbs([
+                13411290199175628629,
+                10038996896644529243,
+                11947263012875073479,
+                1050405225465388504,
+            ]),
+            Fq::from_montgomery_limbs([
+                3013922637634451706,
+                17350372338959868978,
+                17491021125798821054,
+                405969455674430226,
+            ]),
+            Fq::from_montgomery_limbs([
+                2871971279569302460,
+                5902200185045773105,
+                15272071853036571083,
+                941279412367001988,
+            ]),
+            Fq::from_montgomery_limbs([
+                14909589899485380334,
+                2917475496634709669,
+                805576903243861046,
+                12408968652323648,
+            ]),
+            Fq::from_montgomery_limbs([
+                4965718533832256809,
+                1754212836488011074,
+                17929916215787627657,
+                31212560827472606,
+            ]),
+            Fq::from_montgomery_limbs([
+                6624683280281697565,
+                249073705282553302,
+                16773266491344868904,
+                666426407772358176,
+            ]),
+            Fq::from_montgomery_limbs([
+                14658400370175416115,
+                16598358123841855784,
+                7468884340301859808,
+                498537156418909055,
+            ]),
+            Fq::from_montgomery_limbs([
+                3092692647615888959,
+                15473128321828432632,
+                12183371422629955356,
+                90122917472629323,
+            ]),
+            Fq::from_montgomery_limbs([
+                12948930298760900529,
+                6952928607372708477,
+                3139982085339567641,
+                1248134962189517308,
+            ]),
+            Fq::from_montgomery_limbs([
+                6985744378309783460,
+                16477142920331415730,
+                8851338866999953727,
+                442949356761173079,
+            ]),
+            Fq::from_montgomery_limbs([
+                15701203712204066808,
+                13583395279151644219,
+                13319518473994361938,
+                313659699513516831,
+            ]),
+            Fq::from_montgomery_limbs([
+                4825982344890422239,
+                14619863489862822918,
+                1240442949495009158,
+                34032685716615221,
+            ]),
+            Fq::from_montgomery_limbs([
+                15545629095678396535,
+                4807038152398854202,
+                5291485113044851045,
+                1303726295325580712,
+            ]),
+            Fq::from_montgomery_limbs([
+                6389172079403680156,
+                9032943120868519698,
+                15462492130574804512,
+                85088725746886094,
+            ]),
+            Fq::from_montgomery_limbs([
+                5703032911896532477,
+                13223349092185850833,
+                13075913410088371937,
+                174044037817553041,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11541911724613093271,
+                4764365618581054999,
+                1236896763333242662,
+                1259872335580108126,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2858340427253329451,
+                17666363118145411722,
+                644021337032390310,
+                619426993988426820,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1088012237914233267,
+                73296323299833064,
+                13622780370247685153,
+                260700213316646147,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6427797696532427758,
+                11424752597215964675,
+                14887356145891116784,
+                863548716722722468,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11437943120721169405,
+                6546613891818046223,
+                178966259774236863,
+                1114839788972903234,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2094898556354124243,
+                13730991406997678209,
+                616649692195872202,
+                1236777671584359336,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16095313340818833781,
+                13937789742706603353,
+                6397033448230542498,
+                603204123469938259,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1705117050581324529,
+                296583458857145766,
+                8946277180784758086,
+                281170724713728130,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10912321988948606009,
+                6222286936745316389,
+                441444520491600665,
+                1076851150923331147,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17811669542596313111,
+                16652606336421068442,
+                6881953541310887684,
+                461715494060477184,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9221479786505771477,
+                15367893497646562693,
+                3952283275298385853,
+                261957975079282710,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13040031135954504466,
+                10952213822335016457,
+                13924799356045966471,
+                329682796765711523,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3032038969124423358,
+                10532596429154895857,
+                7480358942074664060,
+                714889132491649074,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17945386978030467367,
+                689840354174626752,
+                13441615711035064138,
+                48540130120849808,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11596190507026945332,
+                930618260203344803,
+                5783552431375002603,
+                1170304220195084171,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4191471380619499680,
+                3977427785766270064,
+                14863414094819423097,
+                369990768251591395,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                927276877018645766,
+                1515047868533727122,
+                3117222274522286699,
+                891282278984721569,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15848882989765910770,
+                11888542144642875822,
+                7675827243425441442,
+                266469658223114957,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9274819953864480687,
+                5199585757108166379,
+                428065869911267370,
+                1296563830650762620,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11331876759534629960,
+                4491645632291667140,
+                6471208083896513755,
+                251321573567790390,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2296983847295834720,
+                10070205925895024578,
+                3808704241385520597,
+                777335751660715814,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10193201119745616494,
+                7548638466184426259,
+                15639999335003930430,
+                133124373232750652,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11842460291033809738,
+                18322665478951052614,
+                8407158870738333521,
+                675819066965714199,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14430102768084525081,
+                253076384413780656,
+                18138422527146078790,
+                519134570454474837,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15933812843803610316,
+                15983896530967204283,
+                9882825799493580812,
+                1302584266364271218,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12173067797535379898,
+                5325717727517207025,
+                7409031107175632380,
+                96582998239009476,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3305085874063816795,
+                9289450573383419474,
+                1058150341928589043,
+                529778246127445551,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1330582041258681185,
+                5564654505335177367,
+                13945517366705984989,
+                1206931251834026145,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12598826180439895463,
+                16521478025169841937,
+                3386516632795098953,
+                1102805146979224271,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10044930521853437097,
+                2877912515895504967,
+                18035052145330597777,
+                33064885354983112,
+            ]),
+            Fq::from_montgomery_limbs([
+                9382880122260864933,
+                17058597661703525282,
+                6556783905869804897,
+                1168870260449205008,
+            ]),
+            Fq::from_montgomery_limbs([
+                18432528797484006433,
+                4735722900425166584,
+                18265368024891288921,
+                1027470586870758805,
+            ]),
+            Fq::from_montgomery_limbs([
+                15719656347167412293,
+                11332557354049636842,
+                519942159990717230,
+                772666384261156981,
+            ]),
+            Fq::from_montgomery_limbs([
+                9321751289388745336,
+                14253755837443665045,
+                3168938111435047357,
+                420111122235415811,
+            ]),
+            Fq::from_montgomery_limbs([
+                13059683238057048005,
+                18288382662468137834,
+                1823611276910947821,
+                690198691824589796,
+            ]),
+            Fq::from_montgomery_limbs([
+                18162828489183545438,
+                10351953785278496677,
+                9286254997771830812,
+                927279774944502804,
+            ]),
+            Fq::from_montgomery_limbs([
+                4963522345382144594,
+                4253154676900921460,
+                7282282161051089230,
+                593587527071216780,
+            ]),
+            Fq::from_montgomery_limbs([
+                6479485194355552383,
+                15251033991938434070,
+                12590591367953273056,
+                274424551013743663,
+            ]),
+            Fq::from_montgomery_limbs([
+                2620796545435651276,
+                14586846717862121128,
+                2302332097837636045,
+                1283819796493180077,
+            ]),
+            Fq::from_montgomery_limbs([
+                8991468273571358236,
+                7227313118633653499,
+                15747490088173436757,
+                561599486120549554,
+            ]),
+            Fq::from_montgomery_limbs([
+                18294313051361620990,
+                14965102751025789813,
+                15706172076599615971,
+                802378037031916183,
+            ]),
+            Fq::from_montgomery_limbs([
+                1064780434465538961,
+                4607860607817195808,
+                9030343624340936138,
+                833831034884030663,
+            ]),
+            Fq::from_montgomery_limbs([
+                13416431627176782435,
+                10066339175618225206,
+                572112985484452636,
+                822499382746857240,
+            ]),
+            Fq::from_montgomery_limbs([
+                7143711692038495865,
+                12219773754522029043,
+                17242582676156166270,
+                777289807176518798,
+            ]),
+            Fq::from_montgomery_limbs([
+                7113128717936292292,
+                13857688210100771388,
+                8793336458737340360,
+                1330563484766800196,
+            ]),
+            Fq::from_montgomery_limbs([
+                2730533637401062800,
+                4389919884552042601,
+                13851575688231639897,
+                236614382605506649,
+            ]),
+            Fq::from_montgomery_limbs([
+                17358821117845333391,
+                4091803739023226619,
+                8948513744110832556,
+                231736711022474418,
+            ]),
+            Fq::from_montgomery_limbs([
+                7525422049735017990,
+                5816926910449671754,
+                2946348668431926471,
+                473912870826693324,
+            ]),
+            Fq::from_montgomery_limbs([
+                15022760476885596465,
+                8777648649255031612,
+                5258841101915267847,
+                176970954051611421,
+            ]),
+            Fq::from_montgomery_limbs([
+                8126893524716324009,
+                16080651881406269453,
+                13291422568851640799,
+                1155650272966203044,
+            ]),
+            Fq::from_montgomery_limbs([
+                15350389434008552926,
+                3983401594947074567,
+                8320244211595590690,
+                933663017215356916,
+            ]),
+            Fq::from_montgomery_limbs([
+                12639111985855809416,
+                12674676990244963713,
+                12240668328821425162,
+                345986273063363717,
+            ]),
+            Fq::from_montgomery_limbs([
+                2898677285425868830,
+                6536906805649771176,
+                11349791580100289305,
+                99788647319827915,
+            ]),
+            Fq::from_montgomery_limbs([
+                2167792350480917328,
+                10589339628863920664,
+                7699977007227927339,
+                793939266875570330,
+            ]),
+            Fq::from_montgomery_limbs([
+                8444282800967210325,
+                759373735047886169,
+                16753673678151798746,
+                1050202154677582396,
+            ]),
+            Fq::from_montgomery_limbs([
+                10129561244087704230,
+                5414967705790977670,
+                3812232643872852410,
+                736979276485277292,
+            ]),
+            Fq::from_montgomery_limbs([
+                6553964492519427603,
+                5621330608573000640,
+                647164520143749491,
+                857936355866178316,
+            ]),
+            Fq::from_montgomery_limbs([
+                16917175845255297458,
+                14902769560824204802,
+                13364901292932298968,
+                254879825976482888,
+            ]),
+            Fq::from_montgomery_limbs([
+                17604611775216405316,
+                10074391535614335317,
+                7938180133983796667,
+                795763492170113068,
+            ]),
+            Fq::from_montgomery_limbs([
+                16129353635343846276,
+                118924070074156239,
+                5769885889604993219,
+                640412620105514222,
+            ]),
+            Fq::from_montgomery_limbs([
+                4436710323259045428,
+                3157658111094362024,
+                14205278966518752479,
+                249458572467107909,
+            ]),
+            Fq::from_montgomery_limbs([
+                9495877350319107901,
+                12614925493550843935,
+                910471512100498256,
+                635940713216673844,
+            ]),
+            Fq::from_montgomery_limbs([
+                6816593327656799161,
+                14990003390002406416,
+                2134917325919873748,
+                253204383704722232,
+            ]),
+            Fq::from_montgomery_limbs([
+                1312844402086509858,
+                5035352872659912609,
+                6154765806631455701,
+                1344234666107787112,
+            ]),
+            Fq::from_montgomery_limbs([
+                10710900327348193728,
+                2417552609569632747,
+                5805242454197579417,
+                114309584150664154,
+            ]),
+            Fq::from_montgomery_limbs([
+                17408989706991867480,
+                9421945546499341389,
+                14411019173772756235,
+                1160260016630264827,
+            ]),
+            Fq::from_montgomery_limbs([
+                13207692001601626216,
+                11838677159016750842,
+                4984734708755270432,
+                766235337952488170,
+            ]),
+            Fq::from_montgomery_limbs([
+                4650502189017190309,
+                17817715353396297679,
+                14399617884320905796,
+                583659183149749248,
+            ]),
+            Fq::from_montgomery_limbs([
+                5707286640737596443,
+                10032967129287362657,
+                10890310144471980168,
+                866298111687890363,
+            ]),
+        ]),
+    }
+}
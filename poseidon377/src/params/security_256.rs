@@ -0,0 +1,2 @@
+pub mod rate_1;
+pub mod rate_2;
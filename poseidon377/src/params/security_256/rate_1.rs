@@ -0,0 +1,4040 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-1 instance of Poseidon at the 256-bit security level.
+pub const fn rate_1() -> PoseidonParameters<3, 2, 9, 4, 71, 3, 213, 63> {
+    PoseidonParameters {
+        M: 256,
+        arc: ArcMatrix::<71, 3, 213>::new_from_known([
+            Fq::from_montgomery_limbs([
+                1050046623613023911,
+                13268778607376071837,
+                8904221672492130614,
+                1273339441992694274,
+            ]),
+            Fq::from_montgomery_limbs([
+                4153019583303763566,
+                11390647539793497788,
+                16360116825618116401,
+                1036503722201984421,
+            ]),
+            Fq::from_montgomery_limbs([
+                5911590747790262108,
+                194725568978728709,
+                13519638096131438711,
+                228646837584931025,
+            ]),
+            Fq::from_montgomery_limbs([
+                17670831522146573351,
+                10779247625819283299,
+                16848222853931480359,
+                1182798555987523899,
+            ]),
+            Fq::from_montgomery_limbs([
+                3932092914949664299,
+                18085730274790951202,
+                16084382108253635410,
+                483181012663064411,
+            ]),
+            Fq::from_montgomery_limbs([
+                16723932899416471515,
+                7127411490594890254,
+                12596120808119157901,
+                1141139321220337399,
+            ]),
+            Fq::from_montgomery_limbs([
+                12308412233891983949,
+                15971209088294080653,
+                13335008445242461818,
+                182311186667016785,
+            ]),
+            Fq::from_montgomery_limbs([
+                11642903992810645912,
+                12439397090444711109,
+                16742305974933744170,
+                377811829229089805,
+            ]),
+            Fq::from_montgomery_limbs([
+                2091667989258390691,
+                11272096119006032235,
+                14314228804343148589,
+                505774215781836481,
+            ]),
+            Fq::from_montgomery_limbs([
+                10652795202378930270,
+                6265350844023280825,
+                12166849722795146069,
+                654955812609867512,
+            ]),
+            Fq::from_montgomery_limbs([
+                12920023625568645847,
+                14254394802456644961,
+                14939290069580401032,
+                926861860503779442,
+            ]),
+            Fq::from_montgomery_limbs([
+                3114319854421036006,
+                9066376023217515319,
+                221262224965573548,
+                1282396802870924587,
+            ]),
+            Fq::from_montgomery_limbs([
+                17912259432379241100,
+                9564061834062387065,
+                4227341597616387437,
+                186747970156883303,
+            ]),
+            Fq::from_montgomery_limbs([
+                16068642230473834724,
+                2974922597100496324,
+                8305194536922474221,
+                1121554925198929857,
+            ]),
+            Fq::from_montgomery_limbs([
+                14179533708271240361,
+                3128457473625339831,
+                2662795472460965490,
+                440193400744946968,
+            ]),
+            Fq::from_montgomery_limbs([
+                12333398345364082447,
+                2755261031607084952,
+                17711711582109222643,
+                1037679046629595339,
+            ]),
+            Fq::from_montgomery_limbs([
+                17683280623645070761,
+                9317995660073347904,
+                10785794282026417013,
+                1287437194064526536,
+            ]),
+            Fq::from_montgomery_limbs([
+                13091226963473631623,
+                15443617288539215598,
+                2794056941739988537,
+                240204446660745083,
+            ]),
+            Fq::from_montgomery_limbs([
+                6263352766941712825,
+                81177923454994508,
+                18241053211840643129,
+                670439588059762289,
+            ]),
+            Fq::from_montgomery_limbs([
+                12441719446233271146,
+                2788546695415979750,
+                17308571561270833116,
+                217791684651567173,
+            ]),
+            Fq::from_montgomery_limbs([
+                8192712529236083875,
+                11239861778294678326,
+                8158120210969680745,
+                353452848534908266,
+            ]),
+            Fq::from_montgomery_limbs([
+                12275489692007992808,
+                16337017875157535940,
+                3326049676819643399,
+                966814329638916339,
+            ]),
+            Fq::from_montgomery_limbs([
+                963624898843728904,
+                14382899405357791138,
+                5942378100804642063,
+                230916229429583330,
+            ]),
+            Fq::from_montgomery_limbs([
+                7030747232604341073,
+                4100578720504990349,
+                1498473051184535865,
+                511462540806241261,
+            ]),
+            Fq::from_montgomery_limbs([
+                8739400770835231333,
+                8742275495380757545,
+                10891751389986893723,
+                722669604022828927,
+            ]),
+            Fq::from_montgomery_limbs([
+                9297430735078805196,
+                3720201808901013942,
+                754586961029677373,
+                988788671131103879,
+            ]),
+            Fq::from_montgomery_limbs([
+                805177543232697813,
+                8300159541342261327,
+                13718333616856296039,
+                373900800250592934,
+            ]),
+            Fq::from_montgomery_limbs([
+                13371711463731041619,
+                4642505595755467264,
+                18364517759171254271,
+                719910599097291120,
+            ]),
+            Fq::from_montgomery_limbs([
+                18365282987749171180,
+                13236289096363665676,
+                16007433003089700283,
+                1288127431614766453,
+            ]),
+            Fq::from_montgomery_limbs([
+                8173786321487864267,
+                17438465565557235158,
+                17413619004197030356,
+                750329752695860631,
+            ]),
+            Fq::from_montgomery_limbs([
+                6180943342733644125,
+                650066343912396317,
+                13595141622472990314,
+                520429030507656967,
+            ]),
+            Fq::from_montgomery_limbs([
+                5412112463232669391,
+                5613884668235881227,
+                5836422430937451885,
+                347718725440122830,
+            ]),
+            Fq::from_montgomery_limbs([
+                18008552575669290906,
+                11360500035840978512,
+                14032804297379863120,
+                324846554873008507,
+            ]),
+            Fq::from_montgomery_limbs([
+                9697173922641287,
+                15292762557040816523,
+                2186830438598726971,
+                564641620282563672,
+            ]),
+            Fq::from_montgomery_limbs([
+                3460497442600097898,
+                15609183937876201225,
+                3485247189517955944,
+                354092933258449869,
+            ]),
+            Fq::from_montgomery_limbs([
+                2072511046022458848,
+                12405665503624724397,
+                1612467372532509659,
+                641497081334854493,
+            ]),
+            Fq::from_montgomery_limbs([
+                12793395569053319701,
+                2187226245753634770,
+                15489478558872759632,
+                164959152884802729,
+            ]),
+            Fq::from_montgomery_limbs([
+                10714820420309801967,
+                11587510249523289354,
+                2886109311606735288,
+                244061806999312151,
+            ]),
+            Fq::from_montgomery_limbs([
+                14364161903799416014,
+                16032073961458995400,
+                15999939845735930305,
+                904798096898449560,
+            ]),
+            Fq::from_montgomery_limbs([
+                12713643980387689872,
+                7017097066653971524,
+                8218209946651585326,
+                980235802493769723,
+            ]),
+            Fq::from_montgomery_limbs([
+                11591665834061362175,
+                14780452255378200540,
+                15685926748459931719,
+                1086313081619964098,
+            ]),
+            Fq::from_montgomery_limbs([
+                3002649876251592610,
+                15685324708620569656,
+                13789763495363536064,
+                92815563775202689,
+            ]),
+            Fq::from_montgomery_limbs([
+                18426139717911989496,
+                3136860200212422696,
+                618471063864855199,
+                992001598519142650,
+            ]),
+            Fq::from_montgomery_limbs([
+                15382888358099200131,
+                18366070076277799146,
+                4313104306894368162,
+                873929198739955834,
+            ]),
+            Fq::from_montgomery_limbs([
+                405980954305225568,
+                3672605402278479769,
+                16975366923634496557,
+                866505567103449792,
+            ]),
+            Fq::from_montgomery_limbs([
+                12807888479592836013,
+                13506784357966883706,
+                16733348403479036993,
+                872957657665401558,
+            ]),
+            Fq::from_montgomery_limbs([
+                2064655703276289121,
+                18398609274860667614,
+                11460043027893725374,
+                211869139475420793,
+            ]),
+            Fq::from_montgomery_limbs([
+                10838873746345060279,
+                1191765058856420059,
+                4630964633968009880,
+                724703177667852813,
+            ]),
+            Fq::from_montgomery_limbs([
+                7716270870630499590,
+                3981975307065465690,
+                16869055952688105678,
+                1184716079970114413,
+            ]),
+            Fq::from_montgomery_limbs([
+                895645783847565569,
+                7712777954744026494,
+                12913241751097755119,
+                849329385569554837,
+            ]),
+            Fq::from_montgomery_limbs([
+                16536554491119568850,
+                17952089324270615231,
+                17180126404348574073,
+                701771713208536012,
+            ]),
+            Fq::from_montgomery_limbs([
+                8182271388469339519,
+                11720649664190616682,
+                6290116944108251318,
+                1108210453438389745,
+            ]),
+            Fq::from_montgomery_limbs([
+                1797538089661815648,
+                10618849623614281497,
+                12319441971099381820,
+                240801231107934219,
+            ]),
+            Fq::from_montgomery_limbs([
+                17711307217858376194,
+                13562714613086917863,
+                17459551070844245239,
+                1151818942474546106,
+            ]),
+            Fq::from_montgomery_limbs([
+                15804965862507060899,
+                6865741078482406744,
+                3703173711756594039,
+                883999692261219613,
+            ]),
+            Fq::from_montgomery_limbs([
+                12118126553851344462,
+                7154543514668912514,
+                4710765563110660629,
+                987950459572989775,
+            ]),
+            Fq::from_montgomery_limbs([
+                5173750546645721667,
+                249351663734724088,
+                18036736229381411244,
+                94856145593533465,
+            ]),
+            Fq::from_montgomery_limbs([
+                5810596062067652886,
+                10631629817701172762,
+                813749359673084737,
+                1079721926045860187,
+            ]),
+            Fq::from_montgomery_limbs([
+                4431951968721996884,
+                3895721940872201347,
+                11942212364130887123,
+                42682564847305136,
+            ]),
+            Fq::from_montgomery_limbs([
+                1927572263089192907,
+                2862764797425376261,
+                3203482046807650442,
+                191438966795306857,
+            ]),
+            Fq::from_montgomery_limbs([
+                350384307730590554,
+                10204377585951884832,
+                503506504170748348,
+                939065934870091604,
+            ]),
+            Fq::from_montgomery_limbs([
+                355432172261395480,
+                504435008546146380,
+                6725627855072629090,
+                148046428659021049,
+            ]),
+            Fq::from_montgomery_limbs([
+                7195572199349591538,
+                14208789964302910490,
+                12762666866414891779,
+                638124991093757325,
+            ]),
+            Fq::from_montgomery_limbs([
+                584171703861702169,
+                11274001245292650807,
+                1836664316079240344,
+                621004237916359389,
+            ]),
+            Fq::from_montgomery_limbs([
+                3714477042071436964,
+                3659714869940469929,
+                17128959606250658458,
+                1197780741670652008,
+            ]),
+            Fq::from_montgomery_limbs([
+                7897846206906176277,
+                10002983045261385598,
+                8294893722202339736,
+                527947638998153226,
+            ]),
+            Fq::from_montgomery_limbs([
+                18432901539445691427,
+                18173077330914932299,
+                12264072837550229957,
+                107592767874415463,
+            ]),
+            Fq::from_montgomery_limbs([
+                13376617992174659069,
+                10698158436167947265,
+                7996242740709383384,
+                876865724578218559,
+            ]),
+            Fq::from_montgomery_limbs([
+                16585293344628525649,
+                17941293107436300172,
+                5872479393288603682,
+                735872654067366915,
+            ]),
+            Fq::from_montgomery_limbs([
+                13496253295910388279,
+                4184371083147125114,
+                1944666967127464054,
+                504791572132315492,
+            ]),
+            Fq::from_montgomery_limbs([
+                12314689562506221691,
+                5173744299574332952,
+                16513293773609288234,
+                855342441749870065,
+            ]),
+            Fq::from_montgomery_limbs([
+                10276790564859448011,
+                11918311060627916146,
+                6684387102602490665,
+                1182668109032067754,
+            ]),
+            Fq::from_montgomery_limbs([
+                11399304993783067075,
+                2184225218728993440,
+                16068576264566779491,
+                766216736732580419,
+            ]),
+            Fq::from_montgomery_limbs([
+                10841773268627871351,
+                15206163003362233351,
+                16911201492453330574,
+                807816220781768158,
+            ]),
+            Fq::from_montgomery_limbs([
+                9096888785582390150,
+                12697517390141167433,
+                14159742558827008113,
+                1172463341528711790,
+            ]),
+            Fq::from_montgomery_limbs([
+                7784540372744940832,
+                10177730283814256907,
+                8539450131282721470,
+                503782325856415686,
+            ]),
+            Fq::from_montgomery_limbs([
+                1464260183138460122,
+                16683854744682117188,
+                1501886783899500778,
+                877904897083784307,
+            ]),
+            Fq::from_montgomery_limbs([
+                10233555707967183700,
+                7580144035312006884,
+                6104083447999998480,
+                21882187343716487,
+            ]),
+            Fq::from_montgomery_limbs([
+                8603724495178490085,
+                574470250038673418,
+                1110518494161595142,
+                1049857971993606653,
+            ]),
+            Fq::from_montgomery_limbs([
+                16787692808327268802,
+                3134225311245515142,
+                15146450005823687534,
+                638006493227644783,
+            ]),
+            Fq::from_montgomery_limbs([
+                12602544182885631995,
+                13067802028138971004,
+                15166703425507717700,
+                552202180106047989,
+            ]),
+            Fq::from_montgomery_limbs([
+                5558676140155778082,
+                13330123286627416490,
+                5002767673562188322,
+                721720069971752311,
+            ]),
+            Fq::from_montgomery_limbs([
+                6796324091149832301,
+                15854655051591576062,
+                8328849234825815118,
+                530342117159940750,
+            ]),
+            Fq::from_montgomery_limbs([
+                14986504418954812411,
+                16480389205231941118,
+                1365574991031678615,
+                197002248850313370,
+            ]),
+            Fq::from_montgomery_limbs([
+                9028518243788340125,
+                11372918776799165299,
+                16642727180483386543,
+                256632560015219662,
+            ]),
+            Fq::from_montgomery_limbs([
+                11348905715736002579,
+                5098272314054015057,
+                280295382832700276,
+                112829804282190017,
+            ]),
+            Fq::from_montgomery_limbs([
+                9236486374131209248,
+                5991945788945396449,
+                14944940918619493633,
+                664554518368433226,
+            ]),
+            Fq::from_montgomery_limbs([
+                479632792301458443,
+                4161789878630475663,
+                8252007904601982386,
+                187633448212929552,
+            ]),
+            Fq::from_montgomery_limbs([
+                5199358624669169346,
+                2897189619615821342,
+                6606033671562316993,
+                1143382015271678443,
+            ]),
+            Fq::from_montgomery_limbs([
+                17964379247832594041,
+                15897569960835074431,
+                6589735474196210683,
+                415850581854923572,
+            ]),
+            Fq::from_montgomery_limbs([
+                1506613697876321672,
+                15045515484776742146,
+                2175316837245405217,
+                7475161613351998,
+            ]),
+            Fq::from_montgomery_limbs([
+                16864486055500949244,
+                10944659722697931993,
+                3539399203567117180,
+                155680934223371999,
+            ]),
+            Fq::from_montgomery_limbs([
+                435068340233843420,
+                1931015499032348452,
+                5893506963830903987,
+                433364423066659897,
+            ]),
+            Fq::from_montgomery_limbs([
+                5752714787854767935,
+                15885353453432402918,
+                1026197431132852567,
+                52110862982454961,
+            ]),
+            Fq::from_montgomery_limbs([
+                4508960256726538189,
+                8194201533952513938,
+                17025590016584386756,
+                83582308474611269,
+            ]),
+            Fq::from_montgomery_limbs([
+                8746002801767134431,
+                1566097521933601178,
+                2533641110233781635,
+                908933686313146541,
+            ]),
+            Fq::from_montgomery_limbs([
+                9304779639598720996,
+                9262597171063094254,
+                542762386435988171,
+                903481995152201377,
+            ]),
+            Fq::from_montgomery_limbs([
+                9341610935060207678,
+                5491160735838100291,
+                7227935571250502521,
+                904770977888874466,
+            ]),
+            Fq::from_montgomery_limbs([
+                8127523090057478587,
+                8055882361581642011,
+                128950867303906632,
+                509320704300671994,
+            ]),
+            Fq::from_montgomery_limbs([
+                10893243652129308972,
+                9167916179693965972,
+                12766713739629769370,
+                727060964713971996,
+            ]),
+            Fq::from_montgomery_limbs([
+                5329048618653954053,
+                17994747116370069177,
+                8214381771290667881,
+                370805389035250654,
+            ]),
+            Fq::from_montgomery_limbs([
+                10842410112666524834,
+                4201805265867393034,
+                8353499078558793423,
+                376018122521763804,
+            ]),
+            Fq::from_montgomery_limbs([
+                1334745054855170652,
+                7438083266091561969,
+                17701632120921203086,
+                291964634498872016,
+            ]),
+            Fq::from_montgomery_limbs([
+                10197980773465254725,
+                909537705690667750,
+                14986789975750833240,
+                570610641046040622,
+            ]),
+            Fq::from_montgomery_limbs([
+                9395972518257202074,
+                16980023115565603475,
+                6172421768640901207,
+                1129849823650915249,
+            ]),
+            Fq::from_montgomery_limbs([
+                7773235035254106467,
+                3562642252247652196,
+                18024564215857203257,
+                275470941084326036,
+            ]),
+            Fq::from_montgomery_limbs([
+                7926404629866697381,
+                10971703798183345495,
+                1619679394740325564,
+                1120982901536446907,
+            ]),
+            Fq::from_montgomery_limbs([
+                17948462990338287913,
+                8499572422218175181,
+                11482342997529109207,
+                1068472086239160821,
+            ]),
+            Fq::from_montgomery_limbs([
+                13682882833404077759,
+                11129250327699873492,
+                2784136483935001094,
+                747934815477700376,
+            ]),
+            Fq::from_montgomery_limbs([
+                10280745616094496210,
+                4338171306686695107,
+                115994712723915956,
+                145294902632802828,
+            ]),
+            Fq::from_montgomery_limbs([
+                17205281550138074528,
+                11068088476847663297,
+                3588070505812989931,
+                903162127545014751,
+            ]),
+            Fq::from_montgomery_limbs([
+                8116719757102279646,
+                17073384974962537194,
+                5671635788333778786,
+                784446211881766850,
+            ]),
+            Fq::from_montgomery_limbs([
+                14323228254912167216,
+                16218013283725390629,
+                251924962915314624,
+                725639922458502134,
+            ]),
+            Fq::from_montgomery_limbs([
+                18315626251657678798,
+                8410798188214362202,
+                8825334851471755023,
+                1027169131630845620,
+            ]),
+            Fq::from_montgomery_limbs([
+                12661605334454771215,
+                7991993373686906897,
+                331887506505390610,
+                477558484165458901,
+            ]),
+            Fq::from_montgomery_limbs([
+                15756219467733023757,
+                9048026289471970164,
+                15120558111666039242,
+                935146994806568458,
+            ]),
+            Fq::from_montgomery_limbs([
+                15026388215436841370,
+                13065284468687231037,
+                215070680785577174,
+                1079866234783011218,
+            ]),
+            Fq::from_montgomery_limbs([
+                4156627382089932790,
+                15091599326918512563,
+                4028731229961399829,
+                1321701314954570775,
+            ]),
+            Fq::from_montgomery_limbs([
+                50108824196789754,
+                16172442953121668151,
+                11438206965549508330,
+                351166383022626510,
+            ]),
+            Fq::from_montgomery_limbs([
+                14660635842977385445,
+                8258415107299615790,
+                5566211092110286270,
+                644221472642715748,
+            ]),
+            Fq::from_montgomery_limbs([
+                17233720710459385395,
+                1811016771782755719,
+                8948456001078983241,
+                791534052197380679,
+            ]),
+            Fq::from_montgomery_limbs([
+                6497502033986654561,
+                3389931437971390037,
+                1169638761223309197,
+                142337491921776435,
+            ]),
+            Fq::from_montgomery_limbs([
+                10374583427081598506,
+                2000136310193770738,
+                7367175965469055481,
+                1341006908838787394,
+            ]),
+            Fq::from_montgomery_limbs([
+                5916939306554787050,
+                9484027731406785141,
+                16961561978099745411,
+                1209594496527911757,
+            ]),
+            Fq::from_montgomery_limbs([
+                5043493985581241267,
+                6697767821853044413,
+                13681835520160772293,
+                856763876150122035,
+            ]),
+            Fq::from_montgomery_limbs([
+                14856607657325117720,
+                5700998033076402147,
+                2570682530431307008,
+                1221405030220141449,
+            ]),
+            Fq::from_montgomery_limbs([
+                15165851742343361010,
+                262780695458981630,
+                8475796439858369747,
+                1206331805769993189,
+            ]),
+            Fq::from_montgomery_limbs([
+                7618476859857951174,
+                3121678809855680554,
+                6538458765218348329,
+                57223445305275983,
+            ]),
+            Fq::from_montgomery_limbs([
+                15684808131336412347,
+                13860881283747302588,
+                9972487988754574561,
+                814915423968602544,
+            ]),
+            Fq::from_montgomery_limbs([
+                8814221882547137918,
+                3360000935757674406,
+                7158600761774624043,
+                1093277740425176439,
+            ]),
+            Fq::from_montgomery_limbs([
+                12185439628182234410,
+                16580240436629143561,
+                2460467658499224332,
+                463104620438869675,
+            ]),
+            Fq::from_montgomery_limbs([
+                13574896485489508326,
+                16577482041490732764,
+                6347082370598710195,
+                176581363517448108,
+            ]),
+            Fq::from_montgomery_limbs([
+                10291914231089425633,
+                6490531823374080957,
+                12509425781924005228,
+                751871766576513697,
+            ]),
+            Fq::from_montgomery_limbs([
+                10466859477810285071,
+                14642208186832514024,
+                9371750622132024443,
+                329275357705950229,
+            ]),
+            Fq::from_montgomery_limbs([
+                5208828121029349712,
+                14684545606502711096,
+                13362263864183485617,
+                770257127603379817,
+            ]),
+            Fq::from_montgomery_limbs([
+                13165392340950712425,
+                11548455474270060652,
+                14802475349761594857,
+                1013428977981195566,
+            ]),
+            Fq::from_montgomery_limbs([
+                13365771163167667083,
+                4162899214389072702,
+                237091504242934586,
+                875482920321472255,
+            ]),
+            Fq::from_montgomery_limbs([
+                16751082800277275078,
+                16258772119756291251,
+                4112277766712540761,
+                791119615931072703,
+            ]),
+            Fq::from_montgomery_limbs([
+                16876566079744314622,
+                14570087705969092174,
+                6496819141954332791,
+                401669235404582020,
+            ]),
+            Fq::from_montgomery_limbs([
+                10775371878065360662,
+                13017338116187454629,
+                3852169481117835636,
+                845506944573323061,
+            ]),
+            Fq::from_montgomery_limbs([
+                16524693949169571900,
+                8175408412497249593,
+                15567216712787618763,
+                752037824353223,
+            ]),
+            Fq::from_montgomery_limbs([
+                8829905989246326685,
+                5187748342958073834,
+                3750065781642080998,
+                208526207193681840,
+            ]),
+            Fq::from_montgomery_limbs([
+                13579995752358859384,
+                8787537205120702492,
+                16303672939449738274,
+                225222267312307686,
+            ]),
+            Fq::from_montgomery_limbs([
+                12682719581109036465,
+                17407781497050716954,
+                2901351612191946670,
+                430005702584144527,
+            ]),
+            Fq::from_montgomery_limbs([
+                961994323938956977,
+                11836942539098834393,
+                9164485901906113905,
+                1222773190932559003,
+            ]),
+            Fq::from_montgomery_limbs([
+                1823146432863746022,
+                14206648050996503352,
+                9722411974270043920,
+                964616912192322287,
+            ]),
+            Fq::from_montgomery_limbs([
+                12986831887285448919,
+                18380990954804599986,
+                631983678918910175,
+                502000714862521606,
+            ]),
+            Fq::from_montgomery_limbs([
+                497647823327571421,
+                6326288372533545282,
+                12122223978406445469,
+                1052216325917996705,
+            ]),
+            Fq::from_montgomery_limbs([
+                11145765355118396172,
+                11703208549349152741,
+                7340388530403870329,
+                486319068269254007,
+            ]),
+            Fq::from_montgomery_limbs([
+                16142464340712318122,
+                451483736824966961,
+                8151240310453477376,
+                29328890309843634,
+            ]),
+            Fq::from_montgomery_limbs([
+                14060877203274092997,
+                17664838263199598251,
+                8216345483802627133,
+                784204134780786692,
+            ]),
+            Fq::from_montgomery_limbs([
+                6627894500794967911,
+                16605139695516496375,
+                15611773940966688235,
+                939692868784419058,
+            ]),
+            Fq::from_montgomery_limbs([
+                1423423332315238386,
+                239954067064926671,
+                10209292950129808701,
+                514431775458018037,
+            ]),
+            Fq::from_montgomery_limbs([
+                3632525340921094507,
+                2859672831651738086,
+                904045912005366190,
+                249369607840856553,
+            ]),
+            Fq::from_montgomery_limbs([
+                9392944499425699506,
+                16999268062143068819,
+                11553213100807137586,
+                303518619032394455,
+            ]),
+            Fq::from_montgomery_limbs([
+                14483230322233346880,
+                16592961149777275190,
+                7801171891217173353,
+                484775813126985076,
+            ]),
+            Fq::from_montgomery_limbs([
+                17874883731710457710,
+                3676440044795170303,
+                9803645741680513675,
+                1208619395767113263,
+            ]),
+            Fq::from_montgomery_limbs([
+                1906775699697945895,
+                5700024290386596322,
+                18331091326446499016,
+                744915862526670663,
+            ]),
+            Fq::from_montgomery_limbs([
+                6108781643618464420,
+                18375117621413822747,
+                16781154373693575850,
+                787069578947757328,
+            ]),
+            Fq::from_montgomery_limbs([
+                16005705717047774191,
+                3397978253339796526,
+                11111185382834011154,
+                244255535552929104,
+            ]),
+            Fq::from_montgomery_limbs([
+                9431987228674793830,
+                16023181554997078786,
+                8186914565469855049,
+                1146310285243622786,
+            ]),
+            Fq::from_montgomery_limbs([
+                12321295099917779385,
+                660073562401505018,
+                9997835338393089488,
+                74296163618350095,
+            ]),
+            Fq::from_montgomery_limbs([
+                6597119632186852288,
+                216532421419692819,
+                14589220599561596295,
+                601750057487084240,
+            ]),
+            Fq::from_montgomery_limbs([
+                18263467540299407930,
+                17336963869288989965,
+                4658529579425217237,
+                1036523871294901791,
+            ]),
+            Fq::from_montgomery_limbs([
+                12214536820185391249,
+                18028303234747592895,
+                4654629884443778576,
+                279785406020017507,
+            ]),
+            Fq::from_montgomery_limbs([
+                14453848861101432596,
+                10939051357755824613,
+                7714592446493924375,
+                1228171686806755486,
+            ]),
+            Fq::from_montgomery_limbs([
+                14502473050148241901,
+                10752129351465526414,
+                15807023640894566902,
+                779773796830664004,
+            ]),
+            Fq::from_montgomery_limbs([
+                7725591987247911515,
+                1104134255641024707,
+                14585153844596690302,
+                1045589491470450081,
+            ]),
+            Fq::from_montgomery_limbs([
+                4150139331689983490,
+                6614130106615534951,
+                12465630034935745961,
+                781302685636536705,
+            ]),
+            Fq::from_montgomery_limbs([
+                7896334557105318411,
+                5509873825509741893,
+                16949181991853583409,
+                555277978856564669,
+            ]),
+            Fq::from_montgomery_limbs([
+                3300598503279188673,
+                4391000473340468707,
+                11407076530554711615,
+                402486406838747513,
+            ]),
+            Fq::from_montgomery_limbs([
+                4445402769685879793,
+                4558314007959289848,
+                7050009254878035342,
+                1154385350511626443,
+            ]),
+            Fq::from_montgomery_limbs([
+                11501475734598929043,
+                13615561440186400931,
+                1920520086742573205,
+                910186766410625508,
+            ]),
+            Fq::from_montgomery_limbs([
+                15480771508901290235,
+                723882814245679154,
+                14273806107933768926,
+                1302766763143061457,
+            ]),
+            Fq::from_montgomery_limbs([
+                7480305836864266513,
+                16181366354174410081,
+                12563431697949814006,
+                1233588211839405719,
+            ]),
+            Fq::from_montgomery_limbs([
+                3611999124977920633,
+                12185209944038483959,
+                6900237402412676240,
+                256044764862096211,
+            ]),
+            Fq::from_montgomery_limbs([
+                14350802552005247644,
+                16135430759080227006,
+                15664209696026911532,
+                276100657392850567,
+            ]),
+            Fq::from_montgomery_limbs([
+                4635354476476350773,
+                9032998352953207996,
+                10597492641275290833,
+                228870657288227720,
+            ]),
+            Fq::from_montgomery_limbs([
+                5744460318589040130,
+                13795768042389508481,
+                11752455704962898382,
+                903644272731727556,
+            ]),
+            Fq::from_montgomery_limbs([
+                1923423373030041089,
+                17402913615978218450,
+                9815822304822677744,
+                1203191378578220815,
+            ]),
+            Fq::from_montgomery_limbs([
+                18132585894643281443,
+                5903703943511206885,
+                13678731940405183262,
+                914765216327026711,
+            ]),
+            Fq::from_montgomery_limbs([
+                17316890827036290263,
+                5174741283976882250,
+                17726383981806424427,
+                707503602409440587,
+            ]),
+            Fq::from_montgomery_limbs([
+                11235425138771805337,
+                2898819763456378294,
+                6874970636181686459,
+                961371326842892505,
+            ]),
+            Fq::from_montgomery_limbs([
+                17069351034868104209,
+                13355547867550309126,
+                8321294048457737920,
+                1003124842888145223,
+            ]),
+            Fq::from_montgomery_limbs([
+                12794867180276442082,
+                6518703516740667289,
+                7583933689815460290,
+                734752422304279300,
+            ]),
+            Fq::from_montgomery_limbs([
+                11579016745818888361,
+                5663185852140055858,
+                4468632622860459035,
+                544210512129475799,
+            ]),
+            Fq::from_montgomery_limbs([
+                1549759038999664587,
+                6999792322083233607,
+                14135375337607020606,
+                347638631535893607,
+            ]),
+            Fq::from_montgomery_limbs([
+                8340038149240885697,
+                14805031841772658801,
+                14846125870018809712,
+                530356439594433594,
+            ]),
+            Fq::from_montgomery_limbs([
+                5852681194860030910,
+                4406643316620775246,
+                5529514642760022618,
+                1211480627409848587,
+            ]),
+            Fq::from_montgomery_limbs([
+                10674940772998741465,
+                8736681025511155800,
+                5015511696491936585,
+                181092191190927294,
+            ]),
+            Fq::from_montgomery_limbs([
+                2732276371613992258,
+                4153302906380354230,
+                9142816093378990738,
+                955416318698610420,
+            ]),
+            Fq::from_montgomery_limbs([
+                356196089894483636,
+                1796259508793365008,
+                3689031686355752263,
+                594016535136055812,
+            ]),
+            Fq::from_montgomery_limbs([
+                15765680932654684348,
+                1368799365773183612,
+                7881200377094045524,
+                425146575486483637,
+            ]),
+            Fq::from_montgomery_limbs([
+                205252107684405414,
+                14730107572900050986,
+                12905324898073279894,
+                533806217022559871,
+            ]),
+            Fq::from_montgomery_limbs([
+                9174141795609297902,
+                17448533338091340834,
+                9774167837775703353,
+                706439893537557614,
+            ]),
+            Fq::from_montgomery_limbs([
+                88400588524431743,
+                411707665781463301,
+                5631145946184759446,
+                87721365254259172,
+            ]),
+            Fq::from_montgomery_limbs([
+                16026924159898057734,
+                10070873382839929757,
+                5712921888458981925,
+                1186916656347576690,
+            ]),
+            Fq::from_montgomery_limbs([
+                3656919509809442983,
+                1396532799045243248,
+                1483319325573043278,
+                379413187371707955,
+            ]),
+            Fq::from_montgomery_limbs([
+                6790200827205656369,
+                17372174261554780930,
+                12604595403131351330,
+                538307389719131042,
+            ]),
+            Fq::from_montgomery_limbs([
+                6048797070963659403,
+                11083623758376096578,
+                5161880008877430488,
+                351144683229250807,
+            ]),
+            Fq::from_montgomery_limbs([
+                5764350539226191297,
+                8603365088739665061,
+                3111506181173326280,
+                1274960831516509277,
+            ]),
+            Fq::from_montgomery_limbs([
+                1606577324941176859,
+                12116153387829058484,
+                16845247364827099027,
+                16438991333814326,
+            ]),
+            Fq::from_montgomery_limbs([
+                6465878899393193260,
+                13931785102227445410,
+                18211150425976762182,
+                1268988041382719230,
+            ]),
+            Fq::from_montgomery_limbs([
+                3448327573599502052,
+                9130815939399356229,
+                15984778562887871078,
+                1203340939064241838,
+            ]),
+            Fq::from_montgomery_limbs([
+                396738784472197121,
+                18189285121677921781,
+                15148812231081865570,
+                297374582477783171,
+            ]),
+            Fq::from_montgomery_limbs([
+                5165480068383929982,
+                14780637745778156943,
+                13747994414212993879,
+                1227854968624240893,
+            ]),
+            Fq::from_montgomery_limbs([
+                11530305576351671792,
+                14816636819055364274,
+                6161373833283128394,
+                1309770709827933165,
+            ]),
+            Fq::from_montgomery_limbs([
+                10199337696584628077,
+                9963951979620933540,
+                18277982455389979065,
+                1182562071990295704,
+            ]),
+            Fq::from_montgomery_limbs([
+                10681999189951329405,
+                11786257797386053105,
+                14277991443217153717,
+                588761323755914768,
+            ]),
+            Fq::from_montgomery_limbs([
+                5236879680192908078,
+                7141844287209959773,
+                11222923428510590797,
+                550418445049032754,
+            ]),
+            Fq::from_montgomery_limbs([
+                4980096122090169836,
+                10826277060578947791,
+                6628725117376491814,
+                1259272225080388664,
+            ]),
+            Fq::from_montgomery_limbs([
+                1462219582607498380,
+                7023220119968286318,
+                4115899252046501836,
+                869066303250941453,
+            ]),
+            Fq::from_montgomery_limbs([
+                1426364711540255045,
+                848186622252689016,
+                4276821300696643769,
+                175400759717307664,
+            ]),
+        ]),
+        mds: MdsMatrix::<3, 2, 9, 4>::new_from_known([
+            Fq::from_montgomery_limbs([
+                15302903146332116305,
+                8895355854553175376,
+                548696419140371791,
+                319366418254432479,
+            ]),
+            Fq::from_montgomery_limbs([
+                16270238816294404093,
+                17510165790819614716,
+                15988650198697570299,
+                575844906362867708,
+            ]),
+            Fq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+            Fq::from_montgomery_limbs([
+                16270238816294404093,
+                17510165790819614716,
+                15988650198697570299,
+                575844906362867708,
+            ]),
+            Fq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+            Fq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            Fq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+            Fq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+        ]),
+        alpha: Alpha::Exponent(17),
+        rounds: RoundNumbers { r_P: 63, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<2, 4>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+            ]),
+            v: Matrix::<1, 2, 2>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    16270238816294404093,
+                    17510165790819614716,
+                    15988650198697570299,
+                    575844906362867708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+            ]),
+            w: Matrix::<2, 1, 2>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    16270238816294404093,
+                    17510165790819614716,
+                    15988650198697570299,
+                    575844906362867708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<3, 9>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<3, 9>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    15302903146332116305,
+                    8895355854553175376,
+                    548696419140371791,
+                    319366418254432479,
+                ]),
+                Fq::from_montgomery_limbs([
+                    16270238816294404093,
+                    17510165790819614716,
+                    15988650198697570299,
+                    575844906362867708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7147916296078753751,
+                    11795755565450264533,
+                    9448453213491875784,
+                    183737022913545514,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    10406805428937057924,
+                    14160037477503257835,
+                    7036231764655967476,
+                    1082136417579874198,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<3, 9>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    4383832024780107759,
+                    7223259317565124429,
+                    5707462847421561422,
+                    885057472408297272,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6746251504312660021,
+                    9699181025413837338,
+                    15260914163925889303,
+                    35388324151454979,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5008847210566114882,
+                    8801394942975925486,
+                    18175922902442652272,
+                    916924432575702892,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6746251504312660021,
+                    9699181025413837338,
+                    15260914163925889303,
+                    35388324151454979,
+                ]),
+                Fq::from_montgomery_limbs([
+                    15450583684112934333,
+                    18216376800760520619,
+                    4209613945072000948,
+                    155813436852978744,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9834172736316928124,
+                    189466111752828136,
+                    13335900722961176546,
+                    530999546582786085,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5008847210566114882,
+                    8801394942975925486,
+                    18175922902442652272,
+                    916924432575702892,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9834172736316928124,
+                    189466111752828136,
+                    13335900722961176546,
+                    530999546582786085,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11796616273943571364,
+                    15542379905239984210,
+                    1578039792447016471,
+                    947030710751083834,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<2, 4>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17242594123341362780,
+                    10420431729582929402,
+                    13098868289430565627,
+                    261978409307343683,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4962966789362289472,
+                    13671436036280945585,
+                    17033622650389008176,
+                    591212102933547967,
+                ]),
+                Fq::from_montgomery_limbs([
+                    4962966789362289472,
+                    13671436036280945585,
+                    17033622650389008176,
+                    591212102933547967,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2148639234720789121,
+                    15880825911856067065,
+                    16042722653708983750,
+                    635825847167915836,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                15302903146332116305,
+                8895355854553175376,
+                548696419140371791,
+                319366418254432479,
+            ]),
+            M_i: Matrix::<3, 3, 9>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15391431874758848825,
+                    9930478514863663979,
+                    8519746724640521430,
+                    487030469961112469,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5894283800168662667,
+                    9909062195149379308,
+                    15234922836737432538,
+                    986746674052943573,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    5894283800168662667,
+                    9909062195149379308,
+                    15234922836737432538,
+                    986746674052943573,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3821451755152819436,
+                    7560426044084710112,
+                    5334312678325579564,
+                    33407578512545524,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16270238816294404093,
+                        17510165790819614716,
+                        15988650198697570299,
+                        575844906362867708,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2093244959304135473,
+                        7921656493611837028,
+                        14184576022206158435,
+                        729731999227928846,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18204910156218979669,
+                        16293041589642941780,
+                        9975069610392864084,
+                        1088801882579738168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3072474957567996411,
+                        7632850653899625657,
+                        6867324839353272651,
+                        1129105644996777990,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11749409346063797401,
+                        13847779958722295463,
+                        5230051856842938218,
+                        809528761888529318,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16538317435082867476,
+                        140673227072662684,
+                        4917458456429054520,
+                        951347002169384040,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8049501819868988169,
+                        4640840621228515661,
+                        5281323386686467274,
+                        1217317166531385469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15861181624207003323,
+                        10871450011713172103,
+                        4492382029642778046,
+                        495041570025076744,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        491531644685882947,
+                        16236469504155238438,
+                        13405996466460073108,
+                        191442324574972544,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14234832595612208455,
+                        16711171142020806901,
+                        9818085755212544560,
+                        433758843793119660,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8692243294689566788,
+                        17131754811489911037,
+                        16082421295010554115,
+                        245109642615998458,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12752747264486126419,
+                        12936758402746978248,
+                        7901753820298393242,
+                        1086817638717069074,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        716899015486837574,
+                        12929955716143301332,
+                        6296181173922398814,
+                        95630164573893864,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10771615223994036552,
+                        12477611863199363886,
+                        5019464732542008653,
+                        260172239809303582,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2083749357590716775,
+                        7802489018239475310,
+                        4719252491703553508,
+                        331544147020630716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11839081110669849836,
+                        16458332811546812068,
+                        4108523134706353886,
+                        1014020374083768583,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11143510974855252250,
+                        1906425947937085984,
+                        2407368748249553491,
+                        504368299222388920,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8041950831366606113,
+                        14611771049638947187,
+                        11221299042990583579,
+                        744635656555856530,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15987773674750471853,
+                        7582780421302456968,
+                        3513070142855093124,
+                        449192997718482772,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1698389311466971615,
+                        4208825745762883192,
+                        17737380562723619127,
+                        1023230515994865951,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15719775543772287808,
+                        4711976878746234330,
+                        9616083067266331949,
+                        1291758636403773818,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11511319411419341690,
+                        4611804125723845050,
+                        17905923766281011346,
+                        701498562864789906,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6775959117219461845,
+                        7248217303083744152,
+                        8323273847079805491,
+                        1272121734883668680,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15960231410259949054,
+                        831075562495169556,
+                        666337526106305827,
+                        827994946738712632,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3075648154561933440,
+                        4787490504369527279,
+                        2103456873768609578,
+                        930535653041788534,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13632064958207562507,
+                        2403612539570381736,
+                        9671114344743639907,
+                        554518676653218341,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16017511834618514656,
+                        10675130055946071909,
+                        4737919029540133580,
+                        682111021257012783,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4054052073615595653,
+                        15900491953977945207,
+                        9657244812600116702,
+                        746793989576728670,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11427159084253523216,
+                        8137374149736453962,
+                        493707633722255071,
+                        574786622341431128,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3016813624761290833,
+                        9410383431703917657,
+                        207322790806709582,
+                        604735846059941483,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13182715603850996122,
+                        1385581621557644096,
+                        15928797776098706943,
+                        1247128249672542744,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12343557143080639521,
+                        10608577464123341465,
+                        8700628699767163855,
+                        822798305869360351,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15640932130097417474,
+                        2198144264546084253,
+                        15157422365823566225,
+                        162345305798039707,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15379897571473551238,
+                        13605062526804796454,
+                        1262497433072300524,
+                        325397323402951460,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11985517729662602408,
+                        11378058393967136140,
+                        10784471266494716066,
+                        355758022531067864,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15749607349459966392,
+                        10347221607350790242,
+                        16868641356169832097,
+                        9481912848277140,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5070404887673962603,
+                        7505346455783472838,
+                        11581801124979799833,
+                        162417281360137989,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14359230556911256253,
+                        4739001612305070282,
+                        615167045189603842,
+                        893440173159515387,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6892861075705566142,
+                        16405352091222855583,
+                        9442037024015442096,
+                        943715695188578421,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2681738006276381668,
+                        13590338127726729778,
+                        9006319668283998389,
+                        571100162557721724,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7926076457258912989,
+                        15568541251154450028,
+                        15222738110907421829,
+                        194241141418124411,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3900695082401480226,
+                        13241392216815987825,
+                        6715445016875356020,
+                        559176536870193173,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4273472679882108688,
+                        7043568940286842511,
+                        9096453621609771899,
+                        490785749945503317,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2078179201499171590,
+                        16327227019578678368,
+                        17980150213785344661,
+                        1297383831585962991,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14728670056946621521,
+                        17042639142917167694,
+                        2971308019248556661,
+                        314387788586761162,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14126190491907727041,
+                        8208403354023925517,
+                        4660262216078686939,
+                        971808842720812284,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12606246547610594540,
+                        9049616062023318605,
+                        11742190485797839027,
+                        90317661102003606,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7135240500841828540,
+                        8166637984141883895,
+                        4762161495768731224,
+                        1056050894881259133,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11752411941768010421,
+                        2371987101660996229,
+                        7910923918069285785,
+                        283757372746488803,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14169661373594066352,
+                        10756814747639910216,
+                        4179402566370914468,
+                        294039297137006515,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12380990947758453459,
+                        15919689303377847053,
+                        4451172135381562238,
+                        643870172347401539,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16919934541410985772,
+                        12894445305080351094,
+                        3222976969400102992,
+                        1274426454940711344,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17739117012754232566,
+                        7855016506876230601,
+                        10199750748041585788,
+                        610234517763900211,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14969807057009266193,
+                        9743543989422659962,
+                        265344874800963549,
+                        193281162476465682,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9334899116745497567,
+                        1443910720530394642,
+                        15242704534506362012,
+                        557844541838643008,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12596150337841453197,
+                        17941854592742713998,
+                        9536226961205720981,
+                        193378317632915294,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2145847197367443746,
+                        3494461491303192245,
+                        8559536859052153255,
+                        188641306996153597,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6619736277081498517,
+                        16354589870583930111,
+                        5727794428272011445,
+                        472934899434235719,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15229354881950810050,
+                        13117262548706587,
+                        18434975406822541359,
+                        430449497798843798,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4585061371183060684,
+                        14866599352008180181,
+                        17851236730068556506,
+                        483368166519918339,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16121753970412208543,
+                        9309799407998260359,
+                        13316268079574695590,
+                        1332560915242838761,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5320180137128926885,
+                        4585099896596608821,
+                        4959026927695406777,
+                        12672238118064834,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10187411999593275144,
+                        11818295958457531453,
+                        10171627801723943129,
+                        134096185666094551,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13679602049257994748,
+                        5087190971181287881,
+                        11477344382740453175,
+                        1280909811145666249,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6113723698667828259,
+                        18153070584833025317,
+                        408206990767238153,
+                        150618847611618392,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13428266140945152032,
+                        4544748749779003102,
+                        16110596387649263088,
+                        1230312000679957335,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5270973436782433771,
+                        9556958203689635851,
+                        13875387471200265745,
+                        1311400066186188619,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2989099237946687084,
+                        11163600336391845481,
+                        3745698889462068613,
+                        296953453557752213,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4481734888804850036,
+                        8013793428476134334,
+                        2620596248359280776,
+                        42716181359228413,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4698258328750751451,
+                        14408604555777313394,
+                        11788072955162151957,
+                        36775204519824281,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3548247831672774272,
+                        12828043594462564170,
+                        15633794904336917978,
+                        59515116048088842,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1057934729068353118,
+                        11522746606896859971,
+                        12807825331307794702,
+                        973287514697113012,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16693559800753602892,
+                        14511645649265071070,
+                        6982798249335049083,
+                        981285831405373976,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        105925704364391837,
+                        2424150870089054980,
+                        13785412664879170800,
+                        597387049765564941,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2959125300829315583,
+                        10778709732640671047,
+                        7629286629589980071,
+                        699405785781787638,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3952190214724479237,
+                        1273415412790503212,
+                        4909310586834510495,
+                        761376405939090044,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14187427672632352982,
+                        11191828664797669815,
+                        6265700549007008452,
+                        311619903835811648,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10772187935075651609,
+                        13859288782816084455,
+                        10376359552467390130,
+                        321427144004085093,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17003229748092618526,
+                        8268815287600456258,
+                        1834686892799062648,
+                        250423208503327185,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12421149434382833801,
+                        15046142321287824429,
+                        16483564009145856070,
+                        418159664232307763,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3567489655749371268,
+                        4810728873889035900,
+                        7841654053411528570,
+                        1151159536600316336,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8536559511712926800,
+                        8976478954489625118,
+                        54327018776132480,
+                        870205936700792820,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        63626241259003620,
+                        3444583698538046887,
+                        13902281516871057736,
+                        1092749094470554549,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12562372939505071156,
+                        1294923829245277294,
+                        12089804137038444260,
+                        700540876777739334,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15585699608724869379,
+                        12771826263574868036,
+                        9250003458120465289,
+                        245621273644522572,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8704281285142324087,
+                        12312296297119665604,
+                        18010772023223715659,
+                        858750847484462833,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8847910361385199406,
+                        1931478510666983342,
+                        10153543273732894120,
+                        147406716953375873,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3093497871008714240,
+                        15991452684040220700,
+                        1683963820365487717,
+                        1220621576979241837,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14679728000247755444,
+                        18055522599610733624,
+                        166015854687969637,
+                        412288988978971934,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6757782048978781174,
+                        4849306741458751625,
+                        15469892228773263202,
+                        1031734907581942630,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        891051695598193903,
+                        7247190131280538385,
+                        9061112263051556977,
+                        75042899634361705,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10681183762132471971,
+                        13322253624521134182,
+                        12466161145466534510,
+                        664532322809021116,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        476484101673286868,
+                        5055692949071922965,
+                        1225140943738472097,
+                        798404152405795893,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5619903829605988050,
+                        17497938017621798814,
+                        8756475424792893694,
+                        908202464321404609,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1080314242100436438,
+                        4358202089564673029,
+                        7703015936075409585,
+                        400733265913938173,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12207939251941001038,
+                        8615079718137585757,
+                        15753548269582754209,
+                        903420268250773020,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6554959674275816171,
+                        16449990827446521804,
+                        15234241009704605254,
+                        230716697891249804,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8650357388851572911,
+                        11532735475091865297,
+                        3544009606638567269,
+                        452092796104942679,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4635452681802273084,
+                        15946249204640342229,
+                        6834510508712216872,
+                        1332244472548429799,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5419989817178064948,
+                        10252741167930972498,
+                        10238569742937070886,
+                        135067953156109002,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1733688605560569996,
+                        7725907856698496918,
+                        15671838804120224910,
+                        109589503943947673,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        229235129870372534,
+                        12028132970639051421,
+                        2162529712973771233,
+                        241336167304182443,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7342216870443135905,
+                        968592103167267111,
+                        15806152172419222364,
+                        420882027522999514,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        459886786617141712,
+                        13899223382379346632,
+                        13471569285345449369,
+                        308985392784240846,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4149757398847100850,
+                        11796066240077449462,
+                        14178845474773342209,
+                        404730045106274723,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8334005851062603412,
+                        13281076055032989749,
+                        2089530852214058429,
+                        146318260001300359,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14046990718790246597,
+                        4020273006923332256,
+                        11341486260598615968,
+                        374388459825773017,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12630481326118932269,
+                        10540628434474835870,
+                        17389619842122877967,
+                        472723436285947761,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11605734349890868706,
+                        8063629953147006684,
+                        6314413701553773585,
+                        19136894277328557,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5223860283414484703,
+                        12699067671369311916,
+                        17101324254368327618,
+                        1315057941713297938,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10511814421135738929,
+                        689163883936683576,
+                        13144799739898444726,
+                        1254385320001948306,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18097301183929899706,
+                        5269454856983152055,
+                        10337009110961153482,
+                        1087908483083093873,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13547469898305966275,
+                        12271437353754913740,
+                        5458305043932595842,
+                        1239363366927142679,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8514731607072567648,
+                        6820380235034272511,
+                        8459613057105713961,
+                        780876022796629894,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16896236415275630826,
+                        9062602179843564524,
+                        17095118852270980700,
+                        108962603047232171,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3792593112785350251,
+                        18193431463055824631,
+                        12815474962538970748,
+                        93900883582203912,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4228996660927532626,
+                        13546897409164801454,
+                        266722429599023911,
+                        441026779079599106,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14002478139702405979,
+                        17330340067007293374,
+                        2600979741014449855,
+                        223757755879830692,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4108821250663969440,
+                        3478450969298221541,
+                        3506428813126434515,
+                        708453142427433409,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16967135163168414430,
+                        5261985225511111181,
+                        11191123064818024537,
+                        489835677073672825,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1058830600023748251,
+                        11414115921690914660,
+                        12746015706368163795,
+                        1120183488456547751,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10284113819603330607,
+                        9636331667355125770,
+                        6506500109805759695,
+                        1277088539591237294,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14669222199291923853,
+                        12219550229362504537,
+                        14305517368258174557,
+                        122986034462608639,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3464197146680824658,
+                        12132294245401374501,
+                        3912393193119060917,
+                        1298022533492863966,
+                    ]),
+                ]),
+                Matrix::<1, 2, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17992281905302645845,
+                        14244948724826152685,
+                        10454381501492293843,
+                        868731802129146641,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8642100261224665008,
+                        8494090274532395705,
+                        13679074398766134192,
+                        1006691588319042362,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7147916296078753751,
+                        11795755565450264533,
+                        9448453213491875784,
+                        183737022913545514,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10406805428937057924,
+                        14160037477503257835,
+                        7036231764655967476,
+                        1082136417579874198,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18050286569012579189,
+                        12701735779500542307,
+                        16028299840828156173,
+                        889670182449753716,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17537805078915517156,
+                        3881567050914191905,
+                        15320666583506005360,
+                        34817918541568626,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        639651884569206475,
+                        3208454571154516477,
+                        5589814573911893755,
+                        812188059462663385,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6035414598134387451,
+                        221157594769622514,
+                        1246867853953039854,
+                        865666459554075403,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10986953500964328689,
+                        4552354314624673085,
+                        10130711827018722177,
+                        726464205504256466,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14592704253024955499,
+                        4367720503141755092,
+                        3264507028823567377,
+                        503642346381621716,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6929349916420721052,
+                        7263465717590274222,
+                        16428445137775857828,
+                        783617578238482410,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1105466080997706678,
+                        15638892732603802885,
+                        15163494631828844812,
+                        1265995546038282468,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12695614650926966465,
+                        7645153902251269333,
+                        15007858904354383754,
+                        303173888887641556,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13532111267254117566,
+                        453207663850979705,
+                        1118439931840184625,
+                        1109223913114108441,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1127605964966272093,
+                        459071588792078574,
+                        17569200668490318799,
+                        556567547339769587,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        743383751775379478,
+                        2152757563916176356,
+                        18215266305796881375,
+                        613050128242856475,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7479292154348380014,
+                        12505834466797528681,
+                        7061175132070981799,
+                        1037799745298480638,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4733881782580367683,
+                        13385554219210821909,
+                        11595152338390400937,
+                        1286877367267536722,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10482398298648703137,
+                        13154284141257785070,
+                        12900892582326783232,
+                        1312370380898727254,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7694076120856540521,
+                        15146007605344947747,
+                        3193830018416013595,
+                        78000754372621570,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5244970134326960239,
+                        15516929113764341255,
+                        17564742414096943825,
+                        565809721348112173,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5301582977685139465,
+                        1069505112975002608,
+                        16894882112854195663,
+                        1006960914609031286,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        933807009911564028,
+                        17655717523721777752,
+                        659854032695183391,
+                        696948171193839726,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14431054331145497962,
+                        17304755953697263142,
+                        1213662370814867240,
+                        406071929554987935,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12663120994246797343,
+                        2294330459533986298,
+                        12312665243517552316,
+                        1162434858386655769,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14926635415117511105,
+                        17764138882091983380,
+                        9845162467394310721,
+                        365262529860339315,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14453117806705169688,
+                        15371763008771329739,
+                        859830826361086373,
+                        695666911485789237,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4372294882149658880,
+                        8953368466178039118,
+                        7175551968845157174,
+                        1296799522059563299,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10743785724983518030,
+                        11011793084410944909,
+                        4710170432674399868,
+                        872985210632843618,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17034928244178709962,
+                        7064630411064620365,
+                        11395663509669588708,
+                        435858474778976283,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        376125808430990068,
+                        3949770427712474479,
+                        1831829189608085506,
+                        1033600417294508604,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10474175416970714387,
+                        7638666470072040137,
+                        15323998975775188709,
+                        499861799254400548,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1311503503152331198,
+                        10444356300795820152,
+                        14006643509954394380,
+                        360275028297029580,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8848990081142010576,
+                        3767709270230096287,
+                        3203373808160299103,
+                        388150987057922534,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10527714796170242821,
+                        10614707629823438759,
+                        2166835449751899854,
+                        826442630247846539,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6013609006746740436,
+                        17829255150171580013,
+                        4865370498689675602,
+                        631806865209492278,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15407698184224138641,
+                        13892993523782417432,
+                        11074522626536989473,
+                        1282147673049091223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13566156582579433121,
+                        10666124267110644787,
+                        17585510383615080517,
+                        460461758314188289,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        242069913640544639,
+                        8584725967717195423,
+                        8673969491922319906,
+                        906855204727712918,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7507622872789915132,
+                        8838834878197495556,
+                        4143887577251148647,
+                        147314013318059527,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        739343839918695474,
+                        15137511284934501835,
+                        8206744331706291412,
+                        460517726754831566,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5332098194526476884,
+                        17462053065946204764,
+                        17109918215020530224,
+                        45500621783055396,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7181843829067274762,
+                        93170180385149654,
+                        16601265500332534068,
+                        692920224266685092,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17373541931105532396,
+                        11757401236546761356,
+                        8986928677878657520,
+                        855377794858428568,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16770152912401058713,
+                        7784323106136704842,
+                        3166414142982631778,
+                        252798645948426673,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9867428128375842792,
+                        6330871821464522409,
+                        2509737650104409073,
+                        87377932535113034,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9620378538351684400,
+                        16259903847898782084,
+                        14224517332394904862,
+                        248783024579596034,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15289486933546124534,
+                        5057961130890228616,
+                        5618412873763815158,
+                        1218252246195044797,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18285461524892798097,
+                        1312649631515945874,
+                        2702563113361998981,
+                        156990921411102370,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16897672275046167563,
+                        14631253948673542966,
+                        7825433188494427672,
+                        497140819371349325,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5586425496410891559,
+                        3860435103896432777,
+                        15075562942840298887,
+                        539774915240951994,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18444236515588467614,
+                        2138469830557977395,
+                        8290712504743660259,
+                        832327778452741186,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15144610075012749322,
+                        11801332079357554718,
+                        11652842602527962385,
+                        396912768209767018,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10639969620247737667,
+                        7334159324553590340,
+                        15063492084884778567,
+                        878961650630548788,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18348264072788817511,
+                        8009061068808737218,
+                        6097418426121505352,
+                        1211183153837556564,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17198860156343170394,
+                        2167538157704278478,
+                        10949270097435223366,
+                        928056824653534234,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10634763804674129031,
+                        9612181115928191305,
+                        7893421171473086290,
+                        251268211819044542,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12969986235926373418,
+                        712178654575523278,
+                        3830673788986900912,
+                        1046343437814522982,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4298621992800380154,
+                        4055578536241120435,
+                        9617612244989535309,
+                        382604375840733162,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18067399474523304446,
+                        12223555489918789755,
+                        9993950631657703109,
+                        1048484019905387459,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13058818877006642653,
+                        18414014235319212231,
+                        14027764563566307518,
+                        703825358964196766,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9830948603405623404,
+                        3341559968925646454,
+                        12259602563968066179,
+                        912923676012093495,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8043127764066461437,
+                        14803670394754936674,
+                        15225608398783219441,
+                        893330814736280447,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4386126239109851849,
+                        12096965878015338652,
+                        13672154659709754401,
+                        191338360587662586,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        938229506172670121,
+                        10427875269621448880,
+                        5596681118617214448,
+                        888537937873904937,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8207998122651656890,
+                        2877238957695703928,
+                        13475567558498484051,
+                        526954325042111246,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5503632604272343580,
+                        7179831008834220758,
+                        11157439010937887422,
+                        846327213685284559,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        392761118741628062,
+                        11139817671636712015,
+                        8960712530833917626,
+                        10737784618933277,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15986600065699221925,
+                        6712374428386093415,
+                        17193173044544174133,
+                        757842546987985236,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15931120506099130643,
+                        6052305085134746246,
+                        16225216657477550131,
+                        1208935410212143596,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1725229384163641030,
+                        7758695034627205826,
+                        5536324597477956577,
+                        919894933846446360,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7253098188202906862,
+                        8078457014000502045,
+                        2315253281734153774,
+                        214508471660559633,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14093098816530847651,
+                        10494285585065270783,
+                        838843678711724015,
+                        804356052395389442,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9854996891933576260,
+                        8489229010041566755,
+                        16389501899682150232,
+                        787357302277325315,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17647865866310225636,
+                        6696847627411926719,
+                        11975657046459379191,
+                        963541883942557605,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6735018484035071781,
+                        2716665981376605974,
+                        7634161365221451890,
+                        1248381386402555408,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2364170728072727608,
+                        9901396488803860196,
+                        16226636557741294755,
+                        65952430543177542,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15438260925717623057,
+                        14815248103773306934,
+                        11145121748785138434,
+                        590042978388473668,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11469694916447000514,
+                        6992287346596776898,
+                        3509599069055154977,
+                        965963173999052775,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1001791870677337704,
+                        11914334301763968624,
+                        13867901177510053908,
+                        312783071010740645,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6794476770655400648,
+                        10600353917117025433,
+                        2804827204846645788,
+                        566496752520092056,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14216106339577450905,
+                        13539325837182652679,
+                        11544359078761063278,
+                        1080141828905685988,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2195605167855748475,
+                        16176409727773139927,
+                        1428807828899535806,
+                        250705857422638545,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7601641451109820955,
+                        13008788507843161441,
+                        1669453976921806071,
+                        1214740967249943325,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1125900967895257331,
+                        13710413630063213052,
+                        4495746717781606359,
+                        1240469411630063390,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8423401731906958066,
+                        13092777511346619561,
+                        9654611453297401923,
+                        553783998855024561,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18193394635569668105,
+                        1173682474312430054,
+                        2159369101351328700,
+                        712705049490553852,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14645063165500899132,
+                        5568561263742256743,
+                        13071589555565052966,
+                        130224631088483406,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15807929696005891793,
+                        14574702898430858486,
+                        17170502428939323498,
+                        43708578105173093,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6848672996473763845,
+                        3373268343835254539,
+                        15718959521562174948,
+                        187938891152595204,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4218764405255821042,
+                        4091412109477201495,
+                        12287873491922408968,
+                        687105813402325449,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9199788367383711070,
+                        6541734896650345620,
+                        12686515911999431902,
+                        513948789098786742,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        33137235690892033,
+                        11040688309798077305,
+                        5953392685084290898,
+                        951716624103457593,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1540625004816619023,
+                        11842605549233532587,
+                        10670118271543167025,
+                        20958115975822109,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16746347801697493994,
+                        8918235585211377723,
+                        14407803259044154547,
+                        93022018366222387,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6861089910651121795,
+                        16232643579207817149,
+                        14087623686390280159,
+                        486607913966219780,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15079100814272165202,
+                        11890680247121400073,
+                        7186512430543481058,
+                        654245097056973336,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1797893310713711284,
+                        11205343843805256950,
+                        13300000610508862655,
+                        849262290279505041,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8870390497986688558,
+                        10624203789102004534,
+                        1704578555440241260,
+                        1301933563215118114,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12841456220717206452,
+                        1775267567659386872,
+                        13362863975288726118,
+                        1286926009933159562,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7910930952590458571,
+                        16899939258006220904,
+                        13055999994508242107,
+                        416149301338434120,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18255824894990226255,
+                        6365503715401525751,
+                        3089023446686220093,
+                        1123932512518990107,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1594307102344433766,
+                        8702474593698978940,
+                        10374655058030105712,
+                        314691094511027027,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11622776107580522157,
+                        14397931103302425052,
+                        14645948804269890936,
+                        773986123929198892,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6435758678242860573,
+                        12538075728990163093,
+                        6365514185286156907,
+                        384460271218795946,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16455227108648468033,
+                        6362144679030429441,
+                        1564774056202399265,
+                        1157738167465972350,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10763385934005869460,
+                        8306530405409606140,
+                        7519113805282524427,
+                        964281840991618613,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7251744874940568470,
+                        9920385666320796343,
+                        18102102101152181163,
+                        1149623418122833341,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8821775990565393207,
+                        18112124214196452189,
+                        10310113240051296666,
+                        759112477793191914,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        311896102049196032,
+                        10013532509262371602,
+                        9542016043418249504,
+                        1107971034670995867,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6684376784091263732,
+                        17673533755920091453,
+                        3224987460236313767,
+                        826515411413897125,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6129611098637278345,
+                        7946695551742571185,
+                        6669028933121682030,
+                        65127409273224101,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9440011838813399754,
+                        17769037589054187845,
+                        14253493380824938234,
+                        567981038307081407,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4465648352104893225,
+                        6305454036185469619,
+                        10855685273641706379,
+                        241674110450364866,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14423891575786663488,
+                        5445856760771128751,
+                        2693542992881573876,
+                        364369614547442277,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4966459005304464084,
+                        4886402758816107929,
+                        12109880751636358023,
+                        818194099284480276,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5791996560196531047,
+                        3368524468468250672,
+                        4889860902231712570,
+                        42919053164450260,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16003421628905609168,
+                        2432838186115524681,
+                        9310187324606595647,
+                        520378378661505272,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5928493019322915864,
+                        14633485322215880111,
+                        8027174479888559493,
+                        687278222986117898,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2682948137764373992,
+                        6714944552048332983,
+                        9621747143303065585,
+                        1047116896229168161,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2543438514283309272,
+                        10435027919527020884,
+                        18274337424161484405,
+                        675718618924392481,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12911285021133227096,
+                        5911525470472563227,
+                        15084595064352758112,
+                        1160358402106358974,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7632282379380762635,
+                        7360346000979559630,
+                        17859427664492423713,
+                        374600696679041298,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1106640261251941633,
+                        1564921939865947084,
+                        1884832283506871754,
+                        1183286210292793216,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3427257070771211633,
+                        5357027406784115786,
+                        14910860801623203948,
+                        550871283644267213,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15130042335694033747,
+                        9136442777085127394,
+                        15886975043411800747,
+                        241278269012953738,
+                    ]),
+                ]),
+                Matrix::<2, 1, 2>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14470233547136014650,
+                        12050446545191081459,
+                        722589402808187717,
+                        58301218473570983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5292507127368187691,
+                        3467192191635772054,
+                        14238261994769709107,
+                        275649424183336626,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<71, 3, 213>::new_from_known([
+            Fq::from_montgomery_limbs([
+                1050046623613023911,
+                13268778607376071837,
+                8904221672492130614,
+                1273339441992694274,
+            ]),
+            Fq::from_montgomery_limbs([
+                4153019583303763566,
+                11390647539793497788,
+                16360116825618116401,
+                1036503722201984421,
+            ]),
+            Fq::from_montgomery_limbs([
+                5911590747790262108,
+                194725568978728709,
+                13519638096131438711,
+                228646837584931025,
+            ]),
+            Fq::from_montgomery_limbs([
+                17670831522146573351,
+                10779247625819283299,
+                16848222853931480359,
+                1182798555987523899,
+            ]),
+            Fq::from_montgomery_limbs([
+                3932092914949664299,
+                18085730274790951202,
+                16084382108253635410,
+                483181012663064411,
+            ]),
+            Fq::from_montgomery_limbs([
+                16723932899416471515,
+                7127411490594890254,
+                12596120808119157901,
+                1141139321220337399,
+            ]),
+            Fq::from_montgomery_limbs([
+                12308412233891983949,
+                15971209088294080653,
+                13335008445242461818,
+                182311186667016785,
+            ]),
+            Fq::from_montgomery_limbs([
+                11642903992810645912,
+                12439397090444711109,
+                16742305974933744170,
+                377811829229089805,
+            ]),
+            Fq::from_montgomery_limbs([
+                2091667989258390691,
+                11272096119006032235,
+                14314228804343148589,
+                505774215781836481,
+            ]),
+            Fq::from_montgomery_limbs([
+                10652795202378930270,
+                6265350844023280825,
+                12166849722795146069,
+                654955812609867512,
+            ]),
+            Fq::from_montgomery_limbs([
+                12920023625568645847,
+                14254394802456644961,
+                14939290069580401032,
+                926861860503779442,
+            ]),
+            Fq::from_montgomery_limbs([
+                3114319854421036006,
+                9066376023217515319,
+                221262224965573548,
+                1282396802870924587,
+            ]),
+            Fq::from_montgomery_limbs([
+                17912259432379241100,
+                9564061834062387065,
+                4227341597616387437,
+                186747970156883303,
+            ]),
+            Fq::from_montgomery_limbs([
+                5114871977757900019,
+                9741485863371246815,
+                7810491833029237029,
+                911719827364515320,
+            ]),
+            Fq::from_montgomery_limbs([
+                17824890594748743518,
+                15496178443618198510,
+                12374070711549517768,
+                70333872867115257,
+            ]),
+            Fq::from_montgomery_limbs([
+                12098789658487568000,
+                2540149808727838444,
+                1809449835133752722,
+                869032457149357822,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17806515620464341585,
+                8152462048270760725,
+                1093413538822418857,
+                773120927159643044,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8011343560111112537,
+                15228330196318820121,
+                6074303727586375066,
+                800939679182343777,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13642189271358034313,
+                2204608497778409837,
+                9392528919380560483,
+                1013209082296919772,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4178556086803304297,
+                18285195402060677720,
+                4511201686596881599,
+                1314784948997722941,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17185966873627957214,
+                8394571295469455432,
+                11203825621091605500,
+                1253423598055257394,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16948659587716964670,
+                6850176835974785642,
+                2417827772297394984,
+                924141098288560218,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8209183660671366325,
+                11597491010310229733,
+                3045486195430363812,
+                604876098022581846,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16718134615165583657,
+                6552948381291142819,
+                15058520059995365356,
+                698465933694149408,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1535101292501433301,
+                3117202706480091579,
+                8897058223994304834,
+                290863881927726172,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2116541976895624885,
+                7688013082221098877,
+                13681595971285206765,
+                755830138371386179,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13545649251610774895,
+                15464156609950226625,
+                5350057952968940537,
+                963541826761973423,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12068811821828041218,
+                15961526260674460860,
+                14321433734038333512,
+                74557470299034205,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16072440304222330636,
+                8306242887132208872,
+                5253048253885461247,
+                1256513557185395438,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8201132537724467056,
+                16821057586288006475,
+                16057998198852813570,
+                140584263330557352,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4821090982701124813,
+                16851154576650761736,
+                8573564216505384565,
+                280626145183834288,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9403454684208761066,
+                7309292078629827404,
+                1830534197537470300,
+                308759691490110095,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14300929553366552282,
+                17128645897452249438,
+                893987645328214895,
+                495748412550879016,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1516192420372023335,
+                16346432615574082461,
+                10991265018852885078,
+                1173998741368983520,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2502331733990182412,
+                7446072971647962220,
+                4623092855690185655,
+                646477070595870342,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15694656155524688798,
+                2101228688586985727,
+                13817566460025466855,
+                1084723581694026253,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8526000490835036191,
+                9095733320074623010,
+                5264315592359585721,
+                838585464196778911,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16964468527613984892,
+                1145652701741121400,
+                14890128873210002819,
+                91223635286922494,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1606800999043201791,
+                6206808524854271186,
+                13356240695582362835,
+                165986162932021496,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9990537687805735780,
+                439916869141732881,
+                1483947682929622709,
+                728831698272075956,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17768818048541280576,
+                1345541265147352907,
+                11022288661874094479,
+                1315178832841870789,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14616851432118721965,
+                17114499755067494904,
+                7549468388411057010,
+                393590350560439796,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12539422357866667322,
+                6926260884082734008,
+                4514356717171739143,
+                635872496598499308,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                884253118546080881,
+                13039237943557279491,
+                17124562726670624920,
+                553515723758070015,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13302486626103220101,
+                10173431127315605033,
+                15493986199828684827,
+                412347023990472678,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7979254541306592360,
+                18366503109095485737,
+                10663653000831572826,
+                1172627844054268720,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11814378010081210759,
+                15803989134549250927,
+                8297962431973990117,
+                895052835445978017,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16588947705927529881,
+                7965143781968232937,
+                15770441181331200021,
+                420354606334013501,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3070199702279247531,
+                14205781769009081680,
+                15841293405723253932,
+                1338176442943756405,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                205497267772378729,
+                205750408920311904,
+                8063696484203476168,
+                842236486736747044,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4751098888747412561,
+                359239884599597563,
+                15133182391275634861,
+                308476850020193642,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5467488067314373867,
+                5178228328455588898,
+                13885135232267168556,
+                448017442898744259,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6065386136539008620,
+                17134690074127204674,
+                4935773087320093014,
+                306816538596950927,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6544944942826558316,
+                9475133174390656127,
+                17229751315115756349,
+                578597608976361277,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11210801229230301796,
+                13589350026019826983,
+                6296408750837775303,
+                365492417096160161,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15178865838928247938,
+                17042457430770313582,
+                2180486325947478680,
+                1115826553193987205,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12616008359994548164,
+                6690802318789217840,
+                16819424745444942467,
+                117544988777064386,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1239678950665593988,
+                4758187578759493130,
+                8356067996717355169,
+                1094815281821154851,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15674295387267502955,
+                13492804112294968622,
+                13901145528500418506,
+                69360147628962095,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5343187174876652659,
+                17434974837390574639,
+                827294491074317366,
+                616757145892322730,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17663858051058164444,
+                14557668822882729020,
+                17406981387585980253,
+                440559208962136368,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5867370377286832875,
+                10609813883032352530,
+                1951632183967763716,
+                222929756116783713,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                17654729982303657145,
+                3265558167556899340,
+                1765291468851859456,
+                1082425318884742369,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14324143420936529969,
+                8695731798877990861,
+                9532140907656035408,
+                797158565426717432,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                120427741020395204,
+                10440451075515319483,
+                18277011053102090303,
+                656598798605046249,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12747058463915851209,
+                3409734098832644892,
+                16854518141035532063,
+                190786263402022640,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3310168722974640549,
+                8008687153436165297,
+                14149348045328583758,
+                193147058774645757,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1595741532624380678,
+                9016939341462306581,
+                6484405408187882584,
+                788837801781293188,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15343500266253862812,
+                12549278847508085033,
+                5824200608133165090,
+                431542060996230212,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7365167026213900364,
+                2929718782215170997,
+                13213615709111614773,
+                317888321315488052,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16642935942707012264,
+                17463332274025925914,
+                9261392760720832045,
+                248917350197871308,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14314687795685455668,
+                12061693716601562572,
+                11755598504139487014,
+                484509067202894829,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1492172270382512123,
+                13826339111647449664,
+                16465353298136460338,
+                679788126246119084,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12532999752268703141,
+                16368953595433194790,
+                15724720606081151481,
+                1140287909456179420,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15391248535933199704,
+                11280612466874898852,
+                14168692283949605547,
+                992282348912102262,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12972651791900994023,
+                13090318923390356905,
+                3019174437691115072,
+                384252677292115708,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4119087831503791060,
+                14120815750698442357,
+                6174397727262073920,
+                262187193114852851,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                1606577324941176859,
+                12116153387829058484,
+                16845247364827099027,
+                16438991333814326,
+            ]),
+            Fq::from_montgomery_limbs([
+                6465878899393193260,
+                13931785102227445410,
+                18211150425976762182,
+                1268988041382719230,
+            ]),
+            Fq::from_montgomery_limbs([
+                3448327573599502052,
+                9130815939399356229,
+                15984778562887871078,
+                1203340939064241838,
+            ]),
+            Fq::from_montgomery_limbs([
+                396738784472197121,
+                18189285121677921781,
+                15148812231081865570,
+                297374582477783171,
+            ]),
+            Fq::from_montgomery_limbs([
+                5165480068383929982,
+                14780637745778156943,
+                13747994414212993879,
+                1227854968624240893,
+            ]),
+            Fq::from_montgomery_limbs([
+                11530305576351671792,
+                14816636819055364274,
+                6161373833283128394,
+                1309770709827933165,
+            ]),
+            Fq::from_montgomery_limbs([
+                10199337696584628077,
+                9963951979620933540,
+                18277982455389979065,
+                1182562071990295704,
+            ]),
+            Fq::from_montgomery_limbs([
+                10681999189951329405,
+                11786257797386053105,
+                14277991443217153717,
+                588761323755914768,
+            ]),
+            Fq::from_montgomery_limbs([
+                5236879680192908078,
+                7141844287209959773,
+                11222923428510590797,
+                550418445049032754,
+            ]),
+            Fq::from_montgomery_limbs([
+                4980096122090169836,
+                10826277060578947791,
+                6628725117376491814,
+                1259272225080388664,
+            ]),
+            Fq::from_montgomery_limbs([
+                1462219582607498380,
+                7023220119968286318,
+                4115899252046501836,
+                869066303250941453,
+            ]),
+            Fq::from_montgomery_limbs([
+                1426364711540255045,
+                848186622252689016,
+                4276821300696643769,
+                175400759717307664,
+            ]),
+        ]),
+    }
+}
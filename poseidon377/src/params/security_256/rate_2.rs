@@ -0,0 +1,5580 @@
+use decaf377::Fq;
+use poseidon_parameters::v1::{
+    Alpha, ArcMatrix, Matrix, MdsMatrix, OptimizedArcMatrix, OptimizedMdsMatrices,
+    PoseidonParameters, RoundNumbers, SquareMatrix,
+};
+
+/// Parameters for the rate-2 instance of Poseidon at the 256-bit security level.
+pub const fn rate_2() -> PoseidonParameters<4, 3, 16, 9, 71, 4, 284, 63> {
+    PoseidonParameters {
+        M: 256,
+        arc: ArcMatrix::<71, 4, 284>::new_from_known([
+            Fq::from_montgomery_limbs([
+                14267038375356003558,
+                9584342189655787088,
+                6623485159448000560,
+                507128039270894479,
+            ]),
+            Fq::from_montgomery_limbs([
+                10114017139941974311,
+                7456381886840487364,
+                10658033618979124872,
+                779428262014699938,
+            ]),
+            Fq::from_montgomery_limbs([
+                16852230370573171781,
+                5823076055451704322,
+                13548517590454573713,
+                1293122530327861982,
+            ]),
+            Fq::from_montgomery_limbs([
+                17051761748796177719,
+                688169393294624470,
+                2689672292069595949,
+                247079689990131737,
+            ]),
+            Fq::from_montgomery_limbs([
+                6486778403343158939,
+                10387421591957272627,
+                17062317157364807762,
+                1058478900511316106,
+            ]),
+            Fq::from_montgomery_limbs([
+                11900417468126719526,
+                11615271761335555693,
+                13271275040776121373,
+                178078778729501000,
+            ]),
+            Fq::from_montgomery_limbs([
+                16266922741013358415,
+                2720252760467203712,
+                7896613198560894938,
+                1163513313182640392,
+            ]),
+            Fq::from_montgomery_limbs([
+                9339825194848375208,
+                7416508062222770841,
+                8123818705706335439,
+                1274313964938011816,
+            ]),
+            Fq::from_montgomery_limbs([
+                8721024143894250180,
+                8297778531294197285,
+                18277780563516021138,
+                597759318720692906,
+            ]),
+            Fq::from_montgomery_limbs([
+                12177366560200111442,
+                1760134971002250720,
+                4079273786840504803,
+                671580390635906275,
+            ]),
+            Fq::from_montgomery_limbs([
+                17298741316532923926,
+                10002512563918992713,
+                12130104802675559255,
+                1088738516468388148,
+            ]),
+            Fq::from_montgomery_limbs([
+                11273687908481021708,
+                17365775658223141097,
+                8808556021785733900,
+                334476868397257268,
+            ]),
+            Fq::from_montgomery_limbs([
+                16706485865058072524,
+                2312361704139444587,
+                1435913549269997212,
+                1177822653103222809,
+            ]),
+            Fq::from_montgomery_limbs([
+                10042209357849409989,
+                2643752301782337038,
+                14455286532548787960,
+                172542186226897927,
+            ]),
+            Fq::from_montgomery_limbs([
+                6106222169722440388,
+                6120412462137358752,
+                18079598565361280168,
+                403377900817803064,
+            ]),
+            Fq::from_montgomery_limbs([
+                15192025970651190674,
+                18286778500192008651,
+                4675109548723041399,
+                140056451144161510,
+            ]),
+            Fq::from_montgomery_limbs([
+                8185995624972002493,
+                13156856333067205509,
+                3107022464107563918,
+                526588033189239805,
+            ]),
+            Fq::from_montgomery_limbs([
+                14943297013864428187,
+                606636032878766211,
+                11795677257915876023,
+                45758681647767724,
+            ]),
+            Fq::from_montgomery_limbs([
+                12860471005081981649,
+                16200429296480823110,
+                4128078332820510664,
+                788761032378142780,
+            ]),
+            Fq::from_montgomery_limbs([
+                12596319952407121824,
+                18300080082482608637,
+                3102901111846104763,
+                123025444501375266,
+            ]),
+            Fq::from_montgomery_limbs([
+                4323331395551714624,
+                17267671242106018415,
+                1470213639696858122,
+                1231399710317043049,
+            ]),
+            Fq::from_montgomery_limbs([
+                15905616685744663522,
+                7751059536031267498,
+                10152372315993343759,
+                1076794959850322824,
+            ]),
+            Fq::from_montgomery_limbs([
+                12324390880164396471,
+                2505032680652062185,
+                3341132045776102379,
+                292633424049963302,
+            ]),
+            Fq::from_montgomery_limbs([
+                16737819873506979564,
+                9820266241852037061,
+                8854493746316671369,
+                768429103103652917,
+            ]),
+            Fq::from_montgomery_limbs([
+                1950508243781849245,
+                14615683006051389530,
+                3752119804650439215,
+                858902541205016558,
+            ]),
+            Fq::from_montgomery_limbs([
+                1595953551130692571,
+                3077117922293033635,
+                17043167015022634709,
+                766530650274452416,
+            ]),
+            Fq::from_montgomery_limbs([
+                15299318960363215049,
+                17908977724319118707,
+                3961915153065490238,
+                1225089967583506210,
+            ]),
+            Fq::from_montgomery_limbs([
+                12355674990842532924,
+                1137937183247826425,
+                18167236173805905002,
+                560590707886467971,
+            ]),
+            Fq::from_montgomery_limbs([
+                14006156314011490646,
+                1033628938750167168,
+                17559828798417053196,
+                1078306684802507973,
+            ]),
+            Fq::from_montgomery_limbs([
+                16493104956719889201,
+                3069676483014906392,
+                10059543134325023003,
+                243765955213144129,
+            ]),
+            Fq::from_montgomery_limbs([
+                15369014753782254045,
+                1710341297440694694,
+                6061934289685348062,
+                397211630315724305,
+            ]),
+            Fq::from_montgomery_limbs([
+                14144414604636306844,
+                7309748729588667349,
+                8256354931308299261,
+                994439382840099066,
+            ]),
+            Fq::from_montgomery_limbs([
+                8305689635683187814,
+                5312846523155557759,
+                11807806495520977781,
+                1344910887391630146,
+            ]),
+            Fq::from_montgomery_limbs([
+                3674835590086396479,
+                2561656382213372028,
+                10388730756252948557,
+                293063739034697812,
+            ]),
+            Fq::from_montgomery_limbs([
+                12996405054051880566,
+                833959596629655333,
+                14919524514191853917,
+                937248717180573496,
+            ]),
+            Fq::from_montgomery_limbs([
+                11273656973986967593,
+                15516038304311561061,
+                14794275022446356311,
+                743417737807488259,
+            ]),
+            Fq::from_montgomery_limbs([
+                15251421920212929968,
+                2151978187155478877,
+                9890462704292329299,
+                140823158064075753,
+            ]),
+            Fq::from_montgomery_limbs([
+                16658092644068453242,
+                6516705855761721403,
+                4910979032365928049,
+                424893863089533459,
+            ]),
+            Fq::from_montgomery_limbs([
+                2657198396806606659,
+                15778440536129536134,
+                16623829427362008295,
+                1061557657244297415,
+            ]),
+            Fq::from_montgomery_limbs([
+                6020621298909218048,
+                11386481953449454485,
+                14101208321674293701,
+                321465977312755665,
+            ]),
+            Fq::from_montgomery_limbs([
+                2761187558357828589,
+                14703839091814946684,
+                13628210315627479905,
+                171987947255668169,
+            ]),
+            Fq::from_montgomery_limbs([
+                10903988938128295879,
+                9517092867224776278,
+                17623717096823589474,
+                59245322797471814,
+            ]),
+            Fq::from_montgomery_limbs([
+                8728113378218990013,
+                5362943511341076450,
+                2668406346440254559,
+                1328656266574106108,
+            ]),
+            Fq::from_montgomery_limbs([
+                5607305746478977647,
+                17750054808759289809,
+                9840687636034710571,
+                865798834888025659,
+            ]),
+            Fq::from_montgomery_limbs([
+                10000691874016586891,
+                1843107285145626566,
+                11525982690344364077,
+                623936563279688167,
+            ]),
+            Fq::from_montgomery_limbs([
+                13865481683652580881,
+                9965716266092902723,
+                12020801370967025052,
+                916659134931786864,
+            ]),
+            Fq::from_montgomery_limbs([
+                14305187156607974724,
+                13123803210462735041,
+                12612199959446720020,
+                47778754284858208,
+            ]),
+            Fq::from_montgomery_limbs([
+                8735980962170609475,
+                5935231462167797752,
+                8663106067364158196,
+                944519860648970627,
+            ]),
+            Fq::from_montgomery_limbs([
+                13600696060495195050,
+                16991603756267531501,
+                8068810178265370435,
+                22426954387460562,
+            ]),
+            Fq::from_montgomery_limbs([
+                3750690610145320628,
+                9098482805560774552,
+                931621655654321383,
+                1215250622037716674,
+            ]),
+            Fq::from_montgomery_limbs([
+                8669078845519283081,
+                5743009562181553178,
+                35805216631795373,
+                490293256252212712,
+            ]),
+            Fq::from_montgomery_limbs([
+                9498731735558993282,
+                8912524636645291523,
+                18421288518432599817,
+                210767862989592481,
+            ]),
+            Fq::from_montgomery_limbs([
+                16259547589069661786,
+                9992370029547954386,
+                3250943447483509026,
+                844689925920955562,
+            ]),
+            Fq::from_montgomery_limbs([
+                7396324983241194595,
+                2919445526827917965,
+                7082375087931875665,
+                26505080410871288,
+            ]),
+            Fq::from_montgomery_limbs([
+                1449098566431767280,
+                10991189830017307169,
+                891803762443809099,
+                76357929809049325,
+            ]),
+            Fq::from_montgomery_limbs([
+                2773106357408695337,
+                3445193856468571623,
+                497428006037321211,
+                1183483977088487629,
+            ]),
+            Fq::from_montgomery_limbs([
+                578566312093579032,
+                14298788978988694192,
+                13312511189513601430,
+                1099741672102227871,
+            ]),
+            Fq::from_montgomery_limbs([
+                17558093901886202708,
+                10803818110444749490,
+                16193646842763981861,
+                339972773670730956,
+            ]),
+            Fq::from_montgomery_limbs([
+                544272859155957070,
+                16070716256600902491,
+                17294689190680953790,
+                1213016538831100743,
+            ]),
+            Fq::from_montgomery_limbs([
+                8219873792839480369,
+                11058826409809394511,
+                9545338910275467689,
+                1325653841099110072,
+            ]),
+            Fq::from_montgomery_limbs([
+                13370981215824573136,
+                1164661980194153579,
+                11003883247532391325,
+                790659225602766995,
+            ]),
+            Fq::from_montgomery_limbs([
+                7254140136335429264,
+                7483219432912003561,
+                17861821882216110476,
+                755261761042099953,
+            ]),
+            Fq::from_montgomery_limbs([
+                17253800600155413317,
+                3270132516806273722,
+                9623806284083905818,
+                11211896246544297,
+            ]),
+            Fq::from_montgomery_limbs([
+                17724997622687395238,
+                2493620862642357935,
+                2482215974742078832,
+                103242772139725696,
+            ]),
+            Fq::from_montgomery_limbs([
+                5807439164395591250,
+                12886325427453775002,
+                10743162647441066084,
+                271897787830255439,
+            ]),
+            Fq::from_montgomery_limbs([
+                9879569928577584873,
+                9617855211733022241,
+                11447271550751256072,
+                909708418457669507,
+            ]),
+            Fq::from_montgomery_limbs([
+                2203468388620102172,
+                8742279467459245427,
+                5896839943347775980,
+                290422812075024820,
+            ]),
+            Fq::from_montgomery_limbs([
+                2371216084304077889,
+                3021574431152526074,
+                10156395687041361721,
+                1040827625716185453,
+            ]),
+            Fq::from_montgomery_limbs([
+                12166890330465240340,
+                2305865644107830443,
+                8853417787372940708,
+                525789151772447044,
+            ]),
+            Fq::from_montgomery_limbs([
+                1440041290453594348,
+                3927191966139577263,
+                6665739669941364204,
+                810873158818731757,
+            ]),
+            Fq::from_montgomery_limbs([
+                16647233442684322489,
+                543463461150183240,
+                13263504191032401066,
+                693950419183672619,
+            ]),
+            Fq::from_montgomery_limbs([
+                500574490466626778,
+                291514531360116009,
+                1682868443165010838,
+                1306880079208184860,
+            ]),
+            Fq::from_montgomery_limbs([
+                5514570774186693067,
+                10279843049616317333,
+                3557074220981460549,
+                1101535366569864086,
+            ]),
+            Fq::from_montgomery_limbs([
+                13087241126058842613,
+                8370804697568709411,
+                7967132077639599708,
+                667614464575915017,
+            ]),
+            Fq::from_montgomery_limbs([
+                6820158883077320731,
+                2387407040221048013,
+                3446282411183259695,
+                1142549633088017351,
+            ]),
+            Fq::from_montgomery_limbs([
+                17551903448449750929,
+                10684359365220258497,
+                15065319859710047500,
+                903862359933335735,
+            ]),
+            Fq::from_montgomery_limbs([
+                4735378143151724197,
+                7446300945153872292,
+                17756045877147149443,
+                819887152670621276,
+            ]),
+            Fq::from_montgomery_limbs([
+                17275667491828236488,
+                13724359743751348325,
+                7034019159178428724,
+                884741847159447759,
+            ]),
+            Fq::from_montgomery_limbs([
+                10440930209831381705,
+                1090744901104968637,
+                9148853768128170614,
+                361324569522749970,
+            ]),
+            Fq::from_montgomery_limbs([
+                11695319128546268594,
+                6966924998332212197,
+                14171063565302317760,
+                816061019292787562,
+            ]),
+            Fq::from_montgomery_limbs([
+                2317527055074799231,
+                7255434840043856978,
+                11580832788247429820,
+                285633575089694264,
+            ]),
+            Fq::from_montgomery_limbs([
+                6845880770404370465,
+                13854401952803694189,
+                5032815781970849379,
+                1183118344823857200,
+            ]),
+            Fq::from_montgomery_limbs([
+                4069471908946746958,
+                11862843280346928985,
+                13078019923899408237,
+                1033854093596616458,
+            ]),
+            Fq::from_montgomery_limbs([
+                11961776806258489559,
+                11181704000977248022,
+                6142457374368699635,
+                959147647753042781,
+            ]),
+            Fq::from_montgomery_limbs([
+                11393827520304392495,
+                15401899824791595121,
+                15327316027276418729,
+                923435063650473200,
+            ]),
+            Fq::from_montgomery_limbs([
+                2040056065263254225,
+                4124267733519579612,
+                16895338295721536600,
+                878031126439052943,
+            ]),
+            Fq::from_montgomery_limbs([
+                7722207366891562388,
+                16446193746821845790,
+                14519910030317088813,
+                671056553283942980,
+            ]),
+            Fq::from_montgomery_limbs([
+                5520715167662949087,
+                12456306356279116303,
+                15874624663840652634,
+                1176709540041268045,
+            ]),
+            Fq::from_montgomery_limbs([
+                8496051422054345714,
+                16167522196199821959,
+                3592726023981475213,
+                1212966943041712813,
+            ]),
+            Fq::from_montgomery_limbs([
+                12865371325182329362,
+                4288039028711200052,
+                116973411817762495,
+                1307166272488599385,
+            ]),
+            Fq::from_montgomery_limbs([
+                15999936071572444875,
+                18140996154843990152,
+                15118751451416539932,
+                1311443931503580092,
+            ]),
+            Fq::from_montgomery_limbs([
+                17279546945290642629,
+                10211393430646352654,
+                2688913566667816120,
+                160855098929308759,
+            ]),
+            Fq::from_montgomery_limbs([
+                16653357048449695916,
+                540647173512647458,
+                301137985609691988,
+                677923053681083736,
+            ]),
+            Fq::from_montgomery_limbs([
+                143194940956093065,
+                5427526646184063382,
+                14556141624519246297,
+                121895890070725373,
+            ]),
+            Fq::from_montgomery_limbs([
+                15681490009880201983,
+                16318181037926275027,
+                2314323112158466035,
+                1052659343876783918,
+            ]),
+            Fq::from_montgomery_limbs([
+                7891778284191745875,
+                581004893289579493,
+                11307359188210266078,
+                776973579461774511,
+            ]),
+            Fq::from_montgomery_limbs([
+                5746934175288484877,
+                3421575060746429144,
+                2089275326866950144,
+                1296936018614639139,
+            ]),
+            Fq::from_montgomery_limbs([
+                12154115558905907166,
+                1728510716941838393,
+                8572856610114104075,
+                1102892437936584331,
+            ]),
+            Fq::from_montgomery_limbs([
+                15379297402266270237,
+                15229436972971828951,
+                3184908981722510215,
+                420386656961872461,
+            ]),
+            Fq::from_montgomery_limbs([
+                7701237466215361525,
+                8649252445910904005,
+                3342505067600608995,
+                1144655858683598834,
+            ]),
+            Fq::from_montgomery_limbs([
+                14442043715522554085,
+                4819364121618432307,
+                16859149536320993490,
+                391639793536139470,
+            ]),
+            Fq::from_montgomery_limbs([
+                322468626812354365,
+                13780565355344873462,
+                6423593533192932273,
+                911725629612815712,
+            ]),
+            Fq::from_montgomery_limbs([
+                16238063927162340054,
+                11698359567315050521,
+                12346667443587559003,
+                470956447867816285,
+            ]),
+            Fq::from_montgomery_limbs([
+                5651298622392667957,
+                9217985090741499923,
+                662693843224228800,
+                1331450116364332332,
+            ]),
+            Fq::from_montgomery_limbs([
+                15157066953968576408,
+                11885097179605359976,
+                12739062833366972867,
+                362406033152370543,
+            ]),
+            Fq::from_montgomery_limbs([
+                9902879646205668019,
+                18097268062190924257,
+                878468734652282841,
+                891682897500290937,
+            ]),
+            Fq::from_montgomery_limbs([
+                17150047804295326611,
+                17992742261237108250,
+                1013871652014250912,
+                1003009146851582299,
+            ]),
+            Fq::from_montgomery_limbs([
+                12150446682593715184,
+                3445385795208766359,
+                15279752603949845789,
+                311749749808978741,
+            ]),
+            Fq::from_montgomery_limbs([
+                6746293080314834029,
+                10262802889672496879,
+                2148922263011014206,
+                402048750129990979,
+            ]),
+            Fq::from_montgomery_limbs([
+                15396337165724759297,
+                6654818200332275472,
+                3222699197217031606,
+                1214552273366565194,
+            ]),
+            Fq::from_montgomery_limbs([
+                3112137548639266482,
+                2432536438077671851,
+                7598061269051486744,
+                685827455779049179,
+            ]),
+            Fq::from_montgomery_limbs([
+                1675833068757851673,
+                5010672851016033703,
+                8491438425230604316,
+                273817600920892113,
+            ]),
+            Fq::from_montgomery_limbs([
+                17414387794937631322,
+                6128974566005656038,
+                12887663032352828773,
+                414685843767016708,
+            ]),
+            Fq::from_montgomery_limbs([
+                15983558557337124629,
+                14894401183387198866,
+                7582728414748368232,
+                1196939725964640143,
+            ]),
+            Fq::from_montgomery_limbs([
+                3620410223258985011,
+                2249443558858325287,
+                1573447237146756406,
+                434699105865116164,
+            ]),
+            Fq::from_montgomery_limbs([
+                13880575035753019797,
+                743448731384981497,
+                12046301820152455428,
+                1225212923179556447,
+            ]),
+            Fq::from_montgomery_limbs([
+                11805337410753791079,
+                11210153008053088987,
+                4831624141965833909,
+                1193291631355658025,
+            ]),
+            Fq::from_montgomery_limbs([
+                14573116874528499554,
+                3472081333111284539,
+                3918879692302051310,
+                1160457578910578515,
+            ]),
+            Fq::from_montgomery_limbs([
+                11273853489602533798,
+                14075156337779819424,
+                17820196319025192463,
+                940994080244379385,
+            ]),
+            Fq::from_montgomery_limbs([
+                13840460006561149192,
+                6602717214893182444,
+                18006738872559257265,
+                793741218341645985,
+            ]),
+            Fq::from_montgomery_limbs([
+                1371788167836031685,
+                7094202370898418105,
+                17088097888193972432,
+                1251943648640181912,
+            ]),
+            Fq::from_montgomery_limbs([
+                15091190948245089462,
+                17612376681821202753,
+                8111336404921319922,
+                613732845454557643,
+            ]),
+            Fq::from_montgomery_limbs([
+                11817862401301123441,
+                2502233793363490062,
+                11494007981973931976,
+                285562950826153504,
+            ]),
+            Fq::from_montgomery_limbs([
+                4663055570862533031,
+                2692480366126566316,
+                2734703743959041207,
+                353294702342375265,
+            ]),
+            Fq::from_montgomery_limbs([
+                12762444414309763014,
+                2086942682077122302,
+                16789039387242758021,
+                942479673040437152,
+            ]),
+            Fq::from_montgomery_limbs([
+                6972840521257279701,
+                14044271858916709295,
+                16889081431721951787,
+                555804946464388959,
+            ]),
+            Fq::from_montgomery_limbs([
+                7196528443765278922,
+                11944182154017808284,
+                18136232283334894767,
+                128924441231116055,
+            ]),
+            Fq::from_montgomery_limbs([
+                17276014618180510461,
+                2986456542737486249,
+                7222468317390821645,
+                1203184137728801194,
+            ]),
+            Fq::from_montgomery_limbs([
+                6492834270542587531,
+                4928196483899239441,
+                12756620577255797089,
+                522908577920286661,
+            ]),
+            Fq::from_montgomery_limbs([
+                1079439373712958503,
+                6673715625008970369,
+                15538436632960809235,
+                24348526531755418,
+            ]),
+            Fq::from_montgomery_limbs([
+                7509934383626427260,
+                5296116367258075307,
+                14028919285464335195,
+                580816008621530630,
+            ]),
+            Fq::from_montgomery_limbs([
+                13623817491467650620,
+                16487224264954451098,
+                13036710254789900325,
+                192855135232991624,
+            ]),
+            Fq::from_montgomery_limbs([
+                11645082523953616985,
+                2216097299971487710,
+                15297445191849496564,
+                1051017977572072261,
+            ]),
+            Fq::from_montgomery_limbs([
+                15125997142371334893,
+                13985439041828735903,
+                14299220308644714268,
+                1144156701464199404,
+            ]),
+            Fq::from_montgomery_limbs([
+                1489727626040772833,
+                15234490597132435209,
+                2749688847823748305,
+                1311940301911796219,
+            ]),
+            Fq::from_montgomery_limbs([
+                12724439040769965268,
+                16820553199546469874,
+                1771226764894696827,
+                107212753963362458,
+            ]),
+            Fq::from_montgomery_limbs([
+                3387188386496556129,
+                16767793358654585491,
+                17921529126577978038,
+                1309565838885229165,
+            ]),
+            Fq::from_montgomery_limbs([
+                11878864103902133091,
+                2843127354867320141,
+                2153288400447253707,
+                693186288887033809,
+            ]),
+            Fq::from_montgomery_limbs([
+                14847250582244442083,
+                12228316302831696289,
+                17066211032507831341,
+                852075604972997145,
+            ]),
+            Fq::from_montgomery_limbs([
+                11773045538386063821,
+                17275298643986254320,
+                13300161396393839882,
+                859363708395913802,
+            ]),
+            Fq::from_montgomery_limbs([
+                6987174552441639333,
+                17656103324601236018,
+                1550530472851250264,
+                757568591785286320,
+            ]),
+            Fq::from_montgomery_limbs([
+                280393080720518038,
+                14411130644328908648,
+                11102822244635260616,
+                116567693119149985,
+            ]),
+            Fq::from_montgomery_limbs([
+                15424280431784922789,
+                7570632652472923062,
+                10362025471462934644,
+                149564017357149173,
+            ]),
+            Fq::from_montgomery_limbs([
+                15686890889590195326,
+                11867811753280084645,
+                17607451949234826528,
+                525826513884726052,
+            ]),
+            Fq::from_montgomery_limbs([
+                803610367270033499,
+                6674089563175187482,
+                11975650236174709010,
+                781173755617251363,
+            ]),
+            Fq::from_montgomery_limbs([
+                17410744082241438817,
+                105913253464746216,
+                18372249500012033821,
+                1291304956764651312,
+            ]),
+            Fq::from_montgomery_limbs([
+                15735668779157540569,
+                3083056033854374169,
+                18256933748125540599,
+                1079576519671558271,
+            ]),
+            Fq::from_montgomery_limbs([
+                10774747849195575238,
+                494132985181090023,
+                18101766937659588739,
+                702404363316302622,
+            ]),
+            Fq::from_montgomery_limbs([
+                3019749539747945200,
+                123412653910711131,
+                15625862257945927385,
+                75859975496285735,
+            ]),
+            Fq::from_montgomery_limbs([
+                14588630108920153489,
+                17654779004644138568,
+                14925487178834476266,
+                472704336197922089,
+            ]),
+            Fq::from_montgomery_limbs([
+                1240712580741345294,
+                9842583344859097369,
+                14638178932635311713,
+                390206024491653337,
+            ]),
+            Fq::from_montgomery_limbs([
+                11235966064661806040,
+                9281466313449836241,
+                9267741195110181095,
+                1158732049107836212,
+            ]),
+            Fq::from_montgomery_limbs([
+                16297187494324224491,
+                10161924122703699458,
+                15975941324852977216,
+                388604262336972596,
+            ]),
+            Fq::from_montgomery_limbs([
+                15077583871012380292,
+                3715015864019226799,
+                4603978243940599341,
+                1247716497477972746,
+            ]),
+            Fq::from_montgomery_limbs([
+                15603231241068625589,
+                16516923814943227015,
+                3371373724581069196,
+                673048128283409788,
+            ]),
+            Fq::from_montgomery_limbs([
+                9477429097571618800,
+                15490319116295805674,
+                6399680484012972902,
+                388665800984169720,
+            ]),
+            Fq::from_montgomery_limbs([
+                15263976136671194060,
+                12507224291851922081,
+                14963824363079500081,
+                1271184219382605690,
+            ]),
+            Fq::from_montgomery_limbs([
+                5610652579617853652,
+                17893685276836220943,
+                901150332302589888,
+                981616350154476939,
+            ]),
+            Fq::from_montgomery_limbs([
+                15971292818465107056,
+                8676850118768410318,
+                1140967111831755220,
+                545689696224555036,
+            ]),
+            Fq::from_montgomery_limbs([
+                1057336855505867071,
+                6722300673489641624,
+                3897407012028483861,
+                548319901620974067,
+            ]),
+            Fq::from_montgomery_limbs([
+                1250912128688775751,
+                17880029245673297308,
+                4333933528973036984,
+                1218070577305841703,
+            ]),
+            Fq::from_montgomery_limbs([
+                10539871213165546615,
+                3840310907887243863,
+                16271798746841916805,
+                1193134628643055115,
+            ]),
+            Fq::from_montgomery_limbs([
+                1474423269823643240,
+                8598188795561202521,
+                16280698519307403705,
+                1281826074912695610,
+            ]),
+            Fq::from_montgomery_limbs([
+                8738553196508813003,
+                18310444282155945943,
+                14530336178705688183,
+                222362519910949259,
+            ]),
+            Fq::from_montgomery_limbs([
+                521816392027493744,
+                11911962513190026618,
+                5892288525087858251,
+                996171402514695809,
+            ]),
+            Fq::from_montgomery_limbs([
+                2587031235679894016,
+                11444315192521955097,
+                14790458911760040881,
+                990175782359783723,
+            ]),
+            Fq::from_montgomery_limbs([
+                3655789221784505404,
+                2383096189781101841,
+                13533360000242484736,
+                1279665883382204971,
+            ]),
+            Fq::from_montgomery_limbs([
+                10886729258668793533,
+                4705575633100993755,
+                2044061590672922512,
+                642404561667335345,
+            ]),
+            Fq::from_montgomery_limbs([
+                965892504184567778,
+                12626988196373965575,
+                16288187284130622755,
+                197012874585354879,
+            ]),
+            Fq::from_montgomery_limbs([
+                9755911560224942561,
+                17122560000526710926,
+                6861695888816903672,
+                88500762508730903,
+            ]),
+            Fq::from_montgomery_limbs([
+                7588633433090811082,
+                15772653120885919812,
+                3259066464608219058,
+                726475430262758872,
+            ]),
+            Fq::from_montgomery_limbs([
+                16806632943918227031,
+                10321052999391152929,
+                6866224285931725659,
+                365280369465757525,
+            ]),
+            Fq::from_montgomery_limbs([
+                15647966742691947917,
+                5308763335921882868,
+                7602493497188805539,
+                571386541607500375,
+            ]),
+            Fq::from_montgomery_limbs([
+                2809703357664950228,
+                18418870441120161351,
+                17380378739347934800,
+                1073148595110261801,
+            ]),
+            Fq::from_montgomery_limbs([
+                14354679703753943224,
+                11701512632513064188,
+                17344257275639966974,
+                498121142563924573,
+            ]),
+            Fq::from_montgomery_limbs([
+                4162429519041036796,
+                8121907673712973563,
+                3496683665890699573,
+                596573071048034291,
+            ]),
+            Fq::from_montgomery_limbs([
+                15022236366301679471,
+                16774899994565076153,
+                17292237724907076853,
+                79707464885376134,
+            ]),
+            Fq::from_montgomery_limbs([
+                1035696402254073945,
+                7025223954687657376,
+                16841859691829787821,
+                61564830818541401,
+            ]),
+            Fq::from_montgomery_limbs([
+                11835294470248333223,
+                1146712745207403447,
+                8647604996645176362,
+                305554030795457577,
+            ]),
+            Fq::from_montgomery_limbs([
+                11078650241885346069,
+                6581243043656412501,
+                1368598526769140541,
+                479447233783315038,
+            ]),
+            Fq::from_montgomery_limbs([
+                1300062873654266438,
+                8006967752755087534,
+                5299902600608854305,
+                1286825455386663026,
+            ]),
+            Fq::from_montgomery_limbs([
+                6662842457542469626,
+                9679409726869612050,
+                5495956539842787395,
+                235422882131157065,
+            ]),
+            Fq::from_montgomery_limbs([
+                11526543821001009312,
+                16877061956877337142,
+                16313011749459846573,
+                208114511793513440,
+            ]),
+            Fq::from_montgomery_limbs([
+                18209413228848271909,
+                2009884991376674573,
+                15613158233268166950,
+                133158733441471682,
+            ]),
+            Fq::from_montgomery_limbs([
+                18294528278954886810,
+                7216993566273914274,
+                4871927334916626517,
+                127395612585098059,
+            ]),
+            Fq::from_montgomery_limbs([
+                3344329099542204715,
+                4917291533535525562,
+                8328070073827122131,
+                727070526665479591,
+            ]),
+            Fq::from_montgomery_limbs([
+                8394121485618196015,
+                15902744740026215696,
+                10991135953050575381,
+                442769970584657582,
+            ]),
+            Fq::from_montgomery_limbs([
+                13040667169476093944,
+                10996175001670731503,
+                17744678363591451767,
+                545230346566226077,
+            ]),
+            Fq::from_montgomery_limbs([
+                18088407225148664487,
+                7595337587941848507,
+                13543831983622973799,
+                628234348139575234,
+            ]),
+            Fq::from_montgomery_limbs([
+                9866226701502754880,
+                7675141519471836433,
+                6957686142061880983,
+                617236362372546740,
+            ]),
+            Fq::from_montgomery_limbs([
+                6202103794414579408,
+                17791912479037513895,
+                10967617178940365397,
+                865373703956511648,
+            ]),
+            Fq::from_montgomery_limbs([
+                4888505571000687706,
+                14673126964094085209,
+                17244248210507003097,
+                1291668433833609310,
+            ]),
+            Fq::from_montgomery_limbs([
+                11688099389179124858,
+                2454965646655077972,
+                12883923012980452874,
+                829638904587211236,
+            ]),
+            Fq::from_montgomery_limbs([
+                931093419805884106,
+                4402870272347066786,
+                9091186357970987494,
+                1296851471334941230,
+            ]),
+            Fq::from_montgomery_limbs([
+                17671194122960871057,
+                8966878363089459241,
+                15260541910664706036,
+                849119236040180367,
+            ]),
+            Fq::from_montgomery_limbs([
+                1608783860329038625,
+                13840039348617894844,
+                14104723554836817648,
+                804331890091122648,
+            ]),
+            Fq::from_montgomery_limbs([
+                10312390852490278224,
+                13402001933019984220,
+                15729740768968961691,
+                652746745520810299,
+            ]),
+            Fq::from_montgomery_limbs([
+                3255204321169319656,
+                14016588195546427584,
+                17371699829642397373,
+                51383864003736131,
+            ]),
+            Fq::from_montgomery_limbs([
+                7103067258065789618,
+                7720205854506971946,
+                17541123560549718118,
+                480649699175259553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17084749704446166898,
+                364017726969756413,
+                5594862737867079816,
+                290215200373955169,
+            ]),
+            Fq::from_montgomery_limbs([
+                2939884376511613011,
+                14701424807380424501,
+                12471222171023486299,
+                323648619493135590,
+            ]),
+            Fq::from_montgomery_limbs([
+                10793302967299754509,
+                13959628538676621991,
+                10395636140084565652,
+                682231630245377470,
+            ]),
+            Fq::from_montgomery_limbs([
+                15603684923414347287,
+                3074099822730786465,
+                7966633788320026222,
+                416295208647135322,
+            ]),
+            Fq::from_montgomery_limbs([
+                2324185724419772925,
+                3955392388479361644,
+                8192272420512464442,
+                715353910280933031,
+            ]),
+            Fq::from_montgomery_limbs([
+                10430896284461347949,
+                8170018785249312286,
+                2279520450942778967,
+                1026302054812442868,
+            ]),
+            Fq::from_montgomery_limbs([
+                3002043239814045116,
+                1252332915624844679,
+                12607145559186354388,
+                1124221160721404303,
+            ]),
+            Fq::from_montgomery_limbs([
+                12946477418066985547,
+                81496611961887044,
+                5207588796878320585,
+                617848741398128980,
+            ]),
+            Fq::from_montgomery_limbs([
+                4761316307134371923,
+                769297941513648054,
+                2848705171081294599,
+                145966453677751820,
+            ]),
+            Fq::from_montgomery_limbs([
+                11038228110257590149,
+                5917121023827069086,
+                16336988497449083866,
+                599056898735995294,
+            ]),
+            Fq::from_montgomery_limbs([
+                1487304918599548804,
+                15061130090838992199,
+                2907433444598412863,
+                537356053168332185,
+            ]),
+            Fq::from_montgomery_limbs([
+                15911578065653800059,
+                6087875788378688375,
+                8821578667009067069,
+                475542551158857484,
+            ]),
+            Fq::from_montgomery_limbs([
+                5139831904726935440,
+                2245733866104497168,
+                12278418608346203565,
+                570762967830597828,
+            ]),
+            Fq::from_montgomery_limbs([
+                9790156489362647509,
+                3797134156210139393,
+                15344029024389813852,
+                522881361058318926,
+            ]),
+            Fq::from_montgomery_limbs([
+                3677288164353910388,
+                251781194548923601,
+                9743734899177047148,
+                1165111570279918724,
+            ]),
+            Fq::from_montgomery_limbs([
+                5894818652213298334,
+                4464263764739554656,
+                2670036903223640844,
+                258046475330076786,
+            ]),
+            Fq::from_montgomery_limbs([
+                10976314992851581800,
+                13229577109907288185,
+                5651665025748475029,
+                542933894882420530,
+            ]),
+            Fq::from_montgomery_limbs([
+                1111693575022866152,
+                3342712532835874833,
+                8871129467770423564,
+                181831566407630128,
+            ]),
+            Fq::from_montgomery_limbs([
+                6210253393538474510,
+                10299345471201635252,
+                14560579024187180730,
+                68845685820816426,
+            ]),
+            Fq::from_montgomery_limbs([
+                1457788218396916011,
+                12162153813997040075,
+                16870179847585528631,
+                1192677948604727480,
+            ]),
+            Fq::from_montgomery_limbs([
+                3222899646490804859,
+                2826476494901081359,
+                11751401637901827063,
+                289925835031655524,
+            ]),
+            Fq::from_montgomery_limbs([
+                9962662607710833970,
+                6813090286257609027,
+                12763102438764784756,
+                902887334897803855,
+            ]),
+            Fq::from_montgomery_limbs([
+                1698233493084529817,
+                2048644653420105160,
+                17197626723279042997,
+                34620723621968910,
+            ]),
+            Fq::from_montgomery_limbs([
+                1941512875582178405,
+                14336543749649666090,
+                11915263073793606411,
+                547189709136468334,
+            ]),
+            Fq::from_montgomery_limbs([
+                6780620269033143024,
+                2068915743963180174,
+                357881781459962498,
+                601886890286875613,
+            ]),
+            Fq::from_montgomery_limbs([
+                13691993204230349309,
+                12846596644249833716,
+                4073180147395359366,
+                417007769661421899,
+            ]),
+            Fq::from_montgomery_limbs([
+                2687161562812267709,
+                3254997164499761456,
+                2346962916131561889,
+                958454202550931478,
+            ]),
+            Fq::from_montgomery_limbs([
+                8467558363664793650,
+                13900486902684225037,
+                16845607371275707206,
+                615301747496931640,
+            ]),
+            Fq::from_montgomery_limbs([
+                5082217035539346581,
+                2771888076118081404,
+                6934473151242466116,
+                1085060482919238404,
+            ]),
+            Fq::from_montgomery_limbs([
+                10045296311514656511,
+                13701036796010022908,
+                6900992522383990421,
+                1145733517695883494,
+            ]),
+            Fq::from_montgomery_limbs([
+                8621350360982596500,
+                10712980084914803650,
+                2028884613728656630,
+                1283734731007467506,
+            ]),
+            Fq::from_montgomery_limbs([
+                16244802863593532203,
+                10488276924220462180,
+                4970181406166192219,
+                841472522818785122,
+            ]),
+            Fq::from_montgomery_limbs([
+                16022511224739984653,
+                756484694701096791,
+                5198634620865865475,
+                594633567131062998,
+            ]),
+            Fq::from_montgomery_limbs([
+                3913060046088172948,
+                6588622562841647765,
+                9593794067913825605,
+                437108179409685691,
+            ]),
+            Fq::from_montgomery_limbs([
+                7464895294058573090,
+                6985934512116968626,
+                14613269156214206998,
+                1302072193062671893,
+            ]),
+            Fq::from_montgomery_limbs([
+                5812318937836847299,
+                4687126798571971451,
+                7207021357759036730,
+                373403088872803570,
+            ]),
+            Fq::from_montgomery_limbs([
+                1565010296773201552,
+                12668658022813670070,
+                16094393618413123650,
+                1032537295748497916,
+            ]),
+            Fq::from_montgomery_limbs([
+                17995234149520934481,
+                12766793991218457018,
+                9765232627651116815,
+                1158752322391331262,
+            ]),
+            Fq::from_montgomery_limbs([
+                16734042119810399313,
+                13162229880127501810,
+                12063684747142236742,
+                159895994496282322,
+            ]),
+            Fq::from_montgomery_limbs([
+                5970863548693313526,
+                6497973745572896499,
+                12578845614098562692,
+                642358618325222832,
+            ]),
+            Fq::from_montgomery_limbs([
+                11233664741069892383,
+                11689295483934709653,
+                10720631646722800201,
+                1040114435711619023,
+            ]),
+            Fq::from_montgomery_limbs([
+                6309253546688225081,
+                5610918209196810348,
+                15657188054173493492,
+                661133364794949873,
+            ]),
+            Fq::from_montgomery_limbs([
+                17265024596833652116,
+                4639495705921862585,
+                17702194150656699446,
+                1049152521074470557,
+            ]),
+            Fq::from_montgomery_limbs([
+                1565817216564192892,
+                16659040713559994292,
+                4611895519888281154,
+                566462306556837284,
+            ]),
+            Fq::from_montgomery_limbs([
+                12797870648535875756,
+                11797256706696812616,
+                13189746135340735871,
+                1135681495488215068,
+            ]),
+            Fq::from_montgomery_limbs([
+                17443920279042583918,
+                3008371308467578460,
+                16593599261217821826,
+                302151700645037873,
+            ]),
+            Fq::from_montgomery_limbs([
+                984032111553189680,
+                16644843766069137855,
+                14025490096305229895,
+                363134200539470897,
+            ]),
+            Fq::from_montgomery_limbs([
+                17359331220839728909,
+                4782135516292001612,
+                3086904455164818216,
+                142060907363355167,
+            ]),
+            Fq::from_montgomery_limbs([
+                6845164506748369614,
+                16716630817543870049,
+                16111030113854360283,
+                1016590122383465344,
+            ]),
+            Fq::from_montgomery_limbs([
+                12555904640447544851,
+                15534859913557949907,
+                1080778673471503442,
+                553678492068694989,
+            ]),
+            Fq::from_montgomery_limbs([
+                8849527970170368669,
+                4065725982879196977,
+                2764176883332903544,
+                922574294242712274,
+            ]),
+            Fq::from_montgomery_limbs([
+                2388495470417327666,
+                3575998583172720752,
+                1944457402286034769,
+                899631537037716972,
+            ]),
+            Fq::from_montgomery_limbs([
+                15952488205826444398,
+                3519012011084280612,
+                13671213639218317473,
+                1060318626850611126,
+            ]),
+            Fq::from_montgomery_limbs([
+                10285962432622622520,
+                15624852446693741328,
+                9701157449086362237,
+                29257902334343066,
+            ]),
+            Fq::from_montgomery_limbs([
+                2927073494682712996,
+                9654418537900085824,
+                3087607938491212442,
+                946235865725091677,
+            ]),
+            Fq::from_montgomery_limbs([
+                9557973517160429405,
+                11896036454663970842,
+                5720682376538060783,
+                440166667933665711,
+            ]),
+            Fq::from_montgomery_limbs([
+                18435080605013859042,
+                5815931579234858115,
+                8798528173347371192,
+                560781771029574012,
+            ]),
+            Fq::from_montgomery_limbs([
+                17684877935829266033,
+                6285410531779776040,
+                6454983796820570324,
+                1064311548073536185,
+            ]),
+            Fq::from_montgomery_limbs([
+                13898755598796505735,
+                7561421904174547826,
+                18299197285819462114,
+                722020944418240959,
+            ]),
+            Fq::from_montgomery_limbs([
+                9388393507024227048,
+                3429965738646462064,
+                12774785539339001020,
+                821045789236020668,
+            ]),
+            Fq::from_montgomery_limbs([
+                1602947322447652566,
+                14188803714613403245,
+                4010585419616326794,
+                648214331765963752,
+            ]),
+            Fq::from_montgomery_limbs([
+                3687619229579172545,
+                13254373007447605500,
+                13709690793704272701,
+                1157631952578514527,
+            ]),
+            Fq::from_montgomery_limbs([
+                12762130965374070728,
+                5526776504875521629,
+                5160047979165811684,
+                1015615043677502976,
+            ]),
+            Fq::from_montgomery_limbs([
+                1838588491765507359,
+                10762069892533148417,
+                8699544346044690899,
+                1313893484971224811,
+            ]),
+            Fq::from_montgomery_limbs([
+                17547627322358478750,
+                314252269582882101,
+                9134650845694241356,
+                1027731845785389352,
+            ]),
+            Fq::from_montgomery_limbs([
+                9701346946963943772,
+                15193689815227108647,
+                705905800186170568,
+                1074329722042486038,
+            ]),
+            Fq::from_montgomery_limbs([
+                12386221090858309573,
+                5422852202368913410,
+                1073050220937269821,
+                646667098094793342,
+            ]),
+            Fq::from_montgomery_limbs([
+                6461335129812021029,
+                12070936777387039469,
+                1747105871814126815,
+                534083402581800866,
+            ]),
+            Fq::from_montgomery_limbs([
+                155104529584280647,
+                14494926142717015599,
+                10961502978738855732,
+                329903192180384962,
+            ]),
+            Fq::from_montgomery_limbs([
+                3392523935052003379,
+                18209473452431399340,
+                7798646387576687816,
+                730739484465686060,
+            ]),
+            Fq::from_montgomery_limbs([
+                3249691677537960618,
+                12146978027772992900,
+                13043611402458312323,
+                671826637040595194,
+            ]),
+            Fq::from_montgomery_limbs([
+                5218686724010574875,
+                909775019592090073,
+                14416334837264806578,
+                460629113837156516,
+            ]),
+            Fq::from_montgomery_limbs([
+                8551011955417245860,
+                17857719517725331265,
+                2008686125138792445,
+                446369772420929219,
+            ]),
+            Fq::from_montgomery_limbs([
+                4689542379245607125,
+                5048527618736839693,
+                1973356288160696452,
+                171662440470886469,
+            ]),
+            Fq::from_montgomery_limbs([
+                13370383432923813323,
+                7133958835504366457,
+                8064439491969675650,
+                117665874995446234,
+            ]),
+            Fq::from_montgomery_limbs([
+                16841266044362416580,
+                18074195272623883476,
+                6936984937236882502,
+                470166341729039593,
+            ]),
+            Fq::from_montgomery_limbs([
+                14215091730887408881,
+                9662156666872254169,
+                3529510122911032685,
+                128425079071610282,
+            ]),
+            Fq::from_montgomery_limbs([
+                7008149922542713065,
+                15111994775545505092,
+                2357941911484452421,
+                1323912091294466399,
+            ]),
+            Fq::from_montgomery_limbs([
+                16775245108847046474,
+                6746786860833723611,
+                16109872520748379542,
+                1173568211697483319,
+            ]),
+            Fq::from_montgomery_limbs([
+                11488752521769051501,
+                2127498205693192344,
+                17485688842943647039,
+                664615660402459249,
+            ]),
+            Fq::from_montgomery_limbs([
+                13267874742340509520,
+                8945349578822323446,
+                8601522868717924287,
+                1097258505988889852,
+            ]),
+            Fq::from_montgomery_limbs([
+                7054504326847705806,
+                6604729871100083274,
+                14003795248872778511,
+                874993399493059497,
+            ]),
+            Fq::from_montgomery_limbs([
+                4345505784603750142,
+                16209450802814971084,
+                11178930931839838211,
+                771178144733794234,
+            ]),
+            Fq::from_montgomery_limbs([
+                18092937866579702489,
+                478613137004343299,
+                9253937744736765771,
+                371207962547404338,
+            ]),
+            Fq::from_montgomery_limbs([
+                2921394185006349143,
+                7370968066747946128,
+                5290953232447576294,
+                642334592131455796,
+            ]),
+        ]),
+        mds: MdsMatrix::<4, 3, 16, 9>::new_from_known([
+            Fq::from_montgomery_limbs([
+                16270238816294404093,
+                17510165790819614716,
+                15988650198697570299,
+                575844906362867708,
+            ]),
+            Fq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+            Fq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            Fq::from_montgomery_limbs([
+                2093244959304135473,
+                7921656493611837028,
+                14184576022206158435,
+                729731999227928846,
+            ]),
+            Fq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17237574486256691881,
+                7678231653376502440,
+                12981859904545217192,
+                832323394471302938,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                14878706952858838161,
+                15410104227655356999,
+                17393275270132988194,
+                905602962502284432,
+            ]),
+            Fq::from_montgomery_limbs([
+                17721242321237835775,
+                11985636621509722110,
+                11478464757469040638,
+                960562638525520553,
+            ]),
+            Fq::from_montgomery_limbs([
+                17640631015407645126,
+                17416650484724036037,
+                14803487960933328666,
+                554882262980868625,
+            ]),
+            Fq::from_montgomery_limbs([
+                10632745392742701465,
+                7191381972905833266,
+                10576427669223334706,
+                1037506184958051122,
+            ]),
+        ]),
+        alpha: Alpha::Exponent(17),
+        rounds: RoundNumbers { r_P: 63, r_F: 8 },
+        optimized_mds: OptimizedMdsMatrices {
+            M_hat: SquareMatrix::<3, 9>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+            ]),
+            v: Matrix::<1, 3, 3>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+            ]),
+            w: Matrix::<3, 1, 3>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+            ]),
+            M_prime: SquareMatrix::<4, 16>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    17721242321237835775,
+                    11985636621509722110,
+                    11478464757469040638,
+                    960562638525520553,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17640631015407645126,
+                    17416650484724036037,
+                    14803487960933328666,
+                    554882262980868625,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10632745392742701465,
+                    7191381972905833266,
+                    10576427669223334706,
+                    1037506184958051122,
+                ]),
+            ]),
+            M_doubleprime: SquareMatrix::<4, 16>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    16270238816294404093,
+                    17510165790819614716,
+                    15988650198697570299,
+                    575844906362867708,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2093244959304135473,
+                    7921656493611837028,
+                    14184576022206158435,
+                    729731999227928846,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17237574486256691881,
+                    7678231653376502440,
+                    12981859904545217192,
+                    832323394471302938,
+                ]),
+                Fq::from_montgomery_limbs([
+                    14878706952858838161,
+                    15410104227655356999,
+                    17393275270132988194,
+                    905602962502284432,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3912868093747842187,
+                    13891638599538547029,
+                    10034520463596988736,
+                    24923162524038143,
+                ]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    12940192809323646256,
+                    10063211937356421632,
+                    16102475073289099807,
+                    635255441558029483,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    13439545502292733320,
+                    8210365196597573290,
+                    10798232243990715182,
+                    209985169044052016,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+            ]),
+            M_inverse: SquareMatrix::<4, 16>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9007339992229280139,
+                    10326424826243116856,
+                    5092355938437508509,
+                    1003135009120454797,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3793860473594899407,
+                    15568206114553404763,
+                    16793548156181542160,
+                    297017364836875886,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13118704239553060426,
+                    7548360664834135031,
+                    15187305571267045479,
+                    227114138088772868,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2709900338282071005,
+                    579150611132688193,
+                    11995391540129672972,
+                    212155260597768490,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3793860473594899407,
+                    15568206114553404763,
+                    16793548156181542160,
+                    297017364836875886,
+                ]),
+                Fq::from_montgomery_limbs([
+                    928445210678486263,
+                    8868160626470841658,
+                    3755794146411517614,
+                    1205473917191967362,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10094677827265099397,
+                    10861765592258933950,
+                    11901484874902647424,
+                    945996443767726300,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5126644488318323641,
+                    9647529625230561771,
+                    1074775780748510466,
+                    567785345221932172,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13118704239553060426,
+                    7548360664834135031,
+                    15187305571267045479,
+                    227114138088772868,
+                ]),
+                Fq::from_montgomery_limbs([
+                    10094677827265099397,
+                    10861765592258933950,
+                    11901484874902647424,
+                    945996443767726300,
+                ]),
+                Fq::from_montgomery_limbs([
+                    824862419246658903,
+                    16129767034744593238,
+                    2170701329025733649,
+                    1301512370243790878,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3590917015388128985,
+                    13161152940282392610,
+                    1559289252300983907,
+                    436823818333081923,
+                ]),
+                Fq::from_montgomery_limbs([
+                    2709900338282071005,
+                    579150611132688193,
+                    11995391540129672972,
+                    212155260597768490,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5126644488318323641,
+                    9647529625230561771,
+                    1074775780748510466,
+                    567785345221932172,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3590917015388128985,
+                    13161152940282392610,
+                    1559289252300983907,
+                    436823818333081923,
+                ]),
+                Fq::from_montgomery_limbs([
+                    7790664405396569236,
+                    15096721329668270063,
+                    11927867068824539342,
+                    454228276177545737,
+                ]),
+            ]),
+            M_hat_inverse: SquareMatrix::<3, 9>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    3075677070517273610,
+                    12935975526496926241,
+                    5413643861495614409,
+                    209652601170259502,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5785577408799344615,
+                    13515126137629614434,
+                    17409035401625287381,
+                    421807861768027992,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17408664359600548624,
+                    17034391550693366562,
+                    14790887709711326522,
+                    240850225403047528,
+                ]),
+                Fq::from_montgomery_limbs([
+                    5785577408799344615,
+                    13515126137629614434,
+                    17409035401625287381,
+                    421807861768027992,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6563011294473575235,
+                    14911990801343518815,
+                    5387840296410814352,
+                    742514872555164941,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3251880405938485206,
+                    8073678362843046478,
+                    14394469848155607566,
+                    254586312717322188,
+                ]),
+                Fq::from_montgomery_limbs([
+                    17408664359600548624,
+                    17034391550693366562,
+                    14790887709711326522,
+                    240850225403047528,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3251880405938485206,
+                    8073678362843046478,
+                    14394469848155607566,
+                    254586312717322188,
+                ]),
+                Fq::from_montgomery_limbs([
+                    13824643481143691730,
+                    14179537619338997380,
+                    15459123094703748781,
+                    28520280494317649,
+                ]),
+            ]),
+            M_00: Fq::from_montgomery_limbs([
+                16270238816294404093,
+                17510165790819614716,
+                15988650198697570299,
+                575844906362867708,
+            ]),
+            M_i: Matrix::<4, 4, 16>::new_from_known([
+                Fq::from_montgomery_limbs([
+                    9015221291577245683,
+                    8239323489949974514,
+                    1646089257421115374,
+                    958099254763297437,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    15304308768469175717,
+                    5963898101674778386,
+                    2217901424116859924,
+                    621927485165643681,
+                ]),
+                Fq::from_montgomery_limbs([
+                    1992828093851932502,
+                    17761235840979330531,
+                    12386375258518318445,
+                    630695528524171611,
+                ]),
+                Fq::from_montgomery_limbs([
+                    11976785764365666466,
+                    1864493080255193543,
+                    3740155929284262614,
+                    983489955645575540,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    1992828093851932502,
+                    17761235840979330531,
+                    12386375258518318445,
+                    630695528524171611,
+                ]),
+                Fq::from_montgomery_limbs([
+                    6440074696809039911,
+                    11851868235440839970,
+                    18182655271835725258,
+                    947671567711064549,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3657623636555921395,
+                    8698597048056005988,
+                    600897128254479707,
+                    184934216197356458,
+                ]),
+                Fq::from_montgomery_limbs([0, 0, 0, 0]),
+                Fq::from_montgomery_limbs([
+                    11976785764365666466,
+                    1864493080255193543,
+                    3740155929284262614,
+                    983489955645575540,
+                ]),
+                Fq::from_montgomery_limbs([
+                    3657623636555921395,
+                    8698597048056005988,
+                    600897128254479707,
+                    184934216197356458,
+                ]),
+                Fq::from_montgomery_limbs([
+                    12114033748603876609,
+                    2817231083846583244,
+                    11051381574652308880,
+                    634576545221334681,
+                ]),
+            ]),
+            v_collection: [
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2093244959304135473,
+                        7921656493611837028,
+                        14184576022206158435,
+                        729731999227928846,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17237574486256691881,
+                        7678231653376502440,
+                        12981859904545217192,
+                        832323394471302938,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14878706952858838161,
+                        15410104227655356999,
+                        17393275270132988194,
+                        905602962502284432,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3544248464247567155,
+                        2397127324301944422,
+                        9674390580977628774,
+                        1114449731390581691,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10793968004403082763,
+                        14776098320186757028,
+                        3926381262294758649,
+                        695945431440508002,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2462384498404250202,
+                        12590306298374345930,
+                        11038784733069574134,
+                        419995880141749771,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13532986407775683895,
+                        9476195915097473266,
+                        1008114724683017572,
+                        545860035442724601,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16204216982352313226,
+                        12925674836598514697,
+                        6751896652466221822,
+                        941483813900869065,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17313019480228630806,
+                        566130559971125633,
+                        11091260944862748358,
+                        830377232140527827,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13653317352261633777,
+                        10493929866956769626,
+                        8396755137825041221,
+                        1146048882209297127,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4132278932442914408,
+                        14861671218963566328,
+                        7959790402925606057,
+                        1318243553399078199,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13456767915419596367,
+                        17246911423899751971,
+                        5711218416857391278,
+                        35403035103928169,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12907160498704673184,
+                        12152379198293294711,
+                        9252679699965582400,
+                        1224554265731421992,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13419199242664167498,
+                        13891452473958728318,
+                        13543072901518191311,
+                        1037106053989502131,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16666897222883125359,
+                        12131730689573449836,
+                        13003710399516055414,
+                        648272461984720515,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13991351580088989461,
+                        17864339801785851041,
+                        11695126599066872867,
+                        921749339582652232,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11906579800297278037,
+                        11470441940361903448,
+                        15097374289742739909,
+                        275174984759910272,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18232546040145726314,
+                        14147307759659284922,
+                        991776681097878315,
+                        1065560959455072221,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3117800866767190184,
+                        1873664776760606669,
+                        13118797281780700540,
+                        918694694774008673,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16198059297050513347,
+                        8432506190058226772,
+                        9370514214732897655,
+                        212402421053033076,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2518782581476945766,
+                        6771388879653376635,
+                        1394823901821599775,
+                        786725020056686548,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4999125751846201369,
+                        9316583554979616827,
+                        11717167420397718381,
+                        1066546495878431233,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1873871365393108203,
+                        11936414467331150546,
+                        11113449918732250015,
+                        317274884289334993,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5753682051272078512,
+                        3471409429911499297,
+                        5526758011092646462,
+                        1323229023757477097,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2662759502586109043,
+                        1695416312126819095,
+                        1012723056571339871,
+                        933004606031207884,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16642301735251917773,
+                        1183412391970839036,
+                        3358256470522122468,
+                        496531810743047820,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14229377213726726413,
+                        17261160081985153069,
+                        9576276288950416830,
+                        700741200569462595,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6784297665956970408,
+                        11357803791388406129,
+                        11332180445619439434,
+                        1106781037719016960,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        73464770077821962,
+                        229820375535861605,
+                        4835258747884954879,
+                        991229687813626051,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10226838402609267711,
+                        357277105338386461,
+                        10898037771405216876,
+                        738876256175427730,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12081508766912259869,
+                        15930347767913903841,
+                        8853663281043083447,
+                        10038595465753804,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        112737203409514332,
+                        16114412090287437958,
+                        6856032020202317595,
+                        113207183629292293,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16338645855580340203,
+                        2107869018362076089,
+                        12567383037519742350,
+                        815641356564041922,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12125233544299543001,
+                        11893476647476625145,
+                        15457331543628022009,
+                        800448530217165123,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16675745322148137368,
+                        1493849644880660500,
+                        8185984318393084462,
+                        138242285432587533,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17724307965454702231,
+                        4807522038799077672,
+                        8746642154843823745,
+                        883881308868446414,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5656989936467702279,
+                        3451526239075705633,
+                        12473714456419681817,
+                        720076561380511801,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2752710254647861908,
+                        11030634120397179297,
+                        7520710074325720708,
+                        849095153678924863,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15714192245785854425,
+                        1934486269946887645,
+                        249860026764225808,
+                        1186606499687026685,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1065442882426850105,
+                        18187640339393109068,
+                        8850384031746892082,
+                        317569289072515041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1837681773828322091,
+                        10850972211403707738,
+                        336406577412961307,
+                        885368386317519809,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15758214746904074141,
+                        11583431684684102838,
+                        7662385228843486433,
+                        217065657978291661,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2532634061285367793,
+                        4387885387692314654,
+                        12158131981630891597,
+                        230565483900354414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13346419382798549501,
+                        18280976687063744023,
+                        5606331118089126185,
+                        273578743992252446,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17577657789855982030,
+                        9630457784692257422,
+                        12902853055721181839,
+                        36459624765202074,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9935908372408102992,
+                        14641284087709280054,
+                        17684292083487177951,
+                        1155088959507788459,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        541701721303367728,
+                        3959174565794182274,
+                        18151179085562458542,
+                        340776254243440974,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17690336527687088662,
+                        10937885152973991052,
+                        3836836874424836886,
+                        1090509013794447238,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12310573214022515884,
+                        3043141462744167127,
+                        7406092407251694082,
+                        9160197655286166,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5101804845510497503,
+                        7081024525590050191,
+                        11990249650950760548,
+                        264716438448155623,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14013782434072143361,
+                        18031550597362521222,
+                        4176916092795610844,
+                        1008783913934215313,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5197923006462389769,
+                        10850213912970425984,
+                        6236288807254229677,
+                        381647096775612803,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16904562081938006603,
+                        1107639524721847015,
+                        12857487247803522951,
+                        271937989000148046,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7013951325055848953,
+                        17628406277137302222,
+                        10293198099660764650,
+                        1028289934164465192,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9440252581760876632,
+                        13906337780931242750,
+                        7219961419517025615,
+                        863594454449643923,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12415229001429896965,
+                        11797271759794076948,
+                        1936084790537971337,
+                        467019200964667988,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13553333187067381210,
+                        1913316352777824197,
+                        2458517210763467943,
+                        416174277494956025,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7868885723930814728,
+                        3969684852222006688,
+                        847603129181085226,
+                        823204710257787357,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2624306993317474736,
+                        1804999503475194353,
+                        13323117091522537138,
+                        113213718216814537,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13028853788877299982,
+                        16207030875451345887,
+                        2928890708384561735,
+                        948835740600760535,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10332479856281518610,
+                        5461907926022368118,
+                        7566281008880752185,
+                        864542613737871414,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11550343341750412987,
+                        1921332364560602185,
+                        10690162369512005698,
+                        600190744590186860,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11343163367577472637,
+                        7523233831279170268,
+                        14378595658429552329,
+                        120678106820511750,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1497803481831246817,
+                        7312638636652871954,
+                        34925480233639111,
+                        44726202564591048,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6152756361006073958,
+                        6090155380815116625,
+                        6481578954979806710,
+                        476190110671401465,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18102241645952999143,
+                        12827175776728527569,
+                        10917946848969687318,
+                        1031360619507992497,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10479806376196948796,
+                        1250933353292865403,
+                        9714052636425979898,
+                        724896984716198201,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3536451958691514845,
+                        3820878089540374661,
+                        1602865878357815152,
+                        303292371629072230,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2647458017961703424,
+                        14255003531541688237,
+                        9264134941484745925,
+                        1144438898154277748,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14896674334234435047,
+                        6601140327636410720,
+                        11594733032791631077,
+                        947808208377648736,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        93857753711709862,
+                        17114219253807441153,
+                        16571911382240086620,
+                        644986335071170285,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11491045973623638105,
+                        5832623718891215316,
+                        4637897368334583442,
+                        1270136782835782403,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12059760086965268413,
+                        2104859632417338119,
+                        3074047925029609319,
+                        208685506241841351,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        507458650320731749,
+                        9076652763882528689,
+                        10828995108044982790,
+                        52861166057592230,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5221245564257429544,
+                        602546441039285868,
+                        15463515802777952068,
+                        500262458682360187,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7894603091209534363,
+                        5963299443776147743,
+                        13622917766282071553,
+                        713444535504669723,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4707822806056225947,
+                        13008601324591260598,
+                        6664196336989843628,
+                        361594494120552721,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7234555332968855468,
+                        13223173585719696534,
+                        305242624856210053,
+                        317409461914184558,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11052531972415731524,
+                        6240249964832493008,
+                        4690512975789608724,
+                        274300931659090955,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12865203614941348009,
+                        13629535421935003532,
+                        14381802801123693296,
+                        1108602536075241162,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5342089493189303187,
+                        11299417329134660514,
+                        10437815285599784456,
+                        224625807758727328,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9419019633707904193,
+                        17287104400249488354,
+                        2449834218878066498,
+                        880784160362712225,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1290387194510150606,
+                        300217878573287348,
+                        14712510331530271944,
+                        675169241465258261,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4319449301091482228,
+                        6604997191961440301,
+                        3677473832318302588,
+                        1226257433999087430,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9534885027294214845,
+                        4233504142154122132,
+                        671184154419538440,
+                        588715198189399632,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7834331777517711657,
+                        12046762886677754640,
+                        12085017871628461830,
+                        1280695769450865349,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17051048636914562332,
+                        9931763496822042016,
+                        812132922227142181,
+                        1215806818267015733,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10248407954863227910,
+                        6765342538198222232,
+                        3376905111922655053,
+                        689295470605671650,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        852480703421168492,
+                        12355793660799378618,
+                        6731311636032405498,
+                        552777249330804449,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1991623127336121727,
+                        6505031450361927139,
+                        15924460190557187785,
+                        1320271660125443798,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17438135180680113454,
+                        12154982230827864980,
+                        10857096699189529725,
+                        334861850663499479,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12624059910780607141,
+                        14938141535714956518,
+                        10243410760322861612,
+                        175466020402160466,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11857086838875231849,
+                        16760011700969259517,
+                        8560727830203057188,
+                        152554536976681898,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8366377495511845655,
+                        9027212486725311737,
+                        621896686425068484,
+                        59908093638686247,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6821247813496409711,
+                        3809783449247177170,
+                        14246355778269985790,
+                        100067199319926835,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7114331187643612321,
+                        12046884410610249817,
+                        11641883396644737569,
+                        816513613398229934,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10903022878926357374,
+                        5979826527581930957,
+                        9964619736578287748,
+                        1271434037472220735,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17632231262698779721,
+                        8707825535027157717,
+                        2435867734082505465,
+                        600255330328129779,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9678276357057662336,
+                        15065198159805039034,
+                        13844736019338558457,
+                        1030744040472859467,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13577465593870985174,
+                        13019988982449073022,
+                        5630144684460110850,
+                        1067109139774292952,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14181846117952974562,
+                        6483192693799719511,
+                        11686204140875594690,
+                        53556822744562628,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8837072896923545588,
+                        15782617742266896467,
+                        17768352032495473622,
+                        874284623546864947,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4930882077235357086,
+                        16598775934612528495,
+                        6427030583744520257,
+                        454940500998793676,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6127875275316077517,
+                        1098018032759962519,
+                        7168921354059351583,
+                        539217452595689197,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16140691465259840229,
+                        15459742228472625389,
+                        684190287722767135,
+                        24975807305976285,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5477237112676987230,
+                        18260040498029279223,
+                        13809783258308105380,
+                        980761446574822176,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1929545376910953199,
+                        16208816609924361154,
+                        18223810928582510715,
+                        511526970360334692,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        745408440353117228,
+                        6446148919829444880,
+                        9859762884989490143,
+                        948867755568230344,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9584744285611425921,
+                        3444162698216444647,
+                        9075048563141887487,
+                        1091845147398737163,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3992387261031178206,
+                        8442288390990939021,
+                        15878335778617413021,
+                        434932132976693117,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5181129751379370298,
+                        11263035640978647486,
+                        15656906907851165855,
+                        760113975833886721,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17283399203902770817,
+                        4823828749608678477,
+                        18335927644322855466,
+                        291075872541594381,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3563402981706258075,
+                        10804664455293863978,
+                        9875622782079335999,
+                        673828909275117105,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7376305669720650864,
+                        18084850803968701462,
+                        7493247936343898672,
+                        242133383968305422,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        768405041291620160,
+                        16402084532604224467,
+                        8326493203130023144,
+                        527375926353461289,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4493884858502538109,
+                        14177524374133685442,
+                        8564208764161603190,
+                        950807508820071672,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5451032349556884040,
+                        11979111833367591785,
+                        4423067886379875705,
+                        714685638652506398,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7280850589105638813,
+                        2021009939841738455,
+                        3305309641247301754,
+                        1145853947100305381,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14277617808656927503,
+                        3424217281043782122,
+                        17387678576036547232,
+                        286945907482331256,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17836403466023391582,
+                        9600058718067800158,
+                        1215064310075334312,
+                        512091907554853722,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15911401798815879246,
+                        3441727988121772734,
+                        571069208554741638,
+                        87781266185380480,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14300405535407053528,
+                        12933427870157369458,
+                        3165407817013718879,
+                        147023269816399912,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16984767622482815352,
+                        15012536080757057955,
+                        13331788611771111559,
+                        790594190457488637,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13666344951743495978,
+                        4011883471625095853,
+                        8687340369204333578,
+                        6335869032028204,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3589738941147745168,
+                        6375438694001021390,
+                        6318076178695241551,
+                        225530171938347001,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17534454671862535945,
+                        8734526482948168277,
+                        18100888928470402529,
+                        412792969510827576,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15210649698843525855,
+                        3317283543412109116,
+                        10266286337495307332,
+                        1133872079638661976,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6863705326169845975,
+                        7913744781595172103,
+                        17721461713943575894,
+                        800986608178990873,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6627643700182591015,
+                        11426893535294500202,
+                        7043441898549305883,
+                        732296594080166130,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2077116234070680341,
+                        9606822698884172893,
+                        225917718822356973,
+                        467011003797948605,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1379071214991643980,
+                        14896879420105586606,
+                        9100121097383763652,
+                        1344423996678203204,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16334202264073061081,
+                        12265074930728041035,
+                        18047637450699268484,
+                        1144762413062845608,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4127793645839256164,
+                        329705433349336544,
+                        12916738011263886703,
+                        1237776029058458664,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10638845718313681251,
+                        5204456183271817174,
+                        5055586724657168401,
+                        244519569217100845,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18168492025145726775,
+                        1800668050125999412,
+                        11263495083059089404,
+                        572604685401127430,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2424127554614197169,
+                        13670313902085523295,
+                        5729581868200892845,
+                        1185633674245725234,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13391715393809511192,
+                        2136015245293052456,
+                        9399429027056635007,
+                        86837547138622120,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14873164522160417143,
+                        12556126955408094439,
+                        11828972389659422827,
+                        777263461471811751,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13843167715386350044,
+                        1117048182126965944,
+                        8319689258810501467,
+                        315176530189306295,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7135536190247804692,
+                        9026192352055941907,
+                        6508946745899593356,
+                        715020329629880951,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2950471950444940504,
+                        9931002328508553321,
+                        7423810810280461943,
+                        1337961697757219155,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9376683662997739245,
+                        4890182394417731118,
+                        14544384991196881351,
+                        65676515162486741,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18207352499870555995,
+                        7727940318204839523,
+                        2372324174922918603,
+                        357802969035685875,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1941997307693763690,
+                        13059844071888594021,
+                        13800242043957569572,
+                        148007021621402932,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5228291535864900839,
+                        17818009621279245853,
+                        16683012392490710991,
+                        993430496392305468,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14837173888363587504,
+                        7256433229228745487,
+                        10679295223486765918,
+                        932706843420686165,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        762523639826343513,
+                        14421677125898938007,
+                        8733659425457326140,
+                        417770694270795324,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11251786383603128624,
+                        16995486252256364884,
+                        119680462838471498,
+                        1239882880161636727,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6851787281096470988,
+                        15905444806716867573,
+                        11116281144265974928,
+                        902828350771953574,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8756128085064497195,
+                        1931061559360390117,
+                        15790745217839855376,
+                        123851268364975171,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        229601264774550882,
+                        15643079034841534352,
+                        2776308373436918391,
+                        1191904265601044045,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15378108091219863244,
+                        6530239423753441046,
+                        3621137115945231373,
+                        1339393270487541285,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16021795714268825165,
+                        3298814803480024643,
+                        7346030191665711242,
+                        1149590768838055066,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2568037920841246230,
+                        10745685891977186721,
+                        12748952166809899998,
+                        1102232561863033879,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1998206298009799819,
+                        5352580740808335973,
+                        795752737031846270,
+                        1180776003101888545,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1504662908770842616,
+                        801756760144270900,
+                        7408646004440952909,
+                        240871648327951776,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14319626910735357536,
+                        3162493398424631895,
+                        7683385807359292670,
+                        830923459404970067,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15590439244148767408,
+                        13421343740269634823,
+                        3147387741733834051,
+                        233061649286627582,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5629904360167900415,
+                        1603514194547510457,
+                        12458110764968386040,
+                        420117825975322849,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13508951649538962524,
+                        13842481091735246125,
+                        124814268400897828,
+                        1161188179537001734,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14210640685284632749,
+                        14672537187890273563,
+                        3546985791596883119,
+                        536174322787539002,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4737537776762907681,
+                        479530455693770357,
+                        12169826069606386775,
+                        336196110250222245,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3973766991630405987,
+                        10200113960516900442,
+                        4806008924906189256,
+                        272114102593754671,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6701479506239571206,
+                        6608415268377503943,
+                        12719560801047296636,
+                        369021601491575284,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1683072062812655892,
+                        12899319503890524261,
+                        4352614635926418842,
+                        507399132358953121,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8537869593410534166,
+                        15503161611969327331,
+                        4071991661196311045,
+                        994287224044827301,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12192626066548252232,
+                        13985503311680157749,
+                        6785953890170077671,
+                        862064737656007483,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6040089103948853293,
+                        12226637811569354294,
+                        6659771091553808997,
+                        1205717762694962211,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7953514680433375819,
+                        17230260735890801811,
+                        14875712389992217735,
+                        1200305382196250138,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14927686130640150640,
+                        16017968471334408184,
+                        17012541827534367833,
+                        896255524775006375,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11211878678758284883,
+                        16372744263442638438,
+                        13583346251691749641,
+                        602224944426245292,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10041885777786485869,
+                        14206606210001517869,
+                        6156143775030231620,
+                        443403624895756994,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13940161437808146954,
+                        9410713776269289422,
+                        15043458596435776810,
+                        948320407740031724,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16151029407706589745,
+                        3387263781753596122,
+                        5787685600981826338,
+                        836746092933913308,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11810097346305676049,
+                        13124867174017638702,
+                        2231791726149991627,
+                        882509318769728857,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2141889660997417904,
+                        8982418913023189407,
+                        5460563825801236793,
+                        728620418455168899,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3939377957559014764,
+                        18248969916435128906,
+                        16819449343173283536,
+                        494840621143441195,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7052788913641313403,
+                        5843260224217535964,
+                        4500966417319049989,
+                        1338004115836755486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4312709994106011614,
+                        1432088519157045587,
+                        4562718137526528433,
+                        507022826822793818,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13588210697731073628,
+                        809870067805765342,
+                        15614490267927152554,
+                        909658846658865210,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1651846528840336774,
+                        533944805389308264,
+                        8414350162130127333,
+                        665383754339487639,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7613015710603161648,
+                        15242949720882808719,
+                        5596504067474914373,
+                        457024372875256838,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12318683181904981420,
+                        7651897040432660733,
+                        11281431093892082557,
+                        916191545683557817,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13975449828076117672,
+                        18112086868991566484,
+                        920712643871985195,
+                        258679946709010944,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8949149204055602570,
+                        15914297611931515754,
+                        2583994534843671357,
+                        638347749486032734,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14099063564369098511,
+                        8436550672187895422,
+                        3485824970081776690,
+                        1040214611985880832,
+                    ]),
+                ]),
+                Matrix::<1, 3, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9303833870934268836,
+                        15389513102133802585,
+                        18299664428177764770,
+                        432492732926620421,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10673970175639039915,
+                        15080687861288788133,
+                        13965744926092729549,
+                        1027750859359035415,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1725056155997165386,
+                        14307158036532027420,
+                        14847438502822434650,
+                        1029399764345101831,
+                    ]),
+                ]),
+            ],
+            w_hat_collection: [
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3912868093747842187,
+                        13891638599538547029,
+                        10034520463596988736,
+                        24923162524038143,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12940192809323646256,
+                        10063211937356421632,
+                        16102475073289099807,
+                        635255441558029483,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13439545502292733320,
+                        8210365196597573290,
+                        10798232243990715182,
+                        209985169044052016,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5751631526606014397,
+                        8462191803631620224,
+                        10509863267471683158,
+                        447861109114791478,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1437717886047567450,
+                        16559126213892176045,
+                        11375302712551931442,
+                        1010730393746897317,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1032470319927068309,
+                        9033645067614029358,
+                        18086293033601348125,
+                        304884835659797125,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10030163671426435897,
+                        10038896509362625400,
+                        11411437429949289797,
+                        606845429174704889,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13498048305505589045,
+                        14368156343173972859,
+                        5751396272374329581,
+                        1272023166617958572,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9805803927184323301,
+                        13425206131835998785,
+                        13841345243897428245,
+                        615350201809158017,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14956193223177978026,
+                        4328965669156513709,
+                        1332264507148878686,
+                        908612595539291972,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15512085810689995447,
+                        1278811290279093247,
+                        9713635222980865549,
+                        89918473543807263,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10908982736163998884,
+                        10522718116611575026,
+                        15481410495350859749,
+                        209345561210183580,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11298966019883739719,
+                        3737954136375979756,
+                        15666428783954356951,
+                        93322090349950905,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10237667835076512281,
+                        12325953953570405613,
+                        12285254403281429040,
+                        507107093264211690,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14936641530723543297,
+                        12684654093637137182,
+                        6573648560098330931,
+                        479128193670708215,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4665624059074225901,
+                        569773391663140707,
+                        8039376161136700750,
+                        305908263293765813,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12181552876023559131,
+                        7764448317943448064,
+                        10288520924018884818,
+                        1103852203169073095,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15135999239214672948,
+                        10790616626797062588,
+                        17724896909590777918,
+                        1127104704406195421,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6486714260719829146,
+                        11040751111500249834,
+                        8958297661837625585,
+                        504881187176923268,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9943503409323924620,
+                        9519655296534470798,
+                        10031214880424175980,
+                        880224541550113700,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2137123775246483621,
+                        14575781645937393202,
+                        6082200831828333306,
+                        319693495256136311,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        18213874187166503017,
+                        7357620257182256357,
+                        12753979462901781784,
+                        653327632081049969,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5648402480861501803,
+                        1298801804080198935,
+                        16361566576947818947,
+                        92096317293783223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17945395108017238106,
+                        3498558197841053284,
+                        7092504945026653103,
+                        372132312547601421,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15622147422254947733,
+                        250116726000401472,
+                        8318204049018604061,
+                        866935352572882912,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        18241804623807754966,
+                        4053430455599371737,
+                        4911593924672747195,
+                        915087345052626509,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4269143728338544596,
+                        14357201884344458328,
+                        903006646797065399,
+                        462454343275509581,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16493823488007719206,
+                        14779329128742765495,
+                        18378884619527915493,
+                        360610956997885799,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8231956009608211607,
+                        2237272948977110346,
+                        11234918376541957482,
+                        211807215316292218,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4694295422393950539,
+                        494515799644279017,
+                        16377559543581347883,
+                        1023664249665260398,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        269632147881481827,
+                        17676834172042717446,
+                        13502391546248246285,
+                        158178875674850167,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        854535799571654664,
+                        4436276133137563398,
+                        12250035363748361097,
+                        569847052981690503,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8356573900420286918,
+                        3943111982741005596,
+                        6690528014909196681,
+                        100899612597296936,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12053108327360532468,
+                        2215278670095839574,
+                        18310257358516903189,
+                        507153778977982696,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15548327836627863789,
+                        11669395097045425760,
+                        16626297677851084169,
+                        692658363999722441,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5590241427234064239,
+                        15193192965574703365,
+                        7643293374071077424,
+                        502287078265137211,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        7121109350102790552,
+                        3842989306298966953,
+                        15442678426893498207,
+                        43682019324253859,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8543065120635606147,
+                        17618464589270231617,
+                        4296924638616724859,
+                        409105874035239827,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12676854185827013572,
+                        13888077538347807744,
+                        7139127667431143175,
+                        1225243047885808896,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4347759847866483265,
+                        5855881520281836030,
+                        5505541235252858831,
+                        976243446170017999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1880808402247115426,
+                        943890821775946445,
+                        12000264441794154793,
+                        12973763854532988,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8496845738719301602,
+                        11057791601996073401,
+                        18430915991403133806,
+                        442312408397388928,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4597591998391646042,
+                        17549544536524741100,
+                        7632675978077243758,
+                        789227364575351796,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6247914330752058188,
+                        17379927947827819186,
+                        459262613159719376,
+                        17669136651242999,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4416622626270946808,
+                        9011173931926497864,
+                        2385315687804767815,
+                        1100085754679242026,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2858027675101851698,
+                        15199707771225336894,
+                        6912007903404713737,
+                        1195736020550467109,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16536374087395040861,
+                        11905207237145903612,
+                        6114638606661711198,
+                        227197704829902271,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3076102373056113184,
+                        9532492163005512122,
+                        6930666801042367372,
+                        359947620632631939,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9440427530838662463,
+                        13085976282871216533,
+                        11609820600896955445,
+                        51192596107359503,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17890235474475849352,
+                        12861769158994967907,
+                        17199388852287681020,
+                        1318104728008794611,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9609805589266211410,
+                        1670962659820459283,
+                        9562963939311936174,
+                        688275382419503341,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11167389070307520429,
+                        13977359843749392350,
+                        18441644755473839422,
+                        290366407278115355,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12443632002797775505,
+                        4654520606670285598,
+                        7306556664291017650,
+                        1327633699432541153,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8092706722129444827,
+                        3658752323492067072,
+                        4793508300996597563,
+                        747223853807345138,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2950573641865927995,
+                        8127787636945490240,
+                        13677031676353641065,
+                        158618420355845681,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11307462183326711698,
+                        4564920402521666764,
+                        14622180354087693631,
+                        1336492508958389172,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4173008676431506498,
+                        17674916393358760276,
+                        12680688896701595449,
+                        968385841042257188,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15472538886056214069,
+                        16032473331676368569,
+                        16841883688877890652,
+                        857731186179030920,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3288920438915728986,
+                        5565335638237954732,
+                        16168717028973711379,
+                        557711450924669146,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2265592654319698024,
+                        3205443269480017209,
+                        18001932803255029483,
+                        256652906325487142,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        281001495283692254,
+                        13790290956215242906,
+                        864387281468051063,
+                        1176693997057782923,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5123560649935846987,
+                        5199616350359672235,
+                        1101436586367673740,
+                        39975262197625469,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6484555314181403346,
+                        13702935644386089262,
+                        1070288899201945043,
+                        1275458004049976710,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        98165037936801382,
+                        7473654891184825762,
+                        349134079485996919,
+                        938092578927267223,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14188791834503488423,
+                        18250342088521722141,
+                        2040654749291153960,
+                        926512896204558398,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15224380743676270964,
+                        10786962884449555555,
+                        11361613959686699022,
+                        1210280746834414029,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11759988830619476554,
+                        8373507443143532317,
+                        3120367880452859596,
+                        1169296147393250270,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2846578710538047768,
+                        1316160842247957353,
+                        13088400622784413268,
+                        408653118294756083,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17205667740934035850,
+                        3622944266683523375,
+                        9638129797382886543,
+                        994379064072552476,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10882825922057764254,
+                        14418568533897154353,
+                        491214512167095700,
+                        433897962559667772,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        35128611736586842,
+                        16389786149729784671,
+                        10083558142637806972,
+                        137127276176467702,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6798031404470581862,
+                        17848651283256458296,
+                        16222358536010933656,
+                        803683331342998490,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6972405454939855920,
+                        4552281101007467397,
+                        7264795943490057443,
+                        864688899960148444,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13927481061475209845,
+                        15489762958527206567,
+                        6189983331269957882,
+                        547789234607264680,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3562534859757502067,
+                        1843985785467419577,
+                        4386226263096503262,
+                        1115671597351053159,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        12278022351526165705,
+                        17770191387101388710,
+                        10802565891039662934,
+                        1075900781773362211,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9958371080654925973,
+                        11831887817395699647,
+                        1304723427562418477,
+                        354927743406208632,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7902693975921282234,
+                        10693029805943046522,
+                        8006364623005626891,
+                        336833334808426575,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16076162621220796497,
+                        11613654344612125103,
+                        18364396485836487261,
+                        865751367130059847,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        177169936881514673,
+                        15617655321830945408,
+                        2675644582344792450,
+                        813774581927626127,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10024256268223083204,
+                        4626025808735980516,
+                        15602381466554927909,
+                        372989881235976309,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15323355670275250642,
+                        18066772602049731907,
+                        11033816093504217490,
+                        425723417069229499,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13530227036238294143,
+                        4726864651116558793,
+                        11836084010804535503,
+                        345580619383028526,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9033276785296979553,
+                        8015158493901738529,
+                        7685612975844433499,
+                        646369922266692941,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6106858674192767659,
+                        6489383503992760141,
+                        15104627566688873842,
+                        967007977448094027,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15940025338744316838,
+                        9550216566045214897,
+                        11499601007486969156,
+                        519967031225120334,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4457318900167954816,
+                        12351443184813817360,
+                        14113262131954548168,
+                        753463690543950206,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1881864483835241252,
+                        13219574080686529684,
+                        10918646441166728772,
+                        345526905812511168,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16713317497695859602,
+                        11594535032725047066,
+                        8614425525972045100,
+                        729458370408931167,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2382365843901650484,
+                        13640386342346411833,
+                        2679421435014953531,
+                        1060573086709931927,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        17434826777667612735,
+                        2365065059007002287,
+                        12113557410986701552,
+                        486906253608904981,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15422260981332801416,
+                        11687068808662228912,
+                        15340447593430286694,
+                        1095477751721025581,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3067136625363818837,
+                        15414947239083578872,
+                        4357451922062335136,
+                        670849424861114413,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6857276399606485730,
+                        9283462962781902828,
+                        9036672709333692336,
+                        767246981611698041,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4790627237749516576,
+                        16870705317496776272,
+                        8201498104615025061,
+                        1184169050930142288,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7547529495962597834,
+                        16334181419310753475,
+                        4959673272289445188,
+                        622064414724316631,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4699554924975257477,
+                        5239406492763952097,
+                        4514138379314892193,
+                        285913371707115885,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13798881461428781360,
+                        13731509523477882383,
+                        16976897769861195832,
+                        849427285657530276,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        952063905276541282,
+                        8269636048634891956,
+                        17964057567515234603,
+                        1070447721298630591,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15015857425828718687,
+                        5775099707931433635,
+                        8502608212811077834,
+                        958497153166197074,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2717189144441328164,
+                        9171329488469202193,
+                        259316541210055751,
+                        558356340017870591,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6065798026006695955,
+                        7792828742456711757,
+                        11440074088348310053,
+                        1075859908346807801,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        3134440530553818271,
+                        8255184637299442104,
+                        3914768334809935182,
+                        101044237360384907,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6731623173483973963,
+                        17929509414463545315,
+                        6203380609483126415,
+                        1090117544810917978,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11258118484197197697,
+                        12531230517307317268,
+                        11909932475428620217,
+                        714098560023676855,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        16443798894097432740,
+                        17067742110181636056,
+                        968427074407493327,
+                        779479350294359833,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12042739625881714609,
+                        3652554932902842865,
+                        3541534518594911657,
+                        940663901406188983,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16465321337635465873,
+                        16583505888349211971,
+                        3081564802538447298,
+                        39284010428842612,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4614030950475594192,
+                        5269526834281982621,
+                        13946689859734295220,
+                        572834450668633829,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7045565763189285052,
+                        8188934209194161805,
+                        9264297222580518924,
+                        937792513184682512,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1888603576987175290,
+                        13239830129648327139,
+                        15966528111613565942,
+                        428512520553884462,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14544821369811338629,
+                        9277520510311726109,
+                        1944405612066813917,
+                        2042387845638086,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8148858061024264478,
+                        3186229972503618180,
+                        71750729252111413,
+                        118673777446127206,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12524058261693615091,
+                        1932429661932427840,
+                        1713896310300894608,
+                        1049093835593922568,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        2502706814821035925,
+                        18194236067752240105,
+                        14011783808302568395,
+                        848185250263106842,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12840484428748224251,
+                        17699119508710197881,
+                        2831119501902020879,
+                        276048849389384361,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2999251893179110005,
+                        16521814728275655275,
+                        9428411387849592121,
+                        940196927657971330,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13533732460078790337,
+                        387677542177831669,
+                        3109475872432208250,
+                        433410359228626048,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        197547740523383097,
+                        12463508224686310686,
+                        6956928609019547139,
+                        691131875413670961,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14833135481746337585,
+                        4590604263563384575,
+                        2631796799846975241,
+                        356919890071743747,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        11396821317904760494,
+                        3191024265870628480,
+                        6297169331814656973,
+                        1330852384014845395,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        17425025521921972270,
+                        1677371038032693782,
+                        8085423593266762953,
+                        1031519776432511666,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3301044714222505196,
+                        10284367474567452100,
+                        2436807147485185497,
+                        1282664924374824086,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6671945680829599494,
+                        16431716202886168097,
+                        14676946774024884124,
+                        1118188352225305401,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11283184553346067882,
+                        13147629602550985559,
+                        9306472546366375569,
+                        708925093569345977,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        7366012340973532759,
+                        17598590574826475813,
+                        18242341335953999325,
+                        850567668381184956,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        8429950311742381549,
+                        8860514557548562539,
+                        17157970278619620431,
+                        767591871664523743,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1888950965328985669,
+                        12670964109382138288,
+                        17524780283649105145,
+                        1245637638414959460,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13752329753524470530,
+                        12639210299970653052,
+                        13881999051284614757,
+                        220490053230696312,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6222323541059343901,
+                        9704109300052676382,
+                        835626156977381380,
+                        29349964471996200,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        3688327562877745006,
+                        5123346408875513657,
+                        4939216998363701398,
+                        304107178075723952,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5953532054017440302,
+                        18255196798464259627,
+                        5380876274516998304,
+                        1269782724401646792,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        276904087294006329,
+                        6844418875307465688,
+                        9968058427245330692,
+                        1341694139918135212,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9449951338909708803,
+                        3390872338574504617,
+                        12121013142137586495,
+                        245914957142285403,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        10865415094117673992,
+                        4556156956341555401,
+                        6420378458845214729,
+                        471023451759379443,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6481195991705633241,
+                        6428102562531214382,
+                        6723044289920117994,
+                        426747364467375382,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13172916043941166303,
+                        6320322486661563660,
+                        17188422231386612809,
+                        1115872458774521127,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8216819113878731584,
+                        15916974162129344889,
+                        1186969706974249272,
+                        433225779034895683,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10846661729795939451,
+                        878269941055266353,
+                        16594712509298675752,
+                        640454414412543038,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15375856510128910128,
+                        14295217098300352006,
+                        6527306672583628141,
+                        166311091445497201,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16316003137916413615,
+                        4264198745178742006,
+                        17659554073600769304,
+                        768445626908171016,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5181119182281901138,
+                        14975992557513298573,
+                        15003296890159781346,
+                        638306274107453016,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        6590140468054910117,
+                        9295522494191705236,
+                        11879024959256393082,
+                        57008691005531942,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        13543643409822467707,
+                        10550115314107366972,
+                        2103649920880306099,
+                        1068419961830727758,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13152486229011673261,
+                        6125422612985959515,
+                        5347486333719740827,
+                        670195614083308539,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1476508125958563869,
+                        1597989171103748464,
+                        1131793197335725002,
+                        286798588605141776,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        2963702182986757071,
+                        13924212212050806491,
+                        3988293014526415099,
+                        746098693594074694,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13644408669730568739,
+                        13592240252411350391,
+                        4446894207758663525,
+                        716137638190996475,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11547057497391602222,
+                        9994602871744571254,
+                        16734784772244830970,
+                        429483825369679117,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        606118507872861832,
+                        5389765534705317397,
+                        6600024451260133071,
+                        72217868880249074,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        9779170447946803608,
+                        12705811832510739489,
+                        911608193062473198,
+                        349100008632886729,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8606165576589755257,
+                        8454917919204660396,
+                        8701226121311062501,
+                        749145604363211462,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12779786714072407756,
+                        5872162744790673925,
+                        10635629419951499639,
+                        51322060135891106,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10040030253144064139,
+                        5654418216386830440,
+                        2528274219535671088,
+                        276482446112442430,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14000659238631109371,
+                        2075220430183040695,
+                        5301720149663119615,
+                        958247489515557379,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16171758426203361731,
+                        12896589130054300736,
+                        12893521691183845807,
+                        1008711969398206587,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        4067587786350638261,
+                        6915565728083163730,
+                        12999273322463316129,
+                        474696316460436694,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11451434072029574210,
+                        13424232926760854206,
+                        15229466561603676335,
+                        1073537223528044718,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16166334128630959753,
+                        18345033569308076345,
+                        7780957120643379072,
+                        416092432953009569,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        1609062686490141503,
+                        16772451711215305751,
+                        10373812400171511122,
+                        684506201038205631,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12670603061276857118,
+                        10739344297442210822,
+                        577210140073721397,
+                        714810435996541923,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1030789151231651852,
+                        6276266658231312612,
+                        10387293049858073352,
+                        530506103611925681,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        13677764739282977437,
+                        18446098191788425042,
+                        12097518859540165516,
+                        776509510352930991,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4321434974410245218,
+                        5464919931706107873,
+                        5275707242287847685,
+                        825401864154460486,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4831963703991846995,
+                        4287573774015716264,
+                        6370329942810860620,
+                        614643526850553511,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14212831383495951965,
+                        7196420999998735392,
+                        10492567783424206457,
+                        981155350299156571,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8973602122571746232,
+                        11912073191015203475,
+                        5340377354743614977,
+                        1005593273503162896,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9335685206694918381,
+                        15382487007485339900,
+                        7078158414808549654,
+                        999997782249936045,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15289226575476575680,
+                        8248158490116784693,
+                        11758775018930311949,
+                        919522356985235055,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9714900137117084682,
+                        16614243471633599480,
+                        8171908118238752425,
+                        1137960326044549832,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        12330610354136608315,
+                        1438535907064543232,
+                        10123460495564694210,
+                        1158723854041488361,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        15626580778459008978,
+                        13221524369548170243,
+                        634505374112649553,
+                        1329267545538681376,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8823144141484580166,
+                        12365302354970417162,
+                        14770258266512911233,
+                        787210533037091504,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4544823070525253683,
+                        17035252757364324079,
+                        12592094328873391729,
+                        7702135490931267,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        14074211369619600043,
+                        16555935682290920070,
+                        15813125417138093955,
+                        188315741408636404,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        5679743153092134400,
+                        2922190385880885957,
+                        3113463993443231804,
+                        947788739280009202,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        16774707171611185037,
+                        14752763997456234894,
+                        5461985730545885047,
+                        395390743937158505,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5198710621931755538,
+                        17244558763325807787,
+                        10833213891367683257,
+                        610532842826952700,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        14472597420623611617,
+                        3185206089742849739,
+                        10931060478790112597,
+                        1012695745624967870,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        4191957783665913968,
+                        4622745810371195497,
+                        2083192369379864506,
+                        47603334666780890,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        5379566534622169392,
+                        8753820237711703345,
+                        17288397320283977655,
+                        1019568752752132852,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        1362585115201840997,
+                        11833173495923255318,
+                        5643849093728870928,
+                        309540556876238187,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        15769929551525932775,
+                        7386673812492103445,
+                        13263967103708749826,
+                        800821211303628646,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        10290730075253796566,
+                        2924972065508343475,
+                        8667894779957740560,
+                        286276451556505528,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9626896188792812047,
+                        18353916173886216199,
+                        1744054231554318073,
+                        894287613510238065,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        11724980710169988027,
+                        17771906222965644283,
+                        17045491081239633611,
+                        1051378766823000879,
+                    ]),
+                ]),
+                Matrix::<3, 1, 3>::new_from_known([
+                    Fq::from_montgomery_limbs([
+                        6110257462306915908,
+                        12429344425535677357,
+                        1312075139071001066,
+                        622311902861309728,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        9560685211850900447,
+                        1621139148969348418,
+                        11540331098539202286,
+                        980817596832903946,
+                    ]),
+                    Fq::from_montgomery_limbs([
+                        8361221178669196241,
+                        4582238096437542920,
+                        14797652390458123394,
+                        1172320622682515040,
+                    ]),
+                ]),
+            ],
+        },
+        optimized_arc: OptimizedArcMatrix::<71, 4, 284>::new_from_known([
+            Fq::from_montgomery_limbs([
+                14267038375356003558,
+                9584342189655787088,
+                6623485159448000560,
+                507128039270894479,
+            ]),
+            Fq::from_montgomery_limbs([
+                10114017139941974311,
+                7456381886840487364,
+                10658033618979124872,
+                779428262014699938,
+            ]),
+            Fq::from_montgomery_limbs([
+                16852230370573171781,
+                5823076055451704322,
+                13548517590454573713,
+                1293122530327861982,
+            ]),
+            Fq::from_montgomery_limbs([
+                17051761748796177719,
+                688169393294624470,
+                2689672292069595949,
+                247079689990131737,
+            ]),
+            Fq::from_montgomery_limbs([
+                6486778403343158939,
+                10387421591957272627,
+                17062317157364807762,
+                1058478900511316106,
+            ]),
+            Fq::from_montgomery_limbs([
+                11900417468126719526,
+                11615271761335555693,
+                13271275040776121373,
+                178078778729501000,
+            ]),
+            Fq::from_montgomery_limbs([
+                16266922741013358415,
+                2720252760467203712,
+                7896613198560894938,
+                1163513313182640392,
+            ]),
+            Fq::from_montgomery_limbs([
+                9339825194848375208,
+                7416508062222770841,
+                8123818705706335439,
+                1274313964938011816,
+            ]),
+            Fq::from_montgomery_limbs([
+                8721024143894250180,
+                8297778531294197285,
+                18277780563516021138,
+                597759318720692906,
+            ]),
+            Fq::from_montgomery_limbs([
+                12177366560200111442,
+                1760134971002250720,
+                4079273786840504803,
+                671580390635906275,
+            ]),
+            Fq::from_montgomery_limbs([
+                17298741316532923926,
+                10002512563918992713,
+                12130104802675559255,
+                1088738516468388148,
+            ]),
+            Fq::from_montgomery_limbs([
+                11273687908481021708,
+                17365775658223141097,
+                8808556021785733900,
+                334476868397257268,
+            ]),
+            Fq::from_montgomery_limbs([
+                16706485865058072524,
+                2312361704139444587,
+                1435913549269997212,
+                1177822653103222809,
+            ]),
+            Fq::from_montgomery_limbs([
+                10042209357849409989,
+                2643752301782337038,
+                14455286532548787960,
+                172542186226897927,
+            ]),
+            Fq::from_montgomery_limbs([
+                6106222169722440388,
+                6120412462137358752,
+                18079598565361280168,
+                403377900817803064,
+            ]),
+            Fq::from_montgomery_limbs([
+                15192025970651190674,
+                18286778500192008651,
+                4675109548723041399,
+                140056451144161510,
+            ]),
+            Fq::from_montgomery_limbs([
+                8185995624972002493,
+                13156856333067205509,
+                3107022464107563918,
+                526588033189239805,
+            ]),
+            Fq::from_montgomery_limbs([
+                5777534449150427398,
+                5390120047471046744,
+                14313989872103135046,
+                276161256011648591,
+            ]),
+            Fq::from_montgomery_limbs([
+                3386326056514974989,
+                5247681437183564928,
+                17141236265491238403,
+                352198768846135495,
+            ]),
+            Fq::from_montgomery_limbs([
+                5886383507175635001,
+                17941001092862314431,
+                12548730729706956078,
+                967317750047453909,
+            ]),
+            Fq::from_montgomery_limbs([
+                9347397991912182268,
+                15041881558347652740,
+                5184518035613608721,
+                398714686648568552,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                18062892177705346374,
+                11931562700240038580,
+                1501737242104501082,
+                309402153481446431,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11523229399353916995,
+                14762362902345872451,
+                92883235448889241,
+                1150779055660294590,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10480521892833947745,
+                16449147126171849783,
+                4537505809109766878,
+                1086053123704161331,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11617588246201358901,
+                924026829663778672,
+                9976067096283478769,
+                52039474329553530,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2504299777948098421,
+                13445543892451850972,
+                14791760107207903276,
+                1236899696902123918,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3266862095016240142,
+                3166284428850307691,
+                6086665712666267529,
+                35984622971214318,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6685127047394172292,
+                4411295951734127909,
+                11200617234782071405,
+                969355311215112848,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9518251450416079346,
+                8144107541605687035,
+                4528862880933984868,
+                31265962336009551,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8431822268064525220,
+                6828302280000779564,
+                10980937839535830579,
+                1229361351076300260,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15847929557503390831,
+                10337624856425229245,
+                15703849030216535198,
+                150885599663262102,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                22182973115414612,
+                13398869364906289001,
+                5902417246601167552,
+                1336156728439289227,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4914443003093355349,
+                10207937886919793752,
+                14250206515830197923,
+                514210524108746101,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11555981674042483508,
+                11973342909323313452,
+                3666833115943155381,
+                164647886121807851,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3567008078132661718,
+                1595011882564441585,
+                370988852884768350,
+                1263562470866503914,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                12665226036968324581,
+                5926283965416542318,
+                14684765075967708658,
+                136043087622666762,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                178445686163702156,
+                5511494694508441773,
+                17646668169741595144,
+                614123300824379799,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6373895449641052477,
+                10108820603680985923,
+                14102584283198973558,
+                352438934316111470,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7977518665549165737,
+                17233245335783317204,
+                14711208388474371166,
+                235500701647416314,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13975765039292163848,
+                12319338049870389306,
+                12011411838065803167,
+                321234037034129714,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15011280170757871684,
+                16394813223718792962,
+                12590448735297907054,
+                1160533014278360796,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9648613548174441289,
+                17479670291002768589,
+                15159570466373420261,
+                122813645465976726,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8647668814652746460,
+                10524354178033973092,
+                1318862312137997175,
+                1026132682170148065,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                2378976375799722210,
+                10395967970886243160,
+                10843611789425025206,
+                868732312791629386,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11490570966982431910,
+                1929193118366169053,
+                16122250866786409378,
+                1048613476149370563,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11350033462317563022,
+                17945216044688192462,
+                6338394133245440384,
+                788416257598532414,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6648621862940976784,
+                18235480073739144958,
+                15257205814381033170,
+                721062788648396859,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3399547825786290243,
+                12256288011126625150,
+                16336752831311226846,
+                492096341655759928,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11541157723388296056,
+                691089309338755754,
+                10739126339774294186,
+                868881251182215888,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8249537956248136894,
+                4955928705649533192,
+                11688791557305028916,
+                364822918676568126,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                9305498416537244064,
+                8480319070175077653,
+                14312671222616040280,
+                699574582447896976,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13759481297087069839,
+                8545163179557357949,
+                5797243652513307791,
+                283927794789220304,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6136769795243962339,
+                16836156929462542677,
+                14095771819110198519,
+                1244514697746422974,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13191735203035564472,
+                5609893177784541771,
+                6559607014420940548,
+                721521269094543146,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                6754739376661046214,
+                9050396888655386549,
+                15423011459211191821,
+                394093709460890111,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                989587355078263638,
+                12807757489980860480,
+                11292398099466004297,
+                556390184263077635,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14912386946531881487,
+                2093089731554150377,
+                10059531840416790915,
+                16300490129551675,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16884447020464477929,
+                13331112401512458205,
+                2860232377318107396,
+                472190882886775867,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13987259508749626284,
+                15379175113202245889,
+                11909383381821997345,
+                293861868795404176,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11448581830478989181,
+                15293020768286620788,
+                6258813005004801353,
+                1296004972519673836,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3192747321701638796,
+                15489502757361179622,
+                16599155466853771730,
+                440742043863540815,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4229170448000279194,
+                7474931930006904607,
+                1747411313395796167,
+                386731344417055912,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8754209371643534777,
+                9225622769540453780,
+                10501695643716564122,
+                496443172837188808,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11729171710785278652,
+                16153032206586836682,
+                4086085613727100855,
+                147182340089973475,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                4733651033158927610,
+                4708453663219291920,
+                14750244439315525795,
+                964597703068707592,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7853824865472087079,
+                7388211157463803581,
+                808449187043034294,
+                457220636357872555,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                11825701379130794897,
+                11009126196560869202,
+                6803222356841686524,
+                1057263573853507181,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                5446125739459413347,
+                2989569341075640736,
+                4782091905894630401,
+                499074122858445290,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8002927443338532960,
+                11894422983956478516,
+                15293799980039669187,
+                1315136584301351672,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                10618135767673301771,
+                1510197057056639165,
+                3554798582199418424,
+                1339979268129805116,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                7398301217763995461,
+                13297950741597293457,
+                5914828259432340791,
+                436106284797659651,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                14879707038545714755,
+                7918848564339308103,
+                10405617503793645125,
+                989056092748803660,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8472394998783363559,
+                15862289834086788790,
+                6927018236795632540,
+                709757760170774335,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13916285056367638118,
+                6921779704610392546,
+                3945206144592730318,
+                1066534444640039713,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                33164262741071153,
+                13889697674498831340,
+                4460173726963464510,
+                1101927938362767852,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                15503859049468008301,
+                12494447192970372010,
+                15050982765076528675,
+                1098175556278762264,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8039770798119637837,
+                4875583312091010238,
+                16679137857079772605,
+                264422147120732583,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3024856309089009157,
+                1634476260392058844,
+                11033500347544690535,
+                851856856050772482,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16181385706321127819,
+                13776589708126007827,
+                562332703171954492,
+                513577665855357919,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                16003374836775622175,
+                2518224882293891961,
+                9248446729617288824,
+                509337998617065764,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                13725542645122683128,
+                4527389089177201823,
+                360196177828577664,
+                816653300114716606,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                8676389909170442115,
+                10064321070024352229,
+                787065860183831976,
+                910341148493083262,
+            ]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([0, 0, 0, 0]),
+            Fq::from_montgomery_limbs([
+                3392523935052003379,
+                18209473452431399340,
+                7798646387576687816,
+                730739484465686060,
+            ]),
+            Fq::from_montgomery_limbs([
+                3249691677537960618,
+                12146978027772992900,
+                13043611402458312323,
+                671826637040595194,
+            ]),
+            Fq::from_montgomery_limbs([
+                5218686724010574875,
+                909775019592090073,
+                14416334837264806578,
+                460629113837156516,
+            ]),
+            Fq::from_montgomery_limbs([
+                8551011955417245860,
+                17857719517725331265,
+                2008686125138792445,
+                446369772420929219,
+            ]),
+            Fq::from_montgomery_limbs([
+                4689542379245607125,
+                5048527618736839693,
+                1973356288160696452,
+                171662440470886469,
+            ]),
+            Fq::from_montgomery_limbs([
+                13370383432923813323,
+                7133958835504366457,
+                8064439491969675650,
+                117665874995446234,
+            ]),
+            Fq::from_montgomery_limbs([
+                16841266044362416580,
+                18074195272623883476,
+                6936984937236882502,
+                470166341729039593,
+            ]),
+            Fq::from_montgomery_limbs([
+                14215091730887408881,
+                9662156666872254169,
+                3529510122911032685,
+                128425079071610282,
+            ]),
+            Fq::from_montgomery_limbs([
+                7008149922542713065,
+                15111994775545505092,
+                2357941911484452421,
+                1323912091294466399,
+            ]),
+            Fq::from_montgomery_limbs([
+                16775245108847046474,
+                6746786860833723611,
+                16109872520748379542,
+                1173568211697483319,
+            ]),
+            Fq::from_montgomery_limbs([
+                11488752521769051501,
+                2127498205693192344,
+                17485688842943647039,
+                664615660402459249,
+            ]),
+            Fq::from_montgomery_limbs([
+                13267874742340509520,
+                8945349578822323446,
+                8601522868717924287,
+                1097258505988889852,
+            ]),
+            Fq::from_montgomery_limbs([
+                7054504326847705806,
+                6604729871100083274,
+                14003795248872778511,
+                874993399493059497,
+            ]),
+            Fq::from_montgomery_limbs([
+                4345505784603750142,
+                16209450802814971084,
+                11178930931839838211,
+                771178144733794234,
+            ]),
+            Fq::from_montgomery_limbs([
+                18092937866579702489,
+                478613137004343299,
+                9253937744736765771,
+                371207962547404338,
+            ]),
+            Fq::from_montgomery_limbs([
+                2921394185006349143,
+                7370968066747946128,
+                5290953232447576294,
+                642334592131455796,
+            ]),
+        ]),
+    }
+}
@@ -0,0 +1,347 @@
+use decaf377::Fq;
+use poseidon_parameters::v2::{Alpha, ArcMatrix, PoseidonParameters, RoundNumbers, SquareMatrix};
+
+/// Parameters for the rate-1 instance of Poseidon2.
+pub const fn rate_1() -> PoseidonParameters<2, 4, 47, 1, 47> {
+    PoseidonParameters {
+        M: 128,
+        alpha: Alpha::Exponent(17),
+        rounds: RoundNumbers { r_P: 31, r_F: 8 },
+        m_e: SquareMatrix::<2, 4>::new_from_known([
+            Fq::from_montgomery_limbs([
+                17304940830682775525,
+                10017539527700119523,
+                14770643272311271387,
+                570918138838421475,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                7147916296078753751,
+                11795755565450264533,
+                9448453213491875784,
+                183737022913545514,
+            ]),
+        ]),
+        m_i: SquareMatrix::<2, 4>::new_from_known([
+            Fq::from_montgomery_limbs([
+                17304940830682775525,
+                10017539527700119523,
+                14770643272311271387,
+                570918138838421475,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                7147916296078753751,
+                11795755565450264533,
+                9448453213491875784,
+                183737022913545514,
+            ]),
+        ]),
+        arc: ArcMatrix::<47, 1, 47>::new_from_known([
+            Fq::from_montgomery_limbs([
+                6258896775460530561,
+                1267570262938364784,
+                10583705394133388288,
+                4331270346116315,
+            ]),
+            Fq::from_montgomery_limbs([
+                15454694353288852986,
+                2205227775135851582,
+                9104115866970654747,
+                48952601862872692,
+            ]),
+            Fq::from_montgomery_limbs([
+                17632203450119298224,
+                13599315412875479343,
+                7140007131719627884,
+                569020719923681609,
+            ]),
+            Fq::from_montgomery_limbs([
+                6608939517720255573,
+                15066841911042588370,
+                8648475674864948310,
+                578035154261448463,
+            ]),
+            Fq::from_montgomery_limbs([
+                1055805092356900670,
+                5486646384412869322,
+                9705528431443784365,
+                307684188417815151,
+            ]),
+            Fq::from_montgomery_limbs([
+                11248650571348093756,
+                1514639161444600232,
+                968234586550369174,
+                1313541476846290422,
+            ]),
+            Fq::from_montgomery_limbs([
+                5189078861895589451,
+                9996051726892023924,
+                14955307722807799098,
+                1320126769029056157,
+            ]),
+            Fq::from_montgomery_limbs([
+                14397975440942704641,
+                14269464930912859892,
+                9230505175050478925,
+                1093012507774165575,
+            ]),
+            Fq::from_montgomery_limbs([
+                6847445113090943697,
+                17673603016395516883,
+                15250705377106188486,
+                539035154593930175,
+            ]),
+            Fq::from_montgomery_limbs([
+                6947720323756162913,
+                3313209312340777357,
+                18395293995391548637,
+                209157278787801614,
+            ]),
+            Fq::from_montgomery_limbs([
+                3458319354278701363,
+                11429938079178703613,
+                7005241729732086579,
+                150425083810087548,
+            ]),
+            Fq::from_montgomery_limbs([
+                2543869222269883848,
+                6129645233495109995,
+                1076912762679804840,
+                1127382646597654684,
+            ]),
+            Fq::from_montgomery_limbs([
+                3802085031173312140,
+                1153736051705836195,
+                34368926314299214,
+                870379362515076367,
+            ]),
+            Fq::from_montgomery_limbs([
+                5488449230911278270,
+                2470593653263895214,
+                2746242821352744093,
+                1170504430408456781,
+            ]),
+            Fq::from_montgomery_limbs([
+                3039943962034370722,
+                16476527388322339887,
+                5748836754427063321,
+                874163389084517420,
+            ]),
+            Fq::from_montgomery_limbs([
+                11160556015001057055,
+                18242508582059343688,
+                1914918650959602739,
+                1060251542697903110,
+            ]),
+            Fq::from_montgomery_limbs([
+                1250158851421170863,
+                3598229175113435790,
+                11440021114985999339,
+                539355236403595924,
+            ]),
+            Fq::from_montgomery_limbs([
+                17381864786167270102,
+                5920159615239164628,
+                7621039653090189177,
+                80820461513544845,
+            ]),
+            Fq::from_montgomery_limbs([
+                9427313983461117140,
+                12722081891364237901,
+                2526344363227396036,
+                169367802375106360,
+            ]),
+            Fq::from_montgomery_limbs([
+                18279043286872423918,
+                11282585104763746254,
+                6663752460065940976,
+                1313306297247703395,
+            ]),
+            Fq::from_montgomery_limbs([
+                11348011840530639501,
+                13585724393536580138,
+                16173408530293170097,
+                237306649524404549,
+            ]),
+            Fq::from_montgomery_limbs([
+                9105438583131143101,
+                484614180087189633,
+                11007155874658626631,
+                1105934356159118197,
+            ]),
+            Fq::from_montgomery_limbs([
+                17692463671930692407,
+                8848595903938472796,
+                11444065729641962767,
+                856337008348787181,
+            ]),
+            Fq::from_montgomery_limbs([
+                11475710285348090819,
+                12082432292625800602,
+                4973698077631026180,
+                250886293876957218,
+            ]),
+            Fq::from_montgomery_limbs([
+                3831119291993789483,
+                11955292354948817093,
+                10578474534116863906,
+                292270082742245378,
+            ]),
+            Fq::from_montgomery_limbs([
+                11074518745033881448,
+                14103711443902779277,
+                9703092681767716480,
+                1027943651677607178,
+            ]),
+            Fq::from_montgomery_limbs([
+                14255504761835093988,
+                12836619582277229653,
+                16470467141944117103,
+                216605519265731395,
+            ]),
+            Fq::from_montgomery_limbs([
+                17412479146814766387,
+                7550994961529157056,
+                16948400072627593287,
+                686398674032066558,
+            ]),
+            Fq::from_montgomery_limbs([
+                14019001188641598557,
+                17711295492607709098,
+                17317648113970387478,
+                1005852188387049762,
+            ]),
+            Fq::from_montgomery_limbs([
+                2757938402041260135,
+                10858479133892891281,
+                6226991255650955925,
+                113236437448370768,
+            ]),
+            Fq::from_montgomery_limbs([
+                2864831845918016166,
+                11222314241298259255,
+                6809710896423414618,
+                391420167412878448,
+            ]),
+            Fq::from_montgomery_limbs([
+                15295022328131697798,
+                14180180046854426704,
+                5969598612677890929,
+                422063763314834782,
+            ]),
+            Fq::from_montgomery_limbs([
+                9004302479558436050,
+                9395856654729450347,
+                1876513748778519470,
+                1078019802682326971,
+            ]),
+            Fq::from_montgomery_limbs([
+                17483474807509017611,
+                1860495490123329066,
+                10427704098322365889,
+                168618466562963955,
+            ]),
+            Fq::from_montgomery_limbs([
+                3325616628101926400,
+                6318651352098105269,
+                10561353542035867513,
+                347869863814864598,
+            ]),
+            Fq::from_montgomery_limbs([
+                10608180854761738188,
+                7227409243651228657,
+                10245865730256963695,
+                33366710867335616,
+            ]),
+            Fq::from_montgomery_limbs([
+                6377899455837337983,
+                6460750903815498030,
+                14658523922399852198,
+                850391300192126368,
+            ]),
+            Fq::from_montgomery_limbs([
+                9949314602248501685,
+                9592125889973713334,
+                12540141676151911968,
+                902630930644097978,
+            ]),
+            Fq::from_montgomery_limbs([
+                9874569741817026646,
+                8597014183735249181,
+                14218226934847278615,
+                990148636388408942,
+            ]),
+            Fq::from_montgomery_limbs([
+                9660554731559651732,
+                4712943300030820950,
+                8606150340609690108,
+                160577077343575019,
+            ]),
+            Fq::from_montgomery_limbs([
+                1527563399863479849,
+                15731827835699836031,
+                7229971325383988685,
+                826813386663462338,
+            ]),
+            Fq::from_montgomery_limbs([
+                18280004775370667641,
+                10717313307037013407,
+                3515684275541234253,
+                1288829721588576759,
+            ]),
+            Fq::from_montgomery_limbs([
+                9831584723932855792,
+                1077247411674292976,
+                4470807593665567057,
+                629496612576050491,
+            ]),
+            Fq::from_montgomery_limbs([
+                18018152400203412106,
+                16658517283861939563,
+                15927825308647499591,
+                1046841820836193453,
+            ]),
+            Fq::from_montgomery_limbs([
+                424287065221489244,
+                17051291629546655826,
+                4983999217110075951,
+                1278494067020803559,
+            ]),
+            Fq::from_montgomery_limbs([
+                13081137544640017921,
+                1196650010435295235,
+                15746798075854654528,
+                1203678627802318991,
+            ]),
+            Fq::from_montgomery_limbs([
+                939677723946433505,
+                11567589096003441629,
+                12722931402128711345,
+                720362007908860959,
+            ]),
+        ]),
+    }
+}
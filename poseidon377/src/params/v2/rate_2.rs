@@ -0,0 +1,455 @@
+use decaf377::Fq;
+use poseidon_parameters::v2::{Alpha, ArcMatrix, PoseidonParameters, RoundNumbers, SquareMatrix};
+
+/// Parameters for the rate-2 instance of Poseidon2.
+pub const fn rate_2() -> PoseidonParameters<3, 9, 55, 1, 55> {
+    PoseidonParameters {
+        M: 128,
+        alpha: Alpha::Exponent(17),
+        rounds: RoundNumbers { r_P: 31, r_F: 8 },
+        m_e: SquareMatrix::<3, 9>::new_from_known([
+            Fq::from_montgomery_limbs([
+                17304940830682775525,
+                10017539527700119523,
+                14770643272311271387,
+                570918138838421475,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                17304940830682775525,
+                10017539527700119523,
+                14770643272311271387,
+                570918138838421475,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                7147916296078753751,
+                11795755565450264533,
+                9448453213491875784,
+                183737022913545514,
+            ]),
+        ]),
+        m_i: SquareMatrix::<3, 9>::new_from_known([
+            Fq::from_montgomery_limbs([
+                17304940830682775525,
+                10017539527700119523,
+                14770643272311271387,
+                570918138838421475,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                17304940830682775525,
+                10017539527700119523,
+                14770643272311271387,
+                570918138838421475,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                9015221291577245683,
+                8239323489949974514,
+                1646089257421115374,
+                958099254763297437,
+            ]),
+            Fq::from_montgomery_limbs([
+                7147916296078753751,
+                11795755565450264533,
+                9448453213491875784,
+                183737022913545514,
+            ]),
+        ]),
+        arc: ArcMatrix::<55, 1, 55>::new_from_known([
+            Fq::from_montgomery_limbs([
+                13532052565085671856,
+                13175575834742130482,
+                14571043277169067394,
+                1128324334676141805,
+            ]),
+            Fq::from_montgomery_limbs([
+                10586567975029405298,
+                5987505274649066590,
+                9663655893165983133,
+                381788629769180835,
+            ]),
+            Fq::from_montgomery_limbs([
+                7404335995308317606,
+                10342209374602516054,
+                14679697400509748167,
+                1259991645283011647,
+            ]),
+            Fq::from_montgomery_limbs([
+                12711294721892819516,
+                8412935995118692797,
+                7069737649526067295,
+                549549236990677487,
+            ]),
+            Fq::from_montgomery_limbs([
+                128694122049037398,
+                15825223235256271389,
+                74647683628497806,
+                403759781237802093,
+            ]),
+            Fq::from_montgomery_limbs([
+                3501552524290051296,
+                10688664658423588201,
+                8345653809557910120,
+                766292783896459938,
+            ]),
+            Fq::from_montgomery_limbs([
+                13998055823902745235,
+                15535161481341849823,
+                9848874496394823215,
+                579121379437172744,
+            ]),
+            Fq::from_montgomery_limbs([
+                16363263235454987811,
+                9117252686821547535,
+                4794867261658414781,
+                418411818796950741,
+            ]),
+            Fq::from_montgomery_limbs([
+                3710344964675021293,
+                13957371261080223237,
+                15195218532629186765,
+                760708816844813378,
+            ]),
+            Fq::from_montgomery_limbs([
+                16135673103471103205,
+                13943139040297964408,
+                12037506718934223099,
+                1187380734246932890,
+            ]),
+            Fq::from_montgomery_limbs([
+                11326210002632211732,
+                2893415499386035614,
+                2949171194607212014,
+                568849018592141776,
+            ]),
+            Fq::from_montgomery_limbs([
+                13925494990768706056,
+                13969996543019858204,
+                13457764640058086886,
+                114396439087701688,
+            ]),
+            Fq::from_montgomery_limbs([
+                16351586607272228519,
+                6269966282891300082,
+                12490381894697744781,
+                834648274293416263,
+            ]),
+            Fq::from_montgomery_limbs([
+                1437979443977545600,
+                14767193465362841311,
+                11074881595123675812,
+                1204201379421269428,
+            ]),
+            Fq::from_montgomery_limbs([
+                5230240271406271908,
+                16847766581152362930,
+                1712847198165617791,
+                311937423231086981,
+            ]),
+            Fq::from_montgomery_limbs([
+                3645836740283016323,
+                15685198898945208452,
+                1980971647688859029,
+                176967089015698342,
+            ]),
+            Fq::from_montgomery_limbs([
+                7550143813701248311,
+                18291556615524985846,
+                8534400014086995148,
+                500430429779017952,
+            ]),
+            Fq::from_montgomery_limbs([
+                596957473479108084,
+                6324092840533510915,
+                4811101589352947098,
+                76518932650696039,
+            ]),
+            Fq::from_montgomery_limbs([
+                336812235439984799,
+                13522610888312181094,
+                10501405696980879642,
+                449669351326561391,
+            ]),
+            Fq::from_montgomery_limbs([
+                12592523411743912820,
+                3924222765120111350,
+                5699458771760954386,
+                892267873608702453,
+            ]),
+            Fq::from_montgomery_limbs([
+                12993017322389395630,
+                5952902593915102432,
+                5247111021983199097,
+                470467609253978097,
+            ]),
+            Fq::from_montgomery_limbs([
+                7244663031809848522,
+                3071554415549245091,
+                2773295222867337766,
+                1090905641165090834,
+            ]),
+            Fq::from_montgomery_limbs([
+                9981097535495148290,
+                12930981823992014491,
+                9629464214154256600,
+                308026108875661910,
+            ]),
+            Fq::from_montgomery_limbs([
+                12850815434424207307,
+                8847171669624931211,
+                16288364336002865240,
+                954936779594025677,
+            ]),
+            Fq::from_montgomery_limbs([
+                11236881576270168641,
+                15414742267201110956,
+                7825767860878753872,
+                721136380245447153,
+            ]),
+            Fq::from_montgomery_limbs([
+                2832704701681877466,
+                7235621408572302496,
+                11812570847329545593,
+                792455748001887640,
+            ]),
+            Fq::from_montgomery_limbs([
+                3432713492718273410,
+                6465506654245539380,
+                8827139003294513485,
+                992550877185604089,
+            ]),
+            Fq::from_montgomery_limbs([
+                9728786934890323565,
+                10584002864733096791,
+                2293345482796697755,
+                180937419315601034,
+            ]),
+            Fq::from_montgomery_limbs([
+                13822678097673121251,
+                4948809415032161320,
+                15691917123613077102,
+                683890210553461269,
+            ]),
+            Fq::from_montgomery_limbs([
+                9002390647260096239,
+                10448673368926307125,
+                17815937281103619895,
+                1097505426950771788,
+            ]),
+            Fq::from_montgomery_limbs([
+                13908365267431289868,
+                3370079770970658040,
+                15308027316557057295,
+                1308145822882463267,
+            ]),
+            Fq::from_montgomery_limbs([
+                7831189882886479452,
+                4689238810753296883,
+                13159502922430301346,
+                27611585919074368,
+            ]),
+            Fq::from_montgomery_limbs([
+                14912709676120752020,
+                10115204646384083757,
+                10138309295115926582,
+                223210301540489796,
+            ]),
+            Fq::from_montgomery_limbs([
+                12627294687244820632,
+                2059848305109767326,
+                1888484742856066068,
+                1272834063475208138,
+            ]),
+            Fq::from_montgomery_limbs([
+                6115210324513801758,
+                10929870666119062300,
+                11707806468269326656,
+                949025326858793021,
+            ]),
+            Fq::from_montgomery_limbs([
+                7061826933729287205,
+                1126603583829494325,
+                5469143638052028670,
+                1033665004945948695,
+            ]),
+            Fq::from_montgomery_limbs([
+                11008930476894918520,
+                11381807375074895164,
+                2844592577512486485,
+                1128817316316311928,
+            ]),
+            Fq::from_montgomery_limbs([
+                5300558244079945539,
+                8283037415518590201,
+                11038807277807240648,
+                1178232107582202545,
+            ]),
+            Fq::from_montgomery_limbs([
+                11862022433031293870,
+                8913994378642882666,
+                9450763413034135432,
+                77958396662445698,
+            ]),
+            Fq::from_montgomery_limbs([
+                17688402066322086519,
+                524036851070457942,
+                14909117109897886121,
+                557290224371677333,
+            ]),
+            Fq::from_montgomery_limbs([
+                1875815915909501089,
+                10678692213853973640,
+                15989623299920327105,
+                428879321377057641,
+            ]),
+            Fq::from_montgomery_limbs([
+                1082841901890716129,
+                12068516826131602351,
+                7102481379375591007,
+                906616535867466859,
+            ]),
+            Fq::from_montgomery_limbs([
+                17613918888580330287,
+                6204465427334198417,
+                9436344365449852604,
+                655489186886697,
+            ]),
+            Fq::from_montgomery_limbs([
+                17585284967658841164,
+                80944379680805043,
+                2698615756683481750,
+                351188921018806563,
+            ]),
+            Fq::from_montgomery_limbs([
+                15687615037958784071,
+                2186404284346347770,
+                5652967689160162635,
+                1056583988063876017,
+            ]),
+            Fq::from_montgomery_limbs([
+                13107987562409563672,
+                3871322351409280978,
+                8803804680465229381,
+                1030040159656924298,
+            ]),
+            Fq::from_montgomery_limbs([
+                16684869482081657339,
+                10281204171444660632,
+                1624958597982547757,
+                386745834466917047,
+            ]),
+            Fq::from_montgomery_limbs([
+                6970896123209894127,
+                7830160530923913939,
+                17422861911349199859,
+                623374110284783712,
+            ]),
+            Fq::from_montgomery_limbs([
+                4331844870617545742,
+                520046082233107649,
+                5594606953430693091,
+                249698853373994073,
+            ]),
+            Fq::from_montgomery_limbs([
+                6680837442175120485,
+                6814766854525276712,
+                6556825746309967179,
+                570042050188335199,
+            ]),
+            Fq::from_montgomery_limbs([
+                15436728868072851979,
+                10638387680273826288,
+                16379320772832534606,
+                822954407147775124,
+            ]),
+            Fq::from_montgomery_limbs([
+                11837214986206518455,
+                596921394620411645,
+                13159256680099246054,
+                1277661308547494330,
+            ]),
+            Fq::from_montgomery_limbs([
+                13679203667889812546,
+                11676386286470050129,
+                8357354920215222534,
+                456950010799870038,
+            ]),
+            Fq::from_montgomery_limbs([
+                2229876009761017392,
+                13089694557765213339,
+                16529665410348556997,
+                1200161010593736391,
+            ]),
+            Fq::from_montgomery_limbs([
+                16005321486742773520,
+                8650710416750881700,
+                12927174790925866955,
+                1318630719916273524,
+            ]),
+        ]),
+    }
+}
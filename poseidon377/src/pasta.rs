@@ -0,0 +1,15 @@
+//! Poseidon over the scalar fields of the Pasta curves.
+//!
+//! Pallas and Vesta form a cycle of curves, each one's scalar field being the other's
+//! base field, which proof systems such as Halo 2 and Nova use for recursion. The
+//! [`pallas`] and [`vesta`] modules provide the rate-1 to rate-4 instances of Poseidon
+//! over the scalar field of each curve.
+//!
+//! The parameters are generated by `poseidon-paramgen` with the same transcript
+//! procedure as those over [`Fq`](crate::Fq), for a security level of 128 bits, and
+//! the S-box is `x^5` for both fields. The fields are the arkworks types of
+//! `ark-pallas`; their canonical encodings are those of the `Fp` and `Fq` types of
+//! `pasta_curves`, so the constants can be converted to either representation.
+
+pub mod pallas;
+pub mod vesta;
@@ -0,0 +1,102 @@
+//! Poseidon over the scalar field of Pallas.
+//!
+//! This module provides the rate-1 to rate-4 instances of Poseidon over the scalar
+//! field [`Fr`] of Pallas, which is the base field of Vesta, with the same API as the
+//! instances over [`Fq`](crate::Fq), and R1CS gadgets for them with the `r1cs`
+//! feature.
+
+use crate::field::FieldParameters;
+
+#[cfg(feature = "r1cs")]
+pub mod r1cs;
+mod rate_1;
+mod rate_2;
+mod rate_3;
+mod rate_4;
+
+/// The scalar field of Pallas.
+pub use ark_pallas::Fr;
+
+/// Parameters for the rate-1 instance of Poseidon over [`Fr`].
+pub const RATE_1_PARAMS: FieldParameters<Fr, 2> = rate_1::RATE_1_PARAMS;
+
+/// Parameters for the rate-2 instance of Poseidon over [`Fr`].
+pub const RATE_2_PARAMS: FieldParameters<Fr, 3> = rate_2::RATE_2_PARAMS;
+
+/// Parameters for the rate-3 instance of Poseidon over [`Fr`].
+pub const RATE_3_PARAMS: FieldParameters<Fr, 4> = rate_3::RATE_3_PARAMS;
+
+/// Parameters for the rate-4 instance of Poseidon over [`Fr`].
+pub const RATE_4_PARAMS: FieldParameters<Fr, 5> = rate_4::RATE_4_PARAMS;
+
+/// Hash a single [`Fr`] element with the provided `domain_separator`.
+pub fn hash_1(domain_separator: &Fr, value: Fr) -> Fr {
+    RATE_1_PARAMS.n_to_1_fixed_hash([*domain_separator, value])
+}
+
+/// Hash two [`Fr`] elements with the provided `domain_separator`.
+pub fn hash_2(domain_separator: &Fr, value: (Fr, Fr)) -> Fr {
+    RATE_2_PARAMS.n_to_1_fixed_hash([*domain_separator, value.0, value.1])
+}
+
+/// Hash three [`Fr`] elements with the provided `domain_separator`.
+pub fn hash_3(domain_separator: &Fr, value: (Fr, Fr, Fr)) -> Fr {
+    RATE_3_PARAMS.n_to_1_fixed_hash([*domain_separator, value.0, value.1, value.2])
+}
+
+/// Hash four [`Fr`] elements with the provided `domain_separator`.
+pub fn hash_4(domain_separator: &Fr, value: (Fr, Fr, Fr, Fr)) -> Fr {
+    RATE_4_PARAMS.n_to_1_fixed_hash([*domain_separator, value.0, value.1, value.2, value.3])
+}
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use ark_ff::PrimeField;
+
+    use super::*;
+
+    fn fr(s: &str) -> Fr {
+        Fr::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn pallas_params_are_consistent() {
+        assert_eq!(RATE_1_PARAMS.arc.len(), RATE_1_PARAMS.rounds.total());
+        assert_eq!(RATE_2_PARAMS.arc.len(), RATE_2_PARAMS.rounds.total());
+        assert_eq!(RATE_3_PARAMS.arc.len(), RATE_3_PARAMS.rounds.total());
+        assert_eq!(RATE_4_PARAMS.arc.len(), RATE_4_PARAMS.rounds.total());
+
+        // The MDS matrix is the Cauchy matrix `1 / (i + t + j)`.
+        let mds = RATE_2_PARAMS.mds;
+        for (i, row) in mds.iter().enumerate() {
+            for (j, m) in row.iter().enumerate() {
+                assert_eq!(*m * Fr::from((i + 3 + j) as u64), Fr::from(1u64));
+            }
+        }
+    }
+
+    #[test]
+    fn pallas_hashes() {
+        let domain_sep = Fr::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let [a, b, c, d] = [1u64, 2, 3, 4].map(Fr::from);
+
+        assert_eq!(
+            hash_1(&domain_sep, a),
+            fr("2505986978673490481974887260926648622906377959435372766021350663046639561694")
+        );
+        assert_eq!(
+            hash_2(&domain_sep, (a, b)),
+            fr("7470399002347349676633151226643523381965752509862231343864037404561338483600")
+        );
+        assert_eq!(
+            hash_3(&domain_sep, (a, b, c)),
+            fr("7120063951805036852335761661181875683309090046971861713278353131579427618402")
+        );
+        assert_eq!(
+            hash_4(&domain_sep, (a, b, c, d)),
+            fr("18074068101885000986030329643474481993666203556233295995584453942620950468957")
+        );
+    }
+}
@@ -0,0 +1,464 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-1 instance of Poseidon over [`Fr`].
+pub const RATE_1_PARAMS: FieldParameters<Fr, 2> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!("9194680729814864118993736450219925798428105912757975172379913547880479024951"),
+            MontFp!(
+                "28022077313940501649823192024397807408299278777914428408177796362553805644588"
+            ),
+        ],
+        [
+            MontFp!("5814776285247782633916033969834722108830486586258940948466669896835481426485"),
+            MontFp!(
+                "16489204852975842600383912901944318760088022423362692642264028168342014607671"
+            ),
+        ],
+        [
+            MontFp!(
+                "22088021108133585377197313532205988030332307551469808840253687075384465430788"
+            ),
+            MontFp!("8942865926340369438449205824386236900818590925646185165678445123124962045320"),
+        ],
+        [
+            MontFp!("519786856833109553156797210326486421572198266315043594850815495449783931679"),
+            MontFp!(
+                "27631425738914903540534049320249709854476996518626061254718143711072549528761"
+            ),
+        ],
+        [
+            MontFp!("3096554896550936408555391763666294582487931699573809224211868028215135413190"),
+            MontFp!(
+                "19254599251563419291917070286392442185953319533539277743671301170974538926863"
+            ),
+        ],
+        [
+            MontFp!(
+                "13484950581044387790699808025679828353525564359074072540546898366258715012209"
+            ),
+            MontFp!(
+                "22343198701887410072839306125341999541910017950337822426701709261654687100019"
+            ),
+        ],
+        [
+            MontFp!(
+                "23744381985267930147584345166240449275240571238599640944672556783433612752121"
+            ),
+            MontFp!(
+                "23298704662463004610867633087122878818807806229067924536104460869464397280431"
+            ),
+        ],
+        [
+            MontFp!(
+                "10901969816874064074055609557052020560289773161571433372639597724664488626743"
+            ),
+            MontFp!(
+                "14156002715893990298124569001846834233181689787551964956848472227361674962244"
+            ),
+        ],
+        [
+            MontFp!("2685661085030044218247160275297965021653966271389214170504599521193726533183"),
+            MontFp!(
+                "27200270414533964726162123514926790320147103979035007282075520244647503838599"
+            ),
+        ],
+        [
+            MontFp!(
+                "23499290736068897121136270149638030306725062008678375617787025000791534716896"
+            ),
+            MontFp!(
+                "25026692459813233046431171983525524145614222754230448473639282509218760643342"
+            ),
+        ],
+        [
+            MontFp!(
+                "20234924212220459349154855523573808496328490380237819223402109380873383268084"
+            ),
+            MontFp!(
+                "21097046734498187192956166501059419846236308598800867960166513107722059985783"
+            ),
+        ],
+        [
+            MontFp!("5723667574872238562451299599065968208569545175979673123015901199375402731508"),
+            MontFp!("2828728541494243402973658858936864612123420753433922108105647679225192853395"),
+        ],
+        [
+            MontFp!("4099092899011497941968257374349586848896050807634400518463677246271112855037"),
+            MontFp!("150131280709355622082940757968315323579015475231286748392218323571026837267"),
+        ],
+        [
+            MontFp!(
+                "19894449139857240555705312448159189246841762765700063438336987995289197218519"
+            ),
+            MontFp!(
+                "24875748876379422594451357951531237197649326824356184360547876032704330271170"
+            ),
+        ],
+        [
+            MontFp!(
+                "24493898403471906106787773555022521405563810738036827974515797355651064390325"
+            ),
+            MontFp!(
+                "17253096251863725332511136889614595094402775244274501817916895189814344932888"
+            ),
+        ],
+        [
+            MontFp!("9134488723679638053711251020702906077137864096510131652265551158796793227474"),
+            MontFp!(
+                "17726829333985153077368360474346691888335815112930425987929595148814889006389"
+            ),
+        ],
+        [
+            MontFp!("7139340201852932211431160220880328807651329501726391234892608841222552160606"),
+            MontFp!(
+                "18955337246244101814822968825597022170900438282723899171149937891860493874822"
+            ),
+        ],
+        [
+            MontFp!("4430511922036777486626383439457633684018959858766416084903637567089736259613"),
+            MontFp!(
+                "14444618762096317022443047531879836371934041642558066315650981105814099776962"
+            ),
+        ],
+        [
+            MontFp!(
+                "11096655068501714268711593027757944073137485158556813318108550441199982914914"
+            ),
+            MontFp!(
+                "17041976004971069533492844072793736478596943003235751807387629676138459667098"
+            ),
+        ],
+        [
+            MontFp!(
+                "12484699643041458401024394380009192393467599824824608049972185368459787058074"
+            ),
+            MontFp!(
+                "28558607328356925634461776039741009953997585736757816776779486511158310952094"
+            ),
+        ],
+        [
+            MontFp!(
+                "27559592860665995137583787326839574090662823115633693077966946027357772908542"
+            ),
+            MontFp!(
+                "24716297773849956618319385212563484988251373483065689737786140245305294186908"
+            ),
+        ],
+        [
+            MontFp!("3250561641846386044121291265031031004912037858884853207833901205710035298315"),
+            MontFp!(
+                "14278453106820540480534441284466423530347095475925212377268054301185373365294"
+            ),
+        ],
+        [
+            MontFp!(
+                "14587853109773942626857443803972349145163181462617300608882957772815546473803"
+            ),
+            MontFp!("9169556196285897631253508045037165433194456401783864432804740394777876276365"),
+        ],
+        [
+            MontFp!(
+                "25434557676576511493564489817268595062611907382152528279312545519419910574713"
+            ),
+            MontFp!("5137923464491765101403117066738036857747293130548595571015177010649541139743"),
+        ],
+        [
+            MontFp!(
+                "21370958326990209412398814012332687102350498115740779055544011861145715684026"
+            ),
+            MontFp!("3264521601189402979002844480836771620617701378680087164817756099438503846441"),
+        ],
+        [
+            MontFp!(
+                "11034136207993467485208386820753315320068578926635830694503614664071882876272"
+            ),
+            MontFp!(
+                "17786591010025763895876238985816515559379140979466886272128469934893129460038"
+            ),
+        ],
+        [
+            MontFp!(
+                "16190841096657979348936291077544373292713927965903878551083623563773680766802"
+            ),
+            MontFp!(
+                "12770776716681549995796080958646978384426180389375759552749118588088135761812"
+            ),
+        ],
+        [
+            MontFp!(
+                "11142831575950106627117454729442839297195482633655362311773877849208172024599"
+            ),
+            MontFp!("937974246803679115776664090066354878753087342421114344724844687663313210165"),
+        ],
+        [
+            MontFp!(
+                "15546767797631447305965210465752144857649875858626335634605293862808270463478"
+            ),
+            MontFp!("1838318956323037175066655447938820856437471100644852967773383854913285189034"),
+        ],
+        [
+            MontFp!("82724860233775906741829636931412876271053100363960395494927397260812636842"),
+            MontFp!("2656274377843403127772973809123219434517848904395718731266962999986803030686"),
+        ],
+        [
+            MontFp!(
+                "15972294225516973167016570235219237215816855983357174100878811453667056042746"
+            ),
+            MontFp!(
+                "19070660892487421815727530425828841434006016067224999749747120978228088052448"
+            ),
+        ],
+        [
+            MontFp!(
+                "22194260930752626913260387564829411804242674836219652390521874344192007774514"
+            ),
+            MontFp!("8094383081352740827737160985415292865138395514548024915596097467925758128301"),
+        ],
+        [
+            MontFp!(
+                "11973864400513962771905311411628446968500915256237588166777286554979856761034"
+            ),
+            MontFp!("860140094309584209307385273920186570848243341353637952177199120748759887893"),
+        ],
+        [
+            MontFp!("881775279294356920849103433811074188082810800638192209173302856737399920540"),
+            MontFp!(
+                "18224653408250391689479016270814924882394014013865057322213443036968742528124"
+            ),
+        ],
+        [
+            MontFp!(
+                "17824172853653243035325837938775078098682173778115498346461456117964847425221"
+            ),
+            MontFp!("3627167377912130666031698697055448202832326440051432640337829618361025725920"),
+        ],
+        [
+            MontFp!(
+                "26621328677772256762425864408410571810807849254587211148708028578799268233573"
+            ),
+            MontFp!(
+                "10206054486644074336247260040743370318122918819646333500815400045651885873817"
+            ),
+        ],
+        [
+            MontFp!(
+                "26726669470909339988769288011680046765168183720906020606582577100376502595406"
+            ),
+            MontFp!(
+                "14724757198149865462269073480586424251567513124746213484604709192328275800253"
+            ),
+        ],
+        [
+            MontFp!(
+                "11274087298593686647560206998241208013315323971731934949412272963687529734682"
+            ),
+            MontFp!(
+                "28899852338506758536330657852638680369286202105792478126822455486083765529719"
+            ),
+        ],
+        [
+            MontFp!("4679072846133740500639209932265066818011600025600522314677982779273152042821"),
+            MontFp!(
+                "19139982898642845172405964114565690318400936614338376340356878055328037370917"
+            ),
+        ],
+        [
+            MontFp!(
+                "22798133432774706372822920975217532706564243923096271810618303953708796448579"
+            ),
+            MontFp!(
+                "23282077828195986244791454881158217858582940284574652758061489164081440136309"
+            ),
+        ],
+        [
+            MontFp!(
+                "18410173698981084318393650754647141435424676178736129460647647045921087259415"
+            ),
+            MontFp!(
+                "22631474084250841758650469935476573324812453176189875618875594296062942982599"
+            ),
+        ],
+        [
+            MontFp!("5544265673650068566365869797633260356160998967006190960366173412545707501948"),
+            MontFp!(
+                "12172780165739959641414710028405823501110608236992275485583813526232604665327"
+            ),
+        ],
+        [
+            MontFp!(
+                "18506569326913627685798812678254544578779125443232250943724786622137361619347"
+            ),
+            MontFp!(
+                "15305052749999511847244715224219683969140891034531689333189637677654864193500"
+            ),
+        ],
+        [
+            MontFp!(
+                "28121346304499170375434334792036926591903410716276664133913706149799431594260"
+            ),
+            MontFp!("5817782431032803264173323367450776572878339563149906941516133476754187641953"),
+        ],
+        [
+            MontFp!("7332677614262196294381927996984274490310236312263177311781238039164606857654"),
+            MontFp!(
+                "15020079431615618464631448609548400777855795816288261570275905975679608527331"
+            ),
+        ],
+        [
+            MontFp!(
+                "14064764078787217702782351665572001662718812761160327970394472786449923582152"
+            ),
+            MontFp!(
+                "22069032839909743567953127539128681345757596474852717253796979567707387007091"
+            ),
+        ],
+        [
+            MontFp!(
+                "19148430750049830363836336617147430405500949443846864744370942736011960833792"
+            ),
+            MontFp!(
+                "21268493662397457002470459219096902787914644053500577324067350989827448968594"
+            ),
+        ],
+        [
+            MontFp!(
+                "16198452885977457818674833383032764380951191410954799077626666867378776515407"
+            ),
+            MontFp!(
+                "18179787581381283956672324900707865864346358500365726936412089628464466338143"
+            ),
+        ],
+        [
+            MontFp!("3182361928018124322402793044393779908100692462947393447799704955237348971685"),
+            MontFp!(
+                "25333418149220369255463615265889720404701521733596727254519946975636276916074"
+            ),
+        ],
+        [
+            MontFp!("689084580096046807055310440329541532753681818021589579174917339910632100868"),
+            MontFp!("3521006694739343785942657221560942326251469191526419224046080849519240376374"),
+        ],
+        [
+            MontFp!(
+                "25270811340064906597239774793953645273013351609068002505856594850172826943455"
+            ),
+            MontFp!(
+                "28814829856952352923146986550973046874597200758713610185148312517304202733182"
+            ),
+        ],
+        [
+            MontFp!("6148386244662299098698413264701333629144010775879688425808668201256837517311"),
+            MontFp!(
+                "26531701397325674794630292010516691454926123856980263114025170231669465189516"
+            ),
+        ],
+        [
+            MontFp!("8171320995458211354777193573479383144307669802467554258644351481093368092679"),
+            MontFp!("9473590011230332821575745186767905996390750795638188631608111308172747462420"),
+        ],
+        [
+            MontFp!("5441924427077837999602811312530651112119349315366189493881714072069067363818"),
+            MontFp!("8047952810551491715878953402396185446591493644258429004226221181172891949802"),
+        ],
+        [
+            MontFp!("3725624397274391031957439840080198574403067563217562471403451753644909823997"),
+            MontFp!(
+                "16375748697393591928259856135098715184136067097222994286066820461882012332007"
+            ),
+        ],
+        [
+            MontFp!(
+                "11919711882366989417211643475744986995693944781339150708983918631144939418348"
+            ),
+            MontFp!(
+                "13113620150200657160226879145419383174229697008435006707849448404730613560534"
+            ),
+        ],
+        [
+            MontFp!(
+                "22163287331294987786187645719367453109109405870445709738292090587357193143763"
+            ),
+            MontFp!(
+                "10721275016133624779775425376342908038842167997723348619039381625891997628400"
+            ),
+        ],
+        [
+            MontFp!("8385517650730420056608783275622243498284229587726520618131984118354260720631"),
+            MontFp!(
+                "19885500633127382344120075953567463857312816371671907733468714799842548109761"
+            ),
+        ],
+        [
+            MontFp!(
+                "22508150898639954097089506817129563972519402587963014733114008512373635856841"
+            ),
+            MontFp!(
+                "10241851084946590321831603372389042777608538065902762910537467864051580544146"
+            ),
+        ],
+        [
+            MontFp!(
+                "18202678106181823769829221384345510193661404323244604661859497484577035498294"
+            ),
+            MontFp!(
+                "12628255237243063398019640310264331991699073163387201416915416904765296144072"
+            ),
+        ],
+        [
+            MontFp!(
+                "21601258316835681332762202974484166187648153015639053074373073590181572738391"
+            ),
+            MontFp!(
+                "26084268725995554031496844423442474600898744918267935422137000539750745183202"
+            ),
+        ],
+        [
+            MontFp!(
+                "27343368273239021725823457237134061731251729739057178144926633083721754398230"
+            ),
+            MontFp!(
+                "11646571930693563497532241665222162218283747845918093130978053836015231727253"
+            ),
+        ],
+        [
+            MontFp!(
+                "26906483607723070896309977791861200845162631601126842096596125019372626449507"
+            ),
+            MontFp!(
+                "15977213938098880613274111632115313383595801022150013898374500548120663029207"
+            ),
+        ],
+        [
+            MontFp!("3476401377408036493720370488533704901737960188248551594531276661301555255396"),
+            MontFp!(
+                "27261159247070916485498790588425904916368251619955262935700422833590227410676"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "14474011154664524427946373126085988481681528240970823689839871374196681474049"
+            ),
+            MontFp!(
+                "19298681539552699237261830834781317975575370987961098253119828498928908632065"
+            ),
+        ],
+        [
+            MontFp!(
+                "19298681539552699237261830834781317975575370987961098253119828498928908632065"
+            ),
+            MontFp!(
+                "21711016731996786641919559689128982722522292361456235534759807061295022211073"
+            ),
+        ],
+    ],
+};
@@ -0,0 +1,613 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-2 instance of Poseidon over [`Fr`].
+pub const RATE_2_PARAMS: FieldParameters<Fr, 3> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!("5008839363141387316460582940226799324399865015853941760264133396360459591973"),
+            MontFp!("9816885570216849905841908843340064072788117220771877575228699926341384258662"),
+            MontFp!("5005165460276326761817738695792245340455590014870209133326007356764602870342"),
+        ],
+        [
+            MontFp!(
+                "11020866305561641529038863257013500611401004951771141620118161092804555618415"
+            ),
+            MontFp!("2317911988909745602352426046014049023280625166523904206939556821765506936614"),
+            MontFp!("5591202235095954690018221989726472331591381251678544503326113338049651822798"),
+        ],
+        [
+            MontFp!("1783922189081788588132769394189028288265546916477125756788534710977074309996"),
+            MontFp!(
+                "16006545417925875848478466984838620730119997507944584396349233087981774600823"
+            ),
+            MontFp!(
+                "27692603228881916510355401964123023868435926535978406827809212806468813701896"
+            ),
+        ],
+        [
+            MontFp!(
+                "10030127083881151123493696884101662167327410584358878920881456536999724916747"
+            ),
+            MontFp!(
+                "18202052703307256290181397905677123944497164464622948801043210399347990798093"
+            ),
+            MontFp!(
+                "12740175182509714541466143963908129136282228500914171561380442985549290990487"
+            ),
+        ],
+        [
+            MontFp!(
+                "21513575350951581950190205627878140647793708934798308110808736808645087833457"
+            ),
+            MontFp!(
+                "23570036225621157506337715210723807523608220607736239386703456782395921445235"
+            ),
+            MontFp!(
+                "20582402639173253760183769688013430923223350421383315425719070438857067126819"
+            ),
+        ],
+        [
+            MontFp!(
+                "17456365133168257231256246976020776476471177654647721798731013444565972444373"
+            ),
+            MontFp!(
+                "27813387106382194034109553642898430412204146863305848822662236600802343516758"
+            ),
+            MontFp!(
+                "13142718216556541151767918370510400071058208001032162498407805676625063598092"
+            ),
+        ],
+        [
+            MontFp!(
+                "14279991132788062104758517436074305591146532277786315880398122246299654767257"
+            ),
+            MontFp!(
+                "16719548092600147949909192539205199361293354849892829278319773631651050985228"
+            ),
+            MontFp!("6610734769170393305667259116265200352609061255215421649796630362032237821729"),
+        ],
+        [
+            MontFp!(
+                "20245530935012058475474276026767038955300548340347673718105580857249939430722"
+            ),
+            MontFp!(
+                "22428484138139795334542782141897639834021397714861617874200519533160360118504"
+            ),
+            MontFp!(
+                "28881265675827142915225553962241790027216289305144236958510214835581498800647"
+            ),
+        ],
+        [
+            MontFp!("86950289078849963751440890854557288508577567484121986421412071920403495470"),
+            MontFp!("5345459590313267443169713441404104605823997323022813543363384648021456388332"),
+            MontFp!(
+                "10636064407458573368955288197989495418994632737806424860657837451194550720958"
+            ),
+        ],
+        [
+            MontFp!("4838677658711667721528000870700683437393628756305788204064565725376109950765"),
+            MontFp!("741971085774291508267598204639408439439883719952565410292527601022790237785"),
+            MontFp!(
+                "12097091097209138940130038559215028845920797353523817052178734575621620520485"
+            ),
+        ],
+        [
+            MontFp!("1390875613764067929069501359898065171655160753895348963119764866110414207967"),
+            MontFp!("4894624747841559480569646291778415974920730249743025229617928225010841605291"),
+            MontFp!(
+                "11390065649844237587025294611942465115851520204434962430765001376703967776650"
+            ),
+        ],
+        [
+            MontFp!(
+                "22390975781400238192673403316895665541700236985080967727698185776069807181383"
+            ),
+            MontFp!(
+                "15508569319805233953728992537611252224018323596036065989560827334001331755567"
+            ),
+            MontFp!("5087633888079342266931936166907310681224072526405378994939170012157039874018"),
+        ],
+        [
+            MontFp!("5386080978744089985916942131384337296631829926504398410326875681517725716291"),
+            MontFp!(
+                "17676371547381278569054717376380277949222740553026361832680262745073253838053"
+            ),
+            MontFp!(
+                "23157842968512200572219935827798016088570783033937231938445641378060874299446"
+            ),
+        ],
+        [
+            MontFp!(
+                "12917602217783530084725733606281479341152871733546587705820772587203388853711"
+            ),
+            MontFp!("8820689670220317014853731068288475949848229496598142016121987332025198005352"),
+            MontFp!("8890073460394142801656077180570069223005892689484550252249391122642515842186"),
+        ],
+        [
+            MontFp!("6854061964261033650550845732072760745672476142303354298166763529869135194218"),
+            MontFp!("968080456825680911955015878903103436262275964394943422238769614739684038599"),
+            MontFp!(
+                "26987978592745692135665617833433698195192431422044419942542486015884085171961"
+            ),
+        ],
+        [
+            MontFp!("4069048769691694793551866508112144366178620023017501184705806478946760593770"),
+            MontFp!("9030760171787809884776189648092178331803463876790643709251723793047465743187"),
+            MontFp!(
+                "18421328575891499543180845029828203523341731958469925611602959811749413019445"
+            ),
+        ],
+        [
+            MontFp!("3977247332648520277439283841929071858952663834535655763855376684999536110423"),
+            MontFp!(
+                "20881951924797317860789683023872975410049772679336528832870597633034459891230"
+            ),
+            MontFp!(
+                "19331457860407228709861436243865247799196410223981901934966177535717089239371"
+            ),
+        ],
+        [
+            MontFp!("49206397287902362797331916911381432427259948236759626923046747589788513577"),
+            MontFp!(
+                "20153124964812652006683871625393357720006711538850344561094677432623549501068"
+            ),
+            MontFp!(
+                "11746161299173539054170902254377337646013750652753543912970932951949788446554"
+            ),
+        ],
+        [
+            MontFp!("2507246409615052420829458063467631549568817553199136103355761145931140425546"),
+            MontFp!(
+                "14212740680719132183237443857936056148284657367569646442370607000608721212008"
+            ),
+            MontFp!("927072325865098231399486022326468268198058395430951035503838112832796897361"),
+        ],
+        [
+            MontFp!(
+                "14819691727984786907942319247318814012888600946526898350973333214967059367859"
+            ),
+            MontFp!("8397906497503012666810178845082350341285979142625854619432226489612014518084"),
+            MontFp!(
+                "27262449070294637361571410567965201614893684166217566449118934506444489281511"
+            ),
+        ],
+        [
+            MontFp!("4635972832596582538474602671254484755761053961613353703853759543518473630514"),
+            MontFp!(
+                "22164520858900974635826098288036844439115532201492638504302937854971728543239"
+            ),
+            MontFp!(
+                "21920895533196832787992748078251087646164417222224914669988833140771949112309"
+            ),
+        ],
+        [
+            MontFp!(
+                "13651928729915199539274335189503455486035491416773857408696561223511710960794"
+            ),
+            MontFp!("5724746201623027419218583169171269822998852800512360337666023671701423094704"),
+            MontFp!("8196548413347627758992946037979720578691889819991946385132426987779544723912"),
+        ],
+        [
+            MontFp!(
+                "22332426329297900213643972491229560093879361901766699695720002814725970654580"
+            ),
+            MontFp!(
+                "22809097489020696982810430372332832090905320493635219269304943962484735897376"
+            ),
+            MontFp!("4628948609989045131365173451478864630260275869681188061768249887431123275477"),
+        ],
+        [
+            MontFp!("2039372935008127676038525226827611015898249442317230610235480973452336840840"),
+            MontFp!(
+                "11513907641565333699441714152901292519595776121862634337046260728428115893162"
+            ),
+            MontFp!(
+                "19185684565275171112374452414252628320445994197588875360995544583702544688063"
+            ),
+        ],
+        [
+            MontFp!(
+                "24185196264854433057460047891626221460643897764501624234547125750969851624346"
+            ),
+            MontFp!(
+                "14493126973397810294615250115658716469575108630237991553753975130799724388578"
+            ),
+            MontFp!("3377864444505423884104597939303310783496381967884271967523609380704854238857"),
+        ],
+        [
+            MontFp!("6088182783409354616790120912871897246826636701825253009287080254270341207763"),
+            MontFp!(
+                "19142639408803661214246796676730244492829077705468021685835671645954962112435"
+            ),
+            MontFp!("724313481367863362373019683281662021483261130523817044171533209085105226075"),
+        ],
+        [
+            MontFp!(
+                "16249321438217433882952691371439768933428121111684402565674507208380232065863"
+            ),
+            MontFp!(
+                "24426726839076180357137264037786560505074486547001745726692271001199389772101"
+            ),
+            MontFp!(
+                "19126475796775188916946954565755616061336403874540614817168551973109377648385"
+            ),
+        ],
+        [
+            MontFp!(
+                "12099239993890975186493363297691840950825692037395108486136437849323959553869"
+            ),
+            MontFp!("2254639444862920964438640767138226750775079542742650183173559789007552825974"),
+            MontFp!(
+                "17177898532290972552765632179564124366455164261641475750415254276426900855083"
+            ),
+        ],
+        [
+            MontFp!("4412155836431247394179487537690867314656510962299296398287756962268956785896"),
+            MontFp!("3472107566545602875081235621039409676260856378970919205105797843630864676525"),
+            MontFp!(
+                "21230406913636380152732445227912834169567829338223148704015438686277957458404"
+            ),
+        ],
+        [
+            MontFp!(
+                "20167760809304207166078532273940649661340523537430989849861195526819466113705"
+            ),
+            MontFp!(
+                "19018183336695964000636619780988528051624958857287445564563743706493315205962"
+            ),
+            MontFp!("5826914472892109571902589673959393092373058832835566793434469694479337979590"),
+        ],
+        [
+            MontFp!(
+                "23850955817129749255059884522106726369504926570668147713710983229451577944667"
+            ),
+            MontFp!(
+                "16230664954833058089642004196793326708376537001007285169975125185101868640881"
+            ),
+            MontFp!(
+                "28568883024484570426833016600911536931786576095733112542369979996564876618608"
+            ),
+        ],
+        [
+            MontFp!(
+                "21816050112722703501076501666742431110470185191386337229271682340582737292831"
+            ),
+            MontFp!(
+                "23027978280239132332456035615275285000526383368371481376704595363846561526307"
+            ),
+            MontFp!("8176927883403327149763845764665670307343703485832262476322499182300666942587"),
+        ],
+        [
+            MontFp!(
+                "17192028084486675540958851024208691947538114728859877415262741698966155858890"
+            ),
+            MontFp!(
+                "27789955613311816764475619871809842221468223091659950054749364441930498881050"
+            ),
+            MontFp!("2608473838008256042610248630900790173077180059018260174334860616160891667767"),
+        ],
+        [
+            MontFp!("7181412915935292761364905879207013913513270689748985135203996234875492585696"),
+            MontFp!(
+                "17613194877151492584801201002543910519546943446221162227432276177829221412419"
+            ),
+            MontFp!(
+                "28841433005862224484914063686214159748187533067483496867641743388531455375561"
+            ),
+        ],
+        [
+            MontFp!(
+                "19517570611656284834835389035116069973055976702642740833976863299805499494550"
+            ),
+            MontFp!(
+                "18306036790299029141218956212752088804883920505808847585231139509997185233489"
+            ),
+            MontFp!("6205250947374087500242806398077340226368272103452895487199325504506543068922"),
+        ],
+        [
+            MontFp!(
+                "16684794718062218168023688285567283820970935215487178876390463857472422860592"
+            ),
+            MontFp!(
+                "26044134903304523571713228846847456533668486793543117435811282214834046470951"
+            ),
+            MontFp!(
+                "14031685720136407528666307922666657212290854026512909262733509385988203115848"
+            ),
+        ],
+        [
+            MontFp!(
+                "12689589436318466693808878859614798692860973703786707349701672868880700085529"
+            ),
+            MontFp!(
+                "10667922423970662599624441984601423836383126062732657910678914084050310750583"
+            ),
+            MontFp!("4399935490191384652251621903397576611839805788664706374995735372657251439740"),
+        ],
+        [
+            MontFp!(
+                "19974792553007227991258524798154411767772757245983538157286475373587839580655"
+            ),
+            MontFp!(
+                "14468274848791765248357188065280403608662170030215225751763650495706511902084"
+            ),
+            MontFp!("1831384451181655149709252426938115286532989809068292785323826299125257639131"),
+        ],
+        [
+            MontFp!(
+                "13971590034937483543634010686839738390659200960996808474633148797896058558587"
+            ),
+            MontFp!(
+                "28282039546721878419088188737768316384666799481816266403419072604655455233909"
+            ),
+            MontFp!(
+                "19271865382893704874195444478372583167621675324849712160280177943923140597279"
+            ),
+        ],
+        [
+            MontFp!("4216688893700824370195366998957090439939962550181733914364626885227991419216"),
+            MontFp!(
+                "17489832478507150597636826196414967529425586419009551150366431953981287281254"
+            ),
+            MontFp!(
+                "21783617548329663035880569288937504969582579107750393268549461743529612352391"
+            ),
+        ],
+        [
+            MontFp!("3582065349665526649328526078296367415647173339703645410233384987910251163786"),
+            MontFp!(
+                "28789357094428582120986138757928407724175333258725166206053140450406909179941"
+            ),
+            MontFp!("7811553186585062648992113355322041354226617867039000138736475198130863191303"),
+        ],
+        [
+            MontFp!("2189485166511317353133502874455719205619715906731112112374057191333014591046"),
+            MontFp!(
+                "13154993686735349762012898101230628612260214432863489419574395515092087972848"
+            ),
+            MontFp!(
+                "24054985480316744862075638654553149849536829296086616195943205672165995851284"
+            ),
+        ],
+        [
+            MontFp!("3868044632065060573099048231987930106154873260380763762259110007331633567805"),
+            MontFp!(
+                "21186084387082787188361718289731163130680306403899816595347480599542888316778"
+            ),
+            MontFp!(
+                "26656139086513217611479617636281554068246395699362434216793084782176038599902"
+            ),
+        ],
+        [
+            MontFp!(
+                "27839232407768613895115117457920907364455848741348178585876480326362828017469"
+            ),
+            MontFp!(
+                "16773650992236178761649667774172860868458420701908710949274248301897567136768"
+            ),
+            MontFp!(
+                "11188165885548231887479134070434713010371429818455404608753792501064554224874"
+            ),
+        ],
+        [
+            MontFp!("2240774022778137399587760752173529745038205919336685396024801561524399053247"),
+            MontFp!(
+                "16528720504320769252924802059780140187442586013058598649846044637308796969590"
+            ),
+            MontFp!("445180885686286331122366199667699780854994989759492008401697640406896075050"),
+        ],
+        [
+            MontFp!("924053393760511345035346327419908610335050353056955899885244310614266157432"),
+            MontFp!(
+                "21607928050491534698853447825072461219441428064078624560692043141175975870971"
+            ),
+            MontFp!(
+                "17565915268454008983726471645998631256453863098471021348850354076179343559910"
+            ),
+        ],
+        [
+            MontFp!("3868719844435637173430171985605313136482963861176435872963726803200525952243"),
+            MontFp!(
+                "28408432865266181508403548182254392788324781441436343289856274709056449027590"
+            ),
+            MontFp!(
+                "21034832172880052322905633388800967909349444246346199667084594434624160639686"
+            ),
+        ],
+        [
+            MontFp!(
+                "23961310654267693343447626111759961041038967832147476880656777619978765328102"
+            ),
+            MontFp!(
+                "28431652026507039062055097377221952244927965308764996395319791124870315738645"
+            ),
+            MontFp!(
+                "22981392285007288256719776697257595645591424967746838650413563589502675548923"
+            ),
+        ],
+        [
+            MontFp!("3427766516264639939572681151605152391731126152507787847342860101119399401119"),
+            MontFp!(
+                "18176791435051402363767554370205584923766160286401621824937782130975577219022"
+            ),
+            MontFp!(
+                "17999206076805662127542400369455022552106506941579676067832550845005327640097"
+            ),
+        ],
+        [
+            MontFp!(
+                "16326822067074313565512382590270385601191909167043694578756283226221871417770"
+            ),
+            MontFp!(
+                "12629366343384644159536130571097455397060602006474876014880032766670276756514"
+            ),
+            MontFp!("5987250539098777337683347340125984918470289706044027625051224878840997177876"),
+        ],
+        [
+            MontFp!("2076007381137285143852212131436029781458692995258608228284407304022150670385"),
+            MontFp!(
+                "25782601348718356231310088541283920500841857247788278702232586055716918572660"
+            ),
+            MontFp!(
+                "20090022876664251362655368390253530657619977429599745679979768114085926221024"
+            ),
+        ],
+        [
+            MontFp!("7606698809387257570214694241635918210296049747397120655866526255534629424216"),
+            MontFp!(
+                "22165874697187092226099321109156807957645131487934892122440199659996986787185"
+            ),
+            MontFp!(
+                "20459549240264798920490133168046483752753153795093945275916303850670152612753"
+            ),
+        ],
+        [
+            MontFp!("73610818704153108026434408222202364645999293054005961931805359637867586370"),
+            MontFp!(
+                "24847893433581223497376718200373738653138838494315910836945769713083986274753"
+            ),
+            MontFp!(
+                "10010982565066543179389753706767018466610011846470066839997082849223599653341"
+            ),
+        ],
+        [
+            MontFp!("3452438204168241061218202569226370427763666841127949547075114540147906402456"),
+            MontFp!(
+                "19773945454044947858386025241234358483779205994372564856406485708428035354370"
+            ),
+            MontFp!("5543909520744954182708361297780530945713755733249829646581599844297812823446"),
+        ],
+        [
+            MontFp!("1909664866959579599386983632191650010231617750661902863124648390755538400689"),
+            MontFp!("8007040138671589476173336841852581393397757120328779604207939371368723846340"),
+            MontFp!(
+                "22959054934625690002857328099478039507538345099975915814102023343692398890763"
+            ),
+        ],
+        [
+            MontFp!(
+                "10132778591068903065117944119971312088773753859056558067362067743536264399819"
+            ),
+            MontFp!(
+                "14185913783409228340952651187872774367085260173197226014457631657235745589209"
+            ),
+            MontFp!("9229695225653806136390717354211997457483788057986488476834870283487659596808"),
+        ],
+        [
+            MontFp!("5462632777407324170637984877548304297000252448511180912146166789265484793466"),
+            MontFp!("9684862749914608984264928794454831990386688684232145672956190487618440687521"),
+            MontFp!(
+                "23881856345143065043550492385334851640409395485259227206690755463789047769185"
+            ),
+        ],
+        [
+            MontFp!(
+                "26417274804673750883533890946208323322368191967098381031884353520710429441275"
+            ),
+            MontFp!(
+                "24270293322357210484377938636509682138984293658526619058304998173819927028906"
+            ),
+            MontFp!(
+                "20704604494670466892190604351101962572572325760715118171625498782315518509367"
+            ),
+        ],
+        [
+            MontFp!(
+                "11133676833751717239870421940696578138569352349153559419025802110225406949356"
+            ),
+            MontFp!(
+                "24660353901663928270858695566366471568166419258383728907049606093689450838608"
+            ),
+            MontFp!("8401957389953570386399398038481099447615913512157938264069058023222080923118"),
+        ],
+        [
+            MontFp!("4784634422617924570810817516841903203763867666712842901867083393473193724653"),
+            MontFp!("3066298535024871832511293278571811510312298891421208127349294452816137143915"),
+            MontFp!(
+                "10051180258377366832590662227036305889689288609503800247878899250341623129761"
+            ),
+        ],
+        [
+            MontFp!(
+                "20207087046012966438804296857146734537772517650773898059653848623947293598064"
+            ),
+            MontFp!("3949304775570209195296131215710923240156225537031503152129390157202532949545"),
+            MontFp!(
+                "22329643257088514550039102855535707854586552436833658282623567059980718629049"
+            ),
+        ],
+        [
+            MontFp!(
+                "22187597038350096597566269771872627506253676159679477777163454108079476757140"
+            ),
+            MontFp!(
+                "11059137315239367336670169384344068827062019226061374660217368637785881910232"
+            ),
+            MontFp!(
+                "15024695971516248886418847818641571468345415653219040724983652787609631684647"
+            ),
+        ],
+        [
+            MontFp!("6137319497592697408093714124198030357343653162436465507757243194350207713577"),
+            MontFp!(
+                "17322963139176962805365784861472996249691012226214652190337674404154235131377"
+            ),
+            MontFp!(
+                "25836811518335883952859832457822113787736805757959435473272753634979763576207"
+            ),
+        ],
+        [
+            MontFp!(
+                "28271162249773804805821900970876161494856027506190730968321723026977292521758"
+            ),
+            MontFp!(
+                "10219529669278689030939221291325622408584521000947072667844145347161643089754"
+            ),
+            MontFp!(
+                "14507629845646108258353711455871724916552387962786084430210881783232838012972"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "19298681539552699237261830834781317975575370987961098253119828498928908632065"
+            ),
+            MontFp!(
+                "21711016731996786641919559689128982722522292361456235534759807061295022211073"
+            ),
+            MontFp!(
+                "11579208923731619542357098500868790785345222592776658951871897099357345179239"
+            ),
+        ],
+        [
+            MontFp!(
+                "21711016731996786641919559689128982722522292361456235534759807061295022211073"
+            ),
+            MontFp!(
+                "11579208923731619542357098500868790785345222592776658951871897099357345179239"
+            ),
+            MontFp!(
+                "24123351924440874046577288543476647469469213734951372816399785623661135790081"
+            ),
+        ],
+        [
+            MontFp!(
+                "11579208923731619542357098500868790785345222592776658951871897099357345179239"
+            ),
+            MontFp!(
+                "24123351924440874046577288543476647469469213734951372816399785623661135790081"
+            ),
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538084216959852999081921684627"
+            ),
+        ],
+    ],
+};
@@ -0,0 +1,798 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-3 instance of Poseidon over [`Fr`].
+pub const RATE_3_PARAMS: FieldParameters<Fr, 4> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!(
+                "12870269018693415980399714021137750346637497651856679789967097371227234970459"
+            ),
+            MontFp!(
+                "17415492090912875076632434267265015124560402271692183854695170684420481417892"
+            ),
+            MontFp!(
+                "24608743464562186771300215716250948761299738343902526881990133667015552717205"
+            ),
+            MontFp!("4053376953811915006509659473988159473374548719772695393354467615953234322865"),
+        ],
+        [
+            MontFp!(
+                "13004217054882021390252213827223168068629662205006278583803734592537386990712"
+            ),
+            MontFp!(
+                "16459392453025999215224563999060485662755490444733110247683366944747526011892"
+            ),
+            MontFp!(
+                "15254989797101693952155987794040874035443469911073212915799524852147537173831"
+            ),
+            MontFp!("9435880105579996803639750815614927121078659611956242688278965080490070294412"),
+        ],
+        [
+            MontFp!("3981098242180899696944247023081318617714924443117942901780153645240145701071"),
+            MontFp!(
+                "28510085160631371075784709388815667728131850602779561201590406463551779430183"
+            ),
+            MontFp!(
+                "12847004556499682968704749108133341678757768931083774186652723387423042457447"
+            ),
+            MontFp!(
+                "17191716475536805868205299777106634678492219753678632441316190669254585463442"
+            ),
+        ],
+        [
+            MontFp!("5019630049682573685026508212453302512219091126289032150946094030562948460591"),
+            MontFp!(
+                "19576959717280844097693888180514370864690686297758294193134655090776146950178"
+            ),
+            MontFp!(
+                "26899510887154985962529690937242960772340622445848998837862551652285940867122"
+            ),
+            MontFp!(
+                "22473921081503472244496363734604935319482094062469646674360083290452516217681"
+            ),
+        ],
+        [
+            MontFp!(
+                "10727652080614803484944108653619199729261910558990041665954474213832165135412"
+            ),
+            MontFp!(
+                "19376793952657181792747429948526682810919945377449227167987326052685958271825"
+            ),
+            MontFp!("748237694194452926725719077216320309479638463584756670963605498563567712344"),
+            MontFp!("1972253945610540926031024693310763978133533217628967396948789885235112625390"),
+        ],
+        [
+            MontFp!(
+                "24988150333547870518753022677617674072616436193632381062810404564917954792414"
+            ),
+            MontFp!(
+                "20018291943458832921631893671321061830510740030256019616865305377547682156342"
+            ),
+            MontFp!(
+                "23488520368245451045643226088812088575331086992400515343957903277081639303066"
+            ),
+            MontFp!(
+                "22885208521635069275529497599563814565002445038584840857628332648751835306398"
+            ),
+        ],
+        [
+            MontFp!(
+                "24314473221381773655207351108346593111920435044003946791324977847807166306741"
+            ),
+            MontFp!(
+                "21541580455482788349249683393519828934303306958474751249032109199739264736689"
+            ),
+            MontFp!(
+                "25642074647245874848746401832146789108523265720630532893772759004234484394065"
+            ),
+            MontFp!("477690037463790168038142994107535925024378108292160934294208270735089108703"),
+        ],
+        [
+            MontFp!("3393185038305105327599969557479222365064737305202961278106651083174063493948"),
+            MontFp!("3000627389507249456910729716905637730865776696210601232619525656318258365261"),
+            MontFp!(
+                "13354752365388453534064746302344358329937795132868386832318964768964783020572"
+            ),
+            MontFp!(
+                "20340847955219462428305993861581428152679970382171083471130499385513753120314"
+            ),
+        ],
+        [
+            MontFp!("2641630863311676068496196390523947721302290995223652207974159282540075129009"),
+            MontFp!("3558265300843944541599840551160223472762346093340997443829935299833956837174"),
+            MontFp!("3635175095125271880811217650451087479376036669621070103984352383644888691987"),
+            MontFp!(
+                "10312335472081646315252839727829456607143724344606877691801644554049905875602"
+            ),
+        ],
+        [
+            MontFp!("1971248157026297220438129250880639161048778122082793555986430077364279848454"),
+            MontFp!("7736114017845222681497988758844480891746946877924062460402354295798348052821"),
+            MontFp!("2595872785703322641025187333384041360019843319679512122455289605009950395183"),
+            MontFp!(
+                "20017062533296351078543815899026730437527530384036045826565362728193415317298"
+            ),
+        ],
+        [
+            MontFp!(
+                "12468207755904806033622957665446085629863726839831522038769941413640853116656"
+            ),
+            MontFp!(
+                "11067228538963631241487497819660431943303199088674301845958699438154281228951"
+            ),
+            MontFp!(
+                "22062188045770948430672542777877012361977823781959487726196321743781219773639"
+            ),
+            MontFp!(
+                "13980563047520380116160805348078206458757900619231196266209724474698765084610"
+            ),
+        ],
+        [
+            MontFp!("9537126159456366737906591386172021216825539933093577594052118037401190765932"),
+            MontFp!(
+                "18691165506648462673716526952660670331841254682578318495252472185019485107765"
+            ),
+            MontFp!("5881890723164569167837855067158129295272745713152405262976329595703008551773"),
+            MontFp!(
+                "22650942286091412894014402258991864315690946675009586005350429201030906528434"
+            ),
+        ],
+        [
+            MontFp!(
+                "26069750101263285029112391828853296977731883801034445328237875827030865004537"
+            ),
+            MontFp!("7135194554966216055790898362411583453279860409015898947833555808904220423598"),
+            MontFp!("3083904460717160065981586978250913569698523134569232392484359438395212810841"),
+            MontFp!("9728897221462494047332380027254435969092484644943407496531319675797044278015"),
+        ],
+        [
+            MontFp!("7560160851559480529258870065048393032771936261001079419814087451687738504118"),
+            MontFp!(
+                "18116562833630871213906609720786393012577245053995181164886702697295562229140"
+            ),
+            MontFp!(
+                "21003389148305615079163060666512476436043745332185108103966630310763277247574"
+            ),
+            MontFp!("3101709261286615076300477312628382409651671616279756334953588006890166597534"),
+        ],
+        [
+            MontFp!(
+                "15418926064591068936510389312729542770350840851676901374198492775244694586226"
+            ),
+            MontFp!(
+                "12275781808775377128366691009216308267127737927778495815792664768243084876231"
+            ),
+            MontFp!(
+                "10043921127519489614158889027979453839505733777395245705717391141508175898156"
+            ),
+            MontFp!(
+                "14744537026688340158572690640579266921685640601383392055506365536102149191702"
+            ),
+        ],
+        [
+            MontFp!("308884947230079188432990203381396042966257240589958756811111019558190954499"),
+            MontFp!("2309328611608607601013353768693119752519184404943844071274175028841108142861"),
+            MontFp!("1372790171079366420099703418650734902379586568065247950215523730249827443526"),
+            MontFp!(
+                "10012451074853450321801045743063855890490577469356881476511298248805366290967"
+            ),
+        ],
+        [
+            MontFp!("1692460157721875525212813817836716172756777240451973928877406665952201332568"),
+            MontFp!(
+                "17761982916513928866709455001243228290578894602563313913058869493846412861678"
+            ),
+            MontFp!("5994987979151459205966317918792081773021700470170954487240964480881636452693"),
+            MontFp!("6751459070491838765339053571808837556834769775338548062016736759141788103089"),
+        ],
+        [
+            MontFp!(
+                "28606609277467851397847576175896367687421388182069934504688464066877684431981"
+            ),
+            MontFp!("427776614387973724253461069498224931384716413718631668252579431979276691978"),
+            MontFp!(
+                "16730930304238944573664588382472772460447799536974991037236382885671618000598"
+            ),
+            MontFp!(
+                "11013432217963051154421015260053918385850274246173646954276588412096239622277"
+            ),
+        ],
+        [
+            MontFp!("9875829962881491717254139466342116840843047085325077109271414019530067067164"),
+            MontFp!(
+                "22975942106362776931802658570564854391639917893982378619980072233454966601821"
+            ),
+            MontFp!(
+                "21966073358816069482494046431253625163670208857851822482277260404948797037629"
+            ),
+            MontFp!(
+                "27699504629496506451531109090243549401946918307106502392433925855917024694477"
+            ),
+        ],
+        [
+            MontFp!(
+                "16338354113748573731082097303771827546801409834126745071572847629777783796032"
+            ),
+            MontFp!(
+                "18672552639072225887770759282828023712237200354522848413338902545003228654009"
+            ),
+            MontFp!(
+                "28315714429999810970767959261014365796377709747700728774989224202167386200687"
+            ),
+            MontFp!("4137129601597065037049130708149966646137271452187638970911465209789280764191"),
+        ],
+        [
+            MontFp!("9090236659182622520965935596090901540966493765406815762930405135749258556261"),
+            MontFp!(
+                "20212644160022878846396326565983311557714598780252806730672767714884934955842"
+            ),
+            MontFp!(
+                "25344791479513101749523140450792516594493177209165341590634302273555377620633"
+            ),
+            MontFp!("841491158534922804994433784151450453184408100669851386451915731048750429150"),
+        ],
+        [
+            MontFp!("9728026574065350709921073596622933087896752594959491569887662947087305218620"),
+            MontFp!("8744172353994219686309858949131170891231973439893429508008553421632388853600"),
+            MontFp!(
+                "25847096087585207525302487330608912562889577829090925756736208042368165026587"
+            ),
+            MontFp!(
+                "27050494238935233081402783864669244166001981865723570900454757307604585124512"
+            ),
+        ],
+        [
+            MontFp!(
+                "17238050198613603573637897190198237256534456710461586380031534218425699433238"
+            ),
+            MontFp!(
+                "25983314328026767572208606041220908927474434957298696484794592571032950722046"
+            ),
+            MontFp!(
+                "12151167601267263441648732351363315679531514128387476410519474544273664275024"
+            ),
+            MontFp!(
+                "16162294892146508109914242800145398798264955914467532992622190754284274376082"
+            ),
+        ],
+        [
+            MontFp!("845857620012260267070967960942674711000141804197493163976886680621263440802"),
+            MontFp!("7036458933330166173680224031991533056465076475967403126395785147805149378566"),
+            MontFp!("2697719231728763660990765691555595356666244004300492511364524368938024947438"),
+            MontFp!(
+                "24502552455176055693154379072405503823304255672933238959665173294963967282748"
+            ),
+        ],
+        [
+            MontFp!("5878607437552716672611723944077278264217886349468931328126466105314964774741"),
+            MontFp!(
+                "25992306287244332185235701795816614230250111691289084889312450494249596463894"
+            ),
+            MontFp!(
+                "22069364187907645228177867636009521898308228133588121760230075996621167290970"
+            ),
+            MontFp!(
+                "18004771133476219540686107257953298734951693058074426304176741433317078804204"
+            ),
+        ],
+        [
+            MontFp!(
+                "23245524145125373760074098795400785522468407185947562703416812464131699542868"
+            ),
+            MontFp!(
+                "18094948506177837352901303782320378110252637310775826164671833992247027193518"
+            ),
+            MontFp!(
+                "13613099075756383209569009440364754759146684780467637159859569107316878280645"
+            ),
+            MontFp!(
+                "20040695807360918103672237370156409690328593239027859914196293158139634750193"
+            ),
+        ],
+        [
+            MontFp!("7101992660561334608331449818593568403108224132304954498565190056359267786394"),
+            MontFp!("7323622441746539236343135740025620688855999116385289549169850592574402493097"),
+            MontFp!(
+                "22763342722798487837229729382565047104260837761953019847245725492966659069064"
+            ),
+            MontFp!(
+                "10675239477511785150233740944733774579238863666240810884462810554973294509104"
+            ),
+        ],
+        [
+            MontFp!("5885195907115955234469145108971191427917499553542342306229128413140826396458"),
+            MontFp!(
+                "16640427377075306839452015165157862424542484864966796925546822379159854931128"
+            ),
+            MontFp!(
+                "19964700981499554138038457455081583914897364195653464988252392455972193844842"
+            ),
+            MontFp!(
+                "17994229021823034034257945665254868751190632785110592634004973404277160086134"
+            ),
+        ],
+        [
+            MontFp!(
+                "14249101598312166920347556007416182032624393148140893208402764515322942287525"
+            ),
+            MontFp!("1597967094695250765345752641824258725959310879653438329553013887633294284844"),
+            MontFp!(
+                "10541847735804681861947587059496343189234317939536200648774068060009340052940"
+            ),
+            MontFp!(
+                "16945400526601436427727337679509095717941456269765239634454065611462177818164"
+            ),
+        ],
+        [
+            MontFp!(
+                "21227860524546806749319236456223017734481358105151886984618737152761285196845"
+            ),
+            MontFp!(
+                "20564344158384504147357725840571753405188381465132312260217514542043238882123"
+            ),
+            MontFp!(
+                "26628490704825712276977293546870471953152435750220108364678787984766005116797"
+            ),
+            MontFp!("6092253119432294256221787645304239729542052212654615645770175944955813786987"),
+        ],
+        [
+            MontFp!(
+                "12586525892412709390956555295256570233854275875580544812595356114767034365646"
+            ),
+            MontFp!(
+                "22522201356284845167460090535984607997470091849689556236559907419961705128651"
+            ),
+            MontFp!(
+                "25372917423153281668189276846790851172467631446194414203789320113213544484421"
+            ),
+            MontFp!(
+                "23444420973872324476463089003640247851605546935326645929027855343235143966041"
+            ),
+        ],
+        [
+            MontFp!(
+                "20102981076653904796816871755963313443049081924537711374385724723592799583965"
+            ),
+            MontFp!(
+                "13011478667443508107670612092125967063624650834497102363687528398218978872333"
+            ),
+            MontFp!(
+                "27826995419841557092173674337901754835322302593312561674636956089252793183775"
+            ),
+            MontFp!(
+                "16228394868575821089284212245535517116077852915825246224495675412141403618113"
+            ),
+        ],
+        [
+            MontFp!(
+                "25196216126978236746601415753529080898561911709374434470702095402778316163870"
+            ),
+            MontFp!(
+                "18467964809724067651956225360368999738998831047055043664916261051243364202001"
+            ),
+            MontFp!(
+                "14580399221160612038209948932954184937847832466452514203324786080088042935684"
+            ),
+            MontFp!(
+                "14180513728031760265266538749024707432818083287426514228592070603888012131251"
+            ),
+        ],
+        [
+            MontFp!(
+                "13957325834488839144273004305754496528546321835201175246794183550817997690649"
+            ),
+            MontFp!(
+                "23503213541019548342354006259900400582047007578177956736095674405060707742749"
+            ),
+            MontFp!(
+                "13155866980092695654141118237865784105977597077678347008507666999233206761582"
+            ),
+            MontFp!(
+                "13842948140556205671381102431930385862536416369949491179051051074167903664471"
+            ),
+        ],
+        [
+            MontFp!(
+                "23259344917640053199846342819797197437039308991137207819754266852714873895411"
+            ),
+            MontFp!(
+                "21632474208064227941623540708593108632503578952814889860827640964008292198556"
+            ),
+            MontFp!(
+                "26778856805060587695209612542288334155120571217729784570470780171555500164835"
+            ),
+            MontFp!(
+                "11782708265583861641386996976938663300663871968690734257500622628648918941405"
+            ),
+        ],
+        [
+            MontFp!("327628869038317378680072921521839689635513724479010364740407029881068591489"),
+            MontFp!(
+                "16010908954934400433829074683348453212978768215714153965104136189471192448031"
+            ),
+            MontFp!(
+                "14468281684115923387695623453548670533344079557049272646835668667948568205686"
+            ),
+            MontFp!(
+                "16742605833595117156264895968407868971279077185033970254132868657671886998222"
+            ),
+        ],
+        [
+            MontFp!(
+                "12155541869937653576460614175997139979145203396779270300640920193755302448954"
+            ),
+            MontFp!(
+                "20820204902133304759326083089068373837656034283401652342069462294439060108041"
+            ),
+            MontFp!("6505268062939547717102939183349920480310134195157894594878567748077754890429"),
+            MontFp!(
+                "15287843394610112465464943448741868774065554973383192825240894689515980374301"
+            ),
+        ],
+        [
+            MontFp!(
+                "15384864268947170825879343237270501339079434293141608835636596321596161730529"
+            ),
+            MontFp!(
+                "23300216918527810605412828997795975201222224308579244905905371256101112075455"
+            ),
+            MontFp!("2476142441383750717016890834687195207393959893990229439157343546989649411480"),
+            MontFp!("8664434891898415625041564528026531461944774006236618885940627310025001154663"),
+        ],
+        [
+            MontFp!(
+                "22822710544955000730148122268001517778571462019518057920241887331430901815475"
+            ),
+            MontFp!("7550281669449907457572312423331311050665715913809492570664862258696370056188"),
+            MontFp!(
+                "18604120940059936532333724019183356164831306854507207527179068641157368920300"
+            ),
+            MontFp!(
+                "23278104149849056801154306319962946086683183078503011873983396785465567712360"
+            ),
+        ],
+        [
+            MontFp!(
+                "22114478171849390735486716831685023092494125316697366680672652888048887614529"
+            ),
+            MontFp!(
+                "17374325179249172103441245933644550425421517503755835117566807371030586136079"
+            ),
+            MontFp!(
+                "24644294195671543111898054541743469319516176599220475797622322887313133433849"
+            ),
+            MontFp!("8701935106261246239298308916317442373794431252857417694614368258299383809889"),
+        ],
+        [
+            MontFp!(
+                "10322234313019524253818410074323187563346886541766912435096021442699634500276"
+            ),
+            MontFp!(
+                "28188573067633798352989666704335949687451283043234670207243105848240847698329"
+            ),
+            MontFp!("8062216623255206201381878422687800650770619927558195111686226410649854968092"),
+            MontFp!("2013014468165923836569716878267060450204165526719480404511681141709536788888"),
+        ],
+        [
+            MontFp!(
+                "24639399641130596812134822277316706341582322765755260791749650343262734265590"
+            ),
+            MontFp!(
+                "19764820776492343778149729630090524638218930419300105908348437447314966083572"
+            ),
+            MontFp!("4676916054911378989294021297697262650503889986054020176903822250947417013398"),
+            MontFp!(
+                "23659384928542386636443175930657372704704195240262103591565173932583767403034"
+            ),
+        ],
+        [
+            MontFp!(
+                "22153030036847135466652505194897164961141901216559935079328437380102894547917"
+            ),
+            MontFp!(
+                "18064633138002190871165879392270074872616962061826736918761190303865217518935"
+            ),
+            MontFp!(
+                "17263048527490113145582605268833946913018649644548396143851607093956426525422"
+            ),
+            MontFp!(
+                "11154346127158949055627069593703567253059210091704788028026193036299705819789"
+            ),
+        ],
+        [
+            MontFp!("3778271321141210077468095486269620454375443731855875211114836257273462827458"),
+            MontFp!("7953001709567979088636337475072142388392355785814597864914671472339322005406"),
+            MontFp!("2710633852296223800153740384970940744033190364141606524069718232665825894155"),
+            MontFp!(
+                "19560307116966118743505560590488059659369685778998530051901300942457607447813"
+            ),
+        ],
+        [
+            MontFp!(
+                "24897171302154910111332364445560278252874228634492584890994652915230573833022"
+            ),
+            MontFp!("8265909094544542598862837891027317779702107941437085959206437695608941496897"),
+            MontFp!(
+                "12032763365111746603509298945738838520920918184696247095743908082656239304238"
+            ),
+            MontFp!(
+                "21073084944589984563732200454100435032183116978426859863956722202191941812135"
+            ),
+        ],
+        [
+            MontFp!(
+                "17795734698867627178290604273438343826511885907103460155103930805605983117229"
+            ),
+            MontFp!("2217297906636544998920629731980841256120064522945125142939991013508089103194"),
+            MontFp!(
+                "23929003947335219621628028962214514962390207509608036064880983846841490196752"
+            ),
+            MontFp!(
+                "20099121118577576805622202522553528684206360410931700934871889631107329740284"
+            ),
+        ],
+        [
+            MontFp!("5889763207643250146183566746880197529998501013105646483752829738107970998890"),
+            MontFp!("2962551071587069896519641296922881288632617187924926506920919389749605454828"),
+            MontFp!(
+                "15154812666328744671249661731044432405219464017036831914035496227477180808541"
+            ),
+            MontFp!("2229399492302959048991426787801463366060064904894778776830913172971841401710"),
+        ],
+        [
+            MontFp!("9590706232440123271871384731434952043788533470233468936272761665138129672649"),
+            MontFp!("8313816726008973650349750130879435073553145134476813758194537407929569797046"),
+            MontFp!(
+                "15692017003123977880777798729063048293790792716199315036303000683179793169325"
+            ),
+            MontFp!(
+                "12187935259267724225431536028776812534171743249198278763859089549236973449290"
+            ),
+        ],
+        [
+            MontFp!(
+                "18283062903242261204201491454453719280250156737214353795933137590366394601212"
+            ),
+            MontFp!(
+                "16242436709058677908391058043829090175118950357045215652191689348460685263902"
+            ),
+            MontFp!(
+                "10768526087465255956129773860821250005407467451261765349103355122951749154915"
+            ),
+            MontFp!(
+                "12939104329479636805617412503048732401421145493513200091735404901679531361000"
+            ),
+        ],
+        [
+            MontFp!("8564987395553114228233176424681436311667553041616038378239172418402224810660"),
+            MontFp!("611055590017649119086431434969505844301758289351584553703524145665415340879"),
+            MontFp!(
+                "10256256615833499336316731005931137250776719613218673247079555486196889028049"
+            ),
+            MontFp!("7823647592745837064653671698401961962428910054644713613755061389212024685311"),
+        ],
+        [
+            MontFp!(
+                "28162489126979688822128729248567406858776036990689780516539476426429044907790"
+            ),
+            MontFp!(
+                "10425612424063817090945979086128406968153529472977789115276053808853437185237"
+            ),
+            MontFp!(
+                "11249288315166062286392910697370514450353259402547386179047861179768422019573"
+            ),
+            MontFp!(
+                "18404849708600516909060965242033344644346193526717455617392001267268762566248"
+            ),
+        ],
+        [
+            MontFp!(
+                "10655883488815030960637133423477438642119928042153366347968507131383535509530"
+            ),
+            MontFp!("2991638068177895608322802553887665894129016084711368492277895636173936509916"),
+            MontFp!(
+                "14002657834971457591408644541121204675730261860020935892261024488339124659134"
+            ),
+            MontFp!(
+                "17570729629827689923349838060395024886737169079554334055078652878499211278057"
+            ),
+        ],
+        [
+            MontFp!("3344909385810045154895242593796072648302843990339719381585463291609965406853"),
+            MontFp!("8208801265320045376106508666514730997784671386465870887858869521910691276160"),
+            MontFp!("7822221542863851830939148080542515749179203225230933940223080957146473161636"),
+            MontFp!(
+                "18628444059203213708217217674638318417226233651731419129180984525952504604798"
+            ),
+        ],
+        [
+            MontFp!(
+                "28429446973401654531934744373265122102242225291308088348069589506964325367673"
+            ),
+            MontFp!("8915723150204539403212643698220056148549530738587946683185420765336526467366"),
+            MontFp!(
+                "24706393330080589624152945840477542535893079488183496827774634748904506602902"
+            ),
+            MontFp!(
+                "20209671635426280193605728727769927988664231254413201973023999019175172491117"
+            ),
+        ],
+        [
+            MontFp!(
+                "23265491842443639486490751123870112278561235351896076977412985585112207006764"
+            ),
+            MontFp!(
+                "22296994757609815070993036828878833620038373138350742984981491570893449180944"
+            ),
+            MontFp!("7663082253527361071445840146253777455792376147037019624238239838850972971498"),
+            MontFp!("5303993767184196130822450955322872889034828602611140502494650660798126798611"),
+        ],
+        [
+            MontFp!(
+                "28761324594893928644454162382916897571974470453323156665886814710655780293547"
+            ),
+            MontFp!(
+                "13047211732999235637966344041903343833450993381232276197963993831844184536004"
+            ),
+            MontFp!(
+                "21704425981330028719756279630209328394582461127694674838898931179767482297507"
+            ),
+            MontFp!(
+                "20251153791575405798232753787803742859273207265597473036729005808474600507245"
+            ),
+        ],
+        [
+            MontFp!(
+                "25867959697779669743912035902853532351335429037252911361837644553723223245439"
+            ),
+            MontFp!("4082878419210238731207161137061062064476780576300735437567464433399166100983"),
+            MontFp!(
+                "13716624424871920228705511574147466036220785116398032105608879014863104478375"
+            ),
+            MontFp!(
+                "16377911487879338300148333348521854644724940962614132057588419001092408235700"
+            ),
+        ],
+        [
+            MontFp!(
+                "12527716705602647020364645317440375559667579168132566975381182801568707948043"
+            ),
+            MontFp!("7932723849127791292331162318353092752765828470032635248593361089586518574358"),
+            MontFp!(
+                "27980913130278927463937753686397857334437486999221162373152738560335710309050"
+            ),
+            MontFp!(
+                "22801867496927673375968510607861537088873375738161332084366527843291218385705"
+            ),
+        ],
+        [
+            MontFp!(
+                "26148884314029897908100604338522741587298557433177111428473181794181730590413"
+            ),
+            MontFp!(
+                "12938436835993615113594838545473247778552840785157583216641880082477024896671"
+            ),
+            MontFp!("1633818684009178282730810343810628636959288639408994376633178378061698448762"),
+            MontFp!(
+                "27553126667716912651137867610325509337166925359967588477946616093736053967963"
+            ),
+        ],
+        [
+            MontFp!(
+                "14460084342282550232736864438814059607380241416827596889501037936978676132920"
+            ),
+            MontFp!(
+                "20518690098896944622504476188336331078563461804721503097779436662336099615317"
+            ),
+            MontFp!(
+                "17070015952992121244456697882619966969907496216405709660192047374049355102940"
+            ),
+            MontFp!("9623289156929857240367628515661571948820669268934033425369465843536521743294"),
+        ],
+        [
+            MontFp!(
+                "23685560497505331530336383279162007176247548434570910924748952273819228681909"
+            ),
+            MontFp!(
+                "18713197608591716278806730129401621502656207062102839808866449363477508237118"
+            ),
+            MontFp!(
+                "14552500902781090568233469171331583761617714298176271109064323281345934196336"
+            ),
+            MontFp!("527814717050437053213694870833674485730847229900343386395520328586638334327"),
+        ],
+        [
+            MontFp!(
+                "20947635268329642722480892708932749448755363081924415884397269936327750464734"
+            ),
+            MontFp!("8285123563072783876088329508208281063560953063628901703799848693895176845610"),
+            MontFp!(
+                "26903445264182028081385332643306640851053105753925633556779958696227742960363"
+            ),
+            MontFp!("2007878555102656059091271500012392322270518173760007797154836588873579179211"),
+        ],
+        [
+            MontFp!("2850305108558684548383222203064973160567375404787794929893119910859778599556"),
+            MontFp!("6263388888679794331357340842017892077811728340743625002120941605444330896156"),
+            MontFp!("3327335713363930135853657747865484211522743824118249219360776339306735810212"),
+            MontFp!("7163900301343778848772723033297450236080251302114838329743984915077143666924"),
+        ],
+        [
+            MontFp!(
+                "19000819567868496984221448366677430019560069652706770019116700501888775816824"
+            ),
+            MontFp!("3432111685548503233396281689943652751678509058254580370425284220462679868583"),
+            MontFp!(
+                "17530766629442534385862065820866007546300381633911134937286679896547576016613"
+            ),
+            MontFp!(
+                "14762152351033128245577914569549043086697642537000497123286185641094550557785"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "21711016731996786641919559689128982722522292361456235534759807061295022211073"
+            ),
+            MontFp!(
+                "11579208923731619542357098500868790785345222592776658951871897099357345179239"
+            ),
+            MontFp!(
+                "24123351924440874046577288543476647469469213734951372816399785623661135790081"
+            ),
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538084216959852999081921684627"
+            ),
+        ],
+        [
+            MontFp!(
+                "11579208923731619542357098500868790785345222592776658951871897099357345179239"
+            ),
+            MontFp!(
+                "24123351924440874046577288543476647469469213734951372816399785623661135790081"
+            ),
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538084216959852999081921684627"
+            ),
+            MontFp!(
+                "25329519520662917748906152970650479842942674421698941457219774904844192579585"
+            ),
+        ],
+        [
+            MontFp!(
+                "24123351924440874046577288543476647469469213734951372816399785623661135790081"
+            ),
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538084216959852999081921684627"
+            ),
+            MontFp!(
+                "25329519520662917748906152970650479842942674421698941457219774904844192579585"
+            ),
+            MontFp!(
+                "25731575386070265649682441113041757300767161317281464337493104665238544842753"
+            ),
+        ],
+        [
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538084216959852999081921684627"
+            ),
+            MontFp!(
+                "25329519520662917748906152970650479842942674421698941457219774904844192579585"
+            ),
+            MontFp!(
+                "25731575386070265649682441113041757300767161317281464337493104665238544842753"
+            ),
+            MontFp!(
+                "20263615616530334199124922376520383874354139537359153165775819923875354063668"
+            ),
+        ],
+    ],
+};
@@ -0,0 +1,985 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-4 instance of Poseidon over [`Fr`].
+pub const RATE_4_PARAMS: FieldParameters<Fr, 5> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!(
+                "28053628209600539455024823875077143811898473055677439128159318926093352425255"
+            ),
+            MontFp!(
+                "12933532787229425625521515428889317561660520281288236069595153582424117442513"
+            ),
+            MontFp!("6782060172885167536093828184242682416996573567584087284238088228877552618963"),
+            MontFp!(
+                "25469637406451619219080519972131696310498572804448480716002499028381402687193"
+            ),
+            MontFp!("5144958748813857158332097028573585688228027318068576510334981670625415192315"),
+        ],
+        [
+            MontFp!(
+                "22868572633582541277765483192007907067356744251051890188903205483440651331346"
+            ),
+            MontFp!("96625155410229761967755100677328830612341807311791945828550406317746882038"),
+            MontFp!(
+                "11262191648227246139668822329252797430240728659522496151610436472781326690750"
+            ),
+            MontFp!("151256513244375834801338234540220495828501647702593845061257168119161514437"),
+            MontFp!(
+                "16754424560471085722391561563190664546968291920787735840460767328438349721370"
+            ),
+        ],
+        [
+            MontFp!("2617442487094858273686916671005444054664446858057765525770158435631110256653"),
+            MontFp!(
+                "10125103611260218430199625789664306770188817876697320610141860781219364658929"
+            ),
+            MontFp!(
+                "14496189983628889597915280139055020023505550328112892194492141161168870967667"
+            ),
+            MontFp!(
+                "12699221659040393899767428487460674138352499871281496409217647447901076629977"
+            ),
+            MontFp!("5761393314985091338128802398400151416788083096007904086585902618567624282228"),
+        ],
+        [
+            MontFp!(
+                "13747432853546216954750052117716295756200855503076068590914005250305887733805"
+            ),
+            MontFp!("106489534515148202503091574682529043703620482274779781607156732499650745480"),
+            MontFp!("4180282629590186679229021228585401824681582809877445054305509197221857014615"),
+            MontFp!(
+                "17317434271998112749587724653848007253874487024362156050466847145419739534599"
+            ),
+            MontFp!(
+                "18689572328788246003389220640662289086522817800463127798707261415511980772407"
+            ),
+        ],
+        [
+            MontFp!(
+                "27434491638802361741234400775558223263094144987555966008465777803636514358926"
+            ),
+            MontFp!(
+                "12537487003398985501559328641397524579878760422356854165145209160362194907530"
+            ),
+            MontFp!(
+                "25656470272131589020583449455036536164912420293754473675636390479735575315105"
+            ),
+            MontFp!(
+                "16917725672800137300383067699657194843622307841019503734506180483001235268197"
+            ),
+            MontFp!(
+                "11112124024793468750947212128631818295448389315936280329478250872249755199005"
+            ),
+        ],
+        [
+            MontFp!("5495707330402373334352673900795123928928811346406020110560079208947490141547"),
+            MontFp!(
+                "19105921719154588129649986631993142800517379678515888409245685199181495452548"
+            ),
+            MontFp!(
+                "27251670778255766242659000799803454630412787710490340956253708348003455867013"
+            ),
+            MontFp!(
+                "21820407560807877945857440903922815137318011102903886525540306224947127457381"
+            ),
+            MontFp!("6369582362547317491339110289608736625330150392016557485537941706172464867529"),
+        ],
+        [
+            MontFp!(
+                "10877397328884307169960887010402916508727090630616015723560549751613822707416"
+            ),
+            MontFp!(
+                "28750205466490642229302455636914103761825811019311129148960431056065990100418"
+            ),
+            MontFp!("2547738070117995788222079668375466925233623620125895694082527770511876946658"),
+            MontFp!(
+                "23279522909446711397240169079067463325493170833752315727780269297142941254419"
+            ),
+            MontFp!("2978096758639191417952696151971554511548902600174226051234883747606634711265"),
+        ],
+        [
+            MontFp!(
+                "22533122145797803048466245845806337173197247363935574892638853047541720113111"
+            ),
+            MontFp!("377623483162666748051109826769724346744108073997966236335321409002822260445"),
+            MontFp!(
+                "11977020849215510375691996982038282019801556774365275573862238412167664071805"
+            ),
+            MontFp!("700377502549440129171440858937039611713324810214723780059336710265664717115"),
+            MontFp!(
+                "20413481839651737628908267829551760453884467811635532077372996312860769396574"
+            ),
+        ],
+        [
+            MontFp!(
+                "20598491612714564731983954214746662414907336243049825879178540732269636296356"
+            ),
+            MontFp!(
+                "12495115382752124744791355617378868050909233922485302278712039469345650106502"
+            ),
+            MontFp!(
+                "26737682271525850296544244863736214053234408200082810367672540441240093237657"
+            ),
+            MontFp!("1143971434393565046368950195128577790916518703028555209988427778820517526099"),
+            MontFp!(
+                "12029361936474560652321926091731948049560268854675738604622580103941863999928"
+            ),
+        ],
+        [
+            MontFp!("7815707241048795531810831809762579230122256360782657621988885360117657960683"),
+            MontFp!(
+                "28383420069161467945467332744042315739173325650935867639808773639228504995833"
+            ),
+            MontFp!(
+                "22630899807868779685332456508136456329529911762247651289747994955129187608839"
+            ),
+            MontFp!(
+                "15109360849119805207380257626087087597061419683659456600510225810760598580954"
+            ),
+            MontFp!(
+                "14659065681286083904868337352536353019983173371987203439150120028940518819836"
+            ),
+        ],
+        [
+            MontFp!(
+                "27290137782166621961030612255997483168017499399934480930039957246165036847823"
+            ),
+            MontFp!(
+                "10508371886759115030318715041311061002210738785325368451114354969100481839122"
+            ),
+            MontFp!(
+                "25285602105902813106815867724872603088909125325534437989778712307073047145493"
+            ),
+            MontFp!("1793698379441021695243747580224194149241555826416753377748072395224270471955"),
+            MontFp!("4536804029412827064988711506741914967118742341884105910664251985911068937004"),
+        ],
+        [
+            MontFp!("7105735941746345827129987548668161750913015468916375774631465421363320179613"),
+            MontFp!(
+                "12611693397027762695996984258751175129254815758664944320231355850841464917845"
+            ),
+            MontFp!("4741715533459570220393533436096956010827990401264151818823918436032345522807"),
+            MontFp!(
+                "12787959581062047930651018334230635780573152046409908589177150327460096294942"
+            ),
+            MontFp!(
+                "26410880543795784635457453128110063304433838933335338776158832861164420228782"
+            ),
+        ],
+        [
+            MontFp!(
+                "18251889818788223436011610820835580931137952724846800350310731908721001037806"
+            ),
+            MontFp!("4815281876278947534721981413870705463736205147555957209027823456489035456150"),
+            MontFp!(
+                "10534546401891465080608280046098656577636613677222731154671442435624321905762"
+            ),
+            MontFp!("9711599874960831204796257145872321540288373482903216109593895763271318892090"),
+            MontFp!(
+                "20841115713891571482689493904208923703228331060815374490852179072008152227156"
+            ),
+        ],
+        [
+            MontFp!(
+                "25701074353160638127273904377267792904425474307963938963615300155301009088358"
+            ),
+            MontFp!(
+                "17397214212522337615268807969217911625593399050666192128170690004168647596213"
+            ),
+            MontFp!(
+                "23210542389879521079616049029595455152477114289514088196024644930817835257772"
+            ),
+            MontFp!("7538266741553697815105875349402373736824554833586501075519798260355658145010"),
+            MontFp!(
+                "26367341172660674201857813986467886589489946232443133171918721146724849801598"
+            ),
+        ],
+        [
+            MontFp!(
+                "27195236409095133021895514084369602996989496713038638612024645263481481230277"
+            ),
+            MontFp!("7075223444449807896079340042895427035115313369989032354740911246271795319760"),
+            MontFp!(
+                "17543567438116411243849826015727520151497595937870481354816308725560356007730"
+            ),
+            MontFp!(
+                "21569087159163193306189542789090476843802296645181050920057090726040758792289"
+            ),
+            MontFp!(
+                "24184589835082285089291012349906819247961065277682749633289626966586898192020"
+            ),
+        ],
+        [
+            MontFp!(
+                "20385023482637381414500624606997885219503031227631861167812975767581486838029"
+            ),
+            MontFp!(
+                "23597582501536795465604077495146000547960718871429638197843979715414213131470"
+            ),
+            MontFp!(
+                "11482753598251649294061247724075584006856924743670212907682283837537202258611"
+            ),
+            MontFp!(
+                "16326405208825788488190661726343283501384290196712927065528173706550433005383"
+            ),
+            MontFp!(
+                "16537965827533045093084353678193469590273923783628594607833590847798397341163"
+            ),
+        ],
+        [
+            MontFp!(
+                "24512239371247436072259328868519143332832933048502519675338010028774529683512"
+            ),
+            MontFp!(
+                "18041082033273869149780644199757785741048741164657854739879864829034654274463"
+            ),
+            MontFp!(
+                "28296164785388974637538707926866139543843708598325885287463740917059477930459"
+            ),
+            MontFp!("3178321318307414414149076390230121723544287452449255788744225983630769244452"),
+            MontFp!("905438397869601441208291242606521296371760844509476024520837431230917679896"),
+        ],
+        [
+            MontFp!(
+                "24077907093682424409470113804796732678081355995301969953675596205048662169909"
+            ),
+            MontFp!(
+                "20756505576553704308798863822537804228624563352757962797423828507172302233876"
+            ),
+            MontFp!(
+                "11161498588764481878456450544282157875600167128575979070421341879182426006608"
+            ),
+            MontFp!("6209904123751658211734378546537994164646783572652633059094174992556357937181"),
+            MontFp!(
+                "11536717472482747189299073897082242675992288406151722293634689513477413361120"
+            ),
+        ],
+        [
+            MontFp!(
+                "26279193058338579999761300181996378815775347117802807632564019787093215116248"
+            ),
+            MontFp!("8074849009500054987323006194489993107609419782491069209239995347836033300271"),
+            MontFp!("6661066399158344710489791036522625393806523327264110469288253698605229402696"),
+            MontFp!(
+                "27133807558136343528933535355178957606001976623569036831064003700424980324533"
+            ),
+            MontFp!(
+                "28524004864956581277121621210715803810536707697193970691910358387287871773131"
+            ),
+        ],
+        [
+            MontFp!("6192779640678492434038484220876398851626654813979696117802172650032172244174"),
+            MontFp!(
+                "21096125899714644746215032814242294396671526976011364100967731538722056935080"
+            ),
+            MontFp!("1079780905317906382493507180629282199297428133149189679348315453492264431729"),
+            MontFp!(
+                "14571377075277913653836059921179672263180371347709779014983228574809187750717"
+            ),
+            MontFp!(
+                "27503586513036567970942548814396212361976056910694032696407065966472161792159"
+            ),
+        ],
+        [
+            MontFp!(
+                "22590400174964646751209686328726257548925320560521505472869537763952718615476"
+            ),
+            MontFp!(
+                "18293738782128613424558927873680036074961128170763429390526403526473244391892"
+            ),
+            MontFp!(
+                "17662431202457758201520991748582239820718059643079255792785219716133774070485"
+            ),
+            MontFp!(
+                "25293553785893005542998476159064045297687094498875698717251309599317654888160"
+            ),
+            MontFp!("2881141527059285862482084318845951317666869785020536264223845770582581979948"),
+        ],
+        [
+            MontFp!("7412884168848544265635199278911087525558522205026545810684119250323299533261"),
+            MontFp!(
+                "18458925101268808854949473893532908459782678366450610727098638618176971922497"
+            ),
+            MontFp!(
+                "24847217835839231237800913134495423613498916780941810560451137650180422186817"
+            ),
+            MontFp!("5632586001328469827513796084837792499679820114420464694862334942816152728428"),
+            MontFp!(
+                "17384016977074183628941794229797120852453835445818205352110341837063815793899"
+            ),
+        ],
+        [
+            MontFp!("8835540045951910512114848320125223350931171176644307628387279133864938444669"),
+            MontFp!(
+                "18797472527469550914781186718075217674612543152645473974092174532694652908660"
+            ),
+            MontFp!("4485078565724767197345185360281409002354753565585640974810400967518344179551"),
+            MontFp!(
+                "22209380035326252078218883056178203363383224832316868075523541891984941057215"
+            ),
+            MontFp!("5637736829321261069791002192341410737114377672268397980724271290017648625093"),
+        ],
+        [
+            MontFp!("8698865358453017088595647628923821422717736663055893800613193358374418714896"),
+            MontFp!("5461103208071169049457993846853985028495689517108359968761875486668254311566"),
+            MontFp!(
+                "17008964282403035085522066037978332814009331144421513821986989530511592724133"
+            ),
+            MontFp!("1104551997168173000940437322287790162235702218538122814314577346328991230626"),
+            MontFp!(
+                "17776900255844840963991181146122644954003057131140175351281770957985106123553"
+            ),
+        ],
+        [
+            MontFp!(
+                "22307192285146452334139667381920712792751393061261279207060852854470385384853"
+            ),
+            MontFp!(
+                "21776377885575036782865112922323936301734122235173715884140059388004025259589"
+            ),
+            MontFp!(
+                "24649737921244028171491707095403038332523765851041773379086046997742528694666"
+            ),
+            MontFp!(
+                "25378448359999800622709244708822574544078810466694744978875696287435134263739"
+            ),
+            MontFp!("8164106145177074858783861536549047135436628323123658532838757335456431181682"),
+        ],
+        [
+            MontFp!(
+                "17149579170832651625255674255658838255085296058573859370730008188112562061516"
+            ),
+            MontFp!(
+                "17504889239175956245770785510032616963547558724651293881834563972543147007552"
+            ),
+            MontFp!(
+                "10335255988759841887893523469880853850872525703085206534127522169066954688493"
+            ),
+            MontFp!("1307735477164754052675288906310776036397007577208822626814944233538701911479"),
+            MontFp!(
+                "16405648292011674932413076821533648193458679269025836571899703476841170395491"
+            ),
+        ],
+        [
+            MontFp!(
+                "24996670437295779724925779145302203821241170125817569156979529578296270061163"
+            ),
+            MontFp!(
+                "23411027040937548308666554023527453626538173922477304623063468292402081067188"
+            ),
+            MontFp!(
+                "21687018105761710556754980047992751965231079851100551521372073263462967982317"
+            ),
+            MontFp!(
+                "19826705917917230536269754774490499044827040383891450542561971136550720440669"
+            ),
+            MontFp!("6755718676076605691375812811500221144219317095211044789673680489473092101159"),
+        ],
+        [
+            MontFp!(
+                "13299534843806059728100455279692690254535150711942081399354082809736354449672"
+            ),
+            MontFp!(
+                "17347609393706830337079904579836459442883324081141513487686932031080910563536"
+            ),
+            MontFp!(
+                "16170902194391972456183646657422474305551523436508960714236933512447823324477"
+            ),
+            MontFp!(
+                "15163583714591036608857286160660464003654680373588145755943759188365952636970"
+            ),
+            MontFp!(
+                "17974096250689081289005527710830254379354210158787275085019402644056711097035"
+            ),
+        ],
+        [
+            MontFp!(
+                "25972709982663911931621030260211042665440486619338437084483928422558265055186"
+            ),
+            MontFp!("1266345333761184219967500943874980901358695156910741073510941768494225365475"),
+            MontFp!(
+                "11688441937643835133741653990501535037949194935747008883394032759625688234651"
+            ),
+            MontFp!(
+                "17599990283110411852002614159121993057067690450030451816914214199998491726013"
+            ),
+            MontFp!(
+                "17437918266411760552207818186845831676962791407197812967060147916889021729759"
+            ),
+        ],
+        [
+            MontFp!(
+                "22052889581038343240112573194790615062304119736597852214213221481393184207228"
+            ),
+            MontFp!(
+                "12958884409193180183165530255527654440984550908692129402477640464510809365150"
+            ),
+            MontFp!("1561888391585240707865794567055088367953798258391824041452066900635208249506"),
+            MontFp!(
+                "20139294775173853706679163304631558593187803082429548319085255835055237808740"
+            ),
+            MontFp!(
+                "27400728435394375130312042938550537679143210552346269655069185748745659639182"
+            ),
+        ],
+        [
+            MontFp!(
+                "20840071292065919910103064965393792466232079411180121170533877125024901199619"
+            ),
+            MontFp!(
+                "14797710845541101475762210161468303021096081363502436900399839906697746333497"
+            ),
+            MontFp!(
+                "13975355059822013243270055013930389767418406551314878655145766697520276808044"
+            ),
+            MontFp!(
+                "23688037982809855955990750470984828852294343703184707508849206226732220542952"
+            ),
+            MontFp!(
+                "27605512180616469965357019810958092019987689325685161292671935531057162257879"
+            ),
+        ],
+        [
+            MontFp!(
+                "11953154113098847790063373461914801900711330907171748089991085325220486546649"
+            ),
+            MontFp!(
+                "23824528719528316488216733005957966744715699831609287703540948941318474309886"
+            ),
+            MontFp!("1039962421019697126980269667619453704468826198550062770033422011892565861861"),
+            MontFp!(
+                "23241825107929192750425445661206510860010689424609268862809331224076464269326"
+            ),
+            MontFp!(
+                "18023647706515086961871972870221230738227105606108319299183554668466241062830"
+            ),
+        ],
+        [
+            MontFp!(
+                "13527631784966386656566372635527099172569208119785730606530984459729269421772"
+            ),
+            MontFp!("7500787265933989627658890553973917543503633616983209218895183391945379854649"),
+            MontFp!(
+                "26326040505772533394915573133860166213232116018891677470435923082809152556325"
+            ),
+            MontFp!(
+                "23914088625855999208454525480617226867840428596854532882938541802415736719968"
+            ),
+            MontFp!("1886200910848806422707389470009931166133428845213435810853545043763117377955"),
+        ],
+        [
+            MontFp!(
+                "16987142486554236249734196574649464513803061027324374581795151860173705688246"
+            ),
+            MontFp!(
+                "27075315208084393385812320437332269883462651677223898608659591232320270820790"
+            ),
+            MontFp!("8987111306359717912369074908323431721187596806679198576172766644966267096768"),
+            MontFp!("1685559579469503977133008135408345144369078403438848822162871591677082276424"),
+            MontFp!(
+                "28492414986946181319097919153310295682966298185755085180073820700777524647654"
+            ),
+        ],
+        [
+            MontFp!("2441253342356061109879956081468775976739213643549649114351375120965608792639"),
+            MontFp!(
+                "24176164420029286747434018756001048476710047996719648062813942047768170661573"
+            ),
+            MontFp!("8321019348547279912829945982013953496289863060087488885454837728967532344393"),
+            MontFp!(
+                "27151774847504346469867236349825789495611266692799058776891138608318167594690"
+            ),
+            MontFp!(
+                "21220558166763850578539849362142158336219001373606868748253627300031141988865"
+            ),
+        ],
+        [
+            MontFp!(
+                "17546950181915821250702382844007376730347180035548330216068747376784007939727"
+            ),
+            MontFp!(
+                "20306912225419051019249706940439202967682483754897018612749409874982498669462"
+            ),
+            MontFp!("3041561860392634316523236883015044184498171099589151507773499972534712829317"),
+            MontFp!(
+                "27840796118489124357779474823238250758215799105250168522609597940197434248184"
+            ),
+            MontFp!("6431163860251506490401025925572784003687325618700988718563792105035432260581"),
+        ],
+        [
+            MontFp!(
+                "14324873511728736173540267141238933580004689007102476153384230438823449485529"
+            ),
+            MontFp!(
+                "19291548864249038619050837116950254317147305163136199785060332233461996296919"
+            ),
+            MontFp!("3280244515041717661736552866110697082878311489354623226560407683029175402092"),
+            MontFp!(
+                "22070994549742930494062875753165970759304740150514702559389075919236052679917"
+            ),
+            MontFp!("2888875139588559525586645964918853474932385316638649481462180304741217346055"),
+        ],
+        [
+            MontFp!(
+                "12487126387355836804260015341828437258587404807163093084135270554381225174985"
+            ),
+            MontFp!("6069552143380451995192604942643723066207721970843393767369729648643269746050"),
+            MontFp!("4465590380415784203334305566609480725728266830357319609739976360033799120515"),
+            MontFp!(
+                "23635370539352149925833928190484468639794633905333693937567373436613980991044"
+            ),
+            MontFp!(
+                "22065141507723769900935320988291514953843689987379899354118012657144321030207"
+            ),
+        ],
+        [
+            MontFp!(
+                "26339735805250164529932972326195237923680273439134078173956509495342181013119"
+            ),
+            MontFp!(
+                "12018321807931245683946589336681425867573395594585765599671910491197913915340"
+            ),
+            MontFp!(
+                "26458128313964581402223325794997102477595569988453663179917743701898682953295"
+            ),
+            MontFp!(
+                "14051690407496268020248662377702835014059168861849461330363770844131432387692"
+            ),
+            MontFp!(
+                "22742197361346650261450170002865438424322248819649559551542422318703080298482"
+            ),
+        ],
+        [
+            MontFp!(
+                "11799392423153977070800331972415170153949493284300302270792003822171410219168"
+            ),
+            MontFp!(
+                "23430255686211567918208723641159405283594618235620145546856987930412485869097"
+            ),
+            MontFp!(
+                "20423272645519763907288886756899567212720060196271183682600012372549490147651"
+            ),
+            MontFp!("1155319279490263964761930314462588293821032473503395512784987985621538206582"),
+            MontFp!(
+                "22338108194866369503866379597600545778834141003370681936709180029017910126244"
+            ),
+        ],
+        [
+            MontFp!(
+                "20611865466342782573535744990681133286088571947877785510438633825980644553784"
+            ),
+            MontFp!("7800795469307789395843007858921115779660586965867917185574391161620092850062"),
+            MontFp!("2113089861158760216030526051775725846476323723216412995453698519074220788608"),
+            MontFp!(
+                "20275469527496373592171932695961781210542637442785726746364927178627893450749"
+            ),
+            MontFp!("7480807485276625277395332424240771871328278916897846160860865124712449033249"),
+        ],
+        [
+            MontFp!(
+                "19810606029931992481818706694811798493833365962060207193244213910816791778287"
+            ),
+            MontFp!(
+                "25131508103941000450890536025053907258476524684796628428598976567447835463396"
+            ),
+            MontFp!(
+                "11547381912214815410461691360431531219432645858935440651486301211764946725980"
+            ),
+            MontFp!(
+                "25961932047619899518733700128766973608878664238514086796453588708338152940556"
+            ),
+            MontFp!(
+                "10871671080943357460294545286946080075362321481963895894493166563527795169949"
+            ),
+        ],
+        [
+            MontFp!("8264451445483718336197624010376490706757990155033069130657058673942576363866"),
+            MontFp!("1801292670124240200769876512398903954398782663165809561979423372858692412297"),
+            MontFp!(
+                "14084561295059390532953755140902327150715934382222134792030701802010731649039"
+            ),
+            MontFp!(
+                "13860749296045969318428157803651164228517900278967080267648924366855319969955"
+            ),
+            MontFp!(
+                "11655030372816708592632557201158940995806617514347627271054798206546629876809"
+            ),
+        ],
+        [
+            MontFp!(
+                "26589499911316334786815933061358753655308799440901098488576652333457180482670"
+            ),
+            MontFp!(
+                "21164532502875118349950275382054377968659692056608301119484416824177360232588"
+            ),
+            MontFp!("2751333944306587155208733964913075597803769534998575424013768996971398268389"),
+            MontFp!("5350590384973979724779942891298573728022311889175577848088748882622468784538"),
+            MontFp!(
+                "10837315031031203684070687527569032707417869798483835883285901579340915740910"
+            ),
+        ],
+        [
+            MontFp!(
+                "18620037808097651009537098565874704427882747079710451259601434356527204736119"
+            ),
+            MontFp!("3748948697966091800392988218004558296746392020717721990838042357829740096003"),
+            MontFp!(
+                "15575558924011204749848449544008941476675050316238881344748653286719921621000"
+            ),
+            MontFp!(
+                "17264801666648303415740794205327978587056328360874958309375010786821515382242"
+            ),
+            MontFp!("6073127058190893878159743157190275372119625719045994349114701300183329206045"),
+        ],
+        [
+            MontFp!(
+                "24370069344196586928963167414324454052197462436768029641799702526389003449194"
+            ),
+            MontFp!(
+                "13790553699254525356767209295826342226862251481536582987905604351133911596185"
+            ),
+            MontFp!(
+                "25787974723613633853275548019312592116965481167106621358031873064020823737907"
+            ),
+            MontFp!("5461675197031806429952450109141891935535651331387610864071358931939300232553"),
+            MontFp!("5831280093784642263619018068241767945326985155939531992444207080111594867817"),
+        ],
+        [
+            MontFp!("879885790401295528575090073192175586743593587230659417559238644737784887018"),
+            MontFp!(
+                "14899263724501643443534677232324903880982582554991544107457134242609131538875"
+            ),
+            MontFp!(
+                "10515949925409013251278393851058414047881791099649493243064309948185025590961"
+            ),
+            MontFp!(
+                "22063010296434853263683339572304911006259810415057234780358535778134900204442"
+            ),
+            MontFp!("3482662351963730687441070790646437814475328086336679814331913139886756047504"),
+        ],
+        [
+            MontFp!("6522298411985740658045881284347047788753930658591140132050653759265383331315"),
+            MontFp!("2670205296965929581054083414161557835700568192528086631758234339403090715886"),
+            MontFp!("4743599800040797175091998523076028589126070983691025009722421128637697487207"),
+            MontFp!(
+                "17333255751626063072896093508929028246305847984334273393381133351262842790290"
+            ),
+            MontFp!("9403847387769877650496778386190514698236387362518198119682806046325008574884"),
+        ],
+        [
+            MontFp!("3688181130722788968136595523033855116021803712257961705729882876754427381868"),
+            MontFp!(
+                "15664847247071718788260916666838576395477154268228995355768032615415636649744"
+            ),
+            MontFp!(
+                "10952709937512247510416525682629219356977863389085221084955163292815858904898"
+            ),
+            MontFp!(
+                "19246240734806337082626839628510263251669735208464832014364130287657159156095"
+            ),
+            MontFp!(
+                "24848504644256432852535013492367566461480121503654964105776215746532888168343"
+            ),
+        ],
+        [
+            MontFp!("3066185179846308442236325051909561681162521727496416726030540610074453977169"),
+            MontFp!(
+                "28711177639306749999528008634026379664638564372613998434986332952387123153142"
+            ),
+            MontFp!(
+                "22327265314490914444654677100572455628011274175751145360309399416401652802172"
+            ),
+            MontFp!(
+                "11070996545564953358984719532172349280728963779582277612449228683104349545870"
+            ),
+            MontFp!(
+                "14724692453177058061985823357545989024544636993364944862508992537737809341620"
+            ),
+        ],
+        [
+            MontFp!(
+                "13387574071981077250854500960130292350364748995380792705429845265130084112528"
+            ),
+            MontFp!(
+                "14413737658937226867462459510879750236069231158299731653946608705816045838848"
+            ),
+            MontFp!(
+                "13473122075206056432440854027029733725460803701887438430354055408352895882598"
+            ),
+            MontFp!(
+                "25220474078201682780219343587913545073847642475332691291781613420287244095795"
+            ),
+            MontFp!("3839836265953753873248836503309474239868268375103645714307660817700256299156"),
+        ],
+        [
+            MontFp!(
+                "25425740450540222921356411399618506703867566218776720977193319500328949220960"
+            ),
+            MontFp!(
+                "11951806637064640541341986517203840863007267677897182664827484541355771597691"
+            ),
+            MontFp!(
+                "18449699531111216350097634928147268655800202414717988688374465048348778780584"
+            ),
+            MontFp!("2435751741439328286067210019810690966308322527822608399639823172886221682501"),
+            MontFp!(
+                "24788135582973094924434620140756105381738256453217312321837438877324111178027"
+            ),
+        ],
+        [
+            MontFp!(
+                "27057004180338749134525179358793190007673733019712174670132833806935356951088"
+            ),
+            MontFp!("6016514348058116325001586012604795875218281062136370292444225928289086254847"),
+            MontFp!("9314360133007045452261305428153643023889087146481643310539781168556668831152"),
+            MontFp!(
+                "17830768588330132281401102005469446115825612177153342329531587610588205796443"
+            ),
+            MontFp!(
+                "23305488904349965645181916349667582105209131141686403345894772741040910471316"
+            ),
+        ],
+        [
+            MontFp!("2559724177623859728213557070713746635367075966657534373367284268331589963171"),
+            MontFp!(
+                "19892107871108410713120407104906329290784406511812436244420681723449582808413"
+            ),
+            MontFp!(
+                "22244145647186760800614766819672975417678426277551922501549844653838160553812"
+            ),
+            MontFp!("7065392348971875496081392937747197912603488378979722469274735143674927912259"),
+            MontFp!("3572536685158240997068080607578904923975786618320993849472470855040307162142"),
+        ],
+        [
+            MontFp!("7814671266975840908963486737944598234856723750350551757437656025217976318151"),
+            MontFp!("451576848299422819942181864726414662421657920486874691428888757065339838214"),
+            MontFp!(
+                "19542894637154093631340374133010730554598334756904119368229157846900991324573"
+            ),
+            MontFp!(
+                "25318025915747286659600987514244766608004404180276561654919234516819301533404"
+            ),
+            MontFp!("8378382917540772592295290721330798750947422888303009029207759712356561392083"),
+        ],
+        [
+            MontFp!(
+                "16270894924760510903408309591506480411534135001700132313949528564332405642157"
+            ),
+            MontFp!(
+                "13162826838940462452182158950869470097616037252878244262314466452426849449373"
+            ),
+            MontFp!(
+                "14908576234436033677158462163027881231722412639764223133169210961456833685367"
+            ),
+            MontFp!(
+                "26115393435782100476083621304715425364148811088931407313825561686584421158973"
+            ),
+            MontFp!("4282399332044465859325802186769852862202991130554154239036789602233824307420"),
+        ],
+        [
+            MontFp!(
+                "24474617796040506108137312967425474624566458402125446111536321086947366200452"
+            ),
+            MontFp!(
+                "15016680196842603912094210506324533277520501477585158146802833346871463414183"
+            ),
+            MontFp!(
+                "24641710271727949919258556268732494507248258907754349141257228474502099194568"
+            ),
+            MontFp!(
+                "10886812275290947871805811704452421473910147423535121081276242542734861569715"
+            ),
+            MontFp!("8570183902751233048403996868975584521827108485612861165408241793081879156356"),
+        ],
+        [
+            MontFp!("8593997776123558116385436757449997985387616151130198369331845539303451215367"),
+            MontFp!(
+                "17225414133071253184672532796164560230026455443053669156979970971742295191922"
+            ),
+            MontFp!(
+                "23505621759643996086042289202328055800046584165866181638324561430783949121330"
+            ),
+            MontFp!("6857535361055077446127536667359856021915069970307030354724125750457966113030"),
+            MontFp!(
+                "20242462623807295024966921150043417521105619671374486168022395620718501410800"
+            ),
+        ],
+        [
+            MontFp!("5228136700100877005159606550980303658353464924057237097619171719597578029210"),
+            MontFp!("885133694834595101969090299724686484623828821181893725935114500057704038630"),
+            MontFp!("8272807306392013575875572431403171603276997834613612275449338372650967729644"),
+            MontFp!("827045344310471977059794232600221081031336347584483736884744869486414944555"),
+            MontFp!(
+                "21766373400287390452172426458087297962046312815282435639626061342606743974422"
+            ),
+        ],
+        [
+            MontFp!("6311186417055950731354961302502817141960771388109317084222532184027396733029"),
+            MontFp!("5764911758948580251578545775182268904360024735717150068147006090219370137099"),
+            MontFp!("5275658714566735122942404628625720387123755621418008922591877914361927897470"),
+            MontFp!(
+                "12182214304806633994322001270566217295907338443086965237727659002013006976485"
+            ),
+            MontFp!(
+                "19547861386090766129313875483457078677369239596875766067924623777634741074638"
+            ),
+        ],
+        [
+            MontFp!(
+                "12246695487978449039000077693474430087277332479166273851028594478545115174116"
+            ),
+            MontFp!(
+                "28400668695806958008483308237457134398389538995216625078033247072517996240411"
+            ),
+            MontFp!(
+                "21740135177751800540029441401440205062093613734388658030752487840408233630347"
+            ),
+            MontFp!("3419506683165460429633822769490325448943991642305186282205504469540978747881"),
+            MontFp!(
+                "17680241473296110808555711871821693050944559270210649983913486239810614086105"
+            ),
+        ],
+        [
+            MontFp!(
+                "23745631126947988615358645340394195066987380190869610475044233867278724976074"
+            ),
+            MontFp!(
+                "12793259552201836927416570706245291262928450989732747188267553467798355533483"
+            ),
+            MontFp!("8419429234544229237800061668026111896364542492652305785618031879534740325271"),
+            MontFp!(
+                "21474746298567753511805292790106018816505727128193996766802961526104052809357"
+            ),
+            MontFp!(
+                "24084561316568971040928444672816659367649046079852355811939673457244390467197"
+            ),
+        ],
+        [
+            MontFp!(
+                "18829682288186809787127098508172002221237015288124282798881286382454932562684"
+            ),
+            MontFp!(
+                "28008284076543141562885107220989036165587202478673199516396273436583709216189"
+            ),
+            MontFp!(
+                "22094807878499771875648543321985749909254745593578119879499553127234315255529"
+            ),
+            MontFp!(
+                "24080797940659805701003008408545595888973318075763103205830629888515468030124"
+            ),
+            MontFp!(
+                "14529116648688304853244810964847260362828603388802945488059775929066951983359"
+            ),
+        ],
+        [
+            MontFp!(
+                "20411219762995605237848505409525711823382173469734614220976719990082805707785"
+            ),
+            MontFp!(
+                "19491420352647976316066482273328091968188429875598411014043648359696795951898"
+            ),
+            MontFp!(
+                "15410022465484982245452522377150958567495476112785947393488344685152407419518"
+            ),
+            MontFp!("6984893323415286506557554890504601187968605962114976188514465196056692859578"),
+            MontFp!(
+                "20365022994507776308143785181216916268209902265149211865992751052552018085108"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "11579208923731619542357098500868790785345222592776658951871897099357345179239"
+            ),
+            MontFp!(
+                "24123351924440874046577288543476647469469213734951372816399785623661135790081"
+            ),
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538084216959852999081921684627"
+            ),
+            MontFp!(
+                "25329519520662917748906152970650479842942674421698941457219774904844192579585"
+            ),
+            MontFp!(
+                "25731575386070265649682441113041757300767161317281464337493104665238544842753"
+            ),
+        ],
+        [
+            MontFp!(
+                "24123351924440874046577288543476647469469213734951372816399785623661135790081"
+            ),
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538084216959852999081921684627"
+            ),
+            MontFp!(
+                "25329519520662917748906152970650479842942674421698941457219774904844192579585"
+            ),
+            MontFp!(
+                "25731575386070265649682441113041757300767161317281464337493104665238544842753"
+            ),
+            MontFp!(
+                "20263615616530334199124922376520383874354139537359153165775819923875354063668"
+            ),
+        ],
+        [
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538084216959852999081921684627"
+            ),
+            MontFp!(
+                "25329519520662917748906152970650479842942674421698941457219774904844192579585"
+            ),
+            MontFp!(
+                "25731575386070265649682441113041757300767161317281464337493104665238544842753"
+            ),
+            MontFp!(
+                "20263615616530334199124922376520383874354139537359153165775819923875354063668"
+            ),
+            MontFp!(
+                "15789830350543117557759679773911987434561667171968171298007132408214561608053"
+            ),
+        ],
+        [
+            MontFp!(
+                "25329519520662917748906152970650479842942674421698941457219774904844192579585"
+            ),
+            MontFp!(
+                "25731575386070265649682441113041757300767161317281464337493104665238544842753"
+            ),
+            MontFp!(
+                "20263615616530334199124922376520383874354139537359153165775819923875354063668"
+            ),
+            MontFp!(
+                "15789830350543117557759679773911987434561667171968171298007132408214561608053"
+            ),
+            MontFp!(
+                "26535687116884961451235017397824312216416135108446510098039764186027249369089"
+            ),
+        ],
+        [
+            MontFp!(
+                "25731575386070265649682441113041757300767161317281464337493104665238544842753"
+            ),
+            MontFp!(
+                "20263615616530334199124922376520383874354139537359153165775819923875354063668"
+            ),
+            MontFp!(
+                "15789830350543117557759679773911987434561667171968171298007132408214561608053"
+            ),
+            MontFp!(
+                "26535687116884961451235017397824312216416135108446510098039764186027249369089"
+            ),
+            MontFp!(
+                "17814167574971722372857074616721216592838803988887167618264457075934377198829"
+            ),
+        ],
+    ],
+};
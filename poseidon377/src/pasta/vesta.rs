@@ -0,0 +1,102 @@
+//! Poseidon over the scalar field of Vesta.
+//!
+//! This module provides the rate-1 to rate-4 instances of Poseidon over the scalar
+//! field [`Fr`] of Vesta, which is the base field of Pallas, with the same API as the
+//! instances over [`Fq`](crate::Fq), and R1CS gadgets for them with the `r1cs`
+//! feature.
+
+use crate::field::FieldParameters;
+
+#[cfg(feature = "r1cs")]
+pub mod r1cs;
+mod rate_1;
+mod rate_2;
+mod rate_3;
+mod rate_4;
+
+/// The scalar field of Vesta.
+pub use ark_pallas::Fq as Fr;
+
+/// Parameters for the rate-1 instance of Poseidon over [`Fr`].
+pub const RATE_1_PARAMS: FieldParameters<Fr, 2> = rate_1::RATE_1_PARAMS;
+
+/// Parameters for the rate-2 instance of Poseidon over [`Fr`].
+pub const RATE_2_PARAMS: FieldParameters<Fr, 3> = rate_2::RATE_2_PARAMS;
+
+/// Parameters for the rate-3 instance of Poseidon over [`Fr`].
+pub const RATE_3_PARAMS: FieldParameters<Fr, 4> = rate_3::RATE_3_PARAMS;
+
+/// Parameters for the rate-4 instance of Poseidon over [`Fr`].
+pub const RATE_4_PARAMS: FieldParameters<Fr, 5> = rate_4::RATE_4_PARAMS;
+
+/// Hash a single [`Fr`] element with the provided `domain_separator`.
+pub fn hash_1(domain_separator: &Fr, value: Fr) -> Fr {
+    RATE_1_PARAMS.n_to_1_fixed_hash([*domain_separator, value])
+}
+
+/// Hash two [`Fr`] elements with the provided `domain_separator`.
+pub fn hash_2(domain_separator: &Fr, value: (Fr, Fr)) -> Fr {
+    RATE_2_PARAMS.n_to_1_fixed_hash([*domain_separator, value.0, value.1])
+}
+
+/// Hash three [`Fr`] elements with the provided `domain_separator`.
+pub fn hash_3(domain_separator: &Fr, value: (Fr, Fr, Fr)) -> Fr {
+    RATE_3_PARAMS.n_to_1_fixed_hash([*domain_separator, value.0, value.1, value.2])
+}
+
+/// Hash four [`Fr`] elements with the provided `domain_separator`.
+pub fn hash_4(domain_separator: &Fr, value: (Fr, Fr, Fr, Fr)) -> Fr {
+    RATE_4_PARAMS.n_to_1_fixed_hash([*domain_separator, value.0, value.1, value.2, value.3])
+}
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use ark_ff::PrimeField;
+
+    use super::*;
+
+    fn fr(s: &str) -> Fr {
+        Fr::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn vesta_params_are_consistent() {
+        assert_eq!(RATE_1_PARAMS.arc.len(), RATE_1_PARAMS.rounds.total());
+        assert_eq!(RATE_2_PARAMS.arc.len(), RATE_2_PARAMS.rounds.total());
+        assert_eq!(RATE_3_PARAMS.arc.len(), RATE_3_PARAMS.rounds.total());
+        assert_eq!(RATE_4_PARAMS.arc.len(), RATE_4_PARAMS.rounds.total());
+
+        // The MDS matrix is the Cauchy matrix `1 / (i + t + j)`.
+        let mds = RATE_2_PARAMS.mds;
+        for (i, row) in mds.iter().enumerate() {
+            for (j, m) in row.iter().enumerate() {
+                assert_eq!(*m * Fr::from((i + 3 + j) as u64), Fr::from(1u64));
+            }
+        }
+    }
+
+    #[test]
+    fn vesta_hashes() {
+        let domain_sep = Fr::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let [a, b, c, d] = [1u64, 2, 3, 4].map(Fr::from);
+
+        assert_eq!(
+            hash_1(&domain_sep, a),
+            fr("6314580837422957571414138753159725472885860655171465250054152385995159783213")
+        );
+        assert_eq!(
+            hash_2(&domain_sep, (a, b)),
+            fr("12252082888756562469317674794207348450438001611606470800092931508832124823423")
+        );
+        assert_eq!(
+            hash_3(&domain_sep, (a, b, c)),
+            fr("3082761720565530746911832974613139199011030381810169771056746950578121253959")
+        );
+        assert_eq!(
+            hash_4(&domain_sep, (a, b, c, d)),
+            fr("18840419859561807964051763368557081162759718752615559269303619647335854945239")
+        );
+    }
+}
@@ -0,0 +1,93 @@
+//! R1CS gadgets for the instances of Poseidon over the scalar field of Vesta.
+
+use ark_r1cs_std::fields::fp::FpVar;
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+
+use super::{Fr, RATE_1_PARAMS, RATE_2_PARAMS, RATE_3_PARAMS, RATE_4_PARAMS};
+
+pub fn hash_1(
+    _cs: ConstraintSystemRef<Fr>,
+    domain_separator: &FpVar<Fr>,
+    value: FpVar<Fr>,
+) -> Result<FpVar<Fr>, SynthesisError> {
+    RATE_1_PARAMS.n_to_1_fixed_hash_var([domain_separator.clone(), value])
+}
+
+pub fn hash_2(
+    _cs: ConstraintSystemRef<Fr>,
+    domain_separator: &FpVar<Fr>,
+    value: (FpVar<Fr>, FpVar<Fr>),
+) -> Result<FpVar<Fr>, SynthesisError> {
+    RATE_2_PARAMS.n_to_1_fixed_hash_var([domain_separator.clone(), value.0, value.1])
+}
+
+pub fn hash_3(
+    _cs: ConstraintSystemRef<Fr>,
+    domain_separator: &FpVar<Fr>,
+    value: (FpVar<Fr>, FpVar<Fr>, FpVar<Fr>),
+) -> Result<FpVar<Fr>, SynthesisError> {
+    RATE_3_PARAMS.n_to_1_fixed_hash_var([domain_separator.clone(), value.0, value.1, value.2])
+}
+
+pub fn hash_4(
+    _cs: ConstraintSystemRef<Fr>,
+    domain_separator: &FpVar<Fr>,
+    value: (FpVar<Fr>, FpVar<Fr>, FpVar<Fr>, FpVar<Fr>),
+) -> Result<FpVar<Fr>, SynthesisError> {
+    RATE_4_PARAMS.n_to_1_fixed_hash_var([
+        domain_separator.clone(),
+        value.0,
+        value.1,
+        value.2,
+        value.3,
+    ])
+}
+
+#[cfg(test)]
+mod test {
+    use ark_ff::PrimeField;
+    use ark_r1cs_std::{alloc::AllocVar, R1CSVar};
+    use ark_relations::r1cs::ConstraintSystem;
+
+    use super::*;
+    use crate::pasta::vesta;
+
+    #[test]
+    fn vesta_hash_gadgets_match_native() {
+        let domain_separator = Fr::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let [a, b, c, d] = [1u64, 2, 3, 4].map(Fr::from);
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let ds = FpVar::new_constant(cs.clone(), domain_separator).unwrap();
+        let [a_var, b_var, c_var, d_var] =
+            [a, b, c, d].map(|x| FpVar::new_witness(cs.clone(), || Ok(x)).unwrap());
+
+        let output = hash_1(cs.clone(), &ds, a_var.clone()).unwrap();
+        assert_eq!(output.value().unwrap(), vesta::hash_1(&domain_separator, a));
+
+        let output = hash_2(cs.clone(), &ds, (a_var.clone(), b_var.clone())).unwrap();
+        assert_eq!(
+            output.value().unwrap(),
+            vesta::hash_2(&domain_separator, (a, b))
+        );
+
+        let output = hash_3(
+            cs.clone(),
+            &ds,
+            (a_var.clone(), b_var.clone(), c_var.clone()),
+        )
+        .unwrap();
+        assert_eq!(
+            output.value().unwrap(),
+            vesta::hash_3(&domain_separator, (a, b, c))
+        );
+
+        let output = hash_4(cs.clone(), &ds, (a_var, b_var, c_var, d_var)).unwrap();
+        assert_eq!(
+            output.value().unwrap(),
+            vesta::hash_4(&domain_separator, (a, b, c, d))
+        );
+
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
@@ -0,0 +1,454 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-1 instance of Poseidon over [`Fr`].
+pub const RATE_1_PARAMS: FieldParameters<Fr, 2> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!(
+                "10492696468594742196941772386373219328717797116158260902961845027358696139313"
+            ),
+            MontFp!(
+                "18495832039666747818859006213296724076926890525328187110661297909833545001106"
+            ),
+        ],
+        [
+            MontFp!(
+                "15660216102995161432689043241405287948513778425453941522909881588384024336151"
+            ),
+            MontFp!(
+                "21053646797586872369408058490222453006999141392310944676736240666283017212499"
+            ),
+        ],
+        [
+            MontFp!("4982817368809483340094197284872432306834362274941298348170730697012174466777"),
+            MontFp!(
+                "13805429248275949828878240700489348777669547116649515890028931792199986013491"
+            ),
+        ],
+        [
+            MontFp!(
+                "27191408118176693255723636135144678821543787924324449651719642469262950839782"
+            ),
+            MontFp!(
+                "20267168449628393448517181377702219848647646359820826297555128606074834804001"
+            ),
+        ],
+        [
+            MontFp!(
+                "17793733937819232460240730754258652356627287538455625537361685316261399976168"
+            ),
+            MontFp!(
+                "10011441922466540223702420508462357472754246739891282053379631093742544630717"
+            ),
+        ],
+        [
+            MontFp!("4238681231091096122311340938328030829232248132251445727428300173794865698219"),
+            MontFp!(
+                "26798595478741877794694858004011131084645698979979837126864260295990802314480"
+            ),
+        ],
+        [
+            MontFp!(
+                "18112957810945593239168218447153833294641409296128364733504925568086772010923"
+            ),
+            MontFp!(
+                "25132185436346023795429745336909026344780291467189374533071508638618962975544"
+            ),
+        ],
+        [
+            MontFp!(
+                "25475924792408861106192767999003598602714897679436552504118873478391005068040"
+            ),
+            MontFp!(
+                "28872399018073300725491502795962870890105625580895489908311315591136913744078"
+            ),
+        ],
+        [
+            MontFp!("2699932392686882806342487521263480101987440360654851610553998326561635561822"),
+            MontFp!(
+                "22202996907557877538792501005527935850500863875304354444922833668198726293840"
+            ),
+        ],
+        [
+            MontFp!("838931690609742196468785051084907105893602340471110379943997041079427313496"),
+            MontFp!(
+                "21906744340233949256188159508451950692558558507130467711360903665078845947480"
+            ),
+        ],
+        [
+            MontFp!(
+                "25497541757698277633034890893075246860016365355455457046400339266034497343671"
+            ),
+            MontFp!("4079279446196296369610915901375716687765170573092998846315480942070072862931"),
+        ],
+        [
+            MontFp!(
+                "20676458377183574614205384997499399750902543284148040384986344602998381869815"
+            ),
+            MontFp!(
+                "12252478150267902975857724601964267526393483579887320930109188588928633607801"
+            ),
+        ],
+        [
+            MontFp!("8852244224532743509279657735191672714604576539504048328460519947650811049447"),
+            MontFp!("3417652207620102527485244357331343121128497419021132480125943835688255747623"),
+        ],
+        [
+            MontFp!("5015278351095018105941739460577022147333704987856534236879481745387055631614"),
+            MontFp!(
+                "12858859553931247807716850746867857158383934481198745831120139316060184289116"
+            ),
+        ],
+        [
+            MontFp!(
+                "17146071675012964081985627132475531153031288688717001116443871733865537058947"
+            ),
+            MontFp!(
+                "21689899237500830086895239367098179617393280132478801298886218065875007049114"
+            ),
+        ],
+        [
+            MontFp!("4018470413441842742007587090341742403494670780063493963512765408931296361137"),
+            MontFp!(
+                "20129728280175771758601015526253320856788670103332798605910025888068675459609"
+            ),
+        ],
+        [
+            MontFp!(
+                "10151247450216907688238735328068605929088189207893843339828754178187268080387"
+            ),
+            MontFp!(
+                "11367090118688817074073696041209686534497643816651612117604221264373887693412"
+            ),
+        ],
+        [
+            MontFp!(
+                "16719213006762818583304382160203293782832322238092739635627796442613895779812"
+            ),
+            MontFp!(
+                "11603810002364091237857913061694451433794610771523977739691649789568934152098"
+            ),
+        ],
+        [
+            MontFp!(
+                "10181118106267201730830747348147316353138644650522231444905377005865925773903"
+            ),
+            MontFp!("454760546090832287775491355476254448093724633887582971303266926845246886426"),
+        ],
+        [
+            MontFp!(
+                "11042748103369281655159869566483072310790812083354937072567497954400073382039"
+            ),
+            MontFp!("5024626618352298362673159113183954430248271725787348944330830745498348996855"),
+        ],
+        [
+            MontFp!("5234175374270093988172663934199312338720886102224226898083709456172655999653"),
+            MontFp!("8714389542685384401055940905344756082101787603742101699793160960585624825016"),
+        ],
+        [
+            MontFp!("6140653907811831565142434730505084152936290199364333726169952993941399154259"),
+            MontFp!("8017978197107260551178082004057206427686091147693064043448630346085472585637"),
+        ],
+        [
+            MontFp!(
+                "17675195225501642401050209898643810936787445124133567784049615537564063660934"
+            ),
+            MontFp!(
+                "23651894153423656548088449992908917546897214773404323828227446789318243959792"
+            ),
+        ],
+        [
+            MontFp!(
+                "10136834761551505012816026942577386767210607183041991860972266020294535229901"
+            ),
+            MontFp!(
+                "10629033007857965901675210894321518456216545796810363441908259558453876541642"
+            ),
+        ],
+        [
+            MontFp!("3949636959145493462362676968297844888584575285857722114441680689587277997743"),
+            MontFp!("1302459961834906090922579156245404846370897567691795921556319985095594093924"),
+        ],
+        [
+            MontFp!("5718387929205694701623777349165142527743606488211049976108783152999457301746"),
+            MontFp!(
+                "12870920772472635641758754416256018561290899946941859349357585803119123041825"
+            ),
+        ],
+        [
+            MontFp!(
+                "27984432008611830144589919923222445187405177895166263332895520744112983252730"
+            ),
+            MontFp!(
+                "26643888444916813842829485386954376433872127862458939173960555092928353446839"
+            ),
+        ],
+        [
+            MontFp!("4169692775759496994011789911937258517176039257727014149268188704840204591228"),
+            MontFp!("1078015528875657507796568945194737733263969334578289596305799195105782725101"),
+        ],
+        [
+            MontFp!("7379455747224299722144724990761066469439515531082836395842484008196018339648"),
+            MontFp!(
+                "17352105611135930464456137714145912200439197322871906415261733272382064497565"
+            ),
+        ],
+        [
+            MontFp!(
+                "18587403874078381652181531132144012806105918546741707949535250098060387732391"
+            ),
+            MontFp!(
+                "16728599801657454988738409062580877734299540268061349013013557010590776798377"
+            ),
+        ],
+        [
+            MontFp!(
+                "19902745361407495863915753628450170792978345450100753579075081756972471985068"
+            ),
+            MontFp!("5116301187334668462609312939415206621408704900451151122557072935451155099978"),
+        ],
+        [
+            MontFp!(
+                "13408902333944473096416583258352951372092298452658763318128253233202068155123"
+            ),
+            MontFp!(
+                "24441730900061816444177808494951335140677355519957661249003374848381049506071"
+            ),
+        ],
+        [
+            MontFp!("8457902205923978729186012509612634888417941499409665281192408741904466213451"),
+            MontFp!(
+                "12264221408332424875326789919883692197008877813011409397731067233717459756466"
+            ),
+        ],
+        [
+            MontFp!("5495510049789716020704338282017195566426696049717437692461421583107060918690"),
+            MontFp!(
+                "17035915022183105065949375758784225059068327273733291357175678712311739431128"
+            ),
+        ],
+        [
+            MontFp!("1836749632627577033733758365930875706341323858395868689833552961623142494518"),
+            MontFp!("2567878508164438297291373782482897999459247559526591052430520206014483240997"),
+        ],
+        [
+            MontFp!(
+                "17448475960233502462985057377680887354623697488527471059199643603469354025525"
+            ),
+            MontFp!("203736364426400871458793643784717353766803150413515823390344057150050370858"),
+        ],
+        [
+            MontFp!("7786749916983410865960019521419320264502785646855258674547361197961351369760"),
+            MontFp!(
+                "15209893159603824611535327325703675330346354508865964215954663788557506779563"
+            ),
+        ],
+        [
+            MontFp!(
+                "10222885082483595538018795016945101534797729378303346266482688123087580562079"
+            ),
+            MontFp!(
+                "25782247515039719827571847284388463343632027025673657676546228277424203803221"
+            ),
+        ],
+        [
+            MontFp!(
+                "26595451084219988176097019696917023362456146908067807691226011839418217026402"
+            ),
+            MontFp!(
+                "14764293138627777560491878148017435564393036283031222917083084932841304068409"
+            ),
+        ],
+        [
+            MontFp!("7038210969162078888884575228959980050362930995691847807112327851422049022298"),
+            MontFp!("9330172957141884640455634566554765664535350844897586685728794528062627977594"),
+        ],
+        [
+            MontFp!("2498934496045175423025484690476021717879791253087204527499437604816908369313"),
+            MontFp!(
+                "11804886871979961259112989071786963730729021546589166906005950669230645141509"
+            ),
+        ],
+        [
+            MontFp!(
+                "25162057202117020461863333733430739528813282954245814488217825104904123965893"
+            ),
+            MontFp!(
+                "10684961317525420473792312858142530344161174876901089315691195975814583101706"
+            ),
+        ],
+        [
+            MontFp!(
+                "13924667098558019968066719086878115514081213087478863559735225687426326546362"
+            ),
+            MontFp!(
+                "22381025265224928355717000737535002266274543585810779240923306866917711917182"
+            ),
+        ],
+        [
+            MontFp!(
+                "11777582683888455291179318204366061798549840576238377152820168068652982415736"
+            ),
+            MontFp!("3291883912233394744411612830879608592077996387338105387488745118709166199479"),
+        ],
+        [
+            MontFp!(
+                "26591669954969293567652921878202937935189826831841949438865611486704710373812"
+            ),
+            MontFp!(
+                "18989073699463737185223012225342449925194349036337477972899690328374624613214"
+            ),
+        ],
+        [
+            MontFp!(
+                "14923464540023192016446724090195547241839630913754243460158589010123476690603"
+            ),
+            MontFp!("1270211643333826456196763530602877161359269607287854282431872746313109888584"),
+        ],
+        [
+            MontFp!("347698658947042512602184261514110794960619840761690503031235830650111987009"),
+            MontFp!(
+                "28089893141095143957758735187264867640827108249377332061031009025485144797047"
+            ),
+        ],
+        [
+            MontFp!(
+                "22238199302063668055680336641992958672861086608051659571153445379438878591358"
+            ),
+            MontFp!("8700854831256914590504471852076888951204708349011801027823596854476202644645"),
+        ],
+        [
+            MontFp!(
+                "25929848356239275336738113852910339555155485437381630344539940160657442524742"
+            ),
+            MontFp!("5805368561286797193957723569032919872393223902549923635058632102600659496077"),
+        ],
+        [
+            MontFp!(
+                "10652407856330493064826097260996127282317200426201575848409079904470442136875"
+            ),
+            MontFp!("2963076962754839491879575282182129001125488271138547713471483849179995790317"),
+        ],
+        [
+            MontFp!(
+                "26099256109726519494115706021783448893838559635252883950260493667861566816139"
+            ),
+            MontFp!("8254083495215648741774512997633336459116422978692729725423467939304097060459"),
+        ],
+        [
+            MontFp!(
+                "19666711539007487944852375367260685304590728466730077333199950415696876130605"
+            ),
+            MontFp!(
+                "19682730356461597293251615204818383938391123823359547644785568559101754195709"
+            ),
+        ],
+        [
+            MontFp!("9805012229359226561231453989690284473310252249643241460310802784926150400149"),
+            MontFp!("4494483206761819425081313773594017766656994847013736047003401277186461336926"),
+        ],
+        [
+            MontFp!("3544866387269994203663589709898196411870583973211351157128978325618535804936"),
+            MontFp!(
+                "25909676686019067328112577824141060954808320524070414837850624326788941686719"
+            ),
+        ],
+        [
+            MontFp!(
+                "24897914017581968167625796451006593866638288270655579200692247799617554436245"
+            ),
+            MontFp!(
+                "11719783226136105948343454020321112922345444158119129225274927955583589097278"
+            ),
+        ],
+        [
+            MontFp!("9418877397209535447208524840016841767697250756650327779853098410155201582741"),
+            MontFp!(
+                "20660316904493689863954706700567963775755045380816507761930666212807519731828"
+            ),
+        ],
+        [
+            MontFp!(
+                "26332431088284349385804908199229055651954780665709205136519851898757934638431"
+            ),
+            MontFp!(
+                "21447683965179877758742906086774790694409953549353205716773024726879644477270"
+            ),
+        ],
+        [
+            MontFp!(
+                "18673108055257771634345234028468454082521406387329785159179195936282356774962"
+            ),
+            MontFp!("4622247633266180934183359246898429778042863799849154360165111639685098519247"),
+        ],
+        [
+            MontFp!(
+                "26819404414257022315967110805475311830604671951807194224693746905632151099489"
+            ),
+            MontFp!(
+                "23189449201503340661824110644989392776219883657473348973320154167201506556738"
+            ),
+        ],
+        [
+            MontFp!(
+                "15607148900207731387413329602527894392555252163748096444860459214951391590951"
+            ),
+            MontFp!(
+                "10535287387700276940197463729283085502386659144871016197927445111195506064794"
+            ),
+        ],
+        [
+            MontFp!(
+                "10301256269011757221495797983007692668158233982183324468500287203480066173798"
+            ),
+            MontFp!(
+                "28900017114889142219114619797936838390353866544728963025261878670009038688430"
+            ),
+        ],
+        [
+            MontFp!(
+                "19900834459127310864075769622676782092884383671645594674428858822144593609995"
+            ),
+            MontFp!(
+                "20899972023683012870130359817418115083504106468435177572041618625336560092411"
+            ),
+        ],
+        [
+            MontFp!(
+                "23885088075762041726438369539762193417810998418664537815893185661079186847848"
+            ),
+            MontFp!("4331845613806336166567127122489608450335384065326864485742155986280841841739"),
+        ],
+        [
+            MontFp!(
+                "17316047896020171231389980549069962299070753415841021743633693157403305601967"
+            ),
+            MontFp!("9355340815310549931701570141504912888153413381118555734441781732512468879148"),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "14474011154664524427946373126085988481681528240970780357977338382174983815169"
+            ),
+            MontFp!(
+                "19298681539552699237261830834781317975575370987961040477303117842899978420225"
+            ),
+        ],
+        [
+            MontFp!(
+                "19298681539552699237261830834781317975575370987961040477303117842899978420225"
+            ),
+            MontFp!(
+                "21711016731996786641919559689128982722522292361456170536966007573262475722753"
+            ),
+        ],
+    ],
+};
@@ -0,0 +1,613 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-2 instance of Poseidon over [`Fr`].
+pub const RATE_2_PARAMS: FieldParameters<Fr, 3> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!("8835599554393790065491023520967100829483232744879260005184773220711120271443"),
+            MontFp!(
+                "28055739180476734689548303839902921218519589257599789553423277135768870870295"
+            ),
+            MontFp!(
+                "21280298370507437313003078483035169879922039574011116685605564461660144196568"
+            ),
+        ],
+        [
+            MontFp!("6734618621880063693891262496237841546298758619661264202760439810557421171167"),
+            MontFp!(
+                "18436647941791245709442868008827481526259109922625247763531269580971487044074"
+            ),
+            MontFp!(
+                "25894576576381853776814200296638310340705416826264205038685645253063378800237"
+            ),
+        ],
+        [
+            MontFp!(
+                "11302658641406557338266764757874259226860896170886225633084415086991904205538"
+            ),
+            MontFp!(
+                "21726889843043023558461008946408249281434623277148528574484698869310500658112"
+            ),
+            MontFp!("3314478619931188790890501598144944063655348159293119301746156276255324251406"),
+        ],
+        [
+            MontFp!(
+                "17193312930276132771362744691665304885636898055520955807797346433988548828179"
+            ),
+            MontFp!("1258903968862974638029208063527380714818610012567807828636865322993678516277"),
+            MontFp!(
+                "15497751558729426094818633836650138386662692278383937037771232515585349948173"
+            ),
+        ],
+        [
+            MontFp!("2617678121970203662876623008592369908592917132317871878366089075148875439650"),
+            MontFp!(
+                "12362686692817464510897870001283664004852343765127825474975833686729696138035"
+            ),
+            MontFp!(
+                "28150694398543239257413575375277077784183879229531432343647197611643879441991"
+            ),
+        ],
+        [
+            MontFp!(
+                "26332727745279198099839133499703512196837340227436185160456869412499440524457"
+            ),
+            MontFp!("9576608410448373434267377723109699136459728736662045254651879025811147579229"),
+            MontFp!(
+                "15419575036340540258490606612261344622264506749072314999286636408173444462540"
+            ),
+        ],
+        [
+            MontFp!(
+                "10247696655587593440492457187454225770822926858904383291800913373951422860278"
+            ),
+            MontFp!("3443209593776266731872956478971443271603177257140156775379899432095106369933"),
+            MontFp!(
+                "14797232501480752534893641953177768244457804008660187503316853851777059940743"
+            ),
+        ],
+        [
+            MontFp!(
+                "26955819721233565230873976345843720270929331761717298646007630479064306998682"
+            ),
+            MontFp!("3997346465378835859119961166583427325170502894278964035475714535509636962027"),
+            MontFp!("7778987574596619916248943609187493250015126602136487612508006912515115232703"),
+        ],
+        [
+            MontFp!(
+                "15076425168012370029243812285246186913526189318055577792747679646872868099606"
+            ),
+            MontFp!(
+                "15111401290994885668837219329405497101959268710033614586028065338332315236155"
+            ),
+            MontFp!(
+                "28798537812978362944287184241884484516411510000673783516724655563516172256587"
+            ),
+        ],
+        [
+            MontFp!(
+                "27044899601740404117748066750257076137996117318901253913240522339175324299984"
+            ),
+            MontFp!("6907387793761366913548258033772642422619620306446224153149010612176657010535"),
+            MontFp!("6361359390604138508741554992957450811433763445325260216550221263661218892862"),
+        ],
+        [
+            MontFp!(
+                "25276708053485289949697038832604181872093876142729834403339109399064965991213"
+            ),
+            MontFp!(
+                "10612206129771617135140758129824759958591176980429602112903681410716881186234"
+            ),
+            MontFp!("7931459295237580207140111429741631331269586099387361432566481290461536796020"),
+        ],
+        [
+            MontFp!(
+                "11461592183228222745736921025274052137530685677601478247564127677872648375051"
+            ),
+            MontFp!(
+                "20946760254033556769182279662142745615464967859096168931657542966165542849899"
+            ),
+            MontFp!("3020758015856091535244499073633159126899038343772881973481032765945515424854"),
+        ],
+        [
+            MontFp!(
+                "11792066377017554351514041580877035771314639133307896322177142767148106003582"
+            ),
+            MontFp!(
+                "19893818008521110167223972744896322994476529340523777551164060920489181114900"
+            ),
+            MontFp!(
+                "22406962882040662574318914662189474111364562519670531889614660251139549056775"
+            ),
+        ],
+        [
+            MontFp!("6694040365183325306629875815082036531380638660207012223708200209825580772285"),
+            MontFp!("7134814758803346250964902541588011226213569813427067431753065383999183890480"),
+            MontFp!(
+                "14219123517575873875542467089103369326311983773664334108449827209498129160043"
+            ),
+        ],
+        [
+            MontFp!(
+                "25346548436838552056561884305344973200272638513038704929755928708467204050471"
+            ),
+            MontFp!(
+                "24303336242552356786777930663110785990375735641146358421284895775666488488759"
+            ),
+            MontFp!(
+                "27390548562380503753994995141878578689756308491118424475058439223128392420936"
+            ),
+        ],
+        [
+            MontFp!(
+                "23061550273940125322627247250828626859151101203440589170565642667978632850743"
+            ),
+            MontFp!(
+                "13341954313165365568326565121528312154122552320021011104761064092924883291144"
+            ),
+            MontFp!(
+                "13031622979212541358887286173821296070303552385685054353612646041309184214235"
+            ),
+        ],
+        [
+            MontFp!(
+                "21224639055335838433421029914755658864185240859414401660091395135061662206027"
+            ),
+            MontFp!(
+                "27895554987838297173309418777908074227671590271470735974524198266619759556304"
+            ),
+            MontFp!(
+                "22727212749529529190552249358632564832741513780842868416047493149320817546238"
+            ),
+        ],
+        [
+            MontFp!(
+                "17345551843909260446424705348392337928325351403532702378343584270843861311345"
+            ),
+            MontFp!(
+                "12187503796733321992201276143628577769066585385063774363821016435225106096517"
+            ),
+            MontFp!(
+                "16595861597434143350275579005471721323791772736612377632491788716590256460743"
+            ),
+        ],
+        [
+            MontFp!(
+                "18471576248579726827641064974239050878177207437373388689279815688991436823998"
+            ),
+            MontFp!("4591453771471438992895451000909473468050656024063121431592935064987563817927"),
+            MontFp!("6491104447278244045776408689003247264284537620368906042065152028074739701748"),
+        ],
+        [
+            MontFp!(
+                "19605676700968927392632228029609048484396496441519746596709073743915929485148"
+            ),
+            MontFp!(
+                "14439867934026762146646973381008737915634333367491008413229118612516101640240"
+            ),
+            MontFp!(
+                "13131964315066249272850343831964215945012778207805181477413412222399157377805"
+            ),
+        ],
+        [
+            MontFp!("7298147021736863881567530121849975477539443781728307931833444849060104247814"),
+            MontFp!("8660826004492426653297173694177766287039426860243183023455081862975903369332"),
+            MontFp!(
+                "12187545398068371291564347853701036783251100657767380062116410386351192796633"
+            ),
+        ],
+        [
+            MontFp!(
+                "14042872173370612258447922496630768501172888426229127265159202421284372668871"
+            ),
+            MontFp!(
+                "27835517285979029866250032129878214779804718507116191144756287327726370633187"
+            ),
+            MontFp!(
+                "19133222552548383274172734430589876387579306297189686591060580424240826859685"
+            ),
+        ],
+        [
+            MontFp!(
+                "10705922352248440559079288960895789853715223899646289083254586877839694682892"
+            ),
+            MontFp!("7674202771481003596449717308735955518745487635166287801712771450586971563294"),
+            MontFp!("4405176685670148903328009044745352513866257180464308760198243522402150188166"),
+        ],
+        [
+            MontFp!("5629320272431145376781267296917456397812063843528703474521953092733189520984"),
+            MontFp!("9964920177774654882621932972902583840325753234312108149034033663839724983886"),
+            MontFp!("6657595422258485721381678302355958235286784916467955412494077680585045762079"),
+        ],
+        [
+            MontFp!(
+                "12013772941735453074605631821244213172570810105406375088482263625538275277484"
+            ),
+            MontFp!(
+                "23351492570626069424758572104110311174479795276943254172621319524880604445140"
+            ),
+            MontFp!(
+                "18022928407541747389669948036981274392834507766196423897522796983407607635429"
+            ),
+        ],
+        [
+            MontFp!("8793170757398899835376239603698082549270598888715123796102684484551188160062"),
+            MontFp!(
+                "20925286966576815796600290917027358624496920044482245433161351264024201363070"
+            ),
+            MontFp!(
+                "13922493904133970920356113393727764768532632509725544372023078907989653880999"
+            ),
+        ],
+        [
+            MontFp!("9169373883853367425253088927119214019206357985455890127798927833136899294220"),
+            MontFp!("6661676222990208027510888898753617765144758247032300590305361549155021171453"),
+            MontFp!(
+                "25852296255572029667833248030715154462765353393174241354990245403333720869403"
+            ),
+        ],
+        [
+            MontFp!(
+                "13461133729824112257707272023552060958249620039724831918626590395429217296251"
+            ),
+            MontFp!(
+                "21414368367352798530483886752257103681922066918431274441687257906261542443788"
+            ),
+            MontFp!("2243163941379702892053980368711420885852905126716394644409421795631774192968"),
+        ],
+        [
+            MontFp!("1216372828255383890996770426539623964768815187374529153871435618574082829762"),
+            MontFp!(
+                "18494858181166876295283907080636578691641604445279306420302224045581111869830"
+            ),
+            MontFp!("6222407097874468115372591748093930421679863671766958811921487015300948971469"),
+        ],
+        [
+            MontFp!("3955390582500333348293241234699907282729533112251517385529588056311361967243"),
+            MontFp!(
+                "13757763633939422633742261119459789041704929792698743317337121796858865115989"
+            ),
+            MontFp!(
+                "22314867684640690876754229200047530539413910595721781832536159528705362517896"
+            ),
+        ],
+        [
+            MontFp!(
+                "25597983149847978396951969591299756587885490429377593467958828854099820234108"
+            ),
+            MontFp!("2310972368769893352614172521166528854237179671646218984945078473342919825185"),
+            MontFp!("3413522262177950448790087032752669740538092679501511796715433115182968609686"),
+        ],
+        [
+            MontFp!(
+                "14942638959671163115510762524455469714748193562257710352090858942888631414096"
+            ),
+            MontFp!(
+                "11110631349789619650856882114560158793220913908374295170096091834094795714452"
+            ),
+            MontFp!(
+                "12355422030611392949344341613413817478233836619312902445181454737727536874487"
+            ),
+        ],
+        [
+            MontFp!("2691712531599972094872664464034179016541987484404614968662188672391971641023"),
+            MontFp!("8175352078327588097675939544713379147509681466151826881928756247247514240545"),
+            MontFp!(
+                "20167172557286548390955455420842906146146067917851662614351918922418147434973"
+            ),
+        ],
+        [
+            MontFp!(
+                "26430605166914622553617853530389070002159563703220035407975698161576405735417"
+            ),
+            MontFp!(
+                "18079253705042453834546497582748689671025324134080801993758100090885960947856"
+            ),
+            MontFp!(
+                "24402741769185377528724091480481326623660977352083311979782678837768395233645"
+            ),
+        ],
+        [
+            MontFp!("1972277829249136586663104717451264451908983173684962320495361008792609084972"),
+            MontFp!(
+                "28296512242551188410779779472043487965773598467130403045220289897409768468874"
+            ),
+            MontFp!(
+                "22063298629076432079873995021773027167745612015473459424550607696946295229353"
+            ),
+        ],
+        [
+            MontFp!(
+                "22293172206705649255922440752349234012053137779688775876618826476021942168357"
+            ),
+            MontFp!(
+                "28372606116890744856160696433224729274742264042688079722398719311615959572914"
+            ),
+            MontFp!(
+                "12610102164383349687762502413916982943624789426478561655551380502845348728402"
+            ),
+        ],
+        [
+            MontFp!(
+                "13520936102240885642918691165745103495495039291334687321215724787405881460692"
+            ),
+            MontFp!("2836623497305327218823612776398141074019729983792979705932668117038609048605"),
+            MontFp!("543206502432416742007951136413766089755291320146874062302815211312541538213"),
+        ],
+        [
+            MontFp!(
+                "12293005646220075727844301018991360260332260613287959412023711482140863492112"
+            ),
+            MontFp!("5824960958286445911741398079009067626863921270257879547718227110295098000250"),
+            MontFp!(
+                "14783367947814185288922195467206727131246448734702648705296414709003956906871"
+            ),
+        ],
+        [
+            MontFp!(
+                "21981250933231847181753832280440820154534288729757603800392532447475840720280"
+            ),
+            MontFp!(
+                "14635184256724780840184560956464867450297692527187002837169715962820704455848"
+            ),
+            MontFp!("9627498699268174261536792275018531279740331293734817119363915264094726303642"),
+        ],
+        [
+            MontFp!(
+                "27625524380786017932152825335831378393421745712947085969274612351375097456851"
+            ),
+            MontFp!("6162062180275532667829882174027612735650793567166764085848949057159631678745"),
+            MontFp!(
+                "10644297919073625002805846523009766429478685762029772581117478735156256509301"
+            ),
+        ],
+        [
+            MontFp!(
+                "10258712202226116381372415039365532658175847496840227623616243905547597075559"
+            ),
+            MontFp!(
+                "19670915948171117085818677496798673342206480124749347133902510005886304978974"
+            ),
+            MontFp!(
+                "24417305795802961696845862468845958339858538340463516509213937239370512663057"
+            ),
+        ],
+        [
+            MontFp!(
+                "19696581653498613688119579018167522480086080480723970400814834402468343267431"
+            ),
+            MontFp!(
+                "12421190332954889096671321591558232261225757971722574372125334625900182474029"
+            ),
+            MontFp!("7500191939675366045195822433107385685438753936288666509603717184160006259945"),
+        ],
+        [
+            MontFp!("8152282635952614831991985763240702875923115043721134298390372152793388328008"),
+            MontFp!(
+                "10763675791247237195145060946202834047224768612548811271650078548586907741017"
+            ),
+            MontFp!("4356211980132628593743124108942070925233294936139204812046272693776612638823"),
+        ],
+        [
+            MontFp!(
+                "28007917480443265021243917406313402347195665236433433139224022321767343159846"
+            ),
+            MontFp!(
+                "17292745691178938768882859539349626308098719411344134763382023908187605519948"
+            ),
+            MontFp!(
+                "26370134905417698084712215872750815153259155805320225453042161891031291101632"
+            ),
+        ],
+        [
+            MontFp!("4886827234741611587279699891423880528106716349505300226576980027633492829678"),
+            MontFp!(
+                "14234106599938816232772867270395910599349348985161981846204524284111787169397"
+            ),
+            MontFp!(
+                "13898833765949305721339037290157519180841910445453196976163658850073599407318"
+            ),
+        ],
+        [
+            MontFp!("8684542946607839096135375037719461971177106927127085382530187895105203824275"),
+            MontFp!("6128542547876171828511850590244294273085291881559694078966555775625357176387"),
+            MontFp!(
+                "11958726111999475368842296895283458010566278522051827354546094143329965818563"
+            ),
+        ],
+        [
+            MontFp!("7698976943515327747003262163846477562174276126109928993970871001642952772466"),
+            MontFp!("5884754659208562401666208315579366476420203322195270986950317101264179669414"),
+            MontFp!(
+                "18155466331589715473454440935618455415675797947309872911738798322051216380925"
+            ),
+        ],
+        [
+            MontFp!(
+                "21218407475187289794282158068708735466605308650321207039594153558451701840502"
+            ),
+            MontFp!(
+                "16159519439567843023366633186127498373916448324422810348127064786281816173173"
+            ),
+            MontFp!("8933607692220331461360754283339522848612483669143440304728828201878354545682"),
+        ],
+        [
+            MontFp!(
+                "13926599829116906168107540567529159389481824817609615899762444486511604649022"
+            ),
+            MontFp!(
+                "10510556053147778277939034573392203569283327165644364078464724510087550569096"
+            ),
+            MontFp!("112379912902099495593397861436597542012406611891632531216023502033309996288"),
+        ],
+        [
+            MontFp!("2471929155865529439157222423247386686839259980075319773089074732488475206573"),
+            MontFp!("5786194770768103677969269275256935486884139420441822696133417886724047989115"),
+            MontFp!(
+                "10421840415775801899501816115505065465378499594539979277572273032719919123169"
+            ),
+        ],
+        [
+            MontFp!(
+                "28091269504053820384668056905635890962129299879147235587134985229915473407334"
+            ),
+            MontFp!(
+                "23081297719727796586049437897289114885789775652799451337509903750613303523308"
+            ),
+            MontFp!("7448843799774061989673350542902799597774858899625475107938680011335257457436"),
+        ],
+        [
+            MontFp!(
+                "21213919431880317314325308539214565169078170225623079028689758772479768227154"
+            ),
+            MontFp!(
+                "11174026461551848610626296401743725803773405282455127998130835998212010282308"
+            ),
+            MontFp!(
+                "27886999279777202871932961763248293326903486058494802251843271998805275017529"
+            ),
+        ],
+        [
+            MontFp!(
+                "11912140324549818665807110302764802363592655656872008734137270586015770095969"
+            ),
+            MontFp!(
+                "14543918083709670023805323331689992401902751648433135621848220279832832620985"
+            ),
+            MontFp!(
+                "22396313072373889851826361947492761906415959580402897594222228639122465018444"
+            ),
+        ],
+        [
+            MontFp!(
+                "21683841192652353035672470710422386497823080074873484604242372964316987440115"
+            ),
+            MontFp!("3224701496163325283101934902232964597167139980919395089789037515774181832196"),
+            MontFp!("129319477271174988439892955375181952283854400137772905202311042560631824867"),
+        ],
+        [
+            MontFp!(
+                "26508851967971561480743207322531428938499315365144075820988585716271955444223"
+            ),
+            MontFp!("3599294333658382133098360805564279364761775568684873319889762318306020136210"),
+            MontFp!("91945762006546255068028448368708840962938730644435043636711628994865306314"),
+        ],
+        [
+            MontFp!("7513653795027287885908455378880022798678980563121428413750211710613751573163"),
+            MontFp!("8220094825102177691965339894547939395607655291752360333524248622572368753905"),
+            MontFp!(
+                "26981978277710003829004639834570316253047989261627744598046289690066232674220"
+            ),
+        ],
+        [
+            MontFp!("4054962269839637511965577549633084071926758258348167659542668987904292402627"),
+            MontFp!(
+                "28712245983517022025798204972202649582922477182550883879328905442199997290541"
+            ),
+            MontFp!(
+                "10440472608844449110294543409044830952066068393947890747705327758592381374436"
+            ),
+        ],
+        [
+            MontFp!("4292505623894987395245491155127076856247266615474816189626578558561959325705"),
+            MontFp!("463955052058423981066982346454617269837285792069611917651056268371370977234"),
+            MontFp!(
+                "25433966998540721142715269225870094362260982435011027685609368311369077354561"
+            ),
+        ],
+        [
+            MontFp!("8173621983699810030936126564217824801535483470456183859141180366345340283390"),
+            MontFp!("4418547956122155100973729950752093960322399743840780504312991757968528815392"),
+            MontFp!(
+                "20537674705862279050500459055415634077258182237679502864470072550529439392596"
+            ),
+        ],
+        [
+            MontFp!(
+                "21705526521092459518631877265310236750747826567764996321758977744701617539897"
+            ),
+            MontFp!("3188691759283418784402959232274968149760408876106804797978740569530916292360"),
+            MontFp!("1921875114325744869794210268602776016951500677967146113611062565745904936477"),
+        ],
+        [
+            MontFp!(
+                "25988581907939753786465009379537624027774714976063757520468752053689541389341"
+            ),
+            MontFp!("1320223183762250577256010054820279228019622636609321250101994131915753952771"),
+            MontFp!(
+                "28781248688139495258345978741454307496522028075929612966885273475961181005473"
+            ),
+        ],
+        [
+            MontFp!(
+                "23751773962105518624942118003567104470648001219919289083643254246534929430539"
+            ),
+            MontFp!(
+                "11677397846305969481559361178933395942382569782281640643641623596914295424841"
+            ),
+            MontFp!("8836306763445958557710497780596512976910534413056457570784719106326066785945"),
+        ],
+        [
+            MontFp!(
+                "10540847396313674980564034424957828064159710530936894048541264102394799078704"
+            ),
+            MontFp!("9605730020697499359729460784665437774230270776705781718704483126399554227195"),
+            MontFp!(
+                "23860066738468188974783925430548662334989789719701196250701442370362991210131"
+            ),
+        ],
+        [
+            MontFp!(
+                "17290335014947881436331178267547828769329488051627872945851667579919734440697"
+            ),
+            MontFp!(
+                "19583132152181778257214449486185248097250551688671585128945686151016136519569"
+            ),
+            MontFp!(
+                "12453227275473548315727944692920133705912914267592157481879314622003057184833"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "19298681539552699237261830834781317975575370987961040477303117842899978420225"
+            ),
+            MontFp!(
+                "21711016731996786641919559689128982722522292361456170536966007573262475722753"
+            ),
+            MontFp!(
+                "11579208923731619542357098500868790785345222592776624286381870705739987052135"
+            ),
+        ],
+        [
+            MontFp!(
+                "21711016731996786641919559689128982722522292361456170536966007573262475722753"
+            ),
+            MontFp!(
+                "11579208923731619542357098500868790785345222592776624286381870705739987052135"
+            ),
+            MontFp!(
+                "24123351924440874046577288543476647469469213734951300596628897303624973025281"
+            ),
+        ],
+        [
+            MontFp!(
+                "11579208923731619542357098500868790785345222592776624286381870705739987052135"
+            ),
+            MontFp!(
+                "24123351924440874046577288543476647469469213734951300596628897303624973025281"
+            ),
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538034694831243865342838645907"
+            ),
+        ],
+    ],
+};
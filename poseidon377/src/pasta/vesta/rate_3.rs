@@ -0,0 +1,770 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-3 instance of Poseidon over [`Fr`].
+pub const RATE_3_PARAMS: FieldParameters<Fr, 4> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!(
+                "23387966941558121342577958164017417489156982068958531934487121901012983506976"
+            ),
+            MontFp!(
+                "20063055808907959483331053715498132952724417192725139956453991853848556594915"
+            ),
+            MontFp!("4441518890159700359428350619197283323769149684728073036996204023368848320000"),
+            MontFp!(
+                "10075969555168253590211771901572935324080110752220943685023200211842712373653"
+            ),
+        ],
+        [
+            MontFp!(
+                "11694351942745523123045556347800827786158246518797431290033551467570384434867"
+            ),
+            MontFp!("2967785245514790911289615146961681646768149200758071299151657173223115183677"),
+            MontFp!(
+                "18384191336432158306405088800884392117871859422914971220404146287895923832945"
+            ),
+            MontFp!(
+                "21303117136871137606595701405533213725853628418079738442506685201669404322109"
+            ),
+        ],
+        [
+            MontFp!(
+                "26739190037174882700563651377210076724317874134405113435756371001721677729153"
+            ),
+            MontFp!(
+                "26335131050604524333327533607252587522566678224730251360153135800426143266648"
+            ),
+            MontFp!("9693761711739007730035137517133365880595424569170221805156874245087009556477"),
+            MontFp!("7945382573870404462920585865320105449253441255704704126437992409177944182476"),
+        ],
+        [
+            MontFp!(
+                "26357619361403460137912708096114128047568084830496294641285025142761883083559"
+            ),
+            MontFp!("5547399166994067377217026171711990890324717002457708610318142488029140027924"),
+            MontFp!("4511705578424433029021486455232275963950279730883338868911731353656336664127"),
+            MontFp!(
+                "24057910572559998142027061214321183295982261094990263278335572857249814782066"
+            ),
+        ],
+        [
+            MontFp!("3259270959141456107139285061085858230375772537611638076525961518060078420996"),
+            MontFp!(
+                "24322461427956361907158061629489530142815923936472164306206827663227650878137"
+            ),
+            MontFp!(
+                "21183661321017740903083475893831472982549506369190260231870287121934562861667"
+            ),
+            MontFp!(
+                "17254512334383003916138179870306125873316927614663992304786552265443622484347"
+            ),
+        ],
+        [
+            MontFp!(
+                "13713313358002427446772790457805877471334602141642094121677871169584485482270"
+            ),
+            MontFp!("6799811419368986639301002431388637938119963667169929122921300688422020868699"),
+            MontFp!("1354549369571000288270641983175388258273428740933630130048888211198518390069"),
+            MontFp!("2620700407059308204322360110077511719482304639578997933512662081296672398466"),
+        ],
+        [
+            MontFp!(
+                "24106127498657519896438720290585625976598326642541333908947720739391027385576"
+            ),
+            MontFp!(
+                "25528630457639216224739650920601581725068166000912638147171578652199747964891"
+            ),
+            MontFp!(
+                "10542335526948851361672569124015588531798278278515789603211805060501106241219"
+            ),
+            MontFp!(
+                "12807597476136443183519280786752454225690527528040210525985936116793057530209"
+            ),
+        ],
+        [
+            MontFp!(
+                "18807526470678008961270291136356668425576087609371750832534957743024307191924"
+            ),
+            MontFp!("9504463608168796809781147868333453067743127129211050072488804240208347432668"),
+            MontFp!(
+                "20108540482983272714157976354355146787525068892459610667033263174876864625692"
+            ),
+            MontFp!("7015691474866977765362222780175619183879750875440591724031981155830375276156"),
+        ],
+        [
+            MontFp!(
+                "14370279717436224428736332701756915741927203597094838219108815198760254198000"
+            ),
+            MontFp!("5903145056147685097314462653124578163509880621304295687958116630236292270885"),
+            MontFp!("3025076200470808889421805925066333873566324813404844230437335624477787721199"),
+            MontFp!(
+                "15444713221527756661819151299646125445314124211110180335448522619941136024343"
+            ),
+        ],
+        [
+            MontFp!("2921258912207201010997700465599009891908502642507564498786815291909357895618"),
+            MontFp!(
+                "18527942716988965413359083286005526344596161470999236646265699883514041141896"
+            ),
+            MontFp!("1543944060234509314980484581161034568384383087239777466581745258239391920679"),
+            MontFp!(
+                "23494492178655223738625510298789535974365423505517470852015971370925846825395"
+            ),
+        ],
+        [
+            MontFp!(
+                "26722974096765291706909881422856099890343801869102502352681827859312669275331"
+            ),
+            MontFp!(
+                "13027298389464190057687581173666968627559334935156816698878290126492704794354"
+            ),
+            MontFp!(
+                "26791646593335118750030262859004969451133947264225333702584727363922151362899"
+            ),
+            MontFp!(
+                "24143226198843778882855320828596042279055963999386665289476267061811130152388"
+            ),
+        ],
+        [
+            MontFp!(
+                "15228104715471988276759821851761677491961983263448785742105334907042229311915"
+            ),
+            MontFp!(
+                "27382068059850607391641707919699543776311651494064448315779264733576892047442"
+            ),
+            MontFp!(
+                "18774014944659259988496162879009323115043473161538913907412013520650014940117"
+            ),
+            MontFp!(
+                "10836467679189798073176946714165907965125819598948323920017910711844591055497"
+            ),
+        ],
+        [
+            MontFp!(
+                "21206232899697636693599084982108216598911739372999807904956247693963598422878"
+            ),
+            MontFp!(
+                "28328776883758292000995755153823402524981275572857218724038915959133945499998"
+            ),
+            MontFp!(
+                "25209065623790371665971912488657598397656262990877477397874919701822454295423"
+            ),
+            MontFp!("7008902708310208133191873461170210257220452474699359468447209088422657215251"),
+        ],
+        [
+            MontFp!("5130746949421360204929495199196099781760772818576348399646780126582260695078"),
+            MontFp!(
+                "11146807046982994117150897954254150422892361401069986416506435521278670075768"
+            ),
+            MontFp!(
+                "27048962479081068119616671778633524015610241439136904118677596738531012117198"
+            ),
+            MontFp!("1586249002144047054049279780302095505554278841439653357609580196324822670172"),
+        ],
+        [
+            MontFp!(
+                "21469353144478995781707333538906111354375383279681144773020826941049019621128"
+            ),
+            MontFp!("637380531365307806494569995310460462073986498048560279613698929641935763332"),
+            MontFp!("3316443812243647452589438301969481708702182453028257177224639691081764670197"),
+            MontFp!(
+                "26236358613283719100959133784957813587913399540312263065086247942150329260812"
+            ),
+        ],
+        [
+            MontFp!(
+                "26928037069748309263599236848017408263467551125869772736989022753147706431976"
+            ),
+            MontFp!(
+                "10671733574635976963329811475998612037815339617803638206172086752710463815709"
+            ),
+            MontFp!(
+                "15221400043292634972246099697929942697108409390484079736053253654243687775953"
+            ),
+            MontFp!("3164564691751201367981641035232974318855889796961651360602089277768302987274"),
+        ],
+        [
+            MontFp!(
+                "21025181875927362436682441642352691368428713201596532612684624648695874647515"
+            ),
+            MontFp!(
+                "25477177164695844931639936794865039400891260804679138046784461368706558927984"
+            ),
+            MontFp!(
+                "10081973479532298401797235547888484947800728531578274591036235470389041071869"
+            ),
+            MontFp!(
+                "28144558973409170631273647938140300384281103640924201800649451440677985471901"
+            ),
+        ],
+        [
+            MontFp!(
+                "12975403268592550269019010019530653912932291767973730220612136785509859270619"
+            ),
+            MontFp!(
+                "22312539787389608206628670114150825258248666299285645680471239624563261465747"
+            ),
+            MontFp!(
+                "28349302293089136063494760723341564952130120168897757055332028955501517719185"
+            ),
+            MontFp!(
+                "11165816155641963251789538509210688175754004454384393043173901834437562797407"
+            ),
+        ],
+        [
+            MontFp!(
+                "16708577970235704261019145698211756791263797726505561276345974084525265773906"
+            ),
+            MontFp!(
+                "15485170515235531394102985322220322727854253370477882375309918081206707163193"
+            ),
+            MontFp!("8743441397044375806022443107883483409861210258948256456626680046091639272312"),
+            MontFp!(
+                "11808461991541431314850161890907051537994196256003609254004973525195227947378"
+            ),
+        ],
+        [
+            MontFp!(
+                "19222485750090223569305009184294281243673183220442993671151416545153967961162"
+            ),
+            MontFp!("8276323302732026475103928060368525283046474203249834956205949016415701684394"),
+            MontFp!("7830503660043925547575536775204152282732162928980568478406044190547668005474"),
+            MontFp!(
+                "24940302697289157724557652079544002564298004150047233195618551579045557330340"
+            ),
+        ],
+        [
+            MontFp!(
+                "15016805356141848739040352348226949419282651934536894490448718933525584913981"
+            ),
+            MontFp!(
+                "20225075521818192848093076996240134184293493004016541561206838729802684958322"
+            ),
+            MontFp!("1628971508277787617802122623221988531331085772849472193789156002503749535458"),
+            MontFp!("35468418631817957366028347851140770092894179594184661664957661312675322315"),
+        ],
+        [
+            MontFp!(
+                "12929922299442317571178811801488972686245339207342558349619983965070648109116"
+            ),
+            MontFp!(
+                "25776386595752200957730336951893959209257281711880237584087761911550005939617"
+            ),
+            MontFp!(
+                "10165865502848212695306299196745387749175318453579486230761886755056778996743"
+            ),
+            MontFp!(
+                "10544965825550663675186677802541201065558705618008756929715863703060287083504"
+            ),
+        ],
+        [
+            MontFp!("3664576834577361149033888172945851440390972900864366955812786555262789637983"),
+            MontFp!(
+                "11073128837954896788575660572076562943895091696451084889000112729516706273905"
+            ),
+            MontFp!(
+                "10147932676391356598519752934167433758785286038052941844176172085068236540710"
+            ),
+            MontFp!("9869613150724553971603636686196753162142214465869575742229524521522715221018"),
+        ],
+        [
+            MontFp!(
+                "15953917249283147972975120626258549203778451347662617088983691743051778450442"
+            ),
+            MontFp!("7767649346079598490888582818809465155182582905293452878112070147718005880842"),
+            MontFp!(
+                "20855794281228734141337613712457725243925468752882536027910728045796760543196"
+            ),
+            MontFp!(
+                "17752546950747013622367866954112369307438111869978115069423293740968658818136"
+            ),
+        ],
+        [
+            MontFp!(
+                "24208264431574188871971457864908119391334906307778994436667213759420349111309"
+            ),
+            MontFp!(
+                "15654298012260579194093974650410660200154510611741125337850014126841968415186"
+            ),
+            MontFp!("7572756669692364349587943828162354446621134395909036093177513147716840006193"),
+            MontFp!("8322709805945478168661300695669429054592351671099629090329576652590411191801"),
+        ],
+        [
+            MontFp!(
+                "22881886559978179107648665631289997847974822401940602667678704889063835091590"
+            ),
+            MontFp!("3891003809137758598175422632018370014177307379976439413836728752441242646716"),
+            MontFp!(
+                "28936251633729042825117086679638483088181062530408409077786676956761253908421"
+            ),
+            MontFp!(
+                "25547329463297244721268957349938545907713602854223907833449303494966147622940"
+            ),
+        ],
+        [
+            MontFp!("3074110820526332187754004127856944923727184635590384602961980220590980364744"),
+            MontFp!(
+                "27771066089468927776520298163975302915982397616657959108840187714216885375547"
+            ),
+            MontFp!("1126237457412902444113238324880776027910729374448871591236039771315481489755"),
+            MontFp!(
+                "27488700923919836703521089477066921456234992469644804509446565720252513029576"
+            ),
+        ],
+        [
+            MontFp!(
+                "21941594430510202530165129117320589231085830751111410505077628626545278689293"
+            ),
+            MontFp!(
+                "27420391936407705214347224106465129875139480033838449209634807255069243093025"
+            ),
+            MontFp!(
+                "10641851984934143439597511492618136975596558049794627934153222040082923932828"
+            ),
+            MontFp!(
+                "28257324122875289747231137990115293233033507905958981713842695582862268461883"
+            ),
+        ],
+        [
+            MontFp!(
+                "10935410823236600737084278020892983326495617114031957531371659654058467686029"
+            ),
+            MontFp!("6121699233573358574828378306537363092934412587254353656780790481967372959227"),
+            MontFp!(
+                "14885028048266813412572280900363279284181442361656317718874541479400512366504"
+            ),
+            MontFp!("6038092557302473365253653829831725014116590905411767395032536197276669718343"),
+        ],
+        [
+            MontFp!(
+                "14405783760303425541613044459821807581306240112680181793031719817546959407371"
+            ),
+            MontFp!("3756737525118532609663704179624916376285845674600162662084127396366556309480"),
+            MontFp!(
+                "13248800125805394922615306422158035268323590542302918940141505500392047675695"
+            ),
+            MontFp!(
+                "13876862623408630054814767931174377848987078911081750215299314410627904930567"
+            ),
+        ],
+        [
+            MontFp!(
+                "21772447958115707978753741541580312997317319768571861116135544864523887842489"
+            ),
+            MontFp!(
+                "16945879429113688677636687395512532743616689558730971570151157310897651486093"
+            ),
+            MontFp!(
+                "28210792068136595084284220744689458457527236621690286308445442515154401918283"
+            ),
+            MontFp!("8384981966680696832744418147754334008181047511700834699134294122280474524242"),
+        ],
+        [
+            MontFp!(
+                "19665613965176157176512512694663598900638257603766566821686388945531518978239"
+            ),
+            MontFp!("5583770156756072527936460538506643011673218887779455680909084304166692286129"),
+            MontFp!(
+                "24464126365673512925307333188219159170901742727227022222451169127929803479431"
+            ),
+            MontFp!(
+                "16274444870095653831428633896539863260846278345448633375090427060577432562330"
+            ),
+        ],
+        [
+            MontFp!(
+                "19924595365337390434276270395692065980847417564113365310193752591621188428900"
+            ),
+            MontFp!(
+                "12583340465248939413428022613787038739602731816219014041423310666054050042649"
+            ),
+            MontFp!("7121911744553856691452027575304902038040962618127501974069283022462985920909"),
+            MontFp!("4144079302233441927808062392585377849787705270439450240281023163986198081627"),
+        ],
+        [
+            MontFp!("941730231585852501455707623757564656655880516550256662872101807536241117282"),
+            MontFp!("9199159266765438160709303346557831318255016079265348167887702952090882779275"),
+            MontFp!("3210846107847258053821748611419566381121875626990853491055834733772698571234"),
+            MontFp!(
+                "15239782944124016162955820070562159781270156694521819323637864193106183995223"
+            ),
+        ],
+        [
+            MontFp!(
+                "16673717627582065630563685585980050633547074141973815102001542915337028953191"
+            ),
+            MontFp!(
+                "16510687520799509978415718753676895166414799396846951829959169631948531557638"
+            ),
+            MontFp!(
+                "21572930805298146182162185868111768976581892444049270213463663128900748733503"
+            ),
+            MontFp!(
+                "28834310798137444687365328143344195337786083354842227995501813049702412890187"
+            ),
+        ],
+        [
+            MontFp!("1108019429538937762200021061748718542977118863828119143731436618844853755889"),
+            MontFp!(
+                "24823574611900722604761002784053622970004650769953200605336226926490720762456"
+            ),
+            MontFp!(
+                "19818388462141553572478122488263876733524216645436823513630733645966019077864"
+            ),
+            MontFp!("7739186651019775540303160360051556721046970451471389062493763493280944911790"),
+        ],
+        [
+            MontFp!(
+                "25093053802418815198033881121946061756437654545365803850668712274504608244807"
+            ),
+            MontFp!(
+                "25857710127246293604665796898357619858901481682140950509668073282817085013550"
+            ),
+            MontFp!(
+                "28310459918746552093110821200674413218133270088503534492874604336511136956979"
+            ),
+            MontFp!(
+                "20835296223883299593396454275446481961822272018380205876457365358617040748208"
+            ),
+        ],
+        [
+            MontFp!("2049158003965119778907173378852605939507026277612277946180001784740969530351"),
+            MontFp!("4936256541453833583667372873939579806481294099593624525473744212245549933197"),
+            MontFp!(
+                "28036495168375354453505783698248549579374545095850437270768077429377903953925"
+            ),
+            MontFp!("8233470825917721119251484044711348840793154054771939690196633095594488705718"),
+        ],
+        [
+            MontFp!("1230353263400420903266825929404286269705722622759117433086490414433974223381"),
+            MontFp!(
+                "27929668328354930108786143397963035435925661967534084295716516255545745611402"
+            ),
+            MontFp!("5704668042735783434783005786043041402275404606903789120737610752466089055797"),
+            MontFp!("1343730108775523792703887882831563545853314619226967768647005269935114474255"),
+        ],
+        [
+            MontFp!(
+                "16318988817831377570562396132887557034055295188386303955282294119736002677994"
+            ),
+            MontFp!(
+                "10089269525568807533226562018674883896560002665312965943049581465289217945194"
+            ),
+            MontFp!(
+                "12426116072876231929338506465930079139305161463329699116755485356476915516667"
+            ),
+            MontFp!("5668669608086116133888806994198838644301493939225815311473236426379826514150"),
+        ],
+        [
+            MontFp!("1748901323485788202249727272866139846230752321983395608237158074929363368575"),
+            MontFp!("1606015184311966077573396731770707657682125045588724690904926758779269509268"),
+            MontFp!(
+                "14422665841907983074964721612748176749926806645256620702958854852297275964336"
+            ),
+            MontFp!(
+                "17684020093598267782025903537507320499681947223903796812490781913008791208091"
+            ),
+        ],
+        [
+            MontFp!(
+                "22172604152867184407170321551603923694083544082674753692162380864521566464104"
+            ),
+            MontFp!(
+                "20322404700710671571885618065640741901202105118128786178125315137526956596996"
+            ),
+            MontFp!("8156873019028694310799572118262919050100311838554378276046739894438064212665"),
+            MontFp!(
+                "20601647368906913378256622413067042827889513092175188356909388489647338018207"
+            ),
+        ],
+        [
+            MontFp!("2833001734813016978923237088425214355096368871969329111838547609832349914839"),
+            MontFp!("6509080799872832864913947615599392533930185130383134326414504150198317908406"),
+            MontFp!(
+                "28882612909071784172686588369314349528832179126007834165058926985838573838610"
+            ),
+            MontFp!(
+                "13265444540566857516307036803889085115807557510120378754099445383294513921403"
+            ),
+        ],
+        [
+            MontFp!("4192303059626885919376993289676769779766822001184919933702929933055490309028"),
+            MontFp!(
+                "18694366352855104927934121099065558522933888550925185009522895357481489409378"
+            ),
+            MontFp!("5437025683855327382827662918440602494646126272259388009071408105806333851893"),
+            MontFp!("3839172952811932623111393562808066569884094219587526854683482621459176091084"),
+        ],
+        [
+            MontFp!("4124348183815361274605696911611564038558513135295809810358470442434566959664"),
+            MontFp!(
+                "25414058285660253269153928704815261642564639010795130470925809996787630419024"
+            ),
+            MontFp!("1810626791555882929924840749092828149662635382209492320085817946775947822041"),
+            MontFp!("4920591692014281629605844504332750932551134215838153168639102130085819235863"),
+        ],
+        [
+            MontFp!(
+                "11504273539810905286730499089711249245031011189300985135313057533308663410650"
+            ),
+            MontFp!("2771099783215896033951348956913573416348192909704452439683216458943733504911"),
+            MontFp!(
+                "13296857490347363194827359063812442952952071137688923946841258976216347201581"
+            ),
+            MontFp!(
+                "16906768408035187423726409154894843365507755985013546630285260704665103705760"
+            ),
+        ],
+        [
+            MontFp!("2314441850897100473074665352672165956833984675753114077078778570771945317076"),
+            MontFp!(
+                "10981982998761549160940285285103655673679922388467956784956393758009818610084"
+            ),
+            MontFp!(
+                "12361335114347151164354117674491150944480096555401873874987236447911423992610"
+            ),
+            MontFp!(
+                "12885433755353232803490261666224858948048036327351633127909209039117727484246"
+            ),
+        ],
+        [
+            MontFp!("2052089521750380115438943127101479476971038871415642859499684542540796227306"),
+            MontFp!(
+                "12349252516570384768473217649633731894989870612542527584456291016702159344334"
+            ),
+            MontFp!("5106755073413379545358034312863816941944772898052917520409186154304116299505"),
+            MontFp!(
+                "21030231173873484136998604895073272685961180553177815782887061346442322314145"
+            ),
+        ],
+        [
+            MontFp!(
+                "17462568133438060550575152571043268726412630377618701223599432744044837836962"
+            ),
+            MontFp!(
+                "17213961363559208077353006250566939296394818259729577950541201582614738870780"
+            ),
+            MontFp!("6615949476351972612916111251887661925029413965317527817765949559569440770860"),
+            MontFp!(
+                "19112009256019886116060663335099009951803894598609289668818991897049776328466"
+            ),
+        ],
+        [
+            MontFp!(
+                "26176475310945352825459767751272432358637480469830464005319925528297631582846"
+            ),
+            MontFp!("1028447715781739132032223914464558305132241966633932087466603044405998857961"),
+            MontFp!(
+                "28033153553910087001910598880750405717156783992129188962512151861760855444317"
+            ),
+            MontFp!("9539252936084921905399148722827244830856989021819035659294340297114419015781"),
+        ],
+        [
+            MontFp!(
+                "13793270297002075183564686706038729352847666140545903037445163848957486969543"
+            ),
+            MontFp!(
+                "15627876128529497713059695167059367826504447538041992645831010365043780996263"
+            ),
+            MontFp!(
+                "15106842907156950504113386768350776484088879489918313174657480193602753905619"
+            ),
+            MontFp!(
+                "13796166278761526592620191415051971261445288714981157687662031674118373683422"
+            ),
+        ],
+        [
+            MontFp!("4183708110572399072872135688960276428269537690311498245129610092711152247998"),
+            MontFp!(
+                "14204708486277721505550668452831734287953940490023902451619223496945368960106"
+            ),
+            MontFp!("9304937012539313285092964214232659865633769345843877668942653153968320744312"),
+            MontFp!("3953388727318972964350760308331907881014370203921744993316295845884991004010"),
+        ],
+        [
+            MontFp!(
+                "23985996489552119073395634294910397889613431561611906739500144545110747640024"
+            ),
+            MontFp!(
+                "13758231624546134961067579058977010958018030220434863146908854140717289708650"
+            ),
+            MontFp!(
+                "10277465678926220605625330164797781004865738583206949804502932952895380605084"
+            ),
+            MontFp!(
+                "17303008457406158678010281091342893369113632442472348042956817887170089577548"
+            ),
+        ],
+        [
+            MontFp!("6127247682987378947737234727247315129632441792159384894222023942243056916919"),
+            MontFp!(
+                "27580443929020250585592615304625294826628917431445580796451537868564939076208"
+            ),
+            MontFp!(
+                "16296662918538373442585153441921458998809169211355215669461426918821910226129"
+            ),
+            MontFp!("3739046284492561131231848906073534408146366938505920766983861717306402873471"),
+        ],
+        [
+            MontFp!("7864476318091330583048464314437270498681295995174194986963878692902830417454"),
+            MontFp!(
+                "18822304575236826457145094560528318805911391511364142783113024965495672049417"
+            ),
+            MontFp!(
+                "21365561070698284194634305742484961565282253712379394107400934791710595190049"
+            ),
+            MontFp!("1517329339955367247829271634169547196627346861603042062739016661895591990558"),
+        ],
+        [
+            MontFp!(
+                "12356402782524872619907155155775269850972550464872059215994151643377676524337"
+            ),
+            MontFp!("9532767595291936369019544214335079637721363162397569240800559622906781008590"),
+            MontFp!("9980272881276807485327914027127001286464613875740408365297171937227278604211"),
+            MontFp!("911974665483982763765603478023611361932502382120537472254921100337381166385"),
+        ],
+        [
+            MontFp!("7363588055238807070257175099053285443630569728046251114890137987771420451208"),
+            MontFp!("8802272551859686582787823068207388115633378400566053724336803461431897775327"),
+            MontFp!(
+                "21792647707759185970214998332982442914549039405462346675375672994165650968557"
+            ),
+            MontFp!("9329556454358474623694293270095229601501939039395742759429342849816042205536"),
+        ],
+        [
+            MontFp!(
+                "22192557003636519600467549082448614326297551713021887085695456620997120697054"
+            ),
+            MontFp!(
+                "17141001918203949467906219677726933797001442270184118795608976560634173949024"
+            ),
+            MontFp!(
+                "27058215533740575562138243800642061631721672046957166473824005475285242385113"
+            ),
+            MontFp!(
+                "10989474656161933429195070227701015757203646676981190020280928694261936895496"
+            ),
+        ],
+        [
+            MontFp!("4625819250820789051469094322431326700095581107834694090186235857554652079457"),
+            MontFp!(
+                "19511947896746986941295726557495109813433673454658748818659436014452541362594"
+            ),
+            MontFp!(
+                "22553573968727694531039787864727003289850768140833870115272844736537013265038"
+            ),
+            MontFp!("5898161847842306311674209816133388902180123230045772022792259024986898021459"),
+        ],
+        [
+            MontFp!("2095265029098158123374772577900790112033670044343833381697615079208893742239"),
+            MontFp!("139432347585148762026799472055977145365819423113790908104606588115348525275"),
+            MontFp!(
+                "12445405201560871641538278292551198103441194835494176506922754157293579835427"
+            ),
+            MontFp!(
+                "14938763577868889011019877654921720661588091212601627277663546131538832275048"
+            ),
+        ],
+        [
+            MontFp!("2063261051103821092673694890790429774445173214284779507107261981011529251016"),
+            MontFp!(
+                "21988541290844216202635995138889191250423008958073454161330058157781638921192"
+            ),
+            MontFp!("6893078927947383860205568610945645554583345106445586673394795590133335694250"),
+            MontFp!(
+                "12888117172007454480360019700351803479630512351886503846724476609055449048680"
+            ),
+        ],
+        [
+            MontFp!(
+                "15598882592061044207407676054294119913627008052523142101758556638564187208209"
+            ),
+            MontFp!(
+                "21532379308479923954088952384280593281074800355285684416200396393610781446114"
+            ),
+            MontFp!("9717793580192948821098666108680816072050953589728487572011642993487791207093"),
+            MontFp!("8164698289684259941735695845871083019358754987770145174849902551600002164200"),
+        ],
+        [
+            MontFp!("3461668722279129982788751521780724850969372877563079059191368846260397295311"),
+            MontFp!(
+                "27064834399912397935131141430550774095687480179376513027935085585634105060915"
+            ),
+            MontFp!("8309107572332498739005645020179318237424508206273162540266765132497765102080"),
+            MontFp!(
+                "24486065640927735919953210908704942173357356339989828836874631252544626507771"
+            ),
+        ],
+        [
+            MontFp!(
+                "28676962690702584178108317222487339419288660513108355332417498517601401272005"
+            ),
+            MontFp!("1376565852265254330727934430088939584310203450927236708386813990159991920936"),
+            MontFp!("1458365555757827000003455881962568690959364199969104982168610304553148737111"),
+            MontFp!(
+                "13580195597714767426309820485851782110359289651296416512861249669578472680372"
+            ),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "21711016731996786641919559689128982722522292361456170536966007573262475722753"
+            ),
+            MontFp!(
+                "11579208923731619542357098500868790785345222592776624286381870705739987052135"
+            ),
+            MontFp!(
+                "24123351924440874046577288543476647469469213734951300596628897303624973025281"
+            ),
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538034694831243865342838645907"
+            ),
+        ],
+        [
+            MontFp!(
+                "11579208923731619542357098500868790785345222592776624286381870705739987052135"
+            ),
+            MontFp!(
+                "24123351924440874046577288543476647469469213734951300596628897303624973025281"
+            ),
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538034694831243865342838645907"
+            ),
+            MontFp!(
+                "25329519520662917748906152970650479842942674421698865626460342168806221676545"
+            ),
+        ],
+        [
+            MontFp!(
+                "24123351924440874046577288543476647469469213734951300596628897303624973025281"
+            ),
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538034694831243865342838645907"
+            ),
+            MontFp!(
+                "25329519520662917748906152970650479842942674421698865626460342168806221676545"
+            ),
+            MontFp!("6432893846517566412420610278260439325191790329320346825767705947633326140075"),
+        ],
+        [
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538034694831243865342838645907"
+            ),
+            MontFp!(
+                "25329519520662917748906152970650479842942674421698865626460342168806221676545"
+            ),
+            MontFp!("6432893846517566412420610278260439325191790329320346825767705947633326140075"),
+            MontFp!(
+                "20263615616530334199124922376520383874354139537359092501168273735044977341236"
+            ),
+        ],
+    ],
+};
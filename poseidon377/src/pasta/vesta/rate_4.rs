@@ -0,0 +1,947 @@
+use ark_ff::MontFp;
+use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+use super::Fr;
+use crate::field::FieldParameters;
+
+/// Parameters for the rate-4 instance of Poseidon over [`Fr`].
+pub const RATE_4_PARAMS: FieldParameters<Fr, 5> = FieldParameters {
+    M: 128,
+    alpha: Alpha::Exponent(5),
+    rounds: RoundNumbers { r_P: 56, r_F: 8 },
+    arc: &[
+        [
+            MontFp!(
+                "13749402850282809693316716302476259135212441465329206238205934472133798312426"
+            ),
+            MontFp!("141065013907068083792782148667162357067851932693280065254607151619535936906"),
+            MontFp!(
+                "22903327284028606186277966506063483509425031585198165222788507867969729702859"
+            ),
+            MontFp!(
+                "12993528099216535393279885870977772848233154330386539941277521808884397529608"
+            ),
+            MontFp!("668743686249318463861222396543150135871461387397118381787927680264072135799"),
+        ],
+        [
+            MontFp!(
+                "16970390622052158409842444216054879147673068379722280187744454458974031888691"
+            ),
+            MontFp!("7825236329972456733910345602193990625184524980361820835365852391250065308621"),
+            MontFp!(
+                "28304179243082081575647801087990406774446528161270388488939998818737930593191"
+            ),
+            MontFp!(
+                "22580106802678839148157492663070273619082965971664352660291770163713907462653"
+            ),
+            MontFp!(
+                "18080658470986510752784312791781909268839045702069607585279520368725412802866"
+            ),
+        ],
+        [
+            MontFp!(
+                "20012468709167551531137936964001832626359274331891253440478933912075567777924"
+            ),
+            MontFp!(
+                "19341191177411729184374218620977435436999798010742144906705927466413956440705"
+            ),
+            MontFp!("8280630100963394025781076297795997650432666267295406471381639017801552922408"),
+            MontFp!(
+                "21652432002980015772673606136072877906698611646687374345746931886597421721475"
+            ),
+            MontFp!(
+                "20133248082519169087248009401617478403467956672601889112489309850753497209181"
+            ),
+        ],
+        [
+            MontFp!("6566008638109790466464049610767558192868521468106884475726057420739186152559"),
+            MontFp!(
+                "18770064988780985085135036305708228357450803737961012725592122385873326998537"
+            ),
+            MontFp!("4617214012937228675933376151846997507658103329760069120716432822307194048623"),
+            MontFp!("4149029186216298883988307207738436417736935954427133332667221852803419267428"),
+            MontFp!(
+                "14029410551365726165422249273600795369954237883231247620162508393221608142014"
+            ),
+        ],
+        [
+            MontFp!(
+                "21452717270489201203824412657665689436162909194910439432169447209524248028323"
+            ),
+            MontFp!("1029402703470415690145870544415623370484743251630651327610520596495600378052"),
+            MontFp!(
+                "18359858387263127178389530428243732742607487540432901315220807556629120368908"
+            ),
+            MontFp!(
+                "20773782135032010942573420195309283252348825727440424787818872482731573479879"
+            ),
+            MontFp!(
+                "23875782167632240089699346615771376107423481484616456129177501144334742815868"
+            ),
+        ],
+        [
+            MontFp!(
+                "28594539964969246185156685671901299997324463457158925733731129203365893567751"
+            ),
+            MontFp!(
+                "11186266491381212309154173037531648435055726050378256719597275023737083419149"
+            ),
+            MontFp!(
+                "22358193370521556655954371909245484710659946300537121343536209330676117199483"
+            ),
+            MontFp!(
+                "12629341163822688311929662695926944059948331341152011477649243462843571653141"
+            ),
+            MontFp!(
+                "26760116959894287926717805697812157025195429301723148723691301977197027499050"
+            ),
+        ],
+        [
+            MontFp!("565974308586505849552528651431654583211790554362140841021336356378430509204"),
+            MontFp!(
+                "15799303176084787973673732868256027008484633751736638363337248275418407258722"
+            ),
+            MontFp!("2105529173264147885994807511739911752602409009029065169022373350965312433772"),
+            MontFp!(
+                "14734338879291158979929116726158039856356995362378704314536429944760028017270"
+            ),
+            MontFp!(
+                "25141706365829188982256535628197398646432468761453481200157066136202189323610"
+            ),
+        ],
+        [
+            MontFp!(
+                "22857332062660159025404906310731243411850952527466610986073354106410885984152"
+            ),
+            MontFp!(
+                "25664088006904239752000479102660710120159761753047697136817801743946873074432"
+            ),
+            MontFp!("3470129472871899587341734854600015537350755995129087390709543114385599519259"),
+            MontFp!(
+                "26583478305868984328787173018359564637161082233048258730046740733239458936417"
+            ),
+            MontFp!(
+                "14668113182556135269278069704924359880438282748621533366025051544952507509099"
+            ),
+        ],
+        [
+            MontFp!(
+                "28356983291064579926187213199697420640010670206150696160627507750736770369866"
+            ),
+            MontFp!(
+                "13375275258198428981365612020373504279946442282651434478501509353438050598874"
+            ),
+            MontFp!("9590718400171947331013903769043426138817824108236529792694539692668263873235"),
+            MontFp!(
+                "26399510885471536023352586604247483325964553468670444940715870803041443138740"
+            ),
+            MontFp!(
+                "21255722255901020146568488158761215005081975769111896331733581465395279071064"
+            ),
+        ],
+        [
+            MontFp!(
+                "28857722658415406106722507782480679621222620330240974020369912966629080727796"
+            ),
+            MontFp!(
+                "22763126595505534067093983108901218994978669810532061769374664524949174420304"
+            ),
+            MontFp!(
+                "27096951897181262124121906923944676043654492897227921454893265856123534033177"
+            ),
+            MontFp!(
+                "11018118780641696463589829952167472268656968040734056805914271553284208116662"
+            ),
+            MontFp!(
+                "13733238445036835467971936959434135894310613025534374191525180729892324770804"
+            ),
+        ],
+        [
+            MontFp!("2163822720805599792585344109628991220066476674665155499998586144159440055419"),
+            MontFp!("4759987047889932193728393122353802821996957406974310599567866846566002178426"),
+            MontFp!(
+                "21004250768761064417498214114175403309957982588508663238939472108655771175393"
+            ),
+            MontFp!(
+                "19654569953305741214328405919559065118670781694563060086580871772484382435220"
+            ),
+            MontFp!("5053485167277065589404538921362455821503530955114308476983677699542901835080"),
+        ],
+        [
+            MontFp!(
+                "20154726554768685926814227113476122368393048409034178847005619747923763736825"
+            ),
+            MontFp!("7685309538274970124307348581322215586515767206456937466720495552870995222967"),
+            MontFp!("1736115739398431817108065852007695329207476694516705451798783381609830911032"),
+            MontFp!(
+                "22764682158995120987158449014277715011830380691682059205365093006348584590145"
+            ),
+            MontFp!(
+                "21428022790481092213403881771638043492122716553689584172935225655650447421800"
+            ),
+        ],
+        [
+            MontFp!(
+                "19921846860293535909467917583907964236113106807025660598703458647139497031193"
+            ),
+            MontFp!("9132171533364131327402648949431141592087433047069082358898618335481460749240"),
+            MontFp!(
+                "18383824715619062527423283947093202743962525605429720738114022426273512278770"
+            ),
+            MontFp!(
+                "20336041437508826166898783859954987674205710730666390740809810487526364261258"
+            ),
+            MontFp!(
+                "23942245491146931345070245143825030839657430712233297659521737128530042873574"
+            ),
+        ],
+        [
+            MontFp!(
+                "23869058913005036997739817606391565081673448788534877644510601294844415348040"
+            ),
+            MontFp!(
+                "15351123018468019271801030475314265988319186255300740364913516480508608591005"
+            ),
+            MontFp!("1394122517026297262657469590032029542013219734914034422698839315263762456026"),
+            MontFp!("4647185415781751412782373241688484483924209013717867510167755762107504811171"),
+            MontFp!("8641483235954356293103316253164345077981653633179324335640282248525120761112"),
+        ],
+        [
+            MontFp!(
+                "20527830313868170266662861559394573340188007484558016408996745814380540597883"
+            ),
+            MontFp!(
+                "10242625447875257546074534991824894137384408145074765832210926542769187729537"
+            ),
+            MontFp!(
+                "17909763675756152610045727136296052040034831459955630187838168809543116260630"
+            ),
+            MontFp!(
+                "10034570274815341583714022832289447210502806680615254658011584276456381546567"
+            ),
+            MontFp!("6260529989570647471057103997166099838082743005363750909092345771829405767372"),
+        ],
+        [
+            MontFp!(
+                "19047864832840658835908670655855122544284028850121384759787195533533049462586"
+            ),
+            MontFp!("7980974819370997117982063748028499848894259203651662219658360464034161031654"),
+            MontFp!(
+                "18252922556795026477443819641898063494750338106862176832626855823840915662640"
+            ),
+            MontFp!("7198870841572233330328714856950304093686882597254149075337015142611203442251"),
+            MontFp!(
+                "15860569567725216043290144875447862759349759500654116340554569759659107016003"
+            ),
+        ],
+        [
+            MontFp!("7544324433254476480676090087190792815641660495649909725569593439978952208407"),
+            MontFp!(
+                "23782460829363623272418141429345190532472239585039032289444062466985724833726"
+            ),
+            MontFp!(
+                "12404729863600525253717299827346235190848788947523820699982847852013439733775"
+            ),
+            MontFp!(
+                "26456693916906980593711828652880520066949337831524095811403922644017141626989"
+            ),
+            MontFp!(
+                "12620809144544510465336778558136652358111894044935880359987492087007758333703"
+            ),
+        ],
+        [
+            MontFp!(
+                "17351044242418967989044001063758848239083936174777597952670033003001276068343"
+            ),
+            MontFp!("265043895025392285285953838927360368681255982021721331912665571248847830884"),
+            MontFp!("4178114119592298428083781192600566647519806564121330315349955314213573550502"),
+            MontFp!(
+                "14608692942951666297624885696866952350438669997839588320548780717744661497964"
+            ),
+            MontFp!("2215008367187773303322082828449551214576611808024154646892088693735047212432"),
+        ],
+        [
+            MontFp!(
+                "23370557702064999120245537868810828990639113987056586131275935745899007999691"
+            ),
+            MontFp!(
+                "15261287268594187182940073082134557085128233398031102923497554517469461468275"
+            ),
+            MontFp!(
+                "25167550495342126198893741214774108745486092428628051332602354717165946752461"
+            ),
+            MontFp!("5349041736157993377561230295274090632914772922722101211741206730019490161072"),
+            MontFp!(
+                "21212273009424857511972780779973699284463826366102341285472998229492352778628"
+            ),
+        ],
+        [
+            MontFp!("4414950653368713771832943973064319618186104000139000947136626277606026980691"),
+            MontFp!(
+                "22520160229851332351890105293114411100689082147181355016385379877166698979369"
+            ),
+            MontFp!("4690202117080090367566633561242607843182109941860112916337328131748259268772"),
+            MontFp!(
+                "18164756138934827406715770944370885649617789344172706384359934917542457778797"
+            ),
+            MontFp!(
+                "11731164776122482979090360432594731423372718145063580012514317404931340070884"
+            ),
+        ],
+        [
+            MontFp!("5750768141124241263926421293457059073070642276994125143909773963914792855709"),
+            MontFp!("6959509291090329344636468182035726579169059958742774195900475731212321348192"),
+            MontFp!(
+                "21417358235749434390889435095624892689625240183827385848885454004001991027929"
+            ),
+            MontFp!(
+                "16907752021186872023011635187758649498946784755227106542967537280764589539796"
+            ),
+            MontFp!(
+                "26689167723368297909787425031383278378991346109334083220433741819137000362109"
+            ),
+        ],
+        [
+            MontFp!("1993142311922837191841816466121712993939688877303605962230509913035943739791"),
+            MontFp!(
+                "16209988888295009788150686304544346866622640532757300025685114632509947155011"
+            ),
+            MontFp!("291481844819086700833766094237504623133103700340257428942763110165940146502"),
+            MontFp!("6961631916730908242268610463442182320111567026131598663411823131429560224972"),
+            MontFp!(
+                "25954876755675408837334333614918003329562168893864329317242648830313719319069"
+            ),
+        ],
+        [
+            MontFp!("5424317729883867441213962306685464070757724789409166584009704676707049660953"),
+            MontFp!(
+                "14478723860923163488060846647392070065210042655986902925849941248201723249288"
+            ),
+            MontFp!(
+                "14143627335543967417807202737619144869632594611373065283605104951522735977271"
+            ),
+            MontFp!(
+                "17808029495694530547478527386694246783879775369104370683482564843025525831205"
+            ),
+            MontFp!(
+                "13647720867396846115547502487673116796816617813690203637482817271210568530242"
+            ),
+        ],
+        [
+            MontFp!(
+                "11005830721424056557504940054522661542001108640128967584468582870496329699366"
+            ),
+            MontFp!(
+                "28135532996403286970860660313461153432992982720680165123614702066848383015884"
+            ),
+            MontFp!(
+                "18291861668563094034448530488854810547970809916671138877107168540012934498745"
+            ),
+            MontFp!(
+                "18689347155105385012708840644802468265617544338132628218732529510615771671355"
+            ),
+            MontFp!(
+                "22157266483932573832490337341754221912615687293357804659039544957603564066068"
+            ),
+        ],
+        [
+            MontFp!(
+                "19068557253110068428355390706050544086475522341271787678834106277425301271751"
+            ),
+            MontFp!("4021262069147916341668344687294198258595852904595764331026977136327476084732"),
+            MontFp!(
+                "27440403952410403940828411738305541789472054946383681341515342075453175597699"
+            ),
+            MontFp!(
+                "28596787433693329284262937732868735561269509189886831915590444033964893462755"
+            ),
+            MontFp!("6075564837445858582909033600092047106790575532854827846866594448046220748650"),
+        ],
+        [
+            MontFp!(
+                "19828333212871982585667793663010497365743073996701411552126523741733623651962"
+            ),
+            MontFp!("8727265239751133999657705146895476645707744877402518932867321591443743339666"),
+            MontFp!(
+                "28880893404916797467134102068611954222895391852767651582096962418864354958699"
+            ),
+            MontFp!("2600248584882820807174302830215246640967306983782239107642911283837158600717"),
+            MontFp!(
+                "26765821723438388330173283585924750366038302777073486138530794744582045940944"
+            ),
+        ],
+        [
+            MontFp!(
+                "13878050597449804222155682132061150518438555503207445788375607531615953087220"
+            ),
+            MontFp!(
+                "21220008046501838902403761386153315412598565040526977099046886581792918364574"
+            ),
+            MontFp!(
+                "25889295639575182511148980372571827584366591024801287643476219484112563762735"
+            ),
+            MontFp!(
+                "22812170710484286033704934918277978176526091071501561325693692676910208202989"
+            ),
+            MontFp!("9165482212827878599023510883488430347708210722382777057088904995776714719410"),
+        ],
+        [
+            MontFp!("5022746595877739370125884159754004108708088295260522434744197045290951860563"),
+            MontFp!("829365578677343081771789772911067479238513185506342973481892904451994786158"),
+            MontFp!("9950462451115644979874144026785522341302694909905345222271078207983182949507"),
+            MontFp!(
+                "22763192378225020421238411084293625453830101448540008520924565450403557082963"
+            ),
+            MontFp!("4108258117144481310562988121722125730691442669182445235532072286265698499470"),
+        ],
+        [
+            MontFp!("6648581732191143581019088790230272102726548193979238867278666248922357162884"),
+            MontFp!("9855329730766122043705223069042378569303211542873453133641157353297235706402"),
+            MontFp!(
+                "14152279903438678838669529427099531998699541545763029921435559401041123724005"
+            ),
+            MontFp!(
+                "20249197038567230601948410560110589899131284456809666221324729483874244988365"
+            ),
+            MontFp!(
+                "16844107306364408060052770507525334764514744132969338977919646880584488986121"
+            ),
+        ],
+        [
+            MontFp!(
+                "26820344472622879407755258371010006988199865179634055386885529311343857274484"
+            ),
+            MontFp!(
+                "18531123604852351430963013869430895009028637027529987609597353846514795233628"
+            ),
+            MontFp!("1000609403406889156215623783616937926352038934117102393610639123194612867386"),
+            MontFp!(
+                "13434016288998694985814952316635313562263358518831979087968352365197560306957"
+            ),
+            MontFp!(
+                "21780149768860723498020723347103841028502691413169860233254667096875170724696"
+            ),
+        ],
+        [
+            MontFp!(
+                "22984470335610527130618805089519163251469535571433868780389345619745121688433"
+            ),
+            MontFp!(
+                "10994088874825086572016538409743520078284637825516968320330011330554046731679"
+            ),
+            MontFp!(
+                "11082756340756418357388237539382202438267813655354776568035463736494213429395"
+            ),
+            MontFp!("1998736207122043374351901299268990760757672053443245249607162662877643421668"),
+            MontFp!("2660763527295302762585978192407900026312157935983177796883370279192508462982"),
+        ],
+        [
+            MontFp!("3486446510038813893317009086553536197353231238029265031846902359563080589816"),
+            MontFp!(
+                "28562037897366893871711337005846297755586274803696474964561985217735565064850"
+            ),
+            MontFp!(
+                "22083153754128282007637395520743170048114784138682730730595355140980625061879"
+            ),
+            MontFp!("124817896136688658882607171198982068967039391692130653175546797798214780252"),
+            MontFp!("205902096375577385209649865272214228279600371105665528502505461712021724199"),
+        ],
+        [
+            MontFp!(
+                "11215175391896205277293432836482239863893217912061462246024145258534484582463"
+            ),
+            MontFp!("1632413059906203033722651891891388839180842005045854698951801790890599637160"),
+            MontFp!("2530374818640956892936450260553432264355346018186234942142295394938610558369"),
+            MontFp!("8671443276907703004467812595011742084626406351351450916606445431571994302917"),
+            MontFp!(
+                "20208440559772237934608139520957072787394151496684431211136917713380845783383"
+            ),
+        ],
+        [
+            MontFp!(
+                "23944886439199266211252581240390871933029066210404430527835070874107209540742"
+            ),
+            MontFp!(
+                "10053000186366378957337509690310807295294139092470986204578249762445736651342"
+            ),
+            MontFp!(
+                "25613214638069134697975693706244343178840328182141614048083194308866047643759"
+            ),
+            MontFp!(
+                "16714516457297064005490496897739861894315334339039385628595556419091719881897"
+            ),
+            MontFp!(
+                "25698099554189571545146889380738369822259369212908900269368364046030180194914"
+            ),
+        ],
+        [
+            MontFp!(
+                "22309947198604153633090081832802693761536155440360876781076986874625774710060"
+            ),
+            MontFp!(
+                "15751150123469074630989161397118888988912643743518372923081920327054911821121"
+            ),
+            MontFp!(
+                "25509537781890594648104828472057404032571875915988221577410514714129706984377"
+            ),
+            MontFp!("317386650406333835625062558903436403164665214247359946098551985108740111506"),
+            MontFp!(
+                "13069006083558036880306809424169434272526147224107833273152295189797925605774"
+            ),
+        ],
+        [
+            MontFp!(
+                "26597358625814110922862645729249919387813859400045240800381165305557981598986"
+            ),
+            MontFp!(
+                "22809804681457366200908548928835148478431345073508625131277172620061317441241"
+            ),
+            MontFp!("1523500666793021038177019855949538554117863955099691225691740307912486391688"),
+            MontFp!("5662734410122435240224427077673768545683958548474213190576792659501279765792"),
+            MontFp!(
+                "21491286388669392828184760543206120723724693048613256051064540406904232458038"
+            ),
+        ],
+        [
+            MontFp!("2803969427111386471284376194450339790280386372600735307291056778749164847397"),
+            MontFp!(
+                "27651974745579884035146304705542032809901230227347603391016637413151022252445"
+            ),
+            MontFp!(
+                "10847996587611782250930599209311462377223580129550640088954364819237984848773"
+            ),
+            MontFp!(
+                "12613128967797067203983503366555897313806642714314200725635095368744540479115"
+            ),
+            MontFp!(
+                "24975657611636797072279037529496097899962944289740339312229589757797373185564"
+            ),
+        ],
+        [
+            MontFp!("2793341662824386290105333074542137937437400990997101460121820050597310223612"),
+            MontFp!(
+                "12580405800301338855725907087294424268443627844164241481835582696836924498673"
+            ),
+            MontFp!(
+                "21293886776435149090057480818943555192057762716516349686780802410336305629062"
+            ),
+            MontFp!(
+                "22307887293086998200437021419825014839990644437146550875376109485316013063334"
+            ),
+            MontFp!("8459374779601144298835197838195433878139174080426511452511143101184726753215"),
+        ],
+        [
+            MontFp!("1561031776926123268063858836822111912666137318571258795877324797167269341996"),
+            MontFp!("4941401940794099665110409486243178867737573066975578853390513622732145450318"),
+            MontFp!(
+                "26098706844377493570796572031516876422580507817458134528724383815910631833449"
+            ),
+            MontFp!(
+                "18837647259063237471830094203414928905127087506647919195190559514256525753340"
+            ),
+            MontFp!("3073691281388457071247597648382006192184898023521075823539604555946851002715"),
+        ],
+        [
+            MontFp!(
+                "27349871024281288756304656036727712999673559166714345075366539600812607689884"
+            ),
+            MontFp!("2442237444433071648259387498927761437845795964510847008662088798001617764582"),
+            MontFp!(
+                "17872579944963358907289823522108442425352011102721302101106328305443886093648"
+            ),
+            MontFp!("2347485058035959945626685555312010109691060370545128790322106806344038505425"),
+            MontFp!(
+                "25282550365767127739059508661024043559760342733332178850651438799811170077119"
+            ),
+        ],
+        [
+            MontFp!(
+                "19241900076520736684380668588645695438358321443160507117902419223931873712058"
+            ),
+            MontFp!(
+                "21492679033591631311382378387296593595039546134487122334703870876123601099847"
+            ),
+            MontFp!("4761056881595704491346426338299141292840144679141458388963227771698155286077"),
+            MontFp!("1484649835675589849372173022088750643176159705075590997698259913319425493197"),
+            MontFp!(
+                "23723149695095881048950458727152791126164880105060865446130634953526717075543"
+            ),
+        ],
+        [
+            MontFp!("6366506809013708531056987112379877991243212361909801527217495612010250639351"),
+            MontFp!("4862332313521616557894813394172700069814043532667060234576560194856511721020"),
+            MontFp!(
+                "11157834529097484095683370763260311494215370695813673156062769266213533663234"
+            ),
+            MontFp!(
+                "12316974448466684177211005251120233797579953292536384120760703564189476197154"
+            ),
+            MontFp!("7277218664345111312233968661948456160115374329719777387991257273045302134312"),
+        ],
+        [
+            MontFp!("9449770927357245524073981082538549982384984367720633493857227208229966575673"),
+            MontFp!("4058179605618486462256947436922398965050644138248754259188181789527298578574"),
+            MontFp!(
+                "18989231613061224919979443319893749443332951956556776099650856159109491737834"
+            ),
+            MontFp!(
+                "21360618661229399060706132243464684919425513529941915014887463859204370017840"
+            ),
+            MontFp!(
+                "12462499946695300204660284699404679632899379781636391756087058170300545807528"
+            ),
+        ],
+        [
+            MontFp!(
+                "11077571942015550751597166766349093414038632009481161855167149995085398289967"
+            ),
+            MontFp!(
+                "28351561527855054486992536200022469897451673513724174075919514227597220411979"
+            ),
+            MontFp!(
+                "17000087825158323714790050165997597383311478414348690106129877160112317768227"
+            ),
+            MontFp!(
+                "22540119829966996879334231396139320588645207193446925319998471799232181695638"
+            ),
+            MontFp!("5012670837042583249397665515057794305457376363709331386426069771165229075110"),
+        ],
+        [
+            MontFp!(
+                "22197145894833483376814676492489822462941978483631795684816722147439812153879"
+            ),
+            MontFp!(
+                "10190572959730462256882977523122578457966824151190854119925885165185142132985"
+            ),
+            MontFp!(
+                "26757538603556372756530041541165078688617266727293987911877883075893331551980"
+            ),
+            MontFp!(
+                "23723155206579152802826370339809841872425522821299864371039438160533255758293"
+            ),
+            MontFp!(
+                "18355838036857177669689443859461230371677169550002396336337978950967351060358"
+            ),
+        ],
+        [
+            MontFp!("8034492215064801848003939874642438388777783267724536766924777594070538820116"),
+            MontFp!(
+                "12996379427089582142344188759193347285868851512128464197608088194770079901585"
+            ),
+            MontFp!(
+                "16781527026980478089478240502600920290149122465837065838410863924453015857155"
+            ),
+            MontFp!(
+                "21148139867496424475095852198008818688132418974045902487750444868986205384100"
+            ),
+            MontFp!(
+                "19316070259092862609702577370722674274138672942635344957208509948088003782346"
+            ),
+        ],
+        [
+            MontFp!("7541875584641146400547229447864851191753724610560488277829116819145583937437"),
+            MontFp!("3238416598610164455765403644821002750321050171432230059880124651980643193947"),
+            MontFp!(
+                "16622502723037235708688102785229821567422460498127044192503671351734202334170"
+            ),
+            MontFp!("4644149066878423479137887900795498555000717477128201966460374616016784182992"),
+            MontFp!(
+                "15515700021137396958233737539476355029143449197207730562736637270030492634477"
+            ),
+        ],
+        [
+            MontFp!("4715091343475480388594226605517721313932710478320849277709709083391405686739"),
+            MontFp!(
+                "13222524617693304533883448239458851731743169340050952963188798923986140464319"
+            ),
+            MontFp!(
+                "11756006914574714859858581360202564213014771452813697665411373568921280727442"
+            ),
+            MontFp!("8904361080511503500570356049819342694541282038066570668524667047815017473003"),
+            MontFp!(
+                "26589281580323741970666550279411647033609957062942599747521429949116018589841"
+            ),
+        ],
+        [
+            MontFp!(
+                "28446363334153454145641832023785259517217221174451239501417165099106970802248"
+            ),
+            MontFp!("1487069479650630282134627741075506377326827650639929273033087419735388789180"),
+            MontFp!(
+                "19553681366773401952256066169619756119072148538933195739077958208816497969775"
+            ),
+            MontFp!("9962083828666305040743958183252936141689803865142021356418678822460248126449"),
+            MontFp!(
+                "27456725399268524681587032715248083462745316470871497541954858459796248790468"
+            ),
+        ],
+        [
+            MontFp!("5455353608181495249555721993255716097836483833840734321298505764063573183928"),
+            MontFp!("7986071930675300979855839487624545242200462983234763130929094619233112056013"),
+            MontFp!(
+                "25046301974788289518567770160863066405019958335936842259015173669804582516235"
+            ),
+            MontFp!(
+                "14314381914658742357961304204596685610290305074774451817035090752735786578638"
+            ),
+            MontFp!(
+                "16560798798734480502322351471253156332201172015510085676938948321234375891892"
+            ),
+        ],
+        [
+            MontFp!("7172293177541568654057802101674148262214697723466675347488420971626212782495"),
+            MontFp!(
+                "23499771745930822189571357043778311704795518520386725052796842681781912069147"
+            ),
+            MontFp!(
+                "19667414807400926501706907214719254014678376354362015817464544181255393945787"
+            ),
+            MontFp!(
+                "11484930346174721080950202698790259340029800685733565675548420824734701116485"
+            ),
+            MontFp!("1679684276352069914420082962183592418501315412649820027452395940858485517039"),
+        ],
+        [
+            MontFp!(
+                "16382897590696036777382261040933097245671732300858692898234899854818748619030"
+            ),
+            MontFp!(
+                "10412556881040578876557073275246302787695958469534725399704811222773619339832"
+            ),
+            MontFp!("4066107102587354083847308473239630872256373339900017767319812944234616681704"),
+            MontFp!("1276885512360834049606192021325668355400009089963330481640958447984045403763"),
+            MontFp!("1473547596853470833692574382048761552725709098659485345169559098479772722599"),
+        ],
+        [
+            MontFp!(
+                "16271699861834935371768606385734283310971106908481691148405888884769232181751"
+            ),
+            MontFp!("9531424431263924069680585492065284013587252951090780100379229229308018784594"),
+            MontFp!(
+                "24442096106587183129758654070838874605047119140101699005473652648794820598187"
+            ),
+            MontFp!("7451314700443728658485326805782296909839625811985258702248551559935322238076"),
+            MontFp!(
+                "27983796676458706013001274226402866138361597193394897987197395843408707378208"
+            ),
+        ],
+        [
+            MontFp!(
+                "27480236281380464537231179835876220214454336972389587930232684176367594334020"
+            ),
+            MontFp!(
+                "13823916925522186539423458670557868749032227072978177795638993743612659684139"
+            ),
+            MontFp!("3044420778877254779883351586545188135662575581872009844343179610947798137747"),
+            MontFp!(
+                "19677630044925048202657938818785060969609566079318821647496776835124328156739"
+            ),
+            MontFp!("4419363693618269097724650092188648815543924905099030394714837632783362943355"),
+        ],
+        [
+            MontFp!("4409669631600410218502971806984521480759490246892105660658151045781278251183"),
+            MontFp!(
+                "14590713651622165373091489892826861011036608368556186327742752295710932925314"
+            ),
+            MontFp!(
+                "18943885673303688270282864462348186533825812983855326444299306854553207536861"
+            ),
+            MontFp!("7785320548970727130388724397943316398908452470999924905352086788104777861393"),
+            MontFp!(
+                "21953238547040598463608644311084087957225586691567834605522129903418020163786"
+            ),
+        ],
+        [
+            MontFp!("9663864178565985636995252474638890400118187675953963314172556355218570744754"),
+            MontFp!("8784183958635119832808592323073436556877240261210477823817476200445743750230"),
+            MontFp!(
+                "27953605428563864015144191840332200303662168221906079337875986269536502931558"
+            ),
+            MontFp!(
+                "17670925116606385652673187685364799878884937372450900538514474532914276058709"
+            ),
+            MontFp!(
+                "24083131679561128657406653326632140307760395640404252728780884954696539224086"
+            ),
+        ],
+        [
+            MontFp!(
+                "27949728718119385797168630660429770842828485646756666932732317753727409370883"
+            ),
+            MontFp!(
+                "18601457949080527134099756693665151715950502719681953102346157955991311889889"
+            ),
+            MontFp!(
+                "13990589529735134897653046100833744168716016941913748800643380789955968960909"
+            ),
+            MontFp!("204853372047292306622949346491598507979358895908901761590950566281542332247"),
+            MontFp!(
+                "20771624592740866002694958736173747801201677371701626322870214826315466466592"
+            ),
+        ],
+        [
+            MontFp!("9848879537594319204003013557453047803246081208144113387796547448202306857747"),
+            MontFp!("6035804849027810241986616682922361780585325832911256250838131208740707713511"),
+            MontFp!(
+                "27263946662299392898071402659616866783065392495260565426816901121691208082403"
+            ),
+            MontFp!("4704970568377230431247118158751469906662588875866419998148084964118018731243"),
+            MontFp!(
+                "28537513434419404219909341511379757572565941028908834758192431978148544324375"
+            ),
+        ],
+        [
+            MontFp!("6251778308088701527289871853381810596457265669306930203153859315771684327004"),
+            MontFp!("7950111636207487522013413594529415454562955302288465974418063084623863066284"),
+            MontFp!(
+                "13093111806249498536044117470240696943314342632498384598643367700194581688277"
+            ),
+            MontFp!(
+                "26941568413468976383338611162010705527108908764511729553138039646874877608968"
+            ),
+            MontFp!(
+                "24610188365888537936554633505644381483000267792369374624467425204562437437192"
+            ),
+        ],
+        [
+            MontFp!(
+                "15535860946800491164126269160706974469201188651602225750831189799759906328774"
+            ),
+            MontFp!(
+                "18152705249343530859679814965111102279667133725044833147607689295664734356460"
+            ),
+            MontFp!("5053279145620309083508931418856892166351655978153967993543234113755254442583"),
+            MontFp!(
+                "28312400056107661649649040691594788817770323678979965098010455143037387927111"
+            ),
+            MontFp!("9123146372264359718033405296289333132340393131491400160569190779041418963541"),
+        ],
+        [
+            MontFp!(
+                "27450644113957382641648636163890804727473197573997503601283633032629738058305"
+            ),
+            MontFp!("3465112334112828562280598145474335245055568003216731809214448013236953690472"),
+            MontFp!(
+                "19165081473709867528544868475844614593111856544398269198967106031864816128579"
+            ),
+            MontFp!(
+                "25664263397070351581683552523412835549775873083169713049472088248737999682195"
+            ),
+            MontFp!(
+                "17462896669569999601636955782119658251809281688424872299169960654824883088681"
+            ),
+        ],
+        [
+            MontFp!(
+                "13305582547804274856264356378222977901228777605721836196814623997072173084854"
+            ),
+            MontFp!("2678816614515616136954400650264554949657595807329148024122626858348599465572"),
+            MontFp!(
+                "24677604617963276874567751890514017192352226040007876226625441671684744632362"
+            ),
+            MontFp!("3465628520683687206428911747387831732983279108542842945979580239544243813410"),
+            MontFp!("2183141380973934095715891890305868448336255335044423934856256446634043997342"),
+        ],
+        [
+            MontFp!(
+                "22903759402718218566993496864884532043388102088855261289972319398453281237854"
+            ),
+            MontFp!(
+                "19412959509650728445370843476932648245523724408339334969477431217709392255126"
+            ),
+            MontFp!(
+                "15756098643617940362992076414145282992457772106317054918152351669127924734788"
+            ),
+            MontFp!("6117695214429623403379141854299290232502638724020227016945855341154849152000"),
+            MontFp!(
+                "12048385774386290041093520366205127466398857749625036581908549206808057708414"
+            ),
+        ],
+        [
+            MontFp!(
+                "25652482871431977473155436228031386693946274623603846103432526905539174250925"
+            ),
+            MontFp!("4729461175690466633969698572090499086677468194929691210957910013437966939202"),
+            MontFp!("5227775034852959153283249208727270340508833453082110782571128533733756595201"),
+            MontFp!("3573109452646889969317718842568835635850998541150919439967636163831884770527"),
+            MontFp!("5824518409616144908315920135174029403520245081418387034037263353807244293280"),
+        ],
+    ],
+    mds: [
+        [
+            MontFp!(
+                "11579208923731619542357098500868790785345222592776624286381870705739987052135"
+            ),
+            MontFp!(
+                "24123351924440874046577288543476647469469213734951300596628897303624973025281"
+            ),
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538034694831243865342838645907"
+            ),
+            MontFp!(
+                "25329519520662917748906152970650479842942674421698865626460342168806221676545"
+            ),
+            MontFp!("6432893846517566412420610278260439325191790329320346825767705947633326140075"),
+        ],
+        [
+            MontFp!(
+                "24123351924440874046577288543476647469469213734951300596628897303624973025281"
+            ),
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538034694831243865342838645907"
+            ),
+            MontFp!(
+                "25329519520662917748906152970650479842942674421698865626460342168806221676545"
+            ),
+            MontFp!("6432893846517566412420610278260439325191790329320346825767705947633326140075"),
+            MontFp!(
+                "20263615616530334199124922376520383874354139537359092501168273735044977341236"
+            ),
+        ],
+        [
+            MontFp!(
+                "16541727033902313631938712144098272550493175132538034694831243865342838645907"
+            ),
+            MontFp!(
+                "25329519520662917748906152970650479842942674421698865626460342168806221676545"
+            ),
+            MontFp!("6432893846517566412420610278260439325191790329320346825767705947633326140075"),
+            MontFp!(
+                "20263615616530334199124922376520383874354139537359092501168273735044977341236"
+            ),
+            MontFp!(
+                "23684745525814676336639519660867981151842500757952186040326553716286337152094"
+            ),
+        ],
+        [
+            MontFp!(
+                "25329519520662917748906152970650479842942674421698865626460342168806221676545"
+            ),
+            MontFp!("6432893846517566412420610278260439325191790329320346825767705947633326140075"),
+            MontFp!(
+                "20263615616530334199124922376520383874354139537359092501168273735044977341236"
+            ),
+            MontFp!(
+                "23684745525814676336639519660867981151842500757952186040326553716286337152094"
+            ),
+            MontFp!(
+                "26535687116884961451235017397824312216416135108446430656291787033987470327809"
+            ),
+        ],
+        [
+            MontFp!("6432893846517566412420610278260439325191790329320346825767705947633326140075"),
+            MontFp!(
+                "20263615616530334199124922376520383874354139537359092501168273735044977341236"
+            ),
+            MontFp!(
+                "23684745525814676336639519660867981151842500757952186040326553716286337152094"
+            ),
+            MontFp!(
+                "26535687116884961451235017397824312216416135108446430656291787033987470327809"
+            ),
+            MontFp!(
+                "11133854734357326483035671635450760370524252493054446429213337217057679857822"
+            ),
+        ],
+    ],
+};
@@ -0,0 +1,350 @@
+//! Descriptions of the permutation for plonkish proving systems.
+//!
+//! A [`Descriptor`] carries everything a custom-gate implementation needs to
+//! reproduce a Poseidon instance exactly: the round numbers, the unoptimized
+//! round constants and MDS matrix, the S-box exponent, and a suggested
+//! decomposition of each round into multiplication gates. The optimized
+//! constants used by the native and R1CS permutations are an implementation
+//! detail of those backends and are not included.
+//!
+//! Field elements are encoded the same way as a [`HashOutput`]: the lowercase hex
+//! of their canonical 32-byte little-endian encoding.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+use poseidon_parameters::v1::{Alpha, MatrixOperations};
+
+use crate::{Fq, HashOutput, PoseidonParameters};
+
+/// A single step in the evaluation of the S-box on an input `x`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SboxStep {
+    /// Square the accumulator.
+    Square,
+    /// Multiply the accumulator by the S-box input `x`.
+    Multiply,
+    /// Invert the accumulator, mapping zero to zero.
+    Inverse,
+}
+
+impl SboxStep {
+    fn name(&self) -> &'static str {
+        match self {
+            SboxStep::Square => "square",
+            SboxStep::Multiply => "multiply",
+            SboxStep::Inverse => "inverse",
+        }
+    }
+}
+
+/// A suggested custom gate covering one kind of round.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Gate {
+    /// Name of the gate, `full_round` or `partial_round`.
+    pub name: &'static str,
+    /// Number of rounds of the permutation using this gate.
+    pub rounds: usize,
+    /// Number of S-boxes applied per round.
+    pub sboxes: usize,
+    /// Number of multiplication steps per round, following [`Descriptor::sbox`].
+    pub multiplications: usize,
+}
+
+/// A machine-readable description of a Poseidon permutation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Descriptor {
+    /// Width of the permutation, `t`.
+    pub state_size: usize,
+    /// Number of state elements absorbed per permutation, `t - 1`.
+    pub rate: usize,
+    /// Exponent of the S-box.
+    pub alpha: Alpha,
+    /// Total number of full rounds, split evenly before and after the partial rounds.
+    pub full_rounds: usize,
+    /// Number of partial rounds.
+    pub partial_rounds: usize,
+    /// Round constants, one row of `t` elements per round.
+    pub round_constants: Vec<Vec<Fq>>,
+    /// Rows of the `t x t` MDS matrix.
+    pub mds: Vec<Vec<Fq>>,
+    /// Steps evaluating the S-box, starting from an accumulator equal to `x`.
+    pub sbox: Vec<SboxStep>,
+    /// Suggested gates, in the order the rounds are applied.
+    pub gates: Vec<Gate>,
+}
+
+impl Descriptor {
+    /// Describe the permutation defined by `parameters`.
+    pub fn new<
+        const STATE_SIZE: usize,
+        const STATE_SIZE_MINUS_1: usize,
+        const NUM_MDS_ELEMENTS: usize,
+        const NUM_STATE_SIZE_MINUS_1_ELEMENTS: usize,
+        const NUM_ROUND_ROWS: usize,
+        const NUM_ROUND_COLS: usize,
+        const NUM_ROUND_ELEMENTS: usize,
+        const NUM_PARTIAL_ROUNDS: usize,
+    >(
+        parameters: &PoseidonParameters<
+            STATE_SIZE,
+            STATE_SIZE_MINUS_1,
+            NUM_MDS_ELEMENTS,
+            NUM_STATE_SIZE_MINUS_1_ELEMENTS,
+            NUM_ROUND_ROWS,
+            NUM_ROUND_COLS,
+            NUM_ROUND_ELEMENTS,
+            NUM_PARTIAL_ROUNDS,
+        >,
+    ) -> Self {
+        let arc = &parameters.arc;
+        let round_constants = (0..arc.n_rows())
+            .map(|i| (0..arc.n_cols()).map(|j| arc.get_element(i, j)).collect())
+            .collect();
+
+        let mds = &parameters.mds.0;
+        let mds = (0..mds.n_rows())
+            .map(|i| (0..mds.n_cols()).map(|j| mds.get_element(i, j)).collect())
+            .collect();
+
+        let sbox = sbox_steps(parameters.alpha);
+        let multiplications = sbox.len();
+        let full_rounds = parameters.rounds.full();
+        let partial_rounds = parameters.rounds.partial();
+        let gates = [
+            ("full_round", full_rounds / 2, STATE_SIZE),
+            ("partial_round", partial_rounds, 1),
+            ("full_round", full_rounds - full_rounds / 2, STATE_SIZE),
+        ]
+        .iter()
+        .map(|&(name, rounds, sboxes)| Gate {
+            name,
+            rounds,
+            sboxes,
+            multiplications: sboxes * multiplications,
+        })
+        .collect();
+
+        Self {
+            state_size: STATE_SIZE,
+            rate: STATE_SIZE - 1,
+            alpha: parameters.alpha,
+            full_rounds,
+            partial_rounds,
+            round_constants,
+            mds,
+            sbox,
+            gates,
+        }
+    }
+
+    /// Apply the described permutation to `state`, following the descriptor
+    /// literally: each round adds its constants, applies the S-box steps, and
+    /// multiplies by the MDS matrix.
+    ///
+    /// This is a reference for checking implementations generated from the
+    /// descriptor, and is much slower than the crate's own permutation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `state` does not have `state_size` elements.
+    pub fn permute(&self, state: &mut [Fq]) {
+        assert_eq!(state.len(), self.state_size, "state has the wrong width");
+
+        let mut round = 0;
+        for gate in &self.gates {
+            for _ in 0..gate.rounds {
+                for (word, constant) in state.iter_mut().zip(&self.round_constants[round]) {
+                    *word += constant;
+                }
+                for word in state.iter_mut().take(gate.sboxes) {
+                    *word = self.apply_sbox(*word);
+                }
+                let mixed: Vec<Fq> = self
+                    .mds
+                    .iter()
+                    .map(|row| row.iter().zip(state.iter()).map(|(m, x)| *m * x).sum())
+                    .collect();
+                state.copy_from_slice(&mixed);
+                round += 1;
+            }
+        }
+    }
+
+    /// Evaluate the S-box on `x` by following [`Descriptor::sbox`].
+    pub(crate) fn apply_sbox(&self, x: Fq) -> Fq {
+        self.sbox.iter().fold(x, |acc, step| match step {
+            SboxStep::Square => acc * acc,
+            SboxStep::Multiply => acc * x,
+            SboxStep::Inverse => acc.inverse().unwrap_or(Fq::from(0u64)),
+        })
+    }
+
+    /// Total number of multiplication steps in one permutation.
+    pub fn multiplications(&self) -> usize {
+        self.gates
+            .iter()
+            .map(|g| g.rounds * g.multiplications)
+            .sum()
+    }
+
+    /// Encode the descriptor as a JSON object.
+    pub fn to_json(&self) -> String {
+        let mut json = String::new();
+        self.write_json(&mut json)
+            .expect("writing to a string cannot fail");
+        json
+    }
+
+    fn write_json(&self, out: &mut String) -> core::fmt::Result {
+        let alpha = match self.alpha {
+            Alpha::Exponent(exp) => exp as i64,
+            Alpha::Inverse => -1,
+        };
+
+        out.push_str("{\"field\":\"decaf377-fq\",\"encoding\":\"le-hex\"");
+        write!(
+            out,
+            ",\"state_size\":{},\"rate\":{},\"alpha\":{},\"full_rounds\":{},\"partial_rounds\":{}",
+            self.state_size, self.rate, alpha, self.full_rounds, self.partial_rounds
+        )?;
+        out.push_str(",\"round_constants\":");
+        write_rows(out, &self.round_constants)?;
+        out.push_str(",\"mds\":");
+        write_rows(out, &self.mds)?;
+
+        out.push_str(",\"sbox\":[");
+        for (i, step) in self.sbox.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write!(out, "\"{}\"", step.name())?;
+        }
+
+        out.push_str("],\"gates\":[");
+        for (i, gate) in self.gates.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            write!(
+                out,
+                "{{\"name\":\"{}\",\"rounds\":{},\"sboxes\":{},\"multiplications\":{}}}",
+                gate.name, gate.rounds, gate.sboxes, gate.multiplications
+            )?;
+        }
+        out.push_str("]}");
+        Ok(())
+    }
+}
+
+/// Write a matrix of field elements as a JSON array of arrays of hex strings.
+pub(crate) fn write_rows(out: &mut String, rows: &[Vec<Fq>]) -> core::fmt::Result {
+    out.push('[');
+    for (i, row) in rows.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push('[');
+        for (j, element) in row.iter().enumerate() {
+            if j > 0 {
+                out.push(',');
+            }
+            write!(out, "\"{}\"", HashOutput(*element))?;
+        }
+        out.push(']');
+    }
+    out.push(']');
+    Ok(())
+}
+
+/// Decompose the S-box into squarings and multiplications by `x`, using
+/// left-to-right binary exponentiation.
+fn sbox_steps(alpha: Alpha) -> Vec<SboxStep> {
+    match alpha {
+        Alpha::Inverse => alloc::vec![SboxStep::Inverse],
+        Alpha::Exponent(exp) => {
+            let bits = 32 - exp.leading_zeros();
+            let mut steps = Vec::new();
+            for bit in (0..bits.saturating_sub(1)).rev() {
+                steps.push(SboxStep::Square);
+                if (exp >> bit) & 1 == 1 {
+                    steps.push(SboxStep::Multiply);
+                }
+            }
+            steps
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{inverse_sbox, Instance, RATE_2_PARAMS, RATE_4_PARAMS};
+
+    #[test]
+    fn sbox_decomposition() {
+        let x = Fq::from(0x1234_5678u64);
+        let mut x_17 = Fq::from(1u64);
+        for _ in 0..17 {
+            x_17 *= x;
+        }
+
+        let descriptor = Descriptor::new(&RATE_2_PARAMS);
+        assert_eq!(descriptor.sbox.len(), 5);
+        assert_eq!(descriptor.apply_sbox(x), x_17);
+
+        let descriptor = Descriptor::new(&inverse_sbox::RATE_2_PARAMS);
+        assert_eq!(descriptor.sbox, [SboxStep::Inverse]);
+        assert_eq!(descriptor.apply_sbox(x) * x, Fq::from(1u64));
+    }
+
+    #[test]
+    fn descriptor_matches_parameters() {
+        let descriptor = Descriptor::new(&RATE_4_PARAMS);
+        assert_eq!(descriptor.state_size, 5);
+        assert_eq!(descriptor.rate, 4);
+        assert_eq!(descriptor.round_constants.len(), 39);
+        assert_eq!(descriptor.mds.len(), 5);
+        assert_eq!(descriptor.mds[2][3], RATE_4_PARAMS.mds.0.get_element(2, 3));
+        assert_eq!(
+            descriptor.round_constants[38][4],
+            RATE_4_PARAMS.arc.get_element(38, 4)
+        );
+
+        let rounds: usize = descriptor.gates.iter().map(|g| g.rounds).sum();
+        assert_eq!(rounds, descriptor.round_constants.len());
+        assert_eq!(descriptor.multiplications(), 5 * (8 * 5 + 31));
+
+        let json = descriptor.to_json();
+        assert!(json.starts_with("{\"field\":\"decaf377-fq\""));
+        assert!(json.contains("\"alpha\":17,\"full_rounds\":8,\"partial_rounds\":31"));
+        assert!(json.contains(&alloc::format!(
+            "\"mds\":[[\"{}\"",
+            HashOutput(descriptor.mds[0][0])
+        )));
+        assert!(json.ends_with(
+            "{\"name\":\"full_round\",\"rounds\":4,\"sboxes\":5,\"multiplications\":25}]}"
+        ));
+    }
+
+    #[test]
+    fn reference_permutation() {
+        let mut state = [1u64, 2, 3, 4, 5].map(Fq::from);
+        let mut expected = state;
+        Descriptor::new(&RATE_4_PARAMS).permute(&mut state);
+
+        let mut instance = Instance::new(&RATE_4_PARAMS);
+        instance.n_to_1_fixed_hash(&expected);
+        expected = instance.output_words();
+        assert_eq!(state, expected);
+
+        let mut state = [0u64, 7, 8].map(Fq::from);
+        let mut expected = state;
+        Descriptor::new(&inverse_sbox::RATE_2_PARAMS).permute(&mut state);
+
+        let mut instance = Instance::new(&inverse_sbox::RATE_2_PARAMS);
+        instance.n_to_1_fixed_hash(&expected);
+        expected = instance.output_words();
+        assert_eq!(state, expected);
+    }
+}
@@ -1,8 +1,23 @@
-use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
-use decaf377::r1cs::FqVar;
+use core::convert::TryInto;
+
+use ark_r1cs_std::{
+    alloc::{AllocVar, AllocationMode},
+    eq::EqGadget,
+    fields::FieldVar,
+    R1CSVar,
+};
+use ark_relations::r1cs::{
+    ConstraintSystem, ConstraintSystemRef, OptimizationGoal, SynthesisError,
+};
+use ark_std::{vec, vec::Vec};
+use decaf377::r1cs::{ElementVar, FqVar};
 use poseidon_permutation::r1cs::InstanceVar;
+pub use poseidon_permutation::r1cs::Strategy;
 
-use crate::Fq;
+use crate::{
+    sponge::{DuplexMode, SPONGE_RATE},
+    Fq,
+};
 
 pub fn hash_1(
     cs: ConstraintSystemRef<Fq>,
@@ -111,3 +126,615 @@ pub fn hash_7(
         ],
     ))
 }
+
+/// Fixed-width hash gadgets, implemented for each number of elements `N`.
+///
+/// This allows circuit components such as trees or transcripts to be written once,
+/// generically over the arity, with a bound like `FixedHashGadget: HashGadget<N>`.
+pub trait HashGadget<const N: usize> {
+    /// Hash `N` [`FqVar`] elements with the provided `domain_separator`, using
+    /// [`Strategy::Constraints`].
+    fn hash(
+        cs: ConstraintSystemRef<Fq>,
+        domain_separator: &FqVar,
+        input: [FqVar; N],
+    ) -> Result<FqVar, SynthesisError> {
+        Self::hash_with_strategy(cs, domain_separator, input, Strategy::Constraints)
+    }
+
+    /// Hash `N` [`FqVar`] elements with the provided `domain_separator`, laying
+    /// out the permutation with `strategy`.
+    ///
+    /// Every strategy computes the same output; they only differ in the shape of
+    /// the constraints.
+    fn hash_with_strategy(
+        cs: ConstraintSystemRef<Fq>,
+        domain_separator: &FqVar,
+        input: [FqVar; N],
+        strategy: Strategy,
+    ) -> Result<FqVar, SynthesisError>;
+
+    /// Hash like [`HashGadget::hash`], then allocate the output with `mode` and
+    /// constrain it to equal the computed hash.
+    ///
+    /// With [`AllocationMode::Input`] the output becomes a public input, so a
+    /// statement like "this public digest is the hash of these private values"
+    /// needs no further wiring.
+    fn hash_with_mode(
+        cs: ConstraintSystemRef<Fq>,
+        domain_separator: &FqVar,
+        input: [FqVar; N],
+        mode: AllocationMode,
+    ) -> Result<FqVar, SynthesisError> {
+        let output = Self::hash(cs.clone(), domain_separator, input)?;
+        let allocated = FqVar::new_variable(cs, || output.value(), mode)?;
+        allocated.enforce_equal(&output)?;
+        Ok(allocated)
+    }
+
+    /// Allocate `N` inputs with `input_mode`, then hash them with
+    /// [`HashGadget::hash_with_mode`], allocating the output with `output_mode`.
+    ///
+    /// `values` is only called when the constraint system needs the assignment,
+    /// so this can also be used during setup. Returns the allocated inputs along
+    /// with the output.
+    fn alloc_and_hash(
+        cs: ConstraintSystemRef<Fq>,
+        domain_separator: &FqVar,
+        values: impl FnOnce() -> Result<[Fq; N], SynthesisError>,
+        input_mode: AllocationMode,
+        output_mode: AllocationMode,
+    ) -> Result<([FqVar; N], FqVar), SynthesisError> {
+        let mut values = Some(values);
+        let mut assignment = None;
+        let mut input = Vec::with_capacity(N);
+        for i in 0..N {
+            let word = FqVar::new_variable(
+                cs.clone(),
+                || {
+                    let assignment = assignment.get_or_insert_with(|| {
+                        (values.take().expect("values is only called once"))()
+                    });
+                    (*assignment).map(|values| values[i])
+                },
+                input_mode,
+            )?;
+            input.push(word);
+        }
+        let input: [FqVar; N] = input
+            .try_into()
+            .unwrap_or_else(|_| unreachable!("exactly N inputs were allocated"));
+
+        let output = Self::hash_with_mode(cs, domain_separator, input.clone(), output_mode)?;
+        Ok((input, output))
+    }
+}
+
+/// The gadgets [`hash_1`] to [`hash_7`], implementing [`HashGadget`] for `N` from
+/// `1` to `7`.
+///
+/// The round constants and MDS matrices are used as constants of the circuit, not
+/// allocated as variables, so repeated calls share nothing that would need to be
+/// prepared once: each call only allocates the witnesses of its own S-boxes.
+pub struct FixedHashGadget;
+
+macro_rules! impl_hash_gadget {
+    ($n:literal, $params:ident) => {
+        impl HashGadget<$n> for FixedHashGadget {
+            fn hash_with_strategy(
+                cs: ConstraintSystemRef<Fq>,
+                domain_separator: &FqVar,
+                input: [FqVar; $n],
+                strategy: Strategy,
+            ) -> Result<FqVar, SynthesisError> {
+                let state: [FqVar; $n + 1] = core::array::from_fn(|i| match i {
+                    0 => domain_separator.clone(),
+                    _ => input[i - 1].clone(),
+                });
+                InstanceVar::n_to_1_fixed_hash_with_strategy(
+                    crate::$params.clone(),
+                    cs,
+                    state,
+                    strategy,
+                )
+            }
+        }
+    };
+}
+
+impl_hash_gadget!(1, RATE_1_PARAMS);
+impl_hash_gadget!(2, RATE_2_PARAMS);
+impl_hash_gadget!(3, RATE_3_PARAMS);
+impl_hash_gadget!(4, RATE_4_PARAMS);
+impl_hash_gadget!(5, RATE_5_PARAMS);
+impl_hash_gadget!(6, RATE_6_PARAMS);
+impl_hash_gadget!(7, RATE_7_PARAMS);
+impl_hash_gadget!(8, RATE_8_PARAMS);
+impl_hash_gadget!(9, RATE_9_PARAMS);
+impl_hash_gadget!(10, RATE_10_PARAMS);
+impl_hash_gadget!(11, RATE_11_PARAMS);
+impl_hash_gadget!(12, RATE_12_PARAMS);
+impl_hash_gadget!(13, RATE_13_PARAMS);
+impl_hash_gadget!(14, RATE_14_PARAMS);
+impl_hash_gadget!(15, RATE_15_PARAMS);
+impl_hash_gadget!(16, RATE_16_PARAMS);
+
+/// The size of the constraints generated by a gadget.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ConstraintReport {
+    /// The number of constraints.
+    pub constraints: usize,
+    /// The number of witness variables allocated by the gadget, not counting its inputs.
+    pub witnesses: usize,
+    /// The number of non-zero entries in the `A`, `B` and `C` constraint matrices,
+    /// once linear combinations are inlined.
+    pub non_zero_entries: usize,
+}
+
+/// Measure the gadget hashing `N` elements, for `N` from 1 to 16, with witness
+/// inputs and a constant domain separator.
+///
+/// This synthesizes the gadget in a throwaway constraint system optimized for the
+/// number of constraints, the default when proving. Constant inputs are folded by
+/// the gadgets: words of the state that only depend on constants are computed
+/// natively, so the S-box of the domain separator in the first round is free, and
+/// hashing only constants takes no constraints at all.
+pub fn profile<const N: usize>() -> ConstraintReport
+where
+    FixedHashGadget: HashGadget<N>,
+{
+    profile_with_strategy::<N>(Strategy::Constraints)
+}
+
+/// Measure the gadget hashing `N` elements as [`profile`] does, laying out the
+/// permutation with `strategy`.
+pub fn profile_with_strategy<const N: usize>(strategy: Strategy) -> ConstraintReport
+where
+    FixedHashGadget: HashGadget<N>,
+{
+    let cs = ConstraintSystem::<Fq>::new_ref();
+    cs.set_optimization_goal(OptimizationGoal::Constraints);
+
+    let domain_separator = FqVar::constant(Fq::from(0u64));
+    let input = [(); N]
+        .map(|()| FqVar::new_witness(cs.clone(), || Ok(Fq::from(0u64))).expect("can allocate"));
+    let _output =
+        FixedHashGadget::hash_with_strategy(cs.clone(), &domain_separator, input, strategy)
+            .expect("can synthesize");
+
+    let witnesses = cs.num_witness_variables() - N;
+    cs.finalize();
+    let matrices = cs
+        .to_matrices()
+        .expect("constraint system constructs matrices");
+    ConstraintReport {
+        constraints: matrices.num_constraints,
+        witnesses,
+        non_zero_entries: matrices.a_num_non_zero
+            + matrices.b_num_non_zero
+            + matrices.c_num_non_zero,
+    }
+}
+
+/// Hash an arbitrary number of [`FqVar`] elements with the provided `domain_separator`.
+///
+/// This is the in-circuit counterpart of [`hash_varlen`](crate::hash_varlen). The
+/// number of elements is fixed by the circuit, so the padding costs no constraints.
+pub fn hash_varlen(
+    cs: ConstraintSystemRef<Fq>,
+    domain_separator: &FqVar,
+    input: &[FqVar],
+) -> Result<FqVar, SynthesisError> {
+    let mut sponge = DuplexSpongeVar::new(cs, domain_separator);
+    sponge.absorb(input);
+    Ok(sponge.squeeze())
+}
+
+/// Generate `len` elements of the keystream for `key` and `nonce`.
+///
+/// This is the in-circuit counterpart of [`keystream`](crate::keystream).
+pub fn keystream(
+    cs: ConstraintSystemRef<Fq>,
+    key: &FqVar,
+    nonce: &FqVar,
+    len: usize,
+) -> Result<Vec<FqVar>, SynthesisError> {
+    let capacity = hash_1(
+        cs.clone(),
+        &FqVar::constant(crate::hash::KEYSTREAM_DOMAIN_SEP),
+        key.clone(),
+    )?;
+    let mut sponge = DuplexSpongeVar::from_capacity(cs, &capacity);
+    sponge.absorb(&[nonce.clone()]);
+    Ok(sponge.squeeze_n(len))
+}
+
+/// Hash an arbitrary number of [`FqVar`] elements to a `decaf377` group element
+/// with the provided `domain_separator`.
+///
+/// This is the in-circuit counterpart of [`hash_to_group`](crate::hash_to_group).
+pub fn hash_to_group(
+    cs: ConstraintSystemRef<Fq>,
+    domain_separator: &FqVar,
+    input: &[FqVar],
+) -> Result<ElementVar, SynthesisError> {
+    let group_domain_sep = hash_1(
+        cs.clone(),
+        &FqVar::constant(crate::hash::GROUP_DOMAIN_SEP),
+        domain_separator.clone(),
+    )?;
+    let mut sponge = DuplexSpongeVar::new(cs, &group_domain_sep);
+    sponge.absorb(input);
+    let r = sponge.squeeze_n(2);
+    Ok(ElementVar::encode_to_curve(&r[0])? + ElementVar::encode_to_curve(&r[1])?)
+}
+
+/// The rate-4 instance of Poseidon that the sponge gadgets are built on.
+type SpongeInstanceVar = InstanceVar<5, 4, 25, 16, 39, 5, 195, 31>;
+
+/// The in-circuit counterpart of [`DuplexSponge`](crate::DuplexSponge).
+///
+/// Absorbing and squeezing the same sequence of words produces the same outputs as
+/// the native sponge. Whether the sponge is absorbing or squeezing, and at which
+/// position, only depends on the sequence of calls, so the padding and the choice of
+/// when to permute are fixed by the circuit and cost no constraints.
+#[derive(Clone)]
+pub struct DuplexSpongeVar {
+    instance: SpongeInstanceVar,
+    mode: DuplexMode,
+}
+
+impl DuplexSpongeVar {
+    /// Create a new duplex sponge for the provided `domain_separator`.
+    ///
+    /// As for the native sponge, the capacity is the hash of the `domain_separator`,
+    /// which costs no constraints if the `domain_separator` is a constant.
+    pub fn new(cs: ConstraintSystemRef<Fq>, domain_separator: &FqVar) -> Self {
+        let capacity = InstanceVar::n_to_1_fixed_hash(
+            crate::RATE_1_PARAMS.clone(),
+            cs.clone(),
+            [
+                FqVar::constant(crate::sponge::SPONGE_DOMAIN_SEP),
+                domain_separator.clone(),
+            ],
+        );
+        Self::from_capacity(cs, &capacity)
+    }
+
+    /// Create a new duplex sponge with `capacity` as its capacity word, such as a key.
+    pub(crate) fn from_capacity(cs: ConstraintSystemRef<Fq>, capacity: &FqVar) -> Self {
+        let mut state_words = vec![capacity.clone()];
+        state_words.extend((0..SPONGE_RATE).map(|_| FqVar::zero()));
+        Self {
+            instance: InstanceVar {
+                parameters: crate::RATE_4_PARAMS.clone(),
+                cs,
+                state_words,
+            },
+            mode: DuplexMode::Absorbing(0),
+        }
+    }
+
+    /// The constraint system the sponge is operating in.
+    pub fn cs(&self) -> ConstraintSystemRef<Fq> {
+        self.instance.cs.clone()
+    }
+
+    /// Absorb `input` words into the sponge.
+    pub fn absorb(&mut self, input: &[FqVar]) {
+        for word in input {
+            let mut pos = match self.mode {
+                DuplexMode::Absorbing(pos) => pos,
+                DuplexMode::Squeezing(_) => 0,
+            };
+
+            self.instance.state_words[1 + pos] += word;
+            pos += 1;
+            if pos == SPONGE_RATE {
+                self.instance.permute();
+                pos = 0;
+            }
+            self.mode = DuplexMode::Absorbing(pos);
+        }
+    }
+
+    /// Squeeze a single word out of the sponge.
+    pub fn squeeze(&mut self) -> FqVar {
+        let mut pos = match self.mode {
+            DuplexMode::Absorbing(pos) => {
+                self.instance.state_words[1 + pos] += FqVar::one();
+                self.instance.permute();
+                0
+            }
+            DuplexMode::Squeezing(pos) => pos,
+        };
+
+        if pos == SPONGE_RATE {
+            self.instance.permute();
+            pos = 0;
+        }
+        let word = self.instance.state_words[1 + pos].clone();
+        self.mode = DuplexMode::Squeezing(pos + 1);
+        word
+    }
+
+    /// Squeeze `n` words out of the sponge.
+    ///
+    /// This is the in-circuit counterpart of
+    /// [`DuplexSponge::squeeze_into`](crate::DuplexSponge::squeeze_into).
+    pub fn squeeze_n(&mut self, n: usize) -> Vec<FqVar> {
+        (0..n).map(|_| self.squeeze()).collect()
+    }
+}
+
+/// The in-circuit counterpart of [`Transcript`](crate::Transcript).
+///
+/// Labels are hashed outside of the circuit, so they cost no constraints. Appending
+/// the same labeled messages and extracting challenges with the same labels in the
+/// same order produces the same challenges as the native transcript.
+pub struct TranscriptVar {
+    sponge: DuplexSpongeVar,
+}
+
+impl TranscriptVar {
+    /// Create a new transcript for the protocol identified by `label`.
+    pub fn new(cs: ConstraintSystemRef<Fq>, label: &[u8]) -> Self {
+        let domain_separator = FqVar::constant(crate::transcript::label_element(label));
+        Self {
+            sponge: DuplexSpongeVar::new(cs, &domain_separator),
+        }
+    }
+
+    /// Append a `message` to the transcript under `label`.
+    pub fn append_message(&mut self, label: &[u8], message: &[FqVar]) {
+        self.sponge.absorb(&[
+            FqVar::constant(crate::transcript::label_element(label)),
+            FqVar::constant(Fq::from(message.len() as u64)),
+        ]);
+        self.sponge.absorb(message);
+    }
+
+    /// Extract a challenge under `label`, binding all messages appended so far.
+    pub fn challenge(&mut self, label: &[u8]) -> FqVar {
+        self.sponge
+            .absorb(&[FqVar::constant(crate::transcript::label_element(label))]);
+        self.sponge.squeeze()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn profile_counts() {
+        // Each S-box `x^17` takes five multiplications, and the instance for `N`
+        // elements has eight full rounds of `N + 1` S-boxes and 31 partial rounds,
+        // less the S-box of the constant domain separator in the first round.
+        let sboxes = |n: usize| 8 * (n + 1) + 31 - 1;
+
+        let reports = [
+            profile::<1>(),
+            profile::<2>(),
+            profile::<3>(),
+            profile::<4>(),
+            profile::<5>(),
+            profile::<6>(),
+            profile::<7>(),
+            profile::<8>(),
+            profile::<9>(),
+            profile::<10>(),
+            profile::<11>(),
+            profile::<12>(),
+            profile::<13>(),
+            profile::<14>(),
+            profile::<15>(),
+            profile::<16>(),
+        ];
+        let expected = [
+            (230, 2538),
+            (270, 2949),
+            (310, 3402),
+            (350, 3897),
+            (390, 4434),
+            (430, 5013),
+            (470, 5634),
+            (510, 6297),
+            (550, 7002),
+            (590, 7749),
+            (630, 8538),
+            (670, 9369),
+            (710, 10242),
+            (750, 11157),
+            (790, 12114),
+            (830, 13113),
+        ];
+        for (i, (report, (constraints, non_zero_entries))) in
+            reports.iter().zip(expected).enumerate()
+        {
+            let n = i + 1;
+            assert_eq!(report.constraints, 5 * sboxes(n));
+            assert_eq!(report.constraints, constraints, "N = {}", n);
+            assert_eq!(report.witnesses, constraints, "N = {}", n);
+            assert_eq!(report.non_zero_entries, non_zero_entries, "N = {}", n);
+        }
+    }
+
+    /// A generic component, written once over the arity.
+    fn hash_gadget_matches_native<const N: usize>()
+    where
+        FixedHashGadget: HashGadget<N>,
+    {
+        use ark_r1cs_std::R1CSVar;
+
+        let domain_separator = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input: [Fq; N] = core::array::from_fn(|i| Fq::from(i as u64 + 1));
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let domain_separator_var = FqVar::new_constant(cs.clone(), domain_separator).unwrap();
+        let input_vars = input.map(|x| FqVar::new_witness(cs.clone(), || Ok(x)).unwrap());
+        let output = FixedHashGadget::hash(cs.clone(), &domain_separator_var, input_vars).unwrap();
+        assert_eq!(
+            output.value().unwrap(),
+            crate::hash_fixed(&domain_separator, input)
+        );
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn hash_gadgets() {
+        hash_gadget_matches_native::<1>();
+        hash_gadget_matches_native::<2>();
+        hash_gadget_matches_native::<3>();
+        hash_gadget_matches_native::<4>();
+        hash_gadget_matches_native::<5>();
+        hash_gadget_matches_native::<6>();
+        hash_gadget_matches_native::<7>();
+        hash_gadget_matches_native::<8>();
+        hash_gadget_matches_native::<9>();
+        hash_gadget_matches_native::<10>();
+        hash_gadget_matches_native::<11>();
+        hash_gadget_matches_native::<12>();
+        hash_gadget_matches_native::<13>();
+        hash_gadget_matches_native::<14>();
+        hash_gadget_matches_native::<15>();
+        hash_gadget_matches_native::<16>();
+    }
+
+    #[test]
+    fn strategies() {
+        use ark_r1cs_std::R1CSVar;
+
+        let domain_separator = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input = [1u64, 2, 3, 4].map(Fq::from);
+        let expected = crate::hash_fixed(&domain_separator, input);
+
+        for strategy in [Strategy::Constraints, Strategy::Weight] {
+            let cs = ConstraintSystem::<Fq>::new_ref();
+            let input_vars = input.map(|x| FqVar::new_witness(cs.clone(), || Ok(x)).unwrap());
+            let output = FixedHashGadget::hash_with_strategy(
+                cs.clone(),
+                &FqVar::constant(domain_separator),
+                input_vars,
+                strategy,
+            )
+            .unwrap();
+            assert_eq!(output.value().unwrap(), expected, "{:?}", strategy);
+            assert!(cs.is_satisfied().unwrap());
+        }
+
+        // Allocating the state during the partial rounds trades a few extra
+        // constraints for much sparser matrices.
+        let constraints = profile_with_strategy::<4>(Strategy::Constraints);
+        let weight = profile_with_strategy::<4>(Strategy::Weight);
+        assert_eq!(
+            (constraints.constraints, constraints.non_zero_entries),
+            (350, 3897)
+        );
+        assert_eq!((weight.constraints, weight.non_zero_entries), (385, 2319));
+        assert_eq!(profile::<4>(), constraints);
+    }
+
+    #[test]
+    fn constant_folding() {
+        use ark_r1cs_std::R1CSVar;
+
+        let domain_separator = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input = [Fq::from(1u64), Fq::from(2u64)];
+        let expected = crate::hash_2(&domain_separator, (input[0], input[1]));
+
+        // Only constants: the output is a constant, computed without constraints.
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let output = hash_2(
+            cs.clone(),
+            &FqVar::constant(domain_separator),
+            (FqVar::constant(input[0]), FqVar::constant(input[1])),
+        )
+        .unwrap();
+        assert!(output.is_constant());
+        assert_eq!(output.value().unwrap(), expected);
+        assert_eq!(cs.num_constraints(), 0);
+        assert_eq!(cs.num_witness_variables(), 0);
+
+        // A witness domain separator costs one more S-box than a constant one.
+        let count = |domain_separator: FqVar, cs: ConstraintSystemRef<Fq>| {
+            let input = input.map(|x| FqVar::new_witness(cs.clone(), || Ok(x)).unwrap());
+            let output = FixedHashGadget::hash(cs.clone(), &domain_separator, input).unwrap();
+            assert_eq!(output.value().unwrap(), expected);
+            assert!(cs.is_satisfied().unwrap());
+            cs.num_constraints()
+        };
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let constant = count(FqVar::constant(domain_separator), cs);
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let witness = FqVar::new_witness(cs.clone(), || Ok(domain_separator)).unwrap();
+        assert_eq!(count(witness, cs), constant + 5);
+    }
+
+    #[test]
+    fn repeated_hashes_allocate_only_sboxes() {
+        let domain_separator = FqVar::constant(Fq::from(1u64));
+        let single = profile::<2>();
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let mut node = FqVar::new_witness(cs.clone(), || Ok(Fq::from(0u64))).unwrap();
+        for _ in 0..10 {
+            let sibling = FqVar::new_witness(cs.clone(), || Ok(Fq::from(0u64))).unwrap();
+            node = FixedHashGadget::hash(cs.clone(), &domain_separator, [node, sibling]).unwrap();
+        }
+        assert!(cs.is_satisfied().unwrap());
+        assert_eq!(cs.num_constraints(), 10 * single.constraints);
+        assert_eq!(cs.num_witness_variables(), 11 + 10 * single.witnesses);
+        assert_eq!(cs.num_instance_variables(), 1);
+    }
+
+    #[test]
+    fn allocation_modes() {
+        use ark_relations::r1cs::SynthesisMode;
+
+        let domain_separator = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input = [Fq::from(1u64), Fq::from(2u64), Fq::from(3u64)];
+        let expected = crate::hash_3(&domain_separator, (input[0], input[1], input[2]));
+
+        // Private inputs, public output: a single new instance variable.
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let (words, output) = FixedHashGadget::alloc_and_hash(
+            cs.clone(),
+            &FqVar::constant(domain_separator),
+            || Ok(input),
+            AllocationMode::Witness,
+            AllocationMode::Input,
+        )
+        .unwrap();
+        assert_eq!(cs.num_instance_variables(), 2);
+        assert_eq!(words.value().unwrap(), input.to_vec());
+        assert_eq!(output.value().unwrap(), expected);
+        assert!(cs.is_satisfied().unwrap());
+
+        // Public inputs and output.
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let _allocated = FixedHashGadget::alloc_and_hash(
+            cs.clone(),
+            &FqVar::constant(domain_separator),
+            || Ok(input),
+            AllocationMode::Input,
+            AllocationMode::Input,
+        )
+        .unwrap();
+        assert_eq!(cs.num_instance_variables(), 5);
+        assert!(cs.is_satisfied().unwrap());
+
+        // Setup never asks for the values.
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        cs.set_mode(SynthesisMode::Setup);
+        let _allocated = FixedHashGadget::alloc_and_hash(
+            cs.clone(),
+            &FqVar::constant(domain_separator),
+            || -> Result<[Fq; 3], _> { panic!("values requested during setup") },
+            AllocationMode::Witness,
+            AllocationMode::Input,
+        )
+        .unwrap();
+        assert_eq!(cs.num_instance_variables(), 2);
+    }
+}
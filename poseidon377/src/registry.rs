@@ -0,0 +1,252 @@
+//! Stable names for the parameter sets provided by this crate.
+//!
+//! Each parameter set has a name of the form `<family>/rate-<r>/<version>`, e.g.
+//! `poseidon377/rate-2/v1` for [`RATE_2_PARAMS`](crate::RATE_2_PARAMS), so that
+//! protocols can negotiate an instance by name and look it up with [`by_name`].
+//! The families are `poseidon377` for the `x^17` instances, `poseidon377-inverse`
+//! for the [`inverse_sbox`] instances and `poseidon377-256` for the [`security_256`]
+//! instances, and the versions are `v1` for Poseidon and `v2` for
+//! [Poseidon2](crate::v2).
+//!
+//! Names are matched exactly, and a name is never reassigned to different
+//! parameters: new parameter sets, including ones over other fields, get new names.
+
+#[cfg(feature = "alloc")]
+use poseidon_parameters::v1::DynPoseidonParameters;
+
+#[cfg(feature = "alloc")]
+use crate::{inverse_sbox, security_256};
+
+/// A parameter set provided by this crate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ParameterSet {
+    /// The Poseidon instance with the `x^17` S-box at the crate root, e.g.
+    /// [`RATE_2_PARAMS`](crate::RATE_2_PARAMS).
+    V1 {
+        /// Rate of the instance.
+        rate: usize,
+    },
+    /// The Poseidon instance with the inverse S-box in [`inverse_sbox`].
+    V1Inverse {
+        /// Rate of the instance.
+        rate: usize,
+    },
+    /// The Poseidon instance at the 256-bit security level in
+    /// [`security_256`](crate::security_256), with a capacity of two elements.
+    V1Security256 {
+        /// Rate of the instance.
+        rate: usize,
+    },
+    /// The Poseidon2 instance in [`v2`](crate::v2).
+    V2 {
+        /// Rate of the instance.
+        rate: usize,
+    },
+}
+
+/// Every parameter set with its name.
+const REGISTRY: &[(&str, ParameterSet)] = &[
+    ("poseidon377/rate-1/v1", ParameterSet::V1 { rate: 1 }),
+    ("poseidon377/rate-2/v1", ParameterSet::V1 { rate: 2 }),
+    ("poseidon377/rate-3/v1", ParameterSet::V1 { rate: 3 }),
+    ("poseidon377/rate-4/v1", ParameterSet::V1 { rate: 4 }),
+    ("poseidon377/rate-5/v1", ParameterSet::V1 { rate: 5 }),
+    ("poseidon377/rate-6/v1", ParameterSet::V1 { rate: 6 }),
+    ("poseidon377/rate-7/v1", ParameterSet::V1 { rate: 7 }),
+    ("poseidon377/rate-8/v1", ParameterSet::V1 { rate: 8 }),
+    ("poseidon377/rate-9/v1", ParameterSet::V1 { rate: 9 }),
+    ("poseidon377/rate-10/v1", ParameterSet::V1 { rate: 10 }),
+    ("poseidon377/rate-11/v1", ParameterSet::V1 { rate: 11 }),
+    ("poseidon377/rate-12/v1", ParameterSet::V1 { rate: 12 }),
+    ("poseidon377/rate-13/v1", ParameterSet::V1 { rate: 13 }),
+    ("poseidon377/rate-14/v1", ParameterSet::V1 { rate: 14 }),
+    ("poseidon377/rate-15/v1", ParameterSet::V1 { rate: 15 }),
+    ("poseidon377/rate-16/v1", ParameterSet::V1 { rate: 16 }),
+    (
+        "poseidon377-inverse/rate-1/v1",
+        ParameterSet::V1Inverse { rate: 1 },
+    ),
+    (
+        "poseidon377-inverse/rate-2/v1",
+        ParameterSet::V1Inverse { rate: 2 },
+    ),
+    (
+        "poseidon377-inverse/rate-3/v1",
+        ParameterSet::V1Inverse { rate: 3 },
+    ),
+    (
+        "poseidon377-inverse/rate-4/v1",
+        ParameterSet::V1Inverse { rate: 4 },
+    ),
+    (
+        "poseidon377-inverse/rate-5/v1",
+        ParameterSet::V1Inverse { rate: 5 },
+    ),
+    (
+        "poseidon377-inverse/rate-6/v1",
+        ParameterSet::V1Inverse { rate: 6 },
+    ),
+    (
+        "poseidon377-inverse/rate-7/v1",
+        ParameterSet::V1Inverse { rate: 7 },
+    ),
+    (
+        "poseidon377-256/rate-1/v1",
+        ParameterSet::V1Security256 { rate: 1 },
+    ),
+    (
+        "poseidon377-256/rate-2/v1",
+        ParameterSet::V1Security256 { rate: 2 },
+    ),
+    ("poseidon377/rate-1/v2", ParameterSet::V2 { rate: 1 }),
+    ("poseidon377/rate-2/v2", ParameterSet::V2 { rate: 2 }),
+];
+
+/// Look up the parameter set with the given `name`, e.g. `poseidon377/rate-2/v1`.
+///
+/// Returns `None` for unknown names.
+pub fn by_name(name: &str) -> Option<ParameterSet> {
+    REGISTRY
+        .iter()
+        .find(|(entry, _)| *entry == name)
+        .map(|(_, set)| *set)
+}
+
+/// The names of all parameter sets, in the order of [`ParameterSet::all`].
+pub fn names() -> impl Iterator<Item = &'static str> {
+    REGISTRY.iter().map(|(name, _)| *name)
+}
+
+impl ParameterSet {
+    /// All parameter sets provided by this crate.
+    pub fn all() -> impl Iterator<Item = ParameterSet> {
+        REGISTRY.iter().map(|(_, set)| *set)
+    }
+
+    /// The stable name of this parameter set, or `None` if no such set exists, e.g.
+    /// for [`ParameterSet::V1`] with a rate above 16.
+    pub fn name(&self) -> Option<&'static str> {
+        REGISTRY
+            .iter()
+            .find(|(_, set)| set == self)
+            .map(|(name, _)| *name)
+    }
+
+    /// The rate of this parameter set.
+    pub fn rate(&self) -> usize {
+        match self {
+            ParameterSet::V1 { rate }
+            | ParameterSet::V1Inverse { rate }
+            | ParameterSet::V1Security256 { rate }
+            | ParameterSet::V2 { rate } => *rate,
+        }
+    }
+
+    /// The Poseidon parameters of this set, for use with
+    /// [`DynInstance`](poseidon_permutation::DynInstance).
+    ///
+    /// Returns `None` for Poseidon2 sets, which have no runtime-sized representation,
+    /// for sets that do not exist, and for [`ParameterSet::V1`] sets whose `rate-<r>`
+    /// feature is disabled.
+    #[cfg(feature = "alloc")]
+    pub fn dyn_parameters(&self) -> Option<DynPoseidonParameters> {
+        match self {
+            #[cfg(feature = "rate-1")]
+            ParameterSet::V1 { rate: 1 } => Some((&crate::RATE_1_PARAMS).into()),
+            #[cfg(feature = "rate-2")]
+            ParameterSet::V1 { rate: 2 } => Some((&crate::RATE_2_PARAMS).into()),
+            #[cfg(feature = "rate-3")]
+            ParameterSet::V1 { rate: 3 } => Some((&crate::RATE_3_PARAMS).into()),
+            #[cfg(feature = "rate-4")]
+            ParameterSet::V1 { rate: 4 } => Some((&crate::RATE_4_PARAMS).into()),
+            #[cfg(feature = "rate-5")]
+            ParameterSet::V1 { rate: 5 } => Some((&crate::RATE_5_PARAMS).into()),
+            #[cfg(feature = "rate-6")]
+            ParameterSet::V1 { rate: 6 } => Some((&crate::RATE_6_PARAMS).into()),
+            #[cfg(feature = "rate-7")]
+            ParameterSet::V1 { rate: 7 } => Some((&crate::RATE_7_PARAMS).into()),
+            #[cfg(feature = "rate-8")]
+            ParameterSet::V1 { rate: 8 } => Some((&crate::RATE_8_PARAMS).into()),
+            #[cfg(feature = "rate-9")]
+            ParameterSet::V1 { rate: 9 } => Some((&crate::RATE_9_PARAMS).into()),
+            #[cfg(feature = "rate-10")]
+            ParameterSet::V1 { rate: 10 } => Some((&crate::RATE_10_PARAMS).into()),
+            #[cfg(feature = "rate-11")]
+            ParameterSet::V1 { rate: 11 } => Some((&crate::RATE_11_PARAMS).into()),
+            #[cfg(feature = "rate-12")]
+            ParameterSet::V1 { rate: 12 } => Some((&crate::RATE_12_PARAMS).into()),
+            #[cfg(feature = "rate-13")]
+            ParameterSet::V1 { rate: 13 } => Some((&crate::RATE_13_PARAMS).into()),
+            #[cfg(feature = "rate-14")]
+            ParameterSet::V1 { rate: 14 } => Some((&crate::RATE_14_PARAMS).into()),
+            #[cfg(feature = "rate-15")]
+            ParameterSet::V1 { rate: 15 } => Some((&crate::RATE_15_PARAMS).into()),
+            #[cfg(feature = "rate-16")]
+            ParameterSet::V1 { rate: 16 } => Some((&crate::RATE_16_PARAMS).into()),
+            ParameterSet::V1Inverse { rate: 1 } => Some((&inverse_sbox::RATE_1_PARAMS).into()),
+            ParameterSet::V1Inverse { rate: 2 } => Some((&inverse_sbox::RATE_2_PARAMS).into()),
+            ParameterSet::V1Inverse { rate: 3 } => Some((&inverse_sbox::RATE_3_PARAMS).into()),
+            ParameterSet::V1Inverse { rate: 4 } => Some((&inverse_sbox::RATE_4_PARAMS).into()),
+            ParameterSet::V1Inverse { rate: 5 } => Some((&inverse_sbox::RATE_5_PARAMS).into()),
+            ParameterSet::V1Inverse { rate: 6 } => Some((&inverse_sbox::RATE_6_PARAMS).into()),
+            ParameterSet::V1Inverse { rate: 7 } => Some((&inverse_sbox::RATE_7_PARAMS).into()),
+            ParameterSet::V1Security256 { rate: 1 } => Some((&security_256::RATE_1_PARAMS).into()),
+            ParameterSet::V1Security256 { rate: 2 } => Some((&security_256::RATE_2_PARAMS).into()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn names_round_trip() {
+        assert_eq!(names().count(), 27);
+        for (name, set) in names().zip(ParameterSet::all()) {
+            assert_eq!(by_name(name), Some(set));
+            assert_eq!(set.name(), Some(name));
+        }
+        assert_eq!(
+            by_name("poseidon377/rate-2/v1"),
+            Some(ParameterSet::V1 { rate: 2 })
+        );
+        assert_eq!(by_name("poseidon377/rate-17/v1"), None);
+        assert_eq!(by_name("Poseidon377/rate-2/v1"), None);
+        assert_eq!(ParameterSet::V2 { rate: 3 }.name(), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn dyn_parameters() {
+        let parameters = by_name("poseidon377/rate-2/v1")
+            .and_then(|set| set.dyn_parameters())
+            .unwrap();
+        assert_eq!(
+            parameters,
+            DynPoseidonParameters::from(crate::RATE_2_PARAMS)
+        );
+
+        let parameters = by_name("poseidon377-inverse/rate-7/v1")
+            .and_then(|set| set.dyn_parameters())
+            .unwrap();
+        assert_eq!(
+            parameters,
+            DynPoseidonParameters::from(inverse_sbox::RATE_7_PARAMS)
+        );
+
+        for set in ParameterSet::all() {
+            let parameters = set.dyn_parameters();
+            match set {
+                ParameterSet::V2 { .. } => assert!(parameters.is_none()),
+                ParameterSet::V1Security256 { .. } => assert_eq!(
+                    parameters.unwrap().state_size(),
+                    set.rate() + security_256::CAPACITY
+                ),
+                _ => assert_eq!(parameters.unwrap().state_size(), set.rate() + 1),
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+use crate::{sponge::Sponge, Fq, ToFieldElements, Xof};
+
+/// Domain separator used to seed [`PoseidonRng`], `from_le_bytes_mod_order(b"poseidon377.rng")`.
+const RNG_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    14564492886596824310,
+    12434560349434681944,
+    6908559969219473921,
+    248781003207250787,
+]);
+
+/// A deterministic random number generator whose output is squeezed from the sponge.
+///
+/// The generator is an [`Xof`] over the rate-4 sponge with a domain separator
+/// reserved for this type, which has absorbed the seed. Elements squeezed with
+/// [`PoseidonRng::fill_elements`] can therefore be recomputed in a circuit, and
+/// bytes are extracted from squeezed elements as with [`Xof::squeeze_bytes_into`].
+///
+/// Seeding with [`SeedableRng::from_seed`] absorbs the [`ToFieldElements`] encoding
+/// of the 32 seed bytes, and [`PoseidonRng::from_seed_element`] absorbs a single
+/// element. The output is only unpredictable if the seed is.
+///
+/// With the `zeroize` feature, the sponge state and any buffered output bytes are
+/// wiped when the generator is dropped.
+pub struct PoseidonRng {
+    xof: Xof,
+}
+
+impl PoseidonRng {
+    /// Create a generator seeded with a single field element.
+    pub fn from_seed_element(seed: Fq) -> Self {
+        let mut sponge = Sponge::new(&RNG_DOMAIN_SEP);
+        sponge.absorb(&[seed]);
+        Self {
+            xof: Xof::new(sponge),
+        }
+    }
+
+    /// Fill `output` with uniformly random field elements.
+    pub fn fill_elements(&mut self, output: &mut [Fq]) {
+        self.xof.squeeze_into(output);
+    }
+}
+
+impl SeedableRng for PoseidonRng {
+    type Seed = [u8; 32];
+
+    fn from_seed(seed: [u8; 32]) -> Self {
+        let mut elements = SeedElements::default();
+        seed[..].append_field_elements(&mut elements);
+
+        let mut sponge = Sponge::new(&RNG_DOMAIN_SEP);
+        sponge.absorb(&elements.elements[..elements.len]);
+        Self {
+            xof: Xof::new(sponge),
+        }
+    }
+}
+
+impl RngCore for PoseidonRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.xof.squeeze_bytes_into(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for PoseidonRng {}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for PoseidonRng {}
+
+/// The encoding of a 32-byte seed: its length followed by two chunks.
+#[derive(Default)]
+struct SeedElements {
+    elements: [Fq; 3],
+    len: usize,
+}
+
+impl Extend<Fq> for SeedElements {
+    fn extend<I: IntoIterator<Item = Fq>>(&mut self, iter: I) {
+        for element in iter {
+            self.elements[self.len] = element;
+            self.len += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hash_bytes;
+
+    #[test]
+    fn rng_outputs() {
+        assert_eq!(
+            RNG_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.rng")
+        );
+
+        // The first element is the hash of the seed.
+        let seed = [7u8; 32];
+        let mut rng = PoseidonRng::from_seed(seed);
+        let mut elements = [Fq::from(0u64); 2];
+        rng.fill_elements(&mut elements);
+        assert_eq!(elements[0], hash_bytes(&RNG_DOMAIN_SEP, &seed));
+
+        // The byte stream does not depend on how it is split into calls.
+        let mut rng_1 = PoseidonRng::from_seed_element(Fq::from(1u64));
+        let mut rng_2 = PoseidonRng::from_seed_element(Fq::from(1u64));
+        let mut bytes = [0u8; 40];
+        rng_1.fill_bytes(&mut bytes);
+        assert_eq!(rng_2.next_u64().to_le_bytes(), bytes[..8]);
+        assert_eq!(rng_2.next_u32().to_le_bytes(), bytes[8..12]);
+
+        let mut rng_3 = PoseidonRng::from_seed_element(Fq::from(2u64));
+        assert_ne!(rng_3.next_u64().to_le_bytes(), bytes[..8]);
+    }
+}
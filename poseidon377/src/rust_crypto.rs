@@ -0,0 +1,118 @@
+use alloc::vec::Vec;
+
+use digest::{
+    consts::U32, FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
+
+use crate::{hash_bytes, Fq};
+
+/// Domain separator used by [`PoseidonDigest`], `from_le_bytes_mod_order(b"poseidon377.digest")`.
+const DIGEST_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    13334123451907442483,
+    3485726270208844219,
+    2328164387378491768,
+    574990454709945087,
+]);
+
+/// An adapter implementing the RustCrypto [`digest`] traits over [`hash_bytes`].
+///
+/// The output is the canonical 32-byte little-endian encoding of [`hash_bytes`]
+/// over all of the input, with a domain separator reserved for this adapter unless
+/// one is given with [`PoseidonDigest::with_domain_separator`].
+///
+/// Since the byte encoding starts with the length of the input, the input is
+/// buffered until the digest is finalized.
+#[derive(Clone, Debug)]
+pub struct PoseidonDigest {
+    domain_separator: Fq,
+    buffer: Vec<u8>,
+}
+
+impl PoseidonDigest {
+    /// Create a digest hashing with the provided `domain_separator`.
+    pub fn with_domain_separator(domain_separator: &Fq) -> Self {
+        Self {
+            domain_separator: *domain_separator,
+            buffer: Vec::new(),
+        }
+    }
+
+    fn finalize_bytes(&self, out: &mut Output<Self>) {
+        let hash = hash_bytes(&self.domain_separator, &self.buffer);
+        out.copy_from_slice(&hash.to_bytes());
+    }
+}
+
+impl Default for PoseidonDigest {
+    fn default() -> Self {
+        Self::with_domain_separator(&DIGEST_DOMAIN_SEP)
+    }
+}
+
+impl HashMarker for PoseidonDigest {}
+
+impl OutputSizeUser for PoseidonDigest {
+    type OutputSize = U32;
+}
+
+impl Update for PoseidonDigest {
+    fn update(&mut self, data: &[u8]) {
+        self.buffer.extend_from_slice(data);
+    }
+}
+
+impl FixedOutput for PoseidonDigest {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        self.finalize_bytes(out);
+    }
+}
+
+impl Reset for PoseidonDigest {
+    fn reset(&mut self) {
+        self.buffer.clear();
+    }
+}
+
+impl FixedOutputReset for PoseidonDigest {
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        self.finalize_bytes(out);
+        Reset::reset(self);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use digest::Digest;
+
+    use super::*;
+
+    #[test]
+    fn digest_matches_hash_bytes() {
+        assert_eq!(
+            DIGEST_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.digest")
+        );
+
+        let message = [7u8; 100];
+        let expected = hash_bytes(&DIGEST_DOMAIN_SEP, &message).to_bytes();
+
+        assert_eq!(PoseidonDigest::digest(message).as_slice(), &expected);
+
+        let mut hasher = PoseidonDigest::new();
+        Digest::update(&mut hasher, &message[..40]);
+        Digest::update(&mut hasher, &message[40..]);
+        assert_eq!(hasher.finalize_reset().as_slice(), &expected);
+        assert_eq!(
+            hasher.finalize().as_slice(),
+            &hash_bytes(&DIGEST_DOMAIN_SEP, &[]).to_bytes()
+        );
+
+        let domain_sep = Fq::from(1u64);
+        let mut hasher = PoseidonDigest::with_domain_separator(&domain_sep);
+        Digest::update(&mut hasher, message);
+        assert_eq!(
+            hasher.finalize().as_slice(),
+            &hash_bytes(&domain_sep, &message).to_bytes()
+        );
+    }
+}
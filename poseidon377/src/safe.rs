@@ -0,0 +1,255 @@
+use core::convert::TryFrom;
+
+use crate::{
+    error::SafeError,
+    hash_bytes,
+    sponge::{Sponge, SpongeInstance, SPONGE_RATE},
+    Fq, Instance,
+};
+
+/// Largest length of an operation, or of consecutive operations of the same kind.
+const MAX_OP_LEN: u64 = 0x7fff_ffff;
+
+/// Domain separator used to derive the capacity tag, `from_le_bytes_mod_order(b"poseidon377.safe")`.
+const SAFE_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    12439986467565186472,
+    15697472764870798291,
+    8976629359867129902,
+    563172152280255186,
+]);
+
+/// A single call declared in the IO pattern of a [`SafeSponge`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpongeOp {
+    /// Absorb the given number of field elements.
+    Absorb(u32),
+    /// Squeeze the given number of field elements.
+    Squeeze(u32),
+}
+
+impl SpongeOp {
+    fn is_absorb(&self) -> bool {
+        matches!(self, SpongeOp::Absorb(_))
+    }
+
+    fn len(&self) -> u32 {
+        match self {
+            SpongeOp::Absorb(len) | SpongeOp::Squeeze(len) => *len,
+        }
+    }
+}
+
+/// A sponge following the SAFE (Sponge API for Field Elements) specification.
+///
+/// The sequence of calls, the IO pattern, is declared up front in [`SafeSponge::start`]
+/// and bound into the capacity together with an application-specific domain
+/// separator, so two protocols only share sponge states if they agree on both. Each
+/// call to [`SafeSponge::absorb`] or [`SafeSponge::squeeze`] must then match the next
+/// declared operation exactly, and [`SafeSponge::finish`] checks that all of them
+/// were performed.
+///
+/// The tag placed in the capacity is computed as follows: consecutive operations of
+/// the same kind are aggregated, each aggregated operation is encoded as a 32-bit
+/// word (`0x80000000 + len` for absorbing and `len` for squeezing), and the number of
+/// words, the words themselves and [`hash_bytes`] of the domain separator are hashed
+/// with the rate-4 sponge. This replaces the SHA3 call of the specification so the
+/// tag can be recomputed cheaply in circuits.
+///
+/// With the `zeroize` feature, the sponge state is wiped when it is dropped.
+pub struct SafeSponge<'a> {
+    instance: SpongeInstance,
+    io_pattern: &'a [SpongeOp],
+    /// Index of the next operation expected from the IO pattern.
+    next_op: usize,
+    absorb_pos: usize,
+    squeeze_pos: usize,
+}
+
+impl<'a> SafeSponge<'a> {
+    /// Start a new sponge for the given `io_pattern` and `domain_separator`.
+    pub fn start(io_pattern: &'a [SpongeOp], domain_separator: &[u8]) -> Result<Self, SafeError> {
+        let mut instance = Instance::new(&crate::RATE_4_PARAMS);
+        instance.state_words_mut()[0] = tag(io_pattern, domain_separator)?;
+        Ok(Self {
+            instance,
+            io_pattern,
+            next_op: 0,
+            absorb_pos: 0,
+            // Output is only ever squeezed after a permutation.
+            squeeze_pos: SPONGE_RATE,
+        })
+    }
+
+    /// Absorb `input`, which must match the next operation in the IO pattern.
+    pub fn absorb(&mut self, input: &[Fq]) -> Result<(), SafeError> {
+        self.expect_op(SpongeOp::Absorb(op_len(input.len())?))?;
+
+        for word in input {
+            if self.absorb_pos == SPONGE_RATE {
+                self.instance.permute();
+                self.absorb_pos = 0;
+            }
+            self.instance.state_words_mut()[1 + self.absorb_pos] += *word;
+            self.absorb_pos += 1;
+        }
+        self.squeeze_pos = SPONGE_RATE;
+
+        Ok(())
+    }
+
+    /// Squeeze enough elements to fill `output`, which must match the next operation in the IO pattern.
+    pub fn squeeze(&mut self, output: &mut [Fq]) -> Result<(), SafeError> {
+        self.expect_op(SpongeOp::Squeeze(op_len(output.len())?))?;
+
+        for word in output.iter_mut() {
+            if self.squeeze_pos == SPONGE_RATE {
+                self.instance.permute();
+                self.squeeze_pos = 0;
+                self.absorb_pos = 0;
+            }
+            *word = self.instance.output_words()[1 + self.squeeze_pos];
+            self.squeeze_pos += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Finish using the sponge, checking that the whole IO pattern was performed.
+    pub fn finish(self) -> Result<(), SafeError> {
+        if self.next_op != self.io_pattern.len() {
+            return Err(SafeError::IoPatternIncomplete);
+        }
+        Ok(())
+    }
+
+    /// Check that `op` is the next operation in the IO pattern and advance past it.
+    ///
+    /// On a mismatch, the sponge is left unusable: all further calls fail.
+    fn expect_op(&mut self, op: SpongeOp) -> Result<(), SafeError> {
+        if self.io_pattern.get(self.next_op) != Some(&op) {
+            self.next_op = usize::MAX;
+            return Err(SafeError::IoPatternMismatch);
+        }
+        self.next_op += 1;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for SafeSponge<'_> {}
+
+/// Convert a slice length into an operation length.
+fn op_len(len: usize) -> Result<u32, SafeError> {
+    u32::try_from(len).map_err(|_| SafeError::IoPatternMismatch)
+}
+
+/// Compute the capacity tag for an IO pattern and domain separator.
+fn tag(io_pattern: &[SpongeOp], domain_separator: &[u8]) -> Result<Fq, SafeError> {
+    if io_pattern.is_empty() || io_pattern.iter().any(|op| op.len() == 0) {
+        return Err(SafeError::InvalidIoPattern);
+    }
+
+    let mut num_words = 0u64;
+    for (_, len) in aggregate(io_pattern) {
+        if len > MAX_OP_LEN {
+            return Err(SafeError::InvalidIoPattern);
+        }
+        num_words += 1;
+    }
+
+    let mut sponge = Sponge::new(&SAFE_DOMAIN_SEP);
+    sponge.absorb(&[Fq::from(num_words)]);
+    for (is_absorb, len) in aggregate(io_pattern) {
+        let word = if is_absorb { 0x8000_0000 + len } else { len };
+        sponge.absorb(&[Fq::from(word)]);
+    }
+    sponge.absorb(&[hash_bytes(&SAFE_DOMAIN_SEP, domain_separator)]);
+    Ok(sponge.finalize())
+}
+
+/// Merge consecutive operations of the same kind, yielding their kind and total length.
+fn aggregate(io_pattern: &[SpongeOp]) -> impl Iterator<Item = (bool, u64)> + '_ {
+    let mut ops = io_pattern.iter().peekable();
+    core::iter::from_fn(move || {
+        let first = ops.next()?;
+        let mut len = first.len() as u64;
+        while let Some(op) = ops.next_if(|op| op.is_absorb() == first.is_absorb()) {
+            len += op.len() as u64;
+        }
+        Some((first.is_absorb(), len))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PATTERN: [SpongeOp; 3] = [
+        SpongeOp::Absorb(2),
+        SpongeOp::Absorb(5),
+        SpongeOp::Squeeze(3),
+    ];
+
+    #[test]
+    fn safe_happy_path() {
+        assert_eq!(
+            SAFE_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.safe")
+        );
+
+        let input: [Fq; 7] = core::array::from_fn(|i| Fq::from(i as u64));
+        let mut output = [Fq::from(0u64); 3];
+
+        let mut sponge = SafeSponge::start(&PATTERN, b"test").unwrap();
+        sponge.absorb(&input[..2]).unwrap();
+        sponge.absorb(&input[2..]).unwrap();
+        sponge.squeeze(&mut output).unwrap();
+        sponge.finish().unwrap();
+
+        // A different domain separator results in different output.
+        let mut other_output = [Fq::from(0u64); 3];
+        let mut sponge = SafeSponge::start(&PATTERN, b"other").unwrap();
+        sponge.absorb(&input[..2]).unwrap();
+        sponge.absorb(&input[2..]).unwrap();
+        sponge.squeeze(&mut other_output).unwrap();
+        sponge.finish().unwrap();
+        assert_ne!(output, other_output);
+    }
+
+    #[test]
+    fn safe_tag_aggregates_operations() {
+        let aggregated = [SpongeOp::Absorb(7), SpongeOp::Squeeze(3)];
+        assert_eq!(
+            tag(&PATTERN, b"test").unwrap(),
+            tag(&aggregated, b"test").unwrap()
+        );
+        assert_ne!(
+            tag(&PATTERN, b"test").unwrap(),
+            tag(&[SpongeOp::Absorb(7), SpongeOp::Squeeze(2)], b"test").unwrap()
+        );
+    }
+
+    #[test]
+    fn safe_rejects_pattern_violations() {
+        assert_eq!(
+            SafeSponge::start(&[], b"test").err(),
+            Some(SafeError::InvalidIoPattern)
+        );
+        assert_eq!(
+            SafeSponge::start(&[SpongeOp::Absorb(0)], b"test").err(),
+            Some(SafeError::InvalidIoPattern)
+        );
+
+        let input = [Fq::from(1u64); 3];
+        let mut sponge = SafeSponge::start(&PATTERN, b"test").unwrap();
+        assert_eq!(sponge.absorb(&input), Err(SafeError::IoPatternMismatch));
+        assert_eq!(
+            sponge.absorb(&input[..2]),
+            Err(SafeError::IoPatternMismatch)
+        );
+
+        let mut sponge = SafeSponge::start(&PATTERN, b"test").unwrap();
+        sponge.absorb(&input[..2]).unwrap();
+        assert_eq!(sponge.finish(), Err(SafeError::IoPatternIncomplete));
+    }
+}
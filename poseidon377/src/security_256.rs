@@ -0,0 +1,121 @@
+//! Poseidon instances at the 256-bit security level.
+//!
+//! The instances at the crate root target 128 bits of security: their capacity is a
+//! single element and their output is a single element, which bounds collision
+//! resistance at ~126 bits. The instances of this module are for long-lived
+//! commitments that need more:
+//!
+//! * the round numbers are generated by `poseidon-paramgen` for `M = 256`, with the
+//!   same `x^17` S-box and transcript procedure as the instances at the crate root,
+//! * the capacity is [`CAPACITY`] elements, the domain separator and a zero word, so
+//!   a width-`t` instance has rate `t - 2`,
+//! * the output is two elements, squeezed from the rate.
+//!
+//! Two elements of [`Fq`] hold ~505 bits, so the capacity and the output provide
+//! ~252-bit collision and preimage resistance, the most this field allows for
+//! two-element capacities and outputs. These instances are entirely separate from
+//! those at the crate root: the same inputs hash to unrelated outputs.
+
+use crate::{params, Fq, Instance, PoseidonParameters};
+
+/// The number of capacity elements of the instances of this module.
+pub const CAPACITY: usize = 2;
+
+/// Parameters for the rate-1 instance of Poseidon at the 256-bit security level.
+pub const RATE_1_PARAMS: PoseidonParameters<3, 2, 9, 4, 71, 3, 213, 63> =
+    params::security_256::rate_1::rate_1();
+
+/// Parameters for the rate-2 instance of Poseidon at the 256-bit security level.
+pub const RATE_2_PARAMS: PoseidonParameters<4, 3, 16, 9, 71, 4, 284, 63> =
+    params::security_256::rate_2::rate_2();
+
+/// Hash a single [`Fq`] element with the provided `domain_separator`, producing two
+/// outputs.
+///
+/// With a single rate element, the outputs are squeezed with two permutations.
+pub fn hash_1(domain_separator: &Fq, value: Fq) -> [Fq; 2] {
+    let mut state = Instance::new(&RATE_1_PARAMS);
+    *state.state_words_mut() = [*domain_separator, Fq::from(0u64), value];
+    state.permute();
+    let first = state.output_words()[CAPACITY];
+    state.permute();
+    [first, state.output_words()[CAPACITY]]
+}
+
+/// Hash two [`Fq`] elements with the provided `domain_separator`, producing two
+/// outputs.
+pub fn hash_2(domain_separator: &Fq, value: (Fq, Fq)) -> [Fq; 2] {
+    let mut state = Instance::new(&RATE_2_PARAMS);
+    *state.state_words_mut() = [*domain_separator, Fq::from(0u64), value.0, value.1];
+    state.permute();
+    let output = state.output_words();
+    [output[CAPACITY], output[CAPACITY + 1]]
+}
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use poseidon_parameters::v1::{Alpha, RoundNumbers};
+
+    use super::*;
+
+    fn fq(s: &str) -> Fq {
+        Fq::from_str(s).unwrap()
+    }
+
+    #[test]
+    fn security_256_params() {
+        for (security, alpha, rounds) in [
+            (RATE_1_PARAMS.M, RATE_1_PARAMS.alpha, RATE_1_PARAMS.rounds),
+            (RATE_2_PARAMS.M, RATE_2_PARAMS.alpha, RATE_2_PARAMS.rounds),
+        ] {
+            assert_eq!(security, 256);
+            assert_eq!(alpha, Alpha::Exponent(17));
+            assert_eq!(rounds, RoundNumbers { r_P: 63, r_F: 8 });
+        }
+    }
+
+    #[test]
+    fn security_256_matches_unoptimized() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let [a, b] = [1u64, 2].map(Fq::from);
+
+        let input = [domain_sep, Fq::from(0u64), a];
+        assert_eq!(
+            Instance::new(&RATE_1_PARAMS).n_to_1_fixed_hash(&input),
+            Instance::new(&RATE_1_PARAMS).unoptimized_n_to_1_fixed_hash(input)
+        );
+
+        let input = [domain_sep, Fq::from(0u64), a, b];
+        assert_eq!(
+            Instance::new(&RATE_2_PARAMS).n_to_1_fixed_hash(&input),
+            Instance::new(&RATE_2_PARAMS).unoptimized_n_to_1_fixed_hash(input)
+        );
+    }
+
+    #[test]
+    fn security_256_hashes() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let [a, b] = [1u64, 2].map(Fq::from);
+
+        assert_eq!(
+            hash_1(&domain_sep, a),
+            [
+                fq("667407561392370147222394834027978837499969042816775871016884190089895182660"),
+                fq("3144375495291861894108860086139792865603756203239678500269304611663049684398"),
+            ]
+        );
+        assert_eq!(
+            hash_2(&domain_sep, (a, b)),
+            [
+                fq("3471531982106909139123137732019858367990565939375014366553016190349435775680"),
+                fq("5911129887122336135133712551899838565238206900187697177667187480042155130135"),
+            ]
+        );
+        assert_ne!(
+            hash_2(&domain_sep, (a, b))[0],
+            crate::hash_2(&domain_sep, (a, b))
+        );
+    }
+}
@@ -0,0 +1,233 @@
+use crate::{Fq, Instance};
+
+/// Number of rate words in the sponge used for variable-length hashing.
+pub(crate) const SPONGE_RATE: usize = 4;
+
+/// Domain separator used to bind the domain separator of a sponge into its capacity,
+/// `from_le_bytes_mod_order(b"poseidon377.sponge")`.
+pub(crate) const SPONGE_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    17490830561049390863,
+    7009289195476712591,
+    4868967079171742991,
+    334263811190983764,
+]);
+
+/// The rate-4 instance of Poseidon that the sponge constructions are built on.
+pub(crate) type SpongeInstance = Instance<'static, 5, 4, 25, 16, 39, 5, 195, 31>;
+
+/// Whether the duplex is currently absorbing or squeezing, and at which rate position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DuplexMode {
+    /// Position in the rate portion of the state that the next word is added to.
+    Absorbing(usize),
+    /// Position in the rate portion of the state that the next word is read from.
+    Squeezing(usize),
+}
+
+/// A duplex sponge over the rate-4 instance of Poseidon.
+///
+/// The capacity word is initialized to [`hash_1`](crate::hash_1) of the domain
+/// separator under a domain separator reserved for sponges, so a sponge never
+/// starts from the state of a fixed-width hash with the same domain separator, such
+/// as [`hash_4`](crate::hash_4) of the padding. Absorbed words are
+/// added into the rate portion of the state, permuting each time it fills up. When
+/// switching from absorbing to squeezing, the absorbed words are padded with a
+/// single `1` followed by as many `0`s as are needed to complete the block, and the
+/// state is permuted before any output is read. Squeezed words are read from the
+/// rate portion of the state, permuting each time it is exhausted.
+///
+/// Absorbing a sequence of words and then squeezing once produces the same output
+/// as [`hash_varlen`](crate::hash_varlen).
+///
+/// With the `zeroize` feature, the sponge state is wiped when it is dropped.
+#[derive(Clone)]
+pub struct DuplexSponge {
+    instance: SpongeInstance,
+    mode: DuplexMode,
+}
+
+impl DuplexSponge {
+    /// Create a new duplex sponge for the provided `domain_separator`.
+    pub fn new(domain_separator: &Fq) -> Self {
+        Self::from_capacity(&crate::hash_1(&SPONGE_DOMAIN_SEP, *domain_separator))
+    }
+
+    /// Create a new duplex sponge with `capacity` as its capacity word, such as a key.
+    pub(crate) fn from_capacity(capacity: &Fq) -> Self {
+        let mut instance = Instance::new(&crate::RATE_4_PARAMS);
+        instance.state_words_mut()[0] = *capacity;
+        Self {
+            instance,
+            mode: DuplexMode::Absorbing(0),
+        }
+    }
+
+    /// Absorb `input` words into the sponge.
+    pub fn absorb(&mut self, input: &[Fq]) {
+        for word in input {
+            self.absorb_word(*word);
+        }
+    }
+
+    /// Absorb a single word, permuting if the rate portion of the state is full.
+    fn absorb_word(&mut self, word: Fq) {
+        let mut pos = match self.mode {
+            DuplexMode::Absorbing(pos) => pos,
+            DuplexMode::Squeezing(_) => 0,
+        };
+
+        self.instance.state_words_mut()[1 + pos] += word;
+        pos += 1;
+        if pos == SPONGE_RATE {
+            self.instance.permute();
+            pos = 0;
+        }
+        self.mode = DuplexMode::Absorbing(pos);
+    }
+
+    /// Squeeze a single word out of the sponge.
+    pub fn squeeze(&mut self) -> Fq {
+        let mut pos = match self.mode {
+            DuplexMode::Absorbing(pos) => {
+                // Adding the trailing `0`s of the padding is a no-op.
+                self.instance.state_words_mut()[1 + pos] += Fq::from(1u64);
+                self.instance.permute();
+                0
+            }
+            DuplexMode::Squeezing(pos) => pos,
+        };
+
+        if pos == SPONGE_RATE {
+            self.instance.permute();
+            pos = 0;
+        }
+        let word = self.instance.output_words()[1 + pos];
+        self.mode = DuplexMode::Squeezing(pos + 1);
+        word
+    }
+
+    /// Squeeze enough words out of the sponge to fill `output`.
+    pub fn squeeze_into(&mut self, output: &mut [Fq]) {
+        for word in output.iter_mut() {
+            *word = self.squeeze();
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for DuplexSponge {}
+
+/// A sponge that absorbs all of its input before squeezing any output.
+#[derive(Clone)]
+pub(crate) struct Sponge(DuplexSponge);
+
+impl Sponge {
+    /// Create a new sponge for the provided `domain_separator`, as [`DuplexSponge::new`] does.
+    pub(crate) fn new(domain_separator: &Fq) -> Self {
+        Self(DuplexSponge::new(domain_separator))
+    }
+
+    /// Create a new sponge with `capacity` as its capacity word, such as a key.
+    pub(crate) fn from_capacity(capacity: &Fq) -> Self {
+        Self(DuplexSponge::from_capacity(capacity))
+    }
+
+    /// Absorb `input` words into the sponge.
+    pub(crate) fn absorb(&mut self, input: &[Fq]) {
+        self.0.absorb(input);
+    }
+
+    /// Pad the absorbed input, apply the final permutation and squeeze a single word.
+    pub(crate) fn finalize(mut self) -> Fq {
+        self.0.squeeze()
+    }
+
+    /// Pad the absorbed input, apply the final permutation and squeeze enough words
+    /// to fill `output`, permuting again each time the rate portion is exhausted.
+    pub(crate) fn finalize_into(mut self, output: &mut [Fq]) {
+        self.squeeze_into(output);
+    }
+
+    /// Squeeze enough words to fill `output`, padding the absorbed input and
+    /// applying the final permutation on the first call.
+    pub(crate) fn squeeze_into(&mut self, output: &mut [Fq]) {
+        self.0.squeeze_into(output);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hash_varlen;
+
+    #[test]
+    fn duplex_matches_varlen() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input: [Fq; 9] = core::array::from_fn(|i| Fq::from(i as u64));
+
+        let mut duplex = DuplexSponge::new(&domain_sep);
+        duplex.absorb(&input[..3]);
+        duplex.absorb(&input[3..]);
+        assert_eq!(duplex.squeeze(), hash_varlen(&domain_sep, &input));
+    }
+
+    #[cfg(feature = "r1cs")]
+    #[test]
+    fn duplex_gadget_interleaving() {
+        use ark_r1cs_std::{alloc::AllocVar, R1CSVar};
+        use ark_relations::r1cs::ConstraintSystem;
+        use decaf377::r1cs::FqVar;
+
+        use crate::r1cs::DuplexSpongeVar;
+
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let input: [Fq; 7] = core::array::from_fn(|i| Fq::from(i as u64));
+
+        let mut duplex = DuplexSponge::new(&domain_sep);
+        duplex.absorb(&input[..2]);
+        let mut first = [Fq::from(0u64); 6];
+        duplex.squeeze_into(&mut first);
+        duplex.absorb(&input[2..]);
+        let second = duplex.squeeze();
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let domain_sep_var = FqVar::new_constant(cs.clone(), domain_sep).unwrap();
+        let input_vars: Vec<FqVar> = input
+            .iter()
+            .map(|x| FqVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+            .collect();
+        let mut duplex_var = DuplexSpongeVar::new(cs.clone(), &domain_sep_var);
+        duplex_var.absorb(&input_vars[..2]);
+        assert_eq!(duplex_var.squeeze_n(6).value().unwrap(), first);
+        duplex_var.absorb(&input_vars[2..]);
+        assert_eq!(duplex_var.squeeze().value().unwrap(), second);
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn duplex_interleaving() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let a = Fq::from(1u64);
+        let b = Fq::from(2u64);
+
+        let mut duplex_1 = DuplexSponge::new(&domain_sep);
+        duplex_1.absorb(&[a]);
+        let challenge_1 = duplex_1.squeeze();
+        duplex_1.absorb(&[b]);
+        let challenge_2 = duplex_1.squeeze();
+
+        // Squeezing in between changes all later outputs.
+        let mut duplex_2 = DuplexSponge::new(&domain_sep);
+        duplex_2.absorb(&[a, b]);
+        assert_ne!(duplex_2.squeeze(), challenge_2);
+
+        // Squeezing is deterministic and repeated squeezes differ.
+        let mut duplex_3 = DuplexSponge::new(&domain_sep);
+        duplex_3.absorb(&[a]);
+        let mut outputs = [Fq::from(0u64); 6];
+        duplex_3.squeeze_into(&mut outputs);
+        assert_eq!(outputs[0], challenge_1);
+        assert_ne!(outputs[0], outputs[1]);
+        assert_ne!(outputs[3], outputs[4]);
+    }
+}
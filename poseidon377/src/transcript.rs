@@ -0,0 +1,142 @@
+use crate::{hash_bytes, DuplexSponge, Fq};
+
+/// Domain separator used to hash transcript labels,
+/// `from_le_bytes_mod_order(b"poseidon377.transcript")`.
+const TRANSCRIPT_DOMAIN_SEP: Fq = Fq::from_montgomery_limbs([
+    15130453964972470154,
+    15941102202220205281,
+    9979670548158517670,
+    342839767181417561,
+]);
+
+/// Hash a transcript `label` to the element absorbed in its place.
+pub(crate) fn label_element(label: &[u8]) -> Fq {
+    hash_bytes(&TRANSCRIPT_DOMAIN_SEP, label)
+}
+
+/// A Fiat-Shamir transcript built on the [`DuplexSponge`].
+///
+/// The protocol label given to [`Transcript::new`] is hashed into the capacity.
+/// Appending a message absorbs the hash of its label, its length and the message
+/// itself, and extracting a challenge absorbs the hash of its label and then
+/// squeezes a single word. Since the length of each message is absorbed, the
+/// words absorbed between two challenges can only be parsed in one way, and every
+/// challenge binds all labels and messages that came before it.
+///
+/// Each label costs one permutation to hash, so labels should be short. With the
+/// `r1cs` feature, `r1cs::TranscriptVar` is the in-circuit counterpart of this
+/// type, which produces the same challenges.
+///
+/// ```
+/// use poseidon377::{Fq, Transcript};
+///
+/// let mut transcript = Transcript::new(b"my.protocol.v1");
+/// transcript.append_message(b"commitment", &[Fq::from(1u64)]);
+/// let challenge = transcript.challenge(b"challenge");
+/// ```
+#[derive(Clone)]
+pub struct Transcript {
+    sponge: DuplexSponge,
+}
+
+impl Transcript {
+    /// Create a new transcript for the protocol identified by `label`.
+    pub fn new(label: &[u8]) -> Self {
+        Self {
+            sponge: DuplexSponge::new(&label_element(label)),
+        }
+    }
+
+    /// Append a `message` to the transcript under `label`.
+    pub fn append_message(&mut self, label: &[u8], message: &[Fq]) {
+        self.sponge
+            .absorb(&[label_element(label), Fq::from(message.len() as u64)]);
+        self.sponge.absorb(message);
+    }
+
+    /// Extract a challenge under `label`, binding all messages appended so far.
+    pub fn challenge(&mut self, label: &[u8]) -> Fq {
+        self.sponge.absorb(&[label_element(label)]);
+        self.sponge.squeeze()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn transcript_binds_labels_and_messages() {
+        assert_eq!(
+            TRANSCRIPT_DOMAIN_SEP,
+            Fq::from_le_bytes_mod_order(b"poseidon377.transcript")
+        );
+
+        let challenge = |protocol: &[u8], label: &[u8], message: &[Fq]| {
+            let mut transcript = Transcript::new(protocol);
+            transcript.append_message(label, message);
+            transcript.challenge(b"challenge")
+        };
+        let a = Fq::from(1u64);
+        let b = Fq::from(2u64);
+
+        let expected = challenge(b"protocol", b"message", &[a, b]);
+        assert_eq!(challenge(b"protocol", b"message", &[a, b]), expected);
+        assert_ne!(challenge(b"other protocol", b"message", &[a, b]), expected);
+        assert_ne!(challenge(b"protocol", b"other message", &[a, b]), expected);
+        assert_ne!(challenge(b"protocol", b"message", &[b, a]), expected);
+
+        // Message boundaries are bound by the absorbed lengths.
+        let mut transcript = Transcript::new(b"protocol");
+        transcript.append_message(b"message", &[a]);
+        transcript.append_message(b"message", &[b]);
+        assert_ne!(transcript.challenge(b"challenge"), expected);
+
+        // Successive challenges differ.
+        let mut transcript = Transcript::new(b"protocol");
+        let first = transcript.challenge(b"challenge");
+        assert_ne!(transcript.challenge(b"challenge"), first);
+    }
+
+    #[cfg(feature = "r1cs")]
+    #[test]
+    fn transcript_var_matches_native() {
+        use ark_r1cs_std::{alloc::AllocVar, R1CSVar};
+        use ark_relations::r1cs::ConstraintSystem;
+        use decaf377::r1cs::FqVar;
+
+        use crate::r1cs::TranscriptVar;
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let messages: [&[Fq]; 3] = [
+            &[Fq::from(1u64)],
+            &[],
+            &[
+                Fq::from(2u64),
+                Fq::from(3u64),
+                Fq::from(4u64),
+                Fq::from(5u64),
+            ],
+        ];
+
+        let mut transcript = Transcript::new(b"protocol");
+        let mut transcript_var = TranscriptVar::new(cs.clone(), b"protocol");
+        assert_eq!(
+            transcript_var.challenge(b"first").value().unwrap(),
+            transcript.challenge(b"first")
+        );
+        for message in messages {
+            let message_vars: Vec<FqVar> = message
+                .iter()
+                .map(|x| FqVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+                .collect();
+            transcript.append_message(b"message", message);
+            transcript_var.append_message(b"message", &message_vars);
+            assert_eq!(
+                transcript_var.challenge(b"challenge").value().unwrap(),
+                transcript.challenge(b"challenge")
+            );
+        }
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
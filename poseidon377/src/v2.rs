@@ -0,0 +1,67 @@
+//! Poseidon2 instances, with the external and internal rounds of the Poseidon2 paper.
+//!
+//! These instances use the parameters generated by `poseidon-paramgen` for
+//! Poseidon2 and are unrelated to the Poseidon instances at the crate root: the
+//! same inputs hash to different outputs. Their internal rounds multiply by a
+//! matrix with ones off the diagonal, which takes `t` rather than `t^2`
+//! multiplications.
+
+use poseidon_parameters::v2::PoseidonParameters;
+use poseidon_permutation::v2::Instance;
+
+use crate::{params, Fq};
+
+/// Parameters for the rate-1 instance of Poseidon2.
+pub const RATE_1_PARAMS: PoseidonParameters<2, 4, 47, 1, 47> = params::v2::rate_1::rate_1();
+
+/// Parameters for the rate-2 instance of Poseidon2.
+pub const RATE_2_PARAMS: PoseidonParameters<3, 9, 55, 1, 55> = params::v2::rate_2::rate_2();
+
+/// Hash a single [`Fq`] element with the provided `domain_separator`.
+pub fn hash_1(domain_separator: &Fq, value: Fq) -> Fq {
+    let mut state = Instance::new(&RATE_1_PARAMS);
+    state.n_to_1_fixed_hash(&[*domain_separator, value])
+}
+
+/// Hash two [`Fq`] elements with the provided `domain_separator`.
+pub fn hash_2(domain_separator: &Fq, value: (Fq, Fq)) -> Fq {
+    let mut state = Instance::new(&RATE_2_PARAMS);
+    state.n_to_1_fixed_hash(&[*domain_separator, value.0, value.1])
+}
+
+#[cfg(test)]
+mod test {
+    use core::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn poseidon2_test_vectors() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+
+        let expected_output = Fq::from_str(
+            "7959026818457904776576168981051680711424244133273836320378039242536213989863",
+        )
+        .unwrap();
+        assert_eq!(hash_1(&domain_sep, Fq::from(1u64)), expected_output);
+
+        let expected_output = Fq::from_str(
+            "6825356123899579768147299276729507070708906881306574558873219502542177180159",
+        )
+        .unwrap();
+        assert_eq!(
+            hash_2(&domain_sep, (Fq::from(1u64), Fq::from(2u64))),
+            expected_output
+        );
+    }
+
+    #[test]
+    fn poseidon2_differs_from_poseidon() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let (a, b) = (Fq::from(1u64), Fq::from(2u64));
+        assert_ne!(
+            hash_2(&domain_sep, (a, b)),
+            crate::hash_2(&domain_sep, (a, b))
+        );
+    }
+}
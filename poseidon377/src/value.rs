@@ -0,0 +1,125 @@
+use crate::{hash, Fq, Hasher, ToFieldElements};
+
+/// A primitive value that can be hashed with [`hash_values`].
+///
+/// Every value is encoded as the single [`Fq`] element equal to it as an integer:
+/// unsigned integers map to themselves, and a negative `i64` value `-x` maps to
+/// `p - x`, where `p` is the modulus. Since `p` is greater than `2^128`, distinct
+/// integers always map to distinct elements, and integers of different types that
+/// are equal encode identically. [`Fq`] values are used as they are.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Value {
+    /// An unsigned 64-bit integer.
+    U64(u64),
+    /// An unsigned 128-bit integer.
+    U128(u128),
+    /// A signed 64-bit integer.
+    I64(i64),
+    /// A field element.
+    Fq(Fq),
+}
+
+impl Value {
+    /// The field element encoding this value.
+    pub fn to_fq(&self) -> Fq {
+        match *self {
+            Value::U64(value) => Fq::from(value),
+            Value::U128(value) => Fq::from_le_bytes_mod_order(&value.to_le_bytes()),
+            Value::I64(value) if value < 0 => -Fq::from(value.unsigned_abs()),
+            Value::I64(value) => Fq::from(value as u64),
+            Value::Fq(value) => value,
+        }
+    }
+}
+
+impl From<u64> for Value {
+    fn from(value: u64) -> Self {
+        Value::U64(value)
+    }
+}
+
+impl From<u128> for Value {
+    fn from(value: u128) -> Self {
+        Value::U128(value)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::I64(value)
+    }
+}
+
+impl From<Fq> for Value {
+    fn from(value: Fq) -> Self {
+        Value::Fq(value)
+    }
+}
+
+impl From<Value> for Fq {
+    fn from(value: Value) -> Self {
+        value.to_fq()
+    }
+}
+
+impl ToFieldElements for Value {
+    fn append_field_elements<E: Extend<Fq>>(&self, output: &mut E) {
+        output.extend(Some(self.to_fq()));
+    }
+}
+
+/// Hash a slice of primitive [`Value`]s with the provided `domain_separator`.
+///
+/// Each value is encoded as a single [`Fq`] element as documented on [`Value`],
+/// and the elements are hashed with [`hash`], i.e. with the fixed-width instance
+/// for one to seven values and with [`hash_varlen`](crate::hash_varlen) otherwise.
+pub fn hash_values(domain_separator: &Fq, values: &[Value]) -> Fq {
+    let mut elements = [Fq::from(0u64); 7];
+    if values.is_empty() || values.len() > elements.len() {
+        let mut hasher = Hasher::new(domain_separator);
+        hasher.extend(values.iter().map(Value::to_fq));
+        return hasher.finalize();
+    }
+
+    for (element, value) in elements.iter_mut().zip(values) {
+        *element = value.to_fq();
+    }
+    hash(domain_separator, &elements[..values.len()])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{hash_2, hash_varlen};
+
+    #[test]
+    fn value_encoding() {
+        assert_eq!(Value::U64(5).to_fq(), Fq::from(5u64));
+        assert_eq!(Value::U128(5).to_fq(), Fq::from(5u64));
+        assert_eq!(Value::I64(5).to_fq(), Fq::from(5u64));
+        assert_eq!(Value::I64(-5).to_fq() + Fq::from(5u64), Fq::from(0u64));
+        assert_eq!(Value::I64(i64::MIN).to_fq(), -Fq::from(1u64 << 63));
+        let two_to_64 = Fq::from(u64::MAX) + Fq::from(1u64);
+        assert_eq!(
+            Value::U128(u128::MAX).to_fq() + Fq::from(1u64),
+            two_to_64 * two_to_64
+        );
+    }
+
+    #[test]
+    fn hash_values_matches_hash() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let y = Fq::from(9u64);
+
+        assert_eq!(
+            hash_values(&domain_sep, &[Value::U64(7), Value::Fq(y)]),
+            hash_2(&domain_sep, (Fq::from(7u64), y))
+        );
+
+        let values = [Value::I64(-1); 8];
+        assert_eq!(
+            hash_values(&domain_sep, &values),
+            hash_varlen(&domain_sep, &[-Fq::from(1u64); 8])
+        );
+    }
+}
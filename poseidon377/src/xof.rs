@@ -0,0 +1,123 @@
+use crate::{sponge::Sponge, Fq};
+
+/// Number of bytes taken from each squeezed [`Fq`] element by [`Xof::squeeze_bytes`].
+///
+/// The low 16 bytes of a uniformly random element are within statistical distance
+/// `2^128 / p < 2^-124` of uniform, whereas using more bytes per element would
+/// introduce a noticeable bias.
+const BYTES_PER_ELEMENT: usize = 16;
+
+/// An extendable-output function, producing an arbitrary amount of output.
+///
+/// An `Xof` is created from a [`Hasher`](crate::Hasher) that has absorbed all of the
+/// input with [`Hasher::finalize_xof`](crate::Hasher::finalize_xof). Outputs are
+/// squeezed from the rate-4 sponge, permuting each time four elements have been
+/// read. The first element squeezed is equal to the output of
+/// [`Hasher::finalize`](crate::Hasher::finalize), and squeezing `n` elements one
+/// call at a time produces the same elements as squeezing them at once.
+///
+/// With the `zeroize` feature, the sponge state and any buffered output bytes are
+/// wiped when the `Xof` is dropped.
+pub struct Xof {
+    sponge: Sponge,
+    /// Bytes of the last squeezed element that have not been returned yet.
+    bytes: [u8; BYTES_PER_ELEMENT],
+    bytes_pos: usize,
+}
+
+impl Xof {
+    pub(crate) fn new(sponge: Sponge) -> Self {
+        Self {
+            sponge,
+            bytes: [0u8; BYTES_PER_ELEMENT],
+            bytes_pos: BYTES_PER_ELEMENT,
+        }
+    }
+
+    /// Squeeze enough elements to fill `output`.
+    pub fn squeeze_into(&mut self, output: &mut [Fq]) {
+        self.sponge.squeeze_into(output);
+    }
+
+    /// Squeeze `n` elements.
+    #[cfg(feature = "alloc")]
+    pub fn squeeze(&mut self, n: usize) -> alloc::vec::Vec<Fq> {
+        let mut output = alloc::vec![Fq::from(0u64); n];
+        self.squeeze_into(&mut output);
+        output
+    }
+
+    /// Squeeze enough bytes to fill `output`.
+    ///
+    /// Each squeezed element provides 16 bytes, its low bytes in little-endian
+    /// order. Bytes left over from the last element are returned by the next call,
+    /// so the byte stream does not depend on how it is split into calls. Byte and
+    /// element outputs share the same underlying sponge, so interleaving the two
+    /// skips the unused bytes of the current element.
+    pub fn squeeze_bytes_into(&mut self, output: &mut [u8]) {
+        for byte in output.iter_mut() {
+            if self.bytes_pos == BYTES_PER_ELEMENT {
+                let mut element = [Fq::from(0u64)];
+                self.sponge.squeeze_into(&mut element);
+                self.bytes
+                    .copy_from_slice(&element[0].to_bytes()[..BYTES_PER_ELEMENT]);
+                #[cfg(feature = "zeroize")]
+                zeroize::Zeroize::zeroize(&mut element);
+                self.bytes_pos = 0;
+            }
+            *byte = self.bytes[self.bytes_pos];
+            self.bytes_pos += 1;
+        }
+    }
+
+    /// Squeeze `n` bytes, as with [`Xof::squeeze_bytes_into`].
+    #[cfg(feature = "alloc")]
+    pub fn squeeze_bytes(&mut self, n: usize) -> alloc::vec::Vec<u8> {
+        let mut output = alloc::vec![0u8; n];
+        self.squeeze_bytes_into(&mut output);
+        output
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Xof {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.bytes);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for Xof {}
+
+#[cfg(test)]
+mod test {
+    use crate::{hash_varlen, Fq, Hasher};
+
+    #[test]
+    fn xof_outputs() {
+        let domain_sep = Fq::from_le_bytes_mod_order(b"Penumbra_TestVec");
+        let seed = [Fq::from(42u64)];
+
+        let mut hasher = Hasher::new(&domain_sep);
+        hasher.update(&seed);
+        let mut xof = hasher.finalize_xof();
+        let output = xof.squeeze(9);
+        assert_eq!(output[0], hash_varlen(&domain_sep, &seed));
+
+        let mut hasher = Hasher::new(&domain_sep);
+        hasher.update(&seed);
+        let mut xof = hasher.finalize_xof();
+        let mut split = xof.squeeze(2);
+        split.extend(xof.squeeze(7));
+        assert_eq!(output, split);
+
+        let mut hasher = Hasher::new(&domain_sep);
+        hasher.update(&seed);
+        let mut xof = hasher.finalize_xof();
+        let mut bytes = xof.squeeze_bytes(5);
+        bytes.extend(xof.squeeze_bytes(30));
+        assert_eq!(bytes[..16], output[0].to_bytes()[..16]);
+        assert_eq!(bytes[16..32], output[1].to_bytes()[..16]);
+        assert_eq!(bytes[32..], output[2].to_bytes()[..3]);
+    }
+}